@@ -18,6 +18,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::entities::{CharacterSheetData, FieldValue};
+use crate::SkillAliases;
+
 // =============================================================================
 // Character Sheet Schema
 // =============================================================================
@@ -444,6 +447,81 @@ pub struct CreationStep {
     /// Whether this step is required
     #[serde(default = "default_true")]
     pub required: bool,
+    /// Point-buy or array rules for assigning stats in this step
+    #[serde(default)]
+    pub allocation: Option<StatAllocation>,
+}
+
+/// Ways a creation step lets players assign a group of stats.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatAllocation {
+    /// Fields assigned together (e.g., the six ability scores)
+    pub field_ids: Vec<String>,
+    /// Point-buy rules, if the system offers them
+    #[serde(default)]
+    pub point_buy: Option<PointBuy>,
+    /// Fixed arrays of values to distribute across the fields
+    #[serde(default)]
+    pub arrays: Vec<StatArray>,
+    /// Whether values may also be entered directly (e.g., after rolling)
+    #[serde(default = "default_true")]
+    pub allow_manual: bool,
+}
+
+/// Point-buy budget and the cost of each purchasable value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PointBuy {
+    /// Total points available
+    pub budget: u32,
+    /// Cost of each value; values not listed can't be bought
+    pub costs: Vec<PointCost>,
+}
+
+impl PointBuy {
+    /// Cost of a single value, if it can be bought
+    pub fn cost_of(&self, value: i32) -> Option<u32> {
+        self.costs.iter().find(|c| c.value == value).map(|c| c.cost)
+    }
+
+    /// Total cost of a set of values, or None if any can't be bought
+    pub fn total_cost(&self, values: &[i32]) -> Option<u32> {
+        values.iter().map(|v| self.cost_of(*v)).sum()
+    }
+
+    /// Lowest purchasable value, where every field starts
+    pub fn min_value(&self) -> Option<i32> {
+        self.costs.iter().map(|c| c.value).min()
+    }
+}
+
+/// Cost of one value under point buy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PointCost {
+    pub value: i32,
+    pub cost: u32,
+}
+
+/// A fixed set of values, each assigned to exactly one field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatArray {
+    /// Display name (e.g., "Standard Array")
+    pub label: String,
+    pub values: Vec<i32>,
+}
+
+impl StatArray {
+    /// Whether the assigned values use every array value exactly once
+    pub fn matches(&self, assigned: &[i32]) -> bool {
+        let mut expected = self.values.clone();
+        let mut actual = assigned.to_vec();
+        expected.sort_unstable();
+        actual.sort_unstable();
+        expected == actual
+    }
 }
 
 // =============================================================================
@@ -502,6 +580,124 @@ pub struct ValidationError {
     pub message: String,
 }
 
+// =============================================================================
+// Sheet Conversion
+// =============================================================================
+
+/// A character sheet carried over to another game system's schema.
+#[derive(Debug, Clone, Default)]
+pub struct SheetConversion {
+    /// The values the new schema has a field for, under that field's id
+    pub sheet: CharacterSheetData,
+    /// Labels of the old fields whose values had nowhere to go
+    pub unconverted: Vec<String>,
+}
+
+impl CharacterSheetSchema {
+    /// Every field in the schema, section by section
+    pub fn fields(&self) -> impl Iterator<Item = &FieldDefinition> {
+        self.sections.iter().flat_map(|s| s.fields.iter())
+    }
+
+    /// Carry `sheet`, filled in against the `from` schema, over to this one.
+    ///
+    /// A value keeps its field when this schema has the same id, otherwise it
+    /// moves to the field whose label the alias table matches ("Spot Hidden"
+    /// becomes "Notice"). Values move unchanged, so one that doesn't fit the
+    /// new field (a proficiency into a ladder rating, a percentile into a
+    /// 3-18 score) stays behind with the fields that have no counterpart.
+    /// Derived fields are left for the new system to recalculate.
+    pub fn convert_sheet(
+        &self,
+        from: Option<&CharacterSheetSchema>,
+        sheet: &CharacterSheetData,
+        aliases: &SkillAliases,
+    ) -> SheetConversion {
+        let targets: Vec<&FieldDefinition> =
+            self.fields().filter(|f| f.derived_from.is_none()).collect();
+        let labels: Vec<&str> = targets.iter().map(|f| f.label.as_str()).collect();
+
+        let mut pending: Vec<(&String, &FieldValue, &str)> = Vec::new();
+        for (id, value) in &sheet.values {
+            let old = from.and_then(|schema| schema.fields().find(|f| f.id == *id));
+            if old.is_some_and(|f| f.derived_from.is_some()) {
+                continue;
+            }
+            pending.push((id, value, old.map_or(id.as_str(), |f| f.label.as_str())));
+        }
+        pending.sort_by(|a, b| a.0.cmp(b.0));
+
+        // Same-id fields are placed first so an alias never takes their spot
+        let mut conversion = SheetConversion::default();
+        let mut aliased = Vec::new();
+        for (id, value, label) in pending {
+            match targets.iter().find(|f| f.id.eq_ignore_ascii_case(id)) {
+                Some(field) if field.field_type.accepts(value) => {
+                    conversion.sheet.set(field.id.clone(), value.clone())
+                }
+                Some(_) => conversion.unconverted.push(label.to_string()),
+                None => aliased.push((value, label)),
+            }
+        }
+        for (value, label) in aliased {
+            let field = aliases
+                .resolve(label, &labels)
+                .and_then(|matched| targets.iter().find(|f| f.label == matched));
+            match field {
+                Some(field)
+                    if field.field_type.accepts(value)
+                        && conversion.sheet.get(&field.id).is_none() =>
+                {
+                    conversion.sheet.set(field.id.clone(), value.clone())
+                }
+                _ => conversion.unconverted.push(label.to_string()),
+            }
+        }
+        conversion
+    }
+}
+
+impl SchemaFieldType {
+    /// Whether a stored value fits this field
+    pub fn accepts(&self, value: &FieldValue) -> bool {
+        let in_range = |n: i32, min: &Option<i32>, max: &Option<i32>| {
+            min.is_none_or(|min| n >= min) && max.is_none_or(|max| n <= max)
+        };
+        match (self, value) {
+            (Self::Unknown, _) => true,
+            (
+                Self::Text { .. } | Self::Select { .. } | Self::EntityRef { .. },
+                FieldValue::Text(_),
+            ) => true,
+            (Self::Integer { min, max, .. }, FieldValue::Number(n))
+            | (Self::AbilityScore { min, max }, FieldValue::Number(n)) => in_range(*n, min, max),
+            (
+                Self::Skill { .. } | Self::SavingThrow { .. },
+                FieldValue::SkillEntry { .. } | FieldValue::Boolean(_) | FieldValue::Text(_),
+            ) => true,
+            (Self::Boolean { .. }, FieldValue::Boolean(_)) => true,
+            (
+                Self::MultiSelect { .. } | Self::Tags | Self::ModifierList { .. },
+                FieldValue::List(_),
+            ) => true,
+            (Self::ResourceBar { .. }, FieldValue::Resource { .. } | FieldValue::Number(_)) => true,
+            (Self::DicePool { .. }, FieldValue::DicePool { .. }) => true,
+            (Self::DicePool { max_dice, .. }, FieldValue::Number(n)) => {
+                (0..=i32::from(*max_dice)).contains(n)
+            }
+            (Self::LadderRating { min, max, .. }, FieldValue::LadderRating(r)) => {
+                (*min..=*max).contains(&i32::from(*r))
+            }
+            (Self::PercentileSkill { .. }, FieldValue::Percentile(_)) => true,
+            (
+                Self::Clock { .. } | Self::ConditionTrack { .. } | Self::XpProgress { .. },
+                FieldValue::Number(_),
+            ) => true,
+            _ => false,
+        }
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -570,4 +766,72 @@ mod tests {
         assert!(json.contains("dnd5e"));
         assert!(json.contains("Strength"));
     }
+
+    #[test]
+    fn test_point_buy_cost() {
+        let point_buy = PointBuy {
+            budget: 5,
+            costs: vec![
+                PointCost { value: 8, cost: 0 },
+                PointCost { value: 9, cost: 1 },
+                PointCost { value: 10, cost: 2 },
+            ],
+        };
+        assert_eq!(point_buy.min_value(), Some(8));
+        assert_eq!(point_buy.total_cost(&[8, 9, 10]), Some(3));
+        assert_eq!(point_buy.total_cost(&[8, 11]), None);
+    }
+
+    #[test]
+    fn test_stat_array_matches_any_order() {
+        let array = StatArray {
+            label: "Standard Array".to_string(),
+            values: vec![15, 14, 13],
+        };
+        assert!(array.matches(&[13, 15, 14]));
+        assert!(!array.matches(&[15, 15, 13]));
+        assert!(!array.matches(&[15, 14]));
+    }
+
+    #[test]
+    fn test_creation_step_allocation_defaults() {
+        let step: CreationStep = serde_json::from_str(
+            r#"{"id":"stats","label":"Stats","description":"","sectionIds":[],"order":1}"#,
+        )
+        .unwrap();
+        assert!(step.required);
+        assert!(step.allocation.is_none());
+    }
+
+    #[test]
+    fn test_convert_sheet_between_systems() {
+        use crate::game_systems::character_sheet_schema;
+
+        let dnd = character_sheet_schema("dnd5e").unwrap();
+        let pf2e = character_sheet_schema("pf2e").unwrap();
+        let fate = character_sheet_schema("fate_core").unwrap();
+
+        let mut sheet = CharacterSheetData::new();
+        sheet.set("NAME", FieldValue::Text("Mira".to_string()));
+        sheet.set("STR", FieldValue::Number(16));
+        let trained = FieldValue::SkillEntry {
+            skill_id: "sleight_of_hand".to_string(),
+            proficient: true,
+            bonus: 0,
+        };
+        sheet.set("SLEIGHT_OF_HAND_PROF", trained.clone());
+        sheet.set("ANIMAL_HANDLING_PROF", FieldValue::Boolean(true));
+
+        let aliases = SkillAliases::standard();
+        let converted = pf2e.convert_sheet(Some(&dnd), &sheet, &aliases);
+        assert_eq!(converted.sheet.get_text("NAME"), Some("Mira"));
+        assert_eq!(converted.sheet.get_number("STR"), Some(16));
+        assert!(converted.sheet.get("THIEVERY_RANK").is_some());
+        assert_eq!(converted.unconverted, vec!["Animal Handling".to_string()]);
+
+        // A score can't become a ladder rating
+        let converted = fate.convert_sheet(Some(&dnd), &sheet, &aliases);
+        assert_eq!(converted.sheet.get_text("NAME"), Some("Mira"));
+        assert!(converted.unconverted.contains(&"Strength".to_string()));
+    }
 }
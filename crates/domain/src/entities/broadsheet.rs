@@ -0,0 +1,290 @@
+//! Broadsheet entity - the world's weekly news digest
+//!
+//! Each game week the engine drafts a broadsheet from the story events and
+//! rumors of the week. The DM edits the draft and publishes it, at which
+//! point every player gets it as a handout. A published issue no longer
+//! changes.
+//!
+//! Game weeks are counted from the first day of the in-game year: days 1-7
+//! are the first week, days 8-14 the second, and so on.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_BROADSHEET]->(Broadsheet)`
+
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{BroadsheetId, WorldId};
+
+/// Longest broadsheet title or article headline, in characters
+pub const MAX_BROADSHEET_HEADLINE_LEN: usize = 200;
+
+/// Longest article body, in characters
+pub const MAX_BROADSHEET_ARTICLE_LEN: usize = 4000;
+
+/// Most articles in one issue
+pub const MAX_BROADSHEET_ARTICLES: usize = 12;
+
+/// Days in a game week
+pub const GAME_WEEK_DAYS: i64 = 7;
+
+/// One story in a broadsheet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadsheetArticle {
+    pub headline: String,
+    pub body: String,
+}
+
+impl BroadsheetArticle {
+    pub fn new(headline: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            headline: headline.into(),
+            body: body.into(),
+        }
+    }
+}
+
+/// An issue of the world's broadsheet, drafted or published
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Broadsheet {
+    pub id: BroadsheetId,
+    pub world_id: WorldId,
+    /// 1 for the world's first issue
+    pub issue_number: u32,
+    pub title: String,
+    pub articles: Vec<BroadsheetArticle>,
+    /// Game time the issue's news starts from
+    pub period_start: DateTime<Utc>,
+    /// Game time the issue's news runs to
+    pub period_end: DateTime<Utc>,
+    /// When the DM published it
+    pub published_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Broadsheet {
+    /// A draft covering the game time from `period_start` to `period_end`
+    pub fn new(
+        world_id: WorldId,
+        issue_number: u32,
+        title: impl Into<String>,
+        articles: Vec<BroadsheetArticle>,
+        period_start: DateTime<Utc>,
+        period_end: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        if period_end < period_start {
+            return Err(DomainError::validation(
+                "A broadsheet's period cannot end before it starts",
+            ));
+        }
+        Ok(Self {
+            id: BroadsheetId::new(),
+            world_id,
+            issue_number,
+            title: validate_title(title.into())?,
+            articles: validate_articles(articles)?,
+            period_start,
+            period_end,
+            published_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn is_published(&self) -> bool {
+        self.published_at.is_some()
+    }
+
+    /// Replace the draft's title and articles
+    pub fn edit(
+        &mut self,
+        title: impl Into<String>,
+        articles: Vec<BroadsheetArticle>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if self.is_published() {
+            return Err(DomainError::invalid_state_transition(
+                "This issue has already been published",
+            ));
+        }
+        self.title = validate_title(title.into())?;
+        self.articles = validate_articles(articles)?;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Hand the issue to the players
+    pub fn publish(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.is_published() {
+            return Err(DomainError::invalid_state_transition(
+                "This issue has already been published",
+            ));
+        }
+        if self.articles.is_empty() {
+            return Err(DomainError::validation(
+                "A broadsheet needs at least one article",
+            ));
+        }
+        self.published_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+/// Midnight on the first day of the game week `game_time` falls in
+pub fn game_week_start(game_time: DateTime<Utc>) -> DateTime<Utc> {
+    let day_in_week = i64::from((game_time.ordinal() - 1) % GAME_WEEK_DAYS as u32);
+    let midnight = game_time
+        - Duration::hours(i64::from(game_time.hour()))
+        - Duration::minutes(i64::from(game_time.minute()))
+        - Duration::seconds(i64::from(game_time.second()))
+        - Duration::nanoseconds(i64::from(game_time.nanosecond()));
+    midnight - Duration::days(day_in_week)
+}
+
+/// The game week number of `game_time` within its year, from 1
+pub fn game_week_number(game_time: DateTime<Utc>) -> u32 {
+    (game_time.ordinal() - 1) / GAME_WEEK_DAYS as u32 + 1
+}
+
+/// Whether a game week ended while the clock moved from `before` to `after`
+pub fn game_week_ended(before: DateTime<Utc>, after: DateTime<Utc>) -> bool {
+    game_week_start(after) > game_week_start(before)
+}
+
+fn validate_title(title: String) -> Result<String, DomainError> {
+    let title = title.trim().to_string();
+    if title.is_empty() {
+        return Err(DomainError::validation("A broadsheet needs a title"));
+    }
+    if title.chars().count() > MAX_BROADSHEET_HEADLINE_LEN {
+        return Err(DomainError::validation(format!(
+            "Broadsheet title cannot exceed {} characters",
+            MAX_BROADSHEET_HEADLINE_LEN
+        )));
+    }
+    Ok(title)
+}
+
+fn validate_articles(
+    articles: Vec<BroadsheetArticle>,
+) -> Result<Vec<BroadsheetArticle>, DomainError> {
+    if articles.len() > MAX_BROADSHEET_ARTICLES {
+        return Err(DomainError::validation(format!(
+            "A broadsheet cannot have more than {} articles",
+            MAX_BROADSHEET_ARTICLES
+        )));
+    }
+    articles
+        .into_iter()
+        .map(|article| {
+            let headline = article.headline.trim().to_string();
+            let body = article.body.trim().to_string();
+            if headline.is_empty() {
+                return Err(DomainError::validation("Every article needs a headline"));
+            }
+            if headline.chars().count() > MAX_BROADSHEET_HEADLINE_LEN {
+                return Err(DomainError::validation(format!(
+                    "Headlines cannot exceed {} characters",
+                    MAX_BROADSHEET_HEADLINE_LEN
+                )));
+            }
+            if body.chars().count() > MAX_BROADSHEET_ARTICLE_LEN {
+                return Err(DomainError::validation(format!(
+                    "Articles cannot exceed {} characters",
+                    MAX_BROADSHEET_ARTICLE_LEN
+                )));
+            }
+            Ok(BroadsheetArticle { headline, body })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().into()
+    }
+
+    fn draft() -> Broadsheet {
+        Broadsheet::new(
+            WorldId::new(),
+            1,
+            "The Harbour Crier",
+            vec![BroadsheetArticle::new(
+                "Fire at the docks",
+                "Three warehouses lost.",
+            )],
+            at("2024-01-01T00:00:00Z"),
+            at("2024-01-08T00:00:00Z"),
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn game_weeks_start_every_seventh_day_of_the_year() {
+        assert_eq!(
+            game_week_start(at("2024-01-03T14:30:00Z")),
+            at("2024-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            game_week_start(at("2024-01-08T00:00:00Z")),
+            at("2024-01-08T00:00:00Z")
+        );
+        assert_eq!(game_week_number(at("2024-01-08T09:00:00Z")), 2);
+
+        assert!(!game_week_ended(
+            at("2024-01-02T08:00:00Z"),
+            at("2024-01-07T23:00:00Z")
+        ));
+        assert!(game_week_ended(
+            at("2024-01-07T23:00:00Z"),
+            at("2024-01-08T01:00:00Z")
+        ));
+    }
+
+    #[test]
+    fn drafts_can_be_edited_until_published() {
+        let mut issue = draft();
+        issue
+            .edit(
+                "The Harbour Crier",
+                vec![BroadsheetArticle::new(
+                    "  Docks ablaze ",
+                    "Four warehouses lost.",
+                )],
+                Utc::now(),
+            )
+            .unwrap();
+        assert_eq!(issue.articles[0].headline, "Docks ablaze");
+
+        issue.publish(Utc::now()).unwrap();
+        assert!(issue.is_published());
+        assert!(issue.edit("Late edition", vec![], Utc::now()).is_err());
+        assert!(issue.publish(Utc::now()).is_err());
+    }
+
+    #[test]
+    fn issues_need_a_title_and_articles_with_headlines() {
+        let mut issue = draft();
+        assert!(issue.edit("  ", vec![], Utc::now()).is_err());
+        assert!(issue
+            .edit(
+                "Crier",
+                vec![BroadsheetArticle::new("", "Body")],
+                Utc::now()
+            )
+            .is_err());
+
+        issue.edit("Crier", vec![], Utc::now()).unwrap();
+        assert!(issue.publish(Utc::now()).is_err());
+    }
+}
@@ -15,7 +15,7 @@
 //! The embedded fields `scene_id`, `skill_id`, and `prerequisite_challenges` are
 //! DEPRECATED and kept only for backward compatibility during migration.
 
-use crate::{ChallengeId, LocationId, RegionId, SceneId, WorldId};
+use crate::{ChallengeId, LocationId, ProgressClockId, RegionId, SceneId, SkillAliases, WorldId};
 use serde::{Deserialize, Serialize};
 
 // Re-export narrative resolution types from types module
@@ -81,6 +81,32 @@ impl Challenge {
         }
     }
 
+    /// A copy of this challenge, outcomes and triggers included, with a new ID
+    pub fn duplicate(&self) -> Self {
+        Self {
+            id: ChallengeId::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Whether this challenge is about noticing things, so lighting matters
+    ///
+    /// Goes by the checked stat or the name mentioning perception.
+    pub fn is_perception_check(&self) -> bool {
+        let mentions = |text: &str| text.to_lowercase().contains("perception");
+        self.check_stat.as_deref().is_some_and(mentions) || mentions(&self.name)
+    }
+
+    /// Point the check at the matching skill of another rule system
+    ///
+    /// Returns the skill now checked, or `None` (leaving the challenge as it
+    /// is) when it checks nothing or none of `skills` fits.
+    pub fn remap_skill(&mut self, aliases: &SkillAliases, skills: &[&str]) -> Option<String> {
+        let skill = aliases.resolve(self.check_stat.as_deref()?, skills)?;
+        self.check_stat = Some(skill.to_string());
+        Some(skill.to_string())
+    }
+
     /// Set the stat to check for this challenge.
     pub fn with_check_stat(mut self, stat: impl Into<String>) -> Self {
         self.check_stat = Some(stat.into());
@@ -229,6 +255,28 @@ impl Challenge {
         self.evaluate_roll_narrative(roll, modifier, None, None, None, None)
     }
 
+    /// Chance, in whole percent, that a roll with `modifier` succeeds
+    ///
+    /// Every face of the difficulty's suggested dice is put through
+    /// `evaluate_roll`, so the odds follow the same rules as the real roll
+    /// (partial successes count). `None` for opposed and custom difficulties,
+    /// which the DM adjudicates.
+    pub fn success_chance(&self, modifier: i32) -> Option<u8> {
+        let rolls: Vec<i32> = match self.difficulty {
+            Difficulty::DC(_) => (1..=20).collect(),
+            Difficulty::Percentage(_) => (1..=100).collect(),
+            Difficulty::Descriptor(_) => {
+                (1..=6).flat_map(|a| (1..=6).map(move |b| a + b)).collect()
+            }
+            Difficulty::Opposed | Difficulty::Custom(_) => return None,
+        };
+        let successes = rolls
+            .iter()
+            .filter(|roll| self.evaluate_roll(**roll, modifier).0.is_success())
+            .count();
+        Some((successes * 100 / rolls.len()) as u8)
+    }
+
     /// Evaluate a dice roll with full narrative resolution support.
     ///
     /// # Arguments
@@ -428,7 +476,10 @@ impl Challenge {
     }
 
     /// Get the outcome reference for an outcome type
-    fn outcome_for_type(&self, outcome_type: OutcomeType) -> &Outcome {
+    ///
+    /// Critical and partial results fall back to the plain success or
+    /// failure outcome when the challenge has none of their own.
+    pub fn outcome_for_type(&self, outcome_type: OutcomeType) -> &Outcome {
         match outcome_type {
             OutcomeType::CriticalSuccess => self
                 .outcomes
@@ -620,6 +671,19 @@ impl ChallengeOutcomes {
         self.critical_failure = Some(Outcome::new(critical));
         self
     }
+
+    /// Every outcome that is set, success and failure first
+    pub fn all_mut(&mut self) -> impl Iterator<Item = &mut Outcome> {
+        [
+            Some(&mut self.success),
+            Some(&mut self.failure),
+            self.partial.as_mut(),
+            self.critical_success.as_mut(),
+            self.critical_failure.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+    }
 }
 
 /// A single outcome with narrative text and triggered effects
@@ -669,6 +733,11 @@ pub enum OutcomeTrigger {
         item_name: String,
         item_description: Option<String>,
     },
+    /// Fill (or clear, if negative) segments on a progress clock
+    TickClock {
+        clock_id: ProgressClockId,
+        amount: i32,
+    },
     /// Custom trigger with free-text description
     Custom { description: String },
 }
@@ -709,6 +778,9 @@ impl std::fmt::Display for OutcomeTrigger {
                     write!(f, "Give item: {}", item_name)
                 }
             }
+            Self::TickClock { clock_id, amount } => {
+                write!(f, "Tick clock {}: {:+}", clock_id, amount)
+            }
             Self::Custom { description } => {
                 write!(f, "Custom: {}", description)
             }
@@ -726,6 +798,7 @@ impl OutcomeTrigger {
             Self::ModifyCharacterStat { .. } => "modify_stat",
             Self::TriggerScene { .. } => "trigger_scene",
             Self::GiveItem { .. } => "give_item",
+            Self::TickClock { .. } => "tick_clock",
             Self::Custom { .. } => "custom",
         }
     }
@@ -760,6 +833,11 @@ impl OutcomeTrigger {
                 }
             }
             // EnableChallenge, DisableChallenge, and TriggerScene have typed IDs that are always valid
+            Self::TickClock { amount, .. } => {
+                if *amount == 0 {
+                    return Err("TickClock trigger requires a non-zero amount".to_string());
+                }
+            }
             Self::EnableChallenge { .. }
             | Self::DisableChallenge { .. }
             | Self::TriggerScene { .. } => {}
@@ -810,6 +888,10 @@ impl OutcomeTrigger {
     pub fn scene(scene_id: SceneId) -> Self {
         Self::TriggerScene { scene_id }
     }
+
+    pub fn tick_clock(clock_id: ProgressClockId, amount: i32) -> Self {
+        Self::TickClock { clock_id, amount }
+    }
 }
 
 /// Condition that triggers LLM to suggest a challenge
@@ -1185,6 +1267,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn skills_are_remapped_through_their_aliases() {
+        let aliases = SkillAliases::standard();
+        let fate = ["Athletics", "Notice", "Investigate", "Will"];
+        let mut challenge =
+            Challenge::new(WorldId::new(), "Spot the thief", Difficulty::d20_medium())
+                .with_check_stat("Perception");
+
+        assert_eq!(
+            challenge.remap_skill(&aliases, &fate).as_deref(),
+            Some("Notice")
+        );
+        assert_eq!(challenge.check_stat.as_deref(), Some("Notice"));
+        // An exact match wins over an alias
+        assert_eq!(
+            challenge
+                .clone()
+                .with_check_stat("athletics")
+                .remap_skill(&aliases, &fate)
+                .as_deref(),
+            Some("Athletics")
+        );
+
+        let mut unknown = challenge.clone().with_check_stat("Pilot");
+        assert_eq!(unknown.remap_skill(&aliases, &fate), None);
+        assert_eq!(unknown.check_stat.as_deref(), Some("Pilot"));
+    }
+
+    #[test]
+    fn perception_checks_are_spotted_by_stat_or_name() {
+        let world_id = WorldId::new();
+        let difficulty = Difficulty::d20_medium();
+
+        assert!(
+            Challenge::new(world_id, "Spot the thief", difficulty.clone())
+                .with_check_stat("PERCEPTION_MOD")
+                .is_perception_check()
+        );
+        assert!(
+            Challenge::new(world_id, "Perception: the crypt", difficulty.clone())
+                .is_perception_check()
+        );
+        assert!(!Challenge::new(world_id, "Climb the wall", difficulty)
+            .with_check_stat("STR")
+            .is_perception_check());
+    }
+
     #[test]
     fn test_trigger_condition_matching() {
         let trigger = TriggerCondition::new(
@@ -1304,6 +1433,28 @@ mod tests {
         assert_eq!(outcome_type, OutcomeType::Failure);
     }
 
+    #[test]
+    fn test_success_chance() {
+        let world_id = WorldId::new();
+        let outcomes = ChallengeOutcomes::simple("Success!", "Failure!");
+        let chance = |difficulty: Difficulty, modifier: i32| {
+            Challenge::new(world_id, "Test", difficulty)
+                .with_outcomes(outcomes.clone())
+                .success_chance(modifier)
+        };
+
+        // d20 + 3 >= 15 on 12 through 20
+        assert_eq!(chance(Difficulty::DC(15), 3), Some(45));
+        assert_eq!(chance(Difficulty::DC(30), 0), Some(0));
+        assert_eq!(chance(Difficulty::Percentage(45), 0), Some(45));
+        // 2d6 totals of 7 or more, partial successes included
+        assert_eq!(
+            chance(Difficulty::Descriptor(DifficultyDescriptor::Moderate), 0),
+            Some(58)
+        );
+        assert_eq!(chance(Difficulty::Opposed, 0), None);
+    }
+
     #[test]
     fn test_difficulty_dice_suggestion() {
         assert_eq!(Difficulty::DC(15).dice_suggestion().0, "1d20");
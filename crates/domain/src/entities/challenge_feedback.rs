@@ -0,0 +1,280 @@
+//! Challenge suggestion feedback - what the DM did with LLM challenge suggestions
+//!
+//! Every accept, reject or change of difficulty is kept together with the
+//! suggestion and the exchange that prompted it. [`SuggestionPreferences`]
+//! sums up a world's recent decisions into a note for the dialogue prompt, so
+//! the LLM's suggestions drift towards the challenges and difficulties this
+//! table actually uses.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_SUGGESTION_DECISION]->(SuggestionDecision)`
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::ids::{ChallengeId, WorldId};
+use crate::value_objects::ChallengeSuggestion;
+
+/// How many of a world's latest decisions shape its preferences
+pub const MAX_SUGGESTION_DECISIONS_CONSIDERED: usize = 50;
+
+/// Rejections it takes before a pattern counts as unwanted
+const REJECTIONS_TO_AVOID: u32 = 2;
+
+/// What the DM did with a suggestion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "verdict")]
+pub enum SuggestionVerdict {
+    Accepted,
+    Rejected,
+    /// Accepted at a difficulty the DM picked
+    Modified {
+        difficulty: String,
+    },
+}
+
+/// A DM decision on an LLM challenge suggestion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestionDecision {
+    /// The approval item the suggestion arrived in
+    pub id: Uuid,
+    pub world_id: WorldId,
+    pub challenge_id: Option<ChallengeId>,
+    pub challenge_name: String,
+    pub skill_name: String,
+    /// The difficulty as suggested, e.g. "DC 15"
+    pub difficulty: String,
+    /// The LLM's confidence: "high", "medium" or "low"
+    pub confidence: String,
+    pub reasoning: String,
+    /// The NPC the exchange was with
+    pub npc_name: String,
+    /// What the player said that prompted the suggestion
+    pub player_dialogue: Option<String>,
+    pub verdict: SuggestionVerdict,
+    pub decided_at: DateTime<Utc>,
+}
+
+impl SuggestionDecision {
+    pub fn new(
+        id: Uuid,
+        world_id: WorldId,
+        suggestion: &ChallengeSuggestion,
+        verdict: SuggestionVerdict,
+        decided_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            world_id,
+            challenge_id: Uuid::parse_str(&suggestion.challenge_id)
+                .ok()
+                .map(ChallengeId::from),
+            challenge_name: suggestion.challenge_name.clone(),
+            skill_name: suggestion.skill_name.clone(),
+            difficulty: suggestion.difficulty_display.clone(),
+            confidence: suggestion.confidence.clone(),
+            reasoning: suggestion.reasoning.clone(),
+            npc_name: String::new(),
+            player_dialogue: None,
+            verdict,
+            decided_at,
+        }
+    }
+
+    /// Record the exchange that prompted the suggestion
+    pub fn with_context(
+        mut self,
+        npc_name: impl Into<String>,
+        player_dialogue: Option<String>,
+    ) -> Self {
+        self.npc_name = npc_name.into();
+        self.player_dialogue = player_dialogue;
+        self
+    }
+
+    /// The difficulty the challenge went ahead at, if it did
+    pub fn settled_difficulty(&self) -> Option<&str> {
+        match &self.verdict {
+            SuggestionVerdict::Accepted => Some(&self.difficulty),
+            SuggestionVerdict::Modified { difficulty } => Some(difficulty),
+            SuggestionVerdict::Rejected => None,
+        }
+    }
+}
+
+/// What a world's DM tends to do with challenge suggestions
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SuggestionPreferences {
+    pub accepted: u32,
+    pub modified: u32,
+    pub rejected: u32,
+    /// Difficulties challenges went ahead at, most used first
+    pub preferred_difficulties: Vec<String>,
+    /// Challenges the DM keeps turning down
+    pub rejected_challenges: Vec<String>,
+    /// Confidence levels the DM keeps turning down
+    pub rejected_confidence: Vec<String>,
+}
+
+impl SuggestionPreferences {
+    /// Sum up decisions into preferences
+    pub fn from_decisions(decisions: &[SuggestionDecision]) -> Self {
+        let mut prefs = Self::default();
+        let mut difficulties: HashMap<&str, u32> = HashMap::new();
+        // (taken, rejected) per challenge and per confidence level
+        let mut challenges: HashMap<&str, (u32, u32)> = HashMap::new();
+        let mut confidence: HashMap<&str, (u32, u32)> = HashMap::new();
+
+        for decision in decisions {
+            let rejected = decision.verdict == SuggestionVerdict::Rejected;
+            match decision.verdict {
+                SuggestionVerdict::Accepted => prefs.accepted += 1,
+                SuggestionVerdict::Modified { .. } => prefs.modified += 1,
+                SuggestionVerdict::Rejected => prefs.rejected += 1,
+            }
+            if let Some(difficulty) = decision.settled_difficulty() {
+                *difficulties.entry(difficulty).or_default() += 1;
+            }
+            for (tally, key) in [
+                (&mut challenges, decision.challenge_name.as_str()),
+                (&mut confidence, decision.confidence.as_str()),
+            ] {
+                let counts = tally.entry(key).or_default();
+                if rejected {
+                    counts.1 += 1;
+                } else {
+                    counts.0 += 1;
+                }
+            }
+        }
+
+        let mut difficulties: Vec<(&str, u32)> = difficulties.into_iter().collect();
+        difficulties.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        prefs.preferred_difficulties = difficulties
+            .into_iter()
+            .take(3)
+            .map(|(d, _)| d.to_string())
+            .collect();
+        prefs.rejected_challenges = unwanted(challenges);
+        prefs.rejected_confidence = unwanted(confidence);
+        prefs
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accepted + self.modified + self.rejected == 0
+    }
+
+    /// Guidance for the LLM on what to suggest, `None` without any decisions
+    pub fn prompt_note(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut note = format!(
+            "How the DM has handled your challenge suggestions: {} accepted, {} accepted at another difficulty, {} rejected.",
+            self.accepted, self.modified, self.rejected
+        );
+        if !self.preferred_difficulties.is_empty() {
+            note.push_str(&format!(
+                "\nChallenges usually go ahead at: {}.",
+                self.preferred_difficulties.join(", ")
+            ));
+        }
+        if !self.rejected_challenges.is_empty() {
+            note.push_str(&format!(
+                "\nThe DM keeps turning down: {}. Only suggest these with a strong reason.",
+                self.rejected_challenges.join(", ")
+            ));
+        }
+        if !self.rejected_confidence.is_empty() {
+            note.push_str(&format!(
+                "\nSuggestions you rate {} confidence are usually rejected; hold back unless you're sure.",
+                self.rejected_confidence.join(" or ")
+            ));
+        }
+        Some(note)
+    }
+}
+
+/// Keys rejected often, and more often than taken, in name order
+fn unwanted(tally: HashMap<&str, (u32, u32)>) -> Vec<String> {
+    let mut keys: Vec<String> = tally
+        .into_iter()
+        .filter(|(key, (taken, rejected))| {
+            !key.is_empty() && *rejected >= REJECTIONS_TO_AVOID && rejected > taken
+        })
+        .map(|(key, _)| key.to_string())
+        .collect();
+    keys.sort();
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decision(name: &str, confidence: &str, verdict: SuggestionVerdict) -> SuggestionDecision {
+        let suggestion = ChallengeSuggestion {
+            challenge_id: ChallengeId::new().to_string(),
+            challenge_name: name.to_string(),
+            skill_name: "Perception".to_string(),
+            difficulty_display: "DC 15".to_string(),
+            confidence: confidence.to_string(),
+            reasoning: "The player looked around".to_string(),
+            target_pc_id: None,
+            outcomes: None,
+        };
+        SuggestionDecision::new(
+            Uuid::new_v4(),
+            WorldId::new(),
+            &suggestion,
+            verdict,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn no_decisions_means_no_note() {
+        assert_eq!(
+            SuggestionPreferences::from_decisions(&[]).prompt_note(),
+            None
+        );
+    }
+
+    #[test]
+    fn preferences_follow_what_the_dm_did() {
+        let decisions = vec![
+            decision("Spot the ambush", "high", SuggestionVerdict::Accepted),
+            decision(
+                "Spot the ambush",
+                "high",
+                SuggestionVerdict::Modified {
+                    difficulty: "DC 12".to_string(),
+                },
+            ),
+            decision(
+                "Climb the wall",
+                "medium",
+                SuggestionVerdict::Modified {
+                    difficulty: "DC 12".to_string(),
+                },
+            ),
+            decision("Haggle", "low", SuggestionVerdict::Rejected),
+            decision("Haggle", "low", SuggestionVerdict::Rejected),
+            decision("Climb the wall", "medium", SuggestionVerdict::Rejected),
+        ];
+
+        let prefs = SuggestionPreferences::from_decisions(&decisions);
+        assert_eq!((prefs.accepted, prefs.modified, prefs.rejected), (1, 2, 3));
+        assert_eq!(prefs.preferred_difficulties, vec!["DC 12", "DC 15"]);
+        assert_eq!(prefs.rejected_challenges, vec!["Haggle"]);
+        assert_eq!(prefs.rejected_confidence, vec!["low"]);
+
+        let note = prefs.prompt_note().expect("note");
+        assert!(note.contains("DC 12, DC 15"));
+        assert!(note.contains("turning down: Haggle"));
+    }
+}
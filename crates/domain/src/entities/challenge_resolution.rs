@@ -0,0 +1,130 @@
+//! Challenge resolution - the record left by every resolved challenge
+//!
+//! When the DM approves a challenge outcome, what went into the roll, the
+//! roll itself, the outcome and the triggers it ran are kept, so the DM can
+//! look back at a session and see how the dice have treated each PC.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_CHALLENGE_RESOLUTION]->(ChallengeResolution)`
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::challenge::{OutcomeTrigger, OutcomeType};
+use crate::ids::{ChallengeId, PlayerCharacterId, WorldId};
+
+/// Most resolutions returned for one history query
+pub const MAX_CHALLENGE_HISTORY: usize = 200;
+
+/// A resolved challenge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeResolution {
+    /// The approval item the outcome was decided in
+    pub id: Uuid,
+    pub world_id: WorldId,
+    pub challenge_id: ChallengeId,
+    pub challenge_name: String,
+    /// The stat the roll was checked against, if any
+    pub skill_name: Option<String>,
+    /// The difficulty at resolution, e.g. "DC 15"
+    pub difficulty: String,
+    pub pc_id: PlayerCharacterId,
+    pub character_name: String,
+    /// The raw die result
+    pub roll: i32,
+    pub modifier: i32,
+    pub total: i32,
+    pub roll_breakdown: Option<String>,
+    pub outcome: OutcomeType,
+    /// The outcome as narrated, after any DM edit
+    pub outcome_description: String,
+    /// Whether the DM rewrote the outcome description
+    pub edited: bool,
+    /// The outcome's triggers, in the order they ran
+    pub triggers: Vec<OutcomeTrigger>,
+    pub resolved_at: DateTime<Utc>,
+}
+
+/// Totals over a set of resolutions
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChallengeHistoryStats {
+    pub resolved: u32,
+    /// Successes of any kind, partial ones included
+    pub succeeded: u32,
+    pub critical_successes: u32,
+    pub critical_failures: u32,
+    /// Mean raw die result, `None` without resolutions
+    pub average_roll: Option<f32>,
+}
+
+impl ChallengeHistoryStats {
+    pub fn from_resolutions(resolutions: &[ChallengeResolution]) -> Self {
+        let mut stats = Self::default();
+        let mut roll_sum = 0i64;
+        for resolution in resolutions {
+            stats.resolved += 1;
+            roll_sum += i64::from(resolution.roll);
+            if resolution.outcome.is_success() {
+                stats.succeeded += 1;
+            }
+            match resolution.outcome {
+                OutcomeType::CriticalSuccess => stats.critical_successes += 1,
+                OutcomeType::CriticalFailure => stats.critical_failures += 1,
+                _ => {}
+            }
+        }
+        if stats.resolved > 0 {
+            stats.average_roll = Some(roll_sum as f32 / stats.resolved as f32);
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolution(roll: i32, outcome: OutcomeType) -> ChallengeResolution {
+        ChallengeResolution {
+            id: Uuid::new_v4(),
+            world_id: WorldId::new(),
+            challenge_id: ChallengeId::new(),
+            challenge_name: "Pick the lock".to_string(),
+            skill_name: Some("Sleight of Hand".to_string()),
+            difficulty: "DC 15".to_string(),
+            pc_id: PlayerCharacterId::new(),
+            character_name: "Wren".to_string(),
+            roll,
+            modifier: 2,
+            total: roll + 2,
+            roll_breakdown: None,
+            outcome,
+            outcome_description: String::new(),
+            edited: false,
+            triggers: vec![],
+            resolved_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn stats_total_the_history() {
+        assert_eq!(
+            ChallengeHistoryStats::from_resolutions(&[]),
+            ChallengeHistoryStats::default()
+        );
+
+        let stats = ChallengeHistoryStats::from_resolutions(&[
+            resolution(20, OutcomeType::CriticalSuccess),
+            resolution(14, OutcomeType::Success),
+            resolution(9, OutcomeType::Partial),
+            resolution(1, OutcomeType::CriticalFailure),
+        ]);
+        assert_eq!(stats.resolved, 4);
+        assert_eq!(stats.succeeded, 3);
+        assert_eq!(stats.critical_successes, 1);
+        assert_eq!(stats.critical_failures, 1);
+        assert_eq!(stats.average_roll, Some(11.0));
+    }
+}
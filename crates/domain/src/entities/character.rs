@@ -13,6 +13,7 @@
 use crate::value_objects::{
     ArchetypeChange, CampbellArchetype, DispositionLevel, ExpressionConfig, MoodState,
 };
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use wrldbldr_domain::{CharacterId, WorldId};
 
@@ -43,6 +44,9 @@ pub struct Character {
     // Character state
     pub is_alive: bool,
     pub is_active: bool,
+    /// Birthdate on the game calendar; ages are computed from it
+    #[serde(default)]
+    pub birth_date: Option<NaiveDate>,
 
     /// Default disposition for this NPC (used when no PC-specific disposition is set)
     pub default_disposition: DispositionLevel,
@@ -71,12 +75,21 @@ impl Character {
             stats: StatBlock::default(),
             is_alive: true,
             is_active: true,
+            birth_date: None,
             default_disposition: DispositionLevel::Neutral,
             default_mood: MoodState::default(),
             expression_config: ExpressionConfig::default(),
         }
     }
 
+    /// A copy of this character with a new ID
+    pub fn duplicate(&self) -> Self {
+        Self {
+            id: CharacterId::new(),
+            ..self.clone()
+        }
+    }
+
     pub fn with_default_disposition(mut self, disposition: DispositionLevel) -> Self {
         self.default_disposition = disposition;
         self
@@ -107,6 +120,16 @@ impl Character {
         self
     }
 
+    pub fn with_birth_date(mut self, birth_date: NaiveDate) -> Self {
+        self.birth_date = Some(birth_date);
+        self
+    }
+
+    /// Age in whole years on `date`, or None without a birthdate or before birth
+    pub fn age_on(&self, date: NaiveDate) -> Option<u32> {
+        date.years_since(self.birth_date?)
+    }
+
     /// Change the character's current archetype
     pub fn change_archetype(
         &mut self,
@@ -0,0 +1,208 @@
+//! Companion entity - an NPC travelling with a player character
+//!
+//! Hirelings, sidekicks and animal companions: a companion binds one NPC to
+//! one PC. By default the NPC goes where the PC goes, so they are in every
+//! region the PC enters and are staged alongside them. A companion told to
+//! stay put keeps their last region until they are told to follow again.
+//!
+//! Loyalty is a 0-100 score the DM keeps, and wages build up per game day
+//! like property upkeep: the companion remembers the game time they are paid
+//! through, and paying settles whole days only.
+//!
+//! # Neo4j Relationships
+//! - `(PlayerCharacter)-[:HAS_COMPANION]->(Companion)`
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{CharacterId, CompanionId, PlayerCharacterId, WorldId};
+
+/// Highest loyalty score
+pub const MAX_LOYALTY: u8 = 100;
+
+/// Loyalty of a newly hired companion
+pub const DEFAULT_LOYALTY: u8 = 50;
+
+/// Largest daily wage a companion can earn
+pub const MAX_DAILY_WAGE: u32 = 100_000;
+
+/// Most companions one PC can have
+pub const MAX_COMPANIONS_PER_PC: usize = 12;
+
+/// An NPC bound to a player character
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Companion {
+    pub id: CompanionId,
+    pub world_id: WorldId,
+    pub pc_id: PlayerCharacterId,
+    pub character_id: CharacterId,
+    /// 0 is about to walk out, 100 would die for the PC
+    pub loyalty: u8,
+    /// Pay per game day, in the world's currency
+    pub wage_per_day: u32,
+    /// Whether the companion moves with the PC
+    pub follows_pc: bool,
+    /// Game time up to which wages have been paid
+    pub wages_paid_through: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Companion {
+    /// A companion hired at game time `hired_at`, following the PC and paid
+    /// up until then
+    pub fn new(
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        character_id: CharacterId,
+        hired_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: CompanionId::new(),
+            world_id,
+            pc_id,
+            character_id,
+            loyalty: DEFAULT_LOYALTY,
+            wage_per_day: 0,
+            follows_pc: true,
+            wages_paid_through: hired_at,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn with_loyalty(mut self, loyalty: u8) -> Result<Self, DomainError> {
+        self.loyalty = validate_loyalty(loyalty)?;
+        Ok(self)
+    }
+
+    pub fn with_wage(mut self, per_day: u32) -> Result<Self, DomainError> {
+        self.wage_per_day = validate_wage(per_day)?;
+        Ok(self)
+    }
+
+    pub fn with_follows_pc(mut self, follows_pc: bool) -> Self {
+        self.follows_pc = follows_pc;
+        self
+    }
+
+    /// Change whichever of the DM-set fields are given
+    pub fn update(
+        &mut self,
+        loyalty: Option<u8>,
+        wage_per_day: Option<u32>,
+        follows_pc: Option<bool>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let loyalty = loyalty.map(validate_loyalty).transpose()?;
+        let wage_per_day = wage_per_day.map(validate_wage).transpose()?;
+        if let Some(loyalty) = loyalty {
+            self.loyalty = loyalty;
+        }
+        if let Some(wage_per_day) = wage_per_day {
+            self.wage_per_day = wage_per_day;
+        }
+        if let Some(follows_pc) = follows_pc {
+            self.follows_pc = follows_pc;
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Whole game days of wages owed at game time `game_now`
+    pub fn days_owed(&self, game_now: DateTime<Utc>) -> u32 {
+        let days = (game_now - self.wages_paid_through).num_days();
+        u32::try_from(days.max(0)).unwrap_or(u32::MAX)
+    }
+
+    /// Wages owed at game time `game_now`
+    pub fn wages_due(&self, game_now: DateTime<Utc>) -> u64 {
+        self.days_owed(game_now) as u64 * self.wage_per_day as u64
+    }
+
+    /// Mark the owed days paid, keeping any part day towards the next one.
+    /// Returns what was settled.
+    pub fn settle_wages(&mut self, game_now: DateTime<Utc>, now: DateTime<Utc>) -> u64 {
+        let days = self.days_owed(game_now);
+        let due = self.wages_due(game_now);
+        self.wages_paid_through += Duration::days(days as i64);
+        self.updated_at = now;
+        due
+    }
+}
+
+fn validate_loyalty(loyalty: u8) -> Result<u8, DomainError> {
+    if loyalty > MAX_LOYALTY {
+        return Err(DomainError::validation(format!(
+            "Loyalty cannot exceed {}",
+            MAX_LOYALTY
+        )));
+    }
+    Ok(loyalty)
+}
+
+fn validate_wage(per_day: u32) -> Result<u32, DomainError> {
+    if per_day > MAX_DAILY_WAGE {
+        return Err(DomainError::validation(format!(
+            "Wages cannot exceed {} per day",
+            MAX_DAILY_WAGE
+        )));
+    }
+    Ok(per_day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hireling(hired_at: DateTime<Utc>) -> Companion {
+        Companion::new(
+            WorldId::new(),
+            PlayerCharacterId::new(),
+            CharacterId::new(),
+            hired_at,
+            Utc::now(),
+        )
+        .with_wage(2)
+        .unwrap()
+    }
+
+    #[test]
+    fn new_companions_follow_with_middling_loyalty() {
+        let companion = hireling(Utc::now());
+        assert!(companion.follows_pc);
+        assert_eq!(companion.loyalty, DEFAULT_LOYALTY);
+    }
+
+    #[test]
+    fn wages_settle_whole_days_only() {
+        let hired = Utc::now();
+        let mut companion = hireling(hired);
+        let game_now = hired + Duration::hours(60);
+
+        assert_eq!(companion.wages_due(game_now), 4);
+        assert_eq!(companion.settle_wages(game_now, Utc::now()), 4);
+        assert_eq!(companion.wages_due(game_now), 0);
+        assert_eq!(companion.wages_paid_through, hired + Duration::days(2));
+    }
+
+    #[test]
+    fn update_changes_only_given_fields() {
+        let mut companion = hireling(Utc::now());
+        companion
+            .update(Some(80), None, Some(false), Utc::now())
+            .unwrap();
+        assert_eq!(companion.loyalty, 80);
+        assert_eq!(companion.wage_per_day, 2);
+        assert!(!companion.follows_pc);
+
+        assert!(companion
+            .update(Some(MAX_LOYALTY + 1), Some(1), None, Utc::now())
+            .is_err());
+        // A rejected update changes nothing
+        assert_eq!(companion.wage_per_day, 2);
+    }
+}
@@ -0,0 +1,476 @@
+//! Content Draft entity - revisions of a text field before it goes live
+//!
+//! LLM suggestions rarely land right the first time. A draft collects the
+//! generated and hand-edited versions of one text field (an NPC's
+//! description, a location's atmosphere) so the DM can compare them and pick
+//! the one that becomes canonical. Publishing a revision writes its text to
+//! the entity; until then, the entity keeps whatever it had.
+//!
+//! A draft is `Published` while its newest revision is the one on the
+//! entity, and back to `Draft` as soon as a newer revision is added.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_CONTENT_DRAFT]->(ContentDraft)`
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::DomainError;
+use crate::ids::{ContentDraftId, WorldId};
+use crate::types::EntityType;
+
+/// Most revisions a draft keeps; the oldest unpublished ones go first
+pub const MAX_DRAFT_REVISIONS: usize = 50;
+
+/// Longest revision text, in characters
+pub const MAX_DRAFT_TEXT_LEN: usize = 10_000;
+
+/// Above this many word pairs, a diff gives up on alignment and reports the
+/// whole text as replaced
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// The text field a draft is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DraftField {
+    /// Character or location description
+    Description,
+    /// Location atmosphere
+    Atmosphere,
+}
+
+impl DraftField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DraftField::Description => "description",
+            DraftField::Atmosphere => "atmosphere",
+        }
+    }
+
+    /// Whether an entity of `entity_type` has this field
+    pub fn applies_to(&self, entity_type: EntityType) -> bool {
+        matches!(
+            (self, entity_type),
+            (DraftField::Description, EntityType::Character)
+                | (DraftField::Description, EntityType::Location)
+                | (DraftField::Atmosphere, EntityType::Location)
+        )
+    }
+}
+
+impl std::str::FromStr for DraftField {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "description" => Ok(DraftField::Description),
+            "atmosphere" => Ok(DraftField::Atmosphere),
+            other => Err(DomainError::parse(format!(
+                "Unknown draft field: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Where a revision's text came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionSource {
+    /// An LLM suggestion
+    Generated,
+    /// Typed or edited by the DM
+    Edited,
+}
+
+/// Whether the newest revision is live on the entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DraftStatus {
+    Draft,
+    Published,
+}
+
+/// One version of the draft's text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftRevision {
+    /// 1-based; numbers are never reused, even after old revisions are pruned
+    pub number: u32,
+    pub text: String,
+    pub source: RevisionSource,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The revision history of one text field of one entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentDraft {
+    pub id: ContentDraftId,
+    pub world_id: WorldId,
+    pub entity_type: EntityType,
+    pub entity_id: Uuid,
+    pub field: DraftField,
+    /// Oldest first
+    pub revisions: Vec<DraftRevision>,
+    /// The revision whose text is on the entity, if any was published
+    pub published_revision: Option<u32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ContentDraft {
+    /// Start a draft with its first revision.
+    pub fn new(
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        field: DraftField,
+        text: impl Into<String>,
+        source: RevisionSource,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        if !field.applies_to(entity_type) {
+            return Err(DomainError::validation(format!(
+                "A {} has no {} to draft",
+                entity_type,
+                field.as_str()
+            )));
+        }
+        let mut draft = Self {
+            id: ContentDraftId::new(),
+            world_id,
+            entity_type,
+            entity_id,
+            field,
+            revisions: Vec::new(),
+            published_revision: None,
+            created_at: now,
+            updated_at: now,
+        };
+        draft.add_revision(text, source, now)?;
+        Ok(draft)
+    }
+
+    pub fn status(&self) -> DraftStatus {
+        match (self.published_revision, self.latest()) {
+            (Some(published), Some(latest)) if published == latest.number => DraftStatus::Published,
+            _ => DraftStatus::Draft,
+        }
+    }
+
+    pub fn latest(&self) -> Option<&DraftRevision> {
+        self.revisions.last()
+    }
+
+    pub fn revision(&self, number: u32) -> Option<&DraftRevision> {
+        self.revisions.iter().find(|r| r.number == number)
+    }
+
+    /// Add a revision, returning its number.
+    ///
+    /// Text identical to the newest revision adds nothing and returns that
+    /// revision's number. When the draft is full, the oldest revision that
+    /// isn't published is dropped.
+    pub fn add_revision(
+        &mut self,
+        text: impl Into<String>,
+        source: RevisionSource,
+        now: DateTime<Utc>,
+    ) -> Result<u32, DomainError> {
+        let text = text.into().trim().to_string();
+        if text.is_empty() {
+            return Err(DomainError::validation("A revision needs some text"));
+        }
+        if text.chars().count() > MAX_DRAFT_TEXT_LEN {
+            return Err(DomainError::validation(format!(
+                "Revision text is limited to {} characters",
+                MAX_DRAFT_TEXT_LEN
+            )));
+        }
+        if let Some(latest) = self.latest() {
+            if latest.text == text {
+                return Ok(latest.number);
+            }
+        }
+
+        if self.revisions.len() >= MAX_DRAFT_REVISIONS {
+            let published = self.published_revision;
+            if let Some(index) = self
+                .revisions
+                .iter()
+                .position(|r| Some(r.number) != published)
+            {
+                self.revisions.remove(index);
+            }
+        }
+
+        let number = self.latest().map_or(1, |r| r.number + 1);
+        self.revisions.push(DraftRevision {
+            number,
+            text,
+            source,
+            created_at: now,
+        });
+        self.updated_at = now;
+        Ok(number)
+    }
+
+    /// Mark a revision as the one on the entity, returning its text.
+    ///
+    /// Any revision can be published, so this is also how the DM rolls back
+    /// to an earlier version.
+    pub fn publish(&mut self, number: u32, now: DateTime<Utc>) -> Result<&str, DomainError> {
+        let index = self
+            .revisions
+            .iter()
+            .position(|r| r.number == number)
+            .ok_or_else(|| DomainError::not_found("DraftRevision", number.to_string()))?;
+        self.published_revision = Some(number);
+        self.updated_at = now;
+        Ok(&self.revisions[index].text)
+    }
+
+    /// Word-level changes from revision `from` to revision `to`.
+    pub fn diff(&self, from: u32, to: u32) -> Result<Vec<DiffSegment>, DomainError> {
+        let old = self
+            .revision(from)
+            .ok_or_else(|| DomainError::not_found("DraftRevision", from.to_string()))?;
+        let new = self
+            .revision(to)
+            .ok_or_else(|| DomainError::not_found("DraftRevision", to.to_string()))?;
+        Ok(diff_words(&old.text, &new.text))
+    }
+}
+
+/// What happened to a stretch of text between two revisions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// A run of text with one [`DiffOp`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffSegment {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+/// Word-level diff of two texts.
+///
+/// Whitespace is kept, so the `Equal` and `Delete` segments join back into
+/// `old` and the `Equal` and `Insert` segments into `new`; a side-by-side
+/// view can render each column from the segments alone.
+pub fn diff_words(old: &str, new: &str) -> Vec<DiffSegment> {
+    let a = tokenize(old);
+    let b = tokenize(new);
+
+    // Common prefix and suffix don't need the table
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+
+    let mut segments = Vec::new();
+    push_all(&mut segments, DiffOp::Equal, &a[..prefix]);
+
+    if a_mid.len().saturating_mul(b_mid.len()) > MAX_DIFF_CELLS {
+        push_all(&mut segments, DiffOp::Delete, a_mid);
+        push_all(&mut segments, DiffOp::Insert, b_mid);
+    } else {
+        // lcs[i][j] = longest common subsequence of a_mid[i..] and b_mid[j..]
+        let (n, m) = (a_mid.len(), b_mid.len());
+        let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i * (m + 1) + j] = if a_mid[i] == b_mid[j] {
+                    lcs[(i + 1) * (m + 1) + j + 1] + 1
+                } else {
+                    lcs[(i + 1) * (m + 1) + j].max(lcs[i * (m + 1) + j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < n && j < m {
+            if a_mid[i] == b_mid[j] {
+                push(&mut segments, DiffOp::Equal, a_mid[i]);
+                i += 1;
+                j += 1;
+            } else if lcs[(i + 1) * (m + 1) + j] >= lcs[i * (m + 1) + j + 1] {
+                push(&mut segments, DiffOp::Delete, a_mid[i]);
+                i += 1;
+            } else {
+                push(&mut segments, DiffOp::Insert, b_mid[j]);
+                j += 1;
+            }
+        }
+        push_all(&mut segments, DiffOp::Delete, &a_mid[i..]);
+        push_all(&mut segments, DiffOp::Insert, &b_mid[j..]);
+    }
+
+    push_all(&mut segments, DiffOp::Equal, &a[a.len() - suffix..]);
+    segments
+}
+
+/// Split text into alternating runs of whitespace and non-whitespace.
+fn tokenize(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut in_space = None;
+    for (index, c) in text.char_indices() {
+        let space = c.is_whitespace();
+        if in_space.is_some_and(|s| s != space) {
+            tokens.push(&text[start..index]);
+            start = index;
+        }
+        in_space = Some(space);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+fn push(segments: &mut Vec<DiffSegment>, op: DiffOp, token: &str) {
+    match segments.last_mut() {
+        Some(last) if last.op == op => last.text.push_str(token),
+        _ => segments.push(DiffSegment {
+            op,
+            text: token.to_string(),
+        }),
+    }
+}
+
+fn push_all(segments: &mut Vec<DiffSegment>, op: DiffOp, tokens: &[&str]) {
+    for token in tokens {
+        push(segments, op, token);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draft(text: &str) -> ContentDraft {
+        ContentDraft::new(
+            WorldId::new(),
+            EntityType::Location,
+            Uuid::new_v4(),
+            DraftField::Atmosphere,
+            text,
+            RevisionSource::Generated,
+            Utc::now(),
+        )
+        .expect("valid draft")
+    }
+
+    fn side(segments: &[DiffSegment], skip: DiffOp) -> String {
+        segments
+            .iter()
+            .filter(|s| s.op != skip)
+            .map(|s| s.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn publishing_the_newest_revision_publishes_the_draft_until_another_is_added() {
+        let mut draft = draft("Smoke hangs low over the tables.");
+        assert_eq!(draft.status(), DraftStatus::Draft);
+
+        let second = draft
+            .add_revision(
+                "Pipe smoke hangs low over the tables.",
+                RevisionSource::Edited,
+                Utc::now(),
+            )
+            .expect("added");
+        assert_eq!(second, 2);
+        assert_eq!(
+            draft.publish(2, Utc::now()).expect("published"),
+            "Pipe smoke hangs low over the tables."
+        );
+        assert_eq!(draft.status(), DraftStatus::Published);
+
+        draft
+            .add_revision(
+                "Pipe smoke and song.",
+                RevisionSource::Generated,
+                Utc::now(),
+            )
+            .expect("added");
+        assert_eq!(draft.status(), DraftStatus::Draft);
+        assert_eq!(draft.published_revision, Some(2));
+        assert!(draft.publish(9, Utc::now()).is_err());
+    }
+
+    #[test]
+    fn repeating_the_newest_text_adds_no_revision() {
+        let mut draft = draft("Cold stone.");
+        let number = draft
+            .add_revision("  Cold stone. ", RevisionSource::Edited, Utc::now())
+            .expect("added");
+        assert_eq!(number, 1);
+        assert_eq!(draft.revisions.len(), 1);
+    }
+
+    #[test]
+    fn a_full_draft_drops_its_oldest_unpublished_revision() {
+        let mut draft = draft("Version 1");
+        draft.publish(1, Utc::now()).expect("published");
+        for n in 2..=MAX_DRAFT_REVISIONS as u32 + 1 {
+            draft
+                .add_revision(format!("Version {}", n), RevisionSource::Edited, Utc::now())
+                .expect("added");
+        }
+        assert_eq!(draft.revisions.len(), MAX_DRAFT_REVISIONS);
+        assert!(draft.revision(1).is_some());
+        assert!(draft.revision(2).is_none());
+        assert_eq!(draft.latest().map(|r| r.number), Some(51));
+    }
+
+    #[test]
+    fn fields_must_belong_to_the_entity() {
+        assert!(ContentDraft::new(
+            WorldId::new(),
+            EntityType::Character,
+            Uuid::new_v4(),
+            DraftField::Atmosphere,
+            "Brooding",
+            RevisionSource::Edited,
+            Utc::now(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn a_word_diff_rebuilds_both_texts() {
+        let old = "The old mill creaks in the wind.";
+        let new = "The abandoned mill groans in the\nnight wind.";
+        let segments = diff_words(old, new);
+
+        assert_eq!(side(&segments, DiffOp::Insert), old);
+        assert_eq!(side(&segments, DiffOp::Delete), new);
+        assert_eq!(
+            segments.first(),
+            Some(&DiffSegment {
+                op: DiffOp::Equal,
+                text: "The ".to_string()
+            })
+        );
+        assert!(segments
+            .iter()
+            .any(|s| s.op == DiffOp::Delete && s.text.contains("creaks")));
+        assert!(segments
+            .iter()
+            .any(|s| s.op == DiffOp::Insert && s.text.contains("groans")));
+    }
+}
@@ -0,0 +1,202 @@
+//! Crowd entity - an unnamed group of background people in a region
+//!
+//! Dock workers, cultists at prayer, a market-day throng: scenes need people
+//! in them, but not dozens of full `Character` records. A crowd is one
+//! lightweight record with an approximate headcount and a collective
+//! disposition. The DM stages it in its region like an NPC; players see it
+//! in the scene while it's present.
+//!
+//! # Neo4j Relationships
+//! - `(Region)-[:HAS_CROWD]->(Crowd)`
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{CrowdId, RegionId, WorldId};
+use crate::types::DispositionLevel;
+
+/// Longest crowd name, in characters
+pub const MAX_CROWD_NAME_LEN: usize = 100;
+
+/// Largest approximate headcount a crowd can have
+pub const MAX_CROWD_COUNT: u32 = 10_000;
+
+/// An unnamed group of people, staged in a region as a whole
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Crowd {
+    pub id: CrowdId,
+    pub world_id: WorldId,
+    pub region_id: RegionId,
+    /// What players call them: "dock workers", "cultists"
+    pub name: String,
+    pub description: String,
+    /// Rough headcount; only its size band is shown to players
+    pub approximate_count: u32,
+    /// How the crowd as a whole regards the PCs
+    pub disposition: DispositionLevel,
+    /// Whether the crowd is currently staged in its region
+    pub is_present: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Crowd {
+    pub fn new(
+        world_id: WorldId,
+        region_id: RegionId,
+        name: impl Into<String>,
+        approximate_count: u32,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let name = validate_crowd_name(&name.into())?;
+        validate_crowd_count(approximate_count)?;
+        Ok(Self {
+            id: CrowdId::new(),
+            world_id,
+            region_id,
+            name,
+            description: String::new(),
+            approximate_count,
+            disposition: DispositionLevel::Neutral,
+            is_present: true,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn with_disposition(mut self, disposition: DispositionLevel) -> Self {
+        self.disposition = disposition;
+        self
+    }
+
+    /// Change the crowd's name, description, headcount and disposition
+    pub fn update(
+        &mut self,
+        name: &str,
+        description: impl Into<String>,
+        approximate_count: u32,
+        disposition: DispositionLevel,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let name = validate_crowd_name(name)?;
+        validate_crowd_count(approximate_count)?;
+        self.name = name;
+        self.description = description.into();
+        self.approximate_count = approximate_count;
+        self.disposition = disposition;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Stage or unstage the crowd; returns whether anything changed
+    pub fn set_present(&mut self, present: bool, now: DateTime<Utc>) -> bool {
+        if self.is_present == present {
+            return false;
+        }
+        self.is_present = present;
+        self.updated_at = now;
+        true
+    }
+
+    /// One member leaves to become a named NPC; returns whether the count
+    /// changed. The count never drops below two, so the crowd stays a crowd.
+    pub fn lose_member(&mut self, now: DateTime<Utc>) -> bool {
+        if self.approximate_count <= 2 {
+            return false;
+        }
+        self.approximate_count -= 1;
+        self.updated_at = now;
+        true
+    }
+
+    /// How many of them there are, in words
+    pub fn size_label(&self) -> &'static str {
+        crowd_size_label(self.approximate_count)
+    }
+}
+
+/// Headcount as players would estimate it at a glance
+pub fn crowd_size_label(count: u32) -> &'static str {
+    match count {
+        0..=5 => "a handful",
+        6..=20 => "a dozen or so",
+        21..=99 => "dozens",
+        100..=999 => "hundreds",
+        _ => "a throng",
+    }
+}
+
+fn validate_crowd_name(name: &str) -> Result<String, DomainError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(DomainError::validation("Crowd name cannot be empty"));
+    }
+    if name.chars().count() > MAX_CROWD_NAME_LEN {
+        return Err(DomainError::validation(format!(
+            "Crowd name cannot exceed {} characters",
+            MAX_CROWD_NAME_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+fn validate_crowd_count(count: u32) -> Result<(), DomainError> {
+    if !(2..=MAX_CROWD_COUNT).contains(&count) {
+        return Err(DomainError::validation(format!(
+            "A crowd has between 2 and {} people, got {}",
+            MAX_CROWD_COUNT, count
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_crowds_are_trimmed_staged_and_validated() {
+        let now = Utc::now();
+        let crowd = Crowd::new(WorldId::new(), RegionId::new(), "  dock workers ", 30, now)
+            .expect("valid crowd");
+
+        assert_eq!(crowd.name, "dock workers");
+        assert!(crowd.is_present);
+        assert_eq!(crowd.size_label(), "dozens");
+        assert!(Crowd::new(WorldId::new(), RegionId::new(), " ", 30, now).is_err());
+        assert!(Crowd::new(WorldId::new(), RegionId::new(), "a lone sailor", 1, now).is_err());
+        assert!(Crowd::new(WorldId::new(), RegionId::new(), "an army", 10_001, now).is_err());
+    }
+
+    #[test]
+    fn size_labels_cover_every_band() {
+        assert_eq!(crowd_size_label(2), "a handful");
+        assert_eq!(crowd_size_label(12), "a dozen or so");
+        assert_eq!(crowd_size_label(99), "dozens");
+        assert_eq!(crowd_size_label(100), "hundreds");
+        assert_eq!(crowd_size_label(5_000), "a throng");
+    }
+
+    #[test]
+    fn invalid_updates_leave_the_crowd_unchanged() {
+        let now = Utc::now();
+        let mut crowd =
+            Crowd::new(WorldId::new(), RegionId::new(), "cultists", 12, now).expect("valid crowd");
+
+        assert!(crowd
+            .update("cultists", "", 0, DispositionLevel::Hostile, now)
+            .is_err());
+        assert_eq!(crowd.approximate_count, 12);
+        assert_eq!(crowd.disposition, DispositionLevel::Neutral);
+
+        assert!(crowd.set_present(false, now));
+        assert!(!crowd.set_present(false, now));
+    }
+}
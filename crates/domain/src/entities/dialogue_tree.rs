@@ -0,0 +1,391 @@
+//! Dialogue tree entity - a DM-authored branching conversation
+//!
+//! Most NPC dialogue is generated and approved turn by turn. A dialogue tree
+//! is for the beats the DM wants word for word: the oracle's riddle, the
+//! captain's ultimatum. Each node is a line the speaker says and the choices
+//! the player can answer with; a choice leads to another node or ends the
+//! beat, and may set flags and run event effects on the way.
+//!
+//! A tree plays when a PC talks to its speaker, or, if it is attached to a
+//! narrative event, when that event triggers. The first node is where it
+//! starts. Unless the tree is repeatable, each PC hears it once.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_DIALOGUE_TREE]->(DialogueTree)`
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::EventEffect;
+use crate::error::DomainError;
+use crate::ids::{CharacterId, DialogueTreeId, NarrativeEventId, PlayerCharacterId, WorldId};
+
+/// Longest tree name, in characters
+pub const MAX_DIALOGUE_TREE_NAME_LEN: usize = 100;
+
+/// Most nodes a tree can hold
+pub const MAX_DIALOGUE_NODES: usize = 50;
+
+/// Most choices a node can offer
+pub const MAX_DIALOGUE_CHOICES: usize = 6;
+
+/// Longest node id, in characters
+pub const MAX_DIALOGUE_NODE_ID_LEN: usize = 40;
+
+/// Longest line a node's speaker says, in characters
+pub const MAX_DIALOGUE_LINE_LEN: usize = 2000;
+
+/// Longest choice text, in characters
+pub const MAX_DIALOGUE_CHOICE_LEN: usize = 200;
+
+/// A player's answer to a node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DialogueBranch {
+    pub text: String,
+    /// The node this choice leads to; `None` ends the beat
+    #[serde(default)]
+    pub next: Option<String>,
+    /// World flags set when the choice is taken
+    #[serde(default)]
+    pub set_flags: Vec<String>,
+    /// Effects run when the choice is taken, as for event outcomes
+    #[serde(default)]
+    pub effects: Vec<EventEffect>,
+}
+
+/// A line the speaker says and the choices the player can answer with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DialogueNode {
+    /// The DM's key for the node ("greeting", "riddle-2"), unique in its tree
+    pub id: String,
+    pub line: String,
+    /// No choices ends the beat after the line
+    #[serde(default)]
+    pub choices: Vec<DialogueBranch>,
+}
+
+impl DialogueNode {
+    /// The id players send back for the choice at `index`
+    pub fn choice_id(&self, index: usize) -> String {
+        format!("{}.{}", self.id, index + 1)
+    }
+
+    /// The choice a player sent back, if it belongs to this node
+    pub fn choice(&self, choice_id: &str) -> Option<&DialogueBranch> {
+        let (node_id, number) = choice_id.rsplit_once('.')?;
+        if node_id != self.id {
+            return None;
+        }
+        let index = number.parse::<usize>().ok()?.checked_sub(1)?;
+        self.choices.get(index)
+    }
+}
+
+/// A branching conversation the DM wrote for one NPC to speak
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DialogueTree {
+    pub id: DialogueTreeId,
+    pub world_id: WorldId,
+    pub name: String,
+    /// The NPC who says every line
+    pub speaker_id: CharacterId,
+    /// Plays when this event triggers instead of when a PC talks to the
+    /// speaker
+    pub narrative_event_id: Option<NarrativeEventId>,
+    /// The first node is where the beat starts
+    pub nodes: Vec<DialogueNode>,
+    /// Inactive trees are kept but never played
+    pub active: bool,
+    /// Repeatable trees play every time; others once per PC
+    pub repeatable: bool,
+    /// PCs who have heard the tree
+    pub played_by: Vec<PlayerCharacterId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl DialogueTree {
+    pub fn new(
+        world_id: WorldId,
+        name: impl Into<String>,
+        speaker_id: CharacterId,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let name = validate_tree_name(&name.into())?;
+        Ok(Self {
+            id: DialogueTreeId::new(),
+            world_id,
+            name,
+            speaker_id,
+            narrative_event_id: None,
+            nodes: Vec::new(),
+            active: true,
+            repeatable: false,
+            played_by: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn rename(&mut self, name: &str, now: DateTime<Utc>) -> Result<(), DomainError> {
+        self.name = validate_tree_name(name)?;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Replace the nodes. Ids, lines, choices and flags are trimmed and blank
+    /// flags dropped; every choice must lead to a node in the tree or end it.
+    pub fn set_nodes(
+        &mut self,
+        nodes: Vec<DialogueNode>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if nodes.is_empty() {
+            return Err(DomainError::validation(
+                "A dialogue tree needs at least one node",
+            ));
+        }
+        if nodes.len() > MAX_DIALOGUE_NODES {
+            return Err(DomainError::validation(format!(
+                "A dialogue tree can have at most {} nodes",
+                MAX_DIALOGUE_NODES
+            )));
+        }
+
+        let mut kept: Vec<DialogueNode> = Vec::with_capacity(nodes.len());
+        for node in nodes {
+            let id = node.id.trim().to_string();
+            if id.is_empty() || id.contains('.') {
+                return Err(DomainError::validation(
+                    "Node ids cannot be empty or contain '.'",
+                ));
+            }
+            if id.chars().count() > MAX_DIALOGUE_NODE_ID_LEN {
+                return Err(DomainError::validation(format!(
+                    "Node ids cannot exceed {} characters",
+                    MAX_DIALOGUE_NODE_ID_LEN
+                )));
+            }
+            if kept.iter().any(|n| n.id == id) {
+                return Err(DomainError::validation(format!(
+                    "Node '{}' appears twice",
+                    id
+                )));
+            }
+            let line = validate_text(&node.line, "Lines", MAX_DIALOGUE_LINE_LEN)?;
+            if node.choices.len() > MAX_DIALOGUE_CHOICES {
+                return Err(DomainError::validation(format!(
+                    "A node can offer at most {} choices",
+                    MAX_DIALOGUE_CHOICES
+                )));
+            }
+            let choices = node
+                .choices
+                .into_iter()
+                .map(|choice| {
+                    Ok(DialogueBranch {
+                        text: validate_text(&choice.text, "Choices", MAX_DIALOGUE_CHOICE_LEN)?,
+                        next: choice
+                            .next
+                            .map(|next| next.trim().to_string())
+                            .filter(|next| !next.is_empty()),
+                        set_flags: choice
+                            .set_flags
+                            .iter()
+                            .map(|f| f.trim())
+                            .filter(|f| !f.is_empty())
+                            .map(String::from)
+                            .collect(),
+                        effects: choice.effects,
+                    })
+                })
+                .collect::<Result<Vec<_>, DomainError>>()?;
+            kept.push(DialogueNode { id, line, choices });
+        }
+
+        for node in &kept {
+            for choice in &node.choices {
+                if let Some(next) = &choice.next {
+                    if !kept.iter().any(|n| &n.id == next) {
+                        return Err(DomainError::validation(format!(
+                            "A choice in '{}' leads to '{}', which isn't in the tree",
+                            node.id, next
+                        )));
+                    }
+                }
+            }
+        }
+
+        self.nodes = kept;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Where the beat starts
+    pub fn start(&self) -> Option<&DialogueNode> {
+        self.nodes.first()
+    }
+
+    pub fn node(&self, id: &str) -> Option<&DialogueNode> {
+        self.nodes.iter().find(|n| n.id == id)
+    }
+
+    /// Whether the tree would play for a PC now
+    pub fn can_play_for(&self, pc_id: PlayerCharacterId) -> bool {
+        self.active && !self.nodes.is_empty() && (self.repeatable || !self.has_played(pc_id))
+    }
+
+    pub fn has_played(&self, pc_id: PlayerCharacterId) -> bool {
+        self.played_by.contains(&pc_id)
+    }
+
+    /// Note that a PC heard the tree; returns whether anything changed
+    pub fn mark_played(&mut self, pc_id: PlayerCharacterId, now: DateTime<Utc>) -> bool {
+        if self.has_played(pc_id) {
+            return false;
+        }
+        self.played_by.push(pc_id);
+        self.updated_at = now;
+        true
+    }
+
+    /// Let every PC hear the tree again
+    pub fn reset_plays(&mut self, now: DateTime<Utc>) {
+        self.played_by.clear();
+        self.updated_at = now;
+    }
+}
+
+fn validate_tree_name(name: &str) -> Result<String, DomainError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(DomainError::validation(
+            "Dialogue tree name cannot be empty",
+        ));
+    }
+    if name.chars().count() > MAX_DIALOGUE_TREE_NAME_LEN {
+        return Err(DomainError::validation(format!(
+            "Dialogue tree name cannot exceed {} characters",
+            MAX_DIALOGUE_TREE_NAME_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+fn validate_text(text: &str, what: &str, max_len: usize) -> Result<String, DomainError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(DomainError::validation(format!("{} cannot be empty", what)));
+    }
+    if text.chars().count() > max_len {
+        return Err(DomainError::validation(format!(
+            "{} cannot exceed {} characters",
+            what, max_len
+        )));
+    }
+    Ok(text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn branch(text: &str, next: Option<&str>) -> DialogueBranch {
+        DialogueBranch {
+            text: text.to_string(),
+            next: next.map(String::from),
+            set_flags: Vec::new(),
+            effects: Vec::new(),
+        }
+    }
+
+    fn node(id: &str, line: &str, choices: Vec<DialogueBranch>) -> DialogueNode {
+        DialogueNode {
+            id: id.to_string(),
+            line: line.to_string(),
+            choices,
+        }
+    }
+
+    fn oracle() -> DialogueTree {
+        DialogueTree::new(
+            WorldId::new(),
+            " The Riddle ",
+            CharacterId::new(),
+            Utc::now(),
+        )
+        .expect("valid tree")
+    }
+
+    #[test]
+    fn choices_must_lead_to_nodes_in_the_tree() {
+        let now = Utc::now();
+        let mut tree = oracle();
+        assert_eq!(tree.name, "The Riddle");
+        assert!(tree.set_nodes(Vec::new(), now).is_err());
+
+        let dangling = vec![node("ask", "Speak.", vec![branch("Leave", Some("gone"))])];
+        assert!(tree.set_nodes(dangling, now).is_err());
+        let twice = vec![node("ask", "Speak.", vec![]), node("ask", "Again.", vec![])];
+        assert!(tree.set_nodes(twice, now).is_err());
+        assert!(tree
+            .set_nodes(vec![node("a.b", "Speak.", vec![])], now)
+            .is_err());
+
+        let mut answer = branch(" A shadow ", Some(" right "));
+        answer.set_flags = vec![" riddle_solved ".to_string(), " ".to_string()];
+        tree.set_nodes(
+            vec![
+                node(" ask ", " What follows you but never leads? ", vec![answer]),
+                node("right", "Pass.", vec![branch("Thank you", Some(""))]),
+            ],
+            now,
+        )
+        .unwrap();
+        let start = tree.start().unwrap();
+        assert_eq!(start.id, "ask");
+        assert_eq!(start.choices[0].next.as_deref(), Some("right"));
+        assert_eq!(start.choices[0].set_flags, vec!["riddle_solved"]);
+        assert_eq!(tree.node("right").unwrap().choices[0].next, None);
+    }
+
+    #[test]
+    fn choice_ids_belong_to_their_node() {
+        let ask = node(
+            "ask",
+            "Well?",
+            vec![branch("Yes", None), branch("No", None)],
+        );
+        assert_eq!(ask.choice_id(1), "ask.2");
+        assert_eq!(ask.choice("ask.2").unwrap().text, "No");
+        assert!(ask.choice("ask.3").is_none());
+        assert!(ask.choice("ask.0").is_none());
+        assert!(ask.choice("other.1").is_none());
+    }
+
+    #[test]
+    fn trees_play_once_per_pc_unless_repeatable() {
+        let now = Utc::now();
+        let mut tree = oracle();
+        let pc_id = PlayerCharacterId::new();
+        assert!(!tree.can_play_for(pc_id));
+
+        tree.set_nodes(vec![node("ask", "Speak.", vec![])], now)
+            .unwrap();
+        assert!(tree.can_play_for(pc_id));
+        assert!(tree.mark_played(pc_id, now));
+        assert!(!tree.mark_played(pc_id, now));
+        assert!(!tree.can_play_for(pc_id));
+
+        tree.repeatable = true;
+        assert!(tree.can_play_for(pc_id));
+        tree.repeatable = false;
+        tree.reset_plays(now);
+        assert!(tree.can_play_for(pc_id));
+        tree.active = false;
+        assert!(!tree.can_play_for(pc_id));
+    }
+}
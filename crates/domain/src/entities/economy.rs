@@ -0,0 +1,373 @@
+//! Market modifier entity - what goods cost, and how easily they're found,
+//! from one place to the next
+//!
+//! An item's base price is what it costs anywhere nothing special is going
+//! on. Market modifiers describe the exceptions: a blockade makes weapons
+//! scarce in the harbour town, a good harvest makes food cheap across the
+//! world. A modifier can be tied to a world flag, so that events which set
+//! the flag ("northern_war") change prices without the DM touching them.
+//!
+//! Merchant prices are never stored; they're worked out from the base price
+//! and whichever modifiers apply when someone asks.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_MARKET_MODIFIER]->(MarketModifier)`
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{LocationId, MarketModifierId, WorldId};
+
+/// Largest price adjustment a modifier can make, in percent either way
+pub const MAX_PRICE_ADJUSTMENT_PERCENT: i32 = 500;
+
+/// Longest reason or category, in characters
+pub const MAX_MARKET_TEXT_LEN: usize = 200;
+
+/// Cheapest a price adjustment can make goods, as a percentage of their price
+const MIN_PRICE_PERCENT: i32 = 10;
+
+/// How hard a kind of goods is to come by
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Scarcity {
+    Abundant,
+    #[default]
+    Common,
+    Scarce,
+    Rare,
+    /// Not for sale at any price
+    Unavailable,
+}
+
+impl Scarcity {
+    pub const ALL: [Scarcity; 5] = [
+        Scarcity::Abundant,
+        Scarcity::Common,
+        Scarcity::Scarce,
+        Scarcity::Rare,
+        Scarcity::Unavailable,
+    ];
+
+    /// What goods of this scarcity cost, as a percentage of their base
+    /// price, or `None` when they can't be bought
+    pub fn price_percent(self) -> Option<u32> {
+        match self {
+            Scarcity::Abundant => Some(75),
+            Scarcity::Common => Some(100),
+            Scarcity::Scarce => Some(150),
+            Scarcity::Rare => Some(250),
+            Scarcity::Unavailable => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Scarcity::Abundant => "abundant",
+            Scarcity::Common => "common",
+            Scarcity::Scarce => "scarce",
+            Scarcity::Rare => "rare",
+            Scarcity::Unavailable => "unavailable",
+        }
+    }
+}
+
+impl fmt::Display for Scarcity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Scarcity {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scarcity::ALL
+            .into_iter()
+            .find(|scarcity| scarcity.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| DomainError::parse(format!("Unknown scarcity: {}", s)))
+    }
+}
+
+/// An exception to normal prices, somewhere or everywhere
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketModifier {
+    pub id: MarketModifierId,
+    pub world_id: WorldId,
+    /// Where it applies; everywhere in the world when `None`
+    pub location_id: Option<LocationId>,
+    /// Which item type it applies to (matched ignoring case); all goods
+    /// when `None`
+    pub category: Option<String>,
+    /// How hard the goods become to find; leaves scarcity alone when `None`
+    pub scarcity: Option<Scarcity>,
+    /// Price change on top of scarcity: `20` is 20% dearer, `-30` is 30%
+    /// cheaper
+    pub price_adjustment: i32,
+    /// World flag the modifier waits for; always in effect when `None`
+    pub flag: Option<String>,
+    /// Why prices are different, in words merchants could use
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MarketModifier {
+    pub fn new(
+        world_id: WorldId,
+        reason: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        Ok(Self {
+            id: MarketModifierId::new(),
+            world_id,
+            location_id: None,
+            category: None,
+            scarcity: None,
+            price_adjustment: 0,
+            flag: None,
+            reason: validate_reason(&reason.into())?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn at_location(mut self, location_id: LocationId) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    pub fn for_category(mut self, category: impl Into<String>) -> Self {
+        self.category = clean_optional(Some(category.into()));
+        self
+    }
+
+    pub fn with_scarcity(mut self, scarcity: Scarcity) -> Self {
+        self.scarcity = Some(scarcity);
+        self
+    }
+
+    pub fn with_price_adjustment(mut self, percent: i32) -> Result<Self, DomainError> {
+        self.price_adjustment = validate_adjustment(percent)?;
+        Ok(self)
+    }
+
+    pub fn while_flag(mut self, flag: impl Into<String>) -> Self {
+        self.flag = clean_optional(Some(flag.into()));
+        self
+    }
+
+    /// Change everything the DM can set
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        location_id: Option<LocationId>,
+        category: Option<String>,
+        scarcity: Option<Scarcity>,
+        price_adjustment: i32,
+        flag: Option<String>,
+        reason: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let reason = validate_reason(reason)?;
+        let price_adjustment = validate_adjustment(price_adjustment)?;
+        let category = clean_optional(category);
+        if category
+            .as_ref()
+            .is_some_and(|c| c.chars().count() > MAX_MARKET_TEXT_LEN)
+        {
+            return Err(DomainError::validation(format!(
+                "Category cannot exceed {} characters",
+                MAX_MARKET_TEXT_LEN
+            )));
+        }
+        self.location_id = location_id;
+        self.category = category;
+        self.scarcity = scarcity;
+        self.price_adjustment = price_adjustment;
+        self.flag = clean_optional(flag);
+        self.reason = reason;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Whether the modifier's flag, if any, is among the world's set flags
+    pub fn is_active(&self, world_flags: &[String]) -> bool {
+        match &self.flag {
+            Some(flag) => world_flags.iter().any(|f| f == flag),
+            None => true,
+        }
+    }
+
+    /// Whether it changes prices of `category` goods at `location_id` right
+    /// now
+    pub fn applies_to(
+        &self,
+        location_id: Option<LocationId>,
+        category: Option<&str>,
+        world_flags: &[String],
+    ) -> bool {
+        let location_matches = self.location_id.is_none() || self.location_id == location_id;
+        let category_matches = match (&self.category, category) {
+            (None, _) => true,
+            (Some(own), Some(category)) => own.eq_ignore_ascii_case(category.trim()),
+            (Some(_), None) => false,
+        };
+        location_matches && category_matches && self.is_active(world_flags)
+    }
+}
+
+/// Market conditions for one kind of goods in one place
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MarketConditions {
+    /// The most severe scarcity any applicable modifier sets
+    pub scarcity: Scarcity,
+    /// Sum of the applicable price adjustments, in percent
+    pub price_adjustment: i32,
+    /// Reasons of the applicable modifiers, in the order given
+    pub reasons: Vec<String>,
+}
+
+impl MarketConditions {
+    /// What all the modifiers together make of a base price, as a percentage,
+    /// or `None` when the goods can't be bought
+    pub fn price_percent(&self) -> Option<u32> {
+        let scarcity = self.scarcity.price_percent()?;
+        let adjustment = (100 + self.price_adjustment).max(MIN_PRICE_PERCENT) as u32;
+        Some(scarcity * adjustment / 100)
+    }
+
+    /// The local price of something with this base price, or `None` when
+    /// it can't be bought. Rounded to the nearest unit; anything that costs
+    /// something costs at least one.
+    pub fn price(&self, base_price: u32) -> Option<u32> {
+        let percent = self.price_percent()? as u64;
+        let price = (base_price as u64 * percent + 50) / 100;
+        let price = if base_price > 0 { price.max(1) } else { 0 };
+        Some(price.min(u32::MAX as u64) as u32)
+    }
+}
+
+/// The combined effect of `modifiers` on `category` goods at `location_id`,
+/// given the flags currently set in the world
+pub fn market_conditions(
+    modifiers: &[MarketModifier],
+    location_id: Option<LocationId>,
+    category: Option<&str>,
+    world_flags: &[String],
+) -> MarketConditions {
+    let mut scarcity: Option<Scarcity> = None;
+    let mut conditions = MarketConditions::default();
+    for modifier in modifiers
+        .iter()
+        .filter(|m| m.applies_to(location_id, category, world_flags))
+    {
+        if let Some(set) = modifier.scarcity {
+            scarcity = Some(scarcity.map_or(set, |current| current.max(set)));
+        }
+        conditions.price_adjustment += modifier.price_adjustment;
+        conditions.reasons.push(modifier.reason.clone());
+    }
+    conditions.scarcity = scarcity.unwrap_or_default();
+    conditions
+}
+
+fn clean_optional(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn validate_adjustment(percent: i32) -> Result<i32, DomainError> {
+    if !(-MAX_PRICE_ADJUSTMENT_PERCENT..=MAX_PRICE_ADJUSTMENT_PERCENT).contains(&percent) {
+        return Err(DomainError::validation(format!(
+            "Price adjustment must be between -{0}% and {0}%",
+            MAX_PRICE_ADJUSTMENT_PERCENT
+        )));
+    }
+    Ok(percent)
+}
+
+fn validate_reason(reason: &str) -> Result<String, DomainError> {
+    let reason = reason.trim();
+    if reason.is_empty() {
+        return Err(DomainError::validation("A market modifier needs a reason"));
+    }
+    if reason.chars().count() > MAX_MARKET_TEXT_LEN {
+        return Err(DomainError::validation(format!(
+            "Reason cannot exceed {} characters",
+            MAX_MARKET_TEXT_LEN
+        )));
+    }
+    Ok(reason.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modifier(reason: &str) -> MarketModifier {
+        MarketModifier::new(WorldId::new(), reason, Utc::now()).unwrap()
+    }
+
+    #[test]
+    fn modifiers_apply_by_location_category_and_flag() {
+        let harbour = LocationId::new();
+        let blockade = modifier("The blockade")
+            .at_location(harbour)
+            .for_category("Weapon")
+            .with_scarcity(Scarcity::Scarce)
+            .while_flag("blockade");
+        let flags = vec!["blockade".to_string()];
+
+        assert!(blockade.applies_to(Some(harbour), Some("weapon"), &flags));
+        assert!(!blockade.applies_to(Some(harbour), Some("Food"), &flags));
+        assert!(!blockade.applies_to(Some(LocationId::new()), Some("Weapon"), &flags));
+        assert!(!blockade.applies_to(Some(harbour), Some("Weapon"), &[]));
+    }
+
+    #[test]
+    fn the_most_severe_scarcity_wins_and_adjustments_add_up() {
+        let modifiers = vec![
+            modifier("Good harvest").with_scarcity(Scarcity::Abundant),
+            modifier("War in the north")
+                .with_scarcity(Scarcity::Rare)
+                .with_price_adjustment(20)
+                .unwrap(),
+            modifier("Guild tax").with_price_adjustment(-10).unwrap(),
+        ];
+
+        let conditions = market_conditions(&modifiers, None, Some("Food"), &[]);
+
+        assert_eq!(conditions.scarcity, Scarcity::Rare);
+        assert_eq!(conditions.price_adjustment, 10);
+        assert_eq!(conditions.reasons.len(), 3);
+        // 250% for rare goods, then 10% dearer
+        assert_eq!(conditions.price(10), Some(28));
+    }
+
+    #[test]
+    fn unavailable_goods_have_no_price_and_cheap_goods_still_cost_something() {
+        let gone = vec![modifier("Embargo").with_scarcity(Scarcity::Unavailable)];
+        assert_eq!(market_conditions(&gone, None, None, &[]).price(10), None);
+
+        let glut = vec![modifier("Glut").with_price_adjustment(-500).unwrap()];
+        let conditions = market_conditions(&glut, None, None, &[]);
+        assert_eq!(conditions.price_percent(), Some(MIN_PRICE_PERCENT as u32));
+        assert_eq!(conditions.price(1), Some(1));
+        assert_eq!(conditions.price(0), Some(0));
+    }
+
+    #[test]
+    fn adjustments_are_bounded() {
+        assert!(modifier("Too much").with_price_adjustment(501).is_err());
+        assert!(MarketModifier::new(WorldId::new(), "  ", Utc::now()).is_err());
+    }
+}
@@ -0,0 +1,418 @@
+//! Entity Template entity - reusable prefabs for repetitive prep
+//!
+//! A template captures an NPC ("innkeeper") or a whole region ("guard post")
+//! with the items lying around in it and the NPCs who live, work or hang out
+//! there. Instantiating it creates fresh entities, substituting `{name}` and
+//! any other `{variable}` placeholders in the template's text.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_TEMPLATE]->(EntityTemplate)` - Template belongs to a world
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{EntityTemplateId, WorldId};
+use crate::value_objects::{CampbellArchetype, RegionRelationshipType};
+
+/// Most templates a world may keep
+pub const MAX_TEMPLATES_PER_WORLD: usize = 200;
+/// Most items a region template may place
+pub const MAX_TEMPLATE_ITEMS: usize = 50;
+/// Most NPCs a region template may create
+pub const MAX_TEMPLATE_NPCS: usize = 20;
+/// The placeholder every instantiation fills with the new entity's name
+pub const TEMPLATE_NAME_VARIABLE: &str = "name";
+
+/// A saved prefab that can be stamped out into new entities
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityTemplate {
+    pub id: EntityTemplateId,
+    pub world_id: WorldId,
+    /// Display name of the template itself (e.g., "Innkeeper")
+    pub name: String,
+    /// DM notes on when to use it
+    pub description: Option<String>,
+    pub body: TemplateBody,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// What a template creates
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TemplateBody {
+    Npc(NpcTemplate),
+    Region(RegionTemplate),
+}
+
+/// A non-player character blueprint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NpcTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub archetype: CampbellArchetype,
+    #[serde(default)]
+    pub sprite_asset: Option<String>,
+    #[serde(default)]
+    pub portrait_asset: Option<String>,
+}
+
+/// A region blueprint with its items and resident NPCs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegionTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub atmosphere: Option<String>,
+    #[serde(default)]
+    pub backdrop_asset: Option<String>,
+    #[serde(default)]
+    pub is_spawn_point: bool,
+    /// Items placed in the new region
+    #[serde(default)]
+    pub items: Vec<ItemTemplate>,
+    /// NPCs created alongside the region, tied to it so staging suggests them
+    #[serde(default)]
+    pub npcs: Vec<TemplateNpc>,
+}
+
+/// An item blueprint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub item_type: Option<String>,
+}
+
+/// An NPC in a region template and how they relate to the region
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateNpc {
+    pub npc: NpcTemplate,
+    pub role: RegionRelationshipType,
+}
+
+impl EntityTemplate {
+    pub fn new(
+        world_id: WorldId,
+        name: impl Into<String>,
+        description: Option<String>,
+        body: TemplateBody,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let mut template = Self {
+            id: EntityTemplateId::new(),
+            world_id,
+            name: String::new(),
+            description: None,
+            body: body.clone(),
+            created_at: now,
+            updated_at: now,
+        };
+        template.update(name, description, body, now)?;
+        Ok(template)
+    }
+
+    /// Replace the template's name, notes and body.
+    pub fn update(
+        &mut self,
+        name: impl Into<String>,
+        description: Option<String>,
+        body: TemplateBody,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let name = name.into().trim().to_string();
+        if name.is_empty() {
+            return Err(DomainError::validation("Template name cannot be empty"));
+        }
+        body.validate()?;
+
+        self.name = name;
+        self.description = description.filter(|d| !d.trim().is_empty());
+        self.body = body;
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+impl TemplateBody {
+    /// Every `{variable}` the body uses, sorted. Always includes `name`.
+    pub fn variables(&self) -> Vec<String> {
+        let mut found = BTreeSet::from([TEMPLATE_NAME_VARIABLE.to_string()]);
+        let _ = self.map_text(&mut |text| {
+            found.extend(placeholders(text));
+            Ok(text.to_string())
+        });
+        found.into_iter().collect()
+    }
+
+    /// A copy with every placeholder filled in. Fails if `name` is blank or a
+    /// placeholder has no value.
+    pub fn instantiate(
+        &self,
+        name: &str,
+        variables: &BTreeMap<String, String>,
+    ) -> Result<Self, DomainError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DomainError::validation("A name is required"));
+        }
+        let mut values = variables.clone();
+        values.insert(TEMPLATE_NAME_VARIABLE.to_string(), name.to_string());
+        let body = self.map_text(&mut |text| substitute(text, &values))?;
+        body.validate()?;
+        Ok(body)
+    }
+
+    /// A copy with every occurrence of `source_name` replaced by `{name}`, for
+    /// saving an existing entity as a template.
+    pub fn generalized(&self, source_name: &str) -> Self {
+        let source_name = source_name.trim();
+        if source_name.is_empty() {
+            return self.clone();
+        }
+        let placeholder = format!("{{{}}}", TEMPLATE_NAME_VARIABLE);
+        self.map_text(&mut |text| Ok(text.replace(source_name, &placeholder)))
+            .unwrap_or_else(|_| self.clone())
+    }
+
+    fn validate(&self) -> Result<(), DomainError> {
+        match self {
+            TemplateBody::Npc(npc) => npc.validate(),
+            TemplateBody::Region(region) => {
+                if region.name.trim().is_empty() {
+                    return Err(DomainError::validation("Region name cannot be empty"));
+                }
+                if region.items.len() > MAX_TEMPLATE_ITEMS {
+                    return Err(DomainError::validation(format!(
+                        "A template can place at most {} items",
+                        MAX_TEMPLATE_ITEMS
+                    )));
+                }
+                if region.npcs.len() > MAX_TEMPLATE_NPCS {
+                    return Err(DomainError::validation(format!(
+                        "A template can create at most {} NPCs",
+                        MAX_TEMPLATE_NPCS
+                    )));
+                }
+                if region.items.iter().any(|item| item.name.trim().is_empty()) {
+                    return Err(DomainError::validation("Item name cannot be empty"));
+                }
+                region
+                    .npcs
+                    .iter()
+                    .try_for_each(|entry| entry.npc.validate())
+            }
+        }
+    }
+
+    /// Rebuild the body with `f` applied to every piece of display text
+    /// (names, descriptions, item types). Asset paths are left alone.
+    fn map_text(
+        &self,
+        f: &mut impl FnMut(&str) -> Result<String, DomainError>,
+    ) -> Result<Self, DomainError> {
+        Ok(match self {
+            TemplateBody::Npc(npc) => TemplateBody::Npc(npc.map_text(f)?),
+            TemplateBody::Region(region) => TemplateBody::Region(RegionTemplate {
+                name: f(&region.name)?,
+                description: f(&region.description)?,
+                atmosphere: map_opt(&region.atmosphere, f)?,
+                backdrop_asset: region.backdrop_asset.clone(),
+                is_spawn_point: region.is_spawn_point,
+                items: region
+                    .items
+                    .iter()
+                    .map(|item| {
+                        Ok(ItemTemplate {
+                            name: f(&item.name)?,
+                            description: map_opt(&item.description, f)?,
+                            item_type: map_opt(&item.item_type, f)?,
+                        })
+                    })
+                    .collect::<Result<_, DomainError>>()?,
+                npcs: region
+                    .npcs
+                    .iter()
+                    .map(|entry| {
+                        Ok(TemplateNpc {
+                            npc: entry.npc.map_text(f)?,
+                            role: entry.role.clone(),
+                        })
+                    })
+                    .collect::<Result<_, DomainError>>()?,
+            }),
+        })
+    }
+}
+
+impl NpcTemplate {
+    fn validate(&self) -> Result<(), DomainError> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::validation("NPC name cannot be empty"));
+        }
+        Ok(())
+    }
+
+    fn map_text(
+        &self,
+        f: &mut impl FnMut(&str) -> Result<String, DomainError>,
+    ) -> Result<Self, DomainError> {
+        Ok(Self {
+            name: f(&self.name)?,
+            description: f(&self.description)?,
+            archetype: self.archetype,
+            sprite_asset: self.sprite_asset.clone(),
+            portrait_asset: self.portrait_asset.clone(),
+        })
+    }
+}
+
+fn map_opt(
+    text: &Option<String>,
+    f: &mut impl FnMut(&str) -> Result<String, DomainError>,
+) -> Result<Option<String>, DomainError> {
+    text.as_deref().map(f).transpose()
+}
+
+/// Spans of `{variable}` placeholders in `text`: (start, end, variable).
+/// Variables are lowercase letters, digits and underscores; anything else in
+/// braces is plain text.
+fn placeholder_spans(text: &str) -> Vec<(usize, usize, &str)> {
+    let mut spans = Vec::new();
+    let mut rest = 0;
+    while let Some(open) = text[rest..].find('{').map(|i| rest + i) {
+        let Some(close) = text[open..].find('}').map(|i| open + i) else {
+            break;
+        };
+        let inner = &text[open + 1..close];
+        if !inner.is_empty()
+            && inner
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            spans.push((open, close + 1, inner));
+            rest = close + 1;
+        } else {
+            rest = open + 1;
+        }
+    }
+    spans
+}
+
+fn placeholders(text: &str) -> Vec<String> {
+    placeholder_spans(text)
+        .into_iter()
+        .map(|(_, _, var)| var.to_string())
+        .collect()
+}
+
+fn substitute(text: &str, values: &BTreeMap<String, String>) -> Result<String, DomainError> {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (start, end, var) in placeholder_spans(text) {
+        let value = values
+            .get(var)
+            .ok_or_else(|| DomainError::validation(format!("Missing value for {{{}}}", var)))?;
+        out.push_str(&text[last..start]);
+        out.push_str(value);
+        last = end;
+    }
+    out.push_str(&text[last..]);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::RegionShift;
+
+    fn guard_post() -> TemplateBody {
+        TemplateBody::Region(RegionTemplate {
+            name: "{name}".to_string(),
+            description: "A cramped post watching the {road} road.".to_string(),
+            atmosphere: None,
+            backdrop_asset: Some("backdrops/{name}.png".to_string()),
+            is_spawn_point: false,
+            items: vec![ItemTemplate {
+                name: "{name} Armory Key".to_string(),
+                description: None,
+                item_type: Some("Key".to_string()),
+            }],
+            npcs: vec![TemplateNpc {
+                npc: NpcTemplate {
+                    name: "{name} Sergeant".to_string(),
+                    description: String::new(),
+                    archetype: CampbellArchetype::ThresholdGuardian,
+                    sprite_asset: None,
+                    portrait_asset: None,
+                },
+                role: RegionRelationshipType::WorksAt {
+                    shift: RegionShift::Day,
+                },
+            }],
+        })
+    }
+
+    #[test]
+    fn test_variables_are_collected_from_all_text() {
+        assert_eq!(guard_post().variables(), vec!["name", "road"]);
+    }
+
+    #[test]
+    fn test_instantiate_fills_placeholders_but_not_asset_paths() {
+        let vars = BTreeMap::from([("road".to_string(), "north".to_string())]);
+        let TemplateBody::Region(region) = guard_post().instantiate("Northgate", &vars).unwrap()
+        else {
+            panic!("expected a region");
+        };
+        assert_eq!(region.name, "Northgate");
+        assert_eq!(
+            region.description,
+            "A cramped post watching the north road."
+        );
+        assert_eq!(region.items[0].name, "Northgate Armory Key");
+        assert_eq!(region.npcs[0].npc.name, "Northgate Sergeant");
+        assert_eq!(
+            region.backdrop_asset.as_deref(),
+            Some("backdrops/{name}.png")
+        );
+    }
+
+    #[test]
+    fn test_instantiate_requires_every_variable_and_a_name() {
+        assert!(guard_post()
+            .instantiate("Northgate", &BTreeMap::new())
+            .is_err());
+        let vars = BTreeMap::from([("road".to_string(), "north".to_string())]);
+        assert!(guard_post().instantiate("  ", &vars).is_err());
+    }
+
+    #[test]
+    fn test_generalized_replaces_source_name_and_ignores_other_braces() {
+        let body = TemplateBody::Npc(NpcTemplate {
+            name: "Greta".to_string(),
+            description: "Greta runs the inn {Closed Mondays}.".to_string(),
+            archetype: CampbellArchetype::Mentor,
+            sprite_asset: None,
+            portrait_asset: None,
+        })
+        .generalized("Greta");
+        assert_eq!(body.variables(), vec!["name"]);
+        let TemplateBody::Npc(npc) = body.instantiate("Hilde", &BTreeMap::new()).unwrap() else {
+            panic!("expected an NPC");
+        };
+        assert_eq!(npc.description, "Hilde runs the inn {Closed Mondays}.");
+    }
+}
@@ -0,0 +1,379 @@
+//! Faction entity - an organization NPCs belong to
+//!
+//! Guilds, noble houses, cults, the city watch: groups with their own goals
+//! and a collective opinion of the party. An NPC's disposition is personal;
+//! a faction's reputation is how the whole organization regards the PCs, and
+//! it colours how every member is staged and voiced. Rivalries are mutual
+//! and kept in step by the use case that sets them.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_FACTION]->(Faction)`
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{CharacterId, FactionId, WorldId};
+
+/// Longest faction name, in characters
+pub const MAX_FACTION_NAME_LEN: usize = 100;
+
+/// Most goals a faction can pursue at once
+pub const MAX_FACTION_GOALS: usize = 10;
+
+/// Longest goal, in characters
+pub const MAX_FACTION_GOAL_LEN: usize = 300;
+
+/// Longest member role ("fence", "high priestess"), in characters
+pub const MAX_FACTION_ROLE_LEN: usize = 60;
+
+/// Lowest reputation a faction can hold with the party
+pub const MIN_FACTION_REPUTATION: i32 = -100;
+
+/// Highest reputation a faction can hold with the party
+pub const MAX_FACTION_REPUTATION: i32 = 100;
+
+/// How a faction regards the party, by reputation band
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ReputationStanding {
+    /// -100 to -50: the party is an enemy
+    Hostile,
+    /// -49 to -10: distrusted, watched
+    Unfriendly,
+    /// -9 to 9: unknown or unremarkable
+    #[default]
+    Neutral,
+    /// 10 to 49: welcome, owed small favours
+    Friendly,
+    /// 50 to 100: trusted as one of their own
+    Allied,
+    /// Unknown standing (for forward compatibility)
+    #[serde(other)]
+    Unknown,
+}
+
+impl ReputationStanding {
+    /// The band a reputation score falls in
+    pub fn from_reputation(reputation: i32) -> Self {
+        match reputation {
+            i32::MIN..=-50 => ReputationStanding::Hostile,
+            -49..=-10 => ReputationStanding::Unfriendly,
+            -9..=9 => ReputationStanding::Neutral,
+            10..=49 => ReputationStanding::Friendly,
+            _ => ReputationStanding::Allied,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ReputationStanding::Hostile => "Hostile",
+            ReputationStanding::Unfriendly => "Unfriendly",
+            ReputationStanding::Neutral => "Neutral",
+            ReputationStanding::Friendly => "Friendly",
+            ReputationStanding::Allied => "Allied",
+            ReputationStanding::Unknown => "Unknown",
+        }
+    }
+}
+
+/// An NPC's place in a faction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FactionMember {
+    pub character_id: CharacterId,
+    /// What they do for the faction; empty for rank and file
+    #[serde(default)]
+    pub role: String,
+}
+
+/// An organization with members, goals and a standing with the party
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Faction {
+    pub id: FactionId,
+    pub world_id: WorldId,
+    pub name: String,
+    pub description: String,
+    /// What the faction is working towards, most pressing first
+    pub goals: Vec<String>,
+    pub members: Vec<FactionMember>,
+    /// How the faction regards the party, from -100 to 100
+    pub reputation: i32,
+    /// Factions this one opposes; always mutual
+    pub rivals: Vec<FactionId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Faction {
+    pub fn new(
+        world_id: WorldId,
+        name: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let name = validate_faction_name(&name.into())?;
+        Ok(Self {
+            id: FactionId::new(),
+            world_id,
+            name,
+            description: String::new(),
+            goals: Vec::new(),
+            members: Vec::new(),
+            reputation: 0,
+            rivals: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Change the faction's name, description and goals
+    pub fn update(
+        &mut self,
+        name: &str,
+        description: impl Into<String>,
+        goals: Vec<String>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let name = validate_faction_name(name)?;
+        let goals = validate_goals(goals)?;
+        self.name = name;
+        self.description = description.into();
+        self.goals = goals;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Replace the member list. Roles are trimmed; an NPC listed twice keeps
+    /// its first role.
+    pub fn set_members(
+        &mut self,
+        members: Vec<FactionMember>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let mut kept: Vec<FactionMember> = Vec::with_capacity(members.len());
+        for member in members {
+            if kept.iter().any(|m| m.character_id == member.character_id) {
+                continue;
+            }
+            let role = member.role.trim();
+            if role.chars().count() > MAX_FACTION_ROLE_LEN {
+                return Err(DomainError::validation(format!(
+                    "Member roles cannot exceed {} characters",
+                    MAX_FACTION_ROLE_LEN
+                )));
+            }
+            kept.push(FactionMember {
+                character_id: member.character_id,
+                role: role.to_string(),
+            });
+        }
+        self.members = kept;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    pub fn member(&self, character_id: CharacterId) -> Option<&FactionMember> {
+        self.members.iter().find(|m| m.character_id == character_id)
+    }
+
+    /// Drop an NPC from the faction; returns whether they were a member
+    pub fn remove_member(&mut self, character_id: CharacterId, now: DateTime<Utc>) -> bool {
+        let before = self.members.len();
+        self.members.retain(|m| m.character_id != character_id);
+        let removed = self.members.len() != before;
+        if removed {
+            self.updated_at = now;
+        }
+        removed
+    }
+
+    /// Set the faction's reputation with the party
+    pub fn set_reputation(
+        &mut self,
+        reputation: i32,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if !(MIN_FACTION_REPUTATION..=MAX_FACTION_REPUTATION).contains(&reputation) {
+            return Err(DomainError::validation(format!(
+                "Reputation must be between {} and {}, got {}",
+                MIN_FACTION_REPUTATION, MAX_FACTION_REPUTATION, reputation
+            )));
+        }
+        self.reputation = reputation;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Move the reputation by `delta`, stopping at the ends of the scale;
+    /// returns the new reputation
+    pub fn adjust_reputation(&mut self, delta: i32, now: DateTime<Utc>) -> i32 {
+        self.reputation = self
+            .reputation
+            .saturating_add(delta)
+            .clamp(MIN_FACTION_REPUTATION, MAX_FACTION_REPUTATION);
+        self.updated_at = now;
+        self.reputation
+    }
+
+    pub fn standing(&self) -> ReputationStanding {
+        ReputationStanding::from_reputation(self.reputation)
+    }
+
+    pub fn is_rival_of(&self, other: FactionId) -> bool {
+        self.rivals.contains(&other)
+    }
+
+    /// Mark or clear a rivalry on this side; returns whether anything
+    /// changed
+    pub fn set_rival(
+        &mut self,
+        other: FactionId,
+        rival: bool,
+        now: DateTime<Utc>,
+    ) -> Result<bool, DomainError> {
+        if other == self.id {
+            return Err(DomainError::validation("A faction cannot be its own rival"));
+        }
+        if rival == self.is_rival_of(other) {
+            return Ok(false);
+        }
+        if rival {
+            self.rivals.push(other);
+        } else {
+            self.rivals.retain(|id| *id != other);
+        }
+        self.updated_at = now;
+        Ok(true)
+    }
+}
+
+fn validate_faction_name(name: &str) -> Result<String, DomainError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(DomainError::validation("Faction name cannot be empty"));
+    }
+    if name.chars().count() > MAX_FACTION_NAME_LEN {
+        return Err(DomainError::validation(format!(
+            "Faction name cannot exceed {} characters",
+            MAX_FACTION_NAME_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+/// Trimmed goals with blanks dropped
+fn validate_goals(goals: Vec<String>) -> Result<Vec<String>, DomainError> {
+    let goals: Vec<String> = goals
+        .iter()
+        .map(|g| g.trim())
+        .filter(|g| !g.is_empty())
+        .map(String::from)
+        .collect();
+    if goals.len() > MAX_FACTION_GOALS {
+        return Err(DomainError::validation(format!(
+            "A faction can pursue at most {} goals",
+            MAX_FACTION_GOALS
+        )));
+    }
+    if goals
+        .iter()
+        .any(|g| g.chars().count() > MAX_FACTION_GOAL_LEN)
+    {
+        return Err(DomainError::validation(format!(
+            "Goals cannot exceed {} characters",
+            MAX_FACTION_GOAL_LEN
+        )));
+    }
+    Ok(goals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guild() -> Faction {
+        Faction::new(WorldId::new(), " Thieves' Guild ", Utc::now()).expect("valid faction")
+    }
+
+    #[test]
+    fn reputation_stays_on_the_scale_and_maps_to_a_standing() {
+        let now = Utc::now();
+        let mut faction = guild();
+        assert_eq!(faction.name, "Thieves' Guild");
+        assert_eq!(faction.standing(), ReputationStanding::Neutral);
+
+        assert_eq!(faction.adjust_reputation(-30, now), -30);
+        assert_eq!(faction.standing(), ReputationStanding::Unfriendly);
+        assert_eq!(faction.adjust_reputation(-500, now), MIN_FACTION_REPUTATION);
+        assert_eq!(faction.standing(), ReputationStanding::Hostile);
+
+        assert!(faction.set_reputation(101, now).is_err());
+        faction.set_reputation(50, now).unwrap();
+        assert_eq!(faction.standing(), ReputationStanding::Allied);
+        assert_eq!(
+            ReputationStanding::from_reputation(10),
+            ReputationStanding::Friendly
+        );
+    }
+
+    #[test]
+    fn members_are_unique_and_goals_are_trimmed() {
+        let now = Utc::now();
+        let mut faction = guild();
+        let fence = CharacterId::new();
+
+        faction
+            .set_members(
+                vec![
+                    FactionMember {
+                        character_id: fence,
+                        role: " fence ".to_string(),
+                    },
+                    FactionMember {
+                        character_id: fence,
+                        role: "boss".to_string(),
+                    },
+                ],
+                now,
+            )
+            .unwrap();
+        assert_eq!(faction.members.len(), 1);
+        assert_eq!(faction.member(fence).unwrap().role, "fence");
+        assert!(faction.remove_member(fence, now));
+        assert!(!faction.remove_member(fence, now));
+
+        faction
+            .update(
+                "Thieves' Guild",
+                "",
+                vec![" Control the docks ".to_string(), " ".to_string()],
+                now,
+            )
+            .unwrap();
+        assert_eq!(faction.goals, vec!["Control the docks"]);
+        assert!(faction
+            .update("Thieves' Guild", "", vec!["x".to_string(); 11], now)
+            .is_err());
+    }
+
+    #[test]
+    fn a_faction_cannot_be_its_own_rival() {
+        let now = Utc::now();
+        let mut faction = guild();
+        let watch = FactionId::new();
+
+        assert!(faction.set_rival(faction.id, true, now).is_err());
+        assert!(faction.set_rival(watch, true, now).unwrap());
+        assert!(!faction.set_rival(watch, true, now).unwrap());
+        assert!(faction.is_rival_of(watch));
+        assert!(faction.set_rival(watch, false, now).unwrap());
+        assert!(faction.rivals.is_empty());
+    }
+}
@@ -3,11 +3,16 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::error::DomainError;
+use crate::value_objects::normalize_tags;
 use crate::{AssetId, BatchId};
 
 // Re-export shared types from types module
 pub use crate::types::{AssetType, EntityType};
 
+/// Most assets a single bulk gallery operation (favorite, tag) may touch
+pub const MAX_GALLERY_BULK: usize = 100;
+
 /// Metadata about how an asset was generated
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -73,6 +78,12 @@ pub struct GalleryAsset {
     pub label: Option<String>,
     /// Metadata about generation (if AI-generated)
     pub generation_metadata: Option<GenerationMetadata>,
+    /// Starred by the DM
+    #[serde(default)]
+    pub is_favorite: bool,
+    /// DM tags for sorting the gallery (normalized, like entity tags)
+    #[serde(default)]
+    pub tags: Vec<String>,
     /// When the asset was created/uploaded
     pub created_at: DateTime<Utc>,
 }
@@ -95,6 +106,8 @@ impl GalleryAsset {
             is_active: false,
             label: None,
             generation_metadata: None,
+            is_favorite: false,
+            tags: Vec::new(),
             created_at: now,
         }
     }
@@ -117,6 +130,8 @@ impl GalleryAsset {
             is_active: false,
             label: None,
             generation_metadata: Some(metadata),
+            is_favorite: false,
+            tags: Vec::new(),
             created_at: now,
         }
     }
@@ -150,4 +165,116 @@ impl GalleryAsset {
     pub fn is_generated(&self) -> bool {
         self.generation_metadata.is_some()
     }
+
+    /// The generation batch this asset came from (if AI-generated)
+    pub fn batch_id(&self) -> Option<BatchId> {
+        self.generation_metadata.as_ref().map(|m| m.batch_id)
+    }
+
+    /// Star or unstar the asset
+    pub fn set_favorite(&mut self, favorite: bool) {
+        self.is_favorite = favorite;
+    }
+
+    /// Add and remove tags. Returns whether the tags changed.
+    pub fn retag<S: AsRef<str>>(&mut self, add: &[S], remove: &[S]) -> Result<bool, DomainError> {
+        let remove = normalize_tags(remove)?;
+        let mut tags = self.tags.clone();
+        tags.extend(normalize_tags(add)?);
+        tags.retain(|t| !remove.contains(t));
+        let tags = normalize_tags(&tags)?;
+        if tags == self.tags {
+            return Ok(false);
+        }
+        self.tags = tags;
+        Ok(true)
+    }
+}
+
+/// Which gallery assets to list; criteria left unset match every asset
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GalleryFilter {
+    pub entity_type: Option<EntityType>,
+    pub entity_id: Option<String>,
+    pub asset_type: Option<AssetType>,
+    pub batch_id: Option<BatchId>,
+    /// Normalized tag the asset must carry
+    pub tag: Option<String>,
+    pub favorites_only: bool,
+}
+
+impl GalleryFilter {
+    pub fn matches(&self, asset: &GalleryAsset) -> bool {
+        self.entity_type.is_none_or(|t| t == asset.entity_type)
+            && self
+                .entity_id
+                .as_ref()
+                .is_none_or(|id| *id == asset.entity_id)
+            && self.asset_type.is_none_or(|t| t == asset.asset_type)
+            && self.batch_id.is_none_or(|b| asset.batch_id() == Some(b))
+            && self.tag.as_ref().is_none_or(|t| asset.tags.contains(t))
+            && (!self.favorites_only || asset.is_favorite)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn asset() -> GalleryAsset {
+        GalleryAsset::new(
+            EntityType::Character,
+            "c1",
+            AssetType::Portrait,
+            "assets/a.png",
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_retag_normalizes_adds_and_removes() {
+        let mut asset = asset();
+        assert!(asset.retag(&["Winter Outfit", "angry"], &[]).unwrap());
+        assert_eq!(asset.tags, vec!["angry", "winter-outfit"]);
+
+        assert!(asset.retag(&["night"], &["ANGRY"]).unwrap());
+        assert_eq!(asset.tags, vec!["night", "winter-outfit"]);
+
+        assert!(!asset.retag(&["night"], &[]).unwrap());
+        assert!(asset.retag(&[""], &[]).is_err());
+    }
+
+    #[test]
+    fn test_filter_combines_criteria() {
+        let batch_id = BatchId::new();
+        let mut generated = GalleryAsset::new_generated(
+            EntityType::Location,
+            "l1",
+            AssetType::Backdrop,
+            "assets/b.png",
+            GenerationMetadata::new("backdrop", "a misty harbor", 7, batch_id),
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+        );
+        generated.set_favorite(true);
+        let uploaded = asset();
+
+        assert!(GalleryFilter::default().matches(&uploaded));
+
+        let by_batch = GalleryFilter {
+            batch_id: Some(batch_id),
+            ..Default::default()
+        };
+        assert!(by_batch.matches(&generated));
+        assert!(!by_batch.matches(&uploaded));
+
+        let favorite_backdrops = GalleryFilter {
+            asset_type: Some(AssetType::Backdrop),
+            favorites_only: true,
+            ..Default::default()
+        };
+        assert!(favorite_backdrops.matches(&generated));
+        generated.set_favorite(false);
+        assert!(!favorite_backdrops.matches(&generated));
+    }
 }
@@ -0,0 +1,492 @@
+//! Injury entity - lasting harm to a player character
+//!
+//! An injury has a severity, the capability it hampers, and a number of game
+//! hours it takes to heal. Recovery is counted from game time: the hours
+//! since the injury was taken, plus credit earned by resting and by
+//! successful treatment. Once the recovered hours reach the recovery time
+//! the injury heals.
+//!
+//! How fast all of this goes is set per rule system by
+//! [`InjuryRecoveryConfig`]. Some systems (Blades in the Dark) only heal
+//! during downtime, so elapsed time counts for nothing and only rest does.
+//!
+//! # Neo4j Relationships
+//! - `(PlayerCharacter)-[:HAS_INJURY]->(Injury)`
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{ChallengeId, InjuryId, PlayerCharacterId, WorldId};
+use crate::types::InjuryRecoveryConfig;
+
+/// Longest injury name, in characters
+pub const MAX_INJURY_NAME_LEN: usize = 200;
+
+/// Longest injury notes, in characters
+pub const MAX_INJURY_NOTES_LEN: usize = 2000;
+
+/// Longest recovery time an injury can have, in game hours (about a year)
+pub const MAX_RECOVERY_HOURS: u32 = 24 * 365;
+
+/// Most unhealed injuries one PC can carry
+pub const MAX_ACTIVE_INJURIES_PER_PC: usize = 20;
+
+/// How bad an injury is
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InjurySeverity {
+    /// Cuts, bruises, a turned ankle
+    #[default]
+    Minor,
+    /// Broken bones, deep wounds
+    Serious,
+    /// Wounds that could still kill
+    Critical,
+}
+
+impl InjurySeverity {
+    pub const ALL: [InjurySeverity; 3] = [
+        InjurySeverity::Minor,
+        InjurySeverity::Serious,
+        InjurySeverity::Critical,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InjurySeverity::Minor => "minor",
+            InjurySeverity::Serious => "serious",
+            InjurySeverity::Critical => "critical",
+        }
+    }
+
+    /// The rule system's recovery time for this severity, in game hours
+    pub fn recovery_hours(self, config: &InjuryRecoveryConfig) -> u32 {
+        match self {
+            InjurySeverity::Minor => config.minor_hours,
+            InjurySeverity::Serious => config.serious_hours,
+            InjurySeverity::Critical => config.critical_hours,
+        }
+    }
+}
+
+impl fmt::Display for InjurySeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for InjurySeverity {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        InjurySeverity::ALL
+            .into_iter()
+            .find(|severity| severity.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| DomainError::parse(format!("Unknown injury severity: {}", s)))
+    }
+}
+
+/// What an injury makes harder
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InjuryCapability {
+    /// Walking, running, climbing
+    Mobility,
+    /// Using the hands: fighting, picking locks, writing
+    Manipulation,
+    /// Sight, hearing and the rest
+    Senses,
+    /// Talking and being understood
+    Speech,
+    /// Endurance and general vigour
+    #[default]
+    Stamina,
+    /// Memory, focus and nerve
+    Mind,
+}
+
+impl InjuryCapability {
+    pub const ALL: [InjuryCapability; 6] = [
+        InjuryCapability::Mobility,
+        InjuryCapability::Manipulation,
+        InjuryCapability::Senses,
+        InjuryCapability::Speech,
+        InjuryCapability::Stamina,
+        InjuryCapability::Mind,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InjuryCapability::Mobility => "mobility",
+            InjuryCapability::Manipulation => "manipulation",
+            InjuryCapability::Senses => "senses",
+            InjuryCapability::Speech => "speech",
+            InjuryCapability::Stamina => "stamina",
+            InjuryCapability::Mind => "mind",
+        }
+    }
+}
+
+impl fmt::Display for InjuryCapability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for InjuryCapability {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        InjuryCapability::ALL
+            .into_iter()
+            .find(|capability| capability.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| DomainError::parse(format!("Unknown injury capability: {}", s)))
+    }
+}
+
+/// An injury a player character is carrying, or once carried
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Injury {
+    pub id: InjuryId,
+    pub world_id: WorldId,
+    pub pc_id: PlayerCharacterId,
+    /// What the DM calls it: "Cracked ribs"
+    pub name: String,
+    pub severity: InjurySeverity,
+    pub capability: InjuryCapability,
+    /// Game hours the injury takes to heal
+    pub recovery_hours: u32,
+    /// Recovery hours earned by resting and treatment
+    pub bonus_hours: u32,
+    /// Challenge the PC rolls to treat the injury, if any
+    pub treatment_challenge_id: Option<ChallengeId>,
+    /// Whether a treatment has already been tried
+    pub treated: bool,
+    /// Game time the injury was taken
+    pub injured_at: DateTime<Utc>,
+    /// Game time the injury healed
+    pub healed_at: Option<DateTime<Utc>>,
+    pub notes: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Injury {
+    /// An injury taken at game time `injured_at`, healing in the rule
+    /// system's time for its severity
+    pub fn new(
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        name: impl Into<String>,
+        severity: InjurySeverity,
+        config: &InjuryRecoveryConfig,
+        injured_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        Ok(Self {
+            id: InjuryId::new(),
+            world_id,
+            pc_id,
+            name: validate_name(name.into())?,
+            severity,
+            capability: InjuryCapability::default(),
+            recovery_hours: validate_recovery_hours(severity.recovery_hours(config))?,
+            bonus_hours: 0,
+            treatment_challenge_id: None,
+            treated: false,
+            injured_at,
+            healed_at: None,
+            notes: String::new(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn with_capability(mut self, capability: InjuryCapability) -> Self {
+        self.capability = capability;
+        self
+    }
+
+    pub fn with_recovery_hours(mut self, hours: u32) -> Result<Self, DomainError> {
+        self.recovery_hours = validate_recovery_hours(hours)?;
+        Ok(self)
+    }
+
+    pub fn with_treatment_challenge(mut self, challenge_id: Option<ChallengeId>) -> Self {
+        self.treatment_challenge_id = challenge_id;
+        self
+    }
+
+    pub fn with_notes(mut self, notes: impl Into<String>) -> Result<Self, DomainError> {
+        self.notes = validate_notes(notes.into())?;
+        Ok(self)
+    }
+
+    /// Change whichever of the DM-set fields are given
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        name: Option<String>,
+        severity: Option<InjurySeverity>,
+        capability: Option<InjuryCapability>,
+        recovery_hours: Option<u32>,
+        treatment_challenge_id: Option<Option<ChallengeId>>,
+        notes: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let name = name.map(validate_name).transpose()?;
+        let recovery_hours = recovery_hours.map(validate_recovery_hours).transpose()?;
+        let notes = notes.map(validate_notes).transpose()?;
+        if let Some(name) = name {
+            self.name = name;
+        }
+        if let Some(severity) = severity {
+            self.severity = severity;
+        }
+        if let Some(capability) = capability {
+            self.capability = capability;
+        }
+        if let Some(recovery_hours) = recovery_hours {
+            self.recovery_hours = recovery_hours;
+        }
+        if let Some(challenge_id) = treatment_challenge_id {
+            self.treatment_challenge_id = challenge_id;
+        }
+        if let Some(notes) = notes {
+            self.notes = notes;
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    pub fn is_healed(&self) -> bool {
+        self.healed_at.is_some()
+    }
+
+    /// Recovery hours earned by game time `game_now`
+    pub fn recovered_hours(&self, game_now: DateTime<Utc>, config: &InjuryRecoveryConfig) -> u32 {
+        let elapsed = if config.heals_only_while_resting {
+            0
+        } else {
+            let hours = (game_now - self.injured_at).num_hours().max(0);
+            u32::try_from(hours).unwrap_or(u32::MAX)
+        };
+        elapsed.saturating_add(self.bonus_hours)
+    }
+
+    /// Game hours left before the injury heals at game time `game_now`
+    pub fn remaining_hours(&self, game_now: DateTime<Utc>, config: &InjuryRecoveryConfig) -> u32 {
+        if self.is_healed() {
+            return 0;
+        }
+        self.recovery_hours
+            .saturating_sub(self.recovered_hours(game_now, config))
+    }
+
+    /// Credit `minutes` of rest. Rest is worth `rest_multiplier` recovery
+    /// hours per hour; where time heals anyway, the elapsed hour already
+    /// counts once, so only the extra is added.
+    pub fn credit_rest(&mut self, minutes: u32, config: &InjuryRecoveryConfig, now: DateTime<Utc>) {
+        if self.is_healed() {
+            return;
+        }
+        let already_counted = if config.heals_only_while_resting {
+            0
+        } else {
+            1
+        };
+        let multiplier = config.rest_multiplier.saturating_sub(already_counted);
+        let hours = (minutes as u64 * multiplier as u64 / 60) as u32;
+        if hours > 0 {
+            self.bonus_hours = self.bonus_hours.saturating_add(hours);
+            self.updated_at = now;
+        }
+    }
+
+    /// Apply the result of a treatment attempt. Success takes the rule
+    /// system's share off the time left; either way the injury has now been
+    /// treated. Returns the recovery hours gained.
+    pub fn treat(
+        &mut self,
+        succeeded: bool,
+        game_now: DateTime<Utc>,
+        config: &InjuryRecoveryConfig,
+        now: DateTime<Utc>,
+    ) -> Result<u32, DomainError> {
+        if self.is_healed() {
+            return Err(DomainError::invalid_state_transition(
+                "A healed injury cannot be treated",
+            ));
+        }
+        if self.treated {
+            return Err(DomainError::invalid_state_transition(
+                "This injury has already been treated",
+            ));
+        }
+        let gained = if succeeded {
+            let percent = config.treatment_percent.min(100) as u64;
+            (self.remaining_hours(game_now, config) as u64 * percent / 100) as u32
+        } else {
+            0
+        };
+        self.bonus_hours = self.bonus_hours.saturating_add(gained);
+        self.treated = true;
+        self.updated_at = now;
+        Ok(gained)
+    }
+
+    /// Mark the injury healed if its recovery time is up at game time
+    /// `game_now`. Returns whether it healed just now.
+    pub fn heal_if_recovered(
+        &mut self,
+        game_now: DateTime<Utc>,
+        config: &InjuryRecoveryConfig,
+        now: DateTime<Utc>,
+    ) -> bool {
+        if self.is_healed() || self.remaining_hours(game_now, config) > 0 {
+            return false;
+        }
+        self.healed_at = Some(game_now);
+        self.updated_at = now;
+        true
+    }
+}
+
+fn validate_name(name: String) -> Result<String, DomainError> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(DomainError::validation("Injury name cannot be empty"));
+    }
+    if name.chars().count() > MAX_INJURY_NAME_LEN {
+        return Err(DomainError::validation(format!(
+            "Injury name cannot exceed {} characters",
+            MAX_INJURY_NAME_LEN
+        )));
+    }
+    Ok(name)
+}
+
+fn validate_notes(notes: String) -> Result<String, DomainError> {
+    if notes.chars().count() > MAX_INJURY_NOTES_LEN {
+        return Err(DomainError::validation(format!(
+            "Injury notes cannot exceed {} characters",
+            MAX_INJURY_NOTES_LEN
+        )));
+    }
+    Ok(notes)
+}
+
+fn validate_recovery_hours(hours: u32) -> Result<u32, DomainError> {
+    if hours > MAX_RECOVERY_HOURS {
+        return Err(DomainError::validation(format!(
+            "Recovery time cannot exceed {} game hours",
+            MAX_RECOVERY_HOURS
+        )));
+    }
+    Ok(hours)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn config() -> InjuryRecoveryConfig {
+        InjuryRecoveryConfig {
+            minor_hours: 24,
+            serious_hours: 168,
+            critical_hours: 720,
+            rest_multiplier: 2,
+            heals_only_while_resting: false,
+            treatment_percent: 50,
+        }
+    }
+
+    fn broken_arm(injured_at: DateTime<Utc>, config: &InjuryRecoveryConfig) -> Injury {
+        Injury::new(
+            WorldId::new(),
+            PlayerCharacterId::new(),
+            "Broken arm",
+            InjurySeverity::Serious,
+            config,
+            injured_at,
+            Utc::now(),
+        )
+        .unwrap()
+        .with_capability(InjuryCapability::Manipulation)
+    }
+
+    #[test]
+    fn recovery_time_comes_from_the_rule_system() {
+        let injury = broken_arm(Utc::now(), &config());
+        assert_eq!(injury.recovery_hours, 168);
+        assert!(Injury::new(
+            WorldId::new(),
+            PlayerCharacterId::new(),
+            "  ",
+            InjurySeverity::Minor,
+            &config(),
+            Utc::now(),
+            Utc::now(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn injuries_heal_as_game_time_passes() {
+        let config = config();
+        let injured = Utc::now();
+        let mut injury = broken_arm(injured, &config);
+
+        let later = injured + Duration::hours(100);
+        assert_eq!(injury.remaining_hours(later, &config), 68);
+        assert!(!injury.heal_if_recovered(later, &config, Utc::now()));
+
+        let healed = injured + Duration::hours(168);
+        assert!(injury.heal_if_recovered(healed, &config, Utc::now()));
+        assert_eq!(injury.healed_at, Some(healed));
+        assert!(!injury.heal_if_recovered(healed, &config, Utc::now()));
+    }
+
+    #[test]
+    fn rest_and_treatment_speed_recovery() {
+        let config = config();
+        let injured = Utc::now();
+        let mut injury = broken_arm(injured, &config);
+
+        // Eight hours of rest count double: eight extra on top of elapsed time
+        injury.credit_rest(8 * 60, &config, Utc::now());
+        let after_rest = injured + Duration::hours(8);
+        assert_eq!(injury.remaining_hours(after_rest, &config), 152);
+
+        assert_eq!(
+            injury.treat(true, after_rest, &config, Utc::now()).unwrap(),
+            76
+        );
+        assert_eq!(injury.remaining_hours(after_rest, &config), 76);
+        // One treatment per injury
+        assert!(injury.treat(true, after_rest, &config, Utc::now()).is_err());
+    }
+
+    #[test]
+    fn rest_only_systems_ignore_elapsed_time() {
+        let config = InjuryRecoveryConfig {
+            heals_only_while_resting: true,
+            rest_multiplier: 1,
+            ..config()
+        };
+        let injured = Utc::now();
+        let mut injury = broken_arm(injured, &config);
+
+        let later = injured + Duration::hours(500);
+        assert_eq!(injury.remaining_hours(later, &config), 168);
+
+        injury.credit_rest(168 * 60, &config, Utc::now());
+        assert!(injury.heal_if_recovered(later, &config, Utc::now()));
+    }
+}
@@ -10,9 +10,15 @@
 //! - `(InteractionTemplate)-[:TARGETS_REGION]->(Region)`
 //!
 //! Conditions remain as JSON (acceptable per ADR - complex nested non-relational)
+//!
+//! How often each PC has used an interaction is an edge too:
+//! - `(PlayerCharacter)-[:USED_INTERACTION {uses, last_used_at}]->(InteractionTemplate)`
 
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
-use wrldbldr_domain::{CharacterId, InteractionId, ItemId, SceneId};
+use wrldbldr_domain::{
+    CharacterId, GameTime, InteractionId, ItemId, PlayerCharacterId, SceneId, TimeOfDay,
+};
 
 /// A template defining an available interaction within a scene
 ///
@@ -38,6 +44,15 @@ pub struct InteractionTemplate {
     pub is_available: bool,
     /// Display order in the UI
     pub order: u32,
+    /// Game minutes a PC must wait before using it again
+    #[serde(default)]
+    pub cooldown_minutes: Option<u32>,
+    /// How many times each PC may use it
+    #[serde(default)]
+    pub max_uses: Option<u32>,
+    /// Times of day it is offered; empty means any time
+    #[serde(default)]
+    pub available_times: Vec<TimeOfDay>,
 }
 
 impl InteractionTemplate {
@@ -58,6 +73,9 @@ impl InteractionTemplate {
             conditions: Vec::new(),
             is_available: true,
             order: 0,
+            cooldown_minutes: None,
+            max_uses: None,
+            available_times: Vec::new(),
         }
     }
 
@@ -85,6 +103,113 @@ impl InteractionTemplate {
         self.is_available = false;
         self
     }
+
+    pub fn with_cooldown_minutes(mut self, minutes: u32) -> Self {
+        self.cooldown_minutes = Some(minutes);
+        self
+    }
+
+    pub fn with_max_uses(mut self, uses: u32) -> Self {
+        self.max_uses = Some(uses);
+        self
+    }
+
+    pub fn with_available_time(mut self, time: TimeOfDay) -> Self {
+        self.available_times.push(time);
+        self
+    }
+
+    /// Whether a PC can use this interaction right now, given how they have
+    /// used it so far and the world's game time.
+    pub fn availability_for(
+        &self,
+        usage: Option<&InteractionUsage>,
+        game_time: &GameTime,
+    ) -> InteractionAvailability {
+        if !self.is_available {
+            return InteractionAvailability::Disabled;
+        }
+        if !self.available_times.is_empty()
+            && !self.available_times.contains(&game_time.time_of_day())
+        {
+            return InteractionAvailability::OutOfHours;
+        }
+        let Some(usage) = usage else {
+            return InteractionAvailability::Available;
+        };
+        if self.max_uses.is_some_and(|max| usage.uses >= max) {
+            return InteractionAvailability::UsedUp;
+        }
+        if let Some(minutes) = self.cooldown_minutes {
+            let ready_at = usage.last_used_at + Duration::minutes(i64::from(minutes));
+            if game_time.current() < ready_at {
+                return InteractionAvailability::CoolingDown { ready_at };
+            }
+        }
+        InteractionAvailability::Available
+    }
+
+    /// Uses a PC has left, if the interaction is limited
+    pub fn uses_left(&self, usage: Option<&InteractionUsage>) -> Option<u32> {
+        self.max_uses
+            .map(|max| max.saturating_sub(usage.map_or(0, |u| u.uses)))
+    }
+}
+
+/// Where an interaction stands for one PC
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InteractionAvailability {
+    Available,
+    /// Switched off by the DM
+    Disabled,
+    /// Not offered at this time of day
+    OutOfHours,
+    /// The PC has no uses left
+    UsedUp,
+    /// Used too recently; usable again at this game time
+    CoolingDown {
+        ready_at: DateTime<Utc>,
+    },
+}
+
+impl InteractionAvailability {
+    pub fn is_available(&self) -> bool {
+        matches!(self, InteractionAvailability::Available)
+    }
+}
+
+/// How often a PC has used an interaction (a USED_INTERACTION edge)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InteractionUsage {
+    pub interaction_id: InteractionId,
+    pub pc_id: PlayerCharacterId,
+    pub uses: u32,
+    /// Game time of the latest use
+    pub last_used_at: DateTime<Utc>,
+}
+
+impl InteractionUsage {
+    /// Usage after the first use
+    pub fn first(
+        interaction_id: InteractionId,
+        pc_id: PlayerCharacterId,
+        at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            interaction_id,
+            pc_id,
+            uses: 1,
+            last_used_at: at,
+        }
+    }
+
+    /// Count one more use at the given game time
+    pub fn record(mut self, at: DateTime<Utc>) -> Self {
+        self.uses = self.uses.saturating_add(1);
+        self.last_used_at = at;
+        self
+    }
 }
 
 /// Types of interactions players can perform
@@ -171,3 +296,60 @@ pub struct InteractionRequirement {
     /// Whether the required item is consumed when the interaction is used
     pub consumed: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at_hour(hour: u32) -> GameTime {
+        GameTime::starting_at(Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn cooldowns_uses_and_hours_gate_a_pc() {
+        let pc_id = PlayerCharacterId::new();
+        let pray = InteractionTemplate::new(
+            SceneId::new(),
+            "Pray at the shrine",
+            InteractionType::Custom("Pray".to_string()),
+            InteractionTarget::None,
+        )
+        .with_cooldown_minutes(60)
+        .with_max_uses(2)
+        .with_available_time(TimeOfDay::Morning);
+
+        let morning = at_hour(8);
+        assert_eq!(
+            pray.availability_for(None, &morning),
+            InteractionAvailability::Available
+        );
+        assert_eq!(pray.uses_left(None), Some(2));
+        assert_eq!(
+            pray.availability_for(None, &at_hour(20)),
+            InteractionAvailability::OutOfHours
+        );
+
+        let used = InteractionUsage::first(pray.id, pc_id, morning.current());
+        assert_eq!(
+            pray.availability_for(Some(&used), &at_hour(8)),
+            InteractionAvailability::CoolingDown {
+                ready_at: at_hour(9).current()
+            }
+        );
+        assert!(pray
+            .availability_for(Some(&used), &at_hour(9))
+            .is_available());
+
+        let used = used.record(at_hour(9).current());
+        assert_eq!(pray.uses_left(Some(&used)), Some(0));
+        assert_eq!(
+            pray.availability_for(Some(&used), &at_hour(11)),
+            InteractionAvailability::UsedUp
+        );
+        assert_eq!(
+            pray.disabled().availability_for(None, &morning),
+            InteractionAvailability::Disabled
+        );
+    }
+}
@@ -39,6 +39,10 @@ pub struct Item {
     pub can_contain_items: bool,
     /// Maximum number of items this container can hold (None = unlimited)
     pub container_limit: Option<u32>,
+    /// What it costs where nothing affects its market (None = not for sale).
+    /// Local prices come from market modifiers on its `item_type`.
+    #[serde(default)]
+    pub base_price: Option<u32>,
 }
 
 impl Item {
@@ -53,6 +57,15 @@ impl Item {
             properties: None,
             can_contain_items: false,
             container_limit: None,
+            base_price: None,
+        }
+    }
+
+    /// A copy of this item with a new ID
+    pub fn duplicate(&self) -> Self {
+        Self {
+            id: ItemId::new(),
+            ..self.clone()
         }
     }
 
@@ -88,6 +101,11 @@ impl Item {
         self.container_limit = Some(limit);
         self
     }
+
+    pub fn with_base_price(mut self, price: u32) -> Self {
+        self.base_price = Some(price);
+        self
+    }
 }
 
 /// Data for the POSSESSES edge between Character/PlayerCharacter and Item
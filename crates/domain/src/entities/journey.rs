@@ -0,0 +1,277 @@
+//! Journey entity - fast travel between known locations
+//!
+//! A journey carries a PC from one location to another in a single step,
+//! taking the hierarchy travel time (see [`LocationTree::travel`]). The
+//! land being crossed may have an [`EncounterTable`]: every
+//! `check_interval_minutes` of the way a percentage roll decides whether
+//! something happens, and a weighted roll picks what. The first encounter
+//! stops the journey part-way until the DM decides how it goes on.
+//!
+//! Journeys waiting on the DM are held in memory by the engine; they are
+//! not persisted.
+//!
+//! [`LocationTree::travel`]: crate::LocationTree::travel
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{JourneyId, LocationId, PlayerCharacterId, WorldId};
+
+/// Most entries one encounter table can hold
+pub const MAX_ENCOUNTER_ENTRIES: usize = 50;
+
+/// Longest encounter name, in characters
+pub const MAX_ENCOUNTER_NAME_LEN: usize = 200;
+
+/// Longest encounter description, in characters
+pub const MAX_ENCOUNTER_DESCRIPTION_LEN: usize = 2000;
+
+/// Shortest gap between encounter checks, in game minutes
+pub const MIN_ENCOUNTER_INTERVAL_MINUTES: u32 = 10;
+
+/// Something that can happen on the road
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncounterEntry {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Relative chance of this entry against the others in the table
+    pub weight: u32,
+}
+
+/// What might happen while travelling through a location
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncounterTable {
+    /// Game minutes between checks
+    pub check_interval_minutes: u32,
+    /// Chance of an encounter at each check, 0-100
+    pub chance_percent: u8,
+    pub entries: Vec<EncounterEntry>,
+}
+
+impl EncounterTable {
+    /// Build a table, trimming names and descriptions and rejecting tables
+    /// that could never roll anything
+    pub fn new(
+        check_interval_minutes: u32,
+        chance_percent: u8,
+        entries: Vec<EncounterEntry>,
+    ) -> Result<Self, DomainError> {
+        if check_interval_minutes < MIN_ENCOUNTER_INTERVAL_MINUTES {
+            return Err(DomainError::validation(format!(
+                "Encounter checks must be at least {} minutes apart",
+                MIN_ENCOUNTER_INTERVAL_MINUTES
+            )));
+        }
+        if chance_percent > 100 {
+            return Err(DomainError::validation(
+                "Encounter chance must be between 0 and 100 percent",
+            ));
+        }
+        if entries.is_empty() {
+            return Err(DomainError::validation(
+                "An encounter table needs at least one entry",
+            ));
+        }
+        if entries.len() > MAX_ENCOUNTER_ENTRIES {
+            return Err(DomainError::validation(format!(
+                "An encounter table can have at most {} entries",
+                MAX_ENCOUNTER_ENTRIES
+            )));
+        }
+
+        let entries = entries
+            .into_iter()
+            .map(|entry| {
+                let name = entry.name.trim().to_string();
+                let description = entry.description.trim().to_string();
+                if name.is_empty() {
+                    return Err(DomainError::validation("Encounter name cannot be empty"));
+                }
+                if name.chars().count() > MAX_ENCOUNTER_NAME_LEN {
+                    return Err(DomainError::validation(format!(
+                        "Encounter name cannot exceed {} characters",
+                        MAX_ENCOUNTER_NAME_LEN
+                    )));
+                }
+                if description.chars().count() > MAX_ENCOUNTER_DESCRIPTION_LEN {
+                    return Err(DomainError::validation(format!(
+                        "Encounter description cannot exceed {} characters",
+                        MAX_ENCOUNTER_DESCRIPTION_LEN
+                    )));
+                }
+                if entry.weight == 0 {
+                    return Err(DomainError::validation(
+                        "Encounter weight must be at least 1",
+                    ));
+                }
+                Ok(EncounterEntry {
+                    name,
+                    description,
+                    weight: entry.weight,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            check_interval_minutes,
+            chance_percent,
+            entries,
+        })
+    }
+
+    /// The first encounter between `from_minute` and `total_minutes` of a
+    /// journey, if any
+    ///
+    /// Checks fall on each multiple of the interval after `from_minute` and
+    /// before arrival. `rng` returns a number in `[min, max]`, inclusive.
+    pub fn roll(
+        &self,
+        from_minute: u32,
+        total_minutes: u32,
+        mut rng: impl FnMut(i32, i32) -> i32,
+    ) -> Option<Encounter> {
+        let interval = self.check_interval_minutes.max(1);
+        let total_weight: u32 = self.entries.iter().map(|e| e.weight).sum();
+        if self.chance_percent == 0 || total_weight == 0 {
+            return None;
+        }
+
+        let mut at_minute = (from_minute / interval + 1) * interval;
+        while at_minute < total_minutes {
+            if rng(1, 100) <= i32::from(self.chance_percent) {
+                let mut pick = rng(1, total_weight as i32).max(1) as u32;
+                let entry = self.entries.iter().find(|e| {
+                    if pick <= e.weight {
+                        true
+                    } else {
+                        pick -= e.weight;
+                        false
+                    }
+                })?;
+                return Some(Encounter {
+                    at_minute,
+                    name: entry.name.clone(),
+                    description: entry.description.clone(),
+                });
+            }
+            at_minute += interval;
+        }
+        None
+    }
+}
+
+/// An encounter that stopped a journey
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Encounter {
+    /// Game minutes into the journey when it happened
+    pub at_minute: u32,
+    pub name: String,
+    pub description: String,
+}
+
+/// A PC on the road between two locations
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Journey {
+    pub id: JourneyId,
+    pub world_id: WorldId,
+    pub pc_id: PlayerCharacterId,
+    pub from_location_id: LocationId,
+    pub to_location_id: LocationId,
+    /// Game minutes the whole journey takes
+    pub total_minutes: u32,
+    /// Game minutes travelled so far
+    pub elapsed_minutes: u32,
+    /// The encounter the journey is stopped for, if it is stopped
+    pub encounter: Option<Encounter>,
+    pub started_at: DateTime<Utc>,
+}
+
+impl Journey {
+    pub fn new(
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        from_location_id: LocationId,
+        to_location_id: LocationId,
+        total_minutes: u32,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: JourneyId::new(),
+            world_id,
+            pc_id,
+            from_location_id,
+            to_location_id,
+            total_minutes,
+            elapsed_minutes: 0,
+            encounter: None,
+            started_at: now,
+        }
+    }
+
+    /// Game minutes still to go
+    pub fn remaining_minutes(&self) -> u32 {
+        self.total_minutes.saturating_sub(self.elapsed_minutes)
+    }
+
+    /// Stop the journey where the encounter happened
+    pub fn interrupt(&mut self, encounter: Encounter) {
+        self.elapsed_minutes = encounter.at_minute.min(self.total_minutes);
+        self.encounter = Some(encounter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, weight: u32) -> EncounterEntry {
+        EncounterEntry {
+            name: name.to_string(),
+            description: String::new(),
+            weight,
+        }
+    }
+
+    #[test]
+    fn new_table_rejects_tables_that_cannot_roll() {
+        assert!(EncounterTable::new(5, 20, vec![entry("Wolves", 1)]).is_err());
+        assert!(EncounterTable::new(60, 101, vec![entry("Wolves", 1)]).is_err());
+        assert!(EncounterTable::new(60, 20, vec![]).is_err());
+        assert!(EncounterTable::new(60, 20, vec![entry("  ", 1)]).is_err());
+        assert!(EncounterTable::new(60, 20, vec![entry("Wolves", 0)]).is_err());
+
+        let table = EncounterTable::new(60, 20, vec![entry("  Wolves ", 2)]).unwrap();
+        assert_eq!(table.entries[0].name, "Wolves");
+    }
+
+    #[test]
+    fn roll_checks_each_interval_before_arrival_and_picks_by_weight() {
+        let table =
+            EncounterTable::new(60, 25, vec![entry("Wolves", 1), entry("Bandits", 3)]).unwrap();
+
+        // Miss at 60, hit at 120, then land on the second entry
+        let mut rolls = vec![90, 25, 2].into_iter();
+        let encounter = table.roll(0, 300, |_, _| rolls.next().unwrap()).unwrap();
+        assert_eq!(encounter.at_minute, 120);
+        assert_eq!(encounter.name, "Bandits");
+
+        // Resuming at 120 checks 180 and 240, never the arrival minute
+        let mut checked = 0;
+        assert!(table
+            .roll(120, 300, |_, _| {
+                checked += 1;
+                100
+            })
+            .is_none());
+        assert_eq!(checked, 2);
+
+        // A journey shorter than one interval is never checked
+        assert!(table.roll(0, 60, |_, _| 1).is_none());
+    }
+}
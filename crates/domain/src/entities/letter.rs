@@ -0,0 +1,277 @@
+//! Letter entity - in-fiction mail between characters
+//!
+//! A PC can write to an NPC or to another PC. The letter spends game time
+//! in transit, longer the further apart writer and addressee are, and is
+//! only delivered once the world's clock passes its delivery time. NPCs
+//! write back to the letters they receive.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_LETTER]->(Letter)`
+//! - `(Letter)-[:REPLY_TO]->(Letter)`
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{CharacterId, LetterId, PlayerCharacterId, WorldId};
+
+/// Longest letter subject, in characters
+pub const MAX_LETTER_SUBJECT_LEN: usize = 200;
+
+/// Longest letter body, in characters
+pub const MAX_LETTER_BODY_LEN: usize = 8000;
+
+/// Shortest time any letter spends in transit, in game minutes
+pub const MIN_DELIVERY_MINUTES: u32 = 60;
+
+/// Time in transit when no route is known between writer and addressee,
+/// in game minutes
+pub const UNROUTED_DELIVERY_MINUTES: u32 = 3 * 24 * 60;
+
+/// Who wrote a letter, or who it is addressed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "id", rename_all = "camelCase")]
+pub enum Correspondent {
+    Pc(PlayerCharacterId),
+    Npc(CharacterId),
+}
+
+impl Correspondent {
+    pub fn pc_id(&self) -> Option<PlayerCharacterId> {
+        match self {
+            Correspondent::Pc(id) => Some(*id),
+            Correspondent::Npc(_) => None,
+        }
+    }
+
+    pub fn npc_id(&self) -> Option<CharacterId> {
+        match self {
+            Correspondent::Pc(_) => None,
+            Correspondent::Npc(id) => Some(*id),
+        }
+    }
+}
+
+/// How long a letter takes to arrive, given the travel time between writer
+/// and addressee if there is a known route
+pub fn delivery_minutes(route_minutes: Option<u32>) -> u32 {
+    route_minutes
+        .unwrap_or(UNROUTED_DELIVERY_MINUTES)
+        .max(MIN_DELIVERY_MINUTES)
+}
+
+/// A letter, in transit or delivered
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Letter {
+    pub id: LetterId,
+    pub world_id: WorldId,
+    pub sender: Correspondent,
+    pub recipient: Correspondent,
+    pub subject: String,
+    pub body: String,
+    /// The letter this one answers
+    pub in_reply_to: Option<LetterId>,
+    /// Game time the letter was sent
+    pub sent_at: DateTime<Utc>,
+    /// Game time the letter arrives
+    pub deliver_at: DateTime<Utc>,
+    /// When the letter reached its addressee
+    pub delivered_at: Option<DateTime<Utc>>,
+    /// When the addressee first read it
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Letter {
+    /// A letter sent at game time `sent_at` that takes `delivery_minutes`
+    /// of game time to arrive
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        world_id: WorldId,
+        sender: Correspondent,
+        recipient: Correspondent,
+        subject: impl Into<String>,
+        body: impl Into<String>,
+        sent_at: DateTime<Utc>,
+        delivery_minutes: u32,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        if sender == recipient {
+            return Err(DomainError::validation(
+                "A letter cannot be addressed to its writer",
+            ));
+        }
+        if sender.pc_id().is_none() && recipient.pc_id().is_none() {
+            return Err(DomainError::validation(
+                "A letter needs a player character at one end",
+            ));
+        }
+        Ok(Self {
+            id: LetterId::new(),
+            world_id,
+            sender,
+            recipient,
+            subject: validate_subject(subject.into())?,
+            body: validate_body(body.into())?,
+            in_reply_to: None,
+            sent_at,
+            deliver_at: sent_at + Duration::minutes(i64::from(delivery_minutes)),
+            delivered_at: None,
+            read_at: None,
+            created_at: now,
+        })
+    }
+
+    pub fn in_reply_to(mut self, letter_id: LetterId) -> Self {
+        self.in_reply_to = Some(letter_id);
+        self
+    }
+
+    pub fn is_delivered(&self) -> bool {
+        self.delivered_at.is_some()
+    }
+
+    /// Whether the world's clock has reached the letter's delivery time
+    pub fn is_due(&self, game_now: DateTime<Utc>) -> bool {
+        !self.is_delivered() && game_now >= self.deliver_at
+    }
+
+    /// Hand the letter to its addressee
+    pub fn deliver(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.is_delivered() {
+            return Err(DomainError::invalid_state_transition(
+                "This letter has already been delivered",
+            ));
+        }
+        self.delivered_at = Some(now);
+        Ok(())
+    }
+
+    /// Note that the addressee has read the letter. Reading it again
+    /// changes nothing.
+    pub fn mark_read(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if !self.is_delivered() {
+            return Err(DomainError::invalid_state_transition(
+                "This letter has not arrived yet",
+            ));
+        }
+        if self.read_at.is_none() {
+            self.read_at = Some(now);
+        }
+        Ok(())
+    }
+
+    /// Whether the PC wrote the letter or is its addressee
+    pub fn involves_pc(&self, pc_id: PlayerCharacterId) -> bool {
+        self.sender.pc_id() == Some(pc_id) || self.recipient.pc_id() == Some(pc_id)
+    }
+
+    /// PCs who can read the letter now: its writer, and its addressee once
+    /// it has arrived
+    pub fn pc_readers(&self) -> Vec<PlayerCharacterId> {
+        let mut readers: Vec<_> = self.sender.pc_id().into_iter().collect();
+        if self.is_delivered() {
+            readers.extend(self.recipient.pc_id());
+        }
+        readers
+    }
+
+    /// Subject for an answer to this letter: "Re: " once, and no longer
+    /// than a subject may be
+    pub fn reply_subject(&self) -> String {
+        if self.subject.is_empty() || self.subject.starts_with("Re: ") {
+            return self.subject.clone();
+        }
+        format!("Re: {}", self.subject)
+            .chars()
+            .take(MAX_LETTER_SUBJECT_LEN)
+            .collect()
+    }
+}
+
+fn validate_subject(subject: String) -> Result<String, DomainError> {
+    let subject = subject.trim().to_string();
+    if subject.chars().count() > MAX_LETTER_SUBJECT_LEN {
+        return Err(DomainError::validation(format!(
+            "Letter subject cannot exceed {} characters",
+            MAX_LETTER_SUBJECT_LEN
+        )));
+    }
+    Ok(subject)
+}
+
+fn validate_body(body: String) -> Result<String, DomainError> {
+    let body = body.trim().to_string();
+    if body.is_empty() {
+        return Err(DomainError::validation("A letter cannot be empty"));
+    }
+    if body.chars().count() > MAX_LETTER_BODY_LEN {
+        return Err(DomainError::validation(format!(
+            "Letter cannot exceed {} characters",
+            MAX_LETTER_BODY_LEN
+        )));
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letter(minutes: u32) -> Letter {
+        Letter::new(
+            WorldId::new(),
+            Correspondent::Pc(PlayerCharacterId::new()),
+            Correspondent::Npc(CharacterId::new()),
+            "About the debt",
+            "I will have the coin by the new moon.",
+            Utc::now(),
+            minutes,
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn delivery_time_has_a_floor_and_a_fallback() {
+        assert_eq!(delivery_minutes(Some(5)), MIN_DELIVERY_MINUTES);
+        assert_eq!(delivery_minutes(Some(600)), 600);
+        assert_eq!(delivery_minutes(None), UNROUTED_DELIVERY_MINUTES);
+    }
+
+    #[test]
+    fn letters_arrive_once_game_time_passes_delivery() {
+        let mut letter = letter(120);
+        assert!(!letter.is_due(letter.sent_at + Duration::minutes(119)));
+        assert!(letter.mark_read(Utc::now()).is_err());
+        assert!(letter.is_due(letter.sent_at + Duration::minutes(120)));
+        assert_eq!(letter.pc_readers().len(), 1);
+
+        letter.deliver(Utc::now()).unwrap();
+        assert!(!letter.is_due(letter.sent_at + Duration::days(1)));
+        assert!(letter.deliver(Utc::now()).is_err());
+        assert!(letter.mark_read(Utc::now()).is_ok());
+    }
+
+    #[test]
+    fn replies_prefix_the_subject_once() {
+        let letter = letter(60);
+        assert_eq!(letter.reply_subject(), "Re: About the debt");
+        let mut reply = letter.clone();
+        reply.subject = letter.reply_subject();
+        assert_eq!(reply.reply_subject(), "Re: About the debt");
+    }
+
+    #[test]
+    fn letters_need_a_pc_and_a_body() {
+        let npc = Correspondent::Npc(CharacterId::new());
+        let other_npc = Correspondent::Npc(CharacterId::new());
+        let now = Utc::now();
+        assert!(Letter::new(WorldId::new(), npc, other_npc, "", "Hi", now, 60, now).is_err());
+        let pc = Correspondent::Pc(PlayerCharacterId::new());
+        assert!(Letter::new(WorldId::new(), pc, pc, "", "Hi", now, 60, now).is_err());
+        assert!(Letter::new(WorldId::new(), pc, npc, "", "   ", now, 60, now).is_err());
+        assert!(Letter::new(WorldId::new(), pc, npc, "", "Hi", now, 60, now).is_ok());
+    }
+}
@@ -0,0 +1,257 @@
+//! Library Entry entity - content a user carries between worlds
+//!
+//! A DM publishes an NPC, item, challenge or lore entry from one world into
+//! their personal library and imports it into another, so the recurring
+//! villain or the favorite magic sword comes along to the next campaign.
+//! Entries belong to a user, not a world, and outlive the world they came from.
+//!
+//! An entry is a snapshot: later edits to the source don't reach it, and
+//! importing it creates an independent copy with fresh IDs.
+//!
+//! # Neo4j Relationships
+//! - None; `(LibraryEntry {owner_id})` is keyed by its owning user
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{LibraryEntryId, LoreChunkId, LoreId, WorldId};
+use crate::types::EntityType;
+
+use super::{Challenge, Character, Item, Lore, OutcomeTrigger, TriggerType};
+
+/// Most entries a user may keep in their library
+pub const MAX_LIBRARY_ENTRIES_PER_USER: usize = 500;
+
+/// A published snapshot of one piece of content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryEntry {
+    pub id: LibraryEntryId,
+    /// The user who published the entry
+    pub owner_id: String,
+    /// Display name, taken from the content when published
+    pub name: String,
+    /// Owner's notes (e.g., "Big bad of the Ashfall campaign")
+    pub description: Option<String>,
+    /// The world the content was published from
+    pub source_world_id: WorldId,
+    pub content: LibraryContent,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The content an entry carries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "entity", rename_all = "snake_case")]
+pub enum LibraryContent {
+    Character(Character),
+    Item(Item),
+    Challenge(Challenge),
+    Lore(Lore),
+}
+
+impl LibraryEntry {
+    pub fn new(
+        owner_id: impl Into<String>,
+        description: Option<String>,
+        content: LibraryContent,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let owner_id = owner_id.into();
+        if owner_id.trim().is_empty() {
+            return Err(DomainError::validation(
+                "Publishing to the library needs a user",
+            ));
+        }
+        Ok(Self {
+            id: LibraryEntryId::new(),
+            owner_id,
+            name: content.name().to_string(),
+            description: description.filter(|d| !d.trim().is_empty()),
+            source_world_id: content.world_id(),
+            content,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+}
+
+impl LibraryContent {
+    pub fn entity_type(&self) -> EntityType {
+        match self {
+            LibraryContent::Character(_) => EntityType::Character,
+            LibraryContent::Item(_) => EntityType::Item,
+            LibraryContent::Challenge(_) => EntityType::Challenge,
+            LibraryContent::Lore(_) => EntityType::Lore,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            LibraryContent::Character(c) => &c.name,
+            LibraryContent::Item(i) => &i.name,
+            LibraryContent::Challenge(c) => &c.name,
+            LibraryContent::Lore(l) => &l.title,
+        }
+    }
+
+    /// One-line description of the content for library listings
+    pub fn summary(&self) -> &str {
+        match self {
+            LibraryContent::Character(c) => &c.description,
+            LibraryContent::Item(i) => i.description.as_deref().unwrap_or_default(),
+            LibraryContent::Challenge(c) => &c.description,
+            LibraryContent::Lore(l) => &l.summary,
+        }
+    }
+
+    pub fn world_id(&self) -> WorldId {
+        match self {
+            LibraryContent::Character(c) => c.world_id,
+            LibraryContent::Item(i) => i.world_id,
+            LibraryContent::Challenge(c) => c.world_id,
+            LibraryContent::Lore(l) => l.world_id,
+        }
+    }
+
+    /// A copy for `world_id` with fresh IDs throughout.
+    ///
+    /// References to other content only make sense in the source world, so
+    /// they are dropped: a challenge loses the triggers and outcome effects
+    /// that point at other challenges, scenes or clocks.
+    pub fn import_into(&self, world_id: WorldId, now: DateTime<Utc>) -> Self {
+        match self {
+            LibraryContent::Character(c) => LibraryContent::Character(Character {
+                world_id,
+                ..c.duplicate()
+            }),
+            LibraryContent::Item(i) => LibraryContent::Item(Item {
+                world_id,
+                ..i.duplicate()
+            }),
+            LibraryContent::Challenge(c) => {
+                let mut challenge = Challenge {
+                    world_id,
+                    ..c.duplicate()
+                };
+                challenge
+                    .trigger_conditions
+                    .retain(|t| !matches!(t.condition_type, TriggerType::ChallengeComplete { .. }));
+                for outcome in challenge.outcomes.all_mut() {
+                    outcome.triggers.retain(|t| {
+                        !matches!(
+                            t,
+                            OutcomeTrigger::EnableChallenge { .. }
+                                | OutcomeTrigger::DisableChallenge { .. }
+                                | OutcomeTrigger::TriggerScene { .. }
+                                | OutcomeTrigger::TickClock { .. }
+                        )
+                    });
+                }
+                LibraryContent::Challenge(challenge)
+            }
+            LibraryContent::Lore(l) => {
+                let mut lore = l.clone();
+                lore.id = LoreId::new();
+                lore.world_id = world_id;
+                for chunk in &mut lore.chunks {
+                    chunk.id = LoreChunkId::new();
+                }
+                lore.created_at = now;
+                lore.updated_at = now;
+                LibraryContent::Lore(lore)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{ChallengeOutcomes, Difficulty, LoreCategory, Outcome, TriggerCondition};
+    use crate::ids::ChallengeId;
+
+    #[test]
+    fn importing_a_challenge_drops_references_into_the_source_world() {
+        let source = WorldId::new();
+        let target = WorldId::new();
+        let mut challenge = Challenge::new(source, "Pick the lock", Difficulty::d20_hard());
+        challenge.trigger_conditions = vec![
+            TriggerCondition::new(
+                TriggerType::ObjectInteraction {
+                    keywords: vec!["lock".to_string()],
+                },
+                "Touches the lock",
+            ),
+            TriggerCondition::new(
+                TriggerType::ChallengeComplete {
+                    challenge_id: ChallengeId::new(),
+                    requires_success: None,
+                },
+                "After the guard is distracted",
+            ),
+        ];
+        challenge.outcomes = ChallengeOutcomes {
+            success: Outcome::new("The door swings open")
+                .with_trigger(OutcomeTrigger::RevealInformation {
+                    info: "A ledger lies inside".to_string(),
+                    persist: true,
+                })
+                .with_trigger(OutcomeTrigger::EnableChallenge {
+                    challenge_id: ChallengeId::new(),
+                }),
+            ..ChallengeOutcomes::simple("", "The pick snaps")
+        };
+        let original_id = challenge.id;
+        let content = LibraryContent::Challenge(challenge);
+
+        let LibraryContent::Challenge(imported) = content.import_into(target, Utc::now()) else {
+            panic!("expected a challenge");
+        };
+        assert_ne!(imported.id, original_id);
+        assert_eq!(imported.world_id, target);
+        assert_eq!(imported.name, "Pick the lock");
+        assert_eq!(imported.trigger_conditions.len(), 1);
+        assert_eq!(imported.outcomes.success.triggers.len(), 1);
+        assert!(matches!(
+            imported.outcomes.success.triggers[0],
+            OutcomeTrigger::RevealInformation { .. }
+        ));
+    }
+
+    #[test]
+    fn importing_lore_gives_every_chunk_a_new_id() {
+        let source = WorldId::new();
+        let lore = Lore::new(
+            source,
+            "The Fall of House Valeren",
+            LoreCategory::Historical,
+            Utc::now(),
+        )
+        .with_chunk("The keep burned")
+        .with_chunk("No heir was found");
+        let chunk_ids = lore.chunk_ids();
+        let entry = LibraryEntry::new("dm-1", None, LibraryContent::Lore(lore), Utc::now())
+            .expect("valid entry");
+        assert_eq!(entry.name, "The Fall of House Valeren");
+        assert_eq!(entry.source_world_id, source);
+
+        let target = WorldId::new();
+        let LibraryContent::Lore(imported) = entry.content.import_into(target, Utc::now()) else {
+            panic!("expected lore");
+        };
+        assert_eq!(imported.world_id, target);
+        assert_eq!(imported.chunks.len(), 2);
+        assert!(imported
+            .chunk_ids()
+            .iter()
+            .all(|id| !chunk_ids.contains(id)));
+    }
+
+    #[test]
+    fn an_entry_needs_an_owner() {
+        let item = Item::new(WorldId::new(), "Sunblade");
+        assert!(LibraryEntry::new(" ", None, LibraryContent::Item(item), Utc::now()).is_err());
+    }
+}
@@ -4,10 +4,20 @@
 //! Connections between locations use CONNECTED_TO edges.
 //! Regions are separate nodes with HAS_REGION edges (see region.rs).
 
-use super::region::MapBounds;
+use std::collections::{HashMap, HashSet};
+
+use super::journey::EncounterTable;
+use super::region::{LockRequirements, MapBounds};
 use serde::{Deserialize, Serialize};
 use wrldbldr_domain::{LocationId, RegionId, WorldId};
 
+/// Deepest nesting allowed, counting the outermost location as level 1
+pub const MAX_LOCATION_DEPTH: usize = 10;
+
+/// Game minutes to travel between two places inside a location that has
+/// no scale
+pub const DEFAULT_CROSSING_MINUTES: u32 = 10;
+
 /// A location in the world
 ///
 /// Locations form a hierarchy via Neo4j edges:
@@ -30,9 +40,27 @@ pub struct Location {
     /// Path to the top-down map image for navigation between regions
     pub map_asset: Option<String>,
 
+    // Hierarchy (continent → kingdom → city → building)
+    /// The location this one sits inside; None for a top-level location
+    #[serde(default)]
+    pub parent_location_id: Option<LocationId>,
+    /// How large a place this is
+    #[serde(default)]
+    pub scale: Option<LocationScale>,
+    /// Game minutes to travel between two places inside this location;
+    /// the scale's default when unset
+    #[serde(default)]
+    pub crossing_minutes: Option<u32>,
+
     // Position on parent location's map (if this location is nested)
     /// Bounds defining where this location appears on its parent's map
     pub parent_map_bounds: Option<MapBounds>,
+    /// Where this location's pin sits on the world overview map
+    #[serde(default)]
+    pub world_map_position: Option<WorldMapPosition>,
+    /// What might happen to travellers crossing this location
+    #[serde(default)]
+    pub encounter_table: Option<EncounterTable>,
 
     // Default entry point
     /// Default region to place players when arriving without a specific region target
@@ -58,7 +86,12 @@ impl Location {
             location_type,
             backdrop_asset: None,
             map_asset: None,
+            parent_location_id: None,
+            scale: None,
+            crossing_minutes: None,
             parent_map_bounds: None,
+            world_map_position: None,
+            encounter_table: None,
             default_region_id: None,
             atmosphere: None,
             presence_cache_ttl_hours: 3,
@@ -66,6 +99,17 @@ impl Location {
         }
     }
 
+    /// A copy of this location with a new ID
+    ///
+    /// The default region still points at the original's region; callers
+    /// copying the regions too should repoint it.
+    pub fn duplicate(&self) -> Self {
+        Self {
+            id: LocationId::new(),
+            ..self.clone()
+        }
+    }
+
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
         self.description = description.into();
         self
@@ -81,11 +125,41 @@ impl Location {
         self
     }
 
+    pub fn with_parent(mut self, parent_id: LocationId) -> Self {
+        self.parent_location_id = Some(parent_id);
+        self
+    }
+
+    pub fn with_scale(mut self, scale: LocationScale) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Game minutes to travel between two places inside this location
+    pub fn crossing_minutes(&self) -> u32 {
+        self.crossing_minutes.unwrap_or_else(|| {
+            self.scale.map_or(
+                DEFAULT_CROSSING_MINUTES,
+                LocationScale::default_crossing_minutes,
+            )
+        })
+    }
+
     pub fn with_parent_map_bounds(mut self, bounds: MapBounds) -> Self {
         self.parent_map_bounds = Some(bounds);
         self
     }
 
+    pub fn with_world_map_position(mut self, position: WorldMapPosition) -> Self {
+        self.world_map_position = Some(position);
+        self
+    }
+
+    pub fn with_encounter_table(mut self, table: EncounterTable) -> Self {
+        self.encounter_table = Some(table);
+        self
+    }
+
     pub fn with_default_region(mut self, region_id: RegionId) -> Self {
         self.default_region_id = Some(region_id);
         self
@@ -116,6 +190,29 @@ impl Location {
     }
 }
 
+/// A pin on the world overview map
+///
+/// Stored as percentages of the map image so the pin stays put however the
+/// image is scaled.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldMapPosition {
+    /// Horizontal position as a percentage of the image width (0-100)
+    pub x: f32,
+    /// Vertical position as a percentage of the image height (0-100)
+    pub y: f32,
+}
+
+impl WorldMapPosition {
+    /// Position on the map, clamped to the image
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            x: x.clamp(0.0, 100.0),
+            y: y.clamp(0.0, 100.0),
+        }
+    }
+}
+
 /// The type of location
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -131,6 +228,188 @@ pub enum LocationType {
     Unknown,
 }
 
+/// How large a place a location is, from a continent down to a room
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LocationScale {
+    Continent,
+    Kingdom,
+    City,
+    District,
+    Building,
+    Room,
+    /// Unknown scale for forward compatibility
+    #[serde(other)]
+    Unknown,
+}
+
+impl LocationScale {
+    /// Game minutes to travel between two places inside a location of this
+    /// scale
+    pub fn default_crossing_minutes(self) -> u32 {
+        match self {
+            Self::Continent => 14 * 24 * 60,
+            Self::Kingdom => 3 * 24 * 60,
+            Self::City => 45,
+            Self::District => 15,
+            Self::Building => 2,
+            Self::Room => 0,
+            Self::Unknown => DEFAULT_CROSSING_MINUTES,
+        }
+    }
+}
+
+/// Travel time between two locations through the hierarchy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HierarchyTravel {
+    /// Game minutes the journey takes
+    pub minutes: u32,
+    /// The innermost location containing both ends; None when travelling
+    /// nowhere
+    pub via: Option<LocationId>,
+}
+
+/// A world's locations arranged by parent, for breadcrumbs, nested listings
+/// and travel times
+///
+/// Tolerates broken data: a parent that isn't in the list ends the chain,
+/// and a loop is cut where it closes.
+pub struct LocationTree<'a> {
+    by_id: HashMap<LocationId, &'a Location>,
+    children: HashMap<LocationId, Vec<&'a Location>>,
+}
+
+impl<'a> LocationTree<'a> {
+    pub fn new(locations: &'a [Location]) -> Self {
+        let by_id: HashMap<_, _> = locations.iter().map(|l| (l.id, l)).collect();
+        let mut children: HashMap<LocationId, Vec<&'a Location>> = HashMap::new();
+        for location in locations {
+            if let Some(parent) = location.parent_location_id {
+                children.entry(parent).or_default().push(location);
+            }
+        }
+        for list in children.values_mut() {
+            list.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        Self { by_id, children }
+    }
+
+    pub fn get(&self, id: LocationId) -> Option<&'a Location> {
+        self.by_id.get(&id).copied()
+    }
+
+    /// The locations containing this one, outermost first
+    pub fn ancestors(&self, id: LocationId) -> Vec<&'a Location> {
+        let mut chain = Vec::new();
+        let mut seen = HashSet::from([id]);
+        let mut next = self.get(id).and_then(|l| l.parent_location_id);
+        while let Some(parent_id) = next {
+            if !seen.insert(parent_id) {
+                break;
+            }
+            let Some(parent) = self.get(parent_id) else {
+                break;
+            };
+            chain.push(parent);
+            next = parent.parent_location_id;
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// The locations directly inside this one, by name
+    pub fn children(&self, id: LocationId) -> Vec<&'a Location> {
+        self.children.get(&id).cloned().unwrap_or_default()
+    }
+
+    /// Every location inside this one, depth-first, each with how many
+    /// levels below it it sits (children are 1)
+    pub fn descendants(&self, id: LocationId) -> Vec<(&'a Location, u32)> {
+        let mut found = Vec::new();
+        let mut seen = HashSet::from([id]);
+        let mut stack: Vec<(&'a Location, u32)> = self
+            .children(id)
+            .into_iter()
+            .rev()
+            .map(|l| (l, 1))
+            .collect();
+        while let Some((location, depth)) = stack.pop() {
+            if !seen.insert(location.id) {
+                continue;
+            }
+            found.push((location, depth));
+            stack.extend(
+                self.children(location.id)
+                    .into_iter()
+                    .rev()
+                    .map(|l| (l, depth + 1)),
+            );
+        }
+        found
+    }
+
+    /// Why `id` can't be placed inside `parent_id`, if it can't
+    pub fn nesting_problem(&self, id: LocationId, parent_id: LocationId) -> Option<String> {
+        if id == parent_id {
+            return Some("A location cannot contain itself".to_string());
+        }
+        let parent_chain = self.ancestors(parent_id);
+        if parent_chain.iter().any(|l| l.id == id) {
+            return Some(
+                "A location cannot be placed inside one of its own sub-locations".to_string(),
+            );
+        }
+        let below = self
+            .descendants(id)
+            .iter()
+            .map(|(_, depth)| *depth as usize)
+            .max()
+            .unwrap_or(0);
+        // The parent's chain, the parent, this location, and what it contains
+        if parent_chain.len() + 2 + below > MAX_LOCATION_DEPTH {
+            return Some(format!(
+                "Locations can be nested at most {} levels deep",
+                MAX_LOCATION_DEPTH
+            ));
+        }
+        None
+    }
+
+    /// Travel time between two locations through the hierarchy
+    ///
+    /// Two places inside the same location are the innermost shared
+    /// location's crossing time apart; a location and a place inside it are
+    /// half of it. None when they share no outer location.
+    pub fn travel(&self, from: LocationId, to: LocationId) -> Option<HierarchyTravel> {
+        if from == to {
+            return Some(HierarchyTravel {
+                minutes: 0,
+                via: None,
+            });
+        }
+        let with_self = |id: LocationId| -> Vec<LocationId> {
+            let mut chain: Vec<LocationId> = self.ancestors(id).iter().map(|l| l.id).collect();
+            chain.push(id);
+            chain
+        };
+        let to_chain = with_self(to);
+        let shared = with_self(from)
+            .into_iter()
+            .rev()
+            .find(|id| to_chain.contains(id))?;
+        let crossing = self.get(shared)?.crossing_minutes();
+        let minutes = if shared == from || shared == to {
+            crossing / 2
+        } else {
+            crossing
+        };
+        Some(HierarchyTravel {
+            minutes,
+            via: Some(shared),
+        })
+    }
+}
+
 /// A connection between two locations
 ///
 /// Stored as a `CONNECTED_TO` edge in Neo4j with properties.
@@ -151,6 +430,10 @@ pub struct LocationConnection {
     pub is_locked: bool,
     /// Description of what's needed to unlock (if locked)
     pub lock_description: Option<String>,
+    /// Keys and challenge that open the lock; kept when unlocked so the
+    /// DM can lock it again
+    #[serde(default)]
+    pub lock: LockRequirements,
 }
 
 impl LocationConnection {
@@ -164,6 +447,7 @@ impl LocationConnection {
             travel_time: 0,
             is_locked: false,
             lock_description: None,
+            lock: LockRequirements::default(),
         }
     }
 
@@ -207,4 +491,110 @@ impl LocationConnection {
         self.lock_description = Some(description.into());
         self
     }
+
+    pub fn with_lock(mut self, lock: LockRequirements) -> Self {
+        self.lock = lock;
+        self
+    }
+
+    /// Open the lock, keeping what opens it
+    pub fn unlock(&mut self) {
+        self.is_locked = false;
+        self.lock_description = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn place(world_id: WorldId, name: &str, scale: LocationScale) -> Location {
+        Location::new(world_id, name, LocationType::Exterior).with_scale(scale)
+    }
+
+    fn sample() -> Vec<Location> {
+        let world_id = WorldId::new();
+        let continent = place(world_id, "Aria", LocationScale::Continent);
+        let kingdom = place(world_id, "Valdor", LocationScale::Kingdom).with_parent(continent.id);
+        let city = place(world_id, "Haven", LocationScale::City).with_parent(kingdom.id);
+        let inn = place(world_id, "Inn", LocationScale::Building).with_parent(city.id);
+        let temple = place(world_id, "Temple", LocationScale::Building).with_parent(city.id);
+        vec![continent, kingdom, city, inn, temple]
+    }
+
+    #[test]
+    fn ancestors_are_outermost_first() {
+        let locations = sample();
+        let tree = LocationTree::new(&locations);
+
+        let names: Vec<&str> = tree
+            .ancestors(locations[3].id)
+            .iter()
+            .map(|l| l.name.as_str())
+            .collect();
+
+        assert_eq!(names, ["Aria", "Valdor", "Haven"]);
+    }
+
+    #[test]
+    fn descendants_carry_their_depth() {
+        let locations = sample();
+        let tree = LocationTree::new(&locations);
+
+        let found: Vec<(&str, u32)> = tree
+            .descendants(locations[1].id)
+            .iter()
+            .map(|(l, depth)| (l.name.as_str(), *depth))
+            .collect();
+
+        assert_eq!(found, [("Haven", 1), ("Inn", 2), ("Temple", 2)]);
+    }
+
+    #[test]
+    fn nesting_inside_own_descendant_is_refused() {
+        let locations = sample();
+        let tree = LocationTree::new(&locations);
+
+        assert!(tree
+            .nesting_problem(locations[0].id, locations[3].id)
+            .is_some());
+        assert!(tree
+            .nesting_problem(locations[3].id, locations[4].id)
+            .is_none());
+    }
+
+    #[test]
+    fn travel_uses_innermost_shared_location() {
+        let locations = sample();
+        let tree = LocationTree::new(&locations);
+
+        let across_city = tree.travel(locations[3].id, locations[4].id).unwrap();
+        assert_eq!(across_city.via, Some(locations[2].id));
+        assert_eq!(across_city.minutes, 45);
+
+        let into_city = tree.travel(locations[2].id, locations[3].id).unwrap();
+        assert_eq!(into_city.minutes, 22);
+
+        let elsewhere = Location::new(WorldId::new(), "Elsewhere", LocationType::Abstract);
+        let mut with_orphan = locations.clone();
+        with_orphan.push(elsewhere.clone());
+        let tree = LocationTree::new(&with_orphan);
+        assert!(tree.travel(locations[3].id, elsewhere.id).is_none());
+    }
+
+    #[test]
+    fn loops_in_broken_data_are_cut() {
+        let mut locations = sample();
+        locations[0].parent_location_id = Some(locations[3].id);
+        let tree = LocationTree::new(&locations);
+
+        assert_eq!(tree.ancestors(locations[3].id).len(), 3);
+        assert_eq!(tree.descendants(locations[0].id).len(), 4);
+    }
+
+    #[test]
+    fn world_map_position_stays_on_the_image() {
+        let pin = WorldMapPosition::new(-5.0, 140.0);
+        assert_eq!(pin, WorldMapPosition { x: 0.0, y: 100.0 });
+    }
 }
@@ -14,7 +14,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::ids::{CharacterId, LoreChunkId, LoreId, WorldId};
+use crate::ids::{CharacterId, LoreChunkId, LoreId, PlayerCharacterId, WorldId};
 
 /// A piece of world knowledge that can be discovered
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -39,6 +39,10 @@ pub struct Lore {
     /// Tags for filtering/searching
     pub tags: Vec<String>,
 
+    /// Year on the game calendar when the lore's events took place
+    #[serde(default)]
+    pub event_year: Option<i32>,
+
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -119,6 +123,11 @@ pub enum LoreDiscoverySource {
     CommonKnowledge,
     /// LLM determined character should know this
     LlmDiscovered { context: String },
+    /// Passed on by another player character
+    SharedByPc {
+        pc_id: PlayerCharacterId,
+        pc_name: String,
+    },
 }
 
 impl Lore {
@@ -137,6 +146,7 @@ impl Lore {
             chunks: Vec::new(),
             is_common_knowledge: false,
             tags: Vec::new(),
+            event_year: None,
             created_at: now,
             updated_at: now,
         }
@@ -174,6 +184,11 @@ impl Lore {
         self
     }
 
+    pub fn with_event_year(mut self, year: i32) -> Self {
+        self.event_year = Some(year);
+        self
+    }
+
     /// Get the full lore text (all chunks combined)
     pub fn full_text(&self) -> String {
         self.chunks
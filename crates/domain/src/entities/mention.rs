@@ -0,0 +1,331 @@
+//! Entity mentions - references to existing entities found in free text
+//!
+//! Descriptions, lore and dialogue name people and places all the time.
+//! A mention is a link from the text that names an entity (the source) to
+//! the entity named (the target), found by matching the target's name or
+//! one of its DM-given aliases as whole words. Stored mentions answer
+//! "where is this NPC referenced?"; the same matching links names in text
+//! to hover-cards as it's shown.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_MENTION]->(EntityMention)`
+//! - `(World)-[:HAS_ENTITY_ALIASES]->(EntityAliases)`
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::DomainError;
+use crate::ids::WorldId;
+use crate::types::EntityType;
+
+/// Most aliases one entity can have
+pub const MAX_ALIASES_PER_ENTITY: usize = 10;
+
+/// Longest alias, in characters
+pub const MAX_ALIAS_LEN: usize = 100;
+
+/// Characters of context kept on each side of a mention in its excerpt
+const EXCERPT_CONTEXT_CHARS: usize = 60;
+
+/// Entity types that text can mention
+pub const MENTION_TARGET_TYPES: [EntityType; 4] = [
+    EntityType::Character,
+    EntityType::Location,
+    EntityType::Region,
+    EntityType::Item,
+];
+
+/// An entity that text can refer to, by its name or any alias
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionTarget {
+    pub entity_type: EntityType,
+    pub entity_id: Uuid,
+    pub name: String,
+    pub aliases: Vec<String>,
+}
+
+impl MentionTarget {
+    pub fn new(entity_type: EntityType, entity_id: Uuid, name: impl Into<String>) -> Self {
+        Self {
+            entity_type,
+            entity_id,
+            name: name.into(),
+            aliases: Vec::new(),
+        }
+    }
+
+    pub fn with_aliases(mut self, aliases: Vec<String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+}
+
+/// Where a target was found in a text, as byte offsets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MentionSpan {
+    /// Index of the matched target in the list searched
+    pub target: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A stored reference from one entity's text to another entity
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityMention {
+    pub world_id: WorldId,
+    pub source_type: EntityType,
+    pub source_id: Uuid,
+    /// The source's name when it was indexed ("The Fall of House Valeren")
+    pub source_name: String,
+    pub target_type: EntityType,
+    pub target_id: Uuid,
+    /// The text that matched, as written in the source
+    pub matched_text: String,
+    /// The first mention with some text around it
+    pub excerpt: String,
+    /// How many times the source mentions the target
+    pub count: u32,
+}
+
+/// Find every mention of `targets` in `text`, in order.
+///
+/// Names and aliases match case-insensitively as whole words. Where two
+/// terms start at the same place the longer one wins ("Mira Vance" over
+/// "Mira"), and mentions never overlap.
+pub fn find_mentions(text: &str, targets: &[MentionTarget]) -> Vec<MentionSpan> {
+    let mut terms: Vec<(usize, Vec<char>)> = targets
+        .iter()
+        .enumerate()
+        .flat_map(|(i, t)| {
+            std::iter::once(t.name.as_str())
+                .chain(t.aliases.iter().map(String::as_str))
+                .map(move |term| (i, term.trim()))
+        })
+        .filter(|(_, term)| term.chars().count() >= 2)
+        .map(|(i, term)| (i, term.chars().collect()))
+        .collect();
+    terms.sort_by_key(|(_, term)| std::cmp::Reverse(term.len()));
+
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    while pos < chars.len() {
+        let at_word_start = pos == 0 || !chars[pos - 1].1.is_alphanumeric();
+        let matched = at_word_start
+            .then(|| {
+                terms.iter().find(|(_, term)| {
+                    let end = pos + term.len();
+                    end <= chars.len()
+                        && (end == chars.len() || !chars[end].1.is_alphanumeric())
+                        && chars[pos..end]
+                            .iter()
+                            .zip(term)
+                            .all(|((_, a), b)| a.to_lowercase().eq(b.to_lowercase()))
+                })
+            })
+            .flatten();
+
+        match matched {
+            Some((target, term)) => {
+                let end = pos + term.len();
+                spans.push(MentionSpan {
+                    target: *target,
+                    start: chars[pos].0,
+                    end: chars.get(end).map_or(text.len(), |(i, _)| *i),
+                });
+                pos = end;
+            }
+            None => pos += 1,
+        }
+    }
+    spans
+}
+
+/// The text around a mention, trimmed to whole words with ellipses
+pub fn mention_excerpt(text: &str, span: MentionSpan) -> String {
+    let before: String = {
+        let mut chars: Vec<char> = text[..span.start]
+            .chars()
+            .rev()
+            .take(EXCERPT_CONTEXT_CHARS)
+            .collect();
+        chars.reverse();
+        chars.into_iter().collect()
+    };
+    let after: String = text[span.end..]
+        .chars()
+        .take(EXCERPT_CONTEXT_CHARS)
+        .collect();
+
+    // Drop the partial words at either end of the window
+    let cut_before = before.len() < text[..span.start].len();
+    let cut_after = after.len() < text[span.end..].len();
+    let before = match (cut_before, before.find(char::is_whitespace)) {
+        (true, Some(i)) => &before[i..],
+        _ => before.as_str(),
+    };
+    let after = match (cut_after, after.rfind(char::is_whitespace)) {
+        (true, Some(i)) => &after[..i],
+        _ => after.as_str(),
+    };
+
+    let excerpt = format!(
+        "{}{}{}",
+        before.trim_start(),
+        &text[span.start..span.end],
+        after.trim_end()
+    );
+    let excerpt = excerpt.split_whitespace().collect::<Vec<_>>().join(" ");
+    format!(
+        "{}{}{}",
+        if cut_before { "…" } else { "" },
+        excerpt,
+        if cut_after { "…" } else { "" }
+    )
+}
+
+/// Mentions of each target in a source's text, one per target, leaving out
+/// the source itself
+pub fn mentions_in(
+    world_id: WorldId,
+    source: (EntityType, Uuid),
+    source_name: &str,
+    text: &str,
+    targets: &[MentionTarget],
+) -> Vec<EntityMention> {
+    let mut mentions: Vec<EntityMention> = Vec::new();
+    for span in find_mentions(text, targets) {
+        let target = &targets[span.target];
+        if (target.entity_type, target.entity_id) == source {
+            continue;
+        }
+        match mentions
+            .iter_mut()
+            .find(|m| m.target_type == target.entity_type && m.target_id == target.entity_id)
+        {
+            Some(existing) => existing.count += 1,
+            None => mentions.push(EntityMention {
+                world_id,
+                source_type: source.0,
+                source_id: source.1,
+                source_name: source_name.to_string(),
+                target_type: target.entity_type,
+                target_id: target.entity_id,
+                matched_text: text[span.start..span.end].to_string(),
+                excerpt: mention_excerpt(text, span),
+                count: 1,
+            }),
+        }
+    }
+    mentions
+}
+
+/// Trim aliases and drop empty and repeated ones, checking the limits
+pub fn normalize_aliases(aliases: &[String]) -> Result<Vec<String>, DomainError> {
+    let mut normalized: Vec<String> = Vec::new();
+    for alias in aliases {
+        let alias = alias.split_whitespace().collect::<Vec<_>>().join(" ");
+        if alias.is_empty() {
+            continue;
+        }
+        if alias.chars().count() > MAX_ALIAS_LEN {
+            return Err(DomainError::validation(format!(
+                "Aliases can be at most {} characters",
+                MAX_ALIAS_LEN
+            )));
+        }
+        if !normalized.iter().any(|a| a.eq_ignore_ascii_case(&alias)) {
+            normalized.push(alias);
+        }
+    }
+    if normalized.len() > MAX_ALIASES_PER_ENTITY {
+        return Err(DomainError::validation(format!(
+            "An entity can have at most {} aliases",
+            MAX_ALIASES_PER_ENTITY
+        )));
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(name: &str, aliases: &[&str]) -> MentionTarget {
+        MentionTarget::new(EntityType::Character, Uuid::new_v4(), name)
+            .with_aliases(aliases.iter().map(|a| a.to_string()).collect())
+    }
+
+    #[test]
+    fn names_and_aliases_match_whole_words_ignoring_case() {
+        let targets = vec![target("Mira Vance", &["the Widow"]), target("Oss", &[])];
+        let text = "THE WIDOW met mira vance at Ossuary Gate, then Oss.";
+
+        let found: Vec<&str> = find_mentions(text, &targets)
+            .iter()
+            .map(|s| &text[s.start..s.end])
+            .collect();
+
+        assert_eq!(found, vec!["THE WIDOW", "mira vance", "Oss"]);
+    }
+
+    #[test]
+    fn the_longest_term_wins_and_mentions_do_not_overlap() {
+        let targets = vec![target("Mira", &[]), target("Mira Vance", &[])];
+        let spans = find_mentions("Ask Mira Vance, not Mira.", &targets);
+
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].target, 1);
+        assert_eq!(spans[1].target, 0);
+    }
+
+    #[test]
+    fn mentions_are_counted_per_target_without_the_source_itself() {
+        let world_id = WorldId::new();
+        let mira = target("Mira", &[]);
+        let oss = target("Oss", &[]);
+        let text = "Mira owes Oss. Oss knows it. Mira pays.";
+
+        let mentions = mentions_in(
+            world_id,
+            (mira.entity_type, mira.entity_id),
+            "Mira",
+            text,
+            &[mira.clone(), oss.clone()],
+        );
+
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].target_id, oss.entity_id);
+        assert_eq!(mentions[0].count, 2);
+        assert_eq!(mentions[0].excerpt, text);
+    }
+
+    #[test]
+    fn long_excerpts_are_cut_at_word_boundaries() {
+        let text = format!("{} Mira {}", "word ".repeat(30), "word ".repeat(30));
+        let spans = find_mentions(&text, &[target("Mira", &[])]);
+
+        let excerpt = mention_excerpt(&text, spans[0]);
+
+        assert!(excerpt.starts_with("…word"));
+        assert!(excerpt.ends_with("word…"));
+        assert!(excerpt.contains(" Mira "));
+    }
+
+    #[test]
+    fn aliases_are_trimmed_deduplicated_and_limited() {
+        let aliases = vec![
+            "  the  Widow ".to_string(),
+            "THE WIDOW".to_string(),
+            String::new(),
+        ];
+        assert_eq!(normalize_aliases(&aliases).unwrap(), vec!["the Widow"]);
+
+        let too_many: Vec<String> = (0..=MAX_ALIASES_PER_ENTITY)
+            .map(|i| format!("alias {}", i))
+            .collect();
+        assert!(normalize_aliases(&too_many).is_err());
+    }
+}
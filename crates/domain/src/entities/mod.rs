@@ -1,71 +1,178 @@
 //! Domain entities - Core business objects with identity
 
+mod broadsheet;
 mod challenge;
+mod challenge_feedback;
+mod challenge_resolution;
 mod character;
 mod character_content;
 mod class_feature;
+mod companion;
+mod content_draft;
+mod crowd;
+mod dialogue_tree;
+mod economy;
+mod entity_template;
 mod event_chain;
+mod faction;
 mod feat;
 mod gallery_asset;
 mod game_flag;
 mod generation_batch;
 mod goal;
 mod grid_map;
+mod injury;
 mod interaction;
 mod item;
+mod journey;
+mod letter;
+mod library_entry;
 mod location;
 mod location_state;
 mod lore;
+mod mention;
+mod name_generator;
 mod narrative_event;
+mod npc_draft;
 mod observation;
+mod pc_secret;
 mod player_character;
+mod progress_clock;
+mod property;
 mod region;
 mod region_state;
+mod saved_filter;
 mod scene;
 mod sheet_template;
 mod skill;
 mod spell;
 mod staging;
 mod story_event;
+mod trade;
 mod want;
 mod workflow_config;
 mod world;
+mod world_simulation;
 
 pub use challenge::{
     Challenge, ChallengeLocationAvailability, ChallengeOutcomes, ChallengePrerequisite,
     ChallengeRegionAvailability, ChallengeType, ChallengeUnlock, Difficulty, DifficultyDescriptor,
     Outcome, OutcomeTrigger, OutcomeType, TriggerCondition, TriggerType,
 };
+pub use challenge_feedback::{
+    SuggestionDecision, SuggestionPreferences, SuggestionVerdict,
+    MAX_SUGGESTION_DECISIONS_CONSIDERED,
+};
+pub use broadsheet::{
+    game_week_ended, game_week_number, game_week_start, Broadsheet, BroadsheetArticle,
+    GAME_WEEK_DAYS, MAX_BROADSHEET_ARTICLES, MAX_BROADSHEET_ARTICLE_LEN,
+    MAX_BROADSHEET_HEADLINE_LEN,
+};
+pub use challenge_resolution::{ChallengeHistoryStats, ChallengeResolution, MAX_CHALLENGE_HISTORY};
 pub use character::{Character, StatBlock, StatModifier, StatValue};
 pub use character_content::{
     AcquiredFeat, ActiveFeature, CharacterFeats, CharacterFeatures, CharacterIdentity,
     CharacterSpells, ClassLevel, KnownSpell, SpellSlotPool,
 };
 pub use class_feature::{BackgroundFeature, ClassFeature, FeatureUses, RacialTrait};
+pub use companion::{
+    Companion, DEFAULT_LOYALTY, MAX_COMPANIONS_PER_PC, MAX_DAILY_WAGE, MAX_LOYALTY,
+};
+pub use content_draft::{
+    diff_words, ContentDraft, DiffOp, DiffSegment, DraftField, DraftRevision, DraftStatus,
+    RevisionSource, MAX_DRAFT_REVISIONS, MAX_DRAFT_TEXT_LEN,
+};
+pub use crowd::{crowd_size_label, Crowd, MAX_CROWD_COUNT, MAX_CROWD_NAME_LEN};
+pub use economy::{
+    market_conditions, MarketConditions, MarketModifier, Scarcity, MAX_MARKET_TEXT_LEN,
+    MAX_PRICE_ADJUSTMENT_PERCENT,
+};
+pub use entity_template::{
+    EntityTemplate, ItemTemplate, NpcTemplate, RegionTemplate, TemplateBody, TemplateNpc,
+    MAX_TEMPLATES_PER_WORLD, MAX_TEMPLATE_ITEMS, MAX_TEMPLATE_NPCS, TEMPLATE_NAME_VARIABLE,
+};
+pub use dialogue_tree::{
+    DialogueBranch, DialogueNode, DialogueTree, MAX_DIALOGUE_CHOICES, MAX_DIALOGUE_CHOICE_LEN,
+    MAX_DIALOGUE_LINE_LEN, MAX_DIALOGUE_NODES, MAX_DIALOGUE_NODE_ID_LEN,
+    MAX_DIALOGUE_TREE_NAME_LEN,
+};
 pub use event_chain::{ChainStatus, EventChain};
+pub use faction::{
+    Faction, FactionMember, ReputationStanding, MAX_FACTION_GOALS, MAX_FACTION_GOAL_LEN,
+    MAX_FACTION_NAME_LEN, MAX_FACTION_REPUTATION, MAX_FACTION_ROLE_LEN, MIN_FACTION_REPUTATION,
+};
 pub use feat::{AbilityUses, Feat, FeatBenefit, Prerequisite, RechargeType, UsesFormula};
-pub use gallery_asset::{AssetType, EntityType, GalleryAsset, GenerationMetadata};
+pub use gallery_asset::{
+    AssetType, EntityType, GalleryAsset, GalleryFilter, GenerationMetadata, MAX_GALLERY_BULK,
+};
 pub use game_flag::{FlagScope, GameFlag};
 pub use generation_batch::{BatchStatus, GenerationBatch, GenerationRequest};
 pub use goal::Goal;
 pub use grid_map::GridMap;
+pub use injury::{
+    Injury, InjuryCapability, InjurySeverity, MAX_ACTIVE_INJURIES_PER_PC, MAX_INJURY_NAME_LEN,
+    MAX_INJURY_NOTES_LEN, MAX_RECOVERY_HOURS,
+};
 pub use interaction::{
-    InteractionCondition, InteractionRequirement, InteractionTarget, InteractionTargetType,
-    InteractionTemplate, InteractionType,
+    InteractionAvailability, InteractionCondition, InteractionRequirement, InteractionTarget,
+    InteractionTargetType, InteractionTemplate, InteractionType, InteractionUsage,
 };
 pub use item::{AcquisitionMethod, FrequencyLevel, InventoryItem, Item};
-pub use location::{Location, LocationConnection, LocationType};
+pub use journey::{
+    Encounter, EncounterEntry, EncounterTable, Journey, MAX_ENCOUNTER_DESCRIPTION_LEN,
+    MAX_ENCOUNTER_ENTRIES, MAX_ENCOUNTER_NAME_LEN, MIN_ENCOUNTER_INTERVAL_MINUTES,
+};
+pub use letter::{
+    delivery_minutes, Correspondent, Letter, MAX_LETTER_BODY_LEN, MAX_LETTER_SUBJECT_LEN,
+    MIN_DELIVERY_MINUTES, UNROUTED_DELIVERY_MINUTES,
+};
+pub use library_entry::{LibraryContent, LibraryEntry, MAX_LIBRARY_ENTRIES_PER_USER};
+pub use location::{
+    HierarchyTravel, Location, LocationConnection, LocationScale, LocationTree, LocationType,
+    WorldMapPosition, DEFAULT_CROSSING_MINUTES, MAX_LOCATION_DEPTH,
+};
 pub use location_state::{LocationState, LocationStateSummary};
 pub use lore::{Lore, LoreCategory, LoreChunk, LoreDiscoverySource, LoreKnowledge};
+pub use mention::{
+    find_mentions, mention_excerpt, mentions_in, normalize_aliases, EntityMention, MentionSpan,
+    MentionTarget, MAX_ALIASES_PER_ENTITY, MAX_ALIAS_LEN, MENTION_TARGET_TYPES,
+};
+pub use name_generator::{
+    name_generator_for, NameGenerator, NameStyle, MAX_GENERATED_NAMES, MAX_NAME_GENERATOR_NAME_LEN,
+    MAX_NAME_PARTS,
+};
 pub use narrative_event::{
     ChainedEvent, EventChainMembership, EventEffect, EventOutcome, FeaturedNpc, NarrativeEvent,
     NarrativeTrigger, NarrativeTriggerType, OutcomeCondition, TriggerContext, TriggerEvaluation,
     TriggerLogic,
 };
-pub use observation::{NpcObservation, ObservationSummary, ObservationType};
-pub use player_character::PlayerCharacter;
-pub use region::{MapBounds, Region, RegionConnection, RegionExit};
+pub use npc_draft::{
+    unknown_names_in, NpcDraft, NpcDraftDetails, NpcDraftSource, NpcDraftStatus,
+    MAX_NPC_DRAFT_CONCEPT_LEN, MAX_NPC_DRAFT_EXCERPT_LEN, MAX_NPC_DRAFT_NAME_LEN,
+};
+pub use observation::{
+    LocationObservation, NpcObservation, ObservationSummary, ObservationType,
+};
+pub use pc_secret::{
+    PcSecret, SecretReaction, MAX_SECRETS_PER_PC, MAX_SECRET_CONTENT_LEN, MAX_SECRET_REACTIONS,
+    MAX_SECRET_TITLE_LEN,
+};
+pub use player_character::{
+    PcDeath, PlayerCharacter, SheetVersion, MAX_DEATH_CAUSE_LEN, MAX_EPITAPH_LEN,
+};
+pub use progress_clock::{
+    ClockKind, ClockTick, ProgressClock, MAX_CLOCK_SEGMENTS, MIN_CLOCK_SEGMENTS,
+};
+pub use property::{
+    Property, PropertySite, PropertyStaff, StaffRole, MAX_DAILY_UPKEEP, MAX_PROPERTY_NOTES_LEN,
+    MAX_PROPERTY_STAFF, MAX_PROPERTY_TEXT_LEN,
+};
+pub use region::{
+    HotspotPoint, HotspotTarget, LightLevel, LockRequirements, Loudness, MapBounds, Region,
+    RegionConnection, RegionExit, RegionHotspot, SoundCarry, MAX_NOISE_DESCRIPTION_LEN,
+};
 pub use region_state::{RegionState, RegionStateSummary};
+pub use saved_filter::{SavedFilter, MAX_SAVED_FILTERS_PER_USER};
 pub use scene::{Scene, SceneCharacter, SceneCharacterRole, SceneCondition, TimeContext};
 pub use sheet_template::{
     CharacterSheetData, CharacterSheetTemplate, FieldType, FieldValue, ItemListType, SectionLayout,
@@ -82,11 +189,22 @@ pub use staging::{
 pub use story_event::InfoImportance as StoryEventInfoImportance;
 pub use story_event::{
     ChallengeEventOutcome, CombatEventType, CombatOutcome, DmMarkerType, InfoType,
-    InvolvedCharacter, ItemSource, MarkerImportance, StoryEvent, StoryEventType,
+    InvolvedCharacter, ItemSource, MarkerImportance, MarkerLink, MarkerPin, StoryEvent,
+    StoryEventType,
 };
+pub use trade::{TradeOffer, TradeSide, TRADE_OFFER_TTL_MINUTES};
 pub use want::{ActantialRole, ActantialView, CharacterWant, Want, WantTargetType, WantVisibility};
 pub use workflow_config::{
-    InputDefault, InputType, PromptMapping, PromptMappingType, WorkflowAnalysis,
-    WorkflowConfiguration, WorkflowInput, WorkflowSlot,
+    InputDefault, InputType, PromptMapping, PromptMappingType, ReferenceImageMapping,
+    WorkflowAnalysis, WorkflowConfiguration, WorkflowInput, WorkflowSlot,
 };
 pub use world::{Act, MonomythStage, TimeAdvanceResult, World};
+pub use world_simulation::{
+    simulated_days, SimulationChange, WorldSimulation, MAX_SIMULATED_DAYS, MAX_SIMULATION_CHANGES,
+    MIN_SIMULATED_MINUTES,
+};
+
+/// Name for a duplicated entity: "Tavern" becomes "Tavern (copy)"
+pub fn copy_name(name: &str) -> String {
+    format!("{} (copy)", name)
+}
@@ -0,0 +1,487 @@
+//! Name generator entity - how one culture names its people
+//!
+//! Left to itself, an LLM reaches for the same few fantasy names, and a
+//! world where every third NPC is "Elara" feels generated. A name generator
+//! describes one culture's names, either as lists to pick from or as a
+//! small grammar of syllables, and is assigned to the regions and factions
+//! where that culture lives. NPC creation and crowd promotion draw names
+//! from it.
+//!
+//! A generator assigned to no region or faction is the world's default.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_NAME_GENERATOR]->(NameGenerator)`
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{NameGeneratorId, RegionId, WorldId};
+
+/// Longest generator name, in characters
+pub const MAX_NAME_GENERATOR_NAME_LEN: usize = 100;
+
+/// Most entries in one list or grammar part
+pub const MAX_NAME_PARTS: usize = 500;
+
+/// Most names generated in one request
+pub const MAX_GENERATED_NAMES: usize = 50;
+
+/// Draws per requested name before giving up on finding an unused one
+const ATTEMPTS_PER_NAME: usize = 20;
+
+/// How a generator builds a name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NameStyle {
+    /// A given name, followed by a family name when there are any
+    List {
+        given_names: Vec<String>,
+        family_names: Vec<String>,
+    },
+    /// A random pattern with each `{part}` replaced by a random entry of
+    /// that part: `"{start}{end} of {clan}"`. A part that begins a word is
+    /// capitalized; the pattern's own text is kept as written.
+    Grammar {
+        patterns: Vec<String>,
+        parts: BTreeMap<String, Vec<String>>,
+    },
+}
+
+impl NameStyle {
+    /// Trimmed, with empty entries dropped, or why it can't make names
+    fn validated(self) -> Result<Self, DomainError> {
+        match self {
+            NameStyle::List {
+                given_names,
+                family_names,
+            } => {
+                let given_names = clean_entries("Given names", given_names)?;
+                let family_names = clean_entries("Family names", family_names)?;
+                if given_names.is_empty() {
+                    return Err(DomainError::validation(
+                        "A name list needs at least one given name",
+                    ));
+                }
+                Ok(NameStyle::List {
+                    given_names,
+                    family_names,
+                })
+            }
+            NameStyle::Grammar { patterns, parts } => {
+                let patterns = clean_entries("Patterns", patterns)?;
+                if patterns.is_empty() {
+                    return Err(DomainError::validation(
+                        "A name grammar needs at least one pattern",
+                    ));
+                }
+                let mut cleaned = BTreeMap::new();
+                for (key, entries) in parts {
+                    let key = key.trim().to_string();
+                    if key.is_empty() {
+                        return Err(DomainError::validation("Grammar parts need a name"));
+                    }
+                    let entries = clean_entries(&format!("Part '{}'", key), entries)?;
+                    cleaned.insert(key, entries);
+                }
+                for pattern in &patterns {
+                    for key in pattern_parts(pattern)? {
+                        if cleaned.get(key).is_none_or(|entries| entries.is_empty()) {
+                            return Err(DomainError::validation(format!(
+                                "Pattern '{}' uses part '{}', which has no entries",
+                                pattern, key
+                            )));
+                        }
+                    }
+                }
+                Ok(NameStyle::Grammar {
+                    patterns,
+                    parts: cleaned,
+                })
+            }
+        }
+    }
+
+    /// One name, which may repeat earlier ones
+    fn draw(&self, rng: &mut impl FnMut(i32, i32) -> i32) -> String {
+        match self {
+            NameStyle::List {
+                given_names,
+                family_names,
+            } => {
+                let given = pick(given_names, rng);
+                if family_names.is_empty() {
+                    given.to_string()
+                } else {
+                    format!("{} {}", given, pick(family_names, rng))
+                }
+            }
+            NameStyle::Grammar { patterns, parts } => {
+                let pattern = pick(patterns, rng);
+                let mut name = String::new();
+                let mut rest = pattern;
+                while let Some(open) = rest.find('{') {
+                    name.push_str(&rest[..open]);
+                    let Some(close) = rest[open..].find('}') else {
+                        break;
+                    };
+                    let key = &rest[open + 1..open + close];
+                    if let Some(entries) = parts.get(key) {
+                        let entry = pick(entries, rng);
+                        if name.is_empty() || name.ends_with(char::is_whitespace) {
+                            name.push_str(&capitalize(entry));
+                        } else {
+                            name.push_str(entry);
+                        }
+                    }
+                    rest = &rest[open + close + 1..];
+                }
+                name.push_str(rest);
+                name.split_whitespace().collect::<Vec<_>>().join(" ")
+            }
+        }
+    }
+}
+
+/// A culture's way of naming people, and where it's used
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NameGenerator {
+    pub id: NameGeneratorId,
+    pub world_id: WorldId,
+    /// The culture or people: "Coastal Varnish", "Hill dwarves"
+    pub name: String,
+    pub description: String,
+    pub style: NameStyle,
+    /// Regions whose NPCs are named this way
+    pub region_ids: Vec<RegionId>,
+    /// Factions whose members are named this way
+    pub factions: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl NameGenerator {
+    pub fn new(
+        world_id: WorldId,
+        name: impl Into<String>,
+        style: NameStyle,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        Ok(Self {
+            id: NameGeneratorId::new(),
+            world_id,
+            name: validate_generator_name(&name.into())?,
+            description: String::new(),
+            style: style.validated()?,
+            region_ids: Vec::new(),
+            factions: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into().trim().to_string();
+        self
+    }
+
+    /// Assign the generator to regions and factions, replacing any earlier
+    /// assignment
+    pub fn with_assignments(mut self, region_ids: Vec<RegionId>, factions: Vec<String>) -> Self {
+        self.assign(region_ids, factions);
+        self
+    }
+
+    /// Change everything the DM can set
+    pub fn update(
+        &mut self,
+        name: &str,
+        description: impl Into<String>,
+        style: NameStyle,
+        region_ids: Vec<RegionId>,
+        factions: Vec<String>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let name = validate_generator_name(name)?;
+        let style = style.validated()?;
+        self.name = name;
+        self.description = description.into().trim().to_string();
+        self.style = style;
+        self.assign(region_ids, factions);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Whether this is the world's fallback, assigned nowhere
+    pub fn is_default(&self) -> bool {
+        self.region_ids.is_empty() && self.factions.is_empty()
+    }
+
+    pub fn covers_region(&self, region_id: RegionId) -> bool {
+        self.region_ids.contains(&region_id)
+    }
+
+    /// Factions match ignoring case
+    pub fn covers_faction(&self, faction: &str) -> bool {
+        let faction = faction.trim();
+        self.factions
+            .iter()
+            .any(|f| f.eq_ignore_ascii_case(faction))
+    }
+
+    /// Up to `count` different names, none of them in `taken` (ignoring
+    /// case). Fewer come back when the style can't make enough new ones.
+    ///
+    /// `rng` returns a number in `[min, max]`, inclusive.
+    pub fn generate(
+        &self,
+        count: usize,
+        taken: &[String],
+        mut rng: impl FnMut(i32, i32) -> i32,
+    ) -> Vec<String> {
+        let count = count.min(MAX_GENERATED_NAMES);
+        let mut names: Vec<String> = Vec::with_capacity(count);
+        for _ in 0..count * ATTEMPTS_PER_NAME {
+            if names.len() == count {
+                break;
+            }
+            let name = self.style.draw(&mut rng);
+            let is_used = |n: &String| n.eq_ignore_ascii_case(&name);
+            if name.is_empty() || taken.iter().any(is_used) || names.iter().any(is_used) {
+                continue;
+            }
+            names.push(name);
+        }
+        names
+    }
+
+    fn assign(&mut self, region_ids: Vec<RegionId>, factions: Vec<String>) {
+        let mut regions: Vec<RegionId> = Vec::new();
+        for region_id in region_ids {
+            if !regions.contains(&region_id) {
+                regions.push(region_id);
+            }
+        }
+        let mut kept: Vec<String> = Vec::new();
+        for faction in factions {
+            let faction = faction.trim();
+            if !faction.is_empty() && !kept.iter().any(|f| f.eq_ignore_ascii_case(faction)) {
+                kept.push(faction.to_string());
+            }
+        }
+        self.region_ids = regions;
+        self.factions = kept;
+    }
+}
+
+/// The generator for an NPC in `region_id` or `faction`: a faction's
+/// generator first, then the region's, then the world default
+pub fn name_generator_for<'a>(
+    generators: &'a [NameGenerator],
+    region_id: Option<RegionId>,
+    faction: Option<&str>,
+) -> Option<&'a NameGenerator> {
+    let by_faction = faction
+        .filter(|f| !f.trim().is_empty())
+        .and_then(|f| generators.iter().find(|g| g.covers_faction(f)));
+    let by_region = || region_id.and_then(|r| generators.iter().find(|g| g.covers_region(r)));
+    by_faction
+        .or_else(by_region)
+        .or_else(|| generators.iter().find(|g| g.is_default()))
+}
+
+fn pick<'a>(entries: &'a [String], rng: &mut impl FnMut(i32, i32) -> i32) -> &'a str {
+    let last = entries.len().saturating_sub(1) as i32;
+    let index = rng(0, last).clamp(0, last) as usize;
+    entries.get(index).map(String::as_str).unwrap_or_default()
+}
+
+/// The part names a pattern uses, or why it can't be read
+fn pattern_parts(pattern: &str) -> Result<Vec<&str>, DomainError> {
+    let mut keys = Vec::new();
+    let mut rest = pattern;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}') else {
+            return Err(DomainError::validation(format!(
+                "Pattern '{}' has a '{{' without a '}}'",
+                pattern
+            )));
+        };
+        keys.push(&rest[open + 1..open + close]);
+        rest = &rest[open + close + 1..];
+    }
+    Ok(keys)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn clean_entries(what: &str, entries: Vec<String>) -> Result<Vec<String>, DomainError> {
+    let entries: Vec<String> = entries
+        .into_iter()
+        .map(|e| e.trim().to_string())
+        .filter(|e| !e.is_empty())
+        .collect();
+    if entries.len() > MAX_NAME_PARTS {
+        return Err(DomainError::validation(format!(
+            "{} cannot have more than {} entries",
+            what, MAX_NAME_PARTS
+        )));
+    }
+    Ok(entries)
+}
+
+fn validate_generator_name(name: &str) -> Result<String, DomainError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(DomainError::validation(
+            "Name generator name cannot be empty",
+        ));
+    }
+    if name.chars().count() > MAX_NAME_GENERATOR_NAME_LEN {
+        return Err(DomainError::validation(format!(
+            "Name generator name cannot exceed {} characters",
+            MAX_NAME_GENERATOR_NAME_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(given: &[&str], family: &[&str]) -> NameStyle {
+        NameStyle::List {
+            given_names: given.iter().map(|s| s.to_string()).collect(),
+            family_names: family.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn grammar(patterns: &[&str], parts: &[(&str, &[&str])]) -> NameStyle {
+        NameStyle::Grammar {
+            patterns: patterns.iter().map(|s| s.to_string()).collect(),
+            parts: parts
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.iter().map(|s| s.to_string()).collect()))
+                .collect(),
+        }
+    }
+
+    /// Returns `values` in order, over and over
+    fn sequence_rng(values: &'static [i32]) -> impl FnMut(i32, i32) -> i32 {
+        let mut n = 0;
+        move |_, _| {
+            let value = values[n % values.len()];
+            n += 1;
+            value
+        }
+    }
+
+    /// Cycles through 0, 1, 2... within each requested range
+    fn counting_rng() -> impl FnMut(i32, i32) -> i32 {
+        let mut n = 0;
+        move |min, max| {
+            let value = min + n % (max - min + 1);
+            n += 1;
+            value
+        }
+    }
+
+    #[test]
+    fn grammars_fill_patterns_and_capitalize_parts() {
+        let generator = NameGenerator::new(
+            WorldId::new(),
+            "Coastal",
+            grammar(
+                &["{start}{end} of {clan}"],
+                &[
+                    ("start", &["ka", "ve"]),
+                    ("end", &["rin"]),
+                    ("clan", &["reef"]),
+                ],
+            ),
+            Utc::now(),
+        )
+        .expect("valid grammar");
+
+        // Pattern, start, end, clan for each name
+        let names = generator.generate(2, &[], sequence_rng(&[0, 0, 0, 0, 0, 1, 0, 0]));
+
+        assert_eq!(names, vec!["Karin of Reef", "Verin of Reef"]);
+    }
+
+    #[test]
+    fn generated_names_skip_taken_ones_and_stop_when_exhausted() {
+        let generator = NameGenerator::new(
+            WorldId::new(),
+            "Villagers",
+            list(&["Elara", "Tomas", "Wren"], &[]),
+            Utc::now(),
+        )
+        .expect("valid list");
+
+        let names = generator.generate(5, &["elara".to_string()], counting_rng());
+
+        assert_eq!(names, vec!["Tomas", "Wren"]);
+    }
+
+    #[test]
+    fn styles_that_cannot_make_names_are_rejected() {
+        let now = Utc::now();
+        let world_id = WorldId::new();
+
+        assert!(NameGenerator::new(world_id, "Empty", list(&[" "], &["Vance"]), now).is_err());
+        assert!(NameGenerator::new(
+            world_id,
+            "Unknown part",
+            grammar(&["{start}{end}"], &[("start", &["ka"])]),
+            now
+        )
+        .is_err());
+        assert!(NameGenerator::new(
+            world_id,
+            "Unclosed",
+            grammar(&["{start"], &[("start", &["ka"])]),
+            now
+        )
+        .is_err());
+        assert!(NameGenerator::new(world_id, " ", list(&["Wren"], &[]), now).is_err());
+    }
+
+    #[test]
+    fn factions_win_over_regions_and_the_default_catches_the_rest() {
+        let now = Utc::now();
+        let world_id = WorldId::new();
+        let harbor = RegionId::new();
+        let default =
+            NameGenerator::new(world_id, "Common", list(&["Wren"], &[]), now).expect("valid");
+        let coastal = NameGenerator::new(world_id, "Coastal", list(&["Kai"], &[]), now)
+            .expect("valid")
+            .with_assignments(vec![harbor], vec![]);
+        let guild = NameGenerator::new(world_id, "Guild", list(&["Ledger"], &[]), now)
+            .expect("valid")
+            .with_assignments(
+                vec![],
+                vec![" Salt Guild ".to_string(), "salt guild".to_string()],
+            );
+        let generators = vec![default, coastal, guild];
+
+        let pick = |region, faction| {
+            name_generator_for(&generators, region, faction).map(|g| g.name.as_str())
+        };
+
+        assert_eq!(generators[2].factions, vec!["Salt Guild"]);
+        assert_eq!(pick(Some(harbor), Some("SALT GUILD")), Some("Guild"));
+        assert_eq!(pick(Some(harbor), None), Some("Coastal"));
+        assert_eq!(pick(Some(RegionId::new()), Some("Thieves")), Some("Common"));
+    }
+}
@@ -383,6 +383,15 @@ pub enum EventEffect {
         description: String,
         requires_dm_action: bool,
     },
+
+    /// Run an effect provided by a plugin
+    Plugin {
+        plugin: String,
+        effect: String,
+        /// Parameters passed to the plugin as-is
+        #[serde(default)]
+        params: serde_json::Value,
+    },
 }
 
 /// Reference to a chained event
@@ -433,6 +442,22 @@ impl NarrativeEvent {
         }
     }
 
+    /// A copy of this event, triggers and outcomes included, with a new ID
+    ///
+    /// The copy starts untriggered, as if freshly created at `now`.
+    pub fn duplicate(&self, now: DateTime<Utc>) -> Self {
+        Self {
+            id: NarrativeEventId::new(),
+            is_triggered: false,
+            triggered_at: None,
+            selected_outcome: None,
+            trigger_count: 0,
+            created_at: now,
+            updated_at: now,
+            ..self.clone()
+        }
+    }
+
     /// Check if this event's triggers match the current game context
     pub fn evaluate_triggers(&self, context: &TriggerContext) -> TriggerEvaluation {
         let mut matched = Vec::new();
@@ -479,6 +504,34 @@ impl NarrativeEvent {
         }
     }
 
+    /// Whether this event's triggers could still be met, given a check for
+    /// triggers that never can be (e.g. ones naming a deleted location).
+    ///
+    /// Events with no triggers are fired by the DM and always can be.
+    pub fn can_still_trigger(&self, is_dead: impl Fn(&NarrativeTriggerType) -> bool) -> bool {
+        let total = self.trigger_conditions.len();
+        if total == 0 {
+            return true;
+        }
+
+        let mut dead = 0;
+        for trigger in &self.trigger_conditions {
+            if is_dead(&trigger.trigger_type) {
+                if trigger.is_required {
+                    return false;
+                }
+                dead += 1;
+            }
+        }
+
+        let live = total - dead;
+        match self.trigger_logic {
+            TriggerLogic::All => dead == 0,
+            TriggerLogic::Any => live > 0,
+            TriggerLogic::AtLeast(n) => live >= n as usize,
+        }
+    }
+
     fn trigger_matches(&self, trigger: &NarrativeTriggerType, context: &TriggerContext) -> bool {
         match trigger {
             NarrativeTriggerType::FlagSet { flag_name } => {
@@ -1367,4 +1420,66 @@ mod tests {
         assert_eq!(context.get_character_stat(char_id, "DEX"), Some(14));
         assert_eq!(context.get_character_stat(char_id, "CON"), Some(16));
     }
+
+    #[test]
+    fn duplicate_starts_untriggered_with_a_new_id() {
+        let created = Utc::now() - chrono::Duration::days(3);
+        let mut event = create_test_event_with_relationship_trigger(
+            CharacterId::new(),
+            CharacterId::new(),
+            Some(0.5),
+            None,
+        );
+        event.created_at = created;
+        event.is_repeatable = true;
+        event.trigger(Some("Success".to_string()), created);
+
+        let now = Utc::now();
+        let copy = event.duplicate(now);
+
+        assert_ne!(copy.id, event.id);
+        assert_eq!(copy.name, event.name);
+        assert_eq!(copy.trigger_conditions.len(), 1);
+        assert!(!copy.is_triggered);
+        assert_eq!(copy.triggered_at, None);
+        assert_eq!(copy.selected_outcome, None);
+        assert_eq!(copy.trigger_count, 0);
+        assert_eq!(copy.created_at, now);
+    }
+
+    #[test]
+    fn can_still_trigger_follows_the_trigger_logic() {
+        let mut event = create_test_event_with_relationship_trigger(
+            CharacterId::new(),
+            CharacterId::new(),
+            Some(0.5),
+            None,
+        );
+        event.trigger_conditions[0].is_required = false;
+        event.trigger_conditions.push(NarrativeTrigger {
+            trigger_type: NarrativeTriggerType::FlagSet {
+                flag_name: "harbor_burned".to_string(),
+            },
+            description: "The harbor burned".to_string(),
+            is_required: false,
+            trigger_id: "trigger-2".to_string(),
+        });
+        let flag_is_dead =
+            |t: &NarrativeTriggerType| matches!(t, NarrativeTriggerType::FlagSet { .. });
+
+        event.trigger_logic = TriggerLogic::All;
+        assert!(!event.can_still_trigger(flag_is_dead));
+        event.trigger_logic = TriggerLogic::Any;
+        assert!(event.can_still_trigger(flag_is_dead));
+        event.trigger_logic = TriggerLogic::AtLeast(2);
+        assert!(!event.can_still_trigger(flag_is_dead));
+        assert!(event.can_still_trigger(|_| false));
+
+        event.trigger_logic = TriggerLogic::Any;
+        event.trigger_conditions[1].is_required = true;
+        assert!(!event.can_still_trigger(flag_is_dead));
+
+        event.trigger_conditions.clear();
+        assert!(event.can_still_trigger(|_| true));
+    }
 }
@@ -0,0 +1,432 @@
+//! NPC draft entity - a character proposed for promotion, awaiting the DM
+//!
+//! Named NPCs often start in the background: one of the dock workers, or a
+//! name dropped in dialogue or a lore entry. Session zero seeds a few more
+//! from a one-line concept each. Promoting one creates a draft whose details
+//! the LLM fills in from where they came from. The DM edits
+//! the draft and approves it into a full `Character`, or rejects it.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_NPC_DRAFT]->(NpcDraft)`
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::Character;
+use crate::error::DomainError;
+use crate::ids::{CrowdId, LoreId, NpcDraftId, RegionId, WorldId};
+use crate::types::{CampbellArchetype, DispositionLevel};
+
+/// Longest name a drafted NPC can have, in characters
+pub const MAX_NPC_DRAFT_NAME_LEN: usize = 100;
+
+/// Longest dialogue excerpt kept as a draft's source, in characters
+pub const MAX_NPC_DRAFT_EXCERPT_LEN: usize = 2_000;
+
+/// Longest session zero concept for a seeded NPC, in characters
+pub const MAX_NPC_DRAFT_CONCEPT_LEN: usize = 500;
+
+/// Where a drafted NPC came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NpcDraftSource {
+    /// One member of a crowd steps forward
+    Crowd { crowd_id: CrowdId },
+    /// A name mentioned in dialogue; `excerpt` is the line it appeared in
+    Dialogue { excerpt: String },
+    /// A name mentioned in a lore entry
+    Lore { lore_id: LoreId },
+    /// Seeded at session zero from a concept ("a smuggler who owes the
+    /// harbormaster"), found in the world's starting region
+    SessionZero { region_id: RegionId, concept: String },
+}
+
+/// Whether the LLM is still filling the draft in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NpcDraftStatus {
+    Generating,
+    /// Ready for the DM to edit, approve or reject
+    Pending,
+}
+
+/// The details generated (or typed) for a drafted NPC
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NpcDraftDetails {
+    pub name: String,
+    pub description: String,
+    pub archetype: CampbellArchetype,
+    pub disposition: DispositionLevel,
+}
+
+/// A proposed NPC, waiting for DM approval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NpcDraft {
+    pub id: NpcDraftId,
+    pub world_id: WorldId,
+    pub source: NpcDraftSource,
+    /// Empty until generated when a crowd member is promoted without a name
+    pub name: String,
+    pub description: String,
+    pub archetype: CampbellArchetype,
+    pub disposition: DispositionLevel,
+    pub status: NpcDraftStatus,
+    /// Why the last generation failed; the draft can still be filled by hand
+    pub generation_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl NpcDraft {
+    /// A new draft, waiting to be generated.
+    ///
+    /// A mentioned name is the whole point of promoting from dialogue or
+    /// lore, so those sources need one; a crowd member or a session zero
+    /// seed can be named by the LLM.
+    pub fn new(
+        world_id: WorldId,
+        source: NpcDraftSource,
+        name: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let name = match name.map(str::trim).filter(|n| !n.is_empty()) {
+            Some(name) => validate_npc_name(name)?,
+            None if matches!(
+                source,
+                NpcDraftSource::Crowd { .. } | NpcDraftSource::SessionZero { .. }
+            ) =>
+            {
+                String::new()
+            }
+            None => {
+                return Err(DomainError::validation(
+                    "A mentioned NPC needs the name they were mentioned by",
+                ))
+            }
+        };
+        let source = match source {
+            NpcDraftSource::Dialogue { excerpt } => NpcDraftSource::Dialogue {
+                excerpt: validate_excerpt(&excerpt)?,
+            },
+            NpcDraftSource::SessionZero { region_id, concept } => NpcDraftSource::SessionZero {
+                region_id,
+                concept: validate_concept(&concept)?,
+            },
+            other => other,
+        };
+
+        Ok(Self {
+            id: NpcDraftId::new(),
+            world_id,
+            source,
+            name,
+            description: String::new(),
+            archetype: CampbellArchetype::default(),
+            disposition: DispositionLevel::default(),
+            status: NpcDraftStatus::Generating,
+            generation_error: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn is_generating(&self) -> bool {
+        self.status == NpcDraftStatus::Generating
+    }
+
+    /// Take the generated details; a name the draft already has is kept
+    pub fn fill(
+        &mut self,
+        details: NpcDraftDetails,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if self.name.is_empty() {
+            self.name = validate_npc_name(&details.name)?;
+        }
+        self.description = details.description.trim().to_string();
+        self.archetype = details.archetype;
+        self.disposition = details.disposition;
+        self.status = NpcDraftStatus::Pending;
+        self.generation_error = None;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Record a failed generation, leaving the draft for the DM to fill in
+    pub fn generation_failed(&mut self, reason: impl Into<String>, now: DateTime<Utc>) {
+        self.status = NpcDraftStatus::Pending;
+        self.generation_error = Some(reason.into());
+        self.updated_at = now;
+    }
+
+    /// Go back to generating, e.g. to try the LLM again
+    pub fn regenerate(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.is_generating() {
+            return Err(DomainError::validation(
+                "This NPC is already being generated",
+            ));
+        }
+        self.status = NpcDraftStatus::Generating;
+        self.generation_error = None;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Replace the details with the DM's edits
+    pub fn edit(
+        &mut self,
+        details: NpcDraftDetails,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if self.is_generating() {
+            return Err(DomainError::validation(
+                "Wait for the NPC to finish generating before editing it",
+            ));
+        }
+        self.name = validate_npc_name(&details.name)?;
+        self.description = details.description.trim().to_string();
+        self.archetype = details.archetype;
+        self.disposition = details.disposition;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// The character this draft becomes when approved
+    pub fn to_character(&self) -> Result<Character, DomainError> {
+        if self.is_generating() {
+            return Err(DomainError::validation(
+                "Wait for the NPC to finish generating before approving it",
+            ));
+        }
+        let name = validate_npc_name(&self.name)?;
+        let mut character = Character::new(self.world_id, name, self.archetype)
+            .with_default_disposition(self.disposition);
+        if !self.description.is_empty() {
+            character = character.with_description(self.description.clone());
+        }
+        Ok(character)
+    }
+}
+
+/// Capitalized names in `text` that aren't one of `known_names`, in order.
+///
+/// A heuristic for spotting people worth promoting: runs of capitalized
+/// words mid-sentence. The first word of a sentence is skipped, since
+/// capitalization says nothing there. Known names match case-insensitively,
+/// whole or by any of their words ("Mira" is known if "Mira Vance" is).
+pub fn unknown_names_in(text: &str, known_names: &[&str]) -> Vec<String> {
+    let known: Vec<String> = known_names
+        .iter()
+        .flat_map(|n| std::iter::once(n.to_string()).chain(n.split_whitespace().map(String::from)))
+        .map(|n| n.trim().to_lowercase())
+        .filter(|n| !n.is_empty())
+        .collect();
+
+    let mut names: Vec<String> = Vec::new();
+    let mut run: Vec<&str> = Vec::new();
+    let mut sentence_start = true;
+    for token in text.split_whitespace() {
+        let word = token
+            .trim_matches(|c: char| !c.is_alphanumeric() && c != '\'' && c != '-')
+            .trim_end_matches("'s")
+            .trim_matches('\'');
+        let capitalized = word.chars().next().is_some_and(char::is_uppercase)
+            && word != "I"
+            && !word.starts_with("I'");
+        // Quoted speech starts a sentence of its own
+        let opens_quote = token.starts_with(['"', '\u{201C}']);
+        let ends_sentence = token
+            .trim_end_matches(['"', '\u{201D}', ')'])
+            .ends_with(['.', '!', '?']);
+        let ends_phrase = ends_sentence || token.ends_with([',', ';', ':', '"', '\u{201D}', ')']);
+
+        if opens_quote {
+            push_name(&mut run, &mut names, &known);
+        }
+        if capitalized && !sentence_start && !opens_quote {
+            run.push(word);
+        } else {
+            push_name(&mut run, &mut names, &known);
+        }
+        if ends_phrase {
+            push_name(&mut run, &mut names, &known);
+        }
+        sentence_start = ends_sentence;
+    }
+    push_name(&mut run, &mut names, &known);
+    names
+}
+
+/// Move the run of words into `names` unless it's known or already there
+fn push_name(run: &mut Vec<&str>, names: &mut Vec<String>, known: &[String]) {
+    if run.is_empty() {
+        return;
+    }
+    let name = run.join(" ");
+    run.clear();
+    let lowered = name.to_lowercase();
+    if !known.contains(&lowered) && !names.iter().any(|n| n.to_lowercase() == lowered) {
+        names.push(name);
+    }
+}
+
+fn validate_npc_name(name: &str) -> Result<String, DomainError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(DomainError::validation("NPC name cannot be empty"));
+    }
+    if name.chars().count() > MAX_NPC_DRAFT_NAME_LEN {
+        return Err(DomainError::validation(format!(
+            "NPC name cannot exceed {} characters",
+            MAX_NPC_DRAFT_NAME_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+fn validate_excerpt(excerpt: &str) -> Result<String, DomainError> {
+    let excerpt = excerpt.trim();
+    if excerpt.is_empty() {
+        return Err(DomainError::validation(
+            "Include the dialogue the name was mentioned in",
+        ));
+    }
+    if excerpt.chars().count() > MAX_NPC_DRAFT_EXCERPT_LEN {
+        return Err(DomainError::validation(format!(
+            "Dialogue excerpt cannot exceed {} characters",
+            MAX_NPC_DRAFT_EXCERPT_LEN
+        )));
+    }
+    Ok(excerpt.to_string())
+}
+
+fn validate_concept(concept: &str) -> Result<String, DomainError> {
+    let concept = concept.trim();
+    if concept.is_empty() {
+        return Err(DomainError::validation("Describe who the NPC should be"));
+    }
+    if concept.chars().count() > MAX_NPC_DRAFT_CONCEPT_LEN {
+        return Err(DomainError::validation(format!(
+            "NPC concept cannot exceed {} characters",
+            MAX_NPC_DRAFT_CONCEPT_LEN
+        )));
+    }
+    Ok(concept.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn details(name: &str) -> NpcDraftDetails {
+        NpcDraftDetails {
+            name: name.to_string(),
+            description: "A stevedore with a bad knee.".to_string(),
+            archetype: CampbellArchetype::Herald,
+            disposition: DispositionLevel::Suspicious,
+        }
+    }
+
+    #[test]
+    fn mentioned_npcs_need_a_name_but_crowd_members_do_not() {
+        let now = Utc::now();
+        let world_id = WorldId::new();
+        let dialogue = NpcDraftSource::Dialogue {
+            excerpt: "Ask old Tobin at the mill.".to_string(),
+        };
+
+        assert!(NpcDraft::new(world_id, dialogue.clone(), None, now).is_err());
+        assert!(NpcDraft::new(world_id, dialogue, Some(" Tobin "), now).is_ok());
+        let member = NpcDraft::new(
+            world_id,
+            NpcDraftSource::Crowd {
+                crowd_id: CrowdId::new(),
+            },
+            None,
+            now,
+        )
+        .expect("crowd member");
+        assert!(member.name.is_empty());
+        assert!(member.is_generating());
+    }
+
+    #[test]
+    fn session_zero_seeds_need_a_concept_but_not_a_name() {
+        let now = Utc::now();
+        let seed = |concept: &str| NpcDraftSource::SessionZero {
+            region_id: RegionId::new(),
+            concept: concept.to_string(),
+        };
+
+        assert!(NpcDraft::new(WorldId::new(), seed("  "), None, now).is_err());
+        let draft = NpcDraft::new(
+            WorldId::new(),
+            seed(" A smuggler who owes the harbormaster "),
+            None,
+            now,
+        )
+        .expect("seed");
+        assert!(draft.name.is_empty());
+        assert!(matches!(
+            draft.source,
+            NpcDraftSource::SessionZero { ref concept, .. }
+                if concept == "A smuggler who owes the harbormaster"
+        ));
+    }
+
+    #[test]
+    fn filling_keeps_a_given_name_and_approval_waits_for_generation() {
+        let now = Utc::now();
+        let mut draft = NpcDraft::new(
+            WorldId::new(),
+            NpcDraftSource::Lore {
+                lore_id: LoreId::new(),
+            },
+            Some("Tobin"),
+            now,
+        )
+        .expect("draft");
+        assert!(draft.to_character().is_err());
+
+        draft.fill(details("Somebody Else"), now).expect("fill");
+
+        assert_eq!(draft.name, "Tobin");
+        assert_eq!(draft.status, NpcDraftStatus::Pending);
+        let character = draft.to_character().expect("character");
+        assert_eq!(character.name, "Tobin");
+        assert_eq!(character.base_archetype, CampbellArchetype::Herald);
+        assert_eq!(character.default_disposition, DispositionLevel::Suspicious);
+    }
+
+    #[test]
+    fn a_failed_generation_can_be_filled_by_hand() {
+        let now = Utc::now();
+        let mut draft = NpcDraft::new(
+            WorldId::new(),
+            NpcDraftSource::Crowd {
+                crowd_id: CrowdId::new(),
+            },
+            None,
+            now,
+        )
+        .expect("draft");
+
+        draft.generation_failed("LLM unavailable", now);
+        assert!(draft.to_character().is_err());
+        draft.edit(details("Hesk"), now).expect("edit");
+
+        assert_eq!(draft.to_character().expect("character").name, "Hesk");
+    }
+
+    #[test]
+    fn unknown_names_skip_sentence_starts_and_known_characters() {
+        let text = "Talk to Captain Ilsa Marr before dawn. She trusts Mira Vance, \
+            not Tobin's brother. \"Never Tobin,\" she said. Ask for Tobin again.";
+
+        let names = unknown_names_in(text, &["Mira Vance"]);
+
+        assert_eq!(names, vec!["Captain Ilsa Marr", "Tobin"]);
+        assert!(unknown_names_in("I saw Mira.", &["Mira Vance"]).is_empty());
+    }
+}
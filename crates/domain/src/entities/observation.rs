@@ -40,6 +40,15 @@ impl ObservationType {
             ObservationType::Deduced => "brain",
         }
     }
+
+    /// Stable key used when storing and sending the type
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            ObservationType::Direct => "direct",
+            ObservationType::HeardAbout => "heard_about",
+            ObservationType::Deduced => "deduced",
+        }
+    }
 }
 
 impl std::fmt::Display for ObservationType {
@@ -190,6 +199,74 @@ impl NpcObservation {
     }
 }
 
+/// A PC's knowledge of a place: somewhere they have been or heard about
+///
+/// Stored as Neo4j edge: `(PlayerCharacter)-[:KNOWS_LOCATION {...}]->(Location)`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationObservation {
+    /// The PC who knows the location
+    pub pc_id: PlayerCharacterId,
+    /// The location they know
+    pub location_id: LocationId,
+    /// When they learned of it (in game time)
+    pub game_time: DateTime<Utc>,
+    /// Direct when they went there, otherwise how they learned of it
+    pub observation_type: ObservationType,
+    /// Optional notes (e.g., "A sailor spoke of a lighthouse to the north")
+    pub notes: Option<String>,
+    /// When this knowledge was recorded (real time)
+    pub created_at: DateTime<Utc>,
+    /// Times the PC has arrived there. Saving an observation adds its visits
+    /// to the ones already stored.
+    #[serde(default)]
+    pub visits: u32,
+}
+
+impl LocationObservation {
+    /// The PC went there
+    pub fn visited(
+        pc_id: PlayerCharacterId,
+        location_id: LocationId,
+        game_time: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            pc_id,
+            location_id,
+            game_time,
+            observation_type: ObservationType::Direct,
+            notes: None,
+            created_at: now,
+            visits: 1,
+        }
+    }
+
+    /// The PC was told about the place
+    pub fn heard_about(
+        pc_id: PlayerCharacterId,
+        location_id: LocationId,
+        game_time: DateTime<Utc>,
+        notes: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            pc_id,
+            location_id,
+            game_time,
+            observation_type: ObservationType::HeardAbout,
+            notes,
+            created_at: now,
+            visits: 0,
+        }
+    }
+
+    /// Whether the PC has been there rather than only heard of it
+    pub fn is_visited(&self) -> bool {
+        self.observation_type == ObservationType::Direct
+    }
+}
+
 /// Summary of an observation for display purposes
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -297,4 +374,28 @@ mod tests {
         assert!(obs.is_revealed_to_player);
         assert_eq!(obs.notes.as_deref(), Some("The bartender told me"));
     }
+
+    #[test]
+    fn observation_type_keys_parse_back() {
+        for kind in [
+            ObservationType::Direct,
+            ObservationType::HeardAbout,
+            ObservationType::Deduced,
+        ] {
+            assert_eq!(kind.as_key().parse::<ObservationType>().unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn heard_about_locations_are_not_visited() {
+        let pc_id = PlayerCharacterId::new();
+        let location_id = LocationId::new();
+        let now = Utc::now();
+
+        let told = LocationObservation::heard_about(pc_id, location_id, now, None, now);
+        let been = LocationObservation::visited(pc_id, location_id, now, now);
+
+        assert!(!told.is_visited());
+        assert!(been.is_visited());
+    }
 }
@@ -0,0 +1,218 @@
+//! PC secret entity - what only the DM and one player know
+//!
+//! A hidden past, a secret agenda, a betrayal waiting for its moment. The
+//! player controlling the PC can read the secret and NPC dialogue is quietly
+//! shaped by it, but nobody else learns of it until the DM reveals it. A
+//! reveal turns the secret into a public story event and shifts how the
+//! NPCs it names feel about the PC.
+//!
+//! # Neo4j Relationships
+//! - `(PlayerCharacter)-[:HAS_SECRET]->(PcSecret)`
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{CharacterId, PcSecretId, PlayerCharacterId, WorldId};
+
+/// Longest secret title, in characters
+pub const MAX_SECRET_TITLE_LEN: usize = 200;
+
+/// Longest secret text, in characters
+pub const MAX_SECRET_CONTENT_LEN: usize = 4000;
+
+/// Most NPC reactions one secret can carry
+pub const MAX_SECRET_REACTIONS: usize = 20;
+
+/// Most unrevealed secrets one PC can keep
+pub const MAX_SECRETS_PER_PC: usize = 20;
+
+/// How one NPC takes a secret coming out
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretReaction {
+    pub npc_id: CharacterId,
+    /// Change in the NPC's sentiment toward the PC, -1.0 to 1.0
+    pub sentiment_change: f32,
+}
+
+/// A secret a player character keeps, or once kept
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PcSecret {
+    pub id: PcSecretId,
+    pub world_id: WorldId,
+    pub pc_id: PlayerCharacterId,
+    /// What the DM calls it: "Sold out the rebels"
+    pub title: String,
+    /// The secret itself, as the player reads it
+    pub content: String,
+    /// How NPCs take it when it is revealed
+    pub reactions: Vec<SecretReaction>,
+    /// When the DM revealed it to everyone
+    pub revealed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PcSecret {
+    pub fn new(
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        title: impl Into<String>,
+        content: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        Ok(Self {
+            id: PcSecretId::new(),
+            world_id,
+            pc_id,
+            title: validate_title(title.into())?,
+            content: validate_content(content.into())?,
+            reactions: Vec::new(),
+            revealed_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn with_reactions(mut self, reactions: Vec<SecretReaction>) -> Result<Self, DomainError> {
+        self.reactions = validate_reactions(reactions)?;
+        Ok(self)
+    }
+
+    /// Change whichever fields are given. A revealed secret is part of the
+    /// story and no longer changes.
+    pub fn update(
+        &mut self,
+        title: Option<String>,
+        content: Option<String>,
+        reactions: Option<Vec<SecretReaction>>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if self.is_revealed() {
+            return Err(DomainError::invalid_state_transition(
+                "A revealed secret can no longer be changed",
+            ));
+        }
+        let title = title.map(validate_title).transpose()?;
+        let content = content.map(validate_content).transpose()?;
+        let reactions = reactions.map(validate_reactions).transpose()?;
+        if let Some(title) = title {
+            self.title = title;
+        }
+        if let Some(content) = content {
+            self.content = content;
+        }
+        if let Some(reactions) = reactions {
+            self.reactions = reactions;
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    pub fn is_revealed(&self) -> bool {
+        self.revealed_at.is_some()
+    }
+
+    /// Make the secret public
+    pub fn reveal(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.is_revealed() {
+            return Err(DomainError::invalid_state_transition(
+                "This secret has already been revealed",
+            ));
+        }
+        self.revealed_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+fn validate_title(title: String) -> Result<String, DomainError> {
+    let title = title.trim().to_string();
+    if title.is_empty() {
+        return Err(DomainError::validation("Secret title cannot be empty"));
+    }
+    if title.chars().count() > MAX_SECRET_TITLE_LEN {
+        return Err(DomainError::validation(format!(
+            "Secret title cannot exceed {} characters",
+            MAX_SECRET_TITLE_LEN
+        )));
+    }
+    Ok(title)
+}
+
+fn validate_content(content: String) -> Result<String, DomainError> {
+    let content = content.trim().to_string();
+    if content.chars().count() > MAX_SECRET_CONTENT_LEN {
+        return Err(DomainError::validation(format!(
+            "Secret text cannot exceed {} characters",
+            MAX_SECRET_CONTENT_LEN
+        )));
+    }
+    Ok(content)
+}
+
+fn validate_reactions(reactions: Vec<SecretReaction>) -> Result<Vec<SecretReaction>, DomainError> {
+    if reactions.len() > MAX_SECRET_REACTIONS {
+        return Err(DomainError::validation(format!(
+            "A secret cannot carry more than {} reactions",
+            MAX_SECRET_REACTIONS
+        )));
+    }
+    for (i, reaction) in reactions.iter().enumerate() {
+        if !(-1.0..=1.0).contains(&reaction.sentiment_change) {
+            return Err(DomainError::validation(
+                "Sentiment change must be between -1.0 and 1.0",
+            ));
+        }
+        if reactions[..i].iter().any(|r| r.npc_id == reaction.npc_id) {
+            return Err(DomainError::validation(
+                "Each NPC can only react to a secret once",
+            ));
+        }
+    }
+    Ok(reactions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret() -> PcSecret {
+        PcSecret::new(
+            WorldId::new(),
+            PlayerCharacterId::new(),
+            "Sold out the rebels",
+            "You gave the magistrate their names for a pardon.",
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn reactions_must_be_in_range_and_unique() {
+        let npc = CharacterId::new();
+        let reaction = |change| SecretReaction {
+            npc_id: npc,
+            sentiment_change: change,
+        };
+        assert!(secret().with_reactions(vec![reaction(-0.5)]).is_ok());
+        assert!(secret().with_reactions(vec![reaction(-1.5)]).is_err());
+        assert!(secret()
+            .with_reactions(vec![reaction(-0.5), reaction(0.2)])
+            .is_err());
+        assert!(secret().with_reactions(vec![reaction(f32::NAN)]).is_err());
+    }
+
+    #[test]
+    fn revealed_secrets_are_settled() {
+        let mut secret = secret();
+        secret.reveal(Utc::now()).unwrap();
+        assert!(secret.is_revealed());
+        assert!(secret.reveal(Utc::now()).is_err());
+        assert!(secret
+            .update(Some("Retold".to_string()), None, None, Utc::now())
+            .is_err());
+    }
+}
@@ -1,9 +1,17 @@
 //! Player Character entity - PCs created by players, distinct from NPCs
 
 use crate::entities::sheet_template::CharacterSheetData;
+use crate::value_objects::RuleSystemVariant;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use wrldbldr_domain::{LocationId, PlayerCharacterId, RegionId, WorldId};
+use wrldbldr_domain::{LocationId, PlayerCharacterId, RegionId, StoryEventId, WorldId};
+
+use crate::error::DomainError;
+
+/// Maximum length of a recorded cause of death
+pub const MAX_DEATH_CAUSE_LEN: usize = 500;
+/// Maximum length of a PC's epitaph
+pub const MAX_EPITAPH_LEN: usize = 2000;
 
 /// A player character (PC) - distinct from NPCs
 ///
@@ -24,6 +32,9 @@ pub struct PlayerCharacter {
 
     // Character sheet data (matches CharacterSheetData from Phase 14)
     pub sheet_data: Option<CharacterSheetData>,
+    /// Sheets kept from before the world changed rule system, oldest first
+    #[serde(default)]
+    pub sheet_versions: Vec<SheetVersion>,
 
     // Location tracking
     pub current_location_id: LocationId,
@@ -42,16 +53,78 @@ pub struct PlayerCharacter {
     /// Whether the character is currently active in the world
     #[serde(default = "default_true")]
     pub is_active: bool,
+    /// How and when the character died, while they are dead
+    #[serde(default)]
+    pub death: Option<PcDeath>,
+    /// How many times the character has been brought back from death
+    #[serde(default)]
+    pub resurrections: u32,
+    /// The fallen PC this character succeeded, if any
+    #[serde(default)]
+    pub predecessor_id: Option<PlayerCharacterId>,
+    /// The PC who took over from this one after their death
+    #[serde(default)]
+    pub successor_id: Option<PlayerCharacterId>,
 
     // Metadata
     pub created_at: DateTime<Utc>,
     pub last_active_at: DateTime<Utc>,
 }
 
+/// A character sheet retired when the world changed rule system
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SheetVersion {
+    /// The rule system the sheet was filled in for
+    pub variant: RuleSystemVariant,
+    pub sheet_data: CharacterSheetData,
+    pub retired_at: DateTime<Utc>,
+}
+
 fn default_true() -> bool {
     true
 }
 
+/// The record of a PC's death
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PcDeath {
+    /// What killed the character
+    pub cause: String,
+    /// Words to remember them by
+    pub epitaph: Option<String>,
+    /// Game time of death
+    pub died_at: DateTime<Utc>,
+    /// The memorial story event written when they died
+    pub memorial_event_id: Option<StoryEventId>,
+}
+
+impl PcDeath {
+    pub fn new(cause: impl Into<String>, died_at: DateTime<Utc>) -> Self {
+        Self {
+            cause: cause.into(),
+            epitaph: None,
+            died_at,
+            memorial_event_id: None,
+        }
+    }
+
+    pub fn with_epitaph(mut self, epitaph: impl Into<String>) -> Self {
+        self.epitaph = Some(epitaph.into());
+        self
+    }
+
+    pub fn with_memorial_event(mut self, event_id: StoryEventId) -> Self {
+        self.memorial_event_id = Some(event_id);
+        self
+    }
+
+    /// Whole game days since the death
+    pub fn days_dead(&self, game_now: DateTime<Utc>) -> i64 {
+        (game_now - self.died_at).num_days().max(0)
+    }
+}
+
 impl PlayerCharacter {
     /// Create a new player character
     pub fn new(
@@ -68,6 +141,7 @@ impl PlayerCharacter {
             name: name.into(),
             description: None,
             sheet_data: None,
+            sheet_versions: Vec::new(),
             current_location_id: starting_location_id,
             current_region_id: None,
             starting_location_id,
@@ -75,6 +149,10 @@ impl PlayerCharacter {
             portrait_asset: None,
             is_alive: true,
             is_active: true,
+            death: None,
+            resurrections: 0,
+            predecessor_id: None,
+            successor_id: None,
             created_at: now,
             last_active_at: now,
         }
@@ -98,6 +176,23 @@ impl PlayerCharacter {
         self
     }
 
+    /// Replace the sheet with one for a new rule system, keeping the old one
+    /// as a version filled in for `variant`
+    pub fn replace_sheet(
+        &mut self,
+        variant: RuleSystemVariant,
+        sheet_data: CharacterSheetData,
+        now: DateTime<Utc>,
+    ) {
+        if let Some(old) = self.sheet_data.replace(sheet_data) {
+            self.sheet_versions.push(SheetVersion {
+                variant,
+                sheet_data: old,
+                retired_at: now,
+            });
+        }
+    }
+
     /// Set the sprite asset
     pub fn with_sprite(mut self, asset_path: impl Into<String>) -> Self {
         self.sprite_asset = Some(asset_path.into());
@@ -110,6 +205,79 @@ impl PlayerCharacter {
         self
     }
 
+    /// Mark the character as succeeding a fallen PC
+    pub fn with_predecessor(mut self, predecessor_id: PlayerCharacterId) -> Self {
+        self.predecessor_id = Some(predecessor_id);
+        self
+    }
+
+    /// Whether the character can no longer move or act
+    pub fn is_locked(&self) -> bool {
+        !self.is_alive
+    }
+
+    /// Record the character's death, locking them out of play
+    pub fn die(&mut self, death: PcDeath, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if !self.is_alive {
+            return Err(DomainError::invalid_state_transition(format!(
+                "{} is already dead",
+                self.name
+            )));
+        }
+        self.is_alive = false;
+        self.is_active = false;
+        self.death = Some(death);
+        self.last_active_at = now;
+        Ok(())
+    }
+
+    /// Bring the character back to life, returning the death record
+    ///
+    /// A PC whose player has already moved on to a successor stays dead.
+    pub fn resurrect(&mut self, now: DateTime<Utc>) -> Result<PcDeath, DomainError> {
+        if self.is_alive {
+            return Err(DomainError::invalid_state_transition(format!(
+                "{} is not dead",
+                self.name
+            )));
+        }
+        if self.successor_id.is_some() {
+            return Err(DomainError::invalid_state_transition(format!(
+                "{} already has a successor",
+                self.name
+            )));
+        }
+        let death = self.death.take().ok_or_else(|| {
+            DomainError::invalid_state_transition(format!("{} has no death record", self.name))
+        })?;
+        self.is_alive = true;
+        self.is_active = true;
+        self.resurrections += 1;
+        self.last_active_at = now;
+        Ok(death)
+    }
+
+    /// Record the PC who takes over from this fallen one
+    pub fn pass_to_successor(
+        &mut self,
+        successor_id: PlayerCharacterId,
+    ) -> Result<(), DomainError> {
+        if self.is_alive {
+            return Err(DomainError::invalid_state_transition(format!(
+                "{} is still alive",
+                self.name
+            )));
+        }
+        if self.successor_id.is_some() {
+            return Err(DomainError::invalid_state_transition(format!(
+                "{} already has a successor",
+                self.name
+            )));
+        }
+        self.successor_id = Some(successor_id);
+        Ok(())
+    }
+
     /// Update the character's current location (clears region)
     pub fn update_location(&mut self, location_id: LocationId, now: DateTime<Utc>) {
         self.current_location_id = location_id;
@@ -148,3 +316,60 @@ impl PlayerCharacter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn pc() -> PlayerCharacter {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+        PlayerCharacter::new("user", WorldId::new(), "Aria", LocationId::new(), now)
+    }
+
+    #[test]
+    fn death_locks_the_character() {
+        let mut aria = pc();
+        let now = aria.created_at;
+        aria.die(PcDeath::new("Fell from the tower", now), now)
+            .unwrap();
+
+        assert!(aria.is_locked());
+        assert!(!aria.is_active);
+        assert!(aria.die(PcDeath::new("Again", now), now).is_err());
+    }
+
+    #[test]
+    fn resurrection_restores_the_character_and_counts() {
+        let mut aria = pc();
+        let now = aria.created_at;
+        aria.die(PcDeath::new("Poison", now), now).unwrap();
+
+        let death = aria.resurrect(now).unwrap();
+        assert_eq!(death.cause, "Poison");
+        assert!(!aria.is_locked());
+        assert!(aria.death.is_none());
+        assert_eq!(aria.resurrections, 1);
+        assert!(aria.resurrect(now).is_err());
+    }
+
+    #[test]
+    fn a_successor_ends_resurrection() {
+        let mut aria = pc();
+        let now = aria.created_at;
+        aria.die(PcDeath::new("Dragonfire", now), now).unwrap();
+        aria.pass_to_successor(PlayerCharacterId::new()).unwrap();
+
+        assert!(aria.pass_to_successor(PlayerCharacterId::new()).is_err());
+        assert!(aria.resurrect(now).is_err());
+    }
+
+    #[test]
+    fn days_dead_counts_whole_game_days() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+        let death = PcDeath::new("Cold", now);
+
+        assert_eq!(death.days_dead(now + Duration::hours(47)), 1);
+        assert_eq!(death.days_dead(now - Duration::hours(5)), 0);
+    }
+}
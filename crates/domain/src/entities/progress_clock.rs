@@ -0,0 +1,308 @@
+//! Progress Clock entity - Blades-style segmented clocks
+//!
+//! A progress clock tracks an ongoing effort, looming danger, or faction
+//! project as a circle divided into segments. The DM fills segments as the
+//! fiction advances (or challenge outcomes tick them automatically). When
+//! every segment is filled the clock is complete.
+//!
+//! A clock owned by a PC is personal: only that PC and the DM ever see it,
+//! and it may secretly work against one of the party's clocks.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_CLOCK]->(ProgressClock)` - Clock belongs to a world
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{PlayerCharacterId, ProgressClockId, WorldId};
+
+/// Smallest number of segments a clock may have
+pub const MIN_CLOCK_SEGMENTS: u8 = 2;
+/// Largest number of segments a clock may have
+pub const MAX_CLOCK_SEGMENTS: u8 = 24;
+
+/// A segmented progress clock
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressClock {
+    pub id: ProgressClockId,
+    pub world_id: WorldId,
+
+    /// Display name (e.g., "The Bluecoats close in")
+    pub name: String,
+    /// Optional DM notes about what the clock represents
+    pub description: Option<String>,
+
+    /// What kind of thing this clock tracks
+    pub kind: ClockKind,
+    /// Name of the faction, quest, or threat this clock is linked to
+    pub subject: Option<String>,
+
+    /// Total number of segments
+    pub segments: u8,
+    /// Number of filled segments (0..=segments)
+    pub filled: u8,
+
+    /// Whether players can see this clock (DM-only when false)
+    pub visible_to_players: bool,
+    /// PC this clock is personal to; hidden from every other player
+    #[serde(default)]
+    pub owner_pc_id: Option<PlayerCharacterId>,
+    /// Party clock this personal objective works against
+    #[serde(default)]
+    pub conflicts_with: Option<ProgressClockId>,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// What a progress clock is tracking
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ClockKind {
+    /// A faction's long-term project or advance
+    Faction,
+    /// Progress toward a quest or objective
+    Quest,
+    /// A looming danger or countdown
+    Threat,
+    /// A PC or crew project
+    Project,
+    /// Unknown kind (for forward compatibility)
+    #[serde(other)]
+    Unknown,
+}
+
+/// Result of ticking a clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockTick {
+    /// Filled segments before the tick
+    pub previous: u8,
+    /// Filled segments after the tick
+    pub filled: u8,
+    /// True if this tick filled the last segment
+    pub just_completed: bool,
+}
+
+impl ProgressClock {
+    pub fn new(
+        world_id: WorldId,
+        name: impl Into<String>,
+        segments: u8,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        validate_segments(segments)?;
+        Ok(Self {
+            id: ProgressClockId::new(),
+            world_id,
+            name: name.into(),
+            description: None,
+            kind: ClockKind::Threat,
+            subject: None,
+            segments,
+            filled: 0,
+            visible_to_players: false,
+            owner_pc_id: None,
+            conflicts_with: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_kind(mut self, kind: ClockKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn with_subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    pub fn visible(mut self) -> Self {
+        self.visible_to_players = true;
+        self
+    }
+
+    /// Make the clock personal to one PC
+    pub fn owned_by(mut self, pc_id: PlayerCharacterId) -> Self {
+        self.owner_pc_id = Some(pc_id);
+        self
+    }
+
+    pub fn conflicting_with(mut self, clock_id: ProgressClockId) -> Self {
+        self.conflicts_with = Some(clock_id);
+        self
+    }
+
+    /// Whether the clock is personal to a single PC
+    pub fn is_personal(&self) -> bool {
+        self.owner_pc_id.is_some()
+    }
+
+    /// Whether a player viewing as any of `pc_ids` may see this clock.
+    ///
+    /// Personal clocks are only ever shown to their owner.
+    pub fn is_visible_to(&self, pc_ids: &[PlayerCharacterId]) -> bool {
+        self.visible_to_players && self.owner_pc_id.is_none_or(|owner| pc_ids.contains(&owner))
+    }
+
+    /// Whether every segment is filled
+    pub fn is_complete(&self) -> bool {
+        self.filled >= self.segments
+    }
+
+    /// Fill (positive) or clear (negative) segments, clamped to the clock size.
+    pub fn tick(&mut self, amount: i32, now: DateTime<Utc>) -> ClockTick {
+        let previous = self.filled;
+        let was_complete = self.is_complete();
+        let next = (i32::from(self.filled) + amount).clamp(0, i32::from(self.segments));
+        self.filled = next as u8;
+        self.updated_at = now;
+        ClockTick {
+            previous,
+            filled: self.filled,
+            just_completed: !was_complete && self.is_complete(),
+        }
+    }
+
+    /// Change the number of segments, keeping filled segments within bounds.
+    pub fn resize(&mut self, segments: u8, now: DateTime<Utc>) -> Result<(), DomainError> {
+        validate_segments(segments)?;
+        self.segments = segments;
+        self.filled = self.filled.min(segments);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Clear all filled segments
+    pub fn reset(&mut self, now: DateTime<Utc>) {
+        self.filled = 0;
+        self.updated_at = now;
+    }
+}
+
+fn validate_segments(segments: u8) -> Result<(), DomainError> {
+    if !(MIN_CLOCK_SEGMENTS..=MAX_CLOCK_SEGMENTS).contains(&segments) {
+        return Err(DomainError::validation(format!(
+            "Clock must have between {} and {} segments, got {}",
+            MIN_CLOCK_SEGMENTS, MAX_CLOCK_SEGMENTS, segments
+        )));
+    }
+    Ok(())
+}
+
+impl std::fmt::Display for ClockKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClockKind::Faction => write!(f, "faction"),
+            ClockKind::Quest => write!(f, "quest"),
+            ClockKind::Threat => write!(f, "threat"),
+            ClockKind::Project => write!(f, "project"),
+            ClockKind::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl std::str::FromStr for ClockKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "faction" => Ok(ClockKind::Faction),
+            "quest" => Ok(ClockKind::Quest),
+            "threat" => Ok(ClockKind::Threat),
+            "project" => Ok(ClockKind::Project),
+            "unknown" => Ok(ClockKind::Unknown),
+            _ => Err(format!(
+                "Invalid clock kind '{}'. Valid kinds: faction, quest, threat, project",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clock(segments: u8) -> ProgressClock {
+        ProgressClock::new(WorldId::new(), "Alarm", segments, Utc::now()).unwrap()
+    }
+
+    #[test]
+    fn test_new_clock_rejects_bad_segment_counts() {
+        assert!(ProgressClock::new(WorldId::new(), "Bad", 1, Utc::now()).is_err());
+        assert!(ProgressClock::new(WorldId::new(), "Bad", 25, Utc::now()).is_err());
+        assert!(ProgressClock::new(WorldId::new(), "Ok", 4, Utc::now()).is_ok());
+    }
+
+    #[test]
+    fn test_tick_clamps_and_reports_completion() {
+        let mut c = clock(4);
+        let tick = c.tick(3, Utc::now());
+        assert_eq!(tick.previous, 0);
+        assert_eq!(tick.filled, 3);
+        assert!(!tick.just_completed);
+
+        let tick = c.tick(5, Utc::now());
+        assert_eq!(tick.filled, 4);
+        assert!(tick.just_completed);
+        assert!(c.is_complete());
+
+        // Already complete - no second completion
+        let tick = c.tick(1, Utc::now());
+        assert!(!tick.just_completed);
+
+        let tick = c.tick(-10, Utc::now());
+        assert_eq!(tick.filled, 0);
+    }
+
+    #[test]
+    fn test_resize_keeps_filled_in_bounds() {
+        let mut c = clock(8);
+        c.tick(6, Utc::now());
+        c.resize(4, Utc::now()).unwrap();
+        assert_eq!(c.filled, 4);
+        assert!(c.resize(0, Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_personal_clock_is_only_visible_to_its_owner() {
+        let owner = PlayerCharacterId::new();
+        let party = clock(6).visible();
+        let personal = clock(4)
+            .visible()
+            .owned_by(owner)
+            .conflicting_with(party.id);
+
+        assert!(personal.is_personal());
+        assert!(personal.is_visible_to(&[PlayerCharacterId::new(), owner]));
+        assert!(!personal.is_visible_to(&[PlayerCharacterId::new()]));
+        assert!(!personal.is_visible_to(&[]));
+        assert!(party.is_visible_to(&[PlayerCharacterId::new()]));
+
+        // The DM can still keep a personal clock back from its owner
+        let prepared = clock(4).owned_by(owner);
+        assert!(!prepared.is_visible_to(&[owner]));
+    }
+
+    #[test]
+    fn test_clock_kind_round_trip() {
+        for kind in [
+            ClockKind::Faction,
+            ClockKind::Quest,
+            ClockKind::Threat,
+            ClockKind::Project,
+        ] {
+            assert_eq!(kind.to_string().parse::<ClockKind>().unwrap(), kind);
+        }
+        assert!("nonsense".parse::<ClockKind>().is_err());
+    }
+}
@@ -0,0 +1,357 @@
+//! Property entity - a place a player character owns
+//!
+//! The party's tavern, the wizard's tower, a stall in the market: a property
+//! ties a PC to a location or one of its regions. Properties cost upkeep,
+//! counted per game day, and can employ staff NPCs who run them while the
+//! owner is away.
+//!
+//! Upkeep is never charged automatically. The property remembers the game
+//! time it's paid through; what's owed is the number of whole game days
+//! since then, and paying settles those days and no more.
+//!
+//! # Neo4j Relationships
+//! - `(PlayerCharacter)-[:OWNS_PROPERTY]->(Property)`
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{CharacterId, LocationId, PlayerCharacterId, PropertyId, RegionId, WorldId};
+
+/// Longest property name or staff duties, in characters
+pub const MAX_PROPERTY_TEXT_LEN: usize = 200;
+
+/// Longest property notes, in characters
+pub const MAX_PROPERTY_NOTES_LEN: usize = 2000;
+
+/// Most staff NPCs one property can employ
+pub const MAX_PROPERTY_STAFF: usize = 20;
+
+/// Largest daily upkeep a property can cost
+pub const MAX_DAILY_UPKEEP: u32 = 100_000;
+
+/// Where a property is: a whole location, or one region of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "snake_case")]
+pub enum PropertySite {
+    Location(LocationId),
+    Region(RegionId),
+}
+
+impl PropertySite {
+    pub fn location_id(self) -> Option<LocationId> {
+        match self {
+            PropertySite::Location(id) => Some(id),
+            PropertySite::Region(_) => None,
+        }
+    }
+
+    pub fn region_id(self) -> Option<RegionId> {
+        match self {
+            PropertySite::Region(id) => Some(id),
+            PropertySite::Location(_) => None,
+        }
+    }
+}
+
+/// What a staff member does at a property
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StaffRole {
+    /// Runs the place in the owner's absence
+    Steward,
+    /// Keeps it safe
+    Guard,
+    /// Everyone else who works there
+    #[default]
+    Worker,
+}
+
+impl StaffRole {
+    pub const ALL: [StaffRole; 3] = [StaffRole::Steward, StaffRole::Guard, StaffRole::Worker];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StaffRole::Steward => "steward",
+            StaffRole::Guard => "guard",
+            StaffRole::Worker => "worker",
+        }
+    }
+}
+
+impl fmt::Display for StaffRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StaffRole {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StaffRole::ALL
+            .into_iter()
+            .find(|role| role.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| DomainError::parse(format!("Unknown staff role: {}", s)))
+    }
+}
+
+/// An NPC employed at a property
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyStaff {
+    pub character_id: CharacterId,
+    pub role: StaffRole,
+    /// Their job in words, when the role alone doesn't say it: "barkeep"
+    #[serde(default)]
+    pub duties: String,
+}
+
+/// A place a player character owns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Property {
+    pub id: PropertyId,
+    pub world_id: WorldId,
+    pub owner_id: PlayerCharacterId,
+    /// What the owner calls it: "The Gilded Flagon"
+    pub name: String,
+    pub site: PropertySite,
+    /// Cost per game day, in the world's currency
+    pub upkeep_per_day: u32,
+    /// Game time up to which upkeep has been paid
+    pub upkeep_paid_through: DateTime<Utc>,
+    pub staff: Vec<PropertyStaff>,
+    pub notes: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Property {
+    /// A property acquired at game time `acquired_at`, paid up until then
+    pub fn new(
+        world_id: WorldId,
+        owner_id: PlayerCharacterId,
+        name: impl Into<String>,
+        site: PropertySite,
+        acquired_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        Ok(Self {
+            id: PropertyId::new(),
+            world_id,
+            owner_id,
+            name: validate_name(&name.into())?,
+            site,
+            upkeep_per_day: 0,
+            upkeep_paid_through: acquired_at,
+            staff: Vec::new(),
+            notes: String::new(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn with_upkeep(mut self, per_day: u32) -> Result<Self, DomainError> {
+        self.upkeep_per_day = validate_upkeep(per_day)?;
+        Ok(self)
+    }
+
+    pub fn with_staff(mut self, staff: Vec<PropertyStaff>) -> Result<Self, DomainError> {
+        self.staff = validate_staff(staff)?;
+        Ok(self)
+    }
+
+    /// Change everything the DM can set
+    pub fn update(
+        &mut self,
+        name: &str,
+        site: PropertySite,
+        upkeep_per_day: u32,
+        staff: Vec<PropertyStaff>,
+        notes: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let name = validate_name(name)?;
+        let upkeep_per_day = validate_upkeep(upkeep_per_day)?;
+        let staff = validate_staff(staff)?;
+        let notes = notes.trim();
+        if notes.chars().count() > MAX_PROPERTY_NOTES_LEN {
+            return Err(DomainError::validation(format!(
+                "Notes cannot exceed {} characters",
+                MAX_PROPERTY_NOTES_LEN
+            )));
+        }
+        self.name = name;
+        self.site = site;
+        self.upkeep_per_day = upkeep_per_day;
+        self.staff = staff;
+        self.notes = notes.to_string();
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Whole game days of upkeep owed at game time `game_now`
+    pub fn days_owed(&self, game_now: DateTime<Utc>) -> u32 {
+        let days = (game_now - self.upkeep_paid_through).num_days();
+        u32::try_from(days.max(0)).unwrap_or(u32::MAX)
+    }
+
+    /// Upkeep owed at game time `game_now`
+    pub fn upkeep_due(&self, game_now: DateTime<Utc>) -> u64 {
+        self.days_owed(game_now) as u64 * self.upkeep_per_day as u64
+    }
+
+    /// Mark the owed days paid, keeping any part day towards the next one.
+    /// Returns what was settled.
+    pub fn settle_upkeep(&mut self, game_now: DateTime<Utc>, now: DateTime<Utc>) -> u64 {
+        let days = self.days_owed(game_now);
+        let due = self.upkeep_due(game_now);
+        self.upkeep_paid_through += Duration::days(days as i64);
+        self.updated_at = now;
+        due
+    }
+
+    /// Whether `character_id` works here
+    pub fn employs(&self, character_id: CharacterId) -> bool {
+        self.staff.iter().any(|s| s.character_id == character_id)
+    }
+}
+
+fn validate_name(name: &str) -> Result<String, DomainError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(DomainError::validation("A property needs a name"));
+    }
+    if name.chars().count() > MAX_PROPERTY_TEXT_LEN {
+        return Err(DomainError::validation(format!(
+            "Property name cannot exceed {} characters",
+            MAX_PROPERTY_TEXT_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+fn validate_upkeep(per_day: u32) -> Result<u32, DomainError> {
+    if per_day > MAX_DAILY_UPKEEP {
+        return Err(DomainError::validation(format!(
+            "Upkeep cannot exceed {} per day",
+            MAX_DAILY_UPKEEP
+        )));
+    }
+    Ok(per_day)
+}
+
+/// Trim duties and drop repeated NPCs, keeping each one's first entry
+fn validate_staff(staff: Vec<PropertyStaff>) -> Result<Vec<PropertyStaff>, DomainError> {
+    let mut cleaned: Vec<PropertyStaff> = Vec::with_capacity(staff.len());
+    for mut member in staff {
+        if cleaned
+            .iter()
+            .any(|s| s.character_id == member.character_id)
+        {
+            continue;
+        }
+        member.duties = member.duties.trim().to_string();
+        if member.duties.chars().count() > MAX_PROPERTY_TEXT_LEN {
+            return Err(DomainError::validation(format!(
+                "Staff duties cannot exceed {} characters",
+                MAX_PROPERTY_TEXT_LEN
+            )));
+        }
+        cleaned.push(member);
+    }
+    if cleaned.len() > MAX_PROPERTY_STAFF {
+        return Err(DomainError::validation(format!(
+            "A property cannot employ more than {} staff",
+            MAX_PROPERTY_STAFF
+        )));
+    }
+    Ok(cleaned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tavern(acquired_at: DateTime<Utc>) -> Property {
+        Property::new(
+            WorldId::new(),
+            PlayerCharacterId::new(),
+            "The Gilded Flagon",
+            PropertySite::Location(LocationId::new()),
+            acquired_at,
+            Utc::now(),
+        )
+        .unwrap()
+        .with_upkeep(3)
+        .unwrap()
+    }
+
+    #[test]
+    fn upkeep_accrues_per_whole_game_day() {
+        let acquired = Utc::now();
+        let property = tavern(acquired);
+
+        assert_eq!(property.upkeep_due(acquired + Duration::hours(23)), 0);
+        assert_eq!(property.days_owed(acquired + Duration::hours(50)), 2);
+        assert_eq!(property.upkeep_due(acquired + Duration::hours(50)), 6);
+        // Turning the clock back owes nothing
+        assert_eq!(property.upkeep_due(acquired - Duration::days(3)), 0);
+    }
+
+    #[test]
+    fn settling_keeps_the_part_day() {
+        let acquired = Utc::now();
+        let mut property = tavern(acquired);
+        let game_now = acquired + Duration::hours(50);
+
+        assert_eq!(property.settle_upkeep(game_now, Utc::now()), 6);
+        assert_eq!(property.upkeep_due(game_now), 0);
+        assert_eq!(property.upkeep_paid_through, acquired + Duration::days(2));
+        assert_eq!(property.days_owed(game_now + Duration::hours(22)), 1);
+    }
+
+    #[test]
+    fn staff_are_deduplicated_and_bounded() {
+        let barkeep = CharacterId::new();
+        let staff = vec![
+            PropertyStaff {
+                character_id: barkeep,
+                role: StaffRole::Worker,
+                duties: "  barkeep ".to_string(),
+            },
+            PropertyStaff {
+                character_id: barkeep,
+                role: StaffRole::Guard,
+                duties: String::new(),
+            },
+        ];
+        let property = tavern(Utc::now()).with_staff(staff).unwrap();
+        assert_eq!(property.staff.len(), 1);
+        assert_eq!(property.staff[0].duties, "barkeep");
+        assert!(property.employs(barkeep));
+
+        let crowd = (0..=MAX_PROPERTY_STAFF)
+            .map(|_| PropertyStaff {
+                character_id: CharacterId::new(),
+                role: StaffRole::Worker,
+                duties: String::new(),
+            })
+            .collect();
+        assert!(tavern(Utc::now()).with_staff(crowd).is_err());
+    }
+
+    #[test]
+    fn names_and_upkeep_are_validated() {
+        let site = PropertySite::Region(RegionId::new());
+        let pc = PlayerCharacterId::new();
+        assert!(Property::new(WorldId::new(), pc, " ", site, Utc::now(), Utc::now()).is_err());
+        assert!(tavern(Utc::now())
+            .with_upkeep(MAX_DAILY_UPKEEP + 1)
+            .is_err());
+    }
+}
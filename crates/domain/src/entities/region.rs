@@ -12,8 +12,11 @@
 //! - `(Character)-[:HOME_REGION]->(Region)` - NPC lives here
 //! - `(Character)-[:AVOIDS_REGION]->(Region)` - NPC avoids this place
 
+use std::collections::HashMap;
+
+use super::location::LocationType;
 use serde::{Deserialize, Serialize};
-use wrldbldr_domain::{LocationId, RegionId};
+use wrldbldr_domain::{ChallengeId, InteractionId, ItemId, LocationId, RegionId, TimeOfDay};
 
 /// A region within a location - represents a distinct "screen" or area
 ///
@@ -33,6 +36,9 @@ pub struct Region {
     pub backdrop_asset: Option<String>,
     /// Sensory/emotional description of the region's atmosphere
     pub atmosphere: Option<String>,
+    /// Path to a generated top-down battle map or layout of this region
+    #[serde(default)]
+    pub map_asset: Option<String>,
 
     // Position on parent location's map (clickable area)
     /// Bounds defining where this region is on the parent location's map
@@ -42,6 +48,15 @@ pub struct Region {
     pub is_spawn_point: bool,
     /// Display order within the location
     pub order: u32,
+
+    /// Clickable areas on the backdrop
+    #[serde(default)]
+    pub hotspots: Vec<RegionHotspot>,
+
+    /// Fixed lighting; None follows the location (daylight outdoors, lit
+    /// indoors)
+    #[serde(default)]
+    pub lighting: Option<LightLevel>,
 }
 
 impl Region {
@@ -54,9 +69,55 @@ impl Region {
             description: String::new(),
             backdrop_asset: None,
             atmosphere: None,
+            map_asset: None,
             map_bounds: None,
             is_spawn_point: false,
             order: 0,
+            hotspots: Vec::new(),
+            lighting: None,
+        }
+    }
+
+    /// A copy of this region, with a new ID, in `location_id`
+    pub fn duplicate_into(&self, location_id: LocationId) -> Self {
+        Self {
+            id: RegionId::new(),
+            location_id,
+            ..self.clone()
+        }
+    }
+
+    /// Repoint hotspots at copied regions and items
+    ///
+    /// Used after copying a whole location: exits and items found in the maps
+    /// are swapped for their copies, everything else is left alone.
+    pub fn remap_hotspots(
+        &mut self,
+        regions: &HashMap<RegionId, RegionId>,
+        items: &HashMap<ItemId, ItemId>,
+    ) {
+        for hotspot in &mut self.hotspots {
+            match &mut hotspot.target {
+                HotspotTarget::Exit { region_id } => {
+                    if let Some(copy) = regions.get(region_id) {
+                        *region_id = *copy;
+                    }
+                }
+                HotspotTarget::Item { item_id } => {
+                    if let Some(copy) = items.get(item_id) {
+                        *item_id = *copy;
+                    }
+                }
+                HotspotTarget::LocationExit {
+                    arrival_region_id: Some(region_id),
+                    ..
+                } => {
+                    if let Some(copy) = regions.get(region_id) {
+                        *region_id = *copy;
+                    }
+                }
+                HotspotTarget::Interaction { .. } | HotspotTarget::LocationExit { .. } => {}
+            }
         }
     }
 
@@ -75,6 +136,11 @@ impl Region {
         self
     }
 
+    pub fn with_map(mut self, asset_path: impl Into<String>) -> Self {
+        self.map_asset = Some(asset_path.into());
+        self
+    }
+
     pub fn with_map_bounds(mut self, bounds: MapBounds) -> Self {
         self.map_bounds = Some(bounds);
         self
@@ -90,6 +156,35 @@ impl Region {
         self
     }
 
+    pub fn with_hotspot(mut self, hotspot: RegionHotspot) -> Self {
+        self.hotspots.push(hotspot);
+        self
+    }
+
+    pub fn with_lighting(mut self, lighting: LightLevel) -> Self {
+        self.lighting = Some(lighting);
+        self
+    }
+
+    /// How well lit the region is right now
+    ///
+    /// A fixed level wins. Otherwise exteriors follow the sun and everything
+    /// else is assumed lit.
+    pub fn light_level(&self, location_type: LocationType, time: TimeOfDay) -> LightLevel {
+        match (self.lighting, location_type) {
+            (Some(level), _) => level,
+            (None, LocationType::Exterior) => LightLevel::daylight(time),
+            (None, _) => LightLevel::Bright,
+        }
+    }
+
+    /// The topmost hotspot at a backdrop position (percent), if any
+    ///
+    /// Later hotspots are drawn over earlier ones, so they win overlaps.
+    pub fn hotspot_at(&self, x: f32, y: f32) -> Option<&RegionHotspot> {
+        self.hotspots.iter().rev().find(|h| h.contains(x, y))
+    }
+
     /// Check if a pixel position is within this region's map bounds
     pub fn contains_point(&self, x: u32, y: u32) -> bool {
         if let Some(bounds) = &self.map_bounds {
@@ -100,6 +195,107 @@ impl Region {
     }
 }
 
+/// How much light there is to see by in a region
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LightLevel {
+    Bright,
+    Dim,
+    Dark,
+}
+
+impl LightLevel {
+    /// Natural light outdoors at a time of day
+    pub fn daylight(time: TimeOfDay) -> Self {
+        match time {
+            TimeOfDay::Morning | TimeOfDay::Afternoon => Self::Bright,
+            TimeOfDay::Evening => Self::Dim,
+            TimeOfDay::Night => Self::Dark,
+        }
+    }
+
+    /// Modifier applied to perception checks made here
+    pub fn perception_modifier(self) -> i32 {
+        match self {
+            Self::Bright => 0,
+            Self::Dim => -2,
+            Self::Dark => -5,
+        }
+    }
+
+    /// Whether a hidden NPC can be spotted at all
+    ///
+    /// In the dark nobody is found by looking, whatever the roll.
+    pub fn reveals_hidden(self) -> bool {
+        self != Self::Dark
+    }
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Bright => "Bright",
+            Self::Dim => "Dim",
+            Self::Dark => "Dark",
+        }
+    }
+
+    /// What it's like to be here, for narration and prompts
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::Bright => "well lit",
+            Self::Dim => "dimly lit; faces and details are hard to make out",
+            Self::Dark => "dark; little can be seen without a light",
+        }
+    }
+}
+
+/// How well sound passes along a connection between regions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SoundCarry {
+    /// An archway or open ground; anything loud is heard
+    #[default]
+    Open,
+    /// A door or thin wall; only the loudest noises get through, muffled
+    Muffled,
+    /// Thick stone or a sealed vault; nothing is heard
+    Blocked,
+}
+
+impl SoundCarry {
+    /// Whether a noise is heard on the far side, and if so whether muffled
+    ///
+    /// Returns `None` when the noise doesn't carry, otherwise
+    /// `Some(muffled)`.
+    pub fn carries(self, loudness: Loudness) -> Option<bool> {
+        match (self, loudness) {
+            (Self::Open, _) => Some(false),
+            (Self::Muffled, Loudness::Deafening) => Some(true),
+            (Self::Muffled, Loudness::Loud) | (Self::Blocked, _) => None,
+        }
+    }
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Open => "Open",
+            Self::Muffled => "Muffled",
+            Self::Blocked => "Blocked",
+        }
+    }
+}
+
+/// Longest description a noise can have
+pub const MAX_NOISE_DESCRIPTION_LEN: usize = 200;
+
+/// How loud a noise made in a region is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Loudness {
+    /// A shout, breaking glass, a scuffle
+    Loud,
+    /// An explosion, a collapsing wall, a war horn
+    Deafening,
+}
+
 /// Bounds defining a rectangular area on a map image
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -158,6 +354,130 @@ impl MapBounds {
     }
 }
 
+/// A clickable area on a region's backdrop
+///
+/// Lets players click the suspicious bookshelf instead of typing
+/// "examine bookshelf". Coordinates are percentages of the backdrop so they
+/// hold at any display size.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegionHotspot {
+    /// Shown on hover and used as the action target name
+    pub label: String,
+    /// Polygon vertices, in drawing order
+    pub points: Vec<HotspotPoint>,
+    /// What clicking the hotspot does
+    pub target: HotspotTarget,
+}
+
+impl RegionHotspot {
+    /// Create a hotspot, clamping points to the backdrop
+    ///
+    /// Returns `None` for a blank label or fewer than three points.
+    pub fn new(
+        label: impl Into<String>,
+        points: Vec<HotspotPoint>,
+        target: HotspotTarget,
+    ) -> Option<Self> {
+        let label = label.into();
+        if label.trim().is_empty() || points.len() < 3 {
+            return None;
+        }
+        Some(Self {
+            label,
+            points: points
+                .into_iter()
+                .map(|p| HotspotPoint::new(p.x, p.y))
+                .collect(),
+            target,
+        })
+    }
+
+    /// Whether a backdrop position (percent) falls inside the polygon
+    ///
+    /// Even-odd ray casting, so concave outlines work too.
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        let mut inside = false;
+        let mut j = self.points.len().wrapping_sub(1);
+        for (i, a) in self.points.iter().enumerate() {
+            let b = &self.points[j];
+            if (a.y > y) != (b.y > y) && x < (b.x - a.x) * (y - a.y) / (b.y - a.y) + a.x {
+                inside = !inside;
+            }
+            j = i;
+        }
+        inside
+    }
+}
+
+/// A hotspot vertex as percentages of the backdrop (0-100)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HotspotPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl HotspotPoint {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            x: x.clamp(0.0, 100.0),
+            y: y.clamp(0.0, 100.0),
+        }
+    }
+}
+
+/// What a hotspot does when clicked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum HotspotTarget {
+    /// Perform an interaction defined for the region's scene
+    #[serde(rename_all = "camelCase")]
+    Interaction { interaction_id: InteractionId },
+    /// Examine an item in the region
+    #[serde(rename_all = "camelCase")]
+    Item { item_id: ItemId },
+    /// Move to a connected region
+    #[serde(rename_all = "camelCase")]
+    Exit { region_id: RegionId },
+    /// Leave for another location
+    #[serde(rename_all = "camelCase")]
+    LocationExit {
+        location_id: LocationId,
+        arrival_region_id: Option<RegionId>,
+    },
+}
+
+/// What opens a locked connection, besides the DM unlocking it
+///
+/// Shared by region and location connections. A PC carrying any one of the
+/// key items can use it on the lock; succeeding at the linked challenge
+/// (picking the lock, forcing the door) opens it too.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockRequirements {
+    /// Items that open the lock; any one of them will do
+    #[serde(default)]
+    pub key_item_ids: Vec<ItemId>,
+    /// Challenge whose success opens the lock
+    #[serde(default)]
+    pub challenge_id: Option<ChallengeId>,
+}
+
+impl LockRequirements {
+    /// Whether nothing but the DM can open the lock
+    pub fn is_empty(&self) -> bool {
+        self.key_item_ids.is_empty() && self.challenge_id.is_none()
+    }
+
+    pub fn opened_by_key(&self, item_id: ItemId) -> bool {
+        self.key_item_ids.contains(&item_id)
+    }
+
+    pub fn opened_by_challenge(&self, challenge_id: ChallengeId) -> bool {
+        self.challenge_id == Some(challenge_id)
+    }
+}
+
 /// A connection between two regions
 ///
 /// Stored as a `CONNECTED_TO_REGION` edge in Neo4j with properties.
@@ -174,6 +494,13 @@ pub struct RegionConnection {
     pub is_locked: bool,
     /// Description of what's needed to unlock (if locked)
     pub lock_description: Option<String>,
+    /// Keys and challenge that open the lock; kept when unlocked so the
+    /// DM can lock it again
+    #[serde(default)]
+    pub lock: LockRequirements,
+    /// How well noise carries through to the other side
+    #[serde(default)]
+    pub sound: SoundCarry,
 }
 
 impl RegionConnection {
@@ -191,6 +518,8 @@ impl RegionConnection {
             bidirectional: true,
             is_locked: false,
             lock_description: None,
+            lock: LockRequirements::default(),
+            sound: SoundCarry::Open,
         })
     }
 
@@ -209,6 +538,22 @@ impl RegionConnection {
         self.lock_description = Some(description.into());
         self
     }
+
+    pub fn with_lock(mut self, lock: LockRequirements) -> Self {
+        self.lock = lock;
+        self
+    }
+
+    pub fn with_sound(mut self, sound: SoundCarry) -> Self {
+        self.sound = sound;
+        self
+    }
+
+    /// Open the lock, keeping what opens it
+    pub fn unlock(&mut self) {
+        self.is_locked = false;
+        self.lock_description = None;
+    }
 }
 
 /// An exit from a region to another location
@@ -372,6 +717,93 @@ mod tests {
         assert_eq!(connection.to_region, to_region);
     }
 
+    // ==========================================================================
+    // Hotspots
+    // ==========================================================================
+
+    fn square(x: f32, y: f32, size: f32) -> Vec<HotspotPoint> {
+        vec![
+            HotspotPoint::new(x, y),
+            HotspotPoint::new(x + size, y),
+            HotspotPoint::new(x + size, y + size),
+            HotspotPoint::new(x, y + size),
+        ]
+    }
+
+    #[test]
+    fn test_hotspot_new_rejects_degenerate_polygons() {
+        let target = HotspotTarget::Exit {
+            region_id: RegionId::new(),
+        };
+        assert!(RegionHotspot::new("Door", square(0.0, 0.0, 10.0)[..2].to_vec(), target).is_none());
+        assert!(RegionHotspot::new("  ", square(0.0, 0.0, 10.0), target).is_none());
+
+        let hotspot = RegionHotspot::new(
+            "Door",
+            vec![
+                HotspotPoint { x: -5.0, y: 0.0 },
+                HotspotPoint { x: 10.0, y: 150.0 },
+                HotspotPoint { x: 20.0, y: 0.0 },
+            ],
+            target,
+        )
+        .unwrap();
+        assert_eq!(hotspot.points[0].x, 0.0);
+        assert_eq!(hotspot.points[1].y, 100.0);
+    }
+
+    #[test]
+    fn test_hotspot_contains_concave_polygon() {
+        // An L shape: the notch at the top right is outside.
+        let l_shape = RegionHotspot::new(
+            "Counter",
+            vec![
+                HotspotPoint::new(0.0, 0.0),
+                HotspotPoint::new(10.0, 0.0),
+                HotspotPoint::new(10.0, 10.0),
+                HotspotPoint::new(20.0, 10.0),
+                HotspotPoint::new(20.0, 20.0),
+                HotspotPoint::new(0.0, 20.0),
+            ],
+            HotspotTarget::Item {
+                item_id: ItemId::new(),
+            },
+        )
+        .unwrap();
+
+        assert!(l_shape.contains(5.0, 5.0));
+        assert!(l_shape.contains(15.0, 15.0));
+        assert!(!l_shape.contains(15.0, 5.0));
+        assert!(!l_shape.contains(25.0, 15.0));
+    }
+
+    #[test]
+    fn test_region_hotspot_at_prefers_topmost() {
+        let shelf = RegionHotspot::new(
+            "Bookshelf",
+            square(10.0, 10.0, 40.0),
+            HotspotTarget::Interaction {
+                interaction_id: InteractionId::new(),
+            },
+        )
+        .unwrap();
+        let book = RegionHotspot::new(
+            "Odd book",
+            square(20.0, 20.0, 5.0),
+            HotspotTarget::Item {
+                item_id: ItemId::new(),
+            },
+        )
+        .unwrap();
+        let region = Region::new(LocationId::new(), "Study")
+            .with_hotspot(shelf)
+            .with_hotspot(book);
+
+        assert_eq!(region.hotspot_at(22.0, 22.0).unwrap().label, "Odd book");
+        assert_eq!(region.hotspot_at(40.0, 40.0).unwrap().label, "Bookshelf");
+        assert!(region.hotspot_at(80.0, 80.0).is_none());
+    }
+
     #[test]
     fn test_region_connection_builder_methods() {
         let from_region = RegionId::new();
@@ -390,4 +822,115 @@ mod tests {
             Some("Requires a key".to_string())
         );
     }
+
+    #[test]
+    fn remap_hotspots_repoints_copied_exits_and_items() {
+        let kept_region = RegionId::new();
+        let (old_region, new_region) = (RegionId::new(), RegionId::new());
+        let (old_item, new_item) = (ItemId::new(), ItemId::new());
+        let hotspot = |target| RegionHotspot {
+            label: "spot".to_string(),
+            points: Vec::new(),
+            target,
+        };
+        let region = Region::new(LocationId::new(), "Hall")
+            .with_hotspot(hotspot(HotspotTarget::Exit {
+                region_id: old_region,
+            }))
+            .with_hotspot(hotspot(HotspotTarget::Exit {
+                region_id: kept_region,
+            }))
+            .with_hotspot(hotspot(HotspotTarget::Item { item_id: old_item }));
+
+        let copy_location = LocationId::new();
+        let mut copy = region.duplicate_into(copy_location);
+        copy.remap_hotspots(
+            &HashMap::from([(old_region, new_region)]),
+            &HashMap::from([(old_item, new_item)]),
+        );
+
+        assert_ne!(copy.id, region.id);
+        assert_eq!(copy.location_id, copy_location);
+        assert_eq!(copy.name, "Hall");
+        let targets: Vec<_> = copy.hotspots.iter().map(|h| h.target).collect();
+        assert_eq!(
+            targets,
+            vec![
+                HotspotTarget::Exit {
+                    region_id: new_region
+                },
+                HotspotTarget::Exit {
+                    region_id: kept_region
+                },
+                HotspotTarget::Item { item_id: new_item },
+            ]
+        );
+
+        // The original is untouched
+        assert_eq!(
+            region.hotspots[0].target,
+            HotspotTarget::Exit {
+                region_id: old_region
+            }
+        );
+    }
+
+    #[test]
+    fn unlocking_keeps_what_opens_the_lock() {
+        let key = ItemId::new();
+        let mut connection = RegionConnection::new(RegionId::new(), RegionId::new())
+            .unwrap()
+            .locked("A heavy iron door")
+            .with_lock(LockRequirements {
+                key_item_ids: vec![key],
+                challenge_id: None,
+            });
+        assert!(connection.lock.opened_by_key(key));
+        assert!(!connection.lock.opened_by_key(ItemId::new()));
+
+        connection.unlock();
+
+        assert!(!connection.is_locked);
+        assert_eq!(connection.lock_description, None);
+        assert!(connection.lock.opened_by_key(key));
+    }
+
+    #[test]
+    fn exteriors_follow_the_sun_unless_lighting_is_fixed() {
+        let glade = Region::new(LocationId::new(), "Glade");
+        assert_eq!(
+            glade.light_level(LocationType::Exterior, TimeOfDay::Afternoon),
+            LightLevel::Bright
+        );
+        assert_eq!(
+            glade.light_level(LocationType::Exterior, TimeOfDay::Evening),
+            LightLevel::Dim
+        );
+        assert_eq!(
+            glade.light_level(LocationType::Exterior, TimeOfDay::Night),
+            LightLevel::Dark
+        );
+        assert_eq!(
+            glade.light_level(LocationType::Interior, TimeOfDay::Night),
+            LightLevel::Bright
+        );
+
+        let crypt = Region::new(LocationId::new(), "Crypt").with_lighting(LightLevel::Dark);
+        assert_eq!(
+            crypt.light_level(LocationType::Interior, TimeOfDay::Morning),
+            LightLevel::Dark
+        );
+        assert!(!LightLevel::Dark.reveals_hidden());
+    }
+
+    #[test]
+    fn doors_only_let_the_loudest_noises_through() {
+        assert_eq!(SoundCarry::Open.carries(Loudness::Loud), Some(false));
+        assert_eq!(SoundCarry::Muffled.carries(Loudness::Loud), None);
+        assert_eq!(SoundCarry::Muffled.carries(Loudness::Deafening), Some(true));
+        assert_eq!(SoundCarry::Blocked.carries(Loudness::Deafening), None);
+
+        let connection = RegionConnection::new(RegionId::new(), RegionId::new()).unwrap();
+        assert_eq!(connection.sound, SoundCarry::Open);
+    }
 }
@@ -0,0 +1,125 @@
+//! Saved Filter entity - a user's named tag filter preset
+//!
+//! A DM who keeps pulling up "every NPC tagged act-2 and villain-adjacent"
+//! saves that combination once and picks it from a list afterwards. Presets
+//! belong to one user in one world; other DMs in the same world don't see them.
+//!
+//! # Neo4j Relationships
+//! - `(World)-[:HAS_SAVED_FILTER]->(SavedFilter)` - Preset belongs to a world
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{SavedFilterId, WorldId};
+use crate::types::EntityType;
+use crate::value_objects::normalize_tags;
+
+/// Most filter presets a user may keep per world
+pub const MAX_SAVED_FILTERS_PER_USER: usize = 50;
+
+/// A named tag filter for one entity type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedFilter {
+    pub id: SavedFilterId,
+    pub world_id: WorldId,
+    /// The user who saved the preset
+    pub user_id: String,
+    /// Display name (e.g., "Act 2 villains")
+    pub name: String,
+    /// Which list the filter applies to
+    pub entity_type: EntityType,
+    /// Entities must carry every one of these (normalized) tags
+    pub tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SavedFilter {
+    pub fn new(
+        world_id: WorldId,
+        user_id: impl Into<String>,
+        name: impl Into<String>,
+        entity_type: EntityType,
+        tags: &[String],
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let mut filter = Self {
+            id: SavedFilterId::new(),
+            world_id,
+            user_id: user_id.into(),
+            name: String::new(),
+            entity_type,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        };
+        filter.update(name, entity_type, tags, now)?;
+        Ok(filter)
+    }
+
+    /// Replace the preset's name, entity type and tags.
+    pub fn update(
+        &mut self,
+        name: impl Into<String>,
+        entity_type: EntityType,
+        tags: &[String],
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let name = name.into().trim().to_string();
+        if name.is_empty() {
+            return Err(DomainError::validation("Filter name cannot be empty"));
+        }
+        if !entity_type.is_taggable() {
+            return Err(DomainError::validation(format!(
+                "{} entities can't be tagged",
+                entity_type
+            )));
+        }
+        let tags = normalize_tags(tags)?;
+        if tags.is_empty() {
+            return Err(DomainError::validation(
+                "A saved filter needs at least one tag",
+            ));
+        }
+
+        self.name = name;
+        self.entity_type = entity_type;
+        self.tags = tags;
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_filter_normalizes_tags() {
+        let filter = SavedFilter::new(
+            WorldId::new(),
+            "dm-user",
+            " Act 2 villains ",
+            EntityType::Character,
+            &["Villain Adjacent".to_string(), "act-2".to_string()],
+            Utc::now(),
+        )
+        .unwrap();
+        assert_eq!(filter.name, "Act 2 villains");
+        assert_eq!(filter.tags, vec!["act-2", "villain-adjacent"]);
+    }
+
+    #[test]
+    fn test_filter_rejects_untaggable_types_and_empty_tags() {
+        let now = Utc::now();
+        let tags = vec!["act-2".to_string()];
+        assert!(
+            SavedFilter::new(WorldId::new(), "u", "n", EntityType::GameTime, &tags, now).is_err()
+        );
+        assert!(
+            SavedFilter::new(WorldId::new(), "u", "n", EntityType::Character, &[], now).is_err()
+        );
+    }
+}
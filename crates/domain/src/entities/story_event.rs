@@ -19,7 +19,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use wrldbldr_domain::{
-    ChallengeId, CharacterId, LocationId, NarrativeEventId, SceneId, StoryEventId, WorldId,
+    ChallengeId, CharacterId, ItemId, LocationId, NarrativeEventId, RegionId, SceneId,
+    StoryEventId, WorldId,
 };
 
 /// A story event - an immutable record of something that happened
@@ -173,6 +174,9 @@ pub enum StoryEventType {
         note: String,
         importance: MarkerImportance,
         marker_type: DmMarkerType,
+        /// Where the marker is pinned on a map, if anywhere
+        #[serde(default)]
+        pin: Option<MarkerPin>,
     },
 
     /// Narrative event was triggered
@@ -316,6 +320,55 @@ pub enum DmMarkerType {
     Custom,
 }
 
+/// Position of a DM marker on a location map or region backdrop
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkerPin {
+    pub location_id: LocationId,
+    /// Region whose backdrop holds the pin; `None` pins to the location map
+    pub region_id: Option<RegionId>,
+    /// Horizontal position as a percentage of the image width (0-100)
+    pub x: f32,
+    /// Vertical position as a percentage of the image height (0-100)
+    pub y: f32,
+    /// Entity opened when the pin is clicked
+    pub link: Option<MarkerLink>,
+}
+
+impl MarkerPin {
+    /// Pin at a position, clamped to the image
+    pub fn new(location_id: LocationId, region_id: Option<RegionId>, x: f32, y: f32) -> Self {
+        Self {
+            location_id,
+            region_id,
+            x: x.clamp(0.0, 100.0),
+            y: y.clamp(0.0, 100.0),
+            link: None,
+        }
+    }
+
+    pub fn with_link(mut self, link: MarkerLink) -> Self {
+        self.link = Some(link);
+        self
+    }
+
+    /// Whether the pin belongs on the given map (`region_id` None = location map)
+    pub fn is_on(&self, location_id: LocationId, region_id: Option<RegionId>) -> bool {
+        self.location_id == location_id && self.region_id == region_id
+    }
+}
+
+/// Entity a map pin links to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "camelCase")]
+pub enum MarkerLink {
+    Character(CharacterId),
+    Location(LocationId),
+    Region(RegionId),
+    Item(ItemId),
+    NarrativeEvent(NarrativeEventId),
+}
+
 impl StoryEvent {
     /// Create a new story event
     ///
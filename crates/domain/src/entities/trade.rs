@@ -0,0 +1,167 @@
+//! Trade entity - item and currency exchanges between player characters
+//!
+//! One PC offers items and/or currency to another, optionally asking for
+//! something back. Offers wait for the other player to accept or decline;
+//! only an accepted offer moves anything, and it moves both sides at once.
+//!
+//! Currency is the game system's currency sheet field (see
+//! [`RuleSystemVariant::currency_field`]).
+//!
+//! [`RuleSystemVariant::currency_field`]: crate::RuleSystemVariant::currency_field
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::ids::{ItemId, PlayerCharacterId, TradeId, WorldId};
+
+/// How long an offer stays open before it lapses
+pub const TRADE_OFFER_TTL_MINUTES: i64 = 10;
+
+/// One side of a trade: what a PC hands over
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeSide {
+    pub item_ids: Vec<ItemId>,
+    pub currency: u32,
+}
+
+impl TradeSide {
+    pub fn new(item_ids: Vec<ItemId>, currency: u32) -> Self {
+        Self { item_ids, currency }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.item_ids.is_empty() && self.currency == 0
+    }
+}
+
+/// A pending trade offer from one PC to another
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeOffer {
+    pub id: TradeId,
+    pub world_id: WorldId,
+    /// PC making the offer
+    pub from_pc: PlayerCharacterId,
+    /// PC who may accept or decline
+    pub to_pc: PlayerCharacterId,
+    /// What `from_pc` gives
+    pub offered: TradeSide,
+    /// What `from_pc` asks for in return (may be empty for a gift)
+    pub requested: TradeSide,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TradeOffer {
+    /// Create an offer, rejecting self-trades, empty trades, and items listed twice
+    pub fn new(
+        world_id: WorldId,
+        from_pc: PlayerCharacterId,
+        to_pc: PlayerCharacterId,
+        offered: TradeSide,
+        requested: TradeSide,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        if from_pc == to_pc {
+            return Err(DomainError::validation(
+                "A character cannot trade with itself",
+            ));
+        }
+        if offered.is_empty() && requested.is_empty() {
+            return Err(DomainError::validation("A trade must include something"));
+        }
+        let mut seen = std::collections::HashSet::new();
+        if !offered
+            .item_ids
+            .iter()
+            .chain(&requested.item_ids)
+            .all(|id| seen.insert(*id))
+        {
+            return Err(DomainError::validation("An item can only be traded once"));
+        }
+
+        Ok(Self {
+            id: TradeId::new(),
+            world_id,
+            from_pc,
+            to_pc,
+            offered,
+            requested,
+            created_at: now,
+        })
+    }
+
+    /// Whether a PC is either party to the trade
+    pub fn involves(&self, pc_id: PlayerCharacterId) -> bool {
+        self.from_pc == pc_id || self.to_pc == pc_id
+    }
+
+    /// When the offer lapses
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.created_at + Duration::minutes(TRADE_OFFER_TTL_MINUTES)
+    }
+
+    /// Whether the offer has been open too long to accept
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now > self.expires_at()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offer(offered: TradeSide, requested: TradeSide) -> Result<TradeOffer, DomainError> {
+        TradeOffer::new(
+            WorldId::new(),
+            PlayerCharacterId::new(),
+            PlayerCharacterId::new(),
+            offered,
+            requested,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_new_offer_rejects_self_trade() {
+        let pc = PlayerCharacterId::new();
+        let result = TradeOffer::new(
+            WorldId::new(),
+            pc,
+            pc,
+            TradeSide::new(vec![], 5),
+            TradeSide::default(),
+            Utc::now(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_new_offer_rejects_empty_and_duplicate_items() {
+        assert!(offer(TradeSide::default(), TradeSide::default()).is_err());
+
+        let item = ItemId::new();
+        assert!(offer(TradeSide::new(vec![item], 0), TradeSide::new(vec![item], 0)).is_err());
+        assert!(offer(TradeSide::new(vec![item, item], 0), TradeSide::default()).is_err());
+    }
+
+    #[test]
+    fn test_gift_and_purchase_are_valid() {
+        // Gift: nothing asked in return
+        assert!(offer(TradeSide::new(vec![ItemId::new()], 0), TradeSide::default()).is_ok());
+        // Purchase: coins for an item
+        assert!(offer(
+            TradeSide::new(vec![], 30),
+            TradeSide::new(vec![ItemId::new()], 0)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_offer_expires_after_ttl() {
+        let trade = offer(TradeSide::new(vec![], 1), TradeSide::default()).unwrap();
+        assert!(!trade.is_expired(trade.created_at + Duration::minutes(1)));
+        assert!(trade.is_expired(trade.created_at + Duration::minutes(TRADE_OFFER_TTL_MINUTES + 1)));
+    }
+}
@@ -10,8 +10,8 @@ use crate::WorkflowConfigId;
 
 // Re-export shared workflow types from types module
 pub use crate::types::{
-    InputDefault, InputType, PromptMapping, PromptMappingType, WorkflowAnalysis, WorkflowInput,
-    WorkflowSlot,
+    InputDefault, InputType, PromptMapping, PromptMappingType, ReferenceImageMapping,
+    WorkflowAnalysis, WorkflowInput, WorkflowSlot,
 };
 
 /// Workflow configuration for a specific asset generation slot
@@ -27,6 +27,9 @@ pub struct WorkflowConfiguration {
     pub workflow_json: serde_json::Value,
     /// Which text inputs should receive the generation prompt
     pub prompt_mappings: Vec<PromptMapping>,
+    /// Which inputs receive a reference image (e.g. the character's portrait)
+    #[serde(default)]
+    pub reference_image_mappings: Vec<ReferenceImageMapping>,
     /// Default values for workflow inputs
     pub input_defaults: Vec<InputDefault>,
     /// Input paths that should always use defaults (never shown in UI)
@@ -49,6 +52,7 @@ impl WorkflowConfiguration {
             name: name.into(),
             workflow_json,
             prompt_mappings: Vec::new(),
+            reference_image_mappings: Vec::new(),
             input_defaults: Vec::new(),
             locked_inputs: Vec::new(),
             created_at: now,
@@ -62,6 +66,12 @@ impl WorkflowConfiguration {
         self
     }
 
+    /// Add a reference image mapping
+    pub fn with_reference_image_mapping(mut self, mapping: ReferenceImageMapping) -> Self {
+        self.reference_image_mappings.push(mapping);
+        self
+    }
+
     /// Add a default value for an input
     pub fn with_default(mut self, default: InputDefault) -> Self {
         self.input_defaults.push(default);
@@ -102,6 +112,11 @@ impl WorkflowConfiguration {
             .find(|m| m.mapping_type == PromptMappingType::Negative)
     }
 
+    /// Whether generations can be conditioned on a reference image
+    pub fn supports_reference_image(&self) -> bool {
+        !self.reference_image_mappings.is_empty()
+    }
+
     /// Update the workflow JSON
     pub fn update_workflow(&mut self, workflow_json: serde_json::Value, now: DateTime<Utc>) {
         self.workflow_json = workflow_json;
@@ -114,6 +129,16 @@ impl WorkflowConfiguration {
         self.updated_at = now;
     }
 
+    /// Update reference image mappings
+    pub fn set_reference_image_mappings(
+        &mut self,
+        mappings: Vec<ReferenceImageMapping>,
+        now: DateTime<Utc>,
+    ) {
+        self.reference_image_mappings = mappings;
+        self.updated_at = now;
+    }
+
     /// Update input defaults
     pub fn set_input_defaults(&mut self, defaults: Vec<InputDefault>, now: DateTime<Utc>) {
         self.input_defaults = defaults;
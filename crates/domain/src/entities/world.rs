@@ -3,8 +3,16 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::value_objects::RuleSystemConfig;
-use crate::{GameTime, GameTimeConfig, TimeAdvanceReason, TimeCostConfig, TimeMode, WorldId};
+use crate::error::DomainError;
+use crate::value_objects::{
+    ContentSafetyConfig, CustomFieldDefinition, Flashback, ParallelScene, RuleSystemConfig,
+    Spotlight, TutorialScript, WorldFeatures, WorldScript, WorldTheme, WorldTypography,
+    MAX_PARALLEL_SCENES,
+};
+use crate::{
+    GameTime, GameTimeConfig, ParallelSceneId, PlayerCharacterId, RegionId, RegionStateId,
+    StagingId, TimeAdvanceReason, TimeCostConfig, TimeMode, WorldId,
+};
 
 // Re-export MonomythStage from types module
 pub use crate::types::MonomythStage;
@@ -22,6 +30,45 @@ pub struct World {
     /// Configuration for how game time behaves
     #[serde(default)]
     pub time_config: GameTimeConfig,
+    /// Lines/veils and content rating enforced on LLM output
+    #[serde(default)]
+    pub content_safety: ContentSafetyConfig,
+    /// Text direction, language, and font used to render world text
+    #[serde(default)]
+    pub typography: WorldTypography,
+    /// Accent color and backdrop frame chosen by the DM
+    #[serde(default)]
+    pub theme: WorldTheme,
+    /// Experimental subsystems the DM has switched on
+    #[serde(default)]
+    pub features: WorldFeatures,
+    /// DM automation scripts run on game hooks
+    #[serde(default)]
+    pub scripts: Vec<WorldScript>,
+    /// DM-defined extra fields on characters, locations and items
+    #[serde(default)]
+    pub custom_fields: Vec<CustomFieldDefinition>,
+    /// Guided steps for a tutorial world (`None` for regular worlds)
+    #[serde(default)]
+    pub tutorial: Option<TutorialScript>,
+    /// Path to the overview map image locations are pinned to
+    #[serde(default)]
+    pub map_asset: Option<String>,
+    /// The world this one was branched from, for alternate timelines
+    #[serde(default)]
+    pub branched_from: Option<WorldId>,
+    /// The flashback being played, if any; `game_time` shows its date
+    #[serde(default)]
+    pub flashback: Option<Flashback>,
+    /// Scenes of a split party, played side by side
+    #[serde(default)]
+    pub parallel_scenes: Vec<ParallelScene>,
+    /// The parallel scene the DM is running; `None` is the main table
+    #[serde(default)]
+    pub focused_scene_id: Option<ParallelSceneId>,
+    /// Who has acted lately, for spotlight rotation
+    #[serde(default)]
+    pub spotlight: Spotlight,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -52,6 +99,19 @@ impl World {
             rule_system: RuleSystemConfig::default(),
             game_time: GameTime::new(now),
             time_config: GameTimeConfig::default(),
+            content_safety: ContentSafetyConfig::default(),
+            typography: WorldTypography::default(),
+            theme: WorldTheme::default(),
+            features: WorldFeatures::default(),
+            scripts: Vec::new(),
+            custom_fields: Vec::new(),
+            tutorial: None,
+            map_asset: None,
+            branched_from: None,
+            flashback: None,
+            parallel_scenes: Vec::new(),
+            focused_scene_id: None,
+            spotlight: Spotlight::default(),
             created_at: now,
             updated_at: now,
         }
@@ -67,6 +127,46 @@ impl World {
         self
     }
 
+    pub fn with_content_safety(mut self, content_safety: ContentSafetyConfig) -> Self {
+        self.content_safety = content_safety;
+        self
+    }
+
+    pub fn with_typography(mut self, typography: WorldTypography) -> Self {
+        self.typography = typography;
+        self
+    }
+
+    pub fn with_theme(mut self, theme: WorldTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn with_features(mut self, features: WorldFeatures) -> Self {
+        self.features = features;
+        self
+    }
+
+    pub fn with_scripts(mut self, scripts: Vec<WorldScript>) -> Self {
+        self.scripts = scripts;
+        self
+    }
+
+    pub fn with_custom_fields(mut self, custom_fields: Vec<CustomFieldDefinition>) -> Self {
+        self.custom_fields = custom_fields;
+        self
+    }
+
+    pub fn with_tutorial(mut self, tutorial: TutorialScript) -> Self {
+        self.tutorial = Some(tutorial);
+        self
+    }
+
+    pub fn with_map(mut self, asset_path: impl Into<String>) -> Self {
+        self.map_asset = Some(asset_path.into());
+        self
+    }
+
     pub fn update_name(&mut self, name: impl Into<String>, now: DateTime<Utc>) {
         self.name = name.into();
         self.updated_at = now;
@@ -93,6 +193,59 @@ impl World {
         self.updated_at = now;
     }
 
+    /// Replace the content safety settings (normalized).
+    pub fn set_content_safety(&mut self, content_safety: ContentSafetyConfig, now: DateTime<Utc>) {
+        self.content_safety = content_safety.normalized();
+        self.updated_at = now;
+    }
+
+    /// Replace the typography settings (normalized).
+    pub fn set_typography(&mut self, typography: WorldTypography, now: DateTime<Utc>) {
+        self.typography = typography.normalized();
+        self.updated_at = now;
+    }
+
+    /// Replace the theme settings (normalized).
+    pub fn set_theme(&mut self, theme: WorldTheme, now: DateTime<Utc>) {
+        self.theme = theme.normalized();
+        self.updated_at = now;
+    }
+
+    /// Replace the rule system configuration.
+    pub fn set_rule_system(&mut self, rule_system: RuleSystemConfig, now: DateTime<Utc>) {
+        self.rule_system = rule_system;
+        self.updated_at = now;
+    }
+
+    /// Replace the experimental feature switches.
+    pub fn set_features(&mut self, features: WorldFeatures, now: DateTime<Utc>) {
+        self.features = features;
+        self.updated_at = now;
+    }
+
+    /// Replace the automation scripts (each normalized).
+    pub fn set_scripts(&mut self, scripts: Vec<WorldScript>, now: DateTime<Utc>) {
+        self.scripts = scripts.into_iter().map(WorldScript::normalized).collect();
+        self.updated_at = now;
+    }
+
+    /// Replace the overview map image (a blank path clears it).
+    pub fn set_map_asset(&mut self, asset_path: Option<String>, now: DateTime<Utc>) {
+        self.map_asset = asset_path
+            .map(|path| path.trim().to_string())
+            .filter(|path| !path.is_empty());
+        self.updated_at = now;
+    }
+
+    /// Replace the custom field schema (each field normalized).
+    pub fn set_custom_fields(&mut self, fields: Vec<CustomFieldDefinition>, now: DateTime<Utc>) {
+        self.custom_fields = fields
+            .into_iter()
+            .map(CustomFieldDefinition::normalized)
+            .collect();
+        self.updated_at = now;
+    }
+
     /// Get the time cost for a given action type.
     pub fn time_cost_for_action(&self, action: &str) -> u32 {
         self.time_config.time_costs.cost_for_action(action)
@@ -130,6 +283,172 @@ impl World {
     pub fn advance_hours(&mut self, hours: u32, now: DateTime<Utc>) -> TimeAdvanceResult {
         self.advance_time(hours * 60, TimeAdvanceReason::DmManual { hours }, now)
     }
+
+    // =========================================================================
+    // Flashbacks
+    // =========================================================================
+
+    /// Start a flashback: keep the live clock, region staging and region
+    /// state, and wind the clock back to the flashback's date.
+    pub fn start_flashback(
+        &mut self,
+        title: &str,
+        region_id: RegionId,
+        flashback_time: GameTime,
+        live_staging_id: Option<StagingId>,
+        live_region_state_id: Option<RegionStateId>,
+        now: DateTime<Utc>,
+    ) -> Result<&Flashback, DomainError> {
+        if self.flashback.is_some() {
+            return Err(DomainError::validation("A flashback is already running"));
+        }
+        let title = Flashback::validate_title(title)?;
+        if flashback_time.current() >= self.game_time.current() {
+            return Err(DomainError::validation(
+                "A flashback must be set before the current game time",
+            ));
+        }
+        let live_time = std::mem::replace(&mut self.game_time, flashback_time);
+        self.updated_at = now;
+        Ok(self.flashback.insert(Flashback {
+            title,
+            region_id,
+            live_time,
+            live_staging_id,
+            live_region_state_id,
+            started_at: now,
+        }))
+    }
+
+    /// End the flashback and put the live clock back. Returns the flashback
+    /// so the caller can restore its region.
+    pub fn end_flashback(&mut self, now: DateTime<Utc>) -> Result<Flashback, DomainError> {
+        let flashback = self
+            .flashback
+            .take()
+            .ok_or_else(|| DomainError::validation("No flashback is running"))?;
+        self.game_time = flashback.live_time.clone();
+        self.updated_at = now;
+        Ok(flashback)
+    }
+
+    // =========================================================================
+    // Parallel Scenes
+    // =========================================================================
+
+    /// The parallel scene a PC is playing in, if the party is split
+    pub fn parallel_scene_of(&self, pc_id: PlayerCharacterId) -> Option<&ParallelScene> {
+        self.parallel_scenes
+            .iter()
+            .find(|scene| scene.pc_ids.contains(&pc_id))
+    }
+
+    /// Split a group of PCs off into their own scene. PCs already in another
+    /// scene move to the new one.
+    pub fn create_parallel_scene(
+        &mut self,
+        name: &str,
+        pc_ids: Vec<PlayerCharacterId>,
+        scene_notes: &str,
+        tone: &str,
+        now: DateTime<Utc>,
+    ) -> Result<&ParallelScene, DomainError> {
+        if self.parallel_scenes.len() >= MAX_PARALLEL_SCENES {
+            return Err(DomainError::validation(format!(
+                "A world can run at most {} parallel scenes",
+                MAX_PARALLEL_SCENES
+            )));
+        }
+        let mut scene = ParallelScene::new(name, pc_ids, now)?;
+        scene.scene_notes = scene_notes.trim().to_string();
+        scene.tone = tone.trim().to_string();
+        self.take_pcs(&scene.pc_ids, scene.id);
+        self.updated_at = now;
+        self.parallel_scenes.push(scene);
+        Ok(self.parallel_scenes.last().expect("scene just added"))
+    }
+
+    /// Rename a scene, regroup its PCs and set its direction
+    pub fn update_parallel_scene(
+        &mut self,
+        scene_id: ParallelSceneId,
+        name: &str,
+        pc_ids: Vec<PlayerCharacterId>,
+        scene_notes: &str,
+        tone: &str,
+        now: DateTime<Utc>,
+    ) -> Result<&ParallelScene, DomainError> {
+        let index = self.parallel_scene_index(scene_id)?;
+        let name = ParallelScene::validate_name(name)?;
+        let scene = &mut self.parallel_scenes[index];
+        scene.set_pcs(pc_ids)?;
+        scene.name = name;
+        scene.scene_notes = scene_notes.trim().to_string();
+        scene.tone = tone.trim().to_string();
+        let pc_ids = scene.pc_ids.clone();
+        self.take_pcs(&pc_ids, scene_id);
+        self.updated_at = now;
+        // Closing emptied scenes may have moved this one
+        let index = self.parallel_scene_index(scene_id)?;
+        Ok(&self.parallel_scenes[index])
+    }
+
+    /// Close a scene; its PCs return to the main table
+    pub fn remove_parallel_scene(
+        &mut self,
+        scene_id: ParallelSceneId,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let index = self.parallel_scene_index(scene_id)?;
+        self.parallel_scenes.remove(index);
+        if self.focused_scene_id == Some(scene_id) {
+            self.focused_scene_id = None;
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Switch the DM's focus to a scene, or back to the main table
+    pub fn focus_parallel_scene(
+        &mut self,
+        scene_id: Option<ParallelSceneId>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if let Some(scene_id) = scene_id {
+            self.parallel_scene_index(scene_id)?;
+        }
+        self.focused_scene_id = scene_id;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Bring the party back together at the main table
+    pub fn merge_parallel_scenes(&mut self, now: DateTime<Utc>) {
+        self.parallel_scenes.clear();
+        self.focused_scene_id = None;
+        self.updated_at = now;
+    }
+
+    fn parallel_scene_index(&self, scene_id: ParallelSceneId) -> Result<usize, DomainError> {
+        self.parallel_scenes
+            .iter()
+            .position(|scene| scene.id == scene_id)
+            .ok_or_else(|| DomainError::not_found("ParallelScene", scene_id.to_string()))
+    }
+
+    /// Take PCs out of every scene but `keep`, closing scenes left empty
+    fn take_pcs(&mut self, pc_ids: &[PlayerCharacterId], keep: ParallelSceneId) {
+        for scene in self.parallel_scenes.iter_mut().filter(|s| s.id != keep) {
+            scene.pc_ids.retain(|pc_id| !pc_ids.contains(pc_id));
+        }
+        self.parallel_scenes
+            .retain(|scene| !scene.pc_ids.is_empty());
+        if let Some(focused) = self.focused_scene_id {
+            if !self.parallel_scenes.iter().any(|scene| scene.id == focused) {
+                self.focused_scene_id = None;
+            }
+        }
+    }
 }
 
 // MonomythStage is now defined in and re-exported from wrldbldr-domain-types
@@ -0,0 +1,268 @@
+//! World simulation - what the world got up to while game time was skipped
+//!
+//! When the DM skips a day or more, the engine works out what would have
+//! happened off-screen: factions push their clocks on, NPCs go where their
+//! schedules take them, rumors pass between neighbours and prices drift. The
+//! result is a changelog the DM reviews. Nothing in the world changes until
+//! the DM accepts it; before that the DM can drop entries or change their
+//! amounts, but not add new ones.
+//!
+//! Simulations awaiting the DM are not persisted.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::MAX_PRICE_ADJUSTMENT_PERCENT;
+use crate::error::DomainError;
+use crate::ids::{
+    CharacterId, LoreId, MarketModifierId, ProgressClockId, RegionId, WorldId, WorldSimulationId,
+};
+
+/// Shortest time skip that is simulated, in game minutes
+pub const MIN_SIMULATED_MINUTES: u32 = 24 * 60;
+
+/// Longest stretch simulated in one pass, in game days
+pub const MAX_SIMULATED_DAYS: u32 = 365;
+
+/// Most changes one simulation proposes
+pub const MAX_SIMULATION_CHANGES: usize = 60;
+
+/// One thing that happened off-screen
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum SimulationChange {
+    /// A faction or threat clock moved on
+    #[serde(rename_all = "camelCase")]
+    ClockAdvanced {
+        clock_id: ProgressClockId,
+        clock_name: String,
+        segments: u8,
+    },
+    /// An NPC went where their schedule puts them
+    #[serde(rename_all = "camelCase")]
+    NpcMoved {
+        npc_id: CharacterId,
+        npc_name: String,
+        region_id: RegionId,
+        region_name: String,
+    },
+    /// An NPC heard a piece of lore from someone who shares a haunt with them
+    #[serde(rename_all = "camelCase")]
+    RumorSpread {
+        lore_id: LoreId,
+        lore_title: String,
+        npc_id: CharacterId,
+        npc_name: String,
+        told_by_id: CharacterId,
+        told_by_name: String,
+    },
+    /// A market modifier's price adjustment drifted
+    #[serde(rename_all = "camelCase")]
+    PriceDrift {
+        modifier_id: MarketModifierId,
+        reason: String,
+        from_percent: i32,
+        to_percent: i32,
+    },
+}
+
+impl SimulationChange {
+    /// One line for the changelog
+    pub fn describe(&self) -> String {
+        match self {
+            Self::ClockAdvanced {
+                clock_name,
+                segments,
+                ..
+            } => {
+                let plural = if *segments == 1 { "" } else { "s" };
+                format!("{} advanced {} segment{}", clock_name, segments, plural)
+            }
+            Self::NpcMoved {
+                npc_name,
+                region_name,
+                ..
+            } => format!("{} went to {}", npc_name, region_name),
+            Self::RumorSpread {
+                lore_title,
+                npc_name,
+                told_by_name,
+                ..
+            } => format!(
+                "{} heard about \"{}\" from {}",
+                npc_name, lore_title, told_by_name
+            ),
+            Self::PriceDrift {
+                reason,
+                from_percent,
+                to_percent,
+                ..
+            } => format!(
+                "Prices ({}) went from {:+}% to {:+}%",
+                reason, from_percent, to_percent
+            ),
+        }
+    }
+
+    /// Whether both changes are about the same thing, whatever the amounts
+    fn same_target(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::ClockAdvanced { clock_id: a, .. }, Self::ClockAdvanced { clock_id: b, .. }) => {
+                a == b
+            }
+            (Self::NpcMoved { npc_id: a, .. }, Self::NpcMoved { npc_id: b, .. }) => a == b,
+            (
+                Self::RumorSpread {
+                    lore_id: a,
+                    npc_id: a_npc,
+                    ..
+                },
+                Self::RumorSpread {
+                    lore_id: b,
+                    npc_id: b_npc,
+                    ..
+                },
+            ) => a == b && a_npc == b_npc,
+            (Self::PriceDrift { modifier_id: a, .. }, Self::PriceDrift { modifier_id: b, .. }) => {
+                a == b
+            }
+            _ => false,
+        }
+    }
+
+    fn validate(&self) -> Result<(), DomainError> {
+        match self {
+            Self::ClockAdvanced { segments: 0, .. } => Err(DomainError::validation(
+                "A clock must advance at least one segment",
+            )),
+            Self::PriceDrift { to_percent, .. }
+                if to_percent.abs() > MAX_PRICE_ADJUSTMENT_PERCENT =>
+            {
+                Err(DomainError::validation(format!(
+                    "Price adjustment must be between -{0}% and {0}%",
+                    MAX_PRICE_ADJUSTMENT_PERCENT
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A time skip's proposed changes, waiting on the DM
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldSimulation {
+    pub id: WorldSimulationId,
+    pub world_id: WorldId,
+    /// Game time the skip started from
+    pub from_game_time: DateTime<Utc>,
+    /// Game time the skip ended at
+    pub to_game_time: DateTime<Utc>,
+    pub changes: Vec<SimulationChange>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WorldSimulation {
+    pub fn new(
+        world_id: WorldId,
+        from_game_time: DateTime<Utc>,
+        to_game_time: DateTime<Utc>,
+        mut changes: Vec<SimulationChange>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        changes.truncate(MAX_SIMULATION_CHANGES);
+        Self {
+            id: WorldSimulationId::new(),
+            world_id,
+            from_game_time,
+            to_game_time,
+            changes,
+            created_at: now,
+        }
+    }
+
+    /// Whole game days the skip covered
+    pub fn days(&self) -> i64 {
+        (self.to_game_time - self.from_game_time).num_days()
+    }
+
+    /// Replace the changes with the DM's edits. Every edited change must be
+    /// about something the simulation proposed.
+    pub fn edit(&mut self, changes: Vec<SimulationChange>) -> Result<(), DomainError> {
+        for change in &changes {
+            if !self.changes.iter().any(|c| c.same_target(change)) {
+                return Err(DomainError::validation(format!(
+                    "\"{}\" was not part of this simulation",
+                    change.describe()
+                )));
+            }
+            change.validate()?;
+        }
+        self.changes = changes;
+        Ok(())
+    }
+
+    /// The changes, one line each
+    pub fn changelog(&self) -> String {
+        self.changes
+            .iter()
+            .map(|c| format!("- {}", c.describe()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Game days a time advance of `minutes` covers, or `None` when it is too
+/// short to simulate
+pub fn simulated_days(minutes: u32) -> Option<u32> {
+    (minutes >= MIN_SIMULATED_MINUTES).then(|| (minutes / (24 * 60)).min(MAX_SIMULATED_DAYS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clock_change(clock_id: ProgressClockId, segments: u8) -> SimulationChange {
+        SimulationChange::ClockAdvanced {
+            clock_id,
+            clock_name: "The Bluecoats close in".to_string(),
+            segments,
+        }
+    }
+
+    #[test]
+    fn only_skips_of_a_day_or_more_are_simulated() {
+        assert_eq!(simulated_days(23 * 60), None);
+        assert_eq!(simulated_days(24 * 60), Some(1));
+        assert_eq!(simulated_days(10 * 24 * 60 + 90), Some(10));
+        assert_eq!(simulated_days(u32::MAX), Some(MAX_SIMULATED_DAYS));
+    }
+
+    #[test]
+    fn edits_may_drop_or_adjust_changes_but_not_add_them() {
+        let clock_id = ProgressClockId::new();
+        let now = Utc::now();
+        let mut sim = WorldSimulation::new(
+            WorldId::new(),
+            now,
+            now + chrono::Duration::days(3),
+            vec![clock_change(clock_id, 2)],
+            now,
+        );
+        assert_eq!(sim.days(), 3);
+
+        sim.edit(vec![clock_change(clock_id, 1)]).unwrap();
+        assert_eq!(
+            sim.changelog(),
+            "- The Bluecoats close in advanced 1 segment"
+        );
+
+        assert!(sim.edit(vec![clock_change(clock_id, 0)]).is_err());
+        assert!(sim
+            .edit(vec![clock_change(ProgressClockId::new(), 1)])
+            .is_err());
+
+        sim.edit(Vec::new()).unwrap();
+        assert!(sim.changes.is_empty());
+    }
+}
@@ -382,6 +382,7 @@ impl CharacterSheetProvider for BladesSystem {
                     section_ids: vec!["identity".to_string()],
                     order: 1,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "actions".to_string(),
@@ -390,6 +391,7 @@ impl CharacterSheetProvider for BladesSystem {
                     section_ids: vec!["attributes_actions".to_string()],
                     order: 2,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "abilities".to_string(),
@@ -398,6 +400,7 @@ impl CharacterSheetProvider for BladesSystem {
                     section_ids: vec!["special_abilities".to_string()],
                     order: 3,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "load".to_string(),
@@ -406,6 +409,7 @@ impl CharacterSheetProvider for BladesSystem {
                     section_ids: vec!["load_armor".to_string()],
                     order: 4,
                     required: false,
+                    allocation: None,
                 },
             ],
         }
@@ -374,6 +374,7 @@ impl CharacterSheetProvider for Coc7eSystem {
                     section_ids: vec!["identity".to_string()],
                     order: 1,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "characteristics".to_string(),
@@ -383,6 +384,7 @@ impl CharacterSheetProvider for Coc7eSystem {
                     section_ids: vec!["characteristics".to_string()],
                     order: 2,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "derived".to_string(),
@@ -391,6 +393,7 @@ impl CharacterSheetProvider for Coc7eSystem {
                     section_ids: vec!["derived_attributes".to_string()],
                     order: 3,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "skills".to_string(),
@@ -400,6 +403,7 @@ impl CharacterSheetProvider for Coc7eSystem {
                     section_ids: vec!["skills".to_string(), "combat".to_string()],
                     order: 4,
                     required: true,
+                    allocation: None,
                 },
             ],
         }
@@ -5,8 +5,9 @@
 use super::traits::{
     CalculationEngine, CasterType, CharacterSheetProvider, CharacterSheetSchema,
     CreationStep, DerivedField, DerivationType, FieldDefinition, FieldLayout,
-    FieldValidation, GameSystem, ProficiencyLevel, ProficiencyOption, ResourceColor,
-    SchemaFieldType, SchemaSection, SchemaSelectOption, SectionType, SpellcastingSystem,
+    FieldValidation, GameSystem, PointBuy, PointCost, ProficiencyLevel, ProficiencyOption,
+    ResourceColor, SchemaFieldType, SchemaSection, SchemaSelectOption, SectionType,
+    SpellcastingSystem, StatAllocation, StatArray,
 };
 use crate::entities::{StatBlock, StatModifier};
 use std::collections::HashMap;
@@ -404,6 +405,7 @@ impl CharacterSheetProvider for Dnd5eSystem {
                     section_ids: vec!["identity".to_string()],
                     order: 1,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "abilities".to_string(),
@@ -413,6 +415,7 @@ impl CharacterSheetProvider for Dnd5eSystem {
                     section_ids: vec!["ability_scores".to_string()],
                     order: 2,
                     required: true,
+                    allocation: Some(self.ability_allocation()),
                 },
                 CreationStep {
                     id: "proficiencies".to_string(),
@@ -422,6 +425,7 @@ impl CharacterSheetProvider for Dnd5eSystem {
                     section_ids: vec!["skills".to_string(), "saving_throws".to_string()],
                     order: 3,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "equipment".to_string(),
@@ -430,6 +434,7 @@ impl CharacterSheetProvider for Dnd5eSystem {
                     section_ids: vec!["combat".to_string()],
                     order: 4,
                     required: false,
+                    allocation: None,
                 },
             ],
         }
@@ -967,6 +972,38 @@ impl Dnd5eSystem {
         }
     }
 
+    /// 27-point buy (8-15) and the standard array, per the Player's Handbook
+    fn ability_allocation(&self) -> StatAllocation {
+        let costs = [
+            (8, 0),
+            (9, 1),
+            (10, 2),
+            (11, 3),
+            (12, 4),
+            (13, 5),
+            (14, 7),
+            (15, 9),
+        ];
+        StatAllocation {
+            field_ids: ["STR", "DEX", "CON", "INT", "WIS", "CHA"]
+                .iter()
+                .map(|id| id.to_string())
+                .collect(),
+            point_buy: Some(PointBuy {
+                budget: 27,
+                costs: costs
+                    .iter()
+                    .map(|&(value, cost)| PointCost { value, cost })
+                    .collect(),
+            }),
+            arrays: vec![StatArray {
+                label: "Standard Array".to_string(),
+                values: vec![15, 14, 13, 12, 10, 8],
+            }],
+            allow_manual: true,
+        }
+    }
+
     fn ability_scores_section(&self) -> SchemaSection {
         let abilities = [
             ("STR", "Strength", "Physical power, athletics, melee attacks"),
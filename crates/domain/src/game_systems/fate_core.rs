@@ -381,6 +381,7 @@ impl CharacterSheetProvider for FateCoreSystem {
                     section_ids: vec!["identity".to_string()],
                     order: 1,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "aspects".to_string(),
@@ -390,6 +391,7 @@ impl CharacterSheetProvider for FateCoreSystem {
                     section_ids: vec!["aspects".to_string()],
                     order: 2,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "skills".to_string(),
@@ -398,6 +400,7 @@ impl CharacterSheetProvider for FateCoreSystem {
                     section_ids: vec!["skills".to_string()],
                     order: 3,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "stunts".to_string(),
@@ -407,6 +410,7 @@ impl CharacterSheetProvider for FateCoreSystem {
                     section_ids: vec!["stunts".to_string(), "resources".to_string()],
                     order: 4,
                     required: false,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "stress".to_string(),
@@ -415,6 +419,7 @@ impl CharacterSheetProvider for FateCoreSystem {
                     section_ids: vec!["stress".to_string(), "consequences".to_string()],
                     order: 5,
                     required: false,
+                    allocation: None,
                 },
             ],
         }
@@ -60,6 +60,44 @@ pub use traits::{
     RestType, SpellcastingSystem,
 };
 
+use crate::character_sheet::CharacterSheetSchema;
+use crate::value_objects::RuleSystemVariant;
+
+/// The character sheet system a rule system variant uses.
+///
+/// Variants without a sheet of their own borrow the closest one.
+pub fn system_id_for_variant(variant: &RuleSystemVariant) -> &'static str {
+    match variant {
+        RuleSystemVariant::Dnd5e => "dnd5e",
+        RuleSystemVariant::Pathfinder2e => "pf2e",
+        RuleSystemVariant::CallOfCthulhu7e => "coc7e",
+        RuleSystemVariant::FateCore => "fate_core",
+        RuleSystemVariant::BladesInTheDark => "blades",
+        RuleSystemVariant::PoweredByApocalypse => "pbta",
+        RuleSystemVariant::KidsOnBikes => "pbta", // Use generic PbtA
+        RuleSystemVariant::RuneQuest => "coc7e",  // Similar to CoC (percentile)
+        RuleSystemVariant::GenericD20 => "dnd5e", // Closest to D&D
+        RuleSystemVariant::GenericD100 => "coc7e", // Percentile system
+        RuleSystemVariant::Custom(_) => "dnd5e",  // Default to D&D for custom systems
+        RuleSystemVariant::Unknown => "dnd5e",    // Default to D&D for unknown
+    }
+}
+
+/// The character sheet schema for a game system, if it has one.
+pub fn character_sheet_schema(system_id: &str) -> Option<CharacterSheetSchema> {
+    match system_id {
+        "dnd5e" => Some(Dnd5eSystem::new().character_sheet_schema()),
+        "pf2e" => Some(Pf2eSystem::new().character_sheet_schema()),
+        "coc7e" => Some(Coc7eSystem::new().character_sheet_schema()),
+        "fate_core" => Some(FateCoreSystem::new().character_sheet_schema()),
+        "blades" => Some(BladesSystem::new().character_sheet_schema()),
+        "pbta" => Some(PbtaSystem::generic().character_sheet_schema()),
+        "pbta_aw" => Some(PbtaSystem::apocalypse_world().character_sheet_schema()),
+        "pbta_dw" => Some(PbtaSystem::dungeon_world().character_sheet_schema()),
+        "pbta_motw" => Some(PbtaSystem::monster_of_the_week().character_sheet_schema()),
+        _ => None,
+    }
+}
 
 use std::sync::Arc;
 
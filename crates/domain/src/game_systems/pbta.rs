@@ -573,6 +573,7 @@ impl PbtaSystem {
                 section_ids: vec!["identity".to_string()],
                 order: 1,
                 required: true,
+                allocation: None,
             },
             CreationStep {
                 id: "stats".to_string(),
@@ -581,6 +582,7 @@ impl PbtaSystem {
                 section_ids: vec!["stats".to_string()],
                 order: 2,
                 required: true,
+                allocation: None,
             },
             CreationStep {
                 id: "moves".to_string(),
@@ -589,6 +591,7 @@ impl PbtaSystem {
                 section_ids: vec!["moves".to_string()],
                 order: 3,
                 required: true,
+                allocation: None,
             },
             CreationStep {
                 id: "bonds".to_string(),
@@ -597,6 +600,7 @@ impl PbtaSystem {
                 section_ids: vec!["bonds".to_string()],
                 order: 4,
                 required: false,
+                allocation: None,
             },
         ]
     }
@@ -485,6 +485,7 @@ impl CharacterSheetProvider for Pf2eSystem {
                     section_ids: vec!["identity".to_string()],
                     order: 1,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "ability_boosts".to_string(),
@@ -494,6 +495,7 @@ impl CharacterSheetProvider for Pf2eSystem {
                     section_ids: vec!["ability_scores".to_string()],
                     order: 2,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "skills".to_string(),
@@ -503,6 +505,7 @@ impl CharacterSheetProvider for Pf2eSystem {
                     section_ids: vec!["skills".to_string()],
                     order: 3,
                     required: true,
+                    allocation: None,
                 },
                 CreationStep {
                     id: "equipment".to_string(),
@@ -511,6 +514,7 @@ impl CharacterSheetProvider for Pf2eSystem {
                     section_ids: vec!["combat".to_string()],
                     order: 4,
                     required: false,
+                    allocation: None,
                 },
             ],
         }
@@ -10,8 +10,9 @@ use std::collections::HashMap;
 // Re-export character sheet schema types for game system implementations
 pub use crate::character_sheet::{
     CharacterSheetSchema, ConditionLevel, CreationStep, DerivedField, DerivationType,
-    FieldDefinition, FieldLayout, FieldValidation, LadderLabel, ProficiencyOption, ResourceColor,
-    SchemaFieldType, SchemaSection, SchemaSelectOption, SectionType,
+    FieldDefinition, FieldLayout, FieldValidation, LadderLabel, PointBuy, PointCost,
+    ProficiencyOption, ResourceColor, SchemaFieldType, SchemaSection, SchemaSelectOption,
+    SectionType, StatAllocation, StatArray,
 };
 
 /// Core trait all game systems must implement.
@@ -108,6 +108,69 @@ define_id!(StagingId);
 define_id!(LoreId);
 define_id!(LoreChunkId);
 
+// Progress clock IDs
+define_id!(ProgressClockId);
+
+// Saved filter IDs
+define_id!(SavedFilterId);
+
+// Entity template IDs
+define_id!(EntityTemplateId);
+
+// Content library IDs
+define_id!(LibraryEntryId);
+
+// Content draft IDs
+define_id!(ContentDraftId);
+
+// Crowd IDs
+define_id!(CrowdId);
+
+// NPC draft IDs
+define_id!(NpcDraftId);
+
+// Name generator IDs
+define_id!(NameGeneratorId);
+
+// Market modifier IDs
+define_id!(MarketModifierId);
+
+// Property IDs
+define_id!(PropertyId);
+
+// Companion IDs
+define_id!(CompanionId);
+
+// Injury IDs
+define_id!(InjuryId);
+
+// PC secret IDs
+define_id!(PcSecretId);
+
+// Letter IDs
+define_id!(LetterId);
+
+// Broadsheet IDs
+define_id!(BroadsheetId);
+
+// World simulation IDs
+define_id!(WorldSimulationId);
+
+// Faction IDs
+define_id!(FactionId);
+
+// Dialogue tree IDs
+define_id!(DialogueTreeId);
+
+// Trade IDs
+define_id!(TradeId);
+
+// Journey IDs
+define_id!(JourneyId);
+
+// Parallel Scene IDs
+define_id!(ParallelSceneId);
+
 // Visual State IDs
 define_id!(LocationStateId);
 define_id!(RegionStateId);
@@ -18,31 +18,66 @@ pub mod value_objects;
 
 // Re-export all entities (explicit list in entities/mod.rs)
 pub use entities::{
-    default_skills_for_variant, AbilityUses, AcquiredFeat, AcquisitionMethod, Act, ActantialRole,
+    copy_name, crowd_size_label, default_skills_for_variant, diff_words, AbilityUses, AcquiredFeat, AcquisitionMethod, Act, ActantialRole,
     ActantialView, ActiveFeature, AssetType, BackgroundFeature, BatchStatus, CastingTime,
     CastingTimeUnit, ChainStatus, ChainedEvent, Challenge, ChallengeEventOutcome,
     ChallengeLocationAvailability, ChallengeOutcomes, ChallengePrerequisite,
     ChallengeRegionAvailability, ChallengeType, ChallengeUnlock, Character, CharacterFeats,
     CharacterFeatures, CharacterIdentity, CharacterSheetData, CharacterSheetTemplate, CharacterSpells,
-    CharacterWant, ClassFeature, ClassLevel, CombatEventType, CombatOutcome, Difficulty,
-    DifficultyDescriptor, DmMarkerType, DurationUnit, EntityType, EventChain, EventChainMembership,
+    CharacterWant, ClassFeature, ClassLevel, ClockKind, ClockTick, CombatEventType, CombatOutcome,
+    ContentDraft, Crowd, DiffOp, DiffSegment, Difficulty,
+    DifficultyDescriptor, DmMarkerType, DraftField, DraftRevision, DraftStatus, DurationUnit, EntityTemplate, EntityType, EventChain, EventChainMembership,
     EventEffect, EventOutcome, Feat, FeatBenefit, FeaturedNpc, FeatureUses, FieldType, FieldValue,
-    FlagScope, FrequencyLevel, GalleryAsset, GameFlag, GenerationBatch, GenerationMetadata,
-    GenerationRequest, Goal, GridMap, InfoType, InputDefault, InputType, InteractionCondition,
+    FlagScope, FrequencyLevel, GalleryAsset, GalleryFilter, GameFlag, GenerationBatch, GenerationMetadata,
+    GenerationRequest, Goal, GridMap, HotspotPoint, HotspotTarget, InfoType, InputDefault, InputType, InteractionAvailability, InteractionCondition,
     InteractionRequirement, InteractionTarget, InteractionTargetType, InteractionTemplate,
-    InteractionType, InventoryItem, InvolvedCharacter, Item, ItemListType, ItemSource, KnownSpell,
-    Location, LocationConnection, LocationState, LocationStateSummary, LocationType, Lore,
+    InteractionType, InteractionUsage, InventoryItem, InvolvedCharacter, Item, Encounter, EncounterEntry, EncounterTable, Journey,
+    MAX_ENCOUNTER_DESCRIPTION_LEN, MAX_ENCOUNTER_ENTRIES, MAX_ENCOUNTER_NAME_LEN,
+    MIN_ENCOUNTER_INTERVAL_MINUTES, ItemListType, ItemTemplate, ItemSource, KnownSpell, LibraryContent, LibraryEntry,
+    HierarchyTravel, Location, LocationConnection, LocationScale, LocationState,
+    LocationStateSummary, LocationTree, LocationType, Lore, WorldMapPosition,
+    DEFAULT_CROSSING_MINUTES, MAX_LOCATION_DEPTH,
     LoreCategory, LoreChunk, LoreDiscoverySource, LoreKnowledge, MapBounds, MarkerImportance,
+    MarkerLink, MarkerPin,
+    market_conditions, MarketConditions, MarketModifier, Scarcity, MAX_MARKET_TEXT_LEN,
+    MAX_PRICE_ADJUSTMENT_PERCENT,
+    Property, PropertySite, PropertyStaff, StaffRole, MAX_DAILY_UPKEEP, MAX_PROPERTY_NOTES_LEN,
+    MAX_PROPERTY_STAFF, MAX_PROPERTY_TEXT_LEN,
+    Companion, DEFAULT_LOYALTY, MAX_COMPANIONS_PER_PC, MAX_DAILY_WAGE, MAX_LOYALTY,
+    Injury, InjuryCapability, InjurySeverity, MAX_ACTIVE_INJURIES_PER_PC, MAX_INJURY_NAME_LEN,
+    MAX_INJURY_NOTES_LEN, MAX_RECOVERY_HOURS,
+    PcSecret, SecretReaction, MAX_SECRETS_PER_PC, MAX_SECRET_CONTENT_LEN, MAX_SECRET_REACTIONS,
+    MAX_SECRET_TITLE_LEN,
+    delivery_minutes, Correspondent, Letter, MAX_LETTER_BODY_LEN, MAX_LETTER_SUBJECT_LEN,
+    MIN_DELIVERY_MINUTES, UNROUTED_DELIVERY_MINUTES,
+    game_week_ended, game_week_number, game_week_start, Broadsheet, BroadsheetArticle,
+    GAME_WEEK_DAYS, MAX_BROADSHEET_ARTICLES, MAX_BROADSHEET_ARTICLE_LEN,
+    MAX_BROADSHEET_HEADLINE_LEN,
+    simulated_days, SimulationChange, WorldSimulation, MAX_SIMULATED_DAYS, MAX_SIMULATION_CHANGES,
+    MIN_SIMULATED_MINUTES,
+    Faction, FactionMember, ReputationStanding, MAX_FACTION_GOALS, MAX_FACTION_GOAL_LEN,
+    MAX_FACTION_NAME_LEN, MAX_FACTION_REPUTATION, MAX_FACTION_ROLE_LEN, MIN_FACTION_REPUTATION,
+    DialogueBranch, DialogueNode, DialogueTree, MAX_DIALOGUE_CHOICES, MAX_DIALOGUE_CHOICE_LEN,
+    MAX_DIALOGUE_LINE_LEN, MAX_DIALOGUE_NODES, MAX_DIALOGUE_NODE_ID_LEN,
+    MAX_DIALOGUE_TREE_NAME_LEN,
+    SuggestionDecision, SuggestionPreferences, SuggestionVerdict,
+    MAX_SUGGESTION_DECISIONS_CONSIDERED,
+    ChallengeHistoryStats, ChallengeResolution, MAX_CHALLENGE_HISTORY,
     MaterialComponent, MonomythStage, NarrativeEvent, NarrativeTrigger, NarrativeTriggerType,
-    NpcObservation, ObservationSummary, ObservationType, Outcome, OutcomeCondition, OutcomeTrigger,
-    OutcomeType, PlayerCharacter, Prerequisite, PromptMapping, PromptMappingType, RacialTrait,
-    RechargeType, Region, RegionConnection, RegionExit, RegionState, RegionStateSummary,
-    ResolvedStateInfo, ResolvedVisualState, Scene, SceneCharacter, SceneCharacterRole,
+    MentionSpan, MentionTarget, EntityMention, find_mentions, mention_excerpt, mentions_in,
+    normalize_aliases, MAX_ALIASES_PER_ENTITY, MAX_ALIAS_LEN, MENTION_TARGET_TYPES,
+    name_generator_for, NameGenerator, NameStyle, MAX_GENERATED_NAMES, MAX_NAME_GENERATOR_NAME_LEN,
+    MAX_NAME_PARTS,
+    NpcDraft, NpcDraftDetails, NpcDraftSource, NpcDraftStatus, NpcObservation, LocationObservation, NpcTemplate, ObservationSummary, ObservationType, Outcome, OutcomeCondition, OutcomeTrigger,
+    OutcomeType, PcDeath, PlayerCharacter, SheetVersion, MAX_DEATH_CAUSE_LEN, MAX_EPITAPH_LEN, Prerequisite, ProgressClock, PromptMapping, PromptMappingType,
+    RacialTrait, LightLevel, LockRequirements, Loudness,
+    RechargeType, ReferenceImageMapping, Region, RegionConnection, RegionExit, RegionHotspot, RegionState, RegionStateSummary, RegionTemplate,
+    ResolvedStateInfo, ResolvedVisualState, RevisionSource, SavedFilter, Scene, SceneCharacter, SceneCharacterRole,
     SceneCondition, SectionLayout, SelectOption, SheetField, SheetSection, SheetTemplateId, Skill,
-    SkillCategory, Spell, SpellComponents, SpellDuration, SpellLevel, SpellRange, SpellSlotPool,
+    SkillCategory, SoundCarry, MAX_NOISE_DESCRIPTION_LEN, Spell, SpellComponents, SpellDuration, SpellLevel, SpellRange, SpellSlotPool,
     StagedNpc, Staging, StagingSource, StatBlock, StoryEvent, StoryEventInfoImportance,
-    StoryEventType, TimeAdvanceResult, TimeContext, TriggerCondition, TriggerContext,
-    TriggerEvaluation, TriggerLogic, TriggerType, UsesFormula, VisualStateSource, Want,
+    StoryEventType, TemplateBody, TemplateNpc, TimeAdvanceResult, TimeContext, TradeOffer, TradeSide, MAX_CROWD_COUNT, MAX_CROWD_NAME_LEN, MAX_DRAFT_REVISIONS, MAX_DRAFT_TEXT_LEN, MAX_GALLERY_BULK, MAX_LIBRARY_ENTRIES_PER_USER, MAX_SAVED_FILTERS_PER_USER, MAX_TEMPLATES_PER_WORLD, MAX_TEMPLATE_ITEMS, MAX_TEMPLATE_NPCS, TEMPLATE_NAME_VARIABLE, TRADE_OFFER_TTL_MINUTES, MAX_NPC_DRAFT_CONCEPT_LEN, MAX_NPC_DRAFT_EXCERPT_LEN, MAX_NPC_DRAFT_NAME_LEN, TriggerCondition, TriggerContext,
+    TriggerEvaluation, TriggerLogic, TriggerType, unknown_names_in, UsesFormula, VisualStateSource, Want,
     WantTargetType, WantVisibility, WorkflowAnalysis, WorkflowConfiguration, WorkflowInput,
     WorkflowSlot, World,
 };
@@ -52,16 +87,18 @@ pub use events::DomainEvent;
 
 // Re-export game system traits and types
 pub use game_systems::{
-    dnd5e_skill_ability, CalculationEngine, CasterType, CharacterSheetProvider, Dnd5eSystem,
-    GameSystem, GameSystemRegistry, ProficiencyLevel, RestType, SpellcastingSystem,
+    character_sheet_schema, dnd5e_skill_ability, system_id_for_variant, CalculationEngine,
+    CasterType, CharacterSheetProvider, Dnd5eSystem, GameSystem, GameSystemRegistry,
+    ProficiencyLevel, RestType, SpellcastingSystem,
 };
 
 // Re-export character sheet schema types
 pub use character_sheet::{
     CharacterSheetResponse, CharacterSheetSchema, ConditionLevel, CreationStep, DerivedField,
     DerivationType, EntityRefType, FieldDefinition, FieldLayout, FieldUpdate,
-    FieldUpdateResponse, FieldValidation, LadderLabel, ProficiencyOption, ResourceColor,
-    SchemaFieldType, SchemaSection, SchemaSelectOption, SectionType, ValidationError,
+    FieldUpdateResponse, FieldValidation, LadderLabel, PointBuy, PointCost, ProficiencyOption,
+    ResourceColor, SchemaFieldType, SchemaSection, SchemaSelectOption, SectionType,
+    SheetConversion, StatAllocation, StatArray, ValidationError,
 };
 
 // Re-export game time types
@@ -71,19 +108,28 @@ pub use game_time::{
 
 // Re-export ID types
 pub use ids::{
-    ActId, ActionId, AssetId, BatchId, ChallengeId, CharacterId, ConnectionId, EventChainId,
-    EventId, GoalId, GridMapId, InteractionId, ItemId, LocationId, LocationStateId, LoreChunkId,
-    LoreId, NarrativeEventId, ParticipantId, PlayerCharacterId, QueueItemId, RegionId,
-    RegionStateId, RelationshipId, SceneId, SkillId, StagingId, StoryEventId, UserId, WantId,
-    WorkflowConfigId, WorkflowId, WorldId,
+    ActId, ActionId, AssetId, BatchId, BroadsheetId, ChallengeId, CharacterId, CompanionId, ConnectionId, ContentDraftId, CrowdId, EntityTemplateId, EventChainId,
+    FactionId,
+    DialogueTreeId,
+    EventId, GoalId, GridMapId, InjuryId, InteractionId, ItemId, JourneyId, LetterId, LibraryEntryId, LocationId, LocationStateId, LoreChunkId,
+    LoreId, MarketModifierId, NameGeneratorId, NarrativeEventId, NpcDraftId, ParallelSceneId, ParticipantId, PcSecretId, PlayerCharacterId, ProgressClockId, PropertyId, QueueItemId,
+    RegionId,
+    RegionStateId, RelationshipId, SavedFilterId, SceneId, SkillId, StagingId, StoryEventId,
+    TradeId, UserId,
+    WantId,
+    WorkflowConfigId, WorkflowId, WorldId, WorldSimulationId,
 };
 
 // Re-export value objects (explicit list in value_objects/mod.rs)
 pub use value_objects::{
+    age_progressions,
     count_tokens,
     exceeds_token_budget,
+    find_chronology_issues,
     get_prompt_default,
     key_to_env_var,
+    normalize_tag,
+    normalize_tags,
     // Dialogue marker parsing functions
     parse_dialogue,
     parse_dialogue_markers,
@@ -92,7 +138,10 @@ pub use value_objects::{
     prompt_template_keys,
     prompt_template_metadata,
     settings_metadata,
+    validate_custom_field_values,
+    validate_custom_fields,
     validate_markers,
+    validate_world_scripts,
     ActantialActor,
     ActantialActorEntry,
     ActantialContext,
@@ -106,6 +155,8 @@ pub use value_objects::{
     ActiveNarrativeEventContext,
     ActorType,
     AdHocOutcomes,
+    AgeProgression,
+    AliasReferenceContext,
     AppSettings,
     // Queue data value objects
     ApprovalDecisionType,
@@ -114,6 +165,7 @@ pub use value_objects::{
     ApprovalUrgency,
     ArchetypeChange,
     AssetGenerationData,
+    BackdropFrame,
     CampbellArchetype,
     ChallengeOutcomeData,
     ChallengeSignificance,
@@ -121,37 +173,78 @@ pub use value_objects::{
     ChallengeSuggestionOutcomes,
     ChangeAmount,
     CharacterContext,
+    ChronologyIssue,
+    CoDmEscalation,
+    CoDmOutcome,
+    CoDmReview,
+    CoDmSettings,
+    CoDmVerdict,
     ComfyUIConfig,
+    ContentRating,
+    ContentSafetyConfig,
     ContextBudgetConfig,
     ContextCategory,
     ConversationEntry,
     ConversationTurn,
-    // Dialogue marker parsing
+    // Custom fields
+    CustomFieldDefinition,
+    CustomFieldType,
+    CustomFieldValue,
+    CustomFieldValues,
     DialogueMarker,
+    DiceExpression,
+    DiceFormula,
+    DiceParseError,
     DiceRollInput,
+    DiceRollResult,
     DiceSystem,
     DirectorialNotes,
     DispositionLevel,
     DmActionData,
     DmActionType,
     DmApprovalDecision,
+    // Experimental feature switches
+    ExperimentalFeature,
     // Expression configuration
     ExpressionConfig,
+    Flashback,
     GamePromptRequest,
+    GenerationPreset,
+    InjuryRecoveryConfig,
+    LifeStage,
     LlmRequestData,
+    LlmProvider,
+    LlmProviderSettings,
     LlmRequestType,
+    LlmTask,
+    MergedLine,
+    ModelRouting,
+    ModelTier,
     MoodState,
     MotivationEntry,
     MotivationsContext,
     NarrativeEventSuggestion,
+    NpcAutonomyLevel,
+    NpcAutonomySettings,
     NpcDialogueContext,
     NpcDispositionState,
     PacingGuidance,
+    // Pacing timers
+    PacingCue,
+    PacingTimer,
+    PacingTimerKind,
     // Dialogue marker types
+    ParallelScene,
     ParsedDialogue,
     PendingApprovalItem,
     PlayerActionContext,
     PlayerActionData,
+    // Plugins
+    PluginCapability,
+    PluginEffectSpec,
+    PluginManifest,
+    PluginOutput,
+    PluginToolSpec,
     PromptTemplateCategory,
     PromptTemplateMetadata,
     ProposedTool,
@@ -165,26 +258,68 @@ pub use value_objects::{
     RelationshipEvent,
     RelationshipLevel,
     RelationshipType,
+    ResurrectionConfig,
+    RollBreakdown,
     RollResult,
+    RolledDie,
+    RolledTerm,
     RuleBasedSuggestion,
     RuleSystemConfig,
     RuleSystemType,
     RuleSystemVariant,
+    SafetySignalLevel,
+    SafetySignalResponse,
     SceneContext,
+    // World scripts
+    ScriptHook,
+    ScriptOutput,
     SecretMotivationContext,
     SecretMotivationEntry,
+    ServiceConnections,
     SettingsFieldMetadata,
+    SkillAliases,
     SocialRelationEntry,
     SocialStanceContext,
     SocialViewSummary,
     Speaker,
+    // Spotlight rotation
+    Spotlight,
     StagingContext,
     StatDefinition,
     SuccessComparison,
     SuggestionContext,
+    TextDirection,
     TokenCountMethod,
     TokenCounter,
     ToneGuidance,
+    // Tutorial scripts
+    TutorialAction,
+    TutorialGoal,
+    TutorialScript,
+    TutorialStep,
     WantContext,
     WantTarget,
+    WorldFeatures,
+    WorldScript,
+    WorldTheme,
+    WorldTypography,
+    AGE_PROGRESSION_MIN_SKIP_DAYS,
+    CUSTOM_FIELD_ENTITY_TYPES,
+    FLASHBACK_TAG,
+    DEFAULT_QUIET_AFTER,
+    DEFAULT_SCRIPT_BUDGET,
+    MAX_CUSTOM_FIELDS,
+    MAX_CUSTOM_FIELD_OPTIONS,
+    MAX_CUSTOM_FIELD_TEXT_LEN,
+    MAX_FLASHBACK_TITLE_LEN,
+    MAX_PARALLEL_SCENES,
+    MAX_PARALLEL_SCENE_NAME_LEN,
+    MAX_SCRIPT_BUDGET,
+    MAX_SCRIPT_SOURCE_LEN,
+    MAX_TAGS_PER_ENTITY,
+    MAX_TAG_LEN,
+    MAX_WORLD_SCRIPTS,
+    REDACTED_SECRET,
+    SPOTLIGHT_HISTORY,
+    TUTORIAL_FLAG_PREFIX,
 };
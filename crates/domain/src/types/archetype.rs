@@ -33,6 +33,20 @@ pub enum CampbellArchetype {
 }
 
 impl CampbellArchetype {
+    /// Get all archetypes for UI dropdowns (excludes Unknown)
+    pub fn all() -> &'static [CampbellArchetype] {
+        &[
+            Self::Hero,
+            Self::Mentor,
+            Self::ThresholdGuardian,
+            Self::Herald,
+            Self::Shapeshifter,
+            Self::Shadow,
+            Self::Trickster,
+            Self::Ally,
+        ]
+    }
+
     /// Returns a description of this archetype's narrative function
     pub fn description(&self) -> &'static str {
         match self {
@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// Use `has_assets()` to check if an entity type can have gallery assets.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum EntityType {
     // === Asset-bearing entities ===
@@ -65,6 +66,12 @@ pub enum EntityType {
     /// Actantial view (narrative role)
     ActantialView,
 
+    // === World content ===
+    /// Lore entry
+    Lore,
+    /// Progress clock
+    ProgressClock,
+
     // === Time ===
     /// Game time state
     GameTime,
@@ -97,6 +104,8 @@ impl std::fmt::Display for EntityType {
             Self::Goal => write!(f, "Goal"),
             Self::Want => write!(f, "Want"),
             Self::ActantialView => write!(f, "ActantialView"),
+            Self::Lore => write!(f, "Lore"),
+            Self::ProgressClock => write!(f, "ProgressClock"),
             Self::GameTime => write!(f, "GameTime"),
             Self::Unknown => write!(f, "Unknown"),
         }
@@ -112,6 +121,33 @@ impl EntityType {
         matches!(self, Self::Character | Self::Location | Self::Item)
     }
 
+    /// Returns true if DMs can put freeform tags on this entity type
+    ///
+    /// Covers the entities that have their own list views; links such as
+    /// relationships and observations, and world-level state, can't be tagged.
+    pub fn is_taggable(&self) -> bool {
+        matches!(
+            self,
+            Self::Character
+                | Self::PlayerCharacter
+                | Self::Location
+                | Self::Region
+                | Self::Item
+                | Self::Scene
+                | Self::Act
+                | Self::Skill
+                | Self::Challenge
+                | Self::Interaction
+                | Self::NarrativeEvent
+                | Self::EventChain
+                | Self::StoryEvent
+                | Self::Goal
+                | Self::Want
+                | Self::Lore
+                | Self::ProgressClock
+        )
+    }
+
     /// Get the lowercase string representation for file paths
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -134,6 +170,8 @@ impl EntityType {
             Self::Goal => "goal",
             Self::Want => "want",
             Self::ActantialView => "actantial_view",
+            Self::Lore => "lore",
+            Self::ProgressClock => "progress_clock",
             Self::GameTime => "game_time",
             Self::Unknown => "unknown",
         }
@@ -164,6 +202,8 @@ impl std::str::FromStr for EntityType {
             "goal" => Ok(Self::Goal),
             "want" => Ok(Self::Want),
             "actantial_view" | "actantialview" => Ok(Self::ActantialView),
+            "lore" => Ok(Self::Lore),
+            "progress_clock" | "progressclock" => Ok(Self::ProgressClock),
             "game_time" | "gametime" => Ok(Self::GameTime),
             "unknown" => Ok(Self::Unknown),
             _ => Err(format!("Unknown entity type: {}", s)),
@@ -173,6 +213,7 @@ impl std::str::FromStr for EntityType {
 
 /// Types of entity changes for broadcast notifications
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ChangeType {
     /// Entity was created
@@ -215,6 +256,8 @@ pub enum AssetType {
     EmotionSheet,
     /// Backdrop for clickable map region (1280x720)
     RegionBackdrop,
+    /// Top-down battle map or layout of a region (1024x1024)
+    Map,
     /// Unknown asset type (for forward compatibility)
     #[serde(other)]
     Unknown,
@@ -230,6 +273,7 @@ impl std::fmt::Display for AssetType {
             Self::ItemIcon => write!(f, "ItemIcon"),
             Self::EmotionSheet => write!(f, "EmotionSheet"),
             Self::RegionBackdrop => write!(f, "RegionBackdrop"),
+            Self::Map => write!(f, "Map"),
             Self::Unknown => write!(f, "Unknown"),
         }
     }
@@ -247,6 +291,7 @@ impl std::str::FromStr for AssetType {
             "itemicon" | "item_icon" => Self::ItemIcon,
             "emotionsheet" | "emotion_sheet" => Self::EmotionSheet,
             "regionbackdrop" | "region_backdrop" => Self::RegionBackdrop,
+            "map" => Self::Map,
             _ => Self::Unknown,
         })
     }
@@ -263,6 +308,7 @@ impl AssetType {
             Self::ItemIcon => "item_icon",
             Self::EmotionSheet => "emotion_sheet",
             Self::RegionBackdrop => "region_backdrop",
+            Self::Map => "map",
             Self::Unknown => "unknown",
         }
     }
@@ -277,6 +323,7 @@ impl AssetType {
             Self::ItemIcon => (64, 64),
             Self::EmotionSheet => (768, 768),
             Self::RegionBackdrop => (1280, 720),
+            Self::Map => (1024, 1024),
             Self::Unknown => (256, 256),
         }
     }
@@ -12,6 +12,7 @@ use std::fmt;
 ///
 /// This is SEPARATE from RelationshipLevel (social distance).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum DispositionLevel {
     /// Actively wants to harm/hinder the PC
@@ -134,6 +135,7 @@ impl std::str::FromStr for DispositionLevel {
 ///
 /// This is SEPARATE from DispositionLevel (emotional stance).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum RelationshipLevel {
     /// Close bond, trusted ally
@@ -45,6 +45,7 @@ pub use rule_system::{
     DifficultyLadder,
     EffectLevel,
     EffectTickConfig,
+    InjuryRecoveryConfig,
     LadderEntry,
     NarrativeDiceConfig,
     NarrativeDiceType,
@@ -53,9 +54,11 @@ pub use rule_system::{
     NarrativeThresholds,
     Position,
     PositionEffectConfig,
+    ResurrectionConfig,
     RuleSystemConfig,
     RuleSystemType,
     RuleSystemVariant,
+    SkillAliases,
     StatDefinition,
     SuccessComparison,
 };
@@ -65,7 +68,9 @@ mod workflow;
 pub use workflow::{
     // Pure analysis functions
     analyze_workflow,
+    apply_reference_image,
     auto_detect_prompt_mappings,
+    auto_detect_reference_image_mappings,
     find_nodes_by_type,
     validate_workflow,
     // Types
@@ -73,6 +78,7 @@ pub use workflow::{
     InputType,
     PromptMapping,
     PromptMappingType,
+    ReferenceImageMapping,
     WorkflowAnalysis,
     WorkflowInput,
     WorkflowSlot,
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 
 /// The type of rule system (determines dice mechanics and success calculation)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[derive(Default)]
 pub enum RuleSystemType {
@@ -25,6 +26,7 @@ pub enum RuleSystemType {
 
 /// Known presets for rule systems
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[derive(Default)]
 pub enum RuleSystemVariant {
@@ -82,6 +84,24 @@ impl RuleSystemVariant {
         }
     }
 
+    /// Sheet field holding a character's money, if the system tracks it
+    ///
+    /// Narrative systems and Call of Cthulhu (which uses Credit Rating) don't.
+    pub fn currency_field(&self) -> Option<&'static str> {
+        match self {
+            Self::BladesInTheDark => Some("COIN"),
+            Self::CallOfCthulhu7e | Self::FateCore | Self::PoweredByApocalypse => None,
+            Self::Dnd5e
+            | Self::Pathfinder2e
+            | Self::GenericD20
+            | Self::KidsOnBikes
+            | Self::RuneQuest
+            | Self::GenericD100
+            | Self::Custom(_)
+            | Self::Unknown => Some("GP"),
+        }
+    }
+
     /// Get all variants for a given system type
     pub fn variants_for_type(system_type: RuleSystemType) -> Vec<Self> {
         match system_type {
@@ -102,6 +122,7 @@ impl RuleSystemVariant {
 
 /// Configuration for a game's rule system
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub struct RuleSystemConfig {
     /// Display name for this configuration
@@ -124,6 +145,17 @@ pub struct RuleSystemConfig {
     /// Only used when system_type is Narrative
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub narrative_config: Option<NarrativeResolutionConfig>,
+    /// How injuries heal. When unset, the variant's defaults apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub injury_config: Option<InjuryRecoveryConfig>,
+    /// Whether and how dead PCs can be brought back. When unset, the
+    /// variant's defaults apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resurrection_config: Option<ResurrectionConfig>,
+    /// Skill names that mean the same thing in other rule systems. When
+    /// unset, the standard table applies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skill_aliases: Option<SkillAliases>,
 }
 
 impl Default for RuleSystemConfig {
@@ -171,6 +203,9 @@ impl RuleSystemConfig {
                 .to_string(),
             description: "Roll d20, add modifiers. Meet or beat the DC to succeed.".to_string(),
             narrative_config: None,
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -194,6 +229,9 @@ impl RuleSystemConfig {
             description: "Roll d20 + modifier. Crit success on DC+10, crit fail on DC-10."
                 .to_string(),
             narrative_config: None,
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -216,6 +254,9 @@ impl RuleSystemConfig {
             skill_check_formula: "1d20 + modifier vs DC".to_string(),
             description: "Roll d20, add modifiers. Meet or beat the DC to succeed.".to_string(),
             narrative_config: None,
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -242,6 +283,9 @@ impl RuleSystemConfig {
             description: "Roll d100. Regular success ≤ skill, Hard ≤ half, Extreme ≤ fifth."
                 .to_string(),
             narrative_config: None,
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -265,6 +309,9 @@ impl RuleSystemConfig {
             skill_check_formula: "Roll d100 ≤ skill value".to_string(),
             description: "Roll d100 under skill. Critical on 1/20th, special on 1/5th.".to_string(),
             narrative_config: None,
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -287,6 +334,9 @@ impl RuleSystemConfig {
             skill_check_formula: "Roll d100 ≤ skill value".to_string(),
             description: "Roll d100 and compare to skill value. Lower is better.".to_string(),
             narrative_config: None,
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -314,6 +364,9 @@ impl RuleSystemConfig {
                 style: NarrativeResolutionStyle::Custom,
                 ..Default::default()
             }),
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -336,6 +389,9 @@ impl RuleSystemConfig {
             skill_check_formula: "4dF + approach vs difficulty ladder".to_string(),
             description: "Roll 4 Fate dice (+/-/blank) + approach. Compare to ladder.".to_string(),
             narrative_config: Some(NarrativeResolutionConfig::fate_core()),
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -358,6 +414,9 @@ impl RuleSystemConfig {
             description: "Roll 2d6 + stat. 10+ success, 7-9 success with cost, 6- trouble."
                 .to_string(),
             narrative_config: Some(NarrativeResolutionConfig::pbta()),
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -396,6 +455,9 @@ impl RuleSystemConfig {
                 "Roll d6 pool equal to action rating. Position sets risk, Effect sets impact."
                     .to_string(),
             narrative_config: Some(NarrativeResolutionConfig::blades()),
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -411,6 +473,9 @@ impl RuleSystemConfig {
             skill_check_formula: "Custom resolution".to_string(),
             description: "A custom rule system. Define your own stats and mechanics.".to_string(),
             narrative_config: Some(NarrativeResolutionConfig::default()),
+            injury_config: None,
+            resurrection_config: None,
+            skill_aliases: None,
         }
     }
 
@@ -420,10 +485,32 @@ impl RuleSystemConfig {
             .clone()
             .unwrap_or_else(NarrativeResolutionConfig::default)
     }
+
+    /// Get the injury recovery config, or the variant's defaults if not set
+    pub fn injury_config_or_default(&self) -> InjuryRecoveryConfig {
+        self.injury_config
+            .clone()
+            .unwrap_or_else(|| InjuryRecoveryConfig::for_variant(&self.variant))
+    }
+
+    /// Get the resurrection config, or the variant's defaults if not set
+    pub fn resurrection_config_or_default(&self) -> ResurrectionConfig {
+        self.resurrection_config
+            .clone()
+            .unwrap_or_else(|| ResurrectionConfig::for_variant(&self.variant))
+    }
+
+    /// Get the skill alias table, or the standard one if not set
+    pub fn skill_aliases_or_default(&self) -> SkillAliases {
+        self.skill_aliases
+            .clone()
+            .unwrap_or_else(SkillAliases::standard)
+    }
 }
 
 /// How success is determined
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum SuccessComparison {
     /// Roll must be >= target (D20 systems)
@@ -439,6 +526,7 @@ pub enum SuccessComparison {
 
 /// Definition of a character stat
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub struct StatDefinition {
     pub name: String,
@@ -468,6 +556,7 @@ impl StatDefinition {
 
 /// The dice system used for resolution
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum DiceSystem {
     /// Classic d20 system (D&D, Pathfinder)
@@ -482,6 +571,311 @@ pub enum DiceSystem {
     Custom(String),
 }
 
+// =============================================================================
+// Injury Recovery
+// =============================================================================
+
+/// How injuries heal under a rule system.
+///
+/// Recovery is counted in game hours. Every game hour that passes counts
+/// once (unless the system heals only during rest), and every hour spent
+/// resting counts `rest_multiplier` times in all.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub struct InjuryRecoveryConfig {
+    /// Game hours a minor injury takes to heal
+    pub minor_hours: u32,
+    /// Game hours a serious injury takes to heal
+    pub serious_hours: u32,
+    /// Game hours a critical injury takes to heal
+    pub critical_hours: u32,
+    /// How many recovery hours each hour of rest is worth
+    pub rest_multiplier: u32,
+    /// Whether injuries heal only while the character rests
+    #[serde(default)]
+    pub heals_only_while_resting: bool,
+    /// Share of the remaining recovery time a successful treatment removes,
+    /// in percent
+    pub treatment_percent: u8,
+}
+
+impl Default for InjuryRecoveryConfig {
+    fn default() -> Self {
+        Self::for_variant(&RuleSystemVariant::GenericD20)
+    }
+}
+
+impl InjuryRecoveryConfig {
+    /// Defaults for a preset
+    ///
+    /// D20 systems heal in days to weeks, D100 systems more slowly, and
+    /// narrative systems clear harm over scenes and sessions. Blades in the
+    /// Dark only heals harm during downtime.
+    pub fn for_variant(variant: &RuleSystemVariant) -> Self {
+        match variant {
+            RuleSystemVariant::CallOfCthulhu7e
+            | RuleSystemVariant::RuneQuest
+            | RuleSystemVariant::GenericD100 => Self {
+                minor_hours: 72,
+                serious_hours: 336,
+                critical_hours: 1440,
+                rest_multiplier: 2,
+                heals_only_while_resting: false,
+                treatment_percent: 50,
+            },
+            RuleSystemVariant::KidsOnBikes
+            | RuleSystemVariant::FateCore
+            | RuleSystemVariant::PoweredByApocalypse => Self {
+                minor_hours: 8,
+                serious_hours: 72,
+                critical_hours: 336,
+                rest_multiplier: 2,
+                heals_only_while_resting: false,
+                treatment_percent: 50,
+            },
+            RuleSystemVariant::BladesInTheDark => Self {
+                minor_hours: 8,
+                serious_hours: 24,
+                critical_hours: 72,
+                rest_multiplier: 1,
+                heals_only_while_resting: true,
+                treatment_percent: 50,
+            },
+            RuleSystemVariant::Dnd5e
+            | RuleSystemVariant::Pathfinder2e
+            | RuleSystemVariant::GenericD20
+            | RuleSystemVariant::Custom(_)
+            | RuleSystemVariant::Unknown => Self {
+                minor_hours: 24,
+                serious_hours: 168,
+                critical_hours: 720,
+                rest_multiplier: 2,
+                heals_only_while_resting: false,
+                treatment_percent: 50,
+            },
+        }
+    }
+}
+
+// =============================================================================
+// Resurrection
+// =============================================================================
+
+/// Whether a rule system lets dead PCs come back, and on what terms.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub struct ResurrectionConfig {
+    /// Whether dead PCs can be resurrected at all
+    pub allowed: bool,
+    /// How many game days after death a resurrection is still possible
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub within_days: Option<u32>,
+    /// How many times a single PC can be resurrected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_resurrections: Option<u32>,
+    /// Currency taken from the PC's sheet for each resurrection
+    #[serde(default)]
+    pub cost: u32,
+}
+
+impl Default for ResurrectionConfig {
+    fn default() -> Self {
+        Self::for_variant(&RuleSystemVariant::GenericD20)
+    }
+}
+
+impl ResurrectionConfig {
+    /// Defaults for a preset
+    ///
+    /// D20 systems have raise-dead magic that works within ten days for a
+    /// price. Investigative, narrative and grounded systems treat death as
+    /// final.
+    pub fn for_variant(variant: &RuleSystemVariant) -> Self {
+        match variant {
+            RuleSystemVariant::Dnd5e
+            | RuleSystemVariant::Pathfinder2e
+            | RuleSystemVariant::GenericD20 => Self {
+                allowed: true,
+                within_days: Some(10),
+                max_resurrections: None,
+                cost: 500,
+            },
+            RuleSystemVariant::Custom(_) | RuleSystemVariant::Unknown => Self {
+                allowed: true,
+                within_days: None,
+                max_resurrections: None,
+                cost: 0,
+            },
+            RuleSystemVariant::CallOfCthulhu7e
+            | RuleSystemVariant::RuneQuest
+            | RuleSystemVariant::GenericD100
+            | RuleSystemVariant::KidsOnBikes
+            | RuleSystemVariant::FateCore
+            | RuleSystemVariant::PoweredByApocalypse
+            | RuleSystemVariant::BladesInTheDark => Self {
+                allowed: false,
+                within_days: None,
+                max_resurrections: None,
+                cost: 0,
+            },
+        }
+    }
+
+    /// Check whether a PC who has died can be brought back
+    ///
+    /// `days_dead` is measured in game days and `resurrections` is how many
+    /// times the PC has already been resurrected.
+    pub fn check(&self, days_dead: i64, resurrections: u32) -> Result<(), String> {
+        if !self.allowed {
+            return Err("This rule system does not allow resurrection".to_string());
+        }
+        if let Some(limit) = self.within_days {
+            if days_dead > i64::from(limit) {
+                return Err(format!(
+                    "Resurrection must happen within {} days of death",
+                    limit
+                ));
+            }
+        }
+        if let Some(max) = self.max_resurrections {
+            if resurrections >= max {
+                return Err(format!(
+                    "This character has already been resurrected {} times",
+                    resurrections
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Skill Aliases
+// =============================================================================
+
+/// Skill names that mean the same thing across rule systems.
+///
+/// Each group lists interchangeable names, most common first, e.g.
+/// "Perception", "Notice" and "Survey". Used to carry challenges over when
+/// content is imported from another system or a world switches systems.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub struct SkillAliases {
+    pub groups: Vec<Vec<String>>,
+}
+
+impl Default for SkillAliases {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl SkillAliases {
+    /// Synonyms between the skills of the preset rule systems
+    pub fn standard() -> Self {
+        const GROUPS: &[&[&str]] = &[
+            &[
+                "Perception",
+                "Notice",
+                "Awareness",
+                "Spot Hidden",
+                "Survey",
+                "Listen",
+            ],
+            &["Stealth", "Prowl"],
+            &["Athletics", "Physique", "Brawn"],
+            &["Acrobatics", "Agility", "Dodge", "Evade", "Flight"],
+            &["Endurance", "Physique", "Grit"],
+            &["Investigation", "Investigate", "Library Use", "Study"],
+            &["Insight", "Empathy", "Psychology", "Read a Person"],
+            &[
+                "Persuasion",
+                "Persuade",
+                "Diplomacy",
+                "Rapport",
+                "Influence",
+                "Sway",
+                "Charm",
+            ],
+            &["Deception", "Deceive", "Deceit", "Fast Talk", "Manipulate"],
+            &[
+                "Intimidation",
+                "Intimidate",
+                "Provoke",
+                "Go Aggro",
+                "Command",
+            ],
+            &[
+                "Sleight of Hand",
+                "Thievery",
+                "Burglary",
+                "Finesse",
+                "Locksmith",
+            ],
+            &["Medicine", "First Aid"],
+            &["Survival", "Track", "Hunt", "Navigate"],
+            &["Arcana", "Occultism", "Occult", "Lore", "Attune"],
+            &["History", "Society", "Knowledge", "Lore"],
+            &[
+                "Crafting",
+                "Crafts",
+                "Craft",
+                "Art/Craft",
+                "Tinker",
+                "Mechanical Repair",
+            ],
+            &[
+                "Fight",
+                "Fighting (Brawl)",
+                "Skirmish",
+                "Combat",
+                "Seize by Force",
+            ],
+            &[
+                "Shoot",
+                "Firearms (Handgun)",
+                "Firearms (Rifle/Shotgun)",
+                "Throw",
+            ],
+            &["Willpower", "Will"],
+            &["Drive", "Drive Auto"],
+        ];
+        Self {
+            groups: GROUPS
+                .iter()
+                .map(|group| group.iter().map(|name| name.to_string()).collect())
+                .collect(),
+        }
+    }
+
+    /// The skill among `skills` that matches `name`
+    ///
+    /// An exact match (ignoring case) wins. Otherwise the names grouped with
+    /// `name` are tried in the order they're listed. `None` if no skill fits.
+    pub fn resolve<'a>(&self, name: &str, skills: &[&'a str]) -> Option<&'a str> {
+        let find = |wanted: &str| {
+            skills
+                .iter()
+                .copied()
+                .find(|skill| skill.trim().eq_ignore_ascii_case(wanted.trim()))
+        };
+        find(name).or_else(|| {
+            self.groups
+                .iter()
+                .filter(|group| {
+                    group
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(name.trim()))
+                })
+                .flat_map(|group| group.iter())
+                .find_map(|alias| find(alias))
+        })
+    }
+}
+
 // =============================================================================
 // Narrative Resolution System
 // =============================================================================
@@ -493,6 +887,7 @@ pub enum DiceSystem {
 /// - Ladder: Descriptor maps to target number, NdF+skill vs target (Fate)
 /// - Blades: Position determines consequences, Effect determines progress
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct NarrativeResolutionConfig {
     /// The narrative resolution style
@@ -563,6 +958,7 @@ impl NarrativeResolutionConfig {
 
 /// The narrative resolution style determines how rolls are evaluated
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum NarrativeResolutionStyle {
     /// PbtA: Fixed thresholds (10+/7-9/6-), descriptor affects narrative only
@@ -605,6 +1001,7 @@ impl NarrativeResolutionStyle {
 
 /// Configurable thresholds for PbtA-style resolution
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct NarrativeThresholds {
     /// Total needed for critical success (optional, default: None)
@@ -652,6 +1049,7 @@ impl NarrativeThresholds {
 
 /// Dice configuration for narrative systems
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct NarrativeDiceConfig {
     /// Type of dice system
@@ -710,6 +1108,7 @@ impl NarrativeDiceConfig {
 
 /// Types of dice for narrative systems
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum NarrativeDiceType {
     /// Standard numbered dice (d6, d10, etc.) - sum all dice
@@ -735,6 +1134,7 @@ impl NarrativeDiceType {
 
 /// Difficulty ladder for Fate-style systems
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct DifficultyLadder {
     /// Ladder entries mapping descriptors to values
@@ -792,6 +1192,7 @@ impl DifficultyLadder {
 
 /// Single entry in a difficulty ladder
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct LadderEntry {
     /// The difficulty descriptor this maps
@@ -820,6 +1221,7 @@ impl LadderEntry {
 
 /// Descriptive difficulty for narrative systems (also used as ladder keys)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum DifficultyDescriptor {
     Trivial,
@@ -887,6 +1289,7 @@ impl DifficultyDescriptor {
 
 /// Position/Effect configuration for Blades-style resolution
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PositionEffectConfig {
     /// Thresholds for Blades-style d6 pool (highest die)
@@ -915,6 +1318,7 @@ impl Default for PositionEffectConfig {
 
 /// Thresholds for Blades d6 pool resolution
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct BladesPoolThresholds {
     /// Highest die value for full success (default: 6)
@@ -940,6 +1344,7 @@ impl Default for BladesPoolThresholds {
 
 /// Effect tick configuration for progress clocks
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct EffectTickConfig {
     pub extreme_ticks: u8,
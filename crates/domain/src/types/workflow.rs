@@ -26,6 +26,10 @@ pub enum WorkflowSlot {
     ItemSet,
     /// Map region backdrop (1280x720)
     MapRegion,
+    /// Top-down battle map of a region (1024x1024)
+    BattleMap,
+    /// Top-down layout of a region (1024x1024)
+    RegionLayout,
     /// Unknown workflow slot (for forward compatibility)
     #[serde(other)]
     Unknown,
@@ -44,6 +48,8 @@ impl WorkflowSlot {
             Self::ItemIcon => (64, 64),
             Self::ItemSet => (256, 256),
             Self::MapRegion => (1280, 720),
+            Self::BattleMap => (1024, 1024),
+            Self::RegionLayout => (1024, 1024),
             Self::Unknown => (256, 256),
         }
     }
@@ -60,6 +66,8 @@ impl WorkflowSlot {
             Self::ItemIcon => "Item Icon",
             Self::ItemSet => "Item Set",
             Self::MapRegion => "Map Region",
+            Self::BattleMap => "Battle Map",
+            Self::RegionLayout => "Region Layout",
             Self::Unknown => "Unknown",
         }
     }
@@ -74,7 +82,7 @@ impl WorkflowSlot {
                 "Location Assets"
             }
             Self::ItemIcon | Self::ItemSet => "Item Assets",
-            Self::MapRegion => "Map Assets",
+            Self::MapRegion | Self::BattleMap | Self::RegionLayout => "Map Assets",
             Self::Unknown => "Other",
         }
     }
@@ -91,6 +99,8 @@ impl WorkflowSlot {
             Self::ItemIcon,
             Self::ItemSet,
             Self::MapRegion,
+            Self::BattleMap,
+            Self::RegionLayout,
         ]
     }
 
@@ -106,6 +116,8 @@ impl WorkflowSlot {
             Self::ItemIcon => "item_icon",
             Self::ItemSet => "item_set",
             Self::MapRegion => "map_region",
+            Self::BattleMap => "battle_map",
+            Self::RegionLayout => "region_layout",
             Self::Unknown => "unknown",
         }
     }
@@ -125,6 +137,8 @@ impl std::str::FromStr for WorkflowSlot {
             "item_icon" => Self::ItemIcon,
             "item_set" => Self::ItemSet,
             "map_region" => Self::MapRegion,
+            "battle_map" => Self::BattleMap,
+            "region_layout" => Self::RegionLayout,
             _ => Self::Unknown,
         })
     }
@@ -173,6 +187,30 @@ impl PromptMapping {
     }
 }
 
+/// A workflow input that receives a reference image
+///
+/// Points at an image loader (usually a `LoadImage` node feeding an
+/// IPAdapter) so generations can be conditioned on an existing image, such
+/// as a character's canonical portrait. Conditioning strength is set through
+/// the IPAdapter node's `weight` input default.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceImageMapping {
+    /// The node ID in the workflow
+    pub node_id: String,
+    /// The input name on that node
+    pub input_name: String,
+}
+
+impl ReferenceImageMapping {
+    pub fn new(node_id: impl Into<String>, input_name: impl Into<String>) -> Self {
+        Self {
+            node_id: node_id.into(),
+            input_name: input_name.into(),
+        }
+    }
+}
+
 /// Default value for a workflow input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -496,3 +534,102 @@ pub fn auto_detect_prompt_mappings(workflow: &serde_json::Value) -> Vec<PromptMa
 
     mappings
 }
+
+/// Auto-detect reference image inputs
+///
+/// Finds `LoadImage` nodes whose output feeds an IPAdapter node; their
+/// `image` input is where a reference image goes.
+/// This is a pure function with no side effects.
+pub fn auto_detect_reference_image_mappings(
+    workflow: &serde_json::Value,
+) -> Vec<ReferenceImageMapping> {
+    let Some(nodes) = workflow.as_object() else {
+        return Vec::new();
+    };
+
+    // Node IDs linked into any IPAdapter node's inputs
+    let feeds_ip_adapter: Vec<&str> = nodes
+        .values()
+        .filter(|node| {
+            node.get("class_type")
+                .and_then(|v| v.as_str())
+                .is_some_and(|ct| ct.to_lowercase().contains("ipadapter"))
+        })
+        .filter_map(|node| node.get("inputs").and_then(|i| i.as_object()))
+        .flat_map(|inputs| inputs.values())
+        .filter_map(|value| value.as_array()?.first()?.as_str())
+        .collect();
+
+    let mut mappings: Vec<ReferenceImageMapping> = find_nodes_by_type(workflow, "LoadImage")
+        .into_iter()
+        .filter(|(node_id, _)| feeds_ip_adapter.contains(&node_id.as_str()))
+        .map(|(node_id, _)| ReferenceImageMapping::new(node_id, "image"))
+        .collect();
+    mappings.sort_by(|a, b| a.node_id.cmp(&b.node_id));
+    mappings
+}
+
+/// Set every mapped reference image input to `image_name`
+///
+/// `image_name` is the name ComfyUI gave the uploaded image. Mappings whose
+/// node is missing from the workflow are skipped.
+/// This is a pure function with no side effects.
+pub fn apply_reference_image(
+    workflow: &serde_json::Value,
+    mappings: &[ReferenceImageMapping],
+    image_name: &str,
+) -> serde_json::Value {
+    let mut workflow = workflow.clone();
+    for mapping in mappings {
+        if let Some(inputs) = workflow
+            .get_mut(&mapping.node_id)
+            .and_then(|node| node.get_mut("inputs"))
+            .and_then(|inputs| inputs.as_object_mut())
+        {
+            inputs.insert(
+                mapping.input_name.clone(),
+                serde_json::Value::String(image_name.to_string()),
+            );
+        }
+    }
+    workflow
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip_adapter_workflow() -> serde_json::Value {
+        serde_json::json!({
+            "4": { "class_type": "CheckpointLoaderSimple", "inputs": { "ckpt_name": "model" } },
+            "10": { "class_type": "LoadImage", "inputs": { "image": "placeholder.png" } },
+            "11": { "class_type": "LoadImage", "inputs": { "image": "mask.png" } },
+            "12": {
+                "class_type": "IPAdapterAdvanced",
+                "inputs": { "model": ["4", 0], "image": ["10", 0], "weight": 0.8 }
+            }
+        })
+    }
+
+    #[test]
+    fn detects_only_load_image_nodes_feeding_an_ip_adapter() {
+        assert_eq!(
+            auto_detect_reference_image_mappings(&ip_adapter_workflow()),
+            vec![ReferenceImageMapping::new("10", "image")]
+        );
+    }
+
+    #[test]
+    fn apply_reference_image_sets_mapped_inputs_and_skips_missing_nodes() {
+        let mappings = [
+            ReferenceImageMapping::new("10", "image"),
+            ReferenceImageMapping::new("99", "image"),
+        ];
+
+        let workflow = apply_reference_image(&ip_adapter_workflow(), &mappings, "portrait.png");
+
+        assert_eq!(workflow["10"]["inputs"]["image"], "portrait.png");
+        assert_eq!(workflow["11"]["inputs"]["image"], "mask.png");
+        assert!(workflow.get("99").is_none());
+    }
+}
@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 /// Domain representation of custom outcome text for challenges
 /// created on-the-fly by the DM.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AdHocOutcomes {
     /// Outcome text for a successful roll
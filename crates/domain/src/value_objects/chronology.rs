@@ -0,0 +1,302 @@
+//! Chronology - character ages and lore dates on the game calendar
+//!
+//! The game calendar is the world's `GameTime`, so a character's age runs
+//! from their birthdate to the current game date. Lore can carry the year
+//! its events took place; lore that mentions a character by name in a year
+//! before their birth is a chronology error.
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Character, Lore};
+use wrldbldr_domain::{CharacterId, LoreId};
+
+/// Shortest time advance that counts as a time skip for age progression
+pub const AGE_PROGRESSION_MIN_SKIP_DAYS: i64 = 30;
+
+/// Broad life stage, derived from age
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LifeStage {
+    Child,
+    Adolescent,
+    Adult,
+    MiddleAged,
+    Elder,
+}
+
+impl LifeStage {
+    pub fn for_age(age: u32) -> Self {
+        match age {
+            0..=12 => LifeStage::Child,
+            13..=17 => LifeStage::Adolescent,
+            18..=39 => LifeStage::Adult,
+            40..=59 => LifeStage::MiddleAged,
+            _ => LifeStage::Elder,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LifeStage::Child => "Child",
+            LifeStage::Adolescent => "Adolescent",
+            LifeStage::Adult => "Adult",
+            LifeStage::MiddleAged => "Middle-aged",
+            LifeStage::Elder => "Elder",
+        }
+    }
+}
+
+/// A contradiction between a character's birthdate and the rest of the world
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChronologyIssue {
+    /// Lore set in `event_year` mentions a character born after it
+    MentionedBeforeBirth {
+        character_id: CharacterId,
+        character_name: String,
+        birth_year: i32,
+        lore_id: LoreId,
+        lore_title: String,
+        event_year: i32,
+    },
+    /// The birthdate is later than the current game date
+    BornInFuture {
+        character_id: CharacterId,
+        character_name: String,
+        birth_date: NaiveDate,
+    },
+}
+
+impl ChronologyIssue {
+    pub fn character_id(&self) -> CharacterId {
+        match self {
+            ChronologyIssue::MentionedBeforeBirth { character_id, .. }
+            | ChronologyIssue::BornInFuture { character_id, .. } => *character_id,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            ChronologyIssue::MentionedBeforeBirth {
+                character_name,
+                birth_year,
+                lore_title,
+                event_year,
+                ..
+            } => format!(
+                "\"{}\" mentions {} in year {}, but they are born in {}",
+                lore_title, character_name, event_year, birth_year
+            ),
+            ChronologyIssue::BornInFuture {
+                character_name,
+                birth_date,
+                ..
+            } => format!(
+                "{} is born on {}, after the current game date",
+                character_name, birth_date
+            ),
+        }
+    }
+}
+
+/// Every chronology error among the world's characters and lore, as of `today`
+pub fn find_chronology_issues(
+    characters: &[Character],
+    lore: &[Lore],
+    today: NaiveDate,
+) -> Vec<ChronologyIssue> {
+    let mut issues = Vec::new();
+    for character in characters {
+        let Some(birth_date) = character.birth_date else {
+            continue;
+        };
+        if birth_date > today {
+            issues.push(ChronologyIssue::BornInFuture {
+                character_id: character.id,
+                character_name: character.name.clone(),
+                birth_date,
+            });
+        }
+        for entry in lore {
+            let Some(event_year) = entry.event_year else {
+                continue;
+            };
+            if event_year < birth_date.year() && lore_mentions(entry, &character.name) {
+                issues.push(ChronologyIssue::MentionedBeforeBirth {
+                    character_id: character.id,
+                    character_name: character.name.clone(),
+                    birth_year: birth_date.year(),
+                    lore_id: entry.id,
+                    lore_title: entry.title.clone(),
+                    event_year,
+                });
+            }
+        }
+    }
+    issues
+}
+
+/// A character whose age changed over a stretch of game time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgeProgression {
+    pub character_id: CharacterId,
+    pub character_name: String,
+    /// None if the character was not yet born at the start
+    pub previous_age: Option<u32>,
+    pub new_age: u32,
+}
+
+impl AgeProgression {
+    pub fn previous_stage(&self) -> Option<LifeStage> {
+        self.previous_age.map(LifeStage::for_age)
+    }
+
+    pub fn new_stage(&self) -> LifeStage {
+        LifeStage::for_age(self.new_age)
+    }
+
+    pub fn stage_changed(&self) -> bool {
+        self.previous_stage() != Some(self.new_stage())
+    }
+}
+
+/// Living characters who had a birthday (or were born) between `from` and `to`
+pub fn age_progressions(
+    characters: &[Character],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Vec<AgeProgression> {
+    characters
+        .iter()
+        .filter(|c| c.is_alive)
+        .filter_map(|c| {
+            let new_age = c.age_on(to)?;
+            let previous_age = c.age_on(from);
+            (previous_age != Some(new_age)).then(|| AgeProgression {
+                character_id: c.id,
+                character_name: c.name.clone(),
+                previous_age,
+                new_age,
+            })
+        })
+        .collect()
+}
+
+/// Whether the lore's title, summary or chunks mention `name` as a whole word
+fn lore_mentions(lore: &Lore, name: &str) -> bool {
+    mentions(&lore.title, name)
+        || mentions(&lore.summary, name)
+        || lore.chunks.iter().any(|c| {
+            mentions(&c.content, name) || c.title.as_deref().is_some_and(|t| mentions(t, name))
+        })
+}
+
+/// Case-insensitive whole-word search, so "Ann" does not match "Annals"
+fn mentions(text: &str, name: &str) -> bool {
+    let name = name.trim().to_lowercase();
+    if name.is_empty() {
+        return false;
+    }
+    let text = text.to_lowercase();
+    text.match_indices(&name).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + name.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::LoreCategory;
+    use crate::value_objects::CampbellArchetype;
+    use wrldbldr_domain::WorldId;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn character(name: &str, birth_date: NaiveDate) -> Character {
+        Character::new(WorldId::new(), name, CampbellArchetype::Mentor).with_birth_date(birth_date)
+    }
+
+    #[test]
+    fn ages_count_whole_years_and_map_to_stages() {
+        let mira = character("Mira", date(1450, 6, 15));
+
+        assert_eq!(mira.age_on(date(1490, 6, 14)), Some(39));
+        assert_eq!(mira.age_on(date(1490, 6, 15)), Some(40));
+        assert_eq!(mira.age_on(date(1449, 1, 1)), None);
+        assert_eq!(LifeStage::for_age(39), LifeStage::Adult);
+        assert_eq!(LifeStage::for_age(40), LifeStage::MiddleAged);
+    }
+
+    #[test]
+    fn lore_mentioning_a_character_before_their_birth_is_flagged() {
+        let mira = character("Mira", date(1450, 6, 15));
+        let world_id = mira.world_id;
+        let now = chrono::Utc::now();
+        let siege = Lore::new(world_id, "The Siege of Varn", LoreCategory::Historical, now)
+            .with_chunk("Mira held the gate for three days.")
+            .with_event_year(1420);
+        let annals = Lore::new(
+            world_id,
+            "Annals of the Miramar",
+            LoreCategory::Historical,
+            now,
+        )
+        .with_summary("Miramar's founding")
+        .with_event_year(1400);
+        let later = Lore::new(world_id, "The Second Siege", LoreCategory::Historical, now)
+            .with_chunk("mira returned, older and wiser.")
+            .with_event_year(1480);
+
+        let issues =
+            find_chronology_issues(&[mira], &[siege.clone(), annals, later], date(1490, 1, 1));
+
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(
+            &issues[0],
+            ChronologyIssue::MentionedBeforeBirth { lore_id, birth_year: 1450, event_year: 1420, .. }
+                if *lore_id == siege.id
+        ));
+    }
+
+    #[test]
+    fn a_birthdate_after_today_is_flagged() {
+        let unborn = character("Tam", date(1500, 1, 1));
+
+        let issues = find_chronology_issues(&[unborn], &[], date(1490, 1, 1));
+
+        assert!(matches!(
+            issues.as_slice(),
+            [ChronologyIssue::BornInFuture { .. }]
+        ));
+    }
+
+    #[test]
+    fn age_progressions_list_birthdays_within_the_skip() {
+        let mut dead = character("Old Bren", date(1400, 3, 1));
+        dead.is_alive = false;
+        let teen = character("Pell", date(1473, 3, 1));
+        let newborn = character("Wren", date(1490, 2, 1));
+        let adult = character("Ossa", date(1460, 12, 1));
+
+        let progressions = age_progressions(
+            &[dead, teen, newborn, adult],
+            date(1490, 1, 1),
+            date(1491, 11, 1),
+        );
+
+        assert_eq!(progressions.len(), 3);
+        let pell = &progressions[0];
+        assert_eq!((pell.previous_age, pell.new_age), (Some(16), 18));
+        assert!(pell.stage_changed());
+        let wren = &progressions[1];
+        assert_eq!((wren.previous_age, wren.new_age), (None, 1));
+        assert!(wren.stage_changed());
+        let ossa = &progressions[2];
+        assert_eq!((ossa.previous_age, ossa.new_age), (Some(29), 30));
+        assert!(!ossa.stage_changed());
+    }
+}
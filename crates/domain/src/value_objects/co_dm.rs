@@ -0,0 +1,427 @@
+//! AI co-DM - An LLM stands in for the DM on approvals
+//!
+//! A solo player has nobody to approve NPC responses. With co-DM mode on,
+//! each approval is put to an LLM with the world's guardrails, and its
+//! decision is applied as if a DM had made it. Anything the guardrails
+//! don't trust the co-DM with is escalated to the approval queue as usual.
+//!
+//! Every review is kept (see [`CoDmReview`]) so a DM can go back over what
+//! the co-DM let through.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::WorldId;
+
+use super::queue_data::{ApprovalRequestData, DmApprovalDecision};
+
+/// Co-DM mode and its guardrails for a world
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct CoDmSettings {
+    /// Resolve approvals with the co-DM instead of waiting for a DM
+    pub enabled: bool,
+    /// Let the co-DM rewrite dialogue; when off it may only accept or reject
+    pub allow_rewrites: bool,
+    /// Let the co-DM approve plugin tool calls; when off none are run
+    pub allow_tools: bool,
+    /// Leave responses flagged by the content safety filter for a DM
+    pub escalate_safety_warnings: bool,
+    /// Leave responses carrying a challenge or narrative event suggestion
+    /// for a DM
+    pub escalate_suggestions: bool,
+    /// Longest dialogue the co-DM may let through, in characters. 0 = no limit.
+    pub max_dialogue_chars: usize,
+    /// Table-specific direction for the co-DM, e.g. "keep it light, favour
+    /// the player"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+}
+
+impl Default for CoDmSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            allow_rewrites: true,
+            allow_tools: false,
+            escalate_safety_warnings: true,
+            escalate_suggestions: true,
+            max_dialogue_chars: 600,
+            instructions: None,
+        }
+    }
+}
+
+impl CoDmSettings {
+    /// Why the co-DM must not review this approval at all, if it mustn't
+    pub fn escalation_for(&self, approval: &ApprovalRequestData) -> Option<CoDmEscalation> {
+        if self.escalate_safety_warnings && !approval.safety_warnings.is_empty() {
+            return Some(CoDmEscalation::SafetyWarnings);
+        }
+        if self.escalate_suggestions
+            && (approval.challenge_suggestion.is_some()
+                || approval.narrative_event_suggestion.is_some())
+        {
+            return Some(CoDmEscalation::Suggestion);
+        }
+        None
+    }
+
+    /// Apply the guardrails to the co-DM's verdict on an approval
+    pub fn outcome(&self, approval: &ApprovalRequestData, verdict: CoDmVerdict) -> CoDmOutcome {
+        let approved_tools = |tools: Vec<String>| -> Vec<String> {
+            if !self.allow_tools {
+                return vec![];
+            }
+            tools
+                .into_iter()
+                .filter(|id| approval.proposed_tools.iter().any(|t| &t.id == id))
+                .collect()
+        };
+
+        let outcome = match verdict {
+            CoDmVerdict::Accept {
+                approved_tools: tools,
+            } => CoDmOutcome::Accepted {
+                approved_tools: approved_tools(tools),
+            },
+            CoDmVerdict::Modify {
+                dialogue,
+                approved_tools: tools,
+            } => {
+                let dialogue = dialogue.trim().to_string();
+                if !self.allow_rewrites {
+                    return CoDmOutcome::Escalated {
+                        reason: CoDmEscalation::RewriteNotAllowed,
+                    };
+                }
+                if dialogue.is_empty() || dialogue == approval.proposed_dialogue.trim() {
+                    CoDmOutcome::Accepted {
+                        approved_tools: approved_tools(tools),
+                    }
+                } else {
+                    CoDmOutcome::Modified {
+                        dialogue,
+                        approved_tools: approved_tools(tools),
+                    }
+                }
+            }
+            CoDmVerdict::Reject => CoDmOutcome::Rejected,
+        };
+
+        let too_long = outcome.final_dialogue(approval).is_some_and(|d| {
+            self.max_dialogue_chars > 0 && d.chars().count() > self.max_dialogue_chars
+        });
+        if too_long {
+            return CoDmOutcome::Escalated {
+                reason: CoDmEscalation::DialogueTooLong,
+            };
+        }
+        outcome
+    }
+}
+
+/// What the co-DM LLM decided, before guardrails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoDmVerdict {
+    /// Let the proposed dialogue through
+    Accept { approved_tools: Vec<String> },
+    /// Let a rewritten dialogue through
+    Modify {
+        dialogue: String,
+        approved_tools: Vec<String>,
+    },
+    /// Drop the response
+    Reject,
+}
+
+/// Why an approval was left for a human DM
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoDmEscalation {
+    /// The content safety filter flagged the response
+    SafetyWarnings,
+    /// The response carries a challenge or narrative event suggestion
+    Suggestion,
+    /// The co-DM wanted to rewrite the dialogue but rewrites are off
+    RewriteNotAllowed,
+    /// The dialogue is longer than the guardrail allows
+    DialogueTooLong,
+    /// The co-DM could not be reached or gave no usable answer
+    Unavailable,
+    /// Unknown reason (for forward compatibility)
+    #[serde(other)]
+    Unknown,
+}
+
+impl CoDmEscalation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CoDmEscalation::SafetyWarnings => "safety_warnings",
+            CoDmEscalation::Suggestion => "suggestion",
+            CoDmEscalation::RewriteNotAllowed => "rewrite_not_allowed",
+            CoDmEscalation::DialogueTooLong => "dialogue_too_long",
+            CoDmEscalation::Unavailable => "unavailable",
+            CoDmEscalation::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for CoDmEscalation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What came of a co-DM review
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoDmOutcome {
+    Accepted {
+        #[serde(default)]
+        approved_tools: Vec<String>,
+    },
+    Modified {
+        dialogue: String,
+        #[serde(default)]
+        approved_tools: Vec<String>,
+    },
+    Rejected,
+    /// Left in the approval queue for a human DM
+    Escalated {
+        reason: CoDmEscalation,
+    },
+}
+
+impl CoDmOutcome {
+    /// The decision to apply, as if the DM had made it; None when escalated
+    pub fn decision(&self, approval: &ApprovalRequestData) -> Option<DmApprovalDecision> {
+        let accept_with = |dialogue: &str, approved_tools: &[String]| {
+            DmApprovalDecision::AcceptWithModification {
+                modified_dialogue: dialogue.to_string(),
+                approved_tools: approved_tools.to_vec(),
+                rejected_tools: approval
+                    .proposed_tools
+                    .iter()
+                    .filter(|t| !approved_tools.contains(&t.id))
+                    .map(|t| t.id.clone())
+                    .collect(),
+                item_recipients: Default::default(),
+            }
+        };
+        match self {
+            CoDmOutcome::Accepted { approved_tools } if approved_tools.is_empty() => {
+                Some(DmApprovalDecision::Accept)
+            }
+            CoDmOutcome::Accepted { approved_tools } => {
+                Some(accept_with(&approval.proposed_dialogue, approved_tools))
+            }
+            CoDmOutcome::Modified {
+                dialogue,
+                approved_tools,
+            } => Some(accept_with(dialogue, approved_tools)),
+            CoDmOutcome::Rejected => Some(DmApprovalDecision::Reject {
+                feedback: "Rejected by co-DM".to_string(),
+            }),
+            CoDmOutcome::Escalated { .. } => None,
+        }
+    }
+
+    /// The dialogue players will see, if any gets through
+    pub fn final_dialogue<'a>(&'a self, approval: &'a ApprovalRequestData) -> Option<&'a str> {
+        match self {
+            CoDmOutcome::Accepted { .. } => Some(&approval.proposed_dialogue),
+            CoDmOutcome::Modified { dialogue, .. } => Some(dialogue),
+            CoDmOutcome::Rejected | CoDmOutcome::Escalated { .. } => None,
+        }
+    }
+}
+
+/// One co-DM review, kept so a DM can audit it later
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoDmReview {
+    pub approval_id: Uuid,
+    pub world_id: WorldId,
+    pub npc_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub player_dialogue: Option<String>,
+    pub proposed_dialogue: String,
+    pub outcome: CoDmOutcome,
+    /// The co-DM's explanation; empty when it wasn't asked
+    #[serde(default)]
+    pub reasoning: String,
+    pub reviewed_at: DateTime<Utc>,
+}
+
+impl CoDmReview {
+    pub fn new(
+        approval_id: Uuid,
+        approval: &ApprovalRequestData,
+        outcome: CoDmOutcome,
+        reasoning: String,
+        reviewed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            approval_id,
+            world_id: approval.world_id,
+            npc_name: approval.npc_name.clone(),
+            player_dialogue: approval.player_dialogue.clone(),
+            proposed_dialogue: approval.proposed_dialogue.clone(),
+            outcome,
+            reasoning,
+            reviewed_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::queue_data::{ApprovalDecisionType, ApprovalUrgency, ProposedTool};
+
+    fn approval(dialogue: &str) -> ApprovalRequestData {
+        ApprovalRequestData {
+            world_id: WorldId::new(),
+            source_action_id: Uuid::new_v4(),
+            decision_type: ApprovalDecisionType::NpcResponse,
+            urgency: ApprovalUrgency::AwaitingPlayer,
+            pc_id: None,
+            npc_id: None,
+            npc_name: "Marta".to_string(),
+            proposed_dialogue: dialogue.to_string(),
+            internal_reasoning: String::new(),
+            proposed_tools: vec![ProposedTool {
+                id: "call-1".to_string(),
+                name: "plugin:weather:set".to_string(),
+                description: "Make it rain".to_string(),
+                arguments: serde_json::json!({}),
+            }],
+            retry_count: 0,
+            challenge_suggestion: None,
+            narrative_event_suggestion: None,
+            challenge_outcome: None,
+            player_dialogue: Some("Is it going to rain?".to_string()),
+            scene_id: None,
+            location_id: None,
+            game_time: None,
+            topics: vec![],
+            conversation_id: None,
+            safety_warnings: vec![],
+            merged_lines: vec![],
+        }
+    }
+
+    #[test]
+    fn flagged_responses_are_escalated_by_default() {
+        let mut flagged = approval("Hmm.");
+        flagged.safety_warnings = vec!["veil: spiders".to_string()];
+
+        let settings = CoDmSettings::default();
+        assert_eq!(
+            settings.escalation_for(&flagged),
+            Some(CoDmEscalation::SafetyWarnings)
+        );
+
+        let trusting = CoDmSettings {
+            escalate_safety_warnings: false,
+            ..Default::default()
+        };
+        assert_eq!(trusting.escalation_for(&flagged), None);
+    }
+
+    #[test]
+    fn tools_are_only_approved_when_allowed_and_proposed() {
+        let approval = approval("Looks like rain.");
+        let verdict = CoDmVerdict::Accept {
+            approved_tools: vec!["call-1".to_string(), "made-up".to_string()],
+        };
+
+        let outcome = CoDmSettings::default().outcome(&approval, verdict.clone());
+        assert_eq!(
+            outcome,
+            CoDmOutcome::Accepted {
+                approved_tools: vec![]
+            }
+        );
+        assert!(matches!(
+            outcome.decision(&approval),
+            Some(DmApprovalDecision::Accept)
+        ));
+
+        let with_tools = CoDmSettings {
+            allow_tools: true,
+            ..Default::default()
+        };
+        let outcome = with_tools.outcome(&approval, verdict);
+        assert_eq!(
+            outcome,
+            CoDmOutcome::Accepted {
+                approved_tools: vec!["call-1".to_string()]
+            }
+        );
+        match outcome.decision(&approval) {
+            Some(DmApprovalDecision::AcceptWithModification {
+                modified_dialogue,
+                approved_tools,
+                ..
+            }) => {
+                assert_eq!(modified_dialogue, "Looks like rain.");
+                assert_eq!(approved_tools, vec!["call-1".to_string()]);
+            }
+            other => panic!("expected AcceptWithModification, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rewrites_respect_the_guardrails() {
+        let approval = approval("Looks like rain.");
+        let rewrite = CoDmVerdict::Modify {
+            dialogue: "Rain by nightfall, mark my words.".to_string(),
+            approved_tools: vec![],
+        };
+
+        let no_rewrites = CoDmSettings {
+            allow_rewrites: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            no_rewrites.outcome(&approval, rewrite.clone()),
+            CoDmOutcome::Escalated {
+                reason: CoDmEscalation::RewriteNotAllowed
+            }
+        );
+
+        let short = CoDmSettings {
+            max_dialogue_chars: 10,
+            ..Default::default()
+        };
+        let outcome = short.outcome(&approval, rewrite);
+        assert_eq!(
+            outcome,
+            CoDmOutcome::Escalated {
+                reason: CoDmEscalation::DialogueTooLong
+            }
+        );
+        assert!(outcome.decision(&approval).is_none());
+    }
+
+    #[test]
+    fn review_round_trips_through_json() {
+        let approval = approval("Looks like rain.");
+        let review = CoDmReview::new(
+            Uuid::new_v4(),
+            &approval,
+            CoDmOutcome::Modified {
+                dialogue: "Rain by nightfall.".to_string(),
+                approved_tools: vec![],
+            },
+            "Tighter and in voice".to_string(),
+            Utc::now(),
+        );
+
+        let json = serde_json::to_string(&review).unwrap();
+        let parsed: CoDmReview = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, review);
+    }
+}
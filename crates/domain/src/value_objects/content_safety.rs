@@ -0,0 +1,383 @@
+//! Content safety configuration - Per-world lines, veils, and content rating
+//!
+//! Lines are topics that must never appear. Veils are topics that may happen
+//! in the fiction but are never depicted on-screen. Both, together with the
+//! world's content rating, are injected into every LLM prompt as hard
+//! constraints, and generated output is checked against them before it can
+//! reach players.
+//!
+//! Players can also raise an anonymous safety signal (X-card style) during
+//! play; the config decides how strongly the engine reacts to each level.
+
+use serde::{Deserialize, Serialize};
+
+/// Overall content rating for a world
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ContentRating {
+    /// Suitable for all ages
+    General,
+    /// Non-graphic violence, no sexual content, mild language
+    #[default]
+    Teen,
+    /// Mature themes allowed, without gratuitous detail
+    Mature,
+    /// Unknown rating (for forward compatibility; treated as Teen)
+    #[serde(other)]
+    Unknown,
+}
+
+impl ContentRating {
+    /// Prompt guidance describing what this rating allows
+    pub fn guidance(&self) -> &'static str {
+        match self {
+            ContentRating::General => {
+                "Keep all content suitable for all ages: no graphic violence, gore, \
+                 sexual content, or strong language."
+            }
+            ContentRating::Teen | ContentRating::Unknown => {
+                "Keep content suitable for teens: violence may be described without gore, \
+                 no sexual content, and only mild language."
+            }
+            ContentRating::Mature => {
+                "Mature themes are allowed, but never include gratuitous or explicit detail."
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ContentRating {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentRating::General => write!(f, "general"),
+            ContentRating::Teen => write!(f, "teen"),
+            ContentRating::Mature => write!(f, "mature"),
+            ContentRating::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl std::str::FromStr for ContentRating {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "general" => Ok(ContentRating::General),
+            "teen" => Ok(ContentRating::Teen),
+            "mature" => Ok(ContentRating::Mature),
+            "unknown" => Ok(ContentRating::Unknown),
+            _ => Err(format!(
+                "Invalid content rating '{}'. Valid ratings: general, teen, mature",
+                s
+            )),
+        }
+    }
+}
+
+/// How strongly a player is signalling discomfort
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SafetySignalLevel {
+    /// "Can we check in?" - the DM should slow down and ask
+    Check,
+    /// X-card - drop whatever content is on the table right now
+    Skip,
+    /// Stop play entirely until the table has talked
+    Stop,
+    /// Unknown level (for forward compatibility; treated as Check)
+    #[serde(other)]
+    Unknown,
+}
+
+impl SafetySignalLevel {
+    fn severity(&self) -> u8 {
+        match self {
+            SafetySignalLevel::Check | SafetySignalLevel::Unknown => 0,
+            SafetySignalLevel::Skip => 1,
+            SafetySignalLevel::Stop => 2,
+        }
+    }
+
+    /// True if this level is at least as severe as `threshold`
+    pub fn reaches(&self, threshold: SafetySignalLevel) -> bool {
+        self.severity() >= threshold.severity()
+    }
+}
+
+impl std::fmt::Display for SafetySignalLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SafetySignalLevel::Check => write!(f, "check"),
+            SafetySignalLevel::Skip => write!(f, "skip"),
+            SafetySignalLevel::Stop => write!(f, "stop"),
+            SafetySignalLevel::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// What the engine does in response to a safety signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetySignalResponse {
+    /// Stop accepting new player actions until the DM resumes
+    pub pause_queue: bool,
+    /// Reject NPC dialogue that is awaiting generation or DM approval
+    pub reject_pending: bool,
+}
+
+/// Per-world safety tools configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentSafetyConfig {
+    /// Overall content rating
+    #[serde(default)]
+    pub content_rating: ContentRating,
+    /// Hard limits: topics that must never appear
+    #[serde(default)]
+    pub lines: Vec<String>,
+    /// Soft limits: topics that may occur but stay off-screen
+    #[serde(default)]
+    pub veils: Vec<String>,
+    /// Lowest signal level that pauses the action queue (None = never)
+    #[serde(default = "default_pause_queue_at")]
+    pub pause_queue_at: Option<SafetySignalLevel>,
+    /// Lowest signal level that auto-rejects pending NPC dialogue (None = never)
+    #[serde(default = "default_reject_pending_at")]
+    pub reject_pending_at: Option<SafetySignalLevel>,
+}
+
+fn default_pause_queue_at() -> Option<SafetySignalLevel> {
+    Some(SafetySignalLevel::Stop)
+}
+
+fn default_reject_pending_at() -> Option<SafetySignalLevel> {
+    Some(SafetySignalLevel::Skip)
+}
+
+impl Default for ContentSafetyConfig {
+    fn default() -> Self {
+        Self::new(ContentRating::default())
+    }
+}
+
+impl ContentSafetyConfig {
+    pub fn new(content_rating: ContentRating) -> Self {
+        Self {
+            content_rating,
+            lines: Vec::new(),
+            veils: Vec::new(),
+            pause_queue_at: default_pause_queue_at(),
+            reject_pending_at: default_reject_pending_at(),
+        }
+    }
+
+    pub fn with_line(mut self, topic: impl Into<String>) -> Self {
+        self.lines.push(topic.into());
+        self
+    }
+
+    pub fn with_veil(mut self, topic: impl Into<String>) -> Self {
+        self.veils.push(topic.into());
+        self
+    }
+
+    /// Trim topics and drop blanks and case-insensitive duplicates.
+    ///
+    /// A topic listed as both a line and a veil is kept only as a line.
+    pub fn normalized(self) -> Self {
+        let lines = dedup_topics(self.lines, &[]);
+        let veils = dedup_topics(self.veils, &lines);
+        Self {
+            lines,
+            veils,
+            ..self
+        }
+    }
+
+    /// Decide how to react to a player's safety signal.
+    pub fn signal_response(&self, level: SafetySignalLevel) -> SafetySignalResponse {
+        SafetySignalResponse {
+            pause_queue: self.pause_queue_at.is_some_and(|t| level.reaches(t)),
+            reject_pending: self.reject_pending_at.is_some_and(|t| level.reaches(t)),
+        }
+    }
+
+    /// Build the constraint block appended to LLM system prompts.
+    pub fn prompt_constraints(&self) -> String {
+        let mut out = String::from("CONTENT SAFETY (hard constraints - never violate these):\n");
+        out.push_str(&format!(
+            "- Content rating: {}. {}\n",
+            self.content_rating,
+            self.content_rating.guidance()
+        ));
+        if !self.lines.is_empty() {
+            out.push_str(&format!(
+                "- Lines (never include or allude to these, not even off-screen): {}\n",
+                self.lines.join(", ")
+            ));
+        }
+        if !self.veils.is_empty() {
+            out.push_str(&format!(
+                "- Veils (may be referenced, but never depicted or described in detail; \
+                 fade to black instead): {}\n",
+                self.veils.join(", ")
+            ));
+        }
+        out
+    }
+
+    /// Check generated text against lines and veils.
+    ///
+    /// Returns one warning per matched topic; an empty list means the text
+    /// passed. Matching is case-insensitive and anchored at word starts, so it
+    /// errs on the side of flagging for DM review.
+    pub fn check_output(&self, text: &str) -> Vec<String> {
+        let haystack = text.to_lowercase();
+        let mut warnings = Vec::new();
+
+        for line in &self.lines {
+            if mentions_topic(&haystack, line) {
+                warnings.push(format!("Mentions line \"{}\"", line));
+            }
+        }
+        for veil in &self.veils {
+            if mentions_topic(&haystack, veil) {
+                warnings.push(format!(
+                    "Mentions veiled topic \"{}\" - make sure it stays off-screen",
+                    veil
+                ));
+            }
+        }
+
+        warnings
+    }
+}
+
+fn dedup_topics(topics: Vec<String>, exclude: &[String]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for topic in topics {
+        let topic = topic.trim();
+        if topic.is_empty() {
+            continue;
+        }
+        let is_dup = out
+            .iter()
+            .chain(exclude.iter())
+            .any(|t| t.eq_ignore_ascii_case(topic));
+        if !is_dup {
+            out.push(topic.to_string());
+        }
+    }
+    out
+}
+
+/// True if `topic` appears in `haystack` (already lowercased) at a word start.
+fn mentions_topic(haystack: &str, topic: &str) -> bool {
+    let needle = topic.trim().to_lowercase();
+    if needle.is_empty() {
+        return false;
+    }
+    haystack.match_indices(&needle).any(|(idx, _)| {
+        haystack[..idx]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_constraints_include_rating_lines_and_veils() {
+        let config = ContentSafetyConfig::new(ContentRating::General)
+            .with_line("spiders")
+            .with_veil("torture");
+
+        let prompt = config.prompt_constraints();
+        assert!(prompt.contains("Content rating: general"));
+        assert!(prompt.contains("Lines"));
+        assert!(prompt.contains("spiders"));
+        assert!(prompt.contains("Veils"));
+        assert!(prompt.contains("torture"));
+    }
+
+    #[test]
+    fn test_prompt_constraints_omit_empty_lists() {
+        let prompt = ContentSafetyConfig::default().prompt_constraints();
+        assert!(prompt.contains("Content rating: teen"));
+        assert!(!prompt.contains("Lines"));
+        assert!(!prompt.contains("Veils"));
+    }
+
+    #[test]
+    fn test_check_output_flags_lines_and_veils() {
+        let config = ContentSafetyConfig::default()
+            .with_line("Spider")
+            .with_veil("torture");
+
+        let warnings = config
+            .check_output("A giant SPIDER drops from the ceiling. Torture devices line the walls.");
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("Spider"));
+        assert!(warnings[1].contains("off-screen"));
+    }
+
+    #[test]
+    fn test_check_output_requires_word_start() {
+        let config = ContentSafetyConfig::default().with_line("rat");
+
+        assert!(config.check_output("The pirate grins.").is_empty());
+        assert_eq!(config.check_output("Rats scurry past.").len(), 1);
+    }
+
+    #[test]
+    fn test_normalized_trims_and_dedups() {
+        let config = ContentSafetyConfig {
+            lines: vec![" spiders ".into(), "".into(), "Spiders".into()],
+            veils: vec!["SPIDERS".into(), "gore".into()],
+            ..ContentSafetyConfig::new(ContentRating::Mature)
+        }
+        .normalized();
+
+        assert_eq!(config.lines, vec!["spiders".to_string()]);
+        assert_eq!(config.veils, vec!["gore".to_string()]);
+    }
+
+    #[test]
+    fn test_signal_response_uses_thresholds() {
+        let config = ContentSafetyConfig::default();
+
+        let check = config.signal_response(SafetySignalLevel::Check);
+        assert!(!check.pause_queue && !check.reject_pending);
+
+        let skip = config.signal_response(SafetySignalLevel::Skip);
+        assert!(!skip.pause_queue && skip.reject_pending);
+
+        let stop = config.signal_response(SafetySignalLevel::Stop);
+        assert!(stop.pause_queue && stop.reject_pending);
+
+        let never = ContentSafetyConfig {
+            pause_queue_at: None,
+            reject_pending_at: None,
+            ..ContentSafetyConfig::default()
+        };
+        let stop = never.signal_response(SafetySignalLevel::Stop);
+        assert!(!stop.pause_queue && !stop.reject_pending);
+    }
+
+    #[test]
+    fn test_missing_signal_thresholds_use_defaults() {
+        let config: ContentSafetyConfig =
+            serde_json::from_str(r#"{"contentRating":"general"}"#).unwrap();
+        assert_eq!(config.pause_queue_at, Some(SafetySignalLevel::Stop));
+        assert_eq!(config.reject_pending_at, Some(SafetySignalLevel::Skip));
+    }
+
+    #[test]
+    fn test_rating_roundtrip_and_unknown() {
+        assert_eq!("Mature".parse::<ContentRating>(), Ok(ContentRating::Mature));
+        let parsed: ContentRating = serde_json::from_str("\"adultsOnly\"").unwrap();
+        assert_eq!(parsed, ContentRating::Unknown);
+    }
+}
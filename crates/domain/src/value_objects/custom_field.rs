@@ -0,0 +1,344 @@
+//! Custom fields - DM-defined extra fields on characters, locations and items
+//!
+//! Each world carries its own field schema, so a table can track house data
+//! ("Faction standing", "Heat", "Shop tier") without a new entity property.
+//! A field is typed (text, number, or one of a fixed set of options) and
+//! belongs to one entity type. Values live beside the entity and are checked
+//! against the schema when saved; values for fields that were since removed
+//! are simply not shown.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::EntityType;
+
+/// Most custom fields a world may define (across all entity types)
+pub const MAX_CUSTOM_FIELDS: usize = 50;
+
+/// Most options a select field may offer
+pub const MAX_CUSTOM_FIELD_OPTIONS: usize = 50;
+
+/// Longest text value accepted
+pub const MAX_CUSTOM_FIELD_TEXT_LEN: usize = 2000;
+
+/// Entity types that can carry custom fields
+pub const CUSTOM_FIELD_ENTITY_TYPES: [EntityType; 3] = [
+    EntityType::Character,
+    EntityType::Location,
+    EntityType::Item,
+];
+
+/// What kind of value a custom field holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CustomFieldType {
+    /// Free text
+    Text,
+    /// Any finite number
+    Number,
+    /// One of the field's options
+    Select,
+}
+
+impl std::fmt::Display for CustomFieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomFieldType::Text => write!(f, "text"),
+            CustomFieldType::Number => write!(f, "number"),
+            CustomFieldType::Select => write!(f, "select"),
+        }
+    }
+}
+
+/// A custom field value (select values are stored as their option text)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CustomFieldValue {
+    Number(f64),
+    Text(String),
+}
+
+/// Values keyed by field key
+pub type CustomFieldValues = BTreeMap<String, CustomFieldValue>;
+
+/// One DM-defined field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldDefinition {
+    /// Stable identifier (lowercase letters, digits and `_`), unique per entity type
+    pub key: String,
+    /// Name shown in forms
+    pub label: String,
+    pub entity_type: EntityType,
+    pub field_type: CustomFieldType,
+    /// Allowed values for select fields (empty for other types)
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// Whether players see the value in detail views
+    #[serde(default)]
+    pub visible_to_players: bool,
+}
+
+impl CustomFieldDefinition {
+    pub fn new(
+        key: impl Into<String>,
+        label: impl Into<String>,
+        entity_type: EntityType,
+        field_type: CustomFieldType,
+    ) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            entity_type,
+            field_type,
+            options: Vec::new(),
+            visible_to_players: false,
+        }
+    }
+
+    pub fn with_options(mut self, options: Vec<String>) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn visible_to_players(mut self) -> Self {
+        self.visible_to_players = true;
+        self
+    }
+
+    /// Trim the label and options, turn the key into `snake_case`, and drop
+    /// options from fields that aren't selects.
+    pub fn normalized(mut self) -> Self {
+        self.key = self
+            .key
+            .trim()
+            .to_lowercase()
+            .split(|c: char| c.is_whitespace() || c == '-')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("_");
+        self.label = self.label.trim().to_string();
+        if self.field_type == CustomFieldType::Select {
+            let mut options: Vec<String> = Vec::new();
+            for option in self.options.iter().map(|o| o.trim()) {
+                if !option.is_empty() && !options.iter().any(|o| o == option) {
+                    options.push(option.to_string());
+                }
+            }
+            self.options = options;
+        } else {
+            self.options.clear();
+        }
+        self
+    }
+
+    /// Check a value has the right shape for this field.
+    pub fn check_value(&self, value: &CustomFieldValue) -> Result<(), String> {
+        match (self.field_type, value) {
+            (CustomFieldType::Text, CustomFieldValue::Text(text)) => {
+                if text.chars().count() > MAX_CUSTOM_FIELD_TEXT_LEN {
+                    return Err(format!(
+                        "{} is longer than {MAX_CUSTOM_FIELD_TEXT_LEN} characters",
+                        self.label
+                    ));
+                }
+                Ok(())
+            }
+            (CustomFieldType::Number, CustomFieldValue::Number(n)) if n.is_finite() => Ok(()),
+            (CustomFieldType::Select, CustomFieldValue::Text(option))
+                if self.options.contains(option) =>
+            {
+                Ok(())
+            }
+            (CustomFieldType::Select, _) => Err(format!(
+                "{} must be one of: {}",
+                self.label,
+                self.options.join(", ")
+            )),
+            (field_type, _) => Err(format!("{} must be a {field_type} value", self.label)),
+        }
+    }
+}
+
+/// Check a world's field schema: valid keys and labels, supported entity
+/// types, options on every select, no duplicate keys per entity type.
+pub fn validate_custom_fields(fields: &[CustomFieldDefinition]) -> Result<(), String> {
+    if fields.len() > MAX_CUSTOM_FIELDS {
+        return Err(format!(
+            "a world can have at most {MAX_CUSTOM_FIELDS} custom fields"
+        ));
+    }
+    for (i, field) in fields.iter().enumerate() {
+        if field.key.is_empty()
+            || !field
+                .key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(format!(
+                "field key {:?} may only use lowercase letters, digits and _",
+                field.key
+            ));
+        }
+        if field.label.trim().is_empty() {
+            return Err(format!("field {:?} needs a label", field.key));
+        }
+        if !CUSTOM_FIELD_ENTITY_TYPES.contains(&field.entity_type) {
+            return Err(format!(
+                "{} entities can't have custom fields",
+                field.entity_type
+            ));
+        }
+        if field.field_type == CustomFieldType::Select {
+            if field.options.is_empty() {
+                return Err(format!("select field {:?} needs options", field.label));
+            }
+            if field.options.len() > MAX_CUSTOM_FIELD_OPTIONS {
+                return Err(format!(
+                    "select field {:?} has more than {MAX_CUSTOM_FIELD_OPTIONS} options",
+                    field.label
+                ));
+            }
+        }
+        if fields[..i]
+            .iter()
+            .any(|f| f.entity_type == field.entity_type && f.key == field.key)
+        {
+            return Err(format!(
+                "duplicate {} field key {:?}",
+                field.entity_type, field.key
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check values for one entity against the world's schema: every key must be
+/// a field defined for `entity_type`, and every value must fit its field.
+pub fn validate_custom_field_values(
+    fields: &[CustomFieldDefinition],
+    entity_type: EntityType,
+    values: &CustomFieldValues,
+) -> Result<(), String> {
+    for (key, value) in values {
+        let field = fields
+            .iter()
+            .find(|f| f.entity_type == entity_type && &f.key == key)
+            .ok_or_else(|| format!("no {entity_type} field {key:?}"))?;
+        field.check_value(value)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn faction() -> CustomFieldDefinition {
+        CustomFieldDefinition::new(
+            "faction",
+            "Faction",
+            EntityType::Character,
+            CustomFieldType::Select,
+        )
+        .with_options(vec!["Crows".to_string(), "Lampblacks".to_string()])
+    }
+
+    #[test]
+    fn test_normalized_slugs_key_and_dedupes_options() {
+        let field = CustomFieldDefinition::new(
+            " Faction Standing ",
+            " Standing ",
+            EntityType::Character,
+            CustomFieldType::Select,
+        )
+        .with_options(vec![" A ".to_string(), "A".to_string(), "".to_string()])
+        .normalized();
+        assert_eq!(field.key, "faction_standing");
+        assert_eq!(field.label, "Standing");
+        assert_eq!(field.options, vec!["A".to_string()]);
+
+        let field = CustomFieldDefinition::new(
+            "heat",
+            "Heat",
+            EntityType::Location,
+            CustomFieldType::Number,
+        )
+        .with_options(vec!["x".to_string()])
+        .normalized();
+        assert!(field.options.is_empty());
+    }
+
+    #[test]
+    fn test_validate_fields_rejects_bad_schemas() {
+        assert!(validate_custom_fields(&[faction()]).is_ok());
+        assert!(validate_custom_fields(&[faction(), faction()]).is_err());
+        assert!(validate_custom_fields(&[faction().with_options(Vec::new())]).is_err());
+
+        let scene_field =
+            CustomFieldDefinition::new("mood", "Mood", EntityType::Scene, CustomFieldType::Text);
+        assert!(validate_custom_fields(&[scene_field]).is_err());
+
+        let bad_key = CustomFieldDefinition::new(
+            "Heat!",
+            "Heat",
+            EntityType::Location,
+            CustomFieldType::Number,
+        );
+        assert!(validate_custom_fields(&[bad_key]).is_err());
+
+        // The same key may be reused on another entity type
+        let mut location_faction = faction();
+        location_faction.entity_type = EntityType::Location;
+        assert!(validate_custom_fields(&[faction(), location_faction]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_values_checks_types_and_keys() {
+        let heat = CustomFieldDefinition::new(
+            "heat",
+            "Heat",
+            EntityType::Character,
+            CustomFieldType::Number,
+        );
+        let fields = vec![faction(), heat];
+
+        let ok = CustomFieldValues::from([
+            (
+                "faction".to_string(),
+                CustomFieldValue::Text("Crows".to_string()),
+            ),
+            ("heat".to_string(), CustomFieldValue::Number(3.0)),
+        ]);
+        assert!(validate_custom_field_values(&fields, EntityType::Character, &ok).is_ok());
+
+        let unknown_option = CustomFieldValues::from([(
+            "faction".to_string(),
+            CustomFieldValue::Text("Bluecoats".to_string()),
+        )]);
+        assert!(
+            validate_custom_field_values(&fields, EntityType::Character, &unknown_option).is_err()
+        );
+
+        let wrong_type = CustomFieldValues::from([(
+            "heat".to_string(),
+            CustomFieldValue::Text("3".to_string()),
+        )]);
+        assert!(validate_custom_field_values(&fields, EntityType::Character, &wrong_type).is_err());
+
+        // Character fields don't apply to locations
+        assert!(validate_custom_field_values(&fields, EntityType::Location, &ok).is_err());
+    }
+
+    #[test]
+    fn test_values_round_trip_untagged() {
+        let values: CustomFieldValues =
+            serde_json::from_str(r#"{"heat": 2.5, "faction": "Crows"}"#).unwrap();
+        assert_eq!(values["heat"], CustomFieldValue::Number(2.5));
+        assert_eq!(
+            values["faction"],
+            CustomFieldValue::Text("Crows".to_string())
+        );
+    }
+}
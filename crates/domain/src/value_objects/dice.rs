@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
 
-use super::DiceSystem;
+use super::{DiceExpression, DiceSystem, RollBreakdown};
 
 /// Error when parsing a dice formula
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
@@ -35,6 +35,9 @@ pub enum DiceParseError {
     /// Modifier overflow
     #[error("Modifier value overflow")]
     ModifierOverflow,
+    /// A term asks for more dice than the roller allows
+    #[error("At most {0} dice per term")]
+    TooManyDice(u32),
 }
 
 /// A parsed dice formula like "2d6+3"
@@ -438,6 +441,26 @@ impl DiceRollInput {
             }
         }
     }
+
+    /// Roll the input as a full dice expression (`4d6kh3`, `d20adv+5`, ...).
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_modifier` - Added as a constant term; ignored for manual results.
+    /// * `rng` - A function that generates a random number in range [min, max] (inclusive).
+    pub fn roll<F>(&self, skill_modifier: i32, rng: F) -> Result<RollBreakdown, DiceParseError>
+    where
+        F: FnMut(i32, i32) -> i32,
+    {
+        match self {
+            Self::Formula(expression) => {
+                let mut expression = DiceExpression::parse(expression)?;
+                expression.add_modifier(skill_modifier);
+                Ok(expression.roll(rng))
+            }
+            Self::ManualResult(total) => Ok(RollBreakdown::manual(*total)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,770 @@
+//! Dice expressions - the full roll notation
+//!
+//! [`DiceFormula`](super::DiceFormula) covers the plain `XdY+Z`. Dice
+//! expressions add what tabletop systems actually roll:
+//!
+//! - Several terms, added or subtracted: `2d8+1d6+3`, `1d20+5-1d4`
+//! - Keep or drop dice: `4d6kh3` (`k3` for short), `2d20kl1`, `4d6dl1`, `5d6dh2`
+//! - Exploding dice: `3d6!` rolls again for every die showing its maximum
+//! - Advantage and disadvantage: `d20adv+5`, `d20dis`
+//! - Pool counting: `5d10>=8` or `3d6>5` counts dice that hit the target, as
+//!   in Blades in the Dark or World of Darkness
+//! - Fate dice (`4dF`) and percentile dice (`d%`)
+//!
+//! Like `DiceFormula::roll`, [`DiceExpression::roll`] takes the random number
+//! generator as a function so results can be replayed in tests.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::DiceParseError;
+
+/// Most dice a single term may roll, before explosions
+pub const MAX_DICE_PER_TERM: u32 = 100;
+/// Largest die an expression may use
+pub const MAX_DIE_SIDES: u32 = 1000;
+/// Most extra dice explosions may add to a single term
+pub const MAX_EXPLOSIONS: u32 = 100;
+/// Most terms an expression may have
+pub const MAX_TERMS: usize = 20;
+/// Largest constant or pool target, so totals stay well inside `i32`
+pub const MAX_CONSTANT: i32 = 10_000;
+
+/// Kind of die in a pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DieKind {
+    /// A die numbered 1 to N
+    Numbered(u32),
+    /// A Fate die: -1, 0 or +1
+    Fate,
+}
+
+impl DieKind {
+    fn max(&self) -> i32 {
+        match self {
+            DieKind::Numbered(sides) => *sides as i32,
+            DieKind::Fate => 1,
+        }
+    }
+
+    fn roll<F>(&self, rng: &mut F) -> i32
+    where
+        F: FnMut(i32, i32) -> i32,
+    {
+        match self {
+            DieKind::Numbered(sides) => rng(1, *sides as i32),
+            DieKind::Fate => rng(1, 3) - 2,
+        }
+    }
+}
+
+/// Which dice of a pool count toward its value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeepRule {
+    Highest(u32),
+    Lowest(u32),
+    DropHighest(u32),
+    DropLowest(u32),
+    /// Roll one die twice and keep the higher
+    Advantage,
+    /// Roll one die twice and keep the lower
+    Disadvantage,
+}
+
+/// A group of identical dice, e.g. `4d6kh3` or `5d10>=8`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DicePool {
+    pub count: u32,
+    pub die: DieKind,
+    pub keep: Option<KeepRule>,
+    /// A die showing its maximum rolls another die
+    pub explode: bool,
+    /// Count kept dice at or above this value instead of summing them
+    pub success_at: Option<i32>,
+}
+
+/// One term of an expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiceTerm {
+    Dice(DicePool),
+    Constant(i32),
+}
+
+/// A term and whether it is subtracted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedTerm {
+    pub negative: bool,
+    pub term: DiceTerm,
+}
+
+/// A parsed dice expression like `4d6kh3+2` or `d20adv+5`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiceExpression {
+    pub terms: Vec<SignedTerm>,
+}
+
+impl DiceExpression {
+    /// Parse an expression. Case and whitespace are ignored.
+    pub fn parse(input: &str) -> Result<Self, DiceParseError> {
+        let normalized: String = input
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+        if normalized.is_empty() {
+            return Err(DiceParseError::Empty);
+        }
+
+        let mut parser = Parser {
+            input: normalized.as_bytes(),
+            pos: 0,
+        };
+        let mut terms = Vec::new();
+        let mut negative = parser.eat("-");
+        if !negative {
+            parser.eat("+");
+        }
+        loop {
+            terms.push(SignedTerm {
+                negative,
+                term: parser.term()?,
+            });
+            if terms.len() > MAX_TERMS {
+                return Err(DiceParseError::InvalidFormat(format!(
+                    "At most {} terms per roll",
+                    MAX_TERMS
+                )));
+            }
+            if parser.eat("+") {
+                negative = false;
+            } else if parser.eat("-") {
+                negative = true;
+            } else if parser.done() {
+                break;
+            } else {
+                return Err(parser.unexpected());
+            }
+        }
+
+        if !terms.iter().any(|t| matches!(t.term, DiceTerm::Dice(_))) {
+            return Err(DiceParseError::InvalidFormat(format!(
+                "No dice to roll in '{}'",
+                normalized
+            )));
+        }
+        Ok(Self { terms })
+    }
+
+    /// Add a flat modifier, e.g. a character's skill bonus, as a constant
+    /// term capped at [`MAX_CONSTANT`]. Zero adds nothing.
+    pub fn add_modifier(&mut self, modifier: i32) {
+        if modifier != 0 {
+            self.terms.push(SignedTerm {
+                negative: modifier < 0,
+                term: DiceTerm::Constant(modifier.saturating_abs().min(MAX_CONSTANT)),
+            });
+        }
+    }
+
+    /// Roll every term.
+    ///
+    /// `rng` returns a number in `[min, max]` (inclusive), as for
+    /// `DiceFormula::roll`.
+    pub fn roll<F>(&self, mut rng: F) -> RollBreakdown
+    where
+        F: FnMut(i32, i32) -> i32,
+    {
+        let terms: Vec<RolledTerm> = self
+            .terms
+            .iter()
+            .map(|signed| match &signed.term {
+                DiceTerm::Dice(pool) => roll_pool(pool, signed.negative, &mut rng),
+                DiceTerm::Constant(value) => RolledTerm {
+                    notation: value.to_string(),
+                    negative: signed.negative,
+                    dice: Vec::new(),
+                    value: *value,
+                    counts_successes: false,
+                },
+            })
+            .collect();
+
+        let counts_successes = terms.iter().any(|t| t.counts_successes);
+        let successes = counts_successes.then(|| {
+            terms
+                .iter()
+                .filter(|t| t.counts_successes)
+                .map(|t| t.value.max(0) as u32)
+                .fold(0, u32::saturating_add)
+        });
+        RollBreakdown {
+            expression: self.to_string(),
+            total: terms
+                .iter()
+                .map(RolledTerm::signed_value)
+                .fold(0, i32::saturating_add),
+            successes,
+            terms,
+        }
+    }
+}
+
+impl fmt::Display for DiceExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, signed) in self.terms.iter().enumerate() {
+            if signed.negative {
+                write!(f, "-")?;
+            } else if index > 0 {
+                write!(f, "+")?;
+            }
+            match &signed.term {
+                DiceTerm::Dice(pool) => write!(f, "{}", pool)?,
+                DiceTerm::Constant(value) => write!(f, "{}", value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DicePool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.die {
+            DieKind::Numbered(sides) => write!(f, "{}d{}", self.count, sides)?,
+            DieKind::Fate => write!(f, "{}dF", self.count)?,
+        }
+        match self.keep {
+            Some(KeepRule::Highest(n)) => write!(f, "kh{}", n)?,
+            Some(KeepRule::Lowest(n)) => write!(f, "kl{}", n)?,
+            Some(KeepRule::DropHighest(n)) => write!(f, "dh{}", n)?,
+            Some(KeepRule::DropLowest(n)) => write!(f, "dl{}", n)?,
+            Some(KeepRule::Advantage) => write!(f, "adv")?,
+            Some(KeepRule::Disadvantage) => write!(f, "dis")?,
+            None => {}
+        }
+        if self.explode {
+            write!(f, "!")?;
+        }
+        if let Some(target) = self.success_at {
+            write!(f, ">={}", target)?;
+        }
+        Ok(())
+    }
+}
+
+fn roll_pool<F>(pool: &DicePool, negative: bool, rng: &mut F) -> RolledTerm
+where
+    F: FnMut(i32, i32) -> i32,
+{
+    let count = match pool.keep {
+        Some(KeepRule::Advantage | KeepRule::Disadvantage) => 2,
+        _ => pool.count,
+    };
+    let mut dice = Vec::with_capacity(count as usize);
+    let mut explosions = 0;
+    for _ in 0..count {
+        let mut value = pool.die.roll(rng);
+        dice.push(RolledDie {
+            value,
+            kept: true,
+            exploded: false,
+        });
+        while pool.explode && value == pool.die.max() && explosions < MAX_EXPLOSIONS {
+            explosions += 1;
+            value = pool.die.roll(rng);
+            dice.push(RolledDie {
+                value,
+                kept: true,
+                exploded: true,
+            });
+        }
+    }
+
+    // Which dice drop, by rank: highest first
+    let mut ranked: Vec<usize> = (0..dice.len()).collect();
+    ranked.sort_by_key(|&i| std::cmp::Reverse(dice[i].value));
+    let len = dice.len();
+    let dropped: Vec<usize> = match pool.keep {
+        Some(KeepRule::Highest(n)) => ranked[(n as usize).min(len)..].to_vec(),
+        Some(KeepRule::Lowest(n)) => ranked[..len - (n as usize).min(len)].to_vec(),
+        Some(KeepRule::DropHighest(n)) => ranked[..(n as usize).min(len)].to_vec(),
+        Some(KeepRule::DropLowest(n)) => ranked[len - (n as usize).min(len)..].to_vec(),
+        Some(KeepRule::Advantage) => ranked[1..].to_vec(),
+        Some(KeepRule::Disadvantage) => ranked[..len - 1].to_vec(),
+        None => Vec::new(),
+    };
+    for index in dropped {
+        dice[index].kept = false;
+    }
+
+    let kept = dice.iter().filter(|d| d.kept);
+    let value = match pool.success_at {
+        Some(target) => kept.filter(|d| d.value >= target).count() as i32,
+        None => kept.map(|d| d.value).fold(0, i32::saturating_add),
+    };
+    RolledTerm {
+        notation: pool.to_string(),
+        negative,
+        dice,
+        value,
+        counts_successes: pool.success_at.is_some(),
+    }
+}
+
+/// A constant or target as an `i32`, if it is at most [`MAX_CONSTANT`]
+fn bounded(value: u32) -> Result<i32, DiceParseError> {
+    i32::try_from(value)
+        .ok()
+        .filter(|value| *value <= MAX_CONSTANT)
+        .ok_or(DiceParseError::ModifierOverflow)
+}
+
+/// Hand-written recursive descent over the normalized input, so the domain
+/// doesn't need a regex or parser dependency
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn done(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.input[self.pos..].starts_with(token.as_bytes()) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn number(&mut self) -> Result<Option<u32>, DiceParseError> {
+        let start = self.pos;
+        while self.pos < self.input.len() && self.input[self.pos].is_ascii_digit() {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Ok(None);
+        }
+        let digits = std::str::from_utf8(&self.input[start..self.pos]).unwrap_or_default();
+        digits
+            .parse()
+            .map(Some)
+            .map_err(|_| DiceParseError::ModifierOverflow)
+    }
+
+    fn unexpected(&self) -> DiceParseError {
+        let rest = String::from_utf8_lossy(&self.input[self.pos..]);
+        DiceParseError::InvalidFormat(format!("Unexpected '{}'", rest))
+    }
+
+    fn term(&mut self) -> Result<DiceTerm, DiceParseError> {
+        let number = self.number()?;
+        if !self.eat("d") {
+            return match number {
+                Some(value) => bounded(value).map(DiceTerm::Constant),
+                None if self.done() => Err(DiceParseError::InvalidFormat(
+                    "Expected a number or dice after the sign".to_string(),
+                )),
+                None => Err(self.unexpected()),
+            };
+        }
+
+        let count = number.unwrap_or(1);
+        if count == 0 {
+            return Err(DiceParseError::InvalidDiceCount);
+        }
+        if count > MAX_DICE_PER_TERM {
+            return Err(DiceParseError::TooManyDice(MAX_DICE_PER_TERM));
+        }
+
+        let die = if self.eat("f") {
+            DieKind::Fate
+        } else if self.eat("%") {
+            DieKind::Numbered(100)
+        } else {
+            match self.number()? {
+                Some(sides) if sides < 2 => return Err(DiceParseError::InvalidDieSize),
+                Some(sides) if sides > MAX_DIE_SIDES => {
+                    return Err(DiceParseError::InvalidFormat(format!(
+                        "Dice can have at most {} sides",
+                        MAX_DIE_SIDES
+                    )))
+                }
+                Some(sides) => DieKind::Numbered(sides),
+                None => {
+                    return Err(DiceParseError::InvalidFormat(
+                        "Expected the number of sides after 'd'".to_string(),
+                    ))
+                }
+            }
+        };
+
+        let mut pool = DicePool {
+            count,
+            die,
+            keep: None,
+            explode: false,
+            success_at: None,
+        };
+        while !self.done() && !matches!(self.input[self.pos], b'+' | b'-') {
+            if self.eat("!") {
+                if pool.die == DieKind::Fate {
+                    return Err(DiceParseError::InvalidFormat(
+                        "Fate dice can't explode".to_string(),
+                    ));
+                }
+                pool.explode = true;
+            } else if self.eat(">=") {
+                pool.success_at = Some(self.target()?);
+            } else if self.eat(">") {
+                let target = self.target()?.checked_add(1);
+                pool.success_at = Some(target.ok_or(DiceParseError::ModifierOverflow)?);
+            } else {
+                let rule = self.keep_rule(count)?;
+                if pool.keep.replace(rule).is_some() {
+                    return Err(DiceParseError::InvalidFormat(
+                        "Only one keep, drop or advantage rule per dice".to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(DiceTerm::Dice(pool))
+    }
+
+    fn target(&mut self) -> Result<i32, DiceParseError> {
+        match self.number()? {
+            Some(target) => bounded(target),
+            None => Err(DiceParseError::InvalidFormat(
+                "Expected a target number after '>'".to_string(),
+            )),
+        }
+    }
+
+    fn keep_rule(&mut self, count: u32) -> Result<KeepRule, DiceParseError> {
+        if self.eat("adv") || self.eat("dis") {
+            let advantage = self.input[self.pos - 3] == b'a';
+            if count != 1 {
+                return Err(DiceParseError::InvalidFormat(
+                    "Advantage and disadvantage apply to a single die".to_string(),
+                ));
+            }
+            return Ok(if advantage {
+                KeepRule::Advantage
+            } else {
+                KeepRule::Disadvantage
+            });
+        }
+
+        // "kh" and "kl" go before the bare "k", which would swallow their 'k'
+        let rule: fn(u32) -> KeepRule = if self.eat("kh") {
+            KeepRule::Highest
+        } else if self.eat("kl") {
+            KeepRule::Lowest
+        } else if self.eat("k") {
+            KeepRule::Highest
+        } else if self.eat("dh") {
+            KeepRule::DropHighest
+        } else if self.eat("dl") {
+            KeepRule::DropLowest
+        } else {
+            return Err(self.unexpected());
+        };
+        let n = self.number()?.unwrap_or(1);
+        if n == 0 || n > count {
+            return Err(DiceParseError::InvalidFormat(format!(
+                "Can only keep or drop 1 to {} of {} dice",
+                count, count
+            )));
+        }
+        Ok(rule(n))
+    }
+}
+
+/// A single die as rolled
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RolledDie {
+    pub value: i32,
+    /// Whether the die counts toward the term
+    pub kept: bool,
+    /// Whether an exploding die added this one
+    pub exploded: bool,
+}
+
+/// One rolled term of an expression
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RolledTerm {
+    /// The term as written, normalized (e.g. "4d6kh3" or "2")
+    pub notation: String,
+    /// Whether the term is subtracted
+    pub negative: bool,
+    /// Every die rolled, including dropped and exploded ones. Empty for
+    /// constants.
+    pub dice: Vec<RolledDie>,
+    /// The term's value before its sign: the kept dice summed, the number
+    /// of successes for a pool, or the constant
+    pub value: i32,
+    /// Whether `value` counts successes
+    pub counts_successes: bool,
+}
+
+impl RolledTerm {
+    fn signed_value(&self) -> i32 {
+        if self.negative {
+            -self.value
+        } else {
+            self.value
+        }
+    }
+
+    fn is_dice(&self) -> bool {
+        !self.dice.is_empty()
+    }
+}
+
+/// Every die of a roll, term by term, and the result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RollBreakdown {
+    /// The expression, normalized (e.g. "1d20adv+5")
+    pub expression: String,
+    pub terms: Vec<RolledTerm>,
+    pub total: i32,
+    /// Successes counted by dice pools, if the roll has any
+    pub successes: Option<u32>,
+}
+
+impl RollBreakdown {
+    /// A result entered by hand, for physical dice
+    pub fn manual(total: i32) -> Self {
+        Self {
+            expression: String::new(),
+            terms: Vec::new(),
+            total,
+            successes: None,
+        }
+    }
+
+    pub fn is_manual(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// The dice terms' part of the total. Manual results count entirely as
+    /// dice.
+    pub fn dice_total(&self) -> i32 {
+        if self.is_manual() {
+            return self.total;
+        }
+        self.terms
+            .iter()
+            .filter(|t| t.is_dice())
+            .map(RolledTerm::signed_value)
+            .fold(0, i32::saturating_add)
+    }
+
+    /// The constant terms' part of the total
+    pub fn modifier(&self) -> i32 {
+        self.terms
+            .iter()
+            .filter(|t| !t.is_dice())
+            .map(RolledTerm::signed_value)
+            .fold(0, i32::saturating_add)
+    }
+
+    /// The dice that count, in roll order
+    pub fn kept_rolls(&self) -> Vec<i32> {
+        self.terms
+            .iter()
+            .flat_map(|t| t.dice.iter())
+            .filter(|d| d.kept)
+            .map(|d| d.value)
+            .collect()
+    }
+
+    /// Human-readable breakdown, e.g. "4d6kh3[6, 5, 3, (1)] + 2 = 16".
+    /// Dropped dice are in parentheses and exploded ones marked with `!`.
+    pub fn text(&self) -> String {
+        if self.is_manual() {
+            return format!("Manual: {}", self.total);
+        }
+
+        let mut text = String::new();
+        for (index, term) in self.terms.iter().enumerate() {
+            match (index, term.negative) {
+                (0, true) => text.push('-'),
+                (0, false) => {}
+                (_, true) => text.push_str(" - "),
+                (_, false) => text.push_str(" + "),
+            }
+            text.push_str(&term.notation);
+            if term.is_dice() {
+                let dice: Vec<String> = term
+                    .dice
+                    .iter()
+                    .map(|d| {
+                        let mark = if d.exploded { "!" } else { "" };
+                        if d.kept {
+                            format!("{}{}", d.value, mark)
+                        } else {
+                            format!("({}{})", d.value, mark)
+                        }
+                    })
+                    .collect();
+                text.push_str(&format!("[{}]", dice.join(", ")));
+            }
+        }
+
+        match self.successes {
+            Some(1) if self.total == 1 => format!("{} = 1 success", text),
+            Some(n) if self.total == n as i32 => format!("{} = {} successes", text, n),
+            _ => format!("{} = {}", text, self.total),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// RNG replaying fixed values
+    fn replay(values: &[i32]) -> impl FnMut(i32, i32) -> i32 + '_ {
+        let mut values = values.iter();
+        move |min, max| (*values.next().expect("enough rolls")).clamp(min, max)
+    }
+
+    #[test]
+    fn keep_highest_drops_the_rest() {
+        let expr = DiceExpression::parse("4d6kh3 + 2").unwrap();
+        assert_eq!(expr.to_string(), "4d6kh3+2");
+        let roll = expr.roll(replay(&[3, 6, 1, 5]));
+        assert_eq!(roll.total, 16);
+        assert_eq!(roll.dice_total(), 14);
+        assert_eq!(roll.modifier(), 2);
+        assert_eq!(roll.kept_rolls(), vec![3, 6, 5]);
+        assert_eq!(roll.text(), "4d6kh3[3, 6, (1), 5] + 2 = 16");
+
+        let roll = DiceExpression::parse("4d6dl1")
+            .unwrap()
+            .roll(replay(&[3, 6, 1, 5]));
+        assert_eq!(roll.total, 14);
+    }
+
+    #[test]
+    fn keep_lowest_drops_the_rest() {
+        let expr = DiceExpression::parse("2d20kl1").unwrap();
+        assert!(matches!(
+            &expr.terms[0].term,
+            DiceTerm::Dice(DicePool {
+                keep: Some(KeepRule::Lowest(1)),
+                ..
+            })
+        ));
+        assert_eq!(expr.to_string(), "2d20kl1");
+        assert_eq!(DiceExpression::parse(&expr.to_string()).unwrap(), expr);
+        let roll = expr.roll(replay(&[14, 5]));
+        assert_eq!(roll.total, 5);
+        assert_eq!(roll.text(), "2d20kl1[(14), 5] = 5");
+
+        let short = DiceExpression::parse("4d6k3").unwrap();
+        assert_eq!(short.to_string(), "4d6kh3");
+    }
+
+    #[test]
+    fn advantage_and_disadvantage_roll_twice() {
+        let adv = DiceExpression::parse("d20adv+5").unwrap();
+        assert_eq!(adv.roll(replay(&[7, 15])).total, 20);
+        let dis = DiceExpression::parse("1D20 DIS").unwrap();
+        assert_eq!(dis.roll(replay(&[7, 15])).total, 7);
+        assert!(DiceExpression::parse("2d20adv").is_err());
+    }
+
+    #[test]
+    fn exploding_dice_roll_again_on_their_maximum() {
+        let roll = DiceExpression::parse("2d6!")
+            .unwrap()
+            .roll(replay(&[6, 6, 2, 4]));
+        assert_eq!(roll.total, 18);
+        assert_eq!(roll.text(), "2d6![6, 6!, 2!, 4] = 18");
+
+        // A die that always explodes stops at the cap
+        let roll = DiceExpression::parse("1d6!").unwrap().roll(|_, max| max);
+        assert_eq!(roll.terms[0].dice.len(), 1 + MAX_EXPLOSIONS as usize);
+    }
+
+    #[test]
+    fn pools_count_successes() {
+        let roll = DiceExpression::parse("5d10>=8")
+            .unwrap()
+            .roll(replay(&[9, 3, 8, 1, 10]));
+        assert_eq!(roll.successes, Some(3));
+        assert_eq!(roll.total, 3);
+        assert_eq!(roll.text(), "5d10>=8[9, 3, 8, 1, 10] = 3 successes");
+
+        // Blades: a 6 on the highest die is a full success
+        let blades = DiceExpression::parse("3d6kh1>5").unwrap();
+        assert_eq!(blades.to_string(), "3d6kh1>=6");
+        assert_eq!(blades.roll(replay(&[2, 6, 4])).successes, Some(1));
+    }
+
+    #[test]
+    fn fate_and_percentile_dice() {
+        let fate = DiceExpression::parse("4dF+2").unwrap();
+        // rng(1, 3) - 2 gives -1, 0, +1
+        assert_eq!(fate.roll(replay(&[1, 2, 3, 3])).total, 3);
+        let percent = DiceExpression::parse("d%").unwrap();
+        assert_eq!(percent.to_string(), "1d100");
+        assert!(DiceExpression::parse("4dF!").is_err());
+    }
+
+    #[test]
+    fn malformed_expressions_are_rejected() {
+        assert_eq!(DiceExpression::parse(" "), Err(DiceParseError::Empty));
+        assert_eq!(
+            DiceExpression::parse("0d6"),
+            Err(DiceParseError::InvalidDiceCount)
+        );
+        assert_eq!(
+            DiceExpression::parse("d1"),
+            Err(DiceParseError::InvalidDieSize)
+        );
+        assert_eq!(
+            DiceExpression::parse("1000d6"),
+            Err(DiceParseError::TooManyDice(MAX_DICE_PER_TERM))
+        );
+        for bad in ["5", "d", "2d6+", "2d6x", "4d6kh5", "4d6kh3kl1", "d20>="] {
+            assert!(DiceExpression::parse(bad).is_err(), "{} parsed", bad);
+        }
+        let roll = DiceExpression::parse("-1d4+1d8-2")
+            .unwrap()
+            .roll(replay(&[3, 8]));
+        assert_eq!(roll.total, 3);
+        assert_eq!(roll.text(), "-1d4[3] + 1d8[8] - 2 = 3");
+    }
+
+    #[test]
+    fn huge_constants_and_targets_are_rejected() {
+        for huge in [
+            "1d6>2147483647",
+            "1d6+2147483647+2147483647",
+            "100d1000!+2147483647",
+            "1d6>=10001",
+        ] {
+            assert_eq!(
+                DiceExpression::parse(huge),
+                Err(DiceParseError::ModifierOverflow),
+                "{} parsed",
+                huge
+            );
+        }
+
+        // The largest allowed terms still add up without overflowing
+        let mut expr = DiceExpression::parse("100d1000!+10000+10000").unwrap();
+        expr.add_modifier(i32::MAX);
+        assert_eq!(expr.to_string(), "100d1000!+10000+10000+10000");
+        let roll = expr.roll(|_, max| max);
+        assert_eq!(roll.total, 200 * 1000 + 30_000);
+    }
+}
@@ -0,0 +1,117 @@
+//! Flashback - a scene played at an earlier date
+//!
+//! While a flashback runs, the world's clock shows the flashback's date and
+//! the flashback region gets its own staging and visual state. The live
+//! clock, staging and region state are kept here and put back when the DM
+//! ends the flashback. Story events recorded meanwhile are tagged
+//! [`FLASHBACK_TAG`].
+
+use chrono::{DateTime, Datelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::{GameTime, RegionId, RegionStateId, StagingId};
+
+/// Tag added to story events recorded during a flashback
+pub const FLASHBACK_TAG: &str = "flashback";
+
+/// Longest flashback title, in characters
+pub const MAX_FLASHBACK_TITLE_LEN: usize = 100;
+
+/// A flashback in progress, and the live state it stands in for
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Flashback {
+    /// Shown to everyone while the flashback runs ("The night of the fire")
+    pub title: String,
+    /// Where the flashback scene is set
+    pub region_id: RegionId,
+    /// The world clock when the flashback started, restored on exit
+    pub live_time: GameTime,
+    /// The region's staging before the flashback, reactivated on exit
+    #[serde(default)]
+    pub live_staging_id: Option<StagingId>,
+    /// The region's active visual state before the flashback
+    #[serde(default)]
+    pub live_region_state_id: Option<RegionStateId>,
+    pub started_at: DateTime<Utc>,
+}
+
+impl Flashback {
+    /// Check a flashback title, returning it trimmed
+    pub fn validate_title(title: &str) -> Result<String, DomainError> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(DomainError::validation("Flashback title cannot be empty"));
+        }
+        if title.chars().count() > MAX_FLASHBACK_TITLE_LEN {
+            return Err(DomainError::validation(format!(
+                "Flashback title cannot exceed {} characters",
+                MAX_FLASHBACK_TITLE_LEN
+            )));
+        }
+        Ok(title.to_string())
+    }
+
+    /// The flashback's clock: `years_back` years before `live`, on the given
+    /// day of that year and hour. Must be earlier than `live`.
+    pub fn flashback_time(
+        live: &GameTime,
+        years_back: u32,
+        day: u32,
+        hour: u8,
+    ) -> Result<GameTime, DomainError> {
+        let mut time = live.clone();
+        if years_back > 0 {
+            let current = time.current();
+            let year = current.year() - years_back as i32;
+            // 29 February falls back to the 28th in non-leap years
+            let moved = current
+                .with_year(year)
+                .or_else(|| current.with_day(28).and_then(|d| d.with_year(year)))
+                .ok_or_else(|| DomainError::validation("Flashback year is out of range"))?;
+            time.set_time(moved);
+        }
+        time.set_day_and_hour(day, hour as u32);
+        if time.current() >= live.current() {
+            return Err(DomainError::validation(
+                "A flashback must be set before the current game time",
+            ));
+        }
+        Ok(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn live() -> GameTime {
+        // Day 100, 14:00
+        GameTime::starting_at(Utc.with_ymd_and_hms(2026, 4, 10, 14, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn flashbacks_must_be_in_the_past() {
+        let earlier = Flashback::flashback_time(&live(), 0, 99, 20).expect("earlier day");
+        assert_eq!((earlier.day(), earlier.hour()), (99, 20));
+
+        assert!(Flashback::flashback_time(&live(), 0, 100, 14).is_err());
+        assert!(Flashback::flashback_time(&live(), 0, 120, 8).is_err());
+
+        let years_ago = Flashback::flashback_time(&live(), 3, 120, 8).expect("years back");
+        assert_eq!(years_ago.current().year(), 2023);
+        assert_eq!(years_ago.day(), 120);
+    }
+
+    #[test]
+    fn titles_are_trimmed_and_required() {
+        assert_eq!(
+            Flashback::validate_title("  The night of the fire ").unwrap(),
+            "The night of the fire"
+        );
+        assert!(Flashback::validate_title(" ").is_err());
+        assert!(Flashback::validate_title(&"x".repeat(101)).is_err());
+    }
+}
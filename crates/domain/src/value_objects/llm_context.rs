@@ -33,6 +33,9 @@ pub struct GamePromptRequest {
     pub active_challenges: Vec<ActiveChallengeContext>,
     /// Active narrative events that could be triggered
     pub active_narrative_events: Vec<ActiveNarrativeEventContext>,
+    /// Aliases the player used, with the entities they stand for
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alias_references: Vec<AliasReferenceContext>,
     /// Optional token budget configuration for prompt size limits
     /// When present, the system prompt will be truncated to fit within the budget
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -63,6 +66,10 @@ pub struct PlayerActionContext {
     pub target: Option<String>,
     /// Dialogue content if the action is speech
     pub dialogue: Option<String>,
+    /// The PCs whose lines were merged into `dialogue`, when several spoke
+    /// to the NPC at once
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub speakers: Vec<String>,
 }
 
 /// Context about the current scene
@@ -79,6 +86,12 @@ pub struct SceneContext {
     /// Items visible in the current region (for NPC awareness)
     #[serde(default)]
     pub region_items: Vec<RegionItemContext>,
+    /// How well lit the region is (e.g. "dimly lit; ...")
+    #[serde(default)]
+    pub lighting: Option<String>,
+    /// Noises that carried in from nearby regions lately
+    #[serde(default)]
+    pub recent_sounds: Vec<String>,
 }
 
 /// Context about an item visible in the current region
@@ -92,6 +105,17 @@ pub struct RegionItemContext {
     pub item_type: Option<String>,
 }
 
+/// An alias found in the player's words, and the entity it names
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AliasReferenceContext {
+    /// The alias as the player wrote it
+    pub alias: String,
+    /// The entity's name
+    pub name: String,
+    /// "character", "location", ...
+    pub entity_type: String,
+}
+
 /// Context about the responding character
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterContext {
@@ -100,6 +124,9 @@ pub struct CharacterContext {
     pub character_id: Option<String>,
     /// Character's name
     pub name: String,
+    /// Other names the character goes by ("the Grey Fox")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
     /// Character archetype / personality summary
     pub archetype: String,
     /// Current emotional state (Tier 2 of emotional model)
@@ -4,9 +4,14 @@ mod actantial_context;
 mod activation_rules;
 mod ad_hoc_outcomes;
 mod archetype;
+mod chronology;
+mod co_dm;
 mod comfyui_config;
+mod content_safety;
 mod context_budget;
+mod custom_field;
 mod dice;
+mod dice_expression;
 mod directorial;
 mod game_tools;
 // IDs live in `wrldbldr-domain`
@@ -14,7 +19,12 @@ mod context_budget_enforcement;
 mod dialogue_markers;
 mod disposition;
 mod expression_config;
+mod flashback;
 mod llm_context;
+mod npc_autonomy;
+mod pacing_timer;
+mod parallel_scene;
+mod plugin;
 mod prompt_templates;
 mod quantity;
 mod queue_data;
@@ -22,8 +32,15 @@ mod region;
 mod relationship;
 mod rule_system;
 mod settings;
+mod spotlight;
 mod staging_context;
+mod tag;
+mod tutorial;
+mod typography;
+mod world_features;
+mod world_script;
 mod world_state;
+mod world_theme;
 
 // Activation rules for visual states
 pub use activation_rules::{ActivationEvaluation, ActivationLogic, ActivationRule};
@@ -40,7 +57,15 @@ pub use actantial_context::{
 // Engine-specific archetype with methods (protocol version is simpler wire format)
 pub use archetype::{ArchetypeChange, CampbellArchetype};
 
+pub use chronology::{
+    age_progressions, find_chronology_issues, AgeProgression, ChronologyIssue, LifeStage,
+    AGE_PROGRESSION_MIN_SKIP_DAYS,
+};
+pub use co_dm::{CoDmEscalation, CoDmOutcome, CoDmReview, CoDmSettings, CoDmVerdict};
 pub use comfyui_config::ComfyUIConfig;
+pub use content_safety::{
+    ContentRating, ContentSafetyConfig, SafetySignalLevel, SafetySignalResponse,
+};
 pub use context_budget::{
     count_tokens, exceeds_token_budget, ContextBudgetConfig, ContextCategory, TokenCountMethod,
     TokenCounter,
@@ -48,10 +73,19 @@ pub use context_budget::{
 pub use context_budget_enforcement::{
     ContextBudgetEnforcer, ContextBuilder, EnforcementResult, EnforcementStats,
 };
+pub use custom_field::{
+    validate_custom_field_values, validate_custom_fields, CustomFieldDefinition, CustomFieldType,
+    CustomFieldValue, CustomFieldValues, CUSTOM_FIELD_ENTITY_TYPES, MAX_CUSTOM_FIELDS,
+    MAX_CUSTOM_FIELD_OPTIONS, MAX_CUSTOM_FIELD_TEXT_LEN,
+};
 pub use dialogue_markers::{
     parse_dialogue, parse_dialogue_markers, validate_markers, DialogueMarker, ParsedDialogue,
 };
 pub use dice::{DiceFormula, DiceParseError, DiceRollInput, DiceRollResult};
+pub use dice_expression::{
+    DiceExpression, DicePool, DiceTerm, DieKind, KeepRule, RollBreakdown, RolledDie, RolledTerm,
+    SignedTerm, MAX_DICE_PER_TERM, MAX_DIE_SIDES,
+};
 pub use directorial::{
     DirectorialNotes, NpcMotivation as DomainNpcMotivation, PacingGuidance, ToneGuidance,
 };
@@ -60,12 +94,19 @@ pub use disposition::{
     RelationshipLevel,
 };
 pub use expression_config::ExpressionConfig;
+pub use flashback::{Flashback, FLASHBACK_TAG, MAX_FLASHBACK_TITLE_LEN};
 pub use game_tools::{ChangeAmount, GameTool, InfoImportance, RelationshipChange};
 pub use llm_context::{
-    ActantialActorEntry, ActiveChallengeContext, ActiveNarrativeEventContext, CharacterContext,
-    ConversationTurn, GamePromptRequest, MotivationEntry, MotivationsContext, PlayerActionContext,
-    RegionItemContext, SceneContext, SecretMotivationEntry, SocialRelationEntry,
-    SocialStanceContext,
+    ActantialActorEntry, ActiveChallengeContext, ActiveNarrativeEventContext,
+    AliasReferenceContext, CharacterContext, ConversationTurn, GamePromptRequest, MotivationEntry,
+    MotivationsContext, PlayerActionContext, RegionItemContext, SceneContext,
+    SecretMotivationEntry, SocialRelationEntry, SocialStanceContext,
+};
+pub use npc_autonomy::{NpcAutonomyLevel, NpcAutonomySettings};
+pub use pacing_timer::{PacingCue, PacingTimer, PacingTimerKind, MAX_TIMER_MINUTES};
+pub use parallel_scene::{ParallelScene, MAX_PARALLEL_SCENES, MAX_PARALLEL_SCENE_NAME_LEN};
+pub use plugin::{
+    PluginCapability, PluginEffectSpec, PluginManifest, PluginOutput, PluginToolSpec,
 };
 pub use prompt_templates::{
     all_keys as prompt_template_keys, defaults as prompt_defaults,
@@ -84,6 +125,7 @@ pub use rule_system::{
     DifficultyLadder,
     EffectLevel,
     EffectTickConfig,
+    InjuryRecoveryConfig,
     LadderEntry,
     NarrativeDiceConfig,
     NarrativeDiceType,
@@ -92,26 +134,42 @@ pub use rule_system::{
     NarrativeThresholds,
     Position,
     PositionEffectConfig,
+    ResurrectionConfig,
     RuleSystemConfig,
     RuleSystemType,
     RuleSystemVariant,
+    SkillAliases,
     StatDefinition,
     SuccessComparison,
 };
 pub use settings::{
-    settings_metadata, AppSettings, BatchQueueFailurePolicy, SettingsFieldMetadata,
+    settings_metadata, AppSettings, BatchQueueFailurePolicy, GenerationPreset, LlmProvider,
+    LlmProviderSettings, LlmTask, ModelRouting, ModelTier, ServiceConnections,
+    SettingsFieldMetadata, REDACTED_SECRET,
 };
+pub use spotlight::{Spotlight, DEFAULT_QUIET_AFTER, SPOTLIGHT_HISTORY};
 pub use staging_context::{
     ActiveEventContext, NpcDialogueContext, RollResult, RuleBasedSuggestion, StagingContext,
 };
+pub use tag::{normalize_tag, normalize_tags, MAX_TAGS_PER_ENTITY, MAX_TAG_LEN};
+pub use tutorial::{
+    TutorialAction, TutorialGoal, TutorialScript, TutorialStep, TUTORIAL_FLAG_PREFIX,
+};
+pub use typography::{TextDirection, WorldTypography};
+pub use world_features::{ExperimentalFeature, WorldFeatures};
+pub use world_script::{
+    validate_world_scripts, ScriptHook, ScriptOutput, WorldScript, DEFAULT_SCRIPT_BUDGET,
+    MAX_SCRIPT_BUDGET, MAX_SCRIPT_SOURCE_LEN, MAX_WORLD_SCRIPTS,
+};
 pub use world_state::{ApprovalType, ConversationEntry, PendingApprovalItem, Speaker};
+pub use world_theme::{BackdropFrame, WorldTheme};
 
 // Queue data value objects (pure domain representations)
 pub use queue_data::{
     ApprovalDecisionType, ApprovalRequestData, ApprovalUrgency, AssetGenerationData,
     ChallengeOutcomeData, ChallengeSuggestion, ChallengeSuggestionOutcomes, DmActionData,
-    DmActionType, DmApprovalDecision, LlmRequestData, LlmRequestType, NarrativeEventSuggestion,
-    PlayerActionData, ProposedTool, SuggestionContext,
+    DmActionType, DmApprovalDecision, LlmRequestData, LlmRequestType, MergedLine,
+    NarrativeEventSuggestion, PlayerActionData, ProposedTool, SuggestionContext,
 };
 
 // NOTE: Want has been promoted to an entity (domain/entities/want.rs)
@@ -0,0 +1,271 @@
+//! NPC autonomy - Which NPC responses skip DM approval
+//!
+//! Busy scenes bury the DM in approvals for harmless small talk. A world's
+//! autonomy settings let routine NPC responses go straight to players while
+//! anything consequential (item grants, challenge suggestions, flagged
+//! content) still waits for the DM. Individual NPCs can be given more or
+//! less rope than the world default.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterId;
+
+use super::queue_data::{ApprovalDecisionType, ApprovalRequestData, DmApprovalDecision};
+
+/// How much an NPC may say and do without DM approval
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NpcAutonomyLevel {
+    /// Every response waits for the DM
+    #[default]
+    Supervised,
+    /// Plain dialogue goes through; responses with tool calls wait
+    SmallTalk,
+    /// Dialogue and tool calls go through, except tools the world always
+    /// reviews
+    Trusted,
+    /// Unknown level (for forward compatibility); treated as supervised
+    #[serde(other)]
+    Unknown,
+}
+
+impl NpcAutonomyLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NpcAutonomyLevel::Supervised => "supervised",
+            NpcAutonomyLevel::SmallTalk => "small_talk",
+            NpcAutonomyLevel::Trusted => "trusted",
+            NpcAutonomyLevel::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for NpcAutonomyLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for NpcAutonomyLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "supervised" => Ok(NpcAutonomyLevel::Supervised),
+            "small_talk" | "smalltalk" => Ok(NpcAutonomyLevel::SmallTalk),
+            "trusted" => Ok(NpcAutonomyLevel::Trusted),
+            other => Err(format!("unknown autonomy level: {}", other)),
+        }
+    }
+}
+
+/// A world's NPC autonomy defaults and per-NPC overrides
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct NpcAutonomySettings {
+    /// Level for NPCs without an override
+    pub default_level: NpcAutonomyLevel,
+    /// Tool calls whose name contains any of these (case-insensitive) always
+    /// wait for the DM, whatever the NPC's level
+    pub always_review_tools: Vec<String>,
+    /// Per-NPC levels that replace the world default
+    pub npc_levels: HashMap<CharacterId, NpcAutonomyLevel>,
+}
+
+impl Default for NpcAutonomySettings {
+    fn default() -> Self {
+        Self {
+            default_level: NpcAutonomyLevel::Supervised,
+            always_review_tools: vec!["item".to_string(), "grant".to_string(), "give".to_string()],
+            npc_levels: HashMap::new(),
+        }
+    }
+}
+
+impl NpcAutonomySettings {
+    /// The autonomy level an NPC acts with
+    pub fn level_for(&self, npc_id: Option<CharacterId>) -> NpcAutonomyLevel {
+        npc_id
+            .and_then(|id| self.npc_levels.get(&id).copied())
+            .unwrap_or(self.default_level)
+    }
+
+    /// Give an NPC its own level, or `None` to fall back to the world default
+    pub fn set_npc_level(&mut self, npc_id: CharacterId, level: Option<NpcAutonomyLevel>) {
+        match level {
+            Some(level) => {
+                self.npc_levels.insert(npc_id, level);
+            }
+            None => {
+                self.npc_levels.remove(&npc_id);
+            }
+        }
+    }
+
+    /// Whether a tool call must always be seen by the DM
+    pub fn always_reviews(&self, tool_name: &str) -> bool {
+        let name = tool_name.to_lowercase();
+        self.always_review_tools
+            .iter()
+            .map(|pattern| pattern.trim().to_lowercase())
+            .any(|pattern| !pattern.is_empty() && name.contains(&pattern))
+    }
+
+    /// The decision to apply without the DM, if the NPC may act on its own.
+    ///
+    /// Only NPC responses qualify, and never ones flagged by the content
+    /// safety filter or carrying a challenge or narrative event suggestion.
+    pub fn auto_decision(&self, approval: &ApprovalRequestData) -> Option<DmApprovalDecision> {
+        if approval.decision_type != ApprovalDecisionType::NpcResponse
+            || !approval.safety_warnings.is_empty()
+            || approval.challenge_suggestion.is_some()
+            || approval.narrative_event_suggestion.is_some()
+        {
+            return None;
+        }
+
+        match self.level_for(approval.npc_id) {
+            NpcAutonomyLevel::SmallTalk if approval.proposed_tools.is_empty() => {
+                Some(DmApprovalDecision::Accept)
+            }
+            NpcAutonomyLevel::Trusted => {
+                if approval
+                    .proposed_tools
+                    .iter()
+                    .any(|tool| self.always_reviews(&tool.name))
+                {
+                    return None;
+                }
+                if approval.proposed_tools.is_empty() {
+                    return Some(DmApprovalDecision::Accept);
+                }
+                Some(DmApprovalDecision::AcceptWithModification {
+                    modified_dialogue: approval.proposed_dialogue.clone(),
+                    approved_tools: approval
+                        .proposed_tools
+                        .iter()
+                        .map(|tool| tool.id.clone())
+                        .collect(),
+                    rejected_tools: vec![],
+                    item_recipients: HashMap::new(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::queue_data::{ApprovalUrgency, ProposedTool};
+    use crate::WorldId;
+    use uuid::Uuid;
+
+    fn approval(npc_id: CharacterId, tools: &[&str]) -> ApprovalRequestData {
+        ApprovalRequestData {
+            world_id: WorldId::new(),
+            source_action_id: Uuid::new_v4(),
+            decision_type: ApprovalDecisionType::NpcResponse,
+            urgency: ApprovalUrgency::AwaitingPlayer,
+            pc_id: None,
+            npc_id: Some(npc_id),
+            npc_name: "Marta".to_string(),
+            proposed_dialogue: "Fine weather today.".to_string(),
+            internal_reasoning: String::new(),
+            proposed_tools: tools
+                .iter()
+                .enumerate()
+                .map(|(i, name)| ProposedTool {
+                    id: format!("call-{}", i),
+                    name: name.to_string(),
+                    description: String::new(),
+                    arguments: serde_json::json!({}),
+                })
+                .collect(),
+            retry_count: 0,
+            challenge_suggestion: None,
+            narrative_event_suggestion: None,
+            challenge_outcome: None,
+            player_dialogue: Some("Nice day?".to_string()),
+            scene_id: None,
+            location_id: None,
+            game_time: None,
+            topics: vec![],
+            conversation_id: None,
+            safety_warnings: vec![],
+            merged_lines: vec![],
+        }
+    }
+
+    #[test]
+    fn npc_override_replaces_world_default() {
+        let npc = CharacterId::new();
+        let mut settings = NpcAutonomySettings::default();
+        assert!(settings.auto_decision(&approval(npc, &[])).is_none());
+
+        settings.set_npc_level(npc, Some(NpcAutonomyLevel::SmallTalk));
+        assert!(matches!(
+            settings.auto_decision(&approval(npc, &[])),
+            Some(DmApprovalDecision::Accept)
+        ));
+        assert!(settings
+            .auto_decision(&approval(CharacterId::new(), &[]))
+            .is_none());
+
+        settings.set_npc_level(npc, None);
+        assert_eq!(settings.level_for(Some(npc)), NpcAutonomyLevel::Supervised);
+    }
+
+    #[test]
+    fn small_talk_leaves_tool_calls_for_the_dm() {
+        let settings = NpcAutonomySettings {
+            default_level: NpcAutonomyLevel::SmallTalk,
+            ..Default::default()
+        };
+        let approval = approval(CharacterId::new(), &["plugin:weather:set"]);
+        assert!(settings.auto_decision(&approval).is_none());
+    }
+
+    #[test]
+    fn trusted_npcs_still_need_review_for_item_grants() {
+        let settings = NpcAutonomySettings {
+            default_level: NpcAutonomyLevel::Trusted,
+            ..Default::default()
+        };
+        let npc = CharacterId::new();
+
+        let weather = approval(npc, &["plugin:weather:set"]);
+        match settings.auto_decision(&weather) {
+            Some(DmApprovalDecision::AcceptWithModification {
+                modified_dialogue,
+                approved_tools,
+                ..
+            }) => {
+                assert_eq!(modified_dialogue, "Fine weather today.");
+                assert_eq!(approved_tools, vec!["call-0".to_string()]);
+            }
+            other => panic!("expected tools to be approved, got {:?}", other),
+        }
+
+        let grant = approval(npc, &["plugin:weather:set", "plugin:loot:Give_Item"]);
+        assert!(settings.auto_decision(&grant).is_none());
+
+        let mut flagged = approval(npc, &[]);
+        flagged.safety_warnings = vec!["veil: spiders".to_string()];
+        assert!(settings.auto_decision(&flagged).is_none());
+    }
+
+    #[test]
+    fn settings_round_trip_through_json() {
+        let npc = CharacterId::new();
+        let mut settings = NpcAutonomySettings::default();
+        settings.set_npc_level(npc, Some(NpcAutonomyLevel::Trusted));
+
+        let json = serde_json::to_string(&settings).unwrap();
+        let parsed: NpcAutonomySettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, settings);
+    }
+}
@@ -0,0 +1,203 @@
+//! Pacing timer - soft real-time limits for scenes and turns
+//!
+//! Convention slots and online sessions run against the clock. The DM can
+//! give a scene, or each player's turn, a number of real minutes. The timer
+//! only cues the table: it warns as time runs low and says when it is up,
+//! but never ends a scene or skips a player. Turn timers start over every
+//! time a player acts.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::PlayerCharacterId;
+
+/// Longest a timer may run, in minutes
+pub const MAX_TIMER_MINUTES: u32 = 8 * 60;
+
+/// What a pacing timer limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PacingTimerKind {
+    /// The whole scene
+    Scene,
+    /// Each player's turn; starts over whenever a player acts
+    Turn,
+}
+
+/// How a timer should look to the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PacingCue {
+    Running,
+    Paused,
+    /// Into the warning window
+    Warning,
+    /// Time is up; the timer keeps counting overtime
+    Expired,
+}
+
+/// A running or paused pacing timer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PacingTimer {
+    pub kind: PacingTimerKind,
+    /// Shown next to the countdown, e.g. "Final battle"
+    pub label: Option<String>,
+    pub duration_secs: u32,
+    /// Seconds before the end at which the timer starts warning
+    pub warning_secs: u32,
+    /// The PC whose turn a turn timer is counting
+    pub pc_id: Option<PlayerCharacterId>,
+    /// When the timer last started or resumed
+    pub started_at: DateTime<Utc>,
+    /// Seconds counted before the last pause
+    pub elapsed_before: u32,
+    pub paused: bool,
+}
+
+impl PacingTimer {
+    /// Start a timer of `minutes`, warning `warning_minutes` before the end
+    pub fn start(
+        kind: PacingTimerKind,
+        minutes: u32,
+        warning_minutes: u32,
+        label: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        if minutes == 0 || minutes > MAX_TIMER_MINUTES {
+            return Err(DomainError::validation(format!(
+                "A timer runs for 1 to {} minutes",
+                MAX_TIMER_MINUTES
+            )));
+        }
+        if warning_minutes >= minutes {
+            return Err(DomainError::validation(
+                "The warning has to come before the time is up",
+            ));
+        }
+        Ok(Self {
+            kind,
+            label: label
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty()),
+            duration_secs: minutes * 60,
+            warning_secs: warning_minutes * 60,
+            pc_id: None,
+            started_at: now,
+            elapsed_before: 0,
+            paused: false,
+        })
+    }
+
+    pub fn elapsed_secs(&self, now: DateTime<Utc>) -> u32 {
+        if self.paused {
+            return self.elapsed_before;
+        }
+        let running = (now - self.started_at).num_seconds().max(0) as u32;
+        self.elapsed_before.saturating_add(running)
+    }
+
+    pub fn remaining_secs(&self, now: DateTime<Utc>) -> u32 {
+        self.duration_secs.saturating_sub(self.elapsed_secs(now))
+    }
+
+    pub fn cue(&self, now: DateTime<Utc>) -> PacingCue {
+        let remaining = self.remaining_secs(now);
+        if remaining == 0 {
+            PacingCue::Expired
+        } else if self.paused {
+            PacingCue::Paused
+        } else if remaining <= self.warning_secs {
+            PacingCue::Warning
+        } else {
+            PacingCue::Running
+        }
+    }
+
+    pub fn pause(&mut self, now: DateTime<Utc>) {
+        if !self.paused {
+            self.elapsed_before = self.elapsed_secs(now);
+            self.paused = true;
+        }
+    }
+
+    pub fn resume(&mut self, now: DateTime<Utc>) {
+        if self.paused {
+            self.started_at = now;
+            self.paused = false;
+        }
+    }
+
+    /// Start the count over, e.g. for the next player's turn. A paused
+    /// timer stays paused.
+    pub fn restart(&mut self, now: DateTime<Utc>) {
+        self.started_at = now;
+        self.elapsed_before = 0;
+    }
+
+    /// Give the timer more time, also after it ran out
+    pub fn extend(&mut self, minutes: u32, now: DateTime<Utc>) -> Result<(), DomainError> {
+        let elapsed = self.elapsed_secs(now);
+        // An expired timer counts on from its end, not from the overtime
+        let base = elapsed.min(self.duration_secs);
+        let duration = self.duration_secs.saturating_add(minutes * 60);
+        if minutes == 0 || duration - base > MAX_TIMER_MINUTES * 60 {
+            return Err(DomainError::validation(format!(
+                "A timer runs for 1 to {} minutes",
+                MAX_TIMER_MINUTES
+            )));
+        }
+        if elapsed > self.duration_secs {
+            self.elapsed_before = self.duration_secs;
+            self.started_at = now;
+        }
+        self.duration_secs = duration;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn the_timer_warns_then_runs_out() {
+        let start = Utc::now();
+        let at = |secs| start + Duration::seconds(secs);
+        let mut timer =
+            PacingTimer::start(PacingTimerKind::Scene, 10, 2, Some(" Heist ".into()), start)
+                .unwrap();
+        assert_eq!(timer.label.as_deref(), Some("Heist"));
+        assert_eq!(timer.cue(at(60)), PacingCue::Running);
+        assert_eq!(timer.cue(at(8 * 60)), PacingCue::Warning);
+        assert_eq!(timer.cue(at(10 * 60 + 5)), PacingCue::Expired);
+        assert_eq!(timer.remaining_secs(at(10 * 60 + 5)), 0);
+
+        // Pausing stops the clock
+        timer.pause(at(60));
+        assert_eq!(timer.cue(at(9 * 60)), PacingCue::Paused);
+        assert_eq!(timer.remaining_secs(at(9 * 60)), 9 * 60);
+        timer.resume(at(9 * 60));
+        assert_eq!(timer.remaining_secs(at(10 * 60)), 8 * 60);
+
+        timer.restart(at(20 * 60));
+        assert_eq!(timer.remaining_secs(at(20 * 60)), 10 * 60);
+    }
+
+    #[test]
+    fn extending_an_expired_timer_counts_from_its_end() {
+        let start = Utc::now();
+        let mut timer = PacingTimer::start(PacingTimerKind::Turn, 1, 0, None, start).unwrap();
+        let late = start + Duration::seconds(5 * 60);
+        timer.extend(2, late).unwrap();
+        assert_eq!(timer.remaining_secs(late), 2 * 60);
+        assert_eq!(timer.cue(late), PacingCue::Running);
+
+        assert!(timer.extend(0, late).is_err());
+        assert!(timer.extend(MAX_TIMER_MINUTES, late).is_err());
+        assert!(PacingTimer::start(PacingTimerKind::Scene, 0, 0, None, start).is_err());
+        assert!(PacingTimer::start(PacingTimerKind::Scene, 5, 5, None, start).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//! Parallel scene - one group of a split party
+//!
+//! When the party splits, the DM gives each group its own scene: which PCs
+//! are in it, and the scene notes and tone that steer its NPC conversations.
+//! PCs outside every parallel scene play at the main table. The DM focuses
+//! one scene at a time; the others keep running and queue their approvals.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::{ParallelSceneId, PlayerCharacterId};
+
+/// Most parallel scenes a world can run at once
+pub const MAX_PARALLEL_SCENES: usize = 8;
+
+/// Longest parallel scene name, in characters
+pub const MAX_PARALLEL_SCENE_NAME_LEN: usize = 60;
+
+/// A group of PCs playing their own scene
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParallelScene {
+    pub id: ParallelSceneId,
+    /// Shown to the DM and the group ("The sewers")
+    pub name: String,
+    /// PCs in this scene; a PC is in at most one
+    pub pc_ids: Vec<PlayerCharacterId>,
+    /// DM guidance for this scene's NPC responses
+    #[serde(default)]
+    pub scene_notes: String,
+    /// Tone for this scene ("tense", "comic relief")
+    #[serde(default)]
+    pub tone: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ParallelScene {
+    pub fn new(
+        name: &str,
+        pc_ids: Vec<PlayerCharacterId>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let mut scene = Self {
+            id: ParallelSceneId::new(),
+            name: Self::validate_name(name)?,
+            pc_ids: Vec::new(),
+            scene_notes: String::new(),
+            tone: String::new(),
+            created_at: now,
+        };
+        scene.set_pcs(pc_ids)?;
+        Ok(scene)
+    }
+
+    /// Check a scene name, returning it trimmed
+    pub fn validate_name(name: &str) -> Result<String, DomainError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DomainError::validation("Scene name cannot be empty"));
+        }
+        if name.chars().count() > MAX_PARALLEL_SCENE_NAME_LEN {
+            return Err(DomainError::validation(format!(
+                "Scene name cannot exceed {} characters",
+                MAX_PARALLEL_SCENE_NAME_LEN
+            )));
+        }
+        Ok(name.to_string())
+    }
+
+    /// Set the scene's PCs, dropping duplicates. A scene needs at least one.
+    pub fn set_pcs(&mut self, pc_ids: Vec<PlayerCharacterId>) -> Result<(), DomainError> {
+        let mut unique = Vec::with_capacity(pc_ids.len());
+        for pc_id in pc_ids {
+            if !unique.contains(&pc_id) {
+                unique.push(pc_id);
+            }
+        }
+        if unique.is_empty() {
+            return Err(DomainError::validation(
+                "A parallel scene needs at least one player character",
+            ));
+        }
+        self.pc_ids = unique;
+        Ok(())
+    }
+
+    /// Note for the prompts of this scene's NPCs, so they answer to this
+    /// group's situation rather than the whole party's
+    pub fn prompt_note(&self) -> String {
+        let mut note = format!(
+            "The party is split. This exchange happens in the scene \"{}\"; \
+            characters in other scenes are not present and cannot hear it.",
+            self.name
+        );
+        if !self.scene_notes.trim().is_empty() {
+            note.push_str(&format!("\nScene notes: {}", self.scene_notes.trim()));
+        }
+        if !self.tone.trim().is_empty() {
+            note.push_str(&format!("\nTone: {}", self.tone.trim()));
+        }
+        note
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::World;
+
+    #[test]
+    fn a_pc_is_in_one_scene_at_a_time() {
+        let now = Utc::now();
+        let mut world = World::new("Saltmere", "", now);
+        let (ana, bo, cy) = (
+            PlayerCharacterId::new(),
+            PlayerCharacterId::new(),
+            PlayerCharacterId::new(),
+        );
+
+        let sewers = world
+            .create_parallel_scene("Sewers", vec![ana, bo], "", "", now)
+            .unwrap()
+            .id;
+        let tower = world
+            .create_parallel_scene("Tower", vec![bo, cy], "", "", now)
+            .unwrap()
+            .id;
+        assert_eq!(world.parallel_scene_of(bo).map(|s| s.id), Some(tower));
+        assert_eq!(world.parallel_scene_of(ana).map(|s| s.id), Some(sewers));
+
+        // Moving the last PC out of a scene closes it
+        world.focus_parallel_scene(Some(sewers), now).unwrap();
+        world
+            .update_parallel_scene(tower, "Tower", vec![ana, bo, cy], "", "", now)
+            .unwrap();
+        assert_eq!(world.parallel_scenes.len(), 1);
+        assert_eq!(world.focused_scene_id, None);
+
+        world.merge_parallel_scenes(now);
+        assert!(world.parallel_scenes.is_empty());
+        assert!(world.parallel_scene_of(ana).is_none());
+    }
+
+    #[test]
+    fn scenes_need_a_name_and_a_pc() {
+        let now = Utc::now();
+        assert!(ParallelScene::new(" ", vec![PlayerCharacterId::new()], now).is_err());
+        assert!(ParallelScene::new("Sewers", vec![], now).is_err());
+        let pc = PlayerCharacterId::new();
+        let scene = ParallelScene::new(" Sewers ", vec![pc, pc], now).unwrap();
+        assert_eq!(scene.name, "Sewers");
+        assert_eq!(scene.pc_ids, vec![pc]);
+    }
+}
@@ -0,0 +1,281 @@
+//! Plugins - third-party game tools and effect executors
+//!
+//! A plugin declares the LLM-invocable tools and narrative effects it
+//! provides, and the capabilities it needs. Plugins never touch the world
+//! directly: they return [`EventEffect`]s, and the engine applies only those
+//! the declared capabilities cover.
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::EventEffect;
+
+/// What a plugin is allowed to change in the world
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginCapability {
+    GiveItems,
+    TakeItems,
+    RevealInformation,
+    SetFlags,
+    ModifyStats,
+    ModifyRelationships,
+    AddRewards,
+    ManageChallenges,
+    ManageEvents,
+    TriggerScenes,
+    StartCombat,
+    /// Unknown capability (for forward compatibility; never granted)
+    #[serde(other)]
+    Unknown,
+}
+
+impl PluginCapability {
+    /// The capability needed to apply `effect`.
+    ///
+    /// `Custom` effects only describe work for the DM and need none. Plugin
+    /// effects need none here either, but plugins may never return them (see
+    /// [`PluginManifest::denied_effects`]).
+    pub fn required_for(effect: &EventEffect) -> Option<Self> {
+        Some(match effect {
+            EventEffect::GiveItem { .. } => Self::GiveItems,
+            EventEffect::TakeItem { .. } => Self::TakeItems,
+            EventEffect::RevealInformation { .. } => Self::RevealInformation,
+            EventEffect::SetFlag { .. } => Self::SetFlags,
+            EventEffect::ModifyStat { .. } => Self::ModifyStats,
+            EventEffect::ModifyRelationship { .. } => Self::ModifyRelationships,
+            EventEffect::AddReward { .. } => Self::AddRewards,
+            EventEffect::EnableChallenge { .. } | EventEffect::DisableChallenge { .. } => {
+                Self::ManageChallenges
+            }
+            EventEffect::EnableEvent { .. } | EventEffect::DisableEvent { .. } => {
+                Self::ManageEvents
+            }
+            EventEffect::TriggerScene { .. } => Self::TriggerScenes,
+            EventEffect::StartCombat { .. } => Self::StartCombat,
+            EventEffect::Custom { .. } | EventEffect::Plugin { .. } => return None,
+        })
+    }
+}
+
+impl std::fmt::Display for PluginCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::GiveItems => "give_items",
+            Self::TakeItems => "take_items",
+            Self::RevealInformation => "reveal_information",
+            Self::SetFlags => "set_flags",
+            Self::ModifyStats => "modify_stats",
+            Self::ModifyRelationships => "modify_relationships",
+            Self::AddRewards => "add_rewards",
+            Self::ManageChallenges => "manage_challenges",
+            Self::ManageEvents => "manage_events",
+            Self::TriggerScenes => "trigger_scenes",
+            Self::StartCombat => "start_combat",
+            Self::Unknown => "unknown",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A tool the LLM may propose during NPC dialogue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginToolSpec {
+    pub name: String,
+    pub description: String,
+    /// JSON Schema for the tool's arguments
+    #[serde(default = "empty_object_schema")]
+    pub parameters: serde_json::Value,
+}
+
+/// A narrative effect a plugin can execute
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginEffectSpec {
+    pub name: String,
+    pub description: String,
+}
+
+/// What a plugin provides and needs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginManifest {
+    /// Unique plugin name (letters, digits, `-` and single `_`s)
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub capabilities: Vec<PluginCapability>,
+    #[serde(default)]
+    pub tools: Vec<PluginToolSpec>,
+    #[serde(default)]
+    pub effects: Vec<PluginEffectSpec>,
+}
+
+impl PluginManifest {
+    pub fn has_capability(&self, capability: PluginCapability) -> bool {
+        capability != PluginCapability::Unknown && self.capabilities.contains(&capability)
+    }
+
+    pub fn tool(&self, name: &str) -> Option<&PluginToolSpec> {
+        self.tools.iter().find(|tool| tool.name == name)
+    }
+
+    pub fn effect(&self, name: &str) -> Option<&PluginEffectSpec> {
+        self.effects.iter().find(|effect| effect.name == name)
+    }
+
+    /// Check the manifest is usable: a valid name and no duplicate entries.
+    pub fn validate(&self) -> Result<(), String> {
+        // Double underscores separate the plugin and tool in LLM tool names
+        if !is_identifier(&self.name) || self.name.contains("__") {
+            return Err(format!("invalid plugin name {:?}", self.name));
+        }
+        for (i, tool) in self.tools.iter().enumerate() {
+            if !is_identifier(&tool.name) {
+                return Err(format!("invalid tool name {:?}", tool.name));
+            }
+            if self.tools[..i].iter().any(|t| t.name == tool.name) {
+                return Err(format!("duplicate tool {:?}", tool.name));
+            }
+        }
+        for (i, effect) in self.effects.iter().enumerate() {
+            if !is_identifier(&effect.name) {
+                return Err(format!("invalid effect name {:?}", effect.name));
+            }
+            if self.effects[..i].iter().any(|e| e.name == effect.name) {
+                return Err(format!("duplicate effect {:?}", effect.name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Effects in `output` that the manifest's capabilities don't cover.
+    ///
+    /// Plugin effects are always denied so plugins can't chain each other.
+    pub fn denied_effects<'a>(&self, output: &'a PluginOutput) -> Vec<&'a EventEffect> {
+        output
+            .effects
+            .iter()
+            .filter(|effect| {
+                matches!(effect, EventEffect::Plugin { .. })
+                    || PluginCapability::required_for(effect)
+                        .is_some_and(|capability| !self.has_capability(capability))
+            })
+            .collect()
+    }
+}
+
+/// The result of running a plugin tool or effect
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginOutput {
+    /// What the plugin did, for the DM
+    #[serde(default)]
+    pub summary: String,
+    /// World changes for the engine to apply
+    #[serde(default)]
+    pub effects: Vec<EventEffect>,
+}
+
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn empty_object_schema() -> serde_json::Value {
+    serde_json::json!({ "type": "object", "properties": {} })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(capabilities: Vec<PluginCapability>) -> PluginManifest {
+        PluginManifest {
+            name: "loot-tables".to_string(),
+            version: "1.0.0".to_string(),
+            description: String::new(),
+            capabilities,
+            tools: vec![],
+            effects: vec![],
+        }
+    }
+
+    fn give_item() -> EventEffect {
+        EventEffect::GiveItem {
+            item_name: "Rusty Key".to_string(),
+            item_description: None,
+            quantity: 1,
+        }
+    }
+
+    #[test]
+    fn test_effects_need_a_declared_capability() {
+        let output = PluginOutput {
+            summary: String::new(),
+            effects: vec![
+                give_item(),
+                EventEffect::SetFlag {
+                    flag_name: "looted".to_string(),
+                    value: true,
+                },
+                EventEffect::Custom {
+                    description: "Describe the chest".to_string(),
+                    requires_dm_action: true,
+                },
+            ],
+        };
+
+        let denied = manifest(vec![PluginCapability::GiveItems]).denied_effects(&output);
+        assert_eq!(denied.len(), 1);
+        assert!(matches!(denied[0], EventEffect::SetFlag { .. }));
+    }
+
+    #[test]
+    fn test_plugins_cannot_chain_plugins() {
+        let output = PluginOutput {
+            summary: String::new(),
+            effects: vec![EventEffect::Plugin {
+                plugin: "other".to_string(),
+                effect: "anything".to_string(),
+                params: serde_json::Value::Null,
+            }],
+        };
+        assert_eq!(manifest(vec![]).denied_effects(&output).len(), 1);
+    }
+
+    #[test]
+    fn test_unknown_capability_is_never_granted() {
+        let manifest: PluginManifest = serde_json::from_str(
+            r#"{"name": "x", "version": "1", "capabilities": ["rewrite_reality"]}"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.capabilities, vec![PluginCapability::Unknown]);
+        assert!(!manifest.has_capability(PluginCapability::Unknown));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_names_and_duplicates() {
+        assert!(manifest(vec![]).validate().is_ok());
+
+        let mut bad = manifest(vec![]);
+        bad.name = "loot tables".to_string();
+        assert!(bad.validate().is_err());
+
+        bad.name = "loot__tables".to_string();
+        assert!(bad.validate().is_err());
+
+        let mut dup = manifest(vec![]);
+        let spec = PluginEffectSpec {
+            name: "roll".to_string(),
+            description: String::new(),
+        };
+        dup.effects = vec![spec.clone(), spec];
+        assert!(dup.validate().is_err());
+    }
+}
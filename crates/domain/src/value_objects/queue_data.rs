@@ -191,6 +191,25 @@ pub struct LlmRequestData {
     /// Conversation ID for dialogue tracking (flows through to ApprovalRequestData)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conversation_id: Option<uuid::Uuid>,
+    /// Times this NPC response has been regenerated after a DM rejection
+    #[serde(default)]
+    pub retry_count: u32,
+    /// Every line answered, in the order said, when several players' lines
+    /// to the NPC were merged into one request
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub merged_lines: Vec<MergedLine>,
+}
+
+/// One player's line in an NPC response that answers several at once
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MergedLine {
+    /// The player action the line was said in
+    pub action_id: Uuid,
+    pub pc_id: Option<PlayerCharacterId>,
+    pub dialogue: String,
+    /// The speaker's conversation with the NPC
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversation_id: Option<Uuid>,
 }
 
 /// Context for LLM suggestion requests.
@@ -375,6 +394,12 @@ pub struct ApprovalRequestData {
     /// Conversation ID (links to Conversation node in Neo4j)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conversation_id: Option<Uuid>,
+    /// Content safety warnings from the post-generation filter
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub safety_warnings: Vec<String>,
+    /// The players' lines the response answers, when they were merged
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub merged_lines: Vec<MergedLine>,
 }
 
 // =============================================================================
@@ -445,6 +470,15 @@ pub struct AssetGenerationData {
     pub workflow_id: String,
     /// Prompt for image generation
     pub prompt: String,
+    /// Things the image should avoid
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub negative_prompt: Option<String>,
+    /// Output size as (width, height). None = the workflow's default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<(u32, u32)>,
+    /// Stored path of an image to condition on, such as the character's portrait
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_image: Option<String>,
     /// Number of images to generate
     pub count: u32,
 }
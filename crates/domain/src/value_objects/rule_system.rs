@@ -12,6 +12,7 @@ pub use crate::types::{
     DifficultyLadder,
     EffectLevel,
     EffectTickConfig,
+    InjuryRecoveryConfig,
     LadderEntry,
     NarrativeDiceConfig,
     NarrativeDiceType,
@@ -20,9 +21,11 @@ pub use crate::types::{
     NarrativeThresholds,
     Position,
     PositionEffectConfig,
+    ResurrectionConfig,
     RuleSystemConfig,
     RuleSystemType,
     RuleSystemVariant,
+    SkillAliases,
     StatDefinition,
     SuccessComparison,
 };
@@ -16,8 +16,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::co_dm::CoDmSettings;
 use super::context_budget::ContextBudgetConfig;
-use wrldbldr_domain::WorldId;
+use super::npc_autonomy::NpcAutonomySettings;
+use wrldbldr_domain::{AssetType, WorldId};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -58,6 +60,217 @@ impl std::str::FromStr for BatchQueueFailurePolicy {
     }
 }
 
+/// Kinds of LLM work, each routed to a model tier by [`ModelRouting`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LlmTask {
+    /// NPC responses shown to players
+    NpcDialogue,
+    /// Content suggestions (names, descriptions, ...)
+    Suggestion,
+    /// Challenge outcome suggestions
+    OutcomeSuggestion,
+    /// Short structured decisions, e.g. which NPCs are present when staging
+    Classification,
+}
+
+/// Model size tier: `Large` uses the dialogue model, `Small` the summarization model
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelTier {
+    Small,
+    Large,
+}
+
+/// Which model tier each kind of LLM work runs on
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ModelRouting {
+    pub npc_dialogue: ModelTier,
+    pub suggestions: ModelTier,
+    pub outcome_suggestions: ModelTier,
+    pub classification: ModelTier,
+}
+
+impl Default for ModelRouting {
+    fn default() -> Self {
+        Self {
+            npc_dialogue: ModelTier::Large,
+            suggestions: ModelTier::Small,
+            outcome_suggestions: ModelTier::Small,
+            classification: ModelTier::Small,
+        }
+    }
+}
+
+impl ModelRouting {
+    pub fn tier_for(&self, task: LlmTask) -> ModelTier {
+        match task {
+            LlmTask::NpcDialogue => self.npc_dialogue,
+            LlmTask::Suggestion => self.suggestions,
+            LlmTask::OutcomeSuggestion => self.outcome_suggestions,
+            LlmTask::Classification => self.classification,
+        }
+    }
+}
+
+/// Service that runs a world's LLM calls
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum LlmProvider {
+    /// The engine's own Ollama server
+    #[default]
+    Ollama,
+    #[serde(rename = "openai")]
+    OpenAi,
+    Anthropic,
+
+    /// Forward-compatibility fallback for newer variants; runs on Ollama.
+    #[serde(other)]
+    Unknown,
+}
+
+impl LlmProvider {
+    /// Whether calls leave the engine for a hosted API that needs a key
+    pub fn is_hosted(&self) -> bool {
+        matches!(self, LlmProvider::OpenAi | LlmProvider::Anthropic)
+    }
+}
+
+impl std::fmt::Display for LlmProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LlmProvider::Ollama => write!(f, "ollama"),
+            LlmProvider::OpenAi => write!(f, "openai"),
+            LlmProvider::Anthropic => write!(f, "anthropic"),
+            LlmProvider::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Which provider a world's LLM calls go to, and how to reach it
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(default)]
+pub struct LlmProviderSettings {
+    pub provider: LlmProvider,
+    /// Model for calls whose tier has no model set. None = the provider's
+    /// default model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// API key for hosted providers. Sent to clients as [`REDACTED_SECRET`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// API root to use instead of the provider's, e.g. a proxy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+}
+
+impl LlmProviderSettings {
+    /// The API key, if one is set and not blank
+    pub fn api_key(&self) -> Option<&str> {
+        self.api_key
+            .as_deref()
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+    }
+
+    /// Whether calls go to a hosted provider rather than the engine's Ollama.
+    /// A hosted provider without a key falls back to Ollama.
+    pub fn uses_hosted(&self) -> bool {
+        self.provider.is_hosted() && self.api_key().is_some()
+    }
+
+    /// Copy with the API key replaced by a placeholder
+    pub fn redacted(&self) -> Self {
+        let mut redacted = self.clone();
+        if redacted.api_key.is_some() {
+            redacted.api_key = Some(REDACTED_SECRET.to_string());
+        }
+        redacted
+    }
+
+    /// Put back the saved key where a client sent the placeholder it was shown
+    pub fn with_saved_secret(mut self, saved: &LlmProviderSettings) -> Self {
+        if self.api_key.as_deref() == Some(REDACTED_SECRET) {
+            self.api_key = saved.api_key.clone();
+        }
+        self
+    }
+}
+
+// Written out by hand so the API key never reaches a log
+impl std::fmt::Debug for LlmProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LlmProviderSettings")
+            .field("provider", &self.provider)
+            .field("model", &self.model)
+            .field("api_key", &self.api_key.as_ref().map(|_| REDACTED_SECRET))
+            .field("base_url", &self.base_url)
+            .finish()
+    }
+}
+
+/// A named art style for one asset slot, so every asset a world generates
+/// with it looks alike
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GenerationPreset {
+    pub name: String,
+    /// Asset slot the preset applies to
+    pub asset_type: AssetType,
+    /// Appended to every prompt, e.g. "watercolor, muted palette, ink outlines"
+    #[serde(default)]
+    pub style_prompt: String,
+    /// Appended to the request's negative prompt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub negative_prompt: Option<String>,
+    /// Output width. None = the slot's default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    /// Output height. None = the slot's default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Workflow to run instead of the one requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<String>,
+    /// Used for generations in this slot that don't name a preset
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+impl GenerationPreset {
+    /// `prompt` with the style fragment appended
+    pub fn styled_prompt(&self, prompt: &str) -> String {
+        join_prompt(Some(prompt), &self.style_prompt).unwrap_or_default()
+    }
+
+    /// `negative_prompt` with the preset's negative prompt appended
+    pub fn styled_negative_prompt(&self, negative_prompt: Option<&str>) -> Option<String> {
+        join_prompt(
+            negative_prompt,
+            self.negative_prompt.as_deref().unwrap_or(""),
+        )
+    }
+
+    /// Output size, falling back to the slot's default dimensions
+    pub fn dimensions(&self) -> (u32, u32) {
+        let (width, height) = self.asset_type.default_dimensions();
+        (self.width.unwrap_or(width), self.height.unwrap_or(height))
+    }
+}
+
+/// Join two comma-separated prompt fragments, skipping empty ones
+fn join_prompt(base: Option<&str>, extra: &str) -> Option<String> {
+    let parts: Vec<&str> = [base.unwrap_or(""), extra]
+        .into_iter()
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(", "))
+    }
+}
+
 /// All configurable application settings
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AppSettings {
@@ -75,6 +288,11 @@ pub struct AppSettings {
     #[serde(default = "default_conversation_history_turns")]
     pub conversation_history_turns: usize,
 
+    /// How long a line to an NPC waits for other PCs to speak up, so the
+    /// NPC answers everyone in one reply. 0 answers each line on its own.
+    #[serde(default = "default_dialogue_merge_window_ms")]
+    pub dialogue_merge_window_ms: u64,
+
     // ============================================================================
     // Circuit Breaker & Health
     // ============================================================================
@@ -148,6 +366,54 @@ pub struct AppSettings {
     #[serde(default)]
     pub context_budget: ContextBudgetConfig,
 
+    /// Large-tier model, for NPC dialogue by default. None = the engine's
+    /// configured model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialogue_model: Option<String>,
+
+    /// Cheaper small-tier model, for suggestions and classification by
+    /// default. None = the engine's configured model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary_model: Option<String>,
+
+    /// Which tier each kind of LLM work runs on
+    #[serde(default)]
+    pub model_routing: ModelRouting,
+
+    /// Provider the world's LLM calls go to: the engine's Ollama, OpenAI or
+    /// Anthropic
+    #[serde(default)]
+    pub llm_provider: LlmProviderSettings,
+
+    /// Reuse responses to identical non-creative prompts (classification,
+    /// name generation). Global only; read at startup.
+    #[serde(default = "default_llm_cache_enabled")]
+    pub llm_cache_enabled: bool,
+
+    /// How long a cached LLM response stays valid
+    #[serde(default = "default_llm_cache_ttl_secs")]
+    pub llm_cache_ttl_secs: u64,
+
+    /// AI co-DM that resolves approvals for solo play, and its guardrails
+    #[serde(default)]
+    pub co_dm: CoDmSettings,
+
+    /// Which NPC responses go to players without DM approval
+    #[serde(default)]
+    pub npc_autonomy: NpcAutonomySettings,
+
+    // ============================================================================
+    // Usage Quotas
+    // ============================================================================
+    /// Soft daily LLM token quota; the DM is warned once it is exceeded.
+    /// None = no quota.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_token_quota: Option<u64>,
+
+    /// Soft daily image generation quota. None = no quota.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_image_quota: Option<u64>,
+
     // ============================================================================
     // Asset Generation
     // ============================================================================
@@ -156,9 +422,21 @@ pub struct AppSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub style_reference_asset_id: Option<String>,
 
+    /// Named art styles per asset slot, picked when generating
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generation_presets: Vec<GenerationPreset>,
+
     /// Policy for how to handle failures while queueing prompts for a batch.
     #[serde(default = "default_batch_queue_failure_policy")]
     pub batch_queue_failure_policy: BatchQueueFailurePolicy,
+
+    // ============================================================================
+    // External Services
+    // ============================================================================
+    /// Neo4j/Ollama/ComfyUI connections saved by the setup wizard.
+    /// Global only; read at startup, so changes require a restart.
+    #[serde(default)]
+    pub services: ServiceConnections,
 }
 
 fn default_outcome_branch_count() -> usize {
@@ -173,9 +451,18 @@ fn default_outcome_branch_max() -> usize {
 fn default_conversation_history_turns() -> usize {
     20
 }
+fn default_dialogue_merge_window_ms() -> u64 {
+    1500
+}
 fn default_suggestion_tokens_per_branch() -> u32 {
     200
 }
+fn default_llm_cache_enabled() -> bool {
+    true
+}
+fn default_llm_cache_ttl_secs() -> u64 {
+    600
+}
 fn default_presence_cache_ttl_hours() -> i32 {
     3
 }
@@ -195,6 +482,7 @@ impl Default for AppSettings {
             world_id: None,
             max_conversation_turns: 30,
             conversation_history_turns: 20,
+            dialogue_merge_window_ms: default_dialogue_merge_window_ms(),
             circuit_breaker_failure_threshold: 5,
             circuit_breaker_open_duration_secs: 60,
             circuit_breaker_half_open_requests: 1,
@@ -214,8 +502,20 @@ impl Default for AppSettings {
             outcome_branch_max: 4,
             suggestion_tokens_per_branch: 200,
             context_budget: ContextBudgetConfig::default(),
+            dialogue_model: None,
+            summary_model: None,
+            model_routing: ModelRouting::default(),
+            llm_provider: LlmProviderSettings::default(),
+            llm_cache_enabled: default_llm_cache_enabled(),
+            llm_cache_ttl_secs: default_llm_cache_ttl_secs(),
+            co_dm: CoDmSettings::default(),
+            npc_autonomy: NpcAutonomySettings::default(),
+            daily_token_quota: None,
+            daily_image_quota: None,
             style_reference_asset_id: None,
+            generation_presets: Vec::new(),
             batch_queue_failure_policy: default_batch_queue_failure_policy(),
+            services: ServiceConnections::default(),
         }
     }
 }
@@ -229,9 +529,36 @@ impl AppSettings {
     pub fn for_world(base: AppSettings, world_id: WorldId) -> Self {
         let mut settings = base;
         settings.world_id = Some(world_id.into());
+        // Service connections are global only
+        settings.services = ServiceConnections::default();
         settings
     }
 
+    /// Model to use for a kind of LLM work. None = the engine's configured model.
+    pub fn model_for(&self, task: LlmTask) -> Option<String> {
+        match self.model_routing.tier_for(task) {
+            ModelTier::Large => self.dialogue_model.clone(),
+            ModelTier::Small => self.summary_model.clone(),
+        }
+    }
+
+    /// Preset to generate `asset_type` with: the one called `name`, or the
+    /// slot's default preset when no name is given
+    pub fn generation_preset(
+        &self,
+        asset_type: AssetType,
+        name: Option<&str>,
+    ) -> Option<&GenerationPreset> {
+        let mut presets = self
+            .generation_presets
+            .iter()
+            .filter(|p| p.asset_type == asset_type);
+        match name {
+            Some(name) => presets.find(|p| p.name.eq_ignore_ascii_case(name.trim())),
+            None => presets.find(|p| p.is_default),
+        }
+    }
+
     /// Merge per-world settings with global settings.
     /// Per-world values override global where present.
     pub fn merge_with_global(&self, _global: &AppSettings) -> AppSettings {
@@ -247,6 +574,120 @@ impl AppSettings {
     }
 }
 
+/// Placeholder returned in place of a saved password
+pub const REDACTED_SECRET: &str = "********";
+
+pub const DEFAULT_NEO4J_URI: &str = "bolt://localhost:7687";
+pub const DEFAULT_NEO4J_USER: &str = "neo4j";
+pub const DEFAULT_NEO4J_PASSWORD: &str = "password";
+pub const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434";
+pub const DEFAULT_OLLAMA_MODEL: &str = "llama3.2";
+pub const DEFAULT_COMFYUI_URL: &str = "http://localhost:8188";
+
+/// Connection details for the engine's external services
+///
+/// Every field is optional: unset fields fall back to the environment
+/// (.env) and then to the local defaults above.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ServiceConnections {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neo4j_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neo4j_user: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neo4j_password: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ollama_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ollama_model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comfyui_url: Option<String>,
+}
+
+impl ServiceConnections {
+    /// Whether no connection has been configured
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Fill unset (or blank) fields from `fallback`
+    pub fn or(self, fallback: &ServiceConnections) -> Self {
+        fn pick(value: Option<String>, fallback: &Option<String>) -> Option<String> {
+            value
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+                .or_else(|| fallback.clone())
+        }
+
+        Self {
+            neo4j_uri: pick(self.neo4j_uri, &fallback.neo4j_uri),
+            neo4j_user: pick(self.neo4j_user, &fallback.neo4j_user),
+            neo4j_password: pick(self.neo4j_password, &fallback.neo4j_password),
+            ollama_url: pick(self.ollama_url, &fallback.ollama_url),
+            ollama_model: pick(self.ollama_model, &fallback.ollama_model),
+            comfyui_url: pick(self.comfyui_url, &fallback.comfyui_url),
+        }
+    }
+
+    /// Copy with every field set, using the local defaults for unset ones
+    pub fn with_defaults(&self) -> Self {
+        Self {
+            neo4j_uri: Some(self.neo4j_uri().to_string()),
+            neo4j_user: Some(self.neo4j_user().to_string()),
+            neo4j_password: Some(self.neo4j_password().to_string()),
+            ollama_url: Some(self.ollama_url().to_string()),
+            ollama_model: Some(self.ollama_model().to_string()),
+            comfyui_url: Some(self.comfyui_url().to_string()),
+        }
+    }
+
+    /// Copy with the Neo4j password replaced by a placeholder
+    pub fn redacted(&self) -> Self {
+        let mut redacted = self.clone();
+        if redacted.neo4j_password.is_some() {
+            redacted.neo4j_password = Some(REDACTED_SECRET.to_string());
+        }
+        redacted
+    }
+
+    /// Drop a password that is only the redaction placeholder
+    ///
+    /// Lets a client send back what it was shown without overwriting the
+    /// real password.
+    pub fn without_redacted_secret(mut self) -> Self {
+        if self.neo4j_password.as_deref() == Some(REDACTED_SECRET) {
+            self.neo4j_password = None;
+        }
+        self
+    }
+
+    pub fn neo4j_uri(&self) -> &str {
+        self.neo4j_uri.as_deref().unwrap_or(DEFAULT_NEO4J_URI)
+    }
+
+    pub fn neo4j_user(&self) -> &str {
+        self.neo4j_user.as_deref().unwrap_or(DEFAULT_NEO4J_USER)
+    }
+
+    pub fn neo4j_password(&self) -> &str {
+        self.neo4j_password
+            .as_deref()
+            .unwrap_or(DEFAULT_NEO4J_PASSWORD)
+    }
+
+    pub fn ollama_url(&self) -> &str {
+        self.ollama_url.as_deref().unwrap_or(DEFAULT_OLLAMA_URL)
+    }
+
+    pub fn ollama_model(&self) -> &str {
+        self.ollama_model.as_deref().unwrap_or(DEFAULT_OLLAMA_MODEL)
+    }
+
+    pub fn comfyui_url(&self) -> &str {
+        self.comfyui_url.as_deref().unwrap_or(DEFAULT_COMFYUI_URL)
+    }
+}
+
 /// Settings field metadata for UI rendering
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SettingsFieldMetadata {
@@ -298,6 +739,17 @@ pub fn settings_metadata() -> Vec<SettingsFieldMetadata> {
             category: "Conversation".into(),
             requires_restart: false,
         },
+        SettingsFieldMetadata {
+            key: "dialogue_merge_window_ms".into(),
+            display_name: "Dialogue Merge Window (ms)".into(),
+            description: "How long a line to an NPC waits for other PCs to join in, so the NPC answers them together (0 = answer each line alone)".into(),
+            field_type: "integer".into(),
+            default_value: serde_json::json!(1500),
+            min_value: Some(serde_json::json!(0)),
+            max_value: Some(serde_json::json!(10000)),
+            category: "Conversation".into(),
+            requires_restart: false,
+        },
         // Validation
         SettingsFieldMetadata {
             key: "max_name_length".into(),
@@ -378,6 +830,252 @@ pub fn settings_metadata() -> Vec<SettingsFieldMetadata> {
             category: "Animation".into(),
             requires_restart: false,
         },
+        // Models
+        SettingsFieldMetadata {
+            key: "dialogue_model".into(),
+            display_name: "Dialogue Model".into(),
+            description: "Large Ollama model, used for NPC dialogue by default. Leave empty to use the Engine's configured model.".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!(null),
+            min_value: None,
+            max_value: None,
+            category: "Models".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "summary_model".into(),
+            display_name: "Summarization Model".into(),
+            description: "Cheaper small Ollama model, used for suggestions and classification by default. Leave empty to use the Engine's configured model.".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!(null),
+            min_value: None,
+            max_value: None,
+            category: "Models".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "model_routing.npc_dialogue".into(),
+            display_name: "NPC Dialogue Tier".into(),
+            description: "Model tier for NPC dialogue (small or large)".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!("large"),
+            min_value: None,
+            max_value: None,
+            category: "Models".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "model_routing.suggestions".into(),
+            display_name: "Suggestions Tier".into(),
+            description: "Model tier for content suggestions (small or large)".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!("small"),
+            min_value: None,
+            max_value: None,
+            category: "Models".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "model_routing.outcome_suggestions".into(),
+            display_name: "Outcome Suggestions Tier".into(),
+            description: "Model tier for challenge outcome suggestions (small or large)".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!("small"),
+            min_value: None,
+            max_value: None,
+            category: "Models".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "model_routing.classification".into(),
+            display_name: "Classification Tier".into(),
+            description: "Model tier for staging and other classification calls (small or large)".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!("small"),
+            min_value: None,
+            max_value: None,
+            category: "Models".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "llm_provider.provider".into(),
+            display_name: "LLM Provider".into(),
+            description: "Where the world's LLM calls go (ollama, openai or anthropic). Hosted providers need an API key; without one calls stay on Ollama.".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!("ollama"),
+            min_value: None,
+            max_value: None,
+            category: "Models".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "llm_provider.model".into(),
+            display_name: "Provider Model".into(),
+            description: "Model for calls whose tier has no model set, e.g. gpt-4o-mini. Leave empty for the provider's default.".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!(null),
+            min_value: None,
+            max_value: None,
+            category: "Models".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "llm_provider.api_key".into(),
+            display_name: "Provider API Key".into(),
+            description: "API key for a hosted provider. Stored by the Engine and never shown again.".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!(null),
+            min_value: None,
+            max_value: None,
+            category: "Models".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "llm_cache_enabled".into(),
+            display_name: "Cache LLM Responses".into(),
+            description: "Reuse responses to identical classification and name-generation prompts".into(),
+            field_type: "boolean".into(),
+            default_value: serde_json::json!(true),
+            min_value: None,
+            max_value: None,
+            category: "Models".into(),
+            requires_restart: true,
+        },
+        SettingsFieldMetadata {
+            key: "llm_cache_ttl_secs".into(),
+            display_name: "LLM Cache TTL (seconds)".into(),
+            description: "How long a cached LLM response is reused".into(),
+            field_type: "integer".into(),
+            default_value: serde_json::json!(600),
+            min_value: Some(serde_json::json!(0)),
+            max_value: Some(serde_json::json!(86400)),
+            category: "Models".into(),
+            requires_restart: true,
+        },
+        // Co-DM
+        SettingsFieldMetadata {
+            key: "co_dm.enabled".into(),
+            display_name: "AI Co-DM".into(),
+            description: "Let an LLM approve NPC responses so a solo player can play without a DM. Every decision is kept for review.".into(),
+            field_type: "boolean".into(),
+            default_value: serde_json::json!(false),
+            min_value: None,
+            max_value: None,
+            category: "Co-DM".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "co_dm.allow_rewrites".into(),
+            display_name: "Co-DM May Rewrite Dialogue".into(),
+            description: "When off, the co-DM can only accept or reject; rewrites go to the DM".into(),
+            field_type: "boolean".into(),
+            default_value: serde_json::json!(true),
+            min_value: None,
+            max_value: None,
+            category: "Co-DM".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "co_dm.allow_tools".into(),
+            display_name: "Co-DM May Approve Plugin Tools".into(),
+            description: "Let the co-DM approve plugin tool calls; when off none are run".into(),
+            field_type: "boolean".into(),
+            default_value: serde_json::json!(false),
+            min_value: None,
+            max_value: None,
+            category: "Co-DM".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "co_dm.escalate_safety_warnings".into(),
+            display_name: "Escalate Safety-Flagged Responses".into(),
+            description: "Leave responses flagged by the content safety filter for the DM".into(),
+            field_type: "boolean".into(),
+            default_value: serde_json::json!(true),
+            min_value: None,
+            max_value: None,
+            category: "Co-DM".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "co_dm.escalate_suggestions".into(),
+            display_name: "Escalate Challenge and Event Suggestions".into(),
+            description: "Leave responses suggesting a challenge or narrative event for the DM".into(),
+            field_type: "boolean".into(),
+            default_value: serde_json::json!(true),
+            min_value: None,
+            max_value: None,
+            category: "Co-DM".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "co_dm.max_dialogue_chars".into(),
+            display_name: "Co-DM Max Dialogue Length".into(),
+            description: "Longer dialogue goes to the DM. Set to 0 for no limit.".into(),
+            field_type: "integer".into(),
+            default_value: serde_json::json!(600),
+            min_value: Some(serde_json::json!(0)),
+            max_value: Some(serde_json::json!(10000)),
+            category: "Co-DM".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "co_dm.instructions".into(),
+            display_name: "Co-DM Instructions".into(),
+            description: "Direction for the co-DM, e.g. tone or what to veto".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!(null),
+            min_value: None,
+            max_value: None,
+            category: "Co-DM".into(),
+            requires_restart: false,
+        },
+        // NPC Autonomy
+        SettingsFieldMetadata {
+            key: "npc_autonomy.default_level".into(),
+            display_name: "Default NPC Autonomy".into(),
+            description: "What NPCs may say without DM approval: supervised (nothing), small_talk (dialogue without tool calls) or trusted (also tool calls not on the review list). Individual NPCs can override this.".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!("supervised"),
+            min_value: None,
+            max_value: None,
+            category: "NPC Autonomy".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "npc_autonomy.always_review_tools".into(),
+            display_name: "Always Reviewed Tools".into(),
+            description: "Tool calls whose name contains any of these words always go to the DM, e.g. item grants".into(),
+            field_type: "string".into(),
+            default_value: serde_json::json!(["item", "grant", "give"]),
+            min_value: None,
+            max_value: None,
+            category: "NPC Autonomy".into(),
+            requires_restart: false,
+        },
+        // Usage Quotas
+        SettingsFieldMetadata {
+            key: "daily_token_quota".into(),
+            display_name: "Daily Token Quota".into(),
+            description: "Warn the DM when the world uses more LLM tokens in a day. Leave empty for no quota.".into(),
+            field_type: "integer".into(),
+            default_value: serde_json::json!(null),
+            min_value: Some(serde_json::json!(0)),
+            max_value: None,
+            category: "Usage".into(),
+            requires_restart: false,
+        },
+        SettingsFieldMetadata {
+            key: "daily_image_quota".into(),
+            display_name: "Daily Image Quota".into(),
+            description: "Warn the DM when the world generates more images in a day. Leave empty for no quota.".into(),
+            field_type: "integer".into(),
+            default_value: serde_json::json!(null),
+            min_value: Some(serde_json::json!(0)),
+            max_value: None,
+            category: "Usage".into(),
+            requires_restart: false,
+        },
         // LLM Context Budgets
         SettingsFieldMetadata {
             key: "context_budget.total_budget_tokens".into(),
@@ -493,7 +1191,7 @@ pub fn settings_metadata() -> Vec<SettingsFieldMetadata> {
         SettingsFieldMetadata {
             key: "circuit_breaker_failure_threshold".into(),
             display_name: "Circuit Breaker Failures".into(),
-            description: "Number of failures before circuit breaker opens".into(),
+            description: "Consecutive LLM or database failures before the circuit breaker opens".into(),
             field_type: "integer".into(),
             default_value: serde_json::json!(5),
             min_value: Some(serde_json::json!(1)),
@@ -615,3 +1313,170 @@ pub fn settings_metadata() -> Vec<SettingsFieldMetadata> {
         },
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_connections_fall_back_per_field() {
+        let saved = ServiceConnections {
+            ollama_url: Some("http://gpu-box:11434".into()),
+            neo4j_password: Some("  ".into()),
+            ..Default::default()
+        };
+        let env = ServiceConnections {
+            neo4j_password: Some("from-env".into()),
+            ollama_url: Some("http://localhost:11434".into()),
+            ..Default::default()
+        };
+
+        let merged = saved.or(&env);
+        assert_eq!(merged.ollama_url(), "http://gpu-box:11434");
+        assert_eq!(merged.neo4j_password(), "from-env");
+        assert_eq!(merged.neo4j_uri(), DEFAULT_NEO4J_URI);
+    }
+
+    #[test]
+    fn test_redacted_password_round_trips_without_overwriting() {
+        let saved = ServiceConnections {
+            neo4j_password: Some("hunter2".into()),
+            ..Default::default()
+        };
+
+        let shown = saved.redacted();
+        assert_eq!(shown.neo4j_password.as_deref(), Some(REDACTED_SECRET));
+
+        let sent_back = shown.without_redacted_secret().or(&saved);
+        assert_eq!(sent_back.neo4j_password(), "hunter2");
+    }
+
+    #[test]
+    fn test_llm_provider_debug_hides_the_api_key() {
+        let settings = LlmProviderSettings {
+            provider: LlmProvider::Anthropic,
+            api_key: Some("sk-secret".into()),
+            ..Default::default()
+        };
+
+        let debug = format!("{:?}", settings);
+        assert!(!debug.contains("sk-secret"));
+        assert!(debug.contains(REDACTED_SECRET));
+    }
+
+    #[test]
+    fn test_settings_without_services_deserialize() {
+        let mut json = serde_json::to_value(AppSettings::default()).unwrap();
+        json.as_object_mut().unwrap().remove("services");
+        let settings: AppSettings = serde_json::from_value(json).unwrap();
+        assert!(settings.services.is_empty());
+    }
+
+    #[test]
+    fn test_model_for_routes_tasks_by_tier() {
+        let mut settings = AppSettings {
+            dialogue_model: Some("llama3.1:70b".into()),
+            summary_model: Some("qwen2.5:0.5b".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.model_for(LlmTask::NpcDialogue).as_deref(),
+            Some("llama3.1:70b")
+        );
+        assert_eq!(
+            settings.model_for(LlmTask::Classification).as_deref(),
+            Some("qwen2.5:0.5b")
+        );
+
+        settings.model_routing.suggestions = ModelTier::Large;
+        assert_eq!(
+            settings.model_for(LlmTask::Suggestion).as_deref(),
+            Some("llama3.1:70b")
+        );
+    }
+
+    #[test]
+    fn test_llm_api_key_round_trips_without_overwriting() {
+        let saved = LlmProviderSettings {
+            provider: LlmProvider::Anthropic,
+            api_key: Some("sk-ant-secret".into()),
+            ..Default::default()
+        };
+        assert!(saved.uses_hosted());
+
+        let shown = saved.redacted();
+        assert_eq!(shown.api_key.as_deref(), Some(REDACTED_SECRET));
+        assert_eq!(shown.with_saved_secret(&saved), saved);
+
+        // A hosted provider without a key stays on Ollama
+        let keyless = LlmProviderSettings {
+            api_key: Some("  ".into()),
+            ..saved
+        };
+        assert!(!keyless.uses_hosted());
+
+        let json = serde_json::json!({ "provider": "openai" });
+        let parsed: LlmProviderSettings = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.provider, LlmProvider::OpenAi);
+    }
+
+    fn preset(name: &str, asset_type: AssetType, is_default: bool) -> GenerationPreset {
+        GenerationPreset {
+            name: name.into(),
+            asset_type,
+            style_prompt: format!("{} style", name),
+            negative_prompt: None,
+            width: None,
+            height: None,
+            workflow: None,
+            is_default,
+        }
+    }
+
+    #[test]
+    fn test_generation_preset_lookup_by_name_or_slot_default() {
+        let settings = AppSettings {
+            generation_presets: vec![
+                preset("Watercolor", AssetType::Portrait, true),
+                preset("Noir", AssetType::Portrait, false),
+                preset("Pixel", AssetType::Sprite, false),
+            ],
+            ..Default::default()
+        };
+
+        let named = settings.generation_preset(AssetType::Portrait, Some("noir"));
+        assert_eq!(named.map(|p| p.name.as_str()), Some("Noir"));
+        let default = settings.generation_preset(AssetType::Portrait, None);
+        assert_eq!(default.map(|p| p.name.as_str()), Some("Watercolor"));
+        // Presets only apply to their own slot, and sprites have no default
+        assert!(settings
+            .generation_preset(AssetType::Backdrop, Some("Noir"))
+            .is_none());
+        assert!(settings
+            .generation_preset(AssetType::Sprite, None)
+            .is_none());
+    }
+
+    #[test]
+    fn test_generation_preset_styles_prompts_and_sizes() {
+        let preset = GenerationPreset {
+            negative_prompt: Some("photo".into()),
+            width: Some(320),
+            ..preset("Ink", AssetType::Portrait, false)
+        };
+
+        assert_eq!(
+            preset.styled_prompt("an old knight"),
+            "an old knight, Ink style"
+        );
+        assert_eq!(
+            preset.styled_negative_prompt(Some("blurry")).as_deref(),
+            Some("blurry, photo")
+        );
+        assert_eq!(
+            preset.styled_negative_prompt(None).as_deref(),
+            Some("photo")
+        );
+        assert_eq!(preset.dimensions(), (320, 256));
+    }
+}
@@ -0,0 +1,195 @@
+//! Spotlight - turn rotation without initiative
+//!
+//! Narrative systems have no initiative order, so a loud player can hold the
+//! scene while a quiet one never gets a word in. The spotlight tracker keeps
+//! a short history of which PCs acted, hands the spotlight to whoever has
+//! gone longest without acting, and flags PCs who have sat out too many
+//! actions. In crowded scenes it can also refuse a player's second action in
+//! a row so someone else gets a turn.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::PlayerCharacterId;
+
+/// How many past actions the tracker remembers
+pub const SPOTLIGHT_HISTORY: usize = 100;
+
+/// Default number of other PCs' actions before a PC counts as quiet
+pub const DEFAULT_QUIET_AFTER: u32 = 4;
+
+/// A world's spotlight settings and action history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Spotlight {
+    /// Whether the tracker runs for the world
+    #[serde(default)]
+    pub enabled: bool,
+    /// Actions by other PCs after which a PC who hasn't acted is flagged
+    #[serde(default = "default_quiet_after")]
+    pub quiet_after: u32,
+    /// Refuse back-to-back actions from one PC when at least this many PCs
+    /// share its region; 0 never refuses
+    #[serde(default)]
+    pub throttle_crowd: u32,
+    /// The PC the DM is turning to
+    #[serde(default)]
+    pub focus_pc_id: Option<PlayerCharacterId>,
+    /// PCs that acted, oldest first
+    #[serde(default)]
+    pub recent: Vec<PlayerCharacterId>,
+}
+
+fn default_quiet_after() -> u32 {
+    DEFAULT_QUIET_AFTER
+}
+
+impl Default for Spotlight {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            quiet_after: DEFAULT_QUIET_AFTER,
+            throttle_crowd: 0,
+            focus_pc_id: None,
+            recent: Vec::new(),
+        }
+    }
+}
+
+impl Spotlight {
+    /// Change the settings, keeping the history
+    pub fn configure(
+        &mut self,
+        enabled: bool,
+        quiet_after: u32,
+        throttle_crowd: u32,
+    ) -> Result<(), DomainError> {
+        if quiet_after == 0 {
+            return Err(DomainError::validation(
+                "A PC must sit out at least one action before counting as quiet",
+            ));
+        }
+        if throttle_crowd == 1 {
+            return Err(DomainError::validation(
+                "Throttling needs a crowd of at least two PCs",
+            ));
+        }
+        self.enabled = enabled;
+        self.quiet_after = quiet_after;
+        self.throttle_crowd = throttle_crowd;
+        Ok(())
+    }
+
+    /// Note that a PC acted. Acting takes the spotlight off them if they
+    /// had it.
+    pub fn record_action(&mut self, pc_id: PlayerCharacterId) {
+        self.recent.push(pc_id);
+        if self.recent.len() > SPOTLIGHT_HISTORY {
+            let excess = self.recent.len() - SPOTLIGHT_HISTORY;
+            self.recent.drain(..excess);
+        }
+        if self.focus_pc_id == Some(pc_id) {
+            self.focus_pc_id = None;
+        }
+    }
+
+    /// How many actions other PCs took since this PC last acted. A PC who
+    /// never acted counts the whole history.
+    pub fn actions_since(&self, pc_id: PlayerCharacterId) -> u32 {
+        let since = match self.recent.iter().rposition(|id| *id == pc_id) {
+            Some(index) => self.recent.len() - index - 1,
+            None => self.recent.len(),
+        };
+        since as u32
+    }
+
+    /// Whether a PC has sat out long enough to be flagged to the DM
+    pub fn is_quiet(&self, pc_id: PlayerCharacterId) -> bool {
+        self.enabled && self.actions_since(pc_id) >= self.quiet_after
+    }
+
+    /// Whether to refuse a PC's action: it acted last, and its region holds
+    /// a crowd of `pcs_present` PCs
+    pub fn throttles(&self, pc_id: PlayerCharacterId, pcs_present: usize) -> bool {
+        self.enabled
+            && self.throttle_crowd > 0
+            && pcs_present >= self.throttle_crowd as usize
+            && self.recent.last() == Some(&pc_id)
+    }
+
+    /// The PCs in the order the spotlight should visit them: longest
+    /// without acting first
+    pub fn rotation(&self, pc_ids: &[PlayerCharacterId]) -> Vec<PlayerCharacterId> {
+        let mut order = pc_ids.to_vec();
+        order.sort_by_key(|pc_id| std::cmp::Reverse(self.actions_since(*pc_id)));
+        order
+    }
+
+    /// Pass the spotlight to the next PC in the rotation who doesn't
+    /// already hold it
+    pub fn rotate(&mut self, pc_ids: &[PlayerCharacterId]) -> Option<PlayerCharacterId> {
+        let next = self
+            .rotation(pc_ids)
+            .into_iter()
+            .find(|pc_id| Some(*pc_id) != self.focus_pc_id)
+            .or(self.focus_pc_id);
+        self.focus_pc_id = next;
+        next
+    }
+
+    /// Forget who acted, keeping the settings
+    pub fn reset(&mut self) {
+        self.recent.clear();
+        self.focus_pc_id = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_spotlight_turns_to_whoever_waited_longest() {
+        let mut spotlight = Spotlight::default();
+        spotlight.configure(true, 2, 0).unwrap();
+        let (ana, bo, cy) = (
+            PlayerCharacterId::new(),
+            PlayerCharacterId::new(),
+            PlayerCharacterId::new(),
+        );
+
+        spotlight.record_action(cy);
+        spotlight.record_action(ana);
+        spotlight.record_action(ana);
+        assert_eq!(spotlight.actions_since(ana), 0);
+        assert_eq!(spotlight.actions_since(cy), 2);
+        assert!(spotlight.is_quiet(bo));
+        assert!(spotlight.is_quiet(cy));
+        assert!(!spotlight.is_quiet(ana));
+
+        assert_eq!(spotlight.rotation(&[ana, bo, cy]), vec![bo, cy, ana]);
+        assert_eq!(spotlight.rotate(&[ana, bo, cy]), Some(bo));
+        assert_eq!(spotlight.rotate(&[ana, bo, cy]), Some(cy));
+
+        // Acting takes the spotlight
+        spotlight.record_action(cy);
+        assert_eq!(spotlight.focus_pc_id, None);
+    }
+
+    #[test]
+    fn back_to_back_actions_are_throttled_in_a_crowd() {
+        let mut spotlight = Spotlight::default();
+        let ana = PlayerCharacterId::new();
+        spotlight.record_action(ana);
+        assert!(!spotlight.throttles(ana, 4));
+
+        spotlight.configure(true, 4, 3).unwrap();
+        assert!(spotlight.throttles(ana, 3));
+        assert!(!spotlight.throttles(ana, 2));
+        spotlight.record_action(PlayerCharacterId::new());
+        assert!(!spotlight.throttles(ana, 3));
+
+        assert!(spotlight.configure(true, 0, 0).is_err());
+        assert!(spotlight.configure(true, 4, 1).is_err());
+    }
+}
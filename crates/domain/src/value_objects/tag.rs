@@ -0,0 +1,80 @@
+//! Entity tags - freeform DM labels such as "act-2" or "villain-adjacent"
+//!
+//! Tags are compared after normalization: trimmed, lowercased, with runs of
+//! whitespace turned into a single `-`. "Act 2" and "act-2" are the same tag.
+
+use crate::error::DomainError;
+
+/// Longest tag accepted, in characters
+pub const MAX_TAG_LEN: usize = 40;
+
+/// Most tags a single entity may carry
+pub const MAX_TAGS_PER_ENTITY: usize = 32;
+
+/// Normalize one tag, rejecting empty, overlong, or control-character tags.
+pub fn normalize_tag(tag: &str) -> Result<String, DomainError> {
+    let normalized = tag
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+    if normalized.is_empty() {
+        return Err(DomainError::validation("Tags can't be empty"));
+    }
+    if normalized.chars().count() > MAX_TAG_LEN {
+        return Err(DomainError::validation(format!(
+            "Tag {:?} is longer than {} characters",
+            normalized, MAX_TAG_LEN
+        )));
+    }
+    if normalized.chars().any(char::is_control) {
+        return Err(DomainError::validation(format!(
+            "Tag {:?} contains control characters",
+            normalized
+        )));
+    }
+    Ok(normalized)
+}
+
+/// Normalize a set of tags, dropping duplicates and sorting them.
+pub fn normalize_tags<S: AsRef<str>>(tags: &[S]) -> Result<Vec<String>, DomainError> {
+    let mut normalized = tags
+        .iter()
+        .map(|tag| normalize_tag(tag.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    normalized.sort();
+    normalized.dedup();
+    if normalized.len() > MAX_TAGS_PER_ENTITY {
+        return Err(DomainError::validation(format!(
+            "At most {} tags are allowed, got {}",
+            MAX_TAGS_PER_ENTITY,
+            normalized.len()
+        )));
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_are_normalized() {
+        assert_eq!(normalize_tag("  Act 2 ").unwrap(), "act-2");
+        assert_eq!(
+            normalize_tag("Villain   Adjacent").unwrap(),
+            "villain-adjacent"
+        );
+        assert!(normalize_tag("   ").is_err());
+        assert!(normalize_tag(&"x".repeat(MAX_TAG_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_tag_sets_are_sorted_and_deduplicated() {
+        let tags = normalize_tags(&["villain", "Act 2", "act-2"]).unwrap();
+        assert_eq!(tags, vec!["act-2".to_string(), "villain".to_string()]);
+
+        let too_many: Vec<String> = (0..=MAX_TAGS_PER_ENTITY).map(|i| format!("t{i}")).collect();
+        assert!(normalize_tags(&too_many).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+//! Tutorial script - a step-by-step guide for new players
+//!
+//! A tutorial world carries a script of steps. Each step names one thing for
+//! the player to do (walk somewhere, talk to someone, roll a challenge); the
+//! engine completes the step when the player does it, so the guide runs
+//! without a DM. Progress is kept per PC as PC-scoped game flags, one per
+//! completed step.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ChallengeId, CharacterId, RegionId};
+
+/// Prefix for the PC flags that record completed tutorial steps
+pub const TUTORIAL_FLAG_PREFIX: &str = "tutorial:";
+
+/// An ordered list of tutorial steps
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialScript {
+    pub steps: Vec<TutorialStep>,
+}
+
+/// A single step of a tutorial script
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialStep {
+    /// Stable key for the step (used in the progress flag)
+    pub id: String,
+    /// Short heading shown in the guide (e.g., "Find your way")
+    pub title: String,
+    /// What the player should do, shown while the step is current
+    pub instructions: String,
+    /// Shown once the step is done (e.g., what the NPC would say)
+    #[serde(default)]
+    pub completion_text: Option<String>,
+    /// The action that completes the step
+    pub goal: TutorialGoal,
+}
+
+/// The player action a tutorial step waits for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TutorialGoal {
+    /// Walk into a region
+    #[serde(rename_all = "camelCase")]
+    EnterRegion { region_id: RegionId },
+    /// Start a conversation with an NPC
+    #[serde(rename_all = "camelCase")]
+    TalkToNpc { npc_id: CharacterId },
+    /// Submit a roll for a challenge (the engine prompts it when the step starts)
+    #[serde(rename_all = "camelCase")]
+    RollChallenge { challenge_id: ChallengeId },
+    /// Unknown goal (for forward compatibility; never completes)
+    #[serde(other)]
+    Unknown,
+}
+
+/// Something a player did that may complete a tutorial step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialAction {
+    EnteredRegion(RegionId),
+    StartedConversation(CharacterId),
+    RolledChallenge(ChallengeId),
+}
+
+impl TutorialGoal {
+    /// Whether the action is the one this goal waits for
+    pub fn is_met_by(&self, action: &TutorialAction) -> bool {
+        match (self, action) {
+            (TutorialGoal::EnterRegion { region_id }, TutorialAction::EnteredRegion(id)) => {
+                region_id == id
+            }
+            (TutorialGoal::TalkToNpc { npc_id }, TutorialAction::StartedConversation(id)) => {
+                npc_id == id
+            }
+            (TutorialGoal::RollChallenge { challenge_id }, TutorialAction::RolledChallenge(id)) => {
+                challenge_id == id
+            }
+            _ => false,
+        }
+    }
+}
+
+impl TutorialStep {
+    pub fn new(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        instructions: impl Into<String>,
+        goal: TutorialGoal,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            instructions: instructions.into(),
+            completion_text: None,
+            goal,
+        }
+    }
+
+    pub fn with_completion_text(mut self, completion_text: impl Into<String>) -> Self {
+        self.completion_text = Some(completion_text.into());
+        self
+    }
+
+    /// Name of the PC flag set when this step is completed
+    pub fn progress_flag(&self) -> String {
+        format!("{}{}", TUTORIAL_FLAG_PREFIX, self.id)
+    }
+}
+
+impl TutorialScript {
+    pub fn new(steps: Vec<TutorialStep>) -> Self {
+        Self { steps }
+    }
+
+    /// Index of the first step not yet completed, given the PC's flags.
+    ///
+    /// Returns `steps.len()` once every step is done.
+    pub fn current_index(&self, pc_flags: &[String]) -> usize {
+        self.steps
+            .iter()
+            .position(|step| !pc_flags.contains(&step.progress_flag()))
+            .unwrap_or(self.steps.len())
+    }
+
+    /// The step the PC is working on, or `None` if the tutorial is finished
+    pub fn current_step(&self, pc_flags: &[String]) -> Option<&TutorialStep> {
+        self.steps.get(self.current_index(pc_flags))
+    }
+
+    /// The current step, if the action completes it.
+    ///
+    /// Steps are done in order: an action that matches a later step does not
+    /// count until the player gets there.
+    pub fn step_completed_by(
+        &self,
+        pc_flags: &[String],
+        action: &TutorialAction,
+    ) -> Option<&TutorialStep> {
+        self.current_step(pc_flags)
+            .filter(|step| step.goal.is_met_by(action))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script(region_id: RegionId, npc_id: CharacterId) -> TutorialScript {
+        TutorialScript::new(vec![
+            TutorialStep::new(
+                "move",
+                "Find your way",
+                "Walk to the docks",
+                TutorialGoal::EnterRegion { region_id },
+            ),
+            TutorialStep::new(
+                "talk",
+                "Say hello",
+                "Talk to the harbormaster",
+                TutorialGoal::TalkToNpc { npc_id },
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_current_step_follows_completed_flags() {
+        let script = script(RegionId::new(), CharacterId::new());
+
+        assert_eq!(script.current_index(&[]), 0);
+        let flags = vec!["tutorial:move".to_string()];
+        assert_eq!(
+            script.current_step(&flags).map(|s| s.id.as_str()),
+            Some("talk")
+        );
+        let flags = vec!["tutorial:move".to_string(), "tutorial:talk".to_string()];
+        assert_eq!(script.current_index(&flags), 2);
+        assert!(script.current_step(&flags).is_none());
+    }
+
+    #[test]
+    fn test_only_current_step_can_be_completed() {
+        let region_id = RegionId::new();
+        let npc_id = CharacterId::new();
+        let script = script(region_id, npc_id);
+
+        // Talking first doesn't skip ahead
+        let talk = TutorialAction::StartedConversation(npc_id);
+        assert!(script.step_completed_by(&[], &talk).is_none());
+
+        let step = script.step_completed_by(&[], &TutorialAction::EnteredRegion(region_id));
+        assert_eq!(
+            step.map(|s| s.progress_flag()),
+            Some("tutorial:move".to_string())
+        );
+
+        let flags = vec!["tutorial:move".to_string()];
+        assert!(script.step_completed_by(&flags, &talk).is_some());
+    }
+
+    #[test]
+    fn test_goal_requires_matching_target() {
+        let goal = TutorialGoal::EnterRegion {
+            region_id: RegionId::new(),
+        };
+        assert!(!goal.is_met_by(&TutorialAction::EnteredRegion(RegionId::new())));
+        assert!(!TutorialGoal::Unknown.is_met_by(&TutorialAction::EnteredRegion(RegionId::new())));
+    }
+
+    #[test]
+    fn test_unknown_goal_deserializes() {
+        let step: TutorialStep = serde_json::from_str(
+            r#"{"id":"x","title":"X","instructions":"Do x","goal":{"type":"flyAway"}}"#,
+        )
+        .unwrap();
+        assert_eq!(step.goal, TutorialGoal::Unknown);
+        assert_eq!(step.completion_text, None);
+    }
+}
@@ -0,0 +1,197 @@
+//! World typography - Text direction, language, and font for a campaign
+//!
+//! Campaigns written in right-to-left scripts (Arabic, Hebrew, Persian) or in
+//! CJK languages need layout hints the Player cannot reliably guess from a
+//! handful of dialogue lines. The DM sets them once per world.
+
+use serde::{Deserialize, Serialize};
+
+/// Languages written right-to-left (BCP 47 primary subtags)
+const RTL_LANGUAGES: &[&str] = &["ar", "dv", "fa", "he", "ps", "sd", "ug", "ur", "yi"];
+
+/// Chinese, Japanese, and Korean primary subtags
+const CJK_LANGUAGES: &[&str] = &["ja", "ko", "zh"];
+
+/// Base direction for world text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TextDirection {
+    /// Derive from the language, or from each text's first strong character
+    #[default]
+    Auto,
+    LeftToRight,
+    RightToLeft,
+    /// Unknown direction (for forward compatibility; treated as Auto)
+    #[serde(other)]
+    Unknown,
+}
+
+impl std::fmt::Display for TextDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextDirection::Auto => write!(f, "auto"),
+            TextDirection::LeftToRight => write!(f, "ltr"),
+            TextDirection::RightToLeft => write!(f, "rtl"),
+            TextDirection::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Per-world typography settings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldTypography {
+    /// Base text direction
+    #[serde(default)]
+    pub direction: TextDirection,
+    /// BCP 47 language tag of the campaign text (e.g. "ar", "ja-JP")
+    #[serde(default)]
+    pub language: Option<String>,
+    /// CSS font family list for dialogue and sheets
+    #[serde(default)]
+    pub font_family: Option<String>,
+    /// Stylesheet URL that loads the font (e.g. a web font CSS)
+    #[serde(default)]
+    pub font_stylesheet_url: Option<String>,
+}
+
+impl WorldTypography {
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    pub fn with_direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn with_font_family(mut self, font_family: impl Into<String>) -> Self {
+        self.font_family = Some(font_family.into());
+        self
+    }
+
+    /// Trim fields, drop empty ones, and canonicalize the language tag case.
+    pub fn normalized(self) -> Self {
+        let clean = |value: Option<String>| {
+            value
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        Self {
+            direction: match self.direction {
+                TextDirection::Unknown => TextDirection::Auto,
+                other => other,
+            },
+            language: clean(self.language).map(|tag| canonical_language_tag(&tag)),
+            font_family: clean(self.font_family),
+            font_stylesheet_url: clean(self.font_stylesheet_url),
+        }
+    }
+
+    /// Primary language subtag in lowercase (e.g. "ja" for "ja-JP")
+    pub fn primary_language(&self) -> Option<String> {
+        self.language
+            .as_deref()
+            .and_then(|tag| tag.split(['-', '_']).next())
+            .filter(|subtag| !subtag.is_empty())
+            .map(str::to_lowercase)
+    }
+
+    /// Direction to render with, resolving `Auto` from the language when known.
+    ///
+    /// Returns `Auto` when neither the setting nor the language decides it, so
+    /// each text block can follow its own first strong character.
+    pub fn effective_direction(&self) -> TextDirection {
+        match self.direction {
+            TextDirection::LeftToRight | TextDirection::RightToLeft => self.direction,
+            TextDirection::Auto | TextDirection::Unknown => match self.primary_language() {
+                Some(lang) if RTL_LANGUAGES.contains(&lang.as_str()) => TextDirection::RightToLeft,
+                Some(_) => TextDirection::LeftToRight,
+                None => TextDirection::Auto,
+            },
+        }
+    }
+
+    /// Whether the campaign language needs CJK line-breaking rules
+    pub fn is_cjk(&self) -> bool {
+        self.primary_language()
+            .is_some_and(|lang| CJK_LANGUAGES.contains(&lang.as_str()))
+    }
+}
+
+/// Canonical BCP 47 casing: lowercase language, uppercase region, title-case script.
+fn canonical_language_tag(tag: &str) -> String {
+    tag.split(['-', '_'])
+        .filter(|part| !part.is_empty())
+        .enumerate()
+        .map(|(i, part)| match (i, part.len()) {
+            (0, _) => part.to_lowercase(),
+            (_, 2) => part.to_uppercase(),
+            (_, 4) => {
+                let mut chars = part.chars();
+                chars
+                    .next()
+                    .map(|first| {
+                        first
+                            .to_uppercase()
+                            .chain(chars.flat_map(char::to_lowercase))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            }
+            _ => part.to_lowercase(),
+        })
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalized_cleans_fields() {
+        let typography = WorldTypography {
+            direction: TextDirection::Unknown,
+            language: Some(" zh_hant_tw ".to_string()),
+            font_family: Some("   ".to_string()),
+            font_stylesheet_url: None,
+        }
+        .normalized();
+
+        assert_eq!(typography.direction, TextDirection::Auto);
+        assert_eq!(typography.language.as_deref(), Some("zh-Hant-TW"));
+        assert_eq!(typography.font_family, None);
+    }
+
+    #[test]
+    fn test_effective_direction_from_language() {
+        let arabic = WorldTypography::default().with_language("ar-EG");
+        assert_eq!(arabic.effective_direction(), TextDirection::RightToLeft);
+
+        let japanese = WorldTypography::default().with_language("ja");
+        assert_eq!(japanese.effective_direction(), TextDirection::LeftToRight);
+
+        assert_eq!(
+            WorldTypography::default().effective_direction(),
+            TextDirection::Auto
+        );
+    }
+
+    #[test]
+    fn test_explicit_direction_wins() {
+        let typography = WorldTypography::default()
+            .with_language("he")
+            .with_direction(TextDirection::LeftToRight);
+        assert_eq!(typography.effective_direction(), TextDirection::LeftToRight);
+    }
+
+    #[test]
+    fn test_is_cjk() {
+        assert!(WorldTypography::default().with_language("ja-JP").is_cjk());
+        assert!(WorldTypography::default().with_language("zh-Hans").is_cjk());
+        assert!(!WorldTypography::default().with_language("ar").is_cjk());
+        assert!(!WorldTypography::default().is_cjk());
+    }
+}
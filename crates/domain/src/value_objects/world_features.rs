@@ -0,0 +1,122 @@
+//! World features - per-world switches for experimental subsystems
+//!
+//! Unlike `GameFlag`s, which record story state, these are DM settings that
+//! decide whether an unfinished subsystem runs for the world at all. All of
+//! them default to off; clients read them from the join snapshot and hide
+//! UI for disabled features.
+
+use serde::{Deserialize, Serialize};
+
+/// An experimental subsystem that can be switched on per world
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExperimentalFeature {
+    /// Initiative order and HP tracking during fights
+    CombatTracker,
+    /// NPC-to-NPC spread of rumors about player actions
+    Rumors,
+    /// Weather per region, shown in scenes and fed to prompts
+    Weather,
+    /// Character aging reported to the DM after long time skips
+    AgeProgression,
+}
+
+impl ExperimentalFeature {
+    pub const ALL: [ExperimentalFeature; 4] = [
+        ExperimentalFeature::CombatTracker,
+        ExperimentalFeature::Rumors,
+        ExperimentalFeature::Weather,
+        ExperimentalFeature::AgeProgression,
+    ];
+}
+
+impl std::fmt::Display for ExperimentalFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExperimentalFeature::CombatTracker => write!(f, "combat_tracker"),
+            ExperimentalFeature::Rumors => write!(f, "rumors"),
+            ExperimentalFeature::Weather => write!(f, "weather"),
+            ExperimentalFeature::AgeProgression => write!(f, "age_progression"),
+        }
+    }
+}
+
+/// Per-world feature switches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldFeatures {
+    #[serde(default)]
+    pub combat_tracker: bool,
+    #[serde(default)]
+    pub rumors: bool,
+    #[serde(default)]
+    pub weather: bool,
+    #[serde(default)]
+    pub age_progression: bool,
+}
+
+impl WorldFeatures {
+    pub fn is_enabled(&self, feature: ExperimentalFeature) -> bool {
+        match feature {
+            ExperimentalFeature::CombatTracker => self.combat_tracker,
+            ExperimentalFeature::Rumors => self.rumors,
+            ExperimentalFeature::Weather => self.weather,
+            ExperimentalFeature::AgeProgression => self.age_progression,
+        }
+    }
+
+    pub fn with(mut self, feature: ExperimentalFeature, enabled: bool) -> Self {
+        self.set(feature, enabled);
+        self
+    }
+
+    pub fn set(&mut self, feature: ExperimentalFeature, enabled: bool) {
+        match feature {
+            ExperimentalFeature::CombatTracker => self.combat_tracker = enabled,
+            ExperimentalFeature::Rumors => self.rumors = enabled,
+            ExperimentalFeature::Weather => self.weather = enabled,
+            ExperimentalFeature::AgeProgression => self.age_progression = enabled,
+        }
+    }
+
+    /// Features currently switched on
+    pub fn enabled(&self) -> Vec<ExperimentalFeature> {
+        ExperimentalFeature::ALL
+            .into_iter()
+            .filter(|feature| self.is_enabled(*feature))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_everything_is_off_by_default() {
+        let features = WorldFeatures::default();
+        for feature in ExperimentalFeature::ALL {
+            assert!(!features.is_enabled(feature), "{feature} should be off");
+        }
+        assert!(features.enabled().is_empty());
+    }
+
+    #[test]
+    fn test_set_toggles_one_feature() {
+        let features = WorldFeatures::default().with(ExperimentalFeature::Rumors, true);
+        assert_eq!(features.enabled(), vec![ExperimentalFeature::Rumors]);
+        assert!(!features.with(ExperimentalFeature::Rumors, false).rumors);
+    }
+
+    #[test]
+    fn test_missing_fields_deserialize_as_disabled() {
+        let features: WorldFeatures = serde_json::from_str(r#"{"weather": true}"#).unwrap();
+        assert_eq!(
+            features,
+            WorldFeatures {
+                weather: true,
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! World scripts - small DM-written automation scripts
+//!
+//! A script is attached to a hook (item pickup, time advance, challenge
+//! resolution) and runs whenever that hook fires in its world. Scripts can
+//! only queue a handful of actions (give/take items, set flags, notify the
+//! DM); the engine applies them afterwards. Each script carries its own
+//! operation budget so a runaway loop stops instead of stalling the engine.
+
+use serde::{Deserialize, Serialize};
+
+use crate::EventEffect;
+
+/// Operations a script may run per invocation unless it says otherwise
+pub const DEFAULT_SCRIPT_BUDGET: u64 = 10_000;
+
+/// Most operations any script may be granted per invocation
+pub const MAX_SCRIPT_BUDGET: u64 = 1_000_000;
+
+/// Longest script source accepted
+pub const MAX_SCRIPT_SOURCE_LEN: usize = 16 * 1024;
+
+/// Most scripts a world may have
+pub const MAX_WORLD_SCRIPTS: usize = 50;
+
+/// When a script runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScriptHook {
+    /// A player character picked up an item
+    ItemPickup,
+    /// Game time moved forward
+    TimeAdvance,
+    /// A challenge outcome was approved
+    ChallengeResolved,
+}
+
+impl ScriptHook {
+    pub const ALL: [ScriptHook; 3] = [
+        ScriptHook::ItemPickup,
+        ScriptHook::TimeAdvance,
+        ScriptHook::ChallengeResolved,
+    ];
+
+    /// Whether the hook always has a player character to act on
+    pub fn has_player_character(&self) -> bool {
+        match self {
+            ScriptHook::ItemPickup | ScriptHook::ChallengeResolved => true,
+            ScriptHook::TimeAdvance => false,
+        }
+    }
+}
+
+impl std::fmt::Display for ScriptHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptHook::ItemPickup => write!(f, "item_pickup"),
+            ScriptHook::TimeAdvance => write!(f, "time_advance"),
+            ScriptHook::ChallengeResolved => write!(f, "challenge_resolved"),
+        }
+    }
+}
+
+/// A DM automation script
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldScript {
+    /// Unique (per world) script name
+    pub name: String,
+    pub hook: ScriptHook,
+    /// Rhai source
+    pub source: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Operations the script may run per invocation
+    #[serde(default = "default_budget")]
+    pub operation_budget: u64,
+}
+
+impl WorldScript {
+    pub fn new(name: impl Into<String>, hook: ScriptHook, source: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            hook,
+            source: source.into(),
+            enabled: true,
+            operation_budget: DEFAULT_SCRIPT_BUDGET,
+        }
+    }
+
+    pub fn with_operation_budget(mut self, operation_budget: u64) -> Self {
+        self.operation_budget = operation_budget;
+        self
+    }
+
+    /// Trim the name and clamp the budget to `1..=MAX_SCRIPT_BUDGET`.
+    pub fn normalized(mut self) -> Self {
+        self.name = self.name.trim().to_string();
+        self.operation_budget = self.operation_budget.clamp(1, MAX_SCRIPT_BUDGET);
+        self
+    }
+}
+
+/// What one script run asked for
+#[derive(Debug, Clone, Default)]
+pub struct ScriptOutput {
+    /// Effects to apply, in the order the script queued them
+    pub effects: Vec<EventEffect>,
+    /// Messages for the DM
+    pub notices: Vec<String>,
+}
+
+/// Check a world's scripts: non-empty unique names, bounded size and count.
+///
+/// Syntax is checked by the engine, which owns the interpreter.
+pub fn validate_world_scripts(scripts: &[WorldScript]) -> Result<(), String> {
+    if scripts.len() > MAX_WORLD_SCRIPTS {
+        return Err(format!(
+            "a world can have at most {MAX_WORLD_SCRIPTS} scripts"
+        ));
+    }
+    for (i, script) in scripts.iter().enumerate() {
+        if script.name.trim().is_empty() {
+            return Err("script names can't be empty".to_string());
+        }
+        if script.source.len() > MAX_SCRIPT_SOURCE_LEN {
+            return Err(format!(
+                "script {:?} is longer than {MAX_SCRIPT_SOURCE_LEN} bytes",
+                script.name
+            ));
+        }
+        if scripts[..i].iter().any(|s| s.name == script.name) {
+            return Err(format!("duplicate script name {:?}", script.name));
+        }
+    }
+    Ok(())
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_budget() -> u64 {
+    DEFAULT_SCRIPT_BUDGET
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalized_clamps_budget_and_trims_name() {
+        let script = WorldScript::new("  loot bell ", ScriptHook::ItemPickup, "")
+            .with_operation_budget(u64::MAX)
+            .normalized();
+        assert_eq!(script.name, "loot bell");
+        assert_eq!(script.operation_budget, MAX_SCRIPT_BUDGET);
+
+        let script = WorldScript::new("x", ScriptHook::TimeAdvance, "")
+            .with_operation_budget(0)
+            .normalized();
+        assert_eq!(script.operation_budget, 1);
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_and_empty_names() {
+        let script = WorldScript::new("a", ScriptHook::ItemPickup, "");
+        assert!(validate_world_scripts(std::slice::from_ref(&script)).is_ok());
+        assert!(validate_world_scripts(&[script.clone(), script]).is_err());
+        assert!(
+            validate_world_scripts(&[WorldScript::new(" ", ScriptHook::ItemPickup, "")]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_missing_fields_deserialize_with_defaults() {
+        let script: WorldScript =
+            serde_json::from_str(r#"{"name": "a", "hook": "timeAdvance", "source": "1"}"#).unwrap();
+        assert!(script.enabled);
+        assert_eq!(script.operation_budget, DEFAULT_SCRIPT_BUDGET);
+    }
+}
@@ -0,0 +1,151 @@
+//! World theme - DM-chosen accent color and backdrop frame
+//!
+//! The Player's light/dark/high-contrast choice is a per-device preference;
+//! this is the part of the look that belongs to the campaign itself.
+
+use serde::{Deserialize, Serialize};
+
+/// Decorative frame drawn around scene backdrops
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BackdropFrame {
+    /// No frame, backdrop fills the scene
+    #[default]
+    Plain,
+    /// Thin accent-colored border
+    Simple,
+    /// Double border with corner flourishes
+    Ornate,
+    /// Torn parchment edges
+    Parchment,
+    /// Dark vignette fading into the edges
+    Vignette,
+    /// Unknown frame (for forward compatibility; treated as Plain)
+    #[serde(other)]
+    Unknown,
+}
+
+impl std::fmt::Display for BackdropFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackdropFrame::Plain => write!(f, "plain"),
+            BackdropFrame::Simple => write!(f, "simple"),
+            BackdropFrame::Ornate => write!(f, "ornate"),
+            BackdropFrame::Parchment => write!(f, "parchment"),
+            BackdropFrame::Vignette => write!(f, "vignette"),
+            BackdropFrame::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Per-world theme settings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldTheme {
+    /// Accent color as a hex string ("#rrggbb"); the Player default when unset
+    #[serde(default)]
+    pub accent_color: Option<String>,
+    /// Frame drawn around scene backdrops
+    #[serde(default)]
+    pub backdrop_frame: BackdropFrame,
+}
+
+impl WorldTheme {
+    pub fn with_accent_color(mut self, accent_color: impl Into<String>) -> Self {
+        self.accent_color = Some(accent_color.into());
+        self
+    }
+
+    pub fn with_backdrop_frame(mut self, backdrop_frame: BackdropFrame) -> Self {
+        self.backdrop_frame = backdrop_frame;
+        self
+    }
+
+    /// Canonicalize the accent color to "#rrggbb", dropping invalid values.
+    pub fn normalized(self) -> Self {
+        Self {
+            accent_color: self
+                .accent_color
+                .as_deref()
+                .and_then(parse_hex_color)
+                .map(|(r, g, b)| format!("#{:02x}{:02x}{:02x}", r, g, b)),
+            backdrop_frame: match self.backdrop_frame {
+                BackdropFrame::Unknown => BackdropFrame::Plain,
+                other => other,
+            },
+        }
+    }
+
+    /// Accent color as RGB components, if set and valid
+    pub fn accent_rgb(&self) -> Option<(u8, u8, u8)> {
+        self.accent_color.as_deref().and_then(parse_hex_color)
+    }
+}
+
+/// Parse "#rgb" or "#rrggbb" (the "#" is optional).
+fn parse_hex_color(value: &str) -> Option<(u8, u8, u8)> {
+    let hex = value.trim().trim_start_matches('#');
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    match hex.len() {
+        3 => {
+            let mut digits = hex.chars().map(|c| c.to_string().repeat(2));
+            Some((
+                channel(&digits.next()?)?,
+                channel(&digits.next()?)?,
+                channel(&digits.next()?)?,
+            ))
+        }
+        6 => Some((
+            channel(&hex[0..2])?,
+            channel(&hex[2..4])?,
+            channel(&hex[4..6])?,
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalized_canonicalizes_accent() {
+        let theme = WorldTheme::default()
+            .with_accent_color(" #3AF ")
+            .normalized();
+        assert_eq!(theme.accent_color.as_deref(), Some("#33aaff"));
+
+        let theme = WorldTheme::default()
+            .with_accent_color("8B0000")
+            .normalized();
+        assert_eq!(theme.accent_color.as_deref(), Some("#8b0000"));
+    }
+
+    #[test]
+    fn test_normalized_drops_invalid_accent() {
+        for invalid in ["", "red", "#12345", "#ggg000"] {
+            let theme = WorldTheme::default()
+                .with_accent_color(invalid)
+                .normalized();
+            assert_eq!(theme.accent_color, None, "{invalid:?} should be dropped");
+        }
+    }
+
+    #[test]
+    fn test_unknown_frame_normalizes_to_plain() {
+        let theme = WorldTheme::default()
+            .with_backdrop_frame(BackdropFrame::Unknown)
+            .normalized();
+        assert_eq!(theme.backdrop_frame, BackdropFrame::Plain);
+    }
+
+    #[test]
+    fn test_accent_rgb() {
+        let theme = WorldTheme::default().with_accent_color("#d4af37");
+        assert_eq!(theme.accent_rgb(), Some((212, 175, 55)));
+        assert_eq!(WorldTheme::default().accent_rgb(), None);
+    }
+}
@@ -12,7 +12,7 @@ use uuid::Uuid;
 /// Timeout for critical message sends (5 seconds)
 const CRITICAL_SEND_TIMEOUT: Duration = Duration::from_secs(5);
 
-use wrldbldr_domain::{PlayerCharacterId, WorldId};
+use wrldbldr_domain::{ParallelScene, ParallelSceneId, PlayerCharacterId, WorldId};
 use wrldbldr_protocol::{DirectorialContext, ServerMessage};
 
 /// Represents a connected client's role in a world.
@@ -37,10 +37,14 @@ pub struct ConnectionInfo {
     pub world_id: Option<WorldId>,
     /// The role in the world
     pub role: WorldRole,
-    /// Player character ID (if role is Player)
+    /// Active player character ID (if role is Player)
     pub pc_id: Option<PlayerCharacterId>,
+    /// Every PC this connection controls, in the order taken (includes `pc_id`)
+    pub controlled_pc_ids: Vec<PlayerCharacterId>,
     /// Spectate target (if role is Spectator)
     pub spectate_pc_id: Option<PlayerCharacterId>,
+    /// Locale for server-generated text (a supported catalog locale)
+    pub locale: String,
 }
 
 impl ConnectionInfo {
@@ -48,6 +52,11 @@ impl ConnectionInfo {
     pub fn is_dm(&self) -> bool {
         matches!(self.role, WorldRole::Dm)
     }
+
+    /// Check if this connection controls a PC (solo players may control several).
+    pub fn controls_pc(&self, pc_id: PlayerCharacterId) -> bool {
+        self.controlled_pc_ids.contains(&pc_id)
+    }
 }
 
 /// Manages all active WebSocket connections.
@@ -56,6 +65,8 @@ pub struct ConnectionManager {
     connections: RwLock<HashMap<Uuid, (ConnectionInfo, mpsc::Sender<ServerMessage>)>>,
     /// Per-world directorial context (scene notes, NPC motivations, etc.)
     directorial_contexts: DashMap<WorldId, DirectorialContext>,
+    /// Per-world parallel scene of each PC in a split party
+    scene_routes: DashMap<WorldId, HashMap<PlayerCharacterId, ParallelSceneId>>,
 }
 
 impl ConnectionManager {
@@ -64,6 +75,7 @@ impl ConnectionManager {
         Self {
             connections: RwLock::new(HashMap::new()),
             directorial_contexts: DashMap::new(),
+            scene_routes: DashMap::new(),
         }
     }
 
@@ -80,7 +92,9 @@ impl ConnectionManager {
             world_id: None,
             role: WorldRole::Spectator,
             pc_id: None,
+            controlled_pc_ids: Vec::new(),
             spectate_pc_id: None,
+            locale: super::i18n::DEFAULT_LOCALE.to_string(),
         };
         let mut connections = self.connections.write().await;
         connections.insert(connection_id, (info, sender));
@@ -120,6 +134,31 @@ impl ConnectionManager {
         }
     }
 
+    /// Set the locale used to localize messages sent to a connection.
+    pub async fn set_locale(&self, connection_id: Uuid, locale: String) {
+        let mut connections = self.connections.write().await;
+        if let Some((info, _)) = connections.get_mut(&connection_id) {
+            info.locale = locale;
+        }
+    }
+
+    /// Get the locale for a connection (English if unknown).
+    pub async fn locale(&self, connection_id: Uuid) -> String {
+        let connections = self.connections.read().await;
+        connections
+            .get(&connection_id)
+            .map(|(info, _)| info.locale.clone())
+            .unwrap_or_else(|| super::i18n::DEFAULT_LOCALE.to_string())
+    }
+
+    /// Get the world a connection has joined, if any.
+    pub async fn world_of(&self, connection_id: Uuid) -> Option<WorldId> {
+        let connections = self.connections.read().await;
+        connections
+            .get(&connection_id)
+            .and_then(|(info, _)| info.world_id)
+    }
+
     /// Join a world.
     pub async fn join_world(
         &self,
@@ -184,6 +223,7 @@ impl ConnectionManager {
             info.world_id = Some(world_id);
             info.role = role;
             info.pc_id = pc_id;
+            info.controlled_pc_ids = pc_id.into_iter().collect();
             tracing::info!(
                 connection_id = %connection_id,
                 world_id = %world_id,
@@ -197,6 +237,67 @@ impl ConnectionManager {
         }
     }
 
+    /// Take control of another PC as well, keeping the active PC.
+    ///
+    /// Only players in a world may do this, and not for a PC another
+    /// connection in the world already controls.
+    pub async fn control_pc(
+        &self,
+        connection_id: Uuid,
+        pc_id: PlayerCharacterId,
+    ) -> Result<ConnectionInfo, ConnectionError> {
+        let mut connections = self.connections.write().await;
+        let world_id = match connections.get(&connection_id) {
+            Some((info, _)) if info.role == WorldRole::Player => {
+                info.world_id.ok_or(ConnectionError::Unauthorized)?
+            }
+            Some(_) => return Err(ConnectionError::Unauthorized),
+            None => return Err(ConnectionError::NotFound),
+        };
+
+        if connections.values().any(|(info, _)| {
+            info.connection_id != connection_id
+                && info.world_id == Some(world_id)
+                && info.controls_pc(pc_id)
+        }) {
+            return Err(ConnectionError::Unauthorized);
+        }
+
+        let (info, _) = connections
+            .get_mut(&connection_id)
+            .ok_or(ConnectionError::NotFound)?;
+        if !info.controls_pc(pc_id) {
+            info.controlled_pc_ids.push(pc_id);
+        }
+        if info.pc_id.is_none() {
+            info.pc_id = Some(pc_id);
+        }
+        tracing::info!(
+            connection_id = %connection_id,
+            pc_id = %pc_id,
+            controlled = info.controlled_pc_ids.len(),
+            "Connection took control of PC"
+        );
+        Ok(info.clone())
+    }
+
+    /// Make one of the connection's controlled PCs the active one.
+    pub async fn switch_pc(
+        &self,
+        connection_id: Uuid,
+        pc_id: PlayerCharacterId,
+    ) -> Result<ConnectionInfo, ConnectionError> {
+        let mut connections = self.connections.write().await;
+        let (info, _) = connections
+            .get_mut(&connection_id)
+            .ok_or(ConnectionError::NotFound)?;
+        if !info.controls_pc(pc_id) {
+            return Err(ConnectionError::Unauthorized);
+        }
+        info.pc_id = Some(pc_id);
+        Ok(info.clone())
+    }
+
     /// Leave the current world.
     pub async fn leave_world(&self, connection_id: Uuid) {
         let mut connections = self.connections.write().await;
@@ -204,6 +305,7 @@ impl ConnectionManager {
             let old_world = info.world_id.take();
             info.role = WorldRole::Spectator;
             info.pc_id = None;
+            info.controlled_pc_ids.clear();
             info.spectate_pc_id = None;
             if let Some(world_id) = old_world {
                 tracing::info!(
@@ -278,11 +380,137 @@ impl ConnectionManager {
         }
     }
 
+    /// Broadcast a message to the players and spectators in a world.
+    pub async fn broadcast_to_players(&self, world_id: WorldId, message: ServerMessage) {
+        let connections = self.connections.read().await;
+        for (info, sender) in connections.values() {
+            if info.world_id == Some(world_id) && !info.is_dm() {
+                if let Err(e) = sender.try_send(message.clone()) {
+                    tracing::warn!(
+                        connection_id = %info.connection_id,
+                        error = %e,
+                        "Failed to broadcast to player"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Broadcast a message to the scene a PC is playing in: the DMs, and
+    /// the players in the same parallel scene. With the party together, or
+    /// no PC to go by, this is the whole main table.
+    pub async fn broadcast_to_scene(
+        &self,
+        world_id: WorldId,
+        pc_id: Option<PlayerCharacterId>,
+        message: ServerMessage,
+    ) {
+        let scene_id = pc_id.and_then(|pc_id| self.scene_of(world_id, pc_id));
+        self.send_to_scenes(world_id, &[scene_id], None, message)
+            .await;
+    }
+
+    /// Broadcast a message to a PC's scene, leaving out one connection
+    /// (usually the sender, who already has it).
+    pub async fn broadcast_to_scene_except(
+        &self,
+        world_id: WorldId,
+        pc_id: Option<PlayerCharacterId>,
+        exclude_connection_id: Uuid,
+        message: ServerMessage,
+    ) {
+        let scene_id = pc_id.and_then(|pc_id| self.scene_of(world_id, pc_id));
+        self.send_to_scenes(world_id, &[scene_id], Some(exclude_connection_id), message)
+            .await;
+    }
+
+    /// Broadcast a message to the scenes of several PCs, such as everyone
+    /// waiting on a region. With no PCs to go by, this is the main table.
+    pub async fn broadcast_to_scenes_of(
+        &self,
+        world_id: WorldId,
+        pc_ids: &[PlayerCharacterId],
+        message: ServerMessage,
+    ) {
+        let mut scene_ids: Vec<_> = pc_ids
+            .iter()
+            .map(|pc_id| self.scene_of(world_id, *pc_id))
+            .collect();
+        if scene_ids.is_empty() {
+            scene_ids.push(None);
+        }
+        self.send_to_scenes(world_id, &scene_ids, None, message)
+            .await;
+    }
+
+    async fn send_to_scenes(
+        &self,
+        world_id: WorldId,
+        scene_ids: &[Option<ParallelSceneId>],
+        exclude_connection_id: Option<Uuid>,
+        message: ServerMessage,
+    ) {
+        let connections = self.connections.read().await;
+        for (info, sender) in connections.values() {
+            if info.world_id != Some(world_id) || exclude_connection_id == Some(info.connection_id)
+            {
+                continue;
+            }
+            if !info.is_dm()
+                && !scene_ids
+                    .iter()
+                    .any(|scene_id| self.in_scene(world_id, info, *scene_id))
+            {
+                continue;
+            }
+            if let Err(e) = sender.try_send(message.clone()) {
+                tracing::warn!(
+                    connection_id = %info.connection_id,
+                    error = %e,
+                    "Failed to broadcast to scene"
+                );
+            }
+        }
+    }
+
+    /// Broadcast a message to every DM, whatever world they are in.
+    pub async fn broadcast_to_all_dms(&self, message: ServerMessage) {
+        let connections = self.connections.read().await;
+        for (info, sender) in connections.values() {
+            if info.world_id.is_some() && info.is_dm() {
+                if let Err(e) = sender.try_send(message.clone()) {
+                    tracing::warn!(
+                        connection_id = %info.connection_id,
+                        error = %e,
+                        "Failed to broadcast to DM"
+                    );
+                }
+            }
+        }
+    }
+
     /// Send a message to a specific PC's player.
     pub async fn send_to_pc(&self, pc_id: PlayerCharacterId, message: ServerMessage) {
         let connections = self.connections.read().await;
         for (info, sender) in connections.values() {
-            if info.pc_id == Some(pc_id) || info.spectate_pc_id == Some(pc_id) {
+            if info.controls_pc(pc_id) || info.spectate_pc_id == Some(pc_id) {
+                if let Err(e) = sender.try_send(message.clone()) {
+                    tracing::warn!(
+                        connection_id = %info.connection_id,
+                        error = %e,
+                        "Failed to send to PC"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Send a message to the players controlling a PC, leaving out anyone
+    /// spectating it. For what only the PC's own player may see.
+    pub async fn send_to_pc_controllers(&self, pc_id: PlayerCharacterId, message: ServerMessage) {
+        let connections = self.connections.read().await;
+        for (info, sender) in connections.values() {
+            if info.controls_pc(pc_id) {
                 if let Err(e) = sender.try_send(message.clone()) {
                     tracing::warn!(
                         connection_id = %info.connection_id,
@@ -376,7 +604,7 @@ impl ConnectionManager {
     pub async fn send_critical_to_pc(&self, pc_id: PlayerCharacterId, message: ServerMessage) {
         let connections = self.connections.read().await;
         for (info, sender) in connections.values() {
-            if info.pc_id == Some(pc_id) || info.spectate_pc_id == Some(pc_id) {
+            if info.controls_pc(pc_id) || info.spectate_pc_id == Some(pc_id) {
                 match timeout(CRITICAL_SEND_TIMEOUT, sender.send(message.clone())).await {
                     Ok(Ok(())) => {}
                     Ok(Err(_)) => {
@@ -417,6 +645,46 @@ impl ConnectionManager {
     pub fn clear_directorial_context(&self, world_id: WorldId) {
         self.directorial_contexts.remove(&world_id);
     }
+
+    /// Route a world's traffic by its parallel scenes. Called whenever the
+    /// scenes change, and on join so a restarted engine picks them up.
+    pub fn set_scene_routes(&self, world_id: WorldId, scenes: &[ParallelScene]) {
+        if scenes.is_empty() {
+            self.scene_routes.remove(&world_id);
+            return;
+        }
+        let routes = scenes
+            .iter()
+            .flat_map(|scene| scene.pc_ids.iter().map(|pc_id| (*pc_id, scene.id)))
+            .collect();
+        self.scene_routes.insert(world_id, routes);
+    }
+
+    /// The parallel scene a PC is in; `None` is the main table
+    pub fn scene_of(&self, world_id: WorldId, pc_id: PlayerCharacterId) -> Option<ParallelSceneId> {
+        self.scene_routes
+            .get(&world_id)
+            .and_then(|routes| routes.get(&pc_id).copied())
+    }
+
+    /// Whether a player's connection is in a scene, by the PCs it controls
+    /// or spectates. Connections without a PC follow the main table.
+    fn in_scene(
+        &self,
+        world_id: WorldId,
+        info: &ConnectionInfo,
+        scene_id: Option<ParallelSceneId>,
+    ) -> bool {
+        let mut pcs = info
+            .controlled_pc_ids
+            .iter()
+            .chain(info.spectate_pc_id.iter())
+            .peekable();
+        if pcs.peek().is_none() {
+            return scene_id.is_none();
+        }
+        pcs.any(|pc_id| self.scene_of(world_id, *pc_id) == scene_id)
+    }
 }
 
 impl Default for ConnectionManager {
@@ -448,3 +716,89 @@ pub enum CriticalSendError {
     #[error("Send timeout - client may be slow or unresponsive")]
     Timeout,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn join(
+        manager: &ConnectionManager,
+        world_id: WorldId,
+        role: WorldRole,
+        pc_id: Option<PlayerCharacterId>,
+    ) -> (Uuid, mpsc::Receiver<ServerMessage>) {
+        let connection_id = Uuid::new_v4();
+        let (tx, rx) = mpsc::channel(8);
+        manager
+            .register(connection_id, connection_id.to_string(), tx)
+            .await;
+        manager
+            .join_world(connection_id, world_id, role, pc_id)
+            .await
+            .expect("join world");
+        (connection_id, rx)
+    }
+
+    fn heard(rx: &mut mpsc::Receiver<ServerMessage>) -> bool {
+        let mut any = false;
+        while rx.try_recv().is_ok() {
+            any = true;
+        }
+        any
+    }
+
+    fn ping() -> ServerMessage {
+        ServerMessage::ActionRetracted {
+            action_id: "ping".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn scene_broadcasts_reach_the_dm_and_the_scene_only() {
+        let manager = ConnectionManager::new();
+        let world_id = WorldId::new();
+        let (sewer_pc, sewer_friend, table_pc) = (
+            PlayerCharacterId::new(),
+            PlayerCharacterId::new(),
+            PlayerCharacterId::new(),
+        );
+        let (_, mut dm) = join(&manager, world_id, WorldRole::Dm, None).await;
+        let (roller, mut roller_rx) =
+            join(&manager, world_id, WorldRole::Player, Some(sewer_pc)).await;
+        let (_, mut friend) = join(&manager, world_id, WorldRole::Player, Some(sewer_friend)).await;
+        let (_, mut table) = join(&manager, world_id, WorldRole::Player, Some(table_pc)).await;
+
+        let sewers = ParallelScene::new(
+            "The sewers",
+            vec![sewer_pc, sewer_friend],
+            chrono::Utc::now(),
+        )
+        .expect("scene");
+        manager.set_scene_routes(world_id, &[sewers]);
+
+        // A roll is shared with the roller's scene, not echoed to the roller
+        manager
+            .broadcast_to_scene_except(world_id, Some(sewer_pc), roller, ping())
+            .await;
+        assert!(heard(&mut dm));
+        assert!(heard(&mut friend));
+        assert!(!heard(&mut roller_rx));
+        assert!(!heard(&mut table));
+
+        // Staging for a region reaches every scene with a PC waiting on it
+        manager
+            .broadcast_to_scenes_of(world_id, &[sewer_pc, table_pc], ping())
+            .await;
+        assert!(heard(&mut dm));
+        assert!(heard(&mut friend));
+        assert!(heard(&mut roller_rx));
+        assert!(heard(&mut table));
+
+        // With no PCs to go by, the main table
+        manager.broadcast_to_scenes_of(world_id, &[], ping()).await;
+        assert!(heard(&mut dm));
+        assert!(heard(&mut table));
+        assert!(!heard(&mut friend));
+        assert!(!heard(&mut roller_rx));
+    }
+}
@@ -1,7 +1,7 @@
 //! HTTP routes.
 
 use axum::{
-    extract::{Path, State},
+    extract::{DefaultBodyLimit, Path, Query, State},
     routing::{get, post},
     Json, Router,
 };
@@ -14,7 +14,8 @@ use crate::app::App;
 pub fn routes() -> Router<Arc<App>> {
     Router::new()
         .route("/", get(health))
-        .route("/api/health", get(health))
+        .route("/api/health", get(dependency_health))
+        .route("/api/metrics", get(metrics))
         .route("/api/worlds", get(list_worlds))
         .route("/api/worlds/{id}", get(get_world))
         .route("/api/worlds/{id}/export", get(export_world))
@@ -22,6 +23,8 @@ pub fn routes() -> Router<Arc<App>> {
         .route("/api/settings", get(get_settings).put(update_settings))
         .route("/api/settings/reset", post(reset_settings))
         .route("/api/settings/metadata", get(get_settings_metadata))
+        .route("/api/setup", get(get_setup_status).put(save_setup))
+        .route("/api/setup/check", post(check_setup))
         .route(
             "/api/worlds/{id}/settings",
             get(get_world_settings).put(update_world_settings),
@@ -34,6 +37,17 @@ pub fn routes() -> Router<Arc<App>> {
             "/api/rule-systems/{system_type}/presets/{variant}",
             get(get_rule_system_preset),
         )
+        .route("/api/assets/generate", post(generate_assets))
+        .route("/api/assets/url/{*key}", get(get_asset_url))
+        .route(
+            "/api/worlds/{id}/assets/upload",
+            post(upload_asset_image).layer(DefaultBodyLimit::max(UPLOAD_BODY_LIMIT)),
+        )
+        .route(
+            "/api/{entity_type}/{entity_id}/gallery",
+            post(attach_uploaded_asset),
+        )
+        .route("/api/blobs/{*key}", get(get_blob))
     // Add more routes as needed
 }
 
@@ -41,6 +55,59 @@ async fn health() -> &'static str {
     "OK"
 }
 
+/// Per-dependency health; 503 if any dependency is unhealthy.
+async fn dependency_health(
+    State(app): State<Arc<App>>,
+) -> (
+    axum::http::StatusCode,
+    Json<crate::use_cases::health::HealthReport>,
+) {
+    let report = app.use_cases.health.check.execute().await;
+    let status = if report.healthy {
+        axum::http::StatusCode::OK
+    } else {
+        axum::http::StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(report))
+}
+
+/// Circuit breaker state for one external service
+#[derive(serde::Serialize)]
+struct CircuitBreakerReport {
+    service: &'static str,
+    state: String,
+    consecutive_failures: u32,
+    total_failures: u64,
+    total_successes: u64,
+    open_count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_in_secs: Option<u64>,
+}
+
+#[derive(serde::Serialize)]
+struct MetricsReport {
+    circuit_breakers: Vec<CircuitBreakerReport>,
+}
+
+/// Runtime metrics for operators (currently circuit breaker state).
+async fn metrics(State(app): State<Arc<App>>) -> Json<MetricsReport> {
+    let circuit_breakers = app
+        .circuit_breakers
+        .metrics()
+        .into_iter()
+        .map(|(service, m)| CircuitBreakerReport {
+            service,
+            state: m.state.to_string(),
+            consecutive_failures: m.consecutive_failures,
+            total_failures: m.total_failures,
+            total_successes: m.total_successes,
+            open_count: m.open_count,
+            retry_in_secs: m.time_until_half_open.map(|d| d.as_secs()),
+        })
+        .collect();
+    Json(MetricsReport { circuit_breakers })
+}
+
 async fn list_worlds(
     State(app): State<Arc<App>>,
 ) -> Result<Json<Vec<wrldbldr_domain::World>>, ApiError> {
@@ -110,7 +177,14 @@ async fn get_settings(State(app): State<Arc<App>>) -> Result<Json<wrldbldr_domai
         .get_global()
         .await
         .map_err(|e| ApiError::Internal(e.to_string()))?;
-    Ok(Json(settings))
+    Ok(Json(redacted(settings)))
+}
+
+/// Settings as clients see them, without saved passwords or API keys
+fn redacted(mut settings: wrldbldr_domain::AppSettings) -> wrldbldr_domain::AppSettings {
+    settings.services = settings.services.redacted();
+    settings.llm_provider = settings.llm_provider.redacted();
+    settings
 }
 
 async fn update_settings(
@@ -123,7 +197,7 @@ async fn update_settings(
         .update_global(settings)
         .await
         .map_err(|e| ApiError::Internal(e.to_string()))?;
-    Ok(Json(updated))
+    Ok(Json(redacted(updated)))
 }
 
 async fn reset_settings(
@@ -135,7 +209,7 @@ async fn reset_settings(
         .reset_global()
         .await
         .map_err(|e| ApiError::Internal(e.to_string()))?;
-    Ok(Json(settings))
+    Ok(Json(redacted(settings)))
 }
 
 async fn get_settings_metadata(
@@ -154,7 +228,7 @@ async fn get_world_settings(
         .get_for_world(wrldbldr_domain::WorldId::from_uuid(id))
         .await
         .map_err(|e| ApiError::Internal(e.to_string()))?;
-    Ok(Json(settings))
+    Ok(Json(redacted(settings)))
 }
 
 async fn update_world_settings(
@@ -174,7 +248,7 @@ async fn update_world_settings(
         .update_for_world(wrldbldr_domain::WorldId::from_uuid(id), settings)
         .await
         .map_err(|e| ApiError::Internal(e.to_string()))?;
-    Ok(Json(updated))
+    Ok(Json(redacted(updated)))
 }
 
 async fn reset_world_settings(
@@ -187,7 +261,52 @@ async fn reset_world_settings(
         .reset_for_world(wrldbldr_domain::WorldId::from_uuid(id))
         .await
         .map_err(|e| ApiError::Internal(e.to_string()))?;
-    Ok(Json(settings))
+    Ok(Json(redacted(settings)))
+}
+
+// =============================================================================
+// First-run Setup
+// =============================================================================
+
+async fn get_setup_status(
+    State(app): State<Arc<App>>,
+) -> Result<Json<crate::use_cases::setup::SetupState>, ApiError> {
+    let state = app
+        .use_cases
+        .setup
+        .status
+        .execute()
+        .await
+        .map_err(|e| ApiError::Internal(e.to_string()))?;
+    Ok(Json(state))
+}
+
+async fn check_setup(
+    State(app): State<Arc<App>>,
+    Json(connections): Json<wrldbldr_domain::ServiceConnections>,
+) -> Result<Json<crate::use_cases::setup::ConnectionReport>, ApiError> {
+    let report = app
+        .use_cases
+        .setup
+        .check
+        .execute(connections)
+        .await
+        .map_err(|e| ApiError::Internal(e.to_string()))?;
+    Ok(Json(report))
+}
+
+async fn save_setup(
+    State(app): State<Arc<App>>,
+    Json(connections): Json<wrldbldr_domain::ServiceConnections>,
+) -> Result<Json<crate::use_cases::setup::SaveOutcome>, ApiError> {
+    let outcome = app
+        .use_cases
+        .setup
+        .save
+        .execute(connections)
+        .await
+        .map_err(|e| ApiError::Internal(e.to_string()))?;
+    Ok(Json(outcome))
 }
 
 // =============================================================================
@@ -267,6 +386,215 @@ fn parse_rule_system_variant(value: &str) -> Result<wrldbldr_domain::RuleSystemV
     Ok(variant)
 }
 
+// =============================================================================
+// Asset Storage
+// =============================================================================
+
+async fn get_asset_url(
+    State(app): State<Arc<App>>,
+    Path(key): Path<String>,
+) -> Result<Json<crate::use_cases::assets::SignedAssetUrl>, ApiError> {
+    let signed = app.use_cases.assets.storage.signed_url(&key)?;
+    Ok(Json(signed))
+}
+
+#[derive(serde::Deserialize)]
+struct SignedBlobQuery {
+    expires: i64,
+    signature: String,
+}
+
+async fn get_blob(
+    State(app): State<Arc<App>>,
+    Path(key): Path<String>,
+    Query(query): Query<SignedBlobQuery>,
+) -> Result<axum::response::Response, ApiError> {
+    use axum::response::IntoResponse;
+
+    let blob = app
+        .use_cases
+        .assets
+        .storage
+        .read_signed(&key, query.expires, &query.signature)
+        .await?;
+    Ok((
+        [
+            (axum::http::header::CONTENT_TYPE, blob.content_type),
+            // Content-addressed, so the bytes behind a key never change
+            (
+                axum::http::header::CACHE_CONTROL,
+                "private, max-age=3600, immutable",
+            ),
+        ],
+        blob.data,
+    )
+        .into_response())
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct QueuedGenerationResponse {
+    queue_id: String,
+}
+
+async fn generate_assets(
+    State(app): State<Arc<App>>,
+    Json(request): Json<wrldbldr_protocol::GenerateAssetRequestDto>,
+) -> Result<(axum::http::StatusCode, Json<QueuedGenerationResponse>), ApiError> {
+    let world_id = Uuid::parse_str(&request.world_id)
+        .map(wrldbldr_domain::WorldId::from_uuid)
+        .map_err(|_| ApiError::BadRequest(format!("Invalid world ID: {}", request.world_id)))?;
+    let asset_type = parse_asset_type(&request.asset_type)?;
+    let workflow = if request.workflow.trim().is_empty() {
+        asset_type.as_str().to_string()
+    } else {
+        request.workflow
+    };
+    let style_reference_id = request
+        .style_reference_id
+        .as_deref()
+        .map(|id| {
+            Uuid::parse_str(id)
+                .map(wrldbldr_domain::AssetId::from_uuid)
+                .map_err(|_| ApiError::BadRequest(format!("Invalid style reference ID: {}", id)))
+        })
+        .transpose()?;
+
+    let queue_id = app
+        .use_cases
+        .assets
+        .generate
+        .queue_generation(
+            Some(world_id),
+            &request.entity_type,
+            &request.entity_id,
+            asset_type,
+            crate::use_cases::assets::GenerationRequest {
+                prompt: request.prompt,
+                negative_prompt: request.negative_prompt,
+                workflow,
+                preset: request.preset,
+                style_reference_id,
+            },
+            u32::from(request.count.max(1)),
+        )
+        .await?;
+
+    Ok((
+        axum::http::StatusCode::ACCEPTED,
+        Json(QueuedGenerationResponse {
+            queue_id: queue_id.to_string(),
+        }),
+    ))
+}
+
+/// Room for a base64-encoded image at the upload size limit
+const UPLOAD_BODY_LIMIT: usize = crate::use_cases::assets::upload::MAX_UPLOAD_BYTES / 3 * 4 + 64 * 1024;
+
+async fn upload_asset_image(
+    State(app): State<Arc<App>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<wrldbldr_protocol::UploadAssetRequestDto>,
+) -> Result<Json<wrldbldr_protocol::UploadAssetResponseDto>, ApiError> {
+    use base64::Engine as _;
+
+    let data = request
+        .data
+        .as_deref()
+        .ok_or_else(|| ApiError::BadRequest("data is required".to_string()))?;
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(data.trim())
+        .map_err(|e| ApiError::BadRequest(format!("Invalid base64 image data: {}", e)))?;
+
+    let result = app
+        .use_cases
+        .assets
+        .upload
+        .upload(
+            wrldbldr_domain::WorldId::from_uuid(id),
+            crate::use_cases::assets::UploadedImage {
+                data,
+                asset_type: parse_asset_type(&request.asset_type)?,
+                file_name: request.file_name,
+                label: request.label,
+                caption: request.caption,
+            },
+        )
+        .await?;
+
+    Ok(Json(wrldbldr_protocol::UploadAssetResponseDto {
+        file_path: result.file_path,
+        caption: result.caption,
+        suggestions: result
+            .suggestions
+            .into_iter()
+            .map(|s| wrldbldr_protocol::AssetAssociationSuggestionDto {
+                entity_type: s.entity_type.to_string(),
+                entity_id: s.entity_id,
+                name: s.name,
+                confidence: s.confidence,
+                reason: s.reason,
+            })
+            .collect(),
+    }))
+}
+
+async fn attach_uploaded_asset(
+    State(app): State<Arc<App>>,
+    Path((entity_type, entity_id)): Path<(String, Uuid)>,
+    Json(request): Json<wrldbldr_protocol::UploadAssetRequestDto>,
+) -> Result<Json<wrldbldr_protocol::GalleryAssetResponseDto>, ApiError> {
+    let entity_type: wrldbldr_domain::EntityType =
+        entity_type.parse().map_err(ApiError::BadRequest)?;
+    if !entity_type.has_assets() {
+        return Err(ApiError::BadRequest(format!(
+            "{} entities have no gallery",
+            entity_type
+        )));
+    }
+    if request.file_path.is_empty() {
+        return Err(ApiError::BadRequest(
+            "file_path is required; upload the image first".to_string(),
+        ));
+    }
+
+    let asset = app
+        .use_cases
+        .assets
+        .upload
+        .attach(
+            entity_type,
+            entity_id,
+            parse_asset_type(&request.asset_type)?,
+            &request.file_path,
+            request.label,
+            request.set_active,
+        )
+        .await?;
+
+    Ok(Json(wrldbldr_protocol::GalleryAssetResponseDto {
+        id: asset.id.to_string(),
+        entity_type: asset.entity_type.to_string(),
+        entity_id: asset.entity_id,
+        asset_type: asset.asset_type.as_str().to_string(),
+        file_path: asset.file_path,
+        is_active: asset.is_active,
+        label: asset.label,
+        is_generated: asset.generation_metadata.is_some(),
+        style_reference_id: None,
+        created_at: asset.created_at.to_rfc3339(),
+    }))
+}
+
+fn parse_asset_type(value: &str) -> Result<wrldbldr_domain::AssetType, ApiError> {
+    match value.parse() {
+        Ok(wrldbldr_domain::AssetType::Unknown) | Err(_) => {
+            Err(ApiError::BadRequest(format!("Unknown asset type: {}", value)))
+        }
+        Ok(asset_type) => Ok(asset_type),
+    }
+}
+
 #[derive(serde::Serialize)]
 struct ImportWorldResponse {
     id: String,
@@ -276,6 +604,7 @@ struct ImportWorldResponse {
 pub enum ApiError {
     NotFound,
     BadRequest(String),
+    Forbidden,
     Internal(String),
 }
 
@@ -288,6 +617,9 @@ impl axum::response::IntoResponse for ApiError {
             ApiError::BadRequest(msg) => {
                 (axum::http::StatusCode::BAD_REQUEST, msg).into_response()
             }
+            ApiError::Forbidden => {
+                (axum::http::StatusCode::FORBIDDEN, "Forbidden").into_response()
+            }
             ApiError::Internal(_) => (
                 axum::http::StatusCode::INTERNAL_SERVER_ERROR,
                 "Internal error",
@@ -303,6 +635,46 @@ impl From<crate::infrastructure::ports::RepoError> for ApiError {
     }
 }
 
+impl From<crate::use_cases::assets::AssetStorageError> for ApiError {
+    fn from(e: crate::use_cases::assets::AssetStorageError) -> Self {
+        use crate::infrastructure::ports::BlobError;
+        use crate::use_cases::assets::AssetStorageError;
+
+        match e {
+            AssetStorageError::InvalidSignature => ApiError::Forbidden,
+            AssetStorageError::Blob(BlobError::NotFound) => ApiError::NotFound,
+            AssetStorageError::Blob(BlobError::InvalidKey(key)) => {
+                ApiError::BadRequest(format!("Invalid asset key: {}", key))
+            }
+            AssetStorageError::Blob(e) => ApiError::Internal(e.to_string()),
+        }
+    }
+}
+
+impl From<crate::use_cases::assets::GenerateError> for ApiError {
+    fn from(e: crate::use_cases::assets::GenerateError) -> Self {
+        use crate::use_cases::assets::GenerateError;
+
+        match e {
+            GenerateError::UnknownPreset(_) => ApiError::BadRequest(e.to_string()),
+            _ => ApiError::Internal(e.to_string()),
+        }
+    }
+}
+
+impl From<crate::use_cases::assets::UploadError> for ApiError {
+    fn from(e: crate::use_cases::assets::UploadError) -> Self {
+        use crate::use_cases::assets::UploadError;
+
+        match e {
+            UploadError::TooLarge(_)
+            | UploadError::UnsupportedFormat
+            | UploadError::InvalidPath(_) => ApiError::BadRequest(e.to_string()),
+            UploadError::Blob(_) | UploadError::Repo(_) => ApiError::Internal(e.to_string()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -410,6 +782,135 @@ mod tests {
         assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn save_setup_keeps_saved_password_and_skips_failed_checks() {
+        use crate::infrastructure::ports::ProbeError;
+
+        let mut repos = TestAppRepos::new(MockWorldRepo::new());
+        let mut saved = wrldbldr_domain::AppSettings::default();
+        saved.services.neo4j_password = Some("hunter2".to_string());
+        repos
+            .settings_repo
+            .expect_get_global()
+            .returning(move || Ok(Some(saved.clone())));
+        repos.settings_repo.expect_save_global().times(0);
+        repos
+            .service_probe
+            .expect_check_neo4j()
+            .withf(|uri, _user, password| uri == "bolt://db:7687" && password == "hunter2")
+            .returning(|_, _, _| Ok(()));
+        repos
+            .service_probe
+            .expect_check_ollama()
+            .returning(|_, model| Err(ProbeError::Rejected(format!("Model '{}' is not pulled", model))));
+        repos
+            .service_probe
+            .expect_check_comfyui()
+            .returning(|_| Ok(()));
+
+        let app = crate::api::websocket::test_support::build_test_app(repos, Utc::now());
+        let router: Router = routes().with_state(app);
+
+        // The wizard sends back the redacted password it was shown.
+        let candidate = wrldbldr_domain::ServiceConnections {
+            neo4j_uri: Some("bolt://db:7687".to_string()),
+            neo4j_password: Some(wrldbldr_domain::REDACTED_SECRET.to_string()),
+            ..Default::default()
+        };
+
+        let response = router
+            .into_service()
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/api/setup")
+                    .method(axum::http::Method::PUT)
+                    .header(axum::http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(serde_json::to_vec(&candidate).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let outcome: serde_json::Value = read_body_json(response).await;
+        assert_eq!(outcome["saved"], false);
+        assert_eq!(outcome["checks"]["neo4j"]["ok"], true);
+        assert_eq!(outcome["checks"]["ollama"]["ok"], false);
+        assert_eq!(
+            outcome["connections"]["neo4j_password"],
+            wrldbldr_domain::REDACTED_SECRET
+        );
+    }
+
+    #[tokio::test]
+    async fn health_reports_each_dependency_and_503_when_one_is_down() {
+        use crate::infrastructure::ports::ProbeError;
+
+        let mut repos = TestAppRepos::new(MockWorldRepo::new());
+        repos.service_probe.expect_check_neo4j().returning(|_, _, _| Ok(()));
+        repos.service_probe.expect_check_ollama().returning(|_, _| Ok(()));
+        repos
+            .service_probe
+            .expect_check_comfyui()
+            .returning(|_| Err(ProbeError::Unreachable("connection refused".to_string())));
+        repos
+            .service_probe
+            .expect_free_disk_space()
+            .returning(|_| Ok(u64::MAX));
+
+        let router = build_router_with_repos(repos);
+
+        let response = router
+            .into_service()
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/api/health")
+                    .method(axum::http::Method::GET)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::SERVICE_UNAVAILABLE);
+        let report: serde_json::Value = read_body_json(response).await;
+        assert_eq!(report["healthy"], false);
+        let deps = report["dependencies"].as_array().unwrap();
+        assert_eq!(deps.len(), 4);
+        let comfyui = deps.iter().find(|d| d["dependency"] == "comfyui").unwrap();
+        assert_eq!(comfyui["healthy"], false);
+        assert_eq!(comfyui["message"], "Could not connect: connection refused");
+        assert!(deps
+            .iter()
+            .filter(|d| d["dependency"] != "comfyui")
+            .all(|d| d["healthy"] == true));
+    }
+
+    #[tokio::test]
+    async fn metrics_reports_circuit_breaker_state_per_service() {
+        let repos = TestAppRepos::new(MockWorldRepo::new());
+        let router = build_router_with_repos(repos);
+
+        let response = router
+            .into_service()
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/api/metrics")
+                    .method(axum::http::Method::GET)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let metrics: serde_json::Value = read_body_json(response).await;
+        let breakers = metrics["circuit_breakers"].as_array().unwrap();
+        let services: Vec<_> = breakers.iter().map(|b| b["service"].clone()).collect();
+        assert_eq!(services, vec!["llm", "neo4j"]);
+        assert!(breakers.iter().all(|b| b["state"] == "closed"));
+    }
+
     #[tokio::test]
     async fn get_rule_system_preset_returns_config() {
         let repos = TestAppRepos::new(MockWorldRepo::new());
@@ -469,4 +970,128 @@ mod tests {
         let payload: serde_json::Value = read_body_json(response).await;
         assert_eq!(payload["id"], world.id.to_string());
     }
+
+    #[tokio::test]
+    async fn get_blob_rejects_a_bad_signature() {
+        let mut repos = TestAppRepos::new(MockWorldRepo::new());
+        repos
+            .blob_store
+            .expect_verify_signature()
+            .returning(|_, _, _| false);
+        repos.blob_store.expect_get().times(0);
+        let router = build_router_with_repos(repos);
+
+        let key = crate::infrastructure::blob_store::content_key(b"image", "image/png");
+        let response = router
+            .into_service()
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/api/blobs/{}?expires=0&signature=00", key))
+                    .method(axum::http::Method::GET)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn upload_asset_image_stores_it_and_suggests_matches() {
+        let mut repos = TestAppRepos::new(MockWorldRepo::new());
+        let world_id = wrldbldr_domain::WorldId::new();
+        let mira = wrldbldr_domain::Character::new(
+            world_id,
+            "Mira Thorne",
+            wrldbldr_domain::CampbellArchetype::Ally,
+        );
+        let mira_id = mira.id;
+        repos.character_repo = crate::infrastructure::ports::MockCharacterRepo::new();
+        repos
+            .character_repo
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![mira.clone()]));
+        repos
+            .blob_store
+            .expect_put()
+            .withf(|_, content_type| content_type == "image/png")
+            .returning(|data, content_type| {
+                Ok(crate::infrastructure::blob_store::content_key(
+                    &data,
+                    content_type,
+                ))
+            });
+        repos.usage_repo.expect_record().returning(|_, _, _| Ok(()));
+        let router = build_router_with_repos(repos);
+
+        let body = serde_json::json!({
+            "assetType": "portrait",
+            "data": "iVBORw0KGgo=",
+            "fileName": "mira_thorne.png",
+        });
+        let response = router
+            .into_service()
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/api/worlds/{}/assets/upload", world_id))
+                    .method(axum::http::Method::POST)
+                    .header(axum::http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let payload: wrldbldr_protocol::UploadAssetResponseDto = read_body_json(response).await;
+        assert!(payload.file_path.ends_with(".png"));
+        assert_eq!(payload.suggestions.len(), 1);
+        assert_eq!(payload.suggestions[0].entity_id, mira_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn generate_assets_rejects_unknown_presets() {
+        let mut repos = TestAppRepos::new(MockWorldRepo::new());
+        let world_id = wrldbldr_domain::WorldId::new();
+        repos.settings_repo.expect_get_for_world().returning(|_| {
+            Ok(Some(wrldbldr_domain::AppSettings {
+                generation_presets: vec![wrldbldr_domain::GenerationPreset {
+                    name: "Watercolor".to_string(),
+                    asset_type: wrldbldr_domain::AssetType::Portrait,
+                    style_prompt: "watercolor".to_string(),
+                    negative_prompt: None,
+                    width: None,
+                    height: None,
+                    workflow: None,
+                    is_default: true,
+                }],
+                ..Default::default()
+            }))
+        });
+        let router = build_router_with_repos(repos);
+
+        let body = serde_json::json!({
+            "worldId": world_id.to_string(),
+            "entityType": "character",
+            "entityId": uuid::Uuid::new_v4().to_string(),
+            "assetType": "portrait",
+            "prompt": "an old knight",
+            "preset": "Noir",
+        });
+        let response = router
+            .into_service()
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/api/assets/generate")
+                    .method(axum::http::Method::POST)
+                    .header(axum::http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+    }
 }
@@ -0,0 +1,308 @@
+//! Localization of server-generated text.
+//!
+//! Engine code keeps producing English strings; they act as message IDs
+//! (gettext style) into per-locale string catalogs. Each outgoing
+//! `ServerMessage` is localized for the receiving connection just before it is
+//! serialized, so broadcasts reach every client in its own language.
+//!
+//! Catalog entries are either exact strings or templates where `{}` matches
+//! a formatted argument. Translations refer to captured arguments as `{0}`,
+//! `{1}`, ... so languages can reorder them. Captured arguments that are
+//! themselves catalog entries (e.g. time period names) are translated too.
+//! Text without a catalog entry passes through in English.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use wrldbldr_protocol::{ResponseResult, ServerMessage};
+
+/// Locale used by the engine's source strings.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Built-in catalogs: (locale, JSON object of source -> translation)
+const BUILTIN_CATALOGS: &[(&str, &str)] = &[
+    ("de", include_str!("catalogs/de.json")),
+    ("es", include_str!("catalogs/es.json")),
+];
+
+static LOCALIZER: LazyLock<Localizer> = LazyLock::new(Localizer::builtin);
+
+/// The engine-wide localizer with the built-in catalogs.
+pub fn localizer() -> &'static Localizer {
+    &LOCALIZER
+}
+
+/// A template entry split on its `{}` placeholders.
+struct Template {
+    segments: Vec<String>,
+    translation: String,
+}
+
+/// Translations for a single locale.
+#[derive(Default)]
+pub struct Catalog {
+    exact: HashMap<String, String>,
+    templates: Vec<Template>,
+}
+
+impl Catalog {
+    /// Parse a catalog from a flat JSON object.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let entries: HashMap<String, String> = serde_json::from_str(json)?;
+        let mut catalog = Self::default();
+        for (source, translation) in entries {
+            catalog.insert(source, translation);
+        }
+        Ok(catalog)
+    }
+
+    pub fn insert(&mut self, source: impl Into<String>, translation: impl Into<String>) {
+        let source = source.into();
+        let translation = translation.into();
+        if source.contains("{}") {
+            self.templates.push(Template {
+                segments: source.split("{}").map(str::to_string).collect(),
+                translation,
+            });
+            // Prefer the most specific template when several match
+            self.templates.sort_by_key(|t| {
+                std::cmp::Reverse(t.segments.iter().map(String::len).sum::<usize>())
+            });
+        } else {
+            self.exact.insert(source, translation);
+        }
+    }
+
+    /// Translate `text`, or `None` if the catalog has no matching entry.
+    pub fn translate(&self, text: &str) -> Option<String> {
+        if let Some(exact) = self.exact.get(text) {
+            return Some(exact.clone());
+        }
+        self.templates.iter().find_map(|template| {
+            let args = match_template(&template.segments, text)?;
+            let mut out = template.translation.clone();
+            for (i, arg) in args.iter().enumerate() {
+                let arg = self.exact.get(*arg).map(String::as_str).unwrap_or(arg);
+                out = out.replace(&format!("{{{}}}", i), arg);
+            }
+            Some(out)
+        })
+    }
+}
+
+/// Match `text` against template segments, returning the captured arguments.
+fn match_template<'a>(segments: &[String], text: &'a str) -> Option<Vec<&'a str>> {
+    let (first, rest) = segments.split_first()?;
+    let mut remaining = text.strip_prefix(first.as_str())?;
+    let mut args = Vec::with_capacity(rest.len());
+
+    for (i, segment) in rest.iter().enumerate() {
+        let is_last = i == rest.len() - 1;
+        let end = if is_last {
+            if !remaining.ends_with(segment.as_str()) {
+                return None;
+            }
+            remaining.len() - segment.len()
+        } else if segment.is_empty() {
+            // Adjacent placeholders are ambiguous
+            return None;
+        } else {
+            remaining.find(segment.as_str())?
+        };
+        if end == 0 {
+            return None;
+        }
+        args.push(&remaining[..end]);
+        remaining = &remaining[end + segment.len()..];
+    }
+
+    Some(args)
+}
+
+/// Catalogs for all supported locales.
+pub struct Localizer {
+    catalogs: HashMap<String, Catalog>,
+}
+
+impl Localizer {
+    /// Localizer with the catalogs shipped in the engine binary.
+    pub fn builtin() -> Self {
+        let mut catalogs = HashMap::new();
+        for (locale, json) in BUILTIN_CATALOGS {
+            match Catalog::from_json(json) {
+                Ok(catalog) => {
+                    catalogs.insert(locale.to_string(), catalog);
+                }
+                Err(e) => tracing::error!(locale = %locale, error = %e, "Invalid string catalog"),
+            }
+        }
+        Self { catalogs }
+    }
+
+    /// Resolve a requested locale (e.g. "es-MX", "de_DE.UTF-8") to a supported one.
+    ///
+    /// Tries the full tag, then the language alone, then falls back to English.
+    pub fn resolve_locale(&self, requested: &str) -> String {
+        let tag = requested
+            .split('.')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .replace('_', "-")
+            .to_lowercase();
+        if self.catalogs.contains_key(&tag) {
+            return tag;
+        }
+        let language = tag.split('-').next().unwrap_or_default();
+        if self.catalogs.contains_key(language) {
+            return language.to_string();
+        }
+        DEFAULT_LOCALE.to_string()
+    }
+
+    /// Supported locales, English first.
+    pub fn supported_locales(&self) -> Vec<String> {
+        let mut locales: Vec<String> = self.catalogs.keys().cloned().collect();
+        locales.sort();
+        locales.insert(0, DEFAULT_LOCALE.to_string());
+        locales
+    }
+
+    /// Translate `text` into `locale`, falling back to the source text.
+    pub fn translate(&self, locale: &str, text: &str) -> String {
+        self.catalogs
+            .get(locale)
+            .and_then(|catalog| catalog.translate(text))
+            .unwrap_or_else(|| text.to_string())
+    }
+
+    /// Localize the human-readable text in a message for one recipient.
+    pub fn localize_message(&self, locale: &str, message: ServerMessage) -> ServerMessage {
+        if !self.catalogs.contains_key(locale) {
+            return message;
+        }
+        let t = |text: String| self.translate(locale, &text);
+
+        match message {
+            ServerMessage::Error { code, message } => ServerMessage::Error {
+                code,
+                message: t(message),
+            },
+            ServerMessage::Response {
+                request_id,
+                result:
+                    ResponseResult::Error {
+                        code,
+                        message,
+                        details,
+                    },
+            } => ServerMessage::Response {
+                request_id,
+                result: ResponseResult::Error {
+                    code,
+                    message: t(message),
+                    details,
+                },
+            },
+            ServerMessage::GameTimeAdvanced { mut data } => {
+                data.reason = t(data.reason);
+                data.new_period = data.new_period.map(t);
+                ServerMessage::GameTimeAdvanced { data }
+            }
+            ServerMessage::TimeSuggestion { mut data } => {
+                data.period_change = data.period_change.map(|(from, to)| (t(from), t(to)));
+                ServerMessage::TimeSuggestion { data }
+            }
+            ServerMessage::MovementBlocked { pc_id, reason } => ServerMessage::MovementBlocked {
+                pc_id,
+                reason: t(reason),
+            },
+            other => other,
+        }
+    }
+}
+
+/// Whether a message carries text that [`Localizer::localize_message`] translates.
+pub fn has_localizable_text(message: &ServerMessage) -> bool {
+    matches!(
+        message,
+        ServerMessage::Error { .. }
+            | ServerMessage::Response {
+                result: ResponseResult::Error { .. },
+                ..
+            }
+            | ServerMessage::GameTimeAdvanced { .. }
+            | ServerMessage::TimeSuggestion { .. }
+            | ServerMessage::MovementBlocked { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spanish() -> Localizer {
+        let mut catalog = Catalog::default();
+        catalog.insert("World not found", "Mundo no encontrado");
+        catalog.insert("Evening", "Tarde");
+        catalog.insert("Skipped to {}", "Saltó a {0}");
+        catalog.insert("Traveled from {} to {}", "Viajó de {0} a {1}");
+        Localizer {
+            catalogs: HashMap::from([("es".to_string(), catalog)]),
+        }
+    }
+
+    #[test]
+    fn test_translate_exact_and_fallback() {
+        let l = spanish();
+        assert_eq!(l.translate("es", "World not found"), "Mundo no encontrado");
+        assert_eq!(l.translate("es", "Something else"), "Something else");
+        assert_eq!(l.translate("en", "World not found"), "World not found");
+    }
+
+    #[test]
+    fn test_translate_templates_with_translated_args() {
+        let l = spanish();
+        assert_eq!(l.translate("es", "Skipped to Evening"), "Saltó a Tarde");
+        assert_eq!(
+            l.translate("es", "Traveled from Docks to Old Town"),
+            "Viajó de Docks a Old Town"
+        );
+    }
+
+    #[test]
+    fn test_resolve_locale() {
+        let l = spanish();
+        assert_eq!(l.resolve_locale("es-MX"), "es");
+        assert_eq!(l.resolve_locale("es_ES.UTF-8"), "es");
+        assert_eq!(l.resolve_locale("ES"), "es");
+        assert_eq!(l.resolve_locale("ja-JP"), "en");
+        assert_eq!(l.resolve_locale(""), "en");
+    }
+
+    #[test]
+    fn test_localize_error_message() {
+        let l = spanish();
+        let msg = l.localize_message(
+            "es",
+            ServerMessage::Error {
+                code: "NOT_FOUND".to_string(),
+                message: "World not found".to_string(),
+            },
+        );
+        match msg {
+            ServerMessage::Error { code, message } => {
+                assert_eq!(code, "NOT_FOUND");
+                assert_eq!(message, "Mundo no encontrado");
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_builtin_catalogs_parse() {
+        let l = Localizer::builtin();
+        assert_eq!(l.supported_locales(), vec!["en", "de", "es"]);
+        assert_eq!(l.translate("de", "Morning"), "Morgen");
+    }
+}
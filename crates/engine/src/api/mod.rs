@@ -2,6 +2,7 @@
 
 pub mod connections;
 pub mod http;
+pub mod i18n;
 pub mod websocket;
 
 pub use connections::ConnectionManager;
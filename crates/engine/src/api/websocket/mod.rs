@@ -13,32 +13,69 @@ use axum::{
 };
 use futures_util::{SinkExt, StreamExt};
 use tokio::sync::mpsc;
+use tracing::Instrument;
 use uuid::Uuid;
 
+mod ws_broadsheet;
 mod ws_challenge;
 mod ws_character_sheet;
+mod ws_chronology;
+mod ws_companion;
+mod ws_crowds;
+mod ws_clock;
 mod ws_core;
 mod ws_creator;
+mod ws_custom_fields;
+mod ws_dice;
 mod ws_conversation;
 mod ws_dm;
+mod ws_drafts;
+mod ws_economy;
+mod ws_factions;
+mod ws_dialogue_tree;
+mod ws_flashback;
 mod ws_event_chain;
+mod ws_gallery;
+mod ws_health;
 mod ws_actantial;
+mod ws_injury;
 mod ws_inventory;
+mod ws_knowledge;
+mod ws_library;
 mod ws_location;
 mod ws_lore;
+mod ws_mail;
+mod ws_mentions;
+mod ws_mortality;
+mod ws_names;
 mod ws_movement;
 mod ws_narrative_event;
+mod ws_npc_drafts;
+mod ws_pacing;
+mod ws_parallel_scenes;
 mod ws_player_action;
 mod ws_player;
+mod ws_property;
+mod ws_safety;
+mod ws_scripts;
+mod ws_secret;
 mod ws_session;
+mod ws_simulation;
 mod ws_scene;
 mod ws_skill;
+mod ws_spotlight;
 mod ws_stat;
 mod ws_story_events;
 mod ws_staging;
+mod ws_tags;
+mod ws_templates;
 mod ws_time;
+mod ws_transcription;
+mod ws_tutorial;
 mod ws_approval;
 
+pub use ws_approval::apply_approval_decision;
+
 use wrldbldr_domain::{
     ActId, ChallengeId, CharacterId, EventChainId, GoalId, InteractionId, ItemId, LocationId,
     MoodState, NarrativeEventId, PlayerCharacterId, RegionId, SceneId, SkillId, StagingSource,
@@ -50,6 +87,7 @@ use wrldbldr_protocol::{
 };
 
 use super::connections::ConnectionManager;
+use super::i18n;
 use crate::app::App;
 use crate::use_cases::staging::PendingStagingRequest;
 
@@ -91,10 +129,34 @@ async fn handle_socket(socket: WebSocket, state: Arc<WsState>) {
 
     tracing::info!(connection_id = %connection_id, "WebSocket connection established");
 
-    // Spawn a task to forward messages from the channel to the WebSocket
+    // Logs while handling this connection's messages carry its connection
+    // and, once joined, its world
+    let span = tracing::info_span!(
+        "ws_connection",
+        connection_id = %connection_id,
+        world_id = tracing::field::Empty,
+    );
+    let mut span_world: Option<WorldId> = None;
+
+    // Spawn a task to forward messages from the channel to the WebSocket,
+    // localizing server-generated text for this connection on the way out
+    let send_state = state.clone();
     let send_task = tokio::spawn(async move {
         while let Some(msg) = rx.recv().await {
+            let msg = if i18n::has_localizable_text(&msg) {
+                let locale = send_state.connections.locale(connection_id).await;
+                i18n::localizer().localize_message(&locale, msg)
+            } else {
+                msg
+            };
             if let Ok(json) = serde_json::to_string(&msg) {
+                if let Some(world_id) = send_state.connections.world_of(connection_id).await {
+                    send_state
+                        .app
+                        .diagnostics
+                        .messages
+                        .record_outbound(world_id, &json);
+                }
                 if ws_sender.send(Message::Text(json.into())).await.is_err() {
                     break;
                 }
@@ -105,28 +167,50 @@ async fn handle_socket(socket: WebSocket, state: Arc<WsState>) {
     // Handle incoming messages
     while let Some(result) = ws_receiver.next().await {
         match result {
-            Ok(Message::Text(text)) => match serde_json::from_str::<ClientMessage>(text.as_str()) {
-                Ok(msg) => {
-                    if let Some(response) =
-                        handle_message(msg, &state, connection_id, tx.clone()).await
-                    {
-                        if tx.try_send(response).is_err() {
-                            tracing::warn!(
-                                connection_id = %connection_id,
-                                "Failed to send response, channel full or closed"
-                            );
-                        }
+            Ok(Message::Text(text)) => {
+                let world_id = state.connections.world_of(connection_id).await;
+                if world_id != span_world {
+                    if let Some(world_id) = world_id {
+                        span.record("world_id", tracing::field::display(world_id));
                     }
+                    span_world = world_id;
                 }
-                Err(e) => {
-                    tracing::warn!(connection_id = %connection_id, error = %e, "Failed to parse message");
-                    let error = ServerMessage::Error {
-                        code: "PARSE_ERROR".to_string(),
-                        message: format!("Invalid message format: {}", e),
-                    };
-                    let _ = tx.try_send(error);
+
+                match serde_json::from_str::<ClientMessage>(text.as_str()) {
+                    Ok(msg) => {
+                        if let Some(world_id) = world_id {
+                            state
+                                .app
+                                .diagnostics
+                                .messages
+                                .record_inbound(world_id, text.as_str());
+                        }
+                        if let Some(response) =
+                            handle_message(msg, &state, connection_id, tx.clone())
+                                .instrument(span.clone())
+                                .await
+                        {
+                            if tx.try_send(response).is_err() {
+                                tracing::warn!(
+                                    connection_id = %connection_id,
+                                    "Failed to send response, channel full or closed"
+                                );
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!(connection_id = %connection_id, error = %e, "Failed to parse message");
+                        if let Some(world_id) = world_id {
+                            state.app.diagnostics.messages.record_parse_error(world_id);
+                        }
+                        let error = ServerMessage::Error {
+                            code: "PARSE_ERROR".to_string(),
+                            message: format!("Invalid message format: {}", e),
+                        };
+                        let _ = tx.try_send(error);
+                    }
                 }
-            },
+            }
             Ok(Message::Ping(_)) => {
                 let _ = tx.try_send(ServerMessage::Pong);
             }
@@ -182,6 +266,18 @@ async fn handle_message(
             ws_session::handle_leave_world(state, connection_id).await
         }
 
+        ClientMessage::ControlPc { pc_id } => {
+            ws_session::handle_control_pc(state, connection_id, pc_id).await
+        }
+
+        ClientMessage::SwitchPc { pc_id } => {
+            ws_session::handle_switch_pc(state, connection_id, pc_id).await
+        }
+
+        ClientMessage::SetLocale { locale } => {
+            ws_session::handle_set_locale(state, connection_id, locale).await
+        }
+
         // Movement
         ClientMessage::MoveToRegion { pc_id, region_id } => {
             ws_movement::handle_move_to_region(state, connection_id, pc_id, region_id).await
@@ -202,6 +298,21 @@ async fn handle_message(
             .await
         }
 
+        ClientMessage::FastTravel { pc_id, location_id } => {
+            ws_movement::handle_fast_travel(state, connection_id, pc_id, location_id).await
+        }
+
+        ClientMessage::ResolveJourney {
+            journey_id,
+            decision,
+        } => {
+            ws_movement::handle_resolve_journey(state, connection_id, journey_id, decision).await
+        }
+
+        ClientMessage::UnlockWithKey { pc_id, item_id, to } => {
+            ws_movement::handle_unlock_with_key(state, connection_id, pc_id, item_id, to).await
+        }
+
         // Inventory
         ClientMessage::EquipItem { pc_id, item_id } => {
             ws_inventory::handle_inventory_action(
@@ -252,6 +363,48 @@ async fn handle_message(
             .await
         }
 
+        ClientMessage::OfferTrade {
+            from_pc_id,
+            to_pc_id,
+            offered_item_ids,
+            offered_currency,
+            requested_item_ids,
+            requested_currency,
+        } => {
+            ws_inventory::handle_offer_trade(
+                state,
+                connection_id,
+                &from_pc_id,
+                &to_pc_id,
+                &offered_item_ids,
+                offered_currency,
+                &requested_item_ids,
+                requested_currency,
+            )
+            .await
+        }
+        ClientMessage::RespondToTrade { trade_id, accept } => {
+            ws_inventory::handle_respond_to_trade(state, connection_id, &trade_id, accept).await
+        }
+        ClientMessage::CancelTrade { trade_id } => {
+            ws_inventory::handle_cancel_trade(state, connection_id, &trade_id).await
+        }
+
+        ClientMessage::ShareKnowledge {
+            from_pc_id,
+            target_pc_ids,
+            knowledge,
+        } => {
+            ws_knowledge::handle_share_knowledge(
+                state,
+                connection_id,
+                &from_pc_id,
+                &target_pc_ids,
+                knowledge,
+            )
+            .await
+        }
+
         // Request/Response pattern (CRUD operations)
         ClientMessage::Request {
             request_id,
@@ -271,6 +424,10 @@ async fn handle_message(
                 .await
         }
 
+        ClientMessage::RollDice { formula } => {
+            ws_dice::handle_roll_dice(state, connection_id, formula).await
+        }
+
         ClientMessage::TriggerChallenge {
             challenge_id,
             target_character_id,
@@ -338,6 +495,14 @@ async fn handle_message(
             decision,
         } => ws_approval::handle_approval_decision(state, connection_id, request_id, decision).await,
 
+        ClientMessage::BulkApprovalDecision {
+            selection,
+            decision,
+        } => {
+            ws_approval::handle_bulk_approval_decision(state, connection_id, selection, decision)
+                .await
+        }
+
         ClientMessage::ChallengeSuggestionDecision {
             request_id,
             approved,
@@ -410,6 +575,22 @@ async fn handle_message(
             description,
         } => ws_dm::handle_trigger_location_event(state, connection_id, region_id, description).await,
 
+        ClientMessage::MakeNoise {
+            region_id,
+            description,
+            loudness,
+        } => ws_dm::handle_make_noise(state, connection_id, region_id, description, loudness).await,
+
+        ClientMessage::ShowOverlay {
+            asset,
+            caption,
+            duration_secs,
+        } => ws_dm::handle_show_overlay(state, connection_id, asset, caption, duration_secs).await,
+
+        ClientMessage::DismissOverlay { overlay_id } => {
+            ws_dm::handle_dismiss_overlay(state, connection_id, overlay_id).await
+        }
+
         ClientMessage::ShareNpcLocation {
             pc_id,
             npc_id,
@@ -476,11 +657,21 @@ async fn handle_message(
             .await
         }
 
+        // Safety tools
+        ClientMessage::SafetySignal { level } => {
+            ws_safety::handle_safety_signal(state, connection_id, level).await
+        }
+
+        ClientMessage::SetActionQueuePaused { world_id, paused } => {
+            ws_safety::handle_set_action_queue_paused(state, connection_id, world_id, paused).await
+        }
+
         // Player action handler
         ClientMessage::PlayerAction {
             action_type,
             target,
             dialogue,
+            choice_id,
         } => {
             ws_player_action::handle_player_action(
                 state,
@@ -488,10 +679,15 @@ async fn handle_message(
                 action_type,
                 target,
                 dialogue,
+                choice_id,
             )
             .await
         }
 
+        ClientMessage::RetractAction { action_id } => {
+            ws_player_action::handle_retract_action(state, connection_id, action_id).await
+        }
+
         ClientMessage::StartConversation { npc_id, message } => {
             ws_conversation::handle_start_conversation(state, connection_id, npc_id, message).await
         }
@@ -511,6 +707,10 @@ async fn handle_message(
             .await
         }
 
+        ClientMessage::CheckComfyUIHealth => {
+            ws_health::handle_check_health(state, connection_id).await
+        }
+
         ClientMessage::PerformInteraction { interaction_id } => {
             ws_conversation::handle_perform_interaction(state, connection_id, interaction_id).await
         }
@@ -555,6 +755,96 @@ async fn handle_request(
         RequestPayload::Lore(req) => {
             ws_lore::handle_lore_request(state, &request_id, &conn_info, req).await
         }
+        RequestPayload::Clock(req) => {
+            ws_clock::handle_clock_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Dice(req) => {
+            ws_dice::handle_dice_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Tutorial(req) => {
+            ws_tutorial::handle_tutorial_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Tag(req) => {
+            ws_tags::handle_tag_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Template(req) => {
+            ws_templates::handle_template_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Library(req) => {
+            ws_library::handle_library_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Draft(req) => {
+            ws_drafts::handle_draft_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Gallery(req) => {
+            ws_gallery::handle_gallery_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Chronology(req) => {
+            ws_chronology::handle_chronology_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Crowd(req) => {
+            ws_crowds::handle_crowd_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::NpcDraft(req) => {
+            ws_npc_drafts::handle_npc_draft_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Mention(req) => {
+            ws_mentions::handle_mention_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::NameGenerator(req) => {
+            ws_names::handle_name_generator_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Economy(req) => {
+            ws_economy::handle_economy_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Property(req) => {
+            ws_property::handle_property_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Companion(req) => {
+            ws_companion::handle_companion_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Injury(req) => {
+            ws_injury::handle_injury_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Mortality(req) => {
+            ws_mortality::handle_mortality_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Secret(req) => {
+            ws_secret::handle_secret_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Mail(req) => {
+            ws_mail::handle_mail_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Broadsheet(req) => {
+            ws_broadsheet::handle_broadsheet_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Faction(req) => {
+            ws_factions::handle_faction_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::DialogueTree(req) => {
+            ws_dialogue_tree::handle_dialogue_tree_request(state, &request_id, &conn_info, req)
+                .await
+        }
+        RequestPayload::Flashback(req) => {
+            ws_flashback::handle_flashback_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::ParallelScene(req) => {
+            ws_parallel_scenes::handle_parallel_scene_request(state, &request_id, &conn_info, req)
+                .await
+        }
+        RequestPayload::Spotlight(req) => {
+            ws_spotlight::handle_spotlight_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Pacing(req) => {
+            ws_pacing::handle_pacing_request(state, &request_id, &conn_info, req).await
+        }
+        RequestPayload::Transcription(req) => {
+            ws_transcription::handle_transcription_request(state, &request_id, &conn_info, req)
+                .await
+        }
+        RequestPayload::Simulation(req) => {
+            ws_simulation::handle_simulation_request(state, &request_id, &conn_info, req).await
+        }
         RequestPayload::StoryEvent(req) => {
             ws_story_events::handle_story_event_request(state, &request_id, &conn_info, req).await
         }
@@ -707,10 +997,10 @@ mod ws_integration_tests_inline {
         QueuePort, RandomPort,
     };
     use crate::infrastructure::ports::{
-        MockActRepo, MockAssetRepo, MockChallengeRepo, MockCharacterRepo, MockFlagRepo,
+        MockActRepo, MockAssetRepo, MockChallengeRepo, MockCharacterRepo, MockCustomFieldRepo, MockFlagRepo,
         MockGoalRepo, MockInteractionRepo, MockItemRepo, MockLocationRepo, MockLocationStateRepo,
         MockLoreRepo, MockNarrativeRepo, MockObservationRepo, MockPlayerCharacterRepo,
-        MockRegionStateRepo, MockSceneRepo, MockSettingsRepo, MockSkillRepo, MockStagingRepo,
+        MockProgressClockRepo, MockRegionStateRepo, MockSceneRepo, MockSettingsRepo, MockSkillRepo, MockStagingRepo, MockTagRepo, MockTemplateRepo, MockLibraryRepo, MockContentDraftRepo, MockCrowdRepo, MockNpcDraftRepo, MockMentionRepo, MockNameGeneratorRepo, MockEconomyRepo, MockPropertyRepo, MockCompanionRepo, MockInjuryRepo, MockPcSecretRepo, MockLetterRepo, MockBroadsheetRepo, MockFactionRepo, MockDialogueTreeRepo, MockUsageRepo, MockBlobStorePort,
         MockWorldRepo,
     };
 
@@ -724,6 +1014,8 @@ mod ws_integration_tests_inline {
         skill_repo: MockSkillRepo,
         interaction_repo: MockInteractionRepo,
         settings_repo: MockSettingsRepo,
+        usage_repo: MockUsageRepo,
+        blob_store: MockBlobStorePort,
         challenge_repo: MockChallengeRepo,
         narrative_repo: MockNarrativeRepo,
         staging_repo: MockStagingRepo,
@@ -733,6 +1025,25 @@ mod ws_integration_tests_inline {
         flag_repo: MockFlagRepo,
         goal_repo: MockGoalRepo,
         lore_repo: MockLoreRepo,
+        progress_clock_repo: MockProgressClockRepo,
+        tag_repo: MockTagRepo,
+        custom_field_repo: MockCustomFieldRepo,
+        template_repo: MockTemplateRepo,
+        library_repo: MockLibraryRepo,
+        content_draft_repo: MockContentDraftRepo,
+        crowd_repo: MockCrowdRepo,
+        npc_draft_repo: MockNpcDraftRepo,
+        mention_repo: MockMentionRepo,
+        name_generator_repo: MockNameGeneratorRepo,
+        economy_repo: MockEconomyRepo,
+        property_repo: MockPropertyRepo,
+        companion_repo: MockCompanionRepo,
+        injury_repo: MockInjuryRepo,
+        pc_secret_repo: MockPcSecretRepo,
+        letter_repo: MockLetterRepo,
+        broadsheet_repo: MockBroadsheetRepo,
+        faction_repo: MockFactionRepo,
+        dialogue_tree_repo: MockDialogueTreeRepo,
         location_state_repo: MockLocationStateRepo,
         region_state_repo: MockRegionStateRepo,
     }
@@ -749,6 +1060,8 @@ mod ws_integration_tests_inline {
                 skill_repo: MockSkillRepo::new(),
                 interaction_repo: MockInteractionRepo::new(),
                 settings_repo: MockSettingsRepo::new(),
+                usage_repo: MockUsageRepo::new(),
+                blob_store: MockBlobStorePort::new(),
                 challenge_repo: MockChallengeRepo::new(),
                 narrative_repo: MockNarrativeRepo::new(),
                 staging_repo: MockStagingRepo::new(),
@@ -758,6 +1071,31 @@ mod ws_integration_tests_inline {
                 flag_repo: MockFlagRepo::new(),
                 goal_repo: MockGoalRepo::new(),
                 lore_repo: MockLoreRepo::new(),
+                progress_clock_repo: MockProgressClockRepo::new(),
+                tag_repo: MockTagRepo::new(),
+                custom_field_repo: MockCustomFieldRepo::new(),
+                template_repo: MockTemplateRepo::new(),
+                library_repo: MockLibraryRepo::new(),
+                content_draft_repo: MockContentDraftRepo::new(),
+                crowd_repo: MockCrowdRepo::new(),
+                npc_draft_repo: MockNpcDraftRepo::new(),
+                mention_repo: MockMentionRepo::new(),
+                name_generator_repo: MockNameGeneratorRepo::new(),
+                economy_repo: MockEconomyRepo::new(),
+                property_repo: MockPropertyRepo::new(),
+                companion_repo: MockCompanionRepo::new(),
+                injury_repo: MockInjuryRepo::new(),
+                pc_secret_repo: MockPcSecretRepo::new(),
+                letter_repo: MockLetterRepo::new(),
+                broadsheet_repo: MockBroadsheetRepo::new(),
+                faction_repo: MockFactionRepo::new(),
+                dialogue_tree_repo: {
+                    // Conversations look for a scripted beat first
+                    let mut repo = MockDialogueTreeRepo::new();
+                    repo.expect_list_in_world()
+                        .returning(|_world_id| Ok(Vec::new()));
+                    repo
+                },
                 location_state_repo: MockLocationStateRepo::new(),
                 region_state_repo: MockRegionStateRepo::new(),
             }
@@ -820,6 +1158,19 @@ mod ws_integration_tests_inline {
             Ok(())
         }
 
+        async fn mark_failed_if_status(
+            &self,
+            _id: Uuid,
+            _expected: QueueItemStatus,
+            _error: &str,
+        ) -> Result<bool, QueueError> {
+            Ok(false)
+        }
+
+        async fn get_item(&self, _id: Uuid) -> Result<Option<QueueItem>, QueueError> {
+            Ok(None)
+        }
+
         async fn get_pending_count(&self, _queue_type: &str) -> Result<usize, QueueError> {
             Ok(0)
         }
@@ -1014,6 +1365,19 @@ mod ws_integration_tests_inline {
             Ok(())
         }
 
+        async fn mark_failed_if_status(
+            &self,
+            _id: Uuid,
+            _expected: QueueItemStatus,
+            _error: &str,
+        ) -> Result<bool, QueueError> {
+            Ok(false)
+        }
+
+        async fn get_item(&self, _id: Uuid) -> Result<Option<QueueItem>, QueueError> {
+            Ok(None)
+        }
+
         async fn get_pending_count(&self, _queue_type: &str) -> Result<usize, QueueError> {
             Ok(0)
         }
@@ -1100,6 +1464,9 @@ mod ws_integration_tests_inline {
         let clock: Arc<dyn ClockPort> = Arc::new(FixedClock { now });
         let random: Arc<dyn RandomPort> = Arc::new(FixedRandom);
         let image_gen: Arc<dyn ImageGenPort> = Arc::new(NoopImageGen);
+        let plugins: Arc<dyn crate::infrastructure::ports::PluginPort> = Arc::new(
+            crate::infrastructure::wasm_plugins::WasmPluginHost::new(Default::default()),
+        );
 
         // Repo mocks.
         let world_repo = Arc::new(repos.world_repo);
@@ -1111,6 +1478,8 @@ mod ws_integration_tests_inline {
         let skill_repo = Arc::new(repos.skill_repo);
         let interaction_repo = Arc::new(repos.interaction_repo);
         let settings_repo = Arc::new(repos.settings_repo);
+        let usage_repo = Arc::new(repos.usage_repo);
+        let blob_store = Arc::new(repos.blob_store);
         let challenge_repo = Arc::new(repos.challenge_repo);
         let narrative_repo = Arc::new(repos.narrative_repo);
         let staging_repo = Arc::new(repos.staging_repo);
@@ -1120,6 +1489,25 @@ mod ws_integration_tests_inline {
         let flag_repo = Arc::new(repos.flag_repo);
         let goal_repo = Arc::new(repos.goal_repo);
         let lore_repo = Arc::new(repos.lore_repo);
+        let progress_clock_repo = Arc::new(repos.progress_clock_repo);
+        let tag_repo = Arc::new(repos.tag_repo);
+        let custom_field_repo = Arc::new(repos.custom_field_repo);
+        let template_repo = Arc::new(repos.template_repo);
+        let library_repo = Arc::new(repos.library_repo);
+        let content_draft_repo = Arc::new(repos.content_draft_repo);
+        let crowd_repo = Arc::new(repos.crowd_repo);
+        let npc_draft_repo = Arc::new(repos.npc_draft_repo);
+        let mention_repo = Arc::new(repos.mention_repo);
+        let name_generator_repo = Arc::new(repos.name_generator_repo);
+        let economy_repo = Arc::new(repos.economy_repo);
+        let property_repo = Arc::new(repos.property_repo);
+        let companion_repo = Arc::new(repos.companion_repo);
+        let injury_repo = Arc::new(repos.injury_repo);
+        let pc_secret_repo = Arc::new(repos.pc_secret_repo);
+        let letter_repo = Arc::new(repos.letter_repo);
+        let broadsheet_repo = Arc::new(repos.broadsheet_repo);
+        let faction_repo = Arc::new(repos.faction_repo);
+        let dialogue_tree_repo = Arc::new(repos.dialogue_tree_repo);
         let location_state_repo = Arc::new(repos.location_state_repo);
         let region_state_repo = Arc::new(repos.region_state_repo);
 
@@ -1162,6 +1550,31 @@ mod ws_integration_tests_inline {
         let flag = Arc::new(crate::entities::Flag::new(flag_repo.clone()));
         let goal = Arc::new(crate::entities::Goal::new(goal_repo.clone()));
         let lore = Arc::new(crate::entities::Lore::new(lore_repo.clone()));
+        let progress_clock = Arc::new(crate::entities::ProgressClock::new(
+            progress_clock_repo,
+            clock.clone(),
+        ));
+        let tag = Arc::new(crate::entities::Tag::new(tag_repo.clone()));
+        let custom_field = Arc::new(crate::entities::CustomField::new(custom_field_repo.clone()));
+        let template = Arc::new(crate::entities::Template::new(template_repo));
+        let library = Arc::new(crate::entities::Library::new(library_repo));
+        let draft = Arc::new(crate::entities::Draft::new(content_draft_repo));
+        let crowd = Arc::new(crate::entities::Crowd::new(crowd_repo));
+        let npc_draft = Arc::new(crate::entities::NpcDraft::new(npc_draft_repo));
+        let mention = Arc::new(crate::entities::Mention::new(mention_repo));
+        let name_generator = Arc::new(crate::entities::NameGenerator::new(name_generator_repo));
+        let economy = Arc::new(crate::entities::Economy::new(economy_repo));
+        let property = Arc::new(crate::entities::Property::new(property_repo));
+        let companion = Arc::new(crate::entities::Companion::new(
+            companion_repo,
+            character_repo.clone(),
+        ));
+        let injury = Arc::new(crate::entities::Injury::new(injury_repo));
+        let pc_secret = Arc::new(crate::entities::PcSecret::new(pc_secret_repo));
+        let letter = Arc::new(crate::entities::Letter::new(letter_repo));
+        let broadsheet = Arc::new(crate::entities::Broadsheet::new(broadsheet_repo));
+        let faction = Arc::new(crate::entities::Faction::new(faction_repo));
+        let dialogue_tree = Arc::new(crate::entities::DialogueTree::new(dialogue_tree_repo));
         let location_state = Arc::new(crate::entities::LocationStateEntity::new(
             location_state_repo.clone(),
         ));
@@ -1185,6 +1598,25 @@ mod ws_integration_tests_inline {
             flag: flag.clone(),
             goal: goal.clone(),
             lore: lore.clone(),
+            progress_clock: progress_clock.clone(),
+            tag: tag.clone(),
+            custom_field: custom_field.clone(),
+            template: template.clone(),
+            library: library.clone(),
+            draft: draft.clone(),
+            crowd: crowd.clone(),
+            npc_draft: npc_draft.clone(),
+            mention: mention.clone(),
+            name_generator: name_generator.clone(),
+            economy: economy.clone(),
+            property: property.clone(),
+            companion: companion.clone(),
+            injury: injury.clone(),
+            pc_secret: pc_secret.clone(),
+            letter: letter.clone(),
+            broadsheet: broadsheet.clone(),
+            faction: faction.clone(),
+            dialogue_tree: dialogue_tree.clone(),
             location_state: location_state.clone(),
             region_state: region_state.clone(),
         };
@@ -1206,6 +1638,7 @@ mod ws_integration_tests_inline {
                 inventory.clone(),
                 flag.clone(),
                 world.clone(),
+                companion.clone(),
                 suggest_time.clone(),
             )),
             Arc::new(crate::use_cases::movement::ExitLocation::new(
@@ -1218,12 +1651,41 @@ mod ws_integration_tests_inline {
                 inventory.clone(),
                 flag.clone(),
                 world.clone(),
+                companion.clone(),
                 suggest_time.clone(),
             )),
+            Arc::new(crate::use_cases::movement::UnlockConnection::new(
+                player_character.clone(),
+                location.clone(),
+                inventory.clone(),
+            )),
         );
 
-        let scene_change =
-            crate::use_cases::SceneChangeBuilder::new(location.clone(), inventory.clone());
+        let travel_uc = crate::use_cases::TravelUseCases::new(Arc::new(
+            crate::use_cases::travel::FastTravel::new(
+                player_character.clone(),
+                location.clone(),
+                observation.clone(),
+                movement.exit_location.clone(),
+                suggest_time.clone(),
+                random.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let make_noise = Arc::new(crate::use_cases::location_events::MakeNoise::new(
+            location.clone(),
+            staging.clone(),
+            player_character.clone(),
+            clock.clone(),
+        ));
+
+        let scene_change = crate::use_cases::SceneChangeBuilder::new(
+            location.clone(),
+            inventory.clone(),
+            crowd.clone(),
+            world.clone(),
+        );
 
         let conversation_start = Arc::new(crate::use_cases::conversation::StartConversation::new(
             character.clone(),
@@ -1255,13 +1717,27 @@ mod ws_integration_tests_inline {
             conversation_end,
         );
 
-        let player_action = crate::use_cases::PlayerActionUseCases::new(Arc::new(
-            crate::use_cases::player_action::HandlePlayerAction::new(
+        // Lines held for a merge window, shared by the worker that answers them
+        // and the use cases that take them back
+        let conversation_queues =
+            Arc::new(crate::use_cases::queues::arbitration::ConversationQueues::default());
+        let safety_signals = Arc::new(crate::use_cases::safety::SafetySignals::new(
+            world.clone(),
+            queue.clone(),
+            conversation_queues.clone(),
+        ));
+
+        let player_action = crate::use_cases::PlayerActionUseCases::new(
+            Arc::new(crate::use_cases::player_action::HandlePlayerAction::new(
                 conversation_start,
                 queue.clone(),
                 clock.clone(),
-            ),
-        ));
+            )),
+            Arc::new(crate::use_cases::player_action::RetractPlayerAction::new(
+                queue.clone(),
+                conversation_queues.clone(),
+            )),
+        );
 
         let actantial = crate::use_cases::ActantialUseCases::new(
             crate::use_cases::actantial::GoalOps::new(goal.clone()),
@@ -1269,13 +1745,20 @@ mod ws_integration_tests_inline {
             crate::use_cases::actantial::ActantialContextOps::new(character.clone()),
         );
 
-        let ai = crate::use_cases::AiUseCases::new(Arc::new(
-            crate::use_cases::ai::SuggestionOps::new(
+        // Create settings entity
+        let settings_entity = Arc::new(crate::entities::Settings::new(settings_repo.clone()));
+
+        let ai = crate::use_cases::AiUseCases::new(
+            Arc::new(crate::use_cases::ai::SuggestionOps::new(
                 queue.clone(),
                 world.clone(),
                 character.clone(),
-            ),
-        ));
+            )),
+            Arc::new(crate::use_cases::ai::ModelOps::new(
+                Arc::new(crate::infrastructure::ports::MockLlmModelPort::new()),
+                settings_entity.clone(),
+            )),
+        );
 
         let resolve_outcome = Arc::new(crate::use_cases::challenge::ResolveOutcome::new(
             challenge.clone(),
@@ -1283,18 +1766,25 @@ mod ws_integration_tests_inline {
             observation.clone(),
             scene.clone(),
             player_character.clone(),
+            progress_clock.clone(),
         ));
         let outcome_decision = Arc::new(crate::use_cases::challenge::OutcomeDecision::new(
             queue.clone(),
             resolve_outcome.clone(),
+            movement.unlock_connection.clone(),
+            challenge.clone(),
+            clock.clone(),
         ));
 
         let challenge_uc = crate::use_cases::ChallengeUseCases::new(
             Arc::new(crate::use_cases::challenge::RollChallenge::new(
                 challenge.clone(),
                 player_character.clone(),
+                location.clone(),
+                world.clone(),
+                staging.clone(),
                 queue.clone(),
-                random,
+                random.clone(),
                 clock.clone(),
             )),
             resolve_outcome,
@@ -1305,8 +1795,63 @@ mod ws_integration_tests_inline {
             Arc::new(crate::use_cases::challenge::ChallengeOps::new(
                 challenge.clone(),
             )),
+            Arc::new(crate::use_cases::challenge::SuggestionFeedback::new(
+                challenge.clone(),
+                queue.clone(),
+                clock.clone(),
+            )),
+            Arc::new(crate::use_cases::challenge::ChallengeOdds::new(
+                challenge.clone(),
+                player_character.clone(),
+            )),
+            Arc::new(crate::use_cases::challenge::ChallengeHistory::new(
+                challenge.clone(),
+            )),
+        );
+
+        let execute_effects = Arc::new(crate::use_cases::narrative::ExecuteEffects::new(
+            inventory.clone(),
+            challenge.clone(),
+            narrative.clone(),
+            character.clone(),
+            observation.clone(),
+            player_character.clone(),
+            scene.clone(),
+            flag.clone(),
+            world.clone(),
+            clock.clone(),
+            plugins.clone(),
+        ));
+        let plugin_tools = Arc::new(crate::use_cases::plugins::PluginTools::new(
+            plugins,
+            execute_effects.clone(),
+        ));
+        let scripts: Arc<dyn crate::infrastructure::ports::ScriptEnginePort> =
+            Arc::new(crate::infrastructure::rhai_scripts::RhaiScriptEngine::new());
+        let scripts_uc = crate::use_cases::ScriptUseCases::new(
+            Arc::new(crate::use_cases::scripts::ManageScripts::new(
+                world.clone(),
+                scripts.clone(),
+                clock.clone(),
+            )),
+            Arc::new(crate::use_cases::scripts::RunScriptHooks::new(
+                world.clone(),
+                flag.clone(),
+                scripts,
+                execute_effects.clone(),
+            )),
         );
 
+        let manage_mentions = Arc::new(crate::use_cases::mentions::ManageMentions::new(
+            mention.clone(),
+            character.clone(),
+            location.clone(),
+            inventory.clone(),
+            lore.clone(),
+            narrative.clone(),
+        ));
+        let mentions_uc = crate::use_cases::MentionUseCases::new(manage_mentions.clone());
+
         let approve_suggestion =
             Arc::new(crate::use_cases::approval::ApproveSuggestion::new(queue.clone()));
         let approval = crate::use_cases::ApprovalUseCases::new(
@@ -1317,41 +1862,204 @@ mod ws_integration_tests_inline {
             Arc::new(crate::use_cases::approval::ApprovalDecisionFlow::new(
                 approve_suggestion,
                 narrative.clone(),
-                queue.clone(),
-            )),
-        );
-
-        let assets_uc = crate::use_cases::AssetUseCases::new(
-            Arc::new(crate::use_cases::assets::GenerateAsset::new(
-                assets.clone(),
-                queue.clone(),
+                queue.clone(),
+                plugin_tools.clone(),
+                Arc::new(crate::use_cases::approval::RetryRejectedResponse::new(
+                    queue.clone(),
+                )),
+            )),
+            Arc::new(crate::use_cases::approval::CoDm::new(
+                queue.clone(),
+                llm.clone(),
+                world.clone(),
+                settings_entity.clone(),
+                clock.clone(),
+            )),
+            Arc::new(crate::use_cases::approval::NpcAutonomy::new(
+                settings_entity.clone(),
+            )),
+            Arc::new(crate::use_cases::approval::SelectApprovals::new(queue.clone())),
+        );
+
+        let generate_asset = Arc::new(crate::use_cases::assets::GenerateAsset::new(
+            assets.clone(),
+            queue.clone(),
+            blob_store.clone(),
+            usage_repo.clone(),
+            settings_entity.clone(),
+            clock.clone(),
+        ));
+        let assets_uc = crate::use_cases::AssetUseCases::new(
+            generate_asset.clone(),
+            Arc::new(crate::use_cases::assets::GenerateExpressionSheet::new(
+                assets.clone(),
+                character.clone(),
+                queue.clone(),
+                clock.clone(),
+            )),
+            Arc::new(crate::use_cases::assets::GenerateRegionMap::new(
+                location.clone(),
+                generate_asset,
+            )),
+            Arc::new(crate::use_cases::assets::AssetStorage::new(
+                blob_store.clone(),
+            )),
+            Arc::new(crate::use_cases::assets::UploadAsset::new(
+                assets.clone(),
+                blob_store,
+                llm.clone(),
+                character.clone(),
+                location.clone(),
+                usage_repo.clone(),
+                clock.clone(),
+            )),
+            Arc::new(crate::use_cases::assets::ManageGallery::new(assets.clone())),
+        );
+
+        let export_world = Arc::new(crate::use_cases::world::ExportWorld::new(
+            world.clone(),
+            location.clone(),
+            character.clone(),
+            inventory.clone(),
+            narrative.clone(),
+        ));
+        let import_world = Arc::new(crate::use_cases::world::ImportWorld::new(
+            world.clone(),
+            location.clone(),
+            character.clone(),
+            inventory.clone(),
+            narrative.clone(),
+        ));
+        let world_uc = crate::use_cases::WorldUseCases::new(
+            export_world.clone(),
+            import_world.clone(),
+            Arc::new(crate::use_cases::world::BranchWorld::new(
+                export_world,
+                import_world,
+                clock.clone(),
+            )),
+        );
+
+        let manage_economy = Arc::new(crate::use_cases::economy::ManageEconomy::new(
+            economy.clone(),
+            inventory.clone(),
+            location.clone(),
+            flag.clone(),
+            world.clone(),
+            clock.clone(),
+        ));
+        let economy_uc = crate::use_cases::EconomyUseCases::new(manage_economy.clone());
+
+        let property_uc = crate::use_cases::PropertyUseCases::new(Arc::new(
+            crate::use_cases::property::ManageProperties::new(
+                property.clone(),
+                player_character.clone(),
+                character.clone(),
+                location.clone(),
+                world.clone(),
+                clock.clone(),
+            ),
+        ));
+        let companion_uc = crate::use_cases::CompanionUseCases::new(Arc::new(
+            crate::use_cases::companions::ManageCompanions::new(
+                companion.clone(),
+                player_character.clone(),
+                character.clone(),
+                world.clone(),
+                clock.clone(),
+            ),
+        ));
+        let injury_uc = crate::use_cases::InjuryUseCases::new(Arc::new(
+            crate::use_cases::injuries::ManageInjuries::new(
+                injury.clone(),
+                player_character.clone(),
+                challenge.clone(),
+                world.clone(),
+                clock.clone(),
+            ),
+        ));
+        let manage_secrets = Arc::new(crate::use_cases::secrets::ManageSecrets::new(
+            pc_secret.clone(),
+            player_character.clone(),
+            character.clone(),
+            narrative.clone(),
+            clock.clone(),
+        ));
+        let secret_uc = crate::use_cases::SecretUseCases::new(manage_secrets.clone());
+        let mail_uc = crate::use_cases::MailUseCases::new(Arc::new(
+            crate::use_cases::mail::ManageMail::new(
+                letter.clone(),
+                player_character.clone(),
+                character.clone(),
+                location.clone(),
+                world.clone(),
+                settings_entity.clone(),
+                llm.clone(),
+                clock.clone(),
+            ),
+        ));
+        let broadsheet_uc = crate::use_cases::BroadsheetUseCases::new(Arc::new(
+            crate::use_cases::broadsheet::ManageBroadsheets::new(
+                broadsheet.clone(),
+                narrative.clone(),
+                world.clone(),
+                settings_entity.clone(),
+                llm.clone(),
+                clock.clone(),
+            ),
+        ));
+        let simulation_uc = crate::use_cases::SimulationUseCases::new(Arc::new(
+            crate::use_cases::simulation::SimulateWorld::new(
+                world.clone(),
+                character.clone(),
+                location.clone(),
+                lore.clone(),
+                economy.clone(),
+                progress_clock.clone(),
+                narrative.clone(),
+                random.clone(),
                 clock.clone(),
-            )),
-            Arc::new(crate::use_cases::assets::GenerateExpressionSheet::new(
-                assets.clone(),
+            ),
+        ));
+        let mortality_uc = crate::use_cases::MortalityUseCases::new(Arc::new(
+            crate::use_cases::mortality::ManageMortality::new(
+                player_character.clone(),
+                world.clone(),
+                narrative.clone(),
+                lore.clone(),
+                observation.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let factions_uc = crate::use_cases::FactionUseCases::new(Arc::new(
+            crate::use_cases::factions::ManageFactions::new(
+                faction.clone(),
                 character.clone(),
-                queue.clone(),
                 clock.clone(),
-            )),
-        );
+            ),
+        ));
 
-        let world_uc = crate::use_cases::WorldUseCases::new(
-            Arc::new(crate::use_cases::world::ExportWorld::new(
-                world.clone(),
-                location.clone(),
+        let dialogue_trees_uc = crate::use_cases::DialogueTreeUseCases::new(
+            Arc::new(crate::use_cases::dialogue_trees::ManageDialogueTrees::new(
+                dialogue_tree.clone(),
                 character.clone(),
-                inventory.clone(),
                 narrative.clone(),
+                clock.clone(),
             )),
-            Arc::new(crate::use_cases::world::ImportWorld::new(
-                world.clone(),
-                location.clone(),
+            Arc::new(crate::use_cases::dialogue_trees::ScriptedBeats::new(
+                dialogue_tree.clone(),
                 character.clone(),
-                inventory.clone(),
-                narrative.clone(),
+                execute_effects.clone(),
+                clock.clone(),
             )),
         );
 
+        let progress_clock_ops = Arc::new(crate::use_cases::progress_clock::ProgressClockOps::new(
+            progress_clock.clone(),
+            clock.clone(),
+        ));
+
         let queues = crate::use_cases::QueueUseCases::new(
             Arc::new(crate::use_cases::queues::ProcessPlayerAction::new(
                 queue.clone(),
@@ -1360,26 +2068,29 @@ mod ws_integration_tests_inline {
                 staging.clone(),
                 scene.clone(),
                 world.clone(),
+                location.clone(),
                 narrative.clone(),
+                manage_mentions,
+                manage_economy,
+                make_noise.clone(),
+                challenge_uc.feedback.clone(),
+                progress_clock_ops.clone(),
+                manage_secrets,
+                factions_uc.manage.clone(),
+                settings_entity.clone(),
+                clock.clone(),
+                conversation_queues,
+                safety_signals.clone(),
             )),
             Arc::new(crate::use_cases::queues::ProcessLlmRequest::new(
                 queue.clone(),
                 llm.clone(),
+                world.clone(),
+                settings_entity.clone(),
+                plugin_tools.clone(),
             )),
         );
 
-        let execute_effects = Arc::new(crate::use_cases::narrative::ExecuteEffects::new(
-            inventory.clone(),
-            challenge.clone(),
-            narrative.clone(),
-            character.clone(),
-            observation.clone(),
-            player_character.clone(),
-            scene.clone(),
-            flag.clone(),
-            world.clone(),
-            clock.clone(),
-        ));
         let narrative_events = Arc::new(crate::use_cases::narrative::NarrativeEventOps::new(
             narrative.clone(),
             execute_effects.clone(),
@@ -1419,6 +2130,8 @@ mod ws_integration_tests_inline {
         let staging_uc = crate::use_cases::StagingUseCases::new(
             Arc::new(crate::use_cases::staging::RequestStagingApproval::new(
                 character.clone(),
+                companion.clone(),
+                faction.clone(),
                 staging.clone(),
                 location.clone(),
                 world.clone(),
@@ -1431,7 +2144,9 @@ mod ws_integration_tests_inline {
                 crate::use_cases::staging::RegenerateStagingSuggestions::new(
                     location.clone(),
                     character.clone(),
+                    faction.clone(),
                     llm.clone(),
+                    settings_repo.clone(),
                 ),
             ),
             Arc::new(crate::use_cases::staging::ApproveStagingRequest::new(
@@ -1444,6 +2159,7 @@ mod ws_integration_tests_inline {
             )),
             Arc::new(crate::use_cases::staging::AutoApproveStagingTimeout::new(
                 character.clone(),
+                companion.clone(),
                 staging.clone(),
                 world.clone(),
                 location.clone(),
@@ -1453,8 +2169,36 @@ mod ws_integration_tests_inline {
             )),
         );
 
-        // Create settings entity
-        let settings_entity = Arc::new(crate::entities::Settings::new(settings_repo.clone()));
+        let flashback_uc = crate::use_cases::FlashbackUseCases::new(Arc::new(
+            crate::use_cases::flashback::ManageFlashbacks::new(
+                world.clone(),
+                location.clone(),
+                staging.clone(),
+                region_state.clone(),
+                staging_uc.approve.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let parallel_scenes_uc = crate::use_cases::ParallelSceneUseCases::new(Arc::new(
+            crate::use_cases::parallel_scenes::ManageParallelScenes::new(
+                world.clone(),
+                player_character.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let spotlight_uc = crate::use_cases::SpotlightUseCases::new(Arc::new(
+            crate::use_cases::spotlight::ManageSpotlight::new(
+                world.clone(),
+                player_character.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let pacing_uc = crate::use_cases::PacingUseCases::new(Arc::new(
+            crate::use_cases::pacing::PacingTimers::new(clock.clone()),
+        ));
 
         let npc_uc = crate::use_cases::NpcUseCases::new(
             Arc::new(crate::use_cases::npc::NpcDisposition::new(
@@ -1487,12 +2231,198 @@ mod ws_integration_tests_inline {
             crate::use_cases::lore::LoreOps::new(lore.clone()),
         ));
 
-        let location_events_uc = crate::use_cases::LocationEventUseCases::new(Arc::new(
-            crate::use_cases::location_events::TriggerLocationEvent::new(location.clone()),
+        let knowledge_uc = crate::use_cases::KnowledgeUseCases::new(Arc::new(
+            crate::use_cases::knowledge::ShareKnowledge::new(
+                player_character.clone(),
+                character.clone(),
+                lore.clone(),
+                observation.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let progress_clock_uc = crate::use_cases::ProgressClockUseCases::new(progress_clock_ops);
+
+        let tags_uc = crate::use_cases::TagUseCases::new(
+            Arc::new(crate::use_cases::tags::ManageTags::new(tag.clone())),
+            Arc::new(crate::use_cases::tags::SavedFilters::new(
+                tag.clone(),
+                clock.clone(),
+            )),
+        );
+        let custom_fields_uc = crate::use_cases::CustomFieldUseCases::new(
+            Arc::new(crate::use_cases::custom_fields::ManageFieldSchema::new(
+                world.clone(),
+                clock.clone(),
+            )),
+            Arc::new(crate::use_cases::custom_fields::FieldValues::new(
+                world.clone(),
+                custom_field.clone(),
+            )),
+        );
+        let templates_uc = crate::use_cases::TemplateUseCases::new(
+            Arc::new(crate::use_cases::templates::ManageTemplates::new(
+                template.clone(),
+                character.clone(),
+                location.clone(),
+                inventory.clone(),
+                clock.clone(),
+            )),
+            Arc::new(crate::use_cases::templates::InstantiateTemplate::new(
+                template.clone(),
+                character.clone(),
+                location.clone(),
+                inventory.clone(),
+            )),
+        );
+        let duplicate_uc = crate::use_cases::DuplicateUseCases::new(Arc::new(
+            crate::use_cases::duplicate::DuplicateEntity::new(
+                character.clone(),
+                location.clone(),
+                inventory.clone(),
+                challenge.clone(),
+                narrative.clone(),
+                tag.clone(),
+                custom_field.clone(),
+                clock.clone(),
+            ),
+        ));
+        let library_uc = crate::use_cases::LibraryUseCases::new(
+            Arc::new(crate::use_cases::library::ManageLibrary::new(
+                library.clone(),
+                character.clone(),
+                inventory.clone(),
+                challenge.clone(),
+                lore.clone(),
+                clock.clone(),
+            )),
+            Arc::new(crate::use_cases::library::ImportLibraryEntry::new(
+                library.clone(),
+                character.clone(),
+                inventory.clone(),
+                challenge.clone(),
+                lore.clone(),
+                world.clone(),
+                skill.clone(),
+                clock.clone(),
+            )),
+        );
+        let drafts_uc = crate::use_cases::DraftUseCases::new(Arc::new(
+            crate::use_cases::drafts::ManageDrafts::new(
+                draft.clone(),
+                character.clone(),
+                location.clone(),
+                clock.clone(),
+            ),
+        ));
+        let chronology_uc = crate::use_cases::ChronologyUseCases::new(Arc::new(
+            crate::use_cases::chronology::ManageChronology::new(
+                world.clone(),
+                character.clone(),
+                lore.clone(),
+                clock.clone(),
+            ),
+        ));
+        let crowds_uc = crate::use_cases::CrowdUseCases::new(Arc::new(
+            crate::use_cases::crowds::ManageCrowds::new(
+                crowd.clone(),
+                location.clone(),
+                clock.clone(),
+            ),
+        ));
+        let manage_names = Arc::new(crate::use_cases::names::ManageNameGenerators::new(
+            name_generator.clone(),
+            character.clone(),
+            location.clone(),
+            random.clone(),
+            clock.clone(),
+        ));
+        let names_uc = crate::use_cases::NameGeneratorUseCases::new(manage_names.clone());
+        let npc_drafts_uc = crate::use_cases::NpcDraftUseCases::new(Arc::new(
+            crate::use_cases::npc_drafts::ManageNpcDrafts::new(
+                npc_draft.clone(),
+                crowd.clone(),
+                location.clone(),
+                lore.clone(),
+                character.clone(),
+                world.clone(),
+                settings_entity.clone(),
+                manage_names,
+                llm.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let safety_uc = crate::use_cases::SafetyUseCases::new(safety_signals);
+
+        let trade_uc =
+            crate::use_cases::TradeUseCases::new(Arc::new(crate::use_cases::trade::Trades::new(
+                player_character.clone(),
+                world.clone(),
+                narrative.clone(),
+                clock.clone(),
+            )));
+
+        let dice_uc = crate::use_cases::DiceUseCases::new(Arc::new(
+            crate::use_cases::dice::RollDice::new(player_character.clone(), random, clock.clone()),
+        ));
+
+        let tutorial_uc = crate::use_cases::TutorialUseCases::new(
+            Arc::new(crate::use_cases::tutorial::CreateTutorialWorld::new(
+                world.clone(),
+                location.clone(),
+                character.clone(),
+                challenge.clone(),
+                staging.clone(),
+                clock.clone(),
+            )),
+            Arc::new(crate::use_cases::tutorial::TutorialProgress::new(
+                world.clone(),
+                flag.clone(),
+            )),
+        );
+
+        let session_zero_uc = crate::use_cases::SessionZeroUseCases::new(Arc::new(
+            crate::use_cases::session_zero::CreateSessionZeroWorld::new(
+                world.clone(),
+                location.clone(),
+                skill.clone(),
+                npc_draft.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let location_events_uc = crate::use_cases::LocationEventUseCases::new(
+            Arc::new(crate::use_cases::location_events::TriggerLocationEvent::new(
+                location.clone(),
+            )),
+            make_noise,
+        );
+
+        let interactions_uc = crate::use_cases::InteractionUseCases::new(Arc::new(
+            crate::use_cases::interactions::InteractionLibrary::new(
+                interaction.clone(),
+                scene.clone(),
+                player_character.clone(),
+                world.clone(),
+            ),
+        ));
+        let rule_system_uc = crate::use_cases::RuleSystemUseCases::new(Arc::new(
+            crate::use_cases::rule_system::ChangeRuleSystem::new(
+                world.clone(),
+                skill.clone(),
+                challenge.clone(),
+                player_character.clone(),
+                clock.clone(),
+            ),
         ));
 
         let management = crate::use_cases::ManagementUseCases::new(
-            crate::use_cases::management::WorldCrud::new(world.clone(), clock.clone()),
+            crate::use_cases::management::WorldCrud::new(
+                world.clone(),
+                location.clone(),
+                clock.clone(),
+            ),
             crate::use_cases::management::CharacterCrud::new(character.clone(), clock.clone()),
             crate::use_cases::management::LocationCrud::new(location.clone()),
             crate::use_cases::management::PlayerCharacterCrud::new(
@@ -1515,6 +2445,90 @@ mod ws_integration_tests_inline {
             crate::use_cases::management::SkillCrud::new(skill.clone()),
         );
 
+        let service_probe: Arc<dyn crate::infrastructure::ports::ServiceProbePort> =
+            Arc::new(crate::infrastructure::ports::MockServiceProbePort::new());
+        let setup_uc = crate::use_cases::SetupUseCases::new(
+            Arc::new(crate::use_cases::setup::SetupStatus::new(
+                settings_entity.clone(),
+                service_probe.clone(),
+                wrldbldr_domain::ServiceConnections::default(),
+            )),
+            Arc::new(crate::use_cases::setup::CheckConnections::new(
+                settings_entity.clone(),
+                service_probe.clone(),
+                wrldbldr_domain::ServiceConnections::default(),
+            )),
+            Arc::new(crate::use_cases::setup::SaveConnections::new(
+                settings_entity.clone(),
+                service_probe.clone(),
+                wrldbldr_domain::ServiceConnections::default(),
+            )),
+        );
+
+        let health_uc = crate::use_cases::HealthUseCases::new(Arc::new(
+            crate::use_cases::health::CheckDependencies::new(
+                service_probe,
+                wrldbldr_domain::ServiceConnections::default(),
+                crate::use_cases::health::ASSETS_DIR,
+                crate::use_cases::health::MIN_FREE_DISK_BYTES,
+            ),
+        ));
+
+        let analytics_uc = crate::use_cases::AnalyticsUseCases::new(
+            Arc::new(crate::use_cases::analytics::GetCampaignAnalytics::new(
+                player_character.clone(),
+                character.clone(),
+                location.clone(),
+                narrative.clone(),
+                challenge.clone(),
+                observation.clone(),
+            )),
+            Arc::new(crate::use_cases::analytics::GetUnusedContent::new(
+                player_character.clone(),
+                character.clone(),
+                location.clone(),
+                narrative.clone(),
+                challenge.clone(),
+                observation.clone(),
+                lore.clone(),
+            )),
+        );
+
+        let prep_uc = crate::use_cases::PrepUseCases::new(Arc::new(
+            crate::use_cases::prep::GeneratePrepChecklist::new(
+                world.clone(),
+                player_character.clone(),
+                location.clone(),
+                character.clone(),
+                staging.clone(),
+                challenge.clone(),
+                progress_clock.clone(),
+            ),
+        ));
+
+        let usage_uc = crate::use_cases::UsageUseCases::new(
+            Arc::new(crate::use_cases::usage::GetUsage::new(
+                usage_repo.clone(),
+                settings_entity.clone(),
+                clock.clone(),
+            )),
+            Arc::new(crate::use_cases::usage::CheckUsageQuotas::new(
+                usage_repo,
+                settings_entity.clone(),
+                clock.clone(),
+            )),
+        );
+
+        let diagnostics = Arc::new(crate::infrastructure::diagnostics::Diagnostics::default());
+        let diagnostics_uc = crate::use_cases::DiagnosticsUseCases::new(Arc::new(
+            crate::use_cases::diagnostics::ExportDiagnostics::new(
+                diagnostics.clone(),
+                queue.clone(),
+                world.clone(),
+                clock.clone(),
+            ),
+        ));
+
         let settings = settings_entity;
 
         let join_world = Arc::new(crate::use_cases::session::JoinWorld::new(
@@ -1527,10 +2541,15 @@ mod ws_integration_tests_inline {
         let join_world_flow =
             Arc::new(crate::use_cases::session::JoinWorldFlow::new(join_world.clone()));
         let directorial_update = Arc::new(crate::use_cases::session::DirectorialUpdate::new());
+        let control_pc = Arc::new(crate::use_cases::session::ControlPc::new(
+            join_world.clone(),
+            player_character.clone(),
+        ));
         let session = crate::use_cases::SessionUseCases::new(
             join_world,
             join_world_flow,
             directorial_update,
+            control_pc,
         );
 
         let use_cases = UseCases {
@@ -1546,16 +2565,59 @@ mod ws_integration_tests_inline {
             queues,
             narrative: narrative_uc,
             player_action,
+            scripts: scripts_uc,
             time: time_uc,
             visual_state: visual_state_uc,
             management,
             session,
             settings,
+            setup: setup_uc,
+            health: health_uc,
             staging: staging_uc,
             npc: npc_uc,
             story_events: story_events_uc,
             lore: lore_uc,
+            knowledge: knowledge_uc,
+            progress_clock: progress_clock_uc,
+            tags: tags_uc,
+            custom_fields: custom_fields_uc,
+            templates: templates_uc,
+            duplicate: duplicate_uc,
+            library: library_uc,
+            drafts: drafts_uc,
+            chronology: chronology_uc,
+            crowds: crowds_uc,
+            npc_drafts: npc_drafts_uc,
+            mentions: mentions_uc,
+            names: names_uc,
+            economy: economy_uc,
+            property: property_uc,
+            companion: companion_uc,
+            injury: injury_uc,
+            secret: secret_uc,
+            mail: mail_uc,
+            broadsheet: broadsheet_uc,
+            factions: factions_uc,
+            dialogue_trees: dialogue_trees_uc,
+            flashback: flashback_uc,
+            parallel_scenes: parallel_scenes_uc,
+            spotlight: spotlight_uc,
+            pacing: pacing_uc,
+            simulation: simulation_uc,
+            mortality: mortality_uc,
+            safety: safety_uc,
+            trade: trade_uc,
+            travel: travel_uc,
+            dice: dice_uc,
+            tutorial: tutorial_uc,
+            session_zero: session_zero_uc,
+            usage: usage_uc,
+            analytics: analytics_uc,
+            prep: prep_uc,
+            diagnostics: diagnostics_uc,
             location_events: location_events_uc,
+            interactions: interactions_uc,
+            rule_system: rule_system_uc,
         };
 
         Arc::new(App {
@@ -1563,6 +2625,9 @@ mod ws_integration_tests_inline {
             use_cases,
             queue,
             llm,
+            circuit_breakers:
+                crate::infrastructure::circuit_breaker::ServiceCircuitBreakers::default(),
+            diagnostics,
         })
     }
 
@@ -2267,6 +3332,8 @@ mod ws_integration_tests_inline {
                 game_time: None,
                 topics: vec![],
                 conversation_id: None,
+                safety_warnings: vec![],
+                merged_lines: vec![],
             },
         );
 
@@ -2417,6 +3484,8 @@ mod ws_integration_tests_inline {
                 game_time: None,
                 topics: vec![],
                 conversation_id: None,
+                safety_warnings: vec![],
+                merged_lines: vec![],
             },
         );
 
@@ -2544,6 +3613,8 @@ mod ws_integration_tests_inline {
                 game_time: None,
                 topics: vec![],
                 conversation_id: None,
+                safety_warnings: vec![],
+                merged_lines: vec![],
             },
         );
 
@@ -2970,6 +4041,12 @@ mod ws_integration_tests_inline {
                 }])
             });
 
+        // World settings choose the classification model.
+        repos
+            .settings_repo
+            .expect_get_for_world()
+            .returning(|_| Ok(None));
+
         // Regenerate should not touch staging persistence.
         repos.staging_repo.expect_save_pending_staging().times(0);
         repos.staging_repo.expect_activate_staging().times(0);
@@ -3087,6 +4164,24 @@ fn parse_item_id(id_str: &str) -> Result<ItemId, ServerMessage> {
     parse_id(id_str, ItemId::from_uuid, "Invalid item ID format")
 }
 
+/// Parse a trade ID from a string.
+fn parse_trade_id(id_str: &str) -> Result<wrldbldr_domain::TradeId, ServerMessage> {
+    parse_id(
+        id_str,
+        wrldbldr_domain::TradeId::from_uuid,
+        "Invalid trade ID format",
+    )
+}
+
+/// Parse a journey ID from a string.
+fn parse_journey_id(id_str: &str) -> Result<wrldbldr_domain::JourneyId, ServerMessage> {
+    parse_id(
+        id_str,
+        wrldbldr_domain::JourneyId::from_uuid,
+        "Invalid journey ID format",
+    )
+}
+
 /// Parse a challenge ID from a string.
 fn parse_challenge_id(id_str: &str) -> Result<ChallengeId, ServerMessage> {
     parse_id(
@@ -13,16 +13,25 @@ use futures_util::{SinkExt, StreamExt};
 use tokio::net::TcpListener;
 use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
 
-use crate::app::{App, Entities, UseCases};
+use crate::app::{App, AppPorts, Repositories};
+use crate::infrastructure::circuit_breaker::ServiceCircuitBreakers;
 use crate::infrastructure::ports::{
-    ClockPort, ImageGenError, ImageGenPort, LlmError, LlmPort, QueueError, QueueItem, RandomPort,
+    ClockPort, ImageGenError, ImageGenPort, LlmError, LlmPort, PluginPort, QueueError, QueueItem,
+    QueueItemData, QueueItemStatus, RandomPort,
 };
 use crate::infrastructure::ports::{
-    MockActRepo, MockAssetRepo, MockChallengeRepo, MockCharacterRepo, MockFlagRepo, MockGoalRepo,
-    MockInteractionRepo, MockItemRepo, MockLocationRepo, MockLocationStateRepo, MockLoreRepo,
-    MockNarrativeRepo, MockObservationRepo, MockPlayerCharacterRepo, MockRegionStateRepo,
-    MockSceneRepo, MockSettingsRepo, MockSkillRepo, MockStagingRepo,
+    MockActRepo, MockAssetRepo, MockBlobStorePort, MockBroadsheetRepo, MockChallengeRepo,
+    MockCharacterRepo, MockCompanionRepo, MockContentDraftRepo, MockCrowdRepo, MockCustomFieldRepo,
+    MockDialogueTreeRepo, MockEconomyRepo, MockFactionRepo, MockFlagRepo, MockGoalRepo, MockInjuryRepo, MockInteractionRepo, MockItemRepo,
+    MockLetterRepo, MockLibraryRepo, MockLlmModelPort, MockLocationRepo, MockLocationStateRepo,
+    MockLoreRepo, MockMentionRepo, MockNameGeneratorRepo, MockNarrativeRepo, MockNpcDraftRepo,
+    MockObservationRepo, MockPcSecretRepo, MockPlayerCharacterRepo, MockProgressClockRepo,
+    MockPropertyRepo, MockRegionStateRepo, MockSceneRepo, MockServiceProbePort, MockSettingsRepo,
+    MockSkillRepo, MockStagingRepo, MockTagRepo, MockTemplateRepo, MockTranscriptionPort,
+    MockUsageRepo,
 };
+use crate::infrastructure::rhai_scripts::RhaiScriptEngine;
+use crate::infrastructure::wasm_plugins::{PluginLimits, WasmPluginHost};
 
 pub(crate) use crate::infrastructure::ports::{MockWorldRepo, QueuePort};
 
@@ -36,6 +45,8 @@ pub(crate) struct TestAppRepos {
     pub(crate) skill_repo: MockSkillRepo,
     pub(crate) interaction_repo: MockInteractionRepo,
     pub(crate) settings_repo: MockSettingsRepo,
+    pub(crate) usage_repo: MockUsageRepo,
+    pub(crate) blob_store: MockBlobStorePort,
     pub(crate) challenge_repo: MockChallengeRepo,
     pub(crate) narrative_repo: MockNarrativeRepo,
     pub(crate) staging_repo: MockStagingRepo,
@@ -45,8 +56,30 @@ pub(crate) struct TestAppRepos {
     pub(crate) flag_repo: MockFlagRepo,
     pub(crate) goal_repo: MockGoalRepo,
     pub(crate) lore_repo: MockLoreRepo,
+    pub(crate) progress_clock_repo: MockProgressClockRepo,
+    pub(crate) tag_repo: MockTagRepo,
+    pub(crate) custom_field_repo: MockCustomFieldRepo,
+    pub(crate) template_repo: MockTemplateRepo,
+    pub(crate) library_repo: MockLibraryRepo,
+    pub(crate) content_draft_repo: MockContentDraftRepo,
+    pub(crate) crowd_repo: MockCrowdRepo,
+    pub(crate) npc_draft_repo: MockNpcDraftRepo,
+    pub(crate) mention_repo: MockMentionRepo,
+    pub(crate) name_generator_repo: MockNameGeneratorRepo,
+    pub(crate) economy_repo: MockEconomyRepo,
+    pub(crate) property_repo: MockPropertyRepo,
+    pub(crate) companion_repo: MockCompanionRepo,
+    pub(crate) injury_repo: MockInjuryRepo,
+    pub(crate) pc_secret_repo: MockPcSecretRepo,
+    pub(crate) letter_repo: MockLetterRepo,
+    pub(crate) broadsheet_repo: MockBroadsheetRepo,
+    pub(crate) faction_repo: MockFactionRepo,
+    pub(crate) dialogue_tree_repo: MockDialogueTreeRepo,
     pub(crate) location_state_repo: MockLocationStateRepo,
     pub(crate) region_state_repo: MockRegionStateRepo,
+    pub(crate) service_probe: MockServiceProbePort,
+    pub(crate) llm_models: MockLlmModelPort,
+    pub(crate) transcription: MockTranscriptionPort,
 }
 
 impl TestAppRepos {
@@ -68,6 +101,40 @@ impl TestAppRepos {
             .expect_get_current()
             .returning(|_world_id| Ok(None));
 
+        // Scene changes list the crowds in the region entered
+        let mut crowd_repo = MockCrowdRepo::new();
+        crowd_repo
+            .expect_list_in_region()
+            .returning(|_region_id| Ok(Vec::new()));
+
+        // Staging labels NPCs with their faction's standing
+        let mut faction_repo = MockFactionRepo::new();
+        faction_repo
+            .expect_list_in_world()
+            .returning(|_world_id| Ok(Vec::new()));
+
+        // Conversations look for a scripted beat before asking the LLM
+        let mut dialogue_tree_repo = MockDialogueTreeRepo::new();
+        dialogue_tree_repo
+            .expect_list_in_world()
+            .returning(|_world_id| Ok(Vec::new()));
+
+        // Saving and deleting text keeps the mention index up to date
+        let mut mention_repo = MockMentionRepo::new();
+        mention_repo
+            .expect_list_aliases()
+            .returning(|_world_id| Ok(Default::default()));
+        mention_repo
+            .expect_replace_mentions()
+            .returning(|_, _, _, _| Ok(()));
+        mention_repo.expect_forget_entity().returning(|_, _| Ok(()));
+
+        // Promoting a crowd member looks for a name generator
+        let mut name_generator_repo = MockNameGeneratorRepo::new();
+        name_generator_repo
+            .expect_list_for_world()
+            .returning(|_world_id| Ok(Vec::new()));
+
         let mut narrative_repo = MockNarrativeRepo::new();
         narrative_repo
             .expect_record_dialogue_context()
@@ -83,6 +150,8 @@ impl TestAppRepos {
             skill_repo: MockSkillRepo::new(),
             interaction_repo: MockInteractionRepo::new(),
             settings_repo: MockSettingsRepo::new(),
+            usage_repo: MockUsageRepo::new(),
+            blob_store: MockBlobStorePort::new(),
             challenge_repo: MockChallengeRepo::new(),
             narrative_repo,
             staging_repo: MockStagingRepo::new(),
@@ -92,8 +161,30 @@ impl TestAppRepos {
             flag_repo: MockFlagRepo::new(),
             goal_repo: MockGoalRepo::new(),
             lore_repo: MockLoreRepo::new(),
+            progress_clock_repo: MockProgressClockRepo::new(),
+            tag_repo: MockTagRepo::new(),
+            custom_field_repo: MockCustomFieldRepo::new(),
+            template_repo: MockTemplateRepo::new(),
+            library_repo: MockLibraryRepo::new(),
+            content_draft_repo: MockContentDraftRepo::new(),
+            crowd_repo,
+            npc_draft_repo: MockNpcDraftRepo::new(),
+            mention_repo,
+            name_generator_repo,
+            economy_repo: MockEconomyRepo::new(),
+            property_repo: MockPropertyRepo::new(),
+            companion_repo: MockCompanionRepo::new(),
+            injury_repo: MockInjuryRepo::new(),
+            pc_secret_repo: MockPcSecretRepo::new(),
+            letter_repo: MockLetterRepo::new(),
+            broadsheet_repo: MockBroadsheetRepo::new(),
+            faction_repo,
+            dialogue_tree_repo,
             location_state_repo: MockLocationStateRepo::new(),
             region_state_repo: MockRegionStateRepo::new(),
+            service_probe: MockServiceProbePort::new(),
+            llm_models: MockLlmModelPort::new(),
+            transcription: MockTranscriptionPort::new(),
         }
     }
 }
@@ -154,6 +245,19 @@ impl QueuePort for NoopQueue {
         Ok(())
     }
 
+    async fn mark_failed_if_status(
+        &self,
+        _id: Uuid,
+        _expected: QueueItemStatus,
+        _error: &str,
+    ) -> Result<bool, QueueError> {
+        Ok(false)
+    }
+
+    async fn get_item(&self, _id: Uuid) -> Result<Option<QueueItem>, QueueError> {
+        Ok(None)
+    }
+
     async fn get_pending_count(&self, _queue_type: &str) -> Result<usize, QueueError> {
         Ok(0)
     }
@@ -177,6 +281,10 @@ impl QueuePort for NoopQueue {
         Ok(false)
     }
 
+    async fn delete_by_callback_id(&self, _callback_id: &str) -> Result<bool, QueueError> {
+        Ok(false)
+    }
+
     async fn get_approval_request(
         &self,
         _id: Uuid,
@@ -264,6 +372,10 @@ impl RandomPort for FixedRandom {
 #[derive(Default)]
 pub(crate) struct RecordingApprovalQueueState {
     pub(crate) approvals: StdHashMap<Uuid, wrldbldr_domain::ApprovalRequestData>,
+    pub(crate) player_actions: StdHashMap<Uuid, wrldbldr_domain::PlayerActionData>,
+    pub(crate) llm_requests: StdHashMap<Uuid, wrldbldr_domain::LlmRequestData>,
+    /// Callback IDs of LLM requests not yet picked up
+    pub(crate) pending_llm_callbacks: Vec<String>,
     pub(crate) completed: Vec<Uuid>,
     pub(crate) failed: Vec<(Uuid, String)>,
 }
@@ -279,6 +391,35 @@ impl RecordingApprovalQueue {
         guard.approvals.insert(id, data);
     }
 
+    pub(crate) fn insert_player_action(&self, id: Uuid, data: wrldbldr_domain::PlayerActionData) {
+        let mut guard = self.state.lock().unwrap();
+        guard.player_actions.insert(id, data);
+    }
+
+    pub(crate) fn insert_llm_request(&self, id: Uuid, data: wrldbldr_domain::LlmRequestData) {
+        let mut guard = self.state.lock().unwrap();
+        guard.llm_requests.insert(id, data);
+    }
+
+    pub(crate) fn llm_requests(&self) -> Vec<wrldbldr_domain::LlmRequestData> {
+        let guard = self.state.lock().unwrap();
+        guard.llm_requests.values().cloned().collect()
+    }
+
+    /// Mark a player action processed, with its NPC response request still pending.
+    pub(crate) fn process_player_action(&self, id: Uuid) {
+        let mut guard = self.state.lock().unwrap();
+        guard.completed.push(id);
+        guard.pending_llm_callbacks.push(id.to_string());
+    }
+
+    pub(crate) fn llm_request_pending(&self, callback_id: Uuid) -> bool {
+        let guard = self.state.lock().unwrap();
+        guard
+            .pending_llm_callbacks
+            .contains(&callback_id.to_string())
+    }
+
     pub(crate) fn completed_contains(&self, id: Uuid) -> bool {
         let guard = self.state.lock().unwrap();
         guard.completed.contains(&id)
@@ -305,9 +446,13 @@ impl QueuePort for RecordingApprovalQueue {
 
     async fn enqueue_llm_request(
         &self,
-        _data: &wrldbldr_domain::LlmRequestData,
+        data: &wrldbldr_domain::LlmRequestData,
     ) -> Result<Uuid, QueueError> {
-        Err(QueueError::Error("not implemented".to_string()))
+        let id = Uuid::new_v4();
+        let mut guard = self.state.lock().unwrap();
+        guard.llm_requests.insert(id, data.clone());
+        guard.pending_llm_callbacks.push(data.callback_id.clone());
+        Ok(id)
     }
 
     async fn dequeue_llm_request(&self) -> Result<Option<QueueItem>, QueueError> {
@@ -348,16 +493,87 @@ impl QueuePort for RecordingApprovalQueue {
         Ok(())
     }
 
+    async fn mark_failed_if_status(
+        &self,
+        id: Uuid,
+        expected: QueueItemStatus,
+        error: &str,
+    ) -> Result<bool, QueueError> {
+        let status = self.get_item(id).await?.map(|item| item.status);
+        if status != Some(expected) {
+            return Ok(false);
+        }
+        self.mark_failed(id, error).await?;
+        Ok(true)
+    }
+
+    async fn get_item(&self, id: Uuid) -> Result<Option<QueueItem>, QueueError> {
+        for queue_type in ["dm_approval", "player_action", "llm_request"] {
+            let items = self.list_by_type(queue_type, usize::MAX).await?;
+            if let Some(item) = items.into_iter().find(|item| item.id == id) {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+
     async fn get_pending_count(&self, _queue_type: &str) -> Result<usize, QueueError> {
         Ok(0)
     }
 
     async fn list_by_type(
         &self,
-        _queue_type: &str,
+        queue_type: &str,
         _limit: usize,
     ) -> Result<Vec<QueueItem>, QueueError> {
-        Ok(vec![])
+        let guard = self.state.lock().unwrap();
+        let (items, unfinished): (Vec<(Uuid, QueueItemData)>, QueueItemStatus) = match queue_type {
+            "dm_approval" => (
+                guard
+                    .approvals
+                    .iter()
+                    .map(|(id, data)| (*id, QueueItemData::DmApproval(data.clone())))
+                    .collect(),
+                QueueItemStatus::Processing,
+            ),
+            "player_action" => (
+                guard
+                    .player_actions
+                    .iter()
+                    .map(|(id, data)| (*id, QueueItemData::PlayerAction(data.clone())))
+                    .collect(),
+                QueueItemStatus::Pending,
+            ),
+            "llm_request" => (
+                guard
+                    .llm_requests
+                    .iter()
+                    .map(|(id, data)| (*id, QueueItemData::LlmRequest(data.clone())))
+                    .collect(),
+                QueueItemStatus::Pending,
+            ),
+            _ => return Ok(vec![]),
+        };
+        Ok(items
+            .into_iter()
+            .map(|(id, data)| {
+                let status = if guard.completed.contains(&id) {
+                    QueueItemStatus::Completed
+                } else if guard.failed.iter().any(|(got, _)| *got == id) {
+                    QueueItemStatus::Failed
+                } else {
+                    unfinished
+                };
+                QueueItem {
+                    id,
+                    data,
+                    created_at: Utc::now(),
+                    status,
+                    error_message: None,
+                    result_json: None,
+                }
+            })
+            .collect())
     }
 
     async fn set_result_json(&self, _id: Uuid, _result_json: &str) -> Result<(), QueueError> {
@@ -366,8 +582,15 @@ impl QueuePort for RecordingApprovalQueue {
 
     async fn cancel_pending_llm_request_by_callback_id(
         &self,
-        _callback_id: &str,
+        callback_id: &str,
     ) -> Result<bool, QueueError> {
+        let mut guard = self.state.lock().unwrap();
+        let before = guard.pending_llm_callbacks.len();
+        guard.pending_llm_callbacks.retain(|id| id != callback_id);
+        Ok(guard.pending_llm_callbacks.len() < before)
+    }
+
+    async fn delete_by_callback_id(&self, _callback_id: &str) -> Result<bool, QueueError> {
         Ok(false)
     }
 
@@ -430,472 +653,86 @@ pub(crate) fn build_test_app_with_ports(
     now: DateTime<Utc>,
     queue: Arc<dyn QueuePort>,
     llm: Arc<dyn LlmPort>,
+) -> Arc<App> {
+    build_test_app_with_plugins(
+        repos,
+        now,
+        queue,
+        llm,
+        Arc::new(WasmPluginHost::new(PluginLimits::default())),
+    )
+}
+
+pub(crate) fn build_test_app_with_plugins(
+    repos: TestAppRepos,
+    now: DateTime<Utc>,
+    queue: Arc<dyn QueuePort>,
+    llm: Arc<dyn LlmPort>,
+    plugins: Arc<dyn PluginPort>,
 ) -> Arc<App> {
     let clock: Arc<dyn ClockPort> = Arc::new(FixedClock { now });
     let random: Arc<dyn RandomPort> = Arc::new(FixedRandom);
     let image_gen: Arc<dyn ImageGenPort> = Arc::new(NoopImageGen);
 
-    // Repo mocks.
-    let world_repo = Arc::new(repos.world_repo);
-    let character_repo = Arc::new(repos.character_repo);
-    let player_character_repo = Arc::new(repos.player_character_repo);
-    let location_repo = Arc::new(repos.location_repo);
-    let scene_repo = Arc::new(repos.scene_repo);
-    let act_repo = Arc::new(repos.act_repo);
-    let skill_repo = Arc::new(repos.skill_repo);
-    let interaction_repo = Arc::new(repos.interaction_repo);
-    let settings_repo = Arc::new(repos.settings_repo);
-    let challenge_repo = Arc::new(repos.challenge_repo);
-    let narrative_repo = Arc::new(repos.narrative_repo);
-    let staging_repo = Arc::new(repos.staging_repo);
-    let observation_repo = Arc::new(repos.observation_repo);
-    let item_repo = Arc::new(repos.item_repo);
-    let asset_repo = Arc::new(repos.asset_repo);
-    let flag_repo = Arc::new(repos.flag_repo);
-    let goal_repo = Arc::new(repos.goal_repo);
-    let lore_repo = Arc::new(repos.lore_repo);
-    let location_state_repo = Arc::new(repos.location_state_repo);
-    let region_state_repo = Arc::new(repos.region_state_repo);
-
-    // Entities
-    let character = Arc::new(crate::entities::Character::new(character_repo.clone()));
-    let player_character = Arc::new(crate::entities::PlayerCharacter::new(
-        player_character_repo.clone(),
-    ));
-    let location = Arc::new(crate::entities::Location::new(location_repo.clone()));
-    let scene = Arc::new(crate::entities::Scene::new(scene_repo.clone()));
-    let act = Arc::new(crate::entities::Act::new(act_repo.clone()));
-    let skill = Arc::new(crate::entities::Skill::new(skill_repo.clone()));
-    let interaction = Arc::new(crate::entities::Interaction::new(interaction_repo.clone()));
-    let challenge = Arc::new(crate::entities::Challenge::new(challenge_repo.clone()));
-    let narrative = Arc::new(crate::entities::Narrative::new(
-        narrative_repo.clone(),
-        location_repo.clone(),
-        world_repo.clone(),
-        player_character_repo.clone(),
-        character_repo.clone(),
-        observation_repo.clone(),
-        challenge_repo.clone(),
-        flag_repo.clone(),
-        scene_repo.clone(),
-        clock.clone(),
-    ));
-    let staging = Arc::new(crate::entities::Staging::new(staging_repo.clone()));
-    let observation = Arc::new(crate::entities::Observation::new(
-        observation_repo.clone(),
-        location_repo.clone(),
-        clock.clone(),
-    ));
-    let inventory = Arc::new(crate::entities::Inventory::new(
-        item_repo.clone(),
-        character_repo.clone(),
-        player_character_repo.clone(),
-    ));
-    let assets = Arc::new(crate::entities::Assets::new(asset_repo.clone(), image_gen));
-    let world = Arc::new(crate::entities::World::new(world_repo, clock.clone()));
-    let flag = Arc::new(crate::entities::Flag::new(flag_repo.clone()));
-    let goal = Arc::new(crate::entities::Goal::new(goal_repo.clone()));
-    let lore = Arc::new(crate::entities::Lore::new(lore_repo.clone()));
-    let location_state = Arc::new(crate::entities::LocationStateEntity::new(
-        location_state_repo.clone(),
-    ));
-    let region_state = Arc::new(crate::entities::RegionStateEntity::new(region_state_repo));
-
-    let entities = Entities {
-        character: character.clone(),
-        player_character: player_character.clone(),
-        location: location.clone(),
-        scene: scene.clone(),
-        act: act.clone(),
-        skill: skill.clone(),
-        interaction: interaction.clone(),
-        challenge: challenge.clone(),
-        narrative: narrative.clone(),
-        staging: staging.clone(),
-        observation: observation.clone(),
-        inventory: inventory.clone(),
-        assets: assets.clone(),
-        world: world.clone(),
-        flag: flag.clone(),
-        goal: goal.clone(),
-        lore: lore.clone(),
-        location_state: location_state.clone(),
-        region_state: region_state.clone(),
-    };
-
-    // Use cases (not exercised by these tests, but required by App).
-    let suggest_time = Arc::new(crate::use_cases::time::SuggestTime::new(
-        world.clone(),
-        clock.clone(),
-    ));
-
-    let movement = crate::use_cases::MovementUseCases::new(
-        Arc::new(crate::use_cases::movement::EnterRegion::new(
-            player_character.clone(),
-            location.clone(),
-            staging.clone(),
-            observation.clone(),
-            narrative.clone(),
-            scene.clone(),
-            inventory.clone(),
-            flag.clone(),
-            world.clone(),
-            suggest_time.clone(),
-        )),
-        Arc::new(crate::use_cases::movement::ExitLocation::new(
-            player_character.clone(),
-            location.clone(),
-            staging.clone(),
-            observation.clone(),
-            narrative.clone(),
-            scene.clone(),
-            inventory.clone(),
-            flag.clone(),
-            world.clone(),
-            suggest_time.clone(),
-        )),
-    );
-
-    let scene_change = crate::use_cases::SceneChangeBuilder::new(
-        location.clone(),
-        inventory.clone(),
-    );
-
-    let conversation_start = Arc::new(crate::use_cases::conversation::StartConversation::new(
-        character.clone(),
-        player_character.clone(),
-        staging.clone(),
-        scene.clone(),
-        world.clone(),
-        queue.clone(),
-        clock.clone(),
-    ));
-    let conversation_continue = Arc::new(crate::use_cases::conversation::ContinueConversation::new(
-        character.clone(),
-        player_character.clone(),
-        staging.clone(),
-        world.clone(),
-        narrative.clone(),
-        queue.clone(),
-        clock.clone(),
-    ));
-    let conversation_end = Arc::new(crate::use_cases::conversation::EndConversation::new(
-        character.clone(),
-        player_character.clone(),
-        narrative.clone(),
-    ));
-    let conversation = crate::use_cases::ConversationUseCases::new(
-        conversation_start.clone(),
-        conversation_continue,
-        conversation_end,
-    );
-
-    let player_action = crate::use_cases::PlayerActionUseCases::new(Arc::new(
-        crate::use_cases::player_action::HandlePlayerAction::new(
-            conversation_start,
-            queue.clone(),
-            clock.clone(),
-        ),
-    ));
-
-    let actantial = crate::use_cases::ActantialUseCases::new(
-        crate::use_cases::actantial::GoalOps::new(goal.clone()),
-        crate::use_cases::actantial::WantOps::new(character.clone(), clock.clone()),
-        crate::use_cases::actantial::ActantialContextOps::new(character.clone()),
-    );
-
-    let ai = crate::use_cases::AiUseCases::new(Arc::new(
-        crate::use_cases::ai::SuggestionOps::new(queue.clone(), world.clone(), character.clone()),
-    ));
-
-    let resolve_outcome = Arc::new(crate::use_cases::challenge::ResolveOutcome::new(
-        challenge.clone(),
-        inventory.clone(),
-        observation.clone(),
-        scene.clone(),
-        player_character.clone(),
-    ));
-    let outcome_decision = Arc::new(crate::use_cases::challenge::OutcomeDecision::new(
-        queue.clone(),
-        resolve_outcome.clone(),
-    ));
-
-    let challenge_uc = crate::use_cases::ChallengeUseCases::new(
-        Arc::new(crate::use_cases::challenge::RollChallenge::new(
-            challenge.clone(),
-            player_character.clone(),
-            queue.clone(),
-            random,
-            clock.clone(),
-        )),
-        resolve_outcome,
-        Arc::new(crate::use_cases::challenge::TriggerChallengePrompt::new(
-            challenge.clone(),
-        )),
-        outcome_decision,
-        Arc::new(crate::use_cases::challenge::ChallengeOps::new(
-            challenge.clone(),
-        )),
-    );
-
-    let approve_suggestion =
-        Arc::new(crate::use_cases::approval::ApproveSuggestion::new(queue.clone()));
-    let approval = crate::use_cases::ApprovalUseCases::new(
-        Arc::new(crate::use_cases::approval::ApproveStaging::new(
-            staging.clone(),
-        )),
-        approve_suggestion.clone(),
-        Arc::new(crate::use_cases::approval::ApprovalDecisionFlow::new(
-            approve_suggestion.clone(),
-            narrative.clone(),
-            queue.clone(),
-        )),
-    );
-
-    let assets_uc = crate::use_cases::AssetUseCases::new(
-        Arc::new(crate::use_cases::assets::GenerateAsset::new(
-            assets.clone(),
-            queue.clone(),
-            clock.clone(),
-        )),
-        Arc::new(crate::use_cases::assets::GenerateExpressionSheet::new(
-            assets.clone(),
-            character.clone(),
-            queue.clone(),
-            clock.clone(),
-        )),
-    );
-
-    let world_uc = crate::use_cases::WorldUseCases::new(
-        Arc::new(crate::use_cases::world::ExportWorld::new(
-            world.clone(),
-            location.clone(),
-            character.clone(),
-            inventory.clone(),
-            narrative.clone(),
-        )),
-        Arc::new(crate::use_cases::world::ImportWorld::new(
-            world.clone(),
-            location.clone(),
-            character.clone(),
-            inventory.clone(),
-            narrative.clone(),
-        )),
-    );
-
-    let queues = crate::use_cases::QueueUseCases::new(
-        Arc::new(crate::use_cases::queues::ProcessPlayerAction::new(
-            queue.clone(),
-            character.clone(),
-            player_character.clone(),
-            staging.clone(),
-            scene.clone(),
-            world.clone(),
-            narrative.clone(),
-        )),
-        Arc::new(crate::use_cases::queues::ProcessLlmRequest::new(
-            queue.clone(),
-            llm.clone(),
-        )),
-    );
-
-    let execute_effects = Arc::new(crate::use_cases::narrative::ExecuteEffects::new(
-        inventory.clone(),
-        challenge.clone(),
-        narrative.clone(),
-        character.clone(),
-        observation.clone(),
-        player_character.clone(),
-        scene.clone(),
-        flag.clone(),
-        world.clone(),
-        clock.clone(),
-    ));
-    let narrative_events = Arc::new(crate::use_cases::narrative::NarrativeEventOps::new(
-        narrative.clone(),
-        execute_effects.clone(),
-    ));
-    let narrative_chains =
-        Arc::new(crate::use_cases::narrative::EventChainOps::new(narrative.clone()));
-    let narrative_decision = Arc::new(crate::use_cases::narrative::NarrativeDecisionFlow::new(
-        approve_suggestion.clone(),
-        queue.clone(),
-        narrative.clone(),
-        execute_effects.clone(),
-    ));
-    let narrative_uc = crate::use_cases::NarrativeUseCases::new(
-        execute_effects,
-        narrative_events,
-        narrative_chains,
-        narrative_decision,
-    );
-
-    let time_control = Arc::new(crate::use_cases::time::TimeControl::new(world.clone()));
-    let time_suggestions =
-        Arc::new(crate::use_cases::time::TimeSuggestions::new(time_control.clone()));
-    let time_uc =
-        crate::use_cases::TimeUseCases::new(suggest_time, time_control, time_suggestions);
-
-    let visual_state_uc = crate::use_cases::VisualStateUseCases::new(Arc::new(
-        crate::use_cases::visual_state::ResolveVisualState::new(
-            location_state.clone(),
-            region_state.clone(),
-            flag.clone(),
-        ),
-    ));
-
-    let staging_uc = crate::use_cases::StagingUseCases::new(
-        Arc::new(crate::use_cases::staging::RequestStagingApproval::new(
-            character.clone(),
-            staging.clone(),
-            location.clone(),
-            world.clone(),
-            flag.clone(),
-            visual_state_uc.resolve.clone(),
-            settings_repo.clone(),
-            llm.clone(),
-        )),
-        Arc::new(
-            crate::use_cases::staging::RegenerateStagingSuggestions::new(
-                location.clone(),
-                character.clone(),
-                llm.clone(),
-            ),
-        ),
-        Arc::new(crate::use_cases::staging::ApproveStagingRequest::new(
-            staging.clone(),
-            world.clone(),
-            character.clone(),
-            location.clone(),
-            location_state.clone(),
-            region_state.clone(),
-        )),
-        Arc::new(crate::use_cases::staging::AutoApproveStagingTimeout::new(
-            character.clone(),
-            staging.clone(),
-            world.clone(),
-            location.clone(),
-            location_state.clone(),
-            region_state.clone(),
-            settings_repo.clone(),
-        )),
-    );
-
-    // Create settings entity
-    let settings_entity = Arc::new(crate::entities::Settings::new(settings_repo.clone()));
-
-    let npc_uc = crate::use_cases::NpcUseCases::new(
-        Arc::new(crate::use_cases::npc::NpcDisposition::new(
-            character.clone(),
-            clock.clone(),
-        )),
-        Arc::new(crate::use_cases::npc::NpcMood::new(
-            staging.clone(),
-            character.clone(),
-        )),
-        Arc::new(crate::use_cases::npc::NpcRegionRelationships::new(
-            character.clone(),
-        )),
-        Arc::new(crate::use_cases::npc::NpcLocationSharing::new(
-            character.clone(),
-            location.clone(),
-            observation.clone(),
-            clock.clone(),
-        )),
-        Arc::new(crate::use_cases::npc::NpcApproachEvents::new(
-            character.clone(),
-        )),
-    );
-
-    let story_events_uc = crate::use_cases::StoryEventUseCases::new(Arc::new(
-        crate::use_cases::story_events::StoryEventOps::new(narrative.clone()),
-    ));
-
-    let lore_uc = crate::use_cases::LoreUseCases::new(Arc::new(
-        crate::use_cases::lore::LoreOps::new(lore.clone()),
-    ));
-
-    let location_events_uc = crate::use_cases::LocationEventUseCases::new(Arc::new(
-        crate::use_cases::location_events::TriggerLocationEvent::new(location.clone()),
-    ));
-
-    let management = crate::use_cases::ManagementUseCases::new(
-        crate::use_cases::management::WorldCrud::new(world.clone(), clock.clone()),
-        crate::use_cases::management::CharacterCrud::new(character.clone(), clock.clone()),
-        crate::use_cases::management::LocationCrud::new(location.clone()),
-        crate::use_cases::management::PlayerCharacterCrud::new(
-            player_character.clone(),
-            location.clone(),
-            clock.clone(),
-        ),
-        crate::use_cases::management::RelationshipCrud::new(character.clone(), clock.clone()),
-        crate::use_cases::management::ObservationCrud::new(
-            observation.clone(),
-            player_character.clone(),
-            character.clone(),
-            location.clone(),
-            world.clone(),
-            clock.clone(),
-        ),
-        crate::use_cases::management::ActCrud::new(act.clone()),
-        crate::use_cases::management::SceneCrud::new(scene.clone()),
-        crate::use_cases::management::InteractionCrud::new(interaction.clone()),
-        crate::use_cases::management::SkillCrud::new(skill.clone()),
-    );
-
-    let settings = settings_entity;
-
-    let join_world = Arc::new(crate::use_cases::session::JoinWorld::new(
-        world.clone(),
-        location.clone(),
-        character.clone(),
-        scene.clone(),
-        player_character.clone(),
-    ));
-    let join_world_flow =
-        Arc::new(crate::use_cases::session::JoinWorldFlow::new(join_world.clone()));
-    let directorial_update = Arc::new(crate::use_cases::session::DirectorialUpdate::new());
-    let session = crate::use_cases::SessionUseCases::new(
-        join_world,
-        join_world_flow,
-        directorial_update,
-    );
-
-    // Create custom condition evaluator
-    let custom_condition = Arc::new(crate::use_cases::CustomConditionEvaluator::new(llm.clone()));
-
-    let use_cases = UseCases {
-        movement,
-        conversation,
-        challenge: challenge_uc,
-        approval,
-        actantial,
-        ai,
-        assets: assets_uc,
-        scene_change,
-        world: world_uc,
-        queues,
-        narrative: narrative_uc,
-        player_action,
-        time: time_uc,
-        visual_state: visual_state_uc,
-        management,
-        session,
-        settings,
-        staging: staging_uc,
-        npc: npc_uc,
-        story_events: story_events_uc,
-        lore: lore_uc,
-        location_events: location_events_uc,
-        custom_condition,
-    };
-
-    Arc::new(App {
-        entities,
-        use_cases,
+    Arc::new(App::from_ports(AppPorts {
+        repos: Repositories {
+            character: Arc::new(repos.character_repo),
+            player_character: Arc::new(repos.player_character_repo),
+            location: Arc::new(repos.location_repo),
+            scene: Arc::new(repos.scene_repo),
+            act: Arc::new(repos.act_repo),
+            skill: Arc::new(repos.skill_repo),
+            interaction: Arc::new(repos.interaction_repo),
+            challenge: Arc::new(repos.challenge_repo),
+            narrative: Arc::new(repos.narrative_repo),
+            staging: Arc::new(repos.staging_repo),
+            observation: Arc::new(repos.observation_repo),
+            item: Arc::new(repos.item_repo),
+            world: Arc::new(repos.world_repo),
+            asset: Arc::new(repos.asset_repo),
+            flag: Arc::new(repos.flag_repo),
+            goal: Arc::new(repos.goal_repo),
+            lore: Arc::new(repos.lore_repo),
+            progress_clock: Arc::new(repos.progress_clock_repo),
+            tag: Arc::new(repos.tag_repo),
+            custom_field: Arc::new(repos.custom_field_repo),
+            template: Arc::new(repos.template_repo),
+            library: Arc::new(repos.library_repo),
+            content_draft: Arc::new(repos.content_draft_repo),
+            crowd: Arc::new(repos.crowd_repo),
+            npc_draft: Arc::new(repos.npc_draft_repo),
+            mention: Arc::new(repos.mention_repo),
+            name_generator: Arc::new(repos.name_generator_repo),
+            economy: Arc::new(repos.economy_repo),
+            property: Arc::new(repos.property_repo),
+            companion: Arc::new(repos.companion_repo),
+            injury: Arc::new(repos.injury_repo),
+            pc_secret: Arc::new(repos.pc_secret_repo),
+            letter: Arc::new(repos.letter_repo),
+            broadsheet: Arc::new(repos.broadsheet_repo),
+            faction: Arc::new(repos.faction_repo),
+            dialogue_tree: Arc::new(repos.dialogue_tree_repo),
+            location_state: Arc::new(repos.location_state_repo),
+            region_state: Arc::new(repos.region_state_repo),
+        },
+        settings_repo: Arc::new(repos.settings_repo),
+        usage_repo: Arc::new(repos.usage_repo),
         queue,
         llm,
-    })
+        llm_models: Arc::new(repos.llm_models),
+        image_gen,
+        transcription: Arc::new(repos.transcription),
+        blob_store: Arc::new(repos.blob_store),
+        service_probe: Arc::new(repos.service_probe),
+        plugins,
+        scripts: Arc::new(RhaiScriptEngine::new()),
+        clock,
+        random,
+        running_connections: wrldbldr_domain::ServiceConnections::default(),
+        env_connections: wrldbldr_domain::ServiceConnections::default(),
+        circuit_breakers: ServiceCircuitBreakers::default(),
+        diagnostics: Arc::new(crate::infrastructure::diagnostics::Diagnostics::default()),
+    }))
 }
 
 pub(crate) fn build_test_app(repos: TestAppRepos, now: DateTime<Utc>) -> Arc<App> {
@@ -2,8 +2,8 @@ use super::*;
 
 use crate::api::connections::ConnectionInfo;
 use wrldbldr_domain::{
-    ActantialActor, ActantialContext, ActantialRole, ActantialTarget, CharacterId, GoalId, WantId,
-    WantTarget, WantVisibility,
+    ActantialActor, ActantialContext, ActantialRole, ActantialTarget, CharacterId, EntityType,
+    GoalId, WantId, WantTarget, WantVisibility,
 };
 use wrldbldr_protocol::{
     messages::{
@@ -40,7 +40,7 @@ pub(super) async fn handle_goal_request(
     request: GoalRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        GoalRequest::ListGoals { world_id } => {
+        GoalRequest::ListGoals { world_id, tags } => {
             if let Err(e) = require_dm_for_request(conn_info, request_id) {
                 return Err(e);
             }
@@ -51,7 +51,17 @@ pub(super) async fn handle_goal_request(
             };
 
             match state.app.use_cases.actantial.goals.list(world_id_typed).await {
-                Ok(goals) => {
+                Ok(mut goals) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        EntityType::Goal,
+                        &tags,
+                        &mut goals,
+                        |details| details.goal.id.to_uuid(),
+                    )
+                    .await?;
                     let data: Vec<GoalResponse> = goals
                         .into_iter()
                         .map(|details| GoalResponse {
@@ -232,7 +242,7 @@ pub(super) async fn handle_want_request(
     request: WantRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        WantRequest::ListWants { character_id } => {
+        WantRequest::ListWants { character_id, tags } => {
             if let Err(e) = require_dm_for_request(conn_info, request_id) {
                 return Err(e);
             }
@@ -252,11 +262,19 @@ pub(super) async fn handle_want_request(
                 .await
             {
                 Ok(Some(context)) => {
-                    let data: Vec<WantResponse> = context
-                        .wants
-                        .iter()
-                        .map(want_context_to_response)
-                        .collect();
+                    let mut wants = context.wants;
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        EntityType::Want,
+                        &tags,
+                        &mut wants,
+                        |want| want.want_id,
+                    )
+                    .await?;
+                    let data: Vec<WantResponse> =
+                        wants.iter().map(want_context_to_response).collect();
                     Ok(ResponseResult::success(data))
                 }
                 Ok(None) => Ok(ResponseResult::error(
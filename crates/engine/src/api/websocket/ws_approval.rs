@@ -1,5 +1,7 @@
 use super::*;
 
+use crate::use_cases::approval::ApprovalSelection;
+
 pub(super) async fn handle_approval_decision(
     state: &WsState,
     connection_id: Uuid,
@@ -53,49 +55,215 @@ pub(super) async fn handle_approval_decision(
         }
     };
 
-    match state
+    match apply_approval_decision(state, approval_id, domain_decision).await {
+        Ok(()) => None,
+        Err(crate::use_cases::approval::ApprovalDecisionError::ApprovalNotFound) => {
+            Some(error_response("NOT_FOUND", "Approval request not found"))
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Approval decision failed");
+            Some(error_response("APPROVAL_ERROR", &e.to_string()))
+        }
+    }
+}
+
+/// Apply a decision on an approval and tell the table about the outcome.
+///
+/// Used for DM decisions and for those the co-DM makes on a DM's behalf.
+pub async fn apply_approval_decision(
+    state: &WsState,
+    approval_id: Uuid,
+    decision: wrldbldr_domain::DmApprovalDecision,
+) -> Result<(), crate::use_cases::approval::ApprovalDecisionError> {
+    let result = state
         .app
         .use_cases
         .approval
         .decision_flow
-        .execute(approval_id, domain_decision)
-        .await
-    {
-        Ok(result) => {
-            if result.approved {
-                let dialogue = result.final_dialogue.clone().unwrap_or_default();
-                let world_id = result.world_id;
-
-                // Send ResponseApproved to DMs (shows what tools were executed)
-                let dm_msg = ServerMessage::ResponseApproved {
-                    npc_dialogue: dialogue.clone(),
-                    executed_tools: result.approved_tools.clone(),
-                };
-                state.connections.broadcast_to_dms(world_id, dm_msg).await;
-
-                // Send DialogueResponse to all players (for visual novel display)
-                if !dialogue.is_empty() {
-                    let dialogue_msg = ServerMessage::DialogueResponse {
-                        speaker_id: result.npc_id.unwrap_or_default(),
-                        speaker_name: result.npc_name.unwrap_or_else(|| "Unknown".to_string()),
-                        text: dialogue,
-                        choices: vec![], // Free-form input mode
-                        conversation_id: result.conversation_id.map(|id| id.to_string()),
-                    };
-                    state
-                        .connections
-                        .broadcast_to_world(world_id, dialogue_msg)
-                        .await;
+        .execute(approval_id, decision)
+        .await?;
+    if !result.approved {
+        // Let the scene know the NPC is having another go
+        if let Some(action_id) = result.retry_action_id {
+            state
+                .connections
+                .broadcast_to_scene(
+                    result.world_id,
+                    result.pc_id,
+                    ServerMessage::LLMProcessing { action_id },
+                )
+                .await;
+        }
+        return Ok(());
+    }
+
+    let dialogue = result.final_dialogue.clone().unwrap_or_default();
+    let world_id = result.world_id;
+
+    // Send ResponseApproved to DMs (shows what tools were executed)
+    let dm_msg = ServerMessage::ResponseApproved {
+        npc_dialogue: dialogue.clone(),
+        executed_tools: result.approved_tools.clone(),
+    };
+    state.connections.broadcast_to_dms(world_id, dm_msg).await;
+
+    for &event_id in &result.dialogue_event_ids {
+        ws_mentions::spawn_index_mentions(
+            state,
+            world_id,
+            wrldbldr_domain::EntityType::StoryEvent,
+            event_id.to_uuid(),
+        );
+    }
+
+    // Tell DMs about approved plugin tools that didn't apply
+    for failed in result.tool_results.iter().filter(|r| !r.success) {
+        let message = match &failed.error {
+            Some(error) => format!("{}: {}", failed.description, error),
+            None => failed.description.clone(),
+        };
+        let error_msg = ServerMessage::Error {
+            code: "PLUGIN_TOOL_FAILED".to_string(),
+            message,
+        };
+        state
+            .connections
+            .broadcast_to_dms(world_id, error_msg)
+            .await;
+    }
+
+    // Send DialogueResponse to the speaker's scene (for visual novel display)
+    if !dialogue.is_empty() {
+        let dialogue_msg = ServerMessage::DialogueResponse {
+            speaker_id: result.npc_id.unwrap_or_default(),
+            speaker_name: result.npc_name.unwrap_or_else(|| "Unknown".to_string()),
+            text: dialogue,
+            choices: vec![], // Free-form input mode
+            conversation_id: result.conversation_id.map(|id| id.to_string()),
+        };
+        state
+            .connections
+            .broadcast_to_scene(world_id, result.pc_id, dialogue_msg)
+            .await;
+    }
+    Ok(())
+}
+
+pub(super) async fn handle_bulk_approval_decision(
+    state: &WsState,
+    connection_id: Uuid,
+    selection: wrldbldr_protocol::ApprovalSelection,
+    decision: wrldbldr_protocol::BulkApprovalDecision,
+) -> Option<ServerMessage> {
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+
+    if let Err(e) = require_dm(&conn_info) {
+        return Some(e);
+    }
+
+    let Some(world_id) = conn_info.world_id else {
+        return Some(error_response("NOT_IN_WORLD", "Must join a world first"));
+    };
+
+    let selection = match selection {
+        wrldbldr_protocol::ApprovalSelection::Requests { request_ids } => {
+            let mut ids = Vec::with_capacity(request_ids.len());
+            for request_id in &request_ids {
+                match parse_id(request_id, |u| u, "Invalid request ID") {
+                    Ok(id) => ids.push(id),
+                    Err(e) => return Some(e),
                 }
             }
-            None
+            ApprovalSelection::Requests(ids)
         }
-        Err(crate::use_cases::approval::ApprovalDecisionError::ApprovalNotFound) => {
-            Some(error_response("NOT_FOUND", "Approval request not found"))
+        wrldbldr_protocol::ApprovalSelection::Conversation { conversation_id } => {
+            match parse_id(&conversation_id, |u| u, "Invalid conversation ID") {
+                Ok(id) => ApprovalSelection::Conversation(id),
+                Err(e) => return Some(e),
+            }
+        }
+        wrldbldr_protocol::ApprovalSelection::SourceAction { action_id } => {
+            match parse_id(&action_id, |u| u, "Invalid action ID") {
+                Ok(id) => ApprovalSelection::SourceAction(id),
+                Err(e) => return Some(e),
+            }
+        }
+        wrldbldr_protocol::ApprovalSelection::Ambient => ApprovalSelection::Ambient,
+        wrldbldr_protocol::ApprovalSelection::Unknown => {
+            return Some(error_response(
+                "INVALID_SELECTION",
+                "Unknown approval selection type",
+            ));
+        }
+    };
+
+    let (domain_decision, accepted) = match decision {
+        wrldbldr_protocol::BulkApprovalDecision::Accept => {
+            (wrldbldr_domain::DmApprovalDecision::Accept, true)
+        }
+        wrldbldr_protocol::BulkApprovalDecision::Reject { feedback } => {
+            (wrldbldr_domain::DmApprovalDecision::Reject { feedback }, false)
+        }
+        wrldbldr_protocol::BulkApprovalDecision::Unknown => {
+            return Some(error_response(
+                "INVALID_DECISION",
+                "Unknown approval decision type",
+            ));
         }
+    };
+
+    let approval_ids = match state
+        .app
+        .use_cases
+        .approval
+        .select
+        .execute(world_id, &selection)
+        .await
+    {
+        Ok(ids) => ids,
         Err(e) => {
-            tracing::error!(error = %e, "Approval decision failed");
-            Some(error_response("APPROVAL_ERROR", &e.to_string()))
+            tracing::error!(error = %e, "Failed to select approvals for bulk decision");
+            return Some(error_response("APPROVAL_ERROR", &e.to_string()));
+        }
+    };
+
+    let mut decided = Vec::new();
+    let mut failed = Vec::new();
+    for approval_id in approval_ids {
+        match apply_approval_decision(state, approval_id, domain_decision.clone()).await {
+            Ok(()) => decided.push(approval_id.to_string()),
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    approval_id = %approval_id,
+                    "Bulk approval decision failed for one approval"
+                );
+                failed.push(approval_id.to_string());
+            }
         }
     }
+
+    tracing::info!(
+        world_id = %world_id,
+        decided = decided.len(),
+        failed = failed.len(),
+        accepted,
+        "Applied bulk approval decision"
+    );
+
+    state
+        .connections
+        .broadcast_to_dms(
+            world_id,
+            ServerMessage::BulkApprovalApplied {
+                decided,
+                failed,
+                accepted,
+            },
+        )
+        .await;
+    None
 }
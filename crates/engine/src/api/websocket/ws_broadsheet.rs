@@ -0,0 +1,152 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::broadsheet::{to_data, BroadsheetError};
+
+use wrldbldr_domain::BroadsheetId;
+use wrldbldr_protocol::BroadsheetRequest;
+
+pub(super) async fn handle_broadsheet_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: BroadsheetRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before reading the broadsheet",
+        ));
+    };
+    let broadsheets = &state.app.use_cases.broadsheet.manage;
+
+    // Everyone reads published issues; drafts and editing are the DM's
+    if !matches!(request, BroadsheetRequest::ListBroadsheets) {
+        require_dm_for_request(conn_info, request_id)?;
+    }
+
+    let result = match request {
+        BroadsheetRequest::ListBroadsheets => broadsheets
+            .list(world_id, conn_info.is_dm())
+            .await
+            .map(ResponseResult::success),
+
+        BroadsheetRequest::GenerateBroadsheet => match broadsheets.generate(world_id).await {
+            Ok(issue) => {
+                let msg = ServerMessage::BroadsheetDrafted {
+                    broadsheet: issue.clone(),
+                };
+                state.connections.broadcast_to_dms(world_id, msg).await;
+                Ok(ResponseResult::success(issue))
+            }
+            Err(e) => Err(e),
+        },
+
+        BroadsheetRequest::UpdateBroadsheet {
+            broadsheet_id,
+            data,
+        } => {
+            let broadsheet_id = parse_broadsheet_id(&broadsheet_id, request_id)?;
+            match broadsheets.update(world_id, broadsheet_id, data).await {
+                Ok(issue) => {
+                    let msg = ServerMessage::BroadsheetDrafted {
+                        broadsheet: issue.clone(),
+                    };
+                    state.connections.broadcast_to_dms(world_id, msg).await;
+                    Ok(ResponseResult::success(issue))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        BroadsheetRequest::PublishBroadsheet { broadsheet_id } => {
+            let broadsheet_id = parse_broadsheet_id(&broadsheet_id, request_id)?;
+            match broadsheets.publish(world_id, broadsheet_id).await {
+                Ok(issue) => {
+                    tracing::info!(
+                        world_id = %world_id,
+                        issue = issue.issue_number,
+                        "DM published broadsheet"
+                    );
+                    let msg = ServerMessage::BroadsheetPublished {
+                        broadsheet: issue.clone(),
+                    };
+                    state.connections.broadcast_to_world(world_id, msg).await;
+                    Ok(ResponseResult::success(issue))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        BroadsheetRequest::DeleteBroadsheet { broadsheet_id } => {
+            let broadsheet_id = parse_broadsheet_id(&broadsheet_id, request_id)?;
+            match broadsheets.delete(world_id, broadsheet_id).await {
+                Ok(issue) => {
+                    let msg = ServerMessage::BroadsheetRemoved {
+                        broadsheet_id: broadsheet_id.to_string(),
+                    };
+                    if issue.is_published() {
+                        state.connections.broadcast_to_world(world_id, msg).await;
+                    } else {
+                        state.connections.broadcast_to_dms(world_id, msg).await;
+                    }
+                    Ok(ResponseResult::success(to_data(&issue)))
+                }
+                Err(e) => Err(e),
+            }
+        }
+    };
+
+    Ok(result.unwrap_or_else(broadsheet_error_response))
+}
+
+/// Draft the broadsheet for a game week that ended as time advanced, and
+/// hand it to the DMs to edit
+pub(super) async fn draft_after_time_advance(
+    state: &WsState,
+    world_id: WorldId,
+    minutes_advanced: u32,
+) {
+    match state
+        .app
+        .use_cases
+        .broadsheet
+        .manage
+        .draft_after_time_advance(world_id, minutes_advanced)
+        .await
+    {
+        Ok(Some(issue)) => {
+            let msg = ServerMessage::BroadsheetDrafted { broadsheet: issue };
+            state.connections.broadcast_to_dms(world_id, msg).await;
+        }
+        Ok(None) => {}
+        Err(e) => {
+            tracing::warn!(world_id = %world_id, error = %e, "Failed to draft broadsheet");
+        }
+    }
+}
+
+fn parse_broadsheet_id(id: &str, request_id: &str) -> Result<BroadsheetId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        BroadsheetId::from_uuid,
+        "Invalid broadsheet ID",
+    )
+}
+
+fn broadsheet_error_response(e: BroadsheetError) -> ResponseResult {
+    match e {
+        BroadsheetError::NotFound | BroadsheetError::WorldNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        BroadsheetError::Conflict(_) => ResponseResult::error(ErrorCode::Conflict, e.to_string()),
+        BroadsheetError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        BroadsheetError::Generation(_) => {
+            ResponseResult::error(ErrorCode::ServiceUnavailable, e.to_string())
+        }
+        BroadsheetError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -1,7 +1,7 @@
 use super::*;
 use crate::api::connections::ConnectionInfo;
 use serde_json::json;
-use wrldbldr_domain::{DiceRollInput, OutcomeType};
+use wrldbldr_domain::{DiceRollInput, EntityType, OutcomeType, ScriptHook, TutorialAction};
 use wrldbldr_protocol::{ChallengeRequest, ErrorCode, ResponseResult};
 use wrldbldr_protocol::types::ProposedToolInfo;
 
@@ -12,10 +12,22 @@ pub(super) async fn handle_challenge_request(
     request: ChallengeRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        ChallengeRequest::ListChallenges { world_id } => {
+        ChallengeRequest::ListChallenges { world_id, tags } => {
             let world_id_typed = parse_world_id_for_request(&world_id, request_id)?;
             match state.app.use_cases.challenge.ops.list(world_id_typed).await {
-                Ok(challenges) => Ok(ResponseResult::success(json!(challenges))),
+                Ok(mut challenges) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        EntityType::Challenge,
+                        &tags,
+                        &mut challenges,
+                        ws_tags::json_entry_id,
+                    )
+                    .await?;
+                    Ok(ResponseResult::success(json!(challenges)))
+                }
                 Err(e) => Ok(ResponseResult::error(
                     ErrorCode::InternalError,
                     e.to_string(),
@@ -93,6 +105,29 @@ pub(super) async fn handle_challenge_request(
                 )),
             }
         }
+        ChallengeRequest::DuplicateChallenge { challenge_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let challenge_id_typed = parse_challenge_id_for_request(&challenge_id, request_id)?;
+            match state
+                .app
+                .use_cases
+                .duplicate
+                .entity
+                .challenge(challenge_id_typed)
+                .await
+            {
+                Ok(challenge) => Ok(ResponseResult::success(
+                    crate::use_cases::challenge::challenge_to_json(&challenge),
+                )),
+                Err(crate::use_cases::duplicate::DuplicateError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "Challenge not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
         ChallengeRequest::SetChallengeActive {
             challenge_id,
             active,
@@ -141,6 +176,39 @@ pub(super) async fn handle_challenge_request(
                 )),
             }
         }
+        ChallengeRequest::GetHistory {
+            world_id,
+            pc_id,
+            limit,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let world_id_typed = parse_world_id_for_request(&world_id, request_id)?;
+            let pc_id_typed = match pc_id {
+                Some(id) => Some(parse_id_for_request(
+                    &id,
+                    request_id,
+                    PlayerCharacterId::from_uuid,
+                    "Invalid PC ID",
+                )?),
+                None => None,
+            };
+            match state
+                .app
+                .use_cases
+                .challenge
+                .history
+                .list(world_id_typed, pc_id_typed, limit.map(|l| l as usize))
+                .await
+            {
+                Ok((resolutions, stats)) => Ok(ResponseResult::success(json!(
+                    challenge_history_data(resolutions, stats)
+                ))),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
     }
 }
 
@@ -234,6 +302,14 @@ pub(super) async fn handle_challenge_roll(
         .await
     {
         Ok(result) => {
+            ws_tutorial::record_tutorial_action(
+                state,
+                world_id,
+                pc_id,
+                TutorialAction::RolledChallenge(challenge_uuid),
+            )
+            .await;
+
             if result.requires_approval {
                 let approval_id = match result.approval_queue_id {
                     Some(id) => id.to_string(),
@@ -283,6 +359,14 @@ pub(super) async fn handle_challenge_roll(
                 })
             } else {
                 // Auto-resolve and broadcast to world
+                let script_data = json!({
+                    "challengeId": challenge_id,
+                    "challengeName": result.challenge_name,
+                    "characterName": result.character_name,
+                    "outcome": outcome_type_to_str(result.outcome_type),
+                    "roll": result.roll,
+                    "total": result.total,
+                });
                 let msg = ServerMessage::ChallengeResolved {
                     challenge_id: challenge_id.clone(),
                     challenge_name: result.challenge_name.clone(),
@@ -295,7 +379,18 @@ pub(super) async fn handle_challenge_roll(
                     roll_breakdown: result.roll_breakdown,
                     individual_rolls: None,
                 };
-                state.connections.broadcast_to_world(world_id, msg).await;
+                state
+                    .connections
+                    .broadcast_to_scene(world_id, Some(result.character_id), msg)
+                    .await;
+                ws_scripts::fire_script_hook(
+                    state,
+                    world_id,
+                    ScriptHook::ChallengeResolved,
+                    Some(result.character_id),
+                    script_data,
+                )
+                .await;
                 None
             }
         }
@@ -357,6 +452,14 @@ pub(super) async fn handle_challenge_roll_input(
         .await
     {
         Ok(result) => {
+            ws_tutorial::record_tutorial_action(
+                state,
+                world_id,
+                pc_id,
+                TutorialAction::RolledChallenge(challenge_uuid),
+            )
+            .await;
+
             if result.requires_approval {
                 let approval_id = match result.approval_queue_id {
                     Some(id) => id.to_string(),
@@ -405,6 +508,14 @@ pub(super) async fn handle_challenge_roll_input(
                     status: "pending".to_string(),
                 })
             } else {
+                let script_data = json!({
+                    "challengeId": challenge_id,
+                    "challengeName": result.challenge_name,
+                    "characterName": result.character_name,
+                    "outcome": outcome_type_to_str(result.outcome_type),
+                    "roll": result.roll,
+                    "total": result.total,
+                });
                 let msg = ServerMessage::ChallengeResolved {
                     challenge_id: challenge_id.clone(),
                     challenge_name: result.challenge_name.clone(),
@@ -417,7 +528,18 @@ pub(super) async fn handle_challenge_roll_input(
                     roll_breakdown: result.roll_breakdown,
                     individual_rolls: None,
                 };
-                state.connections.broadcast_to_world(world_id, msg).await;
+                state
+                    .connections
+                    .broadcast_to_scene(world_id, Some(result.character_id), msg)
+                    .await;
+                ws_scripts::fire_script_hook(
+                    state,
+                    world_id,
+                    ScriptHook::ChallengeResolved,
+                    Some(result.character_id),
+                    script_data,
+                )
+                .await;
                 None
             }
         }
@@ -494,7 +616,7 @@ pub(super) async fn handle_challenge_suggestion_decision(
     connection_id: Uuid,
     request_id: String,
     approved: bool,
-    _modified_difficulty: Option<String>,
+    modified_difficulty: Option<String>,
 ) -> Option<ServerMessage> {
     // Get connection info - only DMs can make decisions
     let conn_info = match state.connections.get(connection_id).await {
@@ -512,6 +634,18 @@ pub(super) async fn handle_challenge_suggestion_decision(
         Err(e) => return Some(e),
     };
 
+    // Kept so later suggestions for this world follow the DM's choices
+    if let Err(e) = state
+        .app
+        .use_cases
+        .challenge
+        .feedback
+        .record(approval_id, approved, modified_difficulty)
+        .await
+    {
+        tracing::warn!(error = %e, "Failed to record challenge suggestion decision");
+    }
+
     let decision = if approved {
         wrldbldr_domain::DmApprovalDecision::Accept
     } else {
@@ -572,6 +706,17 @@ pub(super) async fn handle_challenge_outcome_decision(
         .await
     {
         Ok(crate::use_cases::challenge::OutcomeDecisionResult::Resolved(payload)) => {
+            for clock in &payload.ticked_clocks {
+                super::ws_clock::broadcast_clock_update(state, clock).await;
+            }
+            let script_data = json!({
+                "challengeId": payload.challenge_id,
+                "challengeName": payload.challenge_name,
+                "characterName": payload.character_name,
+                "outcome": payload.outcome,
+                "roll": payload.roll,
+                "total": payload.total,
+            });
             let msg = ServerMessage::ChallengeResolved {
                 challenge_id: payload.challenge_id,
                 challenge_name: payload.challenge_name,
@@ -584,7 +729,29 @@ pub(super) async fn handle_challenge_outcome_decision(
                 roll_breakdown: payload.roll_breakdown,
                 individual_rolls: None,
             };
-            state.connections.broadcast_to_world(world_id, msg).await;
+            state
+                .connections
+                .broadcast_to_scene(world_id, Some(payload.pc_id), msg)
+                .await;
+            for unlocked in &payload.unlocked_connections {
+                let msg = ServerMessage::ConnectionUnlocked {
+                    connection: crate::use_cases::movement::unlocked_connection_to_protocol(
+                        unlocked,
+                    ),
+                };
+                state
+                    .connections
+                    .broadcast_to_scene(world_id, Some(payload.pc_id), msg)
+                    .await;
+            }
+            ws_scripts::fire_script_hook(
+                state,
+                world_id,
+                ScriptHook::ChallengeResolved,
+                Some(payload.pc_id),
+                script_data,
+            )
+            .await;
             None
         }
         Ok(crate::use_cases::challenge::OutcomeDecisionResult::Queued) => None,
@@ -622,3 +789,39 @@ fn outcome_type_to_str(outcome_type: OutcomeType) -> &'static str {
         OutcomeType::CriticalFailure => "critical_failure",
     }
 }
+
+fn challenge_history_data(
+    resolutions: Vec<wrldbldr_domain::ChallengeResolution>,
+    stats: wrldbldr_domain::ChallengeHistoryStats,
+) -> wrldbldr_protocol::ChallengeHistoryData {
+    wrldbldr_protocol::ChallengeHistoryData {
+        resolutions: resolutions
+            .into_iter()
+            .map(|r| wrldbldr_protocol::ChallengeResolutionData {
+                id: r.id.to_string(),
+                challenge_id: r.challenge_id.to_string(),
+                challenge_name: r.challenge_name,
+                skill_name: r.skill_name,
+                difficulty: r.difficulty,
+                pc_id: r.pc_id.to_string(),
+                character_name: r.character_name,
+                roll: r.roll,
+                modifier: r.modifier,
+                total: r.total,
+                roll_breakdown: r.roll_breakdown,
+                outcome: r.outcome.to_string(),
+                outcome_description: r.outcome_description,
+                edited: r.edited,
+                triggers: r.triggers.iter().map(ToString::to_string).collect(),
+                resolved_at: r.resolved_at.to_rfc3339(),
+            })
+            .collect(),
+        stats: wrldbldr_protocol::ChallengeHistoryStatsData {
+            resolved: stats.resolved,
+            succeeded: stats.succeeded,
+            critical_successes: stats.critical_successes,
+            critical_failures: stats.critical_failures,
+            average_roll: stats.average_roll,
+        },
+    }
+}
@@ -6,12 +6,13 @@ use super::*;
 
 use crate::api::connections::ConnectionInfo;
 use serde_json::json;
-use wrldbldr_domain::{CharacterSheetProvider, CharacterSheetSchema, GameSystemRegistry};
+use wrldbldr_domain::{CharacterSheetProvider, GameSystemRegistry};
 use wrldbldr_protocol::{CharacterSheetRequest, ErrorCode, ResponseResult};
 
 // Import all game systems that implement CharacterSheetProvider
 use wrldbldr_domain::game_systems::{
-    BladesSystem, Coc7eSystem, Dnd5eSystem, FateCoreSystem, PbtaSystem, Pf2eSystem,
+    character_sheet_schema, system_id_for_variant, BladesSystem, Coc7eSystem, Dnd5eSystem,
+    FateCoreSystem, PbtaSystem, Pf2eSystem,
 };
 
 /// Check if a game system has a character sheet schema implementation.
@@ -22,22 +23,6 @@ fn has_schema_for_system(system_id: &str) -> bool {
     )
 }
 
-/// Get the character sheet schema for a game system.
-fn get_schema_for_system(system_id: &str) -> Option<CharacterSheetSchema> {
-    match system_id {
-        "dnd5e" => Some(Dnd5eSystem::new().character_sheet_schema()),
-        "pf2e" => Some(Pf2eSystem::new().character_sheet_schema()),
-        "coc7e" => Some(Coc7eSystem::new().character_sheet_schema()),
-        "fate_core" => Some(FateCoreSystem::new().character_sheet_schema()),
-        "blades" => Some(BladesSystem::new().character_sheet_schema()),
-        "pbta" => Some(PbtaSystem::generic().character_sheet_schema()),
-        "pbta_aw" => Some(PbtaSystem::apocalypse_world().character_sheet_schema()),
-        "pbta_dw" => Some(PbtaSystem::dungeon_world().character_sheet_schema()),
-        "pbta_motw" => Some(PbtaSystem::monster_of_the_week().character_sheet_schema()),
-        _ => None,
-    }
-}
-
 /// Get a CharacterSheetProvider for calculating derived values and validation.
 fn get_provider_for_system(system_id: &str) -> Option<Box<dyn CharacterSheetProvider>> {
     match system_id {
@@ -54,25 +39,6 @@ fn get_provider_for_system(system_id: &str) -> Option<Box<dyn CharacterSheetProv
     }
 }
 
-/// Convert a RuleSystemVariant to the corresponding system ID string.
-fn variant_to_system_id(variant: &wrldbldr_domain::RuleSystemVariant) -> String {
-    use wrldbldr_domain::RuleSystemVariant;
-    match variant {
-        RuleSystemVariant::Dnd5e => "dnd5e".to_string(),
-        RuleSystemVariant::Pathfinder2e => "pf2e".to_string(),
-        RuleSystemVariant::CallOfCthulhu7e => "coc7e".to_string(),
-        RuleSystemVariant::FateCore => "fate_core".to_string(),
-        RuleSystemVariant::BladesInTheDark => "blades".to_string(),
-        RuleSystemVariant::PoweredByApocalypse => "pbta".to_string(),
-        RuleSystemVariant::KidsOnBikes => "pbta".to_string(), // Use generic PbtA
-        RuleSystemVariant::RuneQuest => "coc7e".to_string(),  // Similar to CoC (percentile)
-        RuleSystemVariant::GenericD20 => "dnd5e".to_string(), // Closest to D&D
-        RuleSystemVariant::GenericD100 => "coc7e".to_string(), // Percentile system
-        RuleSystemVariant::Custom(_) => "dnd5e".to_string(),  // Default to D&D for custom systems
-        RuleSystemVariant::Unknown => "dnd5e".to_string(),    // Default to D&D for unknown
-    }
-}
-
 pub(super) async fn handle_character_sheet_request(
     state: &WsState,
     request_id: &str,
@@ -94,7 +60,7 @@ pub(super) async fn handle_character_sheet_request(
             };
 
             // Get the schema from the CharacterSheetProvider trait
-            let schema = get_schema_for_system(&system_id);
+            let schema = character_sheet_schema(&system_id);
 
             match schema {
                 Some(schema) => {
@@ -122,6 +88,49 @@ pub(super) async fn handle_character_sheet_request(
             }
         }
 
+        CharacterSheetRequest::GetWorldSchema { world_id } => {
+            let world_id_typed = parse_world_id_for_request(&world_id, request_id)?;
+
+            let world = match state.app.entities.world.get(world_id_typed).await {
+                Ok(Some(w)) => w,
+                Ok(None) => {
+                    return Ok(ResponseResult::error(ErrorCode::NotFound, "World not found"));
+                }
+                Err(e) => {
+                    return Ok(ResponseResult::error(
+                        ErrorCode::InternalError,
+                        e.to_string(),
+                    ));
+                }
+            };
+
+            let system_id = system_id_for_variant(&world.rule_system.variant);
+            let Some(schema) = character_sheet_schema(system_id) else {
+                return Ok(ResponseResult::error(
+                    ErrorCode::BadRequest,
+                    format!(
+                        "Character sheet schema not available for system: {}",
+                        system_id
+                    ),
+                ));
+            };
+            let defaults = get_provider_for_system(system_id)
+                .map(|p| p.default_values())
+                .unwrap_or_default();
+
+            tracing::debug!(
+                world_id = %world_id,
+                system_id = %system_id,
+                "Retrieved world character sheet schema"
+            );
+
+            Ok(ResponseResult::success(json!({
+                "system_id": system_id,
+                "schema": schema,
+                "defaults": defaults,
+            })))
+        }
+
         CharacterSheetRequest::ListSystems => {
             let systems: Vec<serde_json::Value> = registry
                 .list_systems_with_names()
@@ -206,7 +215,7 @@ pub(super) async fn handle_character_sheet_request(
             }
 
             // Get the schema if available
-            let schema = get_schema_for_system(&system_id);
+            let schema = character_sheet_schema(&system_id);
 
             // Get default values from the provider
             let defaults = get_provider_for_system(&system_id)
@@ -271,8 +280,8 @@ pub(super) async fn handle_character_sheet_request(
             };
 
             // Get the system ID from the world's rule system
-            let system_id = variant_to_system_id(&world.rule_system.variant);
-            let provider = get_provider_for_system(&system_id);
+            let system_id = system_id_for_variant(&world.rule_system.variant);
+            let provider = get_provider_for_system(system_id);
 
             // Validate the field if we have a provider
             let all_values = get_character_values(&character);
@@ -359,8 +368,8 @@ pub(super) async fn handle_character_sheet_request(
             };
 
             // Get the system ID from the world's rule system
-            let system_id = variant_to_system_id(&world.rule_system.variant);
-            let schema = get_schema_for_system(&system_id);
+            let system_id = system_id_for_variant(&world.rule_system.variant);
+            let schema = character_sheet_schema(system_id);
 
             // Validate required fields
             let values = get_character_values(&character);
@@ -467,12 +476,12 @@ pub(super) async fn handle_character_sheet_request(
                 }
             };
 
-            let system_id = variant_to_system_id(&world.rule_system.variant);
+            let system_id = system_id_for_variant(&world.rule_system.variant);
 
             // Get schema and calculate derived values
-            let schema = get_schema_for_system(&system_id);
+            let schema = character_sheet_schema(system_id);
             let values = get_character_values(&character);
-            let calculated = get_provider_for_system(&system_id)
+            let calculated = get_provider_for_system(system_id)
                 .map(|p| p.calculate_derived_values(&values))
                 .unwrap_or_default();
 
@@ -536,8 +545,8 @@ pub(super) async fn handle_character_sheet_request(
             };
 
             // Get the system ID from the world's rule system
-            let system_id = variant_to_system_id(&world.rule_system.variant);
-            let provider = get_provider_for_system(&system_id);
+            let system_id = system_id_for_variant(&world.rule_system.variant);
+            let provider = get_provider_for_system(system_id);
 
             // Validate and update
             let all_values = get_character_values(&character);
@@ -622,8 +631,8 @@ pub(super) async fn handle_character_sheet_request(
             };
 
             // Get the system ID from the world's rule system
-            let system_id = variant_to_system_id(&world.rule_system.variant);
-            let provider = get_provider_for_system(&system_id);
+            let system_id = system_id_for_variant(&world.rule_system.variant);
+            let provider = get_provider_for_system(system_id);
 
             // Validate all fields first
             let all_values = get_character_values(&character);
@@ -715,9 +724,9 @@ pub(super) async fn handle_character_sheet_request(
             };
 
             // Get the system ID from the world's rule system
-            let system_id = variant_to_system_id(&world.rule_system.variant);
+            let system_id = system_id_for_variant(&world.rule_system.variant);
             let values = get_character_values(&character);
-            let calculated = get_provider_for_system(&system_id)
+            let calculated = get_provider_for_system(system_id)
                 .map(|p| p.calculate_derived_values(&values))
                 .unwrap_or_default();
 
@@ -766,9 +775,9 @@ pub(super) async fn handle_character_sheet_request(
             };
 
             // Get the system ID from the world's rule system
-            let system_id = variant_to_system_id(&world.rule_system.variant);
+            let system_id = system_id_for_variant(&world.rule_system.variant);
             let values = get_character_values(&character);
-            let calculated = get_provider_for_system(&system_id)
+            let calculated = get_provider_for_system(system_id)
                 .map(|p| p.calculate_derived_values(&values))
                 .unwrap_or_default();
 
@@ -0,0 +1,104 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::chronology::ChronologyError;
+
+use wrldbldr_domain::LoreId;
+use wrldbldr_protocol::ChronologyRequest;
+
+pub(super) async fn handle_chronology_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: ChronologyRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // Birthdates and lore years are DM prep, scoped to the world the DM is in
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before viewing its chronology",
+        ));
+    };
+    let chronology = &state.app.use_cases.chronology.manage;
+
+    let result = match request {
+        ChronologyRequest::GetChronology => chronology
+            .report(world_id)
+            .await
+            .map(ResponseResult::success),
+
+        ChronologyRequest::SetBirthDate {
+            character_id,
+            birth_date,
+        } => {
+            let character_id = parse_id_for_request(
+                &character_id,
+                request_id,
+                CharacterId::from_uuid,
+                "Invalid character ID",
+            )?;
+            chronology
+                .set_birth_date(world_id, character_id, birth_date.as_deref())
+                .await
+                .map(ResponseResult::success)
+        }
+
+        ChronologyRequest::SetLoreEventYear {
+            lore_id,
+            event_year,
+        } => {
+            let lore_id =
+                parse_id_for_request(&lore_id, request_id, LoreId::from_uuid, "Invalid lore ID")?;
+            chronology
+                .set_lore_event_year(world_id, lore_id, event_year)
+                .await
+                .map(ResponseResult::success)
+        }
+    };
+
+    Ok(result.unwrap_or_else(chronology_error_response))
+}
+
+/// Tell the world's DMs who aged during a time advance. Does nothing unless
+/// the world has age progression on and the advance was a long time skip.
+pub(super) async fn notify_age_progression(
+    state: &WsState,
+    world_id: WorldId,
+    minutes_advanced: u32,
+) {
+    match state
+        .app
+        .use_cases
+        .chronology
+        .manage
+        .progressions_for_skip(world_id, minutes_advanced)
+        .await
+    {
+        Ok(progressions) if progressions.is_empty() => {}
+        Ok(progressions) => {
+            let msg = ServerMessage::CharactersAged {
+                world_id: world_id.to_string(),
+                progressions,
+            };
+            state.connections.broadcast_to_dms(world_id, msg).await;
+        }
+        Err(e) => {
+            tracing::warn!(world_id = %world_id, error = %e, "Failed to work out age progression");
+        }
+    }
+}
+
+fn chronology_error_response(e: ChronologyError) -> ResponseResult {
+    match e {
+        ChronologyError::WorldNotFound
+        | ChronologyError::CharacterNotFound
+        | ChronologyError::LoreNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        ChronologyError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        ChronologyError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -0,0 +1,265 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::progress_clock::{clock_to_protocol, ProgressClockError};
+
+use wrldbldr_domain::{EntityType, PlayerCharacterId, ProgressClock};
+use wrldbldr_protocol::ClockRequest;
+
+pub(super) async fn handle_clock_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: ClockRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    match request {
+        ClockRequest::ListClocks { world_id, tags } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .progress_clock
+                .ops
+                .list(world_id, conn_info.is_dm(), &viewed_pc_ids(conn_info))
+                .await
+            {
+                Ok(mut clocks) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        EntityType::ProgressClock,
+                        &tags,
+                        &mut clocks,
+                        |c| Uuid::parse_str(&c.id).unwrap_or_default(),
+                    )
+                    .await?;
+                    Ok(ResponseResult::success(clocks))
+                }
+                Err(e) => Ok(clock_error_response(e)),
+            }
+        }
+
+        ClockRequest::GetClock { clock_id } => {
+            let clock_id = parse_clock_id(&clock_id, request_id)?;
+
+            match state.app.use_cases.progress_clock.ops.get(clock_id).await {
+                // Hidden and other PCs' personal clocks are indistinguishable
+                // from missing ones for players
+                Ok(clock)
+                    if !conn_info.is_dm() && !clock.is_visible_to(&viewed_pc_ids(conn_info)) =>
+                {
+                    Ok(ResponseResult::error(
+                        ErrorCode::NotFound,
+                        "Progress clock not found",
+                    ))
+                }
+                Ok(clock) => Ok(ResponseResult::success(clock_to_protocol(&clock))),
+                Err(e) => Ok(clock_error_response(e)),
+            }
+        }
+
+        ClockRequest::CreateClock { world_id, data } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .progress_clock
+                .ops
+                .create(world_id, data)
+                .await
+            {
+                Ok(clock) => {
+                    broadcast_clock_update(state, &clock).await;
+                    Ok(ResponseResult::success(clock_to_protocol(&clock)))
+                }
+                Err(e) => Ok(clock_error_response(e)),
+            }
+        }
+
+        ClockRequest::UpdateClock { clock_id, data } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let clock_id = parse_clock_id(&clock_id, request_id)?;
+
+            let before = match state.app.use_cases.progress_clock.ops.get(clock_id).await {
+                Ok(clock) => ClockAudience::of(&clock),
+                Err(e) => return Ok(clock_error_response(e)),
+            };
+
+            match state
+                .app
+                .use_cases
+                .progress_clock
+                .ops
+                .update(clock_id, data)
+                .await
+            {
+                Ok(clock) => {
+                    // Players who can no longer see the clock should lose it
+                    if before != ClockAudience::of(&clock) {
+                        broadcast_clock_removed(state, &clock, before).await;
+                    }
+                    broadcast_clock_update(state, &clock).await;
+                    Ok(ResponseResult::success(clock_to_protocol(&clock)))
+                }
+                Err(e) => Ok(clock_error_response(e)),
+            }
+        }
+
+        ClockRequest::DeleteClock { clock_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let clock_id = parse_clock_id(&clock_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .progress_clock
+                .ops
+                .delete(clock_id)
+                .await
+            {
+                Ok(clock) => {
+                    broadcast_clock_removed(state, &clock, ClockAudience::of(&clock)).await;
+                    Ok(ResponseResult::success_empty())
+                }
+                Err(e) => Ok(clock_error_response(e)),
+            }
+        }
+
+        ClockRequest::TickClock { clock_id, amount } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let clock_id = parse_clock_id(&clock_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .progress_clock
+                .ops
+                .tick(clock_id, amount)
+                .await
+            {
+                Ok(clock) => {
+                    broadcast_clock_update(state, &clock).await;
+                    Ok(ResponseResult::success(clock_to_protocol(&clock)))
+                }
+                Err(e) => Ok(clock_error_response(e)),
+            }
+        }
+
+        ClockRequest::ResetClock { clock_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let clock_id = parse_clock_id(&clock_id, request_id)?;
+
+            match state.app.use_cases.progress_clock.ops.reset(clock_id).await {
+                Ok(clock) => {
+                    broadcast_clock_update(state, &clock).await;
+                    Ok(ResponseResult::success(clock_to_protocol(&clock)))
+                }
+                Err(e) => Ok(clock_error_response(e)),
+            }
+        }
+    }
+}
+
+/// Who besides the DMs sees a clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClockAudience {
+    DmsOnly,
+    World,
+    /// A personal clock's owner
+    Pc(PlayerCharacterId),
+}
+
+impl ClockAudience {
+    fn of(clock: &ProgressClock) -> Self {
+        match (clock.visible_to_players, clock.owner_pc_id) {
+            (false, _) => ClockAudience::DmsOnly,
+            (true, None) => ClockAudience::World,
+            (true, Some(pc_id)) => ClockAudience::Pc(pc_id),
+        }
+    }
+}
+
+/// Broadcast a clock change to everyone who may see it.
+///
+/// Player-visible clocks go to the whole world, personal clocks to their
+/// owner and DMs, and hidden clocks only to DMs.
+pub(super) async fn broadcast_clock_update(state: &WsState, clock: &ProgressClock) {
+    let msg = ServerMessage::ClockUpdated {
+        clock: clock_to_protocol(clock),
+    };
+    send_to_audience(state, clock, ClockAudience::of(clock), msg).await;
+}
+
+async fn broadcast_clock_removed(state: &WsState, clock: &ProgressClock, audience: ClockAudience) {
+    let msg = ServerMessage::ClockRemoved {
+        clock_id: clock.id.to_string(),
+    };
+    send_to_audience(state, clock, audience, msg).await;
+}
+
+async fn send_to_audience(
+    state: &WsState,
+    clock: &ProgressClock,
+    audience: ClockAudience,
+    msg: ServerMessage,
+) {
+    match audience {
+        ClockAudience::World => {
+            state
+                .connections
+                .broadcast_to_world(clock.world_id, msg)
+                .await;
+        }
+        ClockAudience::Pc(pc_id) => {
+            state.connections.send_to_pc(pc_id, msg.clone()).await;
+            state
+                .connections
+                .broadcast_to_dms(clock.world_id, msg)
+                .await;
+        }
+        ClockAudience::DmsOnly => {
+            state
+                .connections
+                .broadcast_to_dms(clock.world_id, msg)
+                .await;
+        }
+    }
+}
+
+/// PCs a player connection sees the world as.
+fn viewed_pc_ids(conn_info: &ConnectionInfo) -> Vec<PlayerCharacterId> {
+    conn_info
+        .controlled_pc_ids
+        .iter()
+        .copied()
+        .chain(conn_info.spectate_pc_id)
+        .collect()
+}
+
+fn parse_clock_id(
+    clock_id: &str,
+    request_id: &str,
+) -> Result<wrldbldr_domain::ProgressClockId, ServerMessage> {
+    parse_id_for_request(
+        clock_id,
+        request_id,
+        wrldbldr_domain::ProgressClockId::from_uuid,
+        "Invalid clock_id",
+    )
+}
+
+fn clock_error_response(e: ProgressClockError) -> ResponseResult {
+    match e {
+        ProgressClockError::NotFound => {
+            ResponseResult::error(ErrorCode::NotFound, "Progress clock not found")
+        }
+        ProgressClockError::Validation(msg) => ResponseResult::error(ErrorCode::BadRequest, msg),
+        ProgressClockError::Repo(e) => {
+            ResponseResult::error(ErrorCode::InternalError, e.to_string())
+        }
+    }
+}
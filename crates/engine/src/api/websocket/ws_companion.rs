@@ -0,0 +1,111 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::companions::CompanionError;
+
+use wrldbldr_domain::CompanionId;
+use wrldbldr_protocol::CompanionRequest;
+
+pub(super) async fn handle_companion_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: CompanionRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before managing companions",
+        ));
+    };
+    let companions = &state.app.use_cases.companion.manage;
+
+    let result = match request {
+        CompanionRequest::ListCompanions { pc_id } => {
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            companions
+                .list_for_pc(world_id, pc_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        CompanionRequest::HireCompanion {
+            pc_id,
+            character_id,
+            data,
+        } => {
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            let character_id = parse_id_for_request(
+                &character_id,
+                request_id,
+                CharacterId::from_uuid,
+                "Invalid character ID",
+            )?;
+            companions
+                .hire(world_id, pc_id, character_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        CompanionRequest::UpdateCompanion { companion_id, data } => {
+            let companion_id = parse_companion_id(&companion_id, request_id)?;
+            companions
+                .update(world_id, companion_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        CompanionRequest::DismissCompanion { companion_id } => {
+            let companion_id = parse_companion_id(&companion_id, request_id)?;
+            companions
+                .dismiss(world_id, companion_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+
+        CompanionRequest::PayWages { companion_id } => {
+            let companion_id = parse_companion_id(&companion_id, request_id)?;
+            companions
+                .pay_wages(world_id, companion_id)
+                .await
+                .map(ResponseResult::success)
+        }
+    };
+
+    Ok(result.unwrap_or_else(companion_error_response))
+}
+
+fn parse_pc_id(id: &str, request_id: &str) -> Result<PlayerCharacterId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        PlayerCharacterId::from_uuid,
+        "Invalid PC ID",
+    )
+}
+
+fn parse_companion_id(id: &str, request_id: &str) -> Result<CompanionId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        CompanionId::from_uuid,
+        "Invalid companion ID",
+    )
+}
+
+fn companion_error_response(e: CompanionError) -> ResponseResult {
+    match e {
+        CompanionError::CompanionNotFound
+        | CompanionError::PlayerCharacterNotFound
+        | CompanionError::CharacterNotFound
+        | CompanionError::WorldNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        CompanionError::AlreadyHired => ResponseResult::error(ErrorCode::Conflict, e.to_string()),
+        CompanionError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        CompanionError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -1,12 +1,34 @@
 use super::*;
+use crate::use_cases::interactions::InteractionError;
 use chrono::Utc;
-use wrldbldr_domain::{InteractionTarget, InteractionType, PlayerActionData};
+use wrldbldr_domain::{InteractionTarget, InteractionType, PlayerActionData, TutorialAction};
 
 pub(super) async fn handle_start_conversation(
     state: &WsState,
     connection_id: Uuid,
     npc_id: String,
     message: String,
+) -> Option<ServerMessage> {
+    start_conversation(state, connection_id, npc_id, message, true).await
+}
+
+/// The player left a scripted beat by saying something of their own: the
+/// speaker answers through the LLM, without replaying the script
+pub(super) async fn handle_off_script(
+    state: &WsState,
+    connection_id: Uuid,
+    npc_id: CharacterId,
+    message: String,
+) -> Option<ServerMessage> {
+    start_conversation(state, connection_id, npc_id.to_string(), message, false).await
+}
+
+async fn start_conversation(
+    state: &WsState,
+    connection_id: Uuid,
+    npc_id: String,
+    message: String,
+    play_script: bool,
 ) -> Option<ServerMessage> {
     let conn_info = match state.connections.get(connection_id).await {
         Some(info) => info,
@@ -23,11 +45,27 @@ pub(super) async fn handle_start_conversation(
         None => return Some(error_response("NO_PC", "Must have a PC to start conversation")),
     };
 
+    if let Some(paused) = ws_safety::reject_if_queue_paused(state, world_id).await {
+        return Some(paused);
+    }
+    if let Some(dead) = ws_mortality::reject_if_pc_dead(state, pc_id).await {
+        return Some(dead);
+    }
+    if let Some(throttled) = ws_spotlight::reject_if_throttled(state, world_id, pc_id).await {
+        return Some(throttled);
+    }
+
     let npc_uuid = match parse_character_id(&npc_id) {
         Ok(id) => id,
         Err(e) => return Some(e),
     };
 
+    if play_script && ws_dialogue_tree::begin_npc_beat(state, world_id, pc_id, npc_uuid).await {
+        ws_spotlight::record_spotlight_action(state, world_id, pc_id).await;
+        ws_pacing::restart_turn_timer(state, world_id, pc_id).await;
+        return None;
+    }
+
     let message = normalize_conversation_message(message);
     let conversation = match state
         .app
@@ -69,6 +107,16 @@ pub(super) async fn handle_start_conversation(
     )
     .await;
 
+    ws_tutorial::record_tutorial_action(
+        state,
+        world_id,
+        pc_id,
+        TutorialAction::StartedConversation(npc_uuid),
+    )
+    .await;
+    ws_spotlight::record_spotlight_action(state, world_id, pc_id).await;
+    ws_pacing::restart_turn_timer(state, world_id, pc_id).await;
+
     // Return ConversationStarted with the conversation_id for client tracking
     Some(ServerMessage::ConversationStarted {
         conversation_id: conversation.conversation_id.to_string(),
@@ -100,11 +148,27 @@ pub(super) async fn handle_continue_conversation(
         None => return Some(error_response("NO_PC", "Must have a PC to continue conversation")),
     };
 
+    if let Some(paused) = ws_safety::reject_if_queue_paused(state, world_id).await {
+        return Some(paused);
+    }
+    if let Some(dead) = ws_mortality::reject_if_pc_dead(state, pc_id).await {
+        return Some(dead);
+    }
+    if let Some(throttled) = ws_spotlight::reject_if_throttled(state, world_id, pc_id).await {
+        return Some(throttled);
+    }
+
     let npc_uuid = match parse_character_id(&npc_id) {
         Ok(id) => id,
         Err(e) => return Some(e),
     };
 
+    // Words typed during a scripted beat leave the script
+    let beats = &state.app.use_cases.dialogue_trees.beats;
+    if let Some(speaker_id) = beats.go_off_script(pc_id) {
+        return handle_off_script(state, connection_id, speaker_id, message).await;
+    }
+
     // Parse optional conversation_id from string to UUID
     let conversation_uuid = conversation_id.and_then(|id| Uuid::parse_str(&id).ok());
 
@@ -156,6 +220,8 @@ pub(super) async fn handle_continue_conversation(
         1,
     )
     .await;
+    ws_spotlight::record_spotlight_action(state, world_id, pc_id).await;
+    ws_pacing::restart_turn_timer(state, world_id, pc_id).await;
 
     Some(ServerMessage::ActionReceived {
         action_id: conversation.action_queue_id.to_string(),
@@ -184,6 +250,10 @@ pub(super) async fn handle_perform_interaction(
         None => return Some(error_response("NO_PC", "Must have a PC to act")),
     };
 
+    if let Some(throttled) = ws_spotlight::reject_if_throttled(state, world_id, pc_id).await {
+        return Some(throttled);
+    }
+
     let interaction_uuid = match parse_id(
         &interaction_id,
         wrldbldr_domain::InteractionId::from_uuid,
@@ -193,9 +263,21 @@ pub(super) async fn handle_perform_interaction(
         Err(e) => return Some(e),
     };
 
-    let interaction = match state.app.entities.interaction.get(interaction_uuid).await {
-        Ok(Some(interaction)) => interaction,
-        Ok(None) => return Some(error_response("NOT_FOUND", "Interaction not found")),
+    let interaction = match state
+        .app
+        .use_cases
+        .interactions
+        .library
+        .check_use(pc_id, interaction_uuid)
+        .await
+    {
+        Ok(interaction) => interaction,
+        Err(InteractionError::NotFound) => {
+            return Some(error_response("NOT_FOUND", "Interaction not found"))
+        }
+        Err(e @ InteractionError::Unavailable(_)) => {
+            return Some(error_response("INTERACTION_UNAVAILABLE", &e.to_string()))
+        }
         Err(e) => return Some(error_response("REPO_ERROR", &e.to_string())),
     };
 
@@ -227,6 +309,9 @@ pub(super) async fn handle_perform_interaction(
             Ok(result) => result,
             Err(e) => return Some(error_response("CONVERSATION_ERROR", &e.to_string())),
         };
+        record_interaction_use(state, pc_id, interaction_uuid).await;
+        ws_spotlight::record_spotlight_action(state, world_id, pc_id).await;
+        ws_pacing::restart_turn_timer(state, world_id, pc_id).await;
 
         broadcast_action_queued(
             state,
@@ -264,6 +349,9 @@ pub(super) async fn handle_perform_interaction(
         Ok(id) => id,
         Err(e) => return Some(error_response("QUEUE_ERROR", &e.to_string())),
     };
+    record_interaction_use(state, pc_id, interaction_uuid).await;
+    ws_spotlight::record_spotlight_action(state, world_id, pc_id).await;
+    ws_pacing::restart_turn_timer(state, world_id, pc_id).await;
 
     let queue_depth = state
         .app
@@ -336,3 +424,22 @@ async fn broadcast_action_queued(
     };
     state.connections.broadcast_to_dms(world_id, queue_msg).await;
 }
+
+/// Count a use once the interaction went through; a failed action costs
+/// the PC nothing.
+async fn record_interaction_use(
+    state: &WsState,
+    pc_id: PlayerCharacterId,
+    interaction_id: wrldbldr_domain::InteractionId,
+) {
+    if let Err(e) = state
+        .app
+        .use_cases
+        .interactions
+        .library
+        .record_use(pc_id, interaction_id)
+        .await
+    {
+        tracing::warn!(error = %e, %interaction_id, "Failed to record interaction use");
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 
+use base64::Engine;
 use chrono::Timelike;
 
 use crate::api::connections::ConnectionInfo;
@@ -87,6 +88,45 @@ pub(super) async fn handle_world_request(
             }
         }
 
+        WorldRequest::CreateSessionZeroWorld { data } => {
+            // Like CreateWorld, anyone can run a session zero; the creator
+            // becomes the DM when they join the world.
+            match state
+                .app
+                .use_cases
+                .session_zero
+                .create_world
+                .execute(data)
+                .await
+            {
+                Ok(created) => {
+                    // Nobody has joined yet; the drafts wait in the DM's
+                    // NPC drafts panel once generated
+                    let drafts = state.app.use_cases.npc_drafts.manage.clone();
+                    for draft in &created.drafts {
+                        ws_npc_drafts::spawn_generation(
+                            state,
+                            created.world.id,
+                            drafts.clone(),
+                            draft.id,
+                        );
+                    }
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": created.world.id.to_string(),
+                        "name": created.world.name,
+                        "description": created.world.description,
+                    })))
+                }
+                Err(crate::use_cases::session_zero::SessionZeroError::Invalid(msg)) => {
+                    Ok(ResponseResult::error(ErrorCode::ValidationError, msg))
+                }
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
         WorldRequest::UpdateWorld { world_id, data } => {
             if let Err(e) = require_dm_for_request(conn_info, request_id) {
                 return Err(e);
@@ -100,19 +140,535 @@ pub(super) async fn handle_world_request(
             match state
                 .app
                 .use_cases
-                .management
-                .world
-                .update(world_id_typed, data.name, data.description, data.setting)
+                .management
+                .world
+                .update(world_id_typed, data.name, data.description, data.setting)
+                .await
+            {
+                Ok(world) => Ok(ResponseResult::success(serde_json::json!({
+                    "id": world.id.to_string(),
+                    "name": world.name,
+                    "description": world.description,
+                }))),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::DeleteWorld { world_id } => {
+            if let Err(e) = require_dm_for_request(conn_info, request_id) {
+                return Err(e);
+            }
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .delete(world_id_typed)
+                .await
+            {
+                Ok(()) => Ok(ResponseResult::success_empty()),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::ExportWorld { world_id } => {
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .world
+                .export
+                .execute(world_id_typed)
+                .await
+            {
+                Ok(export) => Ok(ResponseResult::success(serde_json::json!(export))),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::BranchWorld { world_id, name } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .world
+                .branch
+                .execute(world_id_typed, name)
+                .await
+            {
+                Ok(world) => Ok(ResponseResult::success(serde_json::json!({
+                    "id": world.id.to_string(),
+                    "name": world.name,
+                    "description": world.description,
+                    "branchedFrom": world.branched_from.map(|id| id.to_string()),
+                }))),
+                Err(crate::use_cases::world::WorldError::NotFound) => Ok(ResponseResult::error(
+                    ErrorCode::NotFound,
+                    "World not found",
+                )),
+                Err(crate::use_cases::world::WorldError::Invalid(msg)) => {
+                    Ok(ResponseResult::error(ErrorCode::BadRequest, msg))
+                }
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::GetContentSafety { world_id } => {
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .get_content_safety(world_id_typed)
+                .await
+            {
+                Ok(config) => Ok(ResponseResult::success(config)),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::UpdateContentSafety { world_id, config } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .update_content_safety(world_id_typed, config)
+                .await
+            {
+                Ok(config) => Ok(ResponseResult::success(config)),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::GetTypography { world_id } => {
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .get_typography(world_id_typed)
+                .await
+            {
+                Ok(typography) => Ok(ResponseResult::success(typography)),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::UpdateTypography {
+            world_id,
+            typography,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .update_typography(world_id_typed, typography)
+                .await
+            {
+                Ok(typography) => {
+                    state
+                        .connections
+                        .broadcast_to_world(
+                            world_id_typed,
+                            ServerMessage::WorldTypographyUpdated {
+                                world_id: world_id_typed.to_string(),
+                                typography: typography.clone(),
+                            },
+                        )
+                        .await;
+                    Ok(ResponseResult::success(typography))
+                }
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::GetTheme { world_id } => {
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .get_theme(world_id_typed)
+                .await
+            {
+                Ok(theme) => Ok(ResponseResult::success(theme)),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::UpdateTheme { world_id, theme } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .update_theme(world_id_typed, theme)
+                .await
+            {
+                Ok(theme) => {
+                    state
+                        .connections
+                        .broadcast_to_world(
+                            world_id_typed,
+                            ServerMessage::WorldThemeUpdated {
+                                world_id: world_id_typed.to_string(),
+                                theme: theme.clone(),
+                            },
+                        )
+                        .await;
+                    Ok(ResponseResult::success(theme))
+                }
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::GetWorldMap { world_id } => {
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .get_world_map(world_id_typed)
+                .await
+            {
+                Ok(map) => Ok(ResponseResult::success(map)),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::SetWorldMapAsset {
+            world_id,
+            map_asset,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .set_world_map_asset(world_id_typed, map_asset)
+                .await
+            {
+                Ok(map) => {
+                    state
+                        .connections
+                        .broadcast_to_world(
+                            world_id_typed,
+                            ServerMessage::WorldMapUpdated {
+                                world_id: world_id_typed.to_string(),
+                                map: map.clone(),
+                            },
+                        )
+                        .await;
+                    Ok(ResponseResult::success(map))
+                }
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::GetFeatures { world_id } => {
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .get_features(world_id_typed)
+                .await
+            {
+                Ok(features) => Ok(ResponseResult::success(features)),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::UpdateFeatures { world_id, features } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .update_features(world_id_typed, features)
+                .await
+            {
+                Ok(features) => {
+                    state
+                        .connections
+                        .broadcast_to_world(
+                            world_id_typed,
+                            ServerMessage::WorldFeaturesUpdated {
+                                world_id: world_id_typed.to_string(),
+                                features,
+                            },
+                        )
+                        .await;
+                    Ok(ResponseResult::success(features))
+                }
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::GetScripts { world_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state.app.use_cases.scripts.manage.get(world_id_typed).await {
+                Ok(scripts) => Ok(ResponseResult::success(scripts)),
+                Err(crate::use_cases::scripts::ScriptUseCaseError::WorldNotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::UpdateScripts { world_id, scripts } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .scripts
+                .manage
+                .update(world_id_typed, scripts)
+                .await
+            {
+                Ok(scripts) => {
+                    state
+                        .connections
+                        .broadcast_to_dms(
+                            world_id_typed,
+                            ServerMessage::WorldScriptsUpdated {
+                                world_id: world_id_typed.to_string(),
+                                scripts: scripts.clone(),
+                            },
+                        )
+                        .await;
+                    Ok(ResponseResult::success(scripts))
+                }
+                Err(crate::use_cases::scripts::ScriptUseCaseError::WorldNotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
+                Err(crate::use_cases::scripts::ScriptUseCaseError::Invalid(message)) => {
+                    Ok(ResponseResult::error(ErrorCode::ValidationError, message))
+                }
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::GetCustomFields { world_id } => {
+            ws_custom_fields::handle_get_custom_fields(state, request_id, conn_info, world_id).await
+        }
+
+        WorldRequest::UpdateCustomFields { world_id, fields } => {
+            ws_custom_fields::handle_update_custom_fields(
+                state, request_id, conn_info, world_id, fields,
+            )
+            .await
+        }
+
+        WorldRequest::SetCustomFieldValues {
+            world_id,
+            entity_type,
+            entity_id,
+            values,
+        } => {
+            ws_custom_fields::handle_set_custom_field_values(
+                state,
+                request_id,
+                conn_info,
+                world_id,
+                entity_type,
+                entity_id,
+                values,
+            )
+            .await
+        }
+
+        WorldRequest::GetUsage { world_id, days } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .usage
+                .report
+                .execute(world_id_typed, days)
                 .await
             {
-                Ok(world) => Ok(ResponseResult::success(serde_json::json!({
-                    "id": world.id.to_string(),
-                    "name": world.name,
-                    "description": world.description,
-                }))),
-                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
-                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
-                ),
+                Ok(report) => Ok(ResponseResult::success(report)),
                 Err(e) => Ok(ResponseResult::error(
                     ErrorCode::InternalError,
                     e.to_string(),
@@ -120,10 +676,32 @@ pub(super) async fn handle_world_request(
             }
         }
 
-        WorldRequest::DeleteWorld { world_id } => {
-            if let Err(e) = require_dm_for_request(conn_info, request_id) {
-                return Err(e);
+        WorldRequest::GetAnalytics { world_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .analytics
+                .campaign
+                .execute(world_id_typed)
+                .await
+            {
+                Ok(analytics) => Ok(ResponseResult::success(analytics)),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
             }
+        }
+
+        WorldRequest::GetUnusedContent { world_id } => {
+            require_dm_for_request(conn_info, request_id)?;
 
             let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
                 Ok(id) => id,
@@ -133,13 +711,37 @@ pub(super) async fn handle_world_request(
             match state
                 .app
                 .use_cases
-                .management
-                .world
-                .delete(world_id_typed)
+                .analytics
+                .unused
+                .execute(world_id_typed)
                 .await
             {
-                Ok(()) => Ok(ResponseResult::success_empty()),
-                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                Ok(report) => Ok(ResponseResult::success(report)),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        WorldRequest::GeneratePrepChecklist { world_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state
+                .app
+                .use_cases
+                .prep
+                .checklist
+                .execute(world_id_typed)
+                .await
+            {
+                Ok(checklist) => Ok(ResponseResult::success(checklist)),
+                Err(crate::infrastructure::ports::RepoError::NotFound) => Ok(
                     ResponseResult::error(ErrorCode::NotFound, "World not found"),
                 ),
                 Err(e) => Ok(ResponseResult::error(
@@ -149,7 +751,9 @@ pub(super) async fn handle_world_request(
             }
         }
 
-        WorldRequest::ExportWorld { world_id } => {
+        WorldRequest::ExportDiagnostics { world_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+
             let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
                 Ok(id) => id,
                 Err(e) => return Err(e),
@@ -158,12 +762,20 @@ pub(super) async fn handle_world_request(
             match state
                 .app
                 .use_cases
-                .world
+                .diagnostics
                 .export
                 .execute(world_id_typed)
                 .await
             {
-                Ok(export) => Ok(ResponseResult::success(serde_json::json!(export))),
+                Ok(bundle) => Ok(ResponseResult::success(serde_json::json!({
+                    "file_name": bundle.file_name,
+                    "content_type": "application/zip",
+                    "size_bytes": bundle.data.len(),
+                    "data": base64::engine::general_purpose::STANDARD.encode(&bundle.data),
+                }))),
+                Err(crate::use_cases::diagnostics::DiagnosticsError::WorldNotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "World not found"),
+                ),
                 Err(e) => Ok(ResponseResult::error(
                     ErrorCode::InternalError,
                     e.to_string(),
@@ -171,6 +783,46 @@ pub(super) async fn handle_world_request(
             }
         }
 
+        WorldRequest::PreviewRuleSystemChange { world_id, variant } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            let result = state
+                .app
+                .use_cases
+                .rule_system
+                .change
+                .preview(world_id_typed, variant)
+                .await;
+            Ok(rule_system_change_response(result))
+        }
+
+        WorldRequest::ChangeRuleSystem {
+            world_id,
+            variant,
+            skill_overrides,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            let result = state
+                .app
+                .use_cases
+                .rule_system
+                .change
+                .apply(world_id_typed, variant, skill_overrides)
+                .await;
+            Ok(rule_system_change_response(result))
+        }
+
         WorldRequest::GetSheetTemplate { .. } => Ok(ResponseResult::error(
             ErrorCode::BadRequest,
             "Sheet template request is not yet implemented",
@@ -183,6 +835,26 @@ pub(super) async fn handle_world_request(
     }
 }
 
+fn rule_system_change_response(
+    result: Result<
+        wrldbldr_protocol::types::RuleSystemSwitchData,
+        crate::use_cases::rule_system::RuleSystemError,
+    >,
+) -> ResponseResult {
+    use crate::use_cases::rule_system::RuleSystemError;
+
+    match result {
+        Ok(data) => ResponseResult::success(data),
+        Err(RuleSystemError::WorldNotFound) => {
+            ResponseResult::error(ErrorCode::NotFound, "World not found")
+        }
+        Err(RuleSystemError::Invalid(message)) => {
+            ResponseResult::error(ErrorCode::ValidationError, message)
+        }
+        Err(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
+
 pub(super) async fn handle_character_request(
     state: &WsState,
     request_id: &str,
@@ -190,7 +862,7 @@ pub(super) async fn handle_character_request(
     request: CharacterRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        CharacterRequest::ListCharacters { world_id } => {
+        CharacterRequest::ListCharacters { world_id, tags } => {
             let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
                 Ok(id) => id,
                 Err(e) => return Err(e),
@@ -204,14 +876,30 @@ pub(super) async fn handle_character_request(
                 .list_in_world(world_id_typed)
                 .await
             {
-                Ok(chars) => {
+                Ok(mut chars) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        wrldbldr_domain::EntityType::Character,
+                        &tags,
+                        &mut chars,
+                        |c| c.id.to_uuid(),
+                    )
+                    .await?;
+                    let mut aliases =
+                        ws_mentions::listed_aliases(state, conn_info, world_id_typed).await;
                     let data: Vec<serde_json::Value> = chars
                         .into_iter()
                         .map(|c| {
+                            let aliases = aliases
+                                .remove(&(wrldbldr_domain::EntityType::Character, c.id.to_uuid()))
+                                .unwrap_or_default();
                             serde_json::json!({
                                 "id": c.id.to_string(),
                                 "name": c.name,
                                 "archetype": Some(c.current_archetype.to_string()),
+                                "aliases": aliases,
                             })
                         })
                         .collect();
@@ -231,15 +919,26 @@ pub(super) async fn handle_character_request(
             };
 
             match state.app.use_cases.management.character.get(char_id).await {
-                Ok(Some(character)) => Ok(ResponseResult::success(serde_json::json!({
-                    "id": character.id.to_string(),
-                    "name": character.name,
-                    "description": if character.description.is_empty() { None } else { Some(character.description) },
-                    "archetype": Some(character.current_archetype.to_string()),
-                    "sprite_asset": character.sprite_asset,
-                    "portrait_asset": character.portrait_asset,
-                    "sheet_data": serde_json::Value::Null,
-                }))),
+                Ok(Some(character)) => {
+                    let custom_fields = ws_custom_fields::detail_entries(
+                        state,
+                        conn_info,
+                        character.world_id,
+                        wrldbldr_domain::EntityType::Character,
+                        *character.id.as_uuid(),
+                    )
+                    .await;
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": character.id.to_string(),
+                        "name": character.name,
+                        "description": if character.description.is_empty() { None } else { Some(character.description) },
+                        "archetype": Some(character.current_archetype.to_string()),
+                        "sprite_asset": character.sprite_asset,
+                        "portrait_asset": character.portrait_asset,
+                        "sheet_data": serde_json::Value::Null,
+                        "custom_fields": custom_fields,
+                    })))
+                }
                 Ok(None) => Ok(ResponseResult::error(
                     ErrorCode::NotFound,
                     "Character not found",
@@ -276,15 +975,23 @@ pub(super) async fn handle_character_request(
                 )
                 .await
             {
-                Ok(character) => Ok(ResponseResult::success(serde_json::json!({
-                    "id": character.id.to_string(),
-                    "name": character.name,
-                    "description": if character.description.is_empty() { None } else { Some(character.description) },
-                    "archetype": Some(character.current_archetype.to_string()),
-                    "sprite_asset": character.sprite_asset,
-                    "portrait_asset": character.portrait_asset,
-                    "sheet_data": serde_json::Value::Null,
-                }))),
+                Ok(character) => {
+                    ws_mentions::spawn_index_mentions(
+                        state,
+                        character.world_id,
+                        wrldbldr_domain::EntityType::Character,
+                        character.id.to_uuid(),
+                    );
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": character.id.to_string(),
+                        "name": character.name,
+                        "description": if character.description.is_empty() { None } else { Some(character.description) },
+                        "archetype": Some(character.current_archetype.to_string()),
+                        "sprite_asset": character.sprite_asset,
+                        "portrait_asset": character.portrait_asset,
+                        "sheet_data": serde_json::Value::Null,
+                    })))
+                }
                 Err(crate::use_cases::management::ManagementError::InvalidInput(msg)) => {
                     Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
                 }
@@ -321,15 +1028,23 @@ pub(super) async fn handle_character_request(
                 )
                 .await
             {
-                Ok(character) => Ok(ResponseResult::success(serde_json::json!({
-                    "id": character.id.to_string(),
-                    "name": character.name,
-                    "description": if character.description.is_empty() { None } else { Some(character.description) },
-                    "archetype": Some(character.current_archetype.to_string()),
-                    "sprite_asset": character.sprite_asset,
-                    "portrait_asset": character.portrait_asset,
-                    "sheet_data": serde_json::Value::Null,
-                }))),
+                Ok(character) => {
+                    ws_mentions::spawn_index_mentions(
+                        state,
+                        character.world_id,
+                        wrldbldr_domain::EntityType::Character,
+                        character.id.to_uuid(),
+                    );
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": character.id.to_string(),
+                        "name": character.name,
+                        "description": if character.description.is_empty() { None } else { Some(character.description) },
+                        "archetype": Some(character.current_archetype.to_string()),
+                        "sprite_asset": character.sprite_asset,
+                        "portrait_asset": character.portrait_asset,
+                        "sheet_data": serde_json::Value::Null,
+                    })))
+                }
                 Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
                     ResponseResult::error(ErrorCode::NotFound, "Character not found"),
                 ),
@@ -361,7 +1076,15 @@ pub(super) async fn handle_character_request(
                 .delete(char_id)
                 .await
             {
-                Ok(()) => Ok(ResponseResult::success_empty()),
+                Ok(()) => {
+                    ws_mentions::forget_mentions(
+                        state,
+                        wrldbldr_domain::EntityType::Character,
+                        char_id.to_uuid(),
+                    )
+                    .await;
+                    Ok(ResponseResult::success_empty())
+                }
                 Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
                     ResponseResult::error(ErrorCode::NotFound, "Character not found"),
                 ),
@@ -372,6 +1095,37 @@ pub(super) async fn handle_character_request(
             }
         }
 
+        CharacterRequest::DuplicateCharacter { character_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let char_id = parse_character_id_for_request(&character_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .duplicate
+                .entity
+                .character(char_id)
+                .await
+            {
+                Ok(character) => Ok(ResponseResult::success(serde_json::json!({
+                    "id": character.id.to_string(),
+                    "name": character.name,
+                    "description": if character.description.is_empty() { None } else { Some(character.description) },
+                    "archetype": Some(character.current_archetype.to_string()),
+                    "sprite_asset": character.sprite_asset,
+                    "portrait_asset": character.portrait_asset,
+                    "sheet_data": serde_json::Value::Null,
+                }))),
+                Err(crate::use_cases::duplicate::DuplicateError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "Character not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
         CharacterRequest::ChangeArchetype { character_id, data } => {
             if let Err(e) = require_dm_for_request(conn_info, request_id) {
                 return Err(e);
@@ -533,6 +1287,34 @@ pub(super) async fn handle_time_request(
                 .connections
                 .broadcast_to_world(world_id_typed, update_msg)
                 .await;
+            ws_scripts::fire_time_advance_hook(
+                state,
+                world_id_typed,
+                &crate::use_cases::time::build_time_advance_data(
+                    &outcome.previous_time,
+                    &outcome.new_time,
+                    outcome.minutes_advanced,
+                    &wrldbldr_domain::TimeAdvanceReason::DmManual { hours },
+                ),
+            )
+            .await;
+            ws_chronology::notify_age_progression(state, world_id_typed, outcome.minutes_advanced)
+                .await;
+            ws_injury::heal_after_time_advance(
+                state,
+                world_id_typed,
+                outcome.minutes_advanced,
+                None,
+            )
+            .await;
+            ws_mail::deliver_after_time_advance(state, world_id_typed).await;
+            ws_broadsheet::draft_after_time_advance(state, world_id_typed, outcome.minutes_advanced)
+                .await;
+            ws_simulation::simulate_after_time_advance(
+                state,
+                world_id_typed,
+                outcome.minutes_advanced,
+            );
 
             tracing::info!(
                 world_id = %world_id_typed,
@@ -595,11 +1377,17 @@ pub(super) async fn handle_time_request(
                 minutes,
                 &advance_reason,
             );
+            ws_scripts::fire_time_advance_hook(state, world_id_typed, &advance_data).await;
             let update_msg = ServerMessage::GameTimeAdvanced { data: advance_data };
             state
                 .connections
                 .broadcast_to_world(world_id_typed, update_msg)
                 .await;
+            ws_chronology::notify_age_progression(state, world_id_typed, minutes).await;
+            ws_injury::heal_after_time_advance(state, world_id_typed, minutes, None).await;
+            ws_mail::deliver_after_time_advance(state, world_id_typed).await;
+            ws_broadsheet::draft_after_time_advance(state, world_id_typed, minutes).await;
+            ws_simulation::simulate_after_time_advance(state, world_id_typed, minutes);
 
             tracing::info!(
                 world_id = %world_id_typed,
@@ -740,6 +1528,7 @@ pub(super) async fn handle_time_request(
                 outcome.minutes_advanced,
                 &reason,
             );
+            ws_scripts::fire_time_advance_hook(state, world_id_typed, &advance_data).await;
             let update_msg = ServerMessage::GameTimeAdvanced { data: advance_data };
             state
                 .connections
@@ -1366,6 +2155,64 @@ pub(super) async fn handle_npc_request(
             }
         }
 
+        NpcRequest::SetNpcAutonomy { npc_id, level } => {
+            let npc_uuid = match Uuid::parse_str(&npc_id) {
+                Ok(u) => CharacterId::from(u),
+                Err(_) => {
+                    return Err(ServerMessage::Response {
+                        request_id: request_id.to_string(),
+                        result: ResponseResult::error(ErrorCode::BadRequest, "Invalid NPC ID"),
+                    })
+                }
+            };
+
+            let level = match level.as_deref().map(str::parse::<wrldbldr_domain::NpcAutonomyLevel>)
+            {
+                None => None,
+                Some(Ok(level)) => Some(level),
+                Some(Err(e)) => return Ok(ResponseResult::error(ErrorCode::BadRequest, &e)),
+            };
+
+            if !conn_info.is_dm() {
+                return Err(ServerMessage::Response {
+                    request_id: request_id.to_string(),
+                    result: ResponseResult::error(
+                        ErrorCode::Forbidden,
+                        "Only DM can set NPC autonomy",
+                    ),
+                });
+            }
+
+            let world_id = match conn_info.world_id {
+                Some(wid) => wid,
+                None => {
+                    return Err(ServerMessage::Response {
+                        request_id: request_id.to_string(),
+                        result: ResponseResult::error(
+                            ErrorCode::BadRequest,
+                            "Not connected to a world",
+                        ),
+                    })
+                }
+            };
+
+            match state
+                .app
+                .use_cases
+                .approval
+                .autonomy
+                .set_npc_level(world_id, npc_uuid, level)
+                .await
+            {
+                Ok(effective) => Ok(ResponseResult::success(serde_json::json!({
+                    "npc_id": npc_id,
+                    "level": level.map(|l| l.to_string()),
+                    "effective_level": effective.to_string(),
+                }))),
+                Err(e) => Ok(ResponseResult::error(ErrorCode::InternalError, e.to_string())),
+            }
+        }
+
         other => {
             let msg = format!("This request type is not yet implemented: {:?}", other);
             Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
@@ -1380,6 +2227,42 @@ pub(super) async fn handle_items_request(
     request: ItemsRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
+        ItemsRequest::GetItem { item_id } => {
+            let item_uuid = match parse_item_id_for_request(&item_id, request_id) {
+                Ok(id) => id,
+                Err(e) => return Err(e),
+            };
+
+            match state.app.entities.inventory.get(item_uuid).await {
+                Ok(Some(item)) => {
+                    let custom_fields = ws_custom_fields::detail_entries(
+                        state,
+                        conn_info,
+                        item.world_id,
+                        wrldbldr_domain::EntityType::Item,
+                        *item.id.as_uuid(),
+                    )
+                    .await;
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": item.id.to_string(),
+                        "name": item.name,
+                        "description": item.description,
+                        "item_type": item.item_type,
+                        "is_unique": item.is_unique,
+                        "properties": item.properties,
+                        "can_contain_items": item.can_contain_items,
+                        "container_limit": item.container_limit,
+                        "base_price": item.base_price,
+                        "custom_fields": custom_fields,
+                    })))
+                }
+                Ok(None) => Ok(ResponseResult::error(ErrorCode::NotFound, "Item not found")),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
         ItemsRequest::PlaceItemInRegion { region_id, item_id } => {
             if let Err(e) = require_dm_for_request(conn_info, request_id) {
                 return Err(e);
@@ -1441,6 +2324,9 @@ pub(super) async fn handle_items_request(
             if let Some(props) = data.properties {
                 item = item.with_properties(serde_json::to_string(&props).unwrap_or_default());
             }
+            if let Some(price) = data.base_price {
+                item = item.with_base_price(price);
+            }
 
             match state
                 .app
@@ -1459,5 +2345,44 @@ pub(super) async fn handle_items_request(
                 )),
             }
         }
+        ItemsRequest::DuplicateItem { item_id, region_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let item_uuid = parse_item_id_for_request(&item_id, request_id)?;
+            let region_uuid = region_id
+                .as_deref()
+                .map(|id| parse_region_id_for_request(id, request_id))
+                .transpose()?;
+
+            match state
+                .app
+                .use_cases
+                .duplicate
+                .entity
+                .item(item_uuid, region_uuid)
+                .await
+            {
+                Ok(item) => Ok(ResponseResult::success(serde_json::json!({
+                    "id": item.id.to_string(),
+                    "name": item.name,
+                    "description": item.description,
+                    "item_type": item.item_type,
+                    "is_unique": item.is_unique,
+                    "properties": item.properties,
+                    "can_contain_items": item.can_contain_items,
+                    "container_limit": item.container_limit,
+                    "base_price": item.base_price,
+                }))),
+                Err(crate::use_cases::duplicate::DuplicateError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "Item or region not found"),
+                ),
+                Err(crate::use_cases::duplicate::DuplicateError::Invalid(msg)) => {
+                    Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
+                }
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
     }
 }
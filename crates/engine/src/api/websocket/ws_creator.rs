@@ -304,6 +304,40 @@ pub(super) async fn handle_generation_request(
                 }
             }
         }
+
+        GenerationRequest::GenerateNames {
+            world_id,
+            generator_id,
+            region_id,
+            faction,
+            count,
+        } => {
+            super::ws_names::generate_names(
+                state,
+                request_id,
+                conn_info,
+                &world_id,
+                generator_id,
+                region_id,
+                faction,
+                count,
+            )
+            .await
+        }
+    }
+}
+
+fn model_error_response(request_id: &str, error: crate::use_cases::ai::ModelError) -> ServerMessage {
+    use crate::use_cases::ai::ModelError;
+
+    let code = match error {
+        ModelError::EmptyName | ModelError::NotAvailable(_) => ErrorCode::BadRequest,
+        ModelError::Llm(_) => ErrorCode::ServiceUnavailable,
+        ModelError::Settings(_) => ErrorCode::InternalError,
+    };
+    ServerMessage::Response {
+        request_id: request_id.to_string(),
+        result: ResponseResult::error(code, error.to_string()),
     }
 }
 
@@ -378,6 +412,125 @@ pub(super) async fn handle_ai_request(
             })))
         }
 
+        AiRequest::ListModels => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let models = state
+                .app
+                .use_cases
+                .ai
+                .models
+                .list()
+                .await
+                .map_err(|e| model_error_response(request_id, e))?;
+
+            Ok(ResponseResult::success(serde_json::json!({
+                "models": models
+                    .into_iter()
+                    .map(|m| serde_json::json!({ "name": m.name, "size_bytes": m.size_bytes }))
+                    .collect::<Vec<_>>(),
+            })))
+        }
+
+        AiRequest::PullModel { model } => {
+            require_dm_for_request(conn_info, request_id)?;
+
+            let model = model.trim().to_string();
+            if model.is_empty() {
+                return Err(model_error_response(
+                    request_id,
+                    crate::use_cases::ai::ModelError::EmptyName,
+                ));
+            }
+
+            // Downloads take minutes; answer now and broadcast progress to DMs
+            let models = state.app.use_cases.ai.models.clone();
+            let connections = state.connections.clone();
+            let pulling = model.clone();
+            tokio::spawn(async move {
+                let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<
+                    crate::infrastructure::ports::ModelPullProgress,
+                >();
+                let forward = async {
+                    while let Some(update) = rx.recv().await {
+                        connections
+                            .broadcast_to_all_dms(ServerMessage::ModelPullProgress {
+                                model: pulling.clone(),
+                                status: update.status,
+                                completed_bytes: update.completed_bytes,
+                                total_bytes: update.total_bytes,
+                                done: false,
+                                error: None,
+                            })
+                            .await;
+                    }
+                };
+                let (result, ()) = tokio::join!(models.pull(&pulling, tx), forward);
+
+                if let Err(e) = &result {
+                    tracing::warn!(model = %pulling, error = %e, "Model pull failed");
+                }
+                connections
+                    .broadcast_to_all_dms(ServerMessage::ModelPullProgress {
+                        model: pulling.clone(),
+                        status: if result.is_ok() { "success" } else { "failed" }.to_string(),
+                        completed_bytes: None,
+                        total_bytes: None,
+                        done: true,
+                        error: result.err().map(|e| e.to_string()),
+                    })
+                    .await;
+            });
+
+            Ok(ResponseResult::success(serde_json::json!({
+                "model": model,
+                "status": "pulling",
+            })))
+        }
+
+        AiRequest::SetWorldModel {
+            world_id,
+            dialogue_model,
+            summary_model,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            let models = state
+                .app
+                .use_cases
+                .ai
+                .models
+                .set_world_models(world_id, dialogue_model, summary_model)
+                .await
+                .map_err(|e| model_error_response(request_id, e))?;
+
+            Ok(ResponseResult::success(serde_json::json!(models)))
+        }
+
+        AiRequest::ListCoDmReviews { world_id, limit } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .approval
+                .co_dm
+                .reviews(world_id, limit.map(|l| l as usize))
+                .await
+            {
+                Ok(reviews) => Ok(ResponseResult::success(serde_json::json!(reviews
+                    .into_iter()
+                    .map(co_dm_review_data)
+                    .collect::<Vec<_>>()))),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
         AiRequest::SuggestWantDescription { .. }
         | AiRequest::SuggestActantialReason { .. }
         | AiRequest::SuggestDeflectionBehavior { .. }
@@ -582,3 +735,34 @@ pub(super) async fn handle_expression_request(
         }
     }
 }
+
+fn co_dm_review_data(review: wrldbldr_domain::CoDmReview) -> wrldbldr_protocol::CoDmReviewData {
+    use wrldbldr_domain::CoDmOutcome;
+
+    let (outcome, final_dialogue, approved_tools, escalation_reason) = match review.outcome {
+        CoDmOutcome::Accepted { approved_tools } => (
+            "accepted",
+            Some(review.proposed_dialogue.clone()),
+            approved_tools,
+            None,
+        ),
+        CoDmOutcome::Modified {
+            dialogue,
+            approved_tools,
+        } => ("modified", Some(dialogue), approved_tools, None),
+        CoDmOutcome::Rejected => ("rejected", None, vec![], None),
+        CoDmOutcome::Escalated { reason } => ("escalated", None, vec![], Some(reason.to_string())),
+    };
+    wrldbldr_protocol::CoDmReviewData {
+        approval_id: review.approval_id.to_string(),
+        npc_name: review.npc_name,
+        player_dialogue: review.player_dialogue,
+        proposed_dialogue: review.proposed_dialogue,
+        outcome: outcome.to_string(),
+        final_dialogue,
+        approved_tools,
+        escalation_reason,
+        reasoning: review.reasoning,
+        reviewed_at: review.reviewed_at.to_rfc3339(),
+    }
+}
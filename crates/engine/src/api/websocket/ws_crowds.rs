@@ -0,0 +1,131 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::crowds::{crowd_to_protocol, CrowdError};
+
+use wrldbldr_domain::CrowdId;
+use wrldbldr_protocol::CrowdRequest;
+
+pub(super) async fn handle_crowd_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: CrowdRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // Crowds are staged by the DM, in the world the DM is in
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before managing its crowds",
+        ));
+    };
+    let crowds = &state.app.use_cases.crowds.manage;
+
+    let result = match request {
+        CrowdRequest::ListCrowds { region_id } => {
+            let region_id = region_id
+                .map(|id| {
+                    parse_id_for_request(&id, request_id, RegionId::from_uuid, "Invalid region ID")
+                })
+                .transpose()?;
+            crowds
+                .list(world_id, region_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        CrowdRequest::CreateCrowd { region_id, data } => {
+            let region_id = parse_id_for_request(
+                &region_id,
+                request_id,
+                RegionId::from_uuid,
+                "Invalid region ID",
+            )?;
+            match crowds.create(world_id, region_id, data).await {
+                Ok(crowd) => {
+                    announce_region_crowds(state, world_id, crowd.region_id).await;
+                    Ok(ResponseResult::success(crowd_to_protocol(&crowd)))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        CrowdRequest::UpdateCrowd { crowd_id, data } => {
+            let crowd_id = parse_crowd_id(&crowd_id, request_id)?;
+            match crowds.update(world_id, crowd_id, data).await {
+                Ok(crowd) => {
+                    announce_region_crowds(state, world_id, crowd.region_id).await;
+                    Ok(ResponseResult::success(crowd_to_protocol(&crowd)))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        CrowdRequest::DeleteCrowd { crowd_id } => {
+            let crowd_id = parse_crowd_id(&crowd_id, request_id)?;
+            match crowds.delete(world_id, crowd_id).await {
+                Ok(region_id) => {
+                    announce_region_crowds(state, world_id, region_id).await;
+                    Ok(ResponseResult::success_empty())
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        CrowdRequest::SetCrowdPresent { crowd_id, present } => {
+            let crowd_id = parse_crowd_id(&crowd_id, request_id)?;
+            match crowds.set_present(world_id, crowd_id, present).await {
+                Ok(crowd) => {
+                    announce_region_crowds(state, world_id, crowd.region_id).await;
+                    Ok(ResponseResult::success(crowd_to_protocol(&crowd)))
+                }
+                Err(e) => Err(e),
+            }
+        }
+    };
+
+    Ok(result.unwrap_or_else(crowd_error_response))
+}
+
+fn parse_crowd_id(id: &str, request_id: &str) -> Result<CrowdId, ServerMessage> {
+    parse_id_for_request(id, request_id, CrowdId::from_uuid, "Invalid crowd ID")
+}
+
+/// Send the region's present crowds to the world, so players standing in
+/// it see the change without moving
+pub(super) async fn announce_region_crowds(
+    state: &WsState,
+    world_id: WorldId,
+    region_id: RegionId,
+) {
+    match state
+        .app
+        .use_cases
+        .crowds
+        .manage
+        .present_in_region(region_id)
+        .await
+    {
+        Ok(crowds_present) => {
+            let msg = ServerMessage::RegionCrowdsChanged {
+                region_id: region_id.to_string(),
+                crowds_present,
+            };
+            state.connections.broadcast_to_world(world_id, msg).await;
+        }
+        Err(e) => {
+            tracing::warn!(region_id = %region_id, error = %e, "Failed to announce region crowds");
+        }
+    }
+}
+
+fn crowd_error_response(e: CrowdError) -> ResponseResult {
+    match e {
+        CrowdError::NotFound | CrowdError::RegionNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        CrowdError::Invalid(_) => ResponseResult::error(ErrorCode::ValidationError, e.to_string()),
+        CrowdError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -0,0 +1,123 @@
+use super::*;
+
+use std::collections::BTreeMap;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::custom_fields::CustomFieldError;
+
+use wrldbldr_domain::EntityType;
+use wrldbldr_protocol::types::{
+    CustomFieldDefinitionData, CustomFieldEntryData, CustomFieldValueData,
+};
+
+pub(super) async fn handle_get_custom_fields(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    world_id: String,
+) -> Result<ResponseResult, ServerMessage> {
+    require_dm_for_request(conn_info, request_id)?;
+    let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+    match state.app.use_cases.custom_fields.schema.get(world_id).await {
+        Ok(fields) => Ok(ResponseResult::success(fields)),
+        Err(e) => Ok(custom_field_error_response(e)),
+    }
+}
+
+pub(super) async fn handle_update_custom_fields(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    world_id: String,
+    fields: Vec<CustomFieldDefinitionData>,
+) -> Result<ResponseResult, ServerMessage> {
+    require_dm_for_request(conn_info, request_id)?;
+    let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+    match state
+        .app
+        .use_cases
+        .custom_fields
+        .schema
+        .update(world_id, fields)
+        .await
+    {
+        Ok(fields) => {
+            state
+                .connections
+                .broadcast_to_dms(
+                    world_id,
+                    ServerMessage::CustomFieldsUpdated {
+                        world_id: world_id.to_string(),
+                        fields: fields.clone(),
+                    },
+                )
+                .await;
+            Ok(ResponseResult::success(fields))
+        }
+        Err(e) => Ok(custom_field_error_response(e)),
+    }
+}
+
+pub(super) async fn handle_set_custom_field_values(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    world_id: String,
+    entity_type: EntityType,
+    entity_id: String,
+    values: BTreeMap<String, CustomFieldValueData>,
+) -> Result<ResponseResult, ServerMessage> {
+    require_dm_for_request(conn_info, request_id)?;
+    let world_id = parse_world_id_for_request(&world_id, request_id)?;
+    let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity_id")?;
+
+    match state
+        .app
+        .use_cases
+        .custom_fields
+        .values
+        .set(world_id, entity_type, entity_id, values)
+        .await
+    {
+        Ok(entries) => Ok(ResponseResult::success(entries)),
+        Err(e) => Ok(custom_field_error_response(e)),
+    }
+}
+
+/// Custom field entries for a detail response. DMs see every field; others
+/// only the ones marked visible to players. A failed lookup is logged and
+/// leaves the list empty rather than failing the whole detail request.
+pub(super) async fn detail_entries(
+    state: &WsState,
+    conn_info: &ConnectionInfo,
+    world_id: WorldId,
+    entity_type: EntityType,
+    entity_id: Uuid,
+) -> Vec<CustomFieldEntryData> {
+    match state
+        .app
+        .use_cases
+        .custom_fields
+        .values
+        .entries(world_id, entity_type, entity_id, conn_info.is_dm())
+        .await
+    {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::warn!(%world_id, %entity_id, error = %e, "Could not load custom fields");
+            Vec::new()
+        }
+    }
+}
+
+fn custom_field_error_response(e: CustomFieldError) -> ResponseResult {
+    match e {
+        CustomFieldError::WorldNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, "World not found")
+        }
+        CustomFieldError::Invalid(msg) => ResponseResult::error(ErrorCode::ValidationError, msg),
+        CustomFieldError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
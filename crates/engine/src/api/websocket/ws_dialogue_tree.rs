@@ -0,0 +1,216 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::dialogue_trees::{BeatLine, BeatStep, DialogueTreeError};
+
+use wrldbldr_domain::{CharacterId, DialogueTreeId, NarrativeEventId, PlayerCharacterId, WorldId};
+use wrldbldr_protocol::DialogueTreeRequest;
+
+pub(super) async fn handle_dialogue_tree_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: DialogueTreeRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // Players only ever see the line they're on
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before writing dialogue trees",
+        ));
+    };
+    let trees = &state.app.use_cases.dialogue_trees.manage;
+
+    let result = match request {
+        DialogueTreeRequest::ListDialogueTrees => {
+            trees.list(world_id).await.map(ResponseResult::success)
+        }
+
+        DialogueTreeRequest::CreateDialogueTree { data } => trees
+            .create(world_id, data)
+            .await
+            .map(ResponseResult::success),
+
+        DialogueTreeRequest::UpdateDialogueTree { tree_id, data } => {
+            let tree_id = parse_tree_id(&tree_id, request_id)?;
+            trees
+                .update(world_id, tree_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        DialogueTreeRequest::DeleteDialogueTree { tree_id } => {
+            let tree_id = parse_tree_id(&tree_id, request_id)?;
+            trees
+                .delete(world_id, tree_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+
+        DialogueTreeRequest::ResetDialogueTree { tree_id } => {
+            let tree_id = parse_tree_id(&tree_id, request_id)?;
+            trees
+                .reset(world_id, tree_id)
+                .await
+                .map(ResponseResult::success)
+        }
+    };
+
+    Ok(result.unwrap_or_else(dialogue_tree_error_response))
+}
+
+/// Play the NPC's scripted beat if it has one for the PC. Returns whether a
+/// beat started, in which case the caller skips the LLM.
+pub(super) async fn begin_npc_beat(
+    state: &WsState,
+    world_id: WorldId,
+    pc_id: PlayerCharacterId,
+    npc_id: CharacterId,
+) -> bool {
+    let beats = &state.app.use_cases.dialogue_trees.beats;
+    match beats.begin_for_npc(world_id, pc_id, npc_id).await {
+        Ok(Some(line)) => {
+            open_beat(state, world_id, pc_id, line).await;
+            true
+        }
+        Ok(None) => false,
+        Err(e) => {
+            // A broken tree shouldn't stop the NPC from talking
+            tracing::warn!(error = %e, npc_id = %npc_id, "Failed to start scripted dialogue");
+            false
+        }
+    }
+}
+
+/// Play a narrative event's scripted beats to the PCs in the world
+pub(super) async fn begin_event_beats(
+    state: &WsState,
+    world_id: WorldId,
+    event_id: NarrativeEventId,
+) {
+    let pc_ids: Vec<PlayerCharacterId> = state
+        .connections
+        .get_world_connections(world_id)
+        .await
+        .into_iter()
+        .filter_map(|conn| conn.pc_id)
+        .collect();
+    if pc_ids.is_empty() {
+        return;
+    }
+
+    let beats = &state.app.use_cases.dialogue_trees.beats;
+    match beats.begin_for_event(world_id, event_id, &pc_ids).await {
+        Ok(lines) => {
+            for (pc_id, line) in lines {
+                open_beat(state, world_id, pc_id, line).await;
+            }
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, event_id = %event_id, "Failed to start scripted dialogue");
+        }
+    }
+}
+
+/// Answer the scripted line a PC is on with one of its choices
+pub(super) async fn handle_beat_choice(
+    state: &WsState,
+    world_id: WorldId,
+    pc_id: PlayerCharacterId,
+    choice_id: Option<&str>,
+) -> Option<ServerMessage> {
+    let Some(choice_id) = choice_id else {
+        return Some(error_response(
+            "MISSING_PARAMS",
+            "Dialogue choices need a choice ID",
+        ));
+    };
+
+    let beats = &state.app.use_cases.dialogue_trees.beats;
+    let reply = match beats.choose(pc_id, choice_id).await {
+        Ok(BeatStep::Line(line)) => {
+            send_line(state, world_id, pc_id, line).await;
+            None
+        }
+        Ok(BeatStep::Ended {
+            conversation_id,
+            speaker_id,
+            speaker_name,
+        }) => {
+            let ended = ServerMessage::ConversationEnded {
+                npc_id: speaker_id.to_string(),
+                npc_name: speaker_name,
+                pc_id: pc_id.to_string(),
+                summary: None,
+                conversation_id: Some(conversation_id.to_string()),
+            };
+            state.connections.send_to_pc(pc_id, ended.clone()).await;
+            state.connections.broadcast_to_dms(world_id, ended).await;
+            None
+        }
+        Err(e @ DialogueTreeError::Invalid(_)) => {
+            Some(error_response("INVALID_CHOICE", &e.to_string()))
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to follow scripted dialogue");
+            Some(error_response("DIALOGUE_ERROR", &e.to_string()))
+        }
+    };
+    ws_spotlight::record_spotlight_action(state, world_id, pc_id).await;
+    ws_pacing::restart_turn_timer(state, world_id, pc_id).await;
+    reply
+}
+
+/// Tell the PC's players a beat started, then show its first line
+async fn open_beat(state: &WsState, world_id: WorldId, pc_id: PlayerCharacterId, line: BeatLine) {
+    let started = ServerMessage::ConversationStarted {
+        conversation_id: line.conversation_id.to_string(),
+        npc_id: line.speaker_id.to_string(),
+        npc_name: line.speaker_name.clone(),
+        npc_disposition: None,
+    };
+    state
+        .connections
+        .send_to_pc_controllers(pc_id, started)
+        .await;
+    send_line(state, world_id, pc_id, line).await;
+}
+
+/// Scripted lines need no approval; the DM sees them as they go out
+async fn send_line(state: &WsState, world_id: WorldId, pc_id: PlayerCharacterId, line: BeatLine) {
+    let msg = ServerMessage::DialogueResponse {
+        speaker_id: line.speaker_id.to_string(),
+        speaker_name: line.speaker_name,
+        text: line.text,
+        choices: line.choices,
+        conversation_id: Some(line.conversation_id.to_string()),
+    };
+    state.connections.send_to_pc(pc_id, msg.clone()).await;
+    state.connections.broadcast_to_dms(world_id, msg).await;
+}
+
+fn parse_tree_id(id: &str, request_id: &str) -> Result<DialogueTreeId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        DialogueTreeId::from_uuid,
+        "Invalid dialogue tree ID",
+    )
+}
+
+fn dialogue_tree_error_response(e: DialogueTreeError) -> ResponseResult {
+    match e {
+        DialogueTreeError::NotFound
+        | DialogueTreeError::SpeakerNotFound
+        | DialogueTreeError::EventNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        DialogueTreeError::Invalid(_) | DialogueTreeError::NoBeat => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        DialogueTreeError::Repo(e) => {
+            ResponseResult::error(ErrorCode::InternalError, e.to_string())
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use super::*;
+
+use crate::api::connections::{ConnectionInfo, WorldRole};
+use crate::use_cases::dice::DiceError;
+
+use wrldbldr_protocol::types::DiceRollData;
+use wrldbldr_protocol::DiceRequest;
+
+pub(super) async fn handle_dice_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: DiceRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    match request {
+        DiceRequest::Roll { world_id, formula } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            if conn_info.world_id != Some(world_id) {
+                return Ok(ResponseResult::error(
+                    ErrorCode::Forbidden,
+                    "Join the world before rolling dice",
+                ));
+            }
+            if conn_info.role == WorldRole::Spectator {
+                return Ok(ResponseResult::error(
+                    ErrorCode::Forbidden,
+                    "Spectators can't roll dice",
+                ));
+            }
+
+            match roll_and_share(state, conn_info, world_id, &formula).await {
+                Ok(roll) => Ok(ResponseResult::success(roll)),
+                Err(DiceError::InvalidFormula(e)) => Ok(ResponseResult::error(
+                    ErrorCode::ValidationError,
+                    e.to_string(),
+                )),
+                Err(DiceError::Repo(e)) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+    }
+}
+
+/// Free-form roll sent as `ClientMessage::RollDice`; the roller gets
+/// `DiceRolled` back like everyone else in their scene.
+pub(super) async fn handle_roll_dice(
+    state: &WsState,
+    connection_id: Uuid,
+    formula: String,
+) -> Option<ServerMessage> {
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+    let world_id = match conn_info.world_id {
+        Some(id) => id,
+        None => return Some(error_response("NOT_IN_WORLD", "Must join a world first")),
+    };
+    if conn_info.role == WorldRole::Spectator {
+        return Some(error_response("UNAUTHORIZED", "Spectators can't roll dice"));
+    }
+
+    match roll_and_share(state, &conn_info, world_id, &formula).await {
+        Ok(roll) => Some(ServerMessage::DiceRolled { roll }),
+        Err(DiceError::InvalidFormula(e)) => {
+            Some(error_response("INVALID_DICE_INPUT", &e.to_string()))
+        }
+        Err(DiceError::Repo(e)) => Some(error_response("ROLL_ERROR", &e.to_string())),
+    }
+}
+
+/// Roll for a connection and send the result to the rest of its scene
+async fn roll_and_share(
+    state: &WsState,
+    conn_info: &ConnectionInfo,
+    world_id: WorldId,
+    formula: &str,
+) -> Result<DiceRollData, DiceError> {
+    let fallback_name = if conn_info.is_dm() {
+        "DM"
+    } else {
+        conn_info.user_id.as_str()
+    };
+
+    let roll = state
+        .app
+        .use_cases
+        .dice
+        .roll
+        .execute(formula, conn_info.pc_id, fallback_name)
+        .await?;
+
+    // The roller gets the result directly
+    state
+        .connections
+        .broadcast_to_scene_except(
+            world_id,
+            conn_info.pc_id,
+            conn_info.connection_id,
+            ServerMessage::DiceRolled { roll: roll.clone() },
+        )
+        .await;
+    Ok(roll)
+}
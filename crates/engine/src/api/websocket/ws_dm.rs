@@ -150,6 +150,205 @@ pub(super) async fn handle_trigger_location_event(
     None
 }
 
+pub(super) async fn handle_make_noise(
+    state: &WsState,
+    connection_id: Uuid,
+    region_id: String,
+    description: String,
+    loudness: wrldbldr_protocol::types::LoudnessData,
+) -> Option<ServerMessage> {
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+    let world_id = match conn_info.world_id {
+        Some(id) => id,
+        None => return Some(error_response("NOT_IN_WORLD", "Must join a world first")),
+    };
+
+    let region_uuid = match parse_region_id(&region_id) {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+    let loudness = match loudness {
+        wrldbldr_protocol::types::LoudnessData::Loud => wrldbldr_domain::Loudness::Loud,
+        wrldbldr_protocol::types::LoudnessData::Deafening => wrldbldr_domain::Loudness::Deafening,
+        wrldbldr_protocol::types::LoudnessData::Unknown => {
+            return Some(error_response("BAD_REQUEST", "Unknown loudness"))
+        }
+    };
+
+    // Players make noise where their PC stands; the DM anywhere
+    if !conn_info.is_dm() {
+        let Some(pc_id) = conn_info.pc_id else {
+            return Some(error_response("NO_PC", "Must have a PC to make noise"));
+        };
+        if let Some(dead) = ws_mortality::reject_if_pc_dead(state, pc_id).await {
+            return Some(dead);
+        }
+        match state.app.entities.player_character.get(pc_id).await {
+            Ok(Some(pc)) if pc.current_region_id == Some(region_uuid) => {}
+            Ok(_) => {
+                return Some(error_response(
+                    "UNAUTHORIZED",
+                    "Your character can only make noise where they stand",
+                ))
+            }
+            Err(e) => return Some(error_response("NOISE_ERROR", &e.to_string())),
+        }
+    }
+
+    let spread = match state
+        .app
+        .use_cases
+        .location_events
+        .noise
+        .execute(world_id, region_uuid, description, loudness)
+        .await
+    {
+        Ok(spread) => spread,
+        Err(crate::use_cases::location_events::LocationEventError::RegionNotFound) => {
+            return Some(error_response("NOT_FOUND", "Region not found"))
+        }
+        Err(crate::use_cases::location_events::LocationEventError::InvalidNoise(msg)) => {
+            return Some(error_response("BAD_REQUEST", &msg))
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to make noise");
+            return Some(error_response("NOISE_ERROR", &e.to_string()));
+        }
+    };
+
+    for heard in &spread.heard_in {
+        for (pc_id, _) in &heard.pcs {
+            state
+                .connections
+                .send_to_pc(
+                    *pc_id,
+                    ServerMessage::NoiseHeard {
+                        region_id: heard.region_id.to_string(),
+                        from_region_id: spread.region_id.to_string(),
+                        from_region_name: spread.region_name.clone(),
+                        description: spread.description.clone(),
+                        muffled: heard.muffled,
+                    },
+                )
+                .await;
+        }
+    }
+
+    state
+        .connections
+        .broadcast_to_dms(
+            spread.world_id,
+            ServerMessage::NoiseReport {
+                region_id: spread.region_id.to_string(),
+                region_name: spread.region_name,
+                description: spread.description,
+                loudness: match spread.loudness {
+                    wrldbldr_domain::Loudness::Loud => wrldbldr_protocol::types::LoudnessData::Loud,
+                    wrldbldr_domain::Loudness::Deafening => {
+                        wrldbldr_protocol::types::LoudnessData::Deafening
+                    }
+                },
+                heard_in: spread
+                    .heard_in
+                    .into_iter()
+                    .map(|heard| wrldbldr_protocol::types::NoiseHeardInData {
+                        region_id: heard.region_id.to_string(),
+                        region_name: heard.region_name,
+                        muffled: heard.muffled,
+                        npc_names: heard.npc_names,
+                        pc_names: heard.pcs.into_iter().map(|(_, name)| name).collect(),
+                    })
+                    .collect(),
+            },
+        )
+        .await;
+
+    None
+}
+
+/// Maximum auto-dismiss time for a handout overlay (one hour).
+const MAX_OVERLAY_DURATION_SECS: u32 = 3600;
+
+pub(super) async fn handle_show_overlay(
+    state: &WsState,
+    connection_id: Uuid,
+    asset: String,
+    caption: Option<String>,
+    duration_secs: Option<u32>,
+) -> Option<ServerMessage> {
+    // Get connection info - only DMs can show overlays
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+
+    if let Err(e) = require_dm(&conn_info) {
+        return Some(e);
+    }
+
+    let world_id = match conn_info.world_id {
+        Some(id) => id,
+        None => return Some(error_response("NOT_IN_WORLD", "Must join a world first")),
+    };
+
+    let asset = asset.trim().to_string();
+    if asset.is_empty() {
+        return Some(error_response(
+            "INVALID_OVERLAY",
+            "Overlay asset is required",
+        ));
+    }
+    let caption = caption
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty());
+    let duration_secs = duration_secs
+        .filter(|secs| *secs > 0)
+        .map(|secs| secs.min(MAX_OVERLAY_DURATION_SECS));
+
+    let overlay_id = Uuid::new_v4().to_string();
+    tracing::info!(world_id = %world_id, overlay_id = %overlay_id, "DM showed overlay");
+
+    let msg = ServerMessage::ShowOverlay {
+        overlay_id,
+        asset,
+        caption,
+        duration_secs,
+    };
+    state.connections.broadcast_to_world(world_id, msg).await;
+
+    None
+}
+
+pub(super) async fn handle_dismiss_overlay(
+    state: &WsState,
+    connection_id: Uuid,
+    overlay_id: String,
+) -> Option<ServerMessage> {
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+
+    if let Err(e) = require_dm(&conn_info) {
+        return Some(e);
+    }
+
+    let world_id = match conn_info.world_id {
+        Some(id) => id,
+        None => return Some(error_response("NOT_IN_WORLD", "Must join a world first")),
+    };
+
+    state
+        .connections
+        .broadcast_to_world(world_id, ServerMessage::OverlayDismissed { overlay_id })
+        .await;
+
+    None
+}
+
 pub(super) async fn handle_share_npc_location(
     state: &WsState,
     connection_id: Uuid,
@@ -0,0 +1,121 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::drafts::DraftError;
+
+use wrldbldr_domain::ContentDraftId;
+use wrldbldr_protocol::DraftRequest;
+
+pub(super) async fn handle_draft_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: DraftRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // Drafts are a prep tool, scoped to the world the DM is in
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(conn_world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before working on drafts",
+        ));
+    };
+    let drafts = &state.app.use_cases.drafts;
+
+    let result = match request {
+        DraftRequest::ListDrafts {
+            entity_type,
+            entity_id,
+        } => {
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity ID")?;
+            drafts
+                .manage
+                .list(conn_world_id, entity_type, entity_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        DraftRequest::GetDraft { draft_id } => {
+            let draft_id = parse_draft_id_for_request(&draft_id, request_id)?;
+            drafts
+                .manage
+                .get(conn_world_id, draft_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        DraftRequest::AddDraftRevision {
+            world_id,
+            entity_type,
+            entity_id,
+            field,
+            text,
+            source,
+        } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+            if world_id != conn_world_id {
+                return Ok(ResponseResult::error(
+                    ErrorCode::Forbidden,
+                    "Join the world before drafting in it",
+                ));
+            }
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity ID")?;
+            drafts
+                .manage
+                .add_revision(world_id, entity_type, entity_id, field, text, source)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        DraftRequest::DiffDraftRevisions { draft_id, from, to } => {
+            let draft_id = parse_draft_id_for_request(&draft_id, request_id)?;
+            drafts
+                .manage
+                .diff(conn_world_id, draft_id, from, to)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        DraftRequest::PublishDraftRevision { draft_id, revision } => {
+            let draft_id = parse_draft_id_for_request(&draft_id, request_id)?;
+            drafts
+                .manage
+                .publish(conn_world_id, draft_id, revision)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        DraftRequest::DiscardDraft { draft_id } => {
+            let draft_id = parse_draft_id_for_request(&draft_id, request_id)?;
+            drafts
+                .manage
+                .discard(conn_world_id, draft_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+    };
+
+    Ok(result.unwrap_or_else(draft_error_response))
+}
+
+fn parse_draft_id_for_request(
+    id_str: &str,
+    request_id: &str,
+) -> Result<ContentDraftId, ServerMessage> {
+    parse_id_for_request(
+        id_str,
+        request_id,
+        ContentDraftId::from_uuid,
+        "Invalid draft ID",
+    )
+}
+
+fn draft_error_response(e: DraftError) -> ResponseResult {
+    match e {
+        DraftError::NotFound | DraftError::EntityNotFound(_) => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        DraftError::Invalid(_) => ResponseResult::error(ErrorCode::ValidationError, e.to_string()),
+        DraftError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::economy::EconomyError;
+
+use wrldbldr_domain::{ItemId, MarketModifierId};
+use wrldbldr_protocol::EconomyRequest;
+
+pub(super) async fn handle_economy_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: EconomyRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before managing its economy",
+        ));
+    };
+    let economy = &state.app.use_cases.economy.manage;
+
+    let result = match request {
+        EconomyRequest::ListMarketModifiers => economy
+            .list_modifiers(world_id)
+            .await
+            .map(ResponseResult::success),
+
+        EconomyRequest::CreateMarketModifier { data } => economy
+            .create_modifier(world_id, data)
+            .await
+            .map(ResponseResult::success),
+
+        EconomyRequest::UpdateMarketModifier { modifier_id, data } => {
+            let modifier_id = parse_modifier_id(&modifier_id, request_id)?;
+            economy
+                .update_modifier(world_id, modifier_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        EconomyRequest::DeleteMarketModifier { modifier_id } => {
+            let modifier_id = parse_modifier_id(&modifier_id, request_id)?;
+            economy
+                .delete_modifier(world_id, modifier_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+
+        EconomyRequest::GetEconomyOverview { location_id } => {
+            let location_id = parse_location_id_for_request(&location_id, request_id)?;
+            economy
+                .overview(world_id, location_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        EconomyRequest::SetItemBasePrice {
+            item_id,
+            base_price,
+        } => {
+            let item_id =
+                parse_id_for_request(&item_id, request_id, ItemId::from_uuid, "Invalid item ID")?;
+            economy
+                .set_base_price(world_id, item_id, base_price)
+                .await
+                .map(|item| {
+                    ResponseResult::success(serde_json::json!({
+                        "id": item.id.to_string(),
+                        "name": item.name,
+                        "base_price": item.base_price,
+                    }))
+                })
+        }
+    };
+
+    Ok(result.unwrap_or_else(economy_error_response))
+}
+
+fn parse_modifier_id(id: &str, request_id: &str) -> Result<MarketModifierId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        MarketModifierId::from_uuid,
+        "Invalid market modifier ID",
+    )
+}
+
+fn economy_error_response(e: EconomyError) -> ResponseResult {
+    match e {
+        EconomyError::ModifierNotFound
+        | EconomyError::LocationNotFound
+        | EconomyError::ItemNotFound => ResponseResult::error(ErrorCode::NotFound, e.to_string()),
+        EconomyError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        EconomyError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
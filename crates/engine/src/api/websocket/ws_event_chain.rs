@@ -1,7 +1,7 @@
 use super::*;
 use crate::api::connections::ConnectionInfo;
 use serde_json::json;
-use wrldbldr_domain::{ActId, NarrativeEventId};
+use wrldbldr_domain::{ActId, EntityType, NarrativeEventId};
 use wrldbldr_protocol::{ErrorCode, EventChainRequest, ResponseResult};
 
 pub(super) async fn handle_event_chain_request(
@@ -11,10 +11,22 @@ pub(super) async fn handle_event_chain_request(
     request: EventChainRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        EventChainRequest::ListEventChains { world_id } => {
+        EventChainRequest::ListEventChains { world_id, tags } => {
             let world_id_typed = parse_world_id_for_request(&world_id, request_id)?;
             match state.app.use_cases.narrative.chains.list(world_id_typed).await {
-                Ok(chains) => Ok(ResponseResult::success(json!(chains))),
+                Ok(mut chains) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        EntityType::EventChain,
+                        &tags,
+                        &mut chains,
+                        ws_tags::json_entry_id,
+                    )
+                    .await?;
+                    Ok(ResponseResult::success(json!(chains)))
+                }
                 Err(e) => Ok(ResponseResult::error(
                     ErrorCode::InternalError,
                     e.to_string(),
@@ -0,0 +1,110 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::factions::FactionError;
+
+use wrldbldr_domain::FactionId;
+use wrldbldr_protocol::FactionRequest;
+
+pub(super) async fn handle_faction_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: FactionRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // Factions and their reputations are the DM's to keep
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before managing its factions",
+        ));
+    };
+    let factions = &state.app.use_cases.factions.manage;
+
+    let result = match request {
+        FactionRequest::ListFactions => factions.list(world_id).await.map(ResponseResult::success),
+
+        FactionRequest::CreateFaction { data } => factions
+            .create(world_id, data)
+            .await
+            .map(ResponseResult::success),
+
+        FactionRequest::UpdateFaction { faction_id, data } => {
+            let faction_id = parse_faction_id(&faction_id, request_id)?;
+            factions
+                .update(world_id, faction_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        FactionRequest::DeleteFaction { faction_id } => {
+            let faction_id = parse_faction_id(&faction_id, request_id)?;
+            factions
+                .delete(world_id, faction_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+
+        FactionRequest::SetFactionMembers {
+            faction_id,
+            members,
+        } => {
+            let faction_id = parse_faction_id(&faction_id, request_id)?;
+            factions
+                .set_members(world_id, faction_id, members)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        FactionRequest::SetFactionReputation {
+            faction_id,
+            reputation,
+        } => {
+            let faction_id = parse_faction_id(&faction_id, request_id)?;
+            factions
+                .set_reputation(world_id, faction_id, reputation)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        FactionRequest::AdjustFactionReputation { faction_id, delta } => {
+            let faction_id = parse_faction_id(&faction_id, request_id)?;
+            factions
+                .adjust_reputation(world_id, faction_id, delta)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        FactionRequest::SetFactionRivalry {
+            faction_id,
+            rival_id,
+            rivals,
+        } => {
+            let faction_id = parse_faction_id(&faction_id, request_id)?;
+            let rival_id = parse_faction_id(&rival_id, request_id)?;
+            factions
+                .set_rivalry(world_id, faction_id, rival_id, rivals)
+                .await
+                .map(ResponseResult::success)
+        }
+    };
+
+    Ok(result.unwrap_or_else(faction_error_response))
+}
+
+fn parse_faction_id(id: &str, request_id: &str) -> Result<FactionId, ServerMessage> {
+    parse_id_for_request(id, request_id, FactionId::from_uuid, "Invalid faction ID")
+}
+
+fn faction_error_response(e: FactionError) -> ResponseResult {
+    match e {
+        FactionError::NotFound | FactionError::CharacterNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        FactionError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        FactionError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -0,0 +1,96 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::flashback::{FlashbackChange, FlashbackError};
+use crate::use_cases::staging::StagingError;
+
+use wrldbldr_domain::WorldId;
+use wrldbldr_protocol::FlashbackRequest;
+
+pub(super) async fn handle_flashback_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: FlashbackRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before running a flashback",
+        ));
+    };
+    let flashbacks = &state.app.use_cases.flashback.manage;
+
+    // Everyone can see a flashback is on; only the DM winds the clock back
+    if !matches!(request, FlashbackRequest::GetFlashback) {
+        require_dm_for_request(conn_info, request_id)?;
+    }
+
+    let result = match request {
+        FlashbackRequest::GetFlashback => flashbacks
+            .current(world_id)
+            .await
+            .map(ResponseResult::success),
+
+        FlashbackRequest::StartFlashback { data } => {
+            match flashbacks
+                .start(world_id, conn_info.user_id.clone(), data)
+                .await
+            {
+                Ok(change) => {
+                    let flashback = change.flashback.clone();
+                    broadcast_change(state, world_id, change).await;
+                    Ok(ResponseResult::success(flashback))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        FlashbackRequest::EndFlashback => match flashbacks.end(world_id).await {
+            Ok(change) => {
+                broadcast_change(state, world_id, change).await;
+                Ok(ResponseResult::success_empty())
+            }
+            Err(e) => Err(e),
+        },
+    };
+
+    Ok(result.unwrap_or_else(flashback_error_response))
+}
+
+/// Tell everyone in the world about the new date and the restaged region
+async fn broadcast_change(state: &WsState, world_id: WorldId, change: FlashbackChange) {
+    let messages = [
+        ServerMessage::FlashbackChanged {
+            flashback: change.flashback,
+        },
+        ServerMessage::GameTimeUpdated {
+            game_time: crate::use_cases::time::game_time_to_protocol(&change.game_time),
+        },
+        ServerMessage::StagingReady {
+            region_id: change.staging.region_id.to_string(),
+            npcs_present: change.staging.npcs_present,
+            visual_state: change.staging.visual_state,
+        },
+    ];
+    for msg in messages {
+        state.connections.broadcast_to_world(world_id, msg).await;
+    }
+}
+
+fn flashback_error_response(e: FlashbackError) -> ResponseResult {
+    match e {
+        FlashbackError::WorldNotFound
+        | FlashbackError::RegionNotFound
+        | FlashbackError::Staging(StagingError::WorldNotFound)
+        | FlashbackError::Staging(StagingError::RegionNotFound) => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        FlashbackError::Invalid(_) | FlashbackError::Staging(StagingError::Validation(_)) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        FlashbackError::Repo(_) | FlashbackError::Staging(StagingError::Repo(_)) => {
+            ResponseResult::error(ErrorCode::InternalError, e.to_string())
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::assets::GalleryError;
+
+use wrldbldr_domain::{AssetId, AssetType, BatchId, GalleryFilter};
+use wrldbldr_protocol::{GalleryFilterData, GalleryRequest};
+
+pub(super) async fn handle_gallery_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: GalleryRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // Gallery management is a prep tool, scoped to the world the DM is in
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before browsing its gallery",
+        ));
+    };
+    let gallery = &state.app.use_cases.assets.gallery;
+
+    let result = match request {
+        GalleryRequest::ListGallery { filter } => {
+            let filter = parse_gallery_filter(filter, request_id)?;
+            gallery
+                .list(world_id, filter)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        GalleryRequest::SetAssetsFavorite {
+            asset_ids,
+            favorite,
+        } => {
+            let asset_ids = parse_asset_ids_for_request(&asset_ids, request_id)?;
+            gallery
+                .set_favorite(world_id, &asset_ids, favorite)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        GalleryRequest::TagAssets {
+            asset_ids,
+            add,
+            remove,
+        } => {
+            let asset_ids = parse_asset_ids_for_request(&asset_ids, request_id)?;
+            gallery
+                .tag(world_id, &asset_ids, &add, &remove)
+                .await
+                .map(ResponseResult::success)
+        }
+    };
+
+    Ok(result.unwrap_or_else(gallery_error_response))
+}
+
+fn parse_gallery_filter(
+    filter: GalleryFilterData,
+    request_id: &str,
+) -> Result<GalleryFilter, ServerMessage> {
+    let entity_id = filter
+        .entity_id
+        .map(|id| {
+            parse_uuid_for_request(&id, request_id, "Invalid entity ID").map(|id| id.to_string())
+        })
+        .transpose()?;
+    let asset_type = filter
+        .asset_type
+        .map(|value| match value.parse() {
+            Ok(AssetType::Unknown) | Err(_) => Err(ServerMessage::Response {
+                request_id: request_id.to_string(),
+                result: ResponseResult::error(
+                    ErrorCode::BadRequest,
+                    format!("Unknown asset type: {}", value),
+                ),
+            }),
+            Ok(asset_type) => Ok(asset_type),
+        })
+        .transpose()?;
+    let batch_id = filter
+        .batch_id
+        .map(|id| parse_id_for_request(&id, request_id, BatchId::from_uuid, "Invalid batch ID"))
+        .transpose()?;
+
+    Ok(GalleryFilter {
+        entity_type: filter.entity_type,
+        entity_id,
+        asset_type,
+        batch_id,
+        tag: filter.tag,
+        favorites_only: filter.favorites_only,
+    })
+}
+
+fn parse_asset_ids_for_request(
+    ids: &[String],
+    request_id: &str,
+) -> Result<Vec<AssetId>, ServerMessage> {
+    ids.iter()
+        .map(|id| parse_id_for_request(id, request_id, AssetId::from_uuid, "Invalid asset ID"))
+        .collect()
+}
+
+fn gallery_error_response(e: GalleryError) -> ResponseResult {
+    match e {
+        GalleryError::NotFound(_) => ResponseResult::error(ErrorCode::NotFound, e.to_string()),
+        GalleryError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        GalleryError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
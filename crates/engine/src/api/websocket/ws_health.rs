@@ -0,0 +1,51 @@
+use super::*;
+
+use crate::use_cases::health::{status_to_message, Dependency};
+
+/// Re-check every dependency on request (the DM's "Retry Now").
+///
+/// Changes are broadcast to all DMs as usual; the requester always gets
+/// ComfyUI's current status back, since that is what the retry was for.
+pub(super) async fn handle_check_health(
+    state: &WsState,
+    connection_id: Uuid,
+) -> Option<ServerMessage> {
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+
+    if let Err(e) = require_dm(&conn_info) {
+        return Some(e);
+    }
+
+    let health = &state.app.use_cases.health.check;
+    for status in health.changes().await {
+        state
+            .connections
+            .broadcast_to_all_dms(status_to_message(&status))
+            .await;
+    }
+
+    health
+        .last_reported()
+        .await
+        .iter()
+        .find(|status| status.dependency == Dependency::ComfyUI)
+        .map(status_to_message)
+}
+
+/// Tell a DM who just joined the last known status of every dependency.
+pub(super) async fn send_dependency_status(state: &WsState, connection_id: Uuid) {
+    for status in state.app.use_cases.health.check.last_reported().await {
+        // Failures are logged by send_critical; stop if the DM went away
+        if state
+            .connections
+            .send_critical(connection_id, status_to_message(&status))
+            .await
+            .is_err()
+        {
+            return;
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::injuries::InjuryError;
+
+use wrldbldr_domain::InjuryId;
+use wrldbldr_protocol::InjuryRequest;
+
+pub(super) async fn handle_injury_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: InjuryRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before managing injuries",
+        ));
+    };
+    let injuries = &state.app.use_cases.injury.manage;
+
+    let result = match request {
+        InjuryRequest::ListInjuries {
+            pc_id,
+            include_healed,
+        } => {
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            injuries
+                .list_for_pc(world_id, pc_id, include_healed)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        InjuryRequest::InflictInjury { pc_id, data } => {
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            injuries
+                .inflict(world_id, pc_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        InjuryRequest::UpdateInjury { injury_id, data } => {
+            let injury_id = parse_injury_id(&injury_id, request_id)?;
+            injuries
+                .update(world_id, injury_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        InjuryRequest::TreatInjury {
+            injury_id,
+            succeeded,
+        } => {
+            let injury_id = parse_injury_id(&injury_id, request_id)?;
+            injuries
+                .treat(world_id, injury_id, succeeded)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        InjuryRequest::DeleteInjury { injury_id } => {
+            let injury_id = parse_injury_id(&injury_id, request_id)?;
+            injuries
+                .delete(world_id, injury_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+    };
+
+    Ok(result.unwrap_or_else(injury_error_response))
+}
+
+/// Heal the world's injuries after game time advanced by `minutes`, and
+/// tell its DMs which ones healed. `rested_pc_id` is the PC whose rest the
+/// advance was, if any.
+pub(super) async fn heal_after_time_advance(
+    state: &WsState,
+    world_id: WorldId,
+    minutes: u32,
+    rested_pc_id: Option<PlayerCharacterId>,
+) {
+    match state
+        .app
+        .use_cases
+        .injury
+        .manage
+        .after_time_advance(world_id, minutes, rested_pc_id)
+        .await
+    {
+        Ok(injuries) if injuries.is_empty() => {}
+        Ok(injuries) => {
+            let msg = ServerMessage::InjuriesHealed {
+                world_id: world_id.to_string(),
+                injuries,
+            };
+            state.connections.broadcast_to_dms(world_id, msg).await;
+        }
+        Err(e) => {
+            tracing::warn!(world_id = %world_id, error = %e, "Failed to heal injuries");
+        }
+    }
+}
+
+fn parse_pc_id(id: &str, request_id: &str) -> Result<PlayerCharacterId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        PlayerCharacterId::from_uuid,
+        "Invalid PC ID",
+    )
+}
+
+fn parse_injury_id(id: &str, request_id: &str) -> Result<InjuryId, ServerMessage> {
+    parse_id_for_request(id, request_id, InjuryId::from_uuid, "Invalid injury ID")
+}
+
+fn injury_error_response(e: InjuryError) -> ResponseResult {
+    match e {
+        InjuryError::InjuryNotFound
+        | InjuryError::PlayerCharacterNotFound
+        | InjuryError::ChallengeNotFound
+        | InjuryError::WorldNotFound => ResponseResult::error(ErrorCode::NotFound, e.to_string()),
+        InjuryError::Conflict(_) => ResponseResult::error(ErrorCode::Conflict, e.to_string()),
+        InjuryError::Invalid(_) => ResponseResult::error(ErrorCode::ValidationError, e.to_string()),
+        InjuryError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -8,7 +8,52 @@ use std::{
 };
 
 mod approval_suggestions;
+mod broadsheet;
+mod character_sheet;
+mod chronology;
+mod crowds;
+mod custom_fields;
+mod dice;
+mod drafts;
+mod duplicate;
+mod economy;
+mod fast_travel;
+mod features;
+mod gallery;
+mod interactions;
+mod knowledge_sharing;
+mod known_locations;
+mod library;
+mod lighting;
+mod locale;
+mod location_hierarchy;
+mod locks;
+mod mail;
+mod map_markers;
+mod mentions;
+mod multi_pc;
+mod name_generators;
+mod noise;
+mod npc_drafts;
+mod overlay;
+mod personal_quests;
+mod plugins;
+mod property;
+mod region_hotspots;
+mod retract_action;
+mod safety;
+mod scripts;
+mod secrets;
 mod staging_approval;
 mod staging_prestage;
 mod staging_regenerate;
+mod tags;
+mod templates;
+mod theme;
 mod time;
+mod trade;
+mod transcription;
+mod tutorial;
+mod typography;
+mod world_map;
+mod world_simulation;
@@ -42,6 +42,7 @@ async fn when_dm_accepts_approval_suggestion_then_marks_complete_and_broadcasts_
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -58,6 +59,7 @@ async fn when_dm_accepts_approval_suggestion_then_marks_complete_and_broadcasts_
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Spectator,
+            user_id: "spectator-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -102,6 +104,8 @@ async fn when_dm_accepts_approval_suggestion_then_marks_complete_and_broadcasts_
             game_time: None,
             topics: vec![],
             conversation_id: None,
+            safety_warnings: vec![],
+            merged_lines: vec![],
         },
     );
 
@@ -193,6 +197,7 @@ async fn when_dm_rejects_approval_suggestion_then_marks_failed_and_does_not_broa
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -209,6 +214,7 @@ async fn when_dm_rejects_approval_suggestion_then_marks_failed_and_does_not_broa
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Spectator,
+            user_id: "spectator-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -251,6 +257,8 @@ async fn when_dm_rejects_approval_suggestion_then_marks_failed_and_does_not_broa
             game_time: None,
             topics: vec![],
             conversation_id: None,
+            safety_warnings: vec![],
+            merged_lines: vec![],
         },
     );
 
@@ -320,6 +328,7 @@ async fn when_dm_modifies_approval_suggestion_then_marks_complete_and_broadcasts
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -336,6 +345,7 @@ async fn when_dm_modifies_approval_suggestion_then_marks_complete_and_broadcasts
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Spectator,
+            user_id: "spectator-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -378,6 +388,8 @@ async fn when_dm_modifies_approval_suggestion_then_marks_complete_and_broadcasts
             game_time: None,
             topics: vec![],
             conversation_id: None,
+            safety_warnings: vec![],
+            merged_lines: vec![],
         },
     );
 
@@ -430,3 +442,311 @@ async fn when_dm_modifies_approval_suggestion_then_marks_complete_and_broadcasts
 
     server.abort();
 }
+
+#[tokio::test]
+async fn when_dm_bulk_rejects_a_player_action_then_only_its_approvals_are_failed() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+    world_repo.expect_save().returning(|_world| Ok(()));
+
+    let repos = TestAppRepos::new(world_repo);
+
+    let queue = RecordingApprovalQueue::default();
+    let queue_port: Arc<dyn QueuePort> = Arc::new(queue.clone());
+    let app = build_test_app_with_ports(repos, now, queue_port, Arc::new(NoopLlm));
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    // Two approvals from one player action, one from another.
+    let retracted_action = Uuid::new_v4();
+    let approval = |source_action_id: Uuid| wrldbldr_domain::ApprovalRequestData {
+        world_id,
+        source_action_id,
+        decision_type: wrldbldr_domain::ApprovalDecisionType::NpcResponse,
+        urgency: wrldbldr_domain::ApprovalUrgency::AwaitingPlayer,
+        pc_id: None,
+        npc_id: Some(CharacterId::new()),
+        npc_name: "NPC".to_string(),
+        proposed_dialogue: "Hello there".to_string(),
+        internal_reasoning: "".to_string(),
+        proposed_tools: vec![],
+        retry_count: 0,
+        challenge_suggestion: None,
+        narrative_event_suggestion: None,
+        challenge_outcome: None,
+        player_dialogue: None,
+        scene_id: None,
+        location_id: None,
+        game_time: None,
+        topics: vec![],
+        conversation_id: None,
+        safety_warnings: vec![],
+        merged_lines: vec![],
+    };
+    let first = Uuid::new_v4();
+    let second = Uuid::new_v4();
+    let other = Uuid::new_v4();
+    queue.insert_approval(first, approval(retracted_action));
+    queue.insert_approval(second, approval(retracted_action));
+    queue.insert_approval(other, approval(Uuid::new_v4()));
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::BulkApprovalDecision {
+            selection: wrldbldr_protocol::ApprovalSelection::SourceAction {
+                action_id: retracted_action.to_string(),
+            },
+            decision: wrldbldr_protocol::BulkApprovalDecision::Reject {
+                feedback: "Action retracted".to_string(),
+            },
+        },
+    )
+    .await;
+
+    let msg = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::BulkApprovalApplied { .. })
+    })
+    .await;
+    match msg {
+        ServerMessage::BulkApprovalApplied {
+            mut decided,
+            failed,
+            accepted,
+        } => {
+            decided.sort();
+            let mut expected = vec![first.to_string(), second.to_string()];
+            expected.sort();
+            assert_eq!(decided, expected);
+            assert!(failed.is_empty());
+            assert!(!accepted);
+        }
+        other => panic!("expected BulkApprovalApplied, got: {:?}", other),
+    }
+
+    assert!(queue.failed_contains(first));
+    assert!(queue.failed_contains(second));
+    assert!(!queue.failed_contains(other));
+    assert!(!queue.completed_contains(other));
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn when_dm_rejects_with_feedback_then_npc_response_is_regenerated() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+    world_repo.expect_save().returning(|_world| Ok(()));
+
+    let repos = TestAppRepos::new(world_repo);
+
+    let queue = RecordingApprovalQueue::default();
+    let queue_port: Arc<dyn QueuePort> = Arc::new(queue.clone());
+    let app = build_test_app_with_ports(repos, now, queue_port, Arc::new(NoopLlm));
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+    for (ws, role, user_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user"),
+        (
+            &mut spectator_ws,
+            ProtoWorldRole::Spectator,
+            "spectator-user",
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id: None,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // The LLM request the rejected response answered.
+    let action_id = Uuid::new_v4();
+    let request_id = Uuid::new_v4();
+    queue.insert_llm_request(
+        request_id,
+        wrldbldr_domain::LlmRequestData {
+            request_type: wrldbldr_domain::LlmRequestType::NpcResponse {
+                action_item_id: action_id,
+            },
+            world_id,
+            pc_id: None,
+            prompt: Some(wrldbldr_domain::GamePromptRequest {
+                world_id: Some(world_id.to_string()),
+                player_action: wrldbldr_domain::PlayerActionContext {
+                    action_type: "talk".to_string(),
+                    target: None,
+                    dialogue: Some("Where is the mayor?".to_string()),
+                    speakers: vec![],
+                },
+                scene_context: wrldbldr_domain::SceneContext {
+                    scene_name: "Square".to_string(),
+                    location_name: "Town".to_string(),
+                    time_context: "Morning".to_string(),
+                    present_characters: vec![],
+                    region_items: vec![],
+                    lighting: None,
+                    recent_sounds: vec![],
+                },
+                directorial_notes: "Respond as the guard.".to_string(),
+                conversation_history: vec![],
+                responding_character: wrldbldr_domain::CharacterContext {
+                    character_id: None,
+                    name: "Guard".to_string(),
+                    aliases: vec![],
+                    archetype: "NPC".to_string(),
+                    current_mood: None,
+                    disposition_toward_player: None,
+                    motivations: None,
+                    social_stance: None,
+                    relationship_to_player: None,
+                    available_expressions: None,
+                    available_actions: None,
+                },
+                active_challenges: vec![],
+                active_narrative_events: vec![],
+                alias_references: vec![],
+                context_budget: None,
+                scene_id: None,
+                location_id: None,
+                game_time: None,
+            }),
+            suggestion_context: None,
+            callback_id: action_id.to_string(),
+            conversation_id: None,
+            retry_count: 0,
+            merged_lines: vec![],
+        },
+    );
+
+    let approval_id = Uuid::new_v4();
+    queue.insert_approval(
+        approval_id,
+        wrldbldr_domain::ApprovalRequestData {
+            world_id,
+            source_action_id: request_id,
+            decision_type: wrldbldr_domain::ApprovalDecisionType::NpcResponse,
+            urgency: wrldbldr_domain::ApprovalUrgency::AwaitingPlayer,
+            pc_id: None,
+            npc_id: None,
+            npc_name: "Guard".to_string(),
+            proposed_dialogue: "The mayor is dead.".to_string(),
+            internal_reasoning: "".to_string(),
+            proposed_tools: vec![],
+            retry_count: 0,
+            challenge_suggestion: None,
+            narrative_event_suggestion: None,
+            challenge_outcome: None,
+            player_dialogue: Some("Where is the mayor?".to_string()),
+            scene_id: None,
+            location_id: None,
+            game_time: None,
+            topics: vec![],
+            conversation_id: None,
+            safety_warnings: vec![],
+            merged_lines: vec![],
+        },
+    );
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::ApprovalDecision {
+            request_id: approval_id.to_string(),
+            decision: wrldbldr_protocol::ApprovalDecision::Reject {
+                feedback: "Don't spoil the murder yet".to_string(),
+            },
+        },
+    )
+    .await;
+
+    // The table sees the NPC thinking again.
+    let msg = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::LLMProcessing { .. })
+    })
+    .await;
+    assert!(
+        matches!(msg, ServerMessage::LLMProcessing { action_id: got } if got == action_id.to_string())
+    );
+    assert!(queue.failed_contains(approval_id));
+
+    let retry = queue
+        .llm_requests()
+        .into_iter()
+        .find(|request| request.retry_count == 1)
+        .expect("retry request queued");
+    assert_eq!(retry.callback_id, action_id.to_string());
+    let notes = retry
+        .prompt
+        .expect("retry keeps the prompt")
+        .directorial_notes;
+    assert!(notes.starts_with("Respond as the guard."));
+    assert!(notes.contains("Don't spoil the murder yet"));
+    assert!(notes.contains("The mayor is dead."));
+
+    server.abort();
+}
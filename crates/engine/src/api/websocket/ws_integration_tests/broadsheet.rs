@@ -0,0 +1,201 @@
+use super::*;
+
+use wrldbldr_domain::{Broadsheet, BroadsheetArticle};
+use wrldbldr_protocol::{
+    types::BroadsheetData, BroadsheetRequest, ErrorCode, RequestPayload, ResponseResult,
+};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: BroadsheetRequest) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload: RequestPayload::Broadsheet(payload),
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_publishes_the_draft_then_players_get_the_broadsheet() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let location_id = wrldbldr_domain::LocationId::new();
+    let alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", location_id, now);
+    let alice_id = alice.id;
+
+    let draft = Broadsheet::new(
+        world_id,
+        1,
+        "The Harbour Crier",
+        vec![BroadsheetArticle::new(
+            "Fire at the docks",
+            "Three warehouses lost.",
+        )],
+        now - chrono::Duration::days(7),
+        now,
+        now,
+    )
+    .unwrap();
+    let draft_id = draft.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(alice.clone())));
+
+    let stored = Arc::new(Mutex::new(vec![draft]));
+    let fetched = stored.clone();
+    repos
+        .broadsheet_repo
+        .expect_get()
+        .returning(move |id| Ok(fetched.lock().unwrap().iter().find(|b| b.id == id).cloned()));
+    let saved = stored.clone();
+    repos.broadsheet_repo.expect_save().returning(move |issue| {
+        let mut saved = saved.lock().unwrap();
+        saved.retain(|b| b.id != issue.id);
+        saved.push(issue.clone());
+        Ok(())
+    });
+    let listed = stored.clone();
+    repos
+        .broadsheet_repo
+        .expect_list_in_world()
+        .returning(move |_| Ok(listed.lock().unwrap().clone()));
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut alice_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut alice_ws,
+            ProtoWorldRole::Player,
+            "alice-user",
+            Some(*alice_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // Players do not see drafts, nor publish them
+    let early = request(
+        &mut alice_ws,
+        "alice-list",
+        BroadsheetRequest::ListBroadsheets,
+    )
+    .await;
+    let issues = match early {
+        ResponseResult::Success {
+            data: Some(data), ..
+        } => serde_json::from_value::<Vec<BroadsheetData>>(data).unwrap(),
+        other => panic!("expected broadsheets, got: {:?}", other),
+    };
+    assert!(issues.is_empty());
+
+    let forged = request(
+        &mut alice_ws,
+        "alice-publish",
+        BroadsheetRequest::PublishBroadsheet {
+            broadsheet_id: draft_id.to_string(),
+        },
+    )
+    .await;
+    assert!(matches!(
+        forged,
+        ResponseResult::Error {
+            code: ErrorCode::Unauthorized,
+            ..
+        }
+    ));
+
+    let published = request(
+        &mut dm_ws,
+        "publish",
+        BroadsheetRequest::PublishBroadsheet {
+            broadsheet_id: draft_id.to_string(),
+        },
+    )
+    .await;
+    assert!(matches!(published, ResponseResult::Success { .. }));
+
+    let handed_out = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::BroadsheetPublished { .. })
+    })
+    .await;
+    assert!(matches!(
+        handed_out,
+        ServerMessage::BroadsheetPublished { broadsheet }
+            if broadsheet.published && broadsheet.articles[0].headline == "Fire at the docks"
+    ));
+
+    let again = request(
+        &mut dm_ws,
+        "publish-again",
+        BroadsheetRequest::PublishBroadsheet {
+            broadsheet_id: draft_id.to_string(),
+        },
+    )
+    .await;
+    assert!(matches!(
+        again,
+        ResponseResult::Error {
+            code: ErrorCode::Conflict,
+            ..
+        }
+    ));
+
+    server.abort();
+}
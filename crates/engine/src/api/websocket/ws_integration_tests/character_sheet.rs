@@ -0,0 +1,73 @@
+use super::*;
+
+use wrldbldr_protocol::{CharacterSheetRequest, RequestPayload, ResponseResult};
+
+#[tokio::test]
+async fn when_player_requests_world_schema_then_creation_steps_include_allocation() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now)
+        .with_rule_system(wrldbldr_domain::RuleSystemConfig::dnd_5e());
+    world.id = world_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let repos = TestAppRepos::new(world_repo);
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+    let mut ws = ws_connect(addr).await;
+
+    ws_send_client(
+        &mut ws,
+        &ClientMessage::Request {
+            request_id: "schema-1".to_string(),
+            payload: RequestPayload::CharacterSheet(CharacterSheetRequest::GetWorldSchema {
+                world_id: world_id.to_string(),
+            }),
+        },
+    )
+    .await;
+
+    let response = ws_expect_message(
+        &mut ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "schema-1"),
+    )
+    .await;
+    let data = match response {
+        ServerMessage::Response {
+            result: ResponseResult::Success { data },
+            ..
+        } => data.expect("schema data"),
+        other => panic!("unexpected response: {:?}", other),
+    };
+
+    assert_eq!(data["system_id"], "dnd5e");
+    let schema: wrldbldr_domain::CharacterSheetSchema =
+        serde_json::from_value(data["schema"].clone()).expect("schema parses");
+    let abilities = schema
+        .creation_steps
+        .iter()
+        .find(|s| s.id == "abilities")
+        .expect("abilities step");
+    let allocation = abilities.allocation.as_ref().expect("ability allocation");
+    assert_eq!(allocation.field_ids.len(), 6);
+    assert_eq!(allocation.point_buy.as_ref().map(|p| p.budget), Some(27));
+    assert!(allocation.arrays[0].matches(&[8, 10, 12, 13, 14, 15]));
+
+    server.abort();
+}
@@ -0,0 +1,189 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use chrono::TimeZone;
+use wrldbldr_domain::{CampbellArchetype, Character, GameTime, Lore, LoreCategory, WorldFeatures};
+use wrldbldr_protocol::types::{ChronologyIssueKindData, ChronologyReportData, LifeStageData};
+use wrldbldr_protocol::{ChronologyRequest, RequestPayload, ResponseResult, TimeRequest};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+fn success<T: serde::de::DeserializeOwned>(result: ResponseResult) -> T {
+    match result {
+        ResponseResult::Success { data: Some(data) } => serde_json::from_value(data).unwrap(),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_sets_a_birthdate_then_ages_errors_and_time_skips_follow_it() {
+    let now = chrono::Utc::now();
+    let mut world = wrldbldr_domain::World::new("Varn", "desc", now);
+    world.game_time =
+        GameTime::starting_at(chrono::Utc.with_ymd_and_hms(1490, 3, 1, 8, 0, 0).unwrap());
+    world.features = WorldFeatures {
+        age_progression: true,
+        ..Default::default()
+    };
+    let world_id = world.id;
+
+    let pell = Character::new(world_id, "Pell", CampbellArchetype::Herald);
+    let pell_id = pell.id;
+    let siege = Lore::new(world_id, "The Siege of Varn", LoreCategory::Historical, now)
+        .with_chunk("Pell carried word to the besieged gate.")
+        .with_event_year(1460);
+    let siege_id = siege.id;
+
+    // The world and Pell are stored, so time advances and birthdates stick
+    let stored_world = Arc::new(Mutex::new(world));
+    let mut world_repo = MockWorldRepo::new();
+    let fetched = stored_world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(fetched.lock().unwrap().clone())));
+    let saved = stored_world.clone();
+    world_repo.expect_save().returning(move |w| {
+        *saved.lock().unwrap() = w.clone();
+        Ok(())
+    });
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let characters = Arc::new(Mutex::new(vec![pell]));
+    // Replace the default empty world surface
+    repos.character_repo.checkpoint();
+    let listed = characters.clone();
+    repos
+        .character_repo
+        .expect_list_in_world()
+        .returning(move |_| Ok(listed.lock().unwrap().clone()));
+    let fetched = characters.clone();
+    repos
+        .character_repo
+        .expect_get()
+        .returning(move |id| Ok(fetched.lock().unwrap().iter().find(|c| c.id == id).cloned()));
+    let saved = characters.clone();
+    repos.character_repo.expect_save().returning(move |c| {
+        *saved.lock().unwrap() = vec![c.clone()];
+        Ok(())
+    });
+    repos
+        .lore_repo
+        .expect_list_for_world()
+        .returning(move |_| Ok(vec![siege.clone()]));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    // Born after the siege that mentions him
+    let report: ChronologyReportData = success(
+        request(
+            &mut dm_ws,
+            "chronology-1",
+            RequestPayload::Chronology(ChronologyRequest::SetBirthDate {
+                character_id: pell_id.to_string(),
+                birth_date: Some("1473-02-01".to_string()),
+            }),
+        )
+        .await,
+    );
+    assert_eq!(report.current_date, "1490-03-01");
+    assert_eq!(report.characters[0].age, Some(17));
+    assert_eq!(
+        report.characters[0].life_stage,
+        Some(LifeStageData::Adolescent)
+    );
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(
+        report.issues[0].kind,
+        ChronologyIssueKindData::MentionedBeforeBirth
+    );
+    assert_eq!(report.issues[0].lore_id, Some(siege_id.to_string()));
+
+    // Malformed birthdates are rejected
+    let invalid = request(
+        &mut dm_ws,
+        "chronology-2",
+        RequestPayload::Chronology(ChronologyRequest::SetBirthDate {
+            character_id: pell_id.to_string(),
+            birth_date: Some("spring 1473".to_string()),
+        }),
+    )
+    .await;
+    assert!(matches!(invalid, ResponseResult::Error { .. }));
+
+    // A year-long skip tells the DM Pell came of age
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "chronology-3".to_string(),
+            payload: RequestPayload::Time(TimeRequest::AdvanceGameTime {
+                world_id: world_id.to_string(),
+                hours: 365 * 24,
+            }),
+        },
+    )
+    .await;
+    let aged = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::CharactersAged { .. })
+    })
+    .await;
+    let ServerMessage::CharactersAged { progressions, .. } = aged else {
+        unreachable!()
+    };
+    assert_eq!(progressions.len(), 1);
+    assert_eq!(
+        (progressions[0].previous_age, progressions[0].new_age),
+        (Some(17), 18)
+    );
+    assert!(progressions[0].stage_changed);
+
+    server.abort();
+}
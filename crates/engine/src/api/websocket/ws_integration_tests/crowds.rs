@@ -0,0 +1,187 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{Crowd, DispositionLevel, Location, LocationType, Region};
+use wrldbldr_protocol::types::{CrowdData, CrowdInputData};
+use wrldbldr_protocol::{CrowdPresenceData, CrowdRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Send a crowd change and collect the crowds announced for its region,
+/// which arrive before the response
+async fn change(
+    ws: &mut TestWs,
+    request_id: &str,
+    request: CrowdRequest,
+) -> (Vec<CrowdPresenceData>, CrowdData) {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload: RequestPayload::Crowd(request),
+        },
+    )
+    .await;
+
+    let announced = match ws_expect_message(ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::RegionCrowdsChanged { .. })
+    })
+    .await
+    {
+        ServerMessage::RegionCrowdsChanged { crowds_present, .. } => crowds_present,
+        other => panic!("unexpected message: {:?}", other),
+    };
+    let crowd = match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response {
+            result: ResponseResult::Success { data: Some(data) },
+            ..
+        } => serde_json::from_value(data).unwrap(),
+        other => panic!("unexpected message: {:?}", other),
+    };
+    (announced, crowd)
+}
+
+#[tokio::test]
+async fn when_dm_stages_a_crowd_then_the_region_sees_it_without_an_exact_count() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Varn", "desc", now);
+    let world_id = world.id;
+    let location = Location::new(world_id, "Port Varn", LocationType::Exterior);
+    let region = Region::new(location.id, "The Docks");
+    let region_id = region.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .location_repo
+        .expect_get_region()
+        .returning(move |_| Ok(Some(region.clone())));
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |_| Ok(Some(location.clone())));
+    repos.crowd_repo.checkpoint();
+    let stored: Arc<Mutex<Vec<Crowd>>> = Arc::new(Mutex::new(Vec::new()));
+    let saved = stored.clone();
+    repos.crowd_repo.expect_save().returning(move |c| {
+        let mut crowds = saved.lock().unwrap();
+        crowds.retain(|existing| existing.id != c.id);
+        crowds.push(c.clone());
+        Ok(())
+    });
+    let fetched = stored.clone();
+    repos
+        .crowd_repo
+        .expect_get()
+        .returning(move |id| Ok(fetched.lock().unwrap().iter().find(|c| c.id == id).cloned()));
+    let listed = stored.clone();
+    repos
+        .crowd_repo
+        .expect_list_in_region()
+        .returning(move |_| Ok(listed.lock().unwrap().clone()));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    // A new crowd is staged straight away
+    let (announced, created) = change(
+        &mut dm_ws,
+        "crowds-1",
+        CrowdRequest::CreateCrowd {
+            region_id: region_id.to_string(),
+            data: CrowdInputData {
+                name: "dock workers".to_string(),
+                description: "Hauling crates off a Kethic galley.".to_string(),
+                approximate_count: 40,
+                disposition: DispositionLevel::Suspicious,
+            },
+        },
+    )
+    .await;
+    assert_eq!(created.approximate_count, 40);
+    assert_eq!(announced.len(), 1);
+    assert_eq!(announced[0].size_label, "dozens");
+    assert_eq!(announced[0].disposition, DispositionLevel::Suspicious);
+
+    // Unstaging empties the region
+    let (announced, unstaged) = change(
+        &mut dm_ws,
+        "crowds-2",
+        CrowdRequest::SetCrowdPresent {
+            crowd_id: created.id.clone(),
+            present: false,
+        },
+    )
+    .await;
+    assert!(!unstaged.is_present);
+    assert!(announced.is_empty());
+
+    // A lone sailor is not a crowd
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "crowds-3".to_string(),
+            payload: RequestPayload::Crowd(CrowdRequest::UpdateCrowd {
+                crowd_id: created.id,
+                data: CrowdInputData {
+                    name: "a lone sailor".to_string(),
+                    description: String::new(),
+                    approximate_count: 1,
+                    disposition: DispositionLevel::Neutral,
+                },
+            }),
+        },
+    )
+    .await;
+    let invalid = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == "crowds-3"),
+    )
+    .await;
+    assert!(matches!(
+        invalid,
+        ServerMessage::Response {
+            result: ResponseResult::Error { .. },
+            ..
+        }
+    ));
+
+    server.abort();
+}
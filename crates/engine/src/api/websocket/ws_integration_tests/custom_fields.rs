@@ -0,0 +1,211 @@
+use super::*;
+
+use std::collections::BTreeMap;
+
+use wrldbldr_domain::{
+    CampbellArchetype, CustomFieldDefinition, CustomFieldType, CustomFieldValue, CustomFieldValues,
+    EntityType,
+};
+use wrldbldr_protocol::types::{CustomFieldEntryData, CustomFieldValueData};
+use wrldbldr_protocol::{
+    CharacterRequest, ErrorCode, RequestPayload, ResponseResult, WorldRequest,
+};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn join(ws: &mut TestWs, world_id: WorldId, role: ProtoWorldRole, user_id: &str) {
+    ws_send_client(
+        ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role,
+            user_id: user_id.to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+}
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_sets_custom_fields_then_players_see_only_visible_ones() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now).with_custom_fields(vec![
+        CustomFieldDefinition::new(
+            "faction",
+            "Faction",
+            EntityType::Character,
+            CustomFieldType::Select,
+        )
+        .with_options(vec!["Crows".to_string(), "Lampblacks".to_string()])
+        .visible_to_players(),
+        CustomFieldDefinition::new(
+            "heat",
+            "Heat",
+            EntityType::Character,
+            CustomFieldType::Number,
+        ),
+    ]);
+    let world_id = world.id;
+    let character = wrldbldr_domain::Character::new(world_id, "Vex", CampbellArchetype::Shadow);
+    let character_id = character.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .character_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(character.clone())));
+
+    let stored: Arc<Mutex<CustomFieldValues>> = Arc::new(Mutex::new(BTreeMap::new()));
+    let stored_for_set = stored.clone();
+    repos
+        .custom_field_repo
+        .expect_set_values()
+        .returning(move |_, entity_type, _, values| {
+            assert_eq!(entity_type, EntityType::Character);
+            *stored_for_set.lock().unwrap() = values.clone();
+            Ok(())
+        });
+    let stored_for_get = stored.clone();
+    repos
+        .custom_field_repo
+        .expect_get_values()
+        .returning(move |_, _| Ok(stored_for_get.lock().unwrap().clone()));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut player_ws = ws_connect(addr).await;
+    join(&mut dm_ws, world_id, ProtoWorldRole::Dm, "dm-user").await;
+    join(
+        &mut player_ws,
+        world_id,
+        ProtoWorldRole::Spectator,
+        "player-user",
+    )
+    .await;
+
+    let set_values = |values: BTreeMap<String, CustomFieldValueData>| {
+        RequestPayload::World(WorldRequest::SetCustomFieldValues {
+            world_id: world_id.to_string(),
+            entity_type: EntityType::Character,
+            entity_id: character_id.to_string(),
+            values,
+        })
+    };
+
+    // A select value has to be one of the field's options
+    match request(
+        &mut dm_ws,
+        "set-1",
+        set_values(BTreeMap::from([(
+            "faction".to_string(),
+            CustomFieldValueData::Text("Bluecoats".to_string()),
+        )])),
+    )
+    .await
+    {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::ValidationError),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // Players can't edit values
+    match request(&mut player_ws, "set-2", set_values(BTreeMap::new())).await {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::Unauthorized),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    match request(
+        &mut dm_ws,
+        "set-3",
+        set_values(BTreeMap::from([
+            (
+                "faction".to_string(),
+                CustomFieldValueData::Text("Crows".to_string()),
+            ),
+            ("heat".to_string(), CustomFieldValueData::Number(4.0)),
+        ])),
+    )
+    .await
+    {
+        ResponseResult::Success { data: Some(data) } => {
+            let entries: Vec<CustomFieldEntryData> = serde_json::from_value(data).unwrap();
+            assert_eq!(entries.len(), 2);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert_eq!(
+        stored.lock().unwrap().get("heat"),
+        Some(&CustomFieldValue::Number(4.0))
+    );
+
+    // The detail DTO carries the values; heat is DM-only
+    let get_character = || {
+        RequestPayload::Character(CharacterRequest::GetCharacter {
+            character_id: character_id.to_string(),
+        })
+    };
+    match request(&mut dm_ws, "get-1", get_character()).await {
+        ResponseResult::Success { data: Some(data) } => {
+            let entries: Vec<CustomFieldEntryData> =
+                serde_json::from_value(data["custom_fields"].clone()).unwrap();
+            assert_eq!(entries.len(), 2);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match request(&mut player_ws, "get-2", get_character()).await {
+        ResponseResult::Success { data: Some(data) } => {
+            let entries: Vec<CustomFieldEntryData> =
+                serde_json::from_value(data["custom_fields"].clone()).unwrap();
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0].field.key, "faction");
+            assert_eq!(
+                entries[0].value,
+                Some(CustomFieldValueData::Text("Crows".to_string()))
+            );
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    server.abort();
+}
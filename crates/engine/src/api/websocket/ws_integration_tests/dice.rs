@@ -0,0 +1,282 @@
+use super::*;
+
+use wrldbldr_protocol::types::DiceRollData;
+use wrldbldr_protocol::{DiceRequest, ErrorCode, RequestPayload, ResponseResult};
+
+fn roll_request(request_id: &str, world_id: WorldId, formula: &str) -> ClientMessage {
+    ClientMessage::Request {
+        request_id: request_id.to_string(),
+        payload: RequestPayload::Dice(DiceRequest::Roll {
+            world_id: world_id.to_string(),
+            formula: formula.to_string(),
+        }),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_rolls_dice_then_result_is_returned_and_shared_with_world() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let repos = TestAppRepos::new(world_repo);
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user"),
+        (
+            &mut spectator_ws,
+            ProtoWorldRole::Spectator,
+            "spectator-user",
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id: None,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // FixedRandom rolls a 1 on every die.
+    ws_send_client(&mut dm_ws, &roll_request("dice-1", world_id, "2d6+1")).await;
+
+    let response = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "dice-1"),
+    )
+    .await;
+    let roll: DiceRollData = match response {
+        ServerMessage::Response {
+            result: ResponseResult::Success { data: Some(data) },
+            ..
+        } => serde_json::from_value(data).expect("dice roll data"),
+        other => panic!("unexpected response: {:?}", other),
+    };
+    assert_eq!(roll.formula, "2d6+1");
+    assert_eq!(roll.individual_rolls, vec![1, 1]);
+    assert_eq!(roll.total, 3);
+    assert_eq!(roll.roller_name, "DM");
+    assert_eq!(roll.breakdown, "2d6[1, 1] + 1 = 3");
+
+    let shared = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::DiceRolled { .. })
+    })
+    .await;
+    match shared {
+        ServerMessage::DiceRolled { roll: shared } => assert_eq!(shared, roll),
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    // Full expressions: keep the highest three of four dice
+    ws_send_client(&mut dm_ws, &roll_request("dice-kh", world_id, "4d6kh3 + 2")).await;
+    let response = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "dice-kh"),
+    )
+    .await;
+    let roll: DiceRollData = match response {
+        ServerMessage::Response {
+            result: ResponseResult::Success { data: Some(data) },
+            ..
+        } => serde_json::from_value(data).expect("dice roll data"),
+        other => panic!("unexpected response: {:?}", other),
+    };
+    assert_eq!(roll.formula, "4d6kh3+2");
+    assert_eq!(roll.total, 5);
+    assert_eq!(roll.terms.len(), 2);
+    assert_eq!(roll.terms[0].dice.iter().filter(|d| !d.kept).count(), 1);
+    assert_eq!(roll.breakdown, "4d6kh3[1, 1, 1, (1)] + 2 = 5");
+
+    // Spectators watch but don't roll.
+    ws_send_client(&mut spectator_ws, &roll_request("dice-2", world_id, "1d20")).await;
+    let response = ws_expect_message(
+        &mut spectator_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "dice-2"),
+    )
+    .await;
+    assert!(matches!(
+        response,
+        ServerMessage::Response {
+            result: ResponseResult::Error {
+                code: ErrorCode::Forbidden,
+                ..
+            },
+            ..
+        }
+    ));
+
+    ws_send_client(
+        &mut dm_ws,
+        &roll_request("dice-3", world_id, "lots of dice"),
+    )
+    .await;
+    let response = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "dice-3"),
+    )
+    .await;
+    assert!(matches!(
+        response,
+        ServerMessage::Response {
+            result: ResponseResult::Error {
+                code: ErrorCode::ValidationError,
+                ..
+            },
+            ..
+        }
+    ));
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn when_dm_sends_roll_dice_then_roll_comes_back_and_is_shared() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let repos = TestAppRepos::new(world_repo);
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user"),
+        (
+            &mut spectator_ws,
+            ProtoWorldRole::Spectator,
+            "spectator-user",
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id: None,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // FixedRandom rolls a 1 on every die, so keeping the lowest is a 1
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::RollDice {
+            formula: "2d20kl1+3".to_string(),
+        },
+    )
+    .await;
+
+    let roll = match ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::DiceRolled { .. })
+    })
+    .await
+    {
+        ServerMessage::DiceRolled { roll } => roll,
+        other => panic!("unexpected message: {:?}", other),
+    };
+    assert_eq!(roll.formula, "2d20kl1+3");
+    assert_eq!(roll.total, 4);
+    assert_eq!(roll.roller_name, "DM");
+
+    match ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::DiceRolled { .. })
+    })
+    .await
+    {
+        ServerMessage::DiceRolled { roll: shared } => assert_eq!(shared, roll),
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    // Spectators watch but don't roll
+    ws_send_client(
+        &mut spectator_ws,
+        &ClientMessage::RollDice {
+            formula: "1d20".to_string(),
+        },
+    )
+    .await;
+    let error = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(error, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"));
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::RollDice {
+            formula: "lots of dice".to_string(),
+        },
+    )
+    .await;
+    let error = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(error, ServerMessage::Error { code, .. } if code == "INVALID_DICE_INPUT"));
+
+    server.abort();
+}
@@ -0,0 +1,181 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{CampbellArchetype, ContentDraft, EntityType};
+use wrldbldr_protocol::types::{
+    ContentDraftData, DiffOpData, DraftDiffData, DraftFieldData, DraftStatusData,
+    RevisionSourceData,
+};
+use wrldbldr_protocol::{DraftRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+fn success<T: serde::de::DeserializeOwned>(result: ResponseResult) -> T {
+    match result {
+        ResponseResult::Success { data: Some(data) } => serde_json::from_value(data).unwrap(),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_publishes_a_revised_draft_then_the_npc_gets_its_text() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Ashfall", "desc", now);
+    let world_id = world.id;
+    let mut npc = wrldbldr_domain::Character::new(world_id, "Marta", CampbellArchetype::Mentor);
+    npc.description = "An innkeeper.".to_string();
+    let npc_id = npc.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .character_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(npc.clone())));
+    let drafts: Arc<Mutex<Vec<ContentDraft>>> = Arc::new(Mutex::new(Vec::new()));
+    let saved = drafts.clone();
+    repos.content_draft_repo.expect_save().returning(move |d| {
+        let mut drafts = saved.lock().unwrap();
+        drafts.retain(|existing| existing.id != d.id);
+        drafts.push(d.clone());
+        Ok(())
+    });
+    let listed = drafts.clone();
+    repos
+        .content_draft_repo
+        .expect_list_for_entity()
+        .returning(move |_, _| Ok(listed.lock().unwrap().clone()));
+    let fetched = drafts.clone();
+    repos
+        .content_draft_repo
+        .expect_get()
+        .returning(move |id| Ok(fetched.lock().unwrap().iter().find(|d| d.id == id).cloned()));
+    repos
+        .character_repo
+        .expect_save()
+        .withf(|c| c.description == "A stout innkeeper who never forgets a face.")
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    let add = |text: &str, source| {
+        RequestPayload::Draft(DraftRequest::AddDraftRevision {
+            world_id: world_id.to_string(),
+            entity_type: EntityType::Character,
+            entity_id: npc_id.to_string(),
+            field: DraftFieldData::Description,
+            text: text.to_string(),
+            source,
+        })
+    };
+
+    // A generated revision, then the DM's edit of it
+    let draft: ContentDraftData = success(
+        request(
+            &mut dm_ws,
+            "draft-1",
+            add("A stout innkeeper.", RevisionSourceData::Generated),
+        )
+        .await,
+    );
+    assert_eq!(draft.status, DraftStatusData::Draft);
+    let draft: ContentDraftData = success(
+        request(
+            &mut dm_ws,
+            "draft-2",
+            add(
+                "A stout innkeeper who never forgets a face.",
+                RevisionSourceData::Edited,
+            ),
+        )
+        .await,
+    );
+    assert_eq!(draft.revisions.len(), 2);
+
+    let diff: DraftDiffData = success(
+        request(
+            &mut dm_ws,
+            "draft-3",
+            RequestPayload::Draft(DraftRequest::DiffDraftRevisions {
+                draft_id: draft.id.clone(),
+                from: 1,
+                to: 2,
+            }),
+        )
+        .await,
+    );
+    assert!(diff
+        .segments
+        .iter()
+        .any(|s| s.op == DiffOpData::Insert && s.text.contains("forgets")));
+
+    let published: ContentDraftData = success(
+        request(
+            &mut dm_ws,
+            "draft-4",
+            RequestPayload::Draft(DraftRequest::PublishDraftRevision {
+                draft_id: draft.id.clone(),
+                revision: 2,
+            }),
+        )
+        .await,
+    );
+    assert_eq!(published.status, DraftStatusData::Published);
+    assert_eq!(published.published_revision, Some(2));
+
+    server.abort();
+}
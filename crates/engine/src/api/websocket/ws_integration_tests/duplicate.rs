@@ -0,0 +1,293 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{HotspotTarget, RegionConnection, RegionHotspot};
+use wrldbldr_protocol::{ErrorCode, ItemsRequest, LocationRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn join(ws: &mut TestWs, world_id: WorldId, role: ProtoWorldRole, user_id: &str) {
+    ws_send_client(
+        ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role,
+            user_id: user_id.to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+}
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_duplicates_a_location_then_its_regions_items_and_connections_are_copied() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now);
+    let world_id = world.id;
+
+    let mut location =
+        wrldbldr_domain::Location::new(world_id, "Harbor", wrldbldr_domain::LocationType::Exterior);
+    let lantern = wrldbldr_domain::Item::new(world_id, "Lantern");
+    let lantern_id = lantern.id;
+    let hall = wrldbldr_domain::Region::new(location.id, "Hall");
+    let hall_id = hall.id;
+    let hotspot = |target| RegionHotspot {
+        label: "spot".to_string(),
+        points: Vec::new(),
+        target,
+    };
+    let dock = wrldbldr_domain::Region::new(location.id, "Dock")
+        .with_hotspot(hotspot(HotspotTarget::Exit { region_id: hall_id }))
+        .with_hotspot(hotspot(HotspotTarget::Item {
+            item_id: lantern_id,
+        }));
+    let dock_id = dock.id;
+    location = location.with_default_region(dock_id);
+    let location_id = location.id;
+    let connection = RegionConnection {
+        from_region: dock_id,
+        to_region: hall_id,
+        description: Some("A gangway".to_string()),
+        bidirectional: true,
+        is_locked: false,
+        lock_description: None,
+        lock: Default::default(),
+        sound: Default::default(),
+    };
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |_| Ok(Some(location.clone())));
+    let regions = vec![dock, hall];
+    repos
+        .location_repo
+        .expect_list_regions_in_location()
+        .returning(move |_| Ok(regions.clone()));
+    repos
+        .location_repo
+        .expect_get_connections()
+        .returning(move |region_id| {
+            Ok(if region_id == dock_id {
+                vec![connection.clone()]
+            } else {
+                Vec::new()
+            })
+        });
+    repos
+        .item_repo
+        .expect_list_in_region()
+        .returning(move |region_id| {
+            Ok(if region_id == dock_id {
+                vec![lantern.clone()]
+            } else {
+                Vec::new()
+            })
+        });
+    repos.tag_repo.expect_get_tags().returning(|_, ids| {
+        Ok(ids
+            .iter()
+            .map(|id| (*id, vec!["port".to_string()]))
+            .collect())
+    });
+    repos
+        .tag_repo
+        .expect_set_tags()
+        .times(4)
+        .returning(|_, _, _, _| Ok(()));
+    repos
+        .custom_field_repo
+        .expect_get_values()
+        .returning(|_, _| Ok(Default::default()));
+
+    let saved_location = Arc::new(Mutex::new(None));
+    let saved_location_in = saved_location.clone();
+    repos
+        .location_repo
+        .expect_save_location()
+        .times(1)
+        .returning(move |l| {
+            *saved_location_in.lock().unwrap() = Some(l.clone());
+            Ok(())
+        });
+    let saved_regions = Arc::new(Mutex::new(Vec::new()));
+    let saved_regions_in = saved_regions.clone();
+    repos
+        .location_repo
+        .expect_save_region()
+        .times(2)
+        .returning(move |r| {
+            saved_regions_in.lock().unwrap().push(r.clone());
+            Ok(())
+        });
+    let saved_connections = Arc::new(Mutex::new(Vec::new()));
+    let saved_connections_in = saved_connections.clone();
+    repos
+        .location_repo
+        .expect_save_connection()
+        .times(1)
+        .returning(move |c| {
+            saved_connections_in.lock().unwrap().push(c.clone());
+            Ok(())
+        });
+    let saved_items = Arc::new(Mutex::new(Vec::new()));
+    let saved_items_in = saved_items.clone();
+    repos.item_repo.expect_save().times(1).returning(move |i| {
+        saved_items_in.lock().unwrap().push(i.clone());
+        Ok(())
+    });
+    let placed = Arc::new(Mutex::new(Vec::new()));
+    let placed_in = placed.clone();
+    repos
+        .item_repo
+        .expect_place_in_region()
+        .times(1)
+        .returning(move |item_id, region_id| {
+            placed_in.lock().unwrap().push((item_id, region_id));
+            Ok(())
+        });
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    join(&mut dm_ws, world_id, ProtoWorldRole::Dm, "dm-user").await;
+
+    let payload = RequestPayload::Location(LocationRequest::DuplicateLocation {
+        location_id: location_id.to_string(),
+    });
+    match request(&mut dm_ws, "dup-1", payload).await {
+        ResponseResult::Success { data: Some(data) } => {
+            assert_eq!(data["name"], "Harbor (copy)");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let copy = saved_location
+        .lock()
+        .unwrap()
+        .clone()
+        .expect("location saved");
+    assert_ne!(copy.id, location_id);
+    let regions = saved_regions.lock().unwrap().clone();
+    assert!(regions.iter().all(|r| r.location_id == copy.id));
+    assert!(regions.iter().all(|r| r.id != dock_id && r.id != hall_id));
+    let dock_copy = regions.iter().find(|r| r.name == "Dock").unwrap();
+    let hall_copy = regions.iter().find(|r| r.name == "Hall").unwrap();
+    assert_eq!(copy.default_region_id, Some(dock_copy.id));
+
+    let items = saved_items.lock().unwrap().clone();
+    assert_eq!(items[0].name, "Lantern");
+    assert_ne!(items[0].id, lantern_id);
+    assert_eq!(
+        placed.lock().unwrap().clone(),
+        vec![(items[0].id, dock_copy.id)]
+    );
+
+    let targets: Vec<_> = dock_copy.hotspots.iter().map(|h| h.target).collect();
+    assert_eq!(
+        targets,
+        vec![
+            HotspotTarget::Exit {
+                region_id: hall_copy.id
+            },
+            HotspotTarget::Item {
+                item_id: items[0].id
+            },
+        ]
+    );
+
+    let connections = saved_connections.lock().unwrap().clone();
+    assert_eq!(connections[0].from_region, dock_copy.id);
+    assert_eq!(connections[0].to_region, hall_copy.id);
+    assert_eq!(connections[0].description.as_deref(), Some("A gangway"));
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn when_dm_duplicates_a_unique_item_then_the_request_is_rejected() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now);
+    let world_id = world.id;
+    let relic = wrldbldr_domain::Item::new(world_id, "Crown of Ash").unique();
+    let relic_id = relic.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .item_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(relic.clone())));
+    repos.item_repo.expect_save().never();
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    join(&mut dm_ws, world_id, ProtoWorldRole::Dm, "dm-user").await;
+
+    let payload = RequestPayload::Items(ItemsRequest::DuplicateItem {
+        item_id: relic_id.to_string(),
+        region_id: None,
+    });
+    match request(&mut dm_ws, "dup-1", payload).await {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::BadRequest),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    server.abort();
+}
@@ -0,0 +1,161 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{Item, Location, LocationType, MarketModifier};
+use wrldbldr_protocol::types::{
+    EconomyOverviewData, MarketModifierData, MarketModifierInputData, ScarcityData,
+};
+use wrldbldr_protocol::{EconomyRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+fn data<T: serde::de::DeserializeOwned>(result: ResponseResult) -> T {
+    match result {
+        ResponseResult::Success { data: Some(data) } => serde_json::from_value(data).unwrap(),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_a_flagged_shortage_begins_then_local_prices_rise() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Varn", "desc", now);
+    let world_id = world.id;
+    let harbour = Location::new(world_id, "Harbour", LocationType::Exterior);
+    let harbour_id = harbour.id;
+    let sword = Item::new(world_id, "Longsword")
+        .with_type("Weapon")
+        .with_base_price(10);
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |_| Ok(Some(harbour.clone())));
+    repos
+        .item_repo
+        .expect_list_in_world()
+        .returning(move |_| Ok(vec![sword.clone()]));
+    let flags: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let set_flags = flags.clone();
+    repos
+        .flag_repo
+        .expect_get_world_flags()
+        .returning(move |_| {
+            let flags = set_flags.lock().unwrap().clone();
+            Box::pin(async move { Ok(flags) })
+        });
+    let stored: Arc<Mutex<Vec<MarketModifier>>> = Arc::new(Mutex::new(Vec::new()));
+    let saved = stored.clone();
+    repos
+        .economy_repo
+        .expect_save_modifier()
+        .returning(move |m| {
+            saved.lock().unwrap().push(m.clone());
+            Ok(())
+        });
+    let listed = stored.clone();
+    repos
+        .economy_repo
+        .expect_list_modifiers()
+        .returning(move |_| Ok(listed.lock().unwrap().clone()));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    let created: MarketModifierData = data(
+        request(
+            &mut dm_ws,
+            "economy-1",
+            RequestPayload::Economy(EconomyRequest::CreateMarketModifier {
+                data: MarketModifierInputData {
+                    location_id: Some(harbour_id.to_string()),
+                    category: Some("weapon".to_string()),
+                    scarcity: Some(ScarcityData::Scarce),
+                    price_adjustment: 20,
+                    flag: Some("northern_war".to_string()),
+                    reason: "War in the north".to_string(),
+                },
+            }),
+        )
+        .await,
+    );
+    assert!(!created.is_active);
+
+    let overview = || {
+        RequestPayload::Economy(EconomyRequest::GetEconomyOverview {
+            location_id: harbour_id.to_string(),
+        })
+    };
+
+    // Until the war starts, weapons sell at their base price
+    let before: EconomyOverviewData = data(request(&mut dm_ws, "economy-2", overview()).await);
+    assert_eq!(before.location_name, "Harbour");
+    assert_eq!(before.categories.len(), 1);
+    assert_eq!(before.categories[0].scarcity, ScarcityData::Common);
+    assert_eq!(before.categories[0].items[0].local_price, Some(10));
+
+    // An event sets the flag: weapons are scarce and dearer
+    flags.lock().unwrap().push("northern_war".to_string());
+    let after: EconomyOverviewData = data(request(&mut dm_ws, "economy-3", overview()).await);
+    let weapons = &after.categories[0];
+    assert_eq!(weapons.scarcity, ScarcityData::Scarce);
+    assert_eq!(weapons.price_percent, Some(180));
+    assert_eq!(weapons.reasons, vec!["War in the north".to_string()]);
+    assert_eq!(weapons.items[0].local_price, Some(18));
+    assert!(after.modifiers[0].is_active);
+
+    server.abort();
+}
@@ -0,0 +1,184 @@
+use super::*;
+
+use wrldbldr_domain::{
+    EncounterEntry, EncounterTable, Location, LocationObservation, LocationScale, LocationType,
+    RegionId,
+};
+use wrldbldr_protocol::types::JourneyDecision;
+
+#[tokio::test]
+async fn when_an_encounter_stops_a_journey_then_the_dm_can_turn_the_pc_back() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now);
+    let world_id = world.id;
+
+    let wolves = EncounterTable::new(
+        240,
+        30,
+        vec![EncounterEntry {
+            name: "Wolves".to_string(),
+            description: "A pack shadows the road".to_string(),
+            weight: 1,
+        }],
+    )
+    .unwrap();
+    let kingdom = Location::new(world_id, "Veld", LocationType::Exterior)
+        .with_scale(LocationScale::Kingdom)
+        .with_encounter_table(wolves);
+    let city = Location::new(world_id, "Haven", LocationType::Exterior).with_parent(kingdom.id);
+    let port = Location::new(world_id, "Saltmere", LocationType::Exterior).with_parent(kingdom.id);
+    let (city_id, port_id) = (city.id, port.id);
+
+    let mut alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", city_id, now);
+    alice.current_region_id = Some(RegionId::new());
+    let alice_id = alice.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok((id == alice_id).then(|| alice.clone())));
+    // The PC never leaves Haven
+    repos.player_character_repo.expect_update_position().never();
+
+    repos.location_repo.checkpoint();
+    let locations = vec![kingdom, city, port];
+    let by_id = locations.clone();
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |id| Ok(by_id.iter().find(|l| l.id == id).cloned()));
+    repos
+        .location_repo
+        .expect_list_locations_in_world()
+        .returning(move |_| Ok(locations.clone()));
+
+    let heard = LocationObservation::heard_about(alice_id, port_id, now, None, now);
+    repos
+        .observation_repo
+        .expect_get_known_locations()
+        .returning(move |_| Ok(vec![heard.clone()]));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut alice_ws = ws_connect(addr).await;
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut alice_ws,
+            ProtoWorldRole::Player,
+            "alice-user",
+            Some(*alice_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::FastTravel {
+            pc_id: alice_id.to_string(),
+            location_id: port_id.to_string(),
+        },
+    )
+    .await;
+
+    // The test dice always roll 1, so the first check finds the wolves.
+    let stopped = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::JourneyInterrupted { .. })
+    })
+    .await;
+    let ServerMessage::JourneyInterrupted { journey } = stopped else {
+        panic!("expected JourneyInterrupted");
+    };
+    assert_eq!(journey.to_location_name, "Saltmere");
+    assert_eq!(journey.total_minutes, 3 * 24 * 60);
+    assert_eq!(journey.elapsed_minutes, 240);
+    assert_eq!(journey.encounter.as_ref().unwrap().name, "Wolves");
+
+    let _ = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::JourneyInterrupted { .. })
+    })
+    .await;
+
+    // Players can't settle their own journeys
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::ResolveJourney {
+            journey_id: journey.journey_id.clone(),
+            decision: JourneyDecision::Arrive,
+        },
+    )
+    .await;
+    let refused = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(refused, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"));
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::ResolveJourney {
+            journey_id: journey.journey_id.clone(),
+            decision: JourneyDecision::TurnBack,
+        },
+    )
+    .await;
+    let resolved = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::JourneyResolved { .. })
+    })
+    .await;
+    assert!(matches!(
+        resolved,
+        ServerMessage::JourneyResolved {
+            decision: JourneyDecision::TurnBack,
+            ..
+        }
+    ));
+
+    // Settled journeys are gone
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::ResolveJourney {
+            journey_id: journey.journey_id,
+            decision: JourneyDecision::Continue,
+        },
+    )
+    .await;
+    let gone = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(gone, ServerMessage::Error { code, .. } if code == "NOT_FOUND"));
+
+    server.abort();
+}
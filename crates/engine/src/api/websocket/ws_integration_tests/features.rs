@@ -0,0 +1,200 @@
+use super::*;
+
+use wrldbldr_protocol::types::WorldFeaturesData;
+use wrldbldr_protocol::{RequestPayload, ResponseResult, WorldRequest};
+
+#[tokio::test]
+async fn when_dm_enables_feature_then_players_receive_switches() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let saved = Arc::new(Mutex::new(None::<wrldbldr_domain::World>));
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+    let saved_for_save = saved.clone();
+    world_repo.expect_save().returning(move |w| {
+        *saved_for_save.lock().unwrap() = Some(w.clone());
+        Ok(())
+    });
+
+    let repos = TestAppRepos::new(world_repo);
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user"),
+        (
+            &mut spectator_ws,
+            ProtoWorldRole::Spectator,
+            "spectator-user",
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id: None,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "features-1".to_string(),
+            payload: RequestPayload::World(WorldRequest::UpdateFeatures {
+                world_id: world_id.to_string(),
+                features: WorldFeaturesData {
+                    rumors: true,
+                    ..Default::default()
+                },
+            }),
+        },
+    )
+    .await;
+
+    let response = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "features-1"),
+    )
+    .await;
+    match response {
+        ServerMessage::Response {
+            result: ResponseResult::Success { .. },
+            ..
+        } => {}
+        other => panic!("unexpected response: {:?}", other),
+    }
+
+    let updated = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldFeaturesUpdated { .. })
+    })
+    .await;
+    match updated {
+        ServerMessage::WorldFeaturesUpdated { features, .. } => {
+            assert!(features.rumors);
+            assert!(!features.combat_tracker);
+            assert!(!features.weather);
+        }
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    let saved = saved.lock().unwrap().clone().expect("world saved");
+    assert_eq!(
+        saved.features.enabled(),
+        vec![wrldbldr_domain::ExperimentalFeature::Rumors]
+    );
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn when_non_dm_updates_features_then_request_is_rejected() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+    world_repo.expect_save().never();
+
+    let repos = TestAppRepos::new(world_repo);
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut spectator_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut spectator_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Spectator,
+            user_id: "spectator-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let joined = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+    match joined {
+        ServerMessage::WorldJoined { snapshot, .. } => {
+            assert_eq!(snapshot["world"]["features"]["combatTracker"], false);
+        }
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    ws_send_client(
+        &mut spectator_ws,
+        &ClientMessage::Request {
+            request_id: "features-2".to_string(),
+            payload: RequestPayload::World(WorldRequest::UpdateFeatures {
+                world_id: world_id.to_string(),
+                features: WorldFeaturesData {
+                    weather: true,
+                    ..Default::default()
+                },
+            }),
+        },
+    )
+    .await;
+
+    let response = ws_expect_message(
+        &mut spectator_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "features-2"),
+    )
+    .await;
+    match response {
+        ServerMessage::Response {
+            result: ResponseResult::Error { .. },
+            ..
+        } => {}
+        other => panic!("unexpected response: {:?}", other),
+    }
+
+    server.abort();
+}
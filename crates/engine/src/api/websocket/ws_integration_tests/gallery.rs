@@ -0,0 +1,186 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{AssetType, BatchId, EntityType, GalleryAsset, GenerationMetadata};
+use wrldbldr_protocol::types::{GalleryAssetData, GalleryFilterData};
+use wrldbldr_protocol::{GalleryRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+fn success<T: serde::de::DeserializeOwned>(result: ResponseResult) -> T {
+    match result {
+        ResponseResult::Success { data: Some(data) } => serde_json::from_value(data).unwrap(),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_tags_a_batch_then_the_gallery_filters_by_tag_and_favorite() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Ashfall", "desc", now);
+    let world_id = world.id;
+    let batch_id = BatchId::new();
+    let generated = |n: i64| {
+        GalleryAsset::new_generated(
+            EntityType::Location,
+            uuid::Uuid::new_v4().to_string(),
+            AssetType::Backdrop,
+            format!("assets/harbor-{}.png", n),
+            GenerationMetadata::new("backdrop", "a misty harbor", n, batch_id),
+            now,
+        )
+    };
+    let portrait = GalleryAsset::new(
+        EntityType::Character,
+        uuid::Uuid::new_v4().to_string(),
+        AssetType::Portrait,
+        "assets/marta.png",
+        now,
+    );
+    let assets: Arc<Mutex<Vec<GalleryAsset>>> =
+        Arc::new(Mutex::new(vec![generated(1), generated(2), portrait]));
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let listed = assets.clone();
+    repos
+        .asset_repo
+        .expect_list_for_world()
+        .returning(move |_| Ok(listed.lock().unwrap().clone()));
+    let saved = assets.clone();
+    repos.asset_repo.expect_save().returning(move |a| {
+        let mut assets = saved.lock().unwrap();
+        if let Some(existing) = assets.iter_mut().find(|e| e.id == a.id) {
+            *existing = a.clone();
+        }
+        Ok(())
+    });
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    let list =
+        |filter: GalleryFilterData| RequestPayload::Gallery(GalleryRequest::ListGallery { filter });
+
+    // The batch, selected by filtering on it
+    let batch: Vec<GalleryAssetData> = success(
+        request(
+            &mut dm_ws,
+            "gallery-1",
+            list(GalleryFilterData {
+                batch_id: Some(batch_id.to_string()),
+                ..Default::default()
+            }),
+        )
+        .await,
+    );
+    assert_eq!(batch.len(), 2);
+    let batch_ids: Vec<String> = batch.iter().map(|a| a.id.clone()).collect();
+
+    let tagged: Vec<GalleryAssetData> = success(
+        request(
+            &mut dm_ws,
+            "gallery-2",
+            RequestPayload::Gallery(GalleryRequest::TagAssets {
+                asset_ids: batch_ids.clone(),
+                add: vec!["Harbor Town".to_string()],
+                remove: Vec::new(),
+            }),
+        )
+        .await,
+    );
+    assert!(tagged.iter().all(|a| a.tags == vec!["harbor-town"]));
+
+    let _: Vec<GalleryAssetData> = success(
+        request(
+            &mut dm_ws,
+            "gallery-3",
+            RequestPayload::Gallery(GalleryRequest::SetAssetsFavorite {
+                asset_ids: vec![batch_ids[0].clone()],
+                favorite: true,
+            }),
+        )
+        .await,
+    );
+
+    let favorites: Vec<GalleryAssetData> = success(
+        request(
+            &mut dm_ws,
+            "gallery-4",
+            list(GalleryFilterData {
+                tag: Some("harbor-town".to_string()),
+                favorites_only: true,
+                ..Default::default()
+            }),
+        )
+        .await,
+    );
+    assert_eq!(favorites.len(), 1);
+    assert_eq!(favorites[0].id, batch_ids[0]);
+
+    // Unknown assets are rejected
+    let missing = request(
+        &mut dm_ws,
+        "gallery-5",
+        RequestPayload::Gallery(GalleryRequest::SetAssetsFavorite {
+            asset_ids: vec![uuid::Uuid::new_v4().to_string()],
+            favorite: true,
+        }),
+    )
+    .await;
+    assert!(matches!(missing, ResponseResult::Error { .. }));
+
+    server.abort();
+}
@@ -0,0 +1,230 @@
+use super::*;
+
+use chrono::TimeZone;
+use wrldbldr_domain::{
+    ActId, GameTime, InteractionTarget, InteractionTemplate, InteractionType, InteractionUsage,
+    LocationId, Region, Scene, TimeOfDay,
+};
+use wrldbldr_protocol::{InteractionData, InteractionRequest, RequestPayload, ResponseResult};
+
+#[tokio::test]
+async fn when_a_pc_uses_an_interaction_then_it_cools_down_for_that_pc() {
+    let now = chrono::Utc::now();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.game_time =
+        GameTime::starting_at(chrono::Utc.with_ymd_and_hms(2024, 3, 1, 8, 0, 0).unwrap());
+    let world_id = world.id;
+
+    let temple_id = LocationId::new();
+    let nave = Region::new(temple_id, "Nave");
+    let nave_id = nave.id;
+    let scene = Scene::new(ActId::new(), "Morning service", temple_id);
+    let scene_id = scene.id;
+
+    let shrine = InteractionTemplate::new(
+        scene_id,
+        "Pray at the shrine",
+        InteractionType::Examine,
+        InteractionTarget::Environment("shrine".to_string()),
+    )
+    .with_cooldown_minutes(60)
+    .with_max_uses(2);
+    let vigil = InteractionTemplate::new(
+        scene_id,
+        "Keep the night vigil",
+        InteractionType::Custom("Vigil".to_string()),
+        InteractionTarget::None,
+    )
+    .with_available_time(TimeOfDay::Night);
+    let shrine_id = shrine.id;
+    let interactions = vec![shrine, vigil];
+
+    let mut alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", temple_id, now);
+    alice.current_region_id = Some(nave_id);
+    let alice_id = alice.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(alice.clone())));
+    repos
+        .scene_repo
+        .expect_list_for_region()
+        .returning(move |id| {
+            Ok(if id == nave_id {
+                vec![scene.clone()]
+            } else {
+                vec![]
+            })
+        });
+    let listed = interactions.clone();
+    repos
+        .interaction_repo
+        .expect_list_for_scene()
+        .returning(move |_| Ok(listed.clone()));
+    repos
+        .interaction_repo
+        .expect_get()
+        .returning(move |id| Ok(interactions.iter().find(|i| i.id == id).cloned()));
+    let usage: Arc<std::sync::Mutex<Option<InteractionUsage>>> =
+        Arc::new(std::sync::Mutex::new(None));
+    let usage_for_get = usage.clone();
+    repos
+        .interaction_repo
+        .expect_get_usage()
+        .returning(move |id, _| {
+            Ok(usage_for_get
+                .lock()
+                .unwrap()
+                .clone()
+                .filter(|u| u.interaction_id == id))
+        });
+    let usage_for_save = usage.clone();
+    repos
+        .interaction_repo
+        .expect_save_usage()
+        .returning(move |u| {
+            *usage_for_save.lock().unwrap() = Some(u.clone());
+            Ok(())
+        });
+
+    let clock: Arc<dyn crate::infrastructure::ports::ClockPort> = Arc::new(FixedClock { now });
+    let queue = Arc::new(crate::infrastructure::memory::MemoryQueue::new(clock));
+    let app = build_test_app_with_ports(repos, now, queue, Arc::new(NoopLlm));
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state).await;
+
+    let mut alice_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Player,
+            user_id: "alice-user".to_string(),
+            pc_id: Some(*alice_id.as_uuid()),
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    // The vigil isn't offered in the morning
+    let listed = list_available(&mut alice_ws, alice_id.to_string(), "list-1").await;
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].name, "Pray at the shrine");
+    assert!(listed[0].is_available);
+    assert_eq!(listed[0].uses_left, Some(2));
+
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::PerformInteraction {
+            interaction_id: shrine_id.to_string(),
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ActionReceived { .. })
+    })
+    .await;
+    assert_eq!(usage.lock().unwrap().as_ref().unwrap().uses, 1);
+
+    // Game time hasn't moved, so the shrine is cooling down
+    let listed = list_available(&mut alice_ws, alice_id.to_string(), "list-2").await;
+    assert!(!listed[0].is_available);
+    assert_eq!(listed[0].uses_left, Some(1));
+    assert!(listed[0]
+        .unavailable_reason
+        .as_deref()
+        .is_some_and(|reason| reason.starts_with("Ready again")));
+
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::PerformInteraction {
+            interaction_id: shrine_id.to_string(),
+        },
+    )
+    .await;
+    let _ = ws_expect_message(
+        &mut alice_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Error { code, .. } if code == "INTERACTION_UNAVAILABLE"),
+    )
+    .await;
+    assert_eq!(usage.lock().unwrap().as_ref().unwrap().uses, 1);
+
+    // Another PC's interactions are off limits to a player
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::Request {
+            request_id: "list-3".to_string(),
+            payload: RequestPayload::Interaction(InteractionRequest::ListAvailableInteractions {
+                pc_id: wrldbldr_domain::PlayerCharacterId::new().to_string(),
+            }),
+        },
+    )
+    .await;
+    let response = ws_expect_message(
+        &mut alice_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "list-3"),
+    )
+    .await;
+    assert!(matches!(
+        response,
+        ServerMessage::Response {
+            result: ResponseResult::Error { .. },
+            ..
+        }
+    ));
+
+    server.abort();
+}
+
+async fn list_available(
+    ws: &mut tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >,
+    pc_id: String,
+    request_id: &str,
+) -> Vec<InteractionData> {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload: RequestPayload::Interaction(InteractionRequest::ListAvailableInteractions {
+                pc_id,
+            }),
+        },
+    )
+    .await;
+    let response = ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await;
+    let ServerMessage::Response {
+        result: ResponseResult::Success { data: Some(data) },
+        ..
+    } = response
+    else {
+        panic!("expected a successful response, got {:?}", response);
+    };
+    serde_json::from_value(data).unwrap()
+}
@@ -0,0 +1,248 @@
+use super::*;
+
+use wrldbldr_domain::{
+    CampbellArchetype, CharacterId, Lore, LoreCategory, LoreDiscoverySource, LoreKnowledge,
+    NpcObservation, ObservationType, RegionId,
+};
+use wrldbldr_protocol::types::{LoreDiscoverySourceData, SharedKnowledgeData};
+
+#[tokio::test]
+async fn when_player_shares_knowledge_then_party_members_learn_what_they_know() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let location_id = wrldbldr_domain::LocationId::new();
+    let region_id = RegionId::new();
+
+    let alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", location_id, now);
+    let bram =
+        wrldbldr_domain::PlayerCharacter::new("bram-user", world_id, "Bram", location_id, now);
+    let (alice_id, bram_id) = (alice.id, bram.id);
+    let alice_char = CharacterId::from_uuid(alice_id.to_uuid());
+    let bram_char = CharacterId::from_uuid(bram_id.to_uuid());
+
+    let lore = Lore::new(world_id, "The Drowned Bell", LoreCategory::Legend, now)
+        .with_chunk("A bell rings beneath the lake.")
+        .with_chunk("It was cast to call the dead.");
+    let (lore_id, first_chunk) = (lore.id, lore.chunks[0].id);
+
+    let npc = wrldbldr_domain::Character::new(world_id, "Marta", CampbellArchetype::Mentor);
+    let npc_id = npc.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let pcs = [alice.clone(), bram.clone()];
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok(pcs.iter().find(|pc| pc.id == id).cloned()));
+    repos
+        .character_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(npc.clone())));
+
+    // Alice has read only the first part of the legend; Bram knows none of it.
+    repos
+        .lore_repo
+        .expect_get()
+        .returning(move |id| Ok((id == lore_id).then(|| lore.clone())));
+    repos
+        .lore_repo
+        .expect_character_knows_lore()
+        .returning(move |character_id, lore_id| {
+            Ok((character_id == alice_char).then(|| {
+                LoreKnowledge::partial(
+                    lore_id,
+                    alice_char,
+                    vec![first_chunk],
+                    LoreDiscoverySource::Investigation,
+                    now,
+                )
+            }))
+        });
+    repos
+        .lore_repo
+        .expect_grant_knowledge()
+        .withf(move |knowledge| {
+            knowledge.character_id == bram_char
+                && knowledge.known_chunk_ids == vec![first_chunk]
+                && matches!(
+                    &knowledge.discovery_source,
+                    LoreDiscoverySource::SharedByPc { pc_id, pc_name }
+                        if *pc_id == alice_id && pc_name == "Alice"
+                )
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let seen_at = now - chrono::Duration::hours(2);
+    let sighting = NpcObservation::direct(alice_id, npc_id, location_id, region_id, seen_at, now);
+    repos
+        .observation_repo
+        .expect_get_observations()
+        .returning(move |pc_id| {
+            Ok(if pc_id == alice_id {
+                vec![sighting.clone()]
+            } else {
+                vec![]
+            })
+        });
+    repos
+        .observation_repo
+        .expect_save_observation()
+        .withf(move |obs| {
+            obs.pc_id == bram_id
+                && obs.npc_id == npc_id
+                && obs.region_id == region_id
+                && obs.game_time == seen_at
+                && obs.observation_type == ObservationType::HeardAbout
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut alice_ws = ws_connect(addr).await;
+    let mut bram_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut alice_ws,
+            ProtoWorldRole::Player,
+            "alice-user",
+            Some(*alice_id.as_uuid()),
+        ),
+        (
+            &mut bram_ws,
+            ProtoWorldRole::Player,
+            "bram-user",
+            Some(*bram_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // Bram can't pass on what Alice knows.
+    ws_send_client(
+        &mut bram_ws,
+        &ClientMessage::ShareKnowledge {
+            from_pc_id: alice_id.to_string(),
+            target_pc_ids: vec![bram_id.to_string()],
+            knowledge: SharedKnowledgeData::Lore {
+                lore_id: lore_id.to_string(),
+            },
+        },
+    )
+    .await;
+    let denied = ws_expect_message(&mut bram_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(denied, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"));
+
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::ShareKnowledge {
+            from_pc_id: alice_id.to_string(),
+            target_pc_ids: vec![bram_id.to_string()],
+            knowledge: SharedKnowledgeData::Lore {
+                lore_id: lore_id.to_string(),
+            },
+        },
+    )
+    .await;
+
+    let discovered = ws_expect_message(&mut bram_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::LoreDiscovered { .. })
+    })
+    .await;
+    match discovered {
+        ServerMessage::LoreDiscovered {
+            character_id,
+            lore,
+            discovered_chunk_ids,
+            discovery_source,
+        } => {
+            assert_eq!(character_id, bram_id.to_string());
+            assert_eq!(lore.title, "The Drowned Bell");
+            assert_eq!(discovered_chunk_ids, vec![first_chunk.to_string()]);
+            assert!(matches!(
+                discovery_source,
+                LoreDiscoverySourceData::SharedByPc { pc_name, .. } if pc_name == "Alice"
+            ));
+        }
+        other => panic!("expected LoreDiscovered, got: {:?}", other),
+    }
+
+    let dm_told = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::KnowledgeShared { .. })
+    })
+    .await;
+    assert!(matches!(
+        dm_told,
+        ServerMessage::KnowledgeShared { from_pc_name, to_pc_ids, title, .. }
+            if from_pc_name == "Alice"
+                && to_pc_ids == vec![bram_id.to_string()]
+                && title == "The Drowned Bell"
+    ));
+
+    // Alice tells Bram where she last saw Marta.
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::ShareKnowledge {
+            from_pc_id: alice_id.to_string(),
+            target_pc_ids: vec![bram_id.to_string()],
+            knowledge: SharedKnowledgeData::NpcWhereabouts {
+                npc_id: npc_id.to_string(),
+            },
+        },
+    )
+    .await;
+    let told = ws_expect_message(
+        &mut bram_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::KnowledgeShared { title, .. } if title.contains("Marta")),
+    )
+    .await;
+    assert!(matches!(
+        told,
+        ServerMessage::KnowledgeShared { to_pc_ids, .. } if to_pc_ids == vec![bram_id.to_string()]
+    ));
+
+    server.abort();
+}
@@ -0,0 +1,183 @@
+use super::*;
+
+use wrldbldr_domain::{Location, LocationObservation, LocationType, RegionId};
+use wrldbldr_protocol::{ObservationRequest, RequestPayload, ResponseResult};
+
+#[tokio::test]
+async fn when_dm_reveals_a_location_then_the_pc_knows_it_and_no_other() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now);
+    let world_id = world.id;
+
+    let kingdom = Location::new(world_id, "Veld", LocationType::Exterior);
+    let city = Location::new(world_id, "Haven", LocationType::Exterior).with_parent(kingdom.id);
+    let port = Location::new(world_id, "Saltmere", LocationType::Exterior);
+    let lighthouse = Location::new(world_id, "Greywatch", LocationType::Exterior);
+    let (city_id, port_id, lighthouse_id) = (city.id, port.id, lighthouse.id);
+
+    let mut alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", city_id, now);
+    alice.current_region_id = Some(RegionId::new());
+    let alice_id = alice.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok((id == alice_id).then(|| alice.clone())));
+
+    repos.location_repo.checkpoint();
+    let locations = vec![kingdom, city, port, lighthouse];
+    let by_id = locations.clone();
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |id| Ok(by_id.iter().find(|l| l.id == id).cloned()));
+    repos
+        .location_repo
+        .expect_list_locations_in_world()
+        .returning(move |_| Ok(locations.clone()));
+    repos
+        .location_repo
+        .expect_get_region_exits()
+        .returning(|_| Ok(Vec::new()));
+    repos
+        .location_repo
+        .expect_get_region()
+        .returning(|_| Ok(None));
+
+    let known: Arc<Mutex<Vec<LocationObservation>>> = Arc::new(Mutex::new(Vec::new()));
+    let listed = known.clone();
+    repos
+        .observation_repo
+        .expect_get_known_locations()
+        .returning(move |_| Ok(listed.lock().unwrap().clone()));
+    let saved = known.clone();
+    repos
+        .observation_repo
+        .expect_save_location_observation()
+        .withf(move |o| o.pc_id == alice_id && o.location_id == port_id)
+        .times(1)
+        .returning(move |o| {
+            saved.lock().unwrap().push(o.clone());
+            Ok(())
+        });
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut alice_ws = ws_connect(addr).await;
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut alice_ws,
+            ProtoWorldRole::Player,
+            "alice-user",
+            Some(*alice_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // Nothing leads to the lighthouse and nobody has mentioned it.
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::ExitToLocation {
+            pc_id: alice_id.to_string(),
+            location_id: lighthouse_id.to_string(),
+            arrival_region_id: None,
+        },
+    )
+    .await;
+    let blocked = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::MovementBlocked { .. })
+    })
+    .await;
+    assert!(matches!(blocked, ServerMessage::MovementBlocked { .. }));
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "reveal-1".to_string(),
+            payload: RequestPayload::Observation(ObservationRequest::RevealLocation {
+                pc_id: alice_id.to_string(),
+                location_id: port_id.to_string(),
+                notes: Some("  A sailor mentioned it  ".to_string()),
+            }),
+        },
+    )
+    .await;
+    let revealed = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "reveal-1"),
+    )
+    .await;
+    assert!(matches!(
+        revealed,
+        ServerMessage::Response {
+            result: ResponseResult::Success { .. },
+            ..
+        }
+    ));
+
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::Request {
+            request_id: "known-1".to_string(),
+            payload: RequestPayload::Observation(ObservationRequest::ListKnownLocations {
+                pc_id: alice_id.to_string(),
+            }),
+        },
+    )
+    .await;
+    let listed = ws_expect_message(
+        &mut alice_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "known-1"),
+    )
+    .await;
+    match listed {
+        ServerMessage::Response {
+            result: ResponseResult::Success { data: Some(data) },
+            ..
+        } => {
+            let known = data.as_array().expect("known locations");
+            let names: Vec<_> = known.iter().map(|k| k["name"].as_str().unwrap()).collect();
+            assert_eq!(names, vec!["Haven", "Saltmere", "Veld"]);
+            assert_eq!(known[1]["observation_type"], "heard_about");
+            assert_eq!(known[1]["notes"], "A sailor mentioned it");
+            assert_eq!(known[0]["observation_type"], "direct");
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+
+    server.abort();
+}
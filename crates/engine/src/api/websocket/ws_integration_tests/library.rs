@@ -0,0 +1,169 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{CampbellArchetype, EntityType, LibraryEntry};
+use wrldbldr_protocol::types::{CreatedEntityData, LibraryEntryData};
+use wrldbldr_protocol::{ErrorCode, LibraryRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn join(ws: &mut TestWs, world_id: WorldId, role: ProtoWorldRole, user_id: &str) {
+    ws_send_client(
+        ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role,
+            user_id: user_id.to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+}
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_publishes_an_npc_then_it_can_be_imported_into_another_world() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Ashfall", "desc", now);
+    let world_id = world.id;
+    let sequel = wrldbldr_domain::World::new("Ashfall II", "desc", now);
+    let sequel_id = sequel.id;
+    let villain = wrldbldr_domain::Character::new(world_id, "Lady Vex", CampbellArchetype::Shadow);
+    let villain_id = villain.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo.expect_get().returning(move |id| {
+        Ok(Some(if id == sequel_id {
+            sequel.clone()
+        } else {
+            world.clone()
+        }))
+    });
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .character_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(villain.clone())));
+    let entries: Arc<Mutex<Vec<LibraryEntry>>> = Arc::new(Mutex::new(Vec::new()));
+    let saved = entries.clone();
+    repos.library_repo.expect_save().returning(move |e| {
+        saved.lock().unwrap().push(e.clone());
+        Ok(())
+    });
+    let listed = entries.clone();
+    repos
+        .library_repo
+        .expect_list_for_owner()
+        .returning(move |owner| {
+            Ok(listed
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|e| e.owner_id == owner)
+                .cloned()
+                .collect())
+        });
+    let fetched = entries.clone();
+    repos
+        .library_repo
+        .expect_get()
+        .returning(move |id| Ok(fetched.lock().unwrap().iter().find(|e| e.id == id).cloned()));
+    repos
+        .character_repo
+        .expect_save()
+        .withf(move |c| c.world_id == sequel_id && c.id != villain_id && c.name == "Lady Vex")
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    // Publish from the first campaign
+    let mut dm_ws = ws_connect(addr).await;
+    join(&mut dm_ws, world_id, ProtoWorldRole::Dm, "dm-user").await;
+    let publish = RequestPayload::Library(LibraryRequest::PublishToLibrary {
+        world_id: world_id.to_string(),
+        entity_type: EntityType::Character,
+        entity_id: villain_id.to_string(),
+        description: Some("Recurring villain".to_string()),
+    });
+    let entry: LibraryEntryData = match request(&mut dm_ws, "lib-1", publish).await {
+        ResponseResult::Success { data: Some(data) } => serde_json::from_value(data).unwrap(),
+        other => panic!("unexpected result: {:?}", other),
+    };
+    assert_eq!(entry.name, "Lady Vex");
+    assert_eq!(entry.entity_type, EntityType::Character);
+
+    // Importing needs the DM to be in the target world
+    let import = RequestPayload::Library(LibraryRequest::ImportLibraryEntry {
+        entry_id: entry.id.clone(),
+        world_id: sequel_id.to_string(),
+    });
+    match request(&mut dm_ws, "lib-2", import.clone()).await {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::Forbidden),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // The same user, later, in the sequel
+    let mut sequel_ws = ws_connect(addr).await;
+    join(&mut sequel_ws, sequel_id, ProtoWorldRole::Dm, "dm-user").await;
+    match request(
+        &mut sequel_ws,
+        "lib-3",
+        RequestPayload::Library(LibraryRequest::ListLibrary),
+    )
+    .await
+    {
+        ResponseResult::Success { data: Some(data) } => {
+            let listed: Vec<LibraryEntryData> = serde_json::from_value(data).unwrap();
+            assert_eq!(listed.len(), 1);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match request(&mut sequel_ws, "lib-4", import).await {
+        ResponseResult::Success { data: Some(data) } => {
+            let created: CreatedEntityData = serde_json::from_value(data).unwrap();
+            assert_eq!(created.entity_type, EntityType::Character);
+            assert_ne!(created.entity_id, villain_id.to_string());
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    server.abort();
+}
@@ -0,0 +1,158 @@
+use super::*;
+
+use wrldbldr_domain::{LightLevel, LocationId, RegionId};
+use wrldbldr_protocol::types::LightLevelData;
+use wrldbldr_protocol::{RegionRequest, RequestPayload, ResponseResult};
+
+fn set_request(
+    request_id: &str,
+    region_id: RegionId,
+    lighting: Option<LightLevelData>,
+) -> ClientMessage {
+    ClientMessage::Request {
+        request_id: request_id.to_string(),
+        payload: RequestPayload::Region(RegionRequest::SetRegionLighting {
+            region_id: region_id.to_string(),
+            lighting,
+        }),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_changes_region_lighting_then_world_sees_the_light_in_effect() {
+    let now = chrono::Utc::now();
+
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.game_time.set_day_and_hour(1, 23);
+    let world_id = world.id;
+
+    let location_id = LocationId::new();
+    let mut location = wrldbldr_domain::Location::new(
+        world_id,
+        "Old Road",
+        wrldbldr_domain::LocationType::Exterior,
+    );
+    location.id = location_id;
+
+    let region = wrldbldr_domain::Region::new(location_id, "Crossroads");
+    let region_id = region.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .location_repo
+        .expect_get_region()
+        .returning(move |_| Ok(Some(region.clone())));
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |_| Ok(Some(location.clone())));
+    let saved = Arc::new(std::sync::Mutex::new(None));
+    let saved_for_save = saved.clone();
+    repos
+        .location_repo
+        .expect_save_region()
+        .returning(move |r| {
+            *saved_for_save.lock().unwrap() = Some(r.clone());
+            Ok(())
+        });
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+    for (ws, role, user_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user"),
+        (
+            &mut spectator_ws,
+            ProtoWorldRole::Spectator,
+            "spectator-user",
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id: None,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // Only the DM sets the lights
+    ws_send_client(
+        &mut spectator_ws,
+        &set_request("light-0", region_id, Some(LightLevelData::Bright)),
+    )
+    .await;
+    let denied = ws_expect_message(
+        &mut spectator_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "light-0"),
+    )
+    .await;
+    assert!(matches!(
+        denied,
+        ServerMessage::Response {
+            result: ResponseResult::Error { .. },
+            ..
+        }
+    ));
+    assert!(saved.lock().unwrap().is_none());
+
+    // Torches along the road hold off the night
+    ws_send_client(
+        &mut dm_ws,
+        &set_request("light-1", region_id, Some(LightLevelData::Dim)),
+    )
+    .await;
+    let lit = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::RegionLightChanged { .. })
+    })
+    .await;
+    assert!(matches!(
+        lit,
+        ServerMessage::RegionLightChanged { region_id: id, light_level: LightLevelData::Dim }
+            if id == region_id.to_string()
+    ));
+    assert_eq!(
+        saved.lock().unwrap().as_ref().unwrap().lighting,
+        Some(LightLevel::Dim)
+    );
+
+    // Without them the road is as dark as the night outside
+    ws_send_client(&mut dm_ws, &set_request("light-2", region_id, None)).await;
+    let natural = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::RegionLightChanged { .. })
+    })
+    .await;
+    assert!(matches!(
+        natural,
+        ServerMessage::RegionLightChanged {
+            light_level: LightLevelData::Dark,
+            ..
+        }
+    ));
+    assert_eq!(saved.lock().unwrap().as_ref().unwrap().lighting, None);
+
+    server.abort();
+}
@@ -0,0 +1,71 @@
+use super::*;
+
+#[tokio::test]
+async fn when_client_sets_locale_then_server_errors_are_localized() {
+    let now = chrono::Utc::now();
+
+    let repos = TestAppRepos::new(MockWorldRepo::new());
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+    let mut ws = ws_connect(addr).await;
+
+    // Regional tags fall back to the language catalog.
+    ws_send_client(
+        &mut ws,
+        &ClientMessage::SetLocale {
+            locale: "es-MX".to_string(),
+        },
+    )
+    .await;
+
+    let changed = ws_expect_message(&mut ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::LocaleChanged { .. })
+    })
+    .await;
+    match changed {
+        ServerMessage::LocaleChanged {
+            locale,
+            supported_locales,
+        } => {
+            assert_eq!(locale, "es");
+            assert!(supported_locales.contains(&"en".to_string()));
+        }
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    // Acting before joining a world produces a localized error.
+    ws_send_client(
+        &mut ws,
+        &ClientMessage::PlayerAction {
+            action_type: "examine".to_string(),
+            target: None,
+            dialogue: None,
+            choice_id: None,
+        },
+    )
+    .await;
+
+    let error = ws_expect_message(&mut ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    match error {
+        ServerMessage::Error { code, message } => {
+            assert_eq!(code, "NOT_IN_WORLD");
+            assert_eq!(message, "Primero debes unirte a un mundo");
+        }
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    server.abort();
+}
@@ -0,0 +1,131 @@
+use super::*;
+
+use wrldbldr_domain::{Location, LocationScale, LocationType};
+use wrldbldr_protocol::{ErrorCode, LocationRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn join(ws: &mut TestWs, world_id: WorldId) {
+    ws_send_client(
+        ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+}
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+/// A kingdom holding a city with an inn and a temple
+async fn connect_to_kingdom() -> (TestWs, Vec<Location>) {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now);
+    let world_id = world.id;
+
+    let kingdom = Location::new(world_id, "Valdor", LocationType::Exterior)
+        .with_scale(LocationScale::Kingdom);
+    let city = Location::new(world_id, "Haven", LocationType::Exterior)
+        .with_scale(LocationScale::City)
+        .with_parent(kingdom.id);
+    let inn = Location::new(world_id, "Inn", LocationType::Interior)
+        .with_scale(LocationScale::Building)
+        .with_parent(city.id);
+    let temple = Location::new(world_id, "Temple", LocationType::Interior)
+        .with_scale(LocationScale::Building)
+        .with_parent(city.id);
+    let locations = vec![kingdom, city, inn, temple];
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos.location_repo.checkpoint();
+    let by_id = locations.clone();
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |id| Ok(by_id.iter().find(|l| l.id == id).cloned()));
+    let listed = locations.clone();
+    repos
+        .location_repo
+        .expect_list_locations_in_world()
+        .returning(move |_| Ok(listed.clone()));
+    repos.location_repo.expect_save_location().never();
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, _server) = spawn_ws_server(ws_state).await;
+
+    let mut ws = ws_connect(addr).await;
+    join(&mut ws, world_id).await;
+    (ws, locations)
+}
+
+#[tokio::test]
+async fn when_dm_nests_a_location_inside_its_own_child_then_request_is_refused() {
+    let (mut ws, locations) = connect_to_kingdom().await;
+
+    let payload = RequestPayload::Location(LocationRequest::SetLocationParent {
+        location_id: locations[0].id.to_string(),
+        parent_id: Some(locations[2].id.to_string()),
+    });
+    match request(&mut ws, "nest-1", payload).await {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::BadRequest),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_travelling_between_buildings_then_time_is_the_city_crossing() {
+    let (mut ws, locations) = connect_to_kingdom().await;
+
+    let payload = RequestPayload::Location(LocationRequest::GetTravelTime {
+        from_location_id: locations[2].id.to_string(),
+        to_location_id: locations[3].id.to_string(),
+    });
+    match request(&mut ws, "travel-1", payload).await {
+        ResponseResult::Success { data: Some(data) } => {
+            assert_eq!(data["minutes"], 45);
+            assert_eq!(data["via_location_name"], "Haven");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
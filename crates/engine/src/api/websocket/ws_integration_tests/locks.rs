@@ -0,0 +1,189 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{Item, LocationId, Region, RegionConnection};
+use wrldbldr_protocol::types::{
+    ConnectionLockData, LockedWayData, UnlockMethodData, UnlockedConnectionData,
+};
+use wrldbldr_protocol::{RegionRequest, RequestPayload, ResponseResult};
+
+#[tokio::test]
+async fn when_a_pc_uses_the_right_key_then_the_world_hears_the_way_is_open() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now);
+    let world_id = world.id;
+
+    let location_id = LocationId::new();
+    let cellar = Region::new(location_id, "Cellar");
+    let vault = Region::new(location_id, "Vault");
+    let (cellar_id, vault_id) = (cellar.id, vault.id);
+
+    let iron_key = Item::new(world_id, "Iron Key");
+    let spoon = Item::new(world_id, "Spoon");
+    let (iron_key_id, spoon_id) = (iron_key.id, spoon.id);
+
+    let mut alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", location_id, now);
+    alice.current_region_id = Some(cellar_id);
+    let alice_id = alice.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok((id == alice_id).then(|| alice.clone())));
+    repos
+        .player_character_repo
+        .expect_get_inventory()
+        .returning(move |_| Ok(vec![spoon.clone(), iron_key.clone()]));
+
+    let connection = Arc::new(Mutex::new(
+        RegionConnection::new(cellar_id, vault_id).unwrap(),
+    ));
+    let for_get = connection.clone();
+    repos
+        .location_repo
+        .expect_get_connections()
+        .returning(move |_| Ok(vec![for_get.lock().unwrap().clone()]));
+    let for_save = connection.clone();
+    repos
+        .location_repo
+        .expect_save_connection()
+        .returning(move |c| {
+            *for_save.lock().unwrap() = c.clone();
+            Ok(())
+        });
+    repos
+        .location_repo
+        .expect_get_region()
+        .returning(move |id| Ok((id == vault_id).then(|| vault.clone())));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut alice_ws = ws_connect(addr).await;
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut alice_ws,
+            ProtoWorldRole::Player,
+            "alice-user",
+            Some(*alice_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // The DM locks the vault behind the iron key
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "lock".to_string(),
+            payload: RequestPayload::Region(RegionRequest::SetRegionConnectionLock {
+                from_id: cellar_id.to_string(),
+                to_id: vault_id.to_string(),
+                lock: Some(ConnectionLockData {
+                    description: Some("A heavy iron door".to_string()),
+                    key_item_ids: vec![iron_key_id.to_string()],
+                    challenge_id: None,
+                }),
+            }),
+        },
+    )
+    .await;
+    let locked = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "lock"),
+    )
+    .await;
+    assert!(matches!(
+        locked,
+        ServerMessage::Response {
+            result: ResponseResult::Success { .. },
+            ..
+        }
+    ));
+    assert!(connection.lock().unwrap().is_locked);
+
+    let try_key = |item_id: String| ClientMessage::UnlockWithKey {
+        pc_id: alice_id.to_string(),
+        item_id,
+        to: LockedWayData::Region {
+            region_id: vault_id.to_string(),
+        },
+    };
+
+    ws_send_client(&mut alice_ws, &try_key(spoon_id.to_string())).await;
+    let blocked = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::MovementBlocked { .. })
+    })
+    .await;
+    assert!(matches!(
+        blocked,
+        ServerMessage::MovementBlocked { reason, .. } if reason == "It doesn't fit the lock"
+    ));
+    assert!(connection.lock().unwrap().is_locked);
+
+    ws_send_client(&mut alice_ws, &try_key(iron_key_id.to_string())).await;
+    let opened = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ConnectionUnlocked { .. })
+    })
+    .await;
+    let ServerMessage::ConnectionUnlocked { connection: opened } = opened else {
+        panic!("expected ConnectionUnlocked");
+    };
+    assert_eq!(
+        opened,
+        UnlockedConnectionData {
+            pc_id: alice_id.to_string(),
+            pc_name: "Alice".to_string(),
+            from_id: cellar_id.to_string(),
+            to: LockedWayData::Region {
+                region_id: vault_id.to_string(),
+            },
+            to_name: "Vault".to_string(),
+            method: UnlockMethodData::Key,
+            opened_with: "Iron Key".to_string(),
+        }
+    );
+    let _ = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ConnectionUnlocked { .. })
+    })
+    .await;
+
+    // The door stays keyed so the DM can lock it again
+    let saved = connection.lock().unwrap().clone();
+    assert!(!saved.is_locked);
+    assert!(saved.lock.opened_by_key(iron_key_id));
+
+    server.abort();
+}
@@ -0,0 +1,271 @@
+use super::*;
+
+use chrono::TimeZone;
+use wrldbldr_domain::{GameTime, Letter};
+use wrldbldr_protocol::{
+    types::{CorrespondentData, CorrespondentKindData, LetterData, LetterInputData},
+    ErrorCode, MailRequest, RequestPayload, ResponseResult, TimeRequest,
+};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_game_time_reaches_delivery_then_letter_reaches_addressee() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+    // Mid-week, so advancing the clock does not also draft a broadsheet
+    world.game_time =
+        GameTime::starting_at(chrono::Utc.with_ymd_and_hms(2024, 3, 1, 8, 0, 0).unwrap());
+
+    let location_id = wrldbldr_domain::LocationId::new();
+    let alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", location_id, now);
+    let bram =
+        wrldbldr_domain::PlayerCharacter::new("bram-user", world_id, "Bram", location_id, now);
+    let (alice_id, bram_id) = (alice.id, bram.id);
+
+    // The world is stored so the clock can move on
+    let stored_world = Arc::new(Mutex::new(world));
+    let mut world_repo = MockWorldRepo::new();
+    let fetched = stored_world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(fetched.lock().unwrap().clone())));
+    let saved = stored_world.clone();
+    world_repo.expect_save().returning(move |w| {
+        *saved.lock().unwrap() = w.clone();
+        Ok(())
+    });
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let pcs = [alice.clone(), bram.clone()];
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok(pcs.iter().find(|pc| pc.id == id).cloned()));
+    repos
+        .location_repo
+        .expect_list_locations_in_world()
+        .returning(|_| Ok(vec![]));
+    repos
+        .injury_repo
+        .expect_list_active_in_world()
+        .returning(|_| Ok(vec![]));
+
+    let letters: Arc<Mutex<Vec<Letter>>> = Arc::new(Mutex::new(Vec::new()));
+    let stored = letters.clone();
+    repos
+        .letter_repo
+        .expect_get()
+        .returning(move |id| Ok(stored.lock().unwrap().iter().find(|l| l.id == id).cloned()));
+    let stored = letters.clone();
+    repos.letter_repo.expect_save().returning(move |letter| {
+        let mut stored = stored.lock().unwrap();
+        stored.retain(|l| l.id != letter.id);
+        stored.push(letter.clone());
+        Ok(())
+    });
+    let stored = letters.clone();
+    repos
+        .letter_repo
+        .expect_list_for_pc()
+        .returning(move |pc_id| {
+            Ok(stored
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|l| l.involves_pc(pc_id))
+                .cloned()
+                .collect())
+        });
+    let stored = letters.clone();
+    repos
+        .letter_repo
+        .expect_list_undelivered_in_world()
+        .returning(move |_| {
+            Ok(stored
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|l| !l.is_delivered())
+                .cloned()
+                .collect())
+        });
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut alice_ws = ws_connect(addr).await;
+    let mut bram_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut alice_ws,
+            ProtoWorldRole::Player,
+            "alice-user",
+            Some(*alice_id.as_uuid()),
+        ),
+        (
+            &mut bram_ws,
+            ProtoWorldRole::Player,
+            "bram-user",
+            Some(*bram_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    let sent = request(
+        &mut alice_ws,
+        "send",
+        RequestPayload::Mail(MailRequest::SendLetter {
+            pc_id: alice_id.to_string(),
+            data: LetterInputData {
+                recipient: CorrespondentData {
+                    kind: CorrespondentKindData::Pc,
+                    id: bram_id.to_string(),
+                    name: None,
+                },
+                subject: "The ledger".to_string(),
+                body: "Burn it before the magistrate comes.".to_string(),
+                in_reply_to: None,
+            },
+        }),
+    )
+    .await;
+    let letter = match sent {
+        ResponseResult::Success {
+            data: Some(data), ..
+        } => serde_json::from_value::<LetterData>(data).unwrap(),
+        other => panic!("expected sent letter, got: {:?}", other),
+    };
+    assert!(!letter.delivered);
+    assert_eq!(letter.recipient.name.as_deref(), Some("Bram"));
+
+    // The DM watches the mail; Bram sees nothing while it is on the road
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::LetterUpdated { .. })
+    })
+    .await;
+    ws_expect_no_message_matching(&mut bram_ws, Duration::from_millis(200), |m| {
+        matches!(m, ServerMessage::LetterUpdated { .. })
+    })
+    .await;
+
+    let snooped = request(
+        &mut bram_ws,
+        "snoop",
+        RequestPayload::Mail(MailRequest::ListMailbox {
+            pc_id: alice_id.to_string(),
+        }),
+    )
+    .await;
+    assert!(matches!(
+        snooped,
+        ResponseResult::Error {
+            code: ErrorCode::Forbidden,
+            ..
+        }
+    ));
+
+    let advanced = request(
+        &mut dm_ws,
+        "advance",
+        RequestPayload::Time(TimeRequest::AdvanceGameTime {
+            world_id: world_id.to_string(),
+            hours: 2,
+        }),
+    )
+    .await;
+    assert!(matches!(advanced, ResponseResult::Success { .. }));
+
+    let arrived = ws_expect_message(&mut bram_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::LetterUpdated { .. })
+    })
+    .await;
+    assert!(matches!(
+        arrived,
+        ServerMessage::LetterUpdated { letter } if letter.delivered && !letter.read
+    ));
+
+    let read = request(
+        &mut bram_ws,
+        "read",
+        RequestPayload::Mail(MailRequest::MarkLetterRead {
+            letter_id: letter.id.clone(),
+        }),
+    )
+    .await;
+    assert!(matches!(read, ResponseResult::Success { .. }));
+
+    let mailbox = request(
+        &mut bram_ws,
+        "mailbox",
+        RequestPayload::Mail(MailRequest::ListMailbox {
+            pc_id: bram_id.to_string(),
+        }),
+    )
+    .await;
+    let mailbox = match mailbox {
+        ResponseResult::Success {
+            data: Some(data), ..
+        } => serde_json::from_value::<Vec<LetterData>>(data).unwrap(),
+        other => panic!("expected mailbox, got: {:?}", other),
+    };
+    assert_eq!(mailbox.len(), 1);
+    assert!(mailbox[0].read);
+
+    server.abort();
+}
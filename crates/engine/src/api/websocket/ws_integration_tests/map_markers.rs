@@ -0,0 +1,212 @@
+use super::*;
+
+use wrldbldr_domain::{
+    DmMarkerType, LocationId, MarkerImportance, MarkerPin, StoryEvent, StoryEventType,
+};
+use wrldbldr_protocol::{MapMarkerData, RequestPayload, ResponseResult, StoryEventRequest};
+
+fn pinned_marker(
+    world_id: WorldId,
+    title: &str,
+    pin: Option<MarkerPin>,
+    hidden: bool,
+    now: chrono::DateTime<chrono::Utc>,
+) -> StoryEvent {
+    let mut event = StoryEvent::new(
+        world_id,
+        StoryEventType::DmMarker {
+            title: title.to_string(),
+            note: String::new(),
+            importance: MarkerImportance::Notable,
+            marker_type: DmMarkerType::Note,
+            pin,
+        },
+        now,
+    );
+    event.is_hidden = hidden;
+    event
+}
+
+fn list_request(request_id: &str, world_id: WorldId, location_id: LocationId) -> ClientMessage {
+    ClientMessage::Request {
+        request_id: request_id.to_string(),
+        payload: RequestPayload::StoryEvent(StoryEventRequest::ListMapMarkers {
+            world_id: world_id.to_string(),
+            location_id: location_id.to_string(),
+            region_id: None,
+        }),
+    }
+}
+
+async fn expect_markers(
+    ws: &mut tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >,
+    request_id: &str,
+) -> Vec<MapMarkerData> {
+    let response = ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await;
+    match response {
+        ServerMessage::Response {
+            result: ResponseResult::Success { data: Some(data) },
+            ..
+        } => serde_json::from_value(data).expect("map markers"),
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_hides_pinned_marker_then_players_stop_seeing_it() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+    let location_id = LocationId::new();
+
+    let visible = pinned_marker(
+        world_id,
+        "Ambush site",
+        Some(MarkerPin::new(location_id, None, 25.0, 140.0)),
+        false,
+        now,
+    );
+    let secret = pinned_marker(
+        world_id,
+        "Hidden door",
+        Some(MarkerPin::new(location_id, None, 60.0, 40.0)),
+        true,
+        now,
+    );
+    let elsewhere = pinned_marker(
+        world_id,
+        "Other map",
+        Some(MarkerPin::new(LocationId::new(), None, 10.0, 10.0)),
+        false,
+        now,
+    );
+    let unpinned = pinned_marker(world_id, "Just a note", None, false, now);
+    let events = vec![visible.clone(), secret.clone(), elsewhere, unpinned];
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .narrative_repo
+        .expect_list_story_events()
+        .returning(move |_, _| Ok(events.clone()));
+    let visible_for_get = visible.clone();
+    repos
+        .narrative_repo
+        .expect_get_story_event()
+        .returning(move |_| Ok(Some(visible_for_get.clone())));
+    repos
+        .narrative_repo
+        .expect_save_story_event()
+        .returning(|_| Ok(()));
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user"),
+        (
+            &mut spectator_ws,
+            ProtoWorldRole::Spectator,
+            "spectator-user",
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id: None,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // The DM sees every marker on this map; players only the visible one.
+    ws_send_client(
+        &mut dm_ws,
+        &list_request("markers-1", world_id, location_id),
+    )
+    .await;
+    let dm_markers = expect_markers(&mut dm_ws, "markers-1").await;
+    assert_eq!(dm_markers.len(), 2);
+
+    ws_send_client(
+        &mut spectator_ws,
+        &list_request("markers-2", world_id, location_id),
+    )
+    .await;
+    let player_markers = expect_markers(&mut spectator_ws, "markers-2").await;
+    assert_eq!(player_markers.len(), 1);
+    assert_eq!(player_markers[0].id, visible.id.to_string());
+    assert_eq!(player_markers[0].title, "Ambush site");
+    // Pins are clamped to the image.
+    assert_eq!(player_markers[0].pin.y, 100.0);
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "hide-1".to_string(),
+            payload: RequestPayload::StoryEvent(StoryEventRequest::SetStoryEventVisibility {
+                event_id: visible.id.to_string(),
+                visible: false,
+            }),
+        },
+    )
+    .await;
+
+    let removed = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::MapMarkerRemoved { .. })
+    })
+    .await;
+    match removed {
+        ServerMessage::MapMarkerRemoved { marker_id } => {
+            assert_eq!(marker_id, visible.id.to_string())
+        }
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    // The DM keeps the marker, now flagged hidden.
+    let updated = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::MapMarkerUpdated { .. })
+    })
+    .await;
+    match updated {
+        ServerMessage::MapMarkerUpdated { marker } => assert!(!marker.visible_to_players),
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    server.abort();
+}
@@ -0,0 +1,275 @@
+use super::*;
+
+use wrldbldr_domain::{CampbellArchetype, EntityMention, EntityType};
+use wrldbldr_protocol::types::EntityMentionData;
+use wrldbldr_protocol::{ErrorCode, MentionRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, request: MentionRequest) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload: RequestPayload::Mention(request),
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_adds_an_alias_then_text_using_it_is_indexed_as_a_mention() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Varn", "desc", now);
+    let world_id = world.id;
+    let mira = wrldbldr_domain::Character::new(world_id, "Mira Vance", CampbellArchetype::Ally);
+    let oss = wrldbldr_domain::Character::new(world_id, "Oss", CampbellArchetype::Trickster)
+        .with_description("Still owes the Widow for the boat.");
+    let mira_id = mira.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let characters = vec![mira, oss];
+    repos.character_repo.checkpoint();
+    repos
+        .character_repo
+        .expect_list_in_world()
+        .returning(move |_| Ok(characters.clone()));
+    repos
+        .item_repo
+        .expect_list_in_world()
+        .returning(|_| Ok(vec![]));
+    repos
+        .lore_repo
+        .expect_list_for_world()
+        .returning(|_| Ok(vec![]));
+    repos
+        .narrative_repo
+        .expect_list_story_events()
+        .returning(|_, _| Ok(vec![]));
+
+    let aliases: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let mentions: Arc<Mutex<Vec<EntityMention>>> = Arc::new(Mutex::new(Vec::new()));
+    repos.mention_repo.checkpoint();
+    let listed = aliases.clone();
+    repos
+        .mention_repo
+        .expect_list_aliases()
+        .returning(move |_| {
+            Ok([(
+                (EntityType::Character, mira_id.to_uuid()),
+                listed.lock().unwrap().clone(),
+            )]
+            .into_iter()
+            .collect())
+        });
+    let set = aliases.clone();
+    repos
+        .mention_repo
+        .expect_set_aliases()
+        .times(3)
+        .returning(move |_, _, _, a| {
+            *set.lock().unwrap() = a.to_vec();
+            Ok(())
+        });
+    let cleared = mentions.clone();
+    repos
+        .mention_repo
+        .expect_clear_mentions()
+        .returning(move |_| {
+            cleared.lock().unwrap().clear();
+            Ok(())
+        });
+    let replaced = mentions.clone();
+    repos
+        .mention_repo
+        .expect_replace_mentions()
+        .returning(move |_, _, _, found| {
+            replaced.lock().unwrap().extend(found.iter().cloned());
+            Ok(())
+        });
+    let stored = mentions.clone();
+    repos
+        .mention_repo
+        .expect_list_mentions_of()
+        .returning(move |target_type, target_id| {
+            Ok(stored
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|m| m.target_type == target_type && m.target_id == target_id)
+                .cloned()
+                .collect())
+        });
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    // Nobody calls Mira by name yet
+    match request(
+        &mut dm_ws,
+        "mention-1",
+        MentionRequest::GetMentionsOf {
+            entity_type: EntityType::Character,
+            entity_id: mira_id.to_string(),
+        },
+    )
+    .await
+    {
+        ResponseResult::Success { data: Some(data) } => {
+            let found: Vec<EntityMentionData> = serde_json::from_value(data).unwrap();
+            assert!(found.is_empty());
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // Giving her an alias finds Oss's description
+    match request(
+        &mut dm_ws,
+        "mention-2",
+        MentionRequest::SetEntityAliases {
+            entity_type: EntityType::Character,
+            entity_id: mira_id.to_string(),
+            aliases: vec![" the Widow ".to_string()],
+        },
+    )
+    .await
+    {
+        ResponseResult::Success { data: Some(data) } => {
+            assert_eq!(data["aliases"], serde_json::json!(["the Widow"]));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    match request(
+        &mut dm_ws,
+        "mention-3",
+        MentionRequest::GetMentionsOf {
+            entity_type: EntityType::Character,
+            entity_id: mira_id.to_string(),
+        },
+    )
+    .await
+    {
+        ResponseResult::Success { data: Some(data) } => {
+            let found: Vec<EntityMentionData> = serde_json::from_value(data).unwrap();
+            assert_eq!(found.len(), 1);
+            assert_eq!(found[0].source_name, "Oss");
+            assert_eq!(found[0].target_name, "Mira Vance");
+            assert_eq!(found[0].matched_text, "the Widow");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // Aliases can be added and removed one at a time
+    match request(
+        &mut dm_ws,
+        "mention-4",
+        MentionRequest::AddEntityAlias {
+            entity_type: EntityType::Character,
+            entity_id: mira_id.to_string(),
+            alias: "Old Mira".to_string(),
+        },
+    )
+    .await
+    {
+        ResponseResult::Success { data: Some(data) } => {
+            assert_eq!(
+                data["aliases"],
+                serde_json::json!(["the Widow", "Old Mira"])
+            );
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    match request(
+        &mut dm_ws,
+        "mention-5",
+        MentionRequest::RemoveEntityAlias {
+            entity_type: EntityType::Character,
+            entity_id: mira_id.to_string(),
+            alias: "THE WIDOW".to_string(),
+        },
+    )
+    .await
+    {
+        ResponseResult::Success { data: Some(data) } => {
+            assert_eq!(data["aliases"], serde_json::json!(["Old Mira"]));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    match request(
+        &mut dm_ws,
+        "mention-6",
+        MentionRequest::GetEntityAliases {
+            entity_type: EntityType::Character,
+            entity_id: mira_id.to_string(),
+        },
+    )
+    .await
+    {
+        ResponseResult::Success { data: Some(data) } => {
+            assert_eq!(data["aliases"], serde_json::json!(["Old Mira"]));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // Lore is searched for mentions but is never mentioned itself
+    match request(
+        &mut dm_ws,
+        "mention-7",
+        MentionRequest::SetEntityAliases {
+            entity_type: EntityType::Lore,
+            entity_id: Uuid::new_v4().to_string(),
+            aliases: vec!["Old tale".to_string()],
+        },
+    )
+    .await
+    {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::ValidationError),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    server.abort();
+}
@@ -0,0 +1,183 @@
+use super::*;
+
+use wrldbldr_domain::{LocationId, RegionId};
+
+#[tokio::test]
+async fn when_solo_player_controls_second_pc_then_they_can_act_as_it() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let location_id = LocationId::new();
+    let region_id = RegionId::new();
+
+    let mut pcs = Vec::new();
+    for (user_id, name) in [
+        ("solo-user", "Ash"),
+        ("solo-user", "Bryn"),
+        ("other-user", "Cole"),
+    ] {
+        let mut pc =
+            wrldbldr_domain::PlayerCharacter::new(user_id, world_id, name, location_id, now);
+        pc.current_region_id = Some(region_id);
+        pcs.push(pc);
+    }
+    let (ash_id, bryn_id, cole_id) = (pcs[0].id, pcs[1].id, pcs[2].id);
+
+    let lantern = wrldbldr_domain::Item::new(world_id, "Lantern");
+    let lantern_id = lantern.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok(pcs.iter().find(|pc| pc.id == id).cloned()));
+    repos
+        .player_character_repo
+        .expect_get_inventory()
+        .returning(move |id| {
+            Ok(if id == bryn_id {
+                vec![lantern.clone()]
+            } else {
+                vec![]
+            })
+        });
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut solo_ws = ws_connect(addr).await;
+    let mut other_ws = ws_connect(addr).await;
+
+    for (ws, user_id, pc_id) in [
+        (&mut solo_ws, "solo-user", ash_id),
+        (&mut other_ws, "other-user", cole_id),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role: ProtoWorldRole::Player,
+                user_id: user_id.to_string(),
+                pc_id: Some(*pc_id.as_uuid()),
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    let offer_from_bryn = ClientMessage::OfferTrade {
+        from_pc_id: bryn_id.to_string(),
+        to_pc_id: cole_id.to_string(),
+        offered_item_ids: vec![lantern_id.to_string()],
+        offered_currency: 0,
+        requested_item_ids: vec![],
+        requested_currency: 0,
+    };
+
+    // Bryn isn't controlled yet.
+    ws_send_client(&mut solo_ws, &offer_from_bryn).await;
+    let denied = ws_expect_message(&mut solo_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(denied, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"));
+
+    // Cole belongs to someone else.
+    ws_send_client(
+        &mut solo_ws,
+        &ClientMessage::ControlPc {
+            pc_id: *cole_id.as_uuid(),
+        },
+    )
+    .await;
+    let denied = ws_expect_message(&mut solo_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(denied, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"));
+
+    ws_send_client(
+        &mut solo_ws,
+        &ClientMessage::ControlPc {
+            pc_id: *bryn_id.as_uuid(),
+        },
+    )
+    .await;
+    let controlled = ws_expect_message(&mut solo_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ControlledPcsChanged { .. })
+    })
+    .await;
+    match controlled {
+        ServerMessage::ControlledPcsChanged { active_pc_id, pcs } => {
+            assert_eq!(active_pc_id, *ash_id.as_uuid());
+            let names: Vec<_> = pcs.iter().map(|pc| pc["name"].clone()).collect();
+            assert_eq!(names, vec!["Ash", "Bryn"]);
+        }
+        other => panic!("expected ControlledPcsChanged, got {:?}", other),
+    }
+
+    // Now the solo player can act as Bryn without switching.
+    ws_send_client(&mut solo_ws, &offer_from_bryn).await;
+    let offered = ws_expect_message(&mut other_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::TradeOffered { .. })
+    })
+    .await;
+    assert!(
+        matches!(offered, ServerMessage::TradeOffered { trade } if trade.from_pc_name == "Bryn")
+    );
+
+    ws_send_client(
+        &mut solo_ws,
+        &ClientMessage::SwitchPc {
+            pc_id: *bryn_id.as_uuid(),
+        },
+    )
+    .await;
+    let switched = ws_expect_message(&mut solo_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ControlledPcsChanged { .. })
+    })
+    .await;
+    assert!(matches!(
+        switched,
+        ServerMessage::ControlledPcsChanged { active_pc_id, .. } if active_pc_id == *bryn_id.as_uuid()
+    ));
+
+    // Only controlled PCs can be switched to.
+    ws_send_client(
+        &mut solo_ws,
+        &ClientMessage::SwitchPc {
+            pc_id: *cole_id.as_uuid(),
+        },
+    )
+    .await;
+    let denied = ws_expect_message(&mut solo_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(denied, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"));
+
+    server.abort();
+}
@@ -0,0 +1,157 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{CampbellArchetype, Character, NameGenerator};
+use wrldbldr_protocol::types::{
+    GeneratedNamesData, NameGeneratorData, NameGeneratorInputData, NameStyleData,
+};
+use wrldbldr_protocol::{GenerationRequest, NameGeneratorRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+fn data<T: serde::de::DeserializeOwned>(result: ResponseResult) -> T {
+    match result {
+        ResponseResult::Success { data: Some(data) } => serde_json::from_value(data).unwrap(),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_defines_a_naming_culture_then_new_names_skip_existing_characters() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Varn", "desc", now);
+    let world_id = world.id;
+    let elara = Character::new(world_id, "Elara", CampbellArchetype::Ally);
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos.character_repo.checkpoint();
+    repos
+        .character_repo
+        .expect_list_in_world()
+        .returning(move |_| Ok(vec![elara.clone()]));
+    repos.name_generator_repo.checkpoint();
+    let stored: Arc<Mutex<Vec<NameGenerator>>> = Arc::new(Mutex::new(Vec::new()));
+    let saved = stored.clone();
+    repos.name_generator_repo.expect_save().returning(move |g| {
+        let mut generators = saved.lock().unwrap();
+        generators.retain(|existing| existing.id != g.id);
+        generators.push(g.clone());
+        Ok(())
+    });
+    let listed = stored.clone();
+    repos
+        .name_generator_repo
+        .expect_list_for_world()
+        .returning(move |_| Ok(listed.lock().unwrap().clone()));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    // Before any generator exists there is nothing to draw from
+    let missing = request(
+        &mut dm_ws,
+        "names-1",
+        RequestPayload::Generation(GenerationRequest::GenerateNames {
+            world_id: world_id.to_string(),
+            generator_id: None,
+            region_id: None,
+            faction: None,
+            count: 3,
+        }),
+    )
+    .await;
+    assert!(matches!(missing, ResponseResult::Error { .. }));
+
+    let created: NameGeneratorData = data(
+        request(
+            &mut dm_ws,
+            "names-2",
+            RequestPayload::NameGenerator(NameGeneratorRequest::CreateNameGenerator {
+                data: NameGeneratorInputData {
+                    name: "Varnish villagers".to_string(),
+                    description: String::new(),
+                    style: NameStyleData::List {
+                        given_names: vec!["Elara".to_string(), "Tomas".to_string()],
+                        family_names: vec![],
+                    },
+                    region_ids: vec![],
+                    factions: vec![],
+                },
+            }),
+        )
+        .await,
+    );
+    assert!(created.is_default);
+
+    // The world default names everyone; Elara is already taken
+    let generated: GeneratedNamesData = data(
+        request(
+            &mut dm_ws,
+            "names-3",
+            RequestPayload::Generation(GenerationRequest::GenerateNames {
+                world_id: world_id.to_string(),
+                generator_id: None,
+                region_id: None,
+                faction: Some("Harbour Guild".to_string()),
+                count: 3,
+            }),
+        )
+        .await,
+    );
+    assert_eq!(generated.generator_id, created.id);
+    assert_eq!(generated.names, vec!["Tomas".to_string()]);
+
+    server.abort();
+}
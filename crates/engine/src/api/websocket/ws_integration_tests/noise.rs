@@ -0,0 +1,237 @@
+use super::*;
+
+use wrldbldr_domain::{CharacterId, LocationId, Region, RegionConnection, SoundCarry, StagedNpc};
+use wrldbldr_protocol::types::{LoudnessData, SoundCarryData};
+use wrldbldr_protocol::{RegionRequest, RequestPayload, ResponseResult};
+
+#[tokio::test]
+async fn when_a_pc_makes_noise_then_only_open_neighbours_hear_it() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now);
+    let world_id = world.id;
+
+    let mut manor =
+        wrldbldr_domain::Location::new(world_id, "Manor", wrldbldr_domain::LocationType::Interior);
+    let manor_id = LocationId::new();
+    manor.id = manor_id;
+
+    let hall = Region::new(manor_id, "Hall");
+    let kitchen = Region::new(manor_id, "Kitchen");
+    let study = Region::new(manor_id, "Study");
+    let vault = Region::new(manor_id, "Vault");
+    let (hall_id, kitchen_id, study_id) = (hall.id, kitchen.id, study.id);
+    let connections = vec![
+        RegionConnection::new(hall_id, kitchen_id).unwrap(),
+        RegionConnection::new(hall_id, study_id)
+            .unwrap()
+            .with_sound(SoundCarry::Muffled),
+        RegionConnection::new(hall_id, vault.id)
+            .unwrap()
+            .with_sound(SoundCarry::Blocked),
+    ];
+    let regions = [hall, kitchen, study, vault];
+
+    let mut alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", manor_id, now);
+    alice.current_region_id = Some(hall_id);
+    let mut bob = wrldbldr_domain::PlayerCharacter::new("bob-user", world_id, "Bob", manor_id, now);
+    bob.current_region_id = Some(kitchen_id);
+    let mut carol =
+        wrldbldr_domain::PlayerCharacter::new("carol-user", world_id, "Carol", manor_id, now);
+    carol.current_region_id = Some(study_id);
+    let (alice_id, bob_id, carol_id) = (alice.id, bob.id, carol.id);
+    let pcs = vec![alice, bob, carol];
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let pcs_by_id = pcs.clone();
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok(pcs_by_id.iter().find(|pc| pc.id == id).cloned()));
+    repos
+        .player_character_repo
+        .expect_list_in_world()
+        .returning(move |_| Ok(pcs.clone()));
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |_| Ok(Some(manor.clone())));
+    repos
+        .location_repo
+        .expect_get_region()
+        .returning(move |id| Ok(regions.iter().find(|r| r.id == id).cloned()));
+    let connections_for_get = connections.clone();
+    repos
+        .location_repo
+        .expect_get_connections()
+        .returning(move |id| {
+            Ok(connections_for_get
+                .iter()
+                .filter(|c| c.from_region == id)
+                .cloned()
+                .collect())
+        });
+    let saved = Arc::new(std::sync::Mutex::new(None));
+    let saved_for_save = saved.clone();
+    repos
+        .location_repo
+        .expect_save_connection()
+        .returning(move |c| {
+            *saved_for_save.lock().unwrap() = Some(c.clone());
+            Ok(())
+        });
+    repos
+        .staging_repo
+        .expect_get_staged_npcs()
+        .returning(move |id| {
+            Ok(if id == kitchen_id {
+                vec![StagedNpc::new(CharacterId::new(), "Cook", true, "Baking")]
+            } else {
+                vec![]
+            })
+        });
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut alice_ws = ws_connect(addr).await;
+    let mut bob_ws = ws_connect(addr).await;
+    let mut carol_ws = ws_connect(addr).await;
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut alice_ws,
+            ProtoWorldRole::Player,
+            "alice-user",
+            Some(*alice_id.as_uuid()),
+        ),
+        (
+            &mut bob_ws,
+            ProtoWorldRole::Player,
+            "bob-user",
+            Some(*bob_id.as_uuid()),
+        ),
+        (
+            &mut carol_ws,
+            ProtoWorldRole::Player,
+            "carol-user",
+            Some(*carol_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // Alice can't make noise in a room she isn't in
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::MakeNoise {
+            region_id: kitchen_id.to_string(),
+            description: "A pot clatters".to_string(),
+            loudness: LoudnessData::Loud,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(
+        &mut alice_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"),
+    )
+    .await;
+
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::MakeNoise {
+            region_id: hall_id.to_string(),
+            description: "A chair crashes to the floor".to_string(),
+            loudness: LoudnessData::Loud,
+        },
+    )
+    .await;
+
+    let heard = ws_expect_message(&mut bob_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::NoiseHeard { .. })
+    })
+    .await;
+    assert!(matches!(
+        heard,
+        ServerMessage::NoiseHeard { region_id, from_region_name, muffled: false, .. }
+            if region_id == kitchen_id.to_string() && from_region_name == "Hall"
+    ));
+
+    let report = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::NoiseReport { .. })
+    })
+    .await;
+    let ServerMessage::NoiseReport { heard_in, .. } = report else {
+        panic!("expected NoiseReport");
+    };
+    // The study door holds back a mere crash; the vault hears nothing
+    assert_eq!(heard_in.len(), 1);
+    assert_eq!(heard_in[0].region_name, "Kitchen");
+    assert_eq!(heard_in[0].npc_names, vec!["Cook".to_string()]);
+    assert_eq!(heard_in[0].pc_names, vec!["Bob".to_string()]);
+    ws_expect_no_message_matching(&mut carol_ws, Duration::from_millis(200), |m| {
+        matches!(m, ServerMessage::NoiseHeard { .. })
+    })
+    .await;
+
+    // The DM bricks up the kitchen arch
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "sound-1".to_string(),
+            payload: RequestPayload::Region(RegionRequest::SetRegionConnectionSound {
+                from_id: hall_id.to_string(),
+                to_id: kitchen_id.to_string(),
+                sound: SoundCarryData::Blocked,
+            }),
+        },
+    )
+    .await;
+    let response = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "sound-1"),
+    )
+    .await;
+    assert!(matches!(
+        response,
+        ServerMessage::Response {
+            result: ResponseResult::Success { .. },
+            ..
+        }
+    ));
+    assert_eq!(
+        saved.lock().unwrap().as_ref().unwrap().sound,
+        SoundCarry::Blocked
+    );
+
+    server.abort();
+}
@@ -0,0 +1,193 @@
+use super::*;
+
+use wrldbldr_domain::{AppSettings, Crowd, DispositionLevel, NpcDraft};
+use wrldbldr_protocol::types::{
+    CreatedEntityData, NpcDraftData, NpcDraftSourceData, NpcDraftStatusData,
+};
+use wrldbldr_protocol::{NpcDraftRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, request: NpcDraftRequest) {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload: RequestPayload::NpcDraft(request),
+        },
+    )
+    .await;
+}
+
+async fn expect_success(ws: &mut TestWs, request_id: &str) -> serde_json::Value {
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response {
+            result: ResponseResult::Success { data: Some(data) },
+            ..
+        } => data,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_promotes_a_crowd_member_then_the_llm_fills_a_draft_to_approve() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Varn", "desc", now);
+    let world_id = world.id;
+    let crowd = Crowd::new(
+        world_id,
+        wrldbldr_domain::RegionId::new(),
+        "dock workers",
+        40,
+        now,
+    )
+    .expect("crowd");
+    let crowd_id = crowd.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .settings_repo
+        .expect_get_for_world()
+        .returning(|_| Ok(Some(AppSettings::default())));
+    repos
+        .location_repo
+        .expect_get_region()
+        .returning(|_| Ok(None));
+    let crowds = Arc::new(Mutex::new(vec![crowd]));
+    let fetched = crowds.clone();
+    repos
+        .crowd_repo
+        .expect_get()
+        .returning(move |id| Ok(fetched.lock().unwrap().iter().find(|c| c.id == id).cloned()));
+    let saved = crowds.clone();
+    repos.crowd_repo.expect_save().times(1).returning(move |c| {
+        saved.lock().unwrap()[0] = c.clone();
+        Ok(())
+    });
+
+    let drafts: Arc<Mutex<Vec<NpcDraft>>> = Arc::new(Mutex::new(Vec::new()));
+    let stored = drafts.clone();
+    repos.npc_draft_repo.expect_save().returning(move |d| {
+        let mut drafts = stored.lock().unwrap();
+        drafts.retain(|existing| existing.id != d.id);
+        drafts.push(d.clone());
+        Ok(())
+    });
+    let fetched = drafts.clone();
+    repos
+        .npc_draft_repo
+        .expect_get()
+        .returning(move |id| Ok(fetched.lock().unwrap().iter().find(|d| d.id == id).cloned()));
+    let deleted = drafts.clone();
+    repos
+        .npc_draft_repo
+        .expect_delete()
+        .times(1)
+        .returning(move |id| {
+            deleted.lock().unwrap().retain(|d| d.id != id);
+            Ok(())
+        });
+    repos
+        .character_repo
+        .expect_save()
+        .withf(|c| c.name == "Hesk")
+        .times(1)
+        .returning(|_| Ok(()));
+    repos
+        .character_repo
+        .expect_add_frequents_region()
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+
+    let llm = Arc::new(FixedLlm {
+        content: r#"{"name": "Hesk", "description": "A stevedore with a bad knee.",
+            "archetype": "Ally", "disposition": "Suspicious"}"#
+            .to_string(),
+    });
+    let app = build_test_app_with_ports(repos, now, Arc::new(NoopQueue), llm);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    // The draft comes back at once, still generating
+    request(
+        &mut dm_ws,
+        "npc-1",
+        NpcDraftRequest::PromoteToNpc {
+            source: NpcDraftSourceData::Crowd {
+                crowd_id: crowd_id.to_string(),
+            },
+            name: None,
+        },
+    )
+    .await;
+    let promoted: NpcDraftData =
+        serde_json::from_value(expect_success(&mut dm_ws, "npc-1").await).unwrap();
+    assert_eq!(promoted.status, NpcDraftStatusData::Generating);
+    assert_eq!(promoted.source_label, "One of the dock workers");
+
+    // ...and the generated details follow
+    let generated = match ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::NpcDraftUpdated { .. })
+    })
+    .await
+    {
+        ServerMessage::NpcDraftUpdated { draft } => draft,
+        other => panic!("unexpected message: {:?}", other),
+    };
+    assert_eq!(generated.id, promoted.id);
+    assert_eq!(generated.name, "Hesk");
+    assert_eq!(generated.status, NpcDraftStatusData::Pending);
+    assert_eq!(generated.disposition, DispositionLevel::Suspicious);
+
+    // Approving creates the character and thins the crowd
+    request(
+        &mut dm_ws,
+        "npc-2",
+        NpcDraftRequest::ApproveNpcDraft {
+            draft_id: promoted.id,
+        },
+    )
+    .await;
+    let created: CreatedEntityData =
+        serde_json::from_value(expect_success(&mut dm_ws, "npc-2").await).unwrap();
+    assert_eq!(created.name, "Hesk");
+    assert_eq!(crowds.lock().unwrap()[0].approximate_count, 39);
+    assert!(drafts.lock().unwrap().is_empty());
+
+    server.abort();
+}
@@ -0,0 +1,119 @@
+use super::*;
+
+#[tokio::test]
+async fn when_dm_shows_overlay_then_players_see_it_until_dm_dismisses() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let repos = TestAppRepos::new(world_repo);
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user"),
+        (
+            &mut spectator_ws,
+            ProtoWorldRole::Spectator,
+            "spectator-user",
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id: None,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // Only the DM can put something on everyone's screen.
+    ws_send_client(
+        &mut spectator_ws,
+        &ClientMessage::ShowOverlay {
+            asset: "/assets/handouts/letter.png".to_string(),
+            caption: None,
+            duration_secs: None,
+        },
+    )
+    .await;
+    let denied = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(denied, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"));
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::ShowOverlay {
+            asset: "/assets/handouts/map.png".to_string(),
+            caption: Some("  The smuggler's map  ".to_string()),
+            duration_secs: Some(0),
+        },
+    )
+    .await;
+    let shown = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ShowOverlay { .. })
+    })
+    .await;
+    let overlay_id = match shown {
+        ServerMessage::ShowOverlay {
+            overlay_id,
+            asset,
+            caption,
+            duration_secs,
+        } => {
+            assert_eq!(asset, "/assets/handouts/map.png");
+            assert_eq!(caption.as_deref(), Some("The smuggler's map"));
+            assert_eq!(duration_secs, None);
+            overlay_id
+        }
+        other => panic!("expected ShowOverlay, got {:?}", other),
+    };
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::DismissOverlay {
+            overlay_id: overlay_id.clone(),
+        },
+    )
+    .await;
+    let dismissed = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::OverlayDismissed { .. })
+    })
+    .await;
+    assert!(
+        matches!(dismissed, ServerMessage::OverlayDismissed { overlay_id: id } if id == overlay_id)
+    );
+
+    server.abort();
+}
@@ -0,0 +1,231 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{ClockKind, ProgressClock};
+use wrldbldr_protocol::{
+    requests::CreateClockData, types::ProgressClockData, ClockRequest, RequestPayload,
+    ResponseResult,
+};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: ClockRequest) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload: RequestPayload::Clock(payload),
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+fn listed_names(result: ResponseResult) -> Vec<String> {
+    match result {
+        ResponseResult::Success {
+            data: Some(data), ..
+        } => serde_json::from_value::<Vec<ProgressClockData>>(data)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect(),
+        other => panic!("expected clock list, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_creates_personal_quest_then_only_its_pc_sees_it() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let location_id = wrldbldr_domain::LocationId::new();
+    let alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", location_id, now);
+    let bram =
+        wrldbldr_domain::PlayerCharacter::new("bram-user", world_id, "Bram", location_id, now);
+    let (alice_id, bram_id) = (alice.id, bram.id);
+
+    let party_goal = ProgressClock::new(world_id, "Return the crown", 6, now)
+        .unwrap()
+        .with_kind(ClockKind::Quest)
+        .visible();
+    let party_goal_id = party_goal.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let pcs = [alice.clone(), bram.clone()];
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok(pcs.iter().find(|pc| pc.id == id).cloned()));
+
+    let clocks = Arc::new(Mutex::new(vec![party_goal]));
+    let stored = clocks.clone();
+    repos
+        .progress_clock_repo
+        .expect_get()
+        .returning(move |id| Ok(stored.lock().unwrap().iter().find(|c| c.id == id).cloned()));
+    let stored = clocks.clone();
+    repos
+        .progress_clock_repo
+        .expect_save()
+        .returning(move |clock| {
+            stored.lock().unwrap().push(clock.clone());
+            Ok(())
+        });
+    let stored = clocks.clone();
+    repos
+        .progress_clock_repo
+        .expect_list_in_world()
+        .returning(move |_| Ok(stored.lock().unwrap().clone()));
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut alice_ws = ws_connect(addr).await;
+    let mut bram_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut alice_ws,
+            ProtoWorldRole::Player,
+            "alice-user",
+            Some(*alice_id.as_uuid()),
+        ),
+        (
+            &mut bram_ws,
+            ProtoWorldRole::Player,
+            "bram-user",
+            Some(*bram_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    let created = request(
+        &mut dm_ws,
+        "create",
+        ClockRequest::CreateClock {
+            world_id: world_id.to_string(),
+            data: CreateClockData {
+                name: "Keep the crown".to_string(),
+                segments: 4,
+                description: Some("Pawn it to clear your debts".to_string()),
+                kind: Some("quest".to_string()),
+                subject: None,
+                visible_to_players: Some(true),
+                owner_pc_id: Some(alice_id.to_string()),
+                conflicts_with_clock_id: Some(party_goal_id.to_string()),
+            },
+        },
+    )
+    .await;
+    let clock = match created {
+        ResponseResult::Success {
+            data: Some(data), ..
+        } => serde_json::from_value::<ProgressClockData>(data).unwrap(),
+        other => panic!("expected created clock, got: {:?}", other),
+    };
+    assert_eq!(clock.owner_pc_id, Some(alice_id.to_string()));
+    assert_eq!(
+        clock.conflicts_with_clock_id,
+        Some(party_goal_id.to_string())
+    );
+
+    let told = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ClockUpdated { .. })
+    })
+    .await;
+    assert!(matches!(
+        told,
+        ServerMessage::ClockUpdated { clock } if clock.name == "Keep the crown"
+    ));
+    ws_expect_no_message_matching(&mut bram_ws, Duration::from_millis(200), |m| {
+        matches!(m, ServerMessage::ClockUpdated { .. })
+    })
+    .await;
+
+    // Bram sees the party goal but not Alice's secret.
+    let listed = request(
+        &mut bram_ws,
+        "bram-list",
+        ClockRequest::ListClocks {
+            world_id: world_id.to_string(),
+            tags: vec![],
+        },
+    )
+    .await;
+    assert_eq!(listed_names(listed), vec!["Return the crown".to_string()]);
+
+    let hidden = request(
+        &mut bram_ws,
+        "bram-get",
+        ClockRequest::GetClock {
+            clock_id: clock.id.clone(),
+        },
+    )
+    .await;
+    assert!(matches!(hidden, ResponseResult::Error { .. }));
+
+    let listed = request(
+        &mut alice_ws,
+        "alice-list",
+        ClockRequest::ListClocks {
+            world_id: world_id.to_string(),
+            tags: vec![],
+        },
+    )
+    .await;
+    assert_eq!(
+        listed_names(listed),
+        vec!["Return the crown".to_string(), "Keep the crown".to_string()]
+    );
+
+    server.abort();
+}
@@ -0,0 +1,190 @@
+use super::*;
+
+use crate::infrastructure::ports::{MockPluginPort, PluginCallKind};
+use wrldbldr_domain::{PluginCapability, PluginManifest, PluginOutput, PluginToolSpec};
+
+fn loot_manifest() -> PluginManifest {
+    PluginManifest {
+        name: "loot".to_string(),
+        version: "1.0.0".to_string(),
+        description: String::new(),
+        capabilities: vec![PluginCapability::SetFlags],
+        tools: ["roll_loot", "steal", "curse"]
+            .into_iter()
+            .map(|name| PluginToolSpec {
+                name: name.to_string(),
+                description: format!("{name} tool"),
+                parameters: serde_json::json!({ "type": "object" }),
+            })
+            .collect(),
+        effects: vec![],
+    }
+}
+
+fn proposed(id: &str, tool: &str) -> wrldbldr_domain::ProposedTool {
+    wrldbldr_domain::ProposedTool {
+        id: id.to_string(),
+        name: format!("loot__{tool}"),
+        description: format!("{tool} tool"),
+        arguments: serde_json::json!({}),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_approves_plugin_tools_then_only_approved_and_permitted_effects_apply() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    // Only the approved, permitted tool's flag is set; the item repo has no
+    // expectations, so applying the denied TakeItem would panic
+    let flags_set = Arc::new(Mutex::new(Vec::new()));
+    let flags_for_set = flags_set.clone();
+    repos
+        .flag_repo
+        .expect_set_world_flag()
+        .returning(move |id, name| {
+            flags_for_set.lock().unwrap().push((id, name.to_string()));
+            Box::pin(async { Ok(()) })
+        });
+
+    let mut plugins = MockPluginPort::new();
+    plugins
+        .expect_manifests()
+        .returning(|| vec![loot_manifest()]);
+    plugins
+        .expect_call()
+        .withf(|plugin, call| {
+            plugin == "loot" && call.kind == PluginCallKind::Tool && call.name != "curse"
+        })
+        .times(2)
+        .returning(|_, call| {
+            let effect = match call.name.as_str() {
+                "roll_loot" => wrldbldr_domain::EventEffect::SetFlag {
+                    flag_name: "found_key".to_string(),
+                    value: true,
+                },
+                _ => wrldbldr_domain::EventEffect::TakeItem {
+                    item_name: "Sword".to_string(),
+                    quantity: 1,
+                },
+            };
+            Ok(PluginOutput {
+                summary: call.name,
+                effects: vec![effect],
+            })
+        });
+
+    let queue = RecordingApprovalQueue::default();
+    let queue_port: Arc<dyn QueuePort> = Arc::new(queue.clone());
+    let app =
+        build_test_app_with_plugins(repos, now, queue_port, Arc::new(NoopLlm), Arc::new(plugins));
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+    let mut dm_ws = ws_connect(addr).await;
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    let approval_id = Uuid::new_v4();
+    queue.insert_approval(
+        approval_id,
+        wrldbldr_domain::ApprovalRequestData {
+            world_id,
+            source_action_id: Uuid::new_v4(),
+            decision_type: wrldbldr_domain::ApprovalDecisionType::NpcResponse,
+            urgency: wrldbldr_domain::ApprovalUrgency::Normal,
+            pc_id: Some(PlayerCharacterId::new()),
+            npc_id: None,
+            npc_name: "NPC".to_string(),
+            proposed_dialogue: "Take this.".to_string(),
+            internal_reasoning: "".to_string(),
+            proposed_tools: vec![
+                proposed("tool-1", "roll_loot"),
+                proposed("tool-2", "steal"),
+                proposed("tool-3", "curse"),
+            ],
+            retry_count: 0,
+            challenge_suggestion: None,
+            narrative_event_suggestion: None,
+            challenge_outcome: None,
+            player_dialogue: None,
+            scene_id: None,
+            location_id: None,
+            game_time: None,
+            topics: vec![],
+            conversation_id: None,
+            safety_warnings: vec![],
+            merged_lines: vec![],
+        },
+    );
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::ApprovalDecision {
+            request_id: approval_id.to_string(),
+            decision: wrldbldr_protocol::ApprovalDecision::AcceptWithModification {
+                modified_dialogue: "Take this.".to_string(),
+                approved_tools: vec!["tool-1".to_string(), "tool-2".to_string()],
+                rejected_tools: vec!["tool-3".to_string()],
+                item_recipients: Default::default(),
+            },
+        },
+    )
+    .await;
+
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ResponseApproved { .. })
+    })
+    .await;
+    let error = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    match error {
+        ServerMessage::Error { code, message } => {
+            assert_eq!(code, "PLUGIN_TOOL_FAILED");
+            assert!(message.contains("loot/steal"), "{message}");
+        }
+        other => panic!("expected Error, got: {:?}", other),
+    }
+
+    assert!(queue.completed_contains(approval_id));
+    assert_eq!(
+        *flags_set.lock().unwrap(),
+        vec![(world_id, "found_key".to_string())]
+    );
+
+    server.abort();
+}
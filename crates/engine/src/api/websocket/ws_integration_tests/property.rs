@@ -0,0 +1,169 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{Location, LocationType, PlayerCharacter, Property};
+use wrldbldr_protocol::types::{PropertyData, PropertyInputData, UpkeepPaymentData};
+use wrldbldr_protocol::{PropertyRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+fn data<T: serde::de::DeserializeOwned>(result: ResponseResult) -> T {
+    match result {
+        ResponseResult::Success { data: Some(data) } => serde_json::from_value(data).unwrap(),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_game_days_pass_then_upkeep_builds_up_until_paid() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Varn", "desc", now);
+    let world_id = world.id;
+    let tavern = Location::new(world_id, "The Gilded Flagon", LocationType::Interior);
+    let tavern_id = tavern.id;
+    let pc = PlayerCharacter::new("player-1", world_id, "Mira", tavern_id, now);
+    let pc_id = pc.id;
+
+    let current_world = Arc::new(Mutex::new(world));
+    let mut world_repo = MockWorldRepo::new();
+    let read_world = current_world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(read_world.lock().unwrap().clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(pc.clone())));
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |_| Ok(Some(tavern.clone())));
+    let stored: Arc<Mutex<Vec<Property>>> = Arc::new(Mutex::new(Vec::new()));
+    let saved = stored.clone();
+    repos.property_repo.expect_save().returning(move |p| {
+        let mut properties = saved.lock().unwrap();
+        properties.retain(|existing| existing.id != p.id);
+        properties.push(p.clone());
+        Ok(())
+    });
+    let listed = stored.clone();
+    repos
+        .property_repo
+        .expect_list_for_owner()
+        .returning(move |_| Ok(listed.lock().unwrap().clone()));
+    let fetched = stored.clone();
+    repos
+        .property_repo
+        .expect_get()
+        .returning(move |id| Ok(fetched.lock().unwrap().iter().find(|p| p.id == id).cloned()));
+    repos
+        .player_character_repo
+        .expect_modify_stat()
+        .withf(move |id, stat, amount| *id == pc_id && stat == "GP" && *amount == -15)
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    let created: PropertyData = data(
+        request(
+            &mut dm_ws,
+            "property-1",
+            RequestPayload::Property(PropertyRequest::CreateProperty {
+                pc_id: pc_id.to_string(),
+                data: PropertyInputData {
+                    name: "The party's tavern".to_string(),
+                    location_id: Some(tavern_id.to_string()),
+                    region_id: None,
+                    upkeep_per_day: 5,
+                    staff: vec![],
+                    notes: String::new(),
+                },
+            }),
+        )
+        .await,
+    );
+    assert_eq!(created.site_name.as_deref(), Some("The Gilded Flagon"));
+    assert_eq!(created.upkeep_due, 0);
+
+    // Three and a half days go by
+    current_world.lock().unwrap().game_time.advance_hours(84);
+    let listed: Vec<PropertyData> = data(
+        request(
+            &mut dm_ws,
+            "property-2",
+            RequestPayload::Property(PropertyRequest::ListPcProperties {
+                pc_id: pc_id.to_string(),
+            }),
+        )
+        .await,
+    );
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].days_owed, 3);
+    assert_eq!(listed[0].upkeep_due, 15);
+
+    let payment: UpkeepPaymentData = data(
+        request(
+            &mut dm_ws,
+            "property-3",
+            RequestPayload::Property(PropertyRequest::PayUpkeep {
+                property_id: created.id.clone(),
+            }),
+        )
+        .await,
+    );
+    assert_eq!(payment.paid, 15);
+    assert_eq!(payment.property.upkeep_due, 0);
+
+    server.abort();
+}
@@ -0,0 +1,210 @@
+use super::*;
+
+use wrldbldr_domain::{LocationId, RegionId};
+use wrldbldr_protocol::types::{HotspotData, HotspotPointData, HotspotTargetData};
+use wrldbldr_protocol::{RegionRequest, RequestPayload, ResponseResult};
+
+fn hotspot(label: &str, points: &[(f32, f32)], target: HotspotTargetData) -> HotspotData {
+    HotspotData {
+        label: label.to_string(),
+        points: points
+            .iter()
+            .map(|&(x, y)| HotspotPointData { x, y })
+            .collect(),
+        target,
+    }
+}
+
+fn set_request(request_id: &str, region_id: RegionId, hotspots: Vec<HotspotData>) -> ClientMessage {
+    ClientMessage::Request {
+        request_id: request_id.to_string(),
+        payload: RequestPayload::Region(RegionRequest::SetRegionHotspots {
+            region_id: region_id.to_string(),
+            hotspots,
+        }),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_sets_region_hotspots_then_world_receives_them() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let location_id = LocationId::new();
+    let mut location =
+        wrldbldr_domain::Location::new(world_id, "Manor", wrldbldr_domain::LocationType::Interior);
+    location.id = location_id;
+
+    let region_id = RegionId::new();
+    let mut region = wrldbldr_domain::Region::new(location_id, "Study");
+    region.id = region_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let region_for_get = region.clone();
+    repos
+        .location_repo
+        .expect_get_region()
+        .returning(move |_| Ok(Some(region_for_get.clone())));
+    let location_for_get = location.clone();
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |_| Ok(Some(location_for_get.clone())));
+    let saved = Arc::new(std::sync::Mutex::new(None));
+    let saved_for_save = saved.clone();
+    repos
+        .location_repo
+        .expect_save_region()
+        .returning(move |r| {
+            *saved_for_save.lock().unwrap() = Some(r.clone());
+            Ok(())
+        });
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user"),
+        (
+            &mut spectator_ws,
+            ProtoWorldRole::Spectator,
+            "spectator-user",
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id: None,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // Players can't edit hotspots.
+    ws_send_client(
+        &mut spectator_ws,
+        &set_request("hotspots-0", region_id, vec![]),
+    )
+    .await;
+    let denied = ws_expect_message(
+        &mut spectator_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "hotspots-0"),
+    )
+    .await;
+    assert!(matches!(
+        denied,
+        ServerMessage::Response {
+            result: ResponseResult::Error { .. },
+            ..
+        }
+    ));
+
+    // Two points isn't a polygon.
+    ws_send_client(
+        &mut dm_ws,
+        &set_request(
+            "hotspots-1",
+            region_id,
+            vec![hotspot(
+                "Bookshelf",
+                &[(10.0, 10.0), (20.0, 10.0)],
+                HotspotTargetData::Item {
+                    item_id: uuid::Uuid::new_v4().to_string(),
+                },
+            )],
+        ),
+    )
+    .await;
+    let rejected = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "hotspots-1"),
+    )
+    .await;
+    assert!(matches!(
+        rejected,
+        ServerMessage::Response {
+            result: ResponseResult::Error { .. },
+            ..
+        }
+    ));
+    assert!(saved.lock().unwrap().is_none());
+
+    let cellar = RegionId::new();
+    ws_send_client(
+        &mut dm_ws,
+        &set_request(
+            "hotspots-2",
+            region_id,
+            vec![hotspot(
+                "Trapdoor",
+                &[(40.0, 80.0), (60.0, 80.0), (60.0, 120.0), (40.0, 95.0)],
+                HotspotTargetData::Exit {
+                    region_id: cellar.to_string(),
+                },
+            )],
+        ),
+    )
+    .await;
+
+    let update = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::RegionHotspotsUpdated { .. })
+    })
+    .await;
+    match update {
+        ServerMessage::RegionHotspotsUpdated {
+            region_id: updated_id,
+            hotspots,
+        } => {
+            assert_eq!(updated_id, region_id.to_string());
+            assert_eq!(hotspots.len(), 1);
+            assert_eq!(hotspots[0].label, "Trapdoor");
+            // Points are clamped to the backdrop.
+            assert_eq!(hotspots[0].points[2].y, 100.0);
+            assert_eq!(
+                hotspots[0].target,
+                HotspotTargetData::Exit {
+                    region_id: cellar.to_string()
+                }
+            );
+        }
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    let saved_region = saved.lock().unwrap().clone().expect("region saved");
+    assert_eq!(saved_region.hotspots.len(), 1);
+    assert!(saved_region.hotspot_at(50.0, 85.0).is_some());
+
+    server.abort();
+}
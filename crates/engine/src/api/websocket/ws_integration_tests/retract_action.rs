@@ -0,0 +1,160 @@
+use super::*;
+
+use wrldbldr_domain::LocationId;
+
+#[tokio::test]
+async fn when_player_retracts_unanswered_action_then_it_never_reaches_the_npc() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let pc = wrldbldr_domain::PlayerCharacter::new(
+        "player-user",
+        world_id,
+        "Ash",
+        LocationId::new(),
+        now,
+    );
+    let pc_id = pc.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(pc.clone())));
+
+    let queue = RecordingApprovalQueue::default();
+    let queue_port: Arc<dyn QueuePort> = Arc::new(queue.clone());
+    let app = build_test_app_with_ports(repos, now, queue_port, Arc::new(NoopLlm));
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut player_ws = ws_connect(addr).await;
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut player_ws,
+            ProtoWorldRole::Player,
+            "player-user",
+            Some(*pc_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    let action = |player_id: &str| wrldbldr_domain::PlayerActionData {
+        world_id,
+        player_id: player_id.to_string(),
+        pc_id: Some(pc_id),
+        action_type: "talk".to_string(),
+        target: None,
+        dialogue: Some("Helo thier".to_string()),
+        timestamp: now,
+        conversation_id: None,
+    };
+    let queued = Uuid::new_v4();
+    let processed = Uuid::new_v4();
+    let someone_elses = Uuid::new_v4();
+    queue.insert_player_action(queued, action("player-user"));
+    queue.insert_player_action(processed, action("player-user"));
+    queue.insert_player_action(someone_elses, action("other-user"));
+    queue.process_player_action(processed);
+
+    // Still in the player action queue: failed outright.
+    ws_send_client(
+        &mut player_ws,
+        &ClientMessage::RetractAction {
+            action_id: queued.to_string(),
+        },
+    )
+    .await;
+    let msg = ws_expect_message(&mut player_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ActionRetracted { .. })
+    })
+    .await;
+    assert!(
+        matches!(msg, ServerMessage::ActionRetracted { action_id } if action_id == queued.to_string())
+    );
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ActionRetracted { .. })
+    })
+    .await;
+    assert!(queue.failed_contains(queued));
+
+    // Already processed: its NPC response request is cancelled instead.
+    ws_send_client(
+        &mut player_ws,
+        &ClientMessage::RetractAction {
+            action_id: processed.to_string(),
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut player_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ActionRetracted { .. })
+    })
+    .await;
+    assert!(!queue.llm_request_pending(processed));
+
+    // Once the LLM has it, it can't be taken back.
+    ws_send_client(
+        &mut player_ws,
+        &ClientMessage::RetractAction {
+            action_id: processed.to_string(),
+        },
+    )
+    .await;
+    let err = ws_expect_message(&mut player_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(err, ServerMessage::Error { code, .. } if code == "ACTION_ALREADY_PROCESSED"));
+
+    // Other players' actions are theirs to retract.
+    ws_send_client(
+        &mut player_ws,
+        &ClientMessage::RetractAction {
+            action_id: someone_elses.to_string(),
+        },
+    )
+    .await;
+    let err = ws_expect_message(&mut player_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(err, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"));
+    assert!(!queue.failed_contains(someone_elses));
+
+    server.abort();
+}
@@ -0,0 +1,136 @@
+use super::*;
+
+use wrldbldr_protocol::types::SafetySignalLevelData;
+
+#[tokio::test]
+async fn when_player_raises_stop_signal_then_dm_is_notified_and_queue_pauses_until_resumed() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let repos = TestAppRepos::new(world_repo);
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    ws_send_client(
+        &mut spectator_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Spectator,
+            user_id: "spectator-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::UserJoined { .. })
+    })
+    .await;
+
+    // Default thresholds: Stop pauses the queue.
+    ws_send_client(
+        &mut spectator_ws,
+        &ClientMessage::SafetySignal {
+            level: SafetySignalLevelData::Stop,
+        },
+    )
+    .await;
+
+    let raised = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::SafetySignalRaised { .. })
+    })
+    .await;
+    match raised {
+        ServerMessage::SafetySignalRaised {
+            level,
+            queue_paused,
+            ..
+        } => {
+            assert_eq!(level, SafetySignalLevelData::Stop);
+            assert!(queue_paused);
+        }
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    let _ = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ActionQueuePaused { paused: true, .. })
+    })
+    .await;
+    assert!(
+        ws_state
+            .app
+            .use_cases
+            .safety
+            .signals
+            .is_paused(world_id)
+            .await
+    );
+
+    // DM resumes play.
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::SetActionQueuePaused {
+            world_id: world_id.to_string(),
+            paused: false,
+        },
+    )
+    .await;
+
+    let _ = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::ActionQueuePaused { paused: false, .. })
+    })
+    .await;
+    assert!(
+        !ws_state
+            .app
+            .use_cases
+            .safety
+            .signals
+            .is_paused(world_id)
+            .await
+    );
+
+    server.abort();
+}
@@ -0,0 +1,202 @@
+use super::*;
+
+use wrldbldr_domain::{ScriptHook, WorldScript};
+use wrldbldr_protocol::types::{ScriptHookData, WorldScriptData};
+use wrldbldr_protocol::{ErrorCode, RequestPayload, ResponseResult, TimeRequest, WorldRequest};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+fn ws_state_for(
+    world: wrldbldr_domain::World,
+) -> (Arc<WsState>, Arc<Mutex<Option<wrldbldr_domain::World>>>) {
+    let now = chrono::Utc::now();
+    let saved = Arc::new(Mutex::new(None::<wrldbldr_domain::World>));
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+    let saved_for_save = saved.clone();
+    world_repo.expect_save().returning(move |w| {
+        *saved_for_save.lock().unwrap() = Some(w.clone());
+        Ok(())
+    });
+
+    let repos = TestAppRepos::new(world_repo);
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    (ws_state, saved)
+}
+
+async fn join(ws: &mut TestWs, world_id: WorldId, role: ProtoWorldRole, user_id: &str) {
+    ws_send_client(
+        ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role,
+            user_id: user_id.to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+}
+
+async fn update_scripts(
+    ws: &mut TestWs,
+    request_id: &str,
+    world_id: WorldId,
+    source: &str,
+) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload: RequestPayload::World(WorldRequest::UpdateScripts {
+                world_id: world_id.to_string(),
+                scripts: vec![WorldScriptData {
+                    name: "dawn bell".to_string(),
+                    hook: ScriptHookData::TimeAdvance,
+                    source: source.to_string(),
+                    enabled: true,
+                    operation_budget: None,
+                }],
+            }),
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_updates_scripts_then_valid_scripts_are_saved() {
+    let world = wrldbldr_domain::World::new("Test World", "desc", chrono::Utc::now());
+    let world_id = world.id;
+    let (ws_state, saved) = ws_state_for(world);
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut player_ws = ws_connect(addr).await;
+    join(&mut dm_ws, world_id, ProtoWorldRole::Dm, "dm-user").await;
+    join(
+        &mut player_ws,
+        world_id,
+        ProtoWorldRole::Spectator,
+        "player-user",
+    )
+    .await;
+
+    // Syntax errors are rejected before anything is saved
+    match update_scripts(&mut dm_ws, "scripts-1", world_id, "let x = ;").await {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::ValidationError),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert!(saved.lock().unwrap().is_none());
+
+    // Non-DMs can't change scripts
+    match update_scripts(&mut player_ws, "scripts-2", world_id, "1").await {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::Unauthorized),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    match update_scripts(&mut dm_ws, "scripts-3", world_id, r#"notify_dm("ding")"#).await {
+        ResponseResult::Success { data: Some(data) } => {
+            let scripts: Vec<WorldScriptData> = serde_json::from_value(data).unwrap();
+            assert_eq!(scripts.len(), 1);
+            assert_eq!(scripts[0].hook, ScriptHookData::TimeAdvance);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let saved = saved.lock().unwrap().clone().expect("world saved");
+    assert_eq!(saved.scripts[0].name, "dawn bell");
+    assert_eq!(saved.scripts[0].hook, ScriptHook::TimeAdvance);
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn when_time_advances_then_scripts_run_and_notify_dms() {
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", chrono::Utc::now());
+    world.scripts = vec![
+        WorldScript::new(
+            "dawn bell",
+            ScriptHook::TimeAdvance,
+            r#"notify_dm(`advanced ${event.data.minutesAdvanced} minutes`);"#,
+        ),
+        WorldScript::new("runaway", ScriptHook::TimeAdvance, "loop {}").with_operation_budget(100),
+        WorldScript::new(
+            "pickup only",
+            ScriptHook::ItemPickup,
+            r#"notify_dm("wrong hook")"#,
+        ),
+    ];
+    let world_id = world.id;
+    let (ws_state, _saved) = ws_state_for(world);
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    join(&mut dm_ws, world_id, ProtoWorldRole::Dm, "dm-user").await;
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "advance-1".to_string(),
+            payload: RequestPayload::Time(TimeRequest::AdvanceGameTime {
+                world_id: world_id.to_string(),
+                hours: 2,
+            }),
+        },
+    )
+    .await;
+
+    let mut notices = Vec::new();
+    while notices.len() < 2 {
+        match ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::ScriptNotice { .. })
+        })
+        .await
+        {
+            ServerMessage::ScriptNotice {
+                script,
+                message,
+                is_error,
+                ..
+            } => notices.push((script, message, is_error)),
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    assert_eq!(
+        notices[0],
+        (
+            "dawn bell".to_string(),
+            "advanced 120 minutes".to_string(),
+            false
+        )
+    );
+    assert_eq!(notices[1].0, "runaway");
+    assert!(notices[1].2, "budget overrun is reported as an error");
+
+    server.abort();
+}
@@ -0,0 +1,264 @@
+use super::*;
+
+use wrldbldr_domain::{CampbellArchetype, PcSecret, StoryEventType};
+use wrldbldr_protocol::{
+    types::{PcSecretData, PcSecretInputData, SecretReactionData},
+    ErrorCode, RequestPayload, ResponseResult, SecretRequest,
+};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: SecretRequest) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload: RequestPayload::Secret(payload),
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_reveals_secret_then_world_learns_it_and_npcs_react() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let location_id = wrldbldr_domain::LocationId::new();
+    let alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", location_id, now);
+    let bram =
+        wrldbldr_domain::PlayerCharacter::new("bram-user", world_id, "Bram", location_id, now);
+    let (alice_id, bram_id) = (alice.id, bram.id);
+
+    let rebel = wrldbldr_domain::Character::new(world_id, "Sera", CampbellArchetype::Ally);
+    let rebel_id = rebel.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let pcs = [alice.clone(), bram.clone()];
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok(pcs.iter().find(|pc| pc.id == id).cloned()));
+    repos
+        .character_repo
+        .expect_get()
+        .returning(move |id| Ok((id == rebel_id).then(|| rebel.clone())));
+    repos
+        .character_repo
+        .expect_get_disposition()
+        .returning(|_, _| Ok(None));
+    let dispositions = Arc::new(Mutex::new(Vec::new()));
+    let saved = dispositions.clone();
+    repos
+        .character_repo
+        .expect_save_disposition()
+        .returning(move |d| {
+            saved.lock().unwrap().push(d.clone());
+            Ok(())
+        });
+
+    let secrets: Arc<Mutex<Vec<PcSecret>>> = Arc::new(Mutex::new(Vec::new()));
+    let stored = secrets.clone();
+    repos
+        .pc_secret_repo
+        .expect_get()
+        .returning(move |id| Ok(stored.lock().unwrap().iter().find(|s| s.id == id).cloned()));
+    let stored = secrets.clone();
+    repos.pc_secret_repo.expect_save().returning(move |secret| {
+        let mut stored = stored.lock().unwrap();
+        stored.retain(|s| s.id != secret.id);
+        stored.push(secret.clone());
+        Ok(())
+    });
+    let stored = secrets.clone();
+    repos
+        .pc_secret_repo
+        .expect_list_for_pc()
+        .returning(move |pc_id| {
+            Ok(stored
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| s.pc_id == pc_id)
+                .cloned()
+                .collect())
+        });
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = events.clone();
+    repos
+        .narrative_repo
+        .expect_save_story_event()
+        .returning(move |event| {
+            recorded.lock().unwrap().push(event.clone());
+            Ok(())
+        });
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut alice_ws = ws_connect(addr).await;
+    let mut bram_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut alice_ws,
+            ProtoWorldRole::Player,
+            "alice-user",
+            Some(*alice_id.as_uuid()),
+        ),
+        (
+            &mut bram_ws,
+            ProtoWorldRole::Player,
+            "bram-user",
+            Some(*bram_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    let created = request(
+        &mut dm_ws,
+        "create",
+        SecretRequest::CreateSecret {
+            pc_id: alice_id.to_string(),
+            data: PcSecretInputData {
+                title: Some("Sold out the rebels".to_string()),
+                content: Some("You gave the magistrate their names.".to_string()),
+                reactions: Some(vec![SecretReactionData {
+                    npc_id: rebel_id.to_string(),
+                    npc_name: None,
+                    sentiment_change: -0.6,
+                }]),
+            },
+        },
+    )
+    .await;
+    let secret = match created {
+        ResponseResult::Success {
+            data: Some(data), ..
+        } => serde_json::from_value::<PcSecretData>(data).unwrap(),
+        other => panic!("expected created secret, got: {:?}", other),
+    };
+    assert_eq!(secret.reactions[0].npc_name.as_deref(), Some("Sera"));
+
+    // Only Alice hears about her secret
+    let _ = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::PcSecretUpdated { .. })
+    })
+    .await;
+    ws_expect_no_message_matching(&mut bram_ws, Duration::from_millis(200), |m| {
+        matches!(m, ServerMessage::PcSecretUpdated { .. })
+    })
+    .await;
+
+    let snooped = request(
+        &mut bram_ws,
+        "bram-list",
+        SecretRequest::ListSecrets {
+            pc_id: alice_id.to_string(),
+        },
+    )
+    .await;
+    assert!(matches!(
+        snooped,
+        ResponseResult::Error {
+            code: ErrorCode::Forbidden,
+            ..
+        }
+    ));
+
+    let own = request(
+        &mut alice_ws,
+        "alice-list",
+        SecretRequest::ListSecrets {
+            pc_id: alice_id.to_string(),
+        },
+    )
+    .await;
+    assert!(matches!(own, ResponseResult::Success { .. }));
+
+    let revealed = request(
+        &mut dm_ws,
+        "reveal",
+        SecretRequest::RevealSecret {
+            secret_id: secret.id.clone(),
+        },
+    )
+    .await;
+    assert!(matches!(revealed, ResponseResult::Success { .. }));
+
+    let told = ws_expect_message(&mut bram_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::PcSecretRevealed { .. })
+    })
+    .await;
+    assert!(matches!(
+        told,
+        ServerMessage::PcSecretRevealed { pc_name, secret, .. }
+            if pc_name == "Alice" && secret.revealed_at.is_some()
+    ));
+
+    let dispositions = dispositions.lock().unwrap();
+    assert_eq!(dispositions.len(), 1);
+    assert_eq!(dispositions[0].npc_id, rebel_id);
+    assert!(dispositions[0].sentiment < 0.0);
+
+    let events = events.lock().unwrap();
+    assert!(matches!(
+        events[0].event_type,
+        StoryEventType::InformationRevealed { .. }
+    ));
+    assert!(matches!(
+        events[1].event_type,
+        StoryEventType::RelationshipChanged { .. }
+    ));
+
+    server.abort();
+}
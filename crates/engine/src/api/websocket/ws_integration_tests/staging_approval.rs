@@ -232,6 +232,16 @@ async fn when_player_enters_unstaged_region_then_dm_can_approve_and_player_recei
         .character_repo
         .expect_get_npcs_for_region()
         .returning(|_| Ok(vec![]));
+    repos
+        .character_repo
+        .expect_list_in_region()
+        .returning(|_| Ok(vec![]));
+
+    // No companions travel with the PC.
+    repos
+        .companion_repo
+        .expect_list_for_pc()
+        .returning(|_| Ok(vec![]));
 
     let app = build_test_app(repos, now);
     let connections = Arc::new(ConnectionManager::new());
@@ -255,6 +265,7 @@ async fn when_player_enters_unstaged_region_then_dm_can_approve_and_player_recei
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -271,6 +282,7 @@ async fn when_player_enters_unstaged_region_then_dm_can_approve_and_player_recei
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Player,
+            user_id: "player-user".to_string(),
             pc_id: Some(*pc_id.as_uuid()),
             spectate_pc_id: None,
         },
@@ -466,6 +478,10 @@ async fn auto_approve_staging_timeout_uses_world_settings_for_ttl() {
         .character_repo
         .expect_get_npcs_for_region()
         .returning(|_| Ok(vec![]));
+    repos
+        .character_repo
+        .expect_list_in_region()
+        .returning(|_| Ok(vec![]));
 
     // Location state and region state repos
     repos
@@ -484,6 +500,7 @@ async fn auto_approve_staging_timeout_uses_world_settings_for_ttl() {
         region_id,
         location_id,
         world_id,
+        pc_id: PlayerCharacterId::new(),
         created_at: now,
     };
 
@@ -597,6 +614,10 @@ async fn auto_approve_staging_timeout_falls_back_to_defaults_on_settings_error()
         .character_repo
         .expect_get_npcs_for_region()
         .returning(|_| Ok(vec![]));
+    repos
+        .character_repo
+        .expect_list_in_region()
+        .returning(|_| Ok(vec![]));
 
     // Location state and region state repos
     repos
@@ -615,6 +636,7 @@ async fn auto_approve_staging_timeout_falls_back_to_defaults_on_settings_error()
         region_id,
         location_id,
         world_id,
+        pc_id: PlayerCharacterId::new(),
         created_at: now,
     };
 
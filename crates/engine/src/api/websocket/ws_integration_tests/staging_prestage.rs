@@ -209,6 +209,12 @@ async fn when_dm_prestages_region_then_player_entering_gets_scene_changed_withou
         .expect_get_npcs_for_region()
         .returning(|_| Ok(vec![]));
 
+    // No companions travel with the PC.
+    repos
+        .companion_repo
+        .expect_list_for_pc()
+        .returning(|_| Ok(vec![]));
+
     let app = build_test_app(repos, now);
     let connections = Arc::new(ConnectionManager::new());
 
@@ -230,6 +236,7 @@ async fn when_dm_prestages_region_then_player_entering_gets_scene_changed_withou
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -246,6 +253,7 @@ async fn when_dm_prestages_region_then_player_entering_gets_scene_changed_withou
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Player,
+            user_id: "player-user".to_string(),
             pc_id: Some(*pc_id.as_uuid()),
             spectate_pc_id: None,
         },
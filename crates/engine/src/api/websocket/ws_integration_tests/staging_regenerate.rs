@@ -65,6 +65,12 @@ async fn when_dm_requests_staging_regenerate_then_returns_llm_suggestions_and_do
             }])
         });
 
+    // World settings choose the classification model.
+    repos
+        .settings_repo
+        .expect_get_for_world()
+        .returning(|_| Ok(None));
+
     // Regenerate should not touch staging persistence.
     repos.staging_repo.expect_save_pending_staging().times(0);
     repos.staging_repo.expect_activate_staging().times(0);
@@ -94,6 +100,7 @@ async fn when_dm_requests_staging_regenerate_then_returns_llm_suggestions_and_do
                 region_id,
                 location_id,
                 world_id,
+                pc_id: PlayerCharacterId::new(),
                 created_at: now,
             },
         );
@@ -108,6 +115,7 @@ async fn when_dm_requests_staging_regenerate_then_returns_llm_suggestions_and_do
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -0,0 +1,147 @@
+use super::*;
+
+use std::collections::HashMap as StdHashMap;
+
+use wrldbldr_domain::{CampbellArchetype, EntityType};
+use wrldbldr_protocol::{CharacterRequest, ErrorCode, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn join(ws: &mut TestWs, world_id: WorldId, role: ProtoWorldRole, user_id: &str) {
+    ws_send_client(
+        ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role,
+            user_id: user_id.to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+}
+
+async fn list_characters(
+    ws: &mut TestWs,
+    request_id: &str,
+    world_id: WorldId,
+    tags: &[&str],
+) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload: RequestPayload::Character(CharacterRequest::ListCharacters {
+                world_id: world_id.to_string(),
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+            }),
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_lists_characters_by_tag_then_only_tagged_characters_return() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now);
+    let world_id = world.id;
+
+    let villain = wrldbldr_domain::Character::new(world_id, "Vex", CampbellArchetype::Shadow);
+    let ally = wrldbldr_domain::Character::new(world_id, "Wren", CampbellArchetype::Mentor);
+    let villain_id = villain.id;
+    let ally_id = ally.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let characters = vec![villain, ally];
+    // Replace the default empty world surface
+    repos.character_repo.checkpoint();
+    repos
+        .character_repo
+        .expect_list_in_world()
+        .returning(move |_| Ok(characters.clone()));
+    repos
+        .tag_repo
+        .expect_get_tags()
+        .withf(|entity_type, ids| *entity_type == EntityType::Character && ids.len() == 2)
+        .returning(move |_, _| {
+            Ok(StdHashMap::from([
+                (
+                    *villain_id.as_uuid(),
+                    vec!["act-2".to_string(), "villain-adjacent".to_string()],
+                ),
+                (*ally_id.as_uuid(), vec!["act-2".to_string()]),
+            ]))
+        });
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut player_ws = ws_connect(addr).await;
+    join(&mut dm_ws, world_id, ProtoWorldRole::Dm, "dm-user").await;
+    join(
+        &mut player_ws,
+        world_id,
+        ProtoWorldRole::Spectator,
+        "player-user",
+    )
+    .await;
+
+    // Tags are normalized, so "Villain Adjacent" matches "villain-adjacent"
+    match list_characters(&mut dm_ws, "list-1", world_id, &["Villain Adjacent"]).await {
+        ResponseResult::Success { data: Some(data) } => {
+            let ids: Vec<String> = data
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|c| c["id"].as_str().unwrap().to_string())
+                .collect();
+            assert_eq!(ids, vec![villain_id.to_string()]);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // An untagged request still lists everyone, for players too
+    match list_characters(&mut player_ws, "list-2", world_id, &[]).await {
+        ResponseResult::Success { data: Some(data) } => {
+            assert_eq!(data.as_array().unwrap().len(), 2);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // Filtering by tag would leak the DM's tags, so players can't
+    match list_characters(&mut player_ws, "list-3", world_id, &["act-2"]).await {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::Unauthorized),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    server.abort();
+}
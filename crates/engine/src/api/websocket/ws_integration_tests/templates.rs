@@ -0,0 +1,201 @@
+use super::*;
+
+use std::collections::BTreeMap;
+
+use wrldbldr_domain::{
+    CampbellArchetype, EntityTemplate, EntityType, ItemTemplate, NpcTemplate,
+    RegionRelationshipType, RegionShift, RegionTemplate, TemplateBody, TemplateNpc,
+};
+use wrldbldr_protocol::types::CreatedEntityData;
+use wrldbldr_protocol::{ErrorCode, RequestPayload, ResponseResult, TemplateRequest};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn join(ws: &mut TestWs, world_id: WorldId, role: ProtoWorldRole, user_id: &str) {
+    ws_send_client(
+        ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role,
+            user_id: user_id.to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+}
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_instantiates_a_region_template_then_its_items_and_npcs_are_created() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now);
+    let world_id = world.id;
+    let location =
+        wrldbldr_domain::Location::new(world_id, "Harbor", wrldbldr_domain::LocationType::Exterior);
+    let location_id = location.id;
+    let template = EntityTemplate::new(
+        world_id,
+        "Guard post",
+        None,
+        TemplateBody::Region(RegionTemplate {
+            name: "{name}".to_string(),
+            description: "The watch holds {name} for the {faction}.".to_string(),
+            atmosphere: None,
+            backdrop_asset: None,
+            is_spawn_point: false,
+            items: vec![ItemTemplate {
+                name: "{name} logbook".to_string(),
+                description: None,
+                item_type: None,
+            }],
+            npcs: vec![TemplateNpc {
+                npc: NpcTemplate {
+                    name: "Sergeant of {name}".to_string(),
+                    description: String::new(),
+                    archetype: CampbellArchetype::ThresholdGuardian,
+                    sprite_asset: None,
+                    portrait_asset: None,
+                },
+                role: RegionRelationshipType::WorksAt {
+                    shift: RegionShift::Night,
+                },
+            }],
+        }),
+        now,
+    )
+    .expect("valid template");
+    let template_id = template.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .template_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(template.clone())));
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |_| Ok(Some(location.clone())));
+    repos
+        .location_repo
+        .expect_save_region()
+        .withf(move |r| {
+            r.location_id == location_id
+                && r.name == "North Gate"
+                && r.description == "The watch holds North Gate for the Bluecoats."
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+    repos
+        .item_repo
+        .expect_save()
+        .withf(|i| i.name == "North Gate logbook")
+        .times(1)
+        .returning(|_| Ok(()));
+    repos
+        .item_repo
+        .expect_place_in_region()
+        .times(1)
+        .returning(|_, _| Ok(()));
+    repos
+        .character_repo
+        .expect_save()
+        .withf(|c| c.name == "Sergeant of North Gate")
+        .times(1)
+        .returning(|_| Ok(()));
+    repos
+        .character_repo
+        .expect_set_work_region()
+        .withf(|_, _, shift| shift.as_deref() == Some("night"))
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut player_ws = ws_connect(addr).await;
+    join(&mut dm_ws, world_id, ProtoWorldRole::Dm, "dm-user").await;
+    join(
+        &mut player_ws,
+        world_id,
+        ProtoWorldRole::Spectator,
+        "player-user",
+    )
+    .await;
+
+    let instantiate = |variables: BTreeMap<String, String>| {
+        RequestPayload::Template(TemplateRequest::InstantiateTemplate {
+            template_id: template_id.to_string(),
+            name: "North Gate".to_string(),
+            variables,
+            location_id: Some(location_id.to_string()),
+        })
+    };
+    let variables = BTreeMap::from([("faction".to_string(), "Bluecoats".to_string())]);
+
+    // Templates are DM-only
+    match request(&mut player_ws, "inst-1", instantiate(variables.clone())).await {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::Unauthorized),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // Every placeholder needs a value
+    match request(&mut dm_ws, "inst-2", instantiate(BTreeMap::new())).await {
+        ResponseResult::Error { code, .. } => assert_eq!(code, ErrorCode::ValidationError),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    match request(&mut dm_ws, "inst-3", instantiate(variables)).await {
+        ResponseResult::Success { data: Some(data) } => {
+            let created: Vec<CreatedEntityData> = serde_json::from_value(data).unwrap();
+            let kinds: Vec<EntityType> = created.iter().map(|c| c.entity_type).collect();
+            assert_eq!(
+                kinds,
+                vec![EntityType::Region, EntityType::Item, EntityType::Character]
+            );
+            assert_eq!(created[0].name, "North Gate");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    server.abort();
+}
@@ -40,6 +40,7 @@ async fn when_dm_approves_time_suggestion_then_time_advances_and_broadcasts() {
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -57,6 +58,7 @@ async fn when_dm_approves_time_suggestion_then_time_advances_and_broadcasts() {
         &ClientMessage::JoinWorld {
             world_id: *world_id.as_uuid(),
             role: ProtoWorldRole::Spectator,
+            user_id: "spectator-user".to_string(),
             pc_id: None,
             spectate_pc_id: None,
         },
@@ -0,0 +1,350 @@
+use super::*;
+
+use wrldbldr_domain::{LocationId, RegionId, StoryEventType};
+use wrldbldr_protocol::types::TradeClosedReason;
+
+#[tokio::test]
+async fn when_player_accepts_trade_then_items_move_and_dm_is_told() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let location_id = LocationId::new();
+    let region_id = RegionId::new();
+
+    let mut alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", location_id, now);
+    alice.current_region_id = Some(region_id);
+    let mut bram =
+        wrldbldr_domain::PlayerCharacter::new("bram-user", world_id, "Bram", location_id, now);
+    bram.current_region_id = Some(region_id);
+    let (alice_id, bram_id) = (alice.id, bram.id);
+
+    let lantern = wrldbldr_domain::Item::new(world_id, "Lantern");
+    let lantern_id = lantern.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let pcs = [alice.clone(), bram.clone()];
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok(pcs.iter().find(|pc| pc.id == id).cloned()));
+    repos
+        .player_character_repo
+        .expect_get_inventory()
+        .returning(move |id| {
+            Ok(if id == alice_id {
+                vec![lantern.clone()]
+            } else {
+                vec![]
+            })
+        });
+    repos
+        .player_character_repo
+        .expect_execute_trade()
+        .withf(move |offer, currency| {
+            offer.from_pc == alice_id
+                && offer.to_pc == bram_id
+                && offer.offered.item_ids == vec![lantern_id]
+                && currency.is_none()
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+    repos
+        .narrative_repo
+        .expect_save_story_event()
+        .withf(move |event| {
+            matches!(
+                &event.event_type,
+                StoryEventType::ItemTransferred { item_name, to_character, .. }
+                    if item_name == "Lantern" && *to_character.as_uuid() == *bram_id.as_uuid()
+            )
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut alice_ws = ws_connect(addr).await;
+    let mut bram_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id, pc_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user", None),
+        (
+            &mut alice_ws,
+            ProtoWorldRole::Player,
+            "alice-user",
+            Some(*alice_id.as_uuid()),
+        ),
+        (
+            &mut bram_ws,
+            ProtoWorldRole::Player,
+            "bram-user",
+            Some(*bram_id.as_uuid()),
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // Bram can't make offers on Alice's behalf.
+    ws_send_client(
+        &mut bram_ws,
+        &ClientMessage::OfferTrade {
+            from_pc_id: alice_id.to_string(),
+            to_pc_id: bram_id.to_string(),
+            offered_item_ids: vec![lantern_id.to_string()],
+            offered_currency: 0,
+            requested_item_ids: vec![],
+            requested_currency: 0,
+        },
+    )
+    .await;
+    let denied = ws_expect_message(&mut bram_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(denied, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"));
+
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::OfferTrade {
+            from_pc_id: alice_id.to_string(),
+            to_pc_id: bram_id.to_string(),
+            offered_item_ids: vec![lantern_id.to_string()],
+            offered_currency: 0,
+            requested_item_ids: vec![],
+            requested_currency: 0,
+        },
+    )
+    .await;
+
+    let offered = ws_expect_message(&mut bram_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::TradeOffered { .. })
+    })
+    .await;
+    let trade = match offered {
+        ServerMessage::TradeOffered { trade } => trade,
+        other => panic!("expected TradeOffered, got {:?}", other),
+    };
+    assert_eq!(trade.from_pc_name, "Alice");
+    assert_eq!(trade.offered.items.len(), 1);
+    assert_eq!(trade.offered.items[0].name, "Lantern");
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::TradeOffered { .. })
+    })
+    .await;
+
+    // Only the recipient answers an offer.
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::RespondToTrade {
+            trade_id: trade.trade_id.clone(),
+            accept: true,
+        },
+    )
+    .await;
+    let denied = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(denied, ServerMessage::Error { code, .. } if code == "UNAUTHORIZED"));
+
+    ws_send_client(
+        &mut bram_ws,
+        &ClientMessage::RespondToTrade {
+            trade_id: trade.trade_id.clone(),
+            accept: true,
+        },
+    )
+    .await;
+
+    for ws in [&mut alice_ws, &mut bram_ws, &mut dm_ws] {
+        let completed = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::TradeCompleted { .. })
+        })
+        .await;
+        assert!(
+            matches!(completed, ServerMessage::TradeCompleted { trade: t } if t.trade_id == trade.trade_id)
+        );
+    }
+    let _ = ws_expect_message(
+        &mut bram_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::InventoryUpdated { pc_id } if *pc_id == bram_id.to_string()),
+    )
+    .await;
+
+    // The offer is gone once resolved.
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::CancelTrade {
+            trade_id: trade.trade_id.clone(),
+        },
+    )
+    .await;
+    let gone = ws_expect_message(&mut alice_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::Error { .. })
+    })
+    .await;
+    assert!(matches!(gone, ServerMessage::Error { code, .. } if code == "NOT_FOUND"));
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn when_player_declines_trade_then_both_sides_hear_it() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let location_id = LocationId::new();
+    let alice =
+        wrldbldr_domain::PlayerCharacter::new("alice-user", world_id, "Alice", location_id, now);
+    let bram =
+        wrldbldr_domain::PlayerCharacter::new("bram-user", world_id, "Bram", location_id, now);
+    let (alice_id, bram_id) = (alice.id, bram.id);
+    let rope = wrldbldr_domain::Item::new(world_id, "Rope");
+    let rope_id = rope.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    let pcs = [alice, bram];
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |id| Ok(pcs.iter().find(|pc| pc.id == id).cloned()));
+    repos
+        .player_character_repo
+        .expect_get_inventory()
+        .returning(move |id| {
+            Ok(if id == bram_id {
+                vec![rope.clone()]
+            } else {
+                vec![]
+            })
+        });
+    repos.player_character_repo.expect_execute_trade().never();
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut alice_ws = ws_connect(addr).await;
+    let mut bram_ws = ws_connect(addr).await;
+
+    for (ws, user_id, pc_id) in [
+        (&mut alice_ws, "alice-user", alice_id),
+        (&mut bram_ws, "bram-user", bram_id),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role: ProtoWorldRole::Player,
+                user_id: user_id.to_string(),
+                pc_id: Some(*pc_id.as_uuid()),
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    // Alice asks for Bram's rope as a gift.
+    ws_send_client(
+        &mut alice_ws,
+        &ClientMessage::OfferTrade {
+            from_pc_id: alice_id.to_string(),
+            to_pc_id: bram_id.to_string(),
+            offered_item_ids: vec![],
+            offered_currency: 0,
+            requested_item_ids: vec![rope_id.to_string()],
+            requested_currency: 0,
+        },
+    )
+    .await;
+    let offered = ws_expect_message(&mut bram_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::TradeOffered { .. })
+    })
+    .await;
+    let trade_id = match offered {
+        ServerMessage::TradeOffered { trade } => trade.trade_id,
+        other => panic!("expected TradeOffered, got {:?}", other),
+    };
+
+    ws_send_client(
+        &mut bram_ws,
+        &ClientMessage::RespondToTrade {
+            trade_id: trade_id.clone(),
+            accept: false,
+        },
+    )
+    .await;
+
+    for ws in [&mut alice_ws, &mut bram_ws] {
+        let closed = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::TradeClosed { .. })
+        })
+        .await;
+        assert!(matches!(
+            closed,
+            ServerMessage::TradeClosed { trade_id: id, reason: TradeClosedReason::Declined, .. }
+                if id == trade_id
+        ));
+    }
+
+    server.abort();
+}
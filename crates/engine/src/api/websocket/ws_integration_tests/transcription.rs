@@ -0,0 +1,134 @@
+use super::*;
+
+use base64::Engine as _;
+use wrldbldr_protocol::types::TranscriptionData;
+use wrldbldr_protocol::{ErrorCode, RequestPayload, ResponseResult, TranscriptionRequest};
+
+fn transcribe_request(request_id: &str, audio: &str) -> ClientMessage {
+    ClientMessage::Request {
+        request_id: request_id.to_string(),
+        payload: RequestPayload::Transcription(TranscriptionRequest::TranscribeAudio {
+            audio: audio.to_string(),
+            content_type: "audio/webm;codecs=opus".to_string(),
+        }),
+    }
+}
+
+#[tokio::test]
+async fn when_player_speaks_then_transcript_is_returned_to_them_only() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .transcription
+        .expect_transcribe()
+        .withf(|request| request.audio == b"clip" && request.content_type == "audio/webm")
+        .returning(|_| Ok(" [BLANK_AUDIO] I open the door. ".to_string()));
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user"),
+        (
+            &mut spectator_ws,
+            ProtoWorldRole::Spectator,
+            "spectator-user",
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id: None,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    let clip = base64::engine::general_purpose::STANDARD.encode(b"clip");
+    ws_send_client(&mut dm_ws, &transcribe_request("voice-1", &clip)).await;
+    let response = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "voice-1"),
+    )
+    .await;
+    let transcript: TranscriptionData = match response {
+        ServerMessage::Response {
+            result: ResponseResult::Success { data: Some(data) },
+            ..
+        } => serde_json::from_value(data).expect("transcription data"),
+        other => panic!("unexpected response: {:?}", other),
+    };
+    assert_eq!(transcript.text, "I open the door.");
+
+    // Not base64
+    ws_send_client(&mut dm_ws, &transcribe_request("voice-2", "not audio!")).await;
+    let response = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "voice-2"),
+    )
+    .await;
+    assert!(matches!(
+        response,
+        ServerMessage::Response {
+            result: ResponseResult::Error {
+                code: ErrorCode::ValidationError,
+                ..
+            },
+            ..
+        }
+    ));
+
+    // Spectators have no one to speak for
+    ws_send_client(&mut spectator_ws, &transcribe_request("voice-3", &clip)).await;
+    let response = ws_expect_message(
+        &mut spectator_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "voice-3"),
+    )
+    .await;
+    assert!(matches!(
+        response,
+        ServerMessage::Response {
+            result: ResponseResult::Error {
+                code: ErrorCode::Forbidden,
+                ..
+            },
+            ..
+        }
+    ));
+
+    server.abort();
+}
@@ -0,0 +1,138 @@
+use super::*;
+
+use wrldbldr_domain::{
+    CharacterId, LocationId, RegionId, TutorialGoal, TutorialScript, TutorialStep,
+};
+use wrldbldr_protocol::{RequestPayload, ResponseResult, TutorialRequest, TutorialStatusData};
+
+fn status_request(request_id: &str, world_id: WorldId, pc_id: PlayerCharacterId) -> ClientMessage {
+    ClientMessage::Request {
+        request_id: request_id.to_string(),
+        payload: RequestPayload::Tutorial(TutorialRequest::GetTutorialStatus {
+            world_id: world_id.to_string(),
+            pc_id: pc_id.to_string(),
+        }),
+    }
+}
+
+#[tokio::test]
+async fn when_player_asks_for_tutorial_status_then_completed_steps_are_skipped() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let script = TutorialScript::new(vec![
+        TutorialStep::new(
+            "walk",
+            "Find your way",
+            "Walk to the docks",
+            TutorialGoal::EnterRegion {
+                region_id: RegionId::new(),
+            },
+        ),
+        TutorialStep::new(
+            "talk",
+            "Say hello",
+            "Talk to the harbormaster",
+            TutorialGoal::TalkToNpc {
+                npc_id: CharacterId::new(),
+            },
+        ),
+    ]);
+    let mut world = wrldbldr_domain::World::new("Tutorial", "desc", now).with_tutorial(script);
+    world.id = world_id;
+
+    let location_id = LocationId::new();
+    let pc = wrldbldr_domain::PlayerCharacter::new("player-1", world_id, "PC", location_id, now);
+    let pc_id = pc.id;
+    let other_pc_id = PlayerCharacterId::new();
+
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos
+        .player_character_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(pc.clone())));
+    repos
+        .flag_repo
+        .expect_get_pc_flags()
+        .returning(|_| Box::pin(async { Ok(vec!["tutorial:walk".to_string()]) }));
+
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+    let mut player_ws = ws_connect(addr).await;
+
+    ws_send_client(
+        &mut player_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Player,
+            user_id: "player-1".to_string(),
+            pc_id: Some(*pc_id.as_uuid()),
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut player_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    ws_send_client(&mut player_ws, &status_request("status-1", world_id, pc_id)).await;
+    let response = ws_expect_message(
+        &mut player_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "status-1"),
+    )
+    .await;
+    let status: TutorialStatusData = match response {
+        ServerMessage::Response {
+            result: ResponseResult::Success { data: Some(data) },
+            ..
+        } => serde_json::from_value(data).expect("tutorial status"),
+        other => panic!("unexpected response: {:?}", other),
+    };
+    assert_eq!(status.completed_steps, 1);
+    assert_eq!(status.total_steps, 2);
+    assert_eq!(
+        status.current_step.map(|step| step.id).as_deref(),
+        Some("talk")
+    );
+    assert!(status.just_completed.is_none());
+
+    // Players only see their own progress.
+    ws_send_client(
+        &mut player_ws,
+        &status_request("status-2", world_id, other_pc_id),
+    )
+    .await;
+    let denied = ws_expect_message(
+        &mut player_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "status-2"),
+    )
+    .await;
+    assert!(matches!(
+        denied,
+        ServerMessage::Response {
+            result: ResponseResult::Error { .. },
+            ..
+        }
+    ));
+
+    server.abort();
+}
@@ -0,0 +1,119 @@
+use super::*;
+
+use wrldbldr_protocol::types::{TextDirectionData, WorldTypographyData};
+use wrldbldr_protocol::{RequestPayload, ResponseResult, WorldRequest};
+
+#[tokio::test]
+async fn when_dm_updates_typography_then_players_receive_normalized_settings() {
+    let now = chrono::Utc::now();
+
+    let world_id = WorldId::new();
+    let mut world = wrldbldr_domain::World::new("Test World", "desc", now);
+    world.id = world_id;
+
+    let saved = Arc::new(Mutex::new(None::<wrldbldr_domain::World>));
+    let mut world_repo = MockWorldRepo::new();
+    let world_for_get = world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world_for_get.clone())));
+    let saved_for_save = saved.clone();
+    world_repo.expect_save().returning(move |w| {
+        *saved_for_save.lock().unwrap() = Some(w.clone());
+        Ok(())
+    });
+
+    let repos = TestAppRepos::new(world_repo);
+    let app = build_test_app(repos, now);
+    let connections = Arc::new(ConnectionManager::new());
+
+    let ws_state = Arc::new(WsState {
+        app,
+        connections,
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+
+    for (ws, role, user_id) in [
+        (&mut dm_ws, ProtoWorldRole::Dm, "dm-user"),
+        (
+            &mut spectator_ws,
+            ProtoWorldRole::Spectator,
+            "spectator-user",
+        ),
+    ] {
+        ws_send_client(
+            ws,
+            &ClientMessage::JoinWorld {
+                world_id: *world_id.as_uuid(),
+                role,
+                user_id: user_id.to_string(),
+                pc_id: None,
+                spectate_pc_id: None,
+            },
+        )
+        .await;
+        let _ = ws_expect_message(ws, Duration::from_secs(2), |m| {
+            matches!(m, ServerMessage::WorldJoined { .. })
+        })
+        .await;
+    }
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "typography-1".to_string(),
+            payload: RequestPayload::World(WorldRequest::UpdateTypography {
+                world_id: world_id.to_string(),
+                typography: WorldTypographyData {
+                    direction: TextDirectionData::Auto,
+                    language: Some("ar_eg".to_string()),
+                    font_family: Some(" 'Noto Naskh Arabic', serif ".to_string()),
+                    font_stylesheet_url: Some(String::new()),
+                },
+            }),
+        },
+    )
+    .await;
+
+    let response = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "typography-1"),
+    )
+    .await;
+    match response {
+        ServerMessage::Response {
+            result: ResponseResult::Success { .. },
+            ..
+        } => {}
+        other => panic!("unexpected response: {:?}", other),
+    }
+
+    let updated = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldTypographyUpdated { .. })
+    })
+    .await;
+    match updated {
+        ServerMessage::WorldTypographyUpdated { typography, .. } => {
+            assert_eq!(typography.language.as_deref(), Some("ar-EG"));
+            assert_eq!(
+                typography.font_family.as_deref(),
+                Some("'Noto Naskh Arabic', serif")
+            );
+            assert_eq!(typography.font_stylesheet_url, None);
+        }
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    let saved = saved.lock().unwrap().clone().expect("world saved");
+    assert_eq!(saved.typography.language.as_deref(), Some("ar-EG"));
+
+    server.abort();
+}
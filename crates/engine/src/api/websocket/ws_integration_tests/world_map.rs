@@ -0,0 +1,138 @@
+use super::*;
+
+use wrldbldr_domain::{Location, LocationType, WorldMapPosition};
+use wrldbldr_protocol::types::WorldMapPositionData;
+use wrldbldr_protocol::{LocationRequest, RequestPayload, ResponseResult};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Join and hand back the snapshot from WorldJoined
+async fn join(ws: &mut TestWs, world_id: WorldId, role: ProtoWorldRole) -> serde_json::Value {
+    ws_send_client(
+        ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role,
+            user_id: format!("{:?}-user", role),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    match ws_expect_message(ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await
+    {
+        ServerMessage::WorldJoined { snapshot, .. } => snapshot,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_pins_a_location_then_players_see_it_on_the_world_map() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Test World", "desc", now).with_map("maps/world.png");
+    let world_id = world.id;
+
+    let capital = Location::new(world_id, "Haven", LocationType::Exterior)
+        .with_world_map_position(WorldMapPosition::new(40.0, 25.0));
+    let port = Location::new(world_id, "Saltmere", LocationType::Exterior);
+    let port_id = port.id;
+
+    let mut world_repo = MockWorldRepo::new();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(world.clone())));
+
+    let mut repos = TestAppRepos::new(world_repo);
+    repos.location_repo.checkpoint();
+    let locations = Arc::new(Mutex::new(vec![capital, port]));
+    let by_id = locations.clone();
+    repos
+        .location_repo
+        .expect_get_location()
+        .returning(move |id| Ok(by_id.lock().unwrap().iter().find(|l| l.id == id).cloned()));
+    let listed = locations.clone();
+    repos
+        .location_repo
+        .expect_list_locations_in_world()
+        .returning(move |_| Ok(listed.lock().unwrap().clone()));
+    let saved = locations.clone();
+    repos
+        .location_repo
+        .expect_save_location()
+        .returning(move |location| {
+            let mut all = saved.lock().unwrap();
+            if let Some(existing) = all.iter_mut().find(|l| l.id == location.id) {
+                *existing = location.clone();
+            }
+            Ok(())
+        });
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    let mut spectator_ws = ws_connect(addr).await;
+    join(&mut dm_ws, world_id, ProtoWorldRole::Dm).await;
+    let snapshot = join(&mut spectator_ws, world_id, ProtoWorldRole::Spectator).await;
+
+    assert_eq!(snapshot["world"]["map_asset"], "maps/world.png");
+    let haven = &snapshot["locations"][0];
+    assert_eq!(haven["world_map_position"]["x"], 40.0);
+    assert!(snapshot["locations"][1]["world_map_position"].is_null());
+
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "pin-1".to_string(),
+            payload: RequestPayload::Location(LocationRequest::SetLocationMapPosition {
+                location_id: port_id.to_string(),
+                position: Some(WorldMapPositionData { x: 120.0, y: 60.0 }),
+            }),
+        },
+    )
+    .await;
+
+    let response = ws_expect_message(
+        &mut dm_ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id, .. } if request_id == "pin-1"),
+    )
+    .await;
+    match response {
+        ServerMessage::Response {
+            result: ResponseResult::Success { .. },
+            ..
+        } => {}
+        other => panic!("unexpected response: {:?}", other),
+    }
+
+    let updated = ws_expect_message(&mut spectator_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldMapUpdated { .. })
+    })
+    .await;
+    match updated {
+        ServerMessage::WorldMapUpdated { map, .. } => {
+            assert_eq!(map.map_asset.as_deref(), Some("maps/world.png"));
+            let names: Vec<_> = map.pins.iter().map(|p| p.name.as_str()).collect();
+            assert_eq!(names, vec!["Haven", "Saltmere"]);
+            assert_eq!(
+                map.pins[1].position,
+                WorldMapPositionData { x: 100.0, y: 60.0 }
+            );
+        }
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    server.abort();
+}
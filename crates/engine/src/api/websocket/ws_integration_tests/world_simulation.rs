@@ -0,0 +1,239 @@
+use super::*;
+
+use std::sync::Mutex;
+
+use wrldbldr_domain::{MarketModifier, StoryEventType};
+use wrldbldr_protocol::types::{SimulationChangeData, WorldSimulationData};
+use wrldbldr_protocol::{
+    ErrorCode, RequestPayload, ResponseResult, SimulationRequest, TimeRequest,
+};
+
+type TestWs =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn request(ws: &mut TestWs, request_id: &str, payload: RequestPayload) -> ResponseResult {
+    ws_send_client(
+        ws,
+        &ClientMessage::Request {
+            request_id: request_id.to_string(),
+            payload,
+        },
+    )
+    .await;
+
+    match ws_expect_message(
+        ws,
+        Duration::from_secs(2),
+        |m| matches!(m, ServerMessage::Response { request_id: id, .. } if id == request_id),
+    )
+    .await
+    {
+        ServerMessage::Response { result, .. } => result,
+        other => panic!("unexpected message: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn when_dm_skips_a_week_then_they_review_and_accept_what_happened() {
+    let now = chrono::Utc::now();
+    let world = wrldbldr_domain::World::new("Varn", "desc", now);
+    let world_id = world.id;
+
+    // A price nothing holds in place; the test dice always roll a one, so it
+    // falls a step for the week
+    let modifier = MarketModifier::new(world_id, "Grain glut", now).unwrap();
+    let modifier_id = modifier.id;
+
+    // The world is stored so the clock can move on
+    let stored_world = Arc::new(Mutex::new(world));
+    let mut world_repo = MockWorldRepo::new();
+    let fetched = stored_world.clone();
+    world_repo
+        .expect_get()
+        .returning(move |_| Ok(Some(fetched.lock().unwrap().clone())));
+    let saved = stored_world.clone();
+    world_repo.expect_save().returning(move |w| {
+        *saved.lock().unwrap() = w.clone();
+        Ok(())
+    });
+
+    let mut repos = TestAppRepos::new(world_repo);
+    // Nothing else happens as the week passes
+    repos
+        .injury_repo
+        .expect_list_active_in_world()
+        .returning(|_| Ok(vec![]));
+    repos
+        .letter_repo
+        .expect_list_undelivered_in_world()
+        .returning(|_| Ok(vec![]));
+    repos
+        .broadsheet_repo
+        .expect_list_in_world()
+        .returning(|_| Ok(vec![]));
+    repos
+        .narrative_repo
+        .expect_list_story_events()
+        .returning(|_, _| Ok(vec![]));
+    repos
+        .progress_clock_repo
+        .expect_list_in_world()
+        .returning(|_| Ok(vec![]));
+    repos
+        .character_repo
+        .expect_list_npcs_in_world()
+        .returning(|_| Ok(vec![]));
+    repos
+        .lore_repo
+        .expect_list_for_world()
+        .returning(|_| Ok(vec![]));
+
+    let stored = Arc::new(Mutex::new(modifier));
+    let listed = stored.clone();
+    repos
+        .economy_repo
+        .expect_list_modifiers()
+        .returning(move |_| Ok(vec![listed.lock().unwrap().clone()]));
+    let fetched = stored.clone();
+    repos
+        .economy_repo
+        .expect_get_modifier()
+        .returning(move |_| Ok(Some(fetched.lock().unwrap().clone())));
+    let saved = stored.clone();
+    repos
+        .economy_repo
+        .expect_save_modifier()
+        .returning(move |m| {
+            *saved.lock().unwrap() = m.clone();
+            Ok(())
+        });
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = events.clone();
+    repos
+        .narrative_repo
+        .expect_save_story_event()
+        .returning(move |e| {
+            recorded.lock().unwrap().push(e.clone());
+            Ok(())
+        });
+
+    let app = build_test_app(repos, now);
+    let ws_state = Arc::new(WsState {
+        app,
+        connections: Arc::new(ConnectionManager::new()),
+        pending_time_suggestions: tokio::sync::RwLock::new(HashMap::new()),
+        pending_staging_requests: tokio::sync::RwLock::new(HashMap::new()),
+        generation_read_state: tokio::sync::RwLock::new(HashMap::new()),
+    });
+    let (addr, server) = spawn_ws_server(ws_state.clone()).await;
+
+    let mut dm_ws = ws_connect(addr).await;
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::JoinWorld {
+            world_id: *world_id.as_uuid(),
+            role: ProtoWorldRole::Dm,
+            user_id: "dm-user".to_string(),
+            pc_id: None,
+            spectate_pc_id: None,
+        },
+    )
+    .await;
+    let _ = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldJoined { .. })
+    })
+    .await;
+
+    let advanced = request(
+        &mut dm_ws,
+        "advance",
+        RequestPayload::Time(TimeRequest::AdvanceGameTime {
+            world_id: world_id.to_string(),
+            hours: 7 * 24,
+        }),
+    )
+    .await;
+    assert!(matches!(advanced, ResponseResult::Success { .. }));
+
+    let proposed = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldSimulationProposed { .. })
+    })
+    .await;
+    let ServerMessage::WorldSimulationProposed { simulation } = proposed else {
+        unreachable!()
+    };
+    assert_eq!(simulation.days, 7);
+    assert_eq!(
+        simulation.changes,
+        vec![SimulationChangeData::PriceDrift {
+            modifier_id: modifier_id.to_string(),
+            reason: "Grain glut".to_string(),
+            from_percent: 0,
+            to_percent: -5,
+        }]
+    );
+    // Nothing changes until the DM accepts
+    assert_eq!(stored.lock().unwrap().price_adjustment, 0);
+
+    let pending = request(
+        &mut dm_ws,
+        "list",
+        RequestPayload::Simulation(SimulationRequest::ListSimulations),
+    )
+    .await;
+    let pending = match pending {
+        ResponseResult::Success {
+            data: Some(data), ..
+        } => serde_json::from_value::<Vec<WorldSimulationData>>(data).unwrap(),
+        other => panic!("expected simulations, got: {:?}", other),
+    };
+    assert_eq!(pending, vec![simulation.clone()]);
+
+    // The DMs hear it was resolved before the accepting request returns
+    ws_send_client(
+        &mut dm_ws,
+        &ClientMessage::Request {
+            request_id: "accept".to_string(),
+            payload: RequestPayload::Simulation(SimulationRequest::AcceptSimulation {
+                simulation_id: simulation.id.clone(),
+            }),
+        },
+    )
+    .await;
+    let resolved = ws_expect_message(&mut dm_ws, Duration::from_secs(2), |m| {
+        matches!(m, ServerMessage::WorldSimulationResolved { .. })
+    })
+    .await;
+    assert!(matches!(
+        resolved,
+        ServerMessage::WorldSimulationResolved { simulation_id, accepted: true }
+            if simulation_id == simulation.id
+    ));
+
+    assert_eq!(stored.lock().unwrap().price_adjustment, -5);
+    let recorded = events.lock().unwrap().clone();
+    assert_eq!(recorded.len(), 1);
+    assert!(matches!(
+        &recorded[0].event_type,
+        StoryEventType::DmMarker { title, .. } if title == "While time passed"
+    ));
+
+    // It is gone once accepted
+    let again = request(
+        &mut dm_ws,
+        "accept-again",
+        RequestPayload::Simulation(SimulationRequest::AcceptSimulation {
+            simulation_id: simulation.id.clone(),
+        }),
+    )
+    .await;
+    assert!(matches!(
+        again,
+        ResponseResult::Error {
+            code: ErrorCode::NotFound,
+            ..
+        }
+    ));
+
+    server.abort();
+}
@@ -1,5 +1,9 @@
 use super::*;
 
+use crate::use_cases::trade::{trade_to_protocol, PendingTrade, TradeError};
+use wrldbldr_domain::{ScriptHook, TradeId, TradeOffer, TradeSide};
+use wrldbldr_protocol::types::TradeClosedReason;
+
 #[derive(Debug)]
 pub(super) enum InventoryAction {
     Equip,
@@ -33,7 +37,7 @@ pub(super) async fn handle_inventory_action(
     };
 
     // Verify authorization
-    if !conn_info.is_dm() && conn_info.pc_id != Some(pc_uuid) {
+    if !conn_info.is_dm() && !conn_info.controls_pc(pc_uuid) {
         return Some(error_response("UNAUTHORIZED", "Cannot control this PC"));
     }
 
@@ -83,11 +87,26 @@ pub(super) async fn handle_inventory_action(
                 item_name: action_result.item_name,
                 quantity: action_result.quantity,
             }),
-            InventoryAction::Pickup => Some(ServerMessage::ItemPickedUp {
-                pc_id: pc_id.to_string(),
-                item_id: item_id.to_string(),
-                item_name: action_result.item_name,
-            }),
+            InventoryAction::Pickup => {
+                if let Some(world_id) = conn_info.world_id {
+                    ws_scripts::fire_script_hook(
+                        state,
+                        world_id,
+                        ScriptHook::ItemPickup,
+                        Some(pc_uuid),
+                        serde_json::json!({
+                            "itemId": item_id,
+                            "itemName": action_result.item_name,
+                        }),
+                    )
+                    .await;
+                }
+                Some(ServerMessage::ItemPickedUp {
+                    pc_id: pc_id.to_string(),
+                    item_id: item_id.to_string(),
+                    item_name: action_result.item_name,
+                })
+            }
         },
         Err(e) => {
             tracing::error!(error = %e, action = ?action, "Inventory action failed");
@@ -95,3 +114,251 @@ pub(super) async fn handle_inventory_action(
         }
     }
 }
+
+/// Offer a trade from one PC to another.
+///
+/// Both PCs and the world's DMs see the offer, so the DM knows what's changing hands.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn handle_offer_trade(
+    state: &WsState,
+    connection_id: Uuid,
+    from_pc_id: &str,
+    to_pc_id: &str,
+    offered_item_ids: &[String],
+    offered_currency: u32,
+    requested_item_ids: &[String],
+    requested_currency: u32,
+) -> Option<ServerMessage> {
+    let from_pc = match parse_pc_id(from_pc_id) {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+    let to_pc = match parse_pc_id(to_pc_id) {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+    let parse_items = |ids: &[String]| -> Result<Vec<ItemId>, ServerMessage> {
+        ids.iter().map(|id| parse_item_id(id)).collect()
+    };
+    let offered_items = match parse_items(offered_item_ids) {
+        Ok(ids) => ids,
+        Err(e) => return Some(e),
+    };
+    let requested_items = match parse_items(requested_item_ids) {
+        Ok(ids) => ids,
+        Err(e) => return Some(e),
+    };
+
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+    if !conn_info.is_dm() && !conn_info.controls_pc(from_pc) {
+        return Some(error_response("UNAUTHORIZED", "Cannot control this PC"));
+    }
+
+    let trade = match state
+        .app
+        .use_cases
+        .trade
+        .trades
+        .offer(
+            from_pc,
+            to_pc,
+            TradeSide::new(offered_items, offered_currency),
+            TradeSide::new(requested_items, requested_currency),
+        )
+        .await
+    {
+        Ok(trade) => trade,
+        Err(e) => return Some(trade_error_response(&e)),
+    };
+
+    notify_trade_parties(
+        state,
+        &trade,
+        ServerMessage::TradeOffered {
+            trade: trade_to_protocol(&trade),
+        },
+    )
+    .await;
+    None
+}
+
+/// Accept or decline a trade as its recipient.
+pub(super) async fn handle_respond_to_trade(
+    state: &WsState,
+    connection_id: Uuid,
+    trade_id: &str,
+    accept: bool,
+) -> Option<ServerMessage> {
+    let (trade_id, pc_id) =
+        match authorize_trade_party(state, connection_id, trade_id, |o| o.to_pc).await {
+            Ok(ids) => ids,
+            Err(e) => return Some(e),
+        };
+
+    let trades = &state.app.use_cases.trade.trades;
+    if !accept {
+        return match trades.decline(trade_id, pc_id).await {
+            Ok(trade) => {
+                close_trade(state, &trade, TradeClosedReason::Declined, None).await;
+                None
+            }
+            Err(e) => Some(trade_error_response(&e)),
+        };
+    }
+
+    // Take a copy first: a failed accept still removes the offer, and both
+    // parties need to hear about it.
+    let snapshot = trades.get(trade_id).await;
+    match trades.accept(trade_id, pc_id).await {
+        Ok(trade) => {
+            notify_trade_parties(
+                state,
+                &trade,
+                ServerMessage::TradeCompleted {
+                    trade: trade_to_protocol(&trade),
+                },
+            )
+            .await;
+            for pc in [trade.offer.from_pc, trade.offer.to_pc] {
+                state
+                    .connections
+                    .send_to_pc(
+                        pc,
+                        ServerMessage::InventoryUpdated {
+                            pc_id: pc.to_string(),
+                        },
+                    )
+                    .await;
+            }
+            None
+        }
+        Err(e @ (TradeError::Unavailable(_) | TradeError::Expired)) => {
+            let Some(trade) = snapshot else {
+                return Some(trade_error_response(&e));
+            };
+            let reason = if matches!(e, TradeError::Expired) {
+                TradeClosedReason::Expired
+            } else {
+                TradeClosedReason::Failed
+            };
+            close_trade(state, &trade, reason, Some(e.to_string())).await;
+            None
+        }
+        Err(e) => {
+            tracing::error!(error = %e, trade_id = %trade_id, "Trade failed");
+            Some(trade_error_response(&e))
+        }
+    }
+}
+
+/// Withdraw a trade offer as the PC who made it.
+pub(super) async fn handle_cancel_trade(
+    state: &WsState,
+    connection_id: Uuid,
+    trade_id: &str,
+) -> Option<ServerMessage> {
+    let (trade_id, pc_id) =
+        match authorize_trade_party(state, connection_id, trade_id, |o| o.from_pc).await {
+            Ok(ids) => ids,
+            Err(e) => return Some(e),
+        };
+
+    match state
+        .app
+        .use_cases
+        .trade
+        .trades
+        .cancel(trade_id, pc_id)
+        .await
+    {
+        Ok(trade) => {
+            close_trade(state, &trade, TradeClosedReason::Cancelled, None).await;
+            None
+        }
+        Err(e) => Some(trade_error_response(&e)),
+    }
+}
+
+/// Check the connection may act for `party` of an open trade.
+///
+/// DMs may act for either side. Returns the trade ID and the PC acting.
+async fn authorize_trade_party(
+    state: &WsState,
+    connection_id: Uuid,
+    trade_id: &str,
+    party: impl Fn(&TradeOffer) -> PlayerCharacterId,
+) -> Result<(TradeId, PlayerCharacterId), ServerMessage> {
+    let trade_id = parse_trade_id(trade_id)?;
+    let conn_info = state
+        .connections
+        .get(connection_id)
+        .await
+        .ok_or_else(|| error_response("NOT_CONNECTED", "Connection not found"))?;
+    let trade = state
+        .app
+        .use_cases
+        .trade
+        .trades
+        .get(trade_id)
+        .await
+        .ok_or_else(|| error_response("NOT_FOUND", "Trade offer not found"))?;
+
+    let pc_id = party(&trade.offer);
+    if !conn_info.is_dm() && !conn_info.controls_pc(pc_id) {
+        return Err(error_response(
+            "UNAUTHORIZED",
+            "Only the other party can do that",
+        ));
+    }
+    Ok((trade_id, pc_id))
+}
+
+async fn close_trade(
+    state: &WsState,
+    trade: &PendingTrade,
+    reason: TradeClosedReason,
+    message: Option<String>,
+) {
+    notify_trade_parties(
+        state,
+        trade,
+        ServerMessage::TradeClosed {
+            trade_id: trade.offer.id.to_string(),
+            reason,
+            message,
+        },
+    )
+    .await;
+}
+
+/// Send a trade message to both PCs and the world's DMs.
+async fn notify_trade_parties(state: &WsState, trade: &PendingTrade, message: ServerMessage) {
+    let offer = &trade.offer;
+    state
+        .connections
+        .send_to_pc(offer.from_pc, message.clone())
+        .await;
+    state
+        .connections
+        .send_to_pc(offer.to_pc, message.clone())
+        .await;
+    state
+        .connections
+        .broadcast_to_dms(offer.world_id, message)
+        .await;
+}
+
+fn trade_error_response(e: &TradeError) -> ServerMessage {
+    let code = match e {
+        TradeError::NotFound | TradeError::PlayerCharacterNotFound | TradeError::WorldNotFound => {
+            "NOT_FOUND"
+        }
+        TradeError::NotParticipant => "UNAUTHORIZED",
+        TradeError::Repo(_) => "INTERNAL_ERROR",
+        _ => "TRADE_ERROR",
+    };
+    error_response(code, &e.to_string())
+}
@@ -0,0 +1,119 @@
+use super::*;
+
+use crate::use_cases::knowledge::{KnowledgeShared, ShareKnowledgeError, SharedKnowledge};
+use crate::use_cases::lore::lore_to_protocol;
+use wrldbldr_domain::LoreId;
+use wrldbldr_protocol::types::{LoreDiscoverySourceData, SharedKnowledgeData};
+
+/// Pass on lore or an NPC's whereabouts from one PC to party members.
+pub(super) async fn handle_share_knowledge(
+    state: &WsState,
+    connection_id: Uuid,
+    from_pc_id: &str,
+    target_pc_ids: &[String],
+    knowledge: SharedKnowledgeData,
+) -> Option<ServerMessage> {
+    let from_pc = match parse_pc_id(from_pc_id) {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+    let to_pcs: Vec<PlayerCharacterId> = match target_pc_ids
+        .iter()
+        .map(|id| parse_pc_id(id))
+        .collect::<Result<_, _>>()
+    {
+        Ok(ids) => ids,
+        Err(e) => return Some(e),
+    };
+    let knowledge = match knowledge {
+        SharedKnowledgeData::Lore { lore_id } => {
+            match parse_id(&lore_id, LoreId::from_uuid, "Invalid lore ID format") {
+                Ok(id) => SharedKnowledge::Lore(id),
+                Err(e) => return Some(e),
+            }
+        }
+        SharedKnowledgeData::NpcWhereabouts { npc_id } => match parse_character_id(&npc_id) {
+            Ok(id) => SharedKnowledge::NpcWhereabouts(id),
+            Err(e) => return Some(e),
+        },
+        SharedKnowledgeData::Unknown => {
+            return Some(error_response(
+                "BAD_REQUEST",
+                "Unknown kind of knowledge to share",
+            ))
+        }
+    };
+
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+    if !conn_info.is_dm() && !conn_info.controls_pc(from_pc) {
+        return Some(error_response("UNAUTHORIZED", "Cannot control this PC"));
+    }
+
+    let shared = match state
+        .app
+        .use_cases
+        .knowledge
+        .share
+        .execute(from_pc, &to_pcs, knowledge)
+        .await
+    {
+        Ok(shared) => shared,
+        Err(e) => return Some(share_error_response(&e)),
+    };
+
+    notify_knowledge_shared(state, &shared).await;
+    None
+}
+
+async fn notify_knowledge_shared(state: &WsState, shared: &KnowledgeShared) {
+    if let Some(shared_lore) = &shared.lore {
+        let lore_data = lore_to_protocol(&shared_lore.lore);
+        for (pc_id, chunk_ids) in &shared_lore.known_chunk_ids {
+            state
+                .connections
+                .send_to_pc(
+                    *pc_id,
+                    ServerMessage::LoreDiscovered {
+                        character_id: pc_id.to_string(),
+                        lore: lore_data.clone(),
+                        discovered_chunk_ids: chunk_ids.iter().map(|id| id.to_string()).collect(),
+                        discovery_source: LoreDiscoverySourceData::SharedByPc {
+                            pc_id: shared.from_pc_id.to_string(),
+                            pc_name: shared.from_pc_name.clone(),
+                        },
+                    },
+                )
+                .await;
+        }
+    }
+
+    let message = ServerMessage::KnowledgeShared {
+        from_pc_id: shared.from_pc_id.to_string(),
+        from_pc_name: shared.from_pc_name.clone(),
+        to_pc_ids: shared.recipients.iter().map(|id| id.to_string()).collect(),
+        title: shared.title.clone(),
+    };
+    state
+        .connections
+        .send_to_pc(shared.from_pc_id, message.clone())
+        .await;
+    for pc_id in &shared.recipients {
+        state.connections.send_to_pc(*pc_id, message.clone()).await;
+    }
+    state
+        .connections
+        .broadcast_to_dms(shared.world_id, message)
+        .await;
+}
+
+fn share_error_response(e: &ShareKnowledgeError) -> ServerMessage {
+    let code = match e {
+        ShareKnowledgeError::PlayerCharacterNotFound => "NOT_FOUND",
+        ShareKnowledgeError::Repo(_) => "INTERNAL_ERROR",
+        _ => "KNOWLEDGE_ERROR",
+    };
+    error_response(code, &e.to_string())
+}
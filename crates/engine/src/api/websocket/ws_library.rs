@@ -0,0 +1,108 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::library::LibraryError;
+
+use wrldbldr_domain::LibraryEntryId;
+use wrldbldr_protocol::LibraryRequest;
+
+pub(super) async fn handle_library_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: LibraryRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // The library is a prep tool; entries belong to the connected user
+    require_dm_for_request(conn_info, request_id)?;
+    let library = &state.app.use_cases.library;
+    let owner_id = conn_info.user_id.as_str();
+
+    let result = match request {
+        LibraryRequest::ListLibrary => library
+            .manage
+            .list(owner_id)
+            .await
+            .map(ResponseResult::success),
+
+        LibraryRequest::GetLibraryEntry { entry_id } => {
+            let entry_id = parse_library_entry_id_for_request(&entry_id, request_id)?;
+            library
+                .manage
+                .get(owner_id, entry_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        LibraryRequest::PublishToLibrary {
+            world_id,
+            entity_type,
+            entity_id,
+            description,
+        } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+            if conn_info.world_id != Some(world_id) {
+                return Ok(ResponseResult::error(
+                    ErrorCode::Forbidden,
+                    "Join the world before publishing from it",
+                ));
+            }
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity ID")?;
+            library
+                .manage
+                .publish(owner_id, world_id, entity_type, entity_id, description)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        LibraryRequest::DeleteLibraryEntry { entry_id } => {
+            let entry_id = parse_library_entry_id_for_request(&entry_id, request_id)?;
+            library
+                .manage
+                .delete(owner_id, entry_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+
+        LibraryRequest::ImportLibraryEntry { entry_id, world_id } => {
+            let entry_id = parse_library_entry_id_for_request(&entry_id, request_id)?;
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+            if conn_info.world_id != Some(world_id) {
+                return Ok(ResponseResult::error(
+                    ErrorCode::Forbidden,
+                    "Join the world before importing into it",
+                ));
+            }
+            library
+                .import
+                .execute(owner_id, entry_id, world_id)
+                .await
+                .map(ResponseResult::success)
+        }
+    };
+
+    Ok(result.unwrap_or_else(library_error_response))
+}
+
+fn parse_library_entry_id_for_request(
+    id_str: &str,
+    request_id: &str,
+) -> Result<LibraryEntryId, ServerMessage> {
+    parse_id_for_request(
+        id_str,
+        request_id,
+        LibraryEntryId::from_uuid,
+        "Invalid library entry ID",
+    )
+}
+
+fn library_error_response(e: LibraryError) -> ResponseResult {
+    match e {
+        LibraryError::NotFound | LibraryError::EntityNotFound(_) => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        LibraryError::Unsupported(_) | LibraryError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        LibraryError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
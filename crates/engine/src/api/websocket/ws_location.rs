@@ -1,6 +1,16 @@
 use super::*;
 
 use crate::api::connections::ConnectionInfo;
+use crate::use_cases::assets::RegionMapKind;
+use crate::use_cases::management::{
+    encounter_table_to_protocol, hotspot_to_protocol, light_level_to_protocol,
+    sound_carry_to_protocol, ManagementError,
+};
+use wrldbldr_domain::{EntityType, LocationScale, LocationTree};
+use wrldbldr_protocol::types::{
+    HotspotData, LocationBreadcrumbData, LocationScaleData, LocationTravelData, RegionMapKindData,
+    SubLocationData,
+};
 use wrldbldr_protocol::{LocationRequest, RegionRequest};
 
 pub(super) async fn handle_location_request(
@@ -10,7 +20,7 @@ pub(super) async fn handle_location_request(
     request: LocationRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        LocationRequest::ListLocations { world_id } => {
+        LocationRequest::ListLocations { world_id, tags } => {
             let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
                 Ok(id) => id,
                 Err(e) => return Err(e),
@@ -24,14 +34,37 @@ pub(super) async fn handle_location_request(
                 .list_locations(world_id_typed)
                 .await
             {
-                Ok(locations) => {
+                Ok(mut locations) => {
+                    // Breadcrumbs come from the whole world, not just what the tags keep
+                    let all = locations.clone();
+                    let tree = LocationTree::new(&all);
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        EntityType::Location,
+                        &tags,
+                        &mut locations,
+                        |l| l.id.to_uuid(),
+                    )
+                    .await?;
+                    let mut aliases =
+                        ws_mentions::listed_aliases(state, conn_info, world_id_typed).await;
                     let data: Vec<serde_json::Value> = locations
                         .into_iter()
                         .map(|l| {
+                            let aliases = aliases
+                                .remove(&(EntityType::Location, l.id.to_uuid()))
+                                .unwrap_or_default();
+                            let breadcrumb = breadcrumb_data(tree.ancestors(l.id));
                             serde_json::json!({
                                 "id": l.id.to_string(),
                                 "name": l.name,
                                 "location_type": format!("{:?}", l.location_type),
+                                "parent_location_id": l.parent_location_id.map(|id| id.to_string()),
+                                "scale": l.scale.map(scale_to_protocol),
+                                "breadcrumb": breadcrumb,
+                                "aliases": aliases,
                             })
                         })
                         .collect();
@@ -58,15 +91,41 @@ pub(super) async fn handle_location_request(
                 .get_location(location_id_typed)
                 .await
             {
-                Ok(Some(location)) => Ok(ResponseResult::success(serde_json::json!({
-                    "id": location.id.to_string(),
-                    "name": location.name,
-                    "description": if location.description.is_empty() { None } else { Some(location.description) },
-                    "location_type": Some(format!("{:?}", location.location_type)),
-                    "atmosphere": location.atmosphere,
-                    "backdrop_asset": location.backdrop_asset,
-                    "presence_cache_ttl_hours": location.presence_cache_ttl_hours,
-                }))),
+                Ok(Some(location)) => {
+                    let custom_fields = ws_custom_fields::detail_entries(
+                        state,
+                        conn_info,
+                        location.world_id,
+                        EntityType::Location,
+                        *location.id.as_uuid(),
+                    )
+                    .await;
+                    let breadcrumb = state
+                        .app
+                        .use_cases
+                        .management
+                        .location
+                        .location_breadcrumb(&location)
+                        .await
+                        .unwrap_or_default();
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": location.id.to_string(),
+                        "crossing_minutes": location.crossing_minutes(),
+                        "name": location.name,
+                        "description": if location.description.is_empty() { None } else { Some(location.description) },
+                        "location_type": Some(format!("{:?}", location.location_type)),
+                        "atmosphere": location.atmosphere,
+                        "backdrop_asset": location.backdrop_asset,
+                        "map_asset": location.map_asset,
+                        "presence_cache_ttl_hours": location.presence_cache_ttl_hours,
+                        "parent_location_id": location.parent_location_id.map(|id| id.to_string()),
+                        "scale": location.scale.map(scale_to_protocol),
+                        "breadcrumb": breadcrumb_data(breadcrumb.iter()),
+                        "world_map_position": location.world_map_position,
+                        "encounter_table": location.encounter_table.as_ref().map(encounter_table_to_protocol),
+                        "custom_fields": custom_fields,
+                    })))
+                }
                 Ok(None) => Ok(ResponseResult::error(
                     ErrorCode::NotFound,
                     "Location not found",
@@ -87,24 +146,46 @@ pub(super) async fn handle_location_request(
                 Ok(id) => id,
                 Err(e) => return Err(e),
             };
+            let parent_id = match data.parent_id.as_deref() {
+                Some(id) => Some(parse_location_id_for_request(id, request_id)?),
+                None => None,
+            };
 
             match state
                 .app
                 .use_cases
                 .management
                 .location
-                .create_location(world_id_typed, data.name, data.description, data.setting)
+                .create_location(
+                    world_id_typed,
+                    data.name,
+                    data.description,
+                    data.setting,
+                    parent_id,
+                    data.scale.map(scale_from_protocol),
+                )
                 .await
             {
-                Ok(location) => Ok(ResponseResult::success(serde_json::json!({
-                    "id": location.id.to_string(),
-                    "name": location.name,
-                    "description": if location.description.is_empty() { None } else { Some(location.description) },
-                    "location_type": Some(format!("{:?}", location.location_type)),
-                    "atmosphere": location.atmosphere,
-                    "backdrop_asset": location.backdrop_asset,
-                    "presence_cache_ttl_hours": location.presence_cache_ttl_hours,
-                }))),
+                Ok(location) => {
+                    ws_mentions::spawn_index_mentions(
+                        state,
+                        location.world_id,
+                        wrldbldr_domain::EntityType::Location,
+                        location.id.to_uuid(),
+                    );
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": location.id.to_string(),
+                        "crossing_minutes": location.crossing_minutes(),
+                        "name": location.name,
+                        "description": if location.description.is_empty() { None } else { Some(location.description) },
+                        "location_type": Some(format!("{:?}", location.location_type)),
+                        "atmosphere": location.atmosphere,
+                        "backdrop_asset": location.backdrop_asset,
+                        "presence_cache_ttl_hours": location.presence_cache_ttl_hours,
+                        "parent_location_id": location.parent_location_id.map(|id| id.to_string()),
+                        "scale": location.scale.map(scale_to_protocol),
+                    })))
+                }
                 Err(crate::use_cases::management::ManagementError::InvalidInput(msg)) => {
                     Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
                 }
@@ -130,18 +211,36 @@ pub(super) async fn handle_location_request(
                 .use_cases
                 .management
                 .location
-                .update_location(location_id_typed, data.name, data.description, data.setting)
+                .update_location(
+                    location_id_typed,
+                    data.name,
+                    data.description,
+                    data.setting,
+                    data.scale.map(scale_from_protocol),
+                    data.crossing_minutes,
+                )
                 .await
             {
-                Ok(location) => Ok(ResponseResult::success(serde_json::json!({
-                    "id": location.id.to_string(),
-                    "name": location.name,
-                    "description": if location.description.is_empty() { None } else { Some(location.description) },
-                    "location_type": Some(format!("{:?}", location.location_type)),
-                    "atmosphere": location.atmosphere,
-                    "backdrop_asset": location.backdrop_asset,
-                    "presence_cache_ttl_hours": location.presence_cache_ttl_hours,
-                }))),
+                Ok(location) => {
+                    ws_mentions::spawn_index_mentions(
+                        state,
+                        location.world_id,
+                        wrldbldr_domain::EntityType::Location,
+                        location.id.to_uuid(),
+                    );
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": location.id.to_string(),
+                        "crossing_minutes": location.crossing_minutes(),
+                        "name": location.name,
+                        "description": if location.description.is_empty() { None } else { Some(location.description) },
+                        "location_type": Some(format!("{:?}", location.location_type)),
+                        "atmosphere": location.atmosphere,
+                        "backdrop_asset": location.backdrop_asset,
+                        "presence_cache_ttl_hours": location.presence_cache_ttl_hours,
+                        "parent_location_id": location.parent_location_id.map(|id| id.to_string()),
+                        "scale": location.scale.map(scale_to_protocol),
+                    })))
+                }
                 Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
                     ResponseResult::error(ErrorCode::NotFound, "Location not found"),
                 ),
@@ -173,7 +272,15 @@ pub(super) async fn handle_location_request(
                 .delete_location(location_id_typed)
                 .await
             {
-                Ok(()) => Ok(ResponseResult::success_empty()),
+                Ok(()) => {
+                    ws_mentions::forget_mentions(
+                        state,
+                        wrldbldr_domain::EntityType::Location,
+                        location_id_typed.to_uuid(),
+                    )
+                    .await;
+                    Ok(ResponseResult::success_empty())
+                }
                 Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
                     ResponseResult::error(ErrorCode::NotFound, "Location not found"),
                 ),
@@ -184,6 +291,37 @@ pub(super) async fn handle_location_request(
             }
         }
 
+        LocationRequest::DuplicateLocation { location_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let location_id_typed = parse_location_id_for_request(&location_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .duplicate
+                .entity
+                .location(location_id_typed)
+                .await
+            {
+                Ok(location) => Ok(ResponseResult::success(serde_json::json!({
+                    "id": location.id.to_string(),
+                    "name": location.name,
+                    "description": if location.description.is_empty() { None } else { Some(location.description) },
+                    "location_type": Some(format!("{:?}", location.location_type)),
+                    "atmosphere": location.atmosphere,
+                    "backdrop_asset": location.backdrop_asset,
+                    "presence_cache_ttl_hours": location.presence_cache_ttl_hours,
+                }))),
+                Err(crate::use_cases::duplicate::DuplicateError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "Location not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
         LocationRequest::GetLocationConnections { location_id } => {
             let location_id_typed = match parse_location_id_for_request(&location_id, request_id) {
                 Ok(id) => id,
@@ -199,9 +337,12 @@ pub(super) async fn handle_location_request(
                 .await
             {
                 Ok(connections) => {
+                    let is_dm = conn_info.is_dm();
                     let data: Vec<serde_json::Value> = connections
                         .into_iter()
                         .map(|c| {
+                            let (key_item_ids, lock_challenge_id) =
+                                lock_requirements_json(&c.lock, is_dm);
                             serde_json::json!({
                                 "from_location_id": c.from_location.to_string(),
                                 "to_location_id": c.to_location.to_string(),
@@ -209,6 +350,10 @@ pub(super) async fn handle_location_request(
                                 "description": c.description.unwrap_or_default(),
                                 "bidirectional": c.bidirectional,
                                 "travel_time": c.travel_time,
+                                "is_locked": c.is_locked,
+                                "lock_description": c.lock_description,
+                                "key_item_ids": key_item_ids,
+                                "lock_challenge_id": lock_challenge_id,
                             })
                         })
                         .collect();
@@ -281,9 +426,183 @@ pub(super) async fn handle_location_request(
             }
         }
 
-        other => {
-            let msg = format!("This request type is not yet implemented: {:?}", other);
-            Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
+        LocationRequest::SetLocationConnectionLock {
+            from_id,
+            to_id,
+            lock,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let from_id = parse_location_id_for_request(&from_id, request_id)?;
+            let to_id = parse_location_id_for_request(&to_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .location
+                .set_location_connection_lock(from_id, to_id, lock)
+                .await
+            {
+                Ok(()) => Ok(ResponseResult::success_empty()),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "Connection not found"),
+                ),
+                Err(crate::use_cases::management::ManagementError::InvalidInput(msg)) => {
+                    Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
+                }
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        LocationRequest::SetLocationParent {
+            location_id,
+            parent_id,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let location_id_typed = parse_location_id_for_request(&location_id, request_id)?;
+            let parent_id = match parent_id.as_deref() {
+                Some(id) => Some(parse_location_id_for_request(id, request_id)?),
+                None => None,
+            };
+
+            match state
+                .app
+                .use_cases
+                .management
+                .location
+                .set_location_parent(location_id_typed, parent_id)
+                .await
+            {
+                Ok(location) => Ok(ResponseResult::success(serde_json::json!({
+                    "id": location.id.to_string(),
+                    "parent_location_id": location.parent_location_id.map(|id| id.to_string()),
+                }))),
+                Err(e) => Ok(hierarchy_error_response(e)),
+            }
+        }
+
+        LocationRequest::ListSubLocations {
+            location_id,
+            recursive,
+        } => {
+            let location_id_typed = parse_location_id_for_request(&location_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .location
+                .list_sub_locations(location_id_typed, recursive)
+                .await
+            {
+                Ok(inside) => {
+                    let data: Vec<SubLocationData> = inside
+                        .into_iter()
+                        .map(|sub| SubLocationData {
+                            id: sub.location.id.to_string(),
+                            name: sub.location.name,
+                            scale: sub.location.scale.map(scale_to_protocol),
+                            parent_id: sub
+                                .location
+                                .parent_location_id
+                                .map(|id| id.to_string())
+                                .unwrap_or_default(),
+                            depth: sub.depth,
+                            child_count: sub.child_count,
+                            descendant_count: sub.descendant_count,
+                            region_count: sub.region_count,
+                        })
+                        .collect();
+                    Ok(ResponseResult::success(data))
+                }
+                Err(e) => Ok(hierarchy_error_response(e)),
+            }
+        }
+
+        LocationRequest::GetTravelTime {
+            from_location_id,
+            to_location_id,
+        } => {
+            let from = parse_location_id_for_request(&from_location_id, request_id)?;
+            let to = parse_location_id_for_request(&to_location_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .location
+                .travel_time(from, to)
+                .await
+            {
+                Ok(travel) => {
+                    let via = travel.as_ref().and_then(|t| t.via.as_ref());
+                    Ok(ResponseResult::success(LocationTravelData {
+                        from_location_id,
+                        to_location_id,
+                        minutes: travel.as_ref().map(|t| t.minutes),
+                        via_location_id: via.map(|l| l.id.to_string()),
+                        via_location_name: via.map(|l| l.name.clone()),
+                    }))
+                }
+                Err(e) => Ok(hierarchy_error_response(e)),
+            }
+        }
+
+        LocationRequest::SetLocationEncounterTable { location_id, table } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let location_id_typed = parse_location_id_for_request(&location_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .location
+                .set_encounter_table(location_id_typed, table)
+                .await
+            {
+                Ok(location) => Ok(ResponseResult::success(
+                    location
+                        .encounter_table
+                        .as_ref()
+                        .map(encounter_table_to_protocol),
+                )),
+                Err(e) => Ok(hierarchy_error_response(e)),
+            }
+        }
+
+        LocationRequest::SetLocationMapPosition {
+            location_id,
+            position,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let location_id_typed = parse_location_id_for_request(&location_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .world
+                .set_location_map_position(location_id_typed, position)
+                .await
+            {
+                Ok((world_id, map)) => {
+                    state
+                        .connections
+                        .broadcast_to_world(
+                            world_id,
+                            ServerMessage::WorldMapUpdated {
+                                world_id: world_id.to_string(),
+                                map: map.clone(),
+                            },
+                        )
+                        .await;
+                    Ok(ResponseResult::success(map))
+                }
+                Err(e) => Ok(hierarchy_error_response(e)),
+            }
         }
     }
 }
@@ -295,7 +614,7 @@ pub(super) async fn handle_region_request(
     request: RegionRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        RegionRequest::ListRegions { location_id } => {
+        RegionRequest::ListRegions { location_id, tags } => {
             let location_id_typed = match parse_location_id_for_request(&location_id, request_id) {
                 Ok(id) => id,
                 Err(e) => return Err(e),
@@ -309,7 +628,17 @@ pub(super) async fn handle_region_request(
                 .list_regions(location_id_typed)
                 .await
             {
-                Ok(regions) => {
+                Ok(mut regions) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        EntityType::Region,
+                        &tags,
+                        &mut regions,
+                        |r| r.id.to_uuid(),
+                    )
+                    .await?;
                     let data: Vec<serde_json::Value> = regions
                         .into_iter()
                         .map(|r| {
@@ -327,10 +656,12 @@ pub(super) async fn handle_region_request(
                                 "name": r.name,
                                 "description": r.description,
                                 "backdrop_asset": r.backdrop_asset,
+                                "map_asset": r.map_asset,
                                 "atmosphere": r.atmosphere,
                                 "map_bounds": bounds,
                                 "is_spawn_point": r.is_spawn_point,
                                 "order": r.order,
+                                "lighting": r.lighting.map(light_level_to_protocol),
                             })
                         })
                         .collect();
@@ -372,10 +703,17 @@ pub(super) async fn handle_region_request(
                         "name": region.name,
                         "description": region.description,
                         "backdrop_asset": region.backdrop_asset,
+                        "map_asset": region.map_asset,
                         "atmosphere": region.atmosphere,
                         "map_bounds": bounds,
                         "is_spawn_point": region.is_spawn_point,
                         "order": region.order,
+                        "lighting": region.lighting.map(light_level_to_protocol),
+                        "hotspots": region
+                            .hotspots
+                            .iter()
+                            .map(hotspot_to_protocol)
+                            .collect::<Vec<_>>(),
                     })))
                 }
                 Ok(None) => Ok(ResponseResult::error(
@@ -412,22 +750,33 @@ pub(super) async fn handle_region_request(
                 )
                 .await
             {
-                Ok(region) => Ok(ResponseResult::success(serde_json::json!({
-                    "id": region.id.to_string(),
-                    "location_id": region.location_id.to_string(),
-                    "name": region.name,
-                    "description": region.description,
-                    "backdrop_asset": region.backdrop_asset,
-                    "atmosphere": region.atmosphere,
-                    "map_bounds": region.map_bounds.map(|b| serde_json::json!({
-                        "x": b.x,
-                        "y": b.y,
-                        "width": b.width,
-                        "height": b.height,
-                    })),
-                    "is_spawn_point": region.is_spawn_point,
-                    "order": region.order,
-                }))),
+                Ok(region) => {
+                    if let Some(world_id) = conn_info.world_id {
+                        ws_mentions::spawn_index_mentions(
+                            state,
+                            world_id,
+                            wrldbldr_domain::EntityType::Region,
+                            region.id.to_uuid(),
+                        );
+                    }
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": region.id.to_string(),
+                        "location_id": region.location_id.to_string(),
+                        "name": region.name,
+                        "description": region.description,
+                        "backdrop_asset": region.backdrop_asset,
+                        "map_asset": region.map_asset,
+                        "atmosphere": region.atmosphere,
+                        "map_bounds": region.map_bounds.map(|b| serde_json::json!({
+                            "x": b.x,
+                            "y": b.y,
+                            "width": b.width,
+                            "height": b.height,
+                        })),
+                        "is_spawn_point": region.is_spawn_point,
+                        "order": region.order,
+                    })))
+                }
                 Err(crate::use_cases::management::ManagementError::InvalidInput(msg)) => {
                     Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
                 }
@@ -461,22 +810,33 @@ pub(super) async fn handle_region_request(
                 )
                 .await
             {
-                Ok(region) => Ok(ResponseResult::success(serde_json::json!({
-                    "id": region.id.to_string(),
-                    "location_id": region.location_id.to_string(),
-                    "name": region.name,
-                    "description": region.description,
-                    "backdrop_asset": region.backdrop_asset,
-                    "atmosphere": region.atmosphere,
-                    "map_bounds": region.map_bounds.map(|b| serde_json::json!({
-                        "x": b.x,
-                        "y": b.y,
-                        "width": b.width,
-                        "height": b.height,
-                    })),
-                    "is_spawn_point": region.is_spawn_point,
-                    "order": region.order,
-                }))),
+                Ok(region) => {
+                    if let Some(world_id) = conn_info.world_id {
+                        ws_mentions::spawn_index_mentions(
+                            state,
+                            world_id,
+                            wrldbldr_domain::EntityType::Region,
+                            region.id.to_uuid(),
+                        );
+                    }
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": region.id.to_string(),
+                        "location_id": region.location_id.to_string(),
+                        "name": region.name,
+                        "description": region.description,
+                        "backdrop_asset": region.backdrop_asset,
+                        "map_asset": region.map_asset,
+                        "atmosphere": region.atmosphere,
+                        "map_bounds": region.map_bounds.map(|b| serde_json::json!({
+                            "x": b.x,
+                            "y": b.y,
+                            "width": b.width,
+                            "height": b.height,
+                        })),
+                        "is_spawn_point": region.is_spawn_point,
+                        "order": region.order,
+                    })))
+                }
                 Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
                     ResponseResult::error(ErrorCode::NotFound, "Region not found"),
                 ),
@@ -490,6 +850,173 @@ pub(super) async fn handle_region_request(
             }
         }
 
+        RegionRequest::SetRegionHotspots {
+            region_id,
+            hotspots,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let region_id_typed = parse_region_id_for_request(&region_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .location
+                .set_region_hotspots(region_id_typed, hotspots)
+                .await
+            {
+                Ok(region) => {
+                    let hotspots: Vec<_> =
+                        region.hotspots.iter().map(hotspot_to_protocol).collect();
+                    broadcast_region_hotspots(state, &region, hotspots.clone()).await;
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "region_id": region.id.to_string(),
+                        "hotspots": hotspots,
+                    })))
+                }
+                Err(ManagementError::NotFound) => Ok(ResponseResult::error(
+                    ErrorCode::NotFound,
+                    "Region not found",
+                )),
+                Err(ManagementError::InvalidInput(msg)) => {
+                    Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
+                }
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        RegionRequest::SetRegionLighting {
+            region_id,
+            lighting,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let region_id_typed = parse_region_id_for_request(&region_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .location
+                .set_region_lighting(region_id_typed, lighting)
+                .await
+            {
+                Ok(region) => {
+                    broadcast_region_light(state, &region).await;
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "region_id": region.id.to_string(),
+                        "lighting": region.lighting.map(light_level_to_protocol),
+                    })))
+                }
+                Err(ManagementError::NotFound) => Ok(ResponseResult::error(
+                    ErrorCode::NotFound,
+                    "Region not found",
+                )),
+                Err(ManagementError::InvalidInput(msg)) => {
+                    Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
+                }
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        RegionRequest::GenerateRegionMap {
+            region_id,
+            kind,
+            workflow,
+            prompt,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let region_id_typed = parse_region_id_for_request(&region_id, request_id)?;
+            let kind = match kind {
+                RegionMapKindData::BattleMap => RegionMapKind::BattleMap,
+                RegionMapKindData::RegionLayout => RegionMapKind::RegionLayout,
+                RegionMapKindData::Unknown => {
+                    return Ok(ResponseResult::error(
+                        ErrorCode::BadRequest,
+                        "Unknown region map kind",
+                    ))
+                }
+            };
+
+            let management = &state.app.use_cases.management.location;
+            let region = match management.get_region(region_id_typed).await {
+                Ok(Some(region)) => region,
+                Ok(None) => {
+                    return Ok(ResponseResult::error(
+                        ErrorCode::NotFound,
+                        "Region not found",
+                    ))
+                }
+                Err(e) => {
+                    return Ok(ResponseResult::error(
+                        ErrorCode::InternalError,
+                        e.to_string(),
+                    ))
+                }
+            };
+            let world_id = match management.get_location(region.location_id).await {
+                Ok(Some(location)) => location.world_id,
+                Ok(None) => {
+                    return Ok(ResponseResult::error(
+                        ErrorCode::NotFound,
+                        "Location not found",
+                    ))
+                }
+                Err(e) => {
+                    return Ok(ResponseResult::error(
+                        ErrorCode::InternalError,
+                        e.to_string(),
+                    ))
+                }
+            };
+
+            // Image generation takes a while; answer now and broadcast the result
+            let region_map = state.app.use_cases.assets.region_map.clone();
+            let connections = state.connections.clone();
+            tokio::spawn(async move {
+                match region_map
+                    .execute(region_id_typed, kind, workflow, prompt)
+                    .await
+                {
+                    Ok(region) => {
+                        connections
+                            .broadcast_to_world(
+                                world_id,
+                                ServerMessage::RegionMapGenerated {
+                                    region_id: region.id.to_string(),
+                                    map_asset: region.map_asset,
+                                    error: None,
+                                },
+                            )
+                            .await;
+                    }
+                    Err(e) => {
+                        tracing::warn!(region_id = %region_id_typed, error = %e, "Region map generation failed");
+                        connections
+                            .broadcast_to_dms(
+                                world_id,
+                                ServerMessage::RegionMapGenerated {
+                                    region_id: region_id_typed.to_string(),
+                                    map_asset: None,
+                                    error: Some(e.to_string()),
+                                },
+                            )
+                            .await;
+                    }
+                }
+            });
+
+            Ok(ResponseResult::success(serde_json::json!({
+                "region_id": region_id,
+                "status": "generating",
+            })))
+        }
+
         RegionRequest::DeleteRegion { region_id } => {
             if let Err(e) = require_dm_for_request(conn_info, request_id) {
                 return Err(e);
@@ -508,7 +1035,15 @@ pub(super) async fn handle_region_request(
                 .delete_region(region_id_typed)
                 .await
             {
-                Ok(()) => Ok(ResponseResult::success_empty()),
+                Ok(()) => {
+                    ws_mentions::forget_mentions(
+                        state,
+                        wrldbldr_domain::EntityType::Region,
+                        region_id_typed.to_uuid(),
+                    )
+                    .await;
+                    Ok(ResponseResult::success_empty())
+                }
                 Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
                     ResponseResult::error(ErrorCode::NotFound, "Region not found"),
                 ),
@@ -534,9 +1069,12 @@ pub(super) async fn handle_region_request(
                 .await
             {
                 Ok(connections) => {
+                    let is_dm = conn_info.is_dm();
                     let data: Vec<serde_json::Value> = connections
                         .into_iter()
                         .map(|c| {
+                            let (key_item_ids, lock_challenge_id) =
+                                lock_requirements_json(&c.lock, is_dm);
                             serde_json::json!({
                                 "from_region_id": c.from_region.to_string(),
                                 "to_region_id": c.to_region.to_string(),
@@ -544,6 +1082,9 @@ pub(super) async fn handle_region_request(
                                 "bidirectional": c.bidirectional,
                                 "is_locked": c.is_locked,
                                 "lock_description": c.lock_description,
+                                "key_item_ids": key_item_ids,
+                                "lock_challenge_id": lock_challenge_id,
+                                "sound": sound_carry_to_protocol(c.sound),
                             })
                         })
                         .collect();
@@ -660,6 +1201,68 @@ pub(super) async fn handle_region_request(
             }
         }
 
+        RegionRequest::SetRegionConnectionLock {
+            from_id,
+            to_id,
+            lock,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let from_id = parse_region_id_for_request(&from_id, request_id)?;
+            let to_id = parse_region_id_for_request(&to_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .location
+                .set_region_connection_lock(from_id, to_id, lock)
+                .await
+            {
+                Ok(()) => Ok(ResponseResult::success_empty()),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "Connection not found"),
+                ),
+                Err(crate::use_cases::management::ManagementError::InvalidInput(msg)) => {
+                    Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
+                }
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        RegionRequest::SetRegionConnectionSound {
+            from_id,
+            to_id,
+            sound,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let from_id = parse_region_id_for_request(&from_id, request_id)?;
+            let to_id = parse_region_id_for_request(&to_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .location
+                .set_region_connection_sound(from_id, to_id, sound)
+                .await
+            {
+                Ok(()) => Ok(ResponseResult::success_empty()),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "Connection not found"),
+                ),
+                Err(crate::use_cases::management::ManagementError::InvalidInput(msg)) => {
+                    Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
+                }
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
         RegionRequest::GetRegionExits { region_id } => {
             let region_id_typed = match parse_region_id_for_request(&region_id, request_id) {
                 Ok(id) => id,
@@ -776,7 +1379,7 @@ pub(super) async fn handle_region_request(
             }
         }
 
-        RegionRequest::ListSpawnPoints { world_id } => {
+        RegionRequest::ListSpawnPoints { world_id, tags } => {
             let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
                 Ok(id) => id,
                 Err(e) => return Err(e),
@@ -790,7 +1393,17 @@ pub(super) async fn handle_region_request(
                 .list_spawn_points(world_id_typed)
                 .await
             {
-                Ok(spawn_points) => {
+                Ok(mut spawn_points) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        EntityType::Region,
+                        &tags,
+                        &mut spawn_points,
+                        |r| r.id.to_uuid(),
+                    )
+                    .await?;
                     let data: Vec<serde_json::Value> = spawn_points
                         .into_iter()
                         .map(|r| {
@@ -800,6 +1413,7 @@ pub(super) async fn handle_region_request(
                                 "name": r.name,
                                 "description": r.description,
                                 "backdrop_asset": r.backdrop_asset,
+                                "map_asset": r.map_asset,
                                 "atmosphere": r.atmosphere,
                                 "map_bounds": r.map_bounds.map(|b| serde_json::json!({
                                     "x": b.x,
@@ -809,6 +1423,7 @@ pub(super) async fn handle_region_request(
                                 })),
                                 "is_spawn_point": r.is_spawn_point,
                                 "order": r.order,
+                                "lighting": r.lighting.map(light_level_to_protocol),
                             })
                         })
                         .collect();
@@ -822,3 +1437,117 @@ pub(super) async fn handle_region_request(
         }
     }
 }
+
+/// Push a region's new hotspots to everyone in its world
+async fn broadcast_region_hotspots(
+    state: &WsState,
+    region: &wrldbldr_domain::Region,
+    hotspots: Vec<HotspotData>,
+) {
+    let location = state
+        .app
+        .use_cases
+        .management
+        .location
+        .get_location(region.location_id)
+        .await;
+    let Ok(Some(location)) = location else {
+        tracing::warn!(region_id = %region.id, "Region hotspots saved but location lookup failed");
+        return;
+    };
+    state
+        .connections
+        .broadcast_to_world(
+            location.world_id,
+            ServerMessage::RegionHotspotsUpdated {
+                region_id: region.id.to_string(),
+                hotspots,
+            },
+        )
+        .await;
+}
+
+/// Tell the world how lit a region is now that the DM changed it
+async fn broadcast_region_light(state: &WsState, region: &wrldbldr_domain::Region) {
+    let Ok(Some(location)) = state.app.entities.location.get(region.location_id).await else {
+        tracing::warn!(region_id = %region.id, "Region lighting saved but location lookup failed");
+        return;
+    };
+    let Ok(Some(world)) = state.app.entities.world.get(location.world_id).await else {
+        tracing::warn!(region_id = %region.id, "Region lighting saved but world lookup failed");
+        return;
+    };
+    let light_level = region.light_level(location.location_type, world.game_time.time_of_day());
+    state
+        .connections
+        .broadcast_to_world(
+            location.world_id,
+            ServerMessage::RegionLightChanged {
+                region_id: region.id.to_string(),
+                light_level: light_level_to_protocol(light_level),
+            },
+        )
+        .await;
+}
+
+fn hierarchy_error_response(e: ManagementError) -> ResponseResult {
+    match e {
+        ManagementError::NotFound => {
+            ResponseResult::error(ErrorCode::NotFound, "Location not found")
+        }
+        ManagementError::InvalidInput(msg) => ResponseResult::error(ErrorCode::BadRequest, &msg),
+        e => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
+
+/// The path down to a location, outermost first
+fn breadcrumb_data<'a>(
+    ancestors: impl IntoIterator<Item = &'a wrldbldr_domain::Location>,
+) -> Vec<LocationBreadcrumbData> {
+    ancestors
+        .into_iter()
+        .map(|l| LocationBreadcrumbData {
+            id: l.id.to_string(),
+            name: l.name.clone(),
+            scale: l.scale.map(scale_to_protocol),
+        })
+        .collect()
+}
+
+fn scale_from_protocol(scale: LocationScaleData) -> LocationScale {
+    match scale {
+        LocationScaleData::Continent => LocationScale::Continent,
+        LocationScaleData::Kingdom => LocationScale::Kingdom,
+        LocationScaleData::City => LocationScale::City,
+        LocationScaleData::District => LocationScale::District,
+        LocationScaleData::Building => LocationScale::Building,
+        LocationScaleData::Room => LocationScale::Room,
+        LocationScaleData::Unknown => LocationScale::Unknown,
+    }
+}
+
+fn scale_to_protocol(scale: LocationScale) -> LocationScaleData {
+    match scale {
+        LocationScale::Continent => LocationScaleData::Continent,
+        LocationScale::Kingdom => LocationScaleData::Kingdom,
+        LocationScale::City => LocationScaleData::City,
+        LocationScale::District => LocationScaleData::District,
+        LocationScale::Building => LocationScaleData::Building,
+        LocationScale::Room => LocationScaleData::Room,
+        LocationScale::Unknown => LocationScaleData::Unknown,
+    }
+}
+
+/// What opens a connection's lock. Players only learn that it is locked.
+fn lock_requirements_json(
+    lock: &wrldbldr_domain::LockRequirements,
+    is_dm: bool,
+) -> (Vec<String>, Option<String>) {
+    if !is_dm {
+        return (Vec::new(), None);
+    }
+    (
+        lock.key_item_ids.iter().map(|id| id.to_string()).collect(),
+        lock.challenge_id.map(|id| id.to_string()),
+    )
+}
@@ -11,14 +11,26 @@ pub(super) async fn handle_lore_request(
     request: LoreRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        LoreRequest::ListLore { world_id } => {
+        LoreRequest::ListLore { world_id, tags } => {
             let world_uuid = match parse_world_id_for_request(&world_id, request_id) {
                 Ok(id) => id,
                 Err(e) => return Err(e),
             };
 
             match state.app.use_cases.lore.ops.list(world_uuid).await {
-                Ok(data) => Ok(ResponseResult::success(data)),
+                Ok(mut data) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        wrldbldr_domain::EntityType::Lore,
+                        &tags,
+                        &mut data,
+                        ws_tags::json_entry_id,
+                    )
+                    .await?;
+                    Ok(ResponseResult::success(data))
+                }
                 Err(e) => Ok(ResponseResult::error(
                     ErrorCode::InternalError,
                     e.to_string(),
@@ -56,7 +68,10 @@ pub(super) async fn handle_lore_request(
             };
 
             match state.app.use_cases.lore.ops.create(world_uuid, data).await {
-                Ok(result) => Ok(ResponseResult::success(result)),
+                Ok(result) => {
+                    spawn_index_lore(state, Some(world_uuid), lore_id_in(&result, "id"));
+                    Ok(ResponseResult::success(result))
+                }
                 Err(crate::use_cases::lore::LoreError::InvalidCategory(msg)) => {
                     Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
                 }
@@ -84,7 +99,10 @@ pub(super) async fn handle_lore_request(
             };
 
             match state.app.use_cases.lore.ops.update(lore_uuid, data).await {
-                Ok(result) => Ok(ResponseResult::success(result)),
+                Ok(result) => {
+                    spawn_index_lore(state, conn_info.world_id, Some(lore_uuid.to_uuid()));
+                    Ok(ResponseResult::success(result))
+                }
                 Err(crate::use_cases::lore::LoreError::NotFound) => Err(ServerMessage::Response {
                     request_id: request_id.to_string(),
                     result: ResponseResult::error(ErrorCode::NotFound, "Lore not found"),
@@ -113,7 +131,15 @@ pub(super) async fn handle_lore_request(
             };
 
             match state.app.use_cases.lore.ops.delete(lore_uuid).await {
-                Ok(result) => Ok(ResponseResult::success(result)),
+                Ok(result) => {
+                    ws_mentions::forget_mentions(
+                        state,
+                        wrldbldr_domain::EntityType::Lore,
+                        lore_uuid.to_uuid(),
+                    )
+                    .await;
+                    Ok(ResponseResult::success(result))
+                }
                 Err(e) => Ok(ResponseResult::error(
                     ErrorCode::InternalError,
                     &e.to_string(),
@@ -139,7 +165,10 @@ pub(super) async fn handle_lore_request(
                 .add_chunk(lore_uuid, data)
                 .await
             {
-                Ok(result) => Ok(ResponseResult::success(result)),
+                Ok(result) => {
+                    spawn_index_lore(state, conn_info.world_id, Some(lore_uuid.to_uuid()));
+                    Ok(ResponseResult::success(result))
+                }
                 Err(crate::use_cases::lore::LoreError::NotFound) => Err(ServerMessage::Response {
                     request_id: request_id.to_string(),
                     result: ResponseResult::error(ErrorCode::NotFound, "Lore not found"),
@@ -192,7 +221,10 @@ pub(super) async fn handle_lore_request(
                 .update_chunk(world_id, chunk_uuid, data)
                 .await
             {
-                Ok(result) => Ok(ResponseResult::success(result)),
+                Ok(result) => {
+                    spawn_index_lore(state, Some(world_id), lore_id_in(&result, "loreId"));
+                    Ok(ResponseResult::success(result))
+                }
                 Err(crate::use_cases::lore::LoreError::ChunkNotFound) => {
                     Err(ServerMessage::Response {
                         request_id: request_id.to_string(),
@@ -247,7 +279,10 @@ pub(super) async fn handle_lore_request(
                 .delete_chunk(world_id, chunk_uuid)
                 .await
             {
-                Ok(result) => Ok(ResponseResult::success(result)),
+                Ok(result) => {
+                    spawn_index_lore(state, Some(world_id), lore_id_in(&result, "loreId"));
+                    Ok(ResponseResult::success(result))
+                }
                 Err(crate::use_cases::lore::LoreError::ChunkNotFound) => {
                     Err(ServerMessage::Response {
                         request_id: request_id.to_string(),
@@ -330,6 +365,12 @@ pub(super) async fn handle_lore_request(
                         format!("Invalid NPC ID in conversation source: {}", npc_id),
                     ))
                 }
+                Err(crate::use_cases::lore::LoreError::InvalidPcId(pc_id)) => {
+                    Ok(ResponseResult::error(
+                        ErrorCode::BadRequest,
+                        format!("Invalid PC ID in shared source: {}", pc_id),
+                    ))
+                }
                 Err(e) => Ok(ResponseResult::error(
                     ErrorCode::InternalError,
                     &e.to_string(),
@@ -458,3 +499,22 @@ pub(super) async fn handle_lore_request(
         }
     }
 }
+
+/// Index a lore entry's text again once it's saved
+fn spawn_index_lore(state: &WsState, world_id: Option<WorldId>, lore_id: Option<Uuid>) {
+    if let (Some(world_id), Some(lore_id)) = (world_id, lore_id) {
+        ws_mentions::spawn_index_mentions(
+            state,
+            world_id,
+            wrldbldr_domain::EntityType::Lore,
+            lore_id,
+        );
+    }
+}
+
+fn lore_id_in(result: &serde_json::Value, field: &str) -> Option<Uuid> {
+    result
+        .get(field)
+        .and_then(|id| id.as_str())
+        .and_then(|id| Uuid::parse_str(id).ok())
+}
@@ -0,0 +1,211 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::mail::{LetterNotice, MailError};
+
+use wrldbldr_domain::LetterId;
+use wrldbldr_protocol::MailRequest;
+
+pub(super) async fn handle_mail_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: MailRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before reading mail",
+        ));
+    };
+    let mail = &state.app.use_cases.mail.manage;
+
+    // Players handle their own PC's mail; the world's mailbag is the DM's
+    if matches!(
+        request,
+        MailRequest::ListWorldMail
+            | MailRequest::ReplyAsNpc { .. }
+            | MailRequest::DeleteLetter { .. }
+    ) {
+        require_dm_for_request(conn_info, request_id)?;
+    }
+
+    let result = match request {
+        MailRequest::ListMailbox { pc_id } => {
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            if let Err(denied) = require_pc_player(state, world_id, pc_id, conn_info).await {
+                return Ok(denied);
+            }
+            mail.mailbox(world_id, pc_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        MailRequest::SendLetter { pc_id, data } => {
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            if let Err(denied) = require_pc_player(state, world_id, pc_id, conn_info).await {
+                return Ok(denied);
+            }
+            match mail.send(world_id, pc_id, data).await {
+                Ok(notice) => {
+                    let letter = notice.letter.clone();
+                    announce(state, world_id, notice).await;
+                    Ok(ResponseResult::success(letter))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        MailRequest::MarkLetterRead { letter_id } => {
+            let letter_id = parse_letter_id(&letter_id, request_id)?;
+            let addressee = match mail.get(world_id, letter_id).await {
+                Ok(letter) => letter.recipient.pc_id(),
+                Err(e) => return Ok(mail_error_response(e)),
+            };
+            let Some(pc_id) = addressee else {
+                return Ok(ResponseResult::error(
+                    ErrorCode::Forbidden,
+                    "Only the addressee's player can mark a letter read",
+                ));
+            };
+            match mail.get_pc(world_id, pc_id).await {
+                Ok(pc) if pc.user_id == conn_info.user_id => {}
+                Ok(_) => {
+                    return Ok(ResponseResult::error(
+                        ErrorCode::Forbidden,
+                        "Only the addressee's player can mark a letter read",
+                    ))
+                }
+                Err(e) => return Ok(mail_error_response(e)),
+            }
+            match mail.mark_read(world_id, letter_id).await {
+                Ok(notice) => {
+                    let letter = notice.letter.clone();
+                    announce(state, world_id, notice).await;
+                    Ok(ResponseResult::success(letter))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        MailRequest::ListWorldMail => mail.world_mail(world_id).await.map(ResponseResult::success),
+
+        MailRequest::ReplyAsNpc { letter_id, body } => {
+            let letter_id = parse_letter_id(&letter_id, request_id)?;
+            match mail.reply_as_npc(world_id, letter_id, body).await {
+                Ok(notice) => {
+                    let letter = notice.letter.clone();
+                    announce(state, world_id, notice).await;
+                    Ok(ResponseResult::success(letter))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        MailRequest::DeleteLetter { letter_id } => {
+            let letter_id = parse_letter_id(&letter_id, request_id)?;
+            match mail.delete(world_id, letter_id).await {
+                Ok(notice) => {
+                    let msg = ServerMessage::LetterRemoved {
+                        letter_id: letter_id.to_string(),
+                    };
+                    for pc_id in notice.pc_readers {
+                        state
+                            .connections
+                            .send_to_pc_controllers(pc_id, msg.clone())
+                            .await;
+                    }
+                    state.connections.broadcast_to_dms(world_id, msg).await;
+                    Ok(ResponseResult::success_empty())
+                }
+                Err(e) => Err(e),
+            }
+        }
+    };
+
+    Ok(result.unwrap_or_else(mail_error_response))
+}
+
+/// Deliver the letters the world's clock has caught up with, after time
+/// advanced
+pub(super) async fn deliver_after_time_advance(state: &WsState, world_id: WorldId) {
+    match state.app.use_cases.mail.manage.deliver_due(world_id).await {
+        Ok(notices) => {
+            for notice in notices {
+                announce(state, world_id, notice).await;
+            }
+        }
+        Err(e) => {
+            tracing::warn!(world_id = %world_id, error = %e, "Failed to deliver letters");
+        }
+    }
+}
+
+/// Tell the DMs about a letter, and the players whose PCs can read it
+async fn announce(state: &WsState, world_id: WorldId, notice: LetterNotice) {
+    let msg = ServerMessage::LetterUpdated {
+        letter: notice.letter,
+    };
+    for pc_id in notice.pc_readers {
+        state
+            .connections
+            .send_to_pc_controllers(pc_id, msg.clone())
+            .await;
+    }
+    state.connections.broadcast_to_dms(world_id, msg).await;
+}
+
+/// The DM, or the player controlling the PC
+async fn require_pc_player(
+    state: &WsState,
+    world_id: WorldId,
+    pc_id: PlayerCharacterId,
+    conn_info: &ConnectionInfo,
+) -> Result<(), ResponseResult> {
+    if conn_info.is_dm() {
+        return Ok(());
+    }
+    match state
+        .app
+        .use_cases
+        .mail
+        .manage
+        .get_pc(world_id, pc_id)
+        .await
+    {
+        Ok(pc) if pc.user_id == conn_info.user_id => Ok(()),
+        Ok(_) => Err(ResponseResult::error(
+            ErrorCode::Forbidden,
+            "Only the DM or the character's player can handle their mail",
+        )),
+        Err(e) => Err(mail_error_response(e)),
+    }
+}
+
+fn parse_pc_id(id: &str, request_id: &str) -> Result<PlayerCharacterId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        PlayerCharacterId::from_uuid,
+        "Invalid PC ID",
+    )
+}
+
+fn parse_letter_id(id: &str, request_id: &str) -> Result<LetterId, ServerMessage> {
+    parse_id_for_request(id, request_id, LetterId::from_uuid, "Invalid letter ID")
+}
+
+fn mail_error_response(e: MailError) -> ResponseResult {
+    match e {
+        MailError::LetterNotFound
+        | MailError::PlayerCharacterNotFound
+        | MailError::NpcNotFound
+        | MailError::WorldNotFound => ResponseResult::error(ErrorCode::NotFound, e.to_string()),
+        MailError::Conflict(_) => ResponseResult::error(ErrorCode::Conflict, e.to_string()),
+        MailError::Invalid(_) => ResponseResult::error(ErrorCode::ValidationError, e.to_string()),
+        MailError::Generation(_) => {
+            ResponseResult::error(ErrorCode::ServiceUnavailable, e.to_string())
+        }
+        MailError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -0,0 +1,185 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::mentions::MentionError;
+
+use serde_json::json;
+use wrldbldr_domain::EntityType;
+use wrldbldr_protocol::MentionRequest;
+
+pub(super) async fn handle_mention_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: MentionRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // Aliases and the mention index are the DM's, in the world the DM is in
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before looking up mentions",
+        ));
+    };
+    let mentions = &state.app.use_cases.mentions.manage;
+
+    let result = match request {
+        MentionRequest::ListMentionTargets => mentions
+            .list_targets(world_id)
+            .await
+            .map(ResponseResult::success),
+
+        MentionRequest::SetEntityAliases {
+            entity_type,
+            entity_id,
+            aliases,
+        } => {
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity_id")?;
+            mentions
+                .set_aliases(world_id, entity_type, entity_id, aliases)
+                .await
+                .map(|aliases| ResponseResult::success(json!({ "aliases": aliases })))
+        }
+
+        MentionRequest::GetEntityAliases {
+            entity_type,
+            entity_id,
+        } => {
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity_id")?;
+            mentions
+                .aliases_of(world_id, entity_type, entity_id)
+                .await
+                .map(|aliases| ResponseResult::success(json!({ "aliases": aliases })))
+        }
+
+        MentionRequest::AddEntityAlias {
+            entity_type,
+            entity_id,
+            alias,
+        } => {
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity_id")?;
+            mentions
+                .add_alias(world_id, entity_type, entity_id, alias)
+                .await
+                .map(|aliases| ResponseResult::success(json!({ "aliases": aliases })))
+        }
+
+        MentionRequest::RemoveEntityAlias {
+            entity_type,
+            entity_id,
+            alias,
+        } => {
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity_id")?;
+            mentions
+                .remove_alias(world_id, entity_type, entity_id, &alias)
+                .await
+                .map(|aliases| ResponseResult::success(json!({ "aliases": aliases })))
+        }
+
+        MentionRequest::GetMentionsOf {
+            entity_type,
+            entity_id,
+        } => {
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity_id")?;
+            mentions
+                .mentions_of(world_id, entity_type, entity_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        MentionRequest::GetMentionsIn {
+            entity_type,
+            entity_id,
+        } => {
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity_id")?;
+            mentions
+                .mentions_in(world_id, entity_type, entity_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        MentionRequest::ReindexMentions => mentions
+            .reindex_world(world_id)
+            .await
+            .map(|found| ResponseResult::success(json!({ "mentions": found }))),
+    };
+
+    Ok(result.unwrap_or_else(mention_error_response))
+}
+
+/// Indexing reads the whole world's names; keep it off the request that
+/// saved the text
+pub(super) fn spawn_index_mentions(
+    state: &WsState,
+    world_id: WorldId,
+    entity_type: EntityType,
+    entity_id: Uuid,
+) {
+    let mentions = state.app.use_cases.mentions.manage.clone();
+    tokio::spawn(async move {
+        if let Err(e) = mentions
+            .index_source(world_id, entity_type, entity_id)
+            .await
+        {
+            tracing::warn!(
+                entity_type = %entity_type,
+                entity_id = %entity_id,
+                error = %e,
+                "Failed to index mentions"
+            );
+        }
+    });
+}
+
+/// Aliases to include in entity lists, so they can be searched by alias.
+/// An alias can give away a secret ("the Grey Fox" is Vexis), so only the
+/// DM's lists carry them.
+pub(super) async fn listed_aliases(
+    state: &WsState,
+    conn_info: &ConnectionInfo,
+    world_id: WorldId,
+) -> HashMap<(EntityType, Uuid), Vec<String>> {
+    if !conn_info.is_dm() {
+        return HashMap::new();
+    }
+    state
+        .app
+        .use_cases
+        .mentions
+        .manage
+        .aliases(world_id)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Failed to list aliases");
+            HashMap::new()
+        })
+}
+
+/// Drop a deleted entity from the mention index
+pub(super) async fn forget_mentions(state: &WsState, entity_type: EntityType, entity_id: Uuid) {
+    if let Err(e) = state
+        .app
+        .use_cases
+        .mentions
+        .manage
+        .forget(entity_type, entity_id)
+        .await
+    {
+        tracing::warn!(
+            entity_type = %entity_type,
+            entity_id = %entity_id,
+            error = %e,
+            "Failed to forget mentions"
+        );
+    }
+}
+
+fn mention_error_response(e: MentionError) -> ResponseResult {
+    match e {
+        MentionError::NotFound => ResponseResult::error(ErrorCode::NotFound, e.to_string()),
+        MentionError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        MentionError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
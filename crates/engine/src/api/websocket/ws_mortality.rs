@@ -0,0 +1,140 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::mortality::MortalityError;
+
+use wrldbldr_protocol::MortalityRequest;
+
+pub(super) async fn handle_mortality_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: MortalityRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before managing character deaths",
+        ));
+    };
+    let mortality = &state.app.use_cases.mortality.manage;
+
+    let result = match request {
+        MortalityRequest::GetDeath { pc_id } => {
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            if let Some(denied) = require_dm_or_owner(state, conn_info, world_id, pc_id).await {
+                return Ok(denied);
+            }
+            mortality
+                .get_death(world_id, pc_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        MortalityRequest::KillCharacter { pc_id, data } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            let result = mortality.kill(world_id, pc_id, data).await;
+            if let Ok(death) = &result {
+                let msg = ServerMessage::PcDied {
+                    world_id: world_id.to_string(),
+                    death: death.clone(),
+                };
+                state.connections.broadcast_to_world(world_id, msg).await;
+            }
+            result.map(ResponseResult::success)
+        }
+
+        MortalityRequest::ResurrectCharacter { pc_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            let result = mortality.resurrect(world_id, pc_id).await;
+            if let Ok(pc) = &result {
+                let msg = ServerMessage::PcResurrected {
+                    world_id: world_id.to_string(),
+                    pc_id: pc.id.to_string(),
+                    name: pc.name.clone(),
+                };
+                state.connections.broadcast_to_world(world_id, msg).await;
+            }
+            result.map(|_| ResponseResult::success_empty())
+        }
+
+        MortalityRequest::CreateSuccessor { pc_id, data } => {
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            if let Some(denied) = require_dm_or_owner(state, conn_info, world_id, pc_id).await {
+                return Ok(denied);
+            }
+            mortality
+                .create_successor(world_id, pc_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+    };
+
+    Ok(result.unwrap_or_else(mortality_error_response))
+}
+
+/// Turn away a player whose PC has died; they act again through a successor
+/// or once the DM resurrects them
+pub(super) async fn reject_if_pc_dead(
+    state: &WsState,
+    pc_id: PlayerCharacterId,
+) -> Option<ServerMessage> {
+    match state.app.entities.player_character.get(pc_id).await {
+        Ok(Some(pc)) if pc.is_locked() => Some(error_response(
+            "CHARACTER_DEAD",
+            &format!("{} has died and can no longer act", pc.name),
+        )),
+        _ => None,
+    }
+}
+
+/// The DM, or the player the PC belongs to
+async fn require_dm_or_owner(
+    state: &WsState,
+    conn_info: &ConnectionInfo,
+    world_id: WorldId,
+    pc_id: PlayerCharacterId,
+) -> Option<ResponseResult> {
+    if conn_info.is_dm() {
+        return None;
+    }
+    match state
+        .app
+        .use_cases
+        .mortality
+        .manage
+        .get_pc(world_id, pc_id)
+        .await
+    {
+        Ok(pc) if pc.user_id == conn_info.user_id => None,
+        Ok(_) => Some(ResponseResult::error(
+            ErrorCode::Forbidden,
+            "Only the DM or the character's player can do this",
+        )),
+        Err(e) => Some(mortality_error_response(e)),
+    }
+}
+
+fn parse_pc_id(id: &str, request_id: &str) -> Result<PlayerCharacterId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        PlayerCharacterId::from_uuid,
+        "Invalid PC ID",
+    )
+}
+
+fn mortality_error_response(e: MortalityError) -> ResponseResult {
+    match e {
+        MortalityError::PlayerCharacterNotFound | MortalityError::WorldNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        MortalityError::Conflict(_) => ResponseResult::error(ErrorCode::Conflict, e.to_string()),
+        MortalityError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        MortalityError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
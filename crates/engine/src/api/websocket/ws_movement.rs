@@ -1,5 +1,12 @@
 use super::*;
-use crate::use_cases::movement::{EnterRegionError, StagingStatus};
+use crate::use_cases::interactions::PcInteraction;
+use crate::use_cases::movement::{
+    unlocked_connection_to_protocol, EnterRegionError, LockedWay, StagingStatus,
+    UnlockConnectionError,
+};
+use crate::use_cases::travel::{journey_to_protocol, JourneyOutcome, TravelError};
+use wrldbldr_domain::TutorialAction;
+use wrldbldr_protocol::types::{JourneyDecision, LockedWayData};
 use wrldbldr_protocol::{CharacterData, CharacterPosition, InteractionData, SceneData};
 
 pub(super) async fn handle_move_to_region(
@@ -25,7 +32,7 @@ pub(super) async fn handle_move_to_region(
     };
 
     // Verify the PC belongs to this connection (or is DM)
-    if !conn_info.is_dm() && conn_info.pc_id != Some(pc_uuid) {
+    if !conn_info.is_dm() && !conn_info.controls_pc(pc_uuid) {
         return Some(error_response("UNAUTHORIZED", "Cannot control this PC"));
     }
 
@@ -40,6 +47,13 @@ pub(super) async fn handle_move_to_region(
     {
         Ok(result) => {
             let world_id = result.pc.world_id;
+            ws_tutorial::record_tutorial_action(
+                state,
+                world_id,
+                pc_uuid,
+                TutorialAction::EnteredRegion(result.region.id),
+            )
+            .await;
 
             match result.staging_status {
                 StagingStatus::Pending { previous_staging } => {
@@ -112,6 +126,7 @@ pub(super) async fn handle_move_to_region(
                         npcs_present: scene_change.npcs_present,
                         navigation: scene_change.navigation,
                         region_items: scene_change.region_items,
+                        crowds_present: scene_change.crowds_present,
                     })
                 }
             }
@@ -131,6 +146,10 @@ pub(super) async fn handle_move_to_region(
         Err(EnterRegionError::MovementBlocked(reason)) => {
             Some(ServerMessage::MovementBlocked { pc_id, reason })
         }
+        Err(EnterRegionError::PlayerCharacterDead) => Some(ServerMessage::MovementBlocked {
+            pc_id,
+            reason: "Your character has died".to_string(),
+        }),
         Err(e) => Some(error_response("MOVE_ERROR", &e.to_string())),
     }
 }
@@ -166,20 +185,42 @@ pub(super) async fn handle_exit_to_location(
     };
 
     // Verify the PC belongs to this connection (or is DM)
-    if !conn_info.is_dm() && conn_info.pc_id != Some(pc_uuid) {
+    if !conn_info.is_dm() && !conn_info.controls_pc(pc_uuid) {
         return Some(error_response("UNAUTHORIZED", "Cannot control this PC"));
     }
 
-    match state
+    let result = state
         .app
         .use_cases
         .movement
         .exit_location
         .execute(pc_uuid, location_uuid, arrival_region_uuid)
-        .await
-    {
+        .await;
+    exit_location_response(state, pc_id, pc_uuid, conn_info.is_dm(), result).await
+}
+
+/// What the mover hears after trying to change location. Pending staging and
+/// time suggestions go out to DMs along the way.
+async fn exit_location_response(
+    state: &WsState,
+    pc_id: String,
+    pc_uuid: PlayerCharacterId,
+    is_dm: bool,
+    result: Result<
+        crate::use_cases::movement::EnterRegionResult,
+        crate::use_cases::movement::ExitLocationError,
+    >,
+) -> Option<ServerMessage> {
+    match result {
         Ok(result) => {
             let world_id = result.pc.world_id;
+            ws_tutorial::record_tutorial_action(
+                state,
+                world_id,
+                pc_uuid,
+                TutorialAction::EnteredRegion(result.region.id),
+            )
+            .await;
 
             match result.staging_status {
                 StagingStatus::Pending { previous_staging } => {
@@ -231,7 +272,7 @@ pub(super) async fn handle_exit_to_location(
                         .app
                         .use_cases
                         .scene_change
-                        .build_scene_change(&result.region, npcs.clone(), conn_info.is_dm())
+                        .build_scene_change(&result.region, npcs.clone(), is_dm)
                         .await
                     {
                         Ok(sc) => sc,
@@ -257,6 +298,7 @@ pub(super) async fn handle_exit_to_location(
                         npcs_present: scene_change.npcs_present,
                         navigation: scene_change.navigation,
                         region_items: scene_change.region_items,
+                        crowds_present: scene_change.crowds_present,
                     })
                 }
             }
@@ -270,9 +312,21 @@ pub(super) async fn handle_exit_to_location(
         Err(crate::use_cases::movement::ExitLocationError::PlayerCharacterNotFound) => {
             Some(error_response("NOT_FOUND", "Player character not found"))
         }
+        Err(crate::use_cases::movement::ExitLocationError::PlayerCharacterDead) => {
+            Some(ServerMessage::MovementBlocked {
+                pc_id,
+                reason: "Your character has died".to_string(),
+            })
+        }
         Err(crate::use_cases::movement::ExitLocationError::RegionLocationMismatch) => {
             Some(error_response("INVALID_MOVE", "Region is not in target location"))
         }
+        Err(crate::use_cases::movement::ExitLocationError::UnknownLocation) => {
+            Some(ServerMessage::MovementBlocked {
+                pc_id,
+                reason: "You don't know the way there".to_string(),
+            })
+        }
         Err(crate::use_cases::movement::ExitLocationError::WorldNotFound) => {
             Some(error_response("NOT_FOUND", "World not found"))
         }
@@ -280,6 +334,232 @@ pub(super) async fn handle_exit_to_location(
     }
 }
 
+/// Set out on a fast-travel journey to a location the PC knows.
+pub(super) async fn handle_fast_travel(
+    state: &WsState,
+    connection_id: Uuid,
+    pc_id: String,
+    location_id: String,
+) -> Option<ServerMessage> {
+    let pc_uuid = match parse_pc_id(&pc_id) {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+    let location_uuid = match parse_location_id(&location_id) {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+    if !conn_info.is_dm() && !conn_info.controls_pc(pc_uuid) {
+        return Some(error_response("UNAUTHORIZED", "Cannot control this PC"));
+    }
+
+    let result = state
+        .app
+        .use_cases
+        .travel
+        .fast_travel
+        .start(pc_uuid, location_uuid)
+        .await;
+    journey_response(state, pc_id, pc_uuid, conn_info.is_dm(), result).await
+}
+
+/// Settle a journey stopped by an encounter (DM only). The PC hears how it
+/// went; the DM only hears about failures.
+pub(super) async fn handle_resolve_journey(
+    state: &WsState,
+    connection_id: Uuid,
+    journey_id: String,
+    decision: JourneyDecision,
+) -> Option<ServerMessage> {
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+    if let Err(e) = require_dm(&conn_info) {
+        return Some(e);
+    }
+    let journey_id = match parse_journey_id(&journey_id) {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+
+    let fast_travel = &state.app.use_cases.travel.fast_travel;
+    let journey = match fast_travel.get(journey_id).await {
+        Some(journey) if Some(journey.journey.world_id) == conn_info.world_id => journey,
+        _ => return Some(error_response("NOT_FOUND", "Journey not found")),
+    };
+    let pc_uuid = journey.journey.pc_id;
+
+    let result = fast_travel.resolve(journey_id, decision).await;
+    if result.is_ok() {
+        let resolved = ServerMessage::JourneyResolved {
+            journey: journey_to_protocol(&journey),
+            decision,
+        };
+        state
+            .connections
+            .broadcast_to_dms(journey.journey.world_id, resolved.clone())
+            .await;
+        state.connections.send_to_pc(pc_uuid, resolved).await;
+    }
+
+    match journey_response(state, pc_uuid.to_string(), pc_uuid, false, result).await {
+        Some(error @ ServerMessage::Error { .. }) => Some(error),
+        Some(msg) => {
+            state.connections.send_to_pc(pc_uuid, msg).await;
+            None
+        }
+        None => None,
+    }
+}
+
+/// What the traveller hears about their journey. DMs are told about stopped
+/// journeys so they can settle them.
+async fn journey_response(
+    state: &WsState,
+    pc_id: String,
+    pc_uuid: PlayerCharacterId,
+    is_dm: bool,
+    result: Result<JourneyOutcome, TravelError>,
+) -> Option<ServerMessage> {
+    match result {
+        Ok(JourneyOutcome::Arrived { arrival }) => {
+            exit_location_response(state, pc_id, pc_uuid, is_dm, Ok(*arrival)).await
+        }
+        Ok(JourneyOutcome::Interrupted {
+            journey,
+            time_suggestion,
+        }) => {
+            let world_id = journey.journey.world_id;
+            maybe_broadcast_time_suggestion(state, world_id, &time_suggestion).await;
+            let interrupted = ServerMessage::JourneyInterrupted {
+                journey: journey_to_protocol(&journey),
+            };
+            state
+                .connections
+                .broadcast_to_dms(world_id, interrupted.clone())
+                .await;
+            Some(interrupted)
+        }
+        Ok(JourneyOutcome::TurnedBack {
+            journey,
+            time_suggestion,
+        }) => {
+            maybe_broadcast_time_suggestion(state, journey.journey.world_id, &time_suggestion)
+                .await;
+            None
+        }
+        Err(TravelError::Exit(e)) => {
+            exit_location_response(state, pc_id, pc_uuid, is_dm, Err(e)).await
+        }
+        Err(TravelError::PlayerCharacterDead) => Some(ServerMessage::MovementBlocked {
+            pc_id,
+            reason: "Your character has died".to_string(),
+        }),
+        Err(e @ (TravelError::UnknownLocation | TravelError::NoRoute)) => {
+            Some(ServerMessage::MovementBlocked {
+                pc_id,
+                reason: e.to_string(),
+            })
+        }
+        Err(TravelError::JourneyInProgress) => Some(ServerMessage::MovementBlocked {
+            pc_id,
+            reason: "You are already on a journey".to_string(),
+        }),
+        Err(
+            e @ (TravelError::NotFound
+            | TravelError::PlayerCharacterNotFound
+            | TravelError::LocationNotFound),
+        ) => Some(error_response("NOT_FOUND", &e.to_string())),
+        Err(e @ (TravelError::AlreadyThere | TravelError::Invalid(_))) => {
+            Some(error_response("INVALID_MOVE", &e.to_string()))
+        }
+        Err(e) => Some(error_response("MOVE_ERROR", &e.to_string())),
+    }
+}
+
+/// Try a carried item on the lock of a way out of where the PC stands. An
+/// opened lock is announced to the whole world.
+pub(super) async fn handle_unlock_with_key(
+    state: &WsState,
+    connection_id: Uuid,
+    pc_id: String,
+    item_id: String,
+    to: LockedWayData,
+) -> Option<ServerMessage> {
+    let pc_uuid = match parse_pc_id(&pc_id) {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+    let item_uuid = match parse_item_id(&item_id) {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+    let to = match to {
+        LockedWayData::Region { region_id } => match parse_region_id(&region_id) {
+            Ok(id) => LockedWay::Region(id),
+            Err(e) => return Some(e),
+        },
+        LockedWayData::Location { location_id } => match parse_location_id(&location_id) {
+            Ok(id) => LockedWay::Location(id),
+            Err(e) => return Some(e),
+        },
+        LockedWayData::Unknown => {
+            return Some(error_response("INVALID_MOVE", "Unknown kind of connection"))
+        }
+    };
+
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+    if !conn_info.is_dm() && !conn_info.controls_pc(pc_uuid) {
+        return Some(error_response("UNAUTHORIZED", "Cannot control this PC"));
+    }
+
+    match state
+        .app
+        .use_cases
+        .movement
+        .unlock_connection
+        .with_key(pc_uuid, to, item_uuid)
+        .await
+    {
+        Ok(unlocked) => {
+            state
+                .connections
+                .broadcast_to_scene(
+                    unlocked.world_id,
+                    Some(pc_uuid),
+                    ServerMessage::ConnectionUnlocked {
+                        connection: unlocked_connection_to_protocol(&unlocked),
+                    },
+                )
+                .await;
+            None
+        }
+        Err(UnlockConnectionError::PlayerCharacterNotFound) => {
+            Some(error_response("NOT_FOUND", "Player character not found"))
+        }
+        Err(UnlockConnectionError::PlayerCharacterDead) => Some(ServerMessage::MovementBlocked {
+            pc_id,
+            reason: "Your character has died".to_string(),
+        }),
+        Err(UnlockConnectionError::Repo(e)) => {
+            Some(error_response("MOVE_ERROR", &e.to_string()))
+        }
+        Err(e) => Some(ServerMessage::MovementBlocked {
+            pc_id,
+            reason: e.to_string(),
+        }),
+    }
+}
+
 // TODO: Time suggestions are stored in-memory and never expire.
 // Consider adding a cleanup mechanism or TTL for suggestions that are never resolved.
 // For now, this is acceptable as suggestions are typically resolved quickly.
@@ -368,7 +648,14 @@ async fn build_scene_update(
         });
     }
 
-    let interactions = match state.app.entities.interaction.list_for_scene(scene.id).await {
+    let interactions = match state
+        .app
+        .use_cases
+        .interactions
+        .library
+        .list_for_pc_in_scene(pc.id, scene.id)
+        .await
+    {
         Ok(list) => list,
         Err(e) => {
             tracing::warn!(error = %e, scene_id = %scene.id, "Failed to load scene interactions");
@@ -378,17 +665,7 @@ async fn build_scene_update(
 
     let mut interaction_data = Vec::with_capacity(interactions.len());
     for interaction in interactions {
-        let (target_id, target_type, target_name) =
-            resolve_interaction_target(state, &interaction.target).await;
-        interaction_data.push(InteractionData {
-            id: interaction.id.to_string(),
-            name: interaction.name,
-            interaction_type: interaction_type_to_str(&interaction.interaction_type).to_string(),
-            target_name,
-            target_id,
-            target_type,
-            is_available: interaction.is_available,
-        });
+        interaction_data.push(pc_interaction_to_data(state, interaction).await);
     }
 
     Some(ServerMessage::SceneUpdate {
@@ -429,6 +706,35 @@ fn resolve_event_outcome_description(event: &wrldbldr_domain::NarrativeEvent) ->
         .unwrap_or_default()
 }
 
+/// An interaction as one PC's action button. Only a cooldown or running
+/// out of uses comes with a reason; anything else unavailable is hidden.
+pub(super) async fn pc_interaction_to_data(
+    state: &WsState,
+    pc_interaction: PcInteraction,
+) -> InteractionData {
+    let unavailable_reason = match pc_interaction.availability {
+        wrldbldr_domain::InteractionAvailability::UsedUp
+        | wrldbldr_domain::InteractionAvailability::CoolingDown { .. } => {
+            pc_interaction.unavailable_reason()
+        }
+        _ => None,
+    };
+    let interaction = pc_interaction.interaction;
+    let (target_id, target_type, target_name) =
+        resolve_interaction_target(state, &interaction.target).await;
+    InteractionData {
+        id: interaction.id.to_string(),
+        name: interaction.name,
+        interaction_type: interaction_type_to_str(&interaction.interaction_type).to_string(),
+        target_name,
+        target_id,
+        target_type,
+        is_available: pc_interaction.availability.is_available(),
+        unavailable_reason,
+        uses_left: pc_interaction.uses_left,
+    }
+}
+
 fn interaction_type_to_str(interaction_type: &wrldbldr_domain::InteractionType) -> &'static str {
     match interaction_type {
         wrldbldr_domain::InteractionType::Dialogue => "dialogue",
@@ -0,0 +1,114 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::names::{name_generator_to_protocol, NameGeneratorError, NameScope};
+
+use wrldbldr_domain::NameGeneratorId;
+use wrldbldr_protocol::NameGeneratorRequest;
+
+pub(super) async fn handle_name_generator_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: NameGeneratorRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before managing its name generators",
+        ));
+    };
+    let names = &state.app.use_cases.names.manage;
+
+    let result = match request {
+        NameGeneratorRequest::ListNameGenerators => {
+            names.list(world_id).await.map(ResponseResult::success)
+        }
+
+        NameGeneratorRequest::CreateNameGenerator { data } => names
+            .create(world_id, data)
+            .await
+            .map(|g| ResponseResult::success(name_generator_to_protocol(&g))),
+
+        NameGeneratorRequest::UpdateNameGenerator { generator_id, data } => {
+            let generator_id = parse_generator_id(&generator_id, request_id)?;
+            names
+                .update(world_id, generator_id, data)
+                .await
+                .map(|g| ResponseResult::success(name_generator_to_protocol(&g)))
+        }
+
+        NameGeneratorRequest::DeleteNameGenerator { generator_id } => {
+            let generator_id = parse_generator_id(&generator_id, request_id)?;
+            names
+                .delete(world_id, generator_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+    };
+
+    Ok(result.unwrap_or_else(name_generator_error_response))
+}
+
+/// `GenerationRequest::GenerateNames`
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn generate_names(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    world_id: &str,
+    generator_id: Option<String>,
+    region_id: Option<String>,
+    faction: Option<String>,
+    count: u32,
+) -> Result<ResponseResult, ServerMessage> {
+    require_dm_for_request(conn_info, request_id)?;
+    let world_id = parse_world_id_for_request(world_id, request_id)?;
+    let scope = NameScope {
+        generator_id: generator_id
+            .map(|id| parse_generator_id(&id, request_id))
+            .transpose()?,
+        region_id: region_id
+            .map(|id| {
+                parse_id_for_request(&id, request_id, RegionId::from_uuid, "Invalid region ID")
+            })
+            .transpose()?,
+        faction: faction.filter(|f| !f.trim().is_empty()),
+    };
+
+    Ok(state
+        .app
+        .use_cases
+        .names
+        .manage
+        .generate(world_id, scope, count as usize)
+        .await
+        .map(ResponseResult::success)
+        .unwrap_or_else(name_generator_error_response))
+}
+
+fn parse_generator_id(id: &str, request_id: &str) -> Result<NameGeneratorId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        NameGeneratorId::from_uuid,
+        "Invalid name generator ID",
+    )
+}
+
+fn name_generator_error_response(e: NameGeneratorError) -> ResponseResult {
+    match e {
+        NameGeneratorError::NotFound
+        | NameGeneratorError::RegionNotFound
+        | NameGeneratorError::NoGenerator => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        NameGeneratorError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        NameGeneratorError::Repo(e) => {
+            ResponseResult::error(ErrorCode::InternalError, e.to_string())
+        }
+    }
+}
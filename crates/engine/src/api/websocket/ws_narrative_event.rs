@@ -12,7 +12,7 @@ pub(super) async fn handle_narrative_event_request(
     request: NarrativeEventRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        NarrativeEventRequest::ListNarrativeEvents { world_id } => {
+        NarrativeEventRequest::ListNarrativeEvents { world_id, tags } => {
             let world_id_typed = parse_world_id_for_request(&world_id, request_id)?;
             match state
                 .app
@@ -22,7 +22,19 @@ pub(super) async fn handle_narrative_event_request(
                 .list(world_id_typed)
                 .await
             {
-                Ok(events) => Ok(ResponseResult::success(json!(events))),
+                Ok(mut events) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        domain::EntityType::NarrativeEvent,
+                        &tags,
+                        &mut events,
+                        ws_tags::json_entry_id,
+                    )
+                    .await?;
+                    Ok(ResponseResult::success(json!(events)))
+                }
                 Err(e) => Ok(ResponseResult::error(
                     ErrorCode::InternalError,
                     e.to_string(),
@@ -111,6 +123,29 @@ pub(super) async fn handle_narrative_event_request(
                 )),
             }
         }
+        NarrativeEventRequest::DuplicateNarrativeEvent { event_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let event_id_typed = parse_narrative_event_id_for_request(&event_id, request_id)?;
+            match state
+                .app
+                .use_cases
+                .duplicate
+                .entity
+                .narrative_event(event_id_typed)
+                .await
+            {
+                Ok(event) => Ok(ResponseResult::success(
+                    crate::use_cases::narrative::narrative_event_to_json(&event),
+                )),
+                Err(crate::use_cases::duplicate::DuplicateError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "Event not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
         NarrativeEventRequest::SetNarrativeEventActive { event_id, active } => {
             require_dm_for_request(conn_info, request_id)?;
             let event_id_typed = parse_narrative_event_id_for_request(&event_id, request_id)?;
@@ -199,6 +234,7 @@ pub(super) async fn handle_narrative_event_request(
                             },
                         )
                         .await;
+                    ws_dialogue_tree::begin_event_beats(state, world_id, result.event_id).await;
 
                     Ok(ResponseResult::success(json!({
                         "event_id": result.event_id.to_string(),
@@ -302,6 +338,8 @@ pub(super) async fn handle_narrative_event_decision(
                     .connections
                     .broadcast_to_world(result.world_id, msg)
                     .await;
+                ws_dialogue_tree::begin_event_beats(state, result.world_id, narrative_event_id)
+                    .await;
             }
             None
         }
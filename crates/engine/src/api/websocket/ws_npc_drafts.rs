@@ -0,0 +1,132 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::npc_drafts::{ManageNpcDrafts, NpcDraftError};
+
+use wrldbldr_domain::NpcDraftId;
+use wrldbldr_protocol::types::CreatedEntityData;
+use wrldbldr_protocol::{EntityType, NpcDraftRequest};
+
+pub(super) async fn handle_npc_draft_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: NpcDraftRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // Drafts are the DM's to review, in the world the DM is in
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before promoting NPCs",
+        ));
+    };
+    let drafts = state.app.use_cases.npc_drafts.manage.clone();
+
+    let result = match request {
+        NpcDraftRequest::ListNpcDrafts => drafts.list(world_id).await.map(ResponseResult::success),
+
+        NpcDraftRequest::PromoteToNpc { source, name } => {
+            match drafts.promote(world_id, source, name).await {
+                Ok(draft) => {
+                    let data = drafts.to_protocol(&draft).await;
+                    spawn_generation(state, world_id, drafts, draft.id);
+                    Ok(ResponseResult::success(data))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        NpcDraftRequest::UpdateNpcDraft { draft_id, data } => {
+            let draft_id = parse_npc_draft_id(&draft_id, request_id)?;
+            match drafts.update(world_id, draft_id, data).await {
+                Ok(draft) => Ok(ResponseResult::success(drafts.to_protocol(&draft).await)),
+                Err(e) => Err(e),
+            }
+        }
+
+        NpcDraftRequest::RegenerateNpcDraft { draft_id } => {
+            let draft_id = parse_npc_draft_id(&draft_id, request_id)?;
+            match drafts.regenerate(world_id, draft_id).await {
+                Ok(draft) => {
+                    let data = drafts.to_protocol(&draft).await;
+                    spawn_generation(state, world_id, drafts, draft.id);
+                    Ok(ResponseResult::success(data))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        NpcDraftRequest::ApproveNpcDraft { draft_id } => {
+            let draft_id = parse_npc_draft_id(&draft_id, request_id)?;
+            match drafts.approve(world_id, draft_id).await {
+                Ok(approved) => {
+                    if let Some(crowd) = &approved.crowd {
+                        ws_crowds::announce_region_crowds(state, world_id, crowd.region_id).await;
+                    }
+                    Ok(ResponseResult::success(CreatedEntityData {
+                        entity_type: EntityType::Character,
+                        entity_id: approved.character.id.to_string(),
+                        name: approved.character.name.clone(),
+                    }))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        NpcDraftRequest::RejectNpcDraft { draft_id } => {
+            let draft_id = parse_npc_draft_id(&draft_id, request_id)?;
+            drafts
+                .reject(world_id, draft_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+    };
+
+    Ok(result.unwrap_or_else(npc_draft_error_response))
+}
+
+/// Generation takes a while; fill the draft in the background and send it
+/// to the world's DMs when done
+pub(super) fn spawn_generation(
+    state: &WsState,
+    world_id: WorldId,
+    drafts: Arc<ManageNpcDrafts>,
+    draft_id: NpcDraftId,
+) {
+    let connections = state.connections.clone();
+    tokio::spawn(async move {
+        match drafts.generate(draft_id).await {
+            Ok(draft) => {
+                let draft = drafts.to_protocol(&draft).await;
+                connections
+                    .broadcast_to_dms(world_id, ServerMessage::NpcDraftUpdated { draft })
+                    .await;
+            }
+            Err(e) => {
+                tracing::warn!(draft_id = %draft_id, error = %e, "Failed to generate NPC draft");
+            }
+        }
+    });
+}
+
+fn parse_npc_draft_id(id: &str, request_id: &str) -> Result<NpcDraftId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        NpcDraftId::from_uuid,
+        "Invalid NPC draft ID",
+    )
+}
+
+fn npc_draft_error_response(e: NpcDraftError) -> ResponseResult {
+    match e {
+        NpcDraftError::NotFound | NpcDraftError::SourceNotFound(_) => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        NpcDraftError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        NpcDraftError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
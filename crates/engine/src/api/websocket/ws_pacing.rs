@@ -0,0 +1,80 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::pacing::PacingError;
+
+use wrldbldr_domain::{PlayerCharacterId, WorldId};
+use wrldbldr_protocol::types::PacingTimerData;
+use wrldbldr_protocol::PacingRequest;
+
+pub(super) async fn handle_pacing_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: PacingRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before using the pacing timer",
+        ));
+    };
+    let timers = &state.app.use_cases.pacing.timers;
+
+    // Everyone sees the countdown; only the DM runs it
+    if !matches!(request, PacingRequest::GetPacingTimer) {
+        require_dm_for_request(conn_info, request_id)?;
+    }
+
+    let result = match request {
+        PacingRequest::GetPacingTimer => {
+            return Ok(ResponseResult::success(timers.get(world_id)));
+        }
+        PacingRequest::StartPacingTimer { data } => timers.start(world_id, data).map(Some),
+        PacingRequest::PausePacingTimer => timers.pause(world_id).map(Some),
+        PacingRequest::ResumePacingTimer => timers.resume(world_id).map(Some),
+        PacingRequest::ExtendPacingTimer { minutes } => timers.extend(world_id, minutes).map(Some),
+        PacingRequest::StopPacingTimer => timers.stop(world_id).map(|()| None),
+    };
+
+    Ok(match result {
+        Ok(timer) => {
+            broadcast_pacing_timer(state, world_id, timer.clone()).await;
+            ResponseResult::success(timer)
+        }
+        Err(e @ PacingError::NotRunning) => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        Err(e @ PacingError::Invalid(_)) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+    })
+}
+
+/// A player acted: start a turn timer over and show everyone
+pub(super) async fn restart_turn_timer(
+    state: &WsState,
+    world_id: WorldId,
+    pc_id: PlayerCharacterId,
+) {
+    if let Some(timer) = state
+        .app
+        .use_cases
+        .pacing
+        .timers
+        .player_acted(world_id, pc_id)
+    {
+        broadcast_pacing_timer(state, world_id, Some(timer)).await;
+    }
+}
+
+async fn broadcast_pacing_timer(
+    state: &WsState,
+    world_id: WorldId,
+    timer: Option<PacingTimerData>,
+) {
+    state
+        .connections
+        .broadcast_to_world(world_id, ServerMessage::PacingTimerChanged { timer })
+        .await;
+}
@@ -0,0 +1,113 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::parallel_scenes::{scenes_to_protocol, ParallelSceneError, PartySplit};
+
+use wrldbldr_domain::{ParallelSceneId, WorldId};
+use wrldbldr_protocol::ParallelSceneRequest;
+
+pub(super) async fn handle_parallel_scene_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: ParallelSceneRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before managing its scenes",
+        ));
+    };
+    let scenes = &state.app.use_cases.parallel_scenes.manage;
+
+    // Players can see which group they are in; only the DM splits the party
+    if !matches!(request, ParallelSceneRequest::ListParallelScenes) {
+        require_dm_for_request(conn_info, request_id)?;
+    }
+
+    let result = match request {
+        ParallelSceneRequest::ListParallelScenes => {
+            return Ok(match scenes.list(world_id).await {
+                Ok(split) => ResponseResult::success(scenes_to_protocol(&split, conn_info.is_dm())),
+                Err(e) => parallel_scene_error_response(e),
+            });
+        }
+
+        ParallelSceneRequest::CreateParallelScene { data } => scenes.create(world_id, data).await,
+
+        ParallelSceneRequest::UpdateParallelScene { scene_id, data } => {
+            let scene_id = parse_scene_id(&scene_id, request_id)?;
+            scenes.update(world_id, scene_id, data).await
+        }
+
+        ParallelSceneRequest::DeleteParallelScene { scene_id } => {
+            let scene_id = parse_scene_id(&scene_id, request_id)?;
+            scenes.delete(world_id, scene_id).await
+        }
+
+        ParallelSceneRequest::FocusParallelScene { scene_id } => {
+            let scene_id = scene_id
+                .map(|id| parse_scene_id(&id, request_id))
+                .transpose()?;
+            scenes.focus(world_id, scene_id).await
+        }
+
+        ParallelSceneRequest::MergeParallelScenes => scenes.merge(world_id).await,
+    };
+
+    Ok(match result {
+        Ok(split) => {
+            broadcast_split(state, world_id, &split).await;
+            ResponseResult::success(scenes_to_protocol(&split, true))
+        }
+        Err(e) => parallel_scene_error_response(e),
+    })
+}
+
+/// Reroute the world's scene traffic and tell everyone how the party is split
+pub(super) async fn broadcast_split(state: &WsState, world_id: WorldId, split: &PartySplit) {
+    state.connections.set_scene_routes(world_id, &split.scenes);
+    state
+        .connections
+        .broadcast_to_dms(
+            world_id,
+            ServerMessage::ParallelScenesChanged {
+                scenes: scenes_to_protocol(split, true),
+            },
+        )
+        .await;
+    state
+        .connections
+        .broadcast_to_players(
+            world_id,
+            ServerMessage::ParallelScenesChanged {
+                scenes: scenes_to_protocol(split, false),
+            },
+        )
+        .await;
+}
+
+fn parse_scene_id(id: &str, request_id: &str) -> Result<ParallelSceneId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        ParallelSceneId::from_uuid,
+        "Invalid scene ID",
+    )
+}
+
+fn parallel_scene_error_response(e: ParallelSceneError) -> ResponseResult {
+    match e {
+        ParallelSceneError::WorldNotFound
+        | ParallelSceneError::NotFound
+        | ParallelSceneError::PlayerCharacterNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        ParallelSceneError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        ParallelSceneError::Repo(e) => {
+            ResponseResult::error(ErrorCode::InternalError, e.to_string())
+        }
+    }
+}
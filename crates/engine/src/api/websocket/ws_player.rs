@@ -6,11 +6,11 @@ use wrldbldr_protocol::{ObservationRequest, PlayerCharacterRequest, Relationship
 pub(super) async fn handle_player_character_request(
     state: &WsState,
     request_id: &str,
-    _conn_info: &ConnectionInfo,
+    conn_info: &ConnectionInfo,
     request: PlayerCharacterRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        PlayerCharacterRequest::ListPlayerCharacters { world_id } => {
+        PlayerCharacterRequest::ListPlayerCharacters { world_id, tags } => {
             let world_id_typed = match parse_world_id_for_request(&world_id, request_id) {
                 Ok(id) => id,
                 Err(e) => return Err(e),
@@ -24,7 +24,17 @@ pub(super) async fn handle_player_character_request(
                 .list_in_world(world_id_typed)
                 .await
             {
-                Ok(pcs) => {
+                Ok(mut pcs) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        wrldbldr_domain::EntityType::PlayerCharacter,
+                        &tags,
+                        &mut pcs,
+                        |pc| pc.id.to_uuid(),
+                    )
+                    .await?;
                     let data: Vec<serde_json::Value> = pcs.into_iter().map(pc_to_json).collect();
                     Ok(ResponseResult::success(data))
                 }
@@ -433,6 +443,86 @@ pub(super) async fn handle_observation_request(
                 )),
             }
         }
+
+        ObservationRequest::ListKnownLocations { pc_id } => {
+            let pc_id_typed = parse_pc_id(&pc_id)?;
+            if !conn_info.is_dm() && !conn_info.controls_pc(pc_id_typed) {
+                return Ok(ResponseResult::error(
+                    ErrorCode::Unauthorized,
+                    "Cannot view another character's known locations",
+                ));
+            }
+
+            match state
+                .app
+                .use_cases
+                .management
+                .observation
+                .list_known_locations(pc_id_typed)
+                .await
+            {
+                Ok(known) => Ok(ResponseResult::success(serde_json::json!(known))),
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "Player character not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        ObservationRequest::RevealLocation {
+            pc_id,
+            location_id,
+            notes,
+        } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let pc_id_typed = parse_pc_id(&pc_id)?;
+            let location_id_typed = parse_location_id_for_request(&location_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .observation
+                .reveal_location(pc_id_typed, location_id_typed, notes)
+                .await
+            {
+                Ok(()) => Ok(ResponseResult::success_empty()),
+                Err(crate::use_cases::management::ManagementError::InvalidInput(msg)) => {
+                    Ok(ResponseResult::error(ErrorCode::BadRequest, &msg))
+                }
+                Err(crate::use_cases::management::ManagementError::NotFound) => Ok(
+                    ResponseResult::error(ErrorCode::NotFound, "Character or location not found"),
+                ),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
+
+        ObservationRequest::ForgetLocation { pc_id, location_id } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let pc_id_typed = parse_pc_id(&pc_id)?;
+            let location_id_typed = parse_location_id_for_request(&location_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .management
+                .observation
+                .forget_location(pc_id_typed, location_id_typed)
+                .await
+            {
+                Ok(()) => Ok(ResponseResult::success_empty()),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
     }
 }
 
@@ -445,9 +535,13 @@ fn pc_to_json(pc: wrldbldr_domain::PlayerCharacter) -> serde_json::Value {
         "description": pc.description,
         "sheet_data": pc.sheet_data,
         "current_location_id": pc.current_location_id.to_string(),
+        "current_region_id": pc.current_region_id.map(|id| id.to_string()),
         "starting_location_id": pc.starting_location_id.to_string(),
         "sprite_asset": pc.sprite_asset,
         "portrait_asset": pc.portrait_asset,
+        "is_alive": pc.is_alive,
+        "predecessor_id": pc.predecessor_id.map(|id| id.to_string()),
+        "successor_id": pc.successor_id.map(|id| id.to_string()),
         "created_at": pc.created_at.to_rfc3339(),
         "last_active_at": pc.last_active_at.to_rfc3339(),
     })
@@ -6,6 +6,7 @@ pub(super) async fn handle_player_action(
     action_type: String,
     target: Option<String>,
     dialogue: Option<String>,
+    choice_id: Option<String>,
 ) -> Option<ServerMessage> {
     // Get connection info
     let conn_info = match state.connections.get(connection_id).await {
@@ -23,6 +24,37 @@ pub(super) async fn handle_player_action(
         None => return Some(error_response("NO_PC", "Must have a PC to perform actions")),
     };
 
+    if let Some(paused) = ws_safety::reject_if_queue_paused(state, world_id).await {
+        return Some(paused);
+    }
+    if let Some(dead) = ws_mortality::reject_if_pc_dead(state, pc_id).await {
+        return Some(dead);
+    }
+    if let Some(throttled) = ws_spotlight::reject_if_throttled(state, world_id, pc_id).await {
+        return Some(throttled);
+    }
+
+    let beats = &state.app.use_cases.dialogue_trees.beats;
+    if action_type == "dialogue_choice" && beats.in_beat(pc_id) {
+        return ws_dialogue_tree::handle_beat_choice(state, world_id, pc_id, choice_id.as_deref())
+            .await;
+    }
+    // Typing instead of picking a scripted choice leaves the script; the
+    // NPC answers the words like any other conversation
+    if action_type == "custom" && target.is_none() {
+        if let Some(text) = dialogue.as_ref().filter(|text| !text.trim().is_empty()) {
+            if let Some(speaker_id) = beats.go_off_script(pc_id) {
+                return ws_conversation::handle_off_script(
+                    state,
+                    connection_id,
+                    speaker_id,
+                    text.clone(),
+                )
+                .await;
+            }
+        }
+    }
+
     let target_npc = if action_type == "talk" {
         match target.as_ref() {
             Some(target_str) => match parse_character_id(target_str) {
@@ -35,6 +67,14 @@ pub(super) async fn handle_player_action(
         None
     };
 
+    if let Some(npc_id) = target_npc {
+        if ws_dialogue_tree::begin_npc_beat(state, world_id, pc_id, npc_id).await {
+            ws_spotlight::record_spotlight_action(state, world_id, pc_id).await;
+            ws_pacing::restart_turn_timer(state, world_id, pc_id).await;
+            return None;
+        }
+    }
+
     let processed = match state
         .app
         .use_cases
@@ -93,6 +133,86 @@ pub(super) async fn handle_player_action(
         .connections
         .broadcast_to_dms(world_id, queue_msg)
         .await;
+    ws_spotlight::record_spotlight_action(state, world_id, pc_id).await;
+    ws_pacing::restart_turn_timer(state, world_id, pc_id).await;
 
     Some(ack)
 }
+
+pub(super) async fn handle_retract_action(
+    state: &WsState,
+    connection_id: Uuid,
+    action_id: String,
+) -> Option<ServerMessage> {
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+
+    let world_id = match conn_info.world_id {
+        Some(id) => id,
+        None => return Some(error_response("NOT_IN_WORLD", "Must join a world first")),
+    };
+
+    let action_uuid = match parse_id(&action_id, |u| u, "Invalid action ID") {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+
+    let retracted = match state
+        .app
+        .use_cases
+        .player_action
+        .retract
+        .execute(world_id, action_uuid, &conn_info.user_id, conn_info.is_dm())
+        .await
+    {
+        Ok(result) => result,
+        Err(crate::use_cases::player_action::RetractError::NotFound) => {
+            return Some(error_response("NOT_FOUND", "Action not found"))
+        }
+        Err(crate::use_cases::player_action::RetractError::NotOwner) => {
+            return Some(error_response(
+                "UNAUTHORIZED",
+                "Only the player who sent an action can retract it",
+            ))
+        }
+        Err(crate::use_cases::player_action::RetractError::AlreadyProcessed) => {
+            return Some(error_response(
+                "ACTION_ALREADY_PROCESSED",
+                "The NPC is already answering this action",
+            ))
+        }
+        Err(crate::use_cases::player_action::RetractError::Merged) => {
+            return Some(error_response(
+                "ACTION_ALREADY_PROCESSED",
+                "The NPC is answering this together with other players' lines",
+            ))
+        }
+        Err(crate::use_cases::player_action::RetractError::Queue(e)) => {
+            tracing::error!(error = %e, "Failed to retract player action");
+            return Some(error_response("QUEUE_ERROR", &e));
+        }
+    };
+
+    tracing::info!(
+        connection_id = %connection_id,
+        action_id = %retracted.action_id,
+        "Player action retracted"
+    );
+
+    let msg = ServerMessage::ActionRetracted {
+        action_id: retracted.action_id.to_string(),
+    };
+    state
+        .connections
+        .broadcast_to_dms(retracted.world_id, msg.clone())
+        .await;
+
+    // DMs already got the broadcast
+    if conn_info.is_dm() {
+        None
+    } else {
+        Some(msg)
+    }
+}
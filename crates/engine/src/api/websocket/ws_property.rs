@@ -0,0 +1,95 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::property::PropertyError;
+
+use wrldbldr_domain::PropertyId;
+use wrldbldr_protocol::PropertyRequest;
+
+pub(super) async fn handle_property_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: PropertyRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    require_dm_for_request(conn_info, request_id)?;
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before managing properties",
+        ));
+    };
+    let properties = &state.app.use_cases.property.manage;
+
+    let result = match request {
+        PropertyRequest::ListPcProperties { pc_id } => {
+            let pc_id = parse_owner_id(&pc_id, request_id)?;
+            properties
+                .list_for_pc(world_id, pc_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        PropertyRequest::CreateProperty { pc_id, data } => {
+            let pc_id = parse_owner_id(&pc_id, request_id)?;
+            properties
+                .create(world_id, pc_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        PropertyRequest::UpdateProperty { property_id, data } => {
+            let property_id = parse_property_id(&property_id, request_id)?;
+            properties
+                .update(world_id, property_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        PropertyRequest::DeleteProperty { property_id } => {
+            let property_id = parse_property_id(&property_id, request_id)?;
+            properties
+                .delete(world_id, property_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+
+        PropertyRequest::PayUpkeep { property_id } => {
+            let property_id = parse_property_id(&property_id, request_id)?;
+            properties
+                .pay_upkeep(world_id, property_id)
+                .await
+                .map(ResponseResult::success)
+        }
+    };
+
+    Ok(result.unwrap_or_else(property_error_response))
+}
+
+fn parse_owner_id(id: &str, request_id: &str) -> Result<PlayerCharacterId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        PlayerCharacterId::from_uuid,
+        "Invalid PC ID",
+    )
+}
+
+fn parse_property_id(id: &str, request_id: &str) -> Result<PropertyId, ServerMessage> {
+    parse_id_for_request(id, request_id, PropertyId::from_uuid, "Invalid property ID")
+}
+
+fn property_error_response(e: PropertyError) -> ResponseResult {
+    match e {
+        PropertyError::PropertyNotFound
+        | PropertyError::PlayerCharacterNotFound
+        | PropertyError::LocationNotFound
+        | PropertyError::RegionNotFound
+        | PropertyError::StaffNotFound
+        | PropertyError::WorldNotFound => ResponseResult::error(ErrorCode::NotFound, e.to_string()),
+        PropertyError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        PropertyError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
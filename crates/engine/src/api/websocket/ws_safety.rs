@@ -0,0 +1,124 @@
+use super::*;
+
+use crate::use_cases::safety::{signal_level_from_protocol, SafetyError};
+use wrldbldr_protocol::types::SafetySignalLevelData;
+
+/// Handle an anonymous safety signal from anyone at the table.
+///
+/// DMs are told the level and what the engine did about it, but never who
+/// raised it.
+pub(super) async fn handle_safety_signal(
+    state: &WsState,
+    connection_id: Uuid,
+    level: SafetySignalLevelData,
+) -> Option<ServerMessage> {
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+
+    let world_id = match conn_info.world_id {
+        Some(id) => id,
+        None => return Some(error_response("NOT_IN_WORLD", "Must join a world first")),
+    };
+
+    let outcome = match state
+        .app
+        .use_cases
+        .safety
+        .signals
+        .raise(world_id, signal_level_from_protocol(level))
+        .await
+    {
+        Ok(outcome) => outcome,
+        Err(SafetyError::WorldNotFound) => {
+            return Some(error_response("NOT_FOUND", "World not found"))
+        }
+        Err(e) => return Some(error_response("SAFETY_ERROR", &e.to_string())),
+    };
+
+    state
+        .connections
+        .broadcast_to_dms(
+            world_id,
+            ServerMessage::SafetySignalRaised {
+                world_id: world_id.to_string(),
+                level,
+                queue_paused: outcome.queue_paused,
+                rejected_request_ids: outcome.rejected_request_ids,
+            },
+        )
+        .await;
+
+    if outcome.queue_paused {
+        state
+            .connections
+            .broadcast_to_world(
+                world_id,
+                ServerMessage::ActionQueuePaused {
+                    world_id: world_id.to_string(),
+                    paused: true,
+                },
+            )
+            .await;
+    }
+
+    None
+}
+
+pub(super) async fn handle_set_action_queue_paused(
+    state: &WsState,
+    connection_id: Uuid,
+    world_id: String,
+    paused: bool,
+) -> Option<ServerMessage> {
+    let conn_info = match state.connections.get(connection_id).await {
+        Some(info) => info,
+        None => return Some(error_response("NOT_CONNECTED", "Connection not found")),
+    };
+
+    if let Err(e) = require_dm(&conn_info) {
+        return Some(e);
+    }
+
+    let world_id_typed = match parse_world_id(&world_id) {
+        Ok(id) => id,
+        Err(e) => return Some(e),
+    };
+
+    state
+        .app
+        .use_cases
+        .safety
+        .signals
+        .set_paused(world_id_typed, paused)
+        .await;
+
+    let msg = ServerMessage::ActionQueuePaused {
+        world_id: world_id_typed.to_string(),
+        paused,
+    };
+    state
+        .connections
+        .broadcast_to_world(world_id_typed, msg)
+        .await;
+
+    tracing::info!(world_id = %world_id_typed, paused = paused, "Action queue pause state changed");
+    None
+}
+
+/// Error response for player actions submitted while the world's action queue
+/// is paused.
+pub(super) async fn reject_if_queue_paused(
+    state: &WsState,
+    world_id: WorldId,
+) -> Option<ServerMessage> {
+    if state.app.use_cases.safety.signals.is_paused(world_id).await {
+        Some(error_response(
+            "QUEUE_PAUSED",
+            "Play is paused by the DM. Please wait before acting.",
+        ))
+    } else {
+        None
+    }
+}
@@ -1,6 +1,8 @@
 use super::*;
 
 use crate::api::connections::ConnectionInfo;
+use crate::use_cases::interactions::InteractionError;
+use crate::use_cases::management::InteractionLimits;
 use serde_json::json;
 use wrldbldr_domain::{self as domain, InteractionTarget, InteractionType};
 use wrldbldr_protocol::{ActRequest, ErrorCode, InteractionRequest, ResponseResult, SceneRequest};
@@ -12,7 +14,7 @@ pub(super) async fn handle_act_request(
     request: ActRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        ActRequest::ListActs { world_id } => {
+        ActRequest::ListActs { world_id, tags } => {
             let world_id_typed = parse_world_id_for_request(&world_id, request_id)?;
             match state
                 .app
@@ -22,7 +24,17 @@ pub(super) async fn handle_act_request(
                 .list_in_world(world_id_typed)
                 .await
             {
-                Ok(acts) => {
+                Ok(mut acts) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        domain::EntityType::Act,
+                        &tags,
+                        &mut acts,
+                        |a| a.id.to_uuid(),
+                    )
+                    .await?;
                     let data: Vec<serde_json::Value> =
                         acts.iter().map(act_to_json).collect();
                     Ok(ResponseResult::success(json!(data)))
@@ -64,7 +76,7 @@ pub(super) async fn handle_scene_request(
     request: SceneRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        SceneRequest::ListScenes { act_id } => {
+        SceneRequest::ListScenes { act_id, tags } => {
             let act_id_typed = parse_act_id_for_request(&act_id, request_id)?;
             match state
                 .app
@@ -74,7 +86,17 @@ pub(super) async fn handle_scene_request(
                 .list_for_act(act_id_typed)
                 .await
             {
-                Ok(scenes) => {
+                Ok(mut scenes) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        domain::EntityType::Scene,
+                        &tags,
+                        &mut scenes,
+                        |s| s.id.to_uuid(),
+                    )
+                    .await?;
                     let data: Vec<serde_json::Value> =
                         scenes.iter().map(scene_to_json).collect();
                     Ok(ResponseResult::success(json!(data)))
@@ -190,7 +212,7 @@ pub(super) async fn handle_interaction_request(
     request: InteractionRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        InteractionRequest::ListInteractions { scene_id } => {
+        InteractionRequest::ListInteractions { scene_id, tags } => {
             let scene_id_typed = parse_scene_id_for_request(&scene_id, request_id)?;
             match state
                 .app
@@ -200,7 +222,17 @@ pub(super) async fn handle_interaction_request(
                 .list_for_scene(scene_id_typed)
                 .await
             {
-                Ok(interactions) => {
+                Ok(mut interactions) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        domain::EntityType::Interaction,
+                        &tags,
+                        &mut interactions,
+                        |i| i.id.to_uuid(),
+                    )
+                    .await?;
                     let data: Vec<serde_json::Value> =
                         interactions.iter().map(interaction_to_json).collect();
                     Ok(ResponseResult::success(json!(data)))
@@ -235,6 +267,45 @@ pub(super) async fn handle_interaction_request(
                 )),
             }
         }
+        InteractionRequest::ListAvailableInteractions { pc_id } => {
+            let pc_id_typed = parse_id_for_request(
+                &pc_id,
+                request_id,
+                wrldbldr_domain::PlayerCharacterId::from_uuid,
+                "Invalid PC ID",
+            )?;
+            if !conn_info.is_dm() && !conn_info.controls_pc(pc_id_typed) {
+                return Ok(ResponseResult::error(
+                    ErrorCode::Unauthorized,
+                    "Cannot view another character's interactions",
+                ));
+            }
+
+            match state
+                .app
+                .use_cases
+                .interactions
+                .library
+                .list_for_pc(pc_id_typed)
+                .await
+            {
+                Ok(interactions) => {
+                    let mut data = Vec::with_capacity(interactions.len());
+                    for interaction in interactions {
+                        data.push(ws_movement::pc_interaction_to_data(state, interaction).await);
+                    }
+                    Ok(ResponseResult::success(json!(data)))
+                }
+                Err(InteractionError::PcNotFound) => Ok(ResponseResult::error(
+                    ErrorCode::NotFound,
+                    "Player character not found",
+                )),
+                Err(e) => Ok(ResponseResult::error(
+                    ErrorCode::InternalError,
+                    e.to_string(),
+                )),
+            }
+        }
         InteractionRequest::CreateInteraction { scene_id, data } => {
             require_dm_for_request(conn_info, request_id)?;
             let scene_id_typed = parse_scene_id_for_request(&scene_id, request_id)?;
@@ -249,6 +320,13 @@ pub(super) async fn handle_interaction_request(
                     data.description,
                     data.trigger,
                     data.available,
+                    InteractionLimits {
+                        cooldown_minutes: data.cooldown_minutes,
+                        max_uses: data.max_uses,
+                        available_times: Some(
+                            data.available_times.into_iter().map(Into::into).collect(),
+                        ),
+                    },
                 )
                 .await
             {
@@ -282,6 +360,13 @@ pub(super) async fn handle_interaction_request(
                     data.description,
                     data.trigger,
                     data.available,
+                    InteractionLimits {
+                        cooldown_minutes: data.cooldown_minutes,
+                        max_uses: data.max_uses,
+                        available_times: data
+                            .available_times
+                            .map(|times| times.into_iter().map(Into::into).collect()),
+                    },
                 )
                 .await
             {
@@ -334,7 +419,14 @@ pub(super) async fn handle_interaction_request(
                 .use_cases
                 .management
                 .interaction
-                .update(interaction_id_typed, None, None, None, Some(available))
+                .update(
+                    interaction_id_typed,
+                    None,
+                    None,
+                    None,
+                    Some(available),
+                    InteractionLimits::default(),
+                )
                 .await
             {
                 Ok(interaction) => Ok(ResponseResult::success(interaction_to_json(
@@ -403,6 +495,13 @@ fn interaction_to_json(interaction: &domain::InteractionTemplate) -> serde_json:
         "prompt_hints": if interaction.prompt_hints.is_empty() { None } else { Some(interaction.prompt_hints.clone()) },
         "conditions": conditions,
         "order": interaction.order,
+        "cooldown_minutes": interaction.cooldown_minutes,
+        "max_uses": interaction.max_uses,
+        "available_times": interaction
+            .available_times
+            .iter()
+            .map(|time| wrldbldr_protocol::types::TimeOfDayData::from(*time))
+            .collect::<Vec<_>>(),
     })
 }
 
@@ -0,0 +1,55 @@
+use super::*;
+
+use crate::infrastructure::ports::ScriptEvent;
+
+use wrldbldr_domain::ScriptHook;
+
+/// Run the world's scripts on `hook` and send their notices to DMs.
+///
+/// Scripts never fail the action that fired the hook; their errors are
+/// reported to DMs as error notices.
+pub(super) async fn fire_script_hook(
+    state: &WsState,
+    world_id: WorldId,
+    hook: ScriptHook,
+    pc_id: Option<PlayerCharacterId>,
+    data: serde_json::Value,
+) {
+    let notices = state
+        .app
+        .use_cases
+        .scripts
+        .hooks
+        .fire(ScriptEvent {
+            hook,
+            world_id,
+            pc_id,
+            data,
+        })
+        .await;
+
+    for notice in notices {
+        state
+            .connections
+            .broadcast_to_dms(
+                world_id,
+                ServerMessage::ScriptNotice {
+                    world_id: world_id.to_string(),
+                    script: notice.script,
+                    message: notice.message,
+                    is_error: notice.is_error,
+                },
+            )
+            .await;
+    }
+}
+
+/// Run the world's time-advance scripts; `data` is what players were sent.
+pub(super) async fn fire_time_advance_hook(
+    state: &WsState,
+    world_id: WorldId,
+    data: &wrldbldr_protocol::types::TimeAdvanceData,
+) {
+    let data = serde_json::to_value(data).unwrap_or_default();
+    fire_script_hook(state, world_id, ScriptHook::TimeAdvance, None, data).await;
+}
@@ -0,0 +1,150 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::secrets::SecretError;
+
+use wrldbldr_domain::PcSecretId;
+use wrldbldr_protocol::SecretRequest;
+
+pub(super) async fn handle_secret_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: SecretRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before reading secrets",
+        ));
+    };
+    let secrets = &state.app.use_cases.secret.manage;
+
+    // A player may read their own PC's secrets; everything else is the DM's
+    if !matches!(request, SecretRequest::ListSecrets { .. }) {
+        require_dm_for_request(conn_info, request_id)?;
+    }
+
+    let result = match request {
+        SecretRequest::ListSecrets { pc_id } => {
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            if !conn_info.is_dm() {
+                match secrets.get_pc(world_id, pc_id).await {
+                    Ok(pc) if pc.user_id == conn_info.user_id => {}
+                    Ok(_) => {
+                        return Ok(ResponseResult::error(
+                            ErrorCode::Forbidden,
+                            "Only the DM or the character's player can read these secrets",
+                        ))
+                    }
+                    Err(e) => return Ok(secret_error_response(e)),
+                }
+            }
+            secrets
+                .list_for_pc(world_id, pc_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        SecretRequest::CreateSecret { pc_id, data } => {
+            let pc_id = parse_pc_id(&pc_id, request_id)?;
+            match secrets.create(world_id, pc_id, data).await {
+                Ok(secret) => {
+                    tell_player(state, pc_id, secret.clone()).await;
+                    Ok(ResponseResult::success(secret))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        SecretRequest::UpdateSecret { secret_id, data } => {
+            let secret_id = parse_secret_id(&secret_id, request_id)?;
+            match secrets.update(world_id, secret_id, data).await {
+                Ok(secret) => {
+                    let pc_id = parse_pc_id(&secret.pc_id, request_id)?;
+                    tell_player(state, pc_id, secret.clone()).await;
+                    Ok(ResponseResult::success(secret))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        SecretRequest::DeleteSecret { secret_id } => {
+            let secret_id = parse_secret_id(&secret_id, request_id)?;
+            match secrets.delete(world_id, secret_id).await {
+                Ok(pc_id) => {
+                    let msg = ServerMessage::PcSecretRemoved {
+                        pc_id: pc_id.to_string(),
+                        secret_id: secret_id.to_string(),
+                    };
+                    state.connections.send_to_pc_controllers(pc_id, msg).await;
+                    Ok(ResponseResult::success_empty())
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        SecretRequest::RevealSecret { secret_id } => {
+            let secret_id = parse_secret_id(&secret_id, request_id)?;
+            match secrets.reveal(world_id, secret_id).await {
+                Ok(revealed) => {
+                    let msg = ServerMessage::PcSecretRevealed {
+                        world_id: world_id.to_string(),
+                        pc_name: revealed.pc_name,
+                        secret: revealed.secret.clone(),
+                    };
+                    state.connections.broadcast_to_world(world_id, msg).await;
+                    for update in revealed.dispositions {
+                        let msg = ServerMessage::NpcDispositionChanged {
+                            npc_id: update.npc_id.to_string(),
+                            npc_name: update.npc_name,
+                            pc_id: update.pc_id.to_string(),
+                            disposition: update.disposition.to_string(),
+                            relationship: update.relationship.to_string(),
+                            reason: update.reason,
+                        };
+                        state.connections.broadcast_to_dms(world_id, msg).await;
+                    }
+                    Ok(ResponseResult::success(revealed.secret))
+                }
+                Err(e) => Err(e),
+            }
+        }
+    };
+
+    Ok(result.unwrap_or_else(secret_error_response))
+}
+
+/// Send a created or changed secret to its PC's player, and nobody else
+async fn tell_player(
+    state: &WsState,
+    pc_id: PlayerCharacterId,
+    secret: wrldbldr_protocol::types::PcSecretData,
+) {
+    let msg = ServerMessage::PcSecretUpdated { secret };
+    state.connections.send_to_pc_controllers(pc_id, msg).await;
+}
+
+fn parse_pc_id(id: &str, request_id: &str) -> Result<PlayerCharacterId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        PlayerCharacterId::from_uuid,
+        "Invalid PC ID",
+    )
+}
+
+fn parse_secret_id(id: &str, request_id: &str) -> Result<PcSecretId, ServerMessage> {
+    parse_id_for_request(id, request_id, PcSecretId::from_uuid, "Invalid secret ID")
+}
+
+fn secret_error_response(e: SecretError) -> ResponseResult {
+    match e {
+        SecretError::SecretNotFound
+        | SecretError::PlayerCharacterNotFound
+        | SecretError::NpcNotFound => ResponseResult::error(ErrorCode::NotFound, e.to_string()),
+        SecretError::Conflict(_) => ResponseResult::error(ErrorCode::Conflict, e.to_string()),
+        SecretError::Invalid(_) => ResponseResult::error(ErrorCode::ValidationError, e.to_string()),
+        SecretError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -56,6 +56,25 @@ pub(super) async fn handle_join_world(
             .await;
     }
 
+    if matches!(role, ProtoWorldRole::Dm) {
+        super::ws_health::send_dependency_status(state, connection_id).await;
+    }
+
+    // A restarted engine learns how the party is split from the world
+    match state
+        .app
+        .use_cases
+        .parallel_scenes
+        .manage
+        .list(world_id_typed)
+        .await
+    {
+        Ok(split) => state
+            .connections
+            .set_scene_routes(world_id_typed, &split.scenes),
+        Err(e) => tracing::warn!(error = %e, "Failed to load parallel scenes"),
+    }
+
     Some(ServerMessage::WorldJoined {
         world_id,
         snapshot: join_result.snapshot,
@@ -84,3 +103,96 @@ pub(super) async fn handle_leave_world(
     state.connections.leave_world(connection_id).await;
     None
 }
+
+pub(super) async fn handle_control_pc(
+    state: &WsState,
+    connection_id: Uuid,
+    pc_id: Uuid,
+) -> Option<ServerMessage> {
+    let ctx = crate::use_cases::session::ControlPcContext {
+        connections: &state.connections,
+    };
+    let result = state
+        .app
+        .use_cases
+        .session
+        .control_pc
+        .execute(&ctx, connection_id, PlayerCharacterId::from_uuid(pc_id))
+        .await;
+    controlled_pcs_response(result)
+}
+
+pub(super) async fn handle_switch_pc(
+    state: &WsState,
+    connection_id: Uuid,
+    pc_id: Uuid,
+) -> Option<ServerMessage> {
+    let ctx = crate::use_cases::session::ControlPcContext {
+        connections: &state.connections,
+    };
+    let result = state
+        .app
+        .use_cases
+        .session
+        .control_pc
+        .switch(&ctx, connection_id, PlayerCharacterId::from_uuid(pc_id))
+        .await;
+    controlled_pcs_response(result)
+}
+
+fn controlled_pcs_response(
+    result: Result<
+        crate::use_cases::session::ControlledPcs,
+        crate::use_cases::session::ControlPcError,
+    >,
+) -> Option<ServerMessage> {
+    use crate::use_cases::session::ControlPcError;
+
+    match result {
+        Ok(controlled) => Some(ServerMessage::ControlledPcsChanged {
+            active_pc_id: controlled.active_pc_id.to_uuid(),
+            pcs: controlled.pcs,
+        }),
+        Err(ControlPcError::NotConnected) => {
+            Some(error_response("NOT_CONNECTED", "Connection not found"))
+        }
+        Err(ControlPcError::PcNotFound) => {
+            Some(error_response("NOT_FOUND", "Player character not found"))
+        }
+        Err(ControlPcError::Unauthorized) => Some(error_response(
+            "UNAUTHORIZED",
+            "Cannot control that player character",
+        )),
+        Err(ControlPcError::Repo(e)) => {
+            tracing::error!(error = %e, "Failed to change controlled PCs");
+            Some(error_response(
+                "INTERNAL_ERROR",
+                "Failed to change controlled PCs",
+            ))
+        }
+    }
+}
+
+pub(super) async fn handle_set_locale(
+    state: &WsState,
+    connection_id: Uuid,
+    locale: String,
+) -> Option<ServerMessage> {
+    let localizer = i18n::localizer();
+    let resolved = localizer.resolve_locale(&locale);
+    tracing::debug!(
+        connection_id = %connection_id,
+        requested = %locale,
+        resolved = %resolved,
+        "Connection locale set"
+    );
+    state
+        .connections
+        .set_locale(connection_id, resolved.clone())
+        .await;
+
+    Some(ServerMessage::LocaleChanged {
+        locale: resolved,
+        supported_locales: localizer.supported_locales(),
+    })
+}
@@ -0,0 +1,150 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::simulation::{simulation_to_data, SimulationError};
+
+use wrldbldr_domain::WorldSimulationId;
+use wrldbldr_protocol::SimulationRequest;
+
+pub(super) async fn handle_simulation_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: SimulationRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before reviewing simulations",
+        ));
+    };
+    require_dm_for_request(conn_info, request_id)?;
+    let simulations = &state.app.use_cases.simulation.simulate;
+
+    let result = match request {
+        SimulationRequest::ListSimulations => {
+            let pending: Vec<_> = simulations
+                .list(world_id)
+                .await
+                .iter()
+                .map(simulation_to_data)
+                .collect();
+            Ok(ResponseResult::success(pending))
+        }
+
+        SimulationRequest::UpdateSimulation {
+            simulation_id,
+            changes,
+        } => {
+            let simulation_id = parse_simulation_id(&simulation_id, request_id)?;
+            match simulations.update(world_id, simulation_id, changes).await {
+                Ok(simulation) => {
+                    let data = simulation_to_data(&simulation);
+                    let msg = ServerMessage::WorldSimulationProposed {
+                        simulation: data.clone(),
+                    };
+                    state.connections.broadcast_to_dms(world_id, msg).await;
+                    Ok(ResponseResult::success(data))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        SimulationRequest::AcceptSimulation { simulation_id } => {
+            let simulation_id = parse_simulation_id(&simulation_id, request_id)?;
+            match simulations.accept(world_id, simulation_id).await {
+                Ok(accepted) => {
+                    tracing::info!(
+                        world_id = %world_id,
+                        changes = accepted.simulation.changes.len(),
+                        "DM accepted world simulation"
+                    );
+                    for clock in &accepted.clocks {
+                        ws_clock::broadcast_clock_update(state, clock).await;
+                    }
+                    broadcast_resolved(state, world_id, simulation_id, true).await;
+                    Ok(ResponseResult::success(simulation_to_data(
+                        &accepted.simulation,
+                    )))
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        SimulationRequest::DiscardSimulation { simulation_id } => {
+            let simulation_id = parse_simulation_id(&simulation_id, request_id)?;
+            match simulations.discard(world_id, simulation_id).await {
+                Ok(()) => {
+                    broadcast_resolved(state, world_id, simulation_id, false).await;
+                    Ok(ResponseResult::success_empty())
+                }
+                Err(e) => Err(e),
+            }
+        }
+    };
+
+    Ok(result.unwrap_or_else(simulation_error_response))
+}
+
+/// Simulate what the world got up to during a time skip of a day or more,
+/// and hand the changelog to the DMs. It reads most of the world, so it runs
+/// off the request that advanced time.
+pub(super) fn simulate_after_time_advance(
+    state: &WsState,
+    world_id: WorldId,
+    minutes_advanced: u32,
+) {
+    if wrldbldr_domain::simulated_days(minutes_advanced).is_none() {
+        return;
+    }
+    let simulations = state.app.use_cases.simulation.simulate.clone();
+    let connections = state.connections.clone();
+    tokio::spawn(async move {
+        match simulations.simulate(world_id, minutes_advanced).await {
+            Ok(Some(simulation)) => {
+                let msg = ServerMessage::WorldSimulationProposed {
+                    simulation: simulation_to_data(&simulation),
+                };
+                connections.broadcast_to_dms(world_id, msg).await;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(world_id = %world_id, error = %e, "Failed to simulate the world");
+            }
+        }
+    });
+}
+
+async fn broadcast_resolved(
+    state: &WsState,
+    world_id: WorldId,
+    simulation_id: WorldSimulationId,
+    accepted: bool,
+) {
+    let msg = ServerMessage::WorldSimulationResolved {
+        simulation_id: simulation_id.to_string(),
+        accepted,
+    };
+    state.connections.broadcast_to_dms(world_id, msg).await;
+}
+
+fn parse_simulation_id(id: &str, request_id: &str) -> Result<WorldSimulationId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        WorldSimulationId::from_uuid,
+        "Invalid simulation ID",
+    )
+}
+
+fn simulation_error_response(e: SimulationError) -> ResponseResult {
+    match e {
+        SimulationError::NotFound | SimulationError::WorldNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        SimulationError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        SimulationError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
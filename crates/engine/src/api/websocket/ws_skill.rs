@@ -12,7 +12,7 @@ pub(super) async fn handle_skill_request(
     request: SkillRequest,
 ) -> Result<ResponseResult, ServerMessage> {
     match request {
-        SkillRequest::ListSkills { world_id } => {
+        SkillRequest::ListSkills { world_id, tags } => {
             let world_id_typed = parse_world_id_for_request(&world_id, request_id)?;
             match state
                 .app
@@ -22,7 +22,17 @@ pub(super) async fn handle_skill_request(
                 .list_in_world(world_id_typed)
                 .await
             {
-                Ok(skills) => {
+                Ok(mut skills) => {
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        domain::EntityType::Skill,
+                        &tags,
+                        &mut skills,
+                        |s| s.id.to_uuid(),
+                    )
+                    .await?;
                     let data: Vec<serde_json::Value> =
                         skills.iter().map(skill_to_json).collect();
                     Ok(ResponseResult::success(json!(data)))
@@ -0,0 +1,146 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::spotlight::{spotlight_for_players, SpotlightError};
+
+use wrldbldr_domain::{PlayerCharacterId, WorldId};
+use wrldbldr_protocol::types::SpotlightData;
+use wrldbldr_protocol::SpotlightRequest;
+
+pub(super) async fn handle_spotlight_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: SpotlightRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    let Some(world_id) = conn_info.world_id else {
+        return Ok(ResponseResult::error(
+            ErrorCode::BadRequest,
+            "Join a world before using the spotlight",
+        ));
+    };
+    let spotlight = &state.app.use_cases.spotlight.manage;
+
+    // Players can see who has the spotlight; only the DM moves it
+    if !matches!(request, SpotlightRequest::GetSpotlight) {
+        require_dm_for_request(conn_info, request_id)?;
+    }
+
+    let result = match request {
+        SpotlightRequest::GetSpotlight => {
+            return Ok(match spotlight.get(world_id).await {
+                Ok(data) if conn_info.is_dm() => ResponseResult::success(data),
+                Ok(data) => ResponseResult::success(spotlight_for_players(&data)),
+                Err(e) => spotlight_error_response(e),
+            });
+        }
+
+        SpotlightRequest::UpdateSpotlightSettings { data } => {
+            spotlight.update_settings(world_id, data).await
+        }
+
+        SpotlightRequest::RotateSpotlight => spotlight.rotate(world_id).await,
+
+        SpotlightRequest::SetSpotlight { pc_id } => {
+            let pc_id = pc_id.map(|id| parse_pc_id(&id, request_id)).transpose()?;
+            spotlight.set_focus(world_id, pc_id).await
+        }
+
+        SpotlightRequest::ResetSpotlight => spotlight.reset(world_id).await,
+    };
+
+    Ok(match result {
+        Ok(data) => {
+            broadcast_spotlight(state, world_id, &data).await;
+            ResponseResult::success(data)
+        }
+        Err(e) => spotlight_error_response(e),
+    })
+}
+
+/// Refuse a player's action while the spotlight throttles their PC
+pub(super) async fn reject_if_throttled(
+    state: &WsState,
+    world_id: WorldId,
+    pc_id: PlayerCharacterId,
+) -> Option<ServerMessage> {
+    match state
+        .app
+        .use_cases
+        .spotlight
+        .manage
+        .check_action(world_id, pc_id)
+        .await
+    {
+        Ok(()) => None,
+        Err(e @ SpotlightError::Throttled(_)) => {
+            Some(error_response("SPOTLIGHT_THROTTLED", &e.to_string()))
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to check the spotlight");
+            None
+        }
+    }
+}
+
+/// Record a player's action and show everyone where the spotlight is
+pub(super) async fn record_spotlight_action(
+    state: &WsState,
+    world_id: WorldId,
+    pc_id: PlayerCharacterId,
+) {
+    match state
+        .app
+        .use_cases
+        .spotlight
+        .manage
+        .record_action(world_id, pc_id)
+        .await
+    {
+        Ok(Some(data)) => broadcast_spotlight(state, world_id, &data).await,
+        Ok(None) => {}
+        Err(e) => tracing::warn!(error = %e, "Failed to record action for the spotlight"),
+    }
+}
+
+async fn broadcast_spotlight(state: &WsState, world_id: WorldId, data: &SpotlightData) {
+    state
+        .connections
+        .broadcast_to_dms(
+            world_id,
+            ServerMessage::SpotlightChanged {
+                spotlight: data.clone(),
+            },
+        )
+        .await;
+    state
+        .connections
+        .broadcast_to_players(
+            world_id,
+            ServerMessage::SpotlightChanged {
+                spotlight: spotlight_for_players(data),
+            },
+        )
+        .await;
+}
+
+fn parse_pc_id(id: &str, request_id: &str) -> Result<PlayerCharacterId, ServerMessage> {
+    parse_id_for_request(
+        id,
+        request_id,
+        PlayerCharacterId::from_uuid,
+        "Invalid PC ID",
+    )
+}
+
+fn spotlight_error_response(e: SpotlightError) -> ResponseResult {
+    match e {
+        SpotlightError::WorldNotFound | SpotlightError::PlayerCharacterNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, e.to_string())
+        }
+        SpotlightError::Throttled(_) | SpotlightError::Invalid(_) => {
+            ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+        }
+        SpotlightError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -55,8 +55,12 @@ pub(super) async fn handle_staging_approval(
         guard.remove(&request_id)
     };
 
-    let (region_id, location_id) = if let Some(pending) = pending {
-        (pending.region_id, Some(pending.location_id))
+    let (region_id, location_id, asked_by) = if let Some(pending) = pending {
+        (
+            pending.region_id,
+            Some(pending.location_id),
+            Some(pending.pc_id),
+        )
     } else {
         // Check if request_id looks like a UUID (staging request token)
         // If so, it was likely already processed by timeout or another handler
@@ -71,7 +75,7 @@ pub(super) async fn handle_staging_approval(
             Ok(id) => id,
             Err(e) => return Some(e),
         };
-        (region_id, None)
+        (region_id, None, None)
     };
 
     let world_id = match conn_info.world_id {
@@ -102,10 +106,17 @@ pub(super) async fn handle_staging_approval(
         Err(e) => return Some(error_response("REPO_ERROR", &e.to_string())),
     };
 
+    let waiting = crate::use_cases::staging::pcs_awaiting_region(
+        &state.pending_staging_requests,
+        payload.region_id,
+        asked_by,
+    )
+    .await;
     state
         .connections
-        .broadcast_to_world(
+        .broadcast_to_scenes_of(
             world_id,
+            &waiting,
             ServerMessage::StagingReady {
                 region_id: payload.region_id.to_string(),
                 npcs_present: payload.npcs_present,
@@ -1,7 +1,11 @@
 use super::*;
 
 use crate::api::connections::ConnectionInfo;
+use crate::use_cases::story_events::{
+    map_marker_to_protocol, story_event_to_json, StoryEventError,
+};
 
+use wrldbldr_domain::StoryEvent;
 use wrldbldr_protocol::StoryEventRequest;
 
 pub(super) async fn handle_story_event_request(
@@ -68,6 +72,7 @@ pub(super) async fn handle_story_event_request(
             world_id,
             page: _,
             page_size,
+            tags,
         } => {
             let world_uuid = match parse_world_id_for_request(&world_id, request_id) {
                 Ok(id) => id,
@@ -85,7 +90,20 @@ pub(super) async fn handle_story_event_request(
                 .list(world_uuid, limit)
                 .await
             {
-                Ok(events) => Ok(ResponseResult::success(events)),
+                Ok(mut events) => {
+                    // Applied after the limit, so a filtered page may come back short
+                    ws_tags::retain_tagged(
+                        state,
+                        request_id,
+                        conn_info,
+                        wrldbldr_domain::EntityType::StoryEvent,
+                        &tags,
+                        &mut events,
+                        ws_tags::json_entry_id,
+                    )
+                    .await?;
+                    Ok(ResponseResult::success(events))
+                }
                 Err(e) => Ok(ResponseResult::error(
                     ErrorCode::InternalError,
                     e.to_string(),
@@ -111,13 +129,13 @@ pub(super) async fn handle_story_event_request(
                 .create_dm_marker(world_uuid, data)
                 .await
             {
-                Ok(event_id) => Ok(ResponseResult::success(serde_json::json!({
-                    "id": event_id.to_string(),
-                }))),
-                Err(e) => Ok(ResponseResult::error(
-                    ErrorCode::InternalError,
-                    e.to_string(),
-                )),
+                Ok(event) => {
+                    broadcast_marker_update(state, &event).await;
+                    Ok(ResponseResult::success(serde_json::json!({
+                        "id": event.id.to_string(),
+                    })))
+                }
+                Err(e) => Ok(story_event_error_response(e)),
             }
         }
 
@@ -140,7 +158,14 @@ pub(super) async fn handle_story_event_request(
                 .set_visibility(event_uuid, visible)
                 .await
             {
-                Ok(event) => Ok(ResponseResult::success(event)),
+                Ok(event) => {
+                    // A marker hidden from players should disappear from their maps
+                    if !visible {
+                        broadcast_marker_removed(state, &event, true).await;
+                    }
+                    broadcast_marker_update(state, &event).await;
+                    Ok(ResponseResult::success(story_event_to_json(event)))
+                }
                 Err(crate::use_cases::story_events::StoryEventError::NotFound) => Ok(
                     ResponseResult::error(ErrorCode::NotFound, "Story event not found"),
                 ),
@@ -150,5 +175,110 @@ pub(super) async fn handle_story_event_request(
                 )),
             }
         }
+
+        StoryEventRequest::ListMapMarkers {
+            world_id,
+            location_id,
+            region_id,
+        } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+            let location_id = parse_location_id_for_request(&location_id, request_id)?;
+            let region_id = match region_id {
+                Some(id) => Some(parse_region_id_for_request(&id, request_id)?),
+                None => None,
+            };
+
+            match state
+                .app
+                .use_cases
+                .story_events
+                .ops
+                .list_map_markers(world_id, location_id, region_id, conn_info.is_dm())
+                .await
+            {
+                Ok(markers) => Ok(ResponseResult::success(markers)),
+                Err(e) => Ok(story_event_error_response(e)),
+            }
+        }
+
+        StoryEventRequest::PinDmMarker { event_id, pin } => {
+            require_dm_for_request(conn_info, request_id)?;
+            let event_id = parse_id_for_request(
+                &event_id,
+                request_id,
+                wrldbldr_domain::StoryEventId::from_uuid,
+                "Invalid event_id",
+            )?;
+
+            match state
+                .app
+                .use_cases
+                .story_events
+                .ops
+                .pin_marker(event_id, pin)
+                .await
+            {
+                Ok((event, previous)) => {
+                    if map_marker_to_protocol(&event).is_some() {
+                        broadcast_marker_update(state, &event).await;
+                    } else if previous.is_some() {
+                        broadcast_marker_removed(state, &event, !event.is_hidden).await;
+                    }
+                    Ok(ResponseResult::success(story_event_to_json(event)))
+                }
+                Err(e) => Ok(story_event_error_response(e)),
+            }
+        }
+    }
+}
+
+/// Broadcast a pinned marker to everyone who may see it.
+///
+/// Visible markers go to the whole world; hidden ones only to DMs. Events
+/// without a pin are ignored.
+async fn broadcast_marker_update(state: &WsState, event: &StoryEvent) {
+    let Some(marker) = map_marker_to_protocol(event) else {
+        return;
+    };
+    let msg = ServerMessage::MapMarkerUpdated { marker };
+    if event.is_hidden {
+        state
+            .connections
+            .broadcast_to_dms(event.world_id, msg)
+            .await;
+    } else {
+        state
+            .connections
+            .broadcast_to_world(event.world_id, msg)
+            .await;
+    }
+}
+
+async fn broadcast_marker_removed(state: &WsState, event: &StoryEvent, to_players: bool) {
+    let msg = ServerMessage::MapMarkerRemoved {
+        marker_id: event.id.to_string(),
+    };
+    if to_players {
+        state
+            .connections
+            .broadcast_to_world(event.world_id, msg)
+            .await;
+    } else {
+        state
+            .connections
+            .broadcast_to_dms(event.world_id, msg)
+            .await;
+    }
+}
+
+fn story_event_error_response(e: StoryEventError) -> ResponseResult {
+    match e {
+        StoryEventError::NotFound => {
+            ResponseResult::error(ErrorCode::NotFound, "Story event not found")
+        }
+        StoryEventError::NotAMarker | StoryEventError::InvalidInput(_) => {
+            ResponseResult::error(ErrorCode::BadRequest, e.to_string())
+        }
+        StoryEventError::Repo(_) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
     }
 }
@@ -0,0 +1,254 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::tags::TagError;
+
+use wrldbldr_domain::EntityType;
+use wrldbldr_protocol::TagRequest;
+
+pub(super) async fn handle_tag_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: TagRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // Tags are DM notes ("villain-adjacent"), so every tag request is DM-only
+    require_dm_for_request(conn_info, request_id)?;
+
+    match request {
+        TagRequest::GetEntityTags {
+            world_id,
+            entity_type,
+            entity_id,
+        } => {
+            parse_world_id_for_request(&world_id, request_id)?;
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity_id")?;
+
+            match state
+                .app
+                .use_cases
+                .tags
+                .manage
+                .get(entity_type, entity_id)
+                .await
+            {
+                Ok(tags) => Ok(ResponseResult::success(tags)),
+                Err(e) => Ok(tag_error_response(e)),
+            }
+        }
+
+        TagRequest::SetEntityTags {
+            world_id,
+            entity_type,
+            entity_id,
+            tags,
+        } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+            let entity_id = parse_uuid_for_request(&entity_id, request_id, "Invalid entity_id")?;
+
+            match state
+                .app
+                .use_cases
+                .tags
+                .manage
+                .set(world_id, entity_type, entity_id, tags)
+                .await
+            {
+                Ok(tags) => {
+                    state
+                        .connections
+                        .broadcast_to_dms(
+                            world_id,
+                            ServerMessage::EntityTagsUpdated {
+                                world_id: world_id.to_string(),
+                                entity_type,
+                                entity_id: entity_id.to_string(),
+                                tags: tags.clone(),
+                            },
+                        )
+                        .await;
+                    Ok(ResponseResult::success(tags))
+                }
+                Err(e) => Ok(tag_error_response(e)),
+            }
+        }
+
+        TagRequest::ListWorldTags { world_id } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .tags
+                .manage
+                .list_world_tags(world_id)
+                .await
+            {
+                Ok(tags) => Ok(ResponseResult::success(tags)),
+                Err(e) => Ok(tag_error_response(e)),
+            }
+        }
+
+        TagRequest::RenameTag { world_id, from, to } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .tags
+                .manage
+                .rename(world_id, &from, &to)
+                .await
+            {
+                Ok(changed) => {
+                    broadcast_world_tags(state, world_id).await;
+                    Ok(ResponseResult::success(
+                        serde_json::json!({ "changed": changed }),
+                    ))
+                }
+                Err(e) => Ok(tag_error_response(e)),
+            }
+        }
+
+        TagRequest::DeleteTag { world_id, tag } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            match state.app.use_cases.tags.manage.delete(world_id, &tag).await {
+                Ok(changed) => {
+                    broadcast_world_tags(state, world_id).await;
+                    Ok(ResponseResult::success(
+                        serde_json::json!({ "changed": changed }),
+                    ))
+                }
+                Err(e) => Ok(tag_error_response(e)),
+            }
+        }
+
+        TagRequest::ListSavedFilters { world_id } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .tags
+                .filters
+                .list(world_id, &conn_info.user_id)
+                .await
+            {
+                Ok(filters) => Ok(ResponseResult::success(filters)),
+                Err(e) => Ok(tag_error_response(e)),
+            }
+        }
+
+        TagRequest::SaveFilter { world_id, data } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .tags
+                .filters
+                .save(world_id, &conn_info.user_id, data)
+                .await
+            {
+                Ok(filter) => Ok(ResponseResult::success(filter)),
+                Err(e) => Ok(tag_error_response(e)),
+            }
+        }
+
+        TagRequest::DeleteSavedFilter {
+            world_id,
+            filter_id,
+        } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+
+            match state
+                .app
+                .use_cases
+                .tags
+                .filters
+                .delete(world_id, &conn_info.user_id, &filter_id)
+                .await
+            {
+                Ok(()) => Ok(ResponseResult::success_empty()),
+                Err(e) => Ok(tag_error_response(e)),
+            }
+        }
+    }
+}
+
+/// Narrow a list response to the entities carrying every tag in `tags`.
+///
+/// An empty filter leaves the list alone. Filtering by tag is DM-only, since
+/// it would otherwise let players probe the DM's tags.
+pub(super) async fn retain_tagged<T>(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    entity_type: EntityType,
+    tags: &[String],
+    items: &mut Vec<T>,
+    id_of: impl Fn(&T) -> Uuid,
+) -> Result<(), ServerMessage> {
+    if tags.is_empty() {
+        return Ok(());
+    }
+    require_dm_for_request(conn_info, request_id)?;
+
+    state
+        .app
+        .use_cases
+        .tags
+        .manage
+        .retain_tagged(entity_type, tags, items, id_of)
+        .await
+        .map_err(|e| ServerMessage::Response {
+            request_id: request_id.to_string(),
+            result: tag_error_response(e),
+        })
+}
+
+/// Parse the `id` field of a JSON list entry (for handlers that build
+/// their responses with `serde_json::json!`).
+pub(super) fn json_entry_id(entry: &serde_json::Value) -> Uuid {
+    entry
+        .get("id")
+        .and_then(|id| id.as_str())
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .unwrap_or_default()
+}
+
+async fn broadcast_world_tags(state: &WsState, world_id: WorldId) {
+    match state
+        .app
+        .use_cases
+        .tags
+        .manage
+        .list_world_tags(world_id)
+        .await
+    {
+        Ok(tags) => {
+            state
+                .connections
+                .broadcast_to_dms(
+                    world_id,
+                    ServerMessage::WorldTagsUpdated {
+                        world_id: world_id.to_string(),
+                        tags,
+                    },
+                )
+                .await;
+        }
+        Err(e) => tracing::warn!(%world_id, error = %e, "Could not reload world tags"),
+    }
+}
+
+fn tag_error_response(e: TagError) -> ResponseResult {
+    match e {
+        TagError::FilterNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, "Saved filter not found")
+        }
+        TagError::Invalid(msg) => ResponseResult::error(ErrorCode::ValidationError, msg),
+        TagError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
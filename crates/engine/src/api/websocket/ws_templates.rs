@@ -0,0 +1,135 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::templates::TemplateError;
+
+use wrldbldr_domain::EntityTemplateId;
+use wrldbldr_protocol::TemplateRequest;
+
+pub(super) async fn handle_template_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: TemplateRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    // Templates are prep tools, so every template request is DM-only
+    require_dm_for_request(conn_info, request_id)?;
+    let templates = &state.app.use_cases.templates;
+
+    let result = match request {
+        TemplateRequest::ListTemplates { world_id } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+            templates
+                .manage
+                .list(world_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        TemplateRequest::GetTemplate { template_id } => {
+            let template_id = parse_template_id_for_request(&template_id, request_id)?;
+            templates
+                .manage
+                .get(template_id)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        TemplateRequest::CreateTemplate { world_id, data } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+            templates
+                .manage
+                .create(world_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        TemplateRequest::UpdateTemplate { template_id, data } => {
+            let template_id = parse_template_id_for_request(&template_id, request_id)?;
+            templates
+                .manage
+                .update(template_id, data)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        TemplateRequest::DeleteTemplate { template_id } => {
+            let template_id = parse_template_id_for_request(&template_id, request_id)?;
+            templates
+                .manage
+                .delete(template_id)
+                .await
+                .map(|()| ResponseResult::success_empty())
+        }
+
+        TemplateRequest::SaveCharacterAsTemplate { character_id, name } => {
+            let character_id = parse_character_id_for_request(&character_id, request_id)?;
+            templates
+                .manage
+                .save_character(character_id, name)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        TemplateRequest::SaveRegionAsTemplate { region_id, name } => {
+            let region_id = parse_region_id_for_request(&region_id, request_id)?;
+            templates
+                .manage
+                .save_region(region_id, name)
+                .await
+                .map(ResponseResult::success)
+        }
+
+        TemplateRequest::InstantiateTemplate {
+            template_id,
+            name,
+            variables,
+            location_id,
+        } => {
+            let template_id = parse_template_id_for_request(&template_id, request_id)?;
+            let location_id = location_id
+                .as_deref()
+                .map(|id| parse_location_id_for_request(id, request_id))
+                .transpose()?;
+            templates
+                .instantiate
+                .execute(template_id, &name, &variables, location_id)
+                .await
+                .map(ResponseResult::success)
+        }
+    };
+
+    Ok(result.unwrap_or_else(template_error_response))
+}
+
+fn parse_template_id_for_request(
+    id_str: &str,
+    request_id: &str,
+) -> Result<EntityTemplateId, ServerMessage> {
+    parse_id_for_request(
+        id_str,
+        request_id,
+        EntityTemplateId::from_uuid,
+        "Invalid template ID",
+    )
+}
+
+fn template_error_response(e: TemplateError) -> ResponseResult {
+    match e {
+        TemplateError::NotFound => ResponseResult::error(ErrorCode::NotFound, "Template not found"),
+        TemplateError::CharacterNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, "Character not found")
+        }
+        TemplateError::RegionNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, "Region not found")
+        }
+        TemplateError::LocationNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, "Location not found")
+        }
+        TemplateError::Invalid(msg) => ResponseResult::error(ErrorCode::ValidationError, msg),
+        TemplateError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+        TemplateError::Inventory(e) => {
+            ResponseResult::error(ErrorCode::InternalError, e.to_string())
+        }
+    }
+}
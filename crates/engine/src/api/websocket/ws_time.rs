@@ -111,6 +111,7 @@ pub(super) async fn handle_skip_to_period(
         outcome.minutes_advanced,
         &reason,
     );
+    ws_scripts::fire_time_advance_hook(state, world_id_typed, &advance_data).await;
     let msg = ServerMessage::GameTimeAdvanced { data: advance_data };
     state
         .connections
@@ -336,6 +337,9 @@ pub(super) async fn handle_respond_to_time_suggestion(
         .await
     {
         Ok(Some(resolution)) => {
+            ws_scripts::fire_time_advance_hook(state, world_id, &resolution.advance_data).await;
+            let minutes_advanced = resolution.advance_data.minutes_advanced;
+            let rested_pc_id = resolution.rested_pc_id;
             let msg = ServerMessage::GameTimeAdvanced {
                 data: resolution.advance_data,
             };
@@ -343,6 +347,12 @@ pub(super) async fn handle_respond_to_time_suggestion(
                 .connections
                 .broadcast_to_world(world_id, msg)
                 .await;
+            ws_chronology::notify_age_progression(state, world_id, minutes_advanced).await;
+            ws_injury::heal_after_time_advance(state, world_id, minutes_advanced, rested_pc_id)
+                .await;
+            ws_mail::deliver_after_time_advance(state, world_id).await;
+            ws_broadsheet::draft_after_time_advance(state, world_id, minutes_advanced).await;
+            ws_simulation::simulate_after_time_advance(state, world_id, minutes_advanced);
             None
         }
         Ok(None) => None,
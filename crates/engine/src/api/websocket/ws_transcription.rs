@@ -0,0 +1,68 @@
+use super::*;
+
+use base64::Engine as _;
+
+use crate::api::connections::{ConnectionInfo, WorldRole};
+use crate::use_cases::transcription::TranscribeError;
+
+use wrldbldr_protocol::types::TranscriptionData;
+use wrldbldr_protocol::TranscriptionRequest;
+
+pub(super) async fn handle_transcription_request(
+    state: &WsState,
+    _request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: TranscriptionRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    match request {
+        TranscriptionRequest::TranscribeAudio {
+            audio,
+            content_type,
+        } => {
+            if conn_info.world_id.is_none() {
+                return Ok(ResponseResult::error(
+                    ErrorCode::BadRequest,
+                    "Join a world before using voice input",
+                ));
+            }
+            if conn_info.role == WorldRole::Spectator {
+                return Ok(ResponseResult::error(
+                    ErrorCode::Forbidden,
+                    "Spectators can't use voice input",
+                ));
+            }
+
+            let audio = match base64::engine::general_purpose::STANDARD.decode(audio.trim()) {
+                Ok(audio) => audio,
+                Err(e) => {
+                    return Ok(ResponseResult::error(
+                        ErrorCode::ValidationError,
+                        format!("Invalid base64 audio data: {}", e),
+                    ))
+                }
+            };
+
+            let result = state
+                .app
+                .use_cases
+                .transcription
+                .transcribe
+                .execute(audio, &content_type, &conn_info.locale)
+                .await;
+
+            Ok(match result {
+                Ok(text) => ResponseResult::success(TranscriptionData { text }),
+                Err(e @ TranscribeError::Invalid(_)) => {
+                    ResponseResult::error(ErrorCode::ValidationError, e.to_string())
+                }
+                Err(e @ TranscribeError::Unavailable) => {
+                    ResponseResult::error(ErrorCode::ServiceUnavailable, e.to_string())
+                }
+                Err(e @ TranscribeError::Failed(_)) => {
+                    tracing::warn!(error = %e, "Voice input transcription failed");
+                    ResponseResult::error(ErrorCode::InternalError, e.to_string())
+                }
+            })
+        }
+    }
+}
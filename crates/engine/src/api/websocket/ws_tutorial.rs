@@ -0,0 +1,143 @@
+use super::*;
+
+use crate::api::connections::ConnectionInfo;
+use crate::use_cases::tutorial::{status_to_protocol, TutorialError, TutorialStatus};
+
+use wrldbldr_domain::{TutorialAction, TutorialGoal};
+use wrldbldr_protocol::TutorialRequest;
+
+pub(super) async fn handle_tutorial_request(
+    state: &WsState,
+    request_id: &str,
+    conn_info: &ConnectionInfo,
+    request: TutorialRequest,
+) -> Result<ResponseResult, ServerMessage> {
+    match request {
+        TutorialRequest::CreateTutorialWorld => {
+            // Like CreateWorld, anyone can start a tutorial world
+            match state.app.use_cases.tutorial.create_world.execute().await {
+                Ok(world) => Ok(ResponseResult::success(serde_json::json!({
+                    "id": world.id.to_string(),
+                    "name": world.name,
+                    "description": world.description,
+                }))),
+                Err(e) => Ok(tutorial_error_response(e)),
+            }
+        }
+
+        TutorialRequest::GetTutorialStatus { world_id, pc_id } => {
+            let world_id = parse_world_id_for_request(&world_id, request_id)?;
+            let pc_id = parse_id_for_request(
+                &pc_id,
+                request_id,
+                PlayerCharacterId::from_uuid,
+                "Invalid PC ID",
+            )?;
+
+            if !conn_info.is_dm() && !conn_info.controls_pc(pc_id) {
+                return Ok(ResponseResult::error(
+                    ErrorCode::Forbidden,
+                    "Cannot view another player's tutorial",
+                ));
+            }
+
+            match state
+                .app
+                .use_cases
+                .tutorial
+                .progress
+                .status(world_id, pc_id)
+                .await
+            {
+                Ok(Some(status)) => {
+                    // Re-send a pending roll prompt lost to a reload
+                    prompt_current_step(state, &status).await;
+                    Ok(ResponseResult::success(status_to_protocol(&status)))
+                }
+                Ok(None) => Ok(ResponseResult::success(serde_json::Value::Null)),
+                Err(e) => Ok(tutorial_error_response(e)),
+            }
+        }
+    }
+}
+
+/// Advance the PC's tutorial if the action completes their current step.
+///
+/// Tutorial bookkeeping never fails the action itself; errors are logged.
+pub(super) async fn record_tutorial_action(
+    state: &WsState,
+    world_id: WorldId,
+    pc_id: PlayerCharacterId,
+    action: TutorialAction,
+) {
+    let status = match state
+        .app
+        .use_cases
+        .tutorial
+        .progress
+        .record(world_id, pc_id, action)
+        .await
+    {
+        Ok(Some(status)) => status,
+        Ok(None) => return,
+        Err(e) => {
+            tracing::warn!(error = %e, pc_id = %pc_id, "Failed to record tutorial progress");
+            return;
+        }
+    };
+
+    state
+        .connections
+        .send_to_pc(
+            pc_id,
+            ServerMessage::TutorialProgress {
+                status: status_to_protocol(&status),
+            },
+        )
+        .await;
+    prompt_current_step(state, &status).await;
+}
+
+/// Stand in for the DM on steps that need a prompt (a challenge to roll).
+async fn prompt_current_step(state: &WsState, status: &TutorialStatus) {
+    let Some(TutorialGoal::RollChallenge { challenge_id }) =
+        status.current_step.as_ref().map(|step| &step.goal)
+    else {
+        return;
+    };
+
+    match state
+        .app
+        .use_cases
+        .challenge
+        .trigger_prompt
+        .execute(*challenge_id)
+        .await
+    {
+        Ok(prompt) => {
+            let msg = ServerMessage::ChallengePrompt {
+                challenge_id: challenge_id.to_string(),
+                challenge_name: prompt.challenge_name,
+                skill_name: prompt.skill_name,
+                difficulty_display: prompt.difficulty_display,
+                description: prompt.description,
+                character_modifier: prompt.character_modifier,
+                suggested_dice: prompt.suggested_dice,
+                rule_system_hint: prompt.rule_system_hint,
+            };
+            state.connections.send_to_pc(status.pc_id, msg).await;
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, challenge_id = %challenge_id, "Failed to prompt tutorial challenge");
+        }
+    }
+}
+
+fn tutorial_error_response(e: TutorialError) -> ResponseResult {
+    match e {
+        TutorialError::WorldNotFound => {
+            ResponseResult::error(ErrorCode::NotFound, "World not found")
+        }
+        TutorialError::Repo(e) => ResponseResult::error(ErrorCode::InternalError, e.to_string()),
+    }
+}
@@ -4,10 +4,22 @@ use std::sync::Arc;
 
 use crate::entities;
 use crate::infrastructure::{
+    circuit_breaker::ServiceCircuitBreakers,
     clock::{SystemClock, SystemRandom},
+    diagnostics::Diagnostics,
     neo4j::Neo4jRepositories,
-    ports::{ClockPort, ImageGenPort, LlmPort, QueuePort, RandomPort, SettingsRepo},
+    ports::{
+        ActRepo, AssetRepo, BlobStorePort, BroadsheetRepo, ChallengeRepo, CharacterRepo, ClockPort, CompanionRepo,
+        ContentDraftRepo, CrowdRepo, CustomFieldRepo, DialogueTreeRepo, EconomyRepo, FactionRepo, FlagRepo, GoalRepo,
+        ImageGenPort, InjuryRepo, InteractionRepo, ItemRepo, LetterRepo, LibraryRepo, LlmModelPort, LlmPort, LocationRepo,
+        LocationStateRepo, LoreRepo, MentionRepo, NameGeneratorRepo, NarrativeRepo, NpcDraftRepo,
+        ObservationRepo, PcSecretRepo, PlayerCharacterRepo, PluginPort, ProgressClockRepo, PropertyRepo,
+        QueuePort, RandomPort, RegionStateRepo, SceneRepo, ScriptEnginePort, ServiceProbePort,
+        SettingsRepo, SkillRepo, StagingRepo, TagRepo, TemplateRepo, TranscriptionPort, UsageRepo,
+        WorldRepo,
+    },
     queue::SqliteQueue,
+    rhai_scripts::RhaiScriptEngine,
 };
 use crate::use_cases;
 
@@ -20,6 +32,10 @@ pub struct App {
     pub use_cases: UseCases,
     pub queue: Arc<dyn QueuePort>,
     pub llm: Arc<dyn LlmPort>,
+    /// Breakers guarding external services (exposed on the metrics endpoint)
+    pub circuit_breakers: ServiceCircuitBreakers,
+    /// Captured logs and protocol message counts for diagnostics bundles
+    pub diagnostics: Arc<Diagnostics>,
 }
 
 /// Container for all entity modules.
@@ -41,6 +57,25 @@ pub struct Entities {
     pub flag: Arc<entities::Flag>,
     pub goal: Arc<entities::Goal>,
     pub lore: Arc<entities::Lore>,
+    pub progress_clock: Arc<entities::ProgressClock>,
+    pub tag: Arc<entities::Tag>,
+    pub custom_field: Arc<entities::CustomField>,
+    pub template: Arc<entities::Template>,
+    pub library: Arc<entities::Library>,
+    pub draft: Arc<entities::Draft>,
+    pub crowd: Arc<entities::Crowd>,
+    pub npc_draft: Arc<entities::NpcDraft>,
+    pub mention: Arc<entities::Mention>,
+    pub name_generator: Arc<entities::NameGenerator>,
+    pub economy: Arc<entities::Economy>,
+    pub property: Arc<entities::Property>,
+    pub companion: Arc<entities::Companion>,
+    pub injury: Arc<entities::Injury>,
+    pub pc_secret: Arc<entities::PcSecret>,
+    pub letter: Arc<entities::Letter>,
+    pub broadsheet: Arc<entities::Broadsheet>,
+    pub faction: Arc<entities::Faction>,
+    pub dialogue_tree: Arc<entities::DialogueTree>,
     pub location_state: Arc<entities::LocationStateEntity>,
     pub region_state: Arc<entities::RegionStateEntity>,
 }
@@ -59,32 +94,244 @@ pub struct UseCases {
     pub queues: use_cases::QueueUseCases,
     pub narrative: use_cases::NarrativeUseCases,
     pub player_action: use_cases::PlayerActionUseCases,
+    pub scripts: use_cases::ScriptUseCases,
     pub time: use_cases::TimeUseCases,
     pub visual_state: use_cases::VisualStateUseCases,
     pub management: use_cases::ManagementUseCases,
     pub session: use_cases::SessionUseCases,
     pub settings: Arc<entities::Settings>,
+    pub setup: use_cases::SetupUseCases,
+    pub health: use_cases::HealthUseCases,
     pub staging: use_cases::StagingUseCases,
     pub npc: use_cases::NpcUseCases,
     pub story_events: use_cases::StoryEventUseCases,
+    pub tags: use_cases::TagUseCases,
+    pub custom_fields: use_cases::CustomFieldUseCases,
+    pub templates: use_cases::TemplateUseCases,
+    pub duplicate: use_cases::DuplicateUseCases,
+    pub library: use_cases::LibraryUseCases,
+    pub drafts: use_cases::DraftUseCases,
+    pub chronology: use_cases::ChronologyUseCases,
+    pub crowds: use_cases::CrowdUseCases,
+    pub npc_drafts: use_cases::NpcDraftUseCases,
+    pub mentions: use_cases::MentionUseCases,
+    pub names: use_cases::NameGeneratorUseCases,
+    pub economy: use_cases::EconomyUseCases,
+    pub property: use_cases::PropertyUseCases,
+    pub companion: use_cases::CompanionUseCases,
+    pub injury: use_cases::InjuryUseCases,
+    pub secret: use_cases::SecretUseCases,
+    pub mail: use_cases::MailUseCases,
+    pub broadsheet: use_cases::BroadsheetUseCases,
+    pub factions: use_cases::FactionUseCases,
+    pub dialogue_trees: use_cases::DialogueTreeUseCases,
+    pub flashback: use_cases::FlashbackUseCases,
+    pub parallel_scenes: use_cases::ParallelSceneUseCases,
+    pub spotlight: use_cases::SpotlightUseCases,
+    pub pacing: use_cases::PacingUseCases,
+    pub simulation: use_cases::SimulationUseCases,
+    pub mortality: use_cases::MortalityUseCases,
     pub lore: use_cases::LoreUseCases,
+    pub knowledge: use_cases::KnowledgeUseCases,
+    pub progress_clock: use_cases::ProgressClockUseCases,
+    pub safety: use_cases::SafetyUseCases,
+    pub trade: use_cases::TradeUseCases,
+    pub travel: use_cases::TravelUseCases,
+    pub dice: use_cases::DiceUseCases,
+    pub transcription: use_cases::TranscriptionUseCases,
+    pub tutorial: use_cases::TutorialUseCases,
+    pub session_zero: use_cases::SessionZeroUseCases,
+    pub usage: use_cases::UsageUseCases,
+    pub analytics: use_cases::AnalyticsUseCases,
+    pub prep: use_cases::PrepUseCases,
+    pub diagnostics: use_cases::DiagnosticsUseCases,
     pub location_events: use_cases::LocationEventUseCases,
+    pub interactions: use_cases::InteractionUseCases,
+    pub rule_system: use_cases::RuleSystemUseCases,
     pub custom_condition: Arc<use_cases::CustomConditionEvaluator>,
 }
 
+/// Graph repositories the application is composed from.
+pub struct Repositories {
+    pub character: Arc<dyn CharacterRepo>,
+    pub player_character: Arc<dyn PlayerCharacterRepo>,
+    pub location: Arc<dyn LocationRepo>,
+    pub scene: Arc<dyn SceneRepo>,
+    pub act: Arc<dyn ActRepo>,
+    pub skill: Arc<dyn SkillRepo>,
+    pub interaction: Arc<dyn InteractionRepo>,
+    pub challenge: Arc<dyn ChallengeRepo>,
+    pub narrative: Arc<dyn NarrativeRepo>,
+    pub staging: Arc<dyn StagingRepo>,
+    pub observation: Arc<dyn ObservationRepo>,
+    pub item: Arc<dyn ItemRepo>,
+    pub world: Arc<dyn WorldRepo>,
+    pub asset: Arc<dyn AssetRepo>,
+    pub flag: Arc<dyn FlagRepo>,
+    pub goal: Arc<dyn GoalRepo>,
+    pub lore: Arc<dyn LoreRepo>,
+    pub progress_clock: Arc<dyn ProgressClockRepo>,
+    pub tag: Arc<dyn TagRepo>,
+    pub custom_field: Arc<dyn CustomFieldRepo>,
+    pub template: Arc<dyn TemplateRepo>,
+    pub library: Arc<dyn LibraryRepo>,
+    pub content_draft: Arc<dyn ContentDraftRepo>,
+    pub crowd: Arc<dyn CrowdRepo>,
+    pub npc_draft: Arc<dyn NpcDraftRepo>,
+    pub mention: Arc<dyn MentionRepo>,
+    pub name_generator: Arc<dyn NameGeneratorRepo>,
+    pub economy: Arc<dyn EconomyRepo>,
+    pub property: Arc<dyn PropertyRepo>,
+    pub companion: Arc<dyn CompanionRepo>,
+    pub injury: Arc<dyn InjuryRepo>,
+    pub pc_secret: Arc<dyn PcSecretRepo>,
+    pub letter: Arc<dyn LetterRepo>,
+    pub broadsheet: Arc<dyn BroadsheetRepo>,
+    pub faction: Arc<dyn FactionRepo>,
+    pub dialogue_tree: Arc<dyn DialogueTreeRepo>,
+    pub location_state: Arc<dyn LocationStateRepo>,
+    pub region_state: Arc<dyn RegionStateRepo>,
+}
+
+impl From<Neo4jRepositories> for Repositories {
+    fn from(repos: Neo4jRepositories) -> Self {
+        Self {
+            character: repos.character,
+            player_character: repos.player_character,
+            location: repos.location,
+            scene: repos.scene,
+            act: repos.act,
+            skill: repos.skill,
+            interaction: repos.interaction,
+            challenge: repos.challenge,
+            narrative: repos.narrative,
+            staging: repos.staging,
+            observation: repos.observation,
+            item: repos.item,
+            world: repos.world,
+            asset: repos.asset,
+            flag: repos.flag,
+            goal: repos.goal,
+            lore: repos.lore,
+            progress_clock: repos.progress_clock,
+            tag: repos.tag,
+            custom_field: repos.custom_field,
+            template: repos.template,
+            library: repos.library,
+            content_draft: repos.content_draft,
+            crowd: repos.crowd,
+            npc_draft: repos.npc_draft,
+            mention: repos.mention,
+            name_generator: repos.name_generator,
+            economy: repos.economy,
+            property: repos.property,
+            companion: repos.companion,
+            injury: repos.injury,
+            pc_secret: repos.pc_secret,
+            letter: repos.letter,
+            broadsheet: repos.broadsheet,
+            faction: repos.faction,
+            dialogue_tree: repos.dialogue_tree,
+            location_state: repos.location_state,
+            region_state: repos.region_state,
+        }
+    }
+}
+
+/// Every port an [`App`] is composed from.
+///
+/// `App::new` fills these from Neo4j, SQLite and the system clock; tests
+/// pass in-memory, scripted or mocked implementations to `App::from_ports`.
+pub struct AppPorts {
+    pub repos: Repositories,
+    pub settings_repo: Arc<dyn SettingsRepo>,
+    pub usage_repo: Arc<dyn UsageRepo>,
+    pub queue: Arc<dyn QueuePort>,
+    pub llm: Arc<dyn LlmPort>,
+    pub llm_models: Arc<dyn LlmModelPort>,
+    pub image_gen: Arc<dyn ImageGenPort>,
+    pub transcription: Arc<dyn TranscriptionPort>,
+    pub blob_store: Arc<dyn BlobStorePort>,
+    pub service_probe: Arc<dyn ServiceProbePort>,
+    pub plugins: Arc<dyn PluginPort>,
+    pub scripts: Arc<dyn ScriptEnginePort>,
+    pub clock: Arc<dyn ClockPort>,
+    pub random: Arc<dyn RandomPort>,
+    /// Service connections the engine started with
+    pub running_connections: wrldbldr_domain::ServiceConnections,
+    /// Service connections from the environment alone
+    pub env_connections: wrldbldr_domain::ServiceConnections,
+    pub circuit_breakers: ServiceCircuitBreakers,
+    pub diagnostics: Arc<Diagnostics>,
+}
+
 impl App {
     /// Create a new App with all dependencies wired up.
+    ///
+    /// `running_connections` are the service connections the engine started
+    /// with; `env_connections` are those from the environment alone.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         repos: Neo4jRepositories,
         llm: Arc<dyn LlmPort>,
+        llm_models: Arc<dyn LlmModelPort>,
         image_gen: Arc<dyn ImageGenPort>,
+        transcription: Arc<dyn TranscriptionPort>,
         queue: Arc<SqliteQueue>,
         settings_repo: Arc<dyn SettingsRepo>,
+        usage_repo: Arc<dyn UsageRepo>,
+        blob_store: Arc<dyn BlobStorePort>,
+        service_probe: Arc<dyn ServiceProbePort>,
+        plugins: Arc<dyn PluginPort>,
+        running_connections: wrldbldr_domain::ServiceConnections,
+        env_connections: wrldbldr_domain::ServiceConnections,
+        circuit_breakers: ServiceCircuitBreakers,
+        diagnostics: Arc<Diagnostics>,
     ) -> Self {
-        // Create infrastructure services
-        let clock: Arc<dyn ClockPort> = Arc::new(SystemClock::new());
-        let random: Arc<dyn RandomPort> = Arc::new(SystemRandom::new());
-        let queue_port: Arc<dyn QueuePort> = queue.clone();
+        Self::from_ports(AppPorts {
+            repos: repos.into(),
+            settings_repo,
+            usage_repo,
+            queue,
+            llm,
+            llm_models,
+            image_gen,
+            transcription,
+            blob_store,
+            service_probe,
+            plugins,
+            scripts: Arc::new(RhaiScriptEngine::new()),
+            clock: Arc::new(SystemClock::new()),
+            random: Arc::new(SystemRandom::new()),
+            running_connections,
+            env_connections,
+            circuit_breakers,
+            diagnostics,
+        })
+    }
+
+    /// Wire entities and use cases on top of the given ports.
+    pub fn from_ports(ports: AppPorts) -> Self {
+        let AppPorts {
+            repos,
+            settings_repo,
+            usage_repo,
+            queue: queue_port,
+            llm,
+            llm_models,
+            image_gen,
+            transcription,
+            blob_store,
+            service_probe,
+            plugins,
+            scripts,
+            clock,
+            random,
+            running_connections,
+            env_connections,
+            circuit_breakers,
+            diagnostics,
+        } = ports;
 
         // Create entity modules
         let character = Arc::new(entities::Character::new(repos.character.clone()));
@@ -125,6 +372,31 @@ impl App {
         let flag = Arc::new(entities::Flag::new(repos.flag.clone()));
         let goal = Arc::new(entities::Goal::new(repos.goal.clone()));
         let lore = Arc::new(entities::Lore::new(repos.lore.clone()));
+        let progress_clock = Arc::new(entities::ProgressClock::new(
+            repos.progress_clock.clone(),
+            clock.clone(),
+        ));
+        let tag = Arc::new(entities::Tag::new(repos.tag.clone()));
+        let custom_field = Arc::new(entities::CustomField::new(repos.custom_field.clone()));
+        let template = Arc::new(entities::Template::new(repos.template.clone()));
+        let library = Arc::new(entities::Library::new(repos.library.clone()));
+        let draft = Arc::new(entities::Draft::new(repos.content_draft.clone()));
+        let crowd = Arc::new(entities::Crowd::new(repos.crowd.clone()));
+        let npc_draft = Arc::new(entities::NpcDraft::new(repos.npc_draft.clone()));
+        let mention = Arc::new(entities::Mention::new(repos.mention.clone()));
+        let name_generator = Arc::new(entities::NameGenerator::new(repos.name_generator.clone()));
+        let economy = Arc::new(entities::Economy::new(repos.economy.clone()));
+        let property = Arc::new(entities::Property::new(repos.property.clone()));
+        let companion = Arc::new(entities::Companion::new(
+            repos.companion.clone(),
+            repos.character.clone(),
+        ));
+        let injury = Arc::new(entities::Injury::new(repos.injury.clone()));
+        let pc_secret = Arc::new(entities::PcSecret::new(repos.pc_secret.clone()));
+        let letter = Arc::new(entities::Letter::new(repos.letter.clone()));
+        let broadsheet = Arc::new(entities::Broadsheet::new(repos.broadsheet.clone()));
+        let faction = Arc::new(entities::Faction::new(repos.faction.clone()));
+        let dialogue_tree = Arc::new(entities::DialogueTree::new(repos.dialogue_tree.clone()));
         let location_state = Arc::new(entities::LocationStateEntity::new(
             repos.location_state.clone(),
         ));
@@ -148,6 +420,25 @@ impl App {
             flag: flag.clone(),
             goal: goal.clone(),
             lore: lore.clone(),
+            progress_clock: progress_clock.clone(),
+            tag: tag.clone(),
+            custom_field: custom_field.clone(),
+            template: template.clone(),
+            library: library.clone(),
+            draft: draft.clone(),
+            crowd: crowd.clone(),
+            npc_draft: npc_draft.clone(),
+            mention: mention.clone(),
+            name_generator: name_generator.clone(),
+            economy: economy.clone(),
+            property: property.clone(),
+            companion: companion.clone(),
+            injury: injury.clone(),
+            pc_secret: pc_secret.clone(),
+            letter: letter.clone(),
+            broadsheet: broadsheet.clone(),
+            faction: faction.clone(),
+            dialogue_tree: dialogue_tree.clone(),
             location_state: location_state.clone(),
             region_state: region_state.clone(),
         };
@@ -170,6 +461,7 @@ impl App {
                 inventory.clone(),
                 flag.clone(),
                 world.clone(),
+                companion.clone(),
                 suggest_time.clone(),
             )),
             Arc::new(use_cases::movement::ExitLocation::new(
@@ -182,11 +474,40 @@ impl App {
                 inventory.clone(),
                 flag.clone(),
                 world.clone(),
+                companion.clone(),
                 suggest_time.clone(),
             )),
+            Arc::new(use_cases::movement::UnlockConnection::new(
+                player_character.clone(),
+                location.clone(),
+                inventory.clone(),
+            )),
         );
 
-        let scene_change = use_cases::SceneChangeBuilder::new(location.clone(), inventory.clone());
+        let travel_uc =
+            use_cases::TravelUseCases::new(Arc::new(use_cases::travel::FastTravel::new(
+                player_character.clone(),
+                location.clone(),
+                observation.clone(),
+                movement.exit_location.clone(),
+                suggest_time.clone(),
+                random.clone(),
+                clock.clone(),
+            )));
+
+        let make_noise = Arc::new(use_cases::location_events::MakeNoise::new(
+            location.clone(),
+            staging.clone(),
+            player_character.clone(),
+            clock.clone(),
+        ));
+
+        let scene_change = use_cases::SceneChangeBuilder::new(
+            location.clone(),
+            inventory.clone(),
+            crowd.clone(),
+            world.clone(),
+        );
 
         let conversation_start = Arc::new(use_cases::conversation::StartConversation::new(
             character.clone(),
@@ -217,13 +538,27 @@ impl App {
             conversation_end,
         );
 
-        let player_action = use_cases::PlayerActionUseCases::new(Arc::new(
-            use_cases::player_action::HandlePlayerAction::new(
+        // Lines held for a merge window, shared by the worker that answers them
+        // and the use cases that take them back
+        let conversation_queues =
+            Arc::new(use_cases::queues::arbitration::ConversationQueues::default());
+        let safety_signals = Arc::new(use_cases::safety::SafetySignals::new(
+            world.clone(),
+            queue_port.clone(),
+            conversation_queues.clone(),
+        ));
+
+        let player_action = use_cases::PlayerActionUseCases::new(
+            Arc::new(use_cases::player_action::HandlePlayerAction::new(
                 conversation_start,
                 queue_port.clone(),
                 clock.clone(),
-            ),
-        ));
+            )),
+            Arc::new(use_cases::player_action::RetractPlayerAction::new(
+                queue_port.clone(),
+                conversation_queues.clone(),
+            )),
+        );
 
         let actantial = use_cases::ActantialUseCases::new(
             use_cases::actantial::GoalOps::new(goal.clone()),
@@ -231,11 +566,20 @@ impl App {
             use_cases::actantial::ActantialContextOps::new(character.clone()),
         );
 
-        let ai = use_cases::AiUseCases::new(Arc::new(use_cases::ai::SuggestionOps::new(
-            queue_port.clone(),
-            world.clone(),
-            character.clone(),
-        )));
+        // Create settings entity
+        let settings_entity = Arc::new(entities::Settings::new(settings_repo.clone()));
+
+        let ai = use_cases::AiUseCases::new(
+            Arc::new(use_cases::ai::SuggestionOps::new(
+                queue_port.clone(),
+                world.clone(),
+                character.clone(),
+            )),
+            Arc::new(use_cases::ai::ModelOps::new(
+                llm_models,
+                settings_entity.clone(),
+            )),
+        );
 
         let resolve_outcome = Arc::new(use_cases::challenge::ResolveOutcome::new(
             challenge.clone(),
@@ -243,16 +587,23 @@ impl App {
             observation.clone(),
             scene.clone(),
             player_character.clone(),
+            progress_clock.clone(),
         ));
         let outcome_decision = Arc::new(use_cases::challenge::OutcomeDecision::new(
             queue_port.clone(),
             resolve_outcome.clone(),
+            movement.unlock_connection.clone(),
+            challenge.clone(),
+            clock.clone(),
         ));
 
         let challenge_uc = use_cases::ChallengeUseCases::new(
             Arc::new(use_cases::challenge::RollChallenge::new(
                 challenge.clone(),
                 player_character.clone(),
+                location.clone(),
+                world.clone(),
+                staging.clone(),
                 queue_port.clone(),
                 random.clone(),
                 clock.clone(),
@@ -265,7 +616,285 @@ impl App {
             Arc::new(use_cases::challenge::ChallengeOps::new(
                 challenge.clone(),
             )),
+            Arc::new(use_cases::challenge::SuggestionFeedback::new(
+                challenge.clone(),
+                queue_port.clone(),
+                clock.clone(),
+            )),
+            Arc::new(use_cases::challenge::ChallengeOdds::new(
+                challenge.clone(),
+                player_character.clone(),
+            )),
+            Arc::new(use_cases::challenge::ChallengeHistory::new(
+                challenge.clone(),
+            )),
+        );
+
+        let execute_effects = Arc::new(use_cases::narrative::ExecuteEffects::new(
+            inventory.clone(),
+            challenge.clone(),
+            narrative.clone(),
+            character.clone(),
+            observation.clone(),
+            player_character.clone(),
+            scene.clone(),
+            flag.clone(),
+            world.clone(),
+            clock.clone(),
+            plugins.clone(),
+        ));
+        let plugin_tools = Arc::new(use_cases::plugins::PluginTools::new(
+            plugins.clone(),
+            execute_effects.clone(),
+        ));
+        let scripts_uc = use_cases::ScriptUseCases::new(
+            Arc::new(use_cases::scripts::ManageScripts::new(
+                world.clone(),
+                scripts.clone(),
+                clock.clone(),
+            )),
+            Arc::new(use_cases::scripts::RunScriptHooks::new(
+                world.clone(),
+                flag.clone(),
+                scripts,
+                execute_effects.clone(),
+            )),
+        );
+        let tags_uc = use_cases::TagUseCases::new(
+            Arc::new(use_cases::tags::ManageTags::new(tag.clone())),
+            Arc::new(use_cases::tags::SavedFilters::new(tag.clone(), clock.clone())),
+        );
+        let custom_fields_uc = use_cases::CustomFieldUseCases::new(
+            Arc::new(use_cases::custom_fields::ManageFieldSchema::new(
+                world.clone(),
+                clock.clone(),
+            )),
+            Arc::new(use_cases::custom_fields::FieldValues::new(
+                world.clone(),
+                custom_field.clone(),
+            )),
         );
+        let templates_uc = use_cases::TemplateUseCases::new(
+            Arc::new(use_cases::templates::ManageTemplates::new(
+                template.clone(),
+                character.clone(),
+                location.clone(),
+                inventory.clone(),
+                clock.clone(),
+            )),
+            Arc::new(use_cases::templates::InstantiateTemplate::new(
+                template.clone(),
+                character.clone(),
+                location.clone(),
+                inventory.clone(),
+            )),
+        );
+        let duplicate_uc = use_cases::DuplicateUseCases::new(Arc::new(
+            use_cases::duplicate::DuplicateEntity::new(
+                character.clone(),
+                location.clone(),
+                inventory.clone(),
+                challenge.clone(),
+                narrative.clone(),
+                tag.clone(),
+                custom_field.clone(),
+                clock.clone(),
+            ),
+        ));
+        let library_uc = use_cases::LibraryUseCases::new(
+            Arc::new(use_cases::library::ManageLibrary::new(
+                library.clone(),
+                character.clone(),
+                inventory.clone(),
+                challenge.clone(),
+                lore.clone(),
+                clock.clone(),
+            )),
+            Arc::new(use_cases::library::ImportLibraryEntry::new(
+                library.clone(),
+                character.clone(),
+                inventory.clone(),
+                challenge.clone(),
+                lore.clone(),
+                world.clone(),
+                skill.clone(),
+                clock.clone(),
+            )),
+        );
+
+        let drafts_uc =
+            use_cases::DraftUseCases::new(Arc::new(use_cases::drafts::ManageDrafts::new(
+                draft.clone(),
+                character.clone(),
+                location.clone(),
+                clock.clone(),
+            )));
+
+        let chronology_uc = use_cases::ChronologyUseCases::new(Arc::new(
+            use_cases::chronology::ManageChronology::new(
+                world.clone(),
+                character.clone(),
+                lore.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let factions_uc = use_cases::FactionUseCases::new(Arc::new(
+            use_cases::factions::ManageFactions::new(
+                faction.clone(),
+                character.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let dialogue_trees_uc = use_cases::DialogueTreeUseCases::new(
+            Arc::new(use_cases::dialogue_trees::ManageDialogueTrees::new(
+                dialogue_tree.clone(),
+                character.clone(),
+                narrative.clone(),
+                clock.clone(),
+            )),
+            Arc::new(use_cases::dialogue_trees::ScriptedBeats::new(
+                dialogue_tree.clone(),
+                character.clone(),
+                execute_effects.clone(),
+                clock.clone(),
+            )),
+        );
+
+        let crowds_uc = use_cases::CrowdUseCases::new(Arc::new(
+            use_cases::crowds::ManageCrowds::new(crowd.clone(), location.clone(), clock.clone()),
+        ));
+
+        let manage_names = Arc::new(use_cases::names::ManageNameGenerators::new(
+            name_generator.clone(),
+            character.clone(),
+            location.clone(),
+            random.clone(),
+            clock.clone(),
+        ));
+        let names_uc = use_cases::NameGeneratorUseCases::new(manage_names.clone());
+
+        let manage_economy = Arc::new(use_cases::economy::ManageEconomy::new(
+            economy.clone(),
+            inventory.clone(),
+            location.clone(),
+            flag.clone(),
+            world.clone(),
+            clock.clone(),
+        ));
+        let economy_uc = use_cases::EconomyUseCases::new(manage_economy.clone());
+
+        let property_uc =
+            use_cases::PropertyUseCases::new(Arc::new(use_cases::property::ManageProperties::new(
+                property.clone(),
+                player_character.clone(),
+                character.clone(),
+                location.clone(),
+                world.clone(),
+                clock.clone(),
+            )));
+
+        let companion_uc = use_cases::CompanionUseCases::new(Arc::new(
+            use_cases::companions::ManageCompanions::new(
+                companion.clone(),
+                player_character.clone(),
+                character.clone(),
+                world.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let injury_uc = use_cases::InjuryUseCases::new(Arc::new(
+            use_cases::injuries::ManageInjuries::new(
+                injury.clone(),
+                player_character.clone(),
+                challenge.clone(),
+                world.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let manage_secrets = Arc::new(use_cases::secrets::ManageSecrets::new(
+            pc_secret.clone(),
+            player_character.clone(),
+            character.clone(),
+            narrative.clone(),
+            clock.clone(),
+        ));
+        let secret_uc = use_cases::SecretUseCases::new(manage_secrets.clone());
+
+        let mail_uc = use_cases::MailUseCases::new(Arc::new(use_cases::mail::ManageMail::new(
+            letter.clone(),
+            player_character.clone(),
+            character.clone(),
+            location.clone(),
+            world.clone(),
+            settings_entity.clone(),
+            llm.clone(),
+            clock.clone(),
+        )));
+
+        let broadsheet_uc = use_cases::BroadsheetUseCases::new(Arc::new(
+            use_cases::broadsheet::ManageBroadsheets::new(
+                broadsheet.clone(),
+                narrative.clone(),
+                world.clone(),
+                settings_entity.clone(),
+                llm.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let simulation_uc = use_cases::SimulationUseCases::new(Arc::new(
+            use_cases::simulation::SimulateWorld::new(
+                world.clone(),
+                character.clone(),
+                location.clone(),
+                lore.clone(),
+                economy.clone(),
+                progress_clock.clone(),
+                narrative.clone(),
+                random.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let mortality_uc = use_cases::MortalityUseCases::new(Arc::new(
+            use_cases::mortality::ManageMortality::new(
+                player_character.clone(),
+                world.clone(),
+                narrative.clone(),
+                lore.clone(),
+                observation.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let npc_drafts_uc = use_cases::NpcDraftUseCases::new(Arc::new(
+            use_cases::npc_drafts::ManageNpcDrafts::new(
+                npc_draft.clone(),
+                crowd.clone(),
+                location.clone(),
+                lore.clone(),
+                character.clone(),
+                world.clone(),
+                settings_entity.clone(),
+                manage_names.clone(),
+                llm.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let manage_mentions = Arc::new(use_cases::mentions::ManageMentions::new(
+            mention.clone(),
+            character.clone(),
+            location.clone(),
+            inventory.clone(),
+            lore.clone(),
+            narrative.clone(),
+        ));
+        let mentions_uc = use_cases::MentionUseCases::new(manage_mentions.clone());
 
         let approve_suggestion =
             Arc::new(use_cases::approval::ApproveSuggestion::new(queue_port.clone()));
@@ -276,12 +905,30 @@ impl App {
                 approve_suggestion.clone(),
                 narrative.clone(),
                 queue_port.clone(),
+                plugin_tools.clone(),
+                Arc::new(use_cases::approval::RetryRejectedResponse::new(
+                    queue_port.clone(),
+                )),
+            )),
+            Arc::new(use_cases::approval::CoDm::new(
+                queue_port.clone(),
+                llm.clone(),
+                world.clone(),
+                settings_entity.clone(),
+                clock.clone(),
             )),
+            Arc::new(use_cases::approval::NpcAutonomy::new(
+                settings_entity.clone(),
+            )),
+            Arc::new(use_cases::approval::SelectApprovals::new(queue_port.clone())),
         );
 
         let generate_asset = Arc::new(use_cases::assets::GenerateAsset::new(
             assets.clone(),
             queue_port.clone(),
+            blob_store.clone(),
+            usage_repo.clone(),
+            settings_entity.clone(),
             clock.clone(),
         ));
         let expression_sheet = Arc::new(use_cases::assets::GenerateExpressionSheet::new(
@@ -290,25 +937,56 @@ impl App {
             queue_port.clone(),
             clock.clone(),
         ));
-        let assets_uc = use_cases::AssetUseCases::new(generate_asset, expression_sheet);
-
-        let world_uc = use_cases::WorldUseCases::new(
-            Arc::new(use_cases::world::ExportWorld::new(
-                world.clone(),
-                location.clone(),
+        let region_map = Arc::new(use_cases::assets::GenerateRegionMap::new(
+            location.clone(),
+            generate_asset.clone(),
+        ));
+        let assets_uc = use_cases::AssetUseCases::new(
+            generate_asset,
+            expression_sheet,
+            region_map,
+            Arc::new(use_cases::assets::AssetStorage::new(blob_store.clone())),
+            Arc::new(use_cases::assets::UploadAsset::new(
+                assets.clone(),
+                blob_store,
+                llm.clone(),
                 character.clone(),
-                inventory.clone(),
-                narrative.clone(),
-            )),
-            Arc::new(use_cases::world::ImportWorld::new(
-                world.clone(),
                 location.clone(),
-                character.clone(),
-                inventory.clone(),
-                narrative.clone(),
+                usage_repo.clone(),
+                clock.clone(),
             )),
+            Arc::new(use_cases::assets::ManageGallery::new(assets.clone())),
         );
 
+        let export_world = Arc::new(use_cases::world::ExportWorld::new(
+            world.clone(),
+            location.clone(),
+            character.clone(),
+            inventory.clone(),
+            narrative.clone(),
+        ));
+        let import_world = Arc::new(use_cases::world::ImportWorld::new(
+            world.clone(),
+            location.clone(),
+            character.clone(),
+            inventory.clone(),
+            narrative.clone(),
+        ));
+        let world_uc = use_cases::WorldUseCases::new(
+            export_world.clone(),
+            import_world.clone(),
+            Arc::new(use_cases::world::BranchWorld::new(
+                export_world,
+                import_world,
+                clock.clone(),
+            )),
+        );
+
+        let progress_clock_ops = Arc::new(use_cases::progress_clock::ProgressClockOps::new(
+            progress_clock.clone(),
+            clock.clone(),
+        ));
+
         let queues = use_cases::QueueUseCases::new(
             Arc::new(use_cases::queues::ProcessPlayerAction::new(
                 queue_port.clone(),
@@ -317,26 +995,29 @@ impl App {
                 staging.clone(),
                 scene.clone(),
                 world.clone(),
+                location.clone(),
                 narrative.clone(),
+                manage_mentions,
+                manage_economy,
+                make_noise.clone(),
+                challenge_uc.feedback.clone(),
+                progress_clock_ops.clone(),
+                manage_secrets,
+                factions_uc.manage.clone(),
+                settings_entity.clone(),
+                clock.clone(),
+                conversation_queues,
+                safety_signals.clone(),
             )),
             Arc::new(use_cases::queues::ProcessLlmRequest::new(
                 queue_port.clone(),
                 llm.clone(),
+                world.clone(),
+                settings_entity.clone(),
+                plugin_tools.clone(),
             )),
         );
 
-        let execute_effects = Arc::new(use_cases::narrative::ExecuteEffects::new(
-            inventory.clone(),
-            challenge.clone(),
-            narrative.clone(),
-            character.clone(),
-            observation.clone(),
-            player_character.clone(),
-            scene.clone(),
-            flag.clone(),
-            world.clone(),
-            clock.clone(),
-        ));
         let narrative_events = Arc::new(use_cases::narrative::NarrativeEventOps::new(
             narrative.clone(),
             execute_effects.clone(),
@@ -370,6 +1051,8 @@ impl App {
         let staging_uc = use_cases::StagingUseCases::new(
             Arc::new(use_cases::staging::RequestStagingApproval::new(
                 character.clone(),
+                companion.clone(),
+                faction.clone(),
                 staging.clone(),
                 location.clone(),
                 world.clone(),
@@ -381,7 +1064,9 @@ impl App {
             Arc::new(use_cases::staging::RegenerateStagingSuggestions::new(
                 location.clone(),
                 character.clone(),
+                faction.clone(),
                 llm.clone(),
+                settings_repo.clone(),
             )),
             Arc::new(use_cases::staging::ApproveStagingRequest::new(
                 staging.clone(),
@@ -393,6 +1078,7 @@ impl App {
             )),
             Arc::new(use_cases::staging::AutoApproveStagingTimeout::new(
                 character.clone(),
+                companion.clone(),
                 staging.clone(),
                 world.clone(),
                 location.clone(),
@@ -402,8 +1088,36 @@ impl App {
             )),
         );
 
-        // Create settings entity
-        let settings_entity = Arc::new(entities::Settings::new(settings_repo.clone()));
+        let flashback_uc = use_cases::FlashbackUseCases::new(Arc::new(
+            use_cases::flashback::ManageFlashbacks::new(
+                world.clone(),
+                location.clone(),
+                staging.clone(),
+                region_state.clone(),
+                staging_uc.approve.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let parallel_scenes_uc = use_cases::ParallelSceneUseCases::new(Arc::new(
+            use_cases::parallel_scenes::ManageParallelScenes::new(
+                world.clone(),
+                player_character.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let spotlight_uc = use_cases::SpotlightUseCases::new(Arc::new(
+            use_cases::spotlight::ManageSpotlight::new(
+                world.clone(),
+                player_character.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let pacing_uc = use_cases::PacingUseCases::new(Arc::new(
+            use_cases::pacing::PacingTimers::new(clock.clone()),
+        ));
 
         let npc_uc = use_cases::NpcUseCases::new(
             Arc::new(use_cases::npc::NpcDisposition::new(
@@ -433,15 +1147,93 @@ impl App {
         let lore_uc =
             use_cases::LoreUseCases::new(Arc::new(use_cases::lore::LoreOps::new(lore.clone())));
 
-        let location_events_uc = use_cases::LocationEventUseCases::new(Arc::new(
-            use_cases::location_events::TriggerLocationEvent::new(location.clone()),
+        let knowledge_uc = use_cases::KnowledgeUseCases::new(Arc::new(
+            use_cases::knowledge::ShareKnowledge::new(
+                player_character.clone(),
+                character.clone(),
+                lore.clone(),
+                observation.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let progress_clock_uc = use_cases::ProgressClockUseCases::new(progress_clock_ops);
+
+        let safety_uc = use_cases::SafetyUseCases::new(safety_signals);
+
+        let trade_uc = use_cases::TradeUseCases::new(Arc::new(use_cases::trade::Trades::new(
+            player_character.clone(),
+            world.clone(),
+            narrative.clone(),
+            clock.clone(),
+        )));
+
+        let dice_uc = use_cases::DiceUseCases::new(Arc::new(use_cases::dice::RollDice::new(
+            player_character.clone(),
+            random.clone(),
+            clock.clone(),
+        )));
+
+        let transcription_uc = use_cases::TranscriptionUseCases::new(Arc::new(
+            use_cases::transcription::TranscribeAudio::new(transcription),
+        ));
+
+        let tutorial_uc = use_cases::TutorialUseCases::new(
+            Arc::new(use_cases::tutorial::CreateTutorialWorld::new(
+                world.clone(),
+                location.clone(),
+                character.clone(),
+                challenge.clone(),
+                staging.clone(),
+                clock.clone(),
+            )),
+            Arc::new(use_cases::tutorial::TutorialProgress::new(
+                world.clone(),
+                flag.clone(),
+            )),
+        );
+
+        let session_zero_uc = use_cases::SessionZeroUseCases::new(Arc::new(
+            use_cases::session_zero::CreateSessionZeroWorld::new(
+                world.clone(),
+                location.clone(),
+                skill.clone(),
+                npc_draft.clone(),
+                clock.clone(),
+            ),
+        ));
+
+        let location_events_uc = use_cases::LocationEventUseCases::new(
+            Arc::new(use_cases::location_events::TriggerLocationEvent::new(
+                location.clone(),
+            )),
+            make_noise,
+        );
+
+        let interactions_uc = use_cases::InteractionUseCases::new(Arc::new(
+            use_cases::interactions::InteractionLibrary::new(
+                interaction.clone(),
+                scene.clone(),
+                player_character.clone(),
+                world.clone(),
+            ),
+        ));
+
+        let rule_system_uc = use_cases::RuleSystemUseCases::new(Arc::new(
+            use_cases::rule_system::ChangeRuleSystem::new(
+                world.clone(),
+                skill.clone(),
+                challenge.clone(),
+                player_character.clone(),
+                clock.clone(),
+            ),
         ));
 
         // Create custom condition evaluator for LLM-based condition/trigger evaluation
         let custom_condition = Arc::new(use_cases::CustomConditionEvaluator::new(llm.clone()));
 
         let management = use_cases::ManagementUseCases::new(
-            use_cases::management::WorldCrud::new(world.clone(), clock.clone()),
+            use_cases::management::WorldCrud::new(world.clone(), location.clone(), clock.clone()),
             use_cases::management::CharacterCrud::new(character.clone(), clock.clone()),
             use_cases::management::LocationCrud::new(location.clone()),
             use_cases::management::PlayerCharacterCrud::new(
@@ -464,6 +1256,86 @@ impl App {
             use_cases::management::SkillCrud::new(skill.clone()),
         );
 
+        let setup_uc = use_cases::SetupUseCases::new(
+            Arc::new(use_cases::setup::SetupStatus::new(
+                settings_entity.clone(),
+                service_probe.clone(),
+                env_connections.clone(),
+            )),
+            Arc::new(use_cases::setup::CheckConnections::new(
+                settings_entity.clone(),
+                service_probe.clone(),
+                env_connections.clone(),
+            )),
+            Arc::new(use_cases::setup::SaveConnections::new(
+                settings_entity.clone(),
+                service_probe.clone(),
+                env_connections,
+            )),
+        );
+
+        let health_uc = use_cases::HealthUseCases::new(Arc::new(
+            use_cases::health::CheckDependencies::new(
+                service_probe,
+                running_connections,
+                use_cases::health::ASSETS_DIR,
+                use_cases::health::MIN_FREE_DISK_BYTES,
+            ),
+        ));
+
+        let analytics_uc = use_cases::AnalyticsUseCases::new(
+            Arc::new(use_cases::analytics::GetCampaignAnalytics::new(
+                player_character.clone(),
+                character.clone(),
+                location.clone(),
+                narrative.clone(),
+                challenge.clone(),
+                observation.clone(),
+            )),
+            Arc::new(use_cases::analytics::GetUnusedContent::new(
+                player_character.clone(),
+                character.clone(),
+                location.clone(),
+                narrative.clone(),
+                challenge.clone(),
+                observation.clone(),
+                lore.clone(),
+            )),
+        );
+
+        let prep_uc =
+            use_cases::PrepUseCases::new(Arc::new(use_cases::prep::GeneratePrepChecklist::new(
+                world.clone(),
+                player_character.clone(),
+                location.clone(),
+                character.clone(),
+                staging.clone(),
+                challenge.clone(),
+                progress_clock.clone(),
+            )));
+
+        let usage_uc = use_cases::UsageUseCases::new(
+            Arc::new(use_cases::usage::GetUsage::new(
+                usage_repo.clone(),
+                settings_entity.clone(),
+                clock.clone(),
+            )),
+            Arc::new(use_cases::usage::CheckUsageQuotas::new(
+                usage_repo,
+                settings_entity.clone(),
+                clock.clone(),
+            )),
+        );
+
+        let diagnostics_uc = use_cases::DiagnosticsUseCases::new(Arc::new(
+            use_cases::diagnostics::ExportDiagnostics::new(
+                diagnostics.clone(),
+                queue_port.clone(),
+                world.clone(),
+                clock.clone(),
+            ),
+        ));
+
         let settings = settings_entity;
 
         let join_world = Arc::new(use_cases::session::JoinWorld::new(
@@ -476,8 +1348,16 @@ impl App {
         let join_world_flow =
             Arc::new(use_cases::session::JoinWorldFlow::new(join_world.clone()));
         let directorial_update = Arc::new(use_cases::session::DirectorialUpdate::new());
-        let session =
-            use_cases::SessionUseCases::new(join_world, join_world_flow, directorial_update);
+        let control_pc = Arc::new(use_cases::session::ControlPc::new(
+            join_world.clone(),
+            player_character.clone(),
+        ));
+        let session = use_cases::SessionUseCases::new(
+            join_world,
+            join_world_flow,
+            directorial_update,
+            control_pc,
+        );
 
         let use_cases = UseCases {
             movement,
@@ -492,24 +1372,70 @@ impl App {
             queues,
             narrative: narrative_uc,
             player_action,
+            scripts: scripts_uc,
             time: time_uc,
             visual_state: visual_state_uc,
             management,
             session,
             settings,
+            setup: setup_uc,
+            health: health_uc,
             staging: staging_uc,
             npc: npc_uc,
             story_events: story_events_uc,
+            tags: tags_uc,
+            custom_fields: custom_fields_uc,
+            templates: templates_uc,
+            duplicate: duplicate_uc,
+            library: library_uc,
+            drafts: drafts_uc,
+            chronology: chronology_uc,
+            crowds: crowds_uc,
+            npc_drafts: npc_drafts_uc,
+            mentions: mentions_uc,
+            names: names_uc,
+            economy: economy_uc,
+            property: property_uc,
+            companion: companion_uc,
+            injury: injury_uc,
+            secret: secret_uc,
+            mail: mail_uc,
+            broadsheet: broadsheet_uc,
+            factions: factions_uc,
+            dialogue_trees: dialogue_trees_uc,
+            flashback: flashback_uc,
+            parallel_scenes: parallel_scenes_uc,
+            spotlight: spotlight_uc,
+            pacing: pacing_uc,
+            simulation: simulation_uc,
+            mortality: mortality_uc,
             lore: lore_uc,
+            knowledge: knowledge_uc,
+            progress_clock: progress_clock_uc,
+            safety: safety_uc,
+            trade: trade_uc,
+            travel: travel_uc,
+            dice: dice_uc,
+            transcription: transcription_uc,
+            tutorial: tutorial_uc,
+            session_zero: session_zero_uc,
+            usage: usage_uc,
+            analytics: analytics_uc,
+            prep: prep_uc,
+            diagnostics: diagnostics_uc,
             location_events: location_events_uc,
+            interactions: interactions_uc,
+            rule_system: rule_system_uc,
             custom_condition,
         };
 
         Self {
             entities,
             use_cases,
-            queue: queue,
+            queue: queue_port,
             llm,
+            circuit_breakers,
+            diagnostics,
         }
     }
 }
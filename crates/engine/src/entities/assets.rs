@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 use uuid::Uuid;
-use wrldbldr_domain::{self as domain, AssetId};
+use wrldbldr_domain::{self as domain, AssetId, WorldId};
 
 use crate::infrastructure::ports::{AssetRepo, ImageGenPort, ImageRequest, RepoError};
 
@@ -39,6 +39,13 @@ impl Assets {
         self.repo.list_for_entity(entity_type, entity_id).await
     }
 
+    pub async fn list_for_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<domain::GalleryAsset>, RepoError> {
+        self.repo.list_for_world(world_id).await
+    }
+
     pub async fn set_active(
         &self,
         entity_type: &str,
@@ -0,0 +1,39 @@
+//! Broadsheet operations.
+//!
+//! Issues of a world's weekly news digest, drafted or published.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, BroadsheetId, WorldId};
+
+use crate::infrastructure::ports::{BroadsheetRepo, RepoError};
+
+/// Broadsheet operations.
+pub struct Broadsheet {
+    repo: Arc<dyn BroadsheetRepo>,
+}
+
+impl Broadsheet {
+    pub fn new(repo: Arc<dyn BroadsheetRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: BroadsheetId) -> Result<Option<domain::Broadsheet>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, broadsheet: &domain::Broadsheet) -> Result<(), RepoError> {
+        self.repo.save(broadsheet).await
+    }
+
+    pub async fn delete(&self, id: BroadsheetId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_in_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<domain::Broadsheet>, RepoError> {
+        self.repo.list_in_world(world_id).await
+    }
+}
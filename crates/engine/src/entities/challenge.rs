@@ -1,7 +1,7 @@
 //! Challenge entity operations.
 
 use std::sync::Arc;
-use wrldbldr_domain::{self as domain, ChallengeId, SceneId, WorldId};
+use wrldbldr_domain::{self as domain, ChallengeId, PlayerCharacterId, SceneId, WorldId};
 
 use crate::infrastructure::ports::{ChallengeRepo, RepoError};
 
@@ -66,4 +66,37 @@ impl Challenge {
     pub async fn get_resolved(&self, world_id: WorldId) -> Result<Vec<ChallengeId>, RepoError> {
         self.repo.get_resolved_challenges(world_id).await
     }
+
+    pub async fn save_suggestion_decision(
+        &self,
+        decision: &domain::SuggestionDecision,
+    ) -> Result<(), RepoError> {
+        self.repo.save_suggestion_decision(decision).await
+    }
+
+    /// A world's latest suggestion decisions, newest first
+    pub async fn list_suggestion_decisions(
+        &self,
+        world_id: WorldId,
+        limit: usize,
+    ) -> Result<Vec<domain::SuggestionDecision>, RepoError> {
+        self.repo.list_suggestion_decisions(world_id, limit).await
+    }
+
+    pub async fn save_resolution(
+        &self,
+        resolution: &domain::ChallengeResolution,
+    ) -> Result<(), RepoError> {
+        self.repo.save_resolution(resolution).await
+    }
+
+    /// A world's latest resolutions, newest first, optionally for one PC
+    pub async fn list_resolutions(
+        &self,
+        world_id: WorldId,
+        pc_id: Option<PlayerCharacterId>,
+        limit: usize,
+    ) -> Result<Vec<domain::ChallengeResolution>, RepoError> {
+        self.repo.list_resolutions(world_id, pc_id, limit).await
+    }
 }
@@ -0,0 +1,112 @@
+//! Companion operations.
+//!
+//! NPCs bound to player characters, and moving them along with their PC.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    self as domain, Character, CharacterId, CompanionId, PlayerCharacter, PlayerCharacterId,
+    RegionId, StagedNpc,
+};
+
+use crate::infrastructure::ports::{CharacterRepo, CompanionRepo, RepoError};
+
+/// Companion operations.
+pub struct Companion {
+    repo: Arc<dyn CompanionRepo>,
+    character_repo: Arc<dyn CharacterRepo>,
+}
+
+impl Companion {
+    pub fn new(repo: Arc<dyn CompanionRepo>, character_repo: Arc<dyn CharacterRepo>) -> Self {
+        Self {
+            repo,
+            character_repo,
+        }
+    }
+
+    pub async fn get(&self, id: CompanionId) -> Result<Option<domain::Companion>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, companion: &domain::Companion) -> Result<(), RepoError> {
+        self.repo.save(companion).await
+    }
+
+    pub async fn delete(&self, id: CompanionId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_for_pc(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<domain::Companion>, RepoError> {
+        self.repo.list_for_pc(pc_id).await
+    }
+
+    pub async fn get_for_character(
+        &self,
+        character_id: CharacterId,
+    ) -> Result<Option<domain::Companion>, RepoError> {
+        self.repo.get_for_character(character_id).await
+    }
+
+    /// Move the PC's following companions into `region_id`, returning them
+    /// staged as present. Companions whose NPC is gone are skipped.
+    pub async fn travel_with(
+        &self,
+        pc: &PlayerCharacter,
+        region_id: RegionId,
+    ) -> Result<Vec<StagedNpc>, RepoError> {
+        let mut staged = Vec::new();
+        for companion in self.repo.list_for_pc(pc.id).await? {
+            if !companion.follows_pc {
+                continue;
+            }
+            let Some(character) = self.character_repo.get(companion.character_id).await? else {
+                continue;
+            };
+            self.character_repo
+                .update_position(character.id, region_id)
+                .await?;
+            staged.push(staged_companion(
+                character,
+                format!("Travelling with {}", pc.name),
+            ));
+        }
+        Ok(staged)
+    }
+
+    /// Following companions whose NPC is in `region_id`
+    pub async fn staged_in_region(&self, region_id: RegionId) -> Result<Vec<StagedNpc>, RepoError> {
+        let mut staged = Vec::new();
+        for character in self.character_repo.list_in_region(region_id).await? {
+            let follows = self
+                .repo
+                .get_for_character(character.id)
+                .await?
+                .is_some_and(|c| c.follows_pc);
+            if follows {
+                staged.push(staged_companion(
+                    character,
+                    "Companion travelling with a PC".to_string(),
+                ));
+            }
+        }
+        Ok(staged)
+    }
+}
+
+fn staged_companion(character: Character, reasoning: String) -> StagedNpc {
+    StagedNpc {
+        character_id: character.id,
+        name: character.name,
+        sprite_asset: character.sprite_asset,
+        portrait_asset: character.portrait_asset,
+        is_present: true,
+        is_hidden_from_players: false,
+        reasoning,
+        mood: character.default_mood,
+        has_incomplete_data: false,
+    }
+}
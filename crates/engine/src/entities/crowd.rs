@@ -0,0 +1,54 @@
+//! Crowd operations.
+//!
+//! Background crowds staged in regions. Players only ever see the crowds
+//! that are present; the DM sees them all.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, CrowdId, RegionId, WorldId};
+
+use crate::infrastructure::ports::{CrowdRepo, RepoError};
+
+/// Crowd operations.
+pub struct Crowd {
+    repo: Arc<dyn CrowdRepo>,
+}
+
+impl Crowd {
+    pub fn new(repo: Arc<dyn CrowdRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: CrowdId) -> Result<Option<domain::Crowd>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, crowd: &domain::Crowd) -> Result<(), RepoError> {
+        self.repo.save(crowd).await
+    }
+
+    pub async fn delete(&self, id: CrowdId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<domain::Crowd>, RepoError> {
+        self.repo.list_for_world(world_id).await
+    }
+
+    pub async fn list_in_region(
+        &self,
+        region_id: RegionId,
+    ) -> Result<Vec<domain::Crowd>, RepoError> {
+        self.repo.list_in_region(region_id).await
+    }
+
+    /// The crowds players see in a region
+    pub async fn list_present_in_region(
+        &self,
+        region_id: RegionId,
+    ) -> Result<Vec<domain::Crowd>, RepoError> {
+        let mut crowds = self.repo.list_in_region(region_id).await?;
+        crowds.retain(|c| c.is_present);
+        Ok(crowds)
+    }
+}
@@ -0,0 +1,42 @@
+//! Custom field entity operations.
+//!
+//! Values of the DM-defined fields on characters, locations and items. The
+//! field schema lives on the world (see [`wrldbldr_domain::World::custom_fields`]).
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{CustomFieldValues, EntityType, WorldId};
+
+use crate::infrastructure::ports::{CustomFieldRepo, RepoError};
+
+/// Custom field entity operations.
+pub struct CustomField {
+    repo: Arc<dyn CustomFieldRepo>,
+}
+
+impl CustomField {
+    pub fn new(repo: Arc<dyn CustomFieldRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get_values(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<CustomFieldValues, RepoError> {
+        self.repo.get_values(entity_type, entity_id).await
+    }
+
+    pub async fn set_values(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        values: &CustomFieldValues,
+    ) -> Result<(), RepoError> {
+        self.repo
+            .set_values(world_id, entity_type, entity_id, values)
+            .await
+    }
+}
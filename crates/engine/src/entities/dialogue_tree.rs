@@ -0,0 +1,39 @@
+//! Dialogue tree operations.
+//!
+//! Branching conversations the DM wrote for scripted beats.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, DialogueTreeId, WorldId};
+
+use crate::infrastructure::ports::{DialogueTreeRepo, RepoError};
+
+/// Dialogue tree operations.
+pub struct DialogueTree {
+    repo: Arc<dyn DialogueTreeRepo>,
+}
+
+impl DialogueTree {
+    pub fn new(repo: Arc<dyn DialogueTreeRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: DialogueTreeId) -> Result<Option<domain::DialogueTree>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, tree: &domain::DialogueTree) -> Result<(), RepoError> {
+        self.repo.save(tree).await
+    }
+
+    pub async fn delete(&self, id: DialogueTreeId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_in_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<domain::DialogueTree>, RepoError> {
+        self.repo.list_in_world(world_id).await
+    }
+}
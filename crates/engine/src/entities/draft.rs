@@ -0,0 +1,42 @@
+//! Content draft operations.
+//!
+//! Revision histories of entity text fields. Publishing a revision to its
+//! entity is a use case; this only stores the drafts.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{ContentDraft, ContentDraftId, EntityType};
+
+use crate::infrastructure::ports::{ContentDraftRepo, RepoError};
+
+/// Content draft operations.
+pub struct Draft {
+    repo: Arc<dyn ContentDraftRepo>,
+}
+
+impl Draft {
+    pub fn new(repo: Arc<dyn ContentDraftRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: ContentDraftId) -> Result<Option<ContentDraft>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, draft: &ContentDraft) -> Result<(), RepoError> {
+        self.repo.save(draft).await
+    }
+
+    pub async fn delete(&self, id: ContentDraftId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_for_entity(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<ContentDraft>, RepoError> {
+        self.repo.list_for_entity(entity_type, entity_id).await
+    }
+}
@@ -0,0 +1,43 @@
+//! Economy operations.
+//!
+//! Market modifiers, which change item prices by place, category and world
+//! flags.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{MarketModifier, MarketModifierId, WorldId};
+
+use crate::infrastructure::ports::{EconomyRepo, RepoError};
+
+/// Economy operations.
+pub struct Economy {
+    repo: Arc<dyn EconomyRepo>,
+}
+
+impl Economy {
+    pub fn new(repo: Arc<dyn EconomyRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get_modifier(
+        &self,
+        id: MarketModifierId,
+    ) -> Result<Option<MarketModifier>, RepoError> {
+        self.repo.get_modifier(id).await
+    }
+
+    pub async fn save_modifier(&self, modifier: &MarketModifier) -> Result<(), RepoError> {
+        self.repo.save_modifier(modifier).await
+    }
+
+    pub async fn delete_modifier(&self, id: MarketModifierId) -> Result<(), RepoError> {
+        self.repo.delete_modifier(id).await
+    }
+
+    pub async fn list_modifiers(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<MarketModifier>, RepoError> {
+        self.repo.list_modifiers(world_id).await
+    }
+}
@@ -0,0 +1,53 @@
+//! Faction operations.
+//!
+//! Organizations NPCs belong to, with their goals, rivals and standing with
+//! the party.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, CharacterId, FactionId, WorldId};
+
+use crate::infrastructure::ports::{FactionRepo, RepoError};
+
+/// Faction operations.
+pub struct Faction {
+    repo: Arc<dyn FactionRepo>,
+}
+
+impl Faction {
+    pub fn new(repo: Arc<dyn FactionRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: FactionId) -> Result<Option<domain::Faction>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, faction: &domain::Faction) -> Result<(), RepoError> {
+        self.repo.save(faction).await
+    }
+
+    pub async fn delete(&self, id: FactionId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_in_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<domain::Faction>, RepoError> {
+        self.repo.list_in_world(world_id).await
+    }
+
+    /// Factions in a world that an NPC belongs to
+    pub async fn list_for_member(
+        &self,
+        world_id: WorldId,
+        character_id: CharacterId,
+    ) -> Result<Vec<domain::Faction>, RepoError> {
+        let factions = self.repo.list_in_world(world_id).await?;
+        Ok(factions
+            .into_iter()
+            .filter(|f| f.member(character_id).is_some())
+            .collect())
+    }
+}
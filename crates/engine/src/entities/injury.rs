@@ -0,0 +1,46 @@
+//! Injury operations.
+//!
+//! Lasting harm to player characters, healed as game time passes.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, InjuryId, PlayerCharacterId, WorldId};
+
+use crate::infrastructure::ports::{InjuryRepo, RepoError};
+
+/// Injury operations.
+pub struct Injury {
+    repo: Arc<dyn InjuryRepo>,
+}
+
+impl Injury {
+    pub fn new(repo: Arc<dyn InjuryRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: InjuryId) -> Result<Option<domain::Injury>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, injury: &domain::Injury) -> Result<(), RepoError> {
+        self.repo.save(injury).await
+    }
+
+    pub async fn delete(&self, id: InjuryId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_for_pc(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<domain::Injury>, RepoError> {
+        self.repo.list_for_pc(pc_id).await
+    }
+
+    pub async fn list_active_in_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<domain::Injury>, RepoError> {
+        self.repo.list_active_in_world(world_id).await
+    }
+}
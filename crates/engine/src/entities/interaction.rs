@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use wrldbldr_domain::{self as domain, InteractionId, SceneId};
+use wrldbldr_domain::{self as domain, InteractionId, PlayerCharacterId, SceneId};
 
 use crate::infrastructure::ports::{InteractionRepo, RepoError};
 
@@ -37,4 +37,16 @@ impl Interaction {
     pub async fn delete(&self, id: InteractionId) -> Result<(), RepoError> {
         self.repo.delete(id).await
     }
+
+    pub async fn get_usage(
+        &self,
+        interaction_id: InteractionId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Option<domain::InteractionUsage>, RepoError> {
+        self.repo.get_usage(interaction_id, pc_id).await
+    }
+
+    pub async fn save_usage(&self, usage: &domain::InteractionUsage) -> Result<(), RepoError> {
+        self.repo.save_usage(usage).await
+    }
 }
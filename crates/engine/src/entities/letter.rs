@@ -0,0 +1,50 @@
+//! Letter operations.
+//!
+//! In-world mail between PCs and NPCs, in transit or delivered.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, LetterId, PlayerCharacterId, WorldId};
+
+use crate::infrastructure::ports::{LetterRepo, RepoError};
+
+/// Letter operations.
+pub struct Letter {
+    repo: Arc<dyn LetterRepo>,
+}
+
+impl Letter {
+    pub fn new(repo: Arc<dyn LetterRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: LetterId) -> Result<Option<domain::Letter>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, letter: &domain::Letter) -> Result<(), RepoError> {
+        self.repo.save(letter).await
+    }
+
+    pub async fn delete(&self, id: LetterId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_for_pc(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<domain::Letter>, RepoError> {
+        self.repo.list_for_pc(pc_id).await
+    }
+
+    pub async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<domain::Letter>, RepoError> {
+        self.repo.list_in_world(world_id).await
+    }
+
+    pub async fn list_undelivered_in_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<domain::Letter>, RepoError> {
+        self.repo.list_undelivered_in_world(world_id).await
+    }
+}
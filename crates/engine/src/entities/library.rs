@@ -0,0 +1,38 @@
+//! Content library operations.
+//!
+//! Per-user snapshots of characters, items, challenges and lore that can be
+//! imported into any of the user's worlds. Importing is a use case; this only
+//! stores the entries.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{LibraryEntry, LibraryEntryId};
+
+use crate::infrastructure::ports::{LibraryRepo, RepoError};
+
+/// Content library operations.
+pub struct Library {
+    repo: Arc<dyn LibraryRepo>,
+}
+
+impl Library {
+    pub fn new(repo: Arc<dyn LibraryRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: LibraryEntryId) -> Result<Option<LibraryEntry>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, entry: &LibraryEntry) -> Result<(), RepoError> {
+        self.repo.save(entry).await
+    }
+
+    pub async fn delete(&self, id: LibraryEntryId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_for_owner(&self, owner_id: &str) -> Result<Vec<LibraryEntry>, RepoError> {
+        self.repo.list_for_owner(owner_id).await
+    }
+}
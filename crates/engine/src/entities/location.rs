@@ -2,7 +2,8 @@
 
 use std::sync::Arc;
 use wrldbldr_domain::{
-    self as domain, LocationConnection, LocationId, RegionConnection, RegionId, WorldId,
+    self as domain, LightLevel, LocationConnection, LocationId, LocationType, RegionConnection,
+    RegionId, TimeOfDay, WorldId,
 };
 
 use crate::infrastructure::ports::{LocationRepo, RepoError};
@@ -98,6 +99,26 @@ impl Location {
         self.repo.list_regions_in_location(location_id).await
     }
 
+    /// How well lit a region is at a time of day
+    ///
+    /// A region whose location is gone counts as indoors.
+    pub async fn region_light(
+        &self,
+        region: &domain::Region,
+        time: TimeOfDay,
+    ) -> Result<LightLevel, RepoError> {
+        let location_type = match region.lighting {
+            Some(level) => return Ok(level),
+            None => self
+                .repo
+                .get_location(region.location_id)
+                .await?
+                .map(|l| l.location_type)
+                .unwrap_or(LocationType::Interior),
+        };
+        Ok(region.light_level(location_type, time))
+    }
+
     /// Delete a region by ID.
     ///
     /// Uses DETACH DELETE to remove all relationships.
@@ -0,0 +1,82 @@
+//! Entity mention operations.
+//!
+//! DM-given aliases for entities, and the mentions of entities found in
+//! descriptions, lore and dialogue.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{EntityMention, EntityType, WorldId};
+
+use crate::infrastructure::ports::{MentionRepo, RepoError};
+
+/// Entity mention operations.
+pub struct Mention {
+    repo: Arc<dyn MentionRepo>,
+}
+
+impl Mention {
+    pub fn new(repo: Arc<dyn MentionRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn list_aliases(
+        &self,
+        world_id: WorldId,
+    ) -> Result<HashMap<(EntityType, Uuid), Vec<String>>, RepoError> {
+        self.repo.list_aliases(world_id).await
+    }
+
+    pub async fn set_aliases(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        aliases: &[String],
+    ) -> Result<(), RepoError> {
+        self.repo
+            .set_aliases(world_id, entity_type, entity_id, aliases)
+            .await
+    }
+
+    pub async fn replace_mentions(
+        &self,
+        world_id: WorldId,
+        source_type: EntityType,
+        source_id: Uuid,
+        mentions: &[EntityMention],
+    ) -> Result<(), RepoError> {
+        self.repo
+            .replace_mentions(world_id, source_type, source_id, mentions)
+            .await
+    }
+
+    pub async fn clear_mentions(&self, world_id: WorldId) -> Result<(), RepoError> {
+        self.repo.clear_mentions(world_id).await
+    }
+
+    pub async fn forget_entity(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<(), RepoError> {
+        self.repo.forget_entity(entity_type, entity_id).await
+    }
+
+    pub async fn list_mentions_of(
+        &self,
+        target_type: EntityType,
+        target_id: Uuid,
+    ) -> Result<Vec<EntityMention>, RepoError> {
+        self.repo.list_mentions_of(target_type, target_id).await
+    }
+
+    pub async fn list_mentions_in(
+        &self,
+        source_type: EntityType,
+        source_id: Uuid,
+    ) -> Result<Vec<EntityMention>, RepoError> {
+        self.repo.list_mentions_in(source_type, source_id).await
+    }
+}
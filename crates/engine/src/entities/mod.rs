@@ -5,42 +5,80 @@
 
 pub mod act;
 pub mod assets;
+pub mod broadsheet;
 pub mod challenge;
 pub mod character;
+pub mod companion;
+pub mod crowd;
+pub mod custom_field;
+pub mod dialogue_tree;
+pub mod draft;
+pub mod economy;
+pub mod faction;
 pub mod flag;
 pub mod goal;
+pub mod injury;
 pub mod interaction;
 pub mod inventory;
+pub mod letter;
+pub mod library;
 pub mod location;
 pub mod location_state;
 pub mod lore;
+pub mod mention;
+pub mod name_generator;
 pub mod narrative;
+pub mod npc_draft;
 pub mod observation;
+pub mod pc_secret;
 pub mod player_character;
+pub mod progress_clock;
+pub mod property;
 pub mod region_state;
 pub mod scene;
 pub mod settings;
 pub mod skill;
 pub mod staging;
+pub mod tag;
+pub mod template;
 pub mod world;
 
 pub use act::Act;
 pub use assets::Assets;
+pub use broadsheet::Broadsheet;
 pub use challenge::Challenge;
 pub use character::Character;
+pub use companion::Companion;
+pub use crowd::Crowd;
+pub use custom_field::CustomField;
+pub use dialogue_tree::DialogueTree;
+pub use draft::Draft;
+pub use economy::Economy;
+pub use faction::Faction;
 pub use flag::Flag;
 pub use goal::Goal;
+pub use injury::Injury;
 pub use interaction::Interaction;
 pub use inventory::Inventory;
+pub use letter::Letter;
+pub use library::Library;
 pub use location::Location;
 pub use location_state::LocationStateEntity;
 pub use lore::Lore;
+pub use mention::Mention;
+pub use name_generator::NameGenerator;
 pub use narrative::Narrative;
+pub use npc_draft::NpcDraft;
 pub use observation::Observation;
+pub use pc_secret::PcSecret;
 pub use player_character::PlayerCharacter;
+pub use progress_clock::ProgressClock;
+pub use property::Property;
 pub use region_state::RegionStateEntity;
 pub use scene::{Scene, SceneResolutionContext, SceneResolutionResult};
 pub use settings::{Settings, SettingsError};
 pub use skill::Skill;
 pub use staging::Staging;
+pub use tag::Tag;
+pub use template::Template;
 pub use world::{World, WorldError};
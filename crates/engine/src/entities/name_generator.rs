@@ -0,0 +1,42 @@
+//! Name generator operations.
+//!
+//! How each culture in a world names its people, for naming new NPCs.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, NameGeneratorId, WorldId};
+
+use crate::infrastructure::ports::{NameGeneratorRepo, RepoError};
+
+/// Name generator operations.
+pub struct NameGenerator {
+    repo: Arc<dyn NameGeneratorRepo>,
+}
+
+impl NameGenerator {
+    pub fn new(repo: Arc<dyn NameGeneratorRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(
+        &self,
+        id: NameGeneratorId,
+    ) -> Result<Option<domain::NameGenerator>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, generator: &domain::NameGenerator) -> Result<(), RepoError> {
+        self.repo.save(generator).await
+    }
+
+    pub async fn delete(&self, id: NameGeneratorId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_for_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<domain::NameGenerator>, RepoError> {
+        self.repo.list_for_world(world_id).await
+    }
+}
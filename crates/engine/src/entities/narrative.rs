@@ -10,7 +10,7 @@ use wrldbldr_domain::{
 };
 
 use crate::infrastructure::ports::{
-    ChallengeRepo, CharacterRepo, ClockPort, FlagRepo, LocationRepo, NarrativeRepo,
+    ChallengeRepo, CharacterRepo, ClockPort, DialogueCount, FlagRepo, LocationRepo, NarrativeRepo,
     ObservationRepo, PlayerCharacterRepo, RepoError, SceneRepo, WorldRepo,
 };
 
@@ -191,7 +191,19 @@ impl Narrative {
         self.repo.get_story_event(id).await
     }
 
+    /// Save a story event. Events recorded while the world is in a
+    /// flashback are tagged [`domain::FLASHBACK_TAG`].
     pub async fn save_story_event(&self, event: &domain::StoryEvent) -> Result<(), RepoError> {
+        let in_flashback = self
+            .world_repo
+            .get(event.world_id)
+            .await?
+            .is_some_and(|world| world.flashback.is_some());
+        if in_flashback && !event.tags.iter().any(|t| t == domain::FLASHBACK_TAG) {
+            let mut event = event.clone();
+            event.tags.push(domain::FLASHBACK_TAG.to_string());
+            return self.repo.save_story_event(&event).await;
+        }
         self.repo.save_story_event(event).await
     }
 
@@ -255,11 +267,9 @@ impl Narrative {
             .map(|pc| (Some(pc.current_location_id), pc.current_region_id))
             .unwrap_or((None, None));
 
-        let world_game_time = self
-            .world_repo
-            .get(world_id)
-            .await?
-            .map(|world| world.game_time);
+        let world = self.world_repo.get(world_id).await?;
+        let in_flashback = world.as_ref().is_some_and(|w| w.flashback.is_some());
+        let world_game_time = world.map(|world| world.game_time);
 
         // Build summary from dialogue
         let summary = format!(
@@ -291,7 +301,11 @@ impl Narrative {
             game_time: game_time.or(fallback_game_time),
             summary,
             is_hidden: false,
-            tags: vec!["dialogue".to_string()],
+            tags: if in_flashback {
+                vec!["dialogue".to_string(), domain::FLASHBACK_TAG.to_string()]
+            } else {
+                vec!["dialogue".to_string()]
+            },
         };
 
         // Save the story event
@@ -422,6 +436,26 @@ impl Narrative {
         self.repo.end_active_conversation(pc_id, npc_id).await
     }
 
+    // =========================================================================
+    // Analytics
+    // =========================================================================
+
+    /// Dialogue exchanges for every PC and NPC pair in a world that have spoken.
+    pub async fn list_dialogue_counts(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<DialogueCount>, RepoError> {
+        self.repo.list_dialogue_counts(world_id).await
+    }
+
+    /// Story events per PC in a world. PCs without any are left out.
+    pub async fn count_story_events_by_pc(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<(PlayerCharacterId, u32)>, RepoError> {
+        self.repo.count_story_events_by_pc(world_id).await
+    }
+
     // =========================================================================
     // Triggers
     // =========================================================================
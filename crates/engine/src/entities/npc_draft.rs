@@ -0,0 +1,40 @@
+//! NPC draft operations.
+//!
+//! Characters proposed for promotion from a crowd or a mentioned name,
+//! waiting for the DM to approve or reject them.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, NpcDraftId, WorldId};
+
+use crate::infrastructure::ports::{NpcDraftRepo, RepoError};
+
+/// NPC draft operations.
+pub struct NpcDraft {
+    repo: Arc<dyn NpcDraftRepo>,
+}
+
+impl NpcDraft {
+    pub fn new(repo: Arc<dyn NpcDraftRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: NpcDraftId) -> Result<Option<domain::NpcDraft>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, draft: &domain::NpcDraft) -> Result<(), RepoError> {
+        self.repo.save(draft).await
+    }
+
+    pub async fn delete(&self, id: NpcDraftId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_for_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<domain::NpcDraft>, RepoError> {
+        self.repo.list_for_world(world_id).await
+    }
+}
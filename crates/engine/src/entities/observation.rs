@@ -2,13 +2,17 @@
 
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
-use wrldbldr_domain::{CharacterId, NpcObservation, PlayerCharacterId, StagedNpc};
+use wrldbldr_domain::{
+    CharacterId, Location, LocationId, LocationObservation, NpcObservation, PlayerCharacterId,
+    StagedNpc,
+};
 
 use crate::infrastructure::ports::{ClockPort, LocationRepo, ObservationRepo, RepoError};
 
 /// Observation entity operations.
 ///
-/// Tracks what NPCs a player character has observed/met.
+/// Tracks what NPCs a player character has observed/met, and which places
+/// they know.
 pub struct Observation {
     repo: Arc<dyn ObservationRepo>,
     location_repo: Arc<dyn LocationRepo>,
@@ -130,4 +134,94 @@ impl Observation {
 
         Ok(())
     }
+
+    /// Get every location the PC has recorded knowledge of.
+    pub async fn get_known_locations(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<LocationObservation>, RepoError> {
+        self.repo.get_known_locations(pc_id).await
+    }
+
+    /// Record knowledge of a location. A visit is never downgraded to hearsay.
+    pub async fn save_location_observation(
+        &self,
+        observation: &LocationObservation,
+    ) -> Result<(), RepoError> {
+        self.repo.save_location_observation(observation).await
+    }
+
+    /// Make a PC forget a location.
+    pub async fn forget_location(
+        &self,
+        pc_id: PlayerCharacterId,
+        location_id: LocationId,
+    ) -> Result<(), RepoError> {
+        self.repo
+            .delete_location_observation(pc_id, location_id)
+            .await
+    }
+
+    /// Record that a PC arrived at a location.
+    ///
+    /// Being somewhere means knowing the places around it, so every location
+    /// it sits inside is recorded as visited too. Only the location itself
+    /// counts the visit.
+    pub async fn record_location_visit(
+        &self,
+        pc_id: PlayerCharacterId,
+        location: &Location,
+        game_time: DateTime<Utc>,
+    ) -> Result<(), RepoError> {
+        let now = self.clock.now();
+        for location_id in self.with_ancestors(location).await? {
+            let mut visited = LocationObservation::visited(pc_id, location_id, game_time, now);
+            if location_id != location.id {
+                visited.visits = 0;
+            }
+            self.repo.save_location_observation(&visited).await?;
+        }
+        Ok(())
+    }
+
+    /// Whether a PC knows a location well enough to head there.
+    ///
+    /// The PC's own location and everything it sits inside always count, as
+    /// does anywhere they have visited or heard about.
+    pub async fn knows_location(
+        &self,
+        pc_id: PlayerCharacterId,
+        current_location_id: LocationId,
+        location_id: LocationId,
+    ) -> Result<bool, RepoError> {
+        if location_id == current_location_id {
+            return Ok(true);
+        }
+        let known = self.repo.get_known_locations(pc_id).await?;
+        if known.iter().any(|k| k.location_id == location_id) {
+            return Ok(true);
+        }
+        let Some(current) = self.location_repo.get_location(current_location_id).await? else {
+            return Ok(false);
+        };
+        Ok(self.with_ancestors(&current).await?.contains(&location_id))
+    }
+
+    /// The location followed by each location it sits inside, innermost first.
+    async fn with_ancestors(&self, location: &Location) -> Result<Vec<LocationId>, RepoError> {
+        let mut chain = vec![location.id];
+        let mut parent_id = location.parent_location_id;
+        while let Some(id) = parent_id {
+            if chain.contains(&id) {
+                break;
+            }
+            chain.push(id);
+            parent_id = self
+                .location_repo
+                .get_location(id)
+                .await?
+                .and_then(|parent| parent.parent_location_id);
+        }
+        Ok(chain)
+    }
 }
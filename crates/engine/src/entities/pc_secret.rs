@@ -0,0 +1,39 @@
+//! PC secret operations.
+//!
+//! What only the DM and one player know, until the DM reveals it.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, PcSecretId, PlayerCharacterId};
+
+use crate::infrastructure::ports::{PcSecretRepo, RepoError};
+
+/// PC secret operations.
+pub struct PcSecret {
+    repo: Arc<dyn PcSecretRepo>,
+}
+
+impl PcSecret {
+    pub fn new(repo: Arc<dyn PcSecretRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: PcSecretId) -> Result<Option<domain::PcSecret>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, secret: &domain::PcSecret) -> Result<(), RepoError> {
+        self.repo.save(secret).await
+    }
+
+    pub async fn delete(&self, id: PcSecretId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_for_pc(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<domain::PcSecret>, RepoError> {
+        self.repo.list_for_pc(pc_id).await
+    }
+}
@@ -76,6 +76,15 @@ impl PlayerCharacter {
         self.repo.get_inventory(id).await
     }
 
+    /// Swap items and currency between two PCs as one atomic change.
+    pub async fn execute_trade(
+        &self,
+        offer: &domain::TradeOffer,
+        currency_field: Option<String>,
+    ) -> Result<(), RepoError> {
+        self.repo.execute_trade(offer, currency_field).await
+    }
+
     // =========================================================================
     // Stats
     // =========================================================================
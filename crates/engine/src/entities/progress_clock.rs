@@ -0,0 +1,55 @@
+//! Progress clock entity operations.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, ClockTick, ProgressClockId, WorldId};
+
+use crate::infrastructure::ports::{ClockPort, ProgressClockRepo, RepoError};
+
+/// Progress clock entity operations.
+pub struct ProgressClock {
+    repo: Arc<dyn ProgressClockRepo>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ProgressClock {
+    pub fn new(repo: Arc<dyn ProgressClockRepo>, clock: Arc<dyn ClockPort>) -> Self {
+        Self { repo, clock }
+    }
+
+    pub async fn get(
+        &self,
+        id: ProgressClockId,
+    ) -> Result<Option<domain::ProgressClock>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, clock: &domain::ProgressClock) -> Result<(), RepoError> {
+        self.repo.save(clock).await
+    }
+
+    pub async fn delete(&self, id: ProgressClockId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_in_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<domain::ProgressClock>, RepoError> {
+        self.repo.list_in_world(world_id).await
+    }
+
+    /// Fill (or clear, for negative amounts) segments and persist the clock.
+    ///
+    /// Returns `RepoError::NotFound` if the clock doesn't exist.
+    pub async fn tick(
+        &self,
+        id: ProgressClockId,
+        amount: i32,
+    ) -> Result<(domain::ProgressClock, ClockTick), RepoError> {
+        let mut clock = self.repo.get(id).await?.ok_or(RepoError::NotFound)?;
+        let tick = clock.tick(amount, self.clock.now());
+        self.repo.save(&clock).await?;
+        Ok((clock, tick))
+    }
+}
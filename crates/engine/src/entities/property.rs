@@ -0,0 +1,39 @@
+//! Property operations.
+//!
+//! Places player characters own, with their upkeep and staff.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, PlayerCharacterId, PropertyId};
+
+use crate::infrastructure::ports::{PropertyRepo, RepoError};
+
+/// Property operations.
+pub struct Property {
+    repo: Arc<dyn PropertyRepo>,
+}
+
+impl Property {
+    pub fn new(repo: Arc<dyn PropertyRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: PropertyId) -> Result<Option<domain::Property>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, property: &domain::Property) -> Result<(), RepoError> {
+        self.repo.save(property).await
+    }
+
+    pub async fn delete(&self, id: PropertyId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_for_owner(
+        &self,
+        owner_id: PlayerCharacterId,
+    ) -> Result<Vec<domain::Property>, RepoError> {
+        self.repo.list_for_owner(owner_id).await
+    }
+}
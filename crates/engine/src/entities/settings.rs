@@ -5,7 +5,9 @@
 
 use std::sync::Arc;
 
-use wrldbldr_domain::{settings_metadata, AppSettings, SettingsFieldMetadata, WorldId};
+use wrldbldr_domain::{
+    settings_metadata, AppSettings, ServiceConnections, SettingsFieldMetadata, WorldId,
+};
 
 use crate::infrastructure::ports::{RepoError, SettingsRepo};
 
@@ -33,23 +35,46 @@ impl Settings {
 
     /// Update global application settings.
     ///
-    /// Clears any world_id to ensure settings are truly global.
+    /// Clears any world_id to ensure settings are truly global. Service
+    /// connections are kept as saved; only the setup flow changes them. A
+    /// redacted LLM API key keeps the saved one.
     pub async fn update_global(
         &self,
         mut settings: AppSettings,
     ) -> Result<AppSettings, SettingsError> {
+        let saved = self.get_global().await?;
         settings.world_id = None;
+        settings.services = saved.services;
+        settings.llm_provider = settings
+            .llm_provider
+            .with_saved_secret(&saved.llm_provider);
         self.repo.save_global(&settings).await?;
         Ok(settings)
     }
 
-    /// Reset global settings to defaults.
+    /// Reset global settings to defaults, keeping service connections.
     pub async fn reset_global(&self) -> Result<AppSettings, SettingsError> {
-        let settings = AppSettings::default();
+        let settings = AppSettings {
+            services: self.get_global().await?.services,
+            ..AppSettings::default()
+        };
         self.repo.save_global(&settings).await?;
         Ok(settings)
     }
 
+    /// Save the service connections chosen during setup.
+    ///
+    /// Takes effect the next time the engine starts.
+    pub async fn update_services(
+        &self,
+        services: ServiceConnections,
+    ) -> Result<ServiceConnections, SettingsError> {
+        let mut settings = self.get_global().await?;
+        settings.services = services;
+        self.repo.save_global(&settings).await?;
+        Ok(settings.services)
+    }
+
     /// Get settings for a specific world.
     ///
     /// Falls back to global settings if no world-specific settings exist.
@@ -67,12 +92,18 @@ impl Settings {
     /// Update settings for a specific world.
     ///
     /// Sets the world_id to ensure settings are associated with the correct world.
+    /// A redacted LLM API key keeps the one the world currently uses.
     pub async fn update_for_world(
         &self,
         world_id: WorldId,
         mut settings: AppSettings,
     ) -> Result<AppSettings, SettingsError> {
+        let saved = self.get_for_world(world_id).await?;
         settings.world_id = Some(world_id);
+        settings.llm_provider = settings
+            .llm_provider
+            .with_saved_secret(&saved.llm_provider);
+        settings.services = ServiceConnections::default();
         self.repo.save_for_world(world_id, &settings).await?;
         Ok(settings)
     }
@@ -103,6 +103,11 @@ impl Staging {
         self.repo.activate_staging(staging_id, region_id).await
     }
 
+    /// Clear a region's current staging, leaving it unstaged.
+    pub async fn deactivate_staging(&self, region_id: RegionId) -> Result<(), RepoError> {
+        self.repo.deactivate_staging(region_id).await
+    }
+
     /// Resolve which NPCs are present in a region for player view.
     ///
     /// Returns NPCs that are:
@@ -0,0 +1,95 @@
+//! Tag entity operations.
+//!
+//! Freeform tags on any taggable entity, plus each user's saved tag filters.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{EntityType, SavedFilter, SavedFilterId, WorldId};
+
+use crate::infrastructure::ports::{RepoError, TagRepo, TagUsage};
+
+/// Tag entity operations.
+pub struct Tag {
+    repo: Arc<dyn TagRepo>,
+}
+
+impl Tag {
+    pub fn new(repo: Arc<dyn TagRepo>) -> Self {
+        Self { repo }
+    }
+
+    /// Tags on a single entity, sorted (empty if it has none).
+    pub async fn get_tags(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<String>, RepoError> {
+        let mut tags = self.repo.get_tags(entity_type, &[entity_id]).await?;
+        Ok(tags.remove(&entity_id).unwrap_or_default())
+    }
+
+    pub async fn get_tags_for(
+        &self,
+        entity_type: EntityType,
+        entity_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<String>>, RepoError> {
+        if entity_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        self.repo.get_tags(entity_type, entity_ids).await
+    }
+
+    pub async fn set_tags(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        tags: &[String],
+    ) -> Result<(), RepoError> {
+        self.repo
+            .set_tags(world_id, entity_type, entity_id, tags)
+            .await
+    }
+
+    pub async fn list_world_tags(&self, world_id: WorldId) -> Result<Vec<TagUsage>, RepoError> {
+        self.repo.list_world_tags(world_id).await
+    }
+
+    pub async fn rename_tag(
+        &self,
+        world_id: WorldId,
+        from: &str,
+        to: &str,
+    ) -> Result<u32, RepoError> {
+        self.repo.rename_tag(world_id, from, to).await
+    }
+
+    pub async fn delete_tag(&self, world_id: WorldId, tag: &str) -> Result<u32, RepoError> {
+        self.repo.delete_tag(world_id, tag).await
+    }
+
+    pub async fn get_saved_filter(
+        &self,
+        id: SavedFilterId,
+    ) -> Result<Option<SavedFilter>, RepoError> {
+        self.repo.get_saved_filter(id).await
+    }
+
+    pub async fn save_saved_filter(&self, filter: &SavedFilter) -> Result<(), RepoError> {
+        self.repo.save_saved_filter(filter).await
+    }
+
+    pub async fn delete_saved_filter(&self, id: SavedFilterId) -> Result<(), RepoError> {
+        self.repo.delete_saved_filter(id).await
+    }
+
+    pub async fn list_saved_filters(
+        &self,
+        world_id: WorldId,
+        user_id: &str,
+    ) -> Result<Vec<SavedFilter>, RepoError> {
+        self.repo.list_saved_filters(world_id, user_id).await
+    }
+}
@@ -0,0 +1,37 @@
+//! Entity template operations.
+//!
+//! Saved prefabs (an NPC, or a region with its items and NPCs). Stamping a
+//! template out is a use case; this only stores the templates.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{EntityTemplate, EntityTemplateId, WorldId};
+
+use crate::infrastructure::ports::{RepoError, TemplateRepo};
+
+/// Entity template operations.
+pub struct Template {
+    repo: Arc<dyn TemplateRepo>,
+}
+
+impl Template {
+    pub fn new(repo: Arc<dyn TemplateRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, id: EntityTemplateId) -> Result<Option<EntityTemplate>, RepoError> {
+        self.repo.get(id).await
+    }
+
+    pub async fn save(&self, template: &EntityTemplate) -> Result<(), RepoError> {
+        self.repo.save(template).await
+    }
+
+    pub async fn delete(&self, id: EntityTemplateId) -> Result<(), RepoError> {
+        self.repo.delete(id).await
+    }
+
+    pub async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<EntityTemplate>, RepoError> {
+        self.repo.list_in_world(world_id).await
+    }
+}
@@ -0,0 +1,335 @@
+//! Anthropic LLM client (Messages API)
+//!
+//! The Messages API takes the system prompt separately and wants turns that
+//! alternate between user and assistant, starting with the user. System
+//! messages in the history are folded into the system prompt and adjacent
+//! turns of the same role are merged. There is no schema-constrained output,
+//! so a requested response schema is spelled out in the system prompt.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::infrastructure::ports::{
+    FinishReason, LlmError, LlmPort, LlmRequest, LlmResponse, MessageRole, TokenUsage, ToolCall,
+    ToolDefinition,
+};
+
+pub const DEFAULT_ANTHROPIC_URL: &str = "https://api.anthropic.com";
+pub const DEFAULT_ANTHROPIC_MODEL: &str = "claude-3-5-haiku-latest";
+
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// The API requires `max_tokens`; used when the request doesn't set one
+const DEFAULT_MAX_TOKENS: u32 = 1024;
+
+/// Client for Anthropic's Messages API
+#[derive(Clone)]
+pub struct AnthropicClient {
+    client: Client,
+    base_url: String,
+    api_key: String,
+    model: String,
+}
+
+impl AnthropicClient {
+    pub fn new(base_url: Option<&str>, api_key: &str, model: Option<&str>) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(120))
+            .build()
+            .unwrap_or_else(|_| Client::new());
+
+        Self {
+            client,
+            base_url: base_url
+                .unwrap_or(DEFAULT_ANTHROPIC_URL)
+                .trim_end_matches('/')
+                .to_string(),
+            api_key: api_key.to_string(),
+            model: model.unwrap_or(DEFAULT_ANTHROPIC_MODEL).to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl LlmPort for AnthropicClient {
+    async fn generate(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
+        self.generate_with_tools(request, Vec::new()).await
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: LlmRequest,
+        tools: Vec<ToolDefinition>,
+    ) -> Result<LlmResponse, LlmError> {
+        let model = request.model.clone().unwrap_or_else(|| self.model.clone());
+        let api_request = build_request(model, &request, tools);
+
+        let response = self
+            .client
+            .post(format!("{}/v1/messages", self.base_url))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .json(&api_request)
+            .send()
+            .await
+            .map_err(|e| LlmError::RequestFailed(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response
+                .text()
+                .await
+                .map_err(|e| LlmError::RequestFailed(e.to_string()))?;
+            return Err(LlmError::RequestFailed(format!(
+                "{}: {}",
+                status.as_u16(),
+                error_text
+            )));
+        }
+
+        let api_response: AnthropicResponse = response
+            .json()
+            .await
+            .map_err(|e| LlmError::InvalidResponse(e.to_string()))?;
+
+        Ok(convert_response(api_response))
+    }
+}
+
+fn build_request(
+    model: String,
+    request: &LlmRequest,
+    tools: Vec<ToolDefinition>,
+) -> AnthropicRequest {
+    let mut system: Vec<String> = request.system_prompt.iter().cloned().collect();
+    let mut messages: Vec<AnthropicMessage> = Vec::new();
+
+    for msg in &request.messages {
+        let role = match msg.role {
+            MessageRole::System => {
+                system.push(msg.content.clone());
+                continue;
+            }
+            MessageRole::Assistant => "assistant",
+            MessageRole::User | MessageRole::Unknown => "user",
+        };
+        let block = AnthropicContent::Text {
+            text: msg.content.clone(),
+        };
+        match messages.last_mut() {
+            Some(last) if last.role == role => last.content.push(block),
+            _ => messages.push(AnthropicMessage {
+                role: role.to_string(),
+                content: vec![block],
+            }),
+        }
+    }
+
+    // The conversation has to open with the user
+    if messages.first().map(|m| m.role.as_str()) != Some("user") {
+        messages.insert(
+            0,
+            AnthropicMessage {
+                role: "user".to_string(),
+                content: vec![AnthropicContent::Text {
+                    text: "Continue.".to_string(),
+                }],
+            },
+        );
+    }
+
+    // Images go with the last user message
+    if !request.images.is_empty() {
+        if let Some(last_user) = messages.iter_mut().rev().find(|m| m.role == "user") {
+            last_user
+                .content
+                .extend(request.images.iter().map(|image| AnthropicContent::Image {
+                    source: AnthropicImageSource {
+                        r#type: "base64".to_string(),
+                        media_type: image.media_type.clone(),
+                        data: image.data.clone(),
+                    },
+                }));
+        }
+    }
+
+    if let Some(schema) = &request.response_schema {
+        system.push(format!(
+            "Reply with only a JSON value matching this JSON schema:\n{}",
+            schema
+        ));
+    }
+
+    AnthropicRequest {
+        model,
+        max_tokens: request.max_tokens.unwrap_or(DEFAULT_MAX_TOKENS),
+        system: (!system.is_empty()).then(|| system.join("\n\n")),
+        messages,
+        // Anthropic accepts 0.0 - 1.0
+        temperature: request.temperature.map(|t| t.clamp(0.0, 1.0)),
+        tools: (!tools.is_empty()).then(|| {
+            tools
+                .into_iter()
+                .map(|t| AnthropicTool {
+                    name: t.name,
+                    description: t.description,
+                    input_schema: t.parameters,
+                })
+                .collect()
+        }),
+    }
+}
+
+fn convert_response(response: AnthropicResponse) -> LlmResponse {
+    let mut content = String::new();
+    let mut tool_calls = Vec::new();
+    for block in response.content {
+        match block {
+            AnthropicResponseContent::Text { text } => content.push_str(&text),
+            AnthropicResponseContent::ToolUse { id, name, input } => tool_calls.push(ToolCall {
+                id,
+                name,
+                arguments: input,
+            }),
+            AnthropicResponseContent::Other => {}
+        }
+    }
+
+    let finish_reason = match response.stop_reason.as_deref() {
+        Some("end_turn") | Some("stop_sequence") => FinishReason::Stop,
+        Some("max_tokens") => FinishReason::Length,
+        Some("tool_use") => FinishReason::ToolCalls,
+        Some("refusal") => FinishReason::ContentFilter,
+        _ => FinishReason::Stop,
+    };
+
+    LlmResponse {
+        content,
+        tool_calls,
+        finish_reason,
+        usage: response.usage.map(|u| TokenUsage {
+            prompt_tokens: u.input_tokens,
+            completion_tokens: u.output_tokens,
+            total_tokens: u.input_tokens + u.output_tokens,
+        }),
+    }
+}
+
+// =============================================================================
+// Anthropic API types
+// =============================================================================
+
+#[derive(Debug, Serialize)]
+struct AnthropicRequest {
+    model: String,
+    max_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<String>,
+    messages: Vec<AnthropicMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<AnthropicTool>>,
+}
+
+#[derive(Debug, Serialize)]
+struct AnthropicMessage {
+    role: String,
+    content: Vec<AnthropicContent>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicContent {
+    Text { text: String },
+    Image { source: AnthropicImageSource },
+}
+
+#[derive(Debug, Serialize)]
+struct AnthropicImageSource {
+    r#type: String,
+    media_type: String,
+    data: String,
+}
+
+#[derive(Debug, Serialize)]
+struct AnthropicTool {
+    name: String,
+    description: String,
+    input_schema: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicResponse {
+    #[serde(default)]
+    content: Vec<AnthropicResponseContent>,
+    stop_reason: Option<String>,
+    usage: Option<AnthropicUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicResponseContent {
+    Text {
+        text: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        #[serde(default)]
+        input: serde_json::Value,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicUsage {
+    input_tokens: u32,
+    output_tokens: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ports::ChatMessage;
+
+    #[test]
+    fn history_is_reshaped_for_the_messages_api() {
+        let mut request = LlmRequest::new(vec![
+            ChatMessage::assistant("The gate creaks open."),
+            ChatMessage::user("I step inside."),
+            ChatMessage::user("Quietly."),
+        ])
+        .with_system_prompt("You are the innkeeper.");
+        request.response_schema = Some(serde_json::json!({ "type": "object" }));
+        request.temperature = Some(1.4);
+
+        let api = build_request("claude".to_string(), &request, Vec::new());
+        let roles: Vec<&str> = api.messages.iter().map(|m| m.role.as_str()).collect();
+        assert_eq!(roles, vec!["user", "assistant", "user"]);
+        assert_eq!(api.messages[2].content.len(), 2);
+        assert_eq!(api.max_tokens, DEFAULT_MAX_TOKENS);
+        assert_eq!(api.temperature, Some(1.0));
+        let system = api.system.unwrap();
+        assert!(system.starts_with("You are the innkeeper."));
+        assert!(system.contains("JSON schema"));
+
+        let response: AnthropicResponse = serde_json::from_value(serde_json::json!({
+            "content": [
+                { "type": "text", "text": "Welcome." },
+                { "type": "tool_use", "id": "t1", "name": "give_item", "input": { "item": "key" } }
+            ],
+            "stop_reason": "tool_use",
+            "usage": { "input_tokens": 40, "output_tokens": 12 }
+        }))
+        .unwrap();
+        let response = convert_response(response);
+        assert_eq!(response.content, "Welcome.");
+        assert_eq!(response.tool_calls[0].name, "give_item");
+        assert_eq!(response.finish_reason, FinishReason::ToolCalls);
+        assert_eq!(response.usage.unwrap().total_tokens, 52);
+    }
+}
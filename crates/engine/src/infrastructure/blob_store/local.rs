@@ -0,0 +1,178 @@
+//! Local filesystem blob store.
+//!
+//! Blobs are written under a root directory at their content-addressed key.
+//! Signed URLs point back at the engine (`/api/blobs/{key}`) and carry an
+//! expiry and an HMAC of the key, which the engine checks before serving.
+
+use async_trait::async_trait;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{content_key, hmac_sha256, hmac_sha256_verify, validate_key};
+use crate::infrastructure::ports::{BlobError, BlobStorePort, ClockPort};
+
+/// Blob store on the engine's local disk
+pub struct LocalBlobStore {
+    root: PathBuf,
+    /// Base URL remote Players reach the engine at, e.g. `https://game.example.com`
+    public_url: String,
+    signing_secret: Vec<u8>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl LocalBlobStore {
+    pub fn new(
+        root: impl Into<PathBuf>,
+        public_url: &str,
+        signing_secret: impl Into<Vec<u8>>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            root: root.into(),
+            public_url: public_url.trim_end_matches('/').to_string(),
+            signing_secret: signing_secret.into(),
+            clock,
+        }
+    }
+
+    fn path_for(&self, key: &str) -> Result<PathBuf, BlobError> {
+        validate_key(key)?;
+        Ok(self.root.join(key))
+    }
+
+    fn signed_message(key: &str, expires_at: i64) -> Vec<u8> {
+        format!("{}\n{}", key, expires_at).into_bytes()
+    }
+}
+
+fn storage_error(e: std::io::Error) -> BlobError {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        BlobError::NotFound
+    } else {
+        BlobError::Storage(e.to_string())
+    }
+}
+
+#[async_trait]
+impl BlobStorePort for LocalBlobStore {
+    async fn put(&self, data: Vec<u8>, content_type: &str) -> Result<String, BlobError> {
+        let key = content_key(&data, content_type);
+        let path = self.path_for(&key)?;
+
+        // Same key means same bytes, so an existing file is already correct
+        if tokio::fs::try_exists(&path).await.unwrap_or(false) {
+            return Ok(key);
+        }
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(storage_error)?;
+        }
+
+        // Write then rename so a reader never sees a partial file
+        let tmp = path.with_extension(format!("{}.tmp", uuid::Uuid::new_v4()));
+        tokio::fs::write(&tmp, &data).await.map_err(storage_error)?;
+        tokio::fs::rename(&tmp, &path)
+            .await
+            .map_err(storage_error)?;
+        Ok(key)
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, BlobError> {
+        tokio::fs::read(self.path_for(key)?)
+            .await
+            .map_err(storage_error)
+    }
+
+    fn signed_url(&self, key: &str, expires_in: Duration) -> Result<String, BlobError> {
+        validate_key(key)?;
+        let expires_at = self.clock.now().timestamp() + expires_in.as_secs() as i64;
+        Ok(format!(
+            "{}/api/blobs/{}?expires={}&signature={}",
+            self.public_url,
+            key,
+            expires_at,
+            hex::encode(hmac_sha256(
+                &self.signing_secret,
+                &Self::signed_message(key, expires_at)
+            ))
+        ))
+    }
+
+    fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> bool {
+        if validate_key(key).is_err() || self.clock.now().timestamp() > expires_at {
+            return false;
+        }
+        let Ok(signature) = hex::decode(signature) else {
+            return false;
+        };
+        hmac_sha256_verify(
+            &self.signing_secret,
+            &Self::signed_message(key, expires_at),
+            &signature,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use chrono::{TimeZone, Utc};
+
+    fn store(root: &std::path::Path, now: i64) -> LocalBlobStore {
+        LocalBlobStore::new(
+            root,
+            "https://game.example.com/",
+            "secret",
+            Arc::new(FixedClock(Utc.timestamp_opt(now, 0).unwrap())),
+        )
+    }
+
+    #[tokio::test]
+    async fn put_get_round_trip() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = store(dir.path(), 1_700_000_000);
+
+        let key = store.put(b"image".to_vec(), "image/png").await.unwrap();
+        let again = store.put(b"image".to_vec(), "image/png").await.unwrap();
+        assert_eq!(key, again);
+        assert!(dir.path().join(&key).exists());
+        assert_eq!(store.get(&key).await.unwrap(), b"image");
+
+        assert!(matches!(
+            store.get("../secrets.png").await,
+            Err(BlobError::InvalidKey(_))
+        ));
+    }
+
+    #[test]
+    fn signed_urls_verify_until_they_expire() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let signer = store(dir.path(), 1_700_000_000);
+        let key = content_key(b"image", "image/png");
+
+        let url = signer.signed_url(&key, Duration::from_secs(60)).unwrap();
+        assert!(url.starts_with(&format!("https://game.example.com/api/blobs/{}?", key)));
+        let query = url.split_once('?').unwrap().1;
+        let mut expires_at = 0;
+        let mut signature = "";
+        for pair in query.split('&') {
+            match pair.split_once('=') {
+                Some(("expires", v)) => expires_at = v.parse().unwrap(),
+                Some(("signature", v)) => signature = v,
+                _ => {}
+            }
+        }
+        assert_eq!(expires_at, 1_700_000_060);
+
+        assert!(signer.verify_signature(&key, expires_at, signature));
+        assert!(!signer.verify_signature(&key, expires_at + 1, signature));
+        let other_key = content_key(b"other", "image/png");
+        assert!(!signer.verify_signature(&other_key, expires_at, signature));
+
+        let later = store(dir.path(), 1_700_000_061);
+        assert!(!later.verify_signature(&key, expires_at, signature));
+    }
+}
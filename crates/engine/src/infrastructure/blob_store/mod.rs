@@ -0,0 +1,140 @@
+//! Asset blob storage.
+//!
+//! Implementations of `BlobStorePort` for the local filesystem and for
+//! S3-compatible object stores (AWS S3, MinIO, Cloudflare R2, ...). Both use
+//! the same content-addressed keys, so assets can move between backends
+//! without their stored paths changing:
+//!
+//! ```text
+//! ab/cd/abcdef0123…(64 hex chars).png
+//! ```
+
+pub mod local;
+pub mod s3;
+
+pub use local::LocalBlobStore;
+pub use s3::{S3BlobStore, S3Config};
+
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+use crate::infrastructure::ports::BlobError;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Lowercase hex SHA-256 of `data`
+pub(crate) fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac
+}
+
+pub(crate) fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    hmac(key, data).finalize().into_bytes().to_vec()
+}
+
+/// Constant-time check that `tag` is the HMAC-SHA256 of `data`
+pub(crate) fn hmac_sha256_verify(key: &[u8], data: &[u8], tag: &[u8]) -> bool {
+    hmac(key, data).verify_slice(tag).is_ok()
+}
+
+/// File extension stored with a blob of `content_type`
+fn extension_for(content_type: &str) -> &'static str {
+    match content_type {
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/webp" => "webp",
+        "image/gif" => "gif",
+        "application/json" => "json",
+        _ => "bin",
+    }
+}
+
+/// Content type of a blob, from its key's extension
+pub fn content_type_for(key: &str) -> &'static str {
+    match key.rsplit_once('.').map(|(_, ext)| ext) {
+        Some("png") => "image/png",
+        Some("jpg") => "image/jpeg",
+        Some("webp") => "image/webp",
+        Some("gif") => "image/gif",
+        Some("json") => "application/json",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Content-addressed key for `data`
+pub fn content_key(data: &[u8], content_type: &str) -> String {
+    let hash = sha256_hex(data);
+    format!(
+        "{}/{}/{}.{}",
+        &hash[0..2],
+        &hash[2..4],
+        hash,
+        extension_for(content_type)
+    )
+}
+
+/// Check `key` has the shape `content_key` produces.
+///
+/// Keys reach the stores from URLs, so anything else (`..`, absolute
+/// paths, stray characters) is rejected before it touches a path or bucket.
+pub fn validate_key(key: &str) -> Result<(), BlobError> {
+    let invalid = || BlobError::InvalidKey(key.to_string());
+    let mut parts = key.split('/');
+    let (Some(a), Some(b), Some(file), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+    let (hash, ext) = file.split_once('.').ok_or_else(invalid)?;
+
+    let is_hex = |s: &str| {
+        s.bytes()
+            .all(|c| c.is_ascii_digit() || (b'a'..=b'f').contains(&c))
+    };
+    let valid = hash.len() == 64
+        && is_hex(hash)
+        && a == &hash[0..2]
+        && b == &hash[2..4]
+        && !ext.is_empty()
+        && ext.bytes().all(|c| c.is_ascii_lowercase());
+    if valid {
+        Ok(())
+    } else {
+        Err(invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_key_is_stable_and_valid() {
+        let key = content_key(b"portrait bytes", "image/png");
+        assert_eq!(key, content_key(b"portrait bytes", "image/png"));
+        assert_ne!(key, content_key(b"other bytes", "image/png"));
+        assert!(key.ends_with(".png"));
+        assert!(validate_key(&key).is_ok());
+        assert_eq!(content_type_for(&key), "image/png");
+    }
+
+    #[test]
+    fn validate_key_rejects_paths_outside_the_store() {
+        let key = content_key(b"x", "image/png");
+        for bad in [
+            "../etc/passwd",
+            "/abs/olute/path.png",
+            "ab/cd/short.png",
+            &key.replace(".png", ""),
+            &format!("{}/extra", key),
+            &key.to_uppercase(),
+        ] {
+            assert!(validate_key(bad).is_err(), "{} should be rejected", bad);
+        }
+    }
+}
@@ -10,7 +10,7 @@
 //! See `docs/designs/LLM_RESILIENCE_AND_CUSTOM_EVALUATION.md` for design details.
 
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 
 /// Configuration for circuit breaker behavior
@@ -435,6 +435,63 @@ impl CircuitBreaker {
             callback(old_state, new_state);
         }
     }
+
+    /// Run a single call through the circuit breaker.
+    ///
+    /// Rejects the call with `CircuitOpenError` while the circuit is open.
+    /// Otherwise runs it and records the outcome: errors for which
+    /// `is_failure` returns true count toward opening the circuit, while
+    /// other errors (e.g. bad input) show the service is up and count as
+    /// successes.
+    pub async fn call<T, E, Fut>(
+        &self,
+        operation: impl FnOnce() -> Fut,
+        is_failure: impl Fn(&E) -> bool,
+    ) -> Result<Result<T, E>, CircuitOpenError>
+    where
+        Fut: std::future::Future<Output = Result<T, E>>,
+    {
+        self.allow_request()?;
+
+        let result = operation().await;
+        match &result {
+            Err(e) if is_failure(e) => self.record_failure(),
+            _ => self.record_success(),
+        }
+        Ok(result)
+    }
+}
+
+/// Circuit breakers guarding each external service.
+///
+/// Built once at startup from `AppSettings` and shared between the
+/// resilient adapters and the metrics endpoint.
+#[derive(Clone)]
+pub struct ServiceCircuitBreakers {
+    /// Guards calls to the LLM (Ollama)
+    pub llm: Arc<CircuitBreaker>,
+    /// Guards Neo4j queries
+    pub neo4j: Arc<CircuitBreaker>,
+}
+
+impl ServiceCircuitBreakers {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            llm: Arc::new(CircuitBreaker::new(config.clone())),
+            neo4j: Arc::new(CircuitBreaker::new(config)),
+        }
+    }
+
+    /// Metrics for every breaker, keyed by service name
+    pub fn metrics(&self) -> Vec<(&'static str, CircuitBreakerMetrics)> {
+        vec![("llm", self.llm.metrics()), ("neo4j", self.neo4j.metrics())]
+    }
+}
+
+impl Default for ServiceCircuitBreakers {
+    fn default() -> Self {
+        Self::new(CircuitBreakerConfig::default())
+    }
 }
 
 /// Metrics for circuit breaker state
@@ -457,7 +514,6 @@ pub struct CircuitBreakerMetrics {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::Arc;
     use std::thread;
 
     #[test]
@@ -770,4 +826,41 @@ mod tests {
             CircuitState::Open as u32
         );
     }
+
+    #[tokio::test]
+    async fn test_call_only_counts_service_failures() {
+        let config = CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_duration: Duration::from_secs(60),
+            half_open_max_requests: 1,
+        };
+        let cb = CircuitBreaker::new(config);
+        let is_failure = |e: &&str| *e == "unreachable";
+
+        // Errors the service answered with don't trip the breaker
+        for _ in 0..3 {
+            let result = cb.call(|| async { Err::<(), _>("bad input") }, is_failure).await;
+            assert!(matches!(result, Ok(Err("bad input"))));
+        }
+        assert_eq!(cb.state(), CircuitState::Closed);
+
+        for _ in 0..2 {
+            let _ = cb.call(|| async { Err::<(), _>("unreachable") }, is_failure).await;
+        }
+        assert_eq!(cb.state(), CircuitState::Open);
+
+        // While open, the operation is not run at all
+        let mut ran = false;
+        let result = cb
+            .call(
+                || {
+                    ran = true;
+                    async { Ok::<(), &str>(()) }
+                },
+                is_failure,
+            )
+            .await;
+        assert!(result.is_err());
+        assert!(!ran);
+    }
 }
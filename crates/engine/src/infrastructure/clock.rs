@@ -76,3 +76,59 @@ impl RandomPort for FixedRandom {
         Uuid::nil()
     }
 }
+
+/// Clock that only moves when a test moves it.
+#[cfg(test)]
+pub struct ManualClock(std::sync::Mutex<DateTime<Utc>>);
+
+#[cfg(test)]
+impl ManualClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self(std::sync::Mutex::new(start))
+    }
+
+    pub fn advance(&self, by: chrono::Duration) {
+        let mut now = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        *now += by;
+    }
+}
+
+#[cfg(test)]
+impl ClockPort for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Seeded random for testing - the same seed gives the same sequence.
+#[cfg(test)]
+pub struct SeededRandom(std::sync::Mutex<rand::rngs::StdRng>);
+
+#[cfg(test)]
+impl SeededRandom {
+    pub fn new(seed: u64) -> Self {
+        use rand::SeedableRng;
+        Self(std::sync::Mutex::new(rand::rngs::StdRng::seed_from_u64(
+            seed,
+        )))
+    }
+}
+
+#[cfg(test)]
+impl RandomPort for SeededRandom {
+    fn gen_range(&self, min: i32, max: i32) -> i32 {
+        use rand::Rng;
+        let mut rng = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        rng.gen_range(min..=max)
+    }
+
+    fn gen_uuid(&self) -> Uuid {
+        use rand::RngCore;
+        let mut bytes = [0u8; 16];
+        self.0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .fill_bytes(&mut bytes);
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+}
@@ -64,6 +64,43 @@ impl ComfyUIClient {
             .map_err(|e| ImageGenError::GenerationFailed(e.to_string()))
     }
 
+    /// Upload a reference image to ComfyUI's input folder
+    ///
+    /// Returns the name a `LoadImage` node loads it by.
+    async fn upload_image(&self, data: Vec<u8>) -> Result<String, ImageGenError> {
+        let part = reqwest::multipart::Part::bytes(data)
+            .file_name(format!("wrldbldr_ref_{}.png", uuid::Uuid::new_v4()));
+        let form = reqwest::multipart::Form::new()
+            .part("image", part)
+            .text("overwrite", "true");
+
+        let response = self
+            .client
+            .post(format!("{}/upload/image", self.base_url))
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| ImageGenError::GenerationFailed(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ImageGenError::GenerationFailed(error_text));
+        }
+
+        let uploaded: UploadResponse = response
+            .json()
+            .await
+            .map_err(|e| ImageGenError::GenerationFailed(e.to_string()))?;
+        Ok(if uploaded.subfolder.is_empty() {
+            uploaded.name
+        } else {
+            format!("{}/{}", uploaded.subfolder, uploaded.name)
+        })
+    }
+
     /// Get the history of a completed prompt
     async fn get_history(&self, prompt_id: &str) -> Result<HistoryResponse, ImageGenError> {
         let response = self
@@ -154,10 +191,13 @@ impl ComfyUIClient {
     }
 
     /// Build a simple workflow for image generation
-    fn build_workflow(request: &ImageRequest) -> serde_json::Value {
+    ///
+    /// With a `reference_image` (an uploaded image name), the model is routed
+    /// through an IPAdapter conditioned on that image.
+    fn build_workflow(request: &ImageRequest, reference_image: Option<&str>) -> serde_json::Value {
         // This is a simplified workflow template
         // In production, you'd load workflow JSON from files based on request.workflow
-        serde_json::json!({
+        let mut workflow = serde_json::json!({
             "3": {
                 "inputs": {
                     "seed": rand::random::<u32>(),
@@ -196,7 +236,10 @@ impl ComfyUIClient {
             },
             "7": {
                 "inputs": {
-                    "text": "bad quality, blurry, ugly",
+                    "text": request
+                        .negative_prompt
+                        .as_deref()
+                        .unwrap_or("bad quality, blurry, ugly"),
                     "clip": ["4", 1]
                 },
                 "class_type": "CLIPTextEncode"
@@ -215,15 +258,58 @@ impl ComfyUIClient {
                 },
                 "class_type": "SaveImage"
             }
-        })
+        });
+
+        if let (Some(image), Some(nodes)) = (reference_image, workflow.as_object_mut()) {
+            nodes.insert(
+                "10".to_string(),
+                serde_json::json!({
+                    "inputs": { "image": image },
+                    "class_type": "LoadImage"
+                }),
+            );
+            nodes.insert(
+                "11".to_string(),
+                serde_json::json!({
+                    "inputs": {
+                        "preset": "PLUS (high strength)",
+                        "model": ["4", 0]
+                    },
+                    "class_type": "IPAdapterUnifiedLoader"
+                }),
+            );
+            nodes.insert(
+                "12".to_string(),
+                serde_json::json!({
+                    "inputs": {
+                        "weight": 0.8,
+                        "start_at": 0.0,
+                        "end_at": 1.0,
+                        "weight_type": "standard",
+                        "model": ["11", 0],
+                        "ipadapter": ["11", 1],
+                        "image": ["10", 0]
+                    },
+                    "class_type": "IPAdapter"
+                }),
+            );
+            workflow["3"]["inputs"]["model"] = serde_json::json!(["12", 0]);
+        }
+
+        workflow
     }
 }
 
 #[async_trait]
 impl ImageGenPort for ComfyUIClient {
     async fn generate(&self, request: ImageRequest) -> Result<ImageResult, ImageGenError> {
+        let reference_image = match request.reference_image.clone() {
+            Some(data) => Some(self.upload_image(data).await?),
+            None => None,
+        };
+
         // Build workflow from request
-        let workflow = Self::build_workflow(&request);
+        let workflow = Self::build_workflow(&request, reference_image.as_deref());
 
         // Queue the prompt
         let queue_response = self.queue_prompt(workflow).await?;
@@ -285,6 +371,13 @@ struct QueueResponse {
     number: u32,
 }
 
+#[derive(Debug, Deserialize)]
+struct UploadResponse {
+    name: String,
+    #[serde(default)]
+    subfolder: String,
+}
+
 #[derive(Debug, Deserialize)]
 struct HistoryResponse {
     #[serde(flatten)]
@@ -0,0 +1,306 @@
+//! In-memory diagnostics for bug reports
+//!
+//! Captures recent log events as structured records - each carrying the
+//! fields of the spans it was logged in, so WebSocket logs know their
+//! connection and world - and counts protocol messages per world. Both are
+//! bounded, kept in memory only, and reset when the engine restarts.
+
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use tracing::field::{Field, Visit};
+use tracing::{span, Event, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+use wrldbldr_domain::WorldId;
+
+/// Log records kept before the oldest are dropped
+pub const DEFAULT_LOG_CAPACITY: usize = 5000;
+
+/// A captured log event
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogRecord {
+    pub timestamp: DateTime<Utc>,
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    /// Event fields, merged over the fields of its enclosing spans
+    pub fields: BTreeMap<String, String>,
+}
+
+impl LogRecord {
+    fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+}
+
+/// Ring buffer of recent log records
+pub struct LogBuffer {
+    capacity: usize,
+    records: Mutex<VecDeque<LogRecord>>,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn push(&self, record: LogRecord) {
+        let Ok(mut records) = self.records.lock() else {
+            return;
+        };
+        if records.len() >= self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// The most recent `limit` records about a world, oldest first.
+    ///
+    /// Includes records tagged with the world and records from any connection
+    /// that logged in the world (e.g. its JoinWorld, before the connection
+    /// was tagged).
+    pub fn recent_for_world(&self, world_id: WorldId, limit: usize) -> Vec<LogRecord> {
+        let Ok(records) = self.records.lock() else {
+            return Vec::new();
+        };
+        let world = world_id.to_string();
+        let connections: HashSet<&str> = records
+            .iter()
+            .filter(|record| record.field("world_id") == Some(world.as_str()))
+            .filter_map(|record| record.field("connection_id"))
+            .collect();
+
+        let matching: Vec<&LogRecord> = records
+            .iter()
+            .filter(|record| match record.field("world_id") {
+                Some(id) => id == world,
+                None => record
+                    .field("connection_id")
+                    .is_some_and(|id| connections.contains(id)),
+            })
+            .collect();
+        let skip = matching.len().saturating_sub(limit);
+        matching.into_iter().skip(skip).cloned().collect()
+    }
+}
+
+/// Fields recorded on a span, stored in its extensions
+struct SpanFields(BTreeMap<String, String>);
+
+struct FieldVisitor<'a>(&'a mut BTreeMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+/// Tracing layer that copies every enabled event into a [`LogBuffer`]
+pub struct LogCaptureLayer {
+    buffer: Arc<LogBuffer>,
+}
+
+impl<S> Layer<S> for LogCaptureLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = BTreeMap::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+        span.extensions_mut().insert(SpanFields(fields));
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(SpanFields(fields)) = extensions.get_mut::<SpanFields>() {
+            values.record(&mut FieldVisitor(fields));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut fields = BTreeMap::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                if let Some(SpanFields(span_fields)) = span.extensions().get::<SpanFields>() {
+                    fields.extend(span_fields.clone());
+                }
+            }
+        }
+        event.record(&mut FieldVisitor(&mut fields));
+        let message = fields.remove("message").unwrap_or_default();
+
+        let metadata = event.metadata();
+        self.buffer.push(LogRecord {
+            timestamp: Utc::now(),
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message,
+            fields,
+        });
+    }
+}
+
+/// Protocol message counts for one world
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WorldMessageStats {
+    /// Client messages received, by message type
+    pub inbound: BTreeMap<String, u64>,
+    /// Server messages sent, by message type
+    pub outbound: BTreeMap<String, u64>,
+    pub inbound_bytes: u64,
+    pub outbound_bytes: u64,
+    /// Client messages that could not be parsed
+    pub parse_errors: u64,
+}
+
+/// Per-world protocol message counters
+#[derive(Default)]
+pub struct MessageStats {
+    worlds: DashMap<WorldId, WorldMessageStats>,
+}
+
+#[derive(Deserialize)]
+struct TaggedMessage {
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+/// The `type` tag of a JSON protocol message
+fn message_type(json: &str) -> String {
+    serde_json::from_str::<TaggedMessage>(json)
+        .map(|tagged| tagged.kind)
+        .unwrap_or_else(|_| "Unknown".to_string())
+}
+
+impl MessageStats {
+    /// Count a client message received by a connection in `world_id`
+    pub fn record_inbound(&self, world_id: WorldId, json: &str) {
+        let kind = message_type(json);
+        let mut stats = self.worlds.entry(world_id).or_default();
+        *stats.inbound.entry(kind).or_default() += 1;
+        stats.inbound_bytes += json.len() as u64;
+    }
+
+    /// Count a server message sent to a connection in `world_id`
+    pub fn record_outbound(&self, world_id: WorldId, json: &str) {
+        let kind = message_type(json);
+        let mut stats = self.worlds.entry(world_id).or_default();
+        *stats.outbound.entry(kind).or_default() += 1;
+        stats.outbound_bytes += json.len() as u64;
+    }
+
+    pub fn record_parse_error(&self, world_id: WorldId) {
+        self.worlds.entry(world_id).or_default().parse_errors += 1;
+    }
+
+    pub fn snapshot(&self, world_id: WorldId) -> WorldMessageStats {
+        self.worlds
+            .get(&world_id)
+            .map(|stats| stats.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Diagnostics gathered since the engine started
+pub struct Diagnostics {
+    pub logs: Arc<LogBuffer>,
+    pub messages: MessageStats,
+    pub started_at: DateTime<Utc>,
+}
+
+impl Diagnostics {
+    pub fn new(log_capacity: usize) -> Self {
+        Self {
+            logs: Arc::new(LogBuffer::new(log_capacity)),
+            messages: MessageStats::default(),
+            started_at: Utc::now(),
+        }
+    }
+
+    /// Tracing layer feeding this engine's log buffer
+    pub fn log_layer(&self) -> LogCaptureLayer {
+        LogCaptureLayer {
+            buffer: self.logs.clone(),
+        }
+    }
+}
+
+impl Default for Diagnostics {
+    fn default() -> Self {
+        Self::new(DEFAULT_LOG_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn captured_events_carry_span_fields_and_filter_by_world() {
+        let diagnostics = Diagnostics::new(10);
+        let subscriber = tracing_subscriber::registry().with(diagnostics.log_layer());
+        let world_id = WorldId::new();
+        let other_world = WorldId::new();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!(
+                "ws_connection",
+                connection_id = "conn-1",
+                world_id = tracing::field::Empty
+            );
+            let _entered = span.enter();
+            tracing::info!("joining");
+            span.record("world_id", tracing::field::display(world_id));
+            tracing::warn!(request = "GetWorld", "slow request");
+            tracing::info!(world_id = %other_world, "elsewhere");
+        });
+
+        let records = diagnostics.logs.recent_for_world(world_id, 10);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].message, "joining");
+        assert_eq!(records[1].level, "WARN");
+        assert_eq!(records[1].field("request"), Some("GetWorld"));
+        assert_eq!(
+            records[1].field("world_id"),
+            Some(world_id.to_string().as_str())
+        );
+    }
+
+    #[test]
+    fn message_stats_count_by_type_per_world() {
+        let stats = MessageStats::default();
+        let world_id = WorldId::new();
+
+        stats.record_inbound(world_id, r#"{"type":"Heartbeat"}"#);
+        stats.record_inbound(world_id, r#"{"type":"Heartbeat"}"#);
+        stats.record_outbound(world_id, r#"{"type":"Pong"}"#);
+        stats.record_parse_error(world_id);
+
+        let snapshot = stats.snapshot(world_id);
+        assert_eq!(snapshot.inbound.get("Heartbeat"), Some(&2));
+        assert_eq!(snapshot.outbound.get("Pong"), Some(&1));
+        assert_eq!(snapshot.parse_errors, 1);
+        assert_eq!(stats.snapshot(WorldId::new()), WorldMessageStats::default());
+    }
+}
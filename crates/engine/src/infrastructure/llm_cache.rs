@@ -0,0 +1,243 @@
+//! LLM response cache for deterministic prompts
+//!
+//! Wraps any LlmPort and reuses the response to an identical earlier request
+//! when the request opts in with `LlmRequest::with_cache(true)`. Only
+//! non-creative calls (classification, name generation) opt in, so a DM
+//! regenerating the same view doesn't pay for the same call again. Creative
+//! calls and tool calls always go to the inner client.
+
+use async_trait::async_trait;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::infrastructure::ports::{LlmError, LlmPort, LlmRequest, LlmResponse, ToolDefinition};
+
+/// Entries kept before the oldest are evicted
+const MAX_ENTRIES: usize = 512;
+
+struct CachedResponse {
+    response: LlmResponse,
+    cached_at: Instant,
+}
+
+/// Wrapper that caches responses to cacheable requests by prompt hash
+pub struct CachedLlmClient {
+    inner: Arc<dyn LlmPort>,
+    ttl: Duration,
+    enabled: bool,
+    entries: Mutex<HashMap<u64, CachedResponse>>,
+}
+
+impl CachedLlmClient {
+    pub fn new(inner: Arc<dyn LlmPort>, enabled: bool, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            enabled: enabled && !ttl.is_zero(),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn lookup(&self, key: u64) -> Option<LlmResponse> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(&key) {
+            Some(entry) if entry.cached_at.elapsed() < self.ttl => Some(entry.response.clone()),
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn store(&self, key: u64, response: &LlmResponse) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.len() >= MAX_ENTRIES {
+            let ttl = self.ttl;
+            entries.retain(|_, entry| entry.cached_at.elapsed() < ttl);
+        }
+        if entries.len() >= MAX_ENTRIES {
+            if let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.cached_at)
+                .map(|(key, _)| *key)
+            {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(
+            key,
+            CachedResponse {
+                response: response.clone(),
+                cached_at: Instant::now(),
+            },
+        );
+    }
+}
+
+/// Hash of everything that shapes the response. The world picks the
+/// provider and is billed for the call, so answers never cross worlds.
+fn prompt_hash(request: &LlmRequest) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    request.world_id.hash(&mut hasher);
+    request.model.hash(&mut hasher);
+    request.system_prompt.hash(&mut hasher);
+    for message in &request.messages {
+        std::mem::discriminant(&message.role).hash(&mut hasher);
+        message.content.hash(&mut hasher);
+    }
+    for image in &request.images {
+        image.data.hash(&mut hasher);
+    }
+    request.temperature.map(f32::to_bits).hash(&mut hasher);
+    request.max_tokens.hash(&mut hasher);
+    request
+        .response_schema
+        .as_ref()
+        .map(|schema| schema.to_string())
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+#[async_trait]
+impl LlmPort for CachedLlmClient {
+    async fn generate(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
+        if !self.enabled || !request.cache {
+            return self.inner.generate(request).await;
+        }
+
+        let key = prompt_hash(&request);
+        if let Some(response) = self.lookup(key) {
+            tracing::debug!(prompt_hash = key, "LLM cache hit");
+            return Ok(response);
+        }
+
+        let response = self.inner.generate(request).await?;
+        self.store(key, &response);
+        Ok(response)
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: LlmRequest,
+        tools: Vec<ToolDefinition>,
+    ) -> Result<LlmResponse, LlmError> {
+        self.inner.generate_with_tools(request, tools).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ports::{ChatMessage, FinishReason};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use wrldbldr_domain::WorldId;
+
+    /// Mock LLM that numbers its responses
+    struct CountingLlm {
+        calls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl LlmPort for CountingLlm {
+        async fn generate(&self, _request: LlmRequest) -> Result<LlmResponse, LlmError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(LlmResponse {
+                content: format!("response {}", call),
+                tool_calls: vec![],
+                finish_reason: FinishReason::Stop,
+                usage: None,
+            })
+        }
+
+        async fn generate_with_tools(
+            &self,
+            request: LlmRequest,
+            _tools: Vec<ToolDefinition>,
+        ) -> Result<LlmResponse, LlmError> {
+            self.generate(request).await
+        }
+    }
+
+    fn client(enabled: bool, ttl: Duration) -> (Arc<CountingLlm>, CachedLlmClient) {
+        let inner = Arc::new(CountingLlm {
+            calls: AtomicU32::new(0),
+        });
+        let cached = CachedLlmClient::new(inner.clone(), enabled, ttl);
+        (inner, cached)
+    }
+
+    fn request(prompt: &str) -> LlmRequest {
+        LlmRequest::new(vec![ChatMessage::user(prompt)]).with_cache(true)
+    }
+
+    #[tokio::test]
+    async fn test_identical_cacheable_requests_hit_the_cache() {
+        let (inner, cached) = client(true, Duration::from_secs(60));
+
+        let first = cached.generate(request("Who is present?")).await.unwrap();
+        let second = cached.generate(request("Who is present?")).await.unwrap();
+        let other = cached.generate(request("Name a tavern")).await.unwrap();
+
+        assert_eq!(first.content, "response 1");
+        assert_eq!(second.content, "response 1");
+        assert_eq!(other.content, "response 2");
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_worlds_do_not_share_cached_responses() {
+        let (inner, cached) = client(true, Duration::from_secs(60));
+        let in_world = |world_id| {
+            let mut request = request("Who is present?");
+            request.world_id = Some(world_id);
+            request
+        };
+        let (harbor, desert) = (WorldId::new(), WorldId::new());
+
+        let first = cached.generate(in_world(harbor)).await.unwrap();
+        let other = cached.generate(in_world(desert)).await.unwrap();
+        let again = cached.generate(in_world(harbor)).await.unwrap();
+
+        assert_eq!(first.content, "response 1");
+        assert_eq!(other.content, "response 2");
+        assert_eq!(again.content, "response 1");
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_requests_without_cache_flag_bypass_the_cache() {
+        let (inner, cached) = client(true, Duration::from_secs(60));
+        let creative = || LlmRequest::new(vec![ChatMessage::user("Describe the scene")]);
+
+        cached.generate(creative()).await.unwrap();
+        cached.generate(creative()).await.unwrap();
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_cache_always_calls_inner() {
+        let (inner, cached) = client(false, Duration::from_secs(60));
+
+        cached.generate(request("Who is present?")).await.unwrap();
+        cached.generate(request("Who is present?")).await.unwrap();
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_refreshed() {
+        let (inner, cached) = client(true, Duration::from_millis(20));
+
+        cached.generate(request("Who is present?")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        let refreshed = cached.generate(request("Who is present?")).await.unwrap();
+
+        assert_eq!(refreshed.content, "response 2");
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,284 @@
+//! Per-world LLM provider routing
+//!
+//! Sends each request to the provider its world's settings choose
+//! (`AppSettings::llm_provider`): the engine's Ollama, OpenAI or Anthropic.
+//! Untagged requests follow the global settings. Hosted clients are built on
+//! first use per world and rebuilt when its provider, key or endpoint change,
+//! each behind its own retry and circuit breaker so an outage at one provider
+//! doesn't trip the others.
+//!
+//! Responses without token usage get an estimate from the domain
+//! `TokenCounter`, so metering counts every call.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use wrldbldr_domain::{LlmProvider, LlmProviderSettings, TokenCounter, WorldId};
+
+use crate::infrastructure::anthropic::AnthropicClient;
+use crate::infrastructure::circuit_breaker::CircuitBreakerConfig;
+use crate::infrastructure::openai::OpenAiClient;
+use crate::infrastructure::ports::{
+    LlmError, LlmPort, LlmRequest, LlmResponse, SettingsRepo, TokenUsage, ToolDefinition,
+};
+use crate::infrastructure::resilient_llm::{ResilientLlmClient, RetryConfig};
+
+/// Builds the client for a hosted provider
+type Connector = fn(&LlmProviderSettings) -> Arc<dyn LlmPort>;
+
+/// Hosted client per world (`None` for the global settings), with the
+/// settings it was built from
+type HostedClients = HashMap<Option<WorldId>, (LlmProviderSettings, Arc<dyn LlmPort>)>;
+
+/// Wrapper that routes each request to its world's LLM provider
+pub struct WorldLlmRouter {
+    ollama: Arc<dyn LlmPort>,
+    settings: Arc<dyn SettingsRepo>,
+    retry_config: RetryConfig,
+    circuit_breaker_config: CircuitBreakerConfig,
+    connect: Connector,
+    hosted: Mutex<HostedClients>,
+    counter: TokenCounter,
+}
+
+impl WorldLlmRouter {
+    /// `ollama` should already carry its own retry and circuit breaker
+    pub fn new(
+        ollama: Arc<dyn LlmPort>,
+        settings: Arc<dyn SettingsRepo>,
+        retry_config: RetryConfig,
+        circuit_breaker_config: CircuitBreakerConfig,
+    ) -> Self {
+        Self {
+            ollama,
+            settings,
+            retry_config,
+            circuit_breaker_config,
+            connect: connect_hosted,
+            hosted: Mutex::new(HashMap::new()),
+            counter: TokenCounter::default(),
+        }
+    }
+
+    #[cfg(test)]
+    fn with_connector(mut self, connect: Connector) -> Self {
+        self.connect = connect;
+        self
+    }
+
+    /// The provider settings a request falls under: its world's, else the
+    /// global ones. Unreadable settings fall back to Ollama.
+    async fn provider_for(&self, world_id: Option<WorldId>) -> LlmProviderSettings {
+        let settings = match world_id {
+            Some(world_id) => match self.settings.get_for_world(world_id).await {
+                Ok(Some(settings)) => Ok(Some(settings)),
+                Ok(None) => self.settings.get_global().await,
+                Err(e) => Err(e),
+            },
+            None => self.settings.get_global().await,
+        };
+        match settings {
+            Ok(settings) => settings.map(|s| s.llm_provider).unwrap_or_default(),
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to read LLM provider settings; using Ollama");
+                LlmProviderSettings::default()
+            }
+        }
+    }
+
+    async fn client_for(&self, world_id: Option<WorldId>) -> Arc<dyn LlmPort> {
+        let provider = self.provider_for(world_id).await;
+        let mut hosted = self.hosted.lock().unwrap_or_else(|e| e.into_inner());
+        if !provider.uses_hosted() {
+            hosted.remove(&world_id);
+            return self.ollama.clone();
+        }
+
+        match hosted.get(&world_id) {
+            Some((built_from, client)) if *built_from == provider => client.clone(),
+            _ => {
+                tracing::info!(provider = %provider.provider, "Connecting hosted LLM provider");
+                let client: Arc<dyn LlmPort> = Arc::new(ResilientLlmClient::with_circuit_breaker(
+                    (self.connect)(&provider),
+                    self.retry_config.clone(),
+                    self.circuit_breaker_config.clone(),
+                ));
+                hosted.insert(world_id, (provider, client.clone()));
+                client
+            }
+        }
+    }
+
+    /// Fill in token usage the provider didn't report
+    fn with_usage(&self, request: &LlmRequest, mut response: LlmResponse) -> LlmResponse {
+        if response.usage.is_none() {
+            let prompt_tokens = request
+                .system_prompt
+                .iter()
+                .chain(request.messages.iter().map(|m| &m.content))
+                .map(|text| self.counter.count(text))
+                .sum::<usize>() as u32;
+            let completion_tokens = self.counter.count(&response.content) as u32;
+            response.usage = Some(TokenUsage {
+                prompt_tokens,
+                completion_tokens,
+                total_tokens: prompt_tokens + completion_tokens,
+            });
+        }
+        response
+    }
+}
+
+fn connect_hosted(settings: &LlmProviderSettings) -> Arc<dyn LlmPort> {
+    let api_key = settings.api_key().unwrap_or_default();
+    let base_url = settings.base_url.as_deref();
+    let model = settings.model.as_deref();
+    match settings.provider {
+        LlmProvider::Anthropic => Arc::new(AnthropicClient::new(base_url, api_key, model)),
+        _ => Arc::new(OpenAiClient::new(base_url, api_key, model)),
+    }
+}
+
+#[async_trait]
+impl LlmPort for WorldLlmRouter {
+    async fn generate(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
+        let client = self.client_for(request.world_id).await;
+        let response = client.generate(request.clone()).await?;
+        Ok(self.with_usage(&request, response))
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: LlmRequest,
+        tools: Vec<ToolDefinition>,
+    ) -> Result<LlmResponse, LlmError> {
+        let client = self.client_for(request.world_id).await;
+        let response = client.generate_with_tools(request.clone(), tools).await?;
+        Ok(self.with_usage(&request, response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ports::{ChatMessage, FinishReason, MockSettingsRepo};
+    use wrldbldr_domain::AppSettings;
+
+    /// Mock LLM that answers with a fixed text and no usage
+    struct NamedLlm(&'static str);
+
+    #[async_trait]
+    impl LlmPort for NamedLlm {
+        async fn generate(&self, _request: LlmRequest) -> Result<LlmResponse, LlmError> {
+            Ok(LlmResponse {
+                content: self.0.to_string(),
+                tool_calls: vec![],
+                finish_reason: FinishReason::Stop,
+                usage: None,
+            })
+        }
+
+        async fn generate_with_tools(
+            &self,
+            request: LlmRequest,
+            _tools: Vec<ToolDefinition>,
+        ) -> Result<LlmResponse, LlmError> {
+            self.generate(request).await
+        }
+    }
+
+    fn connect_named(settings: &LlmProviderSettings) -> Arc<dyn LlmPort> {
+        match settings.provider {
+            LlmProvider::Anthropic => Arc::new(NamedLlm("anthropic")),
+            _ => Arc::new(NamedLlm("openai")),
+        }
+    }
+
+    #[tokio::test]
+    async fn requests_go_to_their_worlds_provider() {
+        let hosted_world = WorldId::new();
+        let keyless_world = WorldId::new();
+
+        let mut repo = MockSettingsRepo::new();
+        repo.expect_get_for_world().returning(move |world_id| {
+            let mut settings = AppSettings::default();
+            settings.llm_provider.provider = LlmProvider::Anthropic;
+            if world_id == hosted_world {
+                settings.llm_provider.api_key = Some("sk-ant".to_string());
+            }
+            Ok(Some(settings))
+        });
+        repo.expect_get_global().returning(|| Ok(None));
+
+        let router = WorldLlmRouter::new(
+            Arc::new(NamedLlm("ollama")),
+            Arc::new(repo),
+            RetryConfig {
+                max_retries: 0,
+                ..Default::default()
+            },
+            CircuitBreakerConfig::default(),
+        )
+        .with_connector(connect_named);
+
+        let ask = |world_id: Option<WorldId>| {
+            let mut request = LlmRequest::new(vec![ChatMessage::user("Who goes there?")]);
+            request.world_id = world_id;
+            router.generate(request)
+        };
+
+        let response = ask(Some(hosted_world)).await.unwrap();
+        assert_eq!(response.content, "anthropic");
+        // Usage the provider left out is estimated
+        let usage = response.usage.unwrap();
+        assert!(usage.prompt_tokens > 0 && usage.completion_tokens > 0);
+
+        // No key: stays on Ollama, as do untagged requests with default settings
+        assert_eq!(ask(Some(keyless_world)).await.unwrap().content, "ollama");
+        assert_eq!(ask(None).await.unwrap().content, "ollama");
+    }
+
+    #[tokio::test]
+    async fn changed_settings_replace_the_worlds_client() {
+        let world_id = WorldId::new();
+        let api_key = Arc::new(Mutex::new(Some("sk-old".to_string())));
+
+        let mut repo = MockSettingsRepo::new();
+        let key_for_repo = api_key.clone();
+        repo.expect_get_for_world().returning(move |_| {
+            let mut settings = AppSettings::default();
+            settings.llm_provider.provider = LlmProvider::OpenAi;
+            settings.llm_provider.api_key = key_for_repo.lock().unwrap().clone();
+            Ok(Some(settings))
+        });
+
+        let router = WorldLlmRouter::new(
+            Arc::new(NamedLlm("ollama")),
+            Arc::new(repo),
+            RetryConfig::default(),
+            CircuitBreakerConfig::default(),
+        )
+        .with_connector(connect_named);
+        let built_with = || {
+            let hosted = router.hosted.lock().unwrap();
+            hosted
+                .get(&Some(world_id))
+                .map(|(settings, _)| settings.api_key.clone())
+        };
+
+        router.client_for(Some(world_id)).await;
+        assert_eq!(built_with(), Some(Some("sk-old".to_string())));
+
+        // A new key replaces the client rather than adding one
+        *api_key.lock().unwrap() = Some("sk-new".to_string());
+        router.client_for(Some(world_id)).await;
+        assert_eq!(built_with(), Some(Some("sk-new".to_string())));
+        assert_eq!(router.hosted.lock().unwrap().len(), 1);
+
+        // Back on Ollama, the hosted client is dropped
+        *api_key.lock().unwrap() = None;
+        router.client_for(Some(world_id)).await;
+        assert!(router.hosted.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+//! Per-world LLM usage metering
+//!
+//! Wraps any LlmPort and records the tokens each world-tagged request used
+//! (see `LlmRequest::with_world`). Recording failures are logged and never
+//! fail the call itself. Sits inside the response cache, so cache hits cost
+//! nothing.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::infrastructure::ports::{
+    ClockPort, LlmError, LlmPort, LlmRequest, LlmResponse, ToolDefinition, UsageDelta, UsageRepo,
+};
+
+/// Wrapper that records token usage per world
+pub struct MeteredLlmClient {
+    inner: Arc<dyn LlmPort>,
+    usage: Arc<dyn UsageRepo>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl MeteredLlmClient {
+    pub fn new(
+        inner: Arc<dyn LlmPort>,
+        usage: Arc<dyn UsageRepo>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            inner,
+            usage,
+            clock,
+        }
+    }
+
+    async fn record(
+        &self,
+        request_world: Option<wrldbldr_domain::WorldId>,
+        response: &LlmResponse,
+    ) {
+        let Some(world_id) = request_world else {
+            return;
+        };
+        let day = self.clock.now().date_naive();
+        if let Err(e) = self
+            .usage
+            .record(world_id, day, UsageDelta::llm(response.usage.as_ref()))
+            .await
+        {
+            tracing::warn!(error = %e, world_id = %world_id, "Failed to record LLM usage");
+        }
+    }
+}
+
+#[async_trait]
+impl LlmPort for MeteredLlmClient {
+    async fn generate(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
+        let world_id = request.world_id;
+        let response = self.inner.generate(request).await?;
+        self.record(world_id, &response).await;
+        Ok(response)
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: LlmRequest,
+        tools: Vec<ToolDefinition>,
+    ) -> Result<LlmResponse, LlmError> {
+        let world_id = request.world_id;
+        let response = self.inner.generate_with_tools(request, tools).await?;
+        self.record(world_id, &response).await;
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{ChatMessage, FinishReason, MockUsageRepo, TokenUsage};
+    use chrono::{TimeZone, Utc};
+    use wrldbldr_domain::WorldId;
+
+    /// Mock LLM that reports fixed token usage
+    struct FixedUsageLlm;
+
+    #[async_trait]
+    impl LlmPort for FixedUsageLlm {
+        async fn generate(&self, _request: LlmRequest) -> Result<LlmResponse, LlmError> {
+            Ok(response())
+        }
+
+        async fn generate_with_tools(
+            &self,
+            request: LlmRequest,
+            _tools: Vec<ToolDefinition>,
+        ) -> Result<LlmResponse, LlmError> {
+            self.generate(request).await
+        }
+    }
+
+    fn response() -> LlmResponse {
+        LlmResponse {
+            content: "Hello".to_string(),
+            tool_calls: vec![],
+            finish_reason: FinishReason::Stop,
+            usage: Some(TokenUsage {
+                prompt_tokens: 120,
+                completion_tokens: 30,
+                total_tokens: 150,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_world_tagged_requests_are_recorded() {
+        let world_id = WorldId::new();
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+
+        let mut usage = MockUsageRepo::new();
+        usage
+            .expect_record()
+            .withf(move |id, day, delta| {
+                *id == world_id
+                    && *day == now.date_naive()
+                    && delta.llm_requests == 1
+                    && delta.prompt_tokens == 120
+                    && delta.completion_tokens == 30
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let client = MeteredLlmClient::new(
+            Arc::new(FixedUsageLlm),
+            Arc::new(usage),
+            Arc::new(FixedClock(now)),
+        );
+
+        client
+            .generate(LlmRequest::new(vec![ChatMessage::user("Hi")]).with_world(world_id))
+            .await
+            .unwrap();
+        // Untagged requests aren't attributed to any world
+        client
+            .generate(LlmRequest::new(vec![ChatMessage::user("Hi")]))
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,1103 @@
+//! Characters, player characters and what they know about each other.
+
+use async_trait::async_trait;
+use wrldbldr_domain::*;
+
+use super::{MemoryState, MemoryStore, WantRow};
+use crate::infrastructure::ports::{
+    ActantialViewRecord, CharacterRepo, CompanionRepo, DialogueTreeRepo, FactionRepo, InjuryRepo,
+    LetterRepo, NpcDraftRepo, NpcRegionRelationType, NpcRegionRelationship, NpcWithRegionInfo,
+    ObservationRepo, PcSecretRepo, PlayerCharacterRepo, PropertyRepo, RepoError, WantDetails,
+    WantTargetRef,
+};
+
+impl MemoryState {
+    fn items_for<K: PartialEq>(&self, owners: &[(K, ItemId)], owner: K) -> Vec<Item> {
+        owners
+            .iter()
+            .filter(|(o, _)| *o == owner)
+            .filter_map(|(_, item_id)| self.items.get(*item_id).cloned())
+            .collect()
+    }
+
+    fn want_details(&self, want_id: WantId, row: &WantRow) -> WantDetails {
+        WantDetails {
+            character_id: row.character_id,
+            want: row.want.clone(),
+            priority: row.priority,
+            target: self.want_targets.get(want_id).cloned(),
+        }
+    }
+
+    fn actantial_target_name(&self, target: &ActantialTarget) -> String {
+        match target {
+            ActantialTarget::Npc(id) => self
+                .characters
+                .get(CharacterId::from_uuid(*id))
+                .map(|c| c.name.clone()),
+            ActantialTarget::Pc(id) => self
+                .player_characters
+                .get(PlayerCharacterId::from_uuid(*id))
+                .map(|pc| pc.name.clone()),
+        }
+        .unwrap_or_default()
+    }
+
+    /// Replace the NPC's relationship of this type to `region_id`, or to any
+    /// region when the type is exclusive (home, work)
+    fn set_region_relationship(
+        &mut self,
+        id: CharacterId,
+        relationship: NpcRegionRelationship,
+        exclusive: bool,
+    ) {
+        let kind = relationship.relationship_type;
+        let region_id = relationship.region_id;
+        self.region_relationships.retain(|(c, r)| {
+            !(*c == id && r.relationship_type == kind && (exclusive || r.region_id == region_id))
+        });
+        self.region_relationships.push((id, relationship));
+    }
+}
+
+fn region_relationship(region_id: RegionId, kind: NpcRegionRelationType) -> NpcRegionRelationship {
+    NpcRegionRelationship {
+        region_id,
+        relationship_type: kind,
+        shift: None,
+        frequency: None,
+        time_of_day: None,
+        reason: None,
+    }
+}
+
+#[async_trait]
+impl CharacterRepo for MemoryStore {
+    async fn get(&self, id: CharacterId) -> Result<Option<Character>, RepoError> {
+        Ok(self.state().characters.get(id).cloned())
+    }
+
+    async fn save(&self, character: &Character) -> Result<(), RepoError> {
+        self.state()
+            .characters
+            .insert(character.id, character.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: CharacterId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.characters.remove(id);
+        state.character_regions.remove(id);
+        state.character_inventory.retain(|(c, _)| *c != id);
+        state.region_relationships.retain(|(c, _)| *c != id);
+        state.companions.rows.retain(|(_, c)| c.character_id != id);
+        Ok(())
+    }
+
+    async fn list_in_region(&self, region_id: RegionId) -> Result<Vec<Character>, RepoError> {
+        let state = self.state();
+        let mut characters: Vec<Character> = state
+            .characters
+            .values()
+            .filter(|c| state.character_regions.get(c.id) == Some(&region_id))
+            .cloned()
+            .collect();
+        characters.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(characters)
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Character>, RepoError> {
+        Ok(self
+            .state()
+            .characters
+            .values()
+            .filter(|c| c.world_id == world_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_npcs_in_world(&self, world_id: WorldId) -> Result<Vec<Character>, RepoError> {
+        CharacterRepo::list_in_world(self, world_id).await
+    }
+
+    async fn update_position(&self, id: CharacterId, region_id: RegionId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.characters.contains(id) {
+            return Err(RepoError::NotFound);
+        }
+        state.character_regions.insert(id, region_id);
+        Ok(())
+    }
+
+    async fn get_relationships(&self, id: CharacterId) -> Result<Vec<Relationship>, RepoError> {
+        Ok(self
+            .state()
+            .relationships
+            .values()
+            .filter(|r| r.from_character == id)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_relationship(&self, relationship: &Relationship) -> Result<(), RepoError> {
+        self.state()
+            .relationships
+            .insert(relationship.id, relationship.clone());
+        Ok(())
+    }
+
+    async fn delete_relationship(&self, id: RelationshipId) -> Result<(), RepoError> {
+        self.state().relationships.remove(id);
+        Ok(())
+    }
+
+    async fn get_inventory(&self, id: CharacterId) -> Result<Vec<Item>, RepoError> {
+        let state = self.state();
+        Ok(state.items_for(&state.character_inventory, id))
+    }
+
+    async fn add_to_inventory(
+        &self,
+        character_id: CharacterId,
+        item_id: ItemId,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.characters.contains(character_id) || !state.items.contains(item_id) {
+            return Err(RepoError::NotFound);
+        }
+        if !state.character_inventory.contains(&(character_id, item_id)) {
+            state.character_inventory.push((character_id, item_id));
+        }
+        Ok(())
+    }
+
+    async fn remove_from_inventory(
+        &self,
+        character_id: CharacterId,
+        item_id: ItemId,
+    ) -> Result<(), RepoError> {
+        self.state()
+            .character_inventory
+            .retain(|entry| *entry != (character_id, item_id));
+        Ok(())
+    }
+
+    async fn get_wants(&self, id: CharacterId) -> Result<Vec<WantDetails>, RepoError> {
+        let state = self.state();
+        let mut wants: Vec<WantDetails> = state
+            .wants
+            .rows
+            .iter()
+            .filter(|(_, row)| row.character_id == id)
+            .map(|(want_id, row)| state.want_details(*want_id, row))
+            .collect();
+        wants.sort_by_key(|w| w.priority);
+        Ok(wants)
+    }
+
+    async fn get_want(&self, id: WantId) -> Result<Option<WantDetails>, RepoError> {
+        let state = self.state();
+        Ok(state.wants.get(id).map(|row| state.want_details(id, row)))
+    }
+
+    async fn save_want(
+        &self,
+        character_id: CharacterId,
+        want: &Want,
+        priority: u32,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.characters.contains(character_id) {
+            return Err(RepoError::NotFound);
+        }
+        state.wants.insert(
+            want.id,
+            WantRow {
+                character_id,
+                want: want.clone(),
+                priority,
+            },
+        );
+        Ok(())
+    }
+
+    async fn delete_want(&self, id: WantId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.wants.remove(id);
+        state.want_targets.remove(id);
+        state.actantial_views.retain(|(_, view)| view.want_id != id);
+        Ok(())
+    }
+
+    async fn set_want_target(
+        &self,
+        want_id: WantId,
+        target: WantTargetRef,
+    ) -> Result<WantTarget, RepoError> {
+        let mut state = self.state();
+        if !state.wants.contains(want_id) {
+            return Err(RepoError::NotFound);
+        }
+        let resolved = match target {
+            WantTargetRef::Character(id) => {
+                state.characters.get(id).map(|c| WantTarget::Character {
+                    id: id.to_uuid(),
+                    name: c.name.clone(),
+                })
+            }
+            WantTargetRef::Item(id) => state.items.get(id).map(|i| WantTarget::Item {
+                id: id.to_uuid(),
+                name: i.name.clone(),
+            }),
+            WantTargetRef::Goal(id) => state.goals.get(id).map(|g| WantTarget::Goal {
+                id: id.to_uuid(),
+                name: g.name.clone(),
+                description: g.description.clone(),
+            }),
+        }
+        .ok_or(RepoError::NotFound)?;
+        state.want_targets.insert(want_id, resolved.clone());
+        Ok(resolved)
+    }
+
+    async fn remove_want_target(&self, want_id: WantId) -> Result<(), RepoError> {
+        self.state().want_targets.remove(want_id);
+        Ok(())
+    }
+
+    async fn get_disposition(
+        &self,
+        npc_id: CharacterId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Option<NpcDispositionState>, RepoError> {
+        Ok(self
+            .state()
+            .dispositions
+            .iter()
+            .find(|d| d.npc_id == npc_id && d.pc_id == pc_id)
+            .cloned())
+    }
+
+    async fn save_disposition(&self, disposition: &NpcDispositionState) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state
+            .dispositions
+            .retain(|d| !(d.npc_id == disposition.npc_id && d.pc_id == disposition.pc_id));
+        state.dispositions.push(disposition.clone());
+        Ok(())
+    }
+
+    async fn list_dispositions_for_pc(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<NpcDispositionState>, RepoError> {
+        Ok(self
+            .state()
+            .dispositions
+            .iter()
+            .filter(|d| d.pc_id == pc_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_actantial_context(
+        &self,
+        id: CharacterId,
+    ) -> Result<Option<ActantialContext>, RepoError> {
+        let state = self.state();
+        if let Some(context) = state.actantial_contexts.get(id) {
+            return Ok(Some(context.clone()));
+        }
+        Ok(state
+            .characters
+            .get(id)
+            .map(|c| ActantialContext::new(id.to_uuid(), c.name.clone())))
+    }
+
+    async fn save_actantial_context(
+        &self,
+        id: CharacterId,
+        context: &ActantialContext,
+    ) -> Result<(), RepoError> {
+        self.state().actantial_contexts.insert(id, context.clone());
+        Ok(())
+    }
+
+    async fn list_actantial_views(
+        &self,
+        id: CharacterId,
+    ) -> Result<Vec<ActantialViewRecord>, RepoError> {
+        Ok(self
+            .state()
+            .actantial_views
+            .iter()
+            .filter(|(c, _)| *c == id)
+            .map(|(_, view)| view.clone())
+            .collect())
+    }
+
+    async fn add_actantial_view(
+        &self,
+        character_id: CharacterId,
+        want_id: WantId,
+        target: ActantialTarget,
+        role: ActantialRole,
+        reason: String,
+    ) -> Result<ActantialViewRecord, RepoError> {
+        let mut state = self.state();
+        if !state.wants.contains(want_id) {
+            return Err(RepoError::NotFound);
+        }
+        let record = ActantialViewRecord {
+            want_id,
+            target_name: state.actantial_target_name(&target),
+            target,
+            role,
+            reason,
+        };
+        state.actantial_views.retain(|(c, view)| {
+            !(*c == character_id
+                && view.want_id == want_id
+                && view.target == record.target
+                && view.role == role)
+        });
+        state.actantial_views.push((character_id, record.clone()));
+        Ok(record)
+    }
+
+    async fn remove_actantial_view(
+        &self,
+        character_id: CharacterId,
+        want_id: WantId,
+        target: ActantialTarget,
+        role: ActantialRole,
+    ) -> Result<(), RepoError> {
+        self.state().actantial_views.retain(|(c, view)| {
+            !(*c == character_id
+                && view.want_id == want_id
+                && view.target == target
+                && view.role == role)
+        });
+        Ok(())
+    }
+
+    async fn get_region_relationships(
+        &self,
+        id: CharacterId,
+    ) -> Result<Vec<NpcRegionRelationship>, RepoError> {
+        Ok(self
+            .state()
+            .region_relationships
+            .iter()
+            .filter(|(c, _)| *c == id)
+            .map(|(_, r)| r.clone())
+            .collect())
+    }
+
+    async fn set_home_region(&self, id: CharacterId, region_id: RegionId) -> Result<(), RepoError> {
+        self.state().set_region_relationship(
+            id,
+            region_relationship(region_id, NpcRegionRelationType::HomeRegion),
+            true,
+        );
+        Ok(())
+    }
+
+    async fn set_work_region(
+        &self,
+        id: CharacterId,
+        region_id: RegionId,
+        shift: Option<String>,
+    ) -> Result<(), RepoError> {
+        let relationship = NpcRegionRelationship {
+            shift,
+            ..region_relationship(region_id, NpcRegionRelationType::WorksAt)
+        };
+        self.state().set_region_relationship(id, relationship, true);
+        Ok(())
+    }
+
+    async fn add_frequents_region(
+        &self,
+        id: CharacterId,
+        region_id: RegionId,
+        frequency: String,
+        time_of_day: Option<String>,
+    ) -> Result<(), RepoError> {
+        let relationship = NpcRegionRelationship {
+            frequency: Some(frequency),
+            time_of_day,
+            ..region_relationship(region_id, NpcRegionRelationType::Frequents)
+        };
+        self.state()
+            .set_region_relationship(id, relationship, false);
+        Ok(())
+    }
+
+    async fn add_avoids_region(
+        &self,
+        id: CharacterId,
+        region_id: RegionId,
+        reason: Option<String>,
+    ) -> Result<(), RepoError> {
+        let relationship = NpcRegionRelationship {
+            reason,
+            ..region_relationship(region_id, NpcRegionRelationType::Avoids)
+        };
+        self.state()
+            .set_region_relationship(id, relationship, false);
+        Ok(())
+    }
+
+    async fn remove_region_relationship(
+        &self,
+        id: CharacterId,
+        region_id: RegionId,
+        relationship_type: &str,
+    ) -> Result<(), RepoError> {
+        let kind = match relationship_type.to_uppercase().as_str() {
+            "HOME_REGION" | "HOME" => NpcRegionRelationType::HomeRegion,
+            "WORKS_AT_REGION" | "WORKS_AT" | "WORK" => NpcRegionRelationType::WorksAt,
+            "FREQUENTS_REGION" | "FREQUENTS" => NpcRegionRelationType::Frequents,
+            "AVOIDS_REGION" | "AVOIDS" => NpcRegionRelationType::Avoids,
+            _ => {
+                return Err(RepoError::Database(format!(
+                    "Unknown relationship type: {}",
+                    relationship_type
+                )))
+            }
+        };
+        self.state().region_relationships.retain(|(c, r)| {
+            !(*c == id && r.region_id == region_id && r.relationship_type == kind)
+        });
+        Ok(())
+    }
+
+    async fn get_npcs_for_region(
+        &self,
+        region_id: RegionId,
+    ) -> Result<Vec<NpcWithRegionInfo>, RepoError> {
+        let state = self.state();
+        Ok(state
+            .region_relationships
+            .iter()
+            .filter(|(_, r)| r.region_id == region_id)
+            .filter_map(|(id, r)| {
+                let npc = state.characters.get(*id)?;
+                Some(NpcWithRegionInfo {
+                    character_id: npc.id,
+                    name: npc.name.clone(),
+                    sprite_asset: npc.sprite_asset.clone(),
+                    portrait_asset: npc.portrait_asset.clone(),
+                    relationship_type: r.relationship_type,
+                    shift: r.shift.clone(),
+                    frequency: r.frequency.clone(),
+                    time_of_day: r.time_of_day.clone(),
+                    reason: r.reason.clone(),
+                    default_mood: npc.default_mood,
+                })
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl PlayerCharacterRepo for MemoryStore {
+    async fn get(&self, id: PlayerCharacterId) -> Result<Option<PlayerCharacter>, RepoError> {
+        Ok(self.state().player_characters.get(id).cloned())
+    }
+
+    async fn save(&self, pc: &PlayerCharacter) -> Result<(), RepoError> {
+        self.state().player_characters.insert(pc.id, pc.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: PlayerCharacterId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.player_characters.remove(id);
+        state.pc_inventory.retain(|(pc, _)| *pc != id);
+        state.pc_stats.remove(id);
+        state.properties.rows.retain(|(_, p)| p.owner_id != id);
+        state.companions.rows.retain(|(_, c)| c.pc_id != id);
+        state.injuries.rows.retain(|(_, i)| i.pc_id != id);
+        state.pc_secrets.rows.retain(|(_, s)| s.pc_id != id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<PlayerCharacter>, RepoError> {
+        Ok(self
+            .state()
+            .player_characters
+            .values()
+            .filter(|pc| pc.world_id == world_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_by_user(
+        &self,
+        world_id: WorldId,
+        user_id: &str,
+    ) -> Result<Option<PlayerCharacter>, RepoError> {
+        Ok(self
+            .state()
+            .player_characters
+            .values()
+            .filter(|pc| pc.world_id == world_id && pc.user_id == user_id)
+            .max_by_key(|pc| (pc.is_alive, pc.last_active_at))
+            .cloned())
+    }
+
+    async fn update_position(
+        &self,
+        id: PlayerCharacterId,
+        location_id: LocationId,
+        region_id: RegionId,
+    ) -> Result<(), RepoError> {
+        let now = self.now();
+        let mut state = self.state();
+        let pc = state
+            .player_characters
+            .get_mut(id)
+            .ok_or(RepoError::NotFound)?;
+        pc.current_location_id = location_id;
+        pc.current_region_id = Some(region_id);
+        pc.last_active_at = now;
+        Ok(())
+    }
+
+    async fn get_inventory(&self, id: PlayerCharacterId) -> Result<Vec<Item>, RepoError> {
+        let state = self.state();
+        Ok(state.items_for(&state.pc_inventory, id))
+    }
+
+    async fn add_to_inventory(
+        &self,
+        pc_id: PlayerCharacterId,
+        item_id: ItemId,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.player_characters.contains(pc_id) || !state.items.contains(item_id) {
+            return Err(RepoError::NotFound);
+        }
+        if !state.pc_inventory.contains(&(pc_id, item_id)) {
+            state.pc_inventory.push((pc_id, item_id));
+        }
+        Ok(())
+    }
+
+    async fn remove_from_inventory(
+        &self,
+        pc_id: PlayerCharacterId,
+        item_id: ItemId,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state
+            .pc_inventory
+            .retain(|entry| *entry != (pc_id, item_id));
+        state.equipped.retain(|entry| *entry != (pc_id, item_id));
+        Ok(())
+    }
+
+    async fn modify_stat(
+        &self,
+        id: PlayerCharacterId,
+        stat: &str,
+        modifier: i32,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.player_characters.contains(id) {
+            return Err(RepoError::NotFound);
+        }
+        if !state.pc_stats.contains(id) {
+            state.pc_stats.insert(id, Default::default());
+        }
+        if let Some(stats) = state.pc_stats.get_mut(id) {
+            *stats.entry(stat.to_string()).or_insert(0) += i64::from(modifier);
+        }
+        Ok(())
+    }
+
+    async fn execute_trade(
+        &self,
+        offer: &TradeOffer,
+        currency_field: Option<String>,
+    ) -> Result<(), RepoError> {
+        let currency_moves = offer.offered.currency > 0 || offer.requested.currency > 0;
+        let currency_field = match (currency_moves, currency_field) {
+            (false, _) => None,
+            (true, Some(field)) => Some(field),
+            (true, None) => {
+                return Err(RepoError::ConstraintViolation(
+                    "This game system doesn't track currency".to_string(),
+                ))
+            }
+        };
+
+        let mut state = self.state();
+        // (giver, receiver, what the giver hands over)
+        let legs = [
+            (offer.from_pc, offer.to_pc, &offer.offered),
+            (offer.to_pc, offer.from_pc, &offer.requested),
+        ];
+
+        for (giver, _, side) in legs {
+            let owns_all = side
+                .item_ids
+                .iter()
+                .all(|item_id| state.pc_inventory.contains(&(giver, *item_id)));
+            if !owns_all {
+                return Err(RepoError::ConstraintViolation(
+                    "An item in the trade is no longer held by its owner".to_string(),
+                ));
+            }
+        }
+
+        let mut sheets = Vec::new();
+        if let Some(field) = &currency_field {
+            let net_to_sender = offer.requested.currency as i64 - offer.offered.currency as i64;
+            for pc_id in [offer.from_pc, offer.to_pc] {
+                let mut sheet = state
+                    .player_characters
+                    .get(pc_id)
+                    .ok_or(RepoError::NotFound)?
+                    .sheet_data
+                    .clone()
+                    .unwrap_or_default();
+                let change = if pc_id == offer.from_pc {
+                    net_to_sender
+                } else {
+                    -net_to_sender
+                };
+                let balance = sheet.get_number(field).unwrap_or(0) as i64 + change;
+                if balance < 0 {
+                    return Err(RepoError::ConstraintViolation(format!(
+                        "Not enough {} for the trade",
+                        field
+                    )));
+                }
+                let balance = i32::try_from(balance).map_err(|_| {
+                    RepoError::ConstraintViolation(format!("{} balance out of range", field))
+                })?;
+                sheet.set(field.clone(), FieldValue::Number(balance));
+                sheets.push((pc_id, sheet));
+            }
+        }
+
+        for (giver, receiver, side) in legs {
+            for item_id in &side.item_ids {
+                state
+                    .pc_inventory
+                    .retain(|entry| *entry != (giver, *item_id));
+                state.equipped.retain(|entry| *entry != (giver, *item_id));
+                state.pc_inventory.push((receiver, *item_id));
+            }
+        }
+
+        for (pc_id, sheet) in sheets {
+            if let Some(pc) = state.player_characters.get_mut(pc_id) {
+                pc.sheet_data = Some(sheet);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ObservationRepo for MemoryStore {
+    async fn get_observations(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<NpcObservation>, RepoError> {
+        Ok(self
+            .state()
+            .observations
+            .iter()
+            .filter(|o| o.pc_id == pc_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_observation(&self, observation: &NpcObservation) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state
+            .observations
+            .retain(|o| !(o.pc_id == observation.pc_id && o.npc_id == observation.npc_id));
+        state.observations.push(observation.clone());
+        Ok(())
+    }
+
+    async fn delete_observation(
+        &self,
+        pc_id: PlayerCharacterId,
+        target_id: CharacterId,
+    ) -> Result<(), RepoError> {
+        self.state()
+            .observations
+            .retain(|o| !(o.pc_id == pc_id && o.npc_id == target_id));
+        Ok(())
+    }
+
+    async fn has_observed(
+        &self,
+        pc_id: PlayerCharacterId,
+        target_id: CharacterId,
+    ) -> Result<bool, RepoError> {
+        Ok(self
+            .state()
+            .observations
+            .iter()
+            .any(|o| o.pc_id == pc_id && o.npc_id == target_id))
+    }
+
+    async fn save_deduced_info(
+        &self,
+        pc_id: PlayerCharacterId,
+        info: String,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.player_characters.contains(pc_id) {
+            return Err(RepoError::NotFound);
+        }
+        state.deduced_info.push((pc_id, info));
+        Ok(())
+    }
+
+    async fn get_known_locations(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<LocationObservation>, RepoError> {
+        Ok(self
+            .state()
+            .location_observations
+            .iter()
+            .filter(|o| o.pc_id == pc_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_location_observation(
+        &self,
+        observation: &LocationObservation,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let same = |o: &LocationObservation| {
+            o.pc_id == observation.pc_id && o.location_id == observation.location_id
+        };
+        if !observation.is_visited()
+            && state
+                .location_observations
+                .iter()
+                .any(|o| same(o) && o.is_visited())
+        {
+            return Ok(());
+        }
+        let mut observation = observation.clone();
+        observation.visits += state
+            .location_observations
+            .iter()
+            .find(|o| same(o))
+            .map_or(0, |o| o.visits);
+        state.location_observations.retain(|o| !same(o));
+        state.location_observations.push(observation);
+        Ok(())
+    }
+
+    async fn delete_location_observation(
+        &self,
+        pc_id: PlayerCharacterId,
+        location_id: LocationId,
+    ) -> Result<(), RepoError> {
+        self.state()
+            .location_observations
+            .retain(|o| !(o.pc_id == pc_id && o.location_id == location_id));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl NpcDraftRepo for MemoryStore {
+    async fn get(&self, id: NpcDraftId) -> Result<Option<NpcDraft>, RepoError> {
+        Ok(self.state().npc_drafts.get(id).cloned())
+    }
+
+    async fn save(&self, draft: &NpcDraft) -> Result<(), RepoError> {
+        self.state().npc_drafts.insert(draft.id, draft.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: NpcDraftId) -> Result<(), RepoError> {
+        self.state().npc_drafts.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<NpcDraft>, RepoError> {
+        let mut drafts: Vec<NpcDraft> = self
+            .state()
+            .npc_drafts
+            .values()
+            .filter(|d| d.world_id == world_id)
+            .cloned()
+            .collect();
+        drafts.sort_by_key(|d| std::cmp::Reverse(d.created_at));
+        Ok(drafts)
+    }
+}
+
+#[async_trait]
+impl PropertyRepo for MemoryStore {
+    async fn get(&self, id: PropertyId) -> Result<Option<Property>, RepoError> {
+        Ok(self.state().properties.get(id).cloned())
+    }
+
+    async fn save(&self, property: &Property) -> Result<(), RepoError> {
+        self.state()
+            .properties
+            .insert(property.id, property.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: PropertyId) -> Result<(), RepoError> {
+        self.state().properties.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_owner(
+        &self,
+        owner_id: PlayerCharacterId,
+    ) -> Result<Vec<Property>, RepoError> {
+        let mut properties: Vec<Property> = self
+            .state()
+            .properties
+            .values()
+            .filter(|p| p.owner_id == owner_id)
+            .cloned()
+            .collect();
+        properties.sort_by_key(|p| p.created_at);
+        Ok(properties)
+    }
+}
+
+#[async_trait]
+impl CompanionRepo for MemoryStore {
+    async fn get(&self, id: CompanionId) -> Result<Option<Companion>, RepoError> {
+        Ok(self.state().companions.get(id).cloned())
+    }
+
+    async fn save(&self, companion: &Companion) -> Result<(), RepoError> {
+        self.state()
+            .companions
+            .insert(companion.id, companion.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: CompanionId) -> Result<(), RepoError> {
+        self.state().companions.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<Companion>, RepoError> {
+        let mut companions: Vec<Companion> = self
+            .state()
+            .companions
+            .values()
+            .filter(|c| c.pc_id == pc_id)
+            .cloned()
+            .collect();
+        companions.sort_by_key(|c| c.created_at);
+        Ok(companions)
+    }
+
+    async fn get_for_character(
+        &self,
+        character_id: CharacterId,
+    ) -> Result<Option<Companion>, RepoError> {
+        Ok(self
+            .state()
+            .companions
+            .values()
+            .find(|c| c.character_id == character_id)
+            .cloned())
+    }
+}
+
+#[async_trait]
+impl InjuryRepo for MemoryStore {
+    async fn get(&self, id: InjuryId) -> Result<Option<Injury>, RepoError> {
+        Ok(self.state().injuries.get(id).cloned())
+    }
+
+    async fn save(&self, injury: &Injury) -> Result<(), RepoError> {
+        self.state().injuries.insert(injury.id, injury.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: InjuryId) -> Result<(), RepoError> {
+        self.state().injuries.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<Injury>, RepoError> {
+        let mut injuries: Vec<Injury> = self
+            .state()
+            .injuries
+            .values()
+            .filter(|i| i.pc_id == pc_id)
+            .cloned()
+            .collect();
+        injuries.sort_by_key(|i| i.created_at);
+        Ok(injuries)
+    }
+
+    async fn list_active_in_world(&self, world_id: WorldId) -> Result<Vec<Injury>, RepoError> {
+        let mut injuries: Vec<Injury> = self
+            .state()
+            .injuries
+            .values()
+            .filter(|i| i.world_id == world_id && !i.is_healed())
+            .cloned()
+            .collect();
+        injuries.sort_by_key(|i| i.created_at);
+        Ok(injuries)
+    }
+}
+
+#[async_trait]
+impl PcSecretRepo for MemoryStore {
+    async fn get(&self, id: PcSecretId) -> Result<Option<PcSecret>, RepoError> {
+        Ok(self.state().pc_secrets.get(id).cloned())
+    }
+
+    async fn save(&self, secret: &PcSecret) -> Result<(), RepoError> {
+        self.state().pc_secrets.insert(secret.id, secret.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: PcSecretId) -> Result<(), RepoError> {
+        self.state().pc_secrets.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<PcSecret>, RepoError> {
+        let mut secrets: Vec<PcSecret> = self
+            .state()
+            .pc_secrets
+            .values()
+            .filter(|s| s.pc_id == pc_id)
+            .cloned()
+            .collect();
+        secrets.sort_by_key(|s| s.created_at);
+        Ok(secrets)
+    }
+}
+
+#[async_trait]
+impl LetterRepo for MemoryStore {
+    async fn get(&self, id: LetterId) -> Result<Option<Letter>, RepoError> {
+        Ok(self.state().letters.get(id).cloned())
+    }
+
+    async fn save(&self, letter: &Letter) -> Result<(), RepoError> {
+        self.state().letters.insert(letter.id, letter.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: LetterId) -> Result<(), RepoError> {
+        self.state().letters.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<Letter>, RepoError> {
+        let mut letters: Vec<Letter> = self
+            .state()
+            .letters
+            .values()
+            .filter(|l| l.involves_pc(pc_id))
+            .cloned()
+            .collect();
+        letters.sort_by_key(|l| std::cmp::Reverse(l.sent_at));
+        Ok(letters)
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Letter>, RepoError> {
+        let mut letters: Vec<Letter> = self
+            .state()
+            .letters
+            .values()
+            .filter(|l| l.world_id == world_id)
+            .cloned()
+            .collect();
+        letters.sort_by_key(|l| std::cmp::Reverse(l.sent_at));
+        Ok(letters)
+    }
+
+    async fn list_undelivered_in_world(&self, world_id: WorldId) -> Result<Vec<Letter>, RepoError> {
+        let mut letters: Vec<Letter> = self
+            .state()
+            .letters
+            .values()
+            .filter(|l| l.world_id == world_id && !l.is_delivered())
+            .cloned()
+            .collect();
+        letters.sort_by_key(|l| l.deliver_at);
+        Ok(letters)
+    }
+}
+
+#[async_trait]
+impl FactionRepo for MemoryStore {
+    async fn get(&self, id: FactionId) -> Result<Option<Faction>, RepoError> {
+        Ok(self.state().factions.get(id).cloned())
+    }
+
+    async fn save(&self, faction: &Faction) -> Result<(), RepoError> {
+        self.state().factions.insert(faction.id, faction.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: FactionId) -> Result<(), RepoError> {
+        self.state().factions.remove(id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Faction>, RepoError> {
+        let mut factions: Vec<Faction> = self
+            .state()
+            .factions
+            .values()
+            .filter(|f| f.world_id == world_id)
+            .cloned()
+            .collect();
+        factions.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(factions)
+    }
+}
+
+#[async_trait]
+impl DialogueTreeRepo for MemoryStore {
+    async fn get(&self, id: DialogueTreeId) -> Result<Option<DialogueTree>, RepoError> {
+        Ok(self.state().dialogue_trees.get(id).cloned())
+    }
+
+    async fn save(&self, tree: &DialogueTree) -> Result<(), RepoError> {
+        self.state().dialogue_trees.insert(tree.id, tree.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: DialogueTreeId) -> Result<(), RepoError> {
+        self.state().dialogue_trees.remove(id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<DialogueTree>, RepoError> {
+        let mut trees: Vec<DialogueTree> = self
+            .state()
+            .dialogue_trees
+            .values()
+            .filter(|t| t.world_id == world_id)
+            .cloned()
+            .collect();
+        trees.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(trees)
+    }
+}
@@ -0,0 +1,1302 @@
+//! Worlds and the authored content inside them.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+use wrldbldr_domain::*;
+
+use super::{MemoryState, MemoryStore};
+use crate::infrastructure::ports::{
+    ActRepo, AssetRepo, ChallengeRepo, ContentDraftRepo, CustomFieldRepo, EconomyRepo, FlagRepo,
+    GoalDetails, GoalRepo, InteractionRepo, ItemRepo, LibraryRepo, LoreRepo, MentionRepo,
+    NameGeneratorRepo, ProgressClockRepo, RepoError, SceneRepo, SkillRepo, TagRepo, TagUsage,
+    TemplateRepo, WorldRepo,
+};
+
+impl MemoryState {
+    fn goal_details(&self, goal: &Goal) -> GoalDetails {
+        let usage_count = self
+            .want_targets
+            .values()
+            .filter(|t| matches!(t, WantTarget::Goal { id, .. } if *id == goal.id.to_uuid()))
+            .count();
+        GoalDetails {
+            goal: goal.clone(),
+            usage_count: usage_count as u32,
+        }
+    }
+
+    fn sorted_challenges(&self, keep: impl Fn(&Challenge) -> bool) -> Vec<Challenge> {
+        let mut challenges: Vec<Challenge> = self
+            .challenges
+            .values()
+            .filter(|c| keep(c))
+            .cloned()
+            .collect();
+        challenges.sort_by(|a, b| {
+            b.is_favorite
+                .cmp(&a.is_favorite)
+                .then(a.order.cmp(&b.order))
+        });
+        challenges
+    }
+
+    fn lore_in_world(&self, world_id: WorldId, keep: impl Fn(&Lore) -> bool) -> Vec<Lore> {
+        let mut lore: Vec<Lore> = self
+            .lore
+            .values()
+            .filter(|l| l.world_id == world_id && keep(l))
+            .cloned()
+            .collect();
+        lore.sort_by(|a, b| a.title.cmp(&b.title));
+        lore
+    }
+}
+
+#[async_trait]
+impl WorldRepo for MemoryStore {
+    async fn get(&self, id: WorldId) -> Result<Option<World>, RepoError> {
+        Ok(self.state().worlds.get(id).cloned())
+    }
+
+    async fn save(&self, world: &World) -> Result<(), RepoError> {
+        self.state().worlds.insert(world.id, world.clone());
+        Ok(())
+    }
+
+    async fn list_all(&self) -> Result<Vec<World>, RepoError> {
+        Ok(self.state().worlds.values().cloned().collect())
+    }
+
+    async fn delete(&self, id: WorldId) -> Result<(), RepoError> {
+        self.state().worlds.remove(id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SceneRepo for MemoryStore {
+    async fn get(&self, id: SceneId) -> Result<Option<Scene>, RepoError> {
+        Ok(self.state().scenes.get(id).cloned())
+    }
+
+    async fn save(&self, scene: &Scene) -> Result<(), RepoError> {
+        self.state().scenes.insert(scene.id, scene.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: SceneId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.scenes.remove(id);
+        state.featured_characters.remove(id);
+        state.current_scenes.rows.retain(|(_, s)| *s != id);
+        Ok(())
+    }
+
+    async fn get_current(&self, world_id: WorldId) -> Result<Option<Scene>, RepoError> {
+        let state = self.state();
+        Ok(state
+            .current_scenes
+            .get(world_id)
+            .and_then(|id| state.scenes.get(*id))
+            .cloned())
+    }
+
+    async fn set_current(&self, world_id: WorldId, scene_id: SceneId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.scenes.contains(scene_id) {
+            return Err(RepoError::NotFound);
+        }
+        state.current_scenes.insert(world_id, scene_id);
+        Ok(())
+    }
+
+    async fn list_for_region(&self, region_id: RegionId) -> Result<Vec<Scene>, RepoError> {
+        let state = self.state();
+        let Some(location_id) = state.regions.get(region_id).map(|r| r.location_id) else {
+            return Ok(Vec::new());
+        };
+        let mut scenes: Vec<Scene> = state
+            .scenes
+            .values()
+            .filter(|s| s.location_id == location_id)
+            .cloned()
+            .collect();
+        scenes.sort_by_key(|s| s.order);
+        Ok(scenes)
+    }
+
+    async fn list_for_act(&self, act_id: ActId) -> Result<Vec<Scene>, RepoError> {
+        let mut scenes: Vec<Scene> = self
+            .state()
+            .scenes
+            .values()
+            .filter(|s| s.act_id == act_id)
+            .cloned()
+            .collect();
+        scenes.sort_by_key(|s| s.order);
+        Ok(scenes)
+    }
+
+    async fn get_featured_characters(
+        &self,
+        scene_id: SceneId,
+    ) -> Result<Vec<SceneCharacter>, RepoError> {
+        Ok(self
+            .state()
+            .featured_characters
+            .get(scene_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn set_featured_characters(
+        &self,
+        scene_id: SceneId,
+        characters: &[SceneCharacter],
+    ) -> Result<(), RepoError> {
+        self.state()
+            .featured_characters
+            .insert(scene_id, characters.to_vec());
+        Ok(())
+    }
+
+    async fn has_completed_scene(
+        &self,
+        pc_id: PlayerCharacterId,
+        scene_id: SceneId,
+    ) -> Result<bool, RepoError> {
+        Ok(self.state().completed_scenes.contains(&(pc_id, scene_id)))
+    }
+
+    async fn mark_scene_completed(
+        &self,
+        pc_id: PlayerCharacterId,
+        scene_id: SceneId,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.completed_scenes.contains(&(pc_id, scene_id)) {
+            state.completed_scenes.push((pc_id, scene_id));
+        }
+        Ok(())
+    }
+
+    async fn get_completed_scenes(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<SceneId>, RepoError> {
+        Ok(self
+            .state()
+            .completed_scenes
+            .iter()
+            .filter(|(pc, _)| *pc == pc_id)
+            .map(|(_, scene)| *scene)
+            .collect())
+    }
+}
+
+#[async_trait]
+impl ActRepo for MemoryStore {
+    async fn get(&self, id: ActId) -> Result<Option<Act>, RepoError> {
+        Ok(self.state().acts.get(id).cloned())
+    }
+
+    async fn save(&self, act: &Act) -> Result<(), RepoError> {
+        self.state().acts.insert(act.id, act.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: ActId) -> Result<(), RepoError> {
+        self.state().acts.remove(id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Act>, RepoError> {
+        let mut acts: Vec<Act> = self
+            .state()
+            .acts
+            .values()
+            .filter(|a| a.world_id == world_id)
+            .cloned()
+            .collect();
+        acts.sort_by_key(|a| a.order);
+        Ok(acts)
+    }
+}
+
+#[async_trait]
+impl SkillRepo for MemoryStore {
+    async fn get(&self, id: SkillId) -> Result<Option<Skill>, RepoError> {
+        Ok(self.state().skills.get(id).cloned())
+    }
+
+    async fn save(&self, skill: &Skill) -> Result<(), RepoError> {
+        self.state().skills.insert(skill.id, skill.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: SkillId) -> Result<(), RepoError> {
+        self.state().skills.remove(id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Skill>, RepoError> {
+        let mut skills: Vec<Skill> = self
+            .state()
+            .skills
+            .values()
+            .filter(|s| s.world_id == world_id)
+            .cloned()
+            .collect();
+        skills.sort_by_key(|s| s.order);
+        Ok(skills)
+    }
+}
+
+#[async_trait]
+impl InteractionRepo for MemoryStore {
+    async fn get(&self, id: InteractionId) -> Result<Option<InteractionTemplate>, RepoError> {
+        Ok(self.state().interactions.get(id).cloned())
+    }
+
+    async fn save(&self, interaction: &InteractionTemplate) -> Result<(), RepoError> {
+        self.state()
+            .interactions
+            .insert(interaction.id, interaction.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: InteractionId) -> Result<(), RepoError> {
+        self.state().interactions.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_scene(
+        &self,
+        scene_id: SceneId,
+    ) -> Result<Vec<InteractionTemplate>, RepoError> {
+        let mut interactions: Vec<InteractionTemplate> = self
+            .state()
+            .interactions
+            .values()
+            .filter(|i| i.scene_id == scene_id)
+            .cloned()
+            .collect();
+        interactions.sort_by_key(|i| i.order);
+        Ok(interactions)
+    }
+
+    async fn get_usage(
+        &self,
+        interaction_id: InteractionId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Option<InteractionUsage>, RepoError> {
+        Ok(self
+            .state()
+            .interaction_usage
+            .iter()
+            .find(|u| u.interaction_id == interaction_id && u.pc_id == pc_id)
+            .cloned())
+    }
+
+    async fn save_usage(&self, usage: &InteractionUsage) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state
+            .interaction_usage
+            .retain(|u| !(u.interaction_id == usage.interaction_id && u.pc_id == usage.pc_id));
+        state.interaction_usage.push(usage.clone());
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ChallengeRepo for MemoryStore {
+    async fn get(&self, id: ChallengeId) -> Result<Option<Challenge>, RepoError> {
+        Ok(self.state().challenges.get(id).cloned())
+    }
+
+    async fn save(&self, challenge: &Challenge) -> Result<(), RepoError> {
+        self.state()
+            .challenges
+            .insert(challenge.id, challenge.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: ChallengeId) -> Result<(), RepoError> {
+        self.state().challenges.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<Challenge>, RepoError> {
+        Ok(self.state().sorted_challenges(|c| c.world_id == world_id))
+    }
+
+    /// Nothing in the port ties challenges to scenes, so no scene has any.
+    async fn list_for_scene(&self, _scene_id: SceneId) -> Result<Vec<Challenge>, RepoError> {
+        Ok(Vec::new())
+    }
+
+    async fn list_pending_for_world(&self, world_id: WorldId) -> Result<Vec<Challenge>, RepoError> {
+        Ok(self
+            .state()
+            .sorted_challenges(|c| c.world_id == world_id && c.active))
+    }
+
+    async fn mark_resolved(&self, id: ChallengeId) -> Result<(), RepoError> {
+        self.set_enabled(id, false).await
+    }
+
+    async fn set_enabled(&self, id: ChallengeId, enabled: bool) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let challenge = state.challenges.get_mut(id).ok_or(RepoError::NotFound)?;
+        challenge.active = enabled;
+        Ok(())
+    }
+
+    async fn get_resolved_challenges(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<ChallengeId>, RepoError> {
+        Ok(self
+            .state()
+            .challenges
+            .values()
+            .filter(|c| c.world_id == world_id && !c.active)
+            .map(|c| c.id)
+            .collect())
+    }
+
+    async fn save_suggestion_decision(
+        &self,
+        decision: &SuggestionDecision,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.suggestion_decisions.retain(|d| d.id != decision.id);
+        state.suggestion_decisions.push(decision.clone());
+        Ok(())
+    }
+
+    async fn list_suggestion_decisions(
+        &self,
+        world_id: WorldId,
+        limit: usize,
+    ) -> Result<Vec<SuggestionDecision>, RepoError> {
+        let mut decisions: Vec<SuggestionDecision> = self
+            .state()
+            .suggestion_decisions
+            .iter()
+            .filter(|d| d.world_id == world_id)
+            .cloned()
+            .collect();
+        decisions.sort_by_key(|d| Reverse(d.decided_at));
+        decisions.truncate(limit);
+        Ok(decisions)
+    }
+
+    async fn save_resolution(&self, resolution: &ChallengeResolution) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state
+            .challenge_resolutions
+            .retain(|r| r.id != resolution.id);
+        state.challenge_resolutions.push(resolution.clone());
+        Ok(())
+    }
+
+    async fn list_resolutions(
+        &self,
+        world_id: WorldId,
+        pc_id: Option<PlayerCharacterId>,
+        limit: usize,
+    ) -> Result<Vec<ChallengeResolution>, RepoError> {
+        let mut resolutions: Vec<ChallengeResolution> = self
+            .state()
+            .challenge_resolutions
+            .iter()
+            .filter(|r| r.world_id == world_id && pc_id.is_none_or(|pc| r.pc_id == pc))
+            .cloned()
+            .collect();
+        resolutions.sort_by_key(|r| Reverse(r.resolved_at));
+        resolutions.truncate(limit);
+        Ok(resolutions)
+    }
+}
+
+#[async_trait]
+impl ItemRepo for MemoryStore {
+    async fn get(&self, id: ItemId) -> Result<Option<Item>, RepoError> {
+        Ok(self.state().items.get(id).cloned())
+    }
+
+    async fn save(&self, item: &Item) -> Result<(), RepoError> {
+        self.state().items.insert(item.id, item.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: ItemId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.items.remove(id);
+        state.item_regions.remove(id);
+        state.pc_inventory.retain(|(_, item)| *item != id);
+        state.character_inventory.retain(|(_, item)| *item != id);
+        state.equipped.retain(|(_, item)| *item != id);
+        Ok(())
+    }
+
+    async fn list_in_region(&self, region_id: RegionId) -> Result<Vec<Item>, RepoError> {
+        let state = self.state();
+        Ok(state
+            .item_regions
+            .rows
+            .iter()
+            .filter(|(_, region)| *region == region_id)
+            .filter_map(|(item_id, _)| state.items.get(*item_id).cloned())
+            .collect())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Item>, RepoError> {
+        Ok(self
+            .state()
+            .items
+            .values()
+            .filter(|i| i.world_id == world_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn set_equipped(
+        &self,
+        pc_id: PlayerCharacterId,
+        item_id: ItemId,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.pc_inventory.contains(&(pc_id, item_id)) {
+            return Err(RepoError::NotFound);
+        }
+        if !state.equipped.contains(&(pc_id, item_id)) {
+            state.equipped.push((pc_id, item_id));
+        }
+        Ok(())
+    }
+
+    async fn set_unequipped(
+        &self,
+        pc_id: PlayerCharacterId,
+        item_id: ItemId,
+    ) -> Result<(), RepoError> {
+        self.state()
+            .equipped
+            .retain(|entry| *entry != (pc_id, item_id));
+        Ok(())
+    }
+
+    async fn place_in_region(&self, item_id: ItemId, region_id: RegionId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.items.contains(item_id) || !state.regions.contains(region_id) {
+            return Err(RepoError::NotFound);
+        }
+        state.item_regions.insert(item_id, region_id);
+        Ok(())
+    }
+
+    async fn remove_from_region(&self, item_id: ItemId) -> Result<(), RepoError> {
+        self.state().item_regions.remove(item_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GoalRepo for MemoryStore {
+    async fn get(&self, id: GoalId) -> Result<Option<GoalDetails>, RepoError> {
+        let state = self.state();
+        Ok(state.goals.get(id).map(|g| state.goal_details(g)))
+    }
+
+    async fn save(&self, goal: &Goal) -> Result<(), RepoError> {
+        self.state().goals.insert(goal.id, goal.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: GoalId) -> Result<(), RepoError> {
+        self.state().goals.remove(id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<GoalDetails>, RepoError> {
+        let state = self.state();
+        Ok(state
+            .goals
+            .values()
+            .filter(|g| g.world_id == world_id)
+            .map(|g| state.goal_details(g))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl ProgressClockRepo for MemoryStore {
+    async fn get(&self, id: ProgressClockId) -> Result<Option<ProgressClock>, RepoError> {
+        Ok(self.state().progress_clocks.get(id).cloned())
+    }
+
+    async fn save(&self, clock: &ProgressClock) -> Result<(), RepoError> {
+        self.state().progress_clocks.insert(clock.id, clock.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: ProgressClockId) -> Result<(), RepoError> {
+        self.state().progress_clocks.remove(id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<ProgressClock>, RepoError> {
+        Ok(self
+            .state()
+            .progress_clocks
+            .values()
+            .filter(|c| c.world_id == world_id)
+            .cloned()
+            .collect())
+    }
+}
+
+#[async_trait]
+impl LoreRepo for MemoryStore {
+    async fn get(&self, id: LoreId) -> Result<Option<Lore>, RepoError> {
+        Ok(self.state().lore.get(id).cloned())
+    }
+
+    async fn save(&self, lore: &Lore) -> Result<(), RepoError> {
+        self.state().lore.insert(lore.id, lore.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: LoreId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.lore.remove(id);
+        state.lore_knowledge.retain(|k| k.lore_id != id);
+        Ok(())
+    }
+
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<Lore>, RepoError> {
+        Ok(self.state().lore_in_world(world_id, |_| true))
+    }
+
+    async fn list_by_category(
+        &self,
+        world_id: WorldId,
+        category: LoreCategory,
+    ) -> Result<Vec<Lore>, RepoError> {
+        Ok(self
+            .state()
+            .lore_in_world(world_id, |l| l.category == category))
+    }
+
+    async fn list_common_knowledge(&self, world_id: WorldId) -> Result<Vec<Lore>, RepoError> {
+        Ok(self
+            .state()
+            .lore_in_world(world_id, |l| l.is_common_knowledge))
+    }
+
+    async fn search_by_tags(
+        &self,
+        world_id: WorldId,
+        tags: &[String],
+    ) -> Result<Vec<Lore>, RepoError> {
+        if tags.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(self
+            .state()
+            .lore_in_world(world_id, |l| l.tags.iter().any(|t| tags.contains(t))))
+    }
+
+    async fn grant_knowledge(&self, knowledge: &LoreKnowledge) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.lore_knowledge.retain(|k| {
+            !(k.character_id == knowledge.character_id && k.lore_id == knowledge.lore_id)
+        });
+        state.lore_knowledge.push(knowledge.clone());
+        Ok(())
+    }
+
+    async fn revoke_knowledge(
+        &self,
+        character_id: CharacterId,
+        lore_id: LoreId,
+    ) -> Result<(), RepoError> {
+        self.state()
+            .lore_knowledge
+            .retain(|k| !(k.character_id == character_id && k.lore_id == lore_id));
+        Ok(())
+    }
+
+    async fn get_character_knowledge(
+        &self,
+        character_id: CharacterId,
+    ) -> Result<Vec<LoreKnowledge>, RepoError> {
+        Ok(self
+            .state()
+            .lore_knowledge
+            .iter()
+            .filter(|k| k.character_id == character_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_knowledge_for_lore(
+        &self,
+        lore_id: LoreId,
+    ) -> Result<Vec<LoreKnowledge>, RepoError> {
+        Ok(self
+            .state()
+            .lore_knowledge
+            .iter()
+            .filter(|k| k.lore_id == lore_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn character_knows_lore(
+        &self,
+        character_id: CharacterId,
+        lore_id: LoreId,
+    ) -> Result<Option<LoreKnowledge>, RepoError> {
+        Ok(self
+            .state()
+            .lore_knowledge
+            .iter()
+            .find(|k| k.character_id == character_id && k.lore_id == lore_id)
+            .cloned())
+    }
+
+    async fn add_chunks_to_knowledge(
+        &self,
+        character_id: CharacterId,
+        lore_id: LoreId,
+        chunk_ids: &[LoreChunkId],
+    ) -> Result<(), RepoError> {
+        if chunk_ids.is_empty() {
+            return Ok(());
+        }
+        let mut state = self.state();
+        let knowledge = state
+            .lore_knowledge
+            .iter_mut()
+            .find(|k| k.character_id == character_id && k.lore_id == lore_id)
+            .ok_or(RepoError::NotFound)?;
+        for chunk_id in chunk_ids {
+            if !knowledge.known_chunk_ids.contains(chunk_id) {
+                knowledge.known_chunk_ids.push(*chunk_id);
+            }
+        }
+        Ok(())
+    }
+
+    async fn remove_chunks_from_knowledge(
+        &self,
+        character_id: CharacterId,
+        lore_id: LoreId,
+        chunk_ids: &[LoreChunkId],
+    ) -> Result<bool, RepoError> {
+        if chunk_ids.is_empty() {
+            return Ok(false);
+        }
+        let mut state = self.state();
+        let Some(index) = state
+            .lore_knowledge
+            .iter()
+            .position(|k| k.character_id == character_id && k.lore_id == lore_id)
+        else {
+            return Ok(false);
+        };
+        let knowledge = &mut state.lore_knowledge[index];
+        knowledge
+            .known_chunk_ids
+            .retain(|id| !chunk_ids.contains(id));
+        if knowledge.known_chunk_ids.is_empty() {
+            state.lore_knowledge.remove(index);
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+#[async_trait]
+impl AssetRepo for MemoryStore {
+    async fn get(&self, id: AssetId) -> Result<Option<GalleryAsset>, RepoError> {
+        Ok(self.state().assets.get(id).cloned())
+    }
+
+    async fn save(&self, asset: &GalleryAsset) -> Result<(), RepoError> {
+        self.state().assets.insert(asset.id, asset.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: AssetId) -> Result<(), RepoError> {
+        self.state().assets.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_entity(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+    ) -> Result<Vec<GalleryAsset>, RepoError> {
+        let mut assets: Vec<GalleryAsset> = self
+            .state()
+            .assets
+            .values()
+            .filter(|a| is_for_entity(a, entity_type, entity_id))
+            .cloned()
+            .collect();
+        assets.sort_by_key(|a| Reverse(a.created_at));
+        Ok(assets)
+    }
+
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<GalleryAsset>, RepoError> {
+        let state = self.state();
+        let in_world = |asset: &GalleryAsset| {
+            let Ok(id) = Uuid::parse_str(&asset.entity_id) else {
+                return false;
+            };
+            match asset.entity_type {
+                EntityType::Character => state
+                    .characters
+                    .get(CharacterId::from_uuid(id))
+                    .is_some_and(|c| c.world_id == world_id),
+                EntityType::Location => state
+                    .locations
+                    .get(LocationId::from_uuid(id))
+                    .is_some_and(|l| l.world_id == world_id),
+                EntityType::Item => state
+                    .items
+                    .get(ItemId::from_uuid(id))
+                    .is_some_and(|i| i.world_id == world_id),
+                _ => false,
+            }
+        };
+        let mut assets: Vec<GalleryAsset> = state
+            .assets
+            .values()
+            .filter(|a| in_world(a))
+            .cloned()
+            .collect();
+        assets.sort_by_key(|a| Reverse(a.created_at));
+        Ok(assets)
+    }
+
+    async fn set_active(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+        asset_id: AssetId,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let asset_type = state
+            .assets
+            .get(asset_id)
+            .ok_or_else(|| RepoError::Database(format!("Asset not found: {}", asset_id)))?
+            .asset_type;
+        for asset in state.assets.values_mut() {
+            if is_for_entity(asset, entity_type, entity_id) && asset.asset_type == asset_type {
+                asset.is_active = asset.id == asset_id;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_for_entity(asset: &GalleryAsset, entity_type: &str, entity_id: Uuid) -> bool {
+    asset.entity_type.as_str().eq_ignore_ascii_case(entity_type)
+        && asset.entity_id == entity_id.to_string()
+}
+
+#[async_trait]
+impl FlagRepo for MemoryStore {
+    async fn get_world_flags(&self, world_id: WorldId) -> Result<Vec<String>, RepoError> {
+        Ok(self
+            .state()
+            .world_flags
+            .iter()
+            .filter(|(w, _)| *w == world_id)
+            .map(|(_, flag)| flag.clone())
+            .collect())
+    }
+
+    async fn get_pc_flags(&self, pc_id: PlayerCharacterId) -> Result<Vec<String>, RepoError> {
+        Ok(self
+            .state()
+            .pc_flags
+            .iter()
+            .filter(|(pc, _)| *pc == pc_id)
+            .map(|(_, flag)| flag.clone())
+            .collect())
+    }
+
+    async fn set_world_flag(&self, world_id: WorldId, flag_name: &str) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let flag = (world_id, flag_name.to_string());
+        if !state.world_flags.contains(&flag) {
+            state.world_flags.push(flag);
+        }
+        Ok(())
+    }
+
+    async fn unset_world_flag(&self, world_id: WorldId, flag_name: &str) -> Result<(), RepoError> {
+        self.state()
+            .world_flags
+            .retain(|(w, flag)| !(*w == world_id && flag == flag_name));
+        Ok(())
+    }
+
+    async fn set_pc_flag(
+        &self,
+        pc_id: PlayerCharacterId,
+        flag_name: &str,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let flag = (pc_id, flag_name.to_string());
+        if !state.pc_flags.contains(&flag) {
+            state.pc_flags.push(flag);
+        }
+        Ok(())
+    }
+
+    async fn unset_pc_flag(
+        &self,
+        pc_id: PlayerCharacterId,
+        flag_name: &str,
+    ) -> Result<(), RepoError> {
+        self.state()
+            .pc_flags
+            .retain(|(pc, flag)| !(*pc == pc_id && flag == flag_name));
+        Ok(())
+    }
+
+    async fn is_world_flag_set(
+        &self,
+        world_id: WorldId,
+        flag_name: &str,
+    ) -> Result<bool, RepoError> {
+        Ok(self
+            .state()
+            .world_flags
+            .iter()
+            .any(|(w, flag)| *w == world_id && flag == flag_name))
+    }
+
+    async fn is_pc_flag_set(
+        &self,
+        pc_id: PlayerCharacterId,
+        flag_name: &str,
+    ) -> Result<bool, RepoError> {
+        Ok(self
+            .state()
+            .pc_flags
+            .iter()
+            .any(|(pc, flag)| *pc == pc_id && flag == flag_name))
+    }
+}
+
+#[async_trait]
+impl TagRepo for MemoryStore {
+    async fn get_tags(
+        &self,
+        entity_type: EntityType,
+        entity_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<String>>, RepoError> {
+        let state = self.state();
+        Ok(entity_ids
+            .iter()
+            .filter_map(|id| {
+                let (_, tags) = state.entity_tags.get((entity_type, *id))?;
+                Some((*id, tags.clone()))
+            })
+            .collect())
+    }
+
+    async fn set_tags(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        tags: &[String],
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if tags.is_empty() {
+            state.entity_tags.remove((entity_type, entity_id));
+        } else {
+            let mut tags = tags.to_vec();
+            tags.sort();
+            state
+                .entity_tags
+                .insert((entity_type, entity_id), (world_id, tags));
+        }
+        Ok(())
+    }
+
+    async fn list_world_tags(&self, world_id: WorldId) -> Result<Vec<TagUsage>, RepoError> {
+        let mut counts = BTreeMap::<String, u32>::new();
+        for (world, tags) in self.state().entity_tags.values() {
+            if *world == world_id {
+                for tag in tags {
+                    *counts.entry(tag.clone()).or_default() += 1;
+                }
+            }
+        }
+        Ok(counts
+            .into_iter()
+            .map(|(tag, count)| TagUsage { tag, count })
+            .collect())
+    }
+
+    async fn rename_tag(&self, world_id: WorldId, from: &str, to: &str) -> Result<u32, RepoError> {
+        let mut changed = 0;
+        for (world, tags) in self.state().entity_tags.values_mut() {
+            if *world == world_id && tags.iter().any(|t| t == from) {
+                tags.retain(|t| t != from && t != to);
+                tags.push(to.to_string());
+                tags.sort();
+                changed += 1;
+            }
+        }
+        Ok(changed)
+    }
+
+    async fn delete_tag(&self, world_id: WorldId, tag: &str) -> Result<u32, RepoError> {
+        let mut state = self.state();
+        let mut changed = 0;
+        for (world, tags) in state.entity_tags.values_mut() {
+            if *world == world_id && tags.iter().any(|t| t == tag) {
+                tags.retain(|t| t != tag);
+                changed += 1;
+            }
+        }
+        state
+            .entity_tags
+            .rows
+            .retain(|(_, (_, tags))| !tags.is_empty());
+        Ok(changed)
+    }
+
+    async fn get_saved_filter(&self, id: SavedFilterId) -> Result<Option<SavedFilter>, RepoError> {
+        Ok(self.state().saved_filters.get(id).cloned())
+    }
+
+    async fn save_saved_filter(&self, filter: &SavedFilter) -> Result<(), RepoError> {
+        self.state().saved_filters.insert(filter.id, filter.clone());
+        Ok(())
+    }
+
+    async fn delete_saved_filter(&self, id: SavedFilterId) -> Result<(), RepoError> {
+        self.state().saved_filters.remove(id);
+        Ok(())
+    }
+
+    async fn list_saved_filters(
+        &self,
+        world_id: WorldId,
+        user_id: &str,
+    ) -> Result<Vec<SavedFilter>, RepoError> {
+        let mut filters: Vec<SavedFilter> = self
+            .state()
+            .saved_filters
+            .values()
+            .filter(|f| f.world_id == world_id && f.user_id == user_id)
+            .cloned()
+            .collect();
+        filters.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(filters)
+    }
+}
+
+#[async_trait]
+impl MentionRepo for MemoryStore {
+    async fn list_aliases(
+        &self,
+        world_id: WorldId,
+    ) -> Result<HashMap<(EntityType, Uuid), Vec<String>>, RepoError> {
+        Ok(self
+            .state()
+            .entity_aliases
+            .rows
+            .iter()
+            .filter(|(_, (world, _))| *world == world_id)
+            .map(|(key, (_, aliases))| (*key, aliases.clone()))
+            .collect())
+    }
+
+    async fn set_aliases(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        aliases: &[String],
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if aliases.is_empty() {
+            state.entity_aliases.remove((entity_type, entity_id));
+        } else {
+            state
+                .entity_aliases
+                .insert((entity_type, entity_id), (world_id, aliases.to_vec()));
+        }
+        Ok(())
+    }
+
+    async fn replace_mentions(
+        &self,
+        _world_id: WorldId,
+        source_type: EntityType,
+        source_id: Uuid,
+        mentions: &[EntityMention],
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state
+            .mentions
+            .retain(|m| !(m.source_type == source_type && m.source_id == source_id));
+        state.mentions.extend(mentions.iter().cloned());
+        Ok(())
+    }
+
+    async fn clear_mentions(&self, world_id: WorldId) -> Result<(), RepoError> {
+        self.state().mentions.retain(|m| m.world_id != world_id);
+        Ok(())
+    }
+
+    async fn forget_entity(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.entity_aliases.remove((entity_type, entity_id));
+        state.mentions.retain(|m| {
+            !(m.source_type == entity_type && m.source_id == entity_id)
+                && !(m.target_type == entity_type && m.target_id == entity_id)
+        });
+        Ok(())
+    }
+
+    async fn list_mentions_of(
+        &self,
+        target_type: EntityType,
+        target_id: Uuid,
+    ) -> Result<Vec<EntityMention>, RepoError> {
+        let mut mentions: Vec<EntityMention> = self
+            .state()
+            .mentions
+            .iter()
+            .filter(|m| m.target_type == target_type && m.target_id == target_id)
+            .cloned()
+            .collect();
+        mentions.sort_by_key(|m| m.source_name.to_lowercase());
+        Ok(mentions)
+    }
+
+    async fn list_mentions_in(
+        &self,
+        source_type: EntityType,
+        source_id: Uuid,
+    ) -> Result<Vec<EntityMention>, RepoError> {
+        Ok(self
+            .state()
+            .mentions
+            .iter()
+            .filter(|m| m.source_type == source_type && m.source_id == source_id)
+            .cloned()
+            .collect())
+    }
+}
+
+#[async_trait]
+impl NameGeneratorRepo for MemoryStore {
+    async fn get(&self, id: NameGeneratorId) -> Result<Option<NameGenerator>, RepoError> {
+        Ok(self.state().name_generators.get(id).cloned())
+    }
+
+    async fn save(&self, generator: &NameGenerator) -> Result<(), RepoError> {
+        self.state()
+            .name_generators
+            .insert(generator.id, generator.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: NameGeneratorId) -> Result<(), RepoError> {
+        self.state().name_generators.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<NameGenerator>, RepoError> {
+        let mut generators: Vec<NameGenerator> = self
+            .state()
+            .name_generators
+            .values()
+            .filter(|g| g.world_id == world_id)
+            .cloned()
+            .collect();
+        generators.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(generators)
+    }
+}
+
+#[async_trait]
+impl EconomyRepo for MemoryStore {
+    async fn get_modifier(
+        &self,
+        id: MarketModifierId,
+    ) -> Result<Option<MarketModifier>, RepoError> {
+        Ok(self.state().market_modifiers.get(id).cloned())
+    }
+
+    async fn save_modifier(&self, modifier: &MarketModifier) -> Result<(), RepoError> {
+        self.state()
+            .market_modifiers
+            .insert(modifier.id, modifier.clone());
+        Ok(())
+    }
+
+    async fn delete_modifier(&self, id: MarketModifierId) -> Result<(), RepoError> {
+        self.state().market_modifiers.remove(id);
+        Ok(())
+    }
+
+    async fn list_modifiers(&self, world_id: WorldId) -> Result<Vec<MarketModifier>, RepoError> {
+        let mut modifiers: Vec<MarketModifier> = self
+            .state()
+            .market_modifiers
+            .values()
+            .filter(|m| m.world_id == world_id)
+            .cloned()
+            .collect();
+        modifiers.sort_by_key(|m| m.created_at);
+        Ok(modifiers)
+    }
+}
+
+#[async_trait]
+impl CustomFieldRepo for MemoryStore {
+    async fn get_values(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<CustomFieldValues, RepoError> {
+        Ok(self
+            .state()
+            .custom_field_values
+            .get((entity_type, entity_id))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn set_values(
+        &self,
+        _world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        values: &CustomFieldValues,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if values.is_empty() {
+            state.custom_field_values.remove((entity_type, entity_id));
+        } else {
+            state
+                .custom_field_values
+                .insert((entity_type, entity_id), values.clone());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TemplateRepo for MemoryStore {
+    async fn get(&self, id: EntityTemplateId) -> Result<Option<EntityTemplate>, RepoError> {
+        Ok(self.state().templates.get(id).cloned())
+    }
+
+    async fn save(&self, template: &EntityTemplate) -> Result<(), RepoError> {
+        self.state().templates.insert(template.id, template.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: EntityTemplateId) -> Result<(), RepoError> {
+        self.state().templates.remove(id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<EntityTemplate>, RepoError> {
+        let mut templates: Vec<EntityTemplate> = self
+            .state()
+            .templates
+            .values()
+            .filter(|t| t.world_id == world_id)
+            .cloned()
+            .collect();
+        templates.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(templates)
+    }
+}
+
+#[async_trait]
+impl LibraryRepo for MemoryStore {
+    async fn get(&self, id: LibraryEntryId) -> Result<Option<LibraryEntry>, RepoError> {
+        Ok(self.state().library.get(id).cloned())
+    }
+
+    async fn save(&self, entry: &LibraryEntry) -> Result<(), RepoError> {
+        self.state().library.insert(entry.id, entry.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: LibraryEntryId) -> Result<(), RepoError> {
+        self.state().library.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_owner(&self, owner_id: &str) -> Result<Vec<LibraryEntry>, RepoError> {
+        let mut entries: Vec<LibraryEntry> = self
+            .state()
+            .library
+            .values()
+            .filter(|e| e.owner_id == owner_id)
+            .cloned()
+            .collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl ContentDraftRepo for MemoryStore {
+    async fn get(&self, id: ContentDraftId) -> Result<Option<ContentDraft>, RepoError> {
+        Ok(self.state().content_drafts.get(id).cloned())
+    }
+
+    async fn save(&self, draft: &ContentDraft) -> Result<(), RepoError> {
+        self.state().content_drafts.insert(draft.id, draft.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: ContentDraftId) -> Result<(), RepoError> {
+        self.state().content_drafts.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_entity(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<ContentDraft>, RepoError> {
+        let mut drafts: Vec<ContentDraft> = self
+            .state()
+            .content_drafts
+            .values()
+            .filter(|d| d.entity_type == entity_type && d.entity_id == entity_id)
+            .cloned()
+            .collect();
+        drafts.sort_by_key(|d| d.field.as_str());
+        Ok(drafts)
+    }
+}
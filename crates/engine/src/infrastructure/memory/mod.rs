@@ -0,0 +1,250 @@
+//! In-memory port implementations for deterministic simulation tests.
+//!
+//! One `MemoryStore` backs every repository port, so lookups that the graph
+//! answers by following edges (a staged NPC's name, a PC's inventory) see the
+//! same data the use cases wrote. Tables keep insertion order, so listings
+//! come back in the same order on every run.
+
+mod characters;
+mod content;
+mod narrative;
+mod places;
+mod services;
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use wrldbldr_domain::*;
+
+use crate::app::Repositories;
+use crate::infrastructure::ports::{
+    ActantialViewRecord, ClockPort, ConversationTurnRecord, NpcRegionRelationship,
+};
+
+pub(crate) use services::{MemoryBlobStore, MemoryQueue, MemorySettings, OfflineServices};
+
+/// Insertion-ordered map, so listings come back in a stable order.
+struct Table<K, V> {
+    rows: Vec<(K, V)>,
+}
+
+impl<K, V> Default for Table<K, V> {
+    fn default() -> Self {
+        Self { rows: Vec::new() }
+    }
+}
+
+impl<K: PartialEq + Copy, V> Table<K, V> {
+    fn get(&self, key: K) -> Option<&V> {
+        self.rows.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        self.rows
+            .iter_mut()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v)
+    }
+
+    fn contains(&self, key: K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Insert or replace, keeping an existing row's position
+    fn insert(&mut self, key: K, value: V) {
+        match self.get_mut(key) {
+            Some(existing) => *existing = value,
+            None => self.rows.push((key, value)),
+        }
+    }
+
+    fn remove(&mut self, key: K) -> Option<V> {
+        let index = self.rows.iter().position(|(k, _)| *k == key)?;
+        Some(self.rows.remove(index).1)
+    }
+
+    fn values(&self) -> impl Iterator<Item = &V> {
+        self.rows.iter().map(|(_, v)| v)
+    }
+
+    fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.rows.iter_mut().map(|(_, v)| v)
+    }
+}
+
+struct WantRow {
+    character_id: CharacterId,
+    want: Want,
+    priority: u32,
+}
+
+struct Conversation {
+    id: Uuid,
+    pc_id: PlayerCharacterId,
+    npc_id: CharacterId,
+    is_active: bool,
+    turns: Vec<ConversationTurnRecord>,
+}
+
+#[derive(Default)]
+struct MemoryState {
+    worlds: Table<WorldId, World>,
+
+    characters: Table<CharacterId, Character>,
+    character_regions: Table<CharacterId, RegionId>,
+    relationships: Table<RelationshipId, Relationship>,
+    character_inventory: Vec<(CharacterId, ItemId)>,
+    wants: Table<WantId, WantRow>,
+    want_targets: Table<WantId, WantTarget>,
+    dispositions: Vec<NpcDispositionState>,
+    actantial_contexts: Table<CharacterId, ActantialContext>,
+    actantial_views: Vec<(CharacterId, ActantialViewRecord)>,
+    region_relationships: Vec<(CharacterId, NpcRegionRelationship)>,
+
+    player_characters: Table<PlayerCharacterId, PlayerCharacter>,
+    pc_inventory: Vec<(PlayerCharacterId, ItemId)>,
+    pc_stats: Table<PlayerCharacterId, BTreeMap<String, i64>>,
+    observations: Vec<NpcObservation>,
+    location_observations: Vec<LocationObservation>,
+    deduced_info: Vec<(PlayerCharacterId, String)>,
+
+    locations: Table<LocationId, Location>,
+    regions: Table<RegionId, Region>,
+    region_connections: Vec<RegionConnection>,
+    location_connections: Vec<LocationConnection>,
+    region_exits: Vec<RegionExit>,
+    location_states: Table<LocationStateId, LocationState>,
+    active_location_states: Table<LocationId, LocationStateId>,
+    region_states: Table<RegionStateId, RegionState>,
+    active_region_states: Table<RegionId, RegionStateId>,
+    stagings: Table<StagingId, Staging>,
+    current_stagings: Table<RegionId, StagingId>,
+
+    scenes: Table<SceneId, Scene>,
+    current_scenes: Table<WorldId, SceneId>,
+    featured_characters: Table<SceneId, Vec<SceneCharacter>>,
+    completed_scenes: Vec<(PlayerCharacterId, SceneId)>,
+    acts: Table<ActId, Act>,
+    skills: Table<SkillId, Skill>,
+    interactions: Table<InteractionId, InteractionTemplate>,
+    interaction_usage: Vec<InteractionUsage>,
+    challenges: Table<ChallengeId, Challenge>,
+    suggestion_decisions: Vec<SuggestionDecision>,
+    challenge_resolutions: Vec<ChallengeResolution>,
+    items: Table<ItemId, Item>,
+    equipped: Vec<(PlayerCharacterId, ItemId)>,
+    item_regions: Table<ItemId, RegionId>,
+    goals: Table<GoalId, Goal>,
+    progress_clocks: Table<ProgressClockId, ProgressClock>,
+    lore: Table<LoreId, Lore>,
+    lore_knowledge: Vec<LoreKnowledge>,
+    assets: Table<AssetId, GalleryAsset>,
+    entity_tags: Table<(EntityType, Uuid), (WorldId, Vec<String>)>,
+    saved_filters: Table<SavedFilterId, SavedFilter>,
+    custom_field_values: Table<(EntityType, Uuid), CustomFieldValues>,
+    entity_aliases: Table<(EntityType, Uuid), (WorldId, Vec<String>)>,
+    mentions: Vec<EntityMention>,
+    templates: Table<EntityTemplateId, EntityTemplate>,
+    library: Table<LibraryEntryId, LibraryEntry>,
+    content_drafts: Table<ContentDraftId, ContentDraft>,
+    crowds: Table<CrowdId, Crowd>,
+    npc_drafts: Table<NpcDraftId, NpcDraft>,
+    name_generators: Table<NameGeneratorId, NameGenerator>,
+    market_modifiers: Table<MarketModifierId, MarketModifier>,
+    properties: Table<PropertyId, Property>,
+    companions: Table<CompanionId, Companion>,
+    injuries: Table<InjuryId, Injury>,
+    pc_secrets: Table<PcSecretId, PcSecret>,
+    letters: Table<LetterId, Letter>,
+    broadsheets: Table<BroadsheetId, Broadsheet>,
+    factions: Table<FactionId, Faction>,
+    dialogue_trees: Table<DialogueTreeId, DialogueTree>,
+    world_flags: Vec<(WorldId, String)>,
+    pc_flags: Vec<(PlayerCharacterId, String)>,
+
+    events: Table<NarrativeEventId, NarrativeEvent>,
+    chains: Table<EventChainId, EventChain>,
+    story_events: Table<StoryEventId, StoryEvent>,
+    dialogue_events: Vec<(PlayerCharacterId, StoryEventId)>,
+    spoke_to: Vec<(PlayerCharacterId, CharacterId, u32)>,
+    conversations: Vec<Conversation>,
+}
+
+/// Every repository port, held in memory.
+pub(crate) struct MemoryStore {
+    state: Mutex<MemoryState>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl MemoryStore {
+    pub(crate) fn new(clock: Arc<dyn ClockPort>) -> Self {
+        Self {
+            state: Mutex::new(MemoryState::default()),
+            clock,
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, MemoryState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
+    /// This store behind every repository port
+    pub(crate) fn repositories(self: &Arc<Self>) -> Repositories {
+        Repositories {
+            character: self.clone(),
+            player_character: self.clone(),
+            location: self.clone(),
+            scene: self.clone(),
+            act: self.clone(),
+            skill: self.clone(),
+            interaction: self.clone(),
+            challenge: self.clone(),
+            narrative: self.clone(),
+            staging: self.clone(),
+            observation: self.clone(),
+            item: self.clone(),
+            world: self.clone(),
+            asset: self.clone(),
+            flag: self.clone(),
+            goal: self.clone(),
+            lore: self.clone(),
+            progress_clock: self.clone(),
+            tag: self.clone(),
+            custom_field: self.clone(),
+            template: self.clone(),
+            library: self.clone(),
+            content_draft: self.clone(),
+            crowd: self.clone(),
+            npc_draft: self.clone(),
+            mention: self.clone(),
+            name_generator: self.clone(),
+            economy: self.clone(),
+            property: self.clone(),
+            companion: self.clone(),
+            injury: self.clone(),
+            pc_secret: self.clone(),
+            letter: self.clone(),
+            broadsheet: self.clone(),
+            faction: self.clone(),
+            dialogue_tree: self.clone(),
+            location_state: self.clone(),
+            region_state: self.clone(),
+        }
+    }
+
+    /// Information a PC deduced from challenge outcomes, oldest first
+    pub(crate) fn deduced_info(&self, pc_id: PlayerCharacterId) -> Vec<String> {
+        self.state()
+            .deduced_info
+            .iter()
+            .filter(|(pc, _)| *pc == pc_id)
+            .map(|(_, info)| info.clone())
+            .collect()
+    }
+}
@@ -0,0 +1,422 @@
+//! Narrative events, story history and conversations.
+
+use std::cmp::Reverse;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use wrldbldr_domain::*;
+
+use super::{Conversation, MemoryState, MemoryStore};
+use crate::infrastructure::ports::{
+    BroadsheetRepo, ConversationTurnRecord, DialogueCount, NarrativeRepo, RepoError,
+};
+
+impl MemoryState {
+    fn active_conversation(
+        &mut self,
+        pc_id: PlayerCharacterId,
+        npc_id: CharacterId,
+    ) -> Option<&mut Conversation> {
+        self.conversations
+            .iter_mut()
+            .find(|c| c.is_active && c.pc_id == pc_id && c.npc_id == npc_id)
+    }
+}
+
+fn triggers_in_region(event: &NarrativeEvent, region_id: RegionId) -> bool {
+    // Location triggers name the region the player walks into
+    event
+        .trigger_conditions
+        .iter()
+        .any(|t| match &t.trigger_type {
+            NarrativeTriggerType::PlayerEntersLocation { location_id, .. }
+            | NarrativeTriggerType::TimeAtLocation { location_id, .. } => {
+                location_id.to_uuid() == region_id.to_uuid()
+            }
+            _ => false,
+        })
+}
+
+#[async_trait]
+impl NarrativeRepo for MemoryStore {
+    async fn get_event(&self, id: NarrativeEventId) -> Result<Option<NarrativeEvent>, RepoError> {
+        Ok(self.state().events.get(id).cloned())
+    }
+
+    async fn save_event(&self, event: &NarrativeEvent) -> Result<(), RepoError> {
+        self.state().events.insert(event.id, event.clone());
+        Ok(())
+    }
+
+    async fn delete_event(&self, id: NarrativeEventId) -> Result<(), RepoError> {
+        self.state().events.remove(id);
+        Ok(())
+    }
+
+    async fn list_events_for_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<NarrativeEvent>, RepoError> {
+        let mut events: Vec<NarrativeEvent> = self
+            .state()
+            .events
+            .values()
+            .filter(|e| e.world_id == world_id)
+            .cloned()
+            .collect();
+        events.sort_by(|a, b| {
+            b.is_favorite
+                .cmp(&a.is_favorite)
+                .then(b.priority.cmp(&a.priority))
+                .then(a.name.cmp(&b.name))
+        });
+        Ok(events)
+    }
+
+    async fn get_chain(&self, id: EventChainId) -> Result<Option<EventChain>, RepoError> {
+        Ok(self.state().chains.get(id).cloned())
+    }
+
+    async fn save_chain(&self, chain: &EventChain) -> Result<(), RepoError> {
+        self.state().chains.insert(chain.id, chain.clone());
+        Ok(())
+    }
+
+    async fn delete_chain(&self, id: EventChainId) -> Result<(), RepoError> {
+        self.state().chains.remove(id);
+        Ok(())
+    }
+
+    async fn list_chains_for_world(&self, world_id: WorldId) -> Result<Vec<EventChain>, RepoError> {
+        let mut chains: Vec<EventChain> = self
+            .state()
+            .chains
+            .values()
+            .filter(|c| c.world_id == world_id)
+            .cloned()
+            .collect();
+        chains.sort_by(|a, b| b.is_favorite.cmp(&a.is_favorite).then(a.name.cmp(&b.name)));
+        Ok(chains)
+    }
+
+    async fn get_story_event(&self, id: StoryEventId) -> Result<Option<StoryEvent>, RepoError> {
+        Ok(self.state().story_events.get(id).cloned())
+    }
+
+    async fn save_story_event(&self, event: &StoryEvent) -> Result<(), RepoError> {
+        self.state().story_events.insert(event.id, event.clone());
+        Ok(())
+    }
+
+    async fn delete_story_event(&self, id: StoryEventId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.story_events.remove(id);
+        state.dialogue_events.retain(|(_, event)| *event != id);
+        Ok(())
+    }
+
+    async fn list_story_events(
+        &self,
+        world_id: WorldId,
+        limit: usize,
+    ) -> Result<Vec<StoryEvent>, RepoError> {
+        let mut events: Vec<StoryEvent> = self
+            .state()
+            .story_events
+            .values()
+            .filter(|e| e.world_id == world_id && !e.is_hidden)
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| Reverse(e.timestamp));
+        events.truncate(limit);
+        Ok(events)
+    }
+
+    async fn get_dialogues_with_npc(
+        &self,
+        pc_id: PlayerCharacterId,
+        npc_id: CharacterId,
+        limit: usize,
+    ) -> Result<Vec<StoryEvent>, RepoError> {
+        let state = self.state();
+        let mut events: Vec<StoryEvent> = state
+            .dialogue_events
+            .iter()
+            .filter(|(pc, _)| *pc == pc_id)
+            .filter_map(|(_, id)| state.story_events.get(*id))
+            .filter(|e| {
+                matches!(&e.event_type, StoryEventType::DialogueExchange { npc_id: id, .. } if *id == npc_id)
+            })
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| Reverse(e.timestamp));
+        events.truncate(limit);
+        Ok(events)
+    }
+
+    async fn update_spoke_to(
+        &self,
+        pc_id: PlayerCharacterId,
+        npc_id: CharacterId,
+        _timestamp: DateTime<Utc>,
+        _last_topic: Option<String>,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        match state
+            .spoke_to
+            .iter_mut()
+            .find(|(pc, npc, _)| *pc == pc_id && *npc == npc_id)
+        {
+            Some((_, _, count)) => *count += 1,
+            None => state.spoke_to.push((pc_id, npc_id, 1)),
+        }
+        Ok(())
+    }
+
+    async fn record_dialogue_context(
+        &self,
+        _world_id: WorldId,
+        story_event_id: StoryEventId,
+        pc_id: PlayerCharacterId,
+        npc_id: CharacterId,
+        player_dialogue: String,
+        npc_dialogue: String,
+        _topics: Vec<String>,
+        _scene_id: Option<SceneId>,
+        _location_id: Option<LocationId>,
+        _region_id: Option<RegionId>,
+        _game_time: Option<GameTime>,
+        _timestamp: DateTime<Utc>,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let pc_name = state
+            .player_characters
+            .get(pc_id)
+            .map(|pc| pc.name.clone())
+            .ok_or(RepoError::NotFound)?;
+        let npc_name = state
+            .characters
+            .get(npc_id)
+            .map(|npc| npc.name.clone())
+            .ok_or(RepoError::NotFound)?;
+
+        if !state.dialogue_events.contains(&(pc_id, story_event_id)) {
+            state.dialogue_events.push((pc_id, story_event_id));
+        }
+
+        if state.active_conversation(pc_id, npc_id).is_none() {
+            // Numbered rather than random, so runs stay reproducible
+            let id = Uuid::from_u128(state.conversations.len() as u128 + 1);
+            state.conversations.push(Conversation {
+                id,
+                pc_id,
+                npc_id,
+                is_active: true,
+                turns: Vec::new(),
+            });
+        }
+        let conversation = state
+            .active_conversation(pc_id, npc_id)
+            .ok_or(RepoError::NotFound)?;
+        let last_order = conversation.turns.last().map_or(0, |t| t.order);
+        conversation.turns.push(ConversationTurnRecord {
+            speaker: pc_name,
+            text: player_dialogue,
+            order: last_order + 1,
+        });
+        conversation.turns.push(ConversationTurnRecord {
+            speaker: npc_name,
+            text: npc_dialogue,
+            order: last_order + 2,
+        });
+        Ok(())
+    }
+
+    async fn get_conversation_turns(
+        &self,
+        pc_id: PlayerCharacterId,
+        npc_id: CharacterId,
+        limit: usize,
+    ) -> Result<Vec<ConversationTurnRecord>, RepoError> {
+        let mut state = self.state();
+        Ok(state
+            .active_conversation(pc_id, npc_id)
+            .map(|c| c.turns.iter().take(limit).cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn get_active_conversation_id(
+        &self,
+        pc_id: PlayerCharacterId,
+        npc_id: CharacterId,
+    ) -> Result<Option<Uuid>, RepoError> {
+        Ok(self
+            .state()
+            .active_conversation(pc_id, npc_id)
+            .map(|c| c.id))
+    }
+
+    async fn is_conversation_active(&self, conversation_id: Uuid) -> Result<bool, RepoError> {
+        Ok(self
+            .state()
+            .conversations
+            .iter()
+            .any(|c| c.id == conversation_id && c.is_active))
+    }
+
+    async fn end_conversation(&self, conversation_id: Uuid) -> Result<bool, RepoError> {
+        let mut state = self.state();
+        match state
+            .conversations
+            .iter_mut()
+            .find(|c| c.id == conversation_id && c.is_active)
+        {
+            Some(conversation) => {
+                conversation.is_active = false;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn end_active_conversation(
+        &self,
+        pc_id: PlayerCharacterId,
+        npc_id: CharacterId,
+    ) -> Result<Option<Uuid>, RepoError> {
+        let mut state = self.state();
+        Ok(state
+            .active_conversation(pc_id, npc_id)
+            .map(|conversation| {
+                conversation.is_active = false;
+                conversation.id
+            }))
+    }
+
+    async fn list_dialogue_counts(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<DialogueCount>, RepoError> {
+        let state = self.state();
+        Ok(state
+            .spoke_to
+            .iter()
+            .filter(|(pc_id, _, _)| {
+                state
+                    .player_characters
+                    .get(*pc_id)
+                    .is_some_and(|pc| pc.world_id == world_id)
+            })
+            .map(|&(pc_id, npc_id, exchanges)| DialogueCount {
+                pc_id,
+                npc_id,
+                exchanges,
+            })
+            .collect())
+    }
+
+    async fn count_story_events_by_pc(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<(PlayerCharacterId, u32)>, RepoError> {
+        let state = self.state();
+        let events: Vec<(&StoryEvent, String)> = state
+            .story_events
+            .values()
+            .filter(|e| e.world_id == world_id)
+            .map(|e| (e, serde_json::to_string(&e.event_type).unwrap_or_default()))
+            .collect();
+        Ok(state
+            .player_characters
+            .values()
+            .filter(|pc| pc.world_id == world_id)
+            .map(|pc| {
+                let pc_id = pc.id.to_string();
+                let count = events
+                    .iter()
+                    .filter(|(e, json)| {
+                        state.dialogue_events.contains(&(pc.id, e.id)) || json.contains(&pc_id)
+                    })
+                    .count();
+                (pc.id, count as u32)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect())
+    }
+
+    async fn get_triggers_for_region(
+        &self,
+        world_id: WorldId,
+        region_id: RegionId,
+    ) -> Result<Vec<NarrativeEvent>, RepoError> {
+        let mut events: Vec<NarrativeEvent> = self
+            .state()
+            .events
+            .values()
+            .filter(|e| {
+                e.world_id == world_id
+                    && e.is_active
+                    && !e.is_triggered
+                    && triggers_in_region(e, region_id)
+            })
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| Reverse(e.priority));
+        Ok(events)
+    }
+
+    async fn set_event_active(&self, id: NarrativeEventId, active: bool) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let event = state.events.get_mut(id).ok_or(RepoError::NotFound)?;
+        event.is_active = active;
+        Ok(())
+    }
+
+    async fn get_completed_events(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<NarrativeEventId>, RepoError> {
+        let state = self.state();
+        let mut completed = Vec::new();
+        for chain in state.chains.values().filter(|c| c.world_id == world_id) {
+            for event_id in &chain.completed_events {
+                if !completed.contains(event_id) {
+                    completed.push(*event_id);
+                }
+            }
+        }
+        Ok(completed)
+    }
+}
+
+#[async_trait]
+impl BroadsheetRepo for MemoryStore {
+    async fn get(&self, id: BroadsheetId) -> Result<Option<Broadsheet>, RepoError> {
+        Ok(self.state().broadsheets.get(id).cloned())
+    }
+
+    async fn save(&self, broadsheet: &Broadsheet) -> Result<(), RepoError> {
+        self.state()
+            .broadsheets
+            .insert(broadsheet.id, broadsheet.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: BroadsheetId) -> Result<(), RepoError> {
+        self.state().broadsheets.remove(id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Broadsheet>, RepoError> {
+        let mut broadsheets: Vec<Broadsheet> = self
+            .state()
+            .broadsheets
+            .values()
+            .filter(|b| b.world_id == world_id)
+            .cloned()
+            .collect();
+        broadsheets.sort_by_key(|b| Reverse(b.issue_number));
+        Ok(broadsheets)
+    }
+}
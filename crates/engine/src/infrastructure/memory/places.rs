@@ -0,0 +1,642 @@
+//! Locations, regions, their visual states and who is staged in them.
+
+use std::cmp::Reverse;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use wrldbldr_domain::*;
+
+use super::{MemoryState, MemoryStore};
+use crate::infrastructure::ports::{
+    CrowdRepo, LocationRepo, LocationStateRepo, RegionStateRepo, RepoError, StagingRepo,
+};
+
+impl MemoryState {
+    fn current_staging(&self, region_id: RegionId) -> Option<&Staging> {
+        let id = *self.current_stagings.get(region_id)?;
+        self.stagings.get(id)
+    }
+
+    fn current_staging_mut(&mut self, region_id: RegionId) -> Option<&mut Staging> {
+        let id = *self.current_stagings.get(region_id)?;
+        self.stagings.get_mut(id)
+    }
+}
+
+#[async_trait]
+impl LocationRepo for MemoryStore {
+    async fn get_location(&self, id: LocationId) -> Result<Option<Location>, RepoError> {
+        Ok(self.state().locations.get(id).cloned())
+    }
+
+    async fn save_location(&self, location: &Location) -> Result<(), RepoError> {
+        self.state().locations.insert(location.id, location.clone());
+        Ok(())
+    }
+
+    async fn delete_location(&self, id: LocationId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.locations.remove(id);
+        state
+            .location_connections
+            .retain(|c| c.from_location != id && c.to_location != id);
+        state.region_exits.retain(|e| e.to_location != id);
+        Ok(())
+    }
+
+    async fn list_locations_in_world(&self, world_id: WorldId) -> Result<Vec<Location>, RepoError> {
+        Ok(self
+            .state()
+            .locations
+            .values()
+            .filter(|l| l.world_id == world_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_region(&self, id: RegionId) -> Result<Option<Region>, RepoError> {
+        Ok(self.state().regions.get(id).cloned())
+    }
+
+    async fn save_region(&self, region: &Region) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.locations.contains(region.location_id) {
+            return Err(RepoError::NotFound);
+        }
+        state.regions.insert(region.id, region.clone());
+        Ok(())
+    }
+
+    async fn delete_region(&self, id: RegionId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.regions.remove(id);
+        state.crowds.rows.retain(|(_, c)| c.region_id != id);
+        state
+            .region_connections
+            .retain(|c| c.from_region != id && c.to_region != id);
+        state
+            .region_exits
+            .retain(|e| e.from_region != id && e.arrival_region_id != id);
+        Ok(())
+    }
+
+    async fn list_regions_in_location(
+        &self,
+        location_id: LocationId,
+    ) -> Result<Vec<Region>, RepoError> {
+        let mut regions: Vec<Region> = self
+            .state()
+            .regions
+            .values()
+            .filter(|r| r.location_id == location_id)
+            .cloned()
+            .collect();
+        regions.sort_by_key(|r| r.order);
+        Ok(regions)
+    }
+
+    async fn get_connections(
+        &self,
+        region_id: RegionId,
+    ) -> Result<Vec<RegionConnection>, RepoError> {
+        Ok(self
+            .state()
+            .region_connections
+            .iter()
+            .filter(|c| c.from_region == region_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_connection(&self, connection: &RegionConnection) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.regions.contains(connection.from_region)
+            || !state.regions.contains(connection.to_region)
+        {
+            return Err(RepoError::NotFound);
+        }
+        let mut directions = vec![connection.clone()];
+        if connection.bidirectional {
+            directions.push(RegionConnection {
+                from_region: connection.to_region,
+                to_region: connection.from_region,
+                ..connection.clone()
+            });
+        }
+        for direction in directions {
+            state.region_connections.retain(|c| {
+                !(c.from_region == direction.from_region && c.to_region == direction.to_region)
+            });
+            state.region_connections.push(direction);
+        }
+        Ok(())
+    }
+
+    async fn delete_connection(
+        &self,
+        from_region: RegionId,
+        to_region: RegionId,
+    ) -> Result<(), RepoError> {
+        self.state().region_connections.retain(|c| {
+            !((c.from_region == from_region && c.to_region == to_region)
+                || (c.from_region == to_region && c.to_region == from_region))
+        });
+        Ok(())
+    }
+
+    async fn get_location_exits(
+        &self,
+        location_id: LocationId,
+    ) -> Result<Vec<LocationConnection>, RepoError> {
+        Ok(self
+            .state()
+            .location_connections
+            .iter()
+            .filter(|c| c.from_location == location_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_location_connection(
+        &self,
+        connection: &LocationConnection,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.locations.contains(connection.from_location)
+            || !state.locations.contains(connection.to_location)
+        {
+            return Err(RepoError::NotFound);
+        }
+        let mut directions = vec![connection.clone()];
+        if connection.bidirectional {
+            directions.push(LocationConnection {
+                from_location: connection.to_location,
+                to_location: connection.from_location,
+                ..connection.clone()
+            });
+        }
+        for direction in directions {
+            state.location_connections.retain(|c| {
+                !(c.from_location == direction.from_location
+                    && c.to_location == direction.to_location)
+            });
+            state.location_connections.push(direction);
+        }
+        Ok(())
+    }
+
+    async fn delete_location_connection(
+        &self,
+        from_location: LocationId,
+        to_location: LocationId,
+    ) -> Result<(), RepoError> {
+        self.state().location_connections.retain(|c| {
+            !((c.from_location == from_location && c.to_location == to_location)
+                || (c.from_location == to_location && c.to_location == from_location))
+        });
+        Ok(())
+    }
+
+    async fn get_region_exits(&self, region_id: RegionId) -> Result<Vec<RegionExit>, RepoError> {
+        Ok(self
+            .state()
+            .region_exits
+            .iter()
+            .filter(|e| e.from_region == region_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_region_exit(&self, exit: &RegionExit) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let from_location = state
+            .regions
+            .get(exit.from_region)
+            .ok_or(RepoError::NotFound)?
+            .location_id;
+        let mut exits = vec![exit.clone()];
+        if exit.bidirectional {
+            exits.push(RegionExit {
+                from_region: exit.arrival_region_id,
+                to_location: from_location,
+                arrival_region_id: exit.from_region,
+                ..exit.clone()
+            });
+        }
+        for exit in exits {
+            state.region_exits.retain(|e| {
+                !(e.from_region == exit.from_region && e.to_location == exit.to_location)
+            });
+            state.region_exits.push(exit);
+        }
+        Ok(())
+    }
+
+    async fn delete_region_exit(
+        &self,
+        region_id: RegionId,
+        location_id: LocationId,
+    ) -> Result<(), RepoError> {
+        self.state()
+            .region_exits
+            .retain(|e| !(e.from_region == region_id && e.to_location == location_id));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl LocationStateRepo for MemoryStore {
+    async fn get(&self, id: LocationStateId) -> Result<Option<LocationState>, RepoError> {
+        Ok(self.state().location_states.get(id).cloned())
+    }
+
+    async fn save(&self, location_state: &LocationState) -> Result<(), RepoError> {
+        self.state()
+            .location_states
+            .insert(location_state.id, location_state.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: LocationStateId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.location_states.remove(id);
+        state.active_location_states.rows.retain(|(_, s)| *s != id);
+        Ok(())
+    }
+
+    async fn list_for_location(
+        &self,
+        location_id: LocationId,
+    ) -> Result<Vec<LocationState>, RepoError> {
+        let mut states: Vec<LocationState> = self
+            .state()
+            .location_states
+            .values()
+            .filter(|s| s.location_id == location_id)
+            .cloned()
+            .collect();
+        states.sort_by_key(|s| Reverse(s.priority));
+        Ok(states)
+    }
+
+    async fn get_default(
+        &self,
+        location_id: LocationId,
+    ) -> Result<Option<LocationState>, RepoError> {
+        Ok(self
+            .state()
+            .location_states
+            .values()
+            .find(|s| s.location_id == location_id && s.is_default)
+            .cloned())
+    }
+
+    async fn set_active(
+        &self,
+        location_id: LocationId,
+        state_id: LocationStateId,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.location_states.contains(state_id) {
+            return Err(RepoError::NotFound);
+        }
+        state.active_location_states.insert(location_id, state_id);
+        Ok(())
+    }
+
+    async fn get_active(
+        &self,
+        location_id: LocationId,
+    ) -> Result<Option<LocationState>, RepoError> {
+        let state = self.state();
+        Ok(state
+            .active_location_states
+            .get(location_id)
+            .and_then(|id| state.location_states.get(*id))
+            .cloned())
+    }
+
+    async fn clear_active(&self, location_id: LocationId) -> Result<(), RepoError> {
+        self.state().active_location_states.remove(location_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RegionStateRepo for MemoryStore {
+    async fn get(&self, id: RegionStateId) -> Result<Option<RegionState>, RepoError> {
+        Ok(self.state().region_states.get(id).cloned())
+    }
+
+    async fn save(&self, region_state: &RegionState) -> Result<(), RepoError> {
+        self.state()
+            .region_states
+            .insert(region_state.id, region_state.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: RegionStateId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.region_states.remove(id);
+        state.active_region_states.rows.retain(|(_, s)| *s != id);
+        Ok(())
+    }
+
+    async fn list_for_region(&self, region_id: RegionId) -> Result<Vec<RegionState>, RepoError> {
+        let mut states: Vec<RegionState> = self
+            .state()
+            .region_states
+            .values()
+            .filter(|s| s.region_id == region_id)
+            .cloned()
+            .collect();
+        states.sort_by_key(|s| Reverse(s.priority));
+        Ok(states)
+    }
+
+    async fn get_default(&self, region_id: RegionId) -> Result<Option<RegionState>, RepoError> {
+        Ok(self
+            .state()
+            .region_states
+            .values()
+            .find(|s| s.region_id == region_id && s.is_default)
+            .cloned())
+    }
+
+    async fn set_active(
+        &self,
+        region_id: RegionId,
+        state_id: RegionStateId,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        if !state.region_states.contains(state_id) {
+            return Err(RepoError::NotFound);
+        }
+        state.active_region_states.insert(region_id, state_id);
+        Ok(())
+    }
+
+    async fn get_active(&self, region_id: RegionId) -> Result<Option<RegionState>, RepoError> {
+        let state = self.state();
+        Ok(state
+            .active_region_states
+            .get(region_id)
+            .and_then(|id| state.region_states.get(*id))
+            .cloned())
+    }
+
+    async fn clear_active(&self, region_id: RegionId) -> Result<(), RepoError> {
+        self.state().active_region_states.remove(region_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StagingRepo for MemoryStore {
+    async fn get_staged_npcs(&self, region_id: RegionId) -> Result<Vec<StagedNpc>, RepoError> {
+        Ok(self
+            .state()
+            .current_staging(region_id)
+            .filter(|s| s.is_active)
+            .map(|s| s.npcs.iter().filter(|n| n.is_present).cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn stage_npc(
+        &self,
+        region_id: RegionId,
+        character_id: CharacterId,
+    ) -> Result<(), RepoError> {
+        let now = self.now();
+        let mut state = self.state();
+        let npc = state
+            .characters
+            .get(character_id)
+            .cloned()
+            .ok_or(RepoError::NotFound)?;
+
+        if let Some(staging) = state.current_staging_mut(region_id) {
+            match staging
+                .npcs
+                .iter_mut()
+                .find(|n| n.character_id == character_id)
+            {
+                Some(staged) => staged.is_present = true,
+                None => staging.npcs.push(manually_staged(&npc)),
+            }
+            return Ok(());
+        }
+
+        let location_id = state
+            .regions
+            .get(region_id)
+            .ok_or(RepoError::NotFound)?
+            .location_id;
+        let world_id = state
+            .locations
+            .get(location_id)
+            .ok_or(RepoError::NotFound)?
+            .world_id;
+        let staging = Staging::new(
+            region_id,
+            location_id,
+            world_id,
+            now,
+            "system",
+            StagingSource::DmCustomized,
+            24,
+            now,
+        )
+        .with_npcs(vec![manually_staged(&npc)]);
+        state.current_stagings.insert(region_id, staging.id);
+        state.stagings.insert(staging.id, staging);
+        Ok(())
+    }
+
+    async fn unstage_npc(
+        &self,
+        region_id: RegionId,
+        character_id: CharacterId,
+    ) -> Result<(), RepoError> {
+        if let Some(staging) = self.state().current_staging_mut(region_id) {
+            for npc in staging
+                .npcs
+                .iter_mut()
+                .filter(|n| n.character_id == character_id)
+            {
+                npc.is_present = false;
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_pending_staging(&self, world_id: WorldId) -> Result<Vec<Staging>, RepoError> {
+        let state = self.state();
+        let mut pending: Vec<Staging> = state
+            .stagings
+            .values()
+            .filter(|s| {
+                s.world_id == world_id
+                    && !s.is_active
+                    && state.current_stagings.get(s.region_id) != Some(&s.id)
+            })
+            .cloned()
+            .collect();
+        pending.sort_by_key(|s| Reverse(s.approved_at));
+        Ok(pending)
+    }
+
+    async fn save_pending_staging(&self, staging: &Staging) -> Result<(), RepoError> {
+        self.state().stagings.insert(staging.id, staging.clone());
+        Ok(())
+    }
+
+    async fn delete_pending_staging(&self, id: StagingId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        state.stagings.remove(id);
+        state.current_stagings.rows.retain(|(_, s)| *s != id);
+        Ok(())
+    }
+
+    async fn get_active_staging(
+        &self,
+        region_id: RegionId,
+        current_game_time: DateTime<Utc>,
+    ) -> Result<Option<Staging>, RepoError> {
+        Ok(self
+            .state()
+            .current_staging(region_id)
+            .filter(|s| s.is_active && !s.is_expired(&current_game_time))
+            .cloned())
+    }
+
+    async fn activate_staging(
+        &self,
+        staging_id: StagingId,
+        region_id: RegionId,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let previous = state.current_stagings.get(region_id).copied();
+        if let Some(previous) = previous.and_then(|id| state.stagings.get_mut(id)) {
+            previous.is_active = false;
+        }
+        let staging = state
+            .stagings
+            .get_mut(staging_id)
+            .ok_or(RepoError::NotFound)?;
+        staging.is_active = true;
+        state.current_stagings.insert(region_id, staging_id);
+        Ok(())
+    }
+
+    async fn deactivate_staging(&self, region_id: RegionId) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let current = state.current_stagings.remove(region_id);
+        if let Some(staging) = current.and_then(|id| state.stagings.get_mut(id)) {
+            staging.is_active = false;
+        }
+        Ok(())
+    }
+
+    async fn get_staging_history(
+        &self,
+        region_id: RegionId,
+        limit: usize,
+    ) -> Result<Vec<Staging>, RepoError> {
+        let state = self.state();
+        let mut history: Vec<Staging> = state
+            .stagings
+            .values()
+            .filter(|s| {
+                s.region_id == region_id && state.current_stagings.get(s.region_id) != Some(&s.id)
+            })
+            .cloned()
+            .collect();
+        history.sort_by_key(|s| Reverse(s.approved_at));
+        history.truncate(limit);
+        Ok(history)
+    }
+
+    async fn get_npc_mood(
+        &self,
+        region_id: RegionId,
+        npc_id: CharacterId,
+    ) -> Result<MoodState, RepoError> {
+        let state = self.state();
+        let staged = state
+            .current_staging(region_id)
+            .and_then(|s| s.npcs.iter().find(|n| n.character_id == npc_id));
+        match staged {
+            Some(npc) => Ok(npc.mood),
+            None => state
+                .characters
+                .get(npc_id)
+                .map(|c| c.default_mood)
+                .ok_or(RepoError::NotFound),
+        }
+    }
+
+    async fn set_npc_mood(
+        &self,
+        region_id: RegionId,
+        npc_id: CharacterId,
+        mood: MoodState,
+    ) -> Result<(), RepoError> {
+        let mut state = self.state();
+        let staged = state
+            .current_staging_mut(region_id)
+            .and_then(|s| s.npcs.iter_mut().find(|n| n.character_id == npc_id))
+            .ok_or(RepoError::NotFound)?;
+        staged.mood = mood;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CrowdRepo for MemoryStore {
+    async fn get(&self, id: CrowdId) -> Result<Option<Crowd>, RepoError> {
+        Ok(self.state().crowds.get(id).cloned())
+    }
+
+    async fn save(&self, crowd: &Crowd) -> Result<(), RepoError> {
+        self.state().crowds.insert(crowd.id, crowd.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: CrowdId) -> Result<(), RepoError> {
+        self.state().crowds.remove(id);
+        Ok(())
+    }
+
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<Crowd>, RepoError> {
+        let mut crowds: Vec<Crowd> = self
+            .state()
+            .crowds
+            .values()
+            .filter(|c| c.world_id == world_id)
+            .cloned()
+            .collect();
+        crowds.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(crowds)
+    }
+
+    async fn list_in_region(&self, region_id: RegionId) -> Result<Vec<Crowd>, RepoError> {
+        let mut crowds: Vec<Crowd> = self
+            .state()
+            .crowds
+            .values()
+            .filter(|c| c.region_id == region_id)
+            .cloned()
+            .collect();
+        crowds.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(crowds)
+    }
+}
+
+fn manually_staged(npc: &Character) -> StagedNpc {
+    let mut staged = StagedNpc::new(npc.id, npc.name.clone(), true, "Manually staged");
+    staged.sprite_asset = npc.sprite_asset.clone();
+    staged.portrait_asset = npc.portrait_asset.clone();
+    staged.mood = npc.default_mood;
+    staged
+}
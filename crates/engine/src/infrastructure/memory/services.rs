@@ -0,0 +1,481 @@
+//! Queue, settings, blob storage and external services, held in memory.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use wrldbldr_domain::*;
+
+use crate::infrastructure::blob_store::{content_key, validate_key};
+use crate::infrastructure::ports::{
+    BlobError, BlobStorePort, ClockPort, DailyUsage, ImageGenError, ImageGenPort, ImageRequest,
+    ImageResult, LlmError, LlmModelInfo, LlmModelPort, ModelPullProgress, ProbeError, QueueError,
+    QueueItem, QueueItemData, QueueItemStatus, QueuePort, RepoError, ServiceProbePort,
+    SettingsRepo, TranscriptionError, TranscriptionPort, TranscriptionRequest, UsageDelta,
+    UsageRepo,
+};
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+struct QueueRow {
+    queue_type: &'static str,
+    callback_id: Option<String>,
+    item: QueueItem,
+}
+
+#[derive(Default)]
+struct QueueState {
+    rows: Vec<QueueRow>,
+    read_state: HashMap<(String, WorldId), (Vec<String>, Vec<String>)>,
+}
+
+/// Queue with the SQLite queue's ordering and status rules.
+///
+/// Item ids are numbered in enqueue order rather than random.
+pub(crate) struct MemoryQueue {
+    state: Mutex<QueueState>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl MemoryQueue {
+    pub(crate) fn new(clock: Arc<dyn ClockPort>) -> Self {
+        Self {
+            state: Mutex::new(QueueState::default()),
+            clock,
+        }
+    }
+
+    fn enqueue(
+        &self,
+        queue_type: &'static str,
+        callback_id: Option<String>,
+        data: QueueItemData,
+    ) -> Uuid {
+        let mut state = lock(&self.state);
+        let id = Uuid::from_u128(state.rows.len() as u128 + 1);
+        state.rows.push(QueueRow {
+            queue_type,
+            callback_id,
+            item: QueueItem {
+                id,
+                data,
+                created_at: self.clock.now(),
+                status: QueueItemStatus::Pending,
+                error_message: None,
+                result_json: None,
+            },
+        });
+        id
+    }
+
+    /// Oldest pending item of a type, moved to processing
+    fn dequeue(&self, queue_type: &str) -> Option<QueueItem> {
+        let mut state = lock(&self.state);
+        let row = state
+            .rows
+            .iter_mut()
+            .find(|r| r.queue_type == queue_type && r.item.status == QueueItemStatus::Pending)?;
+        row.item.status = QueueItemStatus::Processing;
+        Some(row.item.clone())
+    }
+
+    fn update(&self, id: Uuid, apply: impl FnOnce(&mut QueueItem)) -> Result<(), QueueError> {
+        let mut state = lock(&self.state);
+        let row = state
+            .rows
+            .iter_mut()
+            .find(|r| r.item.id == id)
+            .ok_or_else(|| QueueError::Error(format!("Queue item not found: {}", id)))?;
+        apply(&mut row.item);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl QueuePort for MemoryQueue {
+    async fn enqueue_player_action(&self, data: &PlayerActionData) -> Result<Uuid, QueueError> {
+        Ok(self.enqueue(
+            "player_action",
+            None,
+            QueueItemData::PlayerAction(data.clone()),
+        ))
+    }
+
+    async fn dequeue_player_action(&self) -> Result<Option<QueueItem>, QueueError> {
+        Ok(self.dequeue("player_action"))
+    }
+
+    async fn enqueue_llm_request(&self, data: &LlmRequestData) -> Result<Uuid, QueueError> {
+        Ok(self.enqueue(
+            "llm_request",
+            Some(data.callback_id.clone()),
+            QueueItemData::LlmRequest(data.clone()),
+        ))
+    }
+
+    async fn dequeue_llm_request(&self) -> Result<Option<QueueItem>, QueueError> {
+        Ok(self.dequeue("llm_request"))
+    }
+
+    async fn enqueue_dm_approval(&self, data: &ApprovalRequestData) -> Result<Uuid, QueueError> {
+        Ok(self.enqueue("dm_approval", None, QueueItemData::DmApproval(data.clone())))
+    }
+
+    async fn dequeue_dm_approval(&self) -> Result<Option<QueueItem>, QueueError> {
+        Ok(self.dequeue("dm_approval"))
+    }
+
+    async fn enqueue_asset_generation(
+        &self,
+        data: &AssetGenerationData,
+    ) -> Result<Uuid, QueueError> {
+        Ok(self.enqueue(
+            "asset_generation",
+            None,
+            QueueItemData::AssetGeneration(data.clone()),
+        ))
+    }
+
+    async fn dequeue_asset_generation(&self) -> Result<Option<QueueItem>, QueueError> {
+        Ok(self.dequeue("asset_generation"))
+    }
+
+    async fn mark_complete(&self, id: Uuid) -> Result<(), QueueError> {
+        self.update(id, |item| item.status = QueueItemStatus::Completed)
+    }
+
+    async fn mark_failed(&self, id: Uuid, error: &str) -> Result<(), QueueError> {
+        self.update(id, |item| {
+            item.status = QueueItemStatus::Failed;
+            item.error_message = Some(error.to_string());
+        })
+    }
+
+    async fn mark_failed_if_status(
+        &self,
+        id: Uuid,
+        expected: QueueItemStatus,
+        error: &str,
+    ) -> Result<bool, QueueError> {
+        let mut state = lock(&self.state);
+        let row = state
+            .rows
+            .iter_mut()
+            .find(|r| r.item.id == id)
+            .ok_or_else(|| QueueError::Error(format!("Queue item not found: {}", id)))?;
+        if row.item.status != expected {
+            return Ok(false);
+        }
+        row.item.status = QueueItemStatus::Failed;
+        row.item.error_message = Some(error.to_string());
+        Ok(true)
+    }
+
+    async fn get_item(&self, id: Uuid) -> Result<Option<QueueItem>, QueueError> {
+        Ok(lock(&self.state)
+            .rows
+            .iter()
+            .find(|r| r.item.id == id)
+            .map(|r| r.item.clone()))
+    }
+
+    async fn get_pending_count(&self, queue_type: &str) -> Result<usize, QueueError> {
+        Ok(lock(&self.state)
+            .rows
+            .iter()
+            .filter(|r| r.queue_type == queue_type && r.item.status == QueueItemStatus::Pending)
+            .count())
+    }
+
+    async fn list_by_type(
+        &self,
+        queue_type: &str,
+        limit: usize,
+    ) -> Result<Vec<QueueItem>, QueueError> {
+        Ok(lock(&self.state)
+            .rows
+            .iter()
+            .rev()
+            .filter(|r| r.queue_type == queue_type)
+            .take(limit)
+            .map(|r| r.item.clone())
+            .collect())
+    }
+
+    async fn set_result_json(&self, id: Uuid, result_json: &str) -> Result<(), QueueError> {
+        self.update(id, |item| item.result_json = Some(result_json.to_string()))
+    }
+
+    async fn cancel_pending_llm_request_by_callback_id(
+        &self,
+        callback_id: &str,
+    ) -> Result<bool, QueueError> {
+        let mut state = lock(&self.state);
+        let mut cancelled = false;
+        for row in state.rows.iter_mut().filter(|r| {
+            r.queue_type == "llm_request"
+                && r.item.status == QueueItemStatus::Pending
+                && r.callback_id.as_deref() == Some(callback_id)
+        }) {
+            row.item.status = QueueItemStatus::Failed;
+            row.item.error_message = Some("Cancelled".to_string());
+            cancelled = true;
+        }
+        Ok(cancelled)
+    }
+
+    async fn get_approval_request(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<ApprovalRequestData>, QueueError> {
+        Ok(lock(&self.state)
+            .rows
+            .iter()
+            .find(|r| r.item.id == id)
+            .and_then(|r| match &r.item.data {
+                QueueItemData::DmApproval(data) => Some(data.clone()),
+                _ => None,
+            }))
+    }
+
+    async fn get_generation_read_state(
+        &self,
+        user_id: &str,
+        world_id: WorldId,
+    ) -> Result<Option<(Vec<String>, Vec<String>)>, QueueError> {
+        Ok(lock(&self.state)
+            .read_state
+            .get(&(user_id.to_string(), world_id))
+            .cloned())
+    }
+
+    async fn upsert_generation_read_state(
+        &self,
+        user_id: &str,
+        world_id: WorldId,
+        read_batches: &[String],
+        read_suggestions: &[String],
+    ) -> Result<(), QueueError> {
+        lock(&self.state).read_state.insert(
+            (user_id.to_string(), world_id),
+            (read_batches.to_vec(), read_suggestions.to_vec()),
+        );
+        Ok(())
+    }
+
+    async fn delete_by_callback_id(&self, callback_id: &str) -> Result<bool, QueueError> {
+        let mut state = lock(&self.state);
+        let before = state.rows.len();
+        state
+            .rows
+            .retain(|r| r.callback_id.as_deref() != Some(callback_id));
+        Ok(state.rows.len() < before)
+    }
+}
+
+#[derive(Default)]
+struct SettingsState {
+    global: Option<AppSettings>,
+    worlds: HashMap<WorldId, AppSettings>,
+    usage: Vec<(WorldId, DailyUsage)>,
+}
+
+/// Settings and usage counters.
+#[derive(Default)]
+pub(crate) struct MemorySettings {
+    state: Mutex<SettingsState>,
+}
+
+#[async_trait]
+impl SettingsRepo for MemorySettings {
+    async fn get_global(&self) -> Result<Option<AppSettings>, RepoError> {
+        Ok(lock(&self.state).global.clone())
+    }
+
+    async fn save_global(&self, settings: &AppSettings) -> Result<(), RepoError> {
+        lock(&self.state).global = Some(settings.clone());
+        Ok(())
+    }
+
+    async fn get_for_world(&self, world_id: WorldId) -> Result<Option<AppSettings>, RepoError> {
+        Ok(lock(&self.state).worlds.get(&world_id).cloned())
+    }
+
+    async fn save_for_world(
+        &self,
+        world_id: WorldId,
+        settings: &AppSettings,
+    ) -> Result<(), RepoError> {
+        lock(&self.state).worlds.insert(world_id, settings.clone());
+        Ok(())
+    }
+
+    async fn delete_for_world(&self, world_id: WorldId) -> Result<(), RepoError> {
+        lock(&self.state).worlds.remove(&world_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UsageRepo for MemorySettings {
+    async fn record(
+        &self,
+        world_id: WorldId,
+        day: NaiveDate,
+        delta: UsageDelta,
+    ) -> Result<(), RepoError> {
+        let mut state = lock(&self.state);
+        let index = match state
+            .usage
+            .iter()
+            .position(|(w, u)| *w == world_id && u.day == day)
+        {
+            Some(index) => index,
+            None => {
+                state.usage.push((
+                    world_id,
+                    DailyUsage {
+                        day,
+                        llm_requests: 0,
+                        prompt_tokens: 0,
+                        completion_tokens: 0,
+                        image_generations: 0,
+                        storage_bytes: 0,
+                    },
+                ));
+                state.usage.len() - 1
+            }
+        };
+        let usage = &mut state.usage[index].1;
+        usage.llm_requests += delta.llm_requests;
+        usage.prompt_tokens += delta.prompt_tokens;
+        usage.completion_tokens += delta.completion_tokens;
+        usage.image_generations += delta.image_generations;
+        usage.storage_bytes += delta.storage_bytes;
+        Ok(())
+    }
+
+    async fn list_daily(
+        &self,
+        world_id: WorldId,
+        since: NaiveDate,
+    ) -> Result<Vec<DailyUsage>, RepoError> {
+        let mut days: Vec<DailyUsage> = lock(&self.state)
+            .usage
+            .iter()
+            .filter(|(w, u)| *w == world_id && u.day >= since)
+            .map(|(_, u)| u.clone())
+            .collect();
+        days.sort_by_key(|u| u.day);
+        Ok(days)
+    }
+
+    async fn list_for_day(&self, day: NaiveDate) -> Result<Vec<(WorldId, DailyUsage)>, RepoError> {
+        Ok(lock(&self.state)
+            .usage
+            .iter()
+            .filter(|(_, u)| u.day == day)
+            .cloned()
+            .collect())
+    }
+}
+
+/// Content-addressed blobs. URLs point nowhere a Player could fetch.
+#[derive(Default)]
+pub(crate) struct MemoryBlobStore {
+    blobs: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+#[async_trait]
+impl BlobStorePort for MemoryBlobStore {
+    async fn put(&self, data: Vec<u8>, content_type: &str) -> Result<String, BlobError> {
+        let key = content_key(&data, content_type);
+        lock(&self.blobs).insert(key.clone(), data);
+        Ok(key)
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, BlobError> {
+        validate_key(key)?;
+        lock(&self.blobs)
+            .get(key)
+            .cloned()
+            .ok_or(BlobError::NotFound)
+    }
+
+    fn signed_url(&self, key: &str, _expires_in: Duration) -> Result<String, BlobError> {
+        validate_key(key)?;
+        Ok(format!("memory://{}", key))
+    }
+
+    fn verify_signature(&self, _key: &str, _expires_at: i64, _signature: &str) -> bool {
+        false
+    }
+}
+
+/// Image generation, transcription, the model registry and service probes
+/// with no services behind them: every call fails as if the service were
+/// down.
+pub(crate) struct OfflineServices;
+
+#[async_trait]
+impl ImageGenPort for OfflineServices {
+    async fn generate(&self, _request: ImageRequest) -> Result<ImageResult, ImageGenError> {
+        Err(ImageGenError::Unavailable)
+    }
+
+    async fn check_health(&self) -> Result<bool, ImageGenError> {
+        Ok(false)
+    }
+}
+
+#[async_trait]
+impl TranscriptionPort for OfflineServices {
+    async fn transcribe(
+        &self,
+        _request: TranscriptionRequest,
+    ) -> Result<String, TranscriptionError> {
+        Err(TranscriptionError::Unavailable)
+    }
+}
+
+#[async_trait]
+impl LlmModelPort for OfflineServices {
+    async fn list_models(&self) -> Result<Vec<LlmModelInfo>, LlmError> {
+        Ok(Vec::new())
+    }
+
+    async fn pull_model(
+        &self,
+        model: &str,
+        _progress: tokio::sync::mpsc::UnboundedSender<ModelPullProgress>,
+    ) -> Result<(), LlmError> {
+        Err(LlmError::RequestFailed(format!(
+            "No model server to pull {}",
+            model
+        )))
+    }
+}
+
+#[async_trait]
+impl ServiceProbePort for OfflineServices {
+    async fn check_neo4j(&self, uri: &str, _user: &str, _password: &str) -> Result<(), ProbeError> {
+        Err(ProbeError::Unreachable(uri.to_string()))
+    }
+
+    async fn check_ollama(&self, base_url: &str, _model: &str) -> Result<(), ProbeError> {
+        Err(ProbeError::Unreachable(base_url.to_string()))
+    }
+
+    async fn check_comfyui(&self, base_url: &str) -> Result<(), ProbeError> {
+        Err(ProbeError::Unreachable(base_url.to_string()))
+    }
+
+    async fn free_disk_space(&self, _path: &str) -> Result<u64, ProbeError> {
+        Ok(u64::MAX)
+    }
+}
@@ -2,16 +2,31 @@
 //!
 //! Contains port trait implementations for external dependencies.
 
+pub mod anthropic;
+pub mod blob_store;
 pub mod circuit_breaker;
 pub mod clock;
 pub mod comfyui;
+pub mod diagnostics;
 pub mod importers;
+pub mod llm_cache;
+pub mod llm_router;
+pub mod llm_usage;
 pub mod neo4j;
 pub mod ollama;
+pub mod openai;
 pub mod ports;
 pub mod queue;
 pub mod resilient_llm;
+pub mod rhai_scripts;
+pub mod service_probe;
 pub mod settings;
+pub mod usage;
+pub mod wasm_plugins;
+pub mod whisper;
+pub mod zip;
 
+#[cfg(test)]
+pub(crate) mod memory;
 #[cfg(test)]
 mod queue_integration_tests;
@@ -4,19 +4,20 @@
 //! - `(World)-[:CONTAINS_ACT]->(Act)`
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Row};
+use neo4rs::{query, Row};
 use wrldbldr_domain::{Act, ActId, MonomythStage, WorldId};
 
 use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{ActRepo, RepoError};
 
 /// Repository for Act operations.
 pub struct Neo4jActRepo {
-    graph: Graph,
+    graph: ResilientGraph,
 }
 
 impl Neo4jActRepo {
-    pub fn new(graph: Graph) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 
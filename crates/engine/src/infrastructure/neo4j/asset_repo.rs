@@ -5,20 +5,21 @@
 use std::str::FromStr;
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Node, Row};
+use neo4rs::{query, Node, Row};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use wrldbldr_domain::*;
 
 use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{AssetRepo, RepoError};
 
 pub struct Neo4jAssetRepo {
-    graph: Graph,
+    graph: ResilientGraph,
 }
 
 impl Neo4jAssetRepo {
-    pub fn new(graph: Graph) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 }
@@ -55,6 +56,8 @@ impl AssetRepo for Neo4jAssetRepo {
             .transpose()
             .map_err(|e| RepoError::Serialization(e.to_string()))?
             .unwrap_or_default();
+        let tags_json = serde_json::to_string(&asset.tags)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
 
         // Upsert the asset node
         let q = query(
@@ -67,6 +70,8 @@ impl AssetRepo for Neo4jAssetRepo {
                 a.is_active = $is_active,
                 a.label = $label,
                 a.generation_metadata = $generation_metadata,
+                a.is_favorite = $is_favorite,
+                a.tags_json = $tags_json,
                 a.created_at = $created_at
             ON MATCH SET
                 a.entity_type = $entity_type,
@@ -75,7 +80,9 @@ impl AssetRepo for Neo4jAssetRepo {
                 a.file_path = $file_path,
                 a.is_active = $is_active,
                 a.label = $label,
-                a.generation_metadata = $generation_metadata",
+                a.generation_metadata = $generation_metadata,
+                a.is_favorite = $is_favorite,
+                a.tags_json = $tags_json",
         )
         .param("id", asset.id.to_string())
         .param("entity_type", asset.entity_type.to_string())
@@ -85,6 +92,8 @@ impl AssetRepo for Neo4jAssetRepo {
         .param("is_active", asset.is_active)
         .param("label", asset.label.clone().unwrap_or_default())
         .param("generation_metadata", generation_metadata_json)
+        .param("is_favorite", asset.is_favorite)
+        .param("tags_json", tags_json)
         .param("created_at", asset.created_at.to_rfc3339());
 
         self.graph
@@ -173,6 +182,35 @@ impl AssetRepo for Neo4jAssetRepo {
         Ok(assets)
     }
 
+    /// List the assets of every character, location and item in a world
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<GalleryAsset>, RepoError> {
+        let q = query(
+            "MATCH (w:World {id: $world_id})
+                -[:CONTAINS_CHARACTER|CONTAINS_LOCATION|CONTAINS_ITEM]->()
+                -[:HAS_ASSET]->(a:GalleryAsset)
+            RETURN DISTINCT a
+            ORDER BY a.created_at DESC",
+        )
+        .param("world_id", world_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let mut assets = Vec::new();
+
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            assets.push(row_to_gallery_asset(row)?);
+        }
+
+        Ok(assets)
+    }
+
     /// Set an asset as active (deactivates others of same type for same entity)
     async fn set_active(
         &self,
@@ -241,6 +279,8 @@ fn row_to_gallery_asset(row: Row) -> Result<GalleryAsset, RepoError> {
         .get("file_path")
         .map_err(|e| RepoError::Database(e.to_string()))?;
     let is_active: bool = node.get_bool_or("is_active", false);
+    let is_favorite: bool = node.get_bool_or("is_favorite", false);
+    let tags: Vec<String> = node.get_json_or_default("tags_json");
     let label = node.get_optional_string("label");
     let created_at_str: String = node
         .get("created_at")
@@ -267,6 +307,8 @@ fn row_to_gallery_asset(row: Row) -> Result<GalleryAsset, RepoError> {
         is_active,
         label,
         generation_metadata,
+        is_favorite,
+        tags,
         created_at,
     })
 }
@@ -0,0 +1,167 @@
+//! Neo4j broadsheet repository implementation.
+//!
+//! Issues hang off their world:
+//! - `(World)-[:HAS_BROADSHEET]->(Broadsheet {issue_number, articles, ...})`
+//!
+//! An issue's articles are only read and written whole, so they are stored
+//! as JSON. Drafts store an empty `published_at`.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Node, Row};
+use wrldbldr_domain::{Broadsheet, BroadsheetArticle, BroadsheetId, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{BroadsheetRepo, ClockPort, RepoError};
+
+pub struct Neo4jBroadsheetRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jBroadsheetRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_broadsheet(&self, row: Row) -> Result<Broadsheet, RepoError> {
+        let node: Node = row
+            .get("b")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: BroadsheetId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let articles_json = node.get_string_or("articles", "[]");
+        let articles: Vec<BroadsheetArticle> = serde_json::from_str(&articles_json)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let period_end = node.get_datetime_or("period_end", fallback);
+
+        Ok(Broadsheet {
+            id,
+            world_id,
+            issue_number: node.get_i64_or("issue_number", 1).max(1) as u32,
+            title: node.get_string_or("title", ""),
+            articles,
+            period_start: node.get_datetime_or("period_start", period_end),
+            period_end,
+            published_at: node
+                .get_optional_string("published_at")
+                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                .map(|dt| dt.with_timezone(&Utc)),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+}
+
+#[async_trait]
+impl BroadsheetRepo for Neo4jBroadsheetRepo {
+    async fn get(&self, id: BroadsheetId) -> Result<Option<Broadsheet>, RepoError> {
+        let q = query("MATCH (b:Broadsheet {id: $id}) RETURN b").param("id", id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        match result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            Some(row) => Ok(Some(self.row_to_broadsheet(row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn save(&self, broadsheet: &Broadsheet) -> Result<(), RepoError> {
+        let articles_json = serde_json::to_string(&broadsheet.articles)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+
+        let q = query(
+            "MERGE (b:Broadsheet {id: $id})
+            SET b.world_id = $world_id,
+                b.issue_number = $issue_number,
+                b.title = $title,
+                b.articles = $articles,
+                b.period_start = $period_start,
+                b.period_end = $period_end,
+                b.published_at = $published_at,
+                b.created_at = $created_at,
+                b.updated_at = $updated_at
+            WITH b
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_BROADSHEET]->(b)",
+        )
+        .param("id", broadsheet.id.to_string())
+        .param("world_id", broadsheet.world_id.to_string())
+        .param("issue_number", i64::from(broadsheet.issue_number))
+        .param("title", broadsheet.title.clone())
+        .param("articles", articles_json)
+        .param("period_start", broadsheet.period_start.to_rfc3339())
+        .param("period_end", broadsheet.period_end.to_rfc3339())
+        .param(
+            "published_at",
+            broadsheet
+                .published_at
+                .map(|t| t.to_rfc3339())
+                .unwrap_or_default(),
+        )
+        .param("created_at", broadsheet.created_at.to_rfc3339())
+        .param("updated_at", broadsheet.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: BroadsheetId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (b:Broadsheet {id: $id})
+            DETACH DELETE b",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted broadsheet: {}", id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Broadsheet>, RepoError> {
+        let q = query(
+            "MATCH (:World {id: $world_id})-[:HAS_BROADSHEET]->(b:Broadsheet)
+            RETURN b
+            ORDER BY b.issue_number DESC",
+        )
+        .param("world_id", world_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut broadsheets = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            broadsheets.push(self.row_to_broadsheet(row)?);
+        }
+
+        Ok(broadsheets)
+    }
+}
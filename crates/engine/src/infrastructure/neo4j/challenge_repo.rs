@@ -5,14 +5,17 @@
 //! Challenges use Neo4j edges for relationships:
 //! - `(Challenge)-[:TIED_TO_SCENE]->(Scene)` - Scene this challenge appears in
 //! - `(World)-[:CONTAINS_CHALLENGE]->(Challenge)` - World ownership
+//! - `(World)-[:HAS_SUGGESTION_DECISION]->(SuggestionDecision)` - DM decisions on suggestions
+//! - `(World)-[:HAS_CHALLENGE_RESOLUTION]->(ChallengeResolution)` - History of resolved challenges
 //!
 //! Complex fields (outcomes, triggers, difficulty) are stored as JSON.
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Row};
+use neo4rs::{query, Row};
 use wrldbldr_domain::*;
 
 use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{ChallengeRepo, RepoError};
 
 // =============================================================================
@@ -175,14 +178,94 @@ impl From<TriggerConditionStored> for TriggerCondition {
 
 /// Repository for Challenge operations.
 pub struct Neo4jChallengeRepo {
-    graph: Graph,
+    graph: ResilientGraph,
 }
 
 impl Neo4jChallengeRepo {
-    pub fn new(graph: Graph) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 
+    /// Convert a Neo4j row to a SuggestionDecision.
+    fn row_to_suggestion_decision(&self, row: Row) -> Result<SuggestionDecision, RepoError> {
+        let node: neo4rs::Node = row
+            .get("d")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let id = node
+            .get_uuid("id")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let verdict = node
+            .get_optional_string("verdict_json")
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or(SuggestionVerdict::Accepted);
+
+        Ok(SuggestionDecision {
+            id,
+            world_id,
+            challenge_id: node
+                .get_optional_string("challenge_id")
+                .and_then(|s| s.parse::<uuid::Uuid>().ok())
+                .map(ChallengeId::from),
+            challenge_name: node.get_string_or("challenge_name", ""),
+            skill_name: node.get_string_or("skill_name", ""),
+            difficulty: node.get_string_or("difficulty", ""),
+            confidence: node.get_string_or("confidence", ""),
+            reasoning: node.get_string_or("reasoning", ""),
+            npc_name: node.get_string_or("npc_name", ""),
+            player_dialogue: node.get_optional_string("player_dialogue"),
+            verdict,
+            decided_at: node.get_datetime_or("decided_at", chrono::Utc::now()),
+        })
+    }
+
+    /// Convert a Neo4j row to a ChallengeResolution.
+    fn row_to_resolution(&self, row: Row) -> Result<ChallengeResolution, RepoError> {
+        let node: neo4rs::Node = row
+            .get("r")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let id = node
+            .get_uuid("id")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let challenge_id: ChallengeId = parse_typed_id(&node, "challenge_id")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let pc_id: PlayerCharacterId =
+            parse_typed_id(&node, "pc_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let outcome: OutcomeType = node
+            .get_json("outcome_json")
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let triggers: Vec<OutcomeTrigger> = node
+            .get_optional_string("triggers_json")
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        let int = |field: &str| node.get::<i64>(field).unwrap_or(0) as i32;
+
+        Ok(ChallengeResolution {
+            id,
+            world_id,
+            challenge_id,
+            challenge_name: node.get_string_or("challenge_name", ""),
+            skill_name: node.get_optional_string("skill_name"),
+            difficulty: node.get_string_or("difficulty", ""),
+            pc_id,
+            character_name: node.get_string_or("character_name", ""),
+            roll: int("roll"),
+            modifier: int("modifier"),
+            total: int("total"),
+            roll_breakdown: node.get_optional_string("roll_breakdown"),
+            outcome,
+            outcome_description: node.get_string_or("outcome_description", ""),
+            edited: node.get("edited").unwrap_or(false),
+            triggers,
+            resolved_at: node.get_datetime_or("resolved_at", chrono::Utc::now()),
+        })
+    }
+
     /// Convert a Neo4j row to a Challenge entity.
     fn row_to_challenge(&self, row: Row) -> Result<Challenge, RepoError> {
         let node: neo4rs::Node = row
@@ -497,4 +580,190 @@ impl ChallengeRepo for Neo4jChallengeRepo {
 
         Ok(challenge_ids)
     }
+
+    async fn save_suggestion_decision(
+        &self,
+        decision: &SuggestionDecision,
+    ) -> Result<(), RepoError> {
+        let verdict_json = serde_json::to_string(&decision.verdict)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+
+        let q = query(
+            "MATCH (w:World {id: $world_id})
+            MERGE (d:SuggestionDecision {id: $id})
+            SET d.world_id = $world_id,
+                d.challenge_id = $challenge_id,
+                d.challenge_name = $challenge_name,
+                d.skill_name = $skill_name,
+                d.difficulty = $difficulty,
+                d.confidence = $confidence,
+                d.reasoning = $reasoning,
+                d.npc_name = $npc_name,
+                d.player_dialogue = $player_dialogue,
+                d.verdict_json = $verdict_json,
+                d.decided_at = $decided_at
+            MERGE (w)-[:HAS_SUGGESTION_DECISION]->(d)
+            RETURN d.id as id",
+        )
+        .param("id", decision.id.to_string())
+        .param("world_id", decision.world_id.to_string())
+        .param(
+            "challenge_id",
+            decision
+                .challenge_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param("challenge_name", decision.challenge_name.clone())
+        .param("skill_name", decision.skill_name.clone())
+        .param("difficulty", decision.difficulty.clone())
+        .param("confidence", decision.confidence.clone())
+        .param("reasoning", decision.reasoning.clone())
+        .param("npc_name", decision.npc_name.clone())
+        .param(
+            "player_dialogue",
+            decision.player_dialogue.clone().unwrap_or_default(),
+        )
+        .param("verdict_json", verdict_json)
+        .param("decided_at", decision.decided_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Saved suggestion decision {}", decision.id);
+        Ok(())
+    }
+
+    async fn list_suggestion_decisions(
+        &self,
+        world_id: WorldId,
+        limit: usize,
+    ) -> Result<Vec<SuggestionDecision>, RepoError> {
+        let q = query(
+            "MATCH (w:World {id: $world_id})-[:HAS_SUGGESTION_DECISION]->(d:SuggestionDecision)
+            RETURN d
+            ORDER BY d.decided_at DESC
+            LIMIT $limit",
+        )
+        .param("world_id", world_id.to_string())
+        .param("limit", limit as i64);
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let mut decisions = Vec::new();
+
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            decisions.push(self.row_to_suggestion_decision(row)?);
+        }
+
+        Ok(decisions)
+    }
+
+    async fn save_resolution(&self, resolution: &ChallengeResolution) -> Result<(), RepoError> {
+        let outcome_json = serde_json::to_string(&resolution.outcome)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let triggers_json = serde_json::to_string(&resolution.triggers)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+
+        let q = query(
+            "MATCH (w:World {id: $world_id})
+            MERGE (r:ChallengeResolution {id: $id})
+            SET r.world_id = $world_id,
+                r.challenge_id = $challenge_id,
+                r.challenge_name = $challenge_name,
+                r.skill_name = $skill_name,
+                r.difficulty = $difficulty,
+                r.pc_id = $pc_id,
+                r.character_name = $character_name,
+                r.roll = $roll,
+                r.modifier = $modifier,
+                r.total = $total,
+                r.roll_breakdown = $roll_breakdown,
+                r.outcome_json = $outcome_json,
+                r.outcome_description = $outcome_description,
+                r.edited = $edited,
+                r.triggers_json = $triggers_json,
+                r.resolved_at = $resolved_at
+            MERGE (w)-[:HAS_CHALLENGE_RESOLUTION]->(r)
+            RETURN r.id as id",
+        )
+        .param("id", resolution.id.to_string())
+        .param("world_id", resolution.world_id.to_string())
+        .param("challenge_id", resolution.challenge_id.to_string())
+        .param("challenge_name", resolution.challenge_name.clone())
+        .param(
+            "skill_name",
+            resolution.skill_name.clone().unwrap_or_default(),
+        )
+        .param("difficulty", resolution.difficulty.clone())
+        .param("pc_id", resolution.pc_id.to_string())
+        .param("character_name", resolution.character_name.clone())
+        .param("roll", resolution.roll as i64)
+        .param("modifier", resolution.modifier as i64)
+        .param("total", resolution.total as i64)
+        .param(
+            "roll_breakdown",
+            resolution.roll_breakdown.clone().unwrap_or_default(),
+        )
+        .param("outcome_json", outcome_json)
+        .param(
+            "outcome_description",
+            resolution.outcome_description.clone(),
+        )
+        .param("edited", resolution.edited)
+        .param("triggers_json", triggers_json)
+        .param("resolved_at", resolution.resolved_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Saved challenge resolution {}", resolution.id);
+        Ok(())
+    }
+
+    async fn list_resolutions(
+        &self,
+        world_id: WorldId,
+        pc_id: Option<PlayerCharacterId>,
+        limit: usize,
+    ) -> Result<Vec<ChallengeResolution>, RepoError> {
+        let q = query(
+            "MATCH (w:World {id: $world_id})-[:HAS_CHALLENGE_RESOLUTION]->(r:ChallengeResolution)
+            WHERE $pc_id = '' OR r.pc_id = $pc_id
+            RETURN r
+            ORDER BY r.resolved_at DESC
+            LIMIT $limit",
+        )
+        .param("world_id", world_id.to_string())
+        .param("pc_id", pc_id.map(|id| id.to_string()).unwrap_or_default())
+        .param("limit", i64::try_from(limit).unwrap_or(i64::MAX));
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let mut resolutions = Vec::new();
+
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            resolutions.push(self.row_to_resolution(row)?);
+        }
+
+        Ok(resolutions)
+    }
 }
@@ -11,11 +11,12 @@
 //! Archetype history and stats remain as JSON (acceptable per ADR - complex nested non-relational)
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Row};
+use neo4rs::{query, Row};
 use uuid::Uuid;
 use wrldbldr_domain::*;
 
 use super::helpers::{parse_typed_id, parse_typed_id_from_row, row_to_item, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{
     ActantialViewRecord, CharacterRepo, NpcRegionRelationType, NpcRegionRelationship,
     NpcWithRegionInfo, RepoError, WantDetails, WantTargetRef,
@@ -154,11 +155,11 @@ impl From<ArchetypeChangeStored> for ArchetypeChange {
 
 /// Repository for Character operations.
 pub struct Neo4jCharacterRepo {
-    graph: Graph,
+    graph: ResilientGraph,
 }
 
 impl Neo4jCharacterRepo {
-    pub fn new(graph: Graph) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 
@@ -238,6 +239,9 @@ impl Neo4jCharacterRepo {
             stats,
             is_alive: node.get_bool_or("is_alive", true),
             is_active: node.get_bool_or("is_active", true),
+            birth_date: node
+                .get_optional_string("birth_date")
+                .and_then(|s| s.parse().ok()),
             default_disposition,
             default_mood,
             expression_config,
@@ -301,6 +305,7 @@ impl CharacterRepo for Neo4jCharacterRepo {
                 c.stats = $stats,
                 c.is_alive = $is_alive,
                 c.is_active = $is_active,
+                c.birth_date = $birth_date,
                 c.default_disposition = $default_disposition,
                 c.default_mood = $default_mood,
                 c.expression_config = $expression_config
@@ -328,6 +333,13 @@ impl CharacterRepo for Neo4jCharacterRepo {
         .param("stats", stats_json)
         .param("is_alive", character.is_alive)
         .param("is_active", character.is_active)
+        .param(
+            "birth_date",
+            character
+                .birth_date
+                .map(|d| d.to_string())
+                .unwrap_or_default(),
+        )
         .param(
             "default_disposition",
             character.default_disposition.to_string(),
@@ -361,12 +373,14 @@ impl CharacterRepo for Neo4jCharacterRepo {
     }
 
     async fn delete(&self, id: CharacterId) -> Result<(), RepoError> {
-        // Delete the character and all connected Want nodes in a single atomic query
+        // Delete the character, all connected Want nodes and any companion
+        // record binding them in a single atomic query
         // Using OPTIONAL MATCH ensures we don't fail if there are no wants
         let q = query(
             "MATCH (c:Character {id: $id})
             OPTIONAL MATCH (c)-[:HAS_WANT]->(w:Want)
-            DETACH DELETE w, c",
+            OPTIONAL MATCH (k:Companion {character_id: $id})
+            DETACH DELETE w, k, c",
         )
         .param("id", id.to_string());
 
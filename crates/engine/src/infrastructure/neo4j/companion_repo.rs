@@ -0,0 +1,158 @@
+//! Neo4j companion repository implementation.
+//!
+//! Companion records hang off the PC they travel with:
+//! - `(PlayerCharacter)-[:HAS_COMPANION]->(Companion {character_id, loyalty, wage_per_day, ...})`
+//!
+//! The NPC is kept as an id rather than an edge; where the NPC is comes from
+//! their own `STAGED_IN` edge, which movement updates.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use wrldbldr_domain::{CharacterId, Companion, CompanionId, PlayerCharacterId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, CompanionRepo, RepoError};
+
+pub struct Neo4jCompanionRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jCompanionRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_companion(&self, row: Row) -> Result<Companion, RepoError> {
+        let node: neo4rs::Node = row
+            .get("k")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: CompanionId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let pc_id =
+            parse_typed_id(&node, "pc_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let character_id = parse_typed_id(&node, "character_id")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        Ok(Companion {
+            id,
+            world_id,
+            pc_id,
+            character_id,
+            loyalty: u8::try_from(node.get_i64_or("loyalty", 0)).unwrap_or(0),
+            wage_per_day: u32::try_from(node.get_i64_or("wage_per_day", 0)).unwrap_or(0),
+            follows_pc: node.get_bool_or("follows_pc", true),
+            wages_paid_through: node.get_datetime_or("wages_paid_through", fallback),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<Companion>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut companions = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            companions.push(self.row_to_companion(row)?);
+        }
+
+        Ok(companions)
+    }
+}
+
+#[async_trait]
+impl CompanionRepo for Neo4jCompanionRepo {
+    async fn get(&self, id: CompanionId) -> Result<Option<Companion>, RepoError> {
+        let q = query("MATCH (k:Companion {id: $id}) RETURN k").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save(&self, companion: &Companion) -> Result<(), RepoError> {
+        let q = query(
+            "MERGE (k:Companion {id: $id})
+            SET k.world_id = $world_id,
+                k.pc_id = $pc_id,
+                k.character_id = $character_id,
+                k.loyalty = $loyalty,
+                k.wage_per_day = $wage_per_day,
+                k.follows_pc = $follows_pc,
+                k.wages_paid_through = $wages_paid_through,
+                k.created_at = $created_at,
+                k.updated_at = $updated_at
+            WITH k
+            MATCH (pc:PlayerCharacter {id: $pc_id})
+            MERGE (pc)-[:HAS_COMPANION]->(k)",
+        )
+        .param("id", companion.id.to_string())
+        .param("world_id", companion.world_id.to_string())
+        .param("pc_id", companion.pc_id.to_string())
+        .param("character_id", companion.character_id.to_string())
+        .param("loyalty", companion.loyalty as i64)
+        .param("wage_per_day", companion.wage_per_day as i64)
+        .param("follows_pc", companion.follows_pc)
+        .param(
+            "wages_paid_through",
+            companion.wages_paid_through.to_rfc3339(),
+        )
+        .param("created_at", companion.created_at.to_rfc3339())
+        .param("updated_at", companion.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: CompanionId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (k:Companion {id: $id})
+            DETACH DELETE k",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted companion: {}", id);
+        Ok(())
+    }
+
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<Companion>, RepoError> {
+        let q = query(
+            "MATCH (:PlayerCharacter {id: $pc_id})-[:HAS_COMPANION]->(k:Companion)
+            RETURN k
+            ORDER BY k.created_at",
+        )
+        .param("pc_id", pc_id.to_string());
+
+        self.collect(q).await
+    }
+
+    async fn get_for_character(
+        &self,
+        character_id: CharacterId,
+    ) -> Result<Option<Companion>, RepoError> {
+        let q = query("MATCH (k:Companion {character_id: $character_id}) RETURN k LIMIT 1")
+            .param("character_id", character_id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+}
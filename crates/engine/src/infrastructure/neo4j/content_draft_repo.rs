@@ -0,0 +1,171 @@
+//! Neo4j content draft repository implementation.
+//!
+//! A draft's revisions are stored as JSON; they are only ever read and
+//! written whole:
+//! - `(World)-[:HAS_CONTENT_DRAFT]->(ContentDraft {entity_type, entity_id, field, revisions})`
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use uuid::Uuid;
+use wrldbldr_domain::{ContentDraft, ContentDraftId, DraftRevision, EntityType, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, ContentDraftRepo, RepoError};
+
+pub struct Neo4jContentDraftRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jContentDraftRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_draft(&self, row: Row) -> Result<ContentDraft, RepoError> {
+        let node: neo4rs::Node = row
+            .get("d")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: ContentDraftId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let entity_type: EntityType = node
+            .get_string_or("entity_type", "unknown")
+            .parse()
+            .unwrap_or(EntityType::Unknown);
+        let entity_id = node
+            .get_uuid("entity_id")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let field = node
+            .get_string_or("field", "")
+            .parse()
+            .map_err(|e: wrldbldr_domain::DomainError| RepoError::Database(e.to_string()))?;
+        let revisions_json = node.get_string_or("revisions", "[]");
+        let revisions: Vec<DraftRevision> = serde_json::from_str(&revisions_json)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+
+        Ok(ContentDraft {
+            id,
+            world_id,
+            entity_type,
+            entity_id,
+            field,
+            revisions,
+            published_revision: node.get_positive_i64("published_revision"),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+}
+
+#[async_trait]
+impl ContentDraftRepo for Neo4jContentDraftRepo {
+    async fn get(&self, id: ContentDraftId) -> Result<Option<ContentDraft>, RepoError> {
+        let q = query("MATCH (d:ContentDraft {id: $id}) RETURN d").param("id", id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        if let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            Ok(Some(self.row_to_draft(row)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn save(&self, draft: &ContentDraft) -> Result<(), RepoError> {
+        let revisions_json = serde_json::to_string(&draft.revisions)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let q = query(
+            "MERGE (d:ContentDraft {id: $id})
+            SET d.world_id = $world_id,
+                d.entity_type = $entity_type,
+                d.entity_id = $entity_id,
+                d.field = $field,
+                d.revisions = $revisions,
+                d.published_revision = $published_revision,
+                d.created_at = $created_at,
+                d.updated_at = $updated_at
+            WITH d
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_CONTENT_DRAFT]->(d)",
+        )
+        .param("id", draft.id.to_string())
+        .param("world_id", draft.world_id.to_string())
+        .param("entity_type", draft.entity_type.as_str())
+        .param("entity_id", draft.entity_id.to_string())
+        .param("field", draft.field.as_str())
+        .param("revisions", revisions_json)
+        .param(
+            "published_revision",
+            draft.published_revision.map(|r| r as i64).unwrap_or(-1),
+        )
+        .param("created_at", draft.created_at.to_rfc3339())
+        .param("updated_at", draft.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: ContentDraftId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (d:ContentDraft {id: $id})
+            DETACH DELETE d",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted content draft: {}", id);
+        Ok(())
+    }
+
+    async fn list_for_entity(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<ContentDraft>, RepoError> {
+        let q = query(
+            "MATCH (d:ContentDraft {entity_type: $entity_type, entity_id: $entity_id})
+            RETURN d
+            ORDER BY d.field",
+        )
+        .param("entity_type", entity_type.as_str())
+        .param("entity_id", entity_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut drafts = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            drafts.push(self.row_to_draft(row)?);
+        }
+
+        Ok(drafts)
+    }
+}
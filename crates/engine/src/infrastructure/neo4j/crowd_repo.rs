@@ -0,0 +1,155 @@
+//! Neo4j crowd repository implementation.
+//!
+//! Crowds hang off the region they're staged in:
+//! - `(Region)-[:HAS_CROWD]->(Crowd {name, approximate_count, disposition, is_present})`
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use wrldbldr_domain::{Crowd, CrowdId, DispositionLevel, RegionId, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, CrowdRepo, RepoError};
+
+pub struct Neo4jCrowdRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jCrowdRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_crowd(&self, row: Row) -> Result<Crowd, RepoError> {
+        let node: neo4rs::Node = row
+            .get("c")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: CrowdId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let region_id: RegionId =
+            parse_typed_id(&node, "region_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let disposition: DispositionLevel = node
+            .get_string_or("disposition", "neutral")
+            .parse()
+            .map_err(|e: String| RepoError::Database(e))?;
+
+        Ok(Crowd {
+            id,
+            world_id,
+            region_id,
+            name: node.get_string_or("name", ""),
+            description: node.get_string_or("description", ""),
+            approximate_count: node.get_positive_i64("approximate_count").unwrap_or(2),
+            disposition,
+            is_present: node.get_bool_or("is_present", false),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<Crowd>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut crowds = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            crowds.push(self.row_to_crowd(row)?);
+        }
+
+        Ok(crowds)
+    }
+}
+
+#[async_trait]
+impl CrowdRepo for Neo4jCrowdRepo {
+    async fn get(&self, id: CrowdId) -> Result<Option<Crowd>, RepoError> {
+        let q = query("MATCH (c:Crowd {id: $id}) RETURN c").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save(&self, crowd: &Crowd) -> Result<(), RepoError> {
+        let q = query(
+            "MERGE (c:Crowd {id: $id})
+            SET c.world_id = $world_id,
+                c.region_id = $region_id,
+                c.name = $name,
+                c.description = $description,
+                c.approximate_count = $approximate_count,
+                c.disposition = $disposition,
+                c.is_present = $is_present,
+                c.created_at = $created_at,
+                c.updated_at = $updated_at
+            WITH c
+            MATCH (r:Region {id: $region_id})
+            MERGE (r)-[:HAS_CROWD]->(c)",
+        )
+        .param("id", crowd.id.to_string())
+        .param("world_id", crowd.world_id.to_string())
+        .param("region_id", crowd.region_id.to_string())
+        .param("name", crowd.name.clone())
+        .param("description", crowd.description.clone())
+        .param("approximate_count", i64::from(crowd.approximate_count))
+        .param("disposition", crowd.disposition.to_string())
+        .param("is_present", crowd.is_present)
+        .param("created_at", crowd.created_at.to_rfc3339())
+        .param("updated_at", crowd.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: CrowdId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (c:Crowd {id: $id})
+            DETACH DELETE c",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted crowd: {}", id);
+        Ok(())
+    }
+
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<Crowd>, RepoError> {
+        let q = query(
+            "MATCH (c:Crowd {world_id: $world_id})
+            RETURN c
+            ORDER BY c.name",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.collect(q).await
+    }
+
+    async fn list_in_region(&self, region_id: RegionId) -> Result<Vec<Crowd>, RepoError> {
+        let q = query(
+            "MATCH (:Region {id: $region_id})-[:HAS_CROWD]->(c:Crowd)
+            RETURN c
+            ORDER BY c.name",
+        )
+        .param("region_id", region_id.to_string());
+
+        self.collect(q).await
+    }
+}
@@ -0,0 +1,94 @@
+//! Neo4j custom field value repository implementation.
+//!
+//! Each entity with custom field values gets one `EntityFieldValues` node
+//! holding them as a JSON map, keyed by entity type and id:
+//! - `(World)-[:HAS_FIELD_VALUES]->(EntityFieldValues {entity_type, entity_id, values})`
+
+use async_trait::async_trait;
+use neo4rs::query;
+use uuid::Uuid;
+use wrldbldr_domain::{CustomFieldValues, EntityType, WorldId};
+
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{CustomFieldRepo, RepoError};
+
+pub struct Neo4jCustomFieldRepo {
+    graph: ResilientGraph,
+}
+
+impl Neo4jCustomFieldRepo {
+    pub fn new(graph: ResilientGraph) -> Self {
+        Self { graph }
+    }
+}
+
+#[async_trait]
+impl CustomFieldRepo for Neo4jCustomFieldRepo {
+    async fn get_values(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<CustomFieldValues, RepoError> {
+        let q = query(
+            "MATCH (v:EntityFieldValues {entity_type: $entity_type, entity_id: $entity_id})
+            RETURN v.values AS values",
+        )
+        .param("entity_type", entity_type.as_str())
+        .param("entity_id", entity_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        match result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            Some(row) => {
+                let json: String = row
+                    .get("values")
+                    .map_err(|e| RepoError::Database(e.to_string()))?;
+                serde_json::from_str(&json).map_err(|e| RepoError::Serialization(e.to_string()))
+            }
+            None => Ok(CustomFieldValues::new()),
+        }
+    }
+
+    async fn set_values(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        values: &CustomFieldValues,
+    ) -> Result<(), RepoError> {
+        let values_json =
+            serde_json::to_string(values).map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let q = if values.is_empty() {
+            query(
+                "MATCH (v:EntityFieldValues {entity_type: $entity_type, entity_id: $entity_id})
+                DETACH DELETE v",
+            )
+        } else {
+            query(
+                "MERGE (v:EntityFieldValues {entity_type: $entity_type, entity_id: $entity_id})
+                SET v.world_id = $world_id,
+                    v.values = $values
+                WITH v
+                MATCH (w:World {id: $world_id})
+                MERGE (w)-[:HAS_FIELD_VALUES]->(v)",
+            )
+        }
+        .param("world_id", world_id.to_string())
+        .param("entity_type", entity_type.as_str())
+        .param("entity_id", entity_id.to_string())
+        .param("values", values_json);
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+}
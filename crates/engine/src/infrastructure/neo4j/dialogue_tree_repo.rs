@@ -0,0 +1,170 @@
+//! Neo4j dialogue tree repository implementation.
+//!
+//! Dialogue trees hang off their world:
+//! - `(World)-[:HAS_DIALOGUE_TREE]->(DialogueTree {name, speaker_id, nodes, played_by, ...})`
+//!
+//! Nodes and the PCs who heard the tree are only read and written whole
+//! with it, so they are stored as JSON.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Node, Row};
+use wrldbldr_domain::{
+    DialogueNode, DialogueTree, DialogueTreeId, NarrativeEventId, PlayerCharacterId, WorldId,
+};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, DialogueTreeRepo, RepoError};
+
+pub struct Neo4jDialogueTreeRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jDialogueTreeRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_tree(&self, row: Row) -> Result<DialogueTree, RepoError> {
+        let node: Node = row
+            .get("t")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: DialogueTreeId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let speaker_id =
+            parse_typed_id(&node, "speaker_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let nodes: Vec<DialogueNode> = json_list(&node, "nodes")?;
+        let played_by: Vec<PlayerCharacterId> = json_list(&node, "played_by")?;
+
+        Ok(DialogueTree {
+            id,
+            world_id,
+            name: node.get_string_or("name", ""),
+            speaker_id,
+            narrative_event_id: node
+                .get_optional_string("narrative_event_id")
+                .and_then(|id| uuid::Uuid::parse_str(&id).ok())
+                .map(NarrativeEventId::from_uuid),
+            nodes,
+            active: node.get_bool_or("active", true),
+            repeatable: node.get_bool_or("repeatable", false),
+            played_by,
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<DialogueTree>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut trees = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            trees.push(self.row_to_tree(row)?);
+        }
+
+        Ok(trees)
+    }
+}
+
+fn json_list<T: serde::de::DeserializeOwned>(
+    node: &Node,
+    field: &str,
+) -> Result<Vec<T>, RepoError> {
+    serde_json::from_str(&node.get_string_or(field, "[]"))
+        .map_err(|e| RepoError::Serialization(e.to_string()))
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, RepoError> {
+    serde_json::to_string(value).map_err(|e| RepoError::Serialization(e.to_string()))
+}
+
+#[async_trait]
+impl DialogueTreeRepo for Neo4jDialogueTreeRepo {
+    async fn get(&self, id: DialogueTreeId) -> Result<Option<DialogueTree>, RepoError> {
+        let q = query("MATCH (t:DialogueTree {id: $id}) RETURN t").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save(&self, tree: &DialogueTree) -> Result<(), RepoError> {
+        let q = query(
+            "MERGE (t:DialogueTree {id: $id})
+            SET t.world_id = $world_id,
+                t.name = $name,
+                t.speaker_id = $speaker_id,
+                t.narrative_event_id = $narrative_event_id,
+                t.nodes = $nodes,
+                t.active = $active,
+                t.repeatable = $repeatable,
+                t.played_by = $played_by,
+                t.created_at = $created_at,
+                t.updated_at = $updated_at
+            WITH t
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_DIALOGUE_TREE]->(t)",
+        )
+        .param("id", tree.id.to_string())
+        .param("world_id", tree.world_id.to_string())
+        .param("name", tree.name.clone())
+        .param("speaker_id", tree.speaker_id.to_string())
+        .param(
+            "narrative_event_id",
+            tree.narrative_event_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param("nodes", to_json(&tree.nodes)?)
+        .param("active", tree.active)
+        .param("repeatable", tree.repeatable)
+        .param("played_by", to_json(&tree.played_by)?)
+        .param("created_at", tree.created_at.to_rfc3339())
+        .param("updated_at", tree.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: DialogueTreeId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (t:DialogueTree {id: $id})
+            DETACH DELETE t",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted dialogue tree: {}", id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<DialogueTree>, RepoError> {
+        let q = query(
+            "MATCH (:World {id: $world_id})-[:HAS_DIALOGUE_TREE]->(t:DialogueTree)
+            RETURN t
+            ORDER BY t.name",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.collect(q).await
+    }
+}
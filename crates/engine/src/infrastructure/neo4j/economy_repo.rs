@@ -0,0 +1,163 @@
+//! Neo4j economy repository implementation.
+//!
+//! Market modifiers hang off their world:
+//! - `(World)-[:HAS_MARKET_MODIFIER]->(MarketModifier {location_id, category, scarcity, ...})`
+//!
+//! The location is kept as an id rather than an edge, like name generator
+//! regions; a modifier for a deleted location simply stops matching.
+//! Absent optional fields are stored as empty strings.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use wrldbldr_domain::{LocationId, MarketModifier, MarketModifierId, Scarcity, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, EconomyRepo, RepoError};
+
+pub struct Neo4jEconomyRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jEconomyRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_modifier(&self, row: Row) -> Result<MarketModifier, RepoError> {
+        let node: neo4rs::Node = row
+            .get("m")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: MarketModifierId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let location_id = node
+            .get_optional_string("location_id")
+            .and_then(|id| uuid::Uuid::parse_str(&id).ok())
+            .map(LocationId::from_uuid);
+        let scarcity = node
+            .get_optional_string("scarcity")
+            .map(|s| s.parse::<Scarcity>())
+            .transpose()
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+
+        Ok(MarketModifier {
+            id,
+            world_id,
+            location_id,
+            category: node.get_optional_string("category"),
+            scarcity,
+            price_adjustment: node.get_i64_or("price_adjustment", 0) as i32,
+            flag: node.get_optional_string("flag"),
+            reason: node.get_string_or("reason", ""),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<MarketModifier>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut modifiers = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            modifiers.push(self.row_to_modifier(row)?);
+        }
+
+        Ok(modifiers)
+    }
+}
+
+#[async_trait]
+impl EconomyRepo for Neo4jEconomyRepo {
+    async fn get_modifier(
+        &self,
+        id: MarketModifierId,
+    ) -> Result<Option<MarketModifier>, RepoError> {
+        let q = query("MATCH (m:MarketModifier {id: $id}) RETURN m").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save_modifier(&self, modifier: &MarketModifier) -> Result<(), RepoError> {
+        let q = query(
+            "MERGE (m:MarketModifier {id: $id})
+            SET m.world_id = $world_id,
+                m.location_id = $location_id,
+                m.category = $category,
+                m.scarcity = $scarcity,
+                m.price_adjustment = $price_adjustment,
+                m.flag = $flag,
+                m.reason = $reason,
+                m.created_at = $created_at,
+                m.updated_at = $updated_at
+            WITH m
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_MARKET_MODIFIER]->(m)",
+        )
+        .param("id", modifier.id.to_string())
+        .param("world_id", modifier.world_id.to_string())
+        .param(
+            "location_id",
+            modifier
+                .location_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param("category", modifier.category.clone().unwrap_or_default())
+        .param(
+            "scarcity",
+            modifier.scarcity.map(|s| s.as_str()).unwrap_or_default(),
+        )
+        .param("price_adjustment", modifier.price_adjustment as i64)
+        .param("flag", modifier.flag.clone().unwrap_or_default())
+        .param("reason", modifier.reason.clone())
+        .param("created_at", modifier.created_at.to_rfc3339())
+        .param("updated_at", modifier.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete_modifier(&self, id: MarketModifierId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (m:MarketModifier {id: $id})
+            DETACH DELETE m",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted market modifier: {}", id);
+        Ok(())
+    }
+
+    async fn list_modifiers(&self, world_id: WorldId) -> Result<Vec<MarketModifier>, RepoError> {
+        let q = query(
+            "MATCH (:World {id: $world_id})-[:HAS_MARKET_MODIFIER]->(m:MarketModifier)
+            RETURN m
+            ORDER BY m.created_at",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.collect(q).await
+    }
+}
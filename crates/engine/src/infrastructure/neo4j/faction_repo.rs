@@ -0,0 +1,156 @@
+//! Neo4j faction repository implementation.
+//!
+//! Factions hang off their world:
+//! - `(World)-[:HAS_FACTION]->(Faction {name, goals, members, reputation, rivals, ...})`
+//!
+//! Goals, members and rivals are only read and written whole with the
+//! faction, so they are stored as JSON.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Node, Row};
+use wrldbldr_domain::{Faction, FactionId, FactionMember, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, FactionRepo, RepoError};
+
+pub struct Neo4jFactionRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jFactionRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_faction(&self, row: Row) -> Result<Faction, RepoError> {
+        let node: Node = row
+            .get("f")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: FactionId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let goals: Vec<String> = json_list(&node, "goals")?;
+        let members: Vec<FactionMember> = json_list(&node, "members")?;
+        let rivals: Vec<FactionId> = json_list(&node, "rivals")?;
+
+        Ok(Faction {
+            id,
+            world_id,
+            name: node.get_string_or("name", ""),
+            description: node.get_string_or("description", ""),
+            goals,
+            members,
+            reputation: node.get_i64_or("reputation", 0) as i32,
+            rivals,
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<Faction>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut factions = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            factions.push(self.row_to_faction(row)?);
+        }
+
+        Ok(factions)
+    }
+}
+
+fn json_list<T: serde::de::DeserializeOwned>(
+    node: &Node,
+    field: &str,
+) -> Result<Vec<T>, RepoError> {
+    serde_json::from_str(&node.get_string_or(field, "[]"))
+        .map_err(|e| RepoError::Serialization(e.to_string()))
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, RepoError> {
+    serde_json::to_string(value).map_err(|e| RepoError::Serialization(e.to_string()))
+}
+
+#[async_trait]
+impl FactionRepo for Neo4jFactionRepo {
+    async fn get(&self, id: FactionId) -> Result<Option<Faction>, RepoError> {
+        let q = query("MATCH (f:Faction {id: $id}) RETURN f").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save(&self, faction: &Faction) -> Result<(), RepoError> {
+        let q = query(
+            "MERGE (f:Faction {id: $id})
+            SET f.world_id = $world_id,
+                f.name = $name,
+                f.description = $description,
+                f.goals = $goals,
+                f.members = $members,
+                f.reputation = $reputation,
+                f.rivals = $rivals,
+                f.created_at = $created_at,
+                f.updated_at = $updated_at
+            WITH f
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_FACTION]->(f)",
+        )
+        .param("id", faction.id.to_string())
+        .param("world_id", faction.world_id.to_string())
+        .param("name", faction.name.clone())
+        .param("description", faction.description.clone())
+        .param("goals", to_json(&faction.goals)?)
+        .param("members", to_json(&faction.members)?)
+        .param("reputation", i64::from(faction.reputation))
+        .param("rivals", to_json(&faction.rivals)?)
+        .param("created_at", faction.created_at.to_rfc3339())
+        .param("updated_at", faction.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: FactionId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (f:Faction {id: $id})
+            DETACH DELETE f",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted faction: {}", id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Faction>, RepoError> {
+        let q = query(
+            "MATCH (:World {id: $world_id})-[:HAS_FACTION]->(f:Faction)
+            RETURN f
+            ORDER BY f.name",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.collect(q).await
+    }
+}
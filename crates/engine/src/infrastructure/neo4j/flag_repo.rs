@@ -5,19 +5,19 @@
 //! - PC flags: (PlayerCharacter)-[:HAS_FLAG {name: "flag_name"}]->()
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph};
-use std::sync::Arc;
+use neo4rs::query;
 
 use wrldbldr_domain::{PlayerCharacterId, WorldId};
 
 use crate::infrastructure::ports::{FlagRepo, RepoError};
+use super::resilient_graph::ResilientGraph;
 
 pub struct Neo4jFlagRepo {
-    graph: Arc<Graph>,
+    graph: ResilientGraph,
 }
 
 impl Neo4jFlagRepo {
-    pub fn new(graph: Arc<Graph>) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 }
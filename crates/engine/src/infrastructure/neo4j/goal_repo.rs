@@ -3,18 +3,19 @@
 //! Handles Goal persistence and usage counts.
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph};
+use neo4rs::query;
 use wrldbldr_domain::{Goal, GoalId, WorldId};
 
 use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{GoalDetails, GoalRepo, RepoError};
 
 pub struct Neo4jGoalRepo {
-    graph: Graph,
+    graph: ResilientGraph,
 }
 
 impl Neo4jGoalRepo {
-    pub fn new(graph: Graph) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 }
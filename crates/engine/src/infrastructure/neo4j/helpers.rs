@@ -5,10 +5,11 @@
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use neo4rs::{Graph, Node, Query, Row};
+use neo4rs::{Node, Query, Row};
 use uuid::Uuid;
 use wrldbldr_domain::common::{parse_datetime_or, StringExt};
 
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::RepoError;
 
 // =============================================================================
@@ -29,8 +30,7 @@ use crate::infrastructure::ports::RepoError;
 /// graph.run_or_err(query).await?;
 /// ```
 ///
-/// Note: `execute` is not wrapped because neo4rs 0.8 doesn't export
-/// `DetachedRowStream` publicly. Use the standard pattern for queries:
+/// Note: `execute` is not wrapped; use the standard pattern for queries:
 /// ```ignore
 /// let mut result = graph.execute(q).await.map_err(|e| RepoError::Database(e.to_string()))?;
 /// ```
@@ -43,7 +43,7 @@ pub trait GraphExt {
 }
 
 #[async_trait::async_trait]
-impl GraphExt for Graph {
+impl GraphExt for ResilientGraph {
     async fn run_or_err(&self, query: Query) -> Result<(), RepoError> {
         self.run(query)
             .await
@@ -289,6 +289,7 @@ pub fn row_to_item(row: Row) -> Result<Item, RepoError> {
     } else {
         Some(container_limit_raw as u32)
     };
+    let base_price = u32::try_from(node.get_i64_or("base_price", -1)).ok();
 
     Ok(Item {
         id,
@@ -300,5 +301,6 @@ pub fn row_to_item(row: Row) -> Result<Item, RepoError> {
         properties,
         can_contain_items,
         container_limit,
+        base_price,
     })
 }
@@ -0,0 +1,191 @@
+//! Neo4j injury repository implementation.
+//!
+//! Injuries hang off the PC carrying them:
+//! - `(PlayerCharacter)-[:HAS_INJURY]->(Injury {severity, capability, recovery_hours, ...})`
+//!
+//! Healed injuries are kept, with `healed_at` set, as the PC's medical
+//! history; unhealed ones store an empty `healed_at`.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use wrldbldr_domain::{Injury, InjuryId, PlayerCharacterId, WorldId};
+
+use super::helpers::{parse_optional_typed_id, parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, InjuryRepo, RepoError};
+
+pub struct Neo4jInjuryRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jInjuryRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_injury(&self, row: Row) -> Result<Injury, RepoError> {
+        let node: neo4rs::Node = row
+            .get("i")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: InjuryId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let pc_id =
+            parse_typed_id(&node, "pc_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let treatment_challenge_id = parse_optional_typed_id(&node, "treatment_challenge_id")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        Ok(Injury {
+            id,
+            world_id,
+            pc_id,
+            name: node.get_string_or("name", ""),
+            severity: node
+                .get_string_or("severity", "minor")
+                .parse()
+                .unwrap_or_default(),
+            capability: node
+                .get_string_or("capability", "stamina")
+                .parse()
+                .unwrap_or_default(),
+            recovery_hours: u32::try_from(node.get_i64_or("recovery_hours", 0)).unwrap_or(0),
+            bonus_hours: u32::try_from(node.get_i64_or("bonus_hours", 0)).unwrap_or(0),
+            treatment_challenge_id,
+            treated: node.get_bool_or("treated", false),
+            injured_at: node.get_datetime_or("injured_at", fallback),
+            healed_at: node
+                .get_optional_string("healed_at")
+                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                .map(|dt| dt.with_timezone(&Utc)),
+            notes: node.get_string_or("notes", ""),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<Injury>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut injuries = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            injuries.push(self.row_to_injury(row)?);
+        }
+
+        Ok(injuries)
+    }
+}
+
+#[async_trait]
+impl InjuryRepo for Neo4jInjuryRepo {
+    async fn get(&self, id: InjuryId) -> Result<Option<Injury>, RepoError> {
+        let q = query("MATCH (i:Injury {id: $id}) RETURN i").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save(&self, injury: &Injury) -> Result<(), RepoError> {
+        let q = query(
+            "MERGE (i:Injury {id: $id})
+            SET i.world_id = $world_id,
+                i.pc_id = $pc_id,
+                i.name = $name,
+                i.severity = $severity,
+                i.capability = $capability,
+                i.recovery_hours = $recovery_hours,
+                i.bonus_hours = $bonus_hours,
+                i.treatment_challenge_id = $treatment_challenge_id,
+                i.treated = $treated,
+                i.injured_at = $injured_at,
+                i.healed_at = $healed_at,
+                i.notes = $notes,
+                i.created_at = $created_at,
+                i.updated_at = $updated_at
+            WITH i
+            MATCH (pc:PlayerCharacter {id: $pc_id})
+            MERGE (pc)-[:HAS_INJURY]->(i)",
+        )
+        .param("id", injury.id.to_string())
+        .param("world_id", injury.world_id.to_string())
+        .param("pc_id", injury.pc_id.to_string())
+        .param("name", injury.name.clone())
+        .param("severity", injury.severity.as_str())
+        .param("capability", injury.capability.as_str())
+        .param("recovery_hours", injury.recovery_hours as i64)
+        .param("bonus_hours", injury.bonus_hours as i64)
+        .param(
+            "treatment_challenge_id",
+            injury
+                .treatment_challenge_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param("treated", injury.treated)
+        .param("injured_at", injury.injured_at.to_rfc3339())
+        .param(
+            "healed_at",
+            injury.healed_at.map(|t| t.to_rfc3339()).unwrap_or_default(),
+        )
+        .param("notes", injury.notes.clone())
+        .param("created_at", injury.created_at.to_rfc3339())
+        .param("updated_at", injury.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: InjuryId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (i:Injury {id: $id})
+            DETACH DELETE i",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted injury: {}", id);
+        Ok(())
+    }
+
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<Injury>, RepoError> {
+        let q = query(
+            "MATCH (:PlayerCharacter {id: $pc_id})-[:HAS_INJURY]->(i:Injury)
+            RETURN i
+            ORDER BY i.created_at",
+        )
+        .param("pc_id", pc_id.to_string());
+
+        self.collect(q).await
+    }
+
+    async fn list_active_in_world(&self, world_id: WorldId) -> Result<Vec<Injury>, RepoError> {
+        let q = query(
+            "MATCH (i:Injury {world_id: $world_id})
+            WHERE coalesce(i.healed_at, '') = ''
+            RETURN i
+            ORDER BY i.created_at",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.collect(q).await
+    }
+}
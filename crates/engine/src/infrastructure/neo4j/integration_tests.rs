@@ -76,7 +76,7 @@ async fn narrative_triggers_fallback_is_bounded_to_500() {
 
     let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
     let clock: Arc<dyn crate::infrastructure::ports::ClockPort> = Arc::new(FixedClock(now));
-    let repo = super::Neo4jNarrativeRepo::new(graph.clone(), clock);
+    let repo = super::Neo4jNarrativeRepo::new(graph.clone().into(), clock);
 
     let trigger = NarrativeTrigger {
         trigger_type: NarrativeTriggerType::PlayerEntersLocation {
@@ -149,7 +149,7 @@ async fn save_pending_staging_creates_includes_npc_edges_for_all_npcs() {
 
     let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
     let clock: Arc<dyn crate::infrastructure::ports::ClockPort> = Arc::new(FixedClock(now));
-    let repo = super::Neo4jStagingRepo::new(graph.clone(), clock);
+    let repo = super::Neo4jStagingRepo::new(graph.clone().into(), clock);
 
     let region_id = RegionId::new();
     let location_id = LocationId::new();
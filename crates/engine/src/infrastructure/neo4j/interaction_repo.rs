@@ -2,24 +2,29 @@
 //!
 //! Interactions are stored as nodes and linked to scenes:
 //! - `(InteractionTemplate)-[:BELONGS_TO_SCENE]->(Scene)`
+//!
+//! Per-PC usage is an edge carrying the use count:
+//! - `(PlayerCharacter)-[:USED_INTERACTION {uses, last_used_at}]->(InteractionTemplate)`
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Row};
+use chrono::DateTime;
+use neo4rs::{query, Row};
 use wrldbldr_domain::{
     InteractionCondition, InteractionId, InteractionTarget, InteractionTemplate, InteractionType,
-    SceneId,
+    InteractionUsage, PlayerCharacterId, SceneId, TimeOfDay,
 };
 
-use super::helpers::{parse_typed_id, NodeExt};
+use super::helpers::{parse_typed_id, NodeExt, RowExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{InteractionRepo, RepoError};
 
 /// Repository for Interaction operations.
 pub struct Neo4jInteractionRepo {
-    graph: Graph,
+    graph: ResilientGraph,
 }
 
 impl Neo4jInteractionRepo {
-    pub fn new(graph: Graph) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 
@@ -59,6 +64,17 @@ impl Neo4jInteractionRepo {
             .and_then(|value| serde_json::from_str::<Vec<InteractionCondition>>(&value).ok())
             .unwrap_or_default();
 
+        let cooldown_minutes = node
+            .get_optional_string("cooldown_minutes")
+            .and_then(|value| value.parse::<u32>().ok());
+        let max_uses = node
+            .get_optional_string("max_uses")
+            .and_then(|value| value.parse::<u32>().ok());
+        let available_times = node
+            .get_optional_string("available_times")
+            .and_then(|value| serde_json::from_str::<Vec<TimeOfDay>>(&value).ok())
+            .unwrap_or_default();
+
         Ok(InteractionTemplate {
             id,
             scene_id,
@@ -70,6 +86,9 @@ impl Neo4jInteractionRepo {
             conditions,
             is_available,
             order: order_num as u32,
+            cooldown_minutes,
+            max_uses,
+            available_times,
         })
     }
 }
@@ -106,6 +125,8 @@ impl InteractionRepo for Neo4jInteractionRepo {
             .map_err(|e| RepoError::Serialization(e.to_string()))?;
         let conditions_json = serde_json::to_string(&interaction.conditions)
             .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let available_times_json = serde_json::to_string(&interaction.available_times)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
 
         let q = query(
             "MATCH (s:Scene {id: $scene_id})
@@ -118,7 +139,10 @@ impl InteractionRepo for Neo4jInteractionRepo {
                 i.allowed_tools = $allowed_tools,
                 i.conditions = $conditions,
                 i.is_available = $is_available,
-                i.order_num = $order_num
+                i.order_num = $order_num,
+                i.cooldown_minutes = $cooldown_minutes,
+                i.max_uses = $max_uses,
+                i.available_times = $available_times
             MERGE (i)-[:BELONGS_TO_SCENE]->(s)
             RETURN i.id as id",
         )
@@ -131,7 +155,22 @@ impl InteractionRepo for Neo4jInteractionRepo {
         .param("allowed_tools", allowed_tools_json)
         .param("conditions", conditions_json)
         .param("is_available", interaction.is_available)
-        .param("order_num", interaction.order as i64);
+        .param("order_num", interaction.order as i64)
+        .param(
+            "cooldown_minutes",
+            interaction
+                .cooldown_minutes
+                .map(|m| m.to_string())
+                .unwrap_or_default(),
+        )
+        .param(
+            "max_uses",
+            interaction
+                .max_uses
+                .map(|m| m.to_string())
+                .unwrap_or_default(),
+        )
+        .param("available_times", available_times_json);
 
         self.graph
             .run(q)
@@ -178,4 +217,59 @@ impl InteractionRepo for Neo4jInteractionRepo {
 
         Ok(interactions)
     }
+
+    async fn get_usage(
+        &self,
+        interaction_id: InteractionId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Option<InteractionUsage>, RepoError> {
+        let q = query(
+            "MATCH (pc:PlayerCharacter {id: $pc_id})-[u:USED_INTERACTION]->(i:InteractionTemplate {id: $id})
+            RETURN u.uses as uses, u.last_used_at as last_used_at",
+        )
+        .param("pc_id", pc_id.to_string())
+        .param("id", interaction_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        if let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            let uses: i64 = row.get("uses").unwrap_or(0);
+            Ok(Some(InteractionUsage {
+                interaction_id,
+                pc_id,
+                uses: uses.max(0) as u32,
+                // A broken timestamp should never hold a PC back
+                last_used_at: row.get_datetime_or("last_used_at", DateTime::UNIX_EPOCH),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn save_usage(&self, usage: &InteractionUsage) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (pc:PlayerCharacter {id: $pc_id}), (i:InteractionTemplate {id: $id})
+            MERGE (pc)-[u:USED_INTERACTION]->(i)
+            SET u.uses = $uses,
+                u.last_used_at = $last_used_at",
+        )
+        .param("pc_id", usage.pc_id.to_string())
+        .param("id", usage.interaction_id.to_string())
+        .param("uses", i64::from(usage.uses))
+        .param("last_used_at", usage.last_used_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        Ok(())
+    }
 }
@@ -3,21 +3,22 @@
 //! Handles item persistence in the game world.
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph};
+use neo4rs::query;
 use wrldbldr_domain::*;
 
 use super::helpers::row_to_item;
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{ItemRepo, RepoError};
 
 #[allow(unused_imports)]
 use wrldbldr_domain::PlayerCharacterId;
 
 pub struct Neo4jItemRepo {
-    graph: Graph,
+    graph: ResilientGraph,
 }
 
 impl Neo4jItemRepo {
-    pub fn new(graph: Graph) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 }
@@ -57,7 +58,8 @@ impl ItemRepo for Neo4jItemRepo {
                 i.is_unique = $is_unique,
                 i.properties = $properties,
                 i.can_contain_items = $can_contain_items,
-                i.container_limit = $container_limit
+                i.container_limit = $container_limit,
+                i.base_price = $base_price
             ON MATCH SET
                 i.name = $name,
                 i.description = $description,
@@ -65,7 +67,8 @@ impl ItemRepo for Neo4jItemRepo {
                 i.is_unique = $is_unique,
                 i.properties = $properties,
                 i.can_contain_items = $can_contain_items,
-                i.container_limit = $container_limit
+                i.container_limit = $container_limit,
+                i.base_price = $base_price
             WITH i
             MATCH (w:World {id: $world_id})
             MERGE (w)-[:CONTAINS_ITEM]->(i)",
@@ -81,6 +84,10 @@ impl ItemRepo for Neo4jItemRepo {
         .param(
             "container_limit",
             item.container_limit.map(|l| l as i64).unwrap_or(-1),
+        )
+        .param(
+            "base_price",
+            item.base_price.map(|p| p as i64).unwrap_or(-1),
         );
 
         self.graph
@@ -0,0 +1,234 @@
+//! Neo4j letter repository implementation.
+//!
+//! Letters hang off their world, and replies point at the letter they
+//! answer:
+//! - `(World)-[:HAS_LETTER]->(Letter {sender_kind, sender_id, ...})`
+//! - `(Letter)-[:REPLY_TO]->(Letter)`
+//!
+//! Writer and addressee can each be a PC or an NPC, so they are stored as a
+//! kind ("pc" or "npc") and an id rather than as edges. Letters in transit
+//! store an empty `delivered_at`.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Node, Row};
+use wrldbldr_domain::{Correspondent, Letter, LetterId, PlayerCharacterId, WorldId};
+
+use super::helpers::{parse_optional_typed_id, parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, LetterRepo, RepoError};
+
+pub struct Neo4jLetterRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jLetterRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_letter(&self, row: Row) -> Result<Letter, RepoError> {
+        let node: Node = row
+            .get("l")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: LetterId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let in_reply_to = parse_optional_typed_id(&node, "in_reply_to")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let sent_at = node.get_datetime_or("sent_at", fallback);
+
+        Ok(Letter {
+            id,
+            world_id,
+            sender: correspondent(&node, "sender")?,
+            recipient: correspondent(&node, "recipient")?,
+            subject: node.get_string_or("subject", ""),
+            body: node.get_string_or("body", ""),
+            in_reply_to,
+            sent_at,
+            deliver_at: node.get_datetime_or("deliver_at", sent_at),
+            delivered_at: optional_datetime(&node, "delivered_at"),
+            read_at: optional_datetime(&node, "read_at"),
+            created_at: node.get_datetime_or("created_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<Letter>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut letters = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            letters.push(self.row_to_letter(row)?);
+        }
+
+        Ok(letters)
+    }
+}
+
+/// The writer or addressee stored under `prefix`
+fn correspondent(node: &Node, prefix: &str) -> Result<Correspondent, RepoError> {
+    let kind = node.get_string_or(&format!("{}_kind", prefix), "");
+    let id = node
+        .get_uuid(&format!("{}_id", prefix))
+        .map_err(|e| RepoError::Database(e.to_string()))?;
+    match kind.as_str() {
+        "pc" => Ok(Correspondent::Pc(id.into())),
+        "npc" => Ok(Correspondent::Npc(id.into())),
+        other => Err(RepoError::Database(format!(
+            "Unknown letter {} kind: {}",
+            prefix, other
+        ))),
+    }
+}
+
+fn correspondent_params(correspondent: Correspondent) -> (&'static str, String) {
+    match correspondent {
+        Correspondent::Pc(id) => ("pc", id.to_string()),
+        Correspondent::Npc(id) => ("npc", id.to_string()),
+    }
+}
+
+fn optional_datetime(node: &Node, field: &str) -> Option<DateTime<Utc>> {
+    node.get_optional_string(field)
+        .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+#[async_trait]
+impl LetterRepo for Neo4jLetterRepo {
+    async fn get(&self, id: LetterId) -> Result<Option<Letter>, RepoError> {
+        let q = query("MATCH (l:Letter {id: $id}) RETURN l").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save(&self, letter: &Letter) -> Result<(), RepoError> {
+        let (sender_kind, sender_id) = correspondent_params(letter.sender);
+        let (recipient_kind, recipient_id) = correspondent_params(letter.recipient);
+
+        let q = query(
+            "MERGE (l:Letter {id: $id})
+            SET l.world_id = $world_id,
+                l.sender_kind = $sender_kind,
+                l.sender_id = $sender_id,
+                l.recipient_kind = $recipient_kind,
+                l.recipient_id = $recipient_id,
+                l.subject = $subject,
+                l.body = $body,
+                l.in_reply_to = $in_reply_to,
+                l.sent_at = $sent_at,
+                l.deliver_at = $deliver_at,
+                l.delivered_at = $delivered_at,
+                l.read_at = $read_at,
+                l.created_at = $created_at
+            WITH l
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_LETTER]->(l)
+            WITH l
+            OPTIONAL MATCH (original:Letter {id: $in_reply_to})
+            FOREACH (_ IN CASE WHEN original IS NULL THEN [] ELSE [1] END |
+                MERGE (l)-[:REPLY_TO]->(original))",
+        )
+        .param("id", letter.id.to_string())
+        .param("world_id", letter.world_id.to_string())
+        .param("sender_kind", sender_kind)
+        .param("sender_id", sender_id)
+        .param("recipient_kind", recipient_kind)
+        .param("recipient_id", recipient_id)
+        .param("subject", letter.subject.clone())
+        .param("body", letter.body.clone())
+        .param(
+            "in_reply_to",
+            letter
+                .in_reply_to
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param("sent_at", letter.sent_at.to_rfc3339())
+        .param("deliver_at", letter.deliver_at.to_rfc3339())
+        .param(
+            "delivered_at",
+            letter
+                .delivered_at
+                .map(|t| t.to_rfc3339())
+                .unwrap_or_default(),
+        )
+        .param(
+            "read_at",
+            letter.read_at.map(|t| t.to_rfc3339()).unwrap_or_default(),
+        )
+        .param("created_at", letter.created_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: LetterId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (l:Letter {id: $id})
+            DETACH DELETE l",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted letter: {}", id);
+        Ok(())
+    }
+
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<Letter>, RepoError> {
+        let q = query(
+            "MATCH (l:Letter)
+            WHERE (l.sender_kind = 'pc' AND l.sender_id = $pc_id)
+               OR (l.recipient_kind = 'pc' AND l.recipient_id = $pc_id)
+            RETURN l
+            ORDER BY l.sent_at DESC",
+        )
+        .param("pc_id", pc_id.to_string());
+
+        self.collect(q).await
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Letter>, RepoError> {
+        let q = query(
+            "MATCH (:World {id: $world_id})-[:HAS_LETTER]->(l:Letter)
+            RETURN l
+            ORDER BY l.sent_at DESC",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.collect(q).await
+    }
+
+    async fn list_undelivered_in_world(&self, world_id: WorldId) -> Result<Vec<Letter>, RepoError> {
+        let q = query(
+            "MATCH (:World {id: $world_id})-[:HAS_LETTER]->(l:Letter)
+            WHERE l.delivered_at = ''
+            RETURN l
+            ORDER BY l.deliver_at",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.collect(q).await
+    }
+}
@@ -0,0 +1,148 @@
+//! Neo4j content library repository implementation.
+//!
+//! An entry's content (a character, item, challenge or lore snapshot) is
+//! stored as JSON; it is only ever read and written whole. Entries hang off
+//! no world so they survive the world they were published from:
+//! - `(LibraryEntry {owner_id, name, kind, content})`
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use wrldbldr_domain::{LibraryContent, LibraryEntry, LibraryEntryId, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, LibraryRepo, RepoError};
+
+pub struct Neo4jLibraryRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jLibraryRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_entry(&self, row: Row) -> Result<LibraryEntry, RepoError> {
+        let node: neo4rs::Node = row
+            .get("e")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: LibraryEntryId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let source_world_id: WorldId = parse_typed_id(&node, "source_world_id")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let content_json = node.get_string_or("content", "");
+        let content: LibraryContent = serde_json::from_str(&content_json)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+
+        Ok(LibraryEntry {
+            id,
+            owner_id: node.get_string_or("owner_id", ""),
+            name: node.get_string_or("name", ""),
+            description: node.get_optional_string("description"),
+            source_world_id,
+            content,
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+}
+
+#[async_trait]
+impl LibraryRepo for Neo4jLibraryRepo {
+    async fn get(&self, id: LibraryEntryId) -> Result<Option<LibraryEntry>, RepoError> {
+        let q = query("MATCH (e:LibraryEntry {id: $id}) RETURN e").param("id", id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        if let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            Ok(Some(self.row_to_entry(row)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn save(&self, entry: &LibraryEntry) -> Result<(), RepoError> {
+        let content_json = serde_json::to_string(&entry.content)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let q = query(
+            "MERGE (e:LibraryEntry {id: $id})
+            SET e.owner_id = $owner_id,
+                e.name = $name,
+                e.description = $description,
+                e.kind = $kind,
+                e.source_world_id = $source_world_id,
+                e.content = $content,
+                e.created_at = $created_at,
+                e.updated_at = $updated_at",
+        )
+        .param("id", entry.id.to_string())
+        .param("owner_id", entry.owner_id.clone())
+        .param("name", entry.name.clone())
+        .param("description", entry.description.clone().unwrap_or_default())
+        .param("kind", entry.content.entity_type().to_string())
+        .param("source_world_id", entry.source_world_id.to_string())
+        .param("content", content_json)
+        .param("created_at", entry.created_at.to_rfc3339())
+        .param("updated_at", entry.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: LibraryEntryId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (e:LibraryEntry {id: $id})
+            DETACH DELETE e",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted library entry: {}", id);
+        Ok(())
+    }
+
+    async fn list_for_owner(&self, owner_id: &str) -> Result<Vec<LibraryEntry>, RepoError> {
+        let q = query(
+            "MATCH (e:LibraryEntry {owner_id: $owner_id})
+            RETURN e
+            ORDER BY e.name",
+        )
+        .param("owner_id", owner_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut entries = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            entries.push(self.row_to_entry(row)?);
+        }
+
+        Ok(entries)
+    }
+}
@@ -3,20 +3,21 @@
 //! Handles both Location and Region CRUD operations, plus connections.
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Row};
+use neo4rs::{query, Row};
 use uuid::Uuid;
 use wrldbldr_domain::*;
 
 use super::helpers::{parse_typed_id, NodeExt, RowExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{LocationRepo, RepoError};
 
 /// Repository for Location and Region operations.
 pub struct Neo4jLocationRepo {
-    graph: Graph,
+    graph: ResilientGraph,
 }
 
 impl Neo4jLocationRepo {
-    pub fn new(graph: Graph) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 
@@ -58,6 +59,21 @@ impl Neo4jLocationRepo {
             _ => LocationType::Interior,
         };
 
+        let parent_location_id: Option<LocationId> = node
+            .get_optional_string("parent_location_id")
+            .and_then(|s| uuid::Uuid::parse_str(&s).ok())
+            .map(LocationId::from_uuid);
+        let scale = node.get_optional_string("scale").map(|s| match s.as_str() {
+            "Continent" => LocationScale::Continent,
+            "Kingdom" => LocationScale::Kingdom,
+            "City" => LocationScale::City,
+            "District" => LocationScale::District,
+            "Building" => LocationScale::Building,
+            "Room" => LocationScale::Room,
+            _ => LocationScale::Unknown,
+        });
+        let crossing_minutes = node.get_positive_i64("crossing_minutes");
+
         // Parse parent_map_bounds from JSON
         let parent_map_bounds = node
             .get_optional_string("parent_map_bounds")
@@ -72,6 +88,13 @@ impl Neo4jLocationRepo {
                 })
             });
 
+        let world_map_position = node
+            .get_optional_string("world_map_position")
+            .and_then(|json| serde_json::from_str(&json).ok());
+        let encounter_table = node
+            .get_optional_string("encounter_table")
+            .and_then(|json| serde_json::from_str(&json).ok());
+
         Ok(Location {
             id,
             world_id,
@@ -80,7 +103,12 @@ impl Neo4jLocationRepo {
             location_type,
             backdrop_asset,
             map_asset,
+            parent_location_id,
+            scale,
+            crossing_minutes,
             parent_map_bounds,
+            world_map_position,
+            encounter_table,
             default_region_id,
             atmosphere,
             presence_cache_ttl_hours: presence_cache_ttl_hours as i32,
@@ -103,6 +131,7 @@ impl Neo4jLocationRepo {
         let description = node.get_string_or("description", "");
         let backdrop_asset = node.get_optional_string("backdrop_asset");
         let atmosphere = node.get_optional_string("atmosphere");
+        let map_asset = node.get_optional_string("map_asset");
         let is_spawn_point = node.get_bool_or("is_spawn_point", false);
         let order = node.get_i64_or("order", 0) as u32;
 
@@ -120,6 +149,21 @@ impl Neo4jLocationRepo {
                 })
             });
 
+        let hotspots = node
+            .get_optional_string("hotspots")
+            .filter(|s| !s.is_empty())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+
+        let lighting = node
+            .get_optional_string("lighting")
+            .and_then(|s| match s.as_str() {
+                "Bright" => Some(LightLevel::Bright),
+                "Dim" => Some(LightLevel::Dim),
+                "Dark" => Some(LightLevel::Dark),
+                _ => None,
+            });
+
         Ok(Region {
             id,
             location_id,
@@ -127,9 +171,12 @@ impl Neo4jLocationRepo {
             description,
             backdrop_asset,
             atmosphere,
+            map_asset,
             map_bounds,
             is_spawn_point,
             order,
+            hotspots,
+            lighting,
         })
     }
 
@@ -144,6 +191,15 @@ impl Neo4jLocationRepo {
         let bidirectional: bool = row.get("bidirectional").unwrap_or(true);
         let is_locked: bool = row.get("is_locked").unwrap_or(false);
         let lock_description = row.get_optional_string("lock_description");
+        let lock = row
+            .get_optional_string("lock")
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        let sound = match row.get_optional_string("sound").as_deref() {
+            Some("Muffled") => SoundCarry::Muffled,
+            Some("Blocked") => SoundCarry::Blocked,
+            _ => SoundCarry::Open,
+        };
 
         let from_id =
             uuid::Uuid::parse_str(&from_id_str).map_err(|e| RepoError::Database(e.to_string()))?;
@@ -157,6 +213,8 @@ impl Neo4jLocationRepo {
             bidirectional,
             is_locked,
             lock_description,
+            lock,
+            sound,
         })
     }
 
@@ -175,6 +233,10 @@ impl Neo4jLocationRepo {
         let is_locked: bool = row.get("is_locked").unwrap_or(false);
         let description = row.get_optional_string("description");
         let lock_description = row.get_optional_string("lock_description");
+        let lock = row
+            .get_optional_string("lock")
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
 
         let from_id =
             uuid::Uuid::parse_str(&from_id_str).map_err(|e| RepoError::Database(e.to_string()))?;
@@ -190,6 +252,7 @@ impl Neo4jLocationRepo {
             travel_time: travel_time as u32,
             is_locked,
             lock_description,
+            lock,
         })
     }
 }
@@ -234,6 +297,20 @@ impl LocationRepo for Neo4jLocationRepo {
                 .to_string()
             })
             .unwrap_or_default();
+        let world_map_position_json = location
+            .world_map_position
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| RepoError::Serialization(e.to_string()))?
+            .unwrap_or_default();
+        let encounter_table_json = location
+            .encounter_table
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| RepoError::Serialization(e.to_string()))?
+            .unwrap_or_default();
 
         let q = query(
             "MERGE (l:Location {id: $id})
@@ -244,11 +321,23 @@ impl LocationRepo for Neo4jLocationRepo {
                 l.backdrop_asset = $backdrop_asset,
                 l.map_asset = $map_asset,
                 l.parent_map_bounds = $parent_map_bounds,
+                l.world_map_position = $world_map_position,
+                l.encounter_table = $encounter_table,
+                l.parent_location_id = $parent_location_id,
+                l.scale = $scale,
+                l.crossing_minutes = $crossing_minutes,
                 l.default_region_id = $default_region_id,
                 l.atmosphere = $atmosphere,
                 l.presence_cache_ttl_hours = $presence_cache_ttl_hours,
                 l.use_llm_presence = $use_llm_presence
             WITH l
+            OPTIONAL MATCH (:Location)-[old:CONTAINS_LOCATION]->(l)
+            DELETE old
+            WITH DISTINCT l
+            OPTIONAL MATCH (p:Location {id: $parent_location_id})
+            FOREACH (_ IN CASE WHEN p IS NULL THEN [] ELSE [1] END |
+                MERGE (p)-[:CONTAINS_LOCATION]->(l))
+            WITH l
             MATCH (w:World {id: $world_id})
             MERGE (w)-[:CONTAINS_LOCATION]->(l)
             RETURN l.id as id",
@@ -264,6 +353,26 @@ impl LocationRepo for Neo4jLocationRepo {
         )
         .param("map_asset", location.map_asset.clone().unwrap_or_default())
         .param("parent_map_bounds", map_bounds_json)
+        .param("world_map_position", world_map_position_json)
+        .param("encounter_table", encounter_table_json)
+        .param(
+            "parent_location_id",
+            location
+                .parent_location_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param(
+            "scale",
+            location
+                .scale
+                .map(|scale| format!("{:?}", scale))
+                .unwrap_or_default(),
+        )
+        .param(
+            "crossing_minutes",
+            location.crossing_minutes.map_or(-1, i64::from),
+        )
         .param(
             "default_region_id",
             location
@@ -370,6 +479,8 @@ impl LocationRepo for Neo4jLocationRepo {
                 .to_string()
             })
             .unwrap_or_default();
+        let hotspots_json = serde_json::to_string(&region.hotspots)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
 
         let q = query(
             "MERGE (r:Region {id: $id})
@@ -378,9 +489,12 @@ impl LocationRepo for Neo4jLocationRepo {
                 r.description = $description,
                 r.backdrop_asset = $backdrop_asset,
                 r.atmosphere = $atmosphere,
+                r.map_asset = $map_asset,
                 r.map_bounds = $map_bounds,
                 r.is_spawn_point = $is_spawn_point,
-                r.order = $order
+                r.order = $order,
+                r.hotspots = $hotspots,
+                r.lighting = $lighting
             WITH r
             MATCH (l:Location {id: $location_id})
             MERGE (l)-[:HAS_REGION]->(r)
@@ -395,9 +509,18 @@ impl LocationRepo for Neo4jLocationRepo {
             region.backdrop_asset.clone().unwrap_or_default(),
         )
         .param("atmosphere", region.atmosphere.clone().unwrap_or_default())
+        .param("map_asset", region.map_asset.clone().unwrap_or_default())
         .param("map_bounds", map_bounds_json)
         .param("is_spawn_point", region.is_spawn_point)
-        .param("order", region.order as i64);
+        .param("order", region.order as i64)
+        .param("hotspots", hotspots_json)
+        .param(
+            "lighting",
+            region
+                .lighting
+                .map(|l| format!("{:?}", l))
+                .unwrap_or_default(),
+        );
 
         self.graph
             .run(q)
@@ -440,7 +563,8 @@ impl LocationRepo for Neo4jLocationRepo {
     async fn delete_region(&self, id: RegionId) -> Result<(), RepoError> {
         let q = query(
             "MATCH (r:Region {id: $id})
-            DETACH DELETE r",
+            OPTIONAL MATCH (r)-[:HAS_CROWD]->(c:Crowd)
+            DETACH DELETE r, c",
         )
         .param("id", id.to_string());
 
@@ -467,7 +591,9 @@ impl LocationRepo for Neo4jLocationRepo {
                    rel.description as description,
                    rel.bidirectional as bidirectional,
                    rel.is_locked as is_locked,
-                   rel.lock_description as lock_description",
+                   rel.lock_description as lock_description,
+                   rel.lock as lock,
+                   rel.sound as sound",
         )
         .param("id", region_id.to_string());
 
@@ -490,6 +616,8 @@ impl LocationRepo for Neo4jLocationRepo {
     }
 
     async fn save_connection(&self, connection: &RegionConnection) -> Result<(), RepoError> {
+        let lock_json = serde_json::to_string(&connection.lock)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
         let q = query(
             "MATCH (from:Region {id: $from_id})
             MATCH (to:Region {id: $to_id})
@@ -497,7 +625,9 @@ impl LocationRepo for Neo4jLocationRepo {
             SET rel.description = $description,
                 rel.bidirectional = $bidirectional,
                 rel.is_locked = $is_locked,
-                rel.lock_description = $lock_description
+                rel.lock_description = $lock_description,
+                rel.lock = $lock,
+                rel.sound = $sound
             RETURN from.id as from_id",
         )
         .param("from_id", connection.from_region.to_string())
@@ -511,7 +641,9 @@ impl LocationRepo for Neo4jLocationRepo {
         .param(
             "lock_description",
             connection.lock_description.clone().unwrap_or_default(),
-        );
+        )
+        .param("lock", lock_json.clone())
+        .param("sound", format!("{:?}", connection.sound));
 
         self.graph
             .run(q)
@@ -527,7 +659,9 @@ impl LocationRepo for Neo4jLocationRepo {
                 SET rel.description = $description,
                     rel.bidirectional = $bidirectional,
                     rel.is_locked = $is_locked,
-                    rel.lock_description = $lock_description
+                    rel.lock_description = $lock_description,
+                    rel.lock = $lock,
+                    rel.sound = $sound
                 RETURN from.id as from_id",
             )
             .param("from_id", connection.from_region.to_string())
@@ -541,7 +675,9 @@ impl LocationRepo for Neo4jLocationRepo {
             .param(
                 "lock_description",
                 connection.lock_description.clone().unwrap_or_default(),
-            );
+            )
+            .param("lock", lock_json.clone())
+            .param("sound", format!("{:?}", connection.sound));
 
             self.graph
                 .run(reverse_q)
@@ -605,7 +741,8 @@ impl LocationRepo for Neo4jLocationRepo {
                    r.bidirectional as bidirectional,
                    r.travel_time as travel_time,
                    r.is_locked as is_locked,
-                   r.lock_description as lock_description",
+                   r.lock_description as lock_description,
+                   r.lock as lock",
         )
         .param("id", location_id.to_string());
 
@@ -631,6 +768,8 @@ impl LocationRepo for Neo4jLocationRepo {
         &self,
         connection: &LocationConnection,
     ) -> Result<(), RepoError> {
+        let lock_json = serde_json::to_string(&connection.lock)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
         let q = query(
             "MATCH (from:Location {id: $from_id})
             MATCH (to:Location {id: $to_id})
@@ -640,7 +779,8 @@ impl LocationRepo for Neo4jLocationRepo {
                 r.bidirectional = $bidirectional,
                 r.travel_time = $travel_time,
                 r.is_locked = $is_locked,
-                r.lock_description = $lock_description",
+                r.lock_description = $lock_description,
+                r.lock = $lock",
         )
         .param("from_id", connection.from_location.to_string())
         .param("to_id", connection.to_location.to_string())
@@ -655,7 +795,8 @@ impl LocationRepo for Neo4jLocationRepo {
         .param(
             "lock_description",
             connection.lock_description.clone().unwrap_or_default(),
-        );
+        )
+        .param("lock", lock_json.clone());
 
         self.graph
             .run(q)
@@ -672,7 +813,8 @@ impl LocationRepo for Neo4jLocationRepo {
                     r.bidirectional = $bidirectional,
                     r.travel_time = $travel_time,
                     r.is_locked = $is_locked,
-                    r.lock_description = $lock_description",
+                    r.lock_description = $lock_description,
+                    r.lock = $lock",
             )
             .param("from_id", connection.from_location.to_string())
             .param("to_id", connection.to_location.to_string())
@@ -687,7 +829,8 @@ impl LocationRepo for Neo4jLocationRepo {
             .param(
                 "lock_description",
                 connection.lock_description.clone().unwrap_or_default(),
-            );
+            )
+            .param("lock", lock_json.clone());
 
             self.graph
                 .run(reverse_q)
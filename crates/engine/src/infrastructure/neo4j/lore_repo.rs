@@ -7,20 +7,21 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Row};
+use neo4rs::{query, Row};
 use wrldbldr_domain::*;
 
 use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{ClockPort, LoreRepo, RepoError};
 
 /// Repository for Lore operations.
 pub struct Neo4jLoreRepo {
-    graph: Graph,
+    graph: ResilientGraph,
     clock: Arc<dyn ClockPort>,
 }
 
 impl Neo4jLoreRepo {
-    pub fn new(graph: Graph, clock: Arc<dyn ClockPort>) -> Self {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
         Self { graph, clock }
     }
 
@@ -110,6 +111,8 @@ impl Neo4jLoreRepo {
             chunks: Vec::new(), // Will be populated separately
             is_common_knowledge,
             tags,
+            // Years can be negative, so a missing value is stored as null
+            event_year: node.get::<i64>("event_year").ok().map(|y| y as i32),
             created_at,
             updated_at,
         })
@@ -206,6 +209,7 @@ impl LoreRepo for Neo4jLoreRepo {
                 l.category = $category,
                 l.is_common_knowledge = $is_common_knowledge,
                 l.tags = $tags,
+                l.event_year = $event_year,
                 l.created_at = $created_at,
                 l.updated_at = $updated_at
             WITH l
@@ -220,6 +224,7 @@ impl LoreRepo for Neo4jLoreRepo {
         .param("category", lore.category.to_string())
         .param("is_common_knowledge", lore.is_common_knowledge)
         .param("tags", tags_json)
+        .param("event_year", lore.event_year.map(i64::from))
         .param("created_at", lore.created_at.to_rfc3339())
         .param("updated_at", lore.updated_at.to_rfc3339());
 
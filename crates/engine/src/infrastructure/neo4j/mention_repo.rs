@@ -0,0 +1,279 @@
+//! Neo4j entity mention repository implementation.
+//!
+//! Aliases and mentions are keyed by entity type and id, like tags, so no
+//! entity repository has to know about them:
+//! - `(World)-[:HAS_ENTITY_ALIASES]->(EntityAliases {entity_type, entity_id, aliases})`
+//! - `(World)-[:HAS_MENTION]->(EntityMention {source_type, source_id, target_type, target_id, ...})`
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use uuid::Uuid;
+use wrldbldr_domain::{EntityMention, EntityType, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{MentionRepo, RepoError};
+
+pub struct Neo4jMentionRepo {
+    graph: ResilientGraph,
+}
+
+impl Neo4jMentionRepo {
+    pub fn new(graph: ResilientGraph) -> Self {
+        Self { graph }
+    }
+
+    fn row_to_mention(&self, row: Row) -> Result<EntityMention, RepoError> {
+        let node: neo4rs::Node = row
+            .get("m")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let source_id = node
+            .get_uuid("source_id")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let target_id = node
+            .get_uuid("target_id")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let entity_type = |field: &str| {
+            node.get_string_or(field, "unknown")
+                .parse()
+                .unwrap_or(EntityType::Unknown)
+        };
+
+        Ok(EntityMention {
+            world_id,
+            source_type: entity_type("source_type"),
+            source_id,
+            source_name: node.get_string_or("source_name", ""),
+            target_type: entity_type("target_type"),
+            target_id,
+            matched_text: node.get_string_or("matched_text", ""),
+            excerpt: node.get_string_or("excerpt", ""),
+            count: node.get_positive_i64("count").unwrap_or(1),
+        })
+    }
+
+    async fn list(&self, q: neo4rs::Query) -> Result<Vec<EntityMention>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut mentions = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            mentions.push(self.row_to_mention(row)?);
+        }
+        Ok(mentions)
+    }
+}
+
+#[async_trait]
+impl MentionRepo for Neo4jMentionRepo {
+    async fn list_aliases(
+        &self,
+        world_id: WorldId,
+    ) -> Result<HashMap<(EntityType, Uuid), Vec<String>>, RepoError> {
+        let q = query(
+            "MATCH (a:EntityAliases {world_id: $world_id})
+            RETURN a.entity_type AS entity_type, a.entity_id AS entity_id, a.aliases AS aliases",
+        )
+        .param("world_id", world_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut aliases = HashMap::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            let Ok(entity_id) = row
+                .get::<String>("entity_id")
+                .map_err(|e| e.to_string())
+                .and_then(|id| Uuid::parse_str(&id).map_err(|e| e.to_string()))
+            else {
+                continue;
+            };
+            let entity_type: EntityType = row
+                .get::<String>("entity_type")
+                .unwrap_or_default()
+                .parse()
+                .unwrap_or(EntityType::Unknown);
+            let entity_aliases: Vec<String> = row.get("aliases").unwrap_or_default();
+            aliases.insert((entity_type, entity_id), entity_aliases);
+        }
+
+        Ok(aliases)
+    }
+
+    async fn set_aliases(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        aliases: &[String],
+    ) -> Result<(), RepoError> {
+        let q = if aliases.is_empty() {
+            query(
+                "MATCH (a:EntityAliases {entity_type: $entity_type, entity_id: $entity_id})
+                DETACH DELETE a",
+            )
+        } else {
+            query(
+                "MERGE (a:EntityAliases {entity_type: $entity_type, entity_id: $entity_id})
+                SET a.world_id = $world_id,
+                    a.aliases = $aliases
+                WITH a
+                MATCH (w:World {id: $world_id})
+                MERGE (w)-[:HAS_ENTITY_ALIASES]->(a)",
+            )
+        }
+        .param("world_id", world_id.to_string())
+        .param("entity_type", entity_type.as_str())
+        .param("entity_id", entity_id.to_string())
+        .param("aliases", aliases.to_vec());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn replace_mentions(
+        &self,
+        world_id: WorldId,
+        source_type: EntityType,
+        source_id: Uuid,
+        mentions: &[EntityMention],
+    ) -> Result<(), RepoError> {
+        // One transaction, so readers never see a source half re-indexed
+        let mut txn = self
+            .graph
+            .start_txn()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let clear = query(
+            "MATCH (m:EntityMention {source_type: $source_type, source_id: $source_id})
+            DETACH DELETE m",
+        )
+        .param("source_type", source_type.as_str())
+        .param("source_id", source_id.to_string());
+        txn.run(clear)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        for mention in mentions {
+            let create = query(
+                "MATCH (w:World {id: $world_id})
+                CREATE (w)-[:HAS_MENTION]->(m:EntityMention {
+                    world_id: $world_id,
+                    source_type: $source_type,
+                    source_id: $source_id,
+                    source_name: $source_name,
+                    target_type: $target_type,
+                    target_id: $target_id,
+                    matched_text: $matched_text,
+                    excerpt: $excerpt,
+                    count: $count
+                })",
+            )
+            .param("world_id", world_id.to_string())
+            .param("source_type", source_type.as_str())
+            .param("source_id", source_id.to_string())
+            .param("source_name", mention.source_name.clone())
+            .param("target_type", mention.target_type.as_str())
+            .param("target_id", mention.target_id.to_string())
+            .param("matched_text", mention.matched_text.clone())
+            .param("excerpt", mention.excerpt.clone())
+            .param("count", i64::from(mention.count));
+            txn.run(create)
+                .await
+                .map_err(|e| RepoError::Database(e.to_string()))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn clear_mentions(&self, world_id: WorldId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (m:EntityMention {world_id: $world_id})
+            DETACH DELETE m",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn forget_entity(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<(), RepoError> {
+        let q = query(
+            "OPTIONAL MATCH (a:EntityAliases {entity_type: $entity_type, entity_id: $entity_id})
+            DETACH DELETE a
+            WITH 1 AS done
+            OPTIONAL MATCH (m:EntityMention)
+            WHERE (m.source_type = $entity_type AND m.source_id = $entity_id)
+               OR (m.target_type = $entity_type AND m.target_id = $entity_id)
+            DETACH DELETE m",
+        )
+        .param("entity_type", entity_type.as_str())
+        .param("entity_id", entity_id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn list_mentions_of(
+        &self,
+        target_type: EntityType,
+        target_id: Uuid,
+    ) -> Result<Vec<EntityMention>, RepoError> {
+        let q = query(
+            "MATCH (m:EntityMention {target_type: $target_type, target_id: $target_id})
+            RETURN m
+            ORDER BY toLower(m.source_name)",
+        )
+        .param("target_type", target_type.as_str())
+        .param("target_id", target_id.to_string());
+
+        self.list(q).await
+    }
+
+    async fn list_mentions_in(
+        &self,
+        source_type: EntityType,
+        source_id: Uuid,
+    ) -> Result<Vec<EntityMention>, RepoError> {
+        let q = query(
+            "MATCH (m:EntityMention {source_type: $source_type, source_id: $source_id})
+            RETURN m",
+        )
+        .param("source_type", source_type.as_str())
+        .param("source_id", source_id.to_string());
+
+        self.list(q).await
+    }
+}
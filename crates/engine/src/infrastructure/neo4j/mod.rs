@@ -1,59 +1,100 @@
 //! Neo4j database implementations.
 
-use neo4rs::Graph;
 use std::sync::Arc;
 
 use crate::infrastructure::ports::ClockPort;
 
 mod helpers;
+mod resilient_graph;
 mod schema;
 
+pub use resilient_graph::ResilientGraph;
 pub use schema::ensure_schema;
 
 mod act_repo;
 mod asset_repo;
+mod broadsheet_repo;
 mod challenge_repo;
 mod character_repo;
+mod companion_repo;
+mod content_draft_repo;
+mod crowd_repo;
+mod custom_field_repo;
+mod dialogue_tree_repo;
+mod economy_repo;
+mod faction_repo;
 mod flag_repo;
 mod goal_repo;
+mod injury_repo;
 mod interaction_repo;
 mod item_repo;
+mod letter_repo;
+mod library_repo;
 mod location_repo;
 mod location_state_repo;
 mod lore_repo;
+mod mention_repo;
+mod name_generator_repo;
 mod narrative_repo;
+mod npc_draft_repo;
 mod observation_repo;
+mod pc_secret_repo;
 mod player_character_repo;
+mod progress_clock_repo;
+mod property_repo;
 mod region_state_repo;
 mod scene_repo;
 mod skill_repo;
 mod staging_repo;
+mod tag_repo;
+mod template_repo;
 mod world_repo;
 
 pub use act_repo::Neo4jActRepo;
 pub use asset_repo::Neo4jAssetRepo;
+pub use broadsheet_repo::Neo4jBroadsheetRepo;
 pub use challenge_repo::Neo4jChallengeRepo;
 pub use character_repo::Neo4jCharacterRepo;
+pub use companion_repo::Neo4jCompanionRepo;
+pub use content_draft_repo::Neo4jContentDraftRepo;
+pub use crowd_repo::Neo4jCrowdRepo;
+pub use custom_field_repo::Neo4jCustomFieldRepo;
+pub use dialogue_tree_repo::Neo4jDialogueTreeRepo;
+pub use economy_repo::Neo4jEconomyRepo;
+pub use faction_repo::Neo4jFactionRepo;
 pub use flag_repo::Neo4jFlagRepo;
 pub use goal_repo::Neo4jGoalRepo;
+pub use injury_repo::Neo4jInjuryRepo;
 pub use interaction_repo::Neo4jInteractionRepo;
 pub use item_repo::Neo4jItemRepo;
+pub use letter_repo::Neo4jLetterRepo;
+pub use library_repo::Neo4jLibraryRepo;
 pub use location_repo::Neo4jLocationRepo;
 pub use location_state_repo::Neo4jLocationStateRepo;
 pub use lore_repo::Neo4jLoreRepo;
+pub use mention_repo::Neo4jMentionRepo;
+pub use name_generator_repo::Neo4jNameGeneratorRepo;
 pub use narrative_repo::Neo4jNarrativeRepo;
+pub use npc_draft_repo::Neo4jNpcDraftRepo;
 pub use observation_repo::Neo4jObservationRepo;
+pub use pc_secret_repo::Neo4jPcSecretRepo;
 pub use player_character_repo::Neo4jPlayerCharacterRepo;
+pub use progress_clock_repo::Neo4jProgressClockRepo;
+pub use property_repo::Neo4jPropertyRepo;
 pub use region_state_repo::Neo4jRegionStateRepo;
 pub use scene_repo::Neo4jSceneRepo;
 pub use skill_repo::Neo4jSkillRepo;
 pub use staging_repo::Neo4jStagingRepo;
+pub use tag_repo::Neo4jTagRepo;
+pub use template_repo::Neo4jTemplateRepo;
 pub use world_repo::Neo4jWorldRepo;
 
 #[cfg(test)]
 mod integration_tests;
 
 /// Create all Neo4j repositories from a graph connection.
+///
+/// Every repository shares the graph's circuit breaker.
 pub struct Neo4jRepositories {
     pub character: Arc<Neo4jCharacterRepo>,
     pub player_character: Arc<Neo4jPlayerCharacterRepo>,
@@ -72,12 +113,31 @@ pub struct Neo4jRepositories {
     pub flag: Arc<Neo4jFlagRepo>,
     pub goal: Arc<Neo4jGoalRepo>,
     pub lore: Arc<Neo4jLoreRepo>,
+    pub progress_clock: Arc<Neo4jProgressClockRepo>,
+    pub tag: Arc<Neo4jTagRepo>,
+    pub custom_field: Arc<Neo4jCustomFieldRepo>,
+    pub template: Arc<Neo4jTemplateRepo>,
+    pub library: Arc<Neo4jLibraryRepo>,
+    pub content_draft: Arc<Neo4jContentDraftRepo>,
+    pub crowd: Arc<Neo4jCrowdRepo>,
+    pub npc_draft: Arc<Neo4jNpcDraftRepo>,
+    pub mention: Arc<Neo4jMentionRepo>,
+    pub name_generator: Arc<Neo4jNameGeneratorRepo>,
+    pub economy: Arc<Neo4jEconomyRepo>,
+    pub property: Arc<Neo4jPropertyRepo>,
+    pub companion: Arc<Neo4jCompanionRepo>,
+    pub injury: Arc<Neo4jInjuryRepo>,
+    pub pc_secret: Arc<Neo4jPcSecretRepo>,
+    pub letter: Arc<Neo4jLetterRepo>,
+    pub broadsheet: Arc<Neo4jBroadsheetRepo>,
+    pub faction: Arc<Neo4jFactionRepo>,
+    pub dialogue_tree: Arc<Neo4jDialogueTreeRepo>,
     pub location_state: Arc<Neo4jLocationStateRepo>,
     pub region_state: Arc<Neo4jRegionStateRepo>,
 }
 
 impl Neo4jRepositories {
-    pub fn new(graph: Graph, clock: Arc<dyn ClockPort>) -> Self {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
         Self {
             character: Arc::new(Neo4jCharacterRepo::new(graph.clone())),
             player_character: Arc::new(Neo4jPlayerCharacterRepo::new(graph.clone(), clock.clone())),
@@ -93,9 +153,28 @@ impl Neo4jRepositories {
             item: Arc::new(Neo4jItemRepo::new(graph.clone())),
             world: Arc::new(Neo4jWorldRepo::new(graph.clone(), clock.clone())),
             asset: Arc::new(Neo4jAssetRepo::new(graph.clone())),
-            flag: Arc::new(Neo4jFlagRepo::new(Arc::new(graph.clone()))),
+            flag: Arc::new(Neo4jFlagRepo::new(graph.clone())),
             goal: Arc::new(Neo4jGoalRepo::new(graph.clone())),
             lore: Arc::new(Neo4jLoreRepo::new(graph.clone(), clock.clone())),
+            progress_clock: Arc::new(Neo4jProgressClockRepo::new(graph.clone(), clock.clone())),
+            tag: Arc::new(Neo4jTagRepo::new(graph.clone(), clock.clone())),
+            custom_field: Arc::new(Neo4jCustomFieldRepo::new(graph.clone())),
+            template: Arc::new(Neo4jTemplateRepo::new(graph.clone(), clock.clone())),
+            library: Arc::new(Neo4jLibraryRepo::new(graph.clone(), clock.clone())),
+            content_draft: Arc::new(Neo4jContentDraftRepo::new(graph.clone(), clock.clone())),
+            crowd: Arc::new(Neo4jCrowdRepo::new(graph.clone(), clock.clone())),
+            npc_draft: Arc::new(Neo4jNpcDraftRepo::new(graph.clone(), clock.clone())),
+            mention: Arc::new(Neo4jMentionRepo::new(graph.clone())),
+            name_generator: Arc::new(Neo4jNameGeneratorRepo::new(graph.clone(), clock.clone())),
+            economy: Arc::new(Neo4jEconomyRepo::new(graph.clone(), clock.clone())),
+            property: Arc::new(Neo4jPropertyRepo::new(graph.clone(), clock.clone())),
+            companion: Arc::new(Neo4jCompanionRepo::new(graph.clone(), clock.clone())),
+            injury: Arc::new(Neo4jInjuryRepo::new(graph.clone(), clock.clone())),
+            pc_secret: Arc::new(Neo4jPcSecretRepo::new(graph.clone(), clock.clone())),
+            letter: Arc::new(Neo4jLetterRepo::new(graph.clone(), clock.clone())),
+            broadsheet: Arc::new(Neo4jBroadsheetRepo::new(graph.clone(), clock.clone())),
+            faction: Arc::new(Neo4jFactionRepo::new(graph.clone(), clock.clone())),
+            dialogue_tree: Arc::new(Neo4jDialogueTreeRepo::new(graph.clone(), clock.clone())),
             location_state: Arc::new(Neo4jLocationStateRepo::new(graph.clone(), clock.clone())),
             region_state: Arc::new(Neo4jRegionStateRepo::new(graph, clock)),
         }
@@ -0,0 +1,157 @@
+//! Neo4j name generator repository implementation.
+//!
+//! Generators hang off their world:
+//! - `(World)-[:HAS_NAME_GENERATOR]->(NameGenerator {name, style, region_ids, factions})`
+//!
+//! `style` is stored as JSON, since its shape depends on the kind of style.
+//! Regions are kept as ids rather than edges; a deleted region simply
+//! stops matching.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use wrldbldr_domain::{NameGenerator, NameGeneratorId, NameStyle, RegionId, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, NameGeneratorRepo, RepoError};
+
+pub struct Neo4jNameGeneratorRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jNameGeneratorRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_generator(&self, row: Row) -> Result<NameGenerator, RepoError> {
+        let node: neo4rs::Node = row
+            .get("g")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: NameGeneratorId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let style: NameStyle = node
+            .get_json("style")
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let region_ids = node
+            .get::<Vec<String>>("region_ids")
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|id| uuid::Uuid::parse_str(id).ok())
+            .map(RegionId::from_uuid)
+            .collect();
+
+        Ok(NameGenerator {
+            id,
+            world_id,
+            name: node.get_string_or("name", ""),
+            description: node.get_string_or("description", ""),
+            style,
+            region_ids,
+            factions: node.get("factions").unwrap_or_default(),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<NameGenerator>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut generators = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            generators.push(self.row_to_generator(row)?);
+        }
+
+        Ok(generators)
+    }
+}
+
+#[async_trait]
+impl NameGeneratorRepo for Neo4jNameGeneratorRepo {
+    async fn get(&self, id: NameGeneratorId) -> Result<Option<NameGenerator>, RepoError> {
+        let q = query("MATCH (g:NameGenerator {id: $id}) RETURN g").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save(&self, generator: &NameGenerator) -> Result<(), RepoError> {
+        let style_json = serde_json::to_string(&generator.style)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let region_ids: Vec<String> = generator
+            .region_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+
+        let q = query(
+            "MERGE (g:NameGenerator {id: $id})
+            SET g.world_id = $world_id,
+                g.name = $name,
+                g.description = $description,
+                g.style = $style,
+                g.region_ids = $region_ids,
+                g.factions = $factions,
+                g.created_at = $created_at,
+                g.updated_at = $updated_at
+            WITH g
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_NAME_GENERATOR]->(g)",
+        )
+        .param("id", generator.id.to_string())
+        .param("world_id", generator.world_id.to_string())
+        .param("name", generator.name.clone())
+        .param("description", generator.description.clone())
+        .param("style", style_json)
+        .param("region_ids", region_ids)
+        .param("factions", generator.factions.clone())
+        .param("created_at", generator.created_at.to_rfc3339())
+        .param("updated_at", generator.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: NameGeneratorId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (g:NameGenerator {id: $id})
+            DETACH DELETE g",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted name generator: {}", id);
+        Ok(())
+    }
+
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<NameGenerator>, RepoError> {
+        let q = query(
+            "MATCH (:World {id: $world_id})-[:HAS_NAME_GENERATOR]->(g:NameGenerator)
+            RETURN g
+            ORDER BY g.name",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.collect(q).await
+    }
+}
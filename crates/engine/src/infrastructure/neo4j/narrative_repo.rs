@@ -4,21 +4,24 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Timelike, Utc};
-use neo4rs::{query, Graph, Node, Row};
+use neo4rs::{query, Node, Row};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use wrldbldr_domain::*;
 
-use super::helpers::{parse_optional_typed_id, parse_typed_id, NodeExt};
-use crate::infrastructure::ports::{ClockPort, ConversationTurnRecord, NarrativeRepo, RepoError};
+use super::helpers::{parse_optional_typed_id, parse_typed_id, parse_typed_id_from_row, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{
+    ClockPort, ConversationTurnRecord, DialogueCount, NarrativeRepo, RepoError,
+};
 
 pub struct Neo4jNarrativeRepo {
-    graph: Graph,
+    graph: ResilientGraph,
     clock: std::sync::Arc<dyn ClockPort>,
 }
 
 impl Neo4jNarrativeRepo {
-    pub fn new(graph: Graph, clock: std::sync::Arc<dyn ClockPort>) -> Self {
+    pub fn new(graph: ResilientGraph, clock: std::sync::Arc<dyn ClockPort>) -> Self {
         Self { graph, clock }
     }
 }
@@ -1249,6 +1252,82 @@ impl NarrativeRepo for Neo4jNarrativeRepo {
             Ok(None)
         }
     }
+
+    // =========================================================================
+    // Analytics
+    // =========================================================================
+
+    async fn list_dialogue_counts(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<DialogueCount>, RepoError> {
+        let q = query(
+            "MATCH (pc:PlayerCharacter {world_id: $world_id})-[r:SPOKE_TO]->(npc:Character)
+            RETURN pc.id AS pc_id, npc.id AS npc_id,
+                   COALESCE(r.conversation_count, 0) AS exchanges",
+        )
+        .param("world_id", world_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let mut counts = Vec::new();
+
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            let exchanges: i64 = row.get("exchanges").unwrap_or(0);
+            counts.push(DialogueCount {
+                pc_id: parse_typed_id_from_row(&row, "pc_id")
+                    .map_err(|e| RepoError::Database(e.to_string()))?,
+                npc_id: parse_typed_id_from_row(&row, "npc_id")
+                    .map_err(|e| RepoError::Database(e.to_string()))?,
+                exchanges: u32::try_from(exchanges).unwrap_or(0),
+            });
+        }
+
+        Ok(counts)
+    }
+
+    async fn count_story_events_by_pc(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<(PlayerCharacterId, u32)>, RepoError> {
+        // Dialogue events reach the PC through their conversation; any other
+        // event names the PC in its details, as mortality events do
+        let q = query(
+            "MATCH (pc:PlayerCharacter {world_id: $world_id})
+            MATCH (e:StoryEvent {world_id: $world_id})
+            WHERE (e)-[:PART_OF_CONVERSATION]->(:Conversation)<-[:PARTICIPATED_IN]-(pc)
+               OR e.event_type_json CONTAINS pc.id
+            RETURN pc.id AS pc_id, count(DISTINCT e) AS events",
+        )
+        .param("world_id", world_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let mut counts = Vec::new();
+
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            let pc_id: PlayerCharacterId = parse_typed_id_from_row(&row, "pc_id")
+                .map_err(|e| RepoError::Database(e.to_string()))?;
+            let events: i64 = row.get("events").unwrap_or(0);
+            counts.push((pc_id, u32::try_from(events).unwrap_or(0)));
+        }
+
+        Ok(counts)
+    }
 }
 
 // =============================================================================
@@ -1638,6 +1717,12 @@ enum StoredEventEffect {
         description: String,
         requires_dm_action: bool,
     },
+    Plugin {
+        plugin: String,
+        effect: String,
+        #[serde(default)]
+        params: serde_json::Value,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1745,6 +1830,8 @@ enum StoredStoryEventType {
         note: String,
         importance: StoredMarkerImportance,
         marker_type: StoredDmMarkerType,
+        #[serde(default)]
+        pin: Option<StoredMarkerPin>,
     },
     NarrativeEventTriggered {
         narrative_event_id: String,
@@ -1861,6 +1948,24 @@ enum StoredDmMarkerType {
     Custom,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredMarkerPin {
+    location_id: String,
+    region_id: Option<String>,
+    x: f32,
+    y: f32,
+    link: Option<StoredMarkerLink>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum StoredMarkerLink {
+    Character(String),
+    Location(String),
+    Region(String),
+    Item(String),
+    NarrativeEvent(String),
+}
+
 // =============================================================================
 // Domain -> Stored conversions
 // =============================================================================
@@ -2177,6 +2282,15 @@ impl From<&EventEffect> for StoredEventEffect {
                 description: description.clone(),
                 requires_dm_action: *requires_dm_action,
             },
+            EventEffect::Plugin {
+                plugin,
+                effect,
+                params,
+            } => StoredEventEffect::Plugin {
+                plugin: plugin.clone(),
+                effect: effect.clone(),
+                params: params.clone(),
+            },
         }
     }
 }
@@ -2361,11 +2475,13 @@ impl From<&StoryEventType> for StoredStoryEventType {
                 note,
                 importance,
                 marker_type,
+                pin,
             } => StoredStoryEventType::DmMarker {
                 title: title.clone(),
                 note: note.clone(),
                 importance: (*importance).into(),
                 marker_type: (*marker_type).into(),
+                pin: pin.as_ref().map(StoredMarkerPin::from),
             },
             StoryEventType::NarrativeEventTriggered {
                 narrative_event_id,
@@ -2545,6 +2661,24 @@ impl From<DmMarkerType> for StoredDmMarkerType {
     }
 }
 
+impl From<&MarkerPin> for StoredMarkerPin {
+    fn from(p: &MarkerPin) -> Self {
+        Self {
+            location_id: p.location_id.to_string(),
+            region_id: p.region_id.map(|id| id.to_string()),
+            x: p.x,
+            y: p.y,
+            link: p.link.map(|link| match link {
+                MarkerLink::Character(id) => StoredMarkerLink::Character(id.to_string()),
+                MarkerLink::Location(id) => StoredMarkerLink::Location(id.to_string()),
+                MarkerLink::Region(id) => StoredMarkerLink::Region(id.to_string()),
+                MarkerLink::Item(id) => StoredMarkerLink::Item(id.to_string()),
+                MarkerLink::NarrativeEvent(id) => StoredMarkerLink::NarrativeEvent(id.to_string()),
+            }),
+        }
+    }
+}
+
 // =============================================================================
 // Stored -> Domain conversions
 // =============================================================================
@@ -2858,6 +2992,15 @@ impl From<StoredEventEffect> for EventEffect {
                 description,
                 requires_dm_action,
             },
+            StoredEventEffect::Plugin {
+                plugin,
+                effect,
+                params,
+            } => EventEffect::Plugin {
+                plugin,
+                effect,
+                params,
+            },
         }
     }
 }
@@ -3048,11 +3191,13 @@ impl From<StoredStoryEventType> for StoryEventType {
                 note,
                 importance,
                 marker_type,
+                pin,
             } => StoryEventType::DmMarker {
                 title,
                 note,
                 importance: importance.into(),
                 marker_type: marker_type.into(),
+                pin: pin.map(MarkerPin::from),
             },
             StoredStoryEventType::NarrativeEventTriggered {
                 narrative_event_id,
@@ -3225,3 +3370,33 @@ impl From<StoredDmMarkerType> for DmMarkerType {
         }
     }
 }
+
+impl From<StoredMarkerPin> for MarkerPin {
+    fn from(s: StoredMarkerPin) -> Self {
+        Self {
+            location_id: LocationId::from(parse_uuid_or_nil(&s.location_id, "location_id")),
+            region_id: s
+                .region_id
+                .and_then(|id| Uuid::parse_str(&id).ok().map(RegionId::from)),
+            x: s.x,
+            y: s.y,
+            link: s.link.map(|link| match link {
+                StoredMarkerLink::Character(id) => {
+                    MarkerLink::Character(parse_uuid_or_nil(&id, "link").into())
+                }
+                StoredMarkerLink::Location(id) => {
+                    MarkerLink::Location(parse_uuid_or_nil(&id, "link").into())
+                }
+                StoredMarkerLink::Region(id) => {
+                    MarkerLink::Region(parse_uuid_or_nil(&id, "link").into())
+                }
+                StoredMarkerLink::Item(id) => {
+                    MarkerLink::Item(parse_uuid_or_nil(&id, "link").into())
+                }
+                StoredMarkerLink::NarrativeEvent(id) => {
+                    MarkerLink::NarrativeEvent(parse_uuid_or_nil(&id, "link").into())
+                }
+            }),
+        }
+    }
+}
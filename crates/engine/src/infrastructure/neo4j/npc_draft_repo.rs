@@ -0,0 +1,171 @@
+//! Neo4j NPC draft repository implementation.
+//!
+//! Drafts hang off their world until the DM approves or rejects them:
+//! - `(World)-[:HAS_NPC_DRAFT]->(NpcDraft {name, archetype, disposition, status, source})`
+//!
+//! `source` is stored as JSON, since its shape depends on where the NPC came from.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use wrldbldr_domain::{
+    CampbellArchetype, DispositionLevel, NpcDraft, NpcDraftId, NpcDraftSource, NpcDraftStatus,
+    WorldId,
+};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, NpcDraftRepo, RepoError};
+
+pub struct Neo4jNpcDraftRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jNpcDraftRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_draft(&self, row: Row) -> Result<NpcDraft, RepoError> {
+        let node: neo4rs::Node = row
+            .get("d")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: NpcDraftId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let source: NpcDraftSource = node
+            .get_json("source")
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let archetype: CampbellArchetype = node
+            .get_string_or("archetype", "Unknown")
+            .parse()
+            .unwrap_or_default();
+        let disposition: DispositionLevel = node
+            .get_string_or("disposition", "neutral")
+            .parse()
+            .map_err(|e: String| RepoError::Database(e))?;
+        let status = match node.get_string_or("status", "pending").as_str() {
+            "generating" => NpcDraftStatus::Generating,
+            _ => NpcDraftStatus::Pending,
+        };
+
+        Ok(NpcDraft {
+            id,
+            world_id,
+            source,
+            name: node.get_string_or("name", ""),
+            description: node.get_string_or("description", ""),
+            archetype,
+            disposition,
+            status,
+            generation_error: node.get_optional_string("generation_error"),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<NpcDraft>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut drafts = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            drafts.push(self.row_to_draft(row)?);
+        }
+
+        Ok(drafts)
+    }
+}
+
+#[async_trait]
+impl NpcDraftRepo for Neo4jNpcDraftRepo {
+    async fn get(&self, id: NpcDraftId) -> Result<Option<NpcDraft>, RepoError> {
+        let q = query("MATCH (d:NpcDraft {id: $id}) RETURN d").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save(&self, draft: &NpcDraft) -> Result<(), RepoError> {
+        let source_json = serde_json::to_string(&draft.source)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let status = match draft.status {
+            NpcDraftStatus::Generating => "generating",
+            NpcDraftStatus::Pending => "pending",
+        };
+
+        let q = query(
+            "MERGE (d:NpcDraft {id: $id})
+            SET d.world_id = $world_id,
+                d.source = $source,
+                d.name = $name,
+                d.description = $description,
+                d.archetype = $archetype,
+                d.disposition = $disposition,
+                d.status = $status,
+                d.generation_error = $generation_error,
+                d.created_at = $created_at,
+                d.updated_at = $updated_at
+            WITH d
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_NPC_DRAFT]->(d)",
+        )
+        .param("id", draft.id.to_string())
+        .param("world_id", draft.world_id.to_string())
+        .param("source", source_json)
+        .param("name", draft.name.clone())
+        .param("description", draft.description.clone())
+        .param("archetype", draft.archetype.to_string())
+        .param("disposition", draft.disposition.to_string())
+        .param("status", status)
+        .param(
+            "generation_error",
+            draft.generation_error.clone().unwrap_or_default(),
+        )
+        .param("created_at", draft.created_at.to_rfc3339())
+        .param("updated_at", draft.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: NpcDraftId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (d:NpcDraft {id: $id})
+            DETACH DELETE d",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted NPC draft: {}", id);
+        Ok(())
+    }
+
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<NpcDraft>, RepoError> {
+        let q = query(
+            "MATCH (:World {id: $world_id})-[:HAS_NPC_DRAFT]->(d:NpcDraft)
+            RETURN d
+            ORDER BY d.created_at DESC",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.collect(q).await
+    }
+}
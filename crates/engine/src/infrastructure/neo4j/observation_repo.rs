@@ -2,21 +2,23 @@
 //!
 //! Tracks PC observations of NPC locations (fog of war for investigation gameplay).
 //! Observations are stored as edges: `(PlayerCharacter)-[:OBSERVED_NPC {...}]->(Character)`
+//! Known places are stored as edges: `(PlayerCharacter)-[:KNOWS_LOCATION {...}]->(Location)`
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph};
+use neo4rs::query;
 use wrldbldr_domain::common::{parse_datetime_or, StringExt};
 use wrldbldr_domain::*;
 
 use crate::infrastructure::ports::{ClockPort, ObservationRepo, RepoError};
+use super::resilient_graph::ResilientGraph;
 
 pub struct Neo4jObservationRepo {
-    graph: Graph,
+    graph: ResilientGraph,
     clock: std::sync::Arc<dyn ClockPort>,
 }
 
 impl Neo4jObservationRepo {
-    pub fn new(graph: Graph, clock: std::sync::Arc<dyn ClockPort>) -> Self {
+    pub fn new(graph: ResilientGraph, clock: std::sync::Arc<dyn ClockPort>) -> Self {
         Self { graph, clock }
     }
 }
@@ -215,4 +217,109 @@ impl ObservationRepo for Neo4jObservationRepo {
             .map_err(|e| RepoError::Database(e.to_string()))?;
         Ok(())
     }
+
+    /// Get every location a PC knows
+    async fn get_known_locations(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<LocationObservation>, RepoError> {
+        let q = query(
+            "MATCH (pc:PlayerCharacter {id: $pc_id})-[r:KNOWS_LOCATION]->(l:Location)
+            RETURN l.id as location_id, r.game_time as game_time,
+                   r.observation_type as observation_type, r.notes as notes,
+                   r.created_at as created_at, COALESCE(r.visits, 0) as visits
+            ORDER BY r.game_time DESC",
+        )
+        .param("pc_id", pc_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let mut known = Vec::new();
+        let now = self.clock.now();
+
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            let location_id_str: String = row
+                .get("location_id")
+                .map_err(|e| RepoError::Database(e.to_string()))?;
+            let game_time_str: String = row.get("game_time").unwrap_or_default();
+            let observation_type_str: String = row.get("observation_type").unwrap_or_default();
+            let notes: String = row.get("notes").unwrap_or_default();
+            let created_at_str: String = row.get("created_at").unwrap_or_default();
+            let visits: i64 = row.get("visits").unwrap_or(0);
+
+            known.push(LocationObservation {
+                pc_id,
+                location_id: LocationId::from_uuid(
+                    uuid::Uuid::parse_str(&location_id_str)
+                        .map_err(|e| RepoError::Database(e.to_string()))?,
+                ),
+                game_time: parse_datetime_or(&game_time_str, now),
+                observation_type: observation_type_str
+                    .parse()
+                    .unwrap_or(ObservationType::HeardAbout),
+                notes: notes.into_option(),
+                created_at: parse_datetime_or(&created_at_str, now),
+                visits: u32::try_from(visits).unwrap_or(0),
+            });
+        }
+
+        Ok(known)
+    }
+
+    /// Save knowledge of a location (upsert - hearsay never overwrites a visit)
+    async fn save_location_observation(
+        &self,
+        observation: &LocationObservation,
+    ) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (pc:PlayerCharacter {id: $pc_id}), (l:Location {id: $location_id})
+            MERGE (pc)-[r:KNOWS_LOCATION]->(l)
+            WITH r, (r.observation_type = 'direct' AND $observation_type <> 'direct') as keep
+            SET r.observation_type = CASE WHEN keep THEN r.observation_type ELSE $observation_type END,
+                r.game_time = CASE WHEN keep THEN r.game_time ELSE $game_time END,
+                r.notes = CASE WHEN keep THEN r.notes ELSE $notes END,
+                r.created_at = CASE WHEN keep THEN r.created_at ELSE $created_at END,
+                r.visits = COALESCE(r.visits, 0) + $visits",
+        )
+        .param("pc_id", observation.pc_id.to_string())
+        .param("location_id", observation.location_id.to_string())
+        .param("observation_type", observation.observation_type.as_key())
+        .param("game_time", observation.game_time.to_rfc3339())
+        .param("notes", observation.notes.clone().unwrap_or_default())
+        .param("created_at", observation.created_at.to_rfc3339())
+        .param("visits", i64::from(observation.visits));
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Make a PC forget a location
+    async fn delete_location_observation(
+        &self,
+        pc_id: PlayerCharacterId,
+        location_id: LocationId,
+    ) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (pc:PlayerCharacter {id: $pc_id})-[r:KNOWS_LOCATION]->(l:Location {id: $location_id})
+            DELETE r",
+        )
+        .param("pc_id", pc_id.to_string())
+        .param("location_id", location_id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        Ok(())
+    }
 }
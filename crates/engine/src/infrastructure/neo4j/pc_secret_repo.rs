@@ -0,0 +1,154 @@
+//! Neo4j PC secret repository implementation.
+//!
+//! Secrets hang off the PC keeping them:
+//! - `(PlayerCharacter)-[:HAS_SECRET]->(PcSecret {title, content, reactions, ...})`
+//!
+//! Revealed secrets are kept, with `revealed_at` set; unrevealed ones store
+//! an empty `revealed_at`. NPC reactions are stored as JSON.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use wrldbldr_domain::{PcSecret, PcSecretId, PlayerCharacterId, SecretReaction};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, PcSecretRepo, RepoError};
+
+pub struct Neo4jPcSecretRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jPcSecretRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_secret(&self, row: Row) -> Result<PcSecret, RepoError> {
+        let node: neo4rs::Node = row
+            .get("s")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: PcSecretId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let pc_id =
+            parse_typed_id(&node, "pc_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let reactions: Vec<SecretReaction> = node.get_json_or_default("reactions");
+
+        Ok(PcSecret {
+            id,
+            world_id,
+            pc_id,
+            title: node.get_string_or("title", ""),
+            content: node.get_string_or("content", ""),
+            reactions,
+            revealed_at: node
+                .get_optional_string("revealed_at")
+                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                .map(|dt| dt.with_timezone(&Utc)),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<PcSecret>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut secrets = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            secrets.push(self.row_to_secret(row)?);
+        }
+
+        Ok(secrets)
+    }
+}
+
+#[async_trait]
+impl PcSecretRepo for Neo4jPcSecretRepo {
+    async fn get(&self, id: PcSecretId) -> Result<Option<PcSecret>, RepoError> {
+        let q = query("MATCH (s:PcSecret {id: $id}) RETURN s").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save(&self, secret: &PcSecret) -> Result<(), RepoError> {
+        let reactions_json = serde_json::to_string(&secret.reactions)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+
+        let q = query(
+            "MERGE (s:PcSecret {id: $id})
+            SET s.world_id = $world_id,
+                s.pc_id = $pc_id,
+                s.title = $title,
+                s.content = $content,
+                s.reactions = $reactions,
+                s.revealed_at = $revealed_at,
+                s.created_at = $created_at,
+                s.updated_at = $updated_at
+            WITH s
+            MATCH (pc:PlayerCharacter {id: $pc_id})
+            MERGE (pc)-[:HAS_SECRET]->(s)",
+        )
+        .param("id", secret.id.to_string())
+        .param("world_id", secret.world_id.to_string())
+        .param("pc_id", secret.pc_id.to_string())
+        .param("title", secret.title.clone())
+        .param("content", secret.content.clone())
+        .param("reactions", reactions_json)
+        .param(
+            "revealed_at",
+            secret
+                .revealed_at
+                .map(|t| t.to_rfc3339())
+                .unwrap_or_default(),
+        )
+        .param("created_at", secret.created_at.to_rfc3339())
+        .param("updated_at", secret.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: PcSecretId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (s:PcSecret {id: $id})
+            DETACH DELETE s",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted PC secret: {}", id);
+        Ok(())
+    }
+
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<PcSecret>, RepoError> {
+        let q = query(
+            "MATCH (:PlayerCharacter {id: $pc_id})-[:HAS_SECRET]->(s:PcSecret)
+            RETURN s
+            ORDER BY s.created_at",
+        )
+        .param("pc_id", pc_id.to_string());
+
+        self.collect(q).await
+    }
+}
@@ -5,19 +5,20 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Node, Row};
+use neo4rs::{query, Node, Row};
 use wrldbldr_domain::*;
 
 use super::helpers::{parse_optional_typed_id, parse_typed_id, row_to_item, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{ClockPort, PlayerCharacterRepo, RepoError};
 
 pub struct Neo4jPlayerCharacterRepo {
-    graph: Graph,
+    graph: ResilientGraph,
     clock: Arc<dyn ClockPort>,
 }
 
 impl Neo4jPlayerCharacterRepo {
-    pub fn new(graph: Graph, clock: Arc<dyn ClockPort>) -> Self {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
         Self { graph, clock }
     }
 }
@@ -54,11 +55,14 @@ impl PlayerCharacterRepo for Neo4jPlayerCharacterRepo {
             .transpose()
             .map_err(|e| RepoError::Serialization(e.to_string()))?
             .unwrap_or_else(|| "{}".to_string());
+        let sheet_versions_json = serde_json::to_string(&pc.sheet_versions)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
 
         let current_region_id_str = pc
             .current_region_id
             .map(|r| r.to_string())
             .unwrap_or_default();
+        let death = pc.death.as_ref();
 
         let q = query(
             "MERGE (pc:PlayerCharacter {id: $id})
@@ -68,6 +72,7 @@ impl PlayerCharacterRepo for Neo4jPlayerCharacterRepo {
                 pc.name = $name,
                 pc.description = $description,
                 pc.sheet_data = $sheet_data,
+                pc.sheet_versions = $sheet_versions,
                 pc.current_location_id = $current_location_id,
                 pc.current_region_id = $current_region_id,
                 pc.starting_location_id = $starting_location_id,
@@ -75,18 +80,32 @@ impl PlayerCharacterRepo for Neo4jPlayerCharacterRepo {
                 pc.portrait_asset = $portrait_asset,
                 pc.is_alive = $is_alive,
                 pc.is_active = $is_active,
+                pc.death_cause = $death_cause,
+                pc.death_epitaph = $death_epitaph,
+                pc.died_at = $died_at,
+                pc.memorial_event_id = $memorial_event_id,
+                pc.resurrections = $resurrections,
+                pc.predecessor_id = $predecessor_id,
+                pc.successor_id = $successor_id,
                 pc.created_at = $created_at,
                 pc.last_active_at = $last_active_at
             ON MATCH SET
                 pc.name = $name,
                 pc.description = $description,
                 pc.sheet_data = $sheet_data,
+                pc.sheet_versions = $sheet_versions,
                 pc.current_location_id = $current_location_id,
                 pc.current_region_id = $current_region_id,
                 pc.sprite_asset = $sprite_asset,
                 pc.portrait_asset = $portrait_asset,
                 pc.is_alive = $is_alive,
                 pc.is_active = $is_active,
+                pc.death_cause = $death_cause,
+                pc.death_epitaph = $death_epitaph,
+                pc.died_at = $died_at,
+                pc.memorial_event_id = $memorial_event_id,
+                pc.resurrections = $resurrections,
+                pc.successor_id = $successor_id,
                 pc.last_active_at = $last_active_at
             WITH pc
             MATCH (w:World {id: $world_id})
@@ -101,6 +120,7 @@ impl PlayerCharacterRepo for Neo4jPlayerCharacterRepo {
         .param("name", pc.name.clone())
         .param("description", pc.description.clone().unwrap_or_default())
         .param("sheet_data", sheet_data_json)
+        .param("sheet_versions", sheet_versions_json)
         .param("current_location_id", pc.current_location_id.to_string())
         .param("current_region_id", current_region_id_str)
         .param("starting_location_id", pc.starting_location_id.to_string())
@@ -111,6 +131,36 @@ impl PlayerCharacterRepo for Neo4jPlayerCharacterRepo {
         )
         .param("is_alive", pc.is_alive)
         .param("is_active", pc.is_active)
+        .param(
+            "death_cause",
+            death.map(|d| d.cause.clone()).unwrap_or_default(),
+        )
+        .param(
+            "death_epitaph",
+            death.and_then(|d| d.epitaph.clone()).unwrap_or_default(),
+        )
+        .param(
+            "died_at",
+            death.map(|d| d.died_at.to_rfc3339()).unwrap_or_default(),
+        )
+        .param(
+            "memorial_event_id",
+            death
+                .and_then(|d| d.memorial_event_id)
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param("resurrections", i64::from(pc.resurrections))
+        .param(
+            "predecessor_id",
+            pc.predecessor_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param(
+            "successor_id",
+            pc.successor_id.map(|id| id.to_string()).unwrap_or_default(),
+        )
         .param("created_at", pc.created_at.to_rfc3339())
         .param("last_active_at", pc.last_active_at.to_rfc3339());
 
@@ -124,8 +174,17 @@ impl PlayerCharacterRepo for Neo4jPlayerCharacterRepo {
 
     /// Delete a player character
     async fn delete(&self, id: PlayerCharacterId) -> Result<(), RepoError> {
-        let q = query("MATCH (pc:PlayerCharacter {id: $id}) DETACH DELETE pc")
-            .param("id", id.to_string());
+        // Properties, companion records, injuries and secrets belong to the
+        // PC and go with them
+        let q = query(
+            "MATCH (pc:PlayerCharacter {id: $id})
+            OPTIONAL MATCH (pc)-[:OWNS_PROPERTY]->(p:Property)
+            OPTIONAL MATCH (pc)-[:HAS_COMPANION]->(k:Companion)
+            OPTIONAL MATCH (pc)-[:HAS_INJURY]->(i:Injury)
+            OPTIONAL MATCH (pc)-[:HAS_SECRET]->(s:PcSecret)
+            DETACH DELETE p, k, i, s, pc",
+        )
+        .param("id", id.to_string());
 
         self.graph
             .run(q)
@@ -171,7 +230,7 @@ impl PlayerCharacterRepo for Neo4jPlayerCharacterRepo {
         let q = query(
             "MATCH (pc:PlayerCharacter {user_id: $user_id})-[:IN_WORLD]->(w:World {id: $world_id})
             RETURN pc
-            ORDER BY pc.last_active_at DESC
+            ORDER BY pc.is_alive DESC, pc.last_active_at DESC
             LIMIT 1",
         )
         .param("user_id", user_id)
@@ -386,6 +445,176 @@ impl PlayerCharacterRepo for Neo4jPlayerCharacterRepo {
         tracing::info!(pc_id = %id, stat = %stat, modifier = %modifier, new_value = %new_value, "Modified stat");
         Ok(())
     }
+
+    /// Execute a trade in one transaction.
+    /// Ownership and balances are checked inside the transaction, so a PC
+    /// can't give away an item or coins they lost since the offer was made.
+    async fn execute_trade(
+        &self,
+        offer: &TradeOffer,
+        currency_field: Option<String>,
+    ) -> Result<(), RepoError> {
+        let currency_moves = offer.offered.currency > 0 || offer.requested.currency > 0;
+        let currency_field = match (currency_moves, currency_field.as_deref()) {
+            (false, _) => None,
+            (true, Some(field)) => Some(field),
+            (true, None) => {
+                return Err(RepoError::ConstraintViolation(
+                    "This game system doesn't track currency".to_string(),
+                ))
+            }
+        };
+
+        let mut txn = self
+            .graph
+            .start_txn()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        // (giver, receiver, what the giver hands over)
+        let legs = [
+            (offer.from_pc, offer.to_pc, &offer.offered),
+            (offer.to_pc, offer.from_pc, &offer.requested),
+        ];
+
+        // Step 1: Check each giver still owns what they're giving
+        for (giver, _, side) in legs {
+            if side.item_ids.is_empty() {
+                continue;
+            }
+            let item_ids: Vec<String> = side.item_ids.iter().map(|id| id.to_string()).collect();
+            let check_q = query(
+                "MATCH (pc:PlayerCharacter {id: $pc_id})-[:POSSESSES]->(i:Item)
+                WHERE i.id IN $item_ids
+                RETURN count(DISTINCT i) as owned",
+            )
+            .param("pc_id", giver.to_string())
+            .param("item_ids", item_ids.clone());
+
+            let mut result = txn
+                .execute(check_q)
+                .await
+                .map_err(|e| RepoError::Database(e.to_string()))?;
+            let owned: i64 = match result
+                .next(txn.handle())
+                .await
+                .map_err(|e| RepoError::Database(e.to_string()))?
+            {
+                Some(row) => row.get("owned").unwrap_or(0),
+                None => 0,
+            };
+            if owned != item_ids.len() as i64 {
+                txn.rollback()
+                    .await
+                    .map_err(|e| RepoError::Database(e.to_string()))?;
+                return Err(RepoError::ConstraintViolation(
+                    "An item in the trade is no longer held by its owner".to_string(),
+                ));
+            }
+        }
+
+        // Step 2: Read both sheets and apply the currency changes in Rust
+        let mut sheets = Vec::new();
+        if let Some(field) = currency_field {
+            for pc_id in [offer.from_pc, offer.to_pc] {
+                let read_q = query(
+                    "MATCH (pc:PlayerCharacter {id: $id})
+                    RETURN coalesce(pc.sheet_data, '{}') as sheet_data",
+                )
+                .param("id", pc_id.to_string());
+                let mut result = txn
+                    .execute(read_q)
+                    .await
+                    .map_err(|e| RepoError::Database(e.to_string()))?;
+                let Some(row) = result
+                    .next(txn.handle())
+                    .await
+                    .map_err(|e| RepoError::Database(e.to_string()))?
+                else {
+                    txn.rollback()
+                        .await
+                        .map_err(|e| RepoError::Database(e.to_string()))?;
+                    return Err(RepoError::NotFound);
+                };
+                let json: String = row.get("sheet_data").unwrap_or_else(|_| "{}".to_string());
+                let sheet: CharacterSheetData = if json.is_empty() || json == "{}" {
+                    CharacterSheetData::default()
+                } else {
+                    serde_json::from_str(&json).map_err(|e| {
+                        RepoError::Serialization(format!("Invalid sheet_data: {}", e))
+                    })?
+                };
+                sheets.push((pc_id, sheet));
+            }
+
+            let net_to_sender = offer.requested.currency as i64 - offer.offered.currency as i64;
+            for (pc_id, sheet) in sheets.iter_mut() {
+                let change = if *pc_id == offer.from_pc {
+                    net_to_sender
+                } else {
+                    -net_to_sender
+                };
+                let balance = sheet.get_number(field).unwrap_or(0) as i64 + change;
+                if balance < 0 {
+                    txn.rollback()
+                        .await
+                        .map_err(|e| RepoError::Database(e.to_string()))?;
+                    return Err(RepoError::ConstraintViolation(format!(
+                        "Not enough {} for the trade",
+                        field
+                    )));
+                }
+                let balance = i32::try_from(balance).map_err(|_| {
+                    RepoError::ConstraintViolation(format!("{} balance out of range", field))
+                })?;
+                sheet.set(field, FieldValue::Number(balance));
+            }
+        }
+
+        // Step 3: Move items (unequipping them from the giver)
+        for (giver, receiver, side) in legs {
+            if side.item_ids.is_empty() {
+                continue;
+            }
+            let item_ids: Vec<String> = side.item_ids.iter().map(|id| id.to_string()).collect();
+            let move_q = query(
+                "MATCH (giver:PlayerCharacter {id: $giver_id})-[p:POSSESSES]->(i:Item)
+                WHERE i.id IN $item_ids
+                MATCH (receiver:PlayerCharacter {id: $receiver_id})
+                OPTIONAL MATCH (i)-[e:EQUIPPED_BY]->(giver)
+                DELETE p, e
+                MERGE (receiver)-[:POSSESSES]->(i)",
+            )
+            .param("giver_id", giver.to_string())
+            .param("receiver_id", receiver.to_string())
+            .param("item_ids", item_ids);
+            txn.run(move_q)
+                .await
+                .map_err(|e| RepoError::Database(e.to_string()))?;
+        }
+
+        // Step 4: Write the updated sheets
+        for (pc_id, sheet) in sheets {
+            let json = serde_json::to_string(&sheet)
+                .map_err(|e| RepoError::Serialization(e.to_string()))?;
+            let write_q = query(
+                "MATCH (pc:PlayerCharacter {id: $id})
+                SET pc.sheet_data = $sheet_data",
+            )
+            .param("id", pc_id.to_string())
+            .param("sheet_data", json);
+            txn.run(write_q)
+                .await
+                .map_err(|e| RepoError::Database(e.to_string()))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::info!(trade_id = %offer.id, from = %offer.from_pc, to = %offer.to_pc, "Trade executed");
+        Ok(())
+    }
 }
 
 // =============================================================================
@@ -417,6 +646,8 @@ fn row_to_player_character(row: Row) -> Result<PlayerCharacter, RepoError> {
         serde_json::from_str(&sheet_data_str)
             .map_err(|e| RepoError::Serialization(format!("Invalid sheet_data: {}", e)))?
     };
+    let sheet_versions = serde_json::from_str(&node.get_string_or("sheet_versions", "[]"))
+        .map_err(|e| RepoError::Serialization(format!("Invalid sheet_versions: {}", e)))?;
 
     let current_location_id: LocationId = parse_typed_id(&node, "current_location_id")
         .map_err(|e| RepoError::Database(e.to_string()))?;
@@ -446,6 +677,28 @@ fn row_to_player_character(row: Row) -> Result<PlayerCharacter, RepoError> {
     let is_alive: bool = node.get("is_alive").unwrap_or(true);
     let is_active: bool = node.get("is_active").unwrap_or(true);
 
+    // Death record, present only while the PC is dead
+    let died_at = node
+        .get_optional_string("died_at")
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+        .map(|dt| dt.with_timezone(&chrono::Utc));
+    let death = match died_at {
+        Some(died_at) if !is_alive => Some(PcDeath {
+            cause: node.get_string_or("death_cause", ""),
+            epitaph: node.get_optional_string("death_epitaph"),
+            died_at,
+            memorial_event_id: parse_optional_typed_id(&node, "memorial_event_id")
+                .map_err(|e| RepoError::Database(e.to_string()))?,
+        }),
+        _ => None,
+    };
+    let resurrections = node.get_i64_or("resurrections", 0).max(0) as u32;
+    let predecessor_id: Option<PlayerCharacterId> =
+        parse_optional_typed_id(&node, "predecessor_id")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+    let successor_id: Option<PlayerCharacterId> = parse_optional_typed_id(&node, "successor_id")
+        .map_err(|e| RepoError::Database(e.to_string()))?;
+
     Ok(PlayerCharacter {
         id,
         user_id,
@@ -453,6 +706,7 @@ fn row_to_player_character(row: Row) -> Result<PlayerCharacter, RepoError> {
         name,
         description,
         sheet_data,
+        sheet_versions,
         current_location_id,
         current_region_id,
         starting_location_id,
@@ -460,6 +714,10 @@ fn row_to_player_character(row: Row) -> Result<PlayerCharacter, RepoError> {
         portrait_asset,
         is_alive,
         is_active,
+        death,
+        resurrections,
+        predecessor_id,
+        successor_id,
         created_at,
         last_active_at,
     })
@@ -0,0 +1,180 @@
+//! Neo4j progress clock repository implementation.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use wrldbldr_domain::{ClockKind, PlayerCharacterId, ProgressClock, ProgressClockId, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, ProgressClockRepo, RepoError};
+
+pub struct Neo4jProgressClockRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jProgressClockRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_clock(&self, row: Row) -> Result<ProgressClock, RepoError> {
+        let node: neo4rs::Node = row
+            .get("c")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: ProgressClockId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let kind: ClockKind = node
+            .get_string_or("kind", "threat")
+            .parse()
+            .unwrap_or(ClockKind::Unknown);
+        let segments = node.get_i64_or("segments", 4).clamp(0, u8::MAX as i64) as u8;
+        let filled = node.get_i64_or("filled", 0).clamp(0, segments as i64) as u8;
+
+        Ok(ProgressClock {
+            id,
+            world_id,
+            name: node.get_string_or("name", ""),
+            description: node.get_optional_string("description"),
+            kind,
+            subject: node.get_optional_string("subject"),
+            segments,
+            filled,
+            visible_to_players: node.get_bool_or("visible_to_players", false),
+            owner_pc_id: node
+                .get_optional_string("owner_pc_id")
+                .and_then(|id| uuid::Uuid::parse_str(&id).ok())
+                .map(PlayerCharacterId::from_uuid),
+            conflicts_with: node
+                .get_optional_string("conflicts_with")
+                .and_then(|id| uuid::Uuid::parse_str(&id).ok())
+                .map(ProgressClockId::from_uuid),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+}
+
+#[async_trait]
+impl ProgressClockRepo for Neo4jProgressClockRepo {
+    async fn get(&self, id: ProgressClockId) -> Result<Option<ProgressClock>, RepoError> {
+        let q = query("MATCH (c:ProgressClock {id: $id}) RETURN c").param("id", id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        if let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            Ok(Some(self.row_to_clock(row)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn save(&self, clock: &ProgressClock) -> Result<(), RepoError> {
+        let q = query(
+            "MERGE (c:ProgressClock {id: $id})
+            SET c.world_id = $world_id,
+                c.name = $name,
+                c.description = $description,
+                c.kind = $kind,
+                c.subject = $subject,
+                c.segments = $segments,
+                c.filled = $filled,
+                c.visible_to_players = $visible_to_players,
+                c.owner_pc_id = $owner_pc_id,
+                c.conflicts_with = $conflicts_with,
+                c.created_at = $created_at,
+                c.updated_at = $updated_at
+            WITH c
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_CLOCK]->(c)",
+        )
+        .param("id", clock.id.to_string())
+        .param("world_id", clock.world_id.to_string())
+        .param("name", clock.name.clone())
+        .param("description", clock.description.clone().unwrap_or_default())
+        .param("kind", clock.kind.to_string())
+        .param("subject", clock.subject.clone().unwrap_or_default())
+        .param("segments", clock.segments as i64)
+        .param("filled", clock.filled as i64)
+        .param("visible_to_players", clock.visible_to_players)
+        .param(
+            "owner_pc_id",
+            clock
+                .owner_pc_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param(
+            "conflicts_with",
+            clock
+                .conflicts_with
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param("created_at", clock.created_at.to_rfc3339())
+        .param("updated_at", clock.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: ProgressClockId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (c:ProgressClock {id: $id})
+            DETACH DELETE c",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted progress clock: {}", id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<ProgressClock>, RepoError> {
+        let q = query(
+            "MATCH (w:World {id: $world_id})-[:HAS_CLOCK]->(c:ProgressClock)
+            RETURN c
+            ORDER BY c.created_at",
+        )
+        .param("world_id", world_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut clocks = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            clocks.push(self.row_to_clock(row)?);
+        }
+
+        Ok(clocks)
+    }
+}
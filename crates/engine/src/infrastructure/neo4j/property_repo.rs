@@ -0,0 +1,162 @@
+//! Neo4j property repository implementation.
+//!
+//! Properties hang off the PC that owns them:
+//! - `(PlayerCharacter)-[:OWNS_PROPERTY]->(Property {site, upkeep_per_day, staff, ...})`
+//!
+//! `site` and `staff` are stored as JSON. The site is kept as an id rather
+//! than an edge, like market modifier locations; a property whose location
+//! is deleted keeps its owner and upkeep but loses its site name.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use wrldbldr_domain::{PlayerCharacterId, Property, PropertyId, PropertySite, PropertyStaff};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, PropertyRepo, RepoError};
+
+pub struct Neo4jPropertyRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jPropertyRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_property(&self, row: Row) -> Result<Property, RepoError> {
+        let node: neo4rs::Node = row
+            .get("p")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: PropertyId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let owner_id =
+            parse_typed_id(&node, "owner_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let site: PropertySite = node
+            .get_json("site")
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let staff: Vec<PropertyStaff> = node.get_json_or_default("staff");
+
+        Ok(Property {
+            id,
+            world_id,
+            owner_id,
+            name: node.get_string_or("name", ""),
+            site,
+            upkeep_per_day: u32::try_from(node.get_i64_or("upkeep_per_day", 0)).unwrap_or(0),
+            upkeep_paid_through: node.get_datetime_or("upkeep_paid_through", fallback),
+            staff,
+            notes: node.get_string_or("notes", ""),
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn collect(&self, q: neo4rs::Query) -> Result<Vec<Property>, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut properties = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            properties.push(self.row_to_property(row)?);
+        }
+
+        Ok(properties)
+    }
+}
+
+#[async_trait]
+impl PropertyRepo for Neo4jPropertyRepo {
+    async fn get(&self, id: PropertyId) -> Result<Option<Property>, RepoError> {
+        let q = query("MATCH (p:Property {id: $id}) RETURN p").param("id", id.to_string());
+
+        Ok(self.collect(q).await?.pop())
+    }
+
+    async fn save(&self, property: &Property) -> Result<(), RepoError> {
+        let site_json = serde_json::to_string(&property.site)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let staff_json = serde_json::to_string(&property.staff)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+
+        let q = query(
+            "MERGE (p:Property {id: $id})
+            SET p.world_id = $world_id,
+                p.owner_id = $owner_id,
+                p.name = $name,
+                p.site = $site,
+                p.upkeep_per_day = $upkeep_per_day,
+                p.upkeep_paid_through = $upkeep_paid_through,
+                p.staff = $staff,
+                p.notes = $notes,
+                p.created_at = $created_at,
+                p.updated_at = $updated_at
+            WITH p
+            MATCH (pc:PlayerCharacter {id: $owner_id})
+            MERGE (pc)-[:OWNS_PROPERTY]->(p)",
+        )
+        .param("id", property.id.to_string())
+        .param("world_id", property.world_id.to_string())
+        .param("owner_id", property.owner_id.to_string())
+        .param("name", property.name.clone())
+        .param("site", site_json)
+        .param("upkeep_per_day", property.upkeep_per_day as i64)
+        .param(
+            "upkeep_paid_through",
+            property.upkeep_paid_through.to_rfc3339(),
+        )
+        .param("staff", staff_json)
+        .param("notes", property.notes.clone())
+        .param("created_at", property.created_at.to_rfc3339())
+        .param("updated_at", property.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: PropertyId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (p:Property {id: $id})
+            DETACH DELETE p",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted property: {}", id);
+        Ok(())
+    }
+
+    async fn list_for_owner(
+        &self,
+        owner_id: PlayerCharacterId,
+    ) -> Result<Vec<Property>, RepoError> {
+        let q = query(
+            "MATCH (:PlayerCharacter {id: $owner_id})-[:OWNS_PROPERTY]->(p:Property)
+            RETURN p
+            ORDER BY p.created_at",
+        )
+        .param("owner_id", owner_id.to_string());
+
+        self.collect(q).await
+    }
+}
@@ -3,20 +3,21 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Row};
+use neo4rs::{query, Row};
 use wrldbldr_domain::*;
 
 use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{ClockPort, RegionStateRepo, RepoError};
 
 /// Repository for RegionState operations.
 pub struct Neo4jRegionStateRepo {
-    graph: Graph,
+    graph: ResilientGraph,
     clock: Arc<dyn ClockPort>,
 }
 
 impl Neo4jRegionStateRepo {
-    pub fn new(graph: Graph, clock: Arc<dyn ClockPort>) -> Self {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
         Self { graph, clock }
     }
 
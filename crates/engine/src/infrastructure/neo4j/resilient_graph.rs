@@ -0,0 +1,100 @@
+//! Neo4j graph handle guarded by a circuit breaker.
+//!
+//! Repositories query through `ResilientGraph` instead of `neo4rs::Graph`.
+//! When Neo4j is down, calls fail fast once the breaker opens instead of
+//! each one waiting out the driver's connection backoff.
+
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures_util::{Stream, TryStreamExt};
+use neo4rs::{Error, Graph, Neo4jClientErrorKind, Neo4jErrorKind, Query, Row, Txn};
+
+use crate::infrastructure::circuit_breaker::{
+    CircuitBreaker, CircuitBreakerConfig, CircuitOpenError,
+};
+
+/// Rows returned by [`ResilientGraph::execute`].
+///
+/// Wraps the driver's row stream (which neo4rs doesn't export by name) so
+/// call sites keep using `rows.next().await`.
+pub struct GraphRows {
+    stream: Pin<Box<dyn Stream<Item = Result<Row, Error>> + Send>>,
+}
+
+impl GraphRows {
+    /// Next row, or `None` once the result is exhausted
+    pub async fn next(&mut self) -> Result<Option<Row>, Error> {
+        self.stream.try_next().await
+    }
+}
+
+/// A `neo4rs::Graph` whose queries go through a circuit breaker
+#[derive(Clone)]
+pub struct ResilientGraph {
+    graph: Graph,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl ResilientGraph {
+    pub fn new(graph: Graph, breaker: Arc<CircuitBreaker>) -> Self {
+        Self { graph, breaker }
+    }
+
+    /// Run a query and stream its rows.
+    pub async fn execute(&self, q: Query) -> Result<GraphRows, Error> {
+        let rows = self
+            .breaker
+            .call(|| self.graph.execute(q), is_service_failure)
+            .await
+            .map_err(circuit_open)??;
+        Ok(GraphRows {
+            stream: Box::pin(rows.into_stream().into_stream()),
+        })
+    }
+
+    /// Run a query that returns no rows.
+    pub async fn run(&self, q: Query) -> Result<(), Error> {
+        self.breaker
+            .call(|| self.graph.run(q), is_service_failure)
+            .await
+            .map_err(circuit_open)?
+    }
+
+    /// Start an explicit transaction.
+    pub async fn start_txn(&self) -> Result<Txn, Error> {
+        self.breaker
+            .call(|| self.graph.start_txn(), is_service_failure)
+            .await
+            .map_err(circuit_open)?
+    }
+}
+
+impl From<Graph> for ResilientGraph {
+    /// Guard with a default-configured breaker of its own.
+    fn from(graph: Graph) -> Self {
+        Self::new(
+            graph,
+            Arc::new(CircuitBreaker::new(CircuitBreakerConfig::default())),
+        )
+    }
+}
+
+/// Whether an error means Neo4j itself is unavailable, as opposed to the
+/// query being rejected (constraint violations, syntax errors, ...).
+fn is_service_failure(error: &Error) -> bool {
+    match error {
+        Error::IOError { .. } | Error::ConnectionError => true,
+        Error::Neo4j(e) => matches!(
+            e.kind(),
+            Neo4jErrorKind::Transient
+                | Neo4jErrorKind::Database
+                | Neo4jErrorKind::Client(Neo4jClientErrorKind::SessionExpired)
+        ),
+        _ => false,
+    }
+}
+
+fn circuit_open(error: CircuitOpenError) -> Error {
+    Error::UnexpectedMessage(format!("Circuit breaker is open: {}", error))
+}
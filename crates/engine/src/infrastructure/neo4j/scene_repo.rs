@@ -11,10 +11,11 @@
 //! Entry conditions remain as JSON (acceptable per ADR - complex nested non-relational)
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Row};
+use neo4rs::{query, Row};
 use wrldbldr_domain::*;
 
 use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{RepoError, SceneRepo};
 
 // =============================================================================
@@ -132,11 +133,11 @@ impl SceneConditionStored {
 
 /// Repository for Scene operations.
 pub struct Neo4jSceneRepo {
-    graph: Graph,
+    graph: ResilientGraph,
 }
 
 impl Neo4jSceneRepo {
-    pub fn new(graph: Graph) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 
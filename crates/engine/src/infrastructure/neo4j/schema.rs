@@ -24,6 +24,28 @@ pub async fn ensure_schema(graph: &Graph) -> Result<(), neo4rs::Error> {
         ))
         .await?;
 
+    // Indexes on EntityTags for tag lookups by entity and by world.
+    graph
+        .run(query(
+            "CREATE INDEX entity_tags_entity IF NOT EXISTS
+             FOR (t:EntityTags) ON (t.entity_type, t.entity_id)",
+        ))
+        .await?;
+    graph
+        .run(query(
+            "CREATE INDEX entity_tags_world IF NOT EXISTS
+             FOR (t:EntityTags) ON (t.world_id)",
+        ))
+        .await?;
+
+    // Index on EntityFieldValues for custom field lookups by entity.
+    graph
+        .run(query(
+            "CREATE INDEX entity_field_values_entity IF NOT EXISTS
+             FOR (v:EntityFieldValues) ON (v.entity_type, v.entity_id)",
+        ))
+        .await?;
+
     tracing::info!("Neo4j schema initialized (constraints and indexes ensured)");
     Ok(())
 }
@@ -4,19 +4,20 @@
 //! - `(World)-[:CONTAINS_SKILL]->(Skill)`
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Row};
+use neo4rs::{query, Row};
 use wrldbldr_domain::{Skill, SkillCategory, SkillId, WorldId};
 
 use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{RepoError, SkillRepo};
 
 /// Repository for Skill operations.
 pub struct Neo4jSkillRepo {
-    graph: Graph,
+    graph: ResilientGraph,
 }
 
 impl Neo4jSkillRepo {
-    pub fn new(graph: Graph) -> Self {
+    pub fn new(graph: ResilientGraph) -> Self {
         Self { graph }
     }
 
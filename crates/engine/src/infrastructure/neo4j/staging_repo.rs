@@ -4,21 +4,22 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use neo4rs::{query, Graph, Node, Row};
+use neo4rs::{query, Node, Row};
 
 use wrldbldr_domain::MoodState;
 use wrldbldr_domain::*;
 
 use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{ClockPort, RepoError, StagingRepo};
 
 pub struct Neo4jStagingRepo {
-    graph: Graph,
+    graph: ResilientGraph,
     clock: std::sync::Arc<dyn ClockPort>,
 }
 
 impl Neo4jStagingRepo {
-    pub fn new(graph: Graph, clock: std::sync::Arc<dyn ClockPort>) -> Self {
+    pub fn new(graph: ResilientGraph, clock: std::sync::Arc<dyn ClockPort>) -> Self {
         Self { graph, clock }
     }
 }
@@ -364,6 +365,22 @@ impl StagingRepo for Neo4jStagingRepo {
         Ok(())
     }
 
+    /// Clear a region's current staging, leaving it unstaged.
+    async fn deactivate_staging(&self, region_id: RegionId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (r:Region {id: $region_id})-[old:CURRENT_STAGING]->(s:Staging)
+            SET s.is_active = false
+            DELETE old",
+        )
+        .param("region_id", region_id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        Ok(())
+    }
+
     /// Get staging history for a region (most recent first).
     async fn get_staging_history(
         &self,
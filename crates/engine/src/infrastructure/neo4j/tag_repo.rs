@@ -0,0 +1,323 @@
+//! Neo4j tag repository implementation.
+//!
+//! Each tagged entity gets one `EntityTags` node holding its tags as a list,
+//! keyed by entity type and id so no entity repository has to know about
+//! tags:
+//! - `(World)-[:HAS_ENTITY_TAGS]->(EntityTags {entity_type, entity_id, tags})`
+//! - `(World)-[:HAS_SAVED_FILTER]->(SavedFilter)`
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use uuid::Uuid;
+use wrldbldr_domain::{EntityType, SavedFilter, SavedFilterId, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, RepoError, TagRepo, TagUsage};
+
+pub struct Neo4jTagRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jTagRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_filter(&self, row: Row) -> Result<SavedFilter, RepoError> {
+        let node: neo4rs::Node = row
+            .get("f")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: SavedFilterId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let entity_type: EntityType = node
+            .get_string_or("entity_type", "unknown")
+            .parse()
+            .unwrap_or(EntityType::Unknown);
+        let mut tags: Vec<String> = node.get("tags").unwrap_or_default();
+        tags.sort();
+
+        Ok(SavedFilter {
+            id,
+            world_id,
+            user_id: node.get_string_or("user_id", ""),
+            name: node.get_string_or("name", ""),
+            entity_type,
+            tags,
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+
+    async fn count(&self, q: neo4rs::Query) -> Result<u32, RepoError> {
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let count = match result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            Some(row) => row.get::<i64>("changed").unwrap_or(0),
+            None => 0,
+        };
+        Ok(count.max(0) as u32)
+    }
+
+    /// Drop tag nodes left empty by a rename or delete
+    async fn remove_empty(&self, world_id: WorldId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (t:EntityTags {world_id: $world_id})
+            WHERE size(t.tags) = 0
+            DETACH DELETE t",
+        )
+        .param("world_id", world_id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl TagRepo for Neo4jTagRepo {
+    async fn get_tags(
+        &self,
+        entity_type: EntityType,
+        entity_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<String>>, RepoError> {
+        let ids: Vec<String> = entity_ids.iter().map(|id| id.to_string()).collect();
+        let q = query(
+            "MATCH (t:EntityTags {entity_type: $entity_type})
+            WHERE t.entity_id IN $entity_ids
+            RETURN t.entity_id AS entity_id, t.tags AS tags",
+        )
+        .param("entity_type", entity_type.as_str())
+        .param("entity_ids", ids);
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut tags = HashMap::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            let Ok(entity_id) = row
+                .get::<String>("entity_id")
+                .map_err(|e| e.to_string())
+                .and_then(|id| Uuid::parse_str(&id).map_err(|e| e.to_string()))
+            else {
+                continue;
+            };
+            let mut entity_tags: Vec<String> = row.get("tags").unwrap_or_default();
+            entity_tags.sort();
+            tags.insert(entity_id, entity_tags);
+        }
+
+        Ok(tags)
+    }
+
+    async fn set_tags(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        tags: &[String],
+    ) -> Result<(), RepoError> {
+        let q = if tags.is_empty() {
+            query(
+                "MATCH (t:EntityTags {entity_type: $entity_type, entity_id: $entity_id})
+                DETACH DELETE t",
+            )
+        } else {
+            query(
+                "MERGE (t:EntityTags {entity_type: $entity_type, entity_id: $entity_id})
+                SET t.world_id = $world_id,
+                    t.tags = $tags
+                WITH t
+                MATCH (w:World {id: $world_id})
+                MERGE (w)-[:HAS_ENTITY_TAGS]->(t)",
+            )
+        }
+        .param("world_id", world_id.to_string())
+        .param("entity_type", entity_type.as_str())
+        .param("entity_id", entity_id.to_string())
+        .param("tags", tags.to_vec());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn list_world_tags(&self, world_id: WorldId) -> Result<Vec<TagUsage>, RepoError> {
+        let q = query(
+            "MATCH (t:EntityTags {world_id: $world_id})
+            UNWIND t.tags AS tag
+            RETURN tag, count(*) AS count
+            ORDER BY tag",
+        )
+        .param("world_id", world_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut usage = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            if let Ok(tag) = row.get::<String>("tag") {
+                let count = row.get::<i64>("count").unwrap_or(0).max(0) as u32;
+                usage.push(TagUsage { tag, count });
+            }
+        }
+
+        Ok(usage)
+    }
+
+    async fn rename_tag(&self, world_id: WorldId, from: &str, to: &str) -> Result<u32, RepoError> {
+        let q = query(
+            "MATCH (t:EntityTags {world_id: $world_id})
+            WHERE $from IN t.tags
+            SET t.tags = [tag IN t.tags WHERE tag <> $from AND tag <> $to] + $to
+            RETURN count(t) AS changed",
+        )
+        .param("world_id", world_id.to_string())
+        .param("from", from)
+        .param("to", to);
+
+        self.count(q).await
+    }
+
+    async fn delete_tag(&self, world_id: WorldId, tag: &str) -> Result<u32, RepoError> {
+        let q = query(
+            "MATCH (t:EntityTags {world_id: $world_id})
+            WHERE $tag IN t.tags
+            SET t.tags = [existing IN t.tags WHERE existing <> $tag]
+            RETURN count(t) AS changed",
+        )
+        .param("world_id", world_id.to_string())
+        .param("tag", tag);
+
+        let changed = self.count(q).await?;
+        self.remove_empty(world_id).await?;
+        Ok(changed)
+    }
+
+    async fn get_saved_filter(&self, id: SavedFilterId) -> Result<Option<SavedFilter>, RepoError> {
+        let q = query("MATCH (f:SavedFilter {id: $id}) RETURN f").param("id", id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        if let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            Ok(Some(self.row_to_filter(row)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn save_saved_filter(&self, filter: &SavedFilter) -> Result<(), RepoError> {
+        let q = query(
+            "MERGE (f:SavedFilter {id: $id})
+            SET f.world_id = $world_id,
+                f.user_id = $user_id,
+                f.name = $name,
+                f.entity_type = $entity_type,
+                f.tags = $tags,
+                f.created_at = $created_at,
+                f.updated_at = $updated_at
+            WITH f
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_SAVED_FILTER]->(f)",
+        )
+        .param("id", filter.id.to_string())
+        .param("world_id", filter.world_id.to_string())
+        .param("user_id", filter.user_id.clone())
+        .param("name", filter.name.clone())
+        .param("entity_type", filter.entity_type.as_str())
+        .param("tags", filter.tags.clone())
+        .param("created_at", filter.created_at.to_rfc3339())
+        .param("updated_at", filter.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete_saved_filter(&self, id: SavedFilterId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (f:SavedFilter {id: $id})
+            DETACH DELETE f",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted saved filter: {}", id);
+        Ok(())
+    }
+
+    async fn list_saved_filters(
+        &self,
+        world_id: WorldId,
+        user_id: &str,
+    ) -> Result<Vec<SavedFilter>, RepoError> {
+        let q = query(
+            "MATCH (w:World {id: $world_id})-[:HAS_SAVED_FILTER]->(f:SavedFilter {user_id: $user_id})
+            RETURN f
+            ORDER BY f.name",
+        )
+        .param("world_id", world_id.to_string())
+        .param("user_id", user_id);
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut filters = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            filters.push(self.row_to_filter(row)?);
+        }
+
+        Ok(filters)
+    }
+}
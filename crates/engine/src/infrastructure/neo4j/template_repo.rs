@@ -0,0 +1,148 @@
+//! Neo4j entity template repository implementation.
+//!
+//! A template's body (the NPC or region blueprint) is stored as JSON; it is
+//! only ever read and written whole:
+//! - `(World)-[:HAS_TEMPLATE]->(EntityTemplate {name, body})`
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use neo4rs::{query, Row};
+use wrldbldr_domain::{EntityTemplate, EntityTemplateId, TemplateBody, WorldId};
+
+use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
+use crate::infrastructure::ports::{ClockPort, RepoError, TemplateRepo};
+
+pub struct Neo4jTemplateRepo {
+    graph: ResilientGraph,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl Neo4jTemplateRepo {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
+        Self { graph, clock }
+    }
+
+    fn row_to_template(&self, row: Row) -> Result<EntityTemplate, RepoError> {
+        let node: neo4rs::Node = row
+            .get("t")
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+        let fallback = self.clock.now();
+
+        let id: EntityTemplateId =
+            parse_typed_id(&node, "id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let world_id: WorldId =
+            parse_typed_id(&node, "world_id").map_err(|e| RepoError::Database(e.to_string()))?;
+        let body_json = node.get_string_or("body", "");
+        let body: TemplateBody = serde_json::from_str(&body_json)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+
+        Ok(EntityTemplate {
+            id,
+            world_id,
+            name: node.get_string_or("name", ""),
+            description: node.get_optional_string("description"),
+            body,
+            created_at: node.get_datetime_or("created_at", fallback),
+            updated_at: node.get_datetime_or("updated_at", fallback),
+        })
+    }
+}
+
+#[async_trait]
+impl TemplateRepo for Neo4jTemplateRepo {
+    async fn get(&self, id: EntityTemplateId) -> Result<Option<EntityTemplate>, RepoError> {
+        let q = query("MATCH (t:EntityTemplate {id: $id}) RETURN t").param("id", id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        if let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            Ok(Some(self.row_to_template(row)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn save(&self, template: &EntityTemplate) -> Result<(), RepoError> {
+        let body_json = serde_json::to_string(&template.body)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let q = query(
+            "MERGE (t:EntityTemplate {id: $id})
+            SET t.world_id = $world_id,
+                t.name = $name,
+                t.description = $description,
+                t.body = $body,
+                t.created_at = $created_at,
+                t.updated_at = $updated_at
+            WITH t
+            MATCH (w:World {id: $world_id})
+            MERGE (w)-[:HAS_TEMPLATE]->(t)",
+        )
+        .param("id", template.id.to_string())
+        .param("world_id", template.world_id.to_string())
+        .param("name", template.name.clone())
+        .param(
+            "description",
+            template.description.clone().unwrap_or_default(),
+        )
+        .param("body", body_json)
+        .param("created_at", template.created_at.to_rfc3339())
+        .param("updated_at", template.updated_at.to_rfc3339());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))
+    }
+
+    async fn delete(&self, id: EntityTemplateId) -> Result<(), RepoError> {
+        let q = query(
+            "MATCH (t:EntityTemplate {id: $id})
+            DETACH DELETE t",
+        )
+        .param("id", id.to_string());
+
+        self.graph
+            .run(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        tracing::debug!("Deleted entity template: {}", id);
+        Ok(())
+    }
+
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<EntityTemplate>, RepoError> {
+        let q = query(
+            "MATCH (w:World {id: $world_id})-[:HAS_TEMPLATE]->(t:EntityTemplate)
+            RETURN t
+            ORDER BY t.name",
+        )
+        .param("world_id", world_id.to_string());
+
+        let mut result = self
+            .graph
+            .execute(q)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        let mut templates = Vec::new();
+        while let Some(row) = result
+            .next()
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?
+        {
+            templates.push(self.row_to_template(row)?);
+        }
+
+        Ok(templates)
+    }
+}
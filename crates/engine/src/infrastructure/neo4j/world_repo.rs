@@ -3,20 +3,21 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use neo4rs::{query, Graph, Row};
+use neo4rs::{query, Row};
 use wrldbldr_domain::*;
 
 use super::helpers::{parse_typed_id, NodeExt};
+use super::resilient_graph::ResilientGraph;
 use crate::infrastructure::ports::{ClockPort, RepoError, WorldRepo};
 
 /// Repository for World aggregate operations.
 pub struct Neo4jWorldRepo {
-    graph: Graph,
+    graph: ResilientGraph,
     clock: Arc<dyn ClockPort>,
 }
 
 impl Neo4jWorldRepo {
-    pub fn new(graph: Graph, clock: Arc<dyn ClockPort>) -> Self {
+    pub fn new(graph: ResilientGraph, clock: Arc<dyn ClockPort>) -> Self {
         Self { graph, clock }
     }
 
@@ -58,6 +59,67 @@ impl Neo4jWorldRepo {
             .and_then(|s| serde_json::from_str(&s).ok())
             .unwrap_or_default();
 
+        // Parse content safety settings or use defaults
+        let content_safety: ContentSafetyConfig = node
+            .get_optional_string("content_safety")
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let typography: WorldTypography = node
+            .get_optional_string("typography")
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let theme: WorldTheme = node
+            .get_optional_string("theme")
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let features: WorldFeatures = node
+            .get_optional_string("features")
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let scripts: Vec<WorldScript> = node
+            .get_optional_string("scripts")
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let custom_fields: Vec<CustomFieldDefinition> = node
+            .get_optional_string("custom_fields")
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let tutorial: Option<TutorialScript> = node
+            .get_optional_string("tutorial")
+            .and_then(|s| serde_json::from_str(&s).ok());
+
+        let map_asset = node.get_optional_string("map_asset");
+
+        let branched_from = node
+            .get_optional_string("branched_from")
+            .and_then(|s| uuid::Uuid::parse_str(&s).ok())
+            .map(WorldId::from_uuid);
+
+        let flashback: Option<Flashback> = node
+            .get_optional_string("flashback")
+            .and_then(|s| serde_json::from_str(&s).ok());
+
+        let parallel_scenes: Vec<ParallelScene> = node
+            .get_optional_string("parallel_scenes")
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let focused_scene_id = node
+            .get_optional_string("focused_scene_id")
+            .and_then(|s| uuid::Uuid::parse_str(&s).ok())
+            .map(ParallelSceneId::from_uuid);
+
+        let spotlight: Spotlight = node
+            .get_optional_string("spotlight")
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
         Ok(World {
             id,
             name,
@@ -65,6 +127,19 @@ impl Neo4jWorldRepo {
             rule_system,
             game_time,
             time_config,
+            content_safety,
+            typography,
+            theme,
+            features,
+            scripts,
+            custom_fields,
+            tutorial,
+            map_asset,
+            branched_from,
+            flashback,
+            parallel_scenes,
+            focused_scene_id,
+            spotlight,
             created_at,
             updated_at,
         })
@@ -98,6 +173,36 @@ impl WorldRepo for Neo4jWorldRepo {
             .map_err(|e| RepoError::Serialization(e.to_string()))?;
         let time_config_json = serde_json::to_string(&world.time_config)
             .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let content_safety_json = serde_json::to_string(&world.content_safety)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let typography_json = serde_json::to_string(&world.typography)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let theme_json = serde_json::to_string(&world.theme)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let features_json = serde_json::to_string(&world.features)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let scripts_json = serde_json::to_string(&world.scripts)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let custom_fields_json = serde_json::to_string(&world.custom_fields)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let parallel_scenes_json = serde_json::to_string(&world.parallel_scenes)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let spotlight_json = serde_json::to_string(&world.spotlight)
+            .map_err(|e| RepoError::Serialization(e.to_string()))?;
+        let tutorial_json = world
+            .tutorial
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| RepoError::Serialization(e.to_string()))?
+            .unwrap_or_default();
+        let flashback_json = world
+            .flashback
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| RepoError::Serialization(e.to_string()))?
+            .unwrap_or_default();
 
         // MERGE to handle both create and update
         let q = query(
@@ -108,6 +213,19 @@ impl WorldRepo for Neo4jWorldRepo {
                 w.game_time = $game_time,
                 w.game_time_paused = $game_time_paused,
                 w.time_config = $time_config,
+                w.content_safety = $content_safety,
+                w.typography = $typography,
+                w.theme = $theme,
+                w.features = $features,
+                w.scripts = $scripts,
+                w.custom_fields = $custom_fields,
+                w.tutorial = $tutorial,
+                w.map_asset = $map_asset,
+                w.branched_from = $branched_from,
+                w.flashback = $flashback,
+                w.parallel_scenes = $parallel_scenes,
+                w.focused_scene_id = $focused_scene_id,
+                w.spotlight = $spotlight,
                 w.created_at = $created_at,
                 w.updated_at = $updated_at
             RETURN w.id as id",
@@ -119,6 +237,31 @@ impl WorldRepo for Neo4jWorldRepo {
         .param("game_time", world.game_time.current().to_rfc3339())
         .param("game_time_paused", world.game_time.is_paused())
         .param("time_config", time_config_json)
+        .param("content_safety", content_safety_json)
+        .param("typography", typography_json)
+        .param("theme", theme_json)
+        .param("features", features_json)
+        .param("scripts", scripts_json)
+        .param("custom_fields", custom_fields_json)
+        .param("tutorial", tutorial_json)
+        .param("map_asset", world.map_asset.clone().unwrap_or_default())
+        .param(
+            "branched_from",
+            world
+                .branched_from
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param("flashback", flashback_json)
+        .param("parallel_scenes", parallel_scenes_json)
+        .param(
+            "focused_scene_id",
+            world
+                .focused_scene_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        )
+        .param("spotlight", spotlight_json)
         .param("created_at", world.created_at.to_rfc3339())
         .param("updated_at", world.updated_at.to_rfc3339());
 
@@ -6,8 +6,8 @@ use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 use crate::infrastructure::ports::{
-    FinishReason, LlmError, LlmPort, LlmRequest, LlmResponse, MessageRole, TokenUsage, ToolCall,
-    ToolDefinition,
+    FinishReason, ImageData, LlmError, LlmModelInfo, LlmModelPort, LlmPort, LlmRequest,
+    LlmResponse, MessageRole, ModelPullProgress, TokenUsage, ToolCall, ToolDefinition,
 };
 
 /// Client for Ollama's OpenAI-compatible API
@@ -32,72 +32,138 @@ impl OllamaClient {
             model: model.to_string(),
         }
     }
+
+    /// Root of Ollama's native API (model registry), without the `/v1`
+    /// suffix used by the OpenAI-compatible endpoints.
+    fn native_url(&self) -> &str {
+        self.base_url.trim_end_matches("/v1")
+    }
 }
 
 #[async_trait]
 impl LlmPort for OllamaClient {
     async fn generate(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
-        let api_request = OpenAIChatRequest {
-            model: self.model.clone(),
-            messages: build_messages(&request),
-            temperature: request.temperature,
-            max_tokens: request.max_tokens,
-            tools: None,
-        };
+        let model = request.model.clone().unwrap_or_else(|| self.model.clone());
+        chat_completion(&self.client, &self.base_url, None, model, &request, Vec::new()).await
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: LlmRequest,
+        tools: Vec<ToolDefinition>,
+    ) -> Result<LlmResponse, LlmError> {
+        let model = request.model.clone().unwrap_or_else(|| self.model.clone());
+        chat_completion(&self.client, &self.base_url, None, model, &request, tools).await
+    }
+}
 
+/// Run a chat completion against an OpenAI-compatible API (Ollama's `/v1`
+/// endpoints or OpenAI itself)
+///
+/// Failed requests report the HTTP status first, so auth and validation
+/// errors aren't retried.
+pub(super) async fn chat_completion(
+    client: &Client,
+    base_url: &str,
+    api_key: Option<&str>,
+    model: String,
+    request: &LlmRequest,
+    tools: Vec<ToolDefinition>,
+) -> Result<LlmResponse, LlmError> {
+    let api_tools: Vec<OpenAITool> = tools
+        .into_iter()
+        .map(|t| OpenAITool {
+            r#type: "function".to_string(),
+            function: OpenAIFunction {
+                name: t.name,
+                description: t.description,
+                parameters: t.parameters,
+            },
+        })
+        .collect();
+
+    let api_request = OpenAIChatRequest {
+        model,
+        messages: build_messages(request),
+        temperature: request.temperature,
+        max_tokens: request.max_tokens,
+        tools: (!api_tools.is_empty()).then_some(api_tools),
+        response_format: response_format(request),
+    };
+
+    let mut http_request = client
+        .post(format!("{}/chat/completions", base_url))
+        .json(&api_request);
+    if let Some(api_key) = api_key {
+        http_request = http_request.bearer_auth(api_key);
+    }
+    let response = http_request
+        .send()
+        .await
+        .map_err(|e| LlmError::RequestFailed(e.to_string()))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let error_text = response
+            .text()
+            .await
+            .map_err(|e| LlmError::RequestFailed(e.to_string()))?;
+        return Err(LlmError::RequestFailed(format!(
+            "{}: {}",
+            status.as_u16(),
+            error_text
+        )));
+    }
+
+    let api_response: OpenAIChatResponse = response
+        .json()
+        .await
+        .map_err(|e| LlmError::InvalidResponse(e.to_string()))?;
+
+    Ok(convert_response(api_response))
+}
+
+#[async_trait]
+impl LlmModelPort for OllamaClient {
+    async fn list_models(&self) -> Result<Vec<LlmModelInfo>, LlmError> {
         let response = self
             .client
-            .post(format!("{}/chat/completions", self.base_url))
-            .json(&api_request)
+            .get(format!("{}/api/tags", self.native_url()))
             .send()
             .await
             .map_err(|e| LlmError::RequestFailed(e.to_string()))?;
 
         if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .map_err(|e| LlmError::RequestFailed(e.to_string()))?;
-            return Err(LlmError::RequestFailed(error_text));
+            return Err(LlmError::RequestFailed(format!(
+                "Unexpected response: {}",
+                response.status()
+            )));
         }
 
-        let api_response: OpenAIChatResponse = response
+        let tags: OllamaTags = response
             .json()
             .await
             .map_err(|e| LlmError::InvalidResponse(e.to_string()))?;
 
-        Ok(convert_response(api_response))
-    }
-
-    async fn generate_with_tools(
-        &self,
-        request: LlmRequest,
-        tools: Vec<ToolDefinition>,
-    ) -> Result<LlmResponse, LlmError> {
-        let api_tools: Vec<OpenAITool> = tools
+        Ok(tags
+            .models
             .into_iter()
-            .map(|t| OpenAITool {
-                r#type: "function".to_string(),
-                function: OpenAIFunction {
-                    name: t.name,
-                    description: t.description,
-                    parameters: t.parameters,
-                },
+            .map(|m| LlmModelInfo {
+                name: m.name,
+                size_bytes: m.size,
             })
-            .collect();
-
-        let api_request = OpenAIChatRequest {
-            model: self.model.clone(),
-            messages: build_messages(&request),
-            temperature: request.temperature,
-            max_tokens: request.max_tokens,
-            tools: Some(api_tools),
-        };
+            .collect())
+    }
 
-        let response = self
-            .client
-            .post(format!("{}/chat/completions", self.base_url))
-            .json(&api_request)
+    async fn pull_model(
+        &self,
+        model: &str,
+        progress: tokio::sync::mpsc::UnboundedSender<ModelPullProgress>,
+    ) -> Result<(), LlmError> {
+        // Downloads can take far longer than the generation timeout
+        let mut response = Client::new()
+            .post(format!("{}/api/pull", self.native_url()))
+            .json(&serde_json::json!({ "model": model, "stream": true }))
             .send()
             .await
             .map_err(|e| LlmError::RequestFailed(e.to_string()))?;
@@ -110,12 +176,33 @@ impl LlmPort for OllamaClient {
             return Err(LlmError::RequestFailed(error_text));
         }
 
-        let api_response: OpenAIChatResponse = response
-            .json()
+        // Progress arrives as newline-delimited JSON objects
+        let mut buffer = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
             .await
-            .map_err(|e| LlmError::InvalidResponse(e.to_string()))?;
+            .map_err(|e| LlmError::RequestFailed(e.to_string()))?
+        {
+            buffer.extend_from_slice(&chunk);
+            while let Some(end) = buffer.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = buffer.drain(..=end).collect();
+                if line.iter().all(u8::is_ascii_whitespace) {
+                    continue;
+                }
+                let update: OllamaPullUpdate = serde_json::from_slice(&line)
+                    .map_err(|e| LlmError::InvalidResponse(e.to_string()))?;
+                if let Some(error) = update.error {
+                    return Err(LlmError::RequestFailed(error));
+                }
+                let _ = progress.send(ModelPullProgress {
+                    status: update.status,
+                    completed_bytes: update.completed,
+                    total_bytes: update.total,
+                });
+            }
+        }
 
-        Ok(convert_response(api_response))
+        Ok(())
     }
 }
 
@@ -125,12 +212,19 @@ fn build_messages(request: &LlmRequest) -> Vec<OpenAIMessage> {
     if let Some(system) = &request.system_prompt {
         messages.push(OpenAIMessage {
             role: "system".to_string(),
-            content: Some(system.clone()),
+            content: Some(OpenAIContent::Text(system.clone())),
             tool_calls: None,
         });
     }
 
-    for msg in &request.messages {
+    // Images go with the last user message, as OpenAI-style content parts
+    let image_message = request
+        .messages
+        .iter()
+        .rposition(|msg| msg.role == MessageRole::User)
+        .filter(|_| !request.images.is_empty());
+
+    for (index, msg) in request.messages.iter().enumerate() {
         messages.push(OpenAIMessage {
             role: match msg.role {
                 MessageRole::User => "user",
@@ -139,7 +233,11 @@ fn build_messages(request: &LlmRequest) -> Vec<OpenAIMessage> {
                 MessageRole::Unknown => "user", // Default unknown roles to user
             }
             .to_string(),
-            content: Some(msg.content.clone()),
+            content: Some(if image_message == Some(index) {
+                image_content(&msg.content, &request.images)
+            } else {
+                OpenAIContent::Text(msg.content.clone())
+            }),
             tool_calls: None,
         });
     }
@@ -147,6 +245,32 @@ fn build_messages(request: &LlmRequest) -> Vec<OpenAIMessage> {
     messages
 }
 
+fn image_content(text: &str, images: &[ImageData]) -> OpenAIContent {
+    let mut parts = vec![OpenAIContentPart::Text {
+        text: text.to_string(),
+    }];
+    parts.extend(images.iter().map(|image| OpenAIContentPart::ImageUrl {
+        image_url: OpenAIImageUrl {
+            url: format!("data:{};base64,{}", image.media_type, image.data),
+        },
+    }));
+    OpenAIContent::Parts(parts)
+}
+
+/// Ollama constrains generation to a schema passed as `response_format`.
+fn response_format(request: &LlmRequest) -> Option<OpenAIResponseFormat> {
+    request
+        .response_schema
+        .clone()
+        .map(|schema| OpenAIResponseFormat {
+            r#type: "json_schema".to_string(),
+            json_schema: OpenAIJsonSchema {
+                name: "response".to_string(),
+                schema,
+            },
+        })
+}
+
 fn convert_response(response: OpenAIChatResponse) -> LlmResponse {
     let choice = response.choices.into_iter().next().unwrap_or_default();
 
@@ -171,7 +295,11 @@ fn convert_response(response: OpenAIChatResponse) -> LlmResponse {
     };
 
     LlmResponse {
-        content: choice.message.content.unwrap_or_default(),
+        content: choice
+            .message
+            .content
+            .map(OpenAIContent::into_text)
+            .unwrap_or_default(),
         tool_calls,
         finish_reason,
         usage: response.usage.map(|u| TokenUsage {
@@ -196,17 +324,67 @@ struct OpenAIChatRequest {
     max_tokens: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tools: Option<Vec<OpenAITool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<OpenAIResponseFormat>,
+}
+
+#[derive(Debug, Serialize)]
+struct OpenAIResponseFormat {
+    r#type: String,
+    json_schema: OpenAIJsonSchema,
+}
+
+#[derive(Debug, Serialize)]
+struct OpenAIJsonSchema {
+    name: String,
+    schema: serde_json::Value,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 struct OpenAIMessage {
     role: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    content: Option<String>,
+    content: Option<OpenAIContent>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tool_calls: Option<Vec<OpenAIToolCall>>,
 }
 
+/// Message content: plain text, or text and images for multimodal models
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum OpenAIContent {
+    Text(String),
+    Parts(Vec<OpenAIContentPart>),
+}
+
+impl OpenAIContent {
+    fn into_text(self) -> String {
+        match self {
+            OpenAIContent::Text(text) => text,
+            OpenAIContent::Parts(parts) => parts
+                .into_iter()
+                .filter_map(|part| match part {
+                    OpenAIContentPart::Text { text } => Some(text),
+                    OpenAIContentPart::ImageUrl { .. } => None,
+                })
+                .collect::<Vec<_>>()
+                .join(""),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum OpenAIContentPart {
+    Text { text: String },
+    ImageUrl { image_url: OpenAIImageUrl },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OpenAIImageUrl {
+    url: String,
+}
+
 #[derive(Debug, Serialize)]
 struct OpenAITool {
     r#type: String,
@@ -250,3 +428,29 @@ struct OpenAIUsage {
     completion_tokens: u32,
     total_tokens: u32,
 }
+
+// =============================================================================
+// Ollama native API types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct OllamaTags {
+    #[serde(default)]
+    models: Vec<OllamaModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaModel {
+    name: String,
+    #[serde(default)]
+    size: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaPullUpdate {
+    #[serde(default)]
+    status: String,
+    total: Option<u64>,
+    completed: Option<u64>,
+    error: Option<String>,
+}
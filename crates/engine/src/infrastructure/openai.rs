@@ -0,0 +1,66 @@
+//! OpenAI LLM client (Chat Completions API)
+//!
+//! Shares the OpenAI wire format with the Ollama client; only the endpoint,
+//! the bearer key and the default model differ.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use std::time::Duration;
+
+use crate::infrastructure::ollama::chat_completion;
+use crate::infrastructure::ports::{LlmError, LlmPort, LlmRequest, LlmResponse, ToolDefinition};
+
+pub const DEFAULT_OPENAI_URL: &str = "https://api.openai.com/v1";
+pub const DEFAULT_OPENAI_MODEL: &str = "gpt-4o-mini";
+
+/// Client for OpenAI's Chat Completions API
+#[derive(Clone)]
+pub struct OpenAiClient {
+    client: Client,
+    base_url: String,
+    api_key: String,
+    model: String,
+}
+
+impl OpenAiClient {
+    pub fn new(base_url: Option<&str>, api_key: &str, model: Option<&str>) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(120))
+            .build()
+            .unwrap_or_else(|_| Client::new());
+
+        Self {
+            client,
+            base_url: base_url
+                .unwrap_or(DEFAULT_OPENAI_URL)
+                .trim_end_matches('/')
+                .to_string(),
+            api_key: api_key.to_string(),
+            model: model.unwrap_or(DEFAULT_OPENAI_MODEL).to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl LlmPort for OpenAiClient {
+    async fn generate(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
+        self.generate_with_tools(request, Vec::new()).await
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: LlmRequest,
+        tools: Vec<ToolDefinition>,
+    ) -> Result<LlmResponse, LlmError> {
+        let model = request.model.clone().unwrap_or_else(|| self.model.clone());
+        chat_completion(
+            &self.client,
+            &self.base_url,
+            Some(&self.api_key),
+            model,
+            &request,
+            tools,
+        )
+        .await
+    }
+}
@@ -4,8 +4,14 @@
 //! Ports exist for:
 //! - Database access (could swap Neo4j -> Postgres)
 //! - LLM calls (could swap Ollama -> Claude/OpenAI)
+//! - LLM model registry (listing and pulling local models)
 //! - Image generation (could swap ComfyUI -> other)
+//! - Asset blob storage (local disk or S3-compatible)
 //! - Queues (could swap SQLite -> Redis)
+//! - Usage accounting (per-world daily counters)
+//! - Service probes (live connectivity checks for setup)
+//! - Plugins (sandboxed third-party tools and effects)
+//! - Scripts (DM automation hooks with operation budgets)
 //! - Clock/Random (for testing)
 
 use async_trait::async_trait;
@@ -47,6 +53,86 @@ pub trait SettingsRepo: Send + Sync {
     async fn delete_for_world(&self, world_id: WorldId) -> Result<(), RepoError>;
 }
 
+/// Per-world usage counters, aggregated by UTC day
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UsageRepo: Send + Sync {
+    /// Add to a world's counters for a day
+    async fn record(
+        &self,
+        world_id: WorldId,
+        day: chrono::NaiveDate,
+        delta: UsageDelta,
+    ) -> Result<(), RepoError>;
+    /// A world's usage from `since` onwards, oldest day first
+    async fn list_daily(
+        &self,
+        world_id: WorldId,
+        since: chrono::NaiveDate,
+    ) -> Result<Vec<DailyUsage>, RepoError>;
+    /// Every world's usage on one day
+    async fn list_for_day(
+        &self,
+        day: chrono::NaiveDate,
+    ) -> Result<Vec<(WorldId, DailyUsage)>, RepoError>;
+}
+
+/// Usage to add to a world's counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UsageDelta {
+    pub llm_requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub image_generations: u64,
+    pub storage_bytes: u64,
+}
+
+impl UsageDelta {
+    /// One LLM call and the tokens it used
+    pub fn llm(usage: Option<&TokenUsage>) -> Self {
+        Self {
+            llm_requests: 1,
+            prompt_tokens: usage.map_or(0, |u| u64::from(u.prompt_tokens)),
+            completion_tokens: usage.map_or(0, |u| u64::from(u.completion_tokens)),
+            ..Default::default()
+        }
+    }
+
+    /// One generated image and the bytes it takes to store
+    pub fn image(bytes: usize) -> Self {
+        Self {
+            image_generations: 1,
+            storage_bytes: bytes as u64,
+            ..Default::default()
+        }
+    }
+
+    /// Bytes stored for an uploaded file
+    pub fn storage(bytes: usize) -> Self {
+        Self {
+            storage_bytes: bytes as u64,
+            ..Default::default()
+        }
+    }
+}
+
+/// A world's usage on one day
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct DailyUsage {
+    pub day: chrono::NaiveDate,
+    pub llm_requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub image_generations: u64,
+    pub storage_bytes: u64,
+}
+
+impl DailyUsage {
+    pub fn total_tokens(&self) -> u64 {
+        self.prompt_tokens + self.completion_tokens
+    }
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum LlmError {
     #[error("LLM request failed: {0}")]
@@ -63,6 +149,58 @@ pub enum ImageGenError {
     Unavailable,
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum TranscriptionError {
+    #[error("Transcription failed: {0}")]
+    Failed(String),
+    #[error("Service unavailable")]
+    Unavailable,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BlobError {
+    #[error("Blob not found")]
+    NotFound,
+    #[error("Invalid blob key: {0}")]
+    InvalidKey(String),
+    #[error("Storage error: {0}")]
+    Storage(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProbeError {
+    #[error("Could not connect: {0}")]
+    Unreachable(String),
+    #[error("{0}")]
+    Rejected(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginError {
+    #[error("Unknown plugin: {0}")]
+    UnknownPlugin(String),
+    #[error("Plugin {plugin} has no {kind} named {name}")]
+    UnknownEntry {
+        plugin: String,
+        kind: PluginCallKind,
+        name: String,
+    },
+    #[error("Invalid plugin: {0}")]
+    Invalid(String),
+    #[error("Plugin {plugin} failed: {message}")]
+    Failed { plugin: String, message: String },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ScriptError {
+    #[error("Syntax error: {0}")]
+    Compile(String),
+    #[error("Script exceeded its budget of {0} operations")]
+    BudgetExceeded(u64),
+    #[error("Script error: {0}")]
+    Runtime(String),
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum QueueError {
     #[error("Queue error: {0}")]
@@ -168,6 +306,15 @@ pub struct ConversationTurnRecord {
     pub order: i64,
 }
 
+/// How often a PC and an NPC have spoken, from their SPOKE_TO relationship.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DialogueCount {
+    pub pc_id: PlayerCharacterId,
+    pub npc_id: CharacterId,
+    /// Dialogue exchanges, one per player line and NPC reply
+    pub exchanges: u32,
+}
+
 // =============================================================================
 // Database Ports (one per entity type)
 // =============================================================================
@@ -318,6 +465,325 @@ pub trait GoalRepo: Send + Sync {
     async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<GoalDetails>, RepoError>;
 }
 
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ProgressClockRepo: Send + Sync {
+    async fn get(&self, id: ProgressClockId) -> Result<Option<ProgressClock>, RepoError>;
+    async fn save(&self, clock: &ProgressClock) -> Result<(), RepoError>;
+    async fn delete(&self, id: ProgressClockId) -> Result<(), RepoError>;
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<ProgressClock>, RepoError>;
+}
+
+/// How many entities in a world carry a tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagUsage {
+    pub tag: String,
+    pub count: u32,
+}
+
+/// Freeform entity tags and the saved filter presets built on them.
+///
+/// Tags are keyed by entity type and id rather than stored on the entity
+/// itself, so any taggable entity can carry them without its repository
+/// knowing. Tags passed in are already normalized; tags read back are sorted.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TagRepo: Send + Sync {
+    /// Tags on each of the given entities; untagged entities are left out
+    async fn get_tags(
+        &self,
+        entity_type: EntityType,
+        entity_ids: &[Uuid],
+    ) -> Result<std::collections::HashMap<Uuid, Vec<String>>, RepoError>;
+    /// Replace an entity's tags (an empty list removes them)
+    async fn set_tags(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        tags: &[String],
+    ) -> Result<(), RepoError>;
+    /// Every tag used in a world with its entity count, by tag name
+    async fn list_world_tags(&self, world_id: WorldId) -> Result<Vec<TagUsage>, RepoError>;
+    /// Rename a tag on every entity in a world (merging into `to` if it
+    /// already exists); returns how many entities changed
+    async fn rename_tag(&self, world_id: WorldId, from: &str, to: &str) -> Result<u32, RepoError>;
+    /// Remove a tag from every entity in a world; returns how many changed
+    async fn delete_tag(&self, world_id: WorldId, tag: &str) -> Result<u32, RepoError>;
+
+    async fn get_saved_filter(&self, id: SavedFilterId) -> Result<Option<SavedFilter>, RepoError>;
+    async fn save_saved_filter(&self, filter: &SavedFilter) -> Result<(), RepoError>;
+    async fn delete_saved_filter(&self, id: SavedFilterId) -> Result<(), RepoError>;
+    /// A user's presets in a world, by name
+    async fn list_saved_filters(
+        &self,
+        world_id: WorldId,
+        user_id: &str,
+    ) -> Result<Vec<SavedFilter>, RepoError>;
+}
+
+/// Values of DM-defined custom fields, one map per entity.
+///
+/// Like tags, values are keyed by entity type and id so entity repositories
+/// stay unaware of them. The field schema itself lives on the world; values
+/// passed in have already been checked against it.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CustomFieldRepo: Send + Sync {
+    /// An entity's values by field key (empty if it has none)
+    async fn get_values(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<CustomFieldValues, RepoError>;
+    /// Replace an entity's values (an empty map removes them)
+    async fn set_values(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        values: &CustomFieldValues,
+    ) -> Result<(), RepoError>;
+}
+
+/// Entity templates (prefabs). Instantiating one goes through the regular
+/// character, location and item repositories.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TemplateRepo: Send + Sync {
+    async fn get(&self, id: EntityTemplateId) -> Result<Option<EntityTemplate>, RepoError>;
+    async fn save(&self, template: &EntityTemplate) -> Result<(), RepoError>;
+    async fn delete(&self, id: EntityTemplateId) -> Result<(), RepoError>;
+    /// Templates in a world, sorted by name
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<EntityTemplate>, RepoError>;
+}
+
+/// Users' cross-world content libraries. Entries are keyed by their owner,
+/// not by a world.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait LibraryRepo: Send + Sync {
+    async fn get(&self, id: LibraryEntryId) -> Result<Option<LibraryEntry>, RepoError>;
+    async fn save(&self, entry: &LibraryEntry) -> Result<(), RepoError>;
+    async fn delete(&self, id: LibraryEntryId) -> Result<(), RepoError>;
+    /// A user's entries, sorted by name
+    async fn list_for_owner(&self, owner_id: &str) -> Result<Vec<LibraryEntry>, RepoError>;
+}
+
+/// Revision drafts of entity text fields, at most one per entity and field.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ContentDraftRepo: Send + Sync {
+    async fn get(&self, id: ContentDraftId) -> Result<Option<ContentDraft>, RepoError>;
+    async fn save(&self, draft: &ContentDraft) -> Result<(), RepoError>;
+    async fn delete(&self, id: ContentDraftId) -> Result<(), RepoError>;
+    /// An entity's drafts, sorted by field
+    async fn list_for_entity(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<ContentDraft>, RepoError>;
+}
+
+/// Background crowds staged in regions.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CrowdRepo: Send + Sync {
+    async fn get(&self, id: CrowdId) -> Result<Option<Crowd>, RepoError>;
+    async fn save(&self, crowd: &Crowd) -> Result<(), RepoError>;
+    async fn delete(&self, id: CrowdId) -> Result<(), RepoError>;
+    /// A world's crowds, sorted by name
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<Crowd>, RepoError>;
+    /// A region's crowds, present or not, sorted by name
+    async fn list_in_region(&self, region_id: RegionId) -> Result<Vec<Crowd>, RepoError>;
+}
+
+/// NPCs proposed for promotion, waiting for DM approval.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait NpcDraftRepo: Send + Sync {
+    async fn get(&self, id: NpcDraftId) -> Result<Option<NpcDraft>, RepoError>;
+    async fn save(&self, draft: &NpcDraft) -> Result<(), RepoError>;
+    async fn delete(&self, id: NpcDraftId) -> Result<(), RepoError>;
+    /// A world's drafts, newest first
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<NpcDraft>, RepoError>;
+}
+
+/// Per-culture name generators.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait NameGeneratorRepo: Send + Sync {
+    async fn get(&self, id: NameGeneratorId) -> Result<Option<NameGenerator>, RepoError>;
+    async fn save(&self, generator: &NameGenerator) -> Result<(), RepoError>;
+    async fn delete(&self, id: NameGeneratorId) -> Result<(), RepoError>;
+    /// A world's generators, sorted by name
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<NameGenerator>, RepoError>;
+}
+
+/// Market modifiers, the exceptions to items' base prices.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait EconomyRepo: Send + Sync {
+    async fn get_modifier(&self, id: MarketModifierId)
+        -> Result<Option<MarketModifier>, RepoError>;
+    async fn save_modifier(&self, modifier: &MarketModifier) -> Result<(), RepoError>;
+    async fn delete_modifier(&self, id: MarketModifierId) -> Result<(), RepoError>;
+    /// A world's modifiers, oldest first so their reasons read in order
+    async fn list_modifiers(&self, world_id: WorldId) -> Result<Vec<MarketModifier>, RepoError>;
+}
+
+/// Properties player characters own.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait PropertyRepo: Send + Sync {
+    async fn get(&self, id: PropertyId) -> Result<Option<Property>, RepoError>;
+    async fn save(&self, property: &Property) -> Result<(), RepoError>;
+    async fn delete(&self, id: PropertyId) -> Result<(), RepoError>;
+    /// A PC's properties, oldest first
+    async fn list_for_owner(&self, owner_id: PlayerCharacterId)
+        -> Result<Vec<Property>, RepoError>;
+}
+
+/// NPCs travelling with player characters.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CompanionRepo: Send + Sync {
+    async fn get(&self, id: CompanionId) -> Result<Option<Companion>, RepoError>;
+    async fn save(&self, companion: &Companion) -> Result<(), RepoError>;
+    async fn delete(&self, id: CompanionId) -> Result<(), RepoError>;
+    /// A PC's companions, longest-serving first
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<Companion>, RepoError>;
+    /// The companion record binding an NPC, if any PC has hired them
+    async fn get_for_character(
+        &self,
+        character_id: CharacterId,
+    ) -> Result<Option<Companion>, RepoError>;
+}
+
+/// Injuries carried by player characters.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait InjuryRepo: Send + Sync {
+    async fn get(&self, id: InjuryId) -> Result<Option<Injury>, RepoError>;
+    async fn save(&self, injury: &Injury) -> Result<(), RepoError>;
+    async fn delete(&self, id: InjuryId) -> Result<(), RepoError>;
+    /// A PC's injuries, healed ones included, oldest first
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<Injury>, RepoError>;
+    /// Unhealed injuries across every PC in a world
+    async fn list_active_in_world(&self, world_id: WorldId) -> Result<Vec<Injury>, RepoError>;
+}
+
+/// Secrets kept by player characters.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait PcSecretRepo: Send + Sync {
+    async fn get(&self, id: PcSecretId) -> Result<Option<PcSecret>, RepoError>;
+    async fn save(&self, secret: &PcSecret) -> Result<(), RepoError>;
+    async fn delete(&self, id: PcSecretId) -> Result<(), RepoError>;
+    /// A PC's secrets, revealed ones included, oldest first
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<PcSecret>, RepoError>;
+}
+
+/// In-world letters between PCs and NPCs.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait LetterRepo: Send + Sync {
+    async fn get(&self, id: LetterId) -> Result<Option<Letter>, RepoError>;
+    async fn save(&self, letter: &Letter) -> Result<(), RepoError>;
+    async fn delete(&self, id: LetterId) -> Result<(), RepoError>;
+    /// Letters a PC wrote or was sent, delivered or not, newest first
+    async fn list_for_pc(&self, pc_id: PlayerCharacterId) -> Result<Vec<Letter>, RepoError>;
+    /// Every letter in a world, newest first
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Letter>, RepoError>;
+    /// Letters still in transit, earliest delivery first
+    async fn list_undelivered_in_world(&self, world_id: WorldId) -> Result<Vec<Letter>, RepoError>;
+}
+
+/// Issues of a world's weekly broadsheet.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait BroadsheetRepo: Send + Sync {
+    async fn get(&self, id: BroadsheetId) -> Result<Option<Broadsheet>, RepoError>;
+    async fn save(&self, broadsheet: &Broadsheet) -> Result<(), RepoError>;
+    async fn delete(&self, id: BroadsheetId) -> Result<(), RepoError>;
+    /// Every issue in a world, drafts included, newest issue first
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Broadsheet>, RepoError>;
+}
+
+/// Factions and their members, goals, reputation and rivalries.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait FactionRepo: Send + Sync {
+    async fn get(&self, id: FactionId) -> Result<Option<Faction>, RepoError>;
+    async fn save(&self, faction: &Faction) -> Result<(), RepoError>;
+    async fn delete(&self, id: FactionId) -> Result<(), RepoError>;
+    /// Every faction in a world, by name
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<Faction>, RepoError>;
+}
+
+/// DM-authored dialogue trees for scripted beats.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait DialogueTreeRepo: Send + Sync {
+    async fn get(&self, id: DialogueTreeId) -> Result<Option<DialogueTree>, RepoError>;
+    async fn save(&self, tree: &DialogueTree) -> Result<(), RepoError>;
+    async fn delete(&self, id: DialogueTreeId) -> Result<(), RepoError>;
+    /// Every dialogue tree in a world, by name
+    async fn list_in_world(&self, world_id: WorldId) -> Result<Vec<DialogueTree>, RepoError>;
+}
+
+/// Entity aliases and the mentions found with them.
+///
+/// Like tags, both are keyed by entity type and id so entity repositories
+/// stay unaware of them. Mentions are replaced a source at a time as its
+/// text is indexed again.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait MentionRepo: Send + Sync {
+    /// Every aliased entity in a world with its aliases
+    async fn list_aliases(
+        &self,
+        world_id: WorldId,
+    ) -> Result<std::collections::HashMap<(EntityType, Uuid), Vec<String>>, RepoError>;
+    /// Replace an entity's aliases (an empty list removes them)
+    async fn set_aliases(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        aliases: &[String],
+    ) -> Result<(), RepoError>;
+    /// Replace the mentions found in one source's text
+    async fn replace_mentions(
+        &self,
+        world_id: WorldId,
+        source_type: EntityType,
+        source_id: Uuid,
+        mentions: &[EntityMention],
+    ) -> Result<(), RepoError>;
+    /// Drop a world's mentions, before indexing it from scratch
+    async fn clear_mentions(&self, world_id: WorldId) -> Result<(), RepoError>;
+    /// Drop everything about a deleted entity: its aliases, the mentions in
+    /// its text and the mentions of it
+    async fn forget_entity(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<(), RepoError>;
+    /// Mentions of an entity, by source name
+    async fn list_mentions_of(
+        &self,
+        target_type: EntityType,
+        target_id: Uuid,
+    ) -> Result<Vec<EntityMention>, RepoError>;
+    /// Mentions in an entity's text
+    async fn list_mentions_in(
+        &self,
+        source_type: EntityType,
+        source_id: Uuid,
+    ) -> Result<Vec<EntityMention>, RepoError>;
+}
+
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait PlayerCharacterRepo: Send + Sync {
@@ -357,6 +823,17 @@ pub trait PlayerCharacterRepo: Send + Sync {
         stat: &str,
         modifier: i32,
     ) -> Result<(), RepoError>;
+
+    /// Carry out an accepted trade: both sides' items and currency move together.
+    ///
+    /// Fails with `ConstraintViolation`, changing nothing, if either PC no
+    /// longer has what they're giving. `currency_field` is required when
+    /// either side includes currency.
+    async fn execute_trade(
+        &self,
+        offer: &TradeOffer,
+        currency_field: Option<String>,
+    ) -> Result<(), RepoError>;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -482,6 +959,14 @@ pub trait InteractionRepo: Send + Sync {
         &self,
         scene_id: SceneId,
     ) -> Result<Vec<InteractionTemplate>, RepoError>;
+    /// How often a PC has used an interaction, if at all
+    async fn get_usage(
+        &self,
+        interaction_id: InteractionId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Option<InteractionUsage>, RepoError>;
+    /// Record a PC's usage of an interaction (upsert)
+    async fn save_usage(&self, usage: &InteractionUsage) -> Result<(), RepoError>;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -502,6 +987,27 @@ pub trait ChallengeRepo: Send + Sync {
         &self,
         world_id: WorldId,
     ) -> Result<Vec<ChallengeId>, RepoError>;
+    /// Record what the DM did with an LLM challenge suggestion
+    async fn save_suggestion_decision(
+        &self,
+        decision: &SuggestionDecision,
+    ) -> Result<(), RepoError>;
+    /// A world's latest suggestion decisions, newest first
+    async fn list_suggestion_decisions(
+        &self,
+        world_id: WorldId,
+        limit: usize,
+    ) -> Result<Vec<SuggestionDecision>, RepoError>;
+    /// Record a resolved challenge for the world's history
+    async fn save_resolution(&self, resolution: &ChallengeResolution) -> Result<(), RepoError>;
+    /// A world's latest challenge resolutions, newest first, optionally
+    /// only those of one PC
+    async fn list_resolutions(
+        &self,
+        world_id: WorldId,
+        pc_id: Option<PlayerCharacterId>,
+        limit: usize,
+    ) -> Result<Vec<ChallengeResolution>, RepoError>;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -617,6 +1123,20 @@ pub trait NarrativeRepo: Send + Sync {
         npc_id: CharacterId,
     ) -> Result<Option<Uuid>, RepoError>;
 
+    // Analytics
+    /// Dialogue exchanges for every PC and NPC pair in a world that have spoken.
+    async fn list_dialogue_counts(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<DialogueCount>, RepoError>;
+
+    /// Story events per PC in a world: conversations they took part in and
+    /// events whose details name them. PCs without any are left out.
+    async fn count_story_events_by_pc(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<(PlayerCharacterId, u32)>, RepoError>;
+
     // Triggers
     async fn get_triggers_for_region(
         &self,
@@ -669,6 +1189,9 @@ pub trait StagingRepo: Send + Sync {
         region_id: RegionId,
     ) -> Result<(), RepoError>;
 
+    /// Clear a region's current staging, leaving it unstaged.
+    async fn deactivate_staging(&self, region_id: RegionId) -> Result<(), RepoError>;
+
     /// Get staging history for a region (most recent first, limited).
     /// Returns past stagings that are no longer active.
     async fn get_staging_history(
@@ -723,6 +1246,22 @@ pub trait ObservationRepo: Send + Sync {
         pc_id: PlayerCharacterId,
         info: String,
     ) -> Result<(), RepoError>;
+    /// Locations the PC has visited or heard about
+    async fn get_known_locations(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<LocationObservation>, RepoError>;
+    /// Record knowledge of a location (upsert). A visit is never replaced by
+    /// hearsay.
+    async fn save_location_observation(
+        &self,
+        observation: &LocationObservation,
+    ) -> Result<(), RepoError>;
+    async fn delete_location_observation(
+        &self,
+        pc_id: PlayerCharacterId,
+        location_id: LocationId,
+    ) -> Result<(), RepoError>;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -883,6 +1422,8 @@ pub trait AssetRepo: Send + Sync {
         entity_type: &str,
         entity_id: Uuid,
     ) -> Result<Vec<GalleryAsset>, RepoError>;
+    /// Assets of every character, location and item in the world, newest first
+    async fn list_for_world(&self, world_id: WorldId) -> Result<Vec<GalleryAsset>, RepoError>;
     async fn set_active(
         &self,
         entity_type: &str,
@@ -908,6 +1449,14 @@ pub struct LlmRequest {
     pub max_tokens: Option<u32>,
     /// Optional images for multimodal models
     pub images: Vec<ImageData>,
+    /// Model to use instead of the client's configured one
+    pub model: Option<String>,
+    /// JSON schema the reply must follow, for calls expecting structured data
+    pub response_schema: Option<serde_json::Value>,
+    /// Whether an identical earlier response may be reused (non-creative calls)
+    pub cache: bool,
+    /// World the call is made for, so its tokens count toward that world's usage
+    pub world_id: Option<WorldId>,
 }
 
 impl LlmRequest {
@@ -918,6 +1467,10 @@ impl LlmRequest {
             temperature: None,
             max_tokens: None,
             images: Vec::new(),
+            model: None,
+            response_schema: None,
+            cache: false,
+            world_id: None,
         }
     }
 
@@ -935,6 +1488,26 @@ impl LlmRequest {
         self.max_tokens = max_tokens;
         self
     }
+
+    pub fn with_model(mut self, model: Option<String>) -> Self {
+        self.model = model;
+        self
+    }
+
+    pub fn with_response_schema(mut self, schema: Option<serde_json::Value>) -> Self {
+        self.response_schema = schema;
+        self
+    }
+
+    pub fn with_cache(mut self, cache: bool) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    pub fn with_world(mut self, world_id: WorldId) -> Self {
+        self.world_id = Some(world_id);
+        self
+    }
 }
 
 /// A message in the conversation
@@ -1042,13 +1615,46 @@ pub trait LlmPort: Send + Sync {
     ) -> Result<LlmResponse, LlmError>;
 }
 
+/// A model available to the LLM server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LlmModelInfo {
+    pub name: String,
+    /// Size on disk in bytes
+    pub size_bytes: u64,
+}
+
+/// One progress update while a model downloads
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelPullProgress {
+    /// Server-reported stage (e.g. "pulling manifest", "success")
+    pub status: String,
+    pub completed_bytes: Option<u64>,
+    pub total_bytes: Option<u64>,
+}
+
+/// Lists and downloads models on the LLM server.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait LlmModelPort: Send + Sync {
+    async fn list_models(&self) -> Result<Vec<LlmModelInfo>, LlmError>;
+    /// Download `model`, sending updates to `progress` until it finishes
+    async fn pull_model(
+        &self,
+        model: &str,
+        progress: tokio::sync::mpsc::UnboundedSender<ModelPullProgress>,
+    ) -> Result<(), LlmError>;
+}
+
 /// Image generation request/response types
 #[derive(Debug, Clone)]
 pub struct ImageRequest {
     pub prompt: String,
+    pub negative_prompt: Option<String>,
     pub workflow: String,
     pub width: u32,
     pub height: u32,
+    /// Image to condition on (e.g. a character's canonical portrait)
+    pub reference_image: Option<Vec<u8>>,
 }
 
 #[derive(Debug, Clone)]
@@ -1063,6 +1669,148 @@ pub trait ImageGenPort: Send + Sync {
     async fn check_health(&self) -> Result<bool, ImageGenError>;
 }
 
+// =============================================================================
+// Transcription Port
+// =============================================================================
+
+/// A recorded clip to turn into text
+#[derive(Debug, Clone)]
+pub struct TranscriptionRequest {
+    pub audio: Vec<u8>,
+    /// MIME type of the audio, e.g. "audio/webm"
+    pub content_type: String,
+    /// ISO 639-1 code of the language spoken; `None` lets the model detect it
+    pub language: Option<String>,
+}
+
+/// Speech-to-text for spoken player input.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TranscriptionPort: Send + Sync {
+    async fn transcribe(&self, request: TranscriptionRequest)
+        -> Result<String, TranscriptionError>;
+}
+
+// =============================================================================
+// Blob Store Port
+// =============================================================================
+
+/// Content-addressed storage for asset files (generated images, uploads).
+///
+/// Keys are derived from the content's SHA-256, so storing the same bytes
+/// twice yields the same key and one stored copy.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait BlobStorePort: Send + Sync {
+    /// Store `data`, returning its content-addressed key
+    async fn put(&self, data: Vec<u8>, content_type: &str) -> Result<String, BlobError>;
+    async fn get(&self, key: &str) -> Result<Vec<u8>, BlobError>;
+    /// Time-limited URL a remote Player can fetch the blob from
+    fn signed_url(&self, key: &str, expires_in: std::time::Duration) -> Result<String, BlobError>;
+    /// Whether a signature from `signed_url` is valid and unexpired.
+    ///
+    /// Only meaningful for stores whose URLs the engine serves itself.
+    fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> bool;
+}
+
+// =============================================================================
+// Service Probe Port
+// =============================================================================
+
+/// Live connectivity checks against candidate service settings.
+///
+/// Used by first-run setup to validate connections before they are saved,
+/// and by the health monitor, so each check connects fresh rather than
+/// using the running clients.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ServiceProbePort: Send + Sync {
+    async fn check_neo4j(&self, uri: &str, user: &str, password: &str) -> Result<(), ProbeError>;
+    /// Checks the server responds and has `model` pulled
+    async fn check_ollama(&self, base_url: &str, model: &str) -> Result<(), ProbeError>;
+    async fn check_comfyui(&self, base_url: &str) -> Result<(), ProbeError>;
+    /// Free bytes on the filesystem holding `path` (which need not exist yet)
+    async fn free_disk_space(&self, path: &str) -> Result<u64, ProbeError>;
+}
+
+// =============================================================================
+// Plugin Port
+// =============================================================================
+
+/// Whether a plugin call runs a tool or an effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginCallKind {
+    Tool,
+    Effect,
+}
+
+impl std::fmt::Display for PluginCallKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluginCallKind::Tool => write!(f, "tool"),
+            PluginCallKind::Effect => write!(f, "effect"),
+        }
+    }
+}
+
+/// Input for one plugin tool or effect run
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginCall {
+    pub kind: PluginCallKind,
+    pub name: String,
+    pub arguments: serde_json::Value,
+    pub world_id: WorldId,
+    pub pc_id: Option<PlayerCharacterId>,
+}
+
+/// Third-party plugins, each run in a sandbox.
+///
+/// Plugins only compute: they return effects for the engine to apply after
+/// checking them against the manifest's capabilities.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait PluginPort: Send + Sync {
+    /// Manifests of all loaded plugins
+    fn manifests(&self) -> Vec<PluginManifest>;
+    /// Run a tool or effect declared in `plugin`'s manifest
+    async fn call(&self, plugin: &str, call: PluginCall) -> Result<PluginOutput, PluginError>;
+}
+
+// =============================================================================
+// Script Port
+// =============================================================================
+
+/// A hook firing, as seen by the scripts attached to it
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptEvent {
+    pub hook: ScriptHook,
+    pub world_id: WorldId,
+    /// The character the hook is about, if any
+    pub pc_id: Option<PlayerCharacterId>,
+    /// Hook-specific details (item name, new time, challenge outcome, ...)
+    pub data: serde_json::Value,
+}
+
+/// Interpreter for DM automation scripts.
+///
+/// Scripts never touch the world themselves; they return an output for the
+/// engine to apply.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ScriptEnginePort: Send + Sync {
+    /// Check that `source` compiles
+    fn check(&self, source: &str) -> Result<(), ScriptError>;
+    /// Run `script` for `event` within the script's operation budget
+    async fn run(
+        &self,
+        script: &WorldScript,
+        event: &ScriptEvent,
+    ) -> Result<ScriptOutput, ScriptError>;
+}
+
 // =============================================================================
 // Queue Port
 // =============================================================================
@@ -1122,6 +1870,20 @@ pub trait QueuePort: Send + Sync {
     // Common operations
     async fn mark_complete(&self, id: Uuid) -> Result<(), QueueError>;
     async fn mark_failed(&self, id: Uuid, error: &str) -> Result<(), QueueError>;
+
+    /// Mark an item failed only while it still has the `expected` status.
+    ///
+    /// Returns false if a worker moved the item on first.
+    async fn mark_failed_if_status(
+        &self,
+        id: Uuid,
+        expected: QueueItemStatus,
+        error: &str,
+    ) -> Result<bool, QueueError>;
+
+    /// Get a queue item by ID.
+    async fn get_item(&self, id: Uuid) -> Result<Option<QueueItem>, QueueError>;
+
     async fn get_pending_count(&self, queue_type: &str) -> Result<usize, QueueError>;
 
     /// List queue items by type (newest first).
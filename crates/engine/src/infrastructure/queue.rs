@@ -221,6 +221,16 @@ impl SqliteQueue {
     }
 }
 
+/// The `status` column value for a queue item status
+fn status_str(status: QueueItemStatus) -> &'static str {
+    match status {
+        QueueItemStatus::Pending => "pending",
+        QueueItemStatus::Processing => "processing",
+        QueueItemStatus::Completed => "completed",
+        QueueItemStatus::Failed => "failed",
+    }
+}
+
 #[async_trait]
 impl QueuePort for SqliteQueue {
     // Player action queue
@@ -332,6 +342,50 @@ impl QueuePort for SqliteQueue {
         Ok(())
     }
 
+    async fn mark_failed_if_status(
+        &self,
+        id: Uuid,
+        expected: QueueItemStatus,
+        error: &str,
+    ) -> Result<bool, QueueError> {
+        let now = self.clock.now().to_rfc3339();
+
+        // The status check and update are one statement, so a worker
+        // dequeuing the item at the same time can't be overwritten
+        let result = sqlx::query(
+            r#"
+            UPDATE queue_items
+            SET status = 'failed', updated_at = ?, error_message = ?
+            WHERE id = ? AND status = ?
+            "#,
+        )
+        .bind(&now)
+        .bind(error)
+        .bind(id.to_string())
+        .bind(status_str(expected))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| QueueError::Error(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_item(&self, id: Uuid) -> Result<Option<QueueItem>, QueueError> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, queue_type, payload_json, status, created_at, updated_at, error_message, result_json
+            FROM queue_items
+            WHERE id = ?
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| QueueError::Error(e.to_string()))?;
+
+        row.map(|row| self.row_to_queue_item(row)).transpose()
+    }
+
     async fn list_by_type(
         &self,
         queue_type: &str,
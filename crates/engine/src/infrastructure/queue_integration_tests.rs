@@ -2,10 +2,13 @@ use std::sync::Arc;
 
 use chrono::{TimeZone, Utc};
 use uuid::Uuid;
-use wrldbldr_domain::{ApprovalDecisionType, ApprovalRequestData, ApprovalUrgency, WorldId};
+use wrldbldr_domain::{
+    ApprovalDecisionType, ApprovalRequestData, ApprovalUrgency, PlayerActionData, WorldId,
+};
 
 use crate::infrastructure::{
-    clock::FixedClock, ports::QueueItemData, ports::QueuePort, queue::SqliteQueue,
+    clock::FixedClock, ports::QueueItemData, ports::QueueItemStatus, ports::QueuePort,
+    queue::SqliteQueue,
 };
 
 #[tokio::test]
@@ -39,6 +42,8 @@ async fn sqlite_queue_dm_approval_persists_across_restart() {
         game_time: None,
         topics: vec![],
         conversation_id: None,
+        safety_warnings: vec![],
+        merged_lines: vec![],
     };
 
     let id = {
@@ -157,3 +162,51 @@ async fn sqlite_queue_generation_read_state_persists_across_restart() {
         assert_eq!(got.1, read_suggestions);
     }
 }
+
+#[tokio::test]
+async fn sqlite_queue_fails_item_only_from_expected_status() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let db_path = temp_dir.path().join("queue.db");
+    let db_path_str = db_path.to_string_lossy().to_string();
+
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let clock: Arc<dyn crate::infrastructure::ports::ClockPort> = Arc::new(FixedClock(now));
+    let queue = SqliteQueue::new(&db_path_str, clock)
+        .await
+        .expect("create queue");
+
+    let action = PlayerActionData {
+        world_id: WorldId::new(),
+        player_id: "player".to_string(),
+        pc_id: None,
+        action_type: "talk".to_string(),
+        target: None,
+        dialogue: Some("Hello".to_string()),
+        timestamp: now,
+        conversation_id: None,
+    };
+    let id = queue.enqueue_player_action(&action).await.expect("enqueue");
+
+    let item = queue.get_item(id).await.expect("get").expect("item");
+    assert_eq!(item.status, QueueItemStatus::Pending);
+    assert!(queue.get_item(Uuid::new_v4()).await.expect("get").is_none());
+
+    // A worker dequeues it before the retract lands
+    queue.dequeue_player_action().await.expect("dequeue");
+    let failed = queue
+        .mark_failed_if_status(id, QueueItemStatus::Pending, "Retracted")
+        .await
+        .expect("mark failed");
+    assert!(!failed);
+    let item = queue.get_item(id).await.expect("get").expect("item");
+    assert_eq!(item.status, QueueItemStatus::Processing);
+
+    let failed = queue
+        .mark_failed_if_status(id, QueueItemStatus::Processing, "Retracted")
+        .await
+        .expect("mark failed");
+    assert!(failed);
+    let item = queue.get_item(id).await.expect("get").expect("item");
+    assert_eq!(item.status, QueueItemStatus::Failed);
+    assert_eq!(item.error_message.as_deref(), Some("Retracted"));
+}
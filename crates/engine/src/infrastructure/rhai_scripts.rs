@@ -0,0 +1,261 @@
+//! Rhai interpreter for DM automation scripts.
+//!
+//! Every run gets a fresh [`Engine`] limited to the script's operation budget,
+//! with no modules, no `eval` and bounded strings, arrays, maps and call
+//! depth. Scripts see the hook as a read-only `event` map and can only call:
+//!
+//! - `give_item(name)` / `give_item(name, quantity)`
+//! - `take_item(name)` / `take_item(name, quantity)`
+//! - `set_flag(name, value)`
+//! - `notify_dm(message)`
+//!
+//! Those calls only queue [`EventEffect`]s and notices in a [`ScriptOutput`];
+//! the engine applies them after the script finishes. `print` and `debug`
+//! go to the engine log.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{Engine, EvalAltResult, Scope};
+use wrldbldr_domain::{EventEffect, ScriptOutput, WorldScript};
+
+use super::ports::{ScriptEnginePort, ScriptError, ScriptEvent};
+
+/// Most effects and notices one run may queue
+const MAX_QUEUED: usize = 100;
+const MAX_STRING_SIZE: usize = 16 * 1024;
+const MAX_COLLECTION_SIZE: usize = 1024;
+const MAX_CALL_LEVELS: usize = 16;
+const MAX_EXPR_DEPTH: usize = 64;
+
+type Queued = Arc<Mutex<ScriptOutput>>;
+
+/// Script engine backed by the `rhai` interpreter.
+#[derive(Debug, Default)]
+pub struct RhaiScriptEngine;
+
+impl RhaiScriptEngine {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// An engine with the sandbox limits but no script API.
+fn sandboxed_engine(operation_budget: u64) -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(operation_budget);
+    engine.set_max_string_size(MAX_STRING_SIZE);
+    engine.set_max_array_size(MAX_COLLECTION_SIZE);
+    engine.set_max_map_size(MAX_COLLECTION_SIZE);
+    engine.set_max_call_levels(MAX_CALL_LEVELS);
+    engine.set_max_expr_depths(MAX_EXPR_DEPTH, MAX_EXPR_DEPTH);
+    engine.set_max_modules(0);
+    engine.set_module_resolver(DummyModuleResolver::new());
+    engine.disable_symbol("eval");
+    engine
+}
+
+fn queue(queued: &Queued, push: impl FnOnce(&mut ScriptOutput)) -> Result<(), Box<EvalAltResult>> {
+    let mut output = queued.lock().unwrap_or_else(|e| e.into_inner());
+    if output.effects.len() + output.notices.len() >= MAX_QUEUED {
+        return Err(format!("a script may queue at most {MAX_QUEUED} actions").into());
+    }
+    push(&mut output);
+    Ok(())
+}
+
+fn quantity(quantity: i64) -> Result<u32, Box<EvalAltResult>> {
+    u32::try_from(quantity)
+        .ok()
+        .filter(|quantity| *quantity > 0)
+        .ok_or_else(|| format!("invalid quantity {quantity}").into())
+}
+
+/// Register the script API, queuing into `queued`.
+fn register_api(engine: &mut Engine, script: &str, has_pc: bool, queued: &Queued) {
+    let no_pc = || -> Box<EvalAltResult> { "this hook has no player character".into() };
+
+    let q = queued.clone();
+    let give = move |name: &str, qty: i64| -> Result<(), Box<EvalAltResult>> {
+        if !has_pc {
+            return Err(no_pc());
+        }
+        let quantity = quantity(qty)?;
+        queue(&q, |output| {
+            output.effects.push(EventEffect::GiveItem {
+                item_name: name.to_string(),
+                item_description: None,
+                quantity,
+            })
+        })
+    };
+    let give_one = give.clone();
+    engine.register_fn("give_item", give);
+    engine.register_fn("give_item", move |name: &str| give_one(name, 1));
+
+    let q = queued.clone();
+    let take = move |name: &str, qty: i64| -> Result<(), Box<EvalAltResult>> {
+        if !has_pc {
+            return Err(no_pc());
+        }
+        let quantity = quantity(qty)?;
+        queue(&q, |output| {
+            output.effects.push(EventEffect::TakeItem {
+                item_name: name.to_string(),
+                quantity,
+            })
+        })
+    };
+    let take_one = take.clone();
+    engine.register_fn("take_item", take);
+    engine.register_fn("take_item", move |name: &str| take_one(name, 1));
+
+    let q = queued.clone();
+    engine.register_fn("set_flag", move |name: &str, value: bool| {
+        queue(&q, |output| {
+            output.effects.push(EventEffect::SetFlag {
+                flag_name: name.to_string(),
+                value,
+            })
+        })
+    });
+
+    let q = queued.clone();
+    engine.register_fn("notify_dm", move |message: &str| {
+        queue(&q, |output| output.notices.push(message.to_string()))
+    });
+
+    let name = script.to_string();
+    engine.on_print(move |text| tracing::info!(script = %name, "{}", text));
+    let name = script.to_string();
+    engine.on_debug(move |text, _, pos| tracing::debug!(script = %name, %pos, "{}", text));
+}
+
+fn run_blocking(script: &WorldScript, event: &ScriptEvent) -> Result<ScriptOutput, ScriptError> {
+    let queued = Queued::default();
+    let mut engine = sandboxed_engine(script.operation_budget);
+    register_api(&mut engine, &script.name, event.pc_id.is_some(), &queued);
+
+    let ast = engine
+        .compile(&script.source)
+        .map_err(|e| ScriptError::Compile(e.to_string()))?;
+    let event = rhai::serde::to_dynamic(event).map_err(|e| ScriptError::Runtime(e.to_string()))?;
+    let mut scope = Scope::new();
+    scope.push_constant("event", event);
+
+    engine
+        .run_ast_with_scope(&mut scope, &ast)
+        .map_err(|e| match *e {
+            EvalAltResult::ErrorTooManyOperations(_) => {
+                ScriptError::BudgetExceeded(script.operation_budget)
+            }
+            other => ScriptError::Runtime(other.to_string()),
+        })?;
+
+    let output = std::mem::take(&mut *queued.lock().unwrap_or_else(|e| e.into_inner()));
+    Ok(output)
+}
+
+#[async_trait]
+impl ScriptEnginePort for RhaiScriptEngine {
+    fn check(&self, source: &str) -> Result<(), ScriptError> {
+        let mut engine = sandboxed_engine(1);
+        register_api(&mut engine, "<check>", true, &Queued::default());
+        engine
+            .compile(source)
+            .map(|_| ())
+            .map_err(|e| ScriptError::Compile(e.to_string()))
+    }
+
+    async fn run(
+        &self,
+        script: &WorldScript,
+        event: &ScriptEvent,
+    ) -> Result<ScriptOutput, ScriptError> {
+        let script = script.clone();
+        let event = event.clone();
+        tokio::task::spawn_blocking(move || run_blocking(&script, &event))
+            .await
+            .map_err(|e| ScriptError::Runtime(format!("script task failed: {e}")))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wrldbldr_domain::{PlayerCharacterId, ScriptHook, WorldId};
+
+    fn event(pc: bool) -> ScriptEvent {
+        ScriptEvent {
+            hook: ScriptHook::ItemPickup,
+            world_id: WorldId::new(),
+            pc_id: pc.then(PlayerCharacterId::new),
+            data: serde_json::json!({ "itemName": "Bell" }),
+        }
+    }
+
+    async fn run(source: &str, pc: bool) -> Result<ScriptOutput, ScriptError> {
+        let script = WorldScript::new("test", ScriptHook::ItemPickup, source);
+        RhaiScriptEngine::new().run(&script, &event(pc)).await
+    }
+
+    #[tokio::test]
+    async fn test_script_queues_effects_and_notices() {
+        let output = run(
+            r#"
+            if event.data.itemName == "Bell" {
+                give_item("Clapper", 2);
+                set_flag("bell_found", true);
+                notify_dm(`${event.data.itemName} picked up`);
+            }
+            "#,
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output.notices, vec!["Bell picked up".to_string()]);
+        assert!(matches!(
+            output.effects.as_slice(),
+            [
+                EventEffect::GiveItem { item_name, quantity: 2, .. },
+                EventEffect::SetFlag { flag_name, value: true },
+            ] if item_name == "Clapper" && flag_name == "bell_found"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_budget_stops_runaway_loop() {
+        let script =
+            WorldScript::new("spin", ScriptHook::TimeAdvance, "loop {}").with_operation_budget(500);
+        let err = RhaiScriptEngine::new()
+            .run(&script, &event(false))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ScriptError::BudgetExceeded(500)));
+    }
+
+    #[tokio::test]
+    async fn test_item_calls_need_a_player_character() {
+        let err = run(r#"give_item("Bell")"#, false).await.unwrap_err();
+        assert!(matches!(err, ScriptError::Runtime(_)));
+        assert!(run(r#"set_flag("rang", true)"#, false).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_imports_and_eval_are_unavailable() {
+        assert!(run(r#"import "os" as os;"#, true).await.is_err());
+        assert!(run(r#"eval("1 + 1")"#, true).await.is_err());
+    }
+
+    #[test]
+    fn test_check_reports_syntax_errors() {
+        let engine = RhaiScriptEngine::new();
+        assert!(engine.check(r#"notify_dm("hi");"#).is_ok());
+        assert!(matches!(
+            engine.check("let x = ;"),
+            Err(ScriptError::Compile(_))
+        ));
+    }
+}
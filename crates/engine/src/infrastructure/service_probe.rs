@@ -0,0 +1,139 @@
+//! Live service connectivity checks for first-run setup and health checks.
+//!
+//! Implements ServiceProbePort by connecting to each service with the
+//! candidate settings, independent of the engine's running clients.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::infrastructure::ports::{ProbeError, ServiceProbePort};
+
+/// How long each check may take before the service counts as unreachable
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct LiveServiceProbe {
+    client: Client,
+}
+
+impl LiveServiceProbe {
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .timeout(PROBE_TIMEOUT)
+            .build()
+            .unwrap_or_else(|_| Client::new());
+        Self { client }
+    }
+
+    async fn get(&self, url: String) -> Result<reqwest::Response, ProbeError> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| ProbeError::Unreachable(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(ProbeError::Rejected(format!(
+                "Unexpected response: {}",
+                response.status()
+            )));
+        }
+        Ok(response)
+    }
+}
+
+impl Default for LiveServiceProbe {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ServiceProbePort for LiveServiceProbe {
+    async fn check_neo4j(&self, uri: &str, user: &str, password: &str) -> Result<(), ProbeError> {
+        let check = async {
+            let graph = neo4rs::Graph::new(uri, user, password)
+                .await
+                .map_err(|e| ProbeError::Unreachable(e.to_string()))?;
+            graph
+                .run(neo4rs::query("RETURN 1"))
+                .await
+                .map_err(|e| ProbeError::Rejected(e.to_string()))
+        };
+
+        tokio::time::timeout(PROBE_TIMEOUT, check)
+            .await
+            .map_err(|_| ProbeError::Unreachable("timed out".to_string()))?
+    }
+
+    async fn check_ollama(&self, base_url: &str, model: &str) -> Result<(), ProbeError> {
+        let url = format!("{}/api/tags", base_url.trim_end_matches('/'));
+        let tags: OllamaTags = self
+            .get(url)
+            .await?
+            .json()
+            .await
+            .map_err(|e| ProbeError::Rejected(format!("Not an Ollama server: {}", e)))?;
+
+        // Ollama reports "llama3.2:latest" for a model pulled as "llama3.2"
+        let has_model = tags
+            .models
+            .iter()
+            .any(|m| m.name == model || m.name.strip_suffix(":latest") == Some(model));
+        if has_model {
+            Ok(())
+        } else {
+            Err(ProbeError::Rejected(format!(
+                "Model '{}' is not pulled (run `ollama pull {}`)",
+                model, model
+            )))
+        }
+    }
+
+    async fn check_comfyui(&self, base_url: &str) -> Result<(), ProbeError> {
+        let url = format!("{}/system_stats", base_url.trim_end_matches('/'));
+        self.get(url).await.map(|_| ())
+    }
+
+    async fn free_disk_space(&self, path: &str) -> Result<u64, ProbeError> {
+        // The directory may not exist until the first asset is written, so
+        // measure the nearest existing ancestor (same filesystem).
+        let path = Path::new(path);
+        let existing = path
+            .ancestors()
+            .find(|p| !p.as_os_str().is_empty() && p.exists())
+            .unwrap_or(Path::new("."))
+            .to_path_buf();
+
+        tokio::task::spawn_blocking(move || free_bytes(&existing))
+            .await
+            .map_err(|e| ProbeError::Rejected(e.to_string()))?
+    }
+}
+
+#[cfg(unix)]
+fn free_bytes(path: &Path) -> Result<u64, ProbeError> {
+    let stats = nix::sys::statvfs::statvfs(path)
+        .map_err(|e| ProbeError::Rejected(format!("Could not read disk stats: {}", e)))?;
+    Ok(stats.blocks_available() as u64 * stats.fragment_size() as u64)
+}
+
+#[cfg(not(unix))]
+fn free_bytes(_path: &Path) -> Result<u64, ProbeError> {
+    Err(ProbeError::Rejected(
+        "Disk space checks are not supported on this platform".to_string(),
+    ))
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaTags {
+    #[serde(default)]
+    models: Vec<OllamaModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaModel {
+    name: String,
+}
@@ -0,0 +1,182 @@
+//! SQLite-backed per-world usage accounting.
+//!
+//! Counters are aggregated per world and UTC day: each record adds to the
+//! day's row, so a report is one row per day regardless of call volume.
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+use uuid::Uuid;
+use wrldbldr_domain::WorldId;
+
+use crate::infrastructure::ports::{DailyUsage, RepoError, UsageDelta, UsageRepo};
+
+/// SQLite implementation for usage counters.
+pub struct SqliteUsageRepo {
+    pool: SqlitePool,
+}
+
+impl SqliteUsageRepo {
+    pub async fn new(db_path: &str) -> Result<Self, RepoError> {
+        let pool = SqlitePool::connect(&format!("sqlite:{}?mode=rwc", db_path))
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS world_usage_daily (
+                world_id TEXT NOT NULL,
+                day TEXT NOT NULL,
+                llm_requests INTEGER NOT NULL DEFAULT 0,
+                prompt_tokens INTEGER NOT NULL DEFAULT 0,
+                completion_tokens INTEGER NOT NULL DEFAULT 0,
+                image_generations INTEGER NOT NULL DEFAULT 0,
+                storage_bytes INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (world_id, day)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        Ok(Self { pool })
+    }
+}
+
+fn day_key(day: NaiveDate) -> String {
+    day.format("%Y-%m-%d").to_string()
+}
+
+fn row_to_usage(row: &SqliteRow) -> Result<DailyUsage, RepoError> {
+    let day: String = row.get("day");
+    let day = NaiveDate::parse_from_str(&day, "%Y-%m-%d")
+        .map_err(|e| RepoError::Serialization(e.to_string()))?;
+    let counter = |column: &str| row.get::<i64, _>(column).max(0) as u64;
+
+    Ok(DailyUsage {
+        day,
+        llm_requests: counter("llm_requests"),
+        prompt_tokens: counter("prompt_tokens"),
+        completion_tokens: counter("completion_tokens"),
+        image_generations: counter("image_generations"),
+        storage_bytes: counter("storage_bytes"),
+    })
+}
+
+#[async_trait]
+impl UsageRepo for SqliteUsageRepo {
+    async fn record(
+        &self,
+        world_id: WorldId,
+        day: NaiveDate,
+        delta: UsageDelta,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            INSERT INTO world_usage_daily
+                (world_id, day, llm_requests, prompt_tokens, completion_tokens,
+                 image_generations, storage_bytes)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(world_id, day) DO UPDATE SET
+                llm_requests = llm_requests + excluded.llm_requests,
+                prompt_tokens = prompt_tokens + excluded.prompt_tokens,
+                completion_tokens = completion_tokens + excluded.completion_tokens,
+                image_generations = image_generations + excluded.image_generations,
+                storage_bytes = storage_bytes + excluded.storage_bytes
+            "#,
+        )
+        .bind(world_id.to_string())
+        .bind(day_key(day))
+        .bind(delta.llm_requests as i64)
+        .bind(delta.prompt_tokens as i64)
+        .bind(delta.completion_tokens as i64)
+        .bind(delta.image_generations as i64)
+        .bind(delta.storage_bytes as i64)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn list_daily(
+        &self,
+        world_id: WorldId,
+        since: NaiveDate,
+    ) -> Result<Vec<DailyUsage>, RepoError> {
+        let rows = sqlx::query(
+            "SELECT * FROM world_usage_daily WHERE world_id = ? AND day >= ? ORDER BY day",
+        )
+        .bind(world_id.to_string())
+        .bind(day_key(since))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        rows.iter().map(row_to_usage).collect()
+    }
+
+    async fn list_for_day(&self, day: NaiveDate) -> Result<Vec<(WorldId, DailyUsage)>, RepoError> {
+        let rows = sqlx::query("SELECT * FROM world_usage_daily WHERE day = ?")
+            .bind(day_key(day))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepoError::Database(e.to_string()))?;
+
+        rows.iter()
+            .map(|row| {
+                let world_id: String = row.get("world_id");
+                let world_id = Uuid::parse_str(&world_id)
+                    .map_err(|e| RepoError::Serialization(e.to_string()))?;
+                Ok((WorldId::from_uuid(world_id), row_to_usage(row)?))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn record_accumulates_per_world_and_day() {
+        let temp_dir = tempfile::tempdir().expect("tempdir");
+        let db_path = temp_dir.path().join("usage.db");
+        let repo = SqliteUsageRepo::new(&db_path.to_string_lossy())
+            .await
+            .expect("repo");
+
+        let world_id = WorldId::new();
+        let other_world = WorldId::new();
+        let day1 = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let day2 = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+        let llm = UsageDelta {
+            llm_requests: 1,
+            prompt_tokens: 100,
+            completion_tokens: 40,
+            ..Default::default()
+        };
+        repo.record(world_id, day1, llm).await.unwrap();
+        repo.record(world_id, day1, llm).await.unwrap();
+        repo.record(world_id, day2, UsageDelta::image(2048))
+            .await
+            .unwrap();
+        repo.record(other_world, day2, llm).await.unwrap();
+
+        let daily = repo.list_daily(world_id, day1).await.unwrap();
+        assert_eq!(daily.len(), 2);
+        assert_eq!(daily[0].day, day1);
+        assert_eq!(daily[0].llm_requests, 2);
+        assert_eq!(daily[0].total_tokens(), 280);
+        assert_eq!(daily[1].image_generations, 1);
+        assert_eq!(daily[1].storage_bytes, 2048);
+
+        let since_day2 = repo.list_daily(world_id, day2).await.unwrap();
+        assert_eq!(since_day2.len(), 1);
+
+        let day2_worlds = repo.list_for_day(day2).await.unwrap();
+        assert_eq!(day2_worlds.len(), 2);
+    }
+}
@@ -0,0 +1,475 @@
+//! WebAssembly plugin host.
+//!
+//! Loads `*.wasm` plugins from a directory and runs them in the `wasmi`
+//! interpreter. Every call gets a fresh instance with a fuel budget and a
+//! memory cap, and the only import a plugin may use is `wrldbldr.log`, so a
+//! plugin can compute but has no way to reach files, the network or the
+//! engine's state. Modules importing anything else fail to load.
+//!
+//! # ABI
+//!
+//! A plugin exports its linear `memory` and:
+//!
+//! - `wrldbldr_alloc(len: i32) -> i32`: reserve `len` bytes for host input
+//! - `wrldbldr_manifest() -> i64`: the [`PluginManifest`] as JSON
+//! - `wrldbldr_call(ptr: i32, len: i32) -> i64`: run the [`PluginCall`] JSON
+//!   at `ptr`, returning a [`PluginOutput`] or `{"error": "..."}` as JSON
+//!
+//! Strings are returned packed as `(ptr << 32) | len`. The host import
+//! `wrldbldr.log(ptr: i32, len: i32)` writes a UTF-8 message to the engine log.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use wasmi::core::TrapCode;
+use wasmi::{Caller, Config, Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+use wrldbldr_domain::{PluginManifest, PluginOutput};
+
+use super::ports::{PluginCall, PluginCallKind, PluginError, PluginPort};
+
+/// Resource limits applied to every plugin call
+#[derive(Debug, Clone, Copy)]
+pub struct PluginLimits {
+    /// Instructions (roughly) a call may execute before it is aborted
+    pub fuel: u64,
+    /// Largest linear memory a plugin may grow to
+    pub max_memory_bytes: usize,
+    /// Largest JSON result accepted from a plugin
+    pub max_output_bytes: usize,
+}
+
+impl Default for PluginLimits {
+    fn default() -> Self {
+        Self {
+            fuel: 50_000_000,
+            max_memory_bytes: 16 * 1024 * 1024,
+            max_output_bytes: 1024 * 1024,
+        }
+    }
+}
+
+const MAX_LOG_BYTES: usize = 1024;
+
+struct LoadedPlugin {
+    manifest: PluginManifest,
+    module: Arc<Module>,
+}
+
+/// Plugin host backed by the `wasmi` interpreter.
+pub struct WasmPluginHost {
+    engine: Engine,
+    limits: PluginLimits,
+    plugins: Vec<LoadedPlugin>,
+}
+
+struct HostState {
+    plugin: String,
+    limits: StoreLimits,
+}
+
+impl WasmPluginHost {
+    /// A host without plugins.
+    pub fn new(limits: PluginLimits) -> Self {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        Self {
+            engine: Engine::new(&config),
+            limits,
+            plugins: Vec::new(),
+        }
+    }
+
+    /// Load plugins from `PLUGIN_DIR`, if set.
+    pub fn from_env() -> Self {
+        let mut host = Self::new(PluginLimits::default());
+        if let Ok(dir) = std::env::var("PLUGIN_DIR") {
+            host.load_dir(Path::new(&dir));
+        }
+        host
+    }
+
+    /// Load every `*.wasm` file in `dir`, in name order.
+    ///
+    /// Plugins that fail to load are logged and skipped so one broken plugin
+    /// doesn't keep the engine from starting.
+    pub fn load_dir(&mut self, dir: &Path) {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                tracing::warn!(dir = %dir.display(), error = %e, "Could not read plugin directory");
+                return;
+            }
+        };
+        let mut paths: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "wasm"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let loaded = std::fs::read(&path)
+                .map_err(|e| PluginError::Invalid(e.to_string()))
+                .and_then(|bytes| self.load(&bytes));
+            match loaded {
+                Ok(manifest) => tracing::info!(
+                    path = %path.display(),
+                    plugin = %manifest.name,
+                    version = %manifest.version,
+                    tools = manifest.tools.len(),
+                    effects = manifest.effects.len(),
+                    capabilities = ?manifest.capabilities,
+                    "Loaded plugin"
+                ),
+                Err(e) => {
+                    tracing::error!(path = %path.display(), error = %e, "Skipping plugin")
+                }
+            }
+        }
+    }
+
+    /// Compile a plugin and read its manifest.
+    pub fn load(&mut self, wasm: &[u8]) -> Result<PluginManifest, PluginError> {
+        let module = Module::new(&self.engine, wasm)
+            .map_err(|e| PluginError::Invalid(format!("not a valid module: {e}")))?;
+        let output = run(&self.engine, &module, "<loading>", &self.limits, None)
+            .map_err(PluginError::Invalid)?;
+        let manifest: PluginManifest = serde_json::from_slice(&output)
+            .map_err(|e| PluginError::Invalid(format!("bad manifest: {e}")))?;
+        manifest.validate().map_err(PluginError::Invalid)?;
+        if self.find(&manifest.name).is_some() {
+            return Err(PluginError::Invalid(format!(
+                "a plugin named {:?} is already loaded",
+                manifest.name
+            )));
+        }
+
+        self.plugins.push(LoadedPlugin {
+            manifest: manifest.clone(),
+            module: Arc::new(module),
+        });
+        Ok(manifest)
+    }
+
+    fn find(&self, name: &str) -> Option<&LoadedPlugin> {
+        self.plugins.iter().find(|p| p.manifest.name == name)
+    }
+}
+
+#[async_trait]
+impl PluginPort for WasmPluginHost {
+    fn manifests(&self) -> Vec<PluginManifest> {
+        self.plugins.iter().map(|p| p.manifest.clone()).collect()
+    }
+
+    async fn call(&self, plugin: &str, call: PluginCall) -> Result<PluginOutput, PluginError> {
+        let loaded = self
+            .find(plugin)
+            .ok_or_else(|| PluginError::UnknownPlugin(plugin.to_string()))?;
+        let declared = match call.kind {
+            PluginCallKind::Tool => loaded.manifest.tool(&call.name).is_some(),
+            PluginCallKind::Effect => loaded.manifest.effect(&call.name).is_some(),
+        };
+        if !declared {
+            return Err(PluginError::UnknownEntry {
+                plugin: plugin.to_string(),
+                kind: call.kind,
+                name: call.name,
+            });
+        }
+
+        let failed = |message: String| PluginError::Failed {
+            plugin: plugin.to_string(),
+            message,
+        };
+        let input = serde_json::to_vec(&call).map_err(|e| failed(e.to_string()))?;
+        let engine = self.engine.clone();
+        let module = loaded.module.clone();
+        let name = plugin.to_string();
+        let limits = self.limits;
+        let output = tokio::task::spawn_blocking(move || {
+            run(&engine, &module, &name, &limits, Some(&input))
+        })
+        .await
+        .map_err(|e| failed(e.to_string()))?
+        .map_err(failed)?;
+
+        let value: serde_json::Value =
+            serde_json::from_slice(&output).map_err(|e| failed(format!("bad output: {e}")))?;
+        if let Some(error) = value.get("error") {
+            let message = error
+                .as_str()
+                .map_or_else(|| error.to_string(), str::to_string);
+            return Err(failed(message));
+        }
+        serde_json::from_value(value).map_err(|e| failed(format!("bad output: {e}")))
+    }
+}
+
+/// Instantiate `module` and call `wrldbldr_call` with `input`, or
+/// `wrldbldr_manifest` when there is no input. Returns the result bytes.
+fn run(
+    engine: &Engine,
+    module: &Module,
+    plugin: &str,
+    limits: &PluginLimits,
+    input: Option<&[u8]>,
+) -> Result<Vec<u8>, String> {
+    let mut store = Store::new(
+        engine,
+        HostState {
+            plugin: plugin.to_string(),
+            limits: StoreLimitsBuilder::new()
+                .memory_size(limits.max_memory_bytes)
+                .instances(1)
+                .memories(1)
+                .tables(1)
+                .build(),
+        },
+    );
+    store.limiter(|state| &mut state.limits);
+    store.set_fuel(limits.fuel).map_err(|e| e.to_string())?;
+
+    let mut linker = Linker::<HostState>::new(engine);
+    linker
+        .func_wrap("wrldbldr", "log", host_log)
+        .map_err(|e| e.to_string())?;
+    let instance = linker
+        .instantiate(&mut store, module)
+        .and_then(|pre| pre.start(&mut store))
+        .map_err(describe)?;
+    let memory = instance
+        .get_memory(&store, "memory")
+        .ok_or("plugin does not export `memory`")?;
+
+    let packed = match input {
+        None => instance
+            .get_typed_func::<(), i64>(&store, "wrldbldr_manifest")
+            .map_err(|e| format!("wrldbldr_manifest: {e}"))?
+            .call(&mut store, ())
+            .map_err(describe)?,
+        Some(input) => {
+            let len = i32::try_from(input.len()).map_err(|_| "input too large")?;
+            let ptr = instance
+                .get_typed_func::<i32, i32>(&store, "wrldbldr_alloc")
+                .map_err(|e| format!("wrldbldr_alloc: {e}"))?
+                .call(&mut store, len)
+                .map_err(describe)?;
+            memory
+                .write(&mut store, ptr as u32 as usize, input)
+                .map_err(|e| format!("writing input: {e}"))?;
+            instance
+                .get_typed_func::<(i32, i32), i64>(&store, "wrldbldr_call")
+                .map_err(|e| format!("wrldbldr_call: {e}"))?
+                .call(&mut store, (ptr, len))
+                .map_err(describe)?
+        }
+    };
+
+    let (ptr, len) = unpack(packed);
+    if len > limits.max_output_bytes {
+        return Err(format!(
+            "output of {len} bytes exceeds the {} byte limit",
+            limits.max_output_bytes
+        ));
+    }
+    let mut output = vec![0; len];
+    memory
+        .read(&store, ptr, &mut output)
+        .map_err(|e| format!("reading output: {e}"))?;
+    Ok(output)
+}
+
+fn host_log(caller: Caller<'_, HostState>, ptr: i32, len: i32) {
+    let Some(memory) = caller.get_export("memory").and_then(|e| e.into_memory()) else {
+        return;
+    };
+    let mut bytes = vec![0; (len as u32 as usize).min(MAX_LOG_BYTES)];
+    if memory
+        .read(&caller, ptr as u32 as usize, &mut bytes)
+        .is_ok()
+    {
+        tracing::info!(
+            plugin = %caller.data().plugin,
+            "{}",
+            String::from_utf8_lossy(&bytes)
+        );
+    }
+}
+
+fn unpack(packed: i64) -> (usize, usize) {
+    let packed = packed as u64;
+    ((packed >> 32) as usize, (packed & 0xffff_ffff) as usize)
+}
+
+fn describe(error: wasmi::Error) -> String {
+    match error.as_trap_code() {
+        Some(TrapCode::OutOfFuel) => "ran out of fuel".to_string(),
+        _ => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wrldbldr_domain::{EventEffect, PluginCapability, WorldId};
+
+    /// A plugin whose manifest and call result are fixed strings.
+    ///
+    /// `body` runs at the start of `wrldbldr_call`.
+    fn plugin_wat(manifest: &str, output: &str, body: &str) -> String {
+        let escape = |s: &str| {
+            s.replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n")
+        };
+        let output_at = 4096;
+        format!(
+            r#"(module
+                (import "wrldbldr" "log" (func $log (param i32 i32)))
+                (memory (export "memory") 1)
+                (data (i32.const 0) "{manifest}")
+                (data (i32.const {output_at}) "{output}")
+                (func (export "wrldbldr_alloc") (param i32) (result i32) i32.const 8192)
+                (func (export "wrldbldr_manifest") (result i64)
+                    i64.const {manifest_len})
+                (func (export "wrldbldr_call") (param i32 i32) (result i64)
+                    (call $log (i32.const {output_at}) (i32.const 7))
+                    {body}
+                    i64.const {packed_output}))"#,
+            manifest = escape(manifest),
+            output = escape(output),
+            manifest_len = manifest.len(),
+            packed_output = (output_at << 32) | output.len() as i64,
+        )
+    }
+
+    fn loot_plugin(body: &str) -> Vec<u8> {
+        let manifest = r#"{"name": "loot", "version": "1.0.0",
+            "capabilities": ["give_items"],
+            "tools": [{"name": "roll_loot", "description": "Roll on the loot table"}],
+            "effects": [{"name": "chest", "description": "Open a chest"}]}"#;
+        let output = r#"{"summary": "Found a key", "effects": [
+            {"giveItem": {"item_name": "Rusty Key", "item_description": null, "quantity": 1}}]}"#;
+        wat::parse_str(plugin_wat(manifest, output, body)).unwrap()
+    }
+
+    fn call(kind: PluginCallKind, name: &str) -> PluginCall {
+        PluginCall {
+            kind,
+            name: name.to_string(),
+            arguments: serde_json::json!({ "table": "dungeon" }),
+            world_id: WorldId::new(),
+            pc_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn loads_manifest_and_runs_tools() {
+        let mut host = WasmPluginHost::new(PluginLimits::default());
+        let manifest = host.load(&loot_plugin("")).unwrap();
+        assert_eq!(manifest.name, "loot");
+        assert_eq!(manifest.capabilities, vec![PluginCapability::GiveItems]);
+        assert_eq!(host.manifests().len(), 1);
+
+        let output = host
+            .call("loot", call(PluginCallKind::Tool, "roll_loot"))
+            .await
+            .unwrap();
+        assert_eq!(output.summary, "Found a key");
+        assert!(matches!(
+            output.effects.as_slice(),
+            [EventEffect::GiveItem { item_name, quantity: 1, .. }] if item_name == "Rusty Key"
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_undeclared_entries_and_unknown_plugins() {
+        let mut host = WasmPluginHost::new(PluginLimits::default());
+        host.load(&loot_plugin("")).unwrap();
+
+        let err = host
+            .call("loot", call(PluginCallKind::Effect, "roll_loot"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PluginError::UnknownEntry { .. }), "{err}");
+        let err = host
+            .call("other", call(PluginCallKind::Tool, "roll_loot"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PluginError::UnknownPlugin(_)), "{err}");
+    }
+
+    #[tokio::test]
+    async fn runaway_plugins_run_out_of_fuel() {
+        let mut host = WasmPluginHost::new(PluginLimits {
+            fuel: 100_000,
+            ..Default::default()
+        });
+        host.load(&loot_plugin("(loop $spin (br $spin))")).unwrap();
+
+        let err = host
+            .call("loot", call(PluginCallKind::Tool, "roll_loot"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("ran out of fuel"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn memory_growth_is_capped() {
+        let mut host = WasmPluginHost::new(PluginLimits {
+            max_memory_bytes: 2 * 65536,
+            ..Default::default()
+        });
+        // memory.grow returns -1 when refused; trap if it was allowed
+        host.load(&loot_plugin(
+            "(if (i32.ne (memory.grow (i32.const 4)) (i32.const -1)) (then unreachable))",
+        ))
+        .unwrap();
+
+        host.call("loot", call(PluginCallKind::Tool, "roll_loot"))
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn modules_with_other_imports_do_not_load() {
+        let wasm = wat::parse_str(
+            r#"(module
+                (import "wasi_snapshot_preview1" "fd_write"
+                    (func (param i32 i32 i32 i32) (result i32)))
+                (memory (export "memory") 1)
+                (func (export "wrldbldr_manifest") (result i64) i64.const 0))"#,
+        )
+        .unwrap();
+        let mut host = WasmPluginHost::new(PluginLimits::default());
+        assert!(matches!(host.load(&wasm), Err(PluginError::Invalid(_))));
+        assert!(host.manifests().is_empty());
+    }
+
+    #[test]
+    fn duplicate_plugin_names_do_not_load() {
+        let mut host = WasmPluginHost::new(PluginLimits::default());
+        host.load(&loot_plugin("")).unwrap();
+        assert!(matches!(
+            host.load(&loot_plugin("")),
+            Err(PluginError::Invalid(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn plugin_errors_are_reported() {
+        let manifest = r#"{"name": "broken", "version": "0.1.0",
+            "tools": [{"name": "t", "description": "d"}]}"#;
+        let wasm =
+            wat::parse_str(plugin_wat(manifest, r#"{"error": "table not found"}"#, "")).unwrap();
+        let mut host = WasmPluginHost::new(PluginLimits::default());
+        host.load(&wasm).unwrap();
+
+        let err = host
+            .call("broken", call(PluginCallKind::Tool, "t"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Plugin broken failed: table not found");
+    }
+}
@@ -0,0 +1,114 @@
+//! Whisper speech-to-text client
+//!
+//! Implements the TranscriptionPort trait against a whisper.cpp server
+//! (`whisper-server`), which takes a clip on `POST /inference` and answers
+//! with the text as JSON.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::infrastructure::ports::{TranscriptionError, TranscriptionPort, TranscriptionRequest};
+
+/// Client for a whisper.cpp server
+#[derive(Clone)]
+pub struct WhisperClient {
+    client: Client,
+    /// `None` when no server is configured; every request is then unavailable
+    base_url: Option<String>,
+}
+
+impl WhisperClient {
+    pub fn new(base_url: &str) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(60))
+            .build()
+            .unwrap_or_else(|_| Client::new());
+
+        Self {
+            client,
+            base_url: Some(base_url.trim_end_matches('/').to_string()),
+        }
+    }
+
+    /// Use the server at `WHISPER_URL`, if set.
+    pub fn from_env() -> Self {
+        match std::env::var("WHISPER_URL")
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+        {
+            Some(url) => Self::new(url.trim()),
+            None => Self {
+                client: Client::new(),
+                base_url: None,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl TranscriptionPort for WhisperClient {
+    async fn transcribe(
+        &self,
+        request: TranscriptionRequest,
+    ) -> Result<String, TranscriptionError> {
+        let base_url = self
+            .base_url
+            .as_deref()
+            .ok_or(TranscriptionError::Unavailable)?;
+
+        let extension = request
+            .content_type
+            .rsplit('/')
+            .next()
+            .unwrap_or("webm")
+            .to_string();
+        let part = reqwest::multipart::Part::bytes(request.audio)
+            .file_name(format!("clip.{}", extension))
+            .mime_str(&request.content_type)
+            .map_err(|e| TranscriptionError::Failed(e.to_string()))?;
+        let form = reqwest::multipart::Form::new()
+            .part("file", part)
+            .text("response_format", "json")
+            .text("temperature", "0.0")
+            .text(
+                "language",
+                request.language.unwrap_or_else(|| "auto".to_string()),
+            );
+
+        let response = self
+            .client
+            .post(format!("{}/inference", base_url))
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_connect() {
+                    TranscriptionError::Unavailable
+                } else {
+                    TranscriptionError::Failed(e.to_string())
+                }
+            })?;
+
+        if !response.status().is_success() {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TranscriptionError::Failed(error_text));
+        }
+
+        let body: InferenceResponse = response
+            .json()
+            .await
+            .map_err(|e| TranscriptionError::Failed(e.to_string()))?;
+        Ok(body.text)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct InferenceResponse {
+    #[serde(default)]
+    text: String,
+}
@@ -0,0 +1,165 @@
+//! Minimal zip archive writer
+//!
+//! Writes deflate-compressed entries into an in-memory zip file. Only what
+//! diagnostics bundles need: no zip64, no directories, no encryption.
+
+use std::io::{self, Write};
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use flate2::write::DeflateEncoder;
+use flate2::{Compression, Crc};
+
+const LOCAL_FILE_HEADER: u32 = 0x0403_4b50;
+const CENTRAL_DIRECTORY_HEADER: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+/// Version 2.0: deflate
+const VERSION: u16 = 20;
+/// Entry names are UTF-8
+const FLAG_UTF8: u16 = 0x0800;
+const METHOD_DEFLATE: u16 = 8;
+
+struct CentralEntry {
+    name: String,
+    crc: u32,
+    compressed_size: u32,
+    size: u32,
+    offset: u32,
+}
+
+fn too_large() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "zip entry too large")
+}
+
+/// MS-DOS time and date, as stored in zip headers
+fn dos_time(time: DateTime<Utc>) -> (u16, u16) {
+    let dos_time = (time.hour() << 11) | (time.minute() << 5) | (time.second() / 2);
+    let year = time.year().clamp(1980, 2107) as u32 - 1980;
+    let dos_date = (year << 9) | (time.month() << 5) | time.day();
+    (dos_time as u16, dos_date as u16)
+}
+
+/// Build a zip archive of `(name, contents)` entries, all stamped `modified`.
+pub fn write_zip(entries: &[(String, Vec<u8>)], modified: DateTime<Utc>) -> io::Result<Vec<u8>> {
+    let (time, date) = dos_time(modified);
+    let mut out = Vec::new();
+    let mut central = Vec::with_capacity(entries.len());
+
+    for (name, contents) in entries {
+        let mut crc = Crc::new();
+        crc.update(contents);
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(contents)?;
+        let compressed = encoder.finish()?;
+
+        let entry = CentralEntry {
+            name: name.clone(),
+            crc: crc.sum(),
+            compressed_size: u32::try_from(compressed.len()).map_err(|_| too_large())?,
+            size: u32::try_from(contents.len()).map_err(|_| too_large())?,
+            offset: u32::try_from(out.len()).map_err(|_| too_large())?,
+        };
+
+        out.extend_from_slice(&LOCAL_FILE_HEADER.to_le_bytes());
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        out.extend_from_slice(&FLAG_UTF8.to_le_bytes());
+        out.extend_from_slice(&METHOD_DEFLATE.to_le_bytes());
+        out.extend_from_slice(&time.to_le_bytes());
+        out.extend_from_slice(&date.to_le_bytes());
+        out.extend_from_slice(&entry.crc.to_le_bytes());
+        out.extend_from_slice(&entry.compressed_size.to_le_bytes());
+        out.extend_from_slice(&entry.size.to_le_bytes());
+        out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(&compressed);
+
+        central.push(entry);
+    }
+
+    let directory_offset = u32::try_from(out.len()).map_err(|_| too_large())?;
+    for entry in &central {
+        out.extend_from_slice(&CENTRAL_DIRECTORY_HEADER.to_le_bytes());
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        out.extend_from_slice(&FLAG_UTF8.to_le_bytes());
+        out.extend_from_slice(&METHOD_DEFLATE.to_le_bytes());
+        out.extend_from_slice(&time.to_le_bytes());
+        out.extend_from_slice(&date.to_le_bytes());
+        out.extend_from_slice(&entry.crc.to_le_bytes());
+        out.extend_from_slice(&entry.compressed_size.to_le_bytes());
+        out.extend_from_slice(&entry.size.to_le_bytes());
+        out.extend_from_slice(&(entry.name.len() as u16).to_le_bytes());
+        // Extra field, comment, disk number, internal and external attributes
+        out.extend_from_slice(&[0u8; 12]);
+        out.extend_from_slice(&entry.offset.to_le_bytes());
+        out.extend_from_slice(entry.name.as_bytes());
+    }
+    let directory_size = u32::try_from(out.len()).map_err(|_| too_large())? - directory_offset;
+
+    let count = u16::try_from(central.len()).map_err(|_| too_large())?;
+    out.extend_from_slice(&END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+    // This disk, and the disk the directory starts on
+    out.extend_from_slice(&[0u8; 4]);
+    out.extend_from_slice(&count.to_le_bytes());
+    out.extend_from_slice(&count.to_le_bytes());
+    out.extend_from_slice(&directory_size.to_le_bytes());
+    out.extend_from_slice(&directory_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use flate2::read::DeflateDecoder;
+    use std::io::Read;
+
+    fn u16_at(bytes: &[u8], at: usize) -> u16 {
+        u16::from_le_bytes([bytes[at], bytes[at + 1]])
+    }
+
+    fn u32_at(bytes: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn entries_round_trip_through_the_central_directory() {
+        let modified = Utc.with_ymd_and_hms(2024, 3, 9, 14, 30, 10).unwrap();
+        let entries = vec![
+            ("manifest.json".to_string(), b"{\"ok\":true}".to_vec()),
+            ("logs.jsonl".to_string(), "line\n".repeat(100).into_bytes()),
+        ];
+
+        let zip = write_zip(&entries, modified).unwrap();
+
+        let end = zip.len() - 22;
+        assert_eq!(u32_at(&zip, end), END_OF_CENTRAL_DIRECTORY);
+        assert_eq!(u16_at(&zip, end + 10), 2);
+
+        // Second entry, via the central directory
+        let mut at = u32_at(&zip, end + 16) as usize;
+        at += 46 + u16_at(&zip, at + 28) as usize;
+        assert_eq!(u32_at(&zip, at), CENTRAL_DIRECTORY_HEADER);
+        let compressed_size = u32_at(&zip, at + 20) as usize;
+        let local = u32_at(&zip, at + 42) as usize;
+        assert_eq!(u32_at(&zip, local), LOCAL_FILE_HEADER);
+        assert_eq!(
+            dos_time(modified),
+            (u16_at(&zip, local + 10), u16_at(&zip, local + 12))
+        );
+
+        let name_len = u16_at(&zip, local + 26) as usize;
+        assert_eq!(&zip[local + 30..local + 30 + name_len], b"logs.jsonl");
+        let data = local + 30 + name_len;
+        let mut contents = Vec::new();
+        DeflateDecoder::new(&zip[data..data + compressed_size])
+            .read_to_end(&mut contents)
+            .unwrap();
+        assert_eq!(contents, entries[1].1);
+
+        let mut crc = Crc::new();
+        crc.update(&contents);
+        assert_eq!(u32_at(&zip, local + 14), crc.sum());
+    }
+}
@@ -14,6 +14,8 @@ pub mod api;
 pub mod app;
 pub mod entities;
 pub mod infrastructure;
+#[cfg(test)]
+mod simulation;
 pub mod use_cases;
 
 pub use app::App;
@@ -15,18 +15,40 @@ mod api;
 mod app;
 mod entities;
 mod infrastructure;
+#[cfg(test)]
+mod simulation;
 mod use_cases;
 
+/// How often the dependency health monitor re-checks each dependency
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How often world usage is checked against the daily quotas
+const USAGE_QUOTA_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How often pacing timers are checked for a new minute, warning or end
+const PACING_TICK_INTERVAL: Duration = Duration::from_secs(1);
+
 use api::{websocket::WsState, ConnectionManager};
 use app::App;
 use infrastructure::{
+    blob_store::{LocalBlobStore, S3BlobStore, S3Config},
+    circuit_breaker::{CircuitBreakerConfig, ServiceCircuitBreakers},
     clock::SystemClock,
     comfyui::ComfyUIClient,
-    neo4j::Neo4jRepositories,
+    diagnostics::{Diagnostics, DEFAULT_LOG_CAPACITY},
+    llm_cache::CachedLlmClient,
+    llm_router::WorldLlmRouter,
+    llm_usage::MeteredLlmClient,
+    neo4j::{Neo4jRepositories, ResilientGraph},
     ollama::OllamaClient,
+    ports::{BlobStorePort, ClockPort, SettingsRepo},
     queue::SqliteQueue,
     resilient_llm::{ResilientLlmClient, RetryConfig},
+    service_probe::LiveServiceProbe,
     settings::SqliteSettingsRepo,
+    usage::SqliteUsageRepo,
+    wasm_plugins::WasmPluginHost,
+    whisper::WhisperClient,
 };
 
 #[tokio::main]
@@ -34,65 +56,145 @@ async fn main() -> anyhow::Result<()> {
     // Load environment from repo root (Taskfile runs the engine from `crates/engine`).
     load_dotenv_from_repo_root();
 
-    // Initialize logging
+    // Initialize logging; recent events are also kept for diagnostics bundles
+    let diagnostics = Arc::new(Diagnostics::new(DEFAULT_LOG_CAPACITY));
     tracing_subscriber::registry()
         .with(
             tracing_subscriber::EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| "wrldbldr_engine=debug,tower_http=debug".into()),
         )
         .with(tracing_subscriber::fmt::layer())
+        .with(diagnostics.log_layer())
         .init();
 
     tracing::info!("Starting WrldBldr Engine");
 
     // Load configuration
-    let neo4j_uri = std::env::var("NEO4J_URI").unwrap_or_else(|_| "bolt://localhost:7687".into());
-    let neo4j_user = std::env::var("NEO4J_USER").unwrap_or_else(|_| "neo4j".into());
-    let neo4j_pass = std::env::var("NEO4J_PASSWORD").unwrap_or_else(|_| "password".into());
-    let ollama_url = std::env::var("OLLAMA_URL")
-        .or_else(|_| std::env::var("OLLAMA_BASE_URL"))
-        .unwrap_or_else(|_| "http://localhost:11434".into());
-    let ollama_model = std::env::var("OLLAMA_MODEL").unwrap_or_else(|_| "llama3.2".into());
-    let comfyui_url = std::env::var("COMFYUI_URL")
-        .or_else(|_| std::env::var("COMFYUI_BASE_URL"))
-        .unwrap_or_else(|_| "http://localhost:8188".into());
+    // Service connections saved by the setup wizard win over the environment.
+    let clock: Arc<dyn ClockPort> = Arc::new(SystemClock);
+    let queue_db = std::env::var("QUEUE_DB").unwrap_or_else(|_| "queues.db".into());
+    let settings_repo = Arc::new(SqliteSettingsRepo::new(&queue_db, clock.clone()).await?);
+    let usage_repo = Arc::new(SqliteUsageRepo::new(&queue_db).await?);
+    let env_connections = service_connections_from_env();
+    let global_settings = settings_repo.get_global().await?.unwrap_or_default();
+    let saved_connections = global_settings.services.clone();
+    if !saved_connections.is_empty() {
+        tracing::info!("Using service connections saved by setup");
+    }
+    let connections = saved_connections.or(&env_connections);
+    let neo4j_uri = connections.neo4j_uri().to_string();
     let server_host = std::env::var("SERVER_HOST").unwrap_or_else(|_| "0.0.0.0".into());
     let server_port: u16 = std::env::var("SERVER_PORT")
         .or_else(|_| std::env::var("PORT"))
         .unwrap_or_else(|_| "3000".into())
         .parse()
         .unwrap_or(3000);
-
-    // Create clock for repositories
-    let clock: Arc<dyn infrastructure::ports::ClockPort> = Arc::new(SystemClock);
+    let blob_store = blob_store_from_env(server_port, clock.clone())?;
 
     // Connect to Neo4j
     tracing::info!("Connecting to Neo4j at {}", neo4j_uri);
-    let graph = neo4rs::Graph::new(&neo4j_uri, &neo4j_user, &neo4j_pass).await?;
+    let graph = neo4rs::Graph::new(
+        &neo4j_uri,
+        connections.neo4j_user(),
+        connections.neo4j_password(),
+    )
+    .await?;
+
+    // Ensure database schema (constraints and indexes). On a fresh install
+    // Neo4j may not be reachable yet; keep serving so setup can fix it.
+    if let Err(e) = infrastructure::neo4j::ensure_schema(&graph).await {
+        tracing::error!(
+            error = %e,
+            "Could not prepare the Neo4j schema; check connections in the setup wizard"
+        );
+    }
 
-    // Ensure database schema (constraints and indexes)
-    infrastructure::neo4j::ensure_schema(&graph).await?;
+    // Circuit breakers for the LLM and Neo4j, sharing thresholds from settings
+    let circuit_breaker_config = CircuitBreakerConfig::from_settings(
+        global_settings.circuit_breaker_failure_threshold,
+        global_settings.circuit_breaker_open_duration_secs,
+        global_settings.circuit_breaker_half_open_requests,
+    );
+    let circuit_breakers = ServiceCircuitBreakers::new(circuit_breaker_config.clone());
+    tracing::info!(
+        "Circuit breakers configured: failure_threshold={}, open_duration_secs={}",
+        global_settings.circuit_breaker_failure_threshold,
+        global_settings.circuit_breaker_open_duration_secs
+    );
 
-    let repos = Neo4jRepositories::new(graph, clock.clone());
+    let repos = Neo4jRepositories::new(
+        ResilientGraph::new(graph, circuit_breakers.neo4j.clone()),
+        clock.clone(),
+    );
 
     // Create infrastructure clients
-    let ollama_client = Arc::new(OllamaClient::new(&ollama_url, &ollama_model));
+    let ollama_client = Arc::new(OllamaClient::new(
+        connections.ollama_url(),
+        connections.ollama_model(),
+    ));
     let retry_config = RetryConfig::default();
     tracing::info!(
         "LLM client configured with retry: max_retries={}, base_delay_ms={}",
         retry_config.max_retries,
         retry_config.base_delay_ms
     );
-    let llm = Arc::new(ResilientLlmClient::new(ollama_client, retry_config));
-    let image_gen = Arc::new(ComfyUIClient::new(&comfyui_url));
+    let resilient_llm = Arc::new(ResilientLlmClient::with_shared_circuit_breaker(
+        ollama_client.clone(),
+        retry_config.clone(),
+        circuit_breakers.llm.clone(),
+    ));
+    // Worlds that chose OpenAI or Anthropic go there instead of Ollama
+    let routed_llm = Arc::new(WorldLlmRouter::new(
+        resilient_llm,
+        settings_repo.clone(),
+        retry_config,
+        circuit_breaker_config,
+    ));
+    // Tokens are counted per world for calls that reach the model
+    let metered_llm = Arc::new(MeteredLlmClient::new(
+        routed_llm,
+        usage_repo.clone(),
+        clock.clone(),
+    ));
+    // Cache hits skip the retry/circuit-breaker path (and metering) entirely
+    let llm = Arc::new(CachedLlmClient::new(
+        metered_llm,
+        global_settings.llm_cache_enabled,
+        Duration::from_secs(global_settings.llm_cache_ttl_secs),
+    ));
+    tracing::info!(
+        "LLM response cache: enabled={}, ttl_secs={}",
+        global_settings.llm_cache_enabled,
+        global_settings.llm_cache_ttl_secs
+    );
+    let image_gen = Arc::new(ComfyUIClient::new(connections.comfyui_url()));
+    // Voice input (WHISPER_URL); without it players type as usual
+    let transcription = Arc::new(WhisperClient::from_env());
 
     // Create queue
-    let queue_db = std::env::var("QUEUE_DB").unwrap_or_else(|_| "queues.db".into());
     let queue = Arc::new(SqliteQueue::new(&queue_db, clock.clone()).await?);
-    let settings_repo = Arc::new(SqliteSettingsRepo::new(&queue_db, clock.clone()).await?);
+
+    // Load third-party plugins (PLUGIN_DIR)
+    let plugins = Arc::new(WasmPluginHost::from_env());
 
     // Create application
-    let app = Arc::new(App::new(repos, llm, image_gen, queue, settings_repo));
+    let app = Arc::new(App::new(
+        repos,
+        llm,
+        ollama_client,
+        image_gen,
+        transcription,
+        queue,
+        settings_repo,
+        usage_repo,
+        blob_store,
+        Arc::new(LiveServiceProbe::new()),
+        plugins,
+        connections.clone(),
+        env_connections,
+        circuit_breakers,
+        diagnostics,
+    ));
 
     // Create connection manager
     let connections = Arc::new(ConnectionManager::new());
@@ -109,7 +211,21 @@ async fn main() -> anyhow::Result<()> {
     // Spawn queue processor
     let queue_app = app.clone();
     let queue_connections = ws_state.connections.clone();
+    let queue_ws_state = ws_state.clone();
     tokio::spawn(async move {
+        // Lines a restart dropped mid merge window get answered first
+        match queue_app
+            .use_cases
+            .queues
+            .process_player_action
+            .recover_held()
+            .await
+        {
+            Ok(0) => {}
+            Ok(count) => tracing::info!(count, "Answering lines held before the restart"),
+            Err(e) => tracing::warn!(error = %e, "Failed to recover held player actions"),
+        }
+
         loop {
             // Process player actions
             if let Err(e) = queue_app
@@ -223,6 +339,72 @@ async fn main() -> anyhow::Result<()> {
             {
                 Ok(Some(item)) => {
                     if let infrastructure::ports::QueueItemData::DmApproval(data) = item.data {
+                        // Routine responses from NPCs with enough autonomy go
+                        // straight to players
+                        if let Some(decision) = queue_app
+                            .use_cases
+                            .approval
+                            .autonomy
+                            .auto_decision(&data)
+                            .await
+                        {
+                            tracing::info!(
+                                world_id = %data.world_id,
+                                request_id = %item.id,
+                                npc = %data.npc_name,
+                                "Auto-approved NPC response under NPC autonomy"
+                            );
+                            if let Err(e) = api::websocket::apply_approval_decision(
+                                &queue_ws_state,
+                                item.id,
+                                decision,
+                            )
+                            .await
+                            {
+                                tracing::error!(error = %e, "Failed to apply auto-approval");
+                            }
+                            continue;
+                        }
+
+                        // Worlds in co-DM mode have the LLM decide; only what
+                        // it escalates reaches the DMs
+                        match queue_app
+                            .use_cases
+                            .approval
+                            .co_dm
+                            .review(item.id, &data)
+                            .await
+                        {
+                            Ok(Some(use_cases::approval::CoDmResolution {
+                                review,
+                                decision: Some(decision),
+                            })) => {
+                                tracing::info!(
+                                    world_id = %data.world_id,
+                                    request_id = %item.id,
+                                    outcome = ?review.outcome,
+                                    "Co-DM resolved approval"
+                                );
+                                if let Err(e) = api::websocket::apply_approval_decision(
+                                    &queue_ws_state,
+                                    item.id,
+                                    decision,
+                                )
+                                .await
+                                {
+                                    tracing::error!(error = %e, "Failed to apply co-DM decision");
+                                }
+                                continue;
+                            }
+                            Ok(_) => {}
+                            Err(e) => {
+                                tracing::warn!(
+                                    error = %e,
+                                    "Co-DM review failed, sending approval to DMs"
+                                );
+                            }
+                        }
+
                         // Convert domain types to protocol types
                         let proposed_tools: Vec<wrldbldr_protocol::ProposedToolInfo> = data
                             .proposed_tools
@@ -235,6 +417,29 @@ async fn main() -> anyhow::Result<()> {
                             })
                             .collect();
 
+                        // Each active PC's odds at the suggested difficulty
+                        let party_odds = match data.challenge_suggestion.as_ref() {
+                            Some(cs) => queue_app
+                                .use_cases
+                                .challenge
+                                .odds
+                                .for_suggestion(data.world_id, cs)
+                                .await
+                                .unwrap_or_else(|e| {
+                                    tracing::warn!(error = %e, "Failed to compute party odds");
+                                    vec![]
+                                })
+                                .into_iter()
+                                .map(|o| wrldbldr_protocol::PcSuccessChanceInfo {
+                                    pc_id: o.pc_id.to_string(),
+                                    pc_name: o.pc_name,
+                                    modifier: o.modifier,
+                                    success_percent: o.success_percent,
+                                })
+                                .collect(),
+                            None => vec![],
+                        };
+
                         let challenge_suggestion = data.challenge_suggestion.map(|cs| {
                             wrldbldr_protocol::ChallengeSuggestionInfo {
                                 challenge_id: cs.challenge_id,
@@ -252,6 +457,7 @@ async fn main() -> anyhow::Result<()> {
                                         critical_failure: o.critical_failure,
                                     }
                                 }),
+                                party_odds,
                             }
                         });
 
@@ -277,6 +483,14 @@ async fn main() -> anyhow::Result<()> {
                             proposed_tools,
                             challenge_suggestion,
                             narrative_event_suggestion,
+                            safety_warnings: data.safety_warnings,
+                            conversation_id: data.conversation_id.map(|id| id.to_string()),
+                            source_action_id: Some(data.source_action_id.to_string()),
+                            awaiting_player: data.urgency != wrldbldr_domain::ApprovalUrgency::Normal,
+                            scene_id: data
+                                .pc_id
+                                .and_then(|pc_id| queue_connections.scene_of(data.world_id, pc_id))
+                                .map(|id| id.to_string()),
                         };
 
                         queue_connections.broadcast_to_dms(data.world_id, msg).await;
@@ -297,6 +511,81 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
+    // Spawn dependency health monitor - tells DMs when a dependency goes
+    // down or recovers
+    let health_app = app.clone();
+    let health_connections = ws_state.connections.clone();
+    tokio::spawn(async move {
+        loop {
+            for status in health_app.use_cases.health.check.changes().await {
+                if status.healthy {
+                    tracing::info!(dependency = status.dependency.as_str(), "Dependency healthy");
+                } else {
+                    tracing::warn!(
+                        dependency = status.dependency.as_str(),
+                        message = ?status.message,
+                        "Dependency unhealthy"
+                    );
+                }
+                health_connections
+                    .broadcast_to_all_dms(use_cases::health::status_to_message(&status))
+                    .await;
+            }
+
+            tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+        }
+    });
+
+    // Spawn usage quota monitor - warns DMs once a day when their world goes
+    // over a daily quota (quotas never block generation)
+    let usage_app = app.clone();
+    let usage_connections = ws_state.connections.clone();
+    tokio::spawn(async move {
+        loop {
+            match usage_app.use_cases.usage.quotas.execute().await {
+                Ok(warnings) => {
+                    for warning in warnings {
+                        tracing::warn!(
+                            world_id = %warning.world_id,
+                            resource = warning.resource.as_str(),
+                            used = warning.used,
+                            quota = warning.quota,
+                            "World over daily usage quota"
+                        );
+                        usage_connections
+                            .broadcast_to_dms(
+                                warning.world_id,
+                                use_cases::usage::quota_warning_message(&warning),
+                            )
+                            .await;
+                    }
+                }
+                Err(e) => tracing::warn!(error = %e, "Usage quota check failed"),
+            }
+
+            tokio::time::sleep(USAGE_QUOTA_CHECK_INTERVAL).await;
+        }
+    });
+
+    // Spawn pacing timer ticker - tells each world when its scene or turn
+    // timer crosses a minute, its warning or its end
+    let pacing_app = app.clone();
+    let pacing_connections = ws_state.connections.clone();
+    tokio::spawn(async move {
+        loop {
+            for (world_id, timer) in pacing_app.use_cases.pacing.timers.tick() {
+                pacing_connections
+                    .broadcast_to_world(
+                        world_id,
+                        wrldbldr_protocol::ServerMessage::PacingTimerChanged { timer: Some(timer) },
+                    )
+                    .await;
+            }
+
+            tokio::time::sleep(PACING_TICK_INTERVAL).await;
+        }
+    });
+
     // Spawn staging timeout processor
     let staging_ws_state = ws_state.clone();
     tokio::spawn(async move {
@@ -364,9 +653,17 @@ async fn main() -> anyhow::Result<()> {
                     continue;
                 }
 
+                // Scenes with a PC waiting on this region
+                let waiting = use_cases::staging::pcs_awaiting_region(
+                    &staging_ws_state.pending_staging_requests,
+                    pending.region_id,
+                    Some(pending.pc_id),
+                )
+                .await;
+
                 // Check if auto-approve is enabled for this world
                 if !settings.auto_approve_on_timeout {
-                    // Notify all players in the world that staging for this region timed out
+                    // Notify the scenes waiting on this region that staging timed out
                     // Players waiting for this region will see it and can retry
                     let region_id_str = pending.region_id.to_string();
                     tracing::info!(
@@ -377,8 +674,9 @@ async fn main() -> anyhow::Result<()> {
                     );
                     staging_ws_state
                         .connections
-                        .broadcast_to_world(
+                        .broadcast_to_scenes_of(
                             world_id,
+                            &waiting,
                             wrldbldr_protocol::ServerMessage::StagingTimedOut {
                                 region_id: region_id_str.clone(),
                                 region_name: region_id_str, // Use ID as name (player has region info)
@@ -398,11 +696,12 @@ async fn main() -> anyhow::Result<()> {
                     .await
                 {
                     Ok(payload) => {
-                        // Broadcast StagingReady to all players in world
+                        // Broadcast StagingReady to the scenes waiting on the region
                         staging_ws_state
                             .connections
-                            .broadcast_to_world(
+                            .broadcast_to_scenes_of(
                                 world_id,
+                                &waiting,
                                 wrldbldr_protocol::ServerMessage::StagingReady {
                                     region_id: payload.region_id.to_string(),
                                     npcs_present: payload.npcs_present,
@@ -466,6 +765,75 @@ fn load_dotenv_from_repo_root() {
     }
 }
 
+/// Service connections set in the environment (.env); unset ones stay `None`.
+fn service_connections_from_env() -> wrldbldr_domain::ServiceConnections {
+    wrldbldr_domain::ServiceConnections {
+        neo4j_uri: std::env::var("NEO4J_URI").ok(),
+        neo4j_user: std::env::var("NEO4J_USER").ok(),
+        neo4j_password: std::env::var("NEO4J_PASSWORD").ok(),
+        ollama_url: std::env::var("OLLAMA_URL")
+            .or_else(|_| std::env::var("OLLAMA_BASE_URL"))
+            .ok(),
+        ollama_model: std::env::var("OLLAMA_MODEL").ok(),
+        comfyui_url: std::env::var("COMFYUI_URL")
+            .or_else(|_| std::env::var("COMFYUI_BASE_URL"))
+            .ok(),
+    }
+}
+
+/// Asset blob storage from `ASSET_STORE` (`local`, the default, or `s3`).
+fn blob_store_from_env(
+    server_port: u16,
+    clock: Arc<dyn ClockPort>,
+) -> anyhow::Result<Arc<dyn BlobStorePort>> {
+    let env = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+    let required = |name: &str| {
+        env(name).ok_or_else(|| anyhow::anyhow!("{} must be set when ASSET_STORE=s3", name))
+    };
+
+    match env("ASSET_STORE").as_deref().unwrap_or("local") {
+        "local" => {
+            let root = env("GENERATED_ASSETS_PATH")
+                .unwrap_or_else(|| use_cases::health::ASSETS_DIR.to_string());
+            // Base URL signed asset URLs point at; must be reachable by remote Players
+            let public_url = env("PUBLIC_URL")
+                .unwrap_or_else(|| format!("http://localhost:{}", server_port));
+            let secret = match env("ASSET_URL_SECRET") {
+                Some(secret) => secret.into_bytes(),
+                None => {
+                    tracing::warn!(
+                        "ASSET_URL_SECRET not set; signed asset URLs will stop working on restart"
+                    );
+                    rand::random::<[u8; 32]>().to_vec()
+                }
+            };
+            tracing::info!("Storing assets on local disk at {} ({})", root, public_url);
+            Ok(Arc::new(LocalBlobStore::new(
+                root,
+                &public_url,
+                secret,
+                clock,
+            )))
+        }
+        "s3" => {
+            let config = S3Config {
+                endpoint: required("S3_ENDPOINT")?,
+                bucket: required("S3_BUCKET")?,
+                region: env("S3_REGION").unwrap_or_else(|| "us-east-1".to_string()),
+                access_key_id: required("S3_ACCESS_KEY_ID")?,
+                secret_access_key: required("S3_SECRET_ACCESS_KEY")?,
+            };
+            tracing::info!(
+                "Storing assets in S3 bucket {} at {}",
+                config.bucket,
+                config.endpoint
+            );
+            Ok(Arc::new(S3BlobStore::new(config, clock)))
+        }
+        other => anyhow::bail!("Unknown ASSET_STORE '{}' (expected local or s3)", other),
+    }
+}
+
 fn build_cors_layer_from_env() -> Option<CorsLayer> {
     let allowed_origins = std::env::var("CORS_ALLOWED_ORIGINS")
         .ok()
@@ -0,0 +1,971 @@
+//! Deterministic simulation harness for play sequences.
+//!
+//! Builds a full `App` through `App::from_ports` with a manual clock, a
+//! seeded random source, a scripted LLM and in-memory repositories, so
+//! whole sequences (join, move, talk, approve, resolve) run through the
+//! real use cases without Neo4j or an LLM server. The same seed and script
+//! always play out the same way.
+
+mod prompts;
+mod scripted_llm;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeZone, Utc};
+use wrldbldr_domain::*;
+
+use crate::app::{App, AppPorts};
+use crate::infrastructure::circuit_breaker::ServiceCircuitBreakers;
+use crate::infrastructure::clock::{ManualClock, SeededRandom};
+use crate::infrastructure::diagnostics::Diagnostics;
+use crate::infrastructure::memory::{
+    MemoryBlobStore, MemoryQueue, MemorySettings, MemoryStore, OfflineServices,
+};
+use crate::infrastructure::ports::{
+    ChallengeRepo, CharacterRepo, ClockPort, LocationRepo, PlayerCharacterRepo, WorldRepo,
+};
+use crate::infrastructure::rhai_scripts::RhaiScriptEngine;
+use crate::infrastructure::wasm_plugins::{PluginLimits, WasmPluginHost};
+
+pub(crate) use scripted_llm::ScriptedLlm;
+
+/// An `App` over in-memory ports, plus handles to steer and inspect them.
+pub(crate) struct Simulation {
+    pub(crate) app: Arc<App>,
+    pub(crate) store: Arc<MemoryStore>,
+    pub(crate) llm: Arc<ScriptedLlm>,
+    pub(crate) clock: Arc<ManualClock>,
+}
+
+impl Simulation {
+    /// When every simulation starts
+    fn epoch() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap()
+    }
+
+    pub(crate) fn new(seed: u64) -> Self {
+        let clock = Arc::new(ManualClock::new(Self::epoch()));
+        let store = Arc::new(MemoryStore::new(clock.clone()));
+        let settings = Arc::new(MemorySettings::default());
+        let llm = Arc::new(ScriptedLlm::default());
+
+        let app = Arc::new(App::from_ports(AppPorts {
+            repos: store.repositories(),
+            settings_repo: settings.clone(),
+            usage_repo: settings,
+            queue: Arc::new(MemoryQueue::new(clock.clone())),
+            llm: llm.clone(),
+            llm_models: Arc::new(OfflineServices),
+            image_gen: Arc::new(OfflineServices),
+            transcription: Arc::new(OfflineServices),
+            blob_store: Arc::new(MemoryBlobStore::default()),
+            service_probe: Arc::new(OfflineServices),
+            plugins: Arc::new(WasmPluginHost::new(PluginLimits::default())),
+            scripts: Arc::new(RhaiScriptEngine::new()),
+            clock: clock.clone(),
+            random: Arc::new(SeededRandom::new(seed)),
+            running_connections: ServiceConnections::default(),
+            env_connections: ServiceConnections::default(),
+            circuit_breakers: ServiceCircuitBreakers::default(),
+            diagnostics: Arc::new(Diagnostics::default()),
+        }));
+
+        Self {
+            app,
+            store,
+            llm,
+            clock,
+        }
+    }
+
+    pub(crate) async fn world(&self, name: &str) -> World {
+        let world = World::new(name, "", self.clock.now());
+        WorldRepo::save(self.store.as_ref(), &world).await.unwrap();
+        world
+    }
+
+    pub(crate) async fn location(&self, world_id: WorldId, name: &str) -> Location {
+        let location = Location::new(world_id, name, LocationType::Exterior);
+        self.store.save_location(&location).await.unwrap();
+        location
+    }
+
+    pub(crate) async fn region(&self, location_id: LocationId, name: &str) -> Region {
+        let region = Region::new(location_id, name);
+        self.store.save_region(&region).await.unwrap();
+        region
+    }
+
+    /// Open a two-way path between regions
+    pub(crate) async fn connect(&self, from: RegionId, to: RegionId) {
+        let connection = RegionConnection::new(from, to).expect("distinct regions");
+        self.store.save_connection(&connection).await.unwrap();
+    }
+
+    pub(crate) async fn npc(&self, world_id: WorldId, name: &str) -> Character {
+        let npc = Character::new(world_id, name, CampbellArchetype::Mentor);
+        CharacterRepo::save(self.store.as_ref(), &npc)
+            .await
+            .unwrap();
+        npc
+    }
+
+    pub(crate) async fn pc(
+        &self,
+        world_id: WorldId,
+        name: &str,
+        region: &Region,
+    ) -> PlayerCharacter {
+        let pc = PlayerCharacter::new(
+            "player-1",
+            world_id,
+            name,
+            region.location_id,
+            self.clock.now(),
+        )
+        .with_starting_region(region.id);
+        PlayerCharacterRepo::save(self.store.as_ref(), &pc)
+            .await
+            .unwrap();
+        pc
+    }
+
+    pub(crate) async fn challenge(&self, challenge: Challenge) -> Challenge {
+        ChallengeRepo::save(self.store.as_ref(), &challenge)
+            .await
+            .unwrap();
+        challenge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::use_cases::challenge::OutcomeDecisionResult;
+    use crate::use_cases::player_action::RetractError;
+
+    struct Harbor {
+        world: World,
+        harbor: Location,
+        docks: Region,
+        tavern: Region,
+        mira: Character,
+        pc: PlayerCharacter,
+    }
+
+    async fn harbor(sim: &Simulation) -> Harbor {
+        let world = sim.world("Saltmere").await;
+        let harbor = sim.location(world.id, "Harbor").await;
+        let docks = sim.region(harbor.id, "Docks").await;
+        let tavern = sim.region(harbor.id, "Tavern").await;
+        sim.connect(docks.id, tavern.id).await;
+        let mira = sim.npc(world.id, "Mira").await;
+        let pc = sim.pc(world.id, "Ash", &docks).await;
+        Harbor {
+            world,
+            harbor,
+            docks,
+            tavern,
+            mira,
+            pc,
+        }
+    }
+
+    async fn roll_many(seed: u64, rolls: usize) -> Vec<i32> {
+        let sim = Simulation::new(seed);
+        let h = harbor(&sim).await;
+        let challenge = sim
+            .challenge(Challenge::new(h.world.id, "Haggle", Difficulty::DC(12)))
+            .await;
+
+        let mut results = Vec::new();
+        for _ in 0..rolls {
+            let result = sim
+                .app
+                .use_cases
+                .challenge
+                .roll
+                .execute(h.world.id, challenge.id, h.pc.id, None, 0)
+                .await
+                .unwrap();
+            results.push(result.roll);
+        }
+        results
+    }
+
+    #[tokio::test]
+    async fn join_move_talk_approve_resolve() {
+        let sim = Simulation::new(42);
+        let h = harbor(&sim).await;
+        let challenge = sim
+            .challenge(
+                Challenge::new(h.world.id, "Read Mira", Difficulty::DC(10)).with_outcomes(
+                    ChallengeOutcomes {
+                        success: Outcome::new("Mira's eyes flick to the harbormaster")
+                            .with_trigger(OutcomeTrigger::RevealInformation {
+                                info: "Mira owes the harbormaster".to_string(),
+                                persist: true,
+                            }),
+                        ..ChallengeOutcomes::simple("", "Mira gives nothing away")
+                    },
+                ),
+            )
+            .await;
+        sim.llm.push("Work? Talk to the harbormaster, if you dare.");
+        let use_cases = &sim.app.use_cases;
+
+        // Join
+        let joined = use_cases
+            .session
+            .join_world
+            .execute(h.world.id, Some(h.pc.id), true)
+            .await
+            .unwrap();
+        assert_eq!(joined.world_id, h.world.id);
+        assert!(joined.your_pc.is_some());
+
+        // Move: the DM stages Mira, then the PC walks over
+        use_cases
+            .approval
+            .approve_staging
+            .execute(h.tavern.id, vec![h.mira.id])
+            .await
+            .unwrap();
+        let entered = use_cases
+            .movement
+            .enter_region
+            .execute(h.pc.id, h.tavern.id)
+            .await
+            .unwrap();
+        assert!(entered.npcs.iter().any(|npc| npc.character_id == h.mira.id));
+        let pc = sim
+            .app
+            .entities
+            .player_character
+            .get(h.pc.id)
+            .await
+            .unwrap();
+        assert_eq!(pc.unwrap().current_region_id, Some(h.tavern.id));
+
+        // Talk
+        let started = use_cases
+            .conversation
+            .start
+            .execute(
+                h.world.id,
+                h.pc.id,
+                h.mira.id,
+                "player-1".to_string(),
+                "Any work going?".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(started.npc_name, "Mira");
+        use_cases
+            .queues
+            .process_player_action
+            .execute()
+            .await
+            .unwrap()
+            .expect("queued player action");
+        let processed = use_cases
+            .queues
+            .process_llm_request
+            .execute(|_| {})
+            .await
+            .unwrap()
+            .expect("queued LLM request");
+        let requests = sim.llm.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].messages[0].content.contains("Any work going?"));
+
+        // Approve
+        let approved = use_cases
+            .approval
+            .decision_flow
+            .execute(processed.approval_id, DmApprovalDecision::Accept)
+            .await
+            .unwrap();
+        assert!(approved.approved);
+        assert_eq!(
+            approved.final_dialogue.as_deref(),
+            Some("Work? Talk to the harbormaster, if you dare.")
+        );
+
+        // Resolve: the modifier makes success certain whatever the roll
+        let rolled = use_cases
+            .challenge
+            .roll
+            .execute(h.world.id, challenge.id, h.pc.id, None, 20)
+            .await
+            .unwrap();
+        assert!((1..=20).contains(&rolled.roll));
+        let decided = use_cases
+            .challenge
+            .outcome_decision
+            .execute(
+                h.world.id,
+                rolled.approval_queue_id.unwrap().to_string(),
+                wrldbldr_protocol::ChallengeOutcomeDecisionData::Accept,
+            )
+            .await
+            .unwrap();
+        let OutcomeDecisionResult::Resolved(payload) = decided else {
+            panic!("accepting an outcome should resolve it");
+        };
+        assert_eq!(payload.outcome, "success");
+        assert_eq!(
+            sim.store.deduced_info(h.pc.id),
+            vec!["Mira owes the harbormaster".to_string()]
+        );
+        let challenge = sim.app.entities.challenge.get(challenge.id).await.unwrap();
+        assert!(!challenge.unwrap().active);
+
+        // The resolution is kept in the PC's challenge history
+        let (history, stats) = use_cases
+            .challenge
+            .history
+            .list(h.world.id, Some(h.pc.id), None)
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].roll, rolled.roll);
+        assert_eq!(history[0].modifier, 20);
+        assert_eq!(history[0].triggers.len(), 1);
+        assert_eq!((stats.resolved, stats.succeeded), (1, 1));
+
+        // And in the campaign analytics
+        let analytics = use_cases
+            .analytics
+            .campaign
+            .execute(h.world.id)
+            .await
+            .unwrap();
+        assert_eq!(analytics.pcs[0].challenges, 1);
+    }
+
+    #[tokio::test]
+    async fn analytics_put_the_quietest_first() {
+        let sim = Simulation::new(3);
+        let h = harbor(&sim).await;
+        let lighthouse = sim.location(h.world.id, "Lighthouse").await;
+        let bryn = sim.pc(h.world.id, "Bryn", &h.docks).await;
+        let observation = &sim.app.entities.observation;
+        for _ in 0..2 {
+            observation
+                .record_location_visit(h.pc.id, &h.harbor, sim.clock.now())
+                .await
+                .unwrap();
+        }
+        sim.app
+            .entities
+            .narrative
+            .record_dialogue_exchange(
+                h.world.id,
+                bryn.id,
+                h.mira.id,
+                "Mira".to_string(),
+                "Seen any ships?".to_string(),
+                "Only the one that never docks.".to_string(),
+                vec![],
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let analytics = sim
+            .app
+            .use_cases
+            .analytics
+            .campaign
+            .execute(h.world.id)
+            .await
+            .unwrap();
+        let spotlight: Vec<(PlayerCharacterId, u32, u32)> = analytics
+            .pcs
+            .iter()
+            .map(|pc| (pc.pc_id, pc.dialogue_exchanges, pc.story_events))
+            .collect();
+        assert_eq!(spotlight, vec![(h.pc.id, 0, 0), (bryn.id, 1, 1)]);
+        assert_eq!(
+            (analytics.npcs[0].dialogue_exchanges, analytics.npcs[0].pcs),
+            (1, 1)
+        );
+        let heat: Vec<(LocationId, u32, u32)> = analytics
+            .locations
+            .iter()
+            .map(|l| (l.location_id, l.visits, l.visitors))
+            .collect();
+        assert_eq!(heat, vec![(lighthouse.id, 0, 0), (h.harbor.id, 2, 1)]);
+    }
+
+    #[tokio::test]
+    async fn unused_content_suggests_where_to_bring_it_back() {
+        let sim = Simulation::new(3);
+        let h = harbor(&sim).await;
+        let entities = &sim.app.entities;
+        entities
+            .observation
+            .record_location_visit(h.pc.id, &h.harbor, sim.clock.now())
+            .await
+            .unwrap();
+        let jory = sim.npc(h.world.id, "Jory").await;
+        entities
+            .character
+            .set_work_region(jory.id, h.tavern.id, None)
+            .await
+            .unwrap();
+        let mast = sim
+            .challenge(Challenge::new(
+                h.world.id,
+                "Climb the mast",
+                Difficulty::DC(12),
+            ))
+            .await;
+
+        let lore = Lore::new(
+            h.world.id,
+            "The ship that never docks",
+            LoreCategory::Legend,
+            sim.clock.now(),
+        );
+        entities.lore.save(&lore).await.unwrap();
+        entities
+            .lore
+            .grant_knowledge(&LoreKnowledge::full(
+                lore.id,
+                jory.id,
+                LoreDiscoverySource::Investigation,
+                sim.clock.now(),
+            ))
+            .await
+            .unwrap();
+
+        // The lighthouse was deleted, so the first event can never fire and
+        // the one waiting on it can't either
+        let mut beacon = NarrativeEvent::new(h.world.id, "Beacon lit", sim.clock.now());
+        beacon.trigger_conditions.push(NarrativeTrigger {
+            trigger_type: NarrativeTriggerType::PlayerEntersLocation {
+                location_id: LocationId::new(),
+                location_name: "Lighthouse".to_string(),
+            },
+            description: "Climb the lighthouse".to_string(),
+            is_required: false,
+            trigger_id: "lighthouse".to_string(),
+        });
+        let mut rescue = NarrativeEvent::new(h.world.id, "Rescue", sim.clock.now());
+        rescue.trigger_conditions.push(NarrativeTrigger {
+            trigger_type: NarrativeTriggerType::EventCompleted {
+                event_id: beacon.id,
+                event_name: beacon.name.clone(),
+                outcome_name: None,
+            },
+            description: "After the beacon".to_string(),
+            is_required: false,
+            trigger_id: "beacon".to_string(),
+        });
+        let storm = NarrativeEvent::new(h.world.id, "Storm", sim.clock.now());
+        for event in [&beacon, &rescue, &storm] {
+            entities.narrative.save_event(event).await.unwrap();
+        }
+
+        let report = sim
+            .app
+            .use_cases
+            .analytics
+            .unused
+            .execute(h.world.id)
+            .await
+            .unwrap();
+        assert_eq!(report.challenges.len(), 1);
+        assert_eq!(report.challenges[0].challenge_id, mast.id);
+        assert_eq!(
+            report.challenges[0].suggestion,
+            "Stage it at Harbor, where the party spends the most time"
+        );
+        let npcs: Vec<(&str, &str)> = report
+            .npcs
+            .iter()
+            .map(|n| (n.name.as_str(), n.suggestion.as_str()))
+            .collect();
+        assert_eq!(
+            npcs,
+            vec![
+                ("Jory", "Stage them in Tavern, where they work"),
+                ("Mira", "Give them a reason to be at Harbor"),
+            ]
+        );
+        assert_eq!(report.lore.len(), 1);
+        assert_eq!(report.lore[0].suggestion, "Let Jory share it");
+        let stuck: Vec<(&str, &[String])> = report
+            .events
+            .iter()
+            .map(|e| (e.name.as_str(), e.dead_triggers.as_slice()))
+            .collect();
+        assert_eq!(
+            stuck,
+            vec![
+                ("Beacon lit", &["Climb the lighthouse".to_string()][..]),
+                ("Rescue", &["After the beacon".to_string()][..]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn prep_checklist_covers_where_the_party_may_go() {
+        let sim = Simulation::new(3);
+        let h = harbor(&sim).await;
+        let entities = &sim.app.entities;
+        entities
+            .character
+            .set_work_region(h.mira.id, h.tavern.id, None)
+            .await
+            .unwrap();
+        let lighthouse = sim.location(h.world.id, "Lighthouse").await;
+        let lamp_room = sim.region(lighthouse.id, "Lamp room").await;
+        let _far_shore = sim.location(h.world.id, "Far shore").await;
+        let mut quest =
+            ProgressClock::new(h.world.id, "Relight the lighthouse", 4, sim.clock.now()).unwrap();
+        quest.kind = ClockKind::Quest;
+        entities.progress_clock.save(&quest).await.unwrap();
+        let haggle = sim
+            .challenge(Challenge::new(h.world.id, "Haggle", Difficulty::DC(12)))
+            .await;
+        sim.challenge(
+            Challenge::new(h.world.id, "Read Mira", Difficulty::DC(10))
+                .with_outcomes(ChallengeOutcomes::simple("She softens", "She clams up")),
+        )
+        .await;
+
+        let checklist = sim
+            .app
+            .use_cases
+            .prep
+            .checklist
+            .execute(h.world.id)
+            .await
+            .unwrap();
+        let regions: Vec<(RegionId, &str)> = checklist
+            .unstaged_regions
+            .iter()
+            .map(|r| (r.region_id, r.reason.as_str()))
+            .collect();
+        assert_eq!(
+            regions,
+            vec![
+                (h.docks.id, "A PC is here"),
+                (h.tavern.id, "Next to Docks"),
+                (lamp_room.id, "Quest: Relight the lighthouse"),
+            ]
+        );
+        assert_eq!(checklist.npcs_without_portraits.len(), 1);
+        assert_eq!(checklist.npcs_without_portraits[0].npc_id, h.mira.id);
+        assert_eq!(checklist.npcs_without_portraits[0].region_name, "Tavern");
+        assert_eq!(checklist.challenges_without_outcomes.len(), 1);
+        assert_eq!(
+            checklist.challenges_without_outcomes[0].challenge_id,
+            haggle.id
+        );
+        assert_eq!(
+            checklist.challenges_without_outcomes[0].blank,
+            vec!["success", "failure"]
+        );
+    }
+
+    #[tokio::test]
+    async fn hired_companions_follow_their_pc_into_staged_regions() {
+        let sim = Simulation::new(5);
+        let h = harbor(&sim).await;
+        let use_cases = &sim.app.use_cases;
+
+        use_cases
+            .companion
+            .manage
+            .hire(h.world.id, h.pc.id, h.mira.id, Default::default())
+            .await
+            .unwrap();
+        let at_docks = sim.app.entities.character.list_in_region(h.docks.id).await;
+        assert!(at_docks.unwrap().iter().any(|c| c.id == h.mira.id));
+
+        // The DM stages only the barkeep; Mira comes in with Ash anyway
+        let barkeep = sim.npc(h.world.id, "Tam").await;
+        use_cases
+            .approval
+            .approve_staging
+            .execute(h.tavern.id, vec![barkeep.id])
+            .await
+            .unwrap();
+        let entered = use_cases
+            .movement
+            .enter_region
+            .execute(h.pc.id, h.tavern.id)
+            .await
+            .unwrap();
+        assert!(entered.npcs.iter().any(|npc| npc.character_id == h.mira.id));
+        let in_tavern = sim.app.entities.character.list_in_region(h.tavern.id).await;
+        assert!(in_tavern.unwrap().iter().any(|c| c.id == h.mira.id));
+    }
+
+    #[tokio::test]
+    async fn same_seed_replays_the_same_rolls() {
+        let first = roll_many(7, 8).await;
+        assert_eq!(first, roll_many(7, 8).await);
+        assert!(first.iter().all(|roll| (1..=20).contains(roll)));
+    }
+
+    #[tokio::test]
+    async fn queued_work_is_stamped_with_the_simulation_clock() {
+        let sim = Simulation::new(3);
+        let h = harbor(&sim).await;
+        let challenge = sim
+            .challenge(Challenge::new(h.world.id, "Haggle", Difficulty::DC(12)))
+            .await;
+        sim.clock.advance(chrono::Duration::hours(2));
+
+        sim.app
+            .use_cases
+            .challenge
+            .roll
+            .execute(h.world.id, challenge.id, h.pc.id, None, 0)
+            .await
+            .unwrap();
+
+        let approvals = sim.app.queue.list_by_type("dm_approval", 10).await.unwrap();
+        assert_eq!(approvals.len(), 1);
+        assert_eq!(
+            approvals[0].created_at,
+            Simulation::epoch() + chrono::Duration::hours(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn exhausted_script_fails_the_llm_request() {
+        let sim = Simulation::new(1);
+        let h = harbor(&sim).await;
+        let use_cases = &sim.app.use_cases;
+        use_cases
+            .approval
+            .approve_staging
+            .execute(h.docks.id, vec![h.mira.id])
+            .await
+            .unwrap();
+        use_cases
+            .conversation
+            .start
+            .execute(
+                h.world.id,
+                h.pc.id,
+                h.mira.id,
+                "player-1".to_string(),
+                "Hello?".to_string(),
+            )
+            .await
+            .unwrap();
+        use_cases
+            .queues
+            .process_player_action
+            .execute()
+            .await
+            .unwrap();
+
+        assert!(use_cases
+            .queues
+            .process_llm_request
+            .execute(|_| {})
+            .await
+            .is_err());
+        assert_eq!(sim.llm.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn lines_said_over_each_other_get_one_reply() {
+        let sim = Simulation::new(12);
+        let h = harbor(&sim).await;
+        let bryn = sim.pc(h.world.id, "Bryn", &h.docks).await;
+        let use_cases = &sim.app.use_cases;
+        let queues = &use_cases.queues;
+        sim.llm.push("One at a time! She sails at dawn, and the guild pays.");
+        use_cases
+            .approval
+            .approve_staging
+            .execute(h.docks.id, vec![h.mira.id])
+            .await
+            .unwrap();
+
+        let mut conversations = Vec::new();
+        for (pc, line) in [(&h.pc, "Where's the ship?"), (&bryn, "Who's paying?")] {
+            let started = use_cases
+                .conversation
+                .start
+                .execute(
+                    h.world.id,
+                    pc.id,
+                    h.mira.id,
+                    "player-1".to_string(),
+                    line.to_string(),
+                )
+                .await
+                .unwrap();
+            conversations.push(Some(started.conversation_id));
+            sim.clock.advance(chrono::Duration::milliseconds(300));
+        }
+
+        // Both lines wait out the merge window
+        for _ in 0..2 {
+            assert!(queues.process_player_action.execute().await.unwrap().is_none());
+        }
+        sim.clock.advance(chrono::Duration::seconds(2));
+        let processed = queues
+            .process_player_action
+            .execute()
+            .await
+            .unwrap()
+            .expect("merged player actions");
+        assert_eq!(processed.merged_action_ids.len(), 1);
+
+        // Neither line can be taken back without the other
+        for action_id in [processed.action_id, processed.merged_action_ids[0]] {
+            let retracted = use_cases
+                .player_action
+                .retract
+                .execute(h.world.id, action_id, "player-1", false)
+                .await;
+            assert!(matches!(retracted, Err(RetractError::Merged)));
+        }
+
+        let answered = queues
+            .process_llm_request
+            .execute(|_| {})
+            .await
+            .unwrap()
+            .expect("queued LLM request");
+
+        let requests = sim.llm.requests();
+        assert_eq!(requests.len(), 1);
+        let message = &requests[0].messages[0].content;
+        assert!(message.starts_with("Ash and Bryn speak to Mira at once"));
+        assert!(message.ends_with("Ash: \"Where's the ship?\"\nBryn: \"Who's paying?\""));
+
+        // The DM's approval still knows whose lines, and conversations, it answers
+        let approval = sim
+            .app
+            .queue
+            .get_approval_request(answered.approval_id)
+            .await
+            .unwrap()
+            .expect("approval request");
+        let lines: Vec<_> = approval
+            .merged_lines
+            .iter()
+            .map(|line| (line.action_id, line.conversation_id))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (processed.action_id, conversations[0]),
+                (processed.merged_action_ids[0], conversations[1]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn merged_reply_is_recorded_for_each_speaker() {
+        let sim = Simulation::new(14);
+        let h = harbor(&sim).await;
+        let bryn = sim.pc(h.world.id, "Bryn", &h.docks).await;
+        let use_cases = &sim.app.use_cases;
+        let queues = &use_cases.queues;
+        sim.llm.push("The Gull, at dawn. The guild pays.");
+
+        let lines = [(&h.pc, "Which ship?"), (&bryn, "And the pay?")];
+        for (pc, line) in lines {
+            use_cases
+                .player_action
+                .handle
+                .execute(
+                    h.world.id,
+                    pc.id,
+                    "player-1".to_string(),
+                    "ask".to_string(),
+                    Some(h.mira.id),
+                    Some(line.to_string()),
+                )
+                .await
+                .unwrap();
+            assert!(queues.process_player_action.execute().await.unwrap().is_none());
+        }
+        sim.clock.advance(chrono::Duration::seconds(2));
+        queues
+            .process_player_action
+            .execute()
+            .await
+            .unwrap()
+            .expect("merged player actions");
+        let answered = queues
+            .process_llm_request
+            .execute(|_| {})
+            .await
+            .unwrap()
+            .expect("queued LLM request");
+
+        let approved = use_cases
+            .approval
+            .decision_flow
+            .execute(answered.approval_id, DmApprovalDecision::Accept)
+            .await
+            .unwrap();
+        assert_eq!(approved.dialogue_event_ids.len(), 2);
+        for (pc, line) in lines {
+            let history = sim
+                .app
+                .entities
+                .narrative
+                .get_dialogues_with_npc(pc.id, h.mira.id, 5)
+                .await
+                .unwrap();
+            assert!(matches!(
+                &history[0].event_type,
+                StoryEventType::DialogueExchange { player_dialogue, npc_response, .. }
+                    if player_dialogue == line && npc_response == "The Gull, at dawn. The guild pays."
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn held_lines_can_be_taken_back_or_stopped_by_a_safety_signal() {
+        let sim = Simulation::new(13);
+        let h = harbor(&sim).await;
+        let bryn = sim.pc(h.world.id, "Bryn", &h.docks).await;
+        let use_cases = &sim.app.use_cases;
+        let queues = &use_cases.queues;
+        let signals = &use_cases.safety.signals;
+        use_cases
+            .approval
+            .approve_staging
+            .execute(h.docks.id, vec![h.mira.id])
+            .await
+            .unwrap();
+        let say = |pc_id, line: &str| {
+            use_cases.conversation.start.execute(
+                h.world.id,
+                pc_id,
+                h.mira.id,
+                "player-1".to_string(),
+                line.to_string(),
+            )
+        };
+
+        // A typo is taken back while it waits for the others
+        let typo = say(h.pc.id, "Wehre's the ship?").await.unwrap();
+        assert!(queues.process_player_action.execute().await.unwrap().is_none());
+        use_cases
+            .player_action
+            .retract
+            .execute(h.world.id, typo.action_queue_id, "player-1", false)
+            .await
+            .unwrap();
+        sim.clock.advance(chrono::Duration::seconds(2));
+        assert!(queues.process_player_action.execute().await.unwrap().is_none());
+
+        // A paused table's lines wait, and a safety signal drops them
+        say(bryn.id, "Who's paying?").await.unwrap();
+        assert!(queues.process_player_action.execute().await.unwrap().is_none());
+        signals.set_paused(h.world.id, true).await;
+        sim.clock.advance(chrono::Duration::seconds(2));
+        assert!(queues.process_player_action.execute().await.unwrap().is_none());
+        signals
+            .raise(h.world.id, SafetySignalLevel::Stop)
+            .await
+            .unwrap();
+        signals.set_paused(h.world.id, false).await;
+        assert!(queues.process_player_action.execute().await.unwrap().is_none());
+
+        assert!(queues
+            .process_llm_request
+            .execute(|_| {})
+            .await
+            .unwrap()
+            .is_none());
+        assert!(sim.llm.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn scripted_beat_branches_sets_flags_and_plays_once() {
+        use crate::infrastructure::ports::FlagRepo;
+        use crate::use_cases::dialogue_trees::BeatStep;
+        use wrldbldr_protocol::{DialogueBranchData, DialogueNodeData, DialogueTreeInputData};
+
+        let sim = Simulation::new(11);
+        let h = harbor(&sim).await;
+        let trees = &sim.app.use_cases.dialogue_trees;
+        let branch = |text: &str, next: Option<&str>, flags: &[&str]| DialogueBranchData {
+            text: text.to_string(),
+            next: next.map(str::to_string),
+            set_flags: flags.iter().map(|f| f.to_string()).collect(),
+            effects: Vec::new(),
+        };
+        trees
+            .manage
+            .create(
+                h.world.id,
+                DialogueTreeInputData {
+                    name: "Mira's riddle".to_string(),
+                    speaker_id: h.mira.id.to_string(),
+                    narrative_event_id: None,
+                    nodes: vec![
+                        DialogueNodeData {
+                            id: "greeting".to_string(),
+                            line: "Answer me this.".to_string(),
+                            choices: vec![
+                                branch("Go on", Some("riddle"), &[]),
+                                branch("Not now", None, &[]),
+                            ],
+                        },
+                        DialogueNodeData {
+                            id: "riddle".to_string(),
+                            line: "What has roots nobody sees?".to_string(),
+                            choices: vec![branch("A mountain", None, &["mira_riddle_solved"])],
+                        },
+                    ],
+                    active: true,
+                    repeatable: false,
+                },
+            )
+            .await
+            .unwrap();
+
+        let opening = trees
+            .beats
+            .begin_for_npc(h.world.id, h.pc.id, h.mira.id)
+            .await
+            .unwrap()
+            .expect("Mira has a beat for Ash");
+        assert_eq!(opening.text, "Answer me this.");
+        // Two written choices plus going off-script
+        assert_eq!(opening.choices.len(), 3);
+        assert!(trees.beats.in_beat(h.pc.id));
+
+        match trees.beats.choose(h.pc.id, "greeting.1").await.unwrap() {
+            BeatStep::Line(line) => assert_eq!(line.text, "What has roots nobody sees?"),
+            other => panic!("expected the riddle, got {other:?}"),
+        }
+        assert!(trees.beats.choose(h.pc.id, "greeting.2").await.is_err());
+        assert!(matches!(
+            trees.beats.choose(h.pc.id, "riddle.1").await.unwrap(),
+            BeatStep::Ended { .. }
+        ));
+        assert!(!trees.beats.in_beat(h.pc.id));
+        let flags = sim.store.get_world_flags(h.world.id).await.unwrap();
+        assert!(flags.contains(&"mira_riddle_solved".to_string()));
+
+        // Heard once; the next conversation goes to the LLM
+        assert!(trees
+            .beats
+            .begin_for_npc(h.world.id, h.pc.id, h.mira.id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
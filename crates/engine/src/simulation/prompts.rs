@@ -0,0 +1,393 @@
+//! Golden-file tests for LLM prompt assembly.
+//!
+//! Each test drives a real use case against a fixed world, takes the request
+//! the scripted LLM received, and compares its rendering (system prompt,
+//! messages, sampling settings and response schema) with a checked-in file
+//! under `src/simulation/golden/`. A diff means models will see something
+//! different; if that is intended, regenerate the files with
+//! `task test:prompts:update` and review them like any other change.
+//!
+//! Ids are random per run, so every UUID is rendered as `<uuid-N>`, numbered
+//! by first appearance.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use uuid::Uuid;
+use wrldbldr_domain::*;
+use wrldbldr_protocol::{ActantialRoleData, ChallengeOutcomeDecisionData, SuggestionContextData};
+
+use super::Simulation;
+use crate::infrastructure::ports::{CharacterRepo, LlmRequest, MessageRole};
+use crate::use_cases::custom_condition::EvaluationContext;
+
+const SUGGESTIONS: &str = r#"{"suggestions": ["One", "Two", "Three"]}"#;
+
+struct Fixture {
+    world: World,
+    tavern: Region,
+    mira: Character,
+    bram: Character,
+    pc: PlayerCharacter,
+}
+
+/// Saltmere harbor: Mira works the tavern, Bram drinks there, Ash arrives
+async fn fixture(sim: &Simulation) -> Fixture {
+    let world = sim.world("Saltmere").await;
+    let harbor = sim.location(world.id, "Harbor").await;
+    let docks = sim.region(harbor.id, "Docks").await;
+    let tavern = sim.region(harbor.id, "Salted Eel Tavern").await;
+    sim.connect(docks.id, tavern.id).await;
+    let mira = sim.npc(world.id, "Mira").await;
+    let bram = sim.npc(world.id, "Bram").await;
+    let store = sim.store.as_ref();
+    store
+        .set_work_region(mira.id, tavern.id, Some("day".to_string()))
+        .await
+        .unwrap();
+    store
+        .add_frequents_region(bram.id, tavern.id, "often".to_string(), None)
+        .await
+        .unwrap();
+    let pc = sim.pc(world.id, "Ash", &tavern).await;
+    Fixture {
+        world,
+        tavern,
+        mira,
+        bram,
+        pc,
+    }
+}
+
+/// Render everything a model receives from `request`
+fn render(request: &LlmRequest) -> String {
+    let mut out = String::new();
+    if let Some(system) = &request.system_prompt {
+        writeln!(out, "=== system ===\n{}\n", system.trim_end()).unwrap();
+    }
+    for message in &request.messages {
+        let role = match message.role {
+            MessageRole::User => "user",
+            MessageRole::Assistant => "assistant",
+            MessageRole::System => "system",
+            MessageRole::Unknown => "unknown",
+        };
+        writeln!(out, "=== {role} ===\n{}\n", message.content.trim_end()).unwrap();
+    }
+    writeln!(out, "=== settings ===").unwrap();
+    writeln!(out, "temperature: {:?}", request.temperature).unwrap();
+    writeln!(out, "max_tokens: {:?}", request.max_tokens).unwrap();
+    writeln!(out, "images: {}", request.images.len()).unwrap();
+    writeln!(out, "cache: {}", request.cache).unwrap();
+    if let Some(schema) = &request.response_schema {
+        let schema = serde_json::to_string_pretty(schema).unwrap();
+        writeln!(out, "\n=== response schema ===\n{schema}").unwrap();
+    }
+    redact_uuids(&out)
+}
+
+fn redact_uuids(text: &str) -> String {
+    const LEN: usize = 36;
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < text.len() {
+        let candidate = text.get(i..i + LEN).filter(|s| {
+            s.as_bytes()[8] == b'-' && s.as_bytes()[13] == b'-' && Uuid::try_parse(s).is_ok()
+        });
+        match candidate {
+            Some(uuid) => {
+                let next = seen.len() + 1;
+                let n = *seen.entry(uuid).or_insert(next);
+                write!(out, "<uuid-{n}>").unwrap();
+                i += LEN;
+            }
+            None => {
+                let ch = text[i..].chars().next().unwrap();
+                out.push(ch);
+                i += ch.len_utf8();
+            }
+        }
+    }
+    out
+}
+
+/// Compare the only request the LLM has seen since the last check with
+/// `golden/<name>.txt`
+fn assert_prompt(sim: &Simulation, seen_before: &mut usize, name: &str) {
+    let requests = sim.llm.requests();
+    assert_eq!(
+        requests.len(),
+        *seen_before + 1,
+        "{name}: expected exactly one LLM request"
+    );
+    *seen_before = requests.len();
+    let actual = render(requests.last().unwrap());
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/simulation/golden")
+        .join(format!("{name}.txt"));
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing golden file {}; run with UPDATE_GOLDEN=1 to create it",
+            path.display()
+        )
+    });
+    if expected != actual {
+        let line = expected
+            .lines()
+            .zip(actual.lines())
+            .position(|(e, a)| e != a)
+            .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+        panic!(
+            "prompt `{name}` no longer matches {} (first difference at line {}).\n\
+             Rerun with UPDATE_GOLDEN=1 if the change is intended.\n\n{actual}",
+            path.display(),
+            line + 1
+        );
+    }
+}
+
+#[tokio::test]
+async fn npc_dialogue_prompt() {
+    let sim = Simulation::new(1);
+    let f = fixture(&sim).await;
+    let use_cases = &sim.app.use_cases;
+    sim.llm.push("Work? Talk to the harbormaster, if you dare.");
+    use_cases
+        .approval
+        .approve_staging
+        .execute(f.tavern.id, vec![f.mira.id])
+        .await
+        .unwrap();
+
+    use_cases
+        .conversation
+        .start
+        .execute(
+            f.world.id,
+            f.pc.id,
+            f.mira.id,
+            "player-1".to_string(),
+            "Any work going?".to_string(),
+        )
+        .await
+        .unwrap();
+    use_cases
+        .queues
+        .process_player_action
+        .execute()
+        .await
+        .unwrap();
+    use_cases
+        .queues
+        .process_llm_request
+        .execute(|_| {})
+        .await
+        .unwrap();
+
+    assert_prompt(&sim, &mut 0, "npc_dialogue");
+}
+
+#[tokio::test]
+async fn outcome_suggestion_prompt() {
+    let sim = Simulation::new(2);
+    let f = fixture(&sim).await;
+    let use_cases = &sim.app.use_cases;
+    let challenge = sim
+        .challenge(
+            Challenge::new(f.world.id, "Haggle with Mira", Difficulty::DC(12)).with_outcomes(
+                ChallengeOutcomes::simple("Mira knocks off a silver", "Mira holds firm"),
+            ),
+        )
+        .await;
+    let rolled = use_cases
+        .challenge
+        .roll
+        .execute(f.world.id, challenge.id, f.pc.id, None, 20)
+        .await
+        .unwrap();
+    sim.llm.push(SUGGESTIONS);
+
+    use_cases
+        .challenge
+        .outcome_decision
+        .execute(
+            f.world.id,
+            rolled.approval_queue_id.unwrap().to_string(),
+            ChallengeOutcomeDecisionData::Suggest {
+                guidance: Some("Make her grudging".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    use_cases
+        .queues
+        .process_llm_request
+        .execute(|_| {})
+        .await
+        .unwrap();
+
+    assert_prompt(&sim, &mut 0, "outcome_suggestion");
+}
+
+#[tokio::test]
+async fn content_suggestion_prompts() {
+    let sim = Simulation::new(3);
+    let f = fixture(&sim).await;
+    let ops = &sim.app.use_cases.ai.suggestions;
+    let mut seen = 0;
+
+    let content = [
+        ("character_name", "npc", None, "Gruff dockhand"),
+        (
+            "character_description",
+            "npc",
+            Some("Bram"),
+            "Gruff dockhand",
+        ),
+        ("location_name", "tavern", None, "Smugglers"),
+        (
+            "location_description",
+            "tavern",
+            Some("Salted Eel"),
+            "Smugglers",
+        ),
+        // No dedicated prompt: exercises the generic fallback
+        (
+            "location_atmosphere",
+            "tavern",
+            Some("Salted Eel"),
+            "Smugglers",
+        ),
+    ];
+    for (field, entity_type, entity_name, hints) in content {
+        ops.enqueue_content_suggestion(
+            f.world.id,
+            field.to_string(),
+            SuggestionContextData {
+                entity_type: Some(entity_type.to_string()),
+                entity_name: entity_name.map(str::to_string),
+                hints: Some(hints.to_string()),
+                additional_context: Some("Near the fish market".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        process_suggestion(&sim).await;
+        assert_prompt(&sim, &mut seen, &format!("suggestion_{field}"));
+    }
+
+    ops.suggest_want_description(f.world.id, f.mira.id, Some("Runs the bar".to_string()))
+        .await
+        .unwrap();
+    process_suggestion(&sim).await;
+    assert_prompt(&sim, &mut seen, "suggestion_want_description");
+
+    ops.suggest_deflection_behavior(
+        f.world.id,
+        f.mira.id,
+        "want-1".to_string(),
+        "Buy the tavern outright".to_string(),
+    )
+    .await
+    .unwrap();
+    process_suggestion(&sim).await;
+    assert_prompt(&sim, &mut seen, "suggestion_deflection_behavior");
+
+    ops.suggest_behavioral_tells(
+        f.world.id,
+        f.mira.id,
+        "want-1".to_string(),
+        "Buy the tavern outright".to_string(),
+    )
+    .await
+    .unwrap();
+    process_suggestion(&sim).await;
+    assert_prompt(&sim, &mut seen, "suggestion_behavioral_tells");
+
+    ops.suggest_actantial_reason(
+        f.world.id,
+        f.mira.id,
+        "want-1".to_string(),
+        f.bram.id.to_string(),
+        ActantialRoleData::Opponent,
+    )
+    .await
+    .unwrap();
+    process_suggestion(&sim).await;
+    assert_prompt(&sim, &mut seen, "suggestion_actantial_reason");
+}
+
+async fn process_suggestion(sim: &Simulation) {
+    sim.llm.push(SUGGESTIONS);
+    sim.app
+        .use_cases
+        .queues
+        .process_llm_request
+        .execute(|_| {})
+        .await
+        .unwrap()
+        .expect("queued suggestion");
+}
+
+#[tokio::test]
+async fn staging_suggestion_prompt() {
+    let sim = Simulation::new(4);
+    let f = fixture(&sim).await;
+    sim.llm
+        .push(r#"[{"name": "Mira", "reason": "She works the bar by day"}]"#);
+
+    let staged = sim
+        .app
+        .use_cases
+        .staging
+        .regenerate
+        .execute(f.tavern.id, Some("Market day, the place is packed"))
+        .await
+        .unwrap();
+
+    assert_eq!(staged.len(), 1);
+    assert_prompt(&sim, &mut 0, "staging_suggestions");
+}
+
+#[tokio::test]
+async fn custom_condition_prompt() {
+    let sim = Simulation::new(5);
+    let _ = fixture(&sim).await;
+    sim.llm
+        .push(r#"{"result": true, "confidence": 0.9, "reasoning": "The bribe is in the flags"}"#);
+    let context = EvaluationContext::new()
+        .with_time_of_day("Evening")
+        .with_location("Salted Eel Tavern, crowded and loud")
+        .with_npcs(["Mira", "Bram"])
+        .with_inventory(["Silver ring", "Harbor pass"])
+        .with_flags(["bribed_harbormaster"])
+        .with_recent_events(["Ash lost a dice game to Bram"]);
+
+    sim.app
+        .use_cases
+        .custom_condition
+        .evaluate("Ash has bribed the harbormaster", &context)
+        .await
+        .unwrap();
+
+    assert_prompt(&sim, &mut 0, "custom_condition");
+}
+
+#[test]
+fn uuids_are_numbered_by_first_appearance() {
+    let a = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let b = "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8";
+    assert_eq!(
+        redact_uuids(&format!("{a} → {b}, {a}!")),
+        "<uuid-1> → <uuid-2>, <uuid-1>!"
+    );
+}
@@ -0,0 +1,73 @@
+//! LLM port that replies from a script.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::infrastructure::ports::{
+    FinishReason, LlmError, LlmPort, LlmRequest, LlmResponse, ToolDefinition,
+};
+
+/// Replies with queued lines in order and records every request it saw.
+///
+/// Running out of lines fails the call, so a sequence that makes more LLM
+/// calls than the test expects shows up as an error rather than a hang.
+#[derive(Default)]
+pub(crate) struct ScriptedLlm {
+    replies: Mutex<VecDeque<String>>,
+    requests: Mutex<Vec<LlmRequest>>,
+}
+
+impl ScriptedLlm {
+    /// Queue the next reply
+    pub(crate) fn push(&self, reply: impl Into<String>) {
+        self.replies
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push_back(reply.into());
+    }
+
+    /// Every request made so far, oldest first
+    pub(crate) fn requests(&self) -> Vec<LlmRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn reply(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request);
+        let content = self
+            .replies
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pop_front()
+            .ok_or_else(|| LlmError::RequestFailed("Script has no more replies".to_string()))?;
+
+        Ok(LlmResponse {
+            content,
+            tool_calls: Vec::new(),
+            finish_reason: FinishReason::Stop,
+            usage: None,
+        })
+    }
+}
+
+#[async_trait]
+impl LlmPort for ScriptedLlm {
+    async fn generate(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
+        self.reply(request)
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: LlmRequest,
+        _tools: Vec<ToolDefinition>,
+    ) -> Result<LlmResponse, LlmError> {
+        self.reply(request)
+    }
+}
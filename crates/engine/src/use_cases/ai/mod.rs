@@ -1,3 +1,8 @@
+mod models;
+pub mod structured;
+
+pub use models::{ModelError, ModelOps};
+
 use std::sync::Arc;
 
 use uuid::Uuid;
@@ -11,11 +16,15 @@ use crate::infrastructure::ports::{QueueError, QueuePort, RepoError};
 
 pub struct AiUseCases {
     pub suggestions: Arc<SuggestionOps>,
+    pub models: Arc<ModelOps>,
 }
 
 impl AiUseCases {
-    pub fn new(suggestions: Arc<SuggestionOps>) -> Self {
-        Self { suggestions }
+    pub fn new(suggestions: Arc<SuggestionOps>, models: Arc<ModelOps>) -> Self {
+        Self {
+            suggestions,
+            models,
+        }
     }
 }
 
@@ -208,6 +217,8 @@ impl SuggestionOps {
             suggestion_context,
             callback_id: callback_id.clone(),
             conversation_id: None,
+            retry_count: 0,
+            merged_lines: vec![],
         };
 
         self.queue.enqueue_llm_request(&llm_request).await?;
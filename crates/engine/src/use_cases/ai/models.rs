@@ -0,0 +1,184 @@
+//! LLM model management.
+//!
+//! Proxies the LLM server's model registry (list and pull) and stores which
+//! models each world uses: one for NPC dialogue and a cheaper one for
+//! summaries and suggestions.
+
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio::sync::mpsc::UnboundedSender;
+use wrldbldr_domain::{AppSettings, WorldId};
+
+use crate::entities;
+use crate::entities::settings::SettingsError;
+use crate::infrastructure::ports::{LlmError, LlmModelInfo, LlmModelPort, ModelPullProgress};
+
+/// Models a world uses; `None` means the engine's configured model.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct WorldModels {
+    pub dialogue_model: Option<String>,
+    pub summary_model: Option<String>,
+}
+
+impl From<&AppSettings> for WorldModels {
+    fn from(settings: &AppSettings) -> Self {
+        Self {
+            dialogue_model: settings.dialogue_model.clone(),
+            summary_model: settings.summary_model.clone(),
+        }
+    }
+}
+
+/// Model registry operations.
+pub struct ModelOps {
+    models: Arc<dyn LlmModelPort>,
+    settings: Arc<entities::Settings>,
+}
+
+impl ModelOps {
+    pub fn new(models: Arc<dyn LlmModelPort>, settings: Arc<entities::Settings>) -> Self {
+        Self { models, settings }
+    }
+
+    /// Models available on the LLM server.
+    pub async fn list(&self) -> Result<Vec<LlmModelInfo>, ModelError> {
+        Ok(self.models.list_models().await?)
+    }
+
+    /// Download a model, sending progress updates until it finishes.
+    pub async fn pull(
+        &self,
+        model: &str,
+        progress: UnboundedSender<ModelPullProgress>,
+    ) -> Result<(), ModelError> {
+        let model = model.trim();
+        if model.is_empty() {
+            return Err(ModelError::EmptyName);
+        }
+        Ok(self.models.pull_model(model, progress).await?)
+    }
+
+    /// Choose a world's dialogue and summarization models.
+    ///
+    /// Blank names clear the choice. Named models must already be pulled.
+    pub async fn set_world_models(
+        &self,
+        world_id: WorldId,
+        dialogue_model: Option<String>,
+        summary_model: Option<String>,
+    ) -> Result<WorldModels, ModelError> {
+        let dialogue_model = non_blank(dialogue_model);
+        let summary_model = non_blank(summary_model);
+
+        if dialogue_model.is_some() || summary_model.is_some() {
+            let available = self.models.list_models().await?;
+            for model in dialogue_model.iter().chain(summary_model.iter()) {
+                if !available.iter().any(|m| same_model(&m.name, model)) {
+                    return Err(ModelError::NotAvailable(model.clone()));
+                }
+            }
+        }
+
+        let mut settings = self.settings.get_for_world(world_id).await?;
+        settings.dialogue_model = dialogue_model;
+        settings.summary_model = summary_model;
+        let saved = self.settings.update_for_world(world_id, settings).await?;
+        Ok(WorldModels::from(&saved))
+    }
+}
+
+fn non_blank(model: Option<String>) -> Option<String> {
+    model
+        .map(|m| m.trim().to_string())
+        .filter(|m| !m.is_empty())
+}
+
+/// Ollama lists "llama3.2:latest" for a model pulled as "llama3.2".
+fn same_model(listed: &str, requested: &str) -> bool {
+    listed == requested || listed.strip_suffix(":latest") == Some(requested)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ModelError {
+    #[error("Model name is required")]
+    EmptyName,
+    #[error("Model '{0}' is not available; pull it first")]
+    NotAvailable(String),
+    #[error("LLM error: {0}")]
+    Llm(#[from] LlmError),
+    #[error("Settings error: {0}")]
+    Settings(#[from] SettingsError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ports::{MockLlmModelPort, MockSettingsRepo};
+
+    fn registry() -> MockLlmModelPort {
+        let mut models = MockLlmModelPort::new();
+        models.expect_list_models().returning(|| {
+            Ok(vec![
+                LlmModelInfo {
+                    name: "llama3.2:latest".to_string(),
+                    size_bytes: 2_000_000_000,
+                },
+                LlmModelInfo {
+                    name: "qwen2.5:0.5b".to_string(),
+                    size_bytes: 400_000_000,
+                },
+            ])
+        });
+        models
+    }
+
+    #[tokio::test]
+    async fn set_world_models_saves_available_models() {
+        let world_id = WorldId::new();
+        let mut repo = MockSettingsRepo::new();
+        repo.expect_get_for_world().returning(|_| Ok(None));
+        repo.expect_get_global().returning(|| Ok(None));
+        repo.expect_save_for_world()
+            .withf(|_, s| {
+                s.dialogue_model.as_deref() == Some("llama3.2")
+                    && s.summary_model.as_deref() == Some("qwen2.5:0.5b")
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let ops = ModelOps::new(
+            Arc::new(registry()),
+            Arc::new(entities::Settings::new(Arc::new(repo))),
+        );
+
+        let models = ops
+            .set_world_models(
+                world_id,
+                Some("llama3.2".to_string()),
+                Some(" qwen2.5:0.5b ".to_string()),
+            )
+            .await
+            .expect("models saved");
+
+        assert_eq!(models.dialogue_model.as_deref(), Some("llama3.2"));
+        assert_eq!(models.summary_model.as_deref(), Some("qwen2.5:0.5b"));
+    }
+
+    #[tokio::test]
+    async fn set_world_models_rejects_models_not_pulled() {
+        let mut repo = MockSettingsRepo::new();
+        repo.expect_save_for_world().times(0);
+
+        let ops = ModelOps::new(
+            Arc::new(registry()),
+            Arc::new(entities::Settings::new(Arc::new(repo))),
+        );
+
+        let result = ops
+            .set_world_models(WorldId::new(), Some("mixtral".to_string()), None)
+            .await;
+
+        assert!(matches!(result, Err(ModelError::NotAvailable(m)) if m == "mixtral"));
+    }
+}
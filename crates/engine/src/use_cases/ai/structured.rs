@@ -0,0 +1,306 @@
+//! Structured (JSON) LLM output.
+//!
+//! Calls that expect data rather than prose send a JSON schema with the
+//! request, which Ollama uses to constrain generation. Replies are still
+//! validated against the same schema before use; one that doesn't fit is
+//! retried with the validation error fed back to the model, so malformed
+//! data never reaches callers or the approval queue.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::infrastructure::ports::{ChatMessage, LlmError, LlmPort, LlmRequest};
+
+/// Attempts per call, including the first.
+pub const MAX_ATTEMPTS: usize = 3;
+
+/// Generate a reply constrained to `schema` and deserialize it.
+pub async fn generate_structured<T: DeserializeOwned>(
+    llm: &dyn LlmPort,
+    request: LlmRequest,
+    schema: Value,
+) -> Result<T, LlmError> {
+    generate_validated(llm, request, schema, |value| {
+        serde_json::from_value(value.clone()).map_err(|e| e.to_string())
+    })
+    .await
+}
+
+/// Generate a reply constrained to `schema`, converting it with `parse`.
+///
+/// `parse` only sees replies that already match the schema; an `Err` from
+/// it counts as an invalid reply and is retried like a schema violation.
+pub async fn generate_validated<T>(
+    llm: &dyn LlmPort,
+    request: LlmRequest,
+    schema: Value,
+    parse: impl Fn(&Value) -> Result<T, String>,
+) -> Result<T, LlmError> {
+    let mut request = request.with_response_schema(Some(schema.clone()));
+    let mut last_error = String::new();
+
+    for attempt in 1..=MAX_ATTEMPTS {
+        let response = llm.generate(request.clone()).await?;
+
+        let reply = serde_json::from_str::<Value>(extract_json(&response.content))
+            .map_err(|e| format!("not valid JSON ({})", e))
+            .and_then(|value| validate(&schema, &value).map(|()| value))
+            .and_then(|value| parse(&value));
+
+        match reply {
+            Ok(parsed) => return Ok(parsed),
+            Err(reason) => {
+                tracing::warn!(
+                    attempt,
+                    reason = %reason,
+                    "LLM reply did not match the response schema"
+                );
+                request
+                    .messages
+                    .push(ChatMessage::assistant(response.content));
+                request.messages.push(ChatMessage::user(format!(
+                    "That reply was invalid: {}. Respond again with only JSON matching the schema.",
+                    reason
+                )));
+                last_error = reason;
+            }
+        }
+    }
+
+    Err(LlmError::InvalidResponse(format!(
+        "No valid reply after {} attempts: {}",
+        MAX_ATTEMPTS, last_error
+    )))
+}
+
+/// Strip the markdown code fence some models wrap around JSON.
+fn extract_json(content: &str) -> &str {
+    let trimmed = content.trim();
+    match trimmed.strip_prefix("```") {
+        Some(fenced) => {
+            // Drop the language tag line, e.g. "```json"
+            let body = fenced.split_once('\n').map_or(fenced, |(_, rest)| rest);
+            body.trim_end().trim_end_matches("```").trim()
+        }
+        None => trimmed,
+    }
+}
+
+/// Check `value` against `schema`.
+///
+/// Supports the JSON Schema keywords our response schemas use: `type`,
+/// `properties`, `required`, `items`, `enum`, `minItems`/`maxItems`,
+/// `minLength` and `minimum`/`maximum`.
+pub fn validate(schema: &Value, value: &Value) -> Result<(), String> {
+    validate_at(schema, value, "$")
+}
+
+fn validate_at(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        let matches = match expected {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "number" => value.is_number(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            _ => true,
+        };
+        if !matches {
+            return Err(format!("{} should be of type {}", path, expected));
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            return Err(format!(
+                "{} should be one of {}",
+                path,
+                Value::from(allowed.clone())
+            ));
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(key) {
+                    return Err(format!("{} is missing required field '{}'", path, key));
+                }
+            }
+        }
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (key, property) in properties {
+                if let Some(field) = object.get(key) {
+                    validate_at(property, field, &format!("{}.{}", path, key))?;
+                }
+            }
+        }
+    }
+
+    if let Some(items) = value.as_array() {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if (items.len() as u64) < min {
+                return Err(format!("{} should have at least {} items", path, min));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if items.len() as u64 > max {
+                return Err(format!("{} should have at most {} items", path, max));
+            }
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (i, item) in items.iter().enumerate() {
+                validate_at(item_schema, item, &format!("{}[{}]", path, i))?;
+            }
+        }
+    }
+
+    if let Some(text) = value.as_str() {
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+            if (text.chars().count() as u64) < min {
+                return Err(format!("{} should be at least {} characters", path, min));
+            }
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+            if number < min {
+                return Err(format!("{} should be at least {}", path, min));
+            }
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+            if number > max {
+                return Err(format!("{} should be at most {}", path, max));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ports::{FinishReason, LlmResponse, ToolDefinition};
+    use serde::Deserialize;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    /// Replies with each canned response in turn, recording requests.
+    struct ScriptedLlm {
+        replies: Mutex<Vec<String>>,
+        requests: Mutex<Vec<LlmRequest>>,
+    }
+
+    impl ScriptedLlm {
+        fn new(replies: &[&str]) -> Self {
+            Self {
+                replies: Mutex::new(replies.iter().rev().map(|r| r.to_string()).collect()),
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl LlmPort for ScriptedLlm {
+        async fn generate(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
+            self.requests.lock().unwrap().push(request);
+            let content = self.replies.lock().unwrap().pop().unwrap_or_default();
+            Ok(LlmResponse {
+                content,
+                tool_calls: vec![],
+                finish_reason: FinishReason::Stop,
+                usage: None,
+            })
+        }
+
+        async fn generate_with_tools(
+            &self,
+            request: LlmRequest,
+            _tools: Vec<ToolDefinition>,
+        ) -> Result<LlmResponse, LlmError> {
+            self.generate(request).await
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Suggestions {
+        suggestions: Vec<String>,
+    }
+
+    fn suggestions_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "suggestions": {
+                    "type": "array",
+                    "items": { "type": "string", "minLength": 1 },
+                    "minItems": 1
+                }
+            },
+            "required": ["suggestions"]
+        })
+    }
+
+    #[test]
+    fn validate_reports_the_offending_path() {
+        let schema = suggestions_schema();
+        assert!(validate(&schema, &json!({ "suggestions": ["a", "b"] })).is_ok());
+
+        let err = validate(&schema, &json!({ "suggestions": ["a", 3] })).unwrap_err();
+        assert!(err.contains("$.suggestions[1]"), "{}", err);
+
+        let err = validate(&schema, &json!({ "ideas": [] })).unwrap_err();
+        assert!(err.contains("suggestions"), "{}", err);
+    }
+
+    #[test]
+    fn extract_json_strips_code_fences() {
+        assert_eq!(extract_json("```json\n{\"a\": 1}\n```"), "{\"a\": 1}");
+        assert_eq!(extract_json("  [1, 2] "), "[1, 2]");
+    }
+
+    #[tokio::test]
+    async fn generate_structured_retries_invalid_replies_with_feedback() {
+        let llm = ScriptedLlm::new(&[
+            "Here are some ideas: Mira, Tomas",
+            r#"{"suggestions": ["Mira", "Tomas"]}"#,
+        ]);
+
+        let reply: Suggestions = generate_structured(
+            &llm,
+            LlmRequest::new(vec![ChatMessage::user("Suggest names")]),
+            suggestions_schema(),
+        )
+        .await
+        .expect("second reply is valid");
+
+        assert_eq!(reply.suggestions, vec!["Mira", "Tomas"]);
+
+        let requests = llm.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].response_schema.is_some());
+        assert!(requests[1]
+            .messages
+            .last()
+            .is_some_and(|m| m.content.contains("not valid JSON")));
+    }
+
+    #[tokio::test]
+    async fn generate_structured_gives_up_after_max_attempts() {
+        let llm = ScriptedLlm::new(&[r#"{"suggestions": []}"#; MAX_ATTEMPTS]);
+
+        let result: Result<Suggestions, _> = generate_structured(
+            &llm,
+            LlmRequest::new(vec![ChatMessage::user("Suggest names")]),
+            suggestions_schema(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(LlmError::InvalidResponse(_))));
+        assert_eq!(llm.requests.lock().unwrap().len(), MAX_ATTEMPTS);
+    }
+}
@@ -0,0 +1,201 @@
+//! Campaign analytics use cases.
+//!
+//! Sums up where a world's play has gone: each PC's share of the spotlight,
+//! how often each NPC is spoken to and how often each location is visited,
+//! so the DM can see who has been left out and which content never gets
+//! touched.
+
+mod unused;
+
+pub use unused::GetUnusedContent;
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use serde::Serialize;
+use wrldbldr_domain::{CharacterId, LocationId, PlayerCharacterId, WorldId};
+
+use crate::entities::{Challenge, Character, Location, Narrative, Observation, PlayerCharacter};
+use crate::infrastructure::ports::RepoError;
+
+/// Container for analytics use cases.
+pub struct AnalyticsUseCases {
+    pub campaign: Arc<GetCampaignAnalytics>,
+    pub unused: Arc<GetUnusedContent>,
+}
+
+impl AnalyticsUseCases {
+    pub fn new(campaign: Arc<GetCampaignAnalytics>, unused: Arc<GetUnusedContent>) -> Self {
+        Self { campaign, unused }
+    }
+}
+
+/// One PC's share of the spotlight
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PcSpotlight {
+    pub pc_id: PlayerCharacterId,
+    pub name: String,
+    pub dialogue_exchanges: u32,
+    pub challenges: u32,
+    pub story_events: u32,
+}
+
+impl PcSpotlight {
+    fn total(&self) -> u32 {
+        self.dialogue_exchanges + self.challenges + self.story_events
+    }
+}
+
+/// How often an NPC has been spoken to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NpcInteractions {
+    pub npc_id: CharacterId,
+    pub name: String,
+    pub dialogue_exchanges: u32,
+    /// PCs who have spoken to them
+    pub pcs: u32,
+}
+
+/// How often a location has been visited
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LocationHeat {
+    pub location_id: LocationId,
+    pub name: String,
+    pub visits: u32,
+    /// PCs who have been there
+    pub visitors: u32,
+}
+
+/// A world's campaign analytics. Each list runs quietest first, so whoever
+/// has been left out and whatever has never been touched comes at the top.
+#[derive(Debug, Clone, Serialize)]
+pub struct CampaignAnalytics {
+    pub world_id: WorldId,
+    /// Active PCs
+    pub pcs: Vec<PcSpotlight>,
+    pub npcs: Vec<NpcInteractions>,
+    pub locations: Vec<LocationHeat>,
+}
+
+/// Get a world's campaign analytics.
+pub struct GetCampaignAnalytics {
+    player_character: Arc<PlayerCharacter>,
+    character: Arc<Character>,
+    location: Arc<Location>,
+    narrative: Arc<Narrative>,
+    challenge: Arc<Challenge>,
+    observation: Arc<Observation>,
+}
+
+impl GetCampaignAnalytics {
+    pub fn new(
+        player_character: Arc<PlayerCharacter>,
+        character: Arc<Character>,
+        location: Arc<Location>,
+        narrative: Arc<Narrative>,
+        challenge: Arc<Challenge>,
+        observation: Arc<Observation>,
+    ) -> Self {
+        Self {
+            player_character,
+            character,
+            location,
+            narrative,
+            challenge,
+            observation,
+        }
+    }
+
+    pub async fn execute(&self, world_id: WorldId) -> Result<CampaignAnalytics, RepoError> {
+        let pcs = self.player_character.list_in_world(world_id).await?;
+
+        let dialogue = self.narrative.list_dialogue_counts(world_id).await?;
+        let story_events: HashMap<PlayerCharacterId, u32> = self
+            .narrative
+            .count_story_events_by_pc(world_id)
+            .await?
+            .into_iter()
+            .collect();
+        let mut challenges: HashMap<PlayerCharacterId, u32> = HashMap::new();
+        for resolution in self
+            .challenge
+            .list_resolutions(world_id, None, usize::MAX)
+            .await?
+        {
+            *challenges.entry(resolution.pc_id).or_default() += 1;
+        }
+
+        let mut spotlight: Vec<PcSpotlight> = pcs
+            .iter()
+            .filter(|pc| pc.is_active)
+            .map(|pc| PcSpotlight {
+                pc_id: pc.id,
+                name: pc.name.clone(),
+                dialogue_exchanges: dialogue
+                    .iter()
+                    .filter(|d| d.pc_id == pc.id)
+                    .map(|d| d.exchanges)
+                    .sum(),
+                challenges: challenges.get(&pc.id).copied().unwrap_or(0),
+                story_events: story_events.get(&pc.id).copied().unwrap_or(0),
+            })
+            .collect();
+        spotlight.sort_by(|a, b| a.total().cmp(&b.total()).then_with(|| a.name.cmp(&b.name)));
+
+        let mut npcs: Vec<NpcInteractions> = self
+            .character
+            .list_npcs_in_world(world_id)
+            .await?
+            .into_iter()
+            .map(|npc| {
+                let spoken = dialogue.iter().filter(|d| d.npc_id == npc.id);
+                NpcInteractions {
+                    npc_id: npc.id,
+                    name: npc.name,
+                    dialogue_exchanges: spoken.clone().map(|d| d.exchanges).sum(),
+                    pcs: spoken.map(|d| d.pc_id).collect::<HashSet<_>>().len() as u32,
+                }
+            })
+            .collect();
+        npcs.sort_by(|a, b| {
+            a.dialogue_exchanges
+                .cmp(&b.dialogue_exchanges)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        // Every PC's visits, including those no longer active
+        let mut visits: HashMap<LocationId, (u32, u32)> = HashMap::new();
+        for pc in &pcs {
+            for known in self.observation.get_known_locations(pc.id).await? {
+                if known.is_visited() {
+                    let (count, visitors) = visits.entry(known.location_id).or_default();
+                    *count += known.visits;
+                    *visitors += 1;
+                }
+            }
+        }
+        let mut locations: Vec<LocationHeat> = self
+            .location
+            .list_in_world(world_id)
+            .await?
+            .into_iter()
+            .map(|location| {
+                let (visits, visitors) = visits.get(&location.id).copied().unwrap_or_default();
+                LocationHeat {
+                    location_id: location.id,
+                    name: location.name,
+                    visits,
+                    visitors,
+                }
+            })
+            .collect();
+        locations.sort_by(|a, b| a.visits.cmp(&b.visits).then_with(|| a.name.cmp(&b.name)));
+
+        Ok(CampaignAnalytics {
+            world_id,
+            pcs: spotlight,
+            npcs,
+            locations,
+        })
+    }
+}
@@ -0,0 +1,361 @@
+//! Unused content report ("Chekhov's gun" reminders).
+//!
+//! Finds content the DM authored that play has never reached: challenges
+//! never attempted, NPCs no PC has met, lore no PC has discovered and
+//! narrative events whose triggers can no longer fire. Each comes with a
+//! suggestion for where it could be brought back in.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use serde::Serialize;
+use wrldbldr_domain::{
+    ChallengeId, CharacterId, LocationId, LoreId, NarrativeEventId, NarrativeTriggerType, WorldId,
+};
+
+use crate::entities::{
+    Challenge, Character, Location, Lore, Narrative, Observation, PlayerCharacter,
+};
+use crate::infrastructure::ports::{NpcRegionRelationType, RepoError};
+
+/// An active challenge no PC has attempted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnusedChallenge {
+    pub challenge_id: ChallengeId,
+    pub name: String,
+    pub suggestion: String,
+}
+
+/// An NPC no PC has seen or spoken to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnmetNpc {
+    pub npc_id: CharacterId,
+    pub name: String,
+    pub suggestion: String,
+}
+
+/// Lore that isn't common knowledge and no PC has discovered
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UndiscoveredLore {
+    pub lore_id: LoreId,
+    pub title: String,
+    pub suggestion: String,
+}
+
+/// An active narrative event whose triggers can no longer all be met
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StuckEvent {
+    pub event_id: NarrativeEventId,
+    pub name: String,
+    /// Descriptions of the triggers that can never fire
+    pub dead_triggers: Vec<String>,
+    pub suggestion: String,
+}
+
+/// A world's authored-but-unused content, each list sorted by name.
+#[derive(Debug, Clone, Serialize)]
+pub struct UnusedContentReport {
+    pub world_id: WorldId,
+    pub challenges: Vec<UnusedChallenge>,
+    pub npcs: Vec<UnmetNpc>,
+    pub lore: Vec<UndiscoveredLore>,
+    pub events: Vec<StuckEvent>,
+}
+
+/// Find a world's unused content.
+pub struct GetUnusedContent {
+    player_character: Arc<PlayerCharacter>,
+    character: Arc<Character>,
+    location: Arc<Location>,
+    narrative: Arc<Narrative>,
+    challenge: Arc<Challenge>,
+    observation: Arc<Observation>,
+    lore: Arc<Lore>,
+}
+
+impl GetUnusedContent {
+    pub fn new(
+        player_character: Arc<PlayerCharacter>,
+        character: Arc<Character>,
+        location: Arc<Location>,
+        narrative: Arc<Narrative>,
+        challenge: Arc<Challenge>,
+        observation: Arc<Observation>,
+        lore: Arc<Lore>,
+    ) -> Self {
+        Self {
+            player_character,
+            character,
+            location,
+            narrative,
+            challenge,
+            observation,
+            lore,
+        }
+    }
+
+    pub async fn execute(&self, world_id: WorldId) -> Result<UnusedContentReport, RepoError> {
+        let pcs = self.player_character.list_in_world(world_id).await?;
+        let npcs = self.character.list_npcs_in_world(world_id).await?;
+        let locations = self.location.list_in_world(world_id).await?;
+        let dialogue = self.narrative.list_dialogue_counts(world_id).await?;
+
+        // Who the party has met and where it spends its time
+        let mut met: HashSet<CharacterId> = dialogue.iter().map(|d| d.npc_id).collect();
+        let mut visits: HashMap<LocationId, u32> = HashMap::new();
+        for pc in &pcs {
+            for observation in self.observation.get_observations(pc.id).await? {
+                met.insert(observation.npc_id);
+            }
+            for known in self.observation.get_known_locations(pc.id).await? {
+                *visits.entry(known.location_id).or_default() += known.visits;
+            }
+        }
+        let busiest = locations
+            .iter()
+            .filter_map(|l| visits.get(&l.id).filter(|v| **v > 0).map(|v| (*v, l)))
+            .max_by(|(a, la), (b, lb)| a.cmp(b).then_with(|| lb.name.cmp(&la.name)))
+            .map(|(_, l)| l.name.clone());
+
+        // Challenges
+        let challenges = self.challenge.list_for_world(world_id).await?;
+        let resolutions = self
+            .challenge
+            .list_resolutions(world_id, None, usize::MAX)
+            .await?;
+        let resolved: HashSet<ChallengeId> = self
+            .challenge
+            .get_resolved(world_id)
+            .await?
+            .into_iter()
+            .chain(resolutions.iter().map(|r| r.challenge_id))
+            .collect();
+        let mut unused_challenges: Vec<UnusedChallenge> = challenges
+            .iter()
+            .filter(|c| c.active && !resolved.contains(&c.id))
+            .map(|c| UnusedChallenge {
+                challenge_id: c.id,
+                name: c.name.clone(),
+                suggestion: match &busiest {
+                    Some(place) => {
+                        format!(
+                            "Stage it at {}, where the party spends the most time",
+                            place
+                        )
+                    }
+                    None => "Stage it wherever the party heads next".to_string(),
+                },
+            })
+            .collect();
+        unused_challenges.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // NPCs
+        let mut unmet = Vec::new();
+        for npc in npcs.iter().filter(|n| !met.contains(&n.id)) {
+            let mut places = Vec::new();
+            for relationship in self.character.get_region_relationships(npc.id).await? {
+                let why = match relationship.relationship_type {
+                    NpcRegionRelationType::HomeRegion => "where they live",
+                    NpcRegionRelationType::WorksAt => "where they work",
+                    NpcRegionRelationType::Frequents => "which they frequent",
+                    NpcRegionRelationType::Avoids => continue,
+                };
+                if let Some(region) = self.location.get_region(relationship.region_id).await? {
+                    let visited = visits.get(&region.location_id).copied().unwrap_or(0) > 0;
+                    places.push((visited, region.name, why));
+                }
+            }
+            // Somewhere the party has already been, if they can be found there
+            places.sort_by_key(|(visited, _, _)| !visited);
+            let suggestion = match (places.first(), &busiest) {
+                (Some((_, region, why)), _) => format!("Stage them in {}, {}", region, why),
+                (None, Some(place)) => format!("Give them a reason to be at {}", place),
+                (None, None) => {
+                    "Give them a home or work region so staging can place them".to_string()
+                }
+            };
+            unmet.push(UnmetNpc {
+                npc_id: npc.id,
+                name: npc.name.clone(),
+                suggestion,
+            });
+        }
+        unmet.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Lore
+        let pc_characters: HashSet<CharacterId> = pcs
+            .iter()
+            .map(|pc| CharacterId::from_uuid(pc.id.to_uuid()))
+            .collect();
+        let most_spoken_to = npcs
+            .iter()
+            .map(|npc| {
+                let exchanges: u32 = dialogue
+                    .iter()
+                    .filter(|d| d.npc_id == npc.id)
+                    .map(|d| d.exchanges)
+                    .sum();
+                (exchanges, npc)
+            })
+            .filter(|(exchanges, _)| *exchanges > 0)
+            .max_by(|(a, na), (b, nb)| a.cmp(b).then_with(|| nb.name.cmp(&na.name)))
+            .map(|(_, npc)| npc.name.clone());
+        let mut undiscovered = Vec::new();
+        for lore in self.lore.list_for_world(world_id).await? {
+            if lore.is_common_knowledge {
+                continue;
+            }
+            let knowers: HashSet<CharacterId> = self
+                .lore
+                .get_knowledge_for_lore(lore.id)
+                .await?
+                .into_iter()
+                .map(|k| k.character_id)
+                .collect();
+            if !knowers.is_disjoint(&pc_characters) {
+                continue;
+            }
+            // A knowing NPC the party has already met makes the easiest source
+            let mut sources: Vec<_> = npcs.iter().filter(|n| knowers.contains(&n.id)).collect();
+            sources.sort_by(|a, b| {
+                met.contains(&b.id)
+                    .cmp(&met.contains(&a.id))
+                    .then_with(|| a.name.cmp(&b.name))
+            });
+            let suggestion = match (sources.first(), &most_spoken_to) {
+                (Some(npc), _) => format!("Let {} share it", npc.name),
+                (None, Some(name)) => {
+                    format!("Have {} know it; the party talks to them most", name)
+                }
+                (None, None) => "Tie it to an NPC or place the party will find".to_string(),
+            };
+            undiscovered.push(UndiscoveredLore {
+                lore_id: lore.id,
+                title: lore.title,
+                suggestion,
+            });
+        }
+        undiscovered.sort_by(|a, b| a.title.cmp(&b.title));
+
+        // Narrative events
+        let events = self.narrative.list_events(world_id).await?;
+        let location_ids: HashSet<LocationId> = locations.iter().map(|l| l.id).collect();
+        let character_ids: HashSet<CharacterId> = npcs
+            .iter()
+            .map(|n| n.id)
+            .chain(pc_characters.iter().copied())
+            .collect();
+        let challenge_by_id: HashMap<ChallengeId, &wrldbldr_domain::Challenge> =
+            challenges.iter().map(|c| (c.id, c)).collect();
+        let event_by_id: HashMap<NarrativeEventId, &wrldbldr_domain::NarrativeEvent> =
+            events.iter().map(|e| (e.id, e)).collect();
+
+        // Events waiting on a stuck event are stuck too, so repeat until
+        // nothing new turns up
+        let mut stuck: HashSet<NarrativeEventId> = HashSet::new();
+        loop {
+            let is_dead = |trigger: &NarrativeTriggerType| match trigger {
+                NarrativeTriggerType::NpcAction { npc_id, .. } => !character_ids.contains(npc_id),
+                NarrativeTriggerType::PlayerEntersLocation { location_id, .. }
+                | NarrativeTriggerType::TimeAtLocation { location_id, .. } => {
+                    !location_ids.contains(location_id)
+                }
+                NarrativeTriggerType::DialogueTopic {
+                    with_npc: Some(id), ..
+                }
+                | NarrativeTriggerType::StatThreshold {
+                    character_id: id, ..
+                }
+                | NarrativeTriggerType::CombatResult {
+                    involved_npc: Some(id),
+                    ..
+                } => !character_ids.contains(id),
+                NarrativeTriggerType::RelationshipThreshold {
+                    character_id,
+                    with_character,
+                    ..
+                } => {
+                    !character_ids.contains(character_id) || !character_ids.contains(with_character)
+                }
+                NarrativeTriggerType::ChallengeCompleted {
+                    challenge_id,
+                    requires_success,
+                    ..
+                } => match challenge_by_id.get(challenge_id) {
+                    None => true,
+                    Some(challenge) if challenge.active => false,
+                    // Switched off without ever being attempted, or attempted
+                    // without the result the trigger needs
+                    Some(_) => {
+                        !resolved.contains(challenge_id)
+                            || requires_success.is_some_and(|success| {
+                                let attempts: Vec<_> = resolutions
+                                    .iter()
+                                    .filter(|r| r.challenge_id == *challenge_id)
+                                    .collect();
+                                !attempts.is_empty()
+                                    && attempts.iter().all(|r| r.outcome.is_success() != success)
+                            })
+                    }
+                },
+                NarrativeTriggerType::EventCompleted {
+                    event_id,
+                    outcome_name,
+                    ..
+                } => match event_by_id.get(event_id) {
+                    None => true,
+                    Some(_) if stuck.contains(event_id) => true,
+                    Some(event) if event.is_triggered => {
+                        !event.is_repeatable
+                            && outcome_name.as_ref().is_some_and(|wanted| {
+                                event.selected_outcome.as_ref() != Some(wanted)
+                            })
+                    }
+                    // Retired before it ever fired
+                    Some(event) => !event.is_active,
+                },
+                NarrativeTriggerType::TurnCount {
+                    since_event: Some(event_id),
+                    ..
+                } => !event_by_id.contains_key(event_id) || stuck.contains(event_id),
+                _ => false,
+            };
+
+            let newly_stuck: Vec<NarrativeEventId> = events
+                .iter()
+                .filter(|e| e.is_active && !e.is_triggered && !stuck.contains(&e.id))
+                .filter(|e| !e.can_still_trigger(is_dead))
+                .map(|e| e.id)
+                .collect();
+            if newly_stuck.is_empty() {
+                let mut report: Vec<StuckEvent> = events
+                    .iter()
+                    .filter(|e| stuck.contains(&e.id))
+                    .map(|e| StuckEvent {
+                        event_id: e.id,
+                        name: e.name.clone(),
+                        dead_triggers: e
+                            .trigger_conditions
+                            .iter()
+                            .filter(|t| is_dead(&t.trigger_type))
+                            .map(|t| t.description.clone())
+                            .collect(),
+                        suggestion:
+                            "Replace the triggers that can no longer fire, or trigger it by hand"
+                                .to_string(),
+                    })
+                    .collect();
+                report.sort_by(|a, b| a.name.cmp(&b.name));
+
+                return Ok(UnusedContentReport {
+                    world_id,
+                    challenges: unused_challenges,
+                    npcs: unmet,
+                    lore: undiscovered,
+                    events: report,
+                });
+            }
+            stuck.extend(newly_stuck);
+        }
+    }
+}
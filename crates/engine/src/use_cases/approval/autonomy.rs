@@ -0,0 +1,57 @@
+//! NPC autonomy.
+//!
+//! Lets routine NPC responses skip the approval queue according to the
+//! world's autonomy settings, so the DM only sees what needs a decision.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    ApprovalRequestData, CharacterId, DmApprovalDecision, NpcAutonomyLevel, WorldId,
+};
+
+use crate::entities::{Settings, SettingsError};
+
+pub struct NpcAutonomy {
+    settings: Arc<Settings>,
+}
+
+impl NpcAutonomy {
+    pub fn new(settings: Arc<Settings>) -> Self {
+        Self { settings }
+    }
+
+    /// The decision to apply without the DM, if the NPC may act on its own.
+    ///
+    /// Settings that can't be loaded leave the approval to the DM.
+    pub async fn auto_decision(
+        &self,
+        approval: &ApprovalRequestData,
+    ) -> Option<DmApprovalDecision> {
+        match self.settings.get_for_world(approval.world_id).await {
+            Ok(settings) => settings.npc_autonomy.auto_decision(approval),
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    world_id = %approval.world_id,
+                    "Failed to load NPC autonomy settings, leaving approval to the DM"
+                );
+                None
+            }
+        }
+    }
+
+    /// Set an NPC's own autonomy level, or clear it with `None`.
+    ///
+    /// Returns the level the NPC now acts with.
+    pub async fn set_npc_level(
+        &self,
+        world_id: WorldId,
+        npc_id: CharacterId,
+        level: Option<NpcAutonomyLevel>,
+    ) -> Result<NpcAutonomyLevel, SettingsError> {
+        let mut settings = self.settings.get_for_world(world_id).await?;
+        settings.npc_autonomy.set_npc_level(npc_id, level);
+        let settings = self.settings.update_for_world(world_id, settings).await?;
+        Ok(settings.npc_autonomy.level_for(Some(npc_id)))
+    }
+}
@@ -0,0 +1,80 @@
+//! Bulk approval selection.
+//!
+//! Finds the pending approvals a DM's bulk decision covers: a hand-picked
+//! set, a whole conversation, everything raised by one player action, or
+//! all ambient approvals no player is waiting on.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{ApprovalRequestData, ApprovalUrgency, WorldId};
+
+use crate::infrastructure::ports::{QueueError, QueueItemData, QueueItemStatus, QueuePort};
+
+/// How many recent approval items are scanned for a selection.
+const PENDING_SCAN_LIMIT: usize = 500;
+
+/// Which pending approvals a bulk decision applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApprovalSelection {
+    Requests(Vec<Uuid>),
+    Conversation(Uuid),
+    SourceAction(Uuid),
+    /// Approvals no player is waiting on
+    Ambient,
+}
+
+impl ApprovalSelection {
+    fn matches(&self, id: Uuid, approval: &ApprovalRequestData) -> bool {
+        match self {
+            ApprovalSelection::Requests(ids) => ids.contains(&id),
+            ApprovalSelection::Conversation(conversation_id) => {
+                approval.conversation_id == Some(*conversation_id)
+            }
+            ApprovalSelection::SourceAction(action_id) => approval.source_action_id == *action_id,
+            ApprovalSelection::Ambient => approval.urgency == ApprovalUrgency::Normal,
+        }
+    }
+}
+
+pub struct SelectApprovals {
+    queue: Arc<dyn QueuePort>,
+}
+
+impl SelectApprovals {
+    pub fn new(queue: Arc<dyn QueuePort>) -> Self {
+        Self { queue }
+    }
+
+    /// IDs of the world's undecided approvals in the selection, oldest first.
+    pub async fn execute(
+        &self,
+        world_id: WorldId,
+        selection: &ApprovalSelection,
+    ) -> Result<Vec<Uuid>, QueueError> {
+        let items = self
+            .queue
+            .list_by_type("dm_approval", PENDING_SCAN_LIMIT)
+            .await?;
+        let mut selected: Vec<Uuid> = items
+            .into_iter()
+            .filter(|item| {
+                matches!(
+                    item.status,
+                    QueueItemStatus::Pending | QueueItemStatus::Processing
+                )
+            })
+            .filter(|item| {
+                matches!(
+                    &item.data,
+                    QueueItemData::DmApproval(data)
+                        if data.world_id == world_id && selection.matches(item.id, data)
+                )
+            })
+            .map(|item| item.id)
+            .collect();
+        // Listed newest first; decide in the order the approvals came in
+        selected.reverse();
+        Ok(selected)
+    }
+}
@@ -0,0 +1,303 @@
+//! AI co-DM for solo play.
+//!
+//! When a world has co-DM mode on, approvals are put to an LLM instead of
+//! waiting for a DM. The world's guardrails decide what the co-DM may do;
+//! anything else stays in the approval queue. Each review is stored on its
+//! approval item so a DM can audit the co-DM later.
+
+use std::sync::Arc;
+
+use serde_json::Value;
+use uuid::Uuid;
+use wrldbldr_domain::{
+    AppSettings, ApprovalDecisionType, ApprovalRequestData, CoDmEscalation, CoDmOutcome,
+    CoDmReview, CoDmVerdict, DmApprovalDecision, LlmTask, WorldId,
+};
+
+use crate::entities::{Settings, World};
+use crate::infrastructure::ports::{
+    ChatMessage, ClockPort, LlmPort, LlmRequest, QueueError, QueueItemData, QueuePort,
+};
+use crate::use_cases::ai::structured::generate_validated;
+
+/// How many recent approval items are scanned when listing reviews.
+const REVIEW_SCAN_LIMIT: usize = 500;
+
+/// Most reviews returned at once.
+const MAX_CO_DM_REVIEWS: usize = 100;
+
+/// A co-DM review and the decision to apply for it.
+#[derive(Debug)]
+pub struct CoDmResolution {
+    pub review: CoDmReview,
+    /// None when the review escalated the approval to a DM
+    pub decision: Option<DmApprovalDecision>,
+}
+
+pub struct CoDm {
+    queue: Arc<dyn QueuePort>,
+    llm: Arc<dyn LlmPort>,
+    world: Arc<World>,
+    settings: Arc<Settings>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl CoDm {
+    pub fn new(
+        queue: Arc<dyn QueuePort>,
+        llm: Arc<dyn LlmPort>,
+        world: Arc<World>,
+        settings: Arc<Settings>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            queue,
+            llm,
+            world,
+            settings,
+            clock,
+        }
+    }
+
+    /// Review an approval with the co-DM, recording the review on the item.
+    ///
+    /// Returns `None` when the world has no co-DM or the approval isn't an
+    /// NPC response, i.e. when a DM should see it without a review.
+    pub async fn review(
+        &self,
+        approval_id: Uuid,
+        approval: &ApprovalRequestData,
+    ) -> Result<Option<CoDmResolution>, CoDmError> {
+        if approval.decision_type != ApprovalDecisionType::NpcResponse {
+            return Ok(None);
+        }
+        let settings = match self.settings.get_for_world(approval.world_id).await {
+            Ok(settings) => settings,
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    world_id = %approval.world_id,
+                    "Failed to load co-DM settings, leaving approval to the DM"
+                );
+                return Ok(None);
+            }
+        };
+        let co_dm = &settings.co_dm;
+        if !co_dm.enabled {
+            return Ok(None);
+        }
+
+        let (outcome, reasoning) = match co_dm.escalation_for(approval) {
+            Some(reason) => (CoDmOutcome::Escalated { reason }, String::new()),
+            None => match self.ask(approval, &settings).await {
+                Ok((verdict, reasoning)) => (co_dm.outcome(approval, verdict), reasoning),
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        approval_id = %approval_id,
+                        "Co-DM gave no usable decision, leaving approval to the DM"
+                    );
+                    (
+                        CoDmOutcome::Escalated {
+                            reason: CoDmEscalation::Unavailable,
+                        },
+                        String::new(),
+                    )
+                }
+            },
+        };
+
+        let decision = outcome.decision(approval);
+        let review = CoDmReview::new(approval_id, approval, outcome, reasoning, self.clock.now());
+        let json =
+            serde_json::to_string(&review).map_err(|e| CoDmError::Serialization(e.to_string()))?;
+        self.queue.set_result_json(approval_id, &json).await?;
+
+        Ok(Some(CoDmResolution { review, decision }))
+    }
+
+    /// The world's co-DM reviews, newest first.
+    pub async fn reviews(
+        &self,
+        world_id: WorldId,
+        limit: Option<usize>,
+    ) -> Result<Vec<CoDmReview>, CoDmError> {
+        let limit = limit.unwrap_or(MAX_CO_DM_REVIEWS).min(MAX_CO_DM_REVIEWS);
+        let items = self
+            .queue
+            .list_by_type("dm_approval", REVIEW_SCAN_LIMIT)
+            .await?;
+        Ok(items
+            .into_iter()
+            .filter(|item| {
+                matches!(&item.data, QueueItemData::DmApproval(data) if data.world_id == world_id)
+            })
+            .filter_map(|item| serde_json::from_str::<CoDmReview>(item.result_json.as_deref()?).ok())
+            .take(limit)
+            .collect())
+    }
+
+    /// Put the approval to the LLM, returning its verdict and reasoning.
+    async fn ask(
+        &self,
+        approval: &ApprovalRequestData,
+        settings: &AppSettings,
+    ) -> Result<(CoDmVerdict, String), CoDmError> {
+        let safety_constraints = match self.world.get(approval.world_id).await {
+            Ok(Some(world)) => world.content_safety.prompt_constraints(),
+            Ok(None) => String::new(),
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load content safety settings for the co-DM");
+                String::new()
+            }
+        };
+
+        let mut system_prompt = String::from(
+            "You are the co-DM of a solo tabletop RPG session, standing in for the game master. \
+             Review the NPC's proposed reply to the player. Accept it if it is in character, \
+             consistent and fun; modify it if a small rewrite makes it better; reject it only \
+             if it should never reach the player. Only approve tool calls that clearly fit \
+             what the NPC says. Respond with a JSON object.",
+        );
+        if let Some(instructions) = settings
+            .co_dm
+            .instructions
+            .as_deref()
+            .map(str::trim)
+            .filter(|i| !i.is_empty())
+        {
+            system_prompt.push_str("\n\nThe table's instructions for you: ");
+            system_prompt.push_str(instructions);
+        }
+        if !safety_constraints.is_empty() {
+            system_prompt.push_str("\n\n");
+            system_prompt.push_str(&safety_constraints);
+        }
+
+        let request = LlmRequest::new(vec![ChatMessage::user(review_message(approval))])
+            .with_system_prompt(system_prompt)
+            .with_temperature(0.2)
+            .with_model(settings.model_for(LlmTask::Classification))
+            .with_world(approval.world_id);
+
+        generate_validated(
+            self.llm.as_ref(),
+            request,
+            review_schema(),
+            parse_review_reply,
+        )
+        .await
+        .map_err(|e| CoDmError::Llm(e.to_string()))
+    }
+}
+
+/// The approval as the co-DM sees it
+fn review_message(approval: &ApprovalRequestData) -> String {
+    let mut message = String::new();
+    if let Some(player) = approval.player_dialogue.as_deref() {
+        message.push_str(&format!("The player says: \"{}\"\n", player));
+    }
+    message.push_str(&format!(
+        "{} proposes to reply: \"{}\"\n",
+        approval.npc_name, approval.proposed_dialogue
+    ));
+    if !approval.proposed_tools.is_empty() {
+        message.push_str("Proposed tool calls:\n");
+        for tool in &approval.proposed_tools {
+            message.push_str(&format!(
+                "- id {}: {} ({})\n",
+                tool.id, tool.name, tool.description
+            ));
+        }
+    }
+    message
+}
+
+fn review_schema() -> Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "decision": { "type": "string", "enum": ["accept", "modify", "reject"] },
+            "dialogue": { "type": "string" },
+            "approved_tools": { "type": "array", "items": { "type": "string" } },
+            "reasoning": { "type": "string" }
+        },
+        "required": ["decision", "reasoning"]
+    })
+}
+
+fn parse_review_reply(value: &Value) -> Result<(CoDmVerdict, String), String> {
+    let approved_tools: Vec<String> = value
+        .get("approved_tools")
+        .and_then(Value::as_array)
+        .map(|tools| {
+            tools
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    let reasoning = value
+        .get("reasoning")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+
+    let verdict = match value.get("decision").and_then(Value::as_str) {
+        Some("accept") => CoDmVerdict::Accept { approved_tools },
+        Some("modify") => {
+            let dialogue = value
+                .get("dialogue")
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|d| !d.is_empty())
+                .ok_or("a \"modify\" decision needs the rewritten \"dialogue\"")?;
+            CoDmVerdict::Modify {
+                dialogue: dialogue.to_string(),
+                approved_tools,
+            }
+        }
+        Some("reject") => CoDmVerdict::Reject,
+        other => return Err(format!("unknown decision {:?}", other)),
+    };
+    Ok((verdict, reasoning))
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CoDmError {
+    #[error("Queue error: {0}")]
+    Queue(#[from] QueueError),
+    #[error("LLM error: {0}")]
+    Llm(String),
+    #[error("Serialization error: {0}")]
+    Serialization(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modify_without_dialogue_is_an_invalid_reply() {
+        let reply = serde_json::json!({ "decision": "modify", "reasoning": "tighter" });
+        assert!(parse_review_reply(&reply).is_err());
+
+        let reply = serde_json::json!({
+            "decision": "modify",
+            "dialogue": " Rain by nightfall. ",
+            "approved_tools": ["call-1"],
+            "reasoning": "tighter"
+        });
+        let (verdict, reasoning) = parse_review_reply(&reply).unwrap();
+        assert_eq!(
+            verdict,
+            CoDmVerdict::Modify {
+                dialogue: "Rain by nightfall.".to_string(),
+                approved_tools: vec!["call-1".to_string()],
+            }
+        );
+        assert_eq!(reasoning, "tighter");
+    }
+}
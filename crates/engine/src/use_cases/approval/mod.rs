@@ -4,19 +4,42 @@
 //! - NPC staging (who appears in a region)
 //! - LLM suggestions (NPC dialogue, tool calls)
 //! - Challenge outcomes
+//! - Bulk decisions over a selection of pending approvals
+//! - Regenerating NPC responses the DM rejected with feedback
+//!
+//! Worlds in co-DM mode have NPC responses reviewed by an LLM instead
+//! (see [`CoDm`]), and NPCs with enough autonomy skip approval for routine
+//! responses (see [`NpcAutonomy`]).
+
+mod autonomy;
+mod bulk;
+mod co_dm;
+mod retry;
 
 use std::sync::Arc;
 use uuid::Uuid;
-use wrldbldr_domain::{CharacterId, DmApprovalDecision, RegionId, WorldId};
+use wrldbldr_domain::{
+    CharacterId, DmApprovalDecision, PlayerCharacterId, RegionId, StoryEventId, WorldId,
+};
 
 use crate::entities::Staging;
 use crate::infrastructure::ports::{QueuePort, RepoError};
+use crate::use_cases::narrative::{EffectExecutionContext, EffectExecutionResult};
+use crate::use_cases::plugins::{is_plugin_tool, PluginTools};
+
+pub use autonomy::NpcAutonomy;
+pub use bulk::{ApprovalSelection, SelectApprovals};
+pub use co_dm::{CoDm, CoDmResolution};
+pub use retry::RetryRejectedResponse;
 
 /// Container for approval use cases.
 pub struct ApprovalUseCases {
     pub approve_staging: Arc<ApproveStaging>,
     pub approve_suggestion: Arc<ApproveSuggestion>,
     pub decision_flow: Arc<ApprovalDecisionFlow>,
+    pub co_dm: Arc<CoDm>,
+    pub autonomy: Arc<NpcAutonomy>,
+    pub select: Arc<SelectApprovals>,
 }
 
 impl ApprovalUseCases {
@@ -24,11 +47,17 @@ impl ApprovalUseCases {
         approve_staging: Arc<ApproveStaging>,
         approve_suggestion: Arc<ApproveSuggestion>,
         decision_flow: Arc<ApprovalDecisionFlow>,
+        co_dm: Arc<CoDm>,
+        autonomy: Arc<NpcAutonomy>,
+        select: Arc<SelectApprovals>,
     ) -> Self {
         Self {
             approve_staging,
             approve_suggestion,
             decision_flow,
+            co_dm,
+            autonomy,
+            select,
         }
     }
 }
@@ -200,11 +229,14 @@ impl ApproveSuggestion {
     }
 }
 
-/// Full approval decision flow (approval + dialogue persistence).
+/// Full approval decision flow (approval + dialogue persistence + approved
+/// plugin tools).
 pub struct ApprovalDecisionFlow {
     approve_suggestion: Arc<ApproveSuggestion>,
     narrative: Arc<crate::entities::Narrative>,
     queue: Arc<dyn QueuePort>,
+    plugin_tools: Arc<PluginTools>,
+    retry: Arc<RetryRejectedResponse>,
 }
 
 impl ApprovalDecisionFlow {
@@ -212,11 +244,15 @@ impl ApprovalDecisionFlow {
         approve_suggestion: Arc<ApproveSuggestion>,
         narrative: Arc<crate::entities::Narrative>,
         queue: Arc<dyn QueuePort>,
+        plugin_tools: Arc<PluginTools>,
+        retry: Arc<RetryRejectedResponse>,
     ) -> Self {
         Self {
             approve_suggestion,
             narrative,
             queue,
+            plugin_tools,
+            retry,
         }
     }
 
@@ -232,19 +268,50 @@ impl ApprovalDecisionFlow {
             .map_err(|e| ApprovalDecisionError::QueueError(e.to_string()))?
             .ok_or(ApprovalDecisionError::ApprovalNotFound)?;
 
+        let rejection_feedback = match &decision {
+            DmApprovalDecision::Reject { feedback } => Some(feedback.clone()),
+            _ => None,
+        };
+
         let result = self
             .approve_suggestion
             .execute(approval_id, decision)
             .await
             .map_err(ApprovalDecisionError::Approval)?;
 
+        // A rejection with feedback asks the NPC to try again
+        let mut retry_action_id = None;
+        if let Some(feedback) = rejection_feedback {
+            match self.retry.execute(&approval_data, &feedback).await {
+                Ok(Some(request)) => retry_action_id = Some(request.callback_id),
+                Ok(None) => {}
+                Err(e) => tracing::error!(error = %e, "Failed to queue NPC response retry"),
+            }
+        }
+
+        let mut dialogue_event_ids = Vec::new();
         if result.approved {
             let dialogue = result.final_dialogue.clone().unwrap_or_default();
             if !dialogue.is_empty() {
-                if let (Some(pc_id), Some(npc_id)) = (approval_data.pc_id, approval_data.npc_id) {
-                    let player_dialogue =
-                        approval_data.player_dialogue.clone().unwrap_or_default();
-                    if let Err(e) = self
+                // A reply to merged lines goes in each speaker's history,
+                // against what they said themselves
+                let exchanges: Vec<_> = if approval_data.merged_lines.is_empty() {
+                    vec![(
+                        approval_data.pc_id,
+                        approval_data.player_dialogue.clone().unwrap_or_default(),
+                    )]
+                } else {
+                    approval_data
+                        .merged_lines
+                        .iter()
+                        .map(|line| (line.pc_id, line.dialogue.clone()))
+                        .collect()
+                };
+                for (pc_id, player_dialogue) in exchanges {
+                    let (Some(pc_id), Some(npc_id)) = (pc_id, approval_data.npc_id) else {
+                        continue;
+                    };
+                    match self
                         .narrative
                         .record_dialogue_exchange(
                             approval_data.world_id,
@@ -252,7 +319,7 @@ impl ApprovalDecisionFlow {
                             npc_id,
                             approval_data.npc_name.clone(),
                             player_dialogue,
-                            dialogue,
+                            dialogue.clone(),
                             approval_data.topics.clone(),
                             approval_data.scene_id,
                             approval_data.location_id,
@@ -260,32 +327,82 @@ impl ApprovalDecisionFlow {
                         )
                         .await
                     {
-                        tracing::error!(error = %e, "Failed to record dialogue exchange");
+                        Ok(event_id) => dialogue_event_ids.push(event_id),
+                        Err(e) => {
+                            tracing::error!(error = %e, "Failed to record dialogue exchange");
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut tool_results = Vec::new();
+        if result.approved {
+            let approved_plugin_tools = approval_data.proposed_tools.iter().filter(|tool| {
+                is_plugin_tool(&tool.name) && result.approved_tools.contains(&tool.id)
+            });
+            for tool in approved_plugin_tools {
+                let Some(pc_id) = approval_data.pc_id else {
+                    tracing::warn!(
+                        tool = %tool.name,
+                        "Plugin tool approved without a player character"
+                    );
+                    continue;
+                };
+                let context = EffectExecutionContext {
+                    pc_id,
+                    world_id: approval_data.world_id,
+                    current_scene_id: approval_data.scene_id,
+                };
+                match self.plugin_tools.invoke(tool, &context).await {
+                    Ok(tool_result) => {
+                        if !tool_result.success {
+                            tracing::warn!(
+                                tool = %tool.name,
+                                error = ?tool_result.error,
+                                "Approved plugin tool failed"
+                            );
+                        }
+                        tool_results.push(tool_result);
                     }
+                    Err(e) => tracing::error!(error = %e, "Failed to run approved plugin tool"),
                 }
             }
         }
 
         Ok(ApprovalDecisionOutcome {
             world_id: approval_data.world_id,
+            pc_id: approval_data.pc_id,
             approved: result.approved,
             final_dialogue: result.final_dialogue,
             approved_tools: result.approved_tools,
             npc_id: result.npc_id,
             npc_name: result.npc_name,
             conversation_id: result.conversation_id,
+            dialogue_event_ids,
+            tool_results,
+            retry_action_id,
         })
     }
 }
 
 pub struct ApprovalDecisionOutcome {
     pub world_id: WorldId,
+    /// The PC the NPC is answering, whose scene hears the response
+    pub pc_id: Option<PlayerCharacterId>,
     pub approved: bool,
     pub final_dialogue: Option<String>,
     pub approved_tools: Vec<String>,
     pub npc_id: Option<String>,
     pub npc_name: Option<String>,
     pub conversation_id: Option<Uuid>,
+    /// The story events the approved dialogue was recorded as, one per
+    /// speaker answered
+    pub dialogue_event_ids: Vec<StoryEventId>,
+    /// Results of the approved plugin tools that were run
+    pub tool_results: Vec<EffectExecutionResult>,
+    /// Player action the NPC is answering again after a rejection
+    pub retry_action_id: Option<String>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,79 @@
+//! Regenerating rejected NPC responses.
+//!
+//! When the DM rejects an NPC response with feedback, the original LLM
+//! request is queued again with the feedback added to the directorial notes,
+//! so the player gets a new answer rather than silence.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{ApprovalDecisionType, ApprovalRequestData, LlmRequestData};
+
+use crate::infrastructure::ports::{QueueError, QueueItemData, QueuePort};
+
+/// How many times one NPC response is regenerated before the DM's rejection
+/// stands.
+pub const MAX_REJECTION_RETRIES: u32 = 3;
+
+/// How many recent LLM requests are scanned for the one to retry.
+const REQUEST_SCAN_LIMIT: usize = 200;
+
+pub struct RetryRejectedResponse {
+    queue: Arc<dyn QueuePort>,
+}
+
+impl RetryRejectedResponse {
+    pub fn new(queue: Arc<dyn QueuePort>) -> Self {
+        Self { queue }
+    }
+
+    /// Queue a new attempt at a rejected NPC response.
+    ///
+    /// Returns the retry request, or `None` when there's nothing to retry:
+    /// no feedback was given, the approval isn't an NPC response, retries
+    /// are used up, or the original request is no longer in the queue.
+    pub async fn execute(
+        &self,
+        approval: &ApprovalRequestData,
+        feedback: &str,
+    ) -> Result<Option<LlmRequestData>, QueueError> {
+        let feedback = feedback.trim();
+        if feedback.is_empty()
+            || approval.decision_type != ApprovalDecisionType::NpcResponse
+            || approval.retry_count >= MAX_REJECTION_RETRIES
+        {
+            return Ok(None);
+        }
+
+        // NPC response approvals are raised by the LLM request they answer
+        let original = self
+            .queue
+            .list_by_type("llm_request", REQUEST_SCAN_LIMIT)
+            .await?
+            .into_iter()
+            .find(|item| item.id == approval.source_action_id)
+            .and_then(|item| match item.data {
+                QueueItemData::LlmRequest(data) => Some(data),
+                _ => None,
+            });
+        let Some(mut request) = original else {
+            tracing::warn!(
+                approval_source = %approval.source_action_id,
+                "Rejected NPC response has no LLM request to retry"
+            );
+            return Ok(None);
+        };
+        let Some(prompt) = request.prompt.as_mut() else {
+            return Ok(None);
+        };
+
+        prompt.directorial_notes.push_str(&format!(
+            "\n\nThe DM rejected this earlier response: \"{}\"\nDM feedback: {}\n\
+            Respond again, following the feedback.",
+            approval.proposed_dialogue, feedback
+        ));
+        request.retry_count = approval.retry_count + 1;
+
+        self.queue.enqueue_llm_request(&request).await?;
+        Ok(Some(request))
+    }
+}
@@ -181,6 +181,10 @@ impl GenerateExpressionSheet {
                 entity_id: request.character_id.to_string(),
                 workflow_id: request.workflow,
                 prompt,
+                negative_prompt: None,
+                dimensions: None,
+                // Keep the expressions on-model
+                reference_image: character.portrait_asset.clone(),
                 count: 1, // Expression sheet is a single image
             })
             .await
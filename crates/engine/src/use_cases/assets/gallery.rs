@@ -0,0 +1,243 @@
+//! Gallery management use cases.
+//!
+//! The world-wide view of every character, location and item asset, with
+//! filters, favorites and bulk tagging so a DM can sort through a few
+//! hundred generated images without opening each entity.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    normalize_tag, AssetId, GalleryAsset, GalleryFilter, WorldId, MAX_GALLERY_BULK,
+};
+use wrldbldr_protocol::GalleryAssetData;
+
+use crate::entities::Assets;
+use crate::infrastructure::ports::RepoError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GalleryError {
+    #[error("Asset not found: {0}")]
+    NotFound(AssetId),
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<wrldbldr_domain::DomainError> for GalleryError {
+    fn from(e: wrldbldr_domain::DomainError) -> Self {
+        GalleryError::Invalid(e.to_string())
+    }
+}
+
+pub struct ManageGallery {
+    assets: Arc<Assets>,
+}
+
+impl ManageGallery {
+    pub fn new(assets: Arc<Assets>) -> Self {
+        Self { assets }
+    }
+
+    /// The world's assets that match `filter`, newest first
+    pub async fn list(
+        &self,
+        world_id: WorldId,
+        mut filter: GalleryFilter,
+    ) -> Result<Vec<GalleryAssetData>, GalleryError> {
+        filter.tag = filter.tag.as_deref().map(normalize_tag).transpose()?;
+
+        let assets = self.assets.list_for_world(world_id).await?;
+        Ok(assets
+            .iter()
+            .filter(|a| filter.matches(a))
+            .map(gallery_asset_to_protocol)
+            .collect())
+    }
+
+    /// Star or unstar assets; returns the updated assets
+    pub async fn set_favorite(
+        &self,
+        world_id: WorldId,
+        asset_ids: &[AssetId],
+        favorite: bool,
+    ) -> Result<Vec<GalleryAssetData>, GalleryError> {
+        let mut assets = self.world_assets(world_id, asset_ids).await?;
+        for asset in &mut assets {
+            if asset.is_favorite != favorite {
+                asset.set_favorite(favorite);
+                self.assets.save(asset).await?;
+            }
+        }
+        Ok(assets.iter().map(gallery_asset_to_protocol).collect())
+    }
+
+    /// Add and remove tags on assets; returns the updated assets.
+    ///
+    /// Every asset is checked before any is saved, so an invalid tag or an
+    /// asset that would go over the tag limit leaves all of them unchanged.
+    pub async fn tag(
+        &self,
+        world_id: WorldId,
+        asset_ids: &[AssetId],
+        add: &[String],
+        remove: &[String],
+    ) -> Result<Vec<GalleryAssetData>, GalleryError> {
+        let mut assets = self.world_assets(world_id, asset_ids).await?;
+        let mut changed = Vec::with_capacity(assets.len());
+        for asset in &mut assets {
+            changed.push(asset.retag(add, remove)?);
+        }
+        for (asset, changed) in assets.iter().zip(changed) {
+            if changed {
+                self.assets.save(asset).await?;
+            }
+        }
+        Ok(assets.iter().map(gallery_asset_to_protocol).collect())
+    }
+
+    /// The requested assets, in request order, if they all belong to the world
+    async fn world_assets(
+        &self,
+        world_id: WorldId,
+        asset_ids: &[AssetId],
+    ) -> Result<Vec<GalleryAsset>, GalleryError> {
+        if asset_ids.is_empty() {
+            return Err(GalleryError::Invalid("No assets selected".to_string()));
+        }
+        if asset_ids.len() > MAX_GALLERY_BULK {
+            return Err(GalleryError::Invalid(format!(
+                "At most {} assets can be changed at once, got {}",
+                MAX_GALLERY_BULK,
+                asset_ids.len()
+            )));
+        }
+
+        let mut in_world: HashMap<AssetId, GalleryAsset> = self
+            .assets
+            .list_for_world(world_id)
+            .await?
+            .into_iter()
+            .map(|a| (a.id, a))
+            .collect();
+        let mut selected = Vec::with_capacity(asset_ids.len());
+        for id in asset_ids {
+            // Duplicate IDs were already taken; skip them rather than fail
+            if selected.iter().any(|a: &GalleryAsset| a.id == *id) {
+                continue;
+            }
+            selected.push(in_world.remove(id).ok_or(GalleryError::NotFound(*id))?);
+        }
+        Ok(selected)
+    }
+}
+
+pub(crate) fn gallery_asset_to_protocol(asset: &GalleryAsset) -> GalleryAssetData {
+    GalleryAssetData {
+        id: asset.id.to_string(),
+        entity_type: asset.entity_type,
+        entity_id: asset.entity_id.clone(),
+        asset_type: asset.asset_type.as_str().to_string(),
+        file_path: asset.file_path.clone(),
+        label: asset.label.clone(),
+        is_active: asset.is_active,
+        is_favorite: asset.is_favorite,
+        tags: asset.tags.clone(),
+        batch_id: asset.batch_id().map(|id| id.to_string()),
+        created_at: asset.created_at.to_rfc3339(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::memory::OfflineServices;
+    use crate::infrastructure::ports::MockAssetRepo;
+    use wrldbldr_domain::{AssetType, EntityType};
+
+    fn asset(entity_type: EntityType, asset_type: AssetType) -> GalleryAsset {
+        GalleryAsset::new(
+            entity_type,
+            uuid::Uuid::new_v4().to_string(),
+            asset_type,
+            "assets/image.png",
+            chrono::Utc::now(),
+        )
+    }
+
+    fn gallery(repo: MockAssetRepo) -> ManageGallery {
+        ManageGallery::new(Arc::new(Assets::new(
+            Arc::new(repo),
+            Arc::new(OfflineServices),
+        )))
+    }
+
+    #[tokio::test]
+    async fn list_applies_the_filter_with_a_normalized_tag() {
+        let mut tagged = asset(EntityType::Location, AssetType::Backdrop);
+        tagged.tags = vec!["winter-camp".to_string()];
+        let untagged = asset(EntityType::Location, AssetType::Backdrop);
+        let tagged_id = tagged.id.to_string();
+
+        let mut repo = MockAssetRepo::new();
+        repo.expect_list_for_world()
+            .returning(move |_| Ok(vec![tagged.clone(), untagged.clone()]));
+
+        let listed = gallery(repo)
+            .list(
+                WorldId::new(),
+                GalleryFilter {
+                    tag: Some("Winter Camp".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("list");
+
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, tagged_id);
+    }
+
+    #[tokio::test]
+    async fn bulk_tagging_saves_nothing_when_one_asset_is_outside_the_world() {
+        let portrait = asset(EntityType::Character, AssetType::Portrait);
+        let elsewhere = AssetId::new();
+        let ids = vec![portrait.id, elsewhere];
+
+        let mut repo = MockAssetRepo::new();
+        repo.expect_list_for_world()
+            .returning(move |_| Ok(vec![portrait.clone()]));
+        repo.expect_save().never();
+
+        let result = gallery(repo)
+            .tag(WorldId::new(), &ids, &["villain".to_string()], &[])
+            .await;
+
+        assert!(matches!(result, Err(GalleryError::NotFound(id)) if id == elsewhere));
+    }
+
+    #[tokio::test]
+    async fn favoriting_only_saves_assets_that_change() {
+        let mut starred = asset(EntityType::Item, AssetType::ItemIcon);
+        starred.set_favorite(true);
+        let plain = asset(EntityType::Item, AssetType::ItemIcon);
+        let plain_id = plain.id;
+        let ids = vec![starred.id, plain.id];
+
+        let mut repo = MockAssetRepo::new();
+        repo.expect_list_for_world()
+            .returning(move |_| Ok(vec![starred.clone(), plain.clone()]));
+        repo.expect_save()
+            .withf(move |a| a.id == plain_id && a.is_favorite)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let updated = gallery(repo)
+            .set_favorite(WorldId::new(), &ids, true)
+            .await
+            .expect("favorite");
+
+        assert!(updated.iter().all(|a| a.is_favorite));
+    }
+}
@@ -3,36 +3,59 @@
 //! Handles image generation for game entities (characters, locations, items).
 
 pub mod expression_sheet;
+pub mod gallery;
+pub mod region_map;
+pub mod storage;
+pub mod upload;
 
 use std::sync::Arc;
 use uuid::Uuid;
 use wrldbldr_domain::{
-    AssetGenerationData, AssetId, AssetType, BatchId, EntityType, GalleryAsset, GenerationMetadata,
-    WorldId,
+    AppSettings, AssetGenerationData, AssetId, AssetType, BatchId, EntityType, GalleryAsset,
+    GenerationMetadata, WorldId,
 };
 
-use crate::entities::Assets;
-use crate::infrastructure::ports::{ClockPort, ImageGenError, ImageRequest, QueuePort, RepoError};
+use crate::entities::{Assets, Settings, SettingsError};
+use crate::infrastructure::ports::{
+    BlobStorePort, ClockPort, ImageGenError, ImageRequest, QueuePort, RepoError, UsageDelta,
+    UsageRepo,
+};
 
 pub use expression_sheet::{
     ExpressionSheetError, ExpressionSheetRequest, ExpressionSheetResult, GenerateExpressionSheet,
     SlicedExpression, STANDARD_EXPRESSION_ORDER,
 };
+pub use gallery::{GalleryError, ManageGallery};
+pub use region_map::{GenerateRegionMap, RegionMapKind};
+pub use storage::{AssetStorage, AssetStorageError, SignedAssetUrl};
+pub use upload::{UploadAsset, UploadError, UploadedImage};
 
 /// Container for asset use cases.
 pub struct AssetUseCases {
     pub generate: Arc<GenerateAsset>,
     pub expression_sheet: Arc<GenerateExpressionSheet>,
+    pub region_map: Arc<GenerateRegionMap>,
+    pub storage: Arc<AssetStorage>,
+    pub upload: Arc<UploadAsset>,
+    pub gallery: Arc<ManageGallery>,
 }
 
 impl AssetUseCases {
     pub fn new(
         generate: Arc<GenerateAsset>,
         expression_sheet: Arc<GenerateExpressionSheet>,
+        region_map: Arc<GenerateRegionMap>,
+        storage: Arc<AssetStorage>,
+        upload: Arc<UploadAsset>,
+        gallery: Arc<ManageGallery>,
     ) -> Self {
         Self {
             generate,
             expression_sheet,
+            region_map,
+            storage,
+            upload,
+            gallery,
         }
     }
 }
@@ -42,61 +65,190 @@ impl AssetUseCases {
 pub struct GenerateResult {
     /// The generated asset ID
     pub asset_id: AssetId,
+    /// Stored path of the image
+    pub file_path: String,
     /// The image data (bytes)
     pub image_data: Vec<u8>,
     /// The image format (e.g., "png")
     pub format: String,
 }
 
+/// What to generate, before the world's generation preset is applied.
+#[derive(Debug, Clone)]
+pub struct GenerationRequest {
+    pub prompt: String,
+    pub negative_prompt: Option<String>,
+    /// ComfyUI workflow to use, unless the preset names one
+    pub workflow: String,
+    /// Preset to use for the asset slot. None = the slot's default preset, if any.
+    pub preset: Option<String>,
+    /// Image to condition on. None = the character's canonical portrait, if any.
+    pub style_reference_id: Option<AssetId>,
+}
+
+/// A generation request with the preset's style applied.
+#[derive(Debug, Clone, PartialEq)]
+struct StyledGeneration {
+    prompt: String,
+    negative_prompt: Option<String>,
+    workflow: String,
+    dimensions: Option<(u32, u32)>,
+}
+
+/// A character's canonical portrait: the active one in their gallery.
+fn canonical_portrait(gallery: &[GalleryAsset]) -> Option<&GalleryAsset> {
+    gallery
+        .iter()
+        .find(|a| a.is_active && a.asset_type == AssetType::Portrait)
+}
+
+/// Apply the world's preset for `asset_type` to `request`.
+fn apply_preset(
+    settings: &AppSettings,
+    asset_type: AssetType,
+    request: GenerationRequest,
+) -> Result<StyledGeneration, GenerateError> {
+    let preset = settings.generation_preset(asset_type, request.preset.as_deref());
+    match (preset, request.preset) {
+        (Some(preset), _) => Ok(StyledGeneration {
+            prompt: preset.styled_prompt(&request.prompt),
+            negative_prompt: preset.styled_negative_prompt(request.negative_prompt.as_deref()),
+            workflow: preset.workflow.clone().unwrap_or(request.workflow),
+            dimensions: Some(preset.dimensions()),
+        }),
+        (None, Some(name)) => Err(GenerateError::UnknownPreset(name)),
+        (None, None) => Ok(StyledGeneration {
+            prompt: request.prompt,
+            negative_prompt: request.negative_prompt,
+            workflow: request.workflow,
+            dimensions: None,
+        }),
+    }
+}
+
 /// Generate asset use case.
 ///
 /// Orchestrates image generation for game entities.
 pub struct GenerateAsset {
     assets: Arc<Assets>,
     queue: Arc<dyn QueuePort>,
+    blobs: Arc<dyn BlobStorePort>,
+    usage: Arc<dyn UsageRepo>,
+    settings: Arc<Settings>,
     clock: Arc<dyn ClockPort>,
 }
 
 impl GenerateAsset {
-    pub fn new(assets: Arc<Assets>, queue: Arc<dyn QueuePort>, clock: Arc<dyn ClockPort>) -> Self {
+    pub fn new(
+        assets: Arc<Assets>,
+        queue: Arc<dyn QueuePort>,
+        blobs: Arc<dyn BlobStorePort>,
+        usage: Arc<dyn UsageRepo>,
+        settings: Arc<Settings>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
         Self {
             assets,
             queue,
+            blobs,
+            usage,
+            settings,
             clock,
         }
     }
 
+    /// Apply the generation preset from `world_id`'s settings (or the
+    /// global settings when there is no world).
+    async fn styled(
+        &self,
+        world_id: Option<WorldId>,
+        asset_type: AssetType,
+        request: GenerationRequest,
+    ) -> Result<StyledGeneration, GenerateError> {
+        let settings = match world_id {
+            Some(world_id) => self.settings.get_for_world(world_id).await?,
+            None => self.settings.get_global().await?,
+        };
+        apply_preset(&settings, asset_type, request)
+    }
+
+    /// The asset a generation should be conditioned on, if any.
+    ///
+    /// An explicitly requested reference wins; otherwise generations for a
+    /// character use their canonical portrait so they keep the same look.
+    async fn reference_asset(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        explicit: Option<AssetId>,
+    ) -> Result<Option<GalleryAsset>, GenerateError> {
+        if let Some(id) = explicit {
+            return Ok(self.assets.get(id).await?);
+        }
+        if entity_type != EntityType::Character {
+            return Ok(None);
+        }
+        let gallery = self
+            .assets
+            .list_for_entity(&entity_type.to_string(), entity_id)
+            .await?;
+        Ok(canonical_portrait(&gallery).cloned())
+    }
+
     /// Generate an image synchronously (blocking until complete).
     ///
     /// # Arguments
+    /// * `world_id` - World the image counts toward for usage accounting
     /// * `entity_type` - Type of entity (Character, Location, Item)
     /// * `entity_id` - ID of the entity
     /// * `asset_type` - Type of asset (Portrait, Sprite, etc.)
-    /// * `prompt` - Generation prompt
-    /// * `workflow` - ComfyUI workflow to use
+    /// * `request` - Prompt, workflow and generation preset
     ///
     /// # Returns
     /// * `Ok(GenerateResult)` - Image generated successfully
     /// * `Err(GenerateError)` - Generation failed
     pub async fn execute(
         &self,
+        world_id: WorldId,
         entity_type: EntityType,
         entity_id: Uuid,
         asset_type: AssetType,
-        prompt: &str,
-        workflow: &str,
+        request: GenerationRequest,
     ) -> Result<GenerateResult, GenerateError> {
+        let explicit_reference = request.style_reference_id;
+        let styled = self.styled(Some(world_id), asset_type, request).await?;
+        let reference = self
+            .reference_asset(entity_type, entity_id, explicit_reference)
+            .await?;
+
         // Check if service is available
         if !self.assets.check_health().await.unwrap_or(false) {
             return Err(GenerateError::Unavailable);
         }
 
+        // A reference that can't be read only costs consistency, not the image
+        let reference_image = match &reference {
+            Some(asset) => match self.blobs.get(&asset.file_path).await {
+                Ok(data) => Some(data),
+                Err(e) => {
+                    tracing::warn!(asset_id = %asset.id, error = %e, "Reference image unavailable");
+                    None
+                }
+            },
+            None => None,
+        };
+
         // Generate the image
+        let (width, height) = styled
+            .dimensions
+            .unwrap_or_else(|| asset_type.default_dimensions());
         let request = ImageRequest {
-            prompt: prompt.to_string(),
-            workflow: workflow.to_string(),
-            width: 512,
-            height: 512,
+            prompt: styled.prompt.clone(),
+            negative_prompt: styled.negative_prompt,
+            workflow: styled.workflow.clone(),
+            width,
+            height,
+            reference_image,
         };
 
         let image_data = self
@@ -105,19 +257,37 @@ impl GenerateAsset {
             .await
             .map_err(|e| GenerateError::Failed(e.to_string()))?;
 
+        // Stored under its content hash, which becomes the asset's path
+        let file_path = self
+            .blobs
+            .put(image_data.clone(), "image/png")
+            .await
+            .map_err(|e| GenerateError::Failed(e.to_string()))?;
+
+        let now = self.clock.now();
+        if let Err(e) = self
+            .usage
+            .record(world_id, now.date_naive(), UsageDelta::image(image_data.len()))
+            .await
+        {
+            tracing::warn!(error = %e, world_id = %world_id, "Failed to record image usage");
+        }
+
         // Create generation metadata
         let batch_id = BatchId::new();
         let seed = rand::random::<i64>().abs(); // Random seed
-        let metadata = GenerationMetadata::new(workflow, prompt, seed, batch_id);
+        let mut metadata =
+            GenerationMetadata::new(&styled.workflow, &styled.prompt, seed, batch_id);
+        if let Some(reference) = &reference {
+            metadata = metadata.with_style_reference(reference.id);
+        }
 
         // Create the asset
-        let now = self.clock.now();
-        let file_path = format!("assets/{:?}/{}.png", entity_type, entity_id);
         let asset = GalleryAsset::new_generated(
             entity_type,
             entity_id.to_string(),
             asset_type,
-            file_path,
+            file_path.clone(),
             metadata,
             now,
         );
@@ -131,6 +301,7 @@ impl GenerateAsset {
 
         Ok(GenerateResult {
             asset_id,
+            file_path,
             image_data,
             format: "png".to_string(),
         })
@@ -144,16 +315,28 @@ impl GenerateAsset {
         world_id: Option<WorldId>,
         entity_type: &str,
         entity_id: &str,
-        workflow_id: &str,
-        prompt: &str,
+        asset_type: AssetType,
+        request: GenerationRequest,
         count: u32,
     ) -> Result<Uuid, GenerateError> {
+        let explicit_reference = request.style_reference_id;
+        let styled = self.styled(world_id, asset_type, request).await?;
+        let reference = match (
+            entity_type.parse::<EntityType>(),
+            Uuid::parse_str(entity_id),
+        ) {
+            (Ok(entity), Ok(id)) => self.reference_asset(entity, id, explicit_reference).await?,
+            _ => None,
+        };
         let data = AssetGenerationData {
             world_id,
             entity_type: entity_type.to_string(),
             entity_id: entity_id.to_string(),
-            workflow_id: workflow_id.to_string(),
-            prompt: prompt.to_string(),
+            workflow_id: styled.workflow,
+            prompt: styled.prompt,
+            negative_prompt: styled.negative_prompt,
+            dimensions: styled.dimensions,
+            reference_image: reference.map(|asset| asset.file_path),
             count,
         };
 
@@ -170,8 +353,94 @@ pub enum GenerateError {
     Failed(String),
     #[error("Service unavailable")]
     Unavailable,
+    #[error("Unknown generation preset: {0}")]
+    UnknownPreset(String),
+    #[error("Settings error: {0}")]
+    Settings(#[from] SettingsError),
     #[error("Repository error: {0}")]
     Repo(#[from] RepoError),
     #[error("Image generation error: {0}")]
     ImageGen(#[from] ImageGenError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wrldbldr_domain::GenerationPreset;
+
+    fn request(preset: Option<&str>) -> GenerationRequest {
+        GenerationRequest {
+            prompt: "an old knight".to_string(),
+            negative_prompt: Some("blurry".to_string()),
+            workflow: "portrait".to_string(),
+            preset: preset.map(str::to_string),
+            style_reference_id: None,
+        }
+    }
+
+    fn settings() -> AppSettings {
+        AppSettings {
+            generation_presets: vec![GenerationPreset {
+                name: "Ink".to_string(),
+                asset_type: AssetType::Portrait,
+                style_prompt: "ink wash, muted palette".to_string(),
+                negative_prompt: Some("photo".to_string()),
+                width: Some(384),
+                height: Some(384),
+                workflow: Some("portrait_sdxl".to_string()),
+                is_default: true,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn canonical_portrait_is_the_active_portrait() {
+        let portrait = |active: bool| {
+            let mut asset = GalleryAsset::new(
+                EntityType::Character,
+                uuid::Uuid::new_v4().to_string(),
+                AssetType::Portrait,
+                "portrait.png",
+                chrono::Utc::now(),
+            );
+            asset.is_active = active;
+            asset
+        };
+        let mut sprite = portrait(true);
+        sprite.asset_type = AssetType::Sprite;
+        let active = portrait(true);
+        let gallery = vec![sprite, portrait(false), active.clone()];
+
+        assert_eq!(canonical_portrait(&gallery).map(|a| a.id), Some(active.id));
+        assert!(canonical_portrait(&gallery[..2]).is_none());
+    }
+
+    #[test]
+    fn apply_preset_styles_the_slot_default() {
+        let styled = apply_preset(&settings(), AssetType::Portrait, request(None)).unwrap();
+
+        assert_eq!(
+            styled,
+            StyledGeneration {
+                prompt: "an old knight, ink wash, muted palette".to_string(),
+                negative_prompt: Some("blurry, photo".to_string()),
+                workflow: "portrait_sdxl".to_string(),
+                dimensions: Some((384, 384)),
+            }
+        );
+    }
+
+    #[test]
+    fn apply_preset_leaves_other_slots_alone_and_rejects_unknown_names() {
+        let styled = apply_preset(&settings(), AssetType::Backdrop, request(None)).unwrap();
+        assert_eq!(styled.prompt, "an old knight");
+        assert_eq!(styled.workflow, "portrait");
+        assert_eq!(styled.dimensions, None);
+
+        assert!(matches!(
+            apply_preset(&settings(), AssetType::Portrait, request(Some("Noir"))),
+            Err(GenerateError::UnknownPreset(name)) if name == "Noir"
+        ));
+    }
+}
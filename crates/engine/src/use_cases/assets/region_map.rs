@@ -0,0 +1,167 @@
+//! Region map generation.
+//!
+//! Turns a region's description (and its location's) into a top-down map
+//! image - a gridded battle map for encounters or a layout overview - and
+//! stores it as the region's `map_asset`.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{self as domain, AssetType, EntityType, Region, RegionId, WorkflowSlot};
+
+use super::{GenerateAsset, GenerateError, GenerationRequest};
+use crate::entities::Location;
+use crate::infrastructure::ports::RepoError;
+
+const MAP_NEGATIVE_PROMPT: &str = "perspective, isometric, characters, people, text, \
+    watermark, blurry";
+
+/// Kind of top-down map to generate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionMapKind {
+    /// Gridded tactical map for combat encounters
+    BattleMap,
+    /// Overview of the region's layout and landmarks
+    RegionLayout,
+}
+
+impl RegionMapKind {
+    /// Workflow slot used when neither the request nor a preset names one
+    pub fn workflow_slot(&self) -> WorkflowSlot {
+        match self {
+            Self::BattleMap => WorkflowSlot::BattleMap,
+            Self::RegionLayout => WorkflowSlot::RegionLayout,
+        }
+    }
+
+    fn style(&self) -> &'static str {
+        match self {
+            Self::BattleMap => {
+                "top-down battle map, tabletop RPG encounter map, square grid overlay, \
+                 orthographic view from directly above, clear terrain and cover"
+            }
+            Self::RegionLayout => {
+                "top-down map of the area, orthographic view from directly above, \
+                 labelled landmarks, paths and entrances, cartographic illustration"
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RegionMapError {
+    #[error("Region not found")]
+    RegionNotFound,
+    #[error("Location not found")]
+    LocationNotFound,
+    #[error(transparent)]
+    Generate(#[from] GenerateError),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+/// Image prompt for a map of `region`, from its and its location's descriptions
+pub fn map_prompt(
+    kind: RegionMapKind,
+    location: &domain::Location,
+    region: &Region,
+    extra: Option<&str>,
+) -> String {
+    let mut parts = vec![
+        kind.style().to_string(),
+        format!("{} in {}", region.name, location.name),
+    ];
+    parts.extend(
+        [
+            Some(region.description.as_str()),
+            region.atmosphere.as_deref(),
+            Some(location.description.as_str()),
+            extra,
+        ]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(str::to_string),
+    );
+    parts.join(", ")
+}
+
+/// Generate a top-down map for a region.
+pub struct GenerateRegionMap {
+    location: Arc<Location>,
+    generate: Arc<GenerateAsset>,
+}
+
+impl GenerateRegionMap {
+    pub fn new(location: Arc<Location>, generate: Arc<GenerateAsset>) -> Self {
+        Self { location, generate }
+    }
+
+    /// Generate a map for `region_id` and save it as the region's map.
+    ///
+    /// Blocks until the image is generated, so callers should run it in the
+    /// background. Returns the updated region.
+    pub async fn execute(
+        &self,
+        region_id: RegionId,
+        kind: RegionMapKind,
+        workflow: Option<String>,
+        prompt: Option<String>,
+    ) -> Result<Region, RegionMapError> {
+        let mut region = self
+            .location
+            .get_region(region_id)
+            .await?
+            .ok_or(RegionMapError::RegionNotFound)?;
+        let location = self
+            .location
+            .get(region.location_id)
+            .await?
+            .ok_or(RegionMapError::LocationNotFound)?;
+
+        let request = GenerationRequest {
+            prompt: map_prompt(kind, &location, &region, prompt.as_deref()),
+            negative_prompt: Some(MAP_NEGATIVE_PROMPT.to_string()),
+            workflow: workflow.unwrap_or_else(|| kind.workflow_slot().as_str().to_string()),
+            preset: None,
+            style_reference_id: None,
+        };
+        let result = self
+            .generate
+            .execute(
+                location.world_id,
+                EntityType::Region,
+                *region_id.as_uuid(),
+                AssetType::Map,
+                request,
+            )
+            .await?;
+
+        region.map_asset = Some(result.file_path);
+        self.location.save_region(&region).await?;
+        Ok(region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wrldbldr_domain::{LocationType, WorldId};
+
+    #[test]
+    fn map_prompt_describes_the_region_and_skips_blank_parts() {
+        let location = domain::Location::new(WorldId::new(), "Saltmarsh", LocationType::Exterior)
+            .with_description("A fishing town on a foggy coast");
+        let region = Region::new(location.id, "The Docks")
+            .with_description("Rotting piers stacked with crates")
+            .with_atmosphere("  ");
+
+        let prompt = map_prompt(RegionMapKind::BattleMap, &location, &region, Some("night"));
+
+        assert!(prompt.starts_with("top-down battle map"));
+        assert!(prompt.ends_with(
+            "The Docks in Saltmarsh, Rotting piers stacked with crates, \
+             A fishing town on a foggy coast, night"
+        ));
+    }
+}
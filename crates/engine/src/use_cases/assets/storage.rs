@@ -0,0 +1,107 @@
+//! Asset storage use cases.
+//!
+//! Hands out time-limited signed URLs for stored assets, so remote Players
+//! can fetch images without the engine proxying every request, and serves
+//! local blobs to holders of a valid signed URL.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::infrastructure::blob_store::content_type_for;
+use crate::infrastructure::ports::{BlobError, BlobStorePort};
+
+/// How long a signed asset URL stays valid
+pub const ASSET_URL_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// A signed URL for one asset
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SignedAssetUrl {
+    pub key: String,
+    pub url: String,
+    pub expires_in_secs: u64,
+}
+
+/// A blob read back through a signed URL
+#[derive(Debug)]
+pub struct StoredBlob {
+    pub data: Vec<u8>,
+    pub content_type: &'static str,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AssetStorageError {
+    #[error("Invalid or expired signature")]
+    InvalidSignature,
+    #[error("Blob storage error: {0}")]
+    Blob(#[from] BlobError),
+}
+
+/// Signed access to stored assets.
+pub struct AssetStorage {
+    blobs: Arc<dyn BlobStorePort>,
+}
+
+impl AssetStorage {
+    pub fn new(blobs: Arc<dyn BlobStorePort>) -> Self {
+        Self { blobs }
+    }
+
+    /// Signed URL for the asset stored at `key`.
+    pub fn signed_url(&self, key: &str) -> Result<SignedAssetUrl, AssetStorageError> {
+        let url = self.blobs.signed_url(key, ASSET_URL_TTL)?;
+        Ok(SignedAssetUrl {
+            key: key.to_string(),
+            url,
+            expires_in_secs: ASSET_URL_TTL.as_secs(),
+        })
+    }
+
+    /// Read a blob, if `signature` is valid for `key` until `expires_at`.
+    pub async fn read_signed(
+        &self,
+        key: &str,
+        expires_at: i64,
+        signature: &str,
+    ) -> Result<StoredBlob, AssetStorageError> {
+        if !self.blobs.verify_signature(key, expires_at, signature) {
+            return Err(AssetStorageError::InvalidSignature);
+        }
+        let data = self.blobs.get(key).await?;
+        Ok(StoredBlob {
+            data,
+            content_type: content_type_for(key),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ports::MockBlobStorePort;
+
+    #[tokio::test]
+    async fn read_signed_checks_the_signature_first() {
+        let mut blobs = MockBlobStorePort::new();
+        blobs
+            .expect_verify_signature()
+            .returning(|_, _, signature| signature == "good");
+        blobs
+            .expect_get()
+            .times(1)
+            .returning(|_| Ok(b"png".to_vec()));
+        let storage = AssetStorage::new(Arc::new(blobs));
+
+        assert!(matches!(
+            storage.read_signed("ab/cd/key.png", 0, "bad").await,
+            Err(AssetStorageError::InvalidSignature)
+        ));
+        let blob = storage
+            .read_signed("ab/cd/key.png", 0, "good")
+            .await
+            .unwrap();
+        assert_eq!(blob.data, b"png");
+        assert_eq!(blob.content_type, "image/png");
+    }
+}
@@ -0,0 +1,408 @@
+//! Asset upload use cases.
+//!
+//! Lets DMs bring their own art: an uploaded image is stored, optionally
+//! captioned by the LLM, and matched against the world's characters and
+//! locations so it can be attached to the entity it most likely depicts
+//! without hunting for it by hand.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use base64::Engine as _;
+use serde::Serialize;
+use uuid::Uuid;
+use wrldbldr_domain::{AssetType, EntityType, GalleryAsset, WorldId};
+
+use crate::entities::{Assets, Character, Location};
+use crate::infrastructure::blob_store::validate_key;
+use crate::infrastructure::ports::{
+    BlobError, BlobStorePort, ChatMessage, ClockPort, ImageData, LlmPort, LlmRequest, RepoError,
+    UsageDelta, UsageRepo,
+};
+
+/// Largest image accepted for upload
+pub const MAX_UPLOAD_BYTES: usize = 10 * 1024 * 1024;
+
+/// Most association suggestions returned for one image
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Suggestions below this confidence are dropped
+const MIN_CONFIDENCE: f32 = 0.15;
+
+const CAPTION_PROMPT: &str = "Describe this image in one or two sentences for a tabletop \
+    game master: who or what it shows, their appearance, and the setting. \
+    Mention any visible names or text.";
+
+/// An image uploaded by the DM
+#[derive(Debug, Clone)]
+pub struct UploadedImage {
+    pub data: Vec<u8>,
+    pub asset_type: AssetType,
+    pub file_name: Option<String>,
+    pub label: Option<String>,
+    /// Whether to caption the image with the LLM
+    pub caption: bool,
+}
+
+/// An entity an uploaded image likely depicts
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AssociationSuggestion {
+    pub entity_type: EntityType,
+    pub entity_id: String,
+    pub name: String,
+    /// 0.0 - 1.0
+    pub confidence: f32,
+    pub reason: String,
+}
+
+/// Result of storing an uploaded image
+#[derive(Debug, Clone)]
+pub struct UploadResult {
+    /// Stored path, used when attaching the image to an entity
+    pub file_path: String,
+    pub caption: Option<String>,
+    /// Best matches first
+    pub suggestions: Vec<AssociationSuggestion>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UploadError {
+    #[error("Image is {0} bytes; the limit is {MAX_UPLOAD_BYTES}")]
+    TooLarge(usize),
+    #[error("Not a PNG, JPEG, WebP or GIF image")]
+    UnsupportedFormat,
+    #[error("Invalid asset path: {0}")]
+    InvalidPath(String),
+    #[error("Blob storage error: {0}")]
+    Blob(#[from] BlobError),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+/// Upload images and attach them to entities.
+pub struct UploadAsset {
+    assets: Arc<Assets>,
+    blobs: Arc<dyn BlobStorePort>,
+    llm: Arc<dyn LlmPort>,
+    character: Arc<Character>,
+    location: Arc<Location>,
+    usage: Arc<dyn UsageRepo>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl UploadAsset {
+    pub fn new(
+        assets: Arc<Assets>,
+        blobs: Arc<dyn BlobStorePort>,
+        llm: Arc<dyn LlmPort>,
+        character: Arc<Character>,
+        location: Arc<Location>,
+        usage: Arc<dyn UsageRepo>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            assets,
+            blobs,
+            llm,
+            character,
+            location,
+            usage,
+            clock,
+        }
+    }
+
+    /// Store an image and suggest which of the world's entities it depicts.
+    pub async fn upload(
+        &self,
+        world_id: WorldId,
+        image: UploadedImage,
+    ) -> Result<UploadResult, UploadError> {
+        if image.data.len() > MAX_UPLOAD_BYTES {
+            return Err(UploadError::TooLarge(image.data.len()));
+        }
+        let content_type = sniff_image_type(&image.data).ok_or(UploadError::UnsupportedFormat)?;
+
+        let caption = if image.caption {
+            self.caption(world_id, &image.data, content_type).await
+        } else {
+            None
+        };
+
+        let size = image.data.len();
+        let file_path = self.blobs.put(image.data, content_type).await?;
+        if let Err(e) = self
+            .usage
+            .record(
+                world_id,
+                self.clock.now().date_naive(),
+                UsageDelta::storage(size),
+            )
+            .await
+        {
+            tracing::warn!(error = %e, world_id = %world_id, "Failed to record upload usage");
+        }
+
+        let hint = [
+            caption.as_deref(),
+            image.label.as_deref(),
+            image.file_name.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+        let candidates = self.candidates(world_id, image.asset_type).await?;
+
+        Ok(UploadResult {
+            file_path,
+            caption,
+            suggestions: suggest_associations(&hint, &candidates),
+        })
+    }
+
+    /// Attach a stored image to an entity's gallery.
+    pub async fn attach(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        asset_type: AssetType,
+        file_path: &str,
+        label: Option<String>,
+        set_active: bool,
+    ) -> Result<GalleryAsset, UploadError> {
+        validate_key(file_path).map_err(|_| UploadError::InvalidPath(file_path.to_string()))?;
+
+        let mut asset = GalleryAsset::new(
+            entity_type,
+            entity_id.to_string(),
+            asset_type,
+            file_path,
+            self.clock.now(),
+        );
+        if let Some(label) = label.filter(|l| !l.trim().is_empty()) {
+            asset = asset.with_label(label);
+        }
+        self.assets.save(&asset).await?;
+
+        if set_active {
+            self.assets
+                .set_active(&entity_type.to_string(), entity_id, asset.id)
+                .await?;
+            asset.is_active = true;
+        }
+        Ok(asset)
+    }
+
+    /// Best-effort caption; uploads still succeed without one.
+    async fn caption(&self, world_id: WorldId, data: &[u8], content_type: &str) -> Option<String> {
+        let mut request = LlmRequest::new(vec![ChatMessage::user(CAPTION_PROMPT)])
+            .with_temperature(0.2)
+            .with_max_tokens(Some(200))
+            .with_world(world_id);
+        request.images = vec![ImageData {
+            data: base64::engine::general_purpose::STANDARD.encode(data),
+            media_type: content_type.to_string(),
+        }];
+
+        match self.llm.generate(request).await {
+            Ok(response) => Some(response.content.trim().to_string()).filter(|c| !c.is_empty()),
+            Err(e) => {
+                tracing::warn!(error = %e, world_id = %world_id, "Failed to caption uploaded image");
+                None
+            }
+        }
+    }
+
+    /// Entities an image of `asset_type` could belong to
+    async fn candidates(
+        &self,
+        world_id: WorldId,
+        asset_type: AssetType,
+    ) -> Result<Vec<Candidate>, UploadError> {
+        let (characters, locations) = match asset_type {
+            AssetType::Portrait | AssetType::Sprite | AssetType::EmotionSheet => (true, false),
+            AssetType::Backdrop
+            | AssetType::Tilesheet
+            | AssetType::RegionBackdrop
+            | AssetType::Map => (false, true),
+            AssetType::ItemIcon | AssetType::Unknown => (true, true),
+        };
+
+        let mut candidates = Vec::new();
+        if characters {
+            candidates.extend(
+                self.character
+                    .list_in_world(world_id)
+                    .await?
+                    .into_iter()
+                    .map(|c| Candidate {
+                        entity_type: EntityType::Character,
+                        entity_id: c.id.to_string(),
+                        name: c.name,
+                        description: c.description,
+                    }),
+            );
+        }
+        if locations {
+            candidates.extend(
+                self.location
+                    .list_in_world(world_id)
+                    .await?
+                    .into_iter()
+                    .map(|l| Candidate {
+                        entity_type: EntityType::Location,
+                        entity_id: l.id.to_string(),
+                        name: l.name,
+                        description: l.description,
+                    }),
+            );
+        }
+        Ok(candidates)
+    }
+}
+
+/// Image type from the file's magic bytes
+fn sniff_image_type(data: &[u8]) -> Option<&'static str> {
+    match data {
+        [0x89, b'P', b'N', b'G', ..] => Some("image/png"),
+        [0xFF, 0xD8, 0xFF, ..] => Some("image/jpeg"),
+        [b'G', b'I', b'F', b'8', ..] => Some("image/gif"),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some("image/webp"),
+        _ => None,
+    }
+}
+
+/// An entity an image might depict
+#[derive(Debug, Clone)]
+struct Candidate {
+    entity_type: EntityType,
+    entity_id: String,
+    name: String,
+    description: String,
+}
+
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "with", "from", "this", "that", "has", "his", "her", "their", "image", "shows",
+    "showing", "picture", "portrait", "sprite", "backdrop", "png", "jpg", "jpeg", "webp", "gif",
+];
+
+/// Lowercase words of 3+ letters, minus stop words
+fn keywords(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .filter(|w| !STOP_WORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// Rank `candidates` by how well `hint` (caption, label, file name) matches
+/// their names and descriptions.
+fn suggest_associations(hint: &str, candidates: &[Candidate]) -> Vec<AssociationSuggestion> {
+    let hint_words = keywords(hint);
+    if hint_words.is_empty() {
+        return Vec::new();
+    }
+
+    let mut suggestions: Vec<AssociationSuggestion> = candidates
+        .iter()
+        .filter_map(|candidate| {
+            let name_words = keywords(&candidate.name);
+            let name_hits = name_words.intersection(&hint_words).count();
+            let mut confidence = 0.0;
+            let mut reasons = Vec::new();
+
+            if !name_words.is_empty() && name_hits == name_words.len() {
+                confidence += 0.7;
+                reasons.push(format!("mentions \"{}\"", candidate.name));
+            } else if name_hits > 0 {
+                confidence += 0.45 * name_hits as f32 / name_words.len() as f32;
+                reasons.push(format!("partly matches \"{}\"", candidate.name));
+            }
+
+            let mut shared: Vec<String> = keywords(&candidate.description)
+                .difference(&name_words)
+                .filter(|w| hint_words.contains(*w))
+                .cloned()
+                .collect();
+            if !shared.is_empty() {
+                shared.sort();
+                confidence += (0.06 * shared.len() as f32).min(0.3);
+                shared.truncate(3);
+                reasons.push(format!("description shares {}", shared.join(", ")));
+            }
+
+            (confidence >= MIN_CONFIDENCE).then(|| AssociationSuggestion {
+                entity_type: candidate.entity_type,
+                entity_id: candidate.entity_id.clone(),
+                name: candidate.name.clone(),
+                confidence: confidence.min(1.0),
+                reason: reasons.join("; "),
+            })
+        })
+        .collect();
+
+    suggestions.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    suggestions.truncate(MAX_SUGGESTIONS);
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(entity_type: EntityType, name: &str, description: &str) -> Candidate {
+        Candidate {
+            entity_type,
+            entity_id: Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn suggestions_rank_name_matches_above_description_matches() {
+        let candidates = vec![
+            candidate(
+                EntityType::Character,
+                "Mira Thorne",
+                "A red-haired smuggler with a scarred cheek",
+            ),
+            candidate(
+                EntityType::Character,
+                "Old Bram",
+                "Innkeeper of the Gilded Goose, red-faced and jovial",
+            ),
+            candidate(EntityType::Location, "Saltmarsh Docks", "Fog and gulls"),
+        ];
+
+        let suggestions = suggest_associations(
+            "A red-haired woman with a scarred cheek on a ship. mira_thorne_final.png",
+            &candidates,
+        );
+
+        assert_eq!(suggestions[0].name, "Mira Thorne");
+        assert!(suggestions[0].confidence > 0.7);
+        assert!(suggestions[0].reason.contains("mentions"));
+        assert!(suggestions.iter().all(|s| s.name != "Saltmarsh Docks"));
+    }
+
+    #[test]
+    fn suggestions_are_empty_without_hints() {
+        let candidates = vec![candidate(EntityType::Character, "Mira", "Smuggler")];
+        assert!(suggest_associations("", &candidates).is_empty());
+        assert!(suggest_associations("IMG_0042.png", &candidates).is_empty());
+    }
+
+    #[test]
+    fn sniffs_supported_image_formats() {
+        assert_eq!(
+            sniff_image_type(b"\x89PNG\r\n\x1a\n...."),
+            Some("image/png")
+        );
+        assert_eq!(
+            sniff_image_type(b"RIFF\0\0\0\0WEBPVP8 "),
+            Some("image/webp")
+        );
+        assert_eq!(sniff_image_type(b"<svg></svg>"), None);
+    }
+}
@@ -0,0 +1,574 @@
+//! Broadsheet use cases.
+//!
+//! When a game week ends, the LLM writes the world's broadsheet for it from
+//! the story events and rumors recorded since the last issue. The issue
+//! stays a draft the DM can rewrite until they publish it to the players.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use wrldbldr_domain::{
+    self as domain, game_week_ended, game_week_number, game_week_start, Broadsheet,
+    BroadsheetArticle, BroadsheetId, DomainError, InfoType, LlmTask, StoryEvent, StoryEventType,
+    WorldId, MAX_BROADSHEET_ARTICLES, MAX_BROADSHEET_ARTICLE_LEN, MAX_BROADSHEET_HEADLINE_LEN,
+};
+use wrldbldr_protocol::types::{BroadsheetArticleData, BroadsheetData, BroadsheetInputData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ChatMessage, ClockPort, LlmPort, LlmRequest, RepoError};
+
+/// Story events read when looking for news
+const STORY_EVENTS_SCANNED: usize = 200;
+
+/// Most story events handed to the LLM for one issue
+const MAX_NEWS_ITEMS: usize = 40;
+
+/// Container for broadsheet use cases.
+pub struct BroadsheetUseCases {
+    pub manage: Arc<ManageBroadsheets>,
+}
+
+impl BroadsheetUseCases {
+    pub fn new(manage: Arc<ManageBroadsheets>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BroadsheetError {
+    #[error("Broadsheet not found")]
+    NotFound,
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("Could not write the broadsheet: {0}")]
+    Generation(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for BroadsheetError {
+    fn from(e: DomainError) -> Self {
+        match e {
+            DomainError::InvalidStateTransition(msg) => BroadsheetError::Conflict(msg),
+            other => BroadsheetError::Invalid(other.to_string()),
+        }
+    }
+}
+
+/// Draft, edit and publish a world's weekly broadsheet.
+pub struct ManageBroadsheets {
+    broadsheet: Arc<entities::Broadsheet>,
+    narrative: Arc<entities::Narrative>,
+    world: Arc<entities::World>,
+    settings: Arc<entities::Settings>,
+    llm: Arc<dyn LlmPort>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageBroadsheets {
+    pub fn new(
+        broadsheet: Arc<entities::Broadsheet>,
+        narrative: Arc<entities::Narrative>,
+        world: Arc<entities::World>,
+        settings: Arc<entities::Settings>,
+        llm: Arc<dyn LlmPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            broadsheet,
+            narrative,
+            world,
+            settings,
+            llm,
+            clock,
+        }
+    }
+
+    /// Issues, newest first. Drafts are left out unless asked for.
+    pub async fn list(
+        &self,
+        world_id: WorldId,
+        include_drafts: bool,
+    ) -> Result<Vec<BroadsheetData>, BroadsheetError> {
+        Ok(self
+            .broadsheet
+            .list_in_world(world_id)
+            .await?
+            .iter()
+            .filter(|b| include_drafts || b.is_published())
+            .map(to_data)
+            .collect())
+    }
+
+    /// The issue, if it belongs to the world
+    pub async fn get(
+        &self,
+        world_id: WorldId,
+        broadsheet_id: BroadsheetId,
+    ) -> Result<Broadsheet, BroadsheetError> {
+        self.broadsheet
+            .get(broadsheet_id)
+            .await?
+            .filter(|b| b.world_id == world_id)
+            .ok_or(BroadsheetError::NotFound)
+    }
+
+    /// Draft an issue now, covering the game time since the last one
+    pub async fn generate(&self, world_id: WorldId) -> Result<BroadsheetData, BroadsheetError> {
+        let world = self.get_world(world_id).await?;
+        let game_now = world.game_time.current();
+        let latest = self.latest(world_id).await?;
+        let period_start = latest
+            .as_ref()
+            .map(|b| b.period_end)
+            .unwrap_or_else(|| game_week_start(game_now));
+
+        let news = self.news_since(world_id, latest.as_ref()).await?;
+        if news.is_empty() {
+            return Err(BroadsheetError::Invalid(
+                "Nothing newsworthy has happened since the last issue".to_string(),
+            ));
+        }
+        let issue = self
+            .write_issue(&world, latest.as_ref(), &news, period_start, game_now)
+            .await?;
+        Ok(to_data(&issue))
+    }
+
+    /// Draft the issue for a game week that ended while the world's clock
+    /// advanced by `minutes_advanced`. Nothing is drafted when no week
+    /// ended, the week already has an issue, or there is no news.
+    pub async fn draft_after_time_advance(
+        &self,
+        world_id: WorldId,
+        minutes_advanced: u32,
+    ) -> Result<Option<BroadsheetData>, BroadsheetError> {
+        let world = self.get_world(world_id).await?;
+        let game_now = world.game_time.current();
+        let before = game_now - Duration::minutes(i64::from(minutes_advanced));
+        if !game_week_ended(before, game_now) {
+            return Ok(None);
+        }
+
+        let week_end = game_week_start(game_now);
+        let latest = self.latest(world_id).await?;
+        if latest.as_ref().is_some_and(|b| b.period_end >= week_end) {
+            return Ok(None);
+        }
+        let period_start = latest
+            .as_ref()
+            .map(|b| b.period_end)
+            .unwrap_or_else(|| game_week_start(before));
+
+        let news = self.news_since(world_id, latest.as_ref()).await?;
+        if news.is_empty() {
+            return Ok(None);
+        }
+        let issue = self
+            .write_issue(&world, latest.as_ref(), &news, period_start, week_end)
+            .await?;
+        Ok(Some(to_data(&issue)))
+    }
+
+    /// Replace a draft's title and articles
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        broadsheet_id: BroadsheetId,
+        input: BroadsheetInputData,
+    ) -> Result<BroadsheetData, BroadsheetError> {
+        let mut issue = self.get(world_id, broadsheet_id).await?;
+        let articles = input
+            .articles
+            .into_iter()
+            .map(|a| BroadsheetArticle::new(a.headline, a.body))
+            .collect();
+        issue.edit(input.title, articles, self.clock.now())?;
+        self.broadsheet.save(&issue).await?;
+        Ok(to_data(&issue))
+    }
+
+    /// Publish a draft
+    pub async fn publish(
+        &self,
+        world_id: WorldId,
+        broadsheet_id: BroadsheetId,
+    ) -> Result<BroadsheetData, BroadsheetError> {
+        let mut issue = self.get(world_id, broadsheet_id).await?;
+        issue.publish(self.clock.now())?;
+        self.broadsheet.save(&issue).await?;
+        Ok(to_data(&issue))
+    }
+
+    /// Delete an issue. Returns it as it was, so the right audience can be
+    /// told.
+    pub async fn delete(
+        &self,
+        world_id: WorldId,
+        broadsheet_id: BroadsheetId,
+    ) -> Result<Broadsheet, BroadsheetError> {
+        let issue = self.get(world_id, broadsheet_id).await?;
+        self.broadsheet.delete(broadsheet_id).await?;
+        Ok(issue)
+    }
+
+    async fn get_world(&self, world_id: WorldId) -> Result<domain::World, BroadsheetError> {
+        self.world
+            .get(world_id)
+            .await?
+            .ok_or(BroadsheetError::WorldNotFound)
+    }
+
+    async fn latest(&self, world_id: WorldId) -> Result<Option<Broadsheet>, BroadsheetError> {
+        Ok(self
+            .broadsheet
+            .list_in_world(world_id)
+            .await?
+            .into_iter()
+            .max_by_key(|b| b.issue_number))
+    }
+
+    /// What players could have heard about since the previous issue was
+    /// written, oldest first
+    async fn news_since(
+        &self,
+        world_id: WorldId,
+        previous: Option<&Broadsheet>,
+    ) -> Result<Vec<String>, BroadsheetError> {
+        let since = previous.map(|b| b.created_at);
+        let mut news: Vec<String> = self
+            .narrative
+            .list_story_events(world_id, STORY_EVENTS_SCANNED)
+            .await?
+            .iter()
+            .filter(|e| !e.is_hidden && since.is_none_or(|since| e.timestamp > since))
+            .filter_map(news_item)
+            .take(MAX_NEWS_ITEMS)
+            .collect();
+        news.reverse();
+        Ok(news)
+    }
+
+    /// Write, save and return the next issue
+    async fn write_issue(
+        &self,
+        world: &domain::World,
+        previous: Option<&Broadsheet>,
+        news: &[String],
+        period_start: DateTime<Utc>,
+        period_end: DateTime<Utc>,
+    ) -> Result<Broadsheet, BroadsheetError> {
+        let model = self
+            .settings
+            .get_for_world(world.id)
+            .await
+            .ok()
+            .and_then(|s| s.model_for(LlmTask::Suggestion));
+        let previous_title = previous.map(|b| b.title.clone());
+
+        let mut context = format!(
+            "The news of week {} in {}:\n",
+            game_week_number(period_start),
+            world.name
+        );
+        for item in news {
+            context.push_str(&format!("- {}\n", item));
+        }
+        if let Some(title) = &previous_title {
+            context.push_str(&format!("\nThe broadsheet is called \"{}\".\n", title));
+        }
+
+        let system_prompt = format!(
+            "You write the local broadsheet for a TTRPG world: {}\n\
+            Turn the week's events into a short news digest, in the voice of an \
+            in-world town crier or printer. Report what common folk would have \
+            heard; rumors may be printed as rumors. Start with the broadsheet's \
+            name on a line beginning \"# \", then give each story a headline on a \
+            line beginning \"## \" followed by a paragraph or two. Write at most \
+            {} stories.",
+            world.description, MAX_BROADSHEET_ARTICLES
+        );
+        let request = LlmRequest::new(vec![ChatMessage::user(context)])
+            .with_system_prompt(format!(
+                "{}\n\n{}",
+                system_prompt,
+                world.content_safety.prompt_constraints()
+            ))
+            .with_temperature(0.8)
+            .with_model(model)
+            .with_world(world.id);
+
+        let response = self
+            .llm
+            .generate(request)
+            .await
+            .map_err(|e| BroadsheetError::Generation(e.to_string()))?;
+        let (title, articles) = parse_issue(&response.content);
+        if articles.is_empty() {
+            return Err(BroadsheetError::Generation(
+                "the broadsheet was empty".to_string(),
+            ));
+        }
+        let title = previous_title
+            .or(title)
+            .unwrap_or_else(|| format!("The {} Broadsheet", world.name));
+
+        let issue = Broadsheet::new(
+            world.id,
+            previous.map_or(1, |b| b.issue_number + 1),
+            truncate(&title, MAX_BROADSHEET_HEADLINE_LEN),
+            articles,
+            period_start,
+            period_end,
+            self.clock.now(),
+        )?;
+        self.broadsheet.save(&issue).await?;
+        Ok(issue)
+    }
+}
+
+/// One line of news for the LLM, or None for events that are not news
+fn news_item(event: &StoryEvent) -> Option<String> {
+    match &event.event_type {
+        StoryEventType::SessionStarted { .. }
+        | StoryEventType::SessionEnded { .. }
+        | StoryEventType::DmMarker { .. }
+        | StoryEventType::FlagChanged { .. }
+        | StoryEventType::StatModified { .. } => None,
+        StoryEventType::InformationRevealed {
+            info_type: InfoType::Rumor,
+            title,
+            content,
+            ..
+        } => Some(format!("Rumor: {}. {}", title, content)),
+        _ if !event.summary.trim().is_empty() => Some(event.summary.trim().to_string()),
+        _ => {
+            let mut event = event.clone();
+            event.auto_summarize();
+            Some(event.summary).filter(|s| !s.trim().is_empty())
+        }
+    }
+}
+
+/// Split the LLM's digest into a title and articles. Text before the first
+/// headline is kept as an untitled opening story.
+fn parse_issue(text: &str) -> (Option<String>, Vec<BroadsheetArticle>) {
+    let mut title = None;
+    let mut articles: Vec<BroadsheetArticle> = Vec::new();
+    let mut lead = String::new();
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if let Some(headline) = trimmed.strip_prefix("## ") {
+            articles.push(BroadsheetArticle::new(headline.trim(), ""));
+        } else if let Some(name) = trimmed.strip_prefix("# ") {
+            if title.is_none() && articles.is_empty() {
+                title = Some(name.trim().to_string());
+            }
+        } else {
+            let body = match articles.last_mut() {
+                Some(article) => &mut article.body,
+                None => &mut lead,
+            };
+            body.push_str(line);
+            body.push('\n');
+        }
+    }
+    if !lead.trim().is_empty() {
+        articles.insert(0, BroadsheetArticle::new("This Week", lead));
+    }
+
+    let articles = articles
+        .into_iter()
+        .filter(|a| !a.headline.is_empty())
+        .take(MAX_BROADSHEET_ARTICLES)
+        .map(|a| BroadsheetArticle {
+            headline: truncate(&a.headline, MAX_BROADSHEET_HEADLINE_LEN),
+            body: truncate(a.body.trim(), MAX_BROADSHEET_ARTICLE_LEN),
+        })
+        .collect();
+    (title.filter(|t| !t.is_empty()), articles)
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    text.chars().take(max_chars).collect()
+}
+
+/// The issue for the wire
+pub fn to_data(issue: &Broadsheet) -> BroadsheetData {
+    BroadsheetData {
+        id: issue.id.to_string(),
+        issue_number: issue.issue_number,
+        title: issue.title.clone(),
+        articles: issue
+            .articles
+            .iter()
+            .map(|a| BroadsheetArticleData {
+                headline: a.headline.clone(),
+                body: a.body.clone(),
+            })
+            .collect(),
+        period_start: issue.period_start.to_rfc3339(),
+        period_end: issue.period_end.to_rfc3339(),
+        published: issue.is_published(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        FinishReason, LlmError, LlmResponse, MockBroadsheetRepo, MockChallengeRepo,
+        MockCharacterRepo, MockFlagRepo, MockLocationRepo, MockNarrativeRepo, MockObservationRepo,
+        MockPlayerCharacterRepo, MockSceneRepo, MockSettingsRepo, MockWorldRepo, ToolDefinition,
+    };
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+    use wrldbldr_domain::{AppSettings, GameTime, StoryEventInfoImportance, World};
+
+    /// LLM that always writes the same digest
+    struct FixedLlm(String);
+
+    #[async_trait]
+    impl LlmPort for FixedLlm {
+        async fn generate(&self, _request: LlmRequest) -> Result<LlmResponse, LlmError> {
+            Ok(LlmResponse {
+                content: self.0.clone(),
+                tool_calls: vec![],
+                finish_reason: FinishReason::Stop,
+                usage: None,
+            })
+        }
+
+        async fn generate_with_tools(
+            &self,
+            request: LlmRequest,
+            _tools: Vec<ToolDefinition>,
+        ) -> Result<LlmResponse, LlmError> {
+            self.generate(request).await
+        }
+    }
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().into()
+    }
+
+    /// A world whose clock reads `game_now`, a rumor going round, and no
+    /// issues yet. Saved issues end up in the returned list.
+    fn world_with_news(
+        game_now: DateTime<Utc>,
+    ) -> (ManageBroadsheets, WorldId, Arc<Mutex<Vec<Broadsheet>>>) {
+        let mut world = World::new("Varn", "A river city", Utc::now());
+        world.game_time = GameTime::starting_at(game_now);
+        let world_id = world.id;
+
+        let mut worlds = MockWorldRepo::new();
+        worlds
+            .expect_get()
+            .returning(move |_| Ok(Some(world.clone())));
+        let worlds = Arc::new(worlds);
+        let mut settings = MockSettingsRepo::new();
+        settings
+            .expect_get_for_world()
+            .returning(|_| Ok(Some(AppSettings::default())));
+
+        let rumor = StoryEvent::new(
+            world_id,
+            StoryEventType::InformationRevealed {
+                info_type: InfoType::Rumor,
+                title: "The mill burns".to_string(),
+                content: "Folk say the miller lit it himself.".to_string(),
+                source: None,
+                importance: StoryEventInfoImportance::Notable,
+                persist_to_journal: false,
+            },
+            Utc::now(),
+        );
+        let mut narrative = MockNarrativeRepo::new();
+        narrative
+            .expect_list_story_events()
+            .returning(move |_, _| Ok(vec![rumor.clone()]));
+
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let mut broadsheets = MockBroadsheetRepo::new();
+        broadsheets.expect_list_in_world().returning(|_| Ok(vec![]));
+        let store = saved.clone();
+        broadsheets.expect_save().returning(move |b| {
+            store.lock().unwrap().push(b.clone());
+            Ok(())
+        });
+
+        let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(Utc::now()));
+        let manage = ManageBroadsheets::new(
+            Arc::new(entities::Broadsheet::new(Arc::new(broadsheets))),
+            Arc::new(entities::Narrative::new(
+                Arc::new(narrative),
+                Arc::new(MockLocationRepo::new()),
+                worlds.clone(),
+                Arc::new(MockPlayerCharacterRepo::new()),
+                Arc::new(MockCharacterRepo::new()),
+                Arc::new(MockObservationRepo::new()),
+                Arc::new(MockChallengeRepo::new()),
+                Arc::new(MockFlagRepo::new()),
+                Arc::new(MockSceneRepo::new()),
+                clock.clone(),
+            )),
+            Arc::new(entities::World::new(worlds, clock.clone())),
+            Arc::new(entities::Settings::new(Arc::new(settings))),
+            Arc::new(FixedLlm(
+                "# The Varn Crier\n## Mill Ablaze\nThe old mill burned.\n".to_string(),
+            )),
+            clock,
+        );
+        (manage, world_id, saved)
+    }
+
+    #[tokio::test]
+    async fn the_end_of_a_game_week_drafts_an_issue() {
+        let (manage, world_id, saved) = world_with_news(at("2024-01-08T02:00:00Z"));
+
+        let issue = manage
+            .draft_after_time_advance(world_id, 4 * 60)
+            .await
+            .expect("drafted")
+            .expect("a week ended");
+
+        assert_eq!(issue.issue_number, 1);
+        assert_eq!(issue.title, "The Varn Crier");
+        assert_eq!(issue.articles[0].headline, "Mill Ablaze");
+        assert!(!issue.published);
+        let saved = saved.lock().unwrap();
+        assert_eq!(saved[0].period_start, at("2024-01-01T00:00:00Z"));
+        assert_eq!(saved[0].period_end, at("2024-01-08T00:00:00Z"));
+    }
+
+    #[tokio::test]
+    async fn time_within_a_week_drafts_nothing() {
+        let (manage, world_id, saved) = world_with_news(at("2024-01-05T12:00:00Z"));
+
+        let issue = manage
+            .draft_after_time_advance(world_id, 8 * 60)
+            .await
+            .expect("checked");
+
+        assert!(issue.is_none());
+        assert!(saved.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn digests_split_into_title_and_articles() {
+        let (title, articles) = parse_issue(
+            "Hear ye!\n# The Crier\n## Fire\nThe mill burned.\n\n## Fair\nThe fair opens.",
+        );
+
+        assert_eq!(title.as_deref(), Some("The Crier"));
+        let headlines: Vec<_> = articles.iter().map(|a| a.headline.as_str()).collect();
+        assert_eq!(headlines, vec!["This Week", "Fire", "Fair"]);
+        assert_eq!(articles[1].body, "The mill burned.");
+    }
+}
@@ -155,7 +155,7 @@ pub enum ChallengeError {
     Repo(#[from] RepoError),
 }
 
-fn challenge_to_json(challenge: &domain::Challenge) -> Value {
+pub fn challenge_to_json(challenge: &domain::Challenge) -> Value {
     serde_json::json!({
         "id": challenge.id.to_string(),
         "world_id": challenge.world_id.to_string(),
@@ -240,6 +240,11 @@ fn outcome_trigger_to_json(trigger: &domain::OutcomeTrigger) -> Value {
             "item_name": item_name,
             "item_description": item_description,
         }),
+        domain::OutcomeTrigger::TickClock { clock_id, amount } => serde_json::json!({
+            "type": "tick_clock",
+            "clock_id": clock_id.to_string(),
+            "amount": amount,
+        }),
         domain::OutcomeTrigger::Custom { description } => serde_json::json!({
             "type": "custom",
             "description": description,
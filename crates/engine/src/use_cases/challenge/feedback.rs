@@ -0,0 +1,181 @@
+//! Challenge suggestion feedback.
+//!
+//! Keeps what the DM did with each LLM challenge suggestion and sums a
+//! world's recent decisions into preferences for the dialogue prompt.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    SuggestionDecision, SuggestionPreferences, SuggestionVerdict, WorldId,
+    MAX_SUGGESTION_DECISIONS_CONSIDERED,
+};
+
+use super::ChallengeError;
+use crate::entities::Challenge;
+use crate::infrastructure::ports::{ClockPort, QueuePort, RepoError};
+
+pub struct SuggestionFeedback {
+    challenge: Arc<Challenge>,
+    queue: Arc<dyn QueuePort>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl SuggestionFeedback {
+    pub fn new(
+        challenge: Arc<Challenge>,
+        queue: Arc<dyn QueuePort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            challenge,
+            queue,
+            clock,
+        }
+    }
+
+    /// Record the DM's decision on the suggestion in an approval item.
+    ///
+    /// Returns `None` when the item carries no challenge suggestion.
+    pub async fn record(
+        &self,
+        approval_id: Uuid,
+        approved: bool,
+        modified_difficulty: Option<String>,
+    ) -> Result<Option<SuggestionDecision>, ChallengeError> {
+        let approval = self
+            .queue
+            .get_approval_request(approval_id)
+            .await
+            .map_err(|e| ChallengeError::QueueError(e.to_string()))?
+            .ok_or(ChallengeError::NotFound)?;
+        let Some(suggestion) = approval.challenge_suggestion.as_ref() else {
+            return Ok(None);
+        };
+
+        let verdict = match modified_difficulty.filter(|d| !d.trim().is_empty()) {
+            _ if !approved => SuggestionVerdict::Rejected,
+            Some(difficulty) if difficulty.trim() != suggestion.difficulty_display => {
+                SuggestionVerdict::Modified {
+                    difficulty: difficulty.trim().to_string(),
+                }
+            }
+            _ => SuggestionVerdict::Accepted,
+        };
+        let decision = SuggestionDecision::new(
+            approval_id,
+            approval.world_id,
+            suggestion,
+            verdict,
+            self.clock.now(),
+        )
+        .with_context(approval.npc_name.clone(), approval.player_dialogue.clone());
+
+        self.challenge.save_suggestion_decision(&decision).await?;
+        Ok(Some(decision))
+    }
+
+    /// What the world's DM has recently done with suggestions
+    pub async fn preferences(&self, world_id: WorldId) -> Result<SuggestionPreferences, RepoError> {
+        let decisions = self
+            .challenge
+            .list_suggestion_decisions(world_id, MAX_SUGGESTION_DECISIONS_CONSIDERED)
+            .await?;
+        Ok(SuggestionPreferences::from_decisions(&decisions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+    use wrldbldr_domain::{
+        ApprovalDecisionType, ApprovalRequestData, ApprovalUrgency, ChallengeSuggestion,
+        SuggestionDecision, SuggestionVerdict, WorldId,
+    };
+
+    use super::SuggestionFeedback;
+    use crate::entities;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::memory::MemoryQueue;
+    use crate::infrastructure::ports::{ClockPort, MockChallengeRepo, QueuePort};
+
+    #[tokio::test]
+    async fn modified_difficulty_is_recorded_with_its_context() {
+        let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(now));
+        let queue = Arc::new(MemoryQueue::new(clock.clone()));
+        let world_id = WorldId::new();
+
+        let approval = ApprovalRequestData {
+            world_id,
+            source_action_id: Uuid::new_v4(),
+            decision_type: ApprovalDecisionType::NpcResponse,
+            urgency: ApprovalUrgency::Normal,
+            pc_id: None,
+            npc_id: None,
+            npc_name: "Marta".to_string(),
+            proposed_dialogue: "Prove it.".to_string(),
+            internal_reasoning: "".to_string(),
+            proposed_tools: vec![],
+            retry_count: 0,
+            challenge_suggestion: Some(ChallengeSuggestion {
+                challenge_id: Uuid::new_v4().to_string(),
+                challenge_name: "Convince Marta".to_string(),
+                skill_name: "Persuasion".to_string(),
+                difficulty_display: "DC 15".to_string(),
+                confidence: "medium".to_string(),
+                reasoning: "The player is bargaining".to_string(),
+                target_pc_id: None,
+                outcomes: None,
+            }),
+            narrative_event_suggestion: None,
+            challenge_outcome: None,
+            player_dialogue: Some("You owe me one.".to_string()),
+            scene_id: None,
+            location_id: None,
+            game_time: None,
+            topics: vec![],
+            conversation_id: None,
+            safety_warnings: vec![],
+            merged_lines: vec![],
+        };
+        let approval_id = queue.enqueue_dm_approval(&approval).await.unwrap();
+
+        let saved: Arc<Mutex<Option<SuggestionDecision>>> = Arc::new(Mutex::new(None));
+        let saved_for_repo = saved.clone();
+        let mut challenge_repo = MockChallengeRepo::new();
+        challenge_repo
+            .expect_save_suggestion_decision()
+            .returning(move |d| {
+                *saved_for_repo.lock().unwrap() = Some(d.clone());
+                Ok(())
+            });
+
+        let feedback = SuggestionFeedback::new(
+            Arc::new(entities::Challenge::new(Arc::new(challenge_repo))),
+            queue,
+            clock,
+        );
+        feedback
+            .record(approval_id, true, Some("DC 12".to_string()))
+            .await
+            .expect("record")
+            .expect("suggestion present");
+
+        let decision = saved.lock().unwrap().clone().expect("saved");
+        assert_eq!(decision.id, approval_id);
+        assert_eq!(decision.world_id, world_id);
+        assert_eq!(
+            decision.verdict,
+            SuggestionVerdict::Modified {
+                difficulty: "DC 12".to_string()
+            }
+        );
+        assert_eq!(decision.npc_name, "Marta");
+        assert_eq!(decision.player_dialogue.as_deref(), Some("You owe me one."));
+        assert_eq!(decision.decided_at, now);
+    }
+}
@@ -0,0 +1,43 @@
+//! Challenge history.
+//!
+//! Looks back over a world's resolved challenges, for DM review and campaign
+//! statistics.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    ChallengeHistoryStats, ChallengeResolution, PlayerCharacterId, WorldId, MAX_CHALLENGE_HISTORY,
+};
+
+use crate::entities::Challenge;
+use crate::infrastructure::ports::RepoError;
+
+pub struct ChallengeHistory {
+    challenge: Arc<Challenge>,
+}
+
+impl ChallengeHistory {
+    pub fn new(challenge: Arc<Challenge>) -> Self {
+        Self { challenge }
+    }
+
+    /// The world's latest resolutions, newest first, with their totals.
+    ///
+    /// `limit` is capped at `MAX_CHALLENGE_HISTORY`, which is also the default.
+    pub async fn list(
+        &self,
+        world_id: WorldId,
+        pc_id: Option<PlayerCharacterId>,
+        limit: Option<usize>,
+    ) -> Result<(Vec<ChallengeResolution>, ChallengeHistoryStats), RepoError> {
+        let limit = limit
+            .unwrap_or(MAX_CHALLENGE_HISTORY)
+            .min(MAX_CHALLENGE_HISTORY);
+        let resolutions = self
+            .challenge
+            .list_resolutions(world_id, pc_id, limit)
+            .await?;
+        let stats = ChallengeHistoryStats::from_resolutions(&resolutions);
+        Ok((resolutions, stats))
+    }
+}
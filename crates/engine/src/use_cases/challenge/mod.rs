@@ -12,16 +12,27 @@ use std::sync::Arc;
 use uuid::Uuid;
 use wrldbldr_domain::{
     ApprovalDecisionType, ApprovalRequestData, ApprovalUrgency, ChallengeId, ChallengeOutcomeData,
-    DiceRollInput, OutcomeTrigger, OutcomeType, PlayerCharacterId, ProposedTool, WorldId,
+    ChallengeResolution, DiceRollInput, LightLevel, OutcomeTrigger, OutcomeType, PlayerCharacterId,
+    ProposedTool, RegionId, WorldId,
 };
 use wrldbldr_domain::value_objects::DiceParseError;
 
 mod crud;
-
-pub use crud::{ChallengeError as ChallengeCrudError, ChallengeOps};
-
-use crate::entities::{Challenge, Inventory, Observation, PlayerCharacter, Scene};
+mod feedback;
+mod history;
+mod odds;
+
+pub use crud::{challenge_to_json, ChallengeError as ChallengeCrudError, ChallengeOps};
+pub use feedback::SuggestionFeedback;
+pub use history::ChallengeHistory;
+pub use odds::ChallengeOdds;
+
+use crate::entities::{
+    Challenge, Inventory, Location, Observation, PlayerCharacter, ProgressClock, Scene, Staging,
+    World,
+};
 use crate::infrastructure::ports::{ClockPort, QueuePort, RandomPort, RepoError};
+use crate::use_cases::movement::{UnlockConnection, UnlockedConnection};
 
 /// Container for challenge use cases.
 pub struct ChallengeUseCases {
@@ -30,15 +41,22 @@ pub struct ChallengeUseCases {
     pub trigger_prompt: Arc<TriggerChallengePrompt>,
     pub outcome_decision: Arc<OutcomeDecision>,
     pub ops: Arc<ChallengeOps>,
+    pub feedback: Arc<SuggestionFeedback>,
+    pub odds: Arc<ChallengeOdds>,
+    pub history: Arc<ChallengeHistory>,
 }
 
 impl ChallengeUseCases {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         roll: Arc<RollChallenge>,
         resolve: Arc<ResolveOutcome>,
         trigger_prompt: Arc<TriggerChallengePrompt>,
         outcome_decision: Arc<OutcomeDecision>,
         ops: Arc<ChallengeOps>,
+        feedback: Arc<SuggestionFeedback>,
+        odds: Arc<ChallengeOdds>,
+        history: Arc<ChallengeHistory>,
     ) -> Self {
         Self {
             roll,
@@ -46,6 +64,9 @@ impl ChallengeUseCases {
             trigger_prompt,
             outcome_decision,
             ops,
+            feedback,
+            odds,
+            history,
         }
     }
 }
@@ -137,15 +158,22 @@ impl TriggerChallengePrompt {
 pub struct RollChallenge {
     challenge: Arc<Challenge>,
     player_character: Arc<PlayerCharacter>,
+    location: Arc<Location>,
+    world: Arc<World>,
+    staging: Arc<Staging>,
     queue: Arc<dyn QueuePort>,
     random: Arc<dyn RandomPort>,
     clock: Arc<dyn ClockPort>,
 }
 
 impl RollChallenge {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         challenge: Arc<Challenge>,
         player_character: Arc<PlayerCharacter>,
+        location: Arc<Location>,
+        world: Arc<World>,
+        staging: Arc<Staging>,
         queue: Arc<dyn QueuePort>,
         random: Arc<dyn RandomPort>,
         clock: Arc<dyn ClockPort>,
@@ -153,12 +181,48 @@ impl RollChallenge {
         Self {
             challenge,
             player_character,
+            location,
+            world,
+            staging,
             queue,
             random,
             clock,
         }
     }
 
+    /// The light a perception check is made in, and the names of NPCs
+    /// hiding there
+    ///
+    /// None when the PC isn't in a region.
+    async fn perception_light(
+        &self,
+        world_id: WorldId,
+        region_id: Option<RegionId>,
+    ) -> Result<Option<(LightLevel, Vec<String>)>, RepoError> {
+        let Some(region_id) = region_id else {
+            return Ok(None);
+        };
+        let Some(region) = self.location.get_region(region_id).await? else {
+            return Ok(None);
+        };
+        let Some(world) = self.world.get(world_id).await? else {
+            return Ok(None);
+        };
+        let light = self
+            .location
+            .region_light(&region, world.game_time.time_of_day())
+            .await?;
+        let hidden = self
+            .staging
+            .resolve_for_region_dm_view(region_id)
+            .await?
+            .into_iter()
+            .filter(|npc| npc.is_hidden_from_players)
+            .map(|npc| npc.name)
+            .collect();
+        Ok(Some((light, hidden)))
+    }
+
     /// Execute a challenge roll.
     ///
     /// # Arguments
@@ -213,7 +277,27 @@ impl RollChallenge {
             }
         };
 
-        // 4. Evaluate the roll
+        // 4. Evaluate the roll; perception checks are made in the region's light
+        let light = if challenge.is_perception_check() {
+            self.perception_light(world_id, pc.current_region_id).await?
+        } else {
+            None
+        };
+        let light_modifier = light
+            .as_ref()
+            .map_or(0, |(level, _)| level.perception_modifier());
+        let roll_breakdown = match &light {
+            Some((level, _)) if light_modifier != 0 => format!(
+                "d20({}) + modifier({}) + {} light({}) = {}",
+                roll,
+                modifier,
+                level.display_name().to_lowercase(),
+                light_modifier,
+                roll + modifier + light_modifier
+            ),
+            _ => format!("d20({}) + modifier({}) = {}", roll, modifier, roll + modifier),
+        };
+        let modifier = modifier + light_modifier;
         let (outcome_type, outcome) = challenge.evaluate_roll(roll, modifier);
         let total = roll + modifier;
 
@@ -242,10 +326,7 @@ impl RollChallenge {
                     arguments: serde_json::Value::Null,
                 })
                 .collect(),
-            roll_breakdown: Some(format!(
-                "d20({}) + modifier({}) = {}",
-                roll, modifier, total
-            )),
+            roll_breakdown: Some(roll_breakdown),
             timestamp: self.clock.now(),
             suggestions: None,
             is_generating_suggestions: false,
@@ -262,8 +343,13 @@ impl RollChallenge {
             npc_name: String::new(),
             proposed_dialogue: outcome.description.clone(),
             internal_reasoning: format!(
-                "Challenge '{}' - Roll: {} + {} = {} -> {}",
-                challenge.name, roll, modifier, total, outcome_type
+                "Challenge '{}' - Roll: {} + {} = {} -> {}{}",
+                challenge.name,
+                roll,
+                modifier,
+                total,
+                outcome_type,
+                light.as_ref().map(light_note).unwrap_or_default()
             ),
             proposed_tools: outcome_data.outcome_triggers.clone(),
             retry_count: 0,
@@ -276,6 +362,8 @@ impl RollChallenge {
             game_time: None,
             topics: vec![],
             conversation_id: None, // Challenges don't have conversation context
+            safety_warnings: vec![],
+            merged_lines: vec![],
         };
 
         let approval_queue_id = self
@@ -309,20 +397,43 @@ impl RollChallenge {
         input: DiceRollInput,
     ) -> Result<RollResult, ChallengeError> {
         let roll_result = input
-            .resolve(|min, max| self.random.gen_range(min, max))
+            .roll(0, |min, max| self.random.gen_range(min, max))
             .map_err(ChallengeError::DiceParse)?;
 
         self.execute(
             world_id,
             challenge_id,
             pc_id,
-            Some(roll_result.dice_total),
-            roll_result.modifier_applied,
+            Some(roll_result.dice_total()),
+            roll_result.modifier(),
         )
         .await
     }
 }
 
+/// What the DM should know about the light a perception check was made in
+fn light_note((level, hidden): &(LightLevel, Vec<String>)) -> String {
+    let mut note = format!(
+        "\n{} light ({:+} to perception)",
+        level.display_name(),
+        level.perception_modifier()
+    );
+    if !hidden.is_empty() {
+        if level.reveals_hidden() {
+            note.push_str(&format!(
+                "\nHidden here, a success may spot: {}",
+                hidden.join(", ")
+            ));
+        } else {
+            note.push_str(&format!(
+                "\nToo dark to spot anyone hidden ({})",
+                hidden.join(", ")
+            ));
+        }
+    }
+    note
+}
+
 /// Resolve challenge outcome use case.
 ///
 /// Called after DM approves the outcome to execute triggers.
@@ -332,6 +443,7 @@ pub struct ResolveOutcome {
     observation: Arc<Observation>,
     scene: Arc<Scene>,
     player_character: Arc<PlayerCharacter>,
+    progress_clock: Arc<ProgressClock>,
 }
 
 impl ResolveOutcome {
@@ -341,6 +453,7 @@ impl ResolveOutcome {
         observation: Arc<Observation>,
         scene: Arc<Scene>,
         player_character: Arc<PlayerCharacter>,
+        progress_clock: Arc<ProgressClock>,
     ) -> Self {
         Self {
             challenge,
@@ -348,18 +461,21 @@ impl ResolveOutcome {
             observation,
             scene,
             player_character,
+            progress_clock,
         }
     }
 
     /// Execute the approved outcome with a known target PC.
     ///
     /// This variant is used when we know which PC attempted the challenge.
+    /// Returns the challenge as resolved and any progress clocks that were
+    /// ticked so callers can broadcast them.
     pub async fn execute_for_pc(
         &self,
         challenge_id: ChallengeId,
         outcome_type: OutcomeType,
         target_pc_id: PlayerCharacterId,
-    ) -> Result<(), ChallengeError> {
+    ) -> Result<ResolvedOutcome, ChallengeError> {
         // Get the challenge to access its outcomes
         let challenge = self
             .challenge
@@ -368,30 +484,19 @@ impl ResolveOutcome {
             .ok_or(ChallengeError::NotFound)?;
 
         // Find the matching outcome based on outcome_type
-        let outcome = match outcome_type {
-            OutcomeType::CriticalSuccess => challenge
-                .outcomes
-                .critical_success
-                .as_ref()
-                .unwrap_or(&challenge.outcomes.success),
-            OutcomeType::Success => &challenge.outcomes.success,
-            OutcomeType::Partial => challenge
-                .outcomes
-                .partial
-                .as_ref()
-                .unwrap_or(&challenge.outcomes.success),
-            OutcomeType::Failure => &challenge.outcomes.failure,
-            OutcomeType::CriticalFailure => challenge
-                .outcomes
-                .critical_failure
-                .as_ref()
-                .unwrap_or(&challenge.outcomes.failure),
-        };
+        let outcome = challenge.outcome_for_type(outcome_type);
 
         // Execute each trigger in the outcome
+        let mut ticked_clocks = Vec::new();
         for trigger in &outcome.triggers {
-            self.execute_trigger(trigger, &challenge.name, challenge.world_id, target_pc_id)
-                .await
+            self.execute_trigger(
+                trigger,
+                &challenge.name,
+                challenge.world_id,
+                target_pc_id,
+                &mut ticked_clocks,
+            )
+            .await
                 .map_err(|e| {
                     tracing::error!(
                         challenge = %challenge.name,
@@ -405,7 +510,10 @@ impl ResolveOutcome {
         // Mark the challenge as resolved
         self.challenge.mark_resolved(challenge_id).await?;
 
-        Ok(())
+        Ok(ResolvedOutcome {
+            challenge,
+            ticked_clocks,
+        })
     }
 
     /// Execute a single outcome trigger.
@@ -415,12 +523,14 @@ impl ResolveOutcome {
     /// * `challenge_name` - For logging context
     /// * `world_id` - The world context for the trigger
     /// * `target_pc_id` - The player character affected by this trigger (if applicable)
+    /// * `ticked_clocks` - Collects progress clocks advanced by this trigger
     async fn execute_trigger(
         &self,
         trigger: &OutcomeTrigger,
         challenge_name: &str,
         world_id: WorldId,
         target_pc_id: PlayerCharacterId,
+        ticked_clocks: &mut Vec<wrldbldr_domain::ProgressClock>,
     ) -> Result<(), ChallengeError> {
         match trigger {
             OutcomeTrigger::RevealInformation { info, persist } => {
@@ -525,6 +635,25 @@ impl ResolveOutcome {
                 }
                 Ok(())
             }
+            OutcomeTrigger::TickClock { clock_id, amount } => {
+                tracing::info!(
+                    challenge = %challenge_name,
+                    clock_id = %clock_id,
+                    amount = %amount,
+                    "Ticking progress clock"
+                );
+
+                match self.progress_clock.tick(*clock_id, *amount).await {
+                    Ok((clock, tick)) => {
+                        if tick.just_completed {
+                            tracing::info!(clock = %clock.name, "Progress clock completed");
+                        }
+                        ticked_clocks.push(clock);
+                    }
+                    Err(e) => tracing::warn!(error = %e, "Failed to tick progress clock"),
+                }
+                Ok(())
+            }
             OutcomeTrigger::Custom { description } => {
                 // Custom triggers are logged for DM reference but not automatically executed
                 tracing::info!(
@@ -538,15 +667,76 @@ impl ResolveOutcome {
     }
 }
 
+/// What executing an approved outcome did
+pub struct ResolvedOutcome {
+    /// The challenge as it was when resolved
+    pub challenge: wrldbldr_domain::Challenge,
+    /// Progress clocks advanced by the outcome's triggers
+    pub ticked_clocks: Vec<wrldbldr_domain::ProgressClock>,
+}
+
 /// Decision flow for challenge outcome approvals.
 pub struct OutcomeDecision {
     queue: Arc<dyn QueuePort>,
     resolve: Arc<ResolveOutcome>,
+    unlock_connection: Arc<UnlockConnection>,
+    challenge: Arc<Challenge>,
+    clock: Arc<dyn ClockPort>,
 }
 
 impl OutcomeDecision {
-    pub fn new(queue: Arc<dyn QueuePort>, resolve: Arc<ResolveOutcome>) -> Self {
-        Self { queue, resolve }
+    pub fn new(
+        queue: Arc<dyn QueuePort>,
+        resolve: Arc<ResolveOutcome>,
+        unlock_connection: Arc<UnlockConnection>,
+        challenge: Arc<Challenge>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            queue,
+            resolve,
+            unlock_connection,
+            challenge,
+            clock,
+        }
+    }
+
+    /// Keep the resolution in the world's challenge history. The challenge
+    /// is already resolved, so a failure here is logged rather than returned.
+    async fn record_resolution(&self, resolution: ChallengeResolution) {
+        if let Err(e) = self.challenge.save_resolution(&resolution).await {
+            tracing::warn!(
+                challenge_id = %resolution.challenge_id,
+                error = %e,
+                "Failed to record challenge resolution"
+            );
+        }
+    }
+
+    /// Open the locks around the PC that a successful challenge is linked
+    /// to. The challenge is already resolved, so a failure here is logged
+    /// rather than returned.
+    async fn open_linked_locks(
+        &self,
+        pc_id: PlayerCharacterId,
+        challenge_id: ChallengeId,
+        challenge_name: &str,
+        outcome_type: &OutcomeType,
+    ) -> Vec<UnlockedConnection> {
+        if !outcome_type.is_success() {
+            return Vec::new();
+        }
+        self.unlock_connection
+            .with_challenge(pc_id, challenge_id, challenge_name)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(
+                    challenge_id = %challenge_id,
+                    error = %e,
+                    "Failed to open locks linked to challenge"
+                );
+                Vec::new()
+            })
     }
 
     pub async fn execute(
@@ -576,10 +766,29 @@ impl OutcomeDecision {
         match decision {
             wrldbldr_protocol::ChallengeOutcomeDecisionData::Accept => {
                 let pc_id = approval_data.pc_id.ok_or(OutcomeDecisionError::MissingPcId)?;
-                self.resolve
+                let resolved = self
+                    .resolve
                     .execute_for_pc(challenge_id, outcome_type.clone(), pc_id)
                     .await
                     .map_err(OutcomeDecisionError::Resolve)?;
+                self.record_resolution(resolution_record(
+                    approval_id,
+                    &outcome_data,
+                    pc_id,
+                    &resolved.challenge,
+                    outcome_type,
+                    self.clock.now(),
+                ))
+                .await;
+                let ticked_clocks = resolved.ticked_clocks;
+                let unlocked_connections = self
+                    .open_linked_locks(
+                        pc_id,
+                        challenge_id,
+                        &outcome_data.challenge_name,
+                        &outcome_type,
+                    )
+                    .await;
 
                 // Challenge is now resolved. Queue cleanup is housekeeping - log failure
                 // but return success since the important operation completed.
@@ -593,8 +802,9 @@ impl OutcomeDecision {
                     );
                 }
 
-                Ok(OutcomeDecisionResult::Resolved(ChallengeResolvedPayload {
+                Ok(OutcomeDecisionResult::Resolved(Box::new(ChallengeResolvedPayload {
                     challenge_id: outcome_data.challenge_id.clone(),
+                    pc_id,
                     challenge_name: outcome_data.challenge_name.clone(),
                     character_name: outcome_data.character_name.clone(),
                     roll: outcome_data.roll,
@@ -603,14 +813,39 @@ impl OutcomeDecision {
                     outcome: outcome_type_to_str(&outcome_type).to_string(),
                     outcome_description: outcome_data.outcome_description.clone(),
                     roll_breakdown: outcome_data.roll_breakdown.clone(),
-                }))
+                    ticked_clocks,
+                    unlocked_connections,
+                })))
             }
             wrldbldr_protocol::ChallengeOutcomeDecisionData::Edit { modified_description } => {
                 let pc_id = approval_data.pc_id.ok_or(OutcomeDecisionError::MissingPcId)?;
-                self.resolve
+                let resolved = self
+                    .resolve
                     .execute_for_pc(challenge_id, outcome_type.clone(), pc_id)
                     .await
                     .map_err(OutcomeDecisionError::Resolve)?;
+                self.record_resolution(ChallengeResolution {
+                    outcome_description: modified_description.clone(),
+                    edited: true,
+                    ..resolution_record(
+                        approval_id,
+                        &outcome_data,
+                        pc_id,
+                        &resolved.challenge,
+                        outcome_type,
+                        self.clock.now(),
+                    )
+                })
+                .await;
+                let ticked_clocks = resolved.ticked_clocks;
+                let unlocked_connections = self
+                    .open_linked_locks(
+                        pc_id,
+                        challenge_id,
+                        &outcome_data.challenge_name,
+                        &outcome_type,
+                    )
+                    .await;
 
                 // Challenge is now resolved. Queue cleanup is housekeeping - log failure
                 // but return success since the important operation completed.
@@ -624,8 +859,9 @@ impl OutcomeDecision {
                     );
                 }
 
-                Ok(OutcomeDecisionResult::Resolved(ChallengeResolvedPayload {
+                Ok(OutcomeDecisionResult::Resolved(Box::new(ChallengeResolvedPayload {
                     challenge_id: outcome_data.challenge_id.clone(),
+                    pc_id,
                     challenge_name: outcome_data.challenge_name.clone(),
                     character_name: outcome_data.character_name.clone(),
                     roll: outcome_data.roll,
@@ -634,7 +870,9 @@ impl OutcomeDecision {
                     outcome: outcome_type_to_str(&outcome_type).to_string(),
                     outcome_description: modified_description,
                     roll_breakdown: outcome_data.roll_breakdown.clone(),
-                }))
+                    ticked_clocks,
+                    unlocked_connections,
+                })))
             }
             wrldbldr_protocol::ChallengeOutcomeDecisionData::Suggest { guidance } => {
                 let llm_request = wrldbldr_domain::LlmRequestData {
@@ -665,6 +903,8 @@ impl OutcomeDecision {
                     }),
                     callback_id: format!("outcome_suggestion:{}", approval_id),
                     conversation_id: None,
+                    retry_count: 0,
+                    merged_lines: vec![],
                 };
 
                 self.queue
@@ -682,12 +922,13 @@ impl OutcomeDecision {
 }
 
 pub enum OutcomeDecisionResult {
-    Resolved(ChallengeResolvedPayload),
+    Resolved(Box<ChallengeResolvedPayload>),
     Queued,
 }
 
 pub struct ChallengeResolvedPayload {
     pub challenge_id: String,
+    pub pc_id: PlayerCharacterId,
     pub challenge_name: String,
     pub character_name: String,
     pub roll: i32,
@@ -696,6 +937,10 @@ pub struct ChallengeResolvedPayload {
     pub outcome: String,
     pub outcome_description: String,
     pub roll_breakdown: Option<String>,
+    /// Progress clocks advanced by the outcome's triggers
+    pub ticked_clocks: Vec<wrldbldr_domain::ProgressClock>,
+    /// Locks around the PC opened by succeeding at the challenge
+    pub unlocked_connections: Vec<UnlockedConnection>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -718,6 +963,39 @@ pub enum OutcomeDecisionError {
     Resolve(#[from] ChallengeError),
 }
 
+/// The history record of an approved outcome, narrated as rolled
+fn resolution_record(
+    approval_id: Uuid,
+    outcome_data: &ChallengeOutcomeData,
+    pc_id: PlayerCharacterId,
+    challenge: &wrldbldr_domain::Challenge,
+    outcome_type: OutcomeType,
+    resolved_at: chrono::DateTime<chrono::Utc>,
+) -> ChallengeResolution {
+    ChallengeResolution {
+        id: approval_id,
+        world_id: challenge.world_id,
+        challenge_id: challenge.id,
+        challenge_name: outcome_data.challenge_name.clone(),
+        skill_name: outcome_data
+            .skill_name
+            .clone()
+            .or_else(|| challenge.check_stat.clone()),
+        difficulty: challenge.difficulty.display(),
+        pc_id,
+        character_name: outcome_data.character_name.clone(),
+        roll: outcome_data.roll,
+        modifier: outcome_data.modifier,
+        total: outcome_data.total,
+        roll_breakdown: outcome_data.roll_breakdown.clone(),
+        outcome: outcome_type,
+        outcome_description: outcome_data.outcome_description.clone(),
+        edited: false,
+        triggers: challenge.outcome_for_type(outcome_type).triggers.clone(),
+        resolved_at,
+    }
+}
+
 fn parse_challenge_id_str(id_str: &str) -> Option<ChallengeId> {
     Uuid::parse_str(id_str)
         .ok()
@@ -782,13 +1060,13 @@ mod tests {
     use wrldbldr_domain::{
         Challenge as DomainChallenge, ChallengeId, ChallengeOutcomes, Difficulty, ItemId,
         LocationId, Outcome, OutcomeTrigger, OutcomeType, PlayerCharacter as DomainPc,
-        PlayerCharacterId, SceneId, WorldId,
+        PlayerCharacterId, ProgressClock as DomainClock, SceneId, WorldId,
     };
 
     use crate::entities;
     use crate::infrastructure::ports::{
         ClockPort, MockChallengeRepo, MockCharacterRepo, MockItemRepo, MockLocationRepo,
-        MockObservationRepo, MockPlayerCharacterRepo, MockSceneRepo,
+        MockObservationRepo, MockPlayerCharacterRepo, MockProgressClockRepo, MockSceneRepo,
     };
 
     struct FixedClock(chrono::DateTime<chrono::Utc>);
@@ -808,6 +1086,8 @@ mod tests {
         let now = Utc::now();
 
         let pc = DomainPc::new("user-1", world_id, "PC", LocationId::new(), now);
+        let progress_clock = DomainClock::new(world_id, "Alarm", 4, now).unwrap();
+        let clock_id = progress_clock.id;
 
         let success_outcome = Outcome::new("success")
             .with_trigger(OutcomeTrigger::reveal_persistent("secret"))
@@ -816,7 +1096,8 @@ mod tests {
                 item_description: Some("Rusty".to_string()),
             })
             .with_trigger(OutcomeTrigger::modify_stat("hp", -1))
-            .with_trigger(OutcomeTrigger::scene(scene_id));
+            .with_trigger(OutcomeTrigger::scene(scene_id))
+            .with_trigger(OutcomeTrigger::tick_clock(clock_id, 2));
 
         let outcomes = ChallengeOutcomes {
             success: success_outcome,
@@ -897,6 +1178,19 @@ mod tests {
             .withf(move |w, s| *w == world_id && *s == scene_id)
             .returning(|_, _| Ok(()));
 
+        // ---------------------------------------------------------------------
+        // Progress clock expectations
+        // ---------------------------------------------------------------------
+        let mut clock_repo = MockProgressClockRepo::new();
+        clock_repo
+            .expect_get()
+            .withf(move |id| *id == clock_id)
+            .returning(move |_| Ok(Some(progress_clock.clone())));
+        clock_repo
+            .expect_save()
+            .withf(|clock| clock.filled == 2)
+            .returning(|_| Ok(()));
+
         // Observation entity needs LocationRepo + ClockPort, but this test only
         // exercises record_deduced_info, so provide dummies.
         let location_repo = MockLocationRepo::new();
@@ -916,8 +1210,10 @@ mod tests {
         let observation_entity = Arc::new(entities::Observation::new(
             Arc::new(observation_repo),
             Arc::new(location_repo),
-            clock,
+            clock.clone(),
         ));
+        let progress_clock_entity =
+            Arc::new(entities::ProgressClock::new(Arc::new(clock_repo), clock));
         let scene_entity = Arc::new(entities::Scene::new(Arc::new(scene_repo)));
         let player_character_entity = Arc::new(entities::PlayerCharacter::new(pc_repo));
 
@@ -927,11 +1223,15 @@ mod tests {
             observation_entity,
             scene_entity,
             player_character_entity,
+            progress_clock_entity,
         );
 
-        resolve
+        let resolved = resolve
             .execute_for_pc(challenge_id, OutcomeType::Success, pc_id)
             .await
             .expect("resolve outcome should succeed");
+        let ticked = resolved.ticked_clocks;
+        assert_eq!(ticked.len(), 1);
+        assert_eq!(ticked[0].filled, 2);
     }
 }
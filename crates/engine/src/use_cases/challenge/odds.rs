@@ -0,0 +1,142 @@
+//! Party odds for suggested challenges.
+//!
+//! Works out each active PC's modifier for a suggested challenge's skill and
+//! their chance of passing it, so the DM can set difficulty from numbers.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    self as domain, ChallengeId, ChallengeSuggestion, Difficulty, PlayerCharacterId, WorldId,
+};
+
+use crate::entities::{Challenge, PlayerCharacter};
+use crate::infrastructure::ports::RepoError;
+
+/// One PC's odds on a challenge
+#[derive(Debug, Clone, PartialEq)]
+pub struct PcOdds {
+    pub pc_id: PlayerCharacterId,
+    pub pc_name: String,
+    pub modifier: i32,
+    /// Whole percent; `None` when the DM adjudicates
+    pub success_percent: Option<u8>,
+}
+
+pub struct ChallengeOdds {
+    challenge: Arc<Challenge>,
+    player_character: Arc<PlayerCharacter>,
+}
+
+impl ChallengeOdds {
+    pub fn new(challenge: Arc<Challenge>, player_character: Arc<PlayerCharacter>) -> Self {
+        Self {
+            challenge,
+            player_character,
+        }
+    }
+
+    /// Odds for every active PC in the world at the suggested difficulty.
+    ///
+    /// The suggestion's skill and difficulty win over the stored challenge's,
+    /// which only fills in what the suggestion leaves out (or can't be parsed).
+    pub async fn for_suggestion(
+        &self,
+        world_id: WorldId,
+        suggestion: &ChallengeSuggestion,
+    ) -> Result<Vec<PcOdds>, RepoError> {
+        let stored = match uuid::Uuid::parse_str(&suggestion.challenge_id) {
+            Ok(id) => self.challenge.get(ChallengeId::from(id)).await?,
+            Err(_) => None,
+        };
+        let suggested = Difficulty::parse(&suggestion.difficulty_display);
+        let mut challenge = stored.unwrap_or_else(|| {
+            domain::Challenge::new(world_id, &suggestion.challenge_name, suggested.clone())
+        });
+        if !matches!(suggested, Difficulty::Custom(_)) {
+            challenge.difficulty = suggested;
+        }
+        let skill = Some(suggestion.skill_name.trim())
+            .filter(|s| !s.is_empty())
+            .or(challenge.check_stat.as_deref());
+
+        let pcs = self.player_character.list_in_world(world_id).await?;
+        Ok(pcs
+            .into_iter()
+            .filter(|pc| pc.is_active && pc.is_alive)
+            .map(|pc| {
+                // Same lookup as the roll itself, falling back to a skill
+                // entry whose ID matches the skill's name
+                let modifier = skill
+                    .zip(pc.sheet_data.as_ref())
+                    .and_then(|(s, sheet)| {
+                        sheet
+                            .get_numeric_value(s)
+                            .or_else(|| sheet.get_skill_modifier_by_name(s))
+                    })
+                    .unwrap_or(0);
+                PcOdds {
+                    pc_id: pc.id,
+                    pc_name: pc.name,
+                    modifier,
+                    success_percent: challenge.success_chance(modifier),
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use wrldbldr_domain::{
+        ChallengeSuggestion, CharacterSheetData, FieldValue, LocationId,
+        PlayerCharacter as DomainPc, WorldId,
+    };
+
+    use super::ChallengeOdds;
+    use crate::entities;
+    use crate::infrastructure::ports::{MockChallengeRepo, MockPlayerCharacterRepo};
+
+    #[tokio::test]
+    async fn odds_follow_each_pcs_skill() {
+        let world_id = WorldId::new();
+        let now = Utc::now();
+
+        let mut sharp = DomainPc::new("user-1", world_id, "Sharp", LocationId::new(), now);
+        let mut sheet = CharacterSheetData::new();
+        sheet.set("Perception", FieldValue::Number(5));
+        sharp.sheet_data = Some(sheet);
+        let dull = DomainPc::new("user-2", world_id, "Dull", LocationId::new(), now);
+        let mut gone = DomainPc::new("user-3", world_id, "Gone", LocationId::new(), now);
+        gone.is_active = false;
+
+        let mut pc_repo = MockPlayerCharacterRepo::new();
+        pc_repo
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![sharp.clone(), dull.clone(), gone.clone()]));
+
+        let odds = ChallengeOdds::new(
+            Arc::new(entities::Challenge::new(Arc::new(MockChallengeRepo::new()))),
+            Arc::new(entities::PlayerCharacter::new(Arc::new(pc_repo))),
+        );
+        let suggestion = ChallengeSuggestion {
+            challenge_id: String::new(),
+            challenge_name: "Spot the ambush".to_string(),
+            skill_name: "Perception".to_string(),
+            difficulty_display: "DC 15".to_string(),
+            confidence: "high".to_string(),
+            reasoning: String::new(),
+            target_pc_id: None,
+            outcomes: None,
+        };
+
+        let party = odds.for_suggestion(world_id, &suggestion).await.unwrap();
+        let summary: Vec<(&str, i32, Option<u8>)> = party
+            .iter()
+            .map(|o| (o.pc_name.as_str(), o.modifier, o.success_percent))
+            .collect();
+        assert_eq!(summary, vec![("Sharp", 5, Some(55)), ("Dull", 0, Some(30))]);
+    }
+}
@@ -0,0 +1,371 @@
+//! Chronology use cases.
+//!
+//! Character birthdates and lore event years on the game calendar. Ages are
+//! computed from the world's current game date, and lore that mentions a
+//! character in a year before their birth is reported as a chronology error.
+//! When the world's age progression feature is on, long time skips produce
+//! the list of characters who aged, for the DM.
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use wrldbldr_domain::{
+    age_progressions, find_chronology_issues, AgeProgression, CharacterId, ChronologyIssue,
+    ExperimentalFeature, LifeStage, LoreId, WorldId, AGE_PROGRESSION_MIN_SKIP_DAYS,
+};
+use wrldbldr_protocol::types::{
+    AgeProgressionData, CharacterAgeData, ChronologyIssueData, ChronologyIssueKindData,
+    ChronologyReportData, LifeStageData, LoreDateData,
+};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for chronology use cases.
+pub struct ChronologyUseCases {
+    pub manage: Arc<ManageChronology>,
+}
+
+impl ChronologyUseCases {
+    pub fn new(manage: Arc<ManageChronology>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChronologyError {
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("Character not found")]
+    CharacterNotFound,
+    #[error("Lore not found")]
+    LoreNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+/// Report ages and chronology errors, edit birthdates and lore years, and
+/// work out who aged during a time skip.
+pub struct ManageChronology {
+    world: Arc<entities::World>,
+    character: Arc<entities::Character>,
+    lore: Arc<entities::Lore>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageChronology {
+    pub fn new(
+        world: Arc<entities::World>,
+        character: Arc<entities::Character>,
+        lore: Arc<entities::Lore>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            world,
+            character,
+            lore,
+            clock,
+        }
+    }
+
+    /// Every character's age, every lore date and the chronology errors
+    pub async fn report(&self, world_id: WorldId) -> Result<ChronologyReportData, ChronologyError> {
+        let today = self.today(world_id).await?;
+        let characters = self.character.list_in_world(world_id).await?;
+        let lore = self.lore.list_for_world(world_id).await?;
+
+        Ok(ChronologyReportData {
+            current_date: today.to_string(),
+            characters: characters
+                .iter()
+                .map(|c| {
+                    let age = c.age_on(today);
+                    CharacterAgeData {
+                        character_id: c.id.to_string(),
+                        name: c.name.clone(),
+                        birth_date: c.birth_date.map(|d| d.to_string()),
+                        age,
+                        life_stage: age.map(|a| life_stage_to_protocol(LifeStage::for_age(a))),
+                    }
+                })
+                .collect(),
+            lore: lore
+                .iter()
+                .map(|l| LoreDateData {
+                    lore_id: l.id.to_string(),
+                    title: l.title.clone(),
+                    event_year: l.event_year,
+                })
+                .collect(),
+            issues: find_chronology_issues(&characters, &lore, today)
+                .iter()
+                .map(issue_to_protocol)
+                .collect(),
+        })
+    }
+
+    /// Set or clear a character's birthdate (YYYY-MM-DD)
+    pub async fn set_birth_date(
+        &self,
+        world_id: WorldId,
+        character_id: CharacterId,
+        birth_date: Option<&str>,
+    ) -> Result<ChronologyReportData, ChronologyError> {
+        let birth_date = birth_date
+            .map(|value| {
+                NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map_err(|_| {
+                    ChronologyError::Invalid(format!(
+                        "Birthdate must be YYYY-MM-DD, got '{}'",
+                        value
+                    ))
+                })
+            })
+            .transpose()?;
+
+        let mut character = self
+            .character
+            .get(character_id)
+            .await?
+            .filter(|c| c.world_id == world_id)
+            .ok_or(ChronologyError::CharacterNotFound)?;
+        character.birth_date = birth_date;
+        self.character.save(&character).await?;
+
+        self.report(world_id).await
+    }
+
+    /// Set or clear the year a lore entry's events took place
+    pub async fn set_lore_event_year(
+        &self,
+        world_id: WorldId,
+        lore_id: LoreId,
+        event_year: Option<i32>,
+    ) -> Result<ChronologyReportData, ChronologyError> {
+        let mut lore = self
+            .lore
+            .get(lore_id)
+            .await?
+            .filter(|l| l.world_id == world_id)
+            .ok_or(ChronologyError::LoreNotFound)?;
+        lore.event_year = event_year;
+        lore.updated_at = self.clock.now();
+        self.lore.save(&lore).await?;
+
+        self.report(world_id).await
+    }
+
+    /// Characters who aged over the last `minutes_advanced` of game time.
+    ///
+    /// Empty unless the world has age progression switched on and the
+    /// advance is at least `AGE_PROGRESSION_MIN_SKIP_DAYS` long; call it
+    /// after the advance has been saved.
+    pub async fn progressions_for_skip(
+        &self,
+        world_id: WorldId,
+        minutes_advanced: u32,
+    ) -> Result<Vec<AgeProgressionData>, ChronologyError> {
+        if i64::from(minutes_advanced) < AGE_PROGRESSION_MIN_SKIP_DAYS * 24 * 60 {
+            return Ok(Vec::new());
+        }
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ChronologyError::WorldNotFound)?;
+        if !world
+            .features
+            .is_enabled(ExperimentalFeature::AgeProgression)
+        {
+            return Ok(Vec::new());
+        }
+
+        let to = world.game_time.current();
+        let from = to - chrono::Duration::minutes(i64::from(minutes_advanced));
+        let characters = self.character.list_in_world(world_id).await?;
+        Ok(
+            age_progressions(&characters, from.date_naive(), to.date_naive())
+                .iter()
+                .map(progression_to_protocol)
+                .collect(),
+        )
+    }
+
+    async fn today(&self, world_id: WorldId) -> Result<NaiveDate, ChronologyError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ChronologyError::WorldNotFound)?;
+        Ok(world.game_time.current().date_naive())
+    }
+}
+
+fn life_stage_to_protocol(stage: LifeStage) -> LifeStageData {
+    match stage {
+        LifeStage::Child => LifeStageData::Child,
+        LifeStage::Adolescent => LifeStageData::Adolescent,
+        LifeStage::Adult => LifeStageData::Adult,
+        LifeStage::MiddleAged => LifeStageData::MiddleAged,
+        LifeStage::Elder => LifeStageData::Elder,
+    }
+}
+
+fn issue_to_protocol(issue: &ChronologyIssue) -> ChronologyIssueData {
+    let (kind, lore_id) = match issue {
+        ChronologyIssue::MentionedBeforeBirth { lore_id, .. } => (
+            ChronologyIssueKindData::MentionedBeforeBirth,
+            Some(lore_id.to_string()),
+        ),
+        ChronologyIssue::BornInFuture { .. } => (ChronologyIssueKindData::BornInFuture, None),
+    };
+    ChronologyIssueData {
+        kind,
+        character_id: issue.character_id().to_string(),
+        lore_id,
+        message: issue.message(),
+    }
+}
+
+fn progression_to_protocol(progression: &AgeProgression) -> AgeProgressionData {
+    AgeProgressionData {
+        character_id: progression.character_id.to_string(),
+        name: progression.character_name.clone(),
+        previous_age: progression.previous_age,
+        new_age: progression.new_age,
+        life_stage: life_stage_to_protocol(progression.new_stage()),
+        stage_changed: progression.stage_changed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{MockCharacterRepo, MockLoreRepo, MockWorldRepo};
+    use chrono::{TimeZone, Utc};
+    use wrldbldr_domain::{CampbellArchetype, GameTime, World, WorldFeatures};
+
+    fn world_on(date: NaiveDate, age_progression: bool) -> World {
+        let now = Utc::now();
+        let mut world = World::new("Varn", "", now);
+        world.game_time =
+            GameTime::starting_at(Utc.from_utc_datetime(&date.and_hms_opt(8, 0, 0).unwrap()));
+        world.features = WorldFeatures {
+            age_progression,
+            ..Default::default()
+        };
+        world
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn chronology(
+        world: World,
+        characters: MockCharacterRepo,
+        lore: MockLoreRepo,
+    ) -> ManageChronology {
+        let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(Utc::now()));
+        let mut worlds = MockWorldRepo::new();
+        worlds
+            .expect_get()
+            .returning(move |_| Ok(Some(world.clone())));
+        ManageChronology::new(
+            Arc::new(entities::World::new(Arc::new(worlds), clock.clone())),
+            Arc::new(entities::Character::new(Arc::new(characters))),
+            Arc::new(entities::Lore::new(Arc::new(lore))),
+            clock,
+        )
+    }
+
+    #[tokio::test]
+    async fn report_computes_ages_from_the_game_date() {
+        let world = world_on(date(1490, 3, 1), false);
+        let world_id = world.id;
+        let mira = wrldbldr_domain::Character::new(world_id, "Mira", CampbellArchetype::Mentor)
+            .with_birth_date(date(1450, 6, 15));
+        let mut characters = MockCharacterRepo::new();
+        characters
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![mira.clone()]));
+        let mut lore = MockLoreRepo::new();
+        lore.expect_list_for_world().returning(|_| Ok(vec![]));
+
+        let report = chronology(world, characters, lore)
+            .report(world_id)
+            .await
+            .expect("report");
+
+        assert_eq!(report.current_date, "1490-03-01");
+        assert_eq!(report.characters[0].age, Some(39));
+        assert_eq!(report.characters[0].life_stage, Some(LifeStageData::Adult));
+        assert!(report.issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn an_invalid_birthdate_is_rejected_before_loading_anything() {
+        let world = world_on(date(1490, 3, 1), false);
+        let world_id = world.id;
+        let mut characters = MockCharacterRepo::new();
+        characters.expect_get().never();
+        characters.expect_save().never();
+
+        let result = chronology(world, characters, MockLoreRepo::new())
+            .set_birth_date(world_id, CharacterId::new(), Some("15/06/1450"))
+            .await;
+
+        assert!(matches!(result, Err(ChronologyError::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn short_advances_and_disabled_worlds_report_no_aging() {
+        let world_id = WorldId::new();
+        let month = AGE_PROGRESSION_MIN_SKIP_DAYS as u32 * 24 * 60;
+
+        let off = chronology(
+            world_on(date(1490, 3, 1), false),
+            MockCharacterRepo::new(),
+            MockLoreRepo::new(),
+        );
+        assert!(off
+            .progressions_for_skip(world_id, month * 12)
+            .await
+            .expect("disabled")
+            .is_empty());
+
+        let on = chronology(
+            world_on(date(1490, 3, 1), true),
+            MockCharacterRepo::new(),
+            MockLoreRepo::new(),
+        );
+        assert!(on
+            .progressions_for_skip(world_id, month - 1)
+            .await
+            .expect("short skip")
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_long_skip_reports_birthdays_when_enabled() {
+        let world = world_on(date(1491, 3, 1), true);
+        let world_id = world.id;
+        let pell = wrldbldr_domain::Character::new(world_id, "Pell", CampbellArchetype::Herald)
+            .with_birth_date(date(1473, 2, 1));
+        let mut characters = MockCharacterRepo::new();
+        characters
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![pell.clone()]));
+
+        let aged = chronology(world, characters, MockLoreRepo::new())
+            .progressions_for_skip(world_id, 365 * 24 * 60)
+            .await
+            .expect("progressions");
+
+        assert_eq!(aged.len(), 1);
+        assert_eq!((aged[0].previous_age, aged[0].new_age), (Some(17), 18));
+        assert!(aged[0].stage_changed);
+    }
+}
@@ -0,0 +1,448 @@
+//! Companion use cases.
+//!
+//! Hirelings and sidekicks: an NPC bound to one PC, with a loyalty score and
+//! a daily wage. A following companion shares the PC's region, so hiring one
+//! brings them to the PC and movement takes them along. Wages build up as
+//! game time passes; the DM settles them, which takes them from the PC's
+//! currency when the rule system has one.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use wrldbldr_domain::{
+    CharacterId, Companion, CompanionId, DomainError, PlayerCharacter, PlayerCharacterId, WorldId,
+    MAX_COMPANIONS_PER_PC,
+};
+use wrldbldr_protocol::types::{CompanionData, CompanionInputData, WagePaymentData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for companion use cases.
+pub struct CompanionUseCases {
+    pub manage: Arc<ManageCompanions>,
+}
+
+impl CompanionUseCases {
+    pub fn new(manage: Arc<ManageCompanions>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompanionError {
+    #[error("Companion not found")]
+    CompanionNotFound,
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("NPC not found")]
+    CharacterNotFound,
+    #[error("NPC is already a companion")]
+    AlreadyHired,
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for CompanionError {
+    fn from(e: DomainError) -> Self {
+        CompanionError::Invalid(e.to_string())
+    }
+}
+
+/// Hire, change, dismiss and pay PC companions.
+pub struct ManageCompanions {
+    companion: Arc<entities::Companion>,
+    player_character: Arc<entities::PlayerCharacter>,
+    character: Arc<entities::Character>,
+    world: Arc<entities::World>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageCompanions {
+    pub fn new(
+        companion: Arc<entities::Companion>,
+        player_character: Arc<entities::PlayerCharacter>,
+        character: Arc<entities::Character>,
+        world: Arc<entities::World>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            companion,
+            player_character,
+            character,
+            world,
+            clock,
+        }
+    }
+
+    /// A PC's companions, longest-serving first, with wages owed as of now
+    pub async fn list_for_pc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<CompanionData>, CompanionError> {
+        self.world_pc(world_id, pc_id).await?;
+        let game_now = self.game_now(world_id).await?;
+        let mut companions = Vec::new();
+        for companion in self.companion.list_for_pc(pc_id).await? {
+            companions.push(self.describe(&companion, game_now).await?);
+        }
+        Ok(companions)
+    }
+
+    /// Bind an NPC to a PC, paid up to the current game time. A following
+    /// companion joins the PC's region straight away.
+    pub async fn hire(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        character_id: CharacterId,
+        input: CompanionInputData,
+    ) -> Result<CompanionData, CompanionError> {
+        let pc = self.world_pc(world_id, pc_id).await?;
+        self.character
+            .get(character_id)
+            .await?
+            .filter(|c| c.world_id == world_id)
+            .ok_or(CompanionError::CharacterNotFound)?;
+        if self
+            .companion
+            .get_for_character(character_id)
+            .await?
+            .is_some()
+        {
+            return Err(CompanionError::AlreadyHired);
+        }
+        if self.companion.list_for_pc(pc_id).await?.len() >= MAX_COMPANIONS_PER_PC {
+            return Err(CompanionError::Invalid(format!(
+                "A PC cannot have more than {} companions",
+                MAX_COMPANIONS_PER_PC
+            )));
+        }
+
+        let game_now = self.game_now(world_id).await?;
+        let mut companion =
+            Companion::new(world_id, pc_id, character_id, game_now, self.clock.now());
+        companion.update(
+            input.loyalty,
+            input.wage_per_day,
+            input.follows_pc,
+            self.clock.now(),
+        )?;
+        self.companion.save(&companion).await?;
+        self.join_pc(&companion, &pc).await?;
+        self.describe(&companion, game_now).await
+    }
+
+    /// Change whichever fields are given. A companion told to follow again
+    /// rejoins the PC's region.
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        companion_id: CompanionId,
+        input: CompanionInputData,
+    ) -> Result<CompanionData, CompanionError> {
+        let mut companion = self.world_companion(world_id, companion_id).await?;
+        let was_following = companion.follows_pc;
+        companion.update(
+            input.loyalty,
+            input.wage_per_day,
+            input.follows_pc,
+            self.clock.now(),
+        )?;
+        self.companion.save(&companion).await?;
+        if !was_following {
+            let pc = self.world_pc(world_id, companion.pc_id).await?;
+            self.join_pc(&companion, &pc).await?;
+        }
+        let game_now = self.game_now(world_id).await?;
+        self.describe(&companion, game_now).await
+    }
+
+    /// Release the NPC, who stays wherever they are
+    pub async fn dismiss(
+        &self,
+        world_id: WorldId,
+        companion_id: CompanionId,
+    ) -> Result<(), CompanionError> {
+        let companion = self.world_companion(world_id, companion_id).await?;
+        self.companion.delete(companion.id).await?;
+        Ok(())
+    }
+
+    /// Settle the wages owed as of the current game time. They come out of
+    /// the PC's currency field when the rule system has one; otherwise the
+    /// companion is marked paid and the DM settles it by hand.
+    pub async fn pay_wages(
+        &self,
+        world_id: WorldId,
+        companion_id: CompanionId,
+    ) -> Result<WagePaymentData, CompanionError> {
+        let mut companion = self.world_companion(world_id, companion_id).await?;
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(CompanionError::WorldNotFound)?;
+        let game_now = world.game_time.current();
+        let currency = world
+            .rule_system
+            .variant
+            .currency_field()
+            .map(str::to_string);
+
+        let paid = companion.settle_wages(game_now, self.clock.now());
+        if paid > 0 {
+            if let Some(field) = &currency {
+                let amount = i32::try_from(paid).unwrap_or(i32::MAX);
+                self.player_character
+                    .modify_stat(companion.pc_id, field, -amount)
+                    .await?;
+            }
+        }
+        self.companion.save(&companion).await?;
+
+        Ok(WagePaymentData {
+            companion: self.describe(&companion, game_now).await?,
+            paid,
+            currency,
+        })
+    }
+
+    /// Put a following companion in the PC's region, if the PC is in one
+    async fn join_pc(
+        &self,
+        companion: &Companion,
+        pc: &PlayerCharacter,
+    ) -> Result<(), CompanionError> {
+        if let (true, Some(region_id)) = (companion.follows_pc, pc.current_region_id) {
+            self.character
+                .update_position(companion.character_id, region_id)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn world_pc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<PlayerCharacter, CompanionError> {
+        self.player_character
+            .get(pc_id)
+            .await?
+            .filter(|pc| pc.world_id == world_id)
+            .ok_or(CompanionError::PlayerCharacterNotFound)
+    }
+
+    async fn world_companion(
+        &self,
+        world_id: WorldId,
+        companion_id: CompanionId,
+    ) -> Result<Companion, CompanionError> {
+        self.companion
+            .get(companion_id)
+            .await?
+            .filter(|c| c.world_id == world_id)
+            .ok_or(CompanionError::CompanionNotFound)
+    }
+
+    async fn game_now(&self, world_id: WorldId) -> Result<DateTime<Utc>, CompanionError> {
+        self.world
+            .get(world_id)
+            .await?
+            .map(|w| w.game_time.current())
+            .ok_or(CompanionError::WorldNotFound)
+    }
+
+    /// The companion for the wire, with their NPC's name and portrait
+    async fn describe(
+        &self,
+        companion: &Companion,
+        game_now: DateTime<Utc>,
+    ) -> Result<CompanionData, CompanionError> {
+        let character = self.character.get(companion.character_id).await?;
+        Ok(CompanionData {
+            id: companion.id.to_string(),
+            pc_id: companion.pc_id.to_string(),
+            character_id: companion.character_id.to_string(),
+            name: character.as_ref().map(|c| c.name.clone()),
+            portrait_asset: character.and_then(|c| c.portrait_asset),
+            loyalty: companion.loyalty,
+            wage_per_day: companion.wage_per_day,
+            follows_pc: companion.follows_pc,
+            days_owed: companion.days_owed(game_now),
+            wages_due: companion.wages_due(game_now),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        MockCharacterRepo, MockCompanionRepo, MockPlayerCharacterRepo, MockWorldRepo,
+    };
+    use wrldbldr_domain::{CampbellArchetype, LocationId, RegionId, World};
+
+    struct Repos {
+        companions: MockCompanionRepo,
+        pcs: MockPlayerCharacterRepo,
+        characters: MockCharacterRepo,
+        worlds: MockWorldRepo,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            Self {
+                companions: MockCompanionRepo::new(),
+                pcs: MockPlayerCharacterRepo::new(),
+                characters: MockCharacterRepo::new(),
+                worlds: MockWorldRepo::new(),
+            }
+        }
+
+        fn build(self) -> ManageCompanions {
+            let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(Utc::now()));
+            let characters: Arc<MockCharacterRepo> = Arc::new(self.characters);
+            ManageCompanions::new(
+                Arc::new(entities::Companion::new(
+                    Arc::new(self.companions),
+                    characters.clone(),
+                )),
+                Arc::new(entities::PlayerCharacter::new(Arc::new(self.pcs))),
+                Arc::new(entities::Character::new(characters)),
+                Arc::new(entities::World::new(Arc::new(self.worlds), clock.clone())),
+                clock,
+            )
+        }
+    }
+
+    fn pc_in(world_id: WorldId, region_id: RegionId) -> PlayerCharacter {
+        PlayerCharacter::new("user", world_id, "Ash", LocationId::new(), Utc::now())
+            .with_starting_region(region_id)
+    }
+
+    #[tokio::test]
+    async fn hired_companions_join_the_pcs_region() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let world_id = world.id;
+        let region_id = RegionId::new();
+        let pc = pc_in(world_id, region_id);
+        let pc_id = pc.id;
+        let npc = wrldbldr_domain::Character::new(world_id, "Bram", CampbellArchetype::Ally);
+        let npc_id = npc.id;
+
+        let mut repos = Repos::new();
+        repos
+            .pcs
+            .expect_get()
+            .returning(move |_| Ok(Some(pc.clone())));
+        repos
+            .characters
+            .expect_get()
+            .returning(move |_| Ok(Some(npc.clone())));
+        repos
+            .worlds
+            .expect_get()
+            .returning(move |_| Ok(Some(world.clone())));
+        repos
+            .companions
+            .expect_get_for_character()
+            .returning(|_| Ok(None));
+        repos
+            .companions
+            .expect_list_for_pc()
+            .returning(|_| Ok(vec![]));
+        repos
+            .companions
+            .expect_save()
+            .withf(|c| c.follows_pc && c.wage_per_day == 3)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos
+            .characters
+            .expect_update_position()
+            .withf(move |id, region| *id == npc_id && *region == region_id)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let data = CompanionInputData {
+            wage_per_day: Some(3),
+            ..Default::default()
+        };
+        let hired = repos
+            .build()
+            .hire(world_id, pc_id, npc_id, data)
+            .await
+            .expect("hired");
+
+        assert_eq!(hired.name.as_deref(), Some("Bram"));
+        assert_eq!(hired.loyalty, wrldbldr_domain::DEFAULT_LOYALTY);
+    }
+
+    #[tokio::test]
+    async fn an_npc_serves_one_pc_at_a_time() {
+        let world_id = WorldId::new();
+        let pc = pc_in(world_id, RegionId::new());
+        let pc_id = pc.id;
+        let npc = wrldbldr_domain::Character::new(world_id, "Bram", CampbellArchetype::Ally);
+        let npc_id = npc.id;
+        let existing = Companion::new(
+            world_id,
+            PlayerCharacterId::new(),
+            npc_id,
+            Utc::now(),
+            Utc::now(),
+        );
+
+        let mut repos = Repos::new();
+        repos
+            .pcs
+            .expect_get()
+            .returning(move |_| Ok(Some(pc.clone())));
+        repos
+            .characters
+            .expect_get()
+            .returning(move |_| Ok(Some(npc.clone())));
+        repos
+            .companions
+            .expect_get_for_character()
+            .returning(move |_| Ok(Some(existing.clone())));
+        repos.companions.expect_save().never();
+
+        let result = repos
+            .build()
+            .hire(world_id, pc_id, npc_id, CompanionInputData::default())
+            .await;
+
+        assert!(matches!(result, Err(CompanionError::AlreadyHired)));
+    }
+
+    #[tokio::test]
+    async fn companions_from_other_worlds_are_missing() {
+        let companion = Companion::new(
+            WorldId::new(),
+            PlayerCharacterId::new(),
+            CharacterId::new(),
+            Utc::now(),
+            Utc::now(),
+        );
+        let companion_id = companion.id;
+        let mut repos = Repos::new();
+        repos
+            .companions
+            .expect_get()
+            .returning(move |_| Ok(Some(companion.clone())));
+        repos.companions.expect_delete().never();
+
+        let result = repos.build().dismiss(WorldId::new(), companion_id).await;
+
+        assert!(matches!(result, Err(CompanionError::CompanionNotFound)));
+    }
+}
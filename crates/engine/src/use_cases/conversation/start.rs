@@ -197,7 +197,7 @@ mod tests {
     use crate::entities;
     use crate::infrastructure::ports::{
         ClockPort, MockCharacterRepo, MockPlayerCharacterRepo, MockSceneRepo, MockStagingRepo,
-        MockWorldRepo, QueueError, QueueItem, QueuePort,
+        MockWorldRepo, QueueError, QueueItem, QueueItemStatus, QueuePort,
     };
 
     struct FixedClock(chrono::DateTime<chrono::Utc>);
@@ -276,6 +276,19 @@ mod tests {
             Ok(())
         }
 
+        async fn mark_failed_if_status(
+            &self,
+            _id: Uuid,
+            _expected: QueueItemStatus,
+            _error: &str,
+        ) -> Result<bool, QueueError> {
+            Ok(false)
+        }
+
+        async fn get_item(&self, _id: Uuid) -> Result<Option<QueueItem>, QueueError> {
+            Ok(None)
+        }
+
         async fn get_pending_count(&self, _queue_type: &str) -> Result<usize, QueueError> {
             Ok(0)
         }
@@ -299,6 +312,10 @@ mod tests {
             Ok(false)
         }
 
+        async fn delete_by_callback_id(&self, _callback_id: &str) -> Result<bool, QueueError> {
+            Ok(false)
+        }
+
         async fn get_approval_request(
             &self,
             _id: Uuid,
@@ -0,0 +1,304 @@
+//! Crowd use cases.
+//!
+//! Background crowds give a region its bustle without a `Character` per
+//! dock worker. The DM creates them per region and stages or unstages them;
+//! players see present crowds as part of the scene, with a size band rather
+//! than an exact headcount. Crowds are scoped to their world; crowds and
+//! regions in other worlds are treated as missing.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{Crowd, CrowdId, DomainError, RegionId, WorldId};
+use wrldbldr_protocol::types::{CrowdData, CrowdInputData};
+use wrldbldr_protocol::CrowdPresenceData;
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for crowd use cases.
+pub struct CrowdUseCases {
+    pub manage: Arc<ManageCrowds>,
+}
+
+impl CrowdUseCases {
+    pub fn new(manage: Arc<ManageCrowds>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CrowdError {
+    #[error("Crowd not found")]
+    NotFound,
+    #[error("Region not found")]
+    RegionNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for CrowdError {
+    fn from(e: DomainError) -> Self {
+        CrowdError::Invalid(e.to_string())
+    }
+}
+
+/// Create, edit, stage and remove crowds.
+pub struct ManageCrowds {
+    crowd: Arc<entities::Crowd>,
+    location: Arc<entities::Location>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageCrowds {
+    pub fn new(
+        crowd: Arc<entities::Crowd>,
+        location: Arc<entities::Location>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            crowd,
+            location,
+            clock,
+        }
+    }
+
+    /// Crowds in one region, or in the whole world
+    pub async fn list(
+        &self,
+        world_id: WorldId,
+        region_id: Option<RegionId>,
+    ) -> Result<Vec<CrowdData>, CrowdError> {
+        let crowds = match region_id {
+            Some(region_id) => {
+                self.ensure_region_in_world(world_id, region_id).await?;
+                self.crowd.list_in_region(region_id).await?
+            }
+            None => self.crowd.list_for_world(world_id).await?,
+        };
+        Ok(crowds.iter().map(crowd_to_protocol).collect())
+    }
+
+    pub async fn create(
+        &self,
+        world_id: WorldId,
+        region_id: RegionId,
+        input: CrowdInputData,
+    ) -> Result<Crowd, CrowdError> {
+        self.ensure_region_in_world(world_id, region_id).await?;
+        let crowd = Crowd::new(
+            world_id,
+            region_id,
+            input.name,
+            input.approximate_count,
+            self.clock.now(),
+        )?
+        .with_description(input.description.trim())
+        .with_disposition(input.disposition);
+        self.crowd.save(&crowd).await?;
+        Ok(crowd)
+    }
+
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        crowd_id: CrowdId,
+        input: CrowdInputData,
+    ) -> Result<Crowd, CrowdError> {
+        let mut crowd = self.world_crowd(world_id, crowd_id).await?;
+        crowd.update(
+            &input.name,
+            input.description.trim(),
+            input.approximate_count,
+            input.disposition,
+            self.clock.now(),
+        )?;
+        self.crowd.save(&crowd).await?;
+        Ok(crowd)
+    }
+
+    /// Delete a crowd; returns the region it was in
+    pub async fn delete(
+        &self,
+        world_id: WorldId,
+        crowd_id: CrowdId,
+    ) -> Result<RegionId, CrowdError> {
+        let crowd = self.world_crowd(world_id, crowd_id).await?;
+        self.crowd.delete(crowd.id).await?;
+        Ok(crowd.region_id)
+    }
+
+    pub async fn set_present(
+        &self,
+        world_id: WorldId,
+        crowd_id: CrowdId,
+        present: bool,
+    ) -> Result<Crowd, CrowdError> {
+        let mut crowd = self.world_crowd(world_id, crowd_id).await?;
+        if crowd.set_present(present, self.clock.now()) {
+            self.crowd.save(&crowd).await?;
+        }
+        Ok(crowd)
+    }
+
+    /// What players in a region see
+    pub async fn present_in_region(
+        &self,
+        region_id: RegionId,
+    ) -> Result<Vec<CrowdPresenceData>, CrowdError> {
+        Ok(self
+            .crowd
+            .list_present_in_region(region_id)
+            .await?
+            .iter()
+            .map(crowd_to_presence)
+            .collect())
+    }
+
+    async fn world_crowd(&self, world_id: WorldId, crowd_id: CrowdId) -> Result<Crowd, CrowdError> {
+        self.crowd
+            .get(crowd_id)
+            .await?
+            .filter(|c| c.world_id == world_id)
+            .ok_or(CrowdError::NotFound)
+    }
+
+    async fn ensure_region_in_world(
+        &self,
+        world_id: WorldId,
+        region_id: RegionId,
+    ) -> Result<(), CrowdError> {
+        let region = self
+            .location
+            .get_region(region_id)
+            .await?
+            .ok_or(CrowdError::RegionNotFound)?;
+        self.location
+            .get(region.location_id)
+            .await?
+            .filter(|l| l.world_id == world_id)
+            .map(|_| ())
+            .ok_or(CrowdError::RegionNotFound)
+    }
+}
+
+pub(crate) fn crowd_to_protocol(crowd: &Crowd) -> CrowdData {
+    CrowdData {
+        id: crowd.id.to_string(),
+        region_id: crowd.region_id.to_string(),
+        name: crowd.name.clone(),
+        description: crowd.description.clone(),
+        approximate_count: crowd.approximate_count,
+        size_label: crowd.size_label().to_string(),
+        disposition: crowd.disposition,
+        is_present: crowd.is_present,
+    }
+}
+
+pub(crate) fn crowd_to_presence(crowd: &Crowd) -> CrowdPresenceData {
+    CrowdPresenceData {
+        crowd_id: crowd.id.to_string(),
+        name: crowd.name.clone(),
+        description: crowd.description.clone(),
+        size_label: crowd.size_label().to_string(),
+        disposition: crowd.disposition,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{MockCrowdRepo, MockLocationRepo};
+    use wrldbldr_domain::{DispositionLevel, Location, LocationType, Region};
+
+    fn input(name: &str, count: u32) -> CrowdInputData {
+        CrowdInputData {
+            name: name.to_string(),
+            description: String::new(),
+            approximate_count: count,
+            disposition: DispositionLevel::Suspicious,
+        }
+    }
+
+    fn crowds(repo: MockCrowdRepo, locations: MockLocationRepo) -> ManageCrowds {
+        ManageCrowds::new(
+            Arc::new(entities::Crowd::new(Arc::new(repo))),
+            Arc::new(entities::Location::new(Arc::new(locations))),
+            Arc::new(FixedClock(chrono::Utc::now())),
+        )
+    }
+
+    fn docks_in(world_id: WorldId) -> (MockLocationRepo, RegionId) {
+        let location = Location::new(world_id, "Varn", LocationType::Exterior);
+        let region = Region::new(location.id, "The Docks");
+        let region_id = region.id;
+        let mut locations = MockLocationRepo::new();
+        locations
+            .expect_get_region()
+            .returning(move |_| Ok(Some(region.clone())));
+        locations
+            .expect_get_location()
+            .returning(move |_| Ok(Some(location.clone())));
+        (locations, region_id)
+    }
+
+    #[tokio::test]
+    async fn creating_a_crowd_stages_it_in_the_region() {
+        let world_id = WorldId::new();
+        let (locations, region_id) = docks_in(world_id);
+        let mut repo = MockCrowdRepo::new();
+        repo.expect_save()
+            .withf(move |c| c.region_id == region_id && c.is_present)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let created = crowds(repo, locations)
+            .create(world_id, region_id, input("dock workers", 40))
+            .await
+            .expect("create");
+
+        assert_eq!(created.size_label(), "dozens");
+        assert_eq!(created.disposition, DispositionLevel::Suspicious);
+    }
+
+    #[tokio::test]
+    async fn regions_in_other_worlds_are_missing() {
+        let (locations, region_id) = docks_in(WorldId::new());
+        let mut repo = MockCrowdRepo::new();
+        repo.expect_save().never();
+
+        let result = crowds(repo, locations)
+            .create(WorldId::new(), region_id, input("dock workers", 40))
+            .await;
+
+        assert!(matches!(result, Err(CrowdError::RegionNotFound)));
+    }
+
+    #[tokio::test]
+    async fn unstaging_twice_saves_once() {
+        let world_id = WorldId::new();
+        let mut crowd = Crowd::new(
+            world_id,
+            RegionId::new(),
+            "cultists",
+            12,
+            chrono::Utc::now(),
+        )
+        .expect("crowd");
+        crowd.is_present = false;
+        let crowd_id = crowd.id;
+        let mut repo = MockCrowdRepo::new();
+        repo.expect_get()
+            .returning(move |_| Ok(Some(crowd.clone())));
+        repo.expect_save().never();
+
+        let updated = crowds(repo, MockLocationRepo::new())
+            .set_present(world_id, crowd_id, false)
+            .await
+            .expect("set present");
+
+        assert!(!updated.is_present);
+    }
+}
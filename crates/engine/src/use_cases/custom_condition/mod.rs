@@ -8,6 +8,7 @@
 use std::sync::Arc;
 
 use crate::infrastructure::ports::{ChatMessage, LlmError, LlmPort, LlmRequest};
+use crate::use_cases::ai::structured::generate_validated;
 
 /// Result of evaluating a custom condition.
 #[derive(Debug, Clone)]
@@ -183,17 +184,26 @@ impl CustomConditionEvaluator {
 
         let request = LlmRequest::new(vec![ChatMessage::user(user_message)])
             .with_system_prompt(system_prompt)
-            .with_temperature(0.1); // Low temperature for more deterministic evaluation
+            .with_temperature(0.1) // Low temperature for more deterministic evaluation
+            .with_cache(true);
 
         tracing::debug!(
             condition = %condition_description,
             "Evaluating custom condition via LLM"
         );
 
-        let response = self.llm.generate(request).await?;
-
-        // Parse the response
-        let result = self.parse_response(&response.content, condition_description)?;
+        // The reply is checked against the schema (and retried if it doesn't
+        // fit) before it is parsed
+        let result = generate_validated(
+            self.llm.as_ref(),
+            request,
+            evaluation_schema(),
+            |reply| {
+                self.parse_response(&reply.to_string(), condition_description)
+                    .map_err(|e| e.to_string())
+            },
+        )
+        .await?;
 
         tracing::info!(
             condition = %condition_description,
@@ -343,6 +353,19 @@ Please evaluate whether this condition is currently met and respond with the JSO
     }
 }
 
+/// Schema for evaluation replies
+fn evaluation_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "result": { "type": "boolean" },
+            "confidence": { "type": "number", "minimum": 0.0, "maximum": 1.0 },
+            "reasoning": { "type": "string" }
+        },
+        "required": ["result", "confidence"]
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
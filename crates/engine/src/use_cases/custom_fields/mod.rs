@@ -0,0 +1,356 @@
+//! Custom field use cases.
+//!
+//! DMs define extra typed fields (text, number, select) per world for
+//! characters, locations and items, then fill them in per entity. Detail
+//! views list every field defined for the entity's type with its value, so
+//! a field shows up everywhere as soon as it is added. Players only see the
+//! fields the DM marked visible.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    validate_custom_field_values, validate_custom_fields, CustomFieldDefinition, CustomFieldType,
+    CustomFieldValue, CustomFieldValues, EntityType, WorldId, CUSTOM_FIELD_ENTITY_TYPES,
+};
+use wrldbldr_protocol::types::{
+    CustomFieldDefinitionData, CustomFieldEntryData, CustomFieldTypeData, CustomFieldValueData,
+};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for custom field use cases.
+pub struct CustomFieldUseCases {
+    pub schema: Arc<ManageFieldSchema>,
+    pub values: Arc<FieldValues>,
+}
+
+impl CustomFieldUseCases {
+    pub fn new(schema: Arc<ManageFieldSchema>, values: Arc<FieldValues>) -> Self {
+        Self { schema, values }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CustomFieldError {
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("Invalid custom fields: {0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+/// Read and replace a world's custom field schema.
+pub struct ManageFieldSchema {
+    world: Arc<entities::World>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageFieldSchema {
+    pub fn new(world: Arc<entities::World>, clock: Arc<dyn ClockPort>) -> Self {
+        Self { world, clock }
+    }
+
+    pub async fn get(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<CustomFieldDefinitionData>, CustomFieldError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(CustomFieldError::WorldNotFound)?;
+        Ok(world.custom_fields.iter().map(field_to_protocol).collect())
+    }
+
+    /// Replace the schema. Values of removed fields stay stored but are no
+    /// longer shown, so re-adding a field with the same key brings them back.
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        fields: Vec<CustomFieldDefinitionData>,
+    ) -> Result<Vec<CustomFieldDefinitionData>, CustomFieldError> {
+        let fields = fields
+            .into_iter()
+            .map(|data| field_from_protocol(data).map(CustomFieldDefinition::normalized))
+            .collect::<Result<Vec<_>, _>>()?;
+        validate_custom_fields(&fields).map_err(CustomFieldError::Invalid)?;
+
+        let mut world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(CustomFieldError::WorldNotFound)?;
+        world.set_custom_fields(fields, self.clock.now());
+        self.world.save(&world).await?;
+        Ok(world.custom_fields.iter().map(field_to_protocol).collect())
+    }
+}
+
+/// Read and replace the custom field values on one entity.
+pub struct FieldValues {
+    world: Arc<entities::World>,
+    fields: Arc<entities::CustomField>,
+}
+
+impl FieldValues {
+    pub fn new(world: Arc<entities::World>, fields: Arc<entities::CustomField>) -> Self {
+        Self { world, fields }
+    }
+
+    /// Every field defined for the entity's type, in schema order, with the
+    /// entity's value if set. Hidden fields are left out unless
+    /// `include_hidden` (DM views).
+    pub async fn entries(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        include_hidden: bool,
+    ) -> Result<Vec<CustomFieldEntryData>, CustomFieldError> {
+        let schema = self.schema(world_id).await?;
+        let values = self.fields.get_values(entity_type, entity_id).await?;
+        Ok(entries_for(&schema, entity_type, &values, include_hidden))
+    }
+
+    /// Replace the entity's values after checking them against the schema.
+    pub async fn set(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        values: BTreeMap<String, CustomFieldValueData>,
+    ) -> Result<Vec<CustomFieldEntryData>, CustomFieldError> {
+        if !CUSTOM_FIELD_ENTITY_TYPES.contains(&entity_type) {
+            return Err(CustomFieldError::Invalid(format!(
+                "{} entities can't have custom fields",
+                entity_type
+            )));
+        }
+        let schema = self.schema(world_id).await?;
+        let values: CustomFieldValues = values
+            .into_iter()
+            .map(|(key, value)| (key, value_from_protocol(value)))
+            .collect();
+        validate_custom_field_values(&schema, entity_type, &values)
+            .map_err(CustomFieldError::Invalid)?;
+
+        self.fields
+            .set_values(world_id, entity_type, entity_id, &values)
+            .await?;
+        Ok(entries_for(&schema, entity_type, &values, true))
+    }
+
+    async fn schema(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<CustomFieldDefinition>, CustomFieldError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(CustomFieldError::WorldNotFound)?;
+        Ok(world.custom_fields)
+    }
+}
+
+fn entries_for(
+    schema: &[CustomFieldDefinition],
+    entity_type: EntityType,
+    values: &CustomFieldValues,
+    include_hidden: bool,
+) -> Vec<CustomFieldEntryData> {
+    schema
+        .iter()
+        .filter(|f| f.entity_type == entity_type && (include_hidden || f.visible_to_players))
+        .map(|f| CustomFieldEntryData {
+            field: field_to_protocol(f),
+            value: values.get(&f.key).cloned().map(value_to_protocol),
+        })
+        .collect()
+}
+
+fn field_to_protocol(field: &CustomFieldDefinition) -> CustomFieldDefinitionData {
+    CustomFieldDefinitionData {
+        key: field.key.clone(),
+        label: field.label.clone(),
+        entity_type: field.entity_type,
+        field_type: match field.field_type {
+            CustomFieldType::Text => CustomFieldTypeData::Text,
+            CustomFieldType::Number => CustomFieldTypeData::Number,
+            CustomFieldType::Select => CustomFieldTypeData::Select,
+        },
+        options: field.options.clone(),
+        visible_to_players: field.visible_to_players,
+    }
+}
+
+fn field_from_protocol(
+    data: CustomFieldDefinitionData,
+) -> Result<CustomFieldDefinition, CustomFieldError> {
+    let field_type = match data.field_type {
+        CustomFieldTypeData::Text => CustomFieldType::Text,
+        CustomFieldTypeData::Number => CustomFieldType::Number,
+        CustomFieldTypeData::Select => CustomFieldType::Select,
+        CustomFieldTypeData::Unknown => {
+            return Err(CustomFieldError::Invalid(format!(
+                "field {:?} has an unknown type",
+                data.label
+            )))
+        }
+    };
+    let mut field = CustomFieldDefinition::new(data.key, data.label, data.entity_type, field_type)
+        .with_options(data.options);
+    field.visible_to_players = data.visible_to_players;
+    Ok(field)
+}
+
+fn value_to_protocol(value: CustomFieldValue) -> CustomFieldValueData {
+    match value {
+        CustomFieldValue::Number(n) => CustomFieldValueData::Number(n),
+        CustomFieldValue::Text(text) => CustomFieldValueData::Text(text),
+    }
+}
+
+fn value_from_protocol(value: CustomFieldValueData) -> CustomFieldValue {
+    match value {
+        CustomFieldValueData::Number(n) => CustomFieldValue::Number(n),
+        CustomFieldValueData::Text(text) => CustomFieldValue::Text(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{MockCustomFieldRepo, MockWorldRepo};
+
+    fn world_with_fields() -> wrldbldr_domain::World {
+        wrldbldr_domain::World::new("W", "", chrono::Utc::now()).with_custom_fields(vec![
+            CustomFieldDefinition::new(
+                "heat",
+                "Heat",
+                EntityType::Character,
+                CustomFieldType::Number,
+            )
+            .visible_to_players(),
+            CustomFieldDefinition::new(
+                "secret_boss",
+                "Secret boss",
+                EntityType::Character,
+                CustomFieldType::Text,
+            ),
+            CustomFieldDefinition::new(
+                "tier",
+                "Shop tier",
+                EntityType::Location,
+                CustomFieldType::Number,
+            ),
+        ])
+    }
+
+    fn values(world_repo: MockWorldRepo, field_repo: MockCustomFieldRepo) -> FieldValues {
+        let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(chrono::Utc::now()));
+        FieldValues::new(
+            Arc::new(entities::World::new(Arc::new(world_repo), clock)),
+            Arc::new(entities::CustomField::new(Arc::new(field_repo))),
+        )
+    }
+
+    fn field(key: &str, field_type: CustomFieldTypeData) -> CustomFieldDefinitionData {
+        CustomFieldDefinitionData {
+            key: key.to_string(),
+            label: key.to_string(),
+            entity_type: EntityType::Item,
+            field_type,
+            options: Vec::new(),
+            visible_to_players: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_entries_hide_private_fields_from_players() {
+        let world = world_with_fields();
+        let world_id = world.id;
+        let character_id = Uuid::new_v4();
+        let mut world_repo = MockWorldRepo::new();
+        world_repo
+            .expect_get()
+            .returning(move |_| Ok(Some(world.clone())));
+        let mut field_repo = MockCustomFieldRepo::new();
+        field_repo.expect_get_values().returning(|_, _| {
+            Ok(CustomFieldValues::from([
+                ("heat".to_string(), CustomFieldValue::Number(2.0)),
+                (
+                    "secret_boss".to_string(),
+                    CustomFieldValue::Text("Lyssa".to_string()),
+                ),
+            ]))
+        });
+        let values = values(world_repo, field_repo);
+
+        let dm = values
+            .entries(world_id, EntityType::Character, character_id, true)
+            .await
+            .unwrap();
+        let keys: Vec<&str> = dm.iter().map(|e| e.field.key.as_str()).collect();
+        assert_eq!(keys, vec!["heat", "secret_boss"]);
+
+        let player = values
+            .entries(world_id, EntityType::Character, character_id, false)
+            .await
+            .unwrap();
+        assert_eq!(player.len(), 1);
+        assert_eq!(player[0].value, Some(CustomFieldValueData::Number(2.0)));
+    }
+
+    #[tokio::test]
+    async fn test_set_rejects_values_that_do_not_fit_the_schema() {
+        let world = world_with_fields();
+        let world_id = world.id;
+        let mut world_repo = MockWorldRepo::new();
+        world_repo
+            .expect_get()
+            .returning(move |_| Ok(Some(world.clone())));
+        // Nothing is written when validation fails
+        let err = values(world_repo, MockCustomFieldRepo::new())
+            .set(
+                world_id,
+                EntityType::Character,
+                Uuid::new_v4(),
+                BTreeMap::from([(
+                    "heat".to_string(),
+                    CustomFieldValueData::Text("high".to_string()),
+                )]),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CustomFieldError::Invalid(_)));
+    }
+
+    #[tokio::test]
+    async fn test_update_schema_rejects_select_without_options() {
+        let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(chrono::Utc::now()));
+        let schema = ManageFieldSchema::new(
+            Arc::new(entities::World::new(
+                Arc::new(MockWorldRepo::new()),
+                clock.clone(),
+            )),
+            clock,
+        );
+        let err = schema
+            .update(
+                WorldId::new(),
+                vec![
+                    field("weight", CustomFieldTypeData::Number),
+                    field("rarity", CustomFieldTypeData::Select),
+                ],
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CustomFieldError::Invalid(_)));
+    }
+}
@@ -0,0 +1,267 @@
+//! Diagnostics use cases.
+//!
+//! Bundles what the engine knows about a world's recent activity - its
+//! logs, its queue items and its protocol message counts - into a zip the DM
+//! can attach to a bug report.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+use wrldbldr_domain::WorldId;
+
+use crate::entities::World;
+use crate::infrastructure::diagnostics::{Diagnostics, LogRecord};
+use crate::infrastructure::ports::{
+    ClockPort, QueueError, QueueItem, QueueItemData, QueueItemStatus, QueuePort, RepoError,
+};
+use crate::infrastructure::zip::write_zip;
+
+/// Log records included in a bundle
+pub const MAX_BUNDLE_LOG_RECORDS: usize = 2000;
+
+/// Items scanned per queue for a bundle's queue snapshot
+const QUEUE_SCAN_LIMIT: usize = 500;
+
+const QUEUE_TYPES: [&str; 4] = [
+    "player_action",
+    "llm_request",
+    "dm_approval",
+    "asset_generation",
+];
+
+/// Container for diagnostics use cases.
+pub struct DiagnosticsUseCases {
+    pub export: Arc<ExportDiagnostics>,
+}
+
+impl DiagnosticsUseCases {
+    pub fn new(export: Arc<ExportDiagnostics>) -> Self {
+        Self { export }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DiagnosticsError {
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("Queue error: {0}")]
+    Queue(#[from] QueueError),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+    #[error("Failed to build archive: {0}")]
+    Archive(#[from] std::io::Error),
+}
+
+/// A zipped diagnostics bundle
+#[derive(Debug, Clone)]
+pub struct DiagnosticsBundle {
+    pub file_name: String,
+    pub data: Vec<u8>,
+}
+
+/// A queue item in a bundle's queue snapshot
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueueItemSnapshot {
+    pub id: Uuid,
+    pub queue: &'static str,
+    pub status: &'static str,
+    pub created_at: DateTime<Utc>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Manifest {
+    world_id: String,
+    world_name: String,
+    exported_at: DateTime<Utc>,
+    engine_version: &'static str,
+    engine_started_at: DateTime<Utc>,
+    log_records: usize,
+    queue_items: usize,
+}
+
+fn status_str(status: QueueItemStatus) -> &'static str {
+    match status {
+        QueueItemStatus::Pending => "pending",
+        QueueItemStatus::Processing => "processing",
+        QueueItemStatus::Completed => "completed",
+        QueueItemStatus::Failed => "failed",
+    }
+}
+
+fn item_world(data: &QueueItemData) -> Option<WorldId> {
+    match data {
+        QueueItemData::PlayerAction(data) => Some(data.world_id),
+        QueueItemData::LlmRequest(data) => Some(data.world_id),
+        QueueItemData::DmApproval(data) => Some(data.world_id),
+        QueueItemData::AssetGeneration(data) => data.world_id,
+    }
+}
+
+/// The items of `queue` that belong to `world_id`, oldest first
+pub fn queue_snapshot(
+    queue: &'static str,
+    items: Vec<QueueItem>,
+    world_id: WorldId,
+) -> Vec<QueueItemSnapshot> {
+    let mut snapshot: Vec<QueueItemSnapshot> = items
+        .into_iter()
+        .filter(|item| item_world(&item.data) == Some(world_id))
+        .map(|item| QueueItemSnapshot {
+            id: item.id,
+            queue,
+            status: status_str(item.status),
+            created_at: item.created_at,
+            error: item.error_message,
+        })
+        .collect();
+    snapshot.sort_by_key(|item| item.created_at);
+    snapshot
+}
+
+fn json_lines(records: &[LogRecord]) -> Result<Vec<u8>, serde_json::Error> {
+    let mut out = Vec::new();
+    for record in records {
+        serde_json::to_writer(&mut out, record)?;
+        out.push(b'\n');
+    }
+    Ok(out)
+}
+
+/// Export a world's diagnostics bundle (DM only).
+pub struct ExportDiagnostics {
+    diagnostics: Arc<Diagnostics>,
+    queue: Arc<dyn QueuePort>,
+    world: Arc<World>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ExportDiagnostics {
+    pub fn new(
+        diagnostics: Arc<Diagnostics>,
+        queue: Arc<dyn QueuePort>,
+        world: Arc<World>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            diagnostics,
+            queue,
+            world,
+            clock,
+        }
+    }
+
+    /// Build the bundle: `manifest.json`, `logs.jsonl`, `queues.json` and
+    /// `messages.json`.
+    pub async fn execute(&self, world_id: WorldId) -> Result<DiagnosticsBundle, DiagnosticsError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(DiagnosticsError::WorldNotFound)?;
+        let now = self.clock.now();
+
+        let logs = self
+            .diagnostics
+            .logs
+            .recent_for_world(world_id, MAX_BUNDLE_LOG_RECORDS);
+
+        let mut queue_items = Vec::new();
+        for queue in QUEUE_TYPES {
+            let items = self.queue.list_by_type(queue, QUEUE_SCAN_LIMIT).await?;
+            queue_items.extend(queue_snapshot(queue, items, world_id));
+        }
+
+        let messages = self.diagnostics.messages.snapshot(world_id);
+
+        let manifest = Manifest {
+            world_id: world_id.to_string(),
+            world_name: world.name,
+            exported_at: now,
+            engine_version: env!("CARGO_PKG_VERSION"),
+            engine_started_at: self.diagnostics.started_at,
+            log_records: logs.len(),
+            queue_items: queue_items.len(),
+        };
+
+        let entries = vec![
+            (
+                "manifest.json".to_string(),
+                serde_json::to_vec_pretty(&manifest).map_err(std::io::Error::from)?,
+            ),
+            (
+                "logs.jsonl".to_string(),
+                json_lines(&logs).map_err(std::io::Error::from)?,
+            ),
+            (
+                "queues.json".to_string(),
+                serde_json::to_vec_pretty(&queue_items).map_err(std::io::Error::from)?,
+            ),
+            (
+                "messages.json".to_string(),
+                serde_json::to_vec_pretty(&messages).map_err(std::io::Error::from)?,
+            ),
+        ];
+
+        Ok(DiagnosticsBundle {
+            file_name: format!(
+                "wrldbldr-diagnostics-{}-{}.zip",
+                world_id,
+                now.format("%Y%m%dT%H%M%SZ")
+            ),
+            data: write_zip(&entries, now)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use wrldbldr_domain::{AssetGenerationData, EntityType};
+
+    fn asset_item(world_id: Option<WorldId>, minute: u32) -> QueueItem {
+        QueueItem {
+            id: Uuid::new_v4(),
+            data: QueueItemData::AssetGeneration(AssetGenerationData {
+                world_id,
+                entity_type: EntityType::Character.to_string(),
+                entity_id: Uuid::new_v4().to_string(),
+                workflow_id: "character_portrait".to_string(),
+                prompt: "A knight".to_string(),
+                count: 1,
+                negative_prompt: None,
+                dimensions: None,
+                reference_image: None,
+            }),
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, minute, 0).unwrap(),
+            status: QueueItemStatus::Failed,
+            error_message: Some("ComfyUI unavailable".to_string()),
+            result_json: None,
+        }
+    }
+
+    #[test]
+    fn queue_snapshot_keeps_the_worlds_items_oldest_first() {
+        let world_id = WorldId::new();
+        let newer = asset_item(Some(world_id), 30);
+        let older = asset_item(Some(world_id), 10);
+        let items = vec![
+            newer.clone(),
+            asset_item(Some(WorldId::new()), 20),
+            asset_item(None, 25),
+            older.clone(),
+        ];
+
+        let snapshot = queue_snapshot("asset_generation", items, world_id);
+
+        assert_eq!(
+            snapshot.iter().map(|item| item.id).collect::<Vec<_>>(),
+            vec![older.id, newer.id]
+        );
+        assert_eq!(snapshot[0].status, "failed");
+        assert_eq!(snapshot[0].error.as_deref(), Some("ComfyUI unavailable"));
+    }
+}
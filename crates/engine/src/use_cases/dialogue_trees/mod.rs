@@ -0,0 +1,552 @@
+//! Dialogue tree use cases.
+//!
+//! The DM writes branching dialogue for beats that must land word for word.
+//! A tree plays instead of a generated response when a PC talks to its
+//! speaker, or when its narrative event triggers. The player answers with
+//! the choices the DM wrote; a choice can set world flags and run event
+//! effects, then leads to the next node or ends the beat. Typing something
+//! else goes off-script: the beat ends and the words go to the NPC's usual
+//! LLM conversation.
+//!
+//! Where each PC is in a beat is kept in memory, like the directorial
+//! context; a restart ends beats in progress. Whether a PC has heard a tree
+//! is stored with the tree.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    CharacterId, DialogueBranch, DialogueNode, DialogueTree, DialogueTreeId, DomainError,
+    EventEffect, NarrativeEventId, PlayerCharacterId, WorldId,
+};
+use wrldbldr_protocol::types::{
+    DialogueBranchData, DialogueNodeData, DialogueTreeData, DialogueTreeInputData,
+};
+use wrldbldr_protocol::DialogueChoice;
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+use crate::use_cases::narrative::{EffectExecutionContext, ExecuteEffects};
+
+/// Choice id players send to say something the tree didn't offer
+pub const OFF_SCRIPT_CHOICE_ID: &str = "off_script";
+
+/// Container for dialogue tree use cases.
+pub struct DialogueTreeUseCases {
+    pub manage: Arc<ManageDialogueTrees>,
+    pub beats: Arc<ScriptedBeats>,
+}
+
+impl DialogueTreeUseCases {
+    pub fn new(manage: Arc<ManageDialogueTrees>, beats: Arc<ScriptedBeats>) -> Self {
+        Self { manage, beats }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DialogueTreeError {
+    #[error("Dialogue tree not found")]
+    NotFound,
+    #[error("NPC not found")]
+    SpeakerNotFound,
+    #[error("Narrative event not found")]
+    EventNotFound,
+    #[error("No scripted dialogue is waiting for an answer")]
+    NoBeat,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for DialogueTreeError {
+    fn from(e: DomainError) -> Self {
+        DialogueTreeError::Invalid(e.to_string())
+    }
+}
+
+/// Create and edit dialogue trees.
+pub struct ManageDialogueTrees {
+    dialogue_tree: Arc<entities::DialogueTree>,
+    character: Arc<entities::Character>,
+    narrative: Arc<entities::Narrative>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageDialogueTrees {
+    pub fn new(
+        dialogue_tree: Arc<entities::DialogueTree>,
+        character: Arc<entities::Character>,
+        narrative: Arc<entities::Narrative>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            dialogue_tree,
+            character,
+            narrative,
+            clock,
+        }
+    }
+
+    /// Every dialogue tree in the world, by name
+    pub async fn list(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<DialogueTreeData>, DialogueTreeError> {
+        let trees = self.dialogue_tree.list_in_world(world_id).await?;
+        let names: HashMap<CharacterId, String> = self
+            .character
+            .list_npcs_in_world(world_id)
+            .await?
+            .into_iter()
+            .map(|c| (c.id, c.name))
+            .collect();
+        Ok(trees
+            .iter()
+            .map(|t| {
+                let speaker_name = names.get(&t.speaker_id).cloned().unwrap_or_default();
+                tree_to_protocol(t, speaker_name)
+            })
+            .collect())
+    }
+
+    pub async fn create(
+        &self,
+        world_id: WorldId,
+        input: DialogueTreeInputData,
+    ) -> Result<DialogueTreeData, DialogueTreeError> {
+        let (speaker_id, speaker_name) = self.world_speaker(world_id, &input.speaker_id).await?;
+        let mut tree =
+            DialogueTree::new(world_id, input.name.clone(), speaker_id, self.clock.now())?;
+        self.apply_input(&mut tree, input).await?;
+        self.dialogue_tree.save(&tree).await?;
+        Ok(tree_to_protocol(&tree, speaker_name))
+    }
+
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        tree_id: DialogueTreeId,
+        input: DialogueTreeInputData,
+    ) -> Result<DialogueTreeData, DialogueTreeError> {
+        let mut tree = self.world_tree(world_id, tree_id).await?;
+        let (speaker_id, speaker_name) = self.world_speaker(world_id, &input.speaker_id).await?;
+        tree.speaker_id = speaker_id;
+        self.apply_input(&mut tree, input).await?;
+        self.dialogue_tree.save(&tree).await?;
+        Ok(tree_to_protocol(&tree, speaker_name))
+    }
+
+    pub async fn delete(
+        &self,
+        world_id: WorldId,
+        tree_id: DialogueTreeId,
+    ) -> Result<(), DialogueTreeError> {
+        let tree = self.world_tree(world_id, tree_id).await?;
+        self.dialogue_tree.delete(tree.id).await?;
+        Ok(())
+    }
+
+    /// Let PCs who already heard the tree hear it again
+    pub async fn reset(
+        &self,
+        world_id: WorldId,
+        tree_id: DialogueTreeId,
+    ) -> Result<DialogueTreeData, DialogueTreeError> {
+        let mut tree = self.world_tree(world_id, tree_id).await?;
+        tree.reset_plays(self.clock.now());
+        self.dialogue_tree.save(&tree).await?;
+        let speaker_name = self
+            .character
+            .get(tree.speaker_id)
+            .await?
+            .map(|c| c.name)
+            .unwrap_or_default();
+        Ok(tree_to_protocol(&tree, speaker_name))
+    }
+
+    async fn apply_input(
+        &self,
+        tree: &mut DialogueTree,
+        input: DialogueTreeInputData,
+    ) -> Result<(), DialogueTreeError> {
+        let narrative_event_id = match input.narrative_event_id.as_deref() {
+            Some(id) if !id.trim().is_empty() => {
+                let event_id = Uuid::parse_str(id.trim())
+                    .map(NarrativeEventId::from_uuid)
+                    .map_err(|_| DialogueTreeError::Invalid("Invalid event ID".to_string()))?;
+                match self.narrative.get_event(event_id).await? {
+                    Some(event) if event.world_id == tree.world_id => Some(event_id),
+                    _ => return Err(DialogueTreeError::EventNotFound),
+                }
+            }
+            _ => None,
+        };
+        let nodes = input
+            .nodes
+            .into_iter()
+            .map(node_from_protocol)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let now = self.clock.now();
+        tree.rename(&input.name, now)?;
+        tree.set_nodes(nodes, now)?;
+        tree.narrative_event_id = narrative_event_id;
+        tree.active = input.active;
+        tree.repeatable = input.repeatable;
+        Ok(())
+    }
+
+    async fn world_tree(
+        &self,
+        world_id: WorldId,
+        tree_id: DialogueTreeId,
+    ) -> Result<DialogueTree, DialogueTreeError> {
+        match self.dialogue_tree.get(tree_id).await? {
+            Some(tree) if tree.world_id == world_id => Ok(tree),
+            _ => Err(DialogueTreeError::NotFound),
+        }
+    }
+
+    /// An NPC of the world and their name
+    async fn world_speaker(
+        &self,
+        world_id: WorldId,
+        speaker_id: &str,
+    ) -> Result<(CharacterId, String), DialogueTreeError> {
+        let speaker_id = Uuid::parse_str(speaker_id)
+            .map(CharacterId::from_uuid)
+            .map_err(|_| DialogueTreeError::Invalid("Invalid character ID".to_string()))?;
+        match self.character.get(speaker_id).await? {
+            Some(npc) if npc.world_id == world_id => Ok((speaker_id, npc.name)),
+            _ => Err(DialogueTreeError::SpeakerNotFound),
+        }
+    }
+}
+
+/// Where a PC is in a tree
+#[derive(Debug, Clone)]
+struct Beat {
+    world_id: WorldId,
+    tree_id: DialogueTreeId,
+    node_id: String,
+    conversation_id: Uuid,
+    speaker_id: CharacterId,
+    speaker_name: String,
+}
+
+/// A scripted line to show a player
+#[derive(Debug, Clone)]
+pub struct BeatLine {
+    pub conversation_id: Uuid,
+    pub speaker_id: CharacterId,
+    pub speaker_name: String,
+    pub text: String,
+    /// Empty when the line ends the beat
+    pub choices: Vec<DialogueChoice>,
+}
+
+/// What answering a scripted line led to
+#[derive(Debug, Clone)]
+pub enum BeatStep {
+    Line(BeatLine),
+    Ended {
+        conversation_id: Uuid,
+        speaker_id: CharacterId,
+        speaker_name: String,
+    },
+}
+
+/// Play dialogue trees to PCs and follow their answers.
+pub struct ScriptedBeats {
+    dialogue_tree: Arc<entities::DialogueTree>,
+    character: Arc<entities::Character>,
+    execute_effects: Arc<ExecuteEffects>,
+    clock: Arc<dyn ClockPort>,
+    beats: Mutex<HashMap<PlayerCharacterId, Beat>>,
+}
+
+impl ScriptedBeats {
+    pub fn new(
+        dialogue_tree: Arc<entities::DialogueTree>,
+        character: Arc<entities::Character>,
+        execute_effects: Arc<ExecuteEffects>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            dialogue_tree,
+            character,
+            execute_effects,
+            clock,
+            beats: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// A PC started talking to an NPC: play the first of the NPC's trees
+    /// the PC hasn't heard, if any. Any beat the PC was in ends.
+    pub async fn begin_for_npc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        npc_id: CharacterId,
+    ) -> Result<Option<BeatLine>, DialogueTreeError> {
+        self.lock().remove(&pc_id);
+        let trees = self.dialogue_tree.list_in_world(world_id).await?;
+        let Some(tree) = trees.into_iter().find(|t| {
+            t.speaker_id == npc_id && t.narrative_event_id.is_none() && t.can_play_for(pc_id)
+        }) else {
+            return Ok(None);
+        };
+        self.begin(tree, pc_id).await.map(Some)
+    }
+
+    /// A narrative event triggered: play its trees to the PCs who haven't
+    /// heard them, one tree per PC
+    pub async fn begin_for_event(
+        &self,
+        world_id: WorldId,
+        event_id: NarrativeEventId,
+        pc_ids: &[PlayerCharacterId],
+    ) -> Result<Vec<(PlayerCharacterId, BeatLine)>, DialogueTreeError> {
+        let trees: Vec<DialogueTree> = self
+            .dialogue_tree
+            .list_in_world(world_id)
+            .await?
+            .into_iter()
+            .filter(|t| t.narrative_event_id == Some(event_id))
+            .collect();
+        let mut lines = Vec::new();
+        for &pc_id in pc_ids {
+            // Saving a play changes the tree, so look it up fresh per PC
+            let Some(tree_id) = trees.iter().find(|t| t.can_play_for(pc_id)).map(|t| t.id) else {
+                continue;
+            };
+            let Some(tree) = self.dialogue_tree.get(tree_id).await? else {
+                continue;
+            };
+            if tree.can_play_for(pc_id) {
+                self.lock().remove(&pc_id);
+                lines.push((pc_id, self.begin(tree, pc_id).await?));
+            }
+        }
+        Ok(lines)
+    }
+
+    /// Whether a PC is waiting to answer a scripted line
+    pub fn in_beat(&self, pc_id: PlayerCharacterId) -> bool {
+        self.lock().contains_key(&pc_id)
+    }
+
+    /// The player picked a choice: set its flags, run its effects and move
+    /// to the node it leads to
+    pub async fn choose(
+        &self,
+        pc_id: PlayerCharacterId,
+        choice_id: &str,
+    ) -> Result<BeatStep, DialogueTreeError> {
+        let beat = self
+            .lock()
+            .get(&pc_id)
+            .cloned()
+            .ok_or(DialogueTreeError::NoBeat)?;
+        let Some(tree) = self.dialogue_tree.get(beat.tree_id).await? else {
+            self.lock().remove(&pc_id);
+            return Err(DialogueTreeError::NotFound);
+        };
+        let branch = tree
+            .node(&beat.node_id)
+            .and_then(|node| node.choice(choice_id))
+            .ok_or_else(|| DialogueTreeError::Invalid("That choice isn't on offer".to_string()))?
+            .clone();
+
+        self.run_branch(&beat, pc_id, &branch).await;
+
+        let next = branch.next.as_deref().and_then(|id| tree.node(id));
+        let Some(next) = next else {
+            self.lock().remove(&pc_id);
+            return Ok(BeatStep::Ended {
+                conversation_id: beat.conversation_id,
+                speaker_id: beat.speaker_id,
+                speaker_name: beat.speaker_name,
+            });
+        };
+        let line = self.line(&beat, next);
+        self.follow(pc_id, beat, next);
+        Ok(BeatStep::Line(line))
+    }
+
+    /// The player said something the tree didn't offer: the beat ends and
+    /// the caller hands the words to the returned speaker's LLM conversation
+    pub fn go_off_script(&self, pc_id: PlayerCharacterId) -> Option<CharacterId> {
+        self.lock().remove(&pc_id).map(|beat| beat.speaker_id)
+    }
+
+    async fn begin(
+        &self,
+        mut tree: DialogueTree,
+        pc_id: PlayerCharacterId,
+    ) -> Result<BeatLine, DialogueTreeError> {
+        let start = tree
+            .start()
+            .cloned()
+            .ok_or_else(|| DialogueTreeError::Invalid("The tree has no nodes".to_string()))?;
+        let speaker_name = self
+            .character
+            .get(tree.speaker_id)
+            .await?
+            .map(|c| c.name)
+            .ok_or(DialogueTreeError::SpeakerNotFound)?;
+        if tree.mark_played(pc_id, self.clock.now()) {
+            self.dialogue_tree.save(&tree).await?;
+        }
+
+        let beat = Beat {
+            world_id: tree.world_id,
+            tree_id: tree.id,
+            node_id: start.id.clone(),
+            conversation_id: Uuid::new_v4(),
+            speaker_id: tree.speaker_id,
+            speaker_name,
+        };
+        tracing::info!(
+            tree_id = %tree.id,
+            pc_id = %pc_id,
+            "Scripted dialogue started"
+        );
+        let line = self.line(&beat, &start);
+        self.follow(pc_id, beat, &start);
+        Ok(line)
+    }
+
+    /// Wait for the PC's answer to `node`, or end the beat if it has none
+    fn follow(&self, pc_id: PlayerCharacterId, mut beat: Beat, node: &DialogueNode) {
+        let mut beats = self.lock();
+        if node.choices.is_empty() {
+            beats.remove(&pc_id);
+        } else {
+            beat.node_id = node.id.clone();
+            beats.insert(pc_id, beat);
+        }
+    }
+
+    fn line(&self, beat: &Beat, node: &DialogueNode) -> BeatLine {
+        let mut choices: Vec<DialogueChoice> = node
+            .choices
+            .iter()
+            .enumerate()
+            .map(|(index, choice)| DialogueChoice {
+                id: node.choice_id(index),
+                text: choice.text.clone(),
+                is_custom_input: false,
+            })
+            .collect();
+        if !choices.is_empty() {
+            choices.push(DialogueChoice {
+                id: OFF_SCRIPT_CHOICE_ID.to_string(),
+                text: "Say something else".to_string(),
+                is_custom_input: true,
+            });
+        }
+        BeatLine {
+            conversation_id: beat.conversation_id,
+            speaker_id: beat.speaker_id,
+            speaker_name: beat.speaker_name.clone(),
+            text: node.line.clone(),
+            choices,
+        }
+    }
+
+    /// Set the branch's flags and run its effects; failures are logged, as
+    /// for event outcomes, and don't stop the beat
+    async fn run_branch(&self, beat: &Beat, pc_id: PlayerCharacterId, branch: &DialogueBranch) {
+        let context = EffectExecutionContext {
+            pc_id,
+            world_id: beat.world_id,
+            current_scene_id: None,
+        };
+        let flags = branch.set_flags.iter().map(|flag| EventEffect::SetFlag {
+            flag_name: flag.clone(),
+            value: true,
+        });
+        for effect in flags.chain(branch.effects.iter().cloned()) {
+            let result = self
+                .execute_effects
+                .execute_single_effect(&effect, &context)
+                .await;
+            if !result.success && !result.requires_dm_action {
+                tracing::warn!(
+                    tree_id = %beat.tree_id,
+                    effect = %result.description,
+                    error = ?result.error,
+                    "Dialogue choice effect failed"
+                );
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<PlayerCharacterId, Beat>> {
+        self.beats.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn node_from_protocol(node: DialogueNodeData) -> Result<DialogueNode, DialogueTreeError> {
+    let choices = node
+        .choices
+        .into_iter()
+        .map(|choice| {
+            let effects = choice
+                .effects
+                .into_iter()
+                .map(serde_json::from_value::<EventEffect>)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| DialogueTreeError::Invalid(format!("Invalid effect: {}", e)))?;
+            Ok(DialogueBranch {
+                text: choice.text,
+                next: choice.next,
+                set_flags: choice.set_flags,
+                effects,
+            })
+        })
+        .collect::<Result<Vec<_>, DialogueTreeError>>()?;
+    Ok(DialogueNode {
+        id: node.id,
+        line: node.line,
+        choices,
+    })
+}
+
+pub fn tree_to_protocol(tree: &DialogueTree, speaker_name: String) -> DialogueTreeData {
+    DialogueTreeData {
+        id: tree.id.to_string(),
+        name: tree.name.clone(),
+        speaker_id: tree.speaker_id.to_string(),
+        speaker_name,
+        narrative_event_id: tree.narrative_event_id.map(|id| id.to_string()),
+        nodes: tree
+            .nodes
+            .iter()
+            .map(|node| DialogueNodeData {
+                id: node.id.clone(),
+                line: node.line.clone(),
+                choices: node
+                    .choices
+                    .iter()
+                    .map(|choice| DialogueBranchData {
+                        text: choice.text.clone(),
+                        next: choice.next.clone(),
+                        set_flags: choice.set_flags.clone(),
+                        effects: choice
+                            .effects
+                            .iter()
+                            .filter_map(|e| serde_json::to_value(e).ok())
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+        active: tree.active,
+        repeatable: tree.repeatable,
+        played_count: u32::try_from(tree.played_by.len()).unwrap_or(u32::MAX),
+    }
+}
@@ -0,0 +1,125 @@
+//! Dice tray use cases.
+//!
+//! Free-form dice rolls requested from the player's dice tray. The server is
+//! the only source of truth for the result; clients animate locally and then
+//! show whatever comes back here.
+//!
+//! Rolls accept the full expression notation (`4d6kh3`, `3d6!`, `d20adv+5`,
+//! `5d10>=8`, ...); see `wrldbldr_domain::DiceExpression`.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{DiceExpression, DiceParseError, PlayerCharacterId, RollBreakdown};
+use wrldbldr_protocol::types::{DiceDieData, DiceRollData, DiceTermData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RandomPort, RepoError};
+
+/// Container for dice use cases.
+pub struct DiceUseCases {
+    pub roll: Arc<RollDice>,
+}
+
+impl DiceUseCases {
+    pub fn new(roll: Arc<RollDice>) -> Self {
+        Self { roll }
+    }
+}
+
+/// Roll a dice expression on behalf of a connection.
+pub struct RollDice {
+    player_character: Arc<entities::PlayerCharacter>,
+    random: Arc<dyn RandomPort>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl RollDice {
+    pub fn new(
+        player_character: Arc<entities::PlayerCharacter>,
+        random: Arc<dyn RandomPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            player_character,
+            random,
+            clock,
+        }
+    }
+
+    /// Parse and roll `formula`.
+    ///
+    /// The roll is attributed to the PC's name when one is given, otherwise
+    /// to `fallback_name`.
+    pub async fn execute(
+        &self,
+        formula: &str,
+        pc_id: Option<PlayerCharacterId>,
+        fallback_name: &str,
+    ) -> Result<DiceRollData, DiceError> {
+        let expression = DiceExpression::parse(formula)?;
+        let result = expression.roll(|min, max| self.random.gen_range(min, max));
+
+        let roller_name = match pc_id {
+            Some(pc_id) => self
+                .player_character
+                .get(pc_id)
+                .await?
+                .map(|pc| pc.name)
+                .unwrap_or_else(|| fallback_name.to_string()),
+            None => fallback_name.to_string(),
+        };
+
+        Ok(roll_to_protocol(
+            &result,
+            pc_id.map(|id| id.to_string()),
+            roller_name,
+            self.clock.now().timestamp(),
+        ))
+    }
+}
+
+pub fn roll_to_protocol(
+    result: &RollBreakdown,
+    pc_id: Option<String>,
+    roller_name: String,
+    rolled_at: i64,
+) -> DiceRollData {
+    DiceRollData {
+        formula: result.expression.clone(),
+        individual_rolls: result.kept_rolls(),
+        dice_total: result.dice_total(),
+        modifier: result.modifier(),
+        total: result.total,
+        breakdown: result.text(),
+        pc_id,
+        roller_name,
+        rolled_at,
+        terms: result
+            .terms
+            .iter()
+            .map(|term| DiceTermData {
+                notation: term.notation.clone(),
+                negative: term.negative,
+                dice: term
+                    .dice
+                    .iter()
+                    .map(|die| DiceDieData {
+                        value: die.value,
+                        kept: die.kept,
+                        exploded: die.exploded,
+                    })
+                    .collect(),
+                value: term.value,
+            })
+            .collect(),
+        successes: result.successes,
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DiceError {
+    #[error("{0}")]
+    InvalidFormula(#[from] DiceParseError),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
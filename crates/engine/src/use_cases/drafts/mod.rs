@@ -0,0 +1,474 @@
+//! Content draft use cases.
+//!
+//! Generated text goes through a draft before it becomes canonical: each LLM
+//! suggestion or hand edit of a character or location text field is a
+//! revision, the DM compares revisions side by side, and publishing one
+//! writes its text to the entity. Drafts are scoped to their world; drafts
+//! in other worlds are treated as missing.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    CharacterId, ContentDraft, ContentDraftId, DiffOp, DraftField, DraftStatus, EntityType,
+    LocationId, RevisionSource, WorldId,
+};
+use wrldbldr_protocol::types::{
+    ContentDraftData, DiffOpData, DiffSegmentData, DraftDiffData, DraftFieldData,
+    DraftRevisionData, DraftStatusData, RevisionSourceData,
+};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for content draft use cases.
+pub struct DraftUseCases {
+    pub manage: Arc<ManageDrafts>,
+}
+
+impl DraftUseCases {
+    pub fn new(manage: Arc<ManageDrafts>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DraftError {
+    #[error("Draft not found")]
+    NotFound,
+    #[error("{0} not found")]
+    EntityNotFound(EntityType),
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+/// Add, compare, publish and discard draft revisions.
+pub struct ManageDrafts {
+    draft: Arc<entities::Draft>,
+    character: Arc<entities::Character>,
+    location: Arc<entities::Location>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageDrafts {
+    pub fn new(
+        draft: Arc<entities::Draft>,
+        character: Arc<entities::Character>,
+        location: Arc<entities::Location>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            draft,
+            character,
+            location,
+            clock,
+        }
+    }
+
+    pub async fn list(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<ContentDraftData>, DraftError> {
+        let drafts = self.draft.list_for_entity(entity_type, entity_id).await?;
+        Ok(drafts
+            .iter()
+            .filter(|d| d.world_id == world_id)
+            .map(draft_to_protocol)
+            .collect())
+    }
+
+    pub async fn get(
+        &self,
+        world_id: WorldId,
+        draft_id: ContentDraftId,
+    ) -> Result<ContentDraftData, DraftError> {
+        let draft = self.world_draft(world_id, draft_id).await?;
+        Ok(draft_to_protocol(&draft))
+    }
+
+    /// Add a revision to the entity's draft of `field`, starting the draft
+    /// if there is none. The entity itself is not changed.
+    pub async fn add_revision(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        field: DraftFieldData,
+        text: String,
+        source: RevisionSourceData,
+    ) -> Result<ContentDraftData, DraftError> {
+        let field = field_from_protocol(field)?;
+        let source = source_from_protocol(source)?;
+        if !self
+            .entity_in_world(world_id, entity_type, entity_id)
+            .await?
+        {
+            return Err(DraftError::EntityNotFound(entity_type));
+        }
+
+        let now = self.clock.now();
+        let existing = self
+            .draft
+            .list_for_entity(entity_type, entity_id)
+            .await?
+            .into_iter()
+            .find(|d| d.field == field);
+        let draft = match existing {
+            Some(mut draft) => {
+                draft
+                    .add_revision(text, source, now)
+                    .map_err(|e| DraftError::Invalid(e.to_string()))?;
+                draft
+            }
+            None => ContentDraft::new(world_id, entity_type, entity_id, field, text, source, now)
+                .map_err(|e| DraftError::Invalid(e.to_string()))?,
+        };
+        self.draft.save(&draft).await?;
+        Ok(draft_to_protocol(&draft))
+    }
+
+    pub async fn diff(
+        &self,
+        world_id: WorldId,
+        draft_id: ContentDraftId,
+        from: u32,
+        to: u32,
+    ) -> Result<DraftDiffData, DraftError> {
+        let draft = self.world_draft(world_id, draft_id).await?;
+        let segments = draft
+            .diff(from, to)
+            .map_err(|e| DraftError::Invalid(e.to_string()))?;
+        Ok(DraftDiffData {
+            draft_id: draft.id.to_string(),
+            from,
+            to,
+            segments: segments
+                .into_iter()
+                .map(|s| DiffSegmentData {
+                    op: match s.op {
+                        DiffOp::Equal => DiffOpData::Equal,
+                        DiffOp::Insert => DiffOpData::Insert,
+                        DiffOp::Delete => DiffOpData::Delete,
+                    },
+                    text: s.text,
+                })
+                .collect(),
+        })
+    }
+
+    /// Write a revision's text to its entity.
+    pub async fn publish(
+        &self,
+        world_id: WorldId,
+        draft_id: ContentDraftId,
+        revision: u32,
+    ) -> Result<ContentDraftData, DraftError> {
+        let mut draft = self.world_draft(world_id, draft_id).await?;
+        let text = draft
+            .publish(revision, self.clock.now())
+            .map_err(|e| DraftError::Invalid(e.to_string()))?
+            .to_string();
+
+        match draft.entity_type {
+            EntityType::Character => {
+                let mut character = self
+                    .character
+                    .get(CharacterId::from_uuid(draft.entity_id))
+                    .await?
+                    .ok_or(DraftError::EntityNotFound(EntityType::Character))?;
+                character.description = text;
+                self.character.save(&character).await?;
+            }
+            EntityType::Location => {
+                let mut location = self
+                    .location
+                    .get(LocationId::from_uuid(draft.entity_id))
+                    .await?
+                    .ok_or(DraftError::EntityNotFound(EntityType::Location))?;
+                match draft.field {
+                    DraftField::Description => location.description = text,
+                    DraftField::Atmosphere => location.atmosphere = Some(text),
+                }
+                self.location.save_location(&location).await?;
+            }
+            other => return Err(DraftError::EntityNotFound(other)),
+        }
+        self.draft.save(&draft).await?;
+
+        tracing::info!(
+            draft_id = %draft.id,
+            entity_type = %draft.entity_type,
+            entity_id = %draft.entity_id,
+            field = draft.field.as_str(),
+            revision,
+            "Draft revision published"
+        );
+        Ok(draft_to_protocol(&draft))
+    }
+
+    /// Delete a draft and its history. The entity keeps its current text.
+    pub async fn discard(
+        &self,
+        world_id: WorldId,
+        draft_id: ContentDraftId,
+    ) -> Result<(), DraftError> {
+        self.world_draft(world_id, draft_id).await?;
+        Ok(self.draft.delete(draft_id).await?)
+    }
+
+    /// The draft, if it exists and belongs to `world_id`.
+    async fn world_draft(
+        &self,
+        world_id: WorldId,
+        draft_id: ContentDraftId,
+    ) -> Result<ContentDraft, DraftError> {
+        self.draft
+            .get(draft_id)
+            .await?
+            .filter(|d| d.world_id == world_id)
+            .ok_or(DraftError::NotFound)
+    }
+
+    async fn entity_in_world(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<bool, DraftError> {
+        Ok(match entity_type {
+            EntityType::Character => self
+                .character
+                .get(CharacterId::from_uuid(entity_id))
+                .await?
+                .is_some_and(|c| c.world_id == world_id),
+            EntityType::Location => self
+                .location
+                .get(LocationId::from_uuid(entity_id))
+                .await?
+                .is_some_and(|l| l.world_id == world_id),
+            other => {
+                return Err(DraftError::Invalid(format!(
+                    "{} text can't be drafted",
+                    other
+                )))
+            }
+        })
+    }
+}
+
+fn field_from_protocol(field: DraftFieldData) -> Result<DraftField, DraftError> {
+    match field {
+        DraftFieldData::Description => Ok(DraftField::Description),
+        DraftFieldData::Atmosphere => Ok(DraftField::Atmosphere),
+        DraftFieldData::Unknown => Err(DraftError::Invalid("Unknown draft field".to_string())),
+    }
+}
+
+fn source_from_protocol(source: RevisionSourceData) -> Result<RevisionSource, DraftError> {
+    match source {
+        RevisionSourceData::Generated => Ok(RevisionSource::Generated),
+        RevisionSourceData::Edited => Ok(RevisionSource::Edited),
+        RevisionSourceData::Unknown => {
+            Err(DraftError::Invalid("Unknown revision source".to_string()))
+        }
+    }
+}
+
+fn draft_to_protocol(draft: &ContentDraft) -> ContentDraftData {
+    ContentDraftData {
+        id: draft.id.to_string(),
+        world_id: draft.world_id.to_string(),
+        entity_type: draft.entity_type,
+        entity_id: draft.entity_id.to_string(),
+        field: match draft.field {
+            DraftField::Description => DraftFieldData::Description,
+            DraftField::Atmosphere => DraftFieldData::Atmosphere,
+        },
+        status: match draft.status() {
+            DraftStatus::Draft => DraftStatusData::Draft,
+            DraftStatus::Published => DraftStatusData::Published,
+        },
+        revisions: draft
+            .revisions
+            .iter()
+            .map(|r| DraftRevisionData {
+                number: r.number,
+                text: r.text.clone(),
+                source: match r.source {
+                    RevisionSource::Generated => RevisionSourceData::Generated,
+                    RevisionSource::Edited => RevisionSourceData::Edited,
+                },
+                created_at: r.created_at.to_rfc3339(),
+            })
+            .collect(),
+        published_revision: draft.published_revision,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{MockCharacterRepo, MockContentDraftRepo, MockLocationRepo};
+    use wrldbldr_domain::{CampbellArchetype, LocationType};
+
+    struct Repos {
+        draft: MockContentDraftRepo,
+        character: MockCharacterRepo,
+        location: MockLocationRepo,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            Self {
+                draft: MockContentDraftRepo::new(),
+                character: MockCharacterRepo::new(),
+                location: MockLocationRepo::new(),
+            }
+        }
+
+        fn build(self) -> ManageDrafts {
+            ManageDrafts::new(
+                Arc::new(entities::Draft::new(Arc::new(self.draft))),
+                Arc::new(entities::Character::new(Arc::new(self.character))),
+                Arc::new(entities::Location::new(Arc::new(self.location))),
+                Arc::new(FixedClock(chrono::Utc::now())),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn a_new_revision_joins_the_existing_draft_without_touching_the_entity() {
+        let world_id = WorldId::new();
+        let npc = wrldbldr_domain::Character::new(world_id, "Marta", CampbellArchetype::Mentor);
+        let npc_id = npc.id;
+        let existing = ContentDraft::new(
+            world_id,
+            EntityType::Character,
+            npc_id.into(),
+            DraftField::Description,
+            "A stout innkeeper.",
+            RevisionSource::Generated,
+            chrono::Utc::now(),
+        )
+        .expect("valid draft");
+
+        let mut repos = Repos::new();
+        repos
+            .character
+            .expect_get()
+            .returning(move |_| Ok(Some(npc.clone())));
+        repos
+            .draft
+            .expect_list_for_entity()
+            .returning(move |_, _| Ok(vec![existing.clone()]));
+        repos
+            .draft
+            .expect_save()
+            .withf(|d| d.revisions.len() == 2 && d.published_revision.is_none())
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.character.expect_save().never();
+
+        let draft = repos
+            .build()
+            .add_revision(
+                world_id,
+                EntityType::Character,
+                npc_id.into(),
+                DraftFieldData::Description,
+                "A stout innkeeper with flour on her apron.".to_string(),
+                RevisionSourceData::Edited,
+            )
+            .await
+            .expect("revision added");
+        assert_eq!(draft.status, DraftStatusData::Draft);
+        assert_eq!(draft.revisions[1].number, 2);
+        assert_eq!(draft.revisions[1].source, RevisionSourceData::Edited);
+    }
+
+    #[tokio::test]
+    async fn publishing_writes_the_revision_to_the_location() {
+        let world_id = WorldId::new();
+        let location = wrldbldr_domain::Location::new(world_id, "Old Mill", LocationType::Exterior);
+        let location_id = location.id;
+        let mut draft = ContentDraft::new(
+            world_id,
+            EntityType::Location,
+            location_id.into(),
+            DraftField::Atmosphere,
+            "Creaking boards.",
+            RevisionSource::Generated,
+            chrono::Utc::now(),
+        )
+        .expect("valid draft");
+        draft
+            .add_revision(
+                "Creaking boards and the smell of wet grain.",
+                RevisionSource::Generated,
+                chrono::Utc::now(),
+            )
+            .expect("added");
+        let draft_id = draft.id;
+
+        let mut repos = Repos::new();
+        repos
+            .draft
+            .expect_get()
+            .returning(move |_| Ok(Some(draft.clone())));
+        repos
+            .location
+            .expect_get_location()
+            .returning(move |_| Ok(Some(location.clone())));
+        repos
+            .location
+            .expect_save_location()
+            .withf(|l| l.atmosphere.as_deref() == Some("Creaking boards."))
+            .times(1)
+            .returning(|_| Ok(()));
+        repos
+            .draft
+            .expect_save()
+            .withf(|d| d.published_revision == Some(1))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let published = repos
+            .build()
+            .publish(world_id, draft_id, 1)
+            .await
+            .expect("published");
+        // Revision 2 is newer than the published one
+        assert_eq!(published.status, DraftStatusData::Draft);
+        assert_eq!(published.published_revision, Some(1));
+    }
+
+    #[tokio::test]
+    async fn drafts_in_other_worlds_are_not_found() {
+        let draft = ContentDraft::new(
+            WorldId::new(),
+            EntityType::Character,
+            Uuid::new_v4(),
+            DraftField::Description,
+            "Someone else's NPC.",
+            RevisionSource::Edited,
+            chrono::Utc::now(),
+        )
+        .expect("valid draft");
+        let draft_id = draft.id;
+
+        let mut repos = Repos::new();
+        repos
+            .draft
+            .expect_get()
+            .returning(move |_| Ok(Some(draft.clone())));
+        repos.draft.expect_delete().never();
+
+        let result = repos.build().discard(WorldId::new(), draft_id).await;
+        assert!(matches!(result, Err(DraftError::NotFound)));
+    }
+}
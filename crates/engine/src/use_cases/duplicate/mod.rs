@@ -0,0 +1,322 @@
+//! Duplicate use cases.
+//!
+//! Deep copies of world content so the DM can start from an existing entity
+//! instead of re-entering it. The copy gets new IDs throughout and a "(copy)"
+//! name; nested content (a location's regions, a challenge's outcomes) keeps
+//! its names. Tags and custom field values come along with the copy.
+//!
+//! Graph ties to other content are not copied: a copied NPC has no region
+//! ties or relationships, a copied location has no parent and no connections
+//! to other locations, and a copied event has no featured NPCs and is not
+//! part of any chain.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    copy_name, ChallengeId, CharacterId, EntityType, ItemId, LocationId, NarrativeEventId,
+    RegionConnection, RegionId, WorldId,
+};
+
+use crate::entities;
+use crate::entities::inventory::InventoryError;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for duplicate use cases.
+pub struct DuplicateUseCases {
+    pub entity: Arc<DuplicateEntity>,
+}
+
+impl DuplicateUseCases {
+    pub fn new(entity: Arc<DuplicateEntity>) -> Self {
+        Self { entity }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DuplicateError {
+    #[error("Not found")]
+    NotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+    #[error("Inventory error: {0}")]
+    Inventory(#[from] InventoryError),
+}
+
+/// Deep-copy characters, locations, items, challenges and narrative events.
+pub struct DuplicateEntity {
+    character: Arc<entities::Character>,
+    location: Arc<entities::Location>,
+    inventory: Arc<entities::Inventory>,
+    challenge: Arc<entities::Challenge>,
+    narrative: Arc<entities::Narrative>,
+    tag: Arc<entities::Tag>,
+    custom_field: Arc<entities::CustomField>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl DuplicateEntity {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        character: Arc<entities::Character>,
+        location: Arc<entities::Location>,
+        inventory: Arc<entities::Inventory>,
+        challenge: Arc<entities::Challenge>,
+        narrative: Arc<entities::Narrative>,
+        tag: Arc<entities::Tag>,
+        custom_field: Arc<entities::CustomField>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            character,
+            location,
+            inventory,
+            challenge,
+            narrative,
+            tag,
+            custom_field,
+            clock,
+        }
+    }
+
+    pub async fn character(
+        &self,
+        character_id: CharacterId,
+    ) -> Result<wrldbldr_domain::Character, DuplicateError> {
+        let original = self
+            .character
+            .get(character_id)
+            .await?
+            .ok_or(DuplicateError::NotFound)?;
+        let mut copy = original.duplicate();
+        copy.name = copy_name(&original.name);
+        self.character.save(&copy).await?;
+
+        self.copy_annotations(
+            copy.world_id,
+            EntityType::Character,
+            original.id.into(),
+            copy.id.into(),
+        )
+        .await?;
+        Ok(copy)
+    }
+
+    /// Copy a location with its regions, the (non-unique) items lying in
+    /// them and the connections between them. Hotspots and the default
+    /// region are repointed at the copies.
+    pub async fn location(
+        &self,
+        location_id: LocationId,
+    ) -> Result<wrldbldr_domain::Location, DuplicateError> {
+        let original = self
+            .location
+            .get(location_id)
+            .await?
+            .ok_or(DuplicateError::NotFound)?;
+        let mut copy = original.duplicate();
+        copy.name = copy_name(&original.name);
+
+        let regions = self.location.list_regions_in_location(location_id).await?;
+        let mut region_ids: HashMap<RegionId, RegionId> = HashMap::new();
+        let mut region_copies = Vec::with_capacity(regions.len());
+        for region in &regions {
+            let region_copy = region.duplicate_into(copy.id);
+            region_ids.insert(region.id, region_copy.id);
+            region_copies.push(region_copy);
+        }
+
+        let mut item_ids: HashMap<ItemId, ItemId> = HashMap::new();
+        let mut item_copies = Vec::new();
+        for region in &regions {
+            for item in self.inventory.list_in_region(region.id).await? {
+                if item.is_unique {
+                    continue;
+                }
+                let item_copy = item.duplicate();
+                item_ids.insert(item.id, item_copy.id);
+                item_copies.push((item.id, item_copy, region_ids[&region.id]));
+            }
+        }
+
+        copy.default_region_id = original
+            .default_region_id
+            .and_then(|id| region_ids.get(&id).copied());
+        self.location.save_location(&copy).await?;
+
+        for (region, mut region_copy) in regions.iter().zip(region_copies) {
+            region_copy.remap_hotspots(&region_ids, &item_ids);
+            self.location.save_region(&region_copy).await?;
+            self.copy_annotations(
+                copy.world_id,
+                EntityType::Region,
+                region.id.into(),
+                region_copy.id.into(),
+            )
+            .await?;
+        }
+
+        // Connections within the location; those leading elsewhere stay
+        // with the original
+        for region in &regions {
+            for connection in self.location.get_connections(region.id).await? {
+                let (Some(from), Some(to)) = (
+                    region_ids.get(&connection.from_region),
+                    region_ids.get(&connection.to_region),
+                ) else {
+                    continue;
+                };
+                self.location
+                    .save_connection(&RegionConnection {
+                        from_region: *from,
+                        to_region: *to,
+                        ..connection
+                    })
+                    .await?;
+            }
+        }
+
+        for (original_item_id, item_copy, region_id) in item_copies {
+            let item_copy_id = item_copy.id;
+            self.inventory
+                .create_and_place_in_region(item_copy, region_id)
+                .await?;
+            self.copy_annotations(
+                copy.world_id,
+                EntityType::Item,
+                original_item_id.into(),
+                item_copy_id.into(),
+            )
+            .await?;
+        }
+
+        self.copy_annotations(
+            copy.world_id,
+            EntityType::Location,
+            original.id.into(),
+            copy.id.into(),
+        )
+        .await?;
+
+        tracing::info!(
+            location_id = %location_id,
+            copy_id = %copy.id,
+            regions = region_ids.len(),
+            items = item_ids.len(),
+            "Location duplicated"
+        );
+        Ok(copy)
+    }
+
+    /// Copy an item, placing the copy in `region_id` when given.
+    pub async fn item(
+        &self,
+        item_id: ItemId,
+        region_id: Option<RegionId>,
+    ) -> Result<wrldbldr_domain::Item, DuplicateError> {
+        let original = self
+            .inventory
+            .get(item_id)
+            .await?
+            .ok_or(DuplicateError::NotFound)?;
+        if original.is_unique {
+            return Err(DuplicateError::Invalid(
+                "Unique items can't be duplicated".to_string(),
+            ));
+        }
+        let mut copy = original.duplicate();
+        copy.name = copy_name(&original.name);
+
+        match region_id {
+            Some(region_id) => {
+                self.location
+                    .get_region(region_id)
+                    .await?
+                    .ok_or(DuplicateError::NotFound)?;
+                self.inventory
+                    .create_and_place_in_region(copy.clone(), region_id)
+                    .await?;
+            }
+            None => self.inventory.save(&copy).await?,
+        }
+
+        self.copy_annotations(
+            copy.world_id,
+            EntityType::Item,
+            original.id.into(),
+            copy.id.into(),
+        )
+        .await?;
+        Ok(copy)
+    }
+
+    pub async fn challenge(
+        &self,
+        challenge_id: ChallengeId,
+    ) -> Result<wrldbldr_domain::Challenge, DuplicateError> {
+        let original = self
+            .challenge
+            .get(challenge_id)
+            .await?
+            .ok_or(DuplicateError::NotFound)?;
+        let mut copy = original.duplicate();
+        copy.name = copy_name(&original.name);
+        self.challenge.save(&copy).await?;
+
+        self.copy_annotations(
+            copy.world_id,
+            EntityType::Challenge,
+            original.id.into(),
+            copy.id.into(),
+        )
+        .await?;
+        Ok(copy)
+    }
+
+    pub async fn narrative_event(
+        &self,
+        event_id: NarrativeEventId,
+    ) -> Result<wrldbldr_domain::NarrativeEvent, DuplicateError> {
+        let original = self
+            .narrative
+            .get_event(event_id)
+            .await?
+            .ok_or(DuplicateError::NotFound)?;
+        let mut copy = original.duplicate(self.clock.now());
+        copy.name = copy_name(&original.name);
+        self.narrative.save_event(&copy).await?;
+
+        self.copy_annotations(
+            copy.world_id,
+            EntityType::NarrativeEvent,
+            original.id.into(),
+            copy.id.into(),
+        )
+        .await?;
+        Ok(copy)
+    }
+
+    /// Copy the DM's tags and custom field values from one entity to another.
+    async fn copy_annotations(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        from: Uuid,
+        to: Uuid,
+    ) -> Result<(), RepoError> {
+        let tags = self.tag.get_tags(entity_type, from).await?;
+        if !tags.is_empty() {
+            self.tag.set_tags(world_id, entity_type, to, &tags).await?;
+        }
+        let values = self.custom_field.get_values(entity_type, from).await?;
+        if !values.is_empty() {
+            self.custom_field
+                .set_values(world_id, entity_type, to, &values)
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,546 @@
+//! Economy use cases.
+//!
+//! Items carry a base price; market modifiers make goods scarcer, cheaper
+//! or dearer by location and item type, some only while a world flag is
+//! set. Local prices are worked out on demand, for the DM's overview of a
+//! location and for the wares an NPC merchant offers in dialogue.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    market_conditions, CharacterId, DomainError, Item, ItemId, LocationId, MarketModifier,
+    MarketModifierId, Scarcity, WorldId,
+};
+use wrldbldr_protocol::types::{
+    EconomyOverviewData, MarketCategoryData, MarketItemData, MarketModifierData,
+    MarketModifierInputData, ScarcityData,
+};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for economy use cases.
+pub struct EconomyUseCases {
+    pub manage: Arc<ManageEconomy>,
+}
+
+impl EconomyUseCases {
+    pub fn new(manage: Arc<ManageEconomy>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum EconomyError {
+    #[error("Market modifier not found")]
+    ModifierNotFound,
+    #[error("Location not found")]
+    LocationNotFound,
+    #[error("Item not found")]
+    ItemNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for EconomyError {
+    fn from(e: DomainError) -> Self {
+        EconomyError::Invalid(e.to_string())
+    }
+}
+
+/// Something a merchant has for sale, at the local price
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerchantPrice {
+    pub item_name: String,
+    pub price: u32,
+    pub scarcity: Scarcity,
+    /// Why the price differs from usual; empty when it doesn't
+    pub reasons: Vec<String>,
+}
+
+/// Manage market modifiers and work out local prices.
+pub struct ManageEconomy {
+    economy: Arc<entities::Economy>,
+    inventory: Arc<entities::Inventory>,
+    location: Arc<entities::Location>,
+    flag: Arc<entities::Flag>,
+    world: Arc<entities::World>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageEconomy {
+    pub fn new(
+        economy: Arc<entities::Economy>,
+        inventory: Arc<entities::Inventory>,
+        location: Arc<entities::Location>,
+        flag: Arc<entities::Flag>,
+        world: Arc<entities::World>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            economy,
+            inventory,
+            location,
+            flag,
+            world,
+            clock,
+        }
+    }
+
+    /// The world's modifiers, oldest first
+    pub async fn list_modifiers(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<MarketModifierData>, EconomyError> {
+        let modifiers = self.economy.list_modifiers(world_id).await?;
+        let flags = self.flag.get_world_flags(world_id).await?;
+        Ok(modifiers
+            .iter()
+            .map(|m| market_modifier_to_protocol(m, &flags))
+            .collect())
+    }
+
+    pub async fn create_modifier(
+        &self,
+        world_id: WorldId,
+        input: MarketModifierInputData,
+    ) -> Result<MarketModifierData, EconomyError> {
+        let mut modifier = MarketModifier::new(world_id, input.reason.clone(), self.clock.now())?;
+        self.apply_input(world_id, &mut modifier, input).await?;
+        self.economy.save_modifier(&modifier).await?;
+        let flags = self.flag.get_world_flags(world_id).await?;
+        Ok(market_modifier_to_protocol(&modifier, &flags))
+    }
+
+    pub async fn update_modifier(
+        &self,
+        world_id: WorldId,
+        modifier_id: MarketModifierId,
+        input: MarketModifierInputData,
+    ) -> Result<MarketModifierData, EconomyError> {
+        let mut modifier = self.world_modifier(world_id, modifier_id).await?;
+        self.apply_input(world_id, &mut modifier, input).await?;
+        self.economy.save_modifier(&modifier).await?;
+        let flags = self.flag.get_world_flags(world_id).await?;
+        Ok(market_modifier_to_protocol(&modifier, &flags))
+    }
+
+    pub async fn delete_modifier(
+        &self,
+        world_id: WorldId,
+        modifier_id: MarketModifierId,
+    ) -> Result<(), EconomyError> {
+        let modifier = self.world_modifier(world_id, modifier_id).await?;
+        self.economy.delete_modifier(modifier.id).await?;
+        Ok(())
+    }
+
+    /// Set or clear an item's base price
+    pub async fn set_base_price(
+        &self,
+        world_id: WorldId,
+        item_id: ItemId,
+        base_price: Option<u32>,
+    ) -> Result<Item, EconomyError> {
+        let mut item = self
+            .inventory
+            .get(item_id)
+            .await?
+            .filter(|i| i.world_id == world_id)
+            .ok_or(EconomyError::ItemNotFound)?;
+        item.base_price = base_price;
+        self.inventory.save(&item).await?;
+        Ok(item)
+    }
+
+    /// Scarcity and local prices for every item type at a location
+    pub async fn overview(
+        &self,
+        world_id: WorldId,
+        location_id: LocationId,
+    ) -> Result<EconomyOverviewData, EconomyError> {
+        let location = self
+            .location
+            .get(location_id)
+            .await?
+            .filter(|l| l.world_id == world_id)
+            .ok_or(EconomyError::LocationNotFound)?;
+        let currency = self
+            .world
+            .get(world_id)
+            .await?
+            .and_then(|w| w.rule_system.variant.currency_field())
+            .map(str::to_string);
+        let flags = self.flag.get_world_flags(world_id).await?;
+        let modifiers: Vec<MarketModifier> = self
+            .economy
+            .list_modifiers(world_id)
+            .await?
+            .into_iter()
+            .filter(|m| m.location_id.is_none_or(|id| id == location_id))
+            .collect();
+
+        // Item types are grouped ignoring case, under the first spelling seen
+        let mut categories: BTreeMap<Option<String>, (Option<String>, Vec<Item>)> = BTreeMap::new();
+        let mut items = self.inventory.list_in_world(world_id).await?;
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+        for item in items {
+            let category = item.item_type.clone().filter(|t| !t.trim().is_empty());
+            categories
+                .entry(category.as_ref().map(|c| c.to_lowercase()))
+                .or_insert_with(|| (category, Vec::new()))
+                .1
+                .push(item);
+        }
+        for category in modifiers.iter().filter_map(|m| m.category.as_ref()) {
+            categories
+                .entry(Some(category.to_lowercase()))
+                .or_insert_with(|| (Some(category.clone()), Vec::new()));
+        }
+
+        // Named categories first, then items without a type
+        let mut grouped: Vec<(Option<String>, Vec<Item>)> = categories.into_values().collect();
+        grouped.sort_by_key(|(category, _)| category.is_none());
+        let categories = grouped
+            .into_iter()
+            .map(|(category, items)| {
+                let conditions =
+                    market_conditions(&modifiers, Some(location_id), category.as_deref(), &flags);
+                MarketCategoryData {
+                    category,
+                    scarcity: scarcity_to_protocol(conditions.scarcity),
+                    price_percent: conditions.price_percent(),
+                    reasons: conditions.reasons.clone(),
+                    items: items
+                        .into_iter()
+                        .map(|item| MarketItemData {
+                            item_id: item.id.to_string(),
+                            name: item.name,
+                            base_price: item.base_price,
+                            local_price: item.base_price.and_then(|p| conditions.price(p)),
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Ok(EconomyOverviewData {
+            location_id: location.id.to_string(),
+            location_name: location.name,
+            currency,
+            categories,
+            modifiers: modifiers
+                .iter()
+                .map(|m| market_modifier_to_protocol(m, &flags))
+                .collect(),
+        })
+    }
+
+    /// What an NPC carries that has a price, at local prices where they are.
+    /// Items that can't be bought here are left out.
+    pub async fn merchant_prices(
+        &self,
+        world_id: WorldId,
+        npc_id: CharacterId,
+        location_id: Option<LocationId>,
+    ) -> Result<Vec<MerchantPrice>, EconomyError> {
+        let wares: Vec<Item> = self
+            .inventory
+            .get_character_inventory(npc_id)
+            .await?
+            .into_iter()
+            .filter(|i| i.base_price.is_some())
+            .collect();
+        if wares.is_empty() {
+            return Ok(vec![]);
+        }
+        let modifiers = self.economy.list_modifiers(world_id).await?;
+        let flags = self.flag.get_world_flags(world_id).await?;
+
+        Ok(wares
+            .into_iter()
+            .filter_map(|item| {
+                let conditions =
+                    market_conditions(&modifiers, location_id, item.item_type.as_deref(), &flags);
+                let price = conditions.price(item.base_price.unwrap_or_default())?;
+                Some(MerchantPrice {
+                    item_name: item.name,
+                    price,
+                    scarcity: conditions.scarcity,
+                    reasons: conditions.reasons,
+                })
+            })
+            .collect())
+    }
+
+    async fn apply_input(
+        &self,
+        world_id: WorldId,
+        modifier: &mut MarketModifier,
+        input: MarketModifierInputData,
+    ) -> Result<(), EconomyError> {
+        let location_id = match input.location_id.as_deref().filter(|id| !id.is_empty()) {
+            Some(id) => Some(self.world_location(world_id, id).await?),
+            None => None,
+        };
+        let scarcity = input.scarcity.map(scarcity_from_protocol).transpose()?;
+        modifier.update(
+            location_id,
+            input.category,
+            scarcity,
+            input.price_adjustment,
+            input.flag,
+            &input.reason,
+            self.clock.now(),
+        )?;
+        Ok(())
+    }
+
+    async fn world_modifier(
+        &self,
+        world_id: WorldId,
+        modifier_id: MarketModifierId,
+    ) -> Result<MarketModifier, EconomyError> {
+        self.economy
+            .get_modifier(modifier_id)
+            .await?
+            .filter(|m| m.world_id == world_id)
+            .ok_or(EconomyError::ModifierNotFound)
+    }
+
+    /// Parse a location id, checking it's in the world
+    async fn world_location(
+        &self,
+        world_id: WorldId,
+        id: &str,
+    ) -> Result<LocationId, EconomyError> {
+        let location_id = Uuid::parse_str(id)
+            .map(LocationId::from_uuid)
+            .map_err(|_| EconomyError::Invalid(format!("Invalid location ID: {}", id)))?;
+        self.location
+            .get(location_id)
+            .await?
+            .filter(|l| l.world_id == world_id)
+            .ok_or(EconomyError::LocationNotFound)?;
+        Ok(location_id)
+    }
+}
+
+fn scarcity_from_protocol(scarcity: ScarcityData) -> Result<Scarcity, EconomyError> {
+    match scarcity {
+        ScarcityData::Abundant => Ok(Scarcity::Abundant),
+        ScarcityData::Common => Ok(Scarcity::Common),
+        ScarcityData::Scarce => Ok(Scarcity::Scarce),
+        ScarcityData::Rare => Ok(Scarcity::Rare),
+        ScarcityData::Unavailable => Ok(Scarcity::Unavailable),
+        ScarcityData::Unknown => Err(EconomyError::Invalid("Unknown scarcity".to_string())),
+    }
+}
+
+fn scarcity_to_protocol(scarcity: Scarcity) -> ScarcityData {
+    match scarcity {
+        Scarcity::Abundant => ScarcityData::Abundant,
+        Scarcity::Common => ScarcityData::Common,
+        Scarcity::Scarce => ScarcityData::Scarce,
+        Scarcity::Rare => ScarcityData::Rare,
+        Scarcity::Unavailable => ScarcityData::Unavailable,
+    }
+}
+
+fn market_modifier_to_protocol(modifier: &MarketModifier, flags: &[String]) -> MarketModifierData {
+    MarketModifierData {
+        id: modifier.id.to_string(),
+        location_id: modifier.location_id.map(|id| id.to_string()),
+        category: modifier.category.clone(),
+        scarcity: modifier.scarcity.map(scarcity_to_protocol),
+        price_adjustment: modifier.price_adjustment,
+        flag: modifier.flag.clone(),
+        reason: modifier.reason.clone(),
+        is_active: modifier.is_active(flags),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        MockCharacterRepo, MockEconomyRepo, MockFlagRepo, MockItemRepo, MockLocationRepo,
+        MockPlayerCharacterRepo, MockWorldRepo,
+    };
+    use wrldbldr_domain::{Location, LocationType};
+
+    struct Repos {
+        economy: MockEconomyRepo,
+        items: MockItemRepo,
+        characters: MockCharacterRepo,
+        locations: MockLocationRepo,
+        flags: MockFlagRepo,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            Self {
+                economy: MockEconomyRepo::new(),
+                items: MockItemRepo::new(),
+                characters: MockCharacterRepo::new(),
+                locations: MockLocationRepo::new(),
+                flags: MockFlagRepo::new(),
+            }
+        }
+
+        fn build(self) -> ManageEconomy {
+            let now = chrono::Utc::now();
+            let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(now));
+            let mut world_repo = MockWorldRepo::new();
+            world_repo.expect_get().returning(|_| Ok(None));
+            ManageEconomy::new(
+                Arc::new(entities::Economy::new(Arc::new(self.economy))),
+                Arc::new(entities::Inventory::new(
+                    Arc::new(self.items),
+                    Arc::new(self.characters),
+                    Arc::new(MockPlayerCharacterRepo::new()),
+                )),
+                Arc::new(entities::Location::new(Arc::new(self.locations))),
+                Arc::new(entities::Flag::new(Arc::new(self.flags))),
+                Arc::new(entities::World::new(Arc::new(world_repo), clock.clone())),
+                clock,
+            )
+        }
+    }
+
+    fn war_modifier(world_id: WorldId) -> MarketModifier {
+        MarketModifier::new(world_id, "War in the north", chrono::Utc::now())
+            .expect("valid modifier")
+            .for_category("Weapon")
+            .with_scarcity(Scarcity::Scarce)
+            .while_flag("northern_war")
+    }
+
+    #[tokio::test]
+    async fn merchant_prices_follow_flagged_modifiers() {
+        let world_id = WorldId::new();
+        let sword = Item::new(world_id, "Longsword")
+            .with_type("weapon")
+            .with_base_price(10);
+        let bread = Item::new(world_id, "Bread").with_base_price(1);
+        let keepsake = Item::new(world_id, "Keepsake");
+
+        let mut repos = Repos::new();
+        repos
+            .characters
+            .expect_get_inventory()
+            .returning(move |_| Ok(vec![sword.clone(), bread.clone(), keepsake.clone()]));
+        repos
+            .economy
+            .expect_list_modifiers()
+            .returning(move |_| Ok(vec![war_modifier(world_id)]));
+        repos
+            .flags
+            .expect_get_world_flags()
+            .returning(|_| Box::pin(async { Ok(vec!["northern_war".to_string()]) }));
+
+        let prices = repos
+            .build()
+            .merchant_prices(world_id, CharacterId::new(), None)
+            .await
+            .expect("prices");
+
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices[0].item_name, "Longsword");
+        assert_eq!(prices[0].price, 15);
+        assert_eq!(prices[0].reasons, vec!["War in the north".to_string()]);
+        assert_eq!(prices[1].price, 1);
+        assert_eq!(prices[1].scarcity, Scarcity::Common);
+    }
+
+    #[tokio::test]
+    async fn overview_groups_item_types_and_lists_modifier_categories() {
+        let world_id = WorldId::new();
+        let location = Location::new(world_id, "Harbour", LocationType::Exterior);
+        let location_id = location.id;
+        let sword = Item::new(world_id, "Longsword")
+            .with_type("Weapon")
+            .with_base_price(10);
+        let spear = Item::new(world_id, "Spear").with_type("weapon");
+        let grain = MarketModifier::new(world_id, "Blockade", chrono::Utc::now())
+            .expect("valid modifier")
+            .at_location(location_id)
+            .for_category("Food")
+            .with_scarcity(Scarcity::Unavailable);
+        let elsewhere = MarketModifier::new(world_id, "Festival", chrono::Utc::now())
+            .expect("valid modifier")
+            .at_location(LocationId::new())
+            .with_price_adjustment(50)
+            .expect("valid adjustment");
+
+        let mut repos = Repos::new();
+        repos
+            .locations
+            .expect_get_location()
+            .returning(move |_| Ok(Some(location.clone())));
+        repos
+            .items
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![spear.clone(), sword.clone()]));
+        repos.economy.expect_list_modifiers().returning(move |_| {
+            Ok(vec![
+                war_modifier(world_id),
+                grain.clone(),
+                elsewhere.clone(),
+            ])
+        });
+        repos
+            .flags
+            .expect_get_world_flags()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let overview = repos
+            .build()
+            .overview(world_id, location_id)
+            .await
+            .expect("overview");
+
+        assert_eq!(overview.modifiers.len(), 2);
+        assert!(!overview.modifiers[0].is_active);
+        let categories: Vec<_> = overview
+            .categories
+            .iter()
+            .map(|c| c.category.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(categories, vec!["Food", "Weapon"]);
+        assert_eq!(overview.categories[0].scarcity, ScarcityData::Unavailable);
+        let weapons = &overview.categories[1];
+        // The war hasn't started, so weapons are at base price
+        assert_eq!(weapons.price_percent, Some(100));
+        assert_eq!(weapons.items.len(), 2);
+        assert_eq!(weapons.items[0].name, "Longsword");
+        assert_eq!(weapons.items[0].local_price, Some(10));
+        // Unpriced items are listed so the DM can price them
+        assert_eq!(weapons.items[1].base_price, None);
+        assert_eq!(weapons.items[1].local_price, None);
+    }
+
+    #[tokio::test]
+    async fn modifiers_from_other_worlds_are_missing() {
+        let modifier = war_modifier(WorldId::new());
+        let modifier_id = modifier.id;
+        let mut repos = Repos::new();
+        repos
+            .economy
+            .expect_get_modifier()
+            .returning(move |_| Ok(Some(modifier.clone())));
+        repos.economy.expect_delete_modifier().never();
+
+        let result = repos
+            .build()
+            .delete_modifier(WorldId::new(), modifier_id)
+            .await;
+
+        assert!(matches!(result, Err(EconomyError::ModifierNotFound)));
+    }
+}
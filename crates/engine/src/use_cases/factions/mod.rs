@@ -0,0 +1,468 @@
+//! Faction use cases.
+//!
+//! Factions give NPCs a group-level opinion of the party on top of their
+//! personal dispositions. The DM keeps the roster, goals, rivalries and
+//! reputation; staging suggestions and NPC dialogue prompts read them, so a
+//! guild that has turned on the party is felt through every member.
+//! Factions are scoped to their world; factions and NPCs in other worlds are
+//! treated as missing.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{CharacterId, DomainError, Faction, FactionId, FactionMember, WorldId};
+use wrldbldr_protocol::types::{
+    FactionData, FactionInputData, FactionMemberData, FactionMemberInputData,
+};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for faction use cases.
+pub struct FactionUseCases {
+    pub manage: Arc<ManageFactions>,
+}
+
+impl FactionUseCases {
+    pub fn new(manage: Arc<ManageFactions>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FactionError {
+    #[error("Faction not found")]
+    NotFound,
+    #[error("Character not found")]
+    CharacterNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for FactionError {
+    fn from(e: DomainError) -> Self {
+        FactionError::Invalid(e.to_string())
+    }
+}
+
+/// Create and edit factions, their members, reputation and rivalries.
+pub struct ManageFactions {
+    faction: Arc<entities::Faction>,
+    character: Arc<entities::Character>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageFactions {
+    pub fn new(
+        faction: Arc<entities::Faction>,
+        character: Arc<entities::Character>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            faction,
+            character,
+            clock,
+        }
+    }
+
+    /// Every faction in the world, by name
+    pub async fn list(&self, world_id: WorldId) -> Result<Vec<FactionData>, FactionError> {
+        let factions = self.faction.list_in_world(world_id).await?;
+        let names = self.npc_names(world_id).await?;
+        Ok(factions
+            .iter()
+            .map(|f| faction_to_protocol(f, &names))
+            .collect())
+    }
+
+    pub async fn create(
+        &self,
+        world_id: WorldId,
+        input: FactionInputData,
+    ) -> Result<FactionData, FactionError> {
+        let now = self.clock.now();
+        let mut faction = Faction::new(world_id, input.name.clone(), now)?;
+        faction.update(&input.name, input.description.trim(), input.goals, now)?;
+        self.faction.save(&faction).await?;
+        self.to_protocol(&faction).await
+    }
+
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        faction_id: FactionId,
+        input: FactionInputData,
+    ) -> Result<FactionData, FactionError> {
+        let mut faction = self.world_faction(world_id, faction_id).await?;
+        faction.update(
+            &input.name,
+            input.description.trim(),
+            input.goals,
+            self.clock.now(),
+        )?;
+        self.faction.save(&faction).await?;
+        self.to_protocol(&faction).await
+    }
+
+    /// Delete a faction and end its rivalries
+    pub async fn delete(
+        &self,
+        world_id: WorldId,
+        faction_id: FactionId,
+    ) -> Result<(), FactionError> {
+        let faction = self.world_faction(world_id, faction_id).await?;
+        let now = self.clock.now();
+        for rival_id in &faction.rivals {
+            if let Some(mut rival) = self.faction.get(*rival_id).await? {
+                if rival.set_rival(faction.id, false, now)? {
+                    self.faction.save(&rival).await?;
+                }
+            }
+        }
+        self.faction.delete(faction.id).await?;
+        Ok(())
+    }
+
+    /// Replace the member list with NPCs of the same world
+    pub async fn set_members(
+        &self,
+        world_id: WorldId,
+        faction_id: FactionId,
+        members: Vec<FactionMemberInputData>,
+    ) -> Result<FactionData, FactionError> {
+        let mut faction = self.world_faction(world_id, faction_id).await?;
+        let names = self.npc_names(world_id).await?;
+        let members = members
+            .into_iter()
+            .map(|m| {
+                let character_id = Uuid::parse_str(&m.character_id)
+                    .map(CharacterId::from_uuid)
+                    .map_err(|_| FactionError::Invalid("Invalid character ID".to_string()))?;
+                if !names.contains_key(&character_id) {
+                    return Err(FactionError::CharacterNotFound);
+                }
+                Ok(FactionMember {
+                    character_id,
+                    role: m.role,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        faction.set_members(members, self.clock.now())?;
+        self.faction.save(&faction).await?;
+        Ok(faction_to_protocol(&faction, &names))
+    }
+
+    pub async fn set_reputation(
+        &self,
+        world_id: WorldId,
+        faction_id: FactionId,
+        reputation: i32,
+    ) -> Result<FactionData, FactionError> {
+        let mut faction = self.world_faction(world_id, faction_id).await?;
+        faction.set_reputation(reputation, self.clock.now())?;
+        self.faction.save(&faction).await?;
+        self.to_protocol(&faction).await
+    }
+
+    pub async fn adjust_reputation(
+        &self,
+        world_id: WorldId,
+        faction_id: FactionId,
+        delta: i32,
+    ) -> Result<FactionData, FactionError> {
+        let mut faction = self.world_faction(world_id, faction_id).await?;
+        faction.adjust_reputation(delta, self.clock.now());
+        self.faction.save(&faction).await?;
+        self.to_protocol(&faction).await
+    }
+
+    /// Make two factions rivals, or end their rivalry; both sides change
+    /// together. Returns the first faction.
+    pub async fn set_rivalry(
+        &self,
+        world_id: WorldId,
+        faction_id: FactionId,
+        rival_id: FactionId,
+        rivals: bool,
+    ) -> Result<FactionData, FactionError> {
+        if faction_id == rival_id {
+            return Err(FactionError::Invalid(
+                "A faction cannot be its own rival".to_string(),
+            ));
+        }
+        let mut faction = self.world_faction(world_id, faction_id).await?;
+        let mut rival = self.world_faction(world_id, rival_id).await?;
+        let now = self.clock.now();
+        if faction.set_rival(rival_id, rivals, now)? {
+            self.faction.save(&faction).await?;
+        }
+        if rival.set_rival(faction_id, rivals, now)? {
+            self.faction.save(&rival).await?;
+        }
+        self.to_protocol(&faction).await
+    }
+
+    /// Factions an NPC belongs to
+    pub async fn member_factions(
+        &self,
+        world_id: WorldId,
+        character_id: CharacterId,
+    ) -> Result<Vec<Faction>, FactionError> {
+        Ok(self.faction.list_for_member(world_id, character_id).await?)
+    }
+
+    async fn world_faction(
+        &self,
+        world_id: WorldId,
+        faction_id: FactionId,
+    ) -> Result<Faction, FactionError> {
+        self.faction
+            .get(faction_id)
+            .await?
+            .filter(|f| f.world_id == world_id)
+            .ok_or(FactionError::NotFound)
+    }
+
+    async fn npc_names(
+        &self,
+        world_id: WorldId,
+    ) -> Result<HashMap<CharacterId, String>, FactionError> {
+        Ok(self
+            .character
+            .list_npcs_in_world(world_id)
+            .await?
+            .into_iter()
+            .map(|c| (c.id, c.name))
+            .collect())
+    }
+
+    async fn to_protocol(&self, faction: &Faction) -> Result<FactionData, FactionError> {
+        let names = self.npc_names(faction.world_id).await?;
+        Ok(faction_to_protocol(faction, &names))
+    }
+}
+
+fn faction_to_protocol(faction: &Faction, names: &HashMap<CharacterId, String>) -> FactionData {
+    FactionData {
+        id: faction.id.to_string(),
+        name: faction.name.clone(),
+        description: faction.description.clone(),
+        goals: faction.goals.clone(),
+        members: faction
+            .members
+            .iter()
+            .map(|m| FactionMemberData {
+                character_id: m.character_id.to_string(),
+                name: names.get(&m.character_id).cloned().unwrap_or_default(),
+                role: m.role.clone(),
+            })
+            .collect(),
+        reputation: faction.reputation,
+        standing: faction.standing(),
+        rival_ids: faction.rivals.iter().map(|id| id.to_string()).collect(),
+    }
+}
+
+/// "Thieves' Guild (fence), hostile to the party" for every member of
+/// every faction; NPCs in several factions get them joined with "; "
+pub(crate) fn staging_labels(factions: &[Faction]) -> HashMap<CharacterId, String> {
+    let mut labels: HashMap<CharacterId, String> = HashMap::new();
+    for faction in factions {
+        for member in &faction.members {
+            let label = format!(
+                "{}, {} to the party",
+                membership(faction, member),
+                faction.standing().display_name().to_lowercase()
+            );
+            labels
+                .entry(member.character_id)
+                .and_modify(|l| {
+                    l.push_str("; ");
+                    l.push_str(&label);
+                })
+                .or_insert(label);
+        }
+    }
+    labels
+}
+
+/// Directorial note on the factions the responding NPC speaks for
+pub(crate) fn faction_note(
+    npc_name: &str,
+    character_id: CharacterId,
+    factions: &[Faction],
+) -> Option<String> {
+    let lines: Vec<String> = factions
+        .iter()
+        .filter_map(|faction| {
+            let member = faction.member(character_id)?;
+            let mut line = format!(
+                "{} is {}. The faction is {} toward the party (reputation {}).",
+                npc_name,
+                membership(faction, member),
+                faction.standing().display_name().to_lowercase(),
+                faction.reputation
+            );
+            if let Some(goal) = faction.goals.first() {
+                line.push_str(&format!(" Its chief goal: {}.", goal.trim_end_matches('.')));
+            }
+            Some(line)
+        })
+        .collect();
+    if lines.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "{} Let the faction's view of the party colour {}'s manner, on top of \
+        any personal feelings.",
+        lines.join(" "),
+        npc_name
+    ))
+}
+
+fn membership(faction: &Faction, member: &FactionMember) -> String {
+    if member.role.is_empty() {
+        format!("a member of {}", faction.name)
+    } else {
+        format!("{} of {}", member.role, faction.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{MockCharacterRepo, MockFactionRepo};
+    use std::sync::Mutex;
+    use wrldbldr_domain::CampbellArchetype;
+
+    fn factions(repo: MockFactionRepo, characters: MockCharacterRepo) -> ManageFactions {
+        ManageFactions::new(
+            Arc::new(entities::Faction::new(Arc::new(repo))),
+            Arc::new(entities::Character::new(Arc::new(characters))),
+            Arc::new(FixedClock(chrono::Utc::now())),
+        )
+    }
+
+    fn guild(world_id: WorldId) -> Faction {
+        Faction::new(world_id, "Thieves' Guild", chrono::Utc::now()).expect("faction")
+    }
+
+    #[tokio::test]
+    async fn rivalries_are_set_on_both_sides() {
+        let world_id = WorldId::new();
+        let first = guild(world_id);
+        let second = Faction::new(world_id, "City Watch", chrono::Utc::now()).expect("faction");
+        let (first_id, second_id) = (first.id, second.id);
+        let stored = Arc::new(Mutex::new(HashMap::from([
+            (first_id, first),
+            (second_id, second),
+        ])));
+
+        let mut repo = MockFactionRepo::new();
+        let fetched = stored.clone();
+        repo.expect_get()
+            .returning(move |id| Ok(fetched.lock().unwrap().get(&id).cloned()));
+        let saved = stored.clone();
+        repo.expect_save().times(2).returning(move |f| {
+            saved.lock().unwrap().insert(f.id, f.clone());
+            Ok(())
+        });
+        let mut characters = MockCharacterRepo::new();
+        characters
+            .expect_list_npcs_in_world()
+            .returning(|_| Ok(vec![]));
+
+        let data = factions(repo, characters)
+            .set_rivalry(world_id, first_id, second_id, true)
+            .await
+            .expect("rivalry");
+
+        assert_eq!(data.rival_ids, vec![second_id.to_string()]);
+        assert!(stored.lock().unwrap()[&second_id].is_rival_of(first_id));
+    }
+
+    #[tokio::test]
+    async fn members_must_be_npcs_in_the_world() {
+        let world_id = WorldId::new();
+        let faction = guild(world_id);
+        let faction_id = faction.id;
+        let fence =
+            wrldbldr_domain::Character::new(world_id, "Vexis", CampbellArchetype::Trickster);
+        let fence_id = fence.id;
+
+        let mut repo = MockFactionRepo::new();
+        repo.expect_get()
+            .returning(move |_| Ok(Some(faction.clone())));
+        repo.expect_save().times(1).returning(|_| Ok(()));
+        let mut characters = MockCharacterRepo::new();
+        characters
+            .expect_list_npcs_in_world()
+            .returning(move |_| Ok(vec![fence.clone()]));
+        let factions = factions(repo, characters);
+
+        let stranger = factions
+            .set_members(
+                world_id,
+                faction_id,
+                vec![FactionMemberInputData {
+                    character_id: CharacterId::new().to_string(),
+                    role: String::new(),
+                }],
+            )
+            .await;
+        assert!(matches!(stranger, Err(FactionError::CharacterNotFound)));
+
+        let data = factions
+            .set_members(
+                world_id,
+                faction_id,
+                vec![FactionMemberInputData {
+                    character_id: fence_id.to_string(),
+                    role: "fence".to_string(),
+                }],
+            )
+            .await
+            .expect("members");
+        assert_eq!(data.members[0].name, "Vexis");
+    }
+
+    #[test]
+    fn members_carry_their_factions_standing_into_prompts() {
+        let now = chrono::Utc::now();
+        let fence = CharacterId::new();
+        let mut faction = guild(WorldId::new());
+        faction
+            .update(
+                "Thieves' Guild",
+                "",
+                vec!["Control the docks.".to_string()],
+                now,
+            )
+            .unwrap();
+        faction
+            .set_members(
+                vec![FactionMember {
+                    character_id: fence,
+                    role: "fence".to_string(),
+                }],
+                now,
+            )
+            .unwrap();
+        faction.adjust_reputation(-60, now);
+
+        assert_eq!(
+            staging_labels(std::slice::from_ref(&faction))[&fence],
+            "fence of Thieves' Guild, hostile to the party"
+        );
+        let note = faction_note("Vexis", fence, &[faction]).expect("note");
+        assert!(note.starts_with(
+            "Vexis is fence of Thieves' Guild. The faction is hostile toward the party \
+            (reputation -60). Its chief goal: Control the docks."
+        ));
+        assert!(faction_note("Vexis", CharacterId::new(), &[]).is_none());
+    }
+}
@@ -0,0 +1,316 @@
+//! Flashback use cases.
+//!
+//! A flashback plays a scene at an earlier date without losing the present.
+//! Starting one winds the world clock back and restages its region with the
+//! NPCs and look of that time; ending it puts the live clock, staging and
+//! region state back. Story events recorded meanwhile are tagged so the
+//! timeline can tell them apart. One flashback runs per world at a time.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{DomainError, Flashback, GameTime, RegionId, StagingSource, World, WorldId};
+use wrldbldr_protocol::types::{FlashbackData, StartFlashbackData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+use crate::use_cases::staging::{
+    ApproveStagingInput, ApproveStagingRequest, StagingError, StagingReadyPayload,
+};
+use crate::use_cases::time::game_time_to_protocol;
+
+/// How long the flashback's staging lasts, in game hours
+const FLASHBACK_STAGING_TTL_HOURS: i32 = 24;
+
+/// Container for flashback use cases.
+pub struct FlashbackUseCases {
+    pub manage: Arc<ManageFlashbacks>,
+}
+
+impl FlashbackUseCases {
+    pub fn new(manage: Arc<ManageFlashbacks>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FlashbackError {
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("Region not found")]
+    RegionNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+    #[error("Staging error: {0}")]
+    Staging(#[from] StagingError),
+}
+
+impl From<DomainError> for FlashbackError {
+    fn from(e: DomainError) -> Self {
+        FlashbackError::Invalid(e.to_string())
+    }
+}
+
+/// What changed when a flashback started or ended, for broadcasting
+pub struct FlashbackChange {
+    /// The running flashback; `None` once it has ended
+    pub flashback: Option<FlashbackData>,
+    pub game_time: GameTime,
+    pub staging: StagingReadyPayload,
+}
+
+/// Start and end flashbacks.
+pub struct ManageFlashbacks {
+    world: Arc<entities::World>,
+    location: Arc<entities::Location>,
+    staging: Arc<entities::Staging>,
+    region_state: Arc<entities::RegionStateEntity>,
+    approve_staging: Arc<ApproveStagingRequest>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageFlashbacks {
+    pub fn new(
+        world: Arc<entities::World>,
+        location: Arc<entities::Location>,
+        staging: Arc<entities::Staging>,
+        region_state: Arc<entities::RegionStateEntity>,
+        approve_staging: Arc<ApproveStagingRequest>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            world,
+            location,
+            staging,
+            region_state,
+            approve_staging,
+            clock,
+        }
+    }
+
+    /// The running flashback, if any
+    pub async fn current(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Option<FlashbackData>, FlashbackError> {
+        let world = self.get_world(world_id).await?;
+        Ok(flashback_to_protocol(&world))
+    }
+
+    /// Wind the clock back and restage the flashback's region. The
+    /// region's live staging and visual state are kept for `end`.
+    pub async fn start(
+        &self,
+        world_id: WorldId,
+        approved_by: String,
+        data: StartFlashbackData,
+    ) -> Result<FlashbackChange, FlashbackError> {
+        let mut world = self.get_world(world_id).await?;
+        let title = Flashback::validate_title(&data.title)?;
+        let region_id = Uuid::parse_str(&data.region_id)
+            .map(RegionId::from_uuid)
+            .map_err(|_| FlashbackError::Invalid("Invalid region ID".to_string()))?;
+        let location_id = self.region_location(world_id, region_id).await?;
+        let flashback_time =
+            Flashback::flashback_time(&world.game_time, data.years_back, data.day, data.hour)?;
+
+        let live_staging = self
+            .staging
+            .get_active_staging(region_id, world.game_time.current())
+            .await?;
+        let live_region_state = self.region_state.get_active(region_id).await?;
+
+        world.start_flashback(
+            &title,
+            region_id,
+            flashback_time,
+            live_staging.map(|s| s.id),
+            live_region_state.map(|s| s.id),
+            self.clock.now(),
+        )?;
+        self.world.save(&world).await?;
+
+        // The flashback shows its own look, or the region's default
+        self.region_state.clear_active(region_id).await?;
+        let staging = self
+            .approve_staging
+            .execute(ApproveStagingInput {
+                region_id,
+                location_id: Some(location_id),
+                world_id,
+                approved_by,
+                ttl_hours: FLASHBACK_STAGING_TTL_HOURS,
+                source: StagingSource::DmCustomized,
+                approved_npcs: data.npcs,
+                location_state_id: None,
+                region_state_id: data.region_state_id,
+            })
+            .await?;
+
+        Ok(FlashbackChange {
+            flashback: flashback_to_protocol(&world),
+            game_time: world.game_time,
+            staging,
+        })
+    }
+
+    /// Return to the present: the live clock, and the flashback region's
+    /// earlier staging and visual state.
+    pub async fn end(&self, world_id: WorldId) -> Result<FlashbackChange, FlashbackError> {
+        let mut world = self.get_world(world_id).await?;
+        let flashback = world.end_flashback(self.clock.now())?;
+        self.world.save(&world).await?;
+
+        let region_id = flashback.region_id;
+        let location_id = self.region_location(world_id, region_id).await?;
+        let staging = self
+            .approve_staging
+            .restore(
+                region_id,
+                location_id,
+                flashback.live_staging_id,
+                flashback.live_region_state_id,
+            )
+            .await?;
+
+        Ok(FlashbackChange {
+            flashback: None,
+            game_time: world.game_time,
+            staging,
+        })
+    }
+
+    async fn get_world(&self, world_id: WorldId) -> Result<World, FlashbackError> {
+        self.world
+            .get(world_id)
+            .await?
+            .ok_or(FlashbackError::WorldNotFound)
+    }
+
+    /// The region's location, checking the region is in the world
+    async fn region_location(
+        &self,
+        world_id: WorldId,
+        region_id: RegionId,
+    ) -> Result<wrldbldr_domain::LocationId, FlashbackError> {
+        let region = self
+            .location
+            .get_region(region_id)
+            .await?
+            .ok_or(FlashbackError::RegionNotFound)?;
+        match self.location.get(region.location_id).await? {
+            Some(location) if location.world_id == world_id => Ok(location.id),
+            _ => Err(FlashbackError::RegionNotFound),
+        }
+    }
+}
+
+fn flashback_to_protocol(world: &World) -> Option<FlashbackData> {
+    world.flashback.as_ref().map(|f| FlashbackData {
+        title: f.title.clone(),
+        region_id: f.region_id.to_string(),
+        game_time: game_time_to_protocol(&world.game_time),
+        live_time: game_time_to_protocol(&f.live_time),
+        started_at: f.started_at.to_rfc3339(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::Simulation;
+    use wrldbldr_domain::{RegionState, FLASHBACK_TAG};
+    use wrldbldr_protocol::ApprovedNpcInfo;
+
+    #[tokio::test]
+    async fn ending_a_flashback_restores_the_present() {
+        let sim = Simulation::new(7);
+        let world = sim.world("Saltmere").await;
+        let harbor = sim.location(world.id, "Harbor").await;
+        let docks = sim.region(harbor.id, "Docks").await;
+        let mira = sim.npc(world.id, "Mira").await;
+        let tam = sim.npc(world.id, "Old Tam").await;
+        let use_cases = &sim.app.use_cases;
+        let entities = &sim.app.entities;
+
+        // The present: Mira on the docks, in the rain
+        use_cases
+            .approval
+            .approve_staging
+            .execute(docks.id, vec![mira.id])
+            .await
+            .unwrap();
+        let rain = RegionState::new(docks.id, harbor.id, world.id, "Rain", sim.clock.now());
+        entities.region_state.save(&rain).await.unwrap();
+        entities
+            .region_state
+            .set_active(docks.id, rain.id)
+            .await
+            .unwrap();
+        let live_day = world.game_time.day();
+
+        let started = use_cases
+            .flashback
+            .manage
+            .start(
+                world.id,
+                "dm".to_string(),
+                StartFlashbackData {
+                    title: "The night of the fire".to_string(),
+                    region_id: docks.id.to_string(),
+                    years_back: 10,
+                    day: 1,
+                    hour: 23,
+                    npcs: vec![ApprovedNpcInfo {
+                        character_id: tam.id.to_string(),
+                        is_present: true,
+                        reasoning: None,
+                        is_hidden_from_players: false,
+                        mood: None,
+                    }],
+                    region_state_id: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(started.staging.npcs_present.len(), 1);
+        assert_eq!(started.staging.npcs_present[0].name, "Old Tam");
+        assert!(started.staging.visual_state.is_none());
+        assert_eq!(started.game_time.hour(), 23);
+        assert!(started.game_time.current() < world.game_time.current());
+
+        let event = wrldbldr_domain::StoryEvent::new(
+            world.id,
+            wrldbldr_domain::StoryEventType::DmMarker {
+                title: "The fire".to_string(),
+                note: String::new(),
+                importance: wrldbldr_domain::MarkerImportance::Major,
+                marker_type: wrldbldr_domain::DmMarkerType::Note,
+                pin: None,
+            },
+            sim.clock.now(),
+        );
+        entities.narrative.save_story_event(&event).await.unwrap();
+        let saved = entities
+            .narrative
+            .get_story_event(event.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(saved.tags.iter().any(|t| t == FLASHBACK_TAG));
+
+        let ended = use_cases.flashback.manage.end(world.id).await.unwrap();
+        assert!(ended.flashback.is_none());
+        assert_eq!(ended.game_time.day(), live_day);
+        assert_eq!(ended.staging.npcs_present.len(), 1);
+        assert_eq!(ended.staging.npcs_present[0].name, "Mira");
+        let visual = ended.staging.visual_state.expect("live region state");
+        assert_eq!(visual.region_state.unwrap().name, "Rain");
+
+        let world = entities.world.get(world.id).await.unwrap().unwrap();
+        assert!(world.flashback.is_none());
+        assert!(use_cases.flashback.manage.end(world.id).await.is_err());
+    }
+}
@@ -0,0 +1,256 @@
+//! Dependency health use cases.
+//!
+//! Checks each external dependency the engine relies on (Neo4j, the Ollama
+//! model, ComfyUI, and disk space for assets) and tracks status changes so
+//! DMs can be told when a dependency goes down or comes back.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio::sync::Mutex;
+use wrldbldr_domain::ServiceConnections;
+use wrldbldr_protocol::ServerMessage;
+
+use crate::infrastructure::ports::{ProbeError, ServiceProbePort};
+
+/// Directory generated assets are written to
+pub const ASSETS_DIR: &str = "assets";
+
+/// Free space below which asset storage counts as unhealthy (512 MiB)
+pub const MIN_FREE_DISK_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Container for health use cases.
+pub struct HealthUseCases {
+    pub check: Arc<CheckDependencies>,
+}
+
+impl HealthUseCases {
+    pub fn new(check: Arc<CheckDependencies>) -> Self {
+        Self { check }
+    }
+}
+
+/// An external dependency of the engine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Dependency {
+    Neo4j,
+    Ollama,
+    #[serde(rename = "comfyui")]
+    ComfyUI,
+    Disk,
+}
+
+impl Dependency {
+    pub const ALL: [Dependency; 4] = [
+        Dependency::Neo4j,
+        Dependency::Ollama,
+        Dependency::ComfyUI,
+        Dependency::Disk,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Dependency::Neo4j => "neo4j",
+            Dependency::Ollama => "ollama",
+            Dependency::ComfyUI => "comfyui",
+            Dependency::Disk => "disk",
+        }
+    }
+}
+
+/// Result of checking one dependency
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct DependencyStatus {
+    pub dependency: Dependency,
+    pub healthy: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl DependencyStatus {
+    fn from_probe(dependency: Dependency, result: Result<(), ProbeError>) -> Self {
+        Self {
+            dependency,
+            healthy: result.is_ok(),
+            message: result.err().map(|e| e.to_string()),
+        }
+    }
+}
+
+/// Build the DM notification for a dependency's status.
+pub fn status_to_message(status: &DependencyStatus) -> ServerMessage {
+    ServerMessage::DependencyStatusChanged {
+        dependency: status.dependency.as_str().to_string(),
+        healthy: status.healthy,
+        message: status.message.clone(),
+    }
+}
+
+/// Health of every dependency
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    /// True when every dependency is healthy
+    pub healthy: bool,
+    pub dependencies: Vec<DependencyStatus>,
+}
+
+/// Check the dependencies the engine is running with.
+pub struct CheckDependencies {
+    probe: Arc<dyn ServiceProbePort>,
+    connections: ServiceConnections,
+    assets_dir: String,
+    min_free_disk_bytes: u64,
+    /// Last status reported through `changes`, per dependency
+    last_reported: Mutex<HashMap<Dependency, DependencyStatus>>,
+}
+
+impl CheckDependencies {
+    pub fn new(
+        probe: Arc<dyn ServiceProbePort>,
+        connections: ServiceConnections,
+        assets_dir: impl Into<String>,
+        min_free_disk_bytes: u64,
+    ) -> Self {
+        Self {
+            probe,
+            connections: connections.with_defaults(),
+            assets_dir: assets_dir.into(),
+            min_free_disk_bytes,
+            last_reported: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Check every dependency now.
+    pub async fn execute(&self) -> HealthReport {
+        let c = &self.connections;
+        let (neo4j, ollama, comfyui, disk) = tokio::join!(
+            self.probe
+                .check_neo4j(c.neo4j_uri(), c.neo4j_user(), c.neo4j_password()),
+            self.probe.check_ollama(c.ollama_url(), c.ollama_model()),
+            self.probe.check_comfyui(c.comfyui_url()),
+            self.check_disk(),
+        );
+
+        let dependencies = vec![
+            DependencyStatus::from_probe(Dependency::Neo4j, neo4j),
+            DependencyStatus::from_probe(Dependency::Ollama, ollama),
+            DependencyStatus::from_probe(Dependency::ComfyUI, comfyui),
+            disk,
+        ];
+        HealthReport {
+            healthy: dependencies.iter().all(|d| d.healthy),
+            dependencies,
+        }
+    }
+
+    /// Check every dependency and return those whose status changed since
+    /// the last call. On the first call every dependency counts as changed.
+    pub async fn changes(&self) -> Vec<DependencyStatus> {
+        let report = self.execute().await;
+        let mut last = self.last_reported.lock().await;
+        let mut changed = Vec::new();
+        for status in report.dependencies {
+            if last.get(&status.dependency) != Some(&status) {
+                last.insert(status.dependency, status.clone());
+                changed.push(status);
+            }
+        }
+        changed
+    }
+
+    /// Most recent status reported through `changes`, without re-checking.
+    pub async fn last_reported(&self) -> Vec<DependencyStatus> {
+        let last = self.last_reported.lock().await;
+        Dependency::ALL
+            .iter()
+            .filter_map(|d| last.get(d).cloned())
+            .collect()
+    }
+
+    async fn check_disk(&self) -> DependencyStatus {
+        let status = |healthy: bool, message: Option<String>| DependencyStatus {
+            dependency: Dependency::Disk,
+            healthy,
+            message,
+        };
+
+        match self.probe.free_disk_space(&self.assets_dir).await {
+            Ok(free) if free < self.min_free_disk_bytes => status(
+                false,
+                Some(format!(
+                    "Only {} MiB free for assets (need {} MiB)",
+                    free / (1024 * 1024),
+                    self.min_free_disk_bytes / (1024 * 1024)
+                )),
+            ),
+            Ok(_) => status(true, None),
+            Err(e) => status(false, Some(e.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ports::MockServiceProbePort;
+
+    fn probe(comfyui_up: bool, free: u64) -> MockServiceProbePort {
+        let mut probe = MockServiceProbePort::new();
+        probe.expect_check_neo4j().returning(|_, _, _| Ok(()));
+        probe.expect_check_ollama().returning(|_, _| Ok(()));
+        probe.expect_check_comfyui().returning(move |_| {
+            if comfyui_up {
+                Ok(())
+            } else {
+                Err(ProbeError::Unreachable("connection refused".to_string()))
+            }
+        });
+        probe.expect_free_disk_space().returning(move |_| Ok(free));
+        probe
+    }
+
+    #[tokio::test]
+    async fn reports_low_disk_space_as_unhealthy() {
+        let check = CheckDependencies::new(
+            Arc::new(probe(true, 10 * 1024 * 1024)),
+            ServiceConnections::default(),
+            ASSETS_DIR,
+            MIN_FREE_DISK_BYTES,
+        );
+
+        let report = check.execute().await;
+
+        assert!(!report.healthy);
+        let disk = report
+            .dependencies
+            .iter()
+            .find(|d| d.dependency == Dependency::Disk)
+            .expect("disk status");
+        assert!(!disk.healthy);
+        assert!(disk.message.as_deref().unwrap_or("").contains("10 MiB"));
+    }
+
+    #[tokio::test]
+    async fn changes_only_reports_transitions() {
+        let check = CheckDependencies::new(
+            Arc::new(probe(false, u64::MAX)),
+            ServiceConnections::default(),
+            ASSETS_DIR,
+            MIN_FREE_DISK_BYTES,
+        );
+
+        // First check reports everything, the second nothing new
+        assert_eq!(check.changes().await.len(), Dependency::ALL.len());
+        assert!(check.changes().await.is_empty());
+
+        let comfyui = check
+            .last_reported()
+            .await
+            .into_iter()
+            .find(|d| d.dependency == Dependency::ComfyUI)
+            .expect("comfyui status");
+        assert!(!comfyui.healthy);
+    }
+}
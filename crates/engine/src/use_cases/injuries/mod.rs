@@ -0,0 +1,529 @@
+//! Injury use cases.
+//!
+//! Lasting harm the DM inflicts on a PC: a severity, the capability it
+//! hampers, and a recovery time the rule system sets by default. Injuries
+//! heal by themselves as game time advances; resting earns extra recovery
+//! and a successful treatment challenge takes a share off what is left.
+//! How fast all of this goes comes from the world's rule system.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use wrldbldr_domain::{
+    ChallengeId, DomainError, Injury, InjuryCapability, InjuryId, InjuryRecoveryConfig,
+    InjurySeverity, PlayerCharacterId, WorldId, MAX_ACTIVE_INJURIES_PER_PC,
+};
+use wrldbldr_protocol::types::{
+    InjuryCapabilityData, InjuryData, InjuryInputData, InjurySeverityData,
+};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for injury use cases.
+pub struct InjuryUseCases {
+    pub manage: Arc<ManageInjuries>,
+}
+
+impl InjuryUseCases {
+    pub fn new(manage: Arc<ManageInjuries>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum InjuryError {
+    #[error("Injury not found")]
+    InjuryNotFound,
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("Challenge not found")]
+    ChallengeNotFound,
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for InjuryError {
+    fn from(e: DomainError) -> Self {
+        match e {
+            DomainError::InvalidStateTransition(msg) => InjuryError::Conflict(msg),
+            other => InjuryError::Invalid(other.to_string()),
+        }
+    }
+}
+
+/// Inflict, change, treat and heal PC injuries.
+pub struct ManageInjuries {
+    injury: Arc<entities::Injury>,
+    player_character: Arc<entities::PlayerCharacter>,
+    challenge: Arc<entities::Challenge>,
+    world: Arc<entities::World>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageInjuries {
+    pub fn new(
+        injury: Arc<entities::Injury>,
+        player_character: Arc<entities::PlayerCharacter>,
+        challenge: Arc<entities::Challenge>,
+        world: Arc<entities::World>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            injury,
+            player_character,
+            challenge,
+            world,
+            clock,
+        }
+    }
+
+    /// A PC's injuries, oldest first, with recovery as of now
+    pub async fn list_for_pc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        include_healed: bool,
+    ) -> Result<Vec<InjuryData>, InjuryError> {
+        self.world_pc(world_id, pc_id).await?;
+        let (game_now, config) = self.recovery(world_id).await?;
+        let mut injuries = Vec::new();
+        for injury in self.injury.list_for_pc(pc_id).await? {
+            if include_healed || !injury.is_healed() {
+                injuries.push(self.describe(&injury, game_now, &config).await?);
+            }
+        }
+        Ok(injuries)
+    }
+
+    /// Injure a PC as of the current game time. The recovery time defaults
+    /// to the rule system's time for the severity.
+    pub async fn inflict(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        input: InjuryInputData,
+    ) -> Result<InjuryData, InjuryError> {
+        self.world_pc(world_id, pc_id).await?;
+        let active = self
+            .injury
+            .list_for_pc(pc_id)
+            .await?
+            .iter()
+            .filter(|i| !i.is_healed())
+            .count();
+        if active >= MAX_ACTIVE_INJURIES_PER_PC {
+            return Err(InjuryError::Invalid(format!(
+                "A PC cannot carry more than {} injuries",
+                MAX_ACTIVE_INJURIES_PER_PC
+            )));
+        }
+
+        let name = input
+            .name
+            .clone()
+            .ok_or_else(|| InjuryError::Invalid("Injury name is required".to_string()))?;
+        let severity = input
+            .severity
+            .map(severity_from_protocol)
+            .transpose()?
+            .unwrap_or_default();
+        let (game_now, config) = self.recovery(world_id).await?;
+        let mut injury = Injury::new(
+            world_id,
+            pc_id,
+            name,
+            severity,
+            &config,
+            game_now,
+            self.clock.now(),
+        )?;
+        self.apply(world_id, &mut injury, input).await?;
+        self.injury.save(&injury).await?;
+        self.describe(&injury, game_now, &config).await
+    }
+
+    /// Change whichever fields are given
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        injury_id: InjuryId,
+        input: InjuryInputData,
+    ) -> Result<InjuryData, InjuryError> {
+        let mut injury = self.world_injury(world_id, injury_id).await?;
+        self.apply(world_id, &mut injury, input).await?;
+        let (game_now, config) = self.recovery(world_id).await?;
+        injury.heal_if_recovered(game_now, &config, self.clock.now());
+        self.injury.save(&injury).await?;
+        self.describe(&injury, game_now, &config).await
+    }
+
+    /// Record the outcome of the injury's treatment. An injury the
+    /// treatment finishes healing heals straight away.
+    pub async fn treat(
+        &self,
+        world_id: WorldId,
+        injury_id: InjuryId,
+        succeeded: bool,
+    ) -> Result<InjuryData, InjuryError> {
+        let mut injury = self.world_injury(world_id, injury_id).await?;
+        let (game_now, config) = self.recovery(world_id).await?;
+        injury.treat(succeeded, game_now, &config, self.clock.now())?;
+        injury.heal_if_recovered(game_now, &config, self.clock.now());
+        self.injury.save(&injury).await?;
+        self.describe(&injury, game_now, &config).await
+    }
+
+    pub async fn delete(&self, world_id: WorldId, injury_id: InjuryId) -> Result<(), InjuryError> {
+        let injury = self.world_injury(world_id, injury_id).await?;
+        self.injury.delete(injury.id).await?;
+        Ok(())
+    }
+
+    /// Heal what game time has healed after the clock moved on by
+    /// `minutes`. When the advance was a PC resting, their injuries earn
+    /// rest credit first. Returns the injuries that healed.
+    pub async fn after_time_advance(
+        &self,
+        world_id: WorldId,
+        minutes: u32,
+        rested_pc_id: Option<PlayerCharacterId>,
+    ) -> Result<Vec<InjuryData>, InjuryError> {
+        let (game_now, config) = self.recovery(world_id).await?;
+        let now = self.clock.now();
+        let mut healed = Vec::new();
+        for mut injury in self.injury.list_active_in_world(world_id).await? {
+            let rested = rested_pc_id == Some(injury.pc_id);
+            if rested {
+                injury.credit_rest(minutes, &config, now);
+            }
+            let just_healed = injury.heal_if_recovered(game_now, &config, now);
+            if rested || just_healed {
+                self.injury.save(&injury).await?;
+            }
+            if just_healed {
+                healed.push(self.describe(&injury, game_now, &config).await?);
+            }
+        }
+        Ok(healed)
+    }
+
+    /// Apply the DM-set fields of `input` other than the name on inflict
+    async fn apply(
+        &self,
+        world_id: WorldId,
+        injury: &mut Injury,
+        input: InjuryInputData,
+    ) -> Result<(), InjuryError> {
+        let treatment_challenge_id = match input.treatment_challenge_id.as_deref() {
+            None => None,
+            Some("") => Some(None),
+            Some(id) => {
+                let challenge_id = parse_id(id, ChallengeId::from_uuid, "challenge")?;
+                self.challenge
+                    .get(challenge_id)
+                    .await?
+                    .filter(|c| c.world_id == world_id)
+                    .ok_or(InjuryError::ChallengeNotFound)?;
+                Some(Some(challenge_id))
+            }
+        };
+        injury.update(
+            input.name,
+            input.severity.map(severity_from_protocol).transpose()?,
+            input.capability.map(capability_from_protocol).transpose()?,
+            input.recovery_hours,
+            treatment_challenge_id,
+            input.notes,
+            self.clock.now(),
+        )?;
+        Ok(())
+    }
+
+    async fn world_pc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<(), InjuryError> {
+        self.player_character
+            .get(pc_id)
+            .await?
+            .filter(|pc| pc.world_id == world_id)
+            .ok_or(InjuryError::PlayerCharacterNotFound)?;
+        Ok(())
+    }
+
+    async fn world_injury(
+        &self,
+        world_id: WorldId,
+        injury_id: InjuryId,
+    ) -> Result<Injury, InjuryError> {
+        self.injury
+            .get(injury_id)
+            .await?
+            .filter(|i| i.world_id == world_id)
+            .ok_or(InjuryError::InjuryNotFound)
+    }
+
+    /// The world's game time and its rule system's recovery settings
+    async fn recovery(
+        &self,
+        world_id: WorldId,
+    ) -> Result<(DateTime<Utc>, InjuryRecoveryConfig), InjuryError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(InjuryError::WorldNotFound)?;
+        Ok((
+            world.game_time.current(),
+            world.rule_system.injury_config_or_default(),
+        ))
+    }
+
+    /// The injury for the wire, with its treatment challenge's name
+    async fn describe(
+        &self,
+        injury: &Injury,
+        game_now: DateTime<Utc>,
+        config: &InjuryRecoveryConfig,
+    ) -> Result<InjuryData, InjuryError> {
+        let treatment_challenge_name = match injury.treatment_challenge_id {
+            Some(id) => self.challenge.get(id).await?.map(|c| c.name),
+            None => None,
+        };
+        Ok(InjuryData {
+            id: injury.id.to_string(),
+            pc_id: injury.pc_id.to_string(),
+            name: injury.name.clone(),
+            severity: severity_to_protocol(injury.severity),
+            capability: capability_to_protocol(injury.capability),
+            recovery_hours: injury.recovery_hours,
+            remaining_hours: injury.remaining_hours(game_now, config),
+            treatment_challenge_id: injury.treatment_challenge_id.map(|id| id.to_string()),
+            treatment_challenge_name,
+            treated: injury.treated,
+            injured_at: injury.injured_at.to_rfc3339(),
+            healed_at: injury.healed_at.map(|t| t.to_rfc3339()),
+            notes: injury.notes.clone(),
+        })
+    }
+}
+
+fn parse_id<T>(id: &str, from_uuid: fn(Uuid) -> T, what: &str) -> Result<T, InjuryError> {
+    Uuid::parse_str(id)
+        .map(from_uuid)
+        .map_err(|_| InjuryError::Invalid(format!("Invalid {} ID: {}", what, id)))
+}
+
+fn severity_from_protocol(severity: InjurySeverityData) -> Result<InjurySeverity, InjuryError> {
+    match severity {
+        InjurySeverityData::Minor => Ok(InjurySeverity::Minor),
+        InjurySeverityData::Serious => Ok(InjurySeverity::Serious),
+        InjurySeverityData::Critical => Ok(InjurySeverity::Critical),
+        InjurySeverityData::Unknown => {
+            Err(InjuryError::Invalid("Unknown injury severity".to_string()))
+        }
+    }
+}
+
+fn severity_to_protocol(severity: InjurySeverity) -> InjurySeverityData {
+    match severity {
+        InjurySeverity::Minor => InjurySeverityData::Minor,
+        InjurySeverity::Serious => InjurySeverityData::Serious,
+        InjurySeverity::Critical => InjurySeverityData::Critical,
+    }
+}
+
+fn capability_from_protocol(
+    capability: InjuryCapabilityData,
+) -> Result<InjuryCapability, InjuryError> {
+    match capability {
+        InjuryCapabilityData::Mobility => Ok(InjuryCapability::Mobility),
+        InjuryCapabilityData::Manipulation => Ok(InjuryCapability::Manipulation),
+        InjuryCapabilityData::Senses => Ok(InjuryCapability::Senses),
+        InjuryCapabilityData::Speech => Ok(InjuryCapability::Speech),
+        InjuryCapabilityData::Stamina => Ok(InjuryCapability::Stamina),
+        InjuryCapabilityData::Mind => Ok(InjuryCapability::Mind),
+        InjuryCapabilityData::Unknown => Err(InjuryError::Invalid(
+            "Unknown injury capability".to_string(),
+        )),
+    }
+}
+
+fn capability_to_protocol(capability: InjuryCapability) -> InjuryCapabilityData {
+    match capability {
+        InjuryCapability::Mobility => InjuryCapabilityData::Mobility,
+        InjuryCapability::Manipulation => InjuryCapabilityData::Manipulation,
+        InjuryCapability::Senses => InjuryCapabilityData::Senses,
+        InjuryCapability::Speech => InjuryCapabilityData::Speech,
+        InjuryCapability::Stamina => InjuryCapabilityData::Stamina,
+        InjuryCapability::Mind => InjuryCapabilityData::Mind,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        MockChallengeRepo, MockInjuryRepo, MockPlayerCharacterRepo, MockWorldRepo,
+    };
+    use chrono::Duration;
+    use wrldbldr_domain::{LocationId, PlayerCharacter, World};
+
+    struct Repos {
+        injuries: MockInjuryRepo,
+        pcs: MockPlayerCharacterRepo,
+        challenges: MockChallengeRepo,
+        worlds: MockWorldRepo,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            Self {
+                injuries: MockInjuryRepo::new(),
+                pcs: MockPlayerCharacterRepo::new(),
+                challenges: MockChallengeRepo::new(),
+                worlds: MockWorldRepo::new(),
+            }
+        }
+
+        fn with_world(mut self, world: World) -> Self {
+            self.worlds
+                .expect_get()
+                .returning(move |_| Ok(Some(world.clone())));
+            self
+        }
+
+        fn build(self) -> ManageInjuries {
+            let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(Utc::now()));
+            ManageInjuries::new(
+                Arc::new(entities::Injury::new(Arc::new(self.injuries))),
+                Arc::new(entities::PlayerCharacter::new(Arc::new(self.pcs))),
+                Arc::new(entities::Challenge::new(Arc::new(self.challenges))),
+                Arc::new(entities::World::new(Arc::new(self.worlds), clock.clone())),
+                clock,
+            )
+        }
+    }
+
+    fn injury_in(world: &World, hours_ago: i64) -> Injury {
+        Injury::new(
+            world.id,
+            PlayerCharacterId::new(),
+            "Sprained wrist",
+            InjurySeverity::Minor,
+            &world.rule_system.injury_config_or_default(),
+            world.game_time.current() - Duration::hours(hours_ago),
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn inflicted_injuries_heal_in_the_rule_systems_time() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let world_id = world.id;
+        let expected = world.rule_system.injury_config_or_default().serious_hours;
+        let pc = PlayerCharacter::new("user", world_id, "Ash", LocationId::new(), Utc::now());
+        let pc_id = pc.id;
+
+        let mut repos = Repos::new().with_world(world);
+        repos
+            .pcs
+            .expect_get()
+            .returning(move |_| Ok(Some(pc.clone())));
+        repos
+            .injuries
+            .expect_list_for_pc()
+            .returning(|_| Ok(vec![]));
+        repos
+            .injuries
+            .expect_save()
+            .withf(move |i| i.recovery_hours == expected && !i.is_healed())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let data = InjuryInputData {
+            name: Some("Broken leg".to_string()),
+            severity: Some(InjurySeverityData::Serious),
+            capability: Some(InjuryCapabilityData::Mobility),
+            ..Default::default()
+        };
+        let injury = repos
+            .build()
+            .inflict(world_id, pc_id, data)
+            .await
+            .expect("inflicted");
+
+        assert_eq!(injury.remaining_hours, expected);
+        assert_eq!(injury.capability, InjuryCapabilityData::Mobility);
+    }
+
+    #[tokio::test]
+    async fn time_advances_heal_injuries_whose_time_is_up() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let minor = world.rule_system.injury_config_or_default().minor_hours as i64;
+        let healed = injury_in(&world, minor);
+        let healed_id = healed.id;
+        let mending = injury_in(&world, 1);
+        let world_id = world.id;
+
+        let mut repos = Repos::new().with_world(world);
+        repos
+            .injuries
+            .expect_list_active_in_world()
+            .returning(move |_| Ok(vec![healed.clone(), mending.clone()]));
+        // Only the healed injury changed; nobody rested
+        repos
+            .injuries
+            .expect_save()
+            .withf(move |i| i.id == healed_id && i.is_healed())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let result = repos
+            .build()
+            .after_time_advance(world_id, 60, None)
+            .await
+            .expect("advanced");
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, healed_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn resting_credits_only_the_resting_pc() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let resting = injury_in(&world, 0);
+        let resting_pc = resting.pc_id;
+        let other = injury_in(&world, 0);
+        let world_id = world.id;
+
+        let mut repos = Repos::new().with_world(world);
+        repos
+            .injuries
+            .expect_list_active_in_world()
+            .returning(move |_| Ok(vec![resting.clone(), other.clone()]));
+        repos
+            .injuries
+            .expect_save()
+            .withf(move |i| i.pc_id == resting_pc && i.bonus_hours > 0)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        repos
+            .build()
+            .after_time_advance(world_id, 8 * 60, Some(resting_pc))
+            .await
+            .expect("advanced");
+    }
+}
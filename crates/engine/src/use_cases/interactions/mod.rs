@@ -0,0 +1,224 @@
+//! Interaction use cases.
+//!
+//! Works out which scene interactions a PC can use right now and records
+//! each use. The DM can offer an interaction only at some times of day,
+//! give each PC a limited number of uses, and make a PC wait a number of
+//! game minutes between uses.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    GameTime, InteractionAvailability, InteractionId, InteractionTemplate, InteractionUsage,
+    PlayerCharacterId, SceneId,
+};
+
+use crate::entities::{Interaction, PlayerCharacter, Scene, World};
+use crate::infrastructure::ports::RepoError;
+
+/// Container for interaction use cases.
+pub struct InteractionUseCases {
+    pub library: Arc<InteractionLibrary>,
+}
+
+impl InteractionUseCases {
+    pub fn new(library: Arc<InteractionLibrary>) -> Self {
+        Self { library }
+    }
+}
+
+/// An interaction as one PC sees it.
+#[derive(Debug, Clone)]
+pub struct PcInteraction {
+    pub interaction: InteractionTemplate,
+    pub availability: InteractionAvailability,
+    pub uses_left: Option<u32>,
+}
+
+impl PcInteraction {
+    /// Why the PC can't use it right now, in words for the action button
+    pub fn unavailable_reason(&self) -> Option<String> {
+        unavailable_reason(self.availability)
+    }
+}
+
+/// Per-PC availability of scene interactions.
+pub struct InteractionLibrary {
+    interaction: Arc<Interaction>,
+    scene: Arc<Scene>,
+    player_character: Arc<PlayerCharacter>,
+    world: Arc<World>,
+}
+
+impl InteractionLibrary {
+    pub fn new(
+        interaction: Arc<Interaction>,
+        scene: Arc<Scene>,
+        player_character: Arc<PlayerCharacter>,
+        world: Arc<World>,
+    ) -> Self {
+        Self {
+            interaction,
+            scene,
+            player_character,
+            world,
+        }
+    }
+
+    /// Interactions of the scenes in the PC's region, in display order.
+    ///
+    /// Ones the DM switched off or that aren't offered at this time of day
+    /// are left out; ones the PC used up or must wait for stay in so the UI
+    /// can show why they're greyed out.
+    pub async fn list_for_pc(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<PcInteraction>, InteractionError> {
+        let pc = self
+            .player_character
+            .get(pc_id)
+            .await?
+            .ok_or(InteractionError::PcNotFound)?;
+        let Some(region_id) = pc.current_region_id else {
+            return Ok(Vec::new());
+        };
+        let game_time = self.game_time(pc_id).await?;
+
+        let mut listed = Vec::new();
+        for scene in self.scene.list_for_region(region_id).await? {
+            listed.extend(
+                self.scene_for_pc(pc_id, scene.id, &game_time)
+                    .await?
+                    .into_iter()
+                    .filter(|i| {
+                        !matches!(
+                            i.availability,
+                            InteractionAvailability::Disabled | InteractionAvailability::OutOfHours
+                        )
+                    }),
+            );
+        }
+        Ok(listed)
+    }
+
+    /// Every interaction of a scene, with where each stands for the PC.
+    pub async fn list_for_pc_in_scene(
+        &self,
+        pc_id: PlayerCharacterId,
+        scene_id: SceneId,
+    ) -> Result<Vec<PcInteraction>, InteractionError> {
+        let game_time = self.game_time(pc_id).await?;
+        self.scene_for_pc(pc_id, scene_id, &game_time).await
+    }
+
+    /// The interaction, if the PC can use it right now.
+    pub async fn check_use(
+        &self,
+        pc_id: PlayerCharacterId,
+        interaction_id: InteractionId,
+    ) -> Result<InteractionTemplate, InteractionError> {
+        let (interaction, _, _) = self.usable(pc_id, interaction_id).await?;
+        Ok(interaction)
+    }
+
+    /// Use an interaction: fails if the PC can't use it right now, and
+    /// otherwise counts the use at the current game time.
+    pub async fn record_use(
+        &self,
+        pc_id: PlayerCharacterId,
+        interaction_id: InteractionId,
+    ) -> Result<PcInteraction, InteractionError> {
+        let (interaction, usage, game_time) = self.usable(pc_id, interaction_id).await?;
+
+        let usage = match usage {
+            Some(usage) => usage.record(game_time.current()),
+            None => InteractionUsage::first(interaction_id, pc_id, game_time.current()),
+        };
+        self.interaction.save_usage(&usage).await?;
+
+        Ok(PcInteraction {
+            availability: interaction.availability_for(Some(&usage), &game_time),
+            uses_left: interaction.uses_left(Some(&usage)),
+            interaction,
+        })
+    }
+
+    async fn usable(
+        &self,
+        pc_id: PlayerCharacterId,
+        interaction_id: InteractionId,
+    ) -> Result<(InteractionTemplate, Option<InteractionUsage>, GameTime), InteractionError> {
+        let interaction = self
+            .interaction
+            .get(interaction_id)
+            .await?
+            .ok_or(InteractionError::NotFound)?;
+        let game_time = self.game_time(pc_id).await?;
+        let usage = self.interaction.get_usage(interaction_id, pc_id).await?;
+
+        let availability = interaction.availability_for(usage.as_ref(), &game_time);
+        if !availability.is_available() {
+            return Err(InteractionError::Unavailable(availability));
+        }
+        Ok((interaction, usage, game_time))
+    }
+
+    async fn scene_for_pc(
+        &self,
+        pc_id: PlayerCharacterId,
+        scene_id: SceneId,
+        game_time: &GameTime,
+    ) -> Result<Vec<PcInteraction>, InteractionError> {
+        let mut listed = Vec::new();
+        for interaction in self.interaction.list_for_scene(scene_id).await? {
+            let usage = self.interaction.get_usage(interaction.id, pc_id).await?;
+            listed.push(PcInteraction {
+                availability: interaction.availability_for(usage.as_ref(), game_time),
+                uses_left: interaction.uses_left(usage.as_ref()),
+                interaction,
+            });
+        }
+        Ok(listed)
+    }
+
+    async fn game_time(&self, pc_id: PlayerCharacterId) -> Result<GameTime, InteractionError> {
+        let pc = self
+            .player_character
+            .get(pc_id)
+            .await?
+            .ok_or(InteractionError::PcNotFound)?;
+        let world = self
+            .world
+            .get(pc.world_id)
+            .await?
+            .ok_or(InteractionError::WorldNotFound)?;
+        Ok(world.game_time)
+    }
+}
+
+/// Why an interaction can't be used, or `None` if it can
+pub fn unavailable_reason(availability: InteractionAvailability) -> Option<String> {
+    match availability {
+        InteractionAvailability::Available => None,
+        InteractionAvailability::Disabled => Some("Not available".to_string()),
+        InteractionAvailability::OutOfHours => Some("Not at this time of day".to_string()),
+        InteractionAvailability::UsedUp => Some("No uses left".to_string()),
+        InteractionAvailability::CoolingDown { ready_at } => Some(format!(
+            "Ready again {}",
+            GameTime::starting_at(ready_at).display_date()
+        )),
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum InteractionError {
+    #[error("Interaction not found")]
+    NotFound,
+    #[error("Player character not found")]
+    PcNotFound,
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("{}", unavailable_reason(*.0).unwrap_or_default())]
+    Unavailable(InteractionAvailability),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
@@ -0,0 +1,285 @@
+//! Knowledge sharing between player characters.
+//!
+//! A player can formally pass on something their PC knows - a piece of lore
+//! or where they last saw an NPC - to other PCs in the party. Recipients
+//! learn only what the sharer knows, and never lose anything they already
+//! knew better.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    CharacterId, LoreChunkId, LoreDiscoverySource, LoreId, LoreKnowledge, NpcObservation,
+    PlayerCharacter as DomainPlayerCharacter, PlayerCharacterId, WorldId,
+};
+
+use crate::entities::{Character, Lore, Observation, PlayerCharacter};
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for knowledge use cases.
+pub struct KnowledgeUseCases {
+    pub share: Arc<ShareKnowledge>,
+}
+
+impl KnowledgeUseCases {
+    pub fn new(share: Arc<ShareKnowledge>) -> Self {
+        Self { share }
+    }
+}
+
+/// What a PC passes on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedKnowledge {
+    /// The parts of a lore entry the PC knows
+    Lore(LoreId),
+    /// Where the PC last saw or heard of an NPC
+    NpcWhereabouts(CharacterId),
+}
+
+/// Result of sharing knowledge.
+#[derive(Debug, Clone)]
+pub struct KnowledgeShared {
+    pub world_id: WorldId,
+    pub from_pc_id: PlayerCharacterId,
+    pub from_pc_name: String,
+    /// PCs whose knowledge changed
+    pub recipients: Vec<PlayerCharacterId>,
+    /// Short description of what was shared, for notifications
+    pub title: String,
+    /// Set when lore was shared
+    pub lore: Option<SharedLore>,
+}
+
+/// Lore passed on, with what each recipient now knows of it.
+#[derive(Debug, Clone)]
+pub struct SharedLore {
+    pub lore: wrldbldr_domain::Lore,
+    /// Each recipient's known chunks after sharing (empty = all)
+    pub known_chunk_ids: Vec<(PlayerCharacterId, Vec<LoreChunkId>)>,
+}
+
+pub struct ShareKnowledge {
+    player_character: Arc<PlayerCharacter>,
+    character: Arc<Character>,
+    lore: Arc<Lore>,
+    observation: Arc<Observation>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ShareKnowledge {
+    pub fn new(
+        player_character: Arc<PlayerCharacter>,
+        character: Arc<Character>,
+        lore: Arc<Lore>,
+        observation: Arc<Observation>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            player_character,
+            character,
+            lore,
+            observation,
+            clock,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        from_pc_id: PlayerCharacterId,
+        to_pc_ids: &[PlayerCharacterId],
+        knowledge: SharedKnowledge,
+    ) -> Result<KnowledgeShared, ShareKnowledgeError> {
+        let from_pc = self.get_pc(from_pc_id).await?;
+
+        let mut targets = Vec::with_capacity(to_pc_ids.len());
+        for to_pc_id in to_pc_ids {
+            if *to_pc_id == from_pc_id
+                || targets
+                    .iter()
+                    .any(|pc: &DomainPlayerCharacter| pc.id == *to_pc_id)
+            {
+                continue;
+            }
+            let pc = self.get_pc(*to_pc_id).await?;
+            if pc.world_id != from_pc.world_id {
+                return Err(ShareKnowledgeError::NotInParty);
+            }
+            targets.push(pc);
+        }
+        if targets.is_empty() {
+            return Err(ShareKnowledgeError::NoRecipients);
+        }
+
+        match knowledge {
+            SharedKnowledge::Lore(lore_id) => self.share_lore(&from_pc, &targets, lore_id).await,
+            SharedKnowledge::NpcWhereabouts(npc_id) => {
+                self.share_whereabouts(&from_pc, &targets, npc_id).await
+            }
+        }
+    }
+
+    async fn share_lore(
+        &self,
+        from_pc: &DomainPlayerCharacter,
+        targets: &[DomainPlayerCharacter],
+        lore_id: LoreId,
+    ) -> Result<KnowledgeShared, ShareKnowledgeError> {
+        let lore = self
+            .lore
+            .get(lore_id)
+            .await?
+            .filter(|lore| lore.world_id == from_pc.world_id)
+            .ok_or(ShareKnowledgeError::NotKnown)?;
+        let known = self
+            .lore
+            .character_knows_lore(pc_character_id(from_pc.id), lore_id)
+            .await?
+            .ok_or(ShareKnowledgeError::NotKnown)?;
+
+        let source = LoreDiscoverySource::SharedByPc {
+            pc_id: from_pc.id,
+            pc_name: from_pc.name.clone(),
+        };
+        let now = self.clock.now();
+        let mut known_chunk_ids = Vec::new();
+        for target in targets {
+            let character_id = pc_character_id(target.id);
+            match self
+                .lore
+                .character_knows_lore(character_id, lore_id)
+                .await?
+            {
+                Some(existing) if existing.knows_all() => continue,
+                Some(existing) if !known.knows_all() => {
+                    let new_chunks: Vec<LoreChunkId> = known
+                        .known_chunk_ids
+                        .iter()
+                        .filter(|id| !existing.known_chunk_ids.contains(id))
+                        .copied()
+                        .collect();
+                    if new_chunks.is_empty() {
+                        continue;
+                    }
+                    self.lore
+                        .add_chunks_to_knowledge(character_id, lore_id, &new_chunks)
+                        .await?;
+                    let mut now_known = existing.known_chunk_ids;
+                    now_known.extend(new_chunks);
+                    known_chunk_ids.push((target.id, now_known));
+                }
+                _ => {
+                    let knowledge = if known.knows_all() {
+                        LoreKnowledge::full(lore_id, character_id, source.clone(), now)
+                    } else {
+                        LoreKnowledge::partial(
+                            lore_id,
+                            character_id,
+                            known.known_chunk_ids.clone(),
+                            source.clone(),
+                            now,
+                        )
+                    };
+                    self.lore.grant_knowledge(&knowledge).await?;
+                    known_chunk_ids.push((target.id, knowledge.known_chunk_ids));
+                }
+            }
+        }
+
+        Ok(KnowledgeShared {
+            world_id: from_pc.world_id,
+            from_pc_id: from_pc.id,
+            from_pc_name: from_pc.name.clone(),
+            recipients: known_chunk_ids.iter().map(|(pc_id, _)| *pc_id).collect(),
+            title: lore.title.clone(),
+            lore: Some(SharedLore {
+                lore,
+                known_chunk_ids,
+            }),
+        })
+    }
+
+    async fn share_whereabouts(
+        &self,
+        from_pc: &DomainPlayerCharacter,
+        targets: &[DomainPlayerCharacter],
+        npc_id: CharacterId,
+    ) -> Result<KnowledgeShared, ShareKnowledgeError> {
+        let seen = self
+            .observation
+            .get_observations(from_pc.id)
+            .await?
+            .into_iter()
+            .find(|obs| obs.npc_id == npc_id)
+            .ok_or(ShareKnowledgeError::NotKnown)?;
+
+        let now = self.clock.now();
+        let mut recipients = Vec::new();
+        for target in targets {
+            // Keep what the recipient knows if it's as recent or more so
+            let has_newer = self
+                .observation
+                .get_observations(target.id)
+                .await?
+                .iter()
+                .any(|obs| obs.npc_id == npc_id && obs.game_time >= seen.game_time);
+            if has_newer {
+                continue;
+            }
+
+            let mut observation = NpcObservation::heard_about(
+                target.id,
+                npc_id,
+                seen.location_id,
+                seen.region_id,
+                seen.game_time,
+                Some(format!("Told by {}", from_pc.name)),
+                now,
+            );
+            observation.is_revealed_to_player = seen.is_revealed_to_player;
+            self.observation.save_observation(&observation).await?;
+            recipients.push(target.id);
+        }
+
+        let title = match self.character.get(npc_id).await? {
+            Some(npc) if seen.is_revealed_to_player => format!("Where {} was seen", npc.name),
+            _ => "Where someone was seen".to_string(),
+        };
+
+        Ok(KnowledgeShared {
+            world_id: from_pc.world_id,
+            from_pc_id: from_pc.id,
+            from_pc_name: from_pc.name.clone(),
+            recipients,
+            title,
+            lore: None,
+        })
+    }
+
+    async fn get_pc(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<DomainPlayerCharacter, ShareKnowledgeError> {
+        self.player_character
+            .get(pc_id)
+            .await?
+            .ok_or(ShareKnowledgeError::PlayerCharacterNotFound)
+    }
+}
+
+/// Lore knowledge is keyed by character ID for PCs and NPCs alike.
+fn pc_character_id(pc_id: PlayerCharacterId) -> CharacterId {
+    CharacterId::from_uuid(pc_id.to_uuid())
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ShareKnowledgeError {
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("Knowledge can only be shared within the same world")]
+    NotInParty,
+    #[error("No one to share with")]
+    NoRecipients,
+    #[error("The character doesn't know that")]
+    NotKnown,
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
@@ -0,0 +1,512 @@
+//! Content library use cases.
+//!
+//! A DM publishes characters, items, challenges and lore from one world into
+//! a personal library and imports them into another. The library belongs to
+//! the user: every operation takes the owner and treats other users' entries
+//! as missing.
+//!
+//! Importing creates an independent copy with fresh IDs. Graph ties (an NPC's
+//! relationships, who knows a piece of lore) stay behind in the source world.
+//! An imported challenge checks the target world's matching skill, found
+//! through its rule system's skill aliases.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    ChallengeId, CharacterId, EntityType, ItemId, LibraryContent, LibraryEntry, LibraryEntryId,
+    LoreId, WorldId, MAX_LIBRARY_ENTRIES_PER_USER,
+};
+use wrldbldr_protocol::types::{CreatedEntityData, LibraryEntryData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for content library use cases.
+pub struct LibraryUseCases {
+    pub manage: Arc<ManageLibrary>,
+    pub import: Arc<ImportLibraryEntry>,
+}
+
+impl LibraryUseCases {
+    pub fn new(manage: Arc<ManageLibrary>, import: Arc<ImportLibraryEntry>) -> Self {
+        Self { manage, import }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LibraryError {
+    #[error("Library entry not found")]
+    NotFound,
+    #[error("{0} not found")]
+    EntityNotFound(EntityType),
+    #[error("{0} can't be published to the library")]
+    Unsupported(EntityType),
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+/// The content a library entry can be published from or imported into.
+struct LibrarySources {
+    character: Arc<entities::Character>,
+    inventory: Arc<entities::Inventory>,
+    challenge: Arc<entities::Challenge>,
+    lore: Arc<entities::Lore>,
+}
+
+/// List, publish and delete a user's library entries.
+pub struct ManageLibrary {
+    library: Arc<entities::Library>,
+    sources: LibrarySources,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageLibrary {
+    pub fn new(
+        library: Arc<entities::Library>,
+        character: Arc<entities::Character>,
+        inventory: Arc<entities::Inventory>,
+        challenge: Arc<entities::Challenge>,
+        lore: Arc<entities::Lore>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            library,
+            sources: LibrarySources {
+                character,
+                inventory,
+                challenge,
+                lore,
+            },
+            clock,
+        }
+    }
+
+    pub async fn list(&self, owner_id: &str) -> Result<Vec<LibraryEntryData>, LibraryError> {
+        let entries = self.library.list_for_owner(owner_id).await?;
+        Ok(entries.iter().map(entry_to_protocol).collect())
+    }
+
+    pub async fn get(
+        &self,
+        owner_id: &str,
+        entry_id: LibraryEntryId,
+    ) -> Result<LibraryEntryData, LibraryError> {
+        let entry = owned_entry(&self.library, owner_id, entry_id).await?;
+        Ok(entry_to_protocol(&entry))
+    }
+
+    /// Publish a snapshot of an entity in `world_id`.
+    pub async fn publish(
+        &self,
+        owner_id: &str,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        description: Option<String>,
+    ) -> Result<LibraryEntryData, LibraryError> {
+        let content = self
+            .sources
+            .load(entity_type, entity_id)
+            .await?
+            .filter(|content| content.world_id() == world_id)
+            .ok_or(LibraryError::EntityNotFound(entity_type))?;
+
+        let existing = self.library.list_for_owner(owner_id).await?;
+        if existing.len() >= MAX_LIBRARY_ENTRIES_PER_USER {
+            return Err(LibraryError::Invalid(format!(
+                "A library can hold at most {} entries",
+                MAX_LIBRARY_ENTRIES_PER_USER
+            )));
+        }
+
+        let entry = LibraryEntry::new(owner_id, description, content, self.clock.now())
+            .map_err(|e| LibraryError::Invalid(e.to_string()))?;
+        self.library.save(&entry).await?;
+
+        tracing::info!(
+            entry_id = %entry.id,
+            entity_type = %entity_type,
+            entity_id = %entity_id,
+            "Published to content library"
+        );
+        Ok(entry_to_protocol(&entry))
+    }
+
+    /// Delete an entry. Content already imported from it is unaffected.
+    pub async fn delete(
+        &self,
+        owner_id: &str,
+        entry_id: LibraryEntryId,
+    ) -> Result<(), LibraryError> {
+        owned_entry(&self.library, owner_id, entry_id).await?;
+        Ok(self.library.delete(entry_id).await?)
+    }
+}
+
+/// Copy a library entry into a world.
+pub struct ImportLibraryEntry {
+    library: Arc<entities::Library>,
+    sources: LibrarySources,
+    world: Arc<entities::World>,
+    skill: Arc<entities::Skill>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ImportLibraryEntry {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        library: Arc<entities::Library>,
+        character: Arc<entities::Character>,
+        inventory: Arc<entities::Inventory>,
+        challenge: Arc<entities::Challenge>,
+        lore: Arc<entities::Lore>,
+        world: Arc<entities::World>,
+        skill: Arc<entities::Skill>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            library,
+            sources: LibrarySources {
+                character,
+                inventory,
+                challenge,
+                lore,
+            },
+            world,
+            skill,
+            clock,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        owner_id: &str,
+        entry_id: LibraryEntryId,
+        world_id: WorldId,
+    ) -> Result<CreatedEntityData, LibraryError> {
+        let entry = owned_entry(&self.library, owner_id, entry_id).await?;
+        let mut content = entry.content.import_into(world_id, self.clock.now());
+        if let LibraryContent::Challenge(challenge) = &mut content {
+            self.adopt_skill(challenge, world_id).await?;
+        }
+        let entity_id = self.sources.save(&content).await?;
+
+        tracing::info!(
+            entry_id = %entry_id,
+            world_id = %world_id,
+            entity_id = %entity_id,
+            "Imported from content library"
+        );
+        Ok(CreatedEntityData {
+            entity_type: content.entity_type(),
+            entity_id: entity_id.to_string(),
+            name: content.name().to_string(),
+        })
+    }
+
+    /// Point an imported challenge at the world's skill for what it checks.
+    async fn adopt_skill(
+        &self,
+        challenge: &mut wrldbldr_domain::Challenge,
+        world_id: WorldId,
+    ) -> Result<(), LibraryError> {
+        let Some(world) = self.world.get(world_id).await? else {
+            return Ok(());
+        };
+        let skills = self.skill.list_in_world(world_id).await?;
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        challenge.remap_skill(&world.rule_system.skill_aliases_or_default(), &names);
+        Ok(())
+    }
+}
+
+impl LibrarySources {
+    async fn load(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Option<LibraryContent>, LibraryError> {
+        Ok(match entity_type {
+            EntityType::Character => self
+                .character
+                .get(CharacterId::from_uuid(entity_id))
+                .await?
+                .map(LibraryContent::Character),
+            EntityType::Item => self
+                .inventory
+                .get(ItemId::from_uuid(entity_id))
+                .await?
+                .map(LibraryContent::Item),
+            EntityType::Challenge => self
+                .challenge
+                .get(ChallengeId::from_uuid(entity_id))
+                .await?
+                .map(LibraryContent::Challenge),
+            EntityType::Lore => self
+                .lore
+                .get(LoreId::from_uuid(entity_id))
+                .await?
+                .map(LibraryContent::Lore),
+            other => return Err(LibraryError::Unsupported(other)),
+        })
+    }
+
+    /// Save imported content, returning its ID.
+    async fn save(&self, content: &LibraryContent) -> Result<Uuid, LibraryError> {
+        Ok(match content {
+            LibraryContent::Character(character) => {
+                self.character.save(character).await?;
+                character.id.into()
+            }
+            LibraryContent::Item(item) => {
+                self.inventory.save(item).await?;
+                item.id.into()
+            }
+            LibraryContent::Challenge(challenge) => {
+                self.challenge.save(challenge).await?;
+                challenge.id.into()
+            }
+            LibraryContent::Lore(lore) => {
+                self.lore.save(lore).await?;
+                lore.id.into()
+            }
+        })
+    }
+}
+
+/// The entry, if it exists and belongs to `owner_id`.
+async fn owned_entry(
+    library: &entities::Library,
+    owner_id: &str,
+    entry_id: LibraryEntryId,
+) -> Result<LibraryEntry, LibraryError> {
+    library
+        .get(entry_id)
+        .await?
+        .filter(|entry| entry.owner_id == owner_id)
+        .ok_or(LibraryError::NotFound)
+}
+
+fn entry_to_protocol(entry: &LibraryEntry) -> LibraryEntryData {
+    LibraryEntryData {
+        id: entry.id.to_string(),
+        name: entry.name.clone(),
+        description: entry.description.clone(),
+        entity_type: entry.content.entity_type(),
+        summary: entry.content.summary().to_string(),
+        source_world_id: entry.source_world_id.to_string(),
+        created_at: entry.created_at.to_rfc3339(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        MockChallengeRepo, MockCharacterRepo, MockItemRepo, MockLibraryRepo, MockLoreRepo,
+        MockPlayerCharacterRepo, MockSkillRepo, MockWorldRepo,
+    };
+    use wrldbldr_domain::CampbellArchetype;
+
+    struct Repos {
+        library: MockLibraryRepo,
+        character: MockCharacterRepo,
+        challenge: MockChallengeRepo,
+        world: MockWorldRepo,
+        skill: MockSkillRepo,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            Self {
+                library: MockLibraryRepo::new(),
+                character: MockCharacterRepo::new(),
+                challenge: MockChallengeRepo::new(),
+                world: MockWorldRepo::new(),
+                skill: MockSkillRepo::new(),
+            }
+        }
+
+        fn build(self) -> (ManageLibrary, ImportLibraryEntry) {
+            let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(chrono::Utc::now()));
+            let library = Arc::new(entities::Library::new(Arc::new(self.library)));
+            let character_repo: Arc<dyn crate::infrastructure::ports::CharacterRepo> =
+                Arc::new(self.character);
+            let character = Arc::new(entities::Character::new(character_repo.clone()));
+            let inventory = Arc::new(entities::Inventory::new(
+                Arc::new(MockItemRepo::new()),
+                character_repo,
+                Arc::new(MockPlayerCharacterRepo::new()),
+            ));
+            let challenge = Arc::new(entities::Challenge::new(Arc::new(self.challenge)));
+            let lore = Arc::new(entities::Lore::new(Arc::new(MockLoreRepo::new())));
+            let world = Arc::new(entities::World::new(Arc::new(self.world), clock.clone()));
+            let skill = Arc::new(entities::Skill::new(Arc::new(self.skill)));
+            (
+                ManageLibrary::new(
+                    library.clone(),
+                    character.clone(),
+                    inventory.clone(),
+                    challenge.clone(),
+                    lore.clone(),
+                    clock.clone(),
+                ),
+                ImportLibraryEntry::new(
+                    library, character, inventory, challenge, lore, world, skill, clock,
+                ),
+            )
+        }
+    }
+
+    fn villain(world_id: WorldId) -> wrldbldr_domain::Character {
+        wrldbldr_domain::Character::new(world_id, "Lady Vex", CampbellArchetype::Shadow)
+    }
+
+    #[tokio::test]
+    async fn publishing_content_from_another_world_is_rejected() {
+        let character = villain(WorldId::new());
+        let character_id = character.id;
+
+        let mut repos = Repos::new();
+        repos
+            .character
+            .expect_get()
+            .returning(move |_| Ok(Some(character.clone())));
+        repos.library.expect_save().never();
+        let (manage, _) = repos.build();
+
+        let result = manage
+            .publish(
+                "dm-1",
+                WorldId::new(),
+                EntityType::Character,
+                character_id.into(),
+                None,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(LibraryError::EntityNotFound(EntityType::Character))
+        ));
+    }
+
+    #[tokio::test]
+    async fn importing_a_character_saves_a_copy_in_the_target_world() {
+        let source_world = WorldId::new();
+        let target_world = WorldId::new();
+        let character = villain(source_world);
+        let source_id = character.id;
+        let entry = LibraryEntry::new(
+            "dm-1",
+            Some("Recurring villain".to_string()),
+            LibraryContent::Character(character),
+            chrono::Utc::now(),
+        )
+        .expect("valid entry");
+        let entry_id = entry.id;
+
+        let mut repos = Repos::new();
+        repos
+            .library
+            .expect_get()
+            .returning(move |_| Ok(Some(entry.clone())));
+        repos
+            .character
+            .expect_save()
+            .withf(move |c| c.world_id == target_world && c.id != source_id && c.name == "Lady Vex")
+            .times(1)
+            .returning(|_| Ok(()));
+        let (_, import) = repos.build();
+
+        let created = import
+            .execute("dm-1", entry_id, target_world)
+            .await
+            .expect("imported");
+        assert_eq!(created.entity_type, EntityType::Character);
+        assert_eq!(created.name, "Lady Vex");
+        assert_ne!(created.entity_id, source_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn an_imported_challenge_checks_the_target_worlds_skill() {
+        let challenge = wrldbldr_domain::Challenge::new(
+            WorldId::new(),
+            "Spot the ambush",
+            wrldbldr_domain::Difficulty::d20_medium(),
+        )
+        .with_check_stat("Perception");
+        let entry = LibraryEntry::new(
+            "dm-1",
+            None,
+            LibraryContent::Challenge(challenge),
+            chrono::Utc::now(),
+        )
+        .expect("valid entry");
+        let entry_id = entry.id;
+        let target = wrldbldr_domain::World::new("Fate world", "desc", chrono::Utc::now())
+            .with_rule_system(wrldbldr_domain::RuleSystemConfig::fate_core());
+        let target_id = target.id;
+
+        let mut repos = Repos::new();
+        repos
+            .library
+            .expect_get()
+            .returning(move |_| Ok(Some(entry.clone())));
+        repos
+            .world
+            .expect_get()
+            .returning(move |_| Ok(Some(target.clone())));
+        repos.skill.expect_list_in_world().returning(|world_id| {
+            Ok(wrldbldr_domain::default_skills_for_variant(
+                world_id,
+                &wrldbldr_domain::RuleSystemVariant::FateCore,
+            ))
+        });
+        repos
+            .challenge
+            .expect_save()
+            .withf(|c| c.check_stat.as_deref() == Some("Notice"))
+            .times(1)
+            .returning(|_| Ok(()));
+        let (_, import) = repos.build();
+
+        import
+            .execute("dm-1", entry_id, target_id)
+            .await
+            .expect("imported");
+    }
+
+    #[tokio::test]
+    async fn other_users_entries_are_not_visible() {
+        let entry = LibraryEntry::new(
+            "dm-1",
+            None,
+            LibraryContent::Character(villain(WorldId::new())),
+            chrono::Utc::now(),
+        )
+        .expect("valid entry");
+        let entry_id = entry.id;
+
+        let mut repos = Repos::new();
+        repos
+            .library
+            .expect_get()
+            .returning(move |_| Ok(Some(entry.clone())));
+        repos.library.expect_delete().never();
+        repos.character.expect_save().never();
+        let (manage, import) = repos.build();
+
+        assert!(matches!(
+            manage.delete("dm-2", entry_id).await,
+            Err(LibraryError::NotFound)
+        ));
+        assert!(matches!(
+            import.execute("dm-2", entry_id, WorldId::new()).await,
+            Err(LibraryError::NotFound)
+        ));
+    }
+}
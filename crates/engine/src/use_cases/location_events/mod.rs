@@ -1,21 +1,27 @@
 //! Location event use cases.
 //!
-//! Handles DM-triggered location events.
+//! Handles DM-triggered location events, and noises that carry into the
+//! regions next door.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::entities::Location;
-use crate::infrastructure::ports::RepoError;
-use wrldbldr_domain::RegionId;
+use chrono::{DateTime, Duration, Utc};
+use tokio::sync::RwLock;
+
+use crate::entities::{Location, PlayerCharacter, Staging};
+use crate::infrastructure::ports::{ClockPort, RepoError};
+use wrldbldr_domain::{Loudness, PlayerCharacterId, RegionId, WorldId, MAX_NOISE_DESCRIPTION_LEN};
 
 /// Container for location event use cases.
 pub struct LocationEventUseCases {
     pub trigger: Arc<TriggerLocationEvent>,
+    pub noise: Arc<MakeNoise>,
 }
 
 impl LocationEventUseCases {
-    pub fn new(trigger: Arc<TriggerLocationEvent>) -> Self {
-        Self { trigger }
+    pub fn new(trigger: Arc<TriggerLocationEvent>, noise: Arc<MakeNoise>) -> Self {
+        Self { trigger, noise }
     }
 }
 
@@ -59,6 +65,192 @@ pub struct LocationEventResult {
 pub enum LocationEventError {
     #[error("Region not found")]
     RegionNotFound,
+    #[error("{0}")]
+    InvalidNoise(String),
     #[error("Repository error: {0}")]
     Repo(#[from] RepoError),
 }
+
+/// How long NPCs remember a noise they heard.
+const NOISE_MEMORY_MINUTES: i64 = 10;
+
+/// Noises kept per region; older ones drop off first.
+const MAX_NOISES_PER_REGION: usize = 5;
+
+/// A noise that carried into a region.
+#[derive(Debug, Clone)]
+struct HeardNoise {
+    line: String,
+    heard_at: DateTime<Utc>,
+}
+
+/// A region a noise reached, and who was there to hear it.
+#[derive(Debug, Clone)]
+pub struct NoiseHeardIn {
+    pub region_id: RegionId,
+    pub region_name: String,
+    pub muffled: bool,
+    /// Staged NPCs, hidden ones included
+    pub npc_names: Vec<String>,
+    pub pcs: Vec<(PlayerCharacterId, String)>,
+}
+
+/// Where a noise was made and how far it carried.
+#[derive(Debug, Clone)]
+pub struct NoiseSpread {
+    pub world_id: WorldId,
+    pub region_id: RegionId,
+    pub region_name: String,
+    pub description: String,
+    pub loudness: Loudness,
+    pub heard_in: Vec<NoiseHeardIn>,
+}
+
+/// Make a noise and let it carry one connection over.
+///
+/// Each connection out of the region decides whether the noise gets
+/// through (see `SoundCarry`). What NPCs heard is held in memory for a few
+/// minutes so their replies can react to it; a restarted engine forgets it.
+pub struct MakeNoise {
+    location: Arc<Location>,
+    staging: Arc<Staging>,
+    player_character: Arc<PlayerCharacter>,
+    clock: Arc<dyn ClockPort>,
+    heard: RwLock<HashMap<RegionId, Vec<HeardNoise>>>,
+}
+
+impl MakeNoise {
+    pub fn new(
+        location: Arc<Location>,
+        staging: Arc<Staging>,
+        player_character: Arc<PlayerCharacter>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            location,
+            staging,
+            player_character,
+            clock,
+            heard: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        world_id: WorldId,
+        region_id: RegionId,
+        description: String,
+        loudness: Loudness,
+    ) -> Result<NoiseSpread, LocationEventError> {
+        let description = description.trim().to_string();
+        if description.is_empty() {
+            return Err(LocationEventError::InvalidNoise(
+                "Describe what can be heard".to_string(),
+            ));
+        }
+        if description.chars().count() > MAX_NOISE_DESCRIPTION_LEN {
+            return Err(LocationEventError::InvalidNoise(format!(
+                "Noise description is longer than {} characters",
+                MAX_NOISE_DESCRIPTION_LEN
+            )));
+        }
+
+        let region = self
+            .location
+            .get_region(region_id)
+            .await?
+            .ok_or(LocationEventError::RegionNotFound)?;
+        // A region in another world is as good as missing
+        self.location
+            .get(region.location_id)
+            .await?
+            .filter(|location| location.world_id == world_id)
+            .ok_or(LocationEventError::RegionNotFound)?;
+        let pcs = self.player_character.list_in_world(world_id).await?;
+
+        let now = self.clock.now();
+        let mut heard_in = Vec::new();
+        for connection in self.location.get_connections(region_id).await? {
+            let Some(muffled) = connection.sound.carries(loudness) else {
+                continue;
+            };
+            let Some(next) = self.location.get_region(connection.to_region).await? else {
+                continue;
+            };
+            let npc_names = self
+                .staging
+                .resolve_for_region_dm_view(next.id)
+                .await?
+                .into_iter()
+                .map(|npc| npc.name)
+                .collect();
+            let pcs_there = pcs
+                .iter()
+                .filter(|pc| pc.current_region_id == Some(next.id) && !pc.is_locked())
+                .map(|pc| (pc.id, pc.name.clone()))
+                .collect();
+            self.remember(
+                next.id,
+                heard_line(&description, &region.name, muffled),
+                now,
+            )
+            .await;
+            heard_in.push(NoiseHeardIn {
+                region_id: next.id,
+                region_name: next.name,
+                muffled,
+                npc_names,
+                pcs: pcs_there,
+            });
+        }
+
+        Ok(NoiseSpread {
+            world_id,
+            region_id,
+            region_name: region.name,
+            description,
+            loudness,
+            heard_in,
+        })
+    }
+
+    /// Noises heard in a region in the last few minutes, oldest first.
+    pub async fn recently_heard(&self, region_id: RegionId) -> Vec<String> {
+        let since = self.clock.now() - Duration::minutes(NOISE_MEMORY_MINUTES);
+        self.heard
+            .read()
+            .await
+            .get(&region_id)
+            .map(|noises| {
+                noises
+                    .iter()
+                    .filter(|noise| noise.heard_at > since)
+                    .map(|noise| noise.line.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    async fn remember(&self, region_id: RegionId, line: String, now: DateTime<Utc>) {
+        let since = now - Duration::minutes(NOISE_MEMORY_MINUTES);
+        let mut heard = self.heard.write().await;
+        let noises = heard.entry(region_id).or_default();
+        noises.retain(|noise| noise.heard_at > since);
+        noises.push(HeardNoise {
+            line,
+            heard_at: now,
+        });
+        if noises.len() > MAX_NOISES_PER_REGION {
+            noises.remove(0);
+        }
+    }
+}
+
+/// "shattering glass, from the Kitchen" as an NPC next door would put it
+fn heard_line(description: &str, from_region: &str, muffled: bool) -> String {
+    if muffled {
+        format!("{}, muffled, from the {}", description, from_region)
+    } else {
+        format!("{}, from the {}", description, from_region)
+    }
+}
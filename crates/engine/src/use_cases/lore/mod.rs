@@ -8,12 +8,15 @@ use uuid::Uuid;
 use crate::entities::Lore;
 use crate::infrastructure::ports::RepoError;
 use wrldbldr_domain::{
-    CharacterId, LoreCategory, LoreChunkId, LoreDiscoverySource, LoreId, LoreKnowledge, WorldId,
+    CharacterId, LoreCategory, LoreChunkId, LoreDiscoverySource, LoreId, LoreKnowledge,
+    PlayerCharacterId, WorldId,
 };
 use wrldbldr_protocol::requests::{
     CreateLoreChunkData, CreateLoreData, UpdateLoreChunkData, UpdateLoreData,
 };
-use wrldbldr_protocol::types::LoreDiscoverySourceData;
+use wrldbldr_protocol::types::{
+    LoreCategoryData, LoreChunkData, LoreData, LoreDiscoverySourceData,
+};
 
 /// Container for lore use cases.
 pub struct LoreUseCases {
@@ -432,6 +435,8 @@ pub enum LoreError {
     InvalidChunkIds(String),
     #[error("Invalid NPC ID in conversation source: {0}")]
     InvalidNpcId(String),
+    #[error("Invalid PC ID in shared source: {0}")]
+    InvalidPcId(String),
     #[error("Duplicate chunk order: {0}")]
     DuplicateChunkOrder(u32),
     #[error("Empty chunk list provided - omit chunkIds for full revocation")]
@@ -462,6 +467,15 @@ fn lore_discovery_source(data: LoreDiscoverySourceData) -> Result<LoreDiscoveryS
         LoreDiscoverySourceData::LlmDiscovered { context } => {
             Ok(LoreDiscoverySource::LlmDiscovered { context })
         }
+        LoreDiscoverySourceData::SharedByPc { pc_id, pc_name } => {
+            let pc_uuid = Uuid::parse_str(&pc_id)
+                .map(PlayerCharacterId::from_uuid)
+                .map_err(|_| LoreError::InvalidPcId(pc_id))?;
+            Ok(LoreDiscoverySource::SharedByPc {
+                pc_id: pc_uuid,
+                pc_name,
+            })
+        }
         LoreDiscoverySourceData::Unknown => Ok(LoreDiscoverySource::DmGranted {
             reason: Some("Unknown source type".to_string()),
         }),
@@ -512,6 +526,41 @@ fn lore_to_json(lore: wrldbldr_domain::Lore) -> Value {
     })
 }
 
+/// Lore entry in the wire format pushed to players.
+pub fn lore_to_protocol(lore: &wrldbldr_domain::Lore) -> LoreData {
+    let category = match lore.category {
+        LoreCategory::Historical => LoreCategoryData::Historical,
+        LoreCategory::Legend => LoreCategoryData::Legend,
+        LoreCategory::Secret => LoreCategoryData::Secret,
+        LoreCategory::Common => LoreCategoryData::Common,
+        LoreCategory::Technical => LoreCategoryData::Technical,
+        LoreCategory::Political => LoreCategoryData::Political,
+        LoreCategory::Natural => LoreCategoryData::Natural,
+        LoreCategory::Religious => LoreCategoryData::Religious,
+        LoreCategory::Unknown => LoreCategoryData::Unknown,
+    };
+    LoreData {
+        id: lore.id.to_string(),
+        world_id: lore.world_id.to_string(),
+        title: lore.title.clone(),
+        summary: lore.summary.clone(),
+        category,
+        chunks: lore
+            .chunks
+            .iter()
+            .map(|c| LoreChunkData {
+                id: c.id.to_string(),
+                order: c.order,
+                title: c.title.clone(),
+                content: c.content.clone(),
+                discovery_hint: c.discovery_hint.clone(),
+            })
+            .collect(),
+        is_common_knowledge: lore.is_common_knowledge,
+        tags: lore.tags.clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,662 @@
+//! In-world mail use cases.
+//!
+//! PCs write letters to NPCs and to each other. A letter takes as long to
+//! arrive as the journey between writer and addressee across the location
+//! hierarchy, and stays in transit until the world's game time passes its
+//! delivery time. When a letter reaches an NPC, the NPC writes back through
+//! the LLM and the reply makes the same journey home.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    self as domain, CharacterId, Correspondent, DomainError, Letter, LetterId, LlmTask, LocationId,
+    LocationTree, PlayerCharacter, PlayerCharacterId, WorldId, MAX_LETTER_BODY_LEN,
+};
+use wrldbldr_protocol::types::{
+    CorrespondentData, CorrespondentKindData, LetterData, LetterInputData,
+};
+
+use crate::entities;
+use crate::infrastructure::ports::{
+    ChatMessage, ClockPort, LlmPort, LlmRequest, NpcRegionRelationType, RepoError,
+};
+
+/// Container for mail use cases.
+pub struct MailUseCases {
+    pub manage: Arc<ManageMail>,
+}
+
+impl MailUseCases {
+    pub fn new(manage: Arc<ManageMail>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MailError {
+    #[error("Letter not found")]
+    LetterNotFound,
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("NPC not found")]
+    NpcNotFound,
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("Could not write the reply: {0}")]
+    Generation(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for MailError {
+    fn from(e: DomainError) -> Self {
+        match e {
+            DomainError::InvalidStateTransition(msg) => MailError::Conflict(msg),
+            other => MailError::Invalid(other.to_string()),
+        }
+    }
+}
+
+/// A letter that changed, and the PCs whose players can read it now
+#[derive(Debug, Clone)]
+pub struct LetterNotice {
+    pub letter: LetterData,
+    pub pc_readers: Vec<PlayerCharacterId>,
+}
+
+/// Send, deliver, read and answer in-world letters.
+pub struct ManageMail {
+    letter: Arc<entities::Letter>,
+    player_character: Arc<entities::PlayerCharacter>,
+    character: Arc<entities::Character>,
+    location: Arc<entities::Location>,
+    world: Arc<entities::World>,
+    settings: Arc<entities::Settings>,
+    llm: Arc<dyn LlmPort>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageMail {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        letter: Arc<entities::Letter>,
+        player_character: Arc<entities::PlayerCharacter>,
+        character: Arc<entities::Character>,
+        location: Arc<entities::Location>,
+        world: Arc<entities::World>,
+        settings: Arc<entities::Settings>,
+        llm: Arc<dyn LlmPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            letter,
+            player_character,
+            character,
+            location,
+            world,
+            settings,
+            llm,
+            clock,
+        }
+    }
+
+    /// The PC, if it belongs to the world
+    pub async fn get_pc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<PlayerCharacter, MailError> {
+        self.player_character
+            .get(pc_id)
+            .await?
+            .filter(|pc| pc.world_id == world_id)
+            .ok_or(MailError::PlayerCharacterNotFound)
+    }
+
+    /// The letter, if it belongs to the world
+    pub async fn get(&self, world_id: WorldId, letter_id: LetterId) -> Result<Letter, MailError> {
+        self.letter
+            .get(letter_id)
+            .await?
+            .filter(|l| l.world_id == world_id)
+            .ok_or(MailError::LetterNotFound)
+    }
+
+    /// Letters the PC wrote and the ones that have reached them, newest
+    /// first
+    pub async fn mailbox(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<LetterData>, MailError> {
+        self.get_pc(world_id, pc_id).await?;
+        let mut letters = Vec::new();
+        for letter in self.letter.list_for_pc(pc_id).await? {
+            if letter.pc_readers().contains(&pc_id) {
+                letters.push(self.describe(&letter).await?);
+            }
+        }
+        Ok(letters)
+    }
+
+    /// Every letter in the world, newest first
+    pub async fn world_mail(&self, world_id: WorldId) -> Result<Vec<LetterData>, MailError> {
+        let mut letters = Vec::new();
+        for letter in self.letter.list_in_world(world_id).await? {
+            letters.push(self.describe(&letter).await?);
+        }
+        Ok(letters)
+    }
+
+    /// Send a letter from the PC. It arrives once game time has covered the
+    /// distance to the addressee.
+    pub async fn send(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        input: LetterInputData,
+    ) -> Result<LetterNotice, MailError> {
+        self.get_pc(world_id, pc_id).await?;
+        let sender = Correspondent::Pc(pc_id);
+        let recipient = self.recipient(world_id, &input.recipient).await?;
+        let in_reply_to = match input.in_reply_to.as_deref() {
+            None | Some("") => None,
+            Some(id) => {
+                let original = self
+                    .get(world_id, parse_id(id, LetterId::from_uuid, "letter")?)
+                    .await?;
+                if original.recipient != sender || !original.is_delivered() {
+                    return Err(MailError::Invalid(
+                        "A PC can only answer letters that reached them".to_string(),
+                    ));
+                }
+                Some(original.id)
+            }
+        };
+
+        let mut letter = self
+            .post(world_id, sender, recipient, input.subject, input.body)
+            .await?;
+        if let Some(original) = in_reply_to {
+            letter = letter.in_reply_to(original);
+        }
+        self.letter.save(&letter).await?;
+        self.notice(&letter).await
+    }
+
+    /// Note that the addressee read a delivered letter
+    pub async fn mark_read(
+        &self,
+        world_id: WorldId,
+        letter_id: LetterId,
+    ) -> Result<LetterNotice, MailError> {
+        let mut letter = self.get(world_id, letter_id).await?;
+        if letter.read_at.is_none() {
+            letter.mark_read(self.clock.now())?;
+            self.letter.save(&letter).await?;
+        }
+        self.notice(&letter).await
+    }
+
+    /// Remove a letter. Returns it as it was, so its readers can be told.
+    pub async fn delete(
+        &self,
+        world_id: WorldId,
+        letter_id: LetterId,
+    ) -> Result<LetterNotice, MailError> {
+        let letter = self.get(world_id, letter_id).await?;
+        self.letter.delete(letter.id).await?;
+        self.notice(&letter).await
+    }
+
+    /// Deliver the letters whose time has come after the world's clock
+    /// moved on. NPCs answer the letters they receive; a reply that cannot
+    /// be written is left for the DM. Returns every letter that changed,
+    /// replies included.
+    pub async fn deliver_due(&self, world_id: WorldId) -> Result<Vec<LetterNotice>, MailError> {
+        let game_now = self.game_now(world_id).await?;
+        let now = self.clock.now();
+        let mut notices = Vec::new();
+        for mut letter in self.letter.list_undelivered_in_world(world_id).await? {
+            if !letter.is_due(game_now) {
+                continue;
+            }
+            letter.deliver(now)?;
+            self.letter.save(&letter).await?;
+            notices.push(self.notice(&letter).await?);
+
+            if letter.recipient.npc_id().is_some() {
+                match self.answer(&letter, None).await {
+                    Ok(reply) => notices.push(reply),
+                    Err(e) => tracing::warn!(
+                        letter_id = %letter.id,
+                        error = %e,
+                        "NPC could not answer a letter"
+                    ),
+                }
+            }
+        }
+        Ok(notices)
+    }
+
+    /// Have the NPC a delivered letter reached write back. The reply is
+    /// generated when no body is given.
+    pub async fn reply_as_npc(
+        &self,
+        world_id: WorldId,
+        letter_id: LetterId,
+        body: Option<String>,
+    ) -> Result<LetterNotice, MailError> {
+        let letter = self.get(world_id, letter_id).await?;
+        if letter.recipient.npc_id().is_none() {
+            return Err(MailError::Invalid(
+                "Only letters addressed to an NPC can be answered for them".to_string(),
+            ));
+        }
+        if !letter.is_delivered() {
+            return Err(MailError::Conflict(
+                "This letter has not arrived yet".to_string(),
+            ));
+        }
+        self.answer(&letter, body.filter(|b| !b.trim().is_empty()))
+            .await
+    }
+
+    /// Write and send the NPC's answer to a letter that reached them
+    async fn answer(
+        &self,
+        letter: &Letter,
+        body: Option<String>,
+    ) -> Result<LetterNotice, MailError> {
+        let body = match body {
+            Some(body) => body,
+            None => self.generate_reply(letter).await?,
+        };
+        let reply = self
+            .post(
+                letter.world_id,
+                letter.recipient,
+                letter.sender,
+                letter.reply_subject(),
+                body,
+            )
+            .await?
+            .in_reply_to(letter.id);
+        self.letter.save(&reply).await?;
+        self.notice(&reply).await
+    }
+
+    /// A new letter in transit, timed by the distance between the two
+    async fn post(
+        &self,
+        world_id: WorldId,
+        sender: Correspondent,
+        recipient: Correspondent,
+        subject: String,
+        body: String,
+    ) -> Result<Letter, MailError> {
+        let game_now = self.game_now(world_id).await?;
+        let from = self.whereabouts(sender).await?;
+        let to = self.whereabouts(recipient).await?;
+        let route_minutes = match (from, to) {
+            (Some(from), Some(to)) => {
+                let locations = self.location.list_in_world(world_id).await?;
+                LocationTree::new(&locations)
+                    .travel(from, to)
+                    .map(|t| t.minutes)
+            }
+            _ => None,
+        };
+        Ok(Letter::new(
+            world_id,
+            sender,
+            recipient,
+            subject,
+            body,
+            game_now,
+            domain::delivery_minutes(route_minutes),
+            self.clock.now(),
+        )?)
+    }
+
+    /// Where a letter to or from someone starts or ends: a PC's current
+    /// location, or an NPC's home, workplace or haunt, in that order
+    async fn whereabouts(&self, who: Correspondent) -> Result<Option<LocationId>, MailError> {
+        match who {
+            Correspondent::Pc(pc_id) => Ok(self
+                .player_character
+                .get(pc_id)
+                .await?
+                .map(|pc| pc.current_location_id)),
+            Correspondent::Npc(npc_id) => {
+                let relationships = self.character.get_region_relationships(npc_id).await?;
+                let region_id = [
+                    NpcRegionRelationType::HomeRegion,
+                    NpcRegionRelationType::WorksAt,
+                    NpcRegionRelationType::Frequents,
+                ]
+                .iter()
+                .find_map(|kind| {
+                    relationships
+                        .iter()
+                        .find(|r| r.relationship_type == *kind)
+                        .map(|r| r.region_id)
+                });
+                match region_id {
+                    Some(region_id) => Ok(self
+                        .location
+                        .get_region(region_id)
+                        .await?
+                        .map(|r| r.location_id)),
+                    None => Ok(None),
+                }
+            }
+        }
+    }
+
+    /// The NPC's answer, in their own voice
+    async fn generate_reply(&self, letter: &Letter) -> Result<String, MailError> {
+        let npc_id = letter
+            .recipient
+            .npc_id()
+            .ok_or_else(|| MailError::Invalid("The letter is not addressed to an NPC".into()))?;
+        let npc = self
+            .character
+            .get(npc_id)
+            .await?
+            .ok_or(MailError::NpcNotFound)?;
+        let writer = self.name_of(letter.sender).await?;
+        let safety_constraints = self
+            .world
+            .get(letter.world_id)
+            .await?
+            .map(|w| w.content_safety.prompt_constraints())
+            .unwrap_or_default();
+        let model = self
+            .settings
+            .get_for_world(letter.world_id)
+            .await
+            .ok()
+            .and_then(|s| s.model_for(LlmTask::NpcDialogue));
+
+        let mut context = format!("A letter from {}", writer);
+        if !letter.subject.is_empty() {
+            context.push_str(&format!(", headed \"{}\"", letter.subject));
+        }
+        context.push_str(&format!(":\n\n{}\n", letter.body));
+        if let Some(pc_id) = letter.sender.pc_id() {
+            if let Some(disposition) = self.character.get_disposition(npc_id, pc_id).await? {
+                context.push_str(&format!(
+                    "\nYou are {} toward {}.\n",
+                    disposition.disposition, writer
+                ));
+            }
+        }
+
+        let system_prompt = format!(
+            "You are {}, a character in a TTRPG world. {}\n\
+            Answer the letter you received, in character and in your own voice. \
+            Write only the body of your reply, a few short paragraphs at most, \
+            and sign it with your name.",
+            npc.name, npc.description
+        );
+        let request = LlmRequest::new(vec![ChatMessage::user(context)])
+            .with_system_prompt(format!("{}\n\n{}", system_prompt, safety_constraints))
+            .with_temperature(0.8)
+            .with_model(model)
+            .with_world(letter.world_id);
+
+        let response = self
+            .llm
+            .generate(request)
+            .await
+            .map_err(|e| MailError::Generation(e.to_string()))?;
+        let body: String = response
+            .content
+            .trim()
+            .chars()
+            .take(MAX_LETTER_BODY_LEN)
+            .collect();
+        if body.trim().is_empty() {
+            return Err(MailError::Generation("the reply was empty".to_string()));
+        }
+        Ok(body)
+    }
+
+    /// The addressee named in a letter the PC is writing
+    async fn recipient(
+        &self,
+        world_id: WorldId,
+        recipient: &CorrespondentData,
+    ) -> Result<Correspondent, MailError> {
+        match recipient.kind {
+            CorrespondentKindData::Pc => {
+                let pc_id = parse_id(&recipient.id, PlayerCharacterId::from_uuid, "PC")?;
+                self.get_pc(world_id, pc_id).await?;
+                Ok(Correspondent::Pc(pc_id))
+            }
+            CorrespondentKindData::Npc => {
+                let npc_id = parse_id(&recipient.id, CharacterId::from_uuid, "NPC")?;
+                self.character
+                    .get(npc_id)
+                    .await?
+                    .filter(|c| c.world_id == world_id)
+                    .ok_or(MailError::NpcNotFound)?;
+                Ok(Correspondent::Npc(npc_id))
+            }
+            CorrespondentKindData::Unknown => {
+                Err(MailError::Invalid("Unknown addressee kind".to_string()))
+            }
+        }
+    }
+
+    async fn game_now(
+        &self,
+        world_id: WorldId,
+    ) -> Result<chrono::DateTime<chrono::Utc>, MailError> {
+        Ok(self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(MailError::WorldNotFound)?
+            .game_time
+            .current())
+    }
+
+    async fn notice(&self, letter: &Letter) -> Result<LetterNotice, MailError> {
+        Ok(LetterNotice {
+            letter: self.describe(letter).await?,
+            pc_readers: letter.pc_readers(),
+        })
+    }
+
+    async fn name_of(&self, who: Correspondent) -> Result<String, MailError> {
+        let name = match who {
+            Correspondent::Pc(id) => self.player_character.get(id).await?.map(|pc| pc.name),
+            Correspondent::Npc(id) => self.character.get(id).await?.map(|c| c.name),
+        };
+        Ok(name.unwrap_or_else(|| "someone".to_string()))
+    }
+
+    /// The letter for the wire, with writer and addressee names
+    async fn describe(&self, letter: &Letter) -> Result<LetterData, MailError> {
+        Ok(LetterData {
+            id: letter.id.to_string(),
+            sender: self.correspondent(letter.sender).await?,
+            recipient: self.correspondent(letter.recipient).await?,
+            subject: letter.subject.clone(),
+            body: letter.body.clone(),
+            in_reply_to: letter.in_reply_to.map(|id| id.to_string()),
+            sent_at: letter.sent_at.to_rfc3339(),
+            deliver_at: letter.deliver_at.to_rfc3339(),
+            delivered: letter.is_delivered(),
+            read: letter.read_at.is_some(),
+        })
+    }
+
+    async fn correspondent(&self, who: Correspondent) -> Result<CorrespondentData, MailError> {
+        let (kind, id) = match who {
+            Correspondent::Pc(id) => (CorrespondentKindData::Pc, id.to_string()),
+            Correspondent::Npc(id) => (CorrespondentKindData::Npc, id.to_string()),
+        };
+        Ok(CorrespondentData {
+            kind,
+            id,
+            name: Some(self.name_of(who).await?),
+        })
+    }
+}
+
+fn parse_id<T>(id: &str, from_uuid: fn(Uuid) -> T, what: &str) -> Result<T, MailError> {
+    Uuid::parse_str(id)
+        .map(from_uuid)
+        .map_err(|_| MailError::Invalid(format!("Invalid {} ID: {}", what, id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        FinishReason, LlmError, LlmResponse, MockCharacterRepo, MockLetterRepo, MockLocationRepo,
+        MockPlayerCharacterRepo, MockSettingsRepo, MockWorldRepo, ToolDefinition,
+    };
+    use async_trait::async_trait;
+    use chrono::{Duration, Utc};
+    use std::sync::Mutex;
+    use wrldbldr_domain::{
+        AppSettings, CampbellArchetype, Character, World, UNROUTED_DELIVERY_MINUTES,
+    };
+
+    /// LLM that always replies with the same text, or always fails
+    struct FixedLlm(Option<String>);
+
+    #[async_trait]
+    impl LlmPort for FixedLlm {
+        async fn generate(&self, _request: LlmRequest) -> Result<LlmResponse, LlmError> {
+            match &self.0 {
+                Some(content) => Ok(LlmResponse {
+                    content: content.clone(),
+                    tool_calls: vec![],
+                    finish_reason: FinishReason::Stop,
+                    usage: None,
+                }),
+                None => Err(LlmError::RequestFailed("offline".to_string())),
+            }
+        }
+
+        async fn generate_with_tools(
+            &self,
+            request: LlmRequest,
+            _tools: Vec<ToolDefinition>,
+        ) -> Result<LlmResponse, LlmError> {
+            self.generate(request).await
+        }
+    }
+
+    /// A PC's letter to an NPC, due an hour ago, and everything needed to
+    /// deliver it. Saved letters end up in the returned list.
+    fn mail_to_npc(llm: FixedLlm) -> (ManageMail, Letter, Arc<Mutex<Vec<Letter>>>) {
+        let world = World::new("Varn", "desc", Utc::now());
+        let game_now = world.game_time.current();
+        let pc = PlayerCharacter::new("user", world.id, "Ash", LocationId::new(), Utc::now());
+        let npc = Character::new(world.id, "Sera", CampbellArchetype::Ally);
+        let letter = Letter::new(
+            world.id,
+            Correspondent::Pc(pc.id),
+            Correspondent::Npc(npc.id),
+            "The ledger",
+            "Did you burn it?",
+            game_now - Duration::hours(2),
+            60,
+            Utc::now(),
+        )
+        .unwrap();
+
+        let mut worlds = MockWorldRepo::new();
+        worlds
+            .expect_get()
+            .returning(move |_| Ok(Some(world.clone())));
+        let mut pcs = MockPlayerCharacterRepo::new();
+        pcs.expect_get().returning(move |_| Ok(Some(pc.clone())));
+        let mut characters = MockCharacterRepo::new();
+        characters
+            .expect_get()
+            .returning(move |_| Ok(Some(npc.clone())));
+        characters
+            .expect_get_region_relationships()
+            .returning(|_| Ok(vec![]));
+        characters
+            .expect_get_disposition()
+            .returning(|_, _| Ok(None));
+        let mut settings = MockSettingsRepo::new();
+        settings
+            .expect_get_for_world()
+            .returning(|_| Ok(Some(AppSettings::default())));
+
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let mut letters = MockLetterRepo::new();
+        let due = letter.clone();
+        letters
+            .expect_list_undelivered_in_world()
+            .returning(move |_| Ok(vec![due.clone()]));
+        let store = saved.clone();
+        letters.expect_save().returning(move |l| {
+            store.lock().unwrap().push(l.clone());
+            Ok(())
+        });
+
+        let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(Utc::now()));
+        let mail = ManageMail::new(
+            Arc::new(entities::Letter::new(Arc::new(letters))),
+            Arc::new(entities::PlayerCharacter::new(Arc::new(pcs))),
+            Arc::new(entities::Character::new(Arc::new(characters))),
+            Arc::new(entities::Location::new(Arc::new(MockLocationRepo::new()))),
+            Arc::new(entities::World::new(Arc::new(worlds), clock.clone())),
+            Arc::new(entities::Settings::new(Arc::new(settings))),
+            Arc::new(llm),
+            clock,
+        );
+        (mail, letter, saved)
+    }
+
+    #[tokio::test]
+    async fn npcs_answer_the_letters_that_reach_them() {
+        let (mail, letter, saved) =
+            mail_to_npc(FixedLlm(Some("Ash it is. Come to the mill. - Sera".into())));
+
+        let notices = mail.deliver_due(letter.world_id).await.expect("delivered");
+
+        assert_eq!(notices.len(), 2);
+        assert!(notices[0].letter.delivered);
+        assert_eq!(notices[0].pc_readers, vec![letter.sender.pc_id().unwrap()]);
+        let saved = saved.lock().unwrap();
+        let reply = &saved[1];
+        assert_eq!(reply.sender, letter.recipient);
+        assert_eq!(reply.recipient, letter.sender);
+        assert_eq!(reply.in_reply_to, Some(letter.id));
+        assert_eq!(reply.subject, "Re: The ledger");
+        // Sera has no known whereabouts, so the reply takes the long way
+        assert_eq!(
+            reply.deliver_at - reply.sent_at,
+            Duration::minutes(i64::from(UNROUTED_DELIVERY_MINUTES))
+        );
+    }
+
+    #[tokio::test]
+    async fn a_failed_reply_still_delivers_the_letter() {
+        let (mail, letter, saved) = mail_to_npc(FixedLlm(None));
+
+        let notices = mail.deliver_due(letter.world_id).await.expect("delivered");
+
+        assert_eq!(notices.len(), 1);
+        let saved = saved.lock().unwrap();
+        assert_eq!(saved.len(), 1);
+        assert!(saved[0].is_delivered());
+    }
+}
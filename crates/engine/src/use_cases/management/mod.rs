@@ -2,15 +2,27 @@
 //!
 //! These use cases keep WebSocket handlers thin while coordinating entity modules.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use uuid::Uuid;
 use wrldbldr_domain::{
-    ActId, CharacterId, InteractionId, LocationId, PlayerCharacterId, RegionId, RelationshipId,
-    SceneId, SkillCategory, SkillId, WorldId,
+    ActId, BackdropFrame, ChallengeId, CharacterId, ContentRating, ContentSafetyConfig, EncounterEntry,
+    EncounterTable, HotspotPoint,
+    HotspotTarget, InteractionId, ItemId, LightLevel, LocationId, LocationScale, LocationTree, PlayerCharacterId,
+    LockRequirements, RegionHotspot, RegionId, RelationshipId, SceneId, SkillCategory, SkillId, SoundCarry, TextDirection,
+    WorldFeatures, WorldId, WorldMapPosition, WorldTheme, WorldTypography,
+};
+use wrldbldr_protocol::types::{
+    BackdropFrameData, ConnectionLockData, ContentRatingData, ContentSafetyData, EncounterEntryData,
+    EncounterTableData, HotspotData, HotspotPointData,
+    HotspotTargetData, LightLevelData, SoundCarryData, TextDirectionData, WorldFeaturesData, WorldMapData, WorldMapPinData,
+    WorldMapPositionData, WorldThemeData, WorldTypographyData,
 };
 
 use crate::entities::{Act, Character, Interaction, Location, Observation, PlayerCharacter, Scene, Skill, World};
 use crate::infrastructure::ports::{ClockPort, RepoError};
+use crate::use_cases::safety::{signal_level_from_protocol, signal_level_to_protocol};
 
 /// Shared error type for management use cases.
 #[derive(Debug, thiserror::Error)]
@@ -73,12 +85,17 @@ impl ManagementUseCases {
 
 pub struct WorldCrud {
     world: Arc<World>,
+    location: Arc<Location>,
     clock: Arc<dyn ClockPort>,
 }
 
 impl WorldCrud {
-    pub fn new(world: Arc<World>, clock: Arc<dyn ClockPort>) -> Self {
-        Self { world, clock }
+    pub fn new(world: Arc<World>, location: Arc<Location>, clock: Arc<dyn ClockPort>) -> Self {
+        Self {
+            world,
+            location,
+            clock,
+        }
     }
 
     pub async fn list(&self) -> Result<Vec<wrldbldr_domain::World>, ManagementError> {
@@ -150,6 +167,313 @@ impl WorldCrud {
         self.world.delete(world_id).await?;
         Ok(())
     }
+
+    pub async fn get_content_safety(
+        &self,
+        world_id: WorldId,
+    ) -> Result<ContentSafetyData, ManagementError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        Ok(content_safety_to_protocol(&world.content_safety))
+    }
+
+    /// Replace a world's lines/veils and content rating.
+    ///
+    /// Returns the normalized settings as stored.
+    pub async fn update_content_safety(
+        &self,
+        world_id: WorldId,
+        config: ContentSafetyData,
+    ) -> Result<ContentSafetyData, ManagementError> {
+        let mut world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        world.set_content_safety(content_safety_from_protocol(config), self.clock.now());
+        self.world.save(&world).await?;
+        Ok(content_safety_to_protocol(&world.content_safety))
+    }
+
+    pub async fn get_typography(
+        &self,
+        world_id: WorldId,
+    ) -> Result<WorldTypographyData, ManagementError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        Ok(typography_to_protocol(&world.typography))
+    }
+
+    /// Replace a world's text direction, language, and font settings.
+    pub async fn update_typography(
+        &self,
+        world_id: WorldId,
+        typography: WorldTypographyData,
+    ) -> Result<WorldTypographyData, ManagementError> {
+        let mut world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        world.set_typography(typography_from_protocol(typography), self.clock.now());
+        self.world.save(&world).await?;
+        Ok(typography_to_protocol(&world.typography))
+    }
+
+    pub async fn get_theme(&self, world_id: WorldId) -> Result<WorldThemeData, ManagementError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        Ok(theme_to_protocol(&world.theme))
+    }
+
+    /// Replace a world's accent color and backdrop frame.
+    pub async fn update_theme(
+        &self,
+        world_id: WorldId,
+        theme: WorldThemeData,
+    ) -> Result<WorldThemeData, ManagementError> {
+        let mut world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        world.set_theme(theme_from_protocol(theme), self.clock.now());
+        self.world.save(&world).await?;
+        Ok(theme_to_protocol(&world.theme))
+    }
+
+    /// The overview map image and the locations pinned to it.
+    pub async fn get_world_map(&self, world_id: WorldId) -> Result<WorldMapData, ManagementError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        let locations = self.location.list_in_world(world_id).await?;
+        Ok(world_map_to_protocol(&world, &locations))
+    }
+
+    /// Replace a world's overview map image.
+    pub async fn set_world_map_asset(
+        &self,
+        world_id: WorldId,
+        map_asset: Option<String>,
+    ) -> Result<WorldMapData, ManagementError> {
+        let mut world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        world.set_map_asset(map_asset, self.clock.now());
+        self.world.save(&world).await?;
+        let locations = self.location.list_in_world(world_id).await?;
+        Ok(world_map_to_protocol(&world, &locations))
+    }
+
+    /// Pin a location to its world's map, or take it off.
+    pub async fn set_location_map_position(
+        &self,
+        location_id: LocationId,
+        position: Option<WorldMapPositionData>,
+    ) -> Result<(WorldId, WorldMapData), ManagementError> {
+        let mut location = self
+            .location
+            .get(location_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        let world = self
+            .world
+            .get(location.world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        if let Some(position) = position {
+            if !position.x.is_finite() || !position.y.is_finite() {
+                return Err(ManagementError::InvalidInput(
+                    "Map position must be a number".to_string(),
+                ));
+            }
+        }
+        location.world_map_position = position.map(|p| WorldMapPosition::new(p.x, p.y));
+        self.location.save_location(&location).await?;
+
+        let locations = self.location.list_in_world(world.id).await?;
+        Ok((world.id, world_map_to_protocol(&world, &locations)))
+    }
+
+    pub async fn get_features(
+        &self,
+        world_id: WorldId,
+    ) -> Result<WorldFeaturesData, ManagementError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        Ok(features_to_protocol(&world.features))
+    }
+
+    /// Replace a world's experimental feature switches.
+    pub async fn update_features(
+        &self,
+        world_id: WorldId,
+        features: WorldFeaturesData,
+    ) -> Result<WorldFeaturesData, ManagementError> {
+        let mut world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        world.set_features(features_from_protocol(features), self.clock.now());
+        self.world.save(&world).await?;
+        Ok(features_to_protocol(&world.features))
+    }
+}
+
+fn content_safety_to_protocol(config: &ContentSafetyConfig) -> ContentSafetyData {
+    ContentSafetyData {
+        content_rating: match config.content_rating {
+            ContentRating::General => ContentRatingData::General,
+            ContentRating::Teen => ContentRatingData::Teen,
+            ContentRating::Mature => ContentRatingData::Mature,
+            ContentRating::Unknown => ContentRatingData::Unknown,
+        },
+        lines: config.lines.clone(),
+        veils: config.veils.clone(),
+        pause_queue_at: config.pause_queue_at.map(signal_level_to_protocol),
+        reject_pending_at: config.reject_pending_at.map(signal_level_to_protocol),
+    }
+}
+
+pub(crate) fn content_safety_from_protocol(data: ContentSafetyData) -> ContentSafetyConfig {
+    ContentSafetyConfig {
+        content_rating: match data.content_rating {
+            ContentRatingData::General => ContentRating::General,
+            ContentRatingData::Teen => ContentRating::Teen,
+            ContentRatingData::Mature => ContentRating::Mature,
+            ContentRatingData::Unknown => ContentRating::Unknown,
+        },
+        lines: data.lines,
+        veils: data.veils,
+        pause_queue_at: data.pause_queue_at.map(signal_level_from_protocol),
+        reject_pending_at: data.reject_pending_at.map(signal_level_from_protocol),
+    }
+}
+
+/// Pins for the locations placed on the world map, by name
+fn world_map_to_protocol(
+    world: &wrldbldr_domain::World,
+    locations: &[wrldbldr_domain::Location],
+) -> WorldMapData {
+    let mut pins: Vec<WorldMapPinData> = locations
+        .iter()
+        .filter_map(|location| {
+            let position = location.world_map_position?;
+            Some(WorldMapPinData {
+                location_id: location.id.to_string(),
+                name: location.name.clone(),
+                parent_location_id: location.parent_location_id.map(|id| id.to_string()),
+                position: WorldMapPositionData {
+                    x: position.x,
+                    y: position.y,
+                },
+            })
+        })
+        .collect();
+    pins.sort_by(|a, b| a.name.cmp(&b.name));
+
+    WorldMapData {
+        map_asset: world.map_asset.clone(),
+        pins,
+    }
+}
+
+fn typography_to_protocol(typography: &WorldTypography) -> WorldTypographyData {
+    WorldTypographyData {
+        direction: match typography.direction {
+            TextDirection::Auto => TextDirectionData::Auto,
+            TextDirection::LeftToRight => TextDirectionData::LeftToRight,
+            TextDirection::RightToLeft => TextDirectionData::RightToLeft,
+            TextDirection::Unknown => TextDirectionData::Unknown,
+        },
+        language: typography.language.clone(),
+        font_family: typography.font_family.clone(),
+        font_stylesheet_url: typography.font_stylesheet_url.clone(),
+    }
+}
+
+fn typography_from_protocol(data: WorldTypographyData) -> WorldTypography {
+    WorldTypography {
+        direction: match data.direction {
+            TextDirectionData::Auto => TextDirection::Auto,
+            TextDirectionData::LeftToRight => TextDirection::LeftToRight,
+            TextDirectionData::RightToLeft => TextDirection::RightToLeft,
+            TextDirectionData::Unknown => TextDirection::Unknown,
+        },
+        language: data.language,
+        font_family: data.font_family,
+        font_stylesheet_url: data.font_stylesheet_url,
+    }
+}
+
+fn theme_to_protocol(theme: &WorldTheme) -> WorldThemeData {
+    WorldThemeData {
+        accent_color: theme.accent_color.clone(),
+        backdrop_frame: match theme.backdrop_frame {
+            BackdropFrame::Plain => BackdropFrameData::Plain,
+            BackdropFrame::Simple => BackdropFrameData::Simple,
+            BackdropFrame::Ornate => BackdropFrameData::Ornate,
+            BackdropFrame::Parchment => BackdropFrameData::Parchment,
+            BackdropFrame::Vignette => BackdropFrameData::Vignette,
+            BackdropFrame::Unknown => BackdropFrameData::Unknown,
+        },
+    }
+}
+
+fn theme_from_protocol(data: WorldThemeData) -> WorldTheme {
+    WorldTheme {
+        accent_color: data.accent_color,
+        backdrop_frame: match data.backdrop_frame {
+            BackdropFrameData::Plain => BackdropFrame::Plain,
+            BackdropFrameData::Simple => BackdropFrame::Simple,
+            BackdropFrameData::Ornate => BackdropFrame::Ornate,
+            BackdropFrameData::Parchment => BackdropFrame::Parchment,
+            BackdropFrameData::Vignette => BackdropFrame::Vignette,
+            BackdropFrameData::Unknown => BackdropFrame::Unknown,
+        },
+    }
+}
+
+fn features_to_protocol(features: &WorldFeatures) -> WorldFeaturesData {
+    WorldFeaturesData {
+        combat_tracker: features.combat_tracker,
+        rumors: features.rumors,
+        weather: features.weather,
+        age_progression: features.age_progression,
+    }
+}
+
+fn features_from_protocol(data: WorldFeaturesData) -> WorldFeatures {
+    WorldFeatures {
+        combat_tracker: data.combat_tracker,
+        rumors: data.rumors,
+        weather: data.weather,
+        age_progression: data.age_progression,
+    }
 }
 
 // =============================================================================
@@ -292,6 +616,78 @@ impl CharacterCrud {
 // Location + Region CRUD
 // =============================================================================
 
+/// Longest a location can take to cross, in game minutes (a year)
+const MAX_CROSSING_MINUTES: u32 = 365 * 24 * 60;
+
+/// A location inside another, with counts of what it contains
+#[derive(Debug, Clone)]
+pub struct SubLocation {
+    pub location: wrldbldr_domain::Location,
+    /// Levels below the listed location (its children are 1)
+    pub depth: u32,
+    pub child_count: u32,
+    pub descendant_count: u32,
+    /// Regions in this location and everything inside it
+    pub region_count: u32,
+}
+
+/// Travel time between two locations and where the journey goes through
+#[derive(Debug, Clone)]
+pub struct LocationTravel {
+    pub minutes: u32,
+    /// The innermost location containing both
+    pub via: Option<wrldbldr_domain::Location>,
+}
+
+/// Refuse to nest a location where the hierarchy can't hold it
+fn check_nesting(
+    tree: &LocationTree<'_>,
+    location_id: LocationId,
+    parent_id: LocationId,
+) -> Result<(), ManagementError> {
+    if tree.get(parent_id).is_none() {
+        return Err(ManagementError::InvalidInput(
+            "Parent location not found in this world".to_string(),
+        ));
+    }
+    match tree.nesting_problem(location_id, parent_id) {
+        Some(problem) => Err(ManagementError::InvalidInput(problem)),
+        None => Ok(()),
+    }
+}
+
+pub fn encounter_table_to_protocol(table: &EncounterTable) -> EncounterTableData {
+    EncounterTableData {
+        check_interval_minutes: table.check_interval_minutes,
+        chance_percent: table.chance_percent,
+        entries: table
+            .entries
+            .iter()
+            .map(|e| EncounterEntryData {
+                name: e.name.clone(),
+                description: e.description.clone(),
+                weight: e.weight,
+            })
+            .collect(),
+    }
+}
+
+fn encounter_table_from_protocol(
+    data: EncounterTableData,
+) -> Result<EncounterTable, ManagementError> {
+    let entries = data
+        .entries
+        .into_iter()
+        .map(|e| EncounterEntry {
+            name: e.name,
+            description: e.description,
+            weight: e.weight,
+        })
+        .collect();
+    EncounterTable::new(data.check_interval_minutes, data.chance_percent, entries)
+        .map_err(|e| ManagementError::InvalidInput(e.to_string()))
+}
+
 pub struct LocationCrud {
     location: Arc<Location>,
 }
@@ -321,6 +717,8 @@ impl LocationCrud {
         name: String,
         description: Option<String>,
         setting: Option<String>,
+        parent_id: Option<LocationId>,
+        scale: Option<LocationScale>,
     ) -> Result<wrldbldr_domain::Location, ManagementError> {
         if name.trim().is_empty() {
             return Err(ManagementError::InvalidInput(
@@ -336,6 +734,14 @@ impl LocationCrud {
         if let Some(setting) = setting {
             location = location.with_atmosphere(setting);
         }
+        if let Some(scale) = scale {
+            location = location.with_scale(scale);
+        }
+        if let Some(parent_id) = parent_id {
+            let locations = self.location.list_in_world(world_id).await?;
+            check_nesting(&LocationTree::new(&locations), location.id, parent_id)?;
+            location = location.with_parent(parent_id);
+        }
 
         self.location.save_location(&location).await?;
         Ok(location)
@@ -347,6 +753,8 @@ impl LocationCrud {
         name: Option<String>,
         description: Option<String>,
         setting: Option<String>,
+        scale: Option<LocationScale>,
+        crossing_minutes: Option<u32>,
     ) -> Result<wrldbldr_domain::Location, ManagementError> {
         let mut location = self
             .location
@@ -368,16 +776,163 @@ impl LocationCrud {
         if let Some(setting) = setting {
             location.atmosphere = Some(setting);
         }
+        if let Some(scale) = scale {
+            location.scale = Some(scale);
+        }
+        if let Some(minutes) = crossing_minutes {
+            if minutes > MAX_CROSSING_MINUTES {
+                return Err(ManagementError::InvalidInput(format!(
+                    "Travel time across a location can be at most {} game minutes",
+                    MAX_CROSSING_MINUTES
+                )));
+            }
+            location.crossing_minutes = Some(minutes);
+        }
+
+        self.location.save_location(&location).await?;
+        Ok(location)
+    }
+
+    /// Move a location inside another, or to the top level
+    pub async fn set_location_parent(
+        &self,
+        location_id: LocationId,
+        parent_id: Option<LocationId>,
+    ) -> Result<wrldbldr_domain::Location, ManagementError> {
+        let mut location = self
+            .location
+            .get(location_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        if let Some(parent_id) = parent_id {
+            let locations = self.location.list_in_world(location.world_id).await?;
+            check_nesting(&LocationTree::new(&locations), location_id, parent_id)?;
+        }
 
+        location.parent_location_id = parent_id;
         self.location.save_location(&location).await?;
         Ok(location)
     }
 
+    /// Delete a location; what it contained moves up to its parent
     pub async fn delete_location(&self, location_id: LocationId) -> Result<(), ManagementError> {
+        if let Some(location) = self.location.get(location_id).await? {
+            let locations = self.location.list_in_world(location.world_id).await?;
+            for child in LocationTree::new(&locations).children(location_id) {
+                let mut child = child.clone();
+                child.parent_location_id = location.parent_location_id;
+                self.location.save_location(&child).await?;
+            }
+        }
         self.location.delete(location_id).await?;
         Ok(())
     }
 
+    /// The locations containing this one, outermost first
+    pub async fn location_breadcrumb(
+        &self,
+        location: &wrldbldr_domain::Location,
+    ) -> Result<Vec<wrldbldr_domain::Location>, ManagementError> {
+        if location.parent_location_id.is_none() {
+            return Ok(Vec::new());
+        }
+        let locations = self.location.list_in_world(location.world_id).await?;
+        Ok(LocationTree::new(&locations)
+            .ancestors(location.id)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
+    /// The locations inside a location, with counts of what each contains
+    pub async fn list_sub_locations(
+        &self,
+        location_id: LocationId,
+        recursive: bool,
+    ) -> Result<Vec<SubLocation>, ManagementError> {
+        let location = self
+            .location
+            .get(location_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        let locations = self.location.list_in_world(location.world_id).await?;
+        let tree = LocationTree::new(&locations);
+
+        // Region counts are needed for the whole subtree to aggregate them
+        let mut regions = HashMap::new();
+        for (inside, _) in tree.descendants(location_id) {
+            let count = self
+                .location
+                .list_regions_in_location(inside.id)
+                .await?
+                .len();
+            regions.insert(inside.id, count as u32);
+        }
+
+        Ok(tree
+            .descendants(location_id)
+            .into_iter()
+            .filter(|(_, depth)| recursive || *depth == 1)
+            .map(|(inside, depth)| {
+                let below = tree.descendants(inside.id);
+                let region_count = regions.get(&inside.id).copied().unwrap_or(0)
+                    + below
+                        .iter()
+                        .map(|(l, _)| regions.get(&l.id).copied().unwrap_or(0))
+                        .sum::<u32>();
+                SubLocation {
+                    location: inside.clone(),
+                    depth,
+                    child_count: tree.children(inside.id).len() as u32,
+                    descendant_count: below.len() as u32,
+                    region_count,
+                }
+            })
+            .collect())
+    }
+
+    /// Travel time between two locations through the hierarchy; None when
+    /// they share no outer location
+    pub async fn travel_time(
+        &self,
+        from: LocationId,
+        to: LocationId,
+    ) -> Result<Option<LocationTravel>, ManagementError> {
+        let origin = self
+            .location
+            .get(from)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        let locations = self.location.list_in_world(origin.world_id).await?;
+        let tree = LocationTree::new(&locations);
+        if tree.get(to).is_none() {
+            return Err(ManagementError::NotFound);
+        }
+
+        Ok(tree.travel(from, to).map(|travel| LocationTravel {
+            minutes: travel.minutes,
+            via: travel.via.and_then(|id| tree.get(id)).cloned(),
+        }))
+    }
+
+    /// Set what travellers crossing a location might run into, or clear it
+    pub async fn set_encounter_table(
+        &self,
+        location_id: LocationId,
+        table: Option<EncounterTableData>,
+    ) -> Result<wrldbldr_domain::Location, ManagementError> {
+        let mut location = self
+            .location
+            .get(location_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        location.encounter_table = table.map(encounter_table_from_protocol).transpose()?;
+        self.location.save_location(&location).await?;
+        Ok(location)
+    }
+
     pub async fn list_regions(
         &self,
         location_id: LocationId,
@@ -449,6 +1004,61 @@ impl LocationCrud {
         Ok(region)
     }
 
+    /// Replace a region's backdrop hotspots
+    pub async fn set_region_hotspots(
+        &self,
+        region_id: RegionId,
+        hotspots: Vec<HotspotData>,
+    ) -> Result<wrldbldr_domain::Region, ManagementError> {
+        if hotspots.len() > MAX_REGION_HOTSPOTS {
+            return Err(ManagementError::InvalidInput(format!(
+                "A region can have at most {} hotspots",
+                MAX_REGION_HOTSPOTS
+            )));
+        }
+
+        let mut region = self
+            .location
+            .get_region(region_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        let hotspots = hotspots
+            .into_iter()
+            .map(hotspot_from_protocol)
+            .collect::<Result<Vec<_>, _>>()?;
+        if hotspots
+            .iter()
+            .any(|h| h.target == HotspotTarget::Exit { region_id })
+        {
+            return Err(ManagementError::InvalidInput(
+                "A hotspot cannot exit to its own region".to_string(),
+            ));
+        }
+
+        region.hotspots = hotspots;
+        self.location.save_region(&region).await?;
+        Ok(region)
+    }
+
+    /// Fix a region's lighting, or let it follow its location again
+    pub async fn set_region_lighting(
+        &self,
+        region_id: RegionId,
+        lighting: Option<LightLevelData>,
+    ) -> Result<wrldbldr_domain::Region, ManagementError> {
+        let lighting = lighting.map(light_level_from_protocol).transpose()?;
+        let mut region = self
+            .location
+            .get_region(region_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        region.lighting = lighting;
+        self.location.save_region(&region).await?;
+        Ok(region)
+    }
+
     pub async fn delete_region(&self, region_id: RegionId) -> Result<(), ManagementError> {
         self.location.delete_region(region_id).await?;
         Ok(())
@@ -489,6 +1099,7 @@ impl LocationCrud {
             travel_time: 0,
             is_locked: false,
             lock_description: None,
+            lock: Default::default(),
         };
 
         self.location.save_location_connection(&connection).await?;
@@ -506,6 +1117,39 @@ impl LocationCrud {
         Ok(())
     }
 
+    /// Lock a location connection behind keys or a challenge, or unlock it
+    /// and forget what opened it when `lock` is `None`.
+    pub async fn set_location_connection_lock(
+        &self,
+        from_location: LocationId,
+        to_location: LocationId,
+        lock: Option<ConnectionLockData>,
+    ) -> Result<(), ManagementError> {
+        let mut connection = self
+            .location
+            .get_location_exits(from_location)
+            .await?
+            .into_iter()
+            .find(|c| c.to_location == to_location)
+            .ok_or(ManagementError::NotFound)?;
+
+        match lock {
+            Some(data) => {
+                let (description, lock) = lock_from_protocol(data)?;
+                connection.is_locked = true;
+                connection.lock_description = description;
+                connection.lock = lock;
+            }
+            None => {
+                connection.unlock();
+                connection.lock = LockRequirements::default();
+            }
+        }
+
+        self.location.save_location_connection(&connection).await?;
+        Ok(())
+    }
+
     pub async fn list_region_connections(
         &self,
         region_id: RegionId,
@@ -563,20 +1207,68 @@ impl LocationCrud {
             .find(|c| c.to_region == to_region)
             .ok_or(ManagementError::NotFound)?;
 
-        let mut updated =
-            wrldbldr_domain::RegionConnection::new(existing.from_region, existing.to_region)
-                .ok_or_else(|| {
-                    ManagementError::InvalidInput("Cannot connect a region to itself".to_string())
-                })?;
-        updated.description = existing.description;
-        updated.bidirectional = existing.bidirectional;
-        updated.is_locked = false;
-        updated.lock_description = None;
+        let mut updated = existing;
+        updated.unlock();
 
         self.location.save_connection(&updated).await?;
         Ok(())
     }
 
+    /// Lock a region connection behind keys or a challenge, or unlock it and
+    /// forget what opened it when `lock` is `None`.
+    pub async fn set_region_connection_lock(
+        &self,
+        from_region: RegionId,
+        to_region: RegionId,
+        lock: Option<ConnectionLockData>,
+    ) -> Result<(), ManagementError> {
+        let mut connection = self
+            .location
+            .get_connections(from_region)
+            .await?
+            .into_iter()
+            .find(|c| c.to_region == to_region)
+            .ok_or(ManagementError::NotFound)?;
+
+        match lock {
+            Some(data) => {
+                let (description, lock) = lock_from_protocol(data)?;
+                connection.is_locked = true;
+                connection.lock_description = description;
+                connection.lock = lock;
+            }
+            None => {
+                connection.unlock();
+                connection.lock = LockRequirements::default();
+            }
+        }
+
+        self.location.save_connection(&connection).await?;
+        Ok(())
+    }
+
+    /// Set how well noise carries along a connection; a two-way
+    /// connection carries it the same in both directions
+    pub async fn set_region_connection_sound(
+        &self,
+        from_region: RegionId,
+        to_region: RegionId,
+        sound: SoundCarryData,
+    ) -> Result<(), ManagementError> {
+        let sound = sound_carry_from_protocol(sound)?;
+        let mut connection = self
+            .location
+            .get_connections(from_region)
+            .await?
+            .into_iter()
+            .find(|c| c.to_region == to_region)
+            .ok_or(ManagementError::NotFound)?;
+
+        connection.sound = sound;
+        self.location.save_connection(&connection).await?;
+        Ok(())
+    }
+
     pub async fn list_region_exits(
         &self,
         region_id: RegionId,
@@ -684,6 +1376,147 @@ impl LocationCrud {
     }
 }
 
+/// Keeps backdrops readable and the region node small
+const MAX_REGION_HOTSPOTS: usize = 32;
+
+/// Keeps the connection relationship small
+const MAX_LOCK_KEYS: usize = 16;
+
+fn lock_from_protocol(
+    data: ConnectionLockData,
+) -> Result<(Option<String>, LockRequirements), ManagementError> {
+    if data.key_item_ids.len() > MAX_LOCK_KEYS {
+        return Err(ManagementError::InvalidInput(format!(
+            "A lock can have at most {} keys",
+            MAX_LOCK_KEYS
+        )));
+    }
+    let key_item_ids = data
+        .key_item_ids
+        .iter()
+        .map(|id| parse_hotspot_id(id, "key_item_id").map(ItemId::from))
+        .collect::<Result<Vec<_>, _>>()?;
+    let challenge_id = match data.challenge_id {
+        Some(id) => Some(ChallengeId::from(parse_hotspot_id(&id, "challenge_id")?)),
+        None => None,
+    };
+    let description = data
+        .description
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty());
+    Ok((
+        description,
+        LockRequirements {
+            key_item_ids,
+            challenge_id,
+        },
+    ))
+}
+
+fn parse_hotspot_id(id: &str, field: &str) -> Result<Uuid, ManagementError> {
+    Uuid::parse_str(id).map_err(|_| ManagementError::InvalidInput(format!("Invalid {}", field)))
+}
+
+fn hotspot_from_protocol(data: HotspotData) -> Result<RegionHotspot, ManagementError> {
+    let target = match data.target {
+        HotspotTargetData::Interaction { interaction_id } => HotspotTarget::Interaction {
+            interaction_id: parse_hotspot_id(&interaction_id, "interaction_id")?.into(),
+        },
+        HotspotTargetData::Item { item_id } => HotspotTarget::Item {
+            item_id: parse_hotspot_id(&item_id, "item_id")?.into(),
+        },
+        HotspotTargetData::Exit { region_id } => HotspotTarget::Exit {
+            region_id: parse_hotspot_id(&region_id, "region_id")?.into(),
+        },
+        HotspotTargetData::LocationExit {
+            location_id,
+            arrival_region_id,
+        } => HotspotTarget::LocationExit {
+            location_id: parse_hotspot_id(&location_id, "location_id")?.into(),
+            arrival_region_id: match arrival_region_id {
+                Some(id) => Some(parse_hotspot_id(&id, "arrival_region_id")?.into()),
+                None => None,
+            },
+        },
+    };
+    let points = data
+        .points
+        .into_iter()
+        .map(|p| HotspotPoint::new(p.x, p.y))
+        .collect();
+
+    RegionHotspot::new(data.label, points, target).ok_or_else(|| {
+        ManagementError::InvalidInput("Hotspots need a label and at least three points".to_string())
+    })
+}
+
+pub fn hotspot_to_protocol(hotspot: &RegionHotspot) -> HotspotData {
+    HotspotData {
+        label: hotspot.label.clone(),
+        points: hotspot
+            .points
+            .iter()
+            .map(|p| HotspotPointData { x: p.x, y: p.y })
+            .collect(),
+        target: match hotspot.target {
+            HotspotTarget::Interaction { interaction_id } => HotspotTargetData::Interaction {
+                interaction_id: interaction_id.to_string(),
+            },
+            HotspotTarget::Item { item_id } => HotspotTargetData::Item {
+                item_id: item_id.to_string(),
+            },
+            HotspotTarget::Exit { region_id } => HotspotTargetData::Exit {
+                region_id: region_id.to_string(),
+            },
+            HotspotTarget::LocationExit {
+                location_id,
+                arrival_region_id,
+            } => HotspotTargetData::LocationExit {
+                location_id: location_id.to_string(),
+                arrival_region_id: arrival_region_id.map(|id| id.to_string()),
+            },
+        },
+    }
+}
+
+fn light_level_from_protocol(data: LightLevelData) -> Result<LightLevel, ManagementError> {
+    match data {
+        LightLevelData::Bright => Ok(LightLevel::Bright),
+        LightLevelData::Dim => Ok(LightLevel::Dim),
+        LightLevelData::Dark => Ok(LightLevel::Dark),
+        LightLevelData::Unknown => Err(ManagementError::InvalidInput(
+            "Unknown light level".to_string(),
+        )),
+    }
+}
+
+pub fn light_level_to_protocol(level: LightLevel) -> LightLevelData {
+    match level {
+        LightLevel::Bright => LightLevelData::Bright,
+        LightLevel::Dim => LightLevelData::Dim,
+        LightLevel::Dark => LightLevelData::Dark,
+    }
+}
+
+fn sound_carry_from_protocol(data: SoundCarryData) -> Result<SoundCarry, ManagementError> {
+    match data {
+        SoundCarryData::Open => Ok(SoundCarry::Open),
+        SoundCarryData::Muffled => Ok(SoundCarry::Muffled),
+        SoundCarryData::Blocked => Ok(SoundCarry::Blocked),
+        SoundCarryData::Unknown => Err(ManagementError::InvalidInput(
+            "Unknown sound carry".to_string(),
+        )),
+    }
+}
+
+pub fn sound_carry_to_protocol(sound: SoundCarry) -> SoundCarryData {
+    match sound {
+        SoundCarry::Open => SoundCarryData::Open,
+        SoundCarry::Muffled => SoundCarryData::Muffled,
+        SoundCarry::Blocked => SoundCarryData::Blocked,
+    }
+}
+
 // =============================================================================
 // Player Character CRUD
 // =============================================================================
@@ -1092,6 +1925,98 @@ impl ObservationCrud {
         Ok(())
     }
 
+    /// Locations the PC knows, by name: everywhere they have visited or
+    /// heard about, plus where they are now and what that sits inside.
+    pub async fn list_known_locations(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<KnownLocationData>, ManagementError> {
+        let pc = self
+            .player_character
+            .get(pc_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        let locations = self.location.list_in_world(pc.world_id).await?;
+        let tree = LocationTree::new(&locations);
+
+        let mut known: HashMap<LocationId, KnownLocationData> = HashMap::new();
+        for observation in self.observation.get_known_locations(pc_id).await? {
+            if let Some(location) = tree.get(observation.location_id) {
+                known.insert(
+                    location.id,
+                    known_location_data(location, Some(&observation)),
+                );
+            }
+        }
+        let mut around = vec![pc.current_location_id];
+        around.extend(tree.ancestors(pc.current_location_id).iter().map(|l| l.id));
+        for location_id in around {
+            if let Some(location) = tree.get(location_id) {
+                known
+                    .entry(location_id)
+                    .or_insert_with(|| known_location_data(location, None));
+            }
+        }
+
+        let mut known: Vec<_> = known.into_values().collect();
+        known.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(known)
+    }
+
+    /// Tell a PC about a location (DM). Places they have visited stay visited.
+    pub async fn reveal_location(
+        &self,
+        pc_id: PlayerCharacterId,
+        location_id: LocationId,
+        notes: Option<String>,
+    ) -> Result<(), ManagementError> {
+        let pc = self
+            .player_character
+            .get(pc_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        let location = self
+            .location
+            .get(location_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+        if location.world_id != pc.world_id {
+            return Err(ManagementError::InvalidInput(
+                "Location is in a different world".to_string(),
+            ));
+        }
+        let world = self
+            .world
+            .get(pc.world_id)
+            .await?
+            .ok_or(ManagementError::NotFound)?;
+
+        let notes = notes
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty());
+        let observation = wrldbldr_domain::LocationObservation::heard_about(
+            pc_id,
+            location_id,
+            world.game_time.current(),
+            notes,
+            self.clock.now(),
+        );
+        self.observation
+            .save_location_observation(&observation)
+            .await?;
+        Ok(())
+    }
+
+    /// Make a PC forget a location (DM)
+    pub async fn forget_location(
+        &self,
+        pc_id: PlayerCharacterId,
+        location_id: LocationId,
+    ) -> Result<(), ManagementError> {
+        self.observation.forget_location(pc_id, location_id).await?;
+        Ok(())
+    }
+
     async fn resolve_observation_location(
         &self,
         location_id: Option<LocationId>,
@@ -1262,6 +2187,36 @@ impl SceneCrud {
 // Interaction CRUD
 // =============================================================================
 
+/// Per-PC limits on an interaction; `None` leaves a limit as it is.
+#[derive(Debug, Clone, Default)]
+pub struct InteractionLimits {
+    /// Game minutes between uses; 0 removes the cooldown
+    pub cooldown_minutes: Option<u32>,
+    /// Uses per PC; 0 removes the limit
+    pub max_uses: Option<u32>,
+    /// Times of day it is offered; empty means any time
+    pub available_times: Option<Vec<wrldbldr_domain::TimeOfDay>>,
+}
+
+impl InteractionLimits {
+    fn apply(self, interaction: &mut wrldbldr_domain::InteractionTemplate) {
+        if let Some(minutes) = self.cooldown_minutes {
+            interaction.cooldown_minutes = (minutes > 0).then_some(minutes);
+        }
+        if let Some(uses) = self.max_uses {
+            interaction.max_uses = (uses > 0).then_some(uses);
+        }
+        if let Some(times) = self.available_times {
+            interaction.available_times.clear();
+            for time in times {
+                if !interaction.available_times.contains(&time) {
+                    interaction.available_times.push(time);
+                }
+            }
+        }
+    }
+}
+
 pub struct InteractionCrud {
     interaction: Arc<Interaction>,
 }
@@ -1292,6 +2247,7 @@ impl InteractionCrud {
         description: Option<String>,
         trigger: Option<String>,
         available: Option<bool>,
+        limits: InteractionLimits,
     ) -> Result<wrldbldr_domain::InteractionTemplate, ManagementError> {
         if name.trim().is_empty() {
             return Err(ManagementError::InvalidInput(
@@ -1320,6 +2276,7 @@ impl InteractionCrud {
         if available == Some(false) {
             interaction = interaction.disabled();
         }
+        limits.apply(&mut interaction);
 
         self.interaction.save(&interaction).await?;
         Ok(interaction)
@@ -1332,6 +2289,7 @@ impl InteractionCrud {
         description: Option<String>,
         trigger: Option<String>,
         available: Option<bool>,
+        limits: InteractionLimits,
     ) -> Result<wrldbldr_domain::InteractionTemplate, ManagementError> {
         let mut interaction = self
             .interaction
@@ -1361,6 +2319,7 @@ impl InteractionCrud {
         if let Some(available) = available {
             interaction.is_available = available;
         }
+        limits.apply(&mut interaction);
 
         self.interaction.save(&interaction).await?;
         Ok(interaction)
@@ -1484,6 +2443,38 @@ impl SkillCrud {
     }
 }
 
+/// A location a PC knows, for UI consumption.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct KnownLocationData {
+    pub location_id: String,
+    pub name: String,
+    pub parent_location_id: Option<String>,
+    /// "direct" when visited, otherwise how they learned of it
+    pub observation_type: String,
+    pub observation_type_icon: String,
+    /// When they learned of it; absent for where the PC is now
+    pub game_time: Option<String>,
+    pub notes: Option<String>,
+}
+
+fn known_location_data(
+    location: &wrldbldr_domain::Location,
+    observation: Option<&wrldbldr_domain::LocationObservation>,
+) -> KnownLocationData {
+    let observation_type = observation
+        .map(|o| o.observation_type)
+        .unwrap_or(wrldbldr_domain::ObservationType::Direct);
+    KnownLocationData {
+        location_id: location.id.to_string(),
+        name: location.name.clone(),
+        parent_location_id: location.parent_location_id.map(|id| id.to_string()),
+        observation_type: observation_type.as_key().to_string(),
+        observation_type_icon: observation_type.icon().to_string(),
+        game_time: observation.map(|o| o.game_time.to_rfc3339()),
+        notes: observation.and_then(|o| o.notes.clone()),
+    }
+}
+
 /// Summary of an NPC observation for UI consumption.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ObservationSummaryData {
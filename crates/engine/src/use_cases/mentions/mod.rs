@@ -0,0 +1,830 @@
+//! Entity mention use cases.
+//!
+//! The names and DM-given aliases of characters, locations, regions and
+//! items are looked for in the world's text: their descriptions, lore
+//! entries and recorded dialogue. What's found is stored per source, so
+//! "where is this NPC referenced?" is a lookup. A source is indexed again
+//! whenever it's saved; changing aliases, or asking for it, indexes the
+//! whole world. The player links the same names in the text it shows,
+//! matching against the targets listed here.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    find_mentions, mentions_in, normalize_aliases, AliasReferenceContext, CharacterId,
+    EntityMention, EntityType, ItemId, LocationId, Lore, LoreId, MentionTarget, Region, RegionId,
+    StoryEvent, StoryEventId, StoryEventType, WorldId, MENTION_TARGET_TYPES,
+};
+use wrldbldr_protocol::types::{EntityMentionData, MentionTargetData};
+
+use crate::entities;
+use crate::infrastructure::ports::RepoError;
+
+/// Most recent story events a full reindex reads for dialogue
+const DIALOGUE_EVENTS_SCANNED: usize = 500;
+
+/// Longest hover-card summary, in characters
+const SUMMARY_LEN: usize = 200;
+
+/// Container for entity mention use cases.
+pub struct MentionUseCases {
+    pub manage: Arc<ManageMentions>,
+}
+
+impl MentionUseCases {
+    pub fn new(manage: Arc<ManageMentions>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MentionError {
+    #[error("Entity not found")]
+    NotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+/// A mentionable entity with the start of its description
+struct Target {
+    target: MentionTarget,
+    summary: String,
+}
+
+/// Find, store and look up entity mentions.
+pub struct ManageMentions {
+    mentions: Arc<entities::Mention>,
+    character: Arc<entities::Character>,
+    location: Arc<entities::Location>,
+    inventory: Arc<entities::Inventory>,
+    lore: Arc<entities::Lore>,
+    narrative: Arc<entities::Narrative>,
+}
+
+impl ManageMentions {
+    pub fn new(
+        mentions: Arc<entities::Mention>,
+        character: Arc<entities::Character>,
+        location: Arc<entities::Location>,
+        inventory: Arc<entities::Inventory>,
+        lore: Arc<entities::Lore>,
+        narrative: Arc<entities::Narrative>,
+    ) -> Self {
+        Self {
+            mentions,
+            character,
+            location,
+            inventory,
+            lore,
+            narrative,
+        }
+    }
+
+    /// Every entity the world's text can mention, with its hover-card text
+    pub async fn list_targets(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<MentionTargetData>, MentionError> {
+        Ok(self
+            .targets(world_id)
+            .await?
+            .into_iter()
+            .map(|t| MentionTargetData {
+                entity_type: t.target.entity_type,
+                entity_id: t.target.entity_id.to_string(),
+                name: t.target.name,
+                aliases: t.target.aliases,
+                summary: t.summary,
+            })
+            .collect())
+    }
+
+    /// Replace an entity's aliases and index the world again with them,
+    /// returning the aliases as stored.
+    pub async fn set_aliases(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        aliases: Vec<String>,
+    ) -> Result<Vec<String>, MentionError> {
+        if !MENTION_TARGET_TYPES.contains(&entity_type) {
+            return Err(MentionError::Invalid(format!(
+                "{} entities can't have aliases",
+                entity_type
+            )));
+        }
+        let aliases =
+            normalize_aliases(&aliases).map_err(|e| MentionError::Invalid(e.to_string()))?;
+        let exists = self
+            .targets(world_id)
+            .await?
+            .iter()
+            .any(|t| t.target.entity_type == entity_type && t.target.entity_id == entity_id);
+        if !exists {
+            return Err(MentionError::NotFound);
+        }
+
+        self.mentions
+            .set_aliases(world_id, entity_type, entity_id, &aliases)
+            .await?;
+        self.reindex_world(world_id).await?;
+        Ok(aliases)
+    }
+
+    /// An entity's aliases
+    pub async fn aliases_of(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<String>, MentionError> {
+        self.targets(world_id)
+            .await?
+            .into_iter()
+            .find(|t| t.target.entity_type == entity_type && t.target.entity_id == entity_id)
+            .map(|t| t.target.aliases)
+            .ok_or(MentionError::NotFound)
+    }
+
+    /// Add one alias, returning the entity's aliases as stored
+    pub async fn add_alias(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        alias: String,
+    ) -> Result<Vec<String>, MentionError> {
+        let mut aliases = self.aliases_of(world_id, entity_type, entity_id).await?;
+        aliases.push(alias);
+        self.set_aliases(world_id, entity_type, entity_id, aliases)
+            .await
+    }
+
+    /// Remove one alias, ignoring case, returning the entity's aliases as
+    /// stored
+    pub async fn remove_alias(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        alias: &str,
+    ) -> Result<Vec<String>, MentionError> {
+        let mut aliases = self.aliases_of(world_id, entity_type, entity_id).await?;
+        let before = aliases.len();
+        aliases.retain(|a| !a.eq_ignore_ascii_case(alias.trim()));
+        if aliases.len() == before {
+            return Err(MentionError::NotFound);
+        }
+        self.set_aliases(world_id, entity_type, entity_id, aliases)
+            .await
+    }
+
+    /// Every entity's aliases in a world, for matching names in searches
+    /// and prompts
+    pub async fn aliases(
+        &self,
+        world_id: WorldId,
+    ) -> Result<HashMap<(EntityType, Uuid), Vec<String>>, MentionError> {
+        Ok(self.mentions.list_aliases(world_id).await?)
+    }
+
+    /// The aliases used in `text`, each with the entity it stands for, so a
+    /// prompt can say that "the Grey Fox" is Vexis
+    pub async fn alias_references(
+        &self,
+        world_id: WorldId,
+        text: &str,
+    ) -> Result<Vec<AliasReferenceContext>, MentionError> {
+        let targets: Vec<MentionTarget> = self
+            .targets(world_id)
+            .await?
+            .into_iter()
+            .map(|t| t.target)
+            .filter(|t| !t.aliases.is_empty())
+            .collect();
+
+        let mut references: Vec<AliasReferenceContext> = Vec::new();
+        for span in find_mentions(text, &targets) {
+            let target = &targets[span.target];
+            let alias = &text[span.start..span.end];
+            if alias.eq_ignore_ascii_case(&target.name)
+                || references
+                    .iter()
+                    .any(|r| r.alias.eq_ignore_ascii_case(alias))
+            {
+                continue;
+            }
+            references.push(AliasReferenceContext {
+                alias: alias.to_string(),
+                name: target.name.clone(),
+                entity_type: target.entity_type.as_str().to_string(),
+            });
+        }
+        Ok(references)
+    }
+
+    /// Index one source's text again after it changed; returns how many
+    /// entities it mentions. Sources without text, or from another world,
+    /// are left alone.
+    pub async fn index_source(
+        &self,
+        world_id: WorldId,
+        source_type: EntityType,
+        source_id: Uuid,
+    ) -> Result<usize, MentionError> {
+        let Some((name, text)) = self.source_text(world_id, source_type, source_id).await? else {
+            return Ok(0);
+        };
+        let targets: Vec<MentionTarget> = self
+            .targets(world_id)
+            .await?
+            .into_iter()
+            .map(|t| t.target)
+            .collect();
+
+        let found = mentions_in(world_id, (source_type, source_id), &name, &text, &targets);
+        self.mentions
+            .replace_mentions(world_id, source_type, source_id, &found)
+            .await?;
+        Ok(found.len())
+    }
+
+    /// Drop a world's mentions and find them all again; returns how many
+    /// were found.
+    pub async fn reindex_world(&self, world_id: WorldId) -> Result<u32, MentionError> {
+        let targets: Vec<MentionTarget> = self
+            .targets(world_id)
+            .await?
+            .into_iter()
+            .map(|t| t.target)
+            .collect();
+
+        let mut sources: Vec<(EntityType, Uuid, String, String)> = Vec::new();
+        for character in self.character.list_in_world(world_id).await? {
+            sources.push((
+                EntityType::Character,
+                character.id.to_uuid(),
+                character.name,
+                character.description,
+            ));
+        }
+        for location in self.location.list_in_world(world_id).await? {
+            for region in self.location.list_regions_in_location(location.id).await? {
+                let text = region_text(&region);
+                sources.push((EntityType::Region, region.id.to_uuid(), region.name, text));
+            }
+            sources.push((
+                EntityType::Location,
+                location.id.to_uuid(),
+                location.name,
+                location.description,
+            ));
+        }
+        for item in self.inventory.list_in_world(world_id).await? {
+            sources.push((
+                EntityType::Item,
+                item.id.to_uuid(),
+                item.name,
+                item.description.unwrap_or_default(),
+            ));
+        }
+        for lore in self.lore.list_for_world(world_id).await? {
+            let text = lore_text(&lore);
+            sources.push((EntityType::Lore, lore.id.to_uuid(), lore.title, text));
+        }
+        for event in self
+            .narrative
+            .list_story_events(world_id, DIALOGUE_EVENTS_SCANNED)
+            .await?
+        {
+            if let Some((name, text)) = dialogue_text(&event) {
+                sources.push((EntityType::StoryEvent, event.id.to_uuid(), name, text));
+            }
+        }
+
+        self.mentions.clear_mentions(world_id).await?;
+        let mut total = 0;
+        for (source_type, source_id, name, text) in sources {
+            let found = mentions_in(world_id, (source_type, source_id), &name, &text, &targets);
+            if found.is_empty() {
+                continue;
+            }
+            self.mentions
+                .replace_mentions(world_id, source_type, source_id, &found)
+                .await?;
+            total += found.len() as u32;
+        }
+        Ok(total)
+    }
+
+    /// Forget a deleted entity's aliases and mentions
+    pub async fn forget(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<(), MentionError> {
+        Ok(self.mentions.forget_entity(entity_type, entity_id).await?)
+    }
+
+    /// Where an entity is referenced, by source name
+    pub async fn mentions_of(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<EntityMentionData>, MentionError> {
+        let names = self.target_names(world_id).await?;
+        let target_name = names
+            .get(&(entity_type, entity_id))
+            .cloned()
+            .ok_or(MentionError::NotFound)?;
+        Ok(self
+            .mentions
+            .list_mentions_of(entity_type, entity_id)
+            .await?
+            .into_iter()
+            .filter(|m| m.world_id == world_id)
+            .map(|m| mention_to_protocol(m, target_name.clone()))
+            .collect())
+    }
+
+    /// What an entity's text references, leaving out entities deleted since
+    pub async fn mentions_in(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<EntityMentionData>, MentionError> {
+        let names = self.target_names(world_id).await?;
+        Ok(self
+            .mentions
+            .list_mentions_in(entity_type, entity_id)
+            .await?
+            .into_iter()
+            .filter(|m| m.world_id == world_id)
+            .filter_map(|m| {
+                let name = names.get(&(m.target_type, m.target_id))?.clone();
+                Some(mention_to_protocol(m, name))
+            })
+            .collect())
+    }
+
+    async fn target_names(
+        &self,
+        world_id: WorldId,
+    ) -> Result<HashMap<(EntityType, Uuid), String>, MentionError> {
+        Ok(self
+            .targets(world_id)
+            .await?
+            .into_iter()
+            .map(|t| ((t.target.entity_type, t.target.entity_id), t.target.name))
+            .collect())
+    }
+
+    async fn targets(&self, world_id: WorldId) -> Result<Vec<Target>, MentionError> {
+        let mut aliases = self.mentions.list_aliases(world_id).await?;
+        let mut targets = Vec::new();
+        let mut push =
+            |entity_type: EntityType, entity_id: Uuid, name: String, description: &str| {
+                let target_aliases = aliases
+                    .remove(&(entity_type, entity_id))
+                    .unwrap_or_default();
+                targets.push(Target {
+                    target: MentionTarget::new(entity_type, entity_id, name)
+                        .with_aliases(target_aliases),
+                    summary: summary_of(description),
+                });
+            };
+
+        for character in self.character.list_in_world(world_id).await? {
+            push(
+                EntityType::Character,
+                character.id.to_uuid(),
+                character.name,
+                &character.description,
+            );
+        }
+        for location in self.location.list_in_world(world_id).await? {
+            for region in self.location.list_regions_in_location(location.id).await? {
+                push(
+                    EntityType::Region,
+                    region.id.to_uuid(),
+                    region.name,
+                    &region.description,
+                );
+            }
+            push(
+                EntityType::Location,
+                location.id.to_uuid(),
+                location.name,
+                &location.description,
+            );
+        }
+        for item in self.inventory.list_in_world(world_id).await? {
+            push(
+                EntityType::Item,
+                item.id.to_uuid(),
+                item.name,
+                item.description.as_deref().unwrap_or_default(),
+            );
+        }
+        Ok(targets)
+    }
+
+    /// A source's name and the text to search, if it has any in this world
+    async fn source_text(
+        &self,
+        world_id: WorldId,
+        source_type: EntityType,
+        source_id: Uuid,
+    ) -> Result<Option<(String, String)>, MentionError> {
+        let text = match source_type {
+            EntityType::Character => self
+                .character
+                .get(CharacterId::from_uuid(source_id))
+                .await?
+                .filter(|c| c.world_id == world_id)
+                .map(|c| (c.name, c.description)),
+            EntityType::Location => self
+                .location
+                .get(LocationId::from_uuid(source_id))
+                .await?
+                .filter(|l| l.world_id == world_id)
+                .map(|l| (l.name, l.description)),
+            EntityType::Region => {
+                let Some(region) = self
+                    .location
+                    .get_region(RegionId::from_uuid(source_id))
+                    .await?
+                else {
+                    return Ok(None);
+                };
+                let in_world = self
+                    .location
+                    .get(region.location_id)
+                    .await?
+                    .is_some_and(|l| l.world_id == world_id);
+                in_world.then(|| {
+                    let text = region_text(&region);
+                    (region.name, text)
+                })
+            }
+            EntityType::Item => self
+                .inventory
+                .get(ItemId::from_uuid(source_id))
+                .await?
+                .filter(|i| i.world_id == world_id)
+                .map(|i| (i.name, i.description.unwrap_or_default())),
+            EntityType::Lore => self
+                .lore
+                .get(LoreId::from_uuid(source_id))
+                .await?
+                .filter(|l| l.world_id == world_id)
+                .map(|l| {
+                    let text = lore_text(&l);
+                    (l.title, text)
+                }),
+            EntityType::StoryEvent => self
+                .narrative
+                .get_story_event(StoryEventId::from_uuid(source_id))
+                .await?
+                .filter(|e| e.world_id == world_id)
+                .and_then(|e| dialogue_text(&e)),
+            _ => None,
+        };
+        Ok(text)
+    }
+}
+
+fn region_text(region: &Region) -> String {
+    match &region.atmosphere {
+        Some(atmosphere) => format!("{}\n{}", region.description, atmosphere),
+        None => region.description.clone(),
+    }
+}
+
+fn lore_text(lore: &Lore) -> String {
+    let mut text = lore.summary.clone();
+    for chunk in &lore.chunks {
+        if let Some(title) = &chunk.title {
+            text.push('\n');
+            text.push_str(title);
+        }
+        text.push('\n');
+        text.push_str(&chunk.content);
+    }
+    text
+}
+
+/// What was said in a dialogue exchange; other story events aren't indexed
+fn dialogue_text(event: &StoryEvent) -> Option<(String, String)> {
+    match &event.event_type {
+        StoryEventType::DialogueExchange {
+            npc_name,
+            player_dialogue,
+            npc_response,
+            ..
+        } => Some((
+            format!("Dialogue with {}", npc_name),
+            format!("{}\n{}", player_dialogue, npc_response),
+        )),
+        _ => None,
+    }
+}
+
+/// The start of a description, cut at a word for the hover-card
+fn summary_of(description: &str) -> String {
+    let description = description.trim();
+    if description.chars().count() <= SUMMARY_LEN {
+        return description.to_string();
+    }
+    let cut: String = description.chars().take(SUMMARY_LEN).collect();
+    let cut = cut
+        .rfind(char::is_whitespace)
+        .map_or(cut.as_str(), |i| &cut[..i]);
+    format!("{}…", cut.trim_end())
+}
+
+fn mention_to_protocol(mention: EntityMention, target_name: String) -> EntityMentionData {
+    EntityMentionData {
+        source_type: mention.source_type,
+        source_id: mention.source_id.to_string(),
+        source_name: mention.source_name,
+        target_type: mention.target_type,
+        target_id: mention.target_id.to_string(),
+        target_name,
+        matched_text: mention.matched_text,
+        excerpt: mention.excerpt,
+        count: mention.count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        MockChallengeRepo, MockCharacterRepo, MockFlagRepo, MockItemRepo, MockLocationRepo,
+        MockLoreRepo, MockMentionRepo, MockNarrativeRepo, MockObservationRepo,
+        MockPlayerCharacterRepo, MockSceneRepo, MockWorldRepo,
+    };
+    use wrldbldr_domain::{CampbellArchetype, Character, LoreCategory, LoreChunk};
+
+    struct Repos {
+        mentions: MockMentionRepo,
+        characters: MockCharacterRepo,
+        lore: MockLoreRepo,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            let mut mentions = MockMentionRepo::new();
+            mentions
+                .expect_list_aliases()
+                .returning(|_| Ok(HashMap::new()));
+            Self {
+                mentions,
+                characters: MockCharacterRepo::new(),
+                lore: MockLoreRepo::new(),
+            }
+        }
+
+        fn build(self) -> ManageMentions {
+            let mut locations = MockLocationRepo::new();
+            locations
+                .expect_list_locations_in_world()
+                .returning(|_| Ok(vec![]));
+            let mut items = MockItemRepo::new();
+            items.expect_list_in_world().returning(|_| Ok(vec![]));
+            let mut narrative = MockNarrativeRepo::new();
+            narrative
+                .expect_list_story_events()
+                .returning(|_, _| Ok(vec![]));
+            let characters = Arc::new(self.characters);
+            ManageMentions::new(
+                Arc::new(entities::Mention::new(Arc::new(self.mentions))),
+                Arc::new(entities::Character::new(characters.clone())),
+                Arc::new(entities::Location::new(Arc::new(locations))),
+                Arc::new(entities::Inventory::new(
+                    Arc::new(items),
+                    characters,
+                    Arc::new(MockPlayerCharacterRepo::new()),
+                )),
+                Arc::new(entities::Lore::new(Arc::new(self.lore))),
+                Arc::new(entities::Narrative::new(
+                    Arc::new(narrative),
+                    Arc::new(MockLocationRepo::new()),
+                    Arc::new(MockWorldRepo::new()),
+                    Arc::new(MockPlayerCharacterRepo::new()),
+                    Arc::new(MockCharacterRepo::new()),
+                    Arc::new(MockObservationRepo::new()),
+                    Arc::new(MockChallengeRepo::new()),
+                    Arc::new(MockFlagRepo::new()),
+                    Arc::new(MockSceneRepo::new()),
+                    Arc::new(FixedClock(chrono::Utc::now())),
+                )),
+            )
+        }
+    }
+
+    fn npc(world_id: WorldId, name: &str, description: &str) -> Character {
+        Character::new(world_id, name, CampbellArchetype::Mentor).with_description(description)
+    }
+
+    #[tokio::test]
+    async fn saving_a_source_stores_the_entities_it_mentions() {
+        let world_id = WorldId::new();
+        let mira = npc(world_id, "Mira Vance", "A harbor fixer.");
+        let mira_id = mira.id.to_uuid();
+        let mut lore = Lore::new(
+            world_id,
+            "The Drowned Ledger",
+            LoreCategory::Historical,
+            chrono::Utc::now(),
+        );
+        lore.chunks.push(LoreChunk::new(
+            "Only mira vance knows where the ledger sank.",
+        ));
+        let lore_id = lore.id;
+
+        let mut repos = Repos::new();
+        repos
+            .characters
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![mira.clone()]));
+        repos
+            .lore
+            .expect_get()
+            .returning(move |_| Ok(Some(lore.clone())));
+        repos
+            .mentions
+            .expect_replace_mentions()
+            .withf(move |_, source_type, source_id, mentions| {
+                *source_type == EntityType::Lore
+                    && *source_id == lore_id.to_uuid()
+                    && mentions.len() == 1
+                    && mentions[0].target_id == mira_id
+                    && mentions[0].source_name == "The Drowned Ledger"
+                    && mentions[0].matched_text == "mira vance"
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let found = repos
+            .build()
+            .index_source(world_id, EntityType::Lore, lore_id.to_uuid())
+            .await
+            .expect("indexed");
+
+        assert_eq!(found, 1);
+    }
+
+    #[tokio::test]
+    async fn sources_from_other_worlds_are_not_indexed() {
+        let lore = Lore::new(
+            WorldId::new(),
+            "Elsewhere",
+            LoreCategory::Historical,
+            chrono::Utc::now(),
+        );
+        let lore_id = lore.id;
+        let mut repos = Repos::new();
+        repos
+            .lore
+            .expect_get()
+            .returning(move |_| Ok(Some(lore.clone())));
+        repos.mentions.expect_replace_mentions().never();
+
+        let found = repos
+            .build()
+            .index_source(WorldId::new(), EntityType::Lore, lore_id.to_uuid())
+            .await
+            .expect("skipped");
+
+        assert_eq!(found, 0);
+    }
+
+    #[tokio::test]
+    async fn aliases_can_only_be_set_on_mentionable_entities() {
+        let result = Repos::new()
+            .build()
+            .set_aliases(
+                WorldId::new(),
+                EntityType::Lore,
+                Uuid::new_v4(),
+                vec!["the Ledger".to_string()],
+            )
+            .await;
+
+        assert!(matches!(result, Err(MentionError::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn mentions_of_deleted_targets_are_left_out() {
+        let world_id = WorldId::new();
+        let oss = npc(world_id, "Oss", "");
+        let oss_id = oss.id.to_uuid();
+        let source_id = Uuid::new_v4();
+        let stale = EntityMention {
+            world_id,
+            source_type: EntityType::Character,
+            source_id,
+            source_name: "Mira".to_string(),
+            target_type: EntityType::Character,
+            target_id: Uuid::new_v4(),
+            matched_text: "Kel".to_string(),
+            excerpt: "Kel".to_string(),
+            count: 1,
+        };
+        let live = EntityMention {
+            target_id: oss_id,
+            matched_text: "Oss".to_string(),
+            ..stale.clone()
+        };
+
+        let mut repos = Repos::new();
+        repos
+            .characters
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![oss.clone()]));
+        repos
+            .mentions
+            .expect_list_mentions_in()
+            .returning(move |_, _| Ok(vec![stale.clone(), live.clone()]));
+
+        let mentions = repos
+            .build()
+            .mentions_in(world_id, EntityType::Character, source_id)
+            .await
+            .expect("mentions");
+
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].target_name, "Oss");
+    }
+
+    #[tokio::test]
+    async fn aliases_used_in_text_resolve_to_their_entity() {
+        let world_id = WorldId::new();
+        let vexis = npc(world_id, "Vexis", "");
+        let vexis_id = vexis.id.to_uuid();
+
+        let mut repos = Repos::new();
+        repos
+            .characters
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![vexis.clone()]));
+        repos.mentions.checkpoint();
+        repos.mentions.expect_list_aliases().returning(move |_| {
+            Ok([(
+                (EntityType::Character, vexis_id),
+                vec!["the Grey Fox".to_string()],
+            )]
+            .into_iter()
+            .collect())
+        });
+
+        let references = repos
+            .build()
+            .alias_references(
+                world_id,
+                "Have you seen the grey fox? Vexis owes me, and the Grey Fox knows it.",
+            )
+            .await
+            .expect("references");
+
+        assert_eq!(
+            references,
+            vec![AliasReferenceContext {
+                alias: "the grey fox".to_string(),
+                name: "Vexis".to_string(),
+                entity_type: "character".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn removing_an_alias_the_entity_lacks_is_not_found() {
+        let world_id = WorldId::new();
+        let vexis = npc(world_id, "Vexis", "");
+        let vexis_id = vexis.id.to_uuid();
+
+        let mut repos = Repos::new();
+        repos
+            .characters
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![vexis.clone()]));
+        repos.mentions.expect_set_aliases().never();
+
+        let result = repos
+            .build()
+            .remove_alias(world_id, EntityType::Character, vexis_id, "the Grey Fox")
+            .await;
+
+        assert!(matches!(result, Err(MentionError::NotFound)));
+    }
+}
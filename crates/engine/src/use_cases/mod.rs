@@ -6,24 +6,69 @@
 pub mod approval;
 pub mod actantial;
 pub mod ai;
+pub mod analytics;
 pub mod assets;
+pub mod broadsheet;
 pub mod challenge;
+pub mod chronology;
+pub mod companions;
 pub mod content;
 pub mod conversation;
+pub mod crowds;
 pub mod custom_condition;
+pub mod custom_fields;
+pub mod diagnostics;
+pub mod dialogue_trees;
+pub mod dice;
+pub mod drafts;
+pub mod duplicate;
+pub mod economy;
+pub mod factions;
+pub mod flashback;
+pub mod health;
+pub mod injuries;
+pub mod interactions;
+pub mod knowledge;
+pub mod library;
 pub mod location_events;
 pub mod lore;
+pub mod mail;
 pub mod management;
+pub mod mentions;
+pub mod mortality;
 pub mod movement;
+pub mod names;
 pub mod narrative;
 pub mod npc;
+pub mod npc_drafts;
+pub mod pacing;
+pub mod parallel_scenes;
 pub mod player_action;
+pub mod plugins;
+pub mod prep;
+pub mod progress_clock;
+pub mod property;
 pub mod queues;
+pub mod rule_system;
+pub mod safety;
+pub mod scripts;
+pub mod secrets;
 pub mod settings;
+pub mod simulation;
+pub mod setup;
 pub mod session;
+pub mod session_zero;
+pub mod spotlight;
 pub mod staging;
 pub mod story_events;
+pub mod tags;
+pub mod templates;
 pub mod time;
+pub mod trade;
+pub mod transcription;
+pub mod travel;
+pub mod tutorial;
+pub mod usage;
 pub mod visual_state;
 pub mod world;
 
@@ -31,23 +76,67 @@ pub mod world;
 pub use approval::ApprovalUseCases;
 pub use actantial::ActantialUseCases;
 pub use ai::AiUseCases;
+pub use analytics::AnalyticsUseCases;
 pub use assets::AssetUseCases;
+pub use broadsheet::BroadsheetUseCases;
 pub use challenge::ChallengeUseCases;
+pub use chronology::ChronologyUseCases;
+pub use companions::CompanionUseCases;
 pub use conversation::ConversationUseCases;
 pub use custom_condition::CustomConditionEvaluator;
+pub use custom_fields::CustomFieldUseCases;
+pub use crowds::CrowdUseCases;
+pub use drafts::DraftUseCases;
+pub use duplicate::DuplicateUseCases;
+pub use economy::EconomyUseCases;
+pub use factions::FactionUseCases;
+pub use flashback::FlashbackUseCases;
+pub use diagnostics::DiagnosticsUseCases;
+pub use dialogue_trees::DialogueTreeUseCases;
+pub use dice::DiceUseCases;
+pub use health::HealthUseCases;
+pub use injuries::InjuryUseCases;
+pub use interactions::InteractionUseCases;
+pub use knowledge::KnowledgeUseCases;
+pub use library::LibraryUseCases;
 pub use location_events::LocationEventUseCases;
 pub use lore::LoreUseCases;
+pub use mail::MailUseCases;
 pub use management::ManagementUseCases;
+pub use mentions::MentionUseCases;
+pub use mortality::MortalityUseCases;
 pub use movement::MovementUseCases;
 pub use movement::SceneChangeBuilder;
+pub use names::NameGeneratorUseCases;
 pub use narrative::NarrativeUseCases;
 pub use npc::NpcUseCases;
+pub use npc_drafts::NpcDraftUseCases;
+pub use pacing::PacingUseCases;
+pub use parallel_scenes::ParallelSceneUseCases;
 pub use player_action::PlayerActionUseCases;
+pub use prep::PrepUseCases;
+pub use progress_clock::ProgressClockUseCases;
+pub use property::PropertyUseCases;
 pub use queues::QueueUseCases;
+pub use rule_system::RuleSystemUseCases;
+pub use safety::SafetyUseCases;
+pub use scripts::ScriptUseCases;
+pub use secrets::SecretUseCases;
 pub use settings::SettingsError;
+pub use simulation::SimulationUseCases;
+pub use setup::SetupUseCases;
 pub use session::SessionUseCases;
+pub use session_zero::SessionZeroUseCases;
+pub use spotlight::SpotlightUseCases;
 pub use staging::StagingUseCases;
 pub use story_events::StoryEventUseCases;
+pub use tags::TagUseCases;
+pub use templates::TemplateUseCases;
 pub use time::TimeUseCases;
+pub use trade::TradeUseCases;
+pub use transcription::TranscriptionUseCases;
+pub use travel::TravelUseCases;
+pub use tutorial::TutorialUseCases;
+pub use usage::UsageUseCases;
 pub use visual_state::VisualStateUseCases;
 pub use world::WorldUseCases;
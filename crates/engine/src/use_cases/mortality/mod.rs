@@ -0,0 +1,625 @@
+//! PC mortality use cases.
+//!
+//! When a PC dies the DM records how. The PC is locked out of play, and a
+//! memorial goes into the world's story timeline. Depending on the rule
+//! system, the DM can bring them back within a time limit and for a price.
+//! Otherwise the player creates a successor. The successor starts where the
+//! fallen PC was and can inherit what the party knew: the fallen PC's lore,
+//! and the NPCs they had seen, passed on as hearsay.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    CharacterId, DomainError, LoreDiscoverySource, LoreKnowledge, NpcObservation, PcDeath,
+    PlayerCharacter, PlayerCharacterId, StoryEvent, StoryEventId, StoryEventType, World, WorldId,
+    MAX_DEATH_CAUSE_LEN, MAX_EPITAPH_LEN,
+};
+use wrldbldr_protocol::types::{PcDeathData, PcDeathInputData, SuccessorData, SuccessorInputData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for PC mortality use cases.
+pub struct MortalityUseCases {
+    pub manage: Arc<ManageMortality>,
+}
+
+impl MortalityUseCases {
+    pub fn new(manage: Arc<ManageMortality>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MortalityError {
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for MortalityError {
+    fn from(e: DomainError) -> Self {
+        match e {
+            DomainError::InvalidStateTransition(msg) => MortalityError::Conflict(msg),
+            other => MortalityError::Invalid(other.to_string()),
+        }
+    }
+}
+
+/// Record PC deaths, resurrect them and hand play on to successors.
+pub struct ManageMortality {
+    player_character: Arc<entities::PlayerCharacter>,
+    world: Arc<entities::World>,
+    narrative: Arc<entities::Narrative>,
+    lore: Arc<entities::Lore>,
+    observation: Arc<entities::Observation>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageMortality {
+    pub fn new(
+        player_character: Arc<entities::PlayerCharacter>,
+        world: Arc<entities::World>,
+        narrative: Arc<entities::Narrative>,
+        lore: Arc<entities::Lore>,
+        observation: Arc<entities::Observation>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            player_character,
+            world,
+            narrative,
+            lore,
+            observation,
+            clock,
+        }
+    }
+
+    /// The PC, if they belong to the world
+    pub async fn get_pc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<PlayerCharacter, MortalityError> {
+        self.player_character
+            .get(pc_id)
+            .await?
+            .filter(|pc| pc.world_id == world_id)
+            .ok_or(MortalityError::PlayerCharacterNotFound)
+    }
+
+    /// A dead PC's memorial and whether they can be resurrected now
+    pub async fn get_death(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<PcDeathData, MortalityError> {
+        let pc = self.get_pc(world_id, pc_id).await?;
+        let world = self.get_world(world_id).await?;
+        describe(&pc, &world)
+    }
+
+    /// Record a PC's death as of the current game time and write their
+    /// memorial into the story timeline
+    pub async fn kill(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        input: PcDeathInputData,
+    ) -> Result<PcDeathData, MortalityError> {
+        let mut pc = self.get_pc(world_id, pc_id).await?;
+        let world = self.get_world(world_id).await?;
+
+        let cause = input.cause.trim().to_string();
+        if cause.is_empty() {
+            return Err(MortalityError::Invalid(
+                "A cause of death is required".to_string(),
+            ));
+        }
+        if cause.len() > MAX_DEATH_CAUSE_LEN {
+            return Err(MortalityError::Invalid(format!(
+                "Cause of death cannot exceed {} characters",
+                MAX_DEATH_CAUSE_LEN
+            )));
+        }
+        let epitaph = input
+            .epitaph
+            .map(|e| e.trim().to_string())
+            .filter(|e| !e.is_empty());
+        if epitaph.as_ref().is_some_and(|e| e.len() > MAX_EPITAPH_LEN) {
+            return Err(MortalityError::Invalid(format!(
+                "Epitaph cannot exceed {} characters",
+                MAX_EPITAPH_LEN
+            )));
+        }
+
+        let mut death = PcDeath::new(cause, world.game_time.current());
+        if let Some(epitaph) = epitaph {
+            death = death.with_epitaph(epitaph);
+        }
+        let memorial = self.memorial(&pc, &death, &world);
+        death = death.with_memorial_event(memorial.id);
+        pc.die(death, self.clock.now())?;
+
+        self.narrative.save_story_event(&memorial).await?;
+        self.player_character.save(&pc).await?;
+        describe(&pc, &world)
+    }
+
+    /// Bring a dead PC back, if the rule system allows it this long after
+    /// their death. The cost comes out of the PC's currency field when the
+    /// rule system has one.
+    pub async fn resurrect(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<PlayerCharacter, MortalityError> {
+        let mut pc = self.get_pc(world_id, pc_id).await?;
+        let world = self.get_world(world_id).await?;
+        let config = world.rule_system.resurrection_config_or_default();
+
+        let days_dead = pc
+            .death
+            .as_ref()
+            .map(|d| d.days_dead(world.game_time.current()))
+            .ok_or_else(|| MortalityError::Conflict(format!("{} is not dead", pc.name)))?;
+        config
+            .check(days_dead, pc.resurrections)
+            .map_err(MortalityError::Conflict)?;
+        pc.resurrect(self.clock.now())?;
+
+        self.player_character.save(&pc).await?;
+        if config.cost > 0 {
+            if let Some(field) = world.rule_system.variant.currency_field() {
+                let amount = i32::try_from(config.cost).unwrap_or(i32::MAX);
+                self.player_character
+                    .modify_stat(pc.id, field, -amount)
+                    .await?;
+            }
+        }
+
+        let event = StoryEvent {
+            id: StoryEventId::new(),
+            world_id,
+            event_type: StoryEventType::Custom {
+                event_subtype: "pc_resurrection".to_string(),
+                title: format!("{} returns", pc.name),
+                description: format!("{} was brought back from death.", pc.name),
+                data: serde_json::json!({ "pc_id": pc.id.to_string() }),
+            },
+            timestamp: self.clock.now(),
+            game_time: Some(world.game_time.display_date()),
+            summary: format!("{} returned from death", pc.name),
+            is_hidden: false,
+            tags: vec!["resurrection".to_string()],
+        };
+        self.narrative.save_story_event(&event).await?;
+        Ok(pc)
+    }
+
+    /// Create the PC who takes over from a dead one, for the same player.
+    /// They start where the fallen PC was and inherit the knowledge asked
+    /// for.
+    pub async fn create_successor(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        input: SuccessorInputData,
+    ) -> Result<SuccessorData, MortalityError> {
+        let mut fallen = self.get_pc(world_id, pc_id).await?;
+        let world = self.get_world(world_id).await?;
+
+        let name = input.name.trim().to_string();
+        if name.is_empty() {
+            return Err(MortalityError::Invalid(
+                "Player character name cannot be empty".to_string(),
+            ));
+        }
+
+        let now = self.clock.now();
+        let mut successor = PlayerCharacter::new(
+            fallen.user_id.clone(),
+            world_id,
+            name,
+            fallen.current_location_id,
+            now,
+        )
+        .with_predecessor(fallen.id);
+        if let Some(region_id) = fallen.current_region_id {
+            successor = successor.with_starting_region(region_id);
+        }
+        if let Some(description) = input.description.filter(|d| !d.trim().is_empty()) {
+            successor = successor.with_description(description);
+        }
+        if let Some(sheet_data) = input.sheet_data {
+            let data: wrldbldr_domain::CharacterSheetData = serde_json::from_value(sheet_data)
+                .map_err(|e| MortalityError::Invalid(format!("Invalid sheet_data: {}", e)))?;
+            successor = successor.with_sheet_data(data);
+        }
+        fallen.pass_to_successor(successor.id)?;
+
+        self.player_character.save(&successor).await?;
+        self.player_character.save(&fallen).await?;
+
+        let game_now = world.game_time.current();
+        let inherited_lore = if input.inherit_lore {
+            self.inherit_lore(&fallen, &successor, game_now).await?
+        } else {
+            0
+        };
+        let inherited_observations = if input.inherit_observations {
+            self.inherit_observations(&fallen, &successor, now).await?
+        } else {
+            0
+        };
+
+        Ok(SuccessorData {
+            pc_id: successor.id.to_string(),
+            name: successor.name,
+            predecessor_id: fallen.id.to_string(),
+            inherited_lore,
+            inherited_observations,
+        })
+    }
+
+    /// Pass on the lore the fallen PC knew, chunk for chunk
+    async fn inherit_lore(
+        &self,
+        fallen: &PlayerCharacter,
+        successor: &PlayerCharacter,
+        game_now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u32, MortalityError> {
+        let successor_id = CharacterId::from_uuid(successor.id.to_uuid());
+        let known = self
+            .lore
+            .get_character_knowledge(CharacterId::from_uuid(fallen.id.to_uuid()))
+            .await?;
+        for knowledge in &known {
+            self.lore
+                .grant_knowledge(&LoreKnowledge {
+                    lore_id: knowledge.lore_id,
+                    character_id: successor_id,
+                    known_chunk_ids: knowledge.known_chunk_ids.clone(),
+                    discovery_source: LoreDiscoverySource::DmGranted {
+                        reason: Some(format!("Inherited from {}", fallen.name)),
+                    },
+                    discovered_at: game_now,
+                    notes: None,
+                })
+                .await?;
+        }
+        Ok(known.len() as u32)
+    }
+
+    /// Pass on the NPCs the fallen PC's player had seen, as hearsay
+    async fn inherit_observations(
+        &self,
+        fallen: &PlayerCharacter,
+        successor: &PlayerCharacter,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u32, MortalityError> {
+        let mut count = 0;
+        for seen in self.observation.get_observations(fallen.id).await? {
+            if !seen.is_revealed_to_player {
+                continue;
+            }
+            let heard = NpcObservation::heard_about(
+                successor.id,
+                seen.npc_id,
+                seen.location_id,
+                seen.region_id,
+                seen.game_time,
+                Some(format!("Heard from {}", fallen.name)),
+                now,
+            );
+            self.observation.save_observation(&heard).await?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// The memorial story event for a PC's death
+    fn memorial(&self, pc: &PlayerCharacter, death: &PcDeath, world: &World) -> StoryEvent {
+        let description = match &death.epitaph {
+            Some(epitaph) => format!("{}\n\n{}", death.cause, epitaph),
+            None => death.cause.clone(),
+        };
+        StoryEvent {
+            id: StoryEventId::new(),
+            world_id: world.id,
+            event_type: StoryEventType::Custom {
+                event_subtype: "pc_memorial".to_string(),
+                title: format!("In memory of {}", pc.name),
+                description,
+                data: serde_json::json!({
+                    "pc_id": pc.id.to_string(),
+                    "cause": death.cause,
+                    "epitaph": death.epitaph,
+                }),
+            },
+            timestamp: self.clock.now(),
+            game_time: Some(world.game_time.display_date()),
+            summary: format!("{} died: {}", pc.name, death.cause),
+            is_hidden: false,
+            tags: vec!["memorial".to_string(), "death".to_string()],
+        }
+    }
+
+    async fn get_world(&self, world_id: WorldId) -> Result<World, MortalityError> {
+        self.world
+            .get(world_id)
+            .await?
+            .ok_or(MortalityError::WorldNotFound)
+    }
+}
+
+/// The dead PC for the wire, with the rule system's verdict on bringing
+/// them back
+fn describe(pc: &PlayerCharacter, world: &World) -> Result<PcDeathData, MortalityError> {
+    let death = pc
+        .death
+        .as_ref()
+        .ok_or_else(|| MortalityError::Conflict(format!("{} is not dead", pc.name)))?;
+    let config = world.rule_system.resurrection_config_or_default();
+    let resurrection_blocked = if pc.successor_id.is_some() {
+        Some(format!("{} already has a successor", pc.name))
+    } else {
+        config
+            .check(death.days_dead(world.game_time.current()), pc.resurrections)
+            .err()
+    };
+    Ok(PcDeathData {
+        pc_id: pc.id.to_string(),
+        name: pc.name.clone(),
+        cause: death.cause.clone(),
+        epitaph: death.epitaph.clone(),
+        died_at: death.died_at.to_rfc3339(),
+        memorial_event_id: death.memorial_event_id.map(|id| id.to_string()),
+        resurrections: pc.resurrections,
+        successor_id: pc.successor_id.map(|id| id.to_string()),
+        resurrection_blocked,
+        resurrection_cost: config.cost,
+        currency: world
+            .rule_system
+            .variant
+            .currency_field()
+            .map(str::to_string),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        MockChallengeRepo, MockCharacterRepo, MockFlagRepo, MockLocationRepo, MockLoreRepo,
+        MockNarrativeRepo, MockObservationRepo, MockPlayerCharacterRepo, MockSceneRepo,
+        MockWorldRepo,
+    };
+    use chrono::{Duration, Utc};
+    use wrldbldr_domain::{LocationId, LoreId, ObservationType, RegionId};
+
+    struct Repos {
+        pcs: MockPlayerCharacterRepo,
+        worlds: MockWorldRepo,
+        narrative: MockNarrativeRepo,
+        lore: MockLoreRepo,
+        observations: MockObservationRepo,
+    }
+
+    impl Repos {
+        fn new(world: World) -> Self {
+            let mut worlds = MockWorldRepo::new();
+            worlds
+                .expect_get()
+                .returning(move |_| Ok(Some(world.clone())));
+            Self {
+                pcs: MockPlayerCharacterRepo::new(),
+                worlds,
+                narrative: MockNarrativeRepo::new(),
+                lore: MockLoreRepo::new(),
+                observations: MockObservationRepo::new(),
+            }
+        }
+
+        fn with_pc(mut self, pc: PlayerCharacter) -> Self {
+            self.pcs
+                .expect_get()
+                .returning(move |_| Ok(Some(pc.clone())));
+            self
+        }
+
+        fn build(self) -> ManageMortality {
+            let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(Utc::now()));
+            let worlds: Arc<MockWorldRepo> = Arc::new(self.worlds);
+            ManageMortality::new(
+                Arc::new(entities::PlayerCharacter::new(Arc::new(self.pcs))),
+                Arc::new(entities::World::new(worlds.clone(), clock.clone())),
+                Arc::new(entities::Narrative::new(
+                    Arc::new(self.narrative),
+                    Arc::new(MockLocationRepo::new()),
+                    worlds,
+                    Arc::new(MockPlayerCharacterRepo::new()),
+                    Arc::new(MockCharacterRepo::new()),
+                    Arc::new(MockObservationRepo::new()),
+                    Arc::new(MockChallengeRepo::new()),
+                    Arc::new(MockFlagRepo::new()),
+                    Arc::new(MockSceneRepo::new()),
+                    clock.clone(),
+                )),
+                Arc::new(entities::Lore::new(Arc::new(self.lore))),
+                Arc::new(entities::Observation::new(
+                    Arc::new(self.observations),
+                    Arc::new(MockLocationRepo::new()),
+                    clock.clone(),
+                )),
+                clock,
+            )
+        }
+    }
+
+    fn pc_in(world: &World) -> PlayerCharacter {
+        PlayerCharacter::new("user", world.id, "Ash", LocationId::new(), Utc::now())
+            .with_starting_region(RegionId::new())
+    }
+
+    fn dead_pc_in(world: &World, days_ago: i64) -> PlayerCharacter {
+        let mut pc = pc_in(world);
+        let died_at = world.game_time.current() - Duration::days(days_ago);
+        pc.die(PcDeath::new("Fell in battle", died_at), Utc::now()).unwrap();
+        pc
+    }
+
+    #[tokio::test]
+    async fn death_locks_the_pc_and_writes_a_memorial() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let world_id = world.id;
+        let pc = pc_in(&world);
+        let pc_id = pc.id;
+
+        let mut repos = Repos::new(world).with_pc(pc);
+        repos
+            .narrative
+            .expect_save_story_event()
+            .withf(|e| {
+                e.tags.contains(&"memorial".to_string())
+                    && matches!(
+                        &e.event_type,
+                        StoryEventType::Custom { event_subtype, .. } if event_subtype == "pc_memorial"
+                    )
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        repos
+            .pcs
+            .expect_save()
+            .withf(|pc| {
+                pc.is_locked()
+                    && pc
+                        .death
+                        .as_ref()
+                        .is_some_and(|d| d.memorial_event_id.is_some())
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let data = PcDeathInputData {
+            cause: "Crushed by the collapsing vault".to_string(),
+            epitaph: Some("She held the door.".to_string()),
+        };
+        let death = repos.build().kill(world_id, pc_id, data).await.unwrap();
+
+        assert_eq!(death.cause, "Crushed by the collapsing vault");
+        assert!(death.memorial_event_id.is_some());
+        assert!(death.resurrection_blocked.is_none());
+    }
+
+    #[tokio::test]
+    async fn resurrection_is_refused_past_the_rule_systems_time_limit() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let world_id = world.id;
+        let limit = world
+            .rule_system
+            .resurrection_config_or_default()
+            .within_days
+            .unwrap();
+        let pc = dead_pc_in(&world, i64::from(limit) + 1);
+        let pc_id = pc.id;
+
+        let mut repos = Repos::new(world).with_pc(pc);
+        repos.pcs.expect_save().never();
+
+        let err = repos.build().resurrect(world_id, pc_id).await.unwrap_err();
+
+        assert!(matches!(err, MortalityError::Conflict(_)));
+    }
+
+    #[tokio::test]
+    async fn successors_inherit_lore_and_sightings_as_hearsay() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let world_id = world.id;
+        let fallen = dead_pc_in(&world, 0);
+        let fallen_id = fallen.id;
+        let fallen_character_id = CharacterId::from_uuid(fallen_id.to_uuid());
+        let region_id = fallen.current_region_id.unwrap();
+        let seen = NpcObservation::direct(
+            fallen_id,
+            CharacterId::new(),
+            fallen.current_location_id,
+            region_id,
+            world.game_time.current(),
+            Utc::now(),
+        );
+        let hidden = NpcObservation::direct_unrevealed(
+            fallen_id,
+            CharacterId::new(),
+            fallen.current_location_id,
+            region_id,
+            world.game_time.current(),
+            Utc::now(),
+        );
+        let known = LoreKnowledge::full(
+            LoreId::new(),
+            fallen_character_id,
+            LoreDiscoverySource::CommonKnowledge,
+            world.game_time.current(),
+        );
+
+        let mut repos = Repos::new(world).with_pc(fallen);
+        repos
+            .pcs
+            .expect_save()
+            .withf(move |pc| {
+                (pc.id == fallen_id && pc.successor_id.is_some())
+                    || (pc.predecessor_id == Some(fallen_id)
+                        && pc.current_region_id == Some(region_id))
+            })
+            .times(2)
+            .returning(|_| Ok(()));
+        repos
+            .lore
+            .expect_get_character_knowledge()
+            .withf(move |id| *id == fallen_character_id)
+            .returning(move |_| Ok(vec![known.clone()]));
+        repos
+            .lore
+            .expect_grant_knowledge()
+            .withf(move |k| k.character_id != fallen_character_id)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos
+            .observations
+            .expect_get_observations()
+            .returning(move |_| Ok(vec![seen.clone(), hidden.clone()]));
+        repos
+            .observations
+            .expect_save_observation()
+            .withf(|o| o.observation_type == ObservationType::HeardAbout)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let data = SuccessorInputData {
+            name: "Wren".to_string(),
+            description: None,
+            sheet_data: None,
+            inherit_lore: true,
+            inherit_observations: true,
+        };
+        let successor = repos
+            .build()
+            .create_successor(world_id, fallen_id, data)
+            .await
+            .unwrap();
+
+        assert_eq!(successor.predecessor_id, fallen_id.to_string());
+        assert_eq!(successor.inherited_lore, 1);
+        assert_eq!(successor.inherited_observations, 1);
+    }
+}
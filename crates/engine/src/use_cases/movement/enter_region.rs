@@ -10,12 +10,16 @@ use wrldbldr_domain::{
 };
 
 use crate::entities::{
-    Flag, Inventory, Location, Narrative, Observation, PlayerCharacter, Scene, Staging, World,
+    Companion, Flag, Inventory, Location, Narrative, Observation, PlayerCharacter, Scene, Staging,
+    World,
 };
 use crate::infrastructure::ports::RepoError;
 use crate::use_cases::time::{SuggestTime, TimeSuggestion};
 
-use super::{resolve_scene_for_region, resolve_staging_for_region, suggest_time_for_movement};
+use super::{
+    bring_companions, resolve_scene_for_region, resolve_staging_for_region,
+    suggest_time_for_movement,
+};
 
 /// Result of entering a region.
 #[derive(Debug)]
@@ -61,6 +65,7 @@ pub struct EnterRegion {
     inventory: Arc<Inventory>,
     flag: Arc<Flag>,
     world: Arc<World>,
+    companion: Arc<Companion>,
     suggest_time: Arc<SuggestTime>,
 }
 
@@ -75,6 +80,7 @@ impl EnterRegion {
         inventory: Arc<Inventory>,
         flag: Arc<Flag>,
         world: Arc<World>,
+        companion: Arc<Companion>,
         suggest_time: Arc<SuggestTime>,
     ) -> Self {
         Self {
@@ -87,6 +93,7 @@ impl EnterRegion {
             inventory,
             flag,
             world,
+            companion,
             suggest_time,
         }
     }
@@ -111,6 +118,9 @@ impl EnterRegion {
             .get(pc_id)
             .await?
             .ok_or(EnterRegionError::PlayerCharacterNotFound)?;
+        if pc.is_locked() {
+            return Err(EnterRegionError::PlayerCharacterDead);
+        }
 
         // 2. Get the target region
         let region = self
@@ -152,7 +162,7 @@ impl EnterRegion {
         let current_game_time = world_data.game_time.current();
 
         // 6. Check for valid staging (with TTL check using game time)
-        let (npcs, staging_status) = resolve_staging_for_region(
+        let (mut npcs, staging_status) = resolve_staging_for_region(
             &self.staging,
             region_id,
             region.location_id,
@@ -161,6 +171,9 @@ impl EnterRegion {
         )
         .await?;
 
+        // Following companions arrive with the PC
+        bring_companions(&self.companion, &pc, region_id, &mut npcs, &staging_status).await?;
+
         // 7. Update player's observation state (even if staging pending, record the visit)
         // Use game time for when the observation occurred in-game
         if !npcs.is_empty() {
@@ -267,6 +280,8 @@ enum ConnectionCheckResult {
 pub enum EnterRegionError {
     #[error("Player character not found")]
     PlayerCharacterNotFound,
+    #[error("Player character is dead")]
+    PlayerCharacterDead,
     #[error("Region not found")]
     RegionNotFound,
     #[error("World not found")]
@@ -292,9 +307,9 @@ mod tests {
 
     use crate::entities;
     use crate::infrastructure::ports::{
-        ClockPort, MockChallengeRepo, MockCharacterRepo, MockFlagRepo, MockItemRepo,
-        MockLocationRepo, MockNarrativeRepo, MockObservationRepo, MockPlayerCharacterRepo,
-        MockSceneRepo, MockStagingRepo, MockWorldRepo,
+        ClockPort, MockChallengeRepo, MockCharacterRepo, MockCompanionRepo, MockFlagRepo,
+        MockItemRepo, MockLocationRepo, MockNarrativeRepo, MockObservationRepo,
+        MockPlayerCharacterRepo, MockSceneRepo, MockStagingRepo, MockWorldRepo,
     };
 
     struct FixedClock(chrono::DateTime<chrono::Utc>);
@@ -349,6 +364,10 @@ mod tests {
         let flag = Arc::new(entities::Flag::new(Arc::new(MockFlagRepo::new())));
 
         let world = Arc::new(entities::World::new(Arc::new(world_repo), clock.clone()));
+        let companion = Arc::new(entities::Companion::new(
+            Arc::new(MockCompanionRepo::new()),
+            Arc::new(MockCharacterRepo::new()),
+        ));
         let suggest_time = Arc::new(crate::use_cases::time::SuggestTime::new(
             world.clone(),
             clock,
@@ -364,6 +383,7 @@ mod tests {
             inventory,
             flag,
             world,
+            companion,
             suggest_time,
         )
     }
@@ -393,6 +413,38 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn when_pc_dead_then_returns_player_character_dead() {
+        let now = Utc::now();
+        let pc_id = PlayerCharacterId::new();
+        let region_id = RegionId::new();
+
+        let mut pc = wrldbldr_domain::PlayerCharacter::new(
+            "user",
+            WorldId::new(),
+            "PC",
+            LocationId::new(),
+            now,
+        );
+        pc.die(wrldbldr_domain::PcDeath::new("Drowned", now), now).unwrap();
+
+        let mut pc_repo = MockPlayerCharacterRepo::new();
+        pc_repo
+            .expect_get()
+            .withf(move |id| *id == pc_id)
+            .returning(move |_| Ok(Some(pc.clone())));
+
+        let use_case = build_use_case(
+            pc_repo,
+            MockLocationRepo::new(),
+            MockWorldRepo::new(),
+            Arc::new(FixedClock(now)),
+        );
+
+        let err = use_case.execute(pc_id, region_id).await.unwrap_err();
+        assert!(matches!(err, super::EnterRegionError::PlayerCharacterDead));
+    }
+
     #[tokio::test]
     async fn when_region_missing_then_returns_region_not_found() {
         let now = Utc::now();
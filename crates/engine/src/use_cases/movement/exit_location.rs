@@ -4,16 +4,20 @@
 //! Determines the arrival region and coordinates with staging/narrative/scene/time systems.
 
 use std::sync::Arc;
-use wrldbldr_domain::{LocationId, PlayerCharacterId, RegionId};
+use wrldbldr_domain::{HotspotTarget, LocationId, PlayerCharacterId, RegionId};
 
 use crate::entities::{
-    Flag, Inventory, Location, Narrative, Observation, PlayerCharacter, Scene, Staging, World,
+    Companion, Flag, Inventory, Location, Narrative, Observation, PlayerCharacter, Scene, Staging,
+    World,
 };
 use crate::infrastructure::ports::RepoError;
 use crate::use_cases::time::SuggestTime;
 
 use super::enter_region::EnterRegionResult;
-use super::{resolve_scene_for_region, resolve_staging_for_region, suggest_time_for_movement};
+use super::{
+    bring_companions, resolve_scene_for_region, resolve_staging_for_region,
+    suggest_time_for_movement,
+};
 
 /// Exit to location use case.
 ///
@@ -28,6 +32,7 @@ pub struct ExitLocation {
     inventory: Arc<Inventory>,
     flag: Arc<Flag>,
     world: Arc<World>,
+    companion: Arc<Companion>,
     suggest_time: Arc<SuggestTime>,
 }
 
@@ -42,6 +47,7 @@ impl ExitLocation {
         inventory: Arc<Inventory>,
         flag: Arc<Flag>,
         world: Arc<World>,
+        companion: Arc<Companion>,
         suggest_time: Arc<SuggestTime>,
     ) -> Self {
         Self {
@@ -54,6 +60,7 @@ impl ExitLocation {
             inventory,
             flag,
             world,
+            companion,
             suggest_time,
         }
     }
@@ -74,12 +81,15 @@ impl ExitLocation {
         target_location_id: LocationId,
         arrival_region_id: Option<RegionId>,
     ) -> Result<EnterRegionResult, ExitLocationError> {
-        // 1. Validate player character exists
-        let _pc = self
+        // 1. Validate player character exists and can still move
+        let pc = self
             .player_character
             .get(pc_id)
             .await?
             .ok_or(ExitLocationError::PlayerCharacterNotFound)?;
+        if pc.is_locked() {
+            return Err(ExitLocationError::PlayerCharacterDead);
+        }
 
         // 2. Get the target location
         let location = self
@@ -88,6 +98,11 @@ impl ExitLocation {
             .await?
             .ok_or(ExitLocationError::LocationNotFound)?;
 
+        // The way there has to be in sight, or the PC has to know the place
+        if !self.can_head_for(&pc, target_location_id).await? {
+            return Err(ExitLocationError::UnknownLocation);
+        }
+
         // 3. Determine arrival region
         let region_id = self
             .determine_arrival_region(target_location_id, arrival_region_id)
@@ -126,7 +141,7 @@ impl ExitLocation {
         let current_game_time = world_data.game_time.current();
 
         // 8. Check for valid staging (with TTL check using game time)
-        let (npcs, staging_status) = resolve_staging_for_region(
+        let (mut npcs, staging_status) = resolve_staging_for_region(
             &self.staging,
             region_id,
             region.location_id,
@@ -135,6 +150,9 @@ impl ExitLocation {
         )
         .await?;
 
+        // Following companions arrive with the PC
+        bring_companions(&self.companion, &pc, region_id, &mut npcs, &staging_status).await?;
+
         // 9. Update observation (only if staging ready)
         // Use game time for when the observation occurred in-game
         if !npcs.is_empty() {
@@ -143,6 +161,10 @@ impl ExitLocation {
                 .await?;
         }
 
+        self.observation
+            .record_location_visit(pc_id, &location, current_game_time)
+            .await?;
+
         // 10. Check triggers
         let triggered_events = self.narrative.check_triggers(region_id, pc_id).await?;
 
@@ -190,6 +212,36 @@ impl ExitLocation {
         })
     }
 
+    /// Whether the PC can set out for a location: it is an exit from where
+    /// they stand, or somewhere they have been or heard about.
+    async fn can_head_for(
+        &self,
+        pc: &wrldbldr_domain::PlayerCharacter,
+        location_id: LocationId,
+    ) -> Result<bool, ExitLocationError> {
+        if let Some(region_id) = pc.current_region_id {
+            let exits = self.location.get_exits(region_id).await?;
+            if exits.exits.iter().any(|e| e.location_id == location_id) {
+                return Ok(true);
+            }
+            let hotspot_exit = self.location.get_region(region_id).await?.is_some_and(|r| {
+                r.hotspots.iter().any(|h| {
+                    matches!(
+                        h.target,
+                        HotspotTarget::LocationExit { location_id: to, .. } if to == location_id
+                    )
+                })
+            });
+            if hotspot_exit {
+                return Ok(true);
+            }
+        }
+        Ok(self
+            .observation
+            .knows_location(pc.id, pc.current_location_id, location_id)
+            .await?)
+    }
+
     /// Determine the arrival region for a location.
     async fn determine_arrival_region(
         &self,
@@ -240,6 +292,8 @@ impl ExitLocation {
 pub enum ExitLocationError {
     #[error("Player character not found")]
     PlayerCharacterNotFound,
+    #[error("Player character is dead")]
+    PlayerCharacterDead,
     #[error("Location not found")]
     LocationNotFound,
     #[error("World not found")]
@@ -250,6 +304,8 @@ pub enum ExitLocationError {
     NoArrivalRegion,
     #[error("Region does not belong to target location")]
     RegionLocationMismatch,
+    #[error("The character doesn't know the way to that location")]
+    UnknownLocation,
     #[error("Repository error: {0}")]
     Repo(#[from] RepoError),
 }
@@ -259,13 +315,15 @@ mod tests {
     use std::sync::Arc;
 
     use chrono::Utc;
-    use wrldbldr_domain::{LocationId, LocationType, PlayerCharacterId, Region, RegionId, WorldId};
+    use wrldbldr_domain::{
+        LocationId, LocationObservation, LocationType, PlayerCharacterId, Region, RegionId, WorldId,
+    };
 
     use crate::entities;
     use crate::infrastructure::ports::{
-        ClockPort, MockChallengeRepo, MockCharacterRepo, MockFlagRepo, MockItemRepo,
-        MockLocationRepo, MockNarrativeRepo, MockObservationRepo, MockPlayerCharacterRepo,
-        MockSceneRepo, MockStagingRepo, MockWorldRepo,
+        ClockPort, MockChallengeRepo, MockCharacterRepo, MockCompanionRepo, MockFlagRepo,
+        MockItemRepo, MockLocationRepo, MockNarrativeRepo, MockObservationRepo,
+        MockPlayerCharacterRepo, MockSceneRepo, MockStagingRepo, MockWorldRepo,
     };
 
     struct FixedClock(chrono::DateTime<chrono::Utc>);
@@ -281,6 +339,7 @@ mod tests {
         location_repo: MockLocationRepo,
         world_repo: MockWorldRepo,
         clock: Arc<dyn ClockPort>,
+        known_locations: Vec<LocationId>,
     ) -> super::ExitLocation {
         let player_character = Arc::new(entities::PlayerCharacter::new(Arc::new(
             player_character_repo,
@@ -292,8 +351,18 @@ mod tests {
 
         let staging = Arc::new(entities::Staging::new(Arc::new(MockStagingRepo::new())));
 
+        let mut observation_repo = MockObservationRepo::new();
+        let now = clock.now();
+        observation_repo
+            .expect_get_known_locations()
+            .returning(move |pc_id| {
+                Ok(known_locations
+                    .iter()
+                    .map(|id| LocationObservation::heard_about(pc_id, *id, now, None, now))
+                    .collect())
+            });
         let observation = Arc::new(entities::Observation::new(
-            Arc::new(MockObservationRepo::new()),
+            Arc::new(observation_repo),
             location_repo.clone(),
             clock.clone(),
         ));
@@ -320,6 +389,10 @@ mod tests {
         let flag = Arc::new(entities::Flag::new(Arc::new(MockFlagRepo::new())));
 
         let world = Arc::new(entities::World::new(Arc::new(world_repo), clock.clone()));
+        let companion = Arc::new(entities::Companion::new(
+            Arc::new(MockCompanionRepo::new()),
+            Arc::new(MockCharacterRepo::new()),
+        ));
         let suggest_time = Arc::new(crate::use_cases::time::SuggestTime::new(
             world.clone(),
             clock,
@@ -335,6 +408,7 @@ mod tests {
             inventory,
             flag,
             world,
+            companion,
             suggest_time,
         )
     }
@@ -355,6 +429,7 @@ mod tests {
             MockLocationRepo::new(),
             MockWorldRepo::new(),
             Arc::new(FixedClock(Utc::now())),
+            vec![],
         );
 
         let err = use_case
@@ -397,6 +472,7 @@ mod tests {
             location_repo,
             MockWorldRepo::new(),
             Arc::new(FixedClock(now)),
+            vec![target_location_id],
         );
 
         let err = use_case
@@ -406,6 +482,44 @@ mod tests {
         assert!(matches!(err, super::ExitLocationError::LocationNotFound));
     }
 
+    #[tokio::test]
+    async fn when_pc_does_not_know_the_location_then_returns_unknown_location() {
+        let now = Utc::now();
+        let world_id = WorldId::new();
+        let pc_location_id = LocationId::new();
+        let pc_id = PlayerCharacterId::new();
+
+        let mut pc =
+            wrldbldr_domain::PlayerCharacter::new("user", world_id, "PC", pc_location_id, now);
+        pc.id = pc_id;
+        let target = wrldbldr_domain::Location::new(world_id, "Far Isle", LocationType::Exterior);
+        let target_location_id = target.id;
+
+        let mut pc_repo = MockPlayerCharacterRepo::new();
+        pc_repo
+            .expect_get()
+            .returning(move |_| Ok(Some(pc.clone())));
+
+        let mut location_repo = MockLocationRepo::new();
+        location_repo
+            .expect_get_location()
+            .returning(move |id| Ok((id == target.id).then(|| target.clone())));
+
+        let use_case = build_use_case(
+            pc_repo,
+            location_repo,
+            MockWorldRepo::new(),
+            Arc::new(FixedClock(now)),
+            vec![],
+        );
+
+        let err = use_case
+            .execute(pc_id, target_location_id, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, super::ExitLocationError::UnknownLocation));
+    }
+
     #[tokio::test]
     async fn when_specified_arrival_region_is_not_in_location_then_returns_region_location_mismatch(
     ) {
@@ -454,6 +568,7 @@ mod tests {
             location_repo,
             MockWorldRepo::new(),
             Arc::new(FixedClock(now)),
+            vec![target_location_id],
         );
 
         let err = use_case
@@ -515,6 +630,7 @@ mod tests {
             location_repo,
             MockWorldRepo::new(),
             Arc::new(FixedClock(now)),
+            vec![target_location_id],
         );
 
         let err = use_case
@@ -599,6 +715,7 @@ mod tests {
             location_repo,
             world_repo,
             Arc::new(FixedClock(now)),
+            vec![target_location_id],
         );
 
         let err = use_case
@@ -3,13 +3,19 @@
 mod enter_region;
 mod exit_location;
 mod scene_change;
+mod unlock_connection;
 
 pub use enter_region::{EnterRegion, EnterRegionError, EnterRegionResult, StagingStatus};
 pub use exit_location::{ExitLocation, ExitLocationError};
 pub use scene_change::{SceneChangeBuilder, SceneChangeData};
+pub use unlock_connection::{
+    unlocked_connection_to_protocol, LockedWay, UnlockConnection, UnlockConnectionError,
+    UnlockedConnection,
+};
 
 use crate::entities::{
-    Flag, Inventory, Observation, Scene, SceneResolutionContext, Staging as StagingEntity,
+    Companion, Flag, Inventory, Observation, Scene, SceneResolutionContext,
+    Staging as StagingEntity,
 };
 use crate::infrastructure::ports::RepoError;
 use crate::use_cases::custom_condition::{CustomConditionEvaluator, EvaluationContext};
@@ -17,21 +23,27 @@ use crate::use_cases::time::{SuggestTime, SuggestTimeResult, TimeSuggestion};
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use wrldbldr_domain::{
-    GameTime, LocationId, PlayerCharacterId, RegionId, Scene as DomainScene, StagedNpc, Staging,
-    StagingSource, WorldId,
+    GameTime, LocationId, PlayerCharacter, PlayerCharacterId, RegionId, Scene as DomainScene,
+    StagedNpc, Staging, StagingSource, WorldId,
 };
 
 /// Container for movement use cases.
 pub struct MovementUseCases {
     pub enter_region: Arc<EnterRegion>,
     pub exit_location: Arc<ExitLocation>,
+    pub unlock_connection: Arc<UnlockConnection>,
 }
 
 impl MovementUseCases {
-    pub fn new(enter_region: Arc<EnterRegion>, exit_location: Arc<ExitLocation>) -> Self {
+    pub fn new(
+        enter_region: Arc<EnterRegion>,
+        exit_location: Arc<ExitLocation>,
+        unlock_connection: Arc<UnlockConnection>,
+    ) -> Self {
         Self {
             enter_region,
             exit_location,
+            unlock_connection,
         }
     }
 }
@@ -95,6 +107,29 @@ pub async fn resolve_staging_for_region(
     }
 }
 
+/// Move the PC's following companions into the region along with them.
+///
+/// When staging is ready the companions are added to the NPCs present
+/// (unless the staging already has them); while it is pending they are
+/// proposed to the DM with the rest of the staging suggestions.
+pub async fn bring_companions(
+    companion: &Companion,
+    pc: &PlayerCharacter,
+    region_id: RegionId,
+    npcs: &mut Vec<StagedNpc>,
+    staging_status: &StagingStatus,
+) -> Result<(), RepoError> {
+    let companions = companion.travel_with(pc, region_id).await?;
+    if matches!(staging_status, StagingStatus::Ready) {
+        for staged in companions {
+            if !npcs.iter().any(|n| n.character_id == staged.character_id) {
+                npcs.push(staged);
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Generate a time suggestion for movement.
 ///
 /// This is shared logic between EnterRegion and ExitLocation use cases.
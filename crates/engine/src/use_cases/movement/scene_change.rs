@@ -1,10 +1,14 @@
 use std::sync::Arc;
 
 use wrldbldr_domain::{LocationId, Region, RegionId, StagedNpc};
-use wrldbldr_protocol::{NavigationData, NpcPresenceData, RegionData, RegionItemData};
+use wrldbldr_protocol::{
+    CrowdPresenceData, NavigationData, NpcPresenceData, RegionData, RegionItemData,
+};
 
-use crate::entities::{Inventory, Location};
+use crate::entities::{Crowd, Inventory, Location, World};
 use crate::infrastructure::ports::RepoError;
+use crate::use_cases::crowds::crowd_to_presence;
+use crate::use_cases::management::{hotspot_to_protocol, light_level_to_protocol};
 
 /// Errors that can occur when building scene change data.
 #[derive(Debug, thiserror::Error)]
@@ -25,11 +29,23 @@ pub enum SceneChangeError {
 pub struct SceneChangeBuilder {
     location: Arc<Location>,
     inventory: Arc<Inventory>,
+    crowd: Arc<Crowd>,
+    world: Arc<World>,
 }
 
 impl SceneChangeBuilder {
-    pub fn new(location: Arc<Location>, inventory: Arc<Inventory>) -> Self {
-        Self { location, inventory }
+    pub fn new(
+        location: Arc<Location>,
+        inventory: Arc<Inventory>,
+        crowd: Arc<Crowd>,
+        world: Arc<World>,
+    ) -> Self {
+        Self {
+            location,
+            inventory,
+            crowd,
+            world,
+        }
     }
 
     pub async fn build_scene_change(
@@ -43,6 +59,10 @@ impl SceneChangeBuilder {
             .get(region.location_id)
             .await?
             .ok_or(SceneChangeError::LocationNotFound(region.location_id))?;
+        let light_level =
+            self.world.get(location.world_id).await?.map(|world| {
+                region.light_level(location.location_type, world.game_time.time_of_day())
+            });
 
         let region_data = RegionData {
             id: region.id.to_string(),
@@ -51,7 +71,9 @@ impl SceneChangeBuilder {
             location_name: location.name,
             backdrop_asset: region.backdrop_asset.clone(),
             atmosphere: region.atmosphere.clone(),
-            map_asset: None,
+            map_asset: region.map_asset.clone(),
+            hotspots: region.hotspots.iter().map(hotspot_to_protocol).collect(),
+            light_level: light_level.map(light_level_to_protocol),
         };
 
         let npcs_present: Vec<NpcPresenceData> = npcs
@@ -67,12 +89,14 @@ impl SceneChangeBuilder {
 
         let navigation = self.build_navigation_data(region.id).await?;
         let region_items = self.build_region_items(region.id).await;
+        let crowds_present = self.build_crowds(region.id).await;
 
         Ok(SceneChangeData {
             region: region_data,
             npcs_present,
             navigation,
             region_items,
+            crowds_present,
         })
     }
 
@@ -143,6 +167,17 @@ impl SceneChangeBuilder {
             }
         }
     }
+
+    async fn build_crowds(&self, region_id: RegionId) -> Vec<CrowdPresenceData> {
+        // Crowds are scenery; a failed lookup shouldn't block the move
+        match self.crowd.list_present_in_region(region_id).await {
+            Ok(crowds) => crowds.iter().map(crowd_to_presence).collect(),
+            Err(e) => {
+                tracing::warn!(error = %e, region_id = %region_id, "Failed to fetch region crowds");
+                vec![]
+            }
+        }
+    }
 }
 
 pub struct SceneChangeData {
@@ -150,4 +185,5 @@ pub struct SceneChangeData {
     pub npcs_present: Vec<NpcPresenceData>,
     pub navigation: NavigationData,
     pub region_items: Vec<RegionItemData>,
+    pub crowds_present: Vec<CrowdPresenceData>,
 }
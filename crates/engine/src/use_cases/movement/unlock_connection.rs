@@ -0,0 +1,291 @@
+//! Unlock connection use case.
+//!
+//! Opens a locked way out of where a PC stands: a connection from their
+//! region to another region, or from their location to another location.
+//! The lock opens for a key item the PC carries, or when they succeed at the
+//! challenge linked to it. Bidirectional connections open from both sides.
+
+use std::sync::Arc;
+use wrldbldr_domain::{
+    ChallengeId, ItemId, LocationId, PlayerCharacter as DomainPlayerCharacter, PlayerCharacterId,
+    RegionId, WorldId,
+};
+use wrldbldr_protocol::types::{LockedWayData, UnlockMethodData, UnlockedConnectionData};
+
+use crate::entities::{Inventory, Location, PlayerCharacter};
+use crate::infrastructure::ports::RepoError;
+
+/// Where a locked way leads from the PC's position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockedWay {
+    /// A region connection from the PC's current region
+    Region(RegionId),
+    /// A location connection from the PC's current location
+    Location(LocationId),
+}
+
+/// What opened the lock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnlockMethod {
+    Key { item_name: String },
+    Challenge { challenge_name: String },
+}
+
+/// A connection that was just unlocked.
+#[derive(Debug, Clone)]
+pub struct UnlockedConnection {
+    pub world_id: WorldId,
+    pub pc_id: PlayerCharacterId,
+    pub pc_name: String,
+    /// The region or location the way leads out of
+    pub from_id: String,
+    pub to: LockedWay,
+    pub to_name: String,
+    pub method: UnlockMethod,
+}
+
+/// Unlock connection use case.
+pub struct UnlockConnection {
+    player_character: Arc<PlayerCharacter>,
+    location: Arc<Location>,
+    inventory: Arc<Inventory>,
+}
+
+impl UnlockConnection {
+    pub fn new(
+        player_character: Arc<PlayerCharacter>,
+        location: Arc<Location>,
+        inventory: Arc<Inventory>,
+    ) -> Self {
+        Self {
+            player_character,
+            location,
+            inventory,
+        }
+    }
+
+    /// Use a carried item on the lock of a way out of the PC's position.
+    pub async fn with_key(
+        &self,
+        pc_id: PlayerCharacterId,
+        to: LockedWay,
+        item_id: ItemId,
+    ) -> Result<UnlockedConnection, UnlockConnectionError> {
+        let pc = self
+            .player_character
+            .get(pc_id)
+            .await?
+            .ok_or(UnlockConnectionError::PlayerCharacterNotFound)?;
+        if pc.is_locked() {
+            return Err(UnlockConnectionError::PlayerCharacterDead);
+        }
+
+        let item = self
+            .inventory
+            .get_pc_inventory(pc_id)
+            .await?
+            .into_iter()
+            .find(|item| item.id == item_id)
+            .ok_or(UnlockConnectionError::ItemNotCarried)?;
+        let method = UnlockMethod::Key {
+            item_name: item.name,
+        };
+
+        let (from_id, to_name) = match to {
+            LockedWay::Region(to_region) => {
+                let from_region = pc
+                    .current_region_id
+                    .ok_or(UnlockConnectionError::ConnectionNotFound)?;
+                let mut connection = self
+                    .location
+                    .get_connections(from_region)
+                    .await?
+                    .into_iter()
+                    .find(|c| c.to_region == to_region)
+                    .ok_or(UnlockConnectionError::ConnectionNotFound)?;
+                if !connection.is_locked {
+                    return Err(UnlockConnectionError::NotLocked);
+                }
+                if !connection.lock.opened_by_key(item_id) {
+                    return Err(UnlockConnectionError::WrongKey);
+                }
+                connection.unlock();
+                self.location.save_connection(&connection).await?;
+                (from_region.to_string(), self.region_name(to_region).await?)
+            }
+            LockedWay::Location(to_location) => {
+                let mut connection = self
+                    .location
+                    .get_location_exits(pc.current_location_id)
+                    .await?
+                    .into_iter()
+                    .find(|c| c.to_location == to_location)
+                    .ok_or(UnlockConnectionError::ConnectionNotFound)?;
+                if !connection.is_locked {
+                    return Err(UnlockConnectionError::NotLocked);
+                }
+                if !connection.lock.opened_by_key(item_id) {
+                    return Err(UnlockConnectionError::WrongKey);
+                }
+                connection.unlock();
+                self.location.save_location_connection(&connection).await?;
+                (
+                    pc.current_location_id.to_string(),
+                    self.location_name(to_location).await?,
+                )
+            }
+        };
+
+        tracing::info!(
+            pc_id = %pc_id,
+            item_id = %item_id,
+            to = ?to,
+            "Connection unlocked with a key"
+        );
+        Ok(unlocked(&pc, from_id, to, to_name, method))
+    }
+
+    /// Open every lock around the PC that the challenge they just succeeded
+    /// at is linked to.
+    pub async fn with_challenge(
+        &self,
+        pc_id: PlayerCharacterId,
+        challenge_id: ChallengeId,
+        challenge_name: &str,
+    ) -> Result<Vec<UnlockedConnection>, RepoError> {
+        let Some(pc) = self.player_character.get(pc_id).await? else {
+            return Ok(Vec::new());
+        };
+        let method = UnlockMethod::Challenge {
+            challenge_name: challenge_name.to_string(),
+        };
+        let mut opened = Vec::new();
+
+        if let Some(from_region) = pc.current_region_id {
+            for mut connection in self.location.get_connections(from_region).await? {
+                if !connection.is_locked || !connection.lock.opened_by_challenge(challenge_id) {
+                    continue;
+                }
+                connection.unlock();
+                self.location.save_connection(&connection).await?;
+                let to_name = self.region_name(connection.to_region).await?;
+                opened.push(unlocked(
+                    &pc,
+                    from_region.to_string(),
+                    LockedWay::Region(connection.to_region),
+                    to_name,
+                    method.clone(),
+                ));
+            }
+        }
+
+        for mut connection in self
+            .location
+            .get_location_exits(pc.current_location_id)
+            .await?
+        {
+            if !connection.is_locked || !connection.lock.opened_by_challenge(challenge_id) {
+                continue;
+            }
+            connection.unlock();
+            self.location.save_location_connection(&connection).await?;
+            let to_name = self.location_name(connection.to_location).await?;
+            opened.push(unlocked(
+                &pc,
+                pc.current_location_id.to_string(),
+                LockedWay::Location(connection.to_location),
+                to_name,
+                method.clone(),
+            ));
+        }
+
+        if !opened.is_empty() {
+            tracing::info!(
+                pc_id = %pc_id,
+                challenge_id = %challenge_id,
+                count = opened.len(),
+                "Connections unlocked by a challenge"
+            );
+        }
+        Ok(opened)
+    }
+
+    async fn region_name(&self, id: RegionId) -> Result<String, RepoError> {
+        Ok(self
+            .location
+            .get_region(id)
+            .await?
+            .map(|r| r.name)
+            .unwrap_or_default())
+    }
+
+    async fn location_name(&self, id: LocationId) -> Result<String, RepoError> {
+        Ok(self
+            .location
+            .get(id)
+            .await?
+            .map(|l| l.name)
+            .unwrap_or_default())
+    }
+}
+
+fn unlocked(
+    pc: &DomainPlayerCharacter,
+    from_id: String,
+    to: LockedWay,
+    to_name: String,
+    method: UnlockMethod,
+) -> UnlockedConnection {
+    UnlockedConnection {
+        world_id: pc.world_id,
+        pc_id: pc.id,
+        pc_name: pc.name.clone(),
+        from_id,
+        to,
+        to_name,
+        method,
+    }
+}
+
+pub fn unlocked_connection_to_protocol(unlocked: &UnlockedConnection) -> UnlockedConnectionData {
+    let (method, opened_with) = match &unlocked.method {
+        UnlockMethod::Key { item_name } => (UnlockMethodData::Key, item_name.clone()),
+        UnlockMethod::Challenge { challenge_name } => {
+            (UnlockMethodData::Challenge, challenge_name.clone())
+        }
+    };
+    UnlockedConnectionData {
+        pc_id: unlocked.pc_id.to_string(),
+        pc_name: unlocked.pc_name.clone(),
+        from_id: unlocked.from_id.clone(),
+        to: match unlocked.to {
+            LockedWay::Region(id) => LockedWayData::Region {
+                region_id: id.to_string(),
+            },
+            LockedWay::Location(id) => LockedWayData::Location {
+                location_id: id.to_string(),
+            },
+        },
+        to_name: unlocked.to_name.clone(),
+        method,
+        opened_with,
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UnlockConnectionError {
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("Player character is dead")]
+    PlayerCharacterDead,
+    #[error("You aren't carrying that")]
+    ItemNotCarried,
+    #[error("There is no such way from here")]
+    ConnectionNotFound,
+    #[error("The way isn't locked")]
+    NotLocked,
+    #[error("It doesn't fit the lock")]
+    WrongKey,
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
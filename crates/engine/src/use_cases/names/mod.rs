@@ -0,0 +1,369 @@
+//! Name generator use cases.
+//!
+//! Each culture in a world can have a name generator, assigned to the
+//! regions and factions where it lives. New NPCs take their names from the
+//! generator that fits where they come from, so the LLM isn't left to name
+//! everyone "Elara". Names already used by the world's characters are never
+//! drawn again.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    name_generator_for, DomainError, NameGenerator, NameGeneratorId, NameStyle, RegionId, WorldId,
+};
+use wrldbldr_protocol::types::{
+    GeneratedNamesData, NameGeneratorData, NameGeneratorInputData, NameStyleData,
+};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RandomPort, RepoError};
+
+/// Container for name generator use cases.
+pub struct NameGeneratorUseCases {
+    pub manage: Arc<ManageNameGenerators>,
+}
+
+impl NameGeneratorUseCases {
+    pub fn new(manage: Arc<ManageNameGenerators>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum NameGeneratorError {
+    #[error("Name generator not found")]
+    NotFound,
+    #[error("Region not found")]
+    RegionNotFound,
+    #[error("No name generator fits; create one, or one assigned nowhere as the world default")]
+    NoGenerator,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for NameGeneratorError {
+    fn from(e: DomainError) -> Self {
+        NameGeneratorError::Invalid(e.to_string())
+    }
+}
+
+/// Which generator to draw names from
+#[derive(Debug, Clone, Default)]
+pub struct NameScope {
+    /// A specific generator; otherwise the faction's, the region's or the
+    /// world default
+    pub generator_id: Option<NameGeneratorId>,
+    pub region_id: Option<RegionId>,
+    pub faction: Option<String>,
+}
+
+/// Create, edit and remove name generators, and draw names from them.
+pub struct ManageNameGenerators {
+    name_generator: Arc<entities::NameGenerator>,
+    character: Arc<entities::Character>,
+    location: Arc<entities::Location>,
+    random: Arc<dyn RandomPort>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageNameGenerators {
+    pub fn new(
+        name_generator: Arc<entities::NameGenerator>,
+        character: Arc<entities::Character>,
+        location: Arc<entities::Location>,
+        random: Arc<dyn RandomPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            name_generator,
+            character,
+            location,
+            random,
+            clock,
+        }
+    }
+
+    pub async fn list(
+        &self,
+        world_id: WorldId,
+    ) -> Result<Vec<NameGeneratorData>, NameGeneratorError> {
+        let generators = self.name_generator.list_for_world(world_id).await?;
+        Ok(generators.iter().map(name_generator_to_protocol).collect())
+    }
+
+    pub async fn create(
+        &self,
+        world_id: WorldId,
+        input: NameGeneratorInputData,
+    ) -> Result<NameGenerator, NameGeneratorError> {
+        let region_ids = self.world_regions(world_id, &input.region_ids).await?;
+        let generator = NameGenerator::new(
+            world_id,
+            input.name,
+            style_from_protocol(input.style)?,
+            self.clock.now(),
+        )?
+        .with_description(input.description)
+        .with_assignments(region_ids, input.factions);
+        self.name_generator.save(&generator).await?;
+        Ok(generator)
+    }
+
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        generator_id: NameGeneratorId,
+        input: NameGeneratorInputData,
+    ) -> Result<NameGenerator, NameGeneratorError> {
+        let mut generator = self.world_generator(world_id, generator_id).await?;
+        let region_ids = self.world_regions(world_id, &input.region_ids).await?;
+        generator.update(
+            &input.name,
+            input.description,
+            style_from_protocol(input.style)?,
+            region_ids,
+            input.factions,
+            self.clock.now(),
+        )?;
+        self.name_generator.save(&generator).await?;
+        Ok(generator)
+    }
+
+    pub async fn delete(
+        &self,
+        world_id: WorldId,
+        generator_id: NameGeneratorId,
+    ) -> Result<(), NameGeneratorError> {
+        let generator = self.world_generator(world_id, generator_id).await?;
+        self.name_generator.delete(generator.id).await?;
+        Ok(())
+    }
+
+    /// Up to `count` names no character in the world already has
+    pub async fn generate(
+        &self,
+        world_id: WorldId,
+        scope: NameScope,
+        count: usize,
+    ) -> Result<GeneratedNamesData, NameGeneratorError> {
+        let generator = match scope.generator_id {
+            Some(id) => self.world_generator(world_id, id).await?,
+            None => {
+                let generators = self.name_generator.list_for_world(world_id).await?;
+                name_generator_for(&generators, scope.region_id, scope.faction.as_deref())
+                    .cloned()
+                    .ok_or(NameGeneratorError::NoGenerator)?
+            }
+        };
+        let names = self.draw(world_id, &generator, count).await?;
+        Ok(GeneratedNamesData {
+            generator_id: generator.id.to_string(),
+            generator_name: generator.name.clone(),
+            names,
+        })
+    }
+
+    /// One unused name for an NPC from a region, if a generator fits
+    pub async fn name_for_region(
+        &self,
+        world_id: WorldId,
+        region_id: RegionId,
+    ) -> Result<Option<String>, NameGeneratorError> {
+        let generators = self.name_generator.list_for_world(world_id).await?;
+        let Some(generator) = name_generator_for(&generators, Some(region_id), None) else {
+            return Ok(None);
+        };
+        Ok(self.draw(world_id, generator, 1).await?.pop())
+    }
+
+    async fn draw(
+        &self,
+        world_id: WorldId,
+        generator: &NameGenerator,
+        count: usize,
+    ) -> Result<Vec<String>, NameGeneratorError> {
+        let taken: Vec<String> = self
+            .character
+            .list_in_world(world_id)
+            .await?
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        Ok(generator.generate(count, &taken, |min, max| self.random.gen_range(min, max)))
+    }
+
+    async fn world_generator(
+        &self,
+        world_id: WorldId,
+        generator_id: NameGeneratorId,
+    ) -> Result<NameGenerator, NameGeneratorError> {
+        self.name_generator
+            .get(generator_id)
+            .await?
+            .filter(|g| g.world_id == world_id)
+            .ok_or(NameGeneratorError::NotFound)
+    }
+
+    /// Parse region ids, checking each is in the world
+    async fn world_regions(
+        &self,
+        world_id: WorldId,
+        ids: &[String],
+    ) -> Result<Vec<RegionId>, NameGeneratorError> {
+        let mut region_ids = Vec::with_capacity(ids.len());
+        for id in ids {
+            let region_id = Uuid::parse_str(id)
+                .map(RegionId::from_uuid)
+                .map_err(|_| NameGeneratorError::Invalid(format!("Invalid region ID: {}", id)))?;
+            let region = self
+                .location
+                .get_region(region_id)
+                .await?
+                .ok_or(NameGeneratorError::RegionNotFound)?;
+            self.location
+                .get(region.location_id)
+                .await?
+                .filter(|l| l.world_id == world_id)
+                .ok_or(NameGeneratorError::RegionNotFound)?;
+            region_ids.push(region_id);
+        }
+        Ok(region_ids)
+    }
+}
+
+fn style_from_protocol(style: NameStyleData) -> Result<NameStyle, NameGeneratorError> {
+    match style {
+        NameStyleData::List {
+            given_names,
+            family_names,
+        } => Ok(NameStyle::List {
+            given_names,
+            family_names,
+        }),
+        NameStyleData::Grammar { patterns, parts } => Ok(NameStyle::Grammar { patterns, parts }),
+        NameStyleData::Unknown => Err(NameGeneratorError::Invalid(
+            "Unknown name style".to_string(),
+        )),
+    }
+}
+
+pub(crate) fn name_generator_to_protocol(generator: &NameGenerator) -> NameGeneratorData {
+    let style = match &generator.style {
+        NameStyle::List {
+            given_names,
+            family_names,
+        } => NameStyleData::List {
+            given_names: given_names.clone(),
+            family_names: family_names.clone(),
+        },
+        NameStyle::Grammar { patterns, parts } => NameStyleData::Grammar {
+            patterns: patterns.clone(),
+            parts: parts.clone(),
+        },
+    };
+    NameGeneratorData {
+        id: generator.id.to_string(),
+        name: generator.name.clone(),
+        description: generator.description.clone(),
+        style,
+        region_ids: generator
+            .region_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect(),
+        factions: generator.factions.clone(),
+        is_default: generator.is_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::{FixedClock, SeededRandom};
+    use crate::infrastructure::ports::{
+        MockCharacterRepo, MockLocationRepo, MockNameGeneratorRepo,
+    };
+    use wrldbldr_domain::{CampbellArchetype, Character};
+
+    fn villagers(world_id: WorldId) -> NameGenerator {
+        NameGenerator::new(
+            world_id,
+            "Villagers",
+            NameStyle::List {
+                given_names: vec!["Elara".to_string(), "Tomas".to_string()],
+                family_names: vec![],
+            },
+            chrono::Utc::now(),
+        )
+        .expect("valid generator")
+    }
+
+    fn names(repo: MockNameGeneratorRepo, characters: MockCharacterRepo) -> ManageNameGenerators {
+        ManageNameGenerators::new(
+            Arc::new(entities::NameGenerator::new(Arc::new(repo))),
+            Arc::new(entities::Character::new(Arc::new(characters))),
+            Arc::new(entities::Location::new(Arc::new(MockLocationRepo::new()))),
+            Arc::new(SeededRandom::new(7)),
+            Arc::new(FixedClock(chrono::Utc::now())),
+        )
+    }
+
+    #[tokio::test]
+    async fn names_already_in_use_are_not_drawn() {
+        let world_id = WorldId::new();
+        let generator = villagers(world_id);
+        let elara = Character::new(world_id, "Elara", CampbellArchetype::Ally);
+        let mut repo = MockNameGeneratorRepo::new();
+        repo.expect_list_for_world()
+            .returning(move |_| Ok(vec![generator.clone()]));
+        let mut characters = MockCharacterRepo::new();
+        characters
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![elara.clone()]));
+
+        let generated = names(repo, characters)
+            .generate(world_id, NameScope::default(), 5)
+            .await
+            .expect("names");
+
+        assert_eq!(generated.generator_name, "Villagers");
+        assert_eq!(generated.names, vec!["Tomas"]);
+    }
+
+    #[tokio::test]
+    async fn generators_from_other_worlds_are_missing() {
+        let generator = villagers(WorldId::new());
+        let generator_id = generator.id;
+        let mut repo = MockNameGeneratorRepo::new();
+        repo.expect_get()
+            .returning(move |_| Ok(Some(generator.clone())));
+        repo.expect_delete().never();
+
+        let result = names(repo, MockCharacterRepo::new())
+            .delete(WorldId::new(), generator_id)
+            .await;
+
+        assert!(matches!(result, Err(NameGeneratorError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn regions_without_a_generator_get_no_name() {
+        let world_id = WorldId::new();
+        let mut repo = MockNameGeneratorRepo::new();
+        repo.expect_list_for_world().returning(move |_| {
+            Ok(vec![
+                villagers(world_id).with_assignments(vec![RegionId::new()], vec![])
+            ])
+        });
+
+        let name = names(repo, MockCharacterRepo::new())
+            .name_for_region(world_id, RegionId::new())
+            .await
+            .expect("lookup");
+
+        assert_eq!(name, None);
+    }
+}
@@ -233,7 +233,7 @@ pub enum NarrativeEventError {
     Repo(#[from] RepoError),
 }
 
-fn narrative_event_to_json(event: &NarrativeEvent) -> Value {
+pub fn narrative_event_to_json(event: &NarrativeEvent) -> Value {
     serde_json::json!({
         "id": event.id.to_string(),
         "world_id": event.world_id.to_string(),
@@ -13,7 +13,7 @@ use wrldbldr_domain::{
 use crate::entities::{
     Challenge, Character, Flag, Inventory, Narrative, Observation, PlayerCharacter, Scene, World,
 };
-use crate::infrastructure::ports::ClockPort;
+use crate::infrastructure::ports::{ClockPort, PluginCall, PluginCallKind, PluginPort};
 
 /// Result of executing a single effect.
 #[derive(Debug, Clone)]
@@ -67,6 +67,7 @@ pub struct EffectExecutionContext {
 /// - ModifyStat via PlayerCharacter
 /// - TriggerScene via Scene
 /// - SetFlag via Flag
+/// - Plugin via the plugin host (whose output effects are applied in turn)
 pub struct ExecuteEffects {
     inventory: Arc<Inventory>,
     challenge: Arc<Challenge>,
@@ -78,6 +79,7 @@ pub struct ExecuteEffects {
     flag: Arc<Flag>,
     world: Arc<World>,
     clock: Arc<dyn ClockPort>,
+    plugins: Arc<dyn PluginPort>,
 }
 
 impl ExecuteEffects {
@@ -92,6 +94,7 @@ impl ExecuteEffects {
         flag: Arc<Flag>,
         world: Arc<World>,
         clock: Arc<dyn ClockPort>,
+        plugins: Arc<dyn PluginPort>,
     ) -> Self {
         Self {
             inventory,
@@ -104,6 +107,7 @@ impl ExecuteEffects {
             flag,
             world,
             clock,
+            plugins,
         }
     }
 
@@ -164,7 +168,7 @@ impl ExecuteEffects {
     }
 
     /// Execute a single effect.
-    async fn execute_single_effect(
+    pub async fn execute_single_effect(
         &self,
         effect: &EventEffect,
         context: &EffectExecutionContext,
@@ -419,6 +423,88 @@ impl ExecuteEffects {
                 error: None,
                 requires_dm_action: *requires_dm_action,
             },
+
+            EventEffect::Plugin {
+                plugin,
+                effect,
+                params,
+            } => {
+                self.execute_plugin(
+                    plugin,
+                    PluginCall {
+                        kind: PluginCallKind::Effect,
+                        name: effect.clone(),
+                        arguments: params.clone(),
+                        world_id: context.world_id,
+                        pc_id: Some(context.pc_id),
+                    },
+                    context,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Run a plugin tool or effect and apply the effects it returns.
+    ///
+    /// Output is all-or-nothing: if the plugin returns any effect its
+    /// manifest has no capability for, none of them are applied.
+    pub async fn execute_plugin(
+        &self,
+        plugin: &str,
+        call: PluginCall,
+        context: &EffectExecutionContext,
+    ) -> EffectExecutionResult {
+        let label = format!("{} {}/{}", call.kind, plugin, call.name);
+        let failed = |error: String| EffectExecutionResult {
+            description: format!("Failed to run plugin {}", label),
+            success: false,
+            error: Some(error),
+            requires_dm_action: false,
+        };
+
+        let Some(manifest) = self
+            .plugins
+            .manifests()
+            .into_iter()
+            .find(|manifest| manifest.name == plugin)
+        else {
+            return failed(format!("Plugin {} is not loaded", plugin));
+        };
+        let output = match self.plugins.call(plugin, call).await {
+            Ok(output) => output,
+            Err(e) => return failed(e.to_string()),
+        };
+        let denied = manifest.denied_effects(&output);
+        if !denied.is_empty() {
+            tracing::warn!(
+                plugin = %plugin,
+                denied = ?denied,
+                "Plugin returned effects outside its capabilities"
+            );
+            return failed(format!(
+                "Plugin returned {} effect(s) outside its declared capabilities",
+                denied.len()
+            ));
+        }
+
+        // Plugin effects are denied above, so this never recurses further
+        let mut results = Vec::with_capacity(output.effects.len());
+        for effect in &output.effects {
+            results.push(Box::pin(self.execute_single_effect(effect, context)).await);
+        }
+
+        let mut description = format!("Plugin {}: {}", label, output.summary);
+        for result in &results {
+            description.push_str("; ");
+            description.push_str(&result.description);
+        }
+        let errors: Vec<String> = results.iter().filter_map(|r| r.error.clone()).collect();
+        EffectExecutionResult {
+            description,
+            success: results.iter().all(|r| r.success),
+            error: (!errors.is_empty()).then(|| errors.join("; ")),
+            requires_dm_action: results.iter().any(|r| r.requires_dm_action),
         }
     }
 
@@ -974,11 +1060,8 @@ impl ExecuteEffects {
     }
 
     /// Add gold/currency reward using system-appropriate field name.
-    /// Different game systems track currency differently:
-    /// - D&D 5e, Pathfinder 2e: GP (gold pieces)
-    /// - Blades in the Dark: COIN
-    /// - Call of Cthulhu: SPENDING_LEVEL or specific currency
-    /// - FATE Core, PbtA: Usually narrative-based (no currency tracking)
+    /// The field comes from `RuleSystemVariant::currency_field` (GP, COIN, ...);
+    /// systems that don't track currency leave the reward for the DM.
     async fn execute_add_gold_reward_system_aware(
         &self,
         pc_id: PlayerCharacterId,
@@ -1008,21 +1091,14 @@ impl ExecuteEffects {
             }
         };
 
-        // Map system variant to currency field name
-        match world.rule_system.variant {
-            RuleSystemVariant::Dnd5e
-            | RuleSystemVariant::Pathfinder2e
-            | RuleSystemVariant::GenericD20 => {
-                self.execute_add_stat_reward(pc_id, "GP", amount, description)
-                    .await
-            }
-
-            RuleSystemVariant::BladesInTheDark => {
-                self.execute_add_stat_reward(pc_id, "COIN", amount, description)
+        let variant = &world.rule_system.variant;
+        match variant.currency_field() {
+            Some(field) => {
+                self.execute_add_stat_reward(pc_id, field, amount, description)
                     .await
             }
 
-            RuleSystemVariant::CallOfCthulhu7e => {
+            None if *variant == RuleSystemVariant::CallOfCthulhu7e => {
                 // CoC uses Credit Rating and spending, not direct gold
                 EffectExecutionResult {
                     description: format!(
@@ -1036,7 +1112,7 @@ impl ExecuteEffects {
             }
 
             // Narrative systems typically don't track currency
-            RuleSystemVariant::FateCore | RuleSystemVariant::PoweredByApocalypse => {
+            None => {
                 EffectExecutionResult {
                     description: format!(
                         "Narrative system typically doesn't track currency. {} gold/coins noted for DM.",
@@ -1047,16 +1123,6 @@ impl ExecuteEffects {
                     requires_dm_action: true,
                 }
             }
-
-            // Other systems - use generic GP field
-            RuleSystemVariant::KidsOnBikes
-            | RuleSystemVariant::RuneQuest
-            | RuleSystemVariant::GenericD100
-            | RuleSystemVariant::Custom(_)
-            | RuleSystemVariant::Unknown => {
-                self.execute_add_stat_reward(pc_id, "GP", amount, description)
-                    .await
-            }
         }
     }
 }
@@ -10,7 +10,7 @@ pub use decision::{NarrativeDecisionFlow, NarrativeDecisionOutcome, NarrativeTri
 pub use execute_effects::{
     EffectExecutionContext, EffectExecutionResult, EffectExecutionSummary, ExecuteEffects,
 };
-pub use events::{NarrativeEventError, NarrativeEventOps, TriggeredNarrativeEvent};
+pub use events::{narrative_event_to_json, NarrativeEventError, NarrativeEventOps};
 
 use std::sync::Arc;
 
@@ -0,0 +1,843 @@
+//! NPC promotion use cases.
+//!
+//! Promoting a crowd member, or a name mentioned in dialogue or lore, makes
+//! an `NpcDraft` right away and leaves the LLM to fill it in from where the
+//! NPC came from: the crowd's region and mood, the line they were mentioned
+//! in, or the lore entry. Generation runs in the background; a failure
+//! leaves the draft pending with the error, for the DM to fill in by hand.
+//! A crowd member promoted without a name takes one from the region's name
+//! generator, when the world has one that fits. Session zero seeds drafts
+//! from a concept for the world's starting region.
+//! Approving a draft creates the character; a crowd member keeps
+//! frequenting the crowd's region, and the crowd is one smaller. A session
+//! zero seed frequents the starting region.
+
+use std::sync::Arc;
+
+use serde::Deserialize;
+use wrldbldr_domain::{
+    CampbellArchetype, Character, Crowd, CrowdId, DispositionLevel, DomainError, LlmTask, LoreId,
+    NpcDraft, NpcDraftDetails, NpcDraftId, NpcDraftSource, NpcDraftStatus, RegionId, WorldId,
+};
+use wrldbldr_protocol::types::{
+    NpcDraftData, NpcDraftInputData, NpcDraftSourceData, NpcDraftStatusData,
+};
+
+use crate::entities;
+use crate::infrastructure::ports::{ChatMessage, ClockPort, LlmPort, LlmRequest, RepoError};
+use crate::use_cases::ai::structured::generate_validated;
+use crate::use_cases::names::{ManageNameGenerators, NameGeneratorError};
+
+/// Container for NPC promotion use cases.
+pub struct NpcDraftUseCases {
+    pub manage: Arc<ManageNpcDrafts>,
+}
+
+impl NpcDraftUseCases {
+    pub fn new(manage: Arc<ManageNpcDrafts>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum NpcDraftError {
+    #[error("NPC draft not found")]
+    NotFound,
+    #[error("{0} not found")]
+    SourceNotFound(&'static str),
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for NpcDraftError {
+    fn from(e: DomainError) -> Self {
+        NpcDraftError::Invalid(e.to_string())
+    }
+}
+
+/// The LLM's reply, before it's checked against the domain
+#[derive(Debug, Deserialize)]
+struct GeneratedNpc {
+    name: String,
+    description: String,
+    archetype: String,
+    disposition: String,
+}
+
+/// An approved draft: the new character, and the crowd it left if it came
+/// from one
+pub struct ApprovedNpc {
+    pub character: Character,
+    pub crowd: Option<Crowd>,
+}
+
+/// Promote, generate, edit, approve and reject NPC drafts.
+pub struct ManageNpcDrafts {
+    npc_draft: Arc<entities::NpcDraft>,
+    crowd: Arc<entities::Crowd>,
+    location: Arc<entities::Location>,
+    lore: Arc<entities::Lore>,
+    character: Arc<entities::Character>,
+    world: Arc<entities::World>,
+    settings: Arc<entities::Settings>,
+    names: Arc<ManageNameGenerators>,
+    llm: Arc<dyn LlmPort>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageNpcDrafts {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        npc_draft: Arc<entities::NpcDraft>,
+        crowd: Arc<entities::Crowd>,
+        location: Arc<entities::Location>,
+        lore: Arc<entities::Lore>,
+        character: Arc<entities::Character>,
+        world: Arc<entities::World>,
+        settings: Arc<entities::Settings>,
+        names: Arc<ManageNameGenerators>,
+        llm: Arc<dyn LlmPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            npc_draft,
+            crowd,
+            location,
+            lore,
+            character,
+            world,
+            settings,
+            names,
+            llm,
+            clock,
+        }
+    }
+
+    pub async fn list(&self, world_id: WorldId) -> Result<Vec<NpcDraftData>, NpcDraftError> {
+        let drafts = self.npc_draft.list_for_world(world_id).await?;
+        let mut data = Vec::with_capacity(drafts.len());
+        for draft in &drafts {
+            data.push(self.to_protocol(draft).await);
+        }
+        Ok(data)
+    }
+
+    /// Start a draft from a crowd or a mentioned name; it still needs generating
+    pub async fn promote(
+        &self,
+        world_id: WorldId,
+        source: NpcDraftSourceData,
+        name: Option<String>,
+    ) -> Result<NpcDraft, NpcDraftError> {
+        let source = source_from_protocol(source)?;
+        let mut name = name.filter(|n| !n.trim().is_empty());
+        match &source {
+            NpcDraftSource::Crowd { crowd_id } => {
+                let crowd = self.world_crowd(world_id, *crowd_id).await?;
+                if name.is_none() {
+                    name = self
+                        .names
+                        .name_for_region(world_id, crowd.region_id)
+                        .await
+                        .map_err(|e| match e {
+                            NameGeneratorError::Repo(e) => NpcDraftError::Repo(e),
+                            other => NpcDraftError::Invalid(other.to_string()),
+                        })?;
+                }
+            }
+            NpcDraftSource::Lore { lore_id } => {
+                self.world_lore(world_id, *lore_id).await?;
+            }
+            NpcDraftSource::SessionZero { region_id, .. } => {
+                self.world_region(world_id, *region_id).await?;
+            }
+            NpcDraftSource::Dialogue { .. } => {}
+        }
+        let draft = NpcDraft::new(world_id, source, name.as_deref(), self.clock.now())?;
+        self.npc_draft.save(&draft).await?;
+        Ok(draft)
+    }
+
+    /// Fill a generating draft in with the LLM.
+    ///
+    /// Only repository failures are errors; a failed generation is recorded
+    /// on the draft.
+    pub async fn generate(&self, draft_id: NpcDraftId) -> Result<NpcDraft, NpcDraftError> {
+        let mut draft = self
+            .npc_draft
+            .get(draft_id)
+            .await?
+            .ok_or(NpcDraftError::NotFound)?;
+        if !draft.is_generating() {
+            return Ok(draft);
+        }
+
+        let generated = match self.source_context(&draft).await {
+            Ok(context) => self.generate_details(&draft, &context).await,
+            Err(e) => Err(e.to_string()),
+        };
+        let now = self.clock.now();
+        match generated.and_then(|details| draft.fill(details, now).map_err(|e| e.to_string())) {
+            Ok(()) => {}
+            Err(reason) => {
+                tracing::warn!(draft_id = %draft.id, reason = %reason, "NPC draft generation failed");
+                draft.generation_failed(reason, now);
+            }
+        }
+        self.npc_draft.save(&draft).await?;
+        Ok(draft)
+    }
+
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        draft_id: NpcDraftId,
+        input: NpcDraftInputData,
+    ) -> Result<NpcDraft, NpcDraftError> {
+        let mut draft = self.world_draft(world_id, draft_id).await?;
+        let details = NpcDraftDetails {
+            name: input.name,
+            description: input.description,
+            archetype: input.archetype.parse().unwrap_or_default(),
+            disposition: input.disposition,
+        };
+        draft.edit(details, self.clock.now())?;
+        self.npc_draft.save(&draft).await?;
+        Ok(draft)
+    }
+
+    /// Put a draft back to generating; call `generate` to fill it in again
+    pub async fn regenerate(
+        &self,
+        world_id: WorldId,
+        draft_id: NpcDraftId,
+    ) -> Result<NpcDraft, NpcDraftError> {
+        let mut draft = self.world_draft(world_id, draft_id).await?;
+        draft.regenerate(self.clock.now())?;
+        self.npc_draft.save(&draft).await?;
+        Ok(draft)
+    }
+
+    /// Turn a draft into a character and drop the draft
+    pub async fn approve(
+        &self,
+        world_id: WorldId,
+        draft_id: NpcDraftId,
+    ) -> Result<ApprovedNpc, NpcDraftError> {
+        let draft = self.world_draft(world_id, draft_id).await?;
+        let character = draft.to_character()?;
+        // A crowd that has gone since promotion just means no region tie
+        let crowd = match &draft.source {
+            NpcDraftSource::Crowd { crowd_id } => self
+                .crowd
+                .get(*crowd_id)
+                .await?
+                .filter(|c| c.world_id == world_id),
+            _ => None,
+        };
+
+        self.character.save(&character).await?;
+        let crowd = match crowd {
+            Some(mut crowd) => {
+                self.character
+                    .add_frequents_region(character.id, crowd.region_id, "often".to_string(), None)
+                    .await?;
+                if crowd.lose_member(self.clock.now()) {
+                    self.crowd.save(&crowd).await?;
+                }
+                Some(crowd)
+            }
+            None => None,
+        };
+        if let NpcDraftSource::SessionZero { region_id, .. } = &draft.source {
+            self.character
+                .add_frequents_region(character.id, *region_id, "often".to_string(), None)
+                .await?;
+        }
+        self.npc_draft.delete(draft.id).await?;
+
+        Ok(ApprovedNpc { character, crowd })
+    }
+
+    pub async fn reject(
+        &self,
+        world_id: WorldId,
+        draft_id: NpcDraftId,
+    ) -> Result<(), NpcDraftError> {
+        let draft = self.world_draft(world_id, draft_id).await?;
+        self.npc_draft.delete(draft.id).await?;
+        Ok(())
+    }
+
+    /// Protocol form of a draft, with a short note of where it came from
+    pub async fn to_protocol(&self, draft: &NpcDraft) -> NpcDraftData {
+        let source_label = match &draft.source {
+            NpcDraftSource::Crowd { crowd_id } => match self.crowd.get(*crowd_id).await {
+                Ok(Some(crowd)) => format!("One of the {}", crowd.name),
+                _ => "From a crowd".to_string(),
+            },
+            NpcDraftSource::Dialogue { .. } => "Mentioned in dialogue".to_string(),
+            NpcDraftSource::Lore { lore_id } => match self.lore.get(*lore_id).await {
+                Ok(Some(lore)) => format!("Mentioned in \"{}\"", lore.title),
+                _ => "Mentioned in lore".to_string(),
+            },
+            NpcDraftSource::SessionZero { .. } => "Seeded at session zero".to_string(),
+        };
+        npc_draft_to_protocol(draft, source_label)
+    }
+
+    async fn world_draft(
+        &self,
+        world_id: WorldId,
+        draft_id: NpcDraftId,
+    ) -> Result<NpcDraft, NpcDraftError> {
+        self.npc_draft
+            .get(draft_id)
+            .await?
+            .filter(|d| d.world_id == world_id)
+            .ok_or(NpcDraftError::NotFound)
+    }
+
+    async fn world_crowd(
+        &self,
+        world_id: WorldId,
+        crowd_id: CrowdId,
+    ) -> Result<Crowd, NpcDraftError> {
+        self.crowd
+            .get(crowd_id)
+            .await?
+            .filter(|c| c.world_id == world_id)
+            .ok_or(NpcDraftError::SourceNotFound("Crowd"))
+    }
+
+    async fn world_region(
+        &self,
+        world_id: WorldId,
+        region_id: RegionId,
+    ) -> Result<wrldbldr_domain::Region, NpcDraftError> {
+        let region = self
+            .location
+            .get_region(region_id)
+            .await?
+            .ok_or(NpcDraftError::SourceNotFound("Region"))?;
+        self.location
+            .get(region.location_id)
+            .await?
+            .filter(|l| l.world_id == world_id)
+            .ok_or(NpcDraftError::SourceNotFound("Region"))?;
+        Ok(region)
+    }
+
+    async fn world_lore(
+        &self,
+        world_id: WorldId,
+        lore_id: LoreId,
+    ) -> Result<wrldbldr_domain::Lore, NpcDraftError> {
+        self.lore
+            .get(lore_id)
+            .await?
+            .filter(|l| l.world_id == world_id)
+            .ok_or(NpcDraftError::SourceNotFound("Lore"))
+    }
+
+    /// What the LLM should keep the NPC consistent with
+    async fn source_context(&self, draft: &NpcDraft) -> Result<String, NpcDraftError> {
+        let mut context = String::new();
+        if let Some(world) = self.world.get(draft.world_id).await? {
+            context.push_str(&format!("World: {}\n{}\n\n", world.name, world.description));
+        }
+
+        match &draft.source {
+            NpcDraftSource::Crowd { crowd_id } => {
+                let crowd = self.world_crowd(draft.world_id, *crowd_id).await?;
+                let mut place = String::new();
+                if let Some(region) = self.location.get_region(crowd.region_id).await? {
+                    place = region.name.clone();
+                    if let Some(location) = self.location.get(region.location_id).await? {
+                        place = format!("{} in {}", region.name, location.name);
+                    }
+                }
+                context.push_str(&format!(
+                    "This NPC is one of a crowd: {} ({}) at {}. The crowd is {} toward strangers.\n",
+                    crowd.name,
+                    crowd.size_label(),
+                    place,
+                    crowd.disposition.display_name().to_lowercase(),
+                ));
+                if !crowd.description.is_empty() {
+                    context.push_str(&format!("About the crowd: {}\n", crowd.description));
+                }
+                if draft.name.is_empty() {
+                    context.push_str(
+                        "Make them an individual who plausibly belongs to this crowd, \
+                         with a name of their own.\n",
+                    );
+                } else {
+                    context.push_str(&format!(
+                        "Their name is {}. Make them an individual who plausibly belongs \
+                         to this crowd.\n",
+                        draft.name
+                    ));
+                }
+            }
+            NpcDraftSource::Dialogue { excerpt } => {
+                context.push_str(&format!(
+                    "{} was mentioned in this dialogue:\n\"{}\"\n\
+                     Keep everything the dialogue says or implies about them.\n",
+                    draft.name, excerpt
+                ));
+            }
+            NpcDraftSource::Lore { lore_id } => {
+                let lore = self.world_lore(draft.world_id, *lore_id).await?;
+                context.push_str(&format!(
+                    "{} is mentioned in the lore entry \"{}\": {}\n",
+                    draft.name, lore.title, lore.summary
+                ));
+                for chunk in &lore.chunks {
+                    context.push_str(&format!("{}\n", chunk.content));
+                }
+                context.push_str("Keep everything the lore says or implies about them.\n");
+            }
+            NpcDraftSource::SessionZero { region_id, concept } => {
+                let region = self.world_region(draft.world_id, *region_id).await?;
+                let mut place = region.name.clone();
+                if let Some(location) = self.location.get(region.location_id).await? {
+                    place = format!("{} in {}", region.name, location.name);
+                }
+                context.push_str(&format!(
+                    "This NPC is in the campaign's starting region, {}. The group \
+                     asked for: {}\n",
+                    place, concept
+                ));
+                if draft.name.is_empty() {
+                    context.push_str("Give them a name of their own.\n");
+                } else {
+                    context.push_str(&format!("Their name is {}.\n", draft.name));
+                }
+            }
+        }
+        Ok(context)
+    }
+
+    async fn generate_details(
+        &self,
+        draft: &NpcDraft,
+        context: &str,
+    ) -> Result<NpcDraftDetails, String> {
+        let world = self.world.get(draft.world_id).await.ok().flatten();
+        let safety_constraints = world
+            .map(|w| w.content_safety.prompt_constraints())
+            .unwrap_or_default();
+        let model = self
+            .settings
+            .get_for_world(draft.world_id)
+            .await
+            .ok()
+            .and_then(|s| s.model_for(LlmTask::Suggestion));
+
+        let system_prompt = "You are a worldbuilding assistant for a TTRPG. \
+            Flesh out one NPC as a JSON object with 'name', 'description' (2-4 sentences: \
+            appearance, occupation, manner and a hook for play), 'archetype' and \
+            'disposition' (how they treat strangers). Stay consistent with the context.";
+        let request = LlmRequest::new(vec![ChatMessage::user(context)])
+            .with_system_prompt(format!("{}\n\n{}", system_prompt, safety_constraints))
+            .with_temperature(0.8)
+            .with_model(model)
+            .with_world(draft.world_id);
+
+        generate_validated(self.llm.as_ref(), request, npc_schema(), |value| {
+            let reply: GeneratedNpc =
+                serde_json::from_value(value.clone()).map_err(|e| e.to_string())?;
+            Ok(NpcDraftDetails {
+                name: reply.name,
+                description: reply.description,
+                archetype: reply.archetype.parse()?,
+                disposition: reply.disposition.parse()?,
+            })
+        })
+        .await
+        .map_err(|e| e.to_string())
+    }
+}
+
+/// Schema for the LLM's reply; archetype and disposition are limited to
+/// the domain's values.
+fn npc_schema() -> serde_json::Value {
+    let archetypes: Vec<String> = CampbellArchetype::all()
+        .iter()
+        .map(|a| a.to_string())
+        .collect();
+    let dispositions: Vec<String> = DispositionLevel::all()
+        .iter()
+        .map(|d| d.to_string())
+        .collect();
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string", "minLength": 1 },
+            "description": { "type": "string" },
+            "archetype": { "type": "string", "enum": archetypes },
+            "disposition": { "type": "string", "enum": dispositions }
+        },
+        "required": ["name", "description", "archetype", "disposition"]
+    })
+}
+
+fn source_from_protocol(source: NpcDraftSourceData) -> Result<NpcDraftSource, NpcDraftError> {
+    let invalid = |what: &str| NpcDraftError::Invalid(format!("Invalid {} ID", what));
+    match source {
+        NpcDraftSourceData::Crowd { crowd_id } => Ok(NpcDraftSource::Crowd {
+            crowd_id: CrowdId::from_uuid(crowd_id.parse().map_err(|_| invalid("crowd"))?),
+        }),
+        NpcDraftSourceData::Dialogue { excerpt } => Ok(NpcDraftSource::Dialogue { excerpt }),
+        NpcDraftSourceData::Lore { lore_id } => Ok(NpcDraftSource::Lore {
+            lore_id: LoreId::from_uuid(lore_id.parse().map_err(|_| invalid("lore"))?),
+        }),
+        NpcDraftSourceData::SessionZero { region_id, concept } => {
+            Ok(NpcDraftSource::SessionZero {
+                region_id: RegionId::from_uuid(region_id.parse().map_err(|_| invalid("region"))?),
+                concept,
+            })
+        }
+        NpcDraftSourceData::Unknown => {
+            Err(NpcDraftError::Invalid("Unknown NPC source".to_string()))
+        }
+    }
+}
+
+pub(crate) fn npc_draft_to_protocol(draft: &NpcDraft, source_label: String) -> NpcDraftData {
+    let source = match &draft.source {
+        NpcDraftSource::Crowd { crowd_id } => NpcDraftSourceData::Crowd {
+            crowd_id: crowd_id.to_string(),
+        },
+        NpcDraftSource::Dialogue { excerpt } => NpcDraftSourceData::Dialogue {
+            excerpt: excerpt.clone(),
+        },
+        NpcDraftSource::Lore { lore_id } => NpcDraftSourceData::Lore {
+            lore_id: lore_id.to_string(),
+        },
+        NpcDraftSource::SessionZero { region_id, concept } => NpcDraftSourceData::SessionZero {
+            region_id: region_id.to_string(),
+            concept: concept.clone(),
+        },
+    };
+    NpcDraftData {
+        id: draft.id.to_string(),
+        source,
+        source_label,
+        name: draft.name.clone(),
+        description: draft.description.clone(),
+        archetype: draft.archetype.to_string(),
+        disposition: draft.disposition,
+        status: match draft.status {
+            NpcDraftStatus::Generating => NpcDraftStatusData::Generating,
+            NpcDraftStatus::Pending => NpcDraftStatusData::Pending,
+        },
+        generation_error: draft.generation_error.clone(),
+        created_at: draft.created_at.to_rfc3339(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::{FixedClock, SeededRandom};
+    use crate::infrastructure::ports::{
+        FinishReason, LlmError, LlmResponse, MockCharacterRepo, MockCrowdRepo, MockLocationRepo,
+        MockLoreRepo, MockNameGeneratorRepo, MockNpcDraftRepo, MockSettingsRepo, MockWorldRepo,
+        ToolDefinition,
+    };
+    use async_trait::async_trait;
+    use wrldbldr_domain::{AppSettings, NameGenerator, NameStyle, RegionId};
+
+    /// LLM that always replies with the same text, or always fails
+    struct FixedLlm(Option<String>);
+
+    #[async_trait]
+    impl LlmPort for FixedLlm {
+        async fn generate(&self, _request: LlmRequest) -> Result<LlmResponse, LlmError> {
+            match &self.0 {
+                Some(content) => Ok(LlmResponse {
+                    content: content.clone(),
+                    tool_calls: vec![],
+                    finish_reason: FinishReason::Stop,
+                    usage: None,
+                }),
+                None => Err(LlmError::RequestFailed("offline".to_string())),
+            }
+        }
+
+        async fn generate_with_tools(
+            &self,
+            request: LlmRequest,
+            _tools: Vec<ToolDefinition>,
+        ) -> Result<LlmResponse, LlmError> {
+            self.generate(request).await
+        }
+    }
+
+    struct Repos {
+        drafts: MockNpcDraftRepo,
+        crowds: MockCrowdRepo,
+        characters: MockCharacterRepo,
+        name_generators: MockNameGeneratorRepo,
+        llm: FixedLlm,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            Self {
+                drafts: MockNpcDraftRepo::new(),
+                crowds: MockCrowdRepo::new(),
+                characters: MockCharacterRepo::new(),
+                name_generators: MockNameGeneratorRepo::new(),
+                llm: FixedLlm(None),
+            }
+        }
+
+        fn build(self) -> ManageNpcDrafts {
+            let mut locations = MockLocationRepo::new();
+            locations.expect_get_region().returning(|_| Ok(None));
+            let mut worlds = MockWorldRepo::new();
+            worlds.expect_get().returning(|_| Ok(None));
+            let mut settings = MockSettingsRepo::new();
+            settings
+                .expect_get_for_world()
+                .returning(|_| Ok(Some(AppSettings::default())));
+            let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(chrono::Utc::now()));
+            let location = Arc::new(entities::Location::new(Arc::new(locations)));
+            let character = Arc::new(entities::Character::new(Arc::new(self.characters)));
+            let names = Arc::new(ManageNameGenerators::new(
+                Arc::new(entities::NameGenerator::new(Arc::new(self.name_generators))),
+                character.clone(),
+                location.clone(),
+                Arc::new(SeededRandom::new(3)),
+                clock.clone(),
+            ));
+            ManageNpcDrafts::new(
+                Arc::new(entities::NpcDraft::new(Arc::new(self.drafts))),
+                Arc::new(entities::Crowd::new(Arc::new(self.crowds))),
+                location,
+                Arc::new(entities::Lore::new(Arc::new(MockLoreRepo::new()))),
+                character,
+                Arc::new(entities::World::new(Arc::new(worlds), clock.clone())),
+                Arc::new(entities::Settings::new(Arc::new(settings))),
+                names,
+                Arc::new(self.llm),
+                clock,
+            )
+        }
+    }
+
+    fn dock_workers(world_id: WorldId, count: u32) -> Crowd {
+        Crowd::new(
+            world_id,
+            RegionId::new(),
+            "dock workers",
+            count,
+            chrono::Utc::now(),
+        )
+        .expect("crowd")
+    }
+
+    fn crowd_member(world_id: WorldId, crowd_id: CrowdId) -> NpcDraft {
+        NpcDraft::new(
+            world_id,
+            NpcDraftSource::Crowd { crowd_id },
+            None,
+            chrono::Utc::now(),
+        )
+        .expect("draft")
+    }
+
+    #[tokio::test]
+    async fn crowds_in_other_worlds_cannot_be_promoted_from() {
+        let crowd = dock_workers(WorldId::new(), 40);
+        let crowd_id = crowd.id;
+        let mut repos = Repos::new();
+        repos
+            .crowds
+            .expect_get()
+            .returning(move |_| Ok(Some(crowd.clone())));
+        repos.drafts.expect_save().never();
+
+        let result = repos
+            .build()
+            .promote(
+                WorldId::new(),
+                NpcDraftSourceData::Crowd {
+                    crowd_id: crowd_id.to_string(),
+                },
+                None,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(NpcDraftError::SourceNotFound("Crowd"))
+        ));
+    }
+
+    #[tokio::test]
+    async fn promoted_crowd_members_are_named_by_their_region() {
+        let world_id = WorldId::new();
+        let crowd = dock_workers(world_id, 40);
+        let crowd_id = crowd.id;
+        let generator = NameGenerator::new(
+            world_id,
+            "Harbour folk",
+            NameStyle::List {
+                given_names: vec!["Maren".to_string()],
+                family_names: vec![],
+            },
+            chrono::Utc::now(),
+        )
+        .expect("generator")
+        .with_assignments(vec![crowd.region_id], vec![]);
+        let mut repos = Repos::new();
+        repos
+            .crowds
+            .expect_get()
+            .returning(move |_| Ok(Some(crowd.clone())));
+        repos
+            .name_generators
+            .expect_list_for_world()
+            .returning(move |_| Ok(vec![generator.clone()]));
+        repos
+            .characters
+            .expect_list_in_world()
+            .returning(|_| Ok(vec![]));
+        repos.drafts.expect_save().times(1).returning(|_| Ok(()));
+
+        let draft = repos
+            .build()
+            .promote(
+                world_id,
+                NpcDraftSourceData::Crowd {
+                    crowd_id: crowd_id.to_string(),
+                },
+                None,
+            )
+            .await
+            .expect("promoted");
+
+        assert_eq!(draft.name, "Maren");
+    }
+
+    #[tokio::test]
+    async fn generation_names_a_crowd_member_from_the_llm_reply() {
+        let world_id = WorldId::new();
+        let crowd = dock_workers(world_id, 40);
+        let draft = crowd_member(world_id, crowd.id);
+        let mut repos = Repos::new();
+        repos
+            .drafts
+            .expect_get()
+            .returning(move |_| Ok(Some(draft.clone())));
+        repos
+            .drafts
+            .expect_save()
+            .withf(|d| d.status == NpcDraftStatus::Pending && d.name == "Hesk")
+            .times(1)
+            .returning(|_| Ok(()));
+        repos
+            .crowds
+            .expect_get()
+            .returning(move |_| Ok(Some(crowd.clone())));
+        repos.llm = FixedLlm(Some(
+            r#"{"name": "Hesk", "description": "A stevedore with a bad knee.",
+                "archetype": "Threshold Guardian", "disposition": "Suspicious"}"#
+                .to_string(),
+        ));
+
+        let generated = repos
+            .build()
+            .generate(NpcDraftId::new())
+            .await
+            .expect("generate");
+
+        assert_eq!(generated.archetype, CampbellArchetype::ThresholdGuardian);
+        assert_eq!(generated.disposition, DispositionLevel::Suspicious);
+        assert!(generated.generation_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn a_failed_generation_is_kept_on_the_draft() {
+        let world_id = WorldId::new();
+        let crowd = dock_workers(world_id, 40);
+        let draft = crowd_member(world_id, crowd.id);
+        let mut repos = Repos::new();
+        repos
+            .drafts
+            .expect_get()
+            .returning(move |_| Ok(Some(draft.clone())));
+        repos
+            .drafts
+            .expect_save()
+            .withf(|d| !d.is_generating() && d.generation_error.is_some())
+            .times(1)
+            .returning(|_| Ok(()));
+        repos
+            .crowds
+            .expect_get()
+            .returning(move |_| Ok(Some(crowd.clone())));
+
+        let generated = repos
+            .build()
+            .generate(NpcDraftId::new())
+            .await
+            .expect("generate");
+
+        assert!(generated.name.is_empty());
+    }
+
+    #[tokio::test]
+    async fn approving_a_crowd_member_thins_the_crowd() {
+        let world_id = WorldId::new();
+        let crowd = dock_workers(world_id, 40);
+        let region_id = crowd.region_id;
+        let mut draft = crowd_member(world_id, crowd.id);
+        // Generation failed and the DM named them by hand
+        draft.generation_failed("offline", chrono::Utc::now());
+        draft.name = "Hesk".to_string();
+        let mut repos = Repos::new();
+        repos
+            .drafts
+            .expect_get()
+            .returning(move |_| Ok(Some(draft.clone())));
+        repos.drafts.expect_delete().times(1).returning(|_| Ok(()));
+        repos
+            .crowds
+            .expect_get()
+            .returning(move |_| Ok(Some(crowd.clone())));
+        repos
+            .crowds
+            .expect_save()
+            .withf(|c| c.approximate_count == 39)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos
+            .characters
+            .expect_save()
+            .withf(|c| c.name == "Hesk")
+            .times(1)
+            .returning(|_| Ok(()));
+        repos
+            .characters
+            .expect_add_frequents_region()
+            .withf(move |_, r, _, _| *r == region_id)
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let approved = repos
+            .build()
+            .approve(world_id, NpcDraftId::new())
+            .await
+            .expect("approve");
+
+        assert_eq!(approved.character.name, "Hesk");
+        assert_eq!(approved.crowd.map(|c| c.approximate_count), Some(39));
+    }
+}
@@ -0,0 +1,266 @@
+//! Pacing timer use cases.
+//!
+//! The DM can put a world's scene, or each player's turn, on a real-time
+//! clock. Timers are soft: they warn and say when time is up, but nothing
+//! stops. Like the directorial context they live in memory for the session
+//! and don't survive a restart.
+//!
+//! A background task calls [`PacingTimers::tick`] every second and
+//! broadcasts the timers that crossed a minute, their warning or their end;
+//! clients count down between broadcasts.
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use wrldbldr_domain::{
+    DomainError, PacingCue, PacingTimer, PacingTimerKind, PlayerCharacterId, WorldId,
+};
+use wrldbldr_protocol::types::{
+    PacingCueData, PacingTimerData, PacingTimerKindData, StartPacingTimerData,
+};
+
+use crate::infrastructure::ports::ClockPort;
+
+/// Container for pacing timer use cases.
+pub struct PacingUseCases {
+    pub timers: Arc<PacingTimers>,
+}
+
+impl PacingUseCases {
+    pub fn new(timers: Arc<PacingTimers>) -> Self {
+        Self { timers }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PacingError {
+    #[error("No pacing timer is running")]
+    NotRunning,
+    #[error("{0}")]
+    Invalid(String),
+}
+
+impl From<DomainError> for PacingError {
+    fn from(e: DomainError) -> Self {
+        PacingError::Invalid(e.to_string())
+    }
+}
+
+/// A timer and what the table was last told about it
+struct TrackedTimer {
+    timer: PacingTimer,
+    cue: PacingCue,
+    minutes_left: u32,
+}
+
+/// Run each world's pacing timer.
+pub struct PacingTimers {
+    clock: Arc<dyn ClockPort>,
+    timers: Mutex<HashMap<WorldId, TrackedTimer>>,
+}
+
+impl PacingTimers {
+    pub fn new(clock: Arc<dyn ClockPort>) -> Self {
+        Self {
+            clock,
+            timers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn get(&self, world_id: WorldId) -> Option<PacingTimerData> {
+        let now = self.clock.now();
+        self.lock()
+            .get(&world_id)
+            .map(|tracked| timer_to_protocol(&tracked.timer, now))
+    }
+
+    /// Start a timer, replacing the world's running one
+    pub fn start(
+        &self,
+        world_id: WorldId,
+        data: StartPacingTimerData,
+    ) -> Result<PacingTimerData, PacingError> {
+        let kind = match data.kind {
+            PacingTimerKindData::Scene => PacingTimerKind::Scene,
+            PacingTimerKindData::Turn => PacingTimerKind::Turn,
+            PacingTimerKindData::Unknown => {
+                return Err(PacingError::Invalid("Unknown timer kind".to_string()))
+            }
+        };
+        let now = self.clock.now();
+        let timer = PacingTimer::start(kind, data.minutes, data.warning_minutes, data.label, now)?;
+        let started = timer_to_protocol(&timer, now);
+        self.lock().insert(world_id, Self::track(timer, now));
+        Ok(started)
+    }
+
+    pub fn pause(&self, world_id: WorldId) -> Result<PacingTimerData, PacingError> {
+        self.update(world_id, |timer, now| {
+            timer.pause(now);
+            Ok(())
+        })
+    }
+
+    pub fn resume(&self, world_id: WorldId) -> Result<PacingTimerData, PacingError> {
+        self.update(world_id, |timer, now| {
+            timer.resume(now);
+            Ok(())
+        })
+    }
+
+    pub fn extend(&self, world_id: WorldId, minutes: u32) -> Result<PacingTimerData, PacingError> {
+        self.update(world_id, |timer, now| timer.extend(minutes, now))
+    }
+
+    pub fn stop(&self, world_id: WorldId) -> Result<(), PacingError> {
+        self.lock()
+            .remove(&world_id)
+            .map(|_| ())
+            .ok_or(PacingError::NotRunning)
+    }
+
+    /// A player acted: a turn timer starts over for them. Returns the timer
+    /// if it restarted.
+    pub fn player_acted(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Option<PacingTimerData> {
+        let now = self.clock.now();
+        let mut timers = self.lock();
+        let tracked = timers.get_mut(&world_id)?;
+        if tracked.timer.kind != PacingTimerKind::Turn {
+            return None;
+        }
+        tracked.timer.restart(now);
+        tracked.timer.pc_id = Some(pc_id);
+        *tracked = Self::track(tracked.timer.clone(), now);
+        Some(timer_to_protocol(&tracked.timer, now))
+    }
+
+    /// The timers that crossed a minute, their warning or their end since
+    /// the last tick
+    pub fn tick(&self) -> Vec<(WorldId, PacingTimerData)> {
+        let now = self.clock.now();
+        let mut changed = Vec::new();
+        for (world_id, tracked) in self.lock().iter_mut() {
+            let cue = tracked.timer.cue(now);
+            let minutes_left = tracked.timer.remaining_secs(now).div_ceil(60);
+            if cue != tracked.cue || minutes_left != tracked.minutes_left {
+                tracked.cue = cue;
+                tracked.minutes_left = minutes_left;
+                changed.push((*world_id, timer_to_protocol(&tracked.timer, now)));
+            }
+        }
+        changed
+    }
+
+    fn update(
+        &self,
+        world_id: WorldId,
+        change: impl FnOnce(&mut PacingTimer, DateTime<Utc>) -> Result<(), DomainError>,
+    ) -> Result<PacingTimerData, PacingError> {
+        let now = self.clock.now();
+        let mut timers = self.lock();
+        let tracked = timers.get_mut(&world_id).ok_or(PacingError::NotRunning)?;
+        change(&mut tracked.timer, now)?;
+        *tracked = Self::track(tracked.timer.clone(), now);
+        Ok(timer_to_protocol(&tracked.timer, now))
+    }
+
+    /// What the table is told about a timer as of now, so the next tick
+    /// only reports later changes
+    fn track(timer: PacingTimer, now: DateTime<Utc>) -> TrackedTimer {
+        TrackedTimer {
+            cue: timer.cue(now),
+            minutes_left: timer.remaining_secs(now).div_ceil(60),
+            timer,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<WorldId, TrackedTimer>> {
+        self.timers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+pub fn timer_to_protocol(timer: &PacingTimer, now: DateTime<Utc>) -> PacingTimerData {
+    PacingTimerData {
+        kind: match timer.kind {
+            PacingTimerKind::Scene => PacingTimerKindData::Scene,
+            PacingTimerKind::Turn => PacingTimerKindData::Turn,
+        },
+        label: timer.label.clone(),
+        duration_secs: timer.duration_secs,
+        warning_secs: timer.warning_secs,
+        remaining_secs: timer.remaining_secs(now),
+        cue: match timer.cue(now) {
+            PacingCue::Running => PacingCueData::Running,
+            PacingCue::Paused => PacingCueData::Paused,
+            PacingCue::Warning => PacingCueData::Warning,
+            PacingCue::Expired => PacingCueData::Expired,
+        },
+        pc_id: timer.pc_id.map(|id| id.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ports::MockClockPort;
+    use chrono::Duration;
+
+    #[test]
+    fn ticks_report_minutes_warnings_and_the_end() {
+        let start = Utc::now();
+        let elapsed = Arc::new(Mutex::new(0i64));
+        let mut clock = MockClockPort::new();
+        let clock_elapsed = elapsed.clone();
+        clock
+            .expect_now()
+            .returning(move || start + Duration::seconds(*clock_elapsed.lock().unwrap()));
+        let timers = PacingTimers::new(Arc::new(clock));
+        let world_id = WorldId::new();
+        let advance_to = |secs: i64| *elapsed.lock().unwrap() = secs;
+
+        let started = timers
+            .start(
+                world_id,
+                StartPacingTimerData {
+                    kind: PacingTimerKindData::Turn,
+                    minutes: 3,
+                    warning_minutes: 1,
+                    label: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(started.remaining_secs, 180);
+        assert!(timers.tick().is_empty());
+
+        advance_to(30);
+        assert!(timers.tick().is_empty());
+        advance_to(61);
+        let ticked = timers.tick();
+        assert_eq!(ticked[0].1.remaining_secs, 119);
+        assert_eq!(ticked[0].1.cue, PacingCueData::Running);
+        advance_to(125);
+        assert_eq!(timers.tick()[0].1.cue, PacingCueData::Warning);
+        advance_to(200);
+        assert_eq!(timers.tick()[0].1.cue, PacingCueData::Expired);
+        advance_to(260);
+        assert!(timers.tick().is_empty());
+
+        // A player acting starts the turn over
+        let pc_id = PlayerCharacterId::new();
+        let restarted = timers.player_acted(world_id, pc_id).unwrap();
+        assert_eq!(restarted.remaining_secs, 180);
+        assert_eq!(restarted.pc_id, Some(pc_id.to_string()));
+        assert!(timers.tick().is_empty());
+
+        timers.stop(world_id).unwrap();
+        assert!(matches!(
+            timers.pause(world_id),
+            Err(PacingError::NotRunning)
+        ));
+    }
+}
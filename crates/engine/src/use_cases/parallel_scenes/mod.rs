@@ -0,0 +1,284 @@
+//! Parallel scene use cases.
+//!
+//! When the party splits, the DM runs each group as its own scene. A scene
+//! holds its PCs and the notes and tone that steer its NPC responses; the
+//! websocket layer routes each scene's dialogue and rolls to its own
+//! players only, and labels its approvals so the DM can tell the queues
+//! apart. The DM focuses one scene at a time. PCs outside every scene play
+//! at the main table, and merging puts everyone back there.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    DomainError, ParallelScene, ParallelSceneId, PlayerCharacterId, World, WorldId,
+};
+use wrldbldr_protocol::types::{ParallelSceneData, ParallelSceneInputData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for parallel scene use cases.
+pub struct ParallelSceneUseCases {
+    pub manage: Arc<ManageParallelScenes>,
+}
+
+impl ParallelSceneUseCases {
+    pub fn new(manage: Arc<ManageParallelScenes>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParallelSceneError {
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("Parallel scene not found")]
+    NotFound,
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for ParallelSceneError {
+    fn from(e: DomainError) -> Self {
+        match e {
+            DomainError::NotFound { .. } => ParallelSceneError::NotFound,
+            e => ParallelSceneError::Invalid(e.to_string()),
+        }
+    }
+}
+
+/// A world's parallel scenes after a change, for routing and broadcasting
+#[derive(Debug, Clone)]
+pub struct PartySplit {
+    pub scenes: Vec<ParallelScene>,
+    pub focused_scene_id: Option<ParallelSceneId>,
+}
+
+impl PartySplit {
+    fn of(world: &World) -> Self {
+        Self {
+            scenes: world.parallel_scenes.clone(),
+            focused_scene_id: world.focused_scene_id,
+        }
+    }
+}
+
+/// Split the party into scenes, regroup it, switch focus and merge it.
+pub struct ManageParallelScenes {
+    world: Arc<entities::World>,
+    player_character: Arc<entities::PlayerCharacter>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageParallelScenes {
+    pub fn new(
+        world: Arc<entities::World>,
+        player_character: Arc<entities::PlayerCharacter>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            world,
+            player_character,
+            clock,
+        }
+    }
+
+    pub async fn list(&self, world_id: WorldId) -> Result<PartySplit, ParallelSceneError> {
+        let world = self.get_world(world_id).await?;
+        Ok(PartySplit::of(&world))
+    }
+
+    pub async fn create(
+        &self,
+        world_id: WorldId,
+        data: ParallelSceneInputData,
+    ) -> Result<PartySplit, ParallelSceneError> {
+        let mut world = self.get_world(world_id).await?;
+        let pc_ids = self.resolve_pcs(world_id, &data.pc_ids).await?;
+        world.create_parallel_scene(
+            &data.name,
+            pc_ids,
+            &data.scene_notes,
+            &data.tone,
+            self.clock.now(),
+        )?;
+        self.world.save(&world).await?;
+        Ok(PartySplit::of(&world))
+    }
+
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        scene_id: ParallelSceneId,
+        data: ParallelSceneInputData,
+    ) -> Result<PartySplit, ParallelSceneError> {
+        let mut world = self.get_world(world_id).await?;
+        let pc_ids = self.resolve_pcs(world_id, &data.pc_ids).await?;
+        world.update_parallel_scene(
+            scene_id,
+            &data.name,
+            pc_ids,
+            &data.scene_notes,
+            &data.tone,
+            self.clock.now(),
+        )?;
+        self.world.save(&world).await?;
+        Ok(PartySplit::of(&world))
+    }
+
+    pub async fn delete(
+        &self,
+        world_id: WorldId,
+        scene_id: ParallelSceneId,
+    ) -> Result<PartySplit, ParallelSceneError> {
+        let mut world = self.get_world(world_id).await?;
+        world.remove_parallel_scene(scene_id, self.clock.now())?;
+        self.world.save(&world).await?;
+        Ok(PartySplit::of(&world))
+    }
+
+    /// Switch the DM to a scene, or to the main table with `None`
+    pub async fn focus(
+        &self,
+        world_id: WorldId,
+        scene_id: Option<ParallelSceneId>,
+    ) -> Result<PartySplit, ParallelSceneError> {
+        let mut world = self.get_world(world_id).await?;
+        world.focus_parallel_scene(scene_id, self.clock.now())?;
+        self.world.save(&world).await?;
+        Ok(PartySplit::of(&world))
+    }
+
+    pub async fn merge(&self, world_id: WorldId) -> Result<PartySplit, ParallelSceneError> {
+        let mut world = self.get_world(world_id).await?;
+        world.merge_parallel_scenes(self.clock.now());
+        self.world.save(&world).await?;
+        Ok(PartySplit::of(&world))
+    }
+
+    async fn get_world(&self, world_id: WorldId) -> Result<World, ParallelSceneError> {
+        self.world
+            .get(world_id)
+            .await?
+            .ok_or(ParallelSceneError::WorldNotFound)
+    }
+
+    /// Parse PC IDs, checking each PC is in the world
+    async fn resolve_pcs(
+        &self,
+        world_id: WorldId,
+        ids: &[String],
+    ) -> Result<Vec<PlayerCharacterId>, ParallelSceneError> {
+        let mut pc_ids = Vec::with_capacity(ids.len());
+        for id in ids {
+            let pc_id = Uuid::parse_str(id)
+                .map(PlayerCharacterId::from_uuid)
+                .map_err(|_| ParallelSceneError::Invalid("Invalid player character ID".into()))?;
+            match self.player_character.get(pc_id).await? {
+                Some(pc) if pc.world_id == world_id => pc_ids.push(pc_id),
+                _ => return Err(ParallelSceneError::PlayerCharacterNotFound),
+            }
+        }
+        Ok(pc_ids)
+    }
+}
+
+/// Convert a split for the wire. Players see who is where, but not the DM's
+/// notes and tone.
+pub fn scenes_to_protocol(split: &PartySplit, for_dm: bool) -> Vec<ParallelSceneData> {
+    split
+        .scenes
+        .iter()
+        .map(|scene| ParallelSceneData {
+            id: scene.id.to_string(),
+            name: scene.name.clone(),
+            pc_ids: scene.pc_ids.iter().map(|id| id.to_string()).collect(),
+            scene_notes: if for_dm {
+                scene.scene_notes.clone()
+            } else {
+                String::new()
+            },
+            tone: if for_dm {
+                scene.tone.clone()
+            } else {
+                String::new()
+            },
+            is_focused: split.focused_scene_id == Some(scene.id),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::Simulation;
+
+    #[tokio::test]
+    async fn splitting_and_merging_the_party() {
+        let sim = Simulation::new(11);
+        let world = sim.world("Saltmere").await;
+        let harbor = sim.location(world.id, "Harbor").await;
+        let docks = sim.region(harbor.id, "Docks").await;
+        let ana = sim.pc(world.id, "Ana", &docks).await;
+        let bo = sim.pc(world.id, "Bo", &docks).await;
+        let scenes = &sim.app.use_cases.parallel_scenes.manage;
+
+        let split = scenes
+            .create(
+                world.id,
+                ParallelSceneInputData {
+                    name: "The sewers".to_string(),
+                    pc_ids: vec![ana.id.to_string()],
+                    scene_notes: " Rats everywhere ".to_string(),
+                    tone: "tense".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        let sewers = split.scenes[0].id;
+        assert_eq!(split.scenes[0].scene_notes, "Rats everywhere");
+
+        let split = scenes.focus(world.id, Some(sewers)).await.unwrap();
+        let for_players = scenes_to_protocol(&split, false);
+        assert!(for_players[0].is_focused);
+        assert!(for_players[0].scene_notes.is_empty());
+
+        // A PC from another world can't join the scene
+        let elsewhere = sim.world("Elsewhere").await;
+        let stranger = sim.pc(elsewhere.id, "Stranger", &docks).await;
+        let err = scenes
+            .update(
+                world.id,
+                sewers,
+                ParallelSceneInputData {
+                    name: "The sewers".to_string(),
+                    pc_ids: vec![ana.id.to_string(), stranger.id.to_string()],
+                    scene_notes: String::new(),
+                    tone: String::new(),
+                },
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ParallelSceneError::PlayerCharacterNotFound));
+
+        let world_now = sim.app.entities.world.get(world.id).await.unwrap().unwrap();
+        assert_eq!(
+            world_now.parallel_scene_of(ana.id).map(|s| s.id),
+            Some(sewers)
+        );
+        assert!(world_now.parallel_scene_of(bo.id).is_none());
+
+        let split = scenes.merge(world.id).await.unwrap();
+        assert!(split.scenes.is_empty());
+        assert!(split.focused_scene_id.is_none());
+        assert!(matches!(
+            scenes.focus(world.id, Some(sewers)).await,
+            Err(ParallelSceneError::NotFound)
+        ));
+    }
+}
@@ -4,16 +4,21 @@ use uuid::Uuid;
 
 use wrldbldr_domain::{CharacterId, PlayerActionData, PlayerCharacterId, WorldId};
 
-use crate::infrastructure::ports::{ClockPort, QueuePort};
+use crate::infrastructure::ports::{ClockPort, QueueItemData, QueueItemStatus, QueuePort};
 use crate::use_cases::conversation::{ConversationError, StartConversation};
+use crate::use_cases::queues::arbitration::ConversationQueues;
+
+/// How many recent LLM requests are scanned for a retracted action's line.
+const RETRACT_SCAN_LIMIT: usize = 200;
 
 pub struct PlayerActionUseCases {
     pub handle: Arc<HandlePlayerAction>,
+    pub retract: Arc<RetractPlayerAction>,
 }
 
 impl PlayerActionUseCases {
-    pub fn new(handle: Arc<HandlePlayerAction>) -> Self {
-        Self { handle }
+    pub fn new(handle: Arc<HandlePlayerAction>, retract: Arc<RetractPlayerAction>) -> Self {
+        Self { handle, retract }
     }
 }
 
@@ -104,6 +109,134 @@ impl HandlePlayerAction {
     }
 }
 
+/// Take back a queued player action before the NPC answers it.
+///
+/// An action still waiting in the player action queue, or in its NPC's
+/// merge window, is failed outright. One that has already been turned into
+/// an NPC response request is retracted by cancelling that request, as long
+/// as it hasn't been picked up by the LLM yet and answers no one else's
+/// lines too.
+pub struct RetractPlayerAction {
+    queue: Arc<dyn QueuePort>,
+    conversations: Arc<ConversationQueues>,
+}
+
+impl RetractPlayerAction {
+    pub fn new(queue: Arc<dyn QueuePort>, conversations: Arc<ConversationQueues>) -> Self {
+        Self {
+            queue,
+            conversations,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        world_id: WorldId,
+        action_id: Uuid,
+        user_id: &str,
+        is_dm: bool,
+    ) -> Result<RetractedAction, RetractError> {
+        let item = self
+            .queue
+            .get_item(action_id)
+            .await
+            .map_err(|e| RetractError::Queue(e.to_string()))?
+            .ok_or(RetractError::NotFound)?;
+
+        let data = match &item.data {
+            QueueItemData::PlayerAction(data) if data.world_id == world_id => data,
+            _ => return Err(RetractError::NotFound),
+        };
+        if !is_dm && data.player_id != user_id {
+            return Err(RetractError::NotOwner);
+        }
+
+        match item.status {
+            // The worker may dequeue the action between the read above and
+            // this write, so only fail it if it is still where we found it
+            QueueItemStatus::Pending => self.fail_if_still(action_id, item.status).await?,
+            QueueItemStatus::Processing if self.conversations.remove(action_id).is_some() => {
+                self.fail_if_still(action_id, item.status).await?
+            }
+            QueueItemStatus::Completed => {
+                if self.merged_into_pending_request(action_id).await? {
+                    return Err(RetractError::Merged);
+                }
+                // The action has been processed; its NPC response request
+                // carries the action ID as callback.
+                let cancelled = self
+                    .queue
+                    .cancel_pending_llm_request_by_callback_id(&action_id.to_string())
+                    .await
+                    .map_err(|e| RetractError::Queue(e.to_string()))?;
+                if !cancelled {
+                    return Err(RetractError::AlreadyProcessed);
+                }
+            }
+            QueueItemStatus::Processing | QueueItemStatus::Failed => {
+                return Err(RetractError::AlreadyProcessed);
+            }
+        }
+
+        Ok(RetractedAction {
+            action_id,
+            world_id,
+        })
+    }
+
+    /// Mark the action retracted if its status is still `status`
+    async fn fail_if_still(
+        &self,
+        action_id: Uuid,
+        status: QueueItemStatus,
+    ) -> Result<(), RetractError> {
+        let failed = self
+            .queue
+            .mark_failed_if_status(action_id, status, "Retracted by player")
+            .await
+            .map_err(|e| RetractError::Queue(e.to_string()))?;
+        if !failed {
+            return Err(RetractError::AlreadyProcessed);
+        }
+        Ok(())
+    }
+
+    /// Whether the action's line waits to be answered together with other
+    /// players' lines
+    async fn merged_into_pending_request(&self, action_id: Uuid) -> Result<bool, RetractError> {
+        let requests = self
+            .queue
+            .list_by_type("llm_request", RETRACT_SCAN_LIMIT)
+            .await
+            .map_err(|e| RetractError::Queue(e.to_string()))?;
+        Ok(requests.iter().any(|item| {
+            item.status == QueueItemStatus::Pending
+                && matches!(&item.data, QueueItemData::LlmRequest(data)
+                    if data.merged_lines.iter().any(|line| line.action_id == action_id))
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct RetractedAction {
+    pub action_id: Uuid,
+    pub world_id: WorldId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RetractError {
+    #[error("Player action not found")]
+    NotFound,
+    #[error("Only the player who sent an action can retract it")]
+    NotOwner,
+    #[error("The action has already been answered")]
+    AlreadyProcessed,
+    #[error("The line is being answered together with other players' lines")]
+    Merged,
+    #[error("Queue error: {0}")]
+    Queue(String),
+}
+
 #[derive(Debug)]
 pub struct PlayerActionProcessed {
     pub action_id: Uuid,
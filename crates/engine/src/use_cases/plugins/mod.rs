@@ -0,0 +1,170 @@
+//! Plugin use cases.
+//!
+//! Exposes third-party plugin tools to the LLM during NPC dialogue and runs
+//! the ones the DM approves. Tool names are namespaced as
+//! `{plugin}__{tool}` so plugins can't shadow each other or built-in tools.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{PluginManifest, ProposedTool};
+
+use crate::infrastructure::ports::{
+    PluginCall, PluginCallKind, PluginPort, ToolCall, ToolDefinition,
+};
+use crate::use_cases::narrative::{EffectExecutionContext, EffectExecutionResult, ExecuteEffects};
+
+/// Separates the plugin name from the tool name in LLM tool names
+const TOOL_SEPARATOR: &str = "__";
+
+/// Offer plugin tools to the LLM and run approved calls.
+pub struct PluginTools {
+    plugins: Arc<dyn PluginPort>,
+    execute_effects: Arc<ExecuteEffects>,
+}
+
+impl PluginTools {
+    pub fn new(plugins: Arc<dyn PluginPort>, execute_effects: Arc<ExecuteEffects>) -> Self {
+        Self {
+            plugins,
+            execute_effects,
+        }
+    }
+
+    /// Tool definitions for every loaded plugin.
+    pub fn definitions(&self) -> Vec<ToolDefinition> {
+        tool_definitions(&self.plugins.manifests())
+    }
+
+    /// Turn the LLM's tool calls into proposals for the DM.
+    pub fn proposals(&self, calls: &[ToolCall]) -> Vec<ProposedTool> {
+        proposed_tools(&self.plugins.manifests(), calls)
+    }
+
+    /// Run a DM-approved plugin tool call.
+    pub async fn invoke(
+        &self,
+        tool: &ProposedTool,
+        context: &EffectExecutionContext,
+    ) -> Result<EffectExecutionResult, PluginToolError> {
+        let (plugin, name) = split_tool_name(&tool.name)
+            .ok_or_else(|| PluginToolError::NotAPluginTool(tool.name.clone()))?;
+        let call = PluginCall {
+            kind: PluginCallKind::Tool,
+            name: name.to_string(),
+            arguments: tool.arguments.clone(),
+            world_id: context.world_id,
+            pc_id: Some(context.pc_id),
+        };
+        Ok(self
+            .execute_effects
+            .execute_plugin(plugin, call, context)
+            .await)
+    }
+}
+
+/// Whether `name` refers to a plugin tool (as opposed to a built-in one).
+pub fn is_plugin_tool(name: &str) -> bool {
+    split_tool_name(name).is_some()
+}
+
+fn split_tool_name(name: &str) -> Option<(&str, &str)> {
+    name.split_once(TOOL_SEPARATOR)
+}
+
+fn tool_definitions(manifests: &[PluginManifest]) -> Vec<ToolDefinition> {
+    manifests
+        .iter()
+        .flat_map(|manifest| {
+            manifest.tools.iter().map(move |tool| ToolDefinition {
+                name: format!("{}{}{}", manifest.name, TOOL_SEPARATOR, tool.name),
+                description: tool.description.clone(),
+                parameters: tool.parameters.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Keep the calls that name a loaded plugin tool; anything else the model
+/// made up is dropped.
+fn proposed_tools(manifests: &[PluginManifest], calls: &[ToolCall]) -> Vec<ProposedTool> {
+    calls
+        .iter()
+        .filter_map(|call| {
+            let (plugin, name) = split_tool_name(&call.name)?;
+            let spec = manifests
+                .iter()
+                .find(|manifest| manifest.name == plugin)
+                .and_then(|manifest| manifest.tool(name));
+            let Some(spec) = spec else {
+                tracing::warn!(tool = %call.name, "LLM called an unknown plugin tool");
+                return None;
+            };
+            Some(ProposedTool {
+                id: call.id.clone(),
+                name: call.name.clone(),
+                description: spec.description.clone(),
+                arguments: call.arguments.clone(),
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginToolError {
+    #[error("{0} is not a plugin tool")]
+    NotAPluginTool(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wrldbldr_domain::PluginToolSpec;
+
+    fn manifest() -> PluginManifest {
+        PluginManifest {
+            name: "loot".to_string(),
+            version: "1.0.0".to_string(),
+            description: String::new(),
+            capabilities: vec![],
+            tools: vec![PluginToolSpec {
+                name: "roll_loot".to_string(),
+                description: "Roll on the loot table".to_string(),
+                parameters: serde_json::json!({ "type": "object" }),
+            }],
+            effects: vec![],
+        }
+    }
+
+    fn tool_call(name: &str) -> ToolCall {
+        ToolCall {
+            id: format!("call-{name}"),
+            name: name.to_string(),
+            arguments: serde_json::json!({ "table": "dungeon" }),
+        }
+    }
+
+    #[test]
+    fn tools_are_namespaced_by_plugin() {
+        let definitions = tool_definitions(&[manifest()]);
+        assert_eq!(definitions.len(), 1);
+        assert_eq!(definitions[0].name, "loot__roll_loot");
+        assert_eq!(definitions[0].description, "Roll on the loot table");
+        assert!(is_plugin_tool(&definitions[0].name));
+        assert!(!is_plugin_tool("give_item"));
+    }
+
+    #[test]
+    fn only_calls_to_loaded_tools_are_proposed() {
+        let calls = [
+            tool_call("loot__roll_loot"),
+            tool_call("loot__steal_everything"),
+            tool_call("other__roll_loot"),
+            tool_call("give_item"),
+        ];
+        let proposed = proposed_tools(&[manifest()], &calls);
+        assert_eq!(proposed.len(), 1);
+        assert_eq!(proposed[0].id, "call-loot__roll_loot");
+        assert_eq!(proposed[0].description, "Roll on the loot table");
+        assert_eq!(proposed[0].arguments["table"], "dungeon");
+    }
+}
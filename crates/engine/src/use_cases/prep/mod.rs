@@ -0,0 +1,250 @@
+//! Session prep use cases.
+//!
+//! Looks at where the party is and where it is likely to go next (regions
+//! one connection or exit away, and places named by active quest clocks) and
+//! lists the prep still missing there: regions with no current staging, NPCs
+//! who may turn up without a portrait, and challenges with blank outcomes.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use serde::Serialize;
+use wrldbldr_domain::{ChallengeId, CharacterId, ClockKind, Region, RegionId, WorldId};
+
+use crate::entities::{
+    Challenge, Character, Location, PlayerCharacter, ProgressClock, Staging, World,
+};
+use crate::infrastructure::ports::{NpcRegionRelationType, RepoError};
+
+/// Container for session prep use cases.
+pub struct PrepUseCases {
+    pub checklist: Arc<GeneratePrepChecklist>,
+}
+
+impl PrepUseCases {
+    pub fn new(checklist: Arc<GeneratePrepChecklist>) -> Self {
+        Self { checklist }
+    }
+}
+
+/// A region the party may reach with no current staging
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnstagedRegion {
+    pub region_id: RegionId,
+    pub name: String,
+    pub location_name: String,
+    /// Why the party may end up here, e.g. "Next to Docks"
+    pub reason: String,
+}
+
+/// An NPC who may turn up in those regions but has no portrait
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NpcWithoutPortrait {
+    pub npc_id: CharacterId,
+    pub name: String,
+    /// The first region they may turn up in
+    pub region_name: String,
+}
+
+/// An active challenge with a success or failure outcome left blank
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChallengeWithoutOutcomes {
+    pub challenge_id: ChallengeId,
+    pub name: String,
+    /// Which outcomes are blank ("success", "failure")
+    pub blank: Vec<String>,
+}
+
+/// What still needs prepping before the next session.
+#[derive(Debug, Clone, Serialize)]
+pub struct PrepChecklist {
+    pub world_id: WorldId,
+    /// Regions the party is in, then next to, then pointed at by quests
+    pub unstaged_regions: Vec<UnstagedRegion>,
+    pub npcs_without_portraits: Vec<NpcWithoutPortrait>,
+    pub challenges_without_outcomes: Vec<ChallengeWithoutOutcomes>,
+}
+
+/// Generate a world's prep checklist.
+pub struct GeneratePrepChecklist {
+    world: Arc<World>,
+    player_character: Arc<PlayerCharacter>,
+    location: Arc<Location>,
+    character: Arc<Character>,
+    staging: Arc<Staging>,
+    challenge: Arc<Challenge>,
+    progress_clock: Arc<ProgressClock>,
+}
+
+impl GeneratePrepChecklist {
+    pub fn new(
+        world: Arc<World>,
+        player_character: Arc<PlayerCharacter>,
+        location: Arc<Location>,
+        character: Arc<Character>,
+        staging: Arc<Staging>,
+        challenge: Arc<Challenge>,
+        progress_clock: Arc<ProgressClock>,
+    ) -> Self {
+        Self {
+            world,
+            player_character,
+            location,
+            character,
+            staging,
+            challenge,
+            progress_clock,
+        }
+    }
+
+    pub async fn execute(&self, world_id: WorldId) -> Result<PrepChecklist, RepoError> {
+        let world = self.world.get(world_id).await?.ok_or(RepoError::NotFound)?;
+        let now = world.game_time.current();
+
+        // Where the party is
+        let mut regions: Vec<(Region, String)> = Vec::new();
+        let mut seen: HashSet<RegionId> = HashSet::new();
+        let pcs = self.player_character.list_in_world(world_id).await?;
+        for region_id in pcs
+            .iter()
+            .filter(|pc| pc.is_active)
+            .filter_map(|pc| pc.current_region_id)
+        {
+            if seen.insert(region_id) {
+                if let Some(region) = self.location.get_region(region_id).await? {
+                    regions.push((region, "A PC is here".to_string()));
+                }
+            }
+        }
+
+        // One connection or exit away
+        let occupied: Vec<(RegionId, String)> = regions
+            .iter()
+            .map(|(r, _)| (r.id, r.name.clone()))
+            .collect();
+        for (region_id, region_name) in occupied {
+            let mut next: Vec<RegionId> = self
+                .location
+                .get_connections(region_id)
+                .await?
+                .into_iter()
+                .map(|c| c.to_region)
+                .collect();
+            next.extend(
+                self.location
+                    .get_region_exits(region_id)
+                    .await?
+                    .into_iter()
+                    .map(|e| e.arrival_region_id),
+            );
+            for id in next {
+                if seen.insert(id) {
+                    if let Some(region) = self.location.get_region(id).await? {
+                        regions.push((region, format!("Next to {}", region_name)));
+                    }
+                }
+            }
+        }
+
+        // Places active quests name
+        let locations = self.location.list_in_world(world_id).await?;
+        let quests: Vec<_> = self
+            .progress_clock
+            .list_in_world(world_id)
+            .await?
+            .into_iter()
+            .filter(|c| c.kind == ClockKind::Quest && !c.is_complete())
+            .collect();
+        for quest in &quests {
+            let text = [
+                Some(quest.name.as_str()),
+                quest.subject.as_deref(),
+                quest.description.as_deref(),
+            ]
+            .iter()
+            .flatten()
+            .map(|s| s.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(" ");
+            for location in locations
+                .iter()
+                .filter(|l| text.contains(&l.name.to_lowercase()))
+            {
+                for region in self.location.list_regions_in_location(location.id).await? {
+                    if seen.insert(region.id) {
+                        regions.push((region, format!("Quest: {}", quest.name)));
+                    }
+                }
+            }
+        }
+
+        let mut unstaged_regions = Vec::new();
+        let mut npcs_without_portraits = Vec::new();
+        let mut npcs_seen: HashSet<CharacterId> = HashSet::new();
+        for (region, reason) in &regions {
+            if self
+                .staging
+                .get_active_staging(region.id, now)
+                .await?
+                .is_none()
+            {
+                let location_name = locations
+                    .iter()
+                    .find(|l| l.id == region.location_id)
+                    .map(|l| l.name.clone())
+                    .unwrap_or_default();
+                unstaged_regions.push(UnstagedRegion {
+                    region_id: region.id,
+                    name: region.name.clone(),
+                    location_name,
+                    reason: reason.clone(),
+                });
+            }
+
+            for npc in self.character.get_npcs_for_region(region.id).await? {
+                if npc.relationship_type == NpcRegionRelationType::Avoids
+                    || npc.portrait_asset.is_some()
+                    || !npcs_seen.insert(npc.character_id)
+                {
+                    continue;
+                }
+                npcs_without_portraits.push(NpcWithoutPortrait {
+                    npc_id: npc.character_id,
+                    name: npc.name,
+                    region_name: region.name.clone(),
+                });
+            }
+        }
+
+        let mut challenges_without_outcomes: Vec<ChallengeWithoutOutcomes> = self
+            .challenge
+            .list_for_world(world_id)
+            .await?
+            .into_iter()
+            .filter(|c| c.active)
+            .filter_map(|c| {
+                let blank: Vec<String> = [
+                    ("success", &c.outcomes.success),
+                    ("failure", &c.outcomes.failure),
+                ]
+                .iter()
+                .filter(|(_, outcome)| outcome.description.trim().is_empty())
+                .map(|(name, _)| name.to_string())
+                .collect();
+                (!blank.is_empty()).then_some(ChallengeWithoutOutcomes {
+                    challenge_id: c.id,
+                    name: c.name,
+                    blank,
+                })
+            })
+            .collect();
+        challenges_without_outcomes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(PrepChecklist {
+            world_id,
+            unstaged_regions,
+            npcs_without_portraits,
+            challenges_without_outcomes,
+        })
+    }
+}
@@ -0,0 +1,278 @@
+//! Progress clock use cases.
+//!
+//! CRUD and tick operations for Blades-style progress clocks. Clocks are
+//! DM-only unless marked visible to players, and personal clocks (a PC's
+//! own quests and secret objectives) are only ever shown to their owner.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{ClockKind, PlayerCharacterId, ProgressClock, ProgressClockId, WorldId};
+use wrldbldr_protocol::requests::{CreateClockData, UpdateClockData};
+use wrldbldr_protocol::types::{ClockKindData, ProgressClockData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for progress clock use cases.
+pub struct ProgressClockUseCases {
+    pub ops: Arc<ProgressClockOps>,
+}
+
+impl ProgressClockUseCases {
+    pub fn new(ops: Arc<ProgressClockOps>) -> Self {
+        Self { ops }
+    }
+}
+
+/// Progress clock operations.
+pub struct ProgressClockOps {
+    clocks: Arc<entities::ProgressClock>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ProgressClockOps {
+    pub fn new(clocks: Arc<entities::ProgressClock>, clock: Arc<dyn ClockPort>) -> Self {
+        Self { clocks, clock }
+    }
+
+    /// List clocks in a world. Hidden clocks are only included for DMs, and
+    /// personal clocks only for the PC they belong to.
+    pub async fn list(
+        &self,
+        world_id: WorldId,
+        include_hidden: bool,
+        viewer_pc_ids: &[PlayerCharacterId],
+    ) -> Result<Vec<ProgressClockData>, ProgressClockError> {
+        let clocks = self.clocks.list_in_world(world_id).await?;
+        Ok(clocks
+            .iter()
+            .filter(|c| include_hidden || c.is_visible_to(viewer_pc_ids))
+            .map(clock_to_protocol)
+            .collect())
+    }
+
+    /// A PC's unfinished personal quests, for the DM's eyes only.
+    pub async fn personal_quests(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<PersonalQuest>, ProgressClockError> {
+        let clocks = self.clocks.list_in_world(world_id).await?;
+        Ok(clocks
+            .iter()
+            .filter(|c| c.owner_pc_id == Some(pc_id) && !c.is_complete())
+            .map(|c| PersonalQuest {
+                name: c.name.clone(),
+                description: c.description.clone(),
+                conflicts_with: c
+                    .conflicts_with
+                    .and_then(|id| clocks.iter().find(|other| other.id == id))
+                    .map(|other| other.name.clone()),
+            })
+            .collect())
+    }
+
+    pub async fn get(
+        &self,
+        clock_id: ProgressClockId,
+    ) -> Result<ProgressClock, ProgressClockError> {
+        self.clocks
+            .get(clock_id)
+            .await?
+            .ok_or(ProgressClockError::NotFound)
+    }
+
+    pub async fn create(
+        &self,
+        world_id: WorldId,
+        data: CreateClockData,
+    ) -> Result<ProgressClock, ProgressClockError> {
+        let mut clock = ProgressClock::new(world_id, &data.name, data.segments, self.clock.now())
+            .map_err(|e| ProgressClockError::Validation(e.to_string()))?;
+
+        if let Some(kind) = data.kind.as_deref() {
+            clock = clock.with_kind(parse_kind(kind)?);
+        }
+        if let Some(description) = data.description {
+            clock = clock.with_description(description);
+        }
+        if let Some(subject) = data.subject {
+            clock = clock.with_subject(subject);
+        }
+        if data.visible_to_players.unwrap_or(false) {
+            clock = clock.visible();
+        }
+        if let Some(pc_id) = data.owner_pc_id.as_deref().filter(|id| !id.is_empty()) {
+            clock = clock.owned_by(parse_pc_id(pc_id)?);
+        }
+        if let Some(other) = data
+            .conflicts_with_clock_id
+            .as_deref()
+            .filter(|id| !id.is_empty())
+        {
+            let target = self.conflict_target(&clock, other).await?;
+            clock = clock.conflicting_with(target);
+        }
+
+        self.clocks.save(&clock).await?;
+        Ok(clock)
+    }
+
+    pub async fn update(
+        &self,
+        clock_id: ProgressClockId,
+        data: UpdateClockData,
+    ) -> Result<ProgressClock, ProgressClockError> {
+        let mut clock = self.get(clock_id).await?;
+        let now = self.clock.now();
+
+        if let Some(name) = data.name {
+            clock.name = name;
+        }
+        if let Some(segments) = data.segments {
+            clock
+                .resize(segments, now)
+                .map_err(|e| ProgressClockError::Validation(e.to_string()))?;
+        }
+        if let Some(description) = data.description {
+            clock.description = Some(description).filter(|d| !d.is_empty());
+        }
+        if let Some(kind) = data.kind.as_deref() {
+            clock.kind = parse_kind(kind)?;
+        }
+        if let Some(subject) = data.subject {
+            clock.subject = Some(subject).filter(|s| !s.is_empty());
+        }
+        if let Some(visible) = data.visible_to_players {
+            clock.visible_to_players = visible;
+        }
+        if let Some(pc_id) = data.owner_pc_id.as_deref() {
+            clock.owner_pc_id = match pc_id {
+                "" => None,
+                id => Some(parse_pc_id(id)?),
+            };
+        }
+        if let Some(other) = data.conflicts_with_clock_id.as_deref() {
+            clock.conflicts_with = match other {
+                "" => None,
+                id => Some(self.conflict_target(&clock, id).await?),
+            };
+        }
+        clock.updated_at = now;
+
+        self.clocks.save(&clock).await?;
+        Ok(clock)
+    }
+
+    /// Delete a clock, returning it so callers know who to notify.
+    pub async fn delete(
+        &self,
+        clock_id: ProgressClockId,
+    ) -> Result<ProgressClock, ProgressClockError> {
+        let clock = self.get(clock_id).await?;
+        self.clocks.delete(clock_id).await?;
+        Ok(clock)
+    }
+
+    pub async fn tick(
+        &self,
+        clock_id: ProgressClockId,
+        amount: i32,
+    ) -> Result<ProgressClock, ProgressClockError> {
+        match self.clocks.tick(clock_id, amount).await {
+            Ok((clock, _)) => Ok(clock),
+            Err(RepoError::NotFound) => Err(ProgressClockError::NotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub async fn reset(
+        &self,
+        clock_id: ProgressClockId,
+    ) -> Result<ProgressClock, ProgressClockError> {
+        let mut clock = self.get(clock_id).await?;
+        clock.reset(self.clock.now());
+        self.clocks.save(&clock).await?;
+        Ok(clock)
+    }
+
+    /// Resolve the party clock a personal objective works against.
+    async fn conflict_target(
+        &self,
+        clock: &ProgressClock,
+        other_id: &str,
+    ) -> Result<ProgressClockId, ProgressClockError> {
+        let other_id = Uuid::parse_str(other_id)
+            .map(ProgressClockId::from_uuid)
+            .map_err(|_| ProgressClockError::Validation("Invalid conflicting clock ID".into()))?;
+        let other = self
+            .clocks
+            .get(other_id)
+            .await?
+            .filter(|other| other.world_id == clock.world_id && other.id != clock.id)
+            .ok_or_else(|| {
+                ProgressClockError::Validation("Conflicting clock not found in this world".into())
+            })?;
+        if other.is_personal() {
+            return Err(ProgressClockError::Validation(
+                "A personal objective can only conflict with a party clock".into(),
+            ));
+        }
+        Ok(other.id)
+    }
+}
+
+/// A PC's secret objective, as given to the NPC prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonalQuest {
+    pub name: String,
+    pub description: Option<String>,
+    /// Name of the party clock it works against
+    pub conflicts_with: Option<String>,
+}
+
+fn parse_pc_id(pc_id: &str) -> Result<PlayerCharacterId, ProgressClockError> {
+    Uuid::parse_str(pc_id)
+        .map(PlayerCharacterId::from_uuid)
+        .map_err(|_| ProgressClockError::Validation("Invalid owner PC ID".into()))
+}
+
+fn parse_kind(kind: &str) -> Result<ClockKind, ProgressClockError> {
+    kind.parse::<ClockKind>()
+        .map_err(ProgressClockError::Validation)
+}
+
+/// Convert a domain progress clock to its wire format.
+pub fn clock_to_protocol(clock: &ProgressClock) -> ProgressClockData {
+    ProgressClockData {
+        id: clock.id.to_string(),
+        world_id: clock.world_id.to_string(),
+        name: clock.name.clone(),
+        description: clock.description.clone(),
+        kind: match clock.kind {
+            ClockKind::Faction => ClockKindData::Faction,
+            ClockKind::Quest => ClockKindData::Quest,
+            ClockKind::Threat => ClockKindData::Threat,
+            ClockKind::Project => ClockKindData::Project,
+            ClockKind::Unknown => ClockKindData::Unknown,
+        },
+        subject: clock.subject.clone(),
+        segments: clock.segments,
+        filled: clock.filled,
+        visible_to_players: clock.visible_to_players,
+        is_complete: clock.is_complete(),
+        owner_pc_id: clock.owner_pc_id.map(|id| id.to_string()),
+        conflicts_with_clock_id: clock.conflicts_with.map(|id| id.to_string()),
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProgressClockError {
+    #[error("Progress clock not found")]
+    NotFound,
+    #[error("{0}")]
+    Validation(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
@@ -0,0 +1,539 @@
+//! Property use cases.
+//!
+//! Player characters own locations and regions: the party's tavern, the
+//! wizard's tower. Each property costs upkeep per game day and can employ
+//! staff NPCs. Upkeep builds up as game time passes; the DM settles it,
+//! which takes it from the owner's currency when the rule system has one.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use wrldbldr_domain::{
+    CharacterId, DomainError, LocationId, PlayerCharacterId, Property, PropertyId, PropertySite,
+    PropertyStaff, RegionId, StaffRole, WorldId,
+};
+use wrldbldr_protocol::types::{
+    PropertyData, PropertyInputData, PropertyStaffData, PropertyStaffInputData, StaffRoleData,
+    UpkeepPaymentData,
+};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for property use cases.
+pub struct PropertyUseCases {
+    pub manage: Arc<ManageProperties>,
+}
+
+impl PropertyUseCases {
+    pub fn new(manage: Arc<ManageProperties>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PropertyError {
+    #[error("Property not found")]
+    PropertyNotFound,
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("Location not found")]
+    LocationNotFound,
+    #[error("Region not found")]
+    RegionNotFound,
+    #[error("Staff NPC not found")]
+    StaffNotFound,
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for PropertyError {
+    fn from(e: DomainError) -> Self {
+        PropertyError::Invalid(e.to_string())
+    }
+}
+
+/// Manage PC properties and their upkeep.
+pub struct ManageProperties {
+    property: Arc<entities::Property>,
+    player_character: Arc<entities::PlayerCharacter>,
+    character: Arc<entities::Character>,
+    location: Arc<entities::Location>,
+    world: Arc<entities::World>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageProperties {
+    pub fn new(
+        property: Arc<entities::Property>,
+        player_character: Arc<entities::PlayerCharacter>,
+        character: Arc<entities::Character>,
+        location: Arc<entities::Location>,
+        world: Arc<entities::World>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            property,
+            player_character,
+            character,
+            location,
+            world,
+            clock,
+        }
+    }
+
+    /// A PC's properties, oldest first, with upkeep owed as of now
+    pub async fn list_for_pc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<PropertyData>, PropertyError> {
+        self.world_pc(world_id, pc_id).await?;
+        let game_now = self.game_now(world_id).await?;
+        let mut properties = Vec::new();
+        for property in self.property.list_for_owner(pc_id).await? {
+            properties.push(self.describe(&property, game_now).await?);
+        }
+        Ok(properties)
+    }
+
+    /// Give a PC a property, paid up to the current game time
+    pub async fn create(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        input: PropertyInputData,
+    ) -> Result<PropertyData, PropertyError> {
+        self.world_pc(world_id, pc_id).await?;
+        let site = self.site(world_id, &input).await?;
+        let game_now = self.game_now(world_id).await?;
+        let mut property = Property::new(
+            world_id,
+            pc_id,
+            input.name.clone(),
+            site,
+            game_now,
+            self.clock.now(),
+        )?;
+        self.apply_input(world_id, &mut property, site, input)
+            .await?;
+        self.property.save(&property).await?;
+        self.describe(&property, game_now).await
+    }
+
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        property_id: PropertyId,
+        input: PropertyInputData,
+    ) -> Result<PropertyData, PropertyError> {
+        let mut property = self.world_property(world_id, property_id).await?;
+        let site = self.site(world_id, &input).await?;
+        self.apply_input(world_id, &mut property, site, input)
+            .await?;
+        self.property.save(&property).await?;
+        let game_now = self.game_now(world_id).await?;
+        self.describe(&property, game_now).await
+    }
+
+    pub async fn delete(
+        &self,
+        world_id: WorldId,
+        property_id: PropertyId,
+    ) -> Result<(), PropertyError> {
+        let property = self.world_property(world_id, property_id).await?;
+        self.property.delete(property.id).await?;
+        Ok(())
+    }
+
+    /// Settle the upkeep owed as of the current game time. It comes out of
+    /// the owner's currency field when the rule system has one; otherwise
+    /// the property is marked paid and the DM settles it by hand.
+    pub async fn pay_upkeep(
+        &self,
+        world_id: WorldId,
+        property_id: PropertyId,
+    ) -> Result<UpkeepPaymentData, PropertyError> {
+        let mut property = self.world_property(world_id, property_id).await?;
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(PropertyError::WorldNotFound)?;
+        let game_now = world.game_time.current();
+        let currency = world
+            .rule_system
+            .variant
+            .currency_field()
+            .map(str::to_string);
+
+        let paid = property.settle_upkeep(game_now, self.clock.now());
+        if paid > 0 {
+            if let Some(field) = &currency {
+                let amount = i32::try_from(paid).unwrap_or(i32::MAX);
+                self.player_character
+                    .modify_stat(property.owner_id, field, -amount)
+                    .await?;
+            }
+        }
+        self.property.save(&property).await?;
+
+        Ok(UpkeepPaymentData {
+            property: self.describe(&property, game_now).await?,
+            paid,
+            currency,
+        })
+    }
+
+    async fn apply_input(
+        &self,
+        world_id: WorldId,
+        property: &mut Property,
+        site: PropertySite,
+        input: PropertyInputData,
+    ) -> Result<(), PropertyError> {
+        let mut staff = Vec::with_capacity(input.staff.len());
+        for member in input.staff {
+            staff.push(self.staff_member(world_id, member).await?);
+        }
+        property.update(
+            &input.name,
+            site,
+            input.upkeep_per_day,
+            staff,
+            &input.notes,
+            self.clock.now(),
+        )?;
+        Ok(())
+    }
+
+    /// The region when one is given, otherwise the location, checking
+    /// either is in the world
+    async fn site(
+        &self,
+        world_id: WorldId,
+        input: &PropertyInputData,
+    ) -> Result<PropertySite, PropertyError> {
+        if let Some(id) = input.region_id.as_deref().filter(|id| !id.is_empty()) {
+            let region_id = parse_id(id, RegionId::from_uuid, "region")?;
+            let region = self
+                .location
+                .get_region(region_id)
+                .await?
+                .ok_or(PropertyError::RegionNotFound)?;
+            self.location
+                .get(region.location_id)
+                .await?
+                .filter(|l| l.world_id == world_id)
+                .ok_or(PropertyError::RegionNotFound)?;
+            return Ok(PropertySite::Region(region_id));
+        }
+        let Some(id) = input.location_id.as_deref().filter(|id| !id.is_empty()) else {
+            return Err(PropertyError::Invalid(
+                "A property needs a location or region".to_string(),
+            ));
+        };
+        let location_id = parse_id(id, LocationId::from_uuid, "location")?;
+        self.location
+            .get(location_id)
+            .await?
+            .filter(|l| l.world_id == world_id)
+            .ok_or(PropertyError::LocationNotFound)?;
+        Ok(PropertySite::Location(location_id))
+    }
+
+    async fn staff_member(
+        &self,
+        world_id: WorldId,
+        input: PropertyStaffInputData,
+    ) -> Result<PropertyStaff, PropertyError> {
+        let character_id = parse_id(&input.character_id, CharacterId::from_uuid, "NPC")?;
+        self.character
+            .get(character_id)
+            .await?
+            .filter(|c| c.world_id == world_id)
+            .ok_or(PropertyError::StaffNotFound)?;
+        Ok(PropertyStaff {
+            character_id,
+            role: staff_role_from_protocol(input.role)?,
+            duties: input.duties,
+        })
+    }
+
+    async fn world_pc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<(), PropertyError> {
+        self.player_character
+            .get(pc_id)
+            .await?
+            .filter(|pc| pc.world_id == world_id)
+            .ok_or(PropertyError::PlayerCharacterNotFound)?;
+        Ok(())
+    }
+
+    async fn world_property(
+        &self,
+        world_id: WorldId,
+        property_id: PropertyId,
+    ) -> Result<Property, PropertyError> {
+        self.property
+            .get(property_id)
+            .await?
+            .filter(|p| p.world_id == world_id)
+            .ok_or(PropertyError::PropertyNotFound)
+    }
+
+    async fn game_now(&self, world_id: WorldId) -> Result<DateTime<Utc>, PropertyError> {
+        self.world
+            .get(world_id)
+            .await?
+            .map(|w| w.game_time.current())
+            .ok_or(PropertyError::WorldNotFound)
+    }
+
+    /// The property for the wire, with names of its site and staff
+    async fn describe(
+        &self,
+        property: &Property,
+        game_now: DateTime<Utc>,
+    ) -> Result<PropertyData, PropertyError> {
+        let site_name = match property.site {
+            PropertySite::Location(id) => self.location.get(id).await?.map(|l| l.name),
+            PropertySite::Region(id) => self.location.get_region(id).await?.map(|r| r.name),
+        };
+        let mut staff = Vec::with_capacity(property.staff.len());
+        for member in &property.staff {
+            staff.push(PropertyStaffData {
+                character_id: member.character_id.to_string(),
+                character_name: self
+                    .character
+                    .get(member.character_id)
+                    .await?
+                    .map(|c| c.name),
+                role: staff_role_to_protocol(member.role),
+                duties: member.duties.clone(),
+            });
+        }
+
+        Ok(PropertyData {
+            id: property.id.to_string(),
+            owner_id: property.owner_id.to_string(),
+            name: property.name.clone(),
+            location_id: property.site.location_id().map(|id| id.to_string()),
+            region_id: property.site.region_id().map(|id| id.to_string()),
+            site_name,
+            upkeep_per_day: property.upkeep_per_day,
+            days_owed: property.days_owed(game_now),
+            upkeep_due: property.upkeep_due(game_now),
+            staff,
+            notes: property.notes.clone(),
+        })
+    }
+}
+
+fn parse_id<T>(id: &str, from_uuid: fn(Uuid) -> T, what: &str) -> Result<T, PropertyError> {
+    Uuid::parse_str(id)
+        .map(from_uuid)
+        .map_err(|_| PropertyError::Invalid(format!("Invalid {} ID: {}", what, id)))
+}
+
+fn staff_role_from_protocol(role: StaffRoleData) -> Result<StaffRole, PropertyError> {
+    match role {
+        StaffRoleData::Steward => Ok(StaffRole::Steward),
+        StaffRoleData::Guard => Ok(StaffRole::Guard),
+        StaffRoleData::Worker => Ok(StaffRole::Worker),
+        StaffRoleData::Unknown => Err(PropertyError::Invalid("Unknown staff role".to_string())),
+    }
+}
+
+fn staff_role_to_protocol(role: StaffRole) -> StaffRoleData {
+    match role {
+        StaffRole::Steward => StaffRoleData::Steward,
+        StaffRole::Guard => StaffRoleData::Guard,
+        StaffRole::Worker => StaffRoleData::Worker,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        MockCharacterRepo, MockLocationRepo, MockPlayerCharacterRepo, MockPropertyRepo,
+        MockWorldRepo,
+    };
+    use chrono::Duration;
+    use wrldbldr_domain::{CampbellArchetype, Location, LocationType, PlayerCharacter, World};
+
+    struct Repos {
+        properties: MockPropertyRepo,
+        pcs: MockPlayerCharacterRepo,
+        characters: MockCharacterRepo,
+        locations: MockLocationRepo,
+        worlds: MockWorldRepo,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            Self {
+                properties: MockPropertyRepo::new(),
+                pcs: MockPlayerCharacterRepo::new(),
+                characters: MockCharacterRepo::new(),
+                locations: MockLocationRepo::new(),
+                worlds: MockWorldRepo::new(),
+            }
+        }
+
+        fn build(self) -> ManageProperties {
+            let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(Utc::now()));
+            ManageProperties::new(
+                Arc::new(entities::Property::new(Arc::new(self.properties))),
+                Arc::new(entities::PlayerCharacter::new(Arc::new(self.pcs))),
+                Arc::new(entities::Character::new(Arc::new(self.characters))),
+                Arc::new(entities::Location::new(Arc::new(self.locations))),
+                Arc::new(entities::World::new(Arc::new(self.worlds), clock.clone())),
+                clock,
+            )
+        }
+    }
+
+    fn input(location_id: LocationId) -> PropertyInputData {
+        PropertyInputData {
+            name: "The Gilded Flagon".to_string(),
+            location_id: Some(location_id.to_string()),
+            region_id: None,
+            upkeep_per_day: 2,
+            staff: vec![],
+            notes: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn paying_upkeep_takes_it_from_the_owners_currency() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let world_id = world.id;
+        let game_now = world.game_time.current();
+        let location = Location::new(world_id, "Tavern", LocationType::Interior);
+        let owner = PlayerCharacterId::new();
+        let property = Property::new(
+            world_id,
+            owner,
+            "The Gilded Flagon",
+            PropertySite::Location(location.id),
+            game_now - Duration::days(3),
+            Utc::now(),
+        )
+        .expect("valid property")
+        .with_upkeep(2)
+        .expect("valid upkeep");
+        let property_id = property.id;
+
+        let mut repos = Repos::new();
+        repos
+            .worlds
+            .expect_get()
+            .returning(move |_| Ok(Some(world.clone())));
+        repos
+            .properties
+            .expect_get()
+            .returning(move |_| Ok(Some(property.clone())));
+        repos
+            .properties
+            .expect_save()
+            .withf(move |p| p.upkeep_paid_through == game_now)
+            .returning(|_| Ok(()));
+        repos
+            .pcs
+            .expect_modify_stat()
+            .withf(move |id, stat, amount| *id == owner && stat == "GP" && *amount == -6)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        repos
+            .locations
+            .expect_get_location()
+            .returning(move |_| Ok(Some(location.clone())));
+
+        let payment = repos
+            .build()
+            .pay_upkeep(world_id, property_id)
+            .await
+            .expect("payment");
+
+        assert_eq!(payment.paid, 6);
+        assert_eq!(payment.currency.as_deref(), Some("GP"));
+        assert_eq!(payment.property.days_owed, 0);
+        assert_eq!(payment.property.site_name.as_deref(), Some("Tavern"));
+    }
+
+    #[tokio::test]
+    async fn staff_must_be_npcs_of_the_same_world() {
+        let world_id = WorldId::new();
+        let location = Location::new(world_id, "Tower", LocationType::Exterior);
+        let location_id = location.id;
+        let pc = PlayerCharacter::new("user", world_id, "Mira", location_id, Utc::now());
+        let pc_id = pc.id;
+        let stranger =
+            wrldbldr_domain::Character::new(WorldId::new(), "Stranger", CampbellArchetype::Ally);
+
+        let mut repos = Repos::new();
+        repos
+            .pcs
+            .expect_get()
+            .returning(move |_| Ok(Some(pc.clone())));
+        repos
+            .locations
+            .expect_get_location()
+            .returning(move |_| Ok(Some(location.clone())));
+        repos
+            .worlds
+            .expect_get()
+            .returning(move |_| Ok(Some(World::new("Varn", "desc", Utc::now()))));
+        let stranger_id = stranger.id;
+        repos
+            .characters
+            .expect_get()
+            .returning(move |_| Ok(Some(stranger.clone())));
+        repos.properties.expect_save().never();
+
+        let mut data = input(location_id);
+        data.staff.push(PropertyStaffInputData {
+            character_id: stranger_id.to_string(),
+            role: StaffRoleData::Steward,
+            duties: String::new(),
+        });
+        let result = repos.build().create(world_id, pc_id, data).await;
+
+        assert!(matches!(result, Err(PropertyError::StaffNotFound)));
+    }
+
+    #[tokio::test]
+    async fn properties_from_other_worlds_are_missing() {
+        let property = Property::new(
+            WorldId::new(),
+            PlayerCharacterId::new(),
+            "Tower",
+            PropertySite::Region(RegionId::new()),
+            Utc::now(),
+            Utc::now(),
+        )
+        .expect("valid property");
+        let property_id = property.id;
+        let mut repos = Repos::new();
+        repos
+            .properties
+            .expect_get()
+            .returning(move |_| Ok(Some(property.clone())));
+        repos.properties.expect_delete().never();
+
+        let result = repos.build().delete(WorldId::new(), property_id).await;
+
+        assert!(matches!(result, Err(PropertyError::PropertyNotFound)));
+    }
+}
@@ -0,0 +1,266 @@
+//! Arbitration between players talking to the same NPC.
+//!
+//! Answering each player separately when several speak to one NPC at once
+//! gets replies that talk past, or contradict, each other. Instead, a line
+//! to an NPC waits in that NPC's conversation queue for the world's merge
+//! window. Everything said to the NPC before the window closes is answered
+//! in one reply, with the lines in the order they were spoken.
+//!
+//! Held lines stay `processing` in the player action queue. Retracting one,
+//! or a safety signal rejecting the world's pending dialogue, takes it back
+//! out of its conversation queue.
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, MutexGuard};
+use uuid::Uuid;
+use wrldbldr_domain::{PlayerActionData, WorldId};
+
+/// A dequeued player action waiting for its NPC's merge window to close
+#[derive(Debug, Clone)]
+pub struct HeldAction {
+    pub id: Uuid,
+    pub data: PlayerActionData,
+}
+
+/// What has been said to one NPC since its window opened
+struct ConversationQueue {
+    closes_at: DateTime<Utc>,
+    actions: Vec<HeldAction>,
+}
+
+type ConversationKey = (WorldId, String);
+
+/// Lines waiting to be answered, per world and NPC
+///
+/// Held in memory; the player action worker picks up lines a restart
+/// dropped (see `ProcessPlayerAction::recover_held`).
+#[derive(Default)]
+pub struct ConversationQueues {
+    queues: Mutex<HashMap<ConversationKey, ConversationQueue>>,
+}
+
+impl ConversationQueues {
+    /// Queue `action` behind whatever was already said to its NPC. The
+    /// window closes `window` after the earliest line was said, which need
+    /// not be the first one dequeued.
+    pub fn hold(&self, action: HeldAction, window: Duration) {
+        let key = (
+            action.data.world_id,
+            action.data.target.clone().unwrap_or_default(),
+        );
+        let closes_at = action.data.timestamp + window;
+        let mut queues = self.lock();
+        let queue = queues.entry(key).or_insert_with(|| ConversationQueue {
+            closes_at,
+            actions: Vec::new(),
+        });
+        queue.closes_at = queue.closes_at.min(closes_at);
+        queue.actions.push(action);
+    }
+
+    /// The lines of the conversation whose window closed first, oldest line
+    /// first, once one has closed by `now`. Conversations in `paused` worlds
+    /// wait until play resumes.
+    pub fn take_due(
+        &self,
+        now: DateTime<Utc>,
+        paused: &HashSet<WorldId>,
+    ) -> Option<Vec<HeldAction>> {
+        let mut queues = self.lock();
+        let key = queues
+            .iter()
+            .filter(|((world_id, _), queue)| queue.closes_at <= now && !paused.contains(world_id))
+            .min_by_key(|(_, queue)| queue.closes_at)
+            .map(|(key, _)| key.clone())?;
+        let mut actions = queues.remove(&key)?.actions;
+        actions.sort_by_key(|action| action.data.timestamp);
+        Some(actions)
+    }
+
+    /// Take a held line back out of its conversation, if it is still waiting
+    pub fn remove(&self, action_id: Uuid) -> Option<HeldAction> {
+        let mut queues = self.lock();
+        let (key, index) = queues.iter().find_map(|(key, queue)| {
+            let index = queue.actions.iter().position(|a| a.id == action_id)?;
+            Some((key.clone(), index))
+        })?;
+        let queue = queues.get_mut(&key)?;
+        let action = queue.actions.remove(index);
+        if queue.actions.is_empty() {
+            queues.remove(&key);
+        }
+        Some(action)
+    }
+
+    /// Take every line still waiting in a world
+    pub fn drain_world(&self, world_id: WorldId) -> Vec<HeldAction> {
+        let mut queues = self.lock();
+        let keys: Vec<ConversationKey> = queues
+            .keys()
+            .filter(|(world, _)| *world == world_id)
+            .cloned()
+            .collect();
+        keys.into_iter()
+            .filter_map(|key| queues.remove(&key))
+            .flat_map(|queue| queue.actions)
+            .collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ConversationKey, ConversationQueue>> {
+        self.queues.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Whether an action is something said to an NPC, the only kind merged
+pub fn is_npc_dialogue(data: &PlayerActionData) -> bool {
+    data.target.is_some()
+        && data
+            .dialogue
+            .as_deref()
+            .is_some_and(|dialogue| !dialogue.trim().is_empty())
+}
+
+/// Speakers' lines as one block, one `Name: "line"` per line
+pub fn merged_dialogue(lines: &[(String, String)]) -> String {
+    lines
+        .iter()
+        .map(|(speaker, line)| format!("{}: \"{}\"", speaker, line.trim()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// "Aria", "Aria and Bram", "Aria, Bram and Cole"
+pub fn speaker_list(speakers: &[String]) -> String {
+    match speakers {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn said(world_id: WorldId, npc: &str, line: &str, at: DateTime<Utc>) -> HeldAction {
+        HeldAction {
+            id: Uuid::new_v4(),
+            data: PlayerActionData {
+                world_id,
+                player_id: "player".to_string(),
+                pc_id: None,
+                action_type: "talk".to_string(),
+                target: Some(npc.to_string()),
+                dialogue: Some(line.to_string()),
+                timestamp: at,
+                conversation_id: None,
+            },
+        }
+    }
+
+    #[test]
+    fn lines_to_one_npc_are_answered_together_once_the_window_closes() {
+        let world_id = WorldId::new();
+        let start = Utc::now();
+        let window = Duration::milliseconds(1500);
+        let queues = ConversationQueues::default();
+        let none_paused = HashSet::new();
+
+        let second = said(
+            world_id,
+            "mira",
+            "And who's paying?",
+            start + Duration::milliseconds(400),
+        );
+        queues.hold(second, window);
+        queues.hold(said(world_id, "mira", "Where's the ship?", start), window);
+        queues.hold(
+            said(
+                world_id,
+                "harbormaster",
+                "Papers, please.",
+                start + Duration::milliseconds(200),
+            ),
+            window,
+        );
+
+        assert!(queues
+            .take_due(start + Duration::milliseconds(1000), &none_paused)
+            .is_none());
+
+        // The window opened with the first line said, not the first one held
+        let later = start + Duration::milliseconds(1600);
+        let mira = queues.take_due(later, &none_paused).unwrap();
+        let lines: Vec<_> = mira
+            .iter()
+            .filter_map(|a| a.data.dialogue.as_deref())
+            .collect();
+        assert_eq!(lines, vec!["Where's the ship?", "And who's paying?"]);
+
+        let harbormaster = queues
+            .take_due(later + Duration::milliseconds(200), &none_paused)
+            .unwrap();
+        assert_eq!(harbormaster.len(), 1);
+        assert!(queues
+            .take_due(later + Duration::seconds(10), &none_paused)
+            .is_none());
+    }
+
+    #[test]
+    fn held_lines_can_be_taken_back_and_wait_out_a_pause() {
+        let world_id = WorldId::new();
+        let other_world = WorldId::new();
+        let start = Utc::now();
+        let window = Duration::milliseconds(1500);
+        let queues = ConversationQueues::default();
+
+        let typo = said(world_id, "mira", "Wehre's the ship?", start);
+        let typo_id = typo.id;
+        queues.hold(typo, window);
+        queues.hold(said(world_id, "harbormaster", "Papers?", start), window);
+        queues.hold(said(other_world, "mira", "Hello", start), window);
+
+        assert_eq!(queues.remove(typo_id).map(|a| a.id), Some(typo_id));
+        assert!(queues.remove(typo_id).is_none());
+
+        // A paused world's lines wait; the others are still answered
+        let later = start + Duration::seconds(2);
+        let paused = HashSet::from([world_id]);
+        let due = queues.take_due(later, &paused).unwrap();
+        assert_eq!(due[0].data.world_id, other_world);
+        assert!(queues.take_due(later, &paused).is_none());
+
+        let drained = queues.drain_world(world_id);
+        assert_eq!(drained.len(), 1);
+        assert!(queues.take_due(later, &HashSet::new()).is_none());
+    }
+
+    #[test]
+    fn only_lines_to_an_npc_merge() {
+        let mut action = said(WorldId::new(), "mira", "  ", Utc::now());
+        assert!(!is_npc_dialogue(&action.data));
+        action.data.dialogue = Some("Hello".to_string());
+        assert!(is_npc_dialogue(&action.data));
+        action.data.target = None;
+        assert!(!is_npc_dialogue(&action.data));
+    }
+
+    #[test]
+    fn merged_lines_name_each_speaker() {
+        let lines = vec![
+            ("Aria".to_string(), "Where's the ship? ".to_string()),
+            ("Bram".to_string(), "Who's paying?".to_string()),
+        ];
+        assert_eq!(
+            merged_dialogue(&lines),
+            "Aria: \"Where's the ship?\"\nBram: \"Who's paying?\""
+        );
+        let names = |n: &[&str]| n.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(speaker_list(&names(&["Aria"])), "Aria");
+        assert_eq!(
+            speaker_list(&names(&["Aria", "Bram", "Cole"])),
+            "Aria, Bram and Cole"
+        );
+    }
+}
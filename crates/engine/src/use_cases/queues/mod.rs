@@ -6,15 +6,28 @@
 //! 1. Player Action Queue -> Builds LLM prompt -> LLM Request Queue
 //! 2. LLM Request Queue -> Calls LLM -> DM Approval Queue
 //! 3. DM Approval Queue -> (handled by DM via WebSocket)
+//!
+//! Lines several players say to one NPC at nearly the same time are merged
+//! into one prompt on the way from 1 to 2 (see [`arbitration`]).
+
+pub mod arbitration;
 
 use std::sync::Arc;
 use uuid::Uuid;
 use wrldbldr_domain::{
-    CharacterContext, GamePromptRequest, LlmRequestData, LlmRequestType, PlayerActionContext,
-    PlayerActionData, SceneContext, WorldId,
+    AppSettings, CharacterContext, ContentSafetyConfig, EntityType, GamePromptRequest,
+    LlmRequestData, LlmRequestType, LlmTask, MergedLine, PlayerActionContext, PlayerActionData,
+    SceneContext, WorldId,
 };
 
-use crate::infrastructure::ports::{LlmPort, QueuePort, RepoError};
+use crate::infrastructure::ports::{
+    ClockPort, LlmPort, QueueItemData, QueueItemStatus, QueuePort, RepoError,
+};
+use crate::use_cases::ai::structured::generate_structured;
+use arbitration::{ConversationQueues, HeldAction};
+
+/// How many recent player actions are scanned for lines a restart dropped
+const HELD_SCAN_LIMIT: usize = 200;
 
 /// Events that need to be broadcast to clients after queue processing.
 ///
@@ -68,11 +81,15 @@ pub struct PlayerActionProcessed {
     pub action_id: uuid::Uuid,
     /// The LLM request ID that was queued
     pub llm_request_id: uuid::Uuid,
+    /// Actions by other players answered in the same prompt
+    pub merged_action_ids: Vec<uuid::Uuid>,
 }
 
 /// Process player action from queue.
 ///
 /// Dequeues player actions, builds LLM prompts, and enqueues LLM requests.
+/// Lines to an NPC wait out the world's merge window when other PCs are
+/// there to join in, and are answered together.
 pub struct ProcessPlayerAction {
     queue: Arc<dyn QueuePort>,
     character: Arc<crate::entities::Character>,
@@ -80,10 +97,23 @@ pub struct ProcessPlayerAction {
     staging: Arc<crate::entities::Staging>,
     scene: Arc<crate::entities::Scene>,
     world: Arc<crate::entities::World>,
+    location: Arc<crate::entities::Location>,
     narrative: Arc<crate::entities::Narrative>,
+    mentions: Arc<crate::use_cases::mentions::ManageMentions>,
+    economy: Arc<crate::use_cases::economy::ManageEconomy>,
+    noise: Arc<crate::use_cases::location_events::MakeNoise>,
+    suggestion_feedback: Arc<crate::use_cases::challenge::SuggestionFeedback>,
+    progress_clocks: Arc<crate::use_cases::progress_clock::ProgressClockOps>,
+    secrets: Arc<crate::use_cases::secrets::ManageSecrets>,
+    factions: Arc<crate::use_cases::factions::ManageFactions>,
+    settings: Arc<crate::entities::Settings>,
+    clock: Arc<dyn ClockPort>,
+    conversations: Arc<ConversationQueues>,
+    safety: Arc<crate::use_cases::safety::SafetySignals>,
 }
 
 impl ProcessPlayerAction {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         queue: Arc<dyn QueuePort>,
         character: Arc<crate::entities::Character>,
@@ -91,7 +121,19 @@ impl ProcessPlayerAction {
         staging: Arc<crate::entities::Staging>,
         scene: Arc<crate::entities::Scene>,
         world: Arc<crate::entities::World>,
+        location: Arc<crate::entities::Location>,
         narrative: Arc<crate::entities::Narrative>,
+        mentions: Arc<crate::use_cases::mentions::ManageMentions>,
+        economy: Arc<crate::use_cases::economy::ManageEconomy>,
+        noise: Arc<crate::use_cases::location_events::MakeNoise>,
+        suggestion_feedback: Arc<crate::use_cases::challenge::SuggestionFeedback>,
+        progress_clocks: Arc<crate::use_cases::progress_clock::ProgressClockOps>,
+        secrets: Arc<crate::use_cases::secrets::ManageSecrets>,
+        factions: Arc<crate::use_cases::factions::ManageFactions>,
+        settings: Arc<crate::entities::Settings>,
+        clock: Arc<dyn ClockPort>,
+        conversations: Arc<ConversationQueues>,
+        safety: Arc<crate::use_cases::safety::SafetySignals>,
     ) -> Self {
         Self {
             queue,
@@ -100,66 +142,214 @@ impl ProcessPlayerAction {
             staging,
             scene,
             world,
+            location,
             narrative,
+            mentions,
+            economy,
+            noise,
+            suggestion_feedback,
+            progress_clocks,
+            secrets,
+            factions,
+            settings,
+            clock,
+            conversations,
+            safety,
         }
     }
 
     /// Process the next player action in the queue.
     ///
-    /// Returns None if the queue is empty.
+    /// A line to an NPC may be held for the merge window instead; once the
+    /// window closes, a later call answers it with everything else said to
+    /// that NPC meanwhile. Held lines in a paused world wait for play to
+    /// resume.
+    ///
+    /// Returns None if nothing was ready to process.
     pub async fn execute(&self) -> Result<Option<PlayerActionProcessed>, QueueError> {
-        // Dequeue the next player action
-        let item = match self.queue.dequeue_player_action().await? {
-            Some(item) => item,
-            None => return Ok(None),
+        if let Some(item) = self.queue.dequeue_player_action().await? {
+            // Extract the action data
+            let action_data = match item.data {
+                crate::infrastructure::ports::QueueItemData::PlayerAction(data) => data,
+                _ => {
+                    // Wrong type - mark as failed and return
+                    self.queue
+                        .mark_failed(item.id, "Invalid queue item type")
+                        .await?;
+                    return Err(QueueError::InvalidItemType);
+                }
+            };
+            let action = HeldAction {
+                id: item.id,
+                data: action_data,
+            };
+
+            match self.merge_window(&action.data).await {
+                Some(window) => self.conversations.hold(action, window),
+                None => return self.respond(vec![action]).await,
+            }
+        }
+
+        let paused = self.safety.paused_worlds().await;
+        match self.conversations.take_due(self.clock.now(), &paused) {
+            Some(actions) => self.respond(actions).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Hold again the lines a restart dropped while they waited out their
+    /// merge window. Their windows have long closed, so the next calls
+    /// answer them. Returns how many were found.
+    pub async fn recover_held(&self) -> Result<usize, QueueError> {
+        let stuck = self
+            .queue
+            .list_by_type("player_action", HELD_SCAN_LIMIT)
+            .await?
+            .into_iter()
+            .filter(|item| item.status == QueueItemStatus::Processing);
+        let mut count = 0;
+        for item in stuck {
+            if let QueueItemData::PlayerAction(data) = item.data {
+                let action = HeldAction { id: item.id, data };
+                self.conversations.hold(action, chrono::Duration::zero());
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// How long a line waits for others to join in. None answers it straight
+    /// away: it isn't said to an NPC, the world doesn't merge, or no other
+    /// PC is there to speak up.
+    async fn merge_window(&self, action_data: &PlayerActionData) -> Option<chrono::Duration> {
+        if !arbitration::is_npc_dialogue(action_data) {
+            return None;
+        }
+        let window_ms = match self.settings.get_for_world(action_data.world_id).await {
+            Ok(settings) => settings.dialogue_merge_window_ms,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load merge window, using the default");
+                AppSettings::default().dialogue_merge_window_ms
+            }
         };
+        if window_ms == 0 {
+            return None;
+        }
 
-        // Extract the action data
-        let action_data = match item.data {
-            crate::infrastructure::ports::QueueItemData::PlayerAction(data) => data,
-            _ => {
-                // Wrong type - mark as failed and return
-                self.queue
-                    .mark_failed(item.id, "Invalid queue item type")
-                    .await?;
-                return Err(QueueError::InvalidItemType);
+        let pc_id = action_data.pc_id?;
+        let pcs = match self
+            .player_character
+            .list_in_world(action_data.world_id)
+            .await
+        {
+            Ok(pcs) => pcs,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to list PCs, answering straight away");
+                return None;
             }
         };
+        let speaker = pcs.iter().find(|pc| pc.id == pc_id)?;
+        let has_company = pcs.iter().any(|pc| {
+            pc.id != pc_id
+                && pc.is_active
+                && pc.is_alive
+                && pc.current_location_id == speaker.current_location_id
+                && pc.current_region_id == speaker.current_region_id
+        });
+        has_company.then(|| chrono::Duration::milliseconds(window_ms as i64))
+    }
+
+    /// Queue one NPC response request for `actions`: a single action, or
+    /// the lines of one NPC conversation in the order they were said.
+    async fn respond(
+        &self,
+        actions: Vec<HeldAction>,
+    ) -> Result<Option<PlayerActionProcessed>, QueueError> {
+        let Some((lead, joined)) = actions.split_first() else {
+            return Ok(None);
+        };
+
+        let mut action_data = lead.data.clone();
+        let mut speakers: Vec<String> = Vec::new();
+        let mut merged_lines = Vec::new();
+        if !joined.is_empty() {
+            let mut lines = Vec::with_capacity(actions.len());
+            for action in &actions {
+                let name = self.speaker_name(&action.data).await;
+                if !speakers.contains(&name) {
+                    speakers.push(name.clone());
+                }
+                let dialogue = action.data.dialogue.clone().unwrap_or_default();
+                merged_lines.push(MergedLine {
+                    action_id: action.id,
+                    pc_id: action.data.pc_id,
+                    dialogue: dialogue.clone(),
+                    conversation_id: action.data.conversation_id,
+                });
+                lines.push((name, dialogue));
+            }
+            action_data.dialogue = Some(arbitration::merged_dialogue(&lines));
+            tracing::info!(
+                world_id = %action_data.world_id,
+                action_id = %lead.id,
+                lines = lines.len(),
+                "Merged near-simultaneous lines to one NPC into one prompt"
+            );
+        }
 
         // Build the prompt with character context
         let prompt = self
-            .build_prompt(&action_data)
+            .build_prompt(&action_data, &speakers)
             .await
-            .unwrap_or_else(|_| self.build_fallback_prompt(&action_data));
+            .unwrap_or_else(|_| self.build_fallback_prompt(&action_data, &speakers));
 
         let llm_request = LlmRequestData {
             request_type: LlmRequestType::NpcResponse {
-                action_item_id: item.id,
+                action_item_id: lead.id,
             },
             world_id: action_data.world_id,
             pc_id: action_data.pc_id,
             prompt: Some(prompt),
             suggestion_context: None,
-            callback_id: item.id.to_string(),
+            callback_id: lead.id.to_string(),
             conversation_id: action_data.conversation_id,
+            retry_count: 0,
+            merged_lines,
         };
 
         // Enqueue the LLM request
         let llm_request_id = self.queue.enqueue_llm_request(&llm_request).await?;
 
-        // Mark the player action as complete
-        self.queue.mark_complete(item.id).await?;
+        // Mark the player actions as complete
+        for action in &actions {
+            self.queue.mark_complete(action.id).await?;
+        }
 
         Ok(Some(PlayerActionProcessed {
-            action_id: item.id,
+            action_id: lead.id,
             llm_request_id,
+            merged_action_ids: joined.iter().map(|action| action.id).collect(),
         }))
     }
 
+    /// Name of the PC behind an action, for merged lines
+    async fn speaker_name(&self, action_data: &PlayerActionData) -> String {
+        let pc = match action_data.pc_id {
+            Some(pc_id) => self.player_character.get(pc_id).await.ok().flatten(),
+            None => None,
+        };
+        pc.map(|pc| pc.name)
+            .unwrap_or_else(|| "A player".to_string())
+    }
+
     /// Build a full GamePromptRequest with character context from the database.
+    ///
+    /// `speakers` names the PCs when several lines were merged into the
+    /// action's dialogue.
     async fn build_prompt(
         &self,
         action_data: &PlayerActionData,
+        speakers: &[String],
     ) -> Result<GamePromptRequest, QueueError> {
         let pc = if let Some(pc_id) = action_data.pc_id {
             self.player_character.get(pc_id).await?
@@ -172,7 +362,7 @@ impl ProcessPlayerAction {
             .map(|pc| pc.name.clone())
             .unwrap_or_else(|| "Unknown Player".to_string());
 
-        let (pc_location_id, _pc_region_id) = pc
+        let (pc_location_id, pc_region_id) = pc
             .as_ref()
             .map(|pc| (Some(pc.current_location_id), pc.current_region_id))
             .unwrap_or((None, None));
@@ -206,21 +396,47 @@ impl ProcessPlayerAction {
             action_type: action_data.action_type.clone(),
             target: action_data.target.clone(),
             dialogue: action_data.dialogue.clone(),
+            speakers: speakers.to_vec(),
         };
 
         // Build minimal scene context
         // In a full implementation, we would load scene details from the database
         let current_scene = self.scene.get_current(action_data.world_id).await?;
-        let game_time = self
-            .world
-            .get(action_data.world_id)
-            .await?
-            .map(|world| world.game_time);
+        let world = self.world.get(action_data.world_id).await?;
+        // With the party split, the NPC plays to the speaker's scene only
+        let scene_note = world
+            .as_ref()
+            .zip(action_data.pc_id)
+            .and_then(|(world, pc_id)| world.parallel_scene_of(pc_id))
+            .map(|scene| scene.prompt_note());
+        let currency = world
+            .as_ref()
+            .and_then(|world| world.rule_system.variant.currency_field());
+        let game_time = world.map(|world| world.game_time);
         let game_time_display = game_time.as_ref().map(|gt| gt.display_date());
         let time_context = game_time
             .as_ref()
             .map(|gt| gt.time_of_day().display_name().to_string())
             .unwrap_or_else(|| "Present".to_string());
+        let lighting = match (pc_region_id, game_time.as_ref()) {
+            (Some(region_id), Some(game_time)) => {
+                match self.location.get_region(region_id).await? {
+                    Some(region) => Some(
+                        self.location
+                            .region_light(&region, game_time.time_of_day())
+                            .await?
+                            .describe()
+                            .to_string(),
+                    ),
+                    None => None,
+                }
+            }
+            _ => None,
+        };
+        let recent_sounds = match pc_region_id {
+            Some(region_id) => self.noise.recently_heard(region_id).await,
+            None => vec![],
+        };
 
         let scene_context = SceneContext {
             scene_name: current_scene
@@ -229,8 +445,40 @@ impl ProcessPlayerAction {
                 .unwrap_or_else(|| "Current Scene".to_string()),
             location_name: "Current Location".to_string(),
             time_context,
-            present_characters: vec![pc_name.clone(), target_name.clone()],
+            present_characters: if speakers.is_empty() {
+                vec![pc_name.clone(), target_name.clone()]
+            } else {
+                speakers
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once(target_name.clone()))
+                    .collect()
+            },
             region_items: vec![],
+            lighting,
+            recent_sounds,
+        };
+
+        // Aliases ground the prompt: the NPC's own, and those the player
+        // used ("the Grey Fox" is Vexis)
+        let aliases = match npc_id {
+            Some(id) => self
+                .mentions
+                .aliases_of(action_data.world_id, EntityType::Character, id.to_uuid())
+                .await
+                .unwrap_or_default(),
+            None => vec![],
+        };
+        let alias_references = match action_data.dialogue.as_deref() {
+            Some(text) => self
+                .mentions
+                .alias_references(action_data.world_id, text)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::warn!(error = %e, "Failed to resolve aliases, using none");
+                    vec![]
+                }),
+            None => vec![],
         };
 
         // Build responding character context
@@ -238,6 +486,7 @@ impl ProcessPlayerAction {
         let responding_character = CharacterContext {
             character_id: npc_id.map(|id| id.to_string()),
             name: target_name.clone(),
+            aliases,
             archetype: "NPC".to_string(),
             current_mood: None,
             disposition_toward_player: None,
@@ -249,12 +498,130 @@ impl ProcessPlayerAction {
         };
 
         // Build directorial notes with the prompt
-        let directorial_notes = format!(
-            "You are roleplaying as an NPC in a fantasy TTRPG. \
-            The player character \"{}\" says to {}: \"{}\". \
-            Respond in character as {}. Keep the response concise (1-3 sentences).",
-            pc_name, target_name, dialogue, target_name
-        );
+        let mut directorial_notes = if speakers.is_empty() {
+            format!(
+                "You are roleplaying as an NPC in a fantasy TTRPG. \
+                The player character \"{}\" says to {}: \"{}\". \
+                Respond in character as {}. Keep the response concise (1-3 sentences).",
+                pc_name, target_name, dialogue, target_name
+            )
+        } else {
+            format!(
+                "You are roleplaying as an NPC in a fantasy TTRPG. \
+                {} speak to {} at nearly the same time:\n{}\n\
+                Respond in character as {}, in one reply that answers each of them. \
+                Keep the response concise (a sentence or two for each).",
+                arbitration::speaker_list(speakers),
+                target_name,
+                dialogue,
+                target_name
+            )
+        };
+
+        if let Some(note) = scene_note {
+            directorial_notes.push_str("\n\n");
+            directorial_notes.push_str(&note);
+        }
+
+        // Anything the NPC sells is quoted at the local price, so market
+        // conditions reach the conversation without the DM stepping in
+        if let Some(npc_id) = npc_id {
+            let wares = self
+                .economy
+                .merchant_prices(action_data.world_id, npc_id, pc_location_id)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::warn!(error = %e, "Failed to price merchant wares, using none");
+                    vec![]
+                });
+            if !wares.is_empty() {
+                directorial_notes.push_str("\n\n");
+                directorial_notes.push_str(&wares_note(&target_name, currency, &wares));
+            }
+        }
+
+        // How this table's DM has treated past challenge suggestions, so new
+        // ones lean towards what gets accepted
+        match self
+            .suggestion_feedback
+            .preferences(action_data.world_id)
+            .await
+        {
+            Ok(prefs) => {
+                if let Some(note) = prefs.prompt_note() {
+                    directorial_notes.push_str("\n\n");
+                    directorial_notes.push_str(&note);
+                }
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load challenge suggestion preferences");
+            }
+        }
+
+        // The speaker's personal quests stay between the PC and the DM, but
+        // colour how the NPC casts them
+        if let Some(pc_id) = action_data.pc_id {
+            match self
+                .progress_clocks
+                .personal_quests(action_data.world_id, pc_id)
+                .await
+            {
+                Ok(quests) if !quests.is_empty() => {
+                    directorial_notes.push_str("\n\n");
+                    directorial_notes.push_str(&personal_quests_note(
+                        &pc_name,
+                        &target_name,
+                        &quests,
+                    ));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to load personal quests");
+                }
+            }
+        }
+
+        // So do the speaker's secrets: the NPC plays to them without knowing
+        if let Some(pc_id) = action_data.pc_id {
+            match self.secrets.kept_secrets(pc_id).await {
+                Ok(secrets) if !secrets.is_empty() => {
+                    directorial_notes.push_str("\n\n");
+                    directorial_notes.push_str(&kept_secrets_note(
+                        &pc_name,
+                        &target_name,
+                        &secrets,
+                    ));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to load PC secrets");
+                }
+            }
+        }
+
+        // The NPC speaks for any faction they belong to, and carries its
+        // opinion of the party
+        if let Some(npc_id) = npc_id {
+            match self
+                .factions
+                .member_factions(action_data.world_id, npc_id)
+                .await
+            {
+                Ok(factions) => {
+                    if let Some(note) = crate::use_cases::factions::faction_note(
+                        &target_name,
+                        npc_id,
+                        &factions,
+                    ) {
+                        directorial_notes.push_str("\n\n");
+                        directorial_notes.push_str(&note);
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to load NPC factions");
+                }
+            }
+        }
 
         // Fetch conversation history if we have both PC and NPC IDs
         // Default limit is 20 turns (can be made configurable via settings)
@@ -283,6 +650,7 @@ impl ProcessPlayerAction {
             responding_character,
             active_challenges: vec![],
             active_narrative_events: vec![],
+            alias_references,
             context_budget: None,
             scene_id: current_scene.as_ref().map(|scene| scene.id.to_string()),
             location_id: pc_location_id.map(|id| id.to_string()),
@@ -291,7 +659,11 @@ impl ProcessPlayerAction {
     }
 
     /// Build a fallback prompt when database lookups fail.
-    fn build_fallback_prompt(&self, action_data: &PlayerActionData) -> GamePromptRequest {
+    fn build_fallback_prompt(
+        &self,
+        action_data: &PlayerActionData,
+        speakers: &[String],
+    ) -> GamePromptRequest {
         let target_name = action_data
             .target
             .clone()
@@ -306,6 +678,7 @@ impl ProcessPlayerAction {
             action_type: action_data.action_type.clone(),
             target: action_data.target.clone(),
             dialogue: action_data.dialogue.clone(),
+            speakers: speakers.to_vec(),
         };
 
         let scene_context = SceneContext {
@@ -314,11 +687,14 @@ impl ProcessPlayerAction {
             time_context: "Present".to_string(),
             present_characters: vec!["Player".to_string(), target_name.clone()],
             region_items: vec![],
+            lighting: None,
+            recent_sounds: vec![],
         };
 
         let responding_character = CharacterContext {
             character_id: None,
             name: target_name.clone(),
+            aliases: vec![],
             archetype: "NPC".to_string(),
             current_mood: None,
             disposition_toward_player: None,
@@ -345,6 +721,7 @@ impl ProcessPlayerAction {
             responding_character,
             active_challenges: vec![],
             active_narrative_events: vec![],
+            alias_references: vec![],
             context_budget: None,
             scene_id: None,
             location_id: None,
@@ -369,14 +746,66 @@ pub struct LlmRequestProcessed {
 /// Process LLM request from queue.
 ///
 /// Dequeues LLM requests, calls the LLM, and enqueues DM approval requests.
+///
+/// Every prompt carries the world's content safety constraints, and NPC
+/// dialogue is checked against them before it reaches the approval queue.
+/// NPC dialogue uses the world's dialogue model; suggestions use its
+/// (cheaper) summarization model.
 pub struct ProcessLlmRequest {
     queue: Arc<dyn QueuePort>,
     llm: Arc<dyn LlmPort>,
+    world: Arc<crate::entities::World>,
+    settings: Arc<crate::entities::Settings>,
+    plugin_tools: Arc<crate::use_cases::plugins::PluginTools>,
 }
 
 impl ProcessLlmRequest {
-    pub fn new(queue: Arc<dyn QueuePort>, llm: Arc<dyn LlmPort>) -> Self {
-        Self { queue, llm }
+    pub fn new(
+        queue: Arc<dyn QueuePort>,
+        llm: Arc<dyn LlmPort>,
+        world: Arc<crate::entities::World>,
+        settings: Arc<crate::entities::Settings>,
+        plugin_tools: Arc<crate::use_cases::plugins::PluginTools>,
+    ) -> Self {
+        Self {
+            queue,
+            llm,
+            world,
+            settings,
+            plugin_tools,
+        }
+    }
+
+    /// Load a world's settings for model routing, falling back to defaults
+    /// (the engine's model for every task) on failure.
+    async fn world_settings(&self, world_id: WorldId) -> AppSettings {
+        match self.settings.get_for_world(world_id).await {
+            Ok(settings) => settings,
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    world_id = %world_id,
+                    "Failed to load world model settings, using the default model"
+                );
+                AppSettings::default()
+            }
+        }
+    }
+
+    /// Load a world's safety settings, falling back to defaults on failure.
+    async fn content_safety(&self, world_id: WorldId) -> ContentSafetyConfig {
+        match self.world.get(world_id).await {
+            Ok(Some(world)) => world.content_safety,
+            Ok(None) => ContentSafetyConfig::default(),
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    world_id = %world_id,
+                    "Failed to load content safety settings, using defaults"
+                );
+                ContentSafetyConfig::default()
+            }
+        }
     }
 
     /// Process the next LLM request in the queue.
@@ -407,6 +836,10 @@ impl ProcessLlmRequest {
             }
         };
 
+        let safety = self.content_safety(request_data.world_id).await;
+        let safety_constraints = safety.prompt_constraints();
+        let settings = self.world_settings(request_data.world_id).await;
+
         // Handle different request types
         match &request_data.request_type {
             LlmRequestType::OutcomeSuggestion {
@@ -420,7 +853,7 @@ impl ProcessLlmRequest {
                 let system_prompt = "You are a creative TTRPG game master assistant. \
                     Generate 3 alternative narrative descriptions for a challenge outcome. \
                     Each suggestion should be evocative and fit the fantasy setting. \
-                    Return each suggestion on a separate line, numbered 1-3.";
+                    Respond with a JSON object whose 'suggestions' array holds the 3 descriptions.";
 
                 let user_message = format!(
                     "Challenge: {}\nCurrent outcome description: \"{}\"\n{}Generate 3 alternative descriptions.",
@@ -432,33 +865,19 @@ impl ProcessLlmRequest {
                 let llm_request = crate::infrastructure::ports::LlmRequest::new(vec![
                     crate::infrastructure::ports::ChatMessage::user(&user_message),
                 ])
-                .with_system_prompt(system_prompt.to_string())
-                .with_temperature(0.8);
-
-                let llm_response = self
-                    .llm
-                    .generate(llm_request)
-                    .await
-                    .map_err(|e| QueueError::LlmError(e.to_string()))?;
-
-                // Parse suggestions from response (one per line)
-                let suggestions: Vec<String> = llm_response
-                    .content
-                    .lines()
-                    .filter(|line| !line.trim().is_empty())
-                    .map(|line| {
-                        // Strip numbering if present (e.g., "1. ", "1) ", etc.)
-                        let trimmed = line.trim();
-                        if let Some(rest) = trimmed.strip_prefix(|c: char| c.is_ascii_digit()) {
-                            rest.trim_start_matches(['.', ')', ':', '-', ' '])
-                                .trim()
-                                .to_string()
-                        } else {
-                            trimmed.to_string()
-                        }
-                    })
-                    .take(3)
-                    .collect();
+                .with_system_prompt(format!("{}\n\n{}", system_prompt, safety_constraints))
+                .with_temperature(0.8)
+                .with_model(settings.model_for(LlmTask::OutcomeSuggestion))
+                .with_world(request_data.world_id);
+
+                let reply: SuggestionList = generate_structured(
+                    self.llm.as_ref(),
+                    llm_request,
+                    suggestion_list_schema(3),
+                )
+                .await
+                .map_err(|e| QueueError::LlmError(e.to_string()))?;
+                let suggestions = reply.into_suggestions(3);
 
                 tracing::info!(
                     resolution_id = %resolution_id,
@@ -480,7 +899,7 @@ impl ProcessLlmRequest {
                 Ok(Some(LlmRequestProcessed {
                     request_id: item.id,
                     approval_id: *resolution_id, // Use resolution_id as the "approval" for tracking
-                    npc_dialogue: llm_response.content,
+                    npc_dialogue: suggestions.join("\n"),
                     broadcast_events: vec![broadcast_event],
                 }))
             }
@@ -507,33 +926,23 @@ impl ProcessLlmRequest {
                 let llm_request = crate::infrastructure::ports::LlmRequest::new(vec![
                     crate::infrastructure::ports::ChatMessage::user(&prompt),
                 ])
-                .with_system_prompt(
-                    "You are a helpful worldbuilding assistant. Return only suggestions, one per line.".to_string(),
+                .with_system_prompt(format!(
+                    "You are a helpful worldbuilding assistant. Respond with a JSON object whose 'suggestions' array holds each suggestion.\n\n{}",
+                    safety_constraints
+                ))
+                .with_temperature(0.8)
+                .with_model(settings.model_for(LlmTask::Suggestion))
+                .with_cache(is_name_field(field_type))
+                .with_world(world_id);
+
+                let reply: SuggestionList = generate_structured(
+                    self.llm.as_ref(),
+                    llm_request,
+                    suggestion_list_schema(10),
                 )
-                .with_temperature(0.8);
-
-                let llm_response = self
-                    .llm
-                    .generate(llm_request)
-                    .await
-                    .map_err(|e| QueueError::LlmError(e.to_string()))?;
-
-                let suggestions: Vec<String> = llm_response
-                    .content
-                    .lines()
-                    .map(str::trim)
-                    .filter(|l| !l.is_empty())
-                    .map(|line| {
-                        // Strip simple numbering/bullets
-                        line.trim_start_matches(|c: char| {
-                            c.is_ascii_digit() || matches!(c, '.' | ')' | '-' | ':' | ' ')
-                        })
-                        .trim()
-                        .to_string()
-                    })
-                    .filter(|l| !l.is_empty())
-                    .take(10)
-                    .collect();
+                .await
+                .map_err(|e| QueueError::LlmError(e.to_string()))?;
+                let suggestions = reply.into_suggestions(10);
 
                 // Persist for hydration.
                 let result_json = serde_json::json!({ "suggestions": suggestions });
@@ -549,7 +958,7 @@ impl ProcessLlmRequest {
                 Ok(Some(LlmRequestProcessed {
                     request_id: item.id,
                     approval_id: uuid::Uuid::nil(),
-                    npc_dialogue: llm_response.content,
+                    npc_dialogue: suggestions.join("\n"),
                     broadcast_events: vec![BroadcastEvent::SuggestionComplete {
                         world_id,
                         request_id: callback_id,
@@ -564,28 +973,37 @@ impl ProcessLlmRequest {
                     // Use the full GamePromptRequest to build a rich prompt
                     let system_prompt = format!(
                         "You are roleplaying as an NPC in a fantasy TTRPG. {}\n\n\
-                        Scene: {} at {}\n\
-                        Present characters: {}\n\n\
+                        Scene: {} at {}\n{}{}\
+                        Present characters: {}\n{}\n\
                         Respond in character. Keep responses concise (1-3 sentences). \
-                        Stay true to the NPC's personality and motivations.",
+                        Stay true to the NPC's personality and motivations.\n\n{}",
                         prompt.directorial_notes,
                         prompt.scene_context.scene_name,
                         prompt.scene_context.location_name,
-                        prompt.scene_context.present_characters.join(", ")
+                        lighting_note(prompt),
+                        sounds_note(prompt),
+                        prompt.scene_context.present_characters.join(", "),
+                        alias_notes(prompt),
+                        safety_constraints
                     );
 
-                    let user_message = if let Some(ref dialogue) = prompt.player_action.dialogue {
-                        format!(
-                            "The player character says to {}: \"{}\"",
-                            prompt.player_action.target.as_deref().unwrap_or("you"),
+                    let action = &prompt.player_action;
+                    let target = action.target.as_deref().unwrap_or("you");
+                    let user_message = match action.dialogue {
+                        // Several PCs spoke at once; the lines name each speaker
+                        Some(ref dialogue) if !action.speakers.is_empty() => format!(
+                            "{} speak to {} at once:\n{}",
+                            arbitration::speaker_list(&action.speakers),
+                            prompt.responding_character.name,
                             dialogue
-                        )
-                    } else {
-                        format!(
+                        ),
+                        Some(ref dialogue) => {
+                            format!("The player character says to {}: \"{}\"", target, dialogue)
+                        }
+                        None => format!(
                             "The player character performs action '{}' targeting {}",
-                            prompt.player_action.action_type,
-                            prompt.player_action.target.as_deref().unwrap_or("you")
-                        )
+                            action.action_type, target
+                        ),
                     };
 
                     crate::infrastructure::ports::LlmRequest::new(vec![
@@ -601,16 +1019,37 @@ impl ProcessLlmRequest {
                             "Generate a brief, in-character NPC response to the player's action.",
                         ),
                     ])
-                    .with_system_prompt(
-                        "You are an NPC in a fantasy TTRPG. Respond briefly and in character.",
-                    )
+                    .with_system_prompt(format!(
+                        "You are an NPC in a fantasy TTRPG. Respond briefly and in character.\n\n{}",
+                        safety_constraints
+                    ))
                 };
 
-                let llm_response = self
-                    .llm
-                    .generate(llm_request)
-                    .await
-                    .map_err(|e| QueueError::LlmError(e.to_string()))?;
+                let llm_request = llm_request
+                    .with_model(settings.model_for(LlmTask::NpcDialogue))
+                    .with_world(request_data.world_id);
+                // Plugin tools are only offered when some are loaded, so worlds
+                // without plugins send exactly the same request as before
+                let tools = self.plugin_tools.definitions();
+                let llm_response = if tools.is_empty() {
+                    self.llm.generate(llm_request).await
+                } else {
+                    self.llm.generate_with_tools(llm_request, tools).await
+                }
+                .map_err(|e| QueueError::LlmError(e.to_string()))?;
+                let proposed_tools = self.plugin_tools.proposals(&llm_response.tool_calls);
+
+                // Flag (rather than drop) output that trips the safety filter so
+                // the DM sees a warning and decides before any player does
+                let safety_warnings = safety.check_output(&llm_response.content);
+                if !safety_warnings.is_empty() {
+                    tracing::warn!(
+                        world_id = %request_data.world_id,
+                        request_id = %item.id,
+                        warnings = ?safety_warnings,
+                        "NPC response flagged by content safety filter"
+                    );
+                }
 
                 let (npc_id, npc_name, player_dialogue, scene_id, location_id, game_time) =
                     if let Some(ref prompt) = request_data.prompt {
@@ -647,8 +1086,8 @@ impl ProcessLlmRequest {
                     npc_name,
                     proposed_dialogue: llm_response.content.clone(),
                     internal_reasoning: String::new(),
-                    proposed_tools: vec![],
-                    retry_count: 0,
+                    proposed_tools,
+                    retry_count: request_data.retry_count,
                     challenge_suggestion: None,
                     narrative_event_suggestion: None,
                     challenge_outcome: None,
@@ -658,6 +1097,8 @@ impl ProcessLlmRequest {
                     game_time,
                     topics: vec![],
                     conversation_id: request_data.conversation_id,
+                    safety_warnings,
+                    merged_lines: request_data.merged_lines.clone(),
                 };
 
                 // Enqueue for DM approval
@@ -677,6 +1118,44 @@ impl ProcessLlmRequest {
     }
 }
 
+/// Suggestions the LLM returns for outcome and content suggestion requests
+#[derive(Debug, serde::Deserialize)]
+struct SuggestionList {
+    suggestions: Vec<String>,
+}
+
+impl SuggestionList {
+    /// Trimmed, non-empty suggestions, at most `limit` of them.
+    fn into_suggestions(self, limit: usize) -> Vec<String> {
+        self.suggestions
+            .into_iter()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .take(limit)
+            .collect()
+    }
+}
+
+fn suggestion_list_schema(max_items: usize) -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "suggestions": {
+                "type": "array",
+                "items": { "type": "string", "minLength": 1 },
+                "minItems": 1,
+                "maxItems": max_items
+            }
+        },
+        "required": ["suggestions"]
+    })
+}
+
+/// Name suggestions are cheap to reuse; descriptions should stay fresh.
+fn is_name_field(field_type: &str) -> bool {
+    field_type.ends_with("_name")
+}
+
 fn build_suggestion_prompt(
     field_type: &str,
     context: &wrldbldr_domain::SuggestionContext,
@@ -689,56 +1168,195 @@ fn build_suggestion_prompt(
 
     match field_type {
         "character_name" => format!(
-            "Generate 5 unique character names for a {} in a {} setting. Hints: {}. Return one per line.",
+            "Generate 5 unique character names for a {} in a {} setting. Hints: {}.",
             entity_type, world_setting, hints
         ),
         "location_name" => format!(
-            "Generate 5 evocative names for a {} called '{}' in a {} setting. Hints: {}. Return one per line.",
+            "Generate 5 evocative names for a {} called '{}' in a {} setting. Hints: {}.",
             entity_type, entity_name, world_setting, hints
         ),
         "character_description" => format!(
-            "Generate 3 different physical descriptions for '{}' (a {}). Setting: {}. Hints: {}. Return each description on its own line.",
+            "Generate 3 different physical descriptions for '{}' (a {}). Setting: {}. Hints: {}.",
             entity_name, entity_type, world_setting, hints
         ),
         "location_description" => format!(
-            "Generate 3 different descriptions for '{}' (a {}). Setting: {}. Hints: {}. Return each description on its own line.",
+            "Generate 3 different descriptions for '{}' (a {}). Setting: {}. Hints: {}.",
             entity_name, entity_type, world_setting, hints
         ),
         "deflection_behavior" => format!(
-            "Generate 3 different deflection behaviors for {entity_name} when trying to hide their desire for: {hints}.\nSetting: {world_setting}.\nCharacter context: {extra}.\n\nA deflection behavior is how a character acts to conceal their true want - nervous habits, diversionary topics, or defensive responses.\nEach suggestion should be 1-2 sentences describing the specific behavior.\nReturn each suggestion on its own line.",
+            "Generate 3 different deflection behaviors for {entity_name} when trying to hide their desire for: {hints}.\nSetting: {world_setting}.\nCharacter context: {extra}.\n\nA deflection behavior is how a character acts to conceal their true want - nervous habits, diversionary topics, or defensive responses.\nEach suggestion should be 1-2 sentences describing the specific behavior.",
             entity_name = entity_name,
             hints = hints,
             world_setting = world_setting,
             extra = extra
         ),
         "behavioral_tells" => format!(
-            "Generate 3 different behavioral tells for {entity_name} that reveal their hidden desire for: {hints}.\nSetting: {world_setting}.\nCharacter context: {extra}.\n\nA behavioral tell is a subtle sign that betrays the character's true motivation - a glance, a pause, an involuntary reaction.\nThese are clues perceptive players might notice.\nEach suggestion should be 1-2 sentences describing the specific tell.\nReturn each suggestion on its own line.",
+            "Generate 3 different behavioral tells for {entity_name} that reveal their hidden desire for: {hints}.\nSetting: {world_setting}.\nCharacter context: {extra}.\n\nA behavioral tell is a subtle sign that betrays the character's true motivation - a glance, a pause, an involuntary reaction.\nThese are clues perceptive players might notice.\nEach suggestion should be 1-2 sentences describing the specific tell.",
             entity_name = entity_name,
             hints = hints,
             world_setting = world_setting,
             extra = extra
         ),
         "want_description" => format!(
-            "Generate 3 different want descriptions for {entity_name} in a {world_setting} setting.\nCharacter archetype: {hints}.\nAdditional context: {extra}.\n\nEach want should be phrased as a specific desire or goal, not a personality trait.\nFocus on what the character actively pursues or needs.\nEach description should be a single compelling sentence.\nReturn each want on its own line.",
+            "Generate 3 different want descriptions for {entity_name} in a {world_setting} setting.\nCharacter archetype: {hints}.\nAdditional context: {extra}.\n\nEach want should be phrased as a specific desire or goal, not a personality trait.\nFocus on what the character actively pursues or needs.\nEach description should be a single compelling sentence.",
             entity_name = entity_name,
             world_setting = world_setting,
             hints = hints,
             extra = extra
         ),
         "actantial_reason" => format!(
-            "Generate 3 different reasons why {entity_name} views {hints} as {extra} regarding their current goal.\nSetting: {world_setting}.\n\nProvide narrative justifications for this actantial relationship that could drive interesting roleplay.\nEach reason should explain the history, incident, or belief that created this dynamic.\nEach suggestion should be 1-2 sentences.\nReturn each reason on its own line.",
+            "Generate 3 different reasons why {entity_name} views {hints} as {extra} regarding their current goal.\nSetting: {world_setting}.\n\nProvide narrative justifications for this actantial relationship that could drive interesting roleplay.\nEach reason should explain the history, incident, or belief that created this dynamic.\nEach suggestion should be 1-2 sentences.",
             entity_name = entity_name,
             hints = hints,
             extra = extra,
             world_setting = world_setting
         ),
         other => format!(
-            "Generate 4 suggestions for {} for '{}' ({}). Setting: {}. Hints: {}. Context: {}. Return one per line.",
+            "Generate 4 suggestions for {} for '{}' ({}). Setting: {}. Hints: {}. Context: {}.",
             other, entity_name, entity_type, world_setting, hints, extra
         ),
     }
 }
 
+/// A line saying how well the NPC can see, ending in a newline; empty when
+/// the lighting is unknown
+fn lighting_note(prompt: &GamePromptRequest) -> String {
+    match &prompt.scene_context.lighting {
+        Some(lighting) => format!("The area is {}.\n", lighting),
+        None => String::new(),
+    }
+}
+
+/// A line listing what the NPC heard from next door lately, ending in a
+/// newline; empty when it has been quiet
+fn sounds_note(prompt: &GamePromptRequest) -> String {
+    if prompt.scene_context.recent_sounds.is_empty() {
+        return String::new();
+    }
+    format!(
+        "Recently heard nearby: {}.\n",
+        prompt.scene_context.recent_sounds.join("; ")
+    )
+}
+
+/// Lines telling the NPC which names are the same person or place, one
+/// per line, each ending in a newline
+fn alias_notes(prompt: &GamePromptRequest) -> String {
+    let mut notes = String::new();
+    let character = &prompt.responding_character;
+    if !character.aliases.is_empty() {
+        notes.push_str(&format!(
+            "{} is also known as {}.\n",
+            character.name,
+            character.aliases.join(", ")
+        ));
+    }
+    for reference in &prompt.alias_references {
+        notes.push_str(&format!(
+            "\"{}\" refers to {} ({}).\n",
+            reference.alias, reference.name, reference.entity_type
+        ));
+    }
+    notes
+}
+
+/// What a PC hides, as context the NPC must not let on about
+fn kept_secrets_note(
+    pc_name: &str,
+    npc_name: &str,
+    secrets: &[wrldbldr_domain::PcSecret],
+) -> String {
+    let hidden: Vec<String> = secrets
+        .iter()
+        .map(|secret| {
+            if secret.content.is_empty() {
+                secret.title.clone()
+            } else {
+                format!("{} ({})", secret.title, secret.content)
+            }
+        })
+        .collect();
+    format!(
+        "{pc} hides: {hidden}. {npc} does not know any of this unless it has \
+        come out; let it shape subtext and {pc}'s openings, \
+        but never reveal it.",
+        pc = pc_name,
+        npc = npc_name,
+        hidden = hidden.join("; ")
+    )
+}
+
+/// A PC's private objectives, cast as actantial roles for the NPC
+fn personal_quests_note(
+    pc_name: &str,
+    npc_name: &str,
+    quests: &[crate::use_cases::progress_clock::PersonalQuest],
+) -> String {
+    let goals: Vec<String> = quests
+        .iter()
+        .map(|quest| {
+            let mut goal = quest.name.clone();
+            if let Some(description) = quest.description.as_deref().filter(|d| !d.is_empty()) {
+                goal.push_str(&format!(" ({})", description));
+            }
+            if let Some(party_goal) = &quest.conflicts_with {
+                goal.push_str(&format!(
+                    ", which works against the party's \"{}\"",
+                    party_goal
+                ));
+            }
+            goal
+        })
+        .collect();
+    format!(
+        "{pc} secretly pursues: {goals}. If this bears on what {npc} wants, \
+        {npc} may treat {pc} as a helper or an opponent accordingly, \
+        but must never reveal these goals to anyone else.",
+        pc = pc_name,
+        npc = npc_name,
+        goals = goals.join("; ")
+    )
+}
+
+/// What an NPC has for sale and why prices are what they are, for the prompt
+fn wares_note(
+    npc_name: &str,
+    currency: Option<&str>,
+    wares: &[crate::use_cases::economy::MerchantPrice],
+) -> String {
+    let mut reasons: Vec<&str> = Vec::new();
+    let items: Vec<String> = wares
+        .iter()
+        .map(|ware| {
+            for reason in &ware.reasons {
+                if !reasons.contains(&reason.as_str()) {
+                    reasons.push(reason);
+                }
+            }
+            let price = match currency {
+                Some(currency) => format!("{} {}", ware.price, currency),
+                None => ware.price.to_string(),
+            };
+            match ware.scarcity {
+                wrldbldr_domain::Scarcity::Common => format!("{} ({})", ware.item_name, price),
+                scarcity => format!("{} ({}, {})", ware.item_name, price, scarcity),
+            }
+        })
+        .collect();
+    let mut note = format!(
+        "{} has these for sale; quote these prices if asked: {}.",
+        npc_name,
+        items.join(", ")
+    );
+    if !reasons.is_empty() {
+        note.push_str(&format!(
+            " Prices here are affected by: {}.",
+            reasons.join("; ")
+        ));
+    }
+    note
+}
+
 fn parse_typed_id<T: From<Uuid>>(value: &str) -> Option<T> {
     Uuid::parse_str(value).ok().map(T::from)
 }
@@ -0,0 +1,420 @@
+//! Rule system use cases.
+//!
+//! Changing a world's rule system replaces its preset skills with the new
+//! system's and moves each challenge over to the matching skill through the
+//! world's skill alias table ("Perception" becomes "Notice" in Fate). PC
+//! sheets are carried over to the new system's sheet schema the same way,
+//! and each PC keeps the old sheet as a version. The DM previews the change
+//! first, picks skills for the challenges the table doesn't cover and sees
+//! which sheet fields have no counterpart.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    character_sheet_schema, default_skills_for_variant, system_id_for_variant, CharacterSheetData,
+    RuleSystemConfig, RuleSystemVariant, WorldId,
+};
+use wrldbldr_protocol::types::{
+    ChallengeSkillRemapData, RuleSystemSwitchData, SheetConversionData, SkillOverrideData,
+};
+
+use crate::entities::{Challenge, PlayerCharacter, Skill, World};
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for rule system use cases.
+pub struct RuleSystemUseCases {
+    pub change: Arc<ChangeRuleSystem>,
+}
+
+impl RuleSystemUseCases {
+    pub fn new(change: Arc<ChangeRuleSystem>) -> Self {
+        Self { change }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RuleSystemError {
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+/// Move a world to another rule system.
+pub struct ChangeRuleSystem {
+    world: Arc<World>,
+    skill: Arc<Skill>,
+    challenge: Arc<Challenge>,
+    player_character: Arc<PlayerCharacter>,
+    clock: Arc<dyn ClockPort>,
+}
+
+/// A change worked out but not yet applied.
+struct ChangePlan {
+    data: RuleSystemSwitchData,
+    /// Challenges whose check changes, already updated
+    remapped: Vec<wrldbldr_domain::Challenge>,
+    /// PCs and the sheets they get under the new system
+    sheets: Vec<(wrldbldr_domain::PlayerCharacter, CharacterSheetData)>,
+}
+
+impl ChangeRuleSystem {
+    pub fn new(
+        world: Arc<World>,
+        skill: Arc<Skill>,
+        challenge: Arc<Challenge>,
+        player_character: Arc<PlayerCharacter>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            world,
+            skill,
+            challenge,
+            player_character,
+            clock,
+        }
+    }
+
+    /// What changing to `variant` would do, without changing anything.
+    pub async fn preview(
+        &self,
+        world_id: WorldId,
+        variant: RuleSystemVariant,
+    ) -> Result<RuleSystemSwitchData, RuleSystemError> {
+        let world = self.get_world(world_id).await?;
+        Ok(self.plan(&world, &variant, &[]).await?.data)
+    }
+
+    /// Change the world to `variant`.
+    ///
+    /// Preset skills are replaced with the new system's; custom skills stay.
+    /// Challenges the DM picked a skill for, or that the alias table matches,
+    /// check the new skill; the rest keep their check. When the sheet schema
+    /// changes, each PC gets the converted sheet and keeps the old one as a
+    /// version, so fields that didn't carry over aren't lost. The world's own
+    /// alias table carries over to the new configuration.
+    pub async fn apply(
+        &self,
+        world_id: WorldId,
+        variant: RuleSystemVariant,
+        overrides: Vec<SkillOverrideData>,
+    ) -> Result<RuleSystemSwitchData, RuleSystemError> {
+        let mut world = self.get_world(world_id).await?;
+        let plan = self.plan(&world, &variant, &overrides).await?;
+
+        let existing = self.skill.list_in_world(world_id).await?;
+        for skill in existing.iter().filter(|s| !s.is_custom) {
+            self.skill.delete(skill.id).await?;
+        }
+        for skill in default_skills_for_variant(world_id, &variant) {
+            let shadowed = existing
+                .iter()
+                .any(|s| s.is_custom && s.name.eq_ignore_ascii_case(&skill.name));
+            if !shadowed {
+                self.skill.save(&skill).await?;
+            }
+        }
+        for challenge in &plan.remapped {
+            self.challenge.save(challenge).await?;
+        }
+        let now = self.clock.now();
+        for (mut pc, sheet) in plan.sheets {
+            pc.replace_sheet(world.rule_system.variant.clone(), sheet, now);
+            self.player_character.save(&pc).await?;
+        }
+
+        let rule_system = RuleSystemConfig {
+            skill_aliases: world.rule_system.skill_aliases.clone(),
+            ..RuleSystemConfig::from_variant(variant)
+        };
+        world.set_rule_system(rule_system, now);
+        self.world.save(&world).await?;
+
+        tracing::info!(
+            world_id = %world_id,
+            from = ?plan.data.from_variant,
+            to = ?plan.data.to_variant,
+            remapped = plan.remapped.len(),
+            sheets = plan.data.sheets.len(),
+            "Changed rule system"
+        );
+        Ok(plan.data)
+    }
+
+    async fn plan(
+        &self,
+        world: &wrldbldr_domain::World,
+        variant: &RuleSystemVariant,
+        overrides: &[SkillOverrideData],
+    ) -> Result<ChangePlan, RuleSystemError> {
+        if matches!(variant, RuleSystemVariant::Unknown) {
+            return Err(RuleSystemError::Invalid(
+                "Unknown rule system variant".to_string(),
+            ));
+        }
+
+        let skills: Vec<String> = default_skills_for_variant(world.id, variant)
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        let custom: Vec<String> = self
+            .skill
+            .list_in_world(world.id)
+            .await?
+            .into_iter()
+            .filter(|s| s.is_custom)
+            .map(|s| s.name)
+            .collect();
+        let candidates: Vec<&str> = skills.iter().chain(&custom).map(String::as_str).collect();
+        let aliases = world.rule_system.skill_aliases_or_default();
+
+        let challenges = self.challenge.list_for_world(world.id).await?;
+        for o in overrides {
+            if o.skill.trim().is_empty() {
+                return Err(RuleSystemError::Invalid(
+                    "Skill override needs a skill name".to_string(),
+                ));
+            }
+            if !challenges
+                .iter()
+                .any(|c| c.id.to_string() == o.challenge_id)
+            {
+                return Err(RuleSystemError::Invalid(format!(
+                    "Challenge {} is not in this world",
+                    o.challenge_id
+                )));
+            }
+        }
+
+        let mut rows = Vec::new();
+        let mut remapped = Vec::new();
+        for mut challenge in challenges {
+            let Some(from_skill) = challenge.check_stat.clone() else {
+                continue;
+            };
+            let picked = overrides
+                .iter()
+                .find(|o| o.challenge_id == challenge.id.to_string());
+            let to_skill = match picked {
+                Some(o) => {
+                    challenge.check_stat = Some(o.skill.trim().to_string());
+                    challenge.check_stat.clone()
+                }
+                None => challenge.remap_skill(&aliases, &candidates),
+            };
+            rows.push(ChallengeSkillRemapData {
+                challenge_id: challenge.id.to_string(),
+                challenge_name: challenge.name.clone(),
+                from_skill: from_skill.clone(),
+                to_skill: to_skill.clone(),
+            });
+            if to_skill.is_some_and(|to| to != from_skill) {
+                remapped.push(challenge);
+            }
+        }
+
+        // Sheets only need converting when the schema they follow changes
+        let from_system = system_id_for_variant(&world.rule_system.variant);
+        let system_id = system_id_for_variant(variant);
+        let mut sheet_rows = Vec::new();
+        let mut sheets = Vec::new();
+        let new_schema = if from_system == system_id {
+            None
+        } else {
+            character_sheet_schema(system_id)
+        };
+        if let Some(schema) = new_schema {
+            let old_schema = character_sheet_schema(from_system);
+            for pc in self.player_character.list_in_world(world.id).await? {
+                let Some(sheet) = pc.sheet_data.as_ref() else {
+                    continue;
+                };
+                let conversion = schema.convert_sheet(old_schema.as_ref(), sheet, &aliases);
+                sheet_rows.push(SheetConversionData {
+                    pc_id: pc.id.to_string(),
+                    pc_name: pc.name.clone(),
+                    carried: conversion.sheet.values.len() as u32,
+                    unconverted: conversion.unconverted,
+                });
+                sheets.push((pc, conversion.sheet));
+            }
+        }
+
+        Ok(ChangePlan {
+            data: RuleSystemSwitchData {
+                world_id: world.id.to_string(),
+                from_variant: world.rule_system.variant.clone(),
+                to_variant: variant.clone(),
+                system_id: system_id.to_string(),
+                skills,
+                challenges: rows,
+                sheets: sheet_rows,
+            },
+            remapped,
+            sheets,
+        })
+    }
+
+    async fn get_world(
+        &self,
+        world_id: WorldId,
+    ) -> Result<wrldbldr_domain::World, RuleSystemError> {
+        self.world
+            .get(world_id)
+            .await?
+            .ok_or(RuleSystemError::WorldNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        MockChallengeRepo, MockPlayerCharacterRepo, MockSkillRepo, MockWorldRepo,
+    };
+    use std::sync::Mutex;
+    use wrldbldr_domain::{Difficulty, FieldValue, LocationId, SkillCategory};
+
+    /// Saved challenges as (name, check stat)
+    type SavedChecks = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+    #[tokio::test]
+    async fn changing_to_fate_moves_challenges_and_sheets_to_fate() {
+        let now = chrono::Utc::now();
+        let world = wrldbldr_domain::World::new("Test World", "desc", now)
+            .with_rule_system(RuleSystemConfig::dnd_5e());
+        let world_id = world.id;
+
+        let watch =
+            wrldbldr_domain::Challenge::new(world_id, "Keep watch", Difficulty::d20_medium())
+                .with_check_stat("Perception");
+        let ride = wrldbldr_domain::Challenge::new(world_id, "Ride out", Difficulty::d20_medium())
+            .with_check_stat("Riding");
+        let lie = wrldbldr_domain::Challenge::new(world_id, "Bluff", Difficulty::d20_medium())
+            .with_check_stat("Deception");
+        let fly =
+            wrldbldr_domain::Challenge::new(world_id, "Fly the glider", Difficulty::d20_medium())
+                .with_check_stat("Pilot");
+        let ride_id = ride.id;
+        let challenges = vec![watch, ride, lie, fly];
+
+        let preset = wrldbldr_domain::Skill::new(world_id, "Perception", SkillCategory::Mental);
+        let preset_id = preset.id;
+        let riding = wrldbldr_domain::Skill::custom(world_id, "Riding", SkillCategory::Custom);
+        let skills = vec![preset, riding];
+
+        let mut world_repo = MockWorldRepo::new();
+        let stored = world.clone();
+        world_repo
+            .expect_get()
+            .returning(move |_| Ok(Some(stored.clone())));
+        world_repo
+            .expect_save()
+            .withf(|w| {
+                w.rule_system.variant == RuleSystemVariant::FateCore
+                    && w.rule_system.skill_aliases.is_none()
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut skill_repo = MockSkillRepo::new();
+        skill_repo
+            .expect_list_in_world()
+            .returning(move |_| Ok(skills.clone()));
+        skill_repo
+            .expect_delete()
+            .withf(move |id| *id == preset_id)
+            .times(1)
+            .returning(|_| Ok(()));
+        let added: Arc<Mutex<Vec<String>>> = Arc::default();
+        let added_in_save = added.clone();
+        skill_repo.expect_save().returning(move |s| {
+            added_in_save.lock().unwrap().push(s.name.clone());
+            Ok(())
+        });
+
+        let mut challenge_repo = MockChallengeRepo::new();
+        challenge_repo
+            .expect_list_for_world()
+            .returning(move |_| Ok(challenges.clone()));
+        let saved: SavedChecks = Arc::default();
+        let saved_in_save = saved.clone();
+        challenge_repo.expect_save().returning(move |c| {
+            saved_in_save
+                .lock()
+                .unwrap()
+                .push((c.name.clone(), c.check_stat.clone()));
+            Ok(())
+        });
+
+        let mut sheet = CharacterSheetData::new();
+        sheet.set("NAME", FieldValue::Text("Mira".to_string()));
+        sheet.set("STR", FieldValue::Number(16));
+        let pc =
+            wrldbldr_domain::PlayerCharacter::new("user", world_id, "Mira", LocationId::new(), now)
+                .with_sheet_data(sheet);
+        let mut pc_repo = MockPlayerCharacterRepo::new();
+        pc_repo
+            .expect_list_in_world()
+            .returning(move |_| Ok(vec![pc.clone()]));
+        pc_repo
+            .expect_save()
+            .withf(|pc| {
+                pc.sheet_versions.len() == 1
+                    && pc.sheet_versions[0].variant == RuleSystemVariant::Dnd5e
+                    && pc
+                        .sheet_data
+                        .as_ref()
+                        .is_some_and(|s| s.get("STR").is_none())
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(now));
+        let change = ChangeRuleSystem::new(
+            Arc::new(World::new(Arc::new(world_repo), clock.clone())),
+            Arc::new(Skill::new(Arc::new(skill_repo))),
+            Arc::new(Challenge::new(Arc::new(challenge_repo))),
+            Arc::new(PlayerCharacter::new(Arc::new(pc_repo))),
+            clock,
+        );
+
+        let result = change
+            .apply(
+                world_id,
+                RuleSystemVariant::FateCore,
+                vec![SkillOverrideData {
+                    challenge_id: ride_id.to_string(),
+                    skill: "Athletics".to_string(),
+                }],
+            )
+            .await
+            .expect("switched");
+
+        let to_skills: Vec<_> = result
+            .challenges
+            .iter()
+            .map(|c| (c.from_skill.as_str(), c.to_skill.as_deref()))
+            .collect();
+        assert_eq!(
+            to_skills,
+            vec![
+                ("Perception", Some("Notice")),
+                ("Riding", Some("Athletics")),
+                ("Deception", Some("Deceive")),
+                ("Pilot", None),
+            ]
+        );
+        assert_eq!(saved.lock().unwrap().len(), 3);
+        assert_eq!(result.system_id, "fate_core");
+        assert_eq!(result.sheets.len(), 1);
+        assert_eq!(result.sheets[0].carried, 1);
+        assert_eq!(result.sheets[0].unconverted, vec!["Strength".to_string()]);
+        let added = added.lock().unwrap();
+        assert!(added.contains(&"Notice".to_string()));
+        assert!(!added.contains(&"Perception".to_string()));
+    }
+}
@@ -0,0 +1,201 @@
+//! Safety tool use cases.
+//!
+//! Handles anonymous safety signals (X-card style) raised by players. Depending
+//! on the world's content safety settings a signal can pause the player action
+//! queue and reject NPC dialogue that is still waiting to reach the table.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use wrldbldr_domain::{ApprovalDecisionType, LlmRequestType, SafetySignalLevel, WorldId};
+use wrldbldr_protocol::types::SafetySignalLevelData;
+
+use crate::entities::World;
+use crate::infrastructure::ports::{
+    QueueError, QueueItemData, QueueItemStatus, QueuePort, RepoError,
+};
+use crate::use_cases::queues::arbitration::ConversationQueues;
+
+/// How many recent queue items are scanned when rejecting pending dialogue.
+const PENDING_SCAN_LIMIT: usize = 200;
+
+/// Container for safety use cases.
+pub struct SafetyUseCases {
+    pub signals: Arc<SafetySignals>,
+}
+
+impl SafetyUseCases {
+    pub fn new(signals: Arc<SafetySignals>) -> Self {
+        Self { signals }
+    }
+}
+
+/// Result of raising a safety signal.
+#[derive(Debug, Clone, Default)]
+pub struct SafetySignalOutcome {
+    /// Whether the signal paused the action queue
+    pub queue_paused: bool,
+    /// Approval request IDs that were rejected before reaching players
+    pub rejected_request_ids: Vec<String>,
+}
+
+/// Safety signal handling and per-world action queue pause state.
+///
+/// Pause state is held in memory; a restarted engine starts unpaused.
+pub struct SafetySignals {
+    world: Arc<World>,
+    queue: Arc<dyn QueuePort>,
+    conversations: Arc<ConversationQueues>,
+    paused_worlds: RwLock<HashSet<WorldId>>,
+}
+
+impl SafetySignals {
+    pub fn new(
+        world: Arc<World>,
+        queue: Arc<dyn QueuePort>,
+        conversations: Arc<ConversationQueues>,
+    ) -> Self {
+        Self {
+            world,
+            queue,
+            conversations,
+            paused_worlds: RwLock::new(HashSet::new()),
+        }
+    }
+
+    /// React to a player's safety signal using the world's configured thresholds.
+    pub async fn raise(
+        &self,
+        world_id: WorldId,
+        level: SafetySignalLevel,
+    ) -> Result<SafetySignalOutcome, SafetyError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(SafetyError::WorldNotFound)?;
+        let response = world.content_safety.signal_response(level);
+
+        let mut outcome = SafetySignalOutcome::default();
+        if response.pause_queue {
+            self.set_paused(world_id, true).await;
+            outcome.queue_paused = true;
+        }
+        if response.reject_pending {
+            outcome.rejected_request_ids = self.reject_pending_dialogue(world_id).await?;
+        }
+
+        tracing::info!(
+            world_id = %world_id,
+            level = %level,
+            queue_paused = outcome.queue_paused,
+            rejected = outcome.rejected_request_ids.len(),
+            "Safety signal raised"
+        );
+        Ok(outcome)
+    }
+
+    pub async fn is_paused(&self, world_id: WorldId) -> bool {
+        self.paused_worlds.read().await.contains(&world_id)
+    }
+
+    pub async fn paused_worlds(&self) -> HashSet<WorldId> {
+        self.paused_worlds.read().await.clone()
+    }
+
+    pub async fn set_paused(&self, world_id: WorldId, paused: bool) {
+        let mut guard = self.paused_worlds.write().await;
+        if paused {
+            guard.insert(world_id);
+        } else {
+            guard.remove(&world_id);
+        }
+    }
+
+    /// Reject NPC dialogue in the world that has not yet been approved.
+    ///
+    /// Drops lines waiting out a merge window, cancels queued NPC response
+    /// generations and fails outstanding approval requests. Returns the IDs
+    /// of the rejected approval requests.
+    async fn reject_pending_dialogue(&self, world_id: WorldId) -> Result<Vec<String>, SafetyError> {
+        for held in self.conversations.drain_world(world_id) {
+            self.queue
+                .mark_failed(held.id, "Cancelled by safety signal")
+                .await?;
+        }
+
+        let llm_requests = self
+            .queue
+            .list_by_type("llm_request", PENDING_SCAN_LIMIT)
+            .await?;
+        for item in llm_requests {
+            if item.status != QueueItemStatus::Pending {
+                continue;
+            }
+            if let QueueItemData::LlmRequest(data) = &item.data {
+                if data.world_id == world_id
+                    && matches!(data.request_type, LlmRequestType::NpcResponse { .. })
+                {
+                    self.queue
+                        .mark_failed(item.id, "Cancelled by safety signal")
+                        .await?;
+                }
+            }
+        }
+
+        let approvals = self
+            .queue
+            .list_by_type("dm_approval", PENDING_SCAN_LIMIT)
+            .await?;
+        let mut rejected = Vec::new();
+        for item in approvals {
+            if !matches!(
+                item.status,
+                QueueItemStatus::Pending | QueueItemStatus::Processing
+            ) {
+                continue;
+            }
+            if let QueueItemData::DmApproval(data) = &item.data {
+                if data.world_id == world_id
+                    && data.decision_type == ApprovalDecisionType::NpcResponse
+                {
+                    self.queue
+                        .mark_failed(item.id, "Rejected by safety signal")
+                        .await?;
+                    rejected.push(item.id.to_string());
+                }
+            }
+        }
+
+        Ok(rejected)
+    }
+}
+
+pub fn signal_level_from_protocol(level: SafetySignalLevelData) -> SafetySignalLevel {
+    match level {
+        SafetySignalLevelData::Check => SafetySignalLevel::Check,
+        SafetySignalLevelData::Skip => SafetySignalLevel::Skip,
+        SafetySignalLevelData::Stop => SafetySignalLevel::Stop,
+        SafetySignalLevelData::Unknown => SafetySignalLevel::Unknown,
+    }
+}
+
+pub fn signal_level_to_protocol(level: SafetySignalLevel) -> SafetySignalLevelData {
+    match level {
+        SafetySignalLevel::Check => SafetySignalLevelData::Check,
+        SafetySignalLevel::Skip => SafetySignalLevelData::Skip,
+        SafetySignalLevel::Stop => SafetySignalLevelData::Stop,
+        SafetySignalLevel::Unknown => SafetySignalLevelData::Unknown,
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SafetyError {
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+    #[error("Queue error: {0}")]
+    Queue(#[from] QueueError),
+}
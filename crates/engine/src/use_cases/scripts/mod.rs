@@ -0,0 +1,298 @@
+//! DM automation script use cases.
+//!
+//! DMs attach small Rhai scripts to hooks (item pickup, time advance,
+//! challenge resolution). When a hook fires, every enabled script on it runs
+//! within its operation budget, and the effects it queued are applied
+//! through [`ExecuteEffects`], so scripts can only do what narrative events
+//! already can. Script messages and failures come back as notices for DMs.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    validate_world_scripts, EventEffect, ScriptHook, WorldId, WorldScript, DEFAULT_SCRIPT_BUDGET,
+};
+use wrldbldr_protocol::types::{ScriptHookData, WorldScriptData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError, ScriptEnginePort, ScriptEvent};
+use crate::use_cases::narrative::{EffectExecutionContext, ExecuteEffects};
+
+/// Container for script use cases.
+pub struct ScriptUseCases {
+    pub manage: Arc<ManageScripts>,
+    pub hooks: Arc<RunScriptHooks>,
+}
+
+impl ScriptUseCases {
+    pub fn new(manage: Arc<ManageScripts>, hooks: Arc<RunScriptHooks>) -> Self {
+        Self { manage, hooks }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ScriptUseCaseError {
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("Invalid scripts: {0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+/// A script message or failure for the world's DMs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptNotice {
+    pub script: String,
+    pub message: String,
+    pub is_error: bool,
+}
+
+/// Read and replace a world's scripts.
+pub struct ManageScripts {
+    world: Arc<entities::World>,
+    engine: Arc<dyn ScriptEnginePort>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageScripts {
+    pub fn new(
+        world: Arc<entities::World>,
+        engine: Arc<dyn ScriptEnginePort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            world,
+            engine,
+            clock,
+        }
+    }
+
+    pub async fn get(&self, world_id: WorldId) -> Result<Vec<WorldScriptData>, ScriptUseCaseError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ScriptUseCaseError::WorldNotFound)?;
+        Ok(world.scripts.iter().map(script_to_protocol).collect())
+    }
+
+    /// Replace a world's scripts after checking every one compiles.
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        scripts: Vec<WorldScriptData>,
+    ) -> Result<Vec<WorldScriptData>, ScriptUseCaseError> {
+        let scripts = scripts
+            .into_iter()
+            .map(|data| script_from_protocol(data).map(WorldScript::normalized))
+            .collect::<Result<Vec<_>, _>>()?;
+        validate_world_scripts(&scripts).map_err(ScriptUseCaseError::Invalid)?;
+        for script in &scripts {
+            self.engine
+                .check(&script.source)
+                .map_err(|e| ScriptUseCaseError::Invalid(format!("{}: {}", script.name, e)))?;
+        }
+
+        let mut world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(ScriptUseCaseError::WorldNotFound)?;
+        world.set_scripts(scripts, self.clock.now());
+        self.world.save(&world).await?;
+        Ok(world.scripts.iter().map(script_to_protocol).collect())
+    }
+}
+
+/// Run the scripts attached to a hook and apply what they queue.
+pub struct RunScriptHooks {
+    world: Arc<entities::World>,
+    flag: Arc<entities::Flag>,
+    engine: Arc<dyn ScriptEnginePort>,
+    execute_effects: Arc<ExecuteEffects>,
+}
+
+impl RunScriptHooks {
+    pub fn new(
+        world: Arc<entities::World>,
+        flag: Arc<entities::Flag>,
+        engine: Arc<dyn ScriptEnginePort>,
+        execute_effects: Arc<ExecuteEffects>,
+    ) -> Self {
+        Self {
+            world,
+            flag,
+            engine,
+            execute_effects,
+        }
+    }
+
+    /// Run every enabled script on `event.hook`, in the order the DM listed
+    /// them. One script failing doesn't stop the others.
+    pub async fn fire(&self, event: ScriptEvent) -> Vec<ScriptNotice> {
+        let world = match self.world.get(event.world_id).await {
+            Ok(Some(world)) => world,
+            Ok(None) => return Vec::new(),
+            Err(e) => {
+                tracing::warn!(world_id = %event.world_id, error = %e, "Could not load world scripts");
+                return Vec::new();
+            }
+        };
+
+        let mut notices = Vec::new();
+        for script in world
+            .scripts
+            .iter()
+            .filter(|script| script.enabled && script.hook == event.hook)
+        {
+            let error = |message: String| ScriptNotice {
+                script: script.name.clone(),
+                message,
+                is_error: true,
+            };
+
+            let output = match self.engine.run(script, &event).await {
+                Ok(output) => output,
+                Err(e) => {
+                    tracing::info!(script = %script.name, hook = %event.hook, error = %e, "Script failed");
+                    notices.push(error(e.to_string()));
+                    continue;
+                }
+            };
+
+            for effect in &output.effects {
+                if let Err(message) = self.apply(effect, &event).await {
+                    notices.push(error(message));
+                }
+            }
+            notices.extend(output.notices.into_iter().map(|message| ScriptNotice {
+                script: script.name.clone(),
+                message,
+                is_error: false,
+            }));
+        }
+        notices
+    }
+
+    async fn apply(&self, effect: &EventEffect, event: &ScriptEvent) -> Result<(), String> {
+        match (event.pc_id, effect) {
+            (Some(pc_id), _) => {
+                let context = EffectExecutionContext {
+                    pc_id,
+                    world_id: event.world_id,
+                    current_scene_id: None,
+                };
+                let result = self
+                    .execute_effects
+                    .execute_single_effect(effect, &context)
+                    .await;
+                match result.error {
+                    Some(error) if !result.success => {
+                        Err(format!("{}: {}", result.description, error))
+                    }
+                    _ => Ok(()),
+                }
+            }
+            // Without a PC only world-level effects make sense
+            (None, EventEffect::SetFlag { flag_name, value }) => {
+                let result = if *value {
+                    self.flag.set_world_flag(event.world_id, flag_name).await
+                } else {
+                    self.flag.unset_world_flag(event.world_id, flag_name).await
+                };
+                result.map_err(|e| format!("Failed to set flag '{}': {}", flag_name, e))
+            }
+            (None, _) => Err("this hook has no player character".to_string()),
+        }
+    }
+}
+
+fn script_to_protocol(script: &WorldScript) -> WorldScriptData {
+    WorldScriptData {
+        name: script.name.clone(),
+        hook: match script.hook {
+            ScriptHook::ItemPickup => ScriptHookData::ItemPickup,
+            ScriptHook::TimeAdvance => ScriptHookData::TimeAdvance,
+            ScriptHook::ChallengeResolved => ScriptHookData::ChallengeResolved,
+        },
+        source: script.source.clone(),
+        enabled: script.enabled,
+        operation_budget: Some(script.operation_budget),
+    }
+}
+
+fn script_from_protocol(data: WorldScriptData) -> Result<WorldScript, ScriptUseCaseError> {
+    let hook = match data.hook {
+        ScriptHookData::ItemPickup => ScriptHook::ItemPickup,
+        ScriptHookData::TimeAdvance => ScriptHook::TimeAdvance,
+        ScriptHookData::ChallengeResolved => ScriptHook::ChallengeResolved,
+        ScriptHookData::Unknown => {
+            return Err(ScriptUseCaseError::Invalid(format!(
+                "script {:?} has an unknown hook",
+                data.name
+            )))
+        }
+    };
+    let mut script = WorldScript::new(data.name, hook, data.source)
+        .with_operation_budget(data.operation_budget.unwrap_or(DEFAULT_SCRIPT_BUDGET));
+    script.enabled = data.enabled;
+    Ok(script)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::MockWorldRepo;
+    use crate::infrastructure::rhai_scripts::RhaiScriptEngine;
+
+    fn manage(repo: MockWorldRepo) -> ManageScripts {
+        let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(chrono::Utc::now()));
+        ManageScripts::new(
+            Arc::new(entities::World::new(Arc::new(repo), clock.clone())),
+            Arc::new(RhaiScriptEngine::new()),
+            clock,
+        )
+    }
+
+    fn data(name: &str, source: &str) -> WorldScriptData {
+        WorldScriptData {
+            name: name.to_string(),
+            hook: ScriptHookData::ItemPickup,
+            source: source.to_string(),
+            enabled: true,
+            operation_budget: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_scripts_that_do_not_compile() {
+        // Validation happens before the world is loaded, so the repo is never hit
+        let err = manage(MockWorldRepo::new())
+            .update(WorldId::new(), vec![data("broken", "let x = ;")])
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, ScriptUseCaseError::Invalid(message) if message.starts_with("broken"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_saves_normalized_scripts() {
+        let world = wrldbldr_domain::World::new("W", "", chrono::Utc::now());
+        let world_id = world.id;
+        let mut repo = MockWorldRepo::new();
+        repo.expect_get()
+            .returning(move |_| Ok(Some(world.clone())));
+        repo.expect_save()
+            .withf(|world| world.scripts.len() == 1 && world.scripts[0].name == "bell")
+            .returning(|_| Ok(()));
+
+        let saved = manage(repo)
+            .update(world_id, vec![data(" bell ", r#"notify_dm("ding")"#)])
+            .await
+            .unwrap();
+        assert_eq!(saved[0].name, "bell");
+        assert_eq!(saved[0].operation_budget, Some(DEFAULT_SCRIPT_BUDGET));
+    }
+}
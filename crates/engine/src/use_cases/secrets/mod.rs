@@ -0,0 +1,339 @@
+//! PC secret use cases.
+//!
+//! Secrets the DM gives a PC: a hidden past, a secret agenda, a betrayal
+//! waiting for its moment. Only the DM and the PC's player can read them,
+//! and the player's NPC conversations are quietly shaped by them. When the
+//! DM reveals one it becomes a public story event, and the NPCs it names
+//! change how they feel about the PC.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    CharacterId, DomainError, InfoType, NpcDispositionState, PcSecret, PcSecretId, PlayerCharacter,
+    PlayerCharacterId, SecretReaction, StoryEvent, StoryEventInfoImportance, StoryEventType,
+    WorldId, MAX_SECRETS_PER_PC,
+};
+use wrldbldr_protocol::types::{PcSecretData, PcSecretInputData, SecretReactionData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+use crate::use_cases::npc::NpcDispositionUpdate;
+
+/// Container for PC secret use cases.
+pub struct SecretUseCases {
+    pub manage: Arc<ManageSecrets>,
+}
+
+impl SecretUseCases {
+    pub fn new(manage: Arc<ManageSecrets>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SecretError {
+    #[error("Secret not found")]
+    SecretNotFound,
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("NPC not found")]
+    NpcNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for SecretError {
+    fn from(e: DomainError) -> Self {
+        match e {
+            DomainError::InvalidStateTransition(msg) => SecretError::Conflict(msg),
+            other => SecretError::Invalid(other.to_string()),
+        }
+    }
+}
+
+/// A secret that just came out
+#[derive(Debug, Clone)]
+pub struct SecretRevealed {
+    pub pc_name: String,
+    pub secret: PcSecretData,
+    /// How the NPCs named in the secret's reactions now see the PC
+    pub dispositions: Vec<NpcDispositionUpdate>,
+}
+
+/// Create, change, delete and reveal PC secrets.
+pub struct ManageSecrets {
+    secret: Arc<entities::PcSecret>,
+    player_character: Arc<entities::PlayerCharacter>,
+    character: Arc<entities::Character>,
+    narrative: Arc<entities::Narrative>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageSecrets {
+    pub fn new(
+        secret: Arc<entities::PcSecret>,
+        player_character: Arc<entities::PlayerCharacter>,
+        character: Arc<entities::Character>,
+        narrative: Arc<entities::Narrative>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            secret,
+            player_character,
+            character,
+            narrative,
+            clock,
+        }
+    }
+
+    /// The PC, if it belongs to the world
+    pub async fn get_pc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<PlayerCharacter, SecretError> {
+        self.player_character
+            .get(pc_id)
+            .await?
+            .filter(|pc| pc.world_id == world_id)
+            .ok_or(SecretError::PlayerCharacterNotFound)
+    }
+
+    /// A PC's secrets, oldest first, revealed ones included
+    pub async fn list_for_pc(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<PcSecretData>, SecretError> {
+        self.get_pc(world_id, pc_id).await?;
+        let mut secrets = Vec::new();
+        for secret in self.secret.list_for_pc(pc_id).await? {
+            secrets.push(self.describe(&secret).await?);
+        }
+        Ok(secrets)
+    }
+
+    pub async fn create(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        input: PcSecretInputData,
+    ) -> Result<PcSecretData, SecretError> {
+        self.get_pc(world_id, pc_id).await?;
+        let kept = self
+            .secret
+            .list_for_pc(pc_id)
+            .await?
+            .iter()
+            .filter(|s| !s.is_revealed())
+            .count();
+        if kept >= MAX_SECRETS_PER_PC {
+            return Err(SecretError::Invalid(format!(
+                "A PC cannot keep more than {} secrets",
+                MAX_SECRETS_PER_PC
+            )));
+        }
+
+        let title = input
+            .title
+            .ok_or_else(|| SecretError::Invalid("Secret title is required".to_string()))?;
+        let reactions = match input.reactions {
+            Some(reactions) => self.reactions(world_id, reactions).await?,
+            None => Vec::new(),
+        };
+        let secret = PcSecret::new(
+            world_id,
+            pc_id,
+            title,
+            input.content.unwrap_or_default(),
+            self.clock.now(),
+        )?
+        .with_reactions(reactions)?;
+        self.secret.save(&secret).await?;
+        self.describe(&secret).await
+    }
+
+    /// Change whichever fields are given
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        secret_id: PcSecretId,
+        input: PcSecretInputData,
+    ) -> Result<PcSecretData, SecretError> {
+        let mut secret = self.world_secret(world_id, secret_id).await?;
+        let reactions = match input.reactions {
+            Some(reactions) => Some(self.reactions(world_id, reactions).await?),
+            None => None,
+        };
+        secret.update(input.title, input.content, reactions, self.clock.now())?;
+        self.secret.save(&secret).await?;
+        self.describe(&secret).await
+    }
+
+    /// Delete a secret. Returns the PC that kept it.
+    pub async fn delete(
+        &self,
+        world_id: WorldId,
+        secret_id: PcSecretId,
+    ) -> Result<PlayerCharacterId, SecretError> {
+        let secret = self.world_secret(world_id, secret_id).await?;
+        self.secret.delete(secret.id).await?;
+        Ok(secret.pc_id)
+    }
+
+    /// Make a secret public: record it as a story event and apply its NPC
+    /// reactions to their dispositions toward the PC.
+    pub async fn reveal(
+        &self,
+        world_id: WorldId,
+        secret_id: PcSecretId,
+    ) -> Result<SecretRevealed, SecretError> {
+        let mut secret = self.world_secret(world_id, secret_id).await?;
+        let pc = self.get_pc(world_id, secret.pc_id).await?;
+        let now = self.clock.now();
+        secret.reveal(now)?;
+        self.secret.save(&secret).await?;
+
+        let event = StoryEvent::new(
+            world_id,
+            StoryEventType::InformationRevealed {
+                info_type: InfoType::Secret,
+                title: secret.title.clone(),
+                content: secret.content.clone(),
+                source: None,
+                importance: StoryEventInfoImportance::Major,
+                persist_to_journal: true,
+            },
+            now,
+        )
+        .with_summary(format!("{}'s secret came out: {}", pc.name, secret.title))
+        .with_tag("secret");
+        self.narrative.save_story_event(&event).await?;
+
+        let reason = format!("Learned {}'s secret: {}", pc.name, secret.title);
+        let mut dispositions = Vec::new();
+        for reaction in &secret.reactions {
+            // An NPC deleted since the secret was written has nobody to react
+            let Some(npc) = self.character.get(reaction.npc_id).await? else {
+                continue;
+            };
+            let mut disposition = self
+                .character
+                .get_disposition(npc.id, pc.id)
+                .await?
+                .unwrap_or_else(|| NpcDispositionState::new(npc.id, pc.id, now));
+            let previous_sentiment = disposition.sentiment;
+            disposition.adjust_sentiment(reaction.sentiment_change, Some(reason.clone()), now);
+            self.character.save_disposition(&disposition).await?;
+
+            let event = StoryEvent::new(
+                world_id,
+                StoryEventType::RelationshipChanged {
+                    from_character: npc.id,
+                    to_character: CharacterId::from_uuid(*pc.id.as_uuid()),
+                    previous_sentiment: Some(previous_sentiment),
+                    new_sentiment: disposition.sentiment,
+                    sentiment_change: disposition.sentiment - previous_sentiment,
+                    reason: reason.clone(),
+                },
+                now,
+            )
+            .with_summary(format!("{} reacted to {}'s secret", npc.name, pc.name))
+            .with_tag("secret")
+            .hidden();
+            self.narrative.save_story_event(&event).await?;
+
+            dispositions.push(NpcDispositionUpdate {
+                npc_id: npc.id,
+                npc_name: npc.name,
+                pc_id: pc.id,
+                disposition: disposition.disposition,
+                relationship: disposition.relationship,
+                reason: Some(reason.clone()),
+            });
+        }
+
+        Ok(SecretRevealed {
+            pc_name: pc.name,
+            secret: self.describe(&secret).await?,
+            dispositions,
+        })
+    }
+
+    /// The secrets a PC still keeps, for shaping their conversations
+    pub async fn kept_secrets(
+        &self,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Vec<PcSecret>, SecretError> {
+        Ok(self
+            .secret
+            .list_for_pc(pc_id)
+            .await?
+            .into_iter()
+            .filter(|s| !s.is_revealed())
+            .collect())
+    }
+
+    async fn world_secret(
+        &self,
+        world_id: WorldId,
+        secret_id: PcSecretId,
+    ) -> Result<PcSecret, SecretError> {
+        self.secret
+            .get(secret_id)
+            .await?
+            .filter(|s| s.world_id == world_id)
+            .ok_or(SecretError::SecretNotFound)
+    }
+
+    /// Reactions from the wire, each naming an NPC of the world
+    async fn reactions(
+        &self,
+        world_id: WorldId,
+        reactions: Vec<SecretReactionData>,
+    ) -> Result<Vec<SecretReaction>, SecretError> {
+        let mut parsed = Vec::with_capacity(reactions.len());
+        for reaction in reactions {
+            let npc_id = Uuid::parse_str(&reaction.npc_id)
+                .map(CharacterId::from_uuid)
+                .map_err(|_| {
+                    SecretError::Invalid(format!("Invalid NPC ID: {}", reaction.npc_id))
+                })?;
+            self.character
+                .get(npc_id)
+                .await?
+                .filter(|npc| npc.world_id == world_id)
+                .ok_or(SecretError::NpcNotFound)?;
+            parsed.push(SecretReaction {
+                npc_id,
+                sentiment_change: reaction.sentiment_change,
+            });
+        }
+        Ok(parsed)
+    }
+
+    /// The secret for the wire, with its reacting NPCs' names
+    async fn describe(&self, secret: &PcSecret) -> Result<PcSecretData, SecretError> {
+        let mut reactions = Vec::with_capacity(secret.reactions.len());
+        for reaction in &secret.reactions {
+            reactions.push(SecretReactionData {
+                npc_id: reaction.npc_id.to_string(),
+                npc_name: self.character.get(reaction.npc_id).await?.map(|c| c.name),
+                sentiment_change: reaction.sentiment_change,
+            });
+        }
+        Ok(PcSecretData {
+            id: secret.id.to_string(),
+            pc_id: secret.pc_id.to_string(),
+            title: secret.title.clone(),
+            content: secret.content.clone(),
+            reactions,
+            revealed_at: secret.revealed_at.map(|t| t.to_rfc3339()),
+        })
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::connections::{ConnectionError, ConnectionInfo, ConnectionManager};
+use crate::entities::PlayerCharacter;
+use crate::infrastructure::ports::RepoError;
+use wrldbldr_domain::PlayerCharacterId;
+
+use super::JoinWorld;
+
+/// IO dependencies for multi-PC control (WS-state owned).
+pub struct ControlPcContext<'a> {
+    pub connections: &'a ConnectionManager,
+}
+
+/// The PCs a connection controls after a change.
+#[derive(Debug, Clone)]
+pub struct ControlledPcs {
+    pub active_pc_id: PlayerCharacterId,
+    /// Each controlled PC, in the same shape as the joined PC
+    pub pcs: Vec<Value>,
+}
+
+/// Use case for controlling several PCs from one player connection
+/// (solo play or duet games).
+pub struct ControlPc {
+    join_world: Arc<JoinWorld>,
+    player_character: Arc<PlayerCharacter>,
+}
+
+impl ControlPc {
+    pub fn new(join_world: Arc<JoinWorld>, player_character: Arc<PlayerCharacter>) -> Self {
+        Self {
+            join_world,
+            player_character,
+        }
+    }
+
+    /// Take control of another PC in the joined world.
+    ///
+    /// The PC must belong to the same user as the connection, or to the
+    /// owner of a PC the connection already controls.
+    pub async fn execute(
+        &self,
+        ctx: &ControlPcContext<'_>,
+        connection_id: Uuid,
+        pc_id: PlayerCharacterId,
+    ) -> Result<ControlledPcs, ControlPcError> {
+        let conn_info = ctx
+            .connections
+            .get(connection_id)
+            .await
+            .ok_or(ControlPcError::NotConnected)?;
+        let pc = self
+            .player_character
+            .get(pc_id)
+            .await?
+            .ok_or(ControlPcError::PcNotFound)?;
+        if conn_info.world_id != Some(pc.world_id) {
+            return Err(ControlPcError::PcNotFound);
+        }
+
+        let mut owners = vec![conn_info.user_id.clone()];
+        for id in &conn_info.controlled_pc_ids {
+            if let Some(controlled) = self.player_character.get(*id).await? {
+                owners.push(controlled.user_id);
+            }
+        }
+        if !owners.contains(&pc.user_id) {
+            return Err(ControlPcError::Unauthorized);
+        }
+
+        let info = ctx.connections.control_pc(connection_id, pc_id).await?;
+        Ok(self.controlled(&info, pc_id).await)
+    }
+
+    /// Make a controlled PC the one player actions are taken as.
+    pub async fn switch(
+        &self,
+        ctx: &ControlPcContext<'_>,
+        connection_id: Uuid,
+        pc_id: PlayerCharacterId,
+    ) -> Result<ControlledPcs, ControlPcError> {
+        let info = ctx.connections.switch_pc(connection_id, pc_id).await?;
+        Ok(self.controlled(&info, pc_id).await)
+    }
+
+    async fn controlled(&self, info: &ConnectionInfo, pc_id: PlayerCharacterId) -> ControlledPcs {
+        let mut pcs = Vec::with_capacity(info.controlled_pc_ids.len());
+        for id in &info.controlled_pc_ids {
+            if let Some(pc) = self.join_world.load_pc(Some(*id)).await {
+                pcs.push(pc);
+            }
+        }
+        ControlledPcs {
+            active_pc_id: info.pc_id.unwrap_or(pc_id),
+            pcs,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ControlPcError {
+    #[error("Not connected")]
+    NotConnected,
+    #[error("Player character not found")]
+    PcNotFound,
+    #[error("Not authorized to control this player character")]
+    Unauthorized,
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<ConnectionError> for ControlPcError {
+    fn from(err: ConnectionError) -> Self {
+        match err {
+            ConnectionError::NotFound => ControlPcError::NotConnected,
+            _ => ControlPcError::Unauthorized,
+        }
+    }
+}
@@ -86,6 +86,10 @@ impl JoinWorld {
                 "name": world.name,
                 "description": world.description,
                 "rule_system": world.rule_system,
+                "typography": world.typography,
+                "theme": world.theme,
+                "features": world.features,
+                "map_asset": world.map_asset,
                 "created_at": world.created_at.to_rfc3339(),
                 "updated_at": world.updated_at.to_rfc3339(),
             },
@@ -96,7 +100,8 @@ impl JoinWorld {
                     "description": loc.description,
                     "location_type": format!("{:?}", loc.location_type),
                     "backdrop_asset": loc.backdrop_asset,
-                    "parent_id": null,
+                    "parent_id": loc.parent_location_id.map(|id| id.to_string()),
+                    "world_map_position": loc.world_map_position,
                 })
             }).collect::<Vec<_>>(),
             "characters": characters.into_iter().map(|c| {
@@ -138,7 +143,7 @@ impl JoinWorld {
         self.execute(world_id, pc_id, include_pc).await
     }
 
-    async fn load_pc(&self, pc_id: Option<PlayerCharacterId>) -> Option<Value> {
+    pub(crate) async fn load_pc(&self, pc_id: Option<PlayerCharacterId>) -> Option<Value> {
         let pc_id = pc_id?;
         match self.player_character.get(pc_id).await {
             Ok(Some(pc)) => Some(serde_json::json!({
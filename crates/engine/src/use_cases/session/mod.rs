@@ -4,10 +4,12 @@
 
 use std::sync::Arc;
 
+mod control_pc;
 mod directorial;
 mod join_world;
 mod join_world_flow;
 
+pub use control_pc::{ControlPc, ControlPcContext, ControlPcError, ControlledPcs};
 pub use join_world::{JoinWorld, JoinWorldError, JoinWorldResult};
 pub use join_world_flow::{
     JoinWorldContext, JoinWorldFlow, JoinWorldFlowError, JoinWorldFlowResult, JoinWorldInput,
@@ -20,6 +22,7 @@ pub struct SessionUseCases {
     pub join_world: Arc<JoinWorld>,
     pub join_world_flow: Arc<JoinWorldFlow>,
     pub directorial_update: Arc<DirectorialUpdate>,
+    pub control_pc: Arc<ControlPc>,
 }
 
 impl SessionUseCases {
@@ -27,11 +30,13 @@ impl SessionUseCases {
         join_world: Arc<JoinWorld>,
         join_world_flow: Arc<JoinWorldFlow>,
         directorial_update: Arc<DirectorialUpdate>,
+        control_pc: Arc<ControlPc>,
     ) -> Self {
         Self {
             join_world,
             join_world_flow,
             directorial_update,
+            control_pc,
         }
     }
 }
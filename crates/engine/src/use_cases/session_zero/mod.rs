@@ -0,0 +1,372 @@
+//! Session zero use cases.
+//!
+//! Turns the choices a group makes at session zero into a playable world in
+//! one pass: the world with its rule system, tone, safety settings and
+//! calendar, the rule system's preset skills, a starting location with the
+//! region PCs arrive in, and an NPC draft for each seed. The drafts are left
+//! generating; the caller hands them to the LLM, and the DM reviews them
+//! like any other promoted NPC.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use wrldbldr_domain::{
+    default_skills_for_variant, DomainError, GameTime, Location, LocationType, NpcDraft,
+    NpcDraftSource, Region, RuleSystemConfig, RuleSystemVariant, TimeMode,
+};
+use wrldbldr_protocol::types::{SessionZeroData, TimeMode as TimeModeData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+use crate::use_cases::management::content_safety_from_protocol;
+
+/// Most NPC seeds one session zero can plant
+pub const MAX_SESSION_ZERO_SEEDS: usize = 6;
+
+/// Hour the campaign opens at when the group picks a date but no hour
+const DEFAULT_START_HOUR: u32 = 8;
+
+/// Container for session zero use cases.
+pub struct SessionZeroUseCases {
+    pub create_world: Arc<CreateSessionZeroWorld>,
+}
+
+impl SessionZeroUseCases {
+    pub fn new(create_world: Arc<CreateSessionZeroWorld>) -> Self {
+        Self { create_world }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SessionZeroError {
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for SessionZeroError {
+    fn from(e: DomainError) -> Self {
+        SessionZeroError::Invalid(e.to_string())
+    }
+}
+
+/// Everything a session zero created
+#[derive(Debug, Clone)]
+pub struct SessionZeroWorld {
+    pub world: wrldbldr_domain::World,
+    pub location: Location,
+    /// Where PCs arrive
+    pub region: Region,
+    /// One per seed, still generating
+    pub drafts: Vec<NpcDraft>,
+}
+
+/// Create a world from a session zero's choices.
+pub struct CreateSessionZeroWorld {
+    world: Arc<entities::World>,
+    location: Arc<entities::Location>,
+    skill: Arc<entities::Skill>,
+    npc_draft: Arc<entities::NpcDraft>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl CreateSessionZeroWorld {
+    pub fn new(
+        world: Arc<entities::World>,
+        location: Arc<entities::Location>,
+        skill: Arc<entities::Skill>,
+        npc_draft: Arc<entities::NpcDraft>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            world,
+            location,
+            skill,
+            npc_draft,
+            clock,
+        }
+    }
+
+    /// Check every choice, then save the world and everything in it.
+    ///
+    /// Nothing is saved if any choice is invalid.
+    pub async fn execute(
+        &self,
+        data: SessionZeroData,
+    ) -> Result<SessionZeroWorld, SessionZeroError> {
+        let now = self.clock.now();
+        let content = build_world(data, now)?;
+
+        self.world.save(&content.world).await?;
+        for skill in
+            default_skills_for_variant(content.world.id, &content.world.rule_system.variant)
+        {
+            self.skill.save(&skill).await?;
+        }
+        self.location.save_location(&content.location).await?;
+        self.location.save_region(&content.region).await?;
+        for draft in &content.drafts {
+            self.npc_draft.save(draft).await?;
+        }
+
+        tracing::info!(
+            world_id = %content.world.id,
+            seeds = content.drafts.len(),
+            "Created world from session zero"
+        );
+        Ok(content)
+    }
+}
+
+/// The world a session zero describes, checked but not yet saved
+fn build_world(
+    data: SessionZeroData,
+    now: DateTime<Utc>,
+) -> Result<SessionZeroWorld, SessionZeroError> {
+    let name = required(&data.name, "World name")?;
+    if matches!(data.rule_system, RuleSystemVariant::Unknown) {
+        return Err(SessionZeroError::Invalid("Pick a rule system".to_string()));
+    }
+    if data.npc_seeds.len() > MAX_SESSION_ZERO_SEEDS {
+        return Err(SessionZeroError::Invalid(format!(
+            "A session zero can seed at most {} NPCs",
+            MAX_SESSION_ZERO_SEEDS
+        )));
+    }
+
+    let mut description = optional(data.description).unwrap_or_default();
+    if let Some(tone) = optional(data.tone) {
+        if !description.is_empty() {
+            description.push_str("\n\n");
+        }
+        description.push_str(&format!("Tone: {}", tone));
+    }
+
+    let mut world = wrldbldr_domain::World::new(name, description, now)
+        .with_rule_system(RuleSystemConfig::from_variant(data.rule_system))
+        .with_content_safety(content_safety_from_protocol(data.content_safety));
+    world.game_time = GameTime::starting_at(start_time(
+        data.start_date.as_deref(),
+        data.start_hour,
+        now,
+    )?);
+    world.time_config.mode = match data.time_mode {
+        TimeModeData::Manual => TimeMode::Manual,
+        TimeModeData::Suggested => TimeMode::Suggested,
+        TimeModeData::Auto => TimeMode::Auto,
+    };
+
+    let start = data.starting_location;
+    let mut location = Location::new(
+        world.id,
+        required(&start.name, "Starting location name")?,
+        LocationType::Exterior,
+    );
+    if let Some(description) = optional(start.description) {
+        location = location.with_description(description);
+    }
+    let mut region = Region::new(
+        location.id,
+        required(&start.region_name, "Starting region name")?,
+    )
+    .as_spawn_point()
+    .with_order(0);
+    if let Some(description) = optional(start.region_description) {
+        region = region.with_description(description);
+    }
+    let location = location.with_default_region(region.id);
+
+    let drafts = data
+        .npc_seeds
+        .into_iter()
+        .map(|seed| {
+            NpcDraft::new(
+                world.id,
+                NpcDraftSource::SessionZero {
+                    region_id: region.id,
+                    concept: seed.concept,
+                },
+                seed.name.as_deref(),
+                now,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(SessionZeroWorld {
+        world,
+        location,
+        region,
+        drafts,
+    })
+}
+
+/// When the campaign opens: the chosen date and hour, or now
+fn start_time(
+    date: Option<&str>,
+    hour: Option<u32>,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, SessionZeroError> {
+    let date = date.map(str::trim).filter(|d| !d.is_empty());
+    if date.is_none() && hour.is_none() {
+        return Ok(now);
+    }
+    let day = match date {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+            SessionZeroError::Invalid(format!("Start date must be YYYY-MM-DD, got {}", date))
+        })?,
+        None => now.date_naive(),
+    };
+    let hour = hour.unwrap_or(DEFAULT_START_HOUR);
+    day.and_hms_opt(hour, 0, 0)
+        .map(|t| t.and_utc())
+        .ok_or_else(|| SessionZeroError::Invalid("Start hour must be 0-23".to_string()))
+}
+
+fn required(value: &str, what: &str) -> Result<String, SessionZeroError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(SessionZeroError::Invalid(format!(
+            "{} cannot be empty",
+            what
+        )));
+    }
+    Ok(value.to_string())
+}
+
+fn optional(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        MockLocationRepo, MockNpcDraftRepo, MockSkillRepo, MockWorldRepo,
+    };
+    use chrono::{Datelike, TimeZone, Timelike};
+    use wrldbldr_protocol::types::{ContentSafetyData, NpcSeedData, StartingLocationData};
+
+    fn session_zero() -> SessionZeroData {
+        SessionZeroData {
+            name: "Saltmere".to_string(),
+            description: Some("Smugglers and storms on a cold coast.".to_string()),
+            tone: Some("grim and grounded".to_string()),
+            rule_system: RuleSystemVariant::BladesInTheDark,
+            content_safety: ContentSafetyData {
+                lines: vec!["harm to children".to_string()],
+                ..Default::default()
+            },
+            start_date: Some("1201-03-14".to_string()),
+            start_hour: None,
+            time_mode: TimeModeData::Manual,
+            starting_location: StartingLocationData {
+                name: "Saltmere Harbor".to_string(),
+                description: None,
+                region_name: "Harbor Gate".to_string(),
+                region_description: None,
+            },
+            npc_seeds: vec![
+                NpcSeedData {
+                    name: None,
+                    concept: "a smuggler who owes the harbormaster".to_string(),
+                },
+                NpcSeedData {
+                    name: Some("Wren".to_string()),
+                    concept: "the harbormaster".to_string(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn the_choices_shape_the_world_and_its_starting_place() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 19, 20, 0, 0).unwrap();
+
+        let content = build_world(session_zero(), now).unwrap();
+
+        let world = &content.world;
+        assert_eq!(
+            world.description,
+            "Smugglers and storms on a cold coast.\n\nTone: grim and grounded"
+        );
+        assert_eq!(
+            world.rule_system.variant,
+            RuleSystemVariant::BladesInTheDark
+        );
+        assert_eq!(world.content_safety.lines, vec!["harm to children"]);
+        assert_eq!(world.time_config.mode, TimeMode::Manual);
+        let start = world.game_time.current();
+        assert_eq!((start.year(), start.month(), start.day()), (1201, 3, 14));
+        assert_eq!(start.hour(), DEFAULT_START_HOUR);
+
+        assert_eq!(content.location.world_id, world.id);
+        assert_eq!(content.region.location_id, content.location.id);
+        assert!(content.region.is_spawn_point);
+        assert_eq!(content.location.default_region_id, Some(content.region.id));
+
+        assert_eq!(content.drafts.len(), 2);
+        assert!(content.drafts.iter().all(|d| d.is_generating()));
+        assert!(content.drafts[0].name.is_empty());
+        assert_eq!(content.drafts[1].name, "Wren");
+        assert!(matches!(
+            content.drafts[0].source,
+            NpcDraftSource::SessionZero { region_id, .. } if region_id == content.region.id
+        ));
+    }
+
+    #[test]
+    fn bad_choices_are_caught_before_anything_is_made() {
+        let now = Utc::now();
+        let mut no_region = session_zero();
+        no_region.starting_location.region_name = " ".to_string();
+        let mut bad_date = session_zero();
+        bad_date.start_date = Some("the first thaw".to_string());
+        let mut blank_seed = session_zero();
+        blank_seed.npc_seeds[0].concept = String::new();
+        let mut crowded = session_zero();
+        crowded.npc_seeds = vec![crowded.npc_seeds[0].clone(); MAX_SESSION_ZERO_SEEDS + 1];
+
+        for data in [no_region, bad_date, blank_seed, crowded] {
+            assert!(matches!(
+                build_world(data, now),
+                Err(SessionZeroError::Invalid(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn everything_is_saved_in_one_pass() {
+        let mut worlds = MockWorldRepo::new();
+        worlds.expect_save().times(1).returning(|_| Ok(()));
+        let mut skills = MockSkillRepo::new();
+        skills.expect_save().returning(|_| Ok(()));
+        let mut locations = MockLocationRepo::new();
+        locations
+            .expect_save_location()
+            .times(1)
+            .returning(|_| Ok(()));
+        locations
+            .expect_save_region()
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut drafts = MockNpcDraftRepo::new();
+        drafts.expect_save().times(2).returning(|_| Ok(()));
+
+        let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(Utc::now()));
+        let create = CreateSessionZeroWorld::new(
+            Arc::new(entities::World::new(Arc::new(worlds), clock.clone())),
+            Arc::new(entities::Location::new(Arc::new(locations))),
+            Arc::new(entities::Skill::new(Arc::new(skills))),
+            Arc::new(entities::NpcDraft::new(Arc::new(drafts))),
+            clock,
+        );
+
+        let content = create.execute(session_zero()).await.unwrap();
+
+        assert_eq!(content.world.name, "Saltmere");
+    }
+}
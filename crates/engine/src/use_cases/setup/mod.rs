@@ -0,0 +1,223 @@
+//! First-run setup use cases.
+//!
+//! Lets an operator point the engine at Neo4j, Ollama, and ComfyUI from the
+//! Player UI instead of editing .env. Candidate connections are checked live
+//! and saved to global `AppSettings`; the engine reads them at startup, so a
+//! restart applies them.
+
+use std::sync::Arc;
+
+use serde::Serialize;
+use wrldbldr_domain::ServiceConnections;
+
+use crate::entities;
+use crate::entities::settings::SettingsError;
+use crate::infrastructure::ports::{ProbeError, ServiceProbePort};
+
+/// Container for setup use cases.
+pub struct SetupUseCases {
+    pub status: Arc<SetupStatus>,
+    pub check: Arc<CheckConnections>,
+    pub save: Arc<SaveConnections>,
+}
+
+impl SetupUseCases {
+    pub fn new(
+        status: Arc<SetupStatus>,
+        check: Arc<CheckConnections>,
+        save: Arc<SaveConnections>,
+    ) -> Self {
+        Self {
+            status,
+            check,
+            save,
+        }
+    }
+}
+
+/// Result of one live service check
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ServiceCheck {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<Result<(), ProbeError>> for ServiceCheck {
+    fn from(result: Result<(), ProbeError>) -> Self {
+        match result {
+            Ok(()) => Self {
+                ok: true,
+                error: None,
+            },
+            Err(e) => Self {
+                ok: false,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+/// Live check results for every service
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ConnectionReport {
+    pub neo4j: ServiceCheck,
+    pub ollama: ServiceCheck,
+    pub comfyui: ServiceCheck,
+}
+
+impl ConnectionReport {
+    pub fn all_ok(&self) -> bool {
+        self.neo4j.ok && self.ollama.ok && self.comfyui.ok
+    }
+}
+
+/// Current setup state, shown when the wizard opens
+#[derive(Debug, Clone, Serialize)]
+pub struct SetupState {
+    /// True until connections have been saved through setup
+    pub needs_setup: bool,
+    /// Connections the wizard starts from (password redacted)
+    pub connections: ServiceConnections,
+    pub checks: ConnectionReport,
+}
+
+/// Outcome of a save attempt
+#[derive(Debug, Clone, Serialize)]
+pub struct SaveOutcome {
+    /// False if any check failed; nothing is saved then
+    pub saved: bool,
+    /// Connections as checked (password redacted)
+    pub connections: ServiceConnections,
+    pub checks: ConnectionReport,
+}
+
+/// Report setup state with live checks of the current connections.
+pub struct SetupStatus {
+    settings: Arc<entities::Settings>,
+    probe: Arc<dyn ServiceProbePort>,
+    env: ServiceConnections,
+}
+
+impl SetupStatus {
+    pub fn new(
+        settings: Arc<entities::Settings>,
+        probe: Arc<dyn ServiceProbePort>,
+        env: ServiceConnections,
+    ) -> Self {
+        Self {
+            settings,
+            probe,
+            env,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<SetupState, SettingsError> {
+        let saved = self.settings.get_global().await?.services;
+        let needs_setup = saved.is_empty();
+        let connections = saved.or(&self.env).with_defaults();
+        let checks = run_checks(self.probe.as_ref(), &connections).await;
+        Ok(SetupState {
+            needs_setup,
+            connections: connections.redacted(),
+            checks,
+        })
+    }
+}
+
+/// Check candidate connections without saving them.
+pub struct CheckConnections {
+    settings: Arc<entities::Settings>,
+    probe: Arc<dyn ServiceProbePort>,
+    env: ServiceConnections,
+}
+
+impl CheckConnections {
+    pub fn new(
+        settings: Arc<entities::Settings>,
+        probe: Arc<dyn ServiceProbePort>,
+        env: ServiceConnections,
+    ) -> Self {
+        Self {
+            settings,
+            probe,
+            env,
+        }
+    }
+
+    /// Blank fields (and a redacted password) fall back to the saved value.
+    pub async fn execute(
+        &self,
+        candidate: ServiceConnections,
+    ) -> Result<ConnectionReport, SettingsError> {
+        let connections = resolve(&self.settings, &self.env, candidate).await?;
+        Ok(run_checks(self.probe.as_ref(), &connections).await)
+    }
+}
+
+/// Check candidate connections and save them if every check passes.
+pub struct SaveConnections {
+    settings: Arc<entities::Settings>,
+    probe: Arc<dyn ServiceProbePort>,
+    env: ServiceConnections,
+}
+
+impl SaveConnections {
+    pub fn new(
+        settings: Arc<entities::Settings>,
+        probe: Arc<dyn ServiceProbePort>,
+        env: ServiceConnections,
+    ) -> Self {
+        Self {
+            settings,
+            probe,
+            env,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        candidate: ServiceConnections,
+    ) -> Result<SaveOutcome, SettingsError> {
+        let connections = resolve(&self.settings, &self.env, candidate).await?;
+        let checks = run_checks(self.probe.as_ref(), &connections).await;
+
+        let saved = checks.all_ok();
+        if saved {
+            self.settings.update_services(connections.clone()).await?;
+            tracing::info!("Saved service connections from setup; restart to apply");
+        }
+
+        Ok(SaveOutcome {
+            saved,
+            connections: connections.redacted(),
+            checks,
+        })
+    }
+}
+
+async fn resolve(
+    settings: &entities::Settings,
+    env: &ServiceConnections,
+    candidate: ServiceConnections,
+) -> Result<ServiceConnections, SettingsError> {
+    let saved = settings.get_global().await?.services;
+    Ok(candidate
+        .without_redacted_secret()
+        .or(&saved)
+        .or(env)
+        .with_defaults())
+}
+
+async fn run_checks(probe: &dyn ServiceProbePort, c: &ServiceConnections) -> ConnectionReport {
+    let (neo4j, ollama, comfyui) = tokio::join!(
+        probe.check_neo4j(c.neo4j_uri(), c.neo4j_user(), c.neo4j_password()),
+        probe.check_ollama(c.ollama_url(), c.ollama_model()),
+        probe.check_comfyui(c.comfyui_url()),
+    );
+    ConnectionReport {
+        neo4j: neo4j.into(),
+        ollama: ollama.into(),
+        comfyui: comfyui.into(),
+    }
+}
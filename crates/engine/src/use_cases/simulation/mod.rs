@@ -0,0 +1,987 @@
+//! World simulation use cases.
+//!
+//! When game time jumps a day or more, the world carries on without the
+//! players: faction and threat clocks tick, NPCs follow their schedules,
+//! rumors pass between NPCs who share a haunt and unflagged market prices
+//! drift. The dice decide what happened, and the result waits for the DM to
+//! trim, adjust and accept (or discard) before anything is changed.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::Duration;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use wrldbldr_domain::{
+    simulated_days, CharacterId, ClockKind, DmMarkerType, DomainError, LoreCategory,
+    LoreDiscoverySource, LoreId, LoreKnowledge, MarkerImportance, MarketModifierId, ProgressClock,
+    ProgressClockId, RegionFrequency, RegionId, RegionRelationshipType, RegionShift,
+    SimulationChange, StoryEvent, StoryEventType, TimeOfDay, WorldId, WorldSimulation,
+    WorldSimulationId, MAX_PRICE_ADJUSTMENT_PERCENT, MAX_SIMULATION_CHANGES,
+};
+use wrldbldr_protocol::types::{SimulationChangeData, WorldSimulationData};
+
+use crate::entities;
+use crate::infrastructure::ports::{
+    ClockPort, NpcRegionRelationType, NpcRegionRelationship, RandomPort, RepoError,
+};
+
+/// Sides on the die rolled for each day or week of the skip
+const SIMULATION_DIE: i32 = 6;
+
+/// Percentage points a market price drifts by in a week
+const PRICE_DRIFT_STEP: i32 = 5;
+
+/// Container for world simulation use cases.
+pub struct SimulationUseCases {
+    pub simulate: Arc<SimulateWorld>,
+}
+
+impl SimulationUseCases {
+    pub fn new(simulate: Arc<SimulateWorld>) -> Self {
+        Self { simulate }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SimulationError {
+    #[error("Simulation not found")]
+    NotFound,
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for SimulationError {
+    fn from(e: DomainError) -> Self {
+        SimulationError::Invalid(e.to_string())
+    }
+}
+
+/// An accepted simulation and the clocks it moved.
+#[derive(Debug)]
+pub struct AcceptedSimulation {
+    pub simulation: WorldSimulation,
+    pub clocks: Vec<ProgressClock>,
+}
+
+/// Simulate time skips and apply the DM's accepted results.
+///
+/// Simulations awaiting the DM are held in memory; a restarted engine drops
+/// them and the world stays as it was.
+pub struct SimulateWorld {
+    world: Arc<entities::World>,
+    character: Arc<entities::Character>,
+    location: Arc<entities::Location>,
+    lore: Arc<entities::Lore>,
+    economy: Arc<entities::Economy>,
+    progress_clock: Arc<entities::ProgressClock>,
+    narrative: Arc<entities::Narrative>,
+    random: Arc<dyn RandomPort>,
+    clock: Arc<dyn ClockPort>,
+    pending: RwLock<HashMap<WorldSimulationId, WorldSimulation>>,
+}
+
+impl SimulateWorld {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        world: Arc<entities::World>,
+        character: Arc<entities::Character>,
+        location: Arc<entities::Location>,
+        lore: Arc<entities::Lore>,
+        economy: Arc<entities::Economy>,
+        progress_clock: Arc<entities::ProgressClock>,
+        narrative: Arc<entities::Narrative>,
+        random: Arc<dyn RandomPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            world,
+            character,
+            location,
+            lore,
+            economy,
+            progress_clock,
+            narrative,
+            random,
+            clock,
+            pending: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Work out what happened while game time advanced by `minutes`.
+    ///
+    /// Call it after the world's clock has moved on. Returns `None` when the
+    /// skip is shorter than a day or nothing came of it.
+    pub async fn simulate(
+        &self,
+        world_id: WorldId,
+        minutes: u32,
+    ) -> Result<Option<WorldSimulation>, SimulationError> {
+        let Some(days) = simulated_days(minutes) else {
+            return Ok(None);
+        };
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(SimulationError::WorldNotFound)?;
+        let to_game_time = world.game_time.current();
+        let from_game_time = to_game_time - Duration::minutes(i64::from(minutes));
+
+        let haunts = self.npc_haunts(world_id).await?;
+        let mut changes = self.advance_clocks(world_id, days).await?;
+        changes.extend(
+            self.move_npcs(&haunts, world.game_time.time_of_day())
+                .await?,
+        );
+        changes.extend(self.spread_rumors(world_id, &haunts, days).await?);
+        changes.extend(self.drift_prices(world_id, days).await?);
+        if changes.is_empty() {
+            return Ok(None);
+        }
+
+        let simulation = WorldSimulation::new(
+            world_id,
+            from_game_time,
+            to_game_time,
+            changes,
+            self.clock.now(),
+        );
+        self.pending
+            .write()
+            .await
+            .insert(simulation.id, simulation.clone());
+        Ok(Some(simulation))
+    }
+
+    /// Simulations awaiting the DM, oldest first
+    pub async fn list(&self, world_id: WorldId) -> Vec<WorldSimulation> {
+        let mut simulations: Vec<_> = self
+            .pending
+            .read()
+            .await
+            .values()
+            .filter(|s| s.world_id == world_id)
+            .cloned()
+            .collect();
+        simulations.sort_by_key(|s| s.created_at);
+        simulations
+    }
+
+    /// Replace a simulation's changes with the DM's edits
+    pub async fn update(
+        &self,
+        world_id: WorldId,
+        simulation_id: WorldSimulationId,
+        changes: Vec<SimulationChangeData>,
+    ) -> Result<WorldSimulation, SimulationError> {
+        let changes = changes
+            .into_iter()
+            .map(change_from_data)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut pending = self.pending.write().await;
+        let simulation = pending
+            .get_mut(&simulation_id)
+            .filter(|s| s.world_id == world_id)
+            .ok_or(SimulationError::NotFound)?;
+        simulation.edit(changes)?;
+        Ok(simulation.clone())
+    }
+
+    /// Apply a simulation to the world and note it on the timeline.
+    ///
+    /// Changes whose subject has gone since the simulation ran are skipped.
+    pub async fn accept(
+        &self,
+        world_id: WorldId,
+        simulation_id: WorldSimulationId,
+    ) -> Result<AcceptedSimulation, SimulationError> {
+        let simulation = self.take(world_id, simulation_id).await?;
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(SimulationError::WorldNotFound)?;
+
+        let mut clocks = Vec::new();
+        for change in &simulation.changes {
+            if let Some(clock) = self.apply(change, simulation.to_game_time).await? {
+                clocks.push(clock);
+            }
+        }
+
+        if !simulation.changes.is_empty() {
+            let event = StoryEvent::new(
+                world_id,
+                StoryEventType::DmMarker {
+                    title: "While time passed".to_string(),
+                    note: simulation.changelog(),
+                    importance: MarkerImportance::Notable,
+                    marker_type: DmMarkerType::WorldEvent,
+                    pin: None,
+                },
+                self.clock.now(),
+            )
+            .with_game_time(world.game_time.display_date())
+            .with_summary(format!("{} day(s) passed off-screen", simulation.days()));
+            self.narrative.save_story_event(&event).await?;
+        }
+
+        Ok(AcceptedSimulation { simulation, clocks })
+    }
+
+    /// Drop a simulation without changing anything
+    pub async fn discard(
+        &self,
+        world_id: WorldId,
+        simulation_id: WorldSimulationId,
+    ) -> Result<(), SimulationError> {
+        self.take(world_id, simulation_id).await.map(|_| ())
+    }
+
+    async fn take(
+        &self,
+        world_id: WorldId,
+        simulation_id: WorldSimulationId,
+    ) -> Result<WorldSimulation, SimulationError> {
+        let mut pending = self.pending.write().await;
+        if !pending
+            .get(&simulation_id)
+            .is_some_and(|s| s.world_id == world_id)
+        {
+            return Err(SimulationError::NotFound);
+        }
+        pending
+            .remove(&simulation_id)
+            .ok_or(SimulationError::NotFound)
+    }
+
+    /// Apply one change, returning the clock if it was a clock that moved
+    async fn apply(
+        &self,
+        change: &SimulationChange,
+        game_time: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<ProgressClock>, SimulationError> {
+        match change {
+            SimulationChange::ClockAdvanced {
+                clock_id, segments, ..
+            } => match self
+                .progress_clock
+                .tick(*clock_id, i32::from(*segments))
+                .await
+            {
+                Ok((clock, _)) => return Ok(Some(clock)),
+                Err(RepoError::NotFound) => {}
+                Err(e) => return Err(e.into()),
+            },
+            SimulationChange::NpcMoved {
+                npc_id, region_id, ..
+            } => {
+                if self.character.get(*npc_id).await?.is_some() {
+                    self.character.update_position(*npc_id, *region_id).await?;
+                }
+            }
+            SimulationChange::RumorSpread {
+                lore_id,
+                npc_id,
+                told_by_id,
+                told_by_name,
+                ..
+            } => {
+                let known = self.lore.get_knowledge_for_lore(*lore_id).await?;
+                let teller = known.iter().find(|k| k.character_id == *told_by_id);
+                let already_known = known.iter().any(|k| k.character_id == *npc_id);
+                if let (Some(teller), false) = (teller, already_known) {
+                    let source = LoreDiscoverySource::Conversation {
+                        npc_id: *told_by_id,
+                        npc_name: told_by_name.clone(),
+                    };
+                    let knowledge = LoreKnowledge::partial(
+                        *lore_id,
+                        *npc_id,
+                        teller.known_chunk_ids.clone(),
+                        source,
+                        game_time,
+                    );
+                    self.lore.grant_knowledge(&knowledge).await?;
+                }
+            }
+            SimulationChange::PriceDrift {
+                modifier_id,
+                to_percent,
+                ..
+            } => {
+                if let Some(mut modifier) = self.economy.get_modifier(*modifier_id).await? {
+                    let reason = modifier.reason.clone();
+                    modifier.update(
+                        modifier.location_id,
+                        modifier.category.clone(),
+                        modifier.scarcity,
+                        *to_percent,
+                        modifier.flag.clone(),
+                        &reason,
+                        self.clock.now(),
+                    )?;
+                    self.economy.save_modifier(&modifier).await?;
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Living NPCs and the regions tied to them
+    async fn npc_haunts(&self, world_id: WorldId) -> Result<Vec<NpcHaunts>, SimulationError> {
+        let mut haunts = Vec::new();
+        for npc in self.character.list_npcs_in_world(world_id).await? {
+            if !npc.is_alive || !npc.is_active {
+                continue;
+            }
+            let regions = self.character.get_region_relationships(npc.id).await?;
+            haunts.push(NpcHaunts {
+                id: npc.id,
+                name: npc.name,
+                regions,
+            });
+        }
+        Ok(haunts)
+    }
+
+    /// Faction and threat clocks fill a segment on each day a six is rolled
+    async fn advance_clocks(
+        &self,
+        world_id: WorldId,
+        days: u32,
+    ) -> Result<Vec<SimulationChange>, SimulationError> {
+        let mut changes = Vec::new();
+        for clock in self.progress_clock.list_in_world(world_id).await? {
+            if clock.is_complete()
+                || clock.is_personal()
+                || !matches!(clock.kind, ClockKind::Faction | ClockKind::Threat)
+            {
+                continue;
+            }
+            let remaining = clock.segments - clock.filled;
+            let mut segments = 0;
+            for _ in 0..days {
+                if segments == remaining {
+                    break;
+                }
+                if self.roll() == SIMULATION_DIE {
+                    segments += 1;
+                }
+            }
+            if segments > 0 {
+                changes.push(SimulationChange::ClockAdvanced {
+                    clock_id: clock.id,
+                    clock_name: clock.name,
+                    segments,
+                });
+            }
+        }
+        Ok(changes)
+    }
+
+    /// NPCs end up where their schedule puts them at the new time of day
+    async fn move_npcs(
+        &self,
+        haunts: &[NpcHaunts],
+        time_of_day: TimeOfDay,
+    ) -> Result<Vec<SimulationChange>, SimulationError> {
+        let mut staged: HashMap<RegionId, HashSet<CharacterId>> = HashMap::new();
+        let mut region_names: HashMap<RegionId, String> = HashMap::new();
+        let mut changes = Vec::new();
+        for npc in haunts {
+            let Some(region_id) = scheduled_region(&npc.regions, time_of_day) else {
+                continue;
+            };
+            if let Entry::Vacant(entry) = staged.entry(region_id) {
+                let present = self.character.list_in_region(region_id).await?;
+                entry.insert(present.into_iter().map(|c| c.id).collect());
+            }
+            if staged[&region_id].contains(&npc.id) {
+                continue;
+            }
+            if let Entry::Vacant(entry) = region_names.entry(region_id) {
+                let Some(region) = self.location.get_region(region_id).await? else {
+                    continue;
+                };
+                entry.insert(region.name);
+            }
+            changes.push(SimulationChange::NpcMoved {
+                npc_id: npc.id,
+                npc_name: npc.name.clone(),
+                region_id,
+                region_name: region_names[&region_id].clone(),
+            });
+        }
+        Ok(changes)
+    }
+
+    /// Each NPC who knows a rumor has a daily chance of telling someone who
+    /// shares one of their haunts. Common knowledge and secrets stay put.
+    async fn spread_rumors(
+        &self,
+        world_id: WorldId,
+        haunts: &[NpcHaunts],
+        days: u32,
+    ) -> Result<Vec<SimulationChange>, SimulationError> {
+        let mut changes = Vec::new();
+        for lore in self.lore.list_for_world(world_id).await? {
+            if lore.is_common_knowledge || lore.category == LoreCategory::Secret {
+                continue;
+            }
+            let known = self.lore.get_knowledge_for_lore(lore.id).await?;
+            let mut knows: HashSet<CharacterId> = known.iter().map(|k| k.character_id).collect();
+            let tellers: Vec<&NpcHaunts> =
+                haunts.iter().filter(|n| knows.contains(&n.id)).collect();
+            for teller in tellers {
+                for _ in 0..days {
+                    if self.roll() != SIMULATION_DIE {
+                        continue;
+                    }
+                    let listeners: Vec<&NpcHaunts> = haunts
+                        .iter()
+                        .filter(|n| !knows.contains(&n.id) && teller.shares_haunt_with(n))
+                        .collect();
+                    if listeners.is_empty() {
+                        break;
+                    }
+                    let pick = self.random.gen_range(0, listeners.len() as i32 - 1);
+                    let listener = listeners[pick.clamp(0, listeners.len() as i32 - 1) as usize];
+                    knows.insert(listener.id);
+                    changes.push(SimulationChange::RumorSpread {
+                        lore_id: lore.id,
+                        lore_title: lore.title.clone(),
+                        npc_id: listener.id,
+                        npc_name: listener.name.clone(),
+                        told_by_id: teller.id,
+                        told_by_name: teller.name.clone(),
+                    });
+                }
+            }
+            if changes.len() >= MAX_SIMULATION_CHANGES {
+                break;
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Prices without a flag to hold them in place wander a little each week:
+    /// down on a one or two, up on a five or six
+    async fn drift_prices(
+        &self,
+        world_id: WorldId,
+        days: u32,
+    ) -> Result<Vec<SimulationChange>, SimulationError> {
+        let weeks = days / 7;
+        if weeks == 0 {
+            return Ok(Vec::new());
+        }
+        let mut changes = Vec::new();
+        for modifier in self.economy.list_modifiers(world_id).await? {
+            if modifier.flag.is_some() {
+                continue;
+            }
+            let mut percent = modifier.price_adjustment;
+            for _ in 0..weeks {
+                percent += match self.roll() {
+                    1 | 2 => -PRICE_DRIFT_STEP,
+                    5 | 6 => PRICE_DRIFT_STEP,
+                    _ => 0,
+                };
+            }
+            let percent =
+                percent.clamp(-MAX_PRICE_ADJUSTMENT_PERCENT, MAX_PRICE_ADJUSTMENT_PERCENT);
+            if percent != modifier.price_adjustment {
+                changes.push(SimulationChange::PriceDrift {
+                    modifier_id: modifier.id,
+                    reason: modifier.reason,
+                    from_percent: modifier.price_adjustment,
+                    to_percent: percent,
+                });
+            }
+        }
+        Ok(changes)
+    }
+
+    fn roll(&self) -> i32 {
+        self.random.gen_range(1, SIMULATION_DIE)
+    }
+}
+
+/// An NPC and the regions their schedule ties them to
+struct NpcHaunts {
+    id: CharacterId,
+    name: String,
+    regions: Vec<NpcRegionRelationship>,
+}
+
+impl NpcHaunts {
+    fn shares_haunt_with(&self, other: &NpcHaunts) -> bool {
+        self.id != other.id
+            && self.regions.iter().any(|mine| {
+                mine.relationship_type != NpcRegionRelationType::Avoids
+                    && other.regions.iter().any(|theirs| {
+                        theirs.relationship_type != NpcRegionRelationType::Avoids
+                            && theirs.region_id == mine.region_id
+                    })
+            })
+    }
+}
+
+/// Where an NPC's schedule puts them: work first, then their usual haunts,
+/// then home
+fn scheduled_region(regions: &[NpcRegionRelationship], time_of_day: TimeOfDay) -> Option<RegionId> {
+    [
+        NpcRegionRelationType::WorksAt,
+        NpcRegionRelationType::Frequents,
+        NpcRegionRelationType::HomeRegion,
+    ]
+    .into_iter()
+    .find_map(|wanted| {
+        regions
+            .iter()
+            .filter(|r| r.relationship_type == wanted)
+            .find(|r| schedule_of(r).is_some_and(|s| s.is_npc_present(time_of_day)))
+            .map(|r| r.region_id)
+    })
+}
+
+fn schedule_of(relationship: &NpcRegionRelationship) -> Option<RegionRelationshipType> {
+    match relationship.relationship_type {
+        NpcRegionRelationType::HomeRegion => Some(RegionRelationshipType::Home),
+        NpcRegionRelationType::WorksAt => Some(RegionRelationshipType::WorksAt {
+            shift: relationship
+                .shift
+                .as_deref()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(RegionShift::Always),
+        }),
+        NpcRegionRelationType::Frequents => Some(RegionRelationshipType::Frequents {
+            frequency: relationship
+                .frequency
+                .as_deref()
+                .and_then(|f| f.parse().ok())
+                .unwrap_or(RegionFrequency::Often),
+        }),
+        NpcRegionRelationType::Avoids => None,
+    }
+}
+
+pub fn simulation_to_data(simulation: &WorldSimulation) -> WorldSimulationData {
+    WorldSimulationData {
+        id: simulation.id.to_string(),
+        from_game_time: simulation.from_game_time.to_rfc3339(),
+        to_game_time: simulation.to_game_time.to_rfc3339(),
+        days: simulation.days(),
+        changes: simulation.changes.iter().map(change_to_data).collect(),
+        changelog: simulation.changes.iter().map(|c| c.describe()).collect(),
+    }
+}
+
+fn change_to_data(change: &SimulationChange) -> SimulationChangeData {
+    match change.clone() {
+        SimulationChange::ClockAdvanced {
+            clock_id,
+            clock_name,
+            segments,
+        } => SimulationChangeData::ClockAdvanced {
+            clock_id: clock_id.to_string(),
+            clock_name,
+            segments,
+        },
+        SimulationChange::NpcMoved {
+            npc_id,
+            npc_name,
+            region_id,
+            region_name,
+        } => SimulationChangeData::NpcMoved {
+            npc_id: npc_id.to_string(),
+            npc_name,
+            region_id: region_id.to_string(),
+            region_name,
+        },
+        SimulationChange::RumorSpread {
+            lore_id,
+            lore_title,
+            npc_id,
+            npc_name,
+            told_by_id,
+            told_by_name,
+        } => SimulationChangeData::RumorSpread {
+            lore_id: lore_id.to_string(),
+            lore_title,
+            npc_id: npc_id.to_string(),
+            npc_name,
+            told_by_id: told_by_id.to_string(),
+            told_by_name,
+        },
+        SimulationChange::PriceDrift {
+            modifier_id,
+            reason,
+            from_percent,
+            to_percent,
+        } => SimulationChangeData::PriceDrift {
+            modifier_id: modifier_id.to_string(),
+            reason,
+            from_percent,
+            to_percent,
+        },
+    }
+}
+
+fn change_from_data(data: SimulationChangeData) -> Result<SimulationChange, SimulationError> {
+    Ok(match data {
+        SimulationChangeData::ClockAdvanced {
+            clock_id,
+            clock_name,
+            segments,
+        } => SimulationChange::ClockAdvanced {
+            clock_id: parse_id(&clock_id, ProgressClockId::from_uuid, "clock")?,
+            clock_name,
+            segments,
+        },
+        SimulationChangeData::NpcMoved {
+            npc_id,
+            npc_name,
+            region_id,
+            region_name,
+        } => SimulationChange::NpcMoved {
+            npc_id: parse_id(&npc_id, CharacterId::from_uuid, "NPC")?,
+            npc_name,
+            region_id: parse_id(&region_id, RegionId::from_uuid, "region")?,
+            region_name,
+        },
+        SimulationChangeData::RumorSpread {
+            lore_id,
+            lore_title,
+            npc_id,
+            npc_name,
+            told_by_id,
+            told_by_name,
+        } => SimulationChange::RumorSpread {
+            lore_id: parse_id(&lore_id, LoreId::from_uuid, "lore")?,
+            lore_title,
+            npc_id: parse_id(&npc_id, CharacterId::from_uuid, "NPC")?,
+            npc_name,
+            told_by_id: parse_id(&told_by_id, CharacterId::from_uuid, "NPC")?,
+            told_by_name,
+        },
+        SimulationChangeData::PriceDrift {
+            modifier_id,
+            reason,
+            from_percent,
+            to_percent,
+        } => SimulationChange::PriceDrift {
+            modifier_id: parse_id(&modifier_id, MarketModifierId::from_uuid, "market modifier")?,
+            reason,
+            from_percent,
+            to_percent,
+        },
+        SimulationChangeData::Unknown => {
+            return Err(SimulationError::Invalid(
+                "Unknown simulation change".to_string(),
+            ))
+        }
+    })
+}
+
+fn parse_id<T>(id: &str, from_uuid: fn(Uuid) -> T, what: &str) -> Result<T, SimulationError> {
+    Uuid::parse_str(id)
+        .map(from_uuid)
+        .map_err(|_| SimulationError::Invalid(format!("Invalid {} ID: {}", what, id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::{FixedClock, FixedRandom};
+    use crate::infrastructure::ports::{
+        MockChallengeRepo, MockCharacterRepo, MockEconomyRepo, MockFlagRepo, MockLocationRepo,
+        MockLoreRepo, MockNarrativeRepo, MockObservationRepo, MockPlayerCharacterRepo,
+        MockProgressClockRepo, MockSceneRepo, MockWorldRepo,
+    };
+    use chrono::Utc;
+    use wrldbldr_domain::{
+        CampbellArchetype, Character, LocationId, Lore, MarketModifier, Region, World,
+    };
+
+    struct Repos {
+        worlds: MockWorldRepo,
+        characters: MockCharacterRepo,
+        locations: MockLocationRepo,
+        lore: MockLoreRepo,
+        economy: MockEconomyRepo,
+        clocks: MockProgressClockRepo,
+        narrative: MockNarrativeRepo,
+    }
+
+    impl Repos {
+        fn new(world: World) -> Self {
+            let mut worlds = MockWorldRepo::new();
+            worlds
+                .expect_get()
+                .returning(move |_| Ok(Some(world.clone())));
+            Self {
+                worlds,
+                characters: MockCharacterRepo::new(),
+                locations: MockLocationRepo::new(),
+                lore: MockLoreRepo::new(),
+                economy: MockEconomyRepo::new(),
+                clocks: MockProgressClockRepo::new(),
+                narrative: MockNarrativeRepo::new(),
+            }
+        }
+
+        fn build(self, roll: i32) -> SimulateWorld {
+            let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(Utc::now()));
+            let worlds: Arc<MockWorldRepo> = Arc::new(self.worlds);
+            let characters: Arc<MockCharacterRepo> = Arc::new(self.characters);
+            SimulateWorld::new(
+                Arc::new(entities::World::new(worlds.clone(), clock.clone())),
+                Arc::new(entities::Character::new(characters.clone())),
+                Arc::new(entities::Location::new(Arc::new(self.locations))),
+                Arc::new(entities::Lore::new(Arc::new(self.lore))),
+                Arc::new(entities::Economy::new(Arc::new(self.economy))),
+                Arc::new(entities::ProgressClock::new(
+                    Arc::new(self.clocks),
+                    clock.clone(),
+                )),
+                Arc::new(entities::Narrative::new(
+                    Arc::new(self.narrative),
+                    Arc::new(MockLocationRepo::new()),
+                    worlds,
+                    Arc::new(MockPlayerCharacterRepo::new()),
+                    characters,
+                    Arc::new(MockObservationRepo::new()),
+                    Arc::new(MockChallengeRepo::new()),
+                    Arc::new(MockFlagRepo::new()),
+                    Arc::new(MockSceneRepo::new()),
+                    clock.clone(),
+                )),
+                Arc::new(FixedRandom(roll)),
+                clock,
+            )
+        }
+    }
+
+    fn works_at(region_id: RegionId) -> NpcRegionRelationship {
+        NpcRegionRelationship {
+            region_id,
+            relationship_type: NpcRegionRelationType::WorksAt,
+            shift: Some("always".to_string()),
+            frequency: None,
+            time_of_day: None,
+            reason: None,
+        }
+    }
+
+    /// A threat clock, a smith and her apprentice at the forge, a rumor only
+    /// the smith knows and an unflagged price modifier
+    fn busy_world(
+        world: World,
+    ) -> (
+        Repos,
+        ProgressClock,
+        Character,
+        Character,
+        Lore,
+        MarketModifier,
+    ) {
+        let world_id = world.id;
+        let threat = ProgressClock::new(world_id, "The Bluecoats close in", 4, Utc::now()).unwrap();
+        let smith = Character::new(world_id, "Brenna", CampbellArchetype::Mentor);
+        let apprentice = Character::new(world_id, "Tam", CampbellArchetype::Ally);
+        let forge = Region::new(LocationId::new(), "The Forge");
+        let rumor = Lore::new(
+            world_id,
+            "The mayor's debts",
+            LoreCategory::Political,
+            Utc::now(),
+        );
+        let modifier = MarketModifier::new(world_id, "Iron shortage", Utc::now()).unwrap();
+
+        let mut repos = Repos::new(world);
+        let clocks = vec![threat.clone()];
+        repos
+            .clocks
+            .expect_list_in_world()
+            .returning(move |_| Ok(clocks.clone()));
+        let npcs = vec![smith.clone(), apprentice.clone()];
+        repos
+            .characters
+            .expect_list_npcs_in_world()
+            .returning(move |_| Ok(npcs.clone()));
+        let forge_id = forge.id;
+        repos
+            .characters
+            .expect_get_region_relationships()
+            .returning(move |_| Ok(vec![works_at(forge_id)]));
+        // Only the smith is at the forge yet
+        let present = vec![smith.clone()];
+        repos
+            .characters
+            .expect_list_in_region()
+            .returning(move |_| Ok(present.clone()));
+        repos
+            .locations
+            .expect_get_region()
+            .returning(move |_| Ok(Some(forge.clone())));
+        let lore = vec![rumor.clone()];
+        repos
+            .lore
+            .expect_list_for_world()
+            .returning(move |_| Ok(lore.clone()));
+        let known = vec![LoreKnowledge::full(
+            rumor.id,
+            smith.id,
+            LoreDiscoverySource::DmGranted { reason: None },
+            Utc::now(),
+        )];
+        repos
+            .lore
+            .expect_get_knowledge_for_lore()
+            .returning(move |_| Ok(known.clone()));
+        let modifiers = vec![modifier.clone()];
+        repos
+            .economy
+            .expect_list_modifiers()
+            .returning(move |_| Ok(modifiers.clone()));
+        (repos, threat, smith, apprentice, rumor, modifier)
+    }
+
+    #[tokio::test]
+    async fn skips_shorter_than_a_day_are_not_simulated() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let world_id = world.id;
+        let simulate = Repos::new(world).build(6);
+
+        let result = simulate.simulate(world_id, 23 * 60).await.unwrap();
+
+        assert!(result.is_none());
+        assert!(simulate.list(world_id).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_fortnight_off_screen_proposes_changes_for_the_dm() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let world_id = world.id;
+        let (repos, threat, _, apprentice, rumor, modifier) = busy_world(world);
+        let simulate = repos.build(6);
+
+        let simulation = simulate
+            .simulate(world_id, 14 * 24 * 60)
+            .await
+            .unwrap()
+            .expect("something happened");
+
+        assert_eq!(simulation.days(), 14);
+        assert!(simulation
+            .changes
+            .contains(&SimulationChange::ClockAdvanced {
+                clock_id: threat.id,
+                clock_name: threat.name.clone(),
+                segments: 4,
+            }));
+        assert!(simulation.changes.iter().any(|c| matches!(
+            c,
+            SimulationChange::NpcMoved { npc_id, .. } if *npc_id == apprentice.id
+        )));
+        assert!(simulation.changes.iter().any(|c| matches!(
+            c,
+            SimulationChange::RumorSpread { lore_id, npc_id, .. }
+                if *lore_id == rumor.id && *npc_id == apprentice.id
+        )));
+        assert!(simulation.changes.contains(&SimulationChange::PriceDrift {
+            modifier_id: modifier.id,
+            reason: modifier.reason.clone(),
+            from_percent: 0,
+            to_percent: 10,
+        }));
+        assert_eq!(simulate.list(world_id).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn accepting_applies_the_edited_changes_and_notes_them() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let world_id = world.id;
+        let (mut repos, threat, _, _, _, _) = busy_world(world);
+        let stored = threat.clone();
+        repos
+            .clocks
+            .expect_get()
+            .returning(move |_| Ok(Some(stored.clone())));
+        repos
+            .clocks
+            .expect_save()
+            .withf(|clock| clock.filled == 1)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos
+            .narrative
+            .expect_save_story_event()
+            .withf(|e| {
+                matches!(
+                    &e.event_type,
+                    StoryEventType::DmMarker { note, .. }
+                        if note == "- The Bluecoats close in advanced 1 segment"
+                )
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let simulate = repos.build(6);
+        let simulation = simulate
+            .simulate(world_id, 3 * 24 * 60)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // The DM keeps only the clock, and slows it down
+        let edited = vec![SimulationChangeData::ClockAdvanced {
+            clock_id: threat.id.to_string(),
+            clock_name: threat.name.clone(),
+            segments: 1,
+        }];
+        simulate
+            .update(world_id, simulation.id, edited)
+            .await
+            .unwrap();
+        let accepted = simulate.accept(world_id, simulation.id).await.unwrap();
+
+        assert_eq!(accepted.clocks.len(), 1);
+        assert_eq!(accepted.clocks[0].filled, 1);
+        assert!(simulate.list(world_id).await.is_empty());
+        assert!(matches!(
+            simulate.accept(world_id, simulation.id).await,
+            Err(SimulationError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn quiet_dice_leave_the_world_alone() {
+        let world = World::new("Varn", "desc", Utc::now());
+        let world_id = world.id;
+        let (mut repos, _, smith, apprentice, _, _) = busy_world(world);
+        // Nobody keeps a schedule, so nobody moves or meets anyone to gossip with
+        repos.characters.checkpoint();
+        let npcs = vec![smith.clone(), apprentice.clone()];
+        repos
+            .characters
+            .expect_list_npcs_in_world()
+            .returning(move |_| Ok(npcs.clone()));
+        repos
+            .characters
+            .expect_get_region_relationships()
+            .returning(|_| Ok(Vec::new()));
+        let simulate = repos.build(3);
+
+        let result = simulate.simulate(world_id, 30 * 24 * 60).await.unwrap();
+
+        assert!(result.is_none());
+    }
+}
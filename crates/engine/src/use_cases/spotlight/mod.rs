@@ -0,0 +1,276 @@
+//! Spotlight use cases.
+//!
+//! For narrative systems without initiative, the spotlight tracker records
+//! which PC each player action came from. The DM sees the living PCs in
+//! rotation order with the quiet ones flagged, can hand the spotlight on,
+//! and can have the engine refuse a second action in a row from one player
+//! while a crowd of PCs shares the region.
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{DomainError, PlayerCharacter, PlayerCharacterId, Spotlight, World, WorldId};
+use wrldbldr_protocol::types::{SpotlightData, SpotlightPcData, SpotlightSettingsData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for spotlight use cases.
+pub struct SpotlightUseCases {
+    pub manage: Arc<ManageSpotlight>,
+}
+
+impl SpotlightUseCases {
+    pub fn new(manage: Arc<ManageSpotlight>) -> Self {
+        Self { manage }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SpotlightError {
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("{0} just acted; give someone else a turn first")]
+    Throttled(String),
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+impl From<DomainError> for SpotlightError {
+    fn from(e: DomainError) -> Self {
+        SpotlightError::Invalid(e.to_string())
+    }
+}
+
+/// Track player actions, rotate the spotlight and throttle crowded scenes.
+pub struct ManageSpotlight {
+    world: Arc<entities::World>,
+    player_character: Arc<entities::PlayerCharacter>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageSpotlight {
+    pub fn new(
+        world: Arc<entities::World>,
+        player_character: Arc<entities::PlayerCharacter>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            world,
+            player_character,
+            clock,
+        }
+    }
+
+    pub async fn get(&self, world_id: WorldId) -> Result<SpotlightData, SpotlightError> {
+        let world = self.get_world(world_id).await?;
+        self.to_protocol(&world).await
+    }
+
+    pub async fn update_settings(
+        &self,
+        world_id: WorldId,
+        data: SpotlightSettingsData,
+    ) -> Result<SpotlightData, SpotlightError> {
+        let mut world = self.get_world(world_id).await?;
+        world
+            .spotlight
+            .configure(data.enabled, data.quiet_after, data.throttle_crowd)?;
+        self.save(world).await
+    }
+
+    /// Pass the spotlight to whoever has gone longest without acting
+    pub async fn rotate(&self, world_id: WorldId) -> Result<SpotlightData, SpotlightError> {
+        let mut world = self.get_world(world_id).await?;
+        let pc_ids: Vec<PlayerCharacterId> = self
+            .living_pcs(world_id)
+            .await?
+            .iter()
+            .map(|pc| pc.id)
+            .collect();
+        world.spotlight.rotate(&pc_ids);
+        self.save(world).await
+    }
+
+    /// Hand the spotlight to a PC of the world, or clear it
+    pub async fn set_focus(
+        &self,
+        world_id: WorldId,
+        pc_id: Option<PlayerCharacterId>,
+    ) -> Result<SpotlightData, SpotlightError> {
+        let mut world = self.get_world(world_id).await?;
+        if let Some(pc_id) = pc_id {
+            match self.player_character.get(pc_id).await? {
+                Some(pc) if pc.world_id == world_id => {}
+                _ => return Err(SpotlightError::PlayerCharacterNotFound),
+            }
+        }
+        world.spotlight.focus_pc_id = pc_id;
+        self.save(world).await
+    }
+
+    pub async fn reset(&self, world_id: WorldId) -> Result<SpotlightData, SpotlightError> {
+        let mut world = self.get_world(world_id).await?;
+        world.spotlight.reset();
+        self.save(world).await
+    }
+
+    /// Refuse a PC's action if it acted last and its region is crowded
+    pub async fn check_action(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<(), SpotlightError> {
+        let world = self.get_world(world_id).await?;
+        let spotlight = &world.spotlight;
+        if !spotlight.enabled
+            || spotlight.throttle_crowd == 0
+            || spotlight.recent.last() != Some(&pc_id)
+        {
+            return Ok(());
+        }
+
+        let pcs = self.living_pcs(world_id).await?;
+        let Some(pc) = pcs.iter().find(|pc| pc.id == pc_id) else {
+            return Ok(());
+        };
+        let present = pcs
+            .iter()
+            .filter(|other| {
+                other.current_region_id.is_some() && other.current_region_id == pc.current_region_id
+            })
+            .count();
+        if spotlight.throttles(pc_id, present) {
+            return Err(SpotlightError::Throttled(pc.name.clone()));
+        }
+        Ok(())
+    }
+
+    /// Note that a PC acted. Returns the updated tracker, or `None` when the
+    /// world doesn't use one.
+    pub async fn record_action(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Option<SpotlightData>, SpotlightError> {
+        let mut world = self.get_world(world_id).await?;
+        if !world.spotlight.enabled {
+            return Ok(None);
+        }
+        world.spotlight.record_action(pc_id);
+        self.save(world).await.map(Some)
+    }
+
+    async fn save(&self, mut world: World) -> Result<SpotlightData, SpotlightError> {
+        world.updated_at = self.clock.now();
+        self.world.save(&world).await?;
+        self.to_protocol(&world).await
+    }
+
+    async fn get_world(&self, world_id: WorldId) -> Result<World, SpotlightError> {
+        self.world
+            .get(world_id)
+            .await?
+            .ok_or(SpotlightError::WorldNotFound)
+    }
+
+    async fn living_pcs(&self, world_id: WorldId) -> Result<Vec<PlayerCharacter>, SpotlightError> {
+        let mut pcs = self.player_character.list_in_world(world_id).await?;
+        pcs.retain(|pc| !pc.is_locked());
+        Ok(pcs)
+    }
+
+    async fn to_protocol(&self, world: &World) -> Result<SpotlightData, SpotlightError> {
+        let pcs = self.living_pcs(world.id).await?;
+        Ok(spotlight_to_protocol(&world.spotlight, &pcs))
+    }
+}
+
+/// The tracker for the DM, with the PCs in rotation order
+pub fn spotlight_to_protocol(spotlight: &Spotlight, pcs: &[PlayerCharacter]) -> SpotlightData {
+    let pc_ids: Vec<PlayerCharacterId> = pcs.iter().map(|pc| pc.id).collect();
+    let rotation = spotlight
+        .rotation(&pc_ids)
+        .into_iter()
+        .filter_map(|pc_id| pcs.iter().find(|pc| pc.id == pc_id))
+        .map(|pc| SpotlightPcData {
+            pc_id: pc.id.to_string(),
+            name: pc.name.clone(),
+            actions_since: spotlight.actions_since(pc.id),
+            is_quiet: spotlight.is_quiet(pc.id),
+        })
+        .collect();
+    SpotlightData {
+        settings: SpotlightSettingsData {
+            enabled: spotlight.enabled,
+            quiet_after: spotlight.quiet_after,
+            throttle_crowd: spotlight.throttle_crowd,
+        },
+        focus_pc_id: spotlight.focus_pc_id.map(|id| id.to_string()),
+        rotation,
+    }
+}
+
+/// What players see: who has the spotlight, without the DM's warnings
+pub fn spotlight_for_players(data: &SpotlightData) -> SpotlightData {
+    SpotlightData {
+        rotation: Vec::new(),
+        ..data.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::Simulation;
+
+    #[tokio::test]
+    async fn crowded_scenes_throttle_back_to_back_actions() {
+        let sim = Simulation::new(12);
+        let world = sim.world("Saltmere").await;
+        let harbor = sim.location(world.id, "Harbor").await;
+        let docks = sim.region(harbor.id, "Docks").await;
+        let ana = sim.pc(world.id, "Ana", &docks).await;
+        let bo = sim.pc(world.id, "Bo", &docks).await;
+        let spotlight = &sim.app.use_cases.spotlight.manage;
+
+        // Off by default: nothing is tracked
+        assert!(spotlight
+            .record_action(world.id, ana.id)
+            .await
+            .unwrap()
+            .is_none());
+
+        spotlight
+            .update_settings(
+                world.id,
+                SpotlightSettingsData {
+                    enabled: true,
+                    quiet_after: 1,
+                    throttle_crowd: 2,
+                },
+            )
+            .await
+            .unwrap();
+        let data = spotlight
+            .record_action(world.id, ana.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(data.rotation[0].pc_id, bo.id.to_string());
+        assert!(data.rotation[0].is_quiet);
+
+        assert!(matches!(
+            spotlight.check_action(world.id, ana.id).await,
+            Err(SpotlightError::Throttled(_))
+        ));
+        spotlight.check_action(world.id, bo.id).await.unwrap();
+
+        let data = spotlight.rotate(world.id).await.unwrap();
+        assert_eq!(data.focus_pc_id, Some(bo.id.to_string()));
+        assert!(spotlight_for_players(&data).rotation.is_empty());
+    }
+}
@@ -11,16 +11,18 @@ use uuid::Uuid;
 
 use crate::api::connections::ConnectionManager;
 use crate::entities::{
-    Character, Flag, Location, LocationStateEntity, RegionStateEntity, Staging, World,
+    Character, Companion, Faction, Flag, Location, LocationStateEntity, RegionStateEntity, Staging,
+    World,
 };
 use crate::infrastructure::ports::{
     ChatMessage, LlmPort, LlmRequest, NpcRegionRelationType, RepoError, SettingsRepo,
 };
+use crate::use_cases::ai::structured::generate_structured;
 use crate::use_cases::time::TimeSuggestion;
 use crate::use_cases::visual_state::{ResolveVisualState, StateResolutionContext};
 use wrldbldr_domain::{
-    CharacterId, LocationId, PlayerCharacter, RegionId, Staging as DomainStaging, StagingSource,
-    WorldId,
+    CharacterId, LlmTask, LocationId, PlayerCharacter, PlayerCharacterId, RegionId, RegionStateId,
+    Staging as DomainStaging, StagingId, StagingSource, WorldId,
 };
 use wrldbldr_protocol::{
     ApprovedNpcInfo, NpcPresentInfo, PreviousStagingInfo, ServerMessage, StagedNpcInfo,
@@ -90,9 +92,29 @@ pub struct PendingStagingRequest {
     pub region_id: RegionId,
     pub location_id: LocationId,
     pub world_id: WorldId,
+    /// The PC whose arrival asked for the staging
+    pub pc_id: PlayerCharacterId,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// PCs to tell when a region's staging is settled: the one that asked for
+/// it, plus any still waiting on the same region. Staging is per region,
+/// so every scene with a PC there shares it.
+pub async fn pcs_awaiting_region(
+    pending_requests: &RwLock<HashMap<String, PendingStagingRequest>>,
+    region_id: RegionId,
+    asked_by: Option<PlayerCharacterId>,
+) -> Vec<PlayerCharacterId> {
+    let guard = pending_requests.read().await;
+    let mut pc_ids: Vec<_> = asked_by.into_iter().collect();
+    for pending in guard.values() {
+        if pending.region_id == region_id && !pc_ids.contains(&pending.pc_id) {
+            pc_ids.push(pending.pc_id);
+        }
+    }
+    pc_ids
+}
+
 /// IO dependencies for staging requests (WS-state owned).
 pub struct StagingApprovalContext<'a> {
     pub connections: &'a ConnectionManager,
@@ -113,6 +135,8 @@ pub struct StagingApprovalInput {
 /// Use case for building and broadcasting a staging approval request.
 pub struct RequestStagingApproval {
     character: Arc<Character>,
+    companion: Arc<Companion>,
+    faction: Arc<Faction>,
     staging: Arc<Staging>,
     location: Arc<Location>,
     world: Arc<World>,
@@ -123,8 +147,11 @@ pub struct RequestStagingApproval {
 }
 
 impl RequestStagingApproval {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         character: Arc<Character>,
+        companion: Arc<Companion>,
+        faction: Arc<Faction>,
         staging: Arc<Staging>,
         location: Arc<Location>,
         world: Arc<World>,
@@ -135,6 +162,8 @@ impl RequestStagingApproval {
     ) -> Self {
         Self {
             character,
+            companion,
+            faction,
             staging,
             location,
             world,
@@ -160,6 +189,7 @@ impl RequestStagingApproval {
                     region_id: input.region.id,
                     location_id: input.region.location_id,
                     world_id: input.world_id,
+                    pc_id: input.pc.id,
                     created_at: chrono::Utc::now(),
                 },
             );
@@ -184,15 +214,24 @@ impl RequestStagingApproval {
             .map(|l| l.name)
             .unwrap_or_else(|| "Unknown Location".to_string());
 
-        let rule_based_npcs =
-            generate_rule_based_suggestions(&self.character, &self.staging, input.region.id).await;
+        let faction_labels = faction_labels(&self.faction, input.world_id).await;
+        let rule_based_npcs = generate_rule_based_suggestions(
+            &self.character,
+            &self.companion,
+            &self.staging,
+            input.region.id,
+            &faction_labels,
+        )
+        .await;
         let llm_based_npcs = generate_llm_based_suggestions(
             &self.character,
             self.llm.as_ref(),
-            input.region.id,
-            &input.region.name,
+            &input.region,
             &location_name,
             input.guidance.as_deref(),
+            settings.model_for(LlmTask::Classification),
+            Some(input.world_id),
+            &faction_labels,
         )
         .await;
 
@@ -376,15 +415,25 @@ impl RequestStagingApproval {
 pub struct RegenerateStagingSuggestions {
     location: Arc<Location>,
     character: Arc<Character>,
+    faction: Arc<Faction>,
     llm: Arc<dyn LlmPort>,
+    settings: Arc<dyn SettingsRepo>,
 }
 
 impl RegenerateStagingSuggestions {
-    pub fn new(location: Arc<Location>, character: Arc<Character>, llm: Arc<dyn LlmPort>) -> Self {
+    pub fn new(
+        location: Arc<Location>,
+        character: Arc<Character>,
+        faction: Arc<Faction>,
+        llm: Arc<dyn LlmPort>,
+        settings: Arc<dyn SettingsRepo>,
+    ) -> Self {
         Self {
             location,
             character,
+            faction,
             llm,
+            settings,
         }
     }
 
@@ -399,22 +448,34 @@ impl RegenerateStagingSuggestions {
             .await?
             .ok_or(StagingError::RegionNotFound)?;
 
-        let location_name = self
-            .location
-            .get(region.location_id)
-            .await
-            .ok()
-            .flatten()
+        let location = self.location.get(region.location_id).await.ok().flatten();
+        let world_id = location.as_ref().map(|l| l.world_id);
+        let model = match &location {
+            Some(l) => {
+                let settings =
+                    get_settings_with_fallback(self.settings.as_ref(), l.world_id, "regenerate")
+                        .await;
+                settings.model_for(LlmTask::Classification)
+            }
+            None => None,
+        };
+        let location_name = location
             .map(|l| l.name)
             .unwrap_or_else(|| "Unknown Location".to_string());
+        let faction_labels = match world_id {
+            Some(world_id) => faction_labels(&self.faction, world_id).await,
+            None => HashMap::new(),
+        };
 
         Ok(generate_llm_based_suggestions(
             &self.character,
             self.llm.as_ref(),
-            region_id,
-            &region.name,
+            &region,
             &location_name,
             guidance,
+            model,
+            world_id,
+            &faction_labels,
         )
         .await)
     }
@@ -574,6 +635,55 @@ impl ApproveStagingRequest {
         })
     }
 
+    /// Put a region back to an earlier staging and region state, e.g. when
+    /// a flashback ends. With no staging the region is left unstaged; with no
+    /// region state its state override is cleared.
+    pub async fn restore(
+        &self,
+        region_id: RegionId,
+        location_id: LocationId,
+        staging_id: Option<StagingId>,
+        region_state_id: Option<RegionStateId>,
+    ) -> Result<StagingReadyPayload, StagingError> {
+        match staging_id {
+            Some(staging_id) => {
+                self.staging
+                    .activate_staging(staging_id, region_id)
+                    .await?
+            }
+            None => self.staging.deactivate_staging(region_id).await?,
+        }
+        match region_state_id {
+            Some(state_id) => self.region_state.set_active(region_id, state_id).await?,
+            None => self.region_state.clear_active(region_id).await?,
+        }
+
+        let npcs_present = self
+            .staging
+            .get_staged_npcs(region_id)
+            .await?
+            .into_iter()
+            .filter(|npc| npc.is_present && !npc.is_hidden_from_players)
+            .map(|npc| NpcPresentInfo {
+                character_id: npc.character_id.to_string(),
+                name: npc.name,
+                sprite_asset: npc.sprite_asset,
+                portrait_asset: npc.portrait_asset,
+                is_hidden_from_players: false,
+                mood: Some(npc.mood.to_string()),
+            })
+            .collect();
+        let visual_state = self
+            .build_visual_state_for_staging(location_id, region_id)
+            .await;
+
+        Ok(StagingReadyPayload {
+            region_id,
+            npcs_present,
+            visual_state,
+        })
+    }
+
     async fn build_staged_npcs(
         &self,
         approved_npcs: &[ApprovedNpcInfo],
@@ -797,10 +907,24 @@ struct LlmSuggestion {
     reason: String,
 }
 
+/// Faction membership and standing per NPC, for staging reasoning and
+/// prompts. Staging goes ahead without them if factions can't be loaded.
+async fn faction_labels(faction: &Faction, world_id: WorldId) -> HashMap<CharacterId, String> {
+    match faction.list_in_world(world_id).await {
+        Ok(factions) => crate::use_cases::factions::staging_labels(&factions),
+        Err(e) => {
+            tracing::warn!(error = %e, world_id = %world_id, "Failed to load factions for staging");
+            HashMap::new()
+        }
+    }
+}
+
 async fn generate_rule_based_suggestions(
     character: &Character,
+    companion: &Companion,
     staging: &Staging,
     region_id: RegionId,
+    faction_labels: &HashMap<CharacterId, String>,
 ) -> Vec<StagedNpcInfo> {
     let npcs_with_relationships = character
         .get_npcs_for_region(region_id)
@@ -808,9 +932,33 @@ async fn generate_rule_based_suggestions(
         .ok()
         .unwrap_or_default();
 
-    let mut suggestions: Vec<StagedNpcInfo> = npcs_with_relationships
+    // Companions travelling with a PC are always there
+    let mut suggestions: Vec<StagedNpcInfo> = companion
+        .staged_in_region(region_id)
+        .await
+        .ok()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|staged| StagedNpcInfo {
+            character_id: staged.character_id.to_string(),
+            name: staged.name,
+            sprite_asset: staged.sprite_asset,
+            portrait_asset: staged.portrait_asset,
+            is_present: true,
+            reasoning: staged.reasoning,
+            is_hidden_from_players: false,
+            mood: Some(staged.mood.to_string()),
+        })
+        .collect();
+
+    let rule_based: Vec<StagedNpcInfo> = npcs_with_relationships
         .into_iter()
         .filter(|n| n.relationship_type != NpcRegionRelationType::Avoids)
+        .filter(|n| {
+            !suggestions
+                .iter()
+                .any(|s| s.character_id == n.character_id.to_string())
+        })
         .map(|npc| {
             let reasoning = match npc.relationship_type {
                 NpcRegionRelationType::HomeRegion => "Lives here".to_string(),
@@ -829,6 +977,10 @@ async fn generate_rule_based_suggestions(
                 }
                 NpcRegionRelationType::Avoids => "Avoids this area".to_string(),
             };
+            let reasoning = match faction_labels.get(&npc.character_id) {
+                Some(label) => format!("{} · {}", reasoning, label),
+                None => reasoning,
+            };
 
             StagedNpcInfo {
                 character_id: npc.character_id.to_string(),
@@ -842,6 +994,7 @@ async fn generate_rule_based_suggestions(
             }
         })
         .collect();
+    suggestions.extend(rule_based);
 
     if let Ok(staged_npcs) = staging.get_staged_npcs(region_id).await {
         for staged in staged_npcs {
@@ -866,15 +1019,18 @@ async fn generate_rule_based_suggestions(
     suggestions
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn generate_llm_based_suggestions(
     character: &Character,
     llm: &dyn LlmPort,
-    region_id: RegionId,
-    region_name: &str,
+    region: &wrldbldr_domain::Region,
     location_name: &str,
     guidance: Option<&str>,
+    model: Option<String>,
+    world_id: Option<WorldId>,
+    faction_labels: &HashMap<CharacterId, String>,
 ) -> Vec<StagedNpcInfo> {
-    let npcs_with_relationships = match character.get_npcs_for_region(region_id).await {
+    let npcs_with_relationships = match character.get_npcs_for_region(region.id).await {
         Ok(npcs) => npcs,
         Err(e) => {
             tracing::warn!(error = %e, "Failed to get NPCs for LLM staging");
@@ -901,7 +1057,10 @@ async fn generate_llm_based_suggestions(
                 NpcRegionRelationType::Frequents => "frequents this area",
                 NpcRegionRelationType::Avoids => "avoids this area",
             };
-            format!("{}. {} ({})", i + 1, npc.name, relationship)
+            match faction_labels.get(&npc.character_id) {
+                Some(label) => format!("{}. {} ({}; {})", i + 1, npc.name, relationship, label),
+                None => format!("{}. {} ({})", i + 1, npc.name, relationship),
+            }
         })
         .collect::<Vec<_>>()
         .join("\n");
@@ -913,29 +1072,36 @@ async fn generate_llm_based_suggestions(
 
     let system_prompt = "You are a helpful TTRPG assistant helping decide which NPCs should be present in a scene. \
         Respond with a JSON array of objects, each with 'name' (exact name from the list) and 'reason' (brief explanation). \
-        Select 1-4 NPCs that would logically be present. Only include NPCs from the provided list.";
+        Select 1-4 NPCs that would logically be present. Only include NPCs from the provided list. \
+        Where an NPC's faction is hostile or allied to the party, let that weigh on whether they show up.";
 
     let user_prompt = format!(
         "Region: {} (in {})\n\nAvailable NPCs:\n{}{}\n\nWhich NPCs should be present? Respond with JSON only.",
-        region_name, location_name, npc_list, guidance_text
+        region.name, location_name, npc_list, guidance_text
     );
 
-    let request = LlmRequest::new(vec![ChatMessage::user(&user_prompt)])
+    let mut request = LlmRequest::new(vec![ChatMessage::user(&user_prompt)])
         .with_system_prompt(system_prompt)
-        .with_temperature(0.7);
+        .with_temperature(0.7)
+        .with_model(model)
+        .with_cache(true);
+    if let Some(world_id) = world_id {
+        request = request.with_world(world_id);
+    }
 
-    let response = match llm.generate(request).await {
-        Ok(resp) => resp,
+    let schema = staging_response_schema(&candidates);
+    let parsed: Vec<LlmSuggestion> = match generate_structured(llm, request, schema).await {
+        Ok(parsed) => parsed,
         Err(e) => {
             tracing::warn!(error = %e, "LLM staging suggestion failed");
             return vec![];
         }
     };
 
-    let suggestions = parse_llm_staging_response(&response.content, &candidates);
+    let suggestions = match_llm_suggestions(parsed, &candidates);
 
     tracing::info!(
-        region = %region_name,
+        region = %region.name,
         suggestion_count = suggestions.len(),
         "Generated LLM staging suggestions"
     );
@@ -949,36 +1115,29 @@ fn normalize_name(name: &str) -> String {
     name.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
 }
 
-fn parse_llm_staging_response(
-    content: &str,
+/// Schema for staging replies; names are limited to the candidate NPCs.
+fn staging_response_schema(
     candidates: &[crate::infrastructure::ports::NpcWithRegionInfo],
-) -> Vec<StagedNpcInfo> {
-    let json_start = content.find('[');
-    let json_end = content.rfind(']');
-
-    let json_str = match (json_start, json_end) {
-        (Some(start), Some(end)) if end > start => &content[start..=end],
-        _ => {
-            tracing::warn!(
-                content = %content,
-                "LLM staging response did not contain a valid JSON array - returning empty suggestions"
-            );
-            return vec![];
-        }
-    };
-
-    let parsed: Vec<LlmSuggestion> = match serde_json::from_str(json_str) {
-        Ok(p) => p,
-        Err(e) => {
-            tracing::warn!(
-                error = %e,
-                json = %json_str,
-                "Failed to parse LLM staging JSON response - returning empty suggestions"
-            );
-            return vec![];
-        }
-    };
+) -> serde_json::Value {
+    let names: Vec<&str> = candidates.iter().map(|c| c.name.as_str()).collect();
+    serde_json::json!({
+        "type": "array",
+        "items": {
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "enum": names },
+                "reason": { "type": "string" }
+            },
+            "required": ["name", "reason"]
+        },
+        "maxItems": 4
+    })
+}
 
+fn match_llm_suggestions(
+    parsed: Vec<LlmSuggestion>,
+    candidates: &[crate::infrastructure::ports::NpcWithRegionInfo],
+) -> Vec<StagedNpcInfo> {
     parsed
         .into_iter()
         .filter_map(|suggestion| {
@@ -1003,6 +1162,7 @@ fn parse_llm_staging_response(
 /// Use case for auto-approving expired staging requests.
 pub struct AutoApproveStagingTimeout {
     character: Arc<Character>,
+    companion: Arc<Companion>,
     staging: Arc<Staging>,
     world: Arc<World>,
     location: Arc<Location>,
@@ -1014,6 +1174,7 @@ pub struct AutoApproveStagingTimeout {
 impl AutoApproveStagingTimeout {
     pub fn new(
         character: Arc<Character>,
+        companion: Arc<Companion>,
         staging: Arc<Staging>,
         world: Arc<World>,
         location: Arc<Location>,
@@ -1023,6 +1184,7 @@ impl AutoApproveStagingTimeout {
     ) -> Self {
         Self {
             character,
+            companion,
             staging,
             world,
             location,
@@ -1043,9 +1205,14 @@ impl AutoApproveStagingTimeout {
                 .await;
 
         // Generate rule-based NPC suggestions
-        let rule_based_npcs =
-            generate_rule_based_suggestions(&self.character, &self.staging, pending.region_id)
-                .await;
+        let rule_based_npcs = generate_rule_based_suggestions(
+            &self.character,
+            &self.companion,
+            &self.staging,
+            pending.region_id,
+            &HashMap::new(),
+        )
+        .await;
 
         // Convert to ApprovedNpcInfo format
         let approved_npcs: Vec<ApprovedNpcInfo> = rule_based_npcs
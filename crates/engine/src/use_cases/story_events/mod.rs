@@ -7,9 +7,14 @@ use uuid::Uuid;
 
 use crate::entities::Narrative;
 use crate::infrastructure::ports::RepoError;
-use wrldbldr_domain::{StoryEventId, StoryEventType, WorldId};
+use wrldbldr_domain::{
+    LocationId, MarkerLink, MarkerPin, RegionId, StoryEvent, StoryEventId, StoryEventType, WorldId,
+};
 use wrldbldr_protocol::requests::UpdateStoryEventData;
-use wrldbldr_protocol::CreateDmMarkerData;
+use wrldbldr_protocol::{CreateDmMarkerData, MapMarkerData, MarkerLinkData, MarkerPinData};
+
+/// How many recent story events are scanned for pinned markers
+const MAP_MARKER_SCAN_LIMIT: usize = 1000;
 
 /// Container for story event use cases.
 pub struct StoryEventUseCases {
@@ -72,7 +77,8 @@ impl StoryEventOps {
         &self,
         world_id: WorldId,
         data: CreateDmMarkerData,
-    ) -> Result<Uuid, StoryEventError> {
+    ) -> Result<StoryEvent, StoryEventError> {
+        let pin = data.pin.as_ref().map(pin_from_protocol).transpose()?;
         let now = chrono::Utc::now();
         let event = wrldbldr_domain::StoryEvent {
             id: StoryEventId::new(),
@@ -82,23 +88,72 @@ impl StoryEventOps {
                 note: data.content.unwrap_or_default(),
                 importance: wrldbldr_domain::MarkerImportance::Notable,
                 marker_type: wrldbldr_domain::DmMarkerType::Note,
+                pin,
             },
             timestamp: now,
             game_time: None,
             summary: "DM Marker".to_string(),
-            is_hidden: false,
+            is_hidden: data.hidden,
             tags: Vec::new(),
         };
 
         self.narrative.save_story_event(&event).await?;
-        Ok(Uuid::from(event.id))
+        Ok(event)
+    }
+
+    /// Move a DM marker's pin, or unpin it.
+    ///
+    /// Returns the updated event and the pin it had before.
+    pub async fn pin_marker(
+        &self,
+        event_id: StoryEventId,
+        pin: Option<MarkerPinData>,
+    ) -> Result<(StoryEvent, Option<MarkerPin>), StoryEventError> {
+        let new_pin = pin.as_ref().map(pin_from_protocol).transpose()?;
+        let mut event = self
+            .narrative
+            .get_story_event(event_id)
+            .await?
+            .ok_or(StoryEventError::NotFound)?;
+
+        let StoryEventType::DmMarker { pin, .. } = &mut event.event_type else {
+            return Err(StoryEventError::NotAMarker);
+        };
+        let previous = std::mem::replace(pin, new_pin);
+
+        self.narrative.save_story_event(&event).await?;
+        Ok((event, previous))
+    }
+
+    /// Markers pinned to a location map (`region_id` None) or region backdrop
+    pub async fn list_map_markers(
+        &self,
+        world_id: WorldId,
+        location_id: LocationId,
+        region_id: Option<RegionId>,
+        include_hidden: bool,
+    ) -> Result<Vec<MapMarkerData>, StoryEventError> {
+        let events = self
+            .narrative
+            .list_story_events(world_id, MAP_MARKER_SCAN_LIMIT)
+            .await?;
+
+        Ok(events
+            .iter()
+            .filter(|event| include_hidden || !event.is_hidden)
+            .filter_map(map_marker_to_protocol)
+            .filter(|marker| {
+                marker.pin.location_id == location_id.to_string()
+                    && marker.pin.region_id == region_id.map(|id| id.to_string())
+            })
+            .collect())
     }
 
     pub async fn set_visibility(
         &self,
         event_id: StoryEventId,
         visible: bool,
-    ) -> Result<Value, StoryEventError> {
+    ) -> Result<StoryEvent, StoryEventError> {
         let mut event = self
             .narrative
             .get_story_event(event_id)
@@ -106,7 +161,7 @@ impl StoryEventOps {
             .ok_or(StoryEventError::NotFound)?;
         event.is_hidden = !visible;
         self.narrative.save_story_event(&event).await?;
-        Ok(story_event_to_json(event))
+        Ok(event)
     }
 }
 
@@ -114,11 +169,84 @@ impl StoryEventOps {
 pub enum StoryEventError {
     #[error("Story event not found")]
     NotFound,
+    #[error("Story event is not a DM marker")]
+    NotAMarker,
+    #[error("{0}")]
+    InvalidInput(String),
     #[error("Repository error: {0}")]
     Repo(#[from] RepoError),
 }
 
-fn story_event_to_json(event: wrldbldr_domain::StoryEvent) -> Value {
+fn parse_pin_id(id: &str, field: &str) -> Result<Uuid, StoryEventError> {
+    Uuid::parse_str(id).map_err(|_| StoryEventError::InvalidInput(format!("Invalid {}", field)))
+}
+
+fn pin_from_protocol(pin: &MarkerPinData) -> Result<MarkerPin, StoryEventError> {
+    let location_id = LocationId::from(parse_pin_id(&pin.location_id, "location_id")?);
+    let region_id = pin
+        .region_id
+        .as_deref()
+        .map(|id| parse_pin_id(id, "region_id").map(RegionId::from))
+        .transpose()?;
+
+    let mut marker_pin = MarkerPin::new(location_id, region_id, pin.x, pin.y);
+    if let Some(link) = &pin.link {
+        marker_pin = marker_pin.with_link(match link {
+            MarkerLinkData::Character(id) => {
+                MarkerLink::Character(parse_pin_id(id, "link id")?.into())
+            }
+            MarkerLinkData::Location(id) => {
+                MarkerLink::Location(parse_pin_id(id, "link id")?.into())
+            }
+            MarkerLinkData::Region(id) => MarkerLink::Region(parse_pin_id(id, "link id")?.into()),
+            MarkerLinkData::Item(id) => MarkerLink::Item(parse_pin_id(id, "link id")?.into()),
+            MarkerLinkData::NarrativeEvent(id) => {
+                MarkerLink::NarrativeEvent(parse_pin_id(id, "link id")?.into())
+            }
+        });
+    }
+    Ok(marker_pin)
+}
+
+pub fn pin_to_protocol(pin: &MarkerPin) -> MarkerPinData {
+    MarkerPinData {
+        location_id: pin.location_id.to_string(),
+        region_id: pin.region_id.map(|id| id.to_string()),
+        x: pin.x,
+        y: pin.y,
+        link: pin.link.map(|link| match link {
+            MarkerLink::Character(id) => MarkerLinkData::Character(id.to_string()),
+            MarkerLink::Location(id) => MarkerLinkData::Location(id.to_string()),
+            MarkerLink::Region(id) => MarkerLinkData::Region(id.to_string()),
+            MarkerLink::Item(id) => MarkerLinkData::Item(id.to_string()),
+            MarkerLink::NarrativeEvent(id) => MarkerLinkData::NarrativeEvent(id.to_string()),
+        }),
+    }
+}
+
+/// Wire form of a pinned DM marker; `None` for other events and unpinned markers
+pub fn map_marker_to_protocol(event: &StoryEvent) -> Option<MapMarkerData> {
+    let StoryEventType::DmMarker {
+        title,
+        note,
+        pin: Some(pin),
+        ..
+    } = &event.event_type
+    else {
+        return None;
+    };
+
+    Some(MapMarkerData {
+        id: event.id.to_string(),
+        world_id: event.world_id.to_string(),
+        title: title.clone(),
+        note: note.clone(),
+        pin: pin_to_protocol(pin),
+        visible_to_players: !event.is_hidden,
+    })
+}
+
+pub fn story_event_to_json(event: wrldbldr_domain::StoryEvent) -> Value {
     let event_type = match &event.event_type {
         StoryEventType::LocationChange {
             from_location,
@@ -155,12 +283,14 @@ fn story_event_to_json(event: wrldbldr_domain::StoryEvent) -> Value {
             note,
             importance,
             marker_type,
+            pin,
         } => serde_json::json!({
             "type": "dm_marker",
             "title": title,
             "note": note,
             "importance": format!("{:?}", importance),
             "marker_type": format!("{:?}", marker_type),
+            "pin": pin.as_ref().map(pin_to_protocol),
         }),
 
         StoryEventType::NarrativeEventTriggered {
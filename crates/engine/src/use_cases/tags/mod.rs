@@ -0,0 +1,343 @@
+//! Entity tag use cases.
+//!
+//! DMs put freeform tags ("act-2", "villain-adjacent") on any taggable
+//! entity, filter list requests by them, and save tag combinations they use
+//! often as per-user filter presets. Tags are normalized on the way in, so
+//! filters match regardless of how a tag was typed.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+use wrldbldr_domain::{
+    normalize_tag, normalize_tags, EntityType, SavedFilter, SavedFilterId, WorldId,
+    MAX_SAVED_FILTERS_PER_USER,
+};
+use wrldbldr_protocol::types::{SavedFilterData, TagUsageData};
+use wrldbldr_protocol::SaveFilterData;
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for tag use cases.
+pub struct TagUseCases {
+    pub manage: Arc<ManageTags>,
+    pub filters: Arc<SavedFilters>,
+}
+
+impl TagUseCases {
+    pub fn new(manage: Arc<ManageTags>, filters: Arc<SavedFilters>) -> Self {
+        Self { manage, filters }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TagError {
+    #[error("Saved filter not found")]
+    FilterNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
+
+/// Read, replace, rename and filter by entity tags.
+pub struct ManageTags {
+    tags: Arc<entities::Tag>,
+}
+
+impl ManageTags {
+    pub fn new(tags: Arc<entities::Tag>) -> Self {
+        Self { tags }
+    }
+
+    pub async fn get(
+        &self,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<String>, TagError> {
+        ensure_taggable(entity_type)?;
+        Ok(self.tags.get_tags(entity_type, entity_id).await?)
+    }
+
+    /// Replace an entity's tags, returning them normalized.
+    pub async fn set(
+        &self,
+        world_id: WorldId,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        tags: Vec<String>,
+    ) -> Result<Vec<String>, TagError> {
+        ensure_taggable(entity_type)?;
+        let tags = normalize_tags(&tags).map_err(|e| TagError::Invalid(e.to_string()))?;
+        self.tags
+            .set_tags(world_id, entity_type, entity_id, &tags)
+            .await?;
+        Ok(tags)
+    }
+
+    pub async fn list_world_tags(&self, world_id: WorldId) -> Result<Vec<TagUsageData>, TagError> {
+        Ok(self
+            .tags
+            .list_world_tags(world_id)
+            .await?
+            .into_iter()
+            .map(|usage| TagUsageData {
+                tag: usage.tag,
+                count: usage.count,
+            })
+            .collect())
+    }
+
+    /// Rename a tag across the world; returns how many entities changed.
+    pub async fn rename(&self, world_id: WorldId, from: &str, to: &str) -> Result<u32, TagError> {
+        let from = normalize_tag(from).map_err(|e| TagError::Invalid(e.to_string()))?;
+        let to = normalize_tag(to).map_err(|e| TagError::Invalid(e.to_string()))?;
+        if from == to {
+            return Ok(0);
+        }
+        Ok(self.tags.rename_tag(world_id, &from, &to).await?)
+    }
+
+    /// Remove a tag across the world; returns how many entities changed.
+    pub async fn delete(&self, world_id: WorldId, tag: &str) -> Result<u32, TagError> {
+        let tag = normalize_tag(tag).map_err(|e| TagError::Invalid(e.to_string()))?;
+        Ok(self.tags.delete_tag(world_id, &tag).await?)
+    }
+
+    /// Keep only the items carrying every tag in `tags`. An empty filter
+    /// keeps everything without touching the repository.
+    pub async fn retain_tagged<T>(
+        &self,
+        entity_type: EntityType,
+        tags: &[String],
+        items: &mut Vec<T>,
+        id_of: impl Fn(&T) -> Uuid,
+    ) -> Result<(), TagError> {
+        if tags.is_empty() {
+            return Ok(());
+        }
+        ensure_taggable(entity_type)?;
+        let wanted = normalize_tags(tags).map_err(|e| TagError::Invalid(e.to_string()))?;
+        let ids: Vec<Uuid> = items.iter().map(&id_of).collect();
+        let tagged = self.tags.get_tags_for(entity_type, &ids).await?;
+        items.retain(|item| {
+            tagged
+                .get(&id_of(item))
+                .is_some_and(|entity_tags| wanted.iter().all(|tag| entity_tags.contains(tag)))
+        });
+        Ok(())
+    }
+}
+
+/// Each user's saved tag filters.
+pub struct SavedFilters {
+    tags: Arc<entities::Tag>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl SavedFilters {
+    pub fn new(tags: Arc<entities::Tag>, clock: Arc<dyn ClockPort>) -> Self {
+        Self { tags, clock }
+    }
+
+    pub async fn list(
+        &self,
+        world_id: WorldId,
+        user_id: &str,
+    ) -> Result<Vec<SavedFilterData>, TagError> {
+        Ok(self
+            .tags
+            .list_saved_filters(world_id, user_id)
+            .await?
+            .iter()
+            .map(filter_to_protocol)
+            .collect())
+    }
+
+    /// Create a filter, or replace one of the user's own when `data.filter_id`
+    /// is set.
+    pub async fn save(
+        &self,
+        world_id: WorldId,
+        user_id: &str,
+        data: SaveFilterData,
+    ) -> Result<SavedFilterData, TagError> {
+        let now = self.clock.now();
+        let filter = match data.filter_id.as_deref() {
+            Some(filter_id) => {
+                let mut filter = self.owned_filter(world_id, user_id, filter_id).await?;
+                filter
+                    .update(data.name, data.entity_type, &data.tags, now)
+                    .map_err(|e| TagError::Invalid(e.to_string()))?;
+                filter
+            }
+            None => {
+                let existing = self.tags.list_saved_filters(world_id, user_id).await?;
+                if existing.len() >= MAX_SAVED_FILTERS_PER_USER {
+                    return Err(TagError::Invalid(format!(
+                        "At most {} saved filters are allowed per world",
+                        MAX_SAVED_FILTERS_PER_USER
+                    )));
+                }
+                SavedFilter::new(
+                    world_id,
+                    user_id,
+                    data.name,
+                    data.entity_type,
+                    &data.tags,
+                    now,
+                )
+                .map_err(|e| TagError::Invalid(e.to_string()))?
+            }
+        };
+
+        self.tags.save_saved_filter(&filter).await?;
+        Ok(filter_to_protocol(&filter))
+    }
+
+    pub async fn delete(
+        &self,
+        world_id: WorldId,
+        user_id: &str,
+        filter_id: &str,
+    ) -> Result<(), TagError> {
+        let filter = self.owned_filter(world_id, user_id, filter_id).await?;
+        self.tags.delete_saved_filter(filter.id).await?;
+        Ok(())
+    }
+
+    /// Load a filter, treating other users' filters as missing
+    async fn owned_filter(
+        &self,
+        world_id: WorldId,
+        user_id: &str,
+        filter_id: &str,
+    ) -> Result<SavedFilter, TagError> {
+        let id = Uuid::parse_str(filter_id)
+            .map(SavedFilterId::from_uuid)
+            .map_err(|_| TagError::FilterNotFound)?;
+        self.tags
+            .get_saved_filter(id)
+            .await?
+            .filter(|f| f.world_id == world_id && f.user_id == user_id)
+            .ok_or(TagError::FilterNotFound)
+    }
+}
+
+fn ensure_taggable(entity_type: EntityType) -> Result<(), TagError> {
+    if entity_type.is_taggable() {
+        Ok(())
+    } else {
+        Err(TagError::Invalid(format!(
+            "{} entities can't be tagged",
+            entity_type
+        )))
+    }
+}
+
+fn filter_to_protocol(filter: &SavedFilter) -> SavedFilterData {
+    SavedFilterData {
+        id: filter.id.to_string(),
+        name: filter.name.clone(),
+        entity_type: filter.entity_type,
+        tags: filter.tags.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::MockTagRepo;
+
+    fn manage(repo: MockTagRepo) -> ManageTags {
+        ManageTags::new(Arc::new(entities::Tag::new(Arc::new(repo))))
+    }
+
+    fn filters(repo: MockTagRepo) -> SavedFilters {
+        SavedFilters::new(
+            Arc::new(entities::Tag::new(Arc::new(repo))),
+            Arc::new(FixedClock(chrono::Utc::now())),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_retain_tagged_keeps_entities_with_every_tag() {
+        let (villain, ally, untagged) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut repo = MockTagRepo::new();
+        repo.expect_get_tags()
+            .withf(|entity_type, ids| *entity_type == EntityType::Character && ids.len() == 3)
+            .returning(move |_, _| {
+                Ok(HashMap::from([
+                    (
+                        villain,
+                        vec!["act-2".to_string(), "villain-adjacent".to_string()],
+                    ),
+                    (ally, vec!["act-2".to_string()]),
+                ]))
+            });
+
+        let mut items = vec![villain, ally, untagged];
+        manage(repo)
+            .retain_tagged(
+                EntityType::Character,
+                &["Act 2".to_string(), "villain-adjacent".to_string()],
+                &mut items,
+                |id| *id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(items, vec![villain]);
+    }
+
+    #[tokio::test]
+    async fn test_retain_tagged_without_tags_skips_the_repo() {
+        let mut items = vec![Uuid::new_v4()];
+        manage(MockTagRepo::new())
+            .retain_tagged(EntityType::Character, &[], &mut items, |id| *id)
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_set_rejects_untaggable_types() {
+        let err = manage(MockTagRepo::new())
+            .set(
+                WorldId::new(),
+                EntityType::GameTime,
+                Uuid::new_v4(),
+                vec!["x".to_string()],
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TagError::Invalid(_)));
+    }
+
+    #[tokio::test]
+    async fn test_other_users_filters_are_not_found() {
+        let world_id = WorldId::new();
+        let filter = SavedFilter::new(
+            world_id,
+            "dm-a",
+            "Villains",
+            EntityType::Character,
+            &["villain".to_string()],
+            chrono::Utc::now(),
+        )
+        .unwrap();
+        let filter_id = filter.id.to_string();
+        let mut repo = MockTagRepo::new();
+        repo.expect_get_saved_filter()
+            .returning(move |_| Ok(Some(filter.clone())));
+        repo.expect_delete_saved_filter().never();
+
+        let err = filters(repo)
+            .delete(world_id, "dm-b", &filter_id)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TagError::FilterNotFound));
+    }
+}
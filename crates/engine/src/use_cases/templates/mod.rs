@@ -0,0 +1,815 @@
+//! Entity template use cases.
+//!
+//! DMs save prefabs (an "innkeeper" NPC, a "guard post" region with its items
+//! and the NPCs who work there) and stamp them out with a new name. Template
+//! text uses `{name}` and other `{variable}` placeholders; saving an existing
+//! entity as a template turns its name into `{name}` automatically.
+//!
+//! Region templates create their NPCs with home/work/frequents ties to the new
+//! region, so staging suggests them there like any hand-made NPC.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    CharacterId, EntityTemplate, EntityTemplateId, EntityType, ItemTemplate, LocationId,
+    NpcTemplate, RegionFrequency, RegionId, RegionRelationshipType, RegionShift, RegionTemplate,
+    TemplateBody, TemplateNpc, WorldId, MAX_TEMPLATES_PER_WORLD,
+};
+use wrldbldr_protocol::types::{
+    CreatedEntityData, EntityTemplateData, ItemTemplateData, NpcTemplateData, RegionTemplateData,
+    TemplateBodyData, TemplateNpcData, TemplateNpcRoleData,
+};
+use wrldbldr_protocol::SaveTemplateData;
+
+use crate::entities;
+use crate::entities::inventory::InventoryError;
+use crate::infrastructure::ports::{
+    ClockPort, NpcRegionRelationType, NpcWithRegionInfo, RepoError,
+};
+
+/// Container for entity template use cases.
+pub struct TemplateUseCases {
+    pub manage: Arc<ManageTemplates>,
+    pub instantiate: Arc<InstantiateTemplate>,
+}
+
+impl TemplateUseCases {
+    pub fn new(manage: Arc<ManageTemplates>, instantiate: Arc<InstantiateTemplate>) -> Self {
+        Self {
+            manage,
+            instantiate,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TemplateError {
+    #[error("Template not found")]
+    NotFound,
+    #[error("Character not found")]
+    CharacterNotFound,
+    #[error("Region not found")]
+    RegionNotFound,
+    #[error("Location not found")]
+    LocationNotFound,
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+    #[error("Inventory error: {0}")]
+    Inventory(#[from] InventoryError),
+}
+
+/// Create, edit and delete templates, including saving existing entities.
+pub struct ManageTemplates {
+    templates: Arc<entities::Template>,
+    character: Arc<entities::Character>,
+    location: Arc<entities::Location>,
+    inventory: Arc<entities::Inventory>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ManageTemplates {
+    pub fn new(
+        templates: Arc<entities::Template>,
+        character: Arc<entities::Character>,
+        location: Arc<entities::Location>,
+        inventory: Arc<entities::Inventory>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            templates,
+            character,
+            location,
+            inventory,
+            clock,
+        }
+    }
+
+    pub async fn list(&self, world_id: WorldId) -> Result<Vec<EntityTemplateData>, TemplateError> {
+        let templates = self.templates.list_in_world(world_id).await?;
+        Ok(templates.iter().map(template_to_protocol).collect())
+    }
+
+    pub async fn get(
+        &self,
+        template_id: EntityTemplateId,
+    ) -> Result<EntityTemplateData, TemplateError> {
+        let template = self
+            .templates
+            .get(template_id)
+            .await?
+            .ok_or(TemplateError::NotFound)?;
+        Ok(template_to_protocol(&template))
+    }
+
+    pub async fn create(
+        &self,
+        world_id: WorldId,
+        data: SaveTemplateData,
+    ) -> Result<EntityTemplateData, TemplateError> {
+        let body = body_from_protocol(data.body)?;
+        self.insert(world_id, data.name, data.description, body)
+            .await
+    }
+
+    pub async fn update(
+        &self,
+        template_id: EntityTemplateId,
+        data: SaveTemplateData,
+    ) -> Result<EntityTemplateData, TemplateError> {
+        let mut template = self
+            .templates
+            .get(template_id)
+            .await?
+            .ok_or(TemplateError::NotFound)?;
+        let body = body_from_protocol(data.body)?;
+        template
+            .update(data.name, data.description, body, self.clock.now())
+            .map_err(|e| TemplateError::Invalid(e.to_string()))?;
+        self.templates.save(&template).await?;
+        Ok(template_to_protocol(&template))
+    }
+
+    pub async fn delete(&self, template_id: EntityTemplateId) -> Result<(), TemplateError> {
+        self.templates
+            .get(template_id)
+            .await?
+            .ok_or(TemplateError::NotFound)?;
+        Ok(self.templates.delete(template_id).await?)
+    }
+
+    /// Save an NPC as a template named `name`.
+    pub async fn save_character(
+        &self,
+        character_id: CharacterId,
+        name: String,
+    ) -> Result<EntityTemplateData, TemplateError> {
+        let character = self
+            .character
+            .get(character_id)
+            .await?
+            .ok_or(TemplateError::CharacterNotFound)?;
+        let body = TemplateBody::Npc(npc_from_character(&character)).generalized(&character.name);
+        self.insert(character.world_id, name, None, body).await
+    }
+
+    /// Save a region, the items lying in it and the NPCs tied to it (other
+    /// than those who avoid it) as a template named `name`.
+    pub async fn save_region(
+        &self,
+        region_id: RegionId,
+        name: String,
+    ) -> Result<EntityTemplateData, TemplateError> {
+        let region = self
+            .location
+            .get_region(region_id)
+            .await?
+            .ok_or(TemplateError::RegionNotFound)?;
+        let location = self
+            .location
+            .get(region.location_id)
+            .await?
+            .ok_or(TemplateError::LocationNotFound)?;
+
+        let items = self
+            .inventory
+            .list_in_region(region_id)
+            .await?
+            .into_iter()
+            .map(|item| ItemTemplate {
+                name: item.name,
+                description: item.description,
+                item_type: item.item_type,
+            })
+            .collect();
+
+        let mut npcs = Vec::new();
+        for info in self.character.get_npcs_for_region(region_id).await? {
+            let Some(role) = role_from_info(&info) else {
+                continue;
+            };
+            if let Some(character) = self.character.get(info.character_id).await? {
+                npcs.push(TemplateNpc {
+                    npc: npc_from_character(&character),
+                    role,
+                });
+            }
+        }
+
+        let body = TemplateBody::Region(RegionTemplate {
+            name: region.name.clone(),
+            description: region.description.clone(),
+            atmosphere: region.atmosphere.clone(),
+            backdrop_asset: region.backdrop_asset.clone(),
+            is_spawn_point: region.is_spawn_point,
+            items,
+            npcs,
+        })
+        .generalized(&region.name);
+        self.insert(location.world_id, name, None, body).await
+    }
+
+    async fn insert(
+        &self,
+        world_id: WorldId,
+        name: String,
+        description: Option<String>,
+        body: TemplateBody,
+    ) -> Result<EntityTemplateData, TemplateError> {
+        let existing = self.templates.list_in_world(world_id).await?;
+        if existing.len() >= MAX_TEMPLATES_PER_WORLD {
+            return Err(TemplateError::Invalid(format!(
+                "A world can have at most {} templates",
+                MAX_TEMPLATES_PER_WORLD
+            )));
+        }
+        let template = EntityTemplate::new(world_id, name, description, body, self.clock.now())
+            .map_err(|e| TemplateError::Invalid(e.to_string()))?;
+        self.templates.save(&template).await?;
+        Ok(template_to_protocol(&template))
+    }
+}
+
+/// Stamp out a template into new entities.
+pub struct InstantiateTemplate {
+    templates: Arc<entities::Template>,
+    character: Arc<entities::Character>,
+    location: Arc<entities::Location>,
+    inventory: Arc<entities::Inventory>,
+}
+
+impl InstantiateTemplate {
+    pub fn new(
+        templates: Arc<entities::Template>,
+        character: Arc<entities::Character>,
+        location: Arc<entities::Location>,
+        inventory: Arc<entities::Inventory>,
+    ) -> Self {
+        Self {
+            templates,
+            character,
+            location,
+            inventory,
+        }
+    }
+
+    /// Create the template's entities, the main one (the NPC or region)
+    /// first. Region templates need the location to put the region in.
+    pub async fn execute(
+        &self,
+        template_id: EntityTemplateId,
+        name: &str,
+        variables: &BTreeMap<String, String>,
+        location_id: Option<LocationId>,
+    ) -> Result<Vec<CreatedEntityData>, TemplateError> {
+        let template = self
+            .templates
+            .get(template_id)
+            .await?
+            .ok_or(TemplateError::NotFound)?;
+        let body = template
+            .body
+            .instantiate(name, variables)
+            .map_err(|e| TemplateError::Invalid(e.to_string()))?;
+
+        match body {
+            TemplateBody::Npc(npc) => {
+                let character = self.create_npc(template.world_id, npc).await?;
+                Ok(vec![created(
+                    EntityType::Character,
+                    character.id.to_string(),
+                    character.name,
+                )])
+            }
+            TemplateBody::Region(region_template) => {
+                let location_id = location_id.ok_or_else(|| {
+                    TemplateError::Invalid("Region templates need a location".to_string())
+                })?;
+                let location = self
+                    .location
+                    .get(location_id)
+                    .await?
+                    .filter(|l| l.world_id == template.world_id)
+                    .ok_or(TemplateError::LocationNotFound)?;
+                self.create_region(template.world_id, location.id, region_template)
+                    .await
+            }
+        }
+    }
+
+    async fn create_npc(
+        &self,
+        world_id: WorldId,
+        npc: NpcTemplate,
+    ) -> Result<wrldbldr_domain::Character, TemplateError> {
+        let mut character = wrldbldr_domain::Character::new(world_id, npc.name, npc.archetype);
+        if !npc.description.is_empty() {
+            character = character.with_description(npc.description);
+        }
+        if let Some(sprite) = npc.sprite_asset {
+            character = character.with_sprite(sprite);
+        }
+        if let Some(portrait) = npc.portrait_asset {
+            character = character.with_portrait(portrait);
+        }
+        self.character.save(&character).await?;
+        Ok(character)
+    }
+
+    async fn create_region(
+        &self,
+        world_id: WorldId,
+        location_id: LocationId,
+        template: RegionTemplate,
+    ) -> Result<Vec<CreatedEntityData>, TemplateError> {
+        let mut region = wrldbldr_domain::Region::new(location_id, template.name)
+            .with_description(template.description);
+        if let Some(atmosphere) = template.atmosphere {
+            region = region.with_atmosphere(atmosphere);
+        }
+        if let Some(backdrop) = template.backdrop_asset {
+            region = region.with_backdrop(backdrop);
+        }
+        if template.is_spawn_point {
+            region = region.as_spawn_point();
+        }
+        self.location.save_region(&region).await?;
+
+        let mut created_entities = vec![created(
+            EntityType::Region,
+            region.id.to_string(),
+            region.name.clone(),
+        )];
+
+        for item_template in template.items {
+            let mut item = wrldbldr_domain::Item::new(world_id, item_template.name);
+            if let Some(description) = item_template.description {
+                item = item.with_description(description);
+            }
+            if let Some(item_type) = item_template.item_type {
+                item = item.with_type(item_type);
+            }
+            let item_name = item.name.clone();
+            let item_id = self
+                .inventory
+                .create_and_place_in_region(item, region.id)
+                .await?;
+            created_entities.push(created(EntityType::Item, item_id.to_string(), item_name));
+        }
+
+        for entry in template.npcs {
+            let character = self.create_npc(world_id, entry.npc).await?;
+            self.tie_to_region(character.id, region.id, entry.role)
+                .await?;
+            created_entities.push(created(
+                EntityType::Character,
+                character.id.to_string(),
+                character.name,
+            ));
+        }
+
+        tracing::info!(
+            region_id = %region.id,
+            entities = created_entities.len(),
+            "Region template instantiated"
+        );
+        Ok(created_entities)
+    }
+
+    async fn tie_to_region(
+        &self,
+        character_id: CharacterId,
+        region_id: RegionId,
+        role: RegionRelationshipType,
+    ) -> Result<(), RepoError> {
+        match role {
+            RegionRelationshipType::Home => {
+                self.character
+                    .set_home_region(character_id, region_id)
+                    .await
+            }
+            RegionRelationshipType::WorksAt { shift } => {
+                self.character
+                    .set_work_region(character_id, region_id, Some(shift.to_string()))
+                    .await
+            }
+            RegionRelationshipType::Frequents { frequency } => {
+                self.character
+                    .add_frequents_region(character_id, region_id, frequency.to_string(), None)
+                    .await
+            }
+            RegionRelationshipType::Avoids { reason } => {
+                let reason = (!reason.is_empty()).then_some(reason);
+                self.character
+                    .add_avoids_region(character_id, region_id, reason)
+                    .await
+            }
+        }
+    }
+}
+
+fn created(entity_type: EntityType, entity_id: String, name: String) -> CreatedEntityData {
+    CreatedEntityData {
+        entity_type,
+        entity_id,
+        name,
+    }
+}
+
+fn npc_from_character(character: &wrldbldr_domain::Character) -> NpcTemplate {
+    NpcTemplate {
+        name: character.name.clone(),
+        description: character.description.clone(),
+        archetype: character.current_archetype,
+        sprite_asset: character.sprite_asset.clone(),
+        portrait_asset: character.portrait_asset.clone(),
+    }
+}
+
+/// The template role for an NPC's tie to a region. NPCs who avoid the region
+/// aren't part of it, so they're skipped.
+fn role_from_info(info: &NpcWithRegionInfo) -> Option<RegionRelationshipType> {
+    match info.relationship_type {
+        NpcRegionRelationType::HomeRegion => Some(RegionRelationshipType::Home),
+        NpcRegionRelationType::WorksAt => Some(RegionRelationshipType::WorksAt {
+            shift: info
+                .shift
+                .as_deref()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(RegionShift::Always),
+        }),
+        // "always" has no frequency of its own; it counts as often
+        NpcRegionRelationType::Frequents => Some(RegionRelationshipType::Frequents {
+            frequency: info
+                .frequency
+                .as_deref()
+                .and_then(|f| f.parse().ok())
+                .unwrap_or(RegionFrequency::Often),
+        }),
+        NpcRegionRelationType::Avoids => None,
+    }
+}
+
+fn template_to_protocol(template: &EntityTemplate) -> EntityTemplateData {
+    EntityTemplateData {
+        id: template.id.to_string(),
+        world_id: template.world_id.to_string(),
+        name: template.name.clone(),
+        description: template.description.clone(),
+        body: body_to_protocol(&template.body),
+        variables: template.body.variables(),
+    }
+}
+
+fn body_to_protocol(body: &TemplateBody) -> TemplateBodyData {
+    match body {
+        TemplateBody::Npc(npc) => TemplateBodyData::Npc(npc_to_protocol(npc)),
+        TemplateBody::Region(region) => TemplateBodyData::Region(RegionTemplateData {
+            name: region.name.clone(),
+            description: region.description.clone(),
+            atmosphere: region.atmosphere.clone(),
+            backdrop_asset: region.backdrop_asset.clone(),
+            is_spawn_point: region.is_spawn_point,
+            items: region
+                .items
+                .iter()
+                .map(|item| ItemTemplateData {
+                    name: item.name.clone(),
+                    description: item.description.clone(),
+                    item_type: item.item_type.clone(),
+                })
+                .collect(),
+            npcs: region
+                .npcs
+                .iter()
+                .map(|entry| TemplateNpcData {
+                    npc: npc_to_protocol(&entry.npc),
+                    role: match &entry.role {
+                        RegionRelationshipType::Home => TemplateNpcRoleData::Home,
+                        RegionRelationshipType::WorksAt { shift } => TemplateNpcRoleData::WorksAt {
+                            shift: shift.to_string(),
+                        },
+                        RegionRelationshipType::Frequents { frequency } => {
+                            TemplateNpcRoleData::Frequents {
+                                frequency: frequency.to_string(),
+                            }
+                        }
+                        RegionRelationshipType::Avoids { reason } => TemplateNpcRoleData::Avoids {
+                            reason: reason.clone(),
+                        },
+                    },
+                })
+                .collect(),
+        }),
+    }
+}
+
+fn npc_to_protocol(npc: &NpcTemplate) -> NpcTemplateData {
+    NpcTemplateData {
+        name: npc.name.clone(),
+        description: npc.description.clone(),
+        archetype: npc.archetype.to_string(),
+        sprite_asset: npc.sprite_asset.clone(),
+        portrait_asset: npc.portrait_asset.clone(),
+    }
+}
+
+fn npc_from_protocol(data: NpcTemplateData) -> NpcTemplate {
+    NpcTemplate {
+        name: data.name,
+        description: data.description,
+        // Unrecognized names parse as Unknown
+        archetype: data.archetype.parse().unwrap_or_default(),
+        sprite_asset: data.sprite_asset,
+        portrait_asset: data.portrait_asset,
+    }
+}
+
+fn body_from_protocol(data: TemplateBodyData) -> Result<TemplateBody, TemplateError> {
+    match data {
+        TemplateBodyData::Npc(npc) => Ok(TemplateBody::Npc(npc_from_protocol(npc))),
+        TemplateBodyData::Region(region) => {
+            let npcs = region
+                .npcs
+                .into_iter()
+                .map(|entry| {
+                    Ok(TemplateNpc {
+                        npc: npc_from_protocol(entry.npc),
+                        role: role_from_protocol(entry.role)?,
+                    })
+                })
+                .collect::<Result<_, TemplateError>>()?;
+            Ok(TemplateBody::Region(RegionTemplate {
+                name: region.name,
+                description: region.description,
+                atmosphere: region.atmosphere,
+                backdrop_asset: region.backdrop_asset,
+                is_spawn_point: region.is_spawn_point,
+                items: region
+                    .items
+                    .into_iter()
+                    .map(|item| ItemTemplate {
+                        name: item.name,
+                        description: item.description,
+                        item_type: item.item_type,
+                    })
+                    .collect(),
+                npcs,
+            }))
+        }
+        TemplateBodyData::Unknown => {
+            Err(TemplateError::Invalid("Unknown template kind".to_string()))
+        }
+    }
+}
+
+fn role_from_protocol(data: TemplateNpcRoleData) -> Result<RegionRelationshipType, TemplateError> {
+    let invalid = |e: wrldbldr_domain::DomainError| TemplateError::Invalid(e.to_string());
+    Ok(match data {
+        TemplateNpcRoleData::Home => RegionRelationshipType::Home,
+        TemplateNpcRoleData::WorksAt { shift } => RegionRelationshipType::WorksAt {
+            shift: shift.parse().map_err(invalid)?,
+        },
+        TemplateNpcRoleData::Frequents { frequency } => RegionRelationshipType::Frequents {
+            frequency: frequency.parse().map_err(invalid)?,
+        },
+        TemplateNpcRoleData::Avoids { reason } => RegionRelationshipType::Avoids { reason },
+        TemplateNpcRoleData::Unknown => {
+            return Err(TemplateError::Invalid(
+                "Unknown NPC role in template".to_string(),
+            ))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{
+        MockCharacterRepo, MockItemRepo, MockLocationRepo, MockPlayerCharacterRepo,
+        MockTemplateRepo,
+    };
+    use wrldbldr_domain::CampbellArchetype;
+
+    struct Repos {
+        template: MockTemplateRepo,
+        character: MockCharacterRepo,
+        location: MockLocationRepo,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            Self {
+                template: MockTemplateRepo::new(),
+                character: MockCharacterRepo::new(),
+                location: MockLocationRepo::new(),
+            }
+        }
+
+        fn build(self) -> (ManageTemplates, InstantiateTemplate) {
+            let clock: Arc<dyn ClockPort> = Arc::new(FixedClock(chrono::Utc::now()));
+            let template = Arc::new(entities::Template::new(Arc::new(self.template)));
+            let character_repo: Arc<dyn crate::infrastructure::ports::CharacterRepo> =
+                Arc::new(self.character);
+            let character = Arc::new(entities::Character::new(character_repo.clone()));
+            let location = Arc::new(entities::Location::new(Arc::new(self.location)));
+            let inventory = Arc::new(entities::Inventory::new(
+                Arc::new(MockItemRepo::new()),
+                character_repo,
+                Arc::new(MockPlayerCharacterRepo::new()),
+            ));
+            (
+                ManageTemplates::new(
+                    template.clone(),
+                    character.clone(),
+                    location.clone(),
+                    inventory.clone(),
+                    clock,
+                ),
+                InstantiateTemplate::new(template, character, location, inventory),
+            )
+        }
+    }
+
+    fn npc_template(world_id: WorldId) -> EntityTemplate {
+        EntityTemplate::new(
+            world_id,
+            "Innkeeper",
+            None,
+            TemplateBody::Npc(NpcTemplate {
+                name: "{name}".to_string(),
+                description: "{name} runs the {inn}.".to_string(),
+                archetype: CampbellArchetype::Ally,
+                sprite_asset: None,
+                portrait_asset: None,
+            }),
+            chrono::Utc::now(),
+        )
+        .expect("valid template")
+    }
+
+    #[tokio::test]
+    async fn instantiating_an_npc_template_fills_in_the_name_and_variables() {
+        let world_id = WorldId::new();
+        let template = npc_template(world_id);
+        let template_id = template.id;
+
+        let mut repos = Repos::new();
+        repos
+            .template
+            .expect_get()
+            .returning(move |_| Ok(Some(template.clone())));
+        repos
+            .character
+            .expect_save()
+            .withf(move |c| {
+                c.world_id == world_id
+                    && c.name == "Marta"
+                    && c.description == "Marta runs the Gilded Goose."
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let (_, instantiate) = repos.build();
+
+        let variables = BTreeMap::from([("inn".to_string(), "Gilded Goose".to_string())]);
+        let created = instantiate
+            .execute(template_id, "Marta", &variables, None)
+            .await
+            .expect("instantiated");
+
+        assert_eq!(created.len(), 1);
+        assert_eq!(created[0].entity_type, EntityType::Character);
+        assert_eq!(created[0].name, "Marta");
+    }
+
+    #[tokio::test]
+    async fn instantiating_without_a_required_variable_is_rejected() {
+        let template = npc_template(WorldId::new());
+        let template_id = template.id;
+
+        let mut repos = Repos::new();
+        repos
+            .template
+            .expect_get()
+            .returning(move |_| Ok(Some(template.clone())));
+        repos.character.expect_save().never();
+        let (_, instantiate) = repos.build();
+
+        let result = instantiate
+            .execute(template_id, "Marta", &BTreeMap::new(), None)
+            .await;
+
+        assert!(matches!(result, Err(TemplateError::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn region_templates_need_a_location() {
+        let template = EntityTemplate::new(
+            WorldId::new(),
+            "Guard post",
+            None,
+            TemplateBody::Region(RegionTemplate {
+                name: "{name}".to_string(),
+                description: String::new(),
+                atmosphere: None,
+                backdrop_asset: None,
+                is_spawn_point: false,
+                items: Vec::new(),
+                npcs: Vec::new(),
+            }),
+            chrono::Utc::now(),
+        )
+        .expect("valid template");
+        let template_id = template.id;
+
+        let mut repos = Repos::new();
+        repos
+            .template
+            .expect_get()
+            .returning(move |_| Ok(Some(template.clone())));
+        repos.location.expect_save_region().never();
+        let (_, instantiate) = repos.build();
+
+        let result = instantiate
+            .execute(template_id, "North Gate", &BTreeMap::new(), None)
+            .await;
+
+        assert!(matches!(result, Err(TemplateError::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn saving_a_character_replaces_its_name_with_a_placeholder() {
+        let world_id = WorldId::new();
+        let character = wrldbldr_domain::Character::new(world_id, "Marta", CampbellArchetype::Ally)
+            .with_description("Marta keeps the keys.");
+        let character_id = character.id;
+
+        let mut repos = Repos::new();
+        repos
+            .character
+            .expect_get()
+            .returning(move |_| Ok(Some(character.clone())));
+        repos
+            .template
+            .expect_list_in_world()
+            .returning(|_| Ok(Vec::new()));
+        repos
+            .template
+            .expect_save()
+            .withf(|t| match &t.body {
+                TemplateBody::Npc(npc) => {
+                    npc.name == "{name}" && npc.description == "{name} keeps the keys."
+                }
+                TemplateBody::Region(_) => false,
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let (manage, _) = repos.build();
+
+        let saved = manage
+            .save_character(character_id, "Keyholder".to_string())
+            .await
+            .expect("saved");
+
+        assert_eq!(saved.name, "Keyholder");
+        assert_eq!(saved.world_id, world_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn templates_are_capped_per_world() {
+        let world_id = WorldId::new();
+        let existing: Vec<EntityTemplate> = (0..MAX_TEMPLATES_PER_WORLD)
+            .map(|_| npc_template(world_id))
+            .collect();
+
+        let mut repos = Repos::new();
+        repos
+            .template
+            .expect_list_in_world()
+            .returning(move |_| Ok(existing.clone()));
+        repos.template.expect_save().never();
+        let (manage, _) = repos.build();
+
+        let result = manage
+            .create(
+                world_id,
+                SaveTemplateData {
+                    name: "One too many".to_string(),
+                    description: None,
+                    body: TemplateBodyData::Npc(NpcTemplateData {
+                        name: "{name}".to_string(),
+                        description: String::new(),
+                        archetype: "Ally".to_string(),
+                        sprite_asset: None,
+                        portrait_asset: None,
+                    }),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(TemplateError::Invalid(_))));
+    }
+}
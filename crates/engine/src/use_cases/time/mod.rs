@@ -113,19 +113,66 @@ impl SuggestTime {
             .await?
             .ok_or(SuggestTimeError::WorldNotFound)?;
 
+        let cost_minutes = world.time_config.time_costs.cost_for_action(action_type);
+        Ok(Self::suggest(
+            &world,
+            pc_id,
+            pc_name,
+            action_type,
+            action_description,
+            cost_minutes,
+        ))
+    }
+
+    /// Suggest a set number of minutes passing for an action, such as a
+    /// journey whose length depends on where it goes rather than on the
+    /// action type.
+    pub async fn execute_for_minutes(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        pc_name: String,
+        action_type: &str,
+        action_description: String,
+        minutes: u32,
+    ) -> Result<SuggestTimeResult, SuggestTimeError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(SuggestTimeError::WorldNotFound)?;
+
+        Ok(Self::suggest(
+            &world,
+            pc_id,
+            pc_name,
+            action_type,
+            action_description,
+            minutes,
+        ))
+    }
+
+    fn suggest(
+        world: &wrldbldr_domain::World,
+        pc_id: PlayerCharacterId,
+        pc_name: String,
+        action_type: &str,
+        action_description: String,
+        cost_minutes: u32,
+    ) -> SuggestTimeResult {
+        let world_id = world.id;
         let config = &world.time_config;
-        let cost_minutes = config.time_costs.cost_for_action(action_type);
 
         // If no cost, nothing to do
         if cost_minutes == 0 {
-            return Ok(SuggestTimeResult::NoCost);
+            return SuggestTimeResult::NoCost;
         }
 
         // Check time mode
         match config.mode {
             TimeMode::Manual => {
                 // DM controls time manually, no suggestions
-                Ok(SuggestTimeResult::ManualMode)
+                SuggestTimeResult::ManualMode
             }
             TimeMode::Auto | TimeMode::Suggested => {
                 // Create suggestion for DM approval
@@ -153,7 +200,7 @@ impl SuggestTime {
                     period_change,
                 };
 
-                Ok(SuggestTimeResult::SuggestionCreated(suggestion))
+                SuggestTimeResult::SuggestionCreated(suggestion)
             }
         }
     }
@@ -446,11 +493,18 @@ impl TimeSuggestions {
             &reason,
         );
 
+        let rested_pc_id = matches!(
+            reason,
+            TimeAdvanceReason::RestShort | TimeAdvanceReason::RestLong
+        )
+        .then_some(suggestion.pc_id);
+
         Ok(Some(TimeSuggestionResolution {
             world_id,
             suggestion_id,
             minutes_advanced: minutes_to_advance,
             advance_data,
+            rested_pc_id,
         }))
     }
 }
@@ -461,6 +515,8 @@ pub struct TimeSuggestionResolution {
     pub suggestion_id: Uuid,
     pub minutes_advanced: u32,
     pub advance_data: wrldbldr_protocol::types::TimeAdvanceData,
+    /// The PC who rested, when the approved action was a rest
+    pub rested_pc_id: Option<PlayerCharacterId>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,357 @@
+//! PC-to-PC trade use cases.
+//!
+//! A trade starts as an offer from one PC to another in the same region. The
+//! recipient accepts or declines; the offerer can withdraw it. Accepting moves
+//! items and currency in a single repository transaction and records the
+//! transfers in the story log.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use wrldbldr_domain::{
+    CharacterId, Item, ItemId, PlayerCharacter as DomainPlayerCharacter, PlayerCharacterId,
+    StoryEvent, StoryEventType, TradeId, TradeOffer, TradeSide, WorldId,
+};
+use wrldbldr_protocol::types::{TradeItemData, TradeOfferData, TradeSideData};
+
+use crate::entities::{Narrative, PlayerCharacter, World};
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Container for trade use cases.
+pub struct TradeUseCases {
+    pub trades: Arc<Trades>,
+}
+
+impl TradeUseCases {
+    pub fn new(trades: Arc<Trades>) -> Self {
+        Self { trades }
+    }
+}
+
+/// An open offer with the names needed to show it.
+#[derive(Debug, Clone)]
+pub struct PendingTrade {
+    pub offer: TradeOffer,
+    pub from_pc_name: String,
+    pub to_pc_name: String,
+    pub offered_items: Vec<Item>,
+    pub requested_items: Vec<Item>,
+    pub currency_field: Option<String>,
+}
+
+/// Trade offers and their resolution.
+///
+/// Open offers are held in memory; a restarted engine drops them.
+pub struct Trades {
+    player_character: Arc<PlayerCharacter>,
+    world: Arc<World>,
+    narrative: Arc<Narrative>,
+    clock: Arc<dyn ClockPort>,
+    pending: RwLock<HashMap<TradeId, PendingTrade>>,
+}
+
+impl Trades {
+    pub fn new(
+        player_character: Arc<PlayerCharacter>,
+        world: Arc<World>,
+        narrative: Arc<Narrative>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            player_character,
+            world,
+            narrative,
+            clock,
+            pending: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Open a trade offer from one PC to another.
+    pub async fn offer(
+        &self,
+        from_pc_id: PlayerCharacterId,
+        to_pc_id: PlayerCharacterId,
+        offered: TradeSide,
+        requested: TradeSide,
+    ) -> Result<PendingTrade, TradeError> {
+        let from_pc = self.get_pc(from_pc_id).await?;
+        let to_pc = self.get_pc(to_pc_id).await?;
+
+        if from_pc.world_id != to_pc.world_id {
+            return Err(TradeError::NotTogether);
+        }
+        if from_pc.current_location_id != to_pc.current_location_id
+            || from_pc.current_region_id != to_pc.current_region_id
+        {
+            return Err(TradeError::NotTogether);
+        }
+
+        let currency_field = if offered.currency > 0 || requested.currency > 0 {
+            Some(self.currency_field(from_pc.world_id).await?)
+        } else {
+            None
+        };
+
+        let now = self.clock.now();
+        let offer = TradeOffer::new(
+            from_pc.world_id,
+            from_pc_id,
+            to_pc_id,
+            offered,
+            requested,
+            now,
+        )
+        .map_err(|e| TradeError::Invalid(e.to_string()))?;
+
+        let offered_items = self.held_items(from_pc_id, &offer.offered.item_ids).await?;
+        let requested_items = self.held_items(to_pc_id, &offer.requested.item_ids).await?;
+
+        let pending = PendingTrade {
+            offer,
+            from_pc_name: from_pc.name,
+            to_pc_name: to_pc.name,
+            offered_items,
+            requested_items,
+            currency_field,
+        };
+
+        let mut guard = self.pending.write().await;
+        guard.retain(|_, t| !t.offer.is_expired(now));
+        guard.insert(pending.offer.id, pending.clone());
+
+        tracing::info!(
+            trade_id = %pending.offer.id,
+            from = %from_pc_id,
+            to = %to_pc_id,
+            "Trade offered"
+        );
+        Ok(pending)
+    }
+
+    /// Accept an offer as its recipient and carry out the exchange.
+    pub async fn accept(
+        &self,
+        trade_id: TradeId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<PendingTrade, TradeError> {
+        let trade = self.take(trade_id, pc_id, |o| o.to_pc).await?;
+
+        match self
+            .player_character
+            .execute_trade(&trade.offer, trade.currency_field.clone())
+            .await
+        {
+            Ok(()) => {}
+            Err(RepoError::ConstraintViolation(msg)) => return Err(TradeError::Unavailable(msg)),
+            Err(e) => return Err(e.into()),
+        }
+
+        self.record_story_events(&trade).await;
+        Ok(trade)
+    }
+
+    /// Decline an offer as its recipient.
+    pub async fn decline(
+        &self,
+        trade_id: TradeId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<PendingTrade, TradeError> {
+        self.take(trade_id, pc_id, |o| o.to_pc).await
+    }
+
+    /// Withdraw an offer as the PC who made it.
+    pub async fn cancel(
+        &self,
+        trade_id: TradeId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<PendingTrade, TradeError> {
+        self.take(trade_id, pc_id, |o| o.from_pc).await
+    }
+
+    /// Look up an open offer without resolving it.
+    pub async fn get(&self, trade_id: TradeId) -> Option<PendingTrade> {
+        self.pending.read().await.get(&trade_id).cloned()
+    }
+
+    /// Remove an offer if `pc_id` is the party allowed to resolve it this way.
+    async fn take(
+        &self,
+        trade_id: TradeId,
+        pc_id: PlayerCharacterId,
+        party: impl Fn(&TradeOffer) -> PlayerCharacterId,
+    ) -> Result<PendingTrade, TradeError> {
+        let mut guard = self.pending.write().await;
+        let trade = guard.get(&trade_id).ok_or(TradeError::NotFound)?;
+        if party(&trade.offer) != pc_id {
+            return Err(TradeError::NotParticipant);
+        }
+        let trade = guard.remove(&trade_id).ok_or(TradeError::NotFound)?;
+        if trade.offer.is_expired(self.clock.now()) {
+            return Err(TradeError::Expired);
+        }
+        Ok(trade)
+    }
+
+    async fn get_pc(&self, id: PlayerCharacterId) -> Result<DomainPlayerCharacter, TradeError> {
+        self.player_character
+            .get(id)
+            .await?
+            .ok_or(TradeError::PlayerCharacterNotFound)
+    }
+
+    async fn currency_field(&self, world_id: WorldId) -> Result<String, TradeError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(TradeError::WorldNotFound)?;
+        world
+            .rule_system
+            .variant
+            .currency_field()
+            .map(str::to_string)
+            .ok_or(TradeError::CurrencyUnsupported)
+    }
+
+    /// Resolve `item_ids` against the PC's inventory, failing if any are missing.
+    async fn held_items(
+        &self,
+        pc_id: PlayerCharacterId,
+        item_ids: &[ItemId],
+    ) -> Result<Vec<Item>, TradeError> {
+        if item_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let inventory = self.player_character.get_inventory(pc_id).await?;
+        item_ids
+            .iter()
+            .map(|id| {
+                inventory
+                    .iter()
+                    .find(|item| item.id == *id)
+                    .cloned()
+                    .ok_or(TradeError::ItemNotHeld)
+            })
+            .collect()
+    }
+
+    /// Log each transfer. The trade has already committed, so failures are
+    /// only warned about.
+    async fn record_story_events(&self, trade: &PendingTrade) {
+        let offer = &trade.offer;
+        let legs = [
+            (
+                offer.from_pc,
+                &trade.from_pc_name,
+                offer.to_pc,
+                &trade.to_pc_name,
+                &trade.offered_items,
+                offer.offered.currency,
+            ),
+            (
+                offer.to_pc,
+                &trade.to_pc_name,
+                offer.from_pc,
+                &trade.from_pc_name,
+                &trade.requested_items,
+                offer.requested.currency,
+            ),
+        ];
+
+        let now = self.clock.now();
+        for (giver, giver_name, receiver, receiver_name, items, currency) in legs {
+            let mut transfers: Vec<(String, u32)> =
+                items.iter().map(|item| (item.name.clone(), 1)).collect();
+            if currency > 0 {
+                if let Some(field) = &trade.currency_field {
+                    transfers.push((field.clone(), currency));
+                }
+            }
+
+            for (item_name, quantity) in transfers {
+                let summary = if quantity > 1 {
+                    format!(
+                        "{} traded {} {} to {}",
+                        giver_name, quantity, item_name, receiver_name
+                    )
+                } else {
+                    format!("{} traded {} to {}", giver_name, item_name, receiver_name)
+                };
+                let event = StoryEvent::new(
+                    offer.world_id,
+                    StoryEventType::ItemTransferred {
+                        item_name,
+                        from_character: Some(CharacterId::from(*giver.as_uuid())),
+                        to_character: CharacterId::from(*receiver.as_uuid()),
+                        quantity,
+                        reason: Some("Trade".to_string()),
+                    },
+                    now,
+                )
+                .with_summary(summary)
+                .with_tag("trade");
+
+                if let Err(e) = self.narrative.save_story_event(&event).await {
+                    tracing::warn!(error = %e, trade_id = %offer.id, "Failed to record trade story event");
+                }
+            }
+        }
+    }
+}
+
+pub fn trade_to_protocol(trade: &PendingTrade) -> TradeOfferData {
+    let items = |items: &[Item]| -> Vec<TradeItemData> {
+        items
+            .iter()
+            .map(|item| TradeItemData {
+                item_id: item.id.to_string(),
+                name: item.name.clone(),
+            })
+            .collect()
+    };
+    let offer = &trade.offer;
+    TradeOfferData {
+        trade_id: offer.id.to_string(),
+        from_pc_id: offer.from_pc.to_string(),
+        from_pc_name: trade.from_pc_name.clone(),
+        to_pc_id: offer.to_pc.to_string(),
+        to_pc_name: trade.to_pc_name.clone(),
+        offered: TradeSideData {
+            items: items(&trade.offered_items),
+            currency: offer.offered.currency,
+        },
+        requested: TradeSideData {
+            items: items(&trade.requested_items),
+            currency: offer.requested.currency,
+        },
+        currency_label: trade.currency_field.clone(),
+        expires_at: offer.expires_at().timestamp(),
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TradeError {
+    #[error("Trade offer not found")]
+    NotFound,
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("Both characters must be in the same region to trade")]
+    NotTogether,
+    #[error("Only the other party can do that")]
+    NotParticipant,
+    #[error("Item is not in that character's inventory")]
+    ItemNotHeld,
+    #[error("This game system doesn't track currency")]
+    CurrencyUnsupported,
+    #[error("Trade offer has expired")]
+    Expired,
+    #[error("Invalid trade: {0}")]
+    Invalid(String),
+    #[error("Trade could not be completed: {0}")]
+    Unavailable(String),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
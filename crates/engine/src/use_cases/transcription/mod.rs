@@ -0,0 +1,190 @@
+//! Voice input use cases.
+//!
+//! Players can speak instead of typing. A recorded clip is transcribed into
+//! text for their dialogue field; nothing is sent on their behalf, so they
+//! can fix a misheard word before the NPC hears it.
+
+use std::sync::Arc;
+
+use crate::infrastructure::ports::{TranscriptionError, TranscriptionPort, TranscriptionRequest};
+
+/// Largest clip accepted, about two minutes of compressed speech
+pub const MAX_AUDIO_BYTES: usize = 2 * 1024 * 1024;
+
+/// Formats browsers record and Whisper servers decode
+const AUDIO_TYPES: &[&str] = &[
+    "audio/webm",
+    "audio/ogg",
+    "audio/mp4",
+    "audio/mpeg",
+    "audio/wav",
+    "audio/x-wav",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum TranscribeError {
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Voice input isn't set up on this server")]
+    Unavailable,
+    #[error("Transcription failed: {0}")]
+    Failed(String),
+}
+
+impl From<TranscriptionError> for TranscribeError {
+    fn from(e: TranscriptionError) -> Self {
+        match e {
+            TranscriptionError::Unavailable => Self::Unavailable,
+            TranscriptionError::Failed(message) => Self::Failed(message),
+        }
+    }
+}
+
+/// Container for voice input use cases.
+pub struct TranscriptionUseCases {
+    pub transcribe: Arc<TranscribeAudio>,
+}
+
+impl TranscriptionUseCases {
+    pub fn new(transcribe: Arc<TranscribeAudio>) -> Self {
+        Self { transcribe }
+    }
+}
+
+/// Turn a player's recorded clip into text.
+pub struct TranscribeAudio {
+    transcription: Arc<dyn TranscriptionPort>,
+}
+
+impl TranscribeAudio {
+    pub fn new(transcription: Arc<dyn TranscriptionPort>) -> Self {
+        Self { transcription }
+    }
+
+    /// Transcribe `audio`, expecting speech in the language of `locale`
+    /// (e.g. "es-MX").
+    ///
+    /// Returns an empty string when nothing but silence or noise was heard.
+    pub async fn execute(
+        &self,
+        audio: Vec<u8>,
+        content_type: &str,
+        locale: &str,
+    ) -> Result<String, TranscribeError> {
+        if audio.is_empty() {
+            return Err(TranscribeError::Invalid(
+                "The recording is empty".to_string(),
+            ));
+        }
+        if audio.len() > MAX_AUDIO_BYTES {
+            return Err(TranscribeError::Invalid(format!(
+                "Recordings can be at most {} MB",
+                MAX_AUDIO_BYTES / (1024 * 1024)
+            )));
+        }
+        // Browsers add codec parameters ("audio/webm;codecs=opus")
+        let content_type = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        if !AUDIO_TYPES.contains(&content_type.as_str()) {
+            return Err(TranscribeError::Invalid(format!(
+                "Unsupported audio format: {}",
+                content_type
+            )));
+        }
+
+        let text = self
+            .transcription
+            .transcribe(TranscriptionRequest {
+                audio,
+                content_type,
+                language: language_of(locale),
+            })
+            .await?;
+        Ok(clean_transcript(&text))
+    }
+}
+
+/// The ISO 639 language of a BCP 47 locale ("pt-BR" -> "pt")
+fn language_of(locale: &str) -> Option<String> {
+    let language = locale.split(['-', '_']).next()?.to_ascii_lowercase();
+    let valid =
+        (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_alphabetic());
+    valid.then_some(language)
+}
+
+/// Drop the tags Whisper writes for what isn't speech ("[BLANK_AUDIO]",
+/// "(door creaks)") and the padding around its segments.
+fn clean_transcript(text: &str) -> String {
+    let mut spoken = String::with_capacity(text.len());
+    let mut closing: Option<char> = None;
+    for c in text.chars() {
+        match (closing, c) {
+            (None, '[') => closing = Some(']'),
+            (None, '(') => closing = Some(')'),
+            (None, _) => spoken.push(c),
+            (Some(end), _) if c == end => closing = None,
+            (Some(_), _) => {}
+        }
+    }
+    spoken.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ports::MockTranscriptionPort;
+
+    #[tokio::test]
+    async fn transcribes_in_the_players_language_and_drops_noise_tags() {
+        let mut port = MockTranscriptionPort::new();
+        port.expect_transcribe()
+            .withf(|request| {
+                request.content_type == "audio/webm"
+                    && request.language.as_deref() == Some("es")
+                    && request.audio == vec![1, 2, 3]
+            })
+            .returning(|_| Ok(" [BLANK_AUDIO]\n  ¿Dónde está (tos) el barco? ".to_string()));
+        let transcribe = TranscribeAudio::new(Arc::new(port));
+
+        let text = transcribe
+            .execute(vec![1, 2, 3], "audio/webm;codecs=opus", "es-MX")
+            .await
+            .unwrap();
+
+        assert_eq!(text, "¿Dónde está el barco?");
+    }
+
+    #[tokio::test]
+    async fn rejects_clips_it_cannot_send() {
+        let transcribe = TranscribeAudio::new(Arc::new(MockTranscriptionPort::new()));
+
+        let too_long = vec![0; MAX_AUDIO_BYTES + 1];
+        for (audio, content_type) in [
+            (Vec::new(), "audio/webm"),
+            (too_long, "audio/webm"),
+            (vec![1], "video/webm"),
+        ] {
+            assert!(matches!(
+                transcribe.execute(audio, content_type, "en").await,
+                Err(TranscribeError::Invalid(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn reports_a_missing_server_as_unavailable() {
+        let mut port = MockTranscriptionPort::new();
+        port.expect_transcribe()
+            .returning(|_| Err(TranscriptionError::Unavailable));
+        let transcribe = TranscribeAudio::new(Arc::new(port));
+
+        assert!(matches!(
+            transcribe.execute(vec![1], "audio/ogg", "").await,
+            Err(TranscribeError::Unavailable)
+        ));
+    }
+}
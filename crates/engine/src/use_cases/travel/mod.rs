@@ -0,0 +1,368 @@
+//! Fast travel use cases.
+//!
+//! A PC heads for a location they know and gets there in one step, taking
+//! the hierarchy travel time. If the land being crossed has an encounter
+//! table it is rolled along the way, and the first encounter stops the
+//! journey until the DM lets it go on, waves it through to arrival, or turns
+//! the PC back. Each stretch of road becomes its own time suggestion.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use wrldbldr_domain::{
+    EncounterTable, Journey, JourneyId, LocationId, LocationTree, PlayerCharacterId,
+};
+use wrldbldr_protocol::types::{JourneyData, JourneyDecision, JourneyEncounterData};
+
+use crate::entities::{Location, Observation, PlayerCharacter};
+use crate::infrastructure::ports::{ClockPort, RandomPort, RepoError};
+use crate::use_cases::movement::{EnterRegionResult, ExitLocation, ExitLocationError};
+use crate::use_cases::time::{SuggestTime, SuggestTimeResult, TimeSuggestion};
+
+/// Container for travel use cases.
+pub struct TravelUseCases {
+    pub fast_travel: Arc<FastTravel>,
+}
+
+impl TravelUseCases {
+    pub fn new(fast_travel: Arc<FastTravel>) -> Self {
+        Self { fast_travel }
+    }
+}
+
+/// A journey with the names needed to show it.
+#[derive(Debug, Clone)]
+pub struct PendingJourney {
+    pub journey: Journey,
+    pub pc_name: String,
+    pub from_location_name: String,
+    pub to_location_name: String,
+    /// The encounter table rolled on the way, if the land has one
+    pub table: Option<EncounterTable>,
+}
+
+/// Where a journey got to.
+#[derive(Debug)]
+pub enum JourneyOutcome {
+    /// The PC reached the destination
+    Arrived { arrival: Box<EnterRegionResult> },
+    /// An encounter stopped the PC part-way; the DM decides what happens
+    Interrupted {
+        journey: PendingJourney,
+        time_suggestion: Option<TimeSuggestion>,
+    },
+    /// The PC went back where they started
+    TurnedBack {
+        journey: PendingJourney,
+        time_suggestion: Option<TimeSuggestion>,
+    },
+}
+
+/// Fast travel and the journeys waiting on the DM.
+///
+/// Stopped journeys are held in memory; a restarted engine drops them and
+/// the PC stays where they set out from.
+pub struct FastTravel {
+    player_character: Arc<PlayerCharacter>,
+    location: Arc<Location>,
+    observation: Arc<Observation>,
+    exit_location: Arc<ExitLocation>,
+    suggest_time: Arc<SuggestTime>,
+    random: Arc<dyn RandomPort>,
+    clock: Arc<dyn ClockPort>,
+    pending: RwLock<HashMap<JourneyId, PendingJourney>>,
+}
+
+impl FastTravel {
+    pub fn new(
+        player_character: Arc<PlayerCharacter>,
+        location: Arc<Location>,
+        observation: Arc<Observation>,
+        exit_location: Arc<ExitLocation>,
+        suggest_time: Arc<SuggestTime>,
+        random: Arc<dyn RandomPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            player_character,
+            location,
+            observation,
+            exit_location,
+            suggest_time,
+            random,
+            clock,
+            pending: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Set out for a known location.
+    pub async fn start(
+        &self,
+        pc_id: PlayerCharacterId,
+        to_location_id: LocationId,
+    ) -> Result<JourneyOutcome, TravelError> {
+        let pc = self
+            .player_character
+            .get(pc_id)
+            .await?
+            .ok_or(TravelError::PlayerCharacterNotFound)?;
+        if pc.is_locked() {
+            return Err(TravelError::PlayerCharacterDead);
+        }
+        if pc.current_location_id == to_location_id {
+            return Err(TravelError::AlreadyThere);
+        }
+        if self
+            .pending
+            .read()
+            .await
+            .values()
+            .any(|p| p.journey.pc_id == pc_id)
+        {
+            return Err(TravelError::JourneyInProgress);
+        }
+
+        let locations = self.location.list_in_world(pc.world_id).await?;
+        let tree = LocationTree::new(&locations);
+        let to = tree
+            .get(to_location_id)
+            .ok_or(TravelError::LocationNotFound)?;
+        if !self
+            .observation
+            .knows_location(pc_id, pc.current_location_id, to_location_id)
+            .await?
+        {
+            return Err(TravelError::UnknownLocation);
+        }
+        let travel = tree
+            .travel(pc.current_location_id, to_location_id)
+            .ok_or(TravelError::NoRoute)?;
+
+        let journey = PendingJourney {
+            journey: Journey::new(
+                pc.world_id,
+                pc_id,
+                pc.current_location_id,
+                to_location_id,
+                travel.minutes,
+                self.clock.now(),
+            ),
+            pc_name: pc.name.clone(),
+            from_location_name: tree
+                .get(pc.current_location_id)
+                .map(|l| l.name.clone())
+                .unwrap_or_default(),
+            to_location_name: to.name.clone(),
+            table: travel
+                .via
+                .and_then(|via| encounter_table_on_the_way(&tree, via)),
+        };
+
+        tracing::info!(
+            journey_id = %journey.journey.id,
+            pc_id = %pc_id,
+            to = %to_location_id,
+            minutes = travel.minutes,
+            "Journey started"
+        );
+        self.travel_on(journey, true).await
+    }
+
+    /// Settle a journey stopped by an encounter.
+    pub async fn resolve(
+        &self,
+        journey_id: JourneyId,
+        decision: JourneyDecision,
+    ) -> Result<JourneyOutcome, TravelError> {
+        let journey = self
+            .pending
+            .write()
+            .await
+            .remove(&journey_id)
+            .ok_or(TravelError::NotFound)?;
+
+        let outcome = match decision {
+            JourneyDecision::Continue => self.travel_on(journey.clone(), true).await,
+            JourneyDecision::Arrive => self.travel_on(journey.clone(), false).await,
+            JourneyDecision::TurnBack => {
+                let time_suggestion = self
+                    .suggest_minutes(
+                        &journey,
+                        journey.journey.elapsed_minutes,
+                        format!("Travel back to {}", journey.from_location_name),
+                    )
+                    .await;
+                tracing::info!(journey_id = %journey_id, "Journey turned back");
+                return Ok(JourneyOutcome::TurnedBack {
+                    journey,
+                    time_suggestion,
+                });
+            }
+            JourneyDecision::Unknown => {
+                Err(TravelError::Invalid("Unknown journey decision".to_string()))
+            }
+        };
+
+        // A journey that couldn't go on is still waiting for the DM
+        if outcome.is_err() {
+            self.pending.write().await.insert(journey_id, journey);
+        }
+        outcome
+    }
+
+    /// Look up a stopped journey without settling it.
+    pub async fn get(&self, journey_id: JourneyId) -> Option<PendingJourney> {
+        self.pending.read().await.get(&journey_id).cloned()
+    }
+
+    /// Go on from where the journey stands, rolling for encounters unless
+    /// `roll` is false, until it stops or arrives.
+    async fn travel_on(
+        &self,
+        mut journey: PendingJourney,
+        roll: bool,
+    ) -> Result<JourneyOutcome, TravelError> {
+        let from_minute = journey.journey.elapsed_minutes;
+        let encounter = match (&journey.table, roll) {
+            (Some(table), true) => {
+                table.roll(from_minute, journey.journey.total_minutes, |min, max| {
+                    self.random.gen_range(min, max)
+                })
+            }
+            _ => None,
+        };
+
+        if let Some(encounter) = encounter {
+            let leg = encounter.at_minute.saturating_sub(from_minute);
+            tracing::info!(
+                journey_id = %journey.journey.id,
+                encounter = %encounter.name,
+                at_minute = encounter.at_minute,
+                "Journey interrupted"
+            );
+            journey.journey.interrupt(encounter);
+            let time_suggestion = self
+                .suggest_minutes(
+                    &journey,
+                    leg,
+                    format!("Travel toward {}", journey.to_location_name),
+                )
+                .await;
+            self.pending
+                .write()
+                .await
+                .insert(journey.journey.id, journey.clone());
+            return Ok(JourneyOutcome::Interrupted {
+                journey,
+                time_suggestion,
+            });
+        }
+
+        let leg = journey.journey.remaining_minutes();
+        let mut arrival = self
+            .exit_location
+            .execute(journey.journey.pc_id, journey.journey.to_location_id, None)
+            .await?;
+        // The journey's own length replaces the flat cost of changing location
+        arrival.time_suggestion = self
+            .suggest_minutes(
+                &journey,
+                leg,
+                format!("Travel to {}", journey.to_location_name),
+            )
+            .await;
+        tracing::info!(journey_id = %journey.journey.id, "Journey arrived");
+        Ok(JourneyOutcome::Arrived {
+            arrival: Box::new(arrival),
+        })
+    }
+
+    async fn suggest_minutes(
+        &self,
+        journey: &PendingJourney,
+        minutes: u32,
+        description: String,
+    ) -> Option<TimeSuggestion> {
+        match self
+            .suggest_time
+            .execute_for_minutes(
+                journey.journey.world_id,
+                journey.journey.pc_id,
+                journey.pc_name.clone(),
+                "travel_location",
+                description,
+                minutes,
+            )
+            .await
+        {
+            Ok(SuggestTimeResult::SuggestionCreated(suggestion)) => Some(suggestion),
+            Ok(SuggestTimeResult::NoCost) | Ok(SuggestTimeResult::ManualMode) => None,
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    journey_id = %journey.journey.id,
+                    "Failed to generate time suggestion for journey"
+                );
+                None
+            }
+        }
+    }
+}
+
+/// The encounter table of the land crossed: the innermost location holding
+/// both ends of the journey, or the nearest location around it with a table.
+fn encounter_table_on_the_way(tree: &LocationTree<'_>, via: LocationId) -> Option<EncounterTable> {
+    let mut around: Vec<_> = tree.ancestors(via);
+    around.extend(tree.get(via));
+    around
+        .into_iter()
+        .rev()
+        .find_map(|l| l.encounter_table.clone())
+}
+
+pub fn journey_to_protocol(journey: &PendingJourney) -> JourneyData {
+    let j = &journey.journey;
+    JourneyData {
+        journey_id: j.id.to_string(),
+        pc_id: j.pc_id.to_string(),
+        pc_name: journey.pc_name.clone(),
+        from_location_id: j.from_location_id.to_string(),
+        from_location_name: journey.from_location_name.clone(),
+        to_location_id: j.to_location_id.to_string(),
+        to_location_name: journey.to_location_name.clone(),
+        total_minutes: j.total_minutes,
+        elapsed_minutes: j.elapsed_minutes,
+        encounter: j.encounter.as_ref().map(|e| JourneyEncounterData {
+            at_minute: e.at_minute,
+            name: e.name.clone(),
+            description: e.description.clone(),
+        }),
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TravelError {
+    #[error("Journey not found")]
+    NotFound,
+    #[error("Player character not found")]
+    PlayerCharacterNotFound,
+    #[error("Player character is dead")]
+    PlayerCharacterDead,
+    #[error("Location not found")]
+    LocationNotFound,
+    #[error("Already at that location")]
+    AlreadyThere,
+    #[error("You don't know the way there")]
+    UnknownLocation,
+    #[error("There is no known route between those locations")]
+    NoRoute,
+    #[error("Already on a journey")]
+    JourneyInProgress,
+    #[error("Invalid journey: {0}")]
+    Invalid(String),
+    #[error(transparent)]
+    Exit(#[from] ExitLocationError),
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
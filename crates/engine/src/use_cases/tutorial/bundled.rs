@@ -0,0 +1,145 @@
+//! The bundled tutorial world.
+//!
+//! A small harbor with two regions, one NPC, and one challenge: just enough
+//! to walk somewhere, talk to someone, and make a roll. Every call builds a
+//! fresh copy with new IDs, so each new player gets their own world.
+
+use chrono::{DateTime, Utc};
+use wrldbldr_domain::{
+    CampbellArchetype, Challenge, ChallengeOutcomes, Character, Difficulty, DispositionLevel,
+    Location, LocationType, Region, RegionConnection, TutorialGoal, TutorialScript, TutorialStep,
+    World,
+};
+
+/// Everything that makes up one copy of the tutorial world
+pub struct TutorialWorldContent {
+    pub world: World,
+    pub location: Location,
+    /// Where new PCs arrive
+    pub gate: Region,
+    /// Where the guide NPC waits
+    pub docks: Region,
+    pub connection: RegionConnection,
+    pub guide: Character,
+    pub challenge: Challenge,
+}
+
+/// Build a fresh copy of the harbor tutorial.
+pub fn harbor_tutorial(now: DateTime<Utc>) -> TutorialWorldContent {
+    let world = World::new(
+        "Tutorial: Saltmere Harbor",
+        "A quiet harbor town for learning the ropes. Follow the guide to learn how to move, talk, and roll.",
+        now,
+    );
+
+    let mut location = Location::new(world.id, "Saltmere Harbor", LocationType::Exterior)
+        .with_description("A small fishing harbor of weathered piers and salt-streaked warehouses.")
+        .with_atmosphere("Gulls, tar, and the slap of waves against the pilings")
+        .with_llm_presence(false);
+
+    let gate = Region::new(location.id, "Harbor Gate")
+        .with_description("An archway of old stone opening onto the waterfront.")
+        .as_spawn_point()
+        .with_order(0);
+    let docks = Region::new(location.id, "Dockside")
+        .with_description("Long wooden piers crowded with nets, crates, and moored boats.")
+        .with_order(1);
+    location = location.with_default_region(gate.id);
+
+    let connection = RegionConnection::new(gate.id, docks.id)
+        .expect("gate and docks are different regions")
+        .with_description("A cobbled ramp down to the piers");
+
+    let guide = Character::new(world.id, "Harbormaster Wren", CampbellArchetype::Mentor)
+        .with_description(
+            "A sun-browned woman with a ledger under one arm, who has seen every kind of traveler come through.",
+        )
+        .with_default_disposition(DispositionLevel::Friendly);
+
+    let challenge = Challenge::new(world.id, "Leap to the Ferry", Difficulty::DC(10))
+        .with_description("The ferry is pulling away from the pier. Jump for it!")
+        .with_outcomes(ChallengeOutcomes::simple(
+            "You land on the deck with a thump as the ferry heads out.",
+            "You land in the shallows, soaked but laughing. The ferryman turns back for you.",
+        ))
+        .with_tag("tutorial");
+
+    let script = TutorialScript::new(vec![
+        TutorialStep::new(
+            "walk",
+            "Find your way",
+            "You're standing at the Harbor Gate. Use the navigation panel to walk down to Dockside.",
+            TutorialGoal::EnterRegion { region_id: docks.id },
+        )
+        .with_completion_text(
+            "Moving between regions is how you explore. Someone by the harbor office is waving you over.",
+        ),
+        TutorialStep::new(
+            "talk",
+            "Say hello",
+            "Harbormaster Wren is waiting on the pier. Select her and say something to start a conversation.",
+            TutorialGoal::TalkToNpc { npc_id: guide.id },
+        )
+        .with_completion_text(
+            "In a regular game the DM reviews what NPCs say before you see it, so replies can take a moment.",
+        ),
+        TutorialStep::new(
+            "roll",
+            "Make a roll",
+            "The ferry is leaving! When the challenge appears, roll the dice to leap aboard.",
+            TutorialGoal::RollChallenge {
+                challenge_id: challenge.id,
+            },
+        )
+        .with_completion_text(
+            "Rolls go to the DM, who decides what happens next. Explore, talk, roll: you're ready for a real world!",
+        ),
+    ]);
+
+    TutorialWorldContent {
+        world: world.with_tutorial(script),
+        location,
+        gate,
+        docks,
+        connection,
+        guide,
+        challenge,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wrldbldr_domain::TutorialAction;
+
+    #[test]
+    fn script_goals_point_at_bundled_content() {
+        let content = harbor_tutorial(Utc::now());
+        let script = content.world.tutorial.as_ref().expect("tutorial script");
+
+        let actions = [
+            TutorialAction::EnteredRegion(content.docks.id),
+            TutorialAction::StartedConversation(content.guide.id),
+            TutorialAction::RolledChallenge(content.challenge.id),
+        ];
+        let mut flags = Vec::new();
+        for action in actions {
+            let step = script
+                .step_completed_by(&flags, &action)
+                .expect("action completes the current step");
+            flags.push(step.progress_flag());
+        }
+        assert!(script.current_step(&flags).is_none());
+    }
+
+    #[test]
+    fn each_copy_gets_fresh_ids() {
+        let now = Utc::now();
+        let first = harbor_tutorial(now);
+        let second = harbor_tutorial(now);
+        assert_ne!(first.world.id, second.world.id);
+        assert_ne!(first.docks.id, second.docks.id);
+        assert_eq!(first.location.world_id, first.world.id);
+        assert_eq!(first.challenge.world_id, first.world.id);
+    }
+}
@@ -0,0 +1,246 @@
+//! Tutorial use cases.
+//!
+//! Creates copies of the bundled tutorial world and advances a PC through
+//! the world's tutorial script as they act. Completed steps are stored as
+//! PC-scoped flags, so progress survives reconnects without extra storage.
+
+pub mod bundled;
+
+use std::sync::Arc;
+
+use wrldbldr_domain::{
+    PlayerCharacterId, StagedNpc, Staging, StagingSource, TutorialAction, TutorialScript,
+    TutorialStep, WorldId,
+};
+use wrldbldr_protocol::types::{TutorialStatusData, TutorialStepData};
+
+use crate::entities;
+use crate::infrastructure::ports::{ClockPort, RepoError};
+
+/// Game hours the tutorial's pre-staged NPCs stay put.
+///
+/// World time starts paused, so in practice the staging never expires.
+const TUTORIAL_STAGING_TTL_HOURS: i32 = 24 * 365;
+
+/// Container for tutorial use cases.
+pub struct TutorialUseCases {
+    pub create_world: Arc<CreateTutorialWorld>,
+    pub progress: Arc<TutorialProgress>,
+}
+
+impl TutorialUseCases {
+    pub fn new(create_world: Arc<CreateTutorialWorld>, progress: Arc<TutorialProgress>) -> Self {
+        Self {
+            create_world,
+            progress,
+        }
+    }
+}
+
+/// Where a PC is in a world's tutorial.
+#[derive(Debug, Clone)]
+pub struct TutorialStatus {
+    pub world_id: WorldId,
+    pub pc_id: PlayerCharacterId,
+    pub completed_steps: usize,
+    pub total_steps: usize,
+    /// Step the PC is working on (`None` once finished)
+    pub current_step: Option<TutorialStep>,
+    /// Step the PC just finished, when the status follows an action
+    pub just_completed: Option<TutorialStep>,
+}
+
+impl TutorialStatus {
+    fn new(
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        script: &TutorialScript,
+        pc_flags: &[String],
+    ) -> Self {
+        Self {
+            world_id,
+            pc_id,
+            completed_steps: script.current_index(pc_flags),
+            total_steps: script.steps.len(),
+            current_step: script.current_step(pc_flags).cloned(),
+            just_completed: None,
+        }
+    }
+}
+
+/// Create a new copy of the bundled tutorial world.
+pub struct CreateTutorialWorld {
+    world: Arc<entities::World>,
+    location: Arc<entities::Location>,
+    character: Arc<entities::Character>,
+    challenge: Arc<entities::Challenge>,
+    staging: Arc<entities::Staging>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl CreateTutorialWorld {
+    pub fn new(
+        world: Arc<entities::World>,
+        location: Arc<entities::Location>,
+        character: Arc<entities::Character>,
+        challenge: Arc<entities::Challenge>,
+        staging: Arc<entities::Staging>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            world,
+            location,
+            character,
+            challenge,
+            staging,
+            clock,
+        }
+    }
+
+    /// Save a fresh tutorial world and everything in it.
+    ///
+    /// Both regions are pre-staged, so players never wait on a DM to see
+    /// who is there.
+    pub async fn execute(&self) -> Result<wrldbldr_domain::World, TutorialError> {
+        let now = self.clock.now();
+        let content = bundled::harbor_tutorial(now);
+
+        self.world.save(&content.world).await?;
+        self.location.save_location(&content.location).await?;
+        self.location.save_region(&content.gate).await?;
+        self.location.save_region(&content.docks).await?;
+        self.location.save_connection(&content.connection).await?;
+        self.character.save(&content.guide).await?;
+        self.character
+            .set_work_region(content.guide.id, content.docks.id, None)
+            .await?;
+        self.challenge.save(&content.challenge).await?;
+
+        let game_time = content.world.game_time.current();
+        for (region, npcs) in [
+            (&content.gate, Vec::new()),
+            (
+                &content.docks,
+                vec![StagedNpc::new(
+                    content.guide.id,
+                    content.guide.name.clone(),
+                    true,
+                    "Tutorial guide",
+                )],
+            ),
+        ] {
+            let staging = Staging::new(
+                region.id,
+                content.location.id,
+                content.world.id,
+                game_time,
+                "tutorial",
+                StagingSource::PreStaged,
+                TUTORIAL_STAGING_TTL_HOURS,
+                now,
+            )
+            .with_npcs(npcs);
+            self.staging.save_pending(&staging).await?;
+            self.staging.activate_staging(staging.id, region.id).await?;
+        }
+
+        tracing::info!(world_id = %content.world.id, "Created tutorial world");
+        Ok(content.world)
+    }
+}
+
+/// Track and advance a PC's place in a tutorial script.
+pub struct TutorialProgress {
+    world: Arc<entities::World>,
+    flag: Arc<entities::Flag>,
+}
+
+impl TutorialProgress {
+    pub fn new(world: Arc<entities::World>, flag: Arc<entities::Flag>) -> Self {
+        Self { world, flag }
+    }
+
+    /// Current tutorial status, or `None` if the world has no tutorial.
+    pub async fn status(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+    ) -> Result<Option<TutorialStatus>, TutorialError> {
+        let Some(script) = self.script(world_id).await? else {
+            return Ok(None);
+        };
+        let flags = self.flag.get_pc_flags(pc_id).await?;
+        Ok(Some(TutorialStatus::new(world_id, pc_id, &script, &flags)))
+    }
+
+    /// Record a player action, completing the current step if it matches.
+    ///
+    /// Returns the new status only when a step was completed.
+    pub async fn record(
+        &self,
+        world_id: WorldId,
+        pc_id: PlayerCharacterId,
+        action: TutorialAction,
+    ) -> Result<Option<TutorialStatus>, TutorialError> {
+        let Some(script) = self.script(world_id).await? else {
+            return Ok(None);
+        };
+        let mut flags = self.flag.get_pc_flags(pc_id).await?;
+        let Some(step) = script.step_completed_by(&flags, &action).cloned() else {
+            return Ok(None);
+        };
+
+        let flag = step.progress_flag();
+        self.flag.set_pc_flag(pc_id, &flag).await?;
+        flags.push(flag);
+
+        tracing::debug!(
+            world_id = %world_id,
+            pc_id = %pc_id,
+            step = %step.id,
+            "Tutorial step completed"
+        );
+
+        let mut status = TutorialStatus::new(world_id, pc_id, &script, &flags);
+        status.just_completed = Some(step);
+        Ok(Some(status))
+    }
+
+    async fn script(&self, world_id: WorldId) -> Result<Option<TutorialScript>, TutorialError> {
+        let world = self
+            .world
+            .get(world_id)
+            .await?
+            .ok_or(TutorialError::WorldNotFound)?;
+        Ok(world.tutorial)
+    }
+}
+
+/// Convert a tutorial status to its wire format.
+pub fn status_to_protocol(status: &TutorialStatus) -> TutorialStatusData {
+    TutorialStatusData {
+        world_id: status.world_id.to_string(),
+        pc_id: status.pc_id.to_string(),
+        completed_steps: status.completed_steps as u32,
+        total_steps: status.total_steps as u32,
+        current_step: status.current_step.as_ref().map(step_to_protocol),
+        just_completed: status.just_completed.as_ref().map(step_to_protocol),
+    }
+}
+
+fn step_to_protocol(step: &TutorialStep) -> TutorialStepData {
+    TutorialStepData {
+        id: step.id.clone(),
+        title: step.title.clone(),
+        instructions: step.instructions.clone(),
+        completion_text: step.completion_text.clone(),
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TutorialError {
+    #[error("World not found")]
+    WorldNotFound,
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+}
@@ -0,0 +1,375 @@
+//! Usage accounting use cases.
+//!
+//! Reports each world's LLM tokens, image generations and asset storage,
+//! aggregated per UTC day, and checks the world's optional daily quotas.
+//! Quotas are soft: going over one never blocks generation, it only warns
+//! the DM, once per resource per day.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+use tokio::sync::Mutex;
+use wrldbldr_domain::WorldId;
+use wrldbldr_protocol::ServerMessage;
+
+use crate::entities::{Settings, SettingsError};
+use crate::infrastructure::ports::{ClockPort, DailyUsage, RepoError, UsageRepo};
+
+/// Days covered by a usage report when none are requested
+pub const DEFAULT_REPORT_DAYS: u32 = 30;
+
+/// Longest usage report that can be requested
+pub const MAX_REPORT_DAYS: u32 = 366;
+
+/// Container for usage use cases.
+pub struct UsageUseCases {
+    pub report: Arc<GetUsage>,
+    pub quotas: Arc<CheckUsageQuotas>,
+}
+
+impl UsageUseCases {
+    pub fn new(report: Arc<GetUsage>, quotas: Arc<CheckUsageQuotas>) -> Self {
+        Self { report, quotas }
+    }
+}
+
+/// A resource with a daily quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaResource {
+    Tokens,
+    Images,
+}
+
+impl QuotaResource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            QuotaResource::Tokens => "tokens",
+            QuotaResource::Images => "images",
+        }
+    }
+}
+
+/// A world's daily quotas (None = unlimited)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct UsageQuotas {
+    pub daily_token_quota: Option<u64>,
+    pub daily_image_quota: Option<u64>,
+}
+
+impl UsageQuotas {
+    /// Resources over quota in `usage`, with the amount used and the quota
+    fn exceeded(&self, usage: &DailyUsage) -> Vec<(QuotaResource, u64, u64)> {
+        [
+            (
+                QuotaResource::Tokens,
+                usage.total_tokens(),
+                self.daily_token_quota,
+            ),
+            (
+                QuotaResource::Images,
+                usage.image_generations,
+                self.daily_image_quota,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(resource, used, quota)| {
+            quota
+                .filter(|quota| used > *quota)
+                .map(|quota| (resource, used, quota))
+        })
+        .collect()
+    }
+}
+
+/// Totals across a usage report
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UsageTotals {
+    pub llm_requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub image_generations: u64,
+    pub storage_bytes: u64,
+}
+
+impl UsageTotals {
+    fn add(&mut self, day: &DailyUsage) {
+        self.llm_requests += day.llm_requests;
+        self.prompt_tokens += day.prompt_tokens;
+        self.completion_tokens += day.completion_tokens;
+        self.image_generations += day.image_generations;
+        self.storage_bytes += day.storage_bytes;
+    }
+}
+
+/// A world that went over a daily quota
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QuotaWarning {
+    pub world_id: WorldId,
+    pub resource: QuotaResource,
+    pub used: u64,
+    pub quota: u64,
+}
+
+/// Build the DM notification for a quota warning.
+pub fn quota_warning_message(warning: &QuotaWarning) -> ServerMessage {
+    ServerMessage::UsageQuotaWarning {
+        world_id: warning.world_id.to_string(),
+        resource: warning.resource.as_str().to_string(),
+        used: warning.used,
+        quota: warning.quota,
+    }
+}
+
+/// A world's usage over a range of days
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageReport {
+    pub world_id: WorldId,
+    pub since: NaiveDate,
+    /// Days with any usage, oldest first
+    pub days: Vec<DailyUsage>,
+    pub totals: UsageTotals,
+    pub quotas: UsageQuotas,
+    /// Quotas today's usage is already over
+    pub warnings: Vec<QuotaWarning>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UsageError {
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+    #[error("Settings error: {0}")]
+    Settings(#[from] SettingsError),
+}
+
+async fn quotas_for_world(
+    settings: &Settings,
+    world_id: WorldId,
+) -> Result<UsageQuotas, UsageError> {
+    let settings = settings.get_for_world(world_id).await?;
+    Ok(UsageQuotas {
+        daily_token_quota: settings.daily_token_quota,
+        daily_image_quota: settings.daily_image_quota,
+    })
+}
+
+/// Get a world's usage report.
+pub struct GetUsage {
+    usage: Arc<dyn UsageRepo>,
+    settings: Arc<Settings>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl GetUsage {
+    pub fn new(
+        usage: Arc<dyn UsageRepo>,
+        settings: Arc<Settings>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            usage,
+            settings,
+            clock,
+        }
+    }
+
+    /// Usage for the last `days` days, including today.
+    pub async fn execute(
+        &self,
+        world_id: WorldId,
+        days: Option<u32>,
+    ) -> Result<UsageReport, UsageError> {
+        let days = days
+            .unwrap_or(DEFAULT_REPORT_DAYS)
+            .clamp(1, MAX_REPORT_DAYS);
+        let today = self.clock.now().date_naive();
+        let since = today - Duration::days(i64::from(days) - 1);
+
+        let daily = self.usage.list_daily(world_id, since).await?;
+        let quotas = quotas_for_world(&self.settings, world_id).await?;
+
+        let mut totals = UsageTotals::default();
+        daily.iter().for_each(|day| totals.add(day));
+
+        let warnings = daily
+            .iter()
+            .find(|day| day.day == today)
+            .map(|usage| {
+                quotas
+                    .exceeded(usage)
+                    .into_iter()
+                    .map(|(resource, used, quota)| QuotaWarning {
+                        world_id,
+                        resource,
+                        used,
+                        quota,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(UsageReport {
+            world_id,
+            since,
+            days: daily,
+            totals,
+            quotas,
+            warnings,
+        })
+    }
+}
+
+/// Check every world's usage today against its quotas.
+pub struct CheckUsageQuotas {
+    usage: Arc<dyn UsageRepo>,
+    settings: Arc<Settings>,
+    clock: Arc<dyn ClockPort>,
+    /// Warnings already sent, so each is only sent once per day
+    warned: Mutex<HashSet<(WorldId, NaiveDate, QuotaResource)>>,
+}
+
+impl CheckUsageQuotas {
+    pub fn new(
+        usage: Arc<dyn UsageRepo>,
+        settings: Arc<Settings>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            usage,
+            settings,
+            clock,
+            warned: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Quotas newly exceeded since the last call.
+    pub async fn execute(&self) -> Result<Vec<QuotaWarning>, UsageError> {
+        let today = self.clock.now().date_naive();
+        let usage_today = self.usage.list_for_day(today).await?;
+
+        let mut warned = self.warned.lock().await;
+        warned.retain(|(_, day, _)| *day == today);
+
+        let mut warnings = Vec::new();
+        for (world_id, usage) in usage_today {
+            let quotas = match quotas_for_world(&self.settings, world_id).await {
+                Ok(quotas) => quotas,
+                Err(e) => {
+                    tracing::warn!(error = %e, world_id = %world_id, "Failed to load usage quotas");
+                    continue;
+                }
+            };
+            for (resource, used, quota) in quotas.exceeded(&usage) {
+                if warned.insert((world_id, today, resource)) {
+                    warnings.push(QuotaWarning {
+                        world_id,
+                        resource,
+                        used,
+                        quota,
+                    });
+                }
+            }
+        }
+        Ok(warnings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::ports::{MockSettingsRepo, MockUsageRepo};
+    use chrono::{TimeZone, Utc};
+    use wrldbldr_domain::AppSettings;
+
+    fn usage(day: NaiveDate, tokens: u64, images: u64) -> DailyUsage {
+        DailyUsage {
+            day,
+            llm_requests: 1,
+            prompt_tokens: tokens,
+            completion_tokens: 0,
+            image_generations: images,
+            storage_bytes: 0,
+        }
+    }
+
+    fn settings_with_quotas(tokens: Option<u64>, images: Option<u64>) -> Arc<Settings> {
+        let mut repo = MockSettingsRepo::new();
+        repo.expect_get_for_world().returning(move |_| {
+            Ok(Some(AppSettings {
+                daily_token_quota: tokens,
+                daily_image_quota: images,
+                ..AppSettings::default()
+            }))
+        });
+        Arc::new(Settings::new(Arc::new(repo)))
+    }
+
+    #[tokio::test]
+    async fn quota_warnings_are_sent_once_per_day() {
+        let world_id = WorldId::new();
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        let today = now.date_naive();
+
+        let mut repo = MockUsageRepo::new();
+        repo.expect_list_for_day()
+            .returning(move |day| Ok(vec![(world_id, usage(day, 5_000, 2))]));
+
+        let check = CheckUsageQuotas::new(
+            Arc::new(repo),
+            settings_with_quotas(Some(1_000), Some(10)),
+            Arc::new(FixedClock(now)),
+        );
+
+        let warnings = check.execute().await.unwrap();
+        assert_eq!(
+            warnings,
+            vec![QuotaWarning {
+                world_id,
+                resource: QuotaResource::Tokens,
+                used: 5_000,
+                quota: 1_000,
+            }]
+        );
+        assert!(check.execute().await.unwrap().is_empty());
+        assert!(check
+            .warned
+            .lock()
+            .await
+            .contains(&(world_id, today, QuotaResource::Tokens)));
+    }
+
+    #[tokio::test]
+    async fn report_totals_days_and_flags_todays_overage() {
+        let world_id = WorldId::new();
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 8, 0, 0).unwrap();
+        let today = now.date_naive();
+
+        let mut repo = MockUsageRepo::new();
+        repo.expect_list_daily()
+            .withf(move |_, since| *since == today - Duration::days(6))
+            .returning(move |_, _| {
+                Ok(vec![
+                    usage(today - Duration::days(2), 300, 1),
+                    usage(today, 200, 3),
+                ])
+            });
+
+        let report = GetUsage::new(
+            Arc::new(repo),
+            settings_with_quotas(None, Some(2)),
+            Arc::new(FixedClock(now)),
+        )
+        .execute(world_id, Some(7))
+        .await
+        .unwrap();
+
+        assert_eq!(report.days.len(), 2);
+        assert_eq!(report.totals.prompt_tokens, 500);
+        assert_eq!(report.totals.image_generations, 4);
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.warnings[0].resource, QuotaResource::Images);
+    }
+}
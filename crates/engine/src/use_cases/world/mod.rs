@@ -1,22 +1,35 @@
 //! World management use cases.
 //!
-//! Handles world export and import for backup/sharing.
+//! Handles world export and import for backup/sharing, and branching a
+//! world into an alternate timeline.
 
+use std::collections::HashMap;
 use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
 use wrldbldr_domain::WorldId;
 
 use crate::entities::{Character, Inventory, Location, Narrative, World};
-use crate::infrastructure::ports::RepoError;
+use crate::infrastructure::ports::{ClockPort, RepoError};
 
 /// Container for world use cases.
 pub struct WorldUseCases {
     pub export: Arc<ExportWorld>,
     pub import: Arc<ImportWorld>,
+    pub branch: Arc<BranchWorld>,
 }
 
 impl WorldUseCases {
-    pub fn new(export: Arc<ExportWorld>, import: Arc<ImportWorld>) -> Self {
-        Self { export, import }
+    pub fn new(
+        export: Arc<ExportWorld>,
+        import: Arc<ImportWorld>,
+        branch: Arc<BranchWorld>,
+    ) -> Self {
+        Self {
+            export,
+            import,
+            branch,
+        }
     }
 }
 
@@ -192,6 +205,131 @@ impl ImportWorld {
     }
 }
 
+/// Branch world use case.
+///
+/// Forks a world into an alternate timeline: the world is exported, every
+/// exported entity is given a fresh ID and the copy is imported as a new
+/// world. Nothing is shared afterwards, so changes in the branch never reach
+/// the original.
+pub struct BranchWorld {
+    export: Arc<ExportWorld>,
+    import: Arc<ImportWorld>,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl BranchWorld {
+    pub fn new(
+        export: Arc<ExportWorld>,
+        import: Arc<ImportWorld>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            export,
+            import,
+            clock,
+        }
+    }
+
+    /// Branch a world into a new world with the given name.
+    ///
+    /// # Arguments
+    /// * `world_id` - The world to branch
+    /// * `name` - Name of the branched world
+    ///
+    /// # Returns
+    /// * `Ok(World)` - The branched world
+    /// * `Err(WorldError)` - Branching failed
+    pub async fn execute(
+        &self,
+        world_id: WorldId,
+        name: String,
+    ) -> Result<wrldbldr_domain::World, WorldError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(WorldError::Invalid(
+                "Branch name cannot be empty".to_string(),
+            ));
+        }
+
+        let export = self.export.execute(world_id).await?;
+        let branch = branch_export(export, name, self.clock.now())?;
+        let world = branch.world.clone();
+        self.import.execute(branch).await?;
+
+        tracing::info!(
+            world_id = %world_id,
+            branch_id = %world.id,
+            "Branched world"
+        );
+        Ok(world)
+    }
+}
+
+/// Copy an export under fresh IDs, renamed and marked as a branch.
+///
+/// Every string in the export equal to the ID of an exported entity is
+/// replaced, so references between exported entities follow the copy. IDs of
+/// anything outside the export are left as they are.
+fn branch_export(
+    export: WorldExport,
+    name: &str,
+    now: DateTime<Utc>,
+) -> Result<WorldExport, WorldError> {
+    let source_id = export.world.id;
+
+    let mut remap: HashMap<String, String> = HashMap::new();
+    let ids = std::iter::once(export.world.id.to_string())
+        .chain(export.locations.iter().map(|l| l.id.to_string()))
+        .chain(export.regions.iter().map(|r| r.id.to_string()))
+        .chain(export.characters.iter().map(|c| c.id.to_string()))
+        .chain(export.items.iter().map(|i| i.id.to_string()))
+        .chain(export.narrative_events.iter().map(|e| e.id.to_string()));
+    for id in ids {
+        remap
+            .entry(id)
+            .or_insert_with(|| uuid::Uuid::new_v4().to_string());
+    }
+
+    let mut value =
+        serde_json::to_value(&export).map_err(|e| WorldError::ExportFailed(e.to_string()))?;
+    remap_ids(&mut value, &remap);
+    let mut branch: WorldExport =
+        serde_json::from_value(value).map_err(|e| WorldError::ImportFailed(e.to_string()))?;
+
+    branch.world.name = name.to_string();
+    branch.world.branched_from = Some(source_id);
+    // PCs aren't exported, so the source's party split and spotlight
+    // history don't carry over
+    branch.world.parallel_scenes.clear();
+    branch.world.focused_scene_id = None;
+    branch.world.spotlight.reset();
+    branch.world.created_at = now;
+    branch.world.updated_at = now;
+    Ok(branch)
+}
+
+/// Replace every string in `value` that is a key of `remap`
+fn remap_ids(value: &mut serde_json::Value, remap: &HashMap<String, String>) {
+    match value {
+        serde_json::Value::String(s) => {
+            if let Some(new_id) = remap.get(s.as_str()) {
+                *s = new_id.clone();
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for v in values {
+                remap_ids(v, remap);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for v in map.values_mut() {
+                remap_ids(v, remap);
+            }
+        }
+        _ => {}
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum WorldError {
     #[error("World not found")]
@@ -200,6 +338,60 @@ pub enum WorldError {
     ExportFailed(String),
     #[error("Import failed: {0}")]
     ImportFailed(String),
+    #[error("Invalid: {0}")]
+    Invalid(String),
     #[error("Repository error: {0}")]
     Repo(#[from] RepoError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wrldbldr_domain::{LocationType, Region};
+
+    fn export_with_a_region() -> WorldExport {
+        let now = Utc::now();
+        let world = wrldbldr_domain::World::new("Varn", "desc", now);
+        let location = wrldbldr_domain::Location::new(world.id, "Harbor", LocationType::Exterior);
+        let region = Region::new(location.id, "Docks");
+        WorldExport {
+            world,
+            locations: vec![location],
+            regions: vec![region],
+            characters: vec![],
+            items: vec![],
+            narrative_events: vec![],
+            format_version: 1,
+        }
+    }
+
+    #[test]
+    fn a_branch_gets_fresh_ids_that_still_point_at_each_other() {
+        let export = export_with_a_region();
+        let now = Utc::now();
+
+        let branch = branch_export(export.clone(), "Varn (what if)", now).unwrap();
+
+        assert_ne!(branch.world.id, export.world.id);
+        assert_eq!(branch.world.name, "Varn (what if)");
+        assert_eq!(branch.world.branched_from, Some(export.world.id));
+        assert_eq!(branch.world.created_at, now);
+
+        assert_ne!(branch.locations[0].id, export.locations[0].id);
+        assert_eq!(branch.locations[0].world_id, branch.world.id);
+        assert_eq!(branch.locations[0].name, "Harbor");
+        assert_ne!(branch.regions[0].id, export.regions[0].id);
+        assert_eq!(branch.regions[0].location_id, branch.locations[0].id);
+    }
+
+    #[test]
+    fn ids_outside_the_export_are_left_alone() {
+        let mut export = export_with_a_region();
+        let elsewhere = wrldbldr_domain::LocationId::new();
+        export.regions[0].location_id = elsewhere;
+
+        let branch = branch_export(export, "Elsewhere", Utc::now()).unwrap();
+
+        assert_eq!(branch.regions[0].location_id, elsewhere);
+    }
+}
@@ -66,7 +66,10 @@ pub use world_snapshot::{
 
 // Re-export settings DTOs
 pub use settings::{
-    AppSettings, BatchQueueFailurePolicy, ContextBudgetConfig, SettingsFieldMetadata,
+    AppSettings, BatchQueueFailurePolicy, ConnectionReportData, ContextBudgetConfig,
+    GenerationPreset, LlmProvider, LlmProviderSettings, ModelRouting, ModelTier,
+    NpcAutonomyLevel, ServiceCheckData, ServiceConnectionsData, SettingsFieldMetadata,
+    SetupSaveOutcome, SetupStateData,
 };
 
 // Re-export request DTOs
@@ -90,20 +93,33 @@ pub use player_events::{
     CharacterPosition,
     // Connection
     ConnectedUser,
+    // Crowds
+    CrowdPresenceData,
     // Dialogue
     DialogueChoice,
+    // Dice tray
+    DiceRollData,
     EntityChangedData,
     // Game time
     GameTime,
     GoalData as PlayerEventGoalData,
+    // Region hotspots
+    HotspotData,
     // Interactions & items
     InteractionData,
     JoinError,
+    // Region lighting
+    LightLevelData,
+    // Noise
+    LoudnessData,
+    // Map markers
+    MapMarkerData,
     NarrativeEventSuggestionInfo,
     // Navigation
     NavigationData,
     NavigationExit,
     NavigationTarget,
+    NoiseHeardInData,
     NpcDispositionData,
     // NPCs & staging
     NpcPresenceData,
@@ -111,6 +127,8 @@ pub use player_events::{
     OutcomeBranchData,
     OutcomeDetailData,
     PreviousStagingInfo,
+    // Progress clocks
+    ProgressClockData,
     // Approval & challenges
     ProposedToolInfo,
     RegionData,
@@ -119,6 +137,13 @@ pub use player_events::{
     SceneData,
     SplitPartyLocation,
     StagedNpcInfo,
+    // Tags
+    TagUsageData,
+    // Trades
+    TradeClosedReason,
+    TradeOfferData,
+    // Tutorial
+    TutorialStatusData,
     WaitingPcInfo,
     // Actantial model (from player_events for UI)
     WantData as PlayerEventWantData,
@@ -143,4 +168,83 @@ pub use wrldbldr_protocol::types::{
     LoreSummaryData,
 };
 
+// Re-export content safety types from protocol (same facade pattern)
+pub use wrldbldr_protocol::types::{ContentRatingData, ContentSafetyData, SafetySignalLevelData};
+pub use wrldbldr_protocol::types::{TextDirectionData, WorldTypographyData};
+pub use wrldbldr_protocol::types::{BackdropFrameData, WorldFeaturesData, WorldThemeData};
+pub use wrldbldr_protocol::types::{ScriptHookData, WorldScriptData};
+pub use wrldbldr_protocol::types::{
+    ChallengeSkillRemapData, RuleSystemSwitchData, SheetConversionData, SkillOverrideData,
+};
+pub use wrldbldr_protocol::types::SavedFilterData;
+pub use wrldbldr_protocol::types::{
+    CustomFieldDefinitionData, CustomFieldEntryData, CustomFieldTypeData, CustomFieldValueData,
+};
+pub use wrldbldr_protocol::types::{
+    CreatedEntityData, EntityTemplateData, ItemTemplateData, NpcTemplateData, RegionTemplateData,
+    TemplateBodyData, TemplateNpcData, TemplateNpcRoleData,
+};
+pub use wrldbldr_protocol::types::LibraryEntryData;
+pub use wrldbldr_protocol::types::{
+    ContentDraftData, DiffOpData, DiffSegmentData, DraftDiffData, DraftFieldData,
+    DraftRevisionData, DraftStatusData, RevisionSourceData,
+};
+pub use wrldbldr_protocol::types::{GalleryAssetData, GalleryFilterData};
+pub use wrldbldr_protocol::types::{CrowdData, CrowdInputData};
+pub use wrldbldr_protocol::types::{
+    FactionData, FactionInputData, FactionMemberData, FactionMemberInputData,
+};
+pub use wrldbldr_protocol::types::{
+    DialogueBranchData, DialogueNodeData, DialogueTreeData, DialogueTreeInputData,
+};
+pub use wrldbldr_protocol::types::{FlashbackData, StartFlashbackData};
+pub use wrldbldr_protocol::types::{ParallelSceneData, ParallelSceneInputData};
+pub use wrldbldr_protocol::types::{SpotlightData, SpotlightPcData, SpotlightSettingsData};
+pub use wrldbldr_protocol::types::{
+    PacingCueData, PacingTimerData, PacingTimerKindData, StartPacingTimerData,
+};
+pub use wrldbldr_protocol::types::TranscriptionData;
+pub use wrldbldr_protocol::types::{
+    NpcDraftData, NpcDraftInputData, NpcDraftSourceData, NpcDraftStatusData,
+};
+pub use wrldbldr_protocol::types::{EntityMentionData, MentionTargetData};
+pub use wrldbldr_protocol::types::{
+    GeneratedNamesData, NameGeneratorData, NameGeneratorInputData, NameStyleData,
+};
+pub use wrldbldr_protocol::types::{
+    EconomyOverviewData, MarketCategoryData, MarketItemData, MarketModifierData,
+    MarketModifierInputData, ScarcityData,
+};
+pub use wrldbldr_protocol::types::{
+    PropertyData, PropertyInputData, PropertyStaffData, PropertyStaffInputData, StaffRoleData,
+    UpkeepPaymentData,
+};
+pub use wrldbldr_protocol::types::{CompanionData, CompanionInputData, WagePaymentData};
+pub use wrldbldr_protocol::types::{
+    InjuryCapabilityData, InjuryData, InjuryInputData, InjurySeverityData,
+};
+pub use wrldbldr_protocol::types::{
+    PcDeathData, PcDeathInputData, SuccessorData, SuccessorInputData,
+};
+pub use wrldbldr_protocol::types::{PcSecretData, PcSecretInputData, SecretReactionData};
+pub use wrldbldr_protocol::types::{
+    CorrespondentData, CorrespondentKindData, LetterData, LetterInputData,
+};
+pub use wrldbldr_protocol::types::{BroadsheetArticleData, BroadsheetData, BroadsheetInputData};
+pub use wrldbldr_protocol::types::{SimulationChangeData, WorldSimulationData};
+pub use wrldbldr_protocol::types::{
+    NpcSeedData, SessionZeroData, StartingLocationData, TimeMode as TimeModeData,
+};
+pub use wrldbldr_protocol::types::{WorldMapData, WorldMapPinData, WorldMapPositionData};
+pub use wrldbldr_protocol::types::{
+    EncounterEntryData, EncounterTableData, JourneyData, JourneyDecision, JourneyEncounterData,
+};
+pub use wrldbldr_protocol::types::{
+    ConnectionLockData, LockedWayData, UnlockMethodData, UnlockedConnectionData,
+};
+pub use wrldbldr_protocol::types::{
+    CharacterAgeData, ChronologyIssueData, ChronologyIssueKindData, ChronologyReportData,
+    LifeStageData, LoreDateData,
+};
+
 // NOTE: Infrastructure asset loader now depends inward on these DTOs.
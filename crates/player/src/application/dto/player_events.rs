@@ -7,10 +7,11 @@
 // Re-export all types from the ports layer
 pub use crate::ports::outbound::player_events::{
     ActantialViewData, ChallengeSuggestionInfo, ChallengeSuggestionOutcomes, CharacterData,
-    CharacterPosition, ConnectedUser, DialogueChoice, EntityChangedData, GameTime, GoalData,
-    InteractionData, JoinError, NarrativeEventSuggestionInfo, NavigationData, NavigationExit,
-    NavigationTarget, NpcDispositionData, NpcPresenceData, NpcPresentInfo, OutcomeBranchData,
-    OutcomeDetailData, PlayerEvent, PreviousStagingInfo, ProposedToolInfo, RegionData,
-    RegionItemData, ResponseResult, SceneData, SplitPartyLocation, StagedNpcInfo, WaitingPcInfo,
-    WantData, WantTargetData, WorldRole,
+    CharacterPosition, ConnectedUser, CrowdPresenceData, DialogueChoice, DiceRollData, EntityChangedData, GameTime,
+    GoalData, HotspotData, InteractionData, JoinError, LightLevelData, LoudnessData, MapMarkerData, NarrativeEventSuggestionInfo,
+    NavigationData, NavigationExit, NavigationTarget, NoiseHeardInData, NpcDispositionData, NpcPresenceData,
+    NpcPresentInfo, OutcomeBranchData, OutcomeDetailData, PlayerEvent, PreviousStagingInfo,
+    ProgressClockData, ProposedToolInfo, RegionData, RegionItemData, ResponseResult, SceneData,
+    SplitPartyLocation, StagedNpcInfo, TagUsageData, TradeClosedReason, TradeOfferData, TutorialStatusData, WaitingPcInfo, WantData,
+    WantTargetData, WorldRole,
 };
@@ -3,6 +3,8 @@
 //! This module contains DTOs for Engine application settings.
 //! These types mirror the Engine's AppSettings structure.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -22,6 +24,100 @@ fn default_batch_queue_failure_policy() -> BatchQueueFailurePolicy {
     BatchQueueFailurePolicy::AllOrNothing
 }
 
+/// Model size tier: `Large` uses the dialogue model, `Small` the summarization model
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelTier {
+    Small,
+    Large,
+}
+
+/// Which model tier each kind of LLM work runs on
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ModelRouting {
+    pub npc_dialogue: ModelTier,
+    pub suggestions: ModelTier,
+    pub outcome_suggestions: ModelTier,
+    pub classification: ModelTier,
+}
+
+impl Default for ModelRouting {
+    fn default() -> Self {
+        Self {
+            npc_dialogue: ModelTier::Large,
+            suggestions: ModelTier::Small,
+            outcome_suggestions: ModelTier::Small,
+            classification: ModelTier::Small,
+        }
+    }
+}
+
+/// Service that runs a world's LLM calls
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LlmProvider {
+    /// The Engine's own Ollama server
+    #[default]
+    Ollama,
+    #[serde(rename = "openai")]
+    OpenAi,
+    Anthropic,
+
+    /// Forward-compatibility fallback for newer variants.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Which provider a world's LLM calls go to, and how to reach it
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct LlmProviderSettings {
+    pub provider: LlmProvider,
+    /// Model for calls whose tier has no model set. None = the provider's
+    /// default model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// API key for hosted providers. The Engine sends back a placeholder
+    /// once a key is saved; sending the placeholder keeps the saved key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// API root to use instead of the provider's
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+}
+
+/// A named art style for one asset slot
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GenerationPreset {
+    pub name: String,
+    /// Asset slot, as the Engine names it ("portrait", "itemIcon", ...)
+    pub asset_type: String,
+    /// Appended to every prompt
+    #[serde(default)]
+    pub style_prompt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub negative_prompt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Workflow to run instead of the one requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<String>,
+    /// Used for generations in this slot that don't name a preset
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+impl GenerationPreset {
+    /// Whether this preset is for `asset_type` ("item_icon" and "itemIcon" alike)
+    pub fn applies_to(&self, asset_type: &str) -> bool {
+        let normalize = |s: &str| s.replace('_', "").to_lowercase();
+        normalize(&self.asset_type) == normalize(asset_type)
+    }
+}
+
 /// Token budget configuration for LLM context building
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ContextBudgetConfig {
@@ -68,6 +164,80 @@ impl Default for ContextBudgetConfig {
     }
 }
 
+/// AI co-DM that resolves approvals for solo play, and its guardrails
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct CoDmSettings {
+    /// Resolve approvals with the co-DM instead of waiting for a DM
+    pub enabled: bool,
+    /// Let the co-DM rewrite dialogue; when off it may only accept or reject
+    pub allow_rewrites: bool,
+    /// Let the co-DM approve plugin tool calls
+    pub allow_tools: bool,
+    /// Leave safety-flagged responses for a DM
+    pub escalate_safety_warnings: bool,
+    /// Leave challenge and narrative event suggestions for a DM
+    pub escalate_suggestions: bool,
+    /// Longest dialogue the co-DM may let through. 0 = no limit.
+    pub max_dialogue_chars: usize,
+    /// Table-specific direction for the co-DM
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+}
+
+impl Default for CoDmSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            allow_rewrites: true,
+            allow_tools: false,
+            escalate_safety_warnings: true,
+            escalate_suggestions: true,
+            max_dialogue_chars: 600,
+            instructions: None,
+        }
+    }
+}
+
+/// How much an NPC may say and do without DM approval
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NpcAutonomyLevel {
+    /// Every response waits for the DM
+    #[default]
+    Supervised,
+    /// Plain dialogue goes through; responses with tool calls wait
+    SmallTalk,
+    /// Dialogue and tool calls go through, except always-reviewed tools
+    Trusted,
+
+    /// Forward-compatibility fallback for newer variants.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Which NPC responses go to players without DM approval
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct NpcAutonomySettings {
+    /// Level for NPCs without an override
+    pub default_level: NpcAutonomyLevel,
+    /// Tool calls whose name contains any of these always wait for the DM
+    pub always_review_tools: Vec<String>,
+    /// Per-NPC levels keyed by character ID
+    pub npc_levels: HashMap<String, NpcAutonomyLevel>,
+}
+
+impl Default for NpcAutonomySettings {
+    fn default() -> Self {
+        Self {
+            default_level: NpcAutonomyLevel::Supervised,
+            always_review_tools: vec!["item".to_string(), "grant".to_string(), "give".to_string()],
+            npc_levels: HashMap::new(),
+        }
+    }
+}
+
 /// Application settings from the Engine
 ///
 /// These settings control various aspects of the Engine's behavior,
@@ -89,6 +259,10 @@ pub struct AppSettings {
     #[serde(default = "default_conversation_history_turns")]
     pub conversation_history_turns: usize,
 
+    /// How long (in ms) a line to an NPC waits for other PCs to join in
+    #[serde(default = "default_dialogue_merge_window_ms")]
+    pub dialogue_merge_window_ms: u64,
+
     // ============================================================================
     // Circuit Breaker & Health
     // ============================================================================
@@ -157,6 +331,53 @@ pub struct AppSettings {
     #[serde(default)]
     pub context_budget: ContextBudgetConfig,
 
+    /// Large-tier model, for NPC dialogue by default. None = the Engine's
+    /// configured model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialogue_model: Option<String>,
+
+    /// Cheaper small-tier model, for suggestions and classification by
+    /// default. None = the Engine's configured model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary_model: Option<String>,
+
+    /// Which tier each kind of LLM work runs on
+    #[serde(default)]
+    pub model_routing: ModelRouting,
+
+    /// Provider the world's LLM calls go to
+    #[serde(default)]
+    pub llm_provider: LlmProviderSettings,
+
+    /// Reuse responses to identical non-creative prompts (classification,
+    /// name generation). Global only; read at startup.
+    #[serde(default = "default_llm_cache_enabled")]
+    pub llm_cache_enabled: bool,
+
+    /// How long a cached LLM response stays valid
+    #[serde(default = "default_llm_cache_ttl_secs")]
+    pub llm_cache_ttl_secs: u64,
+
+    /// AI co-DM that resolves approvals for solo play
+    #[serde(default)]
+    pub co_dm: CoDmSettings,
+
+    /// Which NPC responses skip DM approval
+    #[serde(default)]
+    pub npc_autonomy: NpcAutonomySettings,
+
+    // ============================================================================
+    // Usage Quotas
+    // ============================================================================
+    /// Soft daily LLM token quota; the DM is warned once it is exceeded.
+    /// None = no quota.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_token_quota: Option<u64>,
+
+    /// Soft daily image generation quota. None = no quota.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_image_quota: Option<u64>,
+
     // ============================================================================
     // Asset Generation
     // ============================================================================
@@ -165,6 +386,10 @@ pub struct AppSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub style_reference_asset_id: Option<String>,
 
+    /// Named art styles per asset slot, picked when generating
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generation_presets: Vec<GenerationPreset>,
+
     /// Policy for how to handle failures while queueing prompts for a batch.
     #[serde(default = "default_batch_queue_failure_policy")]
     pub batch_queue_failure_policy: BatchQueueFailurePolicy,
@@ -182,9 +407,18 @@ fn default_outcome_branch_max() -> usize {
 fn default_conversation_history_turns() -> usize {
     20
 }
+fn default_dialogue_merge_window_ms() -> u64 {
+    1500
+}
 fn default_suggestion_tokens_per_branch() -> u32 {
     200
 }
+fn default_llm_cache_enabled() -> bool {
+    true
+}
+fn default_llm_cache_ttl_secs() -> u64 {
+    600
+}
 
 impl Default for AppSettings {
     fn default() -> Self {
@@ -192,6 +426,7 @@ impl Default for AppSettings {
             world_id: None,
             max_conversation_turns: 30,
             conversation_history_turns: 20,
+            dialogue_merge_window_ms: 1500,
             circuit_breaker_failure_threshold: 5,
             circuit_breaker_open_duration_secs: 60,
             circuit_breaker_half_open_requests: 1,
@@ -207,7 +442,18 @@ impl Default for AppSettings {
             outcome_branch_max: 4,
             suggestion_tokens_per_branch: 200,
             context_budget: ContextBudgetConfig::default(),
+            dialogue_model: None,
+            summary_model: None,
+            model_routing: ModelRouting::default(),
+            llm_provider: LlmProviderSettings::default(),
+            llm_cache_enabled: default_llm_cache_enabled(),
+            llm_cache_ttl_secs: default_llm_cache_ttl_secs(),
+            co_dm: CoDmSettings::default(),
+            npc_autonomy: NpcAutonomySettings::default(),
+            daily_token_quota: None,
+            daily_image_quota: None,
             style_reference_asset_id: None,
+            generation_presets: Vec::new(),
             batch_queue_failure_policy: default_batch_queue_failure_policy(),
         }
     }
@@ -237,3 +483,68 @@ pub struct SettingsFieldMetadata {
     /// Whether changing this setting requires a restart
     pub requires_restart: bool,
 }
+
+// =============================================================================
+// First-run Setup
+// =============================================================================
+
+/// Engine connections to Neo4j, Ollama, and ComfyUI
+///
+/// Blank fields fall back to the Engine's saved or .env values. The saved
+/// Neo4j password comes back as a placeholder; sending it back unchanged
+/// keeps the saved password.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ServiceConnectionsData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neo4j_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neo4j_user: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neo4j_password: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ollama_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ollama_model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comfyui_url: Option<String>,
+}
+
+/// Result of one live service check
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ServiceCheckData {
+    pub ok: bool,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Live check results for every service
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ConnectionReportData {
+    pub neo4j: ServiceCheckData,
+    pub ollama: ServiceCheckData,
+    pub comfyui: ServiceCheckData,
+}
+
+impl ConnectionReportData {
+    pub fn all_ok(&self) -> bool {
+        self.neo4j.ok && self.ollama.ok && self.comfyui.ok
+    }
+}
+
+/// Setup state reported by the Engine
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SetupStateData {
+    /// True until connections have been saved through setup
+    pub needs_setup: bool,
+    pub connections: ServiceConnectionsData,
+    pub checks: ConnectionReportData,
+}
+
+/// Outcome of saving setup connections
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SetupSaveOutcome {
+    /// False if any check failed; nothing is saved then
+    pub saved: bool,
+    pub connections: ServiceConnectionsData,
+    pub checks: ConnectionReportData,
+}
@@ -6,12 +6,13 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use wrldbldr_protocol::types::{WorldMapData, WorldMapPinData, WorldMapPositionData};
 
 // Import rule system types from domain (canonical source)
 // These have serde derives and are re-exported for player-app consumers
 pub use wrldbldr_domain::value_objects::{
     DiceSystem, RuleSystemConfig, RuleSystemType, RuleSystemVariant, StatDefinition,
-    SuccessComparison,
+    SuccessComparison, WorldFeatures, WorldTheme, WorldTypography,
 };
 
 /// Complete snapshot of a world from the Engine
@@ -128,6 +129,27 @@ impl SessionWorldSnapshot {
     pub fn get_scene(&self, id: &str) -> Option<&SessionSceneData> {
         self.scenes.iter().find(|s| s.id == id)
     }
+
+    /// The world map and the locations pinned to it, by name
+    pub fn world_map(&self) -> WorldMapData {
+        let mut pins: Vec<WorldMapPinData> = self
+            .locations
+            .iter()
+            .filter_map(|l| {
+                Some(WorldMapPinData {
+                    location_id: l.id.clone(),
+                    name: l.name.clone(),
+                    parent_location_id: l.parent_id.clone(),
+                    position: l.world_map_position?,
+                })
+            })
+            .collect();
+        pins.sort_by(|a, b| a.name.cmp(&b.name));
+        WorldMapData {
+            map_asset: self.world.map_asset.clone(),
+            pins,
+        }
+    }
 }
 
 /// World metadata for session snapshots
@@ -137,6 +159,18 @@ pub struct SessionWorldData {
     pub name: String,
     pub description: String,
     pub rule_system: RuleSystemConfig,
+    /// Text direction, language, and font for world text
+    #[serde(default)]
+    pub typography: WorldTypography,
+    /// Accent color and backdrop frame chosen by the DM
+    #[serde(default)]
+    pub theme: WorldTheme,
+    /// Experimental subsystems switched on for this world
+    #[serde(default)]
+    pub features: WorldFeatures,
+    /// Overview map image locations are pinned to
+    #[serde(default)]
+    pub map_asset: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -150,6 +184,9 @@ pub struct SessionLocationData {
     pub location_type: String,
     pub backdrop_asset: Option<String>,
     pub parent_id: Option<String>,
+    /// Where the location is pinned on the world map
+    #[serde(default)]
+    pub world_map_position: Option<WorldMapPositionData>,
 }
 
 /// Character data for session snapshots (simplified)
@@ -524,6 +561,10 @@ pub enum OutcomeTrigger {
         item_name: String,
         item_description: Option<String>,
     },
+    TickClock {
+        clock_id: String,
+        amount: i32,
+    },
     Custom {
         description: String,
     },
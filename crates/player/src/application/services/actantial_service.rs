@@ -190,6 +190,7 @@ impl ActantialService {
             .request_with_timeout(
                 RequestPayload::Want(WantRequest::ListWants {
                     character_id: character_id.to_string(),
+                    tags: Vec::new(),
                 }),
                 get_request_timeout_ms(),
             )
@@ -365,6 +366,7 @@ impl ActantialService {
             .request_with_timeout(
                 RequestPayload::Goal(GoalRequest::ListGoals {
                     world_id: world_id.to_string(),
+                    tags: Vec::new(),
                 }),
                 get_request_timeout_ms(),
             )
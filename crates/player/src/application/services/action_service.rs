@@ -31,6 +31,7 @@ impl ActionService {
             action_type: action.action_type.as_str().to_string(),
             target: action.target,
             dialogue: action.dialogue,
+            choice_id: action.choice_id,
         })
     }
 
@@ -1,8 +1,8 @@
 //! Asset Service - Application service for asset management
 //!
 //! This service provides use case implementations for fetching, generating,
-//! activating, and deleting entity assets. It abstracts away the HTTP client
-//! details from the presentation layer.
+//! uploading, activating, and deleting entity assets. It abstracts away the
+//! HTTP client details from the presentation layer.
 
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +27,7 @@ pub struct GalleryResponse {
 
 /// Request to generate new assets
 #[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GenerateRequest {
     /// World this asset belongs to
     pub world_id: String,
@@ -39,6 +40,56 @@ pub struct GenerateRequest {
     pub count: u8,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub style_reference_id: Option<String>,
+    /// Generation preset to style the images with. None = the slot's default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+}
+
+/// Request to upload an image (e.g. one dropped onto the gallery)
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadRequest {
+    pub asset_type: String,
+    /// Base64-encoded image bytes
+    pub data: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Caption the image with the LLM to improve suggestions
+    pub caption: bool,
+}
+
+/// An entity an uploaded image likely depicts
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssociationSuggestion {
+    pub entity_type: String,
+    pub entity_id: String,
+    pub name: String,
+    pub confidence: f32,
+    pub reason: String,
+}
+
+/// An uploaded image, stored but not yet attached to an entity
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadedImage {
+    pub file_path: String,
+    #[serde(default)]
+    pub caption: Option<String>,
+    pub suggestions: Vec<AssociationSuggestion>,
+}
+
+/// Request to attach an uploaded image to an entity's gallery
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachRequest {
+    pub asset_type: String,
+    pub file_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub set_active: bool,
 }
 
 /// Asset service for managing entity assets
@@ -99,6 +150,28 @@ impl<A: ApiPort> AssetService<A> {
             .await
     }
 
+    /// Upload an image and get suggestions for which entity it depicts
+    pub async fn upload_image(
+        &self,
+        world_id: &str,
+        request: &UploadRequest,
+    ) -> Result<UploadedImage, ApiError> {
+        self.api
+            .post(&format!("/api/worlds/{}/assets/upload", world_id), request)
+            .await
+    }
+
+    /// Attach an uploaded image to an entity's gallery
+    pub async fn attach_upload(
+        &self,
+        entity_type: &str,
+        entity_id: &str,
+        request: &AttachRequest,
+    ) -> Result<(), ApiError> {
+        let path = format!("/api/{}/{}/gallery", entity_type, entity_id);
+        self.api.post_no_response(&path, request).await
+    }
+
     /// Cancel a generation batch
     pub async fn cancel_batch(&self, batch_id: &str) -> Result<(), ApiError> {
         self.api
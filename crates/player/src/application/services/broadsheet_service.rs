@@ -0,0 +1,82 @@
+//! Broadsheet Service - Application service for the world's weekly news
+//!
+//! Each game week the engine drafts a broadsheet from the week's story
+//! events and rumors. The DM edits and publishes it; players read the
+//! published issues.
+
+use crate::application::dto::{BroadsheetData, BroadsheetInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{BroadsheetRequest, RequestPayload};
+
+/// Broadsheet service
+#[derive(Clone)]
+pub struct BroadsheetService {
+    commands: CommandBus,
+}
+
+impl BroadsheetService {
+    /// Create a new BroadsheetService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// The world's issues, newest first; drafts are only listed for the DM
+    pub async fn list_broadsheets(&self) -> Result<Vec<BroadsheetData>, ServiceError> {
+        self.request(BroadsheetRequest::ListBroadsheets).await
+    }
+
+    /// Draft an issue from the news since the last one (DM only)
+    pub async fn generate_broadsheet(&self) -> Result<BroadsheetData, ServiceError> {
+        self.request(BroadsheetRequest::GenerateBroadsheet).await
+    }
+
+    /// Replace a draft's title and articles (DM only)
+    pub async fn update_broadsheet(
+        &self,
+        broadsheet_id: &str,
+        data: BroadsheetInputData,
+    ) -> Result<BroadsheetData, ServiceError> {
+        self.request(BroadsheetRequest::UpdateBroadsheet {
+            broadsheet_id: broadsheet_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Hand a draft to every player (DM only)
+    pub async fn publish_broadsheet(
+        &self,
+        broadsheet_id: &str,
+    ) -> Result<BroadsheetData, ServiceError> {
+        self.request(BroadsheetRequest::PublishBroadsheet {
+            broadsheet_id: broadsheet_id.to_string(),
+        })
+        .await
+    }
+
+    pub async fn delete_broadsheet(
+        &self,
+        broadsheet_id: &str,
+    ) -> Result<BroadsheetData, ServiceError> {
+        self.request(BroadsheetRequest::DeleteBroadsheet {
+            broadsheet_id: broadsheet_id.to_string(),
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: BroadsheetRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Broadsheet(request),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+}
@@ -4,7 +4,7 @@
 //! updating, and managing challenges. It uses WebSocket for real-time
 //! communication with the Engine.
 
-use wrldbldr_protocol::{ChallengeRequest, RequestPayload};
+use wrldbldr_protocol::{ChallengeHistoryData, ChallengeRequest, RequestPayload};
 
 use crate::application::dto::ChallengeData;
 use crate::application::error::{get_request_timeout_ms, ParseResponse, ServiceError};
@@ -34,6 +34,7 @@ impl ChallengeService {
     ) -> Result<Vec<ChallengeData>, ServiceError> {
         let payload = RequestPayload::Challenge(ChallengeRequest::ListChallenges {
             world_id: world_id.to_string(),
+            tags: Vec::new(),
         });
         let response = self
             .commands
@@ -117,6 +118,21 @@ impl ChallengeService {
         response.parse_empty()
     }
 
+    /// Duplicate a challenge, returning the copy
+    pub async fn duplicate_challenge(
+        &self,
+        challenge_id: &str,
+    ) -> Result<ChallengeData, ServiceError> {
+        let payload = RequestPayload::Challenge(ChallengeRequest::DuplicateChallenge {
+            challenge_id: challenge_id.to_string(),
+        });
+        let response = self
+            .commands
+            .request_with_timeout(payload, get_request_timeout_ms())
+            .await?;
+        response.parse()
+    }
+
     /// Toggle challenge favorite status
     ///
     /// Returns the new favorite state after toggling
@@ -150,6 +166,24 @@ impl ChallengeService {
             .await?;
         response.parse_empty()
     }
+
+    /// Get a world's resolved challenges, optionally for one PC
+    pub async fn get_history(
+        &self,
+        world_id: &str,
+        pc_id: Option<&str>,
+    ) -> Result<ChallengeHistoryData, ServiceError> {
+        let payload = RequestPayload::Challenge(ChallengeRequest::GetHistory {
+            world_id: world_id.to_string(),
+            pc_id: pc_id.map(str::to_string),
+            limit: None,
+        });
+        let response = self
+            .commands
+            .request_with_timeout(payload, get_request_timeout_ms())
+            .await?;
+        response.parse()
+    }
 }
 
 #[cfg(test)]
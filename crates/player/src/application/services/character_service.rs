@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::application::dto::requests::{
     ChangeArchetypeRequest, CreateCharacterRequest, UpdateCharacterRequest,
 };
-use crate::application::dto::{CharacterSheetDataApi, InventoryItemData};
+use crate::application::dto::{CharacterSheetDataApi, CustomFieldEntryData, InventoryItemData};
 use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
 use crate::infrastructure::messaging::CommandBus;
 use wrldbldr_protocol::{CharacterRequest, RequestPayload};
@@ -20,6 +20,9 @@ pub struct CharacterSummary {
     pub id: String,
     pub name: String,
     pub archetype: Option<String>,
+    /// Other names the character goes by (only sent to the DM)
+    #[serde(default)]
+    pub aliases: Vec<String>,
 }
 
 /// Full character data for create/edit forms via API
@@ -44,6 +47,9 @@ pub struct CharacterFormData {
     pub portrait_asset: Option<String>,
     #[serde(default)]
     pub sheet_data: Option<CharacterSheetDataApi>,
+    /// DM-defined fields with their values (read-only here; saved separately)
+    #[serde(default, skip_serializing)]
+    pub custom_fields: Vec<CustomFieldEntryData>,
 }
 
 /// Character service for managing characters
@@ -66,12 +72,22 @@ impl CharacterService {
     pub async fn list_characters(
         &self,
         world_id: &str,
+    ) -> Result<Vec<CharacterSummary>, ServiceError> {
+        self.list_tagged_characters(world_id, &[]).await
+    }
+
+    /// List the characters in a world carrying every one of `tags` (DM only)
+    pub async fn list_tagged_characters(
+        &self,
+        world_id: &str,
+        tags: &[String],
     ) -> Result<Vec<CharacterSummary>, ServiceError> {
         let result = self
             .commands
             .request_with_timeout(
                 RequestPayload::Character(CharacterRequest::ListCharacters {
                     world_id: world_id.to_string(),
+                    tags: tags.to_vec(),
                 }),
                 get_request_timeout_ms(),
             )
@@ -165,6 +181,23 @@ impl CharacterService {
         result.parse_empty()
     }
 
+    /// Duplicate a character, returning the copy
+    pub async fn duplicate_character(
+        &self,
+        character_id: &str,
+    ) -> Result<CharacterFormData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Character(CharacterRequest::DuplicateCharacter {
+                    character_id: character_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
     /// Change a character's archetype
     pub async fn change_archetype(
         &self,
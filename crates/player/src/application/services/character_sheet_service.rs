@@ -0,0 +1,137 @@
+//! Character Sheet Service - Application service for schema-driven sheets
+//!
+//! Loads a character's sheet as the game system's schema plus current and
+//! calculated values, and sends single-field edits back to the Engine. The
+//! Engine validates each edit against the system's rules and replies with
+//! any derived values that changed.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+use wrldbldr_domain::{CharacterSheetData, CharacterSheetSchema, FieldValue};
+
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{CharacterSheetRequest, RequestPayload};
+
+/// A character's sheet as returned by `GetSheet`
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct CharacterSheetView {
+    pub character_id: String,
+    pub name: String,
+    /// Schema for the world's game system (None if the Engine has none)
+    #[serde(default)]
+    pub schema: Option<CharacterSheetSchema>,
+    #[serde(default)]
+    pub values: HashMap<String, Value>,
+    #[serde(default)]
+    pub calculated: HashMap<String, Value>,
+}
+
+/// Schema for a world's game system, as returned by `GetWorldSchema`
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct WorldSheetSchema {
+    pub system_id: String,
+    pub schema: CharacterSheetSchema,
+    /// Starting values the system suggests for a new character
+    #[serde(default)]
+    pub defaults: HashMap<String, Value>,
+}
+
+/// Result of a single field update
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct SheetFieldUpdate {
+    pub field_id: String,
+    pub value: Value,
+    /// Derived values recalculated after the update
+    #[serde(default)]
+    pub calculated: HashMap<String, Value>,
+}
+
+/// Pack schema values as sheet data for a new player character
+///
+/// Values with no sheet data equivalent (objects, non-string lists) are
+/// dropped; the game system can recalculate anything derived from them.
+pub fn sheet_data_from_values(values: &HashMap<String, Value>) -> Value {
+    let mut data = CharacterSheetData::new();
+    for (field_id, value) in values {
+        let converted = match value {
+            Value::Bool(b) => Some(FieldValue::Boolean(*b)),
+            Value::Number(n) => n
+                .as_i64()
+                .and_then(|n| i32::try_from(n).ok())
+                .map(FieldValue::Number),
+            Value::String(s) if !s.is_empty() => Some(FieldValue::Text(s.clone())),
+            Value::Array(items) => items
+                .iter()
+                .map(|i| i.as_str().map(str::to_string))
+                .collect::<Option<Vec<_>>>()
+                .map(FieldValue::List),
+            _ => None,
+        };
+        if let Some(converted) = converted {
+            data.set(field_id.clone(), converted);
+        }
+    }
+    serde_json::to_value(data).unwrap_or(Value::Null)
+}
+
+/// Character sheet service
+#[derive(Clone)]
+pub struct CharacterSheetService {
+    commands: CommandBus,
+}
+
+impl CharacterSheetService {
+    /// Create a new CharacterSheetService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Load the schema for a world's game system, before any character exists
+    pub async fn get_world_schema(&self, world_id: &str) -> Result<WorldSheetSchema, ServiceError> {
+        self.request(CharacterSheetRequest::GetWorldSchema {
+            world_id: world_id.to_string(),
+        })
+        .await
+    }
+
+    /// Load a character's sheet with schema and values
+    pub async fn get_sheet(&self, character_id: &str) -> Result<CharacterSheetView, ServiceError> {
+        self.request(CharacterSheetRequest::GetSheet {
+            character_id: character_id.to_string(),
+        })
+        .await
+    }
+
+    /// Update one field; validation failures come back as a server error
+    pub async fn update_field(
+        &self,
+        character_id: &str,
+        field_id: &str,
+        value: Value,
+    ) -> Result<SheetFieldUpdate, ServiceError> {
+        self.request(CharacterSheetRequest::UpdateField {
+            character_id: character_id.to_string(),
+            field_id: field_id.to_string(),
+            value,
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: CharacterSheetRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::CharacterSheet(request),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+}
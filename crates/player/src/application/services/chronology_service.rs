@@ -0,0 +1,70 @@
+//! Chronology Service - Application service for character ages and lore dates
+//!
+//! Reads the chronology report of the world the DM is in (ages on the game
+//! calendar, lore event years and chronology errors) and sets birthdates and
+//! lore years. Every change returns the updated report. All chronology
+//! requests are DM-only.
+
+use crate::application::dto::ChronologyReportData;
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{ChronologyRequest, RequestPayload};
+
+/// Chronology service
+#[derive(Clone)]
+pub struct ChronologyService {
+    commands: CommandBus,
+}
+
+impl ChronologyService {
+    /// Create a new ChronologyService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Ages, lore dates and chronology errors for the current world
+    pub async fn get_chronology(&self) -> Result<ChronologyReportData, ServiceError> {
+        self.request(ChronologyRequest::GetChronology).await
+    }
+
+    /// Set (YYYY-MM-DD) or clear a character's birthdate
+    pub async fn set_birth_date(
+        &self,
+        character_id: &str,
+        birth_date: Option<String>,
+    ) -> Result<ChronologyReportData, ServiceError> {
+        self.request(ChronologyRequest::SetBirthDate {
+            character_id: character_id.to_string(),
+            birth_date,
+        })
+        .await
+    }
+
+    /// Set or clear the year a lore entry's events took place
+    pub async fn set_lore_event_year(
+        &self,
+        lore_id: &str,
+        event_year: Option<i32>,
+    ) -> Result<ChronologyReportData, ServiceError> {
+        self.request(ChronologyRequest::SetLoreEventYear {
+            lore_id: lore_id.to_string(),
+            event_year,
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: ChronologyRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Chronology(request),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+}
@@ -0,0 +1,95 @@
+//! Companion Service - Application service for PC companions
+//!
+//! Lists a PC's companions and hires, changes, dismisses and pays them.
+//! Following companions move with their PC and are staged alongside them;
+//! wages build up per game day like property upkeep. All companion requests
+//! are DM-only.
+
+use crate::application::dto::{CompanionData, CompanionInputData, WagePaymentData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{CompanionRequest, RequestPayload};
+
+/// Companion service
+#[derive(Clone)]
+pub struct CompanionService {
+    commands: CommandBus,
+}
+
+impl CompanionService {
+    /// Create a new CompanionService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// A PC's companions, longest-serving first
+    pub async fn list_companions(&self, pc_id: &str) -> Result<Vec<CompanionData>, ServiceError> {
+        self.request(CompanionRequest::ListCompanions {
+            pc_id: pc_id.to_string(),
+        })
+        .await
+    }
+
+    /// Bind an NPC to a PC; a following companion joins the PC's region
+    pub async fn hire_companion(
+        &self,
+        pc_id: &str,
+        character_id: &str,
+        data: CompanionInputData,
+    ) -> Result<CompanionData, ServiceError> {
+        self.request(CompanionRequest::HireCompanion {
+            pc_id: pc_id.to_string(),
+            character_id: character_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Change a companion's loyalty, wage or whether they follow
+    pub async fn update_companion(
+        &self,
+        companion_id: &str,
+        data: CompanionInputData,
+    ) -> Result<CompanionData, ServiceError> {
+        self.request(CompanionRequest::UpdateCompanion {
+            companion_id: companion_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Release a companion, who stays where they are
+    pub async fn dismiss_companion(&self, companion_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Companion(CompanionRequest::DismissCompanion {
+                    companion_id: companion_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    /// Settle a companion's wages for every whole game day owed
+    pub async fn pay_wages(&self, companion_id: &str) -> Result<WagePaymentData, ServiceError> {
+        self.request(CompanionRequest::PayWages {
+            companion_id: companion_id.to_string(),
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: CompanionRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Companion(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
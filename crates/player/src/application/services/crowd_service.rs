@@ -0,0 +1,101 @@
+//! Crowd Service - Application service for region crowds
+//!
+//! Lists, creates, edits and removes the crowds that fill a region, and moves
+//! them on and off stage. A crowd stands for many unnamed NPCs at once: a
+//! name, an approximate headcount and one collective disposition. All crowd
+//! requests are DM-only.
+
+use crate::application::dto::{CrowdData, CrowdInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{CrowdRequest, RequestPayload};
+
+/// Crowd service
+#[derive(Clone)]
+pub struct CrowdService {
+    commands: CommandBus,
+}
+
+impl CrowdService {
+    /// Create a new CrowdService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Crowds in the current world, or only those in `region_id`
+    pub async fn list_crowds(
+        &self,
+        region_id: Option<&str>,
+    ) -> Result<Vec<CrowdData>, ServiceError> {
+        self.request(CrowdRequest::ListCrowds {
+            region_id: region_id.map(str::to_string),
+        })
+        .await
+    }
+
+    /// Create a crowd in a region
+    pub async fn create_crowd(
+        &self,
+        region_id: &str,
+        data: CrowdInputData,
+    ) -> Result<CrowdData, ServiceError> {
+        self.request(CrowdRequest::CreateCrowd {
+            region_id: region_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Replace a crowd's name, description, count and disposition
+    pub async fn update_crowd(
+        &self,
+        crowd_id: &str,
+        data: CrowdInputData,
+    ) -> Result<CrowdData, ServiceError> {
+        self.request(CrowdRequest::UpdateCrowd {
+            crowd_id: crowd_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Delete a crowd
+    pub async fn delete_crowd(&self, crowd_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Crowd(CrowdRequest::DeleteCrowd {
+                    crowd_id: crowd_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    /// Put a crowd on stage in its region, or take it off
+    pub async fn set_crowd_present(
+        &self,
+        crowd_id: &str,
+        present: bool,
+    ) -> Result<CrowdData, ServiceError> {
+        self.request(CrowdRequest::SetCrowdPresent {
+            crowd_id: crowd_id.to_string(),
+            present,
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: CrowdRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Crowd(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
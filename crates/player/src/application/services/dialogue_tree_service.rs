@@ -0,0 +1,89 @@
+//! Dialogue Tree Service - Application service for scripted dialogue
+//!
+//! Lists, creates, edits and deletes the branching dialogue trees of the
+//! current world. A tree plays when a PC talks to its speaker, or when its
+//! narrative event triggers, in place of an LLM reply. All dialogue tree
+//! requests are DM-only.
+
+use crate::application::dto::{DialogueTreeData, DialogueTreeInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{DialogueTreeRequest, RequestPayload};
+
+/// Dialogue tree service
+#[derive(Clone)]
+pub struct DialogueTreeService {
+    commands: CommandBus,
+}
+
+impl DialogueTreeService {
+    /// Create a new DialogueTreeService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Dialogue trees in the current world, by name
+    pub async fn list_trees(&self) -> Result<Vec<DialogueTreeData>, ServiceError> {
+        self.request(DialogueTreeRequest::ListDialogueTrees).await
+    }
+
+    /// Create a dialogue tree
+    pub async fn create_tree(
+        &self,
+        data: DialogueTreeInputData,
+    ) -> Result<DialogueTreeData, ServiceError> {
+        self.request(DialogueTreeRequest::CreateDialogueTree { data })
+            .await
+    }
+
+    /// Replace a tree's speaker, nodes and settings
+    pub async fn update_tree(
+        &self,
+        tree_id: &str,
+        data: DialogueTreeInputData,
+    ) -> Result<DialogueTreeData, ServiceError> {
+        self.request(DialogueTreeRequest::UpdateDialogueTree {
+            tree_id: tree_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Delete a dialogue tree
+    pub async fn delete_tree(&self, tree_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::DialogueTree(DialogueTreeRequest::DeleteDialogueTree {
+                    tree_id: tree_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    /// Let PCs who already heard a tree hear it again
+    pub async fn reset_tree(&self, tree_id: &str) -> Result<DialogueTreeData, ServiceError> {
+        self.request(DialogueTreeRequest::ResetDialogueTree {
+            tree_id: tree_id.to_string(),
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: DialogueTreeRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::DialogueTree(request),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+}
@@ -0,0 +1,39 @@
+//! Dice Service - Application service for dice tray rolls
+//!
+//! Rolls are always made on the Engine so every client sees the same result.
+//! The roller gets the roll in the response; everyone else in the world
+//! receives it as a `DiceRolled` event.
+
+use crate::application::dto::DiceRollData;
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{DiceRequest, RequestPayload};
+
+/// Dice service
+#[derive(Clone)]
+pub struct DiceService {
+    commands: CommandBus,
+}
+
+impl DiceService {
+    /// Create a new DiceService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Roll a dice expression (e.g. "2d6+1") in a world
+    pub async fn roll(&self, world_id: &str, formula: &str) -> Result<DiceRollData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Dice(DiceRequest::Roll {
+                    world_id: world_id.to_string(),
+                    formula: formula.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+}
@@ -0,0 +1,116 @@
+//! Draft Service - Application service for revision drafts of generated text
+//!
+//! Keeps the generated and edited versions of a character or location text
+//! field as revisions, diffs them, and publishes the chosen revision to the
+//! entity. All draft requests are DM-only.
+
+use crate::application::dto::{
+    ContentDraftData, DraftDiffData, DraftFieldData, RevisionSourceData,
+};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{DraftRequest, EntityType, RequestPayload};
+
+/// Content draft service
+#[derive(Clone)]
+pub struct DraftService {
+    commands: CommandBus,
+}
+
+impl DraftService {
+    /// Create a new DraftService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// An entity's drafts, one per field
+    pub async fn list_drafts(
+        &self,
+        entity_type: EntityType,
+        entity_id: &str,
+    ) -> Result<Vec<ContentDraftData>, ServiceError> {
+        self.request(DraftRequest::ListDrafts {
+            entity_type,
+            entity_id: entity_id.to_string(),
+        })
+        .await
+    }
+
+    /// Add a revision to the entity's draft of `field`
+    pub async fn add_revision(
+        &self,
+        world_id: &str,
+        entity_type: EntityType,
+        entity_id: &str,
+        field: DraftFieldData,
+        text: &str,
+        source: RevisionSourceData,
+    ) -> Result<ContentDraftData, ServiceError> {
+        self.request(DraftRequest::AddDraftRevision {
+            world_id: world_id.to_string(),
+            entity_type,
+            entity_id: entity_id.to_string(),
+            field,
+            text: text.to_string(),
+            source,
+        })
+        .await
+    }
+
+    /// Word-level diff between two revisions
+    pub async fn diff(
+        &self,
+        draft_id: &str,
+        from: u32,
+        to: u32,
+    ) -> Result<DraftDiffData, ServiceError> {
+        self.request(DraftRequest::DiffDraftRevisions {
+            draft_id: draft_id.to_string(),
+            from,
+            to,
+        })
+        .await
+    }
+
+    /// Write a revision's text to the entity
+    pub async fn publish(
+        &self,
+        draft_id: &str,
+        revision: u32,
+    ) -> Result<ContentDraftData, ServiceError> {
+        self.request(DraftRequest::PublishDraftRevision {
+            draft_id: draft_id.to_string(),
+            revision,
+        })
+        .await
+    }
+
+    /// Delete a draft; the entity keeps its current text
+    pub async fn discard(&self, draft_id: &str) -> Result<(), ServiceError> {
+        self.request_empty(DraftRequest::DiscardDraft {
+            draft_id: draft_id.to_string(),
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: DraftRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Draft(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+
+    async fn request_empty(&self, request: DraftRequest) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Draft(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse_empty()
+    }
+}
@@ -0,0 +1,107 @@
+//! Economy Service - Application service for regional prices
+//!
+//! Lists, creates, edits and removes the market modifiers of the DM's
+//! world, sets item base prices, and fetches a location's economy overview:
+//! scarcity and local prices per item type. A modifier can wait for a world
+//! flag, so events change prices by setting flags. All economy requests are
+//! DM-only.
+
+use crate::application::dto::{EconomyOverviewData, MarketModifierData, MarketModifierInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{EconomyRequest, RequestPayload};
+
+/// Economy service
+#[derive(Clone)]
+pub struct EconomyService {
+    commands: CommandBus,
+}
+
+impl EconomyService {
+    /// Create a new EconomyService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Market modifiers in the current world, oldest first
+    pub async fn list_modifiers(&self) -> Result<Vec<MarketModifierData>, ServiceError> {
+        self.request(EconomyRequest::ListMarketModifiers).await
+    }
+
+    /// Create a market modifier
+    pub async fn create_modifier(
+        &self,
+        data: MarketModifierInputData,
+    ) -> Result<MarketModifierData, ServiceError> {
+        self.request(EconomyRequest::CreateMarketModifier { data })
+            .await
+    }
+
+    /// Replace everything about a market modifier
+    pub async fn update_modifier(
+        &self,
+        modifier_id: &str,
+        data: MarketModifierInputData,
+    ) -> Result<MarketModifierData, ServiceError> {
+        self.request(EconomyRequest::UpdateMarketModifier {
+            modifier_id: modifier_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Delete a market modifier
+    pub async fn delete_modifier(&self, modifier_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Economy(EconomyRequest::DeleteMarketModifier {
+                    modifier_id: modifier_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    /// Scarcity and local prices per item type at a location
+    pub async fn overview(&self, location_id: &str) -> Result<EconomyOverviewData, ServiceError> {
+        self.request(EconomyRequest::GetEconomyOverview {
+            location_id: location_id.to_string(),
+        })
+        .await
+    }
+
+    /// Set an item's base price; `None` takes it off sale
+    pub async fn set_base_price(
+        &self,
+        item_id: &str,
+        base_price: Option<u32>,
+    ) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Economy(EconomyRequest::SetItemBasePrice {
+                    item_id: item_id.to_string(),
+                    base_price,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: EconomyRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Economy(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
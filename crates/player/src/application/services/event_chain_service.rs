@@ -152,6 +152,7 @@ impl EventChainService {
             .request_with_timeout(
                 RequestPayload::EventChain(EventChainRequest::ListEventChains {
                     world_id: world_id.to_string(),
+                    tags: Vec::new(),
                 }),
                 get_request_timeout_ms(),
             )
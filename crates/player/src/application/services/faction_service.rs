@@ -0,0 +1,131 @@
+//! Faction Service - Application service for factions
+//!
+//! Lists, creates, edits and deletes the factions of the current world, and
+//! sets their members, reputation with the party and rivalries. A faction's
+//! standing shapes how its members are staged and voiced. All faction
+//! requests are DM-only.
+
+use crate::application::dto::{FactionData, FactionInputData, FactionMemberInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{FactionRequest, RequestPayload};
+
+/// Faction service
+#[derive(Clone)]
+pub struct FactionService {
+    commands: CommandBus,
+}
+
+impl FactionService {
+    /// Create a new FactionService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Factions in the current world, by name
+    pub async fn list_factions(&self) -> Result<Vec<FactionData>, ServiceError> {
+        self.request(FactionRequest::ListFactions).await
+    }
+
+    /// Create a faction
+    pub async fn create_faction(
+        &self,
+        data: FactionInputData,
+    ) -> Result<FactionData, ServiceError> {
+        self.request(FactionRequest::CreateFaction { data }).await
+    }
+
+    /// Replace a faction's name, description and goals
+    pub async fn update_faction(
+        &self,
+        faction_id: &str,
+        data: FactionInputData,
+    ) -> Result<FactionData, ServiceError> {
+        self.request(FactionRequest::UpdateFaction {
+            faction_id: faction_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Delete a faction; its rivals forget the rivalry
+    pub async fn delete_faction(&self, faction_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Faction(FactionRequest::DeleteFaction {
+                    faction_id: faction_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    /// Replace a faction's members
+    pub async fn set_members(
+        &self,
+        faction_id: &str,
+        members: Vec<FactionMemberInputData>,
+    ) -> Result<FactionData, ServiceError> {
+        self.request(FactionRequest::SetFactionMembers {
+            faction_id: faction_id.to_string(),
+            members,
+        })
+        .await
+    }
+
+    /// Move a faction's reputation with the party up or down
+    pub async fn adjust_reputation(
+        &self,
+        faction_id: &str,
+        delta: i32,
+    ) -> Result<FactionData, ServiceError> {
+        self.request(FactionRequest::AdjustFactionReputation {
+            faction_id: faction_id.to_string(),
+            delta,
+        })
+        .await
+    }
+
+    /// Set a faction's reputation with the party outright
+    pub async fn set_reputation(
+        &self,
+        faction_id: &str,
+        reputation: i32,
+    ) -> Result<FactionData, ServiceError> {
+        self.request(FactionRequest::SetFactionReputation {
+            faction_id: faction_id.to_string(),
+            reputation,
+        })
+        .await
+    }
+
+    /// Make two factions rivals, or end their rivalry
+    pub async fn set_rivalry(
+        &self,
+        faction_id: &str,
+        rival_id: &str,
+        rivals: bool,
+    ) -> Result<FactionData, ServiceError> {
+        self.request(FactionRequest::SetFactionRivalry {
+            faction_id: faction_id.to_string(),
+            rival_id: rival_id.to_string(),
+            rivals,
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: FactionRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Faction(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
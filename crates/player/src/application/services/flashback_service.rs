@@ -0,0 +1,61 @@
+//! Flashback Service - Application service for flashback scenes
+//!
+//! Reads the running flashback, and lets the DM start one (winding the
+//! world clock back and restaging a region) or end it (restoring the
+//! present). Starting and ending are DM-only.
+
+use crate::application::dto::{FlashbackData, StartFlashbackData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{FlashbackRequest, RequestPayload};
+
+/// Flashback service
+#[derive(Clone)]
+pub struct FlashbackService {
+    commands: CommandBus,
+}
+
+impl FlashbackService {
+    /// Create a new FlashbackService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// The running flashback, if any
+    pub async fn get_flashback(&self) -> Result<Option<FlashbackData>, ServiceError> {
+        self.request(FlashbackRequest::GetFlashback).await
+    }
+
+    /// Start a flashback
+    pub async fn start_flashback(
+        &self,
+        data: StartFlashbackData,
+    ) -> Result<Option<FlashbackData>, ServiceError> {
+        self.request(FlashbackRequest::StartFlashback { data }).await
+    }
+
+    /// End the flashback and return to the present
+    pub async fn end_flashback(&self) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Flashback(FlashbackRequest::EndFlashback),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: FlashbackRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Flashback(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
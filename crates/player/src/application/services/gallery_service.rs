@@ -0,0 +1,72 @@
+//! Gallery Service - Application service for the world-wide asset gallery
+//!
+//! Lists the assets of every character, location and item in the world the
+//! DM is in, filtered by entity, asset type, generation batch, tag or
+//! favorites, and stars or tags several assets at once. All gallery
+//! requests are DM-only.
+
+use crate::application::dto::{GalleryAssetData, GalleryFilterData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{GalleryRequest, RequestPayload};
+
+/// Asset gallery service
+#[derive(Clone)]
+pub struct GalleryService {
+    commands: CommandBus,
+}
+
+impl GalleryService {
+    /// Create a new GalleryService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// The world's assets matching `filter`, newest first
+    pub async fn list_gallery(
+        &self,
+        filter: GalleryFilterData,
+    ) -> Result<Vec<GalleryAssetData>, ServiceError> {
+        self.request(GalleryRequest::ListGallery { filter }).await
+    }
+
+    /// Star or unstar assets; returns the updated assets
+    pub async fn set_favorite(
+        &self,
+        asset_ids: Vec<String>,
+        favorite: bool,
+    ) -> Result<Vec<GalleryAssetData>, ServiceError> {
+        self.request(GalleryRequest::SetAssetsFavorite {
+            asset_ids,
+            favorite,
+        })
+        .await
+    }
+
+    /// Add and remove tags on assets; returns the updated assets
+    pub async fn tag_assets(
+        &self,
+        asset_ids: Vec<String>,
+        add: Vec<String>,
+        remove: Vec<String>,
+    ) -> Result<Vec<GalleryAssetData>, ServiceError> {
+        self.request(GalleryRequest::TagAssets {
+            asset_ids,
+            add,
+            remove,
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: GalleryRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Gallery(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
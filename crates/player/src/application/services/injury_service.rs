@@ -0,0 +1,103 @@
+//! Injury Service - Application service for PC injuries
+//!
+//! Lists a PC's injuries and inflicts, changes, treats and removes them.
+//! Injuries heal by themselves as game time advances, faster when the PC
+//! rests; how fast comes from the world's rule system. All injury requests
+//! are DM-only.
+
+use crate::application::dto::{InjuryData, InjuryInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{InjuryRequest, RequestPayload};
+
+/// Injury service
+#[derive(Clone)]
+pub struct InjuryService {
+    commands: CommandBus,
+}
+
+impl InjuryService {
+    /// Create a new InjuryService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// A PC's injuries, oldest first
+    pub async fn list_injuries(
+        &self,
+        pc_id: &str,
+        include_healed: bool,
+    ) -> Result<Vec<InjuryData>, ServiceError> {
+        self.request(InjuryRequest::ListInjuries {
+            pc_id: pc_id.to_string(),
+            include_healed,
+        })
+        .await
+    }
+
+    /// Injure a PC as of the current game time
+    pub async fn inflict_injury(
+        &self,
+        pc_id: &str,
+        data: InjuryInputData,
+    ) -> Result<InjuryData, ServiceError> {
+        self.request(InjuryRequest::InflictInjury {
+            pc_id: pc_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Change an injury's details or recovery time
+    pub async fn update_injury(
+        &self,
+        injury_id: &str,
+        data: InjuryInputData,
+    ) -> Result<InjuryData, ServiceError> {
+        self.request(InjuryRequest::UpdateInjury {
+            injury_id: injury_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Record whether the injury's treatment succeeded
+    pub async fn treat_injury(
+        &self,
+        injury_id: &str,
+        succeeded: bool,
+    ) -> Result<InjuryData, ServiceError> {
+        self.request(InjuryRequest::TreatInjury {
+            injury_id: injury_id.to_string(),
+            succeeded,
+        })
+        .await
+    }
+
+    /// Remove an injury that should never have happened
+    pub async fn delete_injury(&self, injury_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Injury(InjuryRequest::DeleteInjury {
+                    injury_id: injury_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: InjuryRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Injury(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
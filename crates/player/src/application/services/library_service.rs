@@ -0,0 +1,87 @@
+//! Library Service - Application service for the cross-world content library
+//!
+//! Publishes characters, items, challenges and lore from the current world
+//! into the user's personal library, and imports library entries into the
+//! current world as independent copies. All library requests are DM-only.
+
+use crate::application::dto::{CreatedEntityData, LibraryEntryData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{EntityType, LibraryRequest, RequestPayload};
+
+/// Content library service
+#[derive(Clone)]
+pub struct LibraryService {
+    commands: CommandBus,
+}
+
+impl LibraryService {
+    /// Create a new LibraryService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// List the user's library entries, sorted by name
+    pub async fn list_library(&self) -> Result<Vec<LibraryEntryData>, ServiceError> {
+        self.request(LibraryRequest::ListLibrary).await
+    }
+
+    /// Publish a snapshot of an entity in `world_id`
+    pub async fn publish(
+        &self,
+        world_id: &str,
+        entity_type: EntityType,
+        entity_id: &str,
+        description: Option<String>,
+    ) -> Result<LibraryEntryData, ServiceError> {
+        self.request(LibraryRequest::PublishToLibrary {
+            world_id: world_id.to_string(),
+            entity_type,
+            entity_id: entity_id.to_string(),
+            description,
+        })
+        .await
+    }
+
+    /// Delete an entry; content imported from it is kept
+    pub async fn delete_entry(&self, entry_id: &str) -> Result<(), ServiceError> {
+        self.request_empty(LibraryRequest::DeleteLibraryEntry {
+            entry_id: entry_id.to_string(),
+        })
+        .await
+    }
+
+    /// Copy an entry into `world_id`
+    pub async fn import_entry(
+        &self,
+        entry_id: &str,
+        world_id: &str,
+    ) -> Result<CreatedEntityData, ServiceError> {
+        self.request(LibraryRequest::ImportLibraryEntry {
+            entry_id: entry_id.to_string(),
+            world_id: world_id.to_string(),
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: LibraryRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Library(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+
+    async fn request_empty(&self, request: LibraryRequest) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Library(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse_empty()
+    }
+}
@@ -6,8 +6,14 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::application::dto::CustomFieldEntryData;
 use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
 use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::types::{
+    ConnectionLockData, EncounterTableData, HotspotData, LightLevelData, LocationBreadcrumbData,
+    LocationScaleData, LocationTravelData, RegionMapKindData, SoundCarryData, SubLocationData,
+    WorldMapData, WorldMapPositionData,
+};
 use wrldbldr_protocol::{LocationRequest, RegionListItemData, RegionRequest, RequestPayload};
 
 /// Location summary for list views
@@ -16,6 +22,33 @@ pub struct LocationSummary {
     pub id: String,
     pub name: String,
     pub location_type: Option<String>,
+    /// The location this one sits inside
+    #[serde(default)]
+    pub parent_location_id: Option<String>,
+    #[serde(default)]
+    pub scale: Option<LocationScaleData>,
+    /// The locations containing this one, outermost first
+    #[serde(default)]
+    pub breadcrumb: Vec<LocationBreadcrumbData>,
+    /// Other names the location goes by (only sent to the DM)
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl LocationSummary {
+    /// Where the location sits, e.g. "Valdor › Haven"; empty at the top level
+    pub fn path(&self) -> String {
+        breadcrumb_path(&self.breadcrumb)
+    }
+}
+
+/// Breadcrumb names joined outermost first
+pub fn breadcrumb_path(breadcrumb: &[LocationBreadcrumbData]) -> String {
+    breadcrumb
+        .iter()
+        .map(|b| b.name.as_str())
+        .collect::<Vec<_>>()
+        .join(" › ")
 }
 
 /// Full location data for create/edit forms via API
@@ -36,13 +69,39 @@ pub struct LocationFormData {
     pub hidden_secrets: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_location_id: Option<String>,
+    /// How large a place this is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<LocationScaleData>,
+    /// Game minutes between two places inside the location
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crossing_minutes: Option<u32>,
+    /// The locations containing this one, outermost first (read-only here)
+    #[serde(default, skip_serializing)]
+    pub breadcrumb: Vec<LocationBreadcrumbData>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub backdrop_asset: Option<String>,
+    /// Top-down map image (read-only here)
+    #[serde(default, skip_serializing)]
+    pub map_asset: Option<String>,
     #[serde(default)]
     pub backdrop_regions: Vec<serde_json::Value>,
     /// Default TTL in hours for staging cache in this location (default: 4 hours)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub presence_cache_ttl_hours: Option<i32>,
+    /// DM-defined fields with their values (read-only here; saved separately)
+    #[serde(default, skip_serializing)]
+    pub custom_fields: Vec<CustomFieldEntryData>,
+    /// What travellers crossing the location might run into (read-only
+    /// here; saved separately)
+    #[serde(default, skip_serializing)]
+    pub encounter_table: Option<EncounterTableData>,
+}
+
+/// The hotspots part of a region response
+#[derive(Deserialize)]
+struct RegionHotspots {
+    #[serde(default)]
+    hotspots: Vec<HotspotData>,
 }
 
 /// Location connection data
@@ -58,12 +117,42 @@ pub struct ConnectionData {
     pub bidirectional: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub travel_time: Option<u32>,
+    #[serde(default)]
+    pub is_locked: bool,
+    #[serde(default)]
+    pub lock_description: Option<String>,
+    /// Items that open the lock (DM only)
+    #[serde(default)]
+    pub key_item_ids: Vec<String>,
+    /// Challenge whose success opens the lock (DM only)
+    #[serde(default)]
+    pub lock_challenge_id: Option<String>,
 }
 
 fn default_bidirectional() -> bool {
     true
 }
 
+/// A way from one region to another in the same location
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct RegionConnectionData {
+    pub from_region_id: String,
+    pub to_region_id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default = "default_bidirectional")]
+    pub bidirectional: bool,
+    #[serde(default)]
+    pub is_locked: bool,
+    /// How well noise carries through
+    #[serde(default = "default_sound")]
+    pub sound: SoundCarryData,
+}
+
+fn default_sound() -> SoundCarryData {
+    SoundCarryData::Open
+}
+
 // From impls for protocol conversion at the boundary
 impl LocationFormData {
     fn to_create_data(&self) -> wrldbldr_protocol::requests::CreateLocationData {
@@ -71,6 +160,8 @@ impl LocationFormData {
             name: self.name.clone(),
             description: self.description.clone(),
             setting: self.atmosphere.clone(),
+            parent_id: self.parent_location_id.clone(),
+            scale: self.scale,
         }
     }
 
@@ -79,6 +170,8 @@ impl LocationFormData {
             name: Some(self.name.clone()),
             description: self.description.clone(),
             setting: self.atmosphere.clone(),
+            scale: self.scale,
+            crossing_minutes: self.crossing_minutes,
         }
     }
 }
@@ -115,12 +208,22 @@ impl LocationService {
     pub async fn list_locations(
         &self,
         world_id: &str,
+    ) -> Result<Vec<LocationSummary>, ServiceError> {
+        self.list_tagged_locations(world_id, &[]).await
+    }
+
+    /// List the locations in a world carrying every one of `tags` (DM only)
+    pub async fn list_tagged_locations(
+        &self,
+        world_id: &str,
+        tags: &[String],
     ) -> Result<Vec<LocationSummary>, ServiceError> {
         let result = self
             .commands
             .request_with_timeout(
                 RequestPayload::Location(LocationRequest::ListLocations {
                     world_id: world_id.to_string(),
+                    tags: tags.to_vec(),
                 }),
                 get_request_timeout_ms(),
             )
@@ -161,12 +264,15 @@ impl LocationService {
         result.parse()
     }
 
-    /// Update an existing location
+    /// Update an existing location, moving it under its chosen parent first
     pub async fn update_location(
         &self,
         location_id: &str,
         location: &LocationFormData,
     ) -> Result<LocationFormData, ServiceError> {
+        self.set_location_parent(location_id, location.parent_location_id.as_deref())
+            .await?;
+
         let result = self
             .commands
             .request_with_timeout(
@@ -180,6 +286,101 @@ impl LocationService {
         result.parse()
     }
 
+    /// Move a location inside another, or to the top level
+    pub async fn set_location_parent(
+        &self,
+        location_id: &str,
+        parent_id: Option<&str>,
+    ) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Location(LocationRequest::SetLocationParent {
+                    location_id: location_id.to_string(),
+                    parent_id: parent_id.map(str::to_string),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse_empty()
+    }
+
+    /// The locations inside a location, with counts of what each contains
+    pub async fn list_sub_locations(
+        &self,
+        location_id: &str,
+        recursive: bool,
+    ) -> Result<Vec<SubLocationData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Location(LocationRequest::ListSubLocations {
+                    location_id: location_id.to_string(),
+                    recursive,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Travel time between two locations through the hierarchy
+    pub async fn get_travel_time(
+        &self,
+        from_location_id: &str,
+        to_location_id: &str,
+    ) -> Result<LocationTravelData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Location(LocationRequest::GetTravelTime {
+                    from_location_id: from_location_id.to_string(),
+                    to_location_id: to_location_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Pin a location to the world map, or take it off with `None` (DM only)
+    pub async fn set_location_map_position(
+        &self,
+        location_id: &str,
+        position: Option<WorldMapPositionData>,
+    ) -> Result<WorldMapData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Location(LocationRequest::SetLocationMapPosition {
+                    location_id: location_id.to_string(),
+                    position,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Set a location's encounter table, or clear it with `None` (DM only)
+    pub async fn set_encounter_table(
+        &self,
+        location_id: &str,
+        table: Option<EncounterTableData>,
+    ) -> Result<Option<EncounterTableData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Location(LocationRequest::SetLocationEncounterTable {
+                    location_id: location_id.to_string(),
+                    table,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
     /// Delete a location
     pub async fn delete_location(&self, location_id: &str) -> Result<(), ServiceError> {
         let result = self
@@ -194,6 +395,23 @@ impl LocationService {
         result.parse_empty()
     }
 
+    /// Duplicate a location with its regions, returning the copy
+    pub async fn duplicate_location(
+        &self,
+        location_id: &str,
+    ) -> Result<LocationFormData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Location(LocationRequest::DuplicateLocation {
+                    location_id: location_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
     /// Get connections from a location
     pub async fn get_connections(
         &self,
@@ -225,6 +443,28 @@ impl LocationService {
         result.parse_empty()
     }
 
+    /// Lock a connection between locations behind keys or a challenge, or
+    /// unlock it with `None` (DM only)
+    pub async fn set_connection_lock(
+        &self,
+        from_location_id: &str,
+        to_location_id: &str,
+        lock: Option<ConnectionLockData>,
+    ) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Location(LocationRequest::SetLocationConnectionLock {
+                    from_id: from_location_id.to_string(),
+                    to_id: to_location_id.to_string(),
+                    lock,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse_empty()
+    }
+
     /// Get all regions for a location (with map bounds)
     pub async fn get_regions(
         &self,
@@ -235,10 +475,134 @@ impl LocationService {
             .request_with_timeout(
                 RequestPayload::Region(RegionRequest::ListRegions {
                     location_id: location_id.to_string(),
+                    tags: Vec::new(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Get the clickable hotspots on a region's backdrop
+    pub async fn get_region_hotspots(
+        &self,
+        region_id: &str,
+    ) -> Result<Vec<HotspotData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Region(RegionRequest::GetRegion {
+                    region_id: region_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        let region: RegionHotspots = result.parse()?;
+        Ok(region.hotspots)
+    }
+
+    /// Replace a region's hotspots (DM only)
+    ///
+    /// Returns the hotspots as saved, with points clamped to the backdrop.
+    pub async fn set_region_hotspots(
+        &self,
+        region_id: &str,
+        hotspots: Vec<HotspotData>,
+    ) -> Result<Vec<HotspotData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Region(RegionRequest::SetRegionHotspots {
+                    region_id: region_id.to_string(),
+                    hotspots,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        let region: RegionHotspots = result.parse()?;
+        Ok(region.hotspots)
+    }
+
+    /// Get the ways out of a region to others in the same location
+    pub async fn get_region_connections(
+        &self,
+        region_id: &str,
+    ) -> Result<Vec<RegionConnectionData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Region(RegionRequest::GetRegionConnections {
+                    region_id: region_id.to_string(),
                 }),
                 get_request_timeout_ms(),
             )
             .await?;
         result.parse()
     }
+
+    /// Set how well noise carries along a region connection (DM only)
+    pub async fn set_region_connection_sound(
+        &self,
+        from_region_id: &str,
+        to_region_id: &str,
+        sound: SoundCarryData,
+    ) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Region(RegionRequest::SetRegionConnectionSound {
+                    from_id: from_region_id.to_string(),
+                    to_id: to_region_id.to_string(),
+                    sound,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse_empty()
+    }
+
+    /// Fix a region's lighting, or let it follow its location when `None`
+    /// (DM only)
+    pub async fn set_region_lighting(
+        &self,
+        region_id: &str,
+        lighting: Option<LightLevelData>,
+    ) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Region(RegionRequest::SetRegionLighting {
+                    region_id: region_id.to_string(),
+                    lighting,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse_empty()
+    }
+
+    /// Start generating a top-down map for a region (DM only)
+    ///
+    /// Returns once the Engine has accepted the job; the map arrives later
+    /// as a `RegionMapGenerated` event.
+    pub async fn generate_region_map(
+        &self,
+        region_id: &str,
+        kind: RegionMapKindData,
+        prompt: Option<String>,
+    ) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Region(RegionRequest::GenerateRegionMap {
+                    region_id: region_id.to_string(),
+                    kind,
+                    workflow: None,
+                    prompt,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse_empty()
+    }
 }
@@ -0,0 +1,96 @@
+//! Mail Service - Application service for in-world letters
+//!
+//! Players write letters from their PC and read the ones that reach it.
+//! Letters take game time to arrive. The DM sees the whole world's mail,
+//! can have an NPC answer a letter and can remove letters.
+
+use crate::application::dto::{LetterData, LetterInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{MailRequest, RequestPayload};
+
+/// Mail service
+#[derive(Clone)]
+pub struct MailService {
+    commands: CommandBus,
+}
+
+impl MailService {
+    /// Create a new MailService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Letters the PC wrote and the ones that reached them, newest first
+    pub async fn list_mailbox(&self, pc_id: &str) -> Result<Vec<LetterData>, ServiceError> {
+        self.request(MailRequest::ListMailbox {
+            pc_id: pc_id.to_string(),
+        })
+        .await
+    }
+
+    /// Send a letter from the PC
+    pub async fn send_letter(
+        &self,
+        pc_id: &str,
+        data: LetterInputData,
+    ) -> Result<LetterData, ServiceError> {
+        self.request(MailRequest::SendLetter {
+            pc_id: pc_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Note that the PC read a letter that reached them
+    pub async fn mark_read(&self, letter_id: &str) -> Result<LetterData, ServiceError> {
+        self.request(MailRequest::MarkLetterRead {
+            letter_id: letter_id.to_string(),
+        })
+        .await
+    }
+
+    /// Every letter in the world, newest first (DM only)
+    pub async fn list_world_mail(&self) -> Result<Vec<LetterData>, ServiceError> {
+        self.request(MailRequest::ListWorldMail).await
+    }
+
+    /// Have the NPC a letter reached write back; generated when `body` is None
+    pub async fn reply_as_npc(
+        &self,
+        letter_id: &str,
+        body: Option<String>,
+    ) -> Result<LetterData, ServiceError> {
+        self.request(MailRequest::ReplyAsNpc {
+            letter_id: letter_id.to_string(),
+            body,
+        })
+        .await
+    }
+
+    pub async fn delete_letter(&self, letter_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Mail(MailRequest::DeleteLetter {
+                    letter_id: letter_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: MailRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Mail(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
@@ -0,0 +1,149 @@
+//! Mention Service - Application service for entity mentions
+//!
+//! Lists the entities text can link to, manages their aliases, and looks
+//! up where an entity is referenced. Text is linked on the client; the
+//! engine keeps the "referenced in" index as descriptions, lore and
+//! dialogue are saved. All mention requests are DM-only.
+
+use crate::application::dto::{EntityMentionData, MentionTargetData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{EntityType, MentionRequest, RequestPayload};
+
+#[derive(serde::Deserialize)]
+struct AliasesResponse {
+    aliases: Vec<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct ReindexResponse {
+    mentions: u32,
+}
+
+/// Mention service
+#[derive(Clone)]
+pub struct MentionService {
+    commands: CommandBus,
+}
+
+impl MentionService {
+    /// Create a new MentionService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Every entity in the current world that text can link to
+    pub async fn list_targets(&self) -> Result<Vec<MentionTargetData>, ServiceError> {
+        self.request(MentionRequest::ListMentionTargets).await
+    }
+
+    /// Replace an entity's aliases, returning them as stored
+    pub async fn set_aliases(
+        &self,
+        entity_type: EntityType,
+        entity_id: &str,
+        aliases: Vec<String>,
+    ) -> Result<Vec<String>, ServiceError> {
+        let response: AliasesResponse = self
+            .request(MentionRequest::SetEntityAliases {
+                entity_type,
+                entity_id: entity_id.to_string(),
+                aliases,
+            })
+            .await?;
+        Ok(response.aliases)
+    }
+
+    /// An entity's aliases
+    pub async fn aliases_of(
+        &self,
+        entity_type: EntityType,
+        entity_id: &str,
+    ) -> Result<Vec<String>, ServiceError> {
+        let response: AliasesResponse = self
+            .request(MentionRequest::GetEntityAliases {
+                entity_type,
+                entity_id: entity_id.to_string(),
+            })
+            .await?;
+        Ok(response.aliases)
+    }
+
+    /// Add one alias, returning the entity's aliases as stored
+    pub async fn add_alias(
+        &self,
+        entity_type: EntityType,
+        entity_id: &str,
+        alias: &str,
+    ) -> Result<Vec<String>, ServiceError> {
+        let response: AliasesResponse = self
+            .request(MentionRequest::AddEntityAlias {
+                entity_type,
+                entity_id: entity_id.to_string(),
+                alias: alias.to_string(),
+            })
+            .await?;
+        Ok(response.aliases)
+    }
+
+    /// Remove one alias, returning the entity's aliases as stored
+    pub async fn remove_alias(
+        &self,
+        entity_type: EntityType,
+        entity_id: &str,
+        alias: &str,
+    ) -> Result<Vec<String>, ServiceError> {
+        let response: AliasesResponse = self
+            .request(MentionRequest::RemoveEntityAlias {
+                entity_type,
+                entity_id: entity_id.to_string(),
+                alias: alias.to_string(),
+            })
+            .await?;
+        Ok(response.aliases)
+    }
+
+    /// Where an entity is referenced
+    pub async fn mentions_of(
+        &self,
+        entity_type: EntityType,
+        entity_id: &str,
+    ) -> Result<Vec<EntityMentionData>, ServiceError> {
+        self.request(MentionRequest::GetMentionsOf {
+            entity_type,
+            entity_id: entity_id.to_string(),
+        })
+        .await
+    }
+
+    /// What an entity's text references
+    pub async fn mentions_in(
+        &self,
+        entity_type: EntityType,
+        entity_id: &str,
+    ) -> Result<Vec<EntityMentionData>, ServiceError> {
+        self.request(MentionRequest::GetMentionsIn {
+            entity_type,
+            entity_id: entity_id.to_string(),
+        })
+        .await
+    }
+
+    /// Scan the world's text again; returns how many mentions were found
+    pub async fn reindex(&self) -> Result<u32, ServiceError> {
+        let response: ReindexResponse = self.request(MentionRequest::ReindexMentions).await?;
+        Ok(response.mentions)
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: MentionRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Mention(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
@@ -7,20 +7,50 @@
 pub mod actantial_service;
 pub mod action_service;
 pub mod asset_service;
+pub mod broadsheet_service;
 pub mod challenge_service;
 pub mod character_service;
+pub mod character_sheet_service;
+pub mod chronology_service;
+pub mod companion_service;
+pub mod crowd_service;
+pub mod dialogue_tree_service;
+pub mod dice_service;
+pub mod draft_service;
+pub mod economy_service;
 pub mod event_chain_service;
+pub mod faction_service;
+pub mod flashback_service;
+pub mod gallery_service;
 pub mod generation_service;
+pub mod injury_service;
+pub mod library_service;
 pub mod location_service;
+pub mod mail_service;
+pub mod mention_service;
+pub mod model_service;
+pub mod mortality_service;
+pub mod name_generator_service;
 pub mod narrative_event_service;
+pub mod npc_draft_service;
 pub mod observation_service;
+pub mod pacing_service;
+pub mod parallel_scene_service;
 pub mod player_character_service;
+pub mod progress_clock_service;
+pub mod property_service;
+pub mod secret_service;
+pub mod spotlight_service;
 pub mod session_command_service;
 pub mod session_service;
 pub mod settings_service;
+pub mod simulation_service;
 pub mod skill_service;
 pub mod story_event_service;
 pub mod suggestion_service;
+pub mod tag_service;
+pub mod template_service;
+pub mod transcription_service;
 pub mod user_service;
 pub mod workflow_service;
 pub mod world_service;
@@ -44,6 +74,12 @@ pub use character_service::{CharacterFormData, CharacterService, CharacterSummar
 // CharacterSheetDataApi is shared - export from dto
 pub use crate::application::dto::CharacterSheetDataApi;
 
+// Re-export character sheet service types
+pub use character_sheet_service::{
+    sheet_data_from_values, CharacterSheetService, CharacterSheetView, SheetFieldUpdate,
+    WorldSheetSchema,
+};
+
 // Re-export player character service types
 pub use player_character_service::{
     CreatePlayerCharacterRequest, PlayerCharacterData, PlayerCharacterService,
@@ -55,6 +91,90 @@ pub use location_service::{LocationFormData, LocationService, LocationSummary};
 // Map-related types from protocol
 pub use wrldbldr_protocol::{MapBoundsData, RegionListItemData};
 
+// Re-export progress clock service types
+pub use progress_clock_service::ProgressClockService;
+
+// Re-export dice service types
+pub use dice_service::DiceService;
+
+// Re-export tag service types
+pub use tag_service::TagService;
+
+// Re-export template service types
+pub use template_service::TemplateService;
+
+// Re-export library service types
+pub use library_service::LibraryService;
+
+// Re-export draft service types
+pub use draft_service::DraftService;
+
+// Re-export gallery service types
+pub use gallery_service::GalleryService;
+
+// Re-export chronology service types
+pub use chronology_service::ChronologyService;
+
+// Re-export crowd service types
+pub use crowd_service::CrowdService;
+
+// Re-export faction service types
+pub use faction_service::FactionService;
+
+// Re-export dialogue tree service types
+pub use dialogue_tree_service::DialogueTreeService;
+
+// Re-export flashback service types
+pub use flashback_service::FlashbackService;
+
+// Re-export parallel scene service types
+pub use parallel_scene_service::ParallelSceneService;
+
+// Re-export spotlight service types
+pub use spotlight_service::SpotlightService;
+
+// Re-export pacing service types
+pub use pacing_service::PacingService;
+
+// Re-export transcription service types
+pub use transcription_service::TranscriptionService;
+
+// Re-export NPC draft service types
+pub use npc_draft_service::NpcDraftService;
+
+// Re-export mention service types
+pub use mention_service::MentionService;
+
+// Re-export name generator service types
+pub use name_generator_service::NameGeneratorService;
+
+// Re-export economy service types
+pub use economy_service::EconomyService;
+
+// Re-export property service types
+pub use property_service::PropertyService;
+
+// Re-export companion service types
+pub use companion_service::CompanionService;
+
+// Re-export injury service types
+pub use injury_service::InjuryService;
+
+// Re-export mortality service types
+pub use mortality_service::MortalityService;
+
+// Re-export secret service types
+pub use secret_service::SecretService;
+
+// Re-export mail service types
+pub use mail_service::MailService;
+
+// Re-export broadsheet service types
+pub use broadsheet_service::BroadsheetService;
+
+// Re-export world simulation service types
+pub use simulation_service::SimulationService;
+
 // Re-export skill service types
 pub use skill_service::{CreateSkillRequest, SkillService, UpdateSkillRequest};
 
@@ -69,17 +189,24 @@ pub use narrative_event_service::NarrativeEventService;
 
 // Re-export workflow service types
 pub use workflow_service::{
-    AnalyzeWorkflowResponse, InputDefault, PromptMapping, TestWorkflowResponse, WorkflowAnalysis,
-    WorkflowConfig, WorkflowInput, WorkflowService, WorkflowSlotCategory, WorkflowSlotStatus,
+    AnalyzeWorkflowResponse, InputDefault, PromptMapping, ReferenceImageMapping,
+    SaveWorkflowRequest, TestWorkflowResponse, WorkflowAnalysis, WorkflowConfig, WorkflowInput,
+    WorkflowService, WorkflowSlotCategory, WorkflowSlotStatus,
 };
 
 // Re-export asset service types
-pub use asset_service::{Asset, AssetService, GenerateRequest};
+pub use asset_service::{
+    Asset, AssetService, AssociationSuggestion, AttachRequest, GenerateRequest, UploadRequest,
+    UploadedImage,
+};
 
 // Re-export suggestion service types
 pub use crate::application::dto::requests::SuggestionContext;
 pub use suggestion_service::SuggestionService;
 
+// Re-export model service types
+pub use model_service::{ModelInfo, ModelService, WorldModels};
+
 // Re-export event chain service types
 pub use event_chain_service::{
     CreateEventChainRequest, EventChainData, EventChainService, UpdateEventChainRequest,
@@ -92,7 +219,7 @@ pub use generation_service::GenerationService;
 pub use settings_service::SettingsService;
 
 // Re-export observation service types
-pub use observation_service::{ObservationService, ObservationSummary};
+pub use observation_service::{KnownLocationSummary, ObservationService, ObservationSummary};
 
 // Re-export actantial service types
 pub use actantial_service::{
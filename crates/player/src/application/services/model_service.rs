@@ -0,0 +1,95 @@
+//! Model Service - Application service for managing the Engine's LLM models
+//!
+//! Lists the models the Engine's Ollama server has, starts downloads, and
+//! chooses each world's dialogue and summarization models. Download progress
+//! is delivered via WebSocket events (ModelPullProgress).
+
+use serde::Deserialize;
+
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{AiRequest, RequestPayload};
+
+/// A model available on the Engine's LLM server
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct ModelInfo {
+    pub name: String,
+    #[serde(default)]
+    pub size_bytes: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct ModelListResponse {
+    models: Vec<ModelInfo>,
+}
+
+/// Models a world uses; `None` means the Engine's configured model
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct WorldModels {
+    pub dialogue_model: Option<String>,
+    pub summary_model: Option<String>,
+}
+
+/// Model service for the Engine's LLM model registry
+#[derive(Clone)]
+pub struct ModelService {
+    commands: CommandBus,
+}
+
+impl ModelService {
+    /// Create a new ModelService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// List models available on the LLM server
+    pub async fn list_models(&self) -> Result<Vec<ModelInfo>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Ai(AiRequest::ListModels),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        let response: ModelListResponse = result.parse()?;
+        Ok(response.models)
+    }
+
+    /// Start downloading a model
+    ///
+    /// Returns once the download has started. Progress delivered via WebSocket events.
+    pub async fn pull_model(&self, model: &str) -> Result<(), ServiceError> {
+        self.commands
+            .request_with_timeout(
+                RequestPayload::Ai(AiRequest::PullModel {
+                    model: model.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?
+            .parse_empty()
+    }
+
+    /// Choose a world's dialogue and summarization models
+    pub async fn set_world_models(
+        &self,
+        world_id: &str,
+        dialogue_model: Option<String>,
+        summary_model: Option<String>,
+    ) -> Result<WorldModels, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Ai(AiRequest::SetWorldModel {
+                    world_id: world_id.to_string(),
+                    dialogue_model,
+                    summary_model,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+}
@@ -0,0 +1,85 @@
+//! Mortality Service - Application service for PC death and succession
+//!
+//! Records a PC's death, brings them back when the world's rule system
+//! allows it, and creates the successor character a player continues with.
+//! Deaths and resurrections are DM-only; the memorial and the successor flow
+//! are open to the character's own player too.
+
+use crate::application::dto::{PcDeathData, PcDeathInputData, SuccessorData, SuccessorInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{MortalityRequest, RequestPayload};
+
+/// Mortality service
+#[derive(Clone)]
+pub struct MortalityService {
+    commands: CommandBus,
+}
+
+impl MortalityService {
+    /// Create a new MortalityService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// The fallen PC's memorial and whether they can still be resurrected
+    pub async fn get_death(&self, pc_id: &str) -> Result<PcDeathData, ServiceError> {
+        self.request(MortalityRequest::GetDeath {
+            pc_id: pc_id.to_string(),
+        })
+        .await
+    }
+
+    /// Record a PC's death as of the current game time
+    pub async fn kill_character(
+        &self,
+        pc_id: &str,
+        data: PcDeathInputData,
+    ) -> Result<PcDeathData, ServiceError> {
+        self.request(MortalityRequest::KillCharacter {
+            pc_id: pc_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Bring a fallen PC back, paying the rule system's cost
+    pub async fn resurrect_character(&self, pc_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Mortality(MortalityRequest::ResurrectCharacter {
+                    pc_id: pc_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    /// Create the character who carries on from a fallen PC
+    pub async fn create_successor(
+        &self,
+        pc_id: &str,
+        data: SuccessorInputData,
+    ) -> Result<SuccessorData, ServiceError> {
+        self.request(MortalityRequest::CreateSuccessor {
+            pc_id: pc_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: MortalityRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Mortality(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
@@ -0,0 +1,109 @@
+//! Name Generator Service - Application service for NPC naming
+//!
+//! Lists, creates, edits and removes the name generators of the DM's world,
+//! and draws fresh NPC names from them. A generator is list-based (given and
+//! family names) or grammar-based (patterns over syllable parts), and can be
+//! assigned to regions and factions; one assigned nowhere is the world
+//! default. All name generator requests are DM-only.
+
+use crate::application::dto::{GeneratedNamesData, NameGeneratorData, NameGeneratorInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{GenerationRequest, NameGeneratorRequest, RequestPayload};
+
+/// Name generator service
+#[derive(Clone)]
+pub struct NameGeneratorService {
+    commands: CommandBus,
+}
+
+impl NameGeneratorService {
+    /// Create a new NameGeneratorService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Name generators in the current world, sorted by name
+    pub async fn list_generators(&self) -> Result<Vec<NameGeneratorData>, ServiceError> {
+        self.request(NameGeneratorRequest::ListNameGenerators).await
+    }
+
+    /// Create a name generator
+    pub async fn create_generator(
+        &self,
+        data: NameGeneratorInputData,
+    ) -> Result<NameGeneratorData, ServiceError> {
+        self.request(NameGeneratorRequest::CreateNameGenerator { data })
+            .await
+    }
+
+    /// Replace a generator's name, style and assignments
+    pub async fn update_generator(
+        &self,
+        generator_id: &str,
+        data: NameGeneratorInputData,
+    ) -> Result<NameGeneratorData, ServiceError> {
+        self.request(NameGeneratorRequest::UpdateNameGenerator {
+            generator_id: generator_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Delete a name generator
+    pub async fn delete_generator(&self, generator_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::NameGenerator(NameGeneratorRequest::DeleteNameGenerator {
+                    generator_id: generator_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    /// Draw names no character in the world has yet. Without a generator,
+    /// the faction's, then the region's, then the world default is used.
+    pub async fn generate_names(
+        &self,
+        world_id: &str,
+        generator_id: Option<&str>,
+        region_id: Option<&str>,
+        faction: Option<&str>,
+        count: u32,
+    ) -> Result<GeneratedNamesData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Generation(GenerationRequest::GenerateNames {
+                    world_id: world_id.to_string(),
+                    generator_id: generator_id.map(str::to_string),
+                    region_id: region_id.map(str::to_string),
+                    faction: faction.map(str::to_string),
+                    count,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: NameGeneratorRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::NameGenerator(request),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+}
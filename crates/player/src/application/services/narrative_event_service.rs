@@ -33,6 +33,7 @@ impl NarrativeEventService {
     ) -> Result<Vec<NarrativeEventData>, ServiceError> {
         let payload = RequestPayload::NarrativeEvent(NarrativeEventRequest::ListNarrativeEvents {
             world_id: world_id.to_string(),
+            tags: Vec::new(),
         });
         let response = self
             .commands
@@ -99,6 +100,22 @@ impl NarrativeEventService {
         response.parse_empty()
     }
 
+    /// Duplicate a narrative event, returning the (untriggered) copy
+    pub async fn duplicate_narrative_event(
+        &self,
+        event_id: &str,
+    ) -> Result<NarrativeEventData, ServiceError> {
+        let payload =
+            RequestPayload::NarrativeEvent(NarrativeEventRequest::DuplicateNarrativeEvent {
+                event_id: event_id.to_string(),
+            });
+        let response = self
+            .commands
+            .request_with_timeout(payload, get_request_timeout_ms())
+            .await?;
+        response.parse()
+    }
+
     /// Create a new narrative event
     pub async fn create_narrative_event(
         &self,
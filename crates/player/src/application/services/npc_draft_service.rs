@@ -0,0 +1,96 @@
+//! NPC Draft Service - Application service for NPC promotion
+//!
+//! Promotes a crowd member, or a name mentioned in dialogue or lore, into an
+//! NPC draft that the LLM fills in. Drafts come back generating; the filled
+//! draft arrives later as an `NpcDraftUpdated` event. The DM edits, approves
+//! or rejects each draft. All draft requests are DM-only.
+
+use crate::application::dto::{NpcDraftData, NpcDraftInputData, NpcDraftSourceData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::types::CreatedEntityData;
+use wrldbldr_protocol::{NpcDraftRequest, RequestPayload};
+
+/// NPC draft service
+#[derive(Clone)]
+pub struct NpcDraftService {
+    commands: CommandBus,
+}
+
+impl NpcDraftService {
+    /// Create a new NpcDraftService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Drafts waiting for approval in the current world, newest first
+    pub async fn list_drafts(&self) -> Result<Vec<NpcDraftData>, ServiceError> {
+        self.request(NpcDraftRequest::ListNpcDrafts).await
+    }
+
+    /// Start a draft; `name` is required unless promoting a crowd member
+    pub async fn promote(
+        &self,
+        source: NpcDraftSourceData,
+        name: Option<String>,
+    ) -> Result<NpcDraftData, ServiceError> {
+        self.request(NpcDraftRequest::PromoteToNpc { source, name })
+            .await
+    }
+
+    /// Replace a draft's name, description, archetype and disposition
+    pub async fn update_draft(
+        &self,
+        draft_id: &str,
+        data: NpcDraftInputData,
+    ) -> Result<NpcDraftData, ServiceError> {
+        self.request(NpcDraftRequest::UpdateNpcDraft {
+            draft_id: draft_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Ask the LLM for new details
+    pub async fn regenerate_draft(&self, draft_id: &str) -> Result<NpcDraftData, ServiceError> {
+        self.request(NpcDraftRequest::RegenerateNpcDraft {
+            draft_id: draft_id.to_string(),
+        })
+        .await
+    }
+
+    /// Create the character and drop the draft
+    pub async fn approve_draft(&self, draft_id: &str) -> Result<CreatedEntityData, ServiceError> {
+        self.request(NpcDraftRequest::ApproveNpcDraft {
+            draft_id: draft_id.to_string(),
+        })
+        .await
+    }
+
+    /// Drop the draft
+    pub async fn reject_draft(&self, draft_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::NpcDraft(NpcDraftRequest::RejectNpcDraft {
+                    draft_id: draft_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: NpcDraftRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::NpcDraft(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
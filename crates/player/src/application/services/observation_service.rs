@@ -1,6 +1,7 @@
 //! Observation Service - Application service for NPC observations
 //!
 //! US-OBS-004/005: Fetch and manage PC observations of NPCs via WebSocket.
+//! US-NAV-017: Locations a PC knows, which bound the world map and travel.
 
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +23,26 @@ pub struct ObservationSummary {
     pub notes: Option<String>,
 }
 
+/// A location a PC has visited or heard about
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KnownLocationSummary {
+    pub location_id: String,
+    pub name: String,
+    pub parent_location_id: Option<String>,
+    /// "direct" when visited, otherwise how they learned of it
+    pub observation_type: String,
+    pub observation_type_icon: String,
+    pub game_time: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl KnownLocationSummary {
+    /// Whether the PC has been there rather than only heard of it
+    pub fn is_visited(&self) -> bool {
+        self.observation_type == "direct"
+    }
+}
+
 /// Observation service for managing NPC observations
 ///
 /// This service provides methods for observation-related operations
@@ -54,4 +75,64 @@ impl ObservationService {
 
         result.parse()
     }
+
+    /// Locations the PC knows, by name
+    pub async fn list_known_locations(
+        &self,
+        pc_id: &str,
+    ) -> Result<Vec<KnownLocationSummary>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Observation(ObservationRequest::ListKnownLocations {
+                    pc_id: pc_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+
+    /// Tell a PC about a location (DM only)
+    pub async fn reveal_location(
+        &self,
+        pc_id: &str,
+        location_id: &str,
+        notes: Option<String>,
+    ) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Observation(ObservationRequest::RevealLocation {
+                    pc_id: pc_id.to_string(),
+                    location_id: location_id.to_string(),
+                    notes,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    /// Make a PC forget a location (DM only)
+    pub async fn forget_location(
+        &self,
+        pc_id: &str,
+        location_id: &str,
+    ) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Observation(ObservationRequest::ForgetLocation {
+                    pc_id: pc_id.to_string(),
+                    location_id: location_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
 }
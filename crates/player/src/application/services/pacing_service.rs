@@ -0,0 +1,70 @@
+//! Pacing Service - Application service for scene and turn timers
+//!
+//! Reads the world's pacing timer, and lets the DM start, pause, resume,
+//! extend or stop it. Everything but reading is DM-only.
+
+use crate::application::dto::{PacingTimerData, StartPacingTimerData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{PacingRequest, RequestPayload, ResponseResult};
+
+/// Pacing timer service
+#[derive(Clone)]
+pub struct PacingService {
+    commands: CommandBus,
+}
+
+impl PacingService {
+    /// Create a new PacingService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// The world's timer, if one is running
+    pub async fn get_timer(&self) -> Result<Option<PacingTimerData>, ServiceError> {
+        self.request(PacingRequest::GetPacingTimer).await
+    }
+
+    /// Start a scene or turn timer, replacing any running one
+    pub async fn start(
+        &self,
+        data: StartPacingTimerData,
+    ) -> Result<Option<PacingTimerData>, ServiceError> {
+        self.request(PacingRequest::StartPacingTimer { data }).await
+    }
+
+    pub async fn pause(&self) -> Result<Option<PacingTimerData>, ServiceError> {
+        self.request(PacingRequest::PausePacingTimer).await
+    }
+
+    pub async fn resume(&self) -> Result<Option<PacingTimerData>, ServiceError> {
+        self.request(PacingRequest::ResumePacingTimer).await
+    }
+
+    /// Add minutes, also to a timer that already ran out
+    pub async fn extend(&self, minutes: u32) -> Result<Option<PacingTimerData>, ServiceError> {
+        self.request(PacingRequest::ExtendPacingTimer { minutes })
+            .await
+    }
+
+    pub async fn stop(&self) -> Result<(), ServiceError> {
+        self.send(PacingRequest::StopPacingTimer)
+            .await?
+            .parse_empty()
+    }
+
+    /// Requests answer with the timer, or nothing once it is stopped
+    async fn request(
+        &self,
+        request: PacingRequest,
+    ) -> Result<Option<PacingTimerData>, ServiceError> {
+        self.send(request).await?.parse_optional()
+    }
+
+    async fn send(&self, request: PacingRequest) -> Result<ResponseResult, ServiceError> {
+        Ok(self
+            .commands
+            .request_with_timeout(RequestPayload::Pacing(request), get_request_timeout_ms())
+            .await?)
+    }
+}
@@ -0,0 +1,91 @@
+//! Parallel Scene Service - Application service for split parties
+//!
+//! Lists the scenes of a split party, and lets the DM split the party into
+//! groups, regroup them, switch focus between them and merge them back.
+//! Everything but listing is DM-only; players get notes and tone blanked.
+
+use crate::application::dto::{ParallelSceneData, ParallelSceneInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{ParallelSceneRequest, RequestPayload};
+
+/// Parallel scene service
+#[derive(Clone)]
+pub struct ParallelSceneService {
+    commands: CommandBus,
+}
+
+impl ParallelSceneService {
+    /// Create a new ParallelSceneService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// The world's parallel scenes; empty while the party is together
+    pub async fn list_scenes(&self) -> Result<Vec<ParallelSceneData>, ServiceError> {
+        self.request(ParallelSceneRequest::ListParallelScenes).await
+    }
+
+    /// Split a group off into its own scene
+    pub async fn create_scene(
+        &self,
+        data: ParallelSceneInputData,
+    ) -> Result<Vec<ParallelSceneData>, ServiceError> {
+        self.request(ParallelSceneRequest::CreateParallelScene { data })
+            .await
+    }
+
+    /// Rename a scene, regroup it or change its direction
+    pub async fn update_scene(
+        &self,
+        scene_id: &str,
+        data: ParallelSceneInputData,
+    ) -> Result<Vec<ParallelSceneData>, ServiceError> {
+        self.request(ParallelSceneRequest::UpdateParallelScene {
+            scene_id: scene_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Close a scene; its PCs return to the main table
+    pub async fn delete_scene(
+        &self,
+        scene_id: &str,
+    ) -> Result<Vec<ParallelSceneData>, ServiceError> {
+        self.request(ParallelSceneRequest::DeleteParallelScene {
+            scene_id: scene_id.to_string(),
+        })
+        .await
+    }
+
+    /// Run a scene, or the main table with `None`
+    pub async fn focus_scene(
+        &self,
+        scene_id: Option<String>,
+    ) -> Result<Vec<ParallelSceneData>, ServiceError> {
+        self.request(ParallelSceneRequest::FocusParallelScene { scene_id })
+            .await
+    }
+
+    /// Bring every group back to the main table
+    pub async fn merge_scenes(&self) -> Result<Vec<ParallelSceneData>, ServiceError> {
+        self.request(ParallelSceneRequest::MergeParallelScenes)
+            .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: ParallelSceneRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::ParallelScene(request),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+}
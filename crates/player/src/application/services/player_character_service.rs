@@ -6,10 +6,10 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::application::dto::CharacterSheetDataApi;
+use crate::application::dto::{CharacterSheetDataApi, InteractionData};
 use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
 use crate::infrastructure::messaging::CommandBus;
-use wrldbldr_protocol::{PlayerCharacterRequest, RequestPayload};
+use wrldbldr_protocol::{InteractionRequest, PlayerCharacterRequest, RequestPayload};
 
 /// Full player character data
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -24,6 +24,8 @@ pub struct PlayerCharacterData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sheet_data: Option<CharacterSheetDataApi>,
     pub current_location_id: String,
+    #[serde(default)]
+    pub current_region_id: Option<String>,
     pub starting_location_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sprite_asset: Option<String>,
@@ -31,6 +33,19 @@ pub struct PlayerCharacterData {
     pub portrait_asset: Option<String>,
     pub created_at: String,
     pub last_active_at: String,
+    /// False once the PC has died; a dead PC is locked until resurrected
+    #[serde(default = "default_alive")]
+    pub is_alive: bool,
+    /// The fallen PC this one succeeded
+    #[serde(default)]
+    pub predecessor_id: Option<String>,
+    /// The PC who carried on after this one died
+    #[serde(default)]
+    pub successor_id: Option<String>,
+}
+
+fn default_alive() -> bool {
+    true
 }
 
 /// Request to create a player character
@@ -160,6 +175,7 @@ impl PlayerCharacterService {
             .request_with_timeout(
                 RequestPayload::PlayerCharacter(PlayerCharacterRequest::ListPlayerCharacters {
                     world_id: world_id.to_string(),
+                    tags: Vec::new(),
                 }),
                 get_request_timeout_ms(),
             )
@@ -210,6 +226,25 @@ impl PlayerCharacterService {
         result.parse()
     }
 
+    /// Interactions in the PC's region as their action buttons, with
+    /// cooldowns and uses left
+    pub async fn list_available_interactions(
+        &self,
+        pc_id: &str,
+    ) -> Result<Vec<InteractionData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Interaction(InteractionRequest::ListAvailableInteractions {
+                    pc_id: pc_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+
     /// Delete a player character
     pub async fn delete_pc(&self, pc_id: &str) -> Result<(), ServiceError> {
         let result = self
@@ -0,0 +1,111 @@
+//! Progress Clock Service - Application service for progress clocks
+//!
+//! Lists, creates, ticks, and deletes Blades-style progress clocks via the
+//! WebSocket request/response pattern. Clock changes are also broadcast as
+//! `ClockUpdated`/`ClockRemoved` events, so callers usually don't need to
+//! apply the returned clock to local state themselves.
+
+use crate::application::dto::ProgressClockData;
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::requests::{CreateClockData, UpdateClockData};
+use wrldbldr_protocol::{ClockRequest, RequestPayload};
+
+/// Progress clock service
+#[derive(Clone)]
+pub struct ProgressClockService {
+    commands: CommandBus,
+}
+
+impl ProgressClockService {
+    /// Create a new ProgressClockService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// List clocks in a world (players only receive visible clocks)
+    pub async fn list_clocks(
+        &self,
+        world_id: &str,
+    ) -> Result<Vec<ProgressClockData>, ServiceError> {
+        self.request(ClockRequest::ListClocks {
+            world_id: world_id.to_string(),
+            tags: Vec::new(),
+        })
+        .await
+    }
+
+    /// Create a new clock
+    pub async fn create_clock(
+        &self,
+        world_id: &str,
+        data: CreateClockData,
+    ) -> Result<ProgressClockData, ServiceError> {
+        self.request(ClockRequest::CreateClock {
+            world_id: world_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Update clock details (name, size, kind, visibility)
+    pub async fn update_clock(
+        &self,
+        clock_id: &str,
+        data: UpdateClockData,
+    ) -> Result<ProgressClockData, ServiceError> {
+        self.request(ClockRequest::UpdateClock {
+            clock_id: clock_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Fill (positive) or clear (negative) segments
+    pub async fn tick_clock(
+        &self,
+        clock_id: &str,
+        amount: i32,
+    ) -> Result<ProgressClockData, ServiceError> {
+        self.request(ClockRequest::TickClock {
+            clock_id: clock_id.to_string(),
+            amount,
+        })
+        .await
+    }
+
+    /// Clear all filled segments
+    pub async fn reset_clock(&self, clock_id: &str) -> Result<ProgressClockData, ServiceError> {
+        self.request(ClockRequest::ResetClock {
+            clock_id: clock_id.to_string(),
+        })
+        .await
+    }
+
+    /// Delete a clock
+    pub async fn delete_clock(&self, clock_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Clock(ClockRequest::DeleteClock {
+                    clock_id: clock_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: ClockRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Clock(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
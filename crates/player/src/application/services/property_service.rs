@@ -0,0 +1,93 @@
+//! Property Service - Application service for PC-owned property
+//!
+//! Lists a PC's properties and creates, edits, removes and settles them.
+//! Upkeep builds up per game day; paying it takes the amount from the
+//! owner's currency stat when the rule system has one. All property
+//! requests are DM-only.
+
+use crate::application::dto::{PropertyData, PropertyInputData, UpkeepPaymentData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{PropertyRequest, RequestPayload};
+
+/// Property service
+#[derive(Clone)]
+pub struct PropertyService {
+    commands: CommandBus,
+}
+
+impl PropertyService {
+    /// Create a new PropertyService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Properties owned by a PC, oldest first
+    pub async fn list_properties(&self, pc_id: &str) -> Result<Vec<PropertyData>, ServiceError> {
+        self.request(PropertyRequest::ListPcProperties {
+            pc_id: pc_id.to_string(),
+        })
+        .await
+    }
+
+    /// Give a PC a new property, with upkeep paid through today
+    pub async fn create_property(
+        &self,
+        pc_id: &str,
+        data: PropertyInputData,
+    ) -> Result<PropertyData, ServiceError> {
+        self.request(PropertyRequest::CreateProperty {
+            pc_id: pc_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Replace everything about a property except its owner and upkeep state
+    pub async fn update_property(
+        &self,
+        property_id: &str,
+        data: PropertyInputData,
+    ) -> Result<PropertyData, ServiceError> {
+        self.request(PropertyRequest::UpdateProperty {
+            property_id: property_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Delete a property
+    pub async fn delete_property(&self, property_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Property(PropertyRequest::DeleteProperty {
+                    property_id: property_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    /// Settle a property's upkeep for every whole game day owed
+    pub async fn pay_upkeep(&self, property_id: &str) -> Result<UpkeepPaymentData, ServiceError> {
+        self.request(PropertyRequest::PayUpkeep {
+            property_id: property_id.to_string(),
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: PropertyRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Property(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
@@ -0,0 +1,91 @@
+//! Secret Service - Application service for PC secrets
+//!
+//! Secrets are known only to the DM and the PC's player until the DM
+//! reveals them. A player can list their own PC's secrets; creating,
+//! changing, deleting and revealing them is DM-only.
+
+use crate::application::dto::{PcSecretData, PcSecretInputData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{RequestPayload, SecretRequest};
+
+/// Secret service
+#[derive(Clone)]
+pub struct SecretService {
+    commands: CommandBus,
+}
+
+impl SecretService {
+    /// Create a new SecretService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// A PC's secrets, oldest first, revealed ones included
+    pub async fn list_secrets(&self, pc_id: &str) -> Result<Vec<PcSecretData>, ServiceError> {
+        self.request(SecretRequest::ListSecrets {
+            pc_id: pc_id.to_string(),
+        })
+        .await
+    }
+
+    /// Give a PC a new secret
+    pub async fn create_secret(
+        &self,
+        pc_id: &str,
+        data: PcSecretInputData,
+    ) -> Result<PcSecretData, ServiceError> {
+        self.request(SecretRequest::CreateSecret {
+            pc_id: pc_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Change a secret that has not been revealed yet
+    pub async fn update_secret(
+        &self,
+        secret_id: &str,
+        data: PcSecretInputData,
+    ) -> Result<PcSecretData, ServiceError> {
+        self.request(SecretRequest::UpdateSecret {
+            secret_id: secret_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Reveal a secret to the whole world and apply its NPC reactions
+    pub async fn reveal_secret(&self, secret_id: &str) -> Result<PcSecretData, ServiceError> {
+        self.request(SecretRequest::RevealSecret {
+            secret_id: secret_id.to_string(),
+        })
+        .await
+    }
+
+    pub async fn delete_secret(&self, secret_id: &str) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Secret(SecretRequest::DeleteSecret {
+                    secret_id: secret_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: SecretRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Secret(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
@@ -4,7 +4,10 @@
 //! updating the Engine's application settings. It abstracts away
 //! the HTTP client details from the presentation layer.
 
-use crate::application::dto::{AppSettings, SettingsFieldMetadata};
+use crate::application::dto::{
+    AppSettings, ConnectionReportData, ServiceConnectionsData, SettingsFieldMetadata,
+    SetupSaveOutcome, SetupStateData,
+};
 use crate::ports::outbound::{ApiError, ApiPort};
 
 /// Settings service for managing Engine application settings
@@ -112,4 +115,31 @@ impl<A: ApiPort> SettingsService<A> {
     pub async fn get_metadata(&self) -> Result<Vec<SettingsFieldMetadata>, ApiError> {
         self.api.get("/api/settings/metadata").await
     }
+
+    // =========================================================================
+    // First-run Setup
+    // =========================================================================
+
+    /// Get setup state with live checks of the Engine's current connections
+    pub async fn get_setup(&self) -> Result<SetupStateData, ApiError> {
+        self.api.get("/api/setup").await
+    }
+
+    /// Check candidate connections without saving them
+    pub async fn check_setup(
+        &self,
+        connections: &ServiceConnectionsData,
+    ) -> Result<ConnectionReportData, ApiError> {
+        self.api.post("/api/setup/check", connections).await
+    }
+
+    /// Save connections if every live check passes
+    ///
+    /// The Engine applies saved connections on its next restart.
+    pub async fn save_setup(
+        &self,
+        connections: &ServiceConnectionsData,
+    ) -> Result<SetupSaveOutcome, ApiError> {
+        self.api.put("/api/setup", connections).await
+    }
 }
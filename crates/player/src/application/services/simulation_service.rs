@@ -0,0 +1,80 @@
+//! Simulation Service - Application service for off-screen world changes
+//!
+//! When the DM skips a day or more, the engine works out what the world got
+//! up to in the meantime. The DM trims the proposed changes, then accepts or
+//! discards them.
+
+use crate::application::dto::{SimulationChangeData, WorldSimulationData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{RequestPayload, SimulationRequest};
+
+/// World simulation service
+#[derive(Clone)]
+pub struct SimulationService {
+    commands: CommandBus,
+}
+
+impl SimulationService {
+    /// Create a new SimulationService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Simulations awaiting review, oldest first (DM only)
+    pub async fn list_simulations(&self) -> Result<Vec<WorldSimulationData>, ServiceError> {
+        self.request(SimulationRequest::ListSimulations).await
+    }
+
+    /// Keep only the given changes, with the DM's amounts (DM only)
+    pub async fn update_simulation(
+        &self,
+        simulation_id: &str,
+        changes: Vec<SimulationChangeData>,
+    ) -> Result<WorldSimulationData, ServiceError> {
+        self.request(SimulationRequest::UpdateSimulation {
+            simulation_id: simulation_id.to_string(),
+            changes,
+        })
+        .await
+    }
+
+    /// Apply the changes to the world (DM only)
+    pub async fn accept_simulation(
+        &self,
+        simulation_id: &str,
+    ) -> Result<WorldSimulationData, ServiceError> {
+        self.request(SimulationRequest::AcceptSimulation {
+            simulation_id: simulation_id.to_string(),
+        })
+        .await
+    }
+
+    /// Drop a simulation without changing anything (DM only)
+    pub async fn discard_simulation(&self, simulation_id: &str) -> Result<(), ServiceError> {
+        self.commands
+            .request_with_timeout(
+                RequestPayload::Simulation(SimulationRequest::DiscardSimulation {
+                    simulation_id: simulation_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?
+            .parse_empty()
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: SimulationRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Simulation(request),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+}
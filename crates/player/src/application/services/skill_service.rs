@@ -85,6 +85,7 @@ impl SkillService {
             .request_with_timeout(
                 RequestPayload::Skill(SkillRequest::ListSkills {
                     world_id: world_id.to_string(),
+                    tags: Vec::new(),
                 }),
                 get_request_timeout_ms(),
             )
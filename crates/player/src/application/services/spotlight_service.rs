@@ -0,0 +1,67 @@
+//! Spotlight Service - Application service for spotlight rotation
+//!
+//! Reads the spotlight tracker, and lets the DM change its settings, pass
+//! the spotlight on, hand it to a PC or clear the history. Everything but
+//! reading is DM-only; players get an empty rotation.
+
+use crate::application::dto::{SpotlightData, SpotlightSettingsData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{RequestPayload, SpotlightRequest};
+
+/// Spotlight service
+#[derive(Clone)]
+pub struct SpotlightService {
+    commands: CommandBus,
+}
+
+impl SpotlightService {
+    /// Create a new SpotlightService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// The world's spotlight tracker
+    pub async fn get_spotlight(&self) -> Result<SpotlightData, ServiceError> {
+        self.request(SpotlightRequest::GetSpotlight).await
+    }
+
+    /// Switch the tracker on or off and set its thresholds
+    pub async fn update_settings(
+        &self,
+        data: SpotlightSettingsData,
+    ) -> Result<SpotlightData, ServiceError> {
+        self.request(SpotlightRequest::UpdateSpotlightSettings { data })
+            .await
+    }
+
+    /// Pass the spotlight to the PC who has gone longest without acting
+    pub async fn rotate(&self) -> Result<SpotlightData, ServiceError> {
+        self.request(SpotlightRequest::RotateSpotlight).await
+    }
+
+    /// Hand the spotlight to a PC, or clear it with `None`
+    pub async fn set_spotlight(
+        &self,
+        pc_id: Option<String>,
+    ) -> Result<SpotlightData, ServiceError> {
+        self.request(SpotlightRequest::SetSpotlight { pc_id }).await
+    }
+
+    /// Forget who acted, keeping the settings
+    pub async fn reset(&self) -> Result<SpotlightData, ServiceError> {
+        self.request(SpotlightRequest::ResetSpotlight).await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: SpotlightRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Spotlight(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+}
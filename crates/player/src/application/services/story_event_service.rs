@@ -5,10 +5,10 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::application::dto::StoryEventData;
+use crate::application::dto::{MapMarkerData, StoryEventData};
 use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
 use crate::infrastructure::messaging::CommandBus;
-use wrldbldr_protocol::{RequestPayload, StoryEventRequest};
+use wrldbldr_protocol::{MarkerPinData, RequestPayload, StoryEventRequest};
 
 /// Paginated response wrapper from Engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +28,11 @@ pub struct CreateDmMarkerRequest {
     pub marker_type: String,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    /// Map position, for markers placed on a map
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pin: Option<MarkerPinData>,
+    /// Keep the marker hidden from players
+    pub hidden: bool,
 }
 
 // From impl for protocol conversion at the boundary
@@ -36,6 +41,8 @@ impl From<&CreateDmMarkerRequest> for wrldbldr_protocol::requests::CreateDmMarke
         Self {
             title: req.title.clone(),
             content: Some(req.note.clone()),
+            pin: req.pin.clone(),
+            hidden: req.hidden,
         }
     }
 }
@@ -67,6 +74,7 @@ impl StoryEventService {
                     world_id: world_id.to_string(),
                     page: None,
                     page_size: None,
+                    tags: Vec::new(),
                 }),
                 get_request_timeout_ms(),
             )
@@ -115,4 +123,46 @@ impl StoryEventService {
 
         result.parse_empty()
     }
+
+    /// List DM markers pinned to a location map, or a region's backdrop
+    pub async fn list_map_markers(
+        &self,
+        world_id: &str,
+        location_id: &str,
+        region_id: Option<&str>,
+    ) -> Result<Vec<MapMarkerData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::StoryEvent(StoryEventRequest::ListMapMarkers {
+                    world_id: world_id.to_string(),
+                    location_id: location_id.to_string(),
+                    region_id: region_id.map(str::to_string),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+
+    /// Move a DM marker's pin, or take it off the map with `None`
+    pub async fn pin_dm_marker(
+        &self,
+        event_id: &str,
+        pin: Option<MarkerPinData>,
+    ) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::StoryEvent(StoryEventRequest::PinDmMarker {
+                    event_id: event_id.to_string(),
+                    pin,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse_empty()
+    }
 }
@@ -0,0 +1,146 @@
+//! Tag Service - Application service for entity tags and saved filters
+//!
+//! Reads and replaces the DM's freeform tags on world entities, renames or
+//! deletes a tag across the world, and manages the current user's saved tag
+//! filters. All tag requests are DM-only. Tag changes are also broadcast to
+//! DMs as `EntityTagsUpdated`/`WorldTagsUpdated` events.
+
+use crate::application::dto::{SavedFilterData, TagUsageData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{EntityType, RequestPayload, SaveFilterData, TagRequest};
+
+/// Entity tag service
+#[derive(Clone)]
+pub struct TagService {
+    commands: CommandBus,
+}
+
+impl TagService {
+    /// Create a new TagService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Get the tags on one entity
+    pub async fn get_entity_tags(
+        &self,
+        world_id: &str,
+        entity_type: EntityType,
+        entity_id: &str,
+    ) -> Result<Vec<String>, ServiceError> {
+        self.request(TagRequest::GetEntityTags {
+            world_id: world_id.to_string(),
+            entity_type,
+            entity_id: entity_id.to_string(),
+        })
+        .await
+    }
+
+    /// Replace the tags on one entity, returning them as normalized by the Engine
+    pub async fn set_entity_tags(
+        &self,
+        world_id: &str,
+        entity_type: EntityType,
+        entity_id: &str,
+        tags: Vec<String>,
+    ) -> Result<Vec<String>, ServiceError> {
+        self.request(TagRequest::SetEntityTags {
+            world_id: world_id.to_string(),
+            entity_type,
+            entity_id: entity_id.to_string(),
+            tags,
+        })
+        .await
+    }
+
+    /// List every tag used in a world with how many entities carry it
+    pub async fn list_world_tags(&self, world_id: &str) -> Result<Vec<TagUsageData>, ServiceError> {
+        self.request(TagRequest::ListWorldTags {
+            world_id: world_id.to_string(),
+        })
+        .await
+    }
+
+    /// Rename a tag on every entity in the world
+    pub async fn rename_tag(
+        &self,
+        world_id: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<(), ServiceError> {
+        self.request_empty(TagRequest::RenameTag {
+            world_id: world_id.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+        .await
+    }
+
+    /// Remove a tag from every entity in the world
+    pub async fn delete_tag(&self, world_id: &str, tag: &str) -> Result<(), ServiceError> {
+        self.request_empty(TagRequest::DeleteTag {
+            world_id: world_id.to_string(),
+            tag: tag.to_string(),
+        })
+        .await
+    }
+
+    /// List the current user's saved filters
+    pub async fn list_saved_filters(
+        &self,
+        world_id: &str,
+    ) -> Result<Vec<SavedFilterData>, ServiceError> {
+        self.request(TagRequest::ListSavedFilters {
+            world_id: world_id.to_string(),
+        })
+        .await
+    }
+
+    /// Create a saved filter, or replace one when `data.filter_id` is set
+    pub async fn save_filter(
+        &self,
+        world_id: &str,
+        data: SaveFilterData,
+    ) -> Result<SavedFilterData, ServiceError> {
+        self.request(TagRequest::SaveFilter {
+            world_id: world_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Delete one of the current user's saved filters
+    pub async fn delete_saved_filter(
+        &self,
+        world_id: &str,
+        filter_id: &str,
+    ) -> Result<(), ServiceError> {
+        self.request_empty(TagRequest::DeleteSavedFilter {
+            world_id: world_id.to_string(),
+            filter_id: filter_id.to_string(),
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: TagRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Tag(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+
+    async fn request_empty(&self, request: TagRequest) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Tag(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse_empty()
+    }
+}
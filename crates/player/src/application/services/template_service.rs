@@ -0,0 +1,134 @@
+//! Template Service - Application service for entity templates
+//!
+//! Lists, edits and deletes the world's NPC and region templates, saves
+//! existing characters and regions as templates, and stamps templates out
+//! into new entities. All template requests are DM-only.
+
+use std::collections::BTreeMap;
+
+use crate::application::dto::{CreatedEntityData, EntityTemplateData};
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{RequestPayload, SaveTemplateData, TemplateRequest};
+
+/// Entity template service
+#[derive(Clone)]
+pub struct TemplateService {
+    commands: CommandBus,
+}
+
+impl TemplateService {
+    /// Create a new TemplateService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// List a world's templates, sorted by name
+    pub async fn list_templates(
+        &self,
+        world_id: &str,
+    ) -> Result<Vec<EntityTemplateData>, ServiceError> {
+        self.request(TemplateRequest::ListTemplates {
+            world_id: world_id.to_string(),
+        })
+        .await
+    }
+
+    /// Create a template from scratch
+    pub async fn create_template(
+        &self,
+        world_id: &str,
+        data: SaveTemplateData,
+    ) -> Result<EntityTemplateData, ServiceError> {
+        self.request(TemplateRequest::CreateTemplate {
+            world_id: world_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Replace a template's name, description and body
+    pub async fn update_template(
+        &self,
+        template_id: &str,
+        data: SaveTemplateData,
+    ) -> Result<EntityTemplateData, ServiceError> {
+        self.request(TemplateRequest::UpdateTemplate {
+            template_id: template_id.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Delete a template; entities made from it are kept
+    pub async fn delete_template(&self, template_id: &str) -> Result<(), ServiceError> {
+        self.request_empty(TemplateRequest::DeleteTemplate {
+            template_id: template_id.to_string(),
+        })
+        .await
+    }
+
+    /// Save an NPC as a template
+    pub async fn save_character_as_template(
+        &self,
+        character_id: &str,
+        name: &str,
+    ) -> Result<EntityTemplateData, ServiceError> {
+        self.request(TemplateRequest::SaveCharacterAsTemplate {
+            character_id: character_id.to_string(),
+            name: name.to_string(),
+        })
+        .await
+    }
+
+    /// Save a region with its items and NPCs as a template
+    pub async fn save_region_as_template(
+        &self,
+        region_id: &str,
+        name: &str,
+    ) -> Result<EntityTemplateData, ServiceError> {
+        self.request(TemplateRequest::SaveRegionAsTemplate {
+            region_id: region_id.to_string(),
+            name: name.to_string(),
+        })
+        .await
+    }
+
+    /// Create entities from a template. Region templates need `location_id`.
+    pub async fn instantiate_template(
+        &self,
+        template_id: &str,
+        name: &str,
+        variables: BTreeMap<String, String>,
+        location_id: Option<String>,
+    ) -> Result<Vec<CreatedEntityData>, ServiceError> {
+        self.request(TemplateRequest::InstantiateTemplate {
+            template_id: template_id.to_string(),
+            name: name.to_string(),
+            variables,
+            location_id,
+        })
+        .await
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: TemplateRequest,
+    ) -> Result<T, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Template(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse()
+    }
+
+    async fn request_empty(&self, request: TemplateRequest) -> Result<(), ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(RequestPayload::Template(request), get_request_timeout_ms())
+            .await?;
+
+        result.parse_empty()
+    }
+}
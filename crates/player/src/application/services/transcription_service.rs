@@ -0,0 +1,44 @@
+//! Transcription Service - Application service for voice input
+//!
+//! Sends a clip recorded in the browser to the Engine, which transcribes it
+//! with the configured Whisper server. The text comes back to the player to
+//! review; nothing is sent to the NPC until they press Send.
+
+use crate::application::dto::TranscriptionData;
+use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
+use crate::infrastructure::messaging::CommandBus;
+use wrldbldr_protocol::{RequestPayload, TranscriptionRequest};
+
+/// Transcription service
+#[derive(Clone)]
+pub struct TranscriptionService {
+    commands: CommandBus,
+}
+
+impl TranscriptionService {
+    /// Create a new TranscriptionService with the given command bus
+    pub fn new(commands: CommandBus) -> Self {
+        Self { commands }
+    }
+
+    /// Transcribe base64-encoded audio of the given MIME type
+    /// (e.g. "audio/webm;codecs=opus")
+    pub async fn transcribe(
+        &self,
+        audio: String,
+        content_type: &str,
+    ) -> Result<TranscriptionData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Transcription(TranscriptionRequest::TranscribeAudio {
+                    audio,
+                    content_type: content_type.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        result.parse()
+    }
+}
@@ -51,6 +51,9 @@ pub struct WorkflowConfig {
     pub name: String,
     pub analysis: WorkflowAnalysis,
     pub prompt_mappings: Vec<PromptMapping>,
+    /// Inputs that receive a character's canonical portrait (IPAdapter)
+    #[serde(default)]
+    pub reference_image_mappings: Vec<ReferenceImageMapping>,
     pub input_defaults: Vec<InputDefault>,
     pub locked_inputs: Vec<String>,
     pub created_at: String,
@@ -87,6 +90,14 @@ pub struct PromptMapping {
     pub mapping_type: String, // "primary" or "negative"
 }
 
+/// Reference image input (e.g. a LoadImage node feeding an IPAdapter)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceImageMapping {
+    pub node_id: String,
+    pub input_name: String,
+}
+
 /// Input default value
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -104,6 +115,8 @@ pub struct AnalyzeWorkflowResponse {
     pub analysis: WorkflowAnalysis,
     pub suggested_prompt_mappings: Vec<PromptMapping>,
     #[serde(default)]
+    pub suggested_reference_image_mappings: Vec<ReferenceImageMapping>,
+    #[serde(default)]
     pub errors: Vec<String>,
 }
 
@@ -114,6 +127,7 @@ pub struct SaveWorkflowRequest {
     pub name: String,
     pub workflow_json: serde_json::Value,
     pub prompt_mappings: Vec<serde_json::Value>,
+    pub reference_image_mappings: Vec<ReferenceImageMapping>,
     pub input_defaults: Vec<InputDefault>,
     pub locked_inputs: Vec<String>,
 }
@@ -168,29 +182,15 @@ impl<A: ApiPort> WorkflowService<A> {
     ///
     /// # Arguments
     /// * `slot_id` - The slot identifier
-    /// * `name` - Workflow name
-    /// * `workflow_json` - The ComfyUI workflow JSON
-    /// * `prompt_mappings` - List of prompt mapping configurations
-    /// * `input_defaults` - List of input default values
-    /// * `locked_inputs` - List of locked input identifiers
+    /// * `request` - Workflow name, JSON, prompt and reference image mappings,
+    ///   input defaults and locked inputs
     pub async fn save_workflow_config(
         &self,
         slot_id: &str,
-        name: &str,
-        workflow_json: serde_json::Value,
-        prompt_mappings: Vec<serde_json::Value>,
-        input_defaults: Vec<InputDefault>,
-        locked_inputs: Vec<String>,
+        request: &SaveWorkflowRequest,
     ) -> Result<(), ApiError> {
         let path = format!("/api/workflows/{}", slot_id);
-        let request = SaveWorkflowRequest {
-            name: name.to_string(),
-            workflow_json,
-            prompt_mappings,
-            input_defaults,
-            locked_inputs,
-        };
-        self.api.post_no_response(&path, &request).await
+        self.api.post_no_response(&path, request).await
     }
 
     /// Delete workflow configuration from a slot
@@ -4,16 +4,23 @@
 //! and creating worlds. It uses WebSocket for real-time operations and
 //! REST for specific endpoints that remain HTTP-only.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
 use crate::infrastructure::messaging::CommandBus;
 use crate::ports::outbound::{ApiError, RawApiPort};
-use wrldbldr_protocol::ErrorCode;
-use wrldbldr_protocol::{RequestPayload, WorldRequest};
+use wrldbldr_protocol::{EntityType, ErrorCode};
+use wrldbldr_protocol::{RequestPayload, TutorialRequest, WorldRequest};
 
 use crate::application::dto::requests::CreateWorldRequest;
+use crate::application::dto::{
+    ContentSafetyData, CustomFieldDefinitionData, CustomFieldEntryData, CustomFieldValueData,
+    RuleSystemSwitchData, RuleSystemVariant, SessionZeroData, SkillOverrideData,
+    TutorialStatusData, WorldFeaturesData, WorldMapData, WorldScriptData, WorldThemeData,
+    WorldTypographyData,
+};
 use crate::application::{get_request_timeout_ms, ParseResponse, ServiceError};
 
 /// Summary of a world for list views
@@ -22,6 +29,185 @@ pub struct WorldSummary {
     pub id: String,
     pub name: String,
     pub description: Option<String>,
+    /// The world this one was branched from, for alternate timelines
+    #[serde(default, rename = "branchedFrom")]
+    pub branched_from: Option<String>,
+}
+
+/// One day of a world's usage
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DailyUsage {
+    /// UTC day, as YYYY-MM-DD
+    pub day: String,
+    pub llm_requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub image_generations: u64,
+    pub storage_bytes: u64,
+}
+
+/// Totals across a usage report
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsageTotals {
+    pub llm_requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub image_generations: u64,
+    pub storage_bytes: u64,
+}
+
+/// A world's daily quotas (None = unlimited)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsageQuotas {
+    pub daily_token_quota: Option<u64>,
+    pub daily_image_quota: Option<u64>,
+}
+
+/// A quota today's usage is already over
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QuotaWarning {
+    /// "tokens" or "images"
+    pub resource: String,
+    pub used: u64,
+    pub quota: u64,
+}
+
+/// A world's LLM, image and storage usage over recent days
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsageReport {
+    /// First day covered, as YYYY-MM-DD
+    pub since: String,
+    /// Days with any usage, oldest first
+    pub days: Vec<DailyUsage>,
+    pub totals: UsageTotals,
+    pub quotas: UsageQuotas,
+    #[serde(default)]
+    pub warnings: Vec<QuotaWarning>,
+}
+
+/// A zipped diagnostics bundle for a bug report
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DiagnosticsBundle {
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: u64,
+    /// Zip contents, base64-encoded
+    pub data: String,
+}
+
+/// One PC's share of the spotlight
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PcSpotlight {
+    pub pc_id: String,
+    pub name: String,
+    pub dialogue_exchanges: u32,
+    pub challenges: u32,
+    pub story_events: u32,
+}
+
+/// How often an NPC has been spoken to
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NpcInteractions {
+    pub npc_id: String,
+    pub name: String,
+    pub dialogue_exchanges: u32,
+    /// PCs who have spoken to them
+    pub pcs: u32,
+}
+
+/// How often a location has been visited
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LocationHeat {
+    pub location_id: String,
+    pub name: String,
+    pub visits: u32,
+    /// PCs who have been there
+    pub visitors: u32,
+}
+
+/// Where a world's play has gone; each list runs quietest first
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CampaignAnalytics {
+    pub pcs: Vec<PcSpotlight>,
+    pub npcs: Vec<NpcInteractions>,
+    pub locations: Vec<LocationHeat>,
+}
+
+/// An active challenge no PC has attempted
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnusedChallenge {
+    pub challenge_id: String,
+    pub name: String,
+    pub suggestion: String,
+}
+
+/// An NPC no PC has seen or spoken to
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnmetNpc {
+    pub npc_id: String,
+    pub name: String,
+    pub suggestion: String,
+}
+
+/// Lore no PC has discovered
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UndiscoveredLore {
+    pub lore_id: String,
+    pub title: String,
+    pub suggestion: String,
+}
+
+/// A narrative event whose triggers can no longer all be met
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StuckEvent {
+    pub event_id: String,
+    pub name: String,
+    /// Descriptions of the triggers that can never fire
+    pub dead_triggers: Vec<String>,
+    pub suggestion: String,
+}
+
+/// Content a world's DM authored that play has never reached
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnusedContentReport {
+    pub challenges: Vec<UnusedChallenge>,
+    pub npcs: Vec<UnmetNpc>,
+    pub lore: Vec<UndiscoveredLore>,
+    pub events: Vec<StuckEvent>,
+}
+
+/// A region the party may reach with no current staging
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnstagedRegion {
+    pub region_id: String,
+    pub name: String,
+    pub location_name: String,
+    /// Why the party may end up here
+    pub reason: String,
+}
+
+/// An NPC who may turn up nearby but has no portrait
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NpcWithoutPortrait {
+    pub npc_id: String,
+    pub name: String,
+    pub region_name: String,
+}
+
+/// An active challenge with a success or failure outcome left blank
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChallengeWithoutOutcomes {
+    pub challenge_id: String,
+    pub name: String,
+    pub blank: Vec<String>,
+}
+
+/// What still needs prepping where the party is and may go next
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PrepChecklist {
+    pub unstaged_regions: Vec<UnstagedRegion>,
+    pub npcs_without_portraits: Vec<NpcWithoutPortrait>,
+    pub challenges_without_outcomes: Vec<ChallengeWithoutOutcomes>,
 }
 
 /// World service for managing worlds
@@ -108,6 +294,76 @@ impl WorldService {
         Ok(response.id)
     }
 
+    /// Create a world from a session zero's choices, with its starting
+    /// location and region and an NPC draft per seed
+    ///
+    /// # Returns
+    /// The ID of the created world
+    pub async fn create_session_zero_world(
+        &self,
+        data: SessionZeroData,
+    ) -> Result<String, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::CreateSessionZeroWorld { data }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        #[derive(Deserialize)]
+        struct CreateResponse {
+            id: String,
+        }
+
+        let response: CreateResponse = result.parse()?;
+        Ok(response.id)
+    }
+
+    /// Create a fresh copy of the bundled tutorial world
+    ///
+    /// # Returns
+    /// The ID of the created world
+    pub async fn create_tutorial_world(&self) -> Result<String, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Tutorial(TutorialRequest::CreateTutorialWorld),
+                get_request_timeout_ms(),
+            )
+            .await?;
+
+        #[derive(Deserialize)]
+        struct CreateResponse {
+            id: String,
+        }
+
+        let response: CreateResponse = result.parse()?;
+        Ok(response.id)
+    }
+
+    /// Fetch a PC's tutorial progress (`None` if the world has no tutorial)
+    pub async fn get_tutorial_status(
+        &self,
+        world_id: &str,
+        pc_id: &str,
+    ) -> Result<Option<TutorialStatusData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::Tutorial(TutorialRequest::GetTutorialStatus {
+                    world_id: world_id.to_string(),
+                    pc_id: pc_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        // The engine answers `null` for worlds without a tutorial
+        Ok(result
+            .parse_optional::<Option<TutorialStatusData>>()?
+            .flatten())
+    }
+
     /// Delete a world by ID
     pub async fn delete_world(&self, id: &str) -> Result<(), ServiceError> {
         let result = self
@@ -154,6 +410,421 @@ impl WorldService {
             .await?;
         result.parse()
     }
+
+    /// Fetch a world's content safety settings (lines, veils, rating)
+    pub async fn get_content_safety(
+        &self,
+        world_id: &str,
+    ) -> Result<ContentSafetyData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GetContentSafety {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Replace a world's content safety settings (DM only)
+    ///
+    /// Returns the settings as stored, after the engine normalizes them.
+    pub async fn update_content_safety(
+        &self,
+        world_id: &str,
+        config: ContentSafetyData,
+    ) -> Result<ContentSafetyData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::UpdateContentSafety {
+                    world_id: world_id.to_string(),
+                    config,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Fetch a world's typography (text direction, language, font)
+    pub async fn get_typography(
+        &self,
+        world_id: &str,
+    ) -> Result<WorldTypographyData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GetTypography {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Replace a world's typography (DM only); connected players are updated live
+    pub async fn update_typography(
+        &self,
+        world_id: &str,
+        typography: WorldTypographyData,
+    ) -> Result<WorldTypographyData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::UpdateTypography {
+                    world_id: world_id.to_string(),
+                    typography,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Fetch a world's theme (accent color, backdrop frame)
+    /// Get a world's usage for the last `days` days (DM only)
+    pub async fn get_usage(
+        &self,
+        world_id: &str,
+        days: Option<u32>,
+    ) -> Result<UsageReport, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GetUsage {
+                    world_id: world_id.to_string(),
+                    days,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Get a world's campaign analytics (DM only)
+    pub async fn get_analytics(&self, world_id: &str) -> Result<CampaignAnalytics, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GetAnalytics {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Get a world's unused content, with suggestions for bringing it back (DM only)
+    pub async fn get_unused_content(
+        &self,
+        world_id: &str,
+    ) -> Result<UnusedContentReport, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GetUnusedContent {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Generate a world's prep checklist for the next session (DM only)
+    pub async fn generate_prep_checklist(
+        &self,
+        world_id: &str,
+    ) -> Result<PrepChecklist, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GeneratePrepChecklist {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Branch a world into a new, separate world for an alternate timeline
+    /// (DM only). The original world is left untouched.
+    pub async fn branch_world(
+        &self,
+        world_id: &str,
+        name: &str,
+    ) -> Result<WorldSummary, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::BranchWorld {
+                    world_id: world_id.to_string(),
+                    name: name.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Export a world's diagnostics bundle (DM only): recent logs, queue
+    /// items and protocol message counts, zipped
+    pub async fn export_diagnostics(
+        &self,
+        world_id: &str,
+    ) -> Result<DiagnosticsBundle, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::ExportDiagnostics {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    pub async fn get_theme(&self, world_id: &str) -> Result<WorldThemeData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GetTheme {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Replace a world's theme (DM only); connected players are updated live
+    pub async fn update_theme(
+        &self,
+        world_id: &str,
+        theme: WorldThemeData,
+    ) -> Result<WorldThemeData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::UpdateTheme {
+                    world_id: world_id.to_string(),
+                    theme,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Fetch a world's overview map and the locations pinned to it
+    pub async fn get_world_map(&self, world_id: &str) -> Result<WorldMapData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GetWorldMap {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Replace a world's overview map image, or clear it with `None` (DM only)
+    pub async fn set_world_map_asset(
+        &self,
+        world_id: &str,
+        map_asset: Option<String>,
+    ) -> Result<WorldMapData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::SetWorldMapAsset {
+                    world_id: world_id.to_string(),
+                    map_asset,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Fetch which experimental subsystems are switched on for a world
+    pub async fn get_features(&self, world_id: &str) -> Result<WorldFeaturesData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GetFeatures {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Switch experimental subsystems on or off (DM only); connected players
+    /// are updated live
+    pub async fn update_features(
+        &self,
+        world_id: &str,
+        features: WorldFeaturesData,
+    ) -> Result<WorldFeaturesData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::UpdateFeatures {
+                    world_id: world_id.to_string(),
+                    features,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// What changing a world to another rule system would do to its skills,
+    /// challenges and character sheets (DM only)
+    pub async fn preview_rule_system_change(
+        &self,
+        world_id: &str,
+        variant: RuleSystemVariant,
+    ) -> Result<RuleSystemSwitchData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::PreviewRuleSystemChange {
+                    world_id: world_id.to_string(),
+                    variant,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Change a world to another rule system, remapping its challenges and
+    /// converting its character sheets (DM only)
+    pub async fn change_rule_system(
+        &self,
+        world_id: &str,
+        variant: RuleSystemVariant,
+        skill_overrides: Vec<SkillOverrideData>,
+    ) -> Result<RuleSystemSwitchData, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::ChangeRuleSystem {
+                    world_id: world_id.to_string(),
+                    variant,
+                    skill_overrides,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Fetch a world's DM automation scripts (DM only)
+    pub async fn get_scripts(&self, world_id: &str) -> Result<Vec<WorldScriptData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GetScripts {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Replace a world's DM automation scripts (DM only); the engine rejects
+    /// scripts that don't compile
+    pub async fn update_scripts(
+        &self,
+        world_id: &str,
+        scripts: Vec<WorldScriptData>,
+    ) -> Result<Vec<WorldScriptData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::UpdateScripts {
+                    world_id: world_id.to_string(),
+                    scripts,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Fetch a world's custom field schema (DM only)
+    pub async fn get_custom_fields(
+        &self,
+        world_id: &str,
+    ) -> Result<Vec<CustomFieldDefinitionData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::GetCustomFields {
+                    world_id: world_id.to_string(),
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Replace a world's custom field schema (DM only); the engine
+    /// normalizes keys and rejects duplicates
+    pub async fn update_custom_fields(
+        &self,
+        world_id: &str,
+        fields: Vec<CustomFieldDefinitionData>,
+    ) -> Result<Vec<CustomFieldDefinitionData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::UpdateCustomFields {
+                    world_id: world_id.to_string(),
+                    fields,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
+
+    /// Replace one entity's custom field values (DM only)
+    pub async fn set_custom_field_values(
+        &self,
+        world_id: &str,
+        entity_type: EntityType,
+        entity_id: &str,
+        values: BTreeMap<String, CustomFieldValueData>,
+    ) -> Result<Vec<CustomFieldEntryData>, ServiceError> {
+        let result = self
+            .commands
+            .request_with_timeout(
+                RequestPayload::World(WorldRequest::SetCustomFieldValues {
+                    world_id: world_id.to_string(),
+                    entity_type,
+                    entity_id: entity_id.to_string(),
+                    values,
+                }),
+                get_request_timeout_ms(),
+            )
+            .await?;
+        result.parse()
+    }
 }
 
 impl Clone for WorldService {
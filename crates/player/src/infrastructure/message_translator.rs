@@ -71,6 +71,13 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
         },
 
         ServerMessage::UserLeft { user_id } => PlayerEvent::UserLeft { user_id },
+        ServerMessage::LocaleChanged {
+            locale,
+            supported_locales,
+        } => PlayerEvent::LocaleChanged {
+            locale,
+            supported_locales,
+        },
 
         ServerMessage::Pong => PlayerEvent::Pong,
 
@@ -94,12 +101,14 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
             npcs_present,
             navigation,
             region_items,
+            crowds_present,
         } => PlayerEvent::SceneChanged {
             pc_id,
             region,
             npcs_present,
             navigation,
             region_items,
+            crowds_present,
         },
 
         ServerMessage::PcSelected {
@@ -118,6 +127,18 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
             PlayerEvent::MovementBlocked { pc_id, reason }
         }
 
+        ServerMessage::JourneyInterrupted { journey } => {
+            PlayerEvent::JourneyInterrupted { journey }
+        }
+
+        ServerMessage::JourneyResolved { journey, decision } => {
+            PlayerEvent::JourneyResolved { journey, decision }
+        }
+
+        ServerMessage::ConnectionUnlocked { connection } => {
+            PlayerEvent::ConnectionUnlocked { connection }
+        }
+
         ServerMessage::SplitPartyNotification {
             location_count,
             locations,
@@ -151,6 +172,8 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
             queue_depth,
         },
 
+        ServerMessage::ActionRetracted { action_id } => PlayerEvent::ActionRetracted { action_id },
+
         ServerMessage::LLMProcessing { action_id } => PlayerEvent::LLMProcessing { action_id },
 
         ServerMessage::QueueStatus {
@@ -227,6 +250,11 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
             proposed_tools,
             challenge_suggestion,
             narrative_event_suggestion,
+            safety_warnings,
+            conversation_id,
+            source_action_id,
+            awaiting_player,
+            scene_id,
         } => PlayerEvent::ApprovalRequired {
             request_id,
             npc_name,
@@ -235,6 +263,21 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
             proposed_tools, // Direct assignment - same type now
             challenge_suggestion,
             narrative_event_suggestion,
+            safety_warnings,
+            conversation_id,
+            source_action_id,
+            awaiting_player,
+            scene_id,
+        },
+
+        ServerMessage::BulkApprovalApplied {
+            decided,
+            failed,
+            accepted,
+        } => PlayerEvent::BulkApprovalApplied {
+            decided,
+            failed,
+            accepted,
         },
 
         // =====================================================================
@@ -408,6 +451,48 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
             region_id,
             description,
         },
+        ServerMessage::NoiseHeard {
+            region_id,
+            from_region_id,
+            from_region_name,
+            description,
+            muffled,
+        } => PlayerEvent::NoiseHeard {
+            region_id,
+            from_region_id,
+            from_region_name,
+            description,
+            muffled,
+        },
+        ServerMessage::NoiseReport {
+            region_id,
+            region_name,
+            description,
+            loudness,
+            heard_in,
+        } => PlayerEvent::NoiseReport {
+            region_id,
+            region_name,
+            description,
+            loudness,
+            heard_in,
+        },
+
+        ServerMessage::ShowOverlay {
+            overlay_id,
+            asset,
+            caption,
+            duration_secs,
+        } => PlayerEvent::ShowOverlay {
+            overlay_id,
+            asset,
+            caption,
+            duration_secs,
+        },
+
+        ServerMessage::OverlayDismissed { overlay_id } => {
+            PlayerEvent::OverlayDismissed { overlay_id }
+        }
 
         ServerMessage::NpcLocationShared {
             npc_id,
@@ -507,6 +592,18 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
             discovery_source,
         },
 
+        ServerMessage::KnowledgeShared {
+            from_pc_id,
+            from_pc_name,
+            to_pc_ids,
+            title,
+        } => PlayerEvent::KnowledgeShared {
+            from_pc_id,
+            from_pc_name,
+            to_pc_ids,
+            title,
+        },
+
         ServerMessage::LoreRevoked {
             character_id,
             lore_id,
@@ -572,6 +669,22 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
 
         ServerMessage::InventoryUpdated { pc_id } => PlayerEvent::InventoryUpdated { pc_id },
 
+        ServerMessage::TradeOffered { trade } => PlayerEvent::TradeOffered { trade },
+
+        ServerMessage::TradeCompleted { trade } => PlayerEvent::TradeCompleted { trade },
+
+        ServerMessage::TradeClosed {
+            trade_id,
+            reason,
+            message,
+        } => PlayerEvent::TradeClosed {
+            trade_id,
+            reason,
+            message,
+        },
+
+        ServerMessage::TutorialProgress { status } => PlayerEvent::TutorialProgress { status },
+
         // =====================================================================
         // Character Events
         // =====================================================================
@@ -809,6 +922,44 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
             retry_in_seconds,
         },
 
+        ServerMessage::DependencyStatusChanged {
+            dependency,
+            healthy,
+            message,
+        } => PlayerEvent::DependencyStatusChanged {
+            dependency,
+            healthy,
+            message,
+        },
+
+        ServerMessage::UsageQuotaWarning {
+            world_id,
+            resource,
+            used,
+            quota,
+        } => PlayerEvent::UsageQuotaWarning {
+            world_id,
+            resource,
+            used,
+            quota,
+        },
+
+        ServerMessage::ModelPullProgress {
+            model,
+            status,
+            completed_bytes,
+            total_bytes,
+            done,
+            error,
+        } => PlayerEvent::ModelPullProgress {
+            model,
+            status,
+            completed_bytes,
+            total_bytes,
+            done,
+            error,
+        },
+
         // =====================================================================
         // Time Events
         // =====================================================================
@@ -852,6 +1003,65 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
             show_time_to_players: config.show_time_to_players,
         },
 
+        ServerMessage::WorldTypographyUpdated {
+            world_id,
+            typography,
+        } => PlayerEvent::WorldTypographyUpdated {
+            world_id,
+            typography,
+        },
+
+        ServerMessage::WorldThemeUpdated { world_id, theme } => {
+            PlayerEvent::WorldThemeUpdated { world_id, theme }
+        }
+
+        ServerMessage::WorldMapUpdated { world_id, map } => {
+            PlayerEvent::WorldMapUpdated { world_id, map }
+        }
+
+        ServerMessage::WorldFeaturesUpdated { world_id, features } => {
+            PlayerEvent::WorldFeaturesUpdated { world_id, features }
+        }
+
+        ServerMessage::WorldScriptsUpdated { world_id, scripts } => {
+            PlayerEvent::WorldScriptsUpdated { world_id, scripts }
+        }
+
+        ServerMessage::CustomFieldsUpdated { world_id, fields } => {
+            PlayerEvent::CustomFieldsUpdated { world_id, fields }
+        }
+
+        ServerMessage::ScriptNotice {
+            world_id,
+            script,
+            message,
+            is_error,
+        } => PlayerEvent::ScriptNotice {
+            world_id,
+            script,
+            message,
+            is_error,
+        },
+
+        // =====================================================================
+        // Safety Tools
+        // =====================================================================
+        ServerMessage::SafetySignalRaised {
+            world_id,
+            level,
+            queue_paused,
+            rejected_request_ids,
+        } => PlayerEvent::SafetySignalRaised {
+            world_id,
+            level,
+            queue_paused,
+            rejected_request_ids,
+        },
+
+        ServerMessage::ActionQueuePaused { world_id, paused } => {
+            PlayerEvent::ActionQueuePaused { world_id, paused }
+        }
+
         // =====================================================================
         // Request/Response Events
         // =====================================================================
@@ -864,10 +1074,162 @@ pub fn translate(msg: ServerMessage) -> PlayerEvent {
             PlayerEvent::EntityChanged(translate_entity_changed_data(data))
         }
 
+        ServerMessage::ControlledPcsChanged { active_pc_id, pcs } => {
+            PlayerEvent::ControlledPcsChanged { active_pc_id, pcs }
+        }
+
         ServerMessage::SpectateTargetChanged { pc_id, pc_name } => {
             PlayerEvent::SpectateTargetChanged { pc_id, pc_name }
         }
 
+        // =====================================================================
+        // Progress Clock Events
+        // =====================================================================
+        ServerMessage::ClockUpdated { clock } => PlayerEvent::ClockUpdated { clock },
+        ServerMessage::ClockRemoved { clock_id } => PlayerEvent::ClockRemoved { clock_id },
+
+        // =====================================================================
+        // Tag Events
+        // =====================================================================
+        ServerMessage::EntityTagsUpdated {
+            entity_type,
+            entity_id,
+            tags,
+            ..
+        } => PlayerEvent::EntityTagsUpdated {
+            entity_type,
+            entity_id,
+            tags,
+        },
+        ServerMessage::WorldTagsUpdated { tags, .. } => PlayerEvent::WorldTagsUpdated { tags },
+
+        // =====================================================================
+        // Dice Events
+        // =====================================================================
+        ServerMessage::DiceRolled { roll } => PlayerEvent::DiceRolled { roll },
+
+        // =====================================================================
+        // Map Marker Events
+        // =====================================================================
+        ServerMessage::MapMarkerUpdated { marker } => PlayerEvent::MapMarkerUpdated { marker },
+        ServerMessage::MapMarkerRemoved { marker_id } => {
+            PlayerEvent::MapMarkerRemoved { marker_id }
+        }
+
+        // =====================================================================
+        // Region Hotspot Events
+        // =====================================================================
+        ServerMessage::RegionHotspotsUpdated {
+            region_id,
+            hotspots,
+        } => PlayerEvent::RegionHotspotsUpdated {
+            region_id,
+            hotspots,
+        },
+        ServerMessage::RegionLightChanged {
+            region_id,
+            light_level,
+        } => PlayerEvent::RegionLightChanged {
+            region_id,
+            light_level,
+        },
+        ServerMessage::RegionMapGenerated {
+            region_id,
+            map_asset,
+            error,
+        } => PlayerEvent::RegionMapGenerated {
+            region_id,
+            map_asset,
+            error,
+        },
+
+        ServerMessage::RegionCrowdsChanged {
+            region_id,
+            crowds_present,
+        } => PlayerEvent::RegionCrowdsChanged {
+            region_id,
+            crowds_present,
+        },
+
+        ServerMessage::NpcDraftUpdated { draft } => PlayerEvent::NpcDraftUpdated { draft },
+
+        ServerMessage::FlashbackChanged { flashback } => {
+            PlayerEvent::FlashbackChanged { flashback }
+        }
+
+        ServerMessage::ParallelScenesChanged { scenes } => {
+            PlayerEvent::ParallelScenesChanged { scenes }
+        }
+
+        ServerMessage::SpotlightChanged { spotlight } => {
+            PlayerEvent::SpotlightChanged { spotlight }
+        }
+
+        ServerMessage::PacingTimerChanged { timer } => PlayerEvent::PacingTimerChanged { timer },
+
+        // =====================================================================
+        // Chronology Events
+        // =====================================================================
+        ServerMessage::CharactersAged {
+            world_id,
+            progressions,
+        } => PlayerEvent::CharactersAged {
+            world_id,
+            progressions,
+        },
+
+        ServerMessage::InjuriesHealed { world_id, injuries } => {
+            PlayerEvent::InjuriesHealed { world_id, injuries }
+        }
+
+        ServerMessage::PcDied { world_id, death } => PlayerEvent::PcDied { world_id, death },
+        ServerMessage::PcResurrected {
+            world_id,
+            pc_id,
+            name,
+        } => PlayerEvent::PcResurrected {
+            world_id,
+            pc_id,
+            name,
+        },
+
+        ServerMessage::PcSecretUpdated { secret } => PlayerEvent::PcSecretUpdated { secret },
+        ServerMessage::PcSecretRemoved { pc_id, secret_id } => {
+            PlayerEvent::PcSecretRemoved { pc_id, secret_id }
+        }
+        ServerMessage::PcSecretRevealed {
+            world_id,
+            pc_name,
+            secret,
+        } => PlayerEvent::PcSecretRevealed {
+            world_id,
+            pc_name,
+            secret,
+        },
+
+        ServerMessage::LetterUpdated { letter } => PlayerEvent::LetterUpdated { letter },
+        ServerMessage::LetterRemoved { letter_id } => PlayerEvent::LetterRemoved { letter_id },
+
+        ServerMessage::BroadsheetDrafted { broadsheet } => {
+            PlayerEvent::BroadsheetDrafted { broadsheet }
+        }
+        ServerMessage::BroadsheetPublished { broadsheet } => {
+            PlayerEvent::BroadsheetPublished { broadsheet }
+        }
+        ServerMessage::BroadsheetRemoved { broadsheet_id } => {
+            PlayerEvent::BroadsheetRemoved { broadsheet_id }
+        }
+        ServerMessage::WorldSimulationProposed { simulation } => {
+            PlayerEvent::WorldSimulationProposed { simulation }
+        }
+        ServerMessage::WorldSimulationResolved {
+            simulation_id,
+            accepted,
+        } => PlayerEvent::WorldSimulationResolved {
+            simulation_id,
+            accepted,
+        },
+
         // =====================================================================
         // Error Events
         // =====================================================================
@@ -2,6 +2,7 @@ pub mod api;
 pub mod http_client;
 pub mod message_translator;
 pub mod messaging;
+pub mod offline;
 pub mod platform;
 pub mod session_type_converters;
 pub mod storage;
@@ -0,0 +1,318 @@
+//! Offline persistence for the Player
+//!
+//! Keeps the last scene a player saw and any actions they composed while the
+//! connection was down, so a flaky connection doesn't blank the screen or
+//! swallow input. Both are stored per world in platform storage, which means
+//! they also survive a page reload.
+//!
+//! Queued actions record the scene they were written against. When the world
+//! is rejoined each one is checked with [`QueuedAction::conflict`] before it
+//! is sent, so an action aimed at a scene that has since moved on is held back
+//! for the player to resend or discard instead of arriving out of context.
+
+use serde::{Deserialize, Serialize};
+use wrldbldr_protocol::ClientMessage;
+
+use crate::application::dto::{
+    CharacterData, CrowdPresenceData, InteractionData, NavigationData, NpcPresenceData,
+    PlayerAction, PlayerActionType, RegionData, RegionItemData, SceneData, SessionWorldSnapshot,
+};
+use crate::infrastructure::websocket::ClientMessageBuilder;
+use crate::ports::outbound::{storage_keys, StorageProvider};
+
+/// Maximum number of actions held while offline (oldest are dropped)
+pub const MAX_QUEUED_ACTIONS: usize = 20;
+
+/// Queued actions older than this are treated as stale on reconnect
+pub const MAX_QUEUED_ACTION_AGE_MS: u64 = 15 * 60 * 1000;
+
+/// The last scene a player saw, restored while the connection is down
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfflineSnapshot {
+    pub world: SessionWorldSnapshot,
+    pub scene: Option<SceneData>,
+    #[serde(default)]
+    pub characters: Vec<CharacterData>,
+    #[serde(default)]
+    pub interactions: Vec<InteractionData>,
+    pub pc_id: Option<String>,
+    pub region: Option<RegionData>,
+    #[serde(default)]
+    pub npcs_present: Vec<NpcPresenceData>,
+    pub navigation: Option<NavigationData>,
+    #[serde(default)]
+    pub region_items: Vec<RegionItemData>,
+    #[serde(default)]
+    pub crowds_present: Vec<CrowdPresenceData>,
+    /// When the snapshot was taken (milliseconds since epoch)
+    pub saved_at_ms: u64,
+}
+
+/// A player action composed while offline, waiting to be sent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedAction {
+    pub id: String,
+    pub action: PlayerAction,
+    /// Scene on screen when the action was composed
+    pub scene_id: Option<String>,
+    /// Region on screen when the action was composed
+    pub region_id: Option<String>,
+    /// When the action was composed (milliseconds since epoch)
+    pub queued_at_ms: u64,
+}
+
+impl QueuedAction {
+    pub fn new(
+        action: PlayerAction,
+        scene_id: Option<String>,
+        region_id: Option<String>,
+        queued_at_ms: u64,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            action,
+            scene_id,
+            region_id,
+            queued_at_ms,
+        }
+    }
+
+    /// Short description of the action for queue and conflict UI
+    pub fn summary(&self) -> String {
+        let action = &self.action;
+        match action.action_type {
+            PlayerActionType::Talk => match action.dialogue.as_deref() {
+                Some(text) => format!("Say \"{}\"", text),
+                None => "Start a conversation".to_string(),
+            },
+            PlayerActionType::Examine => "Examine something".to_string(),
+            PlayerActionType::UseItem => "Use an item".to_string(),
+            PlayerActionType::Travel => "Travel".to_string(),
+            PlayerActionType::Custom => match action.dialogue.as_deref() {
+                Some(text) => format!("\"{}\"", text),
+                None => "Custom action".to_string(),
+            },
+            PlayerActionType::DialogueChoice => "Pick a dialogue choice".to_string(),
+        }
+    }
+
+    /// The wire message for this action
+    pub fn message(&self) -> ClientMessage {
+        ClientMessageBuilder::player_action(
+            self.action.action_type.as_str(),
+            self.action.target.as_deref(),
+            self.action.dialogue.as_deref(),
+            self.action.choice_id.as_deref(),
+        )
+    }
+
+    /// Check whether the action still fits the scene the player is in now
+    ///
+    /// Returns `None` if the action can be sent as-is.
+    pub fn conflict(&self, scene: &SceneContext) -> Option<ActionConflict> {
+        if scene.now_ms.saturating_sub(self.queued_at_ms) > MAX_QUEUED_ACTION_AGE_MS {
+            return Some(ActionConflict::Stale);
+        }
+        if let (Some(then), Some(now)) = (&self.region_id, &scene.region_id) {
+            if then != now {
+                return Some(ActionConflict::RegionChanged);
+            }
+        }
+        if let (Some(then), Some(now)) = (&self.scene_id, &scene.scene_id) {
+            if then != now {
+                return Some(ActionConflict::SceneChanged);
+            }
+        }
+        match self.action.action_type {
+            PlayerActionType::Talk => {
+                let target = self.action.target.as_ref()?;
+                (!scene.target_ids.contains(target)).then_some(ActionConflict::TargetGone)
+            }
+            PlayerActionType::DialogueChoice => {
+                let choice = self.action.choice_id.as_ref()?;
+                (!scene.choice_ids.contains(choice)).then_some(ActionConflict::ChoiceExpired)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// What the player can see when the world is rejoined
+#[derive(Debug, Clone, Default)]
+pub struct SceneContext {
+    pub scene_id: Option<String>,
+    pub region_id: Option<String>,
+    /// Characters, NPCs, and interactions the player can currently target
+    pub target_ids: Vec<String>,
+    /// Dialogue choices currently on offer
+    pub choice_ids: Vec<String>,
+    /// Current time (milliseconds since epoch)
+    pub now_ms: u64,
+}
+
+/// Why a queued action was held back on reconnect
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionConflict {
+    /// Queued too long ago to still make sense
+    Stale,
+    /// The active scene changed while offline
+    SceneChanged,
+    /// The player's character is in a different region now
+    RegionChanged,
+    /// The character being addressed is no longer present
+    TargetGone,
+    /// The conversation moved past the chosen option
+    ChoiceExpired,
+}
+
+impl ActionConflict {
+    /// Player-facing explanation
+    pub fn message(&self) -> &'static str {
+        match self {
+            ActionConflict::Stale => "Queued too long ago",
+            ActionConflict::SceneChanged => "The scene changed while you were offline",
+            ActionConflict::RegionChanged => "You're somewhere else now",
+            ActionConflict::TargetGone => "They're no longer here",
+            ActionConflict::ChoiceExpired => "The conversation moved on",
+        }
+    }
+}
+
+/// Per-world offline storage backed by a [`StorageProvider`]
+pub struct OfflineStore<S: StorageProvider> {
+    storage: S,
+    world_id: String,
+}
+
+impl<S: StorageProvider> OfflineStore<S> {
+    pub fn new(storage: S, world_id: impl Into<String>) -> Self {
+        Self {
+            storage,
+            world_id: world_id.into(),
+        }
+    }
+
+    fn key(&self, prefix: &str) -> String {
+        format!("{}_{}", prefix, self.world_id)
+    }
+
+    /// Persist the scene on screen
+    pub fn save_snapshot(&self, snapshot: &OfflineSnapshot) {
+        match serde_json::to_string(snapshot) {
+            Ok(json) => self
+                .storage
+                .save(&self.key(storage_keys::OFFLINE_SNAPSHOT), &json),
+            Err(e) => tracing::warn!("Failed to serialize offline snapshot: {}", e),
+        }
+    }
+
+    /// Load the last persisted scene, if any
+    pub fn load_snapshot(&self) -> Option<OfflineSnapshot> {
+        let json = self
+            .storage
+            .load(&self.key(storage_keys::OFFLINE_SNAPSHOT))?;
+        serde_json::from_str(&json)
+            .map_err(|e| tracing::warn!("Discarding unreadable offline snapshot: {}", e))
+            .ok()
+    }
+
+    /// Load actions still waiting to be sent
+    pub fn load_queue(&self) -> Vec<QueuedAction> {
+        let Some(json) = self.storage.load(&self.key(storage_keys::OFFLINE_QUEUE)) else {
+            return Vec::new();
+        };
+        serde_json::from_str(&json).unwrap_or_else(|e| {
+            tracing::warn!("Discarding unreadable action queue: {}", e);
+            Vec::new()
+        })
+    }
+
+    /// Replace the persisted queue (an empty queue removes the entry)
+    pub fn save_queue(&self, queue: &[QueuedAction]) {
+        let key = self.key(storage_keys::OFFLINE_QUEUE);
+        if queue.is_empty() {
+            self.storage.remove(&key);
+            return;
+        }
+        match serde_json::to_string(queue) {
+            Ok(json) => self.storage.save(&key, &json),
+            Err(e) => tracing::warn!("Failed to serialize action queue: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::platform::mock::MockStorageProvider;
+
+    fn context(region_id: &str, now_ms: u64) -> SceneContext {
+        SceneContext {
+            scene_id: Some("scene-1".to_string()),
+            region_id: Some(region_id.to_string()),
+            target_ids: vec!["npc-1".to_string()],
+            choice_ids: vec!["choice-1".to_string()],
+            now_ms,
+        }
+    }
+
+    fn queued(action: PlayerAction) -> QueuedAction {
+        QueuedAction::new(
+            action,
+            Some("scene-1".to_string()),
+            Some("tavern".to_string()),
+            1_000,
+        )
+    }
+
+    #[test]
+    fn test_action_in_same_scene_has_no_conflict() {
+        let action = queued(PlayerAction::talk("npc-1", Some("Hello")));
+        assert_eq!(action.conflict(&context("tavern", 2_000)), None);
+
+        let action = queued(PlayerAction::dialogue_choice("choice-1"));
+        assert_eq!(action.conflict(&context("tavern", 2_000)), None);
+    }
+
+    #[test]
+    fn test_conflicts_when_scene_moved_on() {
+        let talk = queued(PlayerAction::talk("npc-1", None));
+        assert_eq!(
+            talk.conflict(&context("docks", 2_000)),
+            Some(ActionConflict::RegionChanged)
+        );
+        assert_eq!(
+            talk.conflict(&context("tavern", 1_000 + MAX_QUEUED_ACTION_AGE_MS + 1)),
+            Some(ActionConflict::Stale)
+        );
+
+        let absent = queued(PlayerAction::talk("npc-2", None));
+        assert_eq!(
+            absent.conflict(&context("tavern", 2_000)),
+            Some(ActionConflict::TargetGone)
+        );
+
+        let choice = queued(PlayerAction::dialogue_choice("choice-9"));
+        assert_eq!(
+            choice.conflict(&context("tavern", 2_000)),
+            Some(ActionConflict::ChoiceExpired)
+        );
+    }
+
+    #[test]
+    fn test_queue_round_trips_per_world() {
+        let storage = MockStorageProvider::new();
+        let store = OfflineStore::new(storage.clone(), "world-a");
+        let other = OfflineStore::new(storage.clone(), "world-b");
+
+        store.save_queue(&[queued(PlayerAction::custom("I wave"))]);
+        let loaded = store.load_queue();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].action.dialogue.as_deref(), Some("I wave"));
+        assert!(other.load_queue().is_empty());
+
+        store.save_queue(&[]);
+        assert!(store.load_queue().is_empty());
+        assert!(storage.get_all().is_empty());
+    }
+}
@@ -4,14 +4,15 @@
 //! standard library and native crates.
 
 use crate::ports::outbound::platform::{
-    DocumentProvider, EngineConfigProvider, LogProvider, RandomProvider, SleepProvider,
-    StorageProvider, TimeProvider,
+    DocumentProvider, EngineConfigProvider, LogProvider, NotificationProvider, RandomProvider,
+    SleepProvider, StorageProvider, TimeProvider,
 };
 use crate::state::Platform;
 use directories::ProjectDirs;
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::process::Command;
 use std::sync::RwLock;
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::{future::Future, pin::Pin, sync::Arc};
@@ -212,6 +213,76 @@ impl DocumentProvider for DesktopDocumentProvider {
     fn set_page_title(&self, _title: &str) {
         // No-op on desktop - window title is managed by OS/Dioxus desktop
     }
+
+    fn preferred_locale(&self) -> String {
+        // POSIX locale variables, e.g. "es_MX.UTF-8"; the engine normalizes them
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty() && value != "C" && value != "POSIX")
+            .unwrap_or_else(|| "en".to_string())
+    }
+}
+
+/// Desktop notification provider
+///
+/// Focus comes from the Dioxus desktop window. Notifications go through the
+/// OS notifier (`notify-send` on Linux, `osascript` on macOS); other systems
+/// get none.
+#[derive(Clone, Default)]
+pub struct DesktopNotificationProvider;
+
+impl NotificationProvider for DesktopNotificationProvider {
+    fn is_backgrounded(&self) -> bool {
+        use dioxus::core::{consume_context_from_scope, Runtime, ScopeId};
+
+        // The window is only reachable from inside the running app
+        if Runtime::try_current().is_none() {
+            return false;
+        }
+        consume_context_from_scope::<dioxus_desktop::DesktopContext>(ScopeId::ROOT)
+            .is_some_and(|desktop| !desktop.window.is_focused())
+    }
+
+    fn request_permission(&self) {
+        // OS notifiers don't ask per app
+    }
+
+    fn notify(&self, title: &str, body: &str) {
+        let Some(mut command) = notifier_command(title, body) else {
+            return;
+        };
+        // Don't block the UI thread on the notifier process
+        std::thread::spawn(move || {
+            if let Err(e) = command.status() {
+                tracing::debug!("System notification failed: {}", e);
+            }
+        });
+    }
+}
+
+fn notifier_command(title: &str, body: &str) -> Option<Command> {
+    if cfg!(target_os = "linux") {
+        let mut command = Command::new("notify-send");
+        command.args(["--app-name=WrldBldr", title, body]);
+        Some(command)
+    } else if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification {} with title {}",
+            applescript_string(body),
+            applescript_string(title)
+        );
+        let mut command = Command::new("osascript");
+        command.args(["-e", &script]);
+        Some(command)
+    } else {
+        None
+    }
+}
+
+/// Quote a string as an AppleScript literal
+fn applescript_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
 /// Desktop sleep provider using tokio timer
@@ -260,6 +331,7 @@ pub fn create_platform() -> Platform {
         DesktopStorageProvider::new(),
         DesktopLogProvider,
         DesktopDocumentProvider,
+        DesktopNotificationProvider,
         DesktopEngineConfigProvider,
     )
 }
@@ -4,8 +4,8 @@
 //! for deterministic testing.
 
 use crate::ports::outbound::platform::{
-    DocumentProvider, EngineConfigProvider, LogProvider, RandomProvider, SleepProvider,
-    StorageProvider, TimeProvider,
+    DocumentProvider, EngineConfigProvider, LogProvider, NotificationProvider, RandomProvider,
+    SleepProvider, StorageProvider, TimeProvider,
 };
 use crate::state::Platform;
 use std::collections::HashMap;
@@ -237,6 +237,48 @@ impl DocumentProvider for MockDocumentProvider {
     fn set_page_title(&self, title: &str) {
         *self.title.write().unwrap() = Some(title.to_string());
     }
+
+    fn preferred_locale(&self) -> String {
+        "en".to_string()
+    }
+}
+
+/// Mock notification provider that records what would have been shown
+#[derive(Clone, Default)]
+pub struct MockNotificationProvider {
+    backgrounded: Arc<RwLock<bool>>,
+    sent: Arc<RwLock<Vec<(String, String)>>>,
+}
+
+impl MockNotificationProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pretend the app was moved to or from the background
+    pub fn set_backgrounded(&self, backgrounded: bool) {
+        *self.backgrounded.write().unwrap() = backgrounded;
+    }
+
+    /// Get all (title, body) notifications shown so far
+    pub fn get_sent(&self) -> Vec<(String, String)> {
+        self.sent.read().unwrap().clone()
+    }
+}
+
+impl NotificationProvider for MockNotificationProvider {
+    fn is_backgrounded(&self) -> bool {
+        *self.backgrounded.read().unwrap()
+    }
+
+    fn request_permission(&self) {}
+
+    fn notify(&self, title: &str, body: &str) {
+        self.sent
+            .write()
+            .unwrap()
+            .push((title.to_string(), body.to_string()));
+    }
 }
 
 /// Mock sleep provider (immediate)
@@ -289,6 +331,7 @@ pub fn create_mock_platform() -> Platform {
         MockStorageProvider::default(),
         MockLogProvider::default(),
         MockDocumentProvider::default(),
+        MockNotificationProvider::default(),
         MockEngineConfigProvider::default(),
     )
 }
@@ -301,6 +344,7 @@ pub struct MockPlatformBuilder {
     storage: MockStorageProvider,
     log: MockLogProvider,
     document: MockDocumentProvider,
+    notification: MockNotificationProvider,
     engine_config: MockEngineConfigProvider,
 }
 
@@ -319,6 +363,7 @@ impl MockPlatformBuilder {
             storage: MockStorageProvider::default(),
             log: MockLogProvider::default(),
             document: MockDocumentProvider::default(),
+            notification: MockNotificationProvider::default(),
             engine_config: MockEngineConfigProvider::default(),
         }
     }
@@ -338,6 +383,12 @@ impl MockPlatformBuilder {
         self
     }
 
+    /// Use a notification provider the test keeps a handle to
+    pub fn with_notifications(mut self, notification: MockNotificationProvider) -> Self {
+        self.notification = notification;
+        self
+    }
+
     pub fn build(self) -> Platform {
         Platform::new(
             self.time,
@@ -346,6 +397,7 @@ impl MockPlatformBuilder {
             self.storage,
             self.log,
             self.document,
+            self.notification,
             self.engine_config,
         )
     }
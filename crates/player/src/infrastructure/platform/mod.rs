@@ -17,13 +17,15 @@ pub mod mock;
 #[cfg(target_arch = "wasm32")]
 pub use wasm::{
     create_platform, WasmDocumentProvider, WasmEngineConfigProvider, WasmLogProvider,
-    WasmRandomProvider, WasmSleepProvider, WasmStorageProvider, WasmTimeProvider,
+    WasmNotificationProvider, WasmRandomProvider, WasmSleepProvider, WasmStorageProvider,
+    WasmTimeProvider,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use desktop::{
     create_platform, DesktopDocumentProvider, DesktopEngineConfigProvider, DesktopLogProvider,
-    DesktopRandomProvider, DesktopSleepProvider, DesktopStorageProvider, DesktopTimeProvider,
+    DesktopNotificationProvider, DesktopRandomProvider, DesktopSleepProvider,
+    DesktopStorageProvider, DesktopTimeProvider,
 };
 
 // Mock platform remains available via `crate::infrastructure::platform::mock`.
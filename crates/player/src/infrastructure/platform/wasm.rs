@@ -4,8 +4,8 @@
 //! js_sys and web_sys crates.
 
 use crate::ports::outbound::platform::{
-    DocumentProvider, EngineConfigProvider, LogProvider, RandomProvider, SleepProvider,
-    StorageProvider, TimeProvider,
+    DocumentProvider, EngineConfigProvider, LogProvider, NotificationProvider, RandomProvider,
+    SleepProvider, StorageProvider, TimeProvider,
 };
 use crate::state::Platform;
 use std::{future::Future, pin::Pin};
@@ -103,6 +103,44 @@ impl DocumentProvider for WasmDocumentProvider {
             document.set_title(&format!("{} | WrldBldr", title));
         }
     }
+
+    fn preferred_locale(&self) -> String {
+        web_sys::window()
+            .and_then(|w| w.navigator().language())
+            .unwrap_or_else(|| "en".to_string())
+    }
+}
+
+/// WASM notification provider using the browser Notification API
+#[derive(Clone, Default)]
+pub struct WasmNotificationProvider;
+
+impl NotificationProvider for WasmNotificationProvider {
+    fn is_backgrounded(&self) -> bool {
+        web_sys::window()
+            .and_then(|w| w.document())
+            .is_some_and(|document| {
+                document.visibility_state() == web_sys::VisibilityState::Hidden
+                    || !document.has_focus().unwrap_or(true)
+            })
+    }
+
+    fn request_permission(&self) {
+        if web_sys::Notification::permission() == web_sys::NotificationPermission::Default {
+            let _ = web_sys::Notification::request_permission();
+        }
+    }
+
+    fn notify(&self, title: &str, body: &str) {
+        if web_sys::Notification::permission() != web_sys::NotificationPermission::Granted {
+            return;
+        }
+        let options = web_sys::NotificationOptions::new();
+        options.set_body(body);
+        // Same tag replaces the previous notification instead of stacking
+        options.set_tag("wrldbldr");
+        let _ = web_sys::Notification::new_with_options(title, &options);
+    }
 }
 
 /// WASM sleep provider using gloo timers
@@ -141,6 +179,7 @@ pub fn create_platform() -> Platform {
         WasmStorageProvider,
         WasmLogProvider,
         WasmDocumentProvider,
+        WasmNotificationProvider,
         WasmEngineConfigProvider,
     )
 }
@@ -6,8 +6,10 @@
 
 use uuid::Uuid;
 use wrldbldr_protocol::{
-    AdHocOutcomes, ApprovalDecision, ApprovedNpcInfo, ChallengeOutcomeDecisionData, ClientMessage,
-    DiceInputType, DirectorialContext, NpcRequest, RequestPayload, TimeRequest, WorldRole,
+    AdHocOutcomes, ApprovalDecision, ApprovalSelection, ApprovedNpcInfo, BulkApprovalDecision,
+    ChallengeOutcomeDecisionData, ClientMessage, DiceInputType, DirectorialContext,
+    JourneyDecision, LockedWayData, LoudnessData, NpcRequest, RequestPayload,
+    SafetySignalLevelData, SharedKnowledgeData, TimeRequest, WorldRole,
 };
 
 /// Builder for ClientMessage variants
@@ -47,6 +49,23 @@ impl ClientMessageBuilder {
         }
     }
 
+    /// Create a ControlPc message (take control of another of the player's PCs)
+    pub fn control_pc(pc_id: Uuid) -> ClientMessage {
+        ClientMessage::ControlPc { pc_id }
+    }
+
+    /// Create a SwitchPc message (act as another controlled PC)
+    pub fn switch_pc(pc_id: Uuid) -> ClientMessage {
+        ClientMessage::SwitchPc { pc_id }
+    }
+
+    /// Create a SetLocale message
+    pub fn set_locale(locale: &str) -> ClientMessage {
+        ClientMessage::SetLocale {
+            locale: locale.to_string(),
+        }
+    }
+
     // =========================================================================
     // Scene / Directorial Messages
     // =========================================================================
@@ -71,6 +90,17 @@ impl ClientMessageBuilder {
         }
     }
 
+    /// Create a BulkApprovalDecision message (DM only)
+    pub fn bulk_approval_decision(
+        selection: ApprovalSelection,
+        decision: BulkApprovalDecision,
+    ) -> ClientMessage {
+        ClientMessage::BulkApprovalDecision {
+            selection,
+            decision,
+        }
+    }
+
     // =========================================================================
     // Challenge Messages
     // =========================================================================
@@ -152,6 +182,42 @@ impl ClientMessageBuilder {
         }
     }
 
+    /// Create a FastTravel message
+    pub fn fast_travel(pc_id: &str, location_id: &str) -> ClientMessage {
+        ClientMessage::FastTravel {
+            pc_id: pc_id.to_string(),
+            location_id: location_id.to_string(),
+        }
+    }
+
+    /// Create an UnlockWithKey message for a locked region connection
+    pub fn unlock_with_key(pc_id: &str, item_id: &str, region_id: &str) -> ClientMessage {
+        ClientMessage::UnlockWithKey {
+            pc_id: pc_id.to_string(),
+            item_id: item_id.to_string(),
+            to: LockedWayData::Region {
+                region_id: region_id.to_string(),
+            },
+        }
+    }
+
+    /// Create a MakeNoise message
+    pub fn make_noise(region_id: &str, description: &str, loudness: LoudnessData) -> ClientMessage {
+        ClientMessage::MakeNoise {
+            region_id: region_id.to_string(),
+            description: description.to_string(),
+            loudness,
+        }
+    }
+
+    /// Create a ResolveJourney message
+    pub fn resolve_journey(journey_id: &str, decision: JourneyDecision) -> ClientMessage {
+        ClientMessage::ResolveJourney {
+            journey_id: journey_id.to_string(),
+            decision,
+        }
+    }
+
     // =========================================================================
     // Staging Messages
     // =========================================================================
@@ -233,6 +299,52 @@ impl ClientMessageBuilder {
         }
     }
 
+    /// Create an OfferTrade message
+    pub fn offer_trade(
+        from_pc_id: &str,
+        to_pc_id: &str,
+        offered_item_ids: Vec<String>,
+        offered_currency: u32,
+        requested_currency: u32,
+    ) -> ClientMessage {
+        ClientMessage::OfferTrade {
+            from_pc_id: from_pc_id.to_string(),
+            to_pc_id: to_pc_id.to_string(),
+            offered_item_ids,
+            offered_currency,
+            requested_item_ids: Vec::new(),
+            requested_currency,
+        }
+    }
+
+    /// Create a RespondToTrade message
+    pub fn respond_to_trade(trade_id: &str, accept: bool) -> ClientMessage {
+        ClientMessage::RespondToTrade {
+            trade_id: trade_id.to_string(),
+            accept,
+        }
+    }
+
+    /// Create a CancelTrade message
+    pub fn cancel_trade(trade_id: &str) -> ClientMessage {
+        ClientMessage::CancelTrade {
+            trade_id: trade_id.to_string(),
+        }
+    }
+
+    /// Create a ShareKnowledge message
+    pub fn share_knowledge(
+        from_pc_id: &str,
+        target_pc_ids: Vec<String>,
+        knowledge: SharedKnowledgeData,
+    ) -> ClientMessage {
+        ClientMessage::ShareKnowledge {
+            from_pc_id: from_pc_id.to_string(),
+            target_pc_ids,
+            knowledge,
+        }
+    }
+
     // =========================================================================
     // Player Action Messages
     // =========================================================================
@@ -242,11 +354,20 @@ impl ClientMessageBuilder {
         action_type: &str,
         target: Option<&str>,
         dialogue: Option<&str>,
+        choice_id: Option<&str>,
     ) -> ClientMessage {
         ClientMessage::PlayerAction {
             action_type: action_type.to_string(),
             target: target.map(|s| s.to_string()),
             dialogue: dialogue.map(|s| s.to_string()),
+            choice_id: choice_id.map(|s| s.to_string()),
+        }
+    }
+
+    /// Take back a queued action the NPC hasn't answered yet
+    pub fn retract_action(action_id: &str) -> ClientMessage {
+        ClientMessage::RetractAction {
+            action_id: action_id.to_string(),
         }
     }
 
@@ -346,6 +467,19 @@ impl ClientMessageBuilder {
         }
     }
 
+    /// Create a SetNpcAutonomy request message
+    ///
+    /// A `None` level returns the NPC to the world default.
+    pub fn set_npc_autonomy(npc_id: &str, level: Option<&str>) -> ClientMessage {
+        ClientMessage::Request {
+            request_id: uuid::Uuid::new_v4().to_string(),
+            payload: RequestPayload::Npc(NpcRequest::SetNpcAutonomy {
+                npc_id: npc_id.to_string(),
+                level: level.map(|s| s.to_string()),
+            }),
+        }
+    }
+
     // =========================================================================
     // Time Control Messages (DM only)
     // =========================================================================
@@ -410,6 +544,47 @@ impl ClientMessageBuilder {
             }),
         }
     }
+
+    // =========================================================================
+    // Safety Tools
+    // =========================================================================
+
+    /// Create an anonymous SafetySignal message
+    pub fn safety_signal(level: SafetySignalLevelData) -> ClientMessage {
+        ClientMessage::SafetySignal { level }
+    }
+
+    /// Create a SetActionQueuePaused message (DM only)
+    pub fn set_action_queue_paused(world_id: &str, paused: bool) -> ClientMessage {
+        ClientMessage::SetActionQueuePaused {
+            world_id: world_id.to_string(),
+            paused,
+        }
+    }
+
+    // =========================================================================
+    // Handout Overlays
+    // =========================================================================
+
+    /// Create a ShowOverlay message (DM only)
+    pub fn show_overlay(
+        asset: &str,
+        caption: Option<&str>,
+        duration_secs: Option<u32>,
+    ) -> ClientMessage {
+        ClientMessage::ShowOverlay {
+            asset: asset.to_string(),
+            caption: caption.map(|s| s.to_string()),
+            duration_secs,
+        }
+    }
+
+    /// Create a DismissOverlay message (DM only)
+    pub fn dismiss_overlay(overlay_id: &str) -> ClientMessage {
+        ClientMessage::DismissOverlay {
+            overlay_id: overlay_id.to_string(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -439,6 +439,7 @@ impl EngineClient {
             action_type: action_type.to_string(),
             target: target.map(|s| s.to_string()),
             dialogue: dialogue.map(|s| s.to_string()),
+            choice_id: None,
         })
     }
 
@@ -12,8 +12,8 @@ pub mod raw_api_port;
 
 pub use api_port::{ApiError, ApiPort};
 pub use platform::{
-    storage_keys, DocumentProvider, EngineConfigProvider, LogProvider, RandomProvider,
-    SleepProvider, StorageProvider, TimeProvider,
+    storage_keys, DocumentProvider, EngineConfigProvider, LogProvider, NotificationProvider,
+    RandomProvider, SleepProvider, StorageProvider, TimeProvider,
 };
 pub use platform_port::PlatformPort;
 pub use raw_api_port::RawApiPort;
@@ -60,6 +60,24 @@ pub trait LogProvider: Clone + 'static {
 pub trait DocumentProvider: Clone + 'static {
     /// Set the browser page title (no-op on desktop)
     fn set_page_title(&self, title: &str);
+
+    /// The user's preferred UI locale as a BCP 47 tag (e.g. "es-MX")
+    fn preferred_locale(&self) -> String;
+}
+
+/// System notifications shown outside the app (browser or OS)
+///
+/// Used for play-by-post style games, where a player may leave the app in a
+/// background tab or window between turns.
+pub trait NotificationProvider: Clone + 'static {
+    /// Whether the app is hidden or unfocused (background tab, inactive window)
+    fn is_backgrounded(&self) -> bool;
+
+    /// Ask the user for permission to show notifications (no-op if not needed)
+    fn request_permission(&self);
+
+    /// Show a notification; silently dropped if permission was not granted
+    fn notify(&self, title: &str, body: &str);
 }
 
 /// Engine configuration provider for API URL management
@@ -80,4 +98,16 @@ pub mod storage_keys {
     pub const ROLE: &str = "wrldbldr_role";
     pub const LAST_WORLD: &str = "wrldbldr_last_world";
     pub const USER_ID: &str = "wrldbldr_user_id";
+    pub const REDUCED_MOTION: &str = "wrldbldr_reduced_motion";
+    pub const THEME: &str = "wrldbldr_theme";
+    /// Last scene seen in a world (suffixed with the world id)
+    pub const OFFLINE_SNAPSHOT: &str = "wrldbldr_offline_snapshot";
+    /// Actions composed while offline (suffixed with the world id)
+    pub const OFFLINE_QUEUE: &str = "wrldbldr_offline_queue";
+    /// Notification feed (suffixed with the PC id)
+    pub const NOTIFICATIONS: &str = "wrldbldr_notifications";
+    /// Saved dice tray expressions (suffixed with the PC id)
+    pub const DICE_FAVORITES: &str = "wrldbldr_dice_favorites";
+    /// Whether to show system notifications while the app is in the background
+    pub const SYSTEM_NOTIFICATIONS: &str = "wrldbldr_system_notifications";
 }
@@ -88,6 +88,22 @@ pub trait PlatformPort: Send + Sync {
     /// Set the browser page title (no-op on desktop)
     fn set_page_title(&self, title: &str);
 
+    /// The user's preferred UI locale as a BCP 47 tag (e.g. "es-MX")
+    fn preferred_locale(&self) -> String;
+
+    // -------------------------------------------------------------------------
+    // System notification operations
+    // -------------------------------------------------------------------------
+
+    /// Whether the app is hidden or unfocused (background tab, inactive window)
+    fn is_app_backgrounded(&self) -> bool;
+
+    /// Ask the user for permission to show system notifications
+    fn request_notification_permission(&self);
+
+    /// Show a browser/OS notification outside the app
+    fn show_system_notification(&self, title: &str, body: &str);
+
     // -------------------------------------------------------------------------
     // Engine config operations
     // -------------------------------------------------------------------------
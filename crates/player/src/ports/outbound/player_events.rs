@@ -49,43 +49,108 @@ use uuid::Uuid;
 
 // Wire-format types with exact field matches - no translation needed
 pub use wrldbldr_protocol::{
+    // Chronology
+    AgeProgressionData,
+    // Broadsheets
+    BroadsheetData,
     // Suggestion types (already re-exported, kept for backward compatibility)
     ChallengeSuggestionInfo,
     ChallengeSuggestionOutcomes,
     // Scene types
     CharacterData,
     CharacterPosition,
+    // Crowds
+    CrowdPresenceData,
+    // Custom fields
+    CustomFieldDefinitionData,
     DialogueChoice,
+    // Dice types
+    DiceRollData,
+    // Flashbacks
+    FlashbackData,
+    // Parallel scenes
+    ParallelSceneData,
+    // Spotlight
+    SpotlightData,
+    // Pacing timers
+    PacingTimerData,
     // Time types
     GameTime,
     // Goal types
     GoalData,
+    // Region hotspots
+    HotspotData,
+    // Injuries
+    InjuryData,
     InteractionData,
+    // Letters
+    LetterData,
+    // Region lighting
+    LightLevelData,
+    // Noise
+    LoudnessData,
+    // Map markers
+    MapMarkerData,
     NarrativeEventSuggestionInfo,
     // Navigation types
     NavigationData,
     NavigationExit,
     NavigationTarget,
+    NoiseHeardInData,
     // Disposition types
     NpcDispositionData,
+    // NPC drafts
+    NpcDraftData,
     NpcPresenceData,
     // Staging types
     NpcPresentInfo,
     // Challenge/Outcome types
     OutcomeBranchData,
     OutcomeDetailData,
+    // PC mortality
+    PcDeathData,
+    // PC secrets
+    PcSecretData,
     PreviousStagingInfo,
+    // Progress clocks
+    ProgressClockData,
     ProposedToolInfo,
     RegionData,
     RegionItemData,
     // Visual State types
     ResolvedVisualStateData,
+    // Safety tools
+    SafetySignalLevelData,
     SceneData,
     // Split party
     SplitPartyLocation,
     StagedNpcInfo,
     StateOptionData,
+    // Tags
+    TagUsageData,
+    // Trades
+    TradeClosedReason,
+    TradeOfferData,
+    // Tutorial
+    TutorialStatusData,
     WaitingPcInfo,
+    // World features
+    WorldFeaturesData,
+    // World map
+    WorldMapData,
+    // Journeys
+    JourneyData,
+    JourneyDecision,
+    // Locks
+    UnlockedConnectionData,
+    // World scripts
+    WorldScriptData,
+    // World simulation
+    WorldSimulationData,
+    // World theme
+    WorldThemeData,
+    // Typography
+    WorldTypographyData,
 };
 
 // =============================================================================
@@ -226,6 +291,12 @@ pub enum PlayerEvent {
     /// A user left the world
     UserLeft { user_id: String },
 
+    /// The server confirmed the locale used for its text on this connection
+    LocaleChanged {
+        locale: String,
+        supported_locales: Vec<String>,
+    },
+
     /// Heartbeat response
     Pong,
 
@@ -246,6 +317,7 @@ pub enum PlayerEvent {
         npcs_present: Vec<NpcPresenceData>,
         navigation: NavigationData,
         region_items: Vec<RegionItemData>,
+        crowds_present: Vec<CrowdPresenceData>,
     },
 
     /// PC was selected for play
@@ -259,6 +331,18 @@ pub enum PlayerEvent {
     /// Movement was blocked
     MovementBlocked { pc_id: String, reason: String },
 
+    /// A fast-travel journey stopped for an encounter
+    JourneyInterrupted { journey: JourneyData },
+
+    /// The DM settled a stopped journey
+    JourneyResolved {
+        journey: JourneyData,
+        decision: JourneyDecision,
+    },
+
+    /// A PC opened a locked connection
+    ConnectionUnlocked { connection: UnlockedConnectionData },
+
     /// Party is split across multiple locations (DM only)
     SplitPartyNotification {
         location_count: usize,
@@ -283,6 +367,9 @@ pub enum PlayerEvent {
         queue_depth: usize,
     },
 
+    /// A queued action was retracted before the NPC answered it
+    ActionRetracted { action_id: String },
+
     /// LLM is processing (DM only)
     LLMProcessing { action_id: String },
 
@@ -341,6 +428,18 @@ pub enum PlayerEvent {
         proposed_tools: Vec<ProposedToolInfo>,
         challenge_suggestion: Option<ChallengeSuggestionInfo>,
         narrative_event_suggestion: Option<NarrativeEventSuggestionInfo>,
+        safety_warnings: Vec<String>,
+        conversation_id: Option<String>,
+        source_action_id: Option<String>,
+        awaiting_player: bool,
+        scene_id: Option<String>,
+    },
+
+    /// A bulk approval decision was applied (DM only)
+    BulkApprovalApplied {
+        decided: Vec<String>,
+        failed: Vec<String>,
+        accepted: bool,
     },
 
     // =========================================================================
@@ -455,6 +554,35 @@ pub enum PlayerEvent {
         description: String,
     },
 
+    /// A noise carried in from a neighbouring region
+    NoiseHeard {
+        region_id: String,
+        from_region_id: String,
+        from_region_name: String,
+        description: String,
+        muffled: bool,
+    },
+
+    /// Where a noise was made and who heard it (DM)
+    NoiseReport {
+        region_id: String,
+        region_name: String,
+        description: String,
+        loudness: LoudnessData,
+        heard_in: Vec<NoiseHeardInData>,
+    },
+
+    /// DM handout shown full screen
+    ShowOverlay {
+        overlay_id: String,
+        asset: String,
+        caption: Option<String>,
+        duration_secs: Option<u32>,
+    },
+
+    /// DM closed a handout overlay for everyone
+    OverlayDismissed { overlay_id: String },
+
     /// NPC location was shared with the player
     NpcLocationShared {
         npc_id: String,
@@ -525,6 +653,14 @@ pub enum PlayerEvent {
         discovery_source: wrldbldr_protocol::types::LoreDiscoverySourceData,
     },
 
+    /// A PC shared lore or an NPC's whereabouts with party members
+    KnowledgeShared {
+        from_pc_id: String,
+        from_pc_name: String,
+        to_pc_ids: Vec<String>,
+        title: String,
+    },
+
     /// Lore was revoked from a character
     LoreRevoked {
         character_id: String,
@@ -577,6 +713,22 @@ pub enum PlayerEvent {
     /// Inventory was updated (refresh signal)
     InventoryUpdated { pc_id: String },
 
+    /// A trade was offered by or to this player's PC
+    TradeOffered { trade: TradeOfferData },
+
+    /// A trade went through
+    TradeCompleted { trade: TradeOfferData },
+
+    /// A trade offer closed without completing
+    TradeClosed {
+        trade_id: String,
+        reason: TradeClosedReason,
+        message: Option<String>,
+    },
+
+    /// This player's PC finished a tutorial step
+    TutorialProgress { status: TutorialStatusData },
+
     // =========================================================================
     // Character Events
     // =========================================================================
@@ -749,6 +901,32 @@ pub enum PlayerEvent {
         retry_in_seconds: Option<u32>,
     },
 
+    /// An Engine dependency (neo4j, ollama, comfyui, disk) became healthy or unhealthy
+    DependencyStatusChanged {
+        dependency: String,
+        healthy: bool,
+        message: Option<String>,
+    },
+
+    /// A world went over one of its daily usage quotas
+    UsageQuotaWarning {
+        world_id: String,
+        /// "tokens" or "images"
+        resource: String,
+        used: u64,
+        quota: u64,
+    },
+
+    /// Download progress for an LLM model on the Engine
+    ModelPullProgress {
+        model: String,
+        status: String,
+        completed_bytes: Option<u64>,
+        total_bytes: Option<u64>,
+        done: bool,
+        error: Option<String>,
+    },
+
     // =========================================================================
     // Time Events
     // =========================================================================
@@ -791,6 +969,61 @@ pub enum PlayerEvent {
         show_time_to_players: bool,
     },
 
+    /// World typography (direction, language, font) changed
+    WorldTypographyUpdated {
+        world_id: String,
+        typography: WorldTypographyData,
+    },
+
+    /// World theme (accent color, backdrop frame) changed
+    WorldThemeUpdated {
+        world_id: String,
+        theme: WorldThemeData,
+    },
+
+    /// The world map image or a location's pin changed
+    WorldMapUpdated { world_id: String, map: WorldMapData },
+
+    /// Experimental feature switches changed
+    WorldFeaturesUpdated {
+        world_id: String,
+        features: WorldFeaturesData,
+    },
+
+    /// DM automation scripts changed (DM only)
+    WorldScriptsUpdated {
+        world_id: String,
+        scripts: Vec<WorldScriptData>,
+    },
+
+    /// Custom field schema changed (DM only)
+    CustomFieldsUpdated {
+        world_id: String,
+        fields: Vec<CustomFieldDefinitionData>,
+    },
+
+    /// A DM automation script sent a message or failed (DM only)
+    ScriptNotice {
+        world_id: String,
+        script: String,
+        message: String,
+        is_error: bool,
+    },
+
+    // =========================================================================
+    // Safety Tools
+    // =========================================================================
+    /// A player raised an anonymous safety signal (DM only)
+    SafetySignalRaised {
+        world_id: String,
+        level: SafetySignalLevelData,
+        queue_paused: bool,
+        rejected_request_ids: Vec<String>,
+    },
+
+    /// Player action queue paused/resumed
+    ActionQueuePaused { world_id: String, paused: bool },
+
     // =========================================================================
     // Request/Response Events
     // =========================================================================
@@ -803,9 +1036,173 @@ pub enum PlayerEvent {
     /// Entity changed broadcast (cache invalidation)
     EntityChanged(EntityChangedData),
 
+    /// The PCs this connection controls changed (solo or duet play)
+    ControlledPcsChanged {
+        active_pc_id: Uuid,
+        pcs: Vec<serde_json::Value>,
+    },
+
     /// Spectate target changed
     SpectateTargetChanged { pc_id: Uuid, pc_name: String },
 
+    // =========================================================================
+    // Progress Clock Events
+    // =========================================================================
+    /// Progress clock created, changed, or ticked
+    ClockUpdated { clock: ProgressClockData },
+
+    /// Progress clock deleted (or hidden from players)
+    ClockRemoved { clock_id: String },
+
+    // =========================================================================
+    // Tag Events
+    // =========================================================================
+    /// An entity's tags were replaced (DM only)
+    EntityTagsUpdated {
+        entity_type: wrldbldr_protocol::EntityType,
+        entity_id: String,
+        tags: Vec<String>,
+    },
+
+    /// A tag was renamed or deleted across the world (DM only)
+    WorldTagsUpdated { tags: Vec<TagUsageData> },
+
+    // =========================================================================
+    // Dice Events
+    // =========================================================================
+    /// Someone else in the world rolled from their dice tray
+    DiceRolled { roll: DiceRollData },
+
+    // =========================================================================
+    // Map Marker Events
+    // =========================================================================
+    /// DM marker pinned, moved, or shown/hidden
+    MapMarkerUpdated { marker: MapMarkerData },
+
+    /// DM marker unpinned (or hidden from players)
+    MapMarkerRemoved { marker_id: String },
+
+    // =========================================================================
+    // Region Hotspot Events
+    // =========================================================================
+    /// DM replaced a region's backdrop hotspots
+    RegionHotspotsUpdated {
+        region_id: String,
+        hotspots: Vec<HotspotData>,
+    },
+    /// DM changed a region's lighting; carries the level in effect now
+    RegionLightChanged {
+        region_id: String,
+        light_level: LightLevelData,
+    },
+    /// A region map finished generating (or failed, for DMs)
+    RegionMapGenerated {
+        region_id: String,
+        map_asset: Option<String>,
+        error: Option<String>,
+    },
+
+    /// The crowds present in a region changed
+    RegionCrowdsChanged {
+        region_id: String,
+        crowds_present: Vec<CrowdPresenceData>,
+    },
+
+    /// An NPC draft finished generating (DM only)
+    NpcDraftUpdated { draft: NpcDraftData },
+
+    /// A flashback started, or ended with `None`
+    FlashbackChanged { flashback: Option<FlashbackData> },
+
+    /// The party split, regrouped or merged, or the DM switched scenes
+    ParallelScenesChanged { scenes: Vec<ParallelSceneData> },
+
+    /// Someone acted, or the DM moved the spotlight or changed its settings
+    SpotlightChanged { spotlight: SpotlightData },
+
+    /// The pacing timer changed or crossed a minute; `None` when stopped
+    PacingTimerChanged { timer: Option<PacingTimerData> },
+
+    // =========================================================================
+    // Chronology Events
+    // =========================================================================
+    /// Characters aged during a long time skip (DM only)
+    CharactersAged {
+        world_id: String,
+        progressions: Vec<AgeProgressionData>,
+    },
+
+    // =========================================================================
+    // Injury Events
+    // =========================================================================
+    /// Injuries healed as game time advanced (DM only)
+    InjuriesHealed {
+        world_id: String,
+        injuries: Vec<InjuryData>,
+    },
+
+    // =========================================================================
+    // Mortality Events
+    // =========================================================================
+    /// A PC died
+    PcDied { world_id: String, death: PcDeathData },
+
+    /// A dead PC was brought back
+    PcResurrected {
+        world_id: String,
+        pc_id: String,
+        name: String,
+    },
+
+    // =========================================================================
+    // Secret Events
+    // =========================================================================
+    /// One of the player's secrets was created or changed
+    PcSecretUpdated { secret: PcSecretData },
+
+    /// One of the player's secrets was deleted
+    PcSecretRemoved { pc_id: String, secret_id: String },
+
+    /// The DM revealed a PC's secret to everyone
+    PcSecretRevealed {
+        world_id: String,
+        pc_name: String,
+        secret: PcSecretData,
+    },
+
+    // =========================================================================
+    // Mail Events
+    // =========================================================================
+    /// A letter was sent, delivered or read, or an NPC wrote back
+    LetterUpdated { letter: LetterData },
+
+    /// The DM removed a letter
+    LetterRemoved { letter_id: String },
+
+    // =========================================================================
+    // Broadsheet Events
+    // =========================================================================
+    /// The engine drafted an issue or the DM edited one (DM only)
+    BroadsheetDrafted { broadsheet: BroadsheetData },
+
+    /// The DM published an issue for every player to read
+    BroadsheetPublished { broadsheet: BroadsheetData },
+
+    /// The DM removed an issue
+    BroadsheetRemoved { broadsheet_id: String },
+
+    // =========================================================================
+    // World Simulation Events
+    // =========================================================================
+    /// What the world got up to during a time skip, for the DM to review
+    WorldSimulationProposed { simulation: WorldSimulationData },
+
+    /// The DM accepted or discarded a simulation
+    WorldSimulationResolved {
+        simulation_id: String,
+        accepted: bool,
+    },
+
     // =========================================================================
     // Error Events
     // =========================================================================
@@ -833,6 +1230,7 @@ impl PlayerEvent {
             Self::WorldJoinFailed { .. } => "WorldJoinFailed",
             Self::UserJoined { .. } => "UserJoined",
             Self::UserLeft { .. } => "UserLeft",
+            Self::LocaleChanged { .. } => "LocaleChanged",
             Self::Pong => "Pong",
             Self::SceneUpdate { .. } => "SceneUpdate",
             Self::SceneChanged { .. } => "SceneChanged",
@@ -841,6 +1239,7 @@ impl PlayerEvent {
             Self::SplitPartyNotification { .. } => "SplitPartyNotification",
             Self::ActionReceived { .. } => "ActionReceived",
             Self::ActionQueued { .. } => "ActionQueued",
+            Self::ActionRetracted { .. } => "ActionRetracted",
             Self::LLMProcessing { .. } => "LLMProcessing",
             Self::QueueStatus { .. } => "QueueStatus",
             Self::ConversationStarted { .. } => "ConversationStarted",
@@ -848,6 +1247,7 @@ impl PlayerEvent {
             Self::ConversationEnded { .. } => "ConversationEnded",
             Self::ResponseApproved { .. } => "ResponseApproved",
             Self::ApprovalRequired { .. } => "ApprovalRequired",
+            Self::BulkApprovalApplied { .. } => "BulkApprovalApplied",
             Self::ChallengePrompt { .. } => "ChallengePrompt",
             Self::ChallengeResolved { .. } => "ChallengeResolved",
             Self::ChallengeRollSubmitted { .. } => "ChallengeRollSubmitted",
@@ -860,6 +1260,10 @@ impl PlayerEvent {
             Self::NarrativeEventTriggered { .. } => "NarrativeEventTriggered",
             Self::ApproachEvent { .. } => "ApproachEvent",
             Self::LocationEvent { .. } => "LocationEvent",
+            Self::NoiseHeard { .. } => "NoiseHeard",
+            Self::NoiseReport { .. } => "NoiseReport",
+            Self::ShowOverlay { .. } => "ShowOverlay",
+            Self::OverlayDismissed { .. } => "OverlayDismissed",
             Self::NpcLocationShared { .. } => "NpcLocationShared",
             Self::StagingApprovalRequired { .. } => "StagingApprovalRequired",
             Self::StagingPending { .. } => "StagingPending",
@@ -867,6 +1271,7 @@ impl PlayerEvent {
             Self::StagingRegenerated { .. } => "StagingRegenerated",
             Self::StagingTimedOut { .. } => "StagingTimedOut",
             Self::LoreDiscovered { .. } => "LoreDiscovered",
+            Self::KnowledgeShared { .. } => "KnowledgeShared",
             Self::LoreRevoked { .. } => "LoreRevoked",
             Self::LoreUpdated { .. } => "LoreUpdated",
             Self::CharacterLoreResponse { .. } => "CharacterLoreResponse",
@@ -875,6 +1280,10 @@ impl PlayerEvent {
             Self::ItemDropped { .. } => "ItemDropped",
             Self::ItemPickedUp { .. } => "ItemPickedUp",
             Self::InventoryUpdated { .. } => "InventoryUpdated",
+            Self::TradeOffered { .. } => "TradeOffered",
+            Self::TradeCompleted { .. } => "TradeCompleted",
+            Self::TradeClosed { .. } => "TradeClosed",
+            Self::TutorialProgress { .. } => "TutorialProgress",
             Self::CharacterStatUpdated { .. } => "CharacterStatUpdated",
             Self::NpcDispositionChanged { .. } => "NpcDispositionChanged",
             Self::NpcMoodChanged { .. } => "NpcMoodChanged",
@@ -904,15 +1313,61 @@ impl PlayerEvent {
             Self::SuggestionComplete { .. } => "SuggestionComplete",
             Self::SuggestionFailed { .. } => "SuggestionFailed",
             Self::ComfyUIStateChanged { .. } => "ComfyUIStateChanged",
+            Self::DependencyStatusChanged { .. } => "DependencyStatusChanged",
+            Self::UsageQuotaWarning { .. } => "UsageQuotaWarning",
+            Self::ModelPullProgress { .. } => "ModelPullProgress",
             Self::GameTimeUpdated { .. } => "GameTimeUpdated",
             Self::GameTimeAdvanced { .. } => "GameTimeAdvanced",
             Self::TimeSuggestion { .. } => "TimeSuggestion",
             Self::TimeModeChanged { .. } => "TimeModeChanged",
             Self::GameTimePaused { .. } => "GameTimePaused",
             Self::TimeConfigUpdated { .. } => "TimeConfigUpdated",
+            Self::WorldTypographyUpdated { .. } => "WorldTypographyUpdated",
+            Self::WorldThemeUpdated { .. } => "WorldThemeUpdated",
+            Self::WorldMapUpdated { .. } => "WorldMapUpdated",
+            Self::JourneyInterrupted { .. } => "JourneyInterrupted",
+            Self::JourneyResolved { .. } => "JourneyResolved",
+            Self::ConnectionUnlocked { .. } => "ConnectionUnlocked",
+            Self::WorldFeaturesUpdated { .. } => "WorldFeaturesUpdated",
+            Self::WorldScriptsUpdated { .. } => "WorldScriptsUpdated",
+            Self::CustomFieldsUpdated { .. } => "CustomFieldsUpdated",
+            Self::ScriptNotice { .. } => "ScriptNotice",
+            Self::SafetySignalRaised { .. } => "SafetySignalRaised",
+            Self::ActionQueuePaused { .. } => "ActionQueuePaused",
             Self::Response { .. } => "Response",
             Self::EntityChanged { .. } => "EntityChanged",
+            Self::ControlledPcsChanged { .. } => "ControlledPcsChanged",
             Self::SpectateTargetChanged { .. } => "SpectateTargetChanged",
+            Self::ClockUpdated { .. } => "ClockUpdated",
+            Self::ClockRemoved { .. } => "ClockRemoved",
+            Self::EntityTagsUpdated { .. } => "EntityTagsUpdated",
+            Self::WorldTagsUpdated { .. } => "WorldTagsUpdated",
+            Self::DiceRolled { .. } => "DiceRolled",
+            Self::MapMarkerUpdated { .. } => "MapMarkerUpdated",
+            Self::MapMarkerRemoved { .. } => "MapMarkerRemoved",
+            Self::RegionHotspotsUpdated { .. } => "RegionHotspotsUpdated",
+            Self::RegionLightChanged { .. } => "RegionLightChanged",
+            Self::RegionMapGenerated { .. } => "RegionMapGenerated",
+            Self::RegionCrowdsChanged { .. } => "RegionCrowdsChanged",
+            Self::NpcDraftUpdated { .. } => "NpcDraftUpdated",
+            Self::FlashbackChanged { .. } => "FlashbackChanged",
+            Self::ParallelScenesChanged { .. } => "ParallelScenesChanged",
+            Self::SpotlightChanged { .. } => "SpotlightChanged",
+            Self::PacingTimerChanged { .. } => "PacingTimerChanged",
+            Self::CharactersAged { .. } => "CharactersAged",
+            Self::InjuriesHealed { .. } => "InjuriesHealed",
+            Self::PcDied { .. } => "PcDied",
+            Self::PcResurrected { .. } => "PcResurrected",
+            Self::PcSecretUpdated { .. } => "PcSecretUpdated",
+            Self::PcSecretRemoved { .. } => "PcSecretRemoved",
+            Self::PcSecretRevealed { .. } => "PcSecretRevealed",
+            Self::LetterUpdated { .. } => "LetterUpdated",
+            Self::LetterRemoved { .. } => "LetterRemoved",
+            Self::BroadsheetDrafted { .. } => "BroadsheetDrafted",
+            Self::BroadsheetPublished { .. } => "BroadsheetPublished",
+            Self::BroadsheetRemoved { .. } => "BroadsheetRemoved",
+            Self::WorldSimulationProposed { .. } => "WorldSimulationProposed",
+            Self::WorldSimulationResolved { .. } => "WorldSimulationResolved",
             Self::Error { .. } => "Error",
             Self::Raw { .. } => "Raw",
         }
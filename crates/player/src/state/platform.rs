@@ -16,8 +16,8 @@
 use std::{future::Future, pin::Pin, sync::Arc};
 
 use crate::ports::outbound::{
-    DocumentProvider, EngineConfigProvider, LogProvider, RandomProvider, SleepProvider,
-    StorageProvider, TimeProvider,
+    DocumentProvider, EngineConfigProvider, LogProvider, NotificationProvider, RandomProvider,
+    SleepProvider, StorageProvider, TimeProvider,
 };
 
 /// Unified platform services container
@@ -32,6 +32,7 @@ pub struct Platform {
     storage: Arc<dyn StorageProviderDyn>,
     log: Arc<dyn LogProviderDyn>,
     document: Arc<dyn DocumentProviderDyn>,
+    notification: Arc<dyn NotificationProviderDyn>,
     engine_config: Arc<dyn EngineConfigProviderDyn>,
 }
 
@@ -68,6 +69,13 @@ trait LogProviderDyn: Send + Sync {
 
 trait DocumentProviderDyn: Send + Sync {
     fn set_page_title(&self, title: &str);
+    fn preferred_locale(&self) -> String;
+}
+
+trait NotificationProviderDyn: Send + Sync {
+    fn is_backgrounded(&self) -> bool;
+    fn request_permission(&self);
+    fn notify(&self, title: &str, body: &str);
 }
 
 trait EngineConfigProviderDyn: Send + Sync {
@@ -134,6 +142,21 @@ impl<T: DocumentProvider + Send + Sync> DocumentProviderDyn for T {
     fn set_page_title(&self, title: &str) {
         DocumentProvider::set_page_title(self, title)
     }
+    fn preferred_locale(&self) -> String {
+        DocumentProvider::preferred_locale(self)
+    }
+}
+
+impl<T: NotificationProvider + Send + Sync> NotificationProviderDyn for T {
+    fn is_backgrounded(&self) -> bool {
+        NotificationProvider::is_backgrounded(self)
+    }
+    fn request_permission(&self) {
+        NotificationProvider::request_permission(self)
+    }
+    fn notify(&self, title: &str, body: &str) {
+        NotificationProvider::notify(self, title, body)
+    }
 }
 
 impl<T: EngineConfigProvider + Send + Sync> EngineConfigProviderDyn for T {
@@ -152,13 +175,15 @@ impl<T: EngineConfigProvider + Send + Sync> EngineConfigProviderDyn for T {
 
 impl Platform {
     /// Create a new Platform with the given providers
-    pub fn new<Tm, Sl, R, S, L, D, E>(
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<Tm, Sl, R, S, L, D, N, E>(
         time: Tm,
         sleep: Sl,
         random: R,
         storage: S,
         log: L,
         document: D,
+        notification: N,
         engine_config: E,
     ) -> Self
     where
@@ -168,6 +193,7 @@ impl Platform {
         S: StorageProvider + Send + Sync,
         L: LogProvider + Send + Sync,
         D: DocumentProvider + Send + Sync,
+        N: NotificationProvider + Send + Sync,
         E: EngineConfigProvider + Send + Sync,
     {
         Self {
@@ -177,6 +203,7 @@ impl Platform {
             storage: Arc::new(storage),
             log: Arc::new(log),
             document: Arc::new(document),
+            notification: Arc::new(notification),
             engine_config: Arc::new(engine_config),
         }
     }
@@ -313,6 +340,30 @@ impl Platform {
         self.document.set_page_title(title)
     }
 
+    /// The user's preferred UI locale (browser language or `LANG` on desktop)
+    pub fn preferred_locale(&self) -> String {
+        self.document.preferred_locale()
+    }
+
+    // -------------------------------------------------------------------------
+    // System notification operations
+    // -------------------------------------------------------------------------
+
+    /// Whether the app is hidden or unfocused (background tab, inactive window)
+    pub fn is_app_backgrounded(&self) -> bool {
+        self.notification.is_backgrounded()
+    }
+
+    /// Ask the user for permission to show system notifications
+    pub fn request_notification_permission(&self) {
+        self.notification.request_permission()
+    }
+
+    /// Show a browser/OS notification outside the app
+    pub fn show_system_notification(&self, title: &str, body: &str) {
+        self.notification.notify(title, body)
+    }
+
     // -------------------------------------------------------------------------
     // Engine config operations
     // -------------------------------------------------------------------------
@@ -420,6 +471,22 @@ impl PlatformPort for Platform {
         self.document.set_page_title(title)
     }
 
+    fn preferred_locale(&self) -> String {
+        self.document.preferred_locale()
+    }
+
+    fn is_app_backgrounded(&self) -> bool {
+        self.notification.is_backgrounded()
+    }
+
+    fn request_notification_permission(&self) {
+        self.notification.request_permission()
+    }
+
+    fn show_system_notification(&self, title: &str, body: &str) {
+        self.notification.notify(title, body)
+    }
+
     fn configure_engine_url(&self, ws_url: &str) {
         self.engine_config.configure_engine_url(ws_url)
     }
@@ -49,6 +49,21 @@ fn AppRoot() -> Element {
     use_context_provider(presentation::state::DialogueState::new);
     use_context_provider(presentation::state::GenerationState::new);
     use_context_provider(presentation::state::LoreState::new);
+    use_context_provider(presentation::state::OfflineState::new);
+
+    // Accessibility and theme preferences are per-device, so load them from local storage
+    let platform = use_platform();
+    let platform_for_theme = platform.clone();
+    use_context_provider(move || {
+        let mut accessibility = presentation::state::AccessibilityState::new();
+        accessibility.load(&platform);
+        accessibility
+    });
+    use_context_provider(move || {
+        let mut theme = presentation::state::ThemeState::new();
+        theme.load(&platform_for_theme);
+        theme
+    });
 
     rsx! {
         document::Stylesheet {
@@ -74,8 +89,10 @@ fn AppRoot() -> Element {
 
 #[component]
 fn DesktopShell(children: Element) -> Element {
+    let root_class = use_root_class();
     rsx! {
         div {
+            class: "{root_class} bg-ink-900 text-parchment-100",
             style: "width: 100vw; height: 100vh; overflow: hidden;",
             {children}
         }
@@ -84,12 +101,26 @@ fn DesktopShell(children: Element) -> Element {
 
 #[component]
 fn MobileShell(children: Element) -> Element {
+    let root_class = use_root_class();
     rsx! {
         // For now, mobile uses the same router and layout bounds.
         // Keeping it separate lets us swap in a mobile-first layout later.
         div {
+            class: "{root_class} bg-ink-900 text-parchment-100",
             style: "width: 100vw; height: 100vh; overflow: hidden;",
             {children}
         }
     }
 }
+
+/// Root classes for the selected theme and reduced motion
+fn use_root_class() -> String {
+    let theme = presentation::state::use_theme_state();
+    let accessibility = presentation::state::use_accessibility_state();
+    let mode = *theme.mode.read();
+    if *accessibility.reduced_motion.read() {
+        format!("{} reduce-motion", mode.css_class())
+    } else {
+        mode.css_class().to_string()
+    }
+}
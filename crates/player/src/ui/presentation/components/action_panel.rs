@@ -46,10 +46,11 @@ pub struct ActionPanelProps {
 /// Action panel - displays system buttons and scene interactions
 #[component]
 pub fn ActionPanel(props: ActionPanelProps) -> Element {
+    // Ones cooling down or used up stay, greyed out with the reason
     let available_interactions: Vec<_> = props
         .interactions
         .iter()
-        .filter(|i| i.is_available)
+        .filter(|i| i.is_available || i.unavailable_reason.is_some())
         .collect();
 
     rsx! {
@@ -135,7 +136,7 @@ pub fn ActionPanel(props: ActionPanelProps) -> Element {
                     key: "{interaction.id}",
                     interaction: interaction.clone(),
                     on_click: props.on_interaction,
-                    disabled: props.disabled,
+                    disabled: props.disabled || !interaction.is_available,
                 }
             }
         }
@@ -227,10 +228,17 @@ pub fn InteractionButton(props: InteractionButtonProps) -> Element {
         "cursor-pointer"
     };
 
+    let title = props
+        .interaction
+        .unavailable_reason
+        .clone()
+        .unwrap_or_default();
+
     rsx! {
         button {
             class: "btn btn-secondary flex items-center gap-2 px-3 py-2 {opacity_class} {cursor_class}",
             disabled: props.disabled,
+            title: "{title}",
             onclick: move |_| {
                 if !props.disabled {
                     props.on_click.call(interaction.clone())
@@ -240,6 +248,13 @@ pub fn InteractionButton(props: InteractionButtonProps) -> Element {
             span { "{icon}" }
             span { "{props.interaction.name}" }
 
+            if let Some(uses_left) = props.interaction.uses_left {
+                span {
+                    class: "text-gray-400 text-xs",
+                    "×{uses_left}"
+                }
+            }
+
             // Show target if available
             if let Some(ref target) = props.interaction.target_name {
                 span {
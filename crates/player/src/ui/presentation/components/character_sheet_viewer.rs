@@ -4,6 +4,7 @@ use dioxus::prelude::*;
 use std::collections::HashMap;
 
 use crate::application::dto::{FieldType, FieldValue, SheetField, SheetSection, SheetTemplate};
+use crate::presentation::utils::use_text_layout;
 
 /// Props for the character sheet viewer
 #[derive(Props, Clone, PartialEq)]
@@ -24,6 +25,7 @@ pub fn CharacterSheetViewer(props: CharacterSheetViewerProps) -> Element {
     // Sort sections by order
     let mut sorted_sections = props.template.sections.clone();
     sorted_sections.sort_by_key(|s| s.order);
+    let layout = use_text_layout();
 
     rsx! {
         // Overlay background
@@ -34,6 +36,9 @@ pub fn CharacterSheetViewer(props: CharacterSheetViewerProps) -> Element {
             // Sheet container (prevent click propagation)
             div {
                 class: "character-sheet-modal bg-gradient-to-br from-dark-surface to-dark-gradient-end rounded-2xl w-full max-w-3xl max-h-[90vh] overflow-hidden flex flex-col shadow-2xl",
+                dir: layout.dir,
+                lang: layout.lang,
+                style: "{layout.style}",
                 onclick: move |e| e.stop_propagation(),
 
                 // Header
@@ -17,6 +17,8 @@ pub struct CharacterOption {
     pub name: String,
     /// Whether this is a PC (true) or NPC (false)
     pub is_pc: bool,
+    /// Other names the character goes by
+    pub aliases: Vec<String>,
 }
 
 impl CharacterOption {
@@ -28,6 +30,15 @@ impl CharacterOption {
             format!("npc:{}", self.id)
         }
     }
+
+    /// Whether the name or one of the aliases contains `search` (lowercase)
+    pub fn matches(&self, search: &str) -> bool {
+        self.name.to_lowercase().contains(search)
+            || self
+                .aliases
+                .iter()
+                .any(|a| a.to_lowercase().contains(search))
+    }
 }
 
 /// Props for the CharacterPicker component
@@ -87,6 +98,7 @@ pub fn CharacterPicker(props: CharacterPickerProps) -> Element {
                                 id: npc.id,
                                 name: npc.name,
                                 is_pc: false,
+                                aliases: npc.aliases,
                             });
                         }
                     }
@@ -107,6 +119,7 @@ pub fn CharacterPicker(props: CharacterPickerProps) -> Element {
                                 id: pc.id,
                                 name: pc.name,
                                 is_pc: true,
+                                aliases: vec![],
                             });
                         }
                     }
@@ -133,7 +146,7 @@ pub fn CharacterPicker(props: CharacterPickerProps) -> Element {
             characters
                 .read()
                 .iter()
-                .filter(|c| c.name.to_lowercase().contains(&search))
+                .filter(|c| c.matches(&search))
                 .cloned()
                 .collect()
         }
@@ -0,0 +1,78 @@
+//! Mention text - free text with known entity names linked to hover-cards
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+use wrldbldr_domain::{find_mentions, MentionTarget};
+
+use crate::application::dto::MentionTargetData;
+
+/// A run of text, linked to a target when it mentions one
+enum Segment {
+    Plain(String),
+    Mention { text: String, target: usize },
+}
+
+/// Split text into plain runs and mentions of `targets`
+fn segments(text: &str, targets: &[MentionTargetData]) -> Vec<Segment> {
+    let searched: Vec<MentionTarget> = targets
+        .iter()
+        .map(|t| {
+            let id = Uuid::parse_str(&t.entity_id).unwrap_or_default();
+            MentionTarget::new(t.entity_type, id, t.name.clone()).with_aliases(t.aliases.clone())
+        })
+        .collect();
+
+    let mut segments = Vec::new();
+    let mut pos = 0;
+    for span in find_mentions(text, &searched) {
+        if span.start > pos {
+            segments.push(Segment::Plain(text[pos..span.start].to_string()));
+        }
+        segments.push(Segment::Mention {
+            text: text[span.start..span.end].to_string(),
+            target: span.target,
+        });
+        pos = span.end;
+    }
+    if pos < text.len() {
+        segments.push(Segment::Plain(text[pos..].to_string()));
+    }
+    segments
+}
+
+/// Text with each mention of a known entity underlined; hovering a
+/// mention shows the entity's name, type and summary
+#[component]
+pub fn MentionText(text: String, targets: Vec<MentionTargetData>) -> Element {
+    if targets.is_empty() {
+        return rsx! { span { "{text}" } };
+    }
+
+    rsx! {
+        span {
+            for (idx, segment) in segments(&text, &targets).into_iter().enumerate() {
+                match segment {
+                    Segment::Plain(text) => rsx! { span { key: "{idx}", "{text}" } },
+                    Segment::Mention { text, target } => {
+                        let target = &targets[target];
+                        rsx! {
+                            span {
+                                key: "{idx}",
+                                class: "mention group relative underline decoration-dotted decoration-amber-400 cursor-help",
+                                "{text}"
+                                span {
+                                    class: "hidden group-hover:block absolute left-0 top-full mt-1 z-20 w-64 p-2 bg-gray-900 border border-gray-700 rounded shadow-lg text-left no-underline",
+                                    span { class: "block text-amber-300 text-sm font-bold", "{target.name}" }
+                                    span { class: "block text-gray-500 text-xs uppercase", "{target.entity_type}" }
+                                    if !target.summary.is_empty() {
+                                        span { class: "block text-gray-300 text-xs mt-1", "{target.summary}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -3,3 +3,6 @@ pub use form_field::FormField;
 
 mod character_picker;
 pub use character_picker::{CharacterOption, CharacterPicker};
+
+mod mention_text;
+pub use mention_text::MentionText;
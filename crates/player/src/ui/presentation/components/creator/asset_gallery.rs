@@ -2,7 +2,10 @@
 
 use dioxus::prelude::*;
 
+use super::asset_upload::AssetUploadZone;
+use super::generation_preset_select::GenerationPresetSelect;
 use crate::infrastructure::spawn_task;
+use crate::application::dto::GenerationPreset;
 use crate::application::services::{Asset, GenerateRequest};
 use crate::presentation::services::{use_asset_service, use_settings_service};
 
@@ -25,6 +28,9 @@ pub fn AssetGallery(world_id: String, entity_type: String, entity_id: String) ->
     let mut is_loading = use_signal(|| true);
     let mut error: Signal<Option<String>> = use_signal(|| None);
     let mut world_style_reference_id: Signal<Option<String>> = use_signal(|| None);
+    let mut world_presets: Signal<Vec<GenerationPreset>> = use_signal(Vec::new);
+    // Bumped to refetch the gallery (e.g. after an upload is attached)
+    let mut reload = use_signal(|| 0u32);
 
     // Fetch assets and world settings on mount
     {
@@ -35,6 +41,7 @@ pub fn AssetGallery(world_id: String, entity_type: String, entity_id: String) ->
         let settings_svc = settings_service.clone();
 
         use_effect(move || {
+            let _ = reload();
             let et = entity_type_clone.clone();
             let ei = entity_id_clone.clone();
             let wid = world_id_clone.clone();
@@ -44,6 +51,7 @@ pub fn AssetGallery(world_id: String, entity_type: String, entity_id: String) ->
                 // Fetch world settings to get current style reference
                 if let Ok(settings) = settings_svc.get_for_world(&wid).await {
                     world_style_reference_id.set(settings.style_reference_asset_id);
+                    world_presets.set(settings.generation_presets);
                 }
 
                 // Skip API call if entity_id is empty (new entity being created)
@@ -208,6 +216,13 @@ pub fn AssetGallery(world_id: String, entity_type: String, entity_id: String) ->
                     span { class: "text-2xl", "+" }
                     span { "Generate" }
                     }
+                    AssetUploadZone {
+                        world_id: world_id.clone(),
+                        entity_type: entity_type.clone(),
+                        entity_id: entity_id.clone(),
+                        asset_type: selected_asset_type.read().clone(),
+                        on_attached: move |_| *reload.write() += 1,
+                    }
                 }
             }
 
@@ -219,6 +234,7 @@ pub fn AssetGallery(world_id: String, entity_type: String, entity_id: String) ->
                     entity_id: entity_id.clone(),
                     asset_type: selected_asset_type.read().clone(),
                     world_style_reference_id: world_style_reference_id.read().clone(),
+                    presets: world_presets.read().clone(),
                     on_close: move |_| show_generate_modal.set(false),
                     on_generate: {
                         let asset_svc_gen = asset_service.clone();
@@ -384,6 +400,8 @@ fn GenerateAssetModal(
     asset_type: String,
     /// World's default style reference asset ID (from settings)
     world_style_reference_id: Option<String>,
+    /// World's generation presets (from settings)
+    presets: Vec<GenerationPreset>,
     on_close: EventHandler<()>,
     on_generate: EventHandler<GenerateRequest>,
 ) -> Element {
@@ -392,6 +410,7 @@ fn GenerateAssetModal(
     let mut negative_prompt = use_signal(String::new);
     let mut count = use_signal(|| 4u8);
     let mut workflow_slot = use_signal(String::new);
+    let preset: Signal<Option<String>> = use_signal(|| None);
     let mut is_generating = use_signal(|| false);
     // Pre-populate with world default style reference
     let mut style_reference_id: Signal<Option<String>> = use_signal(|| None);
@@ -452,6 +471,12 @@ fn GenerateAssetModal(
                     }
                 }
 
+                GenerationPresetSelect {
+                    presets: presets.clone(),
+                    asset_type: asset_type.clone(),
+                    selected: preset,
+                }
+
                 // Style Reference field
                 div { class: "mb-4",
                     label { class: "block text-gray-400 text-sm mb-1", "Style Reference (optional)" }
@@ -618,6 +643,7 @@ fn GenerateAssetModal(
                                     },
                                     count: *count.read(),
                                     style_reference_id: style_reference_id.read().clone(),
+                                    preset: preset.read().clone(),
                                 });
                                 is_generating.set(false);
                             }
@@ -0,0 +1,242 @@
+//! Asset Upload - Bring your own art
+//!
+//! Drop an image onto the gallery (or pick one), optionally have the engine
+//! caption it, then attach it to this entity or to whichever character or
+//! location the engine thinks it depicts.
+
+use base64::Engine as _;
+use dioxus::html::{FileData, HasFileData};
+use dioxus::prelude::*;
+
+use crate::application::services::{AttachRequest, UploadRequest, UploadedImage};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_asset_service;
+
+/// Largest image the engine accepts
+const MAX_UPLOAD_BYTES: usize = 10 * 1024 * 1024;
+
+/// An image picked or dropped, not yet uploaded
+#[derive(Clone, PartialEq)]
+struct PendingImage {
+    file_name: String,
+    data: Vec<u8>,
+}
+
+/// Props for AssetUploadZone
+#[derive(Props, Clone, PartialEq)]
+pub struct AssetUploadZoneProps {
+    pub world_id: String,
+    /// Entity whose gallery this zone sits in
+    pub entity_type: String,
+    pub entity_id: String,
+    /// Gallery tab the image is uploaded as (portrait, sprite, ...)
+    pub asset_type: String,
+    /// Called after the image is attached to this entity
+    pub on_attached: EventHandler<()>,
+}
+
+/// Drop target and upload flow for user-supplied images
+#[component]
+pub fn AssetUploadZone(props: AssetUploadZoneProps) -> Element {
+    let asset_service = use_asset_service();
+    let mut is_drag_over = use_signal(|| false);
+    let mut pending: Signal<Option<PendingImage>> = use_signal(|| None);
+    let mut caption = use_signal(|| false);
+    let mut is_busy = use_signal(|| false);
+    let mut uploaded: Signal<Option<UploadedImage>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let mut take_file = move |files: Vec<FileData>| {
+        let Some(file) = files.into_iter().next() else {
+            return;
+        };
+        error.set(None);
+        uploaded.set(None);
+        spawn_task(async move {
+            match file.read_bytes().await {
+                Ok(bytes) if bytes.len() > MAX_UPLOAD_BYTES => {
+                    error.set(Some("Image is larger than 10 MB".to_string()));
+                }
+                Ok(bytes) => pending.set(Some(PendingImage {
+                    file_name: file.name(),
+                    data: bytes.to_vec(),
+                })),
+                Err(e) => error.set(Some(format!("Could not read file: {}", e))),
+            }
+        });
+    };
+
+    let upload_service = asset_service.clone();
+    let world_id = props.world_id.clone();
+    let asset_type = props.asset_type.clone();
+    let handle_upload = move |_| {
+        let Some(image) = pending.read().clone() else {
+            return;
+        };
+        let svc = upload_service.clone();
+        let request = UploadRequest {
+            asset_type: asset_type.clone(),
+            data: base64::engine::general_purpose::STANDARD.encode(&image.data),
+            file_name: Some(image.file_name),
+            label: None,
+            caption: *caption.read(),
+        };
+        let wid = world_id.clone();
+        is_busy.set(true);
+        spawn_task(async move {
+            match svc.upload_image(&wid, &request).await {
+                Ok(result) => {
+                    pending.set(None);
+                    uploaded.set(Some(result));
+                }
+                Err(e) => error.set(Some(format!("Upload failed: {}", e))),
+            }
+            is_busy.set(false);
+        });
+    };
+
+    let attach_service = asset_service.clone();
+    let asset_type = props.asset_type.clone();
+    let this_entity = (props.entity_type.clone(), props.entity_id.clone());
+    let on_attached = props.on_attached;
+    let attach = use_callback(move |(entity_type, entity_id): (String, String)| {
+        let Some(file_path) = uploaded.read().as_ref().map(|u| u.file_path.clone()) else {
+            return;
+        };
+        let svc = attach_service.clone();
+        let request = AttachRequest {
+            asset_type: asset_type.clone(),
+            file_path,
+            label: None,
+            set_active: false,
+        };
+        let is_this_entity = (entity_type.clone(), entity_id.clone()) == this_entity;
+        is_busy.set(true);
+        spawn_task(async move {
+            match svc.attach_upload(&entity_type, &entity_id, &request).await {
+                Ok(()) => {
+                    uploaded.set(None);
+                    if is_this_entity {
+                        on_attached.call(());
+                    }
+                }
+                Err(e) => error.set(Some(format!("Attach failed: {}", e))),
+            }
+            is_busy.set(false);
+        });
+    });
+
+    let tile_class = if *is_drag_over.read() {
+        "w-16 h-16 flex flex-col items-center justify-center bg-blue-500 bg-opacity-30 border-2 border-dashed border-blue-400 rounded-lg cursor-pointer text-blue-300 text-xs"
+    } else {
+        "w-16 h-16 flex flex-col items-center justify-center bg-blue-500 bg-opacity-10 border-2 border-dashed border-blue-500 rounded-lg cursor-pointer text-blue-400 text-xs"
+    };
+
+    rsx! {
+        label {
+            class: "{tile_class}",
+            title: "Drop an image here or click to choose one",
+            ondragover: move |e| {
+                e.prevent_default();
+                is_drag_over.set(true);
+            },
+            ondragleave: move |_| is_drag_over.set(false),
+            ondrop: move |e| {
+                e.prevent_default();
+                is_drag_over.set(false);
+                take_file(e.data().files());
+            },
+            input {
+                r#type: "file",
+                accept: "image/png,image/jpeg,image/webp,image/gif",
+                class: "hidden",
+                onchange: move |e| take_file(e.files()),
+            }
+            span { class: "text-2xl", "↑" }
+            span { "Upload" }
+        }
+
+        if let Some(err) = error.read().as_ref() {
+            div {
+                class: "w-full p-2 bg-red-500 bg-opacity-10 rounded text-red-500 text-xs",
+                "{err}"
+            }
+        }
+
+        if let Some(image) = pending.read().as_ref() {
+            div {
+                class: "w-full flex items-center gap-3 p-2 bg-dark-surface rounded text-xs text-gray-300",
+                span { class: "flex-1 truncate", "{image.file_name}" }
+                label {
+                    class: "flex items-center gap-1 text-gray-400",
+                    input {
+                        r#type: "checkbox",
+                        checked: *caption.read(),
+                        onchange: move |e| caption.set(e.checked()),
+                    }
+                    "Caption with AI"
+                }
+                button {
+                    class: "px-2 py-1 bg-blue-500 text-white rounded border-0 cursor-pointer disabled:opacity-50",
+                    disabled: *is_busy.read(),
+                    onclick: handle_upload,
+                    if *is_busy.read() { "Uploading..." } else { "Upload" }
+                }
+                button {
+                    class: "px-2 py-1 bg-transparent text-gray-400 rounded border-0 cursor-pointer",
+                    onclick: move |_| pending.set(None),
+                    "Cancel"
+                }
+            }
+        }
+
+        if let Some(result) = uploaded.read().as_ref() {
+            div {
+                class: "w-full flex flex-col gap-2 p-2 bg-dark-surface rounded text-xs",
+
+                if let Some(text) = result.caption.as_ref() {
+                    p { class: "text-gray-400 italic", "\"{text}\"" }
+                }
+
+                div { class: "text-gray-500", "Attach to:" }
+
+                if !props.entity_id.is_empty() {
+                    button {
+                        class: "text-left px-2 py-1 bg-blue-500 bg-opacity-20 text-blue-300 rounded border-0 cursor-pointer disabled:opacity-50",
+                        disabled: *is_busy.read(),
+                        onclick: {
+                            let entity_type = props.entity_type.clone();
+                            let entity_id = props.entity_id.clone();
+                            move |_| attach.call((entity_type.clone(), entity_id.clone()))
+                        },
+                        "This {props.entity_type}"
+                    }
+                }
+
+                for suggestion in result
+                    .suggestions
+                    .iter()
+                    .filter(|s| s.entity_id != props.entity_id)
+                    .cloned()
+                {
+                    button {
+                        key: "{suggestion.entity_id}",
+                        class: "text-left px-2 py-1 bg-transparent text-gray-300 rounded border border-gray-700 cursor-pointer hover:bg-gray-800 disabled:opacity-50",
+                        disabled: *is_busy.read(),
+                        title: "{suggestion.reason}",
+                        onclick: {
+                            let entity_type = suggestion.entity_type.clone();
+                            let entity_id = suggestion.entity_id.clone();
+                            move |_| attach.call((entity_type.clone(), entity_id.clone()))
+                        },
+                        "{suggestion.name} ({suggestion.entity_type}, {(suggestion.confidence * 100.0).round()}%)"
+                    }
+                }
+
+                if result.suggestions.is_empty() {
+                    div { class: "text-gray-500", "No likely matches found in this world." }
+                }
+            }
+        }
+    }
+}
@@ -5,15 +5,26 @@ use std::collections::HashMap;
 
 use crate::infrastructure::spawn_task;
 use super::asset_gallery::AssetGallery;
+use super::custom_fields::CustomFieldsEditor;
+use super::drafts::DraftPanel;
 use super::expression_config_editor::ExpressionConfigEditor;
+use super::library::PublishToLibrary;
+use super::mentions::MentionsPanel;
 use super::motivations_tab::MotivationsTab;
+use super::name_generators::NameSuggestions;
 use super::sheet_field_input::CharacterSheetForm;
 use super::suggestion_button::{SuggestionButton, SuggestionType};
-use crate::application::dto::{FieldValue, SheetTemplate};
+use super::tags::TagEditor;
+use super::templates::SaveCharacterTemplate;
+use crate::application::dto::{CustomFieldEntryData, DraftFieldData, FieldValue, SheetTemplate};
 use crate::application::services::SuggestionContext;
-use crate::application::services::{CharacterFormData, CharacterSheetDataApi};
+use crate::application::services::{CharacterFormData, CharacterSheetDataApi, CharacterSheetView};
+use crate::application::ServiceError;
 use crate::presentation::components::common::FormField;
-use crate::presentation::services::{use_character_service, use_world_service};
+use crate::presentation::components::schema_sheet::SchemaSheet;
+use crate::presentation::services::{
+    use_character_service, use_character_sheet_service, use_world_service,
+};
 use crate::use_platform;
 use wrldbldr_domain::{ExpressionConfig, MoodState};
 
@@ -43,10 +54,13 @@ pub fn CharacterForm(
     let platform = use_platform();
     let char_service = use_character_service();
     let world_service = use_world_service();
+    let sheet_service = use_character_sheet_service();
 
     // Form state
     let mut name = use_signal(String::new);
     let mut description = use_signal(String::new);
+    // Whether the description is an untouched suggestion (for draft revisions)
+    let mut description_generated = use_signal(|| false);
     let mut archetype = use_signal(|| "Hero".to_string());
     let mut wants = use_signal(String::new);
     let mut fears = use_signal(String::new);
@@ -60,6 +74,12 @@ pub fn CharacterForm(
     let mut sheet_template: Signal<Option<SheetTemplate>> = use_signal(|| None);
     let mut sheet_values: Signal<HashMap<String, FieldValue>> = use_signal(HashMap::new);
     let mut show_sheet_section = use_signal(|| true);
+    let mut custom_fields: Signal<Vec<CustomFieldEntryData>> = use_signal(Vec::new);
+
+    // Game system sheet for existing characters; replaces the template form
+    // when the Engine has a schema for the world's system
+    let mut system_sheet: Signal<Option<CharacterSheetView>> = use_signal(|| None);
+    let mut system_sheet_errors: Signal<HashMap<String, String>> = use_signal(HashMap::new);
 
     // Expression config state (Tier 2 & 3 of emotional model)
     let mut expression_config = use_signal(ExpressionConfig::default);
@@ -120,6 +140,7 @@ pub fn CharacterForm(
                             if let Some(data) = char_data.sheet_data {
                                 sheet_values.set(data.values);
                             }
+                            custom_fields.set(char_data.custom_fields);
                             is_loading.set(false);
                         }
                         Err(e) => {
@@ -132,6 +153,57 @@ pub fn CharacterForm(
         });
     }
 
+    // Load the game system sheet if editing existing character
+    {
+        let char_id_for_effect = character_id.clone();
+        let sheet_svc = sheet_service.clone();
+        let plat = platform.clone();
+        use_effect(move || {
+            let char_id = char_id_for_effect.clone();
+            let svc = sheet_svc.clone();
+            let platform = plat.clone();
+            if !char_id.is_empty() {
+                spawn_task(async move {
+                    match svc.get_sheet(&char_id).await {
+                        Ok(sheet) if sheet.schema.is_some() => system_sheet.set(Some(sheet)),
+                        Ok(_) => {}
+                        Err(e) => {
+                            // Falls back to the template form
+                            platform.log_warn(&format!("Failed to load character sheet: {}", e));
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    let update_system_field = {
+        let char_id = character_id.clone();
+        let svc = sheet_service.clone();
+        move |(field_id, value): (String, serde_json::Value)| {
+            let char_id = char_id.clone();
+            let svc = svc.clone();
+            spawn_task(async move {
+                match svc.update_field(&char_id, &field_id, value).await {
+                    Ok(update) => {
+                        system_sheet_errors.write().remove(&field_id);
+                        if let Some(sheet) = system_sheet.write().as_mut() {
+                            sheet.values.insert(update.field_id, update.value);
+                            sheet.calculated.extend(update.calculated);
+                        }
+                    }
+                    Err(e) => {
+                        let message = match e {
+                            ServiceError::ServerError { message, .. } => message,
+                            other => other.to_string(),
+                        };
+                        system_sheet_errors.write().insert(field_id, message);
+                    }
+                }
+            });
+        }
+    };
+
     rsx! {
         div {
             class: "character-form flex flex-col h-full bg-dark-surface rounded-lg overflow-hidden",
@@ -199,6 +271,10 @@ pub fn CharacterForm(
                                 },
                                 on_select: move |value| name.set(value),
                             }
+                            NameSuggestions {
+                                world_id: world_id.clone(),
+                                on_select: move |value| name.set(value),
+                            }
                         }
                     }
                 }
@@ -228,7 +304,10 @@ pub fn CharacterForm(
                         div { class: "flex flex-col gap-2",
                             textarea {
                                 value: "{description}",
-                                oninput: move |e| description.set(e.value()),
+                                oninput: move |e| {
+                                    description.set(e.value());
+                                    description_generated.set(false);
+                                },
                                 placeholder: "Physical appearance, mannerisms, voice...",
                                 class: "w-full min-h-[80px] p-2 bg-dark-bg border border-gray-700 rounded text-white resize-y box-border",
                             }
@@ -241,7 +320,10 @@ pub fn CharacterForm(
                                         hints: Some(archetype.read().clone()),
                                         ..Default::default()
                                     },
-                                    on_select: move |value| description.set(value),
+                                    on_select: move |value| {
+                                        description.set(value);
+                                        description_generated.set(true);
+                                    },
                                 }
                             }
                         }
@@ -396,8 +478,43 @@ pub fn CharacterForm(
                         }
                     }
 
-                    // Character Sheet section (if template available)
-                    if let Some(template) = sheet_template.read().as_ref() {
+                    // Character Sheet section: game system schema when available,
+                    // otherwise the world's sheet template
+                    if let Some(sheet) = system_sheet.read().as_ref() {
+                        if let Some(schema) = sheet.schema.clone() {
+                            div {
+                                class: "sheet-section mt-6 border-t border-gray-700 pt-4",
+
+                                div {
+                                    class: "flex justify-between items-center mb-4 cursor-pointer",
+                                    onclick: move |_| {
+                                        let current = *show_sheet_section.read();
+                                        show_sheet_section.set(!current);
+                                    },
+
+                                    h3 {
+                                        class: "text-gray-400 text-sm uppercase m-0",
+                                        "Character Sheet ({schema.system_name})"
+                                    }
+
+                                    span {
+                                        class: "text-gray-500 text-sm",
+                                        if *show_sheet_section.read() { "[-]" } else { "[+]" }
+                                    }
+                                }
+
+                                if *show_sheet_section.read() {
+                                    SchemaSheet {
+                                        schema,
+                                        values: sheet.values.clone(),
+                                        calculated: sheet.calculated.clone(),
+                                        errors: system_sheet_errors.read().clone(),
+                                        on_change: update_system_field.clone(),
+                                    }
+                                }
+                            }
+                        }
+                    } else if let Some(template) = sheet_template.read().as_ref() {
                         div {
                             class: "sheet-section mt-6 border-t border-gray-700 pt-4",
 
@@ -432,6 +549,96 @@ pub fn CharacterForm(
                         }
                     }
 
+                    // Tags section (only for existing characters)
+                    if !is_new {
+                        div {
+                            class: "tags-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Tags" }
+
+                            TagEditor {
+                                world_id: world_id.clone(),
+                                entity_type: wrldbldr_protocol::EntityType::Character,
+                                entity_id: character_id.clone(),
+                            }
+                        }
+                    }
+
+                    // Where this character is referenced (only for existing characters)
+                    if !is_new {
+                        div {
+                            class: "mentions-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Referenced In" }
+
+                            MentionsPanel {
+                                entity_type: wrldbldr_protocol::EntityType::Character,
+                                entity_id: character_id.clone(),
+                            }
+                        }
+                    }
+
+                    // Custom fields section (only for existing characters with fields defined)
+                    if !is_new && !custom_fields.read().is_empty() {
+                        div {
+                            class: "custom-fields-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Custom Fields" }
+
+                            CustomFieldsEditor {
+                                world_id: world_id.clone(),
+                                entity_type: wrldbldr_protocol::EntityType::Character,
+                                entity_id: character_id.clone(),
+                                entries: custom_fields,
+                            }
+                        }
+                    }
+
+                    // Revision drafts for the generated description (only for existing characters)
+                    if !is_new {
+                        div {
+                            class: "drafts-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Drafts" }
+
+                            DraftPanel {
+                                world_id: world_id.clone(),
+                                entity_type: wrldbldr_protocol::EntityType::Character,
+                                entity_id: character_id.clone(),
+                                field: DraftFieldData::Description,
+                                label: "Description",
+                                text: description,
+                                generated: description_generated,
+                            }
+                        }
+                    }
+
+                    // Save as template (only for existing characters)
+                    if !is_new {
+                        div {
+                            class: "template-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Template" }
+
+                            SaveCharacterTemplate { character_id: character_id.clone() }
+                        }
+                    }
+
+                    // Publish to the cross-world library (only for existing characters)
+                    if !is_new {
+                        div {
+                            class: "library-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Library" }
+
+                            PublishToLibrary {
+                                world_id: world_id.clone(),
+                                entity_type: wrldbldr_protocol::EntityType::Character,
+                                entity_id: character_id.clone(),
+                            }
+                        }
+                    }
+
                     // Motivations section (only for existing NPCs)
                     if !is_new {
                         div {
@@ -466,6 +673,41 @@ pub fn CharacterForm(
             div {
                 class: "form-footer flex justify-end gap-2 p-4 border-t border-gray-700",
 
+                // Duplicate (only for existing characters); the copy shows up in the list
+                if !is_new {
+                    button {
+                        class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer mr-auto",
+                        disabled: *is_saving.read(),
+                        onclick: {
+                            let char_svc = char_service.clone();
+                            let char_id = character_id.clone();
+                            move |_| {
+                                let svc = char_svc.clone();
+                                let char_id = char_id.clone();
+                                error_message.set(None);
+                                success_message.set(None);
+                                spawn_task(async move {
+                                    match svc.duplicate_character(&char_id).await {
+                                        Ok(copy) => {
+                                            success_message.set(Some(format!("Created \"{}\"", copy.name)));
+                                            characters_signal.write().push(
+                                                crate::application::services::character_service::CharacterSummary {
+                                                    id: copy.id.unwrap_or_default(),
+                                                    name: copy.name,
+                                                    archetype: copy.archetype,
+                                                    aliases: vec![],
+                                                },
+                                            );
+                                        }
+                                        Err(e) => error_message.set(Some(format!("Duplicate failed: {}", e))),
+                                    }
+                                });
+                            }
+                        },
+                        "Duplicate"
+                    }
+                }
+
                 button {
                     onclick: move |_| on_close.call(()),
                     class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer",
@@ -534,6 +776,7 @@ pub fn CharacterForm(
                                         sprite_asset: None,
                                         portrait_asset: None,
                                         sheet_data: sheet_data_to_save,
+                                        custom_fields: Vec::new(),
                                     };
 
                                     match if is_new {
@@ -549,6 +792,7 @@ pub fn CharacterForm(
                                                     id: saved_character.id.clone().unwrap_or_default(),
                                                     name: saved_character.name.clone(),
                                                     archetype: saved_character.archetype.clone(),
+                                                    aliases: vec![],
                                                 };
                                                 characters_signal.write().push(summary);
                                             } else {
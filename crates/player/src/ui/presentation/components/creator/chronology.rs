@@ -0,0 +1,228 @@
+//! Chronology - Character birthdates, ages and lore years on the game calendar
+//!
+//! Ages are computed by the engine from the current game date. Lore with an
+//! event year that mentions a character before their birth shows up as a
+//! chronology error at the top of the panel, next to birthdates that lie in
+//! the future.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{
+    CharacterAgeData, ChronologyIssueKindData, ChronologyReportData, LoreDateData,
+};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_chronology_service;
+use crate::presentation::utils::life_stage_label;
+
+/// Chronology of the world the DM is in: errors, character ages and lore years
+#[component]
+pub fn ChronologyPanel() -> Element {
+    let chronology_service = use_chronology_service();
+    let mut expanded = use_signal(|| false);
+    let mut report: Signal<Option<ChronologyReportData>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load whenever the panel is opened
+    {
+        let service = chronology_service.clone();
+        use_effect(move || {
+            if !*expanded.read() {
+                return;
+            }
+            let service = service.clone();
+            spawn_task(async move {
+                match service.get_chronology().await {
+                    Ok(fetched) => {
+                        report.set(Some(fetched));
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to load chronology: {}", e))),
+                }
+            });
+        });
+    }
+
+    let on_updated = move |updated: ChronologyReportData| {
+        report.set(Some(updated));
+        error.set(None);
+    };
+    let on_error = move |message: String| error.set(Some(message));
+
+    let current = report.read().clone();
+
+    rsx! {
+        div {
+            class: "chronology-panel flex flex-col gap-2 bg-dark-surface rounded-lg p-3",
+
+            div {
+                class: "flex justify-between items-center",
+                button {
+                    onclick: move |_| expanded.toggle(),
+                    class: "bg-transparent border-0 p-0 text-gray-400 text-sm uppercase cursor-pointer",
+                    if *expanded.read() { "▾ Chronology" } else { "▸ Chronology" }
+                }
+                if let Some(current) = current.as_ref().filter(|_| *expanded.read()) {
+                    span { class: "text-gray-500 text-xs", "Today: {current.current_date}" }
+                }
+            }
+
+            if *expanded.read() {
+                if let Some(current) = current {
+                    if !current.issues.is_empty() {
+                        div {
+                            class: "flex flex-col gap-1",
+                            for (index, issue) in current.issues.iter().enumerate() {
+                                div {
+                                    key: "{index}",
+                                    class: "p-2 bg-red-900 bg-opacity-30 text-red-400 rounded text-xs",
+                                    if issue.kind == ChronologyIssueKindData::BornInFuture { "⏳ " } else { "⚠ " }
+                                    "{issue.message}"
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "text-gray-400 text-xs uppercase mt-1", "Characters" }
+                    if current.characters.is_empty() {
+                        div { class: "text-gray-500 text-sm", "No characters yet" }
+                    }
+                    for character in current.characters.iter().cloned() {
+                        BirthDateRow {
+                            key: "{character.character_id}",
+                            character: character,
+                            on_updated: on_updated,
+                            on_error: on_error,
+                        }
+                    }
+
+                    div { class: "text-gray-400 text-xs uppercase mt-1", "Lore" }
+                    if current.lore.is_empty() {
+                        div { class: "text-gray-500 text-sm", "No lore yet" }
+                    }
+                    for lore in current.lore.iter().cloned() {
+                        LoreYearRow {
+                            key: "{lore.lore_id}",
+                            lore: lore,
+                            on_updated: on_updated,
+                            on_error: on_error,
+                        }
+                    }
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div { class: "text-red-400 text-xs", "{err}" }
+                }
+            }
+        }
+    }
+}
+
+/// A character's birthdate input with the computed age beside it
+#[component]
+fn BirthDateRow(
+    character: CharacterAgeData,
+    on_updated: EventHandler<ChronologyReportData>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let chronology_service = use_chronology_service();
+    let mut value = use_signal(|| character.birth_date.clone().unwrap_or_default());
+
+    let save = {
+        let character_id = character.character_id.clone();
+        let saved = character.birth_date.clone();
+        move |_| {
+            let trimmed = value.read().trim().to_string();
+            let birth_date = (!trimmed.is_empty()).then_some(trimmed);
+            if birth_date == saved {
+                return;
+            }
+            let character_id = character_id.clone();
+            let service = chronology_service.clone();
+            spawn_task(async move {
+                match service.set_birth_date(&character_id, birth_date).await {
+                    Ok(updated) => on_updated.call(updated),
+                    Err(e) => on_error.call(format!("Failed to set birthdate: {}", e)),
+                }
+            });
+        }
+    };
+
+    let age_label = match (character.age, character.life_stage) {
+        (Some(age), Some(stage)) => format!("{} · {}", age, life_stage_label(stage)),
+        (Some(age), None) => age.to_string(),
+        _ if character.birth_date.is_some() => "Not yet born".to_string(),
+        _ => String::new(),
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 text-sm",
+            span { class: "text-white flex-1 truncate", "{character.name}" }
+            input {
+                r#type: "text",
+                placeholder: "YYYY-MM-DD",
+                value: "{value}",
+                oninput: move |e| value.set(e.value()),
+                onchange: save,
+                class: "w-28 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+            }
+            span { class: "w-28 text-gray-400 text-xs", "{age_label}" }
+        }
+    }
+}
+
+/// A lore entry's event year input
+#[component]
+fn LoreYearRow(
+    lore: LoreDateData,
+    on_updated: EventHandler<ChronologyReportData>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let chronology_service = use_chronology_service();
+    let mut value = use_signal(|| lore.event_year.map(|y| y.to_string()).unwrap_or_default());
+
+    let save = {
+        let lore_id = lore.lore_id.clone();
+        let saved = lore.event_year;
+        move |_| {
+            let trimmed = value.read().trim().to_string();
+            let event_year = if trimmed.is_empty() {
+                None
+            } else {
+                match trimmed.parse::<i32>() {
+                    Ok(year) => Some(year),
+                    Err(_) => {
+                        on_error.call(format!("'{}' is not a year", trimmed));
+                        return;
+                    }
+                }
+            };
+            if event_year == saved {
+                return;
+            }
+            let lore_id = lore_id.clone();
+            let service = chronology_service.clone();
+            spawn_task(async move {
+                match service.set_lore_event_year(&lore_id, event_year).await {
+                    Ok(updated) => on_updated.call(updated),
+                    Err(e) => on_error.call(format!("Failed to set lore year: {}", e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 text-sm",
+            span { class: "text-white flex-1 truncate", "{lore.title}" }
+            input {
+                r#type: "text",
+                placeholder: "Year",
+                value: "{value}",
+                oninput: move |e| value.set(e.value()),
+                onchange: save,
+                class: "w-20 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+            }
+        }
+    }
+}
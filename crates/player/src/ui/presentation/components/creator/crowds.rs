@@ -0,0 +1,327 @@
+//! Crowds - Groups of unnamed NPCs that fill a region
+//!
+//! A crowd is one entry for "dozens of dock workers": a name, an approximate
+//! headcount and a collective disposition. The DM picks a region, adds its
+//! crowds and puts them on or off stage; players see the ones on stage next
+//! to the region name. Promoting a crowd member drafts a named NPC from them.
+
+use dioxus::prelude::*;
+use wrldbldr_domain::DispositionLevel;
+
+use crate::application::dto::{CrowdData, CrowdInputData, NpcDraftSourceData};
+use crate::application::services::location_service::LocationSummary;
+use crate::application::services::RegionListItemData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{
+    use_crowd_service, use_location_service, use_npc_draft_service,
+};
+use crate::presentation::state::use_game_state;
+
+/// Crowds of one region, with a form to add another
+#[component]
+pub fn CrowdPanel(
+    /// Locations whose regions can hold crowds
+    locations: Signal<Vec<LocationSummary>>,
+) -> Element {
+    let crowd_service = use_crowd_service();
+    let location_service = use_location_service();
+    let mut expanded = use_signal(|| false);
+    let mut location_id = use_signal(String::new);
+    let mut regions: Signal<Vec<RegionListItemData>> = use_signal(Vec::new);
+    let mut region_id = use_signal(String::new);
+    let mut crowds: Signal<Vec<CrowdData>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load the chosen location's regions
+    use_effect(move || {
+        let location = location_id.read().clone();
+        region_id.set(String::new());
+        regions.set(Vec::new());
+        if location.is_empty() {
+            return;
+        }
+        let service = location_service.clone();
+        spawn_task(async move {
+            match service.get_regions(&location).await {
+                Ok(fetched) => regions.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load regions: {}", e))),
+            }
+        });
+    });
+
+    // Load the chosen region's crowds
+    {
+        let service = crowd_service.clone();
+        use_effect(move || {
+            let region = region_id.read().clone();
+            crowds.set(Vec::new());
+            if region.is_empty() {
+                return;
+            }
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_crowds(Some(&region)).await {
+                    Ok(fetched) => {
+                        crowds.set(fetched);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to load crowds: {}", e))),
+                }
+            });
+        });
+    }
+
+    let on_changed = move |updated: CrowdData| {
+        let mut list = crowds.write();
+        match list.iter_mut().find(|c| c.id == updated.id) {
+            Some(existing) => *existing = updated,
+            None => list.push(updated),
+        }
+    };
+    let on_deleted = move |crowd_id: String| crowds.write().retain(|c| c.id != crowd_id);
+    let on_error = move |message: String| error.set(Some(message));
+
+    rsx! {
+        div {
+            class: "crowd-panel flex flex-col gap-2 bg-dark-surface rounded-lg p-3",
+
+            button {
+                onclick: move |_| expanded.toggle(),
+                class: "bg-transparent border-0 p-0 text-left text-gray-400 text-sm uppercase cursor-pointer",
+                if *expanded.read() { "▾ Crowds" } else { "▸ Crowds" }
+            }
+
+            if *expanded.read() {
+                select {
+                    value: "{location_id}",
+                    onchange: move |e| location_id.set(e.value()),
+                    class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+
+                    option { value: "", "Choose a location" }
+                    for location in locations.read().iter() {
+                        option { key: "{location.id}", value: "{location.id}", "{location.name}" }
+                    }
+                }
+
+                if !location_id.read().is_empty() {
+                    select {
+                        value: "{region_id}",
+                        onchange: move |e| region_id.set(e.value()),
+                        class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+
+                        option { value: "", "Choose a region" }
+                        for region in regions.read().iter() {
+                            option { key: "{region.id}", value: "{region.id}", "{region.name}" }
+                        }
+                    }
+                }
+
+                if !region_id.read().is_empty() {
+                    if crowds.read().is_empty() {
+                        div { class: "text-gray-500 text-sm", "No crowds in this region" }
+                    }
+                    for crowd in crowds.read().iter().cloned() {
+                        CrowdRow {
+                            key: "{crowd.id}",
+                            crowd: crowd,
+                            on_changed: on_changed,
+                            on_deleted: on_deleted,
+                            on_error: on_error,
+                        }
+                    }
+                    NewCrowdForm {
+                        region_id: region_id.read().clone(),
+                        on_created: on_changed,
+                        on_error: on_error,
+                    }
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div { class: "text-red-400 text-xs", "{err}" }
+                }
+            }
+        }
+    }
+}
+
+/// One crowd with its stage toggle, promote and delete buttons
+#[component]
+fn CrowdRow(
+    crowd: CrowdData,
+    on_changed: EventHandler<CrowdData>,
+    on_deleted: EventHandler<String>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let crowd_service = use_crowd_service();
+    let npc_draft_service = use_npc_draft_service();
+    let game_state = use_game_state();
+
+    let toggle = {
+        let service = crowd_service.clone();
+        let crowd_id = crowd.id.clone();
+        let present = !crowd.is_present;
+        move |_| {
+            let service = service.clone();
+            let crowd_id = crowd_id.clone();
+            spawn_task(async move {
+                match service.set_crowd_present(&crowd_id, present).await {
+                    Ok(updated) => on_changed.call(updated),
+                    Err(e) => on_error.call(format!("Failed to update crowd: {}", e)),
+                }
+            });
+        }
+    };
+
+    // The draft appears in the NPC drafts panel, filled in by the LLM
+    let promote = {
+        let crowd_id = crowd.id.clone();
+        move |_| {
+            let service = npc_draft_service.clone();
+            let source = NpcDraftSourceData::Crowd {
+                crowd_id: crowd_id.clone(),
+            };
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service.promote(source, None).await {
+                    Ok(draft) => game_state.upsert_npc_draft(draft),
+                    Err(e) => on_error.call(format!("Failed to promote crowd member: {}", e)),
+                }
+            });
+        }
+    };
+
+    let delete = {
+        let crowd_id = crowd.id.clone();
+        move |_| {
+            let service = crowd_service.clone();
+            let crowd_id = crowd_id.clone();
+            spawn_task(async move {
+                match service.delete_crowd(&crowd_id).await {
+                    Ok(()) => on_deleted.call(crowd_id),
+                    Err(e) => on_error.call(format!("Failed to delete crowd: {}", e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 text-sm",
+            span {
+                class: "text-white flex-1 truncate",
+                title: "{crowd.description}",
+                "{crowd.name}"
+            }
+            span {
+                class: "text-gray-400 text-xs",
+                "~{crowd.approximate_count} · {crowd.disposition.display_name()}"
+            }
+            button {
+                onclick: toggle,
+                class: if crowd.is_present {
+                    "px-2 py-0.5 bg-green-700 text-white text-xs rounded cursor-pointer"
+                } else {
+                    "px-2 py-0.5 bg-gray-700 text-gray-300 text-xs rounded cursor-pointer"
+                },
+                if crowd.is_present { "On stage" } else { "Off stage" }
+            }
+            button {
+                onclick: promote,
+                title: "Promote one of them to a named NPC",
+                class: "px-2 py-0.5 bg-purple-900 text-purple-200 text-xs rounded cursor-pointer",
+                "Promote"
+            }
+            button {
+                onclick: delete,
+                class: "px-2 py-0.5 bg-transparent border-0 text-red-400 text-xs cursor-pointer",
+                "✕"
+            }
+        }
+    }
+}
+
+/// Name, count and disposition inputs for a new crowd
+#[component]
+fn NewCrowdForm(
+    region_id: String,
+    on_created: EventHandler<CrowdData>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let crowd_service = use_crowd_service();
+    let mut name = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut count = use_signal(|| "20".to_string());
+    let mut disposition = use_signal(DispositionLevel::default);
+
+    let create = move |_| {
+        let Ok(approximate_count) = count.read().trim().parse::<u32>() else {
+            on_error.call(format!("'{}' is not a headcount", count.read().trim()));
+            return;
+        };
+        let data = CrowdInputData {
+            name: name.read().trim().to_string(),
+            description: description.read().trim().to_string(),
+            approximate_count,
+            disposition: *disposition.read(),
+        };
+        let region_id = region_id.clone();
+        let service = crowd_service.clone();
+        spawn_task(async move {
+            match service.create_crowd(&region_id, data).await {
+                Ok(created) => {
+                    name.set(String::new());
+                    description.set(String::new());
+                    on_created.call(created);
+                }
+                Err(e) => on_error.call(format!("Failed to create crowd: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 border-t border-gray-700 pt-2",
+
+            div {
+                class: "flex gap-1",
+                input {
+                    r#type: "text",
+                    value: "{name}",
+                    placeholder: "Dock workers",
+                    oninput: move |e| name.set(e.value()),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+                input {
+                    r#type: "number",
+                    min: "2",
+                    value: "{count}",
+                    oninput: move |e| count.set(e.value()),
+                    class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+                select {
+                    value: "{disposition.read().display_name()}",
+                    onchange: move |e| {
+                        disposition.set(e.value().parse().unwrap_or_default());
+                    },
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    for level in DispositionLevel::all() {
+                        option { key: "{level}", value: "{level}", "{level.display_name()}" }
+                    }
+                }
+            }
+            input {
+                r#type: "text",
+                value: "{description}",
+                placeholder: "What they are doing (optional)",
+                oninput: move |e| description.set(e.value()),
+                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+            }
+            button {
+                onclick: create,
+                disabled: name.read().trim().is_empty(),
+                class: "px-2 py-1 bg-blue-500 text-white text-xs rounded cursor-pointer",
+                "Add crowd"
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Custom fields - Value editor for DM-defined entity fields
+//!
+//! Shows every custom field the world defines for the entity's type (set up
+//! under Settings → World Settings) and saves the values in one request. An
+//! empty input clears the value.
+
+use std::collections::{BTreeMap, HashMap};
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{CustomFieldEntryData, CustomFieldTypeData, CustomFieldValueData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use wrldbldr_protocol::EntityType;
+
+/// A value as it appears in an input
+fn value_text(value: &Option<CustomFieldValueData>) -> String {
+    match value {
+        Some(CustomFieldValueData::Number(n)) => n.to_string(),
+        Some(CustomFieldValueData::Text(text)) => text.clone(),
+        None => String::new(),
+    }
+}
+
+/// Turn the inputs into the values to save, skipping empty ones
+fn parse_values(
+    entries: &[CustomFieldEntryData],
+    drafts: &HashMap<String, String>,
+) -> Result<BTreeMap<String, CustomFieldValueData>, String> {
+    let mut values = BTreeMap::new();
+    for entry in entries {
+        let raw = drafts
+            .get(&entry.field.key)
+            .cloned()
+            .unwrap_or_else(|| value_text(&entry.value));
+        let raw = raw.trim();
+        if raw.is_empty() {
+            continue;
+        }
+        let value = match entry.field.field_type {
+            CustomFieldTypeData::Number => raw
+                .parse::<f64>()
+                .map(CustomFieldValueData::Number)
+                .map_err(|_| format!("{} must be a number", entry.field.label))?,
+            _ => CustomFieldValueData::Text(raw.to_string()),
+        };
+        values.insert(entry.field.key.clone(), value);
+    }
+    Ok(values)
+}
+
+/// Inputs for one entity's custom fields with a Save button
+#[component]
+pub fn CustomFieldsEditor(
+    world_id: String,
+    entity_type: EntityType,
+    entity_id: String,
+    /// Fields and saved values (from the entity's detail response)
+    entries: Signal<Vec<CustomFieldEntryData>>,
+) -> Element {
+    let world_service = use_world_service();
+    let mut entries = entries;
+    // Unsaved input text by field key
+    let mut drafts: Signal<HashMap<String, String>> = use_signal(HashMap::new);
+    let mut is_saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let save = move |_| {
+        let values = match parse_values(&entries.read(), &drafts.read()) {
+            Ok(values) => values,
+            Err(msg) => {
+                error.set(Some(msg));
+                return;
+            }
+        };
+        let world_id = world_id.clone();
+        let entity_id = entity_id.clone();
+        let service = world_service.clone();
+        spawn_task(async move {
+            is_saving.set(true);
+            match service
+                .set_custom_field_values(&world_id, entity_type, &entity_id, values)
+                .await
+            {
+                Ok(saved) => {
+                    entries.set(saved);
+                    drafts.write().clear();
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(format!("Failed to save fields: {}", e))),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let current = entries.read().clone();
+
+    rsx! {
+        div {
+            class: "custom-fields-editor flex flex-col gap-2",
+
+            for entry in current {
+                {
+                    let key = entry.field.key.clone();
+                    let text = drafts
+                        .read()
+                        .get(&key)
+                        .cloned()
+                        .unwrap_or_else(|| value_text(&entry.value));
+                    rsx! {
+                        label {
+                            key: "{key}",
+                            class: "flex items-center gap-2 text-gray-300 text-sm",
+                            span {
+                                class: "w-32 shrink-0",
+                                title: if entry.field.visible_to_players { "Visible to players" } else { "DM only" },
+                                "{entry.field.label}"
+                            }
+                            match entry.field.field_type {
+                                CustomFieldTypeData::Select => rsx! {
+                                    select {
+                                        class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                                        value: "{text}",
+                                        onchange: move |e| {
+                                            drafts.write().insert(key.clone(), e.value());
+                                        },
+                                        option { value: "", "—" }
+                                        for opt in entry.field.options.iter() {
+                                            option { value: "{opt}", "{opt}" }
+                                        }
+                                    }
+                                },
+                                CustomFieldTypeData::Number => rsx! {
+                                    input {
+                                        r#type: "number",
+                                        step: "any",
+                                        class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                                        value: "{text}",
+                                        oninput: move |e| {
+                                            drafts.write().insert(key.clone(), e.value());
+                                        },
+                                    }
+                                },
+                                _ => rsx! {
+                                    input {
+                                        r#type: "text",
+                                        class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                                        value: "{text}",
+                                        oninput: move |e| {
+                                            drafts.write().insert(key.clone(), e.value());
+                                        },
+                                    }
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "flex justify-end",
+                button {
+                    onclick: save,
+                    disabled: *is_saving.read() || drafts.read().is_empty(),
+                    class: "px-3 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer disabled:opacity-50",
+                    if *is_saving.read() { "Saving..." } else { "Save fields" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{err}" }
+            }
+        }
+    }
+}
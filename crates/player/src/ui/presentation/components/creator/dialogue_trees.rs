@@ -0,0 +1,520 @@
+//! Dialogue Trees - Scripted beats the DM writes ahead of time
+//!
+//! Each tree is spoken by one NPC: a start node with a line, and choices
+//! that lead to further nodes or end the beat. A tree plays the first time
+//! a PC talks to its speaker, or to everyone present when its narrative
+//! event triggers. Choices can set world flags; effects written elsewhere
+//! are kept as they are.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{
+    DialogueBranchData, DialogueNodeData, DialogueTreeData, DialogueTreeInputData,
+};
+use crate::application::services::character_service::CharacterSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_dialogue_tree_service, use_narrative_event_service};
+
+/// The world's dialogue trees, with a form to write another
+#[component]
+pub fn DialogueTreePanel(
+    world_id: String,
+    /// NPCs that can speak a tree
+    characters: Signal<Vec<CharacterSummary>>,
+) -> Element {
+    let tree_service = use_dialogue_tree_service();
+    let event_service = use_narrative_event_service();
+    let mut expanded = use_signal(|| false);
+    let mut trees: Signal<Vec<DialogueTreeData>> = use_signal(Vec::new);
+    let mut events: Signal<Vec<(String, String)>> = use_signal(Vec::new);
+    let mut writing = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load trees and the events they can hang off when the panel opens
+    let list_service = tree_service.clone();
+    use_effect(move || {
+        if !*expanded.read() {
+            return;
+        }
+        let service = list_service.clone();
+        spawn_task(async move {
+            match service.list_trees().await {
+                Ok(fetched) => {
+                    trees.set(fetched);
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(format!("Failed to load dialogue trees: {}", e))),
+            }
+        });
+        let service = event_service.clone();
+        let world_id = world_id.clone();
+        spawn_task(async move {
+            if let Ok(fetched) = service.list_narrative_events(&world_id).await {
+                events.set(fetched.into_iter().map(|e| (e.id, e.name)).collect());
+            }
+        });
+    });
+
+    let on_changed = move |updated: DialogueTreeData| {
+        let mut list = trees.write();
+        match list.iter_mut().find(|t| t.id == updated.id) {
+            Some(existing) => *existing = updated,
+            None => list.push(updated),
+        }
+    };
+    let on_deleted = move |tree_id: String| trees.write().retain(|t| t.id != tree_id);
+    let on_error = move |message: String| error.set(Some(message));
+
+    let create = move |data: DialogueTreeInputData| {
+        let service = tree_service.clone();
+        spawn_task(async move {
+            match service.create_tree(data).await {
+                Ok(created) => {
+                    writing.set(false);
+                    trees.write().push(created);
+                }
+                Err(e) => error.set(Some(format!("Failed to create dialogue tree: {}", e))),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "dialogue-tree-panel flex flex-col gap-2 bg-dark-surface rounded-lg p-3",
+
+            button {
+                onclick: move |_| expanded.toggle(),
+                class: "bg-transparent border-0 p-0 text-left text-gray-400 text-sm uppercase cursor-pointer",
+                if *expanded.read() { "▾ Dialogue Trees" } else { "▸ Dialogue Trees" }
+            }
+
+            if *expanded.read() {
+                if trees.read().is_empty() {
+                    div { class: "text-gray-500 text-sm", "No dialogue trees yet" }
+                }
+                for tree in trees.read().iter().cloned() {
+                    DialogueTreeRow {
+                        key: "{tree.id}",
+                        tree: tree,
+                        characters: characters,
+                        events: events,
+                        on_changed: on_changed,
+                        on_deleted: on_deleted,
+                        on_error: on_error,
+                    }
+                }
+
+                if *writing.read() {
+                    DialogueTreeEditor {
+                        initial: blank_tree(),
+                        characters: characters,
+                        events: events,
+                        save_label: "Add tree",
+                        on_save: create,
+                        on_cancel: move |_| writing.set(false),
+                    }
+                } else {
+                    button {
+                        onclick: move |_| writing.set(true),
+                        class: "px-2 py-1 bg-blue-500 text-white text-xs rounded cursor-pointer",
+                        "Write a dialogue tree"
+                    }
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div { class: "text-red-400 text-xs", "{err}" }
+                }
+            }
+        }
+    }
+}
+
+/// One tree: who speaks it, how many PCs heard it, and its editor when open
+#[component]
+fn DialogueTreeRow(
+    tree: DialogueTreeData,
+    characters: Signal<Vec<CharacterSummary>>,
+    events: Signal<Vec<(String, String)>>,
+    on_changed: EventHandler<DialogueTreeData>,
+    on_deleted: EventHandler<String>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let tree_service = use_dialogue_tree_service();
+    let mut editing = use_signal(|| false);
+
+    let save = {
+        let service = tree_service.clone();
+        let tree_id = tree.id.clone();
+        move |data: DialogueTreeInputData| {
+            let service = service.clone();
+            let tree_id = tree_id.clone();
+            spawn_task(async move {
+                match service.update_tree(&tree_id, data).await {
+                    Ok(updated) => {
+                        editing.set(false);
+                        on_changed.call(updated);
+                    }
+                    Err(e) => on_error.call(format!("Failed to save dialogue tree: {}", e)),
+                }
+            });
+        }
+    };
+
+    let reset = {
+        let service = tree_service.clone();
+        let tree_id = tree.id.clone();
+        move |_| {
+            let service = service.clone();
+            let tree_id = tree_id.clone();
+            spawn_task(async move {
+                match service.reset_tree(&tree_id).await {
+                    Ok(updated) => on_changed.call(updated),
+                    Err(e) => on_error.call(format!("Failed to reset dialogue tree: {}", e)),
+                }
+            });
+        }
+    };
+
+    let delete = {
+        let tree_id = tree.id.clone();
+        move |_| {
+            let service = tree_service.clone();
+            let tree_id = tree_id.clone();
+            spawn_task(async move {
+                match service.delete_tree(&tree_id).await {
+                    Ok(()) => on_deleted.call(tree_id),
+                    Err(e) => on_error.call(format!("Failed to delete dialogue tree: {}", e)),
+                }
+            });
+        }
+    };
+
+    let trigger = match &tree.narrative_event_id {
+        Some(event_id) => events
+            .read()
+            .iter()
+            .find(|(id, _)| id == event_id)
+            .map(|(_, name)| format!("on {}", name))
+            .unwrap_or_else(|| "on an event".to_string()),
+        None => format!("talking to {}", tree.speaker_name),
+    };
+    let played = if tree.repeatable {
+        "repeats".to_string()
+    } else {
+        format!("heard by {}", tree.played_count)
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 text-sm",
+
+            div {
+                class: "flex items-center gap-2",
+                button {
+                    onclick: move |_| editing.toggle(),
+                    class: if tree.active {
+                        "bg-transparent border-0 p-0 text-left text-white flex-1 truncate cursor-pointer"
+                    } else {
+                        "bg-transparent border-0 p-0 text-left text-gray-500 flex-1 truncate cursor-pointer"
+                    },
+                    title: "{tree.speaker_name}, {trigger}",
+                    "{tree.name}"
+                }
+                span { class: "text-gray-400 text-xs", "{played}" }
+                if !tree.repeatable && tree.played_count > 0 {
+                    button {
+                        onclick: reset,
+                        title: "Let PCs who heard it hear it again",
+                        class: "px-2 py-0.5 bg-gray-700 text-gray-300 text-xs rounded cursor-pointer",
+                        "Reset"
+                    }
+                }
+                button {
+                    onclick: delete,
+                    class: "px-2 py-0.5 bg-transparent border-0 text-red-400 text-xs cursor-pointer",
+                    "✕"
+                }
+            }
+
+            if *editing.read() {
+                DialogueTreeEditor {
+                    initial: tree_input(&tree),
+                    characters: characters,
+                    events: events,
+                    save_label: "Save",
+                    on_save: save,
+                    on_cancel: move |_| editing.set(false),
+                }
+            }
+        }
+    }
+}
+
+/// Speaker, trigger and nodes of a tree, saved as a whole
+#[component]
+fn DialogueTreeEditor(
+    initial: DialogueTreeInputData,
+    characters: Signal<Vec<CharacterSummary>>,
+    events: Signal<Vec<(String, String)>>,
+    save_label: &'static str,
+    on_save: EventHandler<DialogueTreeInputData>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let mut name = use_signal(|| initial.name.clone());
+    let mut speaker_id = use_signal(|| initial.speaker_id.clone());
+    let mut event_id = use_signal(|| initial.narrative_event_id.clone().unwrap_or_default());
+    let mut active = use_signal(|| initial.active);
+    let mut repeatable = use_signal(|| initial.repeatable);
+    let mut nodes = use_signal(|| initial.nodes.clone());
+
+    let save = move |_| {
+        let event_id = event_id.read().clone();
+        on_save.call(DialogueTreeInputData {
+            name: name.read().trim().to_string(),
+            speaker_id: speaker_id.read().clone(),
+            narrative_event_id: (!event_id.is_empty()).then_some(event_id),
+            nodes: nodes.read().clone(),
+            active: *active.read(),
+            repeatable: *repeatable.read(),
+        });
+    };
+    let add_node = move |_| {
+        let id = next_node_id(&nodes.read());
+        nodes.write().push(DialogueNodeData {
+            id,
+            line: String::new(),
+            choices: Vec::new(),
+        });
+    };
+
+    let node_ids: Vec<String> = nodes.read().iter().map(|n| n.id.clone()).collect();
+    let can_save =
+        !name.read().trim().is_empty() && !speaker_id.read().is_empty() && !nodes.read().is_empty();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 border-t border-gray-700 pt-2",
+
+            input {
+                r#type: "text",
+                value: "{name}",
+                placeholder: "The ferryman's riddle",
+                oninput: move |e| name.set(e.value()),
+                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+            }
+            div {
+                class: "flex gap-1",
+                select {
+                    value: "{speaker_id}",
+                    onchange: move |e| speaker_id.set(e.value()),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    option { value: "", "Who speaks" }
+                    for character in characters.read().iter() {
+                        option { key: "{character.id}", value: "{character.id}", "{character.name}" }
+                    }
+                }
+                select {
+                    value: "{event_id}",
+                    onchange: move |e| event_id.set(e.value()),
+                    title: "Play when a PC talks to the speaker, or when an event triggers",
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    option { value: "", "When a PC talks to them" }
+                    for (id, event_name) in events.read().iter() {
+                        option { key: "{id}", value: "{id}", "When {event_name} triggers" }
+                    }
+                }
+            }
+            div {
+                class: "flex gap-3 text-xs text-gray-300",
+                label {
+                    class: "flex items-center gap-1",
+                    input {
+                        r#type: "checkbox",
+                        checked: *active.read(),
+                        onchange: move |e| active.set(e.checked()),
+                    }
+                    "Active"
+                }
+                label {
+                    class: "flex items-center gap-1",
+                    input {
+                        r#type: "checkbox",
+                        checked: *repeatable.read(),
+                        onchange: move |e| repeatable.set(e.checked()),
+                    }
+                    "Play every time"
+                }
+            }
+
+            for (index, node) in nodes.read().iter().cloned().enumerate() {
+                DialogueNodeEditor {
+                    key: "{index}",
+                    index: index,
+                    node: node,
+                    node_ids: node_ids.clone(),
+                    nodes: nodes,
+                }
+            }
+            button {
+                onclick: add_node,
+                class: "px-2 py-0.5 bg-gray-700 text-gray-300 text-xs rounded cursor-pointer",
+                "+ Node"
+            }
+
+            div {
+                class: "flex gap-1",
+                button {
+                    onclick: save,
+                    disabled: !can_save,
+                    class: "flex-1 px-2 py-1 bg-blue-500 text-white text-xs rounded cursor-pointer",
+                    "{save_label}"
+                }
+                button {
+                    onclick: move |_| on_cancel.call(()),
+                    class: "px-2 py-1 bg-gray-700 text-gray-300 text-xs rounded cursor-pointer",
+                    "Cancel"
+                }
+            }
+        }
+    }
+}
+
+/// One node: its line, and each choice with where it leads and the flags it
+/// sets. The first node is where the beat starts.
+#[component]
+fn DialogueNodeEditor(
+    index: usize,
+    node: DialogueNodeData,
+    node_ids: Vec<String>,
+    nodes: Signal<Vec<DialogueNodeData>>,
+) -> Element {
+    let mut nodes = nodes;
+    let heading = if index == 0 { "Start" } else { "Node" };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 pl-2 border-l border-gray-700",
+
+            div {
+                class: "flex items-center gap-1",
+                span { class: "text-gray-500 text-xs uppercase", "{heading}" }
+                input {
+                    r#type: "text",
+                    value: "{node.id}",
+                    title: "Choices lead here by this key",
+                    oninput: move |e| nodes.write()[index].id = e.value(),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                }
+                button {
+                    onclick: move |_| {
+                        nodes.write().remove(index);
+                    },
+                    class: "bg-transparent border-0 text-red-400 text-xs cursor-pointer",
+                    "✕"
+                }
+            }
+            textarea {
+                value: "{node.line}",
+                placeholder: "What the speaker says",
+                oninput: move |e| nodes.write()[index].line = e.value(),
+                class: "w-full min-h-10 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs resize-y box-border",
+            }
+
+            for (choice_index, choice) in node.choices.iter().cloned().enumerate() {
+                div {
+                    key: "{choice_index}",
+                    class: "flex gap-1 items-center",
+                    input {
+                        r#type: "text",
+                        value: "{choice.text}",
+                        placeholder: "The player answers…",
+                        oninput: move |e| nodes.write()[index].choices[choice_index].text = e.value(),
+                        class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    }
+                    select {
+                        value: "{choice.next.clone().unwrap_or_default()}",
+                        onchange: move |e| {
+                            let next = e.value();
+                            nodes.write()[index].choices[choice_index].next =
+                                (!next.is_empty()).then_some(next);
+                        },
+                        class: "w-24 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        option { value: "", "Ends" }
+                        for id in node_ids.iter() {
+                            option { key: "{id}", value: "{id}", "→ {id}" }
+                        }
+                    }
+                    input {
+                        r#type: "text",
+                        value: "{choice.set_flags.join(\", \")}",
+                        placeholder: "Flags",
+                        title: "World flags the choice sets, comma separated",
+                        oninput: move |e| {
+                            nodes.write()[index].choices[choice_index].set_flags = e
+                                .value()
+                                .split(',')
+                                .map(str::trim)
+                                .filter(|f| !f.is_empty())
+                                .map(String::from)
+                                .collect();
+                        },
+                        class: "w-24 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    }
+                    button {
+                        onclick: move |_| {
+                            nodes.write()[index].choices.remove(choice_index);
+                        },
+                        class: "bg-transparent border-0 text-red-400 text-xs cursor-pointer",
+                        "✕"
+                    }
+                }
+            }
+            button {
+                onclick: move |_| {
+                    nodes.write()[index].choices.push(DialogueBranchData {
+                        text: String::new(),
+                        next: None,
+                        set_flags: Vec::new(),
+                        effects: Vec::new(),
+                    });
+                },
+                class: "self-start px-2 py-0.5 bg-transparent border-0 text-blue-400 text-xs cursor-pointer",
+                "+ Choice"
+            }
+        }
+    }
+}
+
+fn blank_tree() -> DialogueTreeInputData {
+    DialogueTreeInputData {
+        name: String::new(),
+        speaker_id: String::new(),
+        narrative_event_id: None,
+        nodes: vec![DialogueNodeData {
+            id: "start".to_string(),
+            line: String::new(),
+            choices: Vec::new(),
+        }],
+        active: true,
+        repeatable: false,
+    }
+}
+
+fn tree_input(tree: &DialogueTreeData) -> DialogueTreeInputData {
+    DialogueTreeInputData {
+        name: tree.name.clone(),
+        speaker_id: tree.speaker_id.clone(),
+        narrative_event_id: tree.narrative_event_id.clone(),
+        nodes: tree.nodes.clone(),
+        active: tree.active,
+        repeatable: tree.repeatable,
+    }
+}
+
+/// A key no node in the tree uses yet
+fn next_node_id(nodes: &[DialogueNodeData]) -> String {
+    (nodes.len() + 1..)
+        .map(|n| format!("node-{}", n))
+        .find(|id| !nodes.iter().any(|node| &node.id == id))
+        .unwrap_or_default()
+}
@@ -0,0 +1,284 @@
+//! Content drafts - Revision history and side-by-side diff for a text field
+//!
+//! The panel keeps the form's current text as a revision of the field's
+//! draft (marked "generated" when it came straight from a suggestion), shows
+//! any two revisions side by side, and publishes the chosen revision to the
+//! entity. The form field only changes when a revision is published.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{
+    ContentDraftData, DiffOpData, DiffSegmentData, DraftFieldData, DraftStatusData,
+    RevisionSourceData,
+};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_draft_service;
+use wrldbldr_protocol::EntityType;
+
+/// Revisions of one text field of one entity
+#[component]
+pub fn DraftPanel(
+    world_id: String,
+    entity_type: EntityType,
+    entity_id: String,
+    field: DraftFieldData,
+    /// Field name shown in the header (e.g., "Description")
+    label: String,
+    /// The form's value for the field; set when a revision is published
+    text: Signal<String>,
+    /// Whether `text` is an untouched suggestion
+    generated: Signal<bool>,
+) -> Element {
+    let draft_service = use_draft_service();
+    let mut draft: Signal<Option<ContentDraftData>> = use_signal(|| None);
+    let mut selected: Signal<Option<u32>> = use_signal(|| None);
+    let mut diff: Signal<Vec<DiffSegmentData>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load the field's draft on mount
+    {
+        let entity_id = entity_id.clone();
+        let service = draft_service.clone();
+        use_effect(move || {
+            let entity_id = entity_id.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_drafts(entity_type, &entity_id).await {
+                    Ok(drafts) => draft.set(drafts.into_iter().find(|d| d.field == field)),
+                    Err(e) => error.set(Some(format!("Failed to load drafts: {}", e))),
+                }
+            });
+        });
+    }
+
+    // Compare the selected revision with the published one (or the one
+    // before it when nothing, or the selection itself, is published)
+    let compare_from = {
+        let draft = draft.read();
+        let selected = *selected.read();
+        match (draft.as_ref(), selected) {
+            (Some(d), Some(to)) => d.published_revision.filter(|&p| p != to).or_else(|| {
+                d.revisions
+                    .iter()
+                    .map(|r| r.number)
+                    .filter(|&n| n < to)
+                    .max()
+            }),
+            _ => None,
+        }
+    };
+
+    // Fetch the diff whenever the compared pair changes
+    {
+        let service = draft_service.clone();
+        use_effect(use_reactive!(|compare_from| {
+            let Some(to) = *selected.read() else {
+                diff.set(Vec::new());
+                return;
+            };
+            let Some(from) = compare_from else {
+                diff.set(Vec::new());
+                return;
+            };
+            let Some(draft_id) = draft.peek().as_ref().map(|d| d.id.clone()) else {
+                return;
+            };
+            let service = service.clone();
+            spawn_task(async move {
+                match service.diff(&draft_id, from, to).await {
+                    Ok(result) => diff.set(result.segments),
+                    Err(e) => error.set(Some(format!("Failed to compare revisions: {}", e))),
+                }
+            });
+        }));
+    }
+
+    let save_revision = {
+        let world_id = world_id.clone();
+        let entity_id = entity_id.clone();
+        let service = draft_service.clone();
+        move |_| {
+            let world_id = world_id.clone();
+            let entity_id = entity_id.clone();
+            let service = service.clone();
+            let value = text.read().clone();
+            let source = if *generated.read() {
+                RevisionSourceData::Generated
+            } else {
+                RevisionSourceData::Edited
+            };
+            spawn_task(async move {
+                match service
+                    .add_revision(&world_id, entity_type, &entity_id, field, &value, source)
+                    .await
+                {
+                    Ok(updated) => {
+                        error.set(None);
+                        selected.set(updated.revisions.last().map(|r| r.number));
+                        draft.set(Some(updated));
+                    }
+                    Err(e) => error.set(Some(format!("Failed to save revision: {}", e))),
+                }
+            });
+        }
+    };
+
+    let publish = {
+        let service = draft_service.clone();
+        move |_| {
+            let Some(number) = *selected.read() else {
+                return;
+            };
+            let Some(draft_id) = draft.read().as_ref().map(|d| d.id.clone()) else {
+                return;
+            };
+            let service = service.clone();
+            spawn_task(async move {
+                match service.publish(&draft_id, number).await {
+                    Ok(updated) => {
+                        error.set(None);
+                        if let Some(revision) =
+                            updated.revisions.iter().find(|r| r.number == number)
+                        {
+                            text.set(revision.text.clone());
+                            generated.set(false);
+                        }
+                        draft.set(Some(updated));
+                    }
+                    Err(e) => error.set(Some(format!("Failed to publish revision: {}", e))),
+                }
+            });
+        }
+    };
+
+    let discard = {
+        let service = draft_service.clone();
+        move |_| {
+            let Some(draft_id) = draft.read().as_ref().map(|d| d.id.clone()) else {
+                return;
+            };
+            let service = service.clone();
+            spawn_task(async move {
+                match service.discard(&draft_id).await {
+                    Ok(()) => {
+                        draft.set(None);
+                        selected.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to discard draft: {}", e))),
+                }
+            });
+        }
+    };
+
+    let current = draft.read().clone();
+    let status_label = match current.as_ref().map(|d| d.status) {
+        Some(DraftStatusData::Published) => "Published",
+        Some(_) => "Draft",
+        None => "No revisions",
+    };
+
+    rsx! {
+        div {
+            class: "draft-panel flex flex-col gap-2",
+
+            div {
+                class: "flex items-center gap-2",
+                span { class: "text-gray-300 text-sm flex-1", "{label}" }
+                span { class: "px-1 bg-gray-700 text-gray-300 text-xs rounded", "{status_label}" }
+                button {
+                    onclick: save_revision,
+                    disabled: text.read().trim().is_empty(),
+                    class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                    "Save as revision"
+                }
+            }
+
+            if let Some(current) = current {
+                div {
+                    class: "flex flex-wrap gap-1",
+                    for revision in current.revisions.iter().rev().cloned() {
+                        button {
+                            key: "{revision.number}",
+                            onclick: move |_| selected.set(Some(revision.number)),
+                            title: "{revision.created_at}",
+                            class: if *selected.read() == Some(revision.number) {
+                                "px-2 py-0.5 bg-blue-500 text-white text-xs rounded cursor-pointer border-0"
+                            } else {
+                                "px-2 py-0.5 bg-dark-bg text-gray-300 text-xs rounded cursor-pointer border border-gray-700"
+                            },
+                            "r{revision.number}"
+                            if revision.source == RevisionSourceData::Generated { " ✦" }
+                            if current.published_revision == Some(revision.number) { " ✓" }
+                        }
+                    }
+                }
+
+                if let Some(number) = *selected.read() {
+                    if let Some(from) = compare_from {
+                        SideBySideDiff { from: from, to: number, segments: diff.read().clone() }
+                    } else if let Some(revision) = current.revisions.iter().find(|r| r.number == number) {
+                        div { class: "p-2 bg-dark-bg rounded text-gray-300 text-sm whitespace-pre-wrap", "{revision.text}" }
+                    }
+
+                    div {
+                        class: "flex gap-2 justify-end",
+                        button {
+                            onclick: discard,
+                            class: "px-2 py-1 bg-transparent border border-gray-700 text-gray-400 text-xs rounded cursor-pointer mr-auto",
+                            "Discard draft"
+                        }
+                        button {
+                            onclick: publish,
+                            disabled: current.published_revision == Some(number),
+                            class: "px-2 py-1 bg-blue-500 text-white text-xs rounded cursor-pointer",
+                            "Publish r{number}"
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{err}" }
+            }
+        }
+    }
+}
+
+/// Two columns: the older revision with removed words struck through, the
+/// newer one with added words highlighted
+#[component]
+fn SideBySideDiff(from: u32, to: u32, segments: Vec<DiffSegmentData>) -> Element {
+    rsx! {
+        div {
+            class: "grid grid-cols-2 gap-2 text-sm",
+
+            div {
+                class: "p-2 bg-dark-bg rounded text-gray-300 whitespace-pre-wrap",
+                div { class: "text-gray-500 text-xs mb-1", "r{from}" }
+                for (index, segment) in segments.iter().enumerate() {
+                    match segment.op {
+                        DiffOpData::Equal => rsx! { span { key: "{index}", "{segment.text}" } },
+                        DiffOpData::Delete => rsx! {
+                            span { key: "{index}", class: "bg-red-500/20 text-red-300 line-through", "{segment.text}" }
+                        },
+                        _ => rsx! {},
+                    }
+                }
+            }
+
+            div {
+                class: "p-2 bg-dark-bg rounded text-gray-300 whitespace-pre-wrap",
+                div { class: "text-gray-500 text-xs mb-1", "r{to}" }
+                for (index, segment) in segments.iter().enumerate() {
+                    match segment.op {
+                        DiffOpData::Equal => rsx! { span { key: "{index}", "{segment.text}" } },
+                        DiffOpData::Insert => rsx! {
+                            span { key: "{index}", class: "bg-green-500/20 text-green-300", "{segment.text}" }
+                        },
+                        _ => rsx! {},
+                    }
+                }
+            }
+        }
+    }
+}
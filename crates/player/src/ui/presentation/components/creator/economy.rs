@@ -0,0 +1,436 @@
+//! Economy - What goods cost from one location to the next
+//!
+//! The DM picks a location and sees, per item type, how scarce goods are
+//! there, what they cost against their base price and why. Base prices are
+//! edited in place. Market modifiers make goods scarcer, cheaper or dearer
+//! here or everywhere; a modifier with a flag only applies while that world
+//! flag is set, so events change prices by setting flags.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{
+    EconomyOverviewData, MarketItemData, MarketModifierData, MarketModifierInputData, ScarcityData,
+};
+use crate::application::services::location_service::LocationSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_economy_service;
+
+const SCARCITIES: [ScarcityData; 5] = [
+    ScarcityData::Abundant,
+    ScarcityData::Common,
+    ScarcityData::Scarce,
+    ScarcityData::Rare,
+    ScarcityData::Unavailable,
+];
+
+fn scarcity_label(scarcity: ScarcityData) -> &'static str {
+    match scarcity {
+        ScarcityData::Abundant => "Abundant",
+        ScarcityData::Common => "Common",
+        ScarcityData::Scarce => "Scarce",
+        ScarcityData::Rare => "Rare",
+        ScarcityData::Unavailable => "Unavailable",
+        ScarcityData::Unknown => "Unknown",
+    }
+}
+
+fn scarcity_class(scarcity: ScarcityData) -> &'static str {
+    match scarcity {
+        ScarcityData::Abundant => "text-green-400",
+        ScarcityData::Common | ScarcityData::Unknown => "text-gray-400",
+        ScarcityData::Scarce => "text-amber-400",
+        ScarcityData::Rare => "text-orange-400",
+        ScarcityData::Unavailable => "text-red-400",
+    }
+}
+
+/// One location's market overview, with its modifiers and a form to add one
+#[component]
+pub fn EconomyPanel(
+    /// Locations whose markets can be inspected
+    locations: Signal<Vec<LocationSummary>>,
+) -> Element {
+    let economy_service = use_economy_service();
+    let mut expanded = use_signal(|| false);
+    let mut location_id = use_signal(String::new);
+    let mut overview: Signal<Option<EconomyOverviewData>> = use_signal(|| None);
+    let mut version = use_signal(|| 0u32);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load the chosen location's overview, again after every change
+    use_effect(move || {
+        let location = location_id.read().clone();
+        let _ = *version.read();
+        if location.is_empty() {
+            overview.set(None);
+            return;
+        }
+        let service = economy_service.clone();
+        spawn_task(async move {
+            match service.overview(&location).await {
+                Ok(fetched) => {
+                    overview.set(Some(fetched));
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(format!("Failed to load economy: {}", e))),
+            }
+        });
+    });
+
+    let on_changed = move |_| version += 1;
+    let on_error = move |message: String| error.set(Some(message));
+
+    rsx! {
+        div {
+            class: "economy-panel flex flex-col gap-2 bg-dark-surface rounded-lg p-3",
+
+            button {
+                onclick: move |_| expanded.toggle(),
+                class: "bg-transparent border-0 p-0 text-left text-gray-400 text-sm uppercase cursor-pointer",
+                if *expanded.read() { "▾ Economy" } else { "▸ Economy" }
+            }
+
+            if *expanded.read() {
+                select {
+                    value: "{location_id}",
+                    onchange: move |e| location_id.set(e.value()),
+                    class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+
+                    option { value: "", "Choose a location" }
+                    for location in locations.read().iter() {
+                        option { key: "{location.id}", value: "{location.id}", "{location.name}" }
+                    }
+                }
+
+                if let Some(data) = overview.read().clone() {
+                    if data.categories.is_empty() {
+                        div { class: "text-gray-500 text-sm", "No items or market modifiers yet" }
+                    }
+                    for category in data.categories.iter().cloned() {
+                        div {
+                            key: "{category.category.clone().unwrap_or_default()}",
+                            class: "flex flex-col gap-1 border-t border-gray-700 pt-2",
+
+                            div {
+                                class: "flex items-center gap-2 text-sm",
+                                span {
+                                    class: "text-white flex-1",
+                                    "{category.category.clone().unwrap_or_else(|| \"Other goods\".to_string())}"
+                                }
+                                span {
+                                    class: "text-xs {scarcity_class(category.scarcity)}",
+                                    "{scarcity_label(category.scarcity)}"
+                                }
+                                if let Some(percent) = category.price_percent {
+                                    span { class: "text-gray-400 text-xs", "{percent}%" }
+                                }
+                            }
+                            if !category.reasons.is_empty() {
+                                div {
+                                    class: "text-gray-500 text-xs italic",
+                                    "{category.reasons.join(\"; \")}"
+                                }
+                            }
+                            for item in category.items.iter().cloned() {
+                                ItemPriceRow {
+                                    key: "{item.item_id}",
+                                    item: item,
+                                    currency: data.currency.clone(),
+                                    on_saved: on_changed,
+                                    on_error: on_error,
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        class: "flex flex-col gap-1 border-t border-gray-700 pt-2",
+                        span { class: "text-gray-400 text-xs uppercase", "Market modifiers" }
+                        if data.modifiers.is_empty() {
+                            div { class: "text-gray-500 text-sm", "Prices here are normal" }
+                        }
+                        for modifier in data.modifiers.iter().cloned() {
+                            ModifierRow {
+                                key: "{modifier.id}",
+                                modifier: modifier,
+                                on_deleted: on_changed,
+                                on_error: on_error,
+                            }
+                        }
+                        NewModifierForm {
+                            location_id: data.location_id.clone(),
+                            on_created: on_changed,
+                            on_error: on_error,
+                        }
+                    }
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div { class: "text-red-400 text-xs", "{err}" }
+                }
+            }
+        }
+    }
+}
+
+/// An item with its editable base price and its price here
+#[component]
+fn ItemPriceRow(
+    item: MarketItemData,
+    currency: Option<String>,
+    on_saved: EventHandler<()>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let economy_service = use_economy_service();
+    let mut base_price = use_signal(|| item.base_price.map(|p| p.to_string()).unwrap_or_default());
+    let currency = currency.unwrap_or_default();
+
+    let save = {
+        let item_id = item.item_id.clone();
+        let saved = item.base_price;
+        move |_| {
+            let text = base_price.read().trim().to_string();
+            let price = if text.is_empty() {
+                None
+            } else {
+                match text.parse::<u32>() {
+                    Ok(price) => Some(price),
+                    Err(_) => {
+                        on_error.call(format!("'{}' is not a price", text));
+                        return;
+                    }
+                }
+            };
+            if price == saved {
+                return;
+            }
+            let service = economy_service.clone();
+            let item_id = item_id.clone();
+            spawn_task(async move {
+                match service.set_base_price(&item_id, price).await {
+                    Ok(()) => on_saved.call(()),
+                    Err(e) => on_error.call(format!("Failed to set price: {}", e)),
+                }
+            });
+        }
+    };
+
+    let local = match (item.base_price, item.local_price) {
+        (None, _) => "not for sale".to_string(),
+        (Some(_), None) => "unavailable here".to_string(),
+        (Some(_), Some(price)) => format!("{} {}", price, currency),
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 text-sm pl-2",
+            span { class: "text-gray-300 flex-1 truncate", "{item.name}" }
+            input {
+                r#type: "number",
+                min: "0",
+                value: "{base_price}",
+                placeholder: "—",
+                title: "Base price; leave empty for not for sale",
+                oninput: move |e| base_price.set(e.value()),
+                onchange: save,
+                class: "w-16 p-0.5 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+            }
+            span { class: "text-gray-400 text-xs w-24 text-right", "{local}" }
+        }
+    }
+}
+
+/// What a modifier does, with its delete button
+#[component]
+fn ModifierRow(
+    modifier: MarketModifierData,
+    on_deleted: EventHandler<()>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let economy_service = use_economy_service();
+
+    let delete = {
+        let modifier_id = modifier.id.clone();
+        move |_| {
+            let service = economy_service.clone();
+            let modifier_id = modifier_id.clone();
+            spawn_task(async move {
+                match service.delete_modifier(&modifier_id).await {
+                    Ok(()) => on_deleted.call(()),
+                    Err(e) => on_error.call(format!("Failed to delete modifier: {}", e)),
+                }
+            });
+        }
+    };
+
+    let mut effects = Vec::new();
+    if let Some(scarcity) = modifier.scarcity {
+        effects.push(scarcity_label(scarcity).to_lowercase());
+    }
+    if modifier.price_adjustment != 0 {
+        effects.push(format!("{:+}%", modifier.price_adjustment));
+    }
+    let goods = modifier
+        .category
+        .clone()
+        .unwrap_or_else(|| "All goods".to_string());
+    let scope = if modifier.location_id.is_some() {
+        "here"
+    } else {
+        "everywhere"
+    };
+    let summary = format!("{} {}: {}", goods, scope, effects.join(", "));
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 text-sm",
+            div {
+                class: "flex-1 flex flex-col",
+                span {
+                    class: if modifier.is_active { "text-white" } else { "text-gray-500" },
+                    "{summary}"
+                }
+                span {
+                    class: "text-gray-500 text-xs",
+                    "{modifier.reason}"
+                    if let Some(flag) = modifier.flag.as_ref() {
+                        if modifier.is_active {
+                            " · while {flag} is set"
+                        } else {
+                            " · waiting for {flag}"
+                        }
+                    }
+                }
+            }
+            button {
+                onclick: delete,
+                class: "px-2 py-0.5 bg-transparent border-0 text-red-400 text-xs cursor-pointer",
+                "✕"
+            }
+        }
+    }
+}
+
+/// Scope, goods, effect, flag and reason inputs for a new modifier
+#[component]
+fn NewModifierForm(
+    location_id: String,
+    on_created: EventHandler<()>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let economy_service = use_economy_service();
+    let mut everywhere = use_signal(|| false);
+    let mut category = use_signal(String::new);
+    let mut scarcity = use_signal(String::new);
+    let mut adjustment = use_signal(|| "0".to_string());
+    let mut flag = use_signal(String::new);
+    let mut reason = use_signal(String::new);
+
+    let create = move |_| {
+        let Ok(price_adjustment) = adjustment.read().trim().parse::<i32>() else {
+            on_error.call(format!(
+                "'{}' is not a percentage",
+                adjustment.read().trim()
+            ));
+            return;
+        };
+        let optional = |value: &str| Some(value.trim().to_string()).filter(|v| !v.is_empty());
+        let data = MarketModifierInputData {
+            location_id: if *everywhere.read() {
+                None
+            } else {
+                Some(location_id.clone())
+            },
+            category: optional(&category.read()),
+            scarcity: SCARCITIES
+                .into_iter()
+                .find(|s| scarcity_label(*s) == scarcity.read().as_str()),
+            price_adjustment,
+            flag: optional(&flag.read()),
+            reason: reason.read().trim().to_string(),
+        };
+        let service = economy_service.clone();
+        spawn_task(async move {
+            match service.create_modifier(data).await {
+                Ok(_) => {
+                    category.set(String::new());
+                    reason.set(String::new());
+                    flag.set(String::new());
+                    adjustment.set("0".to_string());
+                    on_created.call(());
+                }
+                Err(e) => on_error.call(format!("Failed to create modifier: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 border-t border-gray-700 pt-2",
+
+            div {
+                class: "flex gap-1",
+                select {
+                    value: if *everywhere.read() { "everywhere" } else { "here" },
+                    onchange: move |e| everywhere.set(e.value() == "everywhere"),
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    option { value: "here", "Here" }
+                    option { value: "everywhere", "Everywhere" }
+                }
+                input {
+                    r#type: "text",
+                    value: "{category}",
+                    placeholder: "Item type (all goods)",
+                    oninput: move |e| category.set(e.value()),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+            }
+            div {
+                class: "flex gap-1",
+                select {
+                    value: "{scarcity}",
+                    onchange: move |e| scarcity.set(e.value()),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    option { value: "", "Scarcity unchanged" }
+                    for level in SCARCITIES {
+                        option {
+                            key: "{scarcity_label(level)}",
+                            value: "{scarcity_label(level)}",
+                            "{scarcity_label(level)}"
+                        }
+                    }
+                }
+                input {
+                    r#type: "number",
+                    min: "-500",
+                    max: "500",
+                    value: "{adjustment}",
+                    title: "Price change in percent",
+                    oninput: move |e| adjustment.set(e.value()),
+                    class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+                span { class: "text-gray-400 text-sm self-center", "%" }
+            }
+            input {
+                r#type: "text",
+                value: "{flag}",
+                placeholder: "Only while this world flag is set (optional)",
+                oninput: move |e| flag.set(e.value()),
+                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+            }
+            input {
+                r#type: "text",
+                value: "{reason}",
+                placeholder: "Why: war in the north",
+                oninput: move |e| reason.set(e.value()),
+                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+            }
+            button {
+                onclick: create,
+                disabled: reason.read().trim().is_empty(),
+                class: "px-2 py-1 bg-blue-500 text-white text-xs rounded cursor-pointer",
+                "Add modifier"
+            }
+        }
+    }
+}
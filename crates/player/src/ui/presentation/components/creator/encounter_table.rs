@@ -0,0 +1,206 @@
+//! Encounter table - What travellers crossing a location might run into
+//!
+//! Fast travel through a location (or anywhere nested inside it without a
+//! table of its own) checks this table every interval of the journey. A hit
+//! picks one entry by weight and stops the journey until the DM decides how
+//! it goes on.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{EncounterEntryData, EncounterTableData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_location_service;
+
+/// A blank entry to fill in
+fn new_entry() -> EncounterEntryData {
+    EncounterEntryData {
+        name: String::new(),
+        description: String::new(),
+        weight: 1,
+    }
+}
+
+/// Interval, chance and weighted entries with Save and Clear buttons
+#[component]
+pub fn EncounterTableEditor(
+    location_id: String,
+    /// The saved table, if the location has one
+    table: Option<EncounterTableData>,
+    /// Called with the table as saved, or `None` once cleared
+    on_saved: EventHandler<Option<EncounterTableData>>,
+) -> Element {
+    let loc_service = use_location_service();
+    let has_table = table.is_some();
+    let mut interval = use_signal(|| {
+        table
+            .as_ref()
+            .map(|t| t.check_interval_minutes)
+            .unwrap_or(240)
+            .to_string()
+    });
+    let mut chance = use_signal(|| {
+        table
+            .as_ref()
+            .map(|t| t.chance_percent)
+            .unwrap_or(20)
+            .to_string()
+    });
+    let mut entries: Signal<Vec<EncounterEntryData>> = use_signal(|| {
+        table
+            .as_ref()
+            .map(|t| t.entries.clone())
+            .unwrap_or_else(|| vec![new_entry()])
+    });
+    let mut is_saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let send = {
+        let location_id = location_id.clone();
+        move |table: Option<EncounterTableData>| {
+            let location_id = location_id.clone();
+            let service = loc_service.clone();
+            spawn_task(async move {
+                is_saving.set(true);
+                match service.set_encounter_table(&location_id, table).await {
+                    Ok(saved) => {
+                        error.set(None);
+                        on_saved.call(saved);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to save encounters: {}", e))),
+                }
+                is_saving.set(false);
+            });
+        }
+    };
+
+    let clear = {
+        let send = send.clone();
+        move |_| send(None)
+    };
+
+    let save = move |_| {
+        let Ok(check_interval_minutes) = interval.read().trim().parse::<u32>() else {
+            error.set(Some(
+                "Check interval must be a whole number of minutes".to_string(),
+            ));
+            return;
+        };
+        let Ok(chance_percent) = chance.read().trim().parse::<u8>() else {
+            error.set(Some(
+                "Chance must be a percentage from 0 to 100".to_string(),
+            ));
+            return;
+        };
+        send(Some(EncounterTableData {
+            check_interval_minutes,
+            chance_percent,
+            entries: entries.read().clone(),
+        }));
+    };
+
+    let current = entries.read().clone();
+
+    rsx! {
+        div {
+            class: "encounter-table-editor flex flex-col gap-2",
+
+            div {
+                class: "flex gap-3 text-gray-300 text-sm",
+                label {
+                    class: "flex items-center gap-2",
+                    "Check every"
+                    input {
+                        r#type: "number",
+                        min: "10",
+                        class: "w-20 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                        value: "{interval}",
+                        oninput: move |e| interval.set(e.value()),
+                    }
+                    "minutes"
+                }
+                label {
+                    class: "flex items-center gap-2",
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        max: "100",
+                        class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                        value: "{chance}",
+                        oninput: move |e| chance.set(e.value()),
+                    }
+                    "% chance"
+                }
+            }
+
+            for (i, entry) in current.into_iter().enumerate() {
+                div {
+                    key: "{i}",
+                    class: "flex items-center gap-2",
+                    input {
+                        r#type: "text",
+                        placeholder: "Encounter",
+                        class: "w-40 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                        value: "{entry.name}",
+                        oninput: move |e| entries.write()[i].name = e.value(),
+                    }
+                    input {
+                        r#type: "text",
+                        placeholder: "What happens",
+                        class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                        value: "{entry.description}",
+                        oninput: move |e| entries.write()[i].description = e.value(),
+                    }
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        title: "Weight",
+                        class: "w-14 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                        value: "{entry.weight}",
+                        oninput: move |e| {
+                            if let Ok(weight) = e.value().parse() {
+                                entries.write()[i].weight = weight;
+                            }
+                        },
+                    }
+                    button {
+                        onclick: move |_| {
+                            entries.write().remove(i);
+                        },
+                        class: "px-2 py-1 bg-transparent text-gray-500 text-xs border-none cursor-pointer",
+                        "×"
+                    }
+                }
+            }
+
+            div {
+                class: "flex justify-between",
+                button {
+                    onclick: move |_| entries.write().push(new_entry()),
+                    class: "px-3 py-1 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                    "+ Add encounter"
+                }
+                div {
+                    class: "flex gap-2",
+                    if has_table {
+                        button {
+                            onclick: clear,
+                            disabled: *is_saving.read(),
+                            class: "px-3 py-1 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer disabled:opacity-50",
+                            "Clear"
+                        }
+                    }
+                    button {
+                        onclick: save,
+                        disabled: *is_saving.read() || entries.read().is_empty(),
+                        class: "px-3 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer disabled:opacity-50",
+                        if *is_saving.read() { "Saving..." } else { "Save encounters" }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{err}" }
+            }
+        }
+    }
+}
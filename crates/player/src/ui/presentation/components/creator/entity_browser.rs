@@ -2,6 +2,7 @@
 
 use dioxus::prelude::*;
 
+use super::tags::TagFilterBar;
 use super::EntityTypeTab;
 use crate::application::services::character_service::CharacterSummary;
 use crate::application::services::location_service::LocationSummary;
@@ -19,8 +20,22 @@ pub fn EntityBrowser(
     locations_loading: Signal<bool>,
     characters_error: Signal<Option<String>>,
     locations_error: Signal<Option<String>>,
+    character_tags: Signal<Vec<String>>,
+    location_tags: Signal<Vec<String>>,
     on_select: EventHandler<String>,
 ) -> Element {
+    // Matches names and aliases, so "grey fox" finds Vexis
+    let mut search = use_signal(String::new);
+    let search_input = rsx! {
+        input {
+            r#type: "text",
+            placeholder: "Search names and aliases...",
+            value: "{search}",
+            oninput: move |e| search.set(e.value()),
+            class: "w-full p-2 mb-2 bg-dark-bg border border-gray-700 rounded text-white box-border",
+        }
+    };
+
     rsx! {
         div {
             class: "entity-browser flex-1 flex flex-col bg-dark-surface rounded-lg overflow-hidden",
@@ -51,14 +66,34 @@ pub fn EntityBrowser(
                 }
             }
 
-            // Search/filter bar
+            // Search/filter bar (tag filters for taggable entity types)
             div {
                 class: "browser-search p-2",
 
-                input {
-                    r#type: "text",
-                    placeholder: "Search...",
-                    class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white box-border",
+                match selected_type {
+                    EntityTypeTab::Characters => rsx! {
+                        {search_input.clone()}
+                        TagFilterBar {
+                            world_id: world_id.clone(),
+                            entity_type: wrldbldr_protocol::EntityType::Character,
+                            active_tags: character_tags,
+                        }
+                    },
+                    EntityTypeTab::Locations => rsx! {
+                        {search_input}
+                        TagFilterBar {
+                            world_id: world_id.clone(),
+                            entity_type: wrldbldr_protocol::EntityType::Location,
+                            active_tags: location_tags,
+                        }
+                    },
+                    EntityTypeTab::Items | EntityTypeTab::Maps => rsx! {
+                        input {
+                            r#type: "text",
+                            placeholder: "Search...",
+                            class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white box-border",
+                        }
+                    },
                 }
             }
 
@@ -70,6 +105,7 @@ pub fn EntityBrowser(
                     EntityTypeTab::Characters => rsx! {
                         CharacterList {
                             characters: characters,
+                            search: search.read().to_lowercase(),
                             selected_id: selected_id.clone(),
                             loading: characters_loading,
                             error: characters_error,
@@ -79,6 +115,7 @@ pub fn EntityBrowser(
                     EntityTypeTab::Locations => rsx! {
                         LocationList {
                             locations: locations,
+                            search: search.read().to_lowercase(),
                             selected_id: selected_id.clone(),
                             loading: locations_loading,
                             error: locations_error,
@@ -149,6 +186,7 @@ fn EntityTypeTabLink(world_id: String, tab: EntityTypeTab, active: bool) -> Elem
 #[component]
 fn CharacterList(
     characters: Signal<Vec<CharacterSummary>>,
+    search: String,
     selected_id: Option<String>,
     loading: Signal<bool>,
     error: Signal<Option<String>>,
@@ -169,7 +207,7 @@ fn CharacterList(
             div {
                 class: "flex flex-col gap-1",
 
-                for character in characters.read().iter() {
+                for character in characters.read().iter().filter(|c| matches_search(&c.name, &c.aliases, &search)) {
                     EntityListItem {
                         id: character.id.clone(),
                         name: character.name.clone(),
@@ -197,6 +235,7 @@ fn CharacterList(
 #[component]
 fn LocationList(
     locations: Signal<Vec<LocationSummary>>,
+    search: String,
     selected_id: Option<String>,
     loading: Signal<bool>,
     error: Signal<Option<String>>,
@@ -217,11 +256,15 @@ fn LocationList(
             div {
                 class: "flex flex-col gap-1",
 
-                for location in locations.read().iter() {
+                for location in locations.read().iter().filter(|l| matches_search(&l.name, &l.aliases, &search)) {
                     EntityListItem {
                         id: location.id.clone(),
                         name: location.name.clone(),
-                        subtitle: location.location_type.clone().unwrap_or_else(|| "Unknown".to_string()),
+                        subtitle: {
+                            let kind = location.location_type.clone().unwrap_or_else(|| "Unknown".to_string());
+                            let path = location.path();
+                            if path.is_empty() { kind } else { format!("{} · in {}", kind, path) }
+                        },
                         selected: selected_id.as_deref() == Some(&location.id),
                         on_click: {
                             let loc_id = location.id.clone();
@@ -241,6 +284,13 @@ fn LocationList(
     }
 }
 
+/// Whether a name or one of its aliases contains `search` (lowercase)
+fn matches_search(name: &str, aliases: &[String], search: &str) -> bool {
+    search.is_empty()
+        || name.to_lowercase().contains(search)
+        || aliases.iter().any(|a| a.to_lowercase().contains(search))
+}
+
 /// Reusable entity list item
 #[component]
 fn EntityListItem(
@@ -0,0 +1,439 @@
+//! Factions - Organizations NPCs belong to
+//!
+//! The DM lists the world's guilds, houses and cults with their goals, picks
+//! their members from the world's NPCs, and nudges each faction's reputation
+//! with the party. Rivalries are set from either side and apply to both.
+//! Staging suggestions and NPC dialogue read all of this.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{FactionData, FactionInputData, FactionMemberInputData};
+use crate::application::services::character_service::CharacterSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_faction_service;
+
+/// The world's factions, with a form to add another
+#[component]
+pub fn FactionPanel(
+    /// NPCs that can join a faction
+    characters: Signal<Vec<CharacterSummary>>,
+) -> Element {
+    let faction_service = use_faction_service();
+    let mut expanded = use_signal(|| false);
+    let mut factions: Signal<Vec<FactionData>> = use_signal(Vec::new);
+    let mut version = use_signal(|| 0u32);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load factions when the panel opens, and again after a rivalry changes
+    // both sides
+    use_effect(move || {
+        let _ = version.read();
+        if !*expanded.read() {
+            return;
+        }
+        let service = faction_service.clone();
+        spawn_task(async move {
+            match service.list_factions().await {
+                Ok(fetched) => {
+                    factions.set(fetched);
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(format!("Failed to load factions: {}", e))),
+            }
+        });
+    });
+
+    let on_changed = move |updated: FactionData| {
+        let mut list = factions.write();
+        match list.iter_mut().find(|f| f.id == updated.id) {
+            Some(existing) => *existing = updated,
+            None => list.push(updated),
+        }
+    };
+    let on_reload = move |_| version += 1;
+    let on_error = move |message: String| error.set(Some(message));
+
+    rsx! {
+        div {
+            class: "faction-panel flex flex-col gap-2 bg-dark-surface rounded-lg p-3",
+
+            button {
+                onclick: move |_| expanded.toggle(),
+                class: "bg-transparent border-0 p-0 text-left text-gray-400 text-sm uppercase cursor-pointer",
+                if *expanded.read() { "▾ Factions" } else { "▸ Factions" }
+            }
+
+            if *expanded.read() {
+                if factions.read().is_empty() {
+                    div { class: "text-gray-500 text-sm", "No factions yet" }
+                }
+                for faction in factions.read().iter().cloned() {
+                    FactionRow {
+                        key: "{faction.id}",
+                        faction: faction,
+                        factions: factions,
+                        characters: characters,
+                        on_changed: on_changed,
+                        on_reload: on_reload,
+                        on_error: on_error,
+                    }
+                }
+                NewFactionForm {
+                    on_created: on_changed,
+                    on_error: on_error,
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div { class: "text-red-400 text-xs", "{err}" }
+                }
+            }
+        }
+    }
+}
+
+/// One faction: reputation controls, and when opened its goals, members and
+/// rivals
+#[component]
+fn FactionRow(
+    faction: FactionData,
+    factions: Signal<Vec<FactionData>>,
+    characters: Signal<Vec<CharacterSummary>>,
+    on_changed: EventHandler<FactionData>,
+    on_reload: EventHandler<()>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let faction_service = use_faction_service();
+    let mut open = use_signal(|| false);
+    let mut new_member = use_signal(String::new);
+    let mut new_role = use_signal(String::new);
+    let mut new_rival = use_signal(String::new);
+
+    let adjust = {
+        let service = faction_service.clone();
+        let faction_id = faction.id.clone();
+        move |delta: i32| {
+            let service = service.clone();
+            let faction_id = faction_id.clone();
+            spawn_task(async move {
+                match service.adjust_reputation(&faction_id, delta).await {
+                    Ok(updated) => on_changed.call(updated),
+                    Err(e) => on_error.call(format!("Failed to change reputation: {}", e)),
+                }
+            });
+        }
+    };
+    let adjust_down = {
+        let adjust = adjust.clone();
+        move |_| adjust(-10)
+    };
+    let adjust_up = move |_| adjust(10);
+
+    // Members are replaced as a whole list
+    let save_members = {
+        let service = faction_service.clone();
+        let faction_id = faction.id.clone();
+        move |members: Vec<FactionMemberInputData>| {
+            let service = service.clone();
+            let faction_id = faction_id.clone();
+            spawn_task(async move {
+                match service.set_members(&faction_id, members).await {
+                    Ok(updated) => on_changed.call(updated),
+                    Err(e) => on_error.call(format!("Failed to update members: {}", e)),
+                }
+            });
+        }
+    };
+    let current_members: Vec<FactionMemberInputData> = faction
+        .members
+        .iter()
+        .map(|m| FactionMemberInputData {
+            character_id: m.character_id.clone(),
+            role: m.role.clone(),
+        })
+        .collect();
+    let add_member = {
+        let save_members = save_members.clone();
+        let members = current_members.clone();
+        move |_| {
+            let character_id = new_member.read().clone();
+            if character_id.is_empty() {
+                return;
+            }
+            let mut members = members.clone();
+            members.push(FactionMemberInputData {
+                character_id,
+                role: new_role.read().trim().to_string(),
+            });
+            save_members(members);
+            new_member.set(String::new());
+            new_role.set(String::new());
+        }
+    };
+
+    let set_rivalry = {
+        let service = faction_service.clone();
+        let faction_id = faction.id.clone();
+        move |rival_id: String, rivals: bool| {
+            let service = service.clone();
+            let faction_id = faction_id.clone();
+            spawn_task(async move {
+                match service.set_rivalry(&faction_id, &rival_id, rivals).await {
+                    Ok(_) => on_reload.call(()),
+                    Err(e) => on_error.call(format!("Failed to update rivalry: {}", e)),
+                }
+            });
+        }
+    };
+    let add_rival = {
+        let set_rivalry = set_rivalry.clone();
+        move |_| {
+            let rival_id = new_rival.read().clone();
+            if !rival_id.is_empty() {
+                set_rivalry(rival_id, true);
+                new_rival.set(String::new());
+            }
+        }
+    };
+
+    let delete = {
+        let faction_id = faction.id.clone();
+        move |_| {
+            let service = faction_service.clone();
+            let faction_id = faction_id.clone();
+            spawn_task(async move {
+                match service.delete_faction(&faction_id).await {
+                    Ok(()) => on_reload.call(()),
+                    Err(e) => on_error.call(format!("Failed to delete faction: {}", e)),
+                }
+            });
+        }
+    };
+
+    let rival_names: Vec<(String, String)> = factions
+        .read()
+        .iter()
+        .filter(|f| faction.rival_ids.contains(&f.id))
+        .map(|f| (f.id.clone(), f.name.clone()))
+        .collect();
+    let rival_choices: Vec<(String, String)> = factions
+        .read()
+        .iter()
+        .filter(|f| f.id != faction.id && !faction.rival_ids.contains(&f.id))
+        .map(|f| (f.id.clone(), f.name.clone()))
+        .collect();
+    let member_choices: Vec<CharacterSummary> = characters
+        .read()
+        .iter()
+        .filter(|c| !faction.members.iter().any(|m| m.character_id == c.id))
+        .cloned()
+        .collect();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 text-sm",
+
+            div {
+                class: "flex items-center gap-2",
+                button {
+                    onclick: move |_| open.toggle(),
+                    class: "bg-transparent border-0 p-0 text-left text-white flex-1 truncate cursor-pointer",
+                    title: "{faction.description}",
+                    "{faction.name}"
+                }
+                span {
+                    class: "text-gray-400 text-xs",
+                    "{faction.standing.display_name()} ({faction.reputation})"
+                }
+                button {
+                    onclick: adjust_down,
+                    title: "Lower reputation by 10",
+                    class: "px-2 py-0.5 bg-gray-700 text-gray-300 text-xs rounded cursor-pointer",
+                    "−"
+                }
+                button {
+                    onclick: adjust_up,
+                    title: "Raise reputation by 10",
+                    class: "px-2 py-0.5 bg-gray-700 text-gray-300 text-xs rounded cursor-pointer",
+                    "+"
+                }
+                button {
+                    onclick: delete,
+                    class: "px-2 py-0.5 bg-transparent border-0 text-red-400 text-xs cursor-pointer",
+                    "✕"
+                }
+            }
+
+            if *open.read() {
+                div {
+                    class: "flex flex-col gap-1 pl-2 border-l border-gray-700",
+
+                    for goal in faction.goals.iter() {
+                        div { class: "text-gray-300 text-xs", "• {goal}" }
+                    }
+
+                    div { class: "text-gray-500 text-xs uppercase mt-1", "Members" }
+                    for member in faction.members.iter().cloned() {
+                        div {
+                            key: "{member.character_id}",
+                            class: "flex items-center gap-2 text-xs",
+                            span { class: "text-white flex-1 truncate", "{member.name}" }
+                            if !member.role.is_empty() {
+                                span { class: "text-gray-400", "{member.role}" }
+                            }
+                            button {
+                                onclick: {
+                                    let save_members = save_members.clone();
+                                    let members = current_members.clone();
+                                    let character_id = member.character_id.clone();
+                                    move |_| {
+                                        let kept = members
+                                            .iter()
+                                            .filter(|m| m.character_id != character_id)
+                                            .cloned()
+                                            .collect();
+                                        save_members(kept);
+                                    }
+                                },
+                                class: "bg-transparent border-0 text-red-400 cursor-pointer",
+                                "✕"
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex gap-1",
+                        select {
+                            value: "{new_member}",
+                            onchange: move |e| new_member.set(e.value()),
+                            class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                            option { value: "", "Add an NPC" }
+                            for character in member_choices.iter() {
+                                option { key: "{character.id}", value: "{character.id}", "{character.name}" }
+                            }
+                        }
+                        input {
+                            r#type: "text",
+                            value: "{new_role}",
+                            placeholder: "Role",
+                            oninput: move |e| new_role.set(e.value()),
+                            class: "w-20 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        }
+                        button {
+                            onclick: add_member,
+                            disabled: new_member.read().is_empty(),
+                            class: "px-2 py-0.5 bg-blue-500 text-white text-xs rounded cursor-pointer",
+                            "Add"
+                        }
+                    }
+
+                    div { class: "text-gray-500 text-xs uppercase mt-1", "Rivals" }
+                    for (rival_id, rival_name) in rival_names.into_iter() {
+                        div {
+                            key: "{rival_id}",
+                            class: "flex items-center gap-2 text-xs",
+                            span { class: "text-white flex-1 truncate", "{rival_name}" }
+                            button {
+                                onclick: {
+                                    let set_rivalry = set_rivalry.clone();
+                                    move |_| set_rivalry(rival_id.clone(), false)
+                                },
+                                class: "bg-transparent border-0 text-red-400 cursor-pointer",
+                                "✕"
+                            }
+                        }
+                    }
+                    if !rival_choices.is_empty() {
+                        div {
+                            class: "flex gap-1",
+                            select {
+                                value: "{new_rival}",
+                                onchange: move |e| new_rival.set(e.value()),
+                                class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                                option { value: "", "Add a rival" }
+                                for (id, name) in rival_choices.iter() {
+                                    option { key: "{id}", value: "{id}", "{name}" }
+                                }
+                            }
+                            button {
+                                onclick: add_rival,
+                                disabled: new_rival.read().is_empty(),
+                                class: "px-2 py-0.5 bg-blue-500 text-white text-xs rounded cursor-pointer",
+                                "Add"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Name, description and goals for a new faction
+#[component]
+fn NewFactionForm(
+    on_created: EventHandler<FactionData>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let faction_service = use_faction_service();
+    let mut name = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut goals = use_signal(String::new);
+
+    let create = move |_| {
+        let data = FactionInputData {
+            name: name.read().trim().to_string(),
+            description: description.read().trim().to_string(),
+            goals: goals
+                .read()
+                .lines()
+                .map(str::trim)
+                .filter(|g| !g.is_empty())
+                .map(String::from)
+                .collect(),
+        };
+        let service = faction_service.clone();
+        spawn_task(async move {
+            match service.create_faction(data).await {
+                Ok(created) => {
+                    name.set(String::new());
+                    description.set(String::new());
+                    goals.set(String::new());
+                    on_created.call(created);
+                }
+                Err(e) => on_error.call(format!("Failed to create faction: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 border-t border-gray-700 pt-2",
+
+            input {
+                r#type: "text",
+                value: "{name}",
+                placeholder: "Thieves' Guild",
+                oninput: move |e| name.set(e.value()),
+                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+            }
+            input {
+                r#type: "text",
+                value: "{description}",
+                placeholder: "Who they are (optional)",
+                oninput: move |e| description.set(e.value()),
+                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+            }
+            textarea {
+                value: "{goals}",
+                placeholder: "Goals, one per line",
+                oninput: move |e| goals.set(e.value()),
+                class: "w-full min-h-12 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm resize-y box-border",
+            }
+            button {
+                onclick: create,
+                disabled: name.read().trim().is_empty(),
+                class: "px-2 py-1 bg-blue-500 text-white text-xs rounded cursor-pointer",
+                "Add faction"
+            }
+        }
+    }
+}
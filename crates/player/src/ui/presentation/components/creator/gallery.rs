@@ -0,0 +1,406 @@
+//! Gallery - Every asset in the world, with filters and bulk actions
+//!
+//! Per-entity galleries show one character's portraits; this panel shows the
+//! whole world's assets so the DM can sift through generation batches, star
+//! the keepers and tag several images at once.
+
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{GalleryAssetData, GalleryFilterData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_gallery_service;
+use wrldbldr_protocol::EntityType;
+
+/// Asset slots the filter offers, as (wire value, label)
+const ASSET_TYPES: &[(&str, &str)] = &[
+    ("portrait", "Portrait"),
+    ("sprite", "Sprite"),
+    ("emotion_sheet", "Emotion sheet"),
+    ("backdrop", "Backdrop"),
+    ("region_backdrop", "Region backdrop"),
+    ("map", "Map"),
+    ("tilesheet", "Tilesheet"),
+    ("item_icon", "Item icon"),
+];
+
+/// The gallery of the world the DM is in: filter bar, selectable
+/// thumbnails and bulk actions
+#[component]
+pub fn GalleryPanel(
+    /// Called with (entity_type, entity_id) to open an asset's owner
+    on_navigate_to_entity: EventHandler<(String, String)>,
+) -> Element {
+    let gallery_service = use_gallery_service();
+    let mut expanded = use_signal(|| false);
+    let mut filter: Signal<GalleryFilterData> = use_signal(GalleryFilterData::default);
+    let mut assets: Signal<Vec<GalleryAssetData>> = use_signal(Vec::new);
+    let mut selected: Signal<HashSet<String>> = use_signal(HashSet::new);
+    // Batches seen while browsing, so the batch filter keeps its options
+    let mut batches: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut tag_input = use_signal(String::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // (Re)load whenever the panel is open and the filter changes
+    {
+        let service = gallery_service.clone();
+        use_effect(move || {
+            if !*expanded.read() {
+                return;
+            }
+            let current = filter.read().clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_gallery(current).await {
+                    Ok(fetched) => {
+                        let mut known = batches.peek().clone();
+                        for batch_id in fetched.iter().filter_map(|a| a.batch_id.clone()) {
+                            if !known.contains(&batch_id) {
+                                known.push(batch_id);
+                            }
+                        }
+                        batches.set(known);
+                        let visible: HashSet<String> =
+                            fetched.iter().map(|a| a.id.clone()).collect();
+                        selected.write().retain(|id| visible.contains(id));
+                        assets.set(fetched);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to load gallery: {}", e))),
+                }
+            });
+        });
+    }
+
+    // Put updated assets back into the list in place
+    let mut merge = move |updated: Vec<GalleryAssetData>| {
+        let mut list = assets.write();
+        for asset in updated {
+            if let Some(existing) = list.iter_mut().find(|a| a.id == asset.id) {
+                *existing = asset;
+            }
+        }
+    };
+
+    let set_favorite = {
+        let service = gallery_service.clone();
+        move |favorite: bool| {
+            let ids: Vec<String> = selected.read().iter().cloned().collect();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.set_favorite(ids, favorite).await {
+                    Ok(updated) => merge(updated),
+                    Err(e) => error.set(Some(format!("Failed to update favorites: {}", e))),
+                }
+            });
+        }
+    };
+
+    let retag = {
+        let service = gallery_service.clone();
+        move |add: bool| {
+            let tag = tag_input.read().trim().to_string();
+            if tag.is_empty() {
+                return;
+            }
+            let ids: Vec<String> = selected.read().iter().cloned().collect();
+            let (add, remove) = if add {
+                (vec![tag], Vec::new())
+            } else {
+                (Vec::new(), vec![tag])
+            };
+            let service = service.clone();
+            spawn_task(async move {
+                match service.tag_assets(ids, add, remove).await {
+                    Ok(updated) => {
+                        tag_input.set(String::new());
+                        merge(updated);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to tag assets: {}", e))),
+                }
+            });
+        }
+    };
+
+    let current_filter = filter.read().clone();
+    let selected_count = selected.read().len();
+
+    rsx! {
+        div {
+            class: "gallery-panel flex flex-col gap-2 bg-dark-surface rounded-lg p-3",
+
+            div {
+                class: "flex justify-between items-center",
+                button {
+                    onclick: move |_| expanded.toggle(),
+                    class: "bg-transparent border-0 p-0 text-gray-400 text-sm uppercase cursor-pointer",
+                    if *expanded.read() { "▾ Gallery" } else { "▸ Gallery" }
+                }
+                if *expanded.read() {
+                    button {
+                        onclick: move |_| filter.set(GalleryFilterData::default()),
+                        class: "bg-transparent border-0 text-gray-400 text-xs cursor-pointer",
+                        "Reset filters"
+                    }
+                }
+            }
+
+            if *expanded.read() {
+                // Filters
+                div {
+                    class: "grid grid-cols-2 gap-1",
+
+                    select {
+                        value: match current_filter.entity_type {
+                            Some(EntityType::Character) => "character",
+                            Some(EntityType::Location) => "location",
+                            Some(EntityType::Item) => "item",
+                            _ => "",
+                        },
+                        onchange: move |e| {
+                            let entity_type = match e.value().as_str() {
+                                "character" => Some(EntityType::Character),
+                                "location" => Some(EntityType::Location),
+                                "item" => Some(EntityType::Item),
+                                _ => None,
+                            };
+                            let mut f = filter.write();
+                            f.entity_type = entity_type;
+                            f.entity_id = None;
+                        },
+                        class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        option { value: "", "All owners" }
+                        option { value: "character", "Characters" }
+                        option { value: "location", "Locations" }
+                        option { value: "item", "Items" }
+                    }
+
+                    select {
+                        value: current_filter.asset_type.clone().unwrap_or_default(),
+                        onchange: move |e| {
+                            filter.write().asset_type = Some(e.value()).filter(|v| !v.is_empty());
+                        },
+                        class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        option { value: "", "All types" }
+                        for (value, label) in ASSET_TYPES.iter() {
+                            option { key: "{value}", value: "{value}", "{label}" }
+                        }
+                    }
+
+                    select {
+                        value: current_filter.batch_id.clone().unwrap_or_default(),
+                        onchange: move |e| {
+                            filter.write().batch_id = Some(e.value()).filter(|v| !v.is_empty());
+                        },
+                        class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        option { value: "", "All batches" }
+                        for batch_id in batches.read().iter() {
+                            option {
+                                key: "{batch_id}",
+                                value: "{batch_id}",
+                                "Batch {batch_id.chars().take(8).collect::<String>()}"
+                            }
+                        }
+                    }
+
+                    input {
+                        r#type: "text",
+                        value: current_filter.tag.clone().unwrap_or_default(),
+                        placeholder: "Tag",
+                        onchange: move |e| {
+                            filter.write().tag = Some(e.value().trim().to_string()).filter(|v| !v.is_empty());
+                        },
+                        class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs box-border",
+                    }
+                }
+
+                div {
+                    class: "flex items-center gap-2 text-xs text-gray-400",
+                    label {
+                        class: "flex items-center gap-1 cursor-pointer",
+                        input {
+                            r#type: "checkbox",
+                            checked: current_filter.favorites_only,
+                            onchange: move |e| filter.write().favorites_only = e.checked(),
+                        }
+                        "Favorites only"
+                    }
+                    if current_filter.entity_id.is_some() {
+                        button {
+                            onclick: move |_| filter.write().entity_id = None,
+                            class: "px-1 bg-gray-700 text-gray-300 border-0 rounded cursor-pointer text-xs",
+                            "One owner ×"
+                        }
+                    }
+                    span { class: "flex-1" }
+                    button {
+                        onclick: move |_| {
+                            let all = assets.read().iter().map(|a| a.id.clone()).collect();
+                            selected.set(all);
+                        },
+                        class: "bg-transparent border-0 text-blue-400 cursor-pointer p-0 text-xs",
+                        "Select all"
+                    }
+                }
+
+                // Bulk actions for the selection
+                if selected_count > 0 {
+                    div {
+                        class: "flex flex-col gap-1 p-2 bg-dark-bg rounded",
+                        div {
+                            class: "flex items-center gap-2 text-xs",
+                            span { class: "text-white flex-1", "{selected_count} selected" }
+                            button {
+                                onclick: {
+                                    let set_favorite = set_favorite.clone();
+                                    move |_| set_favorite(true)
+                                },
+                                class: "bg-transparent border-0 text-yellow-400 cursor-pointer p-0 text-xs",
+                                "★ Favorite"
+                            }
+                            button {
+                                onclick: {
+                                    let set_favorite = set_favorite.clone();
+                                    move |_| set_favorite(false)
+                                },
+                                class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0 text-xs",
+                                "☆ Unfavorite"
+                            }
+                            button {
+                                onclick: move |_| selected.write().clear(),
+                                class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0 text-xs",
+                                "Clear"
+                            }
+                        }
+                        div {
+                            class: "flex gap-1",
+                            input {
+                                r#type: "text",
+                                value: "{tag_input}",
+                                placeholder: "Tag",
+                                oninput: move |e| tag_input.set(e.value()),
+                                class: "flex-1 min-w-0 p-1 bg-dark-surface border border-gray-700 rounded text-white text-xs box-border",
+                            }
+                            button {
+                                onclick: {
+                                    let retag = retag.clone();
+                                    move |_| retag(true)
+                                },
+                                class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer border-0",
+                                "Add"
+                            }
+                            button {
+                                onclick: {
+                                    let retag = retag.clone();
+                                    move |_| retag(false)
+                                },
+                                class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer border-0",
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+
+                if assets.read().is_empty() {
+                    span { class: "text-gray-500 text-xs italic", "No assets match" }
+                }
+
+                div {
+                    class: "grid grid-cols-3 gap-1 max-h-64 overflow-y-auto",
+                    for asset in assets.read().iter().cloned() {
+                        GalleryTile {
+                            key: "{asset.id}",
+                            is_selected: selected.read().contains(&asset.id),
+                            asset: asset.clone(),
+                            on_toggle: move |id: String| {
+                                let mut selection = selected.write();
+                                if !selection.remove(&id) {
+                                    selection.insert(id);
+                                }
+                            },
+                            on_filter_owner: move |(entity_type, entity_id): (EntityType, String)| {
+                                let mut f = filter.write();
+                                f.entity_type = Some(entity_type);
+                                f.entity_id = Some(entity_id);
+                            },
+                            on_open_owner: move |owner| on_navigate_to_entity.call(owner),
+                        }
+                    }
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div { class: "text-red-400 text-xs", "{err}" }
+                }
+            }
+        }
+    }
+}
+
+/// One asset: click to select, with its favorite star, tags and owner links
+#[component]
+fn GalleryTile(
+    asset: GalleryAssetData,
+    is_selected: bool,
+    on_toggle: EventHandler<String>,
+    on_filter_owner: EventHandler<(EntityType, String)>,
+    on_open_owner: EventHandler<(String, String)>,
+) -> Element {
+    let border_class = if is_selected {
+        "border-2 border-blue-500"
+    } else if asset.is_active {
+        "border-2 border-green-500"
+    } else {
+        "border-2 border-transparent"
+    };
+    let title = if asset.tags.is_empty() {
+        asset.file_path.clone()
+    } else {
+        format!("{}\n#{}", asset.file_path, asset.tags.join(" #"))
+    };
+    let toggle_id = asset.id.clone();
+    let owner_type = asset.entity_type;
+    let owner_id = asset.entity_id.clone();
+    let open_id = asset.entity_id.clone();
+
+    rsx! {
+        div {
+            class: format!("relative h-16 bg-gradient-to-br from-gray-700 to-gray-800 {} rounded cursor-pointer overflow-hidden", border_class),
+            title: "{title}",
+            onclick: move |_| on_toggle.call(toggle_id.clone()),
+
+            if asset.is_favorite {
+                div { class: "absolute top-0.5 right-0.5 text-yellow-400 text-xs", "★" }
+            }
+            if !asset.tags.is_empty() {
+                div { class: "absolute top-0.5 left-0.5 px-1 bg-black bg-opacity-70 text-gray-300 text-xs rounded", "#{asset.tags.len()}" }
+            }
+
+            div {
+                class: "absolute bottom-0 left-0 right-0 flex items-center gap-1 px-0.5 bg-black bg-opacity-70 text-xs",
+                span {
+                    class: "flex-1 text-white overflow-hidden text-ellipsis whitespace-nowrap",
+                    "{asset.label.clone().unwrap_or_else(|| asset.asset_type.clone())}"
+                }
+                button {
+                    onclick: move |e| {
+                        e.stop_propagation();
+                        on_filter_owner.call((owner_type, owner_id.clone()));
+                    },
+                    class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0 text-xs",
+                    title: "Show only this owner's assets",
+                    "⌕"
+                }
+                button {
+                    onclick: move |e| {
+                        e.stop_propagation();
+                        on_open_owner.call((owner_type.as_str().to_string(), open_id.clone()));
+                    },
+                    class: "bg-transparent border-0 text-blue-400 cursor-pointer p-0 text-xs",
+                    title: "Open the owner",
+                    "↗"
+                }
+            }
+        }
+    }
+}
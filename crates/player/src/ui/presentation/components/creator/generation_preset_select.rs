@@ -0,0 +1,62 @@
+//! Generation Preset Select - Pick a world art style when generating
+//!
+//! Lists the world's presets for one asset slot. Leaving it on the first
+//! option lets the Engine apply the slot's default preset.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::GenerationPreset;
+
+/// Props for GenerationPresetSelect
+#[derive(Props, Clone, PartialEq)]
+pub struct GenerationPresetSelectProps {
+    /// All of the world's presets; only those for `asset_type` are shown
+    pub presets: Vec<GenerationPreset>,
+    pub asset_type: String,
+    /// Chosen preset name. None = the slot's default.
+    pub selected: Signal<Option<String>>,
+}
+
+/// Dropdown of the world's generation presets for an asset slot
+#[component]
+pub fn GenerationPresetSelect(props: GenerationPresetSelectProps) -> Element {
+    let mut selected = props.selected;
+    let presets: Vec<GenerationPreset> = props
+        .presets
+        .into_iter()
+        .filter(|p| p.applies_to(&props.asset_type))
+        .collect();
+
+    if presets.is_empty() {
+        return rsx! {};
+    }
+
+    let default_label = match presets.iter().find(|p| p.is_default) {
+        Some(preset) => format!("World default ({})", preset.name),
+        None => "None".to_string(),
+    };
+    let value = selected.read().clone().unwrap_or_default();
+
+    rsx! {
+        div { class: "mb-4",
+            label { class: "block text-gray-400 text-sm mb-1", "Style Preset" }
+            select {
+                value: "{value}",
+                onchange: move |e| {
+                    let name = e.value();
+                    selected.set(if name.is_empty() { None } else { Some(name) });
+                },
+                class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white box-border",
+                option { value: "", "{default_label}" }
+                for preset in presets.iter().filter(|p| !p.is_default) {
+                    option {
+                        key: "{preset.name}",
+                        value: "{preset.name}",
+                        title: "{preset.style_prompt}",
+                        "{preset.name}"
+                    }
+                }
+            }
+        }
+    }
+}
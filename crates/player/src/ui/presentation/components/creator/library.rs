@@ -0,0 +1,207 @@
+//! Content library - The DM's cross-world library and "publish" controls
+//!
+//! The library holds snapshots of NPCs, items, challenges and lore that the
+//! DM published from any of their worlds. The panel imports an entry into
+//! the current world as an independent copy; the publish control puts an
+//! existing entity into the library.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::LibraryEntryData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_library_service;
+use wrldbldr_protocol::EntityType;
+
+/// The user's library, with import and delete for each entry
+#[component]
+pub fn LibraryPanel(
+    world_id: String,
+    /// Called after an entry was imported, so entity lists can reload
+    on_created: EventHandler<()>,
+) -> Element {
+    let library_service = use_library_service();
+    let mut entries: Signal<Vec<LibraryEntryData>> = use_signal(Vec::new);
+    let mut reload = use_signal(|| 0u32);
+    let mut message: Signal<Option<String>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load entries on mount and whenever the reload counter changes
+    {
+        let service = library_service.clone();
+        use_effect(move || {
+            let _ = reload.read();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_library().await {
+                    Ok(fetched) => entries.set(fetched),
+                    Err(e) => error.set(Some(format!("Failed to load library: {}", e))),
+                }
+            });
+        });
+    }
+
+    rsx! {
+        div {
+            class: "library-panel flex flex-col gap-2 bg-dark-surface rounded-lg p-3",
+
+            div {
+                class: "flex justify-between items-center",
+                h3 { class: "text-gray-400 text-sm uppercase m-0", "Library" }
+                button {
+                    onclick: move |_| reload += 1,
+                    class: "bg-transparent border-0 text-gray-400 text-xs cursor-pointer",
+                    "Refresh"
+                }
+            }
+
+            if entries.read().is_empty() {
+                span { class: "text-gray-500 text-xs italic", "Nothing published yet" }
+            }
+
+            div {
+                class: "flex flex-col gap-1 max-h-40 overflow-y-auto",
+                for entry in entries.read().iter().cloned() {
+                    LibraryRow {
+                        key: "{entry.id}",
+                        entry: entry.clone(),
+                        world_id: world_id.clone(),
+                        on_imported: move |name: String| {
+                            error.set(None);
+                            message.set(Some(format!("Imported {}", name)));
+                            on_created.call(());
+                        },
+                        on_deleted: move |id: String| {
+                            entries.write().retain(|e| e.id != id);
+                        },
+                        on_error: move |msg| {
+                            message.set(None);
+                            error.set(Some(msg));
+                        },
+                    }
+                }
+            }
+
+            if let Some(msg) = message.read().as_ref() {
+                div { class: "text-green-400 text-xs", "{msg}" }
+            }
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{err}" }
+            }
+        }
+    }
+}
+
+/// One library entry: import into this world, or × to delete
+#[component]
+fn LibraryRow(
+    entry: LibraryEntryData,
+    world_id: String,
+    on_imported: EventHandler<String>,
+    on_deleted: EventHandler<String>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let library_service = use_library_service();
+    let import_id = entry.id.clone();
+    let delete_id = entry.id.clone();
+    let title = entry
+        .description
+        .clone()
+        .unwrap_or_else(|| entry.summary.clone());
+    let import_service = library_service.clone();
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 px-2 py-1 rounded text-sm",
+            span {
+                class: "px-1 bg-gray-700 text-gray-300 text-xs rounded",
+                "{entry.entity_type}"
+            }
+            span {
+                title: "{title}",
+                class: "flex-1 text-white text-sm",
+                "{entry.name}"
+            }
+            button {
+                onclick: move |_| {
+                    let entry_id = import_id.clone();
+                    let world_id = world_id.clone();
+                    let service = import_service.clone();
+                    spawn_task(async move {
+                        match service.import_entry(&entry_id, &world_id).await {
+                            Ok(created) => on_imported.call(created.name),
+                            Err(e) => on_error.call(format!("Failed to import: {}", e)),
+                        }
+                    });
+                },
+                class: "bg-transparent border-0 text-blue-400 cursor-pointer p-0 text-xs",
+                "Import"
+            }
+            button {
+                onclick: move |_| {
+                    let entry_id = delete_id.clone();
+                    let service = library_service.clone();
+                    spawn_task(async move {
+                        match service.delete_entry(&entry_id).await {
+                            Ok(()) => on_deleted.call(entry_id),
+                            Err(e) => on_error.call(format!("Failed to delete entry: {}", e)),
+                        }
+                    });
+                },
+                class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0 text-xs",
+                aria_label: "Delete library entry {entry.name}",
+                "×"
+            }
+        }
+    }
+}
+
+/// "Publish to library" for one existing entity
+#[component]
+pub fn PublishToLibrary(world_id: String, entity_type: EntityType, entity_id: String) -> Element {
+    let library_service = use_library_service();
+    let mut description = use_signal(String::new);
+    let mut status: Signal<Option<String>> = use_signal(|| None);
+
+    let publish = move |_| {
+        let world_id = world_id.clone();
+        let entity_id = entity_id.clone();
+        let service = library_service.clone();
+        let notes = Some(description.read().trim().to_string()).filter(|d| !d.is_empty());
+        spawn_task(async move {
+            match service
+                .publish(&world_id, entity_type, &entity_id, notes)
+                .await
+            {
+                Ok(entry) => {
+                    description.set(String::new());
+                    status.set(Some(format!(
+                        "Published \"{}\" to your library",
+                        entry.name
+                    )));
+                }
+                Err(e) => status.set(Some(format!("Failed to publish: {}", e))),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex gap-2",
+            input {
+                r#type: "text",
+                value: "{description}",
+                placeholder: "Notes (optional)",
+                oninput: move |e| description.set(e.value()),
+                class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+            }
+            button {
+                onclick: publish,
+                class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                "Publish to library"
+            }
+        }
+        if let Some(msg) = status.read().as_ref() {
+            div { class: "text-gray-400 text-xs mt-1", "{msg}" }
+        }
+    }
+}
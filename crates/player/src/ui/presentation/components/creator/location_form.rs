@@ -4,11 +4,21 @@ use dioxus::prelude::*;
 
 use crate::infrastructure::spawn_task;
 use super::asset_gallery::AssetGallery;
+use super::custom_fields::CustomFieldsEditor;
+use super::drafts::DraftPanel;
+use super::encounter_table::EncounterTableEditor;
+use super::location_hierarchy::{scale_label, SubLocationsPanel, TravelTimeEstimate, SCALES};
+use super::mentions::MentionsPanel;
 use super::suggestion_button::{SuggestionButton, SuggestionType};
+use super::tags::TagEditor;
+use super::templates::SaveRegionTemplate;
+use crate::application::dto::{CustomFieldEntryData, DraftFieldData, EncounterTableData};
+use crate::application::services::location_service::breadcrumb_path;
 use crate::application::services::LocationFormData;
 use crate::application::services::SuggestionContext;
 use crate::presentation::components::common::FormField;
 use crate::presentation::services::use_location_service;
+use wrldbldr_protocol::types::{LocationBreadcrumbData, LocationScaleData};
 
 /// Location types
 const LOCATION_TYPES: &[&str] = &[
@@ -42,10 +52,18 @@ pub fn LocationForm(
     let mut description = use_signal(String::new);
     let mut location_type = use_signal(|| "Interior".to_string());
     let mut atmosphere = use_signal(String::new);
+    // Whether description/atmosphere are untouched suggestions (for draft revisions)
+    let mut description_generated = use_signal(|| false);
+    let mut atmosphere_generated = use_signal(|| false);
     let mut notable_features = use_signal(String::new);
     let mut hidden_secrets = use_signal(String::new);
     let mut parent_location_id: Signal<Option<String>> = use_signal(|| None);
     let mut parent_locations: Signal<Vec<LocationFormData>> = use_signal(Vec::new);
+    let mut scale: Signal<Option<LocationScaleData>> = use_signal(|| None);
+    let mut crossing_minutes: Signal<Option<u32>> = use_signal(|| None);
+    let mut breadcrumb: Signal<Vec<LocationBreadcrumbData>> = use_signal(Vec::new);
+    let mut custom_fields: Signal<Vec<CustomFieldEntryData>> = use_signal(Vec::new);
+    let mut encounter_table: Signal<Option<EncounterTableData>> = use_signal(|| None);
     let mut is_loading = use_signal(|| !is_new);
     let mut is_saving = use_signal(|| false);
     let mut success_message: Signal<Option<String>> = use_signal(|| None);
@@ -76,10 +94,16 @@ pub fn LocationForm(
                             atmosphere: None,
                             notable_features: None,
                             hidden_secrets: None,
-                            parent_location_id: None,
+                            parent_location_id: summary.parent_location_id.clone(),
+                            scale: summary.scale,
+                            crossing_minutes: None,
+                            breadcrumb: summary.breadcrumb.clone(),
                             backdrop_asset: None,
+                            map_asset: None,
                             backdrop_regions: Vec::new(),
                             presence_cache_ttl_hours: None,
+                            custom_fields: Vec::new(),
+                            encounter_table: None,
                         })
                         .collect();
                     parent_locations.set(parent_data);
@@ -100,6 +124,11 @@ pub fn LocationForm(
                             notable_features.set(loc_data.notable_features.unwrap_or_default());
                             hidden_secrets.set(loc_data.hidden_secrets.unwrap_or_default());
                             parent_location_id.set(loc_data.parent_location_id);
+                            scale.set(loc_data.scale);
+                            crossing_minutes.set(loc_data.crossing_minutes);
+                            breadcrumb.set(loc_data.breadcrumb);
+                            custom_fields.set(loc_data.custom_fields);
+                            encounter_table.set(loc_data.encounter_table);
                             is_loading.set(false);
                         }
                         Err(e) => {
@@ -122,9 +151,17 @@ pub fn LocationForm(
             div {
                 class: "form-header flex justify-between items-center p-4 border-b border-gray-700",
 
-                h2 {
-                    class: "text-white m-0 text-xl",
-                    if is_new { "New Location" } else { "Edit Location" }
+                div {
+                    h2 {
+                        class: "text-white m-0 text-xl",
+                        if is_new { "New Location" } else { "Edit Location" }
+                    }
+                    if !breadcrumb.read().is_empty() {
+                        div {
+                            class: "text-gray-500 text-xs mt-1",
+                            "{breadcrumb_path(&breadcrumb.read())}"
+                        }
+                    }
                 }
 
                 button {
@@ -210,7 +247,10 @@ pub fn LocationForm(
                         div { class: "flex flex-col gap-2",
                             textarea {
                                 value: "{description}",
-                                oninput: move |e| description.set(e.value()),
+                                oninput: move |e| {
+                                    description.set(e.value());
+                                    description_generated.set(false);
+                                },
                                 placeholder: "What does this place look like? What stands out?",
                                 class: "w-full min-h-[80px] p-2 bg-dark-bg border border-gray-700 rounded text-white resize-y box-border",
                             }
@@ -223,7 +263,10 @@ pub fn LocationForm(
                                         entity_type: Some(location_type.read().clone()),
                                         ..Default::default()
                                     },
-                                    on_select: move |value| description.set(value),
+                                    on_select: move |value| {
+                                        description.set(value);
+                                        description_generated.set(true);
+                                    },
                                 }
                             }
                         }
@@ -239,7 +282,10 @@ pub fn LocationForm(
                             input {
                                 r#type: "text",
                                 value: "{atmosphere}",
-                                oninput: move |e| atmosphere.set(e.value()),
+                                oninput: move |e| {
+                                    atmosphere.set(e.value());
+                                    atmosphere_generated.set(false);
+                                },
                                 placeholder: "The mood and feeling of this place...",
                                 class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded text-white",
                             }
@@ -252,7 +298,10 @@ pub fn LocationForm(
                                     additional_context: if description.read().is_empty() { None } else { Some(description.read().clone()) },
                                     ..Default::default()
                                 },
-                                on_select: move |value| atmosphere.set(value),
+                                on_select: move |value| {
+                                    atmosphere.set(value);
+                                    atmosphere_generated.set(true);
+                                },
                             }
                         }
                     }
@@ -335,7 +384,14 @@ pub fn LocationForm(
                                     if parent.id.as_ref() != Some(&location_id) {
                                         option {
                                             value: "{parent.id.as_ref().unwrap_or(&String::new())}",
-                                            "{parent.name}"
+                                            {
+                                                let path = breadcrumb_path(&parent.breadcrumb);
+                                                if path.is_empty() {
+                                                    parent.name.clone()
+                                                } else {
+                                                    format!("{} › {}", path, parent.name)
+                                                }
+                                            }
                                         }
                                     }
                                 }
@@ -343,6 +399,152 @@ pub fn LocationForm(
                         }
                     }
 
+                    // Scale and how long it takes to get around inside
+                    FormField {
+                        label: "Scale",
+                        required: false,
+                        children: rsx! {
+                            div { class: "flex gap-2",
+                                select {
+                                    value: (*scale.read()).map(scale_label).unwrap_or(""),
+                                    onchange: move |e| {
+                                        let val = e.value();
+                                        scale.set(SCALES.iter().find(|(_, label)| *label == val).map(|(s, _)| *s));
+                                    },
+                                    class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded text-white",
+
+                                    option { value: "", "Unspecified" }
+                                    for (_, label) in SCALES {
+                                        option { key: "{label}", value: "{label}", "{label}" }
+                                    }
+                                }
+                                input {
+                                    r#type: "number",
+                                    min: "0",
+                                    value: (*crossing_minutes.read()).map(|m| m.to_string()).unwrap_or_default(),
+                                    oninput: move |e| crossing_minutes.set(e.value().parse().ok()),
+                                    placeholder: "Minutes across",
+                                    title: "Game minutes between two places inside this location",
+                                    class: "w-36 p-2 bg-dark-bg border border-gray-700 rounded text-white",
+                                }
+                            }
+                        }
+                    }
+
+                    // What this location contains and how far away other places are
+                    if !is_new {
+                        div {
+                            class: "hierarchy-section mt-6 border-t border-gray-700 pt-4 flex flex-col gap-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase m-0", "Contains" }
+
+                            SubLocationsPanel { location_id: location_id.clone() }
+                            TravelTimeEstimate {
+                                location_id: location_id.clone(),
+                                locations: locations_signal.read().clone(),
+                            }
+                        }
+                    }
+
+                    // What travellers crossing this location might run into
+                    if !is_new && !*is_loading.read() {
+                        div {
+                            class: "encounters-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Encounters" }
+
+                            EncounterTableEditor {
+                                location_id: location_id.clone(),
+                                table: encounter_table.read().clone(),
+                                on_saved: move |table| encounter_table.set(table),
+                            }
+                        }
+                    }
+
+                    // Tags section (only for existing locations)
+                    if !is_new {
+                        div {
+                            class: "tags-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Tags" }
+
+                            TagEditor {
+                                world_id: world_id.clone(),
+                                entity_type: wrldbldr_protocol::EntityType::Location,
+                                entity_id: location_id.clone(),
+                            }
+                        }
+                    }
+
+                    // Where this location is referenced (only for existing locations)
+                    if !is_new {
+                        div {
+                            class: "mentions-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Referenced In" }
+
+                            MentionsPanel {
+                                entity_type: wrldbldr_protocol::EntityType::Location,
+                                entity_id: location_id.clone(),
+                            }
+                        }
+                    }
+
+                    // Revision drafts for generated text (only for existing locations)
+                    if !is_new {
+                        div {
+                            class: "drafts-section mt-6 border-t border-gray-700 pt-4 flex flex-col gap-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase m-0", "Drafts" }
+
+                            DraftPanel {
+                                world_id: world_id.clone(),
+                                entity_type: wrldbldr_protocol::EntityType::Location,
+                                entity_id: location_id.clone(),
+                                field: DraftFieldData::Description,
+                                label: "Description",
+                                text: description,
+                                generated: description_generated,
+                            }
+                            DraftPanel {
+                                world_id: world_id.clone(),
+                                entity_type: wrldbldr_protocol::EntityType::Location,
+                                entity_id: location_id.clone(),
+                                field: DraftFieldData::Atmosphere,
+                                label: "Atmosphere",
+                                text: atmosphere,
+                                generated: atmosphere_generated,
+                            }
+                        }
+                    }
+
+                    // Save a region as a template (only for existing locations)
+                    if !is_new {
+                        div {
+                            class: "template-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Region Templates" }
+
+                            SaveRegionTemplate { location_id: location_id.clone() }
+                        }
+                    }
+
+                    // Custom fields section (only for existing locations with fields defined)
+                    if !is_new && !custom_fields.read().is_empty() {
+                        div {
+                            class: "custom-fields-section mt-6 border-t border-gray-700 pt-4",
+
+                            h3 { class: "text-gray-400 text-sm uppercase mb-3", "Custom Fields" }
+
+                            CustomFieldsEditor {
+                                world_id: world_id.clone(),
+                                entity_type: wrldbldr_protocol::EntityType::Location,
+                                entity_id: location_id.clone(),
+                                entries: custom_fields,
+                            }
+                        }
+                    }
+
                     // Asset Gallery section
                     div {
                         class: "assets-section mt-4",
@@ -362,6 +564,44 @@ pub fn LocationForm(
             div {
                 class: "form-footer flex justify-end gap-2 p-4 border-t border-gray-700",
 
+                // Duplicate (only for existing locations); copies the regions too
+                if !is_new {
+                    button {
+                        class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer mr-auto",
+                        disabled: *is_saving.read(),
+                        onclick: {
+                            let svc = loc_service.clone();
+                            let loc_id = location_id.clone();
+                            move |_| {
+                                let svc = svc.clone();
+                                let loc_id = loc_id.clone();
+                                error_message.set(None);
+                                success_message.set(None);
+                                spawn_task(async move {
+                                    match svc.duplicate_location(&loc_id).await {
+                                        Ok(copy) => {
+                                            success_message.set(Some(format!("Created \"{}\"", copy.name)));
+                                            locations_signal.write().push(
+                                                crate::application::services::location_service::LocationSummary {
+                                                    id: copy.id.unwrap_or_default(),
+                                                    name: copy.name,
+                                                    location_type: copy.location_type,
+                                                    parent_location_id: copy.parent_location_id,
+                                                    scale: copy.scale,
+                                                    breadcrumb: copy.breadcrumb,
+                                                    aliases: vec![],
+                                                },
+                                            );
+                                        }
+                                        Err(e) => error_message.set(Some(format!("Duplicate failed: {}", e))),
+                                    }
+                                });
+                            }
+                        },
+                        "Duplicate"
+                    }
+                }
+
                 button {
                     onclick: move |_| on_close.call(()),
                     class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer",
@@ -418,9 +658,15 @@ pub fn LocationForm(
                                             if hs.is_empty() { None } else { Some(hs) }
                                         },
                                         parent_location_id: parent_location_id.read().clone(),
+                                        scale: *scale.read(),
+                                        crossing_minutes: *crossing_minutes.read(),
+                                        breadcrumb: Vec::new(),
                                         backdrop_asset: None,
+                                        map_asset: None,
                                         backdrop_regions: Vec::new(),
                                         presence_cache_ttl_hours: None, // TTL is set per-staging, not per-location
+                                        custom_fields: Vec::new(),
+                                        encounter_table: None,
                                     };
 
                                     match if is_new {
@@ -436,6 +682,10 @@ pub fn LocationForm(
                                                     id: saved_location.id.clone().unwrap_or_default(),
                                                     name: saved_location.name.clone(),
                                                     location_type: saved_location.location_type.clone(),
+                                                    parent_location_id: saved_location.parent_location_id.clone(),
+                                                    scale: saved_location.scale,
+                                                    breadcrumb: Vec::new(),
+                                                    aliases: vec![],
                                                 };
                                                 locations_signal.write().push(summary);
                                             } else {
@@ -445,6 +695,8 @@ pub fn LocationForm(
                                                     if let Some(existing) = locs.iter_mut().find(|l| l.id == *id) {
                                                         existing.name = saved_location.name.clone();
                                                         existing.location_type = saved_location.location_type.clone();
+                                                        existing.parent_location_id = saved_location.parent_location_id.clone();
+                                                        existing.scale = saved_location.scale;
                                                     }
                                                 }
                                             }
@@ -0,0 +1,160 @@
+//! Location Hierarchy - What a location contains and how far away things are
+//!
+//! Lists every location nested inside this one (with region counts rolled
+//! up) and estimates the game time it takes to travel to another location
+//! through the map hierarchy.
+
+use dioxus::prelude::*;
+
+use crate::application::services::location_service::LocationSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_location_service;
+use wrldbldr_protocol::types::{LocationScaleData, LocationTravelData, SubLocationData};
+
+/// Scales a location can be given, outermost first
+pub const SCALES: &[(LocationScaleData, &str)] = &[
+    (LocationScaleData::Continent, "Continent"),
+    (LocationScaleData::Kingdom, "Kingdom"),
+    (LocationScaleData::City, "City"),
+    (LocationScaleData::District, "District"),
+    (LocationScaleData::Building, "Building"),
+    (LocationScaleData::Room, "Room"),
+];
+
+/// Display label for a scale
+pub fn scale_label(scale: LocationScaleData) -> &'static str {
+    SCALES
+        .iter()
+        .find(|(s, _)| *s == scale)
+        .map(|(_, label)| *label)
+        .unwrap_or("")
+}
+
+/// "2d 3h", "45m" - game minutes in the largest units that fit
+pub fn format_minutes(minutes: u32) -> String {
+    let days = minutes / (24 * 60);
+    let hours = minutes % (24 * 60) / 60;
+    let mins = minutes % 60;
+    match (days, hours, mins) {
+        (0, 0, m) => format!("{}m", m),
+        (0, h, 0) => format!("{}h", h),
+        (0, h, m) => format!("{}h {}m", h, m),
+        (d, 0, _) => format!("{}d", d),
+        (d, h, _) => format!("{}d {}h", d, h),
+    }
+}
+
+/// Nested locations, indented by depth
+#[component]
+pub fn SubLocationsPanel(location_id: String) -> Element {
+    let loc_service = use_location_service();
+    let mut children: Signal<Vec<SubLocationData>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    use_effect(move || {
+        let location_id = location_id.clone();
+        let service = loc_service.clone();
+        spawn_task(async move {
+            match service.list_sub_locations(&location_id, true).await {
+                Ok(found) => children.set(found),
+                Err(e) => error.set(Some(format!("Failed to load sub-locations: {}", e))),
+            }
+        });
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{err}" }
+            }
+
+            if children.read().is_empty() {
+                p { class: "text-gray-500 text-sm m-0", "Nothing is nested inside this location." }
+            }
+
+            for child in children.read().iter() {
+                div {
+                    key: "{child.id}",
+                    class: "flex justify-between text-sm text-gray-300",
+                    style: "padding-left: {child.depth.saturating_sub(1) * 16}px",
+                    span {
+                        "{child.name}"
+                        if let Some(scale) = child.scale {
+                            span { class: "text-gray-500 text-xs ml-2", "{scale_label(scale)}" }
+                        }
+                    }
+                    span {
+                        class: "text-gray-500 text-xs",
+                        "{child.region_count} regions"
+                        if child.descendant_count > 0 {
+                            " · {child.descendant_count} inside"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Travel time from this location to another one in the world
+#[component]
+pub fn TravelTimeEstimate(location_id: String, locations: Vec<LocationSummary>) -> Element {
+    let loc_service = use_location_service();
+    let mut destination = use_signal(String::new);
+    let mut travel: Signal<Option<LocationTravelData>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let mut estimate = {
+        let location_id = location_id.clone();
+        move |to: String| {
+            destination.set(to.clone());
+            travel.set(None);
+            error.set(None);
+            if to.is_empty() {
+                return;
+            }
+            let from = location_id.clone();
+            let service = loc_service.clone();
+            spawn_task(async move {
+                match service.get_travel_time(&from, &to).await {
+                    Ok(data) => travel.set(Some(data)),
+                    Err(e) => error.set(Some(format!("Failed to estimate travel: {}", e))),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            label { class: "text-gray-400 text-sm", "Travel time to" }
+            select {
+                value: "{destination}",
+                onchange: move |e| estimate(e.value()),
+                class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white",
+
+                option { value: "", "Select a destination..." }
+                for loc in locations.iter().filter(|l| l.id != location_id) {
+                    option { key: "{loc.id}", value: "{loc.id}", "{loc.name}" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{err}" }
+            }
+
+            if let Some(data) = travel.read().as_ref() {
+                p {
+                    class: "text-sm text-gray-300 m-0",
+                    match (data.minutes, data.via_location_name.as_ref()) {
+                        (Some(m), Some(via)) => format!("About {} across {}", format_minutes(m), via),
+                        (Some(m), None) => format!("About {}", format_minutes(m)),
+                        (None, _) => "These locations don't share a parent, so there's no estimate".to_string(),
+                    }
+                }
+            }
+        }
+    }
+}
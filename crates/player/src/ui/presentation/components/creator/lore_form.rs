@@ -3,9 +3,13 @@
 //! US-LORE-001: DM can create lore entries with multiple chunks.
 //! US-LORE-002: DM can link lore to characters, locations, regions, or items.
 //! US-LORE-003: DM can mark lore as common knowledge.
+//! Names the lore mentions can be promoted to NPC drafts.
 
 use dioxus::prelude::*;
 
+use super::library::PublishToLibrary;
+use super::npc_drafts::LoreMentions;
+
 /// Categories for lore
 const LORE_CATEGORIES: &[(&str, &str)] = &[
     ("historical", "Historical"),
@@ -296,6 +300,39 @@ pub fn LoreForm(props: LoreFormProps) -> Element {
                             }
                         }
                     }
+
+                    // Publish to the cross-world library (only for saved entries)
+                    if !is_new {
+                        div {
+                            class: "form-group",
+                            label {
+                                class: "block text-sm font-medium text-gray-400 mb-1",
+                                "Library"
+                            }
+                            PublishToLibrary {
+                                world_id: props.world_id.clone(),
+                                entity_type: wrldbldr_protocol::EntityType::Lore,
+                                entity_id: props.lore_id.clone(),
+                            }
+                        }
+
+                        // Names the lore mentions that could become NPCs
+                        div {
+                            class: "form-group",
+                            label {
+                                class: "block text-sm font-medium text-gray-400 mb-1",
+                                "Mentioned names"
+                            }
+                            LoreMentions {
+                                world_id: props.world_id.clone(),
+                                lore_id: props.lore_id.clone(),
+                                text: std::iter::once(summary.read().clone())
+                                    .chain(chunks.read().iter().map(|c| c.content.clone()))
+                                    .collect::<Vec<_>>()
+                                    .join("\n\n"),
+                            }
+                        }
+                    }
                 }
             }
 
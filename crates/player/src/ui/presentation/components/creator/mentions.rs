@@ -0,0 +1,147 @@
+//! Mentions - Where an entity is referenced, and its aliases
+//!
+//! Descriptions, lore and dialogue that name an entity, by its name or one
+//! of its aliases ("the Widow"), are listed here. Saving aliases scans the
+//! world's text again; "Rescan" does the same on demand.
+
+use dioxus::prelude::*;
+use wrldbldr_domain::MAX_ALIASES_PER_ENTITY;
+
+use crate::application::dto::EntityMentionData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_mention_service;
+use wrldbldr_protocol::EntityType;
+
+/// Split comma-separated input into trimmed, non-empty aliases
+fn parse_aliases(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// "Referenced in" list and alias editor for one entity
+#[component]
+pub fn MentionsPanel(entity_type: EntityType, entity_id: String) -> Element {
+    let mention_service = use_mention_service();
+    let mut aliases_input = use_signal(String::new);
+    let mut mentions: Signal<Vec<EntityMentionData>> = use_signal(Vec::new);
+    let mut busy = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    // Bumped after aliases are saved or the world is rescanned
+    let mut reload = use_signal(|| 0u32);
+
+    {
+        let entity_id = entity_id.clone();
+        let service = mention_service.clone();
+        use_effect(move || {
+            let _ = reload.read();
+            let entity_id = entity_id.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.mentions_of(entity_type, &entity_id).await {
+                    Ok(found) => mentions.set(found),
+                    Err(e) => error.set(Some(format!("Failed to load mentions: {}", e))),
+                }
+                if let Ok(aliases) = service.aliases_of(entity_type, &entity_id).await {
+                    aliases_input.set(aliases.join(", "));
+                }
+            });
+        });
+    }
+
+    let save_aliases = {
+        let entity_id = entity_id.clone();
+        let service = mention_service.clone();
+        move |_| {
+            let entity_id = entity_id.clone();
+            let service = service.clone();
+            let aliases = parse_aliases(&aliases_input.read());
+            busy.set(true);
+            spawn_task(async move {
+                match service.set_aliases(entity_type, &entity_id, aliases).await {
+                    Ok(saved) => {
+                        error.set(None);
+                        aliases_input.set(saved.join(", "));
+                        reload += 1;
+                    }
+                    Err(e) => error.set(Some(format!("Failed to save aliases: {}", e))),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let rescan = move |_| {
+        let service = mention_service.clone();
+        busy.set(true);
+        spawn_task(async move {
+            match service.reindex().await {
+                Ok(_) => {
+                    error.set(None);
+                    reload += 1;
+                }
+                Err(e) => error.set(Some(format!("Failed to rescan: {}", e))),
+            }
+            busy.set(false);
+        });
+    };
+
+    let found = mentions.read().clone();
+
+    rsx! {
+        div {
+            class: "mentions-panel flex flex-col gap-2",
+
+            label { class: "text-gray-400 text-xs", "Aliases (comma-separated, up to {MAX_ALIASES_PER_ENTITY})" }
+            div {
+                class: "flex gap-2",
+                input {
+                    r#type: "text",
+                    value: "{aliases_input}",
+                    placeholder: "the Widow, Old Mira",
+                    oninput: move |e| aliases_input.set(e.value()),
+                    class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+                button {
+                    onclick: save_aliases,
+                    disabled: *busy.read(),
+                    class: "px-3 py-1 bg-blue-500 text-white border-0 rounded cursor-pointer text-sm",
+                    "Save"
+                }
+                button {
+                    onclick: rescan,
+                    disabled: *busy.read(),
+                    title: "Scan all of the world's text for mentions again",
+                    class: "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm",
+                    "Rescan"
+                }
+            }
+
+            if found.is_empty() {
+                div { class: "text-gray-500 text-sm", "Not referenced anywhere yet" }
+            }
+            for mention in found {
+                div {
+                    key: "{mention.source_type}-{mention.source_id}",
+                    class: "p-2 bg-dark-bg rounded",
+                    div {
+                        class: "flex items-center gap-2",
+                        span { class: "text-white text-sm", "{mention.source_name}" }
+                        span { class: "text-gray-500 text-xs uppercase", "{mention.source_type}" }
+                        if mention.count > 1 {
+                            span { class: "text-gray-500 text-xs", "×{mention.count}" }
+                        }
+                    }
+                    div { class: "text-gray-400 text-xs italic mt-1", "{mention.excerpt}" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{err}" }
+            }
+        }
+    }
+}
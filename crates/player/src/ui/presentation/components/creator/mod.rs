@@ -4,17 +4,35 @@
 //! entity creation, editing, asset generation, and LLM suggestions.
 
 pub mod asset_gallery;
+pub mod asset_upload;
 pub mod character_form;
+pub mod chronology;
 pub mod comfyui_banner;
+pub mod crowds;
+pub mod custom_fields;
+pub mod dialogue_trees;
+pub mod drafts;
+pub mod encounter_table;
+pub mod economy;
 pub mod entity_browser;
 pub mod expression_config_editor;
 pub mod expression_sheet_modal;
+pub mod factions;
+pub mod gallery;
+pub mod generation_preset_select;
 pub mod generation_queue;
+pub mod library;
 pub mod location_form;
+pub mod location_hierarchy;
 pub mod lore_form;
+pub mod mentions;
 pub mod motivations_tab;
+pub mod name_generators;
+pub mod npc_drafts;
 pub mod sheet_field_input;
 pub mod suggestion_button;
+pub mod tags;
+pub mod templates;
 
 use crate::infrastructure::spawn_task;
 use crate::presentation::services::use_generation_service;
@@ -62,17 +80,27 @@ pub fn CreatorMode(props: CreatorModeProps) -> Element {
     let mut characters_error: Signal<Option<String>> = use_signal(|| None);
     let mut locations_error: Signal<Option<String>> = use_signal(|| None);
 
+    // Tag filters for the browser lists (empty = no filter)
+    let character_tags: Signal<Vec<String>> = use_signal(Vec::new);
+    let location_tags: Signal<Vec<String>> = use_signal(Vec::new);
+
+    // Bumped when a template creates entities, so both lists reload
+    let mut entities_version = use_signal(|| 0u32);
+
     // Initial data fetching on mount
     let character_service = crate::presentation::services::use_character_service();
     let location_service = crate::presentation::services::use_location_service();
     let world_id_for_fetch = props.world_id.clone();
 
-    // Fetch characters on mount
+    // Fetch characters on mount, and again whenever the tag filter changes
     use_effect(move || {
         let world_id = world_id_for_fetch.clone();
         let svc = character_service.clone();
+        let tags = character_tags.read().clone();
+        let _ = entities_version.read();
+        characters_loading.set(true);
         spawn_task(async move {
-            match svc.list_characters(&world_id).await {
+            match svc.list_tagged_characters(&world_id, &tags).await {
                 Ok(fetched) => {
                     characters.set(fetched);
                     characters_loading.set(false);
@@ -85,13 +113,16 @@ pub fn CreatorMode(props: CreatorModeProps) -> Element {
         });
     });
 
-    // Fetch locations on mount
+    // Fetch locations on mount, and again whenever the tag filter changes
     let world_id_for_locations = props.world_id.clone();
     use_effect(move || {
         let world_id = world_id_for_locations.clone();
         let svc = location_service.clone();
+        let tags = location_tags.read().clone();
+        let _ = entities_version.read();
+        locations_loading.set(true);
         spawn_task(async move {
-            match svc.list_locations(&world_id).await {
+            match svc.list_tagged_locations(&world_id, &tags).await {
                 Ok(fetched) => {
                     locations.set(fetched);
                     locations_loading.set(false);
@@ -165,9 +196,66 @@ pub fn CreatorMode(props: CreatorModeProps) -> Element {
                     locations_loading: locations_loading,
                     characters_error: characters_error,
                     locations_error: locations_error,
+                    character_tags: character_tags,
+                    location_tags: location_tags,
                     on_select: move |id| selected_entity_id.set(Some(id)),
                 }
 
+                // Templates: stamp out NPCs and regions
+                templates::TemplatePanel {
+                    world_id: props.world_id.clone(),
+                    locations: locations,
+                    on_created: move |_| entities_version += 1,
+                }
+
+                // Library: content carried over from the DM's other worlds
+                library::LibraryPanel {
+                    world_id: props.world_id.clone(),
+                    on_created: move |_| entities_version += 1,
+                }
+
+                // Gallery: every asset in the world, with bulk favorite and tag
+                gallery::GalleryPanel {
+                    on_navigate_to_entity: move |(_entity_type, entity_id): (String, String)| {
+                        selected_entity_id.set(Some(entity_id));
+                    },
+                }
+
+                // Chronology: birthdates, ages and lore years on the game calendar
+                chronology::ChronologyPanel {}
+
+                // Crowds: groups of unnamed NPCs in a region
+                crowds::CrowdPanel {
+                    locations: locations,
+                }
+
+                // Factions: members, goals and standing with the party
+                factions::FactionPanel {
+                    characters: characters,
+                }
+
+                // Dialogue trees: scripted beats with branching choices
+                dialogue_trees::DialogueTreePanel {
+                    world_id: props.world_id.clone(),
+                    characters: characters,
+                }
+
+                // Name generators: how each culture names its people
+                name_generators::NameGeneratorPanel {
+                    world_id: props.world_id.clone(),
+                    locations: locations,
+                }
+
+                // Economy: scarcity and local prices by location
+                economy::EconomyPanel {
+                    locations: locations,
+                }
+
+                // NPC drafts: crowd members and mentioned names awaiting approval
+                npc_drafts::NpcDraftPanel {
+                    on_created: move |_| entities_version += 1,
+                }
+
                 // Generation queue panel - navigation handled via entity selection
                 generation_queue::GenerationQueuePanel {
                     on_navigate_to_entity: {
@@ -0,0 +1,538 @@
+//! Name generators - How each culture in the world names its people
+//!
+//! A generator is either lists of given and family names, or a small grammar:
+//! patterns such as `{start}{end} of {clan}` over parts with a few entries
+//! each. Assigning it to regions or factions makes crowd members promoted
+//! there, and names suggested for NPCs from there, come from it; one
+//! assigned nowhere is the world default.
+
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{NameGeneratorData, NameGeneratorInputData, NameStyleData};
+use crate::application::services::location_service::LocationSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_location_service, use_name_generator_service};
+
+/// The world's name generators, with a form to add or edit one
+#[component]
+pub fn NameGeneratorPanel(
+    world_id: String,
+    /// Locations whose regions generators can be assigned to
+    locations: Signal<Vec<LocationSummary>>,
+) -> Element {
+    let name_generator_service = use_name_generator_service();
+    let location_service = use_location_service();
+    let mut expanded = use_signal(|| false);
+    let mut generators: Signal<Vec<NameGeneratorData>> = use_signal(Vec::new);
+    // (region id, "Location › Region") for every region in the world
+    let mut regions: Signal<Vec<(String, String)>> = use_signal(Vec::new);
+    let mut editing: Signal<Option<NameGeneratorData>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load generators and regions whenever the panel is opened
+    {
+        let service = name_generator_service.clone();
+        use_effect(move || {
+            if !*expanded.read() {
+                return;
+            }
+            let service = service.clone();
+            let location_service = location_service.clone();
+            let locations = locations.read().clone();
+            spawn_task(async move {
+                match service.list_generators().await {
+                    Ok(fetched) => {
+                        generators.set(fetched);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to load name generators: {}", e))),
+                }
+                let mut labelled = Vec::new();
+                for location in &locations {
+                    if let Ok(fetched) = location_service.get_regions(&location.id).await {
+                        labelled.extend(
+                            fetched
+                                .into_iter()
+                                .map(|r| (r.id, format!("{} › {}", location.name, r.name))),
+                        );
+                    }
+                }
+                regions.set(labelled);
+            });
+        });
+    }
+
+    let on_saved = move |saved: NameGeneratorData| {
+        let mut list = generators.write();
+        match list.iter_mut().find(|g| g.id == saved.id) {
+            Some(existing) => *existing = saved,
+            None => list.push(saved),
+        }
+        list.sort_by(|a, b| a.name.cmp(&b.name));
+        editing.set(None);
+    };
+    let on_deleted =
+        move |generator_id: String| generators.write().retain(|g| g.id != generator_id);
+    let on_edit = move |generator: NameGeneratorData| editing.set(Some(generator));
+    let on_error = move |message: String| error.set(Some(message));
+
+    let form_key = editing
+        .read()
+        .as_ref()
+        .map(|g| g.id.clone())
+        .unwrap_or_else(|| "new".to_string());
+
+    rsx! {
+        div {
+            class: "name-generator-panel flex flex-col gap-2 bg-dark-surface rounded-lg p-3",
+
+            button {
+                onclick: move |_| expanded.toggle(),
+                class: "bg-transparent border-0 p-0 text-left text-gray-400 text-sm uppercase cursor-pointer",
+                if *expanded.read() { "▾ Name generators" } else { "▸ Name generators" }
+            }
+
+            if *expanded.read() {
+                if generators.read().is_empty() {
+                    div {
+                        class: "text-gray-500 text-sm",
+                        "No name generators; the LLM will name NPCs itself"
+                    }
+                }
+                for generator in generators.read().iter().cloned() {
+                    NameGeneratorRow {
+                        key: "{generator.id}",
+                        world_id: world_id.clone(),
+                        generator: generator,
+                        regions: regions,
+                        on_edit: on_edit,
+                        on_deleted: on_deleted,
+                        on_error: on_error,
+                    }
+                }
+                // Keyed so picking another generator starts a fresh form
+                div {
+                    NameGeneratorForm {
+                        key: "{form_key}",
+                        editing: editing.read().clone(),
+                        regions: regions,
+                        on_saved: on_saved,
+                        on_cancel: move |_| editing.set(None),
+                        on_error: on_error,
+                    }
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div { class: "text-red-400 text-xs", "{err}" }
+                }
+            }
+        }
+    }
+}
+
+/// One generator with its assignments, a few sample names, and edit and
+/// delete buttons
+#[component]
+fn NameGeneratorRow(
+    world_id: String,
+    generator: NameGeneratorData,
+    regions: Signal<Vec<(String, String)>>,
+    on_edit: EventHandler<NameGeneratorData>,
+    on_deleted: EventHandler<String>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let name_generator_service = use_name_generator_service();
+    let mut samples: Signal<Vec<String>> = use_signal(Vec::new);
+
+    let sample = {
+        let service = name_generator_service.clone();
+        let generator_id = generator.id.clone();
+        move |_| {
+            let service = service.clone();
+            let world_id = world_id.clone();
+            let generator_id = generator_id.clone();
+            spawn_task(async move {
+                match service
+                    .generate_names(&world_id, Some(&generator_id), None, None, 5)
+                    .await
+                {
+                    Ok(generated) => samples.set(generated.names),
+                    Err(e) => on_error.call(format!("Failed to generate names: {}", e)),
+                }
+            });
+        }
+    };
+
+    let delete = {
+        let generator_id = generator.id.clone();
+        move |_| {
+            let service = name_generator_service.clone();
+            let generator_id = generator_id.clone();
+            spawn_task(async move {
+                match service.delete_generator(&generator_id).await {
+                    Ok(()) => on_deleted.call(generator_id),
+                    Err(e) => on_error.call(format!("Failed to delete name generator: {}", e)),
+                }
+            });
+        }
+    };
+
+    let assigned_to = if generator.is_default {
+        "World default".to_string()
+    } else {
+        let region_labels = regions.read();
+        generator
+            .region_ids
+            .iter()
+            .map(|id| {
+                region_labels
+                    .iter()
+                    .find(|(region_id, _)| region_id == id)
+                    .map(|(_, label)| label.clone())
+                    .unwrap_or_else(|| "Unknown region".to_string())
+            })
+            .chain(generator.factions.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let edit_generator = generator.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 text-sm",
+            div {
+                class: "flex items-center gap-2",
+                span {
+                    class: "text-white flex-1 truncate",
+                    title: "{generator.description}",
+                    "{generator.name}"
+                }
+                button {
+                    onclick: sample,
+                    title: "Draw a few names",
+                    class: "px-2 py-0.5 bg-purple-900 text-purple-200 text-xs rounded cursor-pointer",
+                    "Sample"
+                }
+                button {
+                    onclick: move |_| on_edit.call(edit_generator.clone()),
+                    class: "px-2 py-0.5 bg-gray-700 text-gray-300 text-xs rounded cursor-pointer",
+                    "Edit"
+                }
+                button {
+                    onclick: delete,
+                    class: "px-2 py-0.5 bg-transparent border-0 text-red-400 text-xs cursor-pointer",
+                    "✕"
+                }
+            }
+            span { class: "text-gray-500 text-xs", "{assigned_to}" }
+            if !samples.read().is_empty() {
+                span { class: "text-gray-300 text-xs italic", "{samples.read().join(\", \")}" }
+            }
+        }
+    }
+}
+
+/// Create a generator, or edit the one given
+#[component]
+fn NameGeneratorForm(
+    editing: Option<NameGeneratorData>,
+    regions: Signal<Vec<(String, String)>>,
+    on_saved: EventHandler<NameGeneratorData>,
+    on_cancel: EventHandler<()>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let name_generator_service = use_name_generator_service();
+    let initial = editing.clone();
+    let mut name = use_signal(|| initial.as_ref().map(|g| g.name.clone()).unwrap_or_default());
+    let mut description = use_signal(|| {
+        initial
+            .as_ref()
+            .map(|g| g.description.clone())
+            .unwrap_or_default()
+    });
+    let (is_grammar, first, second) = match initial.as_ref().map(|g| &g.style) {
+        Some(NameStyleData::Grammar { patterns, parts }) => {
+            (true, patterns.join("\n"), format_parts(parts))
+        }
+        Some(NameStyleData::List {
+            given_names,
+            family_names,
+        }) => (false, given_names.join(", "), family_names.join(", ")),
+        _ => (false, String::new(), String::new()),
+    };
+    let mut grammar = use_signal(|| is_grammar);
+    // Given names or patterns
+    let mut first_entries = use_signal(|| first);
+    // Family names or parts
+    let mut second_entries = use_signal(|| second);
+    let mut region_ids: Signal<Vec<String>> = use_signal(|| {
+        initial
+            .as_ref()
+            .map(|g| g.region_ids.clone())
+            .unwrap_or_default()
+    });
+    let mut factions = use_signal(|| {
+        initial
+            .as_ref()
+            .map(|g| g.factions.join(", "))
+            .unwrap_or_default()
+    });
+
+    let editing_id = editing.as_ref().map(|g| g.id.clone());
+    let is_editing = editing_id.is_some();
+
+    let save = move |_| {
+        let style = if *grammar.read() {
+            NameStyleData::Grammar {
+                patterns: split_lines(&first_entries.read()),
+                parts: parse_parts(&second_entries.read()),
+            }
+        } else {
+            NameStyleData::List {
+                given_names: split_list(&first_entries.read()),
+                family_names: split_list(&second_entries.read()),
+            }
+        };
+        let data = NameGeneratorInputData {
+            name: name.read().trim().to_string(),
+            description: description.read().trim().to_string(),
+            style,
+            region_ids: region_ids.read().clone(),
+            factions: split_list(&factions.read()),
+        };
+        let service = name_generator_service.clone();
+        let editing_id = editing_id.clone();
+        spawn_task(async move {
+            let result = match editing_id {
+                Some(id) => service.update_generator(&id, data).await,
+                None => service.create_generator(data).await,
+            };
+            match result {
+                Ok(saved) => {
+                    name.set(String::new());
+                    description.set(String::new());
+                    first_entries.set(String::new());
+                    second_entries.set(String::new());
+                    region_ids.set(Vec::new());
+                    factions.set(String::new());
+                    on_saved.call(saved);
+                }
+                Err(e) => on_error.call(format!("Failed to save name generator: {}", e)),
+            }
+        });
+    };
+
+    let input_class =
+        "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border";
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 border-t border-gray-700 pt-2",
+
+            div {
+                class: "flex gap-1",
+                input {
+                    r#type: "text",
+                    value: "{name}",
+                    placeholder: "Coastal villagers",
+                    oninput: move |e| name.set(e.value()),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+                select {
+                    value: if *grammar.read() { "grammar" } else { "list" },
+                    onchange: move |e| grammar.set(e.value() == "grammar"),
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    option { value: "list", "Name lists" }
+                    option { value: "grammar", "Syllable grammar" }
+                }
+            }
+            input {
+                r#type: "text",
+                value: "{description}",
+                placeholder: "Who uses these names (optional)",
+                oninput: move |e| description.set(e.value()),
+                class: input_class,
+            }
+            if *grammar.read() {
+                textarea {
+                    value: "{first_entries}",
+                    placeholder: "Patterns, one per line: {{start}}{{end}} of {{clan}}",
+                    oninput: move |e| first_entries.set(e.value()),
+                    class: input_class,
+                    rows: "2",
+                }
+                textarea {
+                    value: "{second_entries}",
+                    placeholder: "Parts, one per line: start: ka, mor, tel",
+                    oninput: move |e| second_entries.set(e.value()),
+                    class: input_class,
+                    rows: "3",
+                }
+            } else {
+                textarea {
+                    value: "{first_entries}",
+                    placeholder: "Given names, separated by commas",
+                    oninput: move |e| first_entries.set(e.value()),
+                    class: input_class,
+                    rows: "2",
+                }
+                textarea {
+                    value: "{second_entries}",
+                    placeholder: "Family names (optional)",
+                    oninput: move |e| second_entries.set(e.value()),
+                    class: input_class,
+                    rows: "2",
+                }
+            }
+
+            if !regions.read().is_empty() {
+                div {
+                    class: "flex flex-col gap-0.5 max-h-24 overflow-y-auto",
+                    for (region_id, label) in regions.read().iter().cloned() {
+                        label {
+                            key: "{region_id}",
+                            class: "flex items-center gap-1 text-gray-300 text-xs",
+                            input {
+                                r#type: "checkbox",
+                                checked: region_ids.read().contains(&region_id),
+                                onchange: {
+                                    let region_id = region_id.clone();
+                                    move |e: FormEvent| {
+                                        let mut ids = region_ids.write();
+                                        ids.retain(|id| *id != region_id);
+                                        if e.checked() {
+                                            ids.push(region_id.clone());
+                                        }
+                                    }
+                                },
+                            }
+                            "{label}"
+                        }
+                    }
+                }
+            }
+            input {
+                r#type: "text",
+                value: "{factions}",
+                placeholder: "Factions, separated by commas (optional)",
+                oninput: move |e| factions.set(e.value()),
+                class: input_class,
+            }
+
+            div {
+                class: "flex gap-1",
+                button {
+                    onclick: save,
+                    disabled: name.read().trim().is_empty() || first_entries.read().trim().is_empty(),
+                    class: "px-2 py-1 bg-blue-500 text-white text-xs rounded cursor-pointer",
+                    if is_editing { "Save generator" } else { "Add generator" }
+                }
+                if is_editing {
+                    button {
+                        onclick: move |_| on_cancel.call(()),
+                        class: "px-2 py-1 bg-gray-700 text-gray-300 text-xs rounded cursor-pointer",
+                        "Cancel"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Button that draws a few names from the world's default generator, shown
+/// as chips to pick from
+#[component]
+pub fn NameSuggestions(world_id: String, on_select: EventHandler<String>) -> Element {
+    let name_generator_service = use_name_generator_service();
+    let mut names: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let draw = move |_| {
+        let service = name_generator_service.clone();
+        let world_id = world_id.clone();
+        spawn_task(async move {
+            match service.generate_names(&world_id, None, None, None, 5).await {
+                Ok(generated) => {
+                    names.set(generated.names);
+                    error.set(None);
+                }
+                Err(e) => {
+                    names.set(Vec::new());
+                    error.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "relative",
+            button {
+                r#type: "button",
+                onclick: draw,
+                title: "Draw names from the world's name generator",
+                class: "px-3 py-2 bg-gray-700 text-white border-0 rounded cursor-pointer text-sm",
+                "🎲"
+            }
+            if !names.read().is_empty() || error.read().is_some() {
+                div {
+                    class: "absolute right-0 top-full mt-1 z-10 flex flex-col gap-1 p-2 bg-dark-surface border border-gray-700 rounded min-w-40",
+                    if let Some(err) = error.read().as_ref() {
+                        div { class: "text-red-400 text-xs", "{err}" }
+                    }
+                    for generated in names.read().iter().cloned() {
+                        button {
+                            key: "{generated}",
+                            r#type: "button",
+                            onclick: {
+                                let generated = generated.clone();
+                                move |_| {
+                                    on_select.call(generated.clone());
+                                    names.set(Vec::new());
+                                }
+                            },
+                            class: "text-left px-2 py-1 bg-transparent border-0 text-white text-sm rounded cursor-pointer hover:bg-gray-700",
+                            "{generated}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Comma- or newline-separated entries
+fn split_list(text: &str) -> Vec<String> {
+    text.split([',', '\n'])
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn split_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `part: a, b, c` per line
+fn parse_parts(text: &str) -> BTreeMap<String, Vec<String>> {
+    text.lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, entries)| (key.trim().to_string(), split_list(entries)))
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
+}
+
+fn format_parts(parts: &BTreeMap<String, Vec<String>>) -> String {
+    parts
+        .iter()
+        .map(|(key, entries)| format!("{}: {}", key, entries.join(", ")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,315 @@
+//! NPC Drafts - Named NPCs promoted from crowds and mentions, awaiting approval
+//!
+//! Promoting a crowd member (from the crowd panel), or a name mentioned in
+//! the conversation log or a lore entry, creates a draft the LLM fills in.
+//! Drafts show up here while generating; once filled, the DM edits them and
+//! approves them into characters, regenerates them or rejects them.
+
+use dioxus::prelude::*;
+use wrldbldr_domain::{unknown_names_in, CampbellArchetype, DispositionLevel};
+
+use crate::application::dto::{
+    NpcDraftData, NpcDraftInputData, NpcDraftSourceData, NpcDraftStatusData,
+};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_character_service, use_npc_draft_service};
+use crate::presentation::state::use_game_state;
+
+/// Drafts waiting for approval, newest first
+#[component]
+pub fn NpcDraftPanel(
+    /// Called after a draft is approved into a character
+    on_created: EventHandler<()>,
+) -> Element {
+    let npc_draft_service = use_npc_draft_service();
+    let game_state = use_game_state();
+    let mut npc_drafts = game_state.npc_drafts;
+    let mut expanded = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load the world's drafts; later changes arrive as events
+    use_effect(move || {
+        let service = npc_draft_service.clone();
+        spawn_task(async move {
+            match service.list_drafts().await {
+                Ok(drafts) => npc_drafts.set(drafts),
+                Err(e) => error.set(Some(format!("Failed to load NPC drafts: {}", e))),
+            }
+        });
+    });
+
+    let on_error = move |message: String| error.set(Some(message));
+    let drafts = npc_drafts.read().clone();
+
+    rsx! {
+        div {
+            class: "npc-draft-panel flex flex-col gap-2 bg-dark-surface rounded-lg p-3",
+
+            button {
+                onclick: move |_| expanded.toggle(),
+                class: "bg-transparent border-0 p-0 text-left text-gray-400 text-sm uppercase cursor-pointer",
+                if *expanded.read() { "▾ NPC drafts ({drafts.len()})" } else { "▸ NPC drafts ({drafts.len()})" }
+            }
+
+            if *expanded.read() {
+                if drafts.is_empty() {
+                    div {
+                        class: "text-gray-500 text-sm",
+                        "Promote a crowd member or a mentioned name to draft an NPC"
+                    }
+                }
+                for draft in drafts {
+                    NpcDraftRow {
+                        key: "{draft.id}-{draft.status:?}",
+                        draft: draft.clone(),
+                        on_created: on_created,
+                        on_error: on_error,
+                    }
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div { class: "text-red-400 text-xs", "{err}" }
+                }
+            }
+        }
+    }
+}
+
+/// One draft: generating, or editable with approve, regenerate and reject
+#[component]
+fn NpcDraftRow(
+    draft: NpcDraftData,
+    on_created: EventHandler<()>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let npc_draft_service = use_npc_draft_service();
+    let game_state = use_game_state();
+    let mut name = use_signal(|| draft.name.clone());
+    let mut description = use_signal(|| draft.description.clone());
+    let mut archetype = use_signal(|| draft.archetype.clone());
+    let mut disposition = use_signal(|| draft.disposition);
+
+    if draft.status == NpcDraftStatusData::Generating {
+        return rsx! {
+            div {
+                class: "text-sm text-gray-400 italic",
+                if draft.name.is_empty() {
+                    "{draft.source_label} — generating…"
+                } else {
+                    "{draft.name} ({draft.source_label}) — generating…"
+                }
+            }
+        };
+    }
+
+    let input = move || NpcDraftInputData {
+        name: name.read().trim().to_string(),
+        description: description.read().trim().to_string(),
+        archetype: archetype.read().clone(),
+        disposition: *disposition.read(),
+    };
+
+    // Approving saves the edits first, so what the DM sees is what is created
+    let approve = {
+        let service = npc_draft_service.clone();
+        let draft_id = draft.id.clone();
+        let game_state = game_state.clone();
+        move |_| {
+            let service = service.clone();
+            let draft_id = draft_id.clone();
+            let mut game_state = game_state.clone();
+            let data = input();
+            spawn_task(async move {
+                let result = match service.update_draft(&draft_id, data).await {
+                    Ok(_) => service.approve_draft(&draft_id).await,
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(_) => {
+                        game_state.remove_npc_draft(&draft_id);
+                        on_created.call(());
+                    }
+                    Err(e) => on_error.call(format!("Failed to approve NPC: {}", e)),
+                }
+            });
+        }
+    };
+
+    let regenerate = {
+        let service = npc_draft_service.clone();
+        let draft_id = draft.id.clone();
+        let game_state = game_state.clone();
+        move |_| {
+            let service = service.clone();
+            let draft_id = draft_id.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service.regenerate_draft(&draft_id).await {
+                    Ok(updated) => game_state.upsert_npc_draft(updated),
+                    Err(e) => on_error.call(format!("Failed to regenerate NPC: {}", e)),
+                }
+            });
+        }
+    };
+
+    let reject = {
+        let draft_id = draft.id.clone();
+        move |_| {
+            let service = npc_draft_service.clone();
+            let draft_id = draft_id.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service.reject_draft(&draft_id).await {
+                    Ok(()) => game_state.remove_npc_draft(&draft_id),
+                    Err(e) => on_error.call(format!("Failed to reject NPC: {}", e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 border-t border-gray-700 pt-2",
+
+            div { class: "text-gray-500 text-xs", "{draft.source_label}" }
+            if let Some(reason) = draft.generation_error.as_ref() {
+                div {
+                    class: "text-amber-400 text-xs",
+                    "Generation failed ({reason}); fill the NPC in by hand or regenerate"
+                }
+            }
+            input {
+                r#type: "text",
+                value: "{name}",
+                placeholder: "Name",
+                oninput: move |e| name.set(e.value()),
+                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+            }
+            textarea {
+                rows: 3,
+                value: "{description}",
+                placeholder: "Description",
+                oninput: move |e| description.set(e.value()),
+                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border resize-none",
+            }
+            div {
+                class: "flex gap-1",
+                select {
+                    value: "{archetype}",
+                    onchange: move |e| archetype.set(e.value()),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    for arch in CampbellArchetype::all() {
+                        option { key: "{arch}", value: "{arch}", "{arch}" }
+                    }
+                }
+                select {
+                    value: "{disposition.read().display_name()}",
+                    onchange: move |e| {
+                        disposition.set(e.value().parse().unwrap_or_default());
+                    },
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    for level in DispositionLevel::all() {
+                        option { key: "{level}", value: "{level}", "{level.display_name()}" }
+                    }
+                }
+            }
+            div {
+                class: "flex gap-1",
+                button {
+                    onclick: approve,
+                    disabled: name.read().trim().is_empty(),
+                    class: "px-2 py-1 bg-green-700 text-white text-xs rounded cursor-pointer",
+                    "Approve"
+                }
+                button {
+                    onclick: regenerate,
+                    class: "px-2 py-1 bg-gray-700 text-gray-200 text-xs rounded cursor-pointer",
+                    "Regenerate"
+                }
+                button {
+                    onclick: reject,
+                    class: "px-2 py-1 bg-transparent border-0 text-red-400 text-xs cursor-pointer",
+                    "Reject"
+                }
+            }
+        }
+    }
+}
+
+/// Names mentioned in a lore entry that aren't characters yet, each
+/// promotable to an NPC draft
+#[component]
+pub fn LoreMentions(
+    world_id: String,
+    lore_id: String,
+    /// The entry's summary and chunk text
+    text: String,
+) -> Element {
+    let character_service = use_character_service();
+    let npc_draft_service = use_npc_draft_service();
+    let game_state = use_game_state();
+    let npc_drafts = game_state.npc_drafts;
+    let mut character_names: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Reloaded as drafts come and go, since approving one creates a character
+    use_effect(move || {
+        let _ = npc_drafts.read().len();
+        let service = character_service.clone();
+        let world_id = world_id.clone();
+        spawn_task(async move {
+            if let Ok(characters) = service.list_characters(&world_id).await {
+                character_names.set(characters.into_iter().map(|c| c.name).collect());
+            }
+        });
+    });
+
+    let known: Vec<String> = character_names
+        .read()
+        .iter()
+        .cloned()
+        .chain(npc_drafts.read().iter().map(|d| d.name.clone()))
+        .collect();
+    let known: Vec<&str> = known.iter().map(String::as_str).collect();
+    let names = unknown_names_in(&text, &known);
+    if names.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "flex flex-wrap gap-1",
+            for name in names {
+                button {
+                    key: "{name}",
+                    title: "Promote to an NPC draft",
+                    onclick: {
+                        let service = npc_draft_service.clone();
+                        let lore_id = lore_id.clone();
+                        let game_state = game_state.clone();
+                        let name = name.clone();
+                        move |_| {
+                            let service = service.clone();
+                            let source = NpcDraftSourceData::Lore {
+                                lore_id: lore_id.clone(),
+                            };
+                            let name = name.clone();
+                            let mut game_state = game_state.clone();
+                            spawn_task(async move {
+                                match service.promote(source, Some(name)).await {
+                                    Ok(draft) => game_state.upsert_npc_draft(draft),
+                                    Err(e) => error.set(Some(format!("Failed to promote NPC: {}", e))),
+                                }
+                            });
+                        }
+                    },
+                    class: "px-2 py-0.5 bg-purple-900 text-purple-200 text-xs rounded cursor-pointer",
+                    "+ {name}"
+                }
+            }
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs w-full", "{err}" }
+            }
+        }
+    }
+}
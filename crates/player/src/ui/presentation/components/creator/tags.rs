@@ -0,0 +1,353 @@
+//! Entity tags - Tag editor and tag filter bar for Creator Mode
+//!
+//! Tags are the DM's freeform labels ("act-2", "villain-adjacent"). The
+//! editor adds and removes tags on one entity; the filter bar narrows the
+//! entity browser to entities carrying every chosen tag and keeps per-user
+//! saved filter presets.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::SavedFilterData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_tag_service;
+use crate::presentation::state::use_game_state;
+use wrldbldr_protocol::{EntityType, SaveFilterData};
+
+/// Split comma-separated input into trimmed, non-empty tags
+fn parse_tags(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Tag chips with add/remove controls for one entity
+#[component]
+pub fn TagEditor(world_id: String, entity_type: EntityType, entity_id: String) -> Element {
+    let game_state = use_game_state();
+    let tag_service = use_tag_service();
+    let mut input = use_signal(String::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load tags on mount; later changes arrive as EntityTagsUpdated
+    {
+        let world_id = world_id.clone();
+        let entity_id = entity_id.clone();
+        let service = tag_service.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let entity_id = entity_id.clone();
+            let service = service.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service
+                    .get_entity_tags(&world_id, entity_type, &entity_id)
+                    .await
+                {
+                    Ok(tags) => game_state.set_entity_tags(&entity_id, tags),
+                    Err(e) => error.set(Some(format!("Failed to load tags: {}", e))),
+                }
+            });
+        });
+    }
+
+    let tags = game_state
+        .entity_tags
+        .read()
+        .get(&entity_id)
+        .cloned()
+        .unwrap_or_default();
+
+    // Replace the entity's tags; the Engine normalizes and echoes them back
+    let save = {
+        let world_id = world_id.clone();
+        let entity_id = entity_id.clone();
+        let game_state = game_state.clone();
+        move |tags: Vec<String>| {
+            let world_id = world_id.clone();
+            let entity_id = entity_id.clone();
+            let service = tag_service.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service
+                    .set_entity_tags(&world_id, entity_type, &entity_id, tags)
+                    .await
+                {
+                    Ok(tags) => {
+                        error.set(None);
+                        game_state.set_entity_tags(&entity_id, tags);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to save tags: {}", e))),
+                }
+            });
+        }
+    };
+    let add = {
+        let save = save.clone();
+        let tags = tags.clone();
+        move || {
+            let added = parse_tags(&input.read());
+            if added.is_empty() {
+                return;
+            }
+            let mut all = tags.clone();
+            all.extend(added);
+            save(all);
+            input.set(String::new());
+        }
+    };
+    let mut add_on_click = add.clone();
+    let mut add_on_enter = add;
+
+    rsx! {
+        div {
+            class: "tag-editor flex flex-col gap-2",
+
+            div {
+                class: "flex flex-wrap gap-1",
+                for tag in tags.iter().cloned() {
+                    span {
+                        key: "{tag}",
+                        class: "inline-flex items-center gap-1 px-2 py-0.5 bg-blue-500 bg-opacity-20 text-blue-300 text-xs rounded",
+                        "{tag}"
+                        button {
+                            onclick: {
+                                let save = save.clone();
+                                let remaining: Vec<String> =
+                                    tags.iter().filter(|t| **t != tag).cloned().collect();
+                                move |_| save(remaining.clone())
+                            },
+                            class: "bg-transparent border-0 text-blue-300 cursor-pointer p-0 text-xs",
+                            aria_label: "Remove tag {tag}",
+                            "×"
+                        }
+                    }
+                }
+                if tags.is_empty() {
+                    span { class: "text-gray-500 text-xs italic", "No tags" }
+                }
+            }
+
+            div {
+                class: "flex gap-2",
+                input {
+                    r#type: "text",
+                    value: "{input}",
+                    placeholder: "Add tags (comma separated)",
+                    oninput: move |e| input.set(e.value()),
+                    onkeydown: move |e: KeyboardEvent| {
+                        if e.key() == Key::Enter {
+                            e.prevent_default();
+                            add_on_enter();
+                        }
+                    },
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                }
+                button {
+                    onclick: move |_| add_on_click(),
+                    class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                    "Add"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{err}" }
+            }
+        }
+    }
+}
+
+/// Tag filter input plus the current user's saved presets for one entity type
+#[component]
+pub fn TagFilterBar(
+    world_id: String,
+    entity_type: EntityType,
+    /// Tags the browser list is currently filtered by
+    active_tags: Signal<Vec<String>>,
+) -> Element {
+    let tag_service = use_tag_service();
+    let mut input = use_signal(String::new);
+    let mut preset_name = use_signal(String::new);
+    let mut presets: Signal<Vec<SavedFilterData>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load saved presets on mount
+    {
+        let world_id = world_id.clone();
+        let service = tag_service.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_saved_filters(&world_id).await {
+                    Ok(filters) => presets.set(filters),
+                    Err(e) => error.set(Some(format!("Failed to load presets: {}", e))),
+                }
+            });
+        });
+    }
+
+    let save_preset = {
+        let world_id = world_id.clone();
+        let service = tag_service.clone();
+        move |_| {
+            let name = preset_name.read().trim().to_string();
+            let tags = active_tags.read().clone();
+            if name.is_empty() || tags.is_empty() {
+                return;
+            }
+            let world_id = world_id.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                let data = SaveFilterData {
+                    filter_id: None,
+                    name,
+                    entity_type,
+                    tags,
+                };
+                match service.save_filter(&world_id, data).await {
+                    Ok(filter) => {
+                        presets.write().push(filter);
+                        preset_name.set(String::new());
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to save preset: {}", e))),
+                }
+            });
+        }
+    };
+
+    let active = active_tags.read().clone();
+    let matching_presets: Vec<SavedFilterData> = presets
+        .read()
+        .iter()
+        .filter(|p| p.entity_type == entity_type)
+        .cloned()
+        .collect();
+
+    rsx! {
+        div {
+            class: "tag-filter-bar flex flex-col gap-2",
+
+            input {
+                r#type: "text",
+                value: "{input}",
+                placeholder: "Filter by tags (comma separated)",
+                oninput: move |e| input.set(e.value()),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Enter {
+                        e.prevent_default();
+                        active_tags.set(parse_tags(&input.read()));
+                    }
+                },
+                class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white box-border",
+            }
+
+            if !active.is_empty() {
+                div {
+                    class: "flex flex-wrap items-center gap-1",
+                    for tag in active.iter() {
+                        span {
+                            key: "{tag}",
+                            class: "px-2 py-0.5 bg-blue-500 bg-opacity-20 text-blue-300 text-xs rounded",
+                            "{tag}"
+                        }
+                    }
+                    button {
+                        onclick: move |_| {
+                            input.set(String::new());
+                            active_tags.set(Vec::new());
+                        },
+                        class: "bg-transparent border-0 text-gray-400 text-xs cursor-pointer",
+                        "Clear"
+                    }
+                }
+
+                div {
+                    class: "flex gap-1",
+                    input {
+                        r#type: "text",
+                        value: "{preset_name}",
+                        placeholder: "Preset name",
+                        oninput: move |e| preset_name.set(e.value()),
+                        class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs box-border",
+                    }
+                    button {
+                        onclick: save_preset,
+                        disabled: preset_name.read().trim().is_empty(),
+                        class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                        "Save preset"
+                    }
+                }
+            }
+
+            if !matching_presets.is_empty() {
+                div {
+                    class: "flex flex-wrap gap-1",
+                    for preset in matching_presets {
+                        PresetChip {
+                            key: "{preset.id}",
+                            world_id: world_id.clone(),
+                            preset: preset.clone(),
+                            on_apply: move |tags: Vec<String>| {
+                                input.set(tags.join(", "));
+                                active_tags.set(tags);
+                            },
+                            on_deleted: move |id: String| presets.write().retain(|p| p.id != id),
+                            on_error: move |msg| error.set(Some(msg)),
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{err}" }
+            }
+        }
+    }
+}
+
+/// A saved preset: click to apply, × to delete
+#[component]
+fn PresetChip(
+    world_id: String,
+    preset: SavedFilterData,
+    on_apply: EventHandler<Vec<String>>,
+    on_deleted: EventHandler<String>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let tag_service = use_tag_service();
+    let tags = preset.tags.clone();
+    let preset_id = preset.id.clone();
+
+    rsx! {
+        span {
+            class: "inline-flex items-center gap-1 px-2 py-0.5 bg-gray-700 text-white text-xs rounded",
+            button {
+                onclick: move |_| on_apply.call(tags.clone()),
+                title: "{preset.tags.join(\", \")}",
+                class: "bg-transparent border-0 text-white cursor-pointer p-0 text-xs",
+                "{preset.name}"
+            }
+            button {
+                onclick: move |_| {
+                    let world_id = world_id.clone();
+                    let preset_id = preset_id.clone();
+                    let service = tag_service.clone();
+                    spawn_task(async move {
+                        match service.delete_saved_filter(&world_id, &preset_id).await {
+                            Ok(()) => on_deleted.call(preset_id),
+                            Err(e) => on_error.call(format!("Failed to delete preset: {}", e)),
+                        }
+                    });
+                },
+                class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0 text-xs",
+                aria_label: "Delete preset {preset.name}",
+                "×"
+            }
+        }
+    }
+}
@@ -0,0 +1,414 @@
+//! Entity templates - Template list, instantiation and "save as template"
+//!
+//! Templates are the DM's prefabs: an NPC, or a region with its items and
+//! NPCs. The panel stamps a template out under a new name, filling in any
+//! other `{variable}` placeholders; the save controls turn an existing NPC
+//! or region into a template.
+
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{EntityTemplateData, TemplateBodyData};
+use crate::application::services::location_service::LocationSummary;
+use crate::application::services::RegionListItemData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_location_service, use_template_service};
+
+/// Short label for a template's kind
+fn kind_label(body: &TemplateBodyData) -> &'static str {
+    match body {
+        TemplateBodyData::Npc(_) => "NPC",
+        TemplateBodyData::Region(_) => "Region",
+        TemplateBodyData::Unknown => "?",
+    }
+}
+
+/// The world's templates, with a form to instantiate the selected one
+#[component]
+pub fn TemplatePanel(
+    world_id: String,
+    /// Locations a region template can be placed in
+    locations: Signal<Vec<LocationSummary>>,
+    /// Called after a template was instantiated, so entity lists can reload
+    on_created: EventHandler<()>,
+) -> Element {
+    let template_service = use_template_service();
+    let mut templates: Signal<Vec<EntityTemplateData>> = use_signal(Vec::new);
+    let mut selected_id: Signal<Option<String>> = use_signal(|| None);
+    let mut reload = use_signal(|| 0u32);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load templates on mount and whenever the reload counter changes
+    {
+        let world_id = world_id.clone();
+        let service = template_service.clone();
+        use_effect(move || {
+            let _ = reload.read();
+            let world_id = world_id.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_templates(&world_id).await {
+                    Ok(fetched) => templates.set(fetched),
+                    Err(e) => error.set(Some(format!("Failed to load templates: {}", e))),
+                }
+            });
+        });
+    }
+
+    let selected = selected_id
+        .read()
+        .as_ref()
+        .and_then(|id| templates.read().iter().find(|t| &t.id == id).cloned());
+
+    rsx! {
+        div {
+            class: "template-panel flex flex-col gap-2 bg-dark-surface rounded-lg p-3",
+
+            div {
+                class: "flex justify-between items-center",
+                h3 { class: "text-gray-400 text-sm uppercase m-0", "Templates" }
+                button {
+                    onclick: move |_| reload += 1,
+                    class: "bg-transparent border-0 text-gray-400 text-xs cursor-pointer",
+                    "Refresh"
+                }
+            }
+
+            if templates.read().is_empty() {
+                span { class: "text-gray-500 text-xs italic", "No templates yet" }
+            }
+
+            div {
+                class: "flex flex-col gap-1 max-h-40 overflow-y-auto",
+                for template in templates.read().iter().cloned() {
+                    TemplateRow {
+                        key: "{template.id}",
+                        selected: selected_id.read().as_deref() == Some(template.id.as_str()),
+                        template: template.clone(),
+                        on_select: move |id| selected_id.set(Some(id)),
+                        on_deleted: move |id: String| {
+                            templates.write().retain(|t| t.id != id);
+                            if selected_id.read().as_deref() == Some(id.as_str()) {
+                                selected_id.set(None);
+                            }
+                        },
+                        on_error: move |msg| error.set(Some(msg)),
+                    }
+                }
+            }
+
+            if let Some(template) = selected {
+                InstantiateForm {
+                    key: "{template.id}",
+                    template: template.clone(),
+                    locations: locations,
+                    on_created: move |_| on_created.call(()),
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{err}" }
+            }
+        }
+    }
+}
+
+/// One template in the list: click to select, × to delete
+#[component]
+fn TemplateRow(
+    template: EntityTemplateData,
+    selected: bool,
+    on_select: EventHandler<String>,
+    on_deleted: EventHandler<String>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let template_service = use_template_service();
+    let template_id = template.id.clone();
+    let delete_id = template.id.clone();
+    let row_class = if selected {
+        "flex items-center gap-2 px-2 py-1 bg-blue-500 bg-opacity-20 rounded text-sm"
+    } else {
+        "flex items-center gap-2 px-2 py-1 rounded text-sm"
+    };
+
+    rsx! {
+        div {
+            class: "{row_class}",
+            span {
+                class: "px-1 bg-gray-700 text-gray-300 text-xs rounded",
+                "{kind_label(&template.body)}"
+            }
+            button {
+                onclick: move |_| on_select.call(template_id.clone()),
+                title: template.description.clone().unwrap_or_default(),
+                class: "flex-1 text-left bg-transparent border-0 text-white cursor-pointer p-0 text-sm",
+                "{template.name}"
+            }
+            button {
+                onclick: move |_| {
+                    let template_id = delete_id.clone();
+                    let service = template_service.clone();
+                    spawn_task(async move {
+                        match service.delete_template(&template_id).await {
+                            Ok(()) => on_deleted.call(template_id),
+                            Err(e) => on_error.call(format!("Failed to delete template: {}", e)),
+                        }
+                    });
+                },
+                class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0 text-xs",
+                aria_label: "Delete template {template.name}",
+                "×"
+            }
+        }
+    }
+}
+
+/// Name, variable and location inputs for stamping out one template
+#[component]
+fn InstantiateForm(
+    template: EntityTemplateData,
+    locations: Signal<Vec<LocationSummary>>,
+    on_created: EventHandler<()>,
+) -> Element {
+    let template_service = use_template_service();
+    let mut name = use_signal(String::new);
+    let mut variables: Signal<BTreeMap<String, String>> = use_signal(BTreeMap::new);
+    let mut location_id = use_signal(String::new);
+    let mut is_creating = use_signal(|| false);
+    let mut message: Signal<Option<String>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let needs_location = matches!(template.body, TemplateBodyData::Region(_));
+    let extra_variables: Vec<String> = template
+        .variables
+        .iter()
+        .filter(|v| v.as_str() != "name")
+        .cloned()
+        .collect();
+
+    let create = {
+        let template_id = template.id.clone();
+        move |_| {
+            let template_id = template_id.clone();
+            let service = template_service.clone();
+            let entity_name = name.read().trim().to_string();
+            let vars = variables.read().clone();
+            let location = Some(location_id.read().clone()).filter(|id| !id.is_empty());
+            is_creating.set(true);
+            spawn_task(async move {
+                match service
+                    .instantiate_template(&template_id, &entity_name, vars, location)
+                    .await
+                {
+                    Ok(created) => {
+                        error.set(None);
+                        message.set(Some(format!(
+                            "Created {}",
+                            created
+                                .iter()
+                                .map(|c| c.name.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )));
+                        name.set(String::new());
+                        on_created.call(());
+                    }
+                    Err(e) => {
+                        message.set(None);
+                        error.set(Some(format!("Failed to create from template: {}", e)));
+                    }
+                }
+                is_creating.set(false);
+            });
+        }
+    };
+
+    let can_create = !*is_creating.read()
+        && !name.read().trim().is_empty()
+        && (!needs_location || !location_id.read().is_empty());
+
+    rsx! {
+        div {
+            class: "instantiate-form flex flex-col gap-2 border-t border-gray-700 pt-2",
+
+            input {
+                r#type: "text",
+                value: "{name}",
+                placeholder: "Name",
+                oninput: move |e| name.set(e.value()),
+                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+            }
+
+            for variable in extra_variables {
+                input {
+                    key: "{variable}",
+                    r#type: "text",
+                    value: variables.read().get(&variable).cloned().unwrap_or_default(),
+                    placeholder: "{variable}",
+                    oninput: {
+                        let variable = variable.clone();
+                        move |e: FormEvent| {
+                            variables.write().insert(variable.clone(), e.value());
+                        }
+                    },
+                    class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                }
+            }
+
+            if needs_location {
+                select {
+                    value: "{location_id}",
+                    onchange: move |e| location_id.set(e.value()),
+                    class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+
+                    option { value: "", "Choose a location" }
+                    for location in locations.read().iter() {
+                        option { key: "{location.id}", value: "{location.id}", "{location.name}" }
+                    }
+                }
+            }
+
+            button {
+                onclick: create,
+                disabled: !can_create,
+                class: "px-2 py-1 bg-blue-500 text-white text-xs rounded cursor-pointer",
+                if *is_creating.read() { "Creating..." } else { "Create from template" }
+            }
+
+            if let Some(msg) = message.read().as_ref() {
+                div { class: "text-green-400 text-xs", "{msg}" }
+            }
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{err}" }
+            }
+        }
+    }
+}
+
+/// "Save as template" for one NPC
+#[component]
+pub fn SaveCharacterTemplate(character_id: String) -> Element {
+    let template_service = use_template_service();
+    let mut template_name = use_signal(String::new);
+    let mut status: Signal<Option<String>> = use_signal(|| None);
+
+    let save = move |_| {
+        let character_id = character_id.clone();
+        let service = template_service.clone();
+        let name = template_name.read().trim().to_string();
+        spawn_task(async move {
+            match service
+                .save_character_as_template(&character_id, &name)
+                .await
+            {
+                Ok(template) => {
+                    template_name.set(String::new());
+                    status.set(Some(format!("Saved template \"{}\"", template.name)));
+                }
+                Err(e) => status.set(Some(format!("Failed to save template: {}", e))),
+            }
+        });
+    };
+
+    rsx! {
+        SaveTemplateRow {
+            template_name: template_name,
+            on_save: save,
+        }
+        if let Some(msg) = status.read().as_ref() {
+            div { class: "text-gray-400 text-xs mt-1", "{msg}" }
+        }
+    }
+}
+
+/// "Save as template" for a region of one location, with its items and NPCs
+#[component]
+pub fn SaveRegionTemplate(location_id: String) -> Element {
+    let template_service = use_template_service();
+    let location_service = use_location_service();
+    let mut regions: Signal<Vec<RegionListItemData>> = use_signal(Vec::new);
+    let mut region_id = use_signal(String::new);
+    let mut template_name = use_signal(String::new);
+    let mut status: Signal<Option<String>> = use_signal(|| None);
+
+    // Load the location's regions on mount
+    {
+        let location_id = location_id.clone();
+        use_effect(move || {
+            let location_id = location_id.clone();
+            let service = location_service.clone();
+            spawn_task(async move {
+                match service.get_regions(&location_id).await {
+                    Ok(fetched) => regions.set(fetched),
+                    Err(e) => status.set(Some(format!("Failed to load regions: {}", e))),
+                }
+            });
+        });
+    }
+
+    let save = move |_| {
+        let region = region_id.read().clone();
+        if region.is_empty() {
+            return;
+        }
+        let service = template_service.clone();
+        let name = template_name.read().trim().to_string();
+        spawn_task(async move {
+            match service.save_region_as_template(&region, &name).await {
+                Ok(template) => {
+                    template_name.set(String::new());
+                    status.set(Some(format!("Saved template \"{}\"", template.name)));
+                }
+                Err(e) => status.set(Some(format!("Failed to save template: {}", e))),
+            }
+        });
+    };
+
+    rsx! {
+        if regions.read().is_empty() {
+            span { class: "text-gray-500 text-xs italic", "No regions to save" }
+        } else {
+            select {
+                value: "{region_id}",
+                onchange: move |e| region_id.set(e.value()),
+                class: "w-full p-1 mb-2 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+
+                option { value: "", "Choose a region" }
+                for region in regions.read().iter() {
+                    option { key: "{region.id}", value: "{region.id}", "{region.name}" }
+                }
+            }
+            SaveTemplateRow {
+                template_name: template_name,
+                on_save: save,
+            }
+        }
+        if let Some(msg) = status.read().as_ref() {
+            div { class: "text-gray-400 text-xs mt-1", "{msg}" }
+        }
+    }
+}
+
+/// Template name input and save button
+#[component]
+fn SaveTemplateRow(template_name: Signal<String>, on_save: EventHandler<()>) -> Element {
+    rsx! {
+        div {
+            class: "flex gap-2",
+            input {
+                r#type: "text",
+                value: "{template_name}",
+                placeholder: "Template name",
+                oninput: move |e| template_name.set(e.value()),
+                class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+            }
+            button {
+                onclick: move |_| on_save.call(()),
+                disabled: template_name.read().trim().is_empty(),
+                class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                "Save as template"
+            }
+        }
+    }
+}
@@ -0,0 +1,377 @@
+//! Dice tray component
+//!
+//! Lets a player roll any dice expression outside of a challenge. The dice
+//! tumble locally while the request is in flight, but the numbers shown while
+//! tumbling are only for show: the Engine rolls, and its breakdown is what
+//! lands. Favorite expressions are saved per player character.
+//!
+//! Uses `wrldbldr_domain::value_objects::DiceExpression` to validate input before
+//! sending (same approved exception as the challenge roll modal).
+
+use dioxus::prelude::*;
+use wrldbldr_domain::value_objects::{DiceExpression, DiceTerm, DieKind, KeepRule};
+
+use crate::application::dto::DiceRollData;
+use crate::infrastructure::spawn_task;
+use crate::ports::outbound::{storage_keys, PlatformPort};
+use crate::presentation::services::use_dice_service;
+use crate::presentation::state::{use_accessibility_state, use_game_state};
+use crate::use_platform;
+
+/// How often the tumbling faces change
+const TUMBLE_FRAME_MS: u64 = 80;
+
+/// Minimum tumble time, so a fast response doesn't cut the animation short
+const MIN_TUMBLE_MS: u64 = 600;
+
+/// Maximum number of saved expressions per PC
+const MAX_FAVORITES: usize = 12;
+
+/// Single dice offered as one-click rolls
+const QUICK_DICE: &[&str] = &["1d4", "1d6", "1d8", "1d10", "1d12", "1d20", "1d100"];
+
+fn favorites_key(pc_id: &str) -> String {
+    format!("{}_{}", storage_keys::DICE_FAVORITES, pc_id)
+}
+
+fn load_favorites(platform: &dyn PlatformPort, pc_id: &str) -> Vec<String> {
+    platform
+        .storage_load(&favorites_key(pc_id))
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_favorites(platform: &dyn PlatformPort, pc_id: &str, favorites: &[String]) {
+    let key = favorites_key(pc_id);
+    if favorites.is_empty() {
+        platform.storage_remove(&key);
+        return;
+    }
+    match serde_json::to_string(favorites) {
+        Ok(json) => platform.storage_save(&key, &json),
+        Err(e) => tracing::warn!("Failed to save dice favorites: {}", e),
+    }
+}
+
+/// Random faces to show while the server roll is in flight
+fn tumble_faces(platform: &dyn PlatformPort, expression: &DiceExpression) -> Vec<i32> {
+    let mut faces = Vec::new();
+    for signed in &expression.terms {
+        let DiceTerm::Dice(pool) = &signed.term else {
+            continue;
+        };
+        let count = match pool.keep {
+            Some(KeepRule::Advantage | KeepRule::Disadvantage) => 2,
+            _ => pool.count,
+        };
+        let (min, max) = match pool.die {
+            DieKind::Numbered(sides) => (1, sides as i32),
+            DieKind::Fate => (-1, 1),
+        };
+        faces.extend((0..count).map(|_| platform.random_range(min, max)));
+    }
+    faces
+}
+
+/// Dice button and tray panel
+#[component]
+pub fn DiceTray() -> Element {
+    let platform = use_platform();
+    let dice_service = use_dice_service();
+    let game_state = use_game_state();
+    let reduced_motion = use_accessibility_state().reduced_motion;
+
+    let mut is_open = use_signal(|| false);
+    let mut formula = use_signal(|| "1d20".to_string());
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    // Faces on screen while waiting for the server (None when idle)
+    let mut tumbling: Signal<Option<Vec<i32>>> = use_signal(|| None);
+    // Bumped per roll so a previous roll's animation loop stops
+    let mut roll_seq = use_signal(|| 0u32);
+    let mut last_roll: Signal<Option<DiceRollData>> = use_signal(|| None);
+    let mut favorites: Signal<Vec<String>> = use_signal(Vec::new);
+
+    let pc_id = game_state.selected_pc_id.read().clone();
+    let world_id = game_state.world.read().as_ref().map(|w| w.world.id.clone());
+
+    // Favorites follow the selected PC
+    {
+        let platform = platform.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let saved = game_state
+                .selected_pc_id
+                .read()
+                .as_deref()
+                .map(|pc_id| load_favorites(platform.as_ref(), pc_id))
+                .unwrap_or_default();
+            favorites.set(saved);
+        });
+    }
+
+    let roll = {
+        let platform = platform.clone();
+        let game_state = game_state.clone();
+        let world_id = world_id.clone();
+        move |expression: String| {
+            let Some(world_id) = world_id.clone() else {
+                return;
+            };
+            if tumbling.peek().is_some() {
+                return;
+            }
+            let parsed = match DiceExpression::parse(&expression) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    error.set(Some(e.to_string()));
+                    return;
+                }
+            };
+            error.set(None);
+            let seq = *roll_seq.peek() + 1;
+            roll_seq.set(seq);
+            tumbling.set(Some(tumble_faces(platform.as_ref(), &parsed)));
+
+            let animate = !*reduced_motion.peek();
+            if animate {
+                let platform = platform.clone();
+                let parsed = parsed.clone();
+                spawn_task(async move {
+                    loop {
+                        platform.sleep_ms(TUMBLE_FRAME_MS).await;
+                        if *roll_seq.peek() != seq || tumbling.peek().is_none() {
+                            break;
+                        }
+                        tumbling.set(Some(tumble_faces(platform.as_ref(), &parsed)));
+                    }
+                });
+            }
+
+            let platform = platform.clone();
+            let service = dice_service.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                let started = platform.now_millis();
+                let result = service.roll(&world_id, &parsed.to_string()).await;
+                let elapsed = platform.now_millis().saturating_sub(started);
+                if animate && elapsed < MIN_TUMBLE_MS {
+                    platform.sleep_ms(MIN_TUMBLE_MS - elapsed).await;
+                }
+                tumbling.set(None);
+                match result {
+                    Ok(roll) => {
+                        last_roll.set(Some(roll.clone()));
+                        game_state.record_dice_roll(roll);
+                    }
+                    Err(e) => error.set(Some(format!("Roll failed: {}", e))),
+                }
+            });
+        }
+    };
+
+    let save_favorite = {
+        let platform = platform.clone();
+        let pc_id = pc_id.clone();
+        move |_| {
+            let Some(pc_id) = pc_id.as_deref() else {
+                return;
+            };
+            let expression = match DiceExpression::parse(&formula.peek()) {
+                Ok(parsed) => parsed.to_string(),
+                Err(e) => {
+                    error.set(Some(e.to_string()));
+                    return;
+                }
+            };
+            let mut saved = favorites.peek().clone();
+            if saved.contains(&expression) {
+                return;
+            }
+            saved.push(expression);
+            if saved.len() > MAX_FAVORITES {
+                saved.remove(0);
+            }
+            save_favorites(platform.as_ref(), pc_id, &saved);
+            favorites.set(saved);
+        }
+    };
+
+    let open = *is_open.read();
+    let faces = tumbling.read().clone();
+    let is_rolling = faces.is_some();
+    let saved = favorites.read().clone();
+    let recent: Vec<DiceRollData> = game_state
+        .dice_rolls
+        .read()
+        .iter()
+        .take(5)
+        .cloned()
+        .collect();
+    let can_roll = world_id.is_some() && !is_rolling;
+
+    rsx! {
+        div {
+            class: "dice-tray flex flex-col items-end gap-1",
+
+            button {
+                onclick: move |_| is_open.set(!open),
+                class: "px-3 py-1 bg-black/70 text-white rounded-lg text-xs cursor-pointer",
+                aria_label: "Dice tray",
+                aria_expanded: "{open}",
+                aria_controls: "dice-tray-panel",
+                span { aria_hidden: "true", "🎲" }
+            }
+
+            if open {
+                div {
+                    id: "dice-tray-panel",
+                    class: "w-72 flex flex-col gap-2 p-3 bg-black/85 rounded-lg",
+                    role: "region",
+                    aria_label: "Dice tray",
+
+                    // Expression input
+                    form {
+                        class: "flex gap-2",
+                        onsubmit: {
+                            let mut roll = roll.clone();
+                            move |evt: FormEvent| {
+                                evt.prevent_default();
+                                roll(formula.peek().clone());
+                            }
+                        },
+                        input {
+                            r#type: "text",
+                            value: "{formula}",
+                            oninput: move |evt| formula.set(evt.value()),
+                            placeholder: "e.g. 2d6+1, 4d6kh3, d20adv+5",
+                            aria_label: "Dice expression",
+                            class: "flex-1 min-w-0 px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                        }
+                        button {
+                            r#type: "submit",
+                            disabled: !can_roll,
+                            class: "px-3 py-1 bg-amber-600 hover:bg-amber-500 disabled:bg-gray-700 text-white rounded text-sm cursor-pointer",
+                            "Roll"
+                        }
+                    }
+
+                    // One-click single dice
+                    div {
+                        class: "flex flex-wrap gap-1",
+                        for die in QUICK_DICE.iter().copied() {
+                            button {
+                                key: "{die}",
+                                onclick: {
+                                    let mut roll = roll.clone();
+                                    move |_| {
+                                        formula.set(die.to_string());
+                                        roll(die.to_string());
+                                    }
+                                },
+                                disabled: !can_roll,
+                                class: "px-2 py-0.5 bg-gray-700 hover:bg-gray-600 disabled:opacity-50 text-white rounded text-xs cursor-pointer",
+                                "{die.trim_start_matches('1')}"
+                            }
+                        }
+                    }
+
+                    // Saved expressions for this PC
+                    div {
+                        class: "flex flex-wrap items-center gap-1",
+                        for favorite in saved {
+                            span {
+                                key: "{favorite}",
+                                class: "flex items-center bg-amber-900/60 rounded text-xs",
+                                button {
+                                    onclick: {
+                                        let mut roll = roll.clone();
+                                        let favorite = favorite.clone();
+                                        move |_| {
+                                            formula.set(favorite.clone());
+                                            roll(favorite.clone());
+                                        }
+                                    },
+                                    disabled: !can_roll,
+                                    class: "px-2 py-0.5 text-amber-100 cursor-pointer",
+                                    "★ {favorite}"
+                                }
+                                button {
+                                    onclick: {
+                                        let platform = platform.clone();
+                                        let pc_id = pc_id.clone();
+                                        let favorite = favorite.clone();
+                                        move |_| {
+                                            let mut remaining = favorites.peek().clone();
+                                            remaining.retain(|f| f != &favorite);
+                                            if let Some(pc_id) = pc_id.as_deref() {
+                                                save_favorites(platform.as_ref(), pc_id, &remaining);
+                                            }
+                                            favorites.set(remaining);
+                                        }
+                                    },
+                                    class: "px-1 text-amber-300 hover:text-white cursor-pointer",
+                                    aria_label: "Remove {favorite} from favorites",
+                                    "×"
+                                }
+                            }
+                        }
+                        if pc_id.is_some() {
+                            button {
+                                onclick: save_favorite,
+                                class: "px-2 py-0.5 text-xs text-gray-400 hover:text-white cursor-pointer",
+                                "☆ Save"
+                            }
+                        }
+                    }
+
+                    // Tumbling dice, then the server's result
+                    div {
+                        class: "min-h-[4rem] flex flex-col items-center justify-center gap-1",
+                        aria_live: "polite",
+                        if let Some(faces) = faces {
+                            div {
+                                class: "flex flex-wrap justify-center gap-1",
+                                style: "perspective: 400px;",
+                                aria_hidden: "true",
+                                for (i, face) in faces.iter().enumerate() {
+                                    span {
+                                        key: "{i}",
+                                        class: "w-8 h-8 flex items-center justify-center bg-white text-black rounded font-bold text-sm animate-dice-tumble",
+                                        "{face}"
+                                    }
+                                }
+                            }
+                            span { class: "sr-only", "Rolling…" }
+                        } else if let Some(roll) = last_roll.read().as_ref() {
+                            div {
+                                class: "flex flex-col items-center animate-fade-in",
+                                div { class: "text-white text-3xl font-bold", "{roll.total}" }
+                                div { class: "text-gray-300 text-xs", "{roll.breakdown}" }
+                            }
+                        }
+                    }
+
+                    if let Some(err) = error.read().as_ref() {
+                        p { class: "text-red-400 text-xs", role: "alert", "{err}" }
+                    }
+
+                    // Latest rolls at the table, ours included
+                    if !recent.is_empty() {
+                        ul {
+                            class: "border-t border-gray-700 pt-2 flex flex-col gap-0.5",
+                            aria_label: "Recent rolls",
+                            for (i, roll) in recent.into_iter().enumerate() {
+                                li {
+                                    key: "{i}",
+                                    class: "flex justify-between text-xs text-gray-400",
+                                    span { class: "truncate", "{roll.roller_name}: {roll.formula}" }
+                                    span { class: "text-gray-200", "{roll.total}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
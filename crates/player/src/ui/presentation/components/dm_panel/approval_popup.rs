@@ -7,6 +7,7 @@
 use crate::application::dto::{
     ChallengeSuggestionInfo, NarrativeEventSuggestionInfo, OutcomeDetailData,
 };
+use crate::presentation::utils::{focus_mounted, use_roving_focus};
 use dioxus::prelude::*;
 use wrldbldr_domain::{parse_dialogue, validate_markers, ExpressionConfig};
 
@@ -96,8 +97,12 @@ pub struct ApprovalPopupProps {
 /// Displays proposed NPC dialogue and tool calls with checkboxes.
 /// DM can approve, modify, or reject the proposed actions.
 /// Supports expression marker editing with validation.
+///
+/// The popup takes focus when it appears so screen readers announce it, and
+/// the arrow keys move between Accept, Modify, and Reject.
 #[component]
 pub fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
+    let decision_focus = use_roving_focus();
     let mut actions = use_signal(|| props.proposed_actions.clone());
     let mut edited_dialogue = use_signal(|| props.dialogue.clone());
     let mut is_editing_dialogue = use_signal(|| false);
@@ -116,9 +121,14 @@ pub fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
     rsx! {
         div {
             class: "approval-popup bg-gray-700 border-2 border-amber-500 rounded-xl p-5 shadow-2xl",
+            role: "region",
+            aria_labelledby: "approval-popup-title",
+            tabindex: "-1",
+            onmounted: move |e| focus_mounted(e.data()),
 
             // Header
             h3 {
+                id: "approval-popup-title",
                 class: "text-amber-500 m-0 mb-4 text-base",
                 "Approval Required"
             }
@@ -151,6 +161,8 @@ pub fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
 
                     button {
                         class: "text-xs text-blue-400 hover:text-blue-300 bg-transparent border-0 cursor-pointer",
+                        aria_pressed: "{is_editing_dialogue}",
+                        aria_label: "Edit proposed dialogue",
                         onclick: move |_| {
                             let current = *is_editing_dialogue.read();
                             is_editing_dialogue.set(!current);
@@ -164,7 +176,9 @@ pub fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
                     textarea {
                         class: "w-full min-h-[80px] p-2 bg-dark-bg border border-gray-600 rounded text-white italic resize-y",
                         value: "{edited_dialogue}",
+                        aria_label: "Proposed dialogue",
                         oninput: move |e| edited_dialogue.set(e.value()),
+                        onmounted: move |e| focus_mounted(e.data()),
                         placeholder: "Enter dialogue with optional *expression* markers...",
                     }
                 } else {
@@ -324,6 +338,26 @@ pub fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
                                 }
                             }
 
+                            // Party odds at the suggested difficulty
+                            if !suggestion.party_odds.is_empty() {
+                                div {
+                                    class: "mb-2 flex flex-col gap-0.5",
+
+                                    for odds in suggestion.party_odds.iter() {
+                                        div {
+                                            key: "{odds.pc_id}",
+                                            class: "flex justify-between text-xs",
+
+                                            span { class: "text-gray-300", "{odds.pc_name} ({odds.modifier:+})" }
+                                            span {
+                                                class: "text-amber-400",
+                                                {odds.success_percent.map(|p| format!("{}%", p)).unwrap_or_else(|| "DM call".to_string())}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
                             p {
                                 class: "text-gray-400 text-xs italic m-0 mb-3 leading-snug",
                                 "\"{suggestion.reasoning}\""
@@ -493,9 +527,16 @@ pub fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
             // Action buttons
             div {
                 class: "flex gap-3",
+                role: "group",
+                aria_label: "Decision",
+                onkeydown: move |e: KeyboardEvent| {
+                    decision_focus.handle_key(&e);
+                },
 
                 button {
                     onclick: move |_| props.on_accept.call(actions.read().to_vec()),
+                    onmounted: move |e| decision_focus.register(0, e.data()),
+                    onfocus: move |_| decision_focus.set_current(0),
                     class: "flex-1 p-3 bg-green-500 text-white border-0 rounded-lg cursor-pointer font-semibold text-sm transition-colors duration-200",
                     onmouseover: move |_| {},
                     "Accept"
@@ -503,12 +544,16 @@ pub fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
 
                 button {
                     onclick: move |_| props.on_modify.call(()),
+                    onmounted: move |e| decision_focus.register(1, e.data()),
+                    onfocus: move |_| decision_focus.set_current(1),
                     class: "flex-1 p-3 bg-blue-500 text-white border-0 rounded-lg cursor-pointer font-semibold text-sm transition-colors duration-200",
                     "Modify"
                 }
 
                 button {
                     onclick: move |_| props.on_reject.call(()),
+                    onmounted: move |e| decision_focus.register(2, e.data()),
+                    onfocus: move |_| decision_focus.set_current(2),
                     class: "flex-1 p-3 bg-red-500 text-white border-0 rounded-lg cursor-pointer font-semibold text-sm transition-colors duration-200",
                     "Reject"
                 }
@@ -722,6 +767,7 @@ fn OutcomeTab(props: OutcomeTabProps) -> Element {
             // Header (clickable to expand)
             button {
                 onclick: move |_| props.on_toggle.call(()),
+                aria_expanded: "{is_expanded}",
                 class: "w-full flex justify-between items-center p-2 px-3 bg-transparent border-0 cursor-pointer text-left",
 
                 span {
@@ -732,6 +778,7 @@ fn OutcomeTab(props: OutcomeTabProps) -> Element {
 
                 span {
                     class: "text-gray-500 text-xs",
+                    aria_hidden: "true",
                     if is_expanded { "v" } else { ">" }
                 }
             }
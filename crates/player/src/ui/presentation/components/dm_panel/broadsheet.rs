@@ -0,0 +1,326 @@
+//! Broadsheet Panel for DM
+//!
+//! Lists the world's broadsheet issues and provides controls for:
+//! - Drafting an issue from the news since the last one
+//! - Editing a draft's title and articles
+//! - Publishing a draft, which hands it to every player
+//! - Removing an issue
+//!
+//! The engine also drafts an issue by itself whenever a game week ends.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{BroadsheetArticleData, BroadsheetData, BroadsheetInputData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_broadsheet_service;
+use crate::presentation::state::use_game_state;
+
+/// The game date of an RFC 3339 timestamp: "1490-03-01"
+fn game_date(timestamp: &str) -> &str {
+    timestamp.get(..10).unwrap_or(timestamp)
+}
+
+/// Broadsheet Panel component for DM view
+#[component]
+pub fn BroadsheetPanel() -> Element {
+    let broadsheet_service = use_broadsheet_service();
+    let game_state = use_game_state();
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut generating = use_signal(|| false);
+
+    // Load issues on mount
+    {
+        let service = broadsheet_service.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let mut game_state = game_state.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_broadsheets().await {
+                    Ok(list) => game_state.set_broadsheets(list),
+                    Err(e) => error.set(Some(format!("Failed to load broadsheets: {}", e))),
+                }
+            });
+        });
+    }
+
+    let generate = {
+        let service = broadsheet_service.clone();
+        let game_state = game_state.clone();
+        move |_| {
+            let mut game_state = game_state.clone();
+            let service = service.clone();
+            generating.set(true);
+            error.set(None);
+            spawn_task(async move {
+                match service.generate_broadsheet().await {
+                    Ok(issue) => game_state.upsert_broadsheet(issue),
+                    Err(e) => error.set(Some(format!("Failed to draft broadsheet: {}", e))),
+                }
+                generating.set(false);
+            });
+        }
+    };
+
+    let list = game_state.broadsheets.read().clone();
+
+    rsx! {
+        div {
+            class: "broadsheet-panel bg-dark-surface rounded-lg p-4",
+
+            div {
+                class: "flex items-center justify-between mb-3",
+                h3 { class: "text-gray-400 text-sm uppercase m-0", "Broadsheet" }
+                button {
+                    onclick: generate,
+                    disabled: *generating.read(),
+                    class: "px-2 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer disabled:opacity-50",
+                    if *generating.read() { "Drafting..." } else { "Draft issue" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if list.is_empty() {
+                div { class: "text-gray-500 italic text-xs", "No issues yet" }
+            } else {
+                div {
+                    class: "flex flex-col gap-2",
+                    for issue in list {
+                        BroadsheetRow {
+                            key: "{issue.id}",
+                            issue: issue.clone(),
+                            on_error: move |msg| error.set(Some(msg)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct BroadsheetRowProps {
+    issue: BroadsheetData,
+    on_error: EventHandler<String>,
+}
+
+/// A single issue with its controls
+#[component]
+fn BroadsheetRow(props: BroadsheetRowProps) -> Element {
+    let broadsheet_service = use_broadsheet_service();
+    let game_state = use_game_state();
+    let issue = props.issue.clone();
+    let mut editing = use_signal(|| false);
+    let mut confirm_publish = use_signal(|| false);
+
+    let publish = {
+        let service = broadsheet_service.clone();
+        let game_state = game_state.clone();
+        let broadsheet_id = issue.id.clone();
+        let on_error = props.on_error;
+        move |_| {
+            let mut game_state = game_state.clone();
+            let service = service.clone();
+            let broadsheet_id = broadsheet_id.clone();
+            confirm_publish.set(false);
+            spawn_task(async move {
+                match service.publish_broadsheet(&broadsheet_id).await {
+                    Ok(updated) => game_state.upsert_broadsheet(updated),
+                    Err(e) => on_error.call(format!("Failed to publish broadsheet: {}", e)),
+                }
+            });
+        }
+    };
+
+    let delete = {
+        let service = broadsheet_service.clone();
+        let game_state = game_state.clone();
+        let broadsheet_id = issue.id.clone();
+        let on_error = props.on_error;
+        move |_| {
+            let mut game_state = game_state.clone();
+            let service = service.clone();
+            let broadsheet_id = broadsheet_id.clone();
+            spawn_task(async move {
+                match service.delete_broadsheet(&broadsheet_id).await {
+                    Ok(_) => game_state.remove_broadsheet(&broadsheet_id),
+                    Err(e) => on_error.call(format!("Failed to remove broadsheet: {}", e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: if issue.published { "p-2 bg-dark-bg rounded opacity-60" } else { "p-2 bg-dark-bg rounded" },
+
+            div {
+                class: "flex items-center justify-between gap-2",
+                span { class: "text-white text-sm truncate", "#{issue.issue_number} {issue.title}" }
+                div {
+                    class: "flex items-center gap-1",
+                    if issue.published {
+                        span { class: "text-green-400 text-xs", "Published" }
+                    } else {
+                        button {
+                            onclick: move |_| {
+                                let open = *editing.read();
+                                editing.set(!open);
+                            },
+                            class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                            if *editing.read() { "Cancel" } else { "Edit" }
+                        }
+                        if *confirm_publish.read() {
+                            button {
+                                onclick: publish,
+                                class: "px-2 py-0.5 bg-red-700 text-white text-xs rounded cursor-pointer",
+                                "Hand to players"
+                            }
+                        } else {
+                            button {
+                                onclick: move |_| confirm_publish.set(true),
+                                class: "px-2 py-0.5 bg-amber-700 text-white text-xs rounded cursor-pointer",
+                                "Publish"
+                            }
+                        }
+                    }
+                    button {
+                        onclick: delete,
+                        class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                        "Remove"
+                    }
+                }
+            }
+
+            if *editing.read() {
+                BroadsheetForm {
+                    issue: issue.clone(),
+                    on_saved: move |_| editing.set(false),
+                    on_error: props.on_error,
+                }
+            } else {
+                div { class: "text-gray-400 text-xs mt-1", "{game_date(&issue.period_start)} – {game_date(&issue.period_end)}" }
+                for (i, article) in issue.articles.iter().enumerate() {
+                    div { key: "{i}", class: "text-gray-300 text-xs truncate", "{article.headline}" }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct BroadsheetFormProps {
+    issue: BroadsheetData,
+    on_saved: EventHandler<()>,
+    on_error: EventHandler<String>,
+}
+
+/// Inline form for editing a draft's title and articles
+#[component]
+fn BroadsheetForm(props: BroadsheetFormProps) -> Element {
+    let broadsheet_service = use_broadsheet_service();
+    let game_state = use_game_state();
+    let initial_title = props.issue.title.clone();
+    let initial_articles = props.issue.articles.clone();
+    let mut title = use_signal(move || initial_title);
+    let mut articles: Signal<Vec<BroadsheetArticleData>> = use_signal(move || initial_articles);
+
+    let handle_save = {
+        let service = broadsheet_service.clone();
+        let game_state = game_state.clone();
+        let broadsheet_id = props.issue.id.clone();
+        let on_saved = props.on_saved;
+        let on_error = props.on_error;
+        move |_| {
+            let mut game_state = game_state.clone();
+            let issue_title = title.read().trim().to_string();
+            if issue_title.is_empty() {
+                return;
+            }
+            let data = BroadsheetInputData {
+                title: issue_title,
+                articles: articles.read().clone(),
+            };
+            let service = service.clone();
+            let broadsheet_id = broadsheet_id.clone();
+            spawn_task(async move {
+                match service.update_broadsheet(&broadsheet_id, data).await {
+                    Ok(issue) => {
+                        game_state.upsert_broadsheet(issue);
+                        on_saved.call(());
+                    }
+                    Err(e) => on_error.call(format!("Failed to save broadsheet: {}", e)),
+                }
+            });
+        }
+    };
+
+    let count = articles.read().len();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 mt-2",
+
+            input {
+                r#type: "text",
+                value: "{title}",
+                placeholder: "The Harbour Crier",
+                oninput: move |e| title.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+
+            for i in 0..count {
+                div {
+                    key: "{i}",
+                    class: "flex flex-col gap-1 p-2 border border-gray-700 rounded",
+                    div {
+                        class: "flex items-center gap-1",
+                        input {
+                            r#type: "text",
+                            value: "{articles.read()[i].headline}",
+                            placeholder: "Headline",
+                            oninput: move |e| articles.write()[i].headline = e.value(),
+                            class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                        }
+                        button {
+                            onclick: move |_| {
+                                articles.write().remove(i);
+                            },
+                            class: "px-1 bg-transparent text-gray-400 border-none cursor-pointer",
+                            "×"
+                        }
+                    }
+                    textarea {
+                        value: "{articles.read()[i].body}",
+                        placeholder: "The story",
+                        rows: "3",
+                        oninput: move |e| articles.write()[i].body = e.value(),
+                        class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    }
+                }
+            }
+
+            div {
+                class: "flex justify-between",
+                button {
+                    onclick: move |_| {
+                        articles.write().push(BroadsheetArticleData {
+                            headline: String::new(),
+                            body: String::new(),
+                        });
+                    },
+                    class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                    "+ Article"
+                }
+                button {
+                    onclick: handle_save,
+                    class: "px-2 py-0.5 bg-blue-600 text-white text-xs rounded cursor-pointer",
+                    "Save"
+                }
+            }
+        }
+    }
+}
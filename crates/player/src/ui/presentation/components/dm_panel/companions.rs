@@ -0,0 +1,335 @@
+//! Companion Panel for DM
+//!
+//! Lists the NPCs travelling with a PC and provides controls for:
+//! - Adjusting a companion's loyalty
+//! - Telling a companion to follow the PC or stay put
+//! - Paying a companion's wages for the game days owed
+//! - Dismissing a companion
+//! - Hiring an NPC as a new companion
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{CompanionData, CompanionInputData};
+use crate::application::services::CharacterSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_character_service, use_companion_service};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CompanionPanelProps {
+    /// The world the PC belongs to, for the NPC picker
+    pub world_id: String,
+    /// The PC whose companions are shown
+    pub pc_id: String,
+}
+
+/// Companion Panel component for DM view
+#[component]
+pub fn CompanionPanel(props: CompanionPanelProps) -> Element {
+    let companion_service = use_companion_service();
+    let mut companions: Signal<Vec<CompanionData>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut show_hire_form = use_signal(|| false);
+
+    // Load companions on mount; wages owed are computed against game time
+    {
+        let pc_id = props.pc_id.clone();
+        let service = companion_service.clone();
+        use_effect(move || {
+            let pc_id = pc_id.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_companions(&pc_id).await {
+                    Ok(list) => companions.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load companions: {}", e))),
+                }
+            });
+        });
+    }
+
+    let list = companions.read().clone();
+
+    rsx! {
+        div {
+            class: "companion-panel mt-3",
+
+            div {
+                class: "flex items-center justify-between mb-2",
+                div { class: "text-gray-400 text-xs uppercase", "Companions" }
+                button {
+                    onclick: move |_| {
+                        let open = *show_hire_form.read();
+                        show_hire_form.set(!open);
+                    },
+                    class: "px-2 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                    if *show_hire_form.read() { "Cancel" } else { "+ Hire" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if *show_hire_form.read() {
+                HireCompanionForm {
+                    world_id: props.world_id.clone(),
+                    pc_id: props.pc_id.clone(),
+                    on_hired: move |companion: CompanionData| {
+                        companions.write().push(companion);
+                        show_hire_form.set(false);
+                    },
+                    on_error: move |msg| error.set(Some(msg)),
+                }
+            }
+
+            if list.is_empty() {
+                div { class: "text-gray-500 italic text-xs", "Travels alone" }
+            } else {
+                div {
+                    class: "flex flex-col gap-2",
+                    for companion in list {
+                        CompanionRow {
+                            key: "{companion.id}",
+                            companion: companion.clone(),
+                            on_changed: move |updated: CompanionData| {
+                                if let Some(slot) = companions.write().iter_mut().find(|c| c.id == updated.id) {
+                                    *slot = updated;
+                                }
+                            },
+                            on_dismissed: move |id: String| companions.write().retain(|c| c.id != id),
+                            on_error: move |msg| error.set(Some(msg)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct CompanionRowProps {
+    companion: CompanionData,
+    on_changed: EventHandler<CompanionData>,
+    on_dismissed: EventHandler<String>,
+    on_error: EventHandler<String>,
+}
+
+/// A single companion with their loyalty and wages
+#[component]
+fn CompanionRow(props: CompanionRowProps) -> Element {
+    let companion_service = use_companion_service();
+    let companion = props.companion.clone();
+
+    let update = {
+        let service = companion_service.clone();
+        let companion_id = companion.id.clone();
+        let on_changed = props.on_changed;
+        let on_error = props.on_error;
+        move |data: CompanionInputData| {
+            let service = service.clone();
+            let companion_id = companion_id.clone();
+            spawn_task(async move {
+                match service.update_companion(&companion_id, data).await {
+                    Ok(updated) => on_changed.call(updated),
+                    Err(e) => on_error.call(format!("Failed to update companion: {}", e)),
+                }
+            });
+        }
+    };
+
+    let pay = {
+        let service = companion_service.clone();
+        let companion_id = companion.id.clone();
+        let on_changed = props.on_changed;
+        let on_error = props.on_error;
+        move |_| {
+            let service = service.clone();
+            let companion_id = companion_id.clone();
+            spawn_task(async move {
+                match service.pay_wages(&companion_id).await {
+                    Ok(payment) => on_changed.call(payment.companion),
+                    Err(e) => on_error.call(format!("Failed to pay wages: {}", e)),
+                }
+            });
+        }
+    };
+
+    let dismiss = {
+        let service = companion_service.clone();
+        let companion_id = companion.id.clone();
+        let on_dismissed = props.on_dismissed;
+        let on_error = props.on_error;
+        move |_| {
+            let service = service.clone();
+            let companion_id = companion_id.clone();
+            spawn_task(async move {
+                match service.dismiss_companion(&companion_id).await {
+                    Ok(()) => on_dismissed.call(companion_id),
+                    Err(e) => on_error.call(format!("Failed to dismiss companion: {}", e)),
+                }
+            });
+        }
+    };
+
+    let name = companion
+        .name
+        .clone()
+        .unwrap_or_else(|| "Departed NPC".to_string());
+    let follows = companion.follows_pc;
+    let set_loyalty = update.clone();
+
+    rsx! {
+        div {
+            class: "p-2 bg-dark-surface rounded",
+
+            div {
+                class: "flex items-center justify-between gap-2",
+                span { class: "text-white text-sm truncate", "{name}" }
+                button {
+                    onclick: dismiss,
+                    class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                    "Dismiss"
+                }
+            }
+
+            div {
+                class: "flex items-center gap-2 mt-1 text-xs",
+                label { class: "text-gray-400", "Loyalty" }
+                input {
+                    r#type: "number",
+                    min: "0",
+                    max: "100",
+                    value: "{companion.loyalty}",
+                    onchange: move |e| {
+                        if let Ok(loyalty) = e.value().parse() {
+                            set_loyalty(CompanionInputData {
+                                loyalty: Some(loyalty),
+                                ..Default::default()
+                            });
+                        }
+                    },
+                    class: "w-14 p-0.5 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                }
+                label {
+                    class: "flex items-center gap-1 text-gray-400 ml-auto cursor-pointer",
+                    title: "Following companions move with the PC and are staged alongside them",
+                    input {
+                        r#type: "checkbox",
+                        checked: follows,
+                        onchange: move |_| update(CompanionInputData {
+                            follows_pc: Some(!follows),
+                            ..Default::default()
+                        }),
+                    }
+                    "Follows"
+                }
+            }
+
+            div {
+                class: "flex items-center gap-2 mt-1 text-xs",
+                span { class: "text-gray-400", "Wage {companion.wage_per_day}/day" }
+                if companion.wages_due > 0 {
+                    span {
+                        class: "text-amber-400",
+                        "{companion.wages_due} owed ({companion.days_owed} days)"
+                    }
+                    button {
+                        onclick: pay,
+                        class: "ml-auto px-2 py-0.5 bg-amber-600 text-white text-xs rounded cursor-pointer",
+                        "Pay"
+                    }
+                } else {
+                    span { class: "text-green-400", "Paid up" }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct HireCompanionFormProps {
+    world_id: String,
+    pc_id: String,
+    on_hired: EventHandler<CompanionData>,
+    on_error: EventHandler<String>,
+}
+
+/// Inline form for hiring an NPC as the PC's companion
+#[component]
+fn HireCompanionForm(props: HireCompanionFormProps) -> Element {
+    let companion_service = use_companion_service();
+    let character_service = use_character_service();
+    let mut npcs: Signal<Vec<CharacterSummary>> = use_signal(Vec::new);
+    let mut npc_id = use_signal(String::new);
+    let mut wage = use_signal(|| 0u32);
+
+    // Load the NPC picker on mount
+    {
+        let world_id = props.world_id.clone();
+        let on_error = props.on_error;
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let chars = character_service.clone();
+            spawn_task(async move {
+                match chars.list_characters(&world_id).await {
+                    Ok(list) => npcs.set(list),
+                    Err(e) => on_error.call(format!("Failed to load NPCs: {}", e)),
+                }
+            });
+        });
+    }
+
+    let handle_hire = move |_| {
+        let character_id = npc_id.read().clone();
+        if character_id.is_empty() {
+            return;
+        }
+        let data = CompanionInputData {
+            wage_per_day: Some(*wage.read()),
+            ..Default::default()
+        };
+        let service = companion_service.clone();
+        let pc_id = props.pc_id.clone();
+        let on_hired = props.on_hired;
+        let on_error = props.on_error;
+        spawn_task(async move {
+            match service.hire_companion(&pc_id, &character_id, data).await {
+                Ok(companion) => {
+                    npc_id.set(String::new());
+                    on_hired.call(companion);
+                }
+                Err(e) => on_error.call(format!("Failed to hire companion: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 mb-2 p-2 bg-dark-surface rounded",
+
+            select {
+                value: "{npc_id}",
+                onchange: move |e| npc_id.set(e.value()),
+                class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                option { value: "", "Choose NPC..." }
+                for npc in npcs.read().iter() {
+                    option { key: "{npc.id}", value: "{npc.id}", "{npc.name}" }
+                }
+            }
+            input {
+                r#type: "number",
+                min: "0",
+                value: "{wage}",
+                title: "Wage per game day",
+                oninput: move |e| wage.set(e.value().parse().unwrap_or(0)),
+                class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+            span { class: "text-gray-500 text-xs", "/day" }
+            button {
+                onclick: handle_hire,
+                disabled: npc_id.read().is_empty(),
+                class: "px-3 py-1 bg-amber-600 text-white text-sm rounded cursor-pointer",
+                "Hire"
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Connection lock editor for DM
+//!
+//! Locks a way between locations behind key items or a challenge. A PC
+//! carrying one of the keys can open it from the navigation panel, and a
+//! success at the linked challenge (lockpicking, forcing the door) opens it
+//! once the DM accepts the outcome. Clearing the lock leaves the way open.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{ChallengeData, ConnectionLockData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_challenge_service, use_location_service};
+
+/// Description, keys and linked challenge with Save and Unlock buttons
+#[component]
+pub fn ConnectionLockEditor(
+    world_id: String,
+    from_location_id: String,
+    to_location_id: String,
+    /// The saved lock, if the way is locked
+    lock: Option<ConnectionLockData>,
+    /// Called with the lock as saved, or `None` once unlocked
+    on_saved: EventHandler<Option<ConnectionLockData>>,
+) -> Element {
+    let loc_service = use_location_service();
+    let challenge_service = use_challenge_service();
+    let is_locked = lock.is_some();
+    let saved = lock.unwrap_or_default();
+    let mut description = use_signal(|| saved.description.clone().unwrap_or_default());
+    let mut keys = use_signal(|| saved.key_item_ids.join(", "));
+    let mut challenge_id = use_signal(|| saved.challenge_id.clone().unwrap_or_default());
+    let mut challenges: Signal<Vec<ChallengeData>> = use_signal(Vec::new);
+    let mut is_saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    use_effect(move || {
+        let world_id = world_id.clone();
+        let service = challenge_service.clone();
+        spawn_task(async move {
+            match service.list_challenges(&world_id).await {
+                Ok(list) => challenges.set(list),
+                Err(e) => tracing::warn!("Failed to load challenges for lock: {}", e),
+            }
+        });
+    });
+
+    let send = move |lock: Option<ConnectionLockData>| {
+        let from = from_location_id.clone();
+        let to = to_location_id.clone();
+        let service = loc_service.clone();
+        spawn_task(async move {
+            is_saving.set(true);
+            match service.set_connection_lock(&from, &to, lock.clone()).await {
+                Ok(()) => {
+                    error.set(None);
+                    on_saved.call(lock);
+                }
+                Err(e) => error.set(Some(format!("Failed to save lock: {}", e))),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let unlock = {
+        let send = send.clone();
+        move |_| send(None)
+    };
+
+    let save = move |_| {
+        let description = description.read().trim().to_string();
+        let challenge_id = challenge_id.read().clone();
+        send(Some(ConnectionLockData {
+            description: (!description.is_empty()).then_some(description),
+            key_item_ids: keys
+                .read()
+                .split(',')
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(str::to_string)
+                .collect(),
+            challenge_id: (!challenge_id.is_empty()).then_some(challenge_id),
+        }));
+    };
+
+    rsx! {
+        div {
+            class: "connection-lock-editor flex flex-col gap-2 mt-2",
+
+            input {
+                r#type: "text",
+                placeholder: "What players see (e.g. A barred iron gate)",
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                value: "{description}",
+                oninput: move |e| description.set(e.value()),
+            }
+            input {
+                r#type: "text",
+                placeholder: "Key item IDs, comma separated",
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                value: "{keys}",
+                oninput: move |e| keys.set(e.value()),
+            }
+            select {
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                aria_label: "Challenge that opens the lock",
+                value: "{challenge_id}",
+                onchange: move |e| challenge_id.set(e.value()),
+                option { value: "", "No challenge" }
+                for challenge in challenges.read().iter() {
+                    option { key: "{challenge.id}", value: "{challenge.id}", "{challenge.name}" }
+                }
+            }
+
+            div {
+                class: "flex justify-end gap-2",
+                if is_locked {
+                    button {
+                        onclick: unlock,
+                        disabled: *is_saving.read(),
+                        class: "px-3 py-1 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer disabled:opacity-50",
+                        "Unlock"
+                    }
+                }
+                button {
+                    onclick: save,
+                    disabled: *is_saving.read(),
+                    class: "px-3 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer disabled:opacity-50",
+                    if *is_saving.read() { "Saving..." } else { "Save lock" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{err}" }
+            }
+        }
+    }
+}
@@ -6,7 +6,11 @@ use crate::application::dto::ChallengeOutcomeDecision;
 use crate::infrastructure::websocket::ClientMessageBuilder;
 use crate::presentation::components::dm_panel::challenge_outcome_approval::ChallengeOutcomesSection;
 use crate::presentation::services::use_command_bus;
-use crate::presentation::state::use_session_state;
+use crate::presentation::state::{
+    group_pending_approvals, use_game_state, use_session_state, ApprovalGroup, ApprovalGroupKey,
+    PendingApproval,
+};
+use wrldbldr_protocol::{ApprovalSelection, BulkApprovalDecision};
 
 /// Compact decision queue view for Director mode
 #[component]
@@ -77,37 +81,14 @@ pub fn DecisionQueuePanel() -> Element {
                     }
                 }
 
-                // Pending approvals list
+                // Pending approvals, grouped by conversation
                 if has_pending && !*show_history_only.read() {
                     div {
                         class: "flex flex-col gap-1.5 mb-1",
-                        for approval in pending.iter() {
-                            div {
-                                key: "{approval.request_id}",
-                                class: "flex flex-col gap-0.5 py-1.5 px-2 bg-dark-bg rounded-md",
-
-                                div {
-                                    class: "flex justify-between items-center",
-                                    span { class: "text-white text-sm", "{approval.npc_name}" }
-                                    span { class: "text-amber-500 text-xs", "Pending" }
-                                }
-
-                                if let Some(challenge) = &approval.challenge_suggestion {
-                                    div {
-                                        class: "text-gray-400 text-xs",
-                                        "Challenge: {challenge.challenge_name}"
-                                    }
-                                } else if let Some(narrative) = &approval.narrative_event_suggestion {
-                                    div {
-                                        class: "text-gray-400 text-xs",
-                                        "Narrative: {narrative.event_name}"
-                                    }
-                                } else {
-                                    div {
-                                        class: "text-gray-400 text-xs overflow-hidden text-ellipsis whitespace-nowrap",
-                                        "{approval.proposed_dialogue}"
-                                    }
-                                }
+                        for group in group_pending_approvals(&pending) {
+                            ApprovalGroupCard {
+                                key: "{group.approvals[0].request_id}",
+                                group: group.clone(),
                             }
                         }
                     }
@@ -153,3 +134,131 @@ pub fn DecisionQueuePanel() -> Element {
         }
     }
 }
+
+/// A group of pending approvals, with bulk actions when there is more than one
+#[component]
+fn ApprovalGroupCard(group: ApprovalGroup) -> Element {
+    let command_bus = use_command_bus();
+
+    let count = group.approvals.len();
+    let (title, selection) = match &group.key {
+        ApprovalGroupKey::Conversation(conversation_id) => (
+            Some(format!("Conversation ({})", count)),
+            ApprovalSelection::Conversation {
+                conversation_id: conversation_id.clone(),
+            },
+        ),
+        ApprovalGroupKey::Ambient => (
+            Some(format!("Ambient ({})", count)),
+            ApprovalSelection::Ambient,
+        ),
+        ApprovalGroupKey::Single(request_id) => (
+            None,
+            ApprovalSelection::Requests {
+                request_ids: vec![request_id.clone()],
+            },
+        ),
+    };
+    let show_bulk = count > 1;
+
+    let send_bulk = move |decision: BulkApprovalDecision| {
+        let msg = ClientMessageBuilder::bulk_approval_decision(selection.clone(), decision);
+        if let Err(e) = command_bus.send(msg) {
+            tracing::error!("Failed to send bulk approval decision: {}", e);
+        }
+    };
+    let accept_all = send_bulk.clone();
+    let reject_all = send_bulk;
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+
+            if let Some(title) = title {
+                div {
+                    class: "flex justify-between items-center",
+                    span { class: "text-gray-500 text-xs uppercase", "{title}" }
+                    if show_bulk {
+                        div {
+                            class: "flex gap-1",
+                            button {
+                                class: "px-1.5 py-0.5 bg-green-700 text-white text-xs border-none rounded cursor-pointer",
+                                onclick: move |_| accept_all(BulkApprovalDecision::Accept),
+                                "Approve all"
+                            }
+                            button {
+                                class: "px-1.5 py-0.5 bg-red-700 text-white text-xs border-none rounded cursor-pointer",
+                                onclick: move |_| reject_all(BulkApprovalDecision::Reject {
+                                    feedback: "Rejected in bulk by the DM".to_string(),
+                                }),
+                                "Reject all"
+                            }
+                        }
+                    }
+                }
+            }
+
+            for approval in group.approvals.iter() {
+                PendingApprovalRow {
+                    key: "{approval.request_id}",
+                    approval: approval.clone(),
+                }
+            }
+        }
+    }
+}
+
+/// Compact summary of one pending approval
+#[component]
+fn PendingApprovalRow(approval: PendingApproval) -> Element {
+    let game_state = use_game_state();
+
+    // With the party split, say which group is waiting
+    let scene_name = approval.scene_id.as_ref().and_then(|id| {
+        game_state
+            .parallel_scenes
+            .read()
+            .iter()
+            .find(|scene| &scene.id == id)
+            .map(|scene| scene.name.clone())
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-0.5 py-1.5 px-2 bg-dark-bg rounded-md",
+
+            div {
+                class: "flex justify-between items-center",
+                div {
+                    class: "flex items-center gap-1.5",
+                    span { class: "text-white text-sm", "{approval.npc_name}" }
+                    if let Some(scene_name) = scene_name {
+                        span { class: "text-purple-300 text-xs", "{scene_name}" }
+                    }
+                }
+                if approval.safety_warnings.is_empty() {
+                    span { class: "text-amber-500 text-xs", "Pending" }
+                } else {
+                    span { class: "text-red-400 text-xs", "Flagged" }
+                }
+            }
+
+            if let Some(challenge) = &approval.challenge_suggestion {
+                div {
+                    class: "text-gray-400 text-xs",
+                    "Challenge: {challenge.challenge_name}"
+                }
+            } else if let Some(narrative) = &approval.narrative_event_suggestion {
+                div {
+                    class: "text-gray-400 text-xs",
+                    "Narrative: {narrative.event_name}"
+                }
+            } else {
+                div {
+                    class: "text-gray-400 text-xs overflow-hidden text-ellipsis whitespace-nowrap",
+                    "{approval.proposed_dialogue}"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Dependency Status Banner - Warns the DM when an Engine dependency is down
+
+use dioxus::prelude::*;
+
+use crate::presentation::state::use_session_state;
+
+/// Banner listing every unhealthy Engine dependency
+///
+/// Shown across all DM modes; hidden while everything is healthy. ComfyUI
+/// also has its own banner with a retry button in Creator mode.
+#[component]
+pub fn DependencyStatusBanner() -> Element {
+    let session_state = use_session_state();
+    let unhealthy: Vec<_> = session_state
+        .dependency_status()
+        .read()
+        .iter()
+        .filter(|s| !s.healthy)
+        .cloned()
+        .collect();
+
+    if unhealthy.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "dependency-status-banner bg-red-900/40 border-b border-red-500/50 px-4 py-2 flex flex-col gap-1",
+            role: "alert",
+
+            for status in unhealthy {
+                div {
+                    key: "{status.dependency}",
+                    class: "flex items-center gap-2 text-sm",
+                    span { class: "text-red-400", "●" }
+                    span { class: "text-red-200 font-medium", "{status.display_name()} unavailable" }
+                    if let Some(msg) = status.message.as_ref() {
+                        span { class: "text-red-300/80 text-xs truncate", "{msg}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -5,8 +5,10 @@
 
 use dioxus::prelude::*;
 
+use crate::application::dto::GenerationPreset;
 use crate::application::services::{Asset, GenerateRequest};
 use crate::infrastructure::spawn_task;
+use crate::presentation::components::creator::generation_preset_select::GenerationPresetSelect;
 use crate::presentation::services::{use_asset_service, use_settings_service};
 
 /// Props for DirectorGenerateModal
@@ -37,6 +39,8 @@ pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
     let mut negative_prompt = use_signal(String::new);
     let mut count = use_signal(|| 4u8);
     let mut workflow_slot = use_signal(String::new);
+    let preset: Signal<Option<String>> = use_signal(|| None);
+    let mut presets: Signal<Vec<GenerationPreset>> = use_signal(Vec::new);
     let mut is_generating = use_signal(|| false);
     let mut style_reference_id: Signal<Option<String>> = use_signal(|| None);
     let mut style_reference_label: Signal<Option<String>> = use_signal(|| None);
@@ -59,6 +63,7 @@ pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
         spawn_task(async move {
             // First fetch world settings to get default style reference
             let world_default_ref = if let Ok(settings) = settings_svc.get_for_world(&wid).await {
+                presets.set(settings.generation_presets);
                 settings.style_reference_asset_id
             } else {
                 None
@@ -119,6 +124,12 @@ pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
                     }
                 }
 
+                GenerationPresetSelect {
+                    presets: presets.read().clone(),
+                    asset_type: props.asset_type.clone(),
+                    selected: preset,
+                }
+
                 // Style Reference field
                 div { class: "mb-4",
                     label { class: "block text-gray-400 text-sm mb-1", "Style Reference (optional)" }
@@ -283,6 +294,7 @@ pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
                                     },
                                     count: *count.read(),
                                     style_reference_id: style_reference_id.read().clone(),
+                                    preset: preset.read().clone(),
                                 };
                                 let svc_clone = svc.clone();
                                 spawn_task(async move {
@@ -0,0 +1,313 @@
+//! Flashback Panel for DM
+//!
+//! Shows the running flashback, if any, and provides controls for:
+//! - Starting a flashback: its title, region, date, and who was there
+//! - Returning to the present, which restores the live clock and the
+//!   region's staging and look
+//!
+//! Story events recorded during a flashback are tagged "flashback".
+
+use dioxus::prelude::*;
+use wrldbldr_protocol::{ApprovedNpcInfo, RegionListItemData};
+
+use crate::application::dto::StartFlashbackData;
+use crate::application::services::character_service::CharacterSummary;
+use crate::application::services::location_service::LocationSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{
+    use_character_service, use_flashback_service, use_location_service,
+};
+use crate::presentation::state::use_game_state;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FlashbackPanelProps {
+    pub world_id: String,
+}
+
+/// Flashback Panel component for DM view
+#[component]
+pub fn FlashbackPanel(props: FlashbackPanelProps) -> Element {
+    let flashback_service = use_flashback_service();
+    let game_state = use_game_state();
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut busy = use_signal(|| false);
+
+    // Load the running flashback on mount
+    {
+        let service = flashback_service.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let mut game_state = game_state.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.get_flashback().await {
+                    Ok(flashback) => game_state.set_flashback(flashback),
+                    Err(e) => error.set(Some(format!("Failed to load flashback: {}", e))),
+                }
+            });
+        });
+    }
+
+    // The engine broadcasts FlashbackChanged, which updates the game state
+    let end = {
+        let service = flashback_service.clone();
+        move |_| {
+            let service = service.clone();
+            busy.set(true);
+            error.set(None);
+            spawn_task(async move {
+                if let Err(e) = service.end_flashback().await {
+                    error.set(Some(format!("Failed to end flashback: {}", e)));
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let flashback = game_state.flashback.read().clone();
+
+    rsx! {
+        div {
+            class: "flashback-panel bg-dark-surface rounded-lg p-4",
+
+            h3 { class: "text-gray-400 text-sm uppercase m-0 mb-3", "Flashback" }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if let Some(flashback) = flashback {
+                div {
+                    class: "flex flex-col gap-2",
+                    div { class: "text-amber-300 text-sm font-semibold", "{flashback.title}" }
+                    div {
+                        class: "text-gray-400 text-xs",
+                        "Day {flashback.game_time.day}, {flashback.game_time.hour:02}:00 · the present is day {flashback.live_time.day}"
+                    }
+                    button {
+                        onclick: end,
+                        disabled: *busy.read(),
+                        class: "px-2 py-1 bg-amber-700 text-white text-xs rounded cursor-pointer disabled:opacity-50",
+                        "Return to the present"
+                    }
+                }
+            } else {
+                FlashbackForm {
+                    world_id: props.world_id.clone(),
+                    on_error: move |msg| error.set(Some(msg)),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct FlashbackFormProps {
+    world_id: String,
+    on_error: EventHandler<String>,
+}
+
+/// Where, when and with whom to start a flashback
+#[component]
+fn FlashbackForm(props: FlashbackFormProps) -> Element {
+    let flashback_service = use_flashback_service();
+    let location_service = use_location_service();
+    let character_service = use_character_service();
+
+    let mut title = use_signal(String::new);
+    let mut location_id = use_signal(String::new);
+    let mut region_id = use_signal(String::new);
+    let mut years_back = use_signal(|| 0u32);
+    let mut day = use_signal(|| 1u32);
+    let mut hour = use_signal(|| 12u8);
+    let mut present: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut locations: Signal<Vec<LocationSummary>> = use_signal(Vec::new);
+    let mut regions: Signal<Vec<RegionListItemData>> = use_signal(Vec::new);
+    let mut npcs: Signal<Vec<CharacterSummary>> = use_signal(Vec::new);
+    let mut starting = use_signal(|| false);
+
+    // Load locations and NPCs on mount
+    {
+        let locations_svc = location_service.clone();
+        let characters_svc = character_service.clone();
+        let world_id = props.world_id.clone();
+        let on_error = props.on_error;
+        use_effect(move || {
+            let locations_svc = locations_svc.clone();
+            let characters_svc = characters_svc.clone();
+            let world_id = world_id.clone();
+            spawn_task(async move {
+                match locations_svc.list_locations(&world_id).await {
+                    Ok(list) => locations.set(list),
+                    Err(e) => on_error.call(format!("Failed to load locations: {}", e)),
+                }
+                match characters_svc.list_characters(&world_id).await {
+                    Ok(list) => npcs.set(list),
+                    Err(e) => on_error.call(format!("Failed to load NPCs: {}", e)),
+                }
+            });
+        });
+    }
+
+    let pick_location = {
+        let service = location_service.clone();
+        let on_error = props.on_error;
+        move |e: Event<FormData>| {
+            let id = e.value();
+            location_id.set(id.clone());
+            region_id.set(String::new());
+            regions.set(Vec::new());
+            if id.is_empty() {
+                return;
+            }
+            let service = service.clone();
+            spawn_task(async move {
+                match service.get_regions(&id).await {
+                    Ok(list) => regions.set(list),
+                    Err(e) => on_error.call(format!("Failed to load regions: {}", e)),
+                }
+            });
+        }
+    };
+
+    let start = {
+        let service = flashback_service.clone();
+        let on_error = props.on_error;
+        move |_| {
+            let data = StartFlashbackData {
+                title: title.read().trim().to_string(),
+                region_id: region_id.read().clone(),
+                years_back: *years_back.read(),
+                day: *day.read(),
+                hour: *hour.read(),
+                npcs: present
+                    .read()
+                    .iter()
+                    .map(|id| ApprovedNpcInfo {
+                        character_id: id.clone(),
+                        is_present: true,
+                        reasoning: None,
+                        is_hidden_from_players: false,
+                        mood: None,
+                    })
+                    .collect(),
+                region_state_id: None,
+            };
+            let service = service.clone();
+            starting.set(true);
+            spawn_task(async move {
+                match service.start_flashback(data).await {
+                    Ok(_) => {
+                        title.set(String::new());
+                        present.set(Vec::new());
+                    }
+                    Err(e) => on_error.call(format!("Failed to start flashback: {}", e)),
+                }
+                starting.set(false);
+            });
+        }
+    };
+
+    let can_start = !title.read().trim().is_empty() && !region_id.read().is_empty();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+
+            input {
+                r#type: "text",
+                value: "{title}",
+                placeholder: "The night of the fire",
+                oninput: move |e| title.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+
+            select {
+                value: "{location_id}",
+                onchange: pick_location,
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                option { value: "", "Location" }
+                for location in locations.read().iter() {
+                    option { key: "{location.id}", value: "{location.id}", "{location.name}" }
+                }
+            }
+
+            if !regions.read().is_empty() {
+                select {
+                    value: "{region_id}",
+                    onchange: move |e| region_id.set(e.value()),
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    option { value: "", "Region" }
+                    for region in regions.read().iter() {
+                        option { key: "{region.id}", value: "{region.id}", "{region.name}" }
+                    }
+                }
+            }
+
+            div {
+                class: "flex gap-2 items-center text-gray-400 text-xs",
+                label { "Years ago" }
+                input {
+                    r#type: "number",
+                    min: "0",
+                    value: "{years_back}",
+                    oninput: move |e| years_back.set(e.value().parse().unwrap_or(0)),
+                    class: "w-14 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                }
+                label { "Day" }
+                input {
+                    r#type: "number",
+                    min: "1",
+                    max: "366",
+                    value: "{day}",
+                    oninput: move |e| day.set(e.value().parse().unwrap_or(1)),
+                    class: "w-14 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                }
+                label { "Hour" }
+                input {
+                    r#type: "number",
+                    min: "0",
+                    max: "23",
+                    value: "{hour}",
+                    oninput: move |e| hour.set(e.value().parse().unwrap_or(12)),
+                    class: "w-12 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                }
+            }
+
+            if !npcs.read().is_empty() {
+                div {
+                    class: "flex flex-col gap-1 max-h-32 overflow-y-auto",
+                    div { class: "text-gray-500 text-xs", "Who was there" }
+                    for npc in npcs.read().iter() {
+                        label {
+                            key: "{npc.id}",
+                            class: "flex items-center gap-1 text-white text-xs",
+                            input {
+                                r#type: "checkbox",
+                                checked: present.read().contains(&npc.id),
+                                onchange: {
+                                    let id = npc.id.clone();
+                                    move |e: Event<FormData>| {
+                                        let mut list = present.write();
+                                        list.retain(|p| p != &id);
+                                        if e.checked() {
+                                            list.push(id.clone());
+                                        }
+                                    }
+                                },
+                            }
+                            "{npc.name}"
+                        }
+                    }
+                }
+            }
+
+            button {
+                onclick: start,
+                disabled: !can_start || *starting.read(),
+                class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer disabled:opacity-50",
+                if *starting.read() { "Starting..." } else { "Start flashback" }
+            }
+        }
+    }
+}
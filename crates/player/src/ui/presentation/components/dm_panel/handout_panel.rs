@@ -0,0 +1,141 @@
+//! Handout Panel for DM
+//!
+//! Puts an image (a revealed map, a letter, cutscene art) full screen for
+//! every player, optionally closing it after a set time, and lets the DM
+//! close it for everyone.
+
+use dioxus::prelude::*;
+
+use crate::infrastructure::websocket::ClientMessageBuilder;
+use crate::presentation::services::use_command_bus;
+use crate::presentation::state::use_game_state;
+
+/// Auto-close choices in seconds (0 = until dismissed)
+const DURATION_OPTIONS: [u32; 5] = [0, 10, 30, 60, 120];
+
+/// Handout Panel component for DM view
+#[component]
+pub fn HandoutPanel() -> Element {
+    let game_state = use_game_state();
+    let command_bus = use_command_bus();
+
+    let mut asset = use_signal(String::new);
+    let mut caption = use_signal(String::new);
+    let mut duration_secs = use_signal(|| 0u32);
+
+    let showing = game_state.overlay.read().clone();
+
+    let handle_show = {
+        let command_bus = command_bus.clone();
+        move |_| {
+            let asset_value = asset.read().trim().to_string();
+            if asset_value.is_empty() {
+                return;
+            }
+            let caption_value = caption.read().trim().to_string();
+            let duration = *duration_secs.read();
+            let msg = ClientMessageBuilder::show_overlay(
+                &asset_value,
+                (!caption_value.is_empty()).then_some(caption_value.as_str()),
+                (duration > 0).then_some(duration),
+            );
+            if let Err(e) = command_bus.send(msg) {
+                tracing::error!("Failed to show overlay: {}", e);
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "handout-panel bg-dark-surface rounded-lg p-4",
+
+            div {
+                class: "flex items-center justify-between mb-3",
+                h3 { class: "text-gray-400 text-sm uppercase m-0", "Handouts" }
+                if showing.is_some() {
+                    span {
+                        class: "text-amber-400 text-xs px-2 py-1 bg-amber-500/20 rounded",
+                        "ON SCREEN"
+                    }
+                }
+            }
+
+            if let Some(overlay) = showing {
+                div {
+                    class: "mb-3 p-2 bg-dark-bg rounded flex items-center gap-3",
+                    img {
+                        src: "{overlay.asset}",
+                        alt: "",
+                        class: "w-12 h-12 object-cover rounded",
+                    }
+                    span {
+                        class: "flex-1 text-gray-300 text-xs truncate",
+                        "{overlay.caption.clone().unwrap_or_else(|| overlay.asset.clone())}"
+                    }
+                    button {
+                        onclick: {
+                            let command_bus = command_bus.clone();
+                            let overlay_id = overlay.overlay_id.clone();
+                            move |_| {
+                                let msg = ClientMessageBuilder::dismiss_overlay(&overlay_id);
+                                if let Err(e) = command_bus.send(msg) {
+                                    tracing::error!("Failed to dismiss overlay: {}", e);
+                                }
+                            }
+                        },
+                        class: "px-2 py-1 bg-red-700 hover:bg-red-600 text-white text-xs rounded cursor-pointer",
+                        "Dismiss for everyone"
+                    }
+                }
+            }
+
+            div {
+                class: "flex flex-col gap-2",
+
+                input {
+                    r#type: "text",
+                    value: "{asset}",
+                    placeholder: "Image URL",
+                    oninput: move |e| asset.set(e.value()),
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+
+                input {
+                    r#type: "text",
+                    value: "{caption}",
+                    placeholder: "Caption (optional)",
+                    oninput: move |e| caption.set(e.value()),
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+
+                select {
+                    value: "{duration_secs}",
+                    onchange: move |e| {
+                        if let Ok(n) = e.value().parse() {
+                            duration_secs.set(n);
+                        }
+                    },
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    for secs in DURATION_OPTIONS {
+                        option {
+                            key: "{secs}",
+                            value: "{secs}",
+                            if secs == 0 {
+                                "Until dismissed"
+                            } else {
+                                "Close after {secs}s"
+                            }
+                        }
+                    }
+                }
+
+                button {
+                    onclick: handle_show,
+                    disabled: asset.read().trim().is_empty(),
+                    class: "px-3 py-1.5 bg-amber-600 hover:bg-amber-500 text-white text-sm rounded cursor-pointer",
+                    "Show to Players"
+                }
+            }
+        }
+    }
+}
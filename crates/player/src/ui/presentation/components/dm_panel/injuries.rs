@@ -0,0 +1,399 @@
+//! Injury Panel for DM
+//!
+//! Lists the injuries a PC carries and provides controls for:
+//! - Inflicting a new injury with a severity and hampered capability
+//! - Recording the outcome of an injury's treatment
+//! - Removing an injury
+//! - Showing injuries that have already healed
+//!
+//! Injuries heal by themselves as game time advances.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{
+    ChallengeData, InjuryCapabilityData, InjuryData, InjuryInputData, InjurySeverityData,
+};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_challenge_service, use_injury_service};
+
+const SEVERITIES: [(InjurySeverityData, &str); 3] = [
+    (InjurySeverityData::Minor, "minor"),
+    (InjurySeverityData::Serious, "serious"),
+    (InjurySeverityData::Critical, "critical"),
+];
+
+const CAPABILITIES: [(InjuryCapabilityData, &str); 6] = [
+    (InjuryCapabilityData::Mobility, "mobility"),
+    (InjuryCapabilityData::Manipulation, "manipulation"),
+    (InjuryCapabilityData::Senses, "senses"),
+    (InjuryCapabilityData::Speech, "speech"),
+    (InjuryCapabilityData::Stamina, "stamina"),
+    (InjuryCapabilityData::Mind, "mind"),
+];
+
+fn severity_label(severity: InjurySeverityData) -> &'static str {
+    SEVERITIES
+        .iter()
+        .find(|(s, _)| *s == severity)
+        .map(|(_, label)| *label)
+        .unwrap_or("unknown")
+}
+
+fn capability_label(capability: InjuryCapabilityData) -> &'static str {
+    CAPABILITIES
+        .iter()
+        .find(|(c, _)| *c == capability)
+        .map(|(_, label)| *label)
+        .unwrap_or("unknown")
+}
+
+/// Game hours as days and hours: "3d 4h"
+fn format_hours(hours: u32) -> String {
+    match (hours / 24, hours % 24) {
+        (0, h) => format!("{}h", h),
+        (d, 0) => format!("{}d", d),
+        (d, h) => format!("{}d {}h", d, h),
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct InjuryPanelProps {
+    /// The world the PC belongs to, for the treatment challenge picker
+    pub world_id: String,
+    /// The PC whose injuries are shown
+    pub pc_id: String,
+}
+
+/// Injury Panel component for DM view
+#[component]
+pub fn InjuryPanel(props: InjuryPanelProps) -> Element {
+    let injury_service = use_injury_service();
+    let mut injuries: Signal<Vec<InjuryData>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut show_form = use_signal(|| false);
+    let mut show_healed = use_signal(|| false);
+
+    // Reload whenever the healed filter changes; recovery is computed
+    // against game time
+    {
+        let pc_id = props.pc_id.clone();
+        let service = injury_service.clone();
+        use_effect(move || {
+            let include_healed = *show_healed.read();
+            let pc_id = pc_id.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_injuries(&pc_id, include_healed).await {
+                    Ok(list) => injuries.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load injuries: {}", e))),
+                }
+            });
+        });
+    }
+
+    let list = injuries.read().clone();
+
+    rsx! {
+        div {
+            class: "injury-panel mt-3",
+
+            div {
+                class: "flex items-center justify-between mb-2",
+                div { class: "text-gray-400 text-xs uppercase", "Injuries" }
+                div {
+                    class: "flex items-center gap-2",
+                    label {
+                        class: "flex items-center gap-1 text-gray-400 text-xs cursor-pointer",
+                        input {
+                            r#type: "checkbox",
+                            checked: *show_healed.read(),
+                            onchange: move |_| {
+                                let shown = *show_healed.read();
+                                show_healed.set(!shown);
+                            },
+                        }
+                        "Healed"
+                    }
+                    button {
+                        onclick: move |_| {
+                            let open = *show_form.read();
+                            show_form.set(!open);
+                        },
+                        class: "px-2 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                        if *show_form.read() { "Cancel" } else { "+ Injure" }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if *show_form.read() {
+                InflictInjuryForm {
+                    world_id: props.world_id.clone(),
+                    pc_id: props.pc_id.clone(),
+                    on_inflicted: move |injury: InjuryData| {
+                        injuries.write().push(injury);
+                        show_form.set(false);
+                    },
+                    on_error: move |msg| error.set(Some(msg)),
+                }
+            }
+
+            if list.is_empty() {
+                div { class: "text-gray-500 italic text-xs", "Unhurt" }
+            } else {
+                div {
+                    class: "flex flex-col gap-2",
+                    for injury in list {
+                        InjuryRow {
+                            key: "{injury.id}",
+                            injury: injury.clone(),
+                            on_changed: move |updated: InjuryData| {
+                                if let Some(slot) = injuries.write().iter_mut().find(|i| i.id == updated.id) {
+                                    *slot = updated;
+                                }
+                            },
+                            on_deleted: move |id: String| injuries.write().retain(|i| i.id != id),
+                            on_error: move |msg| error.set(Some(msg)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct InjuryRowProps {
+    injury: InjuryData,
+    on_changed: EventHandler<InjuryData>,
+    on_deleted: EventHandler<String>,
+    on_error: EventHandler<String>,
+}
+
+/// A single injury with its recovery and treatment
+#[component]
+fn InjuryRow(props: InjuryRowProps) -> Element {
+    let injury_service = use_injury_service();
+    let injury = props.injury.clone();
+
+    let treat = {
+        let service = injury_service.clone();
+        let injury_id = injury.id.clone();
+        let on_changed = props.on_changed;
+        let on_error = props.on_error;
+        move |succeeded: bool| {
+            let service = service.clone();
+            let injury_id = injury_id.clone();
+            spawn_task(async move {
+                match service.treat_injury(&injury_id, succeeded).await {
+                    Ok(updated) => on_changed.call(updated),
+                    Err(e) => on_error.call(format!("Failed to treat injury: {}", e)),
+                }
+            });
+        }
+    };
+
+    let delete = {
+        let service = injury_service.clone();
+        let injury_id = injury.id.clone();
+        let on_deleted = props.on_deleted;
+        let on_error = props.on_error;
+        move |_| {
+            let service = service.clone();
+            let injury_id = injury_id.clone();
+            spawn_task(async move {
+                match service.delete_injury(&injury_id).await {
+                    Ok(()) => on_deleted.call(injury_id),
+                    Err(e) => on_error.call(format!("Failed to remove injury: {}", e)),
+                }
+            });
+        }
+    };
+
+    let healed = injury.healed_at.is_some();
+    let severity = severity_label(injury.severity);
+    let capability = capability_label(injury.capability);
+    let remaining = format_hours(injury.remaining_hours);
+    let treat_failed = treat.clone();
+    let severity_class = match injury.severity {
+        InjurySeverityData::Critical => "text-red-400",
+        InjurySeverityData::Serious => "text-amber-400",
+        _ => "text-gray-400",
+    };
+
+    rsx! {
+        div {
+            class: if healed { "p-2 bg-dark-surface rounded opacity-60" } else { "p-2 bg-dark-surface rounded" },
+
+            div {
+                class: "flex items-center justify-between gap-2",
+                span { class: "text-white text-sm truncate", "{injury.name}" }
+                button {
+                    onclick: delete,
+                    class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                    "Remove"
+                }
+            }
+
+            div {
+                class: "flex items-center gap-2 mt-1 text-xs",
+                span { class: "{severity_class}", "{severity}" }
+                span { class: "text-gray-400", "hampers {capability}" }
+                if healed {
+                    span { class: "ml-auto text-green-400", "Healed" }
+                } else {
+                    span { class: "ml-auto text-gray-400", "{remaining} to heal" }
+                }
+            }
+
+            if !healed {
+                div {
+                    class: "flex items-center gap-2 mt-1 text-xs",
+                    if let Some(challenge) = injury.treatment_challenge_name.as_ref() {
+                        span { class: "text-gray-400 truncate", "Treat with {challenge}" }
+                    }
+                    if injury.treated {
+                        span { class: "ml-auto text-gray-500 italic", "Treated" }
+                    } else {
+                        button {
+                            onclick: move |_| treat(true),
+                            class: "ml-auto px-2 py-0.5 bg-green-700 text-white text-xs rounded cursor-pointer",
+                            "Treated"
+                        }
+                        button {
+                            onclick: move |_| treat_failed(false),
+                            class: "px-2 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                            "Treatment failed"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct InflictInjuryFormProps {
+    world_id: String,
+    pc_id: String,
+    on_inflicted: EventHandler<InjuryData>,
+    on_error: EventHandler<String>,
+}
+
+/// Inline form for injuring the PC
+#[component]
+fn InflictInjuryForm(props: InflictInjuryFormProps) -> Element {
+    let injury_service = use_injury_service();
+    let challenge_service = use_challenge_service();
+    let mut challenges: Signal<Vec<ChallengeData>> = use_signal(Vec::new);
+    let mut name = use_signal(String::new);
+    let mut severity = use_signal(|| InjurySeverityData::Minor);
+    let mut capability = use_signal(|| InjuryCapabilityData::Stamina);
+    let mut challenge_id = use_signal(String::new);
+
+    // Load the treatment challenge picker on mount
+    {
+        let world_id = props.world_id.clone();
+        let on_error = props.on_error;
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let service = challenge_service.clone();
+            spawn_task(async move {
+                match service.list_challenges(&world_id).await {
+                    Ok(list) => challenges.set(list),
+                    Err(e) => on_error.call(format!("Failed to load challenges: {}", e)),
+                }
+            });
+        });
+    }
+
+    let handle_inflict = move |_| {
+        let injury_name = name.read().trim().to_string();
+        if injury_name.is_empty() {
+            return;
+        }
+        let treatment = challenge_id.read().clone();
+        let data = InjuryInputData {
+            name: Some(injury_name),
+            severity: Some(*severity.read()),
+            capability: Some(*capability.read()),
+            treatment_challenge_id: (!treatment.is_empty()).then_some(treatment),
+            ..Default::default()
+        };
+        let service = injury_service.clone();
+        let pc_id = props.pc_id.clone();
+        let on_inflicted = props.on_inflicted;
+        let on_error = props.on_error;
+        spawn_task(async move {
+            match service.inflict_injury(&pc_id, data).await {
+                Ok(injury) => {
+                    name.set(String::new());
+                    on_inflicted.call(injury);
+                }
+                Err(e) => on_error.call(format!("Failed to inflict injury: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 mb-2 p-2 bg-dark-surface rounded",
+
+            input {
+                r#type: "text",
+                value: "{name}",
+                placeholder: "Cracked ribs",
+                oninput: move |e| name.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+            div {
+                class: "flex items-center gap-2",
+                select {
+                    value: "{severity_label(*severity.read())}",
+                    onchange: move |e| {
+                        if let Some((s, _)) = SEVERITIES.iter().find(|(_, label)| *label == e.value()) {
+                            severity.set(*s);
+                        }
+                    },
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    for (_, label) in SEVERITIES {
+                        option { key: "{label}", value: "{label}", "{label}" }
+                    }
+                }
+                select {
+                    value: "{capability_label(*capability.read())}",
+                    onchange: move |e| {
+                        if let Some((c, _)) = CAPABILITIES.iter().find(|(_, label)| *label == e.value()) {
+                            capability.set(*c);
+                        }
+                    },
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    for (_, label) in CAPABILITIES {
+                        option { key: "{label}", value: "{label}", "{label}" }
+                    }
+                }
+            }
+            div {
+                class: "flex items-center gap-2",
+                select {
+                    value: "{challenge_id}",
+                    onchange: move |e| challenge_id.set(e.value()),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    option { value: "", "No treatment challenge" }
+                    for challenge in challenges.read().iter() {
+                        option { key: "{challenge.id}", value: "{challenge.id}", "{challenge.name}" }
+                    }
+                }
+                button {
+                    onclick: handle_inflict,
+                    disabled: name.read().trim().is_empty(),
+                    class: "px-3 py-1 bg-red-700 text-white text-sm rounded cursor-pointer",
+                    "Injure"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Journey Approval Panel for DM
+//!
+//! Lists fast-travel journeys stopped by an encounter. The DM plays the
+//! encounter out, then lets the journey go on (rolling for the rest of the
+//! road), waves the PC straight through to the destination, or turns them
+//! back to where they set out from.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{JourneyData, JourneyDecision};
+use crate::infrastructure::websocket::ClientMessageBuilder;
+use crate::presentation::components::creator::location_hierarchy::format_minutes;
+use crate::presentation::services::use_command_bus;
+use crate::presentation::state::use_game_state;
+
+/// Journey Approval Panel component for DM view; hidden while no journey
+/// is waiting
+#[component]
+pub fn JourneyApprovalPanel() -> Element {
+    let game_state = use_game_state();
+    let journeys = game_state.stopped_journeys.read().clone();
+
+    if journeys.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "journey-approval-panel bg-dark-surface rounded-lg p-4",
+
+            h3 {
+                class: "text-amber-400 text-sm uppercase mt-0 mb-3",
+                "Stopped Journeys ({journeys.len()})"
+            }
+
+            div {
+                class: "flex flex-col gap-3",
+                for journey in journeys {
+                    StoppedJourneyCard { key: "{journey.journey_id}", journey }
+                }
+            }
+        }
+    }
+}
+
+/// One stopped journey with the DM's choices
+#[component]
+fn StoppedJourneyCard(journey: JourneyData) -> Element {
+    let command_bus = use_command_bus();
+
+    let resolve = move |decision: JourneyDecision| {
+        let msg = ClientMessageBuilder::resolve_journey(&journey.journey_id, decision);
+        if let Err(e) = command_bus.send(msg) {
+            tracing::error!("Failed to resolve journey: {}", e);
+        }
+    };
+    let resolve_continue = resolve.clone();
+    let resolve_arrive = resolve.clone();
+    let resolve_back = resolve;
+
+    rsx! {
+        div {
+            class: "p-3 bg-amber-900/30 border border-amber-700 rounded",
+
+            div {
+                class: "text-gray-200 text-sm",
+                "{journey.pc_name}: {journey.from_location_name} → {journey.to_location_name}"
+            }
+            div {
+                class: "text-gray-500 text-xs mt-1",
+                "{format_minutes(journey.elapsed_minutes)} of {format_minutes(journey.total_minutes)} travelled"
+            }
+            if let Some(encounter) = journey.encounter.as_ref() {
+                div {
+                    class: "mt-2",
+                    span { class: "text-amber-300 text-sm font-medium", "{encounter.name}" }
+                    if !encounter.description.is_empty() {
+                        p { class: "text-gray-300 text-xs mt-1 mb-0", "{encounter.description}" }
+                    }
+                }
+            }
+
+            div {
+                class: "flex gap-2 mt-3",
+                button {
+                    onclick: move |_| resolve_continue(JourneyDecision::Continue),
+                    class: "px-3 py-1 bg-blue-600 text-white text-xs rounded cursor-pointer",
+                    title: "Go on, rolling for the rest of the road",
+                    "Continue"
+                }
+                button {
+                    onclick: move |_| resolve_arrive(JourneyDecision::Arrive),
+                    class: "px-3 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                    title: "Arrive without further encounters",
+                    "Arrive"
+                }
+                button {
+                    onclick: move |_| resolve_back(JourneyDecision::TurnBack),
+                    class: "px-3 py-1 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                    "Turn back"
+                }
+            }
+        }
+    }
+}
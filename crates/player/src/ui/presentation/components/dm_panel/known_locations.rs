@@ -0,0 +1,195 @@
+//! Known Locations Panel for DM
+//!
+//! Lists the places a PC has visited or heard about, which are the places
+//! they can find on the world map and travel to. The DM can tell the PC
+//! about another location or make them forget one.
+
+use dioxus::prelude::*;
+
+use crate::application::services::location_service::LocationSummary;
+use crate::application::services::KnownLocationSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_location_service, use_observation_service};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct KnownLocationsPanelProps {
+    /// The world the PC belongs to, for the location picker
+    pub world_id: String,
+    /// The PC whose knowledge is shown
+    pub pc_id: String,
+}
+
+/// Known Locations Panel component for DM view
+#[component]
+pub fn KnownLocationsPanel(props: KnownLocationsPanelProps) -> Element {
+    let observation_service = use_observation_service();
+    let location_service = use_location_service();
+    let mut known: Signal<Vec<KnownLocationSummary>> = use_signal(Vec::new);
+    let mut locations: Signal<Vec<LocationSummary>> = use_signal(Vec::new);
+    let mut reveal_id = use_signal(String::new);
+    let mut reveal_notes = use_signal(String::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let reload = {
+        let service = observation_service.clone();
+        let pc_id = props.pc_id.clone();
+        move || {
+            let service = service.clone();
+            let pc_id = pc_id.clone();
+            spawn_task(async move {
+                match service.list_known_locations(&pc_id).await {
+                    Ok(list) => known.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load known locations: {}", e))),
+                }
+            });
+        }
+    };
+
+    {
+        let reload = reload.clone();
+        let world_id = props.world_id.clone();
+        use_effect(move || {
+            reload();
+            let service = location_service.clone();
+            let world_id = world_id.clone();
+            spawn_task(async move {
+                match service.list_locations(&world_id).await {
+                    Ok(list) => locations.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load locations: {}", e))),
+                }
+            });
+        });
+    }
+
+    let reveal = {
+        let service = observation_service.clone();
+        let pc_id = props.pc_id.clone();
+        let reload = reload.clone();
+        move |_| {
+            let location_id = reveal_id.read().clone();
+            if location_id.is_empty() {
+                return;
+            }
+            let notes = reveal_notes.read().trim().to_string();
+            let service = service.clone();
+            let pc_id = pc_id.clone();
+            let reload = reload.clone();
+            spawn_task(async move {
+                error.set(None);
+                match service
+                    .reveal_location(&pc_id, &location_id, (!notes.is_empty()).then_some(notes))
+                    .await
+                {
+                    Ok(()) => {
+                        reveal_id.set(String::new());
+                        reveal_notes.set(String::new());
+                        reload();
+                    }
+                    Err(e) => error.set(Some(format!("Failed to reveal location: {}", e))),
+                }
+            });
+        }
+    };
+
+    let forget = {
+        let service = observation_service.clone();
+        let pc_id = props.pc_id.clone();
+        move |location_id: String| {
+            let service = service.clone();
+            let pc_id = pc_id.clone();
+            spawn_task(async move {
+                error.set(None);
+                match service.forget_location(&pc_id, &location_id).await {
+                    Ok(()) => known.write().retain(|k| k.location_id != location_id),
+                    Err(e) => error.set(Some(format!("Failed to forget location: {}", e))),
+                }
+            });
+        }
+    };
+
+    let list = known.read().clone();
+    let unknown: Vec<LocationSummary> = locations
+        .read()
+        .iter()
+        .filter(|l| !list.iter().any(|k| k.location_id == l.id))
+        .cloned()
+        .collect();
+
+    rsx! {
+        div {
+            class: "known-locations-panel mt-3",
+
+            div { class: "text-gray-400 text-xs uppercase mb-2", "Known Locations" }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            div {
+                class: "flex flex-col gap-1",
+                for k in list {
+                    div {
+                        key: "{k.location_id}",
+                        class: "flex items-center justify-between gap-2 text-sm",
+                        div {
+                            class: "flex-1 min-w-0",
+                            span { class: "text-white", "{k.name}" }
+                            span {
+                                class: "ml-2 text-gray-500 text-xs",
+                                if k.is_visited() { "visited" } else { "heard about" }
+                            }
+                            if let Some(notes) = k.notes.as_ref() {
+                                div { class: "text-gray-500 text-xs truncate", "{notes}" }
+                            }
+                        }
+                        // Where the PC stands is always known
+                        if k.game_time.is_some() {
+                            button {
+                                onclick: {
+                                    let forget = forget.clone();
+                                    let location_id = k.location_id.clone();
+                                    move |_| forget(location_id.clone())
+                                },
+                                class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs rounded border border-gray-700 cursor-pointer hover:text-red-400",
+                                "Forget"
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !unknown.is_empty() {
+                div {
+                    class: "flex flex-col gap-1 mt-2",
+                    select {
+                        value: "{reveal_id}",
+                        onchange: move |e| reveal_id.set(e.value()),
+                        class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+
+                        option { value: "", "Tell them about a location..." }
+                        for loc in unknown.iter() {
+                            option { key: "{loc.id}", value: "{loc.id}", "{loc.name}" }
+                        }
+                    }
+                    if !reveal_id.read().is_empty() {
+                        div {
+                            class: "flex gap-1",
+                            input {
+                                r#type: "text",
+                                value: "{reveal_notes}",
+                                placeholder: "How they heard (optional)",
+                                oninput: move |e| reveal_notes.set(e.value()),
+                                class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                            }
+                            button {
+                                onclick: reveal,
+                                class: "px-2 py-0.5 bg-blue-600 text-white text-xs rounded cursor-pointer",
+                                "Reveal"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -2,11 +2,20 @@
 
 use dioxus::prelude::*;
 
+use crate::application::dto::ConnectionLockData;
 use crate::application::services::location_service::{ConnectionData, LocationFormData};
 use crate::infrastructure::spawn_task;
-use wrldbldr_protocol::RegionListItemData;
-
-use crate::presentation::services::use_location_service;
+use crate::infrastructure::websocket::ClientMessageBuilder;
+use crate::presentation::components::dm_panel::connection_lock::ConnectionLockEditor;
+use crate::presentation::components::dm_panel::region_sound::RegionSoundEditor;
+use crate::presentation::components::map_markers::MarkerLayer;
+use crate::presentation::components::noise_maker::NoiseMaker;
+use crate::presentation::components::region_hotspots::HotspotEditor;
+use crate::presentation::state::use_game_state;
+use wrldbldr_protocol::types::{HotspotTargetData, LightLevelData, LoudnessData, RegionMapKindData};
+use wrldbldr_protocol::{MarkerLinkData, RegionListItemData};
+
+use crate::presentation::services::{use_command_bus, use_location_service};
 
 #[derive(Props, Clone, PartialEq)]
 pub struct LocationPreviewModalProps {
@@ -109,6 +118,8 @@ pub fn LocationPreviewModal(props: LocationPreviewModalProps) -> Element {
                         }
                     } else if let Some(loc) = location.read().as_ref() {
                         LocationContent {
+                            world_id: props.world_id.clone(),
+                            location_id: props.location_id.clone(),
                             location: loc.clone(),
                             regions: regions.read().clone(),
                             connections: connections.read().clone(),
@@ -122,6 +133,8 @@ pub fn LocationPreviewModal(props: LocationPreviewModalProps) -> Element {
 
 #[derive(Props, Clone, PartialEq)]
 struct LocationContentProps {
+    world_id: String,
+    location_id: String,
     location: LocationFormData,
     regions: Vec<RegionListItemData>,
     connections: Vec<ConnectionData>,
@@ -215,12 +228,22 @@ fn LocationContent(props: LocationContentProps) -> Element {
                             RegionRow {
                                 key: "{region.id}",
                                 region: region.clone(),
+                                regions: props.regions.clone(),
                             }
                         }
                     }
                 }
             }
 
+            // Location map and region backdrops, with pinned DM markers
+            MarkerMapSection {
+                world_id: props.world_id.clone(),
+                location_id: props.location_id.clone(),
+                map_asset: loc.map_asset.clone(),
+                regions: props.regions.clone(),
+                connections: props.connections.clone(),
+            }
+
             // Connections section
             if !props.connections.is_empty() {
                 div {
@@ -238,6 +261,7 @@ fn LocationContent(props: LocationContentProps) -> Element {
                         for connection in props.connections.iter() {
                             ConnectionRow {
                                 key: "{connection.to_location_id}",
+                                world_id: props.world_id.clone(),
                                 connection: connection.clone(),
                             }
                         }
@@ -266,14 +290,259 @@ fn LocationContent(props: LocationContentProps) -> Element {
     }
 }
 
+#[derive(Props, Clone, PartialEq)]
+struct MarkerMapSectionProps {
+    world_id: String,
+    location_id: String,
+    map_asset: Option<String>,
+    regions: Vec<RegionListItemData>,
+    connections: Vec<ConnectionData>,
+}
+
+/// One map at a time (location map or a region backdrop) for placing markers
+///
+/// Region backdrops can also switch to outlining clickable hotspots.
+#[component]
+fn MarkerMapSection(props: MarkerMapSectionProps) -> Element {
+    // Region whose backdrop is shown; None is the location map
+    let mut shown_region: Signal<Option<String>> = use_signal(|| None);
+    let mut editing_hotspots = use_signal(|| false);
+    let game_state = use_game_state();
+
+    // (region_id, label, image) for each map that has an image
+    let maps: Vec<(Option<String>, String, String)> = props
+        .map_asset
+        .iter()
+        .map(|map| (None, "Location map".to_string(), map.clone()))
+        .chain(props.regions.iter().filter_map(|r| {
+            r.backdrop_asset
+                .as_ref()
+                .map(|image| (Some(r.id.clone()), r.name.clone(), image.clone()))
+        }))
+        .collect();
+    let Some(current) = maps
+        .iter()
+        .find(|(id, _, _)| *id == *shown_region.read())
+        .or_else(|| maps.first())
+        .cloned()
+    else {
+        return rsx! {};
+    };
+    let (current_region, _, image) = current;
+
+    let link_options: Vec<(MarkerLinkData, String)> = props
+        .regions
+        .iter()
+        .map(|r| (MarkerLinkData::Region(r.id.clone()), r.name.clone()))
+        .collect();
+    let region_maps: Vec<String> = maps.iter().filter_map(|(id, _, _)| id.clone()).collect();
+    let hotspot_region = current_region.clone().filter(|_| *editing_hotspots.read());
+
+    // Active scene interactions (and the items they target), other regions
+    // to move to, and connected locations to leave for
+    let scene_interactions = game_state.interactions.read().clone();
+    let interaction_targets = scene_interactions.iter().flat_map(|i| {
+        let item = i
+            .target_id
+            .clone()
+            .filter(|_| {
+                i.target_type
+                    .as_deref()
+                    .is_some_and(|t| t.eq_ignore_ascii_case("item"))
+            })
+            .map(|item_id| {
+                let name = i.target_name.clone().unwrap_or_else(|| i.name.clone());
+                (
+                    HotspotTargetData::Item { item_id },
+                    format!("Examine {}", name),
+                )
+            });
+        std::iter::once((
+            HotspotTargetData::Interaction {
+                interaction_id: i.id.clone(),
+            },
+            format!("Interaction: {}", i.name),
+        ))
+        .chain(item)
+    });
+    let hotspot_targets: Vec<(HotspotTargetData, String)> = interaction_targets
+        .chain(
+            props
+                .regions
+                .iter()
+                .filter(|r| Some(&r.id) != current_region.as_ref())
+                .map(|r| {
+                    (
+                        HotspotTargetData::Exit {
+                            region_id: r.id.clone(),
+                        },
+                        format!("Move to {}", r.name),
+                    )
+                }),
+        )
+        .chain(props.connections.iter().map(|c| {
+            let other = if c.from_location_id == props.location_id {
+                c.to_location_id.clone()
+            } else {
+                c.from_location_id.clone()
+            };
+            let label = if c.description.is_empty() {
+                "Leave for connected location".to_string()
+            } else {
+                format!("Leave: {}", c.description)
+            };
+            (
+                HotspotTargetData::LocationExit {
+                    location_id: other,
+                    arrival_region_id: None,
+                },
+                label,
+            )
+        }))
+        .collect();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-3",
+
+            div {
+                class: "flex items-center justify-between gap-2",
+                h4 { class: "m-0 text-gray-400 text-sm uppercase", "Map Markers" }
+
+                if current_region.is_some() {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| {
+                            let current = *editing_hotspots.read();
+                            editing_hotspots.set(!current);
+                        },
+                        class: "ml-auto px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer border-0",
+                        if *editing_hotspots.read() { "Back to markers" } else { "Edit hotspots" }
+                    }
+                }
+
+                if maps.len() > 1 {
+                    select {
+                        aria_label: "Map",
+                        value: "{current_region.clone().unwrap_or_default()}",
+                        onchange: move |e| {
+                            let value = e.value();
+                            shown_region.set(if value.is_empty() { None } else { Some(value) });
+                        },
+                        class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        for (id, label, _) in maps.iter() {
+                            option {
+                                key: "{id.clone().unwrap_or_default()}",
+                                value: "{id.clone().unwrap_or_default()}",
+                                "{label}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "relative w-full rounded-lg overflow-hidden bg-gray-800",
+                img {
+                    src: "{image}",
+                    class: "block w-full h-auto",
+                    alt: "Map",
+                }
+                if let Some(region_id) = hotspot_region {
+                    HotspotEditor {
+                        region_id,
+                        target_options: hotspot_targets,
+                    }
+                } else {
+                    MarkerLayer {
+                        world_id: props.world_id.clone(),
+                        location_id: props.location_id.clone(),
+                        region_id: current_region,
+                        editable: true,
+                        link_options,
+                        on_open: move |link: MarkerLinkData| {
+                            // Region links jump to that region's backdrop
+                            if let MarkerLinkData::Region(id) = link {
+                                if region_maps.contains(&id) {
+                                    shown_region.set(Some(id));
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 struct RegionRowProps {
     region: RegionListItemData,
+    /// Every region of the location, to name where connections lead
+    regions: Vec<RegionListItemData>,
 }
 
 #[component]
 fn RegionRow(props: RegionRowProps) -> Element {
+    let location_service = use_location_service();
+    let mut map_status: Signal<Option<String>> = use_signal(|| None);
+    let mut lighting = use_signal(|| props.region.lighting);
+    let mut lighting_error: Signal<Option<String>> = use_signal(|| None);
+    let mut editing_sound = use_signal(|| false);
+    let command_bus = use_command_bus();
     let region = &props.region;
+    let region_id = region.id.clone();
+
+    let make_noise = {
+        let region_id = region_id.clone();
+        move |(description, loudness): (String, LoudnessData)| {
+            let msg = ClientMessageBuilder::make_noise(&region_id, &description, loudness);
+            if let Err(e) = command_bus.send(msg) {
+                tracing::error!("Failed to make noise: {}", e);
+            }
+        }
+    };
+
+    let set_lighting = {
+        let svc = location_service.clone();
+        let region_id = region_id.clone();
+        move |e: Event<FormData>| {
+            let level = match e.value().as_str() {
+                "bright" => Some(LightLevelData::Bright),
+                "dim" => Some(LightLevelData::Dim),
+                "dark" => Some(LightLevelData::Dark),
+                _ => None,
+            };
+            let svc = svc.clone();
+            let region_id = region_id.clone();
+            spawn_task(async move {
+                match svc.set_region_lighting(&region_id, level).await {
+                    Ok(()) => {
+                        lighting.set(level);
+                        lighting_error.set(None);
+                    }
+                    Err(e) => lighting_error.set(Some(format!("Failed to set lighting: {}", e))),
+                }
+            });
+        }
+    };
+
+    // Maps take a while; the result arrives as RegionMapGenerated
+    let generate = move |kind: RegionMapKindData| {
+        let svc = location_service.clone();
+        let region_id = region_id.clone();
+        map_status.set(Some("Starting...".to_string()));
+        spawn_task(async move {
+            match svc.generate_region_map(&region_id, kind, None).await {
+                Ok(()) => map_status.set(Some(
+                    "Generating - the map appears in the scene when ready".to_string(),
+                )),
+                Err(e) => map_status.set(Some(format!("Failed to start: {}", e))),
+            }
+        });
+    };
+    let mut generate_battle_map = generate.clone();
+    let mut generate_layout = generate;
 
     rsx! {
         div {
@@ -306,6 +575,76 @@ fn RegionRow(props: RegionRowProps) -> Element {
                         "{region.description}"
                     }
                 }
+
+                div {
+                    class: "flex items-center gap-2 mt-2 text-xs",
+
+                    if let Some(ref map) = region.map_asset {
+                        a {
+                            href: "{map}",
+                            target: "_blank",
+                            class: "text-blue-400",
+                            "View map"
+                        }
+                    }
+                    button {
+                        r#type: "button",
+                        onclick: move |_| generate_battle_map(RegionMapKindData::BattleMap),
+                        class: "px-2 py-1 bg-gray-700 text-white rounded cursor-pointer border-0",
+                        "Generate Battle Map"
+                    }
+                    button {
+                        r#type: "button",
+                        onclick: move |_| generate_layout(RegionMapKindData::RegionLayout),
+                        class: "px-2 py-1 bg-gray-700 text-white rounded cursor-pointer border-0",
+                        "Generate Layout"
+                    }
+                    if let Some(status) = map_status.read().as_ref() {
+                        span { class: "text-gray-400", "{status}" }
+                    }
+                }
+
+                div {
+                    class: "flex items-center gap-2 mt-2 text-xs",
+                    select {
+                        aria_label: "Lighting",
+                        value: match *lighting.read() {
+                            Some(LightLevelData::Bright) => "bright",
+                            Some(LightLevelData::Dim) => "dim",
+                            Some(LightLevelData::Dark) => "dark",
+                            _ => "",
+                        },
+                        onchange: set_lighting,
+                        class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        option { value: "", "Natural light" }
+                        option { value: "bright", "Bright" }
+                        option { value: "dim", "Dim" }
+                        option { value: "dark", "Dark" }
+                    }
+                    if let Some(err) = lighting_error.read().as_ref() {
+                        span { class: "text-red-400", "{err}" }
+                    }
+                    button {
+                        r#type: "button",
+                        onclick: move |_| {
+                            let open = *editing_sound.read();
+                            editing_sound.set(!open);
+                        },
+                        class: "px-2 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer",
+                        "Sound"
+                    }
+                }
+
+                if *editing_sound.read() {
+                    RegionSoundEditor {
+                        region_id: region.id.clone(),
+                        regions: props.regions.clone(),
+                    }
+                    div {
+                        class: "mt-2",
+                        NoiseMaker { on_make_noise: make_noise }
+                    }
+                }
             }
         }
     }
@@ -313,44 +652,82 @@ fn RegionRow(props: RegionRowProps) -> Element {
 
 #[derive(Props, Clone, PartialEq)]
 struct ConnectionRowProps {
+    world_id: String,
     connection: ConnectionData,
 }
 
 #[component]
 fn ConnectionRow(props: ConnectionRowProps) -> Element {
     let conn = &props.connection;
+    let mut lock: Signal<Option<ConnectionLockData>> = use_signal(|| {
+        conn.is_locked.then(|| ConnectionLockData {
+            description: conn.lock_description.clone(),
+            key_item_ids: conn.key_item_ids.clone(),
+            challenge_id: conn.lock_challenge_id.clone(),
+        })
+    });
+    let mut editing_lock = use_signal(|| false);
+    let is_locked = lock.read().is_some();
 
     rsx! {
         div {
-            class: "bg-dark-bg rounded-lg p-3 flex items-center gap-3",
-
-            span {
-                class: "text-blue-400",
-                if conn.bidirectional { "" } else { "" }
-            }
+            class: "bg-dark-bg rounded-lg p-3",
 
             div {
-                class: "flex-1",
+                class: "flex items-center gap-3",
 
                 span {
-                    class: "text-white",
-                    // Note: We only have the ID, not the name. In a real implementation,
-                    // we'd either include the name in ConnectionData or fetch it separately.
-                    "Location: {conn.to_location_id}"
+                    class: "text-blue-400",
+                    if conn.bidirectional { "" } else { "" }
                 }
 
-                if !conn.description.is_empty() {
+                div {
+                    class: "flex-1",
+
                     span {
-                        class: "text-gray-400 text-sm ml-2",
-                        "- {conn.description}"
+                        class: "text-white",
+                        // Note: We only have the ID, not the name. In a real implementation,
+                        // we'd either include the name in ConnectionData or fetch it separately.
+                        "Location: {conn.to_location_id}"
+                    }
+
+                    if !conn.description.is_empty() {
+                        span {
+                            class: "text-gray-400 text-sm ml-2",
+                            "- {conn.description}"
+                        }
                     }
                 }
+
+                if let Some(ref conn_type) = conn.connection_type {
+                    span {
+                        class: "text-xs bg-gray-700 text-gray-300 px-2 py-0.5 rounded",
+                        "{conn_type}"
+                    }
+                }
+
+                button {
+                    class: "px-2 py-0.5 bg-transparent text-xs border border-gray-700 rounded cursor-pointer",
+                    class: if is_locked { "text-amber-400" } else { "text-gray-500" },
+                    title: if is_locked { "Edit lock" } else { "Lock this way" },
+                    onclick: move |_| {
+                        let open = *editing_lock.read();
+                        editing_lock.set(!open);
+                    },
+                    if is_locked { "🔒 Locked" } else { "Lock" }
+                }
             }
 
-            if let Some(ref conn_type) = conn.connection_type {
-                span {
-                    class: "text-xs bg-gray-700 text-gray-300 px-2 py-0.5 rounded",
-                    "{conn_type}"
+            if *editing_lock.read() {
+                ConnectionLockEditor {
+                    world_id: props.world_id.clone(),
+                    from_location_id: conn.from_location_id.clone(),
+                    to_location_id: conn.to_location_id.clone(),
+                    lock: lock.read().clone(),
+                    on_saved: move |saved: Option<ConnectionLockData>| {
+                        lock.set(saved);
+                        editing_lock.set(false);
+                    },
                 }
             }
         }
@@ -2,12 +2,24 @@
 
 use dioxus::prelude::*;
 
+use crate::application::dto::MentionTargetData;
+use crate::presentation::components::common::MentionText;
+
 /// Dynamic log entry that accepts String values
 #[derive(Props, Clone, PartialEq)]
 pub struct DynamicLogEntryProps {
     pub speaker: String,
     pub text: String,
     pub is_system: bool,
+    /// Names mentioned in the text that aren't characters yet
+    #[props(default)]
+    pub mentioned_names: Vec<String>,
+    /// Called with a mentioned name to promote it to an NPC draft
+    #[props(default)]
+    pub on_promote: Option<EventHandler<String>>,
+    /// Known entities to link in the text
+    #[props(default)]
+    pub mention_targets: Vec<MentionTargetData>,
 }
 
 #[component]
@@ -19,7 +31,23 @@ pub fn DynamicLogEntry(props: DynamicLogEntryProps) -> Element {
             if !props.is_system {
                 span { class: "text-blue-500 font-bold", "{props.speaker}: " }
             }
-            span { "{props.text}" }
+            MentionText { text: props.text.clone(), targets: props.mention_targets.clone() }
+            if let Some(on_promote) = props.on_promote {
+                if !props.mentioned_names.is_empty() {
+                    div {
+                        class: "flex flex-wrap gap-1 mt-1",
+                        for name in props.mentioned_names.iter().cloned() {
+                            button {
+                                key: "{name}",
+                                title: "Promote to an NPC draft",
+                                onclick: move |_| on_promote.call(name.clone()),
+                                class: "px-2 py-0.5 bg-purple-900 text-purple-200 text-xs rounded cursor-pointer",
+                                "+ {name}"
+                            }
+                        }
+                    }
+                }
+            }
         }
     }
 }
@@ -2,41 +2,80 @@
 //!
 //! Provides reusable components for the DM view including scene preview,
 //! directorial notes, NPC motivation tracking, LLM response approval,
-//! staging approval, challenge management, and time controls.
+//! staging approval, challenge management, time controls, progress clocks,
+//! PC property, companions, injuries, secrets and deaths, safety signals,
+//! stopped journeys, connection locks, region sound, handouts, the
+//! world's broadsheet, and what the world did during time skips.
 
 pub mod adhoc_challenge_modal;
 pub mod approval_popup;
+pub mod broadsheet;
 pub mod challenge_library;
 pub mod challenge_outcome_approval;
 pub mod character_perspective;
+pub mod companions;
+pub mod connection_lock;
 pub mod conversation_log;
 pub mod decision_queue;
+pub mod dependency_status_banner;
 pub mod director_generate_modal;
 pub mod director_queue_panel;
 pub mod directorial_notes;
+pub mod flashback;
+pub mod handout_panel;
+pub mod injuries;
+pub mod journey_approval;
+pub mod known_locations;
 pub mod location_navigator;
 pub mod location_preview_modal;
 pub mod location_staging;
 pub mod log_entry;
+pub mod mortality;
 pub mod npc_disposition_panel;
 pub mod npc_motivation;
+pub mod pacing;
+pub mod parallel_scenes;
 pub mod pc_management;
+pub mod progress_clocks;
+pub mod properties;
+pub mod region_sound;
+pub mod safety_alert;
 pub mod scene_preview;
+pub mod secrets;
 pub mod split_party_banner;
+pub mod spotlight;
 pub mod staging_approval;
 pub mod time_control;
 pub mod tone_selector;
 pub mod trigger_challenge_modal;
+pub mod world_simulation;
 
 // Re-export key types for external use
+pub use broadsheet::BroadsheetPanel;
 pub use challenge_outcome_approval::{ChallengeOutcomeApprovalCard, ChallengeOutcomesSection};
+pub use companions::CompanionPanel;
 pub use conversation_log::{ChallengeResultInfo, ConversationLog, ConversationTurn};
+pub use dependency_status_banner::DependencyStatusBanner;
+pub use flashback::FlashbackPanel;
+pub use handout_panel::HandoutPanel;
+pub use injuries::InjuryPanel;
+pub use journey_approval::JourneyApprovalPanel;
+pub use known_locations::KnownLocationsPanel;
 pub use location_preview_modal::LocationPreviewModal;
 pub use location_staging::{LocationStagingPanel, RegionStagingInfo, StagingStatus};
+pub use mortality::MortalityPanel;
 pub use npc_disposition_panel::{
     DispositionChangeEvent, NpcDispositionListPanel, NpcDispositionPanel, RelationshipChangeEvent,
     SceneNpcInfo, DISPOSITION_OPTIONS, RELATIONSHIP_OPTIONS,
 };
+pub use pacing::PacingPanel;
+pub use parallel_scenes::ParallelScenesPanel;
+pub use progress_clocks::ProgressClockPanel;
+pub use properties::PropertyPanel;
+pub use safety_alert::SafetyAlertPanel;
+pub use secrets::SecretPanel;
 pub use split_party_banner::SplitPartyBanner;
+pub use spotlight::SpotlightPanel;
 pub use staging_approval::{StagingApprovalPopup, StagingApprovalResult, StagingRegenerateRequest};
 pub use time_control::TimeControlPanel;
+pub use world_simulation::WorldSimulationPanel;
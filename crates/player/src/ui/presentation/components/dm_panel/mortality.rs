@@ -0,0 +1,209 @@
+//! Mortality Panel for DM
+//!
+//! Records a PC's death and provides controls for:
+//! - Setting the cause of death and an epitaph for the memorial
+//! - Resurrecting the PC when the world's rule system allows it
+//!
+//! A fallen PC can no longer act; their player carries on through a
+//! successor once they create one.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{PcDeathData, PcDeathInputData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_mortality_service;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MortalityPanelProps {
+    /// The PC whose death is recorded
+    pub pc_id: String,
+    /// Whether the PC was alive when the list was loaded
+    pub is_alive: bool,
+}
+
+/// Mortality Panel component for DM view
+#[component]
+pub fn MortalityPanel(props: MortalityPanelProps) -> Element {
+    let mortality_service = use_mortality_service();
+    let mut is_alive = use_signal(|| props.is_alive);
+    let mut death: Signal<Option<PcDeathData>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut show_form = use_signal(|| false);
+
+    // Load the memorial for a PC who has already fallen
+    {
+        let pc_id = props.pc_id.clone();
+        let service = mortality_service.clone();
+        use_effect(move || {
+            if *is_alive.read() {
+                return;
+            }
+            let pc_id = pc_id.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.get_death(&pc_id).await {
+                    Ok(data) => death.set(Some(data)),
+                    Err(e) => error.set(Some(format!("Failed to load death: {}", e))),
+                }
+            });
+        });
+    }
+
+    let resurrect = {
+        let service = mortality_service.clone();
+        let pc_id = props.pc_id.clone();
+        move |_| {
+            let service = service.clone();
+            let pc_id = pc_id.clone();
+            spawn_task(async move {
+                match service.resurrect_character(&pc_id).await {
+                    Ok(()) => {
+                        death.set(None);
+                        is_alive.set(true);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to resurrect: {}", e))),
+                }
+            });
+        }
+    };
+
+    let current = death.read().clone();
+
+    rsx! {
+        div {
+            class: "mortality-panel mt-3",
+
+            div {
+                class: "flex items-center justify-between mb-2",
+                div { class: "text-gray-400 text-xs uppercase", "Mortality" }
+                if *is_alive.read() {
+                    button {
+                        onclick: move |_| {
+                            let open = *show_form.read();
+                            show_form.set(!open);
+                        },
+                        class: "px-2 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                        if *show_form.read() { "Cancel" } else { "Record death" }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if *is_alive.read() {
+                if *show_form.read() {
+                    RecordDeathForm {
+                        pc_id: props.pc_id.clone(),
+                        on_recorded: move |data: PcDeathData| {
+                            death.set(Some(data));
+                            is_alive.set(false);
+                            show_form.set(false);
+                        },
+                        on_error: move |msg| error.set(Some(msg)),
+                    }
+                } else {
+                    div { class: "text-gray-500 italic text-xs", "Alive" }
+                }
+            } else if let Some(fallen) = current {
+                div {
+                    class: "p-2 bg-dark-surface rounded",
+                    div { class: "text-white text-sm", "Fell: {fallen.cause}" }
+                    if let Some(epitaph) = fallen.epitaph.as_ref() {
+                        div { class: "text-gray-400 text-xs italic mt-1", "\"{epitaph}\"" }
+                    }
+                    div {
+                        class: "flex items-center gap-2 mt-2 text-xs",
+                        if fallen.successor_id.is_some() {
+                            span { class: "text-gray-500 italic", "Succeeded by a new character" }
+                        } else if let Some(reason) = fallen.resurrection_blocked.as_ref() {
+                            span { class: "text-gray-500 italic", "{reason}" }
+                        } else {
+                            if fallen.resurrection_cost > 0 {
+                                span {
+                                    class: "text-gray-400",
+                                    {
+                                        match fallen.currency.as_ref() {
+                                            Some(currency) => format!("Costs {} {}", fallen.resurrection_cost, currency),
+                                            None => format!("Costs {}", fallen.resurrection_cost),
+                                        }
+                                    }
+                                }
+                            }
+                            button {
+                                onclick: resurrect,
+                                class: "ml-auto px-2 py-0.5 bg-green-700 text-white text-xs rounded cursor-pointer",
+                                "Resurrect"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct RecordDeathFormProps {
+    pc_id: String,
+    on_recorded: EventHandler<PcDeathData>,
+    on_error: EventHandler<String>,
+}
+
+/// Inline form for recording the PC's death
+#[component]
+fn RecordDeathForm(props: RecordDeathFormProps) -> Element {
+    let mortality_service = use_mortality_service();
+    let mut cause = use_signal(String::new);
+    let mut epitaph = use_signal(String::new);
+
+    let handle_record = move |_| {
+        let cause_val = cause.read().trim().to_string();
+        if cause_val.is_empty() {
+            return;
+        }
+        let epitaph_val = epitaph.read().trim().to_string();
+        let data = PcDeathInputData {
+            cause: cause_val,
+            epitaph: (!epitaph_val.is_empty()).then_some(epitaph_val),
+        };
+        let service = mortality_service.clone();
+        let pc_id = props.pc_id.clone();
+        let on_recorded = props.on_recorded;
+        let on_error = props.on_error;
+        spawn_task(async move {
+            match service.kill_character(&pc_id, data).await {
+                Ok(death) => on_recorded.call(death),
+                Err(e) => on_error.call(format!("Failed to record death: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 mb-2 p-2 bg-dark-surface rounded",
+
+            input {
+                r#type: "text",
+                value: "{cause}",
+                placeholder: "Slain by the ogre chieftain",
+                oninput: move |e| cause.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+            textarea {
+                value: "{epitaph}",
+                placeholder: "Epitaph (optional)",
+                rows: 2,
+                oninput: move |e| epitaph.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm resize-y",
+            }
+            button {
+                onclick: handle_record,
+                disabled: cause.read().trim().is_empty(),
+                class: "self-end px-3 py-1 bg-red-700 text-white text-sm rounded cursor-pointer",
+                "Record death"
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Pacing Panel for DM
+//!
+//! Soft real-time limits for convention slots and online sessions:
+//! - Start a scene timer, or a turn timer that starts over whenever a
+//!   player acts
+//! - Pause, resume, add time or stop it
+//!
+//! The whole table sees the countdown (`PacingTimerBadge`). Nothing stops
+//! when time runs out; the timer only cues.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{PacingCueData, PacingTimerKindData, StartPacingTimerData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::components::pacing_timer::PacingTimerBadge;
+use crate::presentation::services::use_pacing_service;
+use crate::presentation::state::use_game_state;
+use crate::use_platform;
+
+/// Minutes the "add time" button gives
+const EXTEND_MINUTES: u32 = 5;
+
+/// Pacing Panel component for DM view
+#[component]
+pub fn PacingPanel() -> Element {
+    let service = use_pacing_service();
+    let platform = use_platform();
+    let game_state = use_game_state();
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let mut kind = use_signal(|| PacingTimerKindData::Scene);
+    let mut minutes = use_signal(|| 30u32);
+    let mut warning_minutes = use_signal(|| 5u32);
+    let mut label = use_signal(String::new);
+
+    // Load the timer on mount
+    {
+        let service = service.clone();
+        let platform = platform.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let mut game_state = game_state.clone();
+            let service = service.clone();
+            let platform = platform.clone();
+            spawn_task(async move {
+                match service.get_timer().await {
+                    Ok(timer) => game_state.set_pacing_timer(timer, platform.now_millis()),
+                    Err(e) => error.set(Some(format!("Failed to load the timer: {}", e))),
+                }
+            });
+        });
+    }
+
+    // The engine broadcasts PacingTimerChanged, which updates the game state
+    let start = {
+        let service = service.clone();
+        move |_| {
+            let data = StartPacingTimerData {
+                kind: *kind.read(),
+                minutes: *minutes.read(),
+                warning_minutes: *warning_minutes.read(),
+                label: Some(label.read().trim().to_string()).filter(|l| !l.is_empty()),
+            };
+            let service = service.clone();
+            error.set(None);
+            spawn_task(async move {
+                if let Err(e) = service.start(data).await {
+                    error.set(Some(format!("Failed to start the timer: {}", e)));
+                }
+            });
+        }
+    };
+
+    let mut pause = {
+        let service = service.clone();
+        move |paused: bool| {
+            let service = service.clone();
+            error.set(None);
+            spawn_task(async move {
+                let result = if paused {
+                    service.resume().await
+                } else {
+                    service.pause().await
+                };
+                if let Err(e) = result {
+                    error.set(Some(format!("Failed to update the timer: {}", e)));
+                }
+            });
+        }
+    };
+
+    let extend = {
+        let service = service.clone();
+        move |_| {
+            let service = service.clone();
+            error.set(None);
+            spawn_task(async move {
+                if let Err(e) = service.extend(EXTEND_MINUTES).await {
+                    error.set(Some(format!("Failed to add time: {}", e)));
+                }
+            });
+        }
+    };
+
+    let stop = {
+        let service = service.clone();
+        move |_| {
+            let service = service.clone();
+            error.set(None);
+            spawn_task(async move {
+                if let Err(e) = service.stop().await {
+                    error.set(Some(format!("Failed to stop the timer: {}", e)));
+                }
+            });
+        }
+    };
+
+    let paused = game_state
+        .pacing_timer
+        .read()
+        .as_ref()
+        .map(|(timer, _)| timer.cue == PacingCueData::Paused);
+
+    rsx! {
+        div {
+            class: "pacing-panel bg-dark-surface rounded-lg p-4",
+
+            h3 { class: "text-gray-400 text-sm uppercase m-0 mb-3", "Pacing Timer" }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if let Some(paused) = paused {
+                div {
+                    class: "flex flex-col gap-2 mb-3",
+                    PacingTimerBadge {}
+                    div {
+                        class: "flex gap-1",
+                        button {
+                            onclick: move |_| pause(paused),
+                            class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                            if paused { "Resume" } else { "Pause" }
+                        }
+                        button {
+                            onclick: extend,
+                            class: "px-2 py-1 bg-amber-700 text-white text-xs rounded cursor-pointer",
+                            "+{EXTEND_MINUTES} min"
+                        }
+                        button {
+                            onclick: stop,
+                            class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                            "Stop"
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "flex flex-col gap-2 border-t border-gray-700 pt-3",
+
+                label {
+                    class: "flex items-center justify-between gap-2 text-gray-400 text-xs",
+                    "Time"
+                    select {
+                        value: if *kind.read() == PacingTimerKindData::Turn { "turn" } else { "scene" },
+                        onchange: move |e| {
+                            kind.set(if e.value() == "turn" {
+                                PacingTimerKindData::Turn
+                            } else {
+                                PacingTimerKindData::Scene
+                            });
+                        },
+                        class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        option { value: "scene", "The whole scene" }
+                        option { value: "turn", "Each player's turn" }
+                    }
+                }
+
+                label {
+                    class: "flex items-center justify-between gap-2 text-gray-400 text-xs",
+                    "Minutes"
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        value: "{minutes}",
+                        oninput: move |e| {
+                            if let Ok(n) = e.value().parse() {
+                                minutes.set(n);
+                            }
+                        },
+                        class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    }
+                }
+
+                label {
+                    class: "flex items-center justify-between gap-2 text-gray-400 text-xs",
+                    "Warn this many minutes before the end"
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        value: "{warning_minutes}",
+                        oninput: move |e| {
+                            if let Ok(n) = e.value().parse() {
+                                warning_minutes.set(n);
+                            }
+                        },
+                        class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    }
+                }
+
+                input {
+                    r#type: "text",
+                    placeholder: "Label (optional)",
+                    value: "{label}",
+                    oninput: move |e| label.set(e.value()),
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                }
+
+                button {
+                    onclick: start,
+                    class: "self-start px-2 py-1 bg-amber-700 text-white text-xs rounded cursor-pointer",
+                    if paused.is_some() { "Restart timer" } else { "Start timer" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! Parallel Scenes Panel for DM
+//!
+//! Runs a split party as side-by-side scenes:
+//! - Splitting a group of PCs off into a named scene with its own notes
+//!   and tone, which steer the NPC responses in that scene
+//! - Switching focus between scenes and the main table
+//! - Closing a scene, or merging the whole party back together
+//!
+//! Each scene's dialogue and rolls only reach its own players, and its
+//! approvals are labelled in the decision queue.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{ParallelSceneData, ParallelSceneInputData};
+use crate::application::services::player_character_service::PlayerCharacterData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_parallel_scene_service, use_player_character_service};
+use crate::presentation::state::use_game_state;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ParallelScenesPanelProps {
+    pub world_id: String,
+}
+
+/// Parallel Scenes Panel component for DM view
+#[component]
+pub fn ParallelScenesPanel(props: ParallelScenesPanelProps) -> Element {
+    let scene_service = use_parallel_scene_service();
+    let pc_service = use_player_character_service();
+    let game_state = use_game_state();
+    let mut pcs: Signal<Vec<PlayerCharacterData>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut editing: Signal<Option<ParallelSceneData>> = use_signal(|| None);
+
+    // Load the scenes and the world's PCs on mount
+    {
+        let scene_service = scene_service.clone();
+        let pc_service = pc_service.clone();
+        let world_id = props.world_id.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let mut game_state = game_state.clone();
+            let scene_service = scene_service.clone();
+            let pc_service = pc_service.clone();
+            let world_id = world_id.clone();
+            spawn_task(async move {
+                match scene_service.list_scenes().await {
+                    Ok(scenes) => game_state.set_parallel_scenes(scenes),
+                    Err(e) => error.set(Some(format!("Failed to load scenes: {}", e))),
+                }
+                match pc_service.list_pcs(&world_id).await {
+                    Ok(list) => pcs.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load PCs: {}", e))),
+                }
+            });
+        });
+    }
+
+    // The engine broadcasts ParallelScenesChanged, which updates the game state
+    let focus = {
+        let service = scene_service.clone();
+        move |scene_id: Option<String>| {
+            let service = service.clone();
+            error.set(None);
+            spawn_task(async move {
+                if let Err(e) = service.focus_scene(scene_id).await {
+                    error.set(Some(format!("Failed to switch scene: {}", e)));
+                }
+            });
+        }
+    };
+
+    let close = {
+        let service = scene_service.clone();
+        move |scene_id: String| {
+            let service = service.clone();
+            error.set(None);
+            spawn_task(async move {
+                if let Err(e) = service.delete_scene(&scene_id).await {
+                    error.set(Some(format!("Failed to close scene: {}", e)));
+                }
+            });
+        }
+    };
+
+    let merge = {
+        let service = scene_service.clone();
+        move |_| {
+            let service = service.clone();
+            error.set(None);
+            spawn_task(async move {
+                if let Err(e) = service.merge_scenes().await {
+                    error.set(Some(format!("Failed to merge the party: {}", e)));
+                }
+            });
+        }
+    };
+
+    let scenes = game_state.parallel_scenes.read().clone();
+    let main_focused = !scenes.iter().any(|scene| scene.is_focused);
+    let pc_name = move |id: &str| {
+        pcs.read()
+            .iter()
+            .find(|pc| pc.id == id)
+            .map(|pc| pc.name.clone())
+            .unwrap_or_else(|| "Unknown".to_string())
+    };
+
+    rsx! {
+        div {
+            class: "parallel-scenes-panel bg-dark-surface rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center mb-3",
+                h3 { class: "text-gray-400 text-sm uppercase m-0", "Parallel Scenes" }
+                if !scenes.is_empty() {
+                    button {
+                        onclick: merge,
+                        class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                        "Merge party"
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if !scenes.is_empty() {
+                div {
+                    class: "flex flex-col gap-2 mb-3",
+
+                    button {
+                        onclick: {
+                            let mut focus = focus.clone();
+                            move |_| focus(None)
+                        },
+                        class: if main_focused {
+                            "text-left px-2 py-1 bg-purple-800 text-white text-xs rounded cursor-pointer"
+                        } else {
+                            "text-left px-2 py-1 bg-dark-bg text-gray-300 text-xs rounded cursor-pointer"
+                        },
+                        "Main table"
+                    }
+
+                    for scene in scenes.iter() {
+                        div {
+                            key: "{scene.id}",
+                            class: if scene.is_focused {
+                                "flex flex-col gap-1 p-2 bg-purple-900 rounded"
+                            } else {
+                                "flex flex-col gap-1 p-2 bg-dark-bg rounded"
+                            },
+                            div {
+                                class: "flex justify-between items-center",
+                                span { class: "text-white text-sm", "{scene.name}" }
+                                div {
+                                    class: "flex gap-1",
+                                    if !scene.is_focused {
+                                        button {
+                                            onclick: {
+                                                let mut focus = focus.clone();
+                                                let id = scene.id.clone();
+                                                move |_| focus(Some(id.clone()))
+                                            },
+                                            class: "px-1.5 py-0.5 bg-purple-700 text-white text-xs rounded cursor-pointer",
+                                            "Focus"
+                                        }
+                                    }
+                                    button {
+                                        onclick: {
+                                            let scene = scene.clone();
+                                            move |_| editing.set(Some(scene.clone()))
+                                        },
+                                        class: "px-1.5 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                                        "Edit"
+                                    }
+                                    button {
+                                        onclick: {
+                                            let mut close = close.clone();
+                                            let id = scene.id.clone();
+                                            move |_| close(id.clone())
+                                        },
+                                        class: "px-1.5 py-0.5 bg-red-800 text-white text-xs rounded cursor-pointer",
+                                        "Close"
+                                    }
+                                }
+                            }
+                            div {
+                                class: "text-gray-400 text-xs",
+                                {scene.pc_ids.iter().map(|id| pc_name(id)).collect::<Vec<_>>().join(", ")}
+                            }
+                            if !scene.tone.is_empty() {
+                                div { class: "text-gray-500 text-xs italic", "{scene.tone}" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            ParallelSceneForm {
+                key: "{editing.read().as_ref().map(|s| s.id.clone()).unwrap_or_default()}",
+                pcs: pcs.read().clone(),
+                editing: editing.read().clone(),
+                on_done: move |_| editing.set(None),
+                on_error: move |msg| error.set(Some(msg)),
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ParallelSceneFormProps {
+    pcs: Vec<PlayerCharacterData>,
+    /// The scene being edited; `None` splits off a new one
+    editing: Option<ParallelSceneData>,
+    on_done: EventHandler<()>,
+    on_error: EventHandler<String>,
+}
+
+/// Who is in a scene and how it should play
+#[component]
+fn ParallelSceneForm(props: ParallelSceneFormProps) -> Element {
+    let scene_service = use_parallel_scene_service();
+    let editing = props.editing.clone();
+
+    let mut name = use_signal(|| editing.as_ref().map(|s| s.name.clone()).unwrap_or_default());
+    let mut members: Signal<Vec<String>> = use_signal(|| {
+        editing
+            .as_ref()
+            .map(|s| s.pc_ids.clone())
+            .unwrap_or_default()
+    });
+    let mut scene_notes = use_signal(|| {
+        editing
+            .as_ref()
+            .map(|s| s.scene_notes.clone())
+            .unwrap_or_default()
+    });
+    let mut tone = use_signal(|| editing.as_ref().map(|s| s.tone.clone()).unwrap_or_default());
+    let mut saving = use_signal(|| false);
+
+    let save = {
+        let service = scene_service.clone();
+        let scene_id = editing.as_ref().map(|s| s.id.clone());
+        let on_done = props.on_done;
+        let on_error = props.on_error;
+        move |_| {
+            let data = ParallelSceneInputData {
+                name: name.read().trim().to_string(),
+                pc_ids: members.read().clone(),
+                scene_notes: scene_notes.read().clone(),
+                tone: tone.read().clone(),
+            };
+            let service = service.clone();
+            let scene_id = scene_id.clone();
+            saving.set(true);
+            spawn_task(async move {
+                let result = match &scene_id {
+                    Some(id) => service.update_scene(id, data).await,
+                    None => service.create_scene(data).await,
+                };
+                match result {
+                    Ok(_) => {
+                        name.set(String::new());
+                        members.set(Vec::new());
+                        scene_notes.set(String::new());
+                        tone.set(String::new());
+                        on_done.call(());
+                    }
+                    Err(e) => on_error.call(format!("Failed to save scene: {}", e)),
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    let can_save = !name.read().trim().is_empty() && !members.read().is_empty();
+    let is_editing = editing.is_some();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 border-t border-gray-700 pt-3",
+
+            div {
+                class: "text-gray-500 text-xs",
+                if is_editing { "Edit scene" } else { "Split off a group" }
+            }
+
+            input {
+                r#type: "text",
+                value: "{name}",
+                placeholder: "The sewers",
+                oninput: move |e| name.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+
+            if !props.pcs.is_empty() {
+                div {
+                    class: "flex flex-col gap-1 max-h-32 overflow-y-auto",
+                    for pc in props.pcs.iter() {
+                        label {
+                            key: "{pc.id}",
+                            class: "flex items-center gap-1 text-white text-xs",
+                            input {
+                                r#type: "checkbox",
+                                checked: members.read().contains(&pc.id),
+                                onchange: {
+                                    let id = pc.id.clone();
+                                    move |e: Event<FormData>| {
+                                        let mut list = members.write();
+                                        list.retain(|m| m != &id);
+                                        if e.checked() {
+                                            list.push(id.clone());
+                                        }
+                                    }
+                                },
+                            }
+                            "{pc.name}"
+                        }
+                    }
+                }
+            }
+
+            textarea {
+                value: "{scene_notes}",
+                placeholder: "Scene notes for this group's NPCs",
+                oninput: move |e| scene_notes.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs min-h-[3rem]",
+            }
+
+            input {
+                r#type: "text",
+                value: "{tone}",
+                placeholder: "Tone (tense, comic relief)",
+                oninput: move |e| tone.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+            }
+
+            div {
+                class: "flex gap-2",
+                button {
+                    onclick: save,
+                    disabled: !can_save || *saving.read(),
+                    class: "px-2 py-1 bg-purple-700 text-white text-xs rounded cursor-pointer disabled:opacity-50",
+                    if is_editing { "Save scene" } else { "Split party" }
+                }
+                if is_editing {
+                    button {
+                        onclick: move |_| props.on_done.call(()),
+                        class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                        "Cancel"
+                    }
+                }
+            }
+        }
+    }
+}
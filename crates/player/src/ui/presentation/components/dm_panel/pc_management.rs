@@ -6,6 +6,13 @@ use crate::application::services::PlayerCharacterData;
 use crate::infrastructure::spawn_task;
 use crate::presentation::services::use_player_character_service;
 
+use super::companions::CompanionPanel;
+use super::injuries::InjuryPanel;
+use super::known_locations::KnownLocationsPanel;
+use super::mortality::MortalityPanel;
+use super::properties::PropertyPanel;
+use super::secrets::SecretPanel;
+
 /// Props for PCManagementPanel
 #[derive(Props, Clone, PartialEq)]
 pub struct PCManagementPanelProps {
@@ -80,6 +87,7 @@ pub fn PCManagementPanel(props: PCManagementPanelProps) -> Element {
                                 let pc_id = pc.id.clone();
                                 rsx! {
                                     PCManagementCard {
+                                        world_id: props.world_id.clone(),
                                         pc,
                                         on_view_as: move |_| props.on_view_as_character.call(pc_id.clone()),
                                     }
@@ -96,6 +104,7 @@ pub fn PCManagementPanel(props: PCManagementPanelProps) -> Element {
 /// PC Management Card component
 #[derive(Props, Clone, PartialEq)]
 struct PCManagementCardProps {
+    world_id: String,
     pc: PlayerCharacterData,
     on_view_as: EventHandler<()>,
 }
@@ -153,6 +162,36 @@ fn PCManagementCard(props: PCManagementCardProps) -> Element {
                     }
                 }
             }
+
+            PropertyPanel {
+                world_id: props.world_id.clone(),
+                pc_id: props.pc.id.clone(),
+            }
+
+            CompanionPanel {
+                world_id: props.world_id.clone(),
+                pc_id: props.pc.id.clone(),
+            }
+
+            InjuryPanel {
+                world_id: props.world_id.clone(),
+                pc_id: props.pc.id.clone(),
+            }
+
+            SecretPanel {
+                world_id: props.world_id.clone(),
+                pc_id: props.pc.id.clone(),
+            }
+
+            KnownLocationsPanel {
+                world_id: props.world_id.clone(),
+                pc_id: props.pc.id.clone(),
+            }
+
+            MortalityPanel {
+                pc_id: props.pc.id.clone(),
+                is_alive: props.pc.is_alive,
+            }
         }
     }
 }
@@ -0,0 +1,391 @@
+//! Progress Clock Panel for DM
+//!
+//! Lists the world's progress clocks and provides controls for:
+//! - Ticking clocks forward/back one segment
+//! - Resetting a clock
+//! - Toggling whether players can see a clock
+//! - Creating new clocks, including personal quests that only one PC sees
+
+use dioxus::prelude::*;
+
+use crate::application::dto::ProgressClockData;
+use crate::application::services::PlayerCharacterData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_player_character_service, use_progress_clock_service};
+use crate::presentation::state::use_game_state;
+use wrldbldr_protocol::requests::{CreateClockData, UpdateClockData};
+
+/// Clock kinds offered in the create form
+const CLOCK_KINDS: &[&str] = &["threat", "faction", "quest", "project"];
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ProgressClockPanelProps {
+    /// The world whose clocks are shown
+    pub world_id: String,
+}
+
+/// Progress Clock Panel component for DM view
+#[component]
+pub fn ProgressClockPanel(props: ProgressClockPanelProps) -> Element {
+    let game_state = use_game_state();
+    let clock_service = use_progress_clock_service();
+    let pc_service = use_player_character_service();
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut show_create_form = use_signal(|| false);
+    let mut pcs: Signal<Vec<PlayerCharacterData>> = use_signal(Vec::new);
+
+    // Load clocks on mount; later changes arrive as ClockUpdated/ClockRemoved
+    {
+        let world_id = props.world_id.clone();
+        let service = clock_service.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let service = service.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service.list_clocks(&world_id).await {
+                    Ok(clocks) => game_state.set_progress_clocks(clocks),
+                    Err(e) => error.set(Some(format!("Failed to load clocks: {}", e))),
+                }
+            });
+        });
+    }
+
+    // PCs personal quests can belong to
+    {
+        let world_id = props.world_id.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let service = pc_service.clone();
+            spawn_task(async move {
+                match service.list_pcs(&world_id).await {
+                    Ok(list) => pcs.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load PCs: {}", e))),
+                }
+            });
+        });
+    }
+
+    let clocks = game_state.progress_clocks.read().clone();
+    let pc_list = pcs.read().clone();
+    let party_clocks: Vec<ProgressClockData> = clocks
+        .iter()
+        .filter(|c| c.owner_pc_id.is_none())
+        .cloned()
+        .collect();
+
+    rsx! {
+        div {
+            class: "progress-clock-panel bg-dark-surface rounded-lg p-4",
+
+            div {
+                class: "flex items-center justify-between mb-3",
+                h3 { class: "text-gray-400 text-sm uppercase m-0", "Progress Clocks" }
+                button {
+                    onclick: move |_| {
+                        let open = *show_create_form.read();
+                        show_create_form.set(!open);
+                    },
+                    class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                    if *show_create_form.read() { "Cancel" } else { "+ New" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if *show_create_form.read() {
+                CreateClockForm {
+                    world_id: props.world_id.clone(),
+                    pcs: pc_list.clone(),
+                    party_clocks: party_clocks.clone(),
+                    on_created: move |_| show_create_form.set(false),
+                    on_error: move |msg| error.set(Some(msg)),
+                }
+            }
+
+            if clocks.is_empty() {
+                div { class: "text-gray-500 italic text-center text-sm py-2", "No clocks" }
+            } else {
+                div {
+                    class: "flex flex-col gap-2",
+                    for clock in clocks.iter() {
+                        ClockRow {
+                            key: "{clock.id}",
+                            clock: clock.clone(),
+                            owner_name: clock.owner_pc_id.as_ref().and_then(|id| {
+                                pc_list.iter().find(|pc| &pc.id == id).map(|pc| pc.name.clone())
+                            }),
+                            conflicts_with: clock.conflicts_with_clock_id.as_ref().and_then(|id| {
+                                party_clocks.iter().find(|c| &c.id == id).map(|c| c.name.clone())
+                            }),
+                            on_error: move |msg| error.set(Some(msg)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ClockRowProps {
+    clock: ProgressClockData,
+    /// Name of the PC a personal quest belongs to
+    owner_name: Option<String>,
+    /// Name of the party clock a personal quest works against
+    conflicts_with: Option<String>,
+    on_error: EventHandler<String>,
+}
+
+/// A single clock with segment display and tick controls
+#[component]
+fn ClockRow(props: ClockRowProps) -> Element {
+    let clock_service = use_progress_clock_service();
+    let clock = props.clock.clone();
+
+    let run = {
+        let on_error = props.on_error;
+        move |action: ClockAction| {
+            let service = clock_service.clone();
+            let clock_id = clock.id.clone();
+            let visible = clock.visible_to_players;
+            spawn_task(async move {
+                // The server broadcasts ClockUpdated, which updates game state
+                let result = match action {
+                    ClockAction::Tick(amount) => service.tick_clock(&clock_id, amount).await,
+                    ClockAction::Reset => service.reset_clock(&clock_id).await,
+                    ClockAction::ToggleVisibility => {
+                        let data = UpdateClockData {
+                            visible_to_players: Some(!visible),
+                            ..Default::default()
+                        };
+                        service.update_clock(&clock_id, data).await
+                    }
+                };
+                if let Err(e) = result {
+                    on_error.call(format!("Failed to update clock: {}", e));
+                }
+            });
+        }
+    };
+    let tick_back = run.clone();
+    let tick_forward = run.clone();
+    let reset = run.clone();
+    let toggle = run;
+
+    let clock = &props.clock;
+    let filled = clock.filled as usize;
+    let owner_name = props.owner_name.as_deref().unwrap_or("unknown PC");
+
+    rsx! {
+        div {
+            class: "p-2 bg-dark-bg rounded",
+
+            div {
+                class: "flex items-center justify-between gap-2",
+                div {
+                    class: "min-w-0",
+                    span { class: "text-white text-sm", "{clock.name}" }
+                    if let Some(subject) = clock.subject.as_ref() {
+                        span { class: "text-gray-500 text-xs ml-2", "({subject})" }
+                    }
+                    if clock.owner_pc_id.is_some() {
+                        span {
+                            class: "ml-2 px-1 bg-purple-900 text-purple-300 text-xs rounded",
+                            "Personal: {owner_name}"
+                        }
+                    }
+                    if let Some(party_goal) = props.conflicts_with.as_ref() {
+                        div { class: "text-red-400 text-xs", "Works against: {party_goal}" }
+                    }
+                }
+                span { class: "text-gray-400 text-xs", "{clock.filled}/{clock.segments}" }
+            }
+
+            // Segment display
+            div {
+                class: "flex gap-0.5 my-2",
+                for i in 0..clock.segments as usize {
+                    div {
+                        key: "{i}",
+                        class: if i < filled {
+                            if clock.is_complete { "flex-1 h-2 rounded-sm bg-red-500" } else { "flex-1 h-2 rounded-sm bg-amber-500" }
+                        } else {
+                            "flex-1 h-2 rounded-sm bg-gray-700"
+                        },
+                    }
+                }
+            }
+
+            div {
+                class: "flex items-center gap-1",
+                button {
+                    onclick: move |_| tick_back(ClockAction::Tick(-1)),
+                    disabled: filled == 0,
+                    class: "px-2 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                    "-"
+                }
+                button {
+                    onclick: move |_| tick_forward(ClockAction::Tick(1)),
+                    disabled: clock.is_complete,
+                    class: "px-2 py-0.5 bg-amber-600 text-white text-xs rounded cursor-pointer",
+                    "+"
+                }
+                button {
+                    onclick: move |_| reset(ClockAction::Reset),
+                    class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                    "Reset"
+                }
+                button {
+                    onclick: move |_| toggle(ClockAction::ToggleVisibility),
+                    class: "ml-auto px-2 py-0.5 bg-transparent text-xs border border-gray-700 rounded cursor-pointer",
+                    class: if clock.visible_to_players { "text-blue-400" } else { "text-gray-500" },
+                    if clock.visible_to_players { "Visible" } else { "Hidden" }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ClockAction {
+    Tick(i32),
+    Reset,
+    ToggleVisibility,
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct CreateClockFormProps {
+    world_id: String,
+    pcs: Vec<PlayerCharacterData>,
+    party_clocks: Vec<ProgressClockData>,
+    on_created: EventHandler<()>,
+    on_error: EventHandler<String>,
+}
+
+/// Inline form for creating a clock
+#[component]
+fn CreateClockForm(props: CreateClockFormProps) -> Element {
+    let clock_service = use_progress_clock_service();
+    let mut name = use_signal(String::new);
+    let mut segments = use_signal(|| 4u8);
+    let mut kind = use_signal(|| CLOCK_KINDS[0].to_string());
+    let mut visible = use_signal(|| false);
+    let mut owner_pc_id = use_signal(String::new);
+    let mut conflicts_with = use_signal(String::new);
+
+    let handle_create = move |_| {
+        let clock_name = name.read().trim().to_string();
+        if clock_name.is_empty() {
+            return;
+        }
+        let data = CreateClockData {
+            name: clock_name,
+            segments: *segments.read(),
+            description: None,
+            kind: Some(kind.read().clone()),
+            subject: None,
+            visible_to_players: Some(*visible.read()),
+            owner_pc_id: Some(owner_pc_id.read().clone()).filter(|id| !id.is_empty()),
+            conflicts_with_clock_id: Some(conflicts_with.read().clone())
+                .filter(|id| !id.is_empty() && !owner_pc_id.read().is_empty()),
+        };
+        let service = clock_service.clone();
+        let world_id = props.world_id.clone();
+        let on_created = props.on_created;
+        let on_error = props.on_error;
+        spawn_task(async move {
+            match service.create_clock(&world_id, data).await {
+                Ok(_) => {
+                    name.set(String::new());
+                    on_created.call(());
+                }
+                Err(e) => on_error.call(format!("Failed to create clock: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 mb-3 p-2 bg-dark-bg rounded",
+
+            input {
+                r#type: "text",
+                value: "{name}",
+                placeholder: "Clock name",
+                oninput: move |e| name.set(e.value()),
+                class: "p-1 bg-dark-surface border border-gray-700 rounded text-white text-sm",
+            }
+
+            div {
+                class: "flex items-center gap-2",
+                select {
+                    value: "{segments}",
+                    onchange: move |e| {
+                        if let Ok(n) = e.value().parse() {
+                            segments.set(n);
+                        }
+                    },
+                    class: "p-1 bg-dark-surface border border-gray-700 rounded text-white text-sm",
+                    for n in [4u8, 6, 8, 12] {
+                        option { key: "{n}", value: "{n}", "{n} segments" }
+                    }
+                }
+                select {
+                    value: "{kind}",
+                    onchange: move |e| kind.set(e.value()),
+                    class: "p-1 bg-dark-surface border border-gray-700 rounded text-white text-sm",
+                    for k in CLOCK_KINDS.iter() {
+                        option { key: "{k}", value: "{k}", "{k}" }
+                    }
+                }
+            }
+
+            label {
+                class: "flex items-center gap-2 text-gray-400 text-xs",
+                input {
+                    r#type: "checkbox",
+                    checked: *visible.read(),
+                    onchange: move |e| visible.set(e.checked()),
+                }
+                "Visible to players"
+            }
+
+            select {
+                value: "{owner_pc_id}",
+                onchange: move |e| owner_pc_id.set(e.value()),
+                class: "p-1 bg-dark-surface border border-gray-700 rounded text-white text-sm",
+                option { value: "", "Whole party" }
+                for pc in props.pcs.iter() {
+                    option { key: "{pc.id}", value: "{pc.id}", "Personal to {pc.name}" }
+                }
+            }
+
+            if !owner_pc_id.read().is_empty() {
+                select {
+                    value: "{conflicts_with}",
+                    onchange: move |e| conflicts_with.set(e.value()),
+                    class: "p-1 bg-dark-surface border border-gray-700 rounded text-white text-sm",
+                    option { value: "", "No conflicting party goal" }
+                    for party_clock in props.party_clocks.iter() {
+                        option {
+                            key: "{party_clock.id}",
+                            value: "{party_clock.id}",
+                            "Works against: {party_clock.name}"
+                        }
+                    }
+                }
+            }
+
+            button {
+                onclick: handle_create,
+                disabled: name.read().trim().is_empty(),
+                class: "px-3 py-1 bg-amber-600 text-white text-sm rounded cursor-pointer",
+                "Create Clock"
+            }
+        }
+    }
+}
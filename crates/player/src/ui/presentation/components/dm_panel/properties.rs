@@ -0,0 +1,438 @@
+//! Property Panel for DM
+//!
+//! Lists the places a PC owns and provides controls for:
+//! - Paying a property's upkeep for the game days owed
+//! - Removing a property
+//! - Giving the PC a new property at a location, with upkeep and staff
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{
+    PropertyData, PropertyInputData, PropertyStaffInputData, StaffRoleData,
+};
+use crate::application::services::{CharacterSummary, LocationSummary};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{
+    use_character_service, use_location_service, use_property_service,
+};
+
+/// Staff roles offered in the create form
+const STAFF_ROLES: &[(StaffRoleData, &str)] = &[
+    (StaffRoleData::Worker, "worker"),
+    (StaffRoleData::Steward, "steward"),
+    (StaffRoleData::Guard, "guard"),
+];
+
+fn role_label(role: StaffRoleData) -> &'static str {
+    STAFF_ROLES
+        .iter()
+        .find(|(r, _)| *r == role)
+        .map(|(_, label)| *label)
+        .unwrap_or("staff")
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PropertyPanelProps {
+    /// The world the PC belongs to, for location and NPC pickers
+    pub world_id: String,
+    /// The PC whose properties are shown
+    pub pc_id: String,
+}
+
+/// Property Panel component for DM view
+#[component]
+pub fn PropertyPanel(props: PropertyPanelProps) -> Element {
+    let property_service = use_property_service();
+    let mut properties: Signal<Vec<PropertyData>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut show_create_form = use_signal(|| false);
+
+    // Load properties on mount; upkeep owed is computed against game time
+    {
+        let pc_id = props.pc_id.clone();
+        let service = property_service.clone();
+        use_effect(move || {
+            let pc_id = pc_id.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_properties(&pc_id).await {
+                    Ok(list) => properties.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load properties: {}", e))),
+                }
+            });
+        });
+    }
+
+    let list = properties.read().clone();
+
+    rsx! {
+        div {
+            class: "property-panel mt-3",
+
+            div {
+                class: "flex items-center justify-between mb-2",
+                div { class: "text-gray-400 text-xs uppercase", "Properties" }
+                button {
+                    onclick: move |_| {
+                        let open = *show_create_form.read();
+                        show_create_form.set(!open);
+                    },
+                    class: "px-2 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                    if *show_create_form.read() { "Cancel" } else { "+ Property" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if *show_create_form.read() {
+                CreatePropertyForm {
+                    world_id: props.world_id.clone(),
+                    pc_id: props.pc_id.clone(),
+                    on_created: move |property: PropertyData| {
+                        properties.write().push(property);
+                        show_create_form.set(false);
+                    },
+                    on_error: move |msg| error.set(Some(msg)),
+                }
+            }
+
+            if list.is_empty() {
+                div { class: "text-gray-500 italic text-xs", "Owns no property" }
+            } else {
+                div {
+                    class: "flex flex-col gap-2",
+                    for property in list {
+                        PropertyRow {
+                            key: "{property.id}",
+                            property: property.clone(),
+                            on_changed: move |updated: PropertyData| {
+                                if let Some(slot) = properties.write().iter_mut().find(|p| p.id == updated.id) {
+                                    *slot = updated;
+                                }
+                            },
+                            on_removed: move |id: String| properties.write().retain(|p| p.id != id),
+                            on_error: move |msg| error.set(Some(msg)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct PropertyRowProps {
+    property: PropertyData,
+    on_changed: EventHandler<PropertyData>,
+    on_removed: EventHandler<String>,
+    on_error: EventHandler<String>,
+}
+
+/// A single property with its upkeep and staff
+#[component]
+fn PropertyRow(props: PropertyRowProps) -> Element {
+    let property_service = use_property_service();
+    let property = props.property.clone();
+
+    let pay = {
+        let service = property_service.clone();
+        let property_id = property.id.clone();
+        let on_changed = props.on_changed;
+        let on_error = props.on_error;
+        move |_| {
+            let service = service.clone();
+            let property_id = property_id.clone();
+            spawn_task(async move {
+                match service.pay_upkeep(&property_id).await {
+                    Ok(payment) => on_changed.call(payment.property),
+                    Err(e) => on_error.call(format!("Failed to pay upkeep: {}", e)),
+                }
+            });
+        }
+    };
+
+    let remove = {
+        let service = property_service.clone();
+        let property_id = property.id.clone();
+        let on_removed = props.on_removed;
+        let on_error = props.on_error;
+        move |_| {
+            let service = service.clone();
+            let property_id = property_id.clone();
+            spawn_task(async move {
+                match service.delete_property(&property_id).await {
+                    Ok(()) => on_removed.call(property_id),
+                    Err(e) => on_error.call(format!("Failed to remove property: {}", e)),
+                }
+            });
+        }
+    };
+
+    let site = property
+        .site_name
+        .clone()
+        .unwrap_or_else(|| "Unknown site".to_string());
+
+    rsx! {
+        div {
+            class: "p-2 bg-dark-surface rounded",
+
+            div {
+                class: "flex items-center justify-between gap-2",
+                div {
+                    class: "min-w-0",
+                    span { class: "text-white text-sm", "{property.name}" }
+                    span { class: "text-gray-500 text-xs ml-2", "({site})" }
+                }
+                button {
+                    onclick: remove,
+                    class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                    "Remove"
+                }
+            }
+
+            div {
+                class: "flex items-center gap-2 mt-1 text-xs",
+                span { class: "text-gray-400", "Upkeep {property.upkeep_per_day}/day" }
+                if property.upkeep_due > 0 {
+                    span {
+                        class: "text-amber-400",
+                        "{property.upkeep_due} owed ({property.days_owed} days)"
+                    }
+                    button {
+                        onclick: pay,
+                        class: "ml-auto px-2 py-0.5 bg-amber-600 text-white text-xs rounded cursor-pointer",
+                        "Pay"
+                    }
+                } else {
+                    span { class: "text-green-400", "Paid up" }
+                }
+            }
+
+            if !property.staff.is_empty() {
+                div {
+                    class: "flex flex-wrap gap-1 mt-1",
+                    for member in property.staff.iter() {
+                        span {
+                            key: "{member.character_id}",
+                            class: "px-1.5 py-0.5 bg-dark-bg text-gray-300 text-xs rounded",
+                            title: "{member.duties}",
+                            {
+                                let name = member.character_name.clone().unwrap_or_else(|| "Departed NPC".to_string());
+                                format!("{} ({})", name, role_label(member.role))
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !property.notes.is_empty() {
+                div { class: "text-gray-500 text-xs mt-1", "{property.notes}" }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct CreatePropertyFormProps {
+    world_id: String,
+    pc_id: String,
+    on_created: EventHandler<PropertyData>,
+    on_error: EventHandler<String>,
+}
+
+/// Inline form for giving the PC a property
+#[component]
+fn CreatePropertyForm(props: CreatePropertyFormProps) -> Element {
+    let property_service = use_property_service();
+    let location_service = use_location_service();
+    let character_service = use_character_service();
+    let mut locations: Signal<Vec<LocationSummary>> = use_signal(Vec::new);
+    let mut npcs: Signal<Vec<CharacterSummary>> = use_signal(Vec::new);
+    let mut name = use_signal(String::new);
+    let mut location_id = use_signal(String::new);
+    let mut upkeep = use_signal(|| 0u32);
+    let mut staff: Signal<Vec<PropertyStaffInputData>> = use_signal(Vec::new);
+    let mut staff_npc = use_signal(String::new);
+    let mut staff_role = use_signal(|| StaffRoleData::Worker);
+    let mut staff_duties = use_signal(String::new);
+
+    // Load the pickers on mount
+    {
+        let world_id = props.world_id.clone();
+        let on_error = props.on_error;
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let locs = location_service.clone();
+            let chars = character_service.clone();
+            spawn_task(async move {
+                match locs.list_locations(&world_id).await {
+                    Ok(list) => {
+                        if let Some(first) = list.first() {
+                            location_id.set(first.id.clone());
+                        }
+                        locations.set(list);
+                    }
+                    Err(e) => on_error.call(format!("Failed to load locations: {}", e)),
+                }
+                match chars.list_characters(&world_id).await {
+                    Ok(list) => npcs.set(list),
+                    Err(e) => on_error.call(format!("Failed to load NPCs: {}", e)),
+                }
+            });
+        });
+    }
+
+    let handle_add_staff = move |_| {
+        let character_id = staff_npc.read().clone();
+        if character_id.is_empty() || staff.read().iter().any(|s| s.character_id == character_id) {
+            return;
+        }
+        staff.write().push(PropertyStaffInputData {
+            character_id,
+            role: *staff_role.read(),
+            duties: staff_duties.read().trim().to_string(),
+        });
+        staff_duties.set(String::new());
+    };
+
+    let handle_create = move |_| {
+        let property_name = name.read().trim().to_string();
+        let site = location_id.read().clone();
+        if property_name.is_empty() || site.is_empty() {
+            return;
+        }
+        let data = PropertyInputData {
+            name: property_name,
+            location_id: Some(site),
+            region_id: None,
+            upkeep_per_day: *upkeep.read(),
+            staff: staff.read().clone(),
+            notes: String::new(),
+        };
+        let service = property_service.clone();
+        let pc_id = props.pc_id.clone();
+        let on_created = props.on_created;
+        let on_error = props.on_error;
+        spawn_task(async move {
+            match service.create_property(&pc_id, data).await {
+                Ok(property) => {
+                    name.set(String::new());
+                    staff.set(Vec::new());
+                    on_created.call(property);
+                }
+                Err(e) => on_error.call(format!("Failed to create property: {}", e)),
+            }
+        });
+    };
+
+    let npc_list = npcs.read().clone();
+    let npc_name = move |id: &str| {
+        npcs.read()
+            .iter()
+            .find(|c| c.id == id)
+            .map(|c| c.name.clone())
+            .unwrap_or_default()
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 mb-2 p-2 bg-dark-surface rounded",
+
+            input {
+                r#type: "text",
+                value: "{name}",
+                placeholder: "Property name",
+                oninput: move |e| name.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+
+            div {
+                class: "flex items-center gap-2",
+                select {
+                    value: "{location_id}",
+                    onchange: move |e| location_id.set(e.value()),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    for location in locations.read().iter() {
+                        option { key: "{location.id}", value: "{location.id}", "{location.name}" }
+                    }
+                }
+                input {
+                    r#type: "number",
+                    min: "0",
+                    value: "{upkeep}",
+                    title: "Upkeep per game day",
+                    oninput: move |e| upkeep.set(e.value().parse().unwrap_or(0)),
+                    class: "w-20 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+                span { class: "text-gray-500 text-xs", "/day" }
+            }
+
+            // Staff picker
+            div {
+                class: "flex items-center gap-1",
+                select {
+                    value: "{staff_npc}",
+                    onchange: move |e| staff_npc.set(e.value()),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    option { value: "", "Hire NPC..." }
+                    for npc in npc_list.iter() {
+                        option { key: "{npc.id}", value: "{npc.id}", "{npc.name}" }
+                    }
+                }
+                select {
+                    onchange: move |e| {
+                        let value = e.value();
+                        if let Some((role, _)) = STAFF_ROLES.iter().find(|(_, label)| *label == value) {
+                            staff_role.set(*role);
+                        }
+                    },
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    for (_, label) in STAFF_ROLES.iter() {
+                        option { key: "{label}", value: "{label}", "{label}" }
+                    }
+                }
+                input {
+                    r#type: "text",
+                    value: "{staff_duties}",
+                    placeholder: "Duties",
+                    oninput: move |e| staff_duties.set(e.value()),
+                    class: "w-24 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                }
+                button {
+                    onclick: handle_add_staff,
+                    disabled: staff_npc.read().is_empty(),
+                    class: "px-2 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                    "Add"
+                }
+            }
+
+            if !staff.read().is_empty() {
+                div {
+                    class: "flex flex-wrap gap-1",
+                    for member in staff.read().clone() {
+                        span {
+                            key: "{member.character_id}",
+                            class: "px-1.5 py-0.5 bg-dark-bg text-gray-300 text-xs rounded cursor-pointer",
+                            title: "Click to remove",
+                            onclick: {
+                                let id = member.character_id.clone();
+                                move |_| staff.write().retain(|s| s.character_id != id)
+                            },
+                            "{npc_name(&member.character_id)} ({role_label(member.role)})"
+                        }
+                    }
+                }
+            }
+
+            button {
+                onclick: handle_create,
+                disabled: name.read().trim().is_empty() || location_id.read().is_empty(),
+                class: "px-3 py-1 bg-amber-600 text-white text-sm rounded cursor-pointer",
+                "Create Property"
+            }
+        }
+    }
+}
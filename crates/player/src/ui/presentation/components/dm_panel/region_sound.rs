@@ -0,0 +1,123 @@
+//! Region sound editor for DM
+//!
+//! Sets how well noise carries along each way out of a region: an open
+//! archway lets any loud noise through, a door only the deafening ones and
+//! muffled, thick walls nothing. A two-way connection carries sound the
+//! same in both directions.
+
+use dioxus::prelude::*;
+
+use crate::application::services::location_service::RegionConnectionData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_location_service;
+use wrldbldr_protocol::types::SoundCarryData;
+use wrldbldr_protocol::RegionListItemData;
+
+/// One select per connection out of the region
+#[component]
+pub fn RegionSoundEditor(
+    region_id: String,
+    /// Regions of the same location, to name where each way leads
+    regions: Vec<RegionListItemData>,
+) -> Element {
+    let loc_service = use_location_service();
+    let mut connections: Signal<Option<Vec<RegionConnectionData>>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    {
+        let service = loc_service.clone();
+        let region_id = region_id.clone();
+        use_effect(move || {
+            let service = service.clone();
+            let region_id = region_id.clone();
+            spawn_task(async move {
+                match service.get_region_connections(&region_id).await {
+                    Ok(list) => connections.set(Some(list)),
+                    Err(e) => error.set(Some(format!("Failed to load connections: {}", e))),
+                }
+            });
+        });
+    }
+
+    let set_sound = move |to_region_id: String, sound: SoundCarryData| {
+        let service = loc_service.clone();
+        let from_region_id = region_id.clone();
+        spawn_task(async move {
+            match service
+                .set_region_connection_sound(&from_region_id, &to_region_id, sound)
+                .await
+            {
+                Ok(()) => {
+                    error.set(None);
+                    if let Some(list) = connections.write().as_mut() {
+                        for connection in list.iter_mut() {
+                            if connection.to_region_id == to_region_id {
+                                connection.sound = sound;
+                            }
+                        }
+                    }
+                }
+                Err(e) => error.set(Some(format!("Failed to set sound: {}", e))),
+            }
+        });
+    };
+
+    let list = connections.read().clone();
+
+    rsx! {
+        div {
+            class: "region-sound-editor flex flex-col gap-1 mt-2 text-xs",
+
+            match list {
+                None => rsx! { span { class: "text-gray-500", "Loading connections..." } },
+                Some(list) if list.is_empty() => rsx! {
+                    span { class: "text-gray-500", "No ways out of this region" }
+                },
+                Some(list) => rsx! {
+                    for connection in list.into_iter() {
+                        div {
+                            key: "{connection.to_region_id}",
+                            class: "flex items-center gap-2",
+                            span {
+                                class: "flex-1 text-gray-300",
+                                {regions
+                                    .iter()
+                                    .find(|r| r.id == connection.to_region_id)
+                                    .map(|r| r.name.clone())
+                                    .unwrap_or_else(|| connection.to_region_id.clone())}
+                            }
+                            select {
+                                aria_label: "How sound carries",
+                                class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                                value: match connection.sound {
+                                    SoundCarryData::Muffled => "muffled",
+                                    SoundCarryData::Blocked => "blocked",
+                                    _ => "open",
+                                },
+                                onchange: {
+                                    let set_sound = set_sound.clone();
+                                    let to_region_id = connection.to_region_id.clone();
+                                    move |e: Event<FormData>| {
+                                        let sound = match e.value().as_str() {
+                                            "muffled" => SoundCarryData::Muffled,
+                                            "blocked" => SoundCarryData::Blocked,
+                                            _ => SoundCarryData::Open,
+                                        };
+                                        set_sound(to_region_id.clone(), sound);
+                                    }
+                                },
+                                option { value: "open", "Open - carries loud noises" }
+                                option { value: "muffled", "Muffled - only deafening ones" }
+                                option { value: "blocked", "Blocked - carries nothing" }
+                            }
+                        }
+                    }
+                },
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400", "{err}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Safety Alert Panel for DM
+//!
+//! Shows the latest anonymous safety signal raised at the table, what the
+//! engine did about it, and lets the DM pause or resume player actions.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::SafetySignalLevelData;
+use crate::infrastructure::websocket::ClientMessageBuilder;
+use crate::presentation::services::use_command_bus;
+use crate::presentation::state::use_game_state;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SafetyAlertPanelProps {
+    /// The world whose action queue is controlled
+    pub world_id: String,
+}
+
+/// Safety Alert Panel component for DM view
+#[component]
+pub fn SafetyAlertPanel(props: SafetyAlertPanelProps) -> Element {
+    let mut game_state = use_game_state();
+    let command_bus = use_command_bus();
+
+    let alert = game_state.safety_alert.read().clone();
+    let queue_paused = *game_state.action_queue_paused.read();
+
+    let toggle_pause = {
+        let world_id = props.world_id.clone();
+        move |_| {
+            let msg = ClientMessageBuilder::set_action_queue_paused(&world_id, !queue_paused);
+            if let Err(e) = command_bus.send(msg) {
+                tracing::error!("Failed to change action queue pause: {}", e);
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "safety-alert-panel bg-dark-surface rounded-lg p-4",
+
+            div {
+                class: "flex items-center justify-between mb-3",
+                h3 { class: "text-gray-400 text-sm uppercase m-0", "Safety" }
+                if queue_paused {
+                    span {
+                        class: "text-red-400 text-xs px-2 py-1 bg-red-500/20 rounded",
+                        "PLAY PAUSED"
+                    }
+                }
+            }
+
+            if let Some(alert) = alert {
+                div {
+                    class: "mb-3 p-3 bg-red-900/40 border border-red-700 rounded",
+                    div {
+                        class: "flex items-center justify-between gap-2",
+                        span {
+                            class: "text-red-300 text-sm font-medium",
+                            "{signal_label(alert.level)}"
+                        }
+                        button {
+                            onclick: move |_| game_state.set_safety_alert(None),
+                            class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                            "Dismiss"
+                        }
+                    }
+                    p {
+                        class: "text-gray-300 text-xs mt-2 mb-0",
+                        "A player raised this anonymously. {signal_guidance(alert.level)}"
+                    }
+                    if alert.rejected_count > 0 {
+                        p {
+                            class: "text-gray-400 text-xs mt-1 mb-0",
+                            "{alert.rejected_count} pending NPC response(s) were rejected."
+                        }
+                    }
+                }
+            }
+
+            button {
+                onclick: toggle_pause,
+                class: if queue_paused {
+                    "w-full px-3 py-1.5 bg-green-700 hover:bg-green-600 text-white text-sm rounded cursor-pointer"
+                } else {
+                    "w-full px-3 py-1.5 bg-gray-700 hover:bg-gray-600 text-white text-sm rounded cursor-pointer"
+                },
+                if queue_paused { "Resume Play" } else { "Pause Play" }
+            }
+        }
+    }
+}
+
+fn signal_label(level: SafetySignalLevelData) -> &'static str {
+    match level {
+        SafetySignalLevelData::Check | SafetySignalLevelData::Unknown => "Check-in requested",
+        SafetySignalLevelData::Skip => "X-card: skip this content",
+        SafetySignalLevelData::Stop => "Stop: pause the game",
+    }
+}
+
+fn signal_guidance(level: SafetySignalLevelData) -> &'static str {
+    match level {
+        SafetySignalLevelData::Check | SafetySignalLevelData::Unknown => {
+            "Slow down and check in with the table."
+        }
+        SafetySignalLevelData::Skip => "Move the story away from the current content.",
+        SafetySignalLevelData::Stop => "Talk with the table before resuming play.",
+    }
+}
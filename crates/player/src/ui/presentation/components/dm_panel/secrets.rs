@@ -0,0 +1,428 @@
+//! Secret Panel for DM
+//!
+//! Lists the secrets a PC keeps and provides controls for:
+//! - Writing a new secret, with how named NPCs will take it coming out
+//! - Editing a secret that is still kept
+//! - Revealing a secret to the whole table
+//! - Removing a secret
+//!
+//! Only the DM and the PC's player can read a secret until it is revealed.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{PcSecretData, PcSecretInputData, SecretReactionData};
+use crate::application::services::CharacterSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_character_service, use_secret_service};
+
+/// Sentiment change as a signed percentage: "-40%"
+fn format_change(change: f32) -> String {
+    format!("{:+.0}%", change * 100.0)
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SecretPanelProps {
+    /// The world the PC belongs to, for the NPC picker
+    pub world_id: String,
+    /// The PC whose secrets are shown
+    pub pc_id: String,
+}
+
+/// Secret Panel component for DM view
+#[component]
+pub fn SecretPanel(props: SecretPanelProps) -> Element {
+    let secret_service = use_secret_service();
+    let mut secrets: Signal<Vec<PcSecretData>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut show_form = use_signal(|| false);
+
+    // Load secrets on mount
+    {
+        let pc_id = props.pc_id.clone();
+        let service = secret_service.clone();
+        use_effect(move || {
+            let pc_id = pc_id.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_secrets(&pc_id).await {
+                    Ok(list) => secrets.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load secrets: {}", e))),
+                }
+            });
+        });
+    }
+
+    let list = secrets.read().clone();
+
+    rsx! {
+        div {
+            class: "secret-panel mt-3",
+
+            div {
+                class: "flex items-center justify-between mb-2",
+                div { class: "text-gray-400 text-xs uppercase", "Secrets" }
+                button {
+                    onclick: move |_| {
+                        let open = *show_form.read();
+                        show_form.set(!open);
+                    },
+                    class: "px-2 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                    if *show_form.read() { "Cancel" } else { "+ Secret" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if *show_form.read() {
+                SecretForm {
+                    world_id: props.world_id.clone(),
+                    pc_id: props.pc_id.clone(),
+                    existing: None,
+                    on_saved: move |secret: PcSecretData| {
+                        secrets.write().push(secret);
+                        show_form.set(false);
+                    },
+                    on_error: move |msg| error.set(Some(msg)),
+                }
+            }
+
+            if list.is_empty() {
+                div { class: "text-gray-500 italic text-xs", "Nothing to hide" }
+            } else {
+                div {
+                    class: "flex flex-col gap-2",
+                    for secret in list {
+                        SecretRow {
+                            key: "{secret.id}",
+                            world_id: props.world_id.clone(),
+                            secret: secret.clone(),
+                            on_changed: move |updated: PcSecretData| {
+                                if let Some(slot) = secrets.write().iter_mut().find(|s| s.id == updated.id) {
+                                    *slot = updated;
+                                }
+                            },
+                            on_deleted: move |id: String| secrets.write().retain(|s| s.id != id),
+                            on_error: move |msg| error.set(Some(msg)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SecretRowProps {
+    world_id: String,
+    secret: PcSecretData,
+    on_changed: EventHandler<PcSecretData>,
+    on_deleted: EventHandler<String>,
+    on_error: EventHandler<String>,
+}
+
+/// A single secret with its NPC reactions
+#[component]
+fn SecretRow(props: SecretRowProps) -> Element {
+    let secret_service = use_secret_service();
+    let secret = props.secret.clone();
+    let mut editing = use_signal(|| false);
+    let mut confirm_reveal = use_signal(|| false);
+
+    let reveal = {
+        let service = secret_service.clone();
+        let secret_id = secret.id.clone();
+        let on_changed = props.on_changed;
+        let on_error = props.on_error;
+        move |_| {
+            let service = service.clone();
+            let secret_id = secret_id.clone();
+            confirm_reveal.set(false);
+            spawn_task(async move {
+                match service.reveal_secret(&secret_id).await {
+                    Ok(updated) => on_changed.call(updated),
+                    Err(e) => on_error.call(format!("Failed to reveal secret: {}", e)),
+                }
+            });
+        }
+    };
+
+    let delete = {
+        let service = secret_service.clone();
+        let secret_id = secret.id.clone();
+        let on_deleted = props.on_deleted;
+        let on_error = props.on_error;
+        move |_| {
+            let service = service.clone();
+            let secret_id = secret_id.clone();
+            spawn_task(async move {
+                match service.delete_secret(&secret_id).await {
+                    Ok(()) => on_deleted.call(secret_id),
+                    Err(e) => on_error.call(format!("Failed to remove secret: {}", e)),
+                }
+            });
+        }
+    };
+
+    let revealed = secret.revealed_at.is_some();
+    let on_changed = props.on_changed;
+
+    rsx! {
+        div {
+            class: if revealed { "p-2 bg-dark-surface rounded opacity-60" } else { "p-2 bg-dark-surface rounded" },
+
+            div {
+                class: "flex items-center justify-between gap-2",
+                span { class: "text-white text-sm truncate", "{secret.title}" }
+                div {
+                    class: "flex items-center gap-1",
+                    if revealed {
+                        span { class: "text-amber-400 text-xs", "Revealed" }
+                    } else {
+                        button {
+                            onclick: move |_| {
+                                let open = *editing.read();
+                                editing.set(!open);
+                            },
+                            class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                            if *editing.read() { "Cancel" } else { "Edit" }
+                        }
+                        if *confirm_reveal.read() {
+                            button {
+                                onclick: reveal,
+                                class: "px-2 py-0.5 bg-red-700 text-white text-xs rounded cursor-pointer",
+                                "Reveal to all"
+                            }
+                        } else {
+                            button {
+                                onclick: move |_| confirm_reveal.set(true),
+                                class: "px-2 py-0.5 bg-amber-700 text-white text-xs rounded cursor-pointer",
+                                "Reveal"
+                            }
+                        }
+                    }
+                    button {
+                        onclick: delete,
+                        class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer",
+                        "Remove"
+                    }
+                }
+            }
+
+            if *editing.read() {
+                SecretForm {
+                    world_id: props.world_id.clone(),
+                    pc_id: secret.pc_id.clone(),
+                    existing: Some(secret.clone()),
+                    on_saved: move |updated: PcSecretData| {
+                        editing.set(false);
+                        on_changed.call(updated);
+                    },
+                    on_error: props.on_error,
+                }
+            } else {
+                if !secret.content.is_empty() {
+                    div { class: "text-gray-300 text-xs mt-1 whitespace-pre-wrap", "{secret.content}" }
+                }
+                if !secret.reactions.is_empty() {
+                    div {
+                        class: "flex flex-wrap gap-2 mt-1 text-xs",
+                        for reaction in secret.reactions.iter() {
+                            ReactionBadge { key: "{reaction.npc_id}", reaction: reaction.clone() }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ReactionBadgeProps {
+    reaction: SecretReactionData,
+}
+
+/// How one NPC will take (or took) the secret
+#[component]
+fn ReactionBadge(props: ReactionBadgeProps) -> Element {
+    let name = props
+        .reaction
+        .npc_name
+        .clone()
+        .unwrap_or_else(|| "Unknown NPC".to_string());
+    let change = format_change(props.reaction.sentiment_change);
+    let class = if props.reaction.sentiment_change < 0.0 {
+        "text-red-400"
+    } else {
+        "text-green-400"
+    };
+
+    rsx! {
+        span { class: "{class}", "{name} {change}" }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SecretFormProps {
+    world_id: String,
+    pc_id: String,
+    /// The secret being edited; a new one is written when absent
+    existing: Option<PcSecretData>,
+    on_saved: EventHandler<PcSecretData>,
+    on_error: EventHandler<String>,
+}
+
+/// Inline form for writing or editing a secret and its NPC reactions
+#[component]
+fn SecretForm(props: SecretFormProps) -> Element {
+    let secret_service = use_secret_service();
+    let character_service = use_character_service();
+    let mut npcs: Signal<Vec<CharacterSummary>> = use_signal(Vec::new);
+    let (initial_title, initial_content, initial_reactions) = match &props.existing {
+        Some(s) => (s.title.clone(), s.content.clone(), s.reactions.clone()),
+        None => Default::default(),
+    };
+    let mut title = use_signal(move || initial_title);
+    let mut content = use_signal(move || initial_content);
+    let mut reactions: Signal<Vec<SecretReactionData>> = use_signal(move || initial_reactions);
+    let mut npc_id = use_signal(String::new);
+    let mut change = use_signal(|| -25i32);
+
+    // Load the NPC picker on mount
+    {
+        let world_id = props.world_id.clone();
+        let on_error = props.on_error;
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let chars = character_service.clone();
+            spawn_task(async move {
+                match chars.list_characters(&world_id).await {
+                    Ok(list) => npcs.set(list),
+                    Err(e) => on_error.call(format!("Failed to load NPCs: {}", e)),
+                }
+            });
+        });
+    }
+
+    let add_reaction = move |_| {
+        let id = npc_id.read().clone();
+        if id.is_empty() || reactions.read().iter().any(|r| r.npc_id == id) {
+            return;
+        }
+        let npc_name = npcs
+            .read()
+            .iter()
+            .find(|npc| npc.id == id)
+            .map(|npc| npc.name.clone());
+        reactions.write().push(SecretReactionData {
+            npc_id: id,
+            npc_name,
+            sentiment_change: *change.read() as f32 / 100.0,
+        });
+        npc_id.set(String::new());
+    };
+
+    let is_editing = props.existing.is_some();
+    let handle_save = move |_| {
+        let secret_title = title.read().trim().to_string();
+        if secret_title.is_empty() {
+            return;
+        }
+        let data = PcSecretInputData {
+            title: Some(secret_title),
+            content: Some(content.read().clone()),
+            reactions: Some(reactions.read().clone()),
+        };
+        let service = secret_service.clone();
+        let pc_id = props.pc_id.clone();
+        let existing_id = props.existing.as_ref().map(|s| s.id.clone());
+        let on_saved = props.on_saved;
+        let on_error = props.on_error;
+        spawn_task(async move {
+            let result = match existing_id {
+                Some(secret_id) => service.update_secret(&secret_id, data).await,
+                None => service.create_secret(&pc_id, data).await,
+            };
+            match result {
+                Ok(secret) => on_saved.call(secret),
+                Err(e) => on_error.call(format!("Failed to save secret: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 mb-2 p-2 bg-dark-surface rounded",
+
+            input {
+                r#type: "text",
+                value: "{title}",
+                placeholder: "Sold out the rebels",
+                oninput: move |e| title.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+            textarea {
+                value: "{content}",
+                placeholder: "What the player knows and nobody else does",
+                rows: "3",
+                oninput: move |e| content.set(e.value()),
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+
+            div { class: "text-gray-400 text-xs", "When it comes out" }
+            if !reactions.read().is_empty() {
+                div {
+                    class: "flex flex-col gap-1",
+                    for reaction in reactions.read().iter().cloned() {
+                        div {
+                            key: "{reaction.npc_id}",
+                            class: "flex items-center justify-between text-xs",
+                            ReactionBadge { reaction: reaction.clone() }
+                            button {
+                                onclick: move |_| reactions.write().retain(|r| r.npc_id != reaction.npc_id),
+                                class: "px-1 bg-transparent text-gray-400 border-none cursor-pointer",
+                                "×"
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "flex items-center gap-2",
+                select {
+                    value: "{npc_id}",
+                    onchange: move |e| npc_id.set(e.value()),
+                    class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    option { value: "", "Choose NPC..." }
+                    for npc in npcs.read().iter() {
+                        option { key: "{npc.id}", value: "{npc.id}", "{npc.name}" }
+                    }
+                }
+                input {
+                    r#type: "number",
+                    min: "-100",
+                    max: "100",
+                    step: "5",
+                    value: "{change}",
+                    title: "Change in how the NPC feels about the PC, in percent",
+                    oninput: move |e| change.set(e.value().parse::<i32>().unwrap_or(0).clamp(-100, 100)),
+                    class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+                button {
+                    onclick: add_reaction,
+                    disabled: npc_id.read().is_empty(),
+                    class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                    "Add"
+                }
+            }
+
+            button {
+                onclick: handle_save,
+                disabled: title.read().trim().is_empty(),
+                class: "self-end px-3 py-1 bg-purple-700 text-white text-sm rounded cursor-pointer",
+                if is_editing { "Save" } else { "Keep secret" }
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! Spotlight Panel for DM
+//!
+//! Turn rotation for narrative systems without initiative:
+//! - The PCs in rotation order, longest without acting first
+//! - Warnings for PCs who have sat out too many actions
+//! - Passing the spotlight on, or handing it to a PC
+//! - Settings for the quiet warning and for throttling back-to-back
+//!   actions in crowded scenes
+
+use dioxus::prelude::*;
+
+use crate::application::dto::SpotlightSettingsData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_spotlight_service;
+use crate::presentation::state::use_game_state;
+
+/// Spotlight Panel component for DM view
+#[component]
+pub fn SpotlightPanel() -> Element {
+    let service = use_spotlight_service();
+    let game_state = use_game_state();
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load the tracker on mount
+    {
+        let service = service.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let mut game_state = game_state.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.get_spotlight().await {
+                    Ok(spotlight) => game_state.set_spotlight(spotlight),
+                    Err(e) => error.set(Some(format!("Failed to load the spotlight: {}", e))),
+                }
+            });
+        });
+    }
+
+    // The engine broadcasts SpotlightChanged, which updates the game state
+    let set_spotlight = {
+        let service = service.clone();
+        move |pc_id: Option<String>| {
+            let service = service.clone();
+            error.set(None);
+            spawn_task(async move {
+                if let Err(e) = service.set_spotlight(pc_id).await {
+                    error.set(Some(format!("Failed to move the spotlight: {}", e)));
+                }
+            });
+        }
+    };
+
+    let rotate = {
+        let service = service.clone();
+        move |_| {
+            let service = service.clone();
+            error.set(None);
+            spawn_task(async move {
+                if let Err(e) = service.rotate().await {
+                    error.set(Some(format!("Failed to pass the spotlight: {}", e)));
+                }
+            });
+        }
+    };
+
+    let reset = {
+        let service = service.clone();
+        move |_| {
+            let service = service.clone();
+            error.set(None);
+            spawn_task(async move {
+                if let Err(e) = service.reset().await {
+                    error.set(Some(format!("Failed to reset the spotlight: {}", e)));
+                }
+            });
+        }
+    };
+
+    let Some(spotlight) = game_state.spotlight.read().clone() else {
+        return rsx! {};
+    };
+    let enabled = spotlight.settings.enabled;
+    let focus_pc_id = spotlight.focus_pc_id.clone();
+    let quiet = spotlight
+        .rotation
+        .iter()
+        .filter(|pc| pc.is_quiet)
+        .map(|pc| pc.name.clone())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    rsx! {
+        div {
+            class: "spotlight-panel bg-dark-surface rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center mb-3",
+                h3 { class: "text-gray-400 text-sm uppercase m-0", "Spotlight" }
+                if enabled {
+                    div {
+                        class: "flex gap-1",
+                        button {
+                            onclick: rotate,
+                            class: "px-2 py-1 bg-amber-700 text-white text-xs rounded cursor-pointer",
+                            "Next"
+                        }
+                        button {
+                            onclick: reset,
+                            class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                            "Reset"
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            if enabled && !quiet.is_empty() {
+                div {
+                    class: "text-amber-400 text-xs mb-2",
+                    "Hasn't acted in a while: {quiet}"
+                }
+            }
+
+            if enabled {
+                div {
+                    class: "flex flex-col gap-1 mb-3",
+                    for pc in spotlight.rotation.iter() {
+                        div {
+                            key: "{pc.pc_id}",
+                            class: if focus_pc_id.as_deref() == Some(pc.pc_id.as_str()) {
+                                "flex justify-between items-center px-2 py-1 bg-amber-900 rounded"
+                            } else {
+                                "flex justify-between items-center px-2 py-1 bg-dark-bg rounded"
+                            },
+                            div {
+                                class: "flex flex-col",
+                                span { class: "text-white text-sm", "{pc.name}" }
+                                span {
+                                    class: if pc.is_quiet { "text-amber-400 text-xs" } else { "text-gray-500 text-xs" },
+                                    "{pc.actions_since} actions since their last"
+                                }
+                            }
+                            if focus_pc_id.as_deref() == Some(pc.pc_id.as_str()) {
+                                button {
+                                    onclick: {
+                                        let mut set_spotlight = set_spotlight.clone();
+                                        move |_| set_spotlight(None)
+                                    },
+                                    class: "px-1.5 py-0.5 bg-gray-700 text-white text-xs rounded cursor-pointer",
+                                    "Clear"
+                                }
+                            } else {
+                                button {
+                                    onclick: {
+                                        let mut set_spotlight = set_spotlight.clone();
+                                        let id = pc.pc_id.clone();
+                                        move |_| set_spotlight(Some(id.clone()))
+                                    },
+                                    class: "px-1.5 py-0.5 bg-amber-700 text-white text-xs rounded cursor-pointer",
+                                    "Spotlight"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            SpotlightSettingsForm {
+                key: "{enabled}-{spotlight.settings.quiet_after}-{spotlight.settings.throttle_crowd}",
+                settings: spotlight.settings.clone(),
+                on_error: move |msg| error.set(Some(msg)),
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SpotlightSettingsFormProps {
+    settings: SpotlightSettingsData,
+    on_error: EventHandler<String>,
+}
+
+/// Whether the tracker runs and how strict it is
+#[component]
+fn SpotlightSettingsForm(props: SpotlightSettingsFormProps) -> Element {
+    let service = use_spotlight_service();
+    let mut enabled = use_signal(|| props.settings.enabled);
+    let mut quiet_after = use_signal(|| props.settings.quiet_after);
+    let mut throttle_crowd = use_signal(|| props.settings.throttle_crowd);
+    let mut saving = use_signal(|| false);
+
+    let save = {
+        let service = service.clone();
+        let on_error = props.on_error;
+        move |_| {
+            let data = SpotlightSettingsData {
+                enabled: *enabled.read(),
+                quiet_after: *quiet_after.read(),
+                throttle_crowd: *throttle_crowd.read(),
+            };
+            let service = service.clone();
+            saving.set(true);
+            spawn_task(async move {
+                if let Err(e) = service.update_settings(data).await {
+                    on_error.call(format!("Failed to save spotlight settings: {}", e));
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 border-t border-gray-700 pt-3",
+
+            label {
+                class: "flex items-center gap-1 text-white text-xs",
+                input {
+                    r#type: "checkbox",
+                    checked: *enabled.read(),
+                    onchange: move |e: Event<FormData>| enabled.set(e.checked()),
+                }
+                "Track the spotlight"
+            }
+
+            label {
+                class: "flex items-center justify-between gap-2 text-gray-400 text-xs",
+                "Warn after this many actions by others"
+                input {
+                    r#type: "number",
+                    min: "1",
+                    value: "{quiet_after}",
+                    oninput: move |e| {
+                        if let Ok(n) = e.value().parse() {
+                            quiet_after.set(n);
+                        }
+                    },
+                    class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                }
+            }
+
+            label {
+                class: "flex items-center justify-between gap-2 text-gray-400 text-xs",
+                "No back-to-back actions with this many PCs present (0 = off)"
+                input {
+                    r#type: "number",
+                    min: "0",
+                    value: "{throttle_crowd}",
+                    oninput: move |e| {
+                        if let Ok(n) = e.value().parse() {
+                            throttle_crowd.set(n);
+                        }
+                    },
+                    class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                }
+            }
+
+            button {
+                onclick: save,
+                disabled: *saving.read(),
+                class: "self-start px-2 py-1 bg-amber-700 text-white text-xs rounded cursor-pointer disabled:opacity-50",
+                "Save settings"
+            }
+        }
+    }
+}
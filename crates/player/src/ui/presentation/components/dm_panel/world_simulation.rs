@@ -0,0 +1,248 @@
+//! World Simulation Panel for DM
+//!
+//! Lists what the world got up to during time skips of a day or more and
+//! provides controls for:
+//! - Dropping proposed changes or adjusting clock segments and prices
+//! - Accepting the rest, which applies them and notes them on the timeline
+//! - Discarding a simulation, leaving the world as it was
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{SimulationChangeData, WorldSimulationData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_simulation_service;
+use crate::presentation::state::use_game_state;
+
+/// One line describing a change as it currently stands
+fn describe(change: &SimulationChangeData) -> String {
+    match change {
+        SimulationChangeData::ClockAdvanced {
+            clock_name,
+            segments,
+            ..
+        } => format!("{} advances {} segment(s)", clock_name, segments),
+        SimulationChangeData::NpcMoved {
+            npc_name,
+            region_name,
+            ..
+        } => format!("{} goes to {}", npc_name, region_name),
+        SimulationChangeData::RumorSpread {
+            lore_title,
+            npc_name,
+            told_by_name,
+            ..
+        } => format!(
+            "{} hears about \"{}\" from {}",
+            npc_name, lore_title, told_by_name
+        ),
+        SimulationChangeData::PriceDrift {
+            reason,
+            from_percent,
+            to_percent,
+            ..
+        } => format!(
+            "Prices ({}) {:+}% → {:+}%",
+            reason, from_percent, to_percent
+        ),
+        SimulationChangeData::Unknown => "Unknown change".to_string(),
+    }
+}
+
+/// World Simulation Panel component for DM view
+#[component]
+pub fn WorldSimulationPanel() -> Element {
+    let simulation_service = use_simulation_service();
+    let game_state = use_game_state();
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load pending simulations on mount
+    {
+        let service = simulation_service.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let mut game_state = game_state.clone();
+            let service = service.clone();
+            spawn_task(async move {
+                match service.list_simulations().await {
+                    Ok(list) => game_state.set_world_simulations(list),
+                    Err(e) => error.set(Some(format!("Failed to load simulations: {}", e))),
+                }
+            });
+        });
+    }
+
+    let list = game_state.world_simulations.read().clone();
+
+    if list.is_empty() && error.read().is_none() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "world-simulation-panel bg-dark-surface rounded-lg p-4",
+
+            h3 { class: "text-gray-400 text-sm uppercase m-0 mb-3", "While time passed" }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-400 text-xs mb-2", "{err}" }
+            }
+
+            div {
+                class: "flex flex-col gap-2",
+                for simulation in list {
+                    SimulationCard {
+                        key: "{simulation.id}",
+                        simulation: simulation.clone(),
+                        on_error: move |msg| error.set(Some(msg)),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SimulationCardProps {
+    simulation: WorldSimulationData,
+    on_error: EventHandler<String>,
+}
+
+/// One simulation's changes with the DM's edits
+#[component]
+fn SimulationCard(props: SimulationCardProps) -> Element {
+    let simulation_service = use_simulation_service();
+    let game_state = use_game_state();
+    let initial_changes = props.simulation.changes.clone();
+    let mut changes: Signal<Vec<SimulationChangeData>> = use_signal(move || initial_changes);
+    let mut busy = use_signal(|| false);
+
+    let accept = {
+        let service = simulation_service.clone();
+        let game_state = game_state.clone();
+        let simulation_id = props.simulation.id.clone();
+        let proposed = props.simulation.changes.clone();
+        let on_error = props.on_error;
+        move |_| {
+            let mut game_state = game_state.clone();
+            let service = service.clone();
+            let simulation_id = simulation_id.clone();
+            let edited = changes.read().clone();
+            let dirty = edited != proposed;
+            busy.set(true);
+            spawn_task(async move {
+                if dirty {
+                    if let Err(e) = service.update_simulation(&simulation_id, edited).await {
+                        on_error.call(format!("Failed to save changes: {}", e));
+                        busy.set(false);
+                        return;
+                    }
+                }
+                match service.accept_simulation(&simulation_id).await {
+                    Ok(_) => game_state.remove_world_simulation(&simulation_id),
+                    Err(e) => on_error.call(format!("Failed to accept simulation: {}", e)),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let discard = {
+        let service = simulation_service.clone();
+        let game_state = game_state.clone();
+        let simulation_id = props.simulation.id.clone();
+        let on_error = props.on_error;
+        move |_| {
+            let mut game_state = game_state.clone();
+            let service = service.clone();
+            let simulation_id = simulation_id.clone();
+            busy.set(true);
+            spawn_task(async move {
+                match service.discard_simulation(&simulation_id).await {
+                    Ok(()) => game_state.remove_world_simulation(&simulation_id),
+                    Err(e) => on_error.call(format!("Failed to discard simulation: {}", e)),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let count = changes.read().len();
+    let days = props.simulation.days;
+
+    rsx! {
+        div {
+            class: "p-2 bg-dark-bg rounded",
+
+            div {
+                class: "flex items-center justify-between gap-2 mb-1",
+                span { class: "text-white text-sm", "{days} day(s) off-screen" }
+                div {
+                    class: "flex items-center gap-1",
+                    button {
+                        onclick: accept,
+                        disabled: *busy.read(),
+                        class: "px-2 py-0.5 bg-green-700 text-white text-xs rounded cursor-pointer disabled:opacity-50",
+                        "Accept"
+                    }
+                    button {
+                        onclick: discard,
+                        disabled: *busy.read(),
+                        class: "px-2 py-0.5 bg-transparent text-gray-400 text-xs border border-gray-700 rounded cursor-pointer disabled:opacity-50",
+                        "Discard"
+                    }
+                }
+            }
+
+            if count == 0 {
+                div { class: "text-gray-500 italic text-xs", "Every change dropped" }
+            }
+
+            for i in 0..count {
+                div {
+                    key: "{i}",
+                    class: "flex items-center gap-1",
+                    span { class: "flex-1 text-gray-300 text-xs", "{describe(&changes.read()[i])}" }
+                    match changes.read()[i].clone() {
+                        SimulationChangeData::ClockAdvanced { segments, .. } => rsx! {
+                            input {
+                                r#type: "number",
+                                min: "1",
+                                value: "{segments}",
+                                oninput: move |e| {
+                                    if let Ok(value) = e.value().parse::<u8>() {
+                                        if let SimulationChangeData::ClockAdvanced { segments, .. } = &mut changes.write()[i] {
+                                            *segments = value.max(1);
+                                        }
+                                    }
+                                },
+                                class: "w-12 p-0.5 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                            }
+                        },
+                        SimulationChangeData::PriceDrift { to_percent, .. } => rsx! {
+                            input {
+                                r#type: "number",
+                                value: "{to_percent}",
+                                oninput: move |e| {
+                                    if let Ok(value) = e.value().parse::<i32>() {
+                                        if let SimulationChangeData::PriceDrift { to_percent, .. } = &mut changes.write()[i] {
+                                            *to_percent = value;
+                                        }
+                                    }
+                                },
+                                class: "w-14 p-0.5 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                            }
+                        },
+                        _ => rsx! {},
+                    }
+                    button {
+                        onclick: move |_| {
+                            changes.write().remove(i);
+                        },
+                        class: "px-1 bg-transparent text-gray-400 border-none cursor-pointer",
+                        "×"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -2,10 +2,16 @@
 //!
 //! US-NPC-008: ApproachEventOverlay - NPC approaching player
 //! US-NPC-009: LocationEventBanner - Location-wide events
+//! HandoutOverlay - DM-shown maps, letters, and cutscene art
+//! BroadsheetOverlay - The world's newly published broadsheet
 
 use dioxus::prelude::*;
 
-use crate::presentation::state::{ApproachEventData, LocationEventData};
+use crate::application::dto::BroadsheetData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::state::{ApproachEventData, LocationEventData, OverlayData};
+use crate::presentation::utils::focus_mounted;
+use crate::use_platform;
 
 // =============================================================================
 // US-NPC-008: Approach Event Overlay
@@ -164,3 +170,146 @@ pub fn LocationEventBanner(props: LocationEventBannerProps) -> Element {
         }
     }
 }
+
+// =============================================================================
+// Handout Overlay
+// =============================================================================
+
+/// Props for HandoutOverlay
+#[derive(Props, Clone, PartialEq)]
+pub struct HandoutOverlayProps {
+    /// The handout being shown
+    pub overlay: OverlayData,
+    /// Called with the overlay ID when the player closes it or its time runs out
+    pub on_dismiss: EventHandler<String>,
+}
+
+/// Full-screen handout shown by the DM
+///
+/// Covers the whole view with the image and optional caption. Closes on
+/// click, on Escape, or after the DM-set duration.
+#[component]
+pub fn HandoutOverlay(props: HandoutOverlayProps) -> Element {
+    let platform = use_platform();
+
+    // Restart the timer whenever the DM replaces the handout
+    {
+        let overlay_id = props.overlay.overlay_id.clone();
+        let duration_secs = props.overlay.duration_secs;
+        let on_dismiss = props.on_dismiss;
+        use_effect(use_reactive!(|(overlay_id, duration_secs)| {
+            let Some(secs) = duration_secs else {
+                return;
+            };
+            let platform = platform.clone();
+            let overlay_id = overlay_id.clone();
+            spawn_task(async move {
+                platform.sleep_ms(u64::from(secs) * 1000).await;
+                on_dismiss.call(overlay_id);
+            });
+        }));
+    }
+
+    let overlay_id = props.overlay.overlay_id.clone();
+    let key_overlay_id = overlay_id.clone();
+    let caption = props.overlay.caption.clone().unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "handout-overlay fixed inset-0 bg-black/95 z-[1200] flex flex-col items-center justify-center p-6 cursor-pointer animate-fade-in",
+            tabindex: "0",
+            onmounted: move |e| focus_mounted(e.data()),
+            onclick: move |_| props.on_dismiss.call(overlay_id.clone()),
+            onkeydown: move |e| {
+                if e.key() == Key::Escape {
+                    props.on_dismiss.call(key_overlay_id.clone());
+                }
+            },
+
+            img {
+                src: "{props.overlay.asset}",
+                alt: "{caption}",
+                class: "max-w-full max-h-[85vh] object-contain rounded-lg shadow-2xl",
+            }
+
+            if !caption.is_empty() {
+                p {
+                    class: "text-gray-100 text-lg italic text-center max-w-3xl mt-4 mb-0",
+                    "{caption}"
+                }
+            }
+
+            p {
+                class: "text-gray-500 text-xs text-center mt-4 m-0",
+                "Click anywhere to close"
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Broadsheet Overlay
+// =============================================================================
+
+/// Props for BroadsheetOverlay
+#[derive(Props, Clone, PartialEq)]
+pub struct BroadsheetOverlayProps {
+    /// The published issue
+    pub broadsheet: BroadsheetData,
+    /// Handler for closing the overlay
+    pub on_dismiss: EventHandler<()>,
+}
+
+/// Full-screen broadsheet handed out when the DM publishes an issue
+///
+/// Shows the title and every article. Closes on the Close button or on
+/// Escape; unlike a picture handout it scrolls, so clicks do not close it.
+#[component]
+pub fn BroadsheetOverlay(props: BroadsheetOverlayProps) -> Element {
+    let issue = props.broadsheet.clone();
+
+    rsx! {
+        div {
+            class: "broadsheet-overlay fixed inset-0 bg-black/90 z-[1200] flex items-center justify-center p-6 animate-fade-in",
+            tabindex: "0",
+            onmounted: move |e| focus_mounted(e.data()),
+            onkeydown: move |e| {
+                if e.key() == Key::Escape {
+                    props.on_dismiss.call(());
+                }
+            },
+
+            div {
+                class: "bg-amber-50 text-gray-900 rounded-lg shadow-2xl max-w-3xl w-full max-h-[85vh] overflow-y-auto p-8",
+
+                div {
+                    class: "text-center border-b-2 border-gray-900 pb-3 mb-4",
+                    h2 { class: "font-serif text-3xl m-0", "{issue.title}" }
+                    p { class: "text-xs uppercase tracking-widest mt-2 mb-0", "Issue {issue.issue_number}" }
+                }
+
+                div {
+                    class: "flex flex-col gap-5",
+                    for (i, article) in issue.articles.iter().enumerate() {
+                        div {
+                            key: "{i}",
+                            h3 { class: "font-serif text-xl m-0 mb-1", "{article.headline}" }
+                            if !article.body.is_empty() {
+                                p { class: "text-sm leading-relaxed whitespace-pre-wrap m-0", "{article.body}" }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex justify-end mt-6",
+                    button {
+                        onclick: move |_| props.on_dismiss.call(()),
+                        class: "px-4 py-2 bg-gray-900 text-white text-sm rounded cursor-pointer",
+                        "Close"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -5,6 +5,7 @@
 use dioxus::prelude::*;
 
 use crate::application::dto::InventoryItemData;
+use crate::presentation::components::trade_panel::{TradePartner, TRADE_ITEM_DRAG_FORMAT};
 
 /// Props for the InventoryPanel component
 #[derive(Props, Clone, PartialEq)]
@@ -27,6 +28,12 @@ pub struct InventoryPanelProps {
     /// Handler for dropping an item
     #[props(default)]
     pub on_drop_item: Option<EventHandler<String>>,
+    /// Other PCs in the region who can be traded with
+    #[props(default)]
+    pub trade_partners: Vec<TradePartner>,
+    /// Handler for starting a trade: (partner PC ID, item dragged onto them)
+    #[props(default)]
+    pub on_trade: Option<EventHandler<(String, Option<String>)>>,
 }
 
 /// Inventory Panel - modal overlay showing character inventory
@@ -127,6 +134,7 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
                                     on_use: props.on_use_item,
                                     on_toggle_equip: props.on_toggle_equip,
                                     on_drop: props.on_drop_item,
+                                    tradeable: props.on_trade.is_some(),
                                 }
                             }
 
@@ -139,6 +147,7 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
                                     on_use: props.on_use_item,
                                     on_toggle_equip: props.on_toggle_equip,
                                     on_drop: props.on_drop_item,
+                                    tradeable: props.on_trade.is_some(),
                                 }
                             }
 
@@ -151,6 +160,7 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
                                     on_use: props.on_use_item,
                                     on_toggle_equip: props.on_toggle_equip,
                                     on_drop: props.on_drop_item,
+                                    tradeable: props.on_trade.is_some(),
                                 }
                             }
 
@@ -163,6 +173,7 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
                                     on_use: props.on_use_item,
                                     on_toggle_equip: props.on_toggle_equip,
                                     on_drop: props.on_drop_item,
+                                    tradeable: props.on_trade.is_some(),
                                 }
                             }
 
@@ -175,11 +186,75 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
                                     on_use: props.on_use_item,
                                     on_toggle_equip: props.on_toggle_equip,
                                     on_drop: props.on_drop_item,
+                                    tradeable: props.on_trade.is_some(),
                                 }
                             }
                         }
                     }
                 }
+
+                // Trade partners (drop targets)
+                if let Some(on_trade) = props.on_trade {
+                    if !props.trade_partners.is_empty() {
+                        TradePartnerStrip {
+                            partners: props.trade_partners.clone(),
+                            on_trade,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Other PCs in the region; drop an item on one (or click it) to start a trade
+#[component]
+fn TradePartnerStrip(
+    partners: Vec<TradePartner>,
+    on_trade: EventHandler<(String, Option<String>)>,
+) -> Element {
+    let mut drop_target: Signal<Option<String>> = use_signal(|| None);
+
+    rsx! {
+        div {
+            class: "p-4 border-t border-white/10",
+            p {
+                class: "text-xs text-gray-500 m-0 mb-2",
+                "Drag an item onto someone to offer a trade"
+            }
+            div {
+                class: "flex flex-wrap gap-2",
+                for partner in partners {
+                    {
+                        let id = partner.pc_id.clone();
+                        let is_target = drop_target.read().as_deref() == Some(id.as_str());
+                        let id_over = id.clone();
+                        let id_drop = id.clone();
+                        rsx! {
+                            button {
+                                key: "{id}",
+                                class: if is_target {
+                                    "px-3 py-2 rounded-lg text-sm border border-amber-400 bg-amber-500/20 text-amber-300"
+                                } else {
+                                    "px-3 py-2 rounded-lg text-sm border border-white/10 bg-white/5 text-gray-300 hover:bg-white/10"
+                                },
+                                ondragover: move |e| {
+                                    e.prevent_default();
+                                    drop_target.set(Some(id_over.clone()));
+                                },
+                                ondragleave: move |_| drop_target.set(None),
+                                ondrop: move |e| {
+                                    e.prevent_default();
+                                    drop_target.set(None);
+                                    let item_id = e.data().data_transfer().get_data(TRADE_ITEM_DRAG_FORMAT);
+                                    on_trade.call((id_drop.clone(), item_id));
+                                },
+                                onclick: move |_| on_trade.call((id.clone(), None)),
+                                "{partner.name}"
+                            }
+                        }
+                    }
+                }
             }
         }
     }
@@ -194,6 +269,7 @@ struct InventorySectionProps {
     on_use: Option<EventHandler<String>>,
     on_toggle_equip: Option<EventHandler<String>>,
     on_drop: Option<EventHandler<String>>,
+    tradeable: bool,
 }
 
 /// A section of the inventory (e.g., Weapons, Consumables)
@@ -225,6 +301,7 @@ fn InventorySection(props: InventorySectionProps) -> Element {
                         on_use: props.on_use,
                         on_toggle_equip: props.on_toggle_equip,
                         on_drop: props.on_drop,
+                        tradeable: props.tradeable,
                     }
                 }
             }
@@ -239,6 +316,7 @@ struct InventoryItemCardProps {
     on_use: Option<EventHandler<String>>,
     on_toggle_equip: Option<EventHandler<String>>,
     on_drop: Option<EventHandler<String>>,
+    tradeable: bool,
 }
 
 /// Card displaying a single inventory item
@@ -253,10 +331,15 @@ fn InventoryItemCard(props: InventoryItemCardProps) -> Element {
     };
 
     let item_id = props.item.item.id.clone();
+    let drag_id = item_id.clone();
 
     rsx! {
         div {
             class: "inventory-item bg-black/30 rounded-lg border {border_class} overflow-hidden",
+            draggable: props.tradeable,
+            ondragstart: move |e| {
+                let _ = e.data().data_transfer().set_data(TRADE_ITEM_DRAG_FORMAT, &drag_id);
+            },
 
             // Item header (always visible)
             button {
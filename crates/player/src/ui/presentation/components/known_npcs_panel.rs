@@ -43,6 +43,10 @@ pub struct KnownNpcsPanelProps {
     /// Handler for clicking an NPC (to view details or interact)
     #[props(default)]
     pub on_npc_click: Option<EventHandler<String>>,
+    /// Handler for telling the rest of the party where an NPC was seen
+    /// (None when there is no one to tell)
+    #[props(default)]
+    pub on_share: Option<EventHandler<String>>,
 }
 
 /// Known NPCs Panel - modal showing NPCs the player has observed
@@ -159,6 +163,7 @@ pub fn KnownNpcsPanel(props: KnownNpcsPanelProps) -> Element {
                                     icon_color: "text-blue-400",
                                     observations: direct_obs.into_iter().cloned().collect(),
                                     on_npc_click: props.on_npc_click,
+                                    on_share: props.on_share,
                                 }
                             }
 
@@ -170,6 +175,7 @@ pub fn KnownNpcsPanel(props: KnownNpcsPanelProps) -> Element {
                                     icon_color: "text-yellow-400",
                                     observations: heard_obs.into_iter().cloned().collect(),
                                     on_npc_click: props.on_npc_click,
+                                    on_share: props.on_share,
                                 }
                             }
 
@@ -181,6 +187,7 @@ pub fn KnownNpcsPanel(props: KnownNpcsPanelProps) -> Element {
                                     icon_color: "text-purple-400",
                                     observations: deduced_obs.into_iter().cloned().collect(),
                                     on_npc_click: props.on_npc_click,
+                                    on_share: props.on_share,
                                 }
                             }
                         }
@@ -199,6 +206,7 @@ struct ObservationSectionProps {
     icon_color: &'static str,
     observations: Vec<NpcObservationData>,
     on_npc_click: Option<EventHandler<String>>,
+    on_share: Option<EventHandler<String>>,
 }
 
 /// A section of observations grouped by type
@@ -228,6 +236,7 @@ fn ObservationSection(props: ObservationSectionProps) -> Element {
                         key: "{obs.npc_id}",
                         observation: obs.clone(),
                         on_click: props.on_npc_click,
+                        on_share: props.on_share,
                     }
                 }
             }
@@ -240,6 +249,7 @@ fn ObservationSection(props: ObservationSectionProps) -> Element {
 struct NpcObservationCardProps {
     observation: NpcObservationData,
     on_click: Option<EventHandler<String>>,
+    on_share: Option<EventHandler<String>>,
 }
 
 /// Card displaying a single NPC observation
@@ -331,9 +341,28 @@ fn NpcObservationCard(props: NpcObservationCardProps) -> Element {
 
                 // Time indicator - show formatted game time
                 div {
-                    class: "text-xs text-gray-500 shrink-0",
-                    title: "{props.observation.game_time}",
-                    {format_observation_time(&props.observation.game_time)}
+                    class: "flex flex-col items-end gap-2 shrink-0",
+
+                    span {
+                        class: "text-xs text-gray-500",
+                        title: "{props.observation.game_time}",
+                        {format_observation_time(&props.observation.game_time)}
+                    }
+
+                    if let Some(on_share) = props.on_share {
+                        button {
+                            class: "text-xs px-2 py-1 bg-purple-500/20 hover:bg-purple-500/30 text-purple-300 rounded border-none cursor-pointer",
+                            title: "Tell the rest of the party where they were",
+                            onclick: {
+                                let npc_id = props.observation.npc_id.clone();
+                                move |e: MouseEvent| {
+                                    e.stop_propagation();
+                                    on_share.call(npc_id.clone());
+                                }
+                            },
+                            "Tell party"
+                        }
+                    }
                 }
             }
         }
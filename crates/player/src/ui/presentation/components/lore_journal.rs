@@ -2,6 +2,7 @@
 //!
 //! US-LORE-005: Player can view their character's known lore in a journal/codex.
 //! US-LORE-006: Player sees partial lore entries when they only know some chunks.
+//! US-LORE-011: Player shares what their character knows with the party.
 
 use dioxus::prelude::*;
 
@@ -69,6 +70,10 @@ pub struct LoreJournalProps {
     /// Handler for clicking a lore entry (to view full details)
     #[props(default)]
     pub on_lore_click: Option<EventHandler<String>>,
+    /// Handler for telling the rest of the party what the character knows
+    /// of an entry (None when there is no one to tell)
+    #[props(default)]
+    pub on_share: Option<EventHandler<String>>,
 }
 
 /// Get icon for lore category
@@ -251,6 +256,7 @@ pub fn LoreJournal(props: LoreJournalProps) -> Element {
                                         }
                                     },
                                     on_click: props.on_lore_click,
+                                    on_share: props.on_share,
                                 }
                             }
                         }
@@ -285,6 +291,7 @@ struct LoreEntryCardProps {
     is_expanded: bool,
     on_toggle: EventHandler<()>,
     on_click: Option<EventHandler<String>>,
+    on_share: Option<EventHandler<String>>,
 }
 
 /// Card displaying a single lore entry
@@ -411,6 +418,16 @@ fn LoreEntryCard(props: LoreEntryCardProps) -> Element {
                         span {
                             "{props.entry.discovered_at}"
                         }
+                        if let Some(on_share) = props.on_share {
+                            button {
+                                class: "px-2 py-1 bg-indigo-500/20 hover:bg-indigo-500/30 text-indigo-300 rounded border-none cursor-pointer",
+                                onclick: {
+                                    let lore_id = props.entry.id.clone();
+                                    move |_| on_share.call(lore_id.clone())
+                                },
+                                "Tell party"
+                            }
+                        }
                     }
                 }
             }
@@ -0,0 +1,302 @@
+//! Mailbox card component
+//!
+//! Shows the letters the selected PC wrote and the ones that reached them,
+//! and lets the player write a new letter or answer one. Letters take game
+//! time to arrive, so a sent letter stays "on the road" until the DM's
+//! clock catches up with it.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{
+    CorrespondentData, CorrespondentKindData, LetterData, LetterInputData,
+};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_mail_service;
+use crate::presentation::state::use_game_state;
+
+/// Mail button with the selected PC's letters and a compose form
+#[component]
+pub fn MailboxCard() -> Element {
+    let mail_service = use_mail_service();
+    let game_state = use_game_state();
+    let mut is_open = use_signal(|| false);
+    let mut composing = use_signal(|| false);
+    let mut reply_to: Signal<Option<LetterData>> = use_signal(|| None);
+    let mut recipient_key = use_signal(String::new);
+    let mut subject = use_signal(String::new);
+    let mut body = use_signal(String::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Load the PC's mailbox whenever the selected PC changes; later changes
+    // arrive as LetterUpdated/LetterRemoved
+    {
+        let game_state = game_state.clone();
+        let mail_service = mail_service.clone();
+        use_effect(move || {
+            let Some(pc_id) = game_state.selected_pc_id.read().clone() else {
+                return;
+            };
+            let service = mail_service.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service.list_mailbox(&pc_id).await {
+                    Ok(letters) => game_state.set_letters(letters),
+                    Err(e) => tracing::warn!("Failed to load mailbox: {}", e),
+                }
+            });
+        });
+    }
+
+    let Some(pc_id) = game_state.selected_pc_id.read().clone() else {
+        return rsx! {};
+    };
+    let letters: Vec<_> = game_state
+        .letters
+        .read()
+        .iter()
+        .filter(|l| l.sender.id == pc_id || (l.recipient.id == pc_id && l.delivered))
+        .cloned()
+        .collect();
+    let unread = letters
+        .iter()
+        .filter(|l| l.recipient.id == pc_id && !l.read)
+        .count();
+
+    // Anyone in the scene, or anyone the PC has exchanged letters with
+    let mut recipients: Vec<CorrespondentData> = game_state
+        .npcs_present
+        .read()
+        .iter()
+        .map(|npc| CorrespondentData {
+            kind: CorrespondentKindData::Npc,
+            id: npc.character_id.clone(),
+            name: Some(npc.name.clone()),
+        })
+        .collect();
+    for letter in &letters {
+        for other in [&letter.sender, &letter.recipient] {
+            if other.id != pc_id && !recipients.iter().any(|r| r.id == other.id) {
+                recipients.push(other.clone());
+            }
+        }
+    }
+
+    let send = {
+        let mail_service = mail_service.clone();
+        let pc_id = pc_id.clone();
+        let recipients = recipients.clone();
+        move |_| {
+            let answering = reply_to.read().clone();
+            let recipient = match &answering {
+                Some(letter) => Some(letter.sender.clone()),
+                None => recipients
+                    .iter()
+                    .find(|r| r.id == *recipient_key.read())
+                    .cloned(),
+            };
+            let Some(recipient) = recipient else {
+                error.set(Some("Choose who to write to".to_string()));
+                return;
+            };
+            let data = LetterInputData {
+                recipient,
+                subject: subject.read().clone(),
+                body: body.read().clone(),
+                in_reply_to: answering.map(|l| l.id),
+            };
+            let service = mail_service.clone();
+            let pc_id = pc_id.clone();
+            spawn_task(async move {
+                match service.send_letter(&pc_id, data).await {
+                    Ok(_) => {
+                        composing.set(false);
+                        reply_to.set(None);
+                        subject.set(String::new());
+                        body.set(String::new());
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "mailbox-card flex flex-col items-end gap-1",
+
+            button {
+                onclick: move |_| {
+                    let open = *is_open.read();
+                    is_open.set(!open);
+                },
+                class: "px-3 py-1 bg-black/70 text-white border border-amber-600 rounded-lg text-xs cursor-pointer",
+                title: "Letters take game time to arrive",
+                if unread > 0 { "Mail ({unread} new)" } else { "Mail" }
+            }
+
+            if *is_open.read() {
+                div {
+                    class: "flex flex-col gap-2 p-2 bg-black/80 rounded-lg w-[300px] max-h-[400px] overflow-y-auto",
+
+                    if *composing.read() {
+                        div {
+                            class: "flex flex-col gap-1",
+                            if let Some(letter) = reply_to.read().clone() {
+                                span {
+                                    class: "text-gray-300 text-xs",
+                                    "Answering {correspondent_name(&letter.sender)}"
+                                }
+                            } else {
+                                select {
+                                    class: "p-1 bg-dark-bg text-white border border-gray-700 rounded text-xs",
+                                    value: "{recipient_key}",
+                                    onchange: move |e| recipient_key.set(e.value()),
+                                    option { value: "", "Write to..." }
+                                    for r in recipients.iter() {
+                                        option {
+                                            key: "{r.id}",
+                                            value: "{r.id}",
+                                            "{correspondent_name(r)}"
+                                        }
+                                    }
+                                }
+                            }
+                            input {
+                                class: "p-1 bg-dark-bg text-white border border-gray-700 rounded text-xs",
+                                placeholder: "Subject",
+                                value: "{subject}",
+                                oninput: move |e| subject.set(e.value()),
+                            }
+                            textarea {
+                                class: "p-1 bg-dark-bg text-white border border-gray-700 rounded text-xs min-h-[80px]",
+                                placeholder: "Your letter",
+                                value: "{body}",
+                                oninput: move |e| body.set(e.value()),
+                            }
+                            if let Some(err) = error.read().clone() {
+                                span { class: "text-red-400 text-xs", "{err}" }
+                            }
+                            div {
+                                class: "flex gap-1 justify-end",
+                                button {
+                                    class: "px-2 py-1 bg-gray-700 text-white border-0 rounded cursor-pointer text-xs",
+                                    onclick: move |_| {
+                                        composing.set(false);
+                                        reply_to.set(None);
+                                        error.set(None);
+                                    },
+                                    "Cancel"
+                                }
+                                button {
+                                    class: "px-2 py-1 bg-amber-600 text-white border-0 rounded cursor-pointer text-xs",
+                                    disabled: body.read().trim().is_empty(),
+                                    onclick: send,
+                                    "Send"
+                                }
+                            }
+                        }
+                    } else {
+                        button {
+                            class: "px-2 py-1 bg-amber-600 text-white border-0 rounded cursor-pointer text-xs self-start",
+                            onclick: move |_| composing.set(true),
+                            "Write a letter"
+                        }
+                    }
+
+                    if letters.is_empty() {
+                        span { class: "text-gray-400 text-xs", "No letters yet" }
+                    }
+                    for letter in letters {
+                        LetterRow {
+                            key: "{letter.id}",
+                            letter: letter.clone(),
+                            pc_id: pc_id.clone(),
+                            on_reply: move |letter: LetterData| {
+                                subject.set(reply_subject(&letter.subject));
+                                reply_to.set(Some(letter));
+                                composing.set(true);
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One letter in the mailbox; opening an unread letter marks it read
+#[component]
+fn LetterRow(letter: LetterData, pc_id: String, on_reply: EventHandler<LetterData>) -> Element {
+    let mail_service = use_mail_service();
+    let mut expanded = use_signal(|| false);
+
+    let incoming = letter.recipient.id == pc_id;
+    let unread = incoming && !letter.read;
+    let heading = if incoming {
+        format!("From {}", correspondent_name(&letter.sender))
+    } else {
+        format!("To {}", correspondent_name(&letter.recipient))
+    };
+    let subject = if letter.subject.is_empty() {
+        "(no subject)".to_string()
+    } else {
+        letter.subject.clone()
+    };
+    let letter_id = letter.id.clone();
+    let reply_letter = letter.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 border-b border-gray-700 pb-1",
+            div {
+                class: "flex items-center justify-between gap-2 cursor-pointer",
+                onclick: move |_| {
+                    let open = *expanded.read();
+                    expanded.set(!open);
+                    if !open && unread {
+                        let service = mail_service.clone();
+                        let letter_id = letter_id.clone();
+                        spawn_task(async move {
+                            if let Err(e) = service.mark_read(&letter_id).await {
+                                tracing::warn!("Failed to mark letter read: {}", e);
+                            }
+                        });
+                    }
+                },
+                span {
+                    class: if unread { "text-white text-xs font-semibold" } else { "text-gray-300 text-xs" },
+                    "{heading}: {subject}"
+                }
+                if !letter.delivered {
+                    span { class: "text-amber-400 text-xs", "On the road" }
+                }
+            }
+            if *expanded.read() {
+                div { class: "text-gray-200 text-xs whitespace-pre-wrap", "{letter.body}" }
+                if incoming {
+                    button {
+                        class: "px-2 py-1 bg-gray-700 text-white border-0 rounded cursor-pointer text-xs self-start",
+                        onclick: move |_| on_reply.call(reply_letter.clone()),
+                        "Reply"
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn correspondent_name(correspondent: &CorrespondentData) -> String {
+    correspondent
+        .name
+        .clone()
+        .unwrap_or_else(|| "someone".to_string())
+}
+
+fn reply_subject(subject: &str) -> String {
+    if subject.is_empty() || subject.starts_with("Re: ") {
+        subject.to_string()
+    } else {
+        format!("Re: {}", subject)
+    }
+}
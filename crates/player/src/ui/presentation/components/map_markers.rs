@@ -0,0 +1,489 @@
+//! Map Marker Layer - DM markers pinned over a map or backdrop
+//!
+//! Draws the markers pinned to one map (a location's map, or a region's
+//! backdrop) as pins positioned by percentage, so they stay in place however
+//! the image is scaled. The Engine only sends players visible markers.
+//!
+//! When `editable` (DM), clicking empty space in placing mode drops a new
+//! marker there, and each pin's popover can show/hide it or take it off the
+//! map. Pins with a link open their entity through `on_open`.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::MapMarkerData;
+use crate::application::services::story_event_service::CreateDmMarkerRequest;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_story_event_service;
+use crate::presentation::state::use_game_state;
+use wrldbldr_protocol::{MarkerLinkData, MarkerPinData};
+
+/// Props for the MarkerLayer component
+#[derive(Props, Clone, PartialEq)]
+pub struct MarkerLayerProps {
+    pub world_id: String,
+    pub location_id: String,
+    /// Region whose backdrop this layer covers; None for the location map
+    #[props(default)]
+    pub region_id: Option<String>,
+    /// Allow placing, hiding, and unpinning markers (DM only)
+    #[props(default = false)]
+    pub editable: bool,
+    /// Entities a new marker can link to, with display labels
+    #[props(default)]
+    pub link_options: Vec<(MarkerLinkData, String)>,
+    /// Called when a pin's link is followed
+    #[props(default)]
+    pub on_open: Option<EventHandler<MarkerLinkData>>,
+}
+
+/// Pins for the DM markers on one map, laid over its image
+#[component]
+pub fn MarkerLayer(props: MarkerLayerProps) -> Element {
+    let game_state = use_game_state();
+    let story_event_service = use_story_event_service();
+    let mut placing = use_signal(|| false);
+    let mut draft: Signal<Option<(f32, f32)>> = use_signal(|| None);
+    let mut selected: Signal<Option<String>> = use_signal(|| None);
+    let mut surface: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Reload when the layer moves to another map; later changes arrive as
+    // MapMarkerUpdated/MapMarkerRemoved
+    {
+        let service = story_event_service.clone();
+        let game_state = game_state.clone();
+        let world_id = props.world_id.clone();
+        let location_id = props.location_id.clone();
+        let region_id = props.region_id.clone();
+        use_effect(use_reactive!(|(world_id, location_id, region_id)| {
+            let service = service.clone();
+            let mut game_state = game_state.clone();
+            selected.set(None);
+            draft.set(None);
+            spawn_task(async move {
+                match service
+                    .list_map_markers(&world_id, &location_id, region_id.as_deref())
+                    .await
+                {
+                    Ok(markers) => {
+                        game_state.set_map_markers(&location_id, region_id.as_deref(), markers)
+                    }
+                    Err(e) => error.set(Some(format!("Failed to load markers: {}", e))),
+                }
+            });
+        }));
+    }
+
+    let markers: Vec<MapMarkerData> = game_state
+        .map_markers
+        .read()
+        .iter()
+        .filter(|m| m.pin.location_id == props.location_id && m.pin.region_id == props.region_id)
+        .cloned()
+        .collect();
+    let selected_marker = selected
+        .read()
+        .as_ref()
+        .and_then(|id| markers.iter().find(|m| &m.id == id).cloned());
+    let is_placing = props.editable && *placing.read();
+
+    rsx! {
+        div {
+            class: if is_placing { "marker-layer absolute inset-0 cursor-crosshair z-[5]" } else { "marker-layer absolute inset-0 pointer-events-none z-[5]" },
+            onmounted: move |e| surface.set(Some(e.data())),
+            onclick: move |e: MouseEvent| {
+                e.stop_propagation();
+                if !is_placing {
+                    return;
+                }
+                let point = e.element_coordinates();
+                let Some(mounted) = surface.read().clone() else {
+                    return;
+                };
+                spawn_task(async move {
+                    if let Ok(rect) = mounted.get_client_rect().await {
+                        if rect.width() > 0.0 && rect.height() > 0.0 {
+                            let x = (point.x / rect.width() * 100.0) as f32;
+                            let y = (point.y / rect.height() * 100.0) as f32;
+                            draft.set(Some((x.clamp(0.0, 100.0), y.clamp(0.0, 100.0))));
+                            placing.set(false);
+                        }
+                    }
+                });
+            },
+
+            for marker in markers.iter().cloned() {
+                MarkerPinView {
+                    key: "{marker.id}",
+                    is_selected: selected.read().as_deref() == Some(marker.id.as_str()),
+                    on_select: move |id: String| {
+                        let current = selected.read().clone();
+                        selected.set(if current.as_deref() == Some(id.as_str()) { None } else { Some(id) });
+                    },
+                    marker,
+                }
+            }
+
+            if let Some(marker) = selected_marker {
+                MarkerPopover {
+                    marker,
+                    editable: props.editable,
+                    on_open: props.on_open,
+                    on_close: move |_| selected.set(None),
+                    on_error: move |msg| error.set(Some(msg)),
+                }
+            }
+
+            if let Some((x, y)) = *draft.read() {
+                NewMarkerForm {
+                    world_id: props.world_id.clone(),
+                    location_id: props.location_id.clone(),
+                    region_id: props.region_id.clone(),
+                    x,
+                    y,
+                    link_options: props.link_options.clone(),
+                    on_done: move |_| draft.set(None),
+                    on_error: move |msg| error.set(Some(msg)),
+                }
+            }
+
+            if props.editable {
+                div {
+                    class: "absolute top-2 left-2 flex items-center gap-2 pointer-events-auto",
+                    button {
+                        r#type: "button",
+                        onclick: move |e| {
+                            e.stop_propagation();
+                            let current = *placing.read();
+                            placing.set(!current);
+                            draft.set(None);
+                        },
+                        class: if is_placing { "px-2 py-1 bg-amber-500 text-black text-xs rounded cursor-pointer border-0" } else { "px-2 py-1 bg-black/70 text-white text-xs rounded cursor-pointer border-0" },
+                        if is_placing { "Click the map to place…" } else { "+ Marker" }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "absolute bottom-2 left-2 px-2 py-1 bg-red-600/90 text-white text-xs rounded pointer-events-auto cursor-pointer",
+                    onclick: move |e| {
+                        e.stop_propagation();
+                        error.set(None);
+                    },
+                    "{err}"
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct MarkerPinViewProps {
+    marker: MapMarkerData,
+    is_selected: bool,
+    on_select: EventHandler<String>,
+}
+
+/// A single pin; hidden markers (DM only) are drawn faded
+#[component]
+fn MarkerPinView(props: MarkerPinViewProps) -> Element {
+    let marker = props.marker.clone();
+    let style = format!(
+        "left: {}%; top: {}%; transform: translate(-50%, -100%);",
+        marker.pin.x, marker.pin.y
+    );
+    let color = if !marker.visible_to_players {
+        "bg-gray-500/70 border-dashed"
+    } else if marker.pin.link.is_some() {
+        "bg-blue-500"
+    } else {
+        "bg-amber-500"
+    };
+    let ring = if props.is_selected {
+        "ring-2 ring-white"
+    } else {
+        ""
+    };
+
+    rsx! {
+        button {
+            r#type: "button",
+            class: "absolute w-5 h-5 rounded-full border-2 border-white/80 {color} {ring} cursor-pointer pointer-events-auto shadow-lg p-0",
+            style: "{style}",
+            title: "{marker.title}",
+            aria_label: "Marker: {marker.title}",
+            onclick: move |e| {
+                e.stop_propagation();
+                props.on_select.call(marker.id.clone());
+            },
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct MarkerPopoverProps {
+    marker: MapMarkerData,
+    editable: bool,
+    on_open: Option<EventHandler<MarkerLinkData>>,
+    on_close: EventHandler<()>,
+    on_error: EventHandler<String>,
+}
+
+/// Details for the selected pin, with DM visibility controls
+#[component]
+fn MarkerPopover(props: MarkerPopoverProps) -> Element {
+    let story_event_service = use_story_event_service();
+    let marker = props.marker.clone();
+    let style = format!(
+        "left: {}%; top: {}%; transform: translate(-50%, 8px);",
+        marker.pin.x.clamp(15.0, 85.0),
+        marker.pin.y.min(80.0)
+    );
+    let link = marker.pin.link.clone().filter(|_| props.on_open.is_some());
+
+    let toggle_visibility = {
+        let service = story_event_service.clone();
+        let marker_id = marker.id.clone();
+        let visible = marker.visible_to_players;
+        let on_error = props.on_error;
+        move |_| {
+            let service = service.clone();
+            let marker_id = marker_id.clone();
+            spawn_task(async move {
+                // The server broadcasts MapMarkerUpdated, which updates game state
+                if let Err(e) = service.toggle_event_visibility(&marker_id, !visible).await {
+                    on_error.call(format!("Failed to update marker: {}", e));
+                }
+            });
+        }
+    };
+    let unpin = {
+        let service = story_event_service.clone();
+        let marker_id = marker.id.clone();
+        let on_error = props.on_error;
+        let on_close = props.on_close;
+        move |_| {
+            let service = service.clone();
+            let marker_id = marker_id.clone();
+            spawn_task(async move {
+                match service.pin_dm_marker(&marker_id, None).await {
+                    Ok(()) => on_close.call(()),
+                    Err(e) => on_error.call(format!("Failed to remove marker: {}", e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "absolute w-56 bg-dark-surface/95 border border-white/10 rounded-lg p-3 shadow-xl pointer-events-auto z-10",
+            style: "{style}",
+            onclick: move |e| e.stop_propagation(),
+
+            div {
+                class: "flex items-start justify-between gap-2",
+                h4 { class: "m-0 text-white text-sm font-semibold", "{marker.title}" }
+                button {
+                    r#type: "button",
+                    onclick: move |_| props.on_close.call(()),
+                    class: "bg-transparent border-0 text-gray-400 hover:text-white cursor-pointer p-0",
+                    aria_label: "Close",
+                    "×"
+                }
+            }
+
+            if !marker.note.is_empty() {
+                p { class: "mt-1 mb-0 text-gray-300 text-xs whitespace-pre-wrap", "{marker.note}" }
+            }
+
+            if props.editable && !marker.visible_to_players {
+                p { class: "mt-1 mb-0 text-gray-500 text-xs italic", "Hidden from players" }
+            }
+
+            div {
+                class: "flex flex-wrap gap-2 mt-3",
+
+                if let Some(link) = link {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| {
+                            if let Some(on_open) = props.on_open {
+                                on_open.call(link.clone());
+                            }
+                            props.on_close.call(());
+                        },
+                        class: "px-2 py-1 bg-blue-500 text-white text-xs rounded cursor-pointer border-0",
+                        "{link_label(&link)}"
+                    }
+                }
+
+                if props.editable {
+                    button {
+                        r#type: "button",
+                        onclick: toggle_visibility,
+                        class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer border-0",
+                        if marker.visible_to_players { "Hide from players" } else { "Show to players" }
+                    }
+                    button {
+                        r#type: "button",
+                        onclick: unpin,
+                        class: "px-2 py-1 bg-red-600/80 text-white text-xs rounded cursor-pointer border-0",
+                        "Remove from map"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Button text for following a pin's link
+fn link_label(link: &MarkerLinkData) -> &'static str {
+    match link {
+        MarkerLinkData::Character(_) => "Open character",
+        MarkerLinkData::Location(_) => "Open location",
+        MarkerLinkData::Region(_) => "Go to region",
+        MarkerLinkData::Item(_) => "Open item",
+        MarkerLinkData::NarrativeEvent(_) => "Open event",
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct NewMarkerFormProps {
+    world_id: String,
+    location_id: String,
+    region_id: Option<String>,
+    x: f32,
+    y: f32,
+    link_options: Vec<(MarkerLinkData, String)>,
+    on_done: EventHandler<()>,
+    on_error: EventHandler<String>,
+}
+
+/// Title, note, link, and visibility for a marker being placed
+#[component]
+fn NewMarkerForm(props: NewMarkerFormProps) -> Element {
+    let story_event_service = use_story_event_service();
+    let mut title = use_signal(String::new);
+    let mut note = use_signal(String::new);
+    let mut link_index: Signal<Option<usize>> = use_signal(|| None);
+    let mut visible = use_signal(|| false);
+    let mut saving = use_signal(|| false);
+
+    let style = format!(
+        "left: {}%; top: {}%; transform: translate(-50%, 8px);",
+        props.x.clamp(15.0, 85.0),
+        props.y.min(70.0)
+    );
+
+    let save = {
+        let props = props.clone();
+        move |_| {
+            let title_val = title.read().trim().to_string();
+            if title_val.is_empty() {
+                props.on_error.call("Marker title is required".to_string());
+                return;
+            }
+            let link = link_index
+                .read()
+                .and_then(|i| props.link_options.get(i))
+                .map(|(link, _)| link.clone());
+            let request = CreateDmMarkerRequest {
+                title: title_val,
+                note: note.read().clone(),
+                importance: "notable".to_string(),
+                marker_type: "note".to_string(),
+                tags: Vec::new(),
+                pin: Some(MarkerPinData {
+                    location_id: props.location_id.clone(),
+                    region_id: props.region_id.clone(),
+                    x: props.x,
+                    y: props.y,
+                    link,
+                }),
+                hidden: !*visible.read(),
+            };
+            let service = story_event_service.clone();
+            let world_id = props.world_id.clone();
+            let on_done = props.on_done;
+            let on_error = props.on_error;
+            saving.set(true);
+            spawn_task(async move {
+                // The server broadcasts MapMarkerUpdated, which adds the pin
+                match service.create_dm_marker(&world_id, &request).await {
+                    Ok(()) => on_done.call(()),
+                    Err(e) => {
+                        saving.set(false);
+                        on_error.call(format!("Failed to place marker: {}", e));
+                    }
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "absolute w-64 bg-dark-surface/95 border border-white/10 rounded-lg p-3 shadow-xl pointer-events-auto z-10 flex flex-col gap-2",
+            style: "{style}",
+            onclick: move |e| e.stop_propagation(),
+
+            input {
+                r#type: "text",
+                value: "{title}",
+                placeholder: "Marker title",
+                aria_label: "Marker title",
+                oninput: move |e| title.set(e.value()),
+                class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+            textarea {
+                value: "{note}",
+                placeholder: "Note (optional)",
+                aria_label: "Marker note",
+                rows: 2,
+                oninput: move |e| note.set(e.value()),
+                class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-xs resize-none",
+            }
+
+            if !props.link_options.is_empty() {
+                select {
+                    aria_label: "Linked entity",
+                    onchange: move |e| link_index.set(e.value().parse().ok()),
+                    class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    option { value: "", "No link" }
+                    for (i, (_, label)) in props.link_options.iter().enumerate() {
+                        option { key: "{i}", value: "{i}", "{label}" }
+                    }
+                }
+            }
+
+            label {
+                class: "flex items-center gap-2 text-gray-300 text-xs",
+                input {
+                    r#type: "checkbox",
+                    checked: *visible.read(),
+                    onchange: move |e| visible.set(e.checked()),
+                }
+                "Visible to players"
+            }
+
+            div {
+                class: "flex justify-end gap-2",
+                button {
+                    r#type: "button",
+                    onclick: move |_| props.on_done.call(()),
+                    class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer border-0",
+                    "Cancel"
+                }
+                button {
+                    r#type: "button",
+                    disabled: *saving.read(),
+                    onclick: save,
+                    class: "px-2 py-1 bg-amber-500 text-black text-xs rounded cursor-pointer border-0 disabled:opacity-50",
+                    "Place"
+                }
+            }
+        }
+    }
+}
@@ -3,6 +3,9 @@
 //! US-NAV-010: Visual map showing regions with click-to-navigate.
 
 use dioxus::prelude::*;
+use wrldbldr_protocol::MarkerLinkData;
+
+use super::map_markers::MarkerLayer;
 
 /// Region data for mini-map display (includes bounds)
 #[derive(Clone, Debug, PartialEq)]
@@ -44,6 +47,17 @@ pub struct MiniMapProps {
     /// Whether data is loading
     #[props(default = false)]
     pub is_loading: bool,
+    /// World and location for drawing DM markers pinned to the map
+    #[props(default)]
+    pub world_id: Option<String>,
+    #[props(default)]
+    pub location_id: Option<String>,
+    /// Handler for following a marker's link
+    #[props(default)]
+    pub on_marker_open: Option<EventHandler<MarkerLinkData>>,
+    /// Opens the world overview map; the button is hidden when absent
+    #[props(default)]
+    pub on_world_map: Option<EventHandler<()>>,
     /// Handler for clicking a region
     pub on_region_click: EventHandler<String>,
     /// Handler for closing the map
@@ -82,10 +96,20 @@ pub fn MiniMap(props: MiniMapProps) -> Element {
                         }
                     }
 
-                    button {
-                        class: "w-8 h-8 flex items-center justify-center bg-white/5 hover:bg-white/10 rounded-lg text-gray-400 hover:text-white transition-colors",
-                        onclick: move |_| props.on_close.call(()),
-                        "x"
+                    div {
+                        class: "flex items-center gap-2",
+                        if let Some(on_world_map) = props.on_world_map {
+                            button {
+                                class: "px-3 h-8 flex items-center bg-white/5 hover:bg-white/10 rounded-lg text-gray-300 hover:text-white text-sm transition-colors",
+                                onclick: move |_| on_world_map.call(()),
+                                "World map"
+                            }
+                        }
+                        button {
+                            class: "w-8 h-8 flex items-center justify-center bg-white/5 hover:bg-white/10 rounded-lg text-gray-400 hover:text-white transition-colors",
+                            onclick: move |_| props.on_close.call(()),
+                            "x"
+                        }
                     }
                 }
 
@@ -138,6 +162,14 @@ pub fn MiniMap(props: MiniMapProps) -> Element {
                                 class: "absolute inset-0 w-full h-full object-contain opacity-30",
                             }
 
+                            if let (Some(world_id), Some(location_id)) = (props.world_id.clone(), props.location_id.clone()) {
+                                MarkerLayer {
+                                    world_id,
+                                    location_id,
+                                    on_open: props.on_marker_open,
+                                }
+                            }
+
                             // Region overlays
                             for region in props.regions.iter() {
                                 if let Some(ref bounds) = region.bounds {
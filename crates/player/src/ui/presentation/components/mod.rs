@@ -4,16 +4,32 @@ pub mod action_panel;
 pub mod character_sheet_viewer;
 pub mod common;
 pub mod creator;
+pub mod dice_tray;
 pub mod dm_panel;
 pub mod event_overlays;
 pub mod inventory_panel;
 pub mod known_npcs_panel;
 pub mod lore_journal;
+pub mod mailbox_card;
+pub mod map_markers;
 pub mod mini_map;
 pub mod navigation_panel;
+pub mod noise_maker;
+pub mod notification_center;
+pub mod offline_status;
+pub mod pacing_timer;
+pub mod pc_switcher;
 pub mod pc;
+pub mod region_hotspots;
 pub mod region_items_panel;
+pub mod safety_card;
+pub mod schema_sheet;
+pub mod secrets_card;
 pub mod settings;
 pub mod story_arc;
 pub mod tactical;
+pub mod trade_panel;
+pub mod tutorial_guide;
 pub mod visual_novel;
+pub mod voice_input;
+pub mod world_map;
@@ -1,15 +1,22 @@
 //! Navigation Panel - Player UI for moving between regions and locations
 //!
 //! Displays navigation options for the current region:
-//! - Connected regions within the same location
+//! - Connected regions within the same location, with carried items to try
+//!   on locked ones
 //! - Exits to other locations
+//! - Making a noise that may carry next door
 //! - Game time display
 
 use dioxus::prelude::*;
+use wrldbldr_protocol::types::LoudnessData;
 
-use crate::application::dto::{GameTime, NavigationData, NavigationExit, NavigationTarget};
+use crate::application::dto::{
+    GameTime, InventoryItemData, NavigationData, NavigationExit, NavigationTarget,
+};
 
+use crate::presentation::components::noise_maker::NoiseMaker;
 use crate::presentation::game_time_format;
+use crate::presentation::utils::{focus_mounted, use_roving_focus, RovingFocus};
 
 /// Props for NavigationPanel component
 #[derive(Props, Clone, PartialEq)]
@@ -29,14 +36,28 @@ pub struct NavigationPanelProps {
     /// Whether navigation is disabled (e.g., during LLM processing)
     #[props(default = false)]
     pub disabled: bool,
+    /// Carried items the player can try on a locked region
+    #[props(default)]
+    pub keys: Vec<InventoryItemData>,
+    /// Handler for trying an item on a locked region
+    #[props(default)]
+    pub on_use_key: Option<EventHandler<(String, String)>>, // (region_id, item_id)
+    /// Handler for making a noise in the current region
+    #[props(default)]
+    pub on_make_noise: Option<EventHandler<(String, LoudnessData)>>, // (description, loudness)
 }
 
 /// Navigation Panel - Modal overlay for navigation options
+///
+/// Takes focus when opened; Escape closes it and the arrow keys step through
+/// regions and exits as one list.
 #[component]
 pub fn NavigationPanel(props: NavigationPanelProps) -> Element {
     let has_regions = !props.navigation.connected_regions.is_empty();
     let has_exits = !props.navigation.exits.is_empty();
     let has_any_navigation = has_regions || has_exits;
+    let region_count = props.navigation.connected_regions.len();
+    let destinations = use_roving_focus();
 
     rsx! {
         // Modal overlay
@@ -47,7 +68,20 @@ pub fn NavigationPanel(props: NavigationPanelProps) -> Element {
             // Modal content
             div {
                 class: "navigation-panel bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl w-full max-w-lg max-h-[80vh] overflow-hidden flex flex-col shadow-2xl border border-white/10",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "navigation-panel-title",
+                tabindex: "-1",
                 onclick: move |e| e.stop_propagation(),
+                onmounted: move |e| focus_mounted(e.data()),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.prevent_default();
+                        props.on_close.call(());
+                    } else {
+                        destinations.handle_key(&e);
+                    }
+                },
 
                 // Header
                 div {
@@ -55,6 +89,7 @@ pub fn NavigationPanel(props: NavigationPanelProps) -> Element {
 
                     div {
                         h2 {
+                            id: "navigation-panel-title",
                             class: "text-xl font-bold text-white m-0",
                             "Navigation"
                         }
@@ -66,6 +101,7 @@ pub fn NavigationPanel(props: NavigationPanelProps) -> Element {
 
                     button {
                         class: "w-8 h-8 flex items-center justify-center bg-white/5 hover:bg-white/10 rounded-lg text-gray-400 hover:text-white transition-colors",
+                        aria_label: "Close navigation",
                         onclick: move |_| props.on_close.call(()),
                         "✕"
                     }
@@ -98,16 +134,28 @@ pub fn NavigationPanel(props: NavigationPanelProps) -> Element {
                                 div {
                                     class: "space-y-2",
 
-                                    for target in props.navigation.connected_regions.iter() {
-                                        RegionButton {
+                                    for (index, target) in props.navigation.connected_regions.iter().enumerate() {
+                                        div {
                                             key: "{target.region_id}",
-                                            target: target.clone(),
-                                            disabled: props.disabled,
-                                            on_click: {
-                                                let on_move = props.on_move_to_region;
-                                                let region_id = target.region_id.clone();
-                                                move |_| on_move.call(region_id.clone())
-                                            },
+                                            RegionButton {
+                                                target: target.clone(),
+                                                index,
+                                                focus: destinations,
+                                                disabled: props.disabled,
+                                                on_click: {
+                                                    let on_move = props.on_move_to_region;
+                                                    let region_id = target.region_id.clone();
+                                                    move |_| on_move.call(region_id.clone())
+                                                },
+                                            }
+                                            if let Some(on_use_key) = props.on_use_key.filter(|_| target.is_locked && !props.keys.is_empty()) {
+                                                KeyPicker {
+                                                    region_id: target.region_id.clone(),
+                                                    keys: props.keys.clone(),
+                                                    disabled: props.disabled,
+                                                    on_use_key,
+                                                }
+                                            }
                                         }
                                     }
                                 }
@@ -125,10 +173,12 @@ pub fn NavigationPanel(props: NavigationPanelProps) -> Element {
                                 div {
                                     class: "space-y-2",
 
-                                    for exit in props.navigation.exits.iter() {
+                                    for (index, exit) in props.navigation.exits.iter().enumerate() {
                                         ExitButton {
                                             key: "{exit.location_id}",
                                             exit: exit.clone(),
+                                            index: region_count + index,
+                                            focus: destinations,
                                             disabled: props.disabled,
                                             on_click: {
                                                 let on_exit = props.on_exit_to_location;
@@ -143,6 +193,14 @@ pub fn NavigationPanel(props: NavigationPanelProps) -> Element {
                         }
                     }
                 }
+
+                // Noise
+                if let Some(on_make_noise) = props.on_make_noise {
+                    div {
+                        class: "p-4 border-t border-white/10",
+                        NoiseMaker { disabled: props.disabled, on_make_noise }
+                    }
+                }
             }
         }
     }
@@ -152,6 +210,8 @@ pub fn NavigationPanel(props: NavigationPanelProps) -> Element {
 #[derive(Props, Clone, PartialEq)]
 struct RegionButtonProps {
     target: NavigationTarget,
+    index: usize,
+    focus: RovingFocus,
     disabled: bool,
     on_click: EventHandler<()>,
 }
@@ -161,6 +221,8 @@ struct RegionButtonProps {
 fn RegionButton(props: RegionButtonProps) -> Element {
     let is_locked = props.target.is_locked;
     let is_disabled = props.disabled || is_locked;
+    let index = props.index;
+    let focus = props.focus;
 
     let button_class = if is_locked {
         "w-full p-4 bg-gray-800/50 rounded-xl border border-gray-700 cursor-not-allowed opacity-60"
@@ -179,6 +241,8 @@ fn RegionButton(props: RegionButtonProps) -> Element {
                     props.on_click.call(());
                 }
             },
+            onmounted: move |e| focus.register(index, e.data()),
+            onfocus: move |_| focus.set_current(index),
 
             div {
                 class: "flex items-center gap-3",
@@ -186,6 +250,7 @@ fn RegionButton(props: RegionButtonProps) -> Element {
                 // Icon
                 span {
                     class: if is_locked { "text-gray-500 text-xl" } else { "text-amber-400 text-xl" },
+                    aria_hidden: "true",
                     if is_locked { "🔒" } else { "→" }
                 }
 
@@ -196,6 +261,9 @@ fn RegionButton(props: RegionButtonProps) -> Element {
                     div {
                         class: if is_locked { "font-medium text-gray-500" } else { "font-medium text-white" },
                         "{props.target.name}"
+                        if is_locked {
+                            span { class: "sr-only", " (locked)" }
+                        }
                     }
 
                     if let Some(ref lock_desc) = props.target.lock_description {
@@ -210,10 +278,46 @@ fn RegionButton(props: RegionButtonProps) -> Element {
     }
 }
 
+/// Pick a carried item and try it on a locked region's lock
+#[component]
+fn KeyPicker(
+    region_id: String,
+    keys: Vec<InventoryItemData>,
+    disabled: bool,
+    on_use_key: EventHandler<(String, String)>,
+) -> Element {
+    let mut chosen = use_signal(String::new);
+    let chosen_id = chosen.read().clone();
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 mt-2 pl-10",
+            select {
+                class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                aria_label: "Item to try on the lock",
+                value: "{chosen_id}",
+                onchange: move |e| chosen.set(e.value()),
+                option { value: "", "Try an item…" }
+                for entry in keys.iter() {
+                    option { key: "{entry.item.id}", value: "{entry.item.id}", "{entry.item.name}" }
+                }
+            }
+            button {
+                class: "px-3 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer disabled:opacity-50",
+                disabled: disabled || chosen_id.is_empty(),
+                onclick: move |_| on_use_key.call((region_id.clone(), chosen.read().clone())),
+                "Try"
+            }
+        }
+    }
+}
+
 /// Props for ExitButton
 #[derive(Props, Clone, PartialEq)]
 struct ExitButtonProps {
     exit: NavigationExit,
+    index: usize,
+    focus: RovingFocus,
     disabled: bool,
     on_click: EventHandler<()>,
 }
@@ -226,6 +330,8 @@ fn ExitButton(props: ExitButtonProps) -> Element {
     } else {
         "w-full p-4 bg-blue-500/10 hover:bg-blue-500/20 rounded-xl border border-blue-500/30 hover:border-blue-500/50 cursor-pointer transition-all"
     };
+    let index = props.index;
+    let focus = props.focus;
 
     rsx! {
         button {
@@ -236,6 +342,8 @@ fn ExitButton(props: ExitButtonProps) -> Element {
                     props.on_click.call(());
                 }
             },
+            onmounted: move |e| focus.register(index, e.data()),
+            onfocus: move |_| focus.set_current(index),
 
             div {
                 class: "flex items-center gap-3",
@@ -243,6 +351,7 @@ fn ExitButton(props: ExitButtonProps) -> Element {
                 // Icon
                 span {
                     class: "text-blue-400 text-xl",
+                    aria_hidden: "true",
                     "⇐"
                 }
 
@@ -294,6 +403,8 @@ pub fn NavigationButtons(props: NavigationButtonsProps) -> Element {
     rsx! {
         div {
             class: "navigation-buttons flex flex-wrap gap-2",
+            role: "group",
+            aria_label: "Navigation",
 
             // Region buttons
             for target in props.navigation.connected_regions.iter() {
@@ -0,0 +1,59 @@
+//! Noise maker - Make a noise that may carry into the regions next door
+//!
+//! Players use it from the navigation panel to make noise where their PC
+//! stands; the DM uses it on any region. Open ways carry any loud noise,
+//! doors only the deafening ones (muffled), thick walls nothing.
+
+use dioxus::prelude::*;
+
+use wrldbldr_protocol::types::LoudnessData;
+
+/// Description, loudness and a button
+#[component]
+pub fn NoiseMaker(
+    #[props(default = false)] disabled: bool,
+    /// Called with the description and loudness
+    on_make_noise: EventHandler<(String, LoudnessData)>,
+) -> Element {
+    let mut description = use_signal(String::new);
+    let mut deafening = use_signal(|| false);
+    let text = description.read().trim().to_string();
+
+    rsx! {
+        div {
+            class: "noise-maker flex items-center gap-2 text-xs",
+            input {
+                r#type: "text",
+                maxlength: "200",
+                placeholder: "Make a noise (e.g. A chair crashes over)",
+                aria_label: "Noise to make",
+                class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                value: "{description}",
+                oninput: move |e| description.set(e.value()),
+            }
+            select {
+                aria_label: "Loudness",
+                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                value: if *deafening.read() { "deafening" } else { "loud" },
+                onchange: move |e| deafening.set(e.value() == "deafening"),
+                option { value: "loud", "Loud" }
+                option { value: "deafening", "Deafening" }
+            }
+            button {
+                r#type: "button",
+                class: "px-3 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer border-0 disabled:opacity-50",
+                disabled: disabled || text.is_empty(),
+                onclick: move |_| {
+                    let loudness = if *deafening.read() {
+                        LoudnessData::Deafening
+                    } else {
+                        LoudnessData::Loud
+                    };
+                    on_make_noise.call((text.clone(), loudness));
+                    description.set(String::new());
+                },
+                "Make noise"
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Notification center component
+//!
+//! Bell button with an unread badge that opens the player's notification
+//! feed. Opening an entry marks it read; the feed itself is kept until the
+//! player clears it.
+
+use dioxus::prelude::*;
+
+use crate::presentation::state::{use_session_state, Notification};
+use crate::use_platform;
+
+/// Short "how long ago" label for a notification timestamp
+fn time_ago(now: u64, then: u64) -> String {
+    let secs = now.saturating_sub(then);
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86_399 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
+
+/// Bell button and notification feed
+#[component]
+pub fn NotificationCenter() -> Element {
+    let platform = use_platform();
+    let session_state = use_session_state();
+    let mut notifications = session_state.notifications;
+    let mut is_open = use_signal(|| false);
+    let mut system_alerts = {
+        let platform = platform.clone();
+        use_signal(move || notifications.system_alerts_enabled(platform.as_ref()))
+    };
+
+    let unread = notifications.unread_count();
+    let feed = notifications.notifications.read().clone();
+    let has_entries = !feed.is_empty();
+    let now = platform.now_unix_secs();
+    let open = *is_open.read();
+    let bell_label = if unread > 0 {
+        format!("Notifications, {} unread", unread)
+    } else {
+        "Notifications".to_string()
+    };
+
+    rsx! {
+        div {
+            class: "notification-center flex flex-col items-end gap-1",
+
+            button {
+                onclick: move |_| is_open.set(!open),
+                class: "relative px-3 py-1 bg-black/70 text-white rounded-lg text-xs cursor-pointer",
+                aria_label: "{bell_label}",
+                aria_expanded: "{open}",
+                aria_controls: "notification-feed",
+                span { aria_hidden: "true", "🔔" }
+                if unread > 0 {
+                    span {
+                        class: "absolute -top-1 -right-1 min-w-[1.1rem] px-1 bg-red-600 text-white rounded-full text-[0.65rem] leading-[1.1rem] text-center",
+                        aria_hidden: "true",
+                        "{unread}"
+                    }
+                }
+            }
+
+            if open {
+                div {
+                    id: "notification-feed",
+                    class: "w-72 max-h-96 flex flex-col bg-black/85 rounded-lg overflow-hidden",
+                    role: "region",
+                    aria_label: "Notifications",
+
+                    div {
+                        class: "flex items-center justify-between px-3 py-2 border-b border-gray-700",
+                        span { class: "text-white text-xs font-medium", "Notifications" }
+                        div {
+                            class: "flex gap-2",
+                            button {
+                                onclick: {
+                                    let platform = platform.clone();
+                                    move |_| notifications.mark_all_read(platform.as_ref())
+                                },
+                                disabled: unread == 0,
+                                class: "text-xs text-amber-400 hover:text-amber-300 disabled:text-gray-600 cursor-pointer",
+                                "Mark all read"
+                            }
+                            button {
+                                onclick: {
+                                    let platform = platform.clone();
+                                    move |_| notifications.clear_feed(platform.as_ref())
+                                },
+                                disabled: !has_entries,
+                                class: "text-xs text-gray-400 hover:text-white disabled:text-gray-600 cursor-pointer",
+                                "Clear"
+                            }
+                        }
+                    }
+
+                    if !has_entries {
+                        p {
+                            class: "px-3 py-4 text-gray-400 text-xs text-center",
+                            "Nothing yet. Items, lore, and time changes will show up here."
+                        }
+                    } else {
+                        ul {
+                            class: "flex-1 overflow-y-auto",
+                            for notification in feed {
+                                NotificationRow {
+                                    key: "{notification.id}",
+                                    age: time_ago(now, notification.timestamp),
+                                    on_read: {
+                                        let platform = platform.clone();
+                                        move |id: String| notifications.mark_read(&id, platform.as_ref())
+                                    },
+                                    notification,
+                                }
+                            }
+                        }
+                    }
+
+                    label {
+                        class: "flex items-center gap-2 px-3 py-2 border-t border-gray-700 text-gray-400 text-xs cursor-pointer",
+                        input {
+                            r#type: "checkbox",
+                            checked: *system_alerts.read(),
+                            onchange: {
+                                let platform = platform.clone();
+                                move |e: FormEvent| {
+                                    notifications.set_system_alerts(e.checked(), platform.as_ref());
+                                    system_alerts.set(e.checked());
+                                }
+                            },
+                        }
+                        "Alert me when an NPC answers or it's my turn to roll, even if the app is in the background"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct NotificationRowProps {
+    notification: Notification,
+    age: String,
+    on_read: EventHandler<String>,
+}
+
+#[component]
+fn NotificationRow(props: NotificationRowProps) -> Element {
+    let notification = props.notification;
+    let id = notification.id.clone();
+    let unread = !notification.read;
+
+    rsx! {
+        li {
+            class: "border-b border-gray-800 last:border-b-0",
+            button {
+                onclick: move |_| props.on_read.call(id.clone()),
+                class: "w-full flex gap-2 px-3 py-2 text-left hover:bg-white/5 cursor-pointer",
+                class: if unread { "bg-white/10" } else { "" },
+                span { aria_hidden: "true", "{notification.kind.icon()}" }
+                div {
+                    class: "flex-1 min-w-0",
+                    div {
+                        class: "text-xs",
+                        class: if unread { "text-white font-medium" } else { "text-gray-300" },
+                        "{notification.title}"
+                        if unread {
+                            span { class: "sr-only", " (unread)" }
+                        }
+                    }
+                    if let Some(detail) = notification.detail.as_ref() {
+                        div { class: "text-gray-400 text-xs truncate", "{detail}" }
+                    }
+                    div { class: "text-gray-500 text-[0.65rem]", "{props.age}" }
+                }
+            }
+        }
+    }
+}
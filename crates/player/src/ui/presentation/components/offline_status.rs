@@ -0,0 +1,92 @@
+//! Offline status component
+//!
+//! Shows the player what happened to actions they composed while the
+//! connection was down: how many are still waiting, and which ones were held
+//! back on reconnect because the scene moved on.
+
+use dioxus::prelude::*;
+
+use crate::presentation::services::use_command_bus;
+use crate::presentation::state::{use_offline_state, use_session_state};
+use crate::use_platform;
+
+/// Queued-action count and reconnect conflicts
+#[component]
+pub fn OfflineStatus() -> Element {
+    let command_bus = use_command_bus();
+    let platform = use_platform();
+    let session_state = use_session_state();
+    let mut offline_state = use_offline_state();
+
+    let queued_count = offline_state.queued.read().len();
+    let showing_cached = *offline_state.showing_cached.read();
+    let conflicts = offline_state.conflicts.read().clone();
+
+    rsx! {
+        div {
+            class: "offline-status flex flex-col items-end gap-1",
+            role: "status",
+
+            if showing_cached {
+                div {
+                    class: "px-3 py-1 bg-black/70 text-gray-300 rounded-lg text-xs",
+                    "Showing the last saved scene"
+                }
+            }
+
+            if queued_count > 0 {
+                div {
+                    class: "px-3 py-1 bg-blue-700/80 text-white rounded-lg text-xs",
+                    if queued_count == 1 {
+                        "1 action will send when you reconnect"
+                    } else {
+                        "{queued_count} actions will send when you reconnect"
+                    }
+                }
+            }
+
+            for conflict in conflicts {
+                div {
+                    key: "{conflict.action.id}",
+                    class: "flex flex-col gap-1 px-3 py-2 bg-amber-900/90 text-white rounded-lg text-xs max-w-[250px]",
+                    div { class: "font-medium", "{conflict.action.summary()}" }
+                    div { class: "text-amber-200", "Not sent: {conflict.reason.message()}" }
+                    div {
+                        class: "flex gap-2 justify-end",
+                        button {
+                            onclick: {
+                                let command_bus = command_bus.clone();
+                                let platform = platform.clone();
+                                let session_state = session_state.clone();
+                                let action_id = conflict.action.id.clone();
+                                move |_| {
+                                    let Some(action) = offline_state.resolve_conflict(&action_id) else {
+                                        return;
+                                    };
+                                    if !*session_state.connection.joined.peek() {
+                                        offline_state.requeue(&platform, action);
+                                    } else if let Err(e) = command_bus.send(action.message()) {
+                                        tracing::warn!("Failed to resend action: {}", e);
+                                        offline_state.requeue(&platform, action);
+                                    }
+                                }
+                            },
+                            class: "px-2 py-0.5 bg-amber-600 hover:bg-amber-500 rounded cursor-pointer",
+                            "Send anyway"
+                        }
+                        button {
+                            onclick: {
+                                let action_id = conflict.action.id.clone();
+                                move |_| {
+                                    offline_state.resolve_conflict(&action_id);
+                                }
+                            },
+                            class: "px-2 py-0.5 bg-gray-700 hover:bg-gray-600 rounded cursor-pointer",
+                            "Discard"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
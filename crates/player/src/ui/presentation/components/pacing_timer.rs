@@ -0,0 +1,127 @@
+//! Pacing timer countdown
+//!
+//! Shows the scene or turn timer the DM started. The Engine broadcasts the
+//! timer every minute and when it reaches its warning or its end; in between
+//! the badge counts down locally. The cues stay gentle: amber once the
+//! warning starts, and a soft red "Time's up" at the end (pulsing unless
+//! reduced motion is on). Nothing stops when time runs out.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{PacingCueData, PacingTimerData, PacingTimerKindData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::state::{use_accessibility_state, use_game_state};
+use crate::use_platform;
+
+/// Seconds left on a timer received at `received_at_ms`, as of `now_ms`
+pub fn remaining_secs(timer: &PacingTimerData, received_at_ms: u64, now_ms: u64) -> u32 {
+    if timer.cue == PacingCueData::Paused {
+        return timer.remaining_secs;
+    }
+    let elapsed = now_ms.saturating_sub(received_at_ms) / 1000;
+    timer
+        .remaining_secs
+        .saturating_sub(u32::try_from(elapsed).unwrap_or(u32::MAX))
+}
+
+/// "m:ss", or "h:mm:ss" for long scenes
+pub fn format_countdown(secs: u32) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// Countdown badge for the world's pacing timer; renders nothing without one
+#[component]
+pub fn PacingTimerBadge() -> Element {
+    let platform = use_platform();
+    let game_state = use_game_state();
+    let reduced_motion = *use_accessibility_state().reduced_motion.read();
+    let mut now_ms = use_signal(|| platform.now_millis());
+    // Bumped per timer update so the previous countdown loop stops
+    let mut tick_seq = use_signal(|| 0u32);
+
+    {
+        let platform = platform.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let counting = game_state
+                .pacing_timer
+                .read()
+                .clone()
+                .filter(|(timer, _)| {
+                    timer.cue != PacingCueData::Paused && timer.remaining_secs > 0
+                });
+            let seq = *tick_seq.peek() + 1;
+            tick_seq.set(seq);
+            now_ms.set(platform.now_millis());
+            let Some((timer, received_at_ms)) = counting else {
+                return;
+            };
+
+            let platform = platform.clone();
+            spawn_task(async move {
+                loop {
+                    platform.sleep_ms(1000).await;
+                    if *tick_seq.peek() != seq {
+                        break;
+                    }
+                    let now = platform.now_millis();
+                    now_ms.set(now);
+                    if remaining_secs(&timer, received_at_ms, now) == 0 {
+                        break;
+                    }
+                }
+            });
+        });
+    }
+
+    let Some((timer, received_at_ms)) = game_state.pacing_timer.read().clone() else {
+        return rsx! {};
+    };
+    let remaining = remaining_secs(&timer, received_at_ms, *now_ms.read());
+    let paused = timer.cue == PacingCueData::Paused;
+    let kind = match timer.kind {
+        PacingTimerKindData::Turn => "Turn",
+        _ => "Scene",
+    };
+    let title = match &timer.label {
+        Some(label) => format!("{}: {}", kind, label),
+        None => kind.to_string(),
+    };
+
+    let (class, text) = if remaining == 0 {
+        let pulse = if reduced_motion { "" } else { " animate-pulse" };
+        (
+            format!("bg-red-900/70 text-red-100{}", pulse),
+            "Time's up".to_string(),
+        )
+    } else if paused {
+        (
+            "bg-black/50 text-gray-400".to_string(),
+            format!("{} paused", format_countdown(remaining)),
+        )
+    } else if remaining <= timer.warning_secs {
+        (
+            "bg-amber-800/80 text-amber-100".to_string(),
+            format!("{} left", format_countdown(remaining)),
+        )
+    } else {
+        (
+            "bg-black/50 text-gray-200".to_string(),
+            format_countdown(remaining),
+        )
+    };
+
+    rsx! {
+        div {
+            class: "px-3 py-1 rounded-lg text-xs font-medium tabular-nums {class}",
+            role: "timer",
+            title: "{title}",
+            "⏱ {title} · {text}"
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::application::dto::SheetTemplate;
 use crate::application::services::PlayerCharacterData;
 use crate::infrastructure::spawn_task;
 use crate::presentation::services::use_world_service;
+use crate::presentation::utils::use_text_layout;
 
 /// Props for CharacterPanel
 #[derive(Props, Clone, PartialEq)]
@@ -20,6 +21,7 @@ pub fn CharacterPanel(props: CharacterPanelProps) -> Element {
     let world_service = use_world_service();
     let mut sheet_template: Signal<Option<SheetTemplate>> = use_signal(|| None);
     let mut loading = use_signal(|| true);
+    let layout = use_text_layout();
 
     // Load sheet template
     {
@@ -42,6 +44,9 @@ pub fn CharacterPanel(props: CharacterPanelProps) -> Element {
     rsx! {
         div {
             class: "flex flex-col gap-4 p-4 bg-dark-surface rounded-lg",
+            dir: layout.dir,
+            lang: layout.lang,
+            style: "{layout.style}",
 
             // Header
             div {
@@ -0,0 +1,125 @@
+//! PC switcher component
+//!
+//! For solo players and duet games: one player controlling several PCs.
+//! Shows a tab per controlled PC (switching shows that PC's last scene)
+//! and offers the player's other PCs in the world to take control of.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::services::player_character_service::PlayerCharacterData;
+use crate::infrastructure::spawn_task;
+use crate::infrastructure::websocket::ClientMessageBuilder;
+use crate::presentation::services::{use_command_bus, use_player_character_service};
+use crate::presentation::state::use_game_state;
+
+/// Tabs for the PCs this player controls, plus their other PCs to add
+#[component]
+pub fn PcSwitcher() -> Element {
+    let game_state = use_game_state();
+    let command_bus = use_command_bus();
+    let pc_service = use_player_character_service();
+    let platform = crate::use_platform();
+
+    let mut own_pcs: Signal<Vec<PlayerCharacterData>> = use_signal(Vec::new);
+    let mut feedback: Signal<Option<String>> = use_signal(|| None);
+
+    let game_state_for_load = game_state.clone();
+    use_effect(move || {
+        let world_id = game_state_for_load
+            .world
+            .read()
+            .as_ref()
+            .map(|w| w.world.id.clone());
+        let Some(wid) = world_id else {
+            return;
+        };
+        let svc = pc_service.clone();
+        let user_id = platform.get_user_id();
+        spawn_task(async move {
+            match svc.list_pcs(&wid).await {
+                Ok(pcs) => own_pcs.set(
+                    pcs.into_iter()
+                        .filter(|pc| pc.user_id == user_id && pc.is_alive)
+                        .collect(),
+                ),
+                Err(e) => tracing::warn!("Failed to list PCs for switcher: {}", e),
+            }
+        });
+    });
+
+    let selected = game_state.selected_pc_id.read().clone();
+    let controlled = game_state.controlled_pcs.read().clone();
+    let addable: Vec<PlayerCharacterData> = own_pcs
+        .read()
+        .iter()
+        .filter(|pc| Some(&pc.id) != selected.as_ref() && !controlled.iter().any(|c| c.id == pc.id))
+        .cloned()
+        .collect();
+
+    if controlled.len() < 2 && addable.is_empty() {
+        return rsx! {};
+    }
+
+    let send = move |msg| {
+        if let Err(e) = command_bus.send(msg) {
+            feedback.set(Some(format!("Failed to send: {}", e)));
+        } else {
+            feedback.set(None);
+        }
+    };
+
+    rsx! {
+        div {
+            class: "pc-switcher flex flex-col items-end gap-1",
+
+            if controlled.len() > 1 {
+                div {
+                    class: "flex gap-1 p-1 bg-black/70 rounded-lg",
+                    for pc in controlled.iter() {
+                        button {
+                            key: "{pc.id}",
+                            class: if Some(&pc.id) == selected.as_ref() {
+                                "px-3 py-1 bg-blue-600 text-white rounded text-xs border-0 cursor-default"
+                            } else {
+                                "px-3 py-1 bg-gray-800 hover:bg-gray-700 text-gray-300 rounded text-xs border-0 cursor-pointer"
+                            },
+                            disabled: Some(&pc.id) == selected.as_ref(),
+                            onclick: {
+                                let pc_id = pc.id.clone();
+                                let mut send = send.clone();
+                                move |_| {
+                                    if let Ok(id) = Uuid::parse_str(&pc_id) {
+                                        send(ClientMessageBuilder::switch_pc(id));
+                                    }
+                                }
+                            },
+                            "{pc.name}"
+                        }
+                    }
+                }
+            }
+
+            for pc in addable.iter() {
+                button {
+                    key: "{pc.id}",
+                    class: "px-3 py-1 bg-black/70 hover:bg-gray-800 text-gray-300 rounded-lg text-xs border-0 cursor-pointer",
+                    onclick: {
+                        let pc_id = pc.id.clone();
+                        let mut send = send.clone();
+                        move |_| {
+                            if let Ok(id) = Uuid::parse_str(&pc_id) {
+                                send(ClientMessageBuilder::control_pc(id));
+                            }
+                        }
+                    },
+                    "+ Also play {pc.name}"
+                }
+            }
+
+            if let Some(msg) = feedback.read().as_ref() {
+                div { class: "text-red-400 text-xs", "{msg}" }
+            }
+        }
+    }
+}
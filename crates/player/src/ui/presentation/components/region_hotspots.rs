@@ -0,0 +1,388 @@
+//! Region Hotspots - clickable areas on a region backdrop
+//!
+//! Hotspots are polygons in backdrop percentages, drawn into an SVG that
+//! stretches over the image so they line up however it's scaled.
+//!
+//! `HotspotLayer` is the player view: hovering outlines an area and clicking
+//! it hands the hotspot to `on_activate`. It sits under the character layer so
+//! sprites stay clickable. `HotspotEditor` is the DM tool for tracing new
+//! areas point by point and choosing what they do.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::HotspotData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_location_service;
+use wrldbldr_protocol::types::{HotspotPointData, HotspotTargetData};
+
+/// SVG `points` attribute for a polygon
+fn svg_points(points: &[HotspotPointData]) -> String {
+    points
+        .iter()
+        .map(|p| format!("{},{}", p.x, p.y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Short description of what a hotspot does, for the DM's list
+fn target_summary(target: &HotspotTargetData) -> &'static str {
+    match target {
+        HotspotTargetData::Interaction { .. } => "Interaction",
+        HotspotTargetData::Item { .. } => "Examine item",
+        HotspotTargetData::Exit { .. } => "Move to region",
+        HotspotTargetData::LocationExit { .. } => "Leave location",
+    }
+}
+
+/// Props for the HotspotLayer component
+#[derive(Props, Clone, PartialEq)]
+pub struct HotspotLayerProps {
+    pub hotspots: Vec<HotspotData>,
+    /// Called with the hotspot the player clicked
+    pub on_activate: EventHandler<HotspotData>,
+    /// Ignore clicks (e.g. while an action is being processed)
+    #[props(default = false)]
+    pub disabled: bool,
+}
+
+/// Clickable hotspot outlines over a backdrop
+#[component]
+pub fn HotspotLayer(props: HotspotLayerProps) -> Element {
+    if props.hotspots.is_empty() {
+        return rsx! {};
+    }
+    let cursor = if props.disabled {
+        "cursor-not-allowed"
+    } else {
+        "cursor-pointer"
+    };
+
+    rsx! {
+        svg {
+            class: "hotspot-layer absolute inset-0 w-full h-full pointer-events-none z-0",
+            view_box: "0 0 100 100",
+            preserve_aspect_ratio: "none",
+
+            for (i, hotspot) in props.hotspots.iter().cloned().enumerate() {
+                polygon {
+                    key: "{i}",
+                    points: "{svg_points(&hotspot.points)}",
+                    class: "pointer-events-auto {cursor} fill-transparent stroke-transparent hover:fill-white/10 hover:stroke-amber-300 focus:fill-white/10 focus:stroke-amber-300 outline-none",
+                    stroke_width: "0.4",
+                    vector_effect: "non-scaling-stroke",
+                    tabindex: "0",
+                    role: "button",
+                    onclick: {
+                        let hotspot = hotspot.clone();
+                        move |e: MouseEvent| {
+                            e.stop_propagation();
+                            if !props.disabled {
+                                props.on_activate.call(hotspot.clone());
+                            }
+                        }
+                    },
+                    onkeydown: {
+                        let hotspot = hotspot.clone();
+                        move |e: KeyboardEvent| {
+                            if e.key() == Key::Enter && !props.disabled {
+                                props.on_activate.call(hotspot.clone());
+                            }
+                        }
+                    },
+                    title { "{hotspot.label}" }
+                }
+            }
+        }
+    }
+}
+
+/// Props for the HotspotEditor component
+#[derive(Props, Clone, PartialEq)]
+pub struct HotspotEditorProps {
+    pub region_id: String,
+    /// What a new hotspot can do, with display labels
+    pub target_options: Vec<(HotspotTargetData, String)>,
+}
+
+/// DM tool for tracing and saving a region's hotspots
+///
+/// Goes inside a `relative` container over the backdrop image. Changes are
+/// local until saved; the server then broadcasts them to the world.
+#[component]
+pub fn HotspotEditor(props: HotspotEditorProps) -> Element {
+    let location_service = use_location_service();
+    let mut hotspots: Signal<Vec<HotspotData>> = use_signal(Vec::new);
+    // Points of the outline being traced, while drawing
+    let mut drawing: Signal<Option<Vec<HotspotPointData>>> = use_signal(|| None);
+    // Traced outline waiting for a label and target
+    let mut pending: Signal<Option<Vec<HotspotPointData>>> = use_signal(|| None);
+    let mut dirty = use_signal(|| false);
+    let mut saving = use_signal(|| false);
+    let mut surface: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    {
+        let service = location_service.clone();
+        let region_id = props.region_id.clone();
+        use_effect(use_reactive!(|region_id| {
+            let service = service.clone();
+            drawing.set(None);
+            pending.set(None);
+            dirty.set(false);
+            spawn_task(async move {
+                match service.get_region_hotspots(&region_id).await {
+                    Ok(loaded) => hotspots.set(loaded),
+                    Err(e) => error.set(Some(format!("Failed to load hotspots: {}", e))),
+                }
+            });
+        }));
+    }
+
+    let save = {
+        let service = location_service.clone();
+        let region_id = props.region_id.clone();
+        move |_| {
+            let service = service.clone();
+            let region_id = region_id.clone();
+            let current = hotspots.read().clone();
+            saving.set(true);
+            spawn_task(async move {
+                match service.set_region_hotspots(&region_id, current).await {
+                    Ok(saved) => {
+                        hotspots.set(saved);
+                        dirty.set(false);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to save hotspots: {}", e))),
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    let traced = drawing.read().clone();
+    let is_drawing = traced.is_some();
+    let can_finish = traced.as_ref().is_some_and(|points| points.len() >= 3);
+
+    rsx! {
+        div {
+            class: if is_drawing { "absolute inset-0 cursor-crosshair z-[5]" } else { "absolute inset-0 pointer-events-none z-[5]" },
+            onmounted: move |e| surface.set(Some(e.data())),
+            onclick: move |e: MouseEvent| {
+                e.stop_propagation();
+                if drawing.read().is_none() {
+                    return;
+                }
+                let point = e.element_coordinates();
+                let Some(mounted) = surface.read().clone() else {
+                    return;
+                };
+                spawn_task(async move {
+                    if let Ok(rect) = mounted.get_client_rect().await {
+                        if rect.width() > 0.0 && rect.height() > 0.0 {
+                            let x = (point.x / rect.width() * 100.0) as f32;
+                            let y = (point.y / rect.height() * 100.0) as f32;
+                            if let Some(points) = drawing.write().as_mut() {
+                                points.push(HotspotPointData {
+                                    x: x.clamp(0.0, 100.0),
+                                    y: y.clamp(0.0, 100.0),
+                                });
+                            }
+                        }
+                    }
+                });
+            },
+
+            svg {
+                class: "absolute inset-0 w-full h-full pointer-events-none",
+                view_box: "0 0 100 100",
+                preserve_aspect_ratio: "none",
+
+                for (i, hotspot) in hotspots.read().iter().enumerate() {
+                    polygon {
+                        key: "{i}",
+                        points: "{svg_points(&hotspot.points)}",
+                        class: "fill-amber-400/20 stroke-amber-300",
+                        stroke_width: "1",
+                        vector_effect: "non-scaling-stroke",
+                        title { "{hotspot.label}" }
+                    }
+                }
+
+                if let Some(points) = traced.as_ref().or(pending.read().as_ref()) {
+                    polygon {
+                        points: "{svg_points(points)}",
+                        class: "fill-sky-400/20 stroke-sky-300",
+                        stroke_width: "1",
+                        stroke_dasharray: "4 2",
+                        vector_effect: "non-scaling-stroke",
+                    }
+                }
+            }
+
+            div {
+                class: "absolute top-2 left-2 flex items-center gap-2 pointer-events-auto",
+                onclick: move |e| e.stop_propagation(),
+
+                if is_drawing {
+                    button {
+                        r#type: "button",
+                        disabled: !can_finish,
+                        onclick: move |_| {
+                            let points = drawing.take();
+                            pending.set(points);
+                        },
+                        class: "px-2 py-1 bg-sky-500 text-black text-xs rounded cursor-pointer border-0 disabled:opacity-50",
+                        if can_finish { "Finish outline" } else { "Click at least 3 points…" }
+                    }
+                    button {
+                        r#type: "button",
+                        onclick: move |_| drawing.set(None),
+                        class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer border-0",
+                        "Cancel"
+                    }
+                } else if pending.read().is_none() {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| drawing.set(Some(Vec::new())),
+                        class: "px-2 py-1 bg-black/70 text-white text-xs rounded cursor-pointer border-0",
+                        "+ Hotspot"
+                    }
+                    if *dirty.read() {
+                        button {
+                            r#type: "button",
+                            disabled: *saving.read(),
+                            onclick: save,
+                            class: "px-2 py-1 bg-amber-500 text-black text-xs rounded cursor-pointer border-0 disabled:opacity-50",
+                            if *saving.read() { "Saving…" } else { "Save hotspots" }
+                        }
+                    }
+                }
+            }
+
+            if let Some(points) = pending.read().clone() {
+                NewHotspotForm {
+                    target_options: props.target_options.clone(),
+                    on_add: move |(label, target): (String, HotspotTargetData)| {
+                        hotspots.write().push(HotspotData {
+                            label,
+                            points: points.clone(),
+                            target,
+                        });
+                        pending.set(None);
+                        dirty.set(true);
+                    },
+                    on_cancel: move |_| pending.set(None),
+                }
+            }
+
+            if !hotspots.read().is_empty() && !is_drawing {
+                ul {
+                    class: "absolute bottom-2 right-2 m-0 p-2 list-none bg-black/70 rounded flex flex-col gap-1 pointer-events-auto max-h-[40%] overflow-y-auto",
+                    onclick: move |e| e.stop_propagation(),
+                    for (i, hotspot) in hotspots.read().iter().enumerate() {
+                        li {
+                            key: "{i}",
+                            class: "flex items-center gap-2 text-xs text-white",
+                            span { "{hotspot.label}" }
+                            span { class: "text-gray-400", "{target_summary(&hotspot.target)}" }
+                            button {
+                                r#type: "button",
+                                onclick: move |_| {
+                                    hotspots.write().remove(i);
+                                    dirty.set(true);
+                                },
+                                class: "bg-transparent border-0 text-gray-400 hover:text-red-400 cursor-pointer p-0",
+                                aria_label: "Remove hotspot",
+                                "×"
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "absolute bottom-2 left-2 px-2 py-1 bg-red-600/90 text-white text-xs rounded pointer-events-auto cursor-pointer",
+                    onclick: move |e| {
+                        e.stop_propagation();
+                        error.set(None);
+                    },
+                    "{err}"
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct NewHotspotFormProps {
+    target_options: Vec<(HotspotTargetData, String)>,
+    on_add: EventHandler<(String, HotspotTargetData)>,
+    on_cancel: EventHandler<()>,
+}
+
+/// Label and target for a freshly traced outline
+#[component]
+fn NewHotspotForm(props: NewHotspotFormProps) -> Element {
+    let mut label = use_signal(String::new);
+    let mut target_index: Signal<Option<usize>> = use_signal(|| None);
+
+    let chosen = target_index
+        .read()
+        .and_then(|i| props.target_options.get(i))
+        .map(|(target, _)| target.clone());
+    let ready = chosen.is_some() && !label.read().trim().is_empty();
+
+    rsx! {
+        div {
+            class: "absolute top-12 left-2 w-64 bg-dark-surface/95 border border-white/10 rounded-lg p-3 shadow-xl pointer-events-auto z-10 flex flex-col gap-2",
+            onclick: move |e| e.stop_propagation(),
+
+            input {
+                r#type: "text",
+                value: "{label}",
+                placeholder: "Label (e.g. Dusty bookshelf)",
+                aria_label: "Hotspot label",
+                oninput: move |e| label.set(e.value()),
+                class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+
+            if props.target_options.is_empty() {
+                p { class: "m-0 text-gray-400 text-xs", "Nothing in this region to link to yet." }
+            } else {
+                select {
+                    aria_label: "Hotspot action",
+                    onchange: move |e| target_index.set(e.value().parse().ok()),
+                    class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    option { value: "", "Choose what it does…" }
+                    for (i, (_, option_label)) in props.target_options.iter().enumerate() {
+                        option { key: "{i}", value: "{i}", "{option_label}" }
+                    }
+                }
+            }
+
+            div {
+                class: "flex justify-end gap-2",
+                button {
+                    r#type: "button",
+                    onclick: move |_| props.on_cancel.call(()),
+                    class: "px-2 py-1 bg-gray-700 text-white text-xs rounded cursor-pointer border-0",
+                    "Discard"
+                }
+                button {
+                    r#type: "button",
+                    disabled: !ready,
+                    onclick: move |_| {
+                        if let Some(target) = chosen.clone() {
+                            props.on_add.call((label.read().trim().to_string(), target));
+                        }
+                    },
+                    class: "px-2 py-1 bg-amber-500 text-black text-xs rounded cursor-pointer border-0 disabled:opacity-50",
+                    "Add"
+                }
+            }
+        }
+    }
+}
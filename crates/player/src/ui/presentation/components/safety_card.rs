@@ -0,0 +1,89 @@
+//! Safety card component
+//!
+//! Lets a player raise an anonymous safety signal (X-card style). The DM is
+//! told the level but never who sent it.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::SafetySignalLevelData;
+use crate::infrastructure::websocket::ClientMessageBuilder;
+use crate::presentation::services::use_command_bus;
+
+/// Signal options offered to players: (level, label, description)
+const SIGNAL_OPTIONS: &[(SafetySignalLevelData, &str, &str)] = &[
+    (
+        SafetySignalLevelData::Check,
+        "Check in",
+        "Ask the DM to slow down",
+    ),
+    (
+        SafetySignalLevelData::Skip,
+        "Skip this",
+        "Move away from what's happening",
+    ),
+    (
+        SafetySignalLevelData::Stop,
+        "Stop",
+        "Pause the game so the table can talk",
+    ),
+];
+
+/// Safety card button with a small menu of signal levels
+#[component]
+pub fn SafetyCard() -> Element {
+    let command_bus = use_command_bus();
+    let mut is_open = use_signal(|| false);
+    let mut feedback: Signal<Option<String>> = use_signal(|| None);
+
+    rsx! {
+        div {
+            class: "safety-card flex flex-col items-end gap-1",
+
+            button {
+                onclick: move |_| {
+                    let open = *is_open.read();
+                    is_open.set(!open);
+                    feedback.set(None);
+                },
+                class: "px-3 py-1 bg-black/70 text-white border border-red-500 rounded-lg text-xs cursor-pointer",
+                title: "Raise an anonymous safety signal",
+                "✕ Safety"
+            }
+
+            if *is_open.read() {
+                div {
+                    class: "flex flex-col gap-1 p-2 bg-black/80 rounded-lg",
+                    for (level, label, description) in SIGNAL_OPTIONS.iter().copied() {
+                        button {
+                            key: "{label}",
+                            onclick: {
+                                let command_bus = command_bus.clone();
+                                move |_| {
+                                    let msg = ClientMessageBuilder::safety_signal(level);
+                                    match command_bus.send(msg) {
+                                        Ok(_) => {
+                                            is_open.set(false);
+                                            feedback.set(Some("Sent anonymously to the DM".to_string()));
+                                        }
+                                        Err(e) => feedback.set(Some(format!("Failed to send: {}", e))),
+                                    }
+                                }
+                            },
+                            class: "px-3 py-1 bg-gray-800 hover:bg-red-800 text-left rounded cursor-pointer",
+                            div { class: "text-white text-xs font-medium", "{label}" }
+                            div { class: "text-gray-400 text-xs", "{description}" }
+                        }
+                    }
+                }
+            }
+
+            if let Some(msg) = feedback.read().as_ref() {
+                div {
+                    class: "px-3 py-1 bg-black/70 text-gray-300 rounded-lg text-xs cursor-pointer",
+                    onclick: move |_| feedback.set(None),
+                    "{msg}"
+                }
+            }
+        }
+    }
+}
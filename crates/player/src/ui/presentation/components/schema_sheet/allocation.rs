@@ -0,0 +1,418 @@
+//! Stat allocation widget for character creation
+//!
+//! Offers the ways a creation step's `StatAllocation` allows stats to be
+//! assigned: point buy against a budget, distributing a fixed array, or
+//! typing values in directly.
+
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use serde_json::Value;
+use wrldbldr_domain::{FieldDefinition, PointBuy, StatAllocation, StatArray};
+
+use crate::presentation::utils::sheet_validation::as_integer;
+
+/// How the player is currently assigning stats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllocationMethod {
+    PointBuy,
+    /// Index into `StatAllocation::arrays`
+    Array(usize),
+    Manual,
+}
+
+impl AllocationMethod {
+    /// First method the allocation offers
+    pub fn default_for(allocation: &StatAllocation) -> Self {
+        if allocation.point_buy.is_some() {
+            Self::PointBuy
+        } else if !allocation.arrays.is_empty() {
+            Self::Array(0)
+        } else {
+            Self::Manual
+        }
+    }
+
+    fn label(self, allocation: &StatAllocation) -> String {
+        match self {
+            Self::PointBuy => "Point Buy".to_string(),
+            Self::Array(i) => allocation
+                .arrays
+                .get(i)
+                .map(|a| a.label.clone())
+                .unwrap_or_else(|| "Array".to_string()),
+            Self::Manual => "Enter Values".to_string(),
+        }
+    }
+}
+
+fn available_methods(allocation: &StatAllocation) -> Vec<AllocationMethod> {
+    let mut methods = Vec::new();
+    if allocation.point_buy.is_some() {
+        methods.push(AllocationMethod::PointBuy);
+    }
+    methods.extend((0..allocation.arrays.len()).map(AllocationMethod::Array));
+    if allocation.allow_manual || methods.is_empty() {
+        methods.push(AllocationMethod::Manual);
+    }
+    methods
+}
+
+fn assigned(allocation: &StatAllocation, values: &HashMap<String, Value>) -> Vec<Option<i32>> {
+    allocation
+        .field_ids
+        .iter()
+        .map(|id| {
+            values
+                .get(id)
+                .and_then(as_integer)
+                .and_then(|n| i32::try_from(n).ok())
+        })
+        .collect()
+}
+
+/// Why the current allocation can't be accepted, if it can't
+///
+/// Per-field ranges are checked by field validation; this covers the rules
+/// that span the whole group.
+pub fn allocation_problem(
+    allocation: &StatAllocation,
+    method: AllocationMethod,
+    values: &HashMap<String, Value>,
+) -> Option<String> {
+    let scores = assigned(allocation, values);
+    match method {
+        AllocationMethod::PointBuy => {
+            let point_buy = allocation.point_buy.as_ref()?;
+            let scores: Option<Vec<i32>> = scores.into_iter().collect();
+            let Some(scores) = scores else {
+                return Some("Assign every score".to_string());
+            };
+            match point_buy.total_cost(&scores) {
+                None => Some("Some scores can't be bought with points".to_string()),
+                Some(spent) if spent > point_buy.budget => Some(format!(
+                    "Over budget by {} points",
+                    spent - point_buy.budget
+                )),
+                Some(_) => None,
+            }
+        }
+        AllocationMethod::Array(i) => {
+            let array = allocation.arrays.get(i)?;
+            let scores: Option<Vec<i32>> = scores.into_iter().collect();
+            match scores {
+                Some(scores) if array.matches(&scores) => None,
+                Some(_) => Some(format!("Use each {} value exactly once", array.label)),
+                None => Some(format!("Assign every value from the {}", array.label)),
+            }
+        }
+        AllocationMethod::Manual => None,
+    }
+}
+
+/// Values to start from when switching method
+fn reset_value(allocation: &StatAllocation, method: AllocationMethod) -> Option<Value> {
+    match method {
+        AllocationMethod::PointBuy => allocation
+            .point_buy
+            .as_ref()
+            .and_then(PointBuy::min_value)
+            .map(Value::from),
+        AllocationMethod::Array(_) => Some(Value::Null),
+        AllocationMethod::Manual => None,
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StatAllocatorProps {
+    pub allocation: StatAllocation,
+    /// Definitions for the allocated fields, in allocation order
+    pub fields: Vec<FieldDefinition>,
+    pub values: HashMap<String, Value>,
+    pub method: AllocationMethod,
+    pub on_method_change: EventHandler<AllocationMethod>,
+    pub on_change: EventHandler<(String, Value)>,
+}
+
+/// Assign a group of stats by point buy, array, or direct entry
+#[component]
+pub fn StatAllocator(props: StatAllocatorProps) -> Element {
+    let allocation = props.allocation.clone();
+    let methods = available_methods(&allocation);
+    let method = props.method;
+    let problem = allocation_problem(&allocation, method, &props.values);
+    let scores = assigned(&allocation, &props.values);
+
+    let rows: Vec<(FieldDefinition, Option<i32>)> = allocation
+        .field_ids
+        .iter()
+        .zip(scores.iter().copied())
+        .filter_map(|(id, score)| {
+            props
+                .fields
+                .iter()
+                .find(|f| &f.id == id)
+                .map(|f| (f.clone(), score))
+        })
+        .collect();
+
+    let body = match method {
+        AllocationMethod::PointBuy => match allocation.point_buy.clone() {
+            Some(point_buy) => render_point_buy(&point_buy, &rows, props.on_change),
+            None => rsx! {},
+        },
+        AllocationMethod::Array(i) => match allocation.arrays.get(i).cloned() {
+            Some(array) => render_array(&array, &rows, props.on_change),
+            None => rsx! {},
+        },
+        AllocationMethod::Manual => render_manual(&rows, props.on_change),
+    };
+
+    rsx! {
+        div {
+            class: "stat-allocator flex flex-col gap-3 p-4 bg-black/20 rounded-lg",
+
+            if methods.len() > 1 {
+                div {
+                    class: "flex flex-wrap gap-2",
+                    role: "tablist",
+                    aria_label: "Allocation method",
+                    for option in methods {
+                        {
+                            let is_active = option == method;
+                            let allocation = allocation.clone();
+                            let on_method_change = props.on_method_change;
+                            let on_change = props.on_change;
+                            rsx! {
+                                button {
+                                    key: "{option.label(&allocation)}",
+                                    r#type: "button",
+                                    role: "tab",
+                                    aria_selected: "{is_active}",
+                                    onclick: move |_| {
+                                        if is_active {
+                                            return;
+                                        }
+                                        if let Some(start) = reset_value(&allocation, option) {
+                                            for id in &allocation.field_ids {
+                                                on_change.call((id.clone(), start.clone()));
+                                            }
+                                        }
+                                        on_method_change.call(option);
+                                    },
+                                    class: if is_active { "px-3 py-1 rounded text-sm bg-blue-500 text-white cursor-pointer" } else { "px-3 py-1 rounded text-sm bg-gray-700 text-gray-300 hover:bg-gray-600 cursor-pointer" },
+                                    "{option.label(&allocation)}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            {body}
+
+            if let Some(problem) = problem {
+                p { class: "text-amber-400 text-xs m-0", role: "status", "{problem}" }
+            }
+        }
+    }
+}
+
+fn render_point_buy(
+    point_buy: &PointBuy,
+    rows: &[(FieldDefinition, Option<i32>)],
+    on_change: EventHandler<(String, Value)>,
+) -> Element {
+    let spent: u32 = rows
+        .iter()
+        .filter_map(|(_, score)| score.and_then(|s| point_buy.cost_of(s)))
+        .sum();
+    let remaining = i64::from(point_buy.budget) - i64::from(spent);
+
+    rsx! {
+        div {
+            class: "text-sm text-gray-300",
+            aria_live: "polite",
+            "Points remaining: "
+            span {
+                class: if remaining < 0 { "font-semibold text-red-400" } else { "font-semibold text-white" },
+                "{remaining}"
+            }
+            " / {point_buy.budget}"
+        }
+        div {
+            class: "grid grid-cols-2 sm:grid-cols-3 gap-2",
+            for (field, score) in rows.iter().cloned() {
+                {
+                    let current = score.unwrap_or_default();
+                    let cost = score.and_then(|s| point_buy.cost_of(s));
+                    let lower = point_buy.cost_of(current - 1).map(|_| current - 1);
+                    let raise = point_buy.cost_of(current + 1).and_then(|next_cost| {
+                        let step = i64::from(next_cost) - i64::from(cost.unwrap_or(0));
+                        (step <= remaining).then_some(current + 1)
+                    });
+                    let (down_id, up_id) = (field.id.clone(), field.id.clone());
+                    rsx! {
+                        div {
+                            key: "{field.id}",
+                            class: "flex items-center justify-between gap-2 px-2 py-1 bg-black/30 rounded",
+                            span { class: "text-gray-300 text-sm", "{field.label}" }
+                            div {
+                                class: "flex items-center gap-1",
+                                button {
+                                    r#type: "button",
+                                    disabled: lower.is_none(),
+                                    aria_label: "Lower {field.label}",
+                                    onclick: move |_| {
+                                        if let Some(v) = lower {
+                                            on_change.call((down_id.clone(), Value::from(v)));
+                                        }
+                                    },
+                                    class: "w-6 h-6 rounded bg-gray-700 text-white disabled:opacity-40 cursor-pointer",
+                                    "−"
+                                }
+                                span { class: "w-6 text-center text-white font-semibold", "{current}" }
+                                button {
+                                    r#type: "button",
+                                    disabled: raise.is_none(),
+                                    aria_label: "Raise {field.label}",
+                                    onclick: move |_| {
+                                        if let Some(v) = raise {
+                                            on_change.call((up_id.clone(), Value::from(v)));
+                                        }
+                                    },
+                                    class: "w-6 h-6 rounded bg-gray-700 text-white disabled:opacity-40 cursor-pointer",
+                                    "+"
+                                }
+                                span {
+                                    class: "w-10 text-right text-gray-500 text-xs",
+                                    match cost {
+                                        Some(c) => format!("{} pt", c),
+                                        None => "—".to_string(),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_array(
+    array: &StatArray,
+    rows: &[(FieldDefinition, Option<i32>)],
+    on_change: EventHandler<(String, Value)>,
+) -> Element {
+    // Array values not yet used by any field
+    let mut unused = array.values.clone();
+    for (_, score) in rows {
+        if let Some(pos) = score.and_then(|s| unused.iter().position(|v| *v == s)) {
+            unused.remove(pos);
+        }
+    }
+    let mut choices = unused.clone();
+    choices.sort_unstable_by(|a, b| b.cmp(a));
+    choices.dedup();
+
+    rsx! {
+        div {
+            class: "text-sm text-gray-400",
+            "{array.label}: "
+            for (i, value) in array.values.iter().enumerate() {
+                span {
+                    key: "{i}",
+                    class: if unused.contains(value) { "mr-1 text-white" } else { "mr-1 line-through text-gray-600" },
+                    "{value}"
+                }
+            }
+        }
+        div {
+            class: "grid grid-cols-2 sm:grid-cols-3 gap-2",
+            for (field, score) in rows.iter().cloned() {
+                {
+                    let selected = score.map(|s| s.to_string()).unwrap_or_default();
+                    let mut options = choices.clone();
+                    if let Some(s) = score {
+                        if !options.contains(&s) {
+                            options.push(s);
+                            options.sort_unstable_by(|a, b| b.cmp(a));
+                        }
+                    }
+                    let field_id = field.id.clone();
+                    let select_id = format!("allocate-{}", field.id);
+                    rsx! {
+                        div {
+                            key: "{field.id}",
+                            class: "flex items-center justify-between gap-2 px-2 py-1 bg-black/30 rounded",
+                            label { r#for: "{select_id}", class: "text-gray-300 text-sm", "{field.label}" }
+                            select {
+                                id: "{select_id}",
+                                value: "{selected}",
+                                onchange: move |evt| {
+                                    let value = evt
+                                        .value()
+                                        .parse::<i32>()
+                                        .map(Value::from)
+                                        .unwrap_or(Value::Null);
+                                    on_change.call((field_id.clone(), value));
+                                },
+                                class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                                option { value: "", selected: score.is_none(), "—" }
+                                for value in options {
+                                    option {
+                                        key: "{value}",
+                                        value: "{value}",
+                                        selected: score == Some(value),
+                                        "{value}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_manual(
+    rows: &[(FieldDefinition, Option<i32>)],
+    on_change: EventHandler<(String, Value)>,
+) -> Element {
+    rsx! {
+        div {
+            class: "grid grid-cols-2 sm:grid-cols-3 gap-2",
+            for (field, score) in rows.iter().cloned() {
+                {
+                    let text = score.map(|s| s.to_string()).unwrap_or_default();
+                    let field_id = field.id.clone();
+                    let input_id = format!("allocate-{}", field.id);
+                    rsx! {
+                        div {
+                            key: "{field.id}",
+                            class: "flex items-center justify-between gap-2 px-2 py-1 bg-black/30 rounded",
+                            label { r#for: "{input_id}", class: "text-gray-300 text-sm", "{field.label}" }
+                            input {
+                                id: "{input_id}",
+                                r#type: "number",
+                                value: "{text}",
+                                onchange: move |evt| {
+                                    let raw = evt.value();
+                                    let value = match raw.trim().parse::<i64>() {
+                                        Ok(n) => Value::from(n),
+                                        Err(_) if raw.trim().is_empty() => Value::Null,
+                                        Err(_) => Value::String(raw),
+                                    };
+                                    on_change.call((field_id.clone(), value));
+                                },
+                                class: "w-16 px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
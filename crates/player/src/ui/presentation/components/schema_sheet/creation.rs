@@ -0,0 +1,245 @@
+//! Guided character creation from a schema's creation steps
+//!
+//! Each `CreationStep` shows the sections it lists, with its stat allocation
+//! (if any) in place of the plain inputs for those fields. Derived fields are
+//! left out: the game system calculates them once the character exists.
+
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use serde_json::Value;
+use wrldbldr_domain::{CharacterSheetSchema, CreationStep, FieldDefinition, SchemaFieldType};
+
+use super::allocation::{allocation_problem, AllocationMethod, StatAllocator};
+use super::SchemaSheet;
+use crate::presentation::utils::sheet_validation::is_empty_value;
+use crate::presentation::utils::validate_field;
+
+/// Creation steps in the order they should be shown
+pub fn ordered_steps(schema: &CharacterSheetSchema) -> Vec<CreationStep> {
+    let mut steps = schema.creation_steps.clone();
+    steps.sort_by_key(|s| s.order);
+    steps
+}
+
+/// Fields a player fills in during a step
+fn step_fields<'a>(
+    schema: &'a CharacterSheetSchema,
+    step: &'a CreationStep,
+) -> impl Iterator<Item = &'a FieldDefinition> {
+    step.section_ids
+        .iter()
+        .filter_map(|id| schema.sections.iter().find(|s| &s.id == id))
+        .flat_map(|s| s.fields.iter())
+        .filter(|f| f.editable && f.derived_from.is_none())
+}
+
+/// Problems blocking a step: per-field messages, then one for the allocation
+pub fn step_problems(
+    schema: &CharacterSheetSchema,
+    step: &CreationStep,
+    values: &HashMap<String, Value>,
+    method: Option<AllocationMethod>,
+) -> (HashMap<String, String>, Option<String>) {
+    let errors = step_fields(schema, step)
+        .filter_map(|field| {
+            let value = values.get(&field.id).unwrap_or(&Value::Null);
+            validate_field(field, value).map(|message| (field.id.clone(), message))
+        })
+        .collect();
+
+    let allocation = step.allocation.as_ref().and_then(|allocation| {
+        let method = method.unwrap_or_else(|| AllocationMethod::default_for(allocation));
+        allocation_problem(allocation, method, values)
+    });
+
+    (errors, allocation)
+}
+
+/// The step's sections, minus derived fields and fields the allocation covers
+fn step_schema(schema: &CharacterSheetSchema, step: &CreationStep) -> CharacterSheetSchema {
+    let allocated: &[String] = step
+        .allocation
+        .as_ref()
+        .map(|a| a.field_ids.as_slice())
+        .unwrap_or_default();
+
+    let sections = step
+        .section_ids
+        .iter()
+        .filter_map(|id| schema.sections.iter().find(|s| &s.id == id))
+        .map(|section| {
+            let mut section = section.clone();
+            section
+                .fields
+                .retain(|f| f.derived_from.is_none() && !allocated.contains(&f.id));
+            section.collapsible = false;
+            section
+        })
+        .filter(|section| !section.fields.is_empty())
+        .collect();
+
+    CharacterSheetSchema {
+        system_id: schema.system_id.clone(),
+        system_name: schema.system_name.clone(),
+        sections,
+        creation_steps: Vec::new(),
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SchemaCreationStepProps {
+    pub schema: CharacterSheetSchema,
+    pub step: CreationStep,
+    pub values: HashMap<String, Value>,
+    /// Problems found when the player last tried to continue
+    #[props(default)]
+    pub errors: HashMap<String, String>,
+    /// Chosen allocation method; the allocation's first method if None
+    #[props(default)]
+    pub method: Option<AllocationMethod>,
+    pub on_method_change: EventHandler<AllocationMethod>,
+    pub on_change: EventHandler<(String, Value)>,
+}
+
+/// One creation step: its description, allocation, and fields
+#[component]
+pub fn SchemaCreationStep(props: SchemaCreationStepProps) -> Element {
+    let step = props.step.clone();
+    let sheet = step_schema(&props.schema, &step);
+
+    let allocation = step.allocation.clone().map(|allocation| {
+        let fields: Vec<FieldDefinition> = props
+            .schema
+            .sections
+            .iter()
+            .flat_map(|s| s.fields.iter())
+            .filter(|f| allocation.field_ids.contains(&f.id))
+            .cloned()
+            .collect();
+        let method = props
+            .method
+            .unwrap_or_else(|| AllocationMethod::default_for(&allocation));
+        (allocation, fields, method)
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+
+            div {
+                h3 { class: "m-0 text-lg text-white", "{step.label}" }
+                if !step.description.is_empty() {
+                    p { class: "mt-1 mb-0 text-gray-400 text-sm", "{step.description}" }
+                }
+            }
+
+            if let Some((allocation, fields, method)) = allocation {
+                StatAllocator {
+                    allocation,
+                    fields,
+                    values: props.values.clone(),
+                    method,
+                    on_method_change: props.on_method_change,
+                    on_change: props.on_change,
+                }
+            }
+
+            if !sheet.sections.is_empty() {
+                SchemaSheet {
+                    schema: sheet,
+                    values: props.values.clone(),
+                    errors: props.errors.clone(),
+                    on_change: props.on_change,
+                }
+            }
+        }
+    }
+}
+
+/// How a chosen value reads in the review
+fn display_value(field: &FieldDefinition, value: &Value) -> String {
+    let text = |v: &Value| match v {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+
+    match (&field.field_type, value) {
+        (SchemaFieldType::Select { options, .. }, Value::String(s)) => options
+            .iter()
+            .find(|o| &o.value == s)
+            .map(|o| o.label.clone())
+            .unwrap_or_else(|| s.clone()),
+        (
+            SchemaFieldType::Skill {
+                proficiency_levels, ..
+            },
+            Value::String(s),
+        ) => proficiency_levels
+            .iter()
+            .find(|o| &o.value == s)
+            .map(|o| o.label.clone())
+            .unwrap_or_else(|| s.clone()),
+        (
+            SchemaFieldType::Boolean {
+                checked_label,
+                unchecked_label,
+            },
+            Value::Bool(b),
+        ) => {
+            let label = if *b { checked_label } else { unchecked_label };
+            label
+                .clone()
+                .unwrap_or_else(|| if *b { "Yes" } else { "No" }.to_string())
+        }
+        (SchemaFieldType::SavingThrow { .. }, Value::Bool(b)) => {
+            if *b { "Proficient" } else { "—" }.to_string()
+        }
+        (_, Value::Array(items)) => items.iter().map(text).collect::<Vec<_>>().join(", "),
+        (_, v) => text(v),
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CreationReviewProps {
+    pub schema: CharacterSheetSchema,
+    pub values: HashMap<String, Value>,
+}
+
+/// Summary of everything chosen, grouped by creation step
+#[component]
+pub fn CreationReview(props: CreationReviewProps) -> Element {
+    let groups: Vec<(String, Vec<(String, String)>)> = ordered_steps(&props.schema)
+        .iter()
+        .map(|step| {
+            let entries = step_fields(&props.schema, step)
+                .filter_map(|field| {
+                    let value = props.values.get(&field.id)?;
+                    (!is_empty_value(value))
+                        .then(|| (field.label.clone(), display_value(field, value)))
+                })
+                .collect();
+            (step.label.clone(), entries)
+        })
+        .filter(|(_, entries): &(String, Vec<(String, String)>)| !entries.is_empty())
+        .collect();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            for (label, entries) in groups {
+                section {
+                    key: "{label}",
+                    h4 { class: "m-0 mb-2 text-gray-400 text-sm uppercase", "{label}" }
+                    dl {
+                        class: "grid grid-cols-[auto,1fr] gap-x-4 gap-y-1 m-0",
+                        for (name, value) in entries {
+                            dt { class: "text-gray-400 text-sm", "{name}" }
+                            dd { class: "m-0 text-white text-sm", "{value}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
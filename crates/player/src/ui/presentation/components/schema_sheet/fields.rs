@@ -0,0 +1,989 @@
+//! Per-type field renderers for the schema-driven character sheet
+
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use serde_json::Value;
+use wrldbldr_domain::{
+    ConditionLevel, EntityRefType, FieldDefinition, LadderLabel, ProficiencyOption, ResourceColor,
+    SchemaFieldType, SchemaSelectOption,
+};
+
+use crate::presentation::utils::sheet_validation::as_integer;
+use crate::presentation::utils::validate_field;
+
+const INPUT_CLASS: &str = "w-full px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm disabled:opacity-60";
+
+/// Text for a value shown in an input
+fn value_text(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+/// "+2" / "-1" style modifier
+fn signed(n: i64) -> String {
+    if n >= 0 {
+        format!("+{}", n)
+    } else {
+        n.to_string()
+    }
+}
+
+/// Number typed into an input, or the raw text so validation can reject it
+fn parse_number(raw: &str) -> Value {
+    match raw.trim().parse::<i64>() {
+        Ok(n) => Value::from(n),
+        Err(_) => Value::String(raw.to_string()),
+    }
+}
+
+fn as_bool(value: Option<&Value>) -> bool {
+    match value {
+        Some(Value::Bool(b)) => *b,
+        Some(v) => as_integer(v).is_some_and(|n| n != 0),
+        None => false,
+    }
+}
+
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Proficiency option for a stored value
+///
+/// Accepts the option value itself, or the Engine's numeric storage
+/// (2 expertise, 1 proficient, -1 half, 0 none), matched by multiplier.
+fn selected_proficiency<'a>(
+    options: &'a [ProficiencyOption],
+    value: Option<&Value>,
+) -> Option<&'a ProficiencyOption> {
+    match value? {
+        Value::String(s) => options.iter().find(|o| &o.value == s),
+        v => {
+            let multiplier = match as_integer(v)? {
+                -1 => 0.5,
+                n => n as f32,
+            };
+            options
+                .iter()
+                .find(|o| (o.multiplier - multiplier).abs() < f32::EPSILON)
+        }
+    }
+}
+
+fn resource_color_class(color: ResourceColor) -> &'static str {
+    match color {
+        ResourceColor::Red => "bg-red-600",
+        ResourceColor::Blue => "bg-blue-600",
+        ResourceColor::Green => "bg-green-600",
+        ResourceColor::Purple => "bg-purple-600",
+        ResourceColor::Orange => "bg-orange-500",
+        ResourceColor::Gray => "bg-gray-500",
+    }
+}
+
+fn entity_ref_label(entity_type: EntityRefType) -> &'static str {
+    match entity_type {
+        EntityRefType::Class => "Class",
+        EntityRefType::Race => "Race",
+        EntityRefType::Background => "Background",
+        EntityRefType::Playbook => "Playbook",
+        EntityRefType::Archetype => "Archetype",
+        EntityRefType::Occupation => "Occupation",
+        EntityRefType::Custom => "Name",
+    }
+}
+
+fn ladder_label(labels: &[LadderLabel], rung: i32) -> String {
+    match labels.iter().find(|l| l.value == rung) {
+        Some(l) => format!("{} {}", signed(i64::from(rung)), l.label),
+        None => signed(i64::from(rung)),
+    }
+}
+
+/// Percentage of `current` out of `max`, for bar widths
+fn percent(current: i64, max: i64) -> i64 {
+    if max <= 0 {
+        return 0;
+    }
+    (current.clamp(0, max) * 100) / max
+}
+
+/// What a field renderer needs to validate and report an edit
+#[derive(Clone)]
+struct FieldCtx {
+    field: FieldDefinition,
+    locked: bool,
+    local_error: Signal<Option<String>>,
+    on_change: EventHandler<(String, Value)>,
+}
+
+impl FieldCtx {
+    /// Show the validation result without sending anything
+    fn check(&self, value: &Value) {
+        let mut local_error = self.local_error;
+        local_error.set(validate_field(&self.field, value));
+    }
+
+    /// Validate and, if the value is acceptable, pass it on
+    fn commit(&self, value: Value) {
+        let mut local_error = self.local_error;
+        let problem = validate_field(&self.field, &value);
+        let ok = problem.is_none();
+        local_error.set(problem);
+        if ok {
+            self.on_change.call((self.field.id.clone(), value));
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub(super) struct SchemaFieldViewProps {
+    field: FieldDefinition,
+    /// Stored value for this field, if any
+    value: Option<Value>,
+    /// Every stored value, for fields that reference others
+    values: HashMap<String, Value>,
+    calculated: HashMap<String, Value>,
+    error: Option<String>,
+    read_only: bool,
+    on_change: EventHandler<(String, Value)>,
+}
+
+/// One labelled field with its inline error
+#[component]
+pub(super) fn SchemaFieldView(props: SchemaFieldViewProps) -> Element {
+    let local_error: Signal<Option<String>> = use_signal(|| None);
+
+    let field = props.field.clone();
+    let derived = field.derived_from.is_some();
+    let ctx = FieldCtx {
+        locked: props.read_only || derived || !field.editable,
+        field: field.clone(),
+        local_error,
+        on_change: props.on_change,
+    };
+
+    // Derived values come from the game system, not from storage
+    let value = if derived {
+        props
+            .calculated
+            .get(&field.id)
+            .cloned()
+            .or(props.value.clone())
+    } else {
+        props.value.clone()
+    };
+    let lookup = |id: &str| -> Option<Value> {
+        props
+            .values
+            .get(id)
+            .or_else(|| props.calculated.get(id))
+            .cloned()
+    };
+
+    // Our own check wins; the Engine's message shows until the next edit
+    let error = local_error.read().clone().or(props.error.clone());
+    let input_id = format!("sheet-field-{}", field.id);
+    let error_id = format!("{}-error", input_id);
+    let described_by = if error.is_some() {
+        error_id.clone()
+    } else {
+        String::new()
+    };
+
+    let body = match &field.field_type {
+        SchemaFieldType::Text {
+            multiline,
+            max_length,
+        } => render_text(&ctx, &input_id, value.as_ref(), *multiline, *max_length),
+        SchemaFieldType::Integer { show_modifier, .. } => {
+            let modifier = show_modifier
+                .then(|| value.as_ref().and_then(as_integer))
+                .flatten();
+            render_number(&ctx, &input_id, value.as_ref(), modifier)
+        }
+        SchemaFieldType::AbilityScore { .. } => {
+            let modifier = props
+                .calculated
+                .get(&format!("{}_MOD", field.id))
+                .and_then(as_integer)
+                .or_else(|| {
+                    value
+                        .as_ref()
+                        .and_then(as_integer)
+                        .map(|score| (score - 10).div_euclid(2))
+                });
+            render_number(&ctx, &input_id, value.as_ref(), modifier)
+        }
+        SchemaFieldType::Skill {
+            ability,
+            proficiency_levels,
+        } => {
+            let base = field.id.strip_suffix("_PROF").unwrap_or(&field.id);
+            let total = props
+                .calculated
+                .get(&format!("{}_MOD", base))
+                .and_then(as_integer);
+            render_skill(
+                &ctx,
+                &input_id,
+                value.as_ref(),
+                ability,
+                proficiency_levels,
+                total,
+            )
+        }
+        SchemaFieldType::SavingThrow { ability } => {
+            let base = field.id.strip_suffix("_PROF").unwrap_or(&field.id);
+            let total = props.calculated.get(base).and_then(as_integer);
+            render_saving_throw(&ctx, &input_id, value.as_ref(), ability, total)
+        }
+        SchemaFieldType::Boolean {
+            checked_label,
+            unchecked_label,
+        } => render_boolean(
+            &ctx,
+            &input_id,
+            value.as_ref(),
+            checked_label.as_deref(),
+            unchecked_label.as_deref(),
+        ),
+        SchemaFieldType::Select {
+            options,
+            allow_custom,
+        } => render_select(&ctx, &input_id, value.as_ref(), options, *allow_custom),
+        SchemaFieldType::MultiSelect {
+            options,
+            max_selections,
+        } => render_multi_select(&ctx, value.as_ref(), options, *max_selections),
+        SchemaFieldType::ResourceBar { max_field, color } => {
+            let max = lookup(max_field).as_ref().and_then(as_integer);
+            render_resource_bar(&ctx, &input_id, value.as_ref(), max, *color)
+        }
+        SchemaFieldType::DicePool { max_dice, die_type } => {
+            render_dice_pool(&ctx, value.as_ref(), *max_dice, *die_type)
+        }
+        SchemaFieldType::LadderRating { min, max, labels } => {
+            render_ladder(&ctx, &input_id, value.as_ref(), *min, *max, labels)
+        }
+        SchemaFieldType::PercentileSkill { show_derived } => {
+            render_percentile(&ctx, &input_id, value.as_ref(), *show_derived)
+        }
+        SchemaFieldType::Clock { segments } => render_clock(&ctx, value.as_ref(), *segments),
+        SchemaFieldType::ConditionTrack { levels } => {
+            render_condition_track(&ctx, value.as_ref(), levels)
+        }
+        SchemaFieldType::EntityRef { entity_type } => {
+            render_entity_ref(&ctx, &input_id, value.as_ref(), *entity_type)
+        }
+        SchemaFieldType::Tags => render_tags(&ctx, &input_id, value.as_ref()),
+        SchemaFieldType::XpProgress {
+            current_field,
+            next_level_field,
+        } => {
+            let current = lookup(current_field).as_ref().and_then(as_integer);
+            let next = lookup(next_level_field).as_ref().and_then(as_integer);
+            render_xp_progress(current, next)
+        }
+        SchemaFieldType::ModifierList { .. } => render_modifier_list(value.as_ref()),
+        SchemaFieldType::Unknown => render_raw(value.as_ref()),
+    };
+
+    rsx! {
+        div {
+            class: "sheet-field flex flex-col gap-1 {field.layout.css_class.clone().unwrap_or_default()}",
+            title: field.description.clone().unwrap_or_default(),
+
+            label {
+                r#for: "{input_id}",
+                class: "flex items-center gap-1 text-gray-400 text-xs",
+                "{field.label}"
+                if field.required {
+                    span { class: "text-red-400", aria_hidden: "true", "*" }
+                }
+                if derived {
+                    span { class: "text-gray-600 italic", "(calculated)" }
+                }
+            }
+
+            div {
+                aria_describedby: "{described_by}",
+                {body}
+            }
+
+            if let Some(message) = error {
+                p { id: "{error_id}", class: "text-red-400 text-xs m-0", role: "alert", "{message}" }
+            }
+        }
+    }
+}
+
+fn render_text(
+    ctx: &FieldCtx,
+    input_id: &str,
+    value: Option<&Value>,
+    multiline: bool,
+    max_length: Option<usize>,
+) -> Element {
+    let text = value_text(value);
+    let placeholder = ctx.field.placeholder.clone().unwrap_or_default();
+    let max_length = max_length.map(|m| m.to_string()).unwrap_or_default();
+    let (check, commit) = (ctx.clone(), ctx.clone());
+
+    if multiline {
+        rsx! {
+            textarea {
+                id: "{input_id}",
+                value: "{text}",
+                placeholder: "{placeholder}",
+                disabled: ctx.locked,
+                rows: 3,
+                oninput: move |evt| check.check(&Value::String(evt.value())),
+                onchange: move |evt| commit.commit(Value::String(evt.value())),
+                class: "{INPUT_CLASS} resize-y",
+            }
+        }
+    } else {
+        rsx! {
+            input {
+                id: "{input_id}",
+                r#type: "text",
+                value: "{text}",
+                placeholder: "{placeholder}",
+                maxlength: "{max_length}",
+                disabled: ctx.locked,
+                oninput: move |evt| check.check(&Value::String(evt.value())),
+                onchange: move |evt| commit.commit(Value::String(evt.value())),
+                class: "{INPUT_CLASS}",
+            }
+        }
+    }
+}
+
+fn render_number(
+    ctx: &FieldCtx,
+    input_id: &str,
+    value: Option<&Value>,
+    modifier: Option<i64>,
+) -> Element {
+    let text = value_text(value);
+    let (check, commit) = (ctx.clone(), ctx.clone());
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2",
+            input {
+                id: "{input_id}",
+                r#type: "number",
+                value: "{text}",
+                disabled: ctx.locked,
+                oninput: move |evt| check.check(&parse_number(&evt.value())),
+                onchange: move |evt| commit.commit(parse_number(&evt.value())),
+                class: "{INPUT_CLASS} max-w-[6rem]",
+            }
+            if let Some(modifier) = modifier {
+                span { class: "text-amber-300 text-sm font-semibold", "{signed(modifier)}" }
+            }
+        }
+    }
+}
+
+fn render_skill(
+    ctx: &FieldCtx,
+    input_id: &str,
+    value: Option<&Value>,
+    ability: &str,
+    options: &[ProficiencyOption],
+    total: Option<i64>,
+) -> Element {
+    let selected = selected_proficiency(options, value)
+        .map(|o| o.value.clone())
+        .unwrap_or_default();
+    let options = options.to_vec();
+    let commit = ctx.clone();
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2",
+            if let Some(total) = total {
+                span { class: "w-8 text-right text-amber-300 text-sm font-semibold", "{signed(total)}" }
+            }
+            span { class: "text-gray-500 text-xs uppercase", "{ability}" }
+            select {
+                id: "{input_id}",
+                value: "{selected}",
+                disabled: ctx.locked,
+                onchange: move |evt| commit.commit(Value::String(evt.value())),
+                class: "{INPUT_CLASS}",
+                for option in options {
+                    option {
+                        key: "{option.value}",
+                        value: "{option.value}",
+                        selected: option.value == selected,
+                        "{option.label}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_saving_throw(
+    ctx: &FieldCtx,
+    input_id: &str,
+    value: Option<&Value>,
+    ability: &str,
+    total: Option<i64>,
+) -> Element {
+    let checked = as_bool(value);
+    let commit = ctx.clone();
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2",
+            input {
+                id: "{input_id}",
+                r#type: "checkbox",
+                checked,
+                disabled: ctx.locked,
+                onchange: move |evt| commit.commit(Value::Bool(evt.checked())),
+            }
+            span { class: "text-gray-500 text-xs uppercase", "{ability}" }
+            if let Some(total) = total {
+                span { class: "text-amber-300 text-sm font-semibold", "{signed(total)}" }
+            }
+        }
+    }
+}
+
+fn render_boolean(
+    ctx: &FieldCtx,
+    input_id: &str,
+    value: Option<&Value>,
+    checked_label: Option<&str>,
+    unchecked_label: Option<&str>,
+) -> Element {
+    let checked = as_bool(value);
+    let state_label = if checked {
+        checked_label.unwrap_or_default().to_string()
+    } else {
+        unchecked_label.unwrap_or_default().to_string()
+    };
+    let commit = ctx.clone();
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2",
+            input {
+                id: "{input_id}",
+                r#type: "checkbox",
+                checked,
+                disabled: ctx.locked,
+                onchange: move |evt| commit.commit(Value::Bool(evt.checked())),
+            }
+            if !state_label.is_empty() {
+                span { class: "text-gray-300 text-sm", "{state_label}" }
+            }
+        }
+    }
+}
+
+fn render_select(
+    ctx: &FieldCtx,
+    input_id: &str,
+    value: Option<&Value>,
+    options: &[SchemaSelectOption],
+    allow_custom: bool,
+) -> Element {
+    let selected = value_text(value);
+    let options = options.to_vec();
+    let (check, commit) = (ctx.clone(), ctx.clone());
+
+    if allow_custom {
+        // Free text with the listed options as suggestions
+        let list_id = format!("{}-options", input_id);
+        return rsx! {
+            input {
+                id: "{input_id}",
+                r#type: "text",
+                list: "{list_id}",
+                value: "{selected}",
+                disabled: ctx.locked,
+                oninput: move |evt| check.check(&Value::String(evt.value())),
+                onchange: move |evt| commit.commit(Value::String(evt.value())),
+                class: "{INPUT_CLASS}",
+            }
+            datalist {
+                id: "{list_id}",
+                for option in options {
+                    option { key: "{option.value}", value: "{option.value}", "{option.label}" }
+                }
+            }
+        };
+    }
+
+    let description = options
+        .iter()
+        .find(|o| o.value == selected)
+        .and_then(|o| o.description.clone());
+
+    rsx! {
+        select {
+            id: "{input_id}",
+            value: "{selected}",
+            disabled: ctx.locked,
+            onchange: move |evt| commit.commit(Value::String(evt.value())),
+            class: "{INPUT_CLASS}",
+            option { value: "", selected: selected.is_empty(), "Select…" }
+            for option in options {
+                option {
+                    key: "{option.value}",
+                    value: "{option.value}",
+                    selected: option.value == selected,
+                    "{option.label}"
+                }
+            }
+        }
+        if let Some(description) = description {
+            p { class: "text-gray-500 text-xs m-0", "{description}" }
+        }
+    }
+}
+
+fn render_multi_select(
+    ctx: &FieldCtx,
+    value: Option<&Value>,
+    options: &[SchemaSelectOption],
+    max_selections: Option<usize>,
+) -> Element {
+    let chosen = string_list(value);
+    let at_limit = max_selections.is_some_and(|max| chosen.len() >= max);
+    let options = options.to_vec();
+
+    rsx! {
+        div {
+            class: "flex flex-wrap gap-x-4 gap-y-1",
+            role: "group",
+            aria_label: "{ctx.field.label}",
+            for option in options {
+                {
+                    let is_checked = chosen.contains(&option.value);
+                    let commit = ctx.clone();
+                    let chosen = chosen.clone();
+                    let option_value = option.value.clone();
+                    rsx! {
+                        label {
+                            key: "{option.value}",
+                            class: "flex items-center gap-1 text-gray-300 text-sm",
+                            title: option.description.clone().unwrap_or_default(),
+                            input {
+                                r#type: "checkbox",
+                                checked: is_checked,
+                                disabled: ctx.locked || (at_limit && !is_checked),
+                                onchange: move |evt| {
+                                    let mut next = chosen.clone();
+                                    if evt.checked() {
+                                        next.push(option_value.clone());
+                                    } else {
+                                        next.retain(|v| v != &option_value);
+                                    }
+                                    commit.commit(Value::from(next));
+                                },
+                            }
+                            "{option.label}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_resource_bar(
+    ctx: &FieldCtx,
+    input_id: &str,
+    value: Option<&Value>,
+    max: Option<i64>,
+    color: ResourceColor,
+) -> Element {
+    let current = value.and_then(as_integer).unwrap_or(0);
+    let text = value_text(value);
+    let (check, commit) = (ctx.clone(), ctx.clone());
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            div {
+                class: "flex items-center gap-2",
+                input {
+                    id: "{input_id}",
+                    r#type: "number",
+                    value: "{text}",
+                    disabled: ctx.locked,
+                    oninput: move |evt| check.check(&parse_number(&evt.value())),
+                    onchange: move |evt| commit.commit(parse_number(&evt.value())),
+                    class: "{INPUT_CLASS} max-w-[5rem]",
+                }
+                if let Some(max) = max {
+                    span { class: "text-gray-400 text-sm", "/ {max}" }
+                }
+            }
+            if let Some(max) = max {
+                div {
+                    class: "h-2 bg-gray-800 rounded overflow-hidden",
+                    role: "progressbar",
+                    aria_valuemin: "0",
+                    aria_valuemax: "{max}",
+                    aria_valuenow: "{current}",
+                    div {
+                        class: "h-full {resource_color_class(color)}",
+                        style: "width: {percent(current, max)}%;",
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Row of clickable pips; clicking the last filled pip empties it
+fn render_pips(
+    ctx: &FieldCtx,
+    filled: i64,
+    count: u8,
+    shape_class: &'static str,
+    describe: impl Fn(u8) -> String,
+) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-wrap items-center gap-1",
+            role: "group",
+            aria_label: "{ctx.field.label}",
+            for i in 1..=count {
+                {
+                    let is_filled = i64::from(i) <= filled;
+                    let commit = ctx.clone();
+                    let next = if i64::from(i) == filled { i - 1 } else { i };
+                    rsx! {
+                        button {
+                            key: "{i}",
+                            r#type: "button",
+                            disabled: ctx.locked,
+                            aria_pressed: "{is_filled}",
+                            aria_label: "{describe(i)}",
+                            onclick: move |_| commit.commit(Value::from(next)),
+                            class: if is_filled {
+                                format!("w-4 h-4 border border-amber-400 bg-amber-400 cursor-pointer disabled:cursor-default {}", shape_class)
+                            } else {
+                                format!("w-4 h-4 border border-gray-500 bg-transparent cursor-pointer disabled:cursor-default {}", shape_class)
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_dice_pool(ctx: &FieldCtx, value: Option<&Value>, max_dice: u8, die_type: u8) -> Element {
+    let filled = value.and_then(as_integer).unwrap_or(0);
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2",
+            {render_pips(ctx, filled, max_dice, "rounded-full", |i| format!("{} dice", i))}
+            span { class: "text-gray-400 text-xs", "{filled}d{die_type}" }
+        }
+    }
+}
+
+fn render_clock(ctx: &FieldCtx, value: Option<&Value>, segments: u8) -> Element {
+    let filled = value.and_then(as_integer).unwrap_or(0);
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2",
+            {render_pips(ctx, filled, segments, "rounded-sm", |i| format!("{} of {} segments", i, segments))}
+            span { class: "text-gray-400 text-xs", "{filled}/{segments}" }
+        }
+    }
+}
+
+fn render_ladder(
+    ctx: &FieldCtx,
+    input_id: &str,
+    value: Option<&Value>,
+    min: i32,
+    max: i32,
+    labels: &[LadderLabel],
+) -> Element {
+    let current = value.and_then(as_integer);
+    let selected = current.map(|n| n.to_string()).unwrap_or_default();
+    let rungs: Vec<(i32, String)> = (min..=max)
+        .rev()
+        .map(|rung| (rung, ladder_label(labels, rung)))
+        .collect();
+    let commit = ctx.clone();
+
+    rsx! {
+        select {
+            id: "{input_id}",
+            value: "{selected}",
+            disabled: ctx.locked,
+            onchange: move |evt| commit.commit(parse_number(&evt.value())),
+            class: "{INPUT_CLASS}",
+            if current.is_none() {
+                option { value: "", selected: true, "—" }
+            }
+            for (rung, label) in rungs {
+                option {
+                    key: "{rung}",
+                    value: "{rung}",
+                    selected: current == Some(i64::from(rung)),
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+fn render_percentile(
+    ctx: &FieldCtx,
+    input_id: &str,
+    value: Option<&Value>,
+    show_derived: bool,
+) -> Element {
+    let text = value_text(value);
+    let skill = value.and_then(as_integer);
+    let (check, commit) = (ctx.clone(), ctx.clone());
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2",
+            input {
+                id: "{input_id}",
+                r#type: "number",
+                value: "{text}",
+                disabled: ctx.locked,
+                oninput: move |evt| check.check(&parse_number(&evt.value())),
+                onchange: move |evt| commit.commit(parse_number(&evt.value())),
+                class: "{INPUT_CLASS} max-w-[5rem]",
+            }
+            span { class: "text-gray-400 text-sm", "%" }
+            if show_derived {
+                if let Some(skill) = skill {
+                    span {
+                        class: "text-gray-500 text-xs",
+                        title: "Hard / Extreme",
+                        "{skill / 2} / {skill / 5}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_condition_track(
+    ctx: &FieldCtx,
+    value: Option<&Value>,
+    levels: &[ConditionLevel],
+) -> Element {
+    let current = value.and_then(as_integer).unwrap_or(0);
+    let levels = levels.to_vec();
+    let clear = ctx.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            role: "radiogroup",
+            aria_label: "{ctx.field.label}",
+            button {
+                r#type: "button",
+                role: "radio",
+                aria_checked: "{current == 0}",
+                disabled: ctx.locked,
+                onclick: move |_| clear.commit(Value::from(0)),
+                class: if current == 0 { "text-left px-2 py-1 rounded text-sm bg-green-900/60 text-green-200" } else { "text-left px-2 py-1 rounded text-sm bg-black/20 text-gray-400 hover:bg-black/40 cursor-pointer" },
+                "Unharmed"
+            }
+            for level in levels {
+                {
+                    let is_current = i64::from(level.level) == current;
+                    let commit = ctx.clone();
+                    let n = level.level;
+                    rsx! {
+                        button {
+                            key: "{level.level}",
+                            r#type: "button",
+                            role: "radio",
+                            aria_checked: "{is_current}",
+                            disabled: ctx.locked,
+                            onclick: move |_| commit.commit(Value::from(n)),
+                            class: if is_current { "text-left px-2 py-1 rounded text-sm bg-red-900/60 text-red-100" } else { "text-left px-2 py-1 rounded text-sm bg-black/20 text-gray-400 hover:bg-black/40 cursor-pointer" },
+                            span { "{level.label}" }
+                            if let Some(effect) = level.effect.as_ref() {
+                                span { class: "block text-xs text-gray-500", "{effect}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_entity_ref(
+    ctx: &FieldCtx,
+    input_id: &str,
+    value: Option<&Value>,
+    entity_type: EntityRefType,
+) -> Element {
+    let text = value_text(value);
+    let placeholder = ctx
+        .field
+        .placeholder
+        .clone()
+        .unwrap_or_else(|| entity_ref_label(entity_type).to_string());
+    let (check, commit) = (ctx.clone(), ctx.clone());
+
+    rsx! {
+        input {
+            id: "{input_id}",
+            r#type: "text",
+            value: "{text}",
+            placeholder: "{placeholder}",
+            disabled: ctx.locked,
+            oninput: move |evt| check.check(&Value::String(evt.value())),
+            onchange: move |evt| commit.commit(Value::String(evt.value())),
+            class: "{INPUT_CLASS}",
+        }
+    }
+}
+
+fn render_tags(ctx: &FieldCtx, input_id: &str, value: Option<&Value>) -> Element {
+    let tags = string_list(value);
+    let text = tags.join(", ");
+    let commit = ctx.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            if !tags.is_empty() {
+                div {
+                    class: "flex flex-wrap gap-1",
+                    for tag in tags {
+                        span { key: "{tag}", class: "px-2 py-0.5 bg-gray-700 text-gray-200 rounded text-xs", "{tag}" }
+                    }
+                }
+            }
+            if !ctx.locked {
+                input {
+                    id: "{input_id}",
+                    r#type: "text",
+                    value: "{text}",
+                    placeholder: "Comma-separated",
+                    onchange: move |evt| {
+                        let next: Vec<String> = evt
+                            .value()
+                            .split(',')
+                            .map(|t| t.trim().to_string())
+                            .filter(|t| !t.is_empty())
+                            .collect();
+                        commit.commit(Value::from(next));
+                    },
+                    class: "{INPUT_CLASS}",
+                }
+            }
+        }
+    }
+}
+
+fn render_xp_progress(current: Option<i64>, next: Option<i64>) -> Element {
+    let current = current.unwrap_or(0);
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            span {
+                class: "text-gray-300 text-sm",
+                match next {
+                    Some(next) => format!("{} / {}", current, next),
+                    None => current.to_string(),
+                }
+            }
+            if let Some(next) = next {
+                div {
+                    class: "h-2 bg-gray-800 rounded overflow-hidden",
+                    role: "progressbar",
+                    aria_valuemin: "0",
+                    aria_valuemax: "{next}",
+                    aria_valuenow: "{current}",
+                    div { class: "h-full bg-amber-500", style: "width: {percent(current, next)}%;" }
+                }
+            }
+        }
+    }
+}
+
+/// Modifiers are applied by the game system; the sheet only lists them
+fn render_modifier_list(value: Option<&Value>) -> Element {
+    let entries: Vec<String> = value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| match item {
+                    Value::String(s) => s.clone(),
+                    Value::Object(map) => {
+                        let name = ["name", "source", "label"]
+                            .iter()
+                            .find_map(|k| map.get(*k).and_then(Value::as_str))
+                            .unwrap_or("Modifier");
+                        match map.get("value").and_then(as_integer) {
+                            Some(n) => format!("{} {}", name, signed(n)),
+                            None => name.to_string(),
+                        }
+                    }
+                    other => other.to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    rsx! {
+        if entries.is_empty() {
+            p { class: "text-gray-500 text-sm italic m-0", "None" }
+        } else {
+            ul {
+                class: "flex flex-col gap-0.5 m-0 pl-4 list-disc",
+                for (i, entry) in entries.into_iter().enumerate() {
+                    li { key: "{i}", class: "text-gray-300 text-sm", "{entry}" }
+                }
+            }
+        }
+    }
+}
+
+/// Fallback for field types this client doesn't know: show the data as-is
+fn render_raw(value: Option<&Value>) -> Element {
+    let text = match value {
+        None | Some(Value::Null) => "—".to_string(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => serde_json::to_string_pretty(other).unwrap_or_else(|_| other.to_string()),
+    };
+
+    rsx! {
+        pre { class: "text-gray-300 text-xs whitespace-pre-wrap m-0", "{text}" }
+    }
+}
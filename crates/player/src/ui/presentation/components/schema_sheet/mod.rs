@@ -0,0 +1,178 @@
+//! Schema-driven character sheet
+//!
+//! Renders a `CharacterSheetSchema` from the Engine's game system, so any
+//! system the Engine knows (including ones with dice pools, ladders, clocks,
+//! and condition tracks) gets a usable sheet without client changes.
+//!
+//! Derived fields and fields the schema marks non-editable are shown
+//! read-only. Edits are checked with [`validate_field`] before they're passed
+//! to `on_change`; errors the Engine returns are shown next to their field
+//! via `errors`.
+//!
+//! Uses `wrldbldr_domain` sheet schema types directly (approved exception for
+//! pure value objects, as with `DiceFormula`).
+//!
+//! [`validate_field`]: crate::presentation::utils::validate_field
+
+mod allocation;
+mod creation;
+mod fields;
+
+pub use allocation::{AllocationMethod, StatAllocator};
+pub use creation::{ordered_steps, step_problems, CreationReview, SchemaCreationStep};
+
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use serde_json::Value;
+use wrldbldr_domain::{CharacterSheetSchema, FieldDefinition, SchemaSection, SectionType};
+
+use fields::SchemaFieldView;
+
+/// Props for the schema sheet
+#[derive(Props, Clone, PartialEq)]
+pub struct SchemaSheetProps {
+    pub schema: CharacterSheetSchema,
+    /// Current field values (field_id -> value)
+    pub values: HashMap<String, Value>,
+    /// Values calculated by the game system (field_id -> value)
+    #[props(default)]
+    pub calculated: HashMap<String, Value>,
+    /// Errors returned by the Engine (field_id -> message)
+    #[props(default)]
+    pub errors: HashMap<String, String>,
+    /// Render every field read-only
+    #[props(default = false)]
+    pub read_only: bool,
+    /// Called with (field_id, value) after a field passes validation
+    pub on_change: EventHandler<(String, Value)>,
+}
+
+/// Full character sheet for a game system schema
+#[component]
+pub fn SchemaSheet(props: SchemaSheetProps) -> Element {
+    rsx! {
+        div {
+            class: "schema-sheet flex flex-col gap-4",
+            for section in props.schema.sections.iter().cloned() {
+                SchemaSectionView {
+                    key: "{section.id}",
+                    section,
+                    values: props.values.clone(),
+                    calculated: props.calculated.clone(),
+                    errors: props.errors.clone(),
+                    read_only: props.read_only,
+                    on_change: props.on_change,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SchemaSectionViewProps {
+    section: SchemaSection,
+    values: HashMap<String, Value>,
+    calculated: HashMap<String, Value>,
+    errors: HashMap<String, String>,
+    read_only: bool,
+    on_change: EventHandler<(String, Value)>,
+}
+
+/// Default grid span (out of 12) for fields in a section
+fn default_span(section_type: SectionType) -> u8 {
+    match section_type {
+        SectionType::AbilityScores => 2,
+        SectionType::Skills | SectionType::Combat | SectionType::Resources => 6,
+        SectionType::Clocks => 4,
+        _ => 12,
+    }
+}
+
+/// Inline grid placement from the field's layout hints
+fn field_style(field: &FieldDefinition, section_type: SectionType) -> String {
+    let span = field
+        .layout
+        .width
+        .unwrap_or_else(|| default_span(section_type))
+        .clamp(1, 12);
+    if field.layout.new_row {
+        format!("grid-column: 1 / span {};", span)
+    } else {
+        format!("grid-column: span {} / span {};", span, span)
+    }
+}
+
+#[component]
+fn SchemaSectionView(props: SchemaSectionViewProps) -> Element {
+    let section = props.section.clone();
+    let mut is_collapsed = use_signal(|| section.collapsed_default);
+
+    // Stable sort keeps schema order for fields without an explicit order
+    let mut sorted_fields = section.fields.clone();
+    sorted_fields.sort_by_key(|f| f.layout.order.unwrap_or(0));
+    let placed_fields: Vec<(String, FieldDefinition)> = sorted_fields
+        .into_iter()
+        .map(|f| (field_style(&f, section.section_type), f))
+        .collect();
+
+    let collapsed = section.collapsible && *is_collapsed.read();
+    let content_id = format!("sheet-section-{}", section.id);
+
+    rsx! {
+        section {
+            class: "sheet-section bg-black/20 rounded-lg overflow-hidden",
+            aria_label: "{section.label}",
+
+            if section.collapsible {
+                button {
+                    r#type: "button",
+                    onclick: move |_| {
+                        let current = *is_collapsed.read();
+                        is_collapsed.set(!current);
+                    },
+                    class: "w-full flex justify-between items-center px-4 py-2 bg-black/30 text-left cursor-pointer border-0",
+                    aria_expanded: "{!collapsed}",
+                    aria_controls: "{content_id}",
+                    h3 { class: "text-gray-200 text-sm m-0 font-semibold uppercase tracking-wide", "{section.label}" }
+                    span { class: "text-gray-500 text-sm", aria_hidden: "true", if collapsed { "+" } else { "−" } }
+                }
+            } else {
+                div {
+                    class: "px-4 py-2 bg-black/30",
+                    h3 { class: "text-gray-200 text-sm m-0 font-semibold uppercase tracking-wide", "{section.label}" }
+                }
+            }
+
+            if !collapsed {
+                div {
+                    id: "{content_id}",
+                    class: "p-4 flex flex-col gap-3",
+
+                    if let Some(description) = section.description.as_ref() {
+                        p { class: "text-gray-400 text-xs m-0", "{description}" }
+                    }
+
+                    div {
+                        class: "grid grid-cols-12 gap-3",
+                        for (style, field) in placed_fields {
+                            div {
+                                key: "{field.id}",
+                                style: "{style}",
+                                SchemaFieldView {
+                                    value: props.values.get(&field.id).cloned(),
+                                    values: props.values.clone(),
+                                    calculated: props.calculated.clone(),
+                                    error: props.errors.get(&field.id).cloned(),
+                                    read_only: props.read_only,
+                                    on_change: props.on_change,
+                                    field,
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
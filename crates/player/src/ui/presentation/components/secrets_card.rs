@@ -0,0 +1,88 @@
+//! Secrets card component
+//!
+//! Shows the player the secrets their PC keeps. Nobody else at the table
+//! sees them until the DM reveals one, after which it is marked as out.
+
+use dioxus::prelude::*;
+
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_secret_service;
+use crate::presentation::state::use_game_state;
+
+/// Secrets button with a small list of the selected PC's secrets
+#[component]
+pub fn SecretsCard() -> Element {
+    let secret_service = use_secret_service();
+    let game_state = use_game_state();
+    let mut is_open = use_signal(|| false);
+
+    // Load the PC's secrets whenever the selected PC changes; later changes
+    // arrive as PcSecretUpdated/PcSecretRemoved
+    {
+        let game_state = game_state.clone();
+        use_effect(move || {
+            let Some(pc_id) = game_state.selected_pc_id.read().clone() else {
+                return;
+            };
+            let service = secret_service.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service.list_secrets(&pc_id).await {
+                    Ok(secrets) => game_state.set_pc_secrets(&pc_id, secrets),
+                    Err(e) => tracing::warn!("Failed to load secrets: {}", e),
+                }
+            });
+        });
+    }
+
+    let selected = game_state.selected_pc_id.read().clone();
+    let secrets: Vec<_> = game_state
+        .pc_secrets
+        .read()
+        .iter()
+        .filter(|s| Some(&s.pc_id) == selected.as_ref())
+        .cloned()
+        .collect();
+    if secrets.is_empty() {
+        return rsx! {};
+    }
+    let kept = secrets.iter().filter(|s| s.revealed_at.is_none()).count();
+
+    rsx! {
+        div {
+            class: "secrets-card flex flex-col items-end gap-1",
+
+            button {
+                onclick: move |_| {
+                    let open = *is_open.read();
+                    is_open.set(!open);
+                },
+                class: "px-3 py-1 bg-black/70 text-white border border-purple-500 rounded-lg text-xs cursor-pointer",
+                title: "Only you and the DM know these",
+                "Secrets ({kept})"
+            }
+
+            if *is_open.read() {
+                div {
+                    class: "flex flex-col gap-2 p-2 bg-black/80 rounded-lg max-w-[280px]",
+                    for secret in secrets {
+                        div {
+                            key: "{secret.id}",
+                            class: if secret.revealed_at.is_some() { "opacity-60" } else { "" },
+                            div {
+                                class: "flex items-center justify-between gap-2",
+                                span { class: "text-white text-xs font-semibold", "{secret.title}" }
+                                if secret.revealed_at.is_some() {
+                                    span { class: "text-amber-400 text-xs", "Out" }
+                                }
+                            }
+                            if !secret.content.is_empty() {
+                                div { class: "text-gray-300 text-xs whitespace-pre-wrap", "{secret.content}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Accessibility Panel - Device-local display preferences
+//!
+//! Unlike the Engine's application settings, these are saved in this
+//! device's storage and take effect immediately.
+
+use crate::presentation::state::use_accessibility_state;
+use crate::use_platform;
+use dioxus::prelude::*;
+
+/// Accessibility Panel component
+#[component]
+pub fn AccessibilityPanel() -> Element {
+    let platform = use_platform();
+    let mut accessibility = use_accessibility_state();
+    let reduced_motion = *accessibility.reduced_motion.read();
+
+    rsx! {
+        div {
+            class: "accessibility-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                h3 { class: "text-white text-lg font-medium mb-1", "Accessibility" }
+                p {
+                    class: "text-gray-500 text-sm",
+                    "Saved on this device only."
+                }
+            }
+
+            label {
+                class: "flex items-center gap-3 cursor-pointer",
+
+                // Toggle switch
+                div {
+                    class: "relative",
+
+                    input {
+                        r#type: "checkbox",
+                        role: "switch",
+                        class: "sr-only peer",
+                        checked: reduced_motion,
+                        onchange: move |evt| {
+                            accessibility.set_reduced_motion(&platform, evt.checked());
+                        }
+                    }
+
+                    div {
+                        class: "w-11 h-6 bg-gray-700 peer-focus:outline-none peer-focus:ring-2 peer-focus:ring-blue-500 rounded-full peer peer-checked:after:translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:left-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all peer-checked:bg-blue-600"
+                    }
+                }
+
+                div {
+                    class: "flex flex-col",
+
+                    span {
+                        class: "text-gray-300 text-sm font-medium",
+                        "Reduce Motion"
+                    }
+
+                    span {
+                        class: "text-gray-500 text-xs",
+                        "Show dialogue instantly instead of typing it out, and turn off animations."
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Analytics Panel - Where the campaign's play has gone
+//!
+//! Shows each PC's share of the spotlight, how often each NPC is spoken to
+//! and how often each location is visited. Every list runs quietest first,
+//! so whoever has been left out and whatever never gets touched is on top.
+
+use crate::application::services::world_service::CampaignAnalytics;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+
+/// Props for the Analytics Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct AnalyticsPanelProps {
+    /// The world whose analytics are shown
+    pub world_id: String,
+}
+
+/// Analytics Panel component
+#[component]
+pub fn AnalyticsPanel(props: AnalyticsPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut analytics = use_signal(|| None::<CampaignAnalytics>);
+    let mut is_loading = use_signal(|| true);
+    let mut error = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_refresh = props.world_id.clone();
+    let service_for_load = world_service.clone();
+    let service_for_refresh = world_service.clone();
+
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+            match svc.get_analytics(&wid).await {
+                Ok(loaded) => analytics.set(Some(loaded)),
+                Err(e) => error.set(Some(format!("Failed to load analytics: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let handle_refresh = move |_| {
+        let svc = service_for_refresh.clone();
+        let wid = world_id_for_refresh.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+            match svc.get_analytics(&wid).await {
+                Ok(loaded) => analytics.set(Some(loaded)),
+                Err(e) => error.set(Some(format!("Failed to load analytics: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "analytics-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Campaign Analytics" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "Who has had the spotlight and what the party has touched, quietest first."
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-gray-600 text-white rounded-md hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_refresh,
+                    disabled: *is_loading.read(),
+                    "Refresh"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading analytics..." }
+            } else if let Some(analytics) = analytics.read().as_ref() {
+                h4 { class: "text-gray-300 text-sm font-medium", "Spotlight" }
+                if analytics.pcs.is_empty() {
+                    div { class: "text-gray-500 text-sm", "No active PCs." }
+                } else {
+                    table {
+                        class: "w-full text-sm text-left",
+                        thead {
+                            tr {
+                                class: "text-gray-500 text-xs uppercase",
+                                th { class: "py-1", "PC" }
+                                th { class: "py-1", "Dialogue" }
+                                th { class: "py-1", "Challenges" }
+                                th { class: "py-1", "Story events" }
+                            }
+                        }
+                        tbody {
+                            for pc in analytics.pcs.iter() {
+                                tr {
+                                    key: "{pc.pc_id}",
+                                    class: "text-gray-300 border-t border-gray-800",
+                                    td { class: "py-1", "{pc.name}" }
+                                    td { class: "py-1", "{pc.dialogue_exchanges}" }
+                                    td { class: "py-1", "{pc.challenges}" }
+                                    td { class: "py-1", "{pc.story_events}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                h4 { class: "text-gray-300 text-sm font-medium", "NPCs" }
+                if analytics.npcs.is_empty() {
+                    div { class: "text-gray-500 text-sm", "No NPCs yet." }
+                } else {
+                    table {
+                        class: "w-full text-sm text-left",
+                        thead {
+                            tr {
+                                class: "text-gray-500 text-xs uppercase",
+                                th { class: "py-1", "NPC" }
+                                th { class: "py-1", "Dialogue" }
+                                th { class: "py-1", "PCs" }
+                            }
+                        }
+                        tbody {
+                            for npc in analytics.npcs.iter() {
+                                tr {
+                                    key: "{npc.npc_id}",
+                                    class: "text-gray-300 border-t border-gray-800",
+                                    td { class: "py-1", "{npc.name}" }
+                                    td { class: "py-1", "{npc.dialogue_exchanges}" }
+                                    td { class: "py-1", "{npc.pcs}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                h4 { class: "text-gray-300 text-sm font-medium", "Locations" }
+                if analytics.locations.is_empty() {
+                    div { class: "text-gray-500 text-sm", "No locations yet." }
+                } else {
+                    table {
+                        class: "w-full text-sm text-left",
+                        thead {
+                            tr {
+                                class: "text-gray-500 text-xs uppercase",
+                                th { class: "py-1", "Location" }
+                                th { class: "py-1", "Visits" }
+                                th { class: "py-1", "Visitors" }
+                            }
+                        }
+                        tbody {
+                            for location in analytics.locations.iter() {
+                                tr {
+                                    key: "{location.location_id}",
+                                    class: "text-gray-300 border-t border-gray-800",
+                                    td { class: "py-1", "{location.name}" }
+                                    td { class: "py-1", "{location.visits}" }
+                                    td { class: "py-1", "{location.visitors}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
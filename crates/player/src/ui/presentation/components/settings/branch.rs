@@ -0,0 +1,96 @@
+//! Branch Panel - Fork the world into an alternate timeline
+//!
+//! Copies the world's locations, regions, NPCs, items and narrative events
+//! into a new world the DM can playtest a risky session or run a flashback
+//! in. The original world is left untouched.
+
+use crate::application::services::world_service::WorldSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+
+/// Props for the Branch Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct BranchPanelProps {
+    /// The world to branch
+    pub world_id: String,
+}
+
+/// Branch Panel component
+#[component]
+pub fn BranchPanel(props: BranchPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut name = use_signal(String::new);
+    let mut branch = use_signal(|| None::<WorldSummary>);
+    let mut is_branching = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let handle_branch = move |_| {
+        let svc = world_service.clone();
+        let wid = props.world_id.clone();
+        let branch_name = name.read().trim().to_string();
+        if branch_name.is_empty() {
+            error.set(Some("Name the branch first".to_string()));
+            return;
+        }
+        spawn_task(async move {
+            is_branching.set(true);
+            error.set(None);
+            branch.set(None);
+            match svc.branch_world(&wid, &branch_name).await {
+                Ok(created) => {
+                    branch.set(Some(created));
+                    name.set(String::new());
+                }
+                Err(e) => error.set(Some(format!("Failed to branch world: {}", e))),
+            }
+            is_branching.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "branch-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                h3 { class: "text-white text-lg font-medium mb-1", "Alternate Timeline" }
+                p {
+                    class: "text-gray-500 text-sm",
+                    "Copy this world into a separate world to playtest a risky session or run a flashback. Nothing done in the branch reaches this world."
+                }
+            }
+
+            div {
+                class: "flex gap-2",
+                input {
+                    r#type: "text",
+                    placeholder: "Branch name",
+                    value: "{name}",
+                    oninput: move |e| name.set(e.value()),
+                    class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded-md text-white text-sm",
+                }
+                button {
+                    class: "px-4 py-2 bg-purple-600 text-white rounded-md hover:bg-purple-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_branch,
+                    disabled: *is_branching.read(),
+                    if *is_branching.read() { "Branching..." } else { "Branch World" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if let Some(created) = branch.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-900 bg-opacity-30 text-green-400 rounded-md text-sm",
+                    "Created \"{created.name}\". Open it from the world list."
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,290 @@
+//! Content Safety Panel - Per-world lines, veils, and content rating
+//!
+//! Lets the DM set the world's content rating and list lines (topics that
+//! never appear) and veils (topics kept off-screen). The engine adds these to
+//! every LLM prompt and flags generated dialogue that mentions them.
+//!
+//! Also configures how the engine reacts to anonymous safety signals from
+//! players.
+
+use crate::application::dto::{ContentRatingData, ContentSafetyData, SafetySignalLevelData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+
+/// Props for the Content Safety Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct ContentSafetyPanelProps {
+    /// The world whose safety settings are edited
+    pub world_id: String,
+}
+
+/// Content Safety Panel component
+#[component]
+pub fn ContentSafetyPanel(props: ContentSafetyPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut rating = use_signal(ContentRatingData::default);
+    // Lines and veils are edited as one topic per line
+    let mut lines_text = use_signal(String::new);
+    let mut veils_text = use_signal(String::new);
+    let defaults = ContentSafetyData::default();
+    let mut pause_queue_at = use_signal(|| defaults.pause_queue_at);
+    let mut reject_pending_at = use_signal(|| defaults.reject_pending_at);
+    let mut is_loading = use_signal(|| true);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut success_message = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_save = props.world_id.clone();
+    let service_for_load = world_service.clone();
+    let service_for_save = world_service.clone();
+
+    // Load settings on mount or world_id change
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+
+            match svc.get_content_safety(&wid).await {
+                Ok(config) => {
+                    rating.set(config.content_rating);
+                    lines_text.set(config.lines.join("\n"));
+                    veils_text.set(config.veils.join("\n"));
+                    pause_queue_at.set(config.pause_queue_at);
+                    reject_pending_at.set(config.reject_pending_at);
+                }
+                Err(e) => {
+                    error.set(Some(format!(
+                        "Failed to load content safety settings: {}",
+                        e
+                    )));
+                }
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let handle_save = move |_| {
+        let svc = service_for_save.clone();
+        let wid = world_id_for_save.clone();
+        let config = ContentSafetyData {
+            content_rating: *rating.read(),
+            lines: split_topics(&lines_text.read()),
+            veils: split_topics(&veils_text.read()),
+            pause_queue_at: *pause_queue_at.read(),
+            reject_pending_at: *reject_pending_at.read(),
+        };
+        spawn_task(async move {
+            is_saving.set(true);
+            error.set(None);
+            success_message.set(None);
+
+            match svc.update_content_safety(&wid, config).await {
+                Ok(saved) => {
+                    rating.set(saved.content_rating);
+                    lines_text.set(saved.lines.join("\n"));
+                    veils_text.set(saved.veils.join("\n"));
+                    pause_queue_at.set(saved.pause_queue_at);
+                    reject_pending_at.set(saved.reject_pending_at);
+                    success_message.set(Some("Content safety settings saved!".to_string()));
+                }
+                Err(e) => {
+                    error.set(Some(format!(
+                        "Failed to save content safety settings: {}",
+                        e
+                    )));
+                }
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let rating_value = match *rating.read() {
+        ContentRatingData::General => "general",
+        ContentRatingData::Mature => "mature",
+        ContentRatingData::Teen | ContentRatingData::Unknown => "teen",
+    };
+
+    rsx! {
+        div {
+            class: "content-safety-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Content Safety" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "Hard limits for generated content. Flagged NPC dialogue is held for your review."
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_save,
+                    disabled: *is_loading.read() || *is_saving.read(),
+                    if *is_saving.read() { "Saving..." } else { "Save" }
+                }
+            }
+
+            if let Some(msg) = success_message.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-900 bg-opacity-30 text-green-400 rounded-md text-sm",
+                    "{msg}"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading content safety settings..." }
+            } else {
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Content Rating" }
+                    select {
+                        value: "{rating_value}",
+                        onchange: move |e| {
+                            rating.set(match e.value().as_str() {
+                                "general" => ContentRatingData::General,
+                                "mature" => ContentRatingData::Mature,
+                                _ => ContentRatingData::Teen,
+                            });
+                            success_message.set(None);
+                        },
+                        class: "p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                        option { value: "general", "General - suitable for all ages" }
+                        option { value: "teen", "Teen - no gore or sexual content" }
+                        option { value: "mature", "Mature - mature themes, no gratuitous detail" }
+                    }
+                }
+
+                TopicListField {
+                    label: "Lines",
+                    description: "Topics that never appear, not even off-screen. One per line.",
+                    value: lines_text.read().clone(),
+                    onchange: move |val: String| {
+                        lines_text.set(val);
+                        success_message.set(None);
+                    },
+                }
+
+                TopicListField {
+                    label: "Veils",
+                    description: "Topics that may happen in the story but stay off-screen. One per line.",
+                    value: veils_text.read().clone(),
+                    onchange: move |val: String| {
+                        veils_text.set(val);
+                        success_message.set(None);
+                    },
+                }
+
+                SignalThresholdField {
+                    label: "Pause play on safety signal",
+                    description: "Block new player actions until you resume play.",
+                    value: *pause_queue_at.read(),
+                    onchange: move |val| {
+                        pause_queue_at.set(val);
+                        success_message.set(None);
+                    },
+                }
+
+                SignalThresholdField {
+                    label: "Reject pending NPC dialogue on safety signal",
+                    description: "Discard NPC responses that have not reached players yet.",
+                    value: *reject_pending_at.read(),
+                    onchange: move |val| {
+                        reject_pending_at.set(val);
+                        success_message.set(None);
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct TopicListFieldProps {
+    label: &'static str,
+    description: &'static str,
+    value: String,
+    onchange: EventHandler<String>,
+}
+
+#[component]
+fn TopicListField(props: TopicListFieldProps) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            label { class: "text-gray-300 text-sm", "{props.label}" }
+            p { class: "text-gray-500 text-xs m-0", "{props.description}" }
+            textarea {
+                value: "{props.value}",
+                oninput: move |e| props.onchange.call(e.value()),
+                class: "w-full min-h-[80px] p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm resize-y box-border",
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SignalThresholdFieldProps {
+    label: &'static str,
+    description: &'static str,
+    value: Option<SafetySignalLevelData>,
+    onchange: EventHandler<Option<SafetySignalLevelData>>,
+}
+
+/// Select for the lowest safety signal level that triggers a reaction
+#[component]
+fn SignalThresholdField(props: SignalThresholdFieldProps) -> Element {
+    let value = match props.value {
+        None => "never",
+        Some(SafetySignalLevelData::Check) | Some(SafetySignalLevelData::Unknown) => "check",
+        Some(SafetySignalLevelData::Skip) => "skip",
+        Some(SafetySignalLevelData::Stop) => "stop",
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            label { class: "text-gray-300 text-sm", "{props.label}" }
+            p { class: "text-gray-500 text-xs m-0", "{props.description}" }
+            select {
+                value: "{value}",
+                onchange: move |e| {
+                    props.onchange.call(match e.value().as_str() {
+                        "check" => Some(SafetySignalLevelData::Check),
+                        "skip" => Some(SafetySignalLevelData::Skip),
+                        "stop" => Some(SafetySignalLevelData::Stop),
+                        _ => None,
+                    });
+                },
+                class: "p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                option { value: "check", "Any signal (check-in or stronger)" }
+                option { value: "skip", "Skip or Stop" }
+                option { value: "stop", "Stop only" }
+                option { value: "never", "Never" }
+            }
+        }
+    }
+}
+
+/// Split a one-topic-per-line text area into a topic list.
+fn split_topics(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect()
+}
@@ -0,0 +1,289 @@
+//! Custom Fields Panel - DM-defined fields on characters, locations and items
+//!
+//! Each field has a type (text, number or a fixed list of options) and
+//! belongs to one entity type. Values are filled in from the entity's form in
+//! Creator Mode; fields marked visible also show up in player detail views.
+
+use crate::application::dto::{CustomFieldDefinitionData, CustomFieldTypeData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+use wrldbldr_protocol::EntityType;
+
+const ENTITY_TYPES: [(EntityType, &str, &str); 3] = [
+    (EntityType::Character, "character", "Character"),
+    (EntityType::Location, "location", "Location"),
+    (EntityType::Item, "item", "Item"),
+];
+
+const FIELD_TYPES: [(CustomFieldTypeData, &str, &str); 3] = [
+    (CustomFieldTypeData::Text, "text", "Text"),
+    (CustomFieldTypeData::Number, "number", "Number"),
+    (CustomFieldTypeData::Select, "select", "Select"),
+];
+
+/// Props for the Custom Fields Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct CustomFieldsPanelProps {
+    /// The world whose field schema is edited
+    pub world_id: String,
+}
+
+/// Custom Fields Panel component
+#[component]
+pub fn CustomFieldsPanel(props: CustomFieldsPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut fields = use_signal(Vec::<CustomFieldDefinitionData>::new);
+    let mut is_loading = use_signal(|| true);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut success_message = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_save = props.world_id.clone();
+    let service_for_load = world_service.clone();
+    let service_for_save = world_service.clone();
+
+    // Load the schema on mount or world_id change
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+
+            match svc.get_custom_fields(&wid).await {
+                Ok(data) => fields.set(data),
+                Err(e) => error.set(Some(format!("Failed to load custom fields: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let handle_save = move |_| {
+        let svc = service_for_save.clone();
+        let wid = world_id_for_save.clone();
+        let current = fields.read().clone();
+        spawn_task(async move {
+            is_saving.set(true);
+            error.set(None);
+            success_message.set(None);
+
+            match svc.update_custom_fields(&wid, current).await {
+                Ok(saved) => {
+                    fields.set(saved);
+                    success_message.set(Some("Custom fields saved!".to_string()));
+                }
+                Err(e) => error.set(Some(format!("Failed to save custom fields: {}", e))),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let handle_add = move |_| {
+        let count = fields.read().len();
+        fields.write().push(CustomFieldDefinitionData {
+            key: format!("field_{}", count + 1),
+            label: format!("Field {}", count + 1),
+            entity_type: EntityType::Character,
+            field_type: CustomFieldTypeData::Text,
+            options: Vec::new(),
+            visible_to_players: false,
+        });
+        success_message.set(None);
+    };
+
+    let current = fields.read().clone();
+
+    rsx! {
+        div {
+            class: "custom-fields-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Custom Fields" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "Extra fields for characters, locations and items. "
+                        "Fill them in from each entity's form in Creator Mode."
+                    }
+                }
+
+                div {
+                    class: "flex gap-2",
+                    button {
+                        class: "px-4 py-2 bg-gray-700 text-white rounded-md hover:bg-gray-600 disabled:opacity-50 text-sm",
+                        onclick: handle_add,
+                        disabled: *is_loading.read(),
+                        "Add Field"
+                    }
+                    button {
+                        class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                        onclick: handle_save,
+                        disabled: *is_loading.read() || *is_saving.read(),
+                        if *is_saving.read() { "Saving..." } else { "Save" }
+                    }
+                }
+            }
+
+            if let Some(msg) = success_message.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-900 bg-opacity-30 text-green-400 rounded-md text-sm",
+                    "{msg}"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading custom fields..." }
+            } else if current.is_empty() {
+                div { class: "text-gray-500 text-sm", "No custom fields yet." }
+            } else {
+                for (index, field) in current.into_iter().enumerate() {
+                    FieldEditor {
+                        key: "{index}",
+                        field,
+                        onchange: move |updated: CustomFieldDefinitionData| {
+                            if let Some(slot) = fields.write().get_mut(index) {
+                                *slot = updated;
+                            }
+                            success_message.set(None);
+                        },
+                        onremove: move |_| {
+                            fields.write().remove(index);
+                            success_message.set(None);
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct FieldEditorProps {
+    field: CustomFieldDefinitionData,
+    onchange: EventHandler<CustomFieldDefinitionData>,
+    onremove: EventHandler<()>,
+}
+
+#[component]
+fn FieldEditor(props: FieldEditorProps) -> Element {
+    let field = props.field.clone();
+    let entity_value = ENTITY_TYPES
+        .iter()
+        .find(|(entity_type, _, _)| *entity_type == field.entity_type)
+        .map(|(_, value, _)| *value)
+        .unwrap_or("");
+    let type_value = FIELD_TYPES
+        .iter()
+        .find(|(field_type, _, _)| *field_type == field.field_type)
+        .map(|(_, value, _)| *value)
+        .unwrap_or("");
+    let options = field.options.join(", ");
+
+    let for_label = field.clone();
+    let for_key = field.clone();
+    let for_entity = field.clone();
+    let for_type = field.clone();
+    let for_visible = field.clone();
+    let for_options = field.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 border border-gray-700 rounded-md p-3",
+
+            div {
+                class: "flex gap-2 items-center",
+
+                input {
+                    r#type: "text",
+                    class: "flex-1 px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    placeholder: "Label",
+                    value: "{field.label}",
+                    oninput: move |evt| {
+                        props.onchange.call(CustomFieldDefinitionData { label: evt.value(), ..for_label.clone() });
+                    },
+                }
+
+                input {
+                    r#type: "text",
+                    class: "w-32 px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm font-mono",
+                    title: "Stable key; renaming it detaches saved values",
+                    value: "{field.key}",
+                    oninput: move |evt| {
+                        props.onchange.call(CustomFieldDefinitionData { key: evt.value(), ..for_key.clone() });
+                    },
+                }
+
+                select {
+                    class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    value: "{entity_value}",
+                    onchange: move |evt| {
+                        let value = evt.value();
+                        if let Some((entity_type, _, _)) = ENTITY_TYPES.iter().find(|(_, v, _)| *v == value) {
+                            props.onchange.call(CustomFieldDefinitionData { entity_type: *entity_type, ..for_entity.clone() });
+                        }
+                    },
+                    for (_, value, label) in ENTITY_TYPES {
+                        option { value: "{value}", "{label}" }
+                    }
+                }
+
+                select {
+                    class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    value: "{type_value}",
+                    onchange: move |evt| {
+                        let value = evt.value();
+                        if let Some((field_type, _, _)) = FIELD_TYPES.iter().find(|(_, v, _)| *v == value) {
+                            props.onchange.call(CustomFieldDefinitionData { field_type: *field_type, ..for_type.clone() });
+                        }
+                    },
+                    for (_, value, label) in FIELD_TYPES {
+                        option { value: "{value}", "{label}" }
+                    }
+                }
+
+                label {
+                    class: "flex items-center gap-1 text-gray-300 text-sm",
+                    input {
+                        r#type: "checkbox",
+                        checked: field.visible_to_players,
+                        onchange: move |evt| {
+                            props.onchange.call(CustomFieldDefinitionData { visible_to_players: evt.checked(), ..for_visible.clone() });
+                        },
+                    }
+                    "Players see it"
+                }
+
+                button {
+                    class: "px-2 py-1 text-red-400 hover:text-red-300 text-sm",
+                    onclick: move |_| props.onremove.call(()),
+                    "Remove"
+                }
+            }
+
+            if field.field_type == CustomFieldTypeData::Select {
+                input {
+                    r#type: "text",
+                    class: "w-full px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    placeholder: "Options (comma separated)",
+                    value: "{options}",
+                    oninput: move |evt| {
+                        let options = evt.value().split(',').map(|o| o.trim().to_string()).collect();
+                        props.onchange.call(CustomFieldDefinitionData { options, ..for_options.clone() });
+                    },
+                }
+            }
+        }
+    }
+}
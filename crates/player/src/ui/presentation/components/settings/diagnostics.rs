@@ -0,0 +1,83 @@
+//! Diagnostics Panel - Export a diagnostics bundle for bug reports
+//!
+//! Asks the engine for a zip of the world's recent logs, queue items and
+//! protocol message counts, then offers it as a download the DM can attach
+//! to a bug report.
+
+use crate::application::services::world_service::DiagnosticsBundle;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+
+/// Props for the Diagnostics Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct DiagnosticsPanelProps {
+    /// The world whose diagnostics are exported
+    pub world_id: String,
+}
+
+/// Diagnostics Panel component
+#[component]
+pub fn DiagnosticsPanel(props: DiagnosticsPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut bundle = use_signal(|| None::<DiagnosticsBundle>);
+    let mut is_exporting = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let handle_export = move |_| {
+        let svc = world_service.clone();
+        let wid = props.world_id.clone();
+        spawn_task(async move {
+            is_exporting.set(true);
+            error.set(None);
+            bundle.set(None);
+            match svc.export_diagnostics(&wid).await {
+                Ok(exported) => bundle.set(Some(exported)),
+                Err(e) => error.set(Some(format!("Failed to export diagnostics: {}", e))),
+            }
+            is_exporting.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "diagnostics-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Diagnostics" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "Recent engine logs, queue items and message counts for this world, zipped for a bug report."
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-gray-600 text-white rounded-md hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_export,
+                    disabled: *is_exporting.read(),
+                    if *is_exporting.read() { "Exporting..." } else { "Export Diagnostics" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if let Some(bundle) = bundle.read().as_ref() {
+                a {
+                    class: "text-blue-400 hover:text-blue-300 text-sm underline",
+                    href: "data:{bundle.content_type};base64,{bundle.data}",
+                    download: "{bundle.file_name}",
+                    "Download {bundle.file_name} ({bundle.size_bytes / 1024} KiB)"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Experimental Features Panel - Per-world switches for unfinished subsystems
+//!
+//! Lets the DM opt a world into the combat tracker, rumors, and weather.
+//! Everything is off by default; players' UIs hide what is switched off.
+
+use crate::application::dto::WorldFeaturesData;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+
+/// Props for the Experimental Features Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct FeaturesPanelProps {
+    /// The world whose features are edited
+    pub world_id: String,
+}
+
+/// Experimental Features Panel component
+#[component]
+pub fn FeaturesPanel(props: FeaturesPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut features = use_signal(WorldFeaturesData::default);
+    let mut is_loading = use_signal(|| true);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut success_message = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_save = props.world_id.clone();
+    let service_for_load = world_service.clone();
+    let service_for_save = world_service.clone();
+
+    // Load settings on mount or world_id change
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+
+            match svc.get_features(&wid).await {
+                Ok(data) => features.set(data),
+                Err(e) => error.set(Some(format!("Failed to load features: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let handle_save = move |_| {
+        let svc = service_for_save.clone();
+        let wid = world_id_for_save.clone();
+        let current = *features.read();
+        spawn_task(async move {
+            is_saving.set(true);
+            error.set(None);
+            success_message.set(None);
+
+            match svc.update_features(&wid, current).await {
+                Ok(saved) => {
+                    features.set(saved);
+                    success_message.set(Some("Features saved!".to_string()));
+                }
+                Err(e) => error.set(Some(format!("Failed to save features: {}", e))),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let current = *features.read();
+
+    rsx! {
+        div {
+            class: "features-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Experimental Features" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "Unfinished subsystems. Players only see the ones switched on here."
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_save,
+                    disabled: *is_loading.read() || *is_saving.read(),
+                    if *is_saving.read() { "Saving..." } else { "Save" }
+                }
+            }
+
+            if let Some(msg) = success_message.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-900 bg-opacity-30 text-green-400 rounded-md text-sm",
+                    "{msg}"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading features..." }
+            } else {
+                FeatureToggle {
+                    label: "Combat Tracker",
+                    description: "Initiative order and HP tracking during fights",
+                    value: current.combat_tracker,
+                    onchange: move |enabled| {
+                        features.write().combat_tracker = enabled;
+                        success_message.set(None);
+                    },
+                }
+                FeatureToggle {
+                    label: "Rumors",
+                    description: "NPCs spread word of what the party does",
+                    value: current.rumors,
+                    onchange: move |enabled| {
+                        features.write().rumors = enabled;
+                        success_message.set(None);
+                    },
+                }
+                FeatureToggle {
+                    label: "Weather",
+                    description: "Per-region weather in scenes and NPC dialogue",
+                    value: current.weather,
+                    onchange: move |enabled| {
+                        features.write().weather = enabled;
+                        success_message.set(None);
+                    },
+                }
+                FeatureToggle {
+                    label: "Age Progression",
+                    description: "Tell the DM which characters aged after long time skips",
+                    value: current.age_progression,
+                    onchange: move |enabled| {
+                        features.write().age_progression = enabled;
+                        success_message.set(None);
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct FeatureToggleProps {
+    label: &'static str,
+    description: &'static str,
+    value: bool,
+    onchange: EventHandler<bool>,
+}
+
+#[component]
+fn FeatureToggle(props: FeatureToggleProps) -> Element {
+    rsx! {
+        label {
+            class: "flex items-start gap-3 cursor-pointer",
+
+            input {
+                r#type: "checkbox",
+                class: "mt-1",
+                checked: props.value,
+                onchange: move |evt| props.onchange.call(evt.checked()),
+            }
+
+            div {
+                class: "flex flex-col",
+                span { class: "text-gray-300 text-sm", "{props.label}" }
+                span { class: "text-gray-500 text-xs", "{props.description}" }
+            }
+        }
+    }
+}
@@ -4,7 +4,7 @@
 //! world-specific settings. It's designed for use during active gameplay
 //! where DMs can tune settings for the current world/session.
 
-use crate::application::dto::{AppSettings, BatchQueueFailurePolicy};
+use crate::application::dto::{AppSettings, BatchQueueFailurePolicy, LlmProvider, NpcAutonomyLevel};
 use crate::infrastructure::spawn_task;
 use crate::presentation::services::use_settings_service;
 use dioxus::prelude::*;
@@ -195,6 +195,16 @@ pub fn GameSettingsPanel(props: GameSettingsPanelProps) -> Element {
                                 success_message.set(None);
                             }
                         }
+
+                        NumberField {
+                            label: "Dialogue Merge Window (ms)",
+                            description: "How long a line to an NPC waits for other players to join in, so the NPC answers them together (0 = answer each line alone)",
+                            value: settings.read().dialogue_merge_window_ms as usize,
+                            onchange: move |val: usize| {
+                                settings.with_mut(|s| s.dialogue_merge_window_ms = val as u64);
+                                success_message.set(None);
+                            }
+                        }
                     }
 
                     // Challenge Settings
@@ -253,6 +263,186 @@ pub fn GameSettingsPanel(props: GameSettingsPanelProps) -> Element {
                         }
                     }
 
+                    // LLM Provider
+                    SettingsSection {
+                        title: "Language Model",
+                        description: "Where NPC dialogue and suggestions are generated",
+
+                        SelectField {
+                            label: "Provider",
+                            description: "Hosted providers need an API key",
+                            value: match settings.read().llm_provider.provider {
+                                LlmProvider::OpenAi => "openai",
+                                LlmProvider::Anthropic => "anthropic",
+                                LlmProvider::Ollama | LlmProvider::Unknown => "ollama",
+                            },
+                            options: vec![
+                                ("ollama", "Ollama - the Engine's local models"),
+                                ("openai", "OpenAI"),
+                                ("anthropic", "Anthropic"),
+                            ],
+                            onchange: move |val: String| {
+                                let parsed = match val.as_str() {
+                                    "openai" => LlmProvider::OpenAi,
+                                    "anthropic" => LlmProvider::Anthropic,
+                                    _ => LlmProvider::Ollama,
+                                };
+                                settings.with_mut(|s| s.llm_provider.provider = parsed);
+                                success_message.set(None);
+                            }
+                        }
+
+                        if settings.read().llm_provider.provider != LlmProvider::Ollama {
+                            TextField {
+                                label: "Model",
+                                description: "Empty = the provider's default",
+                                value: settings.read().llm_provider.model.clone().unwrap_or_default(),
+                                secret: false,
+                                onchange: move |val: String| {
+                                    settings.with_mut(|s| {
+                                        s.llm_provider.model = Some(val.trim().to_string()).filter(|m| !m.is_empty())
+                                    });
+                                    success_message.set(None);
+                                }
+                            }
+
+                            TextField {
+                                label: "API Key",
+                                description: "Stored by the Engine, never shown again",
+                                value: settings.read().llm_provider.api_key.clone().unwrap_or_default(),
+                                secret: true,
+                                onchange: move |val: String| {
+                                    settings.with_mut(|s| {
+                                        s.llm_provider.api_key = Some(val.trim().to_string()).filter(|k| !k.is_empty())
+                                    });
+                                    success_message.set(None);
+                                }
+                            }
+                        }
+                    }
+
+                    // Usage Quotas
+                    SettingsSection {
+                        title: "Usage Quotas",
+                        description: "Daily soft limits; you are warned when exceeded, generation is never blocked (0 = no limit)",
+
+                        NumberField {
+                            label: "Daily Token Quota",
+                            description: "LLM prompt and completion tokens per day",
+                            value: settings.read().daily_token_quota.unwrap_or(0) as usize,
+                            onchange: move |val: usize| {
+                                settings.with_mut(|s| s.daily_token_quota = (val > 0).then_some(val as u64));
+                                success_message.set(None);
+                            }
+                        }
+
+                        NumberField {
+                            label: "Daily Image Quota",
+                            description: "Generated images per day",
+                            value: settings.read().daily_image_quota.unwrap_or(0) as usize,
+                            onchange: move |val: usize| {
+                                settings.with_mut(|s| s.daily_image_quota = (val > 0).then_some(val as u64));
+                                success_message.set(None);
+                            }
+                        }
+                    }
+
+                    // Co-DM
+                    SettingsSection {
+                        title: "AI Co-DM",
+                        description: "Let an LLM approve NPC responses for solo play; every decision is kept for review",
+
+                        BooleanField {
+                            label: "Enable Co-DM",
+                            description: "Approvals resolve without a DM",
+                            value: settings.read().co_dm.enabled,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.co_dm.enabled = val);
+                                success_message.set(None);
+                            }
+                        }
+
+                        BooleanField {
+                            label: "Allow Rewrites",
+                            description: "Otherwise rewrites go to the DM",
+                            value: settings.read().co_dm.allow_rewrites,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.co_dm.allow_rewrites = val);
+                                success_message.set(None);
+                            }
+                        }
+
+                        BooleanField {
+                            label: "Allow Plugin Tools",
+                            description: "Otherwise no tool calls run",
+                            value: settings.read().co_dm.allow_tools,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.co_dm.allow_tools = val);
+                                success_message.set(None);
+                            }
+                        }
+
+                        BooleanField {
+                            label: "Escalate Safety Flags",
+                            description: "Flagged responses go to the DM",
+                            value: settings.read().co_dm.escalate_safety_warnings,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.co_dm.escalate_safety_warnings = val);
+                                success_message.set(None);
+                            }
+                        }
+
+                        BooleanField {
+                            label: "Escalate Suggestions",
+                            description: "Challenge and event suggestions go to the DM",
+                            value: settings.read().co_dm.escalate_suggestions,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.co_dm.escalate_suggestions = val);
+                                success_message.set(None);
+                            }
+                        }
+
+                        NumberField {
+                            label: "Max Dialogue Length",
+                            description: "Longer dialogue goes to the DM (0 = no limit)",
+                            value: settings.read().co_dm.max_dialogue_chars,
+                            onchange: move |val: usize| {
+                                settings.with_mut(|s| s.co_dm.max_dialogue_chars = val);
+                                success_message.set(None);
+                            }
+                        }
+                    }
+
+                    // NPC Autonomy
+                    SettingsSection {
+                        title: "NPC Autonomy",
+                        description: "Routine NPC responses skip approval; item grants and suggestions still reach the DM",
+
+                        SelectField {
+                            label: "Default Autonomy",
+                            description: "Individual NPCs can be given their own level",
+                            value: match settings.read().npc_autonomy.default_level {
+                                NpcAutonomyLevel::SmallTalk => "small_talk",
+                                NpcAutonomyLevel::Trusted => "trusted",
+                                NpcAutonomyLevel::Supervised | NpcAutonomyLevel::Unknown => "supervised",
+                            },
+                            options: vec![
+                                ("supervised", "Supervised - review everything"),
+                                ("small_talk", "Small talk - dialogue without tool calls"),
+                                ("trusted", "Trusted - also tool calls not always reviewed"),
+                            ],
+                            onchange: move |val: String| {
+                                let parsed = match val.as_str() {
+                                    "small_talk" => NpcAutonomyLevel::SmallTalk,
+                                    "trusted" => NpcAutonomyLevel::Trusted,
+                                    _ => NpcAutonomyLevel::Supervised,
+                                };
+                                settings.with_mut(|s| s.npc_autonomy.default_level = parsed);
+                                success_message.set(None);
+                            }
+                        }
+                    }
+
                     // Animation Settings
                     SettingsSection {
                         title: "Text Animation",
@@ -492,6 +682,47 @@ fn SelectField(props: SelectFieldProps) -> Element {
     }
 }
 
+/// Single-line text field component
+#[derive(Props, Clone, PartialEq)]
+struct TextFieldProps {
+    label: &'static str,
+    description: &'static str,
+    value: String,
+    /// Mask the input, for keys and passwords
+    secret: bool,
+    onchange: EventHandler<String>,
+}
+
+#[component]
+fn TextField(props: TextFieldProps) -> Element {
+    rsx! {
+        div {
+            class: "text-field flex items-center gap-3",
+
+            div {
+                class: "flex-1",
+
+                span {
+                    class: "text-gray-300 text-sm",
+                    "{props.label}"
+                }
+
+                span {
+                    class: "text-gray-600 text-xs ml-2",
+                    "({props.description})"
+                }
+            }
+
+            input {
+                r#type: if props.secret { "password" } else { "text" },
+                class: "w-56 px-2 py-1 bg-gray-800 border border-gray-700 rounded text-white text-sm focus:outline-none focus:ring-1 focus:ring-blue-500",
+                value: "{props.value}",
+                oninput: move |evt| props.onchange.call(evt.value()),
+            }
+        }
+    }
+}
+
 #[component]
 fn NumberField(props: NumberFieldProps) -> Element {
     let value_str = format!("{}", props.value);
@@ -0,0 +1,330 @@
+//! Generation Presets Panel - Per-world art styles for asset generation
+//!
+//! A preset bundles a style prompt fragment, a negative prompt, an output
+//! size and optionally a workflow for one asset slot. The slot's default
+//! preset is applied to every generation that doesn't pick another, so all
+//! of a world's portraits share one look.
+
+use crate::application::dto::GenerationPreset;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_settings_service;
+use dioxus::prelude::*;
+
+/// Asset slots presets can be made for, as (Engine name, label)
+const PRESET_SLOTS: &[(&str, &str)] = &[
+    ("portrait", "Portrait"),
+    ("sprite", "Sprite"),
+    ("backdrop", "Backdrop"),
+    ("itemIcon", "Item Icon"),
+    ("emotionSheet", "Expression Sheet"),
+    ("tilesheet", "Tilesheet"),
+    ("regionBackdrop", "Region Backdrop"),
+];
+
+/// Props for the Generation Presets Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct GenerationPresetsPanelProps {
+    /// The world whose presets are edited
+    pub world_id: String,
+}
+
+/// Generation Presets Panel component
+#[component]
+pub fn GenerationPresetsPanel(props: GenerationPresetsPanelProps) -> Element {
+    let settings_service = use_settings_service();
+
+    let mut presets: Signal<Vec<GenerationPreset>> = use_signal(Vec::new);
+    let mut is_loading = use_signal(|| true);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut success_message = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_save = props.world_id.clone();
+    let settings_for_load = settings_service.clone();
+    let settings_for_save = settings_service.clone();
+
+    use_effect(move || {
+        let svc = settings_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+            match svc.get_for_world(&wid).await {
+                Ok(settings) => presets.set(settings.generation_presets),
+                Err(e) => error.set(Some(format!("Failed to load presets: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let handle_save = move |_| {
+        let svc = settings_for_save.clone();
+        let wid = world_id_for_save.clone();
+        let edited = presets.read().clone();
+
+        if let Some(problem) = validate(&edited) {
+            error.set(Some(problem));
+            return;
+        }
+
+        spawn_task(async move {
+            is_saving.set(true);
+            error.set(None);
+            success_message.set(None);
+
+            // Save over a fresh copy so other panels' edits aren't lost
+            let saved = match svc.get_for_world(&wid).await {
+                Ok(mut settings) => {
+                    settings.generation_presets = edited;
+                    svc.update_for_world(&wid, &settings).await
+                }
+                Err(e) => Err(e),
+            };
+            match saved {
+                Ok(settings) => {
+                    presets.set(settings.generation_presets);
+                    success_message.set(Some("Presets saved!".to_string()));
+                }
+                Err(e) => error.set(Some(format!("Failed to save presets: {}", e))),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let handle_add = move |_| {
+        presets.write().push(GenerationPreset {
+            name: String::new(),
+            asset_type: PRESET_SLOTS[0].0.to_string(),
+            style_prompt: String::new(),
+            negative_prompt: None,
+            width: None,
+            height: None,
+            workflow: None,
+            is_default: false,
+        });
+        success_message.set(None);
+    };
+
+    let count = presets.read().len();
+
+    rsx! {
+        div {
+            class: "generation-presets-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Generation Presets" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "Named art styles per asset slot. A slot's default preset styles every image generated for it."
+                    }
+                }
+
+                div {
+                    class: "flex gap-2",
+                    button {
+                        class: "px-4 py-2 bg-gray-600 text-white rounded-md hover:bg-gray-700 disabled:opacity-50 text-sm",
+                        onclick: handle_add,
+                        disabled: *is_loading.read(),
+                        "Add Preset"
+                    }
+                    button {
+                        class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                        onclick: handle_save,
+                        disabled: *is_loading.read() || *is_saving.read(),
+                        if *is_saving.read() { "Saving..." } else { "Save" }
+                    }
+                }
+            }
+
+            if let Some(msg) = success_message.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-900 bg-opacity-30 text-green-400 rounded-md text-sm",
+                    "{msg}"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading presets..." }
+            } else if count == 0 {
+                div { class: "text-gray-500 text-sm", "No presets yet." }
+            } else {
+                for index in 0..count {
+                    PresetEditor {
+                        key: "{index}",
+                        preset: presets.read()[index].clone(),
+                        onchange: move |preset: GenerationPreset| {
+                            presets.with_mut(|all| {
+                                // Only one default per slot
+                                if preset.is_default {
+                                    for (i, other) in all.iter_mut().enumerate() {
+                                        if i != index && other.asset_type == preset.asset_type {
+                                            other.is_default = false;
+                                        }
+                                    }
+                                }
+                                all[index] = preset;
+                            });
+                            success_message.set(None);
+                        },
+                        onremove: move |_| {
+                            presets.write().remove(index);
+                            success_message.set(None);
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Problem that would stop the Engine finding a preset by name, if any
+fn validate(presets: &[GenerationPreset]) -> Option<String> {
+    for (i, preset) in presets.iter().enumerate() {
+        if preset.name.trim().is_empty() {
+            return Some("Every preset needs a name".to_string());
+        }
+        let duplicate = presets[..i].iter().any(|other| {
+            other.asset_type == preset.asset_type
+                && other.name.trim().eq_ignore_ascii_case(preset.name.trim())
+        });
+        if duplicate {
+            return Some(format!(
+                "There are two {} presets called \"{}\"",
+                slot_label(&preset.asset_type),
+                preset.name.trim()
+            ));
+        }
+    }
+    None
+}
+
+fn slot_label(asset_type: &str) -> &'static str {
+    PRESET_SLOTS
+        .iter()
+        .find(|(value, _)| *value == asset_type)
+        .map(|(_, label)| *label)
+        .unwrap_or("Unknown")
+}
+
+/// Empty text means "not set"
+fn optional(value: String) -> Option<String> {
+    if value.trim().is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Editable fields for one preset
+#[component]
+fn PresetEditor(
+    preset: GenerationPreset,
+    onchange: EventHandler<GenerationPreset>,
+    onremove: EventHandler<()>,
+) -> Element {
+    let width = preset.width.map(|w| w.to_string()).unwrap_or_default();
+    let height = preset.height.map(|h| h.to_string()).unwrap_or_default();
+    let negative_prompt = preset.negative_prompt.clone().unwrap_or_default();
+    let workflow = preset.workflow.clone().unwrap_or_default();
+
+    let p_name = preset.clone();
+    let p_slot = preset.clone();
+    let p_default = preset.clone();
+    let p_style = preset.clone();
+    let p_negative = preset.clone();
+    let p_width = preset.clone();
+    let p_height = preset.clone();
+    let p_workflow = preset.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 p-3 bg-gray-800 rounded-md",
+
+            div {
+                class: "flex gap-2 items-center",
+                input {
+                    r#type: "text",
+                    value: "{preset.name}",
+                    placeholder: "Preset name",
+                    oninput: move |e| onchange.call(GenerationPreset { name: e.value(), ..p_name.clone() }),
+                    class: "flex-1 p-2 bg-gray-900 border border-gray-700 rounded text-white text-sm",
+                }
+                select {
+                    value: "{preset.asset_type}",
+                    onchange: move |e| onchange.call(GenerationPreset { asset_type: e.value(), ..p_slot.clone() }),
+                    class: "p-2 bg-gray-900 border border-gray-700 rounded text-white text-sm",
+                    for (value, label) in PRESET_SLOTS.iter() {
+                        option { key: "{value}", value: "{value}", "{label}" }
+                    }
+                }
+                label {
+                    class: "flex items-center gap-1 text-gray-400 text-sm",
+                    input {
+                        r#type: "checkbox",
+                        checked: preset.is_default,
+                        onchange: move |e| onchange.call(GenerationPreset { is_default: e.checked(), ..p_default.clone() }),
+                    }
+                    "Default"
+                }
+                button {
+                    class: "px-2 py-1 bg-transparent text-red-400 border-0 cursor-pointer text-sm",
+                    onclick: move |_| onremove.call(()),
+                    "Remove"
+                }
+            }
+
+            textarea {
+                value: "{preset.style_prompt}",
+                placeholder: "Style added to every prompt, e.g. watercolor, muted palette, ink outlines",
+                oninput: move |e| onchange.call(GenerationPreset { style_prompt: e.value(), ..p_style.clone() }),
+                class: "w-full min-h-16 p-2 bg-gray-900 border border-gray-700 rounded text-white text-sm resize-y box-border",
+            }
+
+            input {
+                r#type: "text",
+                value: "{negative_prompt}",
+                placeholder: "Negative prompt (optional)",
+                oninput: move |e| onchange.call(GenerationPreset { negative_prompt: optional(e.value()), ..p_negative.clone() }),
+                class: "w-full p-2 bg-gray-900 border border-gray-700 rounded text-white text-sm box-border",
+            }
+
+            div {
+                class: "flex gap-2",
+                input {
+                    r#type: "number",
+                    min: "64",
+                    value: "{width}",
+                    placeholder: "Width",
+                    oninput: move |e| onchange.call(GenerationPreset { width: e.value().parse().ok(), ..p_width.clone() }),
+                    class: "w-24 p-2 bg-gray-900 border border-gray-700 rounded text-white text-sm",
+                }
+                input {
+                    r#type: "number",
+                    min: "64",
+                    value: "{height}",
+                    placeholder: "Height",
+                    oninput: move |e| onchange.call(GenerationPreset { height: e.value().parse().ok(), ..p_height.clone() }),
+                    class: "w-24 p-2 bg-gray-900 border border-gray-700 rounded text-white text-sm",
+                }
+                input {
+                    r#type: "text",
+                    value: "{workflow}",
+                    placeholder: "Workflow (optional)",
+                    oninput: move |e| onchange.call(GenerationPreset { workflow: optional(e.value()), ..p_workflow.clone() }),
+                    class: "flex-1 p-2 bg-gray-900 border border-gray-700 rounded text-white text-sm",
+                }
+            }
+        }
+    }
+}
@@ -1,14 +1,35 @@
 //! Settings components - Application configuration interface
 //!
 //! Components for the Settings view, providing workflow configuration,
-//! ComfyUI integration settings, skills management, and general application preferences.
-
+//! ComfyUI integration settings, skills management, content safety, LLM models,
+//! typography, themes, the world map, experimental features, rule system
+//! switching, automation scripts, custom fields, usage, analytics, unused
+//! content, the prep checklist, diagnostics, world branching, accessibility,
+//! and general application preferences.
+
+pub mod accessibility;
+pub mod analytics;
 pub mod app_settings;
+pub mod branch;
+pub mod content_safety;
+pub mod custom_fields;
+pub mod diagnostics;
+pub mod features;
 pub mod game_settings;
+pub mod generation_presets;
+pub mod model_settings;
+pub mod prep_checklist;
+pub mod rule_system;
+pub mod scripts;
 pub mod skills_panel;
+pub mod theme;
+pub mod typography;
+pub mod unused_content;
+pub mod usage;
 pub mod workflow_config_editor;
 pub mod workflow_slot_list;
 pub mod workflow_upload_modal;
+pub mod world_map;
 
 // Re-export the game settings panel for easy access
 pub use game_settings::GameSettingsPanel;
@@ -78,12 +99,36 @@ pub fn SettingsView(props: SettingsViewProps) -> Element {
                     },
                     "world-settings" => rsx! {
                         div {
-                            class: "p-4",
+                            class: "p-4 h-full overflow-y-auto flex flex-col gap-4",
                             game_settings::GameSettingsPanel { world_id: props.world_id.clone() }
+                            content_safety::ContentSafetyPanel { world_id: props.world_id.clone() }
+                            model_settings::ModelSettingsPanel { world_id: props.world_id.clone() }
+                            generation_presets::GenerationPresetsPanel { world_id: props.world_id.clone() }
+                            typography::TypographyPanel { world_id: props.world_id.clone() }
+                            theme::WorldThemePanel { world_id: props.world_id.clone() }
+                            world_map::WorldMapPanel { world_id: props.world_id.clone() }
+                            features::FeaturesPanel { world_id: props.world_id.clone() }
+                            rule_system::RuleSystemPanel { world_id: props.world_id.clone() }
+                            scripts::ScriptsPanel { world_id: props.world_id.clone() }
+                            custom_fields::CustomFieldsPanel { world_id: props.world_id.clone() }
+                            usage::UsagePanel { world_id: props.world_id.clone() }
+                            analytics::AnalyticsPanel { world_id: props.world_id.clone() }
+                            unused_content::UnusedContentPanel { world_id: props.world_id.clone() }
+                            prep_checklist::PrepChecklistPanel { world_id: props.world_id.clone() }
+                            diagnostics::DiagnosticsPanel { world_id: props.world_id.clone() }
+                            branch::BranchPanel { world_id: props.world_id.clone() }
                         }
                     },
                     "app-settings" => rsx! {
-                        app_settings::AppSettingsPanel {}
+                        div {
+                            class: "p-4 h-full flex flex-col gap-4",
+                            theme::ThemePanel {}
+                            accessibility::AccessibilityPanel {}
+                            div {
+                                class: "flex-1 min-h-0",
+                                app_settings::AppSettingsPanel {}
+                            }
+                        }
                     },
                     _ => rsx! {
                         AssetWorkflowsTab {}
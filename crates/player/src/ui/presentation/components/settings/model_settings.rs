@@ -0,0 +1,367 @@
+//! Model Settings Panel - Per-world LLM model choice and model downloads
+//!
+//! Lists the models on the Engine's Ollama server, lets the DM download new
+//! ones (progress arrives as ModelPullProgress events), and picks the world's
+//! dialogue model and its cheaper summarization model, plus which of the two
+//! each kind of LLM work is routed to.
+
+use crate::application::dto::{ModelRouting, ModelTier};
+use crate::application::services::ModelInfo;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_model_service, use_settings_service};
+use crate::presentation::state::use_generation_state;
+use dioxus::prelude::*;
+
+/// Props for the Model Settings Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct ModelSettingsPanelProps {
+    /// The world whose models are chosen
+    pub world_id: String,
+}
+
+/// Model Settings Panel component
+#[component]
+pub fn ModelSettingsPanel(props: ModelSettingsPanelProps) -> Element {
+    let model_service = use_model_service();
+    let settings_service = use_settings_service();
+    let generation_state = use_generation_state();
+
+    let mut models: Signal<Vec<ModelInfo>> = use_signal(Vec::new);
+    let mut dialogue_model = use_signal(String::new);
+    let mut summary_model = use_signal(String::new);
+    let mut routing = use_signal(ModelRouting::default);
+    let mut pull_name = use_signal(String::new);
+    let mut is_loading = use_signal(|| true);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut success_message = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_save = props.world_id.clone();
+    let models_for_load = model_service.clone();
+    let models_for_refresh = model_service.clone();
+    let models_for_pull = model_service.clone();
+    let models_for_save = model_service.clone();
+    let settings_for_save = settings_service.clone();
+
+    // Load the available models and the world's current choice
+    use_effect(move || {
+        let models_svc = models_for_load.clone();
+        let settings_svc = settings_service.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+
+            match settings_svc.get_for_world(&wid).await {
+                Ok(settings) => {
+                    dialogue_model.set(settings.dialogue_model.unwrap_or_default());
+                    summary_model.set(settings.summary_model.unwrap_or_default());
+                    routing.set(settings.model_routing);
+                }
+                Err(e) => error.set(Some(format!("Failed to load world models: {}", e))),
+            }
+            match models_svc.list_models().await {
+                Ok(list) => models.set(list),
+                Err(e) => error.set(Some(format!("Failed to list models: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    // Refresh the list once a download finishes
+    let finished_pulls = generation_state
+        .get_model_pulls()
+        .iter()
+        .filter(|p| p.done && p.error.is_none())
+        .count();
+    use_effect(use_reactive!(|finished_pulls| {
+        if finished_pulls == 0 {
+            return;
+        }
+        let svc = models_for_refresh.clone();
+        spawn_task(async move {
+            if let Ok(list) = svc.list_models().await {
+                models.set(list);
+            }
+        });
+    }));
+
+    let handle_pull = move |_| {
+        let svc = models_for_pull.clone();
+        let name = pull_name.read().trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        spawn_task(async move {
+            error.set(None);
+            match svc.pull_model(&name).await {
+                Ok(()) => pull_name.set(String::new()),
+                Err(e) => error.set(Some(format!("Failed to start download: {}", e))),
+            }
+        });
+    };
+
+    let handle_save = move |_| {
+        let svc = models_for_save.clone();
+        let settings_svc = settings_for_save.clone();
+        let wid = world_id_for_save.clone();
+        let dialogue = dialogue_model.read().clone();
+        let summary = summary_model.read().clone();
+        let chosen_routing = routing.read().clone();
+        spawn_task(async move {
+            is_saving.set(true);
+            error.set(None);
+            success_message.set(None);
+
+            let as_choice = |m: String| if m.is_empty() { None } else { Some(m) };
+            match svc
+                .set_world_models(&wid, as_choice(dialogue), as_choice(summary))
+                .await
+            {
+                Ok(saved) => {
+                    dialogue_model.set(saved.dialogue_model.unwrap_or_default());
+                    summary_model.set(saved.summary_model.unwrap_or_default());
+                }
+                Err(e) => {
+                    error.set(Some(format!("Failed to save world models: {}", e)));
+                    is_saving.set(false);
+                    return;
+                }
+            }
+
+            // Routing is an ordinary world setting; save it over the fresh copy
+            let saved_routing = match settings_svc.get_for_world(&wid).await {
+                Ok(mut settings) => {
+                    settings.model_routing = chosen_routing;
+                    settings_svc.update_for_world(&wid, &settings).await
+                }
+                Err(e) => Err(e),
+            };
+            match saved_routing {
+                Ok(settings) => {
+                    routing.set(settings.model_routing);
+                    success_message.set(Some("World models saved!".to_string()));
+                }
+                Err(e) => error.set(Some(format!("Failed to save model routing: {}", e))),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let available = models.read().clone();
+    let pulls = generation_state.get_model_pulls();
+
+    rsx! {
+        div {
+            class: "model-settings-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Models" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "Choose the models this world uses. A smaller summarization model keeps suggestions fast."
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_save,
+                    disabled: *is_loading.read() || *is_saving.read(),
+                    if *is_saving.read() { "Saving..." } else { "Save" }
+                }
+            }
+
+            if let Some(msg) = success_message.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-900 bg-opacity-30 text-green-400 rounded-md text-sm",
+                    "{msg}"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading models..." }
+            } else {
+                ModelSelect {
+                    label: "Dialogue Model",
+                    description: "Large model, used for NPC dialogue by default.",
+                    models: available.clone(),
+                    value: dialogue_model.read().clone(),
+                    onchange: move |val: String| {
+                        dialogue_model.set(val);
+                        success_message.set(None);
+                    },
+                }
+
+                ModelSelect {
+                    label: "Summarization Model",
+                    description: "Small model, used for suggestions and staging decisions by default.",
+                    models: available,
+                    value: summary_model.read().clone(),
+                    onchange: move |val: String| {
+                        summary_model.set(val);
+                        success_message.set(None);
+                    },
+                }
+
+                div {
+                    class: "flex flex-col gap-2",
+                    label { class: "text-gray-300 text-sm", "Routing" }
+                    p { class: "text-gray-500 text-xs", "Which model each kind of work runs on." }
+                    TierSelect {
+                        label: "NPC dialogue",
+                        value: routing.read().npc_dialogue,
+                        onchange: move |tier| {
+                            routing.with_mut(|r| r.npc_dialogue = tier);
+                            success_message.set(None);
+                        },
+                    }
+                    TierSelect {
+                        label: "Content suggestions",
+                        value: routing.read().suggestions,
+                        onchange: move |tier| {
+                            routing.with_mut(|r| r.suggestions = tier);
+                            success_message.set(None);
+                        },
+                    }
+                    TierSelect {
+                        label: "Outcome suggestions",
+                        value: routing.read().outcome_suggestions,
+                        onchange: move |tier| {
+                            routing.with_mut(|r| r.outcome_suggestions = tier);
+                            success_message.set(None);
+                        },
+                    }
+                    TierSelect {
+                        label: "Staging decisions",
+                        value: routing.read().classification,
+                        onchange: move |tier| {
+                            routing.with_mut(|r| r.classification = tier);
+                            success_message.set(None);
+                        },
+                    }
+                }
+
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Download a Model" }
+                    div {
+                        class: "flex gap-2",
+                        input {
+                            r#type: "text",
+                            value: "{pull_name}",
+                            placeholder: "e.g. llama3.2 or qwen2.5:0.5b",
+                            oninput: move |e| pull_name.set(e.value()),
+                            class: "flex-1 p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                        }
+                        button {
+                            class: "px-4 py-2 bg-gray-700 text-white rounded-md hover:bg-gray-600 disabled:opacity-50 text-sm",
+                            onclick: handle_pull,
+                            disabled: pull_name.read().trim().is_empty(),
+                            "Download"
+                        }
+                    }
+                }
+
+                for pull in pulls.iter().rev() {
+                    div {
+                        key: "{pull.model}",
+                        class: "flex justify-between items-center text-sm",
+                        span { class: "text-white", "{pull.model}" }
+                        if let Some(err) = pull.error.as_ref() {
+                            span { class: "text-red-400", "Failed: {err}" }
+                        } else if pull.done {
+                            span { class: "text-green-400", "Downloaded" }
+                        } else if let Some(percent) = pull.percent() {
+                            span { class: "text-gray-400", "{pull.status} ({percent}%)" }
+                        } else {
+                            span { class: "text-gray-400", "{pull.status}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Dropdown of available models, with "Engine default" for no choice
+#[component]
+fn ModelSelect(
+    label: &'static str,
+    description: &'static str,
+    models: Vec<ModelInfo>,
+    value: String,
+    onchange: EventHandler<String>,
+) -> Element {
+    // Keep a saved choice selectable even if the server no longer lists it
+    let missing = !value.is_empty() && !models.iter().any(|m| m.name == value);
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            label { class: "text-gray-300 text-sm", "{label}" }
+            p { class: "text-gray-500 text-xs", "{description}" }
+            select {
+                value: "{value}",
+                onchange: move |e| onchange.call(e.value()),
+                class: "p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                option { value: "", "Engine default" }
+                if missing {
+                    option { value: "{value}", "{value} (not found)" }
+                }
+                for model in models.iter() {
+                    option {
+                        key: "{model.name}",
+                        value: "{model.name}",
+                        "{model.name} ({format_size(model.size_bytes)})"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Small/large tier choice for one kind of LLM work
+#[component]
+fn TierSelect(label: &'static str, value: ModelTier, onchange: EventHandler<ModelTier>) -> Element {
+    let selected = match value {
+        ModelTier::Small => "small",
+        ModelTier::Large => "large",
+    };
+
+    rsx! {
+        div {
+            class: "flex justify-between items-center",
+            span { class: "text-gray-400 text-sm", "{label}" }
+            select {
+                value: "{selected}",
+                onchange: move |e| {
+                    let tier = if e.value() == "large" { ModelTier::Large } else { ModelTier::Small };
+                    onchange.call(tier);
+                },
+                class: "p-1 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                option { value: "small", "Summarization model" }
+                option { value: "large", "Dialogue model" }
+            }
+        }
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    const GIB: u64 = 1024 * 1024 * 1024;
+    if bytes >= GIB {
+        format!("{:.1} GB", bytes as f64 / GIB as f64)
+    } else {
+        format!("{} MB", bytes / (1024 * 1024))
+    }
+}
@@ -0,0 +1,139 @@
+//! Prep Checklist Panel - What to prepare before the next session
+//!
+//! Looks at where the party is and where it may head next (neighbouring
+//! regions and places named by active quests) and lists what is missing
+//! there: regions with no staging, NPCs without portraits and challenges
+//! with blank outcomes.
+
+use crate::application::services::world_service::PrepChecklist;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+
+/// Props for the Prep Checklist Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct PrepChecklistPanelProps {
+    /// The world whose checklist is shown
+    pub world_id: String,
+}
+
+/// Prep Checklist Panel component
+#[component]
+pub fn PrepChecklistPanel(props: PrepChecklistPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut checklist = use_signal(|| None::<PrepChecklist>);
+    let mut is_loading = use_signal(|| true);
+    let mut error = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_refresh = props.world_id.clone();
+    let service_for_load = world_service.clone();
+    let service_for_refresh = world_service.clone();
+
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+            match svc.generate_prep_checklist(&wid).await {
+                Ok(loaded) => checklist.set(Some(loaded)),
+                Err(e) => error.set(Some(format!("Failed to generate checklist: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let handle_refresh = move |_| {
+        let svc = service_for_refresh.clone();
+        let wid = world_id_for_refresh.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+            match svc.generate_prep_checklist(&wid).await {
+                Ok(loaded) => checklist.set(Some(loaded)),
+                Err(e) => error.set(Some(format!("Failed to generate checklist: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "prep-checklist-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Prep Checklist" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "What's missing where the party is and where it may go next."
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-gray-600 text-white rounded-md hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_refresh,
+                    disabled: *is_loading.read(),
+                    "Regenerate"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Generating checklist..." }
+            } else if let Some(checklist) = checklist.read().as_ref() {
+                h4 { class: "text-gray-300 text-sm font-medium", "Unstaged regions" }
+                if checklist.unstaged_regions.is_empty() {
+                    div { class: "text-gray-500 text-sm", "Every nearby region is staged." }
+                } else {
+                    for region in checklist.unstaged_regions.iter() {
+                        div {
+                            key: "{region.region_id}",
+                            class: "text-sm border-t border-gray-800 py-1",
+                            div { class: "text-gray-300", "{region.location_name} › {region.name}" }
+                            div { class: "text-gray-500 text-xs", "{region.reason}" }
+                        }
+                    }
+                }
+
+                h4 { class: "text-gray-300 text-sm font-medium", "NPCs without portraits" }
+                if checklist.npcs_without_portraits.is_empty() {
+                    div { class: "text-gray-500 text-sm", "Every nearby NPC has a portrait." }
+                } else {
+                    for npc in checklist.npcs_without_portraits.iter() {
+                        div {
+                            key: "{npc.npc_id}",
+                            class: "text-sm border-t border-gray-800 py-1",
+                            div { class: "text-gray-300", "{npc.name}" }
+                            div { class: "text-gray-500 text-xs", "{npc.region_name}" }
+                        }
+                    }
+                }
+
+                h4 { class: "text-gray-300 text-sm font-medium", "Challenges without outcomes" }
+                if checklist.challenges_without_outcomes.is_empty() {
+                    div { class: "text-gray-500 text-sm", "Every active challenge has its outcomes." }
+                } else {
+                    for challenge in checklist.challenges_without_outcomes.iter() {
+                        div {
+                            key: "{challenge.challenge_id}",
+                            class: "text-sm border-t border-gray-800 py-1",
+                            div { class: "text-gray-300", "{challenge.name}" }
+                            div { class: "text-gray-500 text-xs", "Blank: {challenge.blank.join(\", \")}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Rule System Panel - Move a world to another rule system
+//!
+//! The DM picks a preset and previews what happens to the world's
+//! challenges: each one moves to the new system's matching skill through the
+//! skill alias table. Challenges the table can't place get a skill typed in
+//! by hand before the change is applied. The preview also lists, per PC, the
+//! sheet fields that won't carry over; the old sheets are kept as versions.
+
+use std::collections::HashMap;
+
+use crate::application::dto::{
+    RuleSystemSwitchData, RuleSystemType, RuleSystemVariant, SkillOverrideData,
+};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+
+/// Props for the Rule System Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct RuleSystemPanelProps {
+    /// The world to switch
+    pub world_id: String,
+}
+
+/// Every preset a world can switch to
+fn preset_variants() -> Vec<RuleSystemVariant> {
+    [
+        RuleSystemType::D20,
+        RuleSystemType::D100,
+        RuleSystemType::Narrative,
+    ]
+    .into_iter()
+    .flat_map(RuleSystemVariant::variants_for_type)
+    .collect()
+}
+
+/// Rule System Panel component
+#[component]
+pub fn RuleSystemPanel(props: RuleSystemPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let variants = preset_variants();
+    let mut target = use_signal(|| 0usize);
+    let mut plan = use_signal(|| None::<RuleSystemSwitchData>);
+    let mut overrides = use_signal(HashMap::<String, String>::new);
+    let mut is_busy = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut success_message = use_signal(|| None::<String>);
+
+    let variants_for_preview = variants.clone();
+    let service_for_preview = world_service.clone();
+    let world_id_for_preview = props.world_id.clone();
+    let handle_preview = move |_| {
+        let svc = service_for_preview.clone();
+        let wid = world_id_for_preview.clone();
+        let variant = variants_for_preview[*target.read()].clone();
+        spawn_task(async move {
+            is_busy.set(true);
+            error.set(None);
+            success_message.set(None);
+            match svc.preview_rule_system_change(&wid, variant).await {
+                Ok(preview) => {
+                    overrides.set(HashMap::new());
+                    plan.set(Some(preview));
+                }
+                Err(e) => error.set(Some(format!("Failed to preview the change: {}", e))),
+            }
+            is_busy.set(false);
+        });
+    };
+
+    let variants_for_switch = variants.clone();
+    let service_for_switch = world_service.clone();
+    let world_id_for_switch = props.world_id.clone();
+    let handle_change = move |_| {
+        let svc = service_for_switch.clone();
+        let wid = world_id_for_switch.clone();
+        let variant = variants_for_switch[*target.read()].clone();
+        let skill_overrides: Vec<SkillOverrideData> = overrides
+            .read()
+            .iter()
+            .filter(|(_, skill)| !skill.trim().is_empty())
+            .map(|(challenge_id, skill)| SkillOverrideData {
+                challenge_id: challenge_id.clone(),
+                skill: skill.trim().to_string(),
+            })
+            .collect();
+        spawn_task(async move {
+            is_busy.set(true);
+            error.set(None);
+            match svc.change_rule_system(&wid, variant, skill_overrides).await {
+                Ok(done) => {
+                    let moved = done
+                        .challenges
+                        .iter()
+                        .filter(|c| c.to_skill.as_ref().is_some_and(|to| *to != c.from_skill))
+                        .count();
+                    success_message.set(Some(format!(
+                        "Changed to {}; {} challenge(s) now check a new skill and {} sheet(s) were converted.",
+                        done.to_variant.display_name(),
+                        moved,
+                        done.sheets.len()
+                    )));
+                    plan.set(None);
+                    overrides.set(HashMap::new());
+                }
+                Err(e) => error.set(Some(format!("Failed to change rule system: {}", e))),
+            }
+            is_busy.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "rule-system-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                h3 { class: "text-white text-lg font-medium mb-1", "Rule System" }
+                p {
+                    class: "text-gray-500 text-sm",
+                    "Change this world to another system. Preset skills are replaced, challenges move to the matching skill and character sheets are converted; custom skills stay and old sheets are kept."
+                }
+            }
+
+            div {
+                class: "flex gap-2 items-center",
+
+                select {
+                    class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    value: "{target}",
+                    onchange: move |evt| {
+                        if let Ok(index) = evt.value().parse::<usize>() {
+                            target.set(index);
+                            plan.set(None);
+                            success_message.set(None);
+                        }
+                    },
+                    for (index, variant) in variants.iter().enumerate() {
+                        option { value: "{index}", "{variant.display_name()}" }
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-gray-600 text-white rounded-md hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_preview,
+                    disabled: *is_busy.read(),
+                    "Preview"
+                }
+            }
+
+            if let Some(msg) = success_message.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-900 bg-opacity-30 text-green-400 rounded-md text-sm",
+                    "{msg}"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if let Some(preview) = plan.read().as_ref() {
+                div {
+                    class: "flex flex-col gap-2",
+
+                    p {
+                        class: "text-gray-400 text-sm",
+                        "{preview.from_variant.display_name()} → {preview.to_variant.display_name()}"
+                    }
+
+                    if preview.challenges.is_empty() {
+                        p { class: "text-gray-500 text-sm italic", "No challenges check a skill." }
+                    }
+
+                    for row in preview.challenges.iter() {
+                        div {
+                            key: "{row.challenge_id}",
+                            class: "flex gap-2 items-center text-sm",
+
+                            span { class: "flex-1 text-gray-300", "{row.challenge_name}" }
+                            span { class: "text-gray-500", "{row.from_skill} →" }
+                            if let Some(to) = row.to_skill.as_ref() {
+                                span { class: "w-40 text-green-400", "{to}" }
+                            } else {
+                                {
+                                    let challenge_id = row.challenge_id.clone();
+                                    let current = overrides
+                                        .read()
+                                        .get(&row.challenge_id)
+                                        .cloned()
+                                        .unwrap_or_default();
+                                    rsx! {
+                                        input {
+                                            class: "w-40 p-1 bg-dark-bg border border-amber-700 rounded text-white",
+                                            placeholder: "Keep {row.from_skill}",
+                                            list: "rule-system-skills",
+                                            value: "{current}",
+                                            oninput: move |evt| {
+                                                overrides.write().insert(challenge_id.clone(), evt.value());
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if !preview.sheets.is_empty() {
+                        p { class: "text-gray-400 text-sm mt-2", "Character sheets ({preview.system_id})" }
+                    }
+
+                    for sheet in preview.sheets.iter() {
+                        div {
+                            key: "{sheet.pc_id}",
+                            class: "flex flex-col text-sm",
+
+                            span { class: "text-gray-300", "{sheet.pc_name}: {sheet.carried} field(s) carried over" }
+                            if !sheet.unconverted.is_empty() {
+                                span {
+                                    class: "text-amber-400",
+                                    "Needs review: {sheet.unconverted.join(\", \")}"
+                                }
+                            }
+                        }
+                    }
+
+                    datalist {
+                        id: "rule-system-skills",
+                        for skill in preview.skills.iter() {
+                            option { value: "{skill}" }
+                        }
+                    }
+
+                    button {
+                        class: "self-end px-4 py-2 bg-red-700 text-white rounded-md hover:bg-red-800 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                        onclick: handle_change,
+                        disabled: *is_busy.read(),
+                        "Change to {preview.to_variant.display_name()}"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,275 @@
+//! Automation Scripts Panel - DM-written Rhai scripts run on game hooks
+//!
+//! Each script runs when its hook fires (item pickup, time advance,
+//! challenge resolution) and can give or take items, set world flags and
+//! send the DM a message. The engine checks syntax on save and stops any
+//! run that goes over the script's operation budget.
+
+use crate::application::dto::{ScriptHookData, WorldScriptData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+
+/// Operation budget shown for new scripts (matches the engine default)
+const DEFAULT_BUDGET: u64 = 10_000;
+
+const HOOKS: [(ScriptHookData, &str, &str); 3] = [
+    (ScriptHookData::ItemPickup, "itemPickup", "Item picked up"),
+    (ScriptHookData::TimeAdvance, "timeAdvance", "Time advanced"),
+    (
+        ScriptHookData::ChallengeResolved,
+        "challengeResolved",
+        "Challenge resolved",
+    ),
+];
+
+/// Props for the Automation Scripts Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct ScriptsPanelProps {
+    /// The world whose scripts are edited
+    pub world_id: String,
+}
+
+/// Automation Scripts Panel component
+#[component]
+pub fn ScriptsPanel(props: ScriptsPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut scripts = use_signal(Vec::<WorldScriptData>::new);
+    let mut is_loading = use_signal(|| true);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut success_message = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_save = props.world_id.clone();
+    let service_for_load = world_service.clone();
+    let service_for_save = world_service.clone();
+
+    // Load scripts on mount or world_id change
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+
+            match svc.get_scripts(&wid).await {
+                Ok(data) => scripts.set(data),
+                Err(e) => error.set(Some(format!("Failed to load scripts: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let handle_save = move |_| {
+        let svc = service_for_save.clone();
+        let wid = world_id_for_save.clone();
+        let current = scripts.read().clone();
+        spawn_task(async move {
+            is_saving.set(true);
+            error.set(None);
+            success_message.set(None);
+
+            match svc.update_scripts(&wid, current).await {
+                Ok(saved) => {
+                    scripts.set(saved);
+                    success_message.set(Some("Scripts saved!".to_string()));
+                }
+                Err(e) => error.set(Some(format!("Failed to save scripts: {}", e))),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let handle_add = move |_| {
+        let count = scripts.read().len();
+        scripts.write().push(WorldScriptData {
+            name: format!("Script {}", count + 1),
+            hook: ScriptHookData::ItemPickup,
+            source: String::new(),
+            enabled: true,
+            operation_budget: Some(DEFAULT_BUDGET),
+        });
+        success_message.set(None);
+    };
+
+    let current = scripts.read().clone();
+
+    rsx! {
+        div {
+            class: "scripts-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Automation Scripts" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "Rhai scripts that run when something happens in the world. "
+                        "Use give_item, take_item, set_flag and notify_dm; the hook's details are in event.data."
+                    }
+                }
+
+                div {
+                    class: "flex gap-2",
+                    button {
+                        class: "px-4 py-2 bg-gray-700 text-white rounded-md hover:bg-gray-600 disabled:opacity-50 text-sm",
+                        onclick: handle_add,
+                        disabled: *is_loading.read(),
+                        "Add Script"
+                    }
+                    button {
+                        class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                        onclick: handle_save,
+                        disabled: *is_loading.read() || *is_saving.read(),
+                        if *is_saving.read() { "Saving..." } else { "Save" }
+                    }
+                }
+            }
+
+            if let Some(msg) = success_message.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-900 bg-opacity-30 text-green-400 rounded-md text-sm",
+                    "{msg}"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading scripts..." }
+            } else if current.is_empty() {
+                div { class: "text-gray-500 text-sm", "No scripts yet." }
+            } else {
+                for (index, script) in current.into_iter().enumerate() {
+                    ScriptEditor {
+                        key: "{index}",
+                        script,
+                        onchange: move |updated: WorldScriptData| {
+                            if let Some(slot) = scripts.write().get_mut(index) {
+                                *slot = updated;
+                            }
+                            success_message.set(None);
+                        },
+                        onremove: move |_| {
+                            scripts.write().remove(index);
+                            success_message.set(None);
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ScriptEditorProps {
+    script: WorldScriptData,
+    onchange: EventHandler<WorldScriptData>,
+    onremove: EventHandler<()>,
+}
+
+#[component]
+fn ScriptEditor(props: ScriptEditorProps) -> Element {
+    let script = props.script.clone();
+    let hook_value = HOOKS
+        .iter()
+        .find(|(hook, _, _)| *hook == script.hook)
+        .map(|(_, value, _)| *value)
+        .unwrap_or("");
+    let budget = script.operation_budget.unwrap_or(DEFAULT_BUDGET);
+
+    let for_name = script.clone();
+    let for_hook = script.clone();
+    let for_enabled = script.clone();
+    let for_budget = script.clone();
+    let for_source = script.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 border border-gray-700 rounded-md p-3",
+
+            div {
+                class: "flex gap-2 items-center",
+
+                input {
+                    r#type: "text",
+                    class: "flex-1 px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    value: "{script.name}",
+                    oninput: move |evt| {
+                        props.onchange.call(WorldScriptData { name: evt.value(), ..for_name.clone() });
+                    },
+                }
+
+                select {
+                    class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    value: "{hook_value}",
+                    onchange: move |evt| {
+                        let value = evt.value();
+                        if let Some((hook, _, _)) = HOOKS.iter().find(|(_, v, _)| *v == value) {
+                            props.onchange.call(WorldScriptData { hook: *hook, ..for_hook.clone() });
+                        }
+                    },
+                    for (_, value, label) in HOOKS {
+                        option { value: "{value}", "{label}" }
+                    }
+                }
+
+                label {
+                    class: "flex items-center gap-1 text-gray-300 text-sm",
+                    input {
+                        r#type: "checkbox",
+                        checked: script.enabled,
+                        onchange: move |evt| {
+                            props.onchange.call(WorldScriptData { enabled: evt.checked(), ..for_enabled.clone() });
+                        },
+                    }
+                    "Enabled"
+                }
+
+                label {
+                    class: "flex items-center gap-1 text-gray-300 text-sm",
+                    title: "Operations the script may run each time it fires",
+                    "Budget"
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        class: "w-24 px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                        value: "{budget}",
+                        oninput: move |evt| {
+                            if let Ok(budget) = evt.value().parse::<u64>() {
+                                props.onchange.call(WorldScriptData {
+                                    operation_budget: Some(budget),
+                                    ..for_budget.clone()
+                                });
+                            }
+                        },
+                    }
+                }
+
+                button {
+                    class: "px-2 py-1 text-red-400 hover:text-red-300 text-sm",
+                    onclick: move |_| props.onremove.call(()),
+                    "Remove"
+                }
+            }
+
+            textarea {
+                class: "w-full h-32 px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm font-mono",
+                spellcheck: false,
+                placeholder: "if event.data.itemName == \"Old Bell\" {{ set_flag(\"bell_found\", true); }}",
+                value: "{script.source}",
+                oninput: move |evt| {
+                    props.onchange.call(WorldScriptData { source: evt.value(), ..for_source.clone() });
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Theme Panels - Device theme and per-world accent and backdrop frame
+//!
+//! The device theme (dark, light, high contrast) is saved locally and applies
+//! everywhere. The world theme is saved on the Engine by the DM and reaches
+//! every connected player.
+
+use crate::application::dto::{BackdropFrameData, WorldThemeData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use crate::presentation::state::{use_theme_state, ThemeMode};
+use crate::presentation::utils::{backdrop_frame_class, world_accent_style, world_theme_from_data};
+use crate::use_platform;
+use dioxus::prelude::*;
+
+/// Default accent shown in the color picker when the world has none
+const DEFAULT_ACCENT: &str = "#d4af37";
+
+/// Device theme selector
+#[component]
+pub fn ThemePanel() -> Element {
+    let platform = use_platform();
+    let mut theme = use_theme_state();
+    let current = *theme.mode.read();
+
+    rsx! {
+        div {
+            class: "theme-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                h3 { class: "text-white text-lg font-medium mb-1", "Theme" }
+                p {
+                    class: "text-gray-500 text-sm",
+                    "Saved on this device only."
+                }
+            }
+
+            div {
+                class: "flex gap-2",
+                role: "radiogroup",
+                aria_label: "Theme",
+
+                for mode in ThemeMode::ALL {
+                    button {
+                        key: "{mode.as_str()}",
+                        role: "radio",
+                        aria_checked: "{mode == current}",
+                        class: if mode == current {
+                            "flex-1 px-3 py-2 rounded-md text-sm border-2 border-amber-500 bg-gray-800 text-white"
+                        } else {
+                            "flex-1 px-3 py-2 rounded-md text-sm border-2 border-gray-700 bg-gray-800 text-gray-300 hover:border-gray-500"
+                        },
+                        onclick: {
+                            let platform = platform.clone();
+                            move |_| theme.set_mode(&platform, mode)
+                        },
+                        "{mode.display_name()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Props for the World Theme Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct WorldThemePanelProps {
+    /// The world whose theme is edited
+    pub world_id: String,
+}
+
+/// World accent color and backdrop frame (DM)
+#[component]
+pub fn WorldThemePanel(props: WorldThemePanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut accent_color = use_signal(String::new);
+    let mut backdrop_frame = use_signal(BackdropFrameData::default);
+    let mut is_loading = use_signal(|| true);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut success_message = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_save = props.world_id.clone();
+    let service_for_load = world_service.clone();
+    let service_for_save = world_service.clone();
+
+    let mut apply = move |data: WorldThemeData| {
+        accent_color.set(data.accent_color.unwrap_or_default());
+        backdrop_frame.set(data.backdrop_frame);
+    };
+
+    // Load settings on mount or world_id change
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+
+            match svc.get_theme(&wid).await {
+                Ok(data) => apply(data),
+                Err(e) => error.set(Some(format!("Failed to load world theme: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let current = move || WorldThemeData {
+        accent_color: {
+            let value = accent_color.read();
+            let trimmed = value.trim();
+            (!trimmed.is_empty()).then(|| trimmed.to_string())
+        },
+        backdrop_frame: *backdrop_frame.read(),
+    };
+
+    let handle_save = move |_| {
+        let svc = service_for_save.clone();
+        let wid = world_id_for_save.clone();
+        let theme = current();
+        spawn_task(async move {
+            is_saving.set(true);
+            error.set(None);
+            success_message.set(None);
+
+            match svc.update_theme(&wid, theme).await {
+                Ok(saved) => {
+                    apply(saved);
+                    success_message.set(Some("World theme saved!".to_string()));
+                }
+                Err(e) => error.set(Some(format!("Failed to save world theme: {}", e))),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let frame_value = match *backdrop_frame.read() {
+        BackdropFrameData::Simple => "simple",
+        BackdropFrameData::Ornate => "ornate",
+        BackdropFrameData::Parchment => "parchment",
+        BackdropFrameData::Vignette => "vignette",
+        BackdropFrameData::Plain | BackdropFrameData::Unknown => "plain",
+    };
+    let picker_value = {
+        let value = accent_color.read();
+        if value.is_empty() {
+            DEFAULT_ACCENT.to_string()
+        } else {
+            value.clone()
+        }
+    };
+    let preview = world_theme_from_data(current());
+    let preview_style = world_accent_style(&preview);
+    let preview_frame = backdrop_frame_class(preview.backdrop_frame);
+
+    rsx! {
+        div {
+            class: "world-theme-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "World Theme" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "Accent color and scene frame shown to every player in this world."
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_save,
+                    disabled: *is_loading.read() || *is_saving.read(),
+                    if *is_saving.read() { "Saving..." } else { "Save" }
+                }
+            }
+
+            if let Some(msg) = success_message.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-900 bg-opacity-30 text-green-400 rounded-md text-sm",
+                    "{msg}"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading world theme..." }
+            } else {
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Accent Color" }
+                    div {
+                        class: "flex gap-2 items-center",
+                        input {
+                            r#type: "color",
+                            aria_label: "Accent color picker",
+                            value: "{picker_value}",
+                            oninput: move |e| {
+                                accent_color.set(e.value());
+                                success_message.set(None);
+                            },
+                            class: "w-10 h-10 p-0 bg-transparent border border-gray-700 rounded cursor-pointer",
+                        }
+                        input {
+                            r#type: "text",
+                            aria_label: "Accent color hex value",
+                            value: "{accent_color}",
+                            placeholder: "Default",
+                            oninput: move |e| {
+                                accent_color.set(e.value());
+                                success_message.set(None);
+                            },
+                            class: "flex-1 p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                        }
+                        button {
+                            class: "px-3 py-2 bg-gray-700 text-white rounded text-sm hover:bg-gray-600",
+                            onclick: move |_| {
+                                accent_color.set(String::new());
+                                success_message.set(None);
+                            },
+                            "Reset"
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Backdrop Frame" }
+                    select {
+                        value: "{frame_value}",
+                        onchange: move |e| {
+                            backdrop_frame.set(match e.value().as_str() {
+                                "simple" => BackdropFrameData::Simple,
+                                "ornate" => BackdropFrameData::Ornate,
+                                "parchment" => BackdropFrameData::Parchment,
+                                "vignette" => BackdropFrameData::Vignette,
+                                _ => BackdropFrameData::Plain,
+                            });
+                            success_message.set(None);
+                        },
+                        class: "p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                        option { value: "plain", "None" }
+                        option { value: "simple", "Simple border" }
+                        option { value: "ornate", "Ornate" }
+                        option { value: "parchment", "Parchment" }
+                        option { value: "vignette", "Vignette" }
+                    }
+                }
+
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Preview" }
+                    div {
+                        style: "{preview_style}",
+                        class: "relative h-32 rounded overflow-hidden bg-gradient-to-b from-dark-surface to-dark-purple-end",
+                        div {
+                            class: "absolute inset-0 pointer-events-none {preview_frame}",
+                        }
+                        div {
+                            class: "absolute bottom-3 left-3 right-3 p-2 bg-ink-900/90 border-t-2 border-gold-500 rounded-sm",
+                            span { class: "text-gold-400 text-sm", "Innkeeper" }
+                            p { class: "text-parchment-100 text-xs m-0", "Welcome, traveler." }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Typography Panel - Per-world text direction, language, and font
+//!
+//! Lets the DM declare the campaign's language and script direction so
+//! dialogue and character sheets render correctly for right-to-left scripts
+//! and CJK text, and pick a font (optionally loaded from a stylesheet URL).
+
+use crate::application::dto::{TextDirectionData, WorldTypographyData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use crate::presentation::utils::{typography_from_data, TextLayout};
+use dioxus::prelude::*;
+
+/// Props for the Typography Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct TypographyPanelProps {
+    /// The world whose typography is edited
+    pub world_id: String,
+}
+
+/// Typography Panel component
+#[component]
+pub fn TypographyPanel(props: TypographyPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut direction = use_signal(TextDirectionData::default);
+    let mut language = use_signal(String::new);
+    let mut font_family = use_signal(String::new);
+    let mut font_stylesheet_url = use_signal(String::new);
+    let mut is_loading = use_signal(|| true);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut success_message = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_save = props.world_id.clone();
+    let service_for_load = world_service.clone();
+    let service_for_save = world_service.clone();
+
+    let mut apply = move |data: WorldTypographyData| {
+        direction.set(data.direction);
+        language.set(data.language.unwrap_or_default());
+        font_family.set(data.font_family.unwrap_or_default());
+        font_stylesheet_url.set(data.font_stylesheet_url.unwrap_or_default());
+    };
+
+    // Load settings on mount or world_id change
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+
+            match svc.get_typography(&wid).await {
+                Ok(data) => apply(data),
+                Err(e) => error.set(Some(format!("Failed to load typography: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let current = move || WorldTypographyData {
+        direction: *direction.read(),
+        language: non_empty(&language.read()),
+        font_family: non_empty(&font_family.read()),
+        font_stylesheet_url: non_empty(&font_stylesheet_url.read()),
+    };
+
+    let handle_save = move |_| {
+        let svc = service_for_save.clone();
+        let wid = world_id_for_save.clone();
+        let typography = current();
+        spawn_task(async move {
+            is_saving.set(true);
+            error.set(None);
+            success_message.set(None);
+
+            match svc.update_typography(&wid, typography).await {
+                Ok(saved) => {
+                    apply(saved);
+                    success_message.set(Some("Typography saved!".to_string()));
+                }
+                Err(e) => error.set(Some(format!("Failed to save typography: {}", e))),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let direction_value = match *direction.read() {
+        TextDirectionData::LeftToRight => "ltr",
+        TextDirectionData::RightToLeft => "rtl",
+        TextDirectionData::Auto | TextDirectionData::Unknown => "auto",
+    };
+    let preview = TextLayout::for_world(&typography_from_data(current()));
+
+    rsx! {
+        div {
+            class: "typography-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Typography" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "Language, text direction, and font for dialogue and character sheets."
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_save,
+                    disabled: *is_loading.read() || *is_saving.read(),
+                    if *is_saving.read() { "Saving..." } else { "Save" }
+                }
+            }
+
+            if let Some(msg) = success_message.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-900 bg-opacity-30 text-green-400 rounded-md text-sm",
+                    "{msg}"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading typography..." }
+            } else {
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Language" }
+                    p {
+                        class: "text-gray-500 text-xs m-0",
+                        "Language tag such as ar, he, ja, or zh-Hant. Controls line breaking and font fallback."
+                    }
+                    input {
+                        r#type: "text",
+                        value: "{language}",
+                        placeholder: "en",
+                        oninput: move |e| {
+                            language.set(e.value());
+                            success_message.set(None);
+                        },
+                        class: "p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                    }
+                }
+
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Text Direction" }
+                    select {
+                        value: "{direction_value}",
+                        onchange: move |e| {
+                            direction.set(match e.value().as_str() {
+                                "ltr" => TextDirectionData::LeftToRight,
+                                "rtl" => TextDirectionData::RightToLeft,
+                                _ => TextDirectionData::Auto,
+                            });
+                            success_message.set(None);
+                        },
+                        class: "p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                        option { value: "auto", "Automatic (from language or text)" }
+                        option { value: "ltr", "Left to right" }
+                        option { value: "rtl", "Right to left" }
+                    }
+                }
+
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Font Family" }
+                    input {
+                        r#type: "text",
+                        value: "{font_family}",
+                        placeholder: "'Noto Naskh Arabic', serif",
+                        oninput: move |e| {
+                            font_family.set(e.value());
+                            success_message.set(None);
+                        },
+                        class: "p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                    }
+                }
+
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Font Stylesheet URL" }
+                    p {
+                        class: "text-gray-500 text-xs m-0",
+                        "Optional CSS that loads the font, e.g. from a web font service."
+                    }
+                    input {
+                        r#type: "url",
+                        value: "{font_stylesheet_url}",
+                        oninput: move |e| {
+                            font_stylesheet_url.set(e.value());
+                            success_message.set(None);
+                        },
+                        class: "p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                    }
+                }
+
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Preview" }
+                    p {
+                        dir: preview.dir,
+                        lang: preview.lang,
+                        style: "{preview.style}",
+                        class: "p-3 bg-black/40 rounded text-white text-base m-0",
+                        "The innkeeper looks up. «مرحباً بك أيها المسافر» — 「ようこそ、旅の方。」"
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
@@ -0,0 +1,156 @@
+//! Unused Content Panel - Chekhov's gun reminders
+//!
+//! Lists what the DM has authored but play has never reached: challenges no
+//! one has attempted, NPCs no one has met, lore no one has discovered and
+//! narrative events that can no longer fire, each with a suggestion for
+//! where to bring it back in.
+
+use crate::application::services::world_service::UnusedContentReport;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+
+/// Props for the Unused Content Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct UnusedContentPanelProps {
+    /// The world whose unused content is shown
+    pub world_id: String,
+}
+
+/// Unused Content Panel component
+#[component]
+pub fn UnusedContentPanel(props: UnusedContentPanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut report = use_signal(|| None::<UnusedContentReport>);
+    let mut is_loading = use_signal(|| true);
+    let mut error = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_refresh = props.world_id.clone();
+    let service_for_load = world_service.clone();
+    let service_for_refresh = world_service.clone();
+
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+            match svc.get_unused_content(&wid).await {
+                Ok(loaded) => report.set(Some(loaded)),
+                Err(e) => error.set(Some(format!("Failed to load unused content: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let handle_refresh = move |_| {
+        let svc = service_for_refresh.clone();
+        let wid = world_id_for_refresh.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+            match svc.get_unused_content(&wid).await {
+                Ok(loaded) => report.set(Some(loaded)),
+                Err(e) => error.set(Some(format!("Failed to load unused content: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "unused-content-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Unused Content" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "What you've prepared that play hasn't reached yet, and where it could come back in."
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-gray-600 text-white rounded-md hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_refresh,
+                    disabled: *is_loading.read(),
+                    "Refresh"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading unused content..." }
+            } else if let Some(report) = report.read().as_ref() {
+                h4 { class: "text-gray-300 text-sm font-medium", "Challenges never attempted" }
+                if report.challenges.is_empty() {
+                    div { class: "text-gray-500 text-sm", "Every challenge has been attempted." }
+                } else {
+                    for challenge in report.challenges.iter() {
+                        div {
+                            key: "{challenge.challenge_id}",
+                            class: "text-sm border-t border-gray-800 py-1",
+                            div { class: "text-gray-300", "{challenge.name}" }
+                            div { class: "text-gray-500 text-xs", "{challenge.suggestion}" }
+                        }
+                    }
+                }
+
+                h4 { class: "text-gray-300 text-sm font-medium", "NPCs never met" }
+                if report.npcs.is_empty() {
+                    div { class: "text-gray-500 text-sm", "The party has met every NPC." }
+                } else {
+                    for npc in report.npcs.iter() {
+                        div {
+                            key: "{npc.npc_id}",
+                            class: "text-sm border-t border-gray-800 py-1",
+                            div { class: "text-gray-300", "{npc.name}" }
+                            div { class: "text-gray-500 text-xs", "{npc.suggestion}" }
+                        }
+                    }
+                }
+
+                h4 { class: "text-gray-300 text-sm font-medium", "Lore never discovered" }
+                if report.lore.is_empty() {
+                    div { class: "text-gray-500 text-sm", "No undiscovered lore." }
+                } else {
+                    for lore in report.lore.iter() {
+                        div {
+                            key: "{lore.lore_id}",
+                            class: "text-sm border-t border-gray-800 py-1",
+                            div { class: "text-gray-300", "{lore.title}" }
+                            div { class: "text-gray-500 text-xs", "{lore.suggestion}" }
+                        }
+                    }
+                }
+
+                h4 { class: "text-gray-300 text-sm font-medium", "Events that can no longer fire" }
+                if report.events.is_empty() {
+                    div { class: "text-gray-500 text-sm", "Every active event can still fire." }
+                } else {
+                    for event in report.events.iter() {
+                        div {
+                            key: "{event.event_id}",
+                            class: "text-sm border-t border-gray-800 py-1",
+                            div { class: "text-gray-300", "{event.name}" }
+                            for trigger in event.dead_triggers.iter() {
+                                div { class: "text-red-400 text-xs", "{trigger}" }
+                            }
+                            div { class: "text-gray-500 text-xs", "{event.suggestion}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
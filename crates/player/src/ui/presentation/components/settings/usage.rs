@@ -0,0 +1,184 @@
+//! Usage Panel - Per-world LLM, image and storage usage
+//!
+//! Shows what the world has used over the last 30 days, day by day, against
+//! the daily quotas set in World Settings. Quotas are soft: the engine warns
+//! the DM when one is exceeded but never blocks generation.
+
+use crate::application::services::world_service::UsageReport;
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+use dioxus::prelude::*;
+
+/// Days of usage shown
+const REPORT_DAYS: u32 = 30;
+
+/// Props for the Usage Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct UsagePanelProps {
+    /// The world whose usage is shown
+    pub world_id: String,
+}
+
+/// Usage Panel component
+#[component]
+pub fn UsagePanel(props: UsagePanelProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut report = use_signal(|| None::<UsageReport>);
+    let mut is_loading = use_signal(|| true);
+    let mut error = use_signal(|| None::<String>);
+
+    let world_id_for_load = props.world_id.clone();
+    let world_id_for_refresh = props.world_id.clone();
+    let service_for_load = world_service.clone();
+    let service_for_refresh = world_service.clone();
+
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        let wid = world_id_for_load.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+            match svc.get_usage(&wid, Some(REPORT_DAYS)).await {
+                Ok(loaded) => report.set(Some(loaded)),
+                Err(e) => error.set(Some(format!("Failed to load usage: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let handle_refresh = move |_| {
+        let svc = service_for_refresh.clone();
+        let wid = world_id_for_refresh.clone();
+        spawn_task(async move {
+            is_loading.set(true);
+            error.set(None);
+            match svc.get_usage(&wid, Some(REPORT_DAYS)).await {
+                Ok(loaded) => report.set(Some(loaded)),
+                Err(e) => error.set(Some(format!("Failed to load usage: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "usage-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                class: "flex justify-between items-center",
+
+                div {
+                    h3 { class: "text-white text-lg font-medium mb-1", "Usage" }
+                    p {
+                        class: "text-gray-500 text-sm",
+                        "LLM tokens, generated images and asset storage over the last {REPORT_DAYS} days."
+                    }
+                }
+
+                button {
+                    class: "px-4 py-2 bg-gray-600 text-white rounded-md hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                    onclick: handle_refresh,
+                    disabled: *is_loading.read(),
+                    "Refresh"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading usage..." }
+            } else if let Some(report) = report.read().as_ref() {
+                for warning in report.warnings.iter() {
+                    div {
+                        class: "p-3 bg-amber-900 bg-opacity-30 text-amber-400 rounded-md text-sm",
+                        "Over today's {warning.resource} quota: {warning.used} of {warning.quota}"
+                    }
+                }
+
+                div {
+                    class: "grid grid-cols-4 gap-3",
+                    UsageStat { label: "LLM requests", value: report.totals.llm_requests.to_string() }
+                    UsageStat {
+                        label: "Tokens",
+                        value: (report.totals.prompt_tokens + report.totals.completion_tokens).to_string(),
+                    }
+                    UsageStat { label: "Images", value: report.totals.image_generations.to_string() }
+                    UsageStat { label: "Storage", value: format_bytes(report.totals.storage_bytes) }
+                }
+
+                div {
+                    class: "text-gray-500 text-xs",
+                    "Daily quotas: {format_quota(report.quotas.daily_token_quota)} tokens, {format_quota(report.quotas.daily_image_quota)} images"
+                }
+
+                if report.days.is_empty() {
+                    div { class: "text-gray-500 text-sm", "No usage recorded yet." }
+                } else {
+                    table {
+                        class: "w-full text-sm text-left",
+                        thead {
+                            tr {
+                                class: "text-gray-500 text-xs uppercase",
+                                th { class: "py-1", "Day" }
+                                th { class: "py-1", "Requests" }
+                                th { class: "py-1", "Tokens" }
+                                th { class: "py-1", "Images" }
+                                th { class: "py-1", "Storage" }
+                            }
+                        }
+                        tbody {
+                            for day in report.days.iter().rev() {
+                                tr {
+                                    key: "{day.day}",
+                                    class: "text-gray-300 border-t border-gray-800",
+                                    td { class: "py-1", "{day.day}" }
+                                    td { class: "py-1", "{day.llm_requests}" }
+                                    td { class: "py-1", "{day.prompt_tokens + day.completion_tokens}" }
+                                    td { class: "py-1", "{day.image_generations}" }
+                                    td { class: "py-1", "{format_bytes(day.storage_bytes)}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A labelled total
+#[derive(Props, Clone, PartialEq)]
+struct UsageStatProps {
+    label: &'static str,
+    value: String,
+}
+
+#[component]
+fn UsageStat(props: UsageStatProps) -> Element {
+    rsx! {
+        div {
+            class: "bg-gray-800 rounded-md p-3",
+            div { class: "text-gray-500 text-xs", "{props.label}" }
+            div { class: "text-white text-lg font-medium", "{props.value}" }
+        }
+    }
+}
+
+fn format_quota(quota: Option<u64>) -> String {
+    quota.map_or_else(|| "no limit on".to_string(), |q| q.to_string())
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const MIB: u64 = 1024 * 1024;
+    if bytes >= MIB {
+        format!("{:.1} MiB", bytes as f64 / MIB as f64)
+    } else {
+        format!("{} KiB", bytes / 1024)
+    }
+}
@@ -373,6 +373,43 @@ pub fn WorkflowConfigEditor(props: WorkflowConfigEditorProps) -> Element {
                         }
                     }
 
+                    // Reference image inputs (read-only, detected on upload)
+                    CollapsibleSection {
+                        title: "Reference Image",
+                        is_expanded: *expanded_section.read() == "reference",
+                        on_toggle: move |_| {
+                            if *expanded_section.read() == "reference" {
+                                expanded_section.set("");
+                            } else {
+                                expanded_section.set("reference");
+                            }
+                        },
+
+                        div {
+                            class: "flex flex-col gap-2",
+
+                            p {
+                                class: "text-gray-500 text-xs mb-2",
+                                "Character generations feed the character's current portrait into these inputs to keep them on-model. Set the IPAdapter weight under Input Defaults."
+                            }
+
+                            if cfg.reference_image_mappings.is_empty() {
+                                div {
+                                    class: "text-gray-500 text-sm p-2",
+                                    "No reference image inputs. Add an IPAdapter with a LoadImage node to the workflow to enable them."
+                                }
+                            }
+
+                            for mapping in cfg.reference_image_mappings.iter() {
+                                div {
+                                    key: "{mapping.node_id}.{mapping.input_name}",
+                                    class: "py-2 px-3 bg-black bg-opacity-20 rounded text-sm text-gray-300",
+                                    "Node {mapping.node_id} → {mapping.input_name}"
+                                }
+                            }
+                        }
+                    }
+
                     // Input Defaults section with lock toggles
                     CollapsibleSection {
                         title: "Input Defaults",
@@ -5,7 +5,9 @@
 
 use dioxus::prelude::*;
 
-use crate::application::services::AnalyzeWorkflowResponse;
+use crate::application::services::{
+    AnalyzeWorkflowResponse, ReferenceImageMapping, SaveWorkflowRequest,
+};
 use crate::infrastructure::spawn_task;
 use crate::presentation::services::use_workflow_service;
 
@@ -29,6 +31,8 @@ pub struct WorkflowAnalysisResult {
     pub text_inputs: Vec<TextInputInfo>,
     pub suggested_primary: Option<TextInputInfo>,
     pub suggested_negative: Option<TextInputInfo>,
+    /// Detected inputs for a reference image (IPAdapter)
+    pub reference_images: Vec<ReferenceImageMapping>,
     pub errors: Vec<String>,
 }
 
@@ -117,6 +121,11 @@ pub fn WorkflowUploadModal(props: WorkflowUploadModalProps) -> Element {
         let name = workflow_name.read().clone();
         let primary = primary_mapping.read().clone();
         let negative = negative_mapping.read().clone();
+        let reference_images = analysis
+            .read()
+            .as_ref()
+            .map(|a| a.reference_images.clone())
+            .unwrap_or_default();
         let slot = slot_for_save.clone();
         let on_save = on_save_handler;
         let svc = workflow_service_for_save.clone();
@@ -152,17 +161,15 @@ pub fn WorkflowUploadModal(props: WorkflowUploadModalProps) -> Element {
                 }));
             }
 
-            match svc
-                .save_workflow_config(
-                    &slot,
-                    &name,
-                    workflow_json_value,
-                    prompt_mappings,
-                    vec![],
-                    vec![],
-                )
-                .await
-            {
+            let request = SaveWorkflowRequest {
+                name,
+                workflow_json: workflow_json_value,
+                prompt_mappings,
+                reference_image_mappings: reference_images,
+                input_defaults: vec![],
+                locked_inputs: vec![],
+            };
+            match svc.save_workflow_config(&slot, &request).await {
                 Ok(_) => {
                     on_save.call(());
                 }
@@ -590,6 +597,13 @@ fn ReviewStepContent(props: ReviewStepContentProps) -> Element {
                         .map(|m| format!("{} → {}", m.node_title.clone().unwrap_or_else(|| format!("Node {}", m.node_id)), m.input_name))
                         .unwrap_or_else(|| "(Not set)".to_string())
                 }
+
+                ReviewRow {
+                    label: "Reference Image",
+                    value: props.analysis.as_ref()
+                        .map(|a| reference_image_summary(&a.reference_images))
+                        .unwrap_or_else(|| "(None)".to_string())
+                }
             }
 
             p {
@@ -600,6 +614,18 @@ fn ReviewStepContent(props: ReviewStepContentProps) -> Element {
     }
 }
 
+/// "Node 10 → image, ..." or a note that characters won't be kept on-model
+fn reference_image_summary(mappings: &[ReferenceImageMapping]) -> String {
+    if mappings.is_empty() {
+        return "(None - no IPAdapter found)".to_string();
+    }
+    mappings
+        .iter()
+        .map(|m| format!("Node {} → {}", m.node_id, m.input_name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 #[component]
 fn ReviewRow(label: &'static str, value: String) -> Element {
     rsx! {
@@ -654,6 +680,7 @@ impl From<AnalyzeWorkflowResponse> for WorkflowAnalysisResult {
             text_inputs,
             suggested_primary,
             suggested_negative,
+            reference_images: resp.suggested_reference_image_mappings,
             errors: resp.errors,
         }
     }
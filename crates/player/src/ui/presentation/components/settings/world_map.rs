@@ -0,0 +1,205 @@
+//! World Map Panel - Overview map image and location pins (DM)
+//!
+//! The DM sets the image used as the world's overview map, then pins
+//! locations to it: choose a location and click where it belongs. Changes
+//! reach every connected player.
+
+use crate::application::dto::{WorldMapData, WorldMapPositionData};
+use crate::application::services::location_service::LocationSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::components::world_map::WorldMapCanvas;
+use crate::presentation::services::{use_location_service, use_world_service};
+use dioxus::prelude::*;
+
+/// Props for the World Map Panel
+#[derive(Props, Clone, PartialEq)]
+pub struct WorldMapPanelProps {
+    /// The world whose map is edited
+    pub world_id: String,
+}
+
+/// World overview map and location pins (DM)
+#[component]
+pub fn WorldMapPanel(props: WorldMapPanelProps) -> Element {
+    let world_service = use_world_service();
+    let location_service = use_location_service();
+
+    let mut map = use_signal(WorldMapData::default);
+    let mut map_asset = use_signal(String::new);
+    let mut locations: Signal<Vec<LocationSummary>> = use_signal(Vec::new);
+    let mut selected: Signal<Option<String>> = use_signal(|| None);
+    let mut is_loading = use_signal(|| true);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let mut apply = move |data: WorldMapData| {
+        map_asset.set(data.map_asset.clone().unwrap_or_default());
+        map.set(data);
+    };
+
+    {
+        let world_service = world_service.clone();
+        let location_service = location_service.clone();
+        let world_id = props.world_id.clone();
+        use_effect(move || {
+            let world_svc = world_service.clone();
+            let location_svc = location_service.clone();
+            let wid = world_id.clone();
+            spawn_task(async move {
+                is_loading.set(true);
+                error.set(None);
+                match world_svc.get_world_map(&wid).await {
+                    Ok(data) => apply(data),
+                    Err(e) => error.set(Some(format!("Failed to load world map: {}", e))),
+                }
+                match location_svc.list_locations(&wid).await {
+                    Ok(found) => locations.set(found),
+                    Err(e) => error.set(Some(format!("Failed to load locations: {}", e))),
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let save_image = {
+        let svc = world_service.clone();
+        let wid = props.world_id.clone();
+        move |_| {
+            let svc = svc.clone();
+            let wid = wid.clone();
+            let asset = map_asset.read().trim().to_string();
+            spawn_task(async move {
+                is_saving.set(true);
+                error.set(None);
+                match svc
+                    .set_world_map_asset(&wid, (!asset.is_empty()).then_some(asset))
+                    .await
+                {
+                    Ok(saved) => apply(saved),
+                    Err(e) => error.set(Some(format!("Failed to save world map: {}", e))),
+                }
+                is_saving.set(false);
+            });
+        }
+    };
+
+    let set_pin = {
+        let svc = location_service.clone();
+        move |location_id: String, position: Option<WorldMapPositionData>| {
+            let svc = svc.clone();
+            spawn_task(async move {
+                error.set(None);
+                match svc.set_location_map_position(&location_id, position).await {
+                    Ok(saved) => apply(saved),
+                    Err(e) => error.set(Some(format!("Failed to place location: {}", e))),
+                }
+            });
+        }
+    };
+
+    let selected_id = selected.read().clone();
+    let selected_is_pinned = selected_id
+        .as_ref()
+        .is_some_and(|id| map.read().pins.iter().any(|p| &p.location_id == id));
+
+    rsx! {
+        div {
+            class: "world-map-panel flex flex-col gap-4 bg-gray-900 rounded-lg p-4",
+
+            div {
+                h3 { class: "text-white text-lg font-medium mb-1", "World Map" }
+                p {
+                    class: "text-gray-500 text-sm",
+                    "Overview map players can open, with pins for the locations you place on it."
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-900 bg-opacity-30 text-red-400 rounded-md text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-400 text-sm", "Loading world map..." }
+            } else {
+                div {
+                    class: "flex flex-col gap-1",
+                    label { class: "text-gray-300 text-sm", "Map Image" }
+                    div {
+                        class: "flex gap-2",
+                        input {
+                            r#type: "text",
+                            value: "{map_asset}",
+                            placeholder: "/assets/maps/world.png",
+                            oninput: move |e| map_asset.set(e.value()),
+                            class: "flex-1 p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+                        }
+                        button {
+                            class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                            onclick: save_image,
+                            disabled: *is_saving.read(),
+                            if *is_saving.read() { "Saving..." } else { "Save" }
+                        }
+                    }
+                }
+
+                if map.read().map_asset.is_some() {
+                    div {
+                        class: "flex gap-2 items-center",
+                        select {
+                            value: selected_id.clone().unwrap_or_default(),
+                            onchange: move |e| {
+                                let val = e.value();
+                                selected.set((!val.is_empty()).then_some(val));
+                            },
+                            class: "flex-1 p-2 bg-gray-800 border border-gray-700 rounded text-white text-sm",
+
+                            option { value: "", "Choose a location to place..." }
+                            for loc in locations.read().iter() {
+                                option {
+                                    key: "{loc.id}",
+                                    value: "{loc.id}",
+                                    if map.read().pins.iter().any(|p| p.location_id == loc.id) {
+                                        "{loc.name} (placed)"
+                                    } else {
+                                        "{loc.name}"
+                                    }
+                                }
+                            }
+                        }
+                        if selected_is_pinned {
+                            button {
+                                class: "px-3 py-2 bg-red-600/80 text-white rounded text-sm hover:bg-red-600",
+                                onclick: {
+                                    let set_pin = set_pin.clone();
+                                    move |_| {
+                                        if let Some(id) = selected.read().clone() {
+                                            set_pin(id, None);
+                                        }
+                                    }
+                                },
+                                "Remove pin"
+                            }
+                        }
+                    }
+
+                    if selected_id.is_some() {
+                        p { class: "m-0 text-amber-400 text-xs", "Click the map to place the location." }
+                    }
+
+                    WorldMapCanvas {
+                        map: map.read().clone(),
+                        selected_location_id: selected_id.clone(),
+                        on_select: move |id: String| selected.set(Some(id)),
+                        on_place: selected_id.clone().map(|id| {
+                            let set_pin = set_pin.clone();
+                            EventHandler::new(move |position| set_pin(id.clone(), Some(position)))
+                        }),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -233,6 +233,8 @@ pub fn AddDmMarkerModal(props: AddDmMarkerModalProps) -> Element {
                                                     importance: importance_val,
                                                     marker_type: marker_type_val,
                                                     tags,
+                                                    pin: None,
+                                                    hidden: false,
                                                 };
 
                                                 match service.create_dm_marker(&world_id, &request).await {
@@ -0,0 +1,309 @@
+//! Trade Panel - PC-to-PC trade offers
+//!
+//! An offer dialog for building a trade with another PC in the region, and a
+//! tray listing open offers: incoming ones can be accepted or declined,
+//! outgoing ones withdrawn.
+
+use dioxus::prelude::*;
+use std::collections::HashSet;
+
+use crate::application::dto::{InventoryItemData, TradeOfferData};
+
+/// Drag data format for inventory items being dragged onto a trade partner
+pub const TRADE_ITEM_DRAG_FORMAT: &str = "text/plain";
+
+/// Another PC in the region who can be offered a trade
+#[derive(Debug, Clone, PartialEq)]
+pub struct TradePartner {
+    pub pc_id: String,
+    pub name: String,
+}
+
+/// A trade as built in the offer dialog, ready to send
+#[derive(Debug, Clone, PartialEq)]
+pub struct TradeDraft {
+    pub to_pc_id: String,
+    pub item_ids: Vec<String>,
+    pub offered_currency: u32,
+    pub requested_currency: u32,
+}
+
+/// Props for the TradeOfferDialog component
+#[derive(Props, Clone, PartialEq)]
+pub struct TradeOfferDialogProps {
+    pub partner: TradePartner,
+    /// The offering PC's inventory
+    pub items: Vec<InventoryItemData>,
+    /// Item dragged onto the partner, selected up front
+    #[props(default)]
+    pub initial_item_id: Option<String>,
+    /// Currency field for the game system; None hides the currency inputs
+    #[props(default)]
+    pub currency_label: Option<String>,
+    pub on_send: EventHandler<TradeDraft>,
+    pub on_cancel: EventHandler<()>,
+}
+
+/// Modal for choosing what to offer another PC and what to ask in return
+#[component]
+pub fn TradeOfferDialog(props: TradeOfferDialogProps) -> Element {
+    let initial = props.initial_item_id.clone();
+    let mut selected: Signal<HashSet<String>> = use_signal(move || initial.into_iter().collect());
+    let mut offered_currency = use_signal(|| 0u32);
+    let mut requested_currency = use_signal(|| 0u32);
+
+    let is_empty = selected.read().is_empty()
+        && *offered_currency.read() == 0
+        && *requested_currency.read() == 0;
+    let to_pc_id = props.partner.pc_id.clone();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/85 z-[1100] flex items-center justify-center p-4",
+            onclick: move |_| props.on_cancel.call(()),
+
+            div {
+                class: "bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl w-full max-w-md max-h-[85vh] overflow-hidden flex flex-col shadow-2xl border border-amber-500/20",
+                onclick: move |e| e.stop_propagation(),
+
+                div {
+                    class: "p-4 border-b border-white/10",
+                    h2 {
+                        class: "text-xl font-bold text-white m-0",
+                        "Trade with {props.partner.name}"
+                    }
+                    p {
+                        class: "text-gray-400 text-sm m-0 mt-1",
+                        "Choose what to give. They can accept or decline."
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-y-auto p-4 space-y-4",
+
+                    div {
+                        h3 {
+                            class: "text-sm font-semibold text-gray-400 uppercase tracking-wider mb-2",
+                            "You give"
+                        }
+                        if props.items.is_empty() {
+                            p {
+                                class: "text-gray-500 text-sm m-0",
+                                "You have no items to give."
+                            }
+                        }
+                        for entry in props.items.iter() {
+                            {
+                                let id = entry.item.id.clone();
+                                let checked = selected.read().contains(&id);
+                                rsx! {
+                                    label {
+                                        key: "{id}",
+                                        class: "flex items-center gap-2 py-1 text-white text-sm cursor-pointer",
+                                        input {
+                                            r#type: "checkbox",
+                                            checked,
+                                            onchange: move |_| {
+                                                let mut set = selected.write();
+                                                if !set.remove(&id) {
+                                                    set.insert(id.clone());
+                                                }
+                                            },
+                                        }
+                                        "{entry.item.name}"
+                                        if entry.equipped {
+                                            span {
+                                                class: "text-xs text-amber-400/70",
+                                                "(equipped)"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if let Some(ref label) = props.currency_label {
+                        div {
+                            class: "grid grid-cols-2 gap-3",
+                            CurrencyInput {
+                                label: format!("{} to give", label),
+                                value: *offered_currency.read(),
+                                on_change: move |v| offered_currency.set(v),
+                            }
+                            CurrencyInput {
+                                label: format!("{} to ask for", label),
+                                value: *requested_currency.read(),
+                                on_change: move |v| requested_currency.set(v),
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "p-4 border-t border-white/10 flex justify-end gap-2",
+                    button {
+                        class: "px-4 py-2 bg-white/5 hover:bg-white/10 text-gray-300 rounded-lg text-sm",
+                        onclick: move |_| props.on_cancel.call(()),
+                        "Cancel"
+                    }
+                    button {
+                        class: "px-4 py-2 bg-amber-500 hover:bg-amber-400 text-dark-bg font-semibold rounded-lg text-sm disabled:opacity-40 disabled:cursor-not-allowed",
+                        disabled: is_empty,
+                        onclick: move |_| {
+                            props.on_send.call(TradeDraft {
+                                to_pc_id: to_pc_id.clone(),
+                                item_ids: selected.read().iter().cloned().collect(),
+                                offered_currency: *offered_currency.read(),
+                                requested_currency: *requested_currency.read(),
+                            });
+                        },
+                        "Offer trade"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CurrencyInput(label: String, value: u32, on_change: EventHandler<u32>) -> Element {
+    rsx! {
+        label {
+            class: "flex flex-col gap-1 text-xs text-gray-400",
+            "{label}"
+            input {
+                r#type: "number",
+                min: "0",
+                class: "px-2 py-1.5 bg-black/30 border border-white/10 rounded text-white text-sm",
+                value: "{value}",
+                oninput: move |e| on_change.call(e.value().parse().unwrap_or(0)),
+            }
+        }
+    }
+}
+
+/// Props for the TradeOffersTray component
+#[derive(Props, Clone, PartialEq)]
+pub struct TradeOffersTrayProps {
+    pub offers: Vec<TradeOfferData>,
+    /// This player's PC; offers to it get Accept/Decline, offers from it Withdraw
+    pub pc_id: Option<String>,
+    /// (trade_id, accept)
+    pub on_respond: EventHandler<(String, bool)>,
+    pub on_cancel: EventHandler<String>,
+}
+
+/// Floating list of open trade offers involving this player's PC
+#[component]
+pub fn TradeOffersTray(props: TradeOffersTrayProps) -> Element {
+    let Some(pc_id) = props.pc_id.clone() else {
+        return rsx! {};
+    };
+    let offers: Vec<TradeOfferData> = props
+        .offers
+        .iter()
+        .filter(|t| t.from_pc_id == pc_id || t.to_pc_id == pc_id)
+        .cloned()
+        .collect();
+    if offers.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "fixed top-16 left-1/2 -translate-x-1/2 z-[950] w-96 max-w-[90vw] flex flex-col gap-2",
+            for trade in offers {
+                {
+                    let incoming = trade.to_pc_id == pc_id;
+                    let trade_id = trade.trade_id.clone();
+                    let label = trade.currency_label.clone().unwrap_or_default();
+                    rsx! {
+                        div {
+                            key: "{trade.trade_id}",
+                            class: "bg-dark-surface/95 border border-amber-500/30 rounded-xl p-3 shadow-xl",
+                            p {
+                                class: "text-white text-sm font-medium m-0 mb-2",
+                                if incoming {
+                                    "{trade.from_pc_name} offers you a trade"
+                                } else {
+                                    "Waiting for {trade.to_pc_name} to answer"
+                                }
+                            }
+                            TradeSideSummary {
+                                heading: if incoming { "You get".to_string() } else { "You give".to_string() },
+                                items: trade.offered.items.iter().map(|i| i.name.clone()).collect::<Vec<_>>(),
+                                currency: trade.offered.currency,
+                                currency_label: label.clone(),
+                            }
+                            if !trade.requested.items.is_empty() || trade.requested.currency > 0 {
+                                TradeSideSummary {
+                                    heading: if incoming { "You give".to_string() } else { "You get".to_string() },
+                                    items: trade.requested.items.iter().map(|i| i.name.clone()).collect::<Vec<_>>(),
+                                    currency: trade.requested.currency,
+                                    currency_label: label,
+                                }
+                            }
+                            div {
+                                class: "flex justify-end gap-2 mt-2",
+                                if incoming {
+                                    {
+                                        let decline_id = trade_id.clone();
+                                        rsx! {
+                                            button {
+                                                class: "px-3 py-1.5 bg-red-500/10 hover:bg-red-500/20 text-red-400 rounded text-sm",
+                                                onclick: move |_| props.on_respond.call((decline_id.clone(), false)),
+                                                "Decline"
+                                            }
+                                            button {
+                                                class: "px-3 py-1.5 bg-green-500/20 hover:bg-green-500/30 text-green-400 rounded text-sm",
+                                                onclick: move |_| props.on_respond.call((trade_id.clone(), true)),
+                                                "Accept"
+                                            }
+                                        }
+                                    }
+                                } else {
+                                    button {
+                                        class: "px-3 py-1.5 bg-white/5 hover:bg-white/10 text-gray-300 rounded text-sm",
+                                        onclick: move |_| props.on_cancel.call(trade_id.clone()),
+                                        "Withdraw"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TradeSideSummary(
+    heading: String,
+    items: Vec<String>,
+    currency: u32,
+    currency_label: String,
+) -> Element {
+    let mut parts = items;
+    if currency > 0 {
+        parts.push(
+            format!("{} {}", currency, currency_label)
+                .trim()
+                .to_string(),
+        );
+    }
+    let text = if parts.is_empty() {
+        "nothing".to_string()
+    } else {
+        parts.join(", ")
+    };
+
+    rsx! {
+        p {
+            class: "text-xs text-gray-400 m-0",
+            span { class: "text-gray-500", "{heading}: " }
+            "{text}"
+        }
+    }
+}
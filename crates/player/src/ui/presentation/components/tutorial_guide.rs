@@ -0,0 +1,59 @@
+//! Tutorial Guide - step-by-step help in tutorial worlds
+//!
+//! A card pinned to the top-left of the player view showing the current
+//! tutorial step. When a step is completed, its completion text is shown
+//! above the next step's instructions.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::TutorialStatusData;
+
+/// Props for the TutorialGuide component
+#[derive(Props, Clone, PartialEq)]
+pub struct TutorialGuideProps {
+    pub status: TutorialStatusData,
+    /// Hide the guide (only offered once the tutorial is finished)
+    pub on_dismiss: EventHandler<()>,
+}
+
+/// Card guiding a new player through the tutorial world
+#[component]
+pub fn TutorialGuide(props: TutorialGuideProps) -> Element {
+    let status = &props.status;
+    let feedback = status
+        .just_completed
+        .as_ref()
+        .and_then(|step| step.completion_text.clone());
+    let progress = format!("{} / {}", status.completed_steps, status.total_steps);
+
+    rsx! {
+        div {
+            class: "tutorial-guide fixed top-16 left-4 z-[940] w-80 max-w-[90vw] bg-dark-surface/95 border border-emerald-500/40 rounded-xl p-4 shadow-xl",
+
+            div {
+                class: "flex justify-between items-center mb-2",
+                span { class: "text-emerald-400 text-xs uppercase tracking-wide", "Tutorial" }
+                span { class: "text-gray-500 text-xs", "{progress}" }
+            }
+
+            if let Some(text) = feedback {
+                p {
+                    class: "text-gray-300 text-sm italic m-0 mb-3 pb-3 border-b border-gray-700",
+                    "{text}"
+                }
+            }
+
+            if let Some(step) = status.current_step.as_ref() {
+                h3 { class: "text-white text-base m-0 mb-1", "{step.title}" }
+                p { class: "text-gray-300 text-sm m-0 leading-snug", "{step.instructions}" }
+            } else {
+                h3 { class: "text-white text-base m-0 mb-2", "Tutorial complete!" }
+                button {
+                    onclick: move |_| props.on_dismiss.call(()),
+                    class: "px-3 py-1.5 bg-emerald-600 text-white border-0 rounded cursor-pointer text-sm",
+                    "Close guide"
+                }
+            }
+        }
+    }
+}
@@ -4,6 +4,9 @@
 
 use dioxus::prelude::*;
 
+use crate::presentation::state::use_game_state;
+use crate::presentation::utils::backdrop_frame_class;
+
 /// Props for the Backdrop component
 #[derive(Props, Clone, PartialEq)]
 pub struct BackdropProps {
@@ -22,8 +25,12 @@ pub struct BackdropProps {
 ///
 /// Uses the `.vn-backdrop` Tailwind class for styling.
 /// Falls back to a gradient if no image is provided.
+/// Draws the world's backdrop frame, if the DM picked one.
 #[component]
 pub fn Backdrop(props: BackdropProps) -> Element {
+    let game_state = use_game_state();
+    let frame_class = backdrop_frame_class(game_state.world_theme.read().backdrop_frame);
+
     // Extract conditionals BEFORE rsx! block (CRITICAL for Dioxus)
     let (bg_class, bg_style) = match &props.image_url {
         Some(url) => (
@@ -53,6 +60,13 @@ pub fn Backdrop(props: BackdropProps) -> Element {
                 class: "backdrop-vignette absolute inset-0 pointer-events-none shadow-[inset_0_0_150px_rgba(0,0,0,0.5)]",
             }
 
+            // World backdrop frame
+            if !frame_class.is_empty() {
+                div {
+                    class: "backdrop-frame absolute inset-0 pointer-events-none z-10 {frame_class}",
+                }
+            }
+
             // Children (character sprites, etc.)
             {props.children}
         }
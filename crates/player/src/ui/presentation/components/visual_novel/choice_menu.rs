@@ -6,6 +6,8 @@
 use dioxus::prelude::*;
 
 use crate::application::dto::DialogueChoice;
+use crate::presentation::components::voice_input::VoiceInputButton;
+use crate::presentation::utils::{focus_mounted, use_roving_focus, RovingFocus};
 use wrldbldr_domain::{parse_dialogue_markers, validate_markers, ExpressionConfig};
 
 /// Props for the ChoiceMenu component
@@ -23,21 +25,55 @@ pub struct ChoiceMenuProps {
 ///
 /// Uses `.vn-choice` Tailwind class for choice buttons.
 /// Includes a text input field for custom responses when available.
+/// The first choice takes focus; arrow keys move between choices and the
+/// number keys 1-9 pick one directly.
 #[component]
 pub fn ChoiceMenu(props: ChoiceMenuProps) -> Element {
     let mut custom_text = use_signal(String::new);
     let has_custom = props.choices.iter().any(|c| c.is_custom_input);
+    let roving = use_roving_focus();
+
+    let standard_ids: Vec<String> = props
+        .choices
+        .iter()
+        .filter(|c| !c.is_custom_input)
+        .map(|c| c.id.clone())
+        .collect();
 
     rsx! {
         div {
             class: "choice-menu flex flex-col gap-2 mt-4",
+            role: "group",
+            aria_label: "Dialogue choices",
 
             // Standard choice buttons
-            for choice in props.choices.iter().filter(|c| !c.is_custom_input) {
-                ChoiceButton {
-                    key: "{choice.id}",
-                    choice: choice.clone(),
-                    on_click: props.on_select,
+            div {
+                class: "flex flex-col gap-2",
+                onkeydown: move |e: KeyboardEvent| {
+                    if roving.handle_key(&e) {
+                        return;
+                    }
+                    if let Key::Character(c) = e.key() {
+                        let picked = c
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|n| n.checked_sub(1))
+                            .and_then(|i| standard_ids.get(i));
+                        if let Some(choice_id) = picked {
+                            e.prevent_default();
+                            props.on_select.call(choice_id.clone());
+                        }
+                    }
+                },
+
+                for (index, choice) in props.choices.iter().filter(|c| !c.is_custom_input).enumerate() {
+                    ChoiceButton {
+                        key: "{choice.id}",
+                        choice: choice.clone(),
+                        index,
+                        focus: roving,
+                        on_click: props.on_select,
+                    }
                 }
             }
 
@@ -62,6 +98,10 @@ pub fn ChoiceMenu(props: ChoiceMenuProps) -> Element {
 pub struct ChoiceButtonProps {
     /// The dialogue choice to display
     pub choice: DialogueChoice,
+    /// Position among the choices (0-based)
+    pub index: usize,
+    /// Roving focus group shared by the menu's buttons
+    pub focus: RovingFocus,
     /// Click handler
     pub on_click: EventHandler<String>,
 }
@@ -70,12 +110,26 @@ pub struct ChoiceButtonProps {
 #[component]
 pub fn ChoiceButton(props: ChoiceButtonProps) -> Element {
     let choice_id = props.choice.id.clone();
+    let index = props.index;
+    let focus = props.focus;
+    let shortcut = index + 1;
 
     rsx! {
         button {
             class: "vn-choice",
             onclick: move |_| props.on_click.call(choice_id.clone()),
+            onmounted: move |e| {
+                let element = e.data();
+                focus.register(index, element.clone());
+                if index == 0 {
+                    focus_mounted(element);
+                }
+            },
+            onfocus: move |_| focus.set_current(index),
 
+            if shortcut <= 9 {
+                span { class: "text-gray-500 me-2", aria_hidden: "true", "{shortcut}." }
+            }
             "{props.choice.text}"
         }
     }
@@ -115,6 +169,7 @@ pub fn CustomInputField(props: CustomInputFieldProps) -> Element {
                 input {
                     class: "input flex-1",
                     r#type: "text",
+                    aria_label: "Your response",
                     placeholder: "Type your response... (use *expression* for emotions)",
                     value: "{value}",
                     oninput: move |e| value.set(e.value()),
@@ -143,11 +198,23 @@ pub fn CustomInputField(props: CustomInputFieldProps) -> Element {
                     },
                     "Send"
                 }
+
+                VoiceInputButton {
+                    on_transcript: move |text: String| {
+                        // Add to what's typed; the player still sends it
+                        let joined = match value.peek().trim() {
+                            "" => text,
+                            typed => format!("{} {}", typed, text),
+                        };
+                        value.set(joined);
+                    },
+                }
             }
 
             // Validation warnings
             if !validation.read().warnings.is_empty() {
                 div {
+                    role: "status",
                     class: "text-yellow-400 text-xs px-2 py-1 bg-yellow-900/30 rounded",
                     for warning in validation.read().warnings.iter() {
                         div { "{warning}" }
@@ -274,10 +341,11 @@ pub struct ContinuePromptProps {
 pub fn ContinuePrompt(props: ContinuePromptProps) -> Element {
     rsx! {
         button {
-            class: "continue-prompt text-gray-400 text-sm bg-transparent border-none cursor-pointer py-2 px-0 text-left animate-pulse",
+            class: "continue-prompt text-gray-400 text-sm bg-transparent border-none cursor-pointer py-2 px-0 text-start animate-pulse",
             onclick: move |_| props.on_continue.call(()),
+            onmounted: move |e| focus_mounted(e.data()),
 
-            "Click to continue..."
+            "Click or press Enter to continue..."
         }
     }
 }
@@ -6,6 +6,7 @@
 use dioxus::prelude::*;
 
 use crate::application::dto::DialogueChoice;
+use crate::presentation::utils::{is_activation_key, use_text_layout};
 
 use super::choice_menu::{ChoiceMenu, ContinuePrompt};
 
@@ -45,15 +46,32 @@ pub struct DialogueBoxProps {
 ///
 /// Uses `.vn-dialogue-box`, `.vn-character-name`, `.vn-dialogue-text` Tailwind classes.
 /// Supports mood tags and action stage directions from the three-tier emotional model.
+///
+/// Screen readers hear each line once, after the typewriter finishes, through
+/// a polite live region; the animating text itself is hidden from them.
 #[component]
 pub fn DialogueBox(props: DialogueBoxProps) -> Element {
     let has_speaker = !props.speaker_name.is_empty();
     let has_choices = !props.choices.is_empty();
     let show_continue = !props.is_typing && !has_choices;
+    let layout = use_text_layout();
+    let can_skip = props.is_typing && !props.is_llm_processing;
+    let announcement = if props.is_typing || props.is_llm_processing {
+        String::new()
+    } else if has_speaker {
+        format!("{}: {}", props.speaker_name, props.dialogue_text)
+    } else {
+        props.dialogue_text.clone()
+    };
 
     rsx! {
         div {
             class: "vn-dialogue-box",
+            role: "region",
+            aria_label: "Dialogue",
+            dir: layout.dir,
+            lang: layout.lang,
+            style: "{layout.style}",
 
             // Speaker name plate with mood tag
             if has_speaker {
@@ -72,11 +90,26 @@ pub fn DialogueBox(props: DialogueBoxProps) -> Element {
                 }
             }
 
+            div {
+                class: "sr-only",
+                aria_live: "polite",
+                "{announcement}"
+            }
+
             // Dialogue text with typewriter cursor or loading indicator
             div {
                 class: "dialogue-text-container min-h-[60px]",
+                role: can_skip.then_some("button"),
+                tabindex: can_skip.then_some("0"),
+                aria_label: can_skip.then_some("Show full line"),
                 onclick: move |_| {
-                    if props.is_typing && !props.is_llm_processing {
+                    if can_skip {
+                        props.on_advance.call(());
+                    }
+                },
+                onkeydown: move |e: KeyboardEvent| {
+                    if can_skip && is_activation_key(&e.key()) {
+                        e.prevent_default();
                         props.on_advance.call(());
                     }
                 },
@@ -84,23 +117,26 @@ pub fn DialogueBox(props: DialogueBoxProps) -> Element {
                 if props.is_llm_processing {
                     p {
                         class: "vn-dialogue-text text-gray-400 italic",
+                        role: "status",
 
                         "NPC is thinking"
 
                         // Animated ellipsis
                         span {
                             class: "animate-ellipsis",
+                            aria_hidden: "true",
                             "..."
                         }
                     }
                 } else {
                     p {
                         class: "vn-dialogue-text",
+                        aria_hidden: "true",
 
                         // Current action as stage direction (italicized)
                         if let Some(action) = &props.current_action {
                             span {
-                                class: "italic text-gray-400 mr-1",
+                                class: "italic text-gray-400 me-1",
                                 "*{action}* "
                             }
                         }
@@ -110,7 +146,8 @@ pub fn DialogueBox(props: DialogueBoxProps) -> Element {
                         // Blinking cursor during typing
                         if props.is_typing {
                             span {
-                                class: "typewriter-cursor animate-blink ml-0.5",
+                                class: "typewriter-cursor animate-blink ms-0.5",
+                                aria_hidden: "true",
                                 "▌"
                             }
                         }
@@ -150,19 +187,41 @@ pub struct NarrationBoxProps {
 
 #[component]
 pub fn NarrationBox(props: NarrationBoxProps) -> Element {
+    let layout = use_text_layout();
+
     rsx! {
         div {
             class: "vn-dialogue-box narration text-center",
+            role: "button",
+            tabindex: "0",
+            aria_label: if props.is_typing { "Show full narration" } else { "Continue" },
+            dir: layout.dir,
+            lang: layout.lang,
+            style: "{layout.style}",
             onclick: move |_| props.on_advance.call(()),
+            onkeydown: move |e: KeyboardEvent| {
+                if is_activation_key(&e.key()) {
+                    e.prevent_default();
+                    props.on_advance.call(());
+                }
+            },
+
+            div {
+                class: "sr-only",
+                aria_live: "polite",
+                if !props.is_typing { "{props.text}" }
+            }
 
             p {
                 class: "vn-dialogue-text italic text-gray-300",
+                aria_hidden: "true",
 
                 "{props.text}"
 
                 if props.is_typing {
                     span {
-                        class: "typewriter-cursor animate-blink ml-0.5",
+                        class: "typewriter-cursor animate-blink ms-0.5",
+                        aria_hidden: "true",
                         "▌"
                     }
                 }
@@ -171,7 +230,8 @@ pub fn NarrationBox(props: NarrationBoxProps) -> Element {
             if !props.is_typing {
                 div {
                     class: "text-gray-500 text-xs mt-2",
-                    "Click to continue"
+                    aria_hidden: "true",
+                    "Click or press Enter to continue"
                 }
             }
         }
@@ -0,0 +1,167 @@
+//! Voice input button
+//!
+//! Records the player in the browser and fills their response with what the
+//! Engine's Whisper server heard. The text is left in the field to check and
+//! edit; the player still presses Send. Servers without voice input answer
+//! with an error, which shows under the button.
+
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_transcription_service;
+use crate::presentation::state::use_accessibility_state;
+
+/// Recording stops by itself after this long
+const MAX_RECORDING_MS: u32 = 30_000;
+
+/// Records until stopped, then sends the clip back as base64
+const RECORD_SCRIPT: &str = r#"
+try {
+    const stream = await navigator.mediaDevices.getUserMedia({ audio: true });
+    const recorder = new MediaRecorder(stream);
+    const chunks = [];
+    recorder.ondataavailable = (e) => chunks.push(e.data);
+    const stopped = new Promise((resolve) => (recorder.onstop = resolve));
+    window.wrldbldrStopVoice = () => {
+        if (recorder.state === "recording") recorder.stop();
+    };
+    recorder.start();
+    setTimeout(window.wrldbldrStopVoice, MAX_RECORDING_MS);
+    await stopped;
+    stream.getTracks().forEach((track) => track.stop());
+
+    const blob = new Blob(chunks, { type: recorder.mimeType });
+    const url = await new Promise((resolve, reject) => {
+        const reader = new FileReader();
+        reader.onload = () => resolve(reader.result);
+        reader.onerror = () => reject(reader.error);
+        reader.readAsDataURL(blob);
+    });
+    dioxus.send({ audio: url.slice(url.indexOf(",") + 1), contentType: blob.type });
+} catch (e) {
+    dioxus.send({ audio: "", contentType: "", error: String(e && e.message ? e.message : e) });
+}
+"#;
+
+const STOP_SCRIPT: &str = "if (window.wrldbldrStopVoice) window.wrldbldrStopVoice();";
+
+/// What the recording script sends back
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordedClip {
+    audio: String,
+    content_type: String,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum VoiceState {
+    Idle,
+    Recording,
+    Transcribing,
+}
+
+/// Props for the VoiceInputButton component
+#[derive(Props, Clone, PartialEq)]
+pub struct VoiceInputButtonProps {
+    /// Receives the transcript of each recording
+    pub on_transcript: EventHandler<String>,
+}
+
+/// Microphone button: press to speak, press again to stop
+#[component]
+pub fn VoiceInputButton(props: VoiceInputButtonProps) -> Element {
+    let transcription_service = use_transcription_service();
+    let mut voice_state = use_signal(|| VoiceState::Idle);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let reduced_motion = *use_accessibility_state().reduced_motion.read();
+    let on_transcript = props.on_transcript;
+
+    let toggle = move |_| {
+        let current = *voice_state.peek();
+        match current {
+            VoiceState::Idle => {
+                error.set(None);
+                voice_state.set(VoiceState::Recording);
+                let service = transcription_service.clone();
+                spawn_task(async move {
+                    let script =
+                        RECORD_SCRIPT.replace("MAX_RECORDING_MS", &MAX_RECORDING_MS.to_string());
+                    let mut recorder = document::eval(&script);
+                    let clip = match recorder.recv::<RecordedClip>().await {
+                        Ok(clip) => clip,
+                        Err(e) => {
+                            error.set(Some(format!("Recording failed: {}", e)));
+                            voice_state.set(VoiceState::Idle);
+                            return;
+                        }
+                    };
+                    if let Some(message) = clip.error {
+                        error.set(Some(format!("Couldn't use the microphone: {}", message)));
+                        voice_state.set(VoiceState::Idle);
+                        return;
+                    }
+
+                    voice_state.set(VoiceState::Transcribing);
+                    match service.transcribe(clip.audio, &clip.content_type).await {
+                        Ok(transcript) if transcript.text.is_empty() => {
+                            error.set(Some("Didn't catch that - try again".to_string()));
+                        }
+                        Ok(transcript) => on_transcript.call(transcript.text),
+                        Err(e) => error.set(Some(e.to_string())),
+                    }
+                    voice_state.set(VoiceState::Idle);
+                });
+            }
+            VoiceState::Recording => {
+                let _ = document::eval(STOP_SCRIPT);
+            }
+            VoiceState::Transcribing => {}
+        }
+    };
+
+    let pulse = if reduced_motion { "" } else { " animate-pulse" };
+    let (label, class) = match *voice_state.read() {
+        VoiceState::Idle => ("Speak", "btn btn-secondary".to_string()),
+        VoiceState::Recording => (
+            "Stop",
+            format!(
+                "px-3 py-2 bg-red-600/80 text-white rounded hover:bg-red-600{}",
+                pulse
+            ),
+        ),
+        VoiceState::Transcribing => ("Listening...", "btn btn-secondary opacity-60".to_string()),
+    };
+    let recording = *voice_state.read() == VoiceState::Recording;
+    let aria_label = if recording {
+        "Stop recording"
+    } else {
+        "Speak your response"
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col items-end",
+
+            button {
+                class: "{class}",
+                r#type: "button",
+                aria_label: aria_label,
+                aria_pressed: "{recording}",
+                disabled: *voice_state.read() == VoiceState::Transcribing,
+                onclick: toggle,
+                "{label}"
+            }
+
+            if let Some(message) = error.read().as_ref() {
+                div {
+                    role: "alert",
+                    class: "text-red-400 text-xs mt-1",
+                    "{message}"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,322 @@
+//! World Map - Overview map with a pin for each placed location
+//!
+//! Draws the world's overview image with the locations the DM pinned to it,
+//! positioned by percentage like map markers so they stay in place however
+//! the image is scaled. Players open it from the mini-map and only see the
+//! places their PC has visited or heard about; clicking a pin shows that
+//! location with how long the journey takes and offers to fast-travel
+//! there, and the location they are in is highlighted.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{WorldMapData, WorldMapPinData, WorldMapPositionData};
+use crate::application::services::KnownLocationSummary;
+use crate::infrastructure::spawn_task;
+use crate::presentation::components::creator::location_hierarchy::format_minutes;
+use crate::presentation::services::{use_location_service, use_observation_service};
+use crate::presentation::state::use_game_state;
+use wrldbldr_protocol::types::LocationTravelData;
+
+/// Props for the WorldMapCanvas component
+#[derive(Props, Clone, PartialEq)]
+pub struct WorldMapCanvasProps {
+    pub map: WorldMapData,
+    /// Location the viewer is in, drawn highlighted
+    #[props(default)]
+    pub current_location_id: Option<String>,
+    #[props(default)]
+    pub selected_location_id: Option<String>,
+    /// Called with the location ID of a clicked pin
+    pub on_select: EventHandler<String>,
+    /// When set, clicking the map reports the position instead (DM placing)
+    #[props(default)]
+    pub on_place: Option<EventHandler<WorldMapPositionData>>,
+}
+
+/// The overview image with its location pins
+#[component]
+pub fn WorldMapCanvas(props: WorldMapCanvasProps) -> Element {
+    let mut surface: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let placing = props.on_place.is_some();
+
+    let Some(map_url) = props.map.map_asset.clone() else {
+        return rsx! {
+            div {
+                class: "p-6 text-center text-gray-400 text-sm",
+                "No world map has been set for this world."
+            }
+        };
+    };
+
+    rsx! {
+        div {
+            class: if placing { "relative w-full cursor-crosshair" } else { "relative w-full" },
+            onmounted: move |e| surface.set(Some(e.data())),
+            onclick: move |e: MouseEvent| {
+                let Some(on_place) = props.on_place else {
+                    return;
+                };
+                let point = e.element_coordinates();
+                let Some(mounted) = surface.read().clone() else {
+                    return;
+                };
+                spawn_task(async move {
+                    if let Ok(rect) = mounted.get_client_rect().await {
+                        if rect.width() > 0.0 && rect.height() > 0.0 {
+                            let x = (point.x / rect.width() * 100.0) as f32;
+                            let y = (point.y / rect.height() * 100.0) as f32;
+                            on_place.call(WorldMapPositionData {
+                                x: x.clamp(0.0, 100.0),
+                                y: y.clamp(0.0, 100.0),
+                            });
+                        }
+                    }
+                });
+            },
+
+            img {
+                src: "{map_url}",
+                alt: "World map",
+                class: "block w-full h-auto rounded-lg select-none",
+                draggable: "false",
+            }
+
+            for pin in props.map.pins.iter().cloned() {
+                WorldMapPin {
+                    key: "{pin.location_id}",
+                    is_current: props.current_location_id.as_deref() == Some(pin.location_id.as_str()),
+                    is_selected: props.selected_location_id.as_deref() == Some(pin.location_id.as_str()),
+                    on_select: props.on_select,
+                    pin,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct WorldMapPinProps {
+    pin: WorldMapPinData,
+    is_current: bool,
+    is_selected: bool,
+    on_select: EventHandler<String>,
+}
+
+/// A single location pin with its name underneath
+#[component]
+fn WorldMapPin(props: WorldMapPinProps) -> Element {
+    let pin = props.pin.clone();
+    let style = format!(
+        "left: {}%; top: {}%; transform: translate(-50%, -50%);",
+        pin.position.x, pin.position.y
+    );
+    let color = if props.is_current {
+        "bg-blue-500"
+    } else {
+        "bg-amber-500"
+    };
+    let ring = if props.is_selected {
+        "ring-2 ring-white"
+    } else {
+        ""
+    };
+
+    rsx! {
+        button {
+            r#type: "button",
+            class: "absolute flex flex-col items-center gap-1 bg-transparent border-0 p-0 cursor-pointer",
+            style: "{style}",
+            aria_label: "Location: {pin.name}",
+            onclick: move |e| {
+                e.stop_propagation();
+                props.on_select.call(pin.location_id.clone());
+            },
+            span { class: "w-4 h-4 rounded-full border-2 border-white/80 shadow-lg {color} {ring}" }
+            span {
+                class: "px-1.5 py-0.5 bg-black/70 text-white text-xs rounded whitespace-nowrap",
+                "{pin.name}"
+            }
+        }
+    }
+}
+
+/// Props for the WorldMapModal component
+#[derive(Props, Clone, PartialEq)]
+pub struct WorldMapModalProps {
+    /// Location the PC is in
+    #[props(default)]
+    pub current_location_id: Option<String>,
+    /// The viewing PC; when set, only the places they know are shown
+    #[props(default)]
+    pub pc_id: Option<String>,
+    /// Called with a known location the PC should travel to
+    #[props(default)]
+    pub on_travel: Option<EventHandler<String>>,
+    pub on_close: EventHandler<()>,
+}
+
+/// World overview map for players, with details for the chosen pin
+#[component]
+pub fn WorldMapModal(props: WorldMapModalProps) -> Element {
+    let game_state = use_game_state();
+    let observation_service = use_observation_service();
+    let location_service = use_location_service();
+    let mut selected: Signal<Option<String>> = use_signal(|| None);
+    let mut known: Signal<Option<Vec<KnownLocationSummary>>> = use_signal(|| None);
+    let mut travel: Signal<Option<LocationTravelData>> = use_signal(|| None);
+    let current_location_id = props.current_location_id.clone();
+
+    {
+        let pc_id = props.pc_id.clone();
+        use_effect(move || {
+            let Some(pc_id) = pc_id.clone() else {
+                return;
+            };
+            let service = observation_service.clone();
+            spawn_task(async move {
+                match service.list_known_locations(&pc_id).await {
+                    Ok(found) => known.set(Some(found)),
+                    Err(e) => {
+                        tracing::warn!("Failed to load known locations: {}", e);
+                        known.set(Some(Vec::new()));
+                    }
+                }
+            });
+        });
+    }
+
+    let mut map = game_state.world_map.read().clone();
+    let knowledge = known.read().clone();
+    if props.pc_id.is_some() {
+        let ids: Vec<&str> = knowledge
+            .iter()
+            .flatten()
+            .map(|k| k.location_id.as_str())
+            .collect();
+        map.pins.retain(|p| ids.contains(&p.location_id.as_str()));
+    }
+    let world_name = game_state
+        .world
+        .read()
+        .as_ref()
+        .map(|w| w.world.name.clone())
+        .unwrap_or_default();
+    let selected_location = selected.read().as_ref().and_then(|id| {
+        game_state
+            .world
+            .read()
+            .as_ref()
+            .and_then(|w| w.get_location(id).cloned())
+    });
+    let selected_knowledge = selected.read().as_ref().and_then(|id| {
+        knowledge
+            .iter()
+            .flatten()
+            .find(|k| &k.location_id == id)
+            .cloned()
+    });
+
+    rsx! {
+        div {
+            class: "world-map-overlay fixed inset-0 bg-black/90 z-[1000] flex items-center justify-center p-4",
+            onclick: move |_| props.on_close.call(()),
+
+            div {
+                class: "world-map-container bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl w-full max-w-5xl max-h-[90vh] overflow-hidden flex flex-col shadow-2xl border border-blue-500/20",
+                onclick: move |e| e.stop_propagation(),
+
+                div {
+                    class: "p-4 border-b border-white/10 flex justify-between items-center",
+                    div {
+                        h2 { class: "text-xl font-bold text-white m-0", "{world_name}" }
+                        p { class: "text-gray-400 text-sm m-0 mt-1", "Click a place you know to read about it" }
+                    }
+                    button {
+                        class: "w-8 h-8 flex items-center justify-center bg-white/5 hover:bg-white/10 rounded-lg text-gray-400 hover:text-white transition-colors",
+                        onclick: move |_| props.on_close.call(()),
+                        "x"
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-auto p-4",
+                    WorldMapCanvas {
+                        map,
+                        current_location_id: props.current_location_id.clone(),
+                        selected_location_id: selected.read().clone(),
+                        on_select: move |id: String| {
+                            let current = selected.read().clone();
+                            travel.set(None);
+                            if current.as_deref() == Some(id.as_str()) {
+                                selected.set(None);
+                                return;
+                            }
+                            selected.set(Some(id.clone()));
+                            // How long the road there is, from where the PC stands
+                            if let Some(from) = current_location_id.clone().filter(|from| *from != id) {
+                                let service = location_service.clone();
+                                spawn_task(async move {
+                                    match service.get_travel_time(&from, &id).await {
+                                        Ok(data) => travel.set(Some(data)),
+                                        Err(e) => tracing::warn!("Failed to estimate travel time: {}", e),
+                                    }
+                                });
+                            }
+                        },
+                    }
+                }
+
+                if let Some(location) = selected_location {
+                    div {
+                        class: "p-4 border-t border-white/10",
+                        h3 {
+                            class: "m-0 text-white text-base font-semibold",
+                            "{location.name}"
+                            if props.current_location_id.as_deref() == Some(location.id.as_str()) {
+                                span { class: "ml-2 text-blue-400 text-xs font-normal", "You are here" }
+                            }
+                        }
+                        if let Some(k) = selected_knowledge.as_ref() {
+                            p {
+                                class: "mt-1 mb-0 text-gray-500 text-xs",
+                                if k.is_visited() { "Visited" } else { "Heard about" }
+                                if let Some(notes) = k.notes.as_ref() {
+                                    " · {notes}"
+                                }
+                            }
+                        }
+                        if !location.description.is_empty() {
+                            p { class: "mt-1 mb-0 text-gray-300 text-sm", "{location.description}" }
+                        }
+                        if let Some(data) = travel.read().as_ref().filter(|t| t.to_location_id == location.id) {
+                            p {
+                                class: "mt-1 mb-0 text-gray-400 text-xs",
+                                match data.minutes {
+                                    Some(minutes) => rsx! { "About {format_minutes(minutes)} away" },
+                                    None => rsx! { "No known road leads there" },
+                                }
+                            }
+                        }
+                        if let Some(on_travel) = props.on_travel {
+                            if props.current_location_id.as_deref() != Some(location.id.as_str()) {
+                                button {
+                                    class: "mt-3 px-4 py-2 bg-blue-600 text-white rounded-md text-sm hover:bg-blue-700",
+                                    onclick: {
+                                        let location_id = location.id.clone();
+                                        move |_| {
+                                            on_travel.call(location_id.clone());
+                                            props.on_close.call(());
+                                        }
+                                    },
+                                    "Travel here"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -52,6 +52,11 @@ pub fn handle_session_event(
 
             session_state.connection_status().set(presentation_status);
 
+            // A new socket has to rejoin the world before actions are accepted
+            if !matches!(state, ConnectionState::Connected) {
+                session_state.connection.joined.set(false);
+            }
+
             // Clear all state on disconnect or failure to prevent stale data
             if matches!(
                 state,
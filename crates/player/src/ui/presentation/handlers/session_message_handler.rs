@@ -4,7 +4,9 @@
 //! presentation state mutations. PlayerEvent is the application-layer
 //! representation of server messages, already translated from wire format.
 
-use crate::application::dto::SessionWorldSnapshot;
+use crate::application::dto::{
+    JourneyDecision, LockedWayData, SessionWorldSnapshot, TradeClosedReason, UnlockMethodData,
+};
 use crate::ports::outbound::player_events::{
     CharacterData, CharacterPosition, ConnectedUser, NpcPresenceData, PlayerEvent, SceneData,
 };
@@ -13,10 +15,17 @@ use crate::presentation::state::{
     approval_state::PendingChallengeOutcome,
     challenge_state::{ChallengePromptData, ChallengeResultData},
     game_state::RegionStagingStatus,
-    DialogueState, GameState, GenerationState, LoreState, PendingApproval, SessionState,
+    ControlledPc, DependencyStatus, DialogueState, GameState, GenerationState, LoreState,
+    ModelPull, NotificationKind, OverlayData, PendingApproval, SafetyAlert, SessionState,
+};
+use crate::presentation::utils::{
+    life_stage_label, typography_from_data, world_features_from_data, world_theme_from_data,
 };
 use dioxus::prelude::{ReadableExt, WritableExt};
 
+/// Longest dialogue excerpt shown in a system notification
+const ALERT_EXCERPT_CHARS: usize = 140;
+
 /// Handle an incoming `PlayerEvent` and update presentation state.
 pub fn handle_server_message(
     message: PlayerEvent,
@@ -40,6 +49,20 @@ pub fn handle_server_message(
                 true,
                 platform,
             );
+            dialogue_state.pending_action_id.set(Some(action_id));
+        }
+
+        PlayerEvent::ActionRetracted { action_id } => {
+            tracing::info!("Action retracted: {}", action_id);
+            if dialogue_state.pending_action_id.read().as_deref() == Some(action_id.as_str()) {
+                dialogue_state.pending_action_id.set(None);
+            }
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("Action {} retracted", action_id),
+                true,
+                platform,
+            );
         }
 
         PlayerEvent::SceneUpdate {
@@ -76,6 +99,18 @@ pub fn handle_server_message(
             choices,
             conversation_id,
         } => {
+            // Approved dialogue goes to the whole world; only alert the player
+            // whose conversation it belongs to
+            let is_own_conversation = conversation_id.is_some()
+                && conversation_id == dialogue_state.get_conversation_id();
+            if is_own_conversation {
+                session_state.notifications.alert(
+                    &format!("{} replied", speaker_name),
+                    &excerpt(&text, ALERT_EXCERPT_CHARS),
+                    platform,
+                );
+            }
+
             // Update conversation ID (may have changed or been assigned)
             dialogue_state.set_conversation_id(conversation_id);
             // Add to conversation log for DM view
@@ -119,6 +154,11 @@ pub fn handle_server_message(
             proposed_tools,
             challenge_suggestion,
             narrative_event_suggestion,
+            safety_warnings,
+            conversation_id,
+            source_action_id,
+            awaiting_player,
+            scene_id,
         } => {
             // PlayerEvent already contains application-layer types
             session_state.add_pending_approval(PendingApproval {
@@ -129,9 +169,36 @@ pub fn handle_server_message(
                 proposed_tools,
                 challenge_suggestion,
                 narrative_event_suggestion,
+                safety_warnings,
+                conversation_id,
+                source_action_id,
+                awaiting_player,
+                scene_id,
             });
         }
 
+        PlayerEvent::BulkApprovalApplied {
+            decided,
+            failed,
+            accepted,
+        } => {
+            tracing::info!(
+                decided = decided.len(),
+                failed = failed.len(),
+                accepted,
+                "Bulk approval decision applied"
+            );
+            for request_id in &decided {
+                session_state.remove_pending_approval(request_id);
+            }
+            if !failed.is_empty() {
+                session_state.error_message().set(Some(format!(
+                    "{} approval(s) could not be decided",
+                    failed.len()
+                )));
+            }
+        }
+
         PlayerEvent::ResponseApproved {
             npc_dialogue: _,
             executed_tools,
@@ -142,6 +209,10 @@ pub fn handle_server_message(
         }
 
         PlayerEvent::Error { code, message } => {
+            // Too late to take the action back; stop offering to
+            if code == "ACTION_ALREADY_PROCESSED" {
+                dialogue_state.pending_action_id.set(None);
+            }
             let error_msg = format!("Server error [{}]: {}", code, message);
             tracing::error!("{}", error_msg);
             session_state.error_message().set(Some(error_msg));
@@ -229,6 +300,80 @@ pub fn handle_server_message(
                 .set(retry_in_seconds);
         }
 
+        PlayerEvent::DependencyStatusChanged {
+            dependency,
+            healthy,
+            message,
+        } => {
+            if healthy {
+                tracing::info!("Dependency {} is healthy", dependency);
+            } else {
+                tracing::warn!("Dependency {} is unhealthy: {:?}", dependency, message);
+            }
+
+            // ComfyUI's banner in Creator mode reads its own state
+            if dependency == "comfyui" {
+                let state = if healthy { "connected" } else { "disconnected" };
+                session_state.comfyui_state().set(state.to_string());
+                session_state.comfyui_message().set(message.clone());
+                session_state.comfyui_retry_in_seconds().set(None);
+            }
+
+            session_state.set_dependency_status(DependencyStatus {
+                dependency,
+                healthy,
+                message,
+            });
+        }
+
+        PlayerEvent::UsageQuotaWarning {
+            world_id,
+            resource,
+            used,
+            quota,
+        } => {
+            tracing::warn!(
+                "World {} is over its daily {} quota: {} of {}",
+                world_id,
+                resource,
+                used,
+                quota
+            );
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!(
+                    "Daily {} quota exceeded: {} used of {}. Generation continues; adjust the quota in World Settings.",
+                    resource, used, quota
+                ),
+                true,
+                platform,
+            );
+        }
+
+        PlayerEvent::ModelPullProgress {
+            model,
+            status,
+            completed_bytes,
+            total_bytes,
+            done,
+            error,
+        } => {
+            if let Some(err) = error.as_ref() {
+                tracing::warn!("Model {} failed to download: {}", model, err);
+            } else if done {
+                tracing::info!("Model {} downloaded", model);
+            }
+
+            generation_state.model_pull_progress(ModelPull {
+                model,
+                status,
+                completed_bytes,
+                total_bytes,
+                done,
+                error,
+            });
+        }
+
         PlayerEvent::ChallengePrompt {
             challenge_id,
             challenge_name,
@@ -249,6 +394,11 @@ pub fn handle_server_message(
                 suggested_dice,
                 rule_system_hint,
             };
+            session_state.notifications.alert(
+                "Your turn to roll",
+                &format!("{} ({})", challenge.challenge_name, challenge.skill_name),
+                platform,
+            );
             session_state.set_active_challenge(challenge);
         }
 
@@ -548,6 +698,83 @@ pub fn handle_server_message(
             game_state.set_location_event(region_id, description);
         }
 
+        PlayerEvent::NoiseHeard {
+            region_id,
+            from_region_name,
+            description,
+            muffled,
+            ..
+        } => {
+            let heard = if muffled {
+                format!("You hear {}, muffled, from the {}", description, from_region_name)
+            } else {
+                format!("You hear {}, from the {}", description, from_region_name)
+            };
+            session_state.add_log_entry(
+                "Narrator".to_string(),
+                format!("[SOUND] {}", heard),
+                true,
+                platform,
+            );
+            game_state.set_location_event(region_id, heard);
+        }
+
+        PlayerEvent::NoiseReport {
+            region_name,
+            description,
+            heard_in,
+            ..
+        } => {
+            let reached = if heard_in.is_empty() {
+                "nobody next door heard it".to_string()
+            } else {
+                let regions = heard_in
+                    .iter()
+                    .map(|heard| {
+                        let listeners: Vec<&str> = heard
+                            .npc_names
+                            .iter()
+                            .chain(heard.pc_names.iter())
+                            .map(String::as_str)
+                            .collect();
+                        let muffled = if heard.muffled { " (muffled)" } else { "" };
+                        if listeners.is_empty() {
+                            format!("{}{}", heard.region_name, muffled)
+                        } else {
+                            format!("{}{}: {}", heard.region_name, muffled, listeners.join(", "))
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                format!("heard in {}", regions)
+            };
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("[NOISE] {} in the {} - {}", description, region_name, reached),
+                true,
+                platform,
+            );
+        }
+
+        PlayerEvent::ShowOverlay {
+            overlay_id,
+            asset,
+            caption,
+            duration_secs,
+        } => {
+            tracing::info!("DM showed overlay {}", overlay_id);
+            game_state.set_overlay(OverlayData {
+                overlay_id,
+                asset,
+                caption,
+                duration_secs,
+            });
+        }
+
+        PlayerEvent::OverlayDismissed { overlay_id } => {
+            game_state.clear_overlay(&overlay_id);
+        }
+
         PlayerEvent::NpcLocationShared {
             npc_id: _npc_id,
             npc_name,
@@ -588,6 +815,7 @@ pub fn handle_server_message(
 
             // Update selected PC in game state
             game_state.selected_pc_id.set(Some(pc_id.clone()));
+            session_state.notifications.load_for_pc(&pc_id, platform);
 
             // Clear NPC dispositions from previous PC (dispositions are PC-specific)
             game_state.clear_npc_dispositions();
@@ -606,47 +834,131 @@ pub fn handle_server_message(
             npcs_present,
             navigation,
             region_items,
+            crowds_present,
         } => {
             tracing::info!(
-                "Scene changed for PC {}: {} in {} ({} NPCs, {} regions, {} exits, {} items)",
+                "Scene changed for PC {}: {} in {} ({} NPCs, {} crowds, {} regions, {} exits, {} items)",
                 pc_id,
                 region.name,
                 region.location_name,
                 npcs_present.len(),
+                crowds_present.len(),
                 navigation.connected_regions.len(),
                 navigation.exits.len(),
                 region_items.len()
             );
 
-            // Clear any active dialogue when changing scenes
-            dialogue_state.clear();
-
             // PlayerEvent already contains application-layer types
             // Update game state with navigation data and region items
-            game_state.apply_scene_changed(
+            let shown = game_state.apply_scene_changed(
                 pc_id.clone(),
                 region.clone(),
                 npcs_present,
                 navigation,
                 region_items,
+                crowds_present,
+            );
+
+            if shown {
+                // Clear any active dialogue when changing scenes
+                dialogue_state.clear();
+                session_state.notifications.load_for_pc(&pc_id, platform);
+                session_state.add_log_entry(
+                    "System".to_string(),
+                    format!("Entered {} ({})", region.name, region.location_name),
+                    true,
+                    platform,
+                );
+            } else {
+                // Another controlled PC moved; its scene waits for the switch
+                let pc_name = game_state
+                    .controlled_pcs
+                    .read()
+                    .iter()
+                    .find(|pc| pc.id == pc_id)
+                    .map(|pc| pc.name.clone())
+                    .unwrap_or_else(|| "Another PC".to_string());
+                session_state.add_log_entry(
+                    "System".to_string(),
+                    format!("{} entered {} ({})", pc_name, region.name, region.location_name),
+                    true,
+                    platform,
+                );
+            }
+        }
+
+        PlayerEvent::MovementBlocked { pc_id, reason } => {
+            tracing::info!("Movement blocked for PC {}: {}", pc_id, reason);
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("Cannot proceed: {}", reason),
+                true,
+                platform,
             );
+        }
 
+        PlayerEvent::JourneyInterrupted { journey } => {
+            tracing::info!(
+                "Journey {} stopped after {} minutes",
+                journey.journey_id,
+                journey.elapsed_minutes
+            );
+            let what = journey
+                .encounter
+                .as_ref()
+                .map(|e| e.name.clone())
+                .unwrap_or_else(|| "something".to_string());
             session_state.add_log_entry(
                 "System".to_string(),
-                format!("Entered {} ({})", region.name, region.location_name),
+                format!(
+                    "{}'s journey to {} was interrupted: {}",
+                    journey.pc_name, journey.to_location_name, what
+                ),
                 true,
                 platform,
             );
+            game_state.add_stopped_journey(journey);
         }
 
-        PlayerEvent::MovementBlocked { pc_id, reason } => {
-            tracing::info!("Movement blocked for PC {}: {}", pc_id, reason);
+        PlayerEvent::JourneyResolved { journey, decision } => {
+            tracing::info!("Journey {} resolved: {:?}", journey.journey_id, decision);
+            let msg = match decision {
+                JourneyDecision::TurnBack => format!(
+                    "{} turned back to {}",
+                    journey.pc_name, journey.from_location_name
+                ),
+                _ => format!(
+                    "{} travels on toward {}",
+                    journey.pc_name, journey.to_location_name
+                ),
+            };
+            session_state.add_log_entry("System".to_string(), msg, true, platform);
+            game_state.remove_stopped_journey(&journey.journey_id);
+        }
+
+        PlayerEvent::ConnectionUnlocked { connection } => {
+            tracing::info!(
+                "{} unlocked the way from {} to {}",
+                connection.pc_id,
+                connection.from_id,
+                connection.to_name
+            );
+            let how = match connection.method {
+                UnlockMethodData::Challenge => "by",
+                _ => "with",
+            };
             session_state.add_log_entry(
                 "System".to_string(),
-                format!("Cannot proceed: {}", reason),
+                format!(
+                    "{} unlocked the way to {} {} {}",
+                    connection.pc_name, connection.to_name, how, connection.opened_with
+                ),
                 true,
                 platform,
             );
+            if let LockedWayData::Region { region_id } = &connection.to {
+                game_state.mark_region_connection_unlocked(&connection.from_id, region_id);
+            }
         }
 
         // =========================================================================
@@ -703,6 +1015,12 @@ pub fn handle_server_message(
             };
 
             session_state.add_log_entry("System".to_string(), message, true, platform);
+            session_state.notifications.push(
+                NotificationKind::TimeAdvanced,
+                format!("Time advanced to {}", time_display),
+                Some(reason),
+                platform,
+            );
         }
 
         PlayerEvent::TimeSuggestion {
@@ -789,6 +1107,92 @@ pub fn handle_server_message(
             // DM-only notification
         }
 
+        PlayerEvent::WorldTypographyUpdated { typography, .. } => {
+            tracing::info!(language = ?typography.language, "World typography updated");
+            game_state.set_typography(typography_from_data(typography));
+        }
+
+        PlayerEvent::WorldThemeUpdated { theme, .. } => {
+            tracing::info!(accent = ?theme.accent_color, "World theme updated");
+            game_state.set_world_theme(world_theme_from_data(theme));
+        }
+
+        PlayerEvent::WorldMapUpdated { map, .. } => {
+            tracing::info!(pins = map.pins.len(), "World map updated");
+            game_state.world_map.set(map);
+        }
+
+        PlayerEvent::WorldFeaturesUpdated { features, .. } => {
+            tracing::info!(?features, "World features updated");
+            game_state.set_world_features(world_features_from_data(features));
+        }
+
+        PlayerEvent::WorldScriptsUpdated { scripts, .. } => {
+            tracing::info!(count = scripts.len(), "World scripts updated");
+        }
+
+        PlayerEvent::CustomFieldsUpdated { fields, .. } => {
+            tracing::info!(count = fields.len(), "Custom fields updated");
+        }
+
+        PlayerEvent::ScriptNotice {
+            script,
+            message,
+            is_error,
+            ..
+        } => {
+            if is_error {
+                tracing::warn!(script = %script, "Script failed: {}", message);
+            }
+            let text = if is_error {
+                format!("Script failed: {}", message)
+            } else {
+                message
+            };
+            session_state.add_log_entry(format!("Script: {}", script), text, true, platform);
+        }
+
+        // =========================================================================
+        // Safety Tools
+        // =========================================================================
+        PlayerEvent::SafetySignalRaised {
+            level,
+            queue_paused,
+            rejected_request_ids,
+            ..
+        } => {
+            tracing::info!(
+                ?level,
+                queue_paused,
+                rejected = rejected_request_ids.len(),
+                "Safety signal raised"
+            );
+            // Rejected dialogue never reaches players; drop it from the DM's queue too
+            for request_id in &rejected_request_ids {
+                session_state.remove_pending_approval(request_id);
+            }
+            game_state.set_safety_alert(Some(SafetyAlert {
+                level,
+                queue_paused,
+                rejected_count: rejected_request_ids.len(),
+            }));
+        }
+
+        PlayerEvent::ActionQueuePaused { paused, .. } => {
+            tracing::info!("Action queue paused: {}", paused);
+            game_state.set_action_queue_paused(paused);
+            session_state.add_log_entry(
+                "System".to_string(),
+                if paused {
+                    "Play has been paused".to_string()
+                } else {
+                    "Play has resumed".to_string()
+                },
+                true,
+                platform,
+            );
+        }
+
         // =========================================================================
         // Queue Status (DM-only, can be ignored by Player view)
         // =========================================================================
@@ -1055,7 +1459,10 @@ pub fn handle_server_message(
             } else {
                 format!("Dropped {}", item_name)
             };
-            session_state.add_log_entry("System".to_string(), msg, true, platform);
+            session_state.add_log_entry("System".to_string(), msg.clone(), true, platform);
+            session_state
+                .notifications
+                .push(NotificationKind::ItemLost, msg, None, platform);
             game_state.trigger_inventory_refresh();
         }
 
@@ -1066,7 +1473,10 @@ pub fn handle_server_message(
         } => {
             tracing::info!("Item picked up for PC {}: {}", pc_id, item_name);
             let msg = format!("Picked up {}", item_name);
-            session_state.add_log_entry("System".to_string(), msg, true, platform);
+            session_state.add_log_entry("System".to_string(), msg.clone(), true, platform);
+            session_state
+                .notifications
+                .push(NotificationKind::ItemReceived, msg, None, platform);
             game_state.trigger_inventory_refresh();
             // Remove the item from visible region items
             game_state.remove_region_item(&item_id);
@@ -1077,6 +1487,71 @@ pub fn handle_server_message(
             game_state.trigger_inventory_refresh();
         }
 
+        PlayerEvent::TradeOffered { trade } => {
+            tracing::info!("Trade {} offered by {}", trade.trade_id, trade.from_pc_name);
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("{} offered a trade to {}", trade.from_pc_name, trade.to_pc_name),
+                true,
+                platform,
+            );
+            game_state.add_trade_offer(trade);
+        }
+
+        PlayerEvent::TradeCompleted { trade } => {
+            tracing::info!("Trade {} completed", trade.trade_id);
+            let msg = format!(
+                "{} and {} completed a trade",
+                trade.from_pc_name, trade.to_pc_name
+            );
+            session_state.add_log_entry("System".to_string(), msg.clone(), true, platform);
+            let involves_me = game_state
+                .selected_pc_id
+                .read()
+                .as_ref()
+                .is_some_and(|pc| *pc == trade.from_pc_id || *pc == trade.to_pc_id);
+            if involves_me {
+                session_state
+                    .notifications
+                    .push(NotificationKind::ItemReceived, msg, None, platform);
+            }
+            game_state.remove_trade_offer(&trade.trade_id);
+            game_state.trigger_inventory_refresh();
+        }
+
+        PlayerEvent::TradeClosed {
+            trade_id,
+            reason,
+            message,
+        } => {
+            tracing::info!("Trade {} closed: {:?}", trade_id, reason);
+            let what = match reason {
+                TradeClosedReason::Declined => "was declined",
+                TradeClosedReason::Cancelled => "was withdrawn",
+                TradeClosedReason::Expired => "expired",
+                TradeClosedReason::Failed | TradeClosedReason::Unknown => "fell through",
+            };
+            let mut msg = format!("Trade offer {}", what);
+            if let Some(detail) = message {
+                msg = format!("{}: {}", msg, detail);
+            }
+            session_state.add_log_entry("System".to_string(), msg, true, platform);
+            game_state.remove_trade_offer(&trade_id);
+        }
+
+        PlayerEvent::TutorialProgress { status } => {
+            if let Some(step) = &status.just_completed {
+                tracing::info!("Tutorial step '{}' completed", step.id);
+                session_state.add_log_entry(
+                    "Tutorial".to_string(),
+                    format!("Step complete: {}", step.title),
+                    true,
+                    platform,
+                );
+            }
+            game_state.set_tutorial_status(status);
+        }
+
         // =========================================================================
         // Character Stat Updates
         // =========================================================================
@@ -1116,6 +1591,12 @@ pub fn handle_server_message(
                 true,
                 platform,
             );
+            session_state.notifications.push(
+                NotificationKind::StatChanged,
+                format!("{}'s {} is now {}", character_name, stat_name, new_value),
+                Some(format!("{} ({})", change_str, source)),
+                platform,
+            );
 
             // Trigger inventory refresh which also covers character sheet stats
             game_state.trigger_inventory_refresh();
@@ -1504,6 +1985,13 @@ pub fn handle_server_message(
             );
         }
 
+        PlayerEvent::LocaleChanged {
+            locale,
+            supported_locales,
+        } => {
+            tracing::info!(locale = %locale, supported = ?supported_locales, "Server locale set");
+        }
+
         PlayerEvent::UserLeft { user_id } => {
             tracing::info!(user_id = %user_id, "User left world");
             session_state.remove_connected_user(&user_id);
@@ -1538,6 +2026,33 @@ pub fn handle_server_message(
             game_state.trigger_entity_refresh(&entity_changed);
         }
 
+        PlayerEvent::ControlledPcsChanged { active_pc_id, pcs } => {
+            let pcs: Vec<ControlledPc> = pcs.iter().filter_map(ControlledPc::from_json).collect();
+            let active_pc_id = active_pc_id.to_string();
+            tracing::info!(
+                active_pc_id = %active_pc_id,
+                pc_count = pcs.len(),
+                "Controlled PCs changed"
+            );
+            let switched = game_state.selected_pc_id.read().as_deref() != Some(active_pc_id.as_str());
+            let message = match pcs.iter().find(|pc| pc.id == active_pc_id) {
+                Some(active) if switched => {
+                    dialogue_state.clear();
+                    session_state.notifications.load_for_pc(&active_pc_id, platform);
+                    format!("Now playing as {}", active.name)
+                }
+                _ => format!(
+                    "Controlling {}",
+                    pcs.iter()
+                        .map(|pc| pc.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            };
+            session_state.add_log_entry("System".to_string(), message, true, platform);
+            game_state.apply_controlled_pcs(active_pc_id, pcs);
+        }
+
         PlayerEvent::SpectateTargetChanged { pc_id, pc_name } => {
             tracing::info!(
                 pc_id = %pc_id,
@@ -1553,6 +2068,267 @@ pub fn handle_server_message(
             // The spectate target change should trigger scene updates via SceneChanged messages
         }
 
+        // =========================================================================
+        // Progress Clock Events
+        // =========================================================================
+        PlayerEvent::ClockUpdated { clock } => {
+            tracing::debug!(
+                clock_id = %clock.id,
+                filled = clock.filled,
+                segments = clock.segments,
+                "Progress clock updated"
+            );
+            game_state.upsert_progress_clock(clock);
+        }
+
+        PlayerEvent::ClockRemoved { clock_id } => {
+            game_state.remove_progress_clock(&clock_id);
+        }
+
+        // =========================================================================
+        // Tag Events
+        // =========================================================================
+        PlayerEvent::EntityTagsUpdated {
+            entity_type,
+            entity_id,
+            tags,
+        } => {
+            tracing::debug!(%entity_type, %entity_id, count = tags.len(), "Entity tags updated");
+            game_state.set_entity_tags(&entity_id, tags);
+        }
+
+        PlayerEvent::WorldTagsUpdated { tags } => {
+            // A rename or delete can touch any entity, so cached tags are stale
+            game_state.entity_tags.write().clear();
+            game_state.set_world_tags(tags);
+        }
+
+        // =========================================================================
+        // Dice Events
+        // =========================================================================
+        PlayerEvent::DiceRolled { roll } => {
+            tracing::debug!(
+                roller = %roll.roller_name,
+                formula = %roll.formula,
+                total = roll.total,
+                "Dice rolled"
+            );
+            game_state.record_dice_roll(roll);
+        }
+
+        // =========================================================================
+        // Map Marker Events
+        // =========================================================================
+        PlayerEvent::MapMarkerUpdated { marker } => {
+            game_state.upsert_map_marker(marker);
+        }
+
+        PlayerEvent::MapMarkerRemoved { marker_id } => {
+            game_state.remove_map_marker(&marker_id);
+        }
+
+        // =========================================================================
+        // Region Hotspot Events
+        // =========================================================================
+        PlayerEvent::RegionHotspotsUpdated {
+            region_id,
+            hotspots,
+        } => {
+            game_state.set_region_hotspots(&region_id, hotspots);
+        }
+
+        PlayerEvent::RegionLightChanged {
+            region_id,
+            light_level,
+        } => {
+            game_state.set_region_light(&region_id, light_level);
+        }
+
+        PlayerEvent::RegionMapGenerated {
+            region_id,
+            map_asset,
+            error,
+        } => {
+            if let Some(err) = error {
+                tracing::warn!("Map generation for region {} failed: {}", region_id, err);
+                session_state.add_log_entry(
+                    "System".to_string(),
+                    format!("Region map generation failed: {}", err),
+                    true,
+                    platform,
+                );
+            } else if let Some(map_asset) = map_asset {
+                game_state.set_region_map(&region_id, map_asset);
+            }
+        }
+
+        PlayerEvent::RegionCrowdsChanged {
+            region_id,
+            crowds_present,
+        } => {
+            game_state.apply_region_crowds(&region_id, crowds_present);
+        }
+
+        PlayerEvent::NpcDraftUpdated { draft } => {
+            game_state.upsert_npc_draft(draft);
+        }
+
+        PlayerEvent::FlashbackChanged { flashback } => {
+            let message = match &flashback {
+                Some(f) => format!("Flashback: {}", f.title),
+                None => "Back to the present".to_string(),
+            };
+            session_state.add_log_entry("System".to_string(), message, true, platform);
+            game_state.set_flashback(flashback);
+        }
+
+        PlayerEvent::ParallelScenesChanged { scenes } => {
+            game_state.set_parallel_scenes(scenes);
+        }
+
+        PlayerEvent::SpotlightChanged { spotlight } => {
+            game_state.set_spotlight(spotlight);
+        }
+
+        PlayerEvent::PacingTimerChanged { timer } => {
+            game_state.set_pacing_timer(timer, platform.now_millis());
+        }
+
+        // =========================================================================
+        // Chronology Events
+        // =========================================================================
+        PlayerEvent::CharactersAged { progressions, .. } => {
+            for progression in progressions {
+                let message = match progression.previous_age {
+                    None => format!("{} was born", progression.name),
+                    Some(_) if progression.stage_changed => format!(
+                        "{} is now {} ({})",
+                        progression.name,
+                        progression.new_age,
+                        life_stage_label(progression.life_stage)
+                    ),
+                    Some(_) => format!("{} is now {}", progression.name, progression.new_age),
+                };
+                session_state.add_log_entry("System".to_string(), message, true, platform);
+            }
+        }
+
+        PlayerEvent::InjuriesHealed { injuries, .. } => {
+            for injury in injuries {
+                let message = format!("{} has healed", injury.name);
+                session_state.add_log_entry("System".to_string(), message, true, platform);
+            }
+        }
+
+        // =========================================================================
+        // Mortality Events
+        // =========================================================================
+        PlayerEvent::PcDied { death, .. } => {
+            let message = format!("{} has died: {}", death.name, death.cause);
+            session_state.add_log_entry("System".to_string(), message, true, platform);
+            if game_state.selected_pc_id.read().as_deref() == Some(death.pc_id.as_str()) {
+                game_state.pc_death.set(Some(death));
+            }
+        }
+
+        PlayerEvent::PcResurrected { pc_id, name, .. } => {
+            let message = format!("{} has returned from death", name);
+            session_state.add_log_entry("System".to_string(), message, true, platform);
+            let is_ours = game_state
+                .pc_death
+                .read()
+                .as_ref()
+                .is_some_and(|death| death.pc_id == pc_id);
+            if is_ours {
+                game_state.pc_death.set(None);
+            }
+        }
+
+        // =========================================================================
+        // Secret Events
+        // =========================================================================
+        PlayerEvent::PcSecretUpdated { secret } => {
+            game_state.upsert_pc_secret(secret);
+        }
+
+        PlayerEvent::PcSecretRemoved { secret_id, .. } => {
+            game_state.remove_pc_secret(&secret_id);
+        }
+
+        PlayerEvent::PcSecretRevealed {
+            pc_name, secret, ..
+        } => {
+            let message = format!("{}'s secret is out: {}", pc_name, secret.title);
+            session_state.add_log_entry("System".to_string(), message, true, platform);
+            let is_ours = game_state
+                .pc_secrets
+                .read()
+                .iter()
+                .any(|s| s.id == secret.id);
+            if is_ours {
+                game_state.upsert_pc_secret(secret);
+            }
+        }
+
+        // =========================================================================
+        // Mail Events
+        // =========================================================================
+        PlayerEvent::LetterUpdated { letter } => {
+            let just_arrived = letter.delivered
+                && !game_state
+                    .letters
+                    .read()
+                    .iter()
+                    .any(|l| l.id == letter.id && l.delivered);
+            let is_for_us =
+                game_state.selected_pc_id.read().as_deref() == Some(letter.recipient.id.as_str());
+            if just_arrived && is_for_us {
+                let sender = letter.sender.name.as_deref().unwrap_or("someone");
+                let message = format!("A letter has arrived from {}", sender);
+                session_state.add_log_entry("System".to_string(), message, true, platform);
+            }
+            game_state.upsert_letter(letter);
+        }
+
+        PlayerEvent::LetterRemoved { letter_id } => {
+            game_state.remove_letter(&letter_id);
+        }
+
+        // =========================================================================
+        // Broadsheet Events
+        // =========================================================================
+        PlayerEvent::BroadsheetDrafted { broadsheet } => {
+            game_state.upsert_broadsheet(broadsheet);
+        }
+
+        PlayerEvent::BroadsheetPublished { broadsheet } => {
+            let message = format!("The new broadsheet is out: {}", broadsheet.title);
+            session_state.add_log_entry("System".to_string(), message, true, platform);
+            game_state.upsert_broadsheet(broadsheet.clone());
+            game_state.show_broadsheet(broadsheet);
+        }
+
+        PlayerEvent::BroadsheetRemoved { broadsheet_id } => {
+            game_state.remove_broadsheet(&broadsheet_id);
+        }
+
+        // =========================================================================
+        // World Simulation Events
+        // =========================================================================
+        PlayerEvent::WorldSimulationProposed { simulation } => {
+            let message = format!(
+                "{} day(s) passed: {} change(s) in the world await your review",
+                simulation.days,
+                simulation.changes.len()
+            );
+            session_state.add_log_entry("System".to_string(), message, true, platform);
+            game_state.upsert_world_simulation(simulation);
+        }
+
+        PlayerEvent::WorldSimulationResolved { simulation_id, .. } => {
+            game_state.remove_world_simulation(&simulation_id);
+        }
+
         // =========================================================================
         // Lore Events
         // =========================================================================
@@ -1578,9 +2354,41 @@ pub fn handle_server_message(
                 discovered_at: chrono::Utc::now().to_rfc3339(),
                 notes: None,
             };
+            session_state.notifications.push(
+                NotificationKind::LoreDiscovered,
+                format!("Discovered: {}", lore.title),
+                Some(lore.summary.clone()).filter(|s| !s.is_empty()),
+                platform,
+            );
             lore_state.add_lore(lore, knowledge);
         }
 
+        PlayerEvent::KnowledgeShared {
+            from_pc_id,
+            from_pc_name,
+            to_pc_ids,
+            title,
+        } => {
+            tracing::info!(
+                "{} shared '{}' with {} PC(s)",
+                from_pc_name,
+                title,
+                to_pc_ids.len()
+            );
+            let my_pc = game_state.selected_pc_id.read().clone();
+            let msg = if to_pc_ids.is_empty() {
+                format!("{} shared \"{}\", but everyone already knew", from_pc_name, title)
+            } else if my_pc.as_deref() == Some(from_pc_id.as_str()) {
+                format!("You shared \"{}\" with the party", title)
+            } else {
+                format!("{} shared \"{}\" with the party", from_pc_name, title)
+            };
+            session_state.add_log_entry("System".to_string(), msg, true, platform);
+            if my_pc.is_some_and(|pc| to_pc_ids.contains(&pc)) {
+                game_state.trigger_observations_refresh();
+            }
+        }
+
         PlayerEvent::LoreRevoked {
             character_id,
             lore_id,
@@ -1626,6 +2434,14 @@ pub fn handle_server_message(
     }
 }
 
+/// First `max_chars` characters of `text`, with an ellipsis if cut
+fn excerpt(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((cut, _)) => format!("{}…", text[..cut].trim_end()),
+        None => text.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1642,12 +2458,23 @@ mod tests {
 
     struct TestPlatform {
         storage: Mutex<HashMap<String, String>>,
+        backgrounded: bool,
+        alerts: Mutex<Vec<(String, String)>>,
     }
 
     impl TestPlatform {
         fn new() -> Self {
             Self {
                 storage: Mutex::new(HashMap::new()),
+                backgrounded: false,
+                alerts: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn backgrounded() -> Self {
+            Self {
+                backgrounded: true,
+                ..Self::new()
             }
         }
     }
@@ -1699,6 +2526,23 @@ mod tests {
 
         fn set_page_title(&self, _title: &str) {}
 
+        fn preferred_locale(&self) -> String {
+            "en".to_string()
+        }
+
+        fn is_app_backgrounded(&self) -> bool {
+            self.backgrounded
+        }
+
+        fn request_notification_permission(&self) {}
+
+        fn show_system_notification(&self, title: &str, body: &str) {
+            self.alerts
+                .lock()
+                .expect("alerts lock")
+                .push((title.to_string(), body.to_string()));
+        }
+
         fn configure_engine_url(&self, _ws_url: &str) {}
 
         fn ws_to_http(&self, ws_url: &str) -> String {
@@ -1747,6 +2591,66 @@ mod tests {
         dom.rebuild(&mut muts);
     }
 
+    #[test]
+    fn dialogue_in_own_conversation_alerts_backgrounded_player() {
+        #[component]
+        fn App() -> Element {
+            let platform = Arc::new(TestPlatform::backgrounded());
+
+            let mut session_state = SessionState::new();
+            let mut game_state = GameState::new();
+            let mut dialogue_state = DialogueState::new();
+            let mut generation_state = GenerationState::new();
+            let mut lore_state = LoreState::new();
+
+            session_state
+                .notifications
+                .set_system_alerts(true, platform.as_ref());
+            dialogue_state.set_conversation_id(Some("conv-mine".to_string()));
+
+            for conversation_id in ["conv-other", "conv-mine"] {
+                handle_server_message(
+                    PlayerEvent::DialogueResponse {
+                        speaker_id: "npc-1".to_string(),
+                        speaker_name: "Mira".to_string(),
+                        text: "The ferry leaves at dawn.".to_string(),
+                        choices: vec![],
+                        conversation_id: Some(conversation_id.to_string()),
+                    },
+                    &mut session_state,
+                    &mut game_state,
+                    &mut dialogue_state,
+                    &mut generation_state,
+                    &mut lore_state,
+                    platform.as_ref(),
+                );
+                // Keep the player's own conversation current for the next message
+                dialogue_state.set_conversation_id(Some("conv-mine".to_string()));
+            }
+
+            let alerts = platform.alerts.lock().expect("alerts lock");
+            assert_eq!(
+                *alerts,
+                vec![(
+                    "Mira replied".to_string(),
+                    "The ferry leaves at dawn.".to_string()
+                )]
+            );
+
+            rsx! { div {} }
+        }
+
+        let mut dom = VirtualDom::new(App);
+        let mut muts = NoOpMutations;
+        dom.rebuild(&mut muts);
+    }
+
+    #[test]
+    fn excerpt_cuts_long_text_on_char_boundary() {
+        assert_eq!(excerpt("short", 10), "short");
+        assert_eq!(excerpt("héllo wörld", 6), "héllo…");
+    }
+
     #[test]
     fn handle_time_suggestion_adds_pending_suggestion() {
         #[component]
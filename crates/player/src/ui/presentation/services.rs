@@ -18,10 +18,15 @@ use dioxus::prelude::*;
 use std::sync::Arc;
 
 use crate::application::services::{
-    ActantialService, AssetService, ChallengeService, CharacterService, EventChainService,
-    GenerationService, LocationService, NarrativeEventService, ObservationService,
-    PlayerCharacterService, SettingsService, SkillService, StoryEventService, SuggestionService,
-    WorkflowService, WorldService,
+    ActantialService, AssetService, BroadsheetService, ChallengeService, CharacterService,
+    CharacterSheetService, ChronologyService, CompanionService, CrowdService, DialogueTreeService, DiceService,
+    DraftService, EconomyService, EventChainService, FactionService, FlashbackService, GalleryService, GenerationService,
+    InjuryService, LibraryService, LocationService, MailService, MentionService, ModelService,
+    MortalityService, NameGeneratorService, NarrativeEventService, NpcDraftService,
+    ObservationService, PacingService, ParallelSceneService, PlayerCharacterService, ProgressClockService, PropertyService,
+    SecretService, SettingsService, SimulationService, SkillService, SpotlightService, StoryEventService,
+    SuggestionService, TagService, TemplateService, TranscriptionService, WorkflowService,
+    WorldService,
 };
 use crate::infrastructure::messaging::{CommandBus, ConnectionKeepAlive};
 use crate::infrastructure::websocket::Connection;
@@ -52,6 +57,7 @@ pub struct Services<A: ApiPort> {
     // WebSocket-based services (non-generic)
     pub world: Arc<WorldService>,
     pub character: Arc<CharacterService>,
+    pub character_sheet: Arc<CharacterSheetService>,
     pub location: Arc<LocationService>,
     pub player_character: Arc<PlayerCharacterService>,
     pub challenge: Arc<ChallengeService>,
@@ -61,8 +67,37 @@ pub struct Services<A: ApiPort> {
     pub observation: Arc<ObservationService>,
     pub actantial: Arc<ActantialService>,
     pub skill: Arc<SkillService>,
+    pub progress_clock: Arc<ProgressClockService>,
+    pub tag: Arc<TagService>,
+    pub template: Arc<TemplateService>,
+    pub library: Arc<LibraryService>,
+    pub draft: Arc<DraftService>,
+    pub gallery: Arc<GalleryService>,
+    pub chronology: Arc<ChronologyService>,
+    pub crowd: Arc<CrowdService>,
+    pub faction: Arc<FactionService>,
+    pub dialogue_tree: Arc<DialogueTreeService>,
+    pub flashback: Arc<FlashbackService>,
+    pub parallel_scene: Arc<ParallelSceneService>,
+    pub spotlight: Arc<SpotlightService>,
+    pub pacing: Arc<PacingService>,
+    pub transcription: Arc<TranscriptionService>,
+    pub npc_draft: Arc<NpcDraftService>,
+    pub mention: Arc<MentionService>,
+    pub name_generator: Arc<NameGeneratorService>,
+    pub economy: Arc<EconomyService>,
+    pub property: Arc<PropertyService>,
+    pub companion: Arc<CompanionService>,
+    pub injury: Arc<InjuryService>,
+    pub mortality: Arc<MortalityService>,
+    pub secret: Arc<SecretService>,
+    pub mail: Arc<MailService>,
+    pub broadsheet: Arc<BroadsheetService>,
+    pub simulation: Arc<SimulationService>,
+    pub dice: Arc<DiceService>,
     pub generation: Arc<GenerationService>,
     pub suggestion: Arc<SuggestionService>,
+    pub model: Arc<ModelService>,
     // REST-based services (generic over ApiPort) - file uploads, large payloads, admin config
     pub workflow: Arc<WorkflowService<A>>,
     pub asset: Arc<AssetService<A>>,
@@ -90,6 +125,7 @@ impl<A: ApiPort + Clone> Services<A> {
             // WebSocket-based services use CommandBus
             world: Arc::new(WorldService::new(command_bus.clone(), raw_api)),
             character: Arc::new(CharacterService::new(command_bus.clone())),
+            character_sheet: Arc::new(CharacterSheetService::new(command_bus.clone())),
             location: Arc::new(LocationService::new(command_bus.clone())),
             player_character: Arc::new(PlayerCharacterService::new(command_bus.clone())),
             challenge: Arc::new(ChallengeService::new(command_bus.clone())),
@@ -99,8 +135,37 @@ impl<A: ApiPort + Clone> Services<A> {
             observation: Arc::new(ObservationService::new(command_bus.clone())),
             actantial: Arc::new(ActantialService::new(command_bus.clone())),
             skill: Arc::new(SkillService::new(command_bus.clone())),
+            progress_clock: Arc::new(ProgressClockService::new(command_bus.clone())),
+            tag: Arc::new(TagService::new(command_bus.clone())),
+            template: Arc::new(TemplateService::new(command_bus.clone())),
+            library: Arc::new(LibraryService::new(command_bus.clone())),
+            draft: Arc::new(DraftService::new(command_bus.clone())),
+            gallery: Arc::new(GalleryService::new(command_bus.clone())),
+            chronology: Arc::new(ChronologyService::new(command_bus.clone())),
+            crowd: Arc::new(CrowdService::new(command_bus.clone())),
+            faction: Arc::new(FactionService::new(command_bus.clone())),
+            dialogue_tree: Arc::new(DialogueTreeService::new(command_bus.clone())),
+            flashback: Arc::new(FlashbackService::new(command_bus.clone())),
+            parallel_scene: Arc::new(ParallelSceneService::new(command_bus.clone())),
+            spotlight: Arc::new(SpotlightService::new(command_bus.clone())),
+            pacing: Arc::new(PacingService::new(command_bus.clone())),
+            transcription: Arc::new(TranscriptionService::new(command_bus.clone())),
+            npc_draft: Arc::new(NpcDraftService::new(command_bus.clone())),
+            mention: Arc::new(MentionService::new(command_bus.clone())),
+            name_generator: Arc::new(NameGeneratorService::new(command_bus.clone())),
+            economy: Arc::new(EconomyService::new(command_bus.clone())),
+            property: Arc::new(PropertyService::new(command_bus.clone())),
+            companion: Arc::new(CompanionService::new(command_bus.clone())),
+            injury: Arc::new(InjuryService::new(command_bus.clone())),
+            mortality: Arc::new(MortalityService::new(command_bus.clone())),
+            secret: Arc::new(SecretService::new(command_bus.clone())),
+            mail: Arc::new(MailService::new(command_bus.clone())),
+            broadsheet: Arc::new(BroadsheetService::new(command_bus.clone())),
+            simulation: Arc::new(SimulationService::new(command_bus.clone())),
+            dice: Arc::new(DiceService::new(command_bus.clone())),
             generation: Arc::new(GenerationService::new(command_bus.clone())),
-            suggestion: Arc::new(SuggestionService::new(command_bus)),
+            suggestion: Arc::new(SuggestionService::new(command_bus.clone())),
+            model: Arc::new(ModelService::new(command_bus)),
             // REST-based services - file uploads, large payloads, admin config
             workflow: Arc::new(WorkflowService::new(api.clone())),
             asset: Arc::new(AssetService::new(api.clone())),
@@ -139,6 +204,12 @@ pub fn use_character_service() -> Arc<CharacterService> {
     services.character.clone()
 }
 
+/// Hook to access the CharacterSheetService from context
+pub fn use_character_sheet_service() -> Arc<CharacterSheetService> {
+    let services = use_context::<UiServices>();
+    services.character_sheet.clone()
+}
+
 /// Hook to access the LocationService from context
 pub fn use_location_service() -> Arc<LocationService> {
     let services = use_context::<UiServices>();
@@ -157,6 +228,174 @@ pub fn use_skill_service() -> Arc<SkillService> {
     services.skill.clone()
 }
 
+/// Hook to access the ProgressClockService from context
+pub fn use_progress_clock_service() -> Arc<ProgressClockService> {
+    let services = use_context::<UiServices>();
+    services.progress_clock.clone()
+}
+
+/// Hook to access the TagService from context
+pub fn use_tag_service() -> Arc<TagService> {
+    let services = use_context::<UiServices>();
+    services.tag.clone()
+}
+
+/// Hook to access the TemplateService from context
+pub fn use_template_service() -> Arc<TemplateService> {
+    let services = use_context::<UiServices>();
+    services.template.clone()
+}
+
+/// Hook to access the LibraryService from context
+pub fn use_library_service() -> Arc<LibraryService> {
+    let services = use_context::<UiServices>();
+    services.library.clone()
+}
+
+/// Hook to access the DraftService from context
+pub fn use_draft_service() -> Arc<DraftService> {
+    let services = use_context::<UiServices>();
+    services.draft.clone()
+}
+
+/// Hook to access the GalleryService from context
+pub fn use_gallery_service() -> Arc<GalleryService> {
+    let services = use_context::<UiServices>();
+    services.gallery.clone()
+}
+
+/// Hook to access the ChronologyService from context
+pub fn use_chronology_service() -> Arc<ChronologyService> {
+    let services = use_context::<UiServices>();
+    services.chronology.clone()
+}
+
+/// Hook to access the CrowdService from context
+pub fn use_crowd_service() -> Arc<CrowdService> {
+    let services = use_context::<UiServices>();
+    services.crowd.clone()
+}
+
+/// Hook to access the FactionService from context
+pub fn use_faction_service() -> Arc<FactionService> {
+    let services = use_context::<UiServices>();
+    services.faction.clone()
+}
+
+/// Hook to access the DialogueTreeService from context
+pub fn use_dialogue_tree_service() -> Arc<DialogueTreeService> {
+    let services = use_context::<UiServices>();
+    services.dialogue_tree.clone()
+}
+
+/// Hook to access the FlashbackService from context
+pub fn use_flashback_service() -> Arc<FlashbackService> {
+    let services = use_context::<UiServices>();
+    services.flashback.clone()
+}
+
+/// Hook to access the ParallelSceneService from context
+pub fn use_parallel_scene_service() -> Arc<ParallelSceneService> {
+    let services = use_context::<UiServices>();
+    services.parallel_scene.clone()
+}
+
+/// Hook to access the SpotlightService from context
+pub fn use_spotlight_service() -> Arc<SpotlightService> {
+    let services = use_context::<UiServices>();
+    services.spotlight.clone()
+}
+
+/// Hook to access the PacingService from context
+pub fn use_pacing_service() -> Arc<PacingService> {
+    let services = use_context::<UiServices>();
+    services.pacing.clone()
+}
+
+/// Hook to access the TranscriptionService from context
+pub fn use_transcription_service() -> Arc<TranscriptionService> {
+    let services = use_context::<UiServices>();
+    services.transcription.clone()
+}
+
+/// Hook to access the NpcDraftService from context
+pub fn use_npc_draft_service() -> Arc<NpcDraftService> {
+    let services = use_context::<UiServices>();
+    services.npc_draft.clone()
+}
+
+/// Hook to access the MentionService from context
+pub fn use_mention_service() -> Arc<MentionService> {
+    let services = use_context::<UiServices>();
+    services.mention.clone()
+}
+
+/// Hook to access the NameGeneratorService from context
+pub fn use_name_generator_service() -> Arc<NameGeneratorService> {
+    let services = use_context::<UiServices>();
+    services.name_generator.clone()
+}
+
+/// Hook to access the EconomyService from context
+pub fn use_economy_service() -> Arc<EconomyService> {
+    let services = use_context::<UiServices>();
+    services.economy.clone()
+}
+
+/// Hook to access the PropertyService from context
+pub fn use_property_service() -> Arc<PropertyService> {
+    let services = use_context::<UiServices>();
+    services.property.clone()
+}
+
+/// Hook to access the CompanionService from context
+pub fn use_companion_service() -> Arc<CompanionService> {
+    let services = use_context::<UiServices>();
+    services.companion.clone()
+}
+
+/// Hook to access the InjuryService from context
+pub fn use_injury_service() -> Arc<InjuryService> {
+    let services = use_context::<UiServices>();
+    services.injury.clone()
+}
+
+/// Hook to access the SecretService from context
+pub fn use_secret_service() -> Arc<SecretService> {
+    let services = use_context::<UiServices>();
+    services.secret.clone()
+}
+
+/// Hook to access the MailService from context
+pub fn use_mail_service() -> Arc<MailService> {
+    let services = use_context::<UiServices>();
+    services.mail.clone()
+}
+
+/// Hook to access the BroadsheetService from context
+pub fn use_broadsheet_service() -> Arc<BroadsheetService> {
+    let services = use_context::<UiServices>();
+    services.broadsheet.clone()
+}
+
+/// Hook to access the SimulationService from context
+pub fn use_simulation_service() -> Arc<SimulationService> {
+    let services = use_context::<UiServices>();
+    services.simulation.clone()
+}
+
+/// Hook to access the MortalityService from context
+pub fn use_mortality_service() -> Arc<MortalityService> {
+    let services = use_context::<UiServices>();
+    services.mortality.clone()
+}
+
+/// Hook to access the DiceService from context
+pub fn use_dice_service() -> Arc<DiceService> {
+    let services = use_context::<UiServices>();
+    services.dice.clone()
+}
+
 /// Hook to access the ChallengeService from context
 pub fn use_challenge_service() -> Arc<ChallengeService> {
     let services = use_context::<UiServices>();
@@ -193,6 +432,12 @@ pub fn use_suggestion_service() -> Arc<SuggestionService> {
     services.suggestion.clone()
 }
 
+/// Hook to access the ModelService from context
+pub fn use_model_service() -> Arc<ModelService> {
+    let services = use_context::<UiServices>();
+    services.model.clone()
+}
+
 /// Hook to access the EventChainService from context
 pub fn use_event_chain_service() -> Arc<EventChainService> {
     let services = use_context::<UiServices>();
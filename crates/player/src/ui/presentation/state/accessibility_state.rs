@@ -0,0 +1,49 @@
+//! Accessibility preferences
+//!
+//! Local, per-device preferences that change how the UI presents itself.
+//! These are stored in platform storage rather than on the Engine, since a
+//! player may want reduced motion on one device but not another.
+
+use dioxus::prelude::*;
+
+use crate::ports::outbound::storage_keys;
+use crate::Platform;
+
+/// Accessibility preferences for this device
+#[derive(Clone, Copy)]
+pub struct AccessibilityState {
+    /// Skip the typewriter effect and disable UI animations and transitions
+    pub reduced_motion: Signal<bool>,
+}
+
+impl AccessibilityState {
+    /// Create a new AccessibilityState with motion enabled
+    pub fn new() -> Self {
+        Self {
+            reduced_motion: Signal::new(false),
+        }
+    }
+
+    /// Load saved preferences from platform storage
+    pub fn load(&mut self, platform: &Platform) {
+        let reduced_motion = platform
+            .storage_load(storage_keys::REDUCED_MOTION)
+            .is_some_and(|value| value == "true");
+        self.reduced_motion.set(reduced_motion);
+    }
+
+    /// Toggle reduced motion and persist the choice
+    pub fn set_reduced_motion(&mut self, platform: &Platform, enabled: bool) {
+        self.reduced_motion.set(enabled);
+        platform.storage_save(
+            storage_keys::REDUCED_MOTION,
+            if enabled { "true" } else { "false" },
+        );
+    }
+}
+
+impl Default for AccessibilityState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
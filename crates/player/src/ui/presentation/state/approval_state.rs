@@ -38,6 +38,57 @@ pub struct PendingApproval {
     pub challenge_suggestion: Option<ChallengeSuggestionInfo>,
     /// Optional narrative event suggestion from the Engine
     pub narrative_event_suggestion: Option<NarrativeEventSuggestionInfo>,
+    /// Content safety warnings from the post-generation filter
+    pub safety_warnings: Vec<String>,
+    /// Conversation the response belongs to, for grouping
+    pub conversation_id: Option<String>,
+    /// Player action that raised the approval
+    pub source_action_id: Option<String>,
+    /// Whether a player is waiting on the response; the rest is ambient
+    pub awaiting_player: bool,
+    /// Parallel scene the response belongs to; `None` is the main table
+    pub scene_id: Option<String>,
+}
+
+/// How pending approvals are grouped in the decision queue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApprovalGroupKey {
+    /// Responses in the same conversation
+    Conversation(String),
+    /// Responses no player is waiting on
+    Ambient,
+    /// A response with nothing to group it by (keyed by request ID)
+    Single(String),
+}
+
+/// Pending approvals that can be decided together
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApprovalGroup {
+    pub key: ApprovalGroupKey,
+    pub approvals: Vec<PendingApproval>,
+}
+
+/// Group pending approvals by conversation, keeping arrival order.
+///
+/// Approvals outside any conversation are ambient when no player is waiting
+/// on them, and stand alone otherwise.
+pub fn group_pending_approvals(pending: &[PendingApproval]) -> Vec<ApprovalGroup> {
+    let mut groups: Vec<ApprovalGroup> = Vec::new();
+    for approval in pending {
+        let key = match (&approval.conversation_id, approval.awaiting_player) {
+            (Some(conversation_id), _) => ApprovalGroupKey::Conversation(conversation_id.clone()),
+            (None, false) => ApprovalGroupKey::Ambient,
+            (None, true) => ApprovalGroupKey::Single(approval.request_id.clone()),
+        };
+        match groups.iter_mut().find(|g| g.key == key) {
+            Some(group) => group.approvals.push(approval.clone()),
+            None => groups.push(ApprovalGroup {
+                key,
+                approvals: vec![approval.clone()],
+            }),
+        }
+    }
+    groups
 }
 
 /// A past approval decision for lightweight decision history in the DM view
@@ -52,6 +52,28 @@ impl ConnectionStatus {
     }
 }
 
+/// Health of one Engine dependency, as last reported by the Engine
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependencyStatus {
+    /// "neo4j", "ollama", "comfyui", or "disk"
+    pub dependency: String,
+    pub healthy: bool,
+    pub message: Option<String>,
+}
+
+impl DependencyStatus {
+    /// Human-readable dependency name
+    pub fn display_name(&self) -> &str {
+        match self.dependency.as_str() {
+            "neo4j" => "Database (Neo4j)",
+            "ollama" => "Language model (Ollama)",
+            "comfyui" => "Image generation (ComfyUI)",
+            "disk" => "Asset storage",
+            other => other,
+        }
+    }
+}
+
 /// Connection state for server and user information
 #[derive(Clone)]
 pub struct ConnectionState {
@@ -59,6 +81,8 @@ pub struct ConnectionState {
     pub connection_status: Signal<ConnectionStatus>,
     /// World ID after joining (all connections are world-scoped)
     pub world_id: Signal<Option<Uuid>>,
+    /// Whether the Engine has acknowledged JoinWorld on the current socket
+    pub joined: Signal<bool>,
     /// User ID (local identifier)
     pub user_id: Signal<Option<String>>,
     /// User role (DungeonMaster, Player, Spectator) - legacy
@@ -75,6 +99,8 @@ pub struct ConnectionState {
     pub comfyui_state: Signal<String>, // "connected", "degraded", "disconnected", "circuit_open"
     pub comfyui_message: Signal<Option<String>>,
     pub comfyui_retry_in_seconds: Signal<Option<u32>>,
+    /// Last reported status of each Engine dependency (DM only)
+    pub dependency_status: Signal<Vec<DependencyStatus>>,
 }
 
 impl ConnectionState {
@@ -83,6 +109,7 @@ impl ConnectionState {
         Self {
             connection_status: Signal::new(ConnectionStatus::Disconnected),
             world_id: Signal::new(None),
+            joined: Signal::new(false),
             user_id: Signal::new(None),
             user_role: Signal::new(None),
             world_role: Signal::new(None),
@@ -92,12 +119,14 @@ impl ConnectionState {
             comfyui_state: Signal::new("unknown".to_string()),
             comfyui_message: Signal::new(None),
             comfyui_retry_in_seconds: Signal::new(None),
+            dependency_status: Signal::new(Vec::new()),
         }
     }
 
     /// Set the connection to connecting state
     pub fn start_connecting(&mut self, server_url: &str) {
         self.connection_status.set(ConnectionStatus::Connecting);
+        self.joined.set(false);
         self.server_url.set(Some(server_url.to_string()));
         self.error_message.set(None);
     }
@@ -117,6 +146,7 @@ impl ConnectionState {
     ) {
         self.world_id.set(Some(world_id));
         self.world_role.set(Some(role));
+        self.joined.set(true);
         self.connected_users.set(connected_users);
         self.connection_status.set(ConnectionStatus::Connected);
         // Clear any previous error message on successful connection
@@ -155,17 +185,21 @@ impl ConnectionState {
     pub fn set_disconnected(&mut self) {
         self.connection_status.set(ConnectionStatus::Disconnected);
         self.world_id.set(None);
+        self.joined.set(false);
     }
 
     /// Set the connection to failed state with error
     pub fn set_failed(&mut self, error: String) {
         self.connection_status.set(ConnectionStatus::Failed);
+        self.joined.set(false);
         self.error_message.set(Some(error));
     }
 
     /// Set the connection to reconnecting state
     pub fn set_reconnecting(&mut self) {
         self.connection_status.set(ConnectionStatus::Reconnecting);
+        // The Engine forgets world membership when the socket drops
+        self.joined.set(false);
         // Clear previous error since we're attempting a new connection
         self.error_message.set(None);
     }
@@ -174,6 +208,7 @@ impl ConnectionState {
     pub fn clear(&mut self) {
         self.connection_status.set(ConnectionStatus::Disconnected);
         self.world_id.set(None);
+        self.joined.set(false);
         self.user_id.set(None);
         self.user_role.set(None);
         self.world_role.set(None);
@@ -184,6 +219,16 @@ impl ConnectionState {
         self.comfyui_state.set("unknown".to_string());
         self.comfyui_message.set(None);
         self.comfyui_retry_in_seconds.set(None);
+        self.dependency_status.set(Vec::new());
+    }
+
+    /// Record a dependency's latest status, replacing any earlier one
+    pub fn set_dependency_status(&mut self, status: DependencyStatus) {
+        let mut all = self.dependency_status.write();
+        match all.iter_mut().find(|s| s.dependency == status.dependency) {
+            Some(existing) => *existing = status,
+            None => all.push(status),
+        }
     }
 }
 
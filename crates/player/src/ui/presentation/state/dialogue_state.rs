@@ -8,6 +8,7 @@ use wrldbldr_domain::{parse_dialogue, DialogueMarker, ParsedDialogue};
 
 use crate::application::dto::DialogueChoice;
 use crate::infrastructure::spawn_task;
+use crate::presentation::state::use_accessibility_state;
 use crate::use_platform;
 
 /// A marker with its trigger position in the clean text
@@ -58,6 +59,8 @@ pub struct DialogueState {
     pub current_action: Signal<Option<String>>,
     /// Current conversation ID for tracking multi-turn conversations
     pub conversation_id: Signal<Option<String>>,
+    /// The player's last action while it can still be retracted
+    pub pending_action_id: Signal<Option<String>>,
 }
 
 impl DialogueState {
@@ -81,6 +84,7 @@ impl DialogueState {
             next_marker_index: Signal::new(0),
             current_action: Signal::new(None),
             conversation_id: Signal::new(None),
+            pending_action_id: Signal::new(None),
         }
     }
 
@@ -129,6 +133,7 @@ impl DialogueState {
         self.awaiting_input.set(false);
         self.custom_input.set(String::new());
         self.is_llm_processing.set(false);
+        self.pending_action_id.set(None);
 
         // Expression system
         self.current_mood.set(initial_mood);
@@ -244,6 +249,7 @@ impl DialogueState {
         self.next_marker_index.set(0);
         self.current_action.set(None);
         self.conversation_id.set(None);
+        self.pending_action_id.set(None);
     }
 
     /// Check if there's active dialogue to display
@@ -298,8 +304,11 @@ impl Default for DialogueState {
 /// Call this in a component to drive the typewriter animation.
 /// Updates expressions and actions as markers are encountered.
 /// Restarts automatically when new dialogue arrives (detected via dialogue_version).
+/// With reduced motion enabled, the full text is shown at once.
 pub fn use_typewriter_effect(dialogue_state: &mut DialogueState) {
     let platform = use_platform();
+    let reduced_motion = use_accessibility_state().reduced_motion;
+    let mut dialogue_for_skip = dialogue_state.clone();
 
     // Read the version to establish dependency - effect re-runs when this changes
     let dialogue_version = *dialogue_state.dialogue_version.read();
@@ -330,6 +339,11 @@ pub fn use_typewriter_effect(dialogue_state: &mut DialogueState) {
             return;
         }
 
+        if *reduced_motion.peek() {
+            dialogue_for_skip.skip_typewriter();
+            return;
+        }
+
         // Spawn the async typewriter animation
         let platform = platform.clone();
         spawn_task(async move {
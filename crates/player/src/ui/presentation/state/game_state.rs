@@ -12,11 +12,23 @@ const MAX_TIME_SUGGESTIONS: usize = 50;
 /// Maximum number of NPC mood entries to track (LRU eviction)
 const MAX_NPC_MOODS: usize = 200;
 
+/// Maximum number of recent dice tray rolls to keep
+const MAX_DICE_ROLLS: usize = 20;
+
 use crate::application::dto::{
-    CharacterData as SceneCharacterState, EntityChangedData, GameTime, InteractionData,
-    NavigationData, NpcDispositionData, NpcPresenceData, RegionData as SceneRegionInfo,
-    RegionItemData, SceneData as SceneSnapshot, SessionWorldSnapshot, SplitPartyLocation,
+    BroadsheetData, CharacterData as SceneCharacterState, CrowdPresenceData, DiceRollData, EntityChangedData,
+    FlashbackData,
+    ParallelSceneData,
+    SpotlightData,
+    PacingTimerData,
+    GameTime, HotspotData, InteractionData, JourneyData, LetterData, LightLevelData, MapMarkerData,
+    NavigationData, NpcDispositionData, NpcDraftData, NpcPresenceData, PcDeathData,
+    PcSecretData, ProgressClockData, RegionData as SceneRegionInfo, RegionItemData, SafetySignalLevelData,
+    SceneData as SceneSnapshot, SessionWorldSnapshot, SplitPartyLocation, TagUsageData,
+    TradeOfferData, TutorialStatusData, WorldMapData, WorldSimulationData,
 };
+use crate::infrastructure::offline::OfflineSnapshot;
+use wrldbldr_domain::{WorldFeatures, WorldTheme, WorldTypography};
 
 /// Approach event data (NPC approaching player)
 #[derive(Clone, Debug, PartialEq)]
@@ -40,6 +52,19 @@ pub struct LocationEventData {
     pub description: String,
 }
 
+/// DM handout shown full screen (map, letter, cutscene art)
+#[derive(Clone, Debug, PartialEq)]
+pub struct OverlayData {
+    /// Server-assigned ID, used by the DM to close it for everyone
+    pub overlay_id: String,
+    /// Image asset URL
+    pub asset: String,
+    /// Optional caption under the image
+    pub caption: Option<String>,
+    /// Seconds before the overlay closes itself (None = until dismissed)
+    pub duration_secs: Option<u32>,
+}
+
 /// View mode for Director - normal or viewing as a specific character
 #[derive(Clone, Debug, PartialEq, Default)]
 pub enum ViewMode {
@@ -156,6 +181,48 @@ pub struct TimeSuggestionData {
     pub period_change: Option<(String, String)>,
 }
 
+/// Latest anonymous safety signal, shown to the DM until dismissed
+#[derive(Clone, Debug, PartialEq)]
+pub struct SafetyAlert {
+    pub level: SafetySignalLevelData,
+    /// Whether the signal paused the action queue
+    pub queue_paused: bool,
+    /// Number of pending NPC responses that were auto-rejected
+    pub rejected_count: usize,
+}
+
+/// A PC this player controls (from ControlledPcsChanged)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ControlledPc {
+    pub id: String,
+    pub name: String,
+    pub portrait_asset: Option<String>,
+}
+
+impl ControlledPc {
+    /// Read a PC in the shape the server sends for `your_pc`
+    pub fn from_json(value: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            name: value.get("name")?.as_str()?.to_string(),
+            portrait_asset: value
+                .get("portrait_asset")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+        })
+    }
+}
+
+/// The last scene seen by one controlled PC, kept so switching PCs is instant
+#[derive(Clone, Debug, PartialEq)]
+pub struct PcSceneView {
+    pub region: SceneRegionInfo,
+    pub npcs_present: Vec<NpcPresenceData>,
+    pub navigation: NavigationData,
+    pub region_items: Vec<RegionItemData>,
+    pub crowds_present: Vec<CrowdPresenceData>,
+}
+
 /// Time mode for the world
 #[derive(Clone, Debug, PartialEq, Default)]
 pub enum TimeMode {
@@ -204,14 +271,22 @@ pub struct GameState {
     pub npcs_present: Signal<Vec<NpcPresenceData>>,
     /// Items visible in the current region (can be picked up)
     pub region_items: Signal<Vec<RegionItemData>>,
+    /// Crowds present in the current region
+    pub crowds_present: Signal<Vec<CrowdPresenceData>>,
     /// Currently selected PC ID
     pub selected_pc_id: Signal<Option<String>>,
+    /// PCs this player controls when playing more than one (empty otherwise)
+    pub controlled_pcs: Signal<Vec<ControlledPc>>,
+    /// Latest scene for each controlled PC, keyed by PC ID
+    pub pc_scenes: Signal<HashMap<String, PcSceneView>>,
     /// Current game time
     pub game_time: Signal<Option<GameTime>>,
     /// Active approach event (NPC approaching player)
     pub approach_event: Signal<Option<ApproachEventData>>,
     /// Active location event (location-wide event)
     pub location_event: Signal<Option<LocationEventData>>,
+    /// Active DM handout overlay
+    pub overlay: Signal<Option<OverlayData>>,
     /// Staging pending for player (waiting for DM approval)
     pub staging_pending: Signal<Option<StagingPendingData>>,
     /// Pending staging approval for DM
@@ -241,6 +316,59 @@ pub struct GameState {
     pub npc_moods: Signal<HashMap<String, String>>,
     /// Whether the backdrop is transitioning (fade effect during scene change)
     pub backdrop_transitioning: Signal<bool>,
+    /// Progress clocks this client can see (all clocks for DMs)
+    pub progress_clocks: Signal<Vec<ProgressClockData>>,
+    /// Recent dice tray rolls in the world, newest first
+    pub dice_rolls: Signal<Vec<DiceRollData>>,
+    /// DM markers pinned to maps this client has loaded (hidden ones for DMs only)
+    pub map_markers: Signal<Vec<MapMarkerData>>,
+    /// Every tag used in the world with its usage count (DM only)
+    pub world_tags: Signal<Vec<TagUsageData>>,
+    /// Tags on entities this client has loaded, keyed by entity ID (DM only)
+    pub entity_tags: Signal<HashMap<String, Vec<String>>>,
+    /// Open trade offers involving this player's PC (all of them for DMs)
+    pub trade_offers: Signal<Vec<TradeOfferData>>,
+    /// Journeys stopped for an encounter, waiting on the DM
+    pub stopped_journeys: Signal<Vec<JourneyData>>,
+    /// NPCs proposed for promotion, newest first (DM only)
+    pub npc_drafts: Signal<Vec<NpcDraftData>>,
+    /// This player's place in the world's tutorial (None outside tutorial worlds)
+    pub tutorial: Signal<Option<TutorialStatusData>>,
+    /// Whether new player actions are blocked (safety pause)
+    pub action_queue_paused: Signal<bool>,
+    /// Latest safety signal alert (DM only)
+    pub safety_alert: Signal<Option<SafetyAlert>>,
+    /// World text direction, language, and font
+    pub typography: Signal<WorldTypography>,
+    /// World accent color and backdrop frame
+    pub world_theme: Signal<WorldTheme>,
+    /// Experimental subsystems enabled for the world; UI for disabled ones is hidden
+    pub world_features: Signal<WorldFeatures>,
+    /// World overview map and the locations pinned to it
+    pub world_map: Signal<WorldMapData>,
+    /// The player's PC has died; set until they are resurrected or succeeded
+    pub pc_death: Signal<Option<PcDeathData>>,
+    /// Secrets of the PCs this player controls (only the DM and the PC's
+    /// player ever see these)
+    pub pc_secrets: Signal<Vec<PcSecretData>>,
+    /// Letters to and from the selected PC, or the whole world's mail for
+    /// the DM, newest first
+    pub letters: Signal<Vec<LetterData>>,
+    /// The world's broadsheet issues, newest first (drafts only for the DM)
+    pub broadsheets: Signal<Vec<BroadsheetData>>,
+    /// A newly published broadsheet the player has not closed yet
+    pub broadsheet_handout: Signal<Option<BroadsheetData>>,
+    /// Time-skip simulations awaiting the DM's review, oldest first
+    pub world_simulations: Signal<Vec<WorldSimulationData>>,
+    /// The flashback being played, if any; the game clock shows its date
+    pub flashback: Signal<Option<FlashbackData>>,
+    /// Scenes of a split party; empty while the party is together
+    pub parallel_scenes: Signal<Vec<ParallelSceneData>>,
+    /// The spotlight tracker; players get an empty rotation
+    pub spotlight: Signal<Option<SpotlightData>>,
+    /// The world's scene or turn timer, and when it arrived (epoch
+    /// milliseconds) so the countdown can run on between broadcasts
+    pub pacing_timer: Signal<Option<(PacingTimerData, u64)>>,
 }
 
 impl GameState {
@@ -255,10 +383,14 @@ impl GameState {
             navigation: Signal::new(None),
             npcs_present: Signal::new(Vec::new()),
             region_items: Signal::new(Vec::new()),
+            crowds_present: Signal::new(Vec::new()),
             selected_pc_id: Signal::new(None),
+            controlled_pcs: Signal::new(Vec::new()),
+            pc_scenes: Signal::new(HashMap::new()),
             game_time: Signal::new(None),
             approach_event: Signal::new(None),
             location_event: Signal::new(None),
+            overlay: Signal::new(None),
             staging_pending: Signal::new(None),
             pending_staging_approval: Signal::new(None),
             inventory_refresh_counter: Signal::new(0),
@@ -273,14 +405,90 @@ impl GameState {
             time_paused: Signal::new(true),
             npc_moods: Signal::new(HashMap::new()),
             backdrop_transitioning: Signal::new(false),
+            progress_clocks: Signal::new(Vec::new()),
+            dice_rolls: Signal::new(Vec::new()),
+            map_markers: Signal::new(Vec::new()),
+            world_tags: Signal::new(Vec::new()),
+            entity_tags: Signal::new(HashMap::new()),
+            trade_offers: Signal::new(Vec::new()),
+            stopped_journeys: Signal::new(Vec::new()),
+            npc_drafts: Signal::new(Vec::new()),
+            tutorial: Signal::new(None),
+            action_queue_paused: Signal::new(false),
+            safety_alert: Signal::new(None),
+            typography: Signal::new(WorldTypography::default()),
+            world_theme: Signal::new(WorldTheme::default()),
+            world_features: Signal::new(WorldFeatures::default()),
+            world_map: Signal::new(WorldMapData::default()),
+            pc_death: Signal::new(None),
+            pc_secrets: Signal::new(Vec::new()),
+            letters: Signal::new(Vec::new()),
+            broadsheets: Signal::new(Vec::new()),
+            broadsheet_handout: Signal::new(None),
+            world_simulations: Signal::new(Vec::new()),
+            flashback: Signal::new(None),
+            parallel_scenes: Signal::new(Vec::new()),
+            spotlight: Signal::new(None),
+            pacing_timer: Signal::new(None),
         }
     }
 
     /// Load a session world snapshot
     pub fn load_world(&mut self, snapshot: SessionWorldSnapshot) {
+        self.typography.set(snapshot.world.typography.clone());
+        self.world_theme.set(snapshot.world.theme.clone());
+        self.world_features.set(snapshot.world.features);
+        self.world_map.set(snapshot.world_map());
         self.world.set(Some(Arc::new(snapshot)));
     }
 
+    /// Capture the scene on screen so it can be shown again while offline
+    pub fn offline_snapshot(&self, saved_at_ms: u64) -> Option<OfflineSnapshot> {
+        let world = self.world.read().as_ref()?.as_ref().clone();
+        Some(OfflineSnapshot {
+            world,
+            scene: self.current_scene.read().clone(),
+            characters: self.scene_characters.read().clone(),
+            interactions: self.interactions.read().clone(),
+            pc_id: self.selected_pc_id.read().clone(),
+            region: self.current_region.read().clone(),
+            npcs_present: self.npcs_present.read().clone(),
+            navigation: self.navigation.read().clone(),
+            region_items: self.region_items.read().clone(),
+            crowds_present: self.crowds_present.read().clone(),
+            saved_at_ms,
+        })
+    }
+
+    /// Restore a scene saved by [`GameState::offline_snapshot`]
+    pub fn restore_offline_snapshot(&mut self, snapshot: OfflineSnapshot) {
+        self.current_scene.set(snapshot.scene);
+        self.scene_characters.set(snapshot.characters);
+        self.interactions.set(snapshot.interactions);
+        self.selected_pc_id.set(snapshot.pc_id);
+        self.current_region.set(snapshot.region);
+        self.npcs_present.set(snapshot.npcs_present);
+        self.navigation.set(snapshot.navigation);
+        self.region_items.set(snapshot.region_items);
+        self.crowds_present.set(snapshot.crowds_present);
+        self.load_world(snapshot.world);
+    }
+
+    /// Replace the world's typography (from WorldTypographyUpdated)
+    pub fn set_typography(&mut self, typography: WorldTypography) {
+        self.typography.set(typography);
+    }
+
+    /// Replace the world's theme (from WorldThemeUpdated)
+    pub fn set_world_theme(&mut self, theme: WorldTheme) {
+        self.world_theme.set(theme);
+    }
+
+    /// Replace the world's experimental feature switches (from WorldFeaturesUpdated)
+    pub fn set_world_features(&mut self, features: WorldFeatures) {
+        self.world_features.set(features);
+    }
+
     /// Update from ServerMessage::SceneUpdate
     pub fn apply_scene_update(
         &mut self,
@@ -294,6 +502,9 @@ impl GameState {
     }
 
     /// Update from ServerMessage::SceneChanged (navigation)
+    ///
+    /// When playing several PCs, a scene for a PC other than the selected one
+    /// is only stored for later. Returns whether the scene was shown.
     pub fn apply_scene_changed(
         &mut self,
         pc_id: String,
@@ -301,19 +512,73 @@ impl GameState {
         npcs_present: Vec<NpcPresenceData>,
         navigation: NavigationData,
         region_items: Vec<RegionItemData>,
-    ) {
+        crowds_present: Vec<CrowdPresenceData>,
+    ) -> bool {
+        let view = PcSceneView {
+            region,
+            npcs_present,
+            navigation,
+            region_items,
+            crowds_present,
+        };
+        self.pc_scenes.write().insert(pc_id.clone(), view.clone());
+
+        let in_background = self.controlled_pcs.read().len() > 1
+            && self
+                .selected_pc_id
+                .read()
+                .as_ref()
+                .is_some_and(|selected| *selected != pc_id);
+        if in_background {
+            return false;
+        }
+
+        self.show_pc_scene(pc_id, view);
+        true
+    }
+
+    /// Replace the controlled PCs (from ControlledPcsChanged) and show the
+    /// active PC's last known scene
+    pub fn apply_controlled_pcs(&mut self, active_pc_id: String, pcs: Vec<ControlledPc>) {
+        self.controlled_pcs.set(pcs);
+        let already_shown = self.selected_pc_id.read().as_deref() == Some(active_pc_id.as_str());
+        if already_shown {
+            return;
+        }
+        let stored = self.pc_scenes.read().get(&active_pc_id).cloned();
+        match stored {
+            Some(view) => self.show_pc_scene(active_pc_id, view),
+            None => self.selected_pc_id.set(Some(active_pc_id)),
+        }
+    }
+
+    fn show_pc_scene(&mut self, pc_id: String, view: PcSceneView) {
         // Trigger backdrop fade transition
         self.trigger_backdrop_transition();
 
         self.selected_pc_id.set(Some(pc_id));
-        self.current_region.set(Some(region));
-        self.npcs_present.set(npcs_present);
-        self.navigation.set(Some(navigation));
-        self.region_items.set(region_items);
+        self.current_region.set(Some(view.region));
+        self.npcs_present.set(view.npcs_present);
+        self.navigation.set(Some(view.navigation));
+        self.region_items.set(view.region_items);
+        self.crowds_present.set(view.crowds_present);
         // Clear NPC moods when changing scene - they'll be repopulated from staging
         self.clear_npc_moods();
     }
 
+    /// Update from ServerMessage::RegionCrowdsChanged; ignored unless it is
+    /// about the region on screen
+    pub fn apply_region_crowds(&mut self, region_id: &str, crowds_present: Vec<CrowdPresenceData>) {
+        let is_current = self
+            .current_region
+            .read()
+            .as_ref()
+            .is_some_and(|r| r.id == region_id);
+        if is_current {
+            self.crowds_present.set(crowds_present);
+        }
+    }
+
     /// Trigger a backdrop fade transition effect
     pub fn trigger_backdrop_transition(&mut self) {
         self.backdrop_transitioning.set(true);
@@ -375,6 +640,26 @@ impl GameState {
         self.location_event.set(None);
     }
 
+    /// Show a DM handout overlay, replacing any current one
+    pub fn set_overlay(&mut self, overlay: OverlayData) {
+        self.overlay.set(Some(overlay));
+    }
+
+    /// Close the overlay if it is still the one with this ID
+    ///
+    /// Ignores stale dismissals (timer or DM) for an overlay that has
+    /// already been replaced.
+    pub fn clear_overlay(&mut self, overlay_id: &str) {
+        let is_current = self
+            .overlay
+            .peek()
+            .as_ref()
+            .is_some_and(|o| o.overlay_id == overlay_id);
+        if is_current {
+            self.overlay.set(None);
+        }
+    }
+
     /// Set staging as pending (player waiting for DM approval)
     ///
     /// `started_at_ms` should be obtained from `PlatformPort::now_millis()`
@@ -542,6 +827,364 @@ impl GameState {
         self.time_paused.set(paused);
     }
 
+    /// Set whether the player action queue is paused
+    pub fn set_action_queue_paused(&mut self, paused: bool) {
+        self.action_queue_paused.set(paused);
+    }
+
+    /// Record a safety signal for the DM to see
+    pub fn set_safety_alert(&mut self, alert: Option<SafetyAlert>) {
+        self.safety_alert.set(alert);
+    }
+
+    /// Replace the known progress clocks (from a ListClocks response)
+    pub fn set_progress_clocks(&mut self, clocks: Vec<ProgressClockData>) {
+        self.progress_clocks.set(clocks);
+    }
+
+    /// Insert or replace a progress clock (from ClockUpdated)
+    pub fn upsert_progress_clock(&mut self, clock: ProgressClockData) {
+        let mut clocks = self.progress_clocks.write();
+        match clocks.iter_mut().find(|c| c.id == clock.id) {
+            Some(existing) => *existing = clock,
+            None => clocks.push(clock),
+        }
+    }
+
+    /// Remove a progress clock by ID (from ClockRemoved)
+    pub fn remove_progress_clock(&mut self, clock_id: &str) {
+        self.progress_clocks.write().retain(|c| c.id != clock_id);
+    }
+
+    /// Replace one PC's secrets (from a ListSecrets response)
+    pub fn set_pc_secrets(&mut self, pc_id: &str, secrets: Vec<PcSecretData>) {
+        let mut all = self.pc_secrets.write();
+        all.retain(|s| s.pc_id != pc_id);
+        all.extend(secrets);
+    }
+
+    /// Insert or replace a secret (from PcSecretUpdated or PcSecretRevealed)
+    pub fn upsert_pc_secret(&mut self, secret: PcSecretData) {
+        let mut secrets = self.pc_secrets.write();
+        match secrets.iter_mut().find(|s| s.id == secret.id) {
+            Some(existing) => *existing = secret,
+            None => secrets.push(secret),
+        }
+    }
+
+    /// Remove a secret by ID (from PcSecretRemoved)
+    pub fn remove_pc_secret(&mut self, secret_id: &str) {
+        self.pc_secrets.write().retain(|s| s.id != secret_id);
+    }
+
+    /// Replace the loaded letters (a PC's mailbox, or the world's mail)
+    pub fn set_letters(&mut self, letters: Vec<LetterData>) {
+        self.letters.set(letters);
+    }
+
+    /// Insert or replace a letter (from LetterUpdated); new ones go first
+    pub fn upsert_letter(&mut self, letter: LetterData) {
+        let mut letters = self.letters.write();
+        match letters.iter_mut().find(|l| l.id == letter.id) {
+            Some(existing) => *existing = letter,
+            None => letters.insert(0, letter),
+        }
+    }
+
+    /// Remove a letter by ID (from LetterRemoved)
+    pub fn remove_letter(&mut self, letter_id: &str) {
+        self.letters.write().retain(|l| l.id != letter_id);
+    }
+
+    /// Replace the loaded broadsheet issues
+    pub fn set_broadsheets(&mut self, broadsheets: Vec<BroadsheetData>) {
+        self.broadsheets.set(broadsheets);
+    }
+
+    /// Insert or replace an issue (from BroadsheetDrafted/Published); new
+    /// ones go first
+    pub fn upsert_broadsheet(&mut self, broadsheet: BroadsheetData) {
+        let mut broadsheets = self.broadsheets.write();
+        match broadsheets.iter_mut().find(|b| b.id == broadsheet.id) {
+            Some(existing) => *existing = broadsheet,
+            None => broadsheets.insert(0, broadsheet),
+        }
+    }
+
+    /// Remove an issue by ID (from BroadsheetRemoved)
+    pub fn remove_broadsheet(&mut self, broadsheet_id: &str) {
+        self.broadsheets.write().retain(|b| b.id != broadsheet_id);
+        if self
+            .broadsheet_handout
+            .read()
+            .as_ref()
+            .is_some_and(|b| b.id == broadsheet_id)
+        {
+            self.broadsheet_handout.set(None);
+        }
+    }
+
+    /// Hand a published issue to the player (from BroadsheetPublished)
+    pub fn show_broadsheet(&mut self, broadsheet: BroadsheetData) {
+        self.broadsheet_handout.set(Some(broadsheet));
+    }
+
+    /// Close the broadsheet handout
+    pub fn clear_broadsheet_handout(&mut self) {
+        self.broadsheet_handout.set(None);
+    }
+
+    /// Replace the simulations awaiting review
+    pub fn set_world_simulations(&mut self, simulations: Vec<WorldSimulationData>) {
+        self.world_simulations.set(simulations);
+    }
+
+    /// Insert or replace a simulation (from WorldSimulationProposed); new
+    /// ones go last
+    pub fn upsert_world_simulation(&mut self, simulation: WorldSimulationData) {
+        let mut simulations = self.world_simulations.write();
+        match simulations.iter_mut().find(|s| s.id == simulation.id) {
+            Some(existing) => *existing = simulation,
+            None => simulations.push(simulation),
+        }
+    }
+
+    /// Remove a simulation by ID (from WorldSimulationResolved)
+    pub fn remove_world_simulation(&mut self, simulation_id: &str) {
+        self.world_simulations
+            .write()
+            .retain(|s| s.id != simulation_id);
+    }
+
+    /// Set or clear the running flashback (from FlashbackChanged or a
+    /// GetFlashback response)
+    pub fn set_flashback(&mut self, flashback: Option<FlashbackData>) {
+        self.flashback.set(flashback);
+    }
+
+    /// Replace the split party's scenes (from ParallelScenesChanged or a
+    /// ListParallelScenes response)
+    pub fn set_parallel_scenes(&mut self, scenes: Vec<ParallelSceneData>) {
+        self.parallel_scenes.set(scenes);
+    }
+
+    /// The parallel scene a PC is in; `None` is the main table
+    pub fn parallel_scene_of(&self, pc_id: &str) -> Option<ParallelSceneData> {
+        self.parallel_scenes
+            .read()
+            .iter()
+            .find(|scene| scene.pc_ids.iter().any(|id| id == pc_id))
+            .cloned()
+    }
+
+    /// Whether the DM is running a scene other than this PC's
+    pub fn dm_is_elsewhere(&self, pc_id: &str) -> bool {
+        let scenes = self.parallel_scenes.read();
+        if scenes.is_empty() {
+            return false;
+        }
+        let focused = scenes.iter().find(|scene| scene.is_focused).map(|s| &s.id);
+        let mine = scenes
+            .iter()
+            .find(|scene| scene.pc_ids.iter().any(|id| id == pc_id))
+            .map(|s| &s.id);
+        focused != mine
+    }
+
+    /// Replace the spotlight tracker (from SpotlightChanged or a
+    /// GetSpotlight response)
+    pub fn set_spotlight(&mut self, spotlight: SpotlightData) {
+        self.spotlight.set(Some(spotlight));
+    }
+
+    /// Replace the pacing timer (from PacingTimerChanged or a GetPacingTimer
+    /// response). `received_at_ms` should come from
+    /// `PlatformPort::now_millis()`.
+    pub fn set_pacing_timer(&mut self, timer: Option<PacingTimerData>, received_at_ms: u64) {
+        self.pacing_timer.set(timer.map(|timer| (timer, received_at_ms)));
+    }
+
+    /// Whether the DM has turned the spotlight to this PC
+    pub fn has_spotlight(&self, pc_id: &str) -> bool {
+        self.spotlight
+            .read()
+            .as_ref()
+            .is_some_and(|s| s.settings.enabled && s.focus_pc_id.as_deref() == Some(pc_id))
+    }
+
+    /// Replace the markers pinned to one map (from a ListMapMarkers response)
+    pub fn set_map_markers(
+        &mut self,
+        location_id: &str,
+        region_id: Option<&str>,
+        markers: Vec<MapMarkerData>,
+    ) {
+        let mut all = self.map_markers.write();
+        all.retain(|m| m.pin.location_id != location_id || m.pin.region_id.as_deref() != region_id);
+        all.extend(markers);
+    }
+
+    /// Insert or replace a pinned marker (from MapMarkerUpdated)
+    pub fn upsert_map_marker(&mut self, marker: MapMarkerData) {
+        let mut markers = self.map_markers.write();
+        match markers.iter_mut().find(|m| m.id == marker.id) {
+            Some(existing) => *existing = marker,
+            None => markers.push(marker),
+        }
+    }
+
+    /// Remove a pinned marker by ID (from MapMarkerRemoved)
+    pub fn remove_map_marker(&mut self, marker_id: &str) {
+        self.map_markers.write().retain(|m| m.id != marker_id);
+    }
+
+    /// Replace the world's tag list (from ListWorldTags or WorldTagsUpdated)
+    pub fn set_world_tags(&mut self, tags: Vec<TagUsageData>) {
+        self.world_tags.set(tags);
+    }
+
+    /// Record an entity's current tags (from GetEntityTags or EntityTagsUpdated)
+    pub fn set_entity_tags(&mut self, entity_id: &str, tags: Vec<String>) {
+        self.entity_tags.write().insert(entity_id.to_string(), tags);
+    }
+
+    /// Track a newly opened trade offer (from TradeOffered)
+    pub fn add_trade_offer(&mut self, trade: TradeOfferData) {
+        let mut offers = self.trade_offers.write();
+        offers.retain(|t| t.trade_id != trade.trade_id);
+        offers.push(trade);
+    }
+
+    /// Forget a trade offer once it completes or closes
+    pub fn remove_trade_offer(&mut self, trade_id: &str) {
+        self.trade_offers.write().retain(|t| t.trade_id != trade_id);
+    }
+
+    /// Track a journey stopped for an encounter (from JourneyInterrupted)
+    pub fn add_stopped_journey(&mut self, journey: JourneyData) {
+        let mut journeys = self.stopped_journeys.write();
+        journeys.retain(|j| j.journey_id != journey.journey_id);
+        journeys.push(journey);
+    }
+
+    /// Show a way out of the current region as open once someone unlocks it
+    pub fn mark_region_connection_unlocked(&mut self, from_region_id: &str, to_region_id: &str) {
+        let here = self
+            .current_region
+            .read()
+            .as_ref()
+            .is_some_and(|r| r.id == from_region_id);
+        if !here {
+            return;
+        }
+        if let Some(navigation) = self.navigation.write().as_mut() {
+            for target in navigation
+                .connected_regions
+                .iter_mut()
+                .filter(|t| t.region_id == to_region_id)
+            {
+                target.is_locked = false;
+                target.lock_description = None;
+            }
+        }
+    }
+
+    /// Forget a stopped journey once the DM settles it
+    pub fn remove_stopped_journey(&mut self, journey_id: &str) {
+        self.stopped_journeys
+            .write()
+            .retain(|j| j.journey_id != journey_id);
+    }
+
+    /// Track a new or changed NPC draft (from NpcDraftUpdated or a draft request)
+    pub fn upsert_npc_draft(&mut self, draft: NpcDraftData) {
+        let mut drafts = self.npc_drafts.write();
+        match drafts.iter_mut().find(|d| d.id == draft.id) {
+            Some(existing) => *existing = draft,
+            None => drafts.insert(0, draft),
+        }
+    }
+
+    /// Forget an NPC draft once it is approved or rejected
+    pub fn remove_npc_draft(&mut self, draft_id: &str) {
+        self.npc_drafts.write().retain(|d| d.id != draft_id);
+    }
+
+    /// Update tutorial progress (from TutorialProgress or GetTutorialStatus)
+    ///
+    /// Statuses for a PC other than the selected one are ignored.
+    pub fn set_tutorial_status(&mut self, status: TutorialStatusData) {
+        let is_mine = self
+            .selected_pc_id
+            .peek()
+            .as_ref()
+            .is_some_and(|pc| *pc == status.pc_id);
+        if is_mine {
+            self.tutorial.set(Some(status));
+        }
+    }
+
+    /// Hide the tutorial guide
+    pub fn dismiss_tutorial(&mut self) {
+        self.tutorial.set(None);
+    }
+
+    /// Replace the current region's hotspots (from RegionHotspotsUpdated)
+    ///
+    /// Updates for other regions are ignored; they arrive with the next
+    /// SceneChanged.
+    pub fn set_region_hotspots(&mut self, region_id: &str, hotspots: Vec<HotspotData>) {
+        let is_current = self
+            .current_region
+            .read()
+            .as_ref()
+            .is_some_and(|r| r.id == region_id);
+        if is_current {
+            if let Some(region) = self.current_region.write().as_mut() {
+                region.hotspots = hotspots;
+            }
+        }
+    }
+
+    /// Update the current region's light (from RegionLightChanged)
+    ///
+    /// Other regions pick up their light with the next SceneChanged.
+    pub fn set_region_light(&mut self, region_id: &str, light_level: LightLevelData) {
+        let is_current = self
+            .current_region
+            .read()
+            .as_ref()
+            .is_some_and(|r| r.id == region_id);
+        if is_current {
+            if let Some(region) = self.current_region.write().as_mut() {
+                region.light_level = Some(light_level);
+            }
+        }
+    }
+
+    /// Set the current region's map (from RegionMapGenerated)
+    ///
+    /// Maps for other regions arrive with the next SceneChanged.
+    pub fn set_region_map(&mut self, region_id: &str, map_asset: String) {
+        let is_current = self
+            .current_region
+            .read()
+            .as_ref()
+            .is_some_and(|r| r.id == region_id);
+        if is_current {
+            if let Some(region) = self.current_region.write().as_mut() {
+                region.map_asset = Some(map_asset);
+            }
+        }
+    }
+
+    /// Record a dice tray roll (ours from the response, others' from DiceRolled)
+    pub fn record_dice_roll(&mut self, roll: DiceRollData) {
+        let mut rolls = self.dice_rolls.write();
+        rolls.insert(0, roll);
+        rolls.truncate(MAX_DICE_ROLLS);
+    }
+
     /// Trigger appropriate refresh based on entity change notification
     pub fn trigger_entity_refresh(&mut self, entity_changed: &EntityChangedData) {
         match entity_changed.entity_type.as_str() {
@@ -642,25 +1285,54 @@ impl GameState {
         self.navigation.set(None);
         self.npcs_present.set(Vec::new());
         self.region_items.set(Vec::new());
+        self.crowds_present.set(Vec::new());
         self.game_time.set(None);
         self.approach_event.set(None);
         self.location_event.set(None);
+        self.overlay.set(None);
         self.staging_pending.set(None);
         self.pending_staging_approval.set(None);
         self.split_party_locations.set(Vec::new());
         self.view_mode.set(ViewMode::Director);
         self.selected_pc_id.set(None);
+        self.controlled_pcs.set(Vec::new());
+        self.pc_scenes.write().clear();
         self.npc_dispositions.set(Vec::new());
         self.npc_moods.write().clear();
         self.region_staging_statuses.write().clear();
         self.pending_time_suggestions.write().clear();
         self.time_mode.set(TimeMode::default());
         self.time_paused.set(true);
+        self.progress_clocks.set(Vec::new());
+        self.dice_rolls.set(Vec::new());
+        self.map_markers.set(Vec::new());
+        self.world_tags.set(Vec::new());
+        self.entity_tags.write().clear();
+        self.trade_offers.set(Vec::new());
+        self.stopped_journeys.set(Vec::new());
+        self.npc_drafts.set(Vec::new());
+        self.tutorial.set(None);
+        self.action_queue_paused.set(false);
+        self.safety_alert.set(None);
+        self.pc_death.set(None);
+        self.pc_secrets.set(Vec::new());
+        self.letters.set(Vec::new());
+        self.broadsheets.set(Vec::new());
+        self.broadsheet_handout.set(None);
+        self.world_simulations.set(Vec::new());
+        self.flashback.set(None);
+        self.parallel_scenes.set(Vec::new());
+        self.spotlight.set(None);
+        self.pacing_timer.set(None);
     }
 
     /// Clear all state
     pub fn clear(&mut self) {
         self.world.set(None);
+        self.typography.set(WorldTypography::default());
+        self.world_theme.set(WorldTheme::default());
+        self.world_features.set(WorldFeatures::default());
+        self.world_map.set(WorldMapData::default());
         self.clear_scene();
     }
 }
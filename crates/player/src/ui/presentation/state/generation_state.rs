@@ -1,7 +1,8 @@
 //! Generation State - Track asset generation queue and status
 //!
-//! Manages the state of ComfyUI asset generation batches and LLM suggestions,
-//! including queue tracking, progress updates, and ready results.
+//! Manages the state of ComfyUI asset generation batches, LLM suggestions,
+//! and LLM model downloads, including queue tracking, progress updates, and
+//! ready results.
 
 use dioxus::prelude::*;
 
@@ -42,6 +43,28 @@ pub struct GenerationBatch {
     pub is_read: bool,
 }
 
+/// An LLM model download on the Engine
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelPull {
+    pub model: String,
+    /// Latest stage reported by the Engine (e.g. "pulling manifest")
+    pub status: String,
+    pub completed_bytes: Option<u64>,
+    pub total_bytes: Option<u64>,
+    pub done: bool,
+    pub error: Option<String>,
+}
+
+impl ModelPull {
+    /// Download progress as a percentage, when the size is known
+    pub fn percent(&self) -> Option<u8> {
+        match (self.completed_bytes, self.total_bytes) {
+            (Some(done), Some(total)) if total > 0 => Some((done * 100 / total).min(100) as u8),
+            _ => None,
+        }
+    }
+}
+
 /// A suggestion task in the queue (for text suggestions)
 #[derive(Debug, Clone, PartialEq)]
 pub struct SuggestionTask {
@@ -70,6 +93,8 @@ pub struct GenerationState {
     has_ready_batches: Signal<bool>,
     /// Whether there are suggestions ready for selection
     has_ready_suggestions: Signal<bool>,
+    /// LLM model downloads, most recent last
+    model_pulls: Signal<Vec<ModelPull>>,
 }
 
 impl GenerationState {
@@ -80,6 +105,7 @@ impl GenerationState {
             suggestions: Signal::new(Vec::new()),
             has_ready_batches: Signal::new(false),
             has_ready_suggestions: Signal::new(false),
+            model_pulls: Signal::new(Vec::new()),
         }
     }
 
@@ -380,6 +406,20 @@ impl GenerationState {
         }
     }
 
+    /// Record a model download update, replacing the model's earlier one
+    pub fn model_pull_progress(&mut self, pull: ModelPull) {
+        let mut pulls = self.model_pulls.write();
+        match pulls.iter_mut().find(|p| p.model == pull.model) {
+            Some(existing) => *existing = pull,
+            None => pulls.push(pull),
+        }
+    }
+
+    /// All tracked model downloads
+    pub fn get_model_pulls(&self) -> Vec<ModelPull> {
+        self.model_pulls.read().clone()
+    }
+
     /// Clear all batches and suggestions (used when hydrating from snapshot)
     pub fn clear(&mut self) {
         self.batches.set(Vec::new());
@@ -2,6 +2,7 @@
 //!
 //! Central state management using Dioxus signals and context.
 
+pub mod accessibility_state;
 pub mod approval_state;
 pub mod challenge_state;
 pub mod connection_state;
@@ -9,20 +10,32 @@ pub mod dialogue_state;
 pub mod game_state;
 pub mod generation_state;
 pub mod lore_state;
+pub mod notification_state;
+pub mod offline_state;
 pub mod session_state;
+pub mod theme_state;
 
 // Export individual substates
-pub use approval_state::{ConversationLogEntry, PendingApproval, PendingChallengeOutcome};
+pub use accessibility_state::AccessibilityState;
+pub use approval_state::{
+    group_pending_approvals, ApprovalGroup, ApprovalGroupKey, ConversationLogEntry,
+    PendingApproval, PendingChallengeOutcome,
+};
 pub use challenge_state::RollSubmissionStatus;
-pub use connection_state::ConnectionStatus;
+pub use connection_state::{ConnectionStatus, DependencyStatus};
 pub use dialogue_state::{use_typewriter_effect, DialogueState};
 pub use game_state::{
-    ApproachEventData, GameState, LocationEventData, TimeMode, TimeSuggestionData, ViewMode,
+    ApproachEventData, ControlledPc, GameState, LocationEventData, OverlayData, SafetyAlert,
+    TimeMode, TimeSuggestionData, ViewMode,
 };
 pub use generation_state::{
-    BatchStatus, GenerationBatch, GenerationState, SuggestionStatus, SuggestionTask,
+    BatchStatus, GenerationBatch, GenerationState, ModelPull, SuggestionStatus, SuggestionTask,
 };
 pub use lore_state::{KnownLoreEntry, LoreState};
+pub use notification_state::{Notification, NotificationKind, NotificationState};
+pub use offline_state::{use_offline_sync, OfflineState, SyncConflict};
+
+pub use theme_state::{ThemeMode, ThemeState};
 
 // SessionState is the facade that composes the substates (backward-compatible)
 pub use session_state::SessionState;
@@ -61,6 +74,30 @@ pub fn use_generation_state() -> GenerationState {
     use_context::<GenerationState>()
 }
 
+/// Get the accessibility preferences from context
+///
+/// # Panics
+/// Panics if AccessibilityState has not been provided via use_context_provider
+pub fn use_accessibility_state() -> AccessibilityState {
+    use_context::<AccessibilityState>()
+}
+
+/// Get the offline queue and sync feedback from context
+///
+/// # Panics
+/// Panics if OfflineState has not been provided via use_context_provider
+pub fn use_offline_state() -> OfflineState {
+    use_context::<OfflineState>()
+}
+
+/// Get the theme selection from context
+///
+/// # Panics
+/// Panics if ThemeState has not been provided via use_context_provider
+pub fn use_theme_state() -> ThemeState {
+    use_context::<ThemeState>()
+}
+
 /// Get the lore state from context
 ///
 /// # Panics
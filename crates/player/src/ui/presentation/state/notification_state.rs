@@ -0,0 +1,210 @@
+//! Notification center state
+//!
+//! Collects transient game events (items received, time passing, lore
+//! discovered) into a feed the player can come back to, instead of letting
+//! them scroll past in the log. The feed and its read/unread flags are saved
+//! per player character, so they follow the PC across reloads.
+//!
+//! Players who opt in also get a browser/OS notification for events that need
+//! them (an NPC answering, a roll to make) while the app is in the background.
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ports::outbound::{storage_keys, PlatformPort};
+
+/// Maximum number of notifications kept per PC (oldest are dropped)
+const MAX_NOTIFICATIONS: usize = 100;
+
+/// What a notification is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    ItemReceived,
+    ItemLost,
+    TimeAdvanced,
+    LoreDiscovered,
+    StatChanged,
+}
+
+impl NotificationKind {
+    /// Icon shown next to the notification
+    pub fn icon(&self) -> &'static str {
+        match self {
+            NotificationKind::ItemReceived => "🎒",
+            NotificationKind::ItemLost => "🗑",
+            NotificationKind::TimeAdvanced => "⏳",
+            NotificationKind::LoreDiscovered => "📜",
+            NotificationKind::StatChanged => "📈",
+        }
+    }
+}
+
+/// A single entry in the notification feed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: String,
+    pub kind: NotificationKind,
+    pub title: String,
+    pub detail: Option<String>,
+    /// Unix timestamp (seconds) when the notification arrived
+    pub timestamp: u64,
+    pub read: bool,
+}
+
+/// Notification feed for the current player character
+#[derive(Clone, Copy)]
+pub struct NotificationState {
+    /// Notifications, newest first
+    pub notifications: Signal<Vec<Notification>>,
+    /// PC the feed is saved under (None until a PC is selected)
+    pc_id: Signal<Option<String>>,
+}
+
+impl NotificationState {
+    /// Create an empty NotificationState
+    pub fn new() -> Self {
+        Self {
+            notifications: Signal::new(Vec::new()),
+            pc_id: Signal::new(None),
+        }
+    }
+
+    fn storage_key(pc_id: &str) -> String {
+        format!("{}_{}", storage_keys::NOTIFICATIONS, pc_id)
+    }
+
+    /// Switch the feed to a PC, loading what was saved for it
+    ///
+    /// Anything that arrived before a PC was known is kept on top.
+    pub fn load_for_pc(&mut self, pc_id: &str, platform: &dyn PlatformPort) {
+        let previous = self.pc_id.peek().clone();
+        if previous.as_deref() == Some(pc_id) {
+            return;
+        }
+
+        let mut feed = if previous.is_none() {
+            self.notifications.peek().clone()
+        } else {
+            Vec::new()
+        };
+        let saved: Vec<Notification> = platform
+            .storage_load(&Self::storage_key(pc_id))
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        feed.extend(saved);
+        feed.truncate(MAX_NOTIFICATIONS);
+
+        self.pc_id.set(Some(pc_id.to_string()));
+        self.notifications.set(feed);
+        self.persist(platform);
+    }
+
+    /// Add a notification to the top of the feed
+    pub fn push(
+        &mut self,
+        kind: NotificationKind,
+        title: String,
+        detail: Option<String>,
+        platform: &dyn PlatformPort,
+    ) {
+        {
+            let mut feed = self.notifications.write();
+            feed.insert(
+                0,
+                Notification {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    kind,
+                    title,
+                    detail,
+                    timestamp: platform.now_unix_secs(),
+                    read: false,
+                },
+            );
+            feed.truncate(MAX_NOTIFICATIONS);
+        }
+        self.persist(platform);
+    }
+
+    /// Number of notifications the player hasn't looked at
+    pub fn unread_count(&self) -> usize {
+        self.notifications.read().iter().filter(|n| !n.read).count()
+    }
+
+    /// Mark one notification as read
+    pub fn mark_read(&mut self, id: &str, platform: &dyn PlatformPort) {
+        if let Some(notification) = self.notifications.write().iter_mut().find(|n| n.id == id) {
+            notification.read = true;
+        }
+        self.persist(platform);
+    }
+
+    /// Mark every notification as read
+    pub fn mark_all_read(&mut self, platform: &dyn PlatformPort) {
+        for notification in self.notifications.write().iter_mut() {
+            notification.read = true;
+        }
+        self.persist(platform);
+    }
+
+    /// Empty the feed for the current PC
+    pub fn clear_feed(&mut self, platform: &dyn PlatformPort) {
+        self.notifications.set(Vec::new());
+        self.persist(platform);
+    }
+
+    /// Whether system notifications are turned on for this device
+    pub fn system_alerts_enabled(&self, platform: &dyn PlatformPort) -> bool {
+        platform
+            .storage_load(storage_keys::SYSTEM_NOTIFICATIONS)
+            .is_some_and(|value| value == "true")
+    }
+
+    /// Turn system notifications on or off for this device
+    ///
+    /// Turning them on asks the browser for permission if it hasn't yet.
+    pub fn set_system_alerts(&self, enabled: bool, platform: &dyn PlatformPort) {
+        platform.storage_save(
+            storage_keys::SYSTEM_NOTIFICATIONS,
+            if enabled { "true" } else { "false" },
+        );
+        if enabled {
+            platform.request_notification_permission();
+        }
+    }
+
+    /// Show a system notification if the player opted in and isn't looking
+    pub fn alert(&self, title: &str, body: &str, platform: &dyn PlatformPort) {
+        if self.system_alerts_enabled(platform) && platform.is_app_backgrounded() {
+            platform.show_system_notification(title, body);
+        }
+    }
+
+    /// Forget the in-memory feed (the saved copy is kept for next time)
+    pub fn clear(&mut self) {
+        self.notifications.set(Vec::new());
+        self.pc_id.set(None);
+    }
+
+    fn persist(&self, platform: &dyn PlatformPort) {
+        let Some(pc_id) = self.pc_id.peek().clone() else {
+            return;
+        };
+        let key = Self::storage_key(&pc_id);
+        let feed = self.notifications.peek();
+        if feed.is_empty() {
+            platform.storage_remove(&key);
+            return;
+        }
+        match serde_json::to_string(&*feed) {
+            Ok(json) => platform.storage_save(&key, &json),
+            Err(e) => tracing::warn!("Failed to save notifications: {}", e),
+        }
+    }
+}
+
+impl Default for NotificationState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
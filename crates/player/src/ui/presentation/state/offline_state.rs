@@ -0,0 +1,287 @@
+//! Offline play state
+//!
+//! Lets a player keep reading the scene and composing actions while the
+//! connection is down. Actions are queued in platform storage and sent once
+//! the world is rejoined; any that no longer fit the scene are held back as
+//! conflicts for the player to resend or discard.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::PlayerAction;
+use crate::infrastructure::offline::{
+    ActionConflict, OfflineStore, QueuedAction, SceneContext, MAX_QUEUED_ACTIONS,
+};
+use crate::infrastructure::spawn_task;
+use crate::ports::outbound::StorageProvider;
+use crate::presentation::services::use_command_bus;
+use crate::presentation::state::{
+    use_dialogue_state, use_game_state, use_offline_state, use_session_state,
+};
+use crate::Platform;
+
+/// Wait after rejoining before flushing, so the scene updates that follow
+/// WorldJoined land before queued actions are checked against them
+const SYNC_SETTLE_MS: u64 = 500;
+
+/// Platform storage as seen by [`OfflineStore`]
+#[derive(Clone)]
+struct PlatformStorage(Platform);
+
+impl StorageProvider for PlatformStorage {
+    fn save(&self, key: &str, value: &str) {
+        self.0.storage_save(key, value);
+    }
+
+    fn load(&self, key: &str) -> Option<String> {
+        self.0.storage_load(key)
+    }
+
+    fn remove(&self, key: &str) {
+        self.0.storage_remove(key);
+    }
+}
+
+/// A queued action held back on reconnect
+#[derive(Debug, Clone)]
+pub struct SyncConflict {
+    pub action: QueuedAction,
+    pub reason: ActionConflict,
+}
+
+/// Offline queue and sync feedback for the current world
+#[derive(Clone, Copy)]
+pub struct OfflineState {
+    /// Actions waiting to be sent once the world is rejoined
+    pub queued: Signal<Vec<QueuedAction>>,
+    /// Queued actions that no longer fit the scene after reconnecting
+    pub conflicts: Signal<Vec<SyncConflict>>,
+    /// Whether the scene on screen was restored from the local cache
+    pub showing_cached: Signal<bool>,
+    /// World the queue belongs to
+    world_id: Signal<Option<String>>,
+}
+
+impl OfflineState {
+    /// Create an empty OfflineState
+    pub fn new() -> Self {
+        Self {
+            queued: Signal::new(Vec::new()),
+            conflicts: Signal::new(Vec::new()),
+            showing_cached: Signal::new(false),
+            world_id: Signal::new(None),
+        }
+    }
+
+    fn store(&self, platform: &Platform) -> Option<OfflineStore<PlatformStorage>> {
+        let world_id = self.world_id.peek().clone()?;
+        Some(OfflineStore::new(
+            PlatformStorage(platform.clone()),
+            world_id,
+        ))
+    }
+
+    /// Switch to a world, loading anything still queued from a previous visit
+    pub fn load(&mut self, platform: &Platform, world_id: &str) {
+        if self.world_id.peek().as_deref() == Some(world_id) {
+            return;
+        }
+        self.world_id.set(Some(world_id.to_string()));
+        self.conflicts.set(Vec::new());
+        self.showing_cached.set(false);
+        let queued = self
+            .store(platform)
+            .map(|store| store.load_queue())
+            .unwrap_or_default();
+        self.queued.set(queued);
+    }
+
+    /// Queue an action composed while offline
+    pub fn enqueue(
+        &mut self,
+        platform: &Platform,
+        action: PlayerAction,
+        scene_id: Option<String>,
+        region_id: Option<String>,
+    ) {
+        let action = QueuedAction::new(action, scene_id, region_id, platform.now_millis());
+        self.requeue(platform, action);
+    }
+
+    /// Put an action back on the queue as-is
+    pub fn requeue(&mut self, platform: &Platform, action: QueuedAction) {
+        let mut queued = self.queued.peek().clone();
+        queued.push(action);
+        if queued.len() > MAX_QUEUED_ACTIONS {
+            queued.remove(0);
+        }
+        self.set_queue(platform, queued);
+    }
+
+    /// Take every queued action, leaving the queue empty
+    pub fn take_queued(&mut self, platform: &Platform) -> Vec<QueuedAction> {
+        let queued = self.queued.peek().clone();
+        self.set_queue(platform, Vec::new());
+        queued
+    }
+
+    fn set_queue(&mut self, platform: &Platform, queued: Vec<QueuedAction>) {
+        if let Some(store) = self.store(platform) {
+            store.save_queue(&queued);
+        }
+        self.queued.set(queued);
+    }
+
+    /// Record a queued action that was held back on reconnect
+    pub fn add_conflict(&mut self, action: QueuedAction, reason: ActionConflict) {
+        self.conflicts.write().push(SyncConflict { action, reason });
+    }
+
+    /// Remove a conflict, returning its action (to resend or drop)
+    pub fn resolve_conflict(&mut self, action_id: &str) -> Option<QueuedAction> {
+        let mut conflicts = self.conflicts.write();
+        let index = conflicts.iter().position(|c| c.action.id == action_id)?;
+        Some(conflicts.remove(index).action)
+    }
+}
+
+impl Default for OfflineState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Keep a Player's world usable across dropped connections
+///
+/// While the world is joined, the scene on screen is cached in platform
+/// storage. When it isn't (reconnecting, failed, or a reload with no
+/// network) and nothing is loaded, the cached scene is restored so the player
+/// can keep reading. Once the world is rejoined, queued actions are checked
+/// against the fresh scene and either sent or surfaced as conflicts.
+pub fn use_offline_sync(world_id: String) {
+    let platform = crate::use_platform();
+    let command_bus = use_command_bus();
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let dialogue_state = use_dialogue_state();
+    let mut offline_state = use_offline_state();
+
+    {
+        let platform = platform.clone();
+        use_effect(move || {
+            offline_state.load(&platform, &world_id);
+        });
+    }
+
+    // Cache the scene while it's live
+    {
+        let platform = platform.clone();
+        let session_state = session_state.clone();
+        let game_state = game_state.clone();
+        use_effect(move || {
+            if !*session_state.connection.joined.read() {
+                return;
+            }
+            if let (Some(snapshot), Some(store)) = (
+                game_state.offline_snapshot(platform.now_millis()),
+                offline_state.store(&platform),
+            ) {
+                store.save_snapshot(&snapshot);
+            }
+        });
+    }
+
+    // Restore the cached scene when there's nothing live to show
+    {
+        let platform = platform.clone();
+        let session_state = session_state.clone();
+        let mut game_state = game_state.clone();
+        use_effect(move || {
+            let joined = *session_state.connection.joined.read();
+            if joined {
+                offline_state.showing_cached.set(false);
+                return;
+            }
+            if game_state.world.read().is_some() {
+                return;
+            }
+            if let Some(snapshot) = offline_state
+                .store(&platform)
+                .and_then(|store| store.load_snapshot())
+            {
+                tracing::info!("Showing cached scene while offline");
+                game_state.restore_offline_snapshot(snapshot);
+                offline_state.showing_cached.set(true);
+            }
+        });
+    }
+
+    // Flush the queue once the world is rejoined
+    use_effect(move || {
+        if !*session_state.connection.joined.read() || offline_state.queued.peek().is_empty() {
+            return;
+        }
+        let platform = platform.clone();
+        let command_bus = command_bus.clone();
+        let session_state = session_state.clone();
+        let game_state = game_state.clone();
+        let dialogue_state = dialogue_state.clone();
+        spawn_task(async move {
+            platform.sleep_ms(SYNC_SETTLE_MS).await;
+            if !*session_state.connection.joined.peek() {
+                return;
+            }
+
+            let scene = SceneContext {
+                scene_id: game_state
+                    .current_scene
+                    .peek()
+                    .as_ref()
+                    .map(|s| s.id.clone()),
+                region_id: game_state
+                    .current_region
+                    .peek()
+                    .as_ref()
+                    .map(|r| r.id.clone()),
+                target_ids: game_state
+                    .scene_characters
+                    .peek()
+                    .iter()
+                    .map(|c| c.id.clone())
+                    .chain(
+                        game_state
+                            .npcs_present
+                            .peek()
+                            .iter()
+                            .map(|n| n.character_id.clone()),
+                    )
+                    .chain(game_state.interactions.peek().iter().map(|i| i.id.clone()))
+                    .collect(),
+                choice_ids: dialogue_state
+                    .choices
+                    .peek()
+                    .iter()
+                    .map(|c| c.id.clone())
+                    .collect(),
+                now_ms: platform.now_millis(),
+            };
+
+            let queued = offline_state.take_queued(&platform);
+            let total = queued.len();
+            let mut sent = 0;
+            for action in queued {
+                if let Some(reason) = action.conflict(&scene) {
+                    offline_state.add_conflict(action, reason);
+                    continue;
+                }
+                match command_bus.send(action.message()) {
+                    Ok(()) => sent += 1,
+                    Err(e) => {
+                        tracing::warn!("Failed to send queued action: {}", e);
+                        offline_state.requeue(&platform, action);
+                    }
+                }
+            }
+            tracing::info!(sent, total, "Synced offline actions");
+        });
+    });
+}
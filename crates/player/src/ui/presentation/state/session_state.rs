@@ -1,7 +1,7 @@
 //! Session state management using Dioxus signals
 //!
-//! This is a facade that composes ConnectionState, ApprovalState, and ChallengeState
-//! for unified session management. Individual substates can be accessed directly
+//! This is a facade that composes ConnectionState, ApprovalState, ChallengeState,
+//! and NotificationState for unified session management. Individual substates can be accessed directly
 //! for more focused functionality.
 
 use dioxus::prelude::*;
@@ -19,13 +19,16 @@ use crate::presentation::state::approval_state::{
 use crate::presentation::state::challenge_state::{
     ChallengePromptData, ChallengeResultData, ChallengeState,
 };
-use crate::presentation::state::connection_state::{ConnectionState, ConnectionStatus};
+use crate::presentation::state::connection_state::{
+    ConnectionState, ConnectionStatus, DependencyStatus,
+};
+use crate::presentation::state::notification_state::NotificationState;
 
 /// Session state for connection and user information
 ///
-/// This is a facade that composes ConnectionState, ApprovalState, and ChallengeState.
-/// For new code, prefer accessing the substates directly via the `connection`,
-/// `approval`, and `challenge` fields.
+/// This is a facade that composes ConnectionState, ApprovalState, ChallengeState, and
+/// NotificationState. For new code, prefer accessing the substates directly via the
+/// `connection`, `approval`, `challenge`, and `notifications` fields.
 #[derive(Clone)]
 pub struct SessionState {
     /// Connection-related state (status, user, session)
@@ -34,6 +37,8 @@ pub struct SessionState {
     pub approval: ApprovalState,
     /// Challenge-related state (active challenge, results, skills)
     pub challenge: ChallengeState,
+    /// Notification feed for the current PC
+    pub notifications: NotificationState,
 }
 
 impl SessionState {
@@ -43,6 +48,7 @@ impl SessionState {
             connection: ConnectionState::new(),
             approval: ApprovalState::new(),
             challenge: ChallengeState::new(),
+            notifications: NotificationState::new(),
         }
     }
 
@@ -133,6 +139,16 @@ impl SessionState {
         self.connection.comfyui_retry_in_seconds
     }
 
+    /// Last reported status of each Engine dependency
+    pub fn dependency_status(&self) -> Signal<Vec<DependencyStatus>> {
+        self.connection.dependency_status
+    }
+
+    /// Record a dependency status change from the Engine
+    pub fn set_dependency_status(&mut self, status: DependencyStatus) {
+        self.connection.set_dependency_status(status);
+    }
+
     // =========================================================================
     // Backward-compatible methods (delegate to substates)
     // =========================================================================
@@ -193,6 +209,7 @@ impl SessionState {
         self.connection.clear();
         self.approval.clear();
         self.challenge.clear();
+        self.notifications.clear();
     }
 
     /// Add a pending approval request
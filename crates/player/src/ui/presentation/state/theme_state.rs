@@ -0,0 +1,93 @@
+//! Theme selection
+//!
+//! The light/dark/high-contrast choice is a per-device preference saved in
+//! platform storage. The world's accent color and backdrop frame come from the
+//! Engine instead (see `GameState::world_theme`).
+
+use dioxus::prelude::*;
+
+use crate::ports::outbound::storage_keys;
+use crate::Platform;
+
+/// Base color theme for the Player UI
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ThemeMode {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeMode {
+    pub const ALL: [ThemeMode; 3] = [ThemeMode::Dark, ThemeMode::Light, ThemeMode::HighContrast];
+
+    /// Stable identifier used for storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeMode::Dark => "dark",
+            ThemeMode::Light => "light",
+            ThemeMode::HighContrast => "high-contrast",
+        }
+    }
+
+    /// Parse a stored identifier, falling back to the default theme
+    pub fn from_storage(value: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.as_str() == value)
+            .unwrap_or_default()
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ThemeMode::Dark => "Dark",
+            ThemeMode::Light => "Light",
+            ThemeMode::HighContrast => "High Contrast",
+        }
+    }
+
+    /// Root CSS class that selects the palette (see `styles/input.css`)
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            ThemeMode::Dark => "theme-dark",
+            ThemeMode::Light => "theme-light",
+            ThemeMode::HighContrast => "theme-high-contrast",
+        }
+    }
+}
+
+/// Selected theme for this device
+#[derive(Clone, Copy)]
+pub struct ThemeState {
+    pub mode: Signal<ThemeMode>,
+}
+
+impl ThemeState {
+    /// Create a new ThemeState with the dark theme
+    pub fn new() -> Self {
+        Self {
+            mode: Signal::new(ThemeMode::default()),
+        }
+    }
+
+    /// Load the saved theme from platform storage
+    pub fn load(&mut self, platform: &Platform) {
+        let mode = platform
+            .storage_load(storage_keys::THEME)
+            .map(|value| ThemeMode::from_storage(&value))
+            .unwrap_or_default();
+        self.mode.set(mode);
+    }
+
+    /// Switch theme and persist the choice
+    pub fn set_mode(&mut self, platform: &Platform, mode: ThemeMode) {
+        self.mode.set(mode);
+        platform.storage_save(storage_keys::THEME, mode.as_str());
+    }
+}
+
+impl Default for ThemeState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
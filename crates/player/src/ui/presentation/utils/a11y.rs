@@ -0,0 +1,129 @@
+//! Accessibility helpers for keyboard navigation
+//!
+//! Dialogue choices, approval actions, and navigation destinations are lists
+//! of buttons. Arrow keys move focus within a list (a "roving" focus), so
+//! keyboard users don't have to Tab through every control to reach the next
+//! option.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+/// Whether the key activates a focused element (Enter or Space)
+pub fn is_activation_key(key: &Key) -> bool {
+    match key {
+        Key::Enter => true,
+        Key::Character(c) => c == " ",
+        _ => false,
+    }
+}
+
+/// Index to focus after an arrow/Home/End key press, wrapping at the ends
+///
+/// `current` is `None` when no item in the list has had focus yet, so the
+/// first arrow press lands on the first (or last) item. Returns `None` for
+/// keys that don't move focus or when the list is empty.
+pub fn roving_index(key: &Key, current: Option<usize>, len: usize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let current = current.map(|c| c.min(len - 1));
+    match key {
+        Key::ArrowDown | Key::ArrowRight => Some(current.map_or(0, |c| (c + 1) % len)),
+        Key::ArrowUp | Key::ArrowLeft => Some(current.map_or(len - 1, |c| (c + len - 1) % len)),
+        Key::Home => Some(0),
+        Key::End => Some(len - 1),
+        _ => None,
+    }
+}
+
+/// Focus a freshly mounted element (e.g. the first control of a dialog)
+pub fn focus_mounted(element: Rc<MountedData>) {
+    spawn(async move {
+        let _ = element.set_focus(true).await;
+    });
+}
+
+/// Roving focus over a list of mounted elements
+#[derive(Clone, Copy, PartialEq)]
+pub struct RovingFocus {
+    elements: Signal<Vec<Option<Rc<MountedData>>>>,
+    current: Signal<Option<usize>>,
+}
+
+impl RovingFocus {
+    /// Record the element at `index` (call from `onmounted`)
+    pub fn register(mut self, index: usize, element: Rc<MountedData>) {
+        let mut elements = self.elements.write();
+        if elements.len() <= index {
+            elements.resize(index + 1, None);
+        }
+        elements[index] = Some(element);
+    }
+
+    /// Track which element has focus (call from `onfocus`)
+    pub fn set_current(mut self, index: usize) {
+        self.current.set(Some(index));
+    }
+
+    /// Move focus to the element at `index`, if it is mounted
+    pub fn focus(self, index: usize) {
+        if let Some(Some(element)) = self.elements.peek().get(index).cloned() {
+            focus_mounted(element);
+        }
+    }
+
+    /// Handle a key press on the list, returning true if focus moved
+    pub fn handle_key(self, event: &KeyboardEvent) -> bool {
+        let len = self.elements.peek().len();
+        match roving_index(&event.key(), *self.current.peek(), len) {
+            Some(next) => {
+                event.prevent_default();
+                self.focus(next);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Create a roving focus group for a list of controls
+pub fn use_roving_focus() -> RovingFocus {
+    RovingFocus {
+        elements: use_signal(Vec::new),
+        current: use_signal(|| None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roving_index_wraps() {
+        assert_eq!(roving_index(&Key::ArrowDown, Some(2), 3), Some(0));
+        assert_eq!(roving_index(&Key::ArrowUp, Some(0), 3), Some(2));
+        assert_eq!(roving_index(&Key::ArrowRight, Some(0), 3), Some(1));
+        assert_eq!(roving_index(&Key::End, Some(0), 3), Some(2));
+        assert_eq!(roving_index(&Key::Home, Some(2), 3), Some(0));
+    }
+
+    #[test]
+    fn test_roving_index_enters_list() {
+        assert_eq!(roving_index(&Key::ArrowDown, None, 3), Some(0));
+        assert_eq!(roving_index(&Key::ArrowUp, None, 3), Some(2));
+    }
+
+    #[test]
+    fn test_roving_index_ignores_other_keys() {
+        assert_eq!(roving_index(&Key::Enter, Some(0), 3), None);
+        assert_eq!(roving_index(&Key::ArrowDown, None, 0), None);
+    }
+
+    #[test]
+    fn test_is_activation_key() {
+        assert!(is_activation_key(&Key::Enter));
+        assert!(is_activation_key(&Key::Character(" ".to_string())));
+        assert!(!is_activation_key(&Key::Character("a".to_string())));
+    }
+}
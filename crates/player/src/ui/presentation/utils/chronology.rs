@@ -0,0 +1,15 @@
+//! Chronology display helpers
+
+use crate::application::dto::LifeStageData;
+
+/// Human-readable life stage
+pub fn life_stage_label(stage: LifeStageData) -> &'static str {
+    match stage {
+        LifeStageData::Child => "Child",
+        LifeStageData::Adolescent => "Adolescent",
+        LifeStageData::Adult => "Adult",
+        LifeStageData::MiddleAged => "Middle-aged",
+        LifeStageData::Elder => "Elder",
+        LifeStageData::Unknown => "Unknown",
+    }
+}
@@ -0,0 +1,18 @@
+//! World feature utilities
+//!
+//! Components for experimental subsystems check `GameState::world_features`
+//! and render nothing when the DM has the feature switched off.
+
+use wrldbldr_domain::WorldFeatures;
+
+use crate::application::dto::WorldFeaturesData;
+
+/// Convert wire feature switches into the domain settings kept in game state
+pub fn world_features_from_data(data: WorldFeaturesData) -> WorldFeatures {
+    WorldFeatures {
+        combat_tracker: data.combat_tracker,
+        rumors: data.rumors,
+        weather: data.weather,
+        age_progression: data.age_progression,
+    }
+}
@@ -2,6 +2,18 @@
 //!
 //! This module contains utility functions and extension traits for UI presentation.
 
+pub mod a11y;
+pub mod chronology;
+pub mod features;
 pub mod position_styles;
+pub mod sheet_validation;
+pub mod text_layout;
+pub mod theme;
 
+pub use a11y::{focus_mounted, is_activation_key, use_roving_focus, RovingFocus};
+pub use chronology::life_stage_label;
+pub use features::world_features_from_data;
 pub use position_styles::CharacterPositionStyle;
+pub use sheet_validation::validate_field;
+pub use text_layout::{typography_from_data, use_text_layout, TextLayout};
+pub use theme::{backdrop_frame_class, world_accent_style, world_theme_from_data};
@@ -0,0 +1,239 @@
+//! Inline validation for schema-driven character sheet fields
+//!
+//! Mirrors what the game system checks on the Engine closely enough to catch
+//! typos while the user is still looking at the field. The Engine stays the
+//! authority: anything that passes here can still be rejected by the
+//! system's own rules when the update is sent.
+
+use serde_json::Value;
+use wrldbldr_domain::{FieldDefinition, SchemaFieldType};
+
+/// Whether a value counts as "not filled in"
+pub fn is_empty_value(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+/// Read a whole number, accepting numeric strings typed into text inputs
+pub fn as_integer(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Check a value against its field definition
+///
+/// Returns a message to show next to the field, or `None` if the value is
+/// acceptable.
+pub fn validate_field(field: &FieldDefinition, value: &Value) -> Option<String> {
+    if is_empty_value(value) {
+        return field
+            .required
+            .then(|| format!("{} is required", field.label));
+    }
+
+    if let Some(message) = validate_type(&field.field_type, value) {
+        return Some(message);
+    }
+
+    let rules = field.validation.as_ref()?;
+    let custom = |default: String| rules.error_message.clone().unwrap_or(default);
+
+    if rules.min.is_some() || rules.max.is_some() {
+        if let Some(n) = as_integer(value) {
+            if let Some(min) = rules.min {
+                if n < i64::from(min) {
+                    return Some(custom(format!("Must be at least {}", min)));
+                }
+            }
+            if let Some(max) = rules.max {
+                if n > i64::from(max) {
+                    return Some(custom(format!("Must be at most {}", max)));
+                }
+            }
+        }
+    }
+
+    if let (Some(pattern), Some(text)) = (rules.pattern.as_deref(), value.as_str()) {
+        // A pattern the client can't compile is left to the Engine
+        if let Ok(re) = regex_lite::Regex::new(pattern) {
+            if !re.is_match(text) {
+                return Some(custom("Doesn't match the expected format".to_string()));
+            }
+        }
+    }
+
+    None
+}
+
+/// Constraints implied by the field type itself
+fn validate_type(field_type: &SchemaFieldType, value: &Value) -> Option<String> {
+    let range = |min: Option<i64>, max: Option<i64>| -> Option<String> {
+        let Some(n) = as_integer(value) else {
+            return Some("Must be a whole number".to_string());
+        };
+        match (min, max) {
+            (Some(min), _) if n < min => Some(format!("Must be at least {}", min)),
+            (_, Some(max)) if n > max => Some(format!("Must be at most {}", max)),
+            _ => None,
+        }
+    };
+
+    match field_type {
+        SchemaFieldType::Integer { min, max, .. } | SchemaFieldType::AbilityScore { min, max } => {
+            range(min.map(i64::from), max.map(i64::from))
+        }
+        SchemaFieldType::LadderRating { min, max, .. } => {
+            range(Some(i64::from(*min)), Some(i64::from(*max)))
+        }
+        SchemaFieldType::DicePool { max_dice, .. } => range(Some(0), Some(i64::from(*max_dice))),
+        SchemaFieldType::Clock { segments } => range(Some(0), Some(i64::from(*segments))),
+        SchemaFieldType::PercentileSkill { .. } => range(Some(0), None),
+        SchemaFieldType::ResourceBar { .. } => range(Some(0), None),
+        SchemaFieldType::ConditionTrack { levels } => {
+            let Some(n) = as_integer(value) else {
+                return Some("Must be a whole number".to_string());
+            };
+            let known = n == 0 || levels.iter().any(|l| i64::from(l.level) == n);
+            (!known).then(|| "Not a level on this track".to_string())
+        }
+        SchemaFieldType::Text { max_length, .. } => {
+            let len = value.as_str().map(|s| s.chars().count())?;
+            match max_length {
+                Some(max) if len > *max => Some(format!("At most {} characters", max)),
+                _ => None,
+            }
+        }
+        SchemaFieldType::Select {
+            options,
+            allow_custom,
+        } => {
+            let selected = value.as_str()?;
+            (!allow_custom && !options.iter().any(|o| o.value == selected))
+                .then(|| "Pick one of the listed options".to_string())
+        }
+        SchemaFieldType::MultiSelect { max_selections, .. } => {
+            let count = value.as_array().map(Vec::len)?;
+            match max_selections {
+                Some(max) if count > *max => Some(format!("Pick at most {}", max)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wrldbldr_domain::{FieldLayout, FieldValidation, SchemaSelectOption};
+
+    fn field(field_type: SchemaFieldType) -> FieldDefinition {
+        FieldDefinition {
+            id: "F".to_string(),
+            label: "Field".to_string(),
+            field_type,
+            editable: true,
+            required: false,
+            derived_from: None,
+            validation: None,
+            layout: FieldLayout::default(),
+            description: None,
+            placeholder: None,
+        }
+    }
+
+    #[test]
+    fn test_required_and_empty_values() {
+        let mut name = field(SchemaFieldType::Text {
+            multiline: false,
+            max_length: Some(5),
+        });
+        assert_eq!(validate_field(&name, &json!("")), None);
+
+        name.required = true;
+        assert_eq!(
+            validate_field(&name, &json!("  ")),
+            Some("Field is required".to_string())
+        );
+        assert_eq!(
+            validate_field(&name, &json!("Alexandra")),
+            Some("At most 5 characters".to_string())
+        );
+    }
+
+    #[test]
+    fn test_type_ranges() {
+        let pool = field(SchemaFieldType::DicePool {
+            max_dice: 4,
+            die_type: 6,
+        });
+        assert_eq!(validate_field(&pool, &json!(3)), None);
+        assert!(validate_field(&pool, &json!(5)).is_some());
+
+        let ladder = field(SchemaFieldType::LadderRating {
+            min: -2,
+            max: 8,
+            labels: vec![],
+        });
+        assert_eq!(validate_field(&ladder, &json!("-1")), None);
+        assert_eq!(
+            validate_field(&ladder, &json!("great")),
+            Some("Must be a whole number".to_string())
+        );
+
+        let select = field(SchemaFieldType::Select {
+            options: vec![SchemaSelectOption {
+                value: "cutter".to_string(),
+                label: "Cutter".to_string(),
+                description: None,
+            }],
+            allow_custom: false,
+        });
+        assert_eq!(validate_field(&select, &json!("cutter")), None);
+        assert!(validate_field(&select, &json!("hound")).is_some());
+    }
+
+    #[test]
+    fn test_schema_rules_use_custom_message() {
+        let mut score = field(SchemaFieldType::Integer {
+            min: None,
+            max: None,
+            show_modifier: false,
+        });
+        score.validation = Some(FieldValidation {
+            min: Some(1),
+            max: Some(30),
+            pattern: None,
+            error_message: Some("Must be between 1 and 30".to_string()),
+        });
+        assert_eq!(validate_field(&score, &json!(18)), None);
+        assert_eq!(
+            validate_field(&score, &json!(31)),
+            Some("Must be between 1 and 30".to_string())
+        );
+
+        let mut code = field(SchemaFieldType::Text {
+            multiline: false,
+            max_length: None,
+        });
+        code.validation = Some(FieldValidation {
+            min: None,
+            max: None,
+            pattern: Some("^[A-Z]{3}$".to_string()),
+            error_message: None,
+        });
+        assert_eq!(validate_field(&code, &json!("ABC")), None);
+        assert_eq!(
+            validate_field(&code, &json!("abc")),
+            Some("Doesn't match the expected format".to_string())
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! Text layout utilities for world text
+//!
+//! Turns a world's typography settings into the `dir`, `lang`, and inline
+//! style attributes used by dialogue and character sheet components. The
+//! `lang` attribute matters for CJK: browsers pick line-break rules and font
+//! fallbacks from it.
+
+use dioxus::prelude::*;
+use wrldbldr_domain::{TextDirection, WorldTypography};
+
+use crate::application::dto::{TextDirectionData, WorldTypographyData};
+use crate::presentation::state::use_game_state;
+
+/// Layout attributes for a block of world text
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TextLayout {
+    /// Value for the HTML `dir` attribute ("ltr", "rtl", or "auto")
+    pub dir: &'static str,
+    /// Value for the HTML `lang` attribute, if the world declares a language
+    pub lang: Option<String>,
+    /// Inline CSS for font and line breaking
+    pub style: String,
+}
+
+impl TextLayout {
+    pub fn for_world(typography: &WorldTypography) -> Self {
+        let dir = match typography.effective_direction() {
+            TextDirection::LeftToRight => "ltr",
+            TextDirection::RightToLeft => "rtl",
+            TextDirection::Auto | TextDirection::Unknown => "auto",
+        };
+
+        let mut style = String::new();
+        if let Some(font_family) = &typography.font_family {
+            // The variable lets themed classes (e.g. `.vn-dialogue-text`) pick it up
+            style.push_str(&format!(
+                "--world-font: {0}; font-family: {0}; ",
+                font_family
+            ));
+        }
+        if typography.is_cjk() {
+            // Keep CJK punctuation off line starts and break between ideographs
+            style.push_str("line-break: strict; word-break: normal; overflow-wrap: anywhere;");
+        }
+
+        Self {
+            dir,
+            lang: typography.language.clone(),
+            style,
+        }
+    }
+}
+
+/// Text layout for the current world, re-rendering when typography changes
+pub fn use_text_layout() -> TextLayout {
+    let game_state = use_game_state();
+    let typography = game_state.typography.read();
+    TextLayout::for_world(&typography)
+}
+
+/// Convert wire typography into the domain settings used for layout
+pub fn typography_from_data(data: WorldTypographyData) -> WorldTypography {
+    WorldTypography {
+        direction: match data.direction {
+            TextDirectionData::Auto => TextDirection::Auto,
+            TextDirectionData::LeftToRight => TextDirection::LeftToRight,
+            TextDirectionData::RightToLeft => TextDirection::RightToLeft,
+            TextDirectionData::Unknown => TextDirection::Unknown,
+        },
+        language: data.language,
+        font_family: data.font_family,
+        font_stylesheet_url: data.font_stylesheet_url,
+    }
+}
@@ -0,0 +1,69 @@
+//! World theme utilities
+//!
+//! Turns the DM's accent color into CSS variable overrides for the gold and
+//! amber palettes, and backdrop frames into their CSS classes.
+
+use wrldbldr_domain::{BackdropFrame, WorldTheme};
+
+use crate::application::dto::{BackdropFrameData, WorldThemeData};
+
+/// Inline style overriding the accent palette steps with the world's color
+///
+/// Lighter and darker steps are mixed toward white and black so hover and
+/// border shades keep their relationship to the base color.
+pub fn world_accent_style(theme: &WorldTheme) -> String {
+    let Some(accent) = theme.accent_rgb() else {
+        return String::new();
+    };
+
+    let steps = [
+        (300, mix(accent, (255, 255, 255), 0.4)),
+        (400, mix(accent, (255, 255, 255), 0.2)),
+        (500, accent),
+        (600, mix(accent, (0, 0, 0), 0.15)),
+        (700, mix(accent, (0, 0, 0), 0.3)),
+    ];
+
+    let mut style = String::new();
+    for palette in ["gold", "amber"] {
+        for (shade, (r, g, b)) in steps {
+            style.push_str(&format!("--color-{palette}-{shade}: {r} {g} {b}; "));
+        }
+    }
+    style
+}
+
+/// CSS class for a backdrop frame (empty for no frame)
+pub fn backdrop_frame_class(frame: BackdropFrame) -> &'static str {
+    match frame {
+        BackdropFrame::Simple => "backdrop-frame-simple",
+        BackdropFrame::Ornate => "backdrop-frame-ornate",
+        BackdropFrame::Parchment => "backdrop-frame-parchment",
+        BackdropFrame::Vignette => "backdrop-frame-vignette",
+        BackdropFrame::Plain | BackdropFrame::Unknown => "",
+    }
+}
+
+/// Convert a wire theme into the domain settings used for styling
+pub fn world_theme_from_data(data: WorldThemeData) -> WorldTheme {
+    WorldTheme {
+        accent_color: data.accent_color,
+        backdrop_frame: match data.backdrop_frame {
+            BackdropFrameData::Plain => BackdropFrame::Plain,
+            BackdropFrameData::Simple => BackdropFrame::Simple,
+            BackdropFrameData::Ornate => BackdropFrame::Ornate,
+            BackdropFrameData::Parchment => BackdropFrame::Parchment,
+            BackdropFrameData::Vignette => BackdropFrame::Vignette,
+            BackdropFrameData::Unknown => BackdropFrame::Unknown,
+        },
+    }
+}
+
+fn mix(from: (u8, u8, u8), to: (u8, u8, u8), amount: f32) -> (u8, u8, u8) {
+    let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * amount).round() as u8;
+    (
+        channel(from.0, to.0),
+        channel(from.1, to.1),
+        channel(from.2, to.2),
+    )
+}
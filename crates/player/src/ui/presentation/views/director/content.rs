@@ -1,25 +1,42 @@
 //! Director mode content - Directing gameplay
 
 use dioxus::prelude::*;
+use wrldbldr_domain::{unknown_names_in, MAX_NPC_DRAFT_EXCERPT_LEN};
 
 use crate::infrastructure::spawn_task;
-use crate::application::dto::{ApprovalDecision, ApprovedNpcInfo, ChallengeData, SkillData};
+use crate::application::dto::{
+    ApprovalDecision, ApprovedNpcInfo, ChallengeData, MentionTargetData, NpcDraftSourceData,
+    SkillData,
+};
+use crate::presentation::components::dm_panel::broadsheet::BroadsheetPanel;
 use crate::presentation::components::dm_panel::challenge_library::ChallengeLibrary;
 use crate::presentation::components::dm_panel::character_perspective::ViewAsData;
 use crate::presentation::components::dm_panel::decision_queue::DecisionQueuePanel;
+use crate::presentation::components::dm_panel::flashback::FlashbackPanel;
+use crate::presentation::components::dm_panel::handout_panel::HandoutPanel;
 use crate::presentation::components::dm_panel::location_preview_modal::LocationPreviewModal;
 use crate::presentation::components::dm_panel::log_entry::DynamicLogEntry;
+use crate::presentation::components::dm_panel::parallel_scenes::ParallelScenesPanel;
 use crate::presentation::components::dm_panel::npc_disposition_panel::{
     DispositionChangeEvent, NpcDispositionListPanel, RelationshipChangeEvent, SceneNpcInfo,
 };
 use crate::presentation::components::dm_panel::split_party_banner::SplitPartyBanner;
+use crate::presentation::components::dm_panel::spotlight::SpotlightPanel;
+use crate::presentation::components::dm_panel::pacing::PacingPanel;
 use crate::presentation::components::dm_panel::staging_approval::{
     StagingApprovalPopup, StagingApprovalResult, StagingRegenerateRequest,
 };
+use crate::presentation::components::dm_panel::journey_approval::JourneyApprovalPanel;
+use crate::presentation::components::dm_panel::progress_clocks::ProgressClockPanel;
+use crate::presentation::components::dm_panel::safety_alert::SafetyAlertPanel;
 use crate::presentation::components::dm_panel::time_control::TimeControlPanel;
 use crate::presentation::components::dm_panel::trigger_challenge_modal::TriggerChallengeModal;
+use crate::presentation::components::dm_panel::world_simulation::WorldSimulationPanel;
 use crate::infrastructure::websocket::ClientMessageBuilder;
-use crate::presentation::services::{use_challenge_service, use_command_bus, use_skill_service};
+use crate::presentation::services::{
+    use_challenge_service, use_character_service, use_command_bus, use_mention_service,
+    use_npc_draft_service, use_skill_service,
+};
 use crate::presentation::state::{
     use_game_state, use_generation_state, use_session_state, GameState, PendingApproval,
     SessionState, ViewMode,
@@ -32,6 +49,9 @@ pub fn DirectorModeContent() -> Element {
     let game_state = use_game_state();
     let skill_service = use_skill_service();
     let challenge_service = use_challenge_service();
+    let character_service = use_character_service();
+    let npc_draft_service = use_npc_draft_service();
+    let mention_service = use_mention_service();
     let _generation_state = use_generation_state();
     let mut show_queue_panel = use_signal(|| false);
 
@@ -83,9 +103,62 @@ pub fn DirectorModeContent() -> Element {
         }
     });
 
+    // Character names, so only names that aren't characters yet are offered
+    // for promotion; reloaded as drafts come and go, since approving one
+    // creates a character
+    let mut character_names: Signal<Vec<String>> = use_signal(Vec::new);
+    let world_id_for_characters = game_state.world.read().as_ref().map(|w| w.world.id.clone());
+    let npc_drafts = game_state.npc_drafts;
+    use_effect(move || {
+        let _ = npc_drafts.read().len();
+        if let Some(world_id) = world_id_for_characters.clone() {
+            let svc = character_service.clone();
+            spawn_task(async move {
+                if let Ok(characters) = svc.list_characters(&world_id).await {
+                    character_names.set(characters.into_iter().map(|c| c.name).collect());
+                }
+            });
+        }
+    });
+    let mut promote_error: Signal<Option<String>> = use_signal(|| None);
+
+    // Entities the log links to hover-cards, reloaded with the names above
+    let mut mention_targets: Signal<Vec<MentionTargetData>> = use_signal(Vec::new);
+    let has_world = game_state.world.read().is_some();
+    use_effect(move || {
+        let _ = npc_drafts.read().len();
+        if has_world {
+            let svc = mention_service.clone();
+            spawn_task(async move {
+                if let Ok(targets) = svc.list_targets().await {
+                    mention_targets.set(targets);
+                }
+            });
+        }
+    });
+
     // Get pending approvals from state
     let pending_approvals = session_state.pending_approvals().read().clone();
     let conversation_log = session_state.conversation_log().read().clone();
+    let known_names: Vec<String> = character_names
+        .read()
+        .iter()
+        .cloned()
+        .chain(game_state.npc_drafts.read().iter().map(|d| d.name.clone()))
+        .chain(
+            game_state
+                .scene_characters
+                .read()
+                .iter()
+                .map(|c| c.name.clone()),
+        )
+        .chain(
+            mention_targets
+                .read()
+                .iter()
+                .flat_map(|t| t.aliases.iter().cloned()),
+        )
+        .collect();
 
     // Get scene characters from game state
     let scene_characters = game_state.scene_characters.read().clone();
@@ -181,8 +254,42 @@ pub fn DirectorModeContent() -> Element {
                                 speaker: entry.speaker.clone(),
                                 text: entry.text.clone(),
                                 is_system: entry.is_system,
+                                mentioned_names: mentioned_names(&entry.speaker, &entry.text, entry.is_system, &known_names),
+                                mention_targets: mention_targets.read().clone(),
+                                on_promote: {
+                                    let excerpt: String = entry
+                                        .text
+                                        .chars()
+                                        .take(MAX_NPC_DRAFT_EXCERPT_LEN)
+                                        .collect();
+                                    let svc = npc_draft_service.clone();
+                                    let game_state = game_state.clone();
+                                    move |name: String| {
+                                        let svc = svc.clone();
+                                        let mut game_state = game_state.clone();
+                                        let source = NpcDraftSourceData::Dialogue {
+                                            excerpt: excerpt.clone(),
+                                        };
+                                        spawn_task(async move {
+                                            match svc.promote(source, Some(name)).await {
+                                                Ok(draft) => {
+                                                    game_state.upsert_npc_draft(draft);
+                                                    promote_error.set(None);
+                                                }
+                                                Err(e) => promote_error.set(Some(format!(
+                                                    "Failed to promote NPC: {}",
+                                                    e
+                                                ))),
+                                            }
+                                        });
+                                    }
+                                },
                             }
                         }
+
+                        if let Some(err) = promote_error.read().as_ref() {
+                            div { class: "text-red-400 text-xs", "{err}" }
+                        }
                     }
                 }
 
@@ -206,9 +313,47 @@ pub fn DirectorModeContent() -> Element {
             div {
                 class: "control-panel flex flex-col gap-4 overflow-y-auto",
 
+                // Safety signals and play pause
+                if let Some(world_id) = session_state.world_id().read().as_ref() {
+                    SafetyAlertPanel { world_id: world_id.to_string() }
+                }
+
+                // Fast-travel journeys stopped by an encounter
+                JourneyApprovalPanel {}
+
                 // Game Time Control Panel
                 TimeControlPanel {}
 
+                // Scenes played at an earlier date
+                if let Some(world_id) = session_state.world_id().read().as_ref() {
+                    FlashbackPanel { world_id: world_id.to_string() }
+                }
+
+                // Split party groups, each running its own scene
+                if let Some(world_id) = session_state.world_id().read().as_ref() {
+                    ParallelScenesPanel { world_id: world_id.to_string() }
+                }
+
+                // Spotlight rotation for systems without initiative
+                SpotlightPanel {}
+
+                // Real-time scene and turn limits
+                PacingPanel {}
+
+                // Progress clocks (faction/quest/threat tracking)
+                if let Some(world_id) = session_state.world_id().read().as_ref() {
+                    ProgressClockPanel { world_id: world_id.to_string() }
+                }
+
+                // Full-screen handouts for players
+                HandoutPanel {}
+
+                // The world's weekly news, drafted for the DM to publish
+                BroadsheetPanel {}
+
+                // What the world did during the last time skip, for review
+                WorldSimulationPanel {}
+
                 // Connection status
                 div {
                     class: "panel-section bg-dark-surface rounded-lg p-4",
@@ -717,6 +862,16 @@ fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
                 span { class: "text-xs text-gray-400 font-normal", "{props.approval.request_id}" }
             }
 
+            // Content safety filter hits - shown before the dialogue so they can't be missed
+            if !props.approval.safety_warnings.is_empty() {
+                div { class: "mb-4 p-3 bg-red-500/10 border border-red-500 rounded-lg",
+                    p { class: "text-red-400 text-sm font-semibold m-0 mb-1", "Content safety warning" }
+                    for warning in props.approval.safety_warnings.iter() {
+                        p { class: "text-red-300 text-xs m-0", "{warning}" }
+                    }
+                }
+            }
+
             div { class: "mb-4",
                 p { class: "text-gray-400 text-sm mb-1", "{npc_name} will say:" }
                 textarea {
@@ -974,6 +1129,7 @@ fn ViewAsCharacterMode(props: ViewAsCharacterModeProps) -> Element {
     let current_region = game_state.current_region.read().clone();
     let npcs_present = game_state.npcs_present.read().clone();
     let region_items = game_state.region_items.read().clone();
+    let crowds_present = game_state.crowds_present.read().clone();
     let conversation_log = session_state.conversation_log().read().clone();
 
     rsx! {
@@ -1102,6 +1258,27 @@ fn ViewAsCharacterMode(props: ViewAsCharacterModeProps) -> Element {
                         }
                     }
 
+                    // Crowds in the region
+                    if !crowds_present.is_empty() {
+                        div {
+                            class: "bg-dark-surface rounded-lg p-4",
+
+                            h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Crowds" }
+
+                            div {
+                                class: "flex flex-col gap-2",
+                                for crowd in crowds_present.iter() {
+                                    div {
+                                        key: "{crowd.crowd_id}",
+                                        class: "flex items-center gap-2 p-2 bg-dark-bg rounded",
+                                        span { class: "text-white flex-1", "{crowd.name}" }
+                                        span { class: "text-gray-400 text-xs", "{crowd.size_label} · {crowd.disposition.display_name()}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
                     // Items visible in the region
                     div {
                         class: "bg-dark-surface rounded-lg p-4",
@@ -1143,3 +1320,14 @@ fn ViewAsCharacterMode(props: ViewAsCharacterModeProps) -> Element {
         }
     }
 }
+
+/// Names in a log line that could be promoted to NPCs: capitalized names
+/// that aren't the speaker, a known character or an existing draft
+fn mentioned_names(speaker: &str, text: &str, is_system: bool, known: &[String]) -> Vec<String> {
+    if is_system {
+        return Vec::new();
+    }
+    let mut known: Vec<&str> = known.iter().map(String::as_str).collect();
+    known.push(speaker);
+    unknown_names_in(text, &known)
+}
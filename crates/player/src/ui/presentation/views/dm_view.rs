@@ -6,6 +6,7 @@ use crate::presentation::components::creator::CreatorMode;
 use crate::presentation::components::dm_panel::adhoc_challenge_modal::{
     AdHocChallengeData, AdHocChallengeModal,
 };
+use crate::presentation::components::dm_panel::DependencyStatusBanner;
 use crate::presentation::components::settings::SettingsView;
 use crate::presentation::views::director::DirectorModeContent;
 use crate::presentation::views::story_arc::StoryArcContent;
@@ -49,6 +50,8 @@ pub fn DMView(props: DMViewProps) -> Element {
         div {
             class: "dm-view h-full flex flex-col bg-dark-bg",
 
+            DependencyStatusBanner {}
+
             // Content area - no header, tabs are in main AppHeader
             div {
                 class: "dm-content flex-1 overflow-hidden",
@@ -6,6 +6,9 @@ pub mod main_menu;
 pub mod pc_creation;
 pub mod pc_view;
 pub mod role_select;
+pub mod session_zero;
+pub mod setup_wizard;
 pub mod spectator_view;
 pub mod story_arc;
+pub mod successor;
 pub mod world_select;
@@ -1,16 +1,25 @@
 //! PC Creation View - Multi-step form for creating a player character
 
 use dioxus::prelude::*;
+use serde_json::Value;
 use std::collections::HashMap;
+use wrldbldr_domain::CharacterSheetSchema;
 
 use crate::infrastructure::spawn_task;
 
 use crate::application::dto::{FieldValue, SheetTemplate};
-use crate::application::services::CreatePlayerCharacterRequest;
+use crate::application::services::{
+    sheet_data_from_values, CreatePlayerCharacterRequest, WorldSheetSchema,
+};
+use crate::presentation::components::schema_sheet::{
+    ordered_steps, step_problems, AllocationMethod, CreationReview, SchemaCreationStep,
+};
 use crate::presentation::services::{
-    use_location_service, use_player_character_service, use_world_service,
+    use_character_sheet_service, use_location_service, use_player_character_service,
+    use_world_service,
 };
 use crate::presentation::state::use_session_state;
+use crate::presentation::utils::sheet_validation::is_empty_value;
 use crate::use_platform;
 
 /// Wizard step enum
@@ -19,6 +28,8 @@ enum CreationStep {
     #[default]
     Basics,
     CharacterSheet,
+    /// Game system creation step, by position in the schema's ordered steps
+    SystemStep(usize),
     StartingLocation,
     Review,
 }
@@ -38,6 +49,7 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
     let pc_service = use_player_character_service();
     let location_service = use_location_service();
     let world_service = use_world_service();
+    let sheet_service = use_character_sheet_service();
 
     // Step tracking
     let mut current_step = use_signal(|| CreationStep::Basics);
@@ -51,6 +63,14 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
     let mut sheet_values: Signal<HashMap<String, FieldValue>> = use_signal(HashMap::new);
     let mut sheet_loading = use_signal(|| false);
 
+    // Game system creation steps, used instead of the template when the
+    // world's system defines them
+    let mut world_schema: Signal<Option<WorldSheetSchema>> = use_signal(|| None);
+    let mut schema_values: Signal<HashMap<String, Value>> = use_signal(HashMap::new);
+    let mut schema_errors: Signal<HashMap<String, String>> = use_signal(HashMap::new);
+    let mut allocation_methods: Signal<HashMap<String, AllocationMethod>> =
+        use_signal(HashMap::new);
+
     // Form state - Step 3: Starting Location
     let mut available_locations: Signal<Vec<crate::application::services::LocationSummary>> =
         use_signal(Vec::new);
@@ -92,6 +112,44 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
         });
     }
 
+    // Load the game system schema
+    {
+        let world_id = props.world_id.clone();
+        let sheet_svc = sheet_service.clone();
+        let platform_clone = platform.clone();
+        use_effect(move || {
+            let svc = sheet_svc.clone();
+            let world_id_clone = world_id.clone();
+            let plat = platform_clone.clone();
+            spawn_task(async move {
+                match svc.get_world_schema(&world_id_clone).await {
+                    Ok(sheet) if !sheet.schema.creation_steps.is_empty() => {
+                        schema_values.set(sheet.defaults.clone());
+                        world_schema.set(Some(sheet));
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        // Falls back to the sheet template step
+                        plat.log_warn(&format!("Failed to load game system schema: {}", e));
+                    }
+                }
+            });
+        });
+    }
+
+    let system_steps = world_schema
+        .read()
+        .as_ref()
+        .map(|sheet| ordered_steps(&sheet.schema))
+        .unwrap_or_default();
+    let schema_has_name = world_schema.read().as_ref().is_some_and(|sheet| {
+        sheet
+            .schema
+            .sections
+            .iter()
+            .any(|s| s.fields.iter().any(|f| f.id == "NAME"))
+    });
+
     // Load available locations
     {
         let world_id = props.world_id.clone();
@@ -117,6 +175,7 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
     }
 
     // Navigation handlers
+    let next_steps = system_steps.clone();
     let go_next = move |_| {
         let step = *current_step.read();
         match step {
@@ -125,11 +184,48 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
                     error_message.set(Some("Character name is required".to_string()));
                     return;
                 }
-                current_step.set(CreationStep::CharacterSheet);
+                if next_steps.is_empty() {
+                    current_step.set(CreationStep::CharacterSheet);
+                } else {
+                    // Carry the name into the sheet unless it's already set
+                    if schema_has_name
+                        && schema_values.read().get("NAME").is_none_or(is_empty_value)
+                    {
+                        let name_val = name.read().trim().to_string();
+                        schema_values
+                            .write()
+                            .insert("NAME".to_string(), Value::String(name_val));
+                    }
+                    current_step.set(CreationStep::SystemStep(0));
+                }
             }
             CreationStep::CharacterSheet => {
                 current_step.set(CreationStep::StartingLocation);
             }
+            CreationStep::SystemStep(i) => {
+                let Some(sheet) = world_schema.read().clone() else {
+                    return;
+                };
+                let Some(system_step) = next_steps.get(i) else {
+                    return;
+                };
+                let method = allocation_methods.read().get(&system_step.id).copied();
+                let (errors, allocation) =
+                    step_problems(&sheet.schema, system_step, &schema_values.read(), method);
+                let blocked = !errors.is_empty() || allocation.is_some();
+                schema_errors.set(errors);
+                if blocked {
+                    error_message.set(Some(allocation.unwrap_or_else(|| {
+                        "Some fields need attention before you continue".to_string()
+                    })));
+                    return;
+                }
+                current_step.set(if i + 1 < next_steps.len() {
+                    CreationStep::SystemStep(i + 1)
+                } else {
+                    CreationStep::StartingLocation
+                });
+            }
             CreationStep::StartingLocation => {
                 if selected_location_id.read().is_none() {
                     error_message.set(Some("Please select a starting location".to_string()));
@@ -144,22 +240,30 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
         error_message.set(None);
     };
 
+    let step_count = system_steps.len();
     let go_back = move |_| {
         let step = *current_step.read();
         match step {
             CreationStep::Basics => {
                 navigator.go_back();
             }
-            CreationStep::CharacterSheet => {
+            CreationStep::CharacterSheet | CreationStep::SystemStep(0) => {
                 current_step.set(CreationStep::Basics);
             }
+            CreationStep::SystemStep(i) => {
+                current_step.set(CreationStep::SystemStep(i - 1));
+            }
             CreationStep::StartingLocation => {
-                current_step.set(CreationStep::CharacterSheet);
+                current_step.set(match step_count {
+                    0 => CreationStep::CharacterSheet,
+                    n => CreationStep::SystemStep(n - 1),
+                });
             }
             CreationStep::Review => {
                 current_step.set(CreationStep::StartingLocation);
             }
         }
+        schema_errors.set(HashMap::new());
         error_message.set(None);
     };
 
@@ -168,6 +272,10 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
         let _desc_val = description.read().clone();
         let location_id = selected_location_id.read().clone();
         let sheet_vals = sheet_values.read().clone();
+        let system_vals = world_schema
+            .read()
+            .is_some()
+            .then(|| schema_values.read().clone());
         let pc_svc = pc_service.clone();
         let nav = navigator;
         let world_id = props.world_id.clone();
@@ -187,7 +295,9 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
 
         spawn_task(async move {
             // Convert sheet values to JSON if present
-            let sheet_data = if sheet_vals.is_empty() {
+            let sheet_data = if let Some(values) = system_vals {
+                Some(sheet_data_from_values(&values))
+            } else if sheet_vals.is_empty() {
                 None
             } else {
                 serde_json::to_value(&sheet_vals).ok()
@@ -224,6 +334,29 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
         });
     };
 
+    // Wizard steps in order, with the current position
+    let mut wizard_steps = vec![(CreationStep::Basics, "Basics".to_string())];
+    if system_steps.is_empty() {
+        wizard_steps.push((CreationStep::CharacterSheet, "Character Sheet".to_string()));
+    } else {
+        wizard_steps.extend(
+            system_steps
+                .iter()
+                .enumerate()
+                .map(|(i, s)| (CreationStep::SystemStep(i), s.label.clone())),
+        );
+    }
+    wizard_steps.push((
+        CreationStep::StartingLocation,
+        "Starting Location".to_string(),
+    ));
+    wizard_steps.push((CreationStep::Review, "Review".to_string()));
+    let current_index = wizard_steps
+        .iter()
+        .position(|(s, _)| *s == *current_step.read())
+        .unwrap_or(0);
+    let step_labels: Vec<String> = wizard_steps.into_iter().map(|(_, l)| l).collect();
+
     rsx! {
         div {
             class: "h-screen flex flex-col bg-dark-bg text-white",
@@ -243,30 +376,16 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
 
             // Progress indicator
             div {
-                class: "flex gap-2 px-6 py-4 bg-black/20 border-b border-gray-700",
-                StepIndicator {
-                    number: 1,
-                    label: "Basics",
-                    is_active: *current_step.read() == CreationStep::Basics,
-                    is_complete: *current_step.read() != CreationStep::Basics,
-                }
-                StepIndicator {
-                    number: 2,
-                    label: "Character Sheet",
-                    is_active: *current_step.read() == CreationStep::CharacterSheet,
-                    is_complete: matches!(*current_step.read(), CreationStep::StartingLocation | CreationStep::Review),
-                }
-                StepIndicator {
-                    number: 3,
-                    label: "Starting Location",
-                    is_active: *current_step.read() == CreationStep::StartingLocation,
-                    is_complete: *current_step.read() == CreationStep::Review,
-                }
-                StepIndicator {
-                    number: 4,
-                    label: "Review",
-                    is_active: *current_step.read() == CreationStep::Review,
-                    is_complete: false,
+                class: "flex flex-wrap gap-2 px-6 py-4 bg-black/20 border-b border-gray-700",
+                for (i, label) in step_labels.iter().enumerate() {
+                    StepIndicator {
+                        key: "{i}",
+                        number: (i + 1) as u8,
+                        label: label.clone(),
+                        is_active: i == current_index,
+                        is_complete: i < current_index,
+                        is_last: i + 1 == step_labels.len(),
+                    }
                 }
             }
 
@@ -299,6 +418,33 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
                             on_values_change: move |v| sheet_values.set(v),
                         }
                     },
+                    CreationStep::SystemStep(i) => {
+                        match (world_schema.read().clone(), system_steps.get(i).cloned()) {
+                            (Some(sheet), Some(step)) => {
+                                let step_id = step.id.clone();
+                                rsx! {
+                                    div {
+                                        class: "max-w-[800px] mx-auto",
+                                        SchemaCreationStep {
+                                            schema: sheet.schema,
+                                            method: allocation_methods.read().get(&step.id).copied(),
+                                            step,
+                                            values: schema_values.read().clone(),
+                                            errors: schema_errors.read().clone(),
+                                            on_method_change: move |method| {
+                                                allocation_methods.write().insert(step_id.clone(), method);
+                                            },
+                                            on_change: move |(field_id, value): (String, Value)| {
+                                                schema_errors.write().remove(&field_id);
+                                                schema_values.write().insert(field_id, value);
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                            _ => rsx! {},
+                        }
+                    }
                     CreationStep::StartingLocation => rsx! {
                         StartingLocationStep {
                             locations: available_locations.read().clone(),
@@ -315,7 +461,9 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
                                 .find(|l| l.id == selected_location_id.read().as_ref().map(|s| s.as_str()).unwrap_or(""))
                                 .map(|l| l.name.clone())
                                 .unwrap_or_default(),
-                            has_sheet: !sheet_values.read().is_empty(),
+                            has_sheet: !sheet_values.read().is_empty() || !schema_values.read().is_empty(),
+                            schema: world_schema.read().as_ref().map(|sheet| sheet.schema.clone()),
+                            schema_values: schema_values.read().clone(),
                         }
                     },
                 }
@@ -361,7 +509,13 @@ pub fn PCCreationView(props: PCCreationProps) -> Element {
 
 /// Step indicator component
 #[component]
-fn StepIndicator(number: u8, label: &'static str, is_active: bool, is_complete: bool) -> Element {
+fn StepIndicator(
+    number: u8,
+    label: String,
+    is_active: bool,
+    is_complete: bool,
+    is_last: bool,
+) -> Element {
     // Extract conditional classes before rsx! block
     let bg_classes = if is_active || is_complete {
         "w-8 h-8 rounded-full flex items-center justify-center text-sm text-white font-semibold bg-blue-500"
@@ -388,7 +542,7 @@ fn StepIndicator(number: u8, label: &'static str, is_active: bool, is_complete:
                 class: "{text_classes}",
                 "{label}"
             }
-            if number < 4 {
+            if !is_last {
                 div {
                     class: "w-[60px] h-0.5 bg-gray-700 mx-2",
                 }
@@ -586,6 +740,9 @@ struct ReviewStepProps {
     description: String,
     location: String,
     has_sheet: bool,
+    /// Game system schema, to summarize the choices made in its steps
+    schema: Option<CharacterSheetSchema>,
+    schema_values: HashMap<String, Value>,
 }
 
 #[component]
@@ -636,12 +793,19 @@ fn ReviewStep(props: ReviewStepProps) -> Element {
                         class: "text-gray-400 text-sm mb-1",
                         "Character Sheet"
                     }
-                    div {
-                        class: "text-white text-sm",
-                        if props.has_sheet {
-                            "✓ Configured"
-                        } else {
-                            "Not configured"
+                    if let Some(schema) = props.schema.clone() {
+                        CreationReview {
+                            schema,
+                            values: props.schema_values.clone(),
+                        }
+                    } else {
+                        div {
+                            class: "text-white text-sm",
+                            if props.has_sheet {
+                                "✓ Configured"
+                            } else {
+                                "Not configured"
+                            }
                         }
                     }
                 }
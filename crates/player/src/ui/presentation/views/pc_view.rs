@@ -9,32 +9,53 @@ use std::collections::HashMap;
 use crate::application::dto::InventoryItemData;
 use crate::infrastructure::spawn_task;
 use crate::application::dto::{
-    DiceInput, FieldValue, InteractionData, PlayerAction, SheetTemplate,
+    DiceInput, FieldValue, InteractionData, LightLevelData, PlayerAction, SheetTemplate,
 };
 use crate::presentation::components::action_panel::ActionPanel;
 use crate::presentation::components::character_sheet_viewer::CharacterSheetViewer;
-use crate::presentation::components::event_overlays::{ApproachEventOverlay, LocationEventBanner};
+use crate::presentation::components::event_overlays::{
+    ApproachEventOverlay, BroadsheetOverlay, HandoutOverlay, LocationEventBanner,
+};
 use crate::presentation::components::inventory_panel::InventoryPanel;
 use crate::presentation::components::known_npcs_panel::{KnownNpcsPanel, NpcObservationData};
+use crate::presentation::components::map_markers::MarkerLayer;
 use crate::presentation::components::mini_map::{MapBounds, MapRegionData, MiniMap};
+use crate::presentation::components::world_map::WorldMapModal;
 use crate::presentation::components::navigation_panel::NavigationPanel;
+use crate::presentation::components::dice_tray::DiceTray;
+use crate::presentation::components::pacing_timer::PacingTimerBadge;
+use crate::presentation::components::notification_center::NotificationCenter;
+use crate::presentation::components::offline_status::OfflineStatus;
+use crate::presentation::components::pc_switcher::PcSwitcher;
+use crate::presentation::components::region_hotspots::HotspotLayer;
 use crate::presentation::components::region_items_panel::RegionItemsPanel;
+use crate::presentation::components::safety_card::SafetyCard;
+use crate::presentation::components::secrets_card::SecretsCard;
+use crate::presentation::components::mailbox_card::MailboxCard;
 use crate::presentation::components::tactical::{
     ChallengeRollModal, PlayerSkillData, SkillsDisplay,
 };
+use crate::presentation::components::trade_panel::{
+    TradeDraft, TradeOfferDialog, TradeOffersTray, TradePartner,
+};
+use crate::presentation::components::tutorial_guide::TutorialGuide;
 use crate::presentation::components::visual_novel::{
     Backdrop, CharacterLayer, DialogueBox, EmptyDialogueBox,
 };
 use crate::infrastructure::messaging::CommandBus;
 use crate::infrastructure::websocket::ClientMessageBuilder;
 use crate::presentation::services::{
-    use_character_service, use_command_bus, use_location_service, use_observation_service,
-    use_skill_service, use_world_service,
+    use_character_service, use_command_bus, use_flashback_service, use_location_service,
+    use_observation_service, use_parallel_scene_service, use_player_character_service,
+    use_pacing_service, use_skill_service, use_spotlight_service, use_world_service,
 };
 use crate::presentation::state::{
-    use_dialogue_state, use_game_state, use_session_state, use_typewriter_effect,
-    RollSubmissionStatus,
+    use_dialogue_state, use_game_state, use_offline_state, use_session_state,
+    use_typewriter_effect, GameState, OfflineState, RollSubmissionStatus, SessionState,
 };
+use crate::Platform;
+use wrldbldr_protocol::types::{HotspotData, HotspotTargetData, LoudnessData, SharedKnowledgeData};
+use wrldbldr_protocol::MarkerLinkData;
 
 /// Player Character View - visual novel gameplay interface
 ///
@@ -45,9 +66,17 @@ pub fn PCView() -> Element {
     let game_state = use_game_state();
     let mut dialogue_state = use_dialogue_state();
     let session_state = use_session_state();
+    let navigator = use_navigator();
 
     // Get command bus for sending messages
     let command_bus = use_command_bus();
+    let actions = ActionSender {
+        command_bus: command_bus.clone(),
+        session_state: session_state.clone(),
+        game_state: game_state.clone(),
+        offline_state: use_offline_state(),
+        platform: crate::use_platform(),
+    };
 
     // Get services
     let world_service = use_world_service();
@@ -55,6 +84,11 @@ pub fn PCView() -> Element {
     let observation_service = use_observation_service();
     let location_service = use_location_service();
     let skill_service = use_skill_service();
+    let player_character_service = use_player_character_service();
+    let flashback_service = use_flashback_service();
+    let parallel_scene_service = use_parallel_scene_service();
+    let spotlight_service = use_spotlight_service();
+    let pacing_service = use_pacing_service();
 
     // Character sheet viewer state
     let mut show_character_sheet = use_signal(|| false);
@@ -72,13 +106,20 @@ pub fn PCView() -> Element {
     let mut inventory_items: Signal<Vec<InventoryItemData>> = use_signal(Vec::new);
     let mut is_loading_inventory = use_signal(|| false);
 
+    // Trade state: PCs sharing the region, and the offer being built
+    let mut trade_partners: Signal<Vec<TradePartner>> = use_signal(Vec::new);
+    let mut trade_draft: Signal<Option<(TradePartner, Option<String>)>> = use_signal(|| None);
+
     // Known NPCs panel state
     let mut show_known_npcs_panel = use_signal(|| false);
     let mut known_npcs: Signal<Vec<NpcObservationData>> = use_signal(Vec::new);
     let mut is_loading_npcs = use_signal(|| false);
+    // Other PCs in the world, who can be told what this PC knows
+    let mut party_pc_ids: Signal<Vec<String>> = use_signal(Vec::new);
 
     // Mini-map state
     let mut show_mini_map = use_signal(|| false);
+    let mut show_world_map = use_signal(|| false);
 
     // Backdrop transition effect - auto-clear after animation completes
     {
@@ -114,6 +155,30 @@ pub fn PCView() -> Element {
     // Run typewriter effect
     use_typewriter_effect(&mut dialogue_state);
 
+    // Reload the open inventory after equips, pickups, and trades
+    let inventory_refresh = *game_state.inventory_refresh_counter.read();
+    {
+        let is_panel_open = *show_inventory_panel.read();
+        let char_id = selected_character_id.read().clone();
+        let char_svc = character_service.clone();
+
+        use_effect(move || {
+            let _ = inventory_refresh;
+
+            if is_panel_open {
+                if let Some(cid) = char_id.clone() {
+                    let char_svc = char_svc.clone();
+                    spawn_task(async move {
+                        match char_svc.get_inventory(&cid).await {
+                            Ok(items) => inventory_items.set(items),
+                            Err(e) => tracing::warn!("Failed to refresh inventory: {}", e),
+                        }
+                    });
+                }
+            }
+        });
+    }
+
     // Auto-refresh observations when refresh counter changes (if panel is open)
     // Track the refresh counter - this will trigger re-render when it changes
     let observations_refresh = *game_state.observations_refresh_counter.read();
@@ -159,6 +224,27 @@ pub fn PCView() -> Element {
         });
     }
 
+    // Load tutorial progress once the PC is known (tutorial worlds only)
+    {
+        let game_state = game_state.clone();
+        let world_svc = world_service.clone();
+        use_effect(move || {
+            let pc_id = game_state.selected_pc_id.read().clone();
+            let world_id = game_state.world.read().as_ref().map(|w| w.world.id.clone());
+            if let (Some(pid), Some(wid)) = (pc_id, world_id) {
+                let world_svc = world_svc.clone();
+                let mut game_state = game_state.clone();
+                spawn_task(async move {
+                    match world_svc.get_tutorial_status(&wid, &pid).await {
+                        Ok(Some(status)) => game_state.set_tutorial_status(status),
+                        Ok(None) => {}
+                        Err(e) => tracing::warn!("Failed to load tutorial status: {}", e),
+                    }
+                });
+            }
+        });
+    }
+
     // Read scene characters from game state (reactive)
     let scene_characters = game_state.scene_characters.read().clone();
 
@@ -169,10 +255,124 @@ pub fn PCView() -> Element {
     let choices = dialogue_state.choices.read().clone();
     let has_dialogue = dialogue_state.has_dialogue();
     let is_llm_processing = *dialogue_state.is_llm_processing.read();
+    let pending_action_id = dialogue_state.pending_action_id.read().clone();
 
     // Get interactions from game state
     let interactions = game_state.interactions.read().clone();
 
+    // A flashback may already be running when the player joins; later
+    // changes arrive as FlashbackChanged
+    {
+        let service = flashback_service.clone();
+        let game_state = game_state.clone();
+        let session_state = session_state.clone();
+        use_effect(move || {
+            if !*session_state.connection.joined.read() {
+                return;
+            }
+            let service = service.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service.get_flashback().await {
+                    Ok(flashback) => game_state.set_flashback(flashback),
+                    Err(e) => tracing::warn!("Failed to load flashback: {}", e),
+                }
+            });
+        });
+    }
+
+    // Likewise the party may already be split; later changes arrive as
+    // ParallelScenesChanged
+    {
+        let service = parallel_scene_service.clone();
+        let game_state = game_state.clone();
+        let session_state = session_state.clone();
+        use_effect(move || {
+            if !*session_state.connection.joined.read() {
+                return;
+            }
+            let service = service.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service.list_scenes().await {
+                    Ok(scenes) => game_state.set_parallel_scenes(scenes),
+                    Err(e) => tracing::warn!("Failed to load parallel scenes: {}", e),
+                }
+            });
+        });
+    }
+
+    // And the DM may already have turned the spotlight to someone
+    {
+        let service = spotlight_service.clone();
+        let game_state = game_state.clone();
+        let session_state = session_state.clone();
+        use_effect(move || {
+            if !*session_state.connection.joined.read() {
+                return;
+            }
+            let service = service.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service.get_spotlight().await {
+                    Ok(spotlight) => game_state.set_spotlight(spotlight),
+                    Err(e) => tracing::warn!("Failed to load the spotlight: {}", e),
+                }
+            });
+        });
+    }
+
+    // Or started a pacing timer
+    {
+        let service = pacing_service.clone();
+        let platform = crate::use_platform();
+        let game_state = game_state.clone();
+        let session_state = session_state.clone();
+        use_effect(move || {
+            if !*session_state.connection.joined.read() {
+                return;
+            }
+            let service = service.clone();
+            let platform = platform.clone();
+            let mut game_state = game_state.clone();
+            spawn_task(async move {
+                match service.get_timer().await {
+                    Ok(timer) => game_state.set_pacing_timer(timer, platform.now_millis()),
+                    Err(e) => tracing::warn!("Failed to load the pacing timer: {}", e),
+                }
+            });
+        });
+    }
+
+    // Cooldowns, uses left and time of day decide which interactions a PC
+    // can use, so refetch them when the PC moves, time passes or one is used
+    let mut interactions_refresh = use_signal(|| 0u32);
+    {
+        let pc_svc = player_character_service.clone();
+        let game_state = game_state.clone();
+        let session_state = session_state.clone();
+        use_effect(move || {
+            let _ = interactions_refresh.read();
+            let _ = game_state.game_time.read();
+            let in_region = game_state.current_region.read().is_some();
+            let pc_id = game_state.selected_pc_id.read().clone();
+            if !*session_state.connection.joined.read() || !in_region {
+                return;
+            }
+            let Some(pc_id) = pc_id else {
+                return;
+            };
+            let pc_svc = pc_svc.clone();
+            let mut interactions = game_state.interactions;
+            spawn_task(async move {
+                match pc_svc.list_available_interactions(&pc_id).await {
+                    Ok(list) => interactions.set(list),
+                    Err(e) => tracing::warn!("Failed to refresh interactions: {}", e),
+                }
+            });
+        });
+    }
+
     // Get active challenge if any
     let active_challenge = session_state.active_challenge().read().clone();
 
@@ -186,15 +386,113 @@ pub fn PCView() -> Element {
     let current_region = game_state.current_region.read().clone();
     let navigation = game_state.navigation.read().clone();
     let selected_pc_id = game_state.selected_pc_id.read().clone();
+    let world_id = game_state.world.read().as_ref().map(|w| w.world.id.clone());
+    // A fast-travel journey of this PC stopped by an encounter
+    let stopped_journey = game_state
+        .stopped_journeys
+        .read()
+        .iter()
+        .find(|j| selected_pc_id.as_deref() == Some(j.pc_id.as_str()))
+        .cloned();
+
+    // Following a map pin's link acts on the linked entity
+    let open_marker_link = {
+        let command_bus = command_bus.clone();
+        let actions = actions.clone();
+        let selected_pc_id = selected_pc_id.clone();
+        move |link: MarkerLinkData| {
+            let result = match link {
+                MarkerLinkData::Region(region_id) => match selected_pc_id.as_ref() {
+                    Some(pc) => send_move_to_region(&command_bus, pc, &region_id),
+                    None => Err("No character selected".to_string()),
+                },
+                MarkerLinkData::Character(character_id) => {
+                    send_player_action(&actions, PlayerAction::talk(&character_id, None))
+                }
+                MarkerLinkData::Item(item_id) => {
+                    send_player_action(&actions, PlayerAction::examine(&item_id))
+                }
+                MarkerLinkData::Location(_) | MarkerLinkData::NarrativeEvent(_) => Ok(()),
+            };
+            if let Err(e) = result {
+                action_error.set(Some(e));
+            }
+        }
+    };
+
+    // Clicking a backdrop hotspot stands in for typing the action
+    let activate_hotspot = {
+        let command_bus = command_bus.clone();
+        let actions = actions.clone();
+        let selected_pc_id = selected_pc_id.clone();
+        let interactions = interactions.clone();
+        move |hotspot: HotspotData| {
+            let result = match hotspot.target {
+                HotspotTargetData::Interaction { interaction_id } => {
+                    match interactions.iter().find(|i| i.id == interaction_id) {
+                        Some(interaction) => handle_interaction(&actions, interaction),
+                        None => send_player_action(
+                            &actions,
+                            PlayerAction::custom_targeted(&interaction_id, &hotspot.label),
+                        ),
+                    }
+                }
+                HotspotTargetData::Item { item_id } => {
+                    send_player_action(&actions, PlayerAction::examine(&item_id))
+                }
+                HotspotTargetData::Exit { region_id } => match selected_pc_id.as_ref() {
+                    Some(pc) => send_move_to_region(&command_bus, pc, &region_id),
+                    None => Err("No character selected".to_string()),
+                },
+                HotspotTargetData::LocationExit {
+                    location_id,
+                    arrival_region_id,
+                } => match selected_pc_id.as_ref() {
+                    Some(pc) => send_exit_to_location(
+                        &command_bus,
+                        pc,
+                        &location_id,
+                        arrival_region_id.as_deref(),
+                    ),
+                    None => Err("No character selected".to_string()),
+                },
+            };
+            if let Err(e) = result {
+                action_error.set(Some(e));
+            }
+        }
+    };
 
     // Get event data from game state
     let approach_event = game_state.approach_event.read().clone();
     let location_event = game_state.location_event.read().clone();
+    let overlay = game_state.overlay.read().clone();
+    let broadsheet_handout = game_state.broadsheet_handout.read().clone();
+    let flashback = game_state.flashback.read().clone();
+    // With the party split, show this PC's group and whether the DM is there
+    let (my_scene, dm_elsewhere) = match selected_pc_id.as_deref() {
+        Some(pc_id) => (
+            game_state.parallel_scene_of(pc_id),
+            game_state.dm_is_elsewhere(pc_id),
+        ),
+        None => (None, false),
+    };
+    let has_spotlight = selected_pc_id
+        .as_deref()
+        .is_some_and(|pc_id| game_state.has_spotlight(pc_id));
 
     rsx! {
         div {
             class: "pc-view h-full flex flex-col relative",
 
+            // Flashback banner (top centre)
+            if let Some(flashback) = flashback {
+                div {
+                    class: "absolute top-4 left-1/2 -translate-x-1/2 z-[100] px-4 py-2 bg-amber-900/80 text-amber-100 rounded-lg text-sm italic",
+                    "Flashback: {flashback.title}"
+                }
+            }
+
             // Location and status indicator (top right)
             div {
                 class: "absolute top-4 right-4 z-[100] flex flex-col gap-2 items-end",
@@ -209,6 +507,12 @@ pub fn PCView() -> Element {
                         class: "px-3 py-1 bg-black/50 text-gray-300 rounded-lg text-xs",
                         "{region.location_name}"
                     }
+                    if let Some(light) = light_badge(region.light_level) {
+                        div {
+                            class: "px-3 py-1 bg-black/50 text-gray-300 rounded-lg text-xs",
+                            "{light}"
+                        }
+                    }
                 } else if let Some(scene) = game_state.current_scene.read().as_ref() {
                     div {
                         class: "px-4 py-2 bg-black/70 text-white rounded-lg text-sm font-medium",
@@ -216,6 +520,36 @@ pub fn PCView() -> Element {
                     }
                 }
 
+                if let Some(scene) = my_scene {
+                    div {
+                        class: "px-3 py-1 bg-purple-900/70 text-purple-100 rounded-lg text-xs",
+                        "{scene.name}"
+                    }
+                }
+                if dm_elsewhere {
+                    div {
+                        class: "px-3 py-1 bg-black/50 text-gray-400 rounded-lg text-xs italic",
+                        "The DM is with another group"
+                    }
+                }
+                if has_spotlight {
+                    div {
+                        class: "px-3 py-1 bg-amber-800/80 text-amber-100 rounded-lg text-xs font-medium",
+                        "The spotlight is on you"
+                    }
+                }
+                PacingTimerBadge {}
+
+                // Crowds filling the region, shown as a group rather than individuals
+                for crowd in game_state.crowds_present.read().iter() {
+                    div {
+                        key: "{crowd.crowd_id}",
+                        title: "{crowd.description}",
+                        class: "px-3 py-1 bg-black/50 text-gray-300 rounded-lg text-xs",
+                        "👥 {crowd.name} · {crowd.size_label} · {crowd.disposition.display_name()}"
+                    }
+                }
+
                 // Connection status
                 if !is_connected {
                     div {
@@ -224,6 +558,88 @@ pub fn PCView() -> Element {
                     }
                 }
 
+                // Actions queued while offline and any held back on reconnect
+                OfflineStatus {}
+
+                // Solo or duet play: switch between controlled PCs
+                PcSwitcher {}
+
+                // The last action can be taken back until the NPC answers it
+                if let Some(action_id) = pending_action_id.clone() {
+                    div {
+                        class: "px-4 py-2 bg-black/70 text-white rounded-lg text-xs flex items-center gap-2",
+                        span { "Waiting for a reply" }
+                        button {
+                            class: "px-2 py-1 bg-gray-600 text-white border-0 rounded cursor-pointer text-xs",
+                            onclick: {
+                                let command_bus = command_bus.clone();
+                                move |_| {
+                                    let msg = ClientMessageBuilder::retract_action(&action_id);
+                                    if let Err(e) = command_bus.send(msg) {
+                                        action_error
+                                            .set(Some(format!("Failed to retract action: {}", e)));
+                                    }
+                                }
+                            },
+                            "Take it back"
+                        }
+                    }
+                }
+
+                // Paused by the DM (e.g. after a safety signal)
+                if *game_state.action_queue_paused.read() {
+                    div {
+                        class: "px-4 py-2 bg-amber-600/80 text-white rounded-lg text-xs",
+                        "Play paused"
+                    }
+                }
+
+                // On the road, waiting for the DM to settle an encounter
+                if let Some(journey) = stopped_journey.as_ref() {
+                    div {
+                        class: "px-4 py-2 bg-amber-700/80 text-white rounded-lg text-xs max-w-[250px]",
+                        "Journey to {journey.to_location_name} stopped"
+                        if let Some(encounter) = journey.encounter.as_ref() {
+                            ": {encounter.name}"
+                        }
+                        " — waiting for the DM"
+                    }
+                }
+
+                // The PC has died; play continues through a successor
+                if let Some(death) = game_state.pc_death.read().as_ref() {
+                    div {
+                        class: "px-4 py-2 bg-gray-900/90 text-white rounded-lg text-xs flex items-center gap-2 max-w-[250px]",
+                        span { "{death.name} has fallen" }
+                        if let Some(wid) = world_id.clone() {
+                            button {
+                                class: "px-2 py-1 bg-blue-500 text-white border-0 rounded cursor-pointer text-xs",
+                                onclick: move |_| {
+                                    navigator.push(crate::routes::Route::PCSuccessorRoute {
+                                        world_id: wid.clone(),
+                                    });
+                                },
+                                "Create successor"
+                            }
+                        }
+                    }
+                }
+
+                // Items, lore, and time changes the player can look back over
+                NotificationCenter {}
+
+                // Free-form dice rolls, resolved by the Engine
+                DiceTray {}
+
+                // Anonymous safety signal
+                SafetyCard {}
+
+                // What only this player and the DM know
+                SecretsCard {}
+
+                // Letters to and from the PC
+                MailboxCard {}
+
                 // Action error feedback (click to dismiss)
                 if let Some(ref err) = *action_error.read() {
                     div {
@@ -239,16 +655,25 @@ pub fn PCView() -> Element {
                 image_url: game_state.backdrop_url(),
                 transitioning: *game_state.backdrop_transitioning.read(),
 
+                // Clickable areas the DM outlined on this backdrop
+                if let Some(region) = current_region.as_ref() {
+                    HotspotLayer {
+                        hotspots: region.hotspots.clone(),
+                        disabled: is_llm_processing,
+                        on_activate: activate_hotspot,
+                    }
+                }
+
                 // Character layer with real scene characters
                 CharacterLayer {
                     characters: scene_characters,
                     on_character_click: {
-                        let command_bus = command_bus.clone();
+                        let actions = actions.clone();
                         move |character_id: String| {
                             tracing::info!("Clicked character: {}", character_id);
                             // Send a talk action when clicking a character
                             if let Err(e) = send_player_action(
-                                &command_bus,
+                                &actions,
                                 PlayerAction::talk(&character_id, None),
                             ) {
                                 action_error.set(Some(e));
@@ -256,13 +681,23 @@ pub fn PCView() -> Element {
                         }
                     }
                 }
+
+                // DM markers pinned to this region's backdrop
+                if let (Some(world_id), Some(region)) = (world_id.clone(), current_region.as_ref()) {
+                    MarkerLayer {
+                        world_id,
+                        location_id: region.location_id.clone(),
+                        region_id: Some(region.id.clone()),
+                        on_open: open_marker_link.clone(),
+                    }
+                }
             }
 
             // Dialogue box (fixed at bottom)
             div {
                 class: "dialogue-container absolute bottom-0 left-0 right-0 z-10",
 
-                if has_dialogue {
+                if has_dialogue || is_llm_processing {
                     DialogueBox {
                         speaker_name: speaker_name,
                         dialogue_text: displayed_text,
@@ -270,19 +705,19 @@ pub fn PCView() -> Element {
                         is_llm_processing: is_llm_processing,
                         choices: choices,
                         on_choice_selected: {
-                            let command_bus = command_bus.clone();
+                            let actions = actions.clone();
                             let mut dialogue_state = dialogue_state.clone();
                             move |choice_id: String| {
-                                if let Err(e) = handle_choice_selected(&command_bus, &mut dialogue_state, &choice_id) {
+                                if let Err(e) = handle_choice_selected(&actions, &mut dialogue_state, &choice_id) {
                                     action_error.set(Some(e));
                                 }
                             }
                         },
                         on_custom_input: {
-                            let command_bus = command_bus.clone();
+                            let actions = actions.clone();
                             let mut dialogue_state = dialogue_state.clone();
                             move |text: String| {
-                                if let Err(e) = handle_custom_input(&command_bus, &mut dialogue_state, &text) {
+                                if let Err(e) = handle_custom_input(&actions, &mut dialogue_state, &text) {
                                     action_error.set(Some(e));
                                 }
                             }
@@ -304,16 +739,18 @@ pub fn PCView() -> Element {
                 interactions: interactions,
                 disabled: is_llm_processing,
                 on_interaction: {
-                    let command_bus = command_bus.clone();
+                    let actions = actions.clone();
                     move |interaction: InteractionData| {
-                        if let Err(e) = handle_interaction(&command_bus, &interaction) {
-                            action_error.set(Some(e));
+                        match handle_interaction(&actions, &interaction) {
+                            Ok(()) => *interactions_refresh.write() += 1,
+                            Err(e) => action_error.set(Some(e)),
                         }
                     }
                 },
                 on_inventory: Some(EventHandler::new({
                     let game_state = game_state.clone();
                     let character_service = character_service.clone();
+                    let player_character_service = player_character_service.clone();
                     move |_| {
                         tracing::info!("Open inventory");
                         show_inventory_panel.set(true);
@@ -344,6 +781,26 @@ pub fn PCView() -> Element {
                         } else {
                             is_loading_inventory.set(false);
                         }
+
+                        // Other PCs standing in the same region can be traded with
+                        trade_partners.set(Vec::new());
+                        let world_id = game_state.world.read().as_ref().map(|w| w.world.id.clone());
+                        let my_pc = game_state.selected_pc_id.read().clone();
+                        let region_id = game_state.current_region.read().as_ref().map(|r| r.id.clone());
+                        if let (Some(wid), Some(my_pc), Some(region_id)) = (world_id, my_pc, region_id) {
+                            let pc_svc = player_character_service.clone();
+                            spawn_task(async move {
+                                match pc_svc.list_pcs(&wid).await {
+                                    Ok(pcs) => trade_partners.set(
+                                        pcs.into_iter()
+                                            .filter(|pc| pc.id != my_pc && pc.current_region_id.as_deref() == Some(region_id.as_str()))
+                                            .map(|pc| TradePartner { pc_id: pc.id, name: pc.name })
+                                            .collect(),
+                                    ),
+                                    Err(e) => tracing::warn!("Failed to load trade partners: {}", e),
+                                }
+                            });
+                        }
                     }
                 })),
                 on_character: Some(EventHandler::new({
@@ -401,6 +858,7 @@ pub fn PCView() -> Element {
                 on_map: Some(EventHandler::new({
                     let game_state = game_state.clone();
                     let location_service = location_service.clone();
+                    let character_service = character_service.clone();
                     move |_| {
                         tracing::info!("Open mini-map");
                         show_mini_map.set(true);
@@ -446,12 +904,24 @@ pub fn PCView() -> Element {
                             show_mini_map.set(false);
                             show_navigation_panel.set(true);
                             is_loading_map.set(false);
+
+                            // Carried items can be tried on locked ways
+                            if let Some(pc_id) = game_state.selected_pc_id.read().clone() {
+                                let char_svc = character_service.clone();
+                                spawn_task(async move {
+                                    match char_svc.get_inventory(&pc_id).await {
+                                        Ok(items) => inventory_items.set(items),
+                                        Err(e) => tracing::warn!("Failed to load inventory: {}", e),
+                                    }
+                                });
+                            }
                         }
                     }
                 })),
                 on_people: Some(EventHandler::new({
                     let game_state = game_state.clone();
                     let observation_service = observation_service.clone();
+                    let player_character_service = player_character_service.clone();
                     move |_| {
                         tracing::info!("Open known NPCs panel");
                         show_known_npcs_panel.set(true);
@@ -460,6 +930,23 @@ pub fn PCView() -> Element {
                         // Get the selected PC ID
                         let pc_id = game_state.selected_pc_id.read().clone();
 
+                        party_pc_ids.set(Vec::new());
+                        let world_id = game_state.world.read().as_ref().map(|w| w.world.id.clone());
+                        if let (Some(wid), Some(my_pc)) = (world_id, pc_id.clone()) {
+                            let pc_svc = player_character_service.clone();
+                            spawn_task(async move {
+                                match pc_svc.list_pcs(&wid).await {
+                                    Ok(pcs) => party_pc_ids.set(
+                                        pcs.into_iter()
+                                            .filter(|pc| pc.id != my_pc)
+                                            .map(|pc| pc.id)
+                                            .collect(),
+                                    ),
+                                    Err(e) => tracing::warn!("Failed to load party members: {}", e),
+                                }
+                            });
+                        }
+
                         if let Some(pid) = pc_id {
                             let obs_svc = observation_service.clone();
                             spawn_task(async move {
@@ -716,6 +1203,31 @@ pub fn PCView() -> Element {
                                 }
                             }
                         },
+                        keys: inventory_items.read().clone(),
+                        on_use_key: {
+                            let command_bus = command_bus.clone();
+                            let pc_id = selected_pc_id.clone();
+                            move |(region_id, item_id): (String, String)| {
+                                if let Some(ref pc) = pc_id {
+                                    if let Err(e) = send_unlock_with_key(&command_bus, pc, &item_id, &region_id) {
+                                        action_error.set(Some(e));
+                                    }
+                                } else {
+                                    action_error.set(Some("No character selected".to_string()));
+                                }
+                            }
+                        },
+                        on_make_noise: {
+                            let command_bus = command_bus.clone();
+                            let region_id = current_region.as_ref().map(|r| r.id.clone());
+                            move |(description, loudness): (String, LoudnessData)| {
+                                if let Some(ref region_id) = region_id {
+                                    if let Err(e) = send_make_noise(&command_bus, region_id, &description, loudness) {
+                                        action_error.set(Some(e));
+                                    }
+                                }
+                            }
+                        },
                         on_close: move |_| {
                             show_navigation_panel.set(false);
                         },
@@ -733,11 +1245,11 @@ pub fn PCView() -> Element {
                         show_inventory_panel.set(false);
                     },
                     on_use_item: Some(EventHandler::new({
-                        let command_bus = command_bus.clone();
+                        let actions = actions.clone();
                         move |item_id: String| {
                             tracing::info!("Use item: {}", item_id);
                             if let Err(e) = send_player_action(
-                                &command_bus,
+                                &actions,
                                 PlayerAction::use_item(&item_id, None),
                             ) {
                                 action_error.set(Some(e));
@@ -787,6 +1299,76 @@ pub fn PCView() -> Element {
                             }
                         }
                     })),
+                    trade_partners: trade_partners.read().clone(),
+                    on_trade: Some(EventHandler::new(move |(partner_id, item_id): (String, Option<String>)| {
+                        let partner = trade_partners.read().iter().find(|p| p.pc_id == partner_id).cloned();
+                        if let Some(partner) = partner {
+                            trade_draft.set(Some((partner, item_id)));
+                        }
+                    })),
+                }
+            }
+
+            // Trade offer dialog (opened from the inventory panel)
+            if let Some((partner, initial_item_id)) = trade_draft.read().clone() {
+                TradeOfferDialog {
+                    partner,
+                    items: inventory_items.read().clone(),
+                    initial_item_id,
+                    currency_label: game_state.world.read().as_ref()
+                        .and_then(|w| w.world.rule_system.variant.currency_field())
+                        .map(str::to_string),
+                    on_send: {
+                        let command_bus = command_bus.clone();
+                        let pc_id = selected_pc_id.clone();
+                        move |draft: TradeDraft| {
+                            let Some(ref pc_id) = pc_id else {
+                                action_error.set(Some("No character selected".to_string()));
+                                return;
+                            };
+                            if let Err(e) = send_offer_trade(&command_bus, pc_id, &draft) {
+                                action_error.set(Some(e));
+                            } else {
+                                trade_draft.set(None);
+                            }
+                        }
+                    },
+                    on_cancel: move |_| trade_draft.set(None),
+                }
+            }
+
+            // Open trade offers involving this PC
+            TradeOffersTray {
+                offers: game_state.trade_offers.read().clone(),
+                pc_id: selected_pc_id.clone(),
+                on_respond: {
+                    let command_bus = command_bus.clone();
+                    move |(trade_id, accept): (String, bool)| {
+                        let msg = ClientMessageBuilder::respond_to_trade(&trade_id, accept);
+                        if let Err(e) = command_bus.send(msg) {
+                            action_error.set(Some(format!("Failed to answer trade: {}", e)));
+                        }
+                    }
+                },
+                on_cancel: {
+                    let command_bus = command_bus.clone();
+                    move |trade_id: String| {
+                        let msg = ClientMessageBuilder::cancel_trade(&trade_id);
+                        if let Err(e) = command_bus.send(msg) {
+                            action_error.set(Some(format!("Failed to withdraw trade: {}", e)));
+                        }
+                    }
+                },
+            }
+
+            // Tutorial guide (tutorial worlds only)
+            if let Some(status) = game_state.tutorial.read().clone() {
+                TutorialGuide {
+                    status,
+                    on_dismiss: {
+                        let mut game_state = game_state.clone();
+                        move |_| game_state.dismiss_tutorial()
+                    },
                 }
             }
 
@@ -799,12 +1381,12 @@ pub fn PCView() -> Element {
                         show_known_npcs_panel.set(false);
                     },
                     on_npc_click: Some(EventHandler::new({
-                        let command_bus = command_bus.clone();
+                        let actions = actions.clone();
                         move |npc_id: String| {
                             tracing::info!("Clicked NPC: {}", npc_id);
                             // Could open NPC details or start a talk action
                             if let Err(e) = send_player_action(
-                                &command_bus,
+                                &actions,
                                 PlayerAction::talk(&npc_id, None),
                             ) {
                                 action_error.set(Some(e));
@@ -813,6 +1395,24 @@ pub fn PCView() -> Element {
                             }
                         }
                     })),
+                    on_share: (!party_pc_ids.read().is_empty()).then(|| EventHandler::new({
+                        let command_bus = command_bus.clone();
+                        let pc_id = selected_pc_id.clone();
+                        move |npc_id: String| {
+                            let Some(ref pc_id) = pc_id else {
+                                action_error.set(Some("No character selected".to_string()));
+                                return;
+                            };
+                            let msg = ClientMessageBuilder::share_knowledge(
+                                pc_id,
+                                party_pc_ids.read().clone(),
+                                SharedKnowledgeData::NpcWhereabouts { npc_id },
+                            );
+                            if let Err(e) = command_bus.send(msg) {
+                                action_error.set(Some(format!("Failed to share: {}", e)));
+                            }
+                        }
+                    })),
                 }
             }
 
@@ -836,6 +1436,15 @@ pub fn PCView() -> Element {
                             .collect())
                         .unwrap_or_default(),
                     is_loading: *is_loading_map.read(),
+                    world_id: world_id.clone(),
+                    location_id: current_region.as_ref().map(|r| r.location_id.clone()),
+                    on_marker_open: open_marker_link.clone(),
+                    on_world_map: game_state.world_map.read().map_asset.is_some().then(|| {
+                        EventHandler::new(move |_| {
+                            show_mini_map.set(false);
+                            show_world_map.set(true);
+                        })
+                    }),
                     on_region_click: {
                         let command_bus = command_bus.clone();
                         let selected_pc_id = selected_pc_id.clone();
@@ -855,6 +1464,23 @@ pub fn PCView() -> Element {
                 }
             }
 
+            // World overview map
+            if *show_world_map.read() {
+                WorldMapModal {
+                    current_location_id: current_region.as_ref().map(|r| r.location_id.clone()),
+                    pc_id: selected_pc_id.clone(),
+                    on_travel: selected_pc_id.clone().map(|pc| {
+                        let command_bus = command_bus.clone();
+                        EventHandler::new(move |location_id: String| {
+                            if let Err(e) = send_fast_travel(&command_bus, &pc, &location_id) {
+                                action_error.set(Some(e));
+                            }
+                        })
+                    }),
+                    on_close: move |_| show_world_map.set(false),
+                }
+            }
+
             // Skills panel modal
             if *show_skills_panel.read() {
                 if *is_loading_skills.read() {
@@ -929,6 +1555,32 @@ pub fn PCView() -> Element {
                 }
             }
 
+            // DM handout (map, letter, cutscene art)
+            if let Some(overlay) = overlay {
+                HandoutOverlay {
+                    overlay,
+                    on_dismiss: {
+                        let mut game_state = game_state.clone();
+                        move |overlay_id: String| {
+                            game_state.clear_overlay(&overlay_id);
+                        }
+                    },
+                }
+            }
+
+            // The world's newly published broadsheet
+            if let Some(broadsheet) = broadsheet_handout {
+                BroadsheetOverlay {
+                    broadsheet,
+                    on_dismiss: {
+                        let mut game_state = game_state.clone();
+                        move |_| {
+                            game_state.clear_broadsheet_handout();
+                        }
+                    },
+                }
+            }
+
             // Staging pending overlay (player waiting for DM to set the scene)
             if let Some(ref pending) = *game_state.staging_pending.read() {
                 StagingPendingOverlay {
@@ -1147,22 +1799,51 @@ fn ChallengeResultPopup(
     }
 }
 
-/// Send a player action via CommandBus
+/// Sends player actions, queueing them while the world connection is down
+#[derive(Clone)]
+struct ActionSender {
+    command_bus: CommandBus,
+    session_state: SessionState,
+    game_state: GameState,
+    offline_state: OfflineState,
+    platform: Platform,
+}
+
+/// Send a player action via CommandBus, or queue it until the world is rejoined
 /// Returns Ok(()) on success, Err(message) on failure
-fn send_player_action(command_bus: &CommandBus, action: PlayerAction) -> Result<(), String> {
+/// Badge for a region that isn't well lit; bright regions need none
+fn light_badge(level: Option<LightLevelData>) -> Option<&'static str> {
+    match level? {
+        LightLevelData::Dim => Some("🌘 Dim light"),
+        LightLevelData::Dark => Some("🌑 Dark"),
+        _ => None,
+    }
+}
+
+fn send_player_action(actions: &ActionSender, action: PlayerAction) -> Result<(), String> {
+    if !*actions.session_state.connection.joined.peek() {
+        let scene_id = actions.game_state.current_scene.peek().as_ref().map(|s| s.id.clone());
+        let region_id = actions.game_state.current_region.peek().as_ref().map(|r| r.id.clone());
+        let mut offline_state = actions.offline_state;
+        offline_state.enqueue(&actions.platform, action, scene_id, region_id);
+        return Ok(());
+    }
+
     let msg = ClientMessageBuilder::player_action(
         action.action_type.as_str(),
         action.target.as_deref(),
         action.dialogue.as_deref(),
+        action.choice_id.as_deref(),
     );
-    command_bus
+    actions
+        .command_bus
         .send(msg)
         .map_err(|e| format!("Failed to send action: {}", e))
 }
 
 /// Handle a dialogue choice being selected
 fn handle_choice_selected(
-    command_bus: &CommandBus,
+    actions: &ActionSender,
     dialogue_state: &mut crate::presentation::state::DialogueState,
     choice_id: &str,
 ) -> Result<(), String> {
@@ -1172,13 +1853,13 @@ fn handle_choice_selected(
     dialogue_state.awaiting_input.set(false);
 
     // Send dialogue choice action to the server
-    send_player_action(command_bus, PlayerAction::dialogue_choice(choice_id))
+    send_player_action(actions, PlayerAction::dialogue_choice(choice_id))
 }
 
 /// Handle custom text input
 /// Returns Ok(()) on success, Err(message) on failure
 fn handle_custom_input(
-    command_bus: &CommandBus,
+    actions: &ActionSender,
     dialogue_state: &mut crate::presentation::state::DialogueState,
     text: &str,
 ) -> Result<(), String> {
@@ -1188,7 +1869,7 @@ fn handle_custom_input(
     dialogue_state.awaiting_input.set(false);
 
     // Send custom action to the server
-    send_player_action(command_bus, PlayerAction::custom(text))
+    send_player_action(actions, PlayerAction::custom(text))
 }
 
 /// Handle advancing dialogue (clicking to continue or skipping typewriter)
@@ -1206,13 +1887,29 @@ fn handle_advance(dialogue_state: &mut crate::presentation::state::DialogueState
 
 /// Handle an interaction being selected from the action panel
 /// Returns Ok(()) on success, Err(message) on failure
-fn handle_interaction(command_bus: &CommandBus, interaction: &InteractionData) -> Result<(), String> {
+fn handle_interaction(actions: &ActionSender, interaction: &InteractionData) -> Result<(), String> {
     tracing::info!(
         "Selected interaction: {} ({})",
         interaction.name,
         interaction.interaction_type
     );
 
+    if !interaction.is_available {
+        return Err(interaction
+            .unavailable_reason
+            .clone()
+            .unwrap_or_else(|| format!("{} isn't available right now", interaction.name)));
+    }
+
+    // The engine checks cooldowns and use limits and counts the use; while
+    // offline the interaction is queued as a plain player action instead
+    if *actions.session_state.connection.joined.peek() {
+        return actions
+            .command_bus
+            .send(ClientMessageBuilder::perform_interaction(&interaction.id))
+            .map_err(|e| format!("Failed to perform interaction: {}", e));
+    }
+
     // Convert interaction type to player action
     let action = match interaction.interaction_type.to_lowercase().as_str() {
         "talk" | "dialogue" | "speak" => PlayerAction::talk(&interaction.id, None),
@@ -1228,7 +1925,7 @@ fn handle_interaction(command_bus: &CommandBus, interaction: &InteractionData) -
         }
     };
 
-    send_player_action(command_bus, action)
+    send_player_action(actions, action)
 }
 
 /// Send a challenge roll with dice input via CommandBus
@@ -1267,6 +1964,43 @@ fn send_exit_to_location(
         .map_err(|e| format!("Failed to exit location: {}", e))
 }
 
+/// Send a fast travel command via CommandBus
+/// Returns Ok(()) on success, Err(message) on failure
+fn send_fast_travel(command_bus: &CommandBus, pc_id: &str, location_id: &str) -> Result<(), String> {
+    let msg = ClientMessageBuilder::fast_travel(pc_id, location_id);
+    command_bus
+        .send(msg)
+        .map_err(|e| format!("Failed to set out: {}", e))
+}
+
+/// Send an unlock-with-key command via CommandBus
+/// Returns Ok(()) on success, Err(message) on failure
+fn send_unlock_with_key(
+    command_bus: &CommandBus,
+    pc_id: &str,
+    item_id: &str,
+    region_id: &str,
+) -> Result<(), String> {
+    let msg = ClientMessageBuilder::unlock_with_key(pc_id, item_id, region_id);
+    command_bus
+        .send(msg)
+        .map_err(|e| format!("Failed to try the lock: {}", e))
+}
+
+/// Send a make noise command via CommandBus
+/// Returns Ok(()) on success, Err(message) on failure
+fn send_make_noise(
+    command_bus: &CommandBus,
+    region_id: &str,
+    description: &str,
+    loudness: LoudnessData,
+) -> Result<(), String> {
+    let msg = ClientMessageBuilder::make_noise(region_id, description, loudness);
+    command_bus
+        .send(msg)
+        .map_err(|e| format!("Failed to make noise: {}", e))
+}
+
 /// Send an equip item command via CommandBus
 /// Returns Ok(()) on success, Err(message) on failure
 fn send_equip_item(command_bus: &CommandBus, pc_id: &str, item_id: &str) -> Result<(), String> {
@@ -1294,6 +2028,25 @@ fn send_drop_item(command_bus: &CommandBus, pc_id: &str, item_id: &str) -> Resul
         .map_err(|e| format!("Failed to drop item: {}", e))
 }
 
+/// Send a trade offer via CommandBus
+/// Returns Ok(()) on success, Err(message) on failure
+fn send_offer_trade(
+    command_bus: &CommandBus,
+    pc_id: &str,
+    draft: &TradeDraft,
+) -> Result<(), String> {
+    let msg = ClientMessageBuilder::offer_trade(
+        pc_id,
+        &draft.to_pc_id,
+        draft.item_ids.clone(),
+        draft.offered_currency,
+        draft.requested_currency,
+    );
+    command_bus
+        .send(msg)
+        .map_err(|e| format!("Failed to offer trade: {}", e))
+}
+
 /// Send a pickup item command via CommandBus
 /// Returns Ok(()) on success, Err(message) on failure
 fn send_pickup_item(command_bus: &CommandBus, pc_id: &str, item_id: &str) -> Result<(), String> {
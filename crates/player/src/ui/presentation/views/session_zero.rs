@@ -0,0 +1,491 @@
+//! Session Zero Wizard - Build a new world with the group, one choice at a time
+//!
+//! Walks the table through the decisions a session zero settles: the pitch
+//! and tone, the rule system, lines and veils, when the campaign opens, and
+//! where the story starts. The group can also seed a few NPCs with a
+//! one-line concept each. The last step creates the world, its starting
+//! location and region, and an NPC draft per seed in one go; the drafts
+//! appear in the DM's NPC drafts panel once the LLM has fleshed them out.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{
+    ContentRatingData, ContentSafetyData, NpcSeedData, RuleSystemType, RuleSystemTypeExt,
+    RuleSystemVariant, RuleSystemVariantExt, SessionZeroData, StartingLocationData, TimeModeData,
+};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_world_service;
+
+/// Most NPCs a session zero can seed (matches the Engine's limit)
+const MAX_SEEDS: usize = 6;
+
+/// Wizard steps, in order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SessionZeroStep {
+    Pitch,
+    Rules,
+    Safety,
+    Calendar,
+    Start,
+    Npcs,
+    Review,
+}
+
+impl SessionZeroStep {
+    const ALL: [SessionZeroStep; 7] = [
+        SessionZeroStep::Pitch,
+        SessionZeroStep::Rules,
+        SessionZeroStep::Safety,
+        SessionZeroStep::Calendar,
+        SessionZeroStep::Start,
+        SessionZeroStep::Npcs,
+        SessionZeroStep::Review,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            SessionZeroStep::Pitch => "Pitch & Tone",
+            SessionZeroStep::Rules => "Rule System",
+            SessionZeroStep::Safety => "Safety Tools",
+            SessionZeroStep::Calendar => "Calendar",
+            SessionZeroStep::Start => "Starting Location",
+            SessionZeroStep::Npcs => "NPC Seeds",
+            SessionZeroStep::Review => "Review & Create",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|s| *s == self).unwrap_or(0)
+    }
+
+    fn next(self) -> Self {
+        Self::ALL.get(self.index() + 1).copied().unwrap_or(self)
+    }
+
+    fn previous(self) -> Self {
+        self.index()
+            .checked_sub(1)
+            .and_then(|i| Self::ALL.get(i).copied())
+            .unwrap_or(self)
+    }
+}
+
+/// Props for SessionZeroWizard
+#[derive(Props, Clone, PartialEq)]
+pub struct SessionZeroWizardProps {
+    /// Called with the new world's ID once it's created
+    pub on_created: EventHandler<String>,
+    /// Called when the DM backs out of the wizard
+    pub on_cancel: EventHandler<()>,
+}
+
+/// Guided world creation for a session zero
+#[component]
+pub fn SessionZeroWizard(props: SessionZeroWizardProps) -> Element {
+    let world_service = use_world_service();
+
+    let mut step = use_signal(|| SessionZeroStep::Pitch);
+    let mut name = use_signal(String::new);
+    let mut pitch = use_signal(String::new);
+    let mut tone = use_signal(String::new);
+    let mut variant = use_signal(|| RuleSystemVariant::Dnd5e);
+    let mut rating = use_signal(ContentRatingData::default);
+    // Lines and veils are edited as one topic per line
+    let mut lines_text = use_signal(String::new);
+    let mut veils_text = use_signal(String::new);
+    let mut start_date = use_signal(String::new);
+    let mut start_hour = use_signal(String::new);
+    let mut time_mode = use_signal(TimeModeData::default);
+    let mut location_name = use_signal(String::new);
+    let mut location_description = use_signal(String::new);
+    let mut region_name = use_signal(String::new);
+    let mut region_description = use_signal(String::new);
+    let mut seeds: Signal<Vec<NpcSeedData>> = use_signal(Vec::new);
+    let mut is_creating = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let handle_create = move |_| {
+        let svc = world_service.clone();
+        let data = SessionZeroData {
+            name: name.read().trim().to_string(),
+            description: non_empty(&pitch.read()),
+            tone: non_empty(&tone.read()),
+            rule_system: variant.read().clone(),
+            content_safety: ContentSafetyData {
+                content_rating: *rating.read(),
+                lines: split_topics(&lines_text.read()),
+                veils: split_topics(&veils_text.read()),
+                ..ContentSafetyData::default()
+            },
+            start_date: non_empty(&start_date.read()),
+            start_hour: start_hour.read().trim().parse().ok(),
+            time_mode: *time_mode.read(),
+            starting_location: StartingLocationData {
+                name: location_name.read().trim().to_string(),
+                description: non_empty(&location_description.read()),
+                region_name: region_name.read().trim().to_string(),
+                region_description: non_empty(&region_description.read()),
+            },
+            npc_seeds: seeds
+                .read()
+                .iter()
+                .filter(|s| !s.concept.trim().is_empty())
+                .cloned()
+                .collect(),
+        };
+        spawn_task(async move {
+            is_creating.set(true);
+            error.set(None);
+            match svc.create_session_zero_world(data).await {
+                Ok(world_id) => props.on_created.call(world_id),
+                Err(e) => error.set(Some(format!("Failed to create world: {}", e))),
+            }
+            is_creating.set(false);
+        });
+    };
+
+    let current = *step.read();
+    let creating = *is_creating.read();
+    // Each step needs its required answers before moving on
+    let can_continue = match current {
+        SessionZeroStep::Pitch => !name.read().trim().is_empty(),
+        SessionZeroStep::Start => {
+            !location_name.read().trim().is_empty() && !region_name.read().trim().is_empty()
+        }
+        _ => true,
+    };
+    let variant_name = variant.read().display_name().to_string();
+    let seed_count = seeds.read().len();
+
+    rsx! {
+        div {
+            class: "session-zero-wizard bg-dark-surface rounded-lg p-6",
+
+            h2 { class: "text-white text-xl m-0 mb-1", "Session Zero" }
+            p {
+                class: "text-gray-400 text-sm m-0 mb-6",
+                "Make the big choices together, then create the world with a starting place and a few NPCs in one go."
+            }
+
+            // Step indicator
+            div {
+                class: "flex gap-2 mb-6",
+                for s in SessionZeroStep::ALL {
+                    div {
+                        key: "{s.index()}",
+                        class: if s == current {
+                            "flex-1 h-1.5 rounded bg-purple-500"
+                        } else if s.index() < current.index() {
+                            "flex-1 h-1.5 rounded bg-purple-500/40"
+                        } else {
+                            "flex-1 h-1.5 rounded bg-gray-700"
+                        },
+                    }
+                }
+            }
+
+            h3 { class: "text-white text-lg m-0 mb-4", "{current.title()}" }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-500/10 border border-red-500/30 rounded-lg text-red-400 text-sm mb-4",
+                    "{err}"
+                }
+            }
+
+            match current {
+                SessionZeroStep::Pitch => rsx! {
+                    WizardField {
+                        label: "World name",
+                        placeholder: "Saltmere",
+                        value: name.read().clone(),
+                        on_change: move |v| name.set(v),
+                    }
+                    WizardArea {
+                        label: "Pitch",
+                        placeholder: "Smugglers and storms on a cold northern coast...",
+                        value: pitch.read().clone(),
+                        on_change: move |v| pitch.set(v),
+                    }
+                    WizardField {
+                        label: "Tone",
+                        placeholder: "grim and grounded, swashbuckling, cozy...",
+                        value: tone.read().clone(),
+                        on_change: move |v| tone.set(v),
+                    }
+                },
+                SessionZeroStep::Rules => rsx! {
+                    div {
+                        class: "flex flex-col gap-2",
+                        for system_type in RuleSystemType::all() {
+                            for v in RuleSystemVariant::variants_for_type(system_type) {
+                                label {
+                                    key: "{v.display_name()}",
+                                    class: "flex items-start gap-2 p-2 rounded cursor-pointer hover:bg-gray-800",
+                                    input {
+                                        r#type: "radio",
+                                        name: "rule-system",
+                                        checked: *variant.read() == v,
+                                        onchange: {
+                                            let v = v.clone();
+                                            move |_| variant.set(v.clone())
+                                        },
+                                    }
+                                    div {
+                                        span { class: "text-white text-sm", "{v.display_name()}" }
+                                        p { class: "text-gray-500 text-xs m-0", "{v.description()}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                SessionZeroStep::Safety => rsx! {
+                    div {
+                        class: "mb-4",
+                        label { class: "block text-gray-400 mb-1 text-sm", "Content rating" }
+                        select {
+                            onchange: move |e| {
+                                rating.set(match e.value().as_str() {
+                                    "general" => ContentRatingData::General,
+                                    "mature" => ContentRatingData::Mature,
+                                    _ => ContentRatingData::Teen,
+                                });
+                            },
+                            class: "w-full p-2.5 border border-gray-700 rounded-lg bg-gray-800 text-white text-sm",
+                            option { value: "general", selected: *rating.read() == ContentRatingData::General, "General - suitable for all ages" }
+                            option { value: "teen", selected: *rating.read() == ContentRatingData::Teen, "Teen - no gore or sexual content" }
+                            option { value: "mature", selected: *rating.read() == ContentRatingData::Mature, "Mature - mature themes, no gratuitous detail" }
+                        }
+                    }
+                    WizardArea {
+                        label: "Lines (never appear, one per line)",
+                        placeholder: "",
+                        value: lines_text.read().clone(),
+                        on_change: move |v| lines_text.set(v),
+                    }
+                    WizardArea {
+                        label: "Veils (happen off-screen, one per line)",
+                        placeholder: "",
+                        value: veils_text.read().clone(),
+                        on_change: move |v| veils_text.set(v),
+                    }
+                },
+                SessionZeroStep::Calendar => rsx! {
+                    WizardField {
+                        label: "Opening date (YYYY-MM-DD, blank for today)",
+                        placeholder: "1201-03-14",
+                        value: start_date.read().clone(),
+                        on_change: move |v| start_date.set(v),
+                    }
+                    WizardField {
+                        label: "Opening hour (0-23)",
+                        placeholder: "8",
+                        value: start_hour.read().clone(),
+                        on_change: move |v| start_hour.set(v),
+                    }
+                    div {
+                        class: "mb-4",
+                        label { class: "block text-gray-400 mb-1 text-sm", "How time passes" }
+                        select {
+                            onchange: move |e| {
+                                time_mode.set(match e.value().as_str() {
+                                    "manual" => TimeModeData::Manual,
+                                    "auto" => TimeModeData::Auto,
+                                    _ => TimeModeData::Suggested,
+                                });
+                            },
+                            class: "w-full p-2.5 border border-gray-700 rounded-lg bg-gray-800 text-white text-sm",
+                            option { value: "suggested", selected: *time_mode.read() == TimeModeData::Suggested, "Suggested - the DM approves each time skip" }
+                            option { value: "manual", selected: *time_mode.read() == TimeModeData::Manual, "Manual - only the DM moves time on" }
+                            option { value: "auto", selected: *time_mode.read() == TimeModeData::Auto, "Automatic - actions move time on by themselves" }
+                        }
+                    }
+                },
+                SessionZeroStep::Start => rsx! {
+                    WizardField {
+                        label: "Location",
+                        placeholder: "Saltmere Harbor",
+                        value: location_name.read().clone(),
+                        on_change: move |v| location_name.set(v),
+                    }
+                    WizardArea {
+                        label: "What it's like",
+                        placeholder: "",
+                        value: location_description.read().clone(),
+                        on_change: move |v| location_description.set(v),
+                    }
+                    WizardField {
+                        label: "Where the PCs arrive",
+                        placeholder: "Harbor Gate",
+                        value: region_name.read().clone(),
+                        on_change: move |v| region_name.set(v),
+                    }
+                    WizardArea {
+                        label: "What they see",
+                        placeholder: "",
+                        value: region_description.read().clone(),
+                        on_change: move |v| region_description.set(v),
+                    }
+                },
+                SessionZeroStep::Npcs => rsx! {
+                    p {
+                        class: "text-gray-400 text-sm m-0 mb-3",
+                        "A line each for people the group wants to meet early. Names are optional; the LLM fills in the rest for you to review."
+                    }
+                    for i in 0..seed_count {
+                        div {
+                            key: "{i}",
+                            class: "flex gap-2 mb-2",
+                            input {
+                                r#type: "text",
+                                placeholder: "Name (optional)",
+                                value: "{seeds.read()[i].name.clone().unwrap_or_default()}",
+                                oninput: move |e| {
+                                    let v = e.value();
+                                    seeds.write()[i].name = if v.trim().is_empty() { None } else { Some(v) };
+                                },
+                                class: "w-1/3 p-2 border border-gray-700 rounded-lg bg-gray-800 text-white text-sm",
+                            }
+                            input {
+                                r#type: "text",
+                                placeholder: "a smuggler who owes the harbormaster",
+                                value: "{seeds.read()[i].concept}",
+                                oninput: move |e| seeds.write()[i].concept = e.value(),
+                                class: "flex-1 p-2 border border-gray-700 rounded-lg bg-gray-800 text-white text-sm",
+                            }
+                            button {
+                                onclick: move |_| {
+                                    seeds.write().remove(i);
+                                },
+                                class: "px-2 bg-transparent text-gray-400 border-none cursor-pointer",
+                                "×"
+                            }
+                        }
+                    }
+                    if seed_count < MAX_SEEDS {
+                        button {
+                            onclick: move |_| seeds.write().push(NpcSeedData { name: None, concept: String::new() }),
+                            class: "px-3 py-1.5 bg-transparent text-purple-400 border border-purple-500/50 rounded-md cursor-pointer text-sm",
+                            "+ Add NPC"
+                        }
+                    }
+                },
+                SessionZeroStep::Review => rsx! {
+                    dl {
+                        class: "grid grid-cols-[auto_1fr] gap-x-4 gap-y-2 text-sm m-0",
+                        dt { class: "text-gray-400", "World" }
+                        dd { class: "text-white m-0", "{name}" }
+                        dt { class: "text-gray-400", "Rule system" }
+                        dd { class: "text-white m-0", "{variant_name}" }
+                        dt { class: "text-gray-400", "Tone" }
+                        dd { class: "text-white m-0", if tone.read().trim().is_empty() { "-" } else { "{tone}" } }
+                        dt { class: "text-gray-400", "Lines / veils" }
+                        dd { class: "text-white m-0", "{split_topics(&lines_text.read()).len()} / {split_topics(&veils_text.read()).len()}" }
+                        dt { class: "text-gray-400", "Opens" }
+                        dd { class: "text-white m-0", if start_date.read().trim().is_empty() { "Today" } else { "{start_date}" } }
+                        dt { class: "text-gray-400", "Starts at" }
+                        dd { class: "text-white m-0", "{region_name}, {location_name}" }
+                        dt { class: "text-gray-400", "NPC seeds" }
+                        dd { class: "text-white m-0", "{seed_count}" }
+                    }
+                },
+            }
+
+            // Navigation
+            div {
+                class: "flex justify-between items-center mt-6 gap-2",
+
+                if current == SessionZeroStep::Pitch {
+                    button {
+                        onclick: move |_| props.on_cancel.call(()),
+                        class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded-md cursor-pointer text-sm",
+                        "Cancel"
+                    }
+                } else {
+                    button {
+                        onclick: move |_| step.set(current.previous()),
+                        class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded-md cursor-pointer text-sm",
+                        "← Back"
+                    }
+                }
+
+                if current == SessionZeroStep::Review {
+                    button {
+                        onclick: handle_create,
+                        disabled: creating,
+                        class: "px-4 py-2 bg-purple-500 text-white border-0 rounded-md cursor-pointer text-sm disabled:opacity-50",
+                        if creating { "Creating..." } else { "Create World" }
+                    }
+                } else {
+                    button {
+                        onclick: move |_| step.set(current.next()),
+                        disabled: !can_continue,
+                        class: "px-4 py-2 bg-purple-500 text-white border-0 rounded-md cursor-pointer text-sm disabled:opacity-50",
+                        "Next →"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Labeled one-line input
+#[component]
+fn WizardField(
+    label: &'static str,
+    placeholder: &'static str,
+    value: String,
+    on_change: EventHandler<String>,
+) -> Element {
+    rsx! {
+        div {
+            class: "mb-4",
+            label { class: "block text-gray-400 mb-1 text-sm", "{label}" }
+            input {
+                r#type: "text",
+                value: "{value}",
+                placeholder: "{placeholder}",
+                oninput: move |e| on_change.call(e.value()),
+                class: "w-full p-2.5 border border-gray-700 rounded-lg bg-gray-800 text-white text-sm box-border",
+            }
+        }
+    }
+}
+
+/// Labeled multi-line input
+#[component]
+fn WizardArea(
+    label: &'static str,
+    placeholder: &'static str,
+    value: String,
+    on_change: EventHandler<String>,
+) -> Element {
+    rsx! {
+        div {
+            class: "mb-4",
+            label { class: "block text-gray-400 mb-1 text-sm", "{label}" }
+            textarea {
+                value: "{value}",
+                placeholder: "{placeholder}",
+                rows: 3,
+                oninput: move |e| on_change.call(e.value()),
+                class: "w-full p-2.5 border border-gray-700 rounded-lg bg-gray-800 text-white text-sm box-border resize-y",
+            }
+        }
+    }
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// One topic per non-blank line
+fn split_topics(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(String::from)
+        .collect()
+}
@@ -0,0 +1,367 @@
+//! Setup Wizard - First-run configuration of the Engine's services
+//!
+//! Walks the operator through Neo4j, Ollama, and ComfyUI connections, one
+//! service per step, with a live "Test connection" check on each. The last
+//! step saves everything to the Engine's settings once all checks pass.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{ConnectionReportData, ServiceCheckData, ServiceConnectionsData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::use_settings_service;
+
+/// Wizard steps, in order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SetupStep {
+    Neo4j,
+    Ollama,
+    ComfyUi,
+    Review,
+}
+
+impl SetupStep {
+    const ALL: [SetupStep; 4] = [
+        SetupStep::Neo4j,
+        SetupStep::Ollama,
+        SetupStep::ComfyUi,
+        SetupStep::Review,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            SetupStep::Neo4j => "Database (Neo4j)",
+            SetupStep::Ollama => "Language Model (Ollama)",
+            SetupStep::ComfyUi => "Image Generation (ComfyUI)",
+            SetupStep::Review => "Review & Save",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|s| *s == self).unwrap_or(0)
+    }
+
+    fn next(self) -> Self {
+        Self::ALL.get(self.index() + 1).copied().unwrap_or(self)
+    }
+
+    fn previous(self) -> Self {
+        self.index()
+            .checked_sub(1)
+            .and_then(|i| Self::ALL.get(i).copied())
+            .unwrap_or(self)
+    }
+
+    fn check(self, report: &ConnectionReportData) -> Option<&ServiceCheckData> {
+        match self {
+            SetupStep::Neo4j => Some(&report.neo4j),
+            SetupStep::Ollama => Some(&report.ollama),
+            SetupStep::ComfyUi => Some(&report.comfyui),
+            SetupStep::Review => None,
+        }
+    }
+}
+
+/// Props for SetupWizard
+#[derive(Props, Clone, PartialEq)]
+pub struct SetupWizardProps {
+    /// Called when the operator finishes or skips setup
+    pub on_done: EventHandler<()>,
+}
+
+/// First-run setup wizard
+#[component]
+pub fn SetupWizard(props: SetupWizardProps) -> Element {
+    let settings_service = use_settings_service();
+
+    let mut step = use_signal(|| SetupStep::Neo4j);
+    let mut connections = use_signal(ServiceConnectionsData::default);
+    let mut report: Signal<Option<ConnectionReportData>> = use_signal(|| None);
+    let mut is_loading = use_signal(|| true);
+    let mut is_busy = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut saved = use_signal(|| false);
+
+    // Start from the Engine's current connections and their live status
+    let service_for_load = settings_service.clone();
+    use_effect(move || {
+        let svc = service_for_load.clone();
+        spawn_task(async move {
+            match svc.get_setup().await {
+                Ok(state) => {
+                    connections.set(state.connections);
+                    report.set(Some(state.checks));
+                }
+                Err(e) => error.set(Some(format!("Could not reach the Engine: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let service_for_check = settings_service.clone();
+    let handle_test = move |_| {
+        let svc = service_for_check.clone();
+        let candidate = connections.read().clone();
+        spawn_task(async move {
+            is_busy.set(true);
+            error.set(None);
+            match svc.check_setup(&candidate).await {
+                Ok(checks) => report.set(Some(checks)),
+                Err(e) => error.set(Some(format!("Check failed: {}", e))),
+            }
+            is_busy.set(false);
+        });
+    };
+
+    let service_for_save = settings_service.clone();
+    let handle_save = move |_| {
+        let svc = service_for_save.clone();
+        let candidate = connections.read().clone();
+        spawn_task(async move {
+            is_busy.set(true);
+            error.set(None);
+            match svc.save_setup(&candidate).await {
+                Ok(outcome) => {
+                    saved.set(outcome.saved);
+                    if !outcome.saved {
+                        error.set(Some(
+                            "Some services could not be reached. Fix them before saving."
+                                .to_string(),
+                        ));
+                    }
+                    connections.set(outcome.connections);
+                    report.set(Some(outcome.checks));
+                }
+                Err(e) => error.set(Some(format!("Failed to save: {}", e))),
+            }
+            is_busy.set(false);
+        });
+    };
+
+    let current = *step.read();
+    let current_report = report.read().clone();
+    let busy = *is_busy.read();
+
+    rsx! {
+        div {
+            class: "setup-wizard h-full flex flex-col items-center justify-center p-8 bg-gradient-to-br from-dark-surface to-dark-gradient-end",
+
+            div {
+                class: "max-w-[600px] w-full bg-dark-bg rounded-xl shadow-2xl p-8",
+
+                h1 { class: "text-white text-2xl m-0 mb-1", "Engine Setup" }
+                p {
+                    class: "text-gray-400 text-sm m-0 mb-6",
+                    "Connect the Engine to its services. Changes take effect after the Engine restarts."
+                }
+
+                // Step indicator
+                div {
+                    class: "flex gap-2 mb-6",
+                    for s in SetupStep::ALL {
+                        div {
+                            key: "{s.index()}",
+                            class: if s == current {
+                                "flex-1 h-1.5 rounded bg-blue-500"
+                            } else if s.index() < current.index() {
+                                "flex-1 h-1.5 rounded bg-blue-500/40"
+                            } else {
+                                "flex-1 h-1.5 rounded bg-gray-700"
+                            },
+                        }
+                    }
+                }
+
+                h2 { class: "text-white text-lg m-0 mb-4", "{current.title()}" }
+
+                if let Some(err) = error.read().as_ref() {
+                    div {
+                        class: "p-3 bg-red-500/10 border border-red-500/30 rounded-lg text-red-400 text-sm mb-4",
+                        "{err}"
+                    }
+                }
+
+                if *is_loading.read() {
+                    div { class: "text-center text-gray-500 p-8", "Checking current connections..." }
+                } else {
+                    match current {
+                        SetupStep::Neo4j => rsx! {
+                            SetupField {
+                                label: "Bolt URI",
+                                placeholder: "bolt://localhost:7687",
+                                value: connections.read().neo4j_uri.clone(),
+                                on_change: move |v| connections.write().neo4j_uri = v,
+                            }
+                            SetupField {
+                                label: "User",
+                                placeholder: "neo4j",
+                                value: connections.read().neo4j_user.clone(),
+                                on_change: move |v| connections.write().neo4j_user = v,
+                            }
+                            SetupField {
+                                label: "Password",
+                                placeholder: "",
+                                value: connections.read().neo4j_password.clone(),
+                                is_secret: true,
+                                on_change: move |v| connections.write().neo4j_password = v,
+                            }
+                        },
+                        SetupStep::Ollama => rsx! {
+                            SetupField {
+                                label: "Ollama URL",
+                                placeholder: "http://localhost:11434",
+                                value: connections.read().ollama_url.clone(),
+                                on_change: move |v| connections.write().ollama_url = v,
+                            }
+                            SetupField {
+                                label: "Model",
+                                placeholder: "llama3.2",
+                                value: connections.read().ollama_model.clone(),
+                                on_change: move |v| connections.write().ollama_model = v,
+                            }
+                        },
+                        SetupStep::ComfyUi => rsx! {
+                            SetupField {
+                                label: "ComfyUI URL",
+                                placeholder: "http://localhost:8188",
+                                value: connections.read().comfyui_url.clone(),
+                                on_change: move |v| connections.write().comfyui_url = v,
+                            }
+                        },
+                        SetupStep::Review => rsx! {
+                            if let Some(checks) = current_report.as_ref() {
+                                for s in [SetupStep::Neo4j, SetupStep::Ollama, SetupStep::ComfyUi] {
+                                    CheckBadge {
+                                        key: "{s.index()}",
+                                        label: s.title(),
+                                        check: s.check(checks).cloned(),
+                                    }
+                                }
+                            } else {
+                                p { class: "text-gray-400 text-sm", "Run the checks to see each service's status." }
+                            }
+                            if *saved.read() {
+                                div {
+                                    class: "p-3 mt-4 bg-green-500/10 border border-green-500/30 rounded-lg text-green-400 text-sm",
+                                    "Saved. Restart the Engine to apply the new connections."
+                                }
+                            }
+                        },
+                    }
+
+                    if current != SetupStep::Review {
+                        if let Some(check) = current_report.as_ref().and_then(|r| current.check(r)).cloned() {
+                            CheckBadge { label: "Status", check: Some(check) }
+                        }
+                    }
+                }
+
+                // Navigation
+                div {
+                    class: "flex justify-between items-center mt-6 gap-2",
+
+                    if current == SetupStep::Neo4j {
+                        button {
+                            onclick: move |_| props.on_done.call(()),
+                            class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded-md cursor-pointer text-sm",
+                            "Skip"
+                        }
+                    } else {
+                        button {
+                            onclick: move |_| step.set(current.previous()),
+                            class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded-md cursor-pointer text-sm",
+                            "← Back"
+                        }
+                    }
+
+                    div {
+                        class: "flex gap-2",
+
+                        button {
+                            onclick: handle_test,
+                            disabled: busy,
+                            class: "px-4 py-2 bg-gray-700 text-white border-0 rounded-md cursor-pointer text-sm disabled:opacity-50",
+                            if busy { "Checking..." } else if current == SetupStep::Review { "Re-check All" } else { "Test Connection" }
+                        }
+
+                        if current == SetupStep::Review {
+                            if *saved.read() {
+                                button {
+                                    onclick: move |_| props.on_done.call(()),
+                                    class: "px-4 py-2 bg-blue-500 text-white border-0 rounded-md cursor-pointer text-sm",
+                                    "Continue"
+                                }
+                            } else {
+                                button {
+                                    onclick: handle_save,
+                                    disabled: busy,
+                                    class: "px-4 py-2 bg-blue-500 text-white border-0 rounded-md cursor-pointer text-sm disabled:opacity-50",
+                                    "Save"
+                                }
+                            }
+                        } else {
+                            button {
+                                onclick: move |_| step.set(current.next()),
+                                class: "px-4 py-2 bg-blue-500 text-white border-0 rounded-md cursor-pointer text-sm",
+                                "Next →"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Labeled text input for one connection field
+#[component]
+fn SetupField(
+    label: &'static str,
+    placeholder: &'static str,
+    value: Option<String>,
+    #[props(default)] is_secret: bool,
+    on_change: EventHandler<Option<String>>,
+) -> Element {
+    let value = value.unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "mb-4",
+            label { class: "block text-gray-400 mb-1 text-sm", "{label}" }
+            input {
+                r#type: if is_secret { "password" } else { "text" },
+                value: "{value}",
+                placeholder: "{placeholder}",
+                oninput: move |e| {
+                    let v = e.value();
+                    on_change.call(if v.trim().is_empty() { None } else { Some(v) });
+                },
+                class: "w-full p-2.5 border border-gray-700 rounded-lg bg-gray-800 text-white text-sm box-border",
+            }
+        }
+    }
+}
+
+/// Pass/fail line for one service check
+#[component]
+fn CheckBadge(label: &'static str, check: Option<ServiceCheckData>) -> Element {
+    let Some(check) = check else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div {
+            class: "flex items-start gap-2 py-2 text-sm",
+            if check.ok {
+                span { class: "text-green-400", "✓" }
+            } else {
+                span { class: "text-red-400", "✗" }
+            }
+            div {
+                span { class: "text-white", "{label}" }
+                if let Some(err) = check.error.as_ref() {
+                    p { class: "text-red-400 m-0 text-xs", "{err}" }
+                }
+            }
+        }
+    }
+}
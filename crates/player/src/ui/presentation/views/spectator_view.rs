@@ -5,8 +5,11 @@
 
 use dioxus::prelude::*;
 
+use crate::presentation::components::event_overlays::HandoutOverlay;
+use crate::presentation::components::map_markers::MarkerLayer;
 use crate::presentation::components::visual_novel::{Backdrop, CharacterLayer, EmptyDialogueBox};
 use crate::presentation::state::{use_dialogue_state, use_game_state, use_typewriter_effect};
+use crate::presentation::utils::use_text_layout;
 
 /// Spectator View - read-only view of the game
 ///
@@ -22,6 +25,9 @@ pub fn SpectatorView() -> Element {
 
     // Read scene characters from game state (reactive)
     let scene_characters = game_state.scene_characters.read().clone();
+    let world_id = game_state.world.read().as_ref().map(|w| w.world.id.clone());
+    let current_region = game_state.current_region.read().clone();
+    let overlay = game_state.overlay.read().clone();
 
     // Get conversation history for the log
     let mut conversation_log = use_signal(Vec::<ConversationEntry>::new);
@@ -80,6 +86,15 @@ pub fn SpectatorView() -> Element {
                     characters: scene_characters,
                     on_character_click: None, // Spectators cannot interact
                 }
+
+                // Visible DM markers on this region's backdrop (view only)
+                if let (Some(world_id), Some(region)) = (world_id, current_region) {
+                    MarkerLayer {
+                        world_id,
+                        location_id: region.location_id,
+                        region_id: Some(region.id),
+                    }
+                }
             }
 
             // Dialogue box (fixed at bottom) - 2.3.2 Read-only dialogue display
@@ -104,6 +119,19 @@ pub fn SpectatorView() -> Element {
                     entries: conversation_log.read().clone(),
                 }
             }
+
+            // DM handout (map, letter, cutscene art)
+            if let Some(overlay) = overlay {
+                HandoutOverlay {
+                    overlay,
+                    on_dismiss: {
+                        let mut game_state = game_state.clone();
+                        move |overlay_id: String| {
+                            game_state.clear_overlay(&overlay_id);
+                        }
+                    },
+                }
+            }
         }
     }
 }
@@ -130,10 +158,14 @@ pub struct SpectatorDialogueBoxProps {
 #[component]
 fn SpectatorDialogueBox(props: SpectatorDialogueBoxProps) -> Element {
     let has_speaker = !props.speaker_name.is_empty();
+    let layout = use_text_layout();
 
     rsx! {
         div {
             class: "spectator-dialogue-box bg-black/85 border-t-2 border-purple-500 p-4 max-h-[200px]",
+            dir: layout.dir,
+            lang: layout.lang,
+            style: "{layout.style}",
 
             // Speaker name plate
             if has_speaker {
@@ -168,7 +200,7 @@ fn SpectatorDialogueBox(props: SpectatorDialogueBoxProps) -> Element {
                         // Blinking cursor during typing
                         if props.is_typing {
                             span {
-                                class: "typewriter-cursor ml-0.5 animate-[blink_0.7s_step-end_infinite]",
+                                class: "typewriter-cursor ms-0.5 animate-[blink_0.7s_step-end_infinite]",
                                 "▌"
                             }
                         }
@@ -0,0 +1,241 @@
+//! Successor View - Carry on after a PC has died
+//!
+//! Shows the fallen PC's memorial and creates the character who takes over,
+//! optionally inheriting the lore and NPC sightings the party already had.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{PcDeathData, SuccessorInputData};
+use crate::infrastructure::spawn_task;
+use crate::presentation::services::{use_mortality_service, use_player_character_service};
+use crate::routes::Route;
+use crate::use_platform;
+
+/// Props for the successor view
+#[derive(Props, Clone, PartialEq)]
+pub struct SuccessorProps {
+    pub world_id: String,
+}
+
+/// Successor View - memorial and successor form for a fallen PC
+#[component]
+pub fn SuccessorView(props: SuccessorProps) -> Element {
+    let navigator = use_navigator();
+    let platform = use_platform();
+    let pc_service = use_player_character_service();
+    let mortality_service = use_mortality_service();
+
+    let mut death: Signal<Option<PcDeathData>> = use_signal(|| None);
+    let mut is_loading = use_signal(|| true);
+
+    let mut name = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut inherit_lore = use_signal(|| true);
+    let mut inherit_observations = use_signal(|| true);
+    let mut is_creating = use_signal(|| false);
+    let mut error_message: Signal<Option<String>> = use_signal(|| None);
+
+    // Load the fallen PC - back to play if they're alive after all
+    {
+        let world_id = props.world_id.clone();
+        let user_id = platform.get_user_id();
+        let pc_svc = pc_service.clone();
+        let mortality_svc = mortality_service.clone();
+        use_effect(move || {
+            let wid = world_id.clone();
+            let uid = user_id.clone();
+            let pc_svc = pc_svc.clone();
+            let mortality_svc = mortality_svc.clone();
+            spawn_task(async move {
+                match pc_svc.get_my_pc(&wid, &uid).await {
+                    Ok(Some(pc)) if !pc.is_alive => match mortality_svc.get_death(&pc.id).await {
+                        Ok(data) => death.set(Some(data)),
+                        Err(e) => {
+                            error_message.set(Some(format!("Failed to load memorial: {}", e)))
+                        }
+                    },
+                    Ok(Some(_)) => {
+                        navigator.push(Route::PCViewRoute { world_id: wid });
+                    }
+                    Ok(None) => {
+                        navigator.push(Route::PCCreationRoute { world_id: wid });
+                    }
+                    Err(e) => {
+                        error_message.set(Some(format!("Failed to load character: {}", e)));
+                    }
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let create_successor = move |_| {
+        let Some(fallen) = death.read().clone() else {
+            return;
+        };
+        let name_val = name.read().trim().to_string();
+        if name_val.is_empty() {
+            error_message.set(Some("Character name is required".to_string()));
+            return;
+        }
+        let desc_val = description.read().trim().to_string();
+        let data = SuccessorInputData {
+            name: name_val,
+            description: (!desc_val.is_empty()).then_some(desc_val),
+            sheet_data: None,
+            inherit_lore: *inherit_lore.read(),
+            inherit_observations: *inherit_observations.read(),
+        };
+        let svc = mortality_service.clone();
+        let world_id = props.world_id.clone();
+
+        is_creating.set(true);
+        error_message.set(None);
+
+        spawn_task(async move {
+            match svc.create_successor(&fallen.pc_id, data).await {
+                Ok(_) => {
+                    navigator.push(Route::PCViewRoute { world_id });
+                }
+                Err(e) => {
+                    error_message.set(Some(format!("Failed to create successor: {}", e)));
+                    is_creating.set(false);
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "h-screen flex flex-col bg-dark-bg text-white",
+
+            // Header
+            div {
+                class: "p-6 border-b border-gray-700",
+                h1 {
+                    class: "m-0 text-2xl text-white",
+                    "Your Character Has Fallen"
+                }
+                p {
+                    class: "mt-2 mb-0 text-gray-400 text-sm",
+                    "Create a successor to carry on the adventure"
+                }
+            }
+
+            div {
+                class: "flex-1 overflow-y-auto p-8",
+                div {
+                    class: "max-w-[600px] mx-auto flex flex-col gap-6",
+
+                    if let Some(err) = error_message.read().as_ref() {
+                        div {
+                            class: "py-3 px-4 bg-red-500/10 border border-red-500 rounded-lg text-red-500",
+                            "{err}"
+                        }
+                    }
+
+                    if *is_loading.read() {
+                        p { class: "text-gray-400 text-sm", "Loading..." }
+                    } else if let Some(fallen) = death.read().as_ref() {
+                        Memorial { death: fallen.clone() }
+
+                        div {
+                            class: "flex flex-col gap-4",
+                            div {
+                                label {
+                                    class: "block mb-2 text-gray-400 text-sm font-medium",
+                                    "Successor Name *"
+                                }
+                                input {
+                                    r#type: "text",
+                                    value: "{name}",
+                                    oninput: move |e| name.set(e.value()),
+                                    placeholder: "Enter your new character's name",
+                                    class: "w-full p-3 bg-dark-surface border border-gray-700 rounded-lg text-white text-base",
+                                }
+                            }
+                            div {
+                                label {
+                                    class: "block mb-2 text-gray-400 text-sm font-medium",
+                                    "Description (Optional)"
+                                }
+                                textarea {
+                                    value: "{description}",
+                                    oninput: move |e| description.set(e.value()),
+                                    placeholder: "How do they come to join the party?",
+                                    rows: 3,
+                                    class: "w-full p-3 bg-dark-surface border border-gray-700 rounded-lg text-white text-base resize-y",
+                                }
+                            }
+                            label {
+                                class: "flex items-center gap-2 text-sm text-gray-300",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: *inherit_lore.read(),
+                                    onchange: move |evt| inherit_lore.set(evt.checked()),
+                                }
+                                "Knows the lore {fallen.name} had learned"
+                            }
+                            label {
+                                class: "flex items-center gap-2 text-sm text-gray-300",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: *inherit_observations.read(),
+                                    onchange: move |evt| inherit_observations.set(evt.checked()),
+                                }
+                                "Has heard about the people {fallen.name} met"
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Footer
+            div {
+                class: "py-4 px-6 border-t border-gray-700 flex justify-end",
+                button {
+                    onclick: create_successor,
+                    disabled: *is_creating.read() || death.read().is_none(),
+                    class: "py-2 px-6 bg-green-500 text-white border-0 rounded-lg cursor-pointer font-medium disabled:opacity-50",
+                    if *is_creating.read() {
+                        "Creating..."
+                    } else {
+                        "Create Successor"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Memorial card for a fallen PC
+#[component]
+fn Memorial(death: PcDeathData) -> Element {
+    let waiting = death.resurrection_blocked.is_none();
+
+    rsx! {
+        div {
+            class: "p-4 bg-dark-surface border border-gray-700 rounded-lg",
+            h2 {
+                class: "m-0 mb-1 text-lg text-white",
+                "In memory of {death.name}"
+            }
+            p {
+                class: "m-0 text-sm text-gray-400",
+                "{death.cause}"
+            }
+            if let Some(epitaph) = death.epitaph.as_ref() {
+                p {
+                    class: "mt-3 mb-0 text-sm text-gray-300 italic",
+                    "\"{epitaph}\""
+                }
+            }
+            if waiting {
+                p {
+                    class: "mt-3 mb-0 text-xs text-amber-400",
+                    "The DM may still bring {death.name} back. Creating a successor ends that chance."
+                }
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! This view appears after role selection and before the game views.
 //! - DM: Can create new worlds or continue existing ones
-//! - Player: Can join existing worlds
+//! - Player: Can join existing worlds or start the bundled tutorial
 //! - Spectator: Can watch existing worlds
 
 use dioxus::prelude::*;
@@ -15,6 +15,7 @@ use crate::application::dto::{
 use crate::application::services::world_service::WorldSummary;
 use crate::presentation::services::use_world_service;
 use crate::presentation::state::{GameState, SessionState};
+use crate::presentation::views::session_zero::SessionZeroWizard;
 use crate::use_platform;
 
 /// Props for WorldSelectView
@@ -39,12 +40,14 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
     let mut is_loading = use_signal(|| true);
     let mut error: Signal<Option<String>> = use_signal(|| None);
     let mut show_create_form = use_signal(|| false);
+    let mut show_session_zero = use_signal(|| false);
 
     let is_dm = props.role == ParticipantRole::DungeonMaster;
 
     // Clone services for use in effects
     let world_service_for_list = world_service.clone();
     let _world_service_for_load = world_service.clone();
+    let world_service_for_tutorial = world_service.clone();
 
     // Fetch worlds on mount
     use_effect(move || {
@@ -144,6 +147,16 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
                         },
                         on_cancel: move |_| show_create_form.set(false),
                     }
+                } else if *show_session_zero.read() && is_dm {
+                    // Guided creation with the group (DM only)
+                    SessionZeroWizard {
+                        on_created: move |world_id: String| {
+                            show_session_zero.set(false);
+                            is_loading.set(true);
+                            props.on_world_selected.call(world_id);
+                        },
+                        on_cancel: move |_| show_session_zero.set(false),
+                    }
                 } else {
                     // World list
                     div {
@@ -158,10 +171,37 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
                             }
 
                             if is_dm {
+                                div {
+                                    class: "flex gap-2",
+                                    button {
+                                        onclick: move |_| show_session_zero.set(true),
+                                        class: "px-4 py-2 bg-transparent text-purple-400 border border-purple-500/50 rounded cursor-pointer text-sm",
+                                        "Session Zero"
+                                    }
+                                    button {
+                                        onclick: move |_| show_create_form.set(true),
+                                        class: "px-4 py-2 bg-purple-500 text-white border-0 rounded cursor-pointer text-sm",
+                                        "+ Create New World"
+                                    }
+                                }
+                            } else if props.role == ParticipantRole::Player {
+                                // New players get their own copy of the tutorial world
                                 button {
-                                    onclick: move |_| show_create_form.set(true),
-                                    class: "px-4 py-2 bg-purple-500 text-white border-0 rounded cursor-pointer text-sm",
-                                    "+ Create New World"
+                                    onclick: move |_| {
+                                        let svc = world_service_for_tutorial.clone();
+                                        is_loading.set(true);
+                                        spawn_task(async move {
+                                            match svc.create_tutorial_world().await {
+                                                Ok(world_id) => props.on_world_selected.call(world_id),
+                                                Err(e) => {
+                                                    error.set(Some(format!("Failed to start tutorial: {}", e)));
+                                                    is_loading.set(false);
+                                                }
+                                            }
+                                        });
+                                    },
+                                    class: "px-4 py-2 bg-emerald-600 text-white border-0 rounded cursor-pointer text-sm",
+                                    "Play the Tutorial"
                                 }
                             }
                         }
@@ -229,6 +269,9 @@ fn WorldCard(
             div {
                 class: "flex-1",
                 h3 { class: "text-white m-0 mb-1 text-base", "{world.name}" }
+                if world.branched_from.is_some() {
+                    span { class: "text-purple-400 text-xs", "Alternate timeline" }
+                }
                 if let Some(desc) = &world.description {
                     p { class: "text-gray-400 m-0 text-sm leading-snug", "{desc}" }
                 }
@@ -137,6 +137,9 @@ fn initiate_connection(
     session_state.start_connecting("shared");
     session_state.set_user(user_id.clone(), role);
 
+    // Server text (errors, time-of-day, etc.) is localized per connection
+    let locale = platform.preferred_locale();
+
     // Set up event subscription for this world session
     #[cfg(not(target_arch = "wasm32"))]
     {
@@ -163,6 +166,7 @@ fn initiate_connection(
         let tx_for_state = tx.clone();
         let world_id_clone = world_id.clone();
         let user_id_clone = user_id.clone();
+        let locale_clone = locale.clone();
 
         spawn_task(async move {
             let mut last_state = state_observer_clone.state();
@@ -180,6 +184,8 @@ fn initiate_connection(
                         user_id = %user_id_clone,
                         "Sending JoinWorld message (native) - already connected"
                     );
+                    let _ = command_bus_clone
+                        .send(ClientMessageBuilder::set_locale(&locale_clone));
                     let _ = command_bus_clone.send(ClientMessageBuilder::join_world(
                         world_uuid,
                         world_role,
@@ -198,6 +204,11 @@ fn initiate_connection(
                         crate::application::services::SessionEvent::StateChanged(current_state),
                     );
 
+                    // A dropped socket loses world membership, so rejoin once it's back
+                    if current_state != ConnectionState::Connected {
+                        join_sent = false;
+                    }
+
                     // Auto-join when connected (if not already sent)
                     if current_state == ConnectionState::Connected && !join_sent {
                         if let Ok(world_uuid) = uuid::Uuid::parse_str(&world_id_clone) {
@@ -210,6 +221,8 @@ fn initiate_connection(
                                 user_id = %user_id_clone,
                                 "Sending JoinWorld message (native)"
                             );
+                            let _ = command_bus_clone
+                                .send(ClientMessageBuilder::set_locale(&locale_clone));
                             let _ = command_bus_clone.send(ClientMessageBuilder::join_world(
                                 world_uuid,
                                 world_role,
@@ -273,6 +286,7 @@ fn initiate_connection(
         let tx_for_state = tx.clone();
         let world_id_clone = world_id.clone();
         let user_id_clone = user_id.clone();
+        let locale_clone = locale.clone();
 
         wasm_bindgen_futures::spawn_local(async move {
             let mut last_state = state_observer_clone.state();
@@ -290,6 +304,8 @@ fn initiate_connection(
                         user_id = %user_id_clone,
                         "Sending JoinWorld message (WASM) - already connected"
                     );
+                    let _ = command_bus_clone
+                        .send(ClientMessageBuilder::set_locale(&locale_clone));
                     let _ = command_bus_clone.send(ClientMessageBuilder::join_world(
                         world_uuid,
                         world_role,
@@ -308,6 +324,11 @@ fn initiate_connection(
                         crate::application::services::SessionEvent::StateChanged(current_state),
                     );
 
+                    // A dropped socket loses world membership, so rejoin once it's back
+                    if current_state != ConnectionState::Connected {
+                        join_sent = false;
+                    }
+
                     // Auto-join when connected (if not already sent)
                     if current_state == ConnectionState::Connected && !join_sent {
                         if let Ok(world_uuid) = uuid::Uuid::parse_str(&world_id_clone) {
@@ -320,6 +341,8 @@ fn initiate_connection(
                                 user_id = %user_id_clone,
                                 "Sending JoinWorld message (WASM)"
                             );
+                            let _ = command_bus_clone
+                                .send(ClientMessageBuilder::set_locale(&locale_clone));
                             let _ = command_bus_clone.send(ClientMessageBuilder::join_world(
                                 world_uuid,
                                 world_role,
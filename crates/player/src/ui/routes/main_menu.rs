@@ -2,15 +2,20 @@
 
 use super::Route;
 use crate::application::services::DEFAULT_ENGINE_URL;
+use crate::infrastructure::spawn_task;
 use crate::ports::outbound::storage_keys;
+use crate::presentation::services::use_settings_service;
 use crate::use_platform;
 use dioxus::prelude::*;
 
 /// Main menu route - automatically redirects to role selection
+///
+/// If the Engine has never been set up, the setup wizard is shown first.
 #[component]
 pub fn MainMenuRoute() -> Element {
     let navigator = use_navigator();
     let platform = use_platform();
+    let settings_service = use_settings_service();
 
     // On web, automatically connect to the default (or last-used) server and
     // skip the manual "Connect to Server" modal. This keeps the flow:
@@ -29,8 +34,21 @@ pub fn MainMenuRoute() -> Element {
         // Configure Engine HTTP base URL from the WebSocket URL
         platform_for_effect.configure_engine_url(&server_url);
 
-        // Go straight to role selection
-        navigator_for_effect.push(Route::RoleSelectRoute {});
+        // Go to role selection, via the setup wizard on first run. If the
+        // Engine can't report its setup state, don't block on it.
+        let svc = settings_service.clone();
+        spawn_task(async move {
+            let needs_setup = svc
+                .get_setup()
+                .await
+                .map(|state| state.needs_setup)
+                .unwrap_or(false);
+            if needs_setup {
+                navigator_for_effect.push(Route::SetupRoute {});
+            } else {
+                navigator_for_effect.push(Route::RoleSelectRoute {});
+            }
+        });
     });
 
     // Minimal placeholder while the effect redirects
@@ -41,3 +59,23 @@ pub fn MainMenuRoute() -> Element {
         }
     }
 }
+
+/// First-run setup route - configures the Engine's service connections
+#[component]
+pub fn SetupRoute() -> Element {
+    let navigator = use_navigator();
+    let platform = use_platform();
+
+    // Set page title for this view
+    use_effect(move || {
+        platform.set_page_title("Engine Setup");
+    });
+
+    rsx! {
+        crate::presentation::views::setup_wizard::SetupWizard {
+            on_done: move |_| {
+                navigator.push(Route::RoleSelectRoute {});
+            }
+        }
+    }
+}
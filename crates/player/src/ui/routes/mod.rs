@@ -36,8 +36,8 @@ mod world_session_layout;
 pub use dm_routes::{
     DMCreatorSubTabRoute, DMSettingsSubTabRoute, DMStoryArcSubTabRoute, DMViewRoute, DMViewTabRoute,
 };
-pub use main_menu::MainMenuRoute;
-pub use pc_creation::PCCreationRoute;
+pub use main_menu::{MainMenuRoute, SetupRoute};
+pub use pc_creation::{PCCreationRoute, PCSuccessorRoute};
 pub use player_routes::{PCViewRoute, SpectatorViewRoute};
 pub use world_select::{RoleSelectRoute, WorldSelectRoute};
 
@@ -50,6 +50,9 @@ pub enum Route {
     #[route("/")]
     MainMenuRoute {},
 
+    #[route("/setup")]
+    SetupRoute {},
+
     #[route("/roles")]
     RoleSelectRoute {},
 
@@ -81,6 +84,9 @@ pub enum Route {
     #[route("/worlds/:world_id/play/create-character")]
     PCCreationRoute { world_id: String },
 
+    #[route("/worlds/:world_id/play/successor")]
+    PCSuccessorRoute { world_id: String },
+
     #[route("/worlds/:world_id/watch")]
     SpectatorViewRoute { world_id: String },
 
@@ -1,4 +1,4 @@
-//! PC (Player Character) creation route handler
+//! PC (Player Character) creation and successor route handlers
 
 use crate::use_platform;
 use dioxus::prelude::*;
@@ -20,3 +20,20 @@ pub fn PCCreationRoute(world_id: String) -> Element {
         }
     }
 }
+
+/// Successor creation route, for a player whose PC has died
+#[component]
+pub fn PCSuccessorRoute(world_id: String) -> Element {
+    let platform = use_platform();
+
+    // Set page title
+    use_effect(move || {
+        platform.set_page_title("Create Successor");
+    });
+
+    rsx! {
+        crate::presentation::views::successor::SuccessorView {
+            world_id: world_id,
+        }
+    }
+}
@@ -4,7 +4,7 @@ use super::world_session_layout::WorldSessionLayout;
 use super::Route;
 use crate::infrastructure::spawn_task;
 use crate::ports::session_types::ParticipantRole;
-use crate::presentation::state::SessionState;
+use crate::presentation::state::{use_offline_sync, SessionState};
 use crate::use_platform;
 use dioxus::prelude::*;
 
@@ -16,7 +16,11 @@ pub fn PCViewRoute(world_id: String) -> Element {
     let platform = use_platform();
     let pc_service = crate::presentation::services::use_player_character_service();
 
-    // Check for existing PC on mount - redirect to creation if none exists
+    // Keep the scene readable and queue actions across dropped connections
+    use_offline_sync(world_id.clone());
+
+    // Check for existing PC on mount - redirect to creation if none exists,
+    // or to the successor flow if they have died
     {
         let world_id_clone = world_id.clone();
         let user_id = platform.get_user_id();
@@ -29,6 +33,10 @@ pub fn PCViewRoute(world_id: String) -> Element {
             let pc_svc_clone = pc_svc.clone();
             spawn_task(async move {
                 match pc_svc_clone.get_my_pc(&wid, &uid).await {
+                    Ok(Some(pc)) if !pc.is_alive => {
+                        // PC has fallen, carry on through a successor
+                        nav_clone.push(Route::PCSuccessorRoute { world_id: wid });
+                    }
                     Ok(Some(_pc)) => {
                         // PC exists, continue to PC View
                     }
@@ -50,6 +58,7 @@ pub fn PCViewRoute(world_id: String) -> Element {
             world_id: world_id.clone(),
             role: ParticipantRole::Player,
             page_title: "Playing",
+            offline_capable: true,
 
             PCViewContent {}
         }
@@ -11,8 +11,10 @@ use dioxus::prelude::*;
 use crate::ports::outbound::storage_keys;
 use crate::ports::session_types::ParticipantRole;
 use crate::presentation::state::{
-    ConnectionStatus, DialogueState, GameState, GenerationState, LoreState, SessionState,
+    use_theme_state, ConnectionStatus, DialogueState, GameState, GenerationState, LoreState,
+    SessionState, ThemeMode,
 };
+use crate::presentation::utils::world_accent_style;
 use crate::use_platform;
 use uuid::Uuid;
 
@@ -32,6 +34,10 @@ pub struct WorldSessionLayoutProps {
     /// Set to false for views that have their own header with status indicator
     #[props(default = true)]
     pub show_status_bar: bool,
+    /// Keep showing the view from the last loaded (or cached) world while the
+    /// connection is down, instead of swapping it for "Connecting..."
+    #[props(default = false)]
+    pub offline_capable: bool,
     /// Child content to render
     pub children: Element,
 }
@@ -111,10 +117,31 @@ pub fn WorldSessionLayout(props: WorldSessionLayoutProps) -> Element {
     let is_connected_to_requested_world = connection_status == ConnectionStatus::Connected
         && requested_world.is_some()
         && current_world == requested_world;
+    let has_requested_world_loaded = game_state
+        .world
+        .read()
+        .as_ref()
+        .is_some_and(|w| w.world.id == props.world_id);
+    let show_children =
+        is_connected_to_requested_world || (props.offline_capable && has_requested_world_loaded);
+    let font_stylesheet_url = game_state.typography.read().font_stylesheet_url.clone();
+    // High contrast keeps its own accent so the DM's color can't reduce legibility
+    let theme_mode = *use_theme_state().mode.read();
+    let accent_style = if theme_mode == ThemeMode::HighContrast {
+        String::new()
+    } else {
+        world_accent_style(&game_state.world_theme.read())
+    };
 
     rsx! {
         div {
             class: "world-session-layout h-full flex flex-col bg-dark-bg",
+            style: "{accent_style}",
+
+            // World font (see Typography settings)
+            if let Some(href) = font_stylesheet_url {
+                document::Stylesheet { href }
+            }
 
             // Connection status bar
             //
@@ -171,7 +198,7 @@ pub fn WorldSessionLayout(props: WorldSessionLayoutProps) -> Element {
             // Main content area
             main {
                 class: "flex-1 overflow-hidden relative",
-                if is_connected_to_requested_world {
+                if show_children {
                     {props.children}
                 } else {
                     div {
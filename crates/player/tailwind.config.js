@@ -1,3 +1,10 @@
+/** A color backed by the `--color-<name>` variable (space-separated RGB) */
+const themed = (name) => `rgb(var(--color-${name}) / <alpha-value>)`;
+
+/** A shade scale whose steps are `--color-<name>-<shade>` variables */
+const scale = (name, shades) =>
+  Object.fromEntries(shades.map((shade) => [shade, themed(`${name}-${shade}`)]));
+
 /** @type {import('tailwindcss').Config} */
 module.exports = {
   content: [
@@ -6,41 +13,24 @@ module.exports = {
   ],
   theme: {
     extend: {
+      // Palettes read CSS variables so themes (see styles/input.css) and the
+      // world accent color can swap them at runtime.
       colors: {
+        'white': themed('white'),
+        'gray': scale('gray', [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950]),
+        'amber': scale('amber', [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950]),
         // Dark UI theme colors
         'dark': {
-          'bg': '#0f0f23',
-          'surface': '#1a1a2e',
-          'border': '#374151',
-          'hover': '#252540',
-          'gradient-end': '#16213e',
-          'purple-end': '#2d1b3d',
+          'bg': themed('dark-bg'),
+          'surface': themed('dark-surface'),
+          'border': themed('dark-border'),
+          'hover': themed('dark-hover'),
+          'gradient-end': themed('dark-gradient-end'),
+          'purple-end': themed('dark-purple-end'),
         },
         // TTRPG themed colors
-        'parchment': {
-          50: '#fdfcf9',
-          100: '#f9f5eb',
-          200: '#f3ead5',
-          300: '#e9d9b5',
-          400: '#dcc48f',
-          500: '#cfae6a',
-          600: '#b8914d',
-          700: '#9a7540',
-          800: '#7d5f38',
-          900: '#664e31',
-        },
-        'ink': {
-          50: '#f6f6f7',
-          100: '#e2e3e5',
-          200: '#c5c6cb',
-          300: '#a1a3ab',
-          400: '#7d808a',
-          500: '#62656f',
-          600: '#4d4f58',
-          700: '#3f4148',
-          800: '#35373c',
-          900: '#2e3034',
-        },
+        'parchment': scale('parchment', [50, 100, 200, 300, 400, 500, 600, 700, 800, 900]),
+        'ink': scale('ink', [50, 100, 200, 300, 400, 500, 600, 700, 800, 900]),
         'blood': {
           50: '#fef2f2',
           100: '#fee2e2',
@@ -53,18 +43,7 @@ module.exports = {
           800: '#5a0000',
           900: '#4a0000',
         },
-        'gold': {
-          50: '#fffbeb',
-          100: '#fef3c7',
-          200: '#fde68a',
-          300: '#fcd34d',
-          400: '#fbbf24',
-          500: '#d4af37',
-          600: '#b8972f',
-          700: '#9a7e27',
-          800: '#7c661f',
-          900: '#5e4d17',
-        },
+        'gold': scale('gold', [50, 100, 200, 300, 400, 500, 600, 700, 800, 900]),
       },
       fontFamily: {
         'fantasy': ['Cinzel', 'Georgia', 'serif'],
@@ -77,7 +56,7 @@ module.exports = {
       },
       boxShadow: {
         'vignette': 'inset 0 0 100px rgba(0,0,0,0.3)',
-        'glow': '0 0 20px rgba(212, 175, 55, 0.5)',
+        'glow': '0 0 20px rgb(var(--color-gold-500) / 0.5)',
       },
       animation: {
         'typewriter': 'typewriter 2s steps(40) forwards',
@@ -85,6 +64,7 @@ module.exports = {
         'fade-out': 'fadeOut 0.5s ease-in-out forwards',
         'slide-up': 'slideUp 0.3s ease-out',
         'backdrop-crossfade': 'backdropCrossfade 0.8s ease-in-out',
+        'dice-tumble': 'diceTumble 0.5s linear infinite',
       },
       keyframes: {
         typewriter: {
@@ -108,6 +88,11 @@ module.exports = {
           '50%': { opacity: '1' },
           '100%': { opacity: '0' },
         },
+        diceTumble: {
+          '0%': { transform: 'rotateX(0deg) rotateY(0deg)' },
+          '50%': { transform: 'rotateX(180deg) rotateY(90deg)' },
+          '100%': { transform: 'rotateX(360deg) rotateY(180deg)' },
+        },
       },
     },
   },
@@ -0,0 +1,7259 @@
+// Generated by `cargo xtask protocol-schema` - do not edit.
+// Types for the WrldBldr Engine <-> Player wire protocol.
+
+export type ActRequest = {
+  type: "list_acts";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "create_act";
+  data: CreateActData;
+  world_id: string;
+};
+
+/**
+ * Actantial actor data (helper, opponent, sender, receiver)
+ */
+export type ActantialActorData = {
+  actor_type: ActorTypeData;
+  id: string;
+  name: string;
+  reason: string;
+};
+
+export type ActantialRequest = {
+  type: "get_actantial_context";
+  character_id: string;
+} | {
+  type: "add_actantial_view";
+  character_id: string;
+  reason: string;
+  role: ActantialRoleData;
+  target_id: string;
+  target_type: ActorTypeData;
+  want_id: string;
+} | {
+  type: "remove_actantial_view";
+  character_id: string;
+  role: ActantialRoleData;
+  target_id: string;
+  target_type: ActorTypeData;
+  want_id: string;
+};
+
+/**
+ * Actantial role type for character views
+ */
+export type ActantialRoleData = "helper" | "opponent" | "sender" | "receiver" | "unknown";
+
+/**
+ * Actantial view data (for adding/removing views)
+ */
+export type ActantialViewData = {
+  reason: string;
+  role: ActantialRoleData;
+  target_id: string;
+  target_name: string;
+  target_type: ActorTypeData;
+  want_id: string;
+};
+
+/**
+ * Type discriminator for actors (NPC vs PC)
+ */
+export type ActorTypeData = "npc" | "pc" | "unknown";
+
+/**
+ * Ad-hoc challenge outcomes for DM-created challenges
+ *
+ * Domain representation of custom outcome text for challenges
+ * created on-the-fly by the DM.
+ */
+export type AdHocOutcomes = {
+  /**
+   * Optional outcome text for a critical failure
+   */
+  criticalFailure?: string | null;
+  /**
+   * Optional outcome text for a critical success
+   */
+  criticalSuccess?: string | null;
+  /**
+   * Outcome text for a failed roll
+   */
+  failure: string;
+  /**
+   * Outcome text for a successful roll
+   */
+  success: string;
+};
+
+/**
+ * Data for adding a modifier to a stat
+ */
+export type AddModifierData = {
+  /**
+   * Whether the modifier is active (defaults to true)
+   */
+  active?: boolean;
+  /**
+   * Source of the modifier (e.g., "Sword of Strength", "Bless spell")
+   */
+  source: string;
+  /**
+   * Name of the stat to modify
+   */
+  statName: string;
+  /**
+   * Value to add (positive) or subtract (negative)
+   */
+  value: number;
+};
+
+/**
+ * A character who aged during a time skip
+ */
+export type AgeProgressionData = {
+  characterId: string;
+  lifeStage: LifeStageData;
+  name: string;
+  newAge: number;
+  /**
+   * None if the character was born during the skip
+   */
+  previousAge?: number | null;
+  /**
+   * Whether the character entered a new life stage
+   */
+  stageChanged: boolean;
+};
+
+export type AiRequest = {
+  type: "suggest_deflection_behavior";
+  npc_id: string;
+  want_description: string;
+  want_id: string;
+} | {
+  type: "suggest_behavioral_tells";
+  npc_id: string;
+  want_description: string;
+  want_id: string;
+} | {
+  type: "suggest_want_description";
+  context?: string | null;
+  npc_id: string;
+} | {
+  type: "suggest_actantial_reason";
+  npc_id: string;
+  role: ActantialRoleData;
+  target_id: string;
+  want_id: string;
+} | {
+  type: "enqueue_content_suggestion";
+  context: SuggestionContextData;
+  suggestion_type: string;
+  world_id: string;
+} | {
+  type: "cancel_content_suggestion";
+  request_id: string;
+} | {
+  type: "list_models";
+} | {
+  type: "pull_model";
+  model: string;
+} | {
+  type: "set_world_model";
+  dialogue_model?: string | null;
+  summary_model?: string | null;
+  world_id: string;
+} | {
+  type: "list_co_dm_reviews";
+  /**
+   * At most this many (capped server-side)
+   */
+  limit?: number | null;
+  world_id: string;
+};
+
+/**
+ * Request to analyze a workflow (without saving).
+ */
+export type AnalyzeWorkflowRequestDto = {
+  workflowJson: unknown;
+};
+
+/**
+ * DM's decision on an approval request
+ */
+export type ApprovalDecision = {
+  decision: "Accept";
+} | {
+  decision: "AcceptWithRecipients";
+  /**
+   * For give_item tools: maps tool_id -> recipient PC IDs
+   * Empty list means "don't give this item"
+   */
+  item_recipients: Record<string, string[]>;
+} | {
+  decision: "AcceptWithModification";
+  approved_tools: string[];
+  /**
+   * For give_item tools: maps tool_id -> recipient PC IDs
+   * Empty list means "don't give this item"
+   */
+  item_recipients?: Record<string, string[]>;
+  modified_dialogue: string;
+  rejected_tools: string[];
+} | {
+  decision: "Reject";
+  feedback: string;
+} | {
+  decision: "TakeOver";
+  dm_response: string;
+} | {
+  decision: "Unknown";
+};
+
+/**
+ * Which pending approvals a bulk decision applies to
+ */
+export type ApprovalSelection = {
+  type: "requests";
+  request_ids: string[];
+} | {
+  type: "conversation";
+  conversation_id: string;
+} | {
+  type: "source_action";
+  action_id: string;
+} | {
+  type: "ambient";
+} | {
+  type: "unknown";
+};
+
+/**
+ * DM's decision for an NPC in staging
+ */
+export type ApprovedNpcInfo = {
+  character_id: string;
+  /**
+   * When true, NPC is present but hidden from players
+   */
+  is_hidden_from_players?: boolean;
+  is_present: boolean;
+  /**
+   * NPC's mood for this staging (Tier 2 of emotional model)
+   * If None, uses character's default_mood
+   */
+  mood?: string | null;
+  /**
+   * Optional override reasoning (if DM modified)
+   */
+  reasoning?: string | null;
+};
+
+/**
+ * An entity an uploaded image likely depicts
+ */
+export type AssetAssociationSuggestionDto = {
+  /**
+   * 0.0 - 1.0
+   */
+  confidence: number;
+  entityId: string;
+  entityType: string;
+  name: string;
+  /**
+   * Why the entity was suggested, for display
+   */
+  reason: string;
+};
+
+/**
+ * Decorative frame drawn around scene backdrops (wire format)
+ */
+export type BackdropFrameData = "plain" | "simple" | "ornate" | "parchment" | "vignette" | "unknown";
+
+/**
+ * Thresholds for Blades d6 pool resolution
+ */
+export type BladesPoolThresholds = {
+  /**
+   * Highest die value for full success (default: 6)
+   */
+  fullSuccess: number;
+  /**
+   * Maximum die value for partial success (default: 5)
+   */
+  partialSuccessMax: number;
+  /**
+   * Minimum die value for partial success (default: 4)
+   */
+  partialSuccessMin: number;
+};
+
+/**
+ * One story in a broadsheet
+ */
+export type BroadsheetArticleData = {
+  body?: string;
+  headline: string;
+};
+
+/**
+ * An issue of the world's broadsheet
+ */
+export type BroadsheetData = {
+  articles: BroadsheetArticleData[];
+  id: string;
+  issueNumber: number;
+  /**
+   * Game time the issue's news runs to (RFC 3339)
+   */
+  periodEnd: string;
+  /**
+   * Game time the issue's news starts from (RFC 3339)
+   */
+  periodStart: string;
+  /**
+   * False while the DM is still editing it
+   */
+  published: boolean;
+  title: string;
+};
+
+/**
+ * The DM's edits to a broadsheet draft
+ */
+export type BroadsheetInputData = {
+  articles?: BroadsheetArticleData[];
+  title: string;
+};
+
+/**
+ * The current world's broadsheet, one issue per game week
+ *
+ * Players read published issues. The DM sees drafts too, and edits,
+ * publishes or deletes them.
+ */
+export type BroadsheetRequest = {
+  type: "list_broadsheets";
+} | {
+  type: "generate_broadsheet";
+} | {
+  type: "update_broadsheet";
+  broadsheet_id: string;
+  data: BroadsheetInputData;
+} | {
+  type: "publish_broadsheet";
+  broadsheet_id: string;
+} | {
+  type: "delete_broadsheet";
+  broadsheet_id: string;
+};
+
+/**
+ * Decision applied to every approval in a bulk selection
+ */
+export type BulkApprovalDecision = {
+  decision: "Accept";
+} | {
+  decision: "Reject";
+  feedback: string;
+} | {
+  decision: "Unknown";
+};
+
+/**
+ * DM's decision on a challenge outcome (wire format)
+ */
+export type ChallengeOutcomeDecisionData = {
+  action: "accept";
+} | {
+  action: "edit";
+  modified_description: string;
+} | {
+  action: "suggest";
+  guidance?: string | null;
+} | {
+  action: "unknown";
+};
+
+export type ChallengeRequest = {
+  type: "list_challenges";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_challenge";
+  challenge_id: string;
+} | {
+  type: "create_challenge";
+  data: CreateChallengeData;
+  world_id: string;
+} | {
+  type: "update_challenge";
+  challenge_id: string;
+  data: UpdateChallengeData;
+} | {
+  type: "delete_challenge";
+  challenge_id: string;
+} | {
+  type: "duplicate_challenge";
+  challenge_id: string;
+} | {
+  type: "set_challenge_active";
+  active: boolean;
+  challenge_id: string;
+} | {
+  type: "set_challenge_favorite";
+  challenge_id: string;
+  favorite: boolean;
+} | {
+  type: "get_history";
+  /**
+   * At most this many (capped server-side)
+   */
+  limit?: number | null;
+  /**
+   * Only this PC's resolutions
+   */
+  pc_id?: string | null;
+  world_id: string;
+};
+
+/**
+ * Challenge suggestion information for DM approval
+ */
+export type ChallengeSuggestionInfo = {
+  challenge_id: string;
+  challenge_name: string;
+  confidence: string;
+  difficulty_display: string;
+  /**
+   * Optional editable outcomes for DM modification
+   */
+  outcomes?: ChallengeSuggestionOutcomes | null;
+  /**
+   * Each active PC's odds at the suggested difficulty
+   */
+  party_odds?: PcSuccessChanceInfo[];
+  reasoning: string;
+  skill_name: string;
+  /**
+   * Target player character ID for skill modifier lookup
+   */
+  target_pc_id?: string | null;
+};
+
+/**
+ * Editable challenge outcomes for DM modification
+ */
+export type ChallengeSuggestionOutcomes = {
+  critical_failure?: string | null;
+  critical_success?: string | null;
+  failure?: string | null;
+  success?: string | null;
+};
+
+/**
+ * Data for changing an archetype
+ */
+export type ChangeArchetypeData = {
+  new_archetype: string;
+  reason: string;
+};
+
+/**
+ * Types of entity changes for broadcast notifications
+ */
+export type ChangeType = "created" | "updated" | "deleted" | "unknown";
+
+/**
+ * Character data for display
+ */
+export type CharacterData = {
+  /**
+   * Character's current expression (Tier 3 - for sprite swapping)
+   * Used to build expression-specific sprite URL
+   */
+  expression?: string | null;
+  id: string;
+  is_speaking: boolean;
+  /**
+   * Character's current mood (Tier 2 - shown as badge on sprite)
+   * E.g., "anxious", "calm", "excited"
+   */
+  mood?: string | null;
+  name: string;
+  portrait_asset?: string | null;
+  position: CharacterPosition;
+  sprite_asset?: string | null;
+};
+
+/**
+ * Character position on screen
+ */
+export type CharacterPosition = "Left" | "Center" | "Right" | "OffScreen" | "Unknown";
+
+export type CharacterRequest = {
+  type: "list_characters";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_character";
+  character_id: string;
+} | {
+  type: "create_character";
+  data: CreateCharacterData;
+  world_id: string;
+} | {
+  type: "update_character";
+  character_id: string;
+  data: UpdateCharacterData;
+} | {
+  type: "delete_character";
+  character_id: string;
+} | {
+  type: "duplicate_character";
+  character_id: string;
+} | {
+  type: "change_archetype";
+  character_id: string;
+  data: ChangeArchetypeData;
+} | {
+  type: "get_character_inventory";
+  character_id: string;
+};
+
+/**
+ * Character sheet operations
+ */
+export type CharacterSheetRequest = {
+  type: "get_schema";
+  /**
+   * Game system ID (e.g., "dnd5e", "pf2e", "blades")
+   */
+  system_id: string;
+} | {
+  type: "get_world_schema";
+  /**
+   * World whose rule system picks the schema
+   */
+  world_id: string;
+} | {
+  type: "list_systems";
+} | {
+  type: "start_creation";
+  /**
+   * Optional character name (can be set later)
+   */
+  name?: string | null;
+  /**
+   * Game system ID
+   */
+  system_id: string;
+  /**
+   * World the character will belong to
+   */
+  world_id: string;
+} | {
+  type: "update_creation_field";
+  /**
+   * Character being created
+   */
+  character_id: string;
+  /**
+   * Field to update
+   */
+  field_id: string;
+  /**
+   * New value
+   */
+  value: unknown;
+} | {
+  type: "complete_creation";
+  /**
+   * Character being created
+   */
+  character_id: string;
+} | {
+  type: "cancel_creation";
+  /**
+   * Character being created
+   */
+  character_id: string;
+} | {
+  type: "get_sheet";
+  /**
+   * Character ID
+   */
+  character_id: string;
+} | {
+  type: "update_field";
+  /**
+   * Character ID
+   */
+  character_id: string;
+  /**
+   * Field to update
+   */
+  field_id: string;
+  /**
+   * New value
+   */
+  value: unknown;
+} | {
+  type: "update_fields";
+  /**
+   * Character ID
+   */
+  character_id: string;
+  /**
+   * Fields to update
+   */
+  updates: FieldUpdateData[];
+} | {
+  type: "get_calculated_values";
+  /**
+   * Character ID
+   */
+  character_id: string;
+} | {
+  type: "recalculate_all";
+  /**
+   * Character ID
+   */
+  character_id: string;
+};
+
+/**
+ * Character ages and lore dates in the world the DM is in (DM only)
+ */
+export type ChronologyRequest = {
+  type: "get_chronology";
+} | {
+  type: "set_birth_date";
+  /**
+   * YYYY-MM-DD on the game calendar; None clears it
+   */
+  birth_date?: string | null;
+  character_id: string;
+} | {
+  type: "set_lore_event_year";
+  event_year?: number | null;
+  lore_id: string;
+};
+
+/**
+ * Messages from client (Player) to server (Engine)
+ */
+export type ClientMessage = {
+  type: "PlayerAction";
+  action_type: string;
+  /**
+   * The `DialogueChoice` picked, for `dialogue_choice` actions
+   */
+  choice_id?: string | null;
+  dialogue?: string | null;
+  target?: string | null;
+} | {
+  type: "RetractAction";
+  action_id: string;
+} | {
+  type: "StartConversation";
+  message: string;
+  npc_id: string;
+} | {
+  type: "ContinueConversation";
+  /**
+   * Optional conversation ID - if provided, validates against active conversation
+   */
+  conversation_id?: string | null;
+  message: string;
+  npc_id: string;
+} | {
+  type: "PerformInteraction";
+  interaction_id: string;
+} | {
+  type: "RequestSceneChange";
+  scene_id: string;
+} | {
+  type: "DirectorialUpdate";
+  context: DirectorialContext;
+} | {
+  type: "ApprovalDecision";
+  decision: ApprovalDecision;
+  request_id: string;
+} | {
+  type: "BulkApprovalDecision";
+  decision: BulkApprovalDecision;
+  selection: ApprovalSelection;
+} | {
+  type: "ChallengeRoll";
+  challenge_id: string;
+  roll: number;
+} | {
+  type: "ChallengeRollInput";
+  challenge_id: string;
+  /**
+   * Dice input - either "formula" with dice string, or "manual" with result
+   */
+  input_type: DiceInputType;
+} | {
+  type: "TriggerChallenge";
+  challenge_id: string;
+  target_character_id: string;
+} | {
+  type: "ChallengeSuggestionDecision";
+  approved: boolean;
+  modified_difficulty?: string | null;
+  request_id: string;
+} | {
+  type: "NarrativeEventSuggestionDecision";
+  approved: boolean;
+  event_id: string;
+  request_id: string;
+  /**
+   * Optional selected outcome if DM pre-selects an outcome
+   */
+  selected_outcome?: string | null;
+} | {
+  type: "Heartbeat";
+} | {
+  type: "CheckComfyUIHealth";
+} | {
+  type: "RegenerateOutcome";
+  guidance?: string | null;
+  outcome_type?: string | null;
+  request_id: string;
+} | {
+  type: "DiscardChallenge";
+  feedback?: string | null;
+  request_id: string;
+} | {
+  type: "CreateAdHocChallenge";
+  challenge_name: string;
+  difficulty: string;
+  outcomes: AdHocOutcomes;
+  skill_name: string;
+  target_pc_id: string;
+} | {
+  type: "ChallengeOutcomeDecision";
+  decision: ChallengeOutcomeDecisionData;
+  resolution_id: string;
+} | {
+  type: "RequestOutcomeSuggestion";
+  guidance?: string | null;
+  resolution_id: string;
+} | {
+  type: "RequestOutcomeBranches";
+  guidance?: string | null;
+  resolution_id: string;
+} | {
+  type: "SelectOutcomeBranch";
+  branch_id: string;
+  modified_description?: string | null;
+  resolution_id: string;
+} | {
+  type: "ShareNpcLocation";
+  location_id: string;
+  notes?: string | null;
+  npc_id: string;
+  pc_id: string;
+  region_id: string;
+} | {
+  type: "SelectPlayerCharacter";
+  pc_id: string;
+} | {
+  type: "MoveToRegion";
+  pc_id: string;
+  region_id: string;
+} | {
+  type: "ExitToLocation";
+  arrival_region_id?: string | null;
+  location_id: string;
+  pc_id: string;
+} | {
+  type: "FastTravel";
+  location_id: string;
+  pc_id: string;
+} | {
+  type: "UnlockWithKey";
+  item_id: string;
+  pc_id: string;
+  to: LockedWayData;
+} | {
+  type: "ResolveJourney";
+  decision: JourneyDecision;
+  journey_id: string;
+} | {
+  type: "TriggerApproachEvent";
+  description: string;
+  npc_id: string;
+  /**
+   * When false, player sees "Unknown Figure" and no sprite
+   */
+  reveal?: boolean;
+  target_pc_id: string;
+} | {
+  type: "TriggerLocationEvent";
+  description: string;
+  region_id: string;
+} | {
+  type: "MakeNoise";
+  description: string;
+  loudness: LoudnessData;
+  region_id: string;
+} | {
+  type: "ShowOverlay";
+  asset: string;
+  caption?: string | null;
+  /**
+   * Seconds before the overlay closes itself (None = until dismissed)
+   */
+  duration_secs?: number | null;
+} | {
+  type: "DismissOverlay";
+  overlay_id: string;
+} | {
+  type: "StagingApprovalResponse";
+  /**
+   * Final list of NPCs with presence decisions
+   */
+  approved_npcs: ApprovedNpcInfo[];
+  /**
+   * Selected location state ID (None = use default or no override)
+   */
+  location_state_id?: string | null;
+  /**
+   * Selected region state ID (None = use default or no override)
+   */
+  region_state_id?: string | null;
+  request_id: string;
+  /**
+   * How this staging was finalized: "rule", "llm", "custom"
+   */
+  source: string;
+  /**
+   * TTL override (or use default)
+   */
+  ttl_hours: number;
+} | {
+  type: "StagingRegenerateRequest";
+  /**
+   * Guidance for LLM regeneration
+   */
+  guidance: string;
+  request_id: string;
+} | {
+  type: "PreStageRegion";
+  /**
+   * Selected location state ID
+   */
+  location_state_id?: string | null;
+  /**
+   * NPCs to pre-stage
+   */
+  npcs: ApprovedNpcInfo[];
+  region_id: string;
+  /**
+   * Selected region state ID
+   */
+  region_state_id?: string | null;
+  ttl_hours: number;
+} | {
+  type: "EquipItem";
+  item_id: string;
+  pc_id: string;
+} | {
+  type: "UnequipItem";
+  item_id: string;
+  pc_id: string;
+} | {
+  type: "DropItem";
+  item_id: string;
+  pc_id: string;
+  /**
+   * Number of items to drop (default 1)
+   */
+  quantity?: number;
+} | {
+  type: "PickupItem";
+  item_id: string;
+  pc_id: string;
+} | {
+  type: "OfferTrade";
+  from_pc_id: string;
+  offered_currency?: number;
+  offered_item_ids?: string[];
+  requested_currency?: number;
+  requested_item_ids?: string[];
+  to_pc_id: string;
+} | {
+  type: "RespondToTrade";
+  accept: boolean;
+  trade_id: string;
+} | {
+  type: "CancelTrade";
+  trade_id: string;
+} | {
+  type: "ShareKnowledge";
+  from_pc_id: string;
+  knowledge: SharedKnowledgeData;
+  target_pc_ids: string[];
+} | {
+  type: "SetGameTime";
+  day: number;
+  hour: number;
+  /**
+   * Whether to notify players of the time change
+   */
+  notify_players?: boolean;
+  world_id: string;
+} | {
+  type: "SkipToPeriod";
+  /**
+   * Period to skip to: "morning", "afternoon", "evening", "night"
+   */
+  period: string;
+  world_id: string;
+} | {
+  type: "PauseGameTime";
+  paused: boolean;
+  world_id: string;
+} | {
+  type: "SetTimeMode";
+  mode: TimeMode;
+  world_id: string;
+} | {
+  type: "SetTimeCosts";
+  costs: TimeCostConfig;
+  world_id: string;
+} | {
+  type: "RespondToTimeSuggestion";
+  decision: TimeSuggestionDecision;
+  suggestion_id: string;
+} | {
+  type: "SafetySignal";
+  level: SafetySignalLevelData;
+} | {
+  type: "SetActionQueuePaused";
+  paused: boolean;
+  world_id: string;
+} | {
+  type: "GrantLore";
+  /**
+   * Character receiving lore (PC or NPC ID)
+   */
+  character_id: string;
+  /**
+   * Specific chunk IDs to grant (empty = all chunks)
+   */
+  chunk_ids?: string[];
+  /**
+   * How the lore was discovered
+   */
+  discovery_source: LoreDiscoverySourceData;
+  lore_id: string;
+  /**
+   * Optional notes
+   */
+  notes?: string | null;
+} | {
+  type: "RevokeLore";
+  character_id: string;
+  lore_id: string;
+} | {
+  type: "JoinWorld";
+  /**
+   * Player character ID (required for Player role)
+   */
+  pc_id?: string | null;
+  /**
+   * Role to join as
+   */
+  role: WorldRole;
+  /**
+   * Target PC to spectate (required for Spectator role)
+   */
+  spectate_pc_id?: string | null;
+  /**
+   * Stable user identifier (from browser storage)
+   */
+  user_id: string;
+  /**
+   * World to join
+   */
+  world_id: string;
+} | {
+  type: "LeaveWorld";
+} | {
+  type: "ControlPc";
+  /**
+   * PC to add to the controlled set
+   */
+  pc_id: string;
+} | {
+  type: "SwitchPc";
+  /**
+   * Controlled PC to act as
+   */
+  pc_id: string;
+} | {
+  type: "SetLocale";
+  /**
+   * BCP 47 language tag, e.g. "es" or "de-AT"
+   */
+  locale: string;
+} | {
+  type: "Request";
+  /**
+   * Request payload
+   */
+  payload: RequestPayload;
+  /**
+   * Unique request ID for correlation
+   */
+  request_id: string;
+} | {
+  type: "SetSpectateTarget";
+  /**
+   * PC to spectate
+   */
+  pc_id: string;
+} | {
+  type: "Unknown";
+};
+
+/**
+ * What a progress clock tracks (wire format)
+ */
+export type ClockKindData = "faction" | "quest" | "threat" | "project" | "unknown";
+
+export type ClockRequest = {
+  type: "list_clocks";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_clock";
+  clock_id: string;
+} | {
+  type: "create_clock";
+  data: CreateClockData;
+  world_id: string;
+} | {
+  type: "update_clock";
+  clock_id: string;
+  data: UpdateClockData;
+} | {
+  type: "delete_clock";
+  clock_id: string;
+} | {
+  type: "tick_clock";
+  amount: number;
+  clock_id: string;
+} | {
+  type: "reset_clock";
+  clock_id: string;
+};
+
+/**
+ * Fields of a companion the DM can set; absent fields keep their current
+ * value, or the default when hiring
+ */
+export type CompanionInputData = {
+  /**
+   * Whether the companion moves with the PC; new hires do
+   */
+  followsPc?: boolean | null;
+  /**
+   * 0-100; new hires start at 50
+   */
+  loyalty?: number | null;
+  /**
+   * Pay per game day, in the world's currency
+   */
+  wagePerDay?: number | null;
+};
+
+/**
+ * Companions travelling with PCs in the DM's current world (DM only)
+ */
+export type CompanionRequest = {
+  type: "list_companions";
+  pc_id: string;
+} | {
+  type: "hire_companion";
+  character_id: string;
+  data?: CompanionInputData;
+  pc_id: string;
+} | {
+  type: "update_companion";
+  companion_id: string;
+  data: CompanionInputData;
+} | {
+  type: "dismiss_companion";
+  companion_id: string;
+} | {
+  type: "pay_wages";
+  companion_id: string;
+};
+
+/**
+ * Information about a user connected to a world
+ */
+export type ConnectedUser = {
+  /**
+   * Number of active connections (for DM with multiple screens)
+   */
+  connection_count: number;
+  /**
+   * Player character ID (for Player role)
+   */
+  pc_id?: string | null;
+  /**
+   * User's role in this world
+   */
+  role: WorldRole;
+  /**
+   * Unique user identifier
+   */
+  user_id: string;
+  /**
+   * Display name (if available)
+   */
+  username?: string | null;
+};
+
+/**
+ * What a locked connection needs to open; `None` in place of this leaves
+ * the connection unlocked
+ */
+export type ConnectionLockData = {
+  /**
+   * Challenge whose success opens the lock (e.g. lockpicking)
+   */
+  challenge_id?: string | null;
+  /**
+   * Shown to players in place of the way through
+   */
+  description?: string | null;
+  /**
+   * Items that open the lock
+   */
+  key_item_ids?: string[];
+};
+
+/**
+ * World content rating (wire format)
+ */
+export type ContentRatingData = "general" | "teen" | "mature" | "unknown";
+
+/**
+ * Per-world safety settings: lines, veils, and content rating
+ */
+export type ContentSafetyData = {
+  contentRating?: ContentRatingData;
+  /**
+   * Topics that must never appear
+   */
+  lines?: string[];
+  /**
+   * Lowest safety signal level that pauses the action queue (None = never)
+   */
+  pauseQueueAt?: SafetySignalLevelData | null;
+  /**
+   * Lowest safety signal level that rejects pending NPC dialogue (None = never)
+   */
+  rejectPendingAt?: SafetySignalLevelData | null;
+  /**
+   * Topics that may occur but stay off-screen
+   */
+  veils?: string[];
+};
+
+/**
+ * The writer or addressee of a letter
+ */
+export type CorrespondentData = {
+  id: string;
+  kind: CorrespondentKindData;
+  /**
+   * Filled in by the server; ignored on input
+   */
+  name?: string | null;
+};
+
+/**
+ * Which kind of character is at one end of a letter (wire format)
+ */
+export type CorrespondentKindData = "pc" | "npc" | "unknown";
+
+/**
+ * Data for creating an act
+ */
+export type CreateActData = {
+  description?: string | null;
+  name: string;
+  order?: number | null;
+};
+
+/**
+ * Data for creating a challenge
+ */
+export type CreateChallengeData = {
+  description?: string | null;
+  difficulty: string;
+  failure_outcome?: string | null;
+  name: string;
+  skill_id: string;
+  success_outcome?: string | null;
+};
+
+/**
+ * Data for creating a character
+ */
+export type CreateCharacterData = {
+  archetype?: string | null;
+  description?: string | null;
+  name: string;
+  portrait_asset?: string | null;
+  sprite_asset?: string | null;
+};
+
+/**
+ * Data for creating a progress clock
+ */
+export type CreateClockData = {
+  /**
+   * Party clock the personal objective works against
+   */
+  conflictsWithClockId?: string | null;
+  description?: string | null;
+  /**
+   * faction, quest, threat, or project (defaults to threat)
+   */
+  kind?: string | null;
+  name: string;
+  /**
+   * Make the clock a personal quest of this PC
+   */
+  ownerPcId?: string | null;
+  segments: number;
+  subject?: string | null;
+  visibleToPlayers?: boolean | null;
+};
+
+/**
+ * Data for creating a DM marker
+ */
+export type CreateDmMarkerData = {
+  content?: string | null;
+  /**
+   * Keep the marker hidden from players
+   */
+  hidden?: boolean;
+  /**
+   * Pin the marker to a map
+   */
+  pin?: MarkerPinData | null;
+  title: string;
+};
+
+/**
+ * Data for creating an event chain
+ */
+export type CreateEventChainData = {
+  /**
+   * Optional act association
+   */
+  act_id?: string | null;
+  /**
+   * Display color (hex or named)
+   */
+  color?: string | null;
+  description?: string | null;
+  /**
+   * Initial events to add to the chain
+   */
+  events?: string[] | null;
+  /**
+   * Whether the chain is active (defaults to true if not specified)
+   */
+  is_active?: boolean | null;
+  name: string;
+  /**
+   * Tags for categorization
+   */
+  tags?: string[] | null;
+};
+
+/**
+ * Data for creating a new goal
+ */
+export type CreateGoalData = {
+  description?: string | null;
+  name: string;
+};
+
+/**
+ * Data for creating an interaction
+ */
+export type CreateInteractionData = {
+  available?: boolean | null;
+  /**
+   * Times of day it is offered; empty means any time
+   */
+  available_times?: TimeOfDayData[];
+  /**
+   * Game minutes a PC must wait between uses
+   */
+  cooldown_minutes?: number | null;
+  description?: string | null;
+  /**
+   * How many times each PC may use it
+   */
+  max_uses?: number | null;
+  name: string;
+  trigger?: string | null;
+};
+
+/**
+ * Data for creating a new item
+ */
+export type CreateItemData = {
+  /**
+   * Price where nothing affects the item's market; not for sale when absent
+   */
+  basePrice?: number | null;
+  description?: string | null;
+  itemType?: string | null;
+  name: string;
+  properties?: unknown;
+};
+
+/**
+ * Data for creating a location connection
+ */
+export type CreateLocationConnectionData = {
+  bidirectional?: boolean | null;
+  from_id: string;
+  to_id: string;
+};
+
+/**
+ * Data for creating a location
+ */
+export type CreateLocationData = {
+  description?: string | null;
+  name: string;
+  /**
+   * The location this one sits inside
+   */
+  parent_id?: string | null;
+  scale?: LocationScaleData | null;
+  setting?: string | null;
+};
+
+/**
+ * Data for creating a lore chunk
+ */
+export type CreateLoreChunkData = {
+  content: string;
+  discoveryHint?: string | null;
+  order?: number | null;
+  /**
+   * Optional title for this chunk (may be omitted)
+   */
+  title?: string | null;
+};
+
+/**
+ * Data for creating a lore entry
+ */
+export type CreateLoreData = {
+  category?: string | null;
+  /**
+   * Initial chunks to create with the lore
+   */
+  chunks?: CreateLoreChunkData[] | null;
+  isCommonKnowledge?: boolean | null;
+  summary?: string | null;
+  tags?: string[] | null;
+  title: string;
+};
+
+/**
+ * Data for creating a narrative event
+ */
+export type CreateNarrativeEventData = {
+  description?: string | null;
+  name: string;
+  outcomes?: unknown;
+  trigger_conditions?: unknown;
+};
+
+/**
+ * Data for creating an observation
+ */
+export type CreateObservationData = {
+  location_id?: string | null;
+  notes?: string | null;
+  npc_id: string;
+  observation_type: string;
+  region_id?: string | null;
+};
+
+/**
+ * Data for creating a player character
+ */
+export type CreatePlayerCharacterData = {
+  name: string;
+  sheet_data?: unknown;
+  /**
+   * Starting region ID - if provided, PC will spawn at this region
+   */
+  starting_region_id?: string | null;
+  user_id?: string | null;
+};
+
+/**
+ * Data for creating a region connection
+ */
+export type CreateRegionConnectionData = {
+  bidirectional?: boolean | null;
+  description?: string | null;
+  locked?: boolean | null;
+};
+
+/**
+ * Data for creating a region
+ */
+export type CreateRegionData = {
+  description?: string | null;
+  is_spawn_point?: boolean | null;
+  name: string;
+};
+
+/**
+ * Data for creating a relationship
+ */
+export type CreateRelationshipData = {
+  description?: string | null;
+  from_character_id: string;
+  relationship_type: string;
+  to_character_id: string;
+};
+
+/**
+ * Data for creating a scene
+ */
+export type CreateSceneData = {
+  description?: string | null;
+  location_id?: string | null;
+  name: string;
+};
+
+/**
+ * Data for creating a skill
+ */
+export type CreateSkillData = {
+  attribute?: string | null;
+  category?: string | null;
+  description?: string | null;
+  name: string;
+};
+
+/**
+ * Data for creating a new want
+ */
+export type CreateWantData = {
+  deflection_behavior?: string | null;
+  description: string;
+  intensity?: number;
+  priority?: number;
+  target_id?: string | null;
+  target_type?: WantTargetTypeData | null;
+  tells?: string[];
+  visibility?: WantVisibilityData;
+};
+
+/**
+ * Request to create/update a workflow configuration.
+ */
+export type CreateWorkflowConfigRequestDto = {
+  inputDefaults?: InputDefaultDto[];
+  lockedInputs?: string[];
+  name: string;
+  promptMappings?: PromptMappingDto[];
+  referenceImageMappings?: ReferenceImageMappingDto[];
+  workflowJson: unknown;
+};
+
+/**
+ * Data for creating a world
+ */
+export type CreateWorldData = {
+  description?: string | null;
+  name: string;
+  setting?: string | null;
+};
+
+/**
+ * Fields of a crowd the DM can set
+ */
+export type CrowdInputData = {
+  approximateCount: number;
+  description?: string;
+  disposition?: DispositionLevel;
+  name: string;
+};
+
+/**
+ * A crowd in the scene, as players see it: no exact headcount
+ */
+export type CrowdPresenceData = {
+  crowd_id: string;
+  description?: string;
+  disposition: DispositionLevel;
+  name: string;
+  /**
+   * The headcount in words ("dozens")
+   */
+  size_label: string;
+};
+
+/**
+ * Background crowds in the DM's current world (DM only). Players see the
+ * present crowds of their region in `SceneChanged`.
+ */
+export type CrowdRequest = {
+  type: "list_crowds";
+  region_id?: string | null;
+} | {
+  type: "create_crowd";
+  data: CrowdInputData;
+  region_id: string;
+} | {
+  type: "update_crowd";
+  crowd_id: string;
+  data: CrowdInputData;
+} | {
+  type: "delete_crowd";
+  crowd_id: string;
+} | {
+  type: "set_crowd_present";
+  crowd_id: string;
+  present: boolean;
+};
+
+/**
+ * A DM-defined field on characters, locations or items
+ */
+export type CustomFieldDefinitionData = {
+  entityType: EntityType;
+  fieldType: CustomFieldTypeData;
+  /**
+   * Stable identifier (normalized to `snake_case` by the Engine)
+   */
+  key: string;
+  label: string;
+  /**
+   * Allowed values for select fields
+   */
+  options?: string[];
+  /**
+   * Whether players see the value in detail views
+   */
+  visibleToPlayers?: boolean;
+};
+
+/**
+ * What kind of value a custom field holds (wire format)
+ */
+export type CustomFieldTypeData = "text" | "number" | "select" | "unknown";
+
+/**
+ * A custom field value: a number, or text (select values are option text)
+ */
+export type CustomFieldValueData = number | string;
+
+/**
+ * A choice the player can answer a dialogue node with
+ */
+export type DialogueBranchData = {
+  /**
+   * Event effects run when the choice is taken, in the same shape as
+   * narrative event outcome effects
+   */
+  effects?: unknown[];
+  /**
+   * Id of the node the choice leads to; none ends the beat
+   */
+  next?: string | null;
+  /**
+   * World flags set when the choice is taken
+   */
+  setFlags?: string[];
+  text: string;
+};
+
+/**
+ * Dialogue choice for player
+ */
+export type DialogueChoice = {
+  id: string;
+  is_custom_input: boolean;
+  text: string;
+};
+
+/**
+ * A line the speaker says and the choices to answer it with
+ */
+export type DialogueNodeData = {
+  /**
+   * No choices ends the beat after the line
+   */
+  choices?: DialogueBranchData[];
+  /**
+   * Unique in the tree; choices refer to nodes by it
+   */
+  id: string;
+  line: string;
+};
+
+/**
+ * Fields of a dialogue tree the DM can set
+ */
+export type DialogueTreeInputData = {
+  active?: boolean;
+  name: string;
+  /**
+   * Play when this narrative event triggers instead of when a PC talks
+   * to the speaker
+   */
+  narrativeEventId?: string | null;
+  /**
+   * The first node is where the beat starts
+   */
+  nodes: DialogueNodeData[];
+  /**
+   * Play every time instead of once per PC
+   */
+  repeatable?: boolean;
+  /**
+   * The NPC who says every line
+   */
+  speakerId: string;
+};
+
+/**
+ * Branching dialogue trees in the DM's current world (DM only). Players
+ * answer them by sending a `PlayerAction` with the choice's `choice_id`.
+ */
+export type DialogueTreeRequest = {
+  type: "list_dialogue_trees";
+} | {
+  type: "create_dialogue_tree";
+  data: DialogueTreeInputData;
+} | {
+  type: "update_dialogue_tree";
+  data: DialogueTreeInputData;
+  tree_id: string;
+} | {
+  type: "delete_dialogue_tree";
+  tree_id: string;
+} | {
+  type: "reset_dialogue_tree";
+  tree_id: string;
+};
+
+/**
+ * A single die of a roll
+ */
+export type DiceDieData = {
+  /**
+   * Whether an exploding die added this one
+   */
+  exploded: boolean;
+  /**
+   * Whether the die counts toward its term
+   */
+  kept: boolean;
+  value: number;
+};
+
+/**
+ * Dice input type for challenge rolls
+ */
+export type DiceInputType = {
+  type: "Formula";
+  value: string;
+} | {
+  type: "Manual";
+  value: number;
+} | {
+  type: "Unknown";
+};
+
+export type DiceRequest = {
+  type: "roll";
+  formula: string;
+  world_id: string;
+};
+
+/**
+ * A server-side dice roll for wire transfer
+ */
+export type DiceRollData = {
+  /**
+   * Human-readable breakdown (e.g. "1d20(14) + 5 = 19")
+   */
+  breakdown: string;
+  diceTotal: number;
+  /**
+   * Normalized formula that was rolled (e.g. "2d6+1")
+   */
+  formula: string;
+  individualRolls: number[];
+  modifier: number;
+  /**
+   * Player character who rolled, if any
+   */
+  pcId?: string | null;
+  /**
+   * Unix timestamp (seconds) of the roll
+   */
+  rolledAt: number;
+  /**
+   * Display name of whoever rolled
+   */
+  rollerName: string;
+  /**
+   * Successes counted by dice pools (e.g. "5d10>=8"), if any
+   */
+  successes?: number | null;
+  /**
+   * Every term with every die rolled, including dropped and exploded dice
+   */
+  terms?: DiceTermData[];
+  total: number;
+};
+
+/**
+ * The dice system used for resolution
+ */
+export type DiceSystem = "d20" | "d100" | {
+  dice_pool: {
+    die_type: number;
+    success_threshold: number;
+  };
+} | "fate" | {
+  custom: string;
+};
+
+/**
+ * One term of a dice roll (e.g. "4d6kh3" or "2")
+ */
+export type DiceTermData = {
+  /**
+   * Empty for constant terms
+   */
+  dice: DiceDieData[];
+  /**
+   * Whether the term is subtracted
+   */
+  negative: boolean;
+  notation: string;
+  /**
+   * Kept dice summed, successes counted, or the constant
+   */
+  value: number;
+};
+
+/**
+ * Descriptive difficulty for narrative systems (also used as ladder keys)
+ */
+export type DifficultyDescriptor = "trivial" | "easy" | "routine" | "moderate" | "challenging" | "hard" | "veryHard" | "extreme" | "impossible" | "risky" | "desperate" | "unknown";
+
+/**
+ * Difficulty ladder for Fate-style systems
+ */
+export type DifficultyLadder = {
+  /**
+   * Ladder entries mapping descriptors to values
+   */
+  entries: LadderEntry[];
+  /**
+   * Shifts needed for success with style (default: 3)
+   */
+  styleThreshold: number;
+  /**
+   * Shift value that counts as a tie (default: 0)
+   */
+  tieThreshold: number;
+};
+
+/**
+ * Directorial context from DM
+ */
+export type DirectorialContext = {
+  forbidden_topics: string[];
+  npc_motivations: NpcMotivationData[];
+  scene_notes: string;
+  tone: string;
+};
+
+/**
+ * Disposition level - how an NPC emotionally feels about a specific PC
+ *
+ * This represents the NPC's subjective emotional stance toward a particular PC,
+ * which can change based on interactions, challenge outcomes, or DM direction.
+ *
+ * This is SEPARATE from RelationshipLevel (social distance).
+ */
+export type DispositionLevel = "hostile" | "suspicious" | "dismissive" | "neutral" | "respectful" | "friendly" | "grateful" | "unknown";
+
+/**
+ * The text field a draft is for (wire format)
+ */
+export type DraftFieldData = "unknown" | "description" | "atmosphere";
+
+/**
+ * Revision drafts of generated text fields (DM only). A draft's text only
+ * reaches the entity when one of its revisions is published.
+ */
+export type DraftRequest = {
+  type: "list_drafts";
+  entity_id: string;
+  entity_type: EntityType;
+} | {
+  type: "get_draft";
+  draft_id: string;
+} | {
+  type: "add_draft_revision";
+  entity_id: string;
+  entity_type: EntityType;
+  field: DraftFieldData;
+  source: RevisionSourceData;
+  text: string;
+  world_id: string;
+} | {
+  type: "diff_draft_revisions";
+  draft_id: string;
+  from: number;
+  to: number;
+} | {
+  type: "publish_draft_revision";
+  draft_id: string;
+  revision: number;
+} | {
+  type: "discard_draft";
+  draft_id: string;
+};
+
+/**
+ * Market modifiers and item prices in the DM's current world (DM only)
+ */
+export type EconomyRequest = {
+  type: "list_market_modifiers";
+} | {
+  type: "create_market_modifier";
+  data: MarketModifierInputData;
+} | {
+  type: "update_market_modifier";
+  data: MarketModifierInputData;
+  modifier_id: string;
+} | {
+  type: "delete_market_modifier";
+  modifier_id: string;
+} | {
+  type: "get_economy_overview";
+  location_id: string;
+} | {
+  type: "set_item_base_price";
+  base_price?: number | null;
+  item_id: string;
+};
+
+/**
+ * Effect tick configuration for progress clocks
+ */
+export type EffectTickConfig = {
+  extremeTicks: number;
+  greatTicks: number;
+  limitedTicks: number;
+  standardTicks: number;
+  zeroTicks: number;
+};
+
+/**
+ * Something that can happen to travellers crossing a location
+ */
+export type EncounterEntryData = {
+  description?: string;
+  name: string;
+  /**
+   * Relative chance against the table's other entries
+   */
+  weight: number;
+};
+
+/**
+ * A location's encounter table
+ */
+export type EncounterTableData = {
+  /**
+   * Chance of an encounter at each check, 0-100
+   */
+  chance_percent: number;
+  /**
+   * Game minutes between checks
+   */
+  check_interval_minutes: number;
+  entries: EncounterEntryData[];
+};
+
+/**
+ * Entity change notification for broadcasts
+ *
+ * Sent to all connected clients when an entity is created, updated, or deleted.
+ * Clients can use this to invalidate caches and update UI.
+ */
+export type EntityChangedData = {
+  /**
+   * Type of change
+   */
+  change_type: ChangeType;
+  /**
+   * Entity data (for create/update; None for delete)
+   */
+  data?: unknown;
+  /**
+   * ID of the entity
+   */
+  entity_id: string;
+  /**
+   * Type of entity that changed
+   */
+  entity_type: EntityType;
+  /**
+   * World this entity belongs to
+   */
+  world_id: string;
+};
+
+/**
+ * Unified entity type enum for the entire WrldBldr system
+ *
+ * This enum represents all types of entities in the game. It serves two purposes:
+ * 1. Asset management: Character, Location, Item can have gallery assets
+ * 2. Change broadcasts: All entity types can be created/updated/deleted
+ *
+ * Use `has_assets()` to check if an entity type can have gallery assets.
+ */
+export type EntityType = "character" | "location" | "item" | "world" | "region" | "scene" | "act" | "skill" | "challenge" | "interaction" | "narrative_event" | "event_chain" | "story_event" | "player_character" | "relationship" | "observation" | "goal" | "want" | "actantial_view" | "lore" | "progress_clock" | "game_time" | "unknown";
+
+/**
+ * Error classification codes
+ */
+export type ErrorCode = "bad_request" | "unauthorized" | "forbidden" | "not_found" | "conflict" | "validation_error" | "rate_limit_exceeded" | "internal_error" | "service_unavailable" | "timeout" | "unknown";
+
+export type EventChainRequest = {
+  type: "list_event_chains";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_event_chain";
+  chain_id: string;
+} | {
+  type: "create_event_chain";
+  data: CreateEventChainData;
+  world_id: string;
+} | {
+  type: "update_event_chain";
+  chain_id: string;
+  data: UpdateEventChainData;
+} | {
+  type: "delete_event_chain";
+  chain_id: string;
+} | {
+  type: "set_event_chain_active";
+  active: boolean;
+  chain_id: string;
+} | {
+  type: "set_event_chain_favorite";
+  chain_id: string;
+  favorite: boolean;
+} | {
+  type: "add_event_to_chain";
+  chain_id: string;
+  event_id: string;
+  position?: number | null;
+} | {
+  type: "remove_event_from_chain";
+  chain_id: string;
+  event_id: string;
+} | {
+  type: "complete_chain_event";
+  chain_id: string;
+  event_id: string;
+} | {
+  type: "reset_event_chain";
+  chain_id: string;
+} | {
+  type: "get_event_chain_status";
+  chain_id: string;
+};
+
+/**
+ * Query parameters DTO for export endpoints
+ */
+export type ExportQueryDto = {
+  format?: string | null;
+};
+
+export type ExpressionRequest = {
+  type: "generate_expression_sheet";
+  character_id: string;
+  expressions?: string[] | null;
+  grid_layout?: string | null;
+  style_prompt?: string | null;
+  workflow: string;
+};
+
+/**
+ * Fields of a faction the DM can set
+ */
+export type FactionInputData = {
+  description?: string;
+  /**
+   * Most pressing first
+   */
+  goals?: string[];
+  name: string;
+};
+
+/**
+ * An NPC to list as a faction member
+ */
+export type FactionMemberInputData = {
+  characterId: string;
+  /**
+   * What they do for the faction; empty for rank and file
+   */
+  role?: string;
+};
+
+/**
+ * Factions in the DM's current world (DM only). A faction's standing with
+ * the party shapes how its members are staged and voiced.
+ */
+export type FactionRequest = {
+  type: "list_factions";
+} | {
+  type: "create_faction";
+  data: FactionInputData;
+} | {
+  type: "update_faction";
+  data: FactionInputData;
+  faction_id: string;
+} | {
+  type: "delete_faction";
+  faction_id: string;
+} | {
+  type: "set_faction_members";
+  faction_id: string;
+  members: FactionMemberInputData[];
+} | {
+  type: "set_faction_reputation";
+  faction_id: string;
+  reputation: number;
+} | {
+  type: "adjust_faction_reputation";
+  delta: number;
+  faction_id: string;
+} | {
+  type: "set_faction_rivalry";
+  faction_id: string;
+  rival_id: string;
+  rivals: boolean;
+};
+
+/**
+ * Data for a single field update.
+ */
+export type FieldUpdateData = {
+  /**
+   * Field ID
+   */
+  fieldId: string;
+  /**
+   * New value
+   */
+  value: unknown;
+};
+
+/**
+ * A flashback in progress
+ */
+export type FlashbackData = {
+  /**
+   * The flashback's date, which the world clock shows meanwhile
+   */
+  gameTime: GameTime;
+  /**
+   * The present, restored when the flashback ends
+   */
+  liveTime: GameTime;
+  regionId: string;
+  startedAt: string;
+  title: string;
+};
+
+/**
+ * Flashback scenes in the current world. While a flashback runs the world
+ * clock shows its date and its region is restaged; ending it puts the
+ * present back. Starting and ending are DM-only.
+ */
+export type FlashbackRequest = {
+  type: "get_flashback";
+} | {
+  type: "start_flashback";
+  data: StartFlashbackData;
+} | {
+  type: "end_flashback";
+};
+
+/**
+ * Response DTO for gallery assets
+ */
+export type GalleryAssetResponseDto = {
+  assetType: string;
+  createdAt: string;
+  entityId: string;
+  entityType: string;
+  filePath: string;
+  id: string;
+  isActive: boolean;
+  isGenerated: boolean;
+  label?: string | null;
+  styleReferenceId?: string | null;
+};
+
+/**
+ * Which gallery assets to list; unset criteria match every asset
+ */
+export type GalleryFilterData = {
+  /**
+   * Asset slot, e.g. "portrait"
+   */
+  assetType?: string | null;
+  batchId?: string | null;
+  entityId?: string | null;
+  entityType?: EntityType | null;
+  favoritesOnly?: boolean;
+  tag?: string | null;
+};
+
+/**
+ * Browsing and organizing the gallery of the world the DM is in (DM only)
+ */
+export type GalleryRequest = {
+  type: "list_gallery";
+  filter?: GalleryFilterData;
+} | {
+  type: "set_assets_favorite";
+  asset_ids: string[];
+  favorite: boolean;
+} | {
+  type: "tag_assets";
+  add?: string[];
+  asset_ids: string[];
+  remove?: string[];
+};
+
+/**
+ * Game time representation for wire transfer
+ *
+ * Uses simple numeric fields for efficient JSON serialization.
+ * Conversion from domain GameTime happens in the adapter layer.
+ */
+export type GameTime = {
+  /**
+   * Day number (currently ordinal-style, 1-based; calendar is planned)
+   */
+  day: number;
+  /**
+   * Hour (0-23)
+   */
+  hour: number;
+  /**
+   * Whether time is paused
+   */
+  is_paused: boolean;
+  /**
+   * Minute (0-59)
+   */
+  minute: number;
+};
+
+/**
+ * Complete time configuration (wire format)
+ */
+export type GameTimeConfig = {
+  /**
+   * How time suggestions are handled
+   */
+  mode: TimeMode;
+  /**
+   * Whether to show exact time to players
+   */
+  showTimeToPlayers: boolean;
+  /**
+   * Default time costs per action type
+   */
+  timeCosts: TimeCostConfig;
+  /**
+   * Time format preference for display
+   */
+  timeFormat?: TimeFormat;
+};
+
+/**
+ * Request DTO for generating an asset
+ */
+export type GenerateAssetRequestDto = {
+  assetType: string;
+  count?: number;
+  entityId: string;
+  entityType: string;
+  negativePrompt?: string | null;
+  /**
+   * Named generation preset for the asset slot. None = the slot's default preset.
+   */
+  preset?: string | null;
+  prompt: string;
+  styleReferenceId?: string | null;
+  /**
+   * ComfyUI workflow. Empty = the preset's workflow, or the asset slot's.
+   */
+  workflow?: string;
+  /**
+   * World this asset belongs to
+   */
+  worldId: string;
+};
+
+/**
+ * Response DTO for generation batches
+ */
+export type GenerationBatchResponseDto = {
+  assetCount: number;
+  assetType: string;
+  completedAt?: string | null;
+  count: number;
+  entityId: string;
+  entityType: string;
+  id: string;
+  progress?: number | null;
+  prompt: string;
+  requestedAt: string;
+  status: string;
+  workflow: string;
+  /**
+   * World this batch belongs to
+   */
+  worldId: string;
+};
+
+export type GenerationRequest = {
+  type: "get_generation_queue";
+  user_id?: string | null;
+  world_id: string;
+} | {
+  type: "sync_generation_read_state";
+  read_batches: string[];
+  read_suggestions: string[];
+  world_id: string;
+} | {
+  type: "dismiss_suggestion";
+  request_id: string;
+} | {
+  type: "generate_names";
+  count?: number;
+  faction?: string | null;
+  generator_id?: string | null;
+  region_id?: string | null;
+  world_id: string;
+};
+
+/**
+ * Goal data for wire transfer
+ */
+export type GoalData = {
+  description?: string | null;
+  id: string;
+  name: string;
+  /**
+   * Number of wants targeting this goal (for UI display)
+   */
+  usage_count?: number;
+};
+
+export type GoalRequest = {
+  type: "list_goals";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_goal";
+  goal_id: string;
+} | {
+  type: "create_goal";
+  data: CreateGoalData;
+  world_id: string;
+} | {
+  type: "update_goal";
+  data: UpdateGoalData;
+  goal_id: string;
+} | {
+  type: "delete_goal";
+  goal_id: string;
+};
+
+/**
+ * A clickable area on a region backdrop, for wire transfer
+ */
+export type HotspotData = {
+  label: string;
+  points: HotspotPointData[];
+  target: HotspotTargetData;
+};
+
+/**
+ * A hotspot polygon vertex, as percent of the backdrop (0-100)
+ */
+export type HotspotPointData = {
+  x: number;
+  y: number;
+};
+
+/**
+ * What clicking a hotspot does (wire format)
+ */
+export type HotspotTargetData = {
+  type: "interaction";
+  interactionId: string;
+} | {
+  type: "item";
+  itemId: string;
+} | {
+  type: "exit";
+  regionId: string;
+} | {
+  type: "locationExit";
+  arrivalRegionId?: string | null;
+  locationId: string;
+};
+
+/**
+ * Import workflow configurations request.
+ */
+export type ImportWorkflowsRequestDto = {
+  data: unknown;
+  replaceExisting?: boolean;
+};
+
+/**
+ * Response for workflow import operation
+ */
+export type ImportWorkflowsResponseDto = {
+  imported: number;
+  skipped: number;
+};
+
+/**
+ * What an injury makes harder (wire format)
+ */
+export type InjuryCapabilityData = "mobility" | "manipulation" | "senses" | "speech" | "stamina" | "mind" | "unknown";
+
+/**
+ * An injury a PC carries, with its recovery as of the current game time
+ */
+export type InjuryData = {
+  capability: InjuryCapabilityData;
+  /**
+   * Game time the injury healed (RFC 3339)
+   */
+  healedAt?: string | null;
+  id: string;
+  /**
+   * Game time the injury was taken (RFC 3339)
+   */
+  injuredAt: string;
+  name: string;
+  notes: string;
+  pcId: string;
+  recoveryHours: number;
+  /**
+   * Game hours left; 0 once healed
+   */
+  remainingHours: number;
+  severity: InjurySeverityData;
+  treated: boolean;
+  treatmentChallengeId?: string | null;
+  /**
+   * Absent when there is no treatment challenge or it has been deleted
+   */
+  treatmentChallengeName?: string | null;
+};
+
+/**
+ * Fields of an injury the DM can set; absent fields keep their current
+ * value, or the rule system's default when inflicting
+ */
+export type InjuryInputData = {
+  /**
+   * Stamina when inflicting without one
+   */
+  capability?: InjuryCapabilityData | null;
+  /**
+   * Required when inflicting
+   */
+  name?: string | null;
+  notes?: string | null;
+  /**
+   * Game hours to heal; defaults to the rule system's time for the
+   * severity
+   */
+  recoveryHours?: number | null;
+  /**
+   * Minor when inflicting without one
+   */
+  severity?: InjurySeverityData | null;
+  /**
+   * Challenge the PC rolls to treat it; an empty string clears it
+   */
+  treatmentChallengeId?: string | null;
+};
+
+/**
+ * How injuries heal under a rule system.
+ *
+ * Recovery is counted in game hours. Every game hour that passes counts
+ * once (unless the system heals only during rest), and every hour spent
+ * resting counts `rest_multiplier` times in all.
+ */
+export type InjuryRecoveryConfig = {
+  /**
+   * Game hours a critical injury takes to heal
+   */
+  critical_hours: number;
+  /**
+   * Whether injuries heal only while the character rests
+   */
+  heals_only_while_resting?: boolean;
+  /**
+   * Game hours a minor injury takes to heal
+   */
+  minor_hours: number;
+  /**
+   * How many recovery hours each hour of rest is worth
+   */
+  rest_multiplier: number;
+  /**
+   * Game hours a serious injury takes to heal
+   */
+  serious_hours: number;
+  /**
+   * Share of the remaining recovery time a successful treatment removes,
+   * in percent
+   */
+  treatment_percent: number;
+};
+
+/**
+ * Injuries carried by PCs in the DM's current world (DM only)
+ */
+export type InjuryRequest = {
+  type: "list_injuries";
+  /**
+   * Also list injuries that have healed
+   */
+  include_healed?: boolean;
+  pc_id: string;
+} | {
+  type: "inflict_injury";
+  data: InjuryInputData;
+  pc_id: string;
+} | {
+  type: "update_injury";
+  data: InjuryInputData;
+  injury_id: string;
+} | {
+  type: "treat_injury";
+  injury_id: string;
+  succeeded: boolean;
+} | {
+  type: "delete_injury";
+  injury_id: string;
+};
+
+/**
+ * How bad an injury is (wire format)
+ */
+export type InjurySeverityData = "minor" | "serious" | "critical" | "unknown";
+
+/**
+ * DTO for input default values
+ */
+export type InputDefaultDto = {
+  defaultValue: unknown;
+  inputName: string;
+  nodeId: string;
+};
+
+/**
+ * DTO for input type classification
+ */
+export type InputTypeDto = "text" | "integer" | "float" | "boolean" | {
+  select: string[];
+} | "unknown";
+
+/**
+ * Available interaction
+ */
+export type InteractionData = {
+  id: string;
+  interaction_type: string;
+  is_available: boolean;
+  name: string;
+  target_id?: string | null;
+  target_name?: string | null;
+  target_type?: string | null;
+  /**
+   * Why the PC can't use it right now (cooldown, no uses left)
+   */
+  unavailable_reason?: string | null;
+  /**
+   * Uses the PC has left, if limited
+   */
+  uses_left?: number | null;
+};
+
+export type InteractionRequest = {
+  type: "list_interactions";
+  scene_id: string;
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+} | {
+  type: "get_interaction";
+  interaction_id: string;
+} | {
+  type: "list_available_interactions";
+  pc_id: string;
+} | {
+  type: "create_interaction";
+  data: CreateInteractionData;
+  scene_id: string;
+} | {
+  type: "update_interaction";
+  data: UpdateInteractionData;
+  interaction_id: string;
+} | {
+  type: "delete_interaction";
+  interaction_id: string;
+} | {
+  type: "set_interaction_availability";
+  available: boolean;
+  interaction_id: string;
+};
+
+/**
+ * An item blueprint
+ */
+export type ItemTemplateData = {
+  description?: string | null;
+  itemType?: string | null;
+  name: string;
+};
+
+export type ItemsRequest = {
+  type: "get_item";
+  item_id: string;
+} | {
+  type: "place_item_in_region";
+  item_id: string;
+  region_id: string;
+} | {
+  type: "create_and_place_item";
+  data: CreateItemData;
+  region_id: string;
+  world_id: string;
+} | {
+  type: "duplicate_item";
+  item_id: string;
+  /**
+   * Region to place the copy in; left unplaced when absent
+   */
+  region_id?: string | null;
+};
+
+/**
+ * Errors that can occur when joining a world
+ */
+export type JoinError = {
+  type: "dm_already_connected";
+  existing_user_id: string;
+} | {
+  type: "player_requires_pc";
+} | {
+  type: "spectator_requires_target";
+} | {
+  type: "world_not_found";
+} | {
+  type: "unauthorized";
+} | {
+  type: "unknown";
+};
+
+/**
+ * A PC's fast-travel journey
+ */
+export type JourneyData = {
+  /**
+   * Game minutes travelled so far
+   */
+  elapsed_minutes: number;
+  encounter?: JourneyEncounterData | null;
+  from_location_id: string;
+  from_location_name: string;
+  journey_id: string;
+  pc_id: string;
+  pc_name: string;
+  to_location_id: string;
+  to_location_name: string;
+  /**
+   * Game minutes the whole journey takes
+   */
+  total_minutes: number;
+};
+
+/**
+ * How the DM lets a stopped journey go on
+ */
+export type JourneyDecision = "unknown" | "continue" | "arrive" | "turn_back";
+
+/**
+ * The encounter a journey stopped for
+ */
+export type JourneyEncounterData = {
+  /**
+   * Game minutes into the journey
+   */
+  at_minute: number;
+  description?: string;
+  name: string;
+};
+
+/**
+ * Single entry in a difficulty ladder
+ */
+export type LadderEntry = {
+  /**
+   * The difficulty descriptor this maps
+   */
+  descriptor: DifficultyDescriptor;
+  /**
+   * Display name (e.g., "Fair", "Great", "Legendary")
+   */
+  displayName: string;
+  /**
+   * The numeric value for this descriptor
+   */
+  value: number;
+};
+
+/**
+ * An in-world letter
+ */
+export type LetterData = {
+  body: string;
+  /**
+   * Game time it arrives (RFC 3339)
+   */
+  deliverAt: string;
+  delivered: boolean;
+  id: string;
+  /**
+   * The letter this one answers
+   */
+  inReplyTo?: string | null;
+  read: boolean;
+  recipient: CorrespondentData;
+  sender: CorrespondentData;
+  /**
+   * Game time it was sent (RFC 3339)
+   */
+  sentAt: string;
+  subject: string;
+};
+
+/**
+ * A letter a PC writes
+ */
+export type LetterInputData = {
+  body: string;
+  /**
+   * A delivered letter the PC is answering
+   */
+  inReplyTo?: string | null;
+  recipient: CorrespondentData;
+  subject?: string;
+};
+
+/**
+ * The connected user's cross-world content library (DM only). Entries
+ * belong to the user, not to a world.
+ */
+export type LibraryRequest = {
+  type: "list_library";
+} | {
+  type: "get_library_entry";
+  entry_id: string;
+} | {
+  type: "publish_to_library";
+  description?: string | null;
+  entity_id: string;
+  entity_type: EntityType;
+  world_id: string;
+} | {
+  type: "delete_library_entry";
+  entry_id: string;
+} | {
+  type: "import_library_entry";
+  entry_id: string;
+  world_id: string;
+};
+
+/**
+ * Broad life stage derived from a character's age (wire format)
+ */
+export type LifeStageData = "child" | "adolescent" | "adult" | "middleAged" | "elder" | "unknown";
+
+/**
+ * How much light a region has to see by (wire format)
+ */
+export type LightLevelData = "bright" | "dim" | "dark" | "unknown";
+
+export type LocationRequest = {
+  type: "list_locations";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_location";
+  location_id: string;
+} | {
+  type: "create_location";
+  data: CreateLocationData;
+  world_id: string;
+} | {
+  type: "update_location";
+  data: UpdateLocationData;
+  location_id: string;
+} | {
+  type: "delete_location";
+  location_id: string;
+} | {
+  type: "duplicate_location";
+  location_id: string;
+} | {
+  type: "get_location_connections";
+  location_id: string;
+} | {
+  type: "create_location_connection";
+  data: CreateLocationConnectionData;
+} | {
+  type: "delete_location_connection";
+  from_id: string;
+  to_id: string;
+} | {
+  type: "set_location_connection_lock";
+  from_id: string;
+  lock?: ConnectionLockData | null;
+  to_id: string;
+} | {
+  type: "set_location_parent";
+  location_id: string;
+  parent_id?: string | null;
+} | {
+  type: "list_sub_locations";
+  location_id: string;
+  /**
+   * Everything nested inside rather than only direct children
+   */
+  recursive?: boolean;
+} | {
+  type: "get_travel_time";
+  from_location_id: string;
+  to_location_id: string;
+} | {
+  type: "set_location_map_position";
+  location_id: string;
+  position?: WorldMapPositionData | null;
+} | {
+  type: "set_location_encounter_table";
+  location_id: string;
+  table?: EncounterTableData | null;
+};
+
+/**
+ * How large a place a location is (wire format)
+ */
+export type LocationScaleData = "continent" | "kingdom" | "city" | "district" | "building" | "room" | "unknown";
+
+/**
+ * A way out of where a PC stands
+ */
+export type LockedWayData = {
+  type: "region";
+  region_id: string;
+} | {
+  type: "location";
+  location_id: string;
+} | {
+  type: "unknown";
+};
+
+/**
+ * Category of lore (wire format)
+ */
+export type LoreCategoryData = "historical" | "legend" | "secret" | "common" | "technical" | "political" | "natural" | "religious" | "unknown";
+
+/**
+ * Lore chunk for wire transfer
+ */
+export type LoreChunkData = {
+  content: string;
+  discoveryHint?: string | null;
+  id: string;
+  order: number;
+  title?: string | null;
+};
+
+/**
+ * Lore entry for wire transfer
+ */
+export type LoreData = {
+  category: LoreCategoryData;
+  chunks: LoreChunkData[];
+  id: string;
+  isCommonKnowledge: boolean;
+  summary: string;
+  tags?: string[];
+  title: string;
+  worldId: string;
+};
+
+/**
+ * How lore was discovered (wire format)
+ */
+export type LoreDiscoverySourceData = {
+  type: "readBook";
+  book_name: string;
+} | {
+  type: "conversation";
+  npc_id: string;
+  npc_name: string;
+} | {
+  type: "investigation";
+} | {
+  type: "dmGranted";
+  reason?: string | null;
+} | {
+  type: "commonKnowledge";
+} | {
+  type: "llmDiscovered";
+  context: string;
+} | {
+  type: "sharedByPc";
+  pc_id: string;
+  pc_name: string;
+} | {
+  type: "unknown";
+};
+
+export type LoreRequest = {
+  type: "list_lore";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_lore";
+  lore_id: string;
+} | {
+  type: "create_lore";
+  data: CreateLoreData;
+  world_id: string;
+} | {
+  type: "update_lore";
+  data: UpdateLoreData;
+  lore_id: string;
+} | {
+  type: "delete_lore";
+  lore_id: string;
+} | {
+  type: "add_lore_chunk";
+  data: CreateLoreChunkData;
+  lore_id: string;
+} | {
+  type: "update_lore_chunk";
+  chunk_id: string;
+  data: UpdateLoreChunkData;
+} | {
+  type: "delete_lore_chunk";
+  chunk_id: string;
+} | {
+  type: "grant_lore_knowledge";
+  character_id: string;
+  chunk_ids?: string[] | null;
+  discovery_source: LoreDiscoverySourceData;
+  lore_id: string;
+} | {
+  type: "revoke_lore_knowledge";
+  character_id: string;
+  chunk_ids?: string[] | null;
+  lore_id: string;
+} | {
+  type: "get_character_lore";
+  character_id: string;
+} | {
+  type: "get_lore_knowers";
+  lore_id: string;
+};
+
+/**
+ * Lore summary for list views
+ */
+export type LoreSummaryData = {
+  category: LoreCategoryData;
+  chunkCount: number;
+  id: string;
+  isCommonKnowledge: boolean;
+  /**
+   * How many chunks the character knows (for partial knowledge display)
+   */
+  knownChunkCount?: number | null;
+  title: string;
+};
+
+/**
+ * How loud a noise is (wire format)
+ */
+export type LoudnessData = "loud" | "deafening" | "unknown";
+
+/**
+ * In-world letters in the current world
+ *
+ * A player writes and reads their own PC's mail. The DM sees all of it and
+ * can write NPC replies or remove letters.
+ */
+export type MailRequest = {
+  type: "list_mailbox";
+  pc_id: string;
+} | {
+  type: "send_letter";
+  data: LetterInputData;
+  pc_id: string;
+} | {
+  type: "mark_letter_read";
+  letter_id: string;
+} | {
+  type: "list_world_mail";
+} | {
+  type: "reply_as_npc";
+  body?: string | null;
+  letter_id: string;
+} | {
+  type: "delete_letter";
+  letter_id: string;
+};
+
+/**
+ * A DM marker pinned to a map, for wire transfer
+ */
+export type MapMarkerData = {
+  /**
+   * Story event ID of the marker
+   */
+  id: string;
+  note?: string;
+  pin: MarkerPinData;
+  title: string;
+  visibleToPlayers: boolean;
+  worldId: string;
+};
+
+/**
+ * Entity a map pin links to (wire format)
+ */
+export type MarkerLinkData = {
+  type: "character";
+  id: string;
+} | {
+  type: "location";
+  id: string;
+} | {
+  type: "region";
+  id: string;
+} | {
+  type: "item";
+  id: string;
+} | {
+  type: "narrativeEvent";
+  id: string;
+};
+
+/**
+ * Where a DM marker is pinned (wire format)
+ */
+export type MarkerPinData = {
+  link?: MarkerLinkData | null;
+  locationId: string;
+  /**
+   * Region backdrop the pin is on; `None` means the location map
+   */
+  regionId?: string | null;
+  /**
+   * Percent of image width (0-100)
+   */
+  x: number;
+  /**
+   * Percent of image height (0-100)
+   */
+  y: number;
+};
+
+/**
+ * Fields of a market modifier the DM can set
+ */
+export type MarketModifierInputData = {
+  /**
+   * Item type it applies to; all goods when absent
+   */
+  category?: string | null;
+  /**
+   * World flag it waits for; always in effect when absent
+   */
+  flag?: string | null;
+  /**
+   * Location it applies to; the whole world when absent
+   */
+  locationId?: string | null;
+  /**
+   * Percent on top of scarcity: 20 is 20% dearer, -30 is 30% cheaper
+   */
+  priceAdjustment?: number;
+  reason: string;
+  /**
+   * Scarcity it sets; leaves scarcity alone when absent
+   */
+  scarcity?: ScarcityData | null;
+};
+
+/**
+ * Entity mentions and aliases in the DM's current world (DM only).
+ * Mentions are found by name and alias in descriptions, lore and dialogue,
+ * and kept up to date as those are saved.
+ */
+export type MentionRequest = {
+  type: "list_mention_targets";
+} | {
+  type: "set_entity_aliases";
+  aliases: string[];
+  entity_id: string;
+  entity_type: EntityType;
+} | {
+  type: "get_entity_aliases";
+  entity_id: string;
+  entity_type: EntityType;
+} | {
+  type: "add_entity_alias";
+  alias: string;
+  entity_id: string;
+  entity_type: EntityType;
+} | {
+  type: "remove_entity_alias";
+  alias: string;
+  entity_id: string;
+  entity_type: EntityType;
+} | {
+  type: "get_mentions_of";
+  entity_id: string;
+  entity_type: EntityType;
+} | {
+  type: "get_mentions_in";
+  entity_id: string;
+  entity_type: EntityType;
+} | {
+  type: "reindex_mentions";
+};
+
+/**
+ * PC death, resurrection and succession in the current world
+ *
+ * Recording a death and resurrecting are DM-only. The dead PC's player can
+ * read the memorial and create their successor.
+ */
+export type MortalityRequest = {
+  type: "get_death";
+  pc_id: string;
+} | {
+  type: "kill_character";
+  data: PcDeathInputData;
+  pc_id: string;
+} | {
+  type: "resurrect_character";
+  pc_id: string;
+} | {
+  type: "create_successor";
+  data: SuccessorInputData;
+  pc_id: string;
+};
+
+/**
+ * Fields of a name generator the DM can set
+ */
+export type NameGeneratorInputData = {
+  description?: string;
+  /**
+   * Factions whose members are named this way
+   */
+  factions?: string[];
+  name: string;
+  /**
+   * Regions whose NPCs are named this way
+   */
+  regionIds?: string[];
+  style: NameStyleData;
+};
+
+/**
+ * Name generators in the DM's current world (DM only). Names are drawn
+ * with `GenerationRequest::GenerateNames`.
+ */
+export type NameGeneratorRequest = {
+  type: "list_name_generators";
+} | {
+  type: "create_name_generator";
+  data: NameGeneratorInputData;
+} | {
+  type: "update_name_generator";
+  data: NameGeneratorInputData;
+  generator_id: string;
+} | {
+  type: "delete_name_generator";
+  generator_id: string;
+};
+
+/**
+ * How a name generator builds names
+ */
+export type NameStyleData = {
+  kind: "list";
+  family_names?: string[];
+  given_names: string[];
+} | {
+  kind: "grammar";
+  parts: Record<string, string[]>;
+  patterns: string[];
+} | {
+  kind: "unknown";
+};
+
+/**
+ * Dice configuration for narrative systems
+ */
+export type NarrativeDiceConfig = {
+  /**
+   * Number of dice to roll (e.g., 2 for 2d6, 4 for 4dF)
+   */
+  diceCount: number;
+  /**
+   * Type of dice system
+   */
+  diceType: NarrativeDiceType;
+  /**
+   * Display formula for UI (e.g., "2d6", "4dF", "d6 pool")
+   */
+  displayFormula: string;
+};
+
+/**
+ * Types of dice for narrative systems
+ */
+export type NarrativeDiceType = {
+  standard: {
+    sides: number;
+  };
+} | "fudge" | {
+  pool: {
+    sides: number;
+  };
+};
+
+export type NarrativeEventRequest = {
+  type: "list_narrative_events";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_narrative_event";
+  event_id: string;
+} | {
+  type: "create_narrative_event";
+  data: CreateNarrativeEventData;
+  world_id: string;
+} | {
+  type: "update_narrative_event";
+  data: UpdateNarrativeEventData;
+  event_id: string;
+} | {
+  type: "delete_narrative_event";
+  event_id: string;
+} | {
+  type: "duplicate_narrative_event";
+  event_id: string;
+} | {
+  type: "set_narrative_event_active";
+  active: boolean;
+  event_id: string;
+} | {
+  type: "set_narrative_event_favorite";
+  event_id: string;
+  favorite: boolean;
+} | {
+  type: "trigger_narrative_event";
+  event_id: string;
+} | {
+  type: "reset_narrative_event";
+  event_id: string;
+} | {
+  type: "get_trigger_schema";
+};
+
+/**
+ * Narrative event suggestion information for DM approval
+ */
+export type NarrativeEventSuggestionInfo = {
+  confidence: string;
+  description: string;
+  event_id: string;
+  event_name: string;
+  matched_triggers: string[];
+  reasoning: string;
+  scene_direction: string;
+  /**
+   * Suggested outcome (can be cleared/modified by DM)
+   */
+  suggested_outcome?: string | null;
+};
+
+/**
+ * Configuration for narrative/fiction-first resolution systems.
+ *
+ * Supports three major styles:
+ * - PbtA: Fixed thresholds (10+/7-9/6-), 2d6+stat
+ * - Ladder: Descriptor maps to target number, NdF+skill vs target (Fate)
+ * - Blades: Position determines consequences, Effect determines progress
+ */
+export type NarrativeResolutionConfig = {
+  /**
+   * Dice configuration for narrative systems
+   */
+  diceConfig?: NarrativeDiceConfig;
+  /**
+   * Difficulty ladder mapping descriptors to target values (used by Ladder style)
+   */
+  ladder?: DifficultyLadder;
+  /**
+   * Position/Effect configuration (used by Blades style)
+   */
+  positionEffect?: PositionEffectConfig;
+  /**
+   * The narrative resolution style
+   */
+  style: NarrativeResolutionStyle;
+  /**
+   * Thresholds for outcome tiers (used by PbtA and Custom styles)
+   */
+  thresholds?: NarrativeThresholds;
+};
+
+/**
+ * The narrative resolution style determines how rolls are evaluated
+ */
+export type NarrativeResolutionStyle = "pbt_a" | "ladder" | "blades" | "custom" | "unknown";
+
+/**
+ * Configurable thresholds for PbtA-style resolution
+ */
+export type NarrativeThresholds = {
+  /**
+   * Total at or below which is critical failure (optional, default: None)
+   */
+  criticalFailure?: number | null;
+  /**
+   * Total needed for critical success (optional, default: None)
+   */
+  criticalSuccess?: number | null;
+  /**
+   * Total needed for full success (default: 10)
+   */
+  fullSuccess: number;
+  /**
+   * Total needed for partial success (default: 7)
+   */
+  partialSuccess: number;
+};
+
+/**
+ * Navigation options from current region
+ */
+export type NavigationData = {
+  connected_regions: NavigationTarget[];
+  exits: NavigationExit[];
+};
+
+/**
+ * An exit to another location
+ */
+export type NavigationExit = {
+  arrival_region_id: string;
+  description?: string | null;
+  location_id: string;
+  location_name: string;
+};
+
+/**
+ * A navigation target (region within same location)
+ */
+export type NavigationTarget = {
+  is_locked: boolean;
+  lock_description?: string | null;
+  name: string;
+  region_id: string;
+};
+
+/**
+ * A region a noise carried into, and who heard it (DM view)
+ */
+export type NoiseHeardInData = {
+  muffled: boolean;
+  /**
+   * Staged NPCs, hidden ones included
+   */
+  npc_names?: string[];
+  pc_names?: string[];
+  region_id: string;
+  region_name: string;
+};
+
+/**
+ * Full NPC actantial context data (response to GetNpcActantialContext)
+ */
+export type NpcActantialContextData = {
+  npc_id: string;
+  npc_name: string;
+  social_views: SocialViewsData;
+  wants: WantData[];
+};
+
+/**
+ * NPC disposition/relationship data for a specific PC
+ */
+export type NpcDispositionData = {
+  disposition: string;
+  /**
+   * Last reason for disposition change
+   */
+  last_reason?: string | null;
+  npc_id: string;
+  npc_name: string;
+  relationship: string;
+  /**
+   * Sentiment value (-1.0 to 1.0)
+   */
+  sentiment?: number;
+};
+
+/**
+ * Wire-format disposition state for protocol serialization
+ *
+ * This DTO is used to transfer NPC disposition state over WebSocket/HTTP.
+ * It uses raw UUIDs instead of domain ID types for serialization compatibility.
+ */
+export type NpcDispositionStateDto = {
+  /**
+   * Current emotional stance
+   */
+  disposition: DispositionLevel;
+  /**
+   * Reason for the last disposition change
+   */
+  dispositionReason?: string | null;
+  /**
+   * The NPC's UUID
+   */
+  npcId: string;
+  /**
+   * The PC's UUID
+   */
+  pcId: string;
+  /**
+   * Long-term relationship level
+   */
+  relationship: RelationshipLevel;
+  /**
+   * Accumulated relationship points
+   */
+  relationshipPoints: number;
+  /**
+   * Fine-grained sentiment score (-1.0 to 1.0)
+   */
+  sentiment: number;
+  /**
+   * When this state was last updated (RFC 3339)
+   */
+  updatedAt: string;
+};
+
+/**
+ * A proposed NPC waiting for DM approval
+ */
+export type NpcDraftData = {
+  /**
+   * Campbell archetype name, e.g. "Mentor"
+   */
+  archetype: string;
+  createdAt: string;
+  description: string;
+  disposition: DispositionLevel;
+  /**
+   * Why the last generation failed; the draft can still be filled by hand
+   */
+  generationError?: string | null;
+  id: string;
+  /**
+   * Empty while a crowd member's name is still being generated
+   */
+  name: string;
+  source: NpcDraftSourceData;
+  /**
+   * Where they came from, in words ("One of the dock workers")
+   */
+  sourceLabel: string;
+  status: NpcDraftStatusData;
+};
+
+/**
+ * Fields of an NPC draft the DM can set
+ */
+export type NpcDraftInputData = {
+  /**
+   * Campbell archetype name, e.g. "Mentor"
+   */
+  archetype: string;
+  description?: string;
+  disposition?: DispositionLevel;
+  name: string;
+};
+
+/**
+ * Promoting crowd members and mentioned names to NPCs in the DM's current
+ * world (DM only). Drafts are filled in by the LLM in the background and
+ * announced with `NpcDraftUpdated`; approving one creates the character.
+ */
+export type NpcDraftRequest = {
+  type: "list_npc_drafts";
+} | {
+  type: "promote_to_npc";
+  name?: string | null;
+  source: NpcDraftSourceData;
+} | {
+  type: "update_npc_draft";
+  data: NpcDraftInputData;
+  draft_id: string;
+} | {
+  type: "regenerate_npc_draft";
+  draft_id: string;
+} | {
+  type: "approve_npc_draft";
+  draft_id: string;
+} | {
+  type: "reject_npc_draft";
+  draft_id: string;
+};
+
+/**
+ * Where a drafted NPC came from
+ */
+export type NpcDraftSourceData = {
+  kind: "crowd";
+  crowd_id: string;
+} | {
+  kind: "dialogue";
+  excerpt: string;
+} | {
+  kind: "lore";
+  lore_id: string;
+} | {
+  kind: "session_zero";
+  concept: string;
+  region_id: string;
+} | {
+  kind: "unknown";
+};
+
+/**
+ * Whether the LLM is still filling a draft in
+ */
+export type NpcDraftStatusData = "generating" | "unknown" | "pending";
+
+/**
+ * NPC motivation data for directorial context
+ *
+ * Note: `emotional_guidance` is a free-form string for DM guidance,
+ * not the same as DispositionLevel or MoodState enums.
+ */
+export type NpcMotivationData = {
+  character_id: string;
+  /**
+   * Free-form emotional guidance for the NPC (e.g., "Conflicted about revealing secrets")
+   * UI label: "Demeanor"
+   */
+  emotional_guidance: string;
+  immediate_goal: string;
+  secret_agenda?: string | null;
+};
+
+/**
+ * NPC presence data for scene display
+ */
+export type NpcPresenceData = {
+  character_id: string;
+  name: string;
+  portrait_asset?: string | null;
+  sprite_asset?: string | null;
+};
+
+/**
+ * NPC presence info for players (simplified, no reasoning)
+ */
+export type NpcPresentInfo = {
+  character_id: string;
+  /**
+   * When true, NPC is present but hidden from players
+   */
+  is_hidden_from_players?: boolean;
+  /**
+   * NPC's current mood (Tier 2 of emotional model)
+   * Used by Player UI to set default expression
+   */
+  mood?: string | null;
+  name: string;
+  portrait_asset?: string | null;
+  sprite_asset?: string | null;
+};
+
+export type NpcRequest = {
+  type: "set_npc_disposition";
+  disposition: string;
+  npc_id: string;
+  pc_id: string;
+  reason?: string | null;
+} | {
+  type: "set_npc_relationship";
+  npc_id: string;
+  pc_id: string;
+  relationship: string;
+} | {
+  type: "get_npc_dispositions";
+  pc_id: string;
+} | {
+  type: "set_npc_mood";
+  mood: string;
+  npc_id: string;
+  reason?: string | null;
+  region_id: string;
+} | {
+  type: "get_npc_mood";
+  npc_id: string;
+  region_id: string;
+} | {
+  type: "list_character_region_relationships";
+  character_id: string;
+} | {
+  type: "set_character_home_region";
+  character_id: string;
+  region_id: string;
+} | {
+  type: "set_character_work_region";
+  character_id: string;
+  region_id: string;
+} | {
+  type: "remove_character_region_relationship";
+  character_id: string;
+  region_id: string;
+  relationship_type: string;
+} | {
+  type: "list_region_npcs";
+  region_id: string;
+} | {
+  type: "set_npc_autonomy";
+  level?: string | null;
+  npc_id: string;
+};
+
+/**
+ * A one-line idea for an NPC the LLM fleshes out into a draft
+ */
+export type NpcSeedData = {
+  /**
+   * Who they are ("a smuggler who owes the harbormaster")
+   */
+  concept: string;
+  /**
+   * Left to the LLM when absent
+   */
+  name?: string | null;
+};
+
+/**
+ * An NPC blueprint
+ */
+export type NpcTemplateData = {
+  /**
+   * Campbell archetype name, e.g. "Mentor"
+   */
+  archetype: string;
+  description?: string;
+  name: string;
+  portraitAsset?: string | null;
+  spriteAsset?: string | null;
+};
+
+export type ObservationRequest = {
+  type: "list_observations";
+  pc_id: string;
+} | {
+  type: "create_observation";
+  data: CreateObservationData;
+  pc_id: string;
+} | {
+  type: "delete_observation";
+  npc_id: string;
+  pc_id: string;
+} | {
+  type: "list_known_locations";
+  pc_id: string;
+} | {
+  type: "reveal_location";
+  location_id: string;
+  notes?: string | null;
+  pc_id: string;
+} | {
+  type: "forget_location";
+  location_id: string;
+  pc_id: string;
+};
+
+/**
+ * Outcome branch data for DM selection
+ */
+export type OutcomeBranchData = {
+  description: string;
+  effects?: string[];
+  id: string;
+  title: string;
+};
+
+/**
+ * Outcome detail data for regenerated outcomes
+ */
+export type OutcomeDetailData = {
+  flavor_text: string;
+  proposed_tools?: ProposedToolInfo[];
+  scene_direction: string;
+};
+
+/**
+ * How a pacing timer should look to the table
+ */
+export type PacingCueData = "running" | "paused" | "warning" | "expired" | "unknown";
+
+/**
+ * The world's pacing timer. Anyone in the world can read it; only the DM
+ * runs it.
+ */
+export type PacingRequest = {
+  type: "get_pacing_timer";
+} | {
+  type: "start_pacing_timer";
+  data: StartPacingTimerData;
+} | {
+  type: "pause_pacing_timer";
+} | {
+  type: "resume_pacing_timer";
+} | {
+  type: "extend_pacing_timer";
+  minutes: number;
+} | {
+  type: "stop_pacing_timer";
+};
+
+/**
+ * A world's pacing timer. Clients count down from `remaining_secs` as of
+ * when the message arrived.
+ */
+export type PacingTimerData = {
+  cue: PacingCueData;
+  durationSecs: number;
+  kind: PacingTimerKindData;
+  label?: string | null;
+  /**
+   * The PC whose turn a turn timer is counting
+   */
+  pcId?: string | null;
+  remainingSecs: number;
+  warningSecs: number;
+};
+
+/**
+ * What a pacing timer limits
+ */
+export type PacingTimerKindData = "scene" | "unknown" | "turn";
+
+/**
+ * One scene of a split party. Players get the notes and tone blanked.
+ */
+export type ParallelSceneData = {
+  id: string;
+  /**
+   * Whether the DM is running this scene right now
+   */
+  isFocused?: boolean;
+  name: string;
+  pcIds: string[];
+  sceneNotes?: string;
+  tone?: string;
+};
+
+/**
+ * A group of a split party and how its scene should play
+ */
+export type ParallelSceneInputData = {
+  name: string;
+  /**
+   * PCs in the scene; PCs already in another scene move to this one
+   */
+  pcIds: string[];
+  /**
+   * Directorial notes for this scene's NPC responses
+   */
+  sceneNotes?: string;
+  tone?: string;
+};
+
+/**
+ * Parallel scenes for a split party in the current world. Each scene keeps
+ * its own players, approvals and direction; the DM focuses one at a time.
+ * Listing is open to anyone in the world; the rest is DM-only.
+ */
+export type ParallelSceneRequest = {
+  type: "list_parallel_scenes";
+} | {
+  type: "create_parallel_scene";
+  data: ParallelSceneInputData;
+} | {
+  type: "update_parallel_scene";
+  data: ParallelSceneInputData;
+  scene_id: string;
+} | {
+  type: "delete_parallel_scene";
+  scene_id: string;
+} | {
+  type: "focus_parallel_scene";
+  scene_id?: string | null;
+} | {
+  type: "merge_parallel_scenes";
+};
+
+/**
+ * A dead PC's memorial and whether they can still come back
+ */
+export type PcDeathData = {
+  cause: string;
+  /**
+   * Sheet field the cost comes from; absent when the rule system has no
+   * currency and the DM settles it by hand
+   */
+  currency?: string | null;
+  /**
+   * Game time of death (RFC 3339)
+   */
+  diedAt: string;
+  epitaph?: string | null;
+  /**
+   * The memorial story event in the world's timeline
+   */
+  memorialEventId?: string | null;
+  name: string;
+  pcId: string;
+  /**
+   * Why the rule system will not allow a resurrection now; absent when
+   * it will
+   */
+  resurrectionBlocked?: string | null;
+  /**
+   * Currency a resurrection takes from the PC's sheet
+   */
+  resurrectionCost: number;
+  /**
+   * Times the PC has already been resurrected
+   */
+  resurrections: number;
+  /**
+   * The PC who took over, once one has been created
+   */
+  successorId?: string | null;
+};
+
+/**
+ * How a PC died, as recorded by the DM
+ */
+export type PcDeathInputData = {
+  cause: string;
+  /**
+   * Words to remember them by, written into the memorial
+   */
+  epitaph?: string | null;
+};
+
+/**
+ * A secret a PC keeps, seen only by the DM and the PC's player until revealed
+ */
+export type PcSecretData = {
+  content: string;
+  id: string;
+  pcId: string;
+  reactions: SecretReactionData[];
+  /**
+   * When the DM revealed it (RFC 3339)
+   */
+  revealedAt?: string | null;
+  title: string;
+};
+
+/**
+ * Fields of a secret the DM can set; absent fields keep their current value
+ */
+export type PcSecretInputData = {
+  content?: string | null;
+  /**
+   * Replaces the whole list
+   */
+  reactions?: SecretReactionData[] | null;
+  /**
+   * Required when creating
+   */
+  title?: string | null;
+};
+
+/**
+ * A PC's chance of passing a suggested challenge
+ */
+export type PcSuccessChanceInfo = {
+  /**
+   * The PC's modifier for the challenge's skill
+   */
+  modifier: number;
+  pc_id: string;
+  pc_name: string;
+  /**
+   * Whole percent; `None` when the DM adjudicates (opposed or custom)
+   */
+  success_percent?: number | null;
+};
+
+export type PlayerCharacterRequest = {
+  type: "list_player_characters";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_player_character";
+  pc_id: string;
+} | {
+  type: "create_player_character";
+  data: CreatePlayerCharacterData;
+  world_id: string;
+} | {
+  type: "update_player_character";
+  data: UpdatePlayerCharacterData;
+  pc_id: string;
+} | {
+  type: "delete_player_character";
+  pc_id: string;
+} | {
+  type: "update_player_character_location";
+  pc_id: string;
+  region_id: string;
+} | {
+  type: "get_my_player_character";
+  user_id: string;
+  world_id: string;
+};
+
+/**
+ * Position/Effect configuration for Blades-style resolution
+ */
+export type PositionEffectConfig = {
+  /**
+   * Minimum dice for critical (default: 2 sixes needed)
+   */
+  criticalDiceCount: number;
+  /**
+   * Effect levels and their clock tick values
+   */
+  effectTicks: EffectTickConfig;
+  /**
+   * Whether to enable the critical rule (multiple 6s)
+   */
+  enableCritical: boolean;
+  /**
+   * Thresholds for Blades-style d6 pool (highest die)
+   */
+  poolThresholds: BladesPoolThresholds;
+};
+
+/**
+ * Info about previous staging (for reference)
+ */
+export type PreviousStagingInfo = {
+  approved_at: string;
+  npcs: StagedNpcInfo[];
+  staging_id: string;
+};
+
+/**
+ * Progress clock for wire transfer
+ */
+export type ProgressClockData = {
+  /**
+   * Party clock this personal objective works against
+   */
+  conflictsWithClockId?: string | null;
+  description?: string | null;
+  filled: number;
+  id: string;
+  isComplete: boolean;
+  kind: ClockKindData;
+  name: string;
+  /**
+   * PC this clock is personal to (only they and DMs see it)
+   */
+  ownerPcId?: string | null;
+  segments: number;
+  /**
+   * Faction, quest, or threat this clock is linked to
+   */
+  subject?: string | null;
+  visibleToPlayers: boolean;
+  worldId: string;
+};
+
+/**
+ * DTO for prompt mapping configuration
+ */
+export type PromptMappingDto = {
+  inputName: string;
+  mappingType: PromptMappingTypeDto;
+  nodeId: string;
+};
+
+/**
+ * DTO for prompt mapping type
+ */
+export type PromptMappingTypeDto = "primary" | "negative" | "unknown";
+
+/**
+ * Fields of a property the DM can set
+ */
+export type PropertyInputData = {
+  /**
+   * The property is a whole location; give this or `region_id`
+   */
+  locationId?: string | null;
+  name: string;
+  notes?: string;
+  /**
+   * The property is one region; takes precedence over `location_id`
+   */
+  regionId?: string | null;
+  staff?: PropertyStaffInputData[];
+  /**
+   * Cost per game day, in the world's currency
+   */
+  upkeepPerDay?: number;
+};
+
+/**
+ * Properties PCs own in the DM's current world (DM only)
+ */
+export type PropertyRequest = {
+  type: "list_pc_properties";
+  pc_id: string;
+} | {
+  type: "create_property";
+  data: PropertyInputData;
+  pc_id: string;
+} | {
+  type: "update_property";
+  data: PropertyInputData;
+  property_id: string;
+} | {
+  type: "delete_property";
+  property_id: string;
+} | {
+  type: "pay_upkeep";
+  property_id: string;
+};
+
+/**
+ * An NPC to employ at a property
+ */
+export type PropertyStaffInputData = {
+  characterId: string;
+  /**
+   * Their job in words: "barkeep"
+   */
+  duties?: string;
+  role: StaffRoleData;
+};
+
+/**
+ * Proposed tool call information
+ */
+export type ProposedToolInfo = {
+  arguments: unknown;
+  description: string;
+  id: string;
+  name: string;
+};
+
+/**
+ * DTO for a reference image input mapping
+ */
+export type ReferenceImageMappingDto = {
+  inputName: string;
+  nodeId: string;
+};
+
+/**
+ * Region data for scene display
+ */
+export type RegionData = {
+  atmosphere?: string | null;
+  backdrop_asset?: string | null;
+  /**
+   * Clickable areas on the backdrop
+   */
+  hotspots?: HotspotData[];
+  id: string;
+  /**
+   * How well lit the region is at the current game time
+   */
+  light_level?: LightLevelData | null;
+  location_id: string;
+  location_name: string;
+  /**
+   * Location's top-down map image for mini-map display
+   */
+  map_asset?: string | null;
+  name: string;
+};
+
+/**
+ * Item data for region display (items visible in the current region)
+ */
+export type RegionItemData = {
+  description?: string | null;
+  id: string;
+  item_type?: string | null;
+  name: string;
+};
+
+/**
+ * Kind of top-down map generated for a region (wire format)
+ */
+export type RegionMapKindData = "unknown" | "battle_map" | "region_layout";
+
+export type RegionRequest = {
+  type: "list_regions";
+  location_id: string;
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+} | {
+  type: "get_region";
+  region_id: string;
+} | {
+  type: "create_region";
+  data: CreateRegionData;
+  location_id: string;
+} | {
+  type: "update_region";
+  data: UpdateRegionData;
+  region_id: string;
+} | {
+  type: "delete_region";
+  region_id: string;
+} | {
+  type: "set_region_hotspots";
+  hotspots: HotspotData[];
+  region_id: string;
+} | {
+  type: "set_region_lighting";
+  lighting?: LightLevelData | null;
+  region_id: string;
+} | {
+  type: "generate_region_map";
+  kind?: RegionMapKindData;
+  /**
+   * Extra prompt text appended to the generated description
+   */
+  prompt?: string | null;
+  region_id: string;
+  /**
+   * Workflow to use instead of the world preset's or the kind's default
+   */
+  workflow?: string | null;
+} | {
+  type: "get_region_connections";
+  region_id: string;
+} | {
+  type: "create_region_connection";
+  data: CreateRegionConnectionData;
+  from_id: string;
+  to_id: string;
+} | {
+  type: "delete_region_connection";
+  from_id: string;
+  to_id: string;
+} | {
+  type: "unlock_region_connection";
+  from_id: string;
+  to_id: string;
+} | {
+  type: "set_region_connection_lock";
+  from_id: string;
+  lock?: ConnectionLockData | null;
+  to_id: string;
+} | {
+  type: "set_region_connection_sound";
+  from_id: string;
+  sound: SoundCarryData;
+  to_id: string;
+} | {
+  type: "get_region_exits";
+  region_id: string;
+} | {
+  type: "create_region_exit";
+  arrival_region_id: string;
+  bidirectional?: boolean | null;
+  description?: string | null;
+  location_id: string;
+  region_id: string;
+} | {
+  type: "delete_region_exit";
+  location_id: string;
+  region_id: string;
+} | {
+  type: "list_spawn_points";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+};
+
+/**
+ * A region blueprint with its items and NPCs
+ */
+export type RegionTemplateData = {
+  atmosphere?: string | null;
+  backdropAsset?: string | null;
+  description?: string;
+  isSpawnPoint?: boolean;
+  items?: ItemTemplateData[];
+  name: string;
+  npcs?: TemplateNpcData[];
+};
+
+/**
+ * Long-term relationship level between NPC and PC
+ *
+ * This represents how well the NPC knows the PC (social distance),
+ * which changes gradually over many interactions.
+ *
+ * This is SEPARATE from DispositionLevel (emotional stance).
+ */
+export type RelationshipLevel = "ally" | "friend" | "acquaintance" | "stranger" | "rival" | "enemy" | "nemesis" | "unknown";
+
+export type RelationshipRequest = {
+  type: "get_social_network";
+  world_id: string;
+} | {
+  type: "create_relationship";
+  data: CreateRelationshipData;
+} | {
+  type: "delete_relationship";
+  relationship_id: string;
+};
+
+/**
+ * All operations that can be requested via WebSocket
+ *
+ * This is the top-level grouping enum. Actual request variants live in
+ * per-group enums in `requests/*`.
+ */
+export type RequestPayload = {
+  group: "world";
+  payload: WorldRequest;
+} | {
+  group: "character";
+  payload: CharacterRequest;
+} | {
+  group: "location";
+  payload: LocationRequest;
+} | {
+  group: "region";
+  payload: RegionRequest;
+} | {
+  group: "scene";
+  payload: SceneRequest;
+} | {
+  group: "act";
+  payload: ActRequest;
+} | {
+  group: "interaction";
+  payload: InteractionRequest;
+} | {
+  group: "skill";
+  payload: SkillRequest;
+} | {
+  group: "challenge";
+  payload: ChallengeRequest;
+} | {
+  group: "narrative_event";
+  payload: NarrativeEventRequest;
+} | {
+  group: "event_chain";
+  payload: EventChainRequest;
+} | {
+  group: "story_event";
+  payload: StoryEventRequest;
+} | {
+  group: "player_character";
+  payload: PlayerCharacterRequest;
+} | {
+  group: "relationship";
+  payload: RelationshipRequest;
+} | {
+  group: "observation";
+  payload: ObservationRequest;
+} | {
+  group: "goal";
+  payload: GoalRequest;
+} | {
+  group: "want";
+  payload: WantRequest;
+} | {
+  group: "actantial";
+  payload: ActantialRequest;
+} | {
+  group: "time";
+  payload: TimeRequest;
+} | {
+  group: "npc";
+  payload: NpcRequest;
+} | {
+  group: "generation";
+  payload: GenerationRequest;
+} | {
+  group: "expression";
+  payload: ExpressionRequest;
+} | {
+  group: "ai";
+  payload: AiRequest;
+} | {
+  group: "items";
+  payload: ItemsRequest;
+} | {
+  group: "lore";
+  payload: LoreRequest;
+} | {
+  group: "stat";
+  payload: StatRequest;
+} | {
+  group: "character_sheet";
+  payload: CharacterSheetRequest;
+} | {
+  group: "clock";
+  payload: ClockRequest;
+} | {
+  group: "dice";
+  payload: DiceRequest;
+} | {
+  group: "tutorial";
+  payload: TutorialRequest;
+} | {
+  group: "tag";
+  payload: TagRequest;
+} | {
+  group: "template";
+  payload: TemplateRequest;
+} | {
+  group: "library";
+  payload: LibraryRequest;
+} | {
+  group: "draft";
+  payload: DraftRequest;
+} | {
+  group: "gallery";
+  payload: GalleryRequest;
+} | {
+  group: "chronology";
+  payload: ChronologyRequest;
+} | {
+  group: "crowd";
+  payload: CrowdRequest;
+} | {
+  group: "npc_draft";
+  payload: NpcDraftRequest;
+} | {
+  group: "mention";
+  payload: MentionRequest;
+} | {
+  group: "name_generator";
+  payload: NameGeneratorRequest;
+} | {
+  group: "economy";
+  payload: EconomyRequest;
+} | {
+  group: "property";
+  payload: PropertyRequest;
+} | {
+  group: "companion";
+  payload: CompanionRequest;
+} | {
+  group: "injury";
+  payload: InjuryRequest;
+} | {
+  group: "mortality";
+  payload: MortalityRequest;
+} | {
+  group: "secret";
+  payload: SecretRequest;
+} | {
+  group: "mail";
+  payload: MailRequest;
+} | {
+  group: "broadsheet";
+  payload: BroadsheetRequest;
+} | {
+  group: "simulation";
+  payload: SimulationRequest;
+} | {
+  group: "faction";
+  payload: FactionRequest;
+} | {
+  group: "dialogue_tree";
+  payload: DialogueTreeRequest;
+} | {
+  group: "flashback";
+  payload: FlashbackRequest;
+} | {
+  group: "parallel_scene";
+  payload: ParallelSceneRequest;
+} | {
+  group: "spotlight";
+  payload: SpotlightRequest;
+} | {
+  group: "pacing";
+  payload: PacingRequest;
+} | {
+  group: "transcription";
+  payload: TranscriptionRequest;
+} | {
+  group: "unknown";
+};
+
+/**
+ * Resolved state info for staging (lightweight)
+ */
+export type ResolvedStateInfoData = {
+  ambientSound?: string | null;
+  atmosphereOverride?: string | null;
+  backdropOverride?: string | null;
+  id: string;
+  name: string;
+};
+
+/**
+ * Complete resolved visual state for staging
+ */
+export type ResolvedVisualStateData = {
+  locationState?: ResolvedStateInfoData | null;
+  regionState?: ResolvedStateInfoData | null;
+};
+
+/**
+ * Result of a request operation
+ */
+export type ResponseResult = {
+  status: "success";
+  /**
+   * Optional data payload (varies by request type)
+   */
+  data?: unknown;
+} | {
+  status: "error";
+  /**
+   * Error classification code
+   */
+  code: ErrorCode;
+  /**
+   * Additional error details (optional)
+   */
+  details?: unknown;
+  /**
+   * Human-readable error message
+   */
+  message: string;
+} | {
+  status: "unknown";
+};
+
+/**
+ * Whether a rule system lets dead PCs come back, and on what terms.
+ */
+export type ResurrectionConfig = {
+  /**
+   * Whether dead PCs can be resurrected at all
+   */
+  allowed: boolean;
+  /**
+   * Currency taken from the PC's sheet for each resurrection
+   */
+  cost?: number;
+  /**
+   * How many times a single PC can be resurrected
+   */
+  max_resurrections?: number | null;
+  /**
+   * How many game days after death a resurrection is still possible
+   */
+  within_days?: number | null;
+};
+
+/**
+ * Where a draft revision came from (wire format)
+ */
+export type RevisionSourceData = "unknown" | "generated" | "edited";
+
+/**
+ * Configuration for a game's rule system
+ */
+export type RuleSystemConfig = {
+  /**
+   * Description of how the system works
+   */
+  description: string;
+  /**
+   * The dice system used for resolution
+   */
+  dice_system: DiceSystem;
+  /**
+   * How injuries heal. When unset, the variant's defaults apply.
+   */
+  injury_config?: InjuryRecoveryConfig | null;
+  /**
+   * Display name for this configuration
+   */
+  name: string;
+  /**
+   * Configuration for narrative resolution systems (PbtA, Fate, Blades)
+   * Only used when system_type is Narrative
+   */
+  narrative_config?: NarrativeResolutionConfig | null;
+  /**
+   * Whether and how dead PCs can be brought back. When unset, the
+   * variant's defaults apply.
+   */
+  resurrection_config?: ResurrectionConfig | null;
+  /**
+   * Skill names that mean the same thing in other rule systems. When
+   * unset, the standard table applies.
+   */
+  skill_aliases?: SkillAliases | null;
+  /**
+   * Formula for skill checks (display only)
+   */
+  skill_check_formula: string;
+  /**
+   * Character stat definitions
+   */
+  stat_definitions: StatDefinition[];
+  /**
+   * How success/failure is determined
+   */
+  success_comparison: SuccessComparison;
+  /**
+   * The type of rule system
+   */
+  system_type: RuleSystemType;
+  /**
+   * The specific variant/preset used
+   */
+  variant: RuleSystemVariant;
+};
+
+/**
+ * Full preset details.
+ */
+export type RuleSystemPresetDetailsDto = {
+  config: RuleSystemConfig;
+  variant: RuleSystemVariant;
+};
+
+/**
+ * Summary of a preset for browsing.
+ */
+export type RuleSystemPresetSummaryDto = {
+  description: string;
+  name: string;
+  variant: RuleSystemVariant;
+};
+
+/**
+ * Summary of a rule system type for browsing.
+ */
+export type RuleSystemSummaryDto = {
+  description: string;
+  diceNotation: string;
+  name: string;
+  presets: RuleSystemPresetSummaryDto[];
+  systemType: RuleSystemType;
+};
+
+/**
+ * The type of rule system (determines dice mechanics and success calculation)
+ */
+export type RuleSystemType = "d20" | "d100" | "narrative" | "custom" | "unknown";
+
+/**
+ * Details about a rule system type.
+ */
+export type RuleSystemTypeDetailsDto = {
+  description: string;
+  diceNotation: string;
+  name: string;
+  presets: RuleSystemPresetSummaryDto[];
+  systemType: RuleSystemType;
+};
+
+/**
+ * Known presets for rule systems
+ */
+export type RuleSystemVariant = "dnd5e" | "pathfinder2e" | "generic_d20" | "call_of_cthulhu7e" | "rune_quest" | "generic_d100" | "kids_on_bikes" | "fate_core" | "powered_by_apocalypse" | "blades_in_the_dark" | "unknown" | {
+  custom: string;
+};
+
+/**
+ * Anonymous safety signal level raised by a player (wire format)
+ */
+export type SafetySignalLevelData = "unknown" | "check" | "skip" | "stop";
+
+/**
+ * Data for creating or replacing a saved tag filter
+ */
+export type SaveFilterData = {
+  entityType: EntityType;
+  /**
+   * Existing filter to replace (creates a new one when absent)
+   */
+  filterId?: string | null;
+  name: string;
+  tags: string[];
+};
+
+/**
+ * Data for creating or replacing an entity template
+ */
+export type SaveTemplateData = {
+  body: TemplateBodyData;
+  description?: string | null;
+  name: string;
+};
+
+/**
+ * How hard a kind of goods is to come by
+ */
+export type ScarcityData = "unknown" | "abundant" | "common" | "scarce" | "rare" | "unavailable";
+
+/**
+ * Scene data from server
+ */
+export type SceneData = {
+  backdrop_asset?: string | null;
+  directorial_notes: string;
+  id: string;
+  location_id: string;
+  location_name: string;
+  name: string;
+  time_context: string;
+};
+
+export type SceneRequest = {
+  type: "list_scenes";
+  act_id: string;
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+} | {
+  type: "get_scene";
+  scene_id: string;
+} | {
+  type: "create_scene";
+  act_id: string;
+  data: CreateSceneData;
+} | {
+  type: "update_scene";
+  data: UpdateSceneData;
+  scene_id: string;
+} | {
+  type: "delete_scene";
+  scene_id: string;
+};
+
+/**
+ * When a DM automation script runs (wire format)
+ */
+export type ScriptHookData = "itemPickup" | "timeAdvance" | "challengeResolved" | "unknown";
+
+/**
+ * How an NPC takes a secret coming out
+ */
+export type SecretReactionData = {
+  npcId: string;
+  /**
+   * Filled in by the server; ignored on input
+   */
+  npcName?: string | null;
+  /**
+   * Change in the NPC's sentiment toward the PC, -1.0 to 1.0
+   */
+  sentimentChange: number;
+};
+
+/**
+ * PC secrets in the current world
+ *
+ * Only the DM manages and reveals secrets. The PC's player can read their
+ * own.
+ */
+export type SecretRequest = {
+  type: "list_secrets";
+  pc_id: string;
+} | {
+  type: "create_secret";
+  data: PcSecretInputData;
+  pc_id: string;
+} | {
+  type: "update_secret";
+  data: PcSecretInputData;
+  secret_id: string;
+} | {
+  type: "delete_secret";
+  secret_id: string;
+} | {
+  type: "reveal_secret";
+  secret_id: string;
+};
+
+/**
+ * Request DTO for selecting assets from a batch
+ */
+export type SelectFromBatchRequestDto = {
+  discardOthers?: boolean;
+  labels?: (string | null)[];
+  selectedAssets: string[];
+};
+
+/**
+ * Messages from server (Engine) to client (Player)
+ */
+export type ServerMessage = {
+  type: "ActionReceived";
+  action_id: string;
+  action_type: string;
+  player_id: string;
+} | {
+  type: "SceneUpdate";
+  characters: CharacterData[];
+  interactions: InteractionData[];
+  scene: SceneData;
+} | {
+  type: "DialogueResponse";
+  choices: DialogueChoice[];
+  /**
+   * Conversation ID for tracking the conversation session
+   */
+  conversation_id?: string | null;
+  speaker_id: string;
+  speaker_name: string;
+  text: string;
+} | {
+  type: "ConversationStarted";
+  /**
+   * The conversation ID
+   */
+  conversation_id: string;
+  /**
+   * NPC's disposition toward the player
+   */
+  npc_disposition?: string | null;
+  /**
+   * The NPC the conversation is with
+   */
+  npc_id: string;
+  npc_name: string;
+} | {
+  type: "ConversationEnded";
+  /**
+   * Conversation ID for tracking
+   */
+  conversation_id?: string | null;
+  /**
+   * The NPC the conversation was with
+   */
+  npc_id: string;
+  npc_name: string;
+  /**
+   * The player character who was conversing
+   */
+  pc_id: string;
+  /**
+   * Optional summary of the conversation
+   */
+  summary?: string | null;
+} | {
+  type: "LLMProcessing";
+  action_id: string;
+} | {
+  type: "ActionQueued";
+  action_id: string;
+  action_type: string;
+  player_name: string;
+  queue_depth: number;
+} | {
+  type: "ActionRetracted";
+  action_id: string;
+} | {
+  type: "QueueStatus";
+  approvals_pending: number;
+  llm_requests_pending: number;
+  llm_requests_processing: number;
+  player_actions_pending: number;
+} | {
+  type: "ApprovalRequired";
+  /**
+   * Whether a player is waiting on the response; the rest is ambient
+   */
+  awaiting_player?: boolean;
+  challenge_suggestion?: ChallengeSuggestionInfo | null;
+  /**
+   * Conversation the response belongs to, for grouping
+   */
+  conversation_id?: string | null;
+  internal_reasoning: string;
+  narrative_event_suggestion?: NarrativeEventSuggestionInfo | null;
+  npc_name: string;
+  proposed_dialogue: string;
+  proposed_tools: ProposedToolInfo[];
+  request_id: string;
+  /**
+   * Content safety warnings; flagged dialogue needs a closer DM look
+   */
+  safety_warnings?: string[];
+  /**
+   * Parallel scene the response belongs to; none for the main table
+   */
+  scene_id?: string | null;
+  /**
+   * Player action that raised the approval
+   */
+  source_action_id?: string | null;
+} | {
+  type: "BulkApprovalApplied";
+  accepted: boolean;
+  /**
+   * Approvals the decision was applied to
+   */
+  decided: string[];
+  /**
+   * Approvals the decision could not be applied to
+   */
+  failed?: string[];
+} | {
+  type: "ResponseApproved";
+  executed_tools: string[];
+  npc_dialogue: string;
+} | {
+  type: "ChallengePrompt";
+  challenge_id: string;
+  challenge_name: string;
+  character_modifier: number;
+  description: string;
+  difficulty_display: string;
+  rule_system_hint?: string | null;
+  skill_name: string;
+  suggested_dice?: string | null;
+} | {
+  type: "ChallengeResolved";
+  challenge_id: string;
+  challenge_name: string;
+  character_name: string;
+  individual_rolls?: number[] | null;
+  modifier: number;
+  outcome: string;
+  outcome_description: string;
+  roll: number;
+  roll_breakdown?: string | null;
+  total: number;
+} | {
+  type: "NarrativeEventTriggered";
+  event_id: string;
+  event_name: string;
+  outcome_description: string;
+  scene_direction: string;
+} | {
+  type: "SplitPartyNotification";
+  location_count: number;
+  locations: SplitPartyLocation[];
+} | {
+  type: "Error";
+  code: string;
+  message: string;
+} | {
+  type: "Pong";
+} | {
+  type: "GenerationQueued";
+  asset_type: string;
+  batch_id: string;
+  entity_id: string;
+  entity_type: string;
+  position: number;
+} | {
+  type: "GenerationProgress";
+  batch_id: string;
+  progress: number;
+} | {
+  type: "GenerationComplete";
+  asset_count: number;
+  batch_id: string;
+} | {
+  type: "GenerationFailed";
+  batch_id: string;
+  error: string;
+} | {
+  type: "SuggestionQueued";
+  entity_id?: string | null;
+  field_type: string;
+  request_id: string;
+} | {
+  type: "SuggestionProgress";
+  request_id: string;
+  status: string;
+} | {
+  type: "SuggestionComplete";
+  request_id: string;
+  suggestions: string[];
+} | {
+  type: "SuggestionFailed";
+  error: string;
+  request_id: string;
+} | {
+  type: "ComfyUIStateChanged";
+  message?: string | null;
+  retry_in_seconds?: number | null;
+  state: string;
+} | {
+  type: "DependencyStatusChanged";
+  /**
+   * "neo4j", "ollama", "comfyui", or "disk"
+   */
+  dependency: string;
+  healthy: boolean;
+  /**
+   * Why the dependency is unhealthy
+   */
+  message?: string | null;
+} | {
+  type: "ModelPullProgress";
+  completed_bytes?: number | null;
+  /**
+   * True once the pull has finished or failed
+   */
+  done: boolean;
+  /**
+   * Why the pull failed
+   */
+  error?: string | null;
+  model: string;
+  /**
+   * Server-reported stage (e.g. "pulling manifest")
+   */
+  status: string;
+  total_bytes?: number | null;
+} | {
+  type: "UsageQuotaWarning";
+  quota: number;
+  /**
+   * "tokens" or "images"
+   */
+  resource: string;
+  used: number;
+  world_id: string;
+} | {
+  type: "OutcomeRegenerated";
+  new_outcome: OutcomeDetailData;
+  outcome_type: string;
+  request_id: string;
+} | {
+  type: "ChallengeDiscarded";
+  request_id: string;
+} | {
+  type: "AdHocChallengeCreated";
+  challenge_id: string;
+  challenge_name: string;
+  target_pc_id: string;
+} | {
+  type: "ChallengeRollSubmitted";
+  challenge_id: string;
+  challenge_name: string;
+  modifier: number;
+  outcome_type: string;
+  roll: number;
+  status: string;
+  total: number;
+} | {
+  type: "ChallengeOutcomePending";
+  challenge_id: string;
+  challenge_name: string;
+  character_id: string;
+  character_name: string;
+  modifier: number;
+  outcome_description: string;
+  outcome_triggers: ProposedToolInfo[];
+  outcome_type: string;
+  resolution_id: string;
+  roll: number;
+  roll_breakdown?: string | null;
+  total: number;
+} | {
+  type: "OutcomeSuggestionReady";
+  resolution_id: string;
+  suggestions: string[];
+} | {
+  type: "OutcomeBranchesReady";
+  branches: OutcomeBranchData[];
+  outcome_type: string;
+  resolution_id: string;
+} | {
+  type: "ApproachEvent";
+  description: string;
+  npc_id: string;
+  npc_name: string;
+  npc_sprite?: string | null;
+  /**
+   * When false, player sees "Unknown Figure" and no sprite
+   */
+  reveal?: boolean;
+} | {
+  type: "LocationEvent";
+  description: string;
+  region_id: string;
+} | {
+  type: "NoiseHeard";
+  description: string;
+  from_region_id: string;
+  from_region_name: string;
+  muffled: boolean;
+  /**
+   * Region the PC is in
+   */
+  region_id: string;
+} | {
+  type: "NoiseReport";
+  description: string;
+  heard_in: NoiseHeardInData[];
+  loudness: LoudnessData;
+  region_id: string;
+  region_name: string;
+} | {
+  type: "ShowOverlay";
+  asset: string;
+  caption?: string | null;
+  /**
+   * Seconds before the overlay closes itself (None = until dismissed)
+   */
+  duration_secs?: number | null;
+  overlay_id: string;
+} | {
+  type: "OverlayDismissed";
+  overlay_id: string;
+} | {
+  type: "NpcLocationShared";
+  notes?: string | null;
+  npc_id: string;
+  npc_name: string;
+  region_name: string;
+} | {
+  type: "PcSelected";
+  location_id: string;
+  pc_id: string;
+  pc_name: string;
+  region_id?: string | null;
+} | {
+  type: "SceneChanged";
+  /**
+   * Background crowds staged in this region
+   */
+  crowds_present?: CrowdPresenceData[];
+  navigation: NavigationData;
+  npcs_present: NpcPresenceData[];
+  pc_id: string;
+  region: RegionData;
+  /**
+   * Items visible in this region (can be picked up)
+   */
+  region_items?: RegionItemData[];
+} | {
+  type: "MovementBlocked";
+  pc_id: string;
+  reason: string;
+} | {
+  type: "JourneyInterrupted";
+  journey: JourneyData;
+} | {
+  type: "JourneyResolved";
+  decision: JourneyDecision;
+  journey: JourneyData;
+} | {
+  type: "ConnectionUnlocked";
+  connection: UnlockedConnectionData;
+} | {
+  type: "GameTimeUpdated";
+  game_time: GameTime;
+} | {
+  type: "GameTimeAdvanced";
+  data: TimeAdvanceData;
+} | {
+  type: "TimeSuggestion";
+  data: TimeSuggestionData;
+} | {
+  type: "TimeModeChanged";
+  mode: TimeMode;
+  world_id: string;
+} | {
+  type: "GameTimePaused";
+  paused: boolean;
+  world_id: string;
+} | {
+  type: "TimeConfigUpdated";
+  config: GameTimeConfig;
+  world_id: string;
+} | {
+  type: "WorldTypographyUpdated";
+  typography: WorldTypographyData;
+  world_id: string;
+} | {
+  type: "WorldThemeUpdated";
+  theme: WorldThemeData;
+  world_id: string;
+} | {
+  type: "WorldMapUpdated";
+  map: WorldMapData;
+  world_id: string;
+} | {
+  type: "WorldFeaturesUpdated";
+  features: WorldFeaturesData;
+  world_id: string;
+} | {
+  type: "WorldScriptsUpdated";
+  scripts: WorldScriptData[];
+  world_id: string;
+} | {
+  type: "CustomFieldsUpdated";
+  fields: CustomFieldDefinitionData[];
+  world_id: string;
+} | {
+  type: "ScriptNotice";
+  is_error?: boolean;
+  message: string;
+  script: string;
+  world_id: string;
+} | {
+  type: "EntityTagsUpdated";
+  entity_id: string;
+  entity_type: EntityType;
+  tags: string[];
+  world_id: string;
+} | {
+  type: "WorldTagsUpdated";
+  tags: TagUsageData[];
+  world_id: string;
+} | {
+  type: "SafetySignalRaised";
+  level: SafetySignalLevelData;
+  /**
+   * Whether the signal paused the action queue
+   */
+  queue_paused: boolean;
+  /**
+   * Pending NPC dialogue that was auto-rejected
+   */
+  rejected_request_ids?: string[];
+  world_id: string;
+} | {
+  type: "ActionQueuePaused";
+  paused: boolean;
+  world_id: string;
+} | {
+  type: "StagingApprovalRequired";
+  /**
+   * Available location states for DM selection
+   */
+  available_location_states?: StateOptionData[];
+  /**
+   * Available region states for DM selection
+   */
+  available_region_states?: StateOptionData[];
+  /**
+   * Default TTL from location settings
+   */
+  default_ttl_hours: number;
+  game_time: GameTime;
+  /**
+   * NPCs suggested by LLM (if enabled)
+   */
+  llm_based_npcs: StagedNpcInfo[];
+  location_id: string;
+  location_name: string;
+  /**
+   * Previous staging if expired (for reference)
+   */
+  previous_staging?: PreviousStagingInfo | null;
+  region_id: string;
+  region_name: string;
+  request_id: string;
+  /**
+   * Auto-resolved visual state based on activation rules
+   */
+  resolved_visual_state?: ResolvedVisualStateData | null;
+  /**
+   * NPCs suggested by rule-based logic
+   */
+  rule_based_npcs: StagedNpcInfo[];
+  /**
+   * PCs waiting for this staging
+   */
+  waiting_pcs: WaitingPcInfo[];
+} | {
+  type: "StagingPending";
+  region_id: string;
+  region_name: string;
+  /**
+   * Timeout in seconds before auto-approve (0 = no auto-approve)
+   */
+  timeout_seconds?: number;
+} | {
+  type: "StagingReady";
+  /**
+   * NPCs present in this staging
+   */
+  npcs_present: NpcPresentInfo[];
+  region_id: string;
+  /**
+   * Resolved visual state for scene display
+   */
+  visual_state?: ResolvedVisualStateData | null;
+} | {
+  type: "StagingRegenerated";
+  /**
+   * Updated LLM-based NPC suggestions
+   */
+  llm_based_npcs: StagedNpcInfo[];
+  request_id: string;
+} | {
+  type: "StagingTimedOut";
+  region_id: string;
+  region_name: string;
+} | {
+  type: "ItemEquipped";
+  item_id: string;
+  item_name: string;
+  pc_id: string;
+} | {
+  type: "ItemUnequipped";
+  item_id: string;
+  item_name: string;
+  pc_id: string;
+} | {
+  type: "ItemDropped";
+  item_id: string;
+  item_name: string;
+  pc_id: string;
+  quantity: number;
+} | {
+  type: "ItemPickedUp";
+  item_id: string;
+  item_name: string;
+  pc_id: string;
+} | {
+  type: "InventoryUpdated";
+  pc_id: string;
+} | {
+  type: "TradeOffered";
+  trade: TradeOfferData;
+} | {
+  type: "TradeCompleted";
+  trade: TradeOfferData;
+} | {
+  type: "TradeClosed";
+  message?: string | null;
+  reason: TradeClosedReason;
+  trade_id: string;
+} | {
+  type: "CharacterStatUpdated";
+  character_id: string;
+  character_name: string;
+  delta: number;
+  new_value: number;
+  old_value: number;
+  /**
+   * Source of the change (e.g., "challenge_outcome", "tool_call", "dm_action")
+   */
+  source: string;
+  stat_name: string;
+} | {
+  type: "NpcDispositionChanged";
+  disposition: string;
+  npc_id: string;
+  npc_name: string;
+  pc_id: string;
+  reason?: string | null;
+  relationship: string;
+} | {
+  type: "NpcMoodChanged";
+  /**
+   * New mood state
+   */
+  new_mood: string;
+  npc_id: string;
+  npc_name: string;
+  /**
+   * Previous mood state
+   */
+  old_mood: string;
+  /**
+   * Reason for the mood change
+   */
+  reason?: string | null;
+  /**
+   * Region where this NPC is currently staged (for routing)
+   */
+  region_id?: string | null;
+} | {
+  type: "NpcDispositionsResponse";
+  dispositions: NpcDispositionData[];
+  pc_id: string;
+} | {
+  type: "LoreDiscovered";
+  character_id: string;
+  /**
+   * Which chunks were just discovered (empty = all)
+   */
+  discovered_chunk_ids?: string[];
+  /**
+   * How it was discovered
+   */
+  discovery_source: LoreDiscoverySourceData;
+  /**
+   * Full lore data (or just discovered chunks)
+   */
+  lore: LoreData;
+} | {
+  type: "KnowledgeShared";
+  from_pc_id: string;
+  from_pc_name: string;
+  /**
+   * What was shared, e.g. the lore title
+   */
+  title: string;
+  /**
+   * PCs whose knowledge changed (those who already knew are left out)
+   */
+  to_pc_ids: string[];
+} | {
+  type: "LoreRevoked";
+  character_id: string;
+  lore_id: string;
+} | {
+  type: "LoreUpdated";
+  lore: LoreData;
+} | {
+  type: "CharacterLoreResponse";
+  character_id: string;
+  known_lore: LoreSummaryData[];
+} | {
+  type: "NpcWantCreated";
+  npc_id: string;
+  want: WantData;
+} | {
+  type: "NpcWantUpdated";
+  npc_id: string;
+  want: WantData;
+} | {
+  type: "NpcWantDeleted";
+  npc_id: string;
+  want_id: string;
+} | {
+  type: "WantTargetSet";
+  target: WantTargetData;
+  want_id: string;
+} | {
+  type: "WantTargetRemoved";
+  want_id: string;
+} | {
+  type: "ActantialViewAdded";
+  npc_id: string;
+  view: ActantialViewData;
+} | {
+  type: "ActantialViewRemoved";
+  npc_id: string;
+  role: ActantialRoleData;
+  target_id: string;
+  want_id: string;
+} | {
+  type: "NpcActantialContextResponse";
+  context: NpcActantialContextData;
+  npc_id: string;
+} | {
+  type: "WorldGoalsResponse";
+  goals: GoalData[];
+  world_id: string;
+} | {
+  type: "GoalCreated";
+  goal: GoalData;
+  world_id: string;
+} | {
+  type: "GoalUpdated";
+  goal: GoalData;
+} | {
+  type: "GoalDeleted";
+  goal_id: string;
+} | {
+  type: "DeflectionSuggestions";
+  npc_id: string;
+  suggestions: string[];
+  want_id: string;
+} | {
+  type: "TellsSuggestions";
+  npc_id: string;
+  suggestions: string[];
+  want_id: string;
+} | {
+  type: "WantDescriptionSuggestions";
+  npc_id: string;
+  suggestions: string[];
+} | {
+  type: "ActantialReasonSuggestions";
+  npc_id: string;
+  role: ActantialRoleData;
+  suggestions: string[];
+  target_id: string;
+  want_id: string;
+} | {
+  type: "WorldJoined";
+  /**
+   * Users currently connected to this world
+   */
+  connected_users: ConnectedUser[];
+  /**
+   * Full world snapshot (for initial load)
+   */
+  snapshot: unknown;
+  /**
+   * World that was joined
+   */
+  world_id: string;
+  /**
+   * Your player character (if Player role)
+   */
+  your_pc?: unknown;
+  /**
+   * Your role in this world
+   */
+  your_role: WorldRole;
+} | {
+  type: "WorldJoinFailed";
+  /**
+   * Reason for failure
+   */
+  error: JoinError;
+  /**
+   * World that was attempted
+   */
+  world_id: string;
+} | {
+  type: "UserJoined";
+  /**
+   * User's PC (if Player role)
+   */
+  pc?: unknown;
+  /**
+   * User's role
+   */
+  role: WorldRole;
+  /**
+   * User who joined
+   */
+  user_id: string;
+  /**
+   * User's display name
+   */
+  username?: string | null;
+} | {
+  type: "UserLeft";
+  /**
+   * User who left
+   */
+  user_id: string;
+} | {
+  type: "LocaleChanged";
+  /**
+   * Locale actually used (falls back to "en" if unsupported)
+   */
+  locale: string;
+  /**
+   * Locales the server has string catalogs for
+   */
+  supported_locales: string[];
+} | {
+  type: "Response";
+  /**
+   * Correlated request ID
+   */
+  request_id: string;
+  /**
+   * Result of the operation
+   */
+  result: ResponseResult;
+} | EntityChangedData & {
+  type: "EntityChanged";
+} | {
+  type: "ControlledPcsChanged";
+  /**
+   * PC that player actions are taken as
+   */
+  active_pc_id: string;
+  /**
+   * Every controlled PC, in the same shape as `WorldJoined::your_pc`
+   */
+  pcs: unknown[];
+} | {
+  type: "SpectateTargetChanged";
+  /**
+   * New PC being spectated
+   */
+  pc_id: string;
+  /**
+   * PC's name
+   */
+  pc_name: string;
+} | {
+  type: "ClockUpdated";
+  clock: ProgressClockData;
+} | {
+  type: "ClockRemoved";
+  clock_id: string;
+} | {
+  type: "DiceRolled";
+  roll: DiceRollData;
+} | {
+  type: "MapMarkerUpdated";
+  marker: MapMarkerData;
+} | {
+  type: "MapMarkerRemoved";
+  marker_id: string;
+} | {
+  type: "RegionHotspotsUpdated";
+  hotspots: HotspotData[];
+  region_id: string;
+} | {
+  type: "RegionLightChanged";
+  light_level: LightLevelData;
+  region_id: string;
+} | {
+  type: "RegionMapGenerated";
+  error?: string | null;
+  map_asset?: string | null;
+  region_id: string;
+} | {
+  type: "TutorialProgress";
+  status: TutorialStatusData;
+} | {
+  type: "CharactersAged";
+  progressions: AgeProgressionData[];
+  world_id: string;
+} | {
+  type: "InjuriesHealed";
+  injuries: InjuryData[];
+  world_id: string;
+} | {
+  type: "PcDied";
+  death: PcDeathData;
+  world_id: string;
+} | {
+  type: "PcResurrected";
+  name: string;
+  pc_id: string;
+  world_id: string;
+} | {
+  type: "PcSecretUpdated";
+  secret: PcSecretData;
+} | {
+  type: "PcSecretRemoved";
+  pc_id: string;
+  secret_id: string;
+} | {
+  type: "PcSecretRevealed";
+  pc_name: string;
+  secret: PcSecretData;
+  world_id: string;
+} | {
+  type: "LetterUpdated";
+  letter: LetterData;
+} | {
+  type: "LetterRemoved";
+  letter_id: string;
+} | {
+  type: "BroadsheetDrafted";
+  broadsheet: BroadsheetData;
+} | {
+  type: "BroadsheetPublished";
+  broadsheet: BroadsheetData;
+} | {
+  type: "BroadsheetRemoved";
+  broadsheet_id: string;
+} | {
+  type: "WorldSimulationProposed";
+  simulation: WorldSimulationData;
+} | {
+  type: "WorldSimulationResolved";
+  accepted: boolean;
+  simulation_id: string;
+} | {
+  type: "RegionCrowdsChanged";
+  crowds_present: CrowdPresenceData[];
+  region_id: string;
+} | {
+  type: "NpcDraftUpdated";
+  draft: NpcDraftData;
+} | {
+  type: "FlashbackChanged";
+  flashback?: FlashbackData | null;
+} | {
+  type: "ParallelScenesChanged";
+  scenes: ParallelSceneData[];
+} | {
+  type: "SpotlightChanged";
+  spotlight: SpotlightData;
+} | {
+  type: "PacingTimerChanged";
+  timer?: PacingTimerData | null;
+} | {
+  type: "Unknown";
+};
+
+/**
+ * The group's choices from a session zero, made into a world in one pass
+ */
+export type SessionZeroData = {
+  contentSafety?: ContentSafetyData;
+  /**
+   * The campaign pitch
+   */
+  description?: string | null;
+  name: string;
+  npcSeeds?: NpcSeedData[];
+  ruleSystem: RuleSystemVariant;
+  /**
+   * Game date the campaign opens on (YYYY-MM-DD); today when absent
+   */
+  startDate?: string | null;
+  /**
+   * Hour of the opening day (0-23)
+   */
+  startHour?: number | null;
+  startingLocation: StartingLocationData;
+  timeMode?: TimeMode;
+  /**
+   * How the campaign should feel ("grim and grounded", "swashbuckling")
+   */
+  tone?: string | null;
+};
+
+/**
+ * Something a player shares with other PCs in the party
+ */
+export type SharedKnowledgeData = {
+  type: "lore";
+  lore_id: string;
+} | {
+  type: "npc_whereabouts";
+  npc_id: string;
+} | {
+  type: "unknown";
+};
+
+/**
+ * One thing that happened off-screen during a time skip
+ */
+export type SimulationChangeData = {
+  kind: "clock_advanced";
+  clock_id: string;
+  clock_name: string;
+  segments: number;
+} | {
+  kind: "npc_moved";
+  npc_id: string;
+  npc_name: string;
+  region_id: string;
+  region_name: string;
+} | {
+  kind: "rumor_spread";
+  lore_id: string;
+  lore_title: string;
+  npc_id: string;
+  npc_name: string;
+  told_by_id: string;
+  told_by_name: string;
+} | {
+  kind: "price_drift";
+  from_percent: number;
+  modifier_id: string;
+  reason: string;
+  to_percent: number;
+} | {
+  kind: "unknown";
+};
+
+/**
+ * Off-screen changes simulated during time skips, awaiting the DM
+ *
+ * All requests are DM only. Nothing changes in the world until the DM
+ * accepts a simulation.
+ */
+export type SimulationRequest = {
+  type: "list_simulations";
+} | {
+  type: "update_simulation";
+  changes: SimulationChangeData[];
+  simulation_id: string;
+} | {
+  type: "accept_simulation";
+  simulation_id: string;
+} | {
+  type: "discard_simulation";
+  simulation_id: string;
+};
+
+/**
+ * Skill names that mean the same thing across rule systems.
+ *
+ * Each group lists interchangeable names, most common first, e.g.
+ * "Perception", "Notice" and "Survey". Used to carry challenges over when
+ * content is imported from another system or a world switches systems.
+ */
+export type SkillAliases = {
+  groups: string[][];
+};
+
+/**
+ * The DM's pick of skill for one challenge in a rule system switch
+ */
+export type SkillOverrideData = {
+  challenge_id: string;
+  skill: string;
+};
+
+export type SkillRequest = {
+  type: "list_skills";
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_skill";
+  skill_id: string;
+} | {
+  type: "create_skill";
+  data: CreateSkillData;
+  world_id: string;
+} | {
+  type: "update_skill";
+  data: UpdateSkillData;
+  skill_id: string;
+} | {
+  type: "delete_skill";
+  skill_id: string;
+};
+
+/**
+ * Social relation data (ally or enemy)
+ */
+export type SocialRelationData = {
+  actor_type: ActorTypeData;
+  id: string;
+  name: string;
+  reasons: string[];
+};
+
+/**
+ * Social views summary data
+ */
+export type SocialViewsData = {
+  allies: SocialRelationData[];
+  enemies: SocialRelationData[];
+};
+
+/**
+ * How well noise passes along a region connection (wire format)
+ */
+export type SoundCarryData = "open" | "muffled" | "blocked" | "unknown";
+
+/**
+ * Location information for split party notification
+ */
+export type SplitPartyLocation = {
+  location_id: string;
+  location_name: string;
+  pc_count: number;
+  pc_names: string[];
+};
+
+/**
+ * The spotlight tracker. Players get an empty rotation.
+ */
+export type SpotlightData = {
+  /**
+   * The PC the DM is turning to
+   */
+  focusPcId?: string | null;
+  /**
+   * PCs in rotation order, longest without acting first
+   */
+  rotation?: SpotlightPcData[];
+  settings: SpotlightSettingsData;
+};
+
+/**
+ * One PC's place in the spotlight rotation
+ */
+export type SpotlightPcData = {
+  /**
+   * Actions other PCs took since this one last acted
+   */
+  actionsSince: number;
+  /**
+   * Sat out long enough to warn the DM
+   */
+  isQuiet: boolean;
+  name: string;
+  pcId: string;
+};
+
+/**
+ * Spotlight rotation in the current world. Anyone in the world can see who
+ * has the spotlight; the rest is DM-only.
+ */
+export type SpotlightRequest = {
+  type: "get_spotlight";
+} | {
+  type: "update_spotlight_settings";
+  data: SpotlightSettingsData;
+} | {
+  type: "rotate_spotlight";
+} | {
+  type: "set_spotlight";
+  pc_id?: string | null;
+} | {
+  type: "reset_spotlight";
+};
+
+/**
+ * Spotlight rotation settings
+ */
+export type SpotlightSettingsData = {
+  enabled: boolean;
+  /**
+   * Actions by others after which a PC who hasn't acted is flagged
+   */
+  quietAfter: number;
+  /**
+   * Refuse back-to-back actions from one PC when at least this many PCs
+   * share its region; 0 never refuses
+   */
+  throttleCrowd?: number;
+};
+
+/**
+ * What a staff member does at a property
+ */
+export type StaffRoleData = "guard" | "worker" | "unknown" | "steward";
+
+/**
+ * Info about a staged NPC (for approval UI)
+ */
+export type StagedNpcInfo = {
+  character_id: string;
+  /**
+   * When true, NPC is present but hidden from players
+   */
+  is_hidden_from_players?: boolean;
+  is_present: boolean;
+  /**
+   * NPC's current mood (Tier 2 of emotional model)
+   * Affects default expression and dialogue tone
+   */
+  mood?: string | null;
+  name: string;
+  portrait_asset?: string | null;
+  reasoning: string;
+  sprite_asset?: string | null;
+};
+
+/**
+ * Where and when to set a flashback
+ */
+export type StartFlashbackData = {
+  /**
+   * Day of that year the flashback happens on
+   */
+  day: number;
+  hour: number;
+  /**
+   * Who is in the region during the flashback
+   */
+  npcs?: ApprovedNpcInfo[];
+  regionId: string;
+  /**
+   * How the region looked back then; none keeps its default look
+   */
+  regionStateId?: string | null;
+  /**
+   * Shown to everyone while the flashback runs
+   */
+  title: string;
+  /**
+   * How many years before the present; 0 for earlier this year
+   */
+  yearsBack?: number;
+};
+
+/**
+ * A pacing timer for the DM to start
+ */
+export type StartPacingTimerData = {
+  kind: PacingTimerKindData;
+  label?: string | null;
+  /**
+   * Real-world minutes per scene or per turn
+   */
+  minutes: number;
+  /**
+   * Minutes before the end at which to warn the table
+   */
+  warningMinutes?: number;
+};
+
+/**
+ * Where a session zero world's story starts
+ */
+export type StartingLocationData = {
+  description?: string | null;
+  name: string;
+  regionDescription?: string | null;
+  /**
+   * The region inside the location where PCs arrive
+   */
+  regionName: string;
+};
+
+/**
+ * Definition of a character stat
+ */
+export type StatDefinition = {
+  abbreviation: string;
+  default_value: number;
+  max_value: number;
+  min_value: number;
+  name: string;
+};
+
+/**
+ * Requests for character stat operations
+ */
+export type StatRequest = {
+  type: "get_character_stats";
+  character_id: string;
+} | {
+  type: "set_base_stat";
+  character_id: string;
+  stat_name: string;
+  value: number;
+} | {
+  type: "add_modifier";
+  character_id: string;
+  data: AddModifierData;
+} | {
+  type: "remove_modifier";
+  character_id: string;
+  modifier_id: string;
+  stat_name: string;
+} | {
+  type: "toggle_modifier";
+  character_id: string;
+  modifier_id: string;
+  stat_name: string;
+} | {
+  type: "clear_stat_modifiers";
+  character_id: string;
+  stat_name: string;
+} | {
+  type: "clear_all_modifiers";
+  character_id: string;
+} | {
+  type: "get_stat_templates";
+  /**
+   * Optional: specific rule system variant (e.g., "dnd5e", "call_of_cthulhu_7e")
+   * If not provided, returns all available templates
+   */
+  variant?: string | null;
+} | {
+  type: "initialize_from_template";
+  character_id: string;
+  /**
+   * Rule system variant to use (e.g., "dnd5e", "fate_core")
+   */
+  variant: string;
+};
+
+/**
+ * State option for DM selection
+ */
+export type StateOptionData = {
+  id: string;
+  isDefault: boolean;
+  /**
+   * Why this state was suggested (rule match description)
+   */
+  matchReason?: string | null;
+  name: string;
+  priority: number;
+};
+
+export type StoryEventRequest = {
+  type: "list_story_events";
+  page?: number | null;
+  page_size?: number | null;
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+  world_id: string;
+} | {
+  type: "get_story_event";
+  event_id: string;
+} | {
+  type: "create_dm_marker";
+  data: CreateDmMarkerData;
+  world_id: string;
+} | {
+  type: "update_story_event";
+  data: UpdateStoryEventData;
+  event_id: string;
+} | {
+  type: "set_story_event_visibility";
+  event_id: string;
+  visible: boolean;
+} | {
+  type: "list_map_markers";
+  location_id: string;
+  /**
+   * Region backdrop; omit for the location map
+   */
+  region_id?: string | null;
+  world_id: string;
+} | {
+  type: "pin_dm_marker";
+  event_id: string;
+  pin?: MarkerPinData | null;
+};
+
+/**
+ * How success is determined
+ */
+export type SuccessComparison = "greater_or_equal" | "less_or_equal" | "narrative" | "unknown";
+
+/**
+ * A new PC taking over from a fallen one
+ */
+export type SuccessorInputData = {
+  description?: string | null;
+  /**
+   * Learn the lore the fallen PC knew
+   */
+  inheritLore?: boolean;
+  /**
+   * Hear about the NPCs the fallen PC had seen
+   */
+  inheritObservations?: boolean;
+  name: string;
+  sheetData?: unknown;
+};
+
+/**
+ * Context data for content suggestions
+ *
+ * This context is passed to the LLM to help generate relevant suggestions.
+ * Fields can be populated by the client with whatever information is available.
+ * The engine may auto-enrich this context with world data when world_id is available.
+ */
+export type SuggestionContextData = {
+  /**
+   * Additional context from other fields (e.g., description, backstory)
+   */
+  additionalContext?: string | null;
+  /**
+   * Name of the entity (if already set)
+   */
+  entityName?: string | null;
+  /**
+   * Type of entity being created (e.g., "character", "location", "tavern", "forest")
+   */
+  entityType?: string | null;
+  /**
+   * Hints or keywords to guide generation (e.g., archetype, theme)
+   */
+  hints?: string | null;
+  /**
+   * World ID for context enrichment (matches domain SuggestionContext.world_id)
+   */
+  worldId?: string | null;
+  /**
+   * World/setting name or type (e.g., "Dark Fantasy", "Sci-Fi Western")
+   * If not provided, the engine may auto-populate this from the world record.
+   */
+  worldSetting?: string | null;
+};
+
+/**
+ * Entity tags and saved tag filters (DM only)
+ */
+export type TagRequest = {
+  type: "get_entity_tags";
+  entity_id: string;
+  entity_type: EntityType;
+  world_id: string;
+} | {
+  type: "set_entity_tags";
+  entity_id: string;
+  entity_type: EntityType;
+  tags: string[];
+  world_id: string;
+} | {
+  type: "list_world_tags";
+  world_id: string;
+} | {
+  type: "rename_tag";
+  from: string;
+  to: string;
+  world_id: string;
+} | {
+  type: "delete_tag";
+  tag: string;
+  world_id: string;
+} | {
+  type: "list_saved_filters";
+  world_id: string;
+} | {
+  type: "save_filter";
+  data: SaveFilterData;
+  world_id: string;
+} | {
+  type: "delete_saved_filter";
+  filter_id: string;
+  world_id: string;
+};
+
+/**
+ * How many entities in a world carry a tag
+ */
+export type TagUsageData = {
+  count: number;
+  tag: string;
+};
+
+/**
+ * What a template creates
+ */
+export type TemplateBodyData = NpcTemplateData & {
+  kind: "npc";
+} | RegionTemplateData & {
+  kind: "region";
+} | {
+  kind: "unknown";
+};
+
+/**
+ * An NPC in a region template and how they relate to the region
+ */
+export type TemplateNpcData = {
+  npc: NpcTemplateData;
+  role: TemplateNpcRoleData;
+};
+
+/**
+ * How a template NPC relates to the new region (drives staging suggestions)
+ */
+export type TemplateNpcRoleData = {
+  type: "home";
+} | {
+  type: "works_at";
+  shift?: string;
+} | {
+  type: "frequents";
+  frequency?: string;
+} | {
+  type: "avoids";
+  reason?: string;
+} | {
+  type: "unknown";
+};
+
+/**
+ * Entity templates (prefabs) and stamping them out (DM only)
+ */
+export type TemplateRequest = {
+  type: "list_templates";
+  world_id: string;
+} | {
+  type: "get_template";
+  template_id: string;
+} | {
+  type: "create_template";
+  data: SaveTemplateData;
+  world_id: string;
+} | {
+  type: "update_template";
+  data: SaveTemplateData;
+  template_id: string;
+} | {
+  type: "delete_template";
+  template_id: string;
+} | {
+  type: "save_character_as_template";
+  character_id: string;
+  name: string;
+} | {
+  type: "save_region_as_template";
+  name: string;
+  region_id: string;
+} | {
+  type: "instantiate_template";
+  /**
+   * Location for the new region (region templates only)
+   */
+  location_id?: string | null;
+  name: string;
+  template_id: string;
+  variables?: Record<string, string>;
+};
+
+/**
+ * Test a workflow configuration request.
+ */
+export type TestWorkflowRequestDto = {
+  negativePrompt?: string | null;
+  prompt: string;
+};
+
+/**
+ * Response for workflow test operation
+ */
+export type TestWorkflowResponseDto = {
+  promptId: string;
+  queuePosition: number;
+};
+
+/**
+ * Base text direction for world text (wire format)
+ */
+export type TextDirectionData = "auto" | "leftToRight" | "rightToLeft" | "unknown";
+
+/**
+ * Data about a time advancement (for broadcasting)
+ */
+export type TimeAdvanceData = {
+  /**
+   * Minutes that were advanced
+   */
+  minutesAdvanced: number;
+  /**
+   * New period name if changed
+   */
+  newPeriod?: string | null;
+  /**
+   * Time after advancement
+   */
+  newTime: GameTime;
+  /**
+   * Whether the time period changed
+   */
+  periodChanged: boolean;
+  /**
+   * Time before advancement
+   */
+  previousTime: GameTime;
+  /**
+   * Human-readable reason
+   */
+  reason: string;
+};
+
+/**
+ * Time costs for various actions (wire format)
+ */
+export type TimeCostConfig = {
+  /**
+   * Minutes per challenge attempt
+   */
+  challenge: number;
+  /**
+   * Minutes per conversation exchange
+   */
+  conversation: number;
+  /**
+   * Minutes for long rest
+   */
+  restLong: number;
+  /**
+   * Minutes for short rest
+   */
+  restShort: number;
+  /**
+   * Minutes for scene transitions
+   */
+  sceneTransition: number;
+  /**
+   * Minutes for travel between locations
+   */
+  travelLocation: number;
+  /**
+   * Minutes for travel between regions
+   */
+  travelRegion: number;
+};
+
+/**
+ * How time is displayed to players.
+ */
+export type TimeFormat = "twelve_hour" | "twenty_four_hour" | "period_only";
+
+/**
+ * How time suggestions are handled (wire format)
+ */
+export type TimeMode = "manual" | "suggested" | "auto";
+
+/**
+ * Time of day period (wire format, matches domain::TimeOfDay)
+ */
+export type TimeOfDayData = "morning" | "afternoon" | "evening" | "night" | "unknown";
+
+export type TimeRequest = {
+  type: "get_game_time";
+  world_id: string;
+} | {
+  type: "advance_game_time";
+  hours: number;
+  world_id: string;
+} | {
+  type: "advance_game_time_minutes";
+  minutes: number;
+  reason?: string | null;
+  world_id: string;
+} | {
+  type: "set_game_time";
+  day: number;
+  hour: number;
+  notify_players?: boolean;
+  world_id: string;
+} | {
+  type: "skip_to_period";
+  period: string;
+  world_id: string;
+} | {
+  type: "get_time_config";
+  world_id: string;
+} | {
+  type: "update_time_config";
+  config: GameTimeConfig;
+  world_id: string;
+};
+
+/**
+ * A time suggestion awaiting DM approval
+ */
+export type TimeSuggestionData = {
+  /**
+   * Human-readable description
+   */
+  actionDescription: string;
+  /**
+   * Type of action (travel_location, rest_short, etc.)
+   */
+  actionType: string;
+  /**
+   * Current time before advancement
+   */
+  currentTime: GameTime;
+  /**
+   * PC that triggered this suggestion
+   */
+  pcId: string;
+  /**
+   * PC name for display
+   */
+  pcName: string;
+  /**
+   * If period changes, (from_period, to_period)
+   */
+  periodChange?: [string, string] | null;
+  /**
+   * Time after advancement if approved
+   */
+  resultingTime: GameTime;
+  /**
+   * Suggested time cost in minutes
+   */
+  suggestedMinutes: number;
+  /**
+   * Unique ID for this suggestion
+   */
+  suggestionId: string;
+};
+
+/**
+ * DM's decision on a time suggestion
+ */
+export type TimeSuggestionDecision = {
+  decision: "approve";
+} | {
+  decision: "modify";
+  minutes: number;
+} | {
+  decision: "skip";
+} | {
+  decision: "unknown";
+};
+
+/**
+ * Why an open trade offer closed without completing
+ */
+export type TradeClosedReason = "declined" | "cancelled" | "expired" | "unknown" | "failed";
+
+/**
+ * An item named in a trade offer
+ */
+export type TradeItemData = {
+  itemId: string;
+  name: string;
+};
+
+/**
+ * A PC-to-PC trade offer for wire transfer
+ */
+export type TradeOfferData = {
+  /**
+   * Sheet field holding currency (e.g. "GP"); None if the system has none
+   */
+  currencyLabel?: string | null;
+  /**
+   * Unix timestamp (seconds) after which the offer lapses
+   */
+  expiresAt: number;
+  fromPcId: string;
+  fromPcName: string;
+  /**
+   * What the offering PC gives
+   */
+  offered: TradeSideData;
+  /**
+   * What the offering PC asks for in return
+   */
+  requested: TradeSideData;
+  toPcId: string;
+  toPcName: string;
+  tradeId: string;
+};
+
+/**
+ * What one party hands over in a trade
+ */
+export type TradeSideData = {
+  currency?: number;
+  items?: TradeItemData[];
+};
+
+/**
+ * Speech-to-text for players who'd rather talk than type. The engine
+ * returns the text; the player reviews it before sending.
+ */
+export type TranscriptionRequest = {
+  type: "transcribe_audio";
+  /**
+   * Base64-encoded audio
+   */
+  audio: string;
+  /**
+   * MIME type of the audio, e.g. "audio/webm"
+   */
+  content_type: string;
+};
+
+export type TutorialRequest = {
+  type: "create_tutorial_world";
+} | {
+  type: "get_tutorial_status";
+  pc_id: string;
+  world_id: string;
+};
+
+/**
+ * Where a PC is in a world's tutorial
+ */
+export type TutorialStatusData = {
+  /**
+   * Number of steps completed so far
+   */
+  completedSteps: number;
+  /**
+   * Step the PC is working on (`None` once the tutorial is finished)
+   */
+  currentStep?: TutorialStepData | null;
+  /**
+   * Step the PC just finished, when this status follows an action
+   */
+  justCompleted?: TutorialStepData | null;
+  pcId: string;
+  totalSteps: number;
+  worldId: string;
+};
+
+/**
+ * A tutorial step for wire transfer
+ */
+export type TutorialStepData = {
+  completionText?: string | null;
+  id: string;
+  instructions: string;
+  title: string;
+};
+
+/**
+ * What opened a lock
+ */
+export type UnlockMethodData = "key" | "challenge" | "unknown";
+
+/**
+ * A connection a PC just unlocked
+ */
+export type UnlockedConnectionData = {
+  /**
+   * The region or location the way leads out of
+   */
+  from_id: string;
+  method: UnlockMethodData;
+  /**
+   * Name of the key item or challenge that opened it
+   */
+  opened_with: string;
+  pc_id: string;
+  pc_name: string;
+  to: LockedWayData;
+  to_name: string;
+};
+
+/**
+ * Request DTO for updating an asset's label
+ */
+export type UpdateAssetLabelRequestDto = {
+  label?: string | null;
+};
+
+/**
+ * Data for updating a challenge
+ */
+export type UpdateChallengeData = {
+  description?: string | null;
+  difficulty?: string | null;
+  failure_outcome?: string | null;
+  name?: string | null;
+  skill_id?: string | null;
+  success_outcome?: string | null;
+};
+
+/**
+ * Data for updating a character
+ */
+export type UpdateCharacterData = {
+  description?: string | null;
+  is_active?: boolean | null;
+  is_alive?: boolean | null;
+  name?: string | null;
+  portrait_asset?: string | null;
+  sprite_asset?: string | null;
+};
+
+/**
+ * Data for updating a progress clock
+ */
+export type UpdateClockData = {
+  /**
+   * Party clock it works against (empty string clears)
+   */
+  conflictsWithClockId?: string | null;
+  description?: string | null;
+  kind?: string | null;
+  name?: string | null;
+  /**
+   * PC the clock is personal to (empty string makes it a party clock)
+   */
+  ownerPcId?: string | null;
+  segments?: number | null;
+  subject?: string | null;
+  visibleToPlayers?: boolean | null;
+};
+
+/**
+ * Data for updating an event chain
+ */
+export type UpdateEventChainData = {
+  /**
+   * Optional act association
+   */
+  act_id?: string | null;
+  /**
+   * Display color (hex or named)
+   */
+  color?: string | null;
+  description?: string | null;
+  /**
+   * Events to set (replaces existing)
+   */
+  events?: string[] | null;
+  /**
+   * Whether the chain is active
+   */
+  is_active?: boolean | null;
+  name?: string | null;
+  /**
+   * Tags for categorization
+   */
+  tags?: string[] | null;
+};
+
+/**
+ * Data for updating an existing goal
+ */
+export type UpdateGoalData = {
+  description?: string | null;
+  name?: string | null;
+};
+
+/**
+ * Data for updating an interaction
+ */
+export type UpdateInteractionData = {
+  available?: boolean | null;
+  /**
+   * Empty means any time
+   */
+  available_times?: TimeOfDayData[] | null;
+  /**
+   * 0 removes the cooldown
+   */
+  cooldown_minutes?: number | null;
+  description?: string | null;
+  /**
+   * 0 removes the limit
+   */
+  max_uses?: number | null;
+  name?: string | null;
+  trigger?: string | null;
+};
+
+/**
+ * Data for updating a location
+ */
+export type UpdateLocationData = {
+  /**
+   * Game minutes between two places inside the location, overriding the
+   * scale's default
+   */
+  crossing_minutes?: number | null;
+  description?: string | null;
+  name?: string | null;
+  scale?: LocationScaleData | null;
+  setting?: string | null;
+};
+
+/**
+ * Data for updating a lore chunk
+ */
+export type UpdateLoreChunkData = {
+  content?: string | null;
+  discoveryHint?: string | null;
+  order?: number | null;
+  title?: string | null;
+};
+
+/**
+ * Data for updating a lore entry
+ */
+export type UpdateLoreData = {
+  category?: string | null;
+  isCommonKnowledge?: boolean | null;
+  summary?: string | null;
+  tags?: string[] | null;
+  title?: string | null;
+};
+
+/**
+ * Data for updating a narrative event
+ */
+export type UpdateNarrativeEventData = {
+  description?: string | null;
+  name?: string | null;
+  outcomes?: unknown;
+  trigger_conditions?: unknown;
+};
+
+/**
+ * Data for updating a player character
+ */
+export type UpdatePlayerCharacterData = {
+  name?: string | null;
+  sheet_data?: unknown;
+};
+
+/**
+ * Data for updating a region
+ */
+export type UpdateRegionData = {
+  description?: string | null;
+  is_spawn_point?: boolean | null;
+  name?: string | null;
+};
+
+/**
+ * Data for updating a scene
+ */
+export type UpdateSceneData = {
+  description?: string | null;
+  location_id?: string | null;
+  name?: string | null;
+};
+
+/**
+ * Data for updating a skill
+ */
+export type UpdateSkillData = {
+  attribute?: string | null;
+  category?: string | null;
+  description?: string | null;
+  is_hidden?: boolean | null;
+  name?: string | null;
+};
+
+/**
+ * Data for updating a story event
+ */
+export type UpdateStoryEventData = {
+  summary?: string | null;
+  tags?: string[] | null;
+};
+
+/**
+ * Data for updating an existing want
+ */
+export type UpdateWantData = {
+  deflection_behavior?: string | null;
+  description?: string | null;
+  intensity?: number | null;
+  priority?: number | null;
+  tells?: string[] | null;
+  visibility?: WantVisibilityData | null;
+};
+
+/**
+ * Request to update just the defaults of a workflow (without re-uploading the workflow JSON).
+ */
+export type UpdateWorkflowDefaultsRequestDto = {
+  inputDefaults?: InputDefaultDto[];
+  lockedInputs?: string[] | null;
+};
+
+/**
+ * Data for updating a world
+ */
+export type UpdateWorldData = {
+  description?: string | null;
+  name?: string | null;
+  setting?: string | null;
+};
+
+/**
+ * Request DTO for uploading an asset
+ *
+ * Either points at an already stored file (`file_path`) or carries the image
+ * itself in `data`, as when a DM drag-drops their own art into the Player.
+ */
+export type UploadAssetRequestDto = {
+  assetType: string;
+  /**
+   * Caption the image with the LLM before suggesting associations
+   */
+  caption?: boolean;
+  /**
+   * Base64-encoded image bytes (PNG, JPEG, WebP or GIF)
+   */
+  data?: string | null;
+  /**
+   * Original file name, used as a hint when suggesting associations
+   */
+  fileName?: string | null;
+  filePath?: string;
+  label?: string | null;
+  setActive?: boolean;
+};
+
+/**
+ * Response DTO for an uploaded image awaiting association
+ */
+export type UploadAssetResponseDto = {
+  caption?: string | null;
+  /**
+   * Stored path, to pass back as `file_path` when associating the image
+   */
+  filePath: string;
+  /**
+   * Best matches first
+   */
+  suggestions: AssetAssociationSuggestionDto[];
+};
+
+/**
+ * Info about a PC waiting for staging
+ */
+export type WaitingPcInfo = {
+  pc_id: string;
+  pc_name: string;
+  player_id: string;
+};
+
+/**
+ * Want data for wire transfer
+ */
+export type WantData = {
+  deflection_behavior?: string | null;
+  description: string;
+  /**
+   * Actantial actors for this want
+   */
+  helpers?: ActantialActorData[];
+  id: string;
+  intensity: number;
+  opponents?: ActantialActorData[];
+  priority: number;
+  receiver?: ActantialActorData | null;
+  sender?: ActantialActorData | null;
+  target?: WantTargetData | null;
+  tells?: string[];
+  visibility: WantVisibilityData;
+};
+
+export type WantRequest = {
+  type: "list_wants";
+  character_id: string;
+  /**
+   * Only entities carrying every one of these tags (DM only)
+   */
+  tags?: string[];
+} | {
+  type: "get_want";
+  want_id: string;
+} | {
+  type: "create_want";
+  character_id: string;
+  data: CreateWantData;
+} | {
+  type: "update_want";
+  data: UpdateWantData;
+  want_id: string;
+} | {
+  type: "delete_want";
+  want_id: string;
+} | {
+  type: "set_want_target";
+  target_id: string;
+  target_type: WantTargetTypeData;
+  want_id: string;
+} | {
+  type: "remove_want_target";
+  want_id: string;
+};
+
+/**
+ * Want target data (resolved target info)
+ */
+export type WantTargetData = {
+  /**
+   * Description for Goal targets
+   */
+  description?: string | null;
+  id: string;
+  name: string;
+  target_type: WantTargetTypeData;
+};
+
+/**
+ * Target type for wants
+ */
+export type WantTargetTypeData = "character" | "item" | "goal" | "unknown";
+
+/**
+ * Want visibility level - how much the player knows
+ */
+export type WantVisibilityData = "known" | "suspected" | "hidden" | "unknown";
+
+/**
+ * DTO for workflow analysis results
+ */
+export type WorkflowAnalysisDto = {
+  errors: string[];
+  inputs: WorkflowInputDto[];
+  nodeCount: number;
+  textInputs: WorkflowInputDto[];
+};
+
+/**
+ * Response for workflow analysis endpoint
+ */
+export type WorkflowAnalysisResponseDto = {
+  analysis: WorkflowAnalysisDto;
+  isValid: boolean;
+  suggestedPromptMappings: PromptMappingDto[];
+  suggestedReferenceImageMappings?: ReferenceImageMappingDto[];
+};
+
+/**
+ * Export/import DTO for workflow configurations (backup/restore).
+ */
+export type WorkflowConfigExportDto = {
+  created_at: string;
+  id: string;
+  input_defaults?: InputDefaultDto[];
+  locked_inputs?: string[];
+  name: string;
+  prompt_mappings?: PromptMappingDto[];
+  reference_image_mappings?: ReferenceImageMappingDto[];
+  slot: string;
+  updated_at: string;
+  workflow_json: unknown;
+};
+
+/**
+ * Full configuration response (includes workflow JSON).
+ */
+export type WorkflowConfigFullResponseDto = {
+  analysis: WorkflowAnalysisDto;
+  createdAt: string;
+  id: string;
+  inputDefaults: InputDefaultDto[];
+  lockedInputs: string[];
+  name: string;
+  promptMappings: PromptMappingDto[];
+  referenceImageMappings?: ReferenceImageMappingDto[];
+  slot: string;
+  slotDisplayName: string;
+  updatedAt: string;
+  workflowJson: unknown;
+};
+
+/**
+ * Response DTO for workflow configuration
+ */
+export type WorkflowConfigResponseDto = {
+  createdAt: string;
+  hasNegativePrompt: boolean;
+  hasPrimaryPrompt: boolean;
+  /**
+   * Whether generations can be conditioned on a reference image
+   */
+  hasReferenceImage?: boolean;
+  id: string;
+  inputCount: number;
+  name: string;
+  nodeCount: number;
+  promptMappings: PromptMappingDto[];
+  slot: string;
+  slotDisplayName: string;
+  updatedAt: string;
+};
+
+/**
+ * DTO for workflow input information
+ */
+export type WorkflowInputDto = {
+  currentValue: unknown;
+  inputName: string;
+  inputType: InputTypeDto;
+  nodeId: string;
+  nodeTitle?: string | null;
+  nodeType: string;
+};
+
+/**
+ * A category of workflow slots (e.g., "Character Assets").
+ */
+export type WorkflowSlotCategoryDto = {
+  name: string;
+  slots: WorkflowSlotStatusDto[];
+};
+
+/**
+ * Response for workflow slot status.
+ */
+export type WorkflowSlotStatusDto = {
+  config?: WorkflowConfigResponseDto | null;
+  configured: boolean;
+  defaultHeight: number;
+  defaultWidth: number;
+  displayName: string;
+  slot: string;
+};
+
+/**
+ * Response containing all workflow slots grouped by category.
+ */
+export type WorkflowSlotsResponseDto = {
+  categories: WorkflowSlotCategoryDto[];
+};
+
+/**
+ * Per-world switches for experimental subsystems (all off by default).
+ * Clients hide UI for disabled features.
+ */
+export type WorldFeaturesData = {
+  /**
+   * Character aging reported to the DM after long time skips
+   */
+  ageProgression?: boolean;
+  /**
+   * Initiative order and HP tracking during fights
+   */
+  combatTracker?: boolean;
+  /**
+   * NPC-to-NPC spread of rumors about player actions
+   */
+  rumors?: boolean;
+  /**
+   * Weather per region
+   */
+  weather?: boolean;
+};
+
+/**
+ * The world overview map and the locations pinned to it
+ */
+export type WorldMapData = {
+  map_asset?: string | null;
+  pins?: WorldMapPinData[];
+};
+
+/**
+ * A location pinned to the world map
+ */
+export type WorldMapPinData = {
+  location_id: string;
+  name: string;
+  parent_location_id?: string | null;
+  position: WorldMapPositionData;
+};
+
+/**
+ * Where a location's pin sits on the world map, as percentages of the image
+ */
+export type WorldMapPositionData = {
+  /**
+   * Percent of image width (0-100)
+   */
+  x: number;
+  /**
+   * Percent of image height (0-100)
+   */
+  y: number;
+};
+
+export type WorldRequest = {
+  type: "list_worlds";
+} | {
+  type: "get_world";
+  world_id: string;
+} | {
+  type: "create_world";
+  data: CreateWorldData;
+} | {
+  type: "update_world";
+  data: UpdateWorldData;
+  world_id: string;
+} | {
+  type: "delete_world";
+  world_id: string;
+} | {
+  type: "create_session_zero_world";
+  data: SessionZeroData;
+} | {
+  type: "export_world";
+  world_id: string;
+} | {
+  type: "branch_world";
+  name: string;
+  world_id: string;
+} | {
+  type: "get_sheet_template";
+  world_id: string;
+} | {
+  type: "get_content_safety";
+  world_id: string;
+} | {
+  type: "update_content_safety";
+  config: ContentSafetyData;
+  world_id: string;
+} | {
+  type: "get_typography";
+  world_id: string;
+} | {
+  type: "update_typography";
+  typography: WorldTypographyData;
+  world_id: string;
+} | {
+  type: "get_theme";
+  world_id: string;
+} | {
+  type: "update_theme";
+  theme: WorldThemeData;
+  world_id: string;
+} | {
+  type: "get_features";
+  world_id: string;
+} | {
+  type: "update_features";
+  features: WorldFeaturesData;
+  world_id: string;
+} | {
+  type: "get_scripts";
+  world_id: string;
+} | {
+  type: "update_scripts";
+  scripts: WorldScriptData[];
+  world_id: string;
+} | {
+  type: "get_custom_fields";
+  world_id: string;
+} | {
+  type: "update_custom_fields";
+  fields: CustomFieldDefinitionData[];
+  world_id: string;
+} | {
+  type: "set_custom_field_values";
+  entity_id: string;
+  entity_type: EntityType;
+  values: Record<string, CustomFieldValueData>;
+  world_id: string;
+} | {
+  type: "get_world_map";
+  world_id: string;
+} | {
+  type: "set_world_map_asset";
+  map_asset?: string | null;
+  world_id: string;
+} | {
+  type: "get_usage";
+  days?: number | null;
+  world_id: string;
+} | {
+  type: "get_analytics";
+  world_id: string;
+} | {
+  type: "get_unused_content";
+  world_id: string;
+} | {
+  type: "generate_prep_checklist";
+  world_id: string;
+} | {
+  type: "export_diagnostics";
+  world_id: string;
+} | {
+  type: "preview_rule_system_change";
+  variant: RuleSystemVariant;
+  world_id: string;
+} | {
+  type: "change_rule_system";
+  /**
+   * Skills the DM picked for challenges, over the alias table
+   */
+  skill_overrides?: SkillOverrideData[];
+  variant: RuleSystemVariant;
+  world_id: string;
+};
+
+/**
+ * Role of a user in a world
+ */
+export type WorldRole = "dm" | "player" | "spectator" | "unknown";
+
+/**
+ * A DM automation script (Rhai source) attached to a hook
+ */
+export type WorldScriptData = {
+  enabled?: boolean;
+  hook: ScriptHookData;
+  name: string;
+  /**
+   * Operations the script may run per invocation (server default if omitted)
+   */
+  operationBudget?: number | null;
+  source: string;
+};
+
+/**
+ * What the world got up to during a time skip, awaiting the DM
+ */
+export type WorldSimulationData = {
+  /**
+   * The changes, one line each
+   */
+  changelog: string[];
+  changes: SimulationChangeData[];
+  days: number;
+  /**
+   * Game time the skip started from (RFC 3339)
+   */
+  fromGameTime: string;
+  id: string;
+  /**
+   * Game time the skip ended at (RFC 3339)
+   */
+  toGameTime: string;
+};
+
+/**
+ * Per-world theme: accent color and backdrop frame
+ */
+export type WorldThemeData = {
+  /**
+   * Accent color as "#rrggbb"
+   */
+  accentColor?: string | null;
+  backdropFrame?: BackdropFrameData;
+};
+
+/**
+ * Per-world typography: direction, language, and font
+ */
+export type WorldTypographyData = {
+  direction?: TextDirectionData;
+  /**
+   * CSS font family list
+   */
+  fontFamily?: string | null;
+  /**
+   * Stylesheet URL that loads the font
+   */
+  fontStylesheetUrl?: string | null;
+  /**
+   * BCP 47 language tag (e.g. "ar", "ja-JP")
+   */
+  language?: string | null;
+};
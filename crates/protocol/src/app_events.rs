@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 
 /// Application-level events published through the EventBus.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum AppEvent {
     // ========================================================================
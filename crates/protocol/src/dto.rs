@@ -19,6 +19,7 @@ use wrldbldr_domain::types::{DispositionLevel, RelationshipLevel, WorkflowAnalys
 /// This DTO is used to transfer NPC disposition state over WebSocket/HTTP.
 /// It uses raw UUIDs instead of domain ID types for serialization compatibility.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct NpcDispositionStateDto {
     /// The NPC's UUID
@@ -47,19 +48,61 @@ pub struct NpcDispositionStateDto {
 // =============================================================================
 
 /// Request DTO for uploading an asset
+///
+/// Either points at an already stored file (`file_path`) or carries the image
+/// itself in `data`, as when a DM drag-drops their own art into the Player.
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct UploadAssetRequestDto {
     pub asset_type: String,
+    #[serde(default)]
     pub file_path: String,
     #[serde(default)]
     pub label: Option<String>,
     #[serde(default)]
     pub set_active: bool,
+    /// Base64-encoded image bytes (PNG, JPEG, WebP or GIF)
+    #[serde(default)]
+    pub data: Option<String>,
+    /// Original file name, used as a hint when suggesting associations
+    #[serde(default)]
+    pub file_name: Option<String>,
+    /// Caption the image with the LLM before suggesting associations
+    #[serde(default)]
+    pub caption: bool,
+}
+
+/// An entity an uploaded image likely depicts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct AssetAssociationSuggestionDto {
+    pub entity_type: String,
+    pub entity_id: String,
+    pub name: String,
+    /// 0.0 - 1.0
+    pub confidence: f32,
+    /// Why the entity was suggested, for display
+    pub reason: String,
+}
+
+/// Response DTO for an uploaded image awaiting association
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UploadAssetResponseDto {
+    /// Stored path, to pass back as `file_path` when associating the image
+    pub file_path: String,
+    #[serde(default)]
+    pub caption: Option<String>,
+    /// Best matches first
+    pub suggestions: Vec<AssetAssociationSuggestionDto>,
 }
 
 /// Request DTO for updating an asset's label
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateAssetLabelRequestDto {
     pub label: Option<String>,
@@ -67,6 +110,7 @@ pub struct UpdateAssetLabelRequestDto {
 
 /// Response DTO for gallery assets
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct GalleryAssetResponseDto {
     pub id: String,
@@ -87,6 +131,7 @@ pub struct GalleryAssetResponseDto {
 
 /// Request DTO for generating an asset
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct GenerateAssetRequestDto {
     /// World this asset belongs to
@@ -94,6 +139,8 @@ pub struct GenerateAssetRequestDto {
     pub entity_type: String,
     pub entity_id: String,
     pub asset_type: String,
+    /// ComfyUI workflow. Empty = the preset's workflow, or the asset slot's.
+    #[serde(default)]
     pub workflow: String,
     pub prompt: String,
     #[serde(default)]
@@ -102,6 +149,9 @@ pub struct GenerateAssetRequestDto {
     pub count: u8,
     #[serde(default)]
     pub style_reference_id: Option<String>,
+    /// Named generation preset for the asset slot. None = the slot's default preset.
+    #[serde(default)]
+    pub preset: Option<String>,
 }
 
 fn default_generate_count() -> u8 {
@@ -110,6 +160,7 @@ fn default_generate_count() -> u8 {
 
 /// Response DTO for generation batches
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct GenerationBatchResponseDto {
     pub id: String,
@@ -133,6 +184,7 @@ pub struct GenerationBatchResponseDto {
 
 /// Request DTO for selecting assets from a batch
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct SelectFromBatchRequestDto {
     pub selected_assets: Vec<String>,
@@ -148,6 +200,7 @@ pub struct SelectFromBatchRequestDto {
 
 /// Query parameters DTO for export endpoints
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ExportQueryDto {
     #[serde(default)]
@@ -158,10 +211,11 @@ pub struct ExportQueryDto {
 // Workflow DTOs (REST API)
 // =============================================================================
 
-use wrldbldr_domain::types::{PromptMapping, PromptMappingType};
+use wrldbldr_domain::types::{PromptMapping, PromptMappingType, ReferenceImageMapping};
 
 /// DTO for prompt mapping configuration
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PromptMappingDto {
     pub node_id: String,
@@ -189,8 +243,36 @@ impl From<PromptMappingDto> for PromptMapping {
     }
 }
 
+/// DTO for a reference image input mapping
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceImageMappingDto {
+    pub node_id: String,
+    pub input_name: String,
+}
+
+impl From<ReferenceImageMapping> for ReferenceImageMappingDto {
+    fn from(value: ReferenceImageMapping) -> Self {
+        Self {
+            node_id: value.node_id,
+            input_name: value.input_name,
+        }
+    }
+}
+
+impl From<ReferenceImageMappingDto> for ReferenceImageMapping {
+    fn from(value: ReferenceImageMappingDto) -> Self {
+        Self {
+            node_id: value.node_id,
+            input_name: value.input_name,
+        }
+    }
+}
+
 /// DTO for prompt mapping type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum PromptMappingTypeDto {
     Primary,
@@ -223,6 +305,7 @@ impl From<PromptMappingTypeDto> for PromptMappingType {
 
 /// Response DTO for workflow configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowConfigResponseDto {
     pub id: String,
@@ -234,6 +317,9 @@ pub struct WorkflowConfigResponseDto {
     pub prompt_mappings: Vec<PromptMappingDto>,
     pub has_primary_prompt: bool,
     pub has_negative_prompt: bool,
+    /// Whether generations can be conditioned on a reference image
+    #[serde(default)]
+    pub has_reference_image: bool,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -246,6 +332,7 @@ use wrldbldr_domain::types::{RuleSystemConfig, RuleSystemType, RuleSystemVariant
 
 /// Summary of a preset for browsing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct RuleSystemPresetSummaryDto {
     pub variant: RuleSystemVariant,
@@ -255,6 +342,7 @@ pub struct RuleSystemPresetSummaryDto {
 
 /// Summary of a rule system type for browsing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct RuleSystemSummaryDto {
     pub system_type: RuleSystemType,
@@ -266,6 +354,7 @@ pub struct RuleSystemSummaryDto {
 
 /// Full preset details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct RuleSystemPresetDetailsDto {
     pub variant: RuleSystemVariant,
@@ -274,6 +363,7 @@ pub struct RuleSystemPresetDetailsDto {
 
 /// Details about a rule system type.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct RuleSystemTypeDetailsDto {
     pub system_type: RuleSystemType,
@@ -329,6 +419,7 @@ use wrldbldr_domain::types::{InputDefault, InputType, WorkflowInput, WorkflowSlo
 
 /// Response for workflow slot status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowSlotStatusDto {
     pub slot: String,
@@ -341,6 +432,7 @@ pub struct WorkflowSlotStatusDto {
 
 /// A category of workflow slots (e.g., "Character Assets").
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowSlotCategoryDto {
     pub name: String,
@@ -349,6 +441,7 @@ pub struct WorkflowSlotCategoryDto {
 
 /// Response containing all workflow slots grouped by category.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowSlotsResponseDto {
     pub categories: Vec<WorkflowSlotCategoryDto>,
@@ -356,6 +449,7 @@ pub struct WorkflowSlotsResponseDto {
 
 /// DTO for input default values
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct InputDefaultDto {
     pub node_id: String,
@@ -385,6 +479,7 @@ impl From<InputDefaultDto> for InputDefault {
 
 /// DTO for input type classification
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum InputTypeDto {
     Text,
@@ -425,6 +520,7 @@ impl From<InputTypeDto> for InputType {
 
 /// DTO for workflow input information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowInputDto {
     pub node_id: String,
@@ -450,6 +546,7 @@ impl From<WorkflowInput> for WorkflowInputDto {
 
 /// DTO for workflow analysis results
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowAnalysisDto {
     pub node_count: usize,
@@ -479,6 +576,7 @@ impl From<WorkflowAnalysis> for WorkflowAnalysisDto {
 
 /// Full configuration response (includes workflow JSON).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowConfigFullResponseDto {
     pub id: String,
@@ -487,6 +585,8 @@ pub struct WorkflowConfigFullResponseDto {
     pub name: String,
     pub workflow_json: serde_json::Value,
     pub prompt_mappings: Vec<PromptMappingDto>,
+    #[serde(default)]
+    pub reference_image_mappings: Vec<ReferenceImageMappingDto>,
     pub input_defaults: Vec<InputDefaultDto>,
     pub locked_inputs: Vec<String>,
     pub analysis: WorkflowAnalysisDto,
@@ -496,15 +596,19 @@ pub struct WorkflowConfigFullResponseDto {
 
 /// Response for workflow analysis endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowAnalysisResponseDto {
     pub is_valid: bool,
     pub analysis: WorkflowAnalysisDto,
     pub suggested_prompt_mappings: Vec<PromptMappingDto>,
+    #[serde(default)]
+    pub suggested_reference_image_mappings: Vec<ReferenceImageMappingDto>,
 }
 
 /// Response for workflow import operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ImportWorkflowsResponseDto {
     pub imported: usize,
@@ -513,6 +617,7 @@ pub struct ImportWorkflowsResponseDto {
 
 /// Response for workflow test operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TestWorkflowResponseDto {
     pub prompt_id: String,
@@ -521,6 +626,7 @@ pub struct TestWorkflowResponseDto {
 
 /// Request to create/update a workflow configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CreateWorkflowConfigRequestDto {
     pub name: String,
@@ -528,6 +634,8 @@ pub struct CreateWorkflowConfigRequestDto {
     #[serde(default)]
     pub prompt_mappings: Vec<PromptMappingDto>,
     #[serde(default)]
+    pub reference_image_mappings: Vec<ReferenceImageMappingDto>,
+    #[serde(default)]
     pub input_defaults: Vec<InputDefaultDto>,
     #[serde(default)]
     pub locked_inputs: Vec<String>,
@@ -535,6 +643,7 @@ pub struct CreateWorkflowConfigRequestDto {
 
 /// Request to update just the defaults of a workflow (without re-uploading the workflow JSON).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateWorkflowDefaultsRequestDto {
     #[serde(default)]
@@ -545,6 +654,7 @@ pub struct UpdateWorkflowDefaultsRequestDto {
 
 /// Request to analyze a workflow (without saving).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AnalyzeWorkflowRequestDto {
     pub workflow_json: serde_json::Value,
@@ -552,6 +662,7 @@ pub struct AnalyzeWorkflowRequestDto {
 
 /// Import workflow configurations request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ImportWorkflowsRequestDto {
     pub data: serde_json::Value,
@@ -561,6 +672,7 @@ pub struct ImportWorkflowsRequestDto {
 
 /// Test a workflow configuration request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TestWorkflowRequestDto {
     pub prompt: String,
@@ -579,6 +691,7 @@ pub fn parse_workflow_slot(slot: &str) -> Result<WorkflowSlot, String> {
 
 /// Export/import DTO for workflow configurations (backup/restore).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct WorkflowConfigExportDto {
     pub id: String,
     pub slot: String,
@@ -587,6 +700,8 @@ pub struct WorkflowConfigExportDto {
     #[serde(default)]
     pub prompt_mappings: Vec<PromptMappingDto>,
     #[serde(default)]
+    pub reference_image_mappings: Vec<ReferenceImageMappingDto>,
+    #[serde(default)]
     pub input_defaults: Vec<InputDefaultDto>,
     #[serde(default)]
     pub locked_inputs: Vec<String>,
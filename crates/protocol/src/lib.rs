@@ -41,6 +41,8 @@ pub use messages::{
     ClientMessage,
     CreateGoalData,
     CreateWantData,
+    // Crowds
+    CrowdPresenceData,
     DialogueChoice,
     DiceInputType,
     // Session types
@@ -120,40 +122,239 @@ pub use types::{
     // Activation rules
     ActivationLogicData,
     ActivationRuleData,
+    // Chronology
+    AgeProgressionData,
     // Approval types
     ApprovalDecision,
+    ApprovalSelection,
+    // World theme
+    BackdropFrameData,
+    BulkApprovalDecision,
     // Character archetypes
     CampbellArchetype,
+    ChallengeHistoryData,
+    ChallengeHistoryStatsData,
+    ChallengeResolutionData,
     ChallengeSuggestionInfo,
     ChallengeSuggestionOutcomes,
+    CoDmReviewData,
+    PcSuccessChanceInfo,
+    CharacterAgeData,
+    ChronologyIssueData,
+    ChronologyIssueKindData,
+    ChronologyReportData,
+    // Progress clocks
+    ClockKindData,
+    // Content drafts
+    ContentDraftData,
+    // Content safety
+    ContentRatingData,
+    ContentSafetyData,
+    // Entity templates
+    CreatedEntityData,
+    // Crowds
+    CrowdData,
+    CrowdInputData,
+    // Factions
+    FactionData,
+    FactionInputData,
+    FactionMemberData,
+    FactionMemberInputData,
+    // Dialogue trees
+    DialogueBranchData,
+    DialogueNodeData,
+    DialogueTreeData,
+    DialogueTreeInputData,
+    // Flashbacks
+    FlashbackData,
+    StartFlashbackData,
+    // Parallel scenes
+    ParallelSceneData,
+    ParallelSceneInputData,
+    // Spotlight
+    SpotlightData,
+    SpotlightPcData,
+    SpotlightSettingsData,
+    // Pacing timers
+    PacingCueData,
+    PacingTimerData,
+    PacingTimerKindData,
+    StartPacingTimerData,
+    // Voice input
+    TranscriptionData,
+    // Custom fields
+    CustomFieldDefinitionData,
+    CustomFieldEntryData,
+    CustomFieldTypeData,
+    CustomFieldValueData,
+    SafetySignalLevelData,
+    // Dice
+    DiceRollData,
+    DiffOpData,
+    DiffSegmentData,
+    DraftDiffData,
+    DraftFieldData,
+    DraftRevisionData,
+    DraftStatusData,
+    // Entity mentions
+    EntityMentionData,
+    EntityTemplateData,
+    // Gallery
+    GalleryAssetData,
+    GalleryFilterData,
     // Game time
     GameTime,
     GameTimeConfig,
+    // Region hotspots
+    HotspotData,
+    HotspotPointData,
+    HotspotTargetData,
+    ItemTemplateData,
+    // Content library
+    LibraryEntryData,
+    LifeStageData,
+    // Region lighting
+    LightLevelData,
     // Location/Region states
     LocationStateData,
     // Lore types
     LoreCategoryData,
     LoreChunkData,
     LoreData,
+    LoreDateData,
     LoreDiscoverySourceData,
     LoreKnowledgeData,
     LoreSummaryData,
+    SharedKnowledgeData,
+    // Map markers
+    MapMarkerData,
+    MarkerLinkData,
+    MarkerPinData,
+    MentionTargetData,
     // Monomyth stages
     MonomythStage,
+    // Economy
+    EconomyOverviewData,
+    MarketCategoryData,
+    MarketItemData,
+    MarketModifierData,
+    MarketModifierInputData,
+    ScarcityData,
+    // Properties
+    PropertyData,
+    PropertyInputData,
+    PropertyStaffData,
+    PropertyStaffInputData,
+    StaffRoleData,
+    UpkeepPaymentData,
+    // Companions
+    CompanionData,
+    CompanionInputData,
+    WagePaymentData,
+    // Injuries
+    InjuryCapabilityData,
+    InjuryData,
+    InjuryInputData,
+    InjurySeverityData,
+    // Location hierarchy
+    LocationBreadcrumbData,
+    LocationScaleData,
+    LocationTravelData,
+    SubLocationData,
+    // World map
+    WorldMapData,
+    WorldMapPinData,
+    WorldMapPositionData,
+    // Journeys
+    EncounterEntryData,
+    EncounterTableData,
+    JourneyData,
+    JourneyDecision,
+    JourneyEncounterData,
+    // Locks
+    ConnectionLockData,
+    LockedWayData,
+    UnlockMethodData,
+    UnlockedConnectionData,
+    // Rule system switch
+    ChallengeSkillRemapData,
+    RuleSystemSwitchData,
+    SheetConversionData,
+    SkillOverrideData,
+    // Noise
+    LoudnessData,
+    NoiseHeardInData,
+    SoundCarryData,
+    // PC mortality
+    PcDeathData,
+    PcDeathInputData,
+    SuccessorData,
+    SuccessorInputData,
+    // PC secrets
+    PcSecretData,
+    PcSecretInputData,
+    SecretReactionData,
+    // Broadsheets
+    BroadsheetArticleData,
+    BroadsheetData,
+    BroadsheetInputData,
+    // World simulation
+    SimulationChangeData,
+    WorldSimulationData,
+    // Session zero
+    NpcSeedData,
+    SessionZeroData,
+    StartingLocationData,
+    // Letters
+    CorrespondentData,
+    CorrespondentKindData,
+    LetterData,
+    LetterInputData,
+    // Name generators
+    GeneratedNamesData,
+    NameGeneratorData,
+    NameGeneratorInputData,
+    NameStyleData,
     NarrativeEventSuggestionInfo,
+    // NPC drafts
+    NpcDraftData,
+    NpcDraftInputData,
+    NpcDraftSourceData,
+    NpcDraftStatusData,
+    NpcTemplateData,
     // Participant roles
     ParticipantRole,
+    ProgressClockData,
     ProposedToolInfo,
+    // Region maps
+    RegionMapKindData,
     RegionStateData,
+    RegionTemplateData,
     ResolvedStateInfoData,
     ResolvedVisualStateData,
+    RevisionSourceData,
+    // Tags
+    SavedFilterData,
+    // World scripts
+    ScriptHookData,
     StateOptionData,
+    TagUsageData,
+    TemplateBodyData,
+    TemplateNpcData,
+    TemplateNpcRoleData,
     TimeCostConfig,
     TimeFormat,
     TimeMode,
     TimeOfDayData,
     TimeSuggestionData,
     TimeSuggestionDecision,
+    // Typography
+    TextDirectionData,
+    // Trades
+    TradeClosedReason,
+    TradeItemData,
+    TradeOfferData,
+    TradeSideData,
     // Trigger schema types (for Visual Trigger Builder)
     TriggerCategory,
     TriggerFieldSchema,
@@ -161,7 +362,15 @@ pub use types::{
     TriggerLogicOption,
     TriggerSchema,
     TriggerTypeSchema,
+    // Tutorial
+    TutorialStatusData,
+    TutorialStepData,
     VisualStateSourceData,
+    // World features
+    WorldFeaturesData,
+    WorldScriptData,
+    WorldThemeData,
+    WorldTypographyData,
 };
 
 // =============================================================================
@@ -180,6 +389,7 @@ pub use dto::{
     // Export DTOs
     ExportQueryDto,
     // Asset DTOs
+    AssetAssociationSuggestionDto,
     GalleryAssetResponseDto,
     GenerateAssetRequestDto,
     GenerationBatchResponseDto,
@@ -191,6 +401,7 @@ pub use dto::{
     NpcDispositionStateDto,
     PromptMappingDto,
     PromptMappingTypeDto,
+    ReferenceImageMappingDto,
     // Rule System DTOs
     RuleSystemPresetDetailsDto,
     RuleSystemPresetSummaryDto,
@@ -202,6 +413,7 @@ pub use dto::{
     UpdateAssetLabelRequestDto,
     UpdateWorkflowDefaultsRequestDto,
     UploadAssetRequestDto,
+    UploadAssetResponseDto,
     WorkflowAnalysisDto,
     WorkflowAnalysisResponseDto,
     WorkflowConfigExportDto,
@@ -220,29 +432,58 @@ pub use requests::{
     act::ActRequest,
     actantial::ActantialRequest,
     ai::AiRequest,
+    broadsheet::BroadsheetRequest,
     challenge::ChallengeRequest,
     character::CharacterRequest,
     character_sheet::{CharacterSheetRequest, FieldUpdateData, GameSystemInfo},
+    chronology::ChronologyRequest,
+    clock::ClockRequest,
+    companion::CompanionRequest,
+    crowd::CrowdRequest,
+    faction::FactionRequest,
+    dialogue_tree::DialogueTreeRequest,
+    flashback::FlashbackRequest,
+    parallel_scene::ParallelSceneRequest,
+    spotlight::SpotlightRequest,
+    pacing::PacingRequest,
+    dice::DiceRequest,
+    draft::DraftRequest,
     event_chain::EventChainRequest,
+    economy::EconomyRequest,
     expression::ExpressionRequest,
+    gallery::GalleryRequest,
     generation::GenerationRequest,
     goal::GoalRequest,
+    injury::InjuryRequest,
     interaction::InteractionRequest,
     items::ItemsRequest,
+    library::LibraryRequest,
     location::LocationRequest,
+    mail::MailRequest,
     lore::LoreRequest,
+    mention::MentionRequest,
+    mortality::MortalityRequest,
+    name_generator::NameGeneratorRequest,
     narrative_event::NarrativeEventRequest,
     npc::NpcRequest,
+    npc_draft::NpcDraftRequest,
     observation::ObservationRequest,
     player_character::PlayerCharacterRequest,
+    property::PropertyRequest,
     region::RegionRequest,
     relationship::RelationshipRequest,
     scene::SceneRequest,
+    secret::SecretRequest,
+    simulation::SimulationRequest,
     skill::SkillRequest,
     stat::AddModifierData,
     stat::StatRequest,
     story_event::StoryEventRequest,
+    tag::TagRequest,
+    template::TemplateRequest,
     time::TimeRequest,
+    transcription::TranscriptionRequest,
+    tutorial::TutorialRequest,
     want::WantRequest,
     world::WorldRequest,
     // Create data types
@@ -267,6 +508,8 @@ pub use requests::{
     CreateWorldData,
     // Main payload enum
     RequestPayload,
+    SaveFilterData,
+    SaveTemplateData,
     // Suggestion types
     SuggestionContextData,
     // Update data types
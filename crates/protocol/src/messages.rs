@@ -17,8 +17,8 @@ use uuid::Uuid;
 use crate::requests::RequestPayload;
 use crate::responses::{ConnectedUser, EntityChangedData, JoinError, ResponseResult, WorldRole};
 use crate::types::{
-    ApprovalDecision, ChallengeSuggestionInfo, NarrativeEventSuggestionInfo, ParticipantRole,
-    ProposedToolInfo,
+    ApprovalDecision, ApprovalSelection, BulkApprovalDecision, ChallengeSuggestionInfo,
+    NarrativeEventSuggestionInfo, ParticipantRole, ProposedToolInfo,
 };
 
 fn default_true() -> bool {
@@ -35,6 +35,7 @@ fn default_one() -> u32 {
 
 /// Messages from client (Player) to server (Engine)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum ClientMessage {
     /// Player performs an action
@@ -42,7 +43,12 @@ pub enum ClientMessage {
         action_type: String,
         target: Option<String>,
         dialogue: Option<String>,
+        /// The `DialogueChoice` picked, for `dialogue_choice` actions
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        choice_id: Option<String>,
     },
+    /// Take back a queued action the NPC hasn't answered yet
+    RetractAction { action_id: String },
     /// Start a conversation with an NPC
     StartConversation { npc_id: String, message: String },
     /// Continue an existing conversation with an NPC
@@ -64,6 +70,11 @@ pub enum ClientMessage {
         request_id: String,
         decision: ApprovalDecision,
     },
+    /// DM accepts or rejects a group of pending approvals at once
+    BulkApprovalDecision {
+        selection: ApprovalSelection,
+        decision: BulkApprovalDecision,
+    },
     /// Player submits a challenge roll (legacy - accepts raw roll value)
     ChallengeRoll { challenge_id: String, roll: i32 },
     /// Player submits a challenge roll with dice input (formula or manual)
@@ -72,6 +83,10 @@ pub enum ClientMessage {
         /// Dice input - either "formula" with dice string, or "manual" with result
         input_type: DiceInputType,
     },
+    /// Roll a dice expression (e.g. "4d6kh3") outside of any challenge
+    ///
+    /// The roller gets `DiceRolled` back; the rest of their scene sees it too.
+    RollDice { formula: String },
     /// DM triggers a challenge manually
     TriggerChallenge {
         challenge_id: String,
@@ -94,7 +109,7 @@ pub enum ClientMessage {
     /// Heartbeat ping
     Heartbeat,
 
-    /// Request manual ComfyUI health check
+    /// Request a manual dependency health check (replies with ComfyUI's status)
     CheckComfyUIHealth,
 
     /// DM requests regeneration of challenge outcome(s)
@@ -166,6 +181,23 @@ pub enum ClientMessage {
         arrival_region_id: Option<String>,
     },
 
+    /// Player fast-travels to a location they know
+    FastTravel { pc_id: String, location_id: String },
+
+    /// Player tries a carried item on the lock of a way out of where their
+    /// PC stands
+    UnlockWithKey {
+        pc_id: String,
+        item_id: String,
+        to: crate::types::LockedWayData,
+    },
+
+    /// DM decides how a journey stopped by an encounter goes on
+    ResolveJourney {
+        journey_id: String,
+        decision: crate::types::JourneyDecision,
+    },
+
     /// DM triggers an NPC approach event (NPC approaches a player)
     TriggerApproachEvent {
         npc_id: String,
@@ -182,6 +214,28 @@ pub enum ClientMessage {
         description: String,
     },
 
+    /// Make a noise in a region that may carry into the regions next door
+    ///
+    /// Players can only make noise where their PC stands; the DM anywhere.
+    MakeNoise {
+        region_id: String,
+        description: String,
+        loudness: crate::types::LoudnessData,
+    },
+
+    /// DM shows a full-screen handout (map, letter, cutscene art) to the world
+    ShowOverlay {
+        asset: String,
+        #[serde(default)]
+        caption: Option<String>,
+        /// Seconds before the overlay closes itself (None = until dismissed)
+        #[serde(default)]
+        duration_secs: Option<u32>,
+    },
+
+    /// DM closes an overlay on every screen
+    DismissOverlay { overlay_id: String },
+
     // =========================================================================
     // Staging System (NPC Presence + Visual State Approval)
     // =========================================================================
@@ -246,6 +300,33 @@ pub enum ClientMessage {
     /// Player picks up an item from their current region
     PickupItem { pc_id: String, item_id: String },
 
+    /// Player offers a trade to another PC in the same region
+    OfferTrade {
+        from_pc_id: String,
+        to_pc_id: String,
+        #[serde(default)]
+        offered_item_ids: Vec<String>,
+        #[serde(default)]
+        offered_currency: u32,
+        #[serde(default)]
+        requested_item_ids: Vec<String>,
+        #[serde(default)]
+        requested_currency: u32,
+    },
+
+    /// Recipient accepts or declines a trade offer
+    RespondToTrade { trade_id: String, accept: bool },
+
+    /// Offering player withdraws a trade offer
+    CancelTrade { trade_id: String },
+
+    /// Player passes on something their PC knows to other PCs in the party
+    ShareKnowledge {
+        from_pc_id: String,
+        target_pc_ids: Vec<String>,
+        knowledge: crate::types::SharedKnowledgeData,
+    },
+
     // =========================================================================
     // Time Control (DM Only)
     // =========================================================================
@@ -287,6 +368,18 @@ pub enum ClientMessage {
         decision: crate::types::TimeSuggestionDecision,
     },
 
+    // =========================================================================
+    // Safety Tools
+    // =========================================================================
+    /// Player raises an anonymous safety signal (X-card)
+    /// The world comes from the connection; the sender is never revealed to the DM
+    SafetySignal {
+        level: crate::types::SafetySignalLevelData,
+    },
+
+    /// DM pauses or resumes the player action queue
+    SetActionQueuePaused { world_id: String, paused: bool },
+
     // =========================================================================
     // Lore System
     // =========================================================================
@@ -333,6 +426,27 @@ pub enum ClientMessage {
     /// Leave the current world
     LeaveWorld,
 
+    /// Take control of another PC as well (solo play or duet games)
+    ///
+    /// The PC must belong to this player; the active PC stays unchanged.
+    ControlPc {
+        /// PC to add to the controlled set
+        pc_id: Uuid,
+    },
+
+    /// Make one of the controlled PCs the active one
+    SwitchPc {
+        /// Controlled PC to act as
+        pc_id: Uuid,
+    },
+
+    /// Set the language for server-generated text on this connection
+    /// May be sent before joining a world
+    SetLocale {
+        /// BCP 47 language tag, e.g. "es" or "de-AT"
+        locale: String,
+    },
+
     /// Send a request (CRUD operations, actions)
     Request {
         /// Unique request ID for correlation
@@ -361,6 +475,7 @@ pub enum ClientMessage {
 
 /// Messages from server (Engine) to client (Player)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum ServerMessage {
     /// Player action was received and is being processed
@@ -410,7 +525,8 @@ pub enum ServerMessage {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         conversation_id: Option<String>,
     },
-    /// LLM is processing (shown to DM)
+    /// LLM is processing (shown to DM, and to players while a rejected
+    /// NPC response is regenerated)
     LLMProcessing { action_id: String },
     /// Action queued for processing
     ActionQueued {
@@ -419,6 +535,9 @@ pub enum ServerMessage {
         action_type: String,
         queue_depth: usize,
     },
+    /// A queued action was retracted before the NPC answered it
+    /// (sent to the player who retracted it and to DMs)
+    ActionRetracted { action_id: String },
     /// Queue status update (sent to DM)
     QueueStatus {
         player_actions_pending: usize,
@@ -435,6 +554,30 @@ pub enum ServerMessage {
         proposed_tools: Vec<ProposedToolInfo>,
         challenge_suggestion: Option<ChallengeSuggestionInfo>,
         narrative_event_suggestion: Option<NarrativeEventSuggestionInfo>,
+        /// Content safety warnings; flagged dialogue needs a closer DM look
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        safety_warnings: Vec<String>,
+        /// Conversation the response belongs to, for grouping
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        conversation_id: Option<String>,
+        /// Player action that raised the approval
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source_action_id: Option<String>,
+        /// Whether a player is waiting on the response; the rest is ambient
+        #[serde(default)]
+        awaiting_player: bool,
+        /// Parallel scene the response belongs to; none for the main table
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        scene_id: Option<String>,
+    },
+    /// A bulk decision was applied (sent to DMs)
+    BulkApprovalApplied {
+        /// Approvals the decision was applied to
+        decided: Vec<String>,
+        /// Approvals the decision could not be applied to
+        #[serde(default)]
+        failed: Vec<String>,
+        accepted: bool,
     },
     /// Response was approved and executed
     ResponseApproved {
@@ -522,6 +665,34 @@ pub enum ServerMessage {
         message: Option<String>,
         retry_in_seconds: Option<u32>,
     },
+    /// An external dependency's health changed (sent to DMs)
+    DependencyStatusChanged {
+        /// "neo4j", "ollama", "comfyui", or "disk"
+        dependency: String,
+        healthy: bool,
+        /// Why the dependency is unhealthy
+        message: Option<String>,
+    },
+    /// Download progress for an LLM model (sent to DMs)
+    ModelPullProgress {
+        model: String,
+        /// Server-reported stage (e.g. "pulling manifest")
+        status: String,
+        completed_bytes: Option<u64>,
+        total_bytes: Option<u64>,
+        /// True once the pull has finished or failed
+        done: bool,
+        /// Why the pull failed
+        error: Option<String>,
+    },
+    /// A world went over one of its daily usage quotas (sent to DMs)
+    UsageQuotaWarning {
+        world_id: String,
+        /// "tokens" or "images"
+        resource: String,
+        used: u64,
+        quota: u64,
+    },
 
     /// Outcome has been regenerated (sent to DM)
     OutcomeRegenerated {
@@ -598,6 +769,39 @@ pub enum ServerMessage {
         description: String,
     },
 
+    /// A noise carried in from a neighbouring region (sent to PCs who hear it)
+    NoiseHeard {
+        /// Region the PC is in
+        region_id: String,
+        from_region_id: String,
+        from_region_name: String,
+        description: String,
+        muffled: bool,
+    },
+
+    /// Where a noise was made and who heard it (sent to DMs)
+    NoiseReport {
+        region_id: String,
+        region_name: String,
+        description: String,
+        loudness: crate::types::LoudnessData,
+        heard_in: Vec<crate::types::NoiseHeardInData>,
+    },
+
+    /// DM handout shown full screen (broadcast to all)
+    ShowOverlay {
+        overlay_id: String,
+        asset: String,
+        #[serde(default)]
+        caption: Option<String>,
+        /// Seconds before the overlay closes itself (None = until dismissed)
+        #[serde(default)]
+        duration_secs: Option<u32>,
+    },
+
+    /// DM closed a handout overlay for everyone
+    OverlayDismissed { overlay_id: String },
+
     /// NPC location was shared with the player (sent to target PC)
     NpcLocationShared {
         npc_id: String,
@@ -623,11 +827,28 @@ pub enum ServerMessage {
         /// Items visible in this region (can be picked up)
         #[serde(default)]
         region_items: Vec<RegionItemData>,
+        /// Background crowds staged in this region
+        #[serde(default)]
+        crowds_present: Vec<CrowdPresenceData>,
     },
 
     /// Movement was blocked (locked door, etc.)
     MovementBlocked { pc_id: String, reason: String },
 
+    /// A journey stopped for an encounter (sent to the player and DMs)
+    JourneyInterrupted { journey: crate::types::JourneyData },
+
+    /// The DM settled a stopped journey (sent to the player and DMs)
+    JourneyResolved {
+        journey: crate::types::JourneyData,
+        decision: crate::types::JourneyDecision,
+    },
+
+    /// A PC opened a locked connection (broadcast to the world)
+    ConnectionUnlocked {
+        connection: crate::types::UnlockedConnectionData,
+    },
+
     /// Game time has been updated (broadcast to all)
     /// Legacy - use GameTimeAdvanced for richer information
     GameTimeUpdated { game_time: crate::types::GameTime },
@@ -657,6 +878,85 @@ pub enum ServerMessage {
         config: crate::types::GameTimeConfig,
     },
 
+    /// World typography (direction, language, font) changed (broadcast to all)
+    WorldTypographyUpdated {
+        world_id: String,
+        typography: crate::types::WorldTypographyData,
+    },
+
+    /// World theme (accent color, backdrop frame) changed (broadcast to all)
+    WorldThemeUpdated {
+        world_id: String,
+        theme: crate::types::WorldThemeData,
+    },
+
+    /// The world map image or a location's pin changed (broadcast to all)
+    WorldMapUpdated {
+        world_id: String,
+        map: crate::types::WorldMapData,
+    },
+
+    /// Experimental feature switches changed (broadcast to all)
+    WorldFeaturesUpdated {
+        world_id: String,
+        features: crate::types::WorldFeaturesData,
+    },
+
+    /// Automation scripts changed (sent to DMs)
+    WorldScriptsUpdated {
+        world_id: String,
+        scripts: Vec<crate::types::WorldScriptData>,
+    },
+
+    /// Custom field schema changed (sent to DMs)
+    CustomFieldsUpdated {
+        world_id: String,
+        fields: Vec<crate::types::CustomFieldDefinitionData>,
+    },
+
+    /// A script sent a message or failed (sent to DMs)
+    ScriptNotice {
+        world_id: String,
+        script: String,
+        message: String,
+        #[serde(default)]
+        is_error: bool,
+    },
+
+    // =========================================================================
+    // Tags
+    // =========================================================================
+    /// An entity's tags were replaced (sent to DMs)
+    EntityTagsUpdated {
+        world_id: String,
+        entity_type: crate::responses::EntityType,
+        entity_id: String,
+        tags: Vec<String>,
+    },
+
+    /// A tag was renamed or deleted across the world (sent to DMs)
+    WorldTagsUpdated {
+        world_id: String,
+        tags: Vec<crate::types::TagUsageData>,
+    },
+
+    // =========================================================================
+    // Safety Tools
+    // =========================================================================
+    /// A player raised an anonymous safety signal (sent to DMs)
+    SafetySignalRaised {
+        world_id: String,
+        level: crate::types::SafetySignalLevelData,
+        /// Whether the signal paused the action queue
+        queue_paused: bool,
+        /// Pending NPC dialogue that was auto-rejected
+        #[serde(default)]
+        rejected_request_ids: Vec<String>,
+    },
+
+    /// Player action queue has been paused/resumed (broadcast to all)
+    ActionQueuePaused { world_id: String, paused: bool },
+
     // =========================================================================
     // Staging System (NPC Presence + Visual State Approval)
     // =========================================================================
@@ -762,6 +1062,20 @@ pub enum ServerMessage {
     /// Inventory was updated (signals client to refresh)
     InventoryUpdated { pc_id: String },
 
+    /// A trade was offered (sent to both PCs and DMs)
+    TradeOffered { trade: crate::types::TradeOfferData },
+
+    /// A trade went through (sent to both PCs and DMs)
+    TradeCompleted { trade: crate::types::TradeOfferData },
+
+    /// A trade offer closed without completing (sent to both PCs and DMs)
+    TradeClosed {
+        trade_id: String,
+        reason: crate::types::TradeClosedReason,
+        #[serde(default)]
+        message: Option<String>,
+    },
+
     // =========================================================================
     // Character Stat Updates
     // =========================================================================
@@ -829,6 +1143,17 @@ pub enum ServerMessage {
         discovery_source: crate::types::LoreDiscoverySourceData,
     },
 
+    /// A PC shared what they know with party members
+    /// (sent to the sharing PC, the recipients, and DMs)
+    KnowledgeShared {
+        from_pc_id: String,
+        from_pc_name: String,
+        /// PCs whose knowledge changed (those who already knew are left out)
+        to_pc_ids: Vec<String>,
+        /// What was shared, e.g. the lore title
+        title: String,
+    },
+
     /// Lore was revoked from a character (sent to player owning the character)
     LoreRevoked {
         character_id: String,
@@ -975,6 +1300,14 @@ pub enum ServerMessage {
         user_id: String,
     },
 
+    /// Connection locale was set (response to SetLocale)
+    LocaleChanged {
+        /// Locale actually used (falls back to "en" if unsupported)
+        locale: String,
+        /// Locales the server has string catalogs for
+        supported_locales: Vec<String>,
+    },
+
     /// Response to a Request message
     Response {
         /// Correlated request ID
@@ -986,6 +1319,14 @@ pub enum ServerMessage {
     /// Entity changed broadcast (for cache invalidation)
     EntityChanged(EntityChangedData),
 
+    /// The set of PCs this connection controls changed
+    ControlledPcsChanged {
+        /// PC that player actions are taken as
+        active_pc_id: Uuid,
+        /// Every controlled PC, in the same shape as `WorldJoined::your_pc`
+        pcs: Vec<serde_json::Value>,
+    },
+
     /// Spectate target changed (for Spectator role)
     SpectateTargetChanged {
         /// New PC being spectated
@@ -994,6 +1335,165 @@ pub enum ServerMessage {
         pc_name: String,
     },
 
+    /// A progress clock was created, changed, or ticked.
+    ///
+    /// Sent to the whole world for player-visible clocks, DMs only otherwise.
+    ClockUpdated {
+        clock: crate::types::ProgressClockData,
+    },
+
+    /// A progress clock was deleted
+    ClockRemoved { clock_id: String },
+
+    /// Someone in the world rolled dice from the dice tray
+    DiceRolled { roll: crate::types::DiceRollData },
+
+    /// A DM marker was pinned, moved, or had its visibility changed.
+    ///
+    /// Sent to the whole world for player-visible markers, DMs only otherwise.
+    MapMarkerUpdated { marker: crate::types::MapMarkerData },
+
+    /// A DM marker was unpinned or hidden from the recipient
+    MapMarkerRemoved { marker_id: String },
+
+    /// A region's backdrop hotspots were replaced
+    ///
+    /// Sent to the whole world so open backdrops pick up the change.
+    RegionHotspotsUpdated {
+        region_id: String,
+        hotspots: Vec<crate::types::HotspotData>,
+    },
+
+    /// A region's lighting was changed by the DM
+    ///
+    /// Carries the level in effect now, so open scenes can update.
+    RegionLightChanged {
+        region_id: String,
+        light_level: crate::types::LightLevelData,
+    },
+
+    /// A region map generation finished
+    ///
+    /// On success `map_asset` is the new map, sent to the whole world. On
+    /// failure `error` is set and only DMs are told.
+    RegionMapGenerated {
+        region_id: String,
+        #[serde(default)]
+        map_asset: Option<String>,
+        #[serde(default)]
+        error: Option<String>,
+    },
+
+    /// A PC finished a tutorial step (sent to that PC's player)
+    TutorialProgress {
+        status: crate::types::TutorialStatusData,
+    },
+
+    /// Characters aged during a long time skip (DMs only, when the world's
+    /// age progression feature is on)
+    CharactersAged {
+        world_id: String,
+        progressions: Vec<crate::types::AgeProgressionData>,
+    },
+
+    /// Injuries healed as game time advanced (DMs only)
+    InjuriesHealed {
+        world_id: String,
+        injuries: Vec<crate::types::InjuryData>,
+    },
+
+    /// A PC died (broadcast to the world)
+    PcDied {
+        world_id: String,
+        death: crate::types::PcDeathData,
+    },
+
+    /// A dead PC was brought back (broadcast to the world)
+    PcResurrected {
+        world_id: String,
+        pc_id: String,
+        name: String,
+    },
+
+    /// One of the player's secrets was created or changed (the PC's player
+    /// only)
+    PcSecretUpdated { secret: crate::types::PcSecretData },
+
+    /// One of the player's secrets was deleted (the PC's player only)
+    PcSecretRemoved { pc_id: String, secret_id: String },
+
+    /// The DM revealed a PC's secret (broadcast to the world)
+    PcSecretRevealed {
+        world_id: String,
+        pc_name: String,
+        secret: crate::types::PcSecretData,
+    },
+
+    /// A letter was sent, delivered or read, or a reply was written (the
+    /// DMs, the writer's player and, once delivered, the addressee's player)
+    LetterUpdated { letter: crate::types::LetterData },
+
+    /// The DM removed a letter (the same audience as LetterUpdated)
+    LetterRemoved { letter_id: String },
+
+    /// A broadsheet draft was written or edited (DMs only)
+    BroadsheetDrafted {
+        broadsheet: crate::types::BroadsheetData,
+    },
+
+    /// The DM published a broadsheet (broadcast to the world as a handout)
+    BroadsheetPublished {
+        broadsheet: crate::types::BroadsheetData,
+    },
+
+    /// The DM deleted a broadsheet (DMs only while a draft, else the world)
+    BroadsheetRemoved { broadsheet_id: String },
+
+    /// A time skip was simulated and awaits the DM's review (DMs only)
+    WorldSimulationProposed {
+        simulation: crate::types::WorldSimulationData,
+    },
+
+    /// The DM accepted or discarded a simulation (DMs only)
+    WorldSimulationResolved {
+        simulation_id: String,
+        accepted: bool,
+    },
+
+    /// The crowds staged in a region changed (broadcast to the world;
+    /// players in other regions ignore it)
+    RegionCrowdsChanged {
+        region_id: String,
+        crowds_present: Vec<CrowdPresenceData>,
+    },
+
+    /// An NPC draft finished generating, successfully or not (DMs only)
+    NpcDraftUpdated { draft: crate::types::NpcDraftData },
+
+    /// A flashback started, or ended with `None` (broadcast to the world)
+    FlashbackChanged {
+        flashback: Option<crate::types::FlashbackData>,
+    },
+
+    /// The party split, regrouped or merged, or the DM switched scenes
+    /// (broadcast to the world; players get scene notes and tone blanked)
+    ParallelScenesChanged {
+        scenes: Vec<crate::types::ParallelSceneData>,
+    },
+
+    /// Someone acted, or the DM moved the spotlight or changed its settings
+    /// (broadcast to the world; players get an empty rotation)
+    SpotlightChanged {
+        spotlight: crate::types::SpotlightData,
+    },
+
+    /// The DM started, paused, extended or stopped the pacing timer, or it
+    /// crossed a minute, its warning or its end (broadcast to the world;
+    /// `None` when stopped)
+    PacingTimerChanged {
+        timer: Option<crate::types::PacingTimerData>,
+    },
+
     /// Unknown message type for forward compatibility
     ///
     /// When deserializing an unknown variant, this variant is used instead of
@@ -1008,6 +1508,7 @@ pub enum ServerMessage {
 
 /// Information about a session participant
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ParticipantInfo {
     pub user_id: String,
     pub role: ParticipantRole,
@@ -1016,6 +1517,7 @@ pub struct ParticipantInfo {
 
 /// Location information for split party notification
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SplitPartyLocation {
     pub location_id: String,
     pub location_name: String,
@@ -1025,6 +1527,7 @@ pub struct SplitPartyLocation {
 
 /// Directorial context from DM
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DirectorialContext {
     pub scene_notes: String,
     pub tone: String,
@@ -1037,6 +1540,7 @@ pub struct DirectorialContext {
 /// Note: `emotional_guidance` is a free-form string for DM guidance,
 /// not the same as DispositionLevel or MoodState enums.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct NpcMotivationData {
     pub character_id: String,
     /// Free-form emotional guidance for the NPC (e.g., "Conflicted about revealing secrets")
@@ -1052,6 +1556,7 @@ pub struct NpcMotivationData {
 
 /// Scene data from server
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SceneData {
     pub id: String,
     pub name: String,
@@ -1064,6 +1569,7 @@ pub struct SceneData {
 
 /// Character data for display
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CharacterData {
     pub id: String,
     pub name: String,
@@ -1083,6 +1589,7 @@ pub struct CharacterData {
 
 /// Character position on screen
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CharacterPosition {
     Left,
     Center,
@@ -1095,6 +1602,7 @@ pub enum CharacterPosition {
 
 /// Available interaction
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct InteractionData {
     pub id: String,
     pub name: String,
@@ -1105,10 +1613,17 @@ pub struct InteractionData {
     #[serde(default)]
     pub target_type: Option<String>,
     pub is_available: bool,
+    /// Why the PC can't use it right now (cooldown, no uses left)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unavailable_reason: Option<String>,
+    /// Uses the PC has left, if limited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uses_left: Option<u32>,
 }
 
 /// Dialogue choice for player
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DialogueChoice {
     pub id: String,
     pub text: String,
@@ -1121,6 +1636,7 @@ pub struct DialogueChoice {
 
 /// Region data for scene display
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RegionData {
     pub id: String,
     pub name: String,
@@ -1131,11 +1647,18 @@ pub struct RegionData {
     /// Location's top-down map image for mini-map display
     #[serde(default)]
     pub map_asset: Option<String>,
+    /// Clickable areas on the backdrop
+    #[serde(default)]
+    pub hotspots: Vec<crate::types::HotspotData>,
+    /// How well lit the region is at the current game time
+    #[serde(default)]
+    pub light_level: Option<crate::types::LightLevelData>,
 }
 
 /// Region list item data (returned by ListRegions request)
 /// Includes map bounds for mini-map display
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RegionListItemData {
     pub id: String,
     pub location_id: String,
@@ -1143,6 +1666,9 @@ pub struct RegionListItemData {
     pub description: String,
     pub backdrop_asset: Option<String>,
     pub atmosphere: Option<String>,
+    /// Generated top-down map of the region
+    #[serde(default)]
+    pub map_asset: Option<String>,
     /// Map bounds for positioning on location mini-map
     #[serde(default)]
     pub map_bounds: Option<MapBoundsData>,
@@ -1152,10 +1678,14 @@ pub struct RegionListItemData {
     /// Display order within location
     #[serde(default)]
     pub order: u32,
+    /// Fixed lighting; absent when the region follows its location
+    #[serde(default)]
+    pub lighting: Option<crate::types::LightLevelData>,
 }
 
 /// Map bounds for region positioning on location map
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MapBoundsData {
     pub x: u32,
     pub y: u32,
@@ -1165,6 +1695,7 @@ pub struct MapBoundsData {
 
 /// NPC presence data for scene display
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct NpcPresenceData {
     pub character_id: String,
     pub name: String,
@@ -1172,8 +1703,22 @@ pub struct NpcPresenceData {
     pub portrait_asset: Option<String>,
 }
 
+/// A crowd in the scene, as players see it: no exact headcount
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CrowdPresenceData {
+    pub crowd_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// The headcount in words ("dozens")
+    pub size_label: String,
+    pub disposition: wrldbldr_domain::types::DispositionLevel,
+}
+
 /// Navigation options from current region
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct NavigationData {
     pub connected_regions: Vec<NavigationTarget>,
     pub exits: Vec<NavigationExit>,
@@ -1181,6 +1726,7 @@ pub struct NavigationData {
 
 /// A navigation target (region within same location)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct NavigationTarget {
     pub region_id: String,
     pub name: String,
@@ -1190,6 +1736,7 @@ pub struct NavigationTarget {
 
 /// An exit to another location
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct NavigationExit {
     pub location_id: String,
     pub location_name: String,
@@ -1199,6 +1746,7 @@ pub struct NavigationExit {
 
 /// Item data for region display (items visible in the current region)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RegionItemData {
     pub id: String,
     pub name: String,
@@ -1214,6 +1762,7 @@ pub struct RegionItemData {
 
 /// Dice input type for challenge rolls
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum DiceInputType {
     /// Roll dice using a formula string like "1d20+5"
@@ -1231,6 +1780,7 @@ pub use wrldbldr_domain::AdHocOutcomes;
 
 /// Outcome detail data for regenerated outcomes
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct OutcomeDetailData {
     pub flavor_text: String,
     pub scene_direction: String,
@@ -1240,6 +1790,7 @@ pub struct OutcomeDetailData {
 
 /// DM's decision on a challenge outcome (wire format)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "action", rename_all = "snake_case")]
 pub enum ChallengeOutcomeDecisionData {
     /// Accept the outcome as-is
@@ -1258,6 +1809,7 @@ pub enum ChallengeOutcomeDecisionData {
 
 /// Outcome branch data for DM selection
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct OutcomeBranchData {
     pub id: String,
     pub title: String,
@@ -1272,6 +1824,7 @@ pub struct OutcomeBranchData {
 
 /// Info about a staged NPC (for approval UI)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StagedNpcInfo {
     pub character_id: String,
     pub name: String,
@@ -1292,6 +1845,7 @@ pub struct StagedNpcInfo {
 
 /// Info about previous staging (for reference)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PreviousStagingInfo {
     pub staging_id: String,
     pub approved_at: String,
@@ -1300,6 +1854,7 @@ pub struct PreviousStagingInfo {
 
 /// Info about a PC waiting for staging
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct WaitingPcInfo {
     pub pc_id: String,
     pub pc_name: String,
@@ -1308,6 +1863,7 @@ pub struct WaitingPcInfo {
 
 /// NPC presence info for players (simplified, no reasoning)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct NpcPresentInfo {
     pub character_id: String,
     pub name: String,
@@ -1326,6 +1882,7 @@ pub struct NpcPresentInfo {
 
 /// DM's decision for an NPC in staging
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ApprovedNpcInfo {
     pub character_id: String,
     pub is_present: bool,
@@ -1347,6 +1904,7 @@ pub struct ApprovedNpcInfo {
 
 /// NPC disposition/relationship data for a specific PC
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct NpcDispositionData {
     pub npc_id: String,
     pub npc_name: String,
@@ -1478,6 +2036,7 @@ mod serde_tests {
 
 /// Want visibility level - how much the player knows
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum WantVisibilityData {
     /// Player knows this motivation openly
@@ -1494,6 +2053,7 @@ pub enum WantVisibilityData {
 
 /// Type discriminator for actors (NPC vs PC)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum ActorTypeData {
     Npc,
@@ -1505,6 +2065,7 @@ pub enum ActorTypeData {
 
 /// Actantial role type for character views
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum ActantialRoleData {
     Helper,
@@ -1518,6 +2079,7 @@ pub enum ActantialRoleData {
 
 /// Target type for wants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum WantTargetTypeData {
     Character,
@@ -1530,6 +2092,7 @@ pub enum WantTargetTypeData {
 
 /// Want data for wire transfer
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct WantData {
     pub id: String,
     pub description: String,
@@ -1555,6 +2118,7 @@ pub struct WantData {
 
 /// Want target data (resolved target info)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct WantTargetData {
     pub id: String,
     pub name: String,
@@ -1566,6 +2130,7 @@ pub struct WantTargetData {
 
 /// Data for creating a new want
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateWantData {
     pub description: String,
     #[serde(default = "default_intensity")]
@@ -1594,6 +2159,7 @@ fn default_priority() -> u32 {
 
 /// Data for updating an existing want
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateWantData {
     #[serde(default)]
     pub description: Option<String>,
@@ -1611,6 +2177,7 @@ pub struct UpdateWantData {
 
 /// Actantial actor data (helper, opponent, sender, receiver)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ActantialActorData {
     pub id: String,
     pub name: String,
@@ -1620,6 +2187,7 @@ pub struct ActantialActorData {
 
 /// Actantial view data (for adding/removing views)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ActantialViewData {
     pub want_id: String,
     pub target_id: String,
@@ -1631,6 +2199,7 @@ pub struct ActantialViewData {
 
 /// Goal data for wire transfer
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GoalData {
     pub id: String,
     pub name: String,
@@ -1643,6 +2212,7 @@ pub struct GoalData {
 
 /// Data for creating a new goal
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateGoalData {
     pub name: String,
     #[serde(default)]
@@ -1651,6 +2221,7 @@ pub struct CreateGoalData {
 
 /// Data for updating an existing goal
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateGoalData {
     #[serde(default)]
     pub name: Option<String>,
@@ -1660,6 +2231,7 @@ pub struct UpdateGoalData {
 
 /// Full NPC actantial context data (response to GetNpcActantialContext)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct NpcActantialContextData {
     pub npc_id: String,
     pub npc_name: String,
@@ -1669,6 +2241,7 @@ pub struct NpcActantialContextData {
 
 /// Social views summary data
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SocialViewsData {
     pub allies: Vec<SocialRelationData>,
     pub enemies: Vec<SocialRelationData>,
@@ -1676,6 +2249,7 @@ pub struct SocialViewsData {
 
 /// Social relation data (ally or enemy)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SocialRelationData {
     pub id: String,
     pub name: String,
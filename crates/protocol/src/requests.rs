@@ -13,28 +13,57 @@ fn default_true() -> bool {
 pub mod act;
 pub mod actantial;
 pub mod ai;
+pub mod broadsheet;
 pub mod challenge;
 pub mod character;
 pub mod character_sheet;
+pub mod chronology;
+pub mod clock;
+pub mod companion;
+pub mod crowd;
+pub mod dialogue_tree;
+pub mod dice;
+pub mod draft;
+pub mod economy;
 pub mod event_chain;
 pub mod expression;
+pub mod faction;
+pub mod flashback;
+pub mod gallery;
 pub mod generation;
 pub mod goal;
+pub mod injury;
 pub mod interaction;
 pub mod items;
+pub mod library;
 pub mod location;
 pub mod lore;
+pub mod mail;
+pub mod mention;
+pub mod mortality;
+pub mod name_generator;
 pub mod narrative_event;
 pub mod npc;
+pub mod npc_draft;
 pub mod observation;
+pub mod pacing;
+pub mod parallel_scene;
 pub mod player_character;
+pub mod property;
 pub mod region;
 pub mod relationship;
 pub mod scene;
+pub mod secret;
+pub mod simulation;
 pub mod skill;
+pub mod spotlight;
 pub mod stat;
 pub mod story_event;
+pub mod tag;
+pub mod template;
 pub mod time;
+pub mod transcription;
+pub mod tutorial;
 pub mod want;
 pub mod world;
 
@@ -47,6 +76,7 @@ pub mod world;
 /// This is the top-level grouping enum. Actual request variants live in
 /// per-group enums in `requests/*`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "group", content = "payload", rename_all = "snake_case")]
 pub enum RequestPayload {
     World(world::WorldRequest),
@@ -76,6 +106,35 @@ pub enum RequestPayload {
     Lore(lore::LoreRequest),
     Stat(stat::StatRequest),
     CharacterSheet(character_sheet::CharacterSheetRequest),
+    Clock(clock::ClockRequest),
+    Dice(dice::DiceRequest),
+    Tutorial(tutorial::TutorialRequest),
+    Tag(tag::TagRequest),
+    Template(template::TemplateRequest),
+    Library(library::LibraryRequest),
+    Draft(draft::DraftRequest),
+    Gallery(gallery::GalleryRequest),
+    Chronology(chronology::ChronologyRequest),
+    Crowd(crowd::CrowdRequest),
+    NpcDraft(npc_draft::NpcDraftRequest),
+    Mention(mention::MentionRequest),
+    NameGenerator(name_generator::NameGeneratorRequest),
+    Economy(economy::EconomyRequest),
+    Property(property::PropertyRequest),
+    Companion(companion::CompanionRequest),
+    Injury(injury::InjuryRequest),
+    Mortality(mortality::MortalityRequest),
+    Secret(secret::SecretRequest),
+    Mail(mail::MailRequest),
+    Broadsheet(broadsheet::BroadsheetRequest),
+    Simulation(simulation::SimulationRequest),
+    Faction(faction::FactionRequest),
+    DialogueTree(dialogue_tree::DialogueTreeRequest),
+    Flashback(flashback::FlashbackRequest),
+    ParallelScene(parallel_scene::ParallelSceneRequest),
+    Spotlight(spotlight::SpotlightRequest),
+    Pacing(pacing::PacingRequest),
+    Transcription(transcription::TranscriptionRequest),
 
     #[serde(other)]
     Unknown,
@@ -87,6 +146,7 @@ pub enum RequestPayload {
 
 /// Data for creating a world
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateWorldData {
     pub name: String,
     #[serde(default)]
@@ -97,6 +157,7 @@ pub struct CreateWorldData {
 
 /// Data for updating a world
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateWorldData {
     #[serde(default)]
     pub name: Option<String>,
@@ -108,6 +169,7 @@ pub struct UpdateWorldData {
 
 /// Data for creating a character
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateCharacterData {
     pub name: String,
     #[serde(default)]
@@ -122,6 +184,7 @@ pub struct CreateCharacterData {
 
 /// Data for updating a character
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateCharacterData {
     #[serde(default)]
     pub name: Option<String>,
@@ -139,6 +202,7 @@ pub struct UpdateCharacterData {
 
 /// Data for changing an archetype
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChangeArchetypeData {
     pub new_archetype: String,
     pub reason: String,
@@ -146,16 +210,23 @@ pub struct ChangeArchetypeData {
 
 /// Data for creating a location
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateLocationData {
     pub name: String,
     #[serde(default)]
     pub description: Option<String>,
     #[serde(default)]
     pub setting: Option<String>,
+    /// The location this one sits inside
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub scale: Option<crate::types::LocationScaleData>,
 }
 
 /// Data for updating a location
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateLocationData {
     #[serde(default)]
     pub name: Option<String>,
@@ -163,10 +234,17 @@ pub struct UpdateLocationData {
     pub description: Option<String>,
     #[serde(default)]
     pub setting: Option<String>,
+    #[serde(default)]
+    pub scale: Option<crate::types::LocationScaleData>,
+    /// Game minutes between two places inside the location, overriding the
+    /// scale's default
+    #[serde(default)]
+    pub crossing_minutes: Option<u32>,
 }
 
 /// Data for creating a location connection
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateLocationConnectionData {
     pub from_id: String,
     pub to_id: String,
@@ -176,6 +254,7 @@ pub struct CreateLocationConnectionData {
 
 /// Data for creating a region
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateRegionData {
     pub name: String,
     #[serde(default)]
@@ -186,6 +265,7 @@ pub struct CreateRegionData {
 
 /// Data for updating a region
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateRegionData {
     #[serde(default)]
     pub name: Option<String>,
@@ -197,6 +277,7 @@ pub struct UpdateRegionData {
 
 /// Data for creating a region connection
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateRegionConnectionData {
     #[serde(default)]
     pub description: Option<String>,
@@ -208,6 +289,7 @@ pub struct CreateRegionConnectionData {
 
 /// Data for creating a scene
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateSceneData {
     pub name: String,
     #[serde(default)]
@@ -218,6 +300,7 @@ pub struct CreateSceneData {
 
 /// Data for updating a scene
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateSceneData {
     #[serde(default)]
     pub name: Option<String>,
@@ -229,6 +312,7 @@ pub struct UpdateSceneData {
 
 /// Data for creating an act
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateActData {
     pub name: String,
     #[serde(default)]
@@ -239,6 +323,7 @@ pub struct CreateActData {
 
 /// Data for creating an interaction
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateInteractionData {
     pub name: String,
     #[serde(default)]
@@ -247,10 +332,20 @@ pub struct CreateInteractionData {
     pub trigger: Option<String>,
     #[serde(default)]
     pub available: Option<bool>,
+    /// Game minutes a PC must wait between uses
+    #[serde(default)]
+    pub cooldown_minutes: Option<u32>,
+    /// How many times each PC may use it
+    #[serde(default)]
+    pub max_uses: Option<u32>,
+    /// Times of day it is offered; empty means any time
+    #[serde(default)]
+    pub available_times: Vec<crate::types::TimeOfDayData>,
 }
 
 /// Data for updating an interaction
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateInteractionData {
     #[serde(default)]
     pub name: Option<String>,
@@ -260,10 +355,20 @@ pub struct UpdateInteractionData {
     pub trigger: Option<String>,
     #[serde(default)]
     pub available: Option<bool>,
+    /// 0 removes the cooldown
+    #[serde(default)]
+    pub cooldown_minutes: Option<u32>,
+    /// 0 removes the limit
+    #[serde(default)]
+    pub max_uses: Option<u32>,
+    /// Empty means any time
+    #[serde(default)]
+    pub available_times: Option<Vec<crate::types::TimeOfDayData>>,
 }
 
 /// Data for creating a skill
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateSkillData {
     pub name: String,
     #[serde(default)]
@@ -276,6 +381,7 @@ pub struct CreateSkillData {
 
 /// Data for updating a skill
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateSkillData {
     #[serde(default)]
     pub name: Option<String>,
@@ -291,6 +397,7 @@ pub struct UpdateSkillData {
 
 /// Data for creating a challenge
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateChallengeData {
     pub name: String,
     #[serde(default)]
@@ -305,6 +412,7 @@ pub struct CreateChallengeData {
 
 /// Data for updating a challenge
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateChallengeData {
     #[serde(default)]
     pub name: Option<String>,
@@ -322,6 +430,7 @@ pub struct UpdateChallengeData {
 
 /// Data for creating a narrative event
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateNarrativeEventData {
     pub name: String,
     #[serde(default)]
@@ -334,6 +443,7 @@ pub struct CreateNarrativeEventData {
 
 /// Data for updating a narrative event
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateNarrativeEventData {
     #[serde(default)]
     pub name: Option<String>,
@@ -347,6 +457,7 @@ pub struct UpdateNarrativeEventData {
 
 /// Data for creating an event chain
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateEventChainData {
     pub name: String,
     #[serde(default)]
@@ -370,6 +481,7 @@ pub struct CreateEventChainData {
 
 /// Data for updating an event chain
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateEventChainData {
     #[serde(default)]
     pub name: Option<String>,
@@ -394,14 +506,22 @@ pub struct UpdateEventChainData {
 
 /// Data for creating a DM marker
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateDmMarkerData {
     pub title: String,
     #[serde(default)]
     pub content: Option<String>,
+    /// Pin the marker to a map
+    #[serde(default)]
+    pub pin: Option<crate::types::MarkerPinData>,
+    /// Keep the marker hidden from players
+    #[serde(default)]
+    pub hidden: bool,
 }
 
 /// Data for updating a story event
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateStoryEventData {
     #[serde(default)]
     pub summary: Option<String>,
@@ -411,6 +531,7 @@ pub struct UpdateStoryEventData {
 
 /// Data for creating a player character
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreatePlayerCharacterData {
     pub name: String,
     #[serde(default)]
@@ -424,6 +545,7 @@ pub struct CreatePlayerCharacterData {
 
 /// Data for updating a player character
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdatePlayerCharacterData {
     #[serde(default)]
     pub name: Option<String>,
@@ -433,6 +555,7 @@ pub struct UpdatePlayerCharacterData {
 
 /// Data for creating a relationship
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateRelationshipData {
     pub from_character_id: String,
     pub to_character_id: String,
@@ -443,6 +566,7 @@ pub struct CreateRelationshipData {
 
 /// Data for creating an observation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CreateObservationData {
     pub npc_id: String,
     pub observation_type: String,
@@ -464,6 +588,7 @@ pub struct CreateObservationData {
 /// Fields can be populated by the client with whatever information is available.
 /// The engine may auto-enrich this context with world data when world_id is available.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct SuggestionContextData {
     /// Type of entity being created (e.g., "character", "location", "tavern", "forest")
@@ -494,6 +619,7 @@ pub struct SuggestionContextData {
 
 /// Data for creating a new item
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CreateItemData {
     pub name: String,
@@ -503,6 +629,9 @@ pub struct CreateItemData {
     pub item_type: Option<String>,
     #[serde(default)]
     pub properties: Option<serde_json::Value>,
+    /// Price where nothing affects the item's market; not for sale when absent
+    #[serde(default)]
+    pub base_price: Option<u32>,
 }
 
 // =============================================================================
@@ -511,6 +640,7 @@ pub struct CreateItemData {
 
 /// Data for creating a lore entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CreateLoreData {
     pub title: String,
@@ -529,6 +659,7 @@ pub struct CreateLoreData {
 
 /// Data for updating a lore entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateLoreData {
     #[serde(default)]
@@ -545,6 +676,7 @@ pub struct UpdateLoreData {
 
 /// Data for creating a lore chunk
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CreateLoreChunkData {
     /// Optional title for this chunk (may be omitted)
@@ -559,6 +691,7 @@ pub struct CreateLoreChunkData {
 
 /// Data for updating a lore chunk
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateLoreChunkData {
     #[serde(default)]
@@ -570,3 +703,88 @@ pub struct UpdateLoreChunkData {
     #[serde(default)]
     pub discovery_hint: Option<String>,
 }
+
+// =============================================================================
+// Progress Clock Data Types
+// =============================================================================
+
+/// Data for creating a progress clock
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CreateClockData {
+    pub name: String,
+    pub segments: u8,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// faction, quest, threat, or project (defaults to threat)
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub subject: Option<String>,
+    #[serde(default)]
+    pub visible_to_players: Option<bool>,
+    /// Make the clock a personal quest of this PC
+    #[serde(default)]
+    pub owner_pc_id: Option<String>,
+    /// Party clock the personal objective works against
+    #[serde(default)]
+    pub conflicts_with_clock_id: Option<String>,
+}
+
+/// Data for updating a progress clock
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateClockData {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub segments: Option<u8>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub subject: Option<String>,
+    #[serde(default)]
+    pub visible_to_players: Option<bool>,
+    /// PC the clock is personal to (empty string makes it a party clock)
+    #[serde(default)]
+    pub owner_pc_id: Option<String>,
+    /// Party clock it works against (empty string clears)
+    #[serde(default)]
+    pub conflicts_with_clock_id: Option<String>,
+}
+
+// =============================================================================
+// Tag Data Types
+// =============================================================================
+
+/// Data for creating or replacing a saved tag filter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SaveFilterData {
+    /// Existing filter to replace (creates a new one when absent)
+    #[serde(default)]
+    pub filter_id: Option<String>,
+    pub name: String,
+    pub entity_type: crate::responses::EntityType,
+    pub tags: Vec<String>,
+}
+
+// =============================================================================
+// Template Data Types
+// =============================================================================
+
+/// Data for creating or replacing an entity template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SaveTemplateData {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub body: crate::types::TemplateBodyData,
+}
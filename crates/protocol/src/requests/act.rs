@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 use super::CreateActData;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ActRequest {
     ListActs {
         world_id: String,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
     },
     CreateAct {
         world_id: String,
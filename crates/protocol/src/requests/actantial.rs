@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::messages::{ActantialRoleData, ActorTypeData};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ActantialRequest {
     GetActantialContext {
@@ -4,6 +4,7 @@ use super::SuggestionContextData;
 use crate::messages::ActantialRoleData;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum AiRequest {
     SuggestDeflectionBehavior {
@@ -36,4 +37,27 @@ pub enum AiRequest {
     CancelContentSuggestion {
         request_id: String,
     },
+
+    /// List models available on the LLM server
+    ListModels,
+    /// Download a model; progress arrives as `ModelPullProgress` broadcasts
+    PullModel {
+        model: String,
+    },
+    /// Choose the models a world uses (None = the Engine's configured model)
+    SetWorldModel {
+        world_id: String,
+        #[serde(default)]
+        dialogue_model: Option<String>,
+        #[serde(default)]
+        summary_model: Option<String>,
+    },
+
+    /// The co-DM's reviews in a world, newest first (DM only)
+    ListCoDmReviews {
+        world_id: String,
+        /// At most this many (capped server-side)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<u32>,
+    },
 }
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::BroadsheetInputData;
+
+/// The current world's broadsheet, one issue per game week
+///
+/// Players read published issues. The DM sees drafts too, and edits,
+/// publishes or deletes them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BroadsheetRequest {
+    /// Issues, newest first; drafts are only listed for the DM
+    ListBroadsheets,
+    /// Draft an issue now from the news since the last one (DM only)
+    GenerateBroadsheet,
+    /// Replace a draft's title and articles (DM only)
+    UpdateBroadsheet {
+        broadsheet_id: String,
+        data: BroadsheetInputData,
+    },
+    /// Publish a draft to every player as a handout (DM only)
+    PublishBroadsheet { broadsheet_id: String },
+    /// Delete an issue, drafted or published (DM only)
+    DeleteBroadsheet { broadsheet_id: String },
+}
@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 use super::{CreateChallengeData, UpdateChallengeData};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ChallengeRequest {
     ListChallenges {
         world_id: String,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
     },
     GetChallenge {
         challenge_id: String,
@@ -22,6 +26,10 @@ pub enum ChallengeRequest {
     DeleteChallenge {
         challenge_id: String,
     },
+    /// Copy a challenge with its outcomes and triggers
+    DuplicateChallenge {
+        challenge_id: String,
+    },
     SetChallengeActive {
         challenge_id: String,
         active: bool,
@@ -30,4 +38,14 @@ pub enum ChallengeRequest {
         challenge_id: String,
         favorite: bool,
     },
+    /// Resolved challenges, newest first, with totals (DM only)
+    GetHistory {
+        world_id: String,
+        /// Only this PC's resolutions
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pc_id: Option<String>,
+        /// At most this many (capped server-side)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<u32>,
+    },
 }
@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 use super::{ChangeArchetypeData, CreateCharacterData, UpdateCharacterData};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CharacterRequest {
     ListCharacters {
         world_id: String,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
     },
     GetCharacter {
         character_id: String,
@@ -22,6 +26,10 @@ pub enum CharacterRequest {
     DeleteCharacter {
         character_id: String,
     },
+    /// Copy a character under a "(copy)" name, with its tags and custom fields
+    DuplicateCharacter {
+        character_id: String,
+    },
     ChangeArchetype {
         character_id: String,
         data: ChangeArchetypeData,
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 
 /// Character sheet operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CharacterSheetRequest {
     // =========================================================================
@@ -19,6 +20,15 @@ pub enum CharacterSheetRequest {
         system_id: String,
     },
 
+    /// Get the character sheet schema for a world's game system.
+    ///
+    /// Returns the system ID, schema, and default values, so a character can
+    /// be built before it exists.
+    GetWorldSchema {
+        /// World whose rule system picks the schema
+        world_id: String,
+    },
+
     /// List all available game systems.
     ///
     /// Returns a list of system IDs and display names.
@@ -120,6 +130,7 @@ pub enum CharacterSheetRequest {
 
 /// Data for a single field update.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct FieldUpdateData {
     /// Field ID
@@ -130,6 +141,7 @@ pub struct FieldUpdateData {
 
 /// Response for system list.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct GameSystemInfo {
     /// System ID
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// Character ages and lore dates in the world the DM is in (DM only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChronologyRequest {
+    /// Every character's age, every lore date and the chronology errors
+    GetChronology,
+    /// Set or clear a character's birthdate; returns the updated report
+    SetBirthDate {
+        character_id: String,
+        /// YYYY-MM-DD on the game calendar; None clears it
+        #[serde(default)]
+        birth_date: Option<String>,
+    },
+    /// Set or clear the year a lore entry's events took place; returns the
+    /// updated report
+    SetLoreEventYear {
+        lore_id: String,
+        #[serde(default)]
+        event_year: Option<i32>,
+    },
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CreateClockData, UpdateClockData};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClockRequest {
+    /// List clocks in a world (players only see visible clocks, and personal
+    /// clocks only of their own PCs)
+    ListClocks {
+        world_id: String,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+    },
+    GetClock {
+        clock_id: String,
+    },
+    CreateClock {
+        world_id: String,
+        data: CreateClockData,
+    },
+    UpdateClock {
+        clock_id: String,
+        data: UpdateClockData,
+    },
+    DeleteClock {
+        clock_id: String,
+    },
+
+    /// Fill (positive) or clear (negative) segments
+    TickClock {
+        clock_id: String,
+        amount: i32,
+    },
+    /// Clear all filled segments
+    ResetClock {
+        clock_id: String,
+    },
+}
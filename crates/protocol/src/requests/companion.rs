@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::CompanionInputData;
+
+/// Companions travelling with PCs in the DM's current world (DM only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CompanionRequest {
+    /// A PC's companions, with wages owed as of the current game time
+    ListCompanions {
+        pc_id: String,
+    },
+    /// Bind an NPC to a PC; the NPC joins the PC's region
+    HireCompanion {
+        pc_id: String,
+        character_id: String,
+        #[serde(default)]
+        data: CompanionInputData,
+    },
+    UpdateCompanion {
+        companion_id: String,
+        data: CompanionInputData,
+    },
+    /// Release the NPC; they stay in the region they are in
+    DismissCompanion {
+        companion_id: String,
+    },
+    /// Settle the wages owed, taking them from the PC's currency
+    PayWages {
+        companion_id: String,
+    },
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::CrowdInputData;
+
+/// Background crowds in the DM's current world (DM only). Players see the
+/// present crowds of their region in `SceneChanged`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CrowdRequest {
+    /// Crowds in one region, or in the whole world without a region
+    ListCrowds {
+        #[serde(default)]
+        region_id: Option<String>,
+    },
+    /// Create a crowd, staged in its region
+    CreateCrowd {
+        region_id: String,
+        data: CrowdInputData,
+    },
+    UpdateCrowd {
+        crowd_id: String,
+        data: CrowdInputData,
+    },
+    DeleteCrowd {
+        crowd_id: String,
+    },
+    /// Stage or unstage a crowd in its region
+    SetCrowdPresent {
+        crowd_id: String,
+        present: bool,
+    },
+}
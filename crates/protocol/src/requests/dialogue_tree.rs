@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::DialogueTreeInputData;
+
+/// Branching dialogue trees in the DM's current world (DM only). Players
+/// answer them by sending a `PlayerAction` with the choice's `choice_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DialogueTreeRequest {
+    /// Every dialogue tree in the world, by name
+    ListDialogueTrees,
+    CreateDialogueTree {
+        data: DialogueTreeInputData,
+    },
+    UpdateDialogueTree {
+        tree_id: String,
+        data: DialogueTreeInputData,
+    },
+    DeleteDialogueTree {
+        tree_id: String,
+    },
+    /// Let PCs who already heard a tree hear it again
+    ResetDialogueTree {
+        tree_id: String,
+    },
+}
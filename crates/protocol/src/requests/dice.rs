@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DiceRequest {
+    /// Roll a dice expression (e.g. "2d6+1") on the server
+    ///
+    /// The result is returned to the roller and shared with the world.
+    Roll { world_id: String, formula: String },
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{DraftFieldData, RevisionSourceData};
+
+/// Revision drafts of generated text fields (DM only). A draft's text only
+/// reaches the entity when one of its revisions is published.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DraftRequest {
+    /// Drafts of one character or location, one per field
+    ListDrafts {
+        entity_type: crate::responses::EntityType,
+        entity_id: String,
+    },
+    GetDraft {
+        draft_id: String,
+    },
+    /// Add a revision to the entity's draft of `field`, starting the draft
+    /// if there is none
+    AddDraftRevision {
+        world_id: String,
+        entity_type: crate::responses::EntityType,
+        entity_id: String,
+        field: DraftFieldData,
+        text: String,
+        source: RevisionSourceData,
+    },
+    /// Word-level diff between two revisions
+    DiffDraftRevisions {
+        draft_id: String,
+        from: u32,
+        to: u32,
+    },
+    /// Write a revision's text to the entity; publishing an older revision
+    /// rolls the entity back to it
+    PublishDraftRevision {
+        draft_id: String,
+        revision: u32,
+    },
+    /// Delete a draft and its history; the entity keeps its current text
+    DiscardDraft {
+        draft_id: String,
+    },
+}
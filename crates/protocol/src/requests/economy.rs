@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::MarketModifierInputData;
+
+/// Market modifiers and item prices in the DM's current world (DM only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EconomyRequest {
+    ListMarketModifiers,
+    CreateMarketModifier {
+        data: MarketModifierInputData,
+    },
+    UpdateMarketModifier {
+        modifier_id: String,
+        data: MarketModifierInputData,
+    },
+    DeleteMarketModifier {
+        modifier_id: String,
+    },
+    /// Scarcity and local prices per item type at a location
+    GetEconomyOverview {
+        location_id: String,
+    },
+    /// Set what an item costs where nothing affects its market; clear it to
+    /// take the item off sale
+    SetItemBasePrice {
+        item_id: String,
+        #[serde(default)]
+        base_price: Option<u32>,
+    },
+}
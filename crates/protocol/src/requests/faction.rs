@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{FactionInputData, FactionMemberInputData};
+
+/// Factions in the DM's current world (DM only). A faction's standing with
+/// the party shapes how its members are staged and voiced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FactionRequest {
+    /// Every faction in the world, by name
+    ListFactions,
+    CreateFaction {
+        data: FactionInputData,
+    },
+    UpdateFaction {
+        faction_id: String,
+        data: FactionInputData,
+    },
+    /// Delete a faction; its rivals forget the rivalry
+    DeleteFaction {
+        faction_id: String,
+    },
+    /// Replace the member list; members must be NPCs in the world
+    SetFactionMembers {
+        faction_id: String,
+        members: Vec<FactionMemberInputData>,
+    },
+    /// Set the reputation with the party, from -100 to 100
+    SetFactionReputation {
+        faction_id: String,
+        reputation: i32,
+    },
+    /// Move the reputation up or down, stopping at the ends of the scale
+    AdjustFactionReputation {
+        faction_id: String,
+        delta: i32,
+    },
+    /// Make two factions rivals, or end their rivalry, on both sides
+    SetFactionRivalry {
+        faction_id: String,
+        rival_id: String,
+        rivals: bool,
+    },
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::StartFlashbackData;
+
+/// Flashback scenes in the current world. While a flashback runs the world
+/// clock shows its date and its region is restaged; ending it puts the
+/// present back. Starting and ending are DM-only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FlashbackRequest {
+    /// The running flashback, or null (anyone in the world)
+    GetFlashback,
+    StartFlashback {
+        data: StartFlashbackData,
+    },
+    /// End the flashback and restore the live clock and region
+    EndFlashback,
+}
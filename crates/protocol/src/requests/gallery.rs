@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::GalleryFilterData;
+
+/// Browsing and organizing the gallery of the world the DM is in (DM only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GalleryRequest {
+    /// Assets of the world's characters, locations and items, newest first
+    ListGallery {
+        #[serde(default)]
+        filter: GalleryFilterData,
+    },
+    /// Star or unstar several assets at once
+    SetAssetsFavorite {
+        asset_ids: Vec<String>,
+        favorite: bool,
+    },
+    /// Add and remove tags on several assets at once
+    TagAssets {
+        asset_ids: Vec<String>,
+        #[serde(default)]
+        add: Vec<String>,
+        #[serde(default)]
+        remove: Vec<String>,
+    },
+}
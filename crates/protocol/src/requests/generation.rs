@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum GenerationRequest {
     GetGenerationQueue {
@@ -17,4 +18,22 @@ pub enum GenerationRequest {
     DismissSuggestion {
         request_id: String,
     },
+    /// Draw new NPC names (DM only). Uses `generator_id` if given,
+    /// otherwise the generator for the faction, then the region, then the
+    /// world default. Names already used by characters are skipped.
+    GenerateNames {
+        world_id: String,
+        #[serde(default)]
+        generator_id: Option<String>,
+        #[serde(default)]
+        region_id: Option<String>,
+        #[serde(default)]
+        faction: Option<String>,
+        #[serde(default = "default_name_count")]
+        count: u32,
+    },
+}
+
+fn default_name_count() -> u32 {
+    5
 }
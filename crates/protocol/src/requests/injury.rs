@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::InjuryInputData;
+
+/// Injuries carried by PCs in the DM's current world (DM only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InjuryRequest {
+    /// A PC's injuries, with recovery as of the current game time
+    ListInjuries {
+        pc_id: String,
+        /// Also list injuries that have healed
+        #[serde(default)]
+        include_healed: bool,
+    },
+    /// Injure a PC as of the current game time
+    InflictInjury {
+        pc_id: String,
+        data: InjuryInputData,
+    },
+    UpdateInjury {
+        injury_id: String,
+        data: InjuryInputData,
+    },
+    /// Record the outcome of the injury's one treatment attempt
+    TreatInjury {
+        injury_id: String,
+        succeeded: bool,
+    },
+    DeleteInjury {
+        injury_id: String,
+    },
+}
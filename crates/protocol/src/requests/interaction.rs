@@ -3,14 +3,22 @@ use serde::{Deserialize, Serialize};
 use super::{CreateInteractionData, UpdateInteractionData};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum InteractionRequest {
     ListInteractions {
         scene_id: String,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
     },
     GetInteraction {
         interaction_id: String,
     },
+    /// Interactions in the PC's current region, with per-PC availability
+    ListAvailableInteractions {
+        pc_id: String,
+    },
     CreateInteraction {
         scene_id: String,
         data: CreateInteractionData,
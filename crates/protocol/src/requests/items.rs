@@ -3,8 +3,12 @@ use serde::{Deserialize, Serialize};
 use super::CreateItemData;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ItemsRequest {
+    GetItem {
+        item_id: String,
+    },
     PlaceItemInRegion {
         region_id: String,
         item_id: String,
@@ -14,4 +18,11 @@ pub enum ItemsRequest {
         region_id: String,
         data: CreateItemData,
     },
+    /// Copy an item under a "(copy)" name. Unique items can't be copied.
+    DuplicateItem {
+        item_id: String,
+        /// Region to place the copy in; left unplaced when absent
+        #[serde(default)]
+        region_id: Option<String>,
+    },
 }
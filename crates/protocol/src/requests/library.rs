@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// The connected user's cross-world content library (DM only). Entries
+/// belong to the user, not to a world.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LibraryRequest {
+    ListLibrary,
+    GetLibraryEntry {
+        entry_id: String,
+    },
+    /// Publish a snapshot of a character, item, challenge or lore entry from
+    /// the world the DM is in
+    PublishToLibrary {
+        world_id: String,
+        entity_type: crate::responses::EntityType,
+        entity_id: String,
+        #[serde(default)]
+        description: Option<String>,
+    },
+    DeleteLibraryEntry {
+        entry_id: String,
+    },
+    /// Create an independent copy of an entry, with fresh IDs, in the world
+    /// the DM is in
+    ImportLibraryEntry {
+        entry_id: String,
+        world_id: String,
+    },
+}
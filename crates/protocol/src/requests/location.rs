@@ -1,12 +1,17 @@
 use serde::{Deserialize, Serialize};
 
 use super::{CreateLocationConnectionData, CreateLocationData, UpdateLocationData};
+use crate::types::ConnectionLockData;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum LocationRequest {
     ListLocations {
         world_id: String,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
     },
     GetLocation {
         location_id: String,
@@ -22,6 +27,11 @@ pub enum LocationRequest {
     DeleteLocation {
         location_id: String,
     },
+    /// Copy a location with its regions, the items lying in them and the
+    /// connections between them
+    DuplicateLocation {
+        location_id: String,
+    },
     GetLocationConnections {
         location_id: String,
     },
@@ -32,4 +42,45 @@ pub enum LocationRequest {
         from_id: String,
         to_id: String,
     },
+    /// Lock a location connection behind keys or a challenge, or unlock it
+    /// when `lock` is absent (DM only)
+    SetLocationConnectionLock {
+        from_id: String,
+        to_id: String,
+        #[serde(default)]
+        lock: Option<ConnectionLockData>,
+    },
+    /// Move a location inside another, or to the top level when
+    /// `parent_id` is absent
+    SetLocationParent {
+        location_id: String,
+        #[serde(default)]
+        parent_id: Option<String>,
+    },
+    /// The locations inside a location, with counts of what each contains
+    ListSubLocations {
+        location_id: String,
+        /// Everything nested inside rather than only direct children
+        #[serde(default)]
+        recursive: bool,
+    },
+    /// Travel time between two locations through the hierarchy
+    GetTravelTime {
+        from_location_id: String,
+        to_location_id: String,
+    },
+    /// Pin a location to the world map, or take it off when `position` is
+    /// absent (DM only)
+    SetLocationMapPosition {
+        location_id: String,
+        #[serde(default)]
+        position: Option<crate::types::WorldMapPositionData>,
+    },
+    /// Set what travellers crossing a location might run into, or clear it
+    /// when `table` is absent (DM only)
+    SetLocationEncounterTable {
+        location_id: String,
+        #[serde(default)]
+        table: Option<crate::types::EncounterTableData>,
+    },
 }
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::LetterInputData;
+
+/// In-world letters in the current world
+///
+/// A player writes and reads their own PC's mail. The DM sees all of it and
+/// can write NPC replies or remove letters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MailRequest {
+    /// Letters the PC wrote and the ones that have reached them, newest
+    /// first
+    ListMailbox { pc_id: String },
+    /// Send a letter from the PC; it arrives after game time passes
+    SendLetter {
+        pc_id: String,
+        data: LetterInputData,
+    },
+    /// Note that the addressee read a delivered letter
+    MarkLetterRead { letter_id: String },
+    /// Every letter in the world, newest first (DM only)
+    ListWorldMail,
+    /// Have the NPC a delivered letter was addressed to write back. The
+    /// reply is generated when no body is given (DM only).
+    ReplyAsNpc {
+        letter_id: String,
+        #[serde(default)]
+        body: Option<String>,
+    },
+    /// Remove a letter, delivered or not (DM only)
+    DeleteLetter { letter_id: String },
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::responses::EntityType;
+
+/// Entity mentions and aliases in the DM's current world (DM only).
+/// Mentions are found by name and alias in descriptions, lore and dialogue,
+/// and kept up to date as those are saved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MentionRequest {
+    /// Every entity text can link to, with its aliases and hover-card text
+    ListMentionTargets,
+    /// Replace an entity's aliases (an empty list clears them)
+    SetEntityAliases {
+        entity_type: EntityType,
+        entity_id: String,
+        aliases: Vec<String>,
+    },
+    /// An entity's aliases
+    GetEntityAliases {
+        entity_type: EntityType,
+        entity_id: String,
+    },
+    /// Add one alias ("the Grey Fox") to an entity
+    AddEntityAlias {
+        entity_type: EntityType,
+        entity_id: String,
+        alias: String,
+    },
+    /// Remove one alias from an entity, ignoring case
+    RemoveEntityAlias {
+        entity_type: EntityType,
+        entity_id: String,
+        alias: String,
+    },
+    /// Where an entity is referenced
+    GetMentionsOf {
+        entity_type: EntityType,
+        entity_id: String,
+    },
+    /// What an entity's text references
+    GetMentionsIn {
+        entity_type: EntityType,
+        entity_id: String,
+    },
+    /// Scan all of the world's text again, e.g. after adding aliases
+    ReindexMentions,
+}
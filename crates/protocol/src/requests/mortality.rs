@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{PcDeathInputData, SuccessorInputData};
+
+/// PC death, resurrection and succession in the current world
+///
+/// Recording a death and resurrecting are DM-only. The dead PC's player can
+/// read the memorial and create their successor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MortalityRequest {
+    /// A dead PC's memorial and resurrection options
+    GetDeath { pc_id: String },
+    /// Record a PC's death as of the current game time
+    KillCharacter {
+        pc_id: String,
+        data: PcDeathInputData,
+    },
+    /// Bring a dead PC back, if the world's rule system allows it
+    ResurrectCharacter { pc_id: String },
+    /// Create the PC who takes over from a dead one
+    CreateSuccessor {
+        pc_id: String,
+        data: SuccessorInputData,
+    },
+}
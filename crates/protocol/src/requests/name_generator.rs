@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::NameGeneratorInputData;
+
+/// Name generators in the DM's current world (DM only). Names are drawn
+/// with `GenerationRequest::GenerateNames`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NameGeneratorRequest {
+    ListNameGenerators,
+    CreateNameGenerator {
+        data: NameGeneratorInputData,
+    },
+    UpdateNameGenerator {
+        generator_id: String,
+        data: NameGeneratorInputData,
+    },
+    DeleteNameGenerator {
+        generator_id: String,
+    },
+}
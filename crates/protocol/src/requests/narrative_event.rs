@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 use super::{CreateNarrativeEventData, UpdateNarrativeEventData};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum NarrativeEventRequest {
     ListNarrativeEvents {
         world_id: String,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
     },
     GetNarrativeEvent {
         event_id: String,
@@ -22,6 +26,10 @@ pub enum NarrativeEventRequest {
     DeleteNarrativeEvent {
         event_id: String,
     },
+    /// Copy an event with its triggers and outcomes; the copy starts untriggered
+    DuplicateNarrativeEvent {
+        event_id: String,
+    },
     SetNarrativeEventActive {
         event_id: String,
         active: bool,
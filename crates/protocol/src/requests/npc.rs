@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum NpcRequest {
     SetNpcDisposition {
@@ -51,4 +52,13 @@ pub enum NpcRequest {
     ListRegionNpcs {
         region_id: String,
     },
+
+    // NPC Autonomy
+    /// Set how much an NPC may say without DM approval ("supervised",
+    /// "small_talk" or "trusted"); no level falls back to the world default
+    SetNpcAutonomy {
+        npc_id: String,
+        #[serde(default)]
+        level: Option<String>,
+    },
 }
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{NpcDraftInputData, NpcDraftSourceData};
+
+/// Promoting crowd members and mentioned names to NPCs in the DM's current
+/// world (DM only). Drafts are filled in by the LLM in the background and
+/// announced with `NpcDraftUpdated`; approving one creates the character.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NpcDraftRequest {
+    ListNpcDrafts,
+    /// Start a draft; a dialogue or lore source needs the mentioned `name`
+    PromoteToNpc {
+        source: NpcDraftSourceData,
+        #[serde(default)]
+        name: Option<String>,
+    },
+    UpdateNpcDraft {
+        draft_id: String,
+        data: NpcDraftInputData,
+    },
+    /// Generate the draft's details again
+    RegenerateNpcDraft {
+        draft_id: String,
+    },
+    /// Create the character and remove the draft
+    ApproveNpcDraft {
+        draft_id: String,
+    },
+    RejectNpcDraft {
+        draft_id: String,
+    },
+}
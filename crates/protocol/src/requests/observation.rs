@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use super::CreateObservationData;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ObservationRequest {
     ListObservations {
@@ -16,4 +17,20 @@ pub enum ObservationRequest {
         pc_id: String,
         npc_id: String,
     },
+    /// Places the PC has visited or heard about, plus where they are now
+    ListKnownLocations {
+        pc_id: String,
+    },
+    /// Tell the PC about a location (DM only)
+    RevealLocation {
+        pc_id: String,
+        location_id: String,
+        #[serde(default)]
+        notes: Option<String>,
+    },
+    /// Make the PC forget a location (DM only)
+    ForgetLocation {
+        pc_id: String,
+        location_id: String,
+    },
 }
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::StartPacingTimerData;
+
+/// The world's pacing timer. Anyone in the world can read it; only the DM
+/// runs it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PacingRequest {
+    GetPacingTimer,
+    /// Start a timer, replacing any running one
+    StartPacingTimer {
+        data: StartPacingTimerData,
+    },
+    PausePacingTimer,
+    ResumePacingTimer,
+    /// Add minutes, also to a timer that already ran out
+    ExtendPacingTimer {
+        minutes: u32,
+    },
+    StopPacingTimer,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::ParallelSceneInputData;
+
+/// Parallel scenes for a split party in the current world. Each scene keeps
+/// its own players, approvals and direction; the DM focuses one at a time.
+/// Listing is open to anyone in the world; the rest is DM-only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ParallelSceneRequest {
+    ListParallelScenes,
+    CreateParallelScene {
+        data: ParallelSceneInputData,
+    },
+    UpdateParallelScene {
+        scene_id: String,
+        data: ParallelSceneInputData,
+    },
+    /// Close a scene; its PCs return to the main table
+    DeleteParallelScene {
+        scene_id: String,
+    },
+    /// Run a scene, or the main table with `None`
+    FocusParallelScene {
+        #[serde(default)]
+        scene_id: Option<String>,
+    },
+    /// Bring every group back to the main table
+    MergeParallelScenes,
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::PropertyInputData;
+
+/// Properties PCs own in the DM's current world (DM only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PropertyRequest {
+    /// A PC's properties, with upkeep owed as of the current game time
+    ListPcProperties {
+        pc_id: String,
+    },
+    CreateProperty {
+        pc_id: String,
+        data: PropertyInputData,
+    },
+    UpdateProperty {
+        property_id: String,
+        data: PropertyInputData,
+    },
+    DeleteProperty {
+        property_id: String,
+    },
+    /// Settle the upkeep owed, taking it from the owner's currency
+    PayUpkeep {
+        property_id: String,
+    },
+}
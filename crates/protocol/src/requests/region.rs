@@ -1,12 +1,19 @@
 use serde::{Deserialize, Serialize};
 
 use super::{CreateRegionConnectionData, CreateRegionData, UpdateRegionData};
+use crate::types::{
+    ConnectionLockData, HotspotData, LightLevelData, RegionMapKindData, SoundCarryData,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum RegionRequest {
     ListRegions {
         location_id: String,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
     },
     GetRegion {
         region_id: String,
@@ -22,6 +29,32 @@ pub enum RegionRequest {
     DeleteRegion {
         region_id: String,
     },
+    /// Replace a region's backdrop hotspots (DM only)
+    SetRegionHotspots {
+        region_id: String,
+        hotspots: Vec<HotspotData>,
+    },
+    /// Fix a region's lighting, or let it follow its location (daylight
+    /// outdoors, lit indoors) when `lighting` is absent (DM only)
+    SetRegionLighting {
+        region_id: String,
+        #[serde(default)]
+        lighting: Option<LightLevelData>,
+    },
+    /// Generate a top-down map of a region from its location's description (DM only)
+    ///
+    /// Runs in the background; the result arrives as `RegionMapGenerated`.
+    GenerateRegionMap {
+        region_id: String,
+        #[serde(default)]
+        kind: RegionMapKindData,
+        /// Workflow to use instead of the world preset's or the kind's default
+        #[serde(default)]
+        workflow: Option<String>,
+        /// Extra prompt text appended to the generated description
+        #[serde(default)]
+        prompt: Option<String>,
+    },
 
     GetRegionConnections {
         region_id: String,
@@ -39,6 +72,20 @@ pub enum RegionRequest {
         from_id: String,
         to_id: String,
     },
+    /// Lock a region connection behind keys or a challenge, or unlock it
+    /// when `lock` is absent (DM only)
+    SetRegionConnectionLock {
+        from_id: String,
+        to_id: String,
+        #[serde(default)]
+        lock: Option<ConnectionLockData>,
+    },
+    /// Set how well noise carries along a region connection (DM only)
+    SetRegionConnectionSound {
+        from_id: String,
+        to_id: String,
+        sound: SoundCarryData,
+    },
 
     GetRegionExits {
         region_id: String,
@@ -59,5 +106,8 @@ pub enum RegionRequest {
 
     ListSpawnPoints {
         world_id: String,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
     },
 }
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::PcSecretInputData;
+
+/// PC secrets in the current world
+///
+/// Only the DM manages and reveals secrets. The PC's player can read their
+/// own.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SecretRequest {
+    /// A PC's secrets, revealed ones included
+    ListSecrets { pc_id: String },
+    CreateSecret {
+        pc_id: String,
+        data: PcSecretInputData,
+    },
+    UpdateSecret {
+        secret_id: String,
+        data: PcSecretInputData,
+    },
+    DeleteSecret { secret_id: String },
+    /// Make the secret public, record it as a story event and apply the NPC
+    /// reactions
+    RevealSecret { secret_id: String },
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::SimulationChangeData;
+
+/// Off-screen changes simulated during time skips, awaiting the DM
+///
+/// All requests are DM only. Nothing changes in the world until the DM
+/// accepts a simulation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SimulationRequest {
+    /// Simulations awaiting review, oldest first
+    ListSimulations,
+    /// Replace a simulation's changes; entries may be dropped or their
+    /// amounts changed, but not added
+    UpdateSimulation {
+        simulation_id: String,
+        changes: Vec<SimulationChangeData>,
+    },
+    /// Apply a simulation's changes to the world
+    AcceptSimulation { simulation_id: String },
+    /// Drop a simulation without changing anything
+    DiscardSimulation { simulation_id: String },
+}
@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 use super::{CreateSkillData, UpdateSkillData};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SkillRequest {
     ListSkills {
         world_id: String,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
     },
     GetSkill {
         skill_id: String,
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::SpotlightSettingsData;
+
+/// Spotlight rotation in the current world. Anyone in the world can see who
+/// has the spotlight; the rest is DM-only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SpotlightRequest {
+    GetSpotlight,
+    UpdateSpotlightSettings {
+        data: SpotlightSettingsData,
+    },
+    /// Pass the spotlight to the PC who has gone longest without acting
+    RotateSpotlight,
+    /// Hand the spotlight to a PC, or clear it with `None`
+    SetSpotlight {
+        pc_id: Option<String>,
+    },
+    /// Forget who acted, keeping the settings
+    ResetSpotlight,
+}
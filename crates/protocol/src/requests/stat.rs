@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 /// Requests for character stat operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum StatRequest {
     /// Get all stats for a character (includes base values, modifiers, and effective values)
@@ -63,6 +64,7 @@ pub enum StatRequest {
 
 /// Data for adding a modifier to a stat
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AddModifierData {
     /// Name of the stat to modify
@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use super::{CreateDmMarkerData, UpdateStoryEventData};
+use crate::types::MarkerPinData;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum StoryEventRequest {
     ListStoryEvents {
@@ -11,6 +13,9 @@ pub enum StoryEventRequest {
         page: Option<u32>,
         #[serde(default)]
         page_size: Option<u32>,
+        /// Only entities carrying every one of these tags (DM only)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
     },
     GetStoryEvent {
         event_id: String,
@@ -27,4 +32,19 @@ pub enum StoryEventRequest {
         event_id: String,
         visible: bool,
     },
+    /// List DM markers pinned to a location map or region backdrop
+    /// (players only see visible markers)
+    ListMapMarkers {
+        world_id: String,
+        location_id: String,
+        /// Region backdrop; omit for the location map
+        #[serde(default)]
+        region_id: Option<String>,
+    },
+    /// Move a DM marker's pin, or unpin it with `None`
+    PinDmMarker {
+        event_id: String,
+        #[serde(default)]
+        pin: Option<MarkerPinData>,
+    },
 }
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use super::SaveFilterData;
+use crate::responses::EntityType;
+
+/// Entity tags and saved tag filters (DM only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TagRequest {
+    GetEntityTags {
+        world_id: String,
+        entity_type: EntityType,
+        entity_id: String,
+    },
+    /// Replace an entity's tags (an empty list clears them)
+    SetEntityTags {
+        world_id: String,
+        entity_type: EntityType,
+        entity_id: String,
+        tags: Vec<String>,
+    },
+    /// Every tag used in a world, with how many entities carry it
+    ListWorldTags {
+        world_id: String,
+    },
+    /// Rename a tag everywhere in a world, merging into `to` if it exists
+    RenameTag {
+        world_id: String,
+        from: String,
+        to: String,
+    },
+    /// Remove a tag from every entity in a world
+    DeleteTag {
+        world_id: String,
+        tag: String,
+    },
+
+    /// The requesting user's saved filters in a world
+    ListSavedFilters {
+        world_id: String,
+    },
+    /// Create a saved filter, or replace one when `data.filter_id` is set
+    SaveFilter {
+        world_id: String,
+        data: SaveFilterData,
+    },
+    DeleteSavedFilter {
+        world_id: String,
+        filter_id: String,
+    },
+}
@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::SaveTemplateData;
+
+/// Entity templates (prefabs) and stamping them out (DM only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TemplateRequest {
+    ListTemplates {
+        world_id: String,
+    },
+    GetTemplate {
+        template_id: String,
+    },
+    CreateTemplate {
+        world_id: String,
+        data: SaveTemplateData,
+    },
+    UpdateTemplate {
+        template_id: String,
+        data: SaveTemplateData,
+    },
+    DeleteTemplate {
+        template_id: String,
+    },
+    /// Save an existing NPC as a template; its name becomes `{name}`
+    SaveCharacterAsTemplate {
+        character_id: String,
+        name: String,
+    },
+    /// Save a region with its items and NPCs as a template; the region's
+    /// name becomes `{name}`
+    SaveRegionAsTemplate {
+        region_id: String,
+        name: String,
+    },
+    /// Create entities from a template, filling `{name}` with `name` and
+    /// other placeholders from `variables`
+    InstantiateTemplate {
+        template_id: String,
+        name: String,
+        #[serde(default)]
+        variables: BTreeMap<String, String>,
+        /// Location for the new region (region templates only)
+        #[serde(default)]
+        location_id: Option<String>,
+    },
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Speech-to-text for players who'd rather talk than type. The engine
+/// returns the text; the player reviews it before sending.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TranscriptionRequest {
+    /// Transcribe a recorded clip in the connection's locale
+    TranscribeAudio {
+        /// Base64-encoded audio
+        audio: String,
+        /// MIME type of the audio, e.g. "audio/webm"
+        content_type: String,
+    },
+}
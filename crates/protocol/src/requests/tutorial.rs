@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TutorialRequest {
+    /// Create a fresh copy of the bundled tutorial world
+    ///
+    /// Like `CreateWorld`, anyone can do this; the response carries the new
+    /// world's id, name, and description.
+    CreateTutorialWorld,
+    /// Where a PC is in the world's tutorial (`null` if the world has none)
+    GetTutorialStatus { world_id: String, pc_id: String },
+}
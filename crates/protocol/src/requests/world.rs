@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use super::{CreateWorldData, UpdateWorldData};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum WorldRequest {
     ListWorlds,
@@ -19,10 +20,130 @@ pub enum WorldRequest {
     DeleteWorld {
         world_id: String,
     },
+    /// Create a world from a session zero's choices, with its starting
+    /// location and region and NPC drafts from the seeds. Like
+    /// `CreateWorld`, anyone can do this.
+    CreateSessionZeroWorld {
+        data: crate::types::SessionZeroData,
+    },
     ExportWorld {
         world_id: String,
     },
+    /// Fork the world into a new, separate world for an alternate timeline
+    /// (DM only)
+    BranchWorld {
+        world_id: String,
+        name: String,
+    },
     GetSheetTemplate {
         world_id: String,
     },
+    GetContentSafety {
+        world_id: String,
+    },
+    UpdateContentSafety {
+        world_id: String,
+        config: crate::types::ContentSafetyData,
+    },
+    GetTypography {
+        world_id: String,
+    },
+    UpdateTypography {
+        world_id: String,
+        typography: crate::types::WorldTypographyData,
+    },
+    GetTheme {
+        world_id: String,
+    },
+    UpdateTheme {
+        world_id: String,
+        theme: crate::types::WorldThemeData,
+    },
+    GetFeatures {
+        world_id: String,
+    },
+    /// Switch experimental subsystems on or off (DM only)
+    UpdateFeatures {
+        world_id: String,
+        features: crate::types::WorldFeaturesData,
+    },
+    /// DM automation scripts (DM only)
+    GetScripts {
+        world_id: String,
+    },
+    /// Replace the world's automation scripts (DM only)
+    UpdateScripts {
+        world_id: String,
+        scripts: Vec<crate::types::WorldScriptData>,
+    },
+    /// Custom field schema for characters, locations and items (DM only)
+    GetCustomFields {
+        world_id: String,
+    },
+    /// Replace the world's custom field schema (DM only)
+    UpdateCustomFields {
+        world_id: String,
+        fields: Vec<crate::types::CustomFieldDefinitionData>,
+    },
+    /// Replace one entity's custom field values (DM only)
+    SetCustomFieldValues {
+        world_id: String,
+        entity_type: crate::responses::EntityType,
+        entity_id: String,
+        values: std::collections::BTreeMap<String, crate::types::CustomFieldValueData>,
+    },
+    /// The overview map image and the locations pinned to it
+    GetWorldMap {
+        world_id: String,
+    },
+    /// Replace the overview map image, or clear it when absent (DM only)
+    SetWorldMapAsset {
+        world_id: String,
+        #[serde(default)]
+        map_asset: Option<String>,
+    },
+    /// Daily LLM, image and storage usage for the last `days` days (DM only)
+    GetUsage {
+        world_id: String,
+        #[serde(default)]
+        days: Option<u32>,
+    },
+    /// Each PC's spotlight (dialogue, challenges, story events), how often
+    /// each NPC is spoken to and how often each location is visited (DM only)
+    GetAnalytics {
+        world_id: String,
+    },
+    /// Challenges never attempted, NPCs never met, lore never discovered and
+    /// narrative events that can no longer fire, each with a suggestion for
+    /// where to bring it back in (DM only)
+    GetUnusedContent {
+        world_id: String,
+    },
+    /// What still needs prepping where the party is and may go next:
+    /// unstaged regions, NPCs without portraits and challenges with blank
+    /// outcomes (DM only)
+    GeneratePrepChecklist {
+        world_id: String,
+    },
+    /// Zip of the world's recent logs, queue items and protocol message
+    /// counts, for attaching to a bug report (DM only)
+    ExportDiagnostics {
+        world_id: String,
+    },
+    /// What changing to another rule system would do to the world's skills,
+    /// challenges and character sheets (DM only)
+    PreviewRuleSystemChange {
+        world_id: String,
+        variant: crate::RuleSystemVariant,
+    },
+    /// Change to another rule system: swap the preset skills, remap
+    /// challenges and sheet fields through the skill alias table and keep
+    /// each PC's old sheet as a version (DM only)
+    ChangeRuleSystem {
+        world_id: String,
+        variant: crate::RuleSystemVariant,
+        /// Skills the DM picked for challenges, over the alias table
+        #[serde(default)]
+        skill_overrides: Vec<crate::types::SkillOverrideData>,
+    },
 }
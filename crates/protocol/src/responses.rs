@@ -14,6 +14,7 @@ pub use wrldbldr_domain::types::{ChangeType, EntityType};
 
 /// Result of a request operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "status", rename_all = "snake_case")]
 pub enum ResponseResult {
     /// Operation succeeded
@@ -92,6 +93,7 @@ impl ResponseResult {
 
 /// Error classification codes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ErrorCode {
     // === Client Errors (4xx) ===
@@ -172,6 +174,7 @@ impl std::error::Error for RequestError {}
 /// Sent to all connected clients when an entity is created, updated, or deleted.
 /// Clients can use this to invalidate caches and update UI.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct EntityChangedData {
     /// Type of entity that changed
     pub entity_type: EntityType,
@@ -243,6 +246,7 @@ impl EntityChangedData {
 
 /// Role of a user in a world
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum WorldRole {
     /// Dungeon Master - full control
@@ -279,6 +283,7 @@ impl WorldRole {
 
 /// Information about a user connected to a world
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ConnectedUser {
     /// Unique user identifier
     pub user_id: String,
@@ -300,6 +305,7 @@ pub struct ConnectedUser {
 
 /// Errors that can occur when joining a world
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum JoinError {
     /// Another DM is already connected to this world
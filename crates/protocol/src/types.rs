@@ -15,6 +15,7 @@ pub use wrldbldr_domain::types::MonomythStage;
 
 /// Role of a participant in a game session
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ParticipantRole {
     DungeonMaster,
     Player,
@@ -30,6 +31,7 @@ pub enum ParticipantRole {
 
 /// Proposed tool call information
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProposedToolInfo {
     pub id: String,
     pub name: String,
@@ -39,6 +41,7 @@ pub struct ProposedToolInfo {
 
 /// DM's decision on an approval request
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "decision")]
 pub enum ApprovalDecision {
     /// Accept all proposed tools with default recipients
@@ -70,12 +73,45 @@ pub enum ApprovalDecision {
     Unknown,
 }
 
+/// Which pending approvals a bulk decision applies to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ApprovalSelection {
+    /// The listed approval requests
+    Requests { request_ids: Vec<String> },
+    /// Every pending approval in a conversation
+    Conversation { conversation_id: String },
+    /// Every pending approval raised by one player action
+    SourceAction { action_id: String },
+    /// Every pending approval no player is waiting on
+    Ambient,
+    /// Unknown variant for forward compatibility
+    #[serde(other)]
+    Unknown,
+}
+
+/// Decision applied to every approval in a bulk selection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "decision")]
+pub enum BulkApprovalDecision {
+    /// Accept each proposed response as-is
+    Accept,
+    /// Reject each proposed response
+    Reject { feedback: String },
+    /// Unknown variant for forward compatibility
+    #[serde(other)]
+    Unknown,
+}
+
 // =============================================================================
 // Suggestion Types
 // =============================================================================
 
 /// Challenge suggestion information for DM approval
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChallengeSuggestionInfo {
     pub challenge_id: String,
     pub challenge_name: String,
@@ -89,10 +125,27 @@ pub struct ChallengeSuggestionInfo {
     /// Optional editable outcomes for DM modification
     #[serde(default)]
     pub outcomes: Option<ChallengeSuggestionOutcomes>,
+    /// Each active PC's odds at the suggested difficulty
+    #[serde(default)]
+    pub party_odds: Vec<PcSuccessChanceInfo>,
+}
+
+/// A PC's chance of passing a suggested challenge
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PcSuccessChanceInfo {
+    pub pc_id: String,
+    pub pc_name: String,
+    /// The PC's modifier for the challenge's skill
+    pub modifier: i32,
+    /// Whole percent; `None` when the DM adjudicates (opposed or custom)
+    #[serde(default)]
+    pub success_percent: Option<u8>,
 }
 
 /// Editable challenge outcomes for DM modification
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChallengeSuggestionOutcomes {
     #[serde(default)]
     pub success: Option<String>,
@@ -106,6 +159,7 @@ pub struct ChallengeSuggestionOutcomes {
 
 /// Narrative event suggestion information for DM approval
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct NarrativeEventSuggestionInfo {
     pub event_id: String,
     pub event_name: String,
@@ -136,6 +190,7 @@ pub struct NarrativeEventSuggestionInfo {
 /// Uses simple numeric fields for efficient JSON serialization.
 /// Conversion from domain GameTime happens in the adapter layer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GameTime {
     /// Day number (currently ordinal-style, 1-based; calendar is planned)
     pub day: u32,
@@ -202,6 +257,7 @@ impl GameTime {
 
 /// How time suggestions are handled (wire format)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum TimeMode {
     /// Time only advances via explicit DM action
@@ -219,6 +275,7 @@ pub enum TimeMode {
 
 /// Time costs for various actions (wire format)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TimeCostConfig {
     /// Minutes for travel between locations
@@ -257,6 +314,7 @@ impl Default for TimeCostConfig {
 
 /// How time is displayed to players.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum TimeFormat {
     /// "9:00 AM"
@@ -274,6 +332,7 @@ pub enum TimeFormat {
 
 /// Complete time configuration (wire format)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct GameTimeConfig {
     /// How time suggestions are handled
@@ -304,6 +363,7 @@ impl Default for GameTimeConfig {
 
 /// A time suggestion awaiting DM approval
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TimeSuggestionData {
     /// Unique ID for this suggestion
@@ -333,6 +393,7 @@ pub struct TimeSuggestionData {
 
 /// Data about a time advancement (for broadcasting)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TimeAdvanceData {
     /// Time before advancement
@@ -356,6 +417,7 @@ pub struct TimeAdvanceData {
 
 /// DM's decision on a time suggestion
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case", tag = "decision")]
 pub enum TimeSuggestionDecision {
     /// Accept the suggested time cost
@@ -375,6 +437,7 @@ pub enum TimeSuggestionDecision {
 
 /// Category of lore (wire format)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum LoreCategoryData {
     Historical,
@@ -391,6 +454,7 @@ pub enum LoreCategoryData {
 
 /// Lore chunk for wire transfer
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct LoreChunkData {
     pub id: String,
@@ -404,6 +468,7 @@ pub struct LoreChunkData {
 
 /// Lore entry for wire transfer
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct LoreData {
     pub id: String,
@@ -419,6 +484,7 @@ pub struct LoreData {
 
 /// How lore was discovered (wire format)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase", tag = "type")]
 pub enum LoreDiscoverySourceData {
     ReadBook {
@@ -436,12 +502,17 @@ pub enum LoreDiscoverySourceData {
     LlmDiscovered {
         context: String,
     },
+    SharedByPc {
+        pc_id: String,
+        pc_name: String,
+    },
     #[serde(other)]
     Unknown,
 }
 
 /// Character's knowledge of lore (wire format)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct LoreKnowledgeData {
     pub lore_id: String,
@@ -456,6 +527,7 @@ pub struct LoreKnowledgeData {
 
 /// Lore summary for list views
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct LoreSummaryData {
     pub id: String,
@@ -468,12 +540,2617 @@ pub struct LoreSummaryData {
     pub known_chunk_count: Option<u32>,
 }
 
+/// Something a player shares with other PCs in the party
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum SharedKnowledgeData {
+    /// The parts of a lore entry the sharing PC knows
+    Lore { lore_id: String },
+    /// Where the sharing PC last saw or heard of an NPC
+    NpcWhereabouts { npc_id: String },
+    #[serde(other)]
+    Unknown,
+}
+
+// =============================================================================
+// Content Safety Types
+// =============================================================================
+
+/// World content rating (wire format)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ContentRatingData {
+    General,
+    #[default]
+    Teen,
+    Mature,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Anonymous safety signal level raised by a player (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SafetySignalLevelData {
+    /// Ask the DM to check in with the table
+    Check,
+    /// X-card: skip the current content
+    Skip,
+    /// Stop play until the table has talked
+    Stop,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Per-world safety settings: lines, veils, and content rating
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ContentSafetyData {
+    #[serde(default)]
+    pub content_rating: ContentRatingData,
+    /// Topics that must never appear
+    #[serde(default)]
+    pub lines: Vec<String>,
+    /// Topics that may occur but stay off-screen
+    #[serde(default)]
+    pub veils: Vec<String>,
+    /// Lowest safety signal level that pauses the action queue (None = never)
+    #[serde(default = "default_pause_queue_at")]
+    pub pause_queue_at: Option<SafetySignalLevelData>,
+    /// Lowest safety signal level that rejects pending NPC dialogue (None = never)
+    #[serde(default = "default_reject_pending_at")]
+    pub reject_pending_at: Option<SafetySignalLevelData>,
+}
+
+fn default_pause_queue_at() -> Option<SafetySignalLevelData> {
+    Some(SafetySignalLevelData::Stop)
+}
+
+fn default_reject_pending_at() -> Option<SafetySignalLevelData> {
+    Some(SafetySignalLevelData::Skip)
+}
+
+impl Default for ContentSafetyData {
+    fn default() -> Self {
+        Self {
+            content_rating: ContentRatingData::default(),
+            lines: Vec::new(),
+            veils: Vec::new(),
+            pause_queue_at: default_pause_queue_at(),
+            reject_pending_at: default_reject_pending_at(),
+        }
+    }
+}
+
+// =============================================================================
+// Typography Types
+// =============================================================================
+
+/// Base text direction for world text (wire format)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum TextDirectionData {
+    #[default]
+    Auto,
+    LeftToRight,
+    RightToLeft,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Per-world typography: direction, language, and font
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WorldTypographyData {
+    #[serde(default)]
+    pub direction: TextDirectionData,
+    /// BCP 47 language tag (e.g. "ar", "ja-JP")
+    #[serde(default)]
+    pub language: Option<String>,
+    /// CSS font family list
+    #[serde(default)]
+    pub font_family: Option<String>,
+    /// Stylesheet URL that loads the font
+    #[serde(default)]
+    pub font_stylesheet_url: Option<String>,
+}
+
+// =============================================================================
+// World Theme Types
+// =============================================================================
+
+/// Decorative frame drawn around scene backdrops (wire format)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum BackdropFrameData {
+    #[default]
+    Plain,
+    Simple,
+    Ornate,
+    Parchment,
+    Vignette,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Per-world theme: accent color and backdrop frame
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WorldThemeData {
+    /// Accent color as "#rrggbb"
+    #[serde(default)]
+    pub accent_color: Option<String>,
+    #[serde(default)]
+    pub backdrop_frame: BackdropFrameData,
+}
+
+// =============================================================================
+// World Feature Types
+// =============================================================================
+
+/// Per-world switches for experimental subsystems (all off by default).
+/// Clients hide UI for disabled features.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WorldFeaturesData {
+    /// Initiative order and HP tracking during fights
+    #[serde(default)]
+    pub combat_tracker: bool,
+    /// NPC-to-NPC spread of rumors about player actions
+    #[serde(default)]
+    pub rumors: bool,
+    /// Weather per region
+    #[serde(default)]
+    pub weather: bool,
+    /// Character aging reported to the DM after long time skips
+    #[serde(default)]
+    pub age_progression: bool,
+}
+
+// =============================================================================
+// World Script Types
+// =============================================================================
+
+/// When a DM automation script runs (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ScriptHookData {
+    ItemPickup,
+    TimeAdvance,
+    ChallengeResolved,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A DM automation script (Rhai source) attached to a hook
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WorldScriptData {
+    pub name: String,
+    pub hook: ScriptHookData,
+    pub source: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Operations the script may run per invocation (server default if omitted)
+    #[serde(default)]
+    pub operation_budget: Option<u64>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+// =============================================================================
+// Custom Field Types
+// =============================================================================
+
+/// What kind of value a custom field holds (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum CustomFieldTypeData {
+    Text,
+    Number,
+    Select,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A DM-defined field on characters, locations or items
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldDefinitionData {
+    /// Stable identifier (normalized to `snake_case` by the Engine)
+    pub key: String,
+    pub label: String,
+    pub entity_type: crate::responses::EntityType,
+    pub field_type: CustomFieldTypeData,
+    /// Allowed values for select fields
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// Whether players see the value in detail views
+    #[serde(default)]
+    pub visible_to_players: bool,
+}
+
+/// A custom field value: a number, or text (select values are option text)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum CustomFieldValueData {
+    Number(f64),
+    Text(String),
+}
+
+/// One field on an entity's detail view, with its value if set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldEntryData {
+    pub field: CustomFieldDefinitionData,
+    #[serde(default)]
+    pub value: Option<CustomFieldValueData>,
+}
+
+// =============================================================================
+// Entity Template Types
+// =============================================================================
+
+/// A saved prefab the DM can stamp out into new entities
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityTemplateData {
+    pub id: String,
+    pub world_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub body: TemplateBodyData,
+    /// Placeholders used in the body's text; always includes `name`
+    #[serde(default)]
+    pub variables: Vec<String>,
+}
+
+/// What a template creates
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TemplateBodyData {
+    Npc(NpcTemplateData),
+    Region(RegionTemplateData),
+    #[serde(other)]
+    Unknown,
+}
+
+/// An NPC blueprint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NpcTemplateData {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Campbell archetype name, e.g. "Mentor"
+    pub archetype: String,
+    #[serde(default)]
+    pub sprite_asset: Option<String>,
+    #[serde(default)]
+    pub portrait_asset: Option<String>,
+}
+
+/// A region blueprint with its items and NPCs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct RegionTemplateData {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub atmosphere: Option<String>,
+    #[serde(default)]
+    pub backdrop_asset: Option<String>,
+    #[serde(default)]
+    pub is_spawn_point: bool,
+    #[serde(default)]
+    pub items: Vec<ItemTemplateData>,
+    #[serde(default)]
+    pub npcs: Vec<TemplateNpcData>,
+}
+
+/// An item blueprint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ItemTemplateData {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub item_type: Option<String>,
+}
+
+/// An NPC in a region template and how they relate to the region
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateNpcData {
+    pub npc: NpcTemplateData,
+    pub role: TemplateNpcRoleData,
+}
+
+/// How a template NPC relates to the new region (drives staging suggestions)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TemplateNpcRoleData {
+    Home,
+    /// `shift` is day, night or always
+    WorksAt {
+        #[serde(default)]
+        shift: String,
+    },
+    /// `frequency` is often, sometimes or rarely
+    Frequents {
+        #[serde(default)]
+        frequency: String,
+    },
+    Avoids {
+        #[serde(default)]
+        reason: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// An entity created from a template or library entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedEntityData {
+    pub entity_type: crate::responses::EntityType,
+    pub entity_id: String,
+    pub name: String,
+}
+
+// =============================================================================
+// Content Library Types
+// =============================================================================
+
+/// An entry in a user's cross-world content library
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryEntryData {
+    pub id: String,
+    pub name: String,
+    /// Owner's notes
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Character, Item, Challenge or Lore
+    pub entity_type: crate::responses::EntityType,
+    /// The content's own description (or lore summary)
+    #[serde(default)]
+    pub summary: String,
+    pub source_world_id: String,
+    /// RFC 3339 publish time
+    pub created_at: String,
+}
+
+// =============================================================================
+// Content Draft Types
+// =============================================================================
+
+/// The text field a draft is for (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum DraftFieldData {
+    /// Character or location description
+    Description,
+    /// Location atmosphere
+    Atmosphere,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Where a draft revision came from (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum RevisionSourceData {
+    /// An LLM suggestion
+    Generated,
+    /// Typed or edited by the DM
+    Edited,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether a draft's newest revision is live on the entity (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum DraftStatusData {
+    Draft,
+    Published,
+    #[serde(other)]
+    Unknown,
+}
+
+/// One version of a draft's text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DraftRevisionData {
+    pub number: u32,
+    pub text: String,
+    pub source: RevisionSourceData,
+    /// RFC 3339 time the revision was added
+    pub created_at: String,
+}
+
+/// The revision history of one text field of one entity
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ContentDraftData {
+    pub id: String,
+    pub world_id: String,
+    /// Character or Location
+    pub entity_type: crate::responses::EntityType,
+    pub entity_id: String,
+    pub field: DraftFieldData,
+    pub status: DraftStatusData,
+    /// Oldest first
+    pub revisions: Vec<DraftRevisionData>,
+    /// The revision whose text is on the entity, if any was published
+    #[serde(default)]
+    pub published_revision: Option<u32>,
+}
+
+/// What happened to a stretch of text between two revisions (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum DiffOpData {
+    Equal,
+    Insert,
+    Delete,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A run of text with one diff operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DiffSegmentData {
+    pub op: DiffOpData,
+    pub text: String,
+}
+
+/// Word-level changes between two revisions of a draft.
+///
+/// `equal` and `delete` segments join back into the `from` text, `equal`
+/// and `insert` segments into the `to` text, so a side-by-side view can
+/// render both columns from `segments` alone.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DraftDiffData {
+    pub draft_id: String,
+    pub from: u32,
+    pub to: u32,
+    pub segments: Vec<DiffSegmentData>,
+}
+
+// =============================================================================
+// Gallery Types
+// =============================================================================
+
+/// An asset in a world's gallery (wire format)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryAssetData {
+    pub id: String,
+    /// Owning character, location or item
+    pub entity_type: crate::responses::EntityType,
+    pub entity_id: String,
+    /// Asset slot, e.g. "portrait" or "backdrop"
+    pub asset_type: String,
+    pub file_path: String,
+    pub label: Option<String>,
+    pub is_active: bool,
+    pub is_favorite: bool,
+    pub tags: Vec<String>,
+    /// Generation batch, for AI-generated assets
+    pub batch_id: Option<String>,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+}
+
+/// Which gallery assets to list; unset criteria match every asset
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryFilterData {
+    #[serde(default)]
+    pub entity_type: Option<crate::responses::EntityType>,
+    #[serde(default)]
+    pub entity_id: Option<String>,
+    /// Asset slot, e.g. "portrait"
+    #[serde(default)]
+    pub asset_type: Option<String>,
+    #[serde(default)]
+    pub batch_id: Option<String>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub favorites_only: bool,
+}
+
+// =============================================================================
+// Chronology Types
+// =============================================================================
+
+/// Broad life stage derived from a character's age (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum LifeStageData {
+    Child,
+    Adolescent,
+    Adult,
+    MiddleAged,
+    Elder,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A character's birthdate and computed age on the game calendar
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterAgeData {
+    pub character_id: String,
+    pub name: String,
+    /// YYYY-MM-DD on the game calendar
+    pub birth_date: Option<String>,
+    /// None without a birthdate, or when the character is not yet born
+    pub age: Option<u32>,
+    pub life_stage: Option<LifeStageData>,
+}
+
+/// When a lore entry's events took place
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LoreDateData {
+    pub lore_id: String,
+    pub title: String,
+    pub event_year: Option<i32>,
+}
+
+/// Kind of chronology error (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ChronologyIssueKindData {
+    /// Lore mentions the character in a year before their birth
+    MentionedBeforeBirth,
+    /// The birthdate is after the current game date
+    BornInFuture,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A chronology error for the DM to fix
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ChronologyIssueData {
+    pub kind: ChronologyIssueKindData,
+    pub character_id: String,
+    /// The lore entry involved, for `MentionedBeforeBirth`
+    pub lore_id: Option<String>,
+    pub message: String,
+}
+
+/// Ages, lore dates and chronology errors for a world
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ChronologyReportData {
+    /// Current game date, YYYY-MM-DD
+    pub current_date: String,
+    pub characters: Vec<CharacterAgeData>,
+    pub lore: Vec<LoreDateData>,
+    pub issues: Vec<ChronologyIssueData>,
+}
+
+/// A character who aged during a time skip
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct AgeProgressionData {
+    pub character_id: String,
+    pub name: String,
+    /// None if the character was born during the skip
+    pub previous_age: Option<u32>,
+    pub new_age: u32,
+    pub life_stage: LifeStageData,
+    /// Whether the character entered a new life stage
+    pub stage_changed: bool,
+}
+
+// =============================================================================
+// Crowd Types
+// =============================================================================
+
+/// Fields of a crowd the DM can set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CrowdInputData {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub approximate_count: u32,
+    #[serde(default)]
+    pub disposition: wrldbldr_domain::types::DispositionLevel,
+}
+
+/// An unnamed group of people in a region, as the DM sees it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CrowdData {
+    pub id: String,
+    pub region_id: String,
+    pub name: String,
+    pub description: String,
+    pub approximate_count: u32,
+    /// The headcount in words ("dozens"), as players see it
+    pub size_label: String,
+    pub disposition: wrldbldr_domain::types::DispositionLevel,
+    /// Whether the crowd is staged in its region
+    pub is_present: bool,
+}
+
+// =============================================================================
+// Faction Types
+// =============================================================================
+
+/// Fields of a faction the DM can set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FactionInputData {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Most pressing first
+    #[serde(default)]
+    pub goals: Vec<String>,
+}
+
+/// An NPC to list as a faction member
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FactionMemberInputData {
+    pub character_id: String,
+    /// What they do for the faction; empty for rank and file
+    #[serde(default)]
+    pub role: String,
+}
+
+/// A faction member with their name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FactionMemberData {
+    pub character_id: String,
+    pub name: String,
+    pub role: String,
+}
+
+/// An organization and its standing with the party, as the DM sees it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FactionData {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub goals: Vec<String>,
+    pub members: Vec<FactionMemberData>,
+    /// -100 (sworn enemies) to 100 (one of their own)
+    pub reputation: i32,
+    pub standing: wrldbldr_domain::ReputationStanding,
+    /// IDs of opposed factions; rivalries are always mutual
+    pub rival_ids: Vec<String>,
+}
+
+// =============================================================================
+// Dialogue Tree Types
+// =============================================================================
+
+/// A choice the player can answer a dialogue node with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DialogueBranchData {
+    pub text: String,
+    /// Id of the node the choice leads to; none ends the beat
+    #[serde(default)]
+    pub next: Option<String>,
+    /// World flags set when the choice is taken
+    #[serde(default)]
+    pub set_flags: Vec<String>,
+    /// Event effects run when the choice is taken, in the same shape as
+    /// narrative event outcome effects
+    #[serde(default)]
+    pub effects: Vec<serde_json::Value>,
+}
+
+/// A line the speaker says and the choices to answer it with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DialogueNodeData {
+    /// Unique in the tree; choices refer to nodes by it
+    pub id: String,
+    pub line: String,
+    /// No choices ends the beat after the line
+    #[serde(default)]
+    pub choices: Vec<DialogueBranchData>,
+}
+
+/// Fields of a dialogue tree the DM can set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DialogueTreeInputData {
+    pub name: String,
+    /// The NPC who says every line
+    pub speaker_id: String,
+    /// Play when this narrative event triggers instead of when a PC talks
+    /// to the speaker
+    #[serde(default)]
+    pub narrative_event_id: Option<String>,
+    /// The first node is where the beat starts
+    pub nodes: Vec<DialogueNodeData>,
+    #[serde(default = "default_true")]
+    pub active: bool,
+    /// Play every time instead of once per PC
+    #[serde(default)]
+    pub repeatable: bool,
+}
+
+/// A DM-authored branching conversation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DialogueTreeData {
+    pub id: String,
+    pub name: String,
+    pub speaker_id: String,
+    pub speaker_name: String,
+    pub narrative_event_id: Option<String>,
+    pub nodes: Vec<DialogueNodeData>,
+    pub active: bool,
+    pub repeatable: bool,
+    /// How many PCs have heard the tree
+    pub played_count: u32,
+}
+
+// =============================================================================
+// Voice Input Types
+// =============================================================================
+
+/// What a player said, as text for the dialogue field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionData {
+    /// Empty when no speech was heard
+    pub text: String,
+}
+
+// =============================================================================
+// Flashback Types
+// =============================================================================
+
+/// Where and when to set a flashback
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct StartFlashbackData {
+    /// Shown to everyone while the flashback runs
+    pub title: String,
+    pub region_id: String,
+    /// How many years before the present; 0 for earlier this year
+    #[serde(default)]
+    pub years_back: u32,
+    /// Day of that year the flashback happens on
+    pub day: u32,
+    pub hour: u8,
+    /// Who is in the region during the flashback
+    #[serde(default)]
+    pub npcs: Vec<crate::messages::ApprovedNpcInfo>,
+    /// How the region looked back then; none keeps its default look
+    #[serde(default)]
+    pub region_state_id: Option<String>,
+}
+
+/// A flashback in progress
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FlashbackData {
+    pub title: String,
+    pub region_id: String,
+    /// The flashback's date, which the world clock shows meanwhile
+    pub game_time: GameTime,
+    /// The present, restored when the flashback ends
+    pub live_time: GameTime,
+    pub started_at: String,
+}
+
+// =============================================================================
+// Parallel Scene Types
+// =============================================================================
+
+/// A group of a split party and how its scene should play
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ParallelSceneInputData {
+    pub name: String,
+    /// PCs in the scene; PCs already in another scene move to this one
+    pub pc_ids: Vec<String>,
+    /// Directorial notes for this scene's NPC responses
+    #[serde(default)]
+    pub scene_notes: String,
+    #[serde(default)]
+    pub tone: String,
+}
+
+/// One scene of a split party. Players get the notes and tone blanked.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ParallelSceneData {
+    pub id: String,
+    pub name: String,
+    pub pc_ids: Vec<String>,
+    #[serde(default)]
+    pub scene_notes: String,
+    #[serde(default)]
+    pub tone: String,
+    /// Whether the DM is running this scene right now
+    #[serde(default)]
+    pub is_focused: bool,
+}
+
+// =============================================================================
+// Spotlight Types
+// =============================================================================
+
+/// Spotlight rotation settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SpotlightSettingsData {
+    pub enabled: bool,
+    /// Actions by others after which a PC who hasn't acted is flagged
+    pub quiet_after: u32,
+    /// Refuse back-to-back actions from one PC when at least this many PCs
+    /// share its region; 0 never refuses
+    #[serde(default)]
+    pub throttle_crowd: u32,
+}
+
+/// One PC's place in the spotlight rotation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SpotlightPcData {
+    pub pc_id: String,
+    pub name: String,
+    /// Actions other PCs took since this one last acted
+    pub actions_since: u32,
+    /// Sat out long enough to warn the DM
+    pub is_quiet: bool,
+}
+
+/// The spotlight tracker. Players get an empty rotation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SpotlightData {
+    pub settings: SpotlightSettingsData,
+    /// The PC the DM is turning to
+    #[serde(default)]
+    pub focus_pc_id: Option<String>,
+    /// PCs in rotation order, longest without acting first
+    #[serde(default)]
+    pub rotation: Vec<SpotlightPcData>,
+}
+
+// =============================================================================
+// Pacing Timer Types
+// =============================================================================
+
+/// What a pacing timer limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PacingTimerKindData {
+    Scene,
+    /// Starts over whenever a player acts
+    Turn,
+    #[serde(other)]
+    Unknown,
+}
+
+/// How a pacing timer should look to the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PacingCueData {
+    Running,
+    Paused,
+    Warning,
+    Expired,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A pacing timer for the DM to start
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct StartPacingTimerData {
+    pub kind: PacingTimerKindData,
+    /// Real-world minutes per scene or per turn
+    pub minutes: u32,
+    /// Minutes before the end at which to warn the table
+    #[serde(default)]
+    pub warning_minutes: u32,
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+/// A world's pacing timer. Clients count down from `remaining_secs` as of
+/// when the message arrived.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PacingTimerData {
+    pub kind: PacingTimerKindData,
+    #[serde(default)]
+    pub label: Option<String>,
+    pub duration_secs: u32,
+    pub warning_secs: u32,
+    pub remaining_secs: u32,
+    pub cue: PacingCueData,
+    /// The PC whose turn a turn timer is counting
+    #[serde(default)]
+    pub pc_id: Option<String>,
+}
+
+// =============================================================================
+// NPC Draft Types
+// =============================================================================
+
+/// Where a drafted NPC came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NpcDraftSourceData {
+    /// One member of a crowd steps forward
+    Crowd { crowd_id: String },
+    /// A name mentioned in dialogue, with the line it appeared in
+    Dialogue { excerpt: String },
+    /// A name mentioned in a lore entry
+    Lore { lore_id: String },
+    /// Seeded at session zero from a concept, in the starting region
+    SessionZero { region_id: String, concept: String },
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether the LLM is still filling a draft in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum NpcDraftStatusData {
+    Generating,
+    /// Ready for the DM to edit, approve or reject
+    Pending,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Fields of an NPC draft the DM can set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NpcDraftInputData {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Campbell archetype name, e.g. "Mentor"
+    pub archetype: String,
+    #[serde(default)]
+    pub disposition: wrldbldr_domain::types::DispositionLevel,
+}
+
+/// A proposed NPC waiting for DM approval
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NpcDraftData {
+    pub id: String,
+    pub source: NpcDraftSourceData,
+    /// Where they came from, in words ("One of the dock workers")
+    pub source_label: String,
+    /// Empty while a crowd member's name is still being generated
+    pub name: String,
+    pub description: String,
+    /// Campbell archetype name, e.g. "Mentor"
+    pub archetype: String,
+    pub disposition: wrldbldr_domain::types::DispositionLevel,
+    pub status: NpcDraftStatusData,
+    /// Why the last generation failed; the draft can still be filled by hand
+    #[serde(default)]
+    pub generation_error: Option<String>,
+    pub created_at: String,
+}
+
+// =============================================================================
+// Entity Mention Types
+// =============================================================================
+
+/// An entity text can link to, with what its hover-card shows
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MentionTargetData {
+    pub entity_type: crate::responses::EntityType,
+    pub entity_id: String,
+    pub name: String,
+    /// Other names the entity goes by ("the Widow")
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// The start of the entity's description
+    #[serde(default)]
+    pub summary: String,
+}
+
+/// A reference from one entity's text to another entity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityMentionData {
+    pub source_type: crate::responses::EntityType,
+    pub source_id: String,
+    pub source_name: String,
+    pub target_type: crate::responses::EntityType,
+    pub target_id: String,
+    pub target_name: String,
+    /// The text that matched, as written in the source
+    pub matched_text: String,
+    /// The first mention with some text around it
+    pub excerpt: String,
+    /// How many times the source mentions the target
+    pub count: u32,
+}
+
+// =============================================================================
+// Name Generator Types
+// =============================================================================
+
+/// How a name generator builds names
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NameStyleData {
+    /// A given name, followed by a family name when there are any
+    List {
+        given_names: Vec<String>,
+        #[serde(default)]
+        family_names: Vec<String>,
+    },
+    /// A random pattern with each `{part}` filled from that part's entries
+    Grammar {
+        patterns: Vec<String>,
+        parts: std::collections::BTreeMap<String, Vec<String>>,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// Fields of a name generator the DM can set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NameGeneratorInputData {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub style: NameStyleData,
+    /// Regions whose NPCs are named this way
+    #[serde(default)]
+    pub region_ids: Vec<String>,
+    /// Factions whose members are named this way
+    #[serde(default)]
+    pub factions: Vec<String>,
+}
+
+/// A culture's way of naming people
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NameGeneratorData {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub style: NameStyleData,
+    pub region_ids: Vec<String>,
+    pub factions: Vec<String>,
+    /// Assigned to no region or faction, so used when nothing else matches
+    pub is_default: bool,
+}
+
+/// Names drawn from a generator
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedNamesData {
+    pub generator_id: String,
+    pub generator_name: String,
+    /// May be fewer than asked for when the generator runs out of new names
+    pub names: Vec<String>,
+}
+
+// =============================================================================
+// Economy Types
+// =============================================================================
+
+/// How hard a kind of goods is to come by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ScarcityData {
+    /// 75% of base price
+    Abundant,
+    /// Base price
+    Common,
+    /// 150% of base price
+    Scarce,
+    /// 250% of base price
+    Rare,
+    /// Not for sale
+    Unavailable,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Fields of a market modifier the DM can set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MarketModifierInputData {
+    /// Location it applies to; the whole world when absent
+    #[serde(default)]
+    pub location_id: Option<String>,
+    /// Item type it applies to; all goods when absent
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Scarcity it sets; leaves scarcity alone when absent
+    #[serde(default)]
+    pub scarcity: Option<ScarcityData>,
+    /// Percent on top of scarcity: 20 is 20% dearer, -30 is 30% cheaper
+    #[serde(default)]
+    pub price_adjustment: i32,
+    /// World flag it waits for; always in effect when absent
+    #[serde(default)]
+    pub flag: Option<String>,
+    pub reason: String,
+}
+
+/// An exception to normal prices
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MarketModifierData {
+    pub id: String,
+    pub location_id: Option<String>,
+    pub category: Option<String>,
+    pub scarcity: Option<ScarcityData>,
+    pub price_adjustment: i32,
+    pub flag: Option<String>,
+    pub reason: String,
+    /// False while the modifier waits for a flag that isn't set
+    pub is_active: bool,
+}
+
+/// One item's price at a location
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MarketItemData {
+    pub item_id: String,
+    pub name: String,
+    /// Absent when the item isn't for sale anywhere
+    pub base_price: Option<u32>,
+    /// Absent when the item can't be bought here
+    pub local_price: Option<u32>,
+}
+
+/// Market conditions for one item type at a location
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MarketCategoryData {
+    /// Item type; absent for items without one
+    pub category: Option<String>,
+    pub scarcity: ScarcityData,
+    /// Local price as a percentage of base price; absent when unavailable
+    pub price_percent: Option<u32>,
+    /// Why prices differ here, from the applicable modifiers
+    pub reasons: Vec<String>,
+    pub items: Vec<MarketItemData>,
+}
+
+/// What goods cost at one location, for the DM
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EconomyOverviewData {
+    pub location_id: String,
+    pub location_name: String,
+    /// Sheet field prices are counted in ("GP"), when the rule system has one
+    pub currency: Option<String>,
+    /// One entry per item type with items or modifiers here
+    pub categories: Vec<MarketCategoryData>,
+    /// Every modifier that could apply here, active or not
+    pub modifiers: Vec<MarketModifierData>,
+}
+
+// =============================================================================
+// Property Types
+// =============================================================================
+
+/// What a staff member does at a property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum StaffRoleData {
+    /// Runs the place in the owner's absence
+    Steward,
+    Guard,
+    Worker,
+    #[serde(other)]
+    Unknown,
+}
+
+/// An NPC to employ at a property
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyStaffInputData {
+    pub character_id: String,
+    pub role: StaffRoleData,
+    /// Their job in words: "barkeep"
+    #[serde(default)]
+    pub duties: String,
+}
+
+/// Fields of a property the DM can set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyInputData {
+    pub name: String,
+    /// The property is a whole location; give this or `region_id`
+    #[serde(default)]
+    pub location_id: Option<String>,
+    /// The property is one region; takes precedence over `location_id`
+    #[serde(default)]
+    pub region_id: Option<String>,
+    /// Cost per game day, in the world's currency
+    #[serde(default)]
+    pub upkeep_per_day: u32,
+    #[serde(default)]
+    pub staff: Vec<PropertyStaffInputData>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// An NPC employed at a property
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyStaffData {
+    pub character_id: String,
+    /// Absent when the NPC has since been deleted
+    pub character_name: Option<String>,
+    pub role: StaffRoleData,
+    pub duties: String,
+}
+
+/// A place a PC owns, with its upkeep as of the current game time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyData {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+    pub location_id: Option<String>,
+    pub region_id: Option<String>,
+    /// Name of the location or region; absent when it has been deleted
+    pub site_name: Option<String>,
+    pub upkeep_per_day: u32,
+    /// Whole game days since upkeep was last paid
+    pub days_owed: u32,
+    pub upkeep_due: u64,
+    pub staff: Vec<PropertyStaffData>,
+    pub notes: String,
+}
+
+/// What paying a property's upkeep did
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UpkeepPaymentData {
+    pub property: PropertyData,
+    /// Amount settled
+    pub paid: u64,
+    /// Sheet field it was taken from; absent when the rule system has no
+    /// currency and the DM settles it by hand
+    pub currency: Option<String>,
+}
+
+// =============================================================================
+// Companion Types
+// =============================================================================
+
+/// Fields of a companion the DM can set; absent fields keep their current
+/// value, or the default when hiring
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CompanionInputData {
+    /// 0-100; new hires start at 50
+    #[serde(default)]
+    pub loyalty: Option<u8>,
+    /// Pay per game day, in the world's currency
+    #[serde(default)]
+    pub wage_per_day: Option<u32>,
+    /// Whether the companion moves with the PC; new hires do
+    #[serde(default)]
+    pub follows_pc: Option<bool>,
+}
+
+/// An NPC bound to a PC, with wages owed as of the current game time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CompanionData {
+    pub id: String,
+    pub pc_id: String,
+    pub character_id: String,
+    /// Absent when the NPC has since been deleted
+    pub name: Option<String>,
+    pub portrait_asset: Option<String>,
+    pub loyalty: u8,
+    pub wage_per_day: u32,
+    pub follows_pc: bool,
+    /// Whole game days since wages were last paid
+    pub days_owed: u32,
+    pub wages_due: u64,
+}
+
+/// What paying a companion's wages did
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WagePaymentData {
+    pub companion: CompanionData,
+    /// Amount settled
+    pub paid: u64,
+    /// Sheet field it was taken from; absent when the rule system has no
+    /// currency and the DM settles it by hand
+    pub currency: Option<String>,
+}
+
+// =============================================================================
+// Injury Types
+// =============================================================================
+
+/// How bad an injury is (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum InjurySeverityData {
+    Minor,
+    Serious,
+    Critical,
+    #[serde(other)]
+    Unknown,
+}
+
+/// What an injury makes harder (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum InjuryCapabilityData {
+    Mobility,
+    Manipulation,
+    Senses,
+    Speech,
+    Stamina,
+    Mind,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Fields of an injury the DM can set; absent fields keep their current
+/// value, or the rule system's default when inflicting
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct InjuryInputData {
+    /// Required when inflicting
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Minor when inflicting without one
+    #[serde(default)]
+    pub severity: Option<InjurySeverityData>,
+    /// Stamina when inflicting without one
+    #[serde(default)]
+    pub capability: Option<InjuryCapabilityData>,
+    /// Game hours to heal; defaults to the rule system's time for the
+    /// severity
+    #[serde(default)]
+    pub recovery_hours: Option<u32>,
+    /// Challenge the PC rolls to treat it; an empty string clears it
+    #[serde(default)]
+    pub treatment_challenge_id: Option<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+/// An injury a PC carries, with its recovery as of the current game time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct InjuryData {
+    pub id: String,
+    pub pc_id: String,
+    pub name: String,
+    pub severity: InjurySeverityData,
+    pub capability: InjuryCapabilityData,
+    pub recovery_hours: u32,
+    /// Game hours left; 0 once healed
+    pub remaining_hours: u32,
+    pub treatment_challenge_id: Option<String>,
+    /// Absent when there is no treatment challenge or it has been deleted
+    pub treatment_challenge_name: Option<String>,
+    pub treated: bool,
+    /// Game time the injury was taken (RFC 3339)
+    pub injured_at: String,
+    /// Game time the injury healed (RFC 3339)
+    pub healed_at: Option<String>,
+    pub notes: String,
+}
+
+// =============================================================================
+// PC Mortality Types
+// =============================================================================
+
+/// How a PC died, as recorded by the DM
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PcDeathInputData {
+    pub cause: String,
+    /// Words to remember them by, written into the memorial
+    #[serde(default)]
+    pub epitaph: Option<String>,
+}
+
+/// A dead PC's memorial and whether they can still come back
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PcDeathData {
+    pub pc_id: String,
+    pub name: String,
+    pub cause: String,
+    pub epitaph: Option<String>,
+    /// Game time of death (RFC 3339)
+    pub died_at: String,
+    /// The memorial story event in the world's timeline
+    pub memorial_event_id: Option<String>,
+    /// Times the PC has already been resurrected
+    pub resurrections: u32,
+    /// The PC who took over, once one has been created
+    pub successor_id: Option<String>,
+    /// Why the rule system will not allow a resurrection now; absent when
+    /// it will
+    pub resurrection_blocked: Option<String>,
+    /// Currency a resurrection takes from the PC's sheet
+    pub resurrection_cost: u32,
+    /// Sheet field the cost comes from; absent when the rule system has no
+    /// currency and the DM settles it by hand
+    pub currency: Option<String>,
+}
+
+/// A new PC taking over from a fallen one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessorInputData {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub sheet_data: Option<serde_json::Value>,
+    /// Learn the lore the fallen PC knew
+    #[serde(default = "default_true")]
+    pub inherit_lore: bool,
+    /// Hear about the NPCs the fallen PC had seen
+    #[serde(default = "default_true")]
+    pub inherit_observations: bool,
+}
+
+/// The successor that was created and what they inherited
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessorData {
+    pub pc_id: String,
+    pub name: String,
+    pub predecessor_id: String,
+    /// Lore entries passed on
+    pub inherited_lore: u32,
+    /// NPC sightings passed on as hearsay
+    pub inherited_observations: u32,
+}
+
+// =============================================================================
+// PC Secret Types
+// =============================================================================
+
+/// How an NPC takes a secret coming out
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SecretReactionData {
+    pub npc_id: String,
+    /// Filled in by the server; ignored on input
+    #[serde(default)]
+    pub npc_name: Option<String>,
+    /// Change in the NPC's sentiment toward the PC, -1.0 to 1.0
+    pub sentiment_change: f32,
+}
+
+/// Fields of a secret the DM can set; absent fields keep their current value
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PcSecretInputData {
+    /// Required when creating
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub content: Option<String>,
+    /// Replaces the whole list
+    #[serde(default)]
+    pub reactions: Option<Vec<SecretReactionData>>,
+}
+
+/// A secret a PC keeps, seen only by the DM and the PC's player until revealed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PcSecretData {
+    pub id: String,
+    pub pc_id: String,
+    pub title: String,
+    pub content: String,
+    pub reactions: Vec<SecretReactionData>,
+    /// When the DM revealed it (RFC 3339)
+    pub revealed_at: Option<String>,
+}
+
+// =============================================================================
+// Letter Types
+// =============================================================================
+
+/// Which kind of character is at one end of a letter (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum CorrespondentKindData {
+    Pc,
+    Npc,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The writer or addressee of a letter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CorrespondentData {
+    pub kind: CorrespondentKindData,
+    pub id: String,
+    /// Filled in by the server; ignored on input
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// An in-world letter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LetterData {
+    pub id: String,
+    pub sender: CorrespondentData,
+    pub recipient: CorrespondentData,
+    pub subject: String,
+    pub body: String,
+    /// The letter this one answers
+    pub in_reply_to: Option<String>,
+    /// Game time it was sent (RFC 3339)
+    pub sent_at: String,
+    /// Game time it arrives (RFC 3339)
+    pub deliver_at: String,
+    pub delivered: bool,
+    pub read: bool,
+}
+
+/// A letter a PC writes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LetterInputData {
+    pub recipient: CorrespondentData,
+    #[serde(default)]
+    pub subject: String,
+    pub body: String,
+    /// A delivered letter the PC is answering
+    #[serde(default)]
+    pub in_reply_to: Option<String>,
+}
+
+// =============================================================================
+// Broadsheet Types
+// =============================================================================
+
+/// One story in a broadsheet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadsheetArticleData {
+    pub headline: String,
+    #[serde(default)]
+    pub body: String,
+}
+
+/// An issue of the world's broadsheet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadsheetData {
+    pub id: String,
+    pub issue_number: u32,
+    pub title: String,
+    pub articles: Vec<BroadsheetArticleData>,
+    /// Game time the issue's news starts from (RFC 3339)
+    pub period_start: String,
+    /// Game time the issue's news runs to (RFC 3339)
+    pub period_end: String,
+    /// False while the DM is still editing it
+    pub published: bool,
+}
+
+/// The DM's edits to a broadsheet draft
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadsheetInputData {
+    pub title: String,
+    #[serde(default)]
+    pub articles: Vec<BroadsheetArticleData>,
+}
+
+// =============================================================================
+// World Simulation Types
+// =============================================================================
+
+/// One thing that happened off-screen during a time skip
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SimulationChangeData {
+    /// A faction or threat clock moved on
+    ClockAdvanced {
+        clock_id: String,
+        clock_name: String,
+        segments: u8,
+    },
+    /// An NPC went where their schedule puts them
+    NpcMoved {
+        npc_id: String,
+        npc_name: String,
+        region_id: String,
+        region_name: String,
+    },
+    /// An NPC heard a piece of lore from someone who shares a haunt with them
+    RumorSpread {
+        lore_id: String,
+        lore_title: String,
+        npc_id: String,
+        npc_name: String,
+        told_by_id: String,
+        told_by_name: String,
+    },
+    /// A market modifier's price adjustment drifted
+    PriceDrift {
+        modifier_id: String,
+        reason: String,
+        from_percent: i32,
+        to_percent: i32,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// What the world got up to during a time skip, awaiting the DM
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WorldSimulationData {
+    pub id: String,
+    /// Game time the skip started from (RFC 3339)
+    pub from_game_time: String,
+    /// Game time the skip ended at (RFC 3339)
+    pub to_game_time: String,
+    pub days: i64,
+    pub changes: Vec<SimulationChangeData>,
+    /// The changes, one line each
+    pub changelog: Vec<String>,
+}
+
+// =============================================================================
+// Session Zero Types
+// =============================================================================
+
+/// Where a session zero world's story starts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct StartingLocationData {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The region inside the location where PCs arrive
+    pub region_name: String,
+    #[serde(default)]
+    pub region_description: Option<String>,
+}
+
+/// A one-line idea for an NPC the LLM fleshes out into a draft
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NpcSeedData {
+    /// Left to the LLM when absent
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Who they are ("a smuggler who owes the harbormaster")
+    pub concept: String,
+}
+
+/// The group's choices from a session zero, made into a world in one pass
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SessionZeroData {
+    pub name: String,
+    /// The campaign pitch
+    #[serde(default)]
+    pub description: Option<String>,
+    /// How the campaign should feel ("grim and grounded", "swashbuckling")
+    #[serde(default)]
+    pub tone: Option<String>,
+    pub rule_system: crate::RuleSystemVariant,
+    #[serde(default)]
+    pub content_safety: ContentSafetyData,
+    /// Game date the campaign opens on (YYYY-MM-DD); today when absent
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Hour of the opening day (0-23)
+    #[serde(default)]
+    pub start_hour: Option<u32>,
+    #[serde(default)]
+    pub time_mode: TimeMode,
+    pub starting_location: StartingLocationData,
+    #[serde(default)]
+    pub npc_seeds: Vec<NpcSeedData>,
+}
+
+// =============================================================================
+// Location Hierarchy Types
+// =============================================================================
+
+/// How large a place a location is (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum LocationScaleData {
+    Continent,
+    Kingdom,
+    City,
+    District,
+    Building,
+    Room,
+    #[serde(other)]
+    Unknown,
+}
+
+/// One step of the path down to a location, outermost first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LocationBreadcrumbData {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub scale: Option<LocationScaleData>,
+}
+
+/// A location inside another, with what it in turn contains
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SubLocationData {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub scale: Option<LocationScaleData>,
+    pub parent_id: String,
+    /// Levels below the listed location (its children are 1)
+    pub depth: u32,
+    /// Locations directly inside this one
+    pub child_count: u32,
+    /// Locations anywhere inside this one
+    pub descendant_count: u32,
+    /// Regions in this location and everything inside it
+    pub region_count: u32,
+}
+
+/// Travel time between two locations through the hierarchy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LocationTravelData {
+    pub from_location_id: String,
+    pub to_location_id: String,
+    /// Game minutes the journey takes; absent when the locations share no
+    /// outer location
+    pub minutes: Option<u32>,
+    /// The innermost location containing both
+    pub via_location_id: Option<String>,
+    pub via_location_name: Option<String>,
+}
+
+// =============================================================================
+// World Map Types
+// =============================================================================
+
+/// Where a location's pin sits on the world map, as percentages of the image
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorldMapPositionData {
+    /// Percent of image width (0-100)
+    pub x: f32,
+    /// Percent of image height (0-100)
+    pub y: f32,
+}
+
+/// A location pinned to the world map
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorldMapPinData {
+    pub location_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub parent_location_id: Option<String>,
+    pub position: WorldMapPositionData,
+}
+
+/// The world overview map and the locations pinned to it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorldMapData {
+    #[serde(default)]
+    pub map_asset: Option<String>,
+    #[serde(default)]
+    pub pins: Vec<WorldMapPinData>,
+}
+
+// =============================================================================
+// Progress Clock Types
+// =============================================================================
+
+/// What a progress clock tracks (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ClockKindData {
+    Faction,
+    Quest,
+    Threat,
+    Project,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Progress clock for wire transfer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressClockData {
+    pub id: String,
+    pub world_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub kind: ClockKindData,
+    /// Faction, quest, or threat this clock is linked to
+    #[serde(default)]
+    pub subject: Option<String>,
+    pub segments: u8,
+    pub filled: u8,
+    pub visible_to_players: bool,
+    pub is_complete: bool,
+    /// PC this clock is personal to (only they and DMs see it)
+    #[serde(default)]
+    pub owner_pc_id: Option<String>,
+    /// Party clock this personal objective works against
+    #[serde(default)]
+    pub conflicts_with_clock_id: Option<String>,
+}
+
+// =============================================================================
+// Tag Types
+// =============================================================================
+
+/// How many entities in a world carry a tag
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TagUsageData {
+    pub tag: String,
+    pub count: u32,
+}
+
+/// A user's saved tag filter for one entity list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SavedFilterData {
+    pub id: String,
+    pub name: String,
+    pub entity_type: crate::responses::EntityType,
+    pub tags: Vec<String>,
+}
+
+// =============================================================================
+// Map Marker Types
+// =============================================================================
+
+/// Entity a map pin links to (wire format)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", content = "id", rename_all = "camelCase")]
+pub enum MarkerLinkData {
+    Character(String),
+    Location(String),
+    Region(String),
+    Item(String),
+    NarrativeEvent(String),
+}
+
+/// Where a DM marker is pinned (wire format)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MarkerPinData {
+    pub location_id: String,
+    /// Region backdrop the pin is on; `None` means the location map
+    #[serde(default)]
+    pub region_id: Option<String>,
+    /// Percent of image width (0-100)
+    pub x: f32,
+    /// Percent of image height (0-100)
+    pub y: f32,
+    #[serde(default)]
+    pub link: Option<MarkerLinkData>,
+}
+
+/// A DM marker pinned to a map, for wire transfer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MapMarkerData {
+    /// Story event ID of the marker
+    pub id: String,
+    pub world_id: String,
+    pub title: String,
+    #[serde(default)]
+    pub note: String,
+    pub pin: MarkerPinData,
+    pub visible_to_players: bool,
+}
+
+// =============================================================================
+// Region Hotspot Types
+// =============================================================================
+
+/// A hotspot polygon vertex, as percent of the backdrop (0-100)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HotspotPointData {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// What clicking a hotspot does (wire format)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum HotspotTargetData {
+    #[serde(rename_all = "camelCase")]
+    Interaction { interaction_id: String },
+    #[serde(rename_all = "camelCase")]
+    Item { item_id: String },
+    #[serde(rename_all = "camelCase")]
+    Exit { region_id: String },
+    #[serde(rename_all = "camelCase")]
+    LocationExit {
+        location_id: String,
+        #[serde(default)]
+        arrival_region_id: Option<String>,
+    },
+}
+
+/// A clickable area on a region backdrop, for wire transfer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct HotspotData {
+    pub label: String,
+    pub points: Vec<HotspotPointData>,
+    pub target: HotspotTargetData,
+}
+
+/// Kind of top-down map generated for a region (wire format)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RegionMapKindData {
+    /// Gridded tactical map for combat encounters
+    #[default]
+    BattleMap,
+    /// Overview of the region's layout and landmarks
+    RegionLayout,
+    #[serde(other)]
+    Unknown,
+}
+
+/// How much light a region has to see by (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum LightLevelData {
+    Bright,
+    Dim,
+    Dark,
+    #[serde(other)]
+    Unknown,
+}
+
+/// How well noise passes along a region connection (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SoundCarryData {
+    Open,
+    Muffled,
+    Blocked,
+    #[serde(other)]
+    Unknown,
+}
+
+/// How loud a noise is (wire format)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum LoudnessData {
+    Loud,
+    Deafening,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A region a noise carried into, and who heard it (DM view)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NoiseHeardInData {
+    pub region_id: String,
+    pub region_name: String,
+    pub muffled: bool,
+    /// Staged NPCs, hidden ones included
+    #[serde(default)]
+    pub npc_names: Vec<String>,
+    #[serde(default)]
+    pub pc_names: Vec<String>,
+}
+
+// =============================================================================
+// Trade Types
+// =============================================================================
+
+/// An item named in a trade offer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TradeItemData {
+    pub item_id: String,
+    pub name: String,
+}
+
+/// What one party hands over in a trade
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TradeSideData {
+    #[serde(default)]
+    pub items: Vec<TradeItemData>,
+    #[serde(default)]
+    pub currency: u32,
+}
+
+/// A PC-to-PC trade offer for wire transfer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TradeOfferData {
+    pub trade_id: String,
+    pub from_pc_id: String,
+    pub from_pc_name: String,
+    pub to_pc_id: String,
+    pub to_pc_name: String,
+    /// What the offering PC gives
+    pub offered: TradeSideData,
+    /// What the offering PC asks for in return
+    pub requested: TradeSideData,
+    /// Sheet field holding currency (e.g. "GP"); None if the system has none
+    #[serde(default)]
+    pub currency_label: Option<String>,
+    /// Unix timestamp (seconds) after which the offer lapses
+    pub expires_at: i64,
+}
+
+/// Why an open trade offer closed without completing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TradeClosedReason {
+    Declined,
+    Cancelled,
+    Expired,
+    /// One side no longer had what they offered when it was accepted
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+// =============================================================================
+// Journey Types
+// =============================================================================
+
+/// Something that can happen to travellers crossing a location
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EncounterEntryData {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Relative chance against the table's other entries
+    pub weight: u32,
+}
+
+/// A location's encounter table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EncounterTableData {
+    /// Game minutes between checks
+    pub check_interval_minutes: u32,
+    /// Chance of an encounter at each check, 0-100
+    pub chance_percent: u8,
+    pub entries: Vec<EncounterEntryData>,
+}
+
+/// The encounter a journey stopped for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JourneyEncounterData {
+    /// Game minutes into the journey
+    pub at_minute: u32,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// A PC's fast-travel journey
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JourneyData {
+    pub journey_id: String,
+    pub pc_id: String,
+    pub pc_name: String,
+    pub from_location_id: String,
+    pub from_location_name: String,
+    pub to_location_id: String,
+    pub to_location_name: String,
+    /// Game minutes the whole journey takes
+    pub total_minutes: u32,
+    /// Game minutes travelled so far
+    pub elapsed_minutes: u32,
+    #[serde(default)]
+    pub encounter: Option<JourneyEncounterData>,
+}
+
+/// How the DM lets a stopped journey go on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JourneyDecision {
+    /// Carry on, rolling for further encounters
+    Continue,
+    /// Carry on and arrive without further encounters
+    Arrive,
+    /// Head back to where the journey started
+    TurnBack,
+    #[serde(other)]
+    Unknown,
+}
+
+// =============================================================================
+// Lock Types
+// =============================================================================
+
+/// What a locked connection needs to open; `None` in place of this leaves
+/// the connection unlocked
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConnectionLockData {
+    /// Shown to players in place of the way through
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Items that open the lock
+    #[serde(default)]
+    pub key_item_ids: Vec<String>,
+    /// Challenge whose success opens the lock (e.g. lockpicking)
+    #[serde(default)]
+    pub challenge_id: Option<String>,
+}
+
+/// A way out of where a PC stands
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LockedWayData {
+    /// A connection from the PC's region to another region
+    Region { region_id: String },
+    /// A connection from the PC's location to another location
+    Location { location_id: String },
+    #[serde(other)]
+    Unknown,
+}
+
+/// What opened a lock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum UnlockMethodData {
+    Key,
+    Challenge,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A connection a PC just unlocked
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UnlockedConnectionData {
+    pub pc_id: String,
+    pub pc_name: String,
+    /// The region or location the way leads out of
+    pub from_id: String,
+    pub to: LockedWayData,
+    pub to_name: String,
+    pub method: UnlockMethodData,
+    /// Name of the key item or challenge that opened it
+    pub opened_with: String,
+}
+
+// =============================================================================
+// Rule System Switch Types
+// =============================================================================
+
+/// Where a challenge's skill ends up when its world switches rule system
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChallengeSkillRemapData {
+    pub challenge_id: String,
+    pub challenge_name: String,
+    /// The skill (or stat) the challenge checks now
+    pub from_skill: String,
+    /// The new system's skill it will check; `None` when the alias table has
+    /// no match and the DM didn't pick one, so it keeps `from_skill`
+    #[serde(default)]
+    pub to_skill: Option<String>,
+}
+
+/// The DM's pick of skill for one challenge in a rule system switch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SkillOverrideData {
+    pub challenge_id: String,
+    pub skill: String,
+}
+
+/// How a PC's character sheet carries over to the new rule system
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SheetConversionData {
+    pub pc_id: String,
+    pub pc_name: String,
+    /// Values that found a field on the new sheet
+    pub carried: u32,
+    /// Fields with no counterpart on the new sheet, for the DM to review;
+    /// the old sheet is kept as a version
+    #[serde(default)]
+    pub unconverted: Vec<String>,
+}
+
+/// What switching a world to another rule system does, or did
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleSystemSwitchData {
+    pub world_id: String,
+    pub from_variant: crate::RuleSystemVariant,
+    pub to_variant: crate::RuleSystemVariant,
+    /// The character sheet schema the world's sheets follow afterwards
+    #[serde(default)]
+    pub system_id: String,
+    /// The preset skills the world gets (custom skills are kept)
+    pub skills: Vec<String>,
+    /// Challenges that check a skill or stat
+    pub challenges: Vec<ChallengeSkillRemapData>,
+    /// PCs with a filled-in sheet; empty when the sheet schema doesn't change
+    #[serde(default)]
+    pub sheets: Vec<SheetConversionData>,
+}
+
+// =============================================================================
+// Challenge History Types
+// =============================================================================
+
+/// One resolved challenge in a world's history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChallengeResolutionData {
+    pub id: String,
+    pub challenge_id: String,
+    pub challenge_name: String,
+    #[serde(default)]
+    pub skill_name: Option<String>,
+    pub difficulty: String,
+    pub pc_id: String,
+    pub character_name: String,
+    pub roll: i32,
+    pub modifier: i32,
+    pub total: i32,
+    #[serde(default)]
+    pub roll_breakdown: Option<String>,
+    /// "critical_success", "success", "partial", "failure" or "critical_failure"
+    pub outcome: String,
+    pub outcome_description: String,
+    /// Whether the DM rewrote the outcome description
+    #[serde(default)]
+    pub edited: bool,
+    /// The outcome's triggers as they ran, e.g. "Give item: Rusty Key"
+    #[serde(default)]
+    pub triggers: Vec<String>,
+    /// RFC 3339
+    pub resolved_at: String,
+}
+
+/// Totals over the returned resolutions
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChallengeHistoryStatsData {
+    pub resolved: u32,
+    /// Successes of any kind, partial ones included
+    pub succeeded: u32,
+    pub critical_successes: u32,
+    pub critical_failures: u32,
+    #[serde(default)]
+    pub average_roll: Option<f32>,
+}
+
+/// A world's challenge history, newest first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChallengeHistoryData {
+    pub resolutions: Vec<ChallengeResolutionData>,
+    pub stats: ChallengeHistoryStatsData,
+}
+
+// =============================================================================
+// Co-DM Types
+// =============================================================================
+
+/// One decision the AI co-DM made on an approval, for review
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CoDmReviewData {
+    pub approval_id: String,
+    pub npc_name: String,
+    #[serde(default)]
+    pub player_dialogue: Option<String>,
+    pub proposed_dialogue: String,
+    /// "accepted", "modified", "rejected" or "escalated"
+    pub outcome: String,
+    /// What players saw, when the response got through
+    #[serde(default)]
+    pub final_dialogue: Option<String>,
+    #[serde(default)]
+    pub approved_tools: Vec<String>,
+    /// Why the approval was left for the DM, when escalated
+    #[serde(default)]
+    pub escalation_reason: Option<String>,
+    /// The co-DM's explanation
+    #[serde(default)]
+    pub reasoning: String,
+    /// RFC 3339
+    pub reviewed_at: String,
+}
+
+// =============================================================================
+// Tutorial Types
+// =============================================================================
+
+/// A tutorial step for wire transfer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialStepData {
+    pub id: String,
+    pub title: String,
+    pub instructions: String,
+    #[serde(default)]
+    pub completion_text: Option<String>,
+}
+
+/// Where a PC is in a world's tutorial
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialStatusData {
+    pub world_id: String,
+    pub pc_id: String,
+    /// Number of steps completed so far
+    pub completed_steps: u32,
+    pub total_steps: u32,
+    /// Step the PC is working on (`None` once the tutorial is finished)
+    #[serde(default)]
+    pub current_step: Option<TutorialStepData>,
+    /// Step the PC just finished, when this status follows an action
+    #[serde(default)]
+    pub just_completed: Option<TutorialStepData>,
+}
+
+// =============================================================================
+// Dice Types
+// =============================================================================
+
+/// A server-side dice roll for wire transfer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DiceRollData {
+    /// Normalized formula that was rolled (e.g. "2d6+1")
+    pub formula: String,
+    pub individual_rolls: Vec<i32>,
+    pub dice_total: i32,
+    pub modifier: i32,
+    pub total: i32,
+    /// Human-readable breakdown (e.g. "1d20(14) + 5 = 19")
+    pub breakdown: String,
+    /// Player character who rolled, if any
+    #[serde(default)]
+    pub pc_id: Option<String>,
+    /// Display name of whoever rolled
+    pub roller_name: String,
+    /// Unix timestamp (seconds) of the roll
+    pub rolled_at: i64,
+    /// Every term with every die rolled, including dropped and exploded dice
+    #[serde(default)]
+    pub terms: Vec<DiceTermData>,
+    /// Successes counted by dice pools (e.g. "5d10>=8"), if any
+    #[serde(default)]
+    pub successes: Option<u32>,
+}
+
+/// One term of a dice roll (e.g. "4d6kh3" or "2")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DiceTermData {
+    pub notation: String,
+    /// Whether the term is subtracted
+    pub negative: bool,
+    /// Empty for constant terms
+    pub dice: Vec<DiceDieData>,
+    /// Kept dice summed, successes counted, or the constant
+    pub value: i32,
+}
+
+/// A single die of a roll
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DiceDieData {
+    pub value: i32,
+    /// Whether the die counts toward its term
+    pub kept: bool,
+    /// Whether an exploding die added this one
+    pub exploded: bool,
+}
+
 // =============================================================================
 // Visual State Types
 // =============================================================================
 
 /// Time of day period (wire format, matches domain::TimeOfDay)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum TimeOfDayData {
     Morning,
@@ -510,6 +3187,7 @@ impl From<TimeOfDayData> for wrldbldr_domain::TimeOfDay {
 
 /// Activation rule for visual states (wire format)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase", tag = "type")]
 pub enum ActivationRuleData {
     Always,
@@ -548,6 +3226,7 @@ pub enum ActivationRuleData {
 
 /// How rules are combined (wire format)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum ActivationLogicData {
     All,
@@ -565,6 +3244,7 @@ impl Default for ActivationLogicData {
 
 /// Location state for wire transfer
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct LocationStateData {
     pub id: String,
@@ -588,6 +3268,7 @@ pub struct LocationStateData {
 
 /// Region state for wire transfer
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct RegionStateData {
     pub id: String,
@@ -610,6 +3291,7 @@ pub struct RegionStateData {
 
 /// Resolved state info for staging (lightweight)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ResolvedStateInfoData {
     pub id: String,
@@ -624,6 +3306,7 @@ pub struct ResolvedStateInfoData {
 
 /// How visual state was resolved
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum VisualStateSourceData {
     #[default]
@@ -637,6 +3320,7 @@ pub enum VisualStateSourceData {
 
 /// Complete resolved visual state for staging
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ResolvedVisualStateData {
     #[serde(default)]
@@ -647,6 +3331,7 @@ pub struct ResolvedVisualStateData {
 
 /// State option for DM selection
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct StateOptionData {
     pub id: String,
@@ -664,6 +3349,7 @@ pub struct StateOptionData {
 
 /// Complete schema describing all available trigger types for the visual builder
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TriggerSchema {
     /// All available trigger types
@@ -674,6 +3360,7 @@ pub struct TriggerSchema {
 
 /// Schema for a single trigger type
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TriggerTypeSchema {
     /// Internal type name (e.g., "PlayerEntersLocation")
@@ -690,6 +3377,7 @@ pub struct TriggerTypeSchema {
 
 /// Category for grouping trigger types in UI
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum TriggerCategory {
     /// Location-based triggers
@@ -731,6 +3419,7 @@ impl TriggerCategory {
 
 /// Schema for a single field within a trigger type
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TriggerFieldSchema {
     /// Field name (matches JSON key)
@@ -751,6 +3440,7 @@ pub struct TriggerFieldSchema {
 
 /// Data type for a trigger field
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum TriggerFieldType {
     /// Plain text string
@@ -786,6 +3476,7 @@ pub enum TriggerFieldType {
 
 /// Option for trigger logic selection
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TriggerLogicOption {
     /// Value to use (e.g., "all", "any", "atLeast")
@@ -0,0 +1,458 @@
+//! Property-based serde tests for the wire protocol.
+//!
+//! Values are generated from the protocol's own JSON Schema (the `schema`
+//! feature), with one strategy per variant of `ClientMessage`,
+//! `ServerMessage` and `RequestPayload`, so every variant is exercised. Each
+//! generated value must decode, and re-encoding it must reach a fixed point.
+//! Malformed input - truncated, corrupted or arbitrary JSON - must be
+//! rejected without panicking, and unknown tags and enum values must fall
+//! back to `Unknown`.
+
+use std::collections::BTreeSet;
+use std::sync::OnceLock;
+
+use proptest::prelude::*;
+use proptest::strategy::{BoxedStrategy, Union};
+use proptest::test_runner::{Config, TestCaseError, TestRunner};
+use schemars::generate::SchemaSettings;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use wrldbldr_protocol::{ClientMessage, RequestPayload, ServerMessage};
+
+/// Cases run per message variant
+const CASES_PER_VARIANT: u32 = 16;
+
+/// Cases run by the fuzz properties
+const FUZZ_CASES: u32 = 512;
+
+/// `$ref` depth after which arrays are left empty and optional fields out
+const MAX_DEPTH: u32 = 3;
+
+/// Schema definitions for every root message type
+fn definitions() -> &'static Map<String, Value> {
+    static DEFINITIONS: OnceLock<Map<String, Value>> = OnceLock::new();
+    DEFINITIONS.get_or_init(|| {
+        let mut generator = SchemaSettings::draft2020_12().into_generator();
+        generator.subschema_for::<ClientMessage>();
+        generator.subschema_for::<ServerMessage>();
+        generator.subschema_for::<RequestPayload>();
+        generator.take_definitions(true)
+    })
+}
+
+fn definition(name: &str) -> &'static Value {
+    definitions()
+        .get(name)
+        .unwrap_or_else(|| panic!("no schema definition for {name}"))
+}
+
+/// The variant schemas of a tagged enum, labelled by their tag
+fn variants(root: &str, tag: &str) -> Vec<(String, &'static Value)> {
+    definition(root)
+        .get("oneOf")
+        .and_then(Value::as_array)
+        .unwrap_or_else(|| panic!("{root} is not a tagged enum"))
+        .iter()
+        .map(|variant| {
+            let label = variant
+                .pointer(&format!("/properties/{tag}/const"))
+                .and_then(Value::as_str)
+                .unwrap_or("?")
+                .to_string();
+            (label, variant)
+        })
+        .collect()
+}
+
+fn known_tags(root: &str, tag: &str) -> BTreeSet<String> {
+    variants(root, tag)
+        .into_iter()
+        .map(|(label, _)| label)
+        .collect()
+}
+
+// =============================================================================
+// Value generation from JSON Schema
+// =============================================================================
+
+/// Small arbitrary JSON, for schemas that accept anything
+fn any_json() -> BoxedStrategy<Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        any::<i32>().prop_map(Value::from),
+        "\\PC{0,8}".prop_map(Value::String),
+    ];
+    leaf.prop_recursive(3, 16, 4, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..4).prop_map(Value::Array),
+            prop::collection::btree_map("[a-z_]{1,8}", inner, 0..4)
+                .prop_map(|map| Value::Object(map.into_iter().collect())),
+        ]
+    })
+    .boxed()
+}
+
+/// Enum values that are a `#[serde(other)]` fallback
+fn is_fallback(value: &Value) -> bool {
+    matches!(value.as_str(), Some("unknown" | "Unknown"))
+}
+
+fn bound(object: &Map<String, Value>, key: &str) -> Option<i64> {
+    object.get(key).and_then(|v| {
+        v.as_i64()
+            .or_else(|| v.as_u64().map(|u| u.min(i64::MAX as u64) as i64))
+            .or_else(|| v.as_f64().map(|f| f as i64))
+    })
+}
+
+fn integer(object: &Map<String, Value>) -> BoxedStrategy<Value> {
+    let (format_min, format_max) = match object.get("format").and_then(Value::as_str) {
+        Some("int8") => (i8::MIN as i64, i8::MAX as i64),
+        Some("int16") => (i16::MIN as i64, i16::MAX as i64),
+        Some("uint8") => (0, u8::MAX as i64),
+        Some("uint16") => (0, u16::MAX as i64),
+        Some(format) if format.starts_with('u') => (0, u32::MAX as i64),
+        _ => (i32::MIN as i64, i32::MAX as i64),
+    };
+    let min = bound(object, "minimum").map_or(format_min, |m| m.max(format_min));
+    let max = bound(object, "maximum").map_or(format_max, |m| m.min(format_max));
+    (min..=max).prop_map(Value::from).boxed()
+}
+
+fn number(object: &Map<String, Value>) -> BoxedStrategy<Value> {
+    let min = object
+        .get("minimum")
+        .and_then(Value::as_f64)
+        .unwrap_or(-1.0e6);
+    let max = object
+        .get("maximum")
+        .and_then(Value::as_f64)
+        .unwrap_or(1.0e6);
+    (min..=max).prop_map(Value::from).boxed()
+}
+
+fn string(object: &Map<String, Value>) -> BoxedStrategy<Value> {
+    match object.get("format").and_then(Value::as_str) {
+        Some("uuid") => any::<u128>()
+            .prop_map(|bits| Value::String(uuid::Uuid::from_u128(bits).to_string()))
+            .boxed(),
+        Some("date-time") => (0i64..4_102_444_800)
+            .prop_map(|secs| {
+                let time = chrono::DateTime::from_timestamp(secs, 0).unwrap_or_default();
+                Value::String(time.to_rfc3339())
+            })
+            .boxed(),
+        Some("date") => (0i64..4_102_444_800)
+            .prop_map(|secs| {
+                let time = chrono::DateTime::from_timestamp(secs, 0).unwrap_or_default();
+                Value::String(time.date_naive().to_string())
+            })
+            .boxed(),
+        _ => {
+            let min = bound(object, "minLength").unwrap_or(0);
+            let max = bound(object, "maxLength").unwrap_or(12).max(min);
+            proptest::string::string_regex(&format!("\\PC{{{min},{max}}}"))
+                .expect("valid string pattern")
+                .prop_map(Value::String)
+                .boxed()
+        }
+    }
+}
+
+fn array(object: &Map<String, Value>, depth: u32) -> BoxedStrategy<Value> {
+    if let Some(items) = object.get("prefixItems").and_then(Value::as_array) {
+        let items: Vec<BoxedStrategy<Value>> =
+            items.iter().map(|item| value(item, depth)).collect();
+        return items.prop_map(Value::Array).boxed();
+    }
+    let min = bound(object, "minItems").unwrap_or(0) as usize;
+    let max = if depth >= MAX_DEPTH {
+        min
+    } else {
+        bound(object, "maxItems").map_or(min + 2, |max| max as usize)
+    };
+    let Some(items) = object.get("items") else {
+        return Just(Value::Array(Vec::new())).boxed();
+    };
+    prop::collection::vec(value(items, depth), min..=max.max(min))
+        .prop_map(Value::Array)
+        .boxed()
+}
+
+fn object(object: &Map<String, Value>, depth: u32) -> BoxedStrategy<Value> {
+    let properties = object.get("properties").and_then(Value::as_object);
+    let additional = object.get("additionalProperties").filter(|v| v.is_object());
+
+    let Some(properties) = properties.filter(|p| !p.is_empty()) else {
+        let Some(additional) = additional.filter(|_| depth < MAX_DEPTH) else {
+            return Just(Value::Object(Map::new())).boxed();
+        };
+        let keys = match object.get("propertyNames").and_then(Value::as_object) {
+            Some(names) => string(names),
+            None => "[a-z_]{1,8}".prop_map(Value::String).boxed(),
+        };
+        let keys = keys.prop_map(|key| key.as_str().unwrap_or_default().to_string());
+        return prop::collection::btree_map(keys, value(additional, depth), 0..3)
+            .prop_map(|map| Value::Object(map.into_iter().collect()))
+            .boxed();
+    };
+
+    let required: BTreeSet<&str> = object
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let fields: Vec<BoxedStrategy<Option<(String, Value)>>> = properties
+        .iter()
+        .map(|(name, schema)| {
+            let is_required = required.contains(name.as_str());
+            let name = name.clone();
+            let field = value(schema, depth).prop_map(move |v| (name.clone(), v));
+            if is_required {
+                field.prop_map(Some).boxed()
+            } else if depth >= MAX_DEPTH {
+                Just(None).boxed()
+            } else {
+                prop::option::of(field).boxed()
+            }
+        })
+        .collect();
+    fields
+        .prop_map(|fields| Value::Object(fields.into_iter().flatten().collect()))
+        .boxed()
+}
+
+/// Strategy for values matching `schema`
+fn value(schema: &Value, depth: u32) -> BoxedStrategy<Value> {
+    let Some(object) = schema.as_object() else {
+        return if schema == &Value::Bool(false) {
+            Just(Value::Null).boxed()
+        } else {
+            any_json()
+        };
+    };
+
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+        let name = reference.trim_start_matches("#/$defs/");
+        let referenced = value(definition(name), depth + 1);
+        if !object.contains_key("properties") {
+            return referenced;
+        }
+        // Newtype variants: the tag sits beside a reference to the payload
+        let mut siblings = object.clone();
+        siblings.remove("$ref");
+        return (referenced, value(&Value::Object(siblings), depth))
+            .prop_map(|(mut merged, siblings)| {
+                if let (Value::Object(merged), Value::Object(siblings)) = (&mut merged, siblings) {
+                    merged.extend(siblings);
+                }
+                merged
+            })
+            .boxed();
+    }
+    if let Some(constant) = object.get("const") {
+        return Just(constant.clone()).boxed();
+    }
+    if let Some(values) = object.get("enum").and_then(Value::as_array) {
+        let known = prop::sample::select(values.clone()).boxed();
+        if !values.iter().any(is_fallback) {
+            return known;
+        }
+        // Values this build doesn't know must decode as the fallback
+        let taken: BTreeSet<String> = values
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect();
+        let unseen = "[a-z_]{1,16}"
+            .prop_filter("a known value", move |v| !taken.contains(v))
+            .prop_map(Value::String);
+        return prop_oneof![3 => known, 1 => unseen].boxed();
+    }
+    for key in ["oneOf", "anyOf"] {
+        if let Some(variants) = object.get(key).and_then(Value::as_array) {
+            let nullable = variants
+                .iter()
+                .any(|v| v.get("type") == Some(&Value::from("null")));
+            if nullable && depth >= MAX_DEPTH {
+                return Just(Value::Null).boxed();
+            }
+            return Union::new(variants.iter().map(|v| value(v, depth))).boxed();
+        }
+    }
+
+    let types: Vec<&str> = match object.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+        None if object.contains_key("properties") => vec!["object"],
+        _ => return any_json(),
+    };
+    if types.contains(&"null") && depth >= MAX_DEPTH {
+        return Just(Value::Null).boxed();
+    }
+    Union::new(types.into_iter().map(|t| match t {
+        "null" => Just(Value::Null).boxed(),
+        "boolean" => any::<bool>().prop_map(Value::Bool).boxed(),
+        "integer" => integer(object),
+        "number" => number(object),
+        "string" => string(object),
+        "array" => array(object, depth),
+        _ => self::object(object, depth),
+    }))
+    .boxed()
+}
+
+// =============================================================================
+// Properties
+// =============================================================================
+
+fn runner(cases: u32) -> TestRunner {
+    TestRunner::new(Config {
+        cases,
+        failure_persistence: None,
+        ..Config::default()
+    })
+}
+
+/// A generated value decodes, and re-encoding it reaches a fixed point
+fn round_trip<T: Serialize + DeserializeOwned>(
+    value: &Value,
+    tag: &str,
+) -> Result<(), TestCaseError> {
+    let decoded: T = serde_json::from_value(value.clone())
+        .map_err(|e| TestCaseError::fail(format!("generated value did not decode: {e}")))?;
+    let encoded = serde_json::to_value(&decoded)
+        .map_err(|e| TestCaseError::fail(format!("decoded value did not encode: {e}")))?;
+    prop_assert_eq!(encoded.get(tag), value.get(tag), "tag changed");
+
+    let redecoded: T = serde_json::from_value(encoded.clone())
+        .map_err(|e| TestCaseError::fail(format!("re-encoded value did not decode: {e}")))?;
+    let reencoded = serde_json::to_value(&redecoded)
+        .map_err(|e| TestCaseError::fail(format!("re-decoded value did not encode: {e}")))?;
+    prop_assert_eq!(reencoded, encoded);
+    Ok(())
+}
+
+/// Round-trip every variant of `root`, reporting all failing variants
+fn round_trip_every_variant<T: Serialize + DeserializeOwned>(root: &str, tag: &str) {
+    let mut failures = Vec::new();
+    for (label, schema) in variants(root, tag) {
+        let strategy = value(schema, 0);
+        if let Err(e) = runner(CASES_PER_VARIANT).run(&strategy, |v| round_trip::<T>(&v, tag)) {
+            failures.push(format!("{root}::{label}: {e}"));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
+
+#[test]
+fn every_client_message_variant_round_trips() {
+    round_trip_every_variant::<ClientMessage>("ClientMessage", "type");
+}
+
+#[test]
+fn every_server_message_variant_round_trips() {
+    round_trip_every_variant::<ServerMessage>("ServerMessage", "type");
+}
+
+#[test]
+fn every_request_payload_variant_round_trips() {
+    round_trip_every_variant::<RequestPayload>("RequestPayload", "group");
+}
+
+/// Serialized valid messages with one corruption applied
+fn corrupted(root: &'static str) -> impl Strategy<Value = String> {
+    let json = value(definition(root), 0).prop_map(|v| v.to_string());
+    let junk = prop::sample::select(vec![
+        "", "{", "}", "[", "]", ",", ":", "\"", "\\", "null", "-", "1e999", "\u{0}", "\u{fffd}",
+    ]);
+    (
+        json,
+        any::<prop::sample::Index>(),
+        any::<prop::sample::Index>(),
+        junk,
+    )
+        .prop_map(|(json, at, end, junk)| {
+            let bytes = json.as_bytes();
+            let at = at.index(bytes.len() + 1);
+            let end = at + end.index(bytes.len() - at + 1);
+            let mut out = bytes[..at].to_vec();
+            out.extend_from_slice(junk.as_bytes());
+            out.extend_from_slice(&bytes[end..]);
+            String::from_utf8_lossy(&out).into_owned()
+        })
+}
+
+/// Decoding must return (Ok or Err) rather than panic
+fn never_panics<T: DeserializeOwned>(strategy: impl Strategy<Value = String>) {
+    runner(FUZZ_CASES)
+        .run(&strategy, |input| {
+            let _ = serde_json::from_str::<T>(&input);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn corrupted_messages_are_rejected_without_panicking() {
+    never_panics::<ClientMessage>(corrupted("ClientMessage"));
+    never_panics::<ServerMessage>(corrupted("ServerMessage"));
+    never_panics::<RequestPayload>(corrupted("RequestPayload"));
+}
+
+#[test]
+fn arbitrary_tagged_json_is_rejected_without_panicking() {
+    let tags: Vec<String> = known_tags("ClientMessage", "type")
+        .into_iter()
+        .chain(known_tags("ServerMessage", "type"))
+        .collect();
+    let groups: Vec<String> = known_tags("RequestPayload", "group").into_iter().collect();
+
+    let message = (prop::sample::select(tags), any_json()).prop_map(|(tag, body)| {
+        let mut object = match body {
+            Value::Object(object) => object,
+            other => Map::from_iter([("value".to_string(), other)]),
+        };
+        object.insert("type".to_string(), Value::String(tag));
+        Value::Object(object).to_string()
+    });
+    let request = (prop::sample::select(groups), any_json()).prop_map(|(group, payload)| {
+        serde_json::json!({ "group": group, "payload": payload }).to_string()
+    });
+
+    never_panics::<ClientMessage>(message.clone());
+    never_panics::<ServerMessage>(message);
+    never_panics::<RequestPayload>(request);
+    never_panics::<ClientMessage>(any_json().prop_map(|v| v.to_string()));
+}
+
+#[test]
+fn unknown_tags_fall_back_to_unknown() {
+    let client_tags = known_tags("ClientMessage", "type");
+    let server_tags = known_tags("ServerMessage", "type");
+    let groups = known_tags("RequestPayload", "group");
+
+    runner(FUZZ_CASES)
+        .run(&("[A-Za-z_]{1,24}", any_json()), |(tag, extra)| {
+            let message = serde_json::json!({ "type": tag, "extra": extra });
+            if !client_tags.contains(&tag) {
+                let decoded: ClientMessage = serde_json::from_value(message.clone())
+                    .map_err(|e| TestCaseError::fail(e.to_string()))?;
+                prop_assert!(matches!(decoded, ClientMessage::Unknown));
+            }
+            if !server_tags.contains(&tag) {
+                let decoded: ServerMessage = serde_json::from_value(message)
+                    .map_err(|e| TestCaseError::fail(e.to_string()))?;
+                prop_assert!(matches!(decoded, ServerMessage::Unknown));
+            }
+            if !groups.contains(&tag) {
+                let decoded: RequestPayload =
+                    serde_json::from_value(serde_json::json!({ "group": tag }))
+                        .map_err(|e| TestCaseError::fail(e.to_string()))?;
+                prop_assert!(matches!(decoded, RequestPayload::Unknown));
+            }
+            Ok(())
+        })
+        .unwrap();
+}
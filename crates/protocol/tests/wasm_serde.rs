@@ -0,0 +1,82 @@
+//! Serde round trips run inside wasm32, where the web Player decodes them.
+//!
+//! Native tests can't catch a dependency or feature that only breaks in the
+//! browser build (uuid/getrandom features, float formatting, chrono), so
+//! these run under `wasm-bindgen-test` via `cargo xtask wasm-check`.
+
+#![cfg(target_arch = "wasm32")]
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use uuid::Uuid;
+use wasm_bindgen_test::wasm_bindgen_test;
+use wrldbldr_protocol::requests::world::WorldRequest;
+use wrldbldr_protocol::{ClientMessage, GameTime, RequestPayload, ServerMessage, WorldRole};
+
+/// Encode, decode and re-encode `value`; both encodings must agree
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Value {
+    let encoded = serde_json::to_string(value).unwrap();
+    let decoded: T = serde_json::from_str(&encoded).unwrap();
+    let reencoded = serde_json::to_value(&decoded).unwrap();
+    assert_eq!(serde_json::from_str::<Value>(&encoded).unwrap(), reencoded);
+    reencoded
+}
+
+#[wasm_bindgen_test]
+fn client_messages_round_trip() {
+    let world_id = Uuid::new_v4();
+    let join = round_trip(&ClientMessage::JoinWorld {
+        world_id,
+        role: WorldRole::Player,
+        user_id: "player-1".to_string(),
+        pc_id: Some(Uuid::new_v4()),
+        spectate_pc_id: None,
+    });
+    assert_eq!(join["type"], "JoinWorld");
+    assert_eq!(join["world_id"], world_id.to_string());
+
+    let request = round_trip(&ClientMessage::Request {
+        request_id: "req-1".to_string(),
+        payload: RequestPayload::World(WorldRequest::GetWorld {
+            world_id: world_id.to_string(),
+        }),
+    });
+    assert_eq!(request["payload"]["group"], "world");
+}
+
+#[wasm_bindgen_test]
+fn server_messages_round_trip() {
+    let joined = round_trip(&ServerMessage::WorldJoined {
+        world_id: Uuid::new_v4(),
+        snapshot: json!({ "world": { "name": "Saltmere" }, "locations": [] }),
+        connected_users: vec![],
+        your_role: WorldRole::Dm,
+        your_pc: None,
+    });
+    assert_eq!(joined["snapshot"]["world"]["name"], "Saltmere");
+
+    let time = round_trip(&ServerMessage::GameTimeUpdated {
+        game_time: GameTime {
+            day: 3,
+            hour: 18,
+            minute: 45,
+            is_paused: false,
+        },
+    });
+    assert_eq!(time["game_time"]["hour"], 18);
+}
+
+#[wasm_bindgen_test]
+fn unknown_tags_decode_to_unknown() {
+    let client: ClientMessage = serde_json::from_str(r#"{"type":"FromTheFuture"}"#).unwrap();
+    assert!(matches!(client, ClientMessage::Unknown));
+    let server: ServerMessage = serde_json::from_str(r#"{"type":"FromTheFuture"}"#).unwrap();
+    assert!(matches!(server, ServerMessage::Unknown));
+}
+
+#[wasm_bindgen_test]
+fn fresh_uuids_are_random() {
+    // uuid's v4 needs a wasm entropy source (getrandom's `js` feature)
+    assert_ne!(Uuid::new_v4(), Uuid::new_v4());
+}
@@ -0,0 +1,608 @@
+//! `cargo xtask load --world <uuid> --pc <uuid>...`
+//!
+//! Connects N synthetic players to a running Engine and has each one move,
+//! talk and heartbeat at random for a fixed time. Every message is timed
+//! until its direct reply (`MoveToRegion` until `SceneChanged`,
+//! `StagingPending` or `MovementBlocked`, `StartConversation` until
+//! `ConversationStarted`, and so on), and the run ends with latency
+//! percentiles per message type, so scalability regressions show up as
+//! numbers rather than a feeling.
+//!
+//! Players are handed the `--pc` ids round-robin and learn where they can go
+//! from the `SceneChanged` replies to their own moves; they only talk to NPCs
+//! staged in their current region. Actions arrive at `--rate` per player per
+//! second (exponential gaps) and are picked with the `--mix` weights. The
+//! same `--seed` replays the same choices against the same world.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use futures_util::{SinkExt, StreamExt};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::Value;
+use tokio::net::TcpStream;
+use tokio::time::Instant;
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use uuid::Uuid;
+use wrldbldr_protocol::{ClientMessage, WorldRole};
+
+const DEFAULT_URL: &str = "ws://127.0.0.1:3000/ws";
+
+/// Errors listed in the report, per message type
+const SHOWN_ERRORS: usize = 3;
+
+const LINES: &[&str] = &[
+    "Hello there.",
+    "Any work going?",
+    "What do you know about the harbor?",
+    "Have you seen anyone suspicious?",
+];
+
+const USAGE: &str = "Usage: cargo xtask load --world <uuid> --pc <uuid>... [--url <ws-url>] \
+                     [--players <n>] [--duration <secs>] [--rate <actions/sec per player>] \
+                     [--mix move=3,talk=1,heartbeat=1] [--seed <n>] [--timeout <secs>]";
+
+/// Relative weights of the random actions
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Mix {
+    moves: u32,
+    talks: u32,
+    heartbeats: u32,
+}
+
+impl Default for Mix {
+    fn default() -> Self {
+        Self {
+            moves: 3,
+            talks: 1,
+            heartbeats: 1,
+        }
+    }
+}
+
+impl Mix {
+    fn parse(spec: &str) -> anyhow::Result<Self> {
+        let mut mix = Self {
+            moves: 0,
+            talks: 0,
+            heartbeats: 0,
+        };
+        for part in spec.split(',') {
+            let (name, weight) = part
+                .split_once('=')
+                .with_context(|| format!("--mix expects name=weight, got `{part}`"))?;
+            let weight = weight
+                .trim()
+                .parse()
+                .with_context(|| format!("--mix weight for `{name}`"))?;
+            match name.trim() {
+                "move" => mix.moves = weight,
+                "talk" => mix.talks = weight,
+                "heartbeat" => mix.heartbeats = weight,
+                other => bail!("unknown --mix action `{other}` (move, talk, heartbeat)"),
+            }
+        }
+        if mix.moves + mix.talks + mix.heartbeats == 0 {
+            bail!("--mix needs at least one non-zero weight");
+        }
+        Ok(mix)
+    }
+
+    fn pick(&self, rng: &mut impl Rng) -> Action {
+        let roll = rng.gen_range(0..self.moves + self.talks + self.heartbeats);
+        if roll < self.moves {
+            Action::Move
+        } else if roll < self.moves + self.talks {
+            Action::Talk
+        } else {
+            Action::Heartbeat
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Action {
+    Move,
+    Talk,
+    Heartbeat,
+}
+
+struct Options {
+    url: String,
+    world_id: Uuid,
+    pcs: Vec<Uuid>,
+    players: usize,
+    duration: Duration,
+    rate: f64,
+    mix: Mix,
+    seed: u64,
+    reply_timeout: Duration,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<Options> {
+    let mut url = DEFAULT_URL.to_string();
+    let mut world_id = None;
+    let mut pcs = Vec::new();
+    let mut players = 10;
+    let mut duration: f64 = 30.0;
+    let mut rate: f64 = 0.5;
+    let mut mix = Mix::default();
+    let mut seed = 0;
+    let mut reply_timeout: f64 = 10.0;
+
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().with_context(|| format!("{flag} needs a value"));
+        match arg.as_str() {
+            "--url" => url = value("--url")?,
+            "--world" => world_id = Some(value("--world")?.parse().context("--world")?),
+            "--pc" => pcs.push(value("--pc")?.parse().context("--pc")?),
+            "--players" => players = value("--players")?.parse().context("--players")?,
+            "--duration" => duration = value("--duration")?.parse().context("--duration")?,
+            "--rate" => rate = value("--rate")?.parse().context("--rate")?,
+            "--mix" => mix = Mix::parse(&value("--mix")?)?,
+            "--seed" => seed = value("--seed")?.parse().context("--seed")?,
+            "--timeout" => reply_timeout = value("--timeout")?.parse().context("--timeout")?,
+            other => bail!("unexpected argument `{other}`\n\n{USAGE}"),
+        }
+    }
+
+    let world_id = world_id.with_context(|| format!("--world is required\n\n{USAGE}"))?;
+    if pcs.is_empty() {
+        bail!("at least one --pc is required\n\n{USAGE}");
+    }
+    if players == 0 {
+        bail!("--players must be at least 1");
+    }
+    if [rate, duration, reply_timeout]
+        .iter()
+        .any(|v| v.is_nan() || *v <= 0.0)
+    {
+        bail!("--rate, --duration and --timeout must be positive");
+    }
+    Ok(Options {
+        url,
+        world_id,
+        pcs,
+        players,
+        duration: Duration::from_secs_f64(duration),
+        rate,
+        mix,
+        seed,
+        reply_timeout: Duration::from_secs_f64(reply_timeout),
+    })
+}
+
+/// Latencies and failures per message type
+#[derive(Debug, Default)]
+struct Stats {
+    latencies: BTreeMap<&'static str, Vec<Duration>>,
+    errors: BTreeMap<&'static str, Vec<String>>,
+}
+
+impl Stats {
+    fn ok(&mut self, kind: &'static str, latency: Duration) {
+        self.latencies.entry(kind).or_default().push(latency);
+    }
+
+    fn failed(&mut self, kind: &'static str, error: String) {
+        self.errors.entry(kind).or_default().push(error);
+    }
+
+    fn merge(&mut self, other: Stats) {
+        for (kind, latencies) in other.latencies {
+            self.latencies.entry(kind).or_default().extend(latencies);
+        }
+        for (kind, errors) in other.errors {
+            self.errors.entry(kind).or_default().extend(errors);
+        }
+    }
+
+    fn total_failed(&self) -> usize {
+        self.errors.values().map(Vec::len).sum()
+    }
+
+    fn report(&self, elapsed: Duration) -> String {
+        let mut kinds: Vec<_> = self.latencies.keys().chain(self.errors.keys()).collect();
+        kinds.sort();
+        kinds.dedup();
+
+        let sent: usize =
+            self.latencies.values().map(Vec::len).sum::<usize>() + self.total_failed();
+        let mut out = format!(
+            "{sent} messages in {:.1} s ({:.1}/s)\n\n{:<20} {:>7} {:>7} {:>8} {:>8} {:>8} {:>8}\n",
+            elapsed.as_secs_f64(),
+            sent as f64 / elapsed.as_secs_f64(),
+            "message",
+            "ok",
+            "failed",
+            "p50 ms",
+            "p95 ms",
+            "p99 ms",
+            "max ms"
+        );
+        for kind in kinds {
+            let mut latencies = self.latencies.get(kind).cloned().unwrap_or_default();
+            latencies.sort();
+            let failed = self.errors.get(kind).map_or(0, Vec::len);
+            let ms = |p| {
+                percentile(&latencies, p).map_or("-".to_string(), |d| d.as_millis().to_string())
+            };
+            out.push_str(&format!(
+                "{kind:<20} {:>7} {failed:>7} {:>8} {:>8} {:>8} {:>8}\n",
+                latencies.len(),
+                ms(50),
+                ms(95),
+                ms(99),
+                ms(100)
+            ));
+        }
+        for (kind, errors) in &self.errors {
+            for error in errors.iter().take(SHOWN_ERRORS) {
+                out.push_str(&format!("\n{kind} failed: {error}"));
+            }
+        }
+        out
+    }
+}
+
+/// Nearest-rank percentile of sorted samples
+fn percentile(sorted: &[Duration], p: usize) -> Option<Duration> {
+    let last = sorted.len().checked_sub(1)?;
+    Some(sorted[last * p / 100])
+}
+
+/// What a player has seen of the world
+#[derive(Debug, Default)]
+struct View {
+    region_id: Option<String>,
+    neighbours: Vec<String>,
+    npcs: Vec<String>,
+}
+
+impl View {
+    fn update(&mut self, reply: &Value) {
+        if reply["type"] != "SceneChanged" {
+            return;
+        }
+        let strings = |items: Option<&Value>, field: &str| -> Vec<String> {
+            items
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter(|item| item["is_locked"] != true)
+                .filter_map(|item| item[field].as_str().map(str::to_string))
+                .collect()
+        };
+        self.region_id = reply["region"]["id"].as_str().map(str::to_string);
+        self.neighbours = strings(reply.pointer("/navigation/connected_regions"), "region_id");
+        self.npcs = strings(reply.get("npcs_present"), "character_id");
+    }
+}
+
+/// Message types that answer `kind`; `Error` answers everything
+fn is_reply(kind: &str, message_type: &str) -> bool {
+    let replies: &[&str] = match kind {
+        "JoinWorld" => &["WorldJoined", "WorldJoinFailed"],
+        "MoveToRegion" => &["SceneChanged", "StagingPending", "MovementBlocked"],
+        "StartConversation" => &["ConversationStarted"],
+        "Heartbeat" => &["Pong"],
+        _ => &[],
+    };
+    message_type == "Error" || replies.contains(&message_type)
+}
+
+fn kind_of(message: &ClientMessage) -> &'static str {
+    match message {
+        ClientMessage::JoinWorld { .. } => "JoinWorld",
+        ClientMessage::MoveToRegion { .. } => "MoveToRegion",
+        ClientMessage::StartConversation { .. } => "StartConversation",
+        _ => "Heartbeat",
+    }
+}
+
+struct Player {
+    socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    pc_id: Uuid,
+    view: View,
+    rng: StdRng,
+    reply_timeout: Duration,
+    stats: Stats,
+}
+
+impl Player {
+    /// Send `message` and wait for its reply, recording the round trip
+    async fn round_trip(&mut self, message: ClientMessage) -> Option<Value> {
+        let kind = kind_of(&message);
+        let started = Instant::now();
+        let text = serde_json::to_string(&message).expect("client messages serialize");
+        if let Err(e) = self.socket.send(Message::Text(text)).await {
+            self.stats.failed(kind, format!("send: {e}"));
+            return None;
+        }
+
+        let deadline = started + self.reply_timeout;
+        loop {
+            let frame = match tokio::time::timeout_at(deadline, self.socket.next()).await {
+                Ok(Some(Ok(frame))) => frame,
+                Ok(Some(Err(e))) => {
+                    self.stats.failed(kind, format!("read: {e}"));
+                    return None;
+                }
+                Ok(None) => {
+                    self.stats.failed(kind, "connection closed".to_string());
+                    return None;
+                }
+                Err(_) => {
+                    let waited = self.reply_timeout.as_millis();
+                    self.stats.failed(kind, format!("no reply in {waited} ms"));
+                    return None;
+                }
+            };
+            let Message::Text(text) = frame else {
+                continue;
+            };
+            let Ok(reply) = serde_json::from_str::<Value>(&text) else {
+                continue;
+            };
+            let reply_type = reply["type"].as_str().unwrap_or_default();
+            if !is_reply(kind, reply_type) {
+                // Broadcasts and other players' traffic
+                continue;
+            }
+            if matches!(reply_type, "Error" | "WorldJoinFailed") {
+                self.stats.failed(kind, reply.to_string());
+                return None;
+            }
+            self.stats.ok(kind, started.elapsed());
+            return Some(reply);
+        }
+    }
+
+    fn next_message(&mut self, mix: Mix) -> ClientMessage {
+        let pc_id = self.pc_id.to_string();
+        match mix.pick(&mut self.rng) {
+            Action::Talk if !self.view.npcs.is_empty() => {
+                let npc = &self.view.npcs[self.rng.gen_range(0..self.view.npcs.len())];
+                ClientMessage::StartConversation {
+                    npc_id: npc.clone(),
+                    message: LINES[self.rng.gen_range(0..LINES.len())].to_string(),
+                }
+            }
+            Action::Heartbeat => ClientMessage::Heartbeat,
+            // With nowhere known to go, re-entering the current region
+            // refreshes the view
+            Action::Move | Action::Talk => {
+                let region_id = if self.view.neighbours.is_empty() {
+                    self.view.region_id.clone()
+                } else {
+                    let index = self.rng.gen_range(0..self.view.neighbours.len());
+                    Some(self.view.neighbours[index].clone())
+                };
+                match region_id {
+                    Some(region_id) => ClientMessage::MoveToRegion { pc_id, region_id },
+                    None => ClientMessage::Heartbeat,
+                }
+            }
+        }
+    }
+}
+
+/// One synthetic player's whole session
+async fn play(options: &Options, index: usize, until: Instant) -> Stats {
+    let mut stats = Stats::default();
+    let socket = match connect_async(options.url.as_str()).await {
+        Ok((socket, _)) => socket,
+        Err(e) => {
+            stats.failed("connect", e.to_string());
+            return stats;
+        }
+    };
+    let mut player = Player {
+        socket,
+        pc_id: options.pcs[index % options.pcs.len()],
+        view: View::default(),
+        rng: StdRng::seed_from_u64(options.seed.wrapping_add(index as u64)),
+        reply_timeout: options.reply_timeout,
+        stats,
+    };
+
+    let joined = player
+        .round_trip(ClientMessage::JoinWorld {
+            world_id: options.world_id,
+            role: WorldRole::Player,
+            user_id: format!("load-player-{index}"),
+            pc_id: Some(player.pc_id),
+            spectate_pc_id: None,
+        })
+        .await;
+    let Some(joined) = joined else {
+        return player.stats;
+    };
+    player.view.region_id = joined
+        .pointer("/your_pc/current_region_id")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+
+    loop {
+        // Exponential gaps make arrivals a Poisson process at `rate`
+        let gap = -(1.0 - player.rng.gen::<f64>()).ln() / options.rate;
+        let at = Instant::now() + Duration::from_secs_f64(gap);
+        if at >= until {
+            break;
+        }
+        tokio::time::sleep_until(at).await;
+        let message = player.next_message(options.mix);
+        if let Some(reply) = player.round_trip(message).await {
+            player.view.update(&reply);
+        }
+    }
+
+    let _ = player.socket.close(None).await;
+    player.stats
+}
+
+async fn run(options: Options) -> Stats {
+    let options = std::sync::Arc::new(options);
+    let until = Instant::now() + options.duration;
+    let mut tasks = tokio::task::JoinSet::new();
+    for index in 0..options.players {
+        let options = options.clone();
+        tasks.spawn(async move { play(&options, index, until).await });
+    }
+
+    let mut stats = Stats::default();
+    while let Some(player) = tasks.join_next().await {
+        match player {
+            Ok(player) => stats.merge(player),
+            Err(e) => stats.failed("player", e.to_string()),
+        }
+    }
+    stats
+}
+
+pub fn load(args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let options = parse_args(args)?;
+    println!(
+        "{} players for {} s at {}/s each against {}",
+        options.players,
+        options.duration.as_secs_f64(),
+        options.rate,
+        options.url
+    );
+    let runtime = tokio::runtime::Runtime::new().context("starting tokio runtime")?;
+    let started = std::time::Instant::now();
+    let stats = runtime.block_on(run(options));
+    println!("{}", stats.report(started.elapsed()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn mix_parses_weights_and_picks_only_weighted_actions() {
+        assert_eq!(
+            Mix::parse("move=2, talk=0,heartbeat=5").unwrap(),
+            Mix {
+                moves: 2,
+                talks: 0,
+                heartbeats: 5
+            }
+        );
+        assert!(Mix::parse("move=0").is_err());
+        assert!(Mix::parse("dance=1").is_err());
+
+        let mix = Mix::parse("move=1").unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!((0..50).all(|_| mix.pick(&mut rng) == Action::Move));
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let samples: Vec<_> = (1..=100).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&samples, 50), Some(Duration::from_millis(50)));
+        assert_eq!(percentile(&samples, 99), Some(Duration::from_millis(99)));
+        assert_eq!(percentile(&samples, 100), Some(Duration::from_millis(100)));
+        assert_eq!(percentile(&[], 50), None);
+    }
+
+    #[test]
+    fn scene_changes_update_the_view() {
+        let mut view = View::default();
+        view.update(&json!({
+            "type": "SceneChanged",
+            "region": { "id": "docks" },
+            "navigation": { "connected_regions": [
+                { "region_id": "tavern", "is_locked": false },
+                { "region_id": "vault", "is_locked": true }
+            ] },
+            "npcs_present": [{ "character_id": "mira" }]
+        }));
+
+        assert_eq!(view.region_id.as_deref(), Some("docks"));
+        assert_eq!(view.neighbours, vec!["tavern".to_string()]);
+        assert_eq!(view.npcs, vec!["mira".to_string()]);
+
+        view.update(&json!({ "type": "MovementBlocked" }));
+        assert_eq!(view.region_id.as_deref(), Some("docks"));
+    }
+
+    /// Two connected regions with an NPC in each
+    fn scene(region: &str) -> Value {
+        let other = if region == "docks" { "tavern" } else { "docks" };
+        json!({
+            "type": "SceneChanged",
+            "region": { "id": region },
+            "navigation": { "connected_regions": [{ "region_id": other, "is_locked": false }] },
+            "npcs_present": [{ "character_id": format!("npc-{region}") }]
+        })
+    }
+
+    /// Only lets players talk to the NPC in the region they are in
+    async fn spawn_fake_engine() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                    let mut region = "docks".to_string();
+                    while let Some(Ok(Message::Text(text))) = ws.next().await {
+                        let message: Value = serde_json::from_str(&text).unwrap();
+                        let reply = match message["type"].as_str().unwrap() {
+                            "JoinWorld" => json!({
+                                "type": "WorldJoined",
+                                "your_pc": { "current_region_id": "docks" }
+                            }),
+                            "MoveToRegion" => {
+                                region = message["region_id"].as_str().unwrap().to_string();
+                                scene(&region)
+                            }
+                            "StartConversation" if message["npc_id"] == format!("npc-{region}") => {
+                                json!({ "type": "ConversationStarted" })
+                            }
+                            "StartConversation" => json!({ "type": "Error" }),
+                            _ => json!({ "type": "Pong" }),
+                        };
+                        // Unrelated traffic first, as broadcasts would be
+                        let noise = json!({ "type": "ActionQueued" });
+                        ws.send(Message::Text(noise.to_string())).await.unwrap();
+                        ws.send(Message::Text(reply.to_string())).await.unwrap();
+                    }
+                });
+            }
+        });
+        format!("ws://{addr}")
+    }
+
+    #[tokio::test]
+    async fn synthetic_players_record_latency_per_message_type() {
+        let url = spawn_fake_engine().await;
+        let options = Options {
+            url,
+            world_id: Uuid::nil(),
+            pcs: vec![Uuid::nil()],
+            players: 3,
+            duration: Duration::from_millis(400),
+            rate: 100.0,
+            mix: Mix::default(),
+            seed: 7,
+            reply_timeout: Duration::from_secs(2),
+        };
+
+        let stats = run(options).await;
+
+        assert_eq!(stats.latencies["JoinWorld"].len(), 3);
+        assert!(stats.latencies.contains_key("MoveToRegion"));
+        assert!(stats.latencies.contains_key("Heartbeat"));
+        // Players only talk to NPCs in the region they are in
+        assert_eq!(stats.total_failed(), 0, "{:?}", stats.errors);
+        let report = stats.report(Duration::from_secs(1));
+        assert!(report.contains("MoveToRegion"), "{report}");
+    }
+}
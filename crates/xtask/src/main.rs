@@ -1,12 +1,27 @@
 use anyhow::Context;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
+mod load;
+mod protocol_schema;
+mod scenario;
+mod wasm_check;
+
 fn main() -> anyhow::Result<()> {
     let mut args = std::env::args().skip(1);
     match args.next().as_deref() {
         Some("arch-check") => arch_check(),
+        Some("load") => load::load(args),
+        Some("protocol-schema") => protocol_schema::protocol_schema(args),
+        Some("scenario") => scenario::scenario(args),
+        Some("wasm-check") => wasm_check::wasm_check(args),
         Some(cmd) => anyhow::bail!("Unknown xtask command: {cmd}"),
-        None => anyhow::bail!("Usage: cargo xtask <command>\n\nCommands:\n  arch-check"),
+        None => anyhow::bail!(
+            "Usage: cargo xtask <command>\n\nCommands:\n  arch-check\n  \
+             load --world <uuid> --pc <uuid>... [--players <n>] [--duration <secs>] [--rate <n>]\n  \
+             protocol-schema [--check] [--out <dir>]\n  \
+             scenario <file> [--url <ws-url>] [--var name=value]... [--runs <n>] [--concurrency <n>]\n  \
+             wasm-check [--no-tests]"
+        ),
     }
 }
 
@@ -1843,6 +1858,7 @@ fn allowed_internal_deps() -> HashMap<&'static str, HashSet<&'static str>> {
             "wrldbldr-player",
             HashSet::from(["wrldbldr-domain", "wrldbldr-protocol"]),
         ),
-        ("xtask", HashSet::from([])),
+        // xtask generates the protocol's JSON Schema / TypeScript definitions
+        ("xtask", HashSet::from(["wrldbldr-protocol"])),
     ])
 }
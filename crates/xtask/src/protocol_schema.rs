@@ -0,0 +1,421 @@
+//! `cargo xtask protocol-schema`
+//!
+//! Generates JSON Schema and TypeScript definitions for the wire protocol
+//! (`ClientMessage`, `ServerMessage`, `RequestPayload`, `ResponseResult` and
+//! the REST DTOs) from the protocol crate's `schema` feature, so third-party
+//! clients and web tooling can stay in sync with the Rust types.
+//!
+//! With `--check`, nothing is written and the command fails if the committed
+//! files are out of date.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use schemars::generate::SchemaSettings;
+use schemars::JsonSchema;
+use serde_json::{json, Map, Value};
+use wrldbldr_protocol::dto;
+
+const SCHEMA_FILE: &str = "protocol.schema.json";
+const TYPESCRIPT_FILE: &str = "protocol.ts";
+
+const HEADER: &str = "// Generated by `cargo xtask protocol-schema` - do not edit.\n\
+// Types for the WrldBldr Engine <-> Player wire protocol.\n";
+
+/// Root types; everything they reference is included too
+fn definitions() -> BTreeMap<String, Value> {
+    let mut generator = SchemaSettings::draft2020_12().into_generator();
+
+    macro_rules! roots {
+        ($($ty:ty),* $(,)?) => {
+            $(add_root::<$ty>(&mut generator);)*
+        };
+    }
+
+    roots![
+        wrldbldr_protocol::ClientMessage,
+        wrldbldr_protocol::ServerMessage,
+        wrldbldr_protocol::RequestPayload,
+        wrldbldr_protocol::ResponseResult,
+        dto::UploadAssetRequestDto,
+        dto::UploadAssetResponseDto,
+        dto::UpdateAssetLabelRequestDto,
+        dto::GalleryAssetResponseDto,
+        dto::GenerateAssetRequestDto,
+        dto::GenerationBatchResponseDto,
+        dto::SelectFromBatchRequestDto,
+        dto::ExportQueryDto,
+        dto::WorkflowConfigResponseDto,
+        dto::WorkflowConfigFullResponseDto,
+        dto::WorkflowSlotsResponseDto,
+        dto::WorkflowAnalysisResponseDto,
+        dto::CreateWorkflowConfigRequestDto,
+        dto::UpdateWorkflowDefaultsRequestDto,
+        dto::AnalyzeWorkflowRequestDto,
+        dto::ImportWorkflowsRequestDto,
+        dto::ImportWorkflowsResponseDto,
+        dto::TestWorkflowRequestDto,
+        dto::TestWorkflowResponseDto,
+        dto::WorkflowConfigExportDto,
+        dto::RuleSystemSummaryDto,
+        dto::RuleSystemPresetDetailsDto,
+        dto::RuleSystemTypeDetailsDto,
+        dto::NpcDispositionStateDto,
+    ];
+
+    generator
+        .take_definitions(true)
+        .into_iter()
+        .map(|(name, mut schema)| {
+            sort_keys(&mut schema);
+            (name, schema)
+        })
+        .collect()
+}
+
+/// Sort object keys so output doesn't depend on serde_json's `preserve_order`
+fn sort_keys(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.sort_keys();
+            map.values_mut().for_each(sort_keys);
+        }
+        Value::Array(items) => items.iter_mut().for_each(sort_keys),
+        _ => {}
+    }
+}
+
+fn add_root<T: JsonSchema>(generator: &mut schemars::SchemaGenerator) {
+    generator.subschema_for::<T>();
+}
+
+pub fn protocol_schema(args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let mut check = false;
+    let mut out_dir = workspace_root().join("crates/protocol/schema");
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--out" => {
+                out_dir = PathBuf::from(args.next().context("--out needs a directory")?);
+            }
+            other => anyhow::bail!("Unknown protocol-schema option: {other}"),
+        }
+    }
+
+    let definitions = definitions();
+    let mut schema = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "WrldBldr Protocol",
+        "description": "Wire types for the WrldBldr Engine <-> Player protocol. \
+            Generated by `cargo xtask protocol-schema`.",
+        "$defs": definitions,
+    });
+    sort_keys(&mut schema);
+    let files = [
+        (
+            SCHEMA_FILE,
+            serde_json::to_string_pretty(&schema).context("serializing schema")? + "\n",
+        ),
+        (TYPESCRIPT_FILE, typescript(&definitions)),
+    ];
+
+    if check {
+        let stale: Vec<&str> = files
+            .iter()
+            .filter(|(name, contents)| {
+                std::fs::read_to_string(out_dir.join(name)).ok().as_deref()
+                    != Some(contents.as_str())
+            })
+            .map(|(name, _)| *name)
+            .collect();
+        if !stale.is_empty() {
+            anyhow::bail!(
+                "Protocol schema is out of date ({}). Run `cargo xtask protocol-schema`.",
+                stale.join(", ")
+            );
+        }
+        println!("protocol-schema: up to date");
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(&out_dir).with_context(|| format!("creating {}", out_dir.display()))?;
+    for (name, contents) in &files {
+        let path = out_dir.join(name);
+        std::fs::write(&path, contents).with_context(|| format!("writing {}", path.display()))?;
+    }
+    println!(
+        "protocol-schema: wrote {} types to {}",
+        definitions.len(),
+        out_dir.display()
+    );
+    Ok(())
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
+
+// =============================================================================
+// TypeScript
+// =============================================================================
+
+/// TypeScript declarations for every schema definition, in name order
+fn typescript(definitions: &BTreeMap<String, Value>) -> String {
+    let mut out = String::from(HEADER);
+    for (name, schema) in definitions {
+        out.push('\n');
+        push_doc(&mut out, schema, "");
+        let _ = writeln!(out, "export type {} = {};", name, ts_type(schema, ""));
+    }
+    out
+}
+
+fn push_doc(out: &mut String, schema: &Value, indent: &str) {
+    let Some(description) = schema.get("description").and_then(Value::as_str) else {
+        return;
+    };
+    let _ = writeln!(out, "{indent}/**");
+    for line in description.lines() {
+        let line = line.replace("*/", "*\\/");
+        if line.is_empty() {
+            let _ = writeln!(out, "{indent} *");
+        } else {
+            let _ = writeln!(out, "{indent} * {line}");
+        }
+    }
+    let _ = writeln!(out, "{indent} */");
+}
+
+fn literal(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn union(parts: Vec<String>) -> String {
+    let mut unique: Vec<String> = Vec::new();
+    for part in parts {
+        if !unique.contains(&part) {
+            unique.push(part);
+        }
+    }
+    match unique.len() {
+        0 => "never".to_string(),
+        1 => unique.remove(0),
+        _ => unique.join(" | "),
+    }
+}
+
+/// Wrap unions and intersections so they can take a `[]` suffix
+fn array_of(item: String) -> String {
+    if item.contains(" | ") || item.contains(" & ") {
+        format!("({item})[]")
+    } else {
+        format!("{item}[]")
+    }
+}
+
+fn ts_type(schema: &Value, indent: &str) -> String {
+    let Some(object) = schema.as_object() else {
+        // `true` accepts anything, `false` nothing
+        return if schema == &Value::Bool(false) {
+            "never".to_string()
+        } else {
+            "unknown".to_string()
+        };
+    };
+
+    let mut parts = Vec::new();
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+        parts.push(
+            reference
+                .rsplit('/')
+                .next()
+                .unwrap_or(reference)
+                .to_string(),
+        );
+    }
+    if let Some(value) = object.get("const") {
+        parts.push(literal(value));
+    } else if let Some(values) = object.get("enum").and_then(Value::as_array) {
+        parts.push(union(values.iter().map(literal).collect()));
+    } else if object.contains_key("type")
+        || object.contains_key("properties")
+        || object.contains_key("items")
+    {
+        parts.push(typed(object, indent));
+    }
+    for key in ["oneOf", "anyOf"] {
+        if let Some(variants) = object.get(key).and_then(Value::as_array) {
+            let variants = union(variants.iter().map(|v| ts_type(v, indent)).collect());
+            parts.push(if parts.is_empty() {
+                variants
+            } else {
+                format!("({variants})")
+            });
+        }
+    }
+    if let Some(all) = object.get("allOf").and_then(Value::as_array) {
+        parts.extend(all.iter().map(|v| ts_type(v, indent)));
+    }
+
+    match parts.len() {
+        0 => "unknown".to_string(),
+        1 => parts.remove(0),
+        _ => parts.join(" & "),
+    }
+}
+
+/// Type of a schema with `type` (possibly several, e.g. `["string", "null"]`)
+fn typed(object: &Map<String, Value>, indent: &str) -> String {
+    let types: Vec<&str> = match object.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+        _ => vec!["object"],
+    };
+    union(
+        types
+            .into_iter()
+            .map(|t| match t {
+                "string" => "string".to_string(),
+                "integer" | "number" => "number".to_string(),
+                "boolean" => "boolean".to_string(),
+                "null" => "null".to_string(),
+                "array" => array_type(object, indent),
+                _ => object_type(object, indent),
+            })
+            .collect(),
+    )
+}
+
+fn array_type(object: &Map<String, Value>, indent: &str) -> String {
+    if let Some(items) = object.get("prefixItems").and_then(Value::as_array) {
+        let items: Vec<String> = items.iter().map(|v| ts_type(v, indent)).collect();
+        return format!("[{}]", items.join(", "));
+    }
+    match object.get("items") {
+        Some(items) => array_of(ts_type(items, indent)),
+        None => "unknown[]".to_string(),
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+fn object_type(object: &Map<String, Value>, indent: &str) -> String {
+    let properties = object.get("properties").and_then(Value::as_object);
+    let additional = object
+        .get("additionalProperties")
+        .filter(|v| **v != Value::Bool(false));
+
+    let Some(properties) = properties.filter(|p| !p.is_empty()) else {
+        return match additional {
+            Some(value) => format!("Record<string, {}>", ts_type(value, indent)),
+            None => "Record<string, never>".to_string(),
+        };
+    };
+
+    let required: Vec<&str> = object
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    // Tags (constant fields) first, then the rest by name
+    let mut properties: Vec<(&String, &Value)> = properties.iter().collect();
+    properties.sort_by_key(|(name, schema)| (schema.get("const").is_none(), *name));
+
+    let inner = format!("{indent}  ");
+    let mut out = String::from("{\n");
+    for (name, schema) in properties {
+        push_doc(&mut out, schema, &inner);
+        let key = if is_identifier(name) {
+            name.clone()
+        } else {
+            serde_json::to_string(name).unwrap_or_else(|_| name.clone())
+        };
+        let optional = if required.contains(&name.as_str()) {
+            ""
+        } else {
+            "?"
+        };
+        let _ = writeln!(out, "{inner}{key}{optional}: {};", ts_type(schema, &inner));
+    }
+    if let Some(value) = additional.filter(|v| **v != Value::Bool(true)) {
+        let _ = writeln!(out, "{inner}[key: string]: {};", ts_type(value, &inner));
+    }
+    out.push_str(indent);
+    out.push('}');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_tagged_unions_and_optional_fields() {
+        let schema = json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": { "type": "string", "const": "Heartbeat" }
+                    },
+                    "required": ["type"]
+                },
+                {
+                    "description": "Join a world",
+                    "type": "object",
+                    "properties": {
+                        "type": { "type": "string", "const": "JoinWorld" },
+                        "world_id": { "type": "string", "format": "uuid" },
+                        "pc_id": { "type": ["string", "null"] },
+                        "tags": { "type": "array", "items": { "$ref": "#/$defs/Tag" } }
+                    },
+                    "required": ["type", "world_id"]
+                }
+            ]
+        });
+
+        assert_eq!(
+            ts_type(&schema, ""),
+            "{\n  type: \"Heartbeat\";\n} | {\n  type: \"JoinWorld\";\n  \
+             pc_id?: string | null;\n  tags?: Tag[];\n  world_id: string;\n}"
+        );
+    }
+
+    #[test]
+    fn converts_maps_enums_and_nullable_refs() {
+        assert_eq!(
+            ts_type(
+                &json!({ "type": "object", "additionalProperties": { "type": "integer" } }),
+                ""
+            ),
+            "Record<string, number>"
+        );
+        assert_eq!(
+            ts_type(&json!({ "type": "string", "enum": ["dm", "player"] }), ""),
+            "\"dm\" | \"player\""
+        );
+        assert_eq!(
+            ts_type(
+                &json!({ "anyOf": [{ "$ref": "#/$defs/Region" }, { "type": "null" }] }),
+                ""
+            ),
+            "Region | null"
+        );
+    }
+}
@@ -0,0 +1,652 @@
+//! `cargo xtask scenario <file>`
+//!
+//! Plays a scripted session against a running Engine. Each client named in
+//! the script opens its own WebSocket, so one script can act as the DM and
+//! several players at once. `send` steps write client messages; `expect`
+//! steps wait for a server message matching a pattern, skipping anything
+//! else that arrives in between (broadcasts interleave).
+//!
+//! Scripts are YAML (`.yaml`/`.yml`) or JSON:
+//!
+//! ```yaml
+//! name: Talk and approve
+//! vars:
+//!   greeting: Any work going?
+//! clients: [dm, player]
+//! steps:
+//!   - client: player
+//!     send: { type: StartConversation, npc_id: "${npc_id}", message: "${greeting}" }
+//!   - client: dm
+//!     expect: { type: ApprovalRequired }
+//!     capture: { approval_id: /request_id }
+//!     timeout_ms: 60000
+//!   - sleep_ms: 250
+//! ```
+//!
+//! A pattern matches when every key it lists matches the message (objects
+//! recursively, arrays element by element, everything else by equality).
+//! `${name}` is replaced by a script var, a `--var name=value`, a value
+//! captured (by JSON pointer) from an earlier expected message, or `run`,
+//! the run's index.
+//!
+//! With `--runs`/`--concurrency` the script is played many times at once and
+//! the command reports pass/fail counts and run times, as a load generator.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context};
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use wrldbldr_protocol::ClientMessage;
+
+const DEFAULT_URL: &str = "ws://127.0.0.1:3000/ws";
+const DEFAULT_TIMEOUT_MS: u64 = 5000;
+
+/// Server messages listed in a timeout error, most recent last
+const RECENT_MESSAGES: usize = 5;
+
+const USAGE: &str = "Usage: cargo xtask scenario <file> [--url <ws-url>] [--var name=value]... \
+                     [--runs <n>] [--concurrency <n>]";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Scenario {
+    #[serde(default)]
+    name: Option<String>,
+    /// Defaults for `${name}` substitution; `--var` overrides them
+    #[serde(default)]
+    vars: BTreeMap<String, Value>,
+    /// One WebSocket connection per client
+    clients: Vec<String>,
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Step {
+    #[serde(default)]
+    client: Option<String>,
+    #[serde(default)]
+    send: Option<Value>,
+    #[serde(default)]
+    expect: Option<Value>,
+    /// Var name to JSON pointer into the matched message
+    #[serde(default)]
+    capture: BTreeMap<String, String>,
+    #[serde(default)]
+    timeout_ms: Option<u64>,
+    #[serde(default)]
+    sleep_ms: Option<u64>,
+}
+
+/// What a step does, once validated
+enum Action<'a> {
+    Send(&'a str, &'a Value),
+    Expect(&'a str, &'a Value),
+    Sleep(Duration),
+}
+
+impl Step {
+    fn action(&self) -> anyhow::Result<Action<'_>> {
+        let client = || {
+            self.client
+                .as_deref()
+                .context("`send` and `expect` steps need a `client`")
+        };
+        let action = match (&self.send, &self.expect, self.sleep_ms) {
+            (Some(message), None, None) => Action::Send(client()?, message),
+            (None, Some(pattern), None) => Action::Expect(client()?, pattern),
+            (None, None, Some(ms)) => Action::Sleep(Duration::from_millis(ms)),
+            _ => bail!("a step needs exactly one of `send`, `expect` or `sleep_ms`"),
+        };
+        if !self.capture.is_empty() && !matches!(action, Action::Expect(..)) {
+            bail!("`capture` only applies to `expect` steps");
+        }
+        if self.timeout_ms.is_some() && !matches!(action, Action::Expect(..)) {
+            bail!("`timeout_ms` only applies to `expect` steps");
+        }
+        Ok(action)
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS))
+    }
+}
+
+impl Scenario {
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let is_json = path.extension().is_some_and(|ext| ext == "json");
+        let scenario = if is_json {
+            Self::from_json(&text)
+        } else {
+            Self::from_yaml(&text)
+        }
+        .with_context(|| format!("parsing {}", path.display()))?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    fn from_json(text: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(text)?)
+    }
+
+    fn from_yaml(text: &str) -> anyhow::Result<Self> {
+        Ok(serde_yaml::from_str(text)?)
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.clients.is_empty() {
+            bail!("scenario declares no clients");
+        }
+        for (index, step) in self.steps.iter().enumerate() {
+            let client = match step.action().with_context(|| step_label(index))? {
+                Action::Send(client, _) | Action::Expect(client, _) => client,
+                Action::Sleep(_) => continue,
+            };
+            if !self.clients.iter().any(|c| c == client) {
+                bail!("{}: unknown client `{client}`", step_label(index));
+            }
+        }
+        Ok(())
+    }
+
+    fn title(&self) -> &str {
+        self.name.as_deref().unwrap_or("scenario")
+    }
+}
+
+fn step_label(index: usize) -> String {
+    format!("step {}", index + 1)
+}
+
+/// Replace `${name}` in every string of `value`.
+///
+/// A string that is exactly `${name}` becomes the var's value, so non-string
+/// vars keep their JSON type; inside longer strings vars are spliced as text.
+fn substitute(value: &Value, vars: &BTreeMap<String, Value>) -> anyhow::Result<Value> {
+    Ok(match value {
+        Value::String(text) => substitute_str(text, vars)?,
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| substitute(item, vars))
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, item)| Ok((key.clone(), substitute(item, vars)?)))
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        other => other.clone(),
+    })
+}
+
+fn substitute_str(text: &str, vars: &BTreeMap<String, Value>) -> anyhow::Result<Value> {
+    let lookup = |name: &str| {
+        vars.get(name)
+            .with_context(|| format!("unknown var `{name}` (pass it with --var {name}=...)"))
+    };
+
+    if let Some(name) = text.strip_prefix("${").and_then(|s| s.strip_suffix('}')) {
+        if !name.contains(['$', '{', '}']) {
+            return Ok(lookup(name)?.clone());
+        }
+    }
+
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find('}')
+            .with_context(|| format!("unclosed `${{` in \"{text}\""))?;
+        match lookup(&after[..end])? {
+            Value::String(s) => out.push_str(s),
+            other => out.push_str(&other.to_string()),
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(Value::String(out))
+}
+
+/// Whether `actual` has everything `pattern` lists
+fn matches(pattern: &Value, actual: &Value) -> bool {
+    match (pattern, actual) {
+        (Value::Object(expected), Value::Object(actual)) => expected
+            .iter()
+            .all(|(key, value)| actual.get(key).is_some_and(|a| matches(value, a))),
+        (Value::Array(expected), Value::Array(actual)) => {
+            expected.len() == actual.len()
+                && expected.iter().zip(actual).all(|(e, a)| matches(e, a))
+        }
+        _ => pattern == actual,
+    }
+}
+
+fn message_type(message: &Value) -> &str {
+    message.get("type").and_then(Value::as_str).unwrap_or("?")
+}
+
+struct Connection {
+    socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    /// Types of the last few messages received, for timeout errors
+    recent: Vec<String>,
+}
+
+impl Connection {
+    async fn open(url: &str) -> anyhow::Result<Self> {
+        let (socket, _) = connect_async(url)
+            .await
+            .with_context(|| format!("connecting to {url}"))?;
+        Ok(Self {
+            socket,
+            recent: Vec::new(),
+        })
+    }
+
+    async fn send(&mut self, message: &Value) -> anyhow::Result<()> {
+        // Catch typos before the Engine silently drops the message
+        let parsed: ClientMessage = serde_json::from_value(message.clone())
+            .with_context(|| format!("not a valid client message: {message}"))?;
+        if matches!(parsed, ClientMessage::Unknown) {
+            bail!("unknown client message type `{}`", message_type(message));
+        }
+        self.socket
+            .send(Message::Text(message.to_string()))
+            .await
+            .context("sending message")
+    }
+
+    /// Read until a message matches `pattern`
+    async fn expect(&mut self, pattern: &Value, timeout: Duration) -> anyhow::Result<Value> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let frame = match tokio::time::timeout_at(deadline, self.socket.next()).await {
+                Ok(Some(frame)) => frame.context("reading message")?,
+                Ok(None) => bail!("connection closed while waiting for {pattern}"),
+                Err(_) => bail!(
+                    "timed out after {} ms waiting for {pattern}; last received: [{}]",
+                    timeout.as_millis(),
+                    self.recent.join(", ")
+                ),
+            };
+            let Message::Text(text) = frame else {
+                continue;
+            };
+            let message: Value = serde_json::from_str(&text)
+                .with_context(|| format!("server sent invalid JSON: {text}"))?;
+            if matches(pattern, &message) {
+                return Ok(message);
+            }
+            if self.recent.len() == RECENT_MESSAGES {
+                self.recent.remove(0);
+            }
+            self.recent.push(message_type(&message).to_string());
+        }
+    }
+}
+
+/// Play the scenario once, returning how long it took
+async fn run_once(
+    scenario: &Scenario,
+    url: &str,
+    mut vars: BTreeMap<String, Value>,
+    verbose: bool,
+) -> anyhow::Result<Duration> {
+    let started = Instant::now();
+    let mut connections = BTreeMap::new();
+    for client in &scenario.clients {
+        let connection = Connection::open(url)
+            .await
+            .with_context(|| format!("client `{client}`"))?;
+        connections.insert(client.as_str(), connection);
+    }
+
+    for (index, step) in scenario.steps.iter().enumerate() {
+        let step_started = Instant::now();
+        let label = step_label(index);
+        match step.action()? {
+            Action::Send(client, message) => {
+                let message = substitute(message, &vars).with_context(|| label.clone())?;
+                connections
+                    .get_mut(client)
+                    .expect("validated client")
+                    .send(&message)
+                    .await
+                    .with_context(|| format!("{label}: {client} send"))?;
+                if verbose {
+                    println!("  {label}: {client} -> {}", message_type(&message));
+                }
+            }
+            Action::Expect(client, pattern) => {
+                let pattern = substitute(pattern, &vars).with_context(|| label.clone())?;
+                let message = connections
+                    .get_mut(client)
+                    .expect("validated client")
+                    .expect(&pattern, step.timeout())
+                    .await
+                    .with_context(|| format!("{label}: {client} expect"))?;
+                for (name, pointer) in &step.capture {
+                    let value = message.pointer(pointer).with_context(|| {
+                        format!("{label}: capture `{name}`: nothing at {pointer} in {message}")
+                    })?;
+                    vars.insert(name.clone(), value.clone());
+                }
+                if verbose {
+                    println!(
+                        "  {label}: {client} <- {} ({} ms)",
+                        message_type(&message),
+                        step_started.elapsed().as_millis()
+                    );
+                }
+            }
+            Action::Sleep(duration) => tokio::time::sleep(duration).await,
+        }
+    }
+
+    for connection in connections.values_mut() {
+        let _ = connection.socket.close(None).await;
+    }
+    Ok(started.elapsed())
+}
+
+struct Options {
+    path: String,
+    url: String,
+    vars: BTreeMap<String, Value>,
+    runs: usize,
+    concurrency: usize,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<Options> {
+    let mut path = None;
+    let mut url = DEFAULT_URL.to_string();
+    let mut vars = BTreeMap::new();
+    let mut runs = 1;
+    let mut concurrency = 1;
+
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().with_context(|| format!("{flag} needs a value"));
+        match arg.as_str() {
+            "--url" => url = value("--url")?,
+            "--var" => {
+                let pair = value("--var")?;
+                let (name, val) = pair
+                    .split_once('=')
+                    .with_context(|| format!("--var expects name=value, got `{pair}`"))?;
+                vars.insert(name.to_string(), Value::String(val.to_string()));
+            }
+            "--runs" => runs = value("--runs")?.parse().context("--runs")?,
+            "--concurrency" => {
+                concurrency = value("--concurrency")?.parse().context("--concurrency")?
+            }
+            flag if flag.starts_with("--") => bail!("unknown option {flag}\n\n{USAGE}"),
+            _ if path.is_none() => path = Some(arg),
+            _ => bail!("unexpected argument `{arg}`\n\n{USAGE}"),
+        }
+    }
+
+    if runs == 0 || concurrency == 0 {
+        bail!("--runs and --concurrency must be at least 1");
+    }
+    Ok(Options {
+        path: path.context(USAGE)?,
+        url,
+        vars,
+        runs,
+        concurrency,
+    })
+}
+
+pub fn scenario(args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let options = parse_args(args)?;
+    let scenario = Arc::new(Scenario::load(Path::new(&options.path))?);
+    let runtime = tokio::runtime::Runtime::new().context("starting tokio runtime")?;
+    runtime.block_on(play(scenario, options))
+}
+
+async fn play(scenario: Arc<Scenario>, options: Options) -> anyhow::Result<()> {
+    let vars_for = |run: usize| {
+        let mut vars = scenario.vars.clone();
+        vars.extend(options.vars.clone());
+        vars.insert("run".to_string(), Value::from(run));
+        vars
+    };
+
+    if options.runs == 1 {
+        println!("{} ({})", scenario.title(), options.url);
+        let elapsed = run_once(&scenario, &options.url, vars_for(0), true).await?;
+        println!("passed in {} ms", elapsed.as_millis());
+        return Ok(());
+    }
+
+    println!(
+        "{}: {} runs, {} at a time ({})",
+        scenario.title(),
+        options.runs,
+        options.concurrency,
+        options.url
+    );
+    let started = Instant::now();
+    let slots = Arc::new(tokio::sync::Semaphore::new(options.concurrency));
+    let mut tasks = tokio::task::JoinSet::new();
+    for run in 0..options.runs {
+        let scenario = scenario.clone();
+        let url = options.url.clone();
+        let vars = vars_for(run);
+        let slots = slots.clone();
+        tasks.spawn(async move {
+            let _slot = slots.acquire_owned().await;
+            (run, run_once(&scenario, &url, vars, false).await)
+        });
+    }
+
+    let mut durations = Vec::new();
+    let mut failures = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined.context("scenario run panicked")? {
+            (_, Ok(elapsed)) => durations.push(elapsed),
+            (run, Err(e)) => failures.push((run, e)),
+        }
+    }
+    durations.sort();
+
+    println!(
+        "{} passed, {} failed in {} ms",
+        durations.len(),
+        failures.len(),
+        started.elapsed().as_millis()
+    );
+    if !durations.is_empty() {
+        let at = |p: usize| durations[(durations.len() - 1) * p / 100].as_millis();
+        println!(
+            "run time: min {} ms, p50 {} ms, p95 {} ms, max {} ms",
+            at(0),
+            at(50),
+            at(95),
+            at(100)
+        );
+    }
+    failures.sort_by_key(|(run, _)| *run);
+    for (run, e) in failures.iter().take(RECENT_MESSAGES) {
+        eprintln!("run {run} failed: {e:#}");
+    }
+    if !failures.is_empty() {
+        bail!("{} of {} runs failed", failures.len(), options.runs);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tokio::net::TcpListener;
+
+    fn vars(pairs: &[(&str, Value)]) -> BTreeMap<String, Value> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn whole_string_vars_keep_their_type_and_inline_vars_splice_as_text() {
+        let vars = vars(&[("n", json!(3)), ("id", json!("abc"))]);
+        let value = json!({ "count": "${n}", "user": "player-${id}-${n}", "list": ["${id}"] });
+
+        assert_eq!(
+            substitute(&value, &vars).unwrap(),
+            json!({ "count": 3, "user": "player-abc-3", "list": ["abc"] })
+        );
+        assert!(substitute(&json!("${missing}"), &vars).is_err());
+    }
+
+    #[test]
+    fn patterns_match_listed_keys_only() {
+        let message = json!({
+            "type": "DialogueResponse",
+            "speaker_name": "Mira",
+            "choices": [{ "id": "c1", "text": "Yes" }]
+        });
+
+        assert!(matches(&json!({ "type": "DialogueResponse" }), &message));
+        assert!(matches(&json!({ "choices": [{ "id": "c1" }] }), &message));
+        assert!(!matches(&json!({ "speaker_name": "Ash" }), &message));
+        assert!(!matches(&json!({ "choices": [] }), &message));
+        assert!(!matches(&json!({ "missing": null }), &message));
+    }
+
+    #[test]
+    fn yaml_scenarios_are_validated() {
+        let scenario = Scenario::from_yaml(
+            "clients: [dm]\nsteps:\n  - client: dm\n    send: { type: LeaveWorld }\n  - sleep_ms: 10\n",
+        )
+        .unwrap();
+        assert!(scenario.validate().is_ok());
+
+        let unknown_client =
+            Scenario::from_yaml("clients: [dm]\nsteps:\n  - client: player\n    expect: {}\n")
+                .unwrap();
+        assert!(unknown_client.validate().is_err());
+
+        let two_actions = Scenario::from_yaml(
+            "clients: [dm]\nsteps:\n  - client: dm\n    send: {}\n    sleep_ms: 5\n",
+        )
+        .unwrap();
+        assert!(two_actions.validate().is_err());
+    }
+
+    #[test]
+    fn example_scenarios_send_valid_client_messages() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../scenarios");
+        let id = json!("00000000-0000-0000-0000-000000000001");
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let scenario = Scenario::load(&path).unwrap();
+            let mut vars = scenario.vars.clone();
+            for name in ["world_id", "pc_id", "npc_id", "approval_id", "run"] {
+                vars.entry(name.to_string()).or_insert(id.clone());
+            }
+            for step in &scenario.steps {
+                if let Action::Send(_, message) = step.action().unwrap() {
+                    let message = substitute(message, &vars).unwrap();
+                    let parsed: ClientMessage = serde_json::from_value(message).unwrap();
+                    assert!(
+                        !matches!(parsed, ClientMessage::Unknown),
+                        "{}",
+                        path.display()
+                    );
+                }
+            }
+        }
+    }
+
+    /// Answers each JoinWorld with a WorldJoined, after an unrelated broadcast
+    async fn spawn_fake_engine() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                    while let Some(Ok(Message::Text(text))) = ws.next().await {
+                        let message: Value = serde_json::from_str(&text).unwrap();
+                        if message["type"] == "JoinWorld" {
+                            let noise = json!({ "type": "UserJoined" });
+                            let joined = json!({
+                                "type": "WorldJoined",
+                                "world_id": message["world_id"],
+                                "your_role": message["role"],
+                            });
+                            ws.send(Message::Text(noise.to_string())).await.unwrap();
+                            ws.send(Message::Text(joined.to_string())).await.unwrap();
+                        }
+                    }
+                });
+            }
+        });
+        format!("ws://{addr}")
+    }
+
+    #[tokio::test]
+    async fn runs_a_script_against_a_server_and_captures_values() {
+        let url = spawn_fake_engine().await;
+        let scenario = Scenario::from_yaml(
+            r#"
+clients: [dm]
+steps:
+  - client: dm
+    send: { type: JoinWorld, world_id: "${world_id}", role: dm, user_id: "dm-${run}" }
+  - client: dm
+    expect: { type: WorldJoined, your_role: dm }
+    capture: { joined: /world_id }
+  - client: dm
+    send: { type: JoinWorld, world_id: "${joined}", role: dm, user_id: "dm-again" }
+  - client: dm
+    expect: { type: WorldJoined, world_id: "${world_id}" }
+"#,
+        )
+        .unwrap();
+        scenario.validate().unwrap();
+        let world_id = "00000000-0000-0000-0000-000000000001";
+        let vars = vars(&[("world_id", json!(world_id)), ("run", json!(0))]);
+
+        run_once(&scenario, &url, vars, false).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn unmatched_expectations_time_out_listing_what_arrived() {
+        let url = spawn_fake_engine().await;
+        let scenario = Scenario::from_json(
+            r#"{
+                "clients": ["player"],
+                "steps": [
+                    { "client": "player", "send": { "type": "JoinWorld",
+                      "world_id": "00000000-0000-0000-0000-000000000001",
+                      "role": "player", "user_id": "p" } },
+                    { "client": "player", "expect": { "type": "DialogueResponse" },
+                      "timeout_ms": 200 }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let error = run_once(&scenario, &url, BTreeMap::new(), false)
+            .await
+            .unwrap_err();
+        let error = format!("{error:#}");
+        assert!(error.contains("step 2"), "{error}");
+        assert!(error.contains("UserJoined, WorldJoined"), "{error}");
+    }
+}
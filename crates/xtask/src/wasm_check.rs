@@ -0,0 +1,139 @@
+//! `cargo xtask wasm-check [--no-tests]`
+//!
+//! Does locally what the web Player build needs: compiles every crate the
+//! Player links for `wasm32-unknown-unknown`, then runs the protocol's serde
+//! round trips inside wasm with `wasm-bindgen-test-runner`. A native-only
+//! dependency (or a dependency feature that has no wasm backend) fails here
+//! instead of in the web build.
+//!
+//! The runner comes from `wasm-bindgen-cli` and must match the workspace's
+//! `wasm-bindgen` version; the error says which one to install. Pass
+//! `--no-tests` to only compile.
+
+use std::process::Command;
+
+use anyhow::{bail, Context};
+
+const TARGET: &str = "wasm32-unknown-unknown";
+
+/// Crates the web Player build compiles
+const CRATES: &[&str] = &["wrldbldr-domain", "wrldbldr-protocol", "wrldbldr-player"];
+
+/// wasm-bindgen tests: (package, test target)
+const TESTS: &[(&str, &str)] = &[("wrldbldr-protocol", "wasm_serde")];
+
+const RUNNER: &str = "wasm-bindgen-test-runner";
+
+pub fn wasm_check(args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let mut run_tests = true;
+    for arg in args {
+        match arg.as_str() {
+            "--no-tests" => run_tests = false,
+            other => {
+                bail!("unexpected argument `{other}`\n\nUsage: cargo xtask wasm-check [--no-tests]")
+            }
+        }
+    }
+
+    ensure_target()?;
+
+    println!("Checking {} for {TARGET}", CRATES.join(", "));
+    let mut check = cargo();
+    check.args(["check", "--target", TARGET]);
+    for krate in CRATES {
+        check.args(["-p", krate]);
+    }
+    run(check, "cargo check for wasm32")?;
+
+    if !run_tests {
+        return Ok(());
+    }
+    if !runner_installed() {
+        bail!(
+            "{RUNNER} not found; install it with\n\n  cargo install wasm-bindgen-cli --version {}\n\n\
+             or pass --no-tests to only compile",
+            wasm_bindgen_version()?
+        );
+    }
+    for (package, test) in TESTS {
+        println!("Running {package} {test} in wasm");
+        let mut cmd = cargo();
+        cmd.args(["test", "--target", TARGET, "-p", package, "--test", test])
+            .env("CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER", RUNNER);
+        run(cmd, "wasm-bindgen tests")?;
+    }
+    Ok(())
+}
+
+fn cargo() -> Command {
+    Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+}
+
+fn run(mut cmd: Command, what: &str) -> anyhow::Result<()> {
+    let status = cmd.status().with_context(|| format!("running {what}"))?;
+    if !status.success() {
+        bail!("{what} failed");
+    }
+    Ok(())
+}
+
+/// Fail early with the fix when the target is missing (rustup installs only)
+fn ensure_target() -> anyhow::Result<()> {
+    let Ok(output) = Command::new("rustup")
+        .args(["target", "list", "--installed"])
+        .output()
+    else {
+        // No rustup (e.g. a Nix toolchain): let cargo report a missing target
+        return Ok(());
+    };
+    let installed = String::from_utf8_lossy(&output.stdout);
+    if output.status.success() && !installed.lines().any(|line| line.trim() == TARGET) {
+        bail!("the {TARGET} target is not installed; run `rustup target add {TARGET}` (task setup:wasm)");
+    }
+    Ok(())
+}
+
+fn runner_installed() -> bool {
+    Command::new(RUNNER)
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// The locked `wasm-bindgen` version, which the CLI has to match
+fn wasm_bindgen_version() -> anyhow::Result<String> {
+    let output = cargo()
+        .args(["pkgid", "-p", "wasm-bindgen"])
+        .output()
+        .context("running cargo pkgid")?;
+    let pkgid = String::from_utf8_lossy(&output.stdout);
+    version_from_pkgid(&pkgid).context("reading the wasm-bindgen version from cargo pkgid")
+}
+
+/// `registry+https://…#wasm-bindgen@0.2.106` (or `…#0.2.106`) -> `0.2.106`
+fn version_from_pkgid(pkgid: &str) -> Option<String> {
+    let (_, fragment) = pkgid.trim().rsplit_once('#')?;
+    let version = fragment.rsplit_once('@').map_or(fragment, |(_, v)| v);
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_version_from_either_pkgid_format() {
+        assert_eq!(
+            version_from_pkgid(
+                "registry+https://github.com/rust-lang/crates.io-index#wasm-bindgen@0.2.106\n"
+            )
+            .as_deref(),
+            Some("0.2.106")
+        );
+        assert_eq!(
+            version_from_pkgid("https://github.com/rust-lang/crates.io-index#0.2.92").as_deref(),
+            Some("0.2.92")
+        );
+        assert_eq!(version_from_pkgid("wasm-bindgen"), None);
+    }
+}